@@ -0,0 +1,93 @@
+//! Counts the allocations of encoding the same account id for many requests:
+//! as the functions did before the client had a path cache, with a client
+//! without one, and with the client's path cache.
+//!
+//! Run with `cargo bench --bench path_cache`.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Instant,
+};
+
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
+
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+const CALLS: usize = 100_000;
+
+const ACCOUNT_ID: &str = "8f3c2b1a-0d4e-4f5a-9b6c-7d8e9f0a1b2c";
+
+/// The set `progenitor_support::encode_path` encodes, which is private to the
+/// crate.
+const PATH_SET: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'`')
+    .add(b'{')
+    .add(b'}')
+    .add(b'/')
+    .add(b'%');
+
+/// How the functions encoded a path parameter before the client had a path
+/// cache: `progenitor_support::encode_path(&account_id.to_string())`.
+fn baseline_encode_path(pc: &str) -> String {
+    utf8_percent_encode(pc, PATH_SET).to_string()
+}
+
+fn run<F, E>(name: &str, encode_path: F)
+where
+    F: Fn(&str) -> E,
+    E: std::fmt::Display,
+{
+    // Fill the cache, if there is one, before counting.
+    encode_path(ACCOUNT_ID).to_string();
+
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    for _ in 0..CALLS {
+        let url = format!("/v2.1/accounts/{}/envelopes", encode_path(ACCOUNT_ID));
+        std::hint::black_box(url);
+    }
+    let elapsed = start.elapsed();
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - before;
+
+    println!(
+        "{:>9}: {:.2} allocations and {:?} per call",
+        name,
+        allocations as f64 / CALLS as f64,
+        elapsed / CALLS as u32
+    );
+}
+
+fn main() {
+    let client = docusign::Client::new("", "", "", "token", "");
+    let uncached = client.with_path_cache(0);
+
+    run("baseline", |id| {
+        let id = id.to_string();
+        baseline_encode_path(&id)
+    });
+    run("uncached", |id| uncached.encode_path(id));
+    run("cached", |id| client.encode_path(id));
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct AccountBrands {
     pub client: Client,
@@ -12,6 +10,14 @@ impl AccountBrands {
         AccountBrands { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        AccountBrands {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets a list of brands.
      *
@@ -33,24 +39,31 @@ impl AccountBrands {
         exclude_distributor_brand: &str,
         include_logos: &str,
     ) -> Result<crate::types::AccountBrands> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_distributor_brand.is_empty() {
             query_args.push((
-                "exclude_distributor_brand".to_string(),
+                "exclude_distributor_brand",
                 exclude_distributor_brand.to_string(),
             ));
         }
         if !include_logos.is_empty() {
-            query_args.push(("include_logos".to_string(), include_logos.to_string()));
+            query_args.push(("include_logos", include_logos.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/brands?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/brands",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/brands",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -77,12 +90,19 @@ impl AccountBrands {
     ) -> Result<crate::types::AccountBrands> {
         let url = format!(
             "/v2.1/accounts/{}/brands",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/brands",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -105,12 +125,19 @@ impl AccountBrands {
     ) -> Result<crate::types::AccountBrands> {
         let url = format!(
             "/v2.1/accounts/{}/brands",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .delete(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/brands",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -136,25 +163,35 @@ impl AccountBrands {
         include_external_references: &str,
         include_logos: &str,
     ) -> Result<crate::types::Brand> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_external_references.is_empty() {
             query_args.push((
-                "include_external_references".to_string(),
+                "include_external_references",
                 include_external_references.to_string(),
             ));
         }
         if !include_logos.is_empty() {
-            query_args.push(("include_logos".to_string(), include_logos.to_string()));
+            query_args.push(("include_logos", include_logos.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/brands/{}?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&brand_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/brands/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(brand_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/brands/{brandId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("brandId", brand_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -179,13 +216,23 @@ impl AccountBrands {
     ) -> Result<crate::types::Brand> {
         let url = format!(
             "/v2.1/accounts/{}/brands/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&brand_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(brand_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/brands/{brandId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("brandId", brand_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -205,11 +252,49 @@ impl AccountBrands {
     pub async fn brand_delete(&self, account_id: &str, brand_id: &str) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/brands/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&brand_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(brand_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/brands/{brandId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("brandId", brand_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Deletes a brand.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/brands/{brandId}` endpoint.
+     *
+     * As opposed to `brand_delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn brand_delete_if_exists(&self, account_id: &str, brand_id: &str) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/brands/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(brand_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/brands/{brandId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("brandId", brand_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -229,11 +314,20 @@ impl AccountBrands {
     pub async fn brand_export_get_file(&self, account_id: &str, brand_id: &str) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/brands/{}/file",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&brand_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(brand_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/brands/{brandId}/file",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("brandId", brand_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -259,12 +353,25 @@ impl AccountBrands {
     ) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/brands/{}/logos/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&brand_id.to_string()),
-            crate::progenitor_support::encode_path(&logo_type.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(brand_id),
+            self.client.encode_path(logo_type),
         );
 
-        self.client.get(&url, None).await
+        self.client
+            .request_with_accept_mime(reqwest::Method::GET, &url, "image/png")
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/v2.1/accounts/{accountId}/brands/{brandId}/logos/{logoType}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("brandId", brand_id.to_string()),
+                        ("logoType", logo_type.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -286,21 +393,76 @@ impl AccountBrands {
      * * `brand_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `logo_type: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      */
-    pub async fn brand_logo_put<T: Into<reqwest::Body>>(
+    pub async fn brand_logo_put<B: AsRef<[u8]>>(
         &self,
         account_id: &str,
         brand_id: &str,
         logo_type: &str,
-        body: bytes::Bytes,
+        body: B,
     ) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/brands/{}/logos/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&brand_id.to_string()),
-            crate::progenitor_support::encode_path(&logo_type.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(brand_id),
+            self.client.encode_path(logo_type),
         );
 
-        self.client.put(&url, Some(body.into())).await
+        self.client
+            .put(
+                &url,
+                Some(bytes::Bytes::copy_from_slice(body.as_ref()).into()),
+            )
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/brands/{brandId}/logos/{logoType}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("brandId", brand_id.to_string()),
+                        ("logoType", logo_type.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * Updates a brand logo.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/brands/{brandId}/logos/{logoType}` endpoint.
+     *
+     * As opposed to `brand_logo_put`, this function reads the body from `body`, a file say, and streams what is past the client's `with_request_body_limit` as it is read.
+     * A streamed upload is not retried.
+     */
+    pub async fn brand_logo_put_from_reader<
+        R: tokio::io::AsyncRead + Send + Sync + Unpin + 'static,
+    >(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        logo_type: &str,
+        body: R,
+    ) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/brands/{}/logos/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(brand_id),
+            self.client.encode_path(logo_type),
+        );
+
+        let (client, body) = self.client.reader_body(body).await?;
+
+        client.put(&url, Some(body)).await.with_context(|| {
+            crate::utils::pretty_url(
+                "PUT",
+                "/v2.1/accounts/{accountId}/brands/{brandId}/logos/{logoType}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("brandId", brand_id.to_string()),
+                    ("logoType", logo_type.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -326,12 +488,58 @@ impl AccountBrands {
     ) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/brands/{}/logos/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&brand_id.to_string()),
-            crate::progenitor_support::encode_path(&logo_type.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(brand_id),
+            self.client.encode_path(logo_type),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/brands/{brandId}/logos/{logoType}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("brandId", brand_id.to_string()),
+                    ("logoType", logo_type.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Deletes a brand logo.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/brands/{brandId}/logos/{logoType}` endpoint.
+     *
+     * As opposed to `brand_logo_delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn brand_logo_delete_if_exists(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        logo_type: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/brands/{}/logos/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(brand_id),
+            self.client.encode_path(logo_type),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/brands/{brandId}/logos/{logoType}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("brandId", brand_id.to_string()),
+                        ("logoType", logo_type.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -355,11 +563,20 @@ impl AccountBrands {
     ) -> Result<crate::types::BrandResourcesList> {
         let url = format!(
             "/v2.1/accounts/{}/brands/{}/resources",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&brand_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(brand_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/brands/{brandId}/resources",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("brandId", brand_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -396,23 +613,34 @@ impl AccountBrands {
         langcode: &str,
         return_master: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !langcode.is_empty() {
-            query_args.push(("langcode".to_string(), langcode.to_string()));
+            query_args.push(("langcode", langcode.to_string()));
         }
         if !return_master.is_empty() {
-            query_args.push(("return_master".to_string(), return_master.to_string()));
+            query_args.push(("return_master", return_master.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/brands/{}/resources/{}?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&brand_id.to_string()),
-            crate::progenitor_support::encode_path(&resource_content_type.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/brands/{}/resources/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(brand_id),
+                self.client.encode_path(resource_content_type)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/brands/{brandId}/resources/{resourceContentType}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("brandId", brand_id.to_string()),
+                    ("resourceContentType", resource_content_type.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -442,20 +670,239 @@ impl AccountBrands {
      *   - `signing`
      *   - `email`
      *   - `signing_captive`.
+     * * `file_xml: &[u8]` -- Brand resource XML file.
      */
     pub async fn brand_resources_put(
         &self,
         account_id: &str,
         brand_id: &str,
         resource_content_type: &str,
+        file_xml: &[u8],
     ) -> Result<crate::types::BrandResources> {
         let url = format!(
             "/v2.1/accounts/{}/brands/{}/resources/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&brand_id.to_string()),
-            crate::progenitor_support::encode_path(&resource_content_type.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(brand_id),
+            self.client.encode_path(resource_content_type),
         );
 
-        self.client.put(&url, None).await
+        let form = reqwest::multipart::Form::new().part(
+            "file.xml",
+            reqwest::multipart::Part::bytes(file_xml.to_vec()).file_name("file.xml"),
+        );
+
+        self.client
+            .request_form(reqwest::Method::PUT, &url, form)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/brands/{brandId}/resources/{resourceContentType}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("brandId", brand_id.to_string()),
+                        ("resourceContentType", resource_content_type.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`AccountBrands`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AccountBrandsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AccountBrandsApi: Send + Sync {
+    async fn brands_get(
+        &self,
+        account_id: &str,
+        exclude_distributor_brand: &str,
+        include_logos: &str,
+    ) -> Result<crate::types::AccountBrands>;
+    async fn brands_post(
+        &self,
+        account_id: &str,
+        body: &crate::types::Brand,
+    ) -> Result<crate::types::AccountBrands>;
+    async fn brands_delete(
+        &self,
+        account_id: &str,
+        body: &crate::types::BrandsRequest,
+    ) -> Result<crate::types::AccountBrands>;
+    async fn brand_get(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        include_external_references: &str,
+        include_logos: &str,
+    ) -> Result<crate::types::Brand>;
+    async fn brand_put(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        body: &crate::types::Brand,
+    ) -> Result<crate::types::Brand>;
+    async fn brand_delete(&self, account_id: &str, brand_id: &str) -> Result<()>;
+    async fn brand_delete_if_exists(&self, account_id: &str, brand_id: &str) -> Result<bool>;
+    async fn brand_export_get_file(&self, account_id: &str, brand_id: &str) -> Result<()>;
+    async fn brand_logo_get(&self, account_id: &str, brand_id: &str, logo_type: &str)
+        -> Result<()>;
+    async fn brand_logo_delete(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        logo_type: &str,
+    ) -> Result<()>;
+    async fn brand_logo_delete_if_exists(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        logo_type: &str,
+    ) -> Result<bool>;
+    async fn brand_resources_get_list(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+    ) -> Result<crate::types::BrandResourcesList>;
+    async fn brand_resources_get(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        resource_content_type: &str,
+        langcode: &str,
+        return_master: &str,
+    ) -> Result<()>;
+    async fn brand_resources_put(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        resource_content_type: &str,
+        file_xml: &[u8],
+    ) -> Result<crate::types::BrandResources>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AccountBrandsApi for AccountBrands {
+    async fn brands_get(
+        &self,
+        account_id: &str,
+        exclude_distributor_brand: &str,
+        include_logos: &str,
+    ) -> Result<crate::types::AccountBrands> {
+        AccountBrands::brands_get(self, account_id, exclude_distributor_brand, include_logos).await
+    }
+    async fn brands_post(
+        &self,
+        account_id: &str,
+        body: &crate::types::Brand,
+    ) -> Result<crate::types::AccountBrands> {
+        AccountBrands::brands_post(self, account_id, body).await
+    }
+    async fn brands_delete(
+        &self,
+        account_id: &str,
+        body: &crate::types::BrandsRequest,
+    ) -> Result<crate::types::AccountBrands> {
+        AccountBrands::brands_delete(self, account_id, body).await
+    }
+    async fn brand_get(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        include_external_references: &str,
+        include_logos: &str,
+    ) -> Result<crate::types::Brand> {
+        AccountBrands::brand_get(
+            self,
+            account_id,
+            brand_id,
+            include_external_references,
+            include_logos,
+        )
+        .await
+    }
+    async fn brand_put(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        body: &crate::types::Brand,
+    ) -> Result<crate::types::Brand> {
+        AccountBrands::brand_put(self, account_id, brand_id, body).await
+    }
+    async fn brand_delete(&self, account_id: &str, brand_id: &str) -> Result<()> {
+        AccountBrands::brand_delete(self, account_id, brand_id).await
+    }
+    async fn brand_delete_if_exists(&self, account_id: &str, brand_id: &str) -> Result<bool> {
+        AccountBrands::brand_delete_if_exists(self, account_id, brand_id).await
+    }
+    async fn brand_export_get_file(&self, account_id: &str, brand_id: &str) -> Result<()> {
+        AccountBrands::brand_export_get_file(self, account_id, brand_id).await
+    }
+    async fn brand_logo_get(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        logo_type: &str,
+    ) -> Result<()> {
+        AccountBrands::brand_logo_get(self, account_id, brand_id, logo_type).await
+    }
+    async fn brand_logo_delete(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        logo_type: &str,
+    ) -> Result<()> {
+        AccountBrands::brand_logo_delete(self, account_id, brand_id, logo_type).await
+    }
+    async fn brand_logo_delete_if_exists(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        logo_type: &str,
+    ) -> Result<bool> {
+        AccountBrands::brand_logo_delete_if_exists(self, account_id, brand_id, logo_type).await
+    }
+    async fn brand_resources_get_list(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+    ) -> Result<crate::types::BrandResourcesList> {
+        AccountBrands::brand_resources_get_list(self, account_id, brand_id).await
+    }
+    async fn brand_resources_get(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        resource_content_type: &str,
+        langcode: &str,
+        return_master: &str,
+    ) -> Result<()> {
+        AccountBrands::brand_resources_get(
+            self,
+            account_id,
+            brand_id,
+            resource_content_type,
+            langcode,
+            return_master,
+        )
+        .await
+    }
+    async fn brand_resources_put(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        resource_content_type: &str,
+        file_xml: &[u8],
+    ) -> Result<crate::types::BrandResources> {
+        AccountBrands::brand_resources_put(
+            self,
+            account_id,
+            brand_id,
+            resource_content_type,
+            file_xml,
+        )
+        .await
     }
 }
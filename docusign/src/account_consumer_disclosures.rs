@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct AccountConsumerDisclosures {
     pub client: Client,
@@ -12,6 +10,14 @@ impl AccountConsumerDisclosures {
         AccountConsumerDisclosures { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        AccountConsumerDisclosures {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets the default Electronic Record and Signature Disclosure for an account.
      *
@@ -79,18 +85,25 @@ impl AccountConsumerDisclosures {
         account_id: &str,
         lang_code: &str,
     ) -> Result<crate::types::AccountConsumerDisclosures> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !lang_code.is_empty() {
-            query_args.push(("langCode".to_string(), lang_code.to_string()));
+            query_args.push(("langCode", lang_code.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/consumer_disclosure?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/consumer_disclosure",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/consumer_disclosure",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -162,11 +175,20 @@ impl AccountConsumerDisclosures {
     ) -> Result<crate::types::AccountConsumerDisclosures> {
         let url = format!(
             "/v2.1/accounts/{}/consumer_disclosure/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&lang_code.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(lang_code),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/consumer_disclosure/{langCode}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("langCode", lang_code.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -284,20 +306,92 @@ impl AccountConsumerDisclosures {
         include_metadata: &str,
         body: &crate::types::ConsumerDisclosure,
     ) -> Result<crate::types::ConsumerDisclosure> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_metadata.is_empty() {
-            query_args.push(("include_metadata".to_string(), include_metadata.to_string()));
+            query_args.push(("include_metadata", include_metadata.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/consumer_disclosure/{}?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&lang_code.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/consumer_disclosure/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(lang_code)
+            ),
+            &query_args,
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/consumer_disclosure/{langCode}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("langCode", lang_code.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`AccountConsumerDisclosures`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AccountConsumerDisclosuresApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AccountConsumerDisclosuresApi: Send + Sync {
+    async fn consumer_disclosure_get(
+        &self,
+        account_id: &str,
+        lang_code: &str,
+    ) -> Result<crate::types::AccountConsumerDisclosures>;
+    async fn consumer_disclosure_get_lang_code(
+        &self,
+        account_id: &str,
+        lang_code: &str,
+    ) -> Result<crate::types::AccountConsumerDisclosures>;
+    async fn consumer_disclosure_put(
+        &self,
+        account_id: &str,
+        lang_code: &str,
+        include_metadata: &str,
+        body: &crate::types::ConsumerDisclosure,
+    ) -> Result<crate::types::ConsumerDisclosure>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AccountConsumerDisclosuresApi for AccountConsumerDisclosures {
+    async fn consumer_disclosure_get(
+        &self,
+        account_id: &str,
+        lang_code: &str,
+    ) -> Result<crate::types::AccountConsumerDisclosures> {
+        AccountConsumerDisclosures::consumer_disclosure_get(self, account_id, lang_code).await
+    }
+    async fn consumer_disclosure_get_lang_code(
+        &self,
+        account_id: &str,
+        lang_code: &str,
+    ) -> Result<crate::types::AccountConsumerDisclosures> {
+        AccountConsumerDisclosures::consumer_disclosure_get_lang_code(self, account_id, lang_code)
+            .await
+    }
+    async fn consumer_disclosure_put(
+        &self,
+        account_id: &str,
+        lang_code: &str,
+        include_metadata: &str,
+        body: &crate::types::ConsumerDisclosure,
+    ) -> Result<crate::types::ConsumerDisclosure> {
+        AccountConsumerDisclosures::consumer_disclosure_put(
+            self,
+            account_id,
+            lang_code,
+            include_metadata,
+            body,
+        )
+        .await
     }
 }
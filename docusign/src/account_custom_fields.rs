@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct AccountCustomFields {
     pub client: Client,
@@ -12,6 +10,14 @@ impl AccountCustomFields {
         AccountCustomFields { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        AccountCustomFields {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets a list of custom fields.
      *
@@ -26,10 +32,16 @@ impl AccountCustomFields {
     pub async fn get(&self, account_id: &str) -> Result<crate::types::AccountCustomFields> {
         let url = format!(
             "/v2.1/accounts/{}/custom_fields",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/custom_fields",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -42,31 +54,36 @@ impl AccountCustomFields {
      * **Parameters:**
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `apply_to_templates: &str` -- (Optional) When set to **true**, the new custom field is applied to all of the templates on the account.
+     * * `apply_to_templates: bool` -- (Optional) When set to **true**, the new custom field is applied to all of the templates on the account.
      */
     pub async fn post(
         &self,
         account_id: &str,
-        apply_to_templates: &str,
+        apply_to_templates: bool,
         body: &crate::types::CustomField,
     ) -> Result<crate::types::AccountCustomFields> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !apply_to_templates.is_empty() {
-            query_args.push((
-                "apply_to_templates".to_string(),
-                apply_to_templates.to_string(),
-            ));
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if apply_to_templates {
+            query_args.push(("apply_to_templates", apply_to_templates.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/custom_fields?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/custom_fields",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/custom_fields",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -79,34 +96,42 @@ impl AccountCustomFields {
      * **Parameters:**
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `custom_field_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `apply_to_templates: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `custom_field_id: &crate::custom_field_id::CustomFieldId` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `apply_to_templates: bool` -- The brand that envelope recipients see when a brand is not explicitly set.
      */
     pub async fn put(
         &self,
         account_id: &str,
-        custom_field_id: &str,
-        apply_to_templates: &str,
+        custom_field_id: &crate::custom_field_id::CustomFieldId,
+        apply_to_templates: bool,
         body: &crate::types::CustomField,
     ) -> Result<crate::types::AccountCustomFields> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !apply_to_templates.is_empty() {
-            query_args.push((
-                "apply_to_templates".to_string(),
-                apply_to_templates.to_string(),
-            ));
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if apply_to_templates {
+            query_args.push(("apply_to_templates", apply_to_templates.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/custom_fields/{}?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&custom_field_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/custom_fields/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(&custom_field_id.to_string())
+            ),
+            &query_args,
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/custom_fields/{customFieldId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("customFieldId", custom_field_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -119,30 +144,154 @@ impl AccountCustomFields {
      * **Parameters:**
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `custom_field_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `apply_to_templates: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `custom_field_id: &crate::custom_field_id::CustomFieldId` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `apply_to_templates: bool` -- The brand that envelope recipients see when a brand is not explicitly set.
      */
     pub async fn delete(
         &self,
         account_id: &str,
-        custom_field_id: &str,
-        apply_to_templates: &str,
+        custom_field_id: &crate::custom_field_id::CustomFieldId,
+        apply_to_templates: bool,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !apply_to_templates.is_empty() {
-            query_args.push((
-                "apply_to_templates".to_string(),
-                apply_to_templates.to_string(),
-            ));
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if apply_to_templates {
+            query_args.push(("apply_to_templates", apply_to_templates.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/custom_fields/{}?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&custom_field_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/custom_fields/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(&custom_field_id.to_string())
+            ),
+            &query_args,
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/custom_fields/{customFieldId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("customFieldId", custom_field_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Deletes an account custom field.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/custom_fields/{customFieldId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(
+        &self,
+        account_id: &str,
+        custom_field_id: &crate::custom_field_id::CustomFieldId,
+        apply_to_templates: bool,
+    ) -> Result<bool> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if apply_to_templates {
+            query_args.push(("apply_to_templates", apply_to_templates.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/custom_fields/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(&custom_field_id.to_string())
+            ),
+            &query_args,
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/custom_fields/{customFieldId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("customFieldId", custom_field_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`AccountCustomFields`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AccountCustomFieldsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AccountCustomFieldsApi: Send + Sync {
+    async fn get(&self, account_id: &str) -> Result<crate::types::AccountCustomFields>;
+    async fn post(
+        &self,
+        account_id: &str,
+        apply_to_templates: bool,
+        body: &crate::types::CustomField,
+    ) -> Result<crate::types::AccountCustomFields>;
+    async fn put(
+        &self,
+        account_id: &str,
+        custom_field_id: &crate::custom_field_id::CustomFieldId,
+        apply_to_templates: bool,
+        body: &crate::types::CustomField,
+    ) -> Result<crate::types::AccountCustomFields>;
+    async fn delete(
+        &self,
+        account_id: &str,
+        custom_field_id: &crate::custom_field_id::CustomFieldId,
+        apply_to_templates: bool,
+    ) -> Result<()>;
+    async fn delete_if_exists(
+        &self,
+        account_id: &str,
+        custom_field_id: &crate::custom_field_id::CustomFieldId,
+        apply_to_templates: bool,
+    ) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AccountCustomFieldsApi for AccountCustomFields {
+    async fn get(&self, account_id: &str) -> Result<crate::types::AccountCustomFields> {
+        AccountCustomFields::get(self, account_id).await
+    }
+    async fn post(
+        &self,
+        account_id: &str,
+        apply_to_templates: bool,
+        body: &crate::types::CustomField,
+    ) -> Result<crate::types::AccountCustomFields> {
+        AccountCustomFields::post(self, account_id, apply_to_templates, body).await
+    }
+    async fn put(
+        &self,
+        account_id: &str,
+        custom_field_id: &crate::custom_field_id::CustomFieldId,
+        apply_to_templates: bool,
+        body: &crate::types::CustomField,
+    ) -> Result<crate::types::AccountCustomFields> {
+        AccountCustomFields::put(self, account_id, custom_field_id, apply_to_templates, body).await
+    }
+    async fn delete(
+        &self,
+        account_id: &str,
+        custom_field_id: &crate::custom_field_id::CustomFieldId,
+        apply_to_templates: bool,
+    ) -> Result<()> {
+        AccountCustomFields::delete(self, account_id, custom_field_id, apply_to_templates).await
+    }
+    async fn delete_if_exists(
+        &self,
+        account_id: &str,
+        custom_field_id: &crate::custom_field_id::CustomFieldId,
+        apply_to_templates: bool,
+    ) -> Result<bool> {
+        AccountCustomFields::delete_if_exists(self, account_id, custom_field_id, apply_to_templates)
+            .await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct AccountPasswordRules {
     pub client: Client,
@@ -12,6 +10,14 @@ impl AccountPasswordRules {
         AccountPasswordRules { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        AccountPasswordRules {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets the password rules for an account.
      *
@@ -26,10 +32,16 @@ impl AccountPasswordRules {
     pub async fn get(&self, account_id: &str) -> Result<crate::types::AccountPasswordRulesData> {
         let url = format!(
             "/v2.1/accounts/{}/settings/password_rules",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/settings/password_rules",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -52,12 +64,19 @@ impl AccountPasswordRules {
     ) -> Result<crate::types::AccountPasswordRulesData> {
         let url = format!(
             "/v2.1/accounts/{}/settings/password_rules",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/settings/password_rules",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -69,6 +88,41 @@ impl AccountPasswordRules {
      */
     pub async fn password_rules_get(&self) -> Result<crate::types::UserPasswordRules> {
         let url = "/v2.1/current_user/password_rules".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/v2.1/current_user/password_rules", &[])
+        })
+    }
+}
+
+/// The functions of [`AccountPasswordRules`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AccountPasswordRulesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AccountPasswordRulesApi: Send + Sync {
+    async fn get(&self, account_id: &str) -> Result<crate::types::AccountPasswordRulesData>;
+    async fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::AccountPasswordRulesData,
+    ) -> Result<crate::types::AccountPasswordRulesData>;
+    async fn password_rules_get(&self) -> Result<crate::types::UserPasswordRules>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AccountPasswordRulesApi for AccountPasswordRules {
+    async fn get(&self, account_id: &str) -> Result<crate::types::AccountPasswordRulesData> {
+        AccountPasswordRules::get(self, account_id).await
+    }
+    async fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::AccountPasswordRulesData,
+    ) -> Result<crate::types::AccountPasswordRulesData> {
+        AccountPasswordRules::put(self, account_id, body).await
+    }
+    async fn password_rules_get(&self) -> Result<crate::types::UserPasswordRules> {
+        AccountPasswordRules::password_rules_get(self).await
     }
 }
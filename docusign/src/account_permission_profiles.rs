@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct AccountPermissionProfiles {
     pub client: Client,
@@ -12,6 +10,14 @@ impl AccountPermissionProfiles {
         AccountPermissionProfiles { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        AccountPermissionProfiles {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets a list of permission profiles.
      *
@@ -37,18 +43,25 @@ impl AccountPermissionProfiles {
         account_id: &str,
         include: &str,
     ) -> Result<crate::types::PermissionProfileInformation> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include.is_empty() {
-            query_args.push(("include".to_string(), include.to_string()));
+            query_args.push(("include", include.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/permission_profiles?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/permission_profiles",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/permission_profiles",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -69,20 +82,28 @@ impl AccountPermissionProfiles {
         include: &str,
         body: &crate::types::PermissionProfile,
     ) -> Result<crate::types::PermissionProfile> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include.is_empty() {
-            query_args.push(("include".to_string(), include.to_string()));
+            query_args.push(("include", include.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/permission_profiles?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/permission_profiles",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/permission_profiles",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -110,19 +131,29 @@ impl AccountPermissionProfiles {
         permission_profile_id: &str,
         include: &str,
     ) -> Result<crate::types::PermissionProfile> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include.is_empty() {
-            query_args.push(("include".to_string(), include.to_string()));
+            query_args.push(("include", include.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/permission_profiles/{}?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&permission_profile_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/permission_profiles/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(permission_profile_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/permission_profiles/{permissionProfileId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("permissionProfileId", permission_profile_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -151,21 +182,32 @@ impl AccountPermissionProfiles {
         include: &str,
         body: &crate::types::PermissionProfile,
     ) -> Result<crate::types::PermissionProfile> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include.is_empty() {
-            query_args.push(("include".to_string(), include.to_string()));
+            query_args.push(("include", include.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/permission_profiles/{}?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&permission_profile_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/permission_profiles/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(permission_profile_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/permission_profiles/{permissionProfileId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("permissionProfileId", permission_profile_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -195,18 +237,191 @@ impl AccountPermissionProfiles {
         permission_profile_id: &str,
         move_users_to: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !move_users_to.is_empty() {
+            query_args.push(("move_users_to", move_users_to.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/permission_profiles/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(permission_profile_id)
+            ),
+            &query_args,
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/permission_profiles/{permissionProfileId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("permissionProfileId", permission_profile_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Deletes a permission profile from an account.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/permission_profiles/{permissionProfileId}` endpoint.
+     *
+     * As opposed to `permission_profiles_delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn permission_profiles_delete_if_exists(
+        &self,
+        account_id: &str,
+        permission_profile_id: &str,
+        move_users_to: &str,
+    ) -> Result<bool> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !move_users_to.is_empty() {
-            query_args.push(("move_users_to".to_string(), move_users_to.to_string()));
+            query_args.push(("move_users_to", move_users_to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/permission_profiles/{}?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&permission_profile_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/permission_profiles/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(permission_profile_id)
+            ),
+            &query_args,
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/permission_profiles/{permissionProfileId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("permissionProfileId", permission_profile_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`AccountPermissionProfiles`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AccountPermissionProfilesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AccountPermissionProfilesApi: Send + Sync {
+    async fn permission_profiles_get(
+        &self,
+        account_id: &str,
+        include: &str,
+    ) -> Result<crate::types::PermissionProfileInformation>;
+    async fn permission_profiles_post(
+        &self,
+        account_id: &str,
+        include: &str,
+        body: &crate::types::PermissionProfile,
+    ) -> Result<crate::types::PermissionProfile>;
+    async fn permission_profiles_get_profile(
+        &self,
+        account_id: &str,
+        permission_profile_id: &str,
+        include: &str,
+    ) -> Result<crate::types::PermissionProfile>;
+    async fn permission_profiles_put(
+        &self,
+        account_id: &str,
+        permission_profile_id: &str,
+        include: &str,
+        body: &crate::types::PermissionProfile,
+    ) -> Result<crate::types::PermissionProfile>;
+    async fn permission_profiles_delete(
+        &self,
+        account_id: &str,
+        permission_profile_id: &str,
+        move_users_to: &str,
+    ) -> Result<()>;
+    async fn permission_profiles_delete_if_exists(
+        &self,
+        account_id: &str,
+        permission_profile_id: &str,
+        move_users_to: &str,
+    ) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AccountPermissionProfilesApi for AccountPermissionProfiles {
+    async fn permission_profiles_get(
+        &self,
+        account_id: &str,
+        include: &str,
+    ) -> Result<crate::types::PermissionProfileInformation> {
+        AccountPermissionProfiles::permission_profiles_get(self, account_id, include).await
+    }
+    async fn permission_profiles_post(
+        &self,
+        account_id: &str,
+        include: &str,
+        body: &crate::types::PermissionProfile,
+    ) -> Result<crate::types::PermissionProfile> {
+        AccountPermissionProfiles::permission_profiles_post(self, account_id, include, body).await
+    }
+    async fn permission_profiles_get_profile(
+        &self,
+        account_id: &str,
+        permission_profile_id: &str,
+        include: &str,
+    ) -> Result<crate::types::PermissionProfile> {
+        AccountPermissionProfiles::permission_profiles_get_profile(
+            self,
+            account_id,
+            permission_profile_id,
+            include,
+        )
+        .await
+    }
+    async fn permission_profiles_put(
+        &self,
+        account_id: &str,
+        permission_profile_id: &str,
+        include: &str,
+        body: &crate::types::PermissionProfile,
+    ) -> Result<crate::types::PermissionProfile> {
+        AccountPermissionProfiles::permission_profiles_put(
+            self,
+            account_id,
+            permission_profile_id,
+            include,
+            body,
+        )
+        .await
+    }
+    async fn permission_profiles_delete(
+        &self,
+        account_id: &str,
+        permission_profile_id: &str,
+        move_users_to: &str,
+    ) -> Result<()> {
+        AccountPermissionProfiles::permission_profiles_delete(
+            self,
+            account_id,
+            permission_profile_id,
+            move_users_to,
+        )
+        .await
+    }
+    async fn permission_profiles_delete_if_exists(
+        &self,
+        account_id: &str,
+        permission_profile_id: &str,
+        move_users_to: &str,
+    ) -> Result<bool> {
+        AccountPermissionProfiles::permission_profiles_delete_if_exists(
+            self,
+            account_id,
+            permission_profile_id,
+            move_users_to,
+        )
+        .await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct AccountSealProviders {
     pub client: Client,
@@ -12,6 +10,14 @@ impl AccountSealProviders {
         AccountSealProviders { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        AccountSealProviders {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Returns available seals for specified account.
      *
@@ -29,9 +35,38 @@ impl AccountSealProviders {
     ) -> Result<crate::types::AccountSeals> {
         let url = format!(
             "/v2.1/accounts/{}/seals",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/seals",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`AccountSealProviders`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AccountSealProvidersApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AccountSealProvidersApi: Send + Sync {
+    async fn account_signature_providers_get_seal(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::AccountSeals>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AccountSealProvidersApi for AccountSealProviders {
+    async fn account_signature_providers_get_seal(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::AccountSeals> {
+        AccountSealProviders::account_signature_providers_get_seal(self, account_id).await
     }
 }
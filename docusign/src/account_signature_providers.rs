@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct AccountSignatureProviders {
     pub client: Client,
@@ -12,6 +10,14 @@ impl AccountSignatureProviders {
         AccountSignatureProviders { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        AccountSignatureProviders {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets the available signature providers for an account.
      *
@@ -29,9 +35,32 @@ impl AccountSignatureProviders {
     ) -> Result<crate::types::AccountSignatureProvidersData> {
         let url = format!(
             "/v2.1/accounts/{}/signatureProviders",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/signatureProviders",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`AccountSignatureProviders`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AccountSignatureProvidersApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AccountSignatureProvidersApi: Send + Sync {
+    async fn get(&self, account_id: &str) -> Result<crate::types::AccountSignatureProvidersData>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AccountSignatureProvidersApi for AccountSignatureProviders {
+    async fn get(&self, account_id: &str) -> Result<crate::types::AccountSignatureProvidersData> {
+        AccountSignatureProviders::get(self, account_id).await
     }
 }
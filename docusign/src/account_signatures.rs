@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct AccountSignatures {
     pub client: Client,
@@ -12,6 +10,14 @@ impl AccountSignatures {
         AccountSignatures { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        AccountSignatures {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Returns the managed signature definitions for the account.
      *
@@ -33,24 +39,31 @@ impl AccountSignatures {
         stamp_name: &str,
         stamp_type: &str,
     ) -> Result<crate::types::AccountSignaturesInformation> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !stamp_format.is_empty() {
-            query_args.push(("stamp_format".to_string(), stamp_format.to_string()));
+            query_args.push(("stamp_format", stamp_format.to_string()));
         }
         if !stamp_name.is_empty() {
-            query_args.push(("stamp_name".to_string(), stamp_name.to_string()));
+            query_args.push(("stamp_name", stamp_name.to_string()));
         }
         if !stamp_type.is_empty() {
-            query_args.push(("stamp_type".to_string(), stamp_type.to_string()));
+            query_args.push(("stamp_type", stamp_type.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/signatures?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/signatures",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/signatures",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -72,12 +85,19 @@ impl AccountSignatures {
     ) -> Result<crate::types::AccountSignaturesInformation> {
         let url = format!(
             "/v2.1/accounts/{}/signatures",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/signatures",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -99,20 +119,28 @@ impl AccountSignatures {
         decode_only: &str,
         body: &crate::types::AccountSignaturesInformation,
     ) -> Result<crate::types::AccountSignaturesInformation> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !decode_only.is_empty() {
-            query_args.push(("decode_only".to_string(), decode_only.to_string()));
+            query_args.push(("decode_only", decode_only.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/signatures?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/signatures",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/signatures",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -134,11 +162,20 @@ impl AccountSignatures {
     ) -> Result<crate::types::AccountSignature> {
         let url = format!(
             "/v2.1/accounts/{}/signatures/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&signature_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(signature_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/signatures/{signatureId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("signatureId", signature_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -161,24 +198,35 @@ impl AccountSignatures {
         close_existing_signature: &str,
         body: &crate::types::AccountSignatureDefinition,
     ) -> Result<crate::types::AccountSignature> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !close_existing_signature.is_empty() {
             query_args.push((
-                "close_existing_signature".to_string(),
+                "close_existing_signature",
                 close_existing_signature.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/signatures/{}?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&signature_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/signatures/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(signature_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/signatures/{signatureId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("signatureId", signature_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -196,11 +244,53 @@ impl AccountSignatures {
     pub async fn delete_signature(&self, account_id: &str, signature_id: &str) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/signatures/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&signature_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(signature_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/signatures/{signatureId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("signatureId", signature_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Close the specified signature by ID.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/signatures/{signatureId}` endpoint.
+     *
+     * As opposed to `delete_signature`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_signature_if_exists(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/signatures/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(signature_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/signatures/{signatureId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("signatureId", signature_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -227,20 +317,34 @@ impl AccountSignatures {
         signature_id: &str,
         include_chrome: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_chrome.is_empty() {
-            query_args.push(("include_chrome".to_string(), include_chrome.to_string()));
+            query_args.push(("include_chrome", include_chrome.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/signatures/{}/{}?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&signature_id.to_string()),
-            crate::progenitor_support::encode_path(&image_type.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/signatures/{}/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(signature_id),
+                self.client.encode_path(image_type)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client
+            .request_with_accept_mime(reqwest::Method::GET, &url, "image/gif")
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/v2.1/accounts/{accountId}/signatures/{signatureId}/{imageType}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("signatureId", signature_id.to_string()),
+                        ("imageType", image_type.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -267,20 +371,31 @@ impl AccountSignatures {
         signature_id: &str,
         transparent_png: &str,
     ) -> Result<crate::types::AccountSignature> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !transparent_png.is_empty() {
-            query_args.push(("transparent_png".to_string(), transparent_png.to_string()));
+            query_args.push(("transparent_png", transparent_png.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/signatures/{}/{}?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&signature_id.to_string()),
-            crate::progenitor_support::encode_path(&image_type.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/signatures/{}/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(signature_id),
+                self.client.encode_path(image_type)
+            ),
+            &query_args,
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "PUT",
+                "/v2.1/accounts/{accountId}/signatures/{signatureId}/{imageType}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("signatureId", signature_id.to_string()),
+                    ("imageType", image_type.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -307,11 +422,187 @@ impl AccountSignatures {
     ) -> Result<crate::types::AccountSignature> {
         let url = format!(
             "/v2.1/accounts/{}/signatures/{}/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&signature_id.to_string()),
-            crate::progenitor_support::encode_path(&image_type.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(signature_id),
+            self.client.encode_path(image_type),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/signatures/{signatureId}/{imageType}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("signatureId", signature_id.to_string()),
+                    ("imageType", image_type.to_string()),
+                ],
+            )
+        })
+    }
+}
+
+/// The functions of [`AccountSignatures`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AccountSignaturesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AccountSignaturesApi: Send + Sync {
+    async fn get(
+        &self,
+        account_id: &str,
+        stamp_format: &str,
+        stamp_name: &str,
+        stamp_type: &str,
+    ) -> Result<crate::types::AccountSignaturesInformation>;
+    async fn put_signature(
+        &self,
+        account_id: &str,
+        body: &crate::types::AccountSignaturesInformation,
+    ) -> Result<crate::types::AccountSignaturesInformation>;
+    async fn post(
+        &self,
+        account_id: &str,
+        decode_only: &str,
+        body: &crate::types::AccountSignaturesInformation,
+    ) -> Result<crate::types::AccountSignaturesInformation>;
+    async fn get_signature(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+    ) -> Result<crate::types::AccountSignature>;
+    async fn put_signature_account_signatures(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+        close_existing_signature: &str,
+        body: &crate::types::AccountSignatureDefinition,
+    ) -> Result<crate::types::AccountSignature>;
+    async fn delete_signature(&self, account_id: &str, signature_id: &str) -> Result<()>;
+    async fn delete_signature_if_exists(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+    ) -> Result<bool>;
+    async fn get_signature_image(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        include_chrome: &str,
+    ) -> Result<()>;
+    async fn put_signature_image(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        transparent_png: &str,
+    ) -> Result<crate::types::AccountSignature>;
+    async fn delete_signature_image(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+    ) -> Result<crate::types::AccountSignature>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AccountSignaturesApi for AccountSignatures {
+    async fn get(
+        &self,
+        account_id: &str,
+        stamp_format: &str,
+        stamp_name: &str,
+        stamp_type: &str,
+    ) -> Result<crate::types::AccountSignaturesInformation> {
+        AccountSignatures::get(self, account_id, stamp_format, stamp_name, stamp_type).await
+    }
+    async fn put_signature(
+        &self,
+        account_id: &str,
+        body: &crate::types::AccountSignaturesInformation,
+    ) -> Result<crate::types::AccountSignaturesInformation> {
+        AccountSignatures::put_signature(self, account_id, body).await
+    }
+    async fn post(
+        &self,
+        account_id: &str,
+        decode_only: &str,
+        body: &crate::types::AccountSignaturesInformation,
+    ) -> Result<crate::types::AccountSignaturesInformation> {
+        AccountSignatures::post(self, account_id, decode_only, body).await
+    }
+    async fn get_signature(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+    ) -> Result<crate::types::AccountSignature> {
+        AccountSignatures::get_signature(self, account_id, signature_id).await
+    }
+    async fn put_signature_account_signatures(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+        close_existing_signature: &str,
+        body: &crate::types::AccountSignatureDefinition,
+    ) -> Result<crate::types::AccountSignature> {
+        AccountSignatures::put_signature_account_signatures(
+            self,
+            account_id,
+            signature_id,
+            close_existing_signature,
+            body,
+        )
+        .await
+    }
+    async fn delete_signature(&self, account_id: &str, signature_id: &str) -> Result<()> {
+        AccountSignatures::delete_signature(self, account_id, signature_id).await
+    }
+    async fn delete_signature_if_exists(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+    ) -> Result<bool> {
+        AccountSignatures::delete_signature_if_exists(self, account_id, signature_id).await
+    }
+    async fn get_signature_image(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        include_chrome: &str,
+    ) -> Result<()> {
+        AccountSignatures::get_signature_image(
+            self,
+            account_id,
+            image_type,
+            signature_id,
+            include_chrome,
+        )
+        .await
+    }
+    async fn put_signature_image(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        transparent_png: &str,
+    ) -> Result<crate::types::AccountSignature> {
+        AccountSignatures::put_signature_image(
+            self,
+            account_id,
+            image_type,
+            signature_id,
+            transparent_png,
+        )
+        .await
+    }
+    async fn delete_signature_image(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+    ) -> Result<crate::types::AccountSignature> {
+        AccountSignatures::delete_signature_image(self, account_id, image_type, signature_id).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct AccountTabSettings {
     pub client: Client,
@@ -12,6 +10,14 @@ impl AccountTabSettings {
         AccountTabSettings { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        AccountTabSettings {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Returns tab settings list for specified account.
      *
@@ -26,10 +32,16 @@ impl AccountTabSettings {
     pub async fn tab_settings_get(&self, account_id: &str) -> Result<crate::types::TabsBlob> {
         let url = format!(
             "/v2.1/accounts/{}/settings/tabs",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/settings/tabs",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -50,11 +62,47 @@ impl AccountTabSettings {
     ) -> Result<crate::types::TabsBlob> {
         let url = format!(
             "/v2.1/accounts/{}/settings/tabs",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/settings/tabs",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`AccountTabSettings`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AccountTabSettingsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AccountTabSettingsApi: Send + Sync {
+    async fn tab_settings_get(&self, account_id: &str) -> Result<crate::types::TabsBlob>;
+    async fn tab_settings_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::TabsBlob,
+    ) -> Result<crate::types::TabsBlob>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AccountTabSettingsApi for AccountTabSettings {
+    async fn tab_settings_get(&self, account_id: &str) -> Result<crate::types::TabsBlob> {
+        AccountTabSettings::tab_settings_get(self, account_id).await
+    }
+    async fn tab_settings_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::TabsBlob,
+    ) -> Result<crate::types::TabsBlob> {
+        AccountTabSettings::tab_settings_put(self, account_id, body).await
     }
 }
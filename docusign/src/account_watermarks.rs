@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct AccountWatermarks {
     pub client: Client,
@@ -12,6 +10,14 @@ impl AccountWatermarks {
         AccountWatermarks { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        AccountWatermarks {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get watermark information.
      *
@@ -26,10 +32,16 @@ impl AccountWatermarks {
     pub async fn watermark_get(&self, account_id: &str) -> Result<crate::types::Watermark> {
         let url = format!(
             "/v2.1/accounts/{}/watermark",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/watermark",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -50,12 +62,19 @@ impl AccountWatermarks {
     ) -> Result<crate::types::Watermark> {
         let url = format!(
             "/v2.1/accounts/{}/watermark",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/watermark",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -76,11 +95,59 @@ impl AccountWatermarks {
     ) -> Result<crate::types::Watermark> {
         let url = format!(
             "/v2.1/accounts/{}/watermark/preview",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/watermark/preview",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`AccountWatermarks`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AccountWatermarksApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AccountWatermarksApi: Send + Sync {
+    async fn watermark_get(&self, account_id: &str) -> Result<crate::types::Watermark>;
+    async fn watermark_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::Watermark,
+    ) -> Result<crate::types::Watermark>;
+    async fn watermark_preview_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::Watermark,
+    ) -> Result<crate::types::Watermark>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AccountWatermarksApi for AccountWatermarks {
+    async fn watermark_get(&self, account_id: &str) -> Result<crate::types::Watermark> {
+        AccountWatermarks::watermark_get(self, account_id).await
+    }
+    async fn watermark_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::Watermark,
+    ) -> Result<crate::types::Watermark> {
+        AccountWatermarks::watermark_put(self, account_id, body).await
+    }
+    async fn watermark_preview_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::Watermark,
+    ) -> Result<crate::types::Watermark> {
+        AccountWatermarks::watermark_preview_put(self, account_id, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Accounts {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Accounts {
         Accounts { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Accounts {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Creates new accounts.
      *
@@ -148,8 +154,9 @@ impl Accounts {
     ) -> Result<crate::types::NewAccountSummary> {
         let url = "/v2.1/accounts".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/v2.1/accounts", &[]))
     }
 
     /**
@@ -161,7 +168,10 @@ impl Accounts {
      */
     pub async fn get_provisioning(&self) -> Result<crate::types::ProvisioningInformation> {
         let url = "/v2.1/accounts/provisioning".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/provisioning", &[]))
     }
 
     /**
@@ -185,21 +195,25 @@ impl Accounts {
         account_id: &str,
         include_account_settings: &str,
     ) -> Result<crate::types::AccountInformation> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_account_settings.is_empty() {
             query_args.push((
-                "include_account_settings".to_string(),
+                "include_account_settings",
                 include_account_settings.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/v2.1/accounts/{}", self.client.encode_path(account_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -214,12 +228,37 @@ impl Accounts {
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      */
     pub async fn delete(&self, account_id: &str) -> Result<()> {
-        let url = format!(
-            "/v2.1/accounts/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-        );
+        let url = format!("/v2.1/accounts/{}", self.client.encode_path(account_id),);
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
 
-        self.client.delete(&url, None).await
+    /**
+     * Deletes the specified account.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, account_id: &str) -> Result<bool> {
+        let url = format!("/v2.1/accounts/{}", self.client.encode_path(account_id),);
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -246,18 +285,25 @@ impl Accounts {
         account_id: &str,
         include_charges: &str,
     ) -> Result<crate::types::BillingChargeResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_charges.is_empty() {
-            query_args.push(("include_charges".to_string(), include_charges.to_string()));
+            query_args.push(("include_charges", include_charges.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/billing_charges?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/billing_charges",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/billing_charges",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -280,13 +326,23 @@ impl Accounts {
     ) -> Result<crate::types::CaptiveRecipientInformation> {
         let url = format!(
             "/v2.1/accounts/{}/captive_recipients/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&recipient_part.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(recipient_part),
         );
 
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .delete(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/captive_recipients/{recipientPart}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("recipientPart", recipient_part.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -306,18 +362,25 @@ impl Accounts {
         account_id: &str,
         email: &str,
     ) -> Result<crate::types::RecipientNamesResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !email.is_empty() {
-            query_args.push(("email".to_string(), email.to_string()));
+            query_args.push(("email", email.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/recipient_names?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/recipient_names",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/recipient_names",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -337,10 +400,16 @@ impl Accounts {
     ) -> Result<crate::types::AccountSettingsInformation> {
         let url = format!(
             "/v2.1/accounts/{}/settings",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/settings",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -361,12 +430,19 @@ impl Accounts {
     ) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/settings",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/settings",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -388,10 +464,16 @@ impl Accounts {
     ) -> Result<crate::types::EnvelopePurgeConfiguration> {
         let url = format!(
             "/v2.1/accounts/{}/settings/envelope_purge_configuration",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/settings/envelope_purge_configuration",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -416,12 +498,19 @@ impl Accounts {
     ) -> Result<crate::types::EnvelopePurgeConfiguration> {
         let url = format!(
             "/v2.1/accounts/{}/settings/envelope_purge_configuration",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/settings/envelope_purge_configuration",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -441,10 +530,16 @@ impl Accounts {
     ) -> Result<crate::types::NotificationDefaultsData> {
         let url = format!(
             "/v2.1/accounts/{}/settings/notification_defaults",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/settings/notification_defaults",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -465,12 +560,19 @@ impl Accounts {
     ) -> Result<crate::types::NotificationDefaultsData> {
         let url = format!(
             "/v2.1/accounts/{}/settings/notification_defaults",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/settings/notification_defaults",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -526,42 +628,49 @@ impl Accounts {
         start_position: &str,
         user_ids: &str,
     ) -> Result<crate::types::AccountSharedAccess> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !envelopes_not_shared_user_status.is_empty() {
             query_args.push((
-                "envelopes_not_shared_user_status".to_string(),
+                "envelopes_not_shared_user_status",
                 envelopes_not_shared_user_status.to_string(),
             ));
         }
         if !folder_ids.is_empty() {
-            query_args.push(("folder_ids".to_string(), folder_ids.to_string()));
+            query_args.push(("folder_ids", folder_ids.to_string()));
         }
         if !item_type.is_empty() {
-            query_args.push(("item_type".to_string(), item_type.to_string()));
+            query_args.push(("item_type", item_type.to_string()));
         }
         if !search_text.is_empty() {
-            query_args.push(("search_text".to_string(), search_text.to_string()));
+            query_args.push(("search_text", search_text.to_string()));
         }
         if !shared.is_empty() {
-            query_args.push(("shared".to_string(), shared.to_string()));
+            query_args.push(("shared", shared.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
         if !user_ids.is_empty() {
-            query_args.push(("user_ids".to_string(), user_ids.to_string()));
+            query_args.push(("user_ids", user_ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/shared_access?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/shared_access",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/shared_access",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -602,29 +711,37 @@ impl Accounts {
         user_ids: &str,
         body: &crate::types::AccountSharedAccess,
     ) -> Result<crate::types::AccountSharedAccess> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !item_type.is_empty() {
-            query_args.push(("item_type".to_string(), item_type.to_string()));
+            query_args.push(("item_type", item_type.to_string()));
         }
         if !preserve_existing_shared_access.is_empty() {
             query_args.push((
-                "preserve_existing_shared_access".to_string(),
+                "preserve_existing_shared_access",
                 preserve_existing_shared_access.to_string(),
             ));
         }
         if !user_ids.is_empty() {
-            query_args.push(("user_ids".to_string(), user_ids.to_string()));
+            query_args.push(("user_ids", user_ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/shared_access?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/shared_access",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/shared_access",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -650,10 +767,16 @@ impl Accounts {
     ) -> Result<crate::types::SupportedLanguages> {
         let url = format!(
             "/v2.1/accounts/{}/supported_languages",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/supported_languages",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -673,10 +796,16 @@ impl Accounts {
     ) -> Result<crate::types::FileTypeList> {
         let url = format!(
             "/v2.1/accounts/{}/unsupported_file_types",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/unsupported_file_types",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -698,10 +827,266 @@ impl Accounts {
     ) -> Result<()> {
         let url = format!(
             "/v2.1/organization_exports/{}/account_settings/{}",
-            crate::progenitor_support::encode_path(&organization_id.to_string()),
-            crate::progenitor_support::encode_path(&result_id.to_string()),
+            self.client.encode_path(organization_id),
+            self.client.encode_path(result_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/organization_exports/{organizationId}/account_settings/{resultId}",
+                &[
+                    ("organizationId", organization_id.to_string()),
+                    ("resultId", result_id.to_string()),
+                ],
+            )
+        })
+    }
+}
+
+/// The functions of [`Accounts`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AccountsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AccountsApi: Send + Sync {
+    async fn post(
+        &self,
+        body: &crate::types::NewAccountDefinition,
+    ) -> Result<crate::types::NewAccountSummary>;
+    async fn get_provisioning(&self) -> Result<crate::types::ProvisioningInformation>;
+    async fn get(
+        &self,
+        account_id: &str,
+        include_account_settings: &str,
+    ) -> Result<crate::types::AccountInformation>;
+    async fn delete(&self, account_id: &str) -> Result<()>;
+    async fn delete_if_exists(&self, account_id: &str) -> Result<bool>;
+    async fn billing_charges_get(
+        &self,
+        account_id: &str,
+        include_charges: &str,
+    ) -> Result<crate::types::BillingChargeResponse>;
+    async fn captive_recipients_delete_part(
+        &self,
+        account_id: &str,
+        recipient_part: &str,
+        body: &crate::types::CaptiveRecipientInformation,
+    ) -> Result<crate::types::CaptiveRecipientInformation>;
+    async fn recipient_names_get(
+        &self,
+        account_id: &str,
+        email: &str,
+    ) -> Result<crate::types::RecipientNamesResponse>;
+    async fn settings_get(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::AccountSettingsInformation>;
+    async fn settings_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::AccountSettingsInformation,
+    ) -> Result<()>;
+    async fn envelope_purge_configuration_get(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::EnvelopePurgeConfiguration>;
+    async fn envelope_purge_configuration_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::EnvelopePurgeConfiguration,
+    ) -> Result<crate::types::EnvelopePurgeConfiguration>;
+    async fn notification_defaults_get(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::NotificationDefaultsData>;
+    async fn notification_defaults_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::NotificationDefaultsData,
+    ) -> Result<crate::types::NotificationDefaultsData>;
+    async fn shared_access_get(
+        &self,
+        account_id: &str,
+        count: &str,
+        envelopes_not_shared_user_status: &str,
+        folder_ids: &str,
+        item_type: &str,
+        search_text: &str,
+        shared: &str,
+        start_position: &str,
+        user_ids: &str,
+    ) -> Result<crate::types::AccountSharedAccess>;
+    async fn shared_access_put(
+        &self,
+        account_id: &str,
+        item_type: &str,
+        preserve_existing_shared_access: &str,
+        user_ids: &str,
+        body: &crate::types::AccountSharedAccess,
+    ) -> Result<crate::types::AccountSharedAccess>;
+    async fn supported_languages_get(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::SupportedLanguages>;
+    async fn unsupported_file_types_get(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::FileTypeList>;
+    async fn organization_exports_get_settings_export(
+        &self,
+        organization_id: &str,
+        result_id: &str,
+    ) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AccountsApi for Accounts {
+    async fn post(
+        &self,
+        body: &crate::types::NewAccountDefinition,
+    ) -> Result<crate::types::NewAccountSummary> {
+        Accounts::post(self, body).await
+    }
+    async fn get_provisioning(&self) -> Result<crate::types::ProvisioningInformation> {
+        Accounts::get_provisioning(self).await
+    }
+    async fn get(
+        &self,
+        account_id: &str,
+        include_account_settings: &str,
+    ) -> Result<crate::types::AccountInformation> {
+        Accounts::get(self, account_id, include_account_settings).await
+    }
+    async fn delete(&self, account_id: &str) -> Result<()> {
+        Accounts::delete(self, account_id).await
+    }
+    async fn delete_if_exists(&self, account_id: &str) -> Result<bool> {
+        Accounts::delete_if_exists(self, account_id).await
+    }
+    async fn billing_charges_get(
+        &self,
+        account_id: &str,
+        include_charges: &str,
+    ) -> Result<crate::types::BillingChargeResponse> {
+        Accounts::billing_charges_get(self, account_id, include_charges).await
+    }
+    async fn captive_recipients_delete_part(
+        &self,
+        account_id: &str,
+        recipient_part: &str,
+        body: &crate::types::CaptiveRecipientInformation,
+    ) -> Result<crate::types::CaptiveRecipientInformation> {
+        Accounts::captive_recipients_delete_part(self, account_id, recipient_part, body).await
+    }
+    async fn recipient_names_get(
+        &self,
+        account_id: &str,
+        email: &str,
+    ) -> Result<crate::types::RecipientNamesResponse> {
+        Accounts::recipient_names_get(self, account_id, email).await
+    }
+    async fn settings_get(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::AccountSettingsInformation> {
+        Accounts::settings_get(self, account_id).await
+    }
+    async fn settings_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::AccountSettingsInformation,
+    ) -> Result<()> {
+        Accounts::settings_put(self, account_id, body).await
+    }
+    async fn envelope_purge_configuration_get(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::EnvelopePurgeConfiguration> {
+        Accounts::envelope_purge_configuration_get(self, account_id).await
+    }
+    async fn envelope_purge_configuration_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::EnvelopePurgeConfiguration,
+    ) -> Result<crate::types::EnvelopePurgeConfiguration> {
+        Accounts::envelope_purge_configuration_put(self, account_id, body).await
+    }
+    async fn notification_defaults_get(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::NotificationDefaultsData> {
+        Accounts::notification_defaults_get(self, account_id).await
+    }
+    async fn notification_defaults_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::NotificationDefaultsData,
+    ) -> Result<crate::types::NotificationDefaultsData> {
+        Accounts::notification_defaults_put(self, account_id, body).await
+    }
+    async fn shared_access_get(
+        &self,
+        account_id: &str,
+        count: &str,
+        envelopes_not_shared_user_status: &str,
+        folder_ids: &str,
+        item_type: &str,
+        search_text: &str,
+        shared: &str,
+        start_position: &str,
+        user_ids: &str,
+    ) -> Result<crate::types::AccountSharedAccess> {
+        Accounts::shared_access_get(
+            self,
+            account_id,
+            count,
+            envelopes_not_shared_user_status,
+            folder_ids,
+            item_type,
+            search_text,
+            shared,
+            start_position,
+            user_ids,
+        )
+        .await
+    }
+    async fn shared_access_put(
+        &self,
+        account_id: &str,
+        item_type: &str,
+        preserve_existing_shared_access: &str,
+        user_ids: &str,
+        body: &crate::types::AccountSharedAccess,
+    ) -> Result<crate::types::AccountSharedAccess> {
+        Accounts::shared_access_put(
+            self,
+            account_id,
+            item_type,
+            preserve_existing_shared_access,
+            user_ids,
+            body,
+        )
+        .await
+    }
+    async fn supported_languages_get(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::SupportedLanguages> {
+        Accounts::supported_languages_get(self, account_id).await
+    }
+    async fn unsupported_file_types_get(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::FileTypeList> {
+        Accounts::unsupported_file_types_get(self, account_id).await
+    }
+    async fn organization_exports_get_settings_export(
+        &self,
+        organization_id: &str,
+        result_id: &str,
+    ) -> Result<()> {
+        Accounts::organization_exports_get_settings_export(self, organization_id, result_id).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct ApplianceInfo {
     pub client: Client,
@@ -12,6 +10,14 @@ impl ApplianceInfo {
         ApplianceInfo { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        ApplianceInfo {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Returns document pages for Display Appliance.
      *
@@ -29,10 +35,16 @@ impl ApplianceInfo {
     ) -> Result<crate::types::ApplianceInfo> {
         let url = format!(
             "/v2.1/accounts/{}/display_appliance_info/dynamicsystemsettings",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/display_appliance_info/dynamicsystemsettings",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -52,10 +64,16 @@ impl ApplianceInfo {
     ) -> Result<crate::types::ApplianceInfo> {
         let url = format!(
             "/v2.1/accounts/{}/display_appliance_info/templateInfo",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/v2.1/accounts/{accountId}/display_appliance_info/templateInfo",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -77,11 +95,20 @@ impl ApplianceInfo {
     ) -> Result<crate::types::ApplianceInfo> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -103,11 +130,11 @@ impl ApplianceInfo {
     ) -> Result<crate::types::DisplayApplianceAccount> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/account_info",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/account_info", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string())]))
     }
 
     /**
@@ -129,11 +156,11 @@ impl ApplianceInfo {
     ) -> Result<crate::types::ApplianceInfo> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/custom_fields",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/custom_fields", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string())]))
     }
 
     /**
@@ -155,11 +182,11 @@ impl ApplianceInfo {
     ) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/custom_fields/delete",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| crate::utils::pretty_url("POST", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/custom_fields/delete", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string())]))
     }
 
     /**
@@ -181,11 +208,11 @@ impl ApplianceInfo {
     ) -> Result<crate::types::ApplianceInfo> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/date_signed",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/date_signed", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string())]))
     }
 
     /**
@@ -209,12 +236,12 @@ impl ApplianceInfo {
     ) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/document/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            crate::progenitor_support::encode_path(&document_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(document_id),
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| crate::utils::pretty_url("PUT", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/document/{documentId}", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("documentId", document_id.to_string())]))
     }
 
     /**
@@ -238,12 +265,35 @@ impl ApplianceInfo {
     ) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/document/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            crate::progenitor_support::encode_path(&document_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(document_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/document/{documentId}", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("documentId", document_id.to_string())]))
+    }
+
+    /**
+     * Deletes document information for Display Appliance.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/document/{documentId}` endpoint.
+     *
+     * As opposed to `envelope_delete_document`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn envelope_delete_document_if_exists(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/document/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(document_id),
+        );
+
+        self.client.delete_if_exists(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/document/{documentId}", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("documentId", document_id.to_string())]))
     }
 
     /**
@@ -265,11 +315,11 @@ impl ApplianceInfo {
     ) -> Result<crate::types::ApplianceInfo> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/document_page_list",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/document_page_list", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string())]))
     }
 
     /**
@@ -291,11 +341,20 @@ impl ApplianceInfo {
     ) -> Result<crate::types::ApplianceInfo> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/image",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/image",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -320,12 +379,12 @@ impl ApplianceInfo {
     ) -> Result<crate::types::ApplianceInfo> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/localepolicy/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(user_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| crate::utils::pretty_url("POST", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/localepolicy/{userId}", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("userId", user_id.to_string())]))
     }
 
     /**
@@ -343,11 +402,11 @@ impl ApplianceInfo {
     pub async fn envelope_put_page(&self, account_id: &str, envelope_id: &str) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/page_info",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| crate::utils::pretty_url("PUT", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/page_info", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string())]))
     }
 
     /**
@@ -365,11 +424,11 @@ impl ApplianceInfo {
     pub async fn envelope_post_page(&self, account_id: &str, envelope_id: &str) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/page_info",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| crate::utils::pretty_url("POST", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/page_info", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string())]))
     }
 
     /**
@@ -387,11 +446,11 @@ impl ApplianceInfo {
     pub async fn envelope_delete_page(&self, account_id: &str, envelope_id: &str) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/page_info/delete",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| crate::utils::pretty_url("POST", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/page_info/delete", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string())]))
     }
 
     /**
@@ -413,11 +472,20 @@ impl ApplianceInfo {
     ) -> Result<crate::types::ApplianceInfo> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/pdf",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "PUT",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/pdf",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -443,12 +511,12 @@ impl ApplianceInfo {
     ) -> Result<crate::types::ApplianceInfo> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/pdf/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            crate::progenitor_support::encode_path(&pdf_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(pdf_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/pdf/{pdfId}", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("pdfId", pdf_id.to_string())]))
     }
 
     /**
@@ -470,11 +538,11 @@ impl ApplianceInfo {
     ) -> Result<crate::types::ApplianceInfo> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/pdf_blobs",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/pdf_blobs", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string())]))
     }
 
     /**
@@ -492,11 +560,11 @@ impl ApplianceInfo {
     pub async fn envelope_put_pdf_blob(&self, account_id: &str, envelope_id: &str) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/pdf_blobs",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| crate::utils::pretty_url("PUT", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/pdf_blobs", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string())]))
     }
 
     /**
@@ -518,11 +586,11 @@ impl ApplianceInfo {
     ) -> Result<crate::types::ApplianceInfo> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/pdf_blobs",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| crate::utils::pretty_url("POST", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/pdf_blobs", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string())]))
     }
 
     /**
@@ -544,11 +612,11 @@ impl ApplianceInfo {
     ) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/recipient_denied_copy",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| crate::utils::pretty_url("PUT", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/recipient_denied_copy", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string())]))
     }
 
     /**
@@ -570,11 +638,32 @@ impl ApplianceInfo {
     ) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/recipient_denied_copy",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/recipient_denied_copy", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string())]))
+    }
+
+    /**
+     * Deletes RecipientDeniedDocumentCopy for Display Appliance.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/recipient_denied_copy` endpoint.
+     *
+     * As opposed to `envelope_delete_recipient_denied_document_copy`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn envelope_delete_recipient_denied_document_copy_if_exists(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/recipient_denied_copy",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client.delete_if_exists(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/recipient_denied_copy", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string())]))
     }
 
     /**
@@ -596,11 +685,11 @@ impl ApplianceInfo {
     ) -> Result<crate::types::ApplianceInfo> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/signer_attachment_info",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/signer_attachment_info", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string())]))
     }
 
     /**
@@ -622,11 +711,32 @@ impl ApplianceInfo {
     ) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/signer_attachment_info",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/signer_attachment_info", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string())]))
+    }
+
+    /**
+     * Deletes signer attachment information for Display Appliance.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/signer_attachment_info` endpoint.
+     *
+     * As opposed to `envelope_delete_signer_attachment`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn envelope_delete_signer_attachment_if_exists(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/signer_attachment_info",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete_if_exists(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/signer_attachment_info", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string())]))
     }
 
     /**
@@ -638,7 +748,9 @@ impl ApplianceInfo {
      */
     pub async fn envelope_post_error(&self) -> Result<()> {
         let url = "/v2.1/display_appliance_info/error".to_string();
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("POST", "/v2.1/display_appliance_info/error", &[])
+        })
     }
 
     /**
@@ -650,6 +762,335 @@ impl ApplianceInfo {
      */
     pub async fn envelope_post_redeem(&self) -> Result<crate::types::ApplianceInfo> {
         let url = "/v2.1/display_appliance_info/redeem".to_string();
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("POST", "/v2.1/display_appliance_info/redeem", &[])
+        })
+    }
+}
+
+/// The functions of [`ApplianceInfo`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ApplianceInfoApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ApplianceInfoApi: Send + Sync {
+    async fn envelope_get_dynamic_system_setting(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::ApplianceInfo>;
+    async fn envelope_get_template(&self, account_id: &str) -> Result<crate::types::ApplianceInfo>;
+    async fn envelope_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::ApplianceInfo>;
+    async fn envelope_get_account(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::DisplayApplianceAccount>;
+    async fn envelope_get_custom_field(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::ApplianceInfo>;
+    async fn envelope_delete_custom_fields(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<()>;
+    async fn envelope_get_date_signed(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::ApplianceInfo>;
+    async fn envelope_put_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> Result<()>;
+    async fn envelope_delete_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> Result<()>;
+    async fn envelope_delete_document_if_exists(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> Result<bool>;
+    async fn envelope_get_document_page(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::ApplianceInfo>;
+    async fn envelope_get_image(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::ApplianceInfo>;
+    async fn envelope_get_locale_policy(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        user_id: &str,
+    ) -> Result<crate::types::ApplianceInfo>;
+    async fn envelope_put_page(&self, account_id: &str, envelope_id: &str) -> Result<()>;
+    async fn envelope_post_page(&self, account_id: &str, envelope_id: &str) -> Result<()>;
+    async fn envelope_delete_page(&self, account_id: &str, envelope_id: &str) -> Result<()>;
+    async fn envelope_put_pdf(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::ApplianceInfo>;
+    async fn envelope_get_pdf(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        pdf_id: &str,
+    ) -> Result<crate::types::ApplianceInfo>;
+    async fn envelope_get_pdf_blob(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::ApplianceInfo>;
+    async fn envelope_put_pdf_blob(&self, account_id: &str, envelope_id: &str) -> Result<()>;
+    async fn envelope_post_pdf_blob(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::ApplianceInfo>;
+    async fn envelope_put_recipient_denied_document_copy(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<()>;
+    async fn envelope_delete_recipient_denied_document_copy(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<()>;
+    async fn envelope_delete_recipient_denied_document_copy_if_exists(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<bool>;
+    async fn envelope_get_signer_attachment(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::ApplianceInfo>;
+    async fn envelope_delete_signer_attachment(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<()>;
+    async fn envelope_delete_signer_attachment_if_exists(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<bool>;
+    async fn envelope_post_error(&self) -> Result<()>;
+    async fn envelope_post_redeem(&self) -> Result<crate::types::ApplianceInfo>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ApplianceInfoApi for ApplianceInfo {
+    async fn envelope_get_dynamic_system_setting(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::ApplianceInfo> {
+        ApplianceInfo::envelope_get_dynamic_system_setting(self, account_id).await
+    }
+    async fn envelope_get_template(&self, account_id: &str) -> Result<crate::types::ApplianceInfo> {
+        ApplianceInfo::envelope_get_template(self, account_id).await
+    }
+    async fn envelope_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::ApplianceInfo> {
+        ApplianceInfo::envelope_get(self, account_id, envelope_id).await
+    }
+    async fn envelope_get_account(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::DisplayApplianceAccount> {
+        ApplianceInfo::envelope_get_account(self, account_id, envelope_id).await
+    }
+    async fn envelope_get_custom_field(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::ApplianceInfo> {
+        ApplianceInfo::envelope_get_custom_field(self, account_id, envelope_id).await
+    }
+    async fn envelope_delete_custom_fields(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<()> {
+        ApplianceInfo::envelope_delete_custom_fields(self, account_id, envelope_id).await
+    }
+    async fn envelope_get_date_signed(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::ApplianceInfo> {
+        ApplianceInfo::envelope_get_date_signed(self, account_id, envelope_id).await
+    }
+    async fn envelope_put_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> Result<()> {
+        ApplianceInfo::envelope_put_document(self, account_id, document_id, envelope_id).await
+    }
+    async fn envelope_delete_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> Result<()> {
+        ApplianceInfo::envelope_delete_document(self, account_id, document_id, envelope_id).await
+    }
+    async fn envelope_delete_document_if_exists(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> Result<bool> {
+        ApplianceInfo::envelope_delete_document_if_exists(
+            self,
+            account_id,
+            document_id,
+            envelope_id,
+        )
+        .await
+    }
+    async fn envelope_get_document_page(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::ApplianceInfo> {
+        ApplianceInfo::envelope_get_document_page(self, account_id, envelope_id).await
+    }
+    async fn envelope_get_image(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::ApplianceInfo> {
+        ApplianceInfo::envelope_get_image(self, account_id, envelope_id).await
+    }
+    async fn envelope_get_locale_policy(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        user_id: &str,
+    ) -> Result<crate::types::ApplianceInfo> {
+        ApplianceInfo::envelope_get_locale_policy(self, account_id, envelope_id, user_id).await
+    }
+    async fn envelope_put_page(&self, account_id: &str, envelope_id: &str) -> Result<()> {
+        ApplianceInfo::envelope_put_page(self, account_id, envelope_id).await
+    }
+    async fn envelope_post_page(&self, account_id: &str, envelope_id: &str) -> Result<()> {
+        ApplianceInfo::envelope_post_page(self, account_id, envelope_id).await
+    }
+    async fn envelope_delete_page(&self, account_id: &str, envelope_id: &str) -> Result<()> {
+        ApplianceInfo::envelope_delete_page(self, account_id, envelope_id).await
+    }
+    async fn envelope_put_pdf(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::ApplianceInfo> {
+        ApplianceInfo::envelope_put_pdf(self, account_id, envelope_id).await
+    }
+    async fn envelope_get_pdf(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        pdf_id: &str,
+    ) -> Result<crate::types::ApplianceInfo> {
+        ApplianceInfo::envelope_get_pdf(self, account_id, envelope_id, pdf_id).await
+    }
+    async fn envelope_get_pdf_blob(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::ApplianceInfo> {
+        ApplianceInfo::envelope_get_pdf_blob(self, account_id, envelope_id).await
+    }
+    async fn envelope_put_pdf_blob(&self, account_id: &str, envelope_id: &str) -> Result<()> {
+        ApplianceInfo::envelope_put_pdf_blob(self, account_id, envelope_id).await
+    }
+    async fn envelope_post_pdf_blob(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::ApplianceInfo> {
+        ApplianceInfo::envelope_post_pdf_blob(self, account_id, envelope_id).await
+    }
+    async fn envelope_put_recipient_denied_document_copy(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<()> {
+        ApplianceInfo::envelope_put_recipient_denied_document_copy(self, account_id, envelope_id)
+            .await
+    }
+    async fn envelope_delete_recipient_denied_document_copy(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<()> {
+        ApplianceInfo::envelope_delete_recipient_denied_document_copy(self, account_id, envelope_id)
+            .await
+    }
+    async fn envelope_delete_recipient_denied_document_copy_if_exists(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<bool> {
+        ApplianceInfo::envelope_delete_recipient_denied_document_copy_if_exists(
+            self,
+            account_id,
+            envelope_id,
+        )
+        .await
+    }
+    async fn envelope_get_signer_attachment(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::ApplianceInfo> {
+        ApplianceInfo::envelope_get_signer_attachment(self, account_id, envelope_id).await
+    }
+    async fn envelope_delete_signer_attachment(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<()> {
+        ApplianceInfo::envelope_delete_signer_attachment(self, account_id, envelope_id).await
+    }
+    async fn envelope_delete_signer_attachment_if_exists(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<bool> {
+        ApplianceInfo::envelope_delete_signer_attachment_if_exists(self, account_id, envelope_id)
+            .await
+    }
+    async fn envelope_post_error(&self) -> Result<()> {
+        ApplianceInfo::envelope_post_error(self).await
+    }
+    async fn envelope_post_redeem(&self) -> Result<crate::types::ApplianceInfo> {
+        ApplianceInfo::envelope_post_redeem(self).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct BccEmailArchive {
     pub client: Client,
@@ -12,6 +10,14 @@ impl BccEmailArchive {
         BccEmailArchive { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        BccEmailArchive {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets the BCC email archive configurations for an account.
      *
@@ -31,21 +37,28 @@ impl BccEmailArchive {
         count: &str,
         start_position: &str,
     ) -> Result<crate::types::BccEmailArchiveList> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/settings/bcc_email_archives?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/settings/bcc_email_archives",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/settings/bcc_email_archives",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -71,12 +84,19 @@ impl BccEmailArchive {
     ) -> Result<crate::types::BccEmailArchiveData> {
         let url = format!(
             "/v2.1/accounts/{}/settings/bcc_email_archives",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/settings/bcc_email_archives",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -100,22 +120,32 @@ impl BccEmailArchive {
         count: &str,
         start_position: &str,
     ) -> Result<crate::types::BccEmailArchiveHistoryList> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/settings/bcc_email_archives/{}?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&bcc_email_archive_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/settings/bcc_email_archives/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(bcc_email_archive_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/settings/bcc_email_archives/{bccEmailArchiveId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("bccEmailArchiveId", bcc_email_archive_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -136,10 +166,122 @@ impl BccEmailArchive {
     pub async fn delete(&self, account_id: &str, bcc_email_archive_id: &str) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/settings/bcc_email_archives/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&bcc_email_archive_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(bcc_email_archive_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/settings/bcc_email_archives/{bccEmailArchiveId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("bccEmailArchiveId", bcc_email_archive_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Deletes a BCC email archive configuration.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/settings/bcc_email_archives/{bccEmailArchiveId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(
+        &self,
+        account_id: &str,
+        bcc_email_archive_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/settings/bcc_email_archives/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(bcc_email_archive_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/settings/bcc_email_archives/{bccEmailArchiveId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("bccEmailArchiveId", bcc_email_archive_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`BccEmailArchive`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn BccEmailArchiveApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait BccEmailArchiveApi: Send + Sync {
+    async fn get_list(
+        &self,
+        account_id: &str,
+        count: &str,
+        start_position: &str,
+    ) -> Result<crate::types::BccEmailArchiveList>;
+    async fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::BccEmailArchiveData,
+    ) -> Result<crate::types::BccEmailArchiveData>;
+    async fn get_history_list(
+        &self,
+        account_id: &str,
+        bcc_email_archive_id: &str,
+        count: &str,
+        start_position: &str,
+    ) -> Result<crate::types::BccEmailArchiveHistoryList>;
+    async fn delete(&self, account_id: &str, bcc_email_archive_id: &str) -> Result<()>;
+    async fn delete_if_exists(&self, account_id: &str, bcc_email_archive_id: &str) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl BccEmailArchiveApi for BccEmailArchive {
+    async fn get_list(
+        &self,
+        account_id: &str,
+        count: &str,
+        start_position: &str,
+    ) -> Result<crate::types::BccEmailArchiveList> {
+        BccEmailArchive::get_list(self, account_id, count, start_position).await
+    }
+    async fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::BccEmailArchiveData,
+    ) -> Result<crate::types::BccEmailArchiveData> {
+        BccEmailArchive::post(self, account_id, body).await
+    }
+    async fn get_history_list(
+        &self,
+        account_id: &str,
+        bcc_email_archive_id: &str,
+        count: &str,
+        start_position: &str,
+    ) -> Result<crate::types::BccEmailArchiveHistoryList> {
+        BccEmailArchive::get_history_list(
+            self,
+            account_id,
+            bcc_email_archive_id,
+            count,
+            start_position,
+        )
+        .await
+    }
+    async fn delete(&self, account_id: &str, bcc_email_archive_id: &str) -> Result<()> {
+        BccEmailArchive::delete(self, account_id, bcc_email_archive_id).await
+    }
+    async fn delete_if_exists(&self, account_id: &str, bcc_email_archive_id: &str) -> Result<bool> {
+        BccEmailArchive::delete_if_exists(self, account_id, bcc_email_archive_id).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct BillingPlans {
     pub client: Client,
@@ -12,6 +10,14 @@ impl BillingPlans {
         BillingPlans { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        BillingPlans {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get Account Billing Plan.
      *
@@ -42,30 +48,37 @@ impl BillingPlans {
         include_metadata: &str,
         include_successor_plans: &str,
     ) -> Result<crate::types::AccountBillingPlanResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_credit_card_information.is_empty() {
             query_args.push((
-                "include_credit_card_information".to_string(),
+                "include_credit_card_information",
                 include_credit_card_information.to_string(),
             ));
         }
         if !include_metadata.is_empty() {
-            query_args.push(("include_metadata".to_string(), include_metadata.to_string()));
+            query_args.push(("include_metadata", include_metadata.to_string()));
         }
         if !include_successor_plans.is_empty() {
             query_args.push((
-                "include_successor_plans".to_string(),
+                "include_successor_plans",
                 include_successor_plans.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/billing_plan?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/billing_plan",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/billing_plan",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -86,23 +99,28 @@ impl BillingPlans {
         preview_billing_plan: &str,
         body: &crate::types::BillingPlanInformation,
     ) -> Result<crate::types::BillingPlanUpdateResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !preview_billing_plan.is_empty() {
-            query_args.push((
-                "preview_billing_plan".to_string(),
-                preview_billing_plan.to_string(),
-            ));
+            query_args.push(("preview_billing_plan", preview_billing_plan.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/billing_plan?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/billing_plan",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/billing_plan",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -122,10 +140,16 @@ impl BillingPlans {
     ) -> Result<crate::types::CreditCardInformation> {
         let url = format!(
             "/v2.1/accounts/{}/billing_plan/credit_card",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/billing_plan/credit_card",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -145,10 +169,16 @@ impl BillingPlans {
     ) -> Result<crate::types::DowngradRequestBillingInfoResponse> {
         let url = format!(
             "/v2.1/accounts/{}/billing_plan/downgrade",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/billing_plan/downgrade",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -169,12 +199,19 @@ impl BillingPlans {
     ) -> Result<crate::types::DowngradePlanUpdateResponse> {
         let url = format!(
             "/v2.1/accounts/{}/billing_plan/downgrade",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/billing_plan/downgrade",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -195,12 +232,19 @@ impl BillingPlans {
     ) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/billing_plan/purchased_envelopes",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/billing_plan/purchased_envelopes",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -212,7 +256,10 @@ impl BillingPlans {
      */
     pub async fn get_billing_plans(&self) -> Result<crate::types::BillingPlansResponse> {
         let url = "/v2.1/billing_plans".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/v2.1/billing_plans", &[]))
     }
 
     /**
@@ -232,9 +279,117 @@ impl BillingPlans {
     ) -> Result<crate::types::BillingPlanResponse> {
         let url = format!(
             "/v2.1/billing_plans/{}",
-            crate::progenitor_support::encode_path(&billing_plan_id.to_string()),
+            self.client.encode_path(billing_plan_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/billing_plans/{billingPlanId}",
+                &[("billingPlanId", billing_plan_id.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`BillingPlans`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn BillingPlansApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait BillingPlansApi: Send + Sync {
+    async fn get(
+        &self,
+        account_id: &str,
+        include_credit_card_information: &str,
+        include_metadata: &str,
+        include_successor_plans: &str,
+    ) -> Result<crate::types::AccountBillingPlanResponse>;
+    async fn put(
+        &self,
+        account_id: &str,
+        preview_billing_plan: &str,
+        body: &crate::types::BillingPlanInformation,
+    ) -> Result<crate::types::BillingPlanUpdateResponse>;
+    async fn get_credit_card_info(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::CreditCardInformation>;
+    async fn get_downgrade_request_info(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::DowngradRequestBillingInfoResponse>;
+    async fn put_downgrade_account(
+        &self,
+        account_id: &str,
+        body: &crate::types::DowngradeBillingPlanInformation,
+    ) -> Result<crate::types::DowngradePlanUpdateResponse>;
+    async fn purchased_envelopes_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::PurchasedEnvelopesInformation,
+    ) -> Result<()>;
+    async fn get_billing_plans(&self) -> Result<crate::types::BillingPlansResponse>;
+    async fn get_plan(&self, billing_plan_id: &str) -> Result<crate::types::BillingPlanResponse>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl BillingPlansApi for BillingPlans {
+    async fn get(
+        &self,
+        account_id: &str,
+        include_credit_card_information: &str,
+        include_metadata: &str,
+        include_successor_plans: &str,
+    ) -> Result<crate::types::AccountBillingPlanResponse> {
+        BillingPlans::get(
+            self,
+            account_id,
+            include_credit_card_information,
+            include_metadata,
+            include_successor_plans,
+        )
+        .await
+    }
+    async fn put(
+        &self,
+        account_id: &str,
+        preview_billing_plan: &str,
+        body: &crate::types::BillingPlanInformation,
+    ) -> Result<crate::types::BillingPlanUpdateResponse> {
+        BillingPlans::put(self, account_id, preview_billing_plan, body).await
+    }
+    async fn get_credit_card_info(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::CreditCardInformation> {
+        BillingPlans::get_credit_card_info(self, account_id).await
+    }
+    async fn get_downgrade_request_info(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::DowngradRequestBillingInfoResponse> {
+        BillingPlans::get_downgrade_request_info(self, account_id).await
+    }
+    async fn put_downgrade_account(
+        &self,
+        account_id: &str,
+        body: &crate::types::DowngradeBillingPlanInformation,
+    ) -> Result<crate::types::DowngradePlanUpdateResponse> {
+        BillingPlans::put_downgrade_account(self, account_id, body).await
+    }
+    async fn purchased_envelopes_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::PurchasedEnvelopesInformation,
+    ) -> Result<()> {
+        BillingPlans::purchased_envelopes_put(self, account_id, body).await
+    }
+    async fn get_billing_plans(&self) -> Result<crate::types::BillingPlansResponse> {
+        BillingPlans::get_billing_plans(self).await
+    }
+    async fn get_plan(&self, billing_plan_id: &str) -> Result<crate::types::BillingPlanResponse> {
+        BillingPlans::get_plan(self, billing_plan_id).await
     }
 }
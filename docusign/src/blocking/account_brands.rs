@@ -0,0 +1,425 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct AccountBrands {
+    pub client: Client,
+}
+
+impl AccountBrands {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        AccountBrands { client }
+    }
+
+    /// The blocking form of [`crate::account_brands::AccountBrands::brands_get`].
+    pub fn brands_get(
+        &self,
+        account_id: &str,
+        exclude_distributor_brand: &str,
+        include_logos: &str,
+    ) -> Result<crate::types::AccountBrands> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !exclude_distributor_brand.is_empty() {
+            query_args.push((
+                "exclude_distributor_brand",
+                exclude_distributor_brand.to_string(),
+            ));
+        }
+        if !include_logos.is_empty() {
+            query_args.push(("include_logos", include_logos.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/brands",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/brands",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::account_brands::AccountBrands::brands_post`].
+    pub fn brands_post(
+        &self,
+        account_id: &str,
+        body: &crate::types::Brand,
+    ) -> Result<crate::types::AccountBrands> {
+        let url = format!(
+            "/v2.1/accounts/{}/brands",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/brands",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::account_brands::AccountBrands::brands_delete`].
+    pub fn brands_delete(
+        &self,
+        account_id: &str,
+        body: &crate::types::BrandsRequest,
+    ) -> Result<crate::types::AccountBrands> {
+        let url = format!(
+            "/v2.1/accounts/{}/brands",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/brands",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::account_brands::AccountBrands::brand_get`].
+    pub fn brand_get(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        include_external_references: &str,
+        include_logos: &str,
+    ) -> Result<crate::types::Brand> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !include_external_references.is_empty() {
+            query_args.push((
+                "include_external_references",
+                include_external_references.to_string(),
+            ));
+        }
+        if !include_logos.is_empty() {
+            query_args.push(("include_logos", include_logos.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/brands/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(brand_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/brands/{brandId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("brandId", brand_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::account_brands::AccountBrands::brand_put`].
+    pub fn brand_put(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        body: &crate::types::Brand,
+    ) -> Result<crate::types::Brand> {
+        let url = format!(
+            "/v2.1/accounts/{}/brands/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(brand_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/brands/{brandId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("brandId", brand_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::account_brands::AccountBrands::brand_delete`].
+    pub fn brand_delete(&self, account_id: &str, brand_id: &str) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/brands/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(brand_id),
+        );
+
+        self.client.delete(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/brands/{brandId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("brandId", brand_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::account_brands::AccountBrands::brand_delete_if_exists`].
+    pub fn brand_delete_if_exists(&self, account_id: &str, brand_id: &str) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/brands/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(brand_id),
+        );
+
+        self.client.delete_if_exists(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/brands/{brandId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("brandId", brand_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::account_brands::AccountBrands::brand_export_get_file`].
+    pub fn brand_export_get_file(&self, account_id: &str, brand_id: &str) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/brands/{}/file",
+            self.client.encode_path(account_id),
+            self.client.encode_path(brand_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/brands/{brandId}/file",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("brandId", brand_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::account_brands::AccountBrands::brand_logo_get`].
+    pub fn brand_logo_get(&self, account_id: &str, brand_id: &str, logo_type: &str) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/brands/{}/logos/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(brand_id),
+            self.client.encode_path(logo_type),
+        );
+
+        self.client
+            .request_with_accept_mime(reqwest::Method::GET, &url, "image/png")
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/v2.1/accounts/{accountId}/brands/{brandId}/logos/{logoType}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("brandId", brand_id.to_string()),
+                        ("logoType", logo_type.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::account_brands::AccountBrands::brand_logo_put`].
+    pub fn brand_logo_put<B: AsRef<[u8]>>(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        logo_type: &str,
+        body: B,
+    ) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/brands/{}/logos/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(brand_id),
+            self.client.encode_path(logo_type),
+        );
+
+        self.client
+            .put(
+                &url,
+                Some(bytes::Bytes::copy_from_slice(body.as_ref()).into()),
+            )
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/brands/{brandId}/logos/{logoType}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("brandId", brand_id.to_string()),
+                        ("logoType", logo_type.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::account_brands::AccountBrands::brand_logo_delete`].
+    pub fn brand_logo_delete(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        logo_type: &str,
+    ) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/brands/{}/logos/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(brand_id),
+            self.client.encode_path(logo_type),
+        );
+
+        self.client.delete(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/brands/{brandId}/logos/{logoType}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("brandId", brand_id.to_string()),
+                    ("logoType", logo_type.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::account_brands::AccountBrands::brand_logo_delete_if_exists`].
+    pub fn brand_logo_delete_if_exists(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        logo_type: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/brands/{}/logos/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(brand_id),
+            self.client.encode_path(logo_type),
+        );
+
+        self.client.delete_if_exists(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/brands/{brandId}/logos/{logoType}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("brandId", brand_id.to_string()),
+                    ("logoType", logo_type.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::account_brands::AccountBrands::brand_resources_get_list`].
+    pub fn brand_resources_get_list(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+    ) -> Result<crate::types::BrandResourcesList> {
+        let url = format!(
+            "/v2.1/accounts/{}/brands/{}/resources",
+            self.client.encode_path(account_id),
+            self.client.encode_path(brand_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/brands/{brandId}/resources",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("brandId", brand_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::account_brands::AccountBrands::brand_resources_get`].
+    pub fn brand_resources_get(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        resource_content_type: &str,
+        langcode: &str,
+        return_master: &str,
+    ) -> Result<()> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !langcode.is_empty() {
+            query_args.push(("langcode", langcode.to_string()));
+        }
+        if !return_master.is_empty() {
+            query_args.push(("return_master", return_master.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/brands/{}/resources/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(brand_id),
+                self.client.encode_path(resource_content_type)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/brands/{brandId}/resources/{resourceContentType}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("brandId", brand_id.to_string()),
+                    ("resourceContentType", resource_content_type.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::account_brands::AccountBrands::brand_resources_put`].
+    pub fn brand_resources_put(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        resource_content_type: &str,
+        file_xml: &[u8],
+    ) -> Result<crate::types::BrandResources> {
+        let url = format!(
+            "/v2.1/accounts/{}/brands/{}/resources/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(brand_id),
+            self.client.encode_path(resource_content_type),
+        );
+
+        let form = reqwest::blocking::multipart::Form::new().part(
+            "file.xml",
+            reqwest::blocking::multipart::Part::bytes(file_xml.to_vec()).file_name("file.xml"),
+        );
+
+        self.client
+            .request_form(reqwest::Method::PUT, &url, form)
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/brands/{brandId}/resources/{resourceContentType}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("brandId", brand_id.to_string()),
+                        ("resourceContentType", resource_content_type.to_string()),
+                    ],
+                )
+            })
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct AccountConsumerDisclosures {
+    pub client: Client,
+}
+
+impl AccountConsumerDisclosures {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        AccountConsumerDisclosures { client }
+    }
+
+    /// The blocking form of [`crate::account_consumer_disclosures::AccountConsumerDisclosures::consumer_disclosure_get`].
+    pub fn consumer_disclosure_get(
+        &self,
+        account_id: &str,
+        lang_code: &str,
+    ) -> Result<crate::types::AccountConsumerDisclosures> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !lang_code.is_empty() {
+            query_args.push(("langCode", lang_code.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/consumer_disclosure",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/consumer_disclosure",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::account_consumer_disclosures::AccountConsumerDisclosures::consumer_disclosure_get_lang_code`].
+    pub fn consumer_disclosure_get_lang_code(
+        &self,
+        account_id: &str,
+        lang_code: &str,
+    ) -> Result<crate::types::AccountConsumerDisclosures> {
+        let url = format!(
+            "/v2.1/accounts/{}/consumer_disclosure/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(lang_code),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/consumer_disclosure/{langCode}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("langCode", lang_code.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::account_consumer_disclosures::AccountConsumerDisclosures::consumer_disclosure_put`].
+    pub fn consumer_disclosure_put(
+        &self,
+        account_id: &str,
+        lang_code: &str,
+        include_metadata: &str,
+        body: &crate::types::ConsumerDisclosure,
+    ) -> Result<crate::types::ConsumerDisclosure> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !include_metadata.is_empty() {
+            query_args.push(("include_metadata", include_metadata.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/consumer_disclosure/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(lang_code)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/consumer_disclosure/{langCode}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("langCode", lang_code.to_string()),
+                    ],
+                )
+            })
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct AccountCustomFields {
+    pub client: Client,
+}
+
+impl AccountCustomFields {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        AccountCustomFields { client }
+    }
+
+    /// The blocking form of [`crate::account_custom_fields::AccountCustomFields::get`].
+    pub fn get(&self, account_id: &str) -> Result<crate::types::AccountCustomFields> {
+        let url = format!(
+            "/v2.1/accounts/{}/custom_fields",
+            self.client.encode_path(account_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/custom_fields",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::account_custom_fields::AccountCustomFields::post`].
+    pub fn post(
+        &self,
+        account_id: &str,
+        apply_to_templates: bool,
+        body: &crate::types::CustomField,
+    ) -> Result<crate::types::AccountCustomFields> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if apply_to_templates {
+            query_args.push(("apply_to_templates", apply_to_templates.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/custom_fields",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/custom_fields",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::account_custom_fields::AccountCustomFields::put`].
+    pub fn put(
+        &self,
+        account_id: &str,
+        custom_field_id: &crate::custom_field_id::CustomFieldId,
+        apply_to_templates: bool,
+        body: &crate::types::CustomField,
+    ) -> Result<crate::types::AccountCustomFields> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if apply_to_templates {
+            query_args.push(("apply_to_templates", apply_to_templates.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/custom_fields/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(&custom_field_id.to_string())
+            ),
+            &query_args,
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/custom_fields/{customFieldId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("customFieldId", custom_field_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::account_custom_fields::AccountCustomFields::delete`].
+    pub fn delete(
+        &self,
+        account_id: &str,
+        custom_field_id: &crate::custom_field_id::CustomFieldId,
+        apply_to_templates: bool,
+    ) -> Result<()> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if apply_to_templates {
+            query_args.push(("apply_to_templates", apply_to_templates.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/custom_fields/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(&custom_field_id.to_string())
+            ),
+            &query_args,
+        );
+
+        self.client.delete(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/custom_fields/{customFieldId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("customFieldId", custom_field_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::account_custom_fields::AccountCustomFields::delete_if_exists`].
+    pub fn delete_if_exists(
+        &self,
+        account_id: &str,
+        custom_field_id: &crate::custom_field_id::CustomFieldId,
+        apply_to_templates: bool,
+    ) -> Result<bool> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if apply_to_templates {
+            query_args.push(("apply_to_templates", apply_to_templates.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/custom_fields/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(&custom_field_id.to_string())
+            ),
+            &query_args,
+        );
+
+        self.client.delete_if_exists(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/custom_fields/{customFieldId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("customFieldId", custom_field_id.to_string()),
+                ],
+            )
+        })
+    }
+}
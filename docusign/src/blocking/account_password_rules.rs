@@ -0,0 +1,58 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct AccountPasswordRules {
+    pub client: Client,
+}
+
+impl AccountPasswordRules {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        AccountPasswordRules { client }
+    }
+
+    /// The blocking form of [`crate::account_password_rules::AccountPasswordRules::get`].
+    pub fn get(&self, account_id: &str) -> Result<crate::types::AccountPasswordRulesData> {
+        let url = format!(
+            "/v2.1/accounts/{}/settings/password_rules",
+            self.client.encode_path(account_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/settings/password_rules",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::account_password_rules::AccountPasswordRules::put`].
+    pub fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::AccountPasswordRulesData,
+    ) -> Result<crate::types::AccountPasswordRulesData> {
+        let url = format!(
+            "/v2.1/accounts/{}/settings/password_rules",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/settings/password_rules",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::account_password_rules::AccountPasswordRules::password_rules_get`].
+    pub fn password_rules_get(&self) -> Result<crate::types::UserPasswordRules> {
+        let url = "/v2.1/current_user/password_rules".to_string();
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url("GET", "/v2.1/current_user/password_rules", &[])
+        })
+    }
+}
@@ -0,0 +1,200 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct AccountPermissionProfiles {
+    pub client: Client,
+}
+
+impl AccountPermissionProfiles {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        AccountPermissionProfiles { client }
+    }
+
+    /// The blocking form of [`crate::account_permission_profiles::AccountPermissionProfiles::permission_profiles_get`].
+    pub fn permission_profiles_get(
+        &self,
+        account_id: &str,
+        include: &str,
+    ) -> Result<crate::types::PermissionProfileInformation> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !include.is_empty() {
+            query_args.push(("include", include.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/permission_profiles",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/permission_profiles",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::account_permission_profiles::AccountPermissionProfiles::permission_profiles_post`].
+    pub fn permission_profiles_post(
+        &self,
+        account_id: &str,
+        include: &str,
+        body: &crate::types::PermissionProfile,
+    ) -> Result<crate::types::PermissionProfile> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !include.is_empty() {
+            query_args.push(("include", include.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/permission_profiles",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/permission_profiles",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::account_permission_profiles::AccountPermissionProfiles::permission_profiles_get_profile`].
+    pub fn permission_profiles_get_profile(
+        &self,
+        account_id: &str,
+        permission_profile_id: &str,
+        include: &str,
+    ) -> Result<crate::types::PermissionProfile> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !include.is_empty() {
+            query_args.push(("include", include.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/permission_profiles/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(permission_profile_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/permission_profiles/{permissionProfileId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("permissionProfileId", permission_profile_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::account_permission_profiles::AccountPermissionProfiles::permission_profiles_put`].
+    pub fn permission_profiles_put(
+        &self,
+        account_id: &str,
+        permission_profile_id: &str,
+        include: &str,
+        body: &crate::types::PermissionProfile,
+    ) -> Result<crate::types::PermissionProfile> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !include.is_empty() {
+            query_args.push(("include", include.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/permission_profiles/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(permission_profile_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/permission_profiles/{permissionProfileId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("permissionProfileId", permission_profile_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::account_permission_profiles::AccountPermissionProfiles::permission_profiles_delete`].
+    pub fn permission_profiles_delete(
+        &self,
+        account_id: &str,
+        permission_profile_id: &str,
+        move_users_to: &str,
+    ) -> Result<()> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !move_users_to.is_empty() {
+            query_args.push(("move_users_to", move_users_to.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/permission_profiles/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(permission_profile_id)
+            ),
+            &query_args,
+        );
+
+        self.client.delete(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/permission_profiles/{permissionProfileId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("permissionProfileId", permission_profile_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::account_permission_profiles::AccountPermissionProfiles::permission_profiles_delete_if_exists`].
+    pub fn permission_profiles_delete_if_exists(
+        &self,
+        account_id: &str,
+        permission_profile_id: &str,
+        move_users_to: &str,
+    ) -> Result<bool> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !move_users_to.is_empty() {
+            query_args.push(("move_users_to", move_users_to.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/permission_profiles/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(permission_profile_id)
+            ),
+            &query_args,
+        );
+
+        self.client.delete_if_exists(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/permission_profiles/{permissionProfileId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("permissionProfileId", permission_profile_id.to_string()),
+                ],
+            )
+        })
+    }
+}
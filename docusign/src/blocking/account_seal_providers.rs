@@ -0,0 +1,31 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct AccountSealProviders {
+    pub client: Client,
+}
+
+impl AccountSealProviders {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        AccountSealProviders { client }
+    }
+
+    /// The blocking form of [`crate::account_seal_providers::AccountSealProviders::account_signature_providers_get_seal`].
+    pub fn account_signature_providers_get_seal(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::AccountSeals> {
+        let url = format!(
+            "/v2.1/accounts/{}/seals",
+            self.client.encode_path(account_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/seals",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+}
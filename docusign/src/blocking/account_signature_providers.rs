@@ -0,0 +1,28 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct AccountSignatureProviders {
+    pub client: Client,
+}
+
+impl AccountSignatureProviders {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        AccountSignatureProviders { client }
+    }
+
+    /// The blocking form of [`crate::account_signature_providers::AccountSignatureProviders::get`].
+    pub fn get(&self, account_id: &str) -> Result<crate::types::AccountSignatureProvidersData> {
+        let url = format!(
+            "/v2.1/accounts/{}/signatureProviders",
+            self.client.encode_path(account_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/signatureProviders",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+}
@@ -0,0 +1,300 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct AccountSignatures {
+    pub client: Client,
+}
+
+impl AccountSignatures {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        AccountSignatures { client }
+    }
+
+    /// The blocking form of [`crate::account_signatures::AccountSignatures::get`].
+    pub fn get(
+        &self,
+        account_id: &str,
+        stamp_format: &str,
+        stamp_name: &str,
+        stamp_type: &str,
+    ) -> Result<crate::types::AccountSignaturesInformation> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !stamp_format.is_empty() {
+            query_args.push(("stamp_format", stamp_format.to_string()));
+        }
+        if !stamp_name.is_empty() {
+            query_args.push(("stamp_name", stamp_name.to_string()));
+        }
+        if !stamp_type.is_empty() {
+            query_args.push(("stamp_type", stamp_type.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/signatures",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/signatures",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::account_signatures::AccountSignatures::put_signature`].
+    pub fn put_signature(
+        &self,
+        account_id: &str,
+        body: &crate::types::AccountSignaturesInformation,
+    ) -> Result<crate::types::AccountSignaturesInformation> {
+        let url = format!(
+            "/v2.1/accounts/{}/signatures",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/signatures",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::account_signatures::AccountSignatures::post`].
+    pub fn post(
+        &self,
+        account_id: &str,
+        decode_only: &str,
+        body: &crate::types::AccountSignaturesInformation,
+    ) -> Result<crate::types::AccountSignaturesInformation> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !decode_only.is_empty() {
+            query_args.push(("decode_only", decode_only.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/signatures",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/signatures",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::account_signatures::AccountSignatures::get_signature`].
+    pub fn get_signature(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+    ) -> Result<crate::types::AccountSignature> {
+        let url = format!(
+            "/v2.1/accounts/{}/signatures/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(signature_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/signatures/{signatureId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("signatureId", signature_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::account_signatures::AccountSignatures::put_signature_account_signatures`].
+    pub fn put_signature_account_signatures(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+        close_existing_signature: &str,
+        body: &crate::types::AccountSignatureDefinition,
+    ) -> Result<crate::types::AccountSignature> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !close_existing_signature.is_empty() {
+            query_args.push((
+                "close_existing_signature",
+                close_existing_signature.to_string(),
+            ));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/signatures/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(signature_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/signatures/{signatureId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("signatureId", signature_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::account_signatures::AccountSignatures::delete_signature`].
+    pub fn delete_signature(&self, account_id: &str, signature_id: &str) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/signatures/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(signature_id),
+        );
+
+        self.client.delete(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/signatures/{signatureId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("signatureId", signature_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::account_signatures::AccountSignatures::delete_signature_if_exists`].
+    pub fn delete_signature_if_exists(&self, account_id: &str, signature_id: &str) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/signatures/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(signature_id),
+        );
+
+        self.client.delete_if_exists(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/signatures/{signatureId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("signatureId", signature_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::account_signatures::AccountSignatures::get_signature_image`].
+    pub fn get_signature_image(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        include_chrome: &str,
+    ) -> Result<()> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !include_chrome.is_empty() {
+            query_args.push(("include_chrome", include_chrome.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/signatures/{}/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(signature_id),
+                self.client.encode_path(image_type)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .request_with_accept_mime(reqwest::Method::GET, &url, "image/gif")
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/v2.1/accounts/{accountId}/signatures/{signatureId}/{imageType}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("signatureId", signature_id.to_string()),
+                        ("imageType", image_type.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::account_signatures::AccountSignatures::put_signature_image`].
+    pub fn put_signature_image(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        transparent_png: &str,
+    ) -> Result<crate::types::AccountSignature> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !transparent_png.is_empty() {
+            query_args.push(("transparent_png", transparent_png.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/signatures/{}/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(signature_id),
+                self.client.encode_path(image_type)
+            ),
+            &query_args,
+        );
+
+        self.client.put(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "PUT",
+                "/v2.1/accounts/{accountId}/signatures/{signatureId}/{imageType}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("signatureId", signature_id.to_string()),
+                    ("imageType", image_type.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::account_signatures::AccountSignatures::delete_signature_image`].
+    pub fn delete_signature_image(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+    ) -> Result<crate::types::AccountSignature> {
+        let url = format!(
+            "/v2.1/accounts/{}/signatures/{}/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(signature_id),
+            self.client.encode_path(image_type),
+        );
+
+        self.client.delete(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/signatures/{signatureId}/{imageType}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("signatureId", signature_id.to_string()),
+                    ("imageType", image_type.to_string()),
+                ],
+            )
+        })
+    }
+}
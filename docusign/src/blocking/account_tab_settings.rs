@@ -0,0 +1,50 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct AccountTabSettings {
+    pub client: Client,
+}
+
+impl AccountTabSettings {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        AccountTabSettings { client }
+    }
+
+    /// The blocking form of [`crate::account_tab_settings::AccountTabSettings::tab_settings_get`].
+    pub fn tab_settings_get(&self, account_id: &str) -> Result<crate::types::TabsBlob> {
+        let url = format!(
+            "/v2.1/accounts/{}/settings/tabs",
+            self.client.encode_path(account_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/settings/tabs",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::account_tab_settings::AccountTabSettings::tab_settings_put`].
+    pub fn tab_settings_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::TabsBlob,
+    ) -> Result<crate::types::TabsBlob> {
+        let url = format!(
+            "/v2.1/accounts/{}/settings/tabs",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/settings/tabs",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct AccountWatermarks {
+    pub client: Client,
+}
+
+impl AccountWatermarks {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        AccountWatermarks { client }
+    }
+
+    /// The blocking form of [`crate::account_watermarks::AccountWatermarks::watermark_get`].
+    pub fn watermark_get(&self, account_id: &str) -> Result<crate::types::Watermark> {
+        let url = format!(
+            "/v2.1/accounts/{}/watermark",
+            self.client.encode_path(account_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/watermark",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::account_watermarks::AccountWatermarks::watermark_put`].
+    pub fn watermark_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::Watermark,
+    ) -> Result<crate::types::Watermark> {
+        let url = format!(
+            "/v2.1/accounts/{}/watermark",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/watermark",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::account_watermarks::AccountWatermarks::watermark_preview_put`].
+    pub fn watermark_preview_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::Watermark,
+    ) -> Result<crate::types::Watermark> {
+        let url = format!(
+            "/v2.1/accounts/{}/watermark/preview",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/watermark/preview",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+}
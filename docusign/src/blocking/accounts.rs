@@ -0,0 +1,449 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct Accounts {
+    pub client: Client,
+}
+
+impl Accounts {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        Accounts { client }
+    }
+
+    /// The blocking form of [`crate::accounts::Accounts::post`].
+    pub fn post(
+        &self,
+        body: &crate::types::NewAccountDefinition,
+    ) -> Result<crate::types::NewAccountSummary> {
+        let url = "/v2.1/accounts".to_string();
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| crate::utils::pretty_url("POST", "/v2.1/accounts", &[]))
+    }
+
+    /// The blocking form of [`crate::accounts::Accounts::get_provisioning`].
+    pub fn get_provisioning(&self) -> Result<crate::types::ProvisioningInformation> {
+        let url = "/v2.1/accounts/provisioning".to_string();
+        self.client
+            .get(&url, None)
+            .with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/provisioning", &[]))
+    }
+
+    /// The blocking form of [`crate::accounts::Accounts::get`].
+    pub fn get(
+        &self,
+        account_id: &str,
+        include_account_settings: &str,
+    ) -> Result<crate::types::AccountInformation> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !include_account_settings.is_empty() {
+            query_args.push((
+                "include_account_settings",
+                include_account_settings.to_string(),
+            ));
+        }
+        let url = crate::Client::url_with_query(
+            &format!("/v2.1/accounts/{}", self.client.encode_path(account_id)),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::accounts::Accounts::delete`].
+    pub fn delete(&self, account_id: &str) -> Result<()> {
+        let url = format!("/v2.1/accounts/{}", self.client.encode_path(account_id),);
+
+        self.client.delete(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::accounts::Accounts::delete_if_exists`].
+    pub fn delete_if_exists(&self, account_id: &str) -> Result<bool> {
+        let url = format!("/v2.1/accounts/{}", self.client.encode_path(account_id),);
+
+        self.client.delete_if_exists(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::accounts::Accounts::billing_charges_get`].
+    pub fn billing_charges_get(
+        &self,
+        account_id: &str,
+        include_charges: &str,
+    ) -> Result<crate::types::BillingChargeResponse> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !include_charges.is_empty() {
+            query_args.push(("include_charges", include_charges.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/billing_charges",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/billing_charges",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::accounts::Accounts::captive_recipients_delete_part`].
+    pub fn captive_recipients_delete_part(
+        &self,
+        account_id: &str,
+        recipient_part: &str,
+        body: &crate::types::CaptiveRecipientInformation,
+    ) -> Result<crate::types::CaptiveRecipientInformation> {
+        let url = format!(
+            "/v2.1/accounts/{}/captive_recipients/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(recipient_part),
+        );
+
+        self.client
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/captive_recipients/{recipientPart}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("recipientPart", recipient_part.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::accounts::Accounts::recipient_names_get`].
+    pub fn recipient_names_get(
+        &self,
+        account_id: &str,
+        email: &str,
+    ) -> Result<crate::types::RecipientNamesResponse> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !email.is_empty() {
+            query_args.push(("email", email.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/recipient_names",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/recipient_names",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::accounts::Accounts::settings_get`].
+    pub fn settings_get(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::AccountSettingsInformation> {
+        let url = format!(
+            "/v2.1/accounts/{}/settings",
+            self.client.encode_path(account_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/settings",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::accounts::Accounts::settings_put`].
+    pub fn settings_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::AccountSettingsInformation,
+    ) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/settings",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/settings",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::accounts::Accounts::envelope_purge_configuration_get`].
+    pub fn envelope_purge_configuration_get(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::EnvelopePurgeConfiguration> {
+        let url = format!(
+            "/v2.1/accounts/{}/settings/envelope_purge_configuration",
+            self.client.encode_path(account_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/settings/envelope_purge_configuration",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::accounts::Accounts::envelope_purge_configuration_put`].
+    pub fn envelope_purge_configuration_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::EnvelopePurgeConfiguration,
+    ) -> Result<crate::types::EnvelopePurgeConfiguration> {
+        let url = format!(
+            "/v2.1/accounts/{}/settings/envelope_purge_configuration",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/settings/envelope_purge_configuration",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::accounts::Accounts::notification_defaults_get`].
+    pub fn notification_defaults_get(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::NotificationDefaultsData> {
+        let url = format!(
+            "/v2.1/accounts/{}/settings/notification_defaults",
+            self.client.encode_path(account_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/settings/notification_defaults",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::accounts::Accounts::notification_defaults_put`].
+    pub fn notification_defaults_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::NotificationDefaultsData,
+    ) -> Result<crate::types::NotificationDefaultsData> {
+        let url = format!(
+            "/v2.1/accounts/{}/settings/notification_defaults",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/settings/notification_defaults",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::accounts::Accounts::shared_access_get`].
+    pub fn shared_access_get(
+        &self,
+        account_id: &str,
+        count: &str,
+        envelopes_not_shared_user_status: &str,
+        folder_ids: &str,
+        item_type: &str,
+        search_text: &str,
+        shared: &str,
+        start_position: &str,
+        user_ids: &str,
+    ) -> Result<crate::types::AccountSharedAccess> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !count.is_empty() {
+            query_args.push(("count", count.to_string()));
+        }
+        if !envelopes_not_shared_user_status.is_empty() {
+            query_args.push((
+                "envelopes_not_shared_user_status",
+                envelopes_not_shared_user_status.to_string(),
+            ));
+        }
+        if !folder_ids.is_empty() {
+            query_args.push(("folder_ids", folder_ids.to_string()));
+        }
+        if !item_type.is_empty() {
+            query_args.push(("item_type", item_type.to_string()));
+        }
+        if !search_text.is_empty() {
+            query_args.push(("search_text", search_text.to_string()));
+        }
+        if !shared.is_empty() {
+            query_args.push(("shared", shared.to_string()));
+        }
+        if !start_position.is_empty() {
+            query_args.push(("start_position", start_position.to_string()));
+        }
+        if !user_ids.is_empty() {
+            query_args.push(("user_ids", user_ids.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/shared_access",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/shared_access",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::accounts::Accounts::shared_access_put`].
+    pub fn shared_access_put(
+        &self,
+        account_id: &str,
+        item_type: &str,
+        preserve_existing_shared_access: &str,
+        user_ids: &str,
+        body: &crate::types::AccountSharedAccess,
+    ) -> Result<crate::types::AccountSharedAccess> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !item_type.is_empty() {
+            query_args.push(("item_type", item_type.to_string()));
+        }
+        if !preserve_existing_shared_access.is_empty() {
+            query_args.push((
+                "preserve_existing_shared_access",
+                preserve_existing_shared_access.to_string(),
+            ));
+        }
+        if !user_ids.is_empty() {
+            query_args.push(("user_ids", user_ids.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/shared_access",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/shared_access",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::accounts::Accounts::supported_languages_get`].
+    pub fn supported_languages_get(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::SupportedLanguages> {
+        let url = format!(
+            "/v2.1/accounts/{}/supported_languages",
+            self.client.encode_path(account_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/supported_languages",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::accounts::Accounts::unsupported_file_types_get`].
+    pub fn unsupported_file_types_get(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::FileTypeList> {
+        let url = format!(
+            "/v2.1/accounts/{}/unsupported_file_types",
+            self.client.encode_path(account_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/unsupported_file_types",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::accounts::Accounts::organization_exports_get_settings_export`].
+    pub fn organization_exports_get_settings_export(
+        &self,
+        organization_id: &str,
+        result_id: &str,
+    ) -> Result<()> {
+        let url = format!(
+            "/v2.1/organization_exports/{}/account_settings/{}",
+            self.client.encode_path(organization_id),
+            self.client.encode_path(result_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/organization_exports/{organizationId}/account_settings/{resultId}",
+                &[
+                    ("organizationId", organization_id.to_string()),
+                    ("resultId", result_id.to_string()),
+                ],
+            )
+        })
+    }
+}
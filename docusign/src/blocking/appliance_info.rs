@@ -0,0 +1,455 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct ApplianceInfo {
+    pub client: Client,
+}
+
+impl ApplianceInfo {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        ApplianceInfo { client }
+    }
+
+    /// The blocking form of [`crate::appliance_info::ApplianceInfo::envelope_get_dynamic_system_setting`].
+    pub fn envelope_get_dynamic_system_setting(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::ApplianceInfo> {
+        let url = format!(
+            "/v2.1/accounts/{}/display_appliance_info/dynamicsystemsettings",
+            self.client.encode_path(account_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/display_appliance_info/dynamicsystemsettings",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::appliance_info::ApplianceInfo::envelope_get_template`].
+    pub fn envelope_get_template(&self, account_id: &str) -> Result<crate::types::ApplianceInfo> {
+        let url = format!(
+            "/v2.1/accounts/{}/display_appliance_info/templateInfo",
+            self.client.encode_path(account_id),
+        );
+
+        self.client.post(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/v2.1/accounts/{accountId}/display_appliance_info/templateInfo",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::appliance_info::ApplianceInfo::envelope_get`].
+    pub fn envelope_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::ApplianceInfo> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/display_appliance_info",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::appliance_info::ApplianceInfo::envelope_get_account`].
+    pub fn envelope_get_account(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::DisplayApplianceAccount> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/account_info",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client.get(&url, None).with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/account_info", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::appliance_info::ApplianceInfo::envelope_get_custom_field`].
+    pub fn envelope_get_custom_field(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::ApplianceInfo> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/custom_fields",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client.get(&url, None).with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/custom_fields", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::appliance_info::ApplianceInfo::envelope_delete_custom_fields`].
+    pub fn envelope_delete_custom_fields(&self, account_id: &str, envelope_id: &str) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/custom_fields/delete",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client.post(&url, None).with_context(|| crate::utils::pretty_url("POST", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/custom_fields/delete", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::appliance_info::ApplianceInfo::envelope_get_date_signed`].
+    pub fn envelope_get_date_signed(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::ApplianceInfo> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/date_signed",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client.get(&url, None).with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/date_signed", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::appliance_info::ApplianceInfo::envelope_put_document`].
+    pub fn envelope_put_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/document/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(document_id),
+        );
+
+        self.client.put(&url, None).with_context(|| crate::utils::pretty_url("PUT", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/document/{documentId}", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("documentId", document_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::appliance_info::ApplianceInfo::envelope_delete_document`].
+    pub fn envelope_delete_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/document/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(document_id),
+        );
+
+        self.client.delete(&url, None).with_context(|| crate::utils::pretty_url("DELETE", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/document/{documentId}", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("documentId", document_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::appliance_info::ApplianceInfo::envelope_delete_document_if_exists`].
+    pub fn envelope_delete_document_if_exists(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/document/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(document_id),
+        );
+
+        self.client.delete_if_exists(&url, None).with_context(|| crate::utils::pretty_url("DELETE", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/document/{documentId}", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("documentId", document_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::appliance_info::ApplianceInfo::envelope_get_document_page`].
+    pub fn envelope_get_document_page(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::ApplianceInfo> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/document_page_list",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client.get(&url, None).with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/document_page_list", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::appliance_info::ApplianceInfo::envelope_get_image`].
+    pub fn envelope_get_image(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::ApplianceInfo> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/image",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client.post(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/image",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::appliance_info::ApplianceInfo::envelope_get_locale_policy`].
+    pub fn envelope_get_locale_policy(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        user_id: &str,
+    ) -> Result<crate::types::ApplianceInfo> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/localepolicy/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(user_id),
+        );
+
+        self.client.post(&url, None).with_context(|| crate::utils::pretty_url("POST", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/localepolicy/{userId}", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("userId", user_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::appliance_info::ApplianceInfo::envelope_put_page`].
+    pub fn envelope_put_page(&self, account_id: &str, envelope_id: &str) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/page_info",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client.put(&url, None).with_context(|| crate::utils::pretty_url("PUT", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/page_info", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::appliance_info::ApplianceInfo::envelope_post_page`].
+    pub fn envelope_post_page(&self, account_id: &str, envelope_id: &str) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/page_info",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client.post(&url, None).with_context(|| crate::utils::pretty_url("POST", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/page_info", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::appliance_info::ApplianceInfo::envelope_delete_page`].
+    pub fn envelope_delete_page(&self, account_id: &str, envelope_id: &str) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/page_info/delete",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client.post(&url, None).with_context(|| crate::utils::pretty_url("POST", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/page_info/delete", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::appliance_info::ApplianceInfo::envelope_put_pdf`].
+    pub fn envelope_put_pdf(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::ApplianceInfo> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/pdf",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client.put(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "PUT",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/pdf",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::appliance_info::ApplianceInfo::envelope_get_pdf`].
+    pub fn envelope_get_pdf(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        pdf_id: &str,
+    ) -> Result<crate::types::ApplianceInfo> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/pdf/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(pdf_id),
+        );
+
+        self.client.get(&url, None).with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/pdf/{pdfId}", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("pdfId", pdf_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::appliance_info::ApplianceInfo::envelope_get_pdf_blob`].
+    pub fn envelope_get_pdf_blob(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::ApplianceInfo> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/pdf_blobs",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client.get(&url, None).with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/pdf_blobs", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::appliance_info::ApplianceInfo::envelope_put_pdf_blob`].
+    pub fn envelope_put_pdf_blob(&self, account_id: &str, envelope_id: &str) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/pdf_blobs",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client.put(&url, None).with_context(|| crate::utils::pretty_url("PUT", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/pdf_blobs", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::appliance_info::ApplianceInfo::envelope_post_pdf_blob`].
+    pub fn envelope_post_pdf_blob(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::ApplianceInfo> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/pdf_blobs",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client.post(&url, None).with_context(|| crate::utils::pretty_url("POST", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/pdf_blobs", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::appliance_info::ApplianceInfo::envelope_put_recipient_denied_document_copy`].
+    pub fn envelope_put_recipient_denied_document_copy(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/recipient_denied_copy",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client.put(&url, None).with_context(|| crate::utils::pretty_url("PUT", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/recipient_denied_copy", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::appliance_info::ApplianceInfo::envelope_delete_recipient_denied_document_copy`].
+    pub fn envelope_delete_recipient_denied_document_copy(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/recipient_denied_copy",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client.delete(&url, None).with_context(|| crate::utils::pretty_url("DELETE", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/recipient_denied_copy", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::appliance_info::ApplianceInfo::envelope_delete_recipient_denied_document_copy_if_exists`].
+    pub fn envelope_delete_recipient_denied_document_copy_if_exists(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/recipient_denied_copy",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client.delete_if_exists(&url, None).with_context(|| crate::utils::pretty_url("DELETE", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/recipient_denied_copy", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::appliance_info::ApplianceInfo::envelope_get_signer_attachment`].
+    pub fn envelope_get_signer_attachment(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::ApplianceInfo> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/signer_attachment_info",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client.get(&url, None).with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/signer_attachment_info", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::appliance_info::ApplianceInfo::envelope_delete_signer_attachment`].
+    pub fn envelope_delete_signer_attachment(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/signer_attachment_info",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client.delete(&url, None).with_context(|| crate::utils::pretty_url("DELETE", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/signer_attachment_info", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::appliance_info::ApplianceInfo::envelope_delete_signer_attachment_if_exists`].
+    pub fn envelope_delete_signer_attachment_if_exists(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/signer_attachment_info",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client.delete_if_exists(&url, None).with_context(|| crate::utils::pretty_url("DELETE", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/display_appliance_info/signer_attachment_info", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::appliance_info::ApplianceInfo::envelope_post_error`].
+    pub fn envelope_post_error(&self) -> Result<()> {
+        let url = "/v2.1/display_appliance_info/error".to_string();
+        self.client.post(&url, None).with_context(|| {
+            crate::utils::pretty_url("POST", "/v2.1/display_appliance_info/error", &[])
+        })
+    }
+
+    /// The blocking form of [`crate::appliance_info::ApplianceInfo::envelope_post_redeem`].
+    pub fn envelope_post_redeem(&self) -> Result<crate::types::ApplianceInfo> {
+        let url = "/v2.1/display_appliance_info/redeem".to_string();
+        self.client.post(&url, None).with_context(|| {
+            crate::utils::pretty_url("POST", "/v2.1/display_appliance_info/redeem", &[])
+        })
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct BccEmailArchive {
+    pub client: Client,
+}
+
+impl BccEmailArchive {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        BccEmailArchive { client }
+    }
+
+    /// The blocking form of [`crate::bcc_email_archive::BccEmailArchive::get_list`].
+    pub fn get_list(
+        &self,
+        account_id: &str,
+        count: &str,
+        start_position: &str,
+    ) -> Result<crate::types::BccEmailArchiveList> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !count.is_empty() {
+            query_args.push(("count", count.to_string()));
+        }
+        if !start_position.is_empty() {
+            query_args.push(("start_position", start_position.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/settings/bcc_email_archives",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/settings/bcc_email_archives",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::bcc_email_archive::BccEmailArchive::post`].
+    pub fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::BccEmailArchiveData,
+    ) -> Result<crate::types::BccEmailArchiveData> {
+        let url = format!(
+            "/v2.1/accounts/{}/settings/bcc_email_archives",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/settings/bcc_email_archives",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::bcc_email_archive::BccEmailArchive::get_history_list`].
+    pub fn get_history_list(
+        &self,
+        account_id: &str,
+        bcc_email_archive_id: &str,
+        count: &str,
+        start_position: &str,
+    ) -> Result<crate::types::BccEmailArchiveHistoryList> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !count.is_empty() {
+            query_args.push(("count", count.to_string()));
+        }
+        if !start_position.is_empty() {
+            query_args.push(("start_position", start_position.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/settings/bcc_email_archives/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(bcc_email_archive_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/settings/bcc_email_archives/{bccEmailArchiveId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("bccEmailArchiveId", bcc_email_archive_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::bcc_email_archive::BccEmailArchive::delete`].
+    pub fn delete(&self, account_id: &str, bcc_email_archive_id: &str) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/settings/bcc_email_archives/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(bcc_email_archive_id),
+        );
+
+        self.client.delete(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/settings/bcc_email_archives/{bccEmailArchiveId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("bccEmailArchiveId", bcc_email_archive_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::bcc_email_archive::BccEmailArchive::delete_if_exists`].
+    pub fn delete_if_exists(&self, account_id: &str, bcc_email_archive_id: &str) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/settings/bcc_email_archives/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(bcc_email_archive_id),
+        );
+
+        self.client.delete_if_exists(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/settings/bcc_email_archives/{bccEmailArchiveId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("bccEmailArchiveId", bcc_email_archive_id.to_string()),
+                ],
+            )
+        })
+    }
+}
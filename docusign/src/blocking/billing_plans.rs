@@ -0,0 +1,189 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct BillingPlans {
+    pub client: Client,
+}
+
+impl BillingPlans {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        BillingPlans { client }
+    }
+
+    /// The blocking form of [`crate::billing_plans::BillingPlans::get`].
+    pub fn get(
+        &self,
+        account_id: &str,
+        include_credit_card_information: &str,
+        include_metadata: &str,
+        include_successor_plans: &str,
+    ) -> Result<crate::types::AccountBillingPlanResponse> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !include_credit_card_information.is_empty() {
+            query_args.push((
+                "include_credit_card_information",
+                include_credit_card_information.to_string(),
+            ));
+        }
+        if !include_metadata.is_empty() {
+            query_args.push(("include_metadata", include_metadata.to_string()));
+        }
+        if !include_successor_plans.is_empty() {
+            query_args.push((
+                "include_successor_plans",
+                include_successor_plans.to_string(),
+            ));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/billing_plan",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/billing_plan",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::billing_plans::BillingPlans::put`].
+    pub fn put(
+        &self,
+        account_id: &str,
+        preview_billing_plan: &str,
+        body: &crate::types::BillingPlanInformation,
+    ) -> Result<crate::types::BillingPlanUpdateResponse> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !preview_billing_plan.is_empty() {
+            query_args.push(("preview_billing_plan", preview_billing_plan.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/billing_plan",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/billing_plan",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::billing_plans::BillingPlans::get_credit_card_info`].
+    pub fn get_credit_card_info(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::CreditCardInformation> {
+        let url = format!(
+            "/v2.1/accounts/{}/billing_plan/credit_card",
+            self.client.encode_path(account_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/billing_plan/credit_card",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::billing_plans::BillingPlans::get_downgrade_request_info`].
+    pub fn get_downgrade_request_info(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::DowngradRequestBillingInfoResponse> {
+        let url = format!(
+            "/v2.1/accounts/{}/billing_plan/downgrade",
+            self.client.encode_path(account_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/billing_plan/downgrade",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::billing_plans::BillingPlans::put_downgrade_account`].
+    pub fn put_downgrade_account(
+        &self,
+        account_id: &str,
+        body: &crate::types::DowngradeBillingPlanInformation,
+    ) -> Result<crate::types::DowngradePlanUpdateResponse> {
+        let url = format!(
+            "/v2.1/accounts/{}/billing_plan/downgrade",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/billing_plan/downgrade",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::billing_plans::BillingPlans::purchased_envelopes_put`].
+    pub fn purchased_envelopes_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::PurchasedEnvelopesInformation,
+    ) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/billing_plan/purchased_envelopes",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/billing_plan/purchased_envelopes",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::billing_plans::BillingPlans::get_billing_plans`].
+    pub fn get_billing_plans(&self) -> Result<crate::types::BillingPlansResponse> {
+        let url = "/v2.1/billing_plans".to_string();
+        self.client
+            .get(&url, None)
+            .with_context(|| crate::utils::pretty_url("GET", "/v2.1/billing_plans", &[]))
+    }
+
+    /// The blocking form of [`crate::billing_plans::BillingPlans::get_plan`].
+    pub fn get_plan(&self, billing_plan_id: &str) -> Result<crate::types::BillingPlanResponse> {
+        let url = format!(
+            "/v2.1/billing_plans/{}",
+            self.client.encode_path(billing_plan_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/billing_plans/{billingPlanId}",
+                &[("billingPlanId", billing_plan_id.to_string())],
+            )
+        })
+    }
+}
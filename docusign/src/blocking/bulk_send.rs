@@ -0,0 +1,272 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct BulkSend {
+    pub client: Client,
+}
+
+impl BulkSend {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        BulkSend { client }
+    }
+
+    /// The blocking form of [`crate::bulk_send::BulkSend::batch_get_batche`].
+    pub fn batch_get_batche(
+        &self,
+        account_id: &str,
+        batch_ids: &str,
+        count: &str,
+        start_position: &str,
+        status: &str,
+    ) -> Result<crate::types::BulkSendBatchSummaries> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !batch_ids.is_empty() {
+            query_args.push(("batch_ids", batch_ids.to_string()));
+        }
+        if !count.is_empty() {
+            query_args.push(("count", count.to_string()));
+        }
+        if !start_position.is_empty() {
+            query_args.push(("start_position", start_position.to_string()));
+        }
+        if !status.is_empty() {
+            query_args.push(("status", status.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/bulk_send_batch",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/bulk_send_batch",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::bulk_send::BulkSend::batch_get_statu`].
+    pub fn batch_get_statu(
+        &self,
+        account_id: &str,
+        bulk_send_batch_id: &str,
+    ) -> Result<crate::types::BulkSendBatchStatus> {
+        let url = format!(
+            "/v2.1/accounts/{}/bulk_send_batch/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(bulk_send_batch_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/bulk_send_batch/{bulkSendBatchId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("bulkSendBatchId", bulk_send_batch_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::bulk_send::BulkSend::batch_put_status`].
+    pub fn batch_put_status(
+        &self,
+        account_id: &str,
+        bulk_send_batch_id: &str,
+        body: &crate::types::BulkSendBatchRequest,
+    ) -> Result<crate::types::BulkSendBatchStatus> {
+        let url = format!(
+            "/v2.1/accounts/{}/bulk_send_batch/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(bulk_send_batch_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/bulk_send_batch/{bulkSendBatchId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("bulkSendBatchId", bulk_send_batch_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::bulk_send::BulkSend::v_2crud_get_list`].
+    pub fn v_2crud_get_list(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::BulkSendingListSummaries> {
+        let url = format!(
+            "/v2.1/accounts/{}/bulk_send_lists",
+            self.client.encode_path(account_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/bulk_send_lists",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::bulk_send::BulkSend::v_2crud_post_list`].
+    pub fn v_2crud_post_list(
+        &self,
+        account_id: &str,
+        body: &crate::types::BulkSendingList,
+    ) -> Result<crate::types::BulkSendingList> {
+        let url = format!(
+            "/v2.1/accounts/{}/bulk_send_lists",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/bulk_send_lists",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::bulk_send::BulkSend::v_2crud_get_list_bulk_send`].
+    pub fn v_2crud_get_list_bulk_send(
+        &self,
+        account_id: &str,
+        bulk_send_list_id: &str,
+    ) -> Result<crate::types::BulkSendingList> {
+        let url = format!(
+            "/v2.1/accounts/{}/bulk_send_lists/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(bulk_send_list_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/bulk_send_lists/{bulkSendListId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("bulkSendListId", bulk_send_list_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::bulk_send::BulkSend::v_2crud_put_list`].
+    pub fn v_2crud_put_list(
+        &self,
+        account_id: &str,
+        bulk_send_list_id: &str,
+        body: &crate::types::BulkSendingList,
+    ) -> Result<crate::types::BulkSendingList> {
+        let url = format!(
+            "/v2.1/accounts/{}/bulk_send_lists/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(bulk_send_list_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/bulk_send_lists/{bulkSendListId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("bulkSendListId", bulk_send_list_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::bulk_send::BulkSend::v_2crud_delete_list`].
+    pub fn v_2crud_delete_list(
+        &self,
+        account_id: &str,
+        bulk_send_list_id: &str,
+    ) -> Result<crate::types::BulkSendingListSummaries> {
+        let url = format!(
+            "/v2.1/accounts/{}/bulk_send_lists/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(bulk_send_list_id),
+        );
+
+        self.client.delete(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/bulk_send_lists/{bulkSendListId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("bulkSendListId", bulk_send_list_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::bulk_send::BulkSend::post_request`].
+    pub fn post_request(
+        &self,
+        account_id: &str,
+        bulk_send_list_id: &str,
+        body: &crate::types::BulkSendRequest,
+    ) -> Result<crate::types::BulkSendResponse> {
+        let url = format!(
+            "/v2.1/accounts/{}/bulk_send_lists/{}/send",
+            self.client.encode_path(account_id),
+            self.client.encode_path(bulk_send_list_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/bulk_send_lists/{bulkSendListId}/send",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("bulkSendListId", bulk_send_list_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::bulk_send::BulkSend::test_post_request`].
+    pub fn test_post_request(
+        &self,
+        account_id: &str,
+        bulk_send_list_id: &str,
+        body: &crate::types::BulkSendRequest,
+    ) -> Result<crate::types::BulkSendTestResponse> {
+        let url = format!(
+            "/v2.1/accounts/{}/bulk_send_lists/{}/test",
+            self.client.encode_path(account_id),
+            self.client.encode_path(bulk_send_list_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/bulk_send_lists/{bulkSendListId}/test",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("bulkSendListId", bulk_send_list_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
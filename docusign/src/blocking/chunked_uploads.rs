@@ -0,0 +1,142 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct ChunkedUploads {
+    pub client: Client,
+}
+
+impl ChunkedUploads {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        ChunkedUploads { client }
+    }
+
+    /// The blocking form of [`crate::chunked_uploads::ChunkedUploads::post`].
+    pub fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::ChunkedUploadRequest,
+    ) -> Result<crate::types::ChunkedUploadResponse> {
+        let url = format!(
+            "/v2.1/accounts/{}/chunked_uploads",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/chunked_uploads",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::chunked_uploads::ChunkedUploads::get_upload`].
+    pub fn get_upload(
+        &self,
+        account_id: &str,
+        chunked_upload_id: &str,
+        include: &str,
+    ) -> Result<crate::types::ChunkedUploadResponse> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !include.is_empty() {
+            query_args.push(("include", include.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/chunked_uploads/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(chunked_upload_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/chunked_uploads/{chunkedUploadId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("chunkedUploadId", chunked_upload_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::chunked_uploads::ChunkedUploads::put`].
+    pub fn put(
+        &self,
+        account_id: &str,
+        chunked_upload_id: &str,
+        action: &str,
+    ) -> Result<crate::types::ChunkedUploadResponse> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !action.is_empty() {
+            query_args.push(("action", action.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/chunked_uploads/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(chunked_upload_id)
+            ),
+            &query_args,
+        );
+
+        self.client.put(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "PUT",
+                "/v2.1/accounts/{accountId}/chunked_uploads/{chunkedUploadId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("chunkedUploadId", chunked_upload_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::chunked_uploads::ChunkedUploads::delete_upload`].
+    pub fn delete_upload(
+        &self,
+        account_id: &str,
+        chunked_upload_id: &str,
+    ) -> Result<crate::types::ChunkedUploadResponse> {
+        let url = format!(
+            "/v2.1/accounts/{}/chunked_uploads/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(chunked_upload_id),
+        );
+
+        self.client.delete(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/chunked_uploads/{chunkedUploadId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("chunkedUploadId", chunked_upload_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::chunked_uploads::ChunkedUploads::put_upload_part`].
+    pub fn put_upload_part(
+        &self,
+        account_id: &str,
+        chunked_upload_id: &str,
+        chunked_upload_part_seq: &str,
+        body: &crate::types::ChunkedUploadRequest,
+    ) -> Result<crate::types::ChunkedUploadResponse> {
+        let url = format!(
+            "/v2.1/accounts/{}/chunked_uploads/{}/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(chunked_upload_id),
+            self.client.encode_path(chunked_upload_part_seq),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| crate::utils::pretty_url("PUT", "/v2.1/accounts/{accountId}/chunked_uploads/{chunkedUploadId}/{chunkedUploadPartSeq}", &[("accountId", account_id.to_string()), ("chunkedUploadId", chunked_upload_id.to_string()), ("chunkedUploadPartSeq", chunked_upload_part_seq.to_string())]))
+    }
+}
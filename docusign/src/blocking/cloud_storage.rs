@@ -0,0 +1,127 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct CloudStorage {
+    pub client: Client,
+}
+
+impl CloudStorage {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        CloudStorage { client }
+    }
+
+    /// The blocking form of [`crate::cloud_storage::CloudStorage::folder_get_all`].
+    pub fn folder_get_all(
+        &self,
+        account_id: &str,
+        service_id: &str,
+        user_id: &str,
+        cloud_storage_folder_path: &str,
+        count: &str,
+        order: &str,
+        order_by: &str,
+        search_text: &str,
+        start_position: &str,
+    ) -> Result<crate::types::ExternalFolder> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !cloud_storage_folder_path.is_empty() {
+            query_args.push((
+                "cloud_storage_folder_path",
+                cloud_storage_folder_path.to_string(),
+            ));
+        }
+        if !count.is_empty() {
+            query_args.push(("count", count.to_string()));
+        }
+        if !order.is_empty() {
+            query_args.push(("order", order.to_string()));
+        }
+        if !order_by.is_empty() {
+            query_args.push(("order_by", order_by.to_string()));
+        }
+        if !search_text.is_empty() {
+            query_args.push(("search_text", search_text.to_string()));
+        }
+        if !start_position.is_empty() {
+            query_args.push(("start_position", start_position.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/users/{}/cloud_storage/{}/folders",
+                self.client.encode_path(account_id),
+                self.client.encode_path(user_id),
+                self.client.encode_path(service_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/users/{userId}/cloud_storage/{serviceId}/folders",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("userId", user_id.to_string()),
+                    ("serviceId", service_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::cloud_storage::CloudStorage::folder_get`].
+    pub fn folder_get(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        service_id: &str,
+        user_id: &str,
+        cloud_storage_folder_path: &str,
+        cloud_storage_folderid_plain: &str,
+        count: &str,
+        order: &str,
+        order_by: &str,
+        search_text: &str,
+        start_position: &str,
+    ) -> Result<crate::types::ExternalFolder> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !cloud_storage_folder_path.is_empty() {
+            query_args.push((
+                "cloud_storage_folder_path",
+                cloud_storage_folder_path.to_string(),
+            ));
+        }
+        if !cloud_storage_folderid_plain.is_empty() {
+            query_args.push((
+                "cloud_storage_folderid_plain",
+                cloud_storage_folderid_plain.to_string(),
+            ));
+        }
+        if !count.is_empty() {
+            query_args.push(("count", count.to_string()));
+        }
+        if !order.is_empty() {
+            query_args.push(("order", order.to_string()));
+        }
+        if !order_by.is_empty() {
+            query_args.push(("order_by", order_by.to_string()));
+        }
+        if !search_text.is_empty() {
+            query_args.push(("search_text", search_text.to_string()));
+        }
+        if !start_position.is_empty() {
+            query_args.push(("start_position", start_position.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/users/{}/cloud_storage/{}/folders/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(user_id),
+                self.client.encode_path(service_id),
+                self.client.encode_path(folder_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/users/{userId}/cloud_storage/{serviceId}/folders/{folderId}", &[("accountId", account_id.to_string()), ("userId", user_id.to_string()), ("serviceId", service_id.to_string()), ("folderId", folder_id.to_string())]))
+    }
+}
@@ -0,0 +1,160 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct CloudStorageProviders {
+    pub client: Client,
+}
+
+impl CloudStorageProviders {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        CloudStorageProviders { client }
+    }
+
+    /// The blocking form of [`crate::cloud_storage_providers::CloudStorageProviders::cloud_storage_get_provider`].
+    pub fn cloud_storage_get_provider(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        redirect_url: &str,
+    ) -> Result<crate::types::CloudStorageProvidersData> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !redirect_url.is_empty() {
+            query_args.push(("redirectUrl", redirect_url.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/users/{}/cloud_storage",
+                self.client.encode_path(account_id),
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/users/{userId}/cloud_storage",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("userId", user_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::cloud_storage_providers::CloudStorageProviders::cloud_storage_post`].
+    pub fn cloud_storage_post(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::CloudStorageProvidersData,
+    ) -> Result<crate::types::CloudStorageProvidersData> {
+        let url = format!(
+            "/v2.1/accounts/{}/users/{}/cloud_storage",
+            self.client.encode_path(account_id),
+            self.client.encode_path(user_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/users/{userId}/cloud_storage",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("userId", user_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::cloud_storage_providers::CloudStorageProviders::cloud_storage_delete_providers`].
+    pub fn cloud_storage_delete_providers(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::CloudStorageProvidersData,
+    ) -> Result<crate::types::CloudStorageProvidersData> {
+        let url = format!(
+            "/v2.1/accounts/{}/users/{}/cloud_storage",
+            self.client.encode_path(account_id),
+            self.client.encode_path(user_id),
+        );
+
+        self.client
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/users/{userId}/cloud_storage",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("userId", user_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::cloud_storage_providers::CloudStorageProviders::cloud_storage_get`].
+    pub fn cloud_storage_get(
+        &self,
+        account_id: &str,
+        service_id: &str,
+        user_id: &str,
+        redirect_url: &str,
+    ) -> Result<crate::types::CloudStorageProvidersData> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !redirect_url.is_empty() {
+            query_args.push(("redirectUrl", redirect_url.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/users/{}/cloud_storage/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(user_id),
+                self.client.encode_path(service_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/users/{userId}/cloud_storage/{serviceId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("userId", user_id.to_string()),
+                    ("serviceId", service_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::cloud_storage_providers::CloudStorageProviders::cloud_storage_delete`].
+    pub fn cloud_storage_delete(
+        &self,
+        account_id: &str,
+        service_id: &str,
+        user_id: &str,
+    ) -> Result<crate::types::CloudStorageProvidersData> {
+        let url = format!(
+            "/v2.1/accounts/{}/users/{}/cloud_storage/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(user_id),
+            self.client.encode_path(service_id),
+        );
+
+        self.client.delete(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/users/{userId}/cloud_storage/{serviceId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("userId", user_id.to_string()),
+                    ("serviceId", service_id.to_string()),
+                ],
+            )
+        })
+    }
+}
@@ -0,0 +1,46 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct Comments {
+    pub client: Client,
+}
+
+impl Comments {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        Comments { client }
+    }
+
+    /// The blocking form of [`crate::comments::Comments::get_transcript`].
+    pub fn get_transcript(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        encoding: &str,
+    ) -> Result<()> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !encoding.is_empty() {
+            query_args.push(("encoding", encoding.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/comments/transcript",
+                self.client.encode_path(account_id),
+                self.client.encode_path(envelope_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .request_with_accept_mime(reqwest::Method::GET, &url, "application/pdf")
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/comments/transcript",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
@@ -0,0 +1,195 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct ConnectConfigurations {
+    pub client: Client,
+}
+
+impl ConnectConfigurations {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        ConnectConfigurations { client }
+    }
+
+    /// The blocking form of [`crate::connect_configurations::ConnectConfigurations::connect_get_config`].
+    pub fn connect_get_config(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::ConnectConfigResults> {
+        let url = format!(
+            "/v2.1/accounts/{}/connect",
+            self.client.encode_path(account_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/connect",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::connect_configurations::ConnectConfigurations::connect_put_configuration`].
+    pub fn connect_put_configuration(
+        &self,
+        account_id: &str,
+        body: &crate::types::ConnectCustomConfiguration,
+    ) -> Result<crate::types::ConnectCustomConfiguration> {
+        let url = format!(
+            "/v2.1/accounts/{}/connect",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/connect",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::connect_configurations::ConnectConfigurations::connect_post_configuration`].
+    pub fn connect_post_configuration(
+        &self,
+        account_id: &str,
+        body: &crate::types::ConnectCustomConfiguration,
+    ) -> Result<crate::types::ConnectCustomConfiguration> {
+        let url = format!(
+            "/v2.1/accounts/{}/connect",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/connect",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::connect_configurations::ConnectConfigurations::connect_get_config_connect_configurations`].
+    pub fn connect_get_config_connect_configurations(
+        &self,
+        account_id: &str,
+        connect_id: &str,
+    ) -> Result<crate::types::ConnectConfigResults> {
+        let url = format!(
+            "/v2.1/accounts/{}/connect/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(connect_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/connect/{connectId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("connectId", connect_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::connect_configurations::ConnectConfigurations::connect_delete_config`].
+    pub fn connect_delete_config(&self, account_id: &str, connect_id: &str) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/connect/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(connect_id),
+        );
+
+        self.client.delete(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/connect/{connectId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("connectId", connect_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::connect_configurations::ConnectConfigurations::connect_delete_config_if_exists`].
+    pub fn connect_delete_config_if_exists(
+        &self,
+        account_id: &str,
+        connect_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/connect/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(connect_id),
+        );
+
+        self.client.delete_if_exists(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/connect/{connectId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("connectId", connect_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::connect_configurations::ConnectConfigurations::connect_get_user`].
+    pub fn connect_get_user(
+        &self,
+        account_id: &str,
+        connect_id: &str,
+        count: &str,
+        email_substring: &str,
+        list_included_users: &str,
+        start_position: &str,
+        status: &str,
+        user_name_substring: &str,
+    ) -> Result<crate::types::IntegratedUserInfoList> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !count.is_empty() {
+            query_args.push(("count", count.to_string()));
+        }
+        if !email_substring.is_empty() {
+            query_args.push(("email_substring", email_substring.to_string()));
+        }
+        if !list_included_users.is_empty() {
+            query_args.push(("list_included_users", list_included_users.to_string()));
+        }
+        if !start_position.is_empty() {
+            query_args.push(("start_position", start_position.to_string()));
+        }
+        if !status.is_empty() {
+            query_args.push(("status", status.to_string()));
+        }
+        if !user_name_substring.is_empty() {
+            query_args.push(("user_name_substring", user_name_substring.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/connect/{}/users",
+                self.client.encode_path(account_id),
+                self.client.encode_path(connect_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/connect/{connectId}/users",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("connectId", connect_id.to_string()),
+                ],
+            )
+        })
+    }
+}
@@ -0,0 +1,272 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct ConnectEvents {
+    pub client: Client,
+}
+
+impl ConnectEvents {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        ConnectEvents { client }
+    }
+
+    /// The blocking form of [`crate::connect_events::ConnectEvents::connect_publish_put_retry`].
+    pub fn connect_publish_put_retry(
+        &self,
+        account_id: &str,
+        body: &crate::types::ConnectFailureFilter,
+    ) -> Result<crate::types::ConnectFailureResults> {
+        let url = format!(
+            "/v2.1/accounts/{}/connect/envelopes/retry_queue",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/connect/envelopes/retry_queue",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::connect_events::ConnectEvents::connect_publish_put_retry_envelope`].
+    pub fn connect_publish_put_retry_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::ConnectFailureResults> {
+        let url = format!(
+            "/v2.1/accounts/{}/connect/envelopes/{}/retry_queue",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client.put(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "PUT",
+                "/v2.1/accounts/{accountId}/connect/envelopes/{envelopeId}/retry_queue",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::connect_events::ConnectEvents::connect_failures_get_log`].
+    pub fn connect_failures_get_log(
+        &self,
+        account_id: &str,
+        from_date: &str,
+        to_date: &str,
+    ) -> Result<crate::types::ConnectLogs> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from_date.is_empty() {
+            query_args.push(("from_date", from_date.to_string()));
+        }
+        if !to_date.is_empty() {
+            query_args.push(("to_date", to_date.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/connect/failures",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/connect/failures",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::connect_events::ConnectEvents::connect_failures_delete_failure_log`].
+    pub fn connect_failures_delete_failure_log(
+        &self,
+        account_id: &str,
+        failure_id: &str,
+    ) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/connect/failures/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(failure_id),
+        );
+
+        self.client.delete(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/connect/failures/{failureId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("failureId", failure_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::connect_events::ConnectEvents::connect_failures_delete_failure_log_if_exists`].
+    pub fn connect_failures_delete_failure_log_if_exists(
+        &self,
+        account_id: &str,
+        failure_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/connect/failures/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(failure_id),
+        );
+
+        self.client.delete_if_exists(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/connect/failures/{failureId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("failureId", failure_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::connect_events::ConnectEvents::connect_log_get_log`].
+    pub fn connect_log_get_log(
+        &self,
+        account_id: &str,
+        from_date: &str,
+        to_date: &str,
+    ) -> Result<crate::types::ConnectLogs> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from_date.is_empty() {
+            query_args.push(("from_date", from_date.to_string()));
+        }
+        if !to_date.is_empty() {
+            query_args.push(("to_date", to_date.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/connect/logs",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/connect/logs",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::connect_events::ConnectEvents::connect_log_delete_logs`].
+    pub fn connect_log_delete_logs(&self, account_id: &str) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/connect/logs",
+            self.client.encode_path(account_id),
+        );
+
+        self.client.delete(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/connect/logs",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::connect_events::ConnectEvents::connect_log_delete_logs_if_exists`].
+    pub fn connect_log_delete_logs_if_exists(&self, account_id: &str) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/connect/logs",
+            self.client.encode_path(account_id),
+        );
+
+        self.client.delete_if_exists(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/connect/logs",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::connect_events::ConnectEvents::connect_log_get`].
+    pub fn connect_log_get(
+        &self,
+        account_id: &str,
+        log_id: &str,
+        additional_info: &str,
+    ) -> Result<crate::types::ConnectLog> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !additional_info.is_empty() {
+            query_args.push(("additional_info", additional_info.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/connect/logs/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(log_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/connect/logs/{logId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("logId", log_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::connect_events::ConnectEvents::connect_log_delete`].
+    pub fn connect_log_delete(&self, account_id: &str, log_id: &str) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/connect/logs/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(log_id),
+        );
+
+        self.client.delete(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/connect/logs/{logId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("logId", log_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::connect_events::ConnectEvents::connect_log_delete_if_exists`].
+    pub fn connect_log_delete_if_exists(&self, account_id: &str, log_id: &str) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/connect/logs/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(log_id),
+        );
+
+        self.client.delete_if_exists(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/connect/logs/{logId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("logId", log_id.to_string()),
+                ],
+            )
+        })
+    }
+}
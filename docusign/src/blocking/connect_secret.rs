@@ -0,0 +1,56 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct ConnectSecret {
+    pub client: Client,
+}
+
+impl ConnectSecret {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        ConnectSecret { client }
+    }
+
+    /// The blocking form of [`crate::connect_secret::ConnectSecret::connect_hmac_delete_secret`].
+    pub fn connect_hmac_delete_secret(&self, account_id: &str, key_id: &str) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/connect/secret/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(key_id),
+        );
+
+        self.client.delete(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/connect/secret/{keyId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("keyId", key_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::connect_secret::ConnectSecret::connect_hmac_delete_secret_if_exists`].
+    pub fn connect_hmac_delete_secret_if_exists(
+        &self,
+        account_id: &str,
+        key_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/connect/secret/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(key_id),
+        );
+
+        self.client.delete_if_exists(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/connect/secret/{keyId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("keyId", key_id.to_string()),
+                ],
+            )
+        })
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct Contacts {
+    pub client: Client,
+}
+
+impl Contacts {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        Contacts { client }
+    }
+
+    /// The blocking form of [`crate::contacts::Contacts::put`].
+    pub fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::ContactModRequest,
+    ) -> Result<crate::types::ContactUpdateResponse> {
+        let url = format!(
+            "/v2.1/accounts/{}/contacts",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/contacts",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::contacts::Contacts::post`].
+    pub fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::ContactModRequest,
+    ) -> Result<crate::types::ContactUpdateResponse> {
+        let url = format!(
+            "/v2.1/accounts/{}/contacts",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/contacts",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::contacts::Contacts::delete`].
+    pub fn delete(
+        &self,
+        account_id: &str,
+        body: &crate::types::ContactModRequest,
+    ) -> Result<crate::types::ContactUpdateResponse> {
+        let url = format!(
+            "/v2.1/accounts/{}/contacts",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/contacts",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::contacts::Contacts::get`].
+    pub fn get(
+        &self,
+        account_id: &str,
+        contact_id: &str,
+        cloud_provider: &str,
+    ) -> Result<crate::types::ContactGetResponse> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !cloud_provider.is_empty() {
+            query_args.push(("cloud_provider", cloud_provider.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/contacts/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(contact_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/contacts/{contactId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("contactId", contact_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::contacts::Contacts::delete_contacts`].
+    pub fn delete_contacts(
+        &self,
+        account_id: &str,
+        contact_id: &str,
+    ) -> Result<crate::types::ContactUpdateResponse> {
+        let url = format!(
+            "/v2.1/accounts/{}/contacts/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(contact_id),
+        );
+
+        self.client.delete(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/contacts/{contactId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("contactId", contact_id.to_string()),
+                ],
+            )
+        })
+    }
+}
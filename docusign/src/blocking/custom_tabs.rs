@@ -0,0 +1,156 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct CustomTabs {
+    pub client: Client,
+}
+
+impl CustomTabs {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        CustomTabs { client }
+    }
+
+    /// The blocking form of [`crate::custom_tabs::CustomTabs::tabs_get_tab_definition`].
+    pub fn tabs_get_tab_definition(
+        &self,
+        account_id: &str,
+        custom_tab_only: &str,
+    ) -> Result<crate::types::TabMetadataList> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !custom_tab_only.is_empty() {
+            query_args.push(("custom_tab_only", custom_tab_only.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/tab_definitions",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/tab_definitions",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::custom_tabs::CustomTabs::tabs_post_tab_definitions`].
+    pub fn tabs_post_tab_definitions(
+        &self,
+        account_id: &str,
+        body: &crate::types::TabMetadata,
+    ) -> Result<crate::types::TabMetadata> {
+        let url = format!(
+            "/v2.1/accounts/{}/tab_definitions",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/tab_definitions",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::custom_tabs::CustomTabs::tab_get_custom`].
+    pub fn tab_get_custom(
+        &self,
+        account_id: &str,
+        custom_tab_id: &str,
+    ) -> Result<crate::types::TabMetadata> {
+        let url = format!(
+            "/v2.1/accounts/{}/tab_definitions/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(custom_tab_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/tab_definitions/{customTabId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("customTabId", custom_tab_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::custom_tabs::CustomTabs::tab_put_custom`].
+    pub fn tab_put_custom(
+        &self,
+        account_id: &str,
+        custom_tab_id: &str,
+        body: &crate::types::TabMetadata,
+    ) -> Result<crate::types::TabMetadata> {
+        let url = format!(
+            "/v2.1/accounts/{}/tab_definitions/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(custom_tab_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/tab_definitions/{customTabId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("customTabId", custom_tab_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::custom_tabs::CustomTabs::tab_delete_custom`].
+    pub fn tab_delete_custom(&self, account_id: &str, custom_tab_id: &str) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/tab_definitions/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(custom_tab_id),
+        );
+
+        self.client.delete(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/tab_definitions/{customTabId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("customTabId", custom_tab_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::custom_tabs::CustomTabs::tab_delete_custom_if_exists`].
+    pub fn tab_delete_custom_if_exists(
+        &self,
+        account_id: &str,
+        custom_tab_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/tab_definitions/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(custom_tab_id),
+        );
+
+        self.client.delete_if_exists(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/tab_definitions/{customTabId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("customTabId", custom_tab_id.to_string()),
+                ],
+            )
+        })
+    }
+}
@@ -0,0 +1,32 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct DocumentResponsiveHtmlPreview {
+    pub client: Client,
+}
+
+impl DocumentResponsiveHtmlPreview {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        DocumentResponsiveHtmlPreview { client }
+    }
+
+    /// The blocking form of [`crate::document_responsive_html_preview::DocumentResponsiveHtmlPreview::responsive_html_post_document_preview`].
+    pub fn responsive_html_post_document_preview(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::DocumentHtmlDefinition,
+    ) -> Result<crate::types::DocumentHtmlDefinitions> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/documents/{}/responsive_html_preview",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(document_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| crate::utils::pretty_url("POST", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/responsive_html_preview", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("documentId", document_id.to_string())]))
+    }
+}
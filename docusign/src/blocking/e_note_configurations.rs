@@ -0,0 +1,82 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct ENoteConfigurations {
+    pub client: Client,
+}
+
+impl ENoteConfigurations {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        ENoteConfigurations { client }
+    }
+
+    /// The blocking form of [`crate::e_note_configurations::ENoteConfigurations::get`].
+    pub fn get(&self, account_id: &str) -> Result<crate::types::ENoteConfiguration> {
+        let url = format!(
+            "/v2.1/accounts/{}/settings/enote_configuration",
+            self.client.encode_path(account_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/settings/enote_configuration",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::e_note_configurations::ENoteConfigurations::put`].
+    pub fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::ENoteConfiguration,
+    ) -> Result<crate::types::ENoteConfiguration> {
+        let url = format!(
+            "/v2.1/accounts/{}/settings/enote_configuration",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/settings/enote_configuration",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::e_note_configurations::ENoteConfigurations::delete`].
+    pub fn delete(&self, account_id: &str) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/settings/enote_configuration",
+            self.client.encode_path(account_id),
+        );
+
+        self.client.delete(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/settings/enote_configuration",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::e_note_configurations::ENoteConfigurations::delete_if_exists`].
+    pub fn delete_if_exists(&self, account_id: &str) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/settings/enote_configuration",
+            self.client.encode_path(account_id),
+        );
+
+        self.client.delete_if_exists(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/settings/enote_configuration",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+}
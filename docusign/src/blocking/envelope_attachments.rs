@@ -0,0 +1,147 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct EnvelopeAttachments {
+    pub client: Client,
+}
+
+impl EnvelopeAttachments {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        EnvelopeAttachments { client }
+    }
+
+    /// The blocking form of [`crate::envelope_attachments::EnvelopeAttachments::attachments_get`].
+    pub fn attachments_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EnvelopeAttachmentsResult> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/attachments",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/attachments",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::envelope_attachments::EnvelopeAttachments::attachments_put`].
+    pub fn attachments_put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeAttachmentsRequest,
+    ) -> Result<crate::types::EnvelopeAttachmentsResult> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/attachments",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/attachments",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::envelope_attachments::EnvelopeAttachments::attachments_delete`].
+    pub fn attachments_delete(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeAttachmentsRequest,
+    ) -> Result<crate::types::EnvelopeAttachmentsResult> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/attachments",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/attachments",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::envelope_attachments::EnvelopeAttachments::attachments_get_attachment`].
+    pub fn attachments_get_attachment(
+        &self,
+        account_id: &str,
+        attachment_id: &str,
+        envelope_id: &str,
+    ) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/attachments/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(attachment_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/attachments/{attachmentId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                    ("attachmentId", attachment_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::envelope_attachments::EnvelopeAttachments::attachments_put_attachment`].
+    pub fn attachments_put_attachment(
+        &self,
+        account_id: &str,
+        attachment_id: &str,
+        envelope_id: &str,
+        body: &crate::types::Attachment,
+    ) -> Result<crate::types::EnvelopeAttachmentsResult> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/attachments/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(attachment_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/attachments/{attachmentId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                        ("attachmentId", attachment_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct EnvelopeConsumerDisclosures {
+    pub client: Client,
+}
+
+impl EnvelopeConsumerDisclosures {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        EnvelopeConsumerDisclosures { client }
+    }
+
+    /// The blocking form of [`crate::envelope_consumer_disclosures::EnvelopeConsumerDisclosures::consumer_disclosure_get_envelope_recipient`].
+    pub fn consumer_disclosure_get_envelope_recipient(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        lang_code: &str,
+    ) -> Result<crate::types::ConsumerDisclosure> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !lang_code.is_empty() {
+            query_args.push(("langCode", lang_code.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients/{}/consumer_disclosure",
+                self.client.encode_path(account_id),
+                self.client.encode_path(envelope_id),
+                self.client.encode_path(recipient_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/consumer_disclosure", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("recipientId", recipient_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::envelope_consumer_disclosures::EnvelopeConsumerDisclosures::consumer_disclosure_get_envelope_recipient_lang_code`].
+    pub fn consumer_disclosure_get_envelope_recipient_lang_code(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        lang_code: &str,
+        recipient_id: &str,
+    ) -> Result<crate::types::ConsumerDisclosure> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/recipients/{}/consumer_disclosure/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(recipient_id),
+            self.client.encode_path(lang_code),
+        );
+
+        self.client.get(&url, None).with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/consumer_disclosure/{langCode}", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("recipientId", recipient_id.to_string()), ("langCode", lang_code.to_string())]))
+    }
+}
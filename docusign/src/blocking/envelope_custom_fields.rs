@@ -0,0 +1,117 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct EnvelopeCustomFields {
+    pub client: Client,
+}
+
+impl EnvelopeCustomFields {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        EnvelopeCustomFields { client }
+    }
+
+    /// The blocking form of [`crate::envelope_custom_fields::EnvelopeCustomFields::custom_fields_get`].
+    pub fn custom_fields_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::CustomFieldsEnvelope> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/custom_fields",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/custom_fields",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::envelope_custom_fields::EnvelopeCustomFields::custom_fields_put`].
+    pub fn custom_fields_put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeCustomFields,
+    ) -> Result<crate::types::EnvelopeCustomFields> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/custom_fields",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/custom_fields",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::envelope_custom_fields::EnvelopeCustomFields::custom_fields_post`].
+    pub fn custom_fields_post(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeCustomFields,
+    ) -> Result<crate::types::EnvelopeCustomFields> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/custom_fields",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/custom_fields",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::envelope_custom_fields::EnvelopeCustomFields::custom_fields_delete`].
+    pub fn custom_fields_delete(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeCustomFields,
+    ) -> Result<crate::types::EnvelopeCustomFields> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/custom_fields",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/custom_fields",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
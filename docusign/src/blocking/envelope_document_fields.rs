@@ -0,0 +1,99 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct EnvelopeDocumentFields {
+    pub client: Client,
+}
+
+impl EnvelopeDocumentFields {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        EnvelopeDocumentFields { client }
+    }
+
+    /// The blocking form of [`crate::envelope_document_fields::EnvelopeDocumentFields::document_fields_get`].
+    pub fn document_fields_get(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EnvelopeDocumentFields> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/documents/{}/fields",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(document_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/fields",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                    ("documentId", document_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::envelope_document_fields::EnvelopeDocumentFields::document_fields_put`].
+    pub fn document_fields_put(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeDocumentFields,
+    ) -> Result<crate::types::EnvelopeDocumentFields> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/documents/{}/fields",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(document_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| crate::utils::pretty_url("PUT", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/fields", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("documentId", document_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::envelope_document_fields::EnvelopeDocumentFields::document_fields_post`].
+    pub fn document_fields_post(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeDocumentFields,
+    ) -> Result<crate::types::EnvelopeDocumentFields> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/documents/{}/fields",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(document_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| crate::utils::pretty_url("POST", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/fields", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("documentId", document_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::envelope_document_fields::EnvelopeDocumentFields::document_fields_delete`].
+    pub fn document_fields_delete(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeDocumentFields,
+    ) -> Result<crate::types::EnvelopeDocumentFields> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/documents/{}/fields",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(document_id),
+        );
+
+        self.client
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| crate::utils::pretty_url("DELETE", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/fields", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("documentId", document_id.to_string())]))
+    }
+}
@@ -0,0 +1,29 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct EnvelopeDocumentHtmlDefinitions {
+    pub client: Client,
+}
+
+impl EnvelopeDocumentHtmlDefinitions {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        EnvelopeDocumentHtmlDefinitions { client }
+    }
+
+    /// The blocking form of [`crate::envelope_document_html_definitions::EnvelopeDocumentHtmlDefinitions::responsive_html_get_envelope_document_definition`].
+    pub fn responsive_html_get_envelope_document_definition(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EnvelopeHtmlDefinitions> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/documents/{}/html_definitions",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(document_id),
+        );
+
+        self.client.get(&url, None).with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/html_definitions", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("documentId", document_id.to_string())]))
+    }
+}
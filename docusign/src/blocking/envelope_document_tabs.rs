@@ -0,0 +1,160 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct EnvelopeDocumentTabs {
+    pub client: Client,
+}
+
+impl EnvelopeDocumentTabs {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        EnvelopeDocumentTabs { client }
+    }
+
+    /// The blocking form of [`crate::envelope_document_tabs::EnvelopeDocumentTabs::tabs_get_page`].
+    pub fn tabs_get_page(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+    ) -> Result<crate::types::EnvelopeDocumentTabs> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/documents/{}/pages/{}/tabs",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(document_id),
+            self.client.encode_path(page_number),
+        );
+
+        self.client.get(&url, None).with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/pages/{pageNumber}/tabs", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("documentId", document_id.to_string()), ("pageNumber", page_number.to_string())]))
+    }
+
+    /// The blocking form of [`crate::envelope_document_tabs::EnvelopeDocumentTabs::tabs_get_document`].
+    pub fn tabs_get_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        include_metadata: &str,
+        page_numbers: &str,
+    ) -> Result<crate::types::EnvelopeDocumentTabs> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !include_metadata.is_empty() {
+            query_args.push(("include_metadata", include_metadata.to_string()));
+        }
+        if !page_numbers.is_empty() {
+            query_args.push(("page_numbers", page_numbers.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}/tabs",
+                self.client.encode_path(account_id),
+                self.client.encode_path(envelope_id),
+                self.client.encode_path(document_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/tabs",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                    ("documentId", document_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::envelope_document_tabs::EnvelopeDocumentTabs::tabs_put_document`].
+    pub fn tabs_put_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::Tabs,
+    ) -> Result<crate::types::Tabs> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/documents/{}/tabs",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(document_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/tabs",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                        ("documentId", document_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::envelope_document_tabs::EnvelopeDocumentTabs::tabs_post_document`].
+    pub fn tabs_post_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::Tabs,
+    ) -> Result<crate::types::Tabs> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/documents/{}/tabs",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(document_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/tabs",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                        ("documentId", document_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::envelope_document_tabs::EnvelopeDocumentTabs::tabs_delete_document`].
+    pub fn tabs_delete_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::Tabs,
+    ) -> Result<crate::types::Tabs> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/documents/{}/tabs",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(document_id),
+        );
+
+        self.client
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/tabs",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                        ("documentId", document_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
@@ -0,0 +1,49 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct EnvelopeDocumentVisibility {
+    pub client: Client,
+}
+
+impl EnvelopeDocumentVisibility {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        EnvelopeDocumentVisibility { client }
+    }
+
+    /// The blocking form of [`crate::envelope_document_visibility::EnvelopeDocumentVisibility::recipients_get_recipient_document_visibility`].
+    pub fn recipients_get_recipient_document_visibility(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> Result<crate::types::DocumentVisibilityList> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/recipients/{}/document_visibility",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(recipient_id),
+        );
+
+        self.client.get(&url, None).with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/document_visibility", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("recipientId", recipient_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::envelope_document_visibility::EnvelopeDocumentVisibility::recipients_put_recipient_document_visibility`].
+    pub fn recipients_put_recipient_document_visibility(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        body: &crate::types::DocumentVisibilityList,
+    ) -> Result<crate::types::DocumentVisibilityList> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/recipients/{}/document_visibility",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(recipient_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| crate::utils::pretty_url("PUT", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/document_visibility", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("recipientId", recipient_id.to_string())]))
+    }
+}
@@ -0,0 +1,214 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct EnvelopeDocuments {
+    pub client: Client,
+}
+
+impl EnvelopeDocuments {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        EnvelopeDocuments { client }
+    }
+
+    /// The blocking form of [`crate::envelope_documents::EnvelopeDocuments::documents_get`].
+    pub fn documents_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        documents_by_userid: &str,
+        include_document_size: &str,
+        include_metadata: &str,
+        include_tabs: &str,
+        recipient_id: &str,
+        shared_user_id: &str,
+    ) -> Result<crate::types::EnvelopeDocumentsResult> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !documents_by_userid.is_empty() {
+            query_args.push(("documents_by_userid", documents_by_userid.to_string()));
+        }
+        if !include_document_size.is_empty() {
+            query_args.push(("include_document_size", include_document_size.to_string()));
+        }
+        if !include_metadata.is_empty() {
+            query_args.push(("include_metadata", include_metadata.to_string()));
+        }
+        if !include_tabs.is_empty() {
+            query_args.push(("include_tabs", include_tabs.to_string()));
+        }
+        if !recipient_id.is_empty() {
+            query_args.push(("recipient_id", recipient_id.to_string()));
+        }
+        if !shared_user_id.is_empty() {
+            query_args.push(("shared_user_id", shared_user_id.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents",
+                self.client.encode_path(account_id),
+                self.client.encode_path(envelope_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::envelope_documents::EnvelopeDocuments::documents_put`].
+    pub fn documents_put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> Result<crate::types::EnvelopeDocumentsResult> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/documents",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::envelope_documents::EnvelopeDocuments::documents_delete`].
+    pub fn documents_delete(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> Result<crate::types::EnvelopeDocumentsResult> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/documents",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::envelope_documents::EnvelopeDocuments::documents_get_document`].
+    pub fn documents_get_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        certificate: &str,
+        documents_by_userid: &str,
+        encoding: &str,
+        encrypt: &str,
+        language: &str,
+        recipient_id: &str,
+        shared_user_id: &str,
+        show_changes: &str,
+        watermark: &str,
+    ) -> Result<()> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !certificate.is_empty() {
+            query_args.push(("certificate", certificate.to_string()));
+        }
+        if !documents_by_userid.is_empty() {
+            query_args.push(("documents_by_userid", documents_by_userid.to_string()));
+        }
+        if !encoding.is_empty() {
+            query_args.push(("encoding", encoding.to_string()));
+        }
+        if !encrypt.is_empty() {
+            query_args.push(("encrypt", encrypt.to_string()));
+        }
+        if !language.is_empty() {
+            query_args.push(("language", language.to_string()));
+        }
+        if !recipient_id.is_empty() {
+            query_args.push(("recipient_id", recipient_id.to_string()));
+        }
+        if !shared_user_id.is_empty() {
+            query_args.push(("shared_user_id", shared_user_id.to_string()));
+        }
+        if !show_changes.is_empty() {
+            query_args.push(("show_changes", show_changes.to_string()));
+        }
+        if !watermark.is_empty() {
+            query_args.push(("watermark", watermark.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(envelope_id),
+                self.client.encode_path(document_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .request_with_accept_mime(reqwest::Method::GET, &url, "application/pdf")
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                        ("documentId", document_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::envelope_documents::EnvelopeDocuments::documents_put_document`].
+    pub fn documents_put_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EnvelopeDocument> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/documents/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(document_id),
+        );
+
+        self.client.put(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "PUT",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                    ("documentId", document_id.to_string()),
+                ],
+            )
+        })
+    }
+}
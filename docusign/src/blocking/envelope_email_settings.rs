@@ -0,0 +1,114 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct EnvelopeEmailSettings {
+    pub client: Client,
+}
+
+impl EnvelopeEmailSettings {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        EnvelopeEmailSettings { client }
+    }
+
+    /// The blocking form of [`crate::envelope_email_settings::EnvelopeEmailSettings::email_settings_get`].
+    pub fn email_settings_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EmailSettings> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/email_settings",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/email_settings",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::envelope_email_settings::EnvelopeEmailSettings::email_settings_put`].
+    pub fn email_settings_put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EmailSettings,
+    ) -> Result<crate::types::EmailSettings> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/email_settings",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/email_settings",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::envelope_email_settings::EnvelopeEmailSettings::email_settings_post`].
+    pub fn email_settings_post(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EmailSettings,
+    ) -> Result<crate::types::EmailSettings> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/email_settings",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/email_settings",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::envelope_email_settings::EnvelopeEmailSettings::email_settings_delete`].
+    pub fn email_settings_delete(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EmailSettings> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/email_settings",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client.delete(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/email_settings",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
+    }
+}
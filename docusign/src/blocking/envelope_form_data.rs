@@ -0,0 +1,36 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct EnvelopeFormData {
+    pub client: Client,
+}
+
+impl EnvelopeFormData {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        EnvelopeFormData { client }
+    }
+
+    /// The blocking form of [`crate::envelope_form_data::EnvelopeFormData::form_data_get`].
+    pub fn form_data_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EnvelopeFormDataType> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/form_data",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/form_data",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
+    }
+}
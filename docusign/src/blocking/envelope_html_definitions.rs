@@ -0,0 +1,36 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct EnvelopeHtmlDefinitions {
+    pub client: Client,
+}
+
+impl EnvelopeHtmlDefinitions {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        EnvelopeHtmlDefinitions { client }
+    }
+
+    /// The blocking form of [`crate::envelope_html_definitions::EnvelopeHtmlDefinitions::responsive_html_get_envelope_definition`].
+    pub fn responsive_html_get_envelope_definition(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EnvelopeHtmlDefinitions> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/html_definitions",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/html_definitions",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct EnvelopeLocks {
+    pub client: Client,
+}
+
+impl EnvelopeLocks {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        EnvelopeLocks { client }
+    }
+
+    /// The blocking form of [`crate::envelope_locks::EnvelopeLocks::lock_get_envelope`].
+    pub fn lock_get_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EnvelopeLocks> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/lock",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/lock",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::envelope_locks::EnvelopeLocks::lock_put_envelope`].
+    pub fn lock_put_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::LockRequest,
+    ) -> Result<crate::types::EnvelopeLocks> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/lock",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/lock",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::envelope_locks::EnvelopeLocks::lock_post_envelope`].
+    pub fn lock_post_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::LockRequest,
+    ) -> Result<crate::types::EnvelopeLocks> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/lock",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/lock",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::envelope_locks::EnvelopeLocks::lock_delete_envelope`].
+    pub fn lock_delete_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EnvelopeLocks> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/lock",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client.delete(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/lock",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
+    }
+}
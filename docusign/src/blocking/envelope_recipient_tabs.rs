@@ -0,0 +1,114 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct EnvelopeRecipientTabs {
+    pub client: Client,
+}
+
+impl EnvelopeRecipientTabs {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        EnvelopeRecipientTabs { client }
+    }
+
+    /// The blocking form of [`crate::envelope_recipient_tabs::EnvelopeRecipientTabs::recipients_get_recipient_tab`].
+    pub fn recipients_get_recipient_tab(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        include_anchor_tab_locations: &str,
+        include_metadata: &str,
+    ) -> Result<crate::types::EnvelopeRecipientTabs> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !include_anchor_tab_locations.is_empty() {
+            query_args.push((
+                "include_anchor_tab_locations",
+                include_anchor_tab_locations.to_string(),
+            ));
+        }
+        if !include_metadata.is_empty() {
+            query_args.push(("include_metadata", include_metadata.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients/{}/tabs",
+                self.client.encode_path(account_id),
+                self.client.encode_path(envelope_id),
+                self.client.encode_path(recipient_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/tabs",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                    ("recipientId", recipient_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::envelope_recipient_tabs::EnvelopeRecipientTabs::recipients_put_recipient_tabs`].
+    pub fn recipients_put_recipient_tabs(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        body: &crate::types::EnvelopeRecipientTabs,
+    ) -> Result<crate::types::EnvelopeRecipientTabs> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/recipients/{}/tabs",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(recipient_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| crate::utils::pretty_url("PUT", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/tabs", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("recipientId", recipient_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::envelope_recipient_tabs::EnvelopeRecipientTabs::recipients_post_recipient_tabs`].
+    pub fn recipients_post_recipient_tabs(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        body: &crate::types::EnvelopeRecipientTabs,
+    ) -> Result<crate::types::EnvelopeRecipientTabs> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/recipients/{}/tabs",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(recipient_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| crate::utils::pretty_url("POST", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/tabs", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("recipientId", recipient_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::envelope_recipient_tabs::EnvelopeRecipientTabs::recipients_delete_recipient_tabs`].
+    pub fn recipients_delete_recipient_tabs(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        body: &crate::types::EnvelopeRecipientTabs,
+    ) -> Result<crate::types::EnvelopeRecipientTabs> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/recipients/{}/tabs",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(recipient_id),
+        );
+
+        self.client
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| crate::utils::pretty_url("DELETE", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/tabs", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("recipientId", recipient_id.to_string())]))
+    }
+}
@@ -0,0 +1,273 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct EnvelopeRecipients {
+    pub client: Client,
+}
+
+impl EnvelopeRecipients {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        EnvelopeRecipients { client }
+    }
+
+    /// The blocking form of [`crate::envelope_recipients::EnvelopeRecipients::recipients_get`].
+    pub fn recipients_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        include_anchor_tab_locations: &str,
+        include_extended: &str,
+        include_metadata: &str,
+        include_tabs: &str,
+    ) -> Result<crate::types::EnvelopeRecipients> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !include_anchor_tab_locations.is_empty() {
+            query_args.push((
+                "include_anchor_tab_locations",
+                include_anchor_tab_locations.to_string(),
+            ));
+        }
+        if !include_extended.is_empty() {
+            query_args.push(("include_extended", include_extended.to_string()));
+        }
+        if !include_metadata.is_empty() {
+            query_args.push(("include_metadata", include_metadata.to_string()));
+        }
+        if !include_tabs.is_empty() {
+            query_args.push(("include_tabs", include_tabs.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients",
+                self.client.encode_path(account_id),
+                self.client.encode_path(envelope_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::envelope_recipients::EnvelopeRecipients::recipients_put`].
+    pub fn recipients_put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        combine_same_order_recipients: &str,
+        offline_signing: &str,
+        resend_envelope: &str,
+        body: &crate::types::EnvelopeRecipients,
+    ) -> Result<crate::types::RecipientsUpdateSummary> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !combine_same_order_recipients.is_empty() {
+            query_args.push((
+                "combine_same_order_recipients",
+                combine_same_order_recipients.to_string(),
+            ));
+        }
+        if !offline_signing.is_empty() {
+            query_args.push(("offline_signing", offline_signing.to_string()));
+        }
+        if !resend_envelope.is_empty() {
+            query_args.push(("resend_envelope", resend_envelope.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients",
+                self.client.encode_path(account_id),
+                self.client.encode_path(envelope_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::envelope_recipients::EnvelopeRecipients::recipients_post`].
+    pub fn recipients_post(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        resend_envelope: &str,
+        body: &crate::types::EnvelopeRecipients,
+    ) -> Result<crate::types::EnvelopeRecipients> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !resend_envelope.is_empty() {
+            query_args.push(("resend_envelope", resend_envelope.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients",
+                self.client.encode_path(account_id),
+                self.client.encode_path(envelope_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::envelope_recipients::EnvelopeRecipients::recipients_delete`].
+    pub fn recipients_delete(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeRecipients,
+    ) -> Result<crate::types::EnvelopeRecipients> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/recipients",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::envelope_recipients::EnvelopeRecipients::recipients_put_document_visibility`].
+    pub fn recipients_put_document_visibility(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::DocumentVisibilityList,
+    ) -> Result<crate::types::DocumentVisibilityList> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/recipients/document_visibility",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| crate::utils::pretty_url("PUT", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/document_visibility", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::envelope_recipients::EnvelopeRecipients::recipients_delete_recipient`].
+    pub fn recipients_delete_recipient(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> Result<crate::types::EnvelopeRecipients> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/recipients/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(recipient_id),
+        );
+
+        self.client.delete(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                    ("recipientId", recipient_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::envelope_recipients::EnvelopeRecipients::recipients_post_recipient_proof_file_resource_token`].
+    pub fn recipients_post_recipient_proof_file_resource_token(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> Result<crate::types::ProofServiceResourceToken> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/recipients/{}/identity_proof_token",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(recipient_id),
+        );
+
+        self.client.post(&url, None).with_context(|| crate::utils::pretty_url("POST", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/identity_proof_token", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("recipientId", recipient_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::envelope_recipients::EnvelopeRecipients::views_post_recipient_manual_review_view`].
+    pub fn views_post_recipient_manual_review_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> Result<crate::types::ViewUrl> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/recipients/{}/views/identity_manual_review",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(recipient_id),
+        );
+
+        self.client.post(&url, None).with_context(|| crate::utils::pretty_url("POST", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/views/identity_manual_review", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("recipientId", recipient_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::envelope_recipients::EnvelopeRecipients::views_post_preview`].
+    pub fn views_post_preview(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::RecipientPreviewRequest,
+    ) -> Result<crate::types::ViewUrl> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/views/recipient_preview",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/views/recipient_preview",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
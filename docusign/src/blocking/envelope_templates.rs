@@ -0,0 +1,176 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct EnvelopeTemplates {
+    pub client: Client,
+}
+
+impl EnvelopeTemplates {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        EnvelopeTemplates { client }
+    }
+
+    /// The blocking form of [`crate::envelope_templates::EnvelopeTemplates::templates_get_document`].
+    pub fn templates_get_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        include: &str,
+    ) -> Result<crate::types::TemplateInformation> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !include.is_empty() {
+            query_args.push(("include", include.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}/templates",
+                self.client.encode_path(account_id),
+                self.client.encode_path(envelope_id),
+                self.client.encode_path(document_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/templates", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("documentId", document_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::envelope_templates::EnvelopeTemplates::templates_post_document`].
+    pub fn templates_post_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        preserve_template_recipient: &str,
+        body: &crate::types::DocumentTemplateList,
+    ) -> Result<crate::types::DocumentTemplateList> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !preserve_template_recipient.is_empty() {
+            query_args.push((
+                "preserve_template_recipient",
+                preserve_template_recipient.to_string(),
+            ));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}/templates",
+                self.client.encode_path(account_id),
+                self.client.encode_path(envelope_id),
+                self.client.encode_path(document_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| crate::utils::pretty_url("POST", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/templates", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("documentId", document_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::envelope_templates::EnvelopeTemplates::templates_delete_document`].
+    pub fn templates_delete_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        template_id: &str,
+    ) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/documents/{}/templates/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(document_id),
+            self.client.encode_path(template_id),
+        );
+
+        self.client.delete(&url, None).with_context(|| crate::utils::pretty_url("DELETE", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/templates/{templateId}", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("documentId", document_id.to_string()), ("templateId", template_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::envelope_templates::EnvelopeTemplates::templates_delete_document_if_exists`].
+    pub fn templates_delete_document_if_exists(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        template_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/documents/{}/templates/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(document_id),
+            self.client.encode_path(template_id),
+        );
+
+        self.client.delete_if_exists(&url, None).with_context(|| crate::utils::pretty_url("DELETE", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/templates/{templateId}", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("documentId", document_id.to_string()), ("templateId", template_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::envelope_templates::EnvelopeTemplates::templates_get_envelope`].
+    pub fn templates_get_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        include: &str,
+    ) -> Result<crate::types::TemplateInformation> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !include.is_empty() {
+            query_args.push(("include", include.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/templates",
+                self.client.encode_path(account_id),
+                self.client.encode_path(envelope_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/templates",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::envelope_templates::EnvelopeTemplates::templates_post_envelope`].
+    pub fn templates_post_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        preserve_template_recipient: &str,
+        body: &crate::types::DocumentTemplateList,
+    ) -> Result<crate::types::DocumentTemplateList> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !preserve_template_recipient.is_empty() {
+            query_args.push((
+                "preserve_template_recipient",
+                preserve_template_recipient.to_string(),
+            ));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/templates",
+                self.client.encode_path(account_id),
+                self.client.encode_path(envelope_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/templates",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
@@ -0,0 +1,167 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct EnvelopeTransferRules {
+    pub client: Client,
+}
+
+impl EnvelopeTransferRules {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        EnvelopeTransferRules { client }
+    }
+
+    /// The blocking form of [`crate::envelope_transfer_rules::EnvelopeTransferRules::get`].
+    pub fn get(
+        &self,
+        account_id: &str,
+        count: &str,
+        start_position: &str,
+    ) -> Result<crate::types::EnvelopeTransferRuleInformation> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !count.is_empty() {
+            query_args.push(("count", count.to_string()));
+        }
+        if !start_position.is_empty() {
+            query_args.push(("start_position", start_position.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/transfer_rules",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/transfer_rules",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::envelope_transfer_rules::EnvelopeTransferRules::put`].
+    pub fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::EnvelopeTransferRuleInformation,
+    ) -> Result<crate::types::EnvelopeTransferRuleInformation> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/transfer_rules",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/envelopes/transfer_rules",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::envelope_transfer_rules::EnvelopeTransferRules::post`].
+    pub fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::EnvelopeTransferRuleRequest,
+    ) -> Result<crate::types::EnvelopeTransferRuleInformation> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/transfer_rules",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/envelopes/transfer_rules",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::envelope_transfer_rules::EnvelopeTransferRules::put_rule`].
+    pub fn put_rule(
+        &self,
+        account_id: &str,
+        envelope_transfer_rule_id: &str,
+        body: &crate::types::EnvelopeTransferRule,
+    ) -> Result<crate::types::EnvelopeTransferRule> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/transfer_rules/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_transfer_rule_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/envelopes/transfer_rules/{envelopeTransferRuleId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        (
+                            "envelopeTransferRuleId",
+                            envelope_transfer_rule_id.to_string(),
+                        ),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::envelope_transfer_rules::EnvelopeTransferRules::delete`].
+    pub fn delete(&self, account_id: &str, envelope_transfer_rule_id: &str) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/transfer_rules/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_transfer_rule_id),
+        );
+
+        self.client.delete(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/envelopes/transfer_rules/{envelopeTransferRuleId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    (
+                        "envelopeTransferRuleId",
+                        envelope_transfer_rule_id.to_string(),
+                    ),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::envelope_transfer_rules::EnvelopeTransferRules::delete_if_exists`].
+    pub fn delete_if_exists(
+        &self,
+        account_id: &str,
+        envelope_transfer_rule_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/transfer_rules/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_transfer_rule_id),
+        );
+
+        self.client.delete_if_exists(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/envelopes/transfer_rules/{envelopeTransferRuleId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    (
+                        "envelopeTransferRuleId",
+                        envelope_transfer_rule_id.to_string(),
+                    ),
+                ],
+            )
+        })
+    }
+}
@@ -0,0 +1,223 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct EnvelopeViews {
+    pub client: Client,
+}
+
+impl EnvelopeViews {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        EnvelopeViews { client }
+    }
+
+    /// The blocking form of [`crate::envelope_views::EnvelopeViews::views_post_envelope_correct_view`].
+    pub fn views_post_envelope_correct_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::CorrectViewRequest,
+    ) -> Result<crate::types::EnvelopeViews> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/views/correct",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/views/correct",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::envelope_views::EnvelopeViews::views_delete_envelope_correct_view`].
+    pub fn views_delete_envelope_correct_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::CorrectViewRequest,
+    ) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/views/correct",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/views/correct",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::envelope_views::EnvelopeViews::views_delete_envelope_correct_view_if_exists`].
+    pub fn views_delete_envelope_correct_view_if_exists(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::CorrectViewRequest,
+    ) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/views/correct",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/views/correct",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::envelope_views::EnvelopeViews::views_post_envelope_edit_view`].
+    pub fn views_post_envelope_edit_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::ReturnUrlRequest,
+    ) -> Result<crate::types::EnvelopeViews> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/views/edit",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/views/edit",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::envelope_views::EnvelopeViews::views_post_envelope_recipient_view`].
+    pub fn views_post_envelope_recipient_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::RecipientViewRequest,
+    ) -> Result<crate::types::EnvelopeViews> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/views/recipient",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/views/recipient",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::envelope_views::EnvelopeViews::views_post_envelope_sender_view`].
+    pub fn views_post_envelope_sender_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::ReturnUrlRequest,
+    ) -> Result<crate::types::EnvelopeViews> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/views/sender",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/views/sender",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::envelope_views::EnvelopeViews::views_post_envelope_recipient_shared_view`].
+    pub fn views_post_envelope_recipient_shared_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::RecipientViewRequest,
+    ) -> Result<crate::types::ViewUrl> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/views/shared",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/views/shared",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::envelope_views::EnvelopeViews::views_post_account_console_view`].
+    pub fn views_post_account_console_view(
+        &self,
+        account_id: &str,
+        body: &crate::types::ConsoleViewRequest,
+    ) -> Result<crate::types::EnvelopeViews> {
+        let url = format!(
+            "/v2.1/accounts/{}/views/console",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/views/console",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+}
@@ -0,0 +1,190 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct EnvelopeWorkflowDefinition {
+    pub client: Client,
+}
+
+impl EnvelopeWorkflowDefinition {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        EnvelopeWorkflowDefinition { client }
+    }
+
+    /// The blocking form of [`crate::envelope_workflow_definition::EnvelopeWorkflowDefinition::get`].
+    pub fn get(&self, account_id: &str, envelope_id: &str) -> Result<crate::types::Workflow> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/workflow",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/workflow",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::envelope_workflow_definition::EnvelopeWorkflowDefinition::put`].
+    pub fn put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::Workflow,
+    ) -> Result<crate::types::Workflow> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/workflow",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/workflow",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::envelope_workflow_definition::EnvelopeWorkflowDefinition::delete`].
+    pub fn delete(&self, account_id: &str, envelope_id: &str) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/workflow",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client.delete(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/workflow",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::envelope_workflow_definition::EnvelopeWorkflowDefinition::delete_if_exists`].
+    pub fn delete_if_exists(&self, account_id: &str, envelope_id: &str) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/workflow",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client.delete_if_exists(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/workflow",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::envelope_workflow_definition::EnvelopeWorkflowDefinition::get_template`].
+    pub fn get_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::Workflow> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/workflow",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/templates/{templateId}/workflow",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("templateId", template_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::envelope_workflow_definition::EnvelopeWorkflowDefinition::put_template`].
+    pub fn put_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::Workflow,
+    ) -> Result<crate::types::Workflow> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/workflow",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/workflow",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::envelope_workflow_definition::EnvelopeWorkflowDefinition::delete_template`].
+    pub fn delete_template(&self, account_id: &str, template_id: &str) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/workflow",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+        );
+
+        self.client.delete(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/templates/{templateId}/workflow",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("templateId", template_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::envelope_workflow_definition::EnvelopeWorkflowDefinition::delete_template_if_exists`].
+    pub fn delete_template_if_exists(&self, account_id: &str, template_id: &str) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/workflow",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+        );
+
+        self.client.delete_if_exists(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/templates/{templateId}/workflow",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("templateId", template_id.to_string()),
+                ],
+            )
+        })
+    }
+}
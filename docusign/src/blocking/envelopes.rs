@@ -0,0 +1,692 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct Envelopes {
+    pub client: Client,
+}
+
+impl Envelopes {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        Envelopes { client }
+    }
+
+    /// The blocking form of [`crate::envelopes::Envelopes::get`].
+    pub fn get(
+        &self,
+        account_id: &str,
+        ac_status: &str,
+        block: &str,
+        cdse_mode: &str,
+        continuation_token: &str,
+        count: &str,
+        custom_field: &str,
+        email: &str,
+        envelope_ids: &str,
+        exclude: &str,
+        folder_ids: &str,
+        folder_types: &str,
+        from_date: &str,
+        from_to_status: &str,
+        include: &str,
+        include_purge_information: &str,
+        intersecting_folder_ids: &str,
+        last_queried_date: &str,
+        order: &str,
+        order_by: &str,
+        powerformids: &str,
+        query_budget: &str,
+        requester_date_format: &str,
+        search_text: &str,
+        start_position: &str,
+        status: &str,
+        to_date: &str,
+        transaction_ids: &str,
+        user_filter: &str,
+        user_id: &str,
+        user_name: &str,
+    ) -> Result<crate::types::EnvelopesInformation> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !ac_status.is_empty() {
+            query_args.push(("ac_status", ac_status.to_string()));
+        }
+        if !block.is_empty() {
+            query_args.push(("block", block.to_string()));
+        }
+        if !cdse_mode.is_empty() {
+            query_args.push(("cdse_mode", cdse_mode.to_string()));
+        }
+        if !continuation_token.is_empty() {
+            query_args.push(("continuation_token", continuation_token.to_string()));
+        }
+        if !count.is_empty() {
+            query_args.push(("count", count.to_string()));
+        }
+        if !custom_field.is_empty() {
+            query_args.push(("custom_field", custom_field.to_string()));
+        }
+        if !email.is_empty() {
+            query_args.push(("email", email.to_string()));
+        }
+        if !envelope_ids.is_empty() {
+            query_args.push(("envelope_ids", envelope_ids.to_string()));
+        }
+        if !exclude.is_empty() {
+            query_args.push(("exclude", exclude.to_string()));
+        }
+        if !folder_ids.is_empty() {
+            query_args.push(("folder_ids", folder_ids.to_string()));
+        }
+        if !folder_types.is_empty() {
+            query_args.push(("folder_types", folder_types.to_string()));
+        }
+        if !from_date.is_empty() {
+            query_args.push(("from_date", from_date.to_string()));
+        }
+        if !from_to_status.is_empty() {
+            query_args.push(("from_to_status", from_to_status.to_string()));
+        }
+        if !include.is_empty() {
+            query_args.push(("include", include.to_string()));
+        }
+        if !include_purge_information.is_empty() {
+            query_args.push((
+                "include_purge_information",
+                include_purge_information.to_string(),
+            ));
+        }
+        if !intersecting_folder_ids.is_empty() {
+            query_args.push((
+                "intersecting_folder_ids",
+                intersecting_folder_ids.to_string(),
+            ));
+        }
+        if !last_queried_date.is_empty() {
+            query_args.push(("last_queried_date", last_queried_date.to_string()));
+        }
+        if !order.is_empty() {
+            query_args.push(("order", order.to_string()));
+        }
+        if !order_by.is_empty() {
+            query_args.push(("order_by", order_by.to_string()));
+        }
+        if !powerformids.is_empty() {
+            query_args.push(("powerformids", powerformids.to_string()));
+        }
+        if !query_budget.is_empty() {
+            query_args.push(("query_budget", query_budget.to_string()));
+        }
+        if !requester_date_format.is_empty() {
+            query_args.push(("requester_date_format", requester_date_format.to_string()));
+        }
+        if !search_text.is_empty() {
+            query_args.push(("search_text", search_text.to_string()));
+        }
+        if !start_position.is_empty() {
+            query_args.push(("start_position", start_position.to_string()));
+        }
+        if !status.is_empty() {
+            query_args.push(("status", status.to_string()));
+        }
+        if !to_date.is_empty() {
+            query_args.push(("to_date", to_date.to_string()));
+        }
+        if !transaction_ids.is_empty() {
+            query_args.push(("transaction_ids", transaction_ids.to_string()));
+        }
+        if !user_filter.is_empty() {
+            query_args.push(("user_filter", user_filter.to_string()));
+        }
+        if !user_id.is_empty() {
+            query_args.push(("user_id", user_id.to_string()));
+        }
+        if !user_name.is_empty() {
+            query_args.push(("user_name", user_name.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::envelopes::Envelopes::post`].
+    pub fn post(
+        &self,
+        account_id: &str,
+        cdse_mode: &str,
+        change_routing_order: &str,
+        completed_documents_only: &str,
+        merge_roles_on_draft: &str,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> Result<crate::types::EnvelopeSummary> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !cdse_mode.is_empty() {
+            query_args.push(("cdse_mode", cdse_mode.to_string()));
+        }
+        if !change_routing_order.is_empty() {
+            query_args.push(("change_routing_order", change_routing_order.to_string()));
+        }
+        if !completed_documents_only.is_empty() {
+            query_args.push((
+                "completed_documents_only",
+                completed_documents_only.to_string(),
+            ));
+        }
+        if !merge_roles_on_draft.is_empty() {
+            query_args.push(("merge_roles_on_draft", merge_roles_on_draft.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/envelopes",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::envelopes::Envelopes::put_status`].
+    pub fn put_status(
+        &self,
+        account_id: &str,
+        ac_status: &str,
+        block: &str,
+        count: &str,
+        email: &str,
+        envelope_ids: &str,
+        from_date: &str,
+        from_to_status: &str,
+        start_position: &str,
+        status: &str,
+        to_date: &str,
+        transaction_ids: &str,
+        user_name: &str,
+        body: &crate::types::EnvelopeIdsRequest,
+    ) -> Result<crate::types::EnvelopesInformation> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !ac_status.is_empty() {
+            query_args.push(("ac_status", ac_status.to_string()));
+        }
+        if !block.is_empty() {
+            query_args.push(("block", block.to_string()));
+        }
+        if !count.is_empty() {
+            query_args.push(("count", count.to_string()));
+        }
+        if !email.is_empty() {
+            query_args.push(("email", email.to_string()));
+        }
+        if !envelope_ids.is_empty() {
+            query_args.push(("envelope_ids", envelope_ids.to_string()));
+        }
+        if !from_date.is_empty() {
+            query_args.push(("from_date", from_date.to_string()));
+        }
+        if !from_to_status.is_empty() {
+            query_args.push(("from_to_status", from_to_status.to_string()));
+        }
+        if !start_position.is_empty() {
+            query_args.push(("start_position", start_position.to_string()));
+        }
+        if !status.is_empty() {
+            query_args.push(("status", status.to_string()));
+        }
+        if !to_date.is_empty() {
+            query_args.push(("to_date", to_date.to_string()));
+        }
+        if !transaction_ids.is_empty() {
+            query_args.push(("transaction_ids", transaction_ids.to_string()));
+        }
+        if !user_name.is_empty() {
+            query_args.push(("user_name", user_name.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/status",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/envelopes/status",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::envelopes::Envelopes::get_envelopes`].
+    pub fn get_envelopes(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        advanced_update: &str,
+        include: &str,
+    ) -> Result<crate::types::Envelope> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !advanced_update.is_empty() {
+            query_args.push(("advanced_update", advanced_update.to_string()));
+        }
+        if !include.is_empty() {
+            query_args.push(("include", include.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(envelope_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::envelopes::Envelopes::put`].
+    pub fn put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        advanced_update: &str,
+        resend_envelope: &str,
+        body: &crate::types::Envelope,
+    ) -> Result<crate::types::EnvelopeUpdateSummary> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !advanced_update.is_empty() {
+            query_args.push(("advanced_update", advanced_update.to_string()));
+        }
+        if !resend_envelope.is_empty() {
+            query_args.push(("resend_envelope", resend_envelope.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(envelope_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::envelopes::Envelopes::audit_events_get`].
+    pub fn audit_events_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EnvelopeAuditEventResponse> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/audit_events",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/audit_events",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::envelopes::Envelopes::pages_get_page_image`].
+    pub fn pages_get_page_image(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        count: &str,
+        dpi: &str,
+        max_height: &str,
+        max_width: &str,
+        nocache: &str,
+        show_changes: &str,
+        start_position: &str,
+    ) -> Result<crate::types::PageImages> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !count.is_empty() {
+            query_args.push(("count", count.to_string()));
+        }
+        if !dpi.is_empty() {
+            query_args.push(("dpi", dpi.to_string()));
+        }
+        if !max_height.is_empty() {
+            query_args.push(("max_height", max_height.to_string()));
+        }
+        if !max_width.is_empty() {
+            query_args.push(("max_width", max_width.to_string()));
+        }
+        if !nocache.is_empty() {
+            query_args.push(("nocache", nocache.to_string()));
+        }
+        if !show_changes.is_empty() {
+            query_args.push(("show_changes", show_changes.to_string()));
+        }
+        if !start_position.is_empty() {
+            query_args.push(("start_position", start_position.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}/pages",
+                self.client.encode_path(account_id),
+                self.client.encode_path(envelope_id),
+                self.client.encode_path(document_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/pages",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                    ("documentId", document_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::envelopes::Envelopes::pages_delete_page`].
+    pub fn pages_delete_page(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+    ) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/documents/{}/pages/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(document_id),
+            self.client.encode_path(page_number),
+        );
+
+        self.client.delete(&url, None).with_context(|| crate::utils::pretty_url("DELETE", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/pages/{pageNumber}", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("documentId", document_id.to_string()), ("pageNumber", page_number.to_string())]))
+    }
+
+    /// The blocking form of [`crate::envelopes::Envelopes::pages_delete_page_if_exists`].
+    pub fn pages_delete_page_if_exists(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/documents/{}/pages/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(document_id),
+            self.client.encode_path(page_number),
+        );
+
+        self.client.delete_if_exists(&url, None).with_context(|| crate::utils::pretty_url("DELETE", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/pages/{pageNumber}", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("documentId", document_id.to_string()), ("pageNumber", page_number.to_string())]))
+    }
+
+    /// The blocking form of [`crate::envelopes::Envelopes::pages_get_page_image_envelopes`].
+    pub fn pages_get_page_image_envelopes(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+        dpi: &str,
+        max_height: &str,
+        max_width: &str,
+        show_changes: &str,
+    ) -> Result<()> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !dpi.is_empty() {
+            query_args.push(("dpi", dpi.to_string()));
+        }
+        if !max_height.is_empty() {
+            query_args.push(("max_height", max_height.to_string()));
+        }
+        if !max_width.is_empty() {
+            query_args.push(("max_width", max_width.to_string()));
+        }
+        if !show_changes.is_empty() {
+            query_args.push(("show_changes", show_changes.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}/pages/{}/page_image",
+                self.client.encode_path(account_id),
+                self.client.encode_path(envelope_id),
+                self.client.encode_path(document_id),
+                self.client.encode_path(page_number)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .request_with_accept_mime(reqwest::Method::GET, &url, "image/png").with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/pages/{pageNumber}/page_image", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("documentId", document_id.to_string()), ("pageNumber", page_number.to_string())]))
+    }
+
+    /// The blocking form of [`crate::envelopes::Envelopes::pages_put_page_image`].
+    pub fn pages_put_page_image(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+        body: &crate::types::PageRequest,
+    ) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/documents/{}/pages/{}/page_image",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(document_id),
+            self.client.encode_path(page_number),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| crate::utils::pretty_url("PUT", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/pages/{pageNumber}/page_image", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("documentId", document_id.to_string()), ("pageNumber", page_number.to_string())]))
+    }
+
+    /// The blocking form of [`crate::envelopes::Envelopes::notification_get`].
+    pub fn notification_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::Notification> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/notification",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/notification",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::envelopes::Envelopes::notification_put`].
+    pub fn notification_put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::Notification,
+    ) -> Result<crate::types::Notification> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/notification",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/notification",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::envelopes::Envelopes::recipients_get_recipient_initials_image`].
+    pub fn recipients_get_recipient_initials_image(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        include_chrome: &str,
+    ) -> Result<()> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !include_chrome.is_empty() {
+            query_args.push(("include_chrome", include_chrome.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients/{}/initials_image",
+                self.client.encode_path(account_id),
+                self.client.encode_path(envelope_id),
+                self.client.encode_path(recipient_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .request_with_accept_mime(reqwest::Method::GET, &url, "image/gif").with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/initials_image", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("recipientId", recipient_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::envelopes::Envelopes::recipients_put_recipient_initials_image`].
+    pub fn recipients_put_recipient_initials_image(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/recipients/{}/initials_image",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(recipient_id),
+        );
+
+        self.client.put(&url, None).with_context(|| crate::utils::pretty_url("PUT", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/initials_image", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("recipientId", recipient_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::envelopes::Envelopes::recipients_get_recipient_signature`].
+    pub fn recipients_get_recipient_signature(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> Result<crate::types::UserSignature> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/recipients/{}/signature",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(recipient_id),
+        );
+
+        self.client.get(&url, None).with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/signature", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("recipientId", recipient_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::envelopes::Envelopes::recipients_get_recipient_signature_image`].
+    pub fn recipients_get_recipient_signature_image(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        include_chrome: &str,
+    ) -> Result<()> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !include_chrome.is_empty() {
+            query_args.push(("include_chrome", include_chrome.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients/{}/signature_image",
+                self.client.encode_path(account_id),
+                self.client.encode_path(envelope_id),
+                self.client.encode_path(recipient_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .request_with_accept_mime(reqwest::Method::GET, &url, "image/gif").with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/signature_image", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("recipientId", recipient_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::envelopes::Envelopes::recipients_put_recipient_signature_image`].
+    pub fn recipients_put_recipient_signature_image(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/recipients/{}/signature_image",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(recipient_id),
+        );
+
+        self.client.put(&url, None).with_context(|| crate::utils::pretty_url("PUT", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/signature_image", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("recipientId", recipient_id.to_string())]))
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct FavoriteTemplates {
+    pub client: Client,
+}
+
+impl FavoriteTemplates {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        FavoriteTemplates { client }
+    }
+
+    /// The blocking form of [`crate::favorite_templates::FavoriteTemplates::get`].
+    pub fn get(&self, account_id: &str) -> Result<crate::types::FavoriteTemplates> {
+        let url = format!(
+            "/v2.1/accounts/{}/favorite_templates",
+            self.client.encode_path(account_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/favorite_templates",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::favorite_templates::FavoriteTemplates::put_template`].
+    pub fn put_template(
+        &self,
+        account_id: &str,
+        body: &crate::types::FavoriteTemplates,
+    ) -> Result<crate::types::FavoriteTemplates> {
+        let url = format!(
+            "/v2.1/accounts/{}/favorite_templates",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/favorite_templates",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::favorite_templates::FavoriteTemplates::un_template`].
+    pub fn un_template(
+        &self,
+        account_id: &str,
+        body: &crate::types::FavoriteTemplates,
+    ) -> Result<crate::types::FavoriteTemplates> {
+        let url = format!(
+            "/v2.1/accounts/{}/favorite_templates",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/favorite_templates",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+}
@@ -0,0 +1,202 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct Folders {
+    pub client: Client,
+}
+
+impl Folders {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        Folders { client }
+    }
+
+    /// The blocking form of [`crate::folders::Folders::get`].
+    pub fn get(
+        &self,
+        account_id: &str,
+        include: &str,
+        include_items: &str,
+        start_position: &str,
+        template: &str,
+        user_filter: &str,
+    ) -> Result<crate::types::FoldersResponse> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !include.is_empty() {
+            query_args.push(("include", include.to_string()));
+        }
+        if !include_items.is_empty() {
+            query_args.push(("include_items", include_items.to_string()));
+        }
+        if !start_position.is_empty() {
+            query_args.push(("start_position", start_position.to_string()));
+        }
+        if !template.is_empty() {
+            query_args.push(("template", template.to_string()));
+        }
+        if !user_filter.is_empty() {
+            query_args.push(("user_filter", user_filter.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/folders",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/folders",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::folders::Folders::get_items`].
+    pub fn get_items(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        from_date: &str,
+        include_items: &str,
+        owner_email: &str,
+        owner_name: &str,
+        search_text: &str,
+        start_position: &str,
+        status: &str,
+        to_date: &str,
+    ) -> Result<crate::types::FoldersResponse> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from_date.is_empty() {
+            query_args.push(("from_date", from_date.to_string()));
+        }
+        if !include_items.is_empty() {
+            query_args.push(("include_items", include_items.to_string()));
+        }
+        if !owner_email.is_empty() {
+            query_args.push(("owner_email", owner_email.to_string()));
+        }
+        if !owner_name.is_empty() {
+            query_args.push(("owner_name", owner_name.to_string()));
+        }
+        if !search_text.is_empty() {
+            query_args.push(("search_text", search_text.to_string()));
+        }
+        if !start_position.is_empty() {
+            query_args.push(("start_position", start_position.to_string()));
+        }
+        if !status.is_empty() {
+            query_args.push(("status", status.to_string()));
+        }
+        if !to_date.is_empty() {
+            query_args.push(("to_date", to_date.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/folders/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(folder_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/folders/{folderId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("folderId", folder_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::folders::Folders::put`].
+    pub fn put(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        body: &crate::types::FoldersRequest,
+    ) -> Result<crate::types::FoldersResponse> {
+        let url = format!(
+            "/v2.1/accounts/{}/folders/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(folder_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/folders/{folderId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("folderId", folder_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::folders::Folders::search_get_contents`].
+    pub fn search_get_contents(
+        &self,
+        account_id: &str,
+        search_folder_id: &str,
+        all: &str,
+        count: &str,
+        from_date: &str,
+        include_recipients: &str,
+        order: &str,
+        order_by: &str,
+        start_position: &str,
+        to_date: &str,
+    ) -> Result<crate::types::FolderItemResponse> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !all.is_empty() {
+            query_args.push(("all", all.to_string()));
+        }
+        if !count.is_empty() {
+            query_args.push(("count", count.to_string()));
+        }
+        if !from_date.is_empty() {
+            query_args.push(("from_date", from_date.to_string()));
+        }
+        if !include_recipients.is_empty() {
+            query_args.push(("include_recipients", include_recipients.to_string()));
+        }
+        if !order.is_empty() {
+            query_args.push(("order", order.to_string()));
+        }
+        if !order_by.is_empty() {
+            query_args.push(("order_by", order_by.to_string()));
+        }
+        if !start_position.is_empty() {
+            query_args.push(("start_position", start_position.to_string()));
+        }
+        if !to_date.is_empty() {
+            query_args.push(("to_date", to_date.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/search_folders/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(search_folder_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/search_folders/{searchFolderId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("searchFolderId", search_folder_id.to_string()),
+                ],
+            )
+        })
+    }
+}
@@ -0,0 +1,90 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct GroupBrands {
+    pub client: Client,
+}
+
+impl GroupBrands {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        GroupBrands { client }
+    }
+
+    /// The blocking form of [`crate::group_brands::GroupBrands::brands_get_group`].
+    pub fn brands_get_group(
+        &self,
+        account_id: &str,
+        group_id: &str,
+    ) -> Result<crate::types::GroupBrands> {
+        let url = format!(
+            "/v2.1/accounts/{}/groups/{}/brands",
+            self.client.encode_path(account_id),
+            self.client.encode_path(group_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/groups/{groupId}/brands",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("groupId", group_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::group_brands::GroupBrands::brands_put_group`].
+    pub fn brands_put_group(
+        &self,
+        account_id: &str,
+        group_id: &str,
+        body: &crate::types::BrandsRequest,
+    ) -> Result<crate::types::GroupBrands> {
+        let url = format!(
+            "/v2.1/accounts/{}/groups/{}/brands",
+            self.client.encode_path(account_id),
+            self.client.encode_path(group_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/groups/{groupId}/brands",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("groupId", group_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::group_brands::GroupBrands::brands_delete_group`].
+    pub fn brands_delete_group(
+        &self,
+        account_id: &str,
+        group_id: &str,
+        body: &crate::types::BrandsRequest,
+    ) -> Result<crate::types::GroupBrands> {
+        let url = format!(
+            "/v2.1/accounts/{}/groups/{}/brands",
+            self.client.encode_path(account_id),
+            self.client.encode_path(group_id),
+        );
+
+        self.client
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/groups/{groupId}/brands",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("groupId", group_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
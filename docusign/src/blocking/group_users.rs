@@ -0,0 +1,102 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct GroupUsers {
+    pub client: Client,
+}
+
+impl GroupUsers {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        GroupUsers { client }
+    }
+
+    /// The blocking form of [`crate::group_users::GroupUsers::groups_get`].
+    pub fn groups_get(
+        &self,
+        account_id: &str,
+        group_id: &str,
+        count: &str,
+        start_position: &str,
+    ) -> Result<crate::types::UsersResponse> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !count.is_empty() {
+            query_args.push(("count", count.to_string()));
+        }
+        if !start_position.is_empty() {
+            query_args.push(("start_position", start_position.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/groups/{}/users",
+                self.client.encode_path(account_id),
+                self.client.encode_path(group_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/groups/{groupId}/users",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("groupId", group_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::group_users::GroupUsers::groups_put`].
+    pub fn groups_put(
+        &self,
+        account_id: &str,
+        group_id: &str,
+        body: &crate::types::UserInfoList,
+    ) -> Result<crate::types::UsersResponse> {
+        let url = format!(
+            "/v2.1/accounts/{}/groups/{}/users",
+            self.client.encode_path(account_id),
+            self.client.encode_path(group_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/groups/{groupId}/users",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("groupId", group_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::group_users::GroupUsers::groups_delete`].
+    pub fn groups_delete(
+        &self,
+        account_id: &str,
+        group_id: &str,
+        body: &crate::types::UserInfoList,
+    ) -> Result<crate::types::UsersResponse> {
+        let url = format!(
+            "/v2.1/accounts/{}/groups/{}/users",
+            self.client.encode_path(account_id),
+            self.client.encode_path(group_id),
+        );
+
+        self.client
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/groups/{groupId}/users",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("groupId", group_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
@@ -0,0 +1,121 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct Groups {
+    pub client: Client,
+}
+
+impl Groups {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        Groups { client }
+    }
+
+    /// The blocking form of [`crate::groups::Groups::get`].
+    pub fn get(
+        &self,
+        account_id: &str,
+        count: &str,
+        group_type: &str,
+        include_usercount: &str,
+        search_text: &str,
+        start_position: &str,
+    ) -> Result<crate::types::GroupInformation> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !count.is_empty() {
+            query_args.push(("count", count.to_string()));
+        }
+        if !group_type.is_empty() {
+            query_args.push(("group_type", group_type.to_string()));
+        }
+        if !include_usercount.is_empty() {
+            query_args.push(("include_usercount", include_usercount.to_string()));
+        }
+        if !search_text.is_empty() {
+            query_args.push(("search_text", search_text.to_string()));
+        }
+        if !start_position.is_empty() {
+            query_args.push(("start_position", start_position.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/groups",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/groups",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::groups::Groups::put`].
+    pub fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::GroupInformation,
+    ) -> Result<crate::types::GroupInformation> {
+        let url = format!(
+            "/v2.1/accounts/{}/groups",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/groups",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::groups::Groups::post`].
+    pub fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::GroupInformation,
+    ) -> Result<crate::types::GroupInformation> {
+        let url = format!(
+            "/v2.1/accounts/{}/groups",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/groups",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::groups::Groups::delete`].
+    pub fn delete(
+        &self,
+        account_id: &str,
+        body: &crate::types::GroupInformation,
+    ) -> Result<crate::types::GroupInformation> {
+        let url = format!(
+            "/v2.1/accounts/{}/groups",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/groups",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+}
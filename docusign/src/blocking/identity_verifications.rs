@@ -0,0 +1,31 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct IdentityVerifications {
+    pub client: Client,
+}
+
+impl IdentityVerifications {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        IdentityVerifications { client }
+    }
+
+    /// The blocking form of [`crate::identity_verifications::IdentityVerifications::account_get`].
+    pub fn account_get(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::AccountIdentityVerificationResponse> {
+        let url = format!(
+            "/v2.1/accounts/{}/identity_verification",
+            self.client.encode_path(account_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/identity_verification",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+}
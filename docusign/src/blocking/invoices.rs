@@ -0,0 +1,86 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct Invoices {
+    pub client: Client,
+}
+
+impl Invoices {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        Invoices { client }
+    }
+
+    /// The blocking form of [`crate::invoices::Invoices::billing_get`].
+    pub fn billing_get(
+        &self,
+        account_id: &str,
+        from_date: &str,
+        to_date: &str,
+    ) -> Result<crate::types::BillingInvoicesResponse> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from_date.is_empty() {
+            query_args.push(("from_date", from_date.to_string()));
+        }
+        if !to_date.is_empty() {
+            query_args.push(("to_date", to_date.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/billing_invoices",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/billing_invoices",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::invoices::Invoices::billing_get_invoices`].
+    pub fn billing_get_invoices(
+        &self,
+        account_id: &str,
+        invoice_id: &str,
+    ) -> Result<crate::types::BillingInvoice> {
+        let url = format!(
+            "/v2.1/accounts/{}/billing_invoices/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(invoice_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/billing_invoices/{invoiceId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("invoiceId", invoice_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::invoices::Invoices::billing_get_past_due`].
+    pub fn billing_get_past_due(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::BillingInvoicesSummary> {
+        let url = format!(
+            "/v2.1/accounts/{}/billing_invoices_past_due",
+            self.client.encode_path(account_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/billing_invoices_past_due",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+}
@@ -0,0 +1,854 @@
+//! The DocuSign API for callers that are not async, with the functions of the
+//! async client under the same names and with the same parameters, but
+//! for the ones streaming their upload or response, which need a runtime.
+
+pub mod account_brands;
+pub mod account_consumer_disclosures;
+pub mod account_custom_fields;
+pub mod account_password_rules;
+pub mod account_permission_profiles;
+pub mod account_seal_providers;
+pub mod account_signature_providers;
+pub mod account_signatures;
+pub mod account_tab_settings;
+pub mod account_watermarks;
+pub mod accounts;
+pub mod appliance_info;
+pub mod bcc_email_archive;
+pub mod billing_plans;
+pub mod bulk_send;
+pub mod chunked_uploads;
+pub mod cloud_storage;
+pub mod cloud_storage_providers;
+pub mod comments;
+pub mod connect_configurations;
+pub mod connect_events;
+pub mod connect_secret;
+pub mod contacts;
+pub mod custom_tabs;
+pub mod document_responsive_html_preview;
+pub mod e_note_configurations;
+pub mod envelope_attachments;
+pub mod envelope_consumer_disclosures;
+pub mod envelope_custom_fields;
+pub mod envelope_document_fields;
+pub mod envelope_document_html_definitions;
+pub mod envelope_document_tabs;
+pub mod envelope_document_visibility;
+pub mod envelope_documents;
+pub mod envelope_email_settings;
+pub mod envelope_form_data;
+pub mod envelope_html_definitions;
+pub mod envelope_locks;
+pub mod envelope_recipient_tabs;
+pub mod envelope_recipients;
+pub mod envelope_templates;
+pub mod envelope_transfer_rules;
+pub mod envelope_views;
+pub mod envelope_workflow_definition;
+pub mod envelopes;
+pub mod favorite_templates;
+pub mod folders;
+pub mod group_brands;
+pub mod group_users;
+pub mod groups;
+pub mod identity_verifications;
+pub mod invoices;
+pub mod notary;
+pub mod notary_journals;
+pub mod notary_jurisdiction;
+pub mod payment_gateway_accounts;
+pub mod payments;
+pub mod power_form_data;
+pub mod power_forms;
+pub mod reports;
+pub mod request_logs;
+pub mod resources;
+pub mod responsive_html_preview;
+pub mod services;
+pub mod signing_group_users;
+pub mod signing_groups;
+pub mod tabs_blob;
+pub mod template_bulk_recipients;
+pub mod template_custom_fields;
+pub mod template_document_fields;
+pub mod template_document_html_definitions;
+pub mod template_document_responsive_html_preview;
+pub mod template_document_tabs;
+pub mod template_document_visibility;
+pub mod template_documents;
+pub mod template_html_definitions;
+pub mod template_locks;
+pub mod template_recipient_tabs;
+pub mod template_recipients;
+pub mod template_responsive_html_preview;
+pub mod template_views;
+pub mod templates;
+pub mod user_custom_settings;
+pub mod user_profiles;
+pub mod user_signatures;
+pub mod users;
+pub mod workspace_items;
+pub mod workspaces;
+
+use anyhow::{anyhow, Result};
+
+/// Entrypoint for interacting with the API without an async runtime.
+///
+/// It retries as the async client does, see `with_retry`, but has none
+/// of its other settings. Make one from an async client, with `From`, to send
+/// with the host, token and retry policy of that client, say once it has
+/// refreshed its token. It must not be used from async code, where it would
+/// block the runtime.
+#[derive(Clone)]
+pub struct Client {
+    host: String,
+    token: String,
+    retry_policy: crate::RetryPolicy,
+
+    client: reqwest::blocking::Client,
+}
+
+impl From<&crate::Client> for Client {
+    fn from(client: &crate::Client) -> Self {
+        Client {
+            host: client.host.clone(),
+            token: client.token.clone(),
+            retry_policy: client.retry_policy,
+            client: reqwest::blocking::Client::new(),
+        }
+    }
+}
+
+/// The blocking form of [`crate::utils::multipart_form`].
+#[allow(dead_code)]
+fn multipart_form<B>(body: &B) -> serde_json::Result<reqwest::blocking::multipart::Form>
+where
+    B: serde::Serialize + ?Sized,
+{
+    let mut form = reqwest::blocking::multipart::Form::new();
+    if let serde_json::Value::Object(fields) = serde_json::to_value(body)? {
+        for (name, value) in fields {
+            form = match value {
+                serde_json::Value::Null => form,
+                serde_json::Value::String(s) => form.text(name, s),
+                value => form.text(name, value.to_string()),
+            };
+        }
+    }
+    Ok(form)
+}
+
+impl Client {
+    /// Create a new blocking client, which sends `token` as the bearer token
+    /// of each request.
+    pub fn new<T>(token: T) -> Self
+    where
+        T: ToString,
+    {
+        Client {
+            host: crate::DEFAULT_HOST.to_string(),
+            token: token.to_string(),
+            retry_policy: crate::RetryPolicy::default(),
+            client: reqwest::blocking::Client::new(),
+        }
+    }
+
+    /// Override the default host for the client.
+    pub fn with_host<H>(&self, host: H) -> Self
+    where
+        H: ToString,
+    {
+        let mut c = self.clone();
+        c.host = host.to_string();
+        c
+    }
+
+    /// Send `token` as the bearer token instead, say one refreshed since the
+    /// client was made.
+    pub fn with_token<T>(&self, token: T) -> Self
+    where
+        T: ToString,
+    {
+        let mut c = self.clone();
+        c.token = token.to_string();
+        c
+    }
+
+    /// Retry the requests that fail with a connection error, a `429 Too Many
+    /// Requests` or a server error, as the async client does, with the thread
+    /// sleeping between the tries. A form upload only gets the one try.
+    pub fn with_retry(&self, policy: crate::RetryPolicy) -> Self {
+        let mut c = self.clone();
+        c.retry_policy = policy;
+        c
+    }
+
+    fn encode_path(&self, pc: &str) -> String {
+        crate::progenitor_support::encode_path(pc)
+    }
+
+    fn serialize_body<B>(&self, body: &B) -> Result<reqwest::Body>
+    where
+        B: serde::Serialize + ?Sized,
+    {
+        Ok(serde_json::to_vec(body)?.into())
+    }
+
+    /// Send the request, and send it again as the retry policy says while it
+    /// fails.
+    fn send(&self, req: reqwest::blocking::RequestBuilder) -> Result<reqwest::blocking::Response> {
+        let policy = &self.retry_policy;
+        let mut backoff = policy.initial_backoff;
+        let mut waited = std::time::Duration::ZERO;
+        for _ in 0..policy.max_retries {
+            // A form is read as it is sent, so it can not be sent again.
+            let r = match req.try_clone() {
+                Some(r) => r,
+                None => break,
+            };
+
+            let (last, wait) = match r.send() {
+                Ok(resp) if !crate::RetryPolicy::should_retry(resp.status()) => return Ok(resp),
+                Ok(resp) => {
+                    let wait = crate::RetryPolicy::retry_after(resp.headers(), chrono::Utc::now())
+                        .unwrap_or_else(|| policy.delay(backoff));
+                    (Ok(resp), wait)
+                }
+                Err(e) if e.is_connect() || e.is_timeout() => (Err(e), policy.delay(backoff)),
+                Err(e) => return Err(e.into()),
+            };
+
+            // Give up with what we got if the wait goes over the retry budget.
+            waited += wait;
+            if policy.max_total_delay.map_or(false, |max| waited > max) {
+                return Ok(last?);
+            }
+
+            std::thread::sleep(wait);
+            backoff = backoff.saturating_mul(2);
+        }
+
+        Ok(req.send()?)
+    }
+
+    fn request_raw(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        body: Option<reqwest::Body>,
+    ) -> Result<reqwest::blocking::Response> {
+        let mut req = self
+            .client
+            .request(method, &format!("{}{}", self.host, uri))
+            .header(reqwest::header::ACCEPT, "application/json")
+            .bearer_auth(&self.token);
+
+        if let Some(body) = body {
+            // The bodies of the functions are serialized JSON or the bytes of
+            // an upload, which are in memory, not a stream the blocking client
+            // would have to poll. They go with the same content type as from
+            // the async client.
+            let bytes = body
+                .as_bytes()
+                .ok_or_else(|| anyhow!("the blocking client can not send a streamed body"))?;
+            req = req
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(bytes.to_vec());
+        }
+
+        self.send(req)
+    }
+
+    fn request<Out>(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        body: Option<reqwest::Body>,
+    ) -> Result<Out>
+    where
+        Out: crate::ResponseType,
+    {
+        let response = self.request_raw(method, uri, body)?;
+
+        let status = response.status();
+        let response_body = response.bytes()?;
+
+        crate::Client::parse_response(status, None, &response_body)
+    }
+
+    /// Send a `multipart/form-data` body, with the content type and boundary
+    /// the form sets.
+    #[allow(dead_code)]
+    fn request_form<Out>(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        form: reqwest::blocking::multipart::Form,
+    ) -> Result<Out>
+    where
+        Out: crate::ResponseType,
+    {
+        let req = self
+            .client
+            .request(method, &format!("{}{}", self.host, uri))
+            .header(reqwest::header::ACCEPT, "application/json")
+            .bearer_auth(&self.token)
+            .multipart(form);
+        let response = self.send(req)?;
+
+        let status = response.status();
+        let response_body = response.bytes()?;
+
+        crate::Client::parse_text_response(status, None, &response_body)
+    }
+
+    /// Ask for a response that is not JSON, say a PDF, which is handed out as
+    /// the `bytes::Bytes` or `String` it is.
+    #[allow(dead_code)]
+    fn request_with_accept_mime<Out>(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        accept_mime_type: &str,
+    ) -> Result<Out>
+    where
+        Out: crate::ResponseType,
+    {
+        let req = self
+            .client
+            .request(method, &format!("{}{}", self.host, uri))
+            .header(reqwest::header::ACCEPT, accept_mime_type)
+            .bearer_auth(&self.token);
+        let response = self.send(req)?;
+
+        let status = response.status();
+        let response_body = response.bytes()?;
+
+        crate::Client::parse_text_response(status, None, &response_body)
+    }
+
+    #[allow(dead_code)]
+    fn get<D>(&self, uri: &str, message: Option<reqwest::Body>) -> Result<D>
+    where
+        D: crate::ResponseType,
+    {
+        self.request(reqwest::Method::GET, uri, message)
+    }
+
+    #[allow(dead_code)]
+    fn post<D>(&self, uri: &str, message: Option<reqwest::Body>) -> Result<D>
+    where
+        D: crate::ResponseType,
+    {
+        self.request(reqwest::Method::POST, uri, message)
+    }
+
+    #[allow(dead_code)]
+    fn patch<D>(&self, uri: &str, message: Option<reqwest::Body>) -> Result<D>
+    where
+        D: crate::ResponseType,
+    {
+        self.request(reqwest::Method::PATCH, uri, message)
+    }
+
+    #[allow(dead_code)]
+    fn put<D>(&self, uri: &str, message: Option<reqwest::Body>) -> Result<D>
+    where
+        D: crate::ResponseType,
+    {
+        self.request(reqwest::Method::PUT, uri, message)
+    }
+
+    #[allow(dead_code)]
+    fn delete<D>(&self, uri: &str, message: Option<reqwest::Body>) -> Result<D>
+    where
+        D: crate::ResponseType,
+    {
+        self.request(reqwest::Method::DELETE, uri, message)
+    }
+
+    /// Delete what `uri` is for, as `delete` does, and return whether there
+    /// was anything to delete, rather than an error if there was not.
+    #[allow(dead_code)]
+    fn delete_if_exists(&self, uri: &str, message: Option<reqwest::Body>) -> Result<bool> {
+        let response = self.request_raw(reqwest::Method::DELETE, uri, message)?;
+
+        let status = response.status();
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Ok(false);
+        }
+        let response_body = response.bytes()?;
+
+        crate::Client::parse_response::<()>(status, None, &response_body)?;
+        Ok(true)
+    }
+}
+
+impl Client {
+    /// The blocking form of [`crate::Client::account_brands`].
+    pub fn account_brands(&self) -> account_brands::AccountBrands {
+        account_brands::AccountBrands::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::account_consumer_disclosures`].
+    pub fn account_consumer_disclosures(
+        &self,
+    ) -> account_consumer_disclosures::AccountConsumerDisclosures {
+        account_consumer_disclosures::AccountConsumerDisclosures::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::account_custom_fields`].
+    pub fn account_custom_fields(&self) -> account_custom_fields::AccountCustomFields {
+        account_custom_fields::AccountCustomFields::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::account_password_rules`].
+    pub fn account_password_rules(&self) -> account_password_rules::AccountPasswordRules {
+        account_password_rules::AccountPasswordRules::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::account_permission_profiles`].
+    pub fn account_permission_profiles(
+        &self,
+    ) -> account_permission_profiles::AccountPermissionProfiles {
+        account_permission_profiles::AccountPermissionProfiles::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::account_seal_providers`].
+    pub fn account_seal_providers(&self) -> account_seal_providers::AccountSealProviders {
+        account_seal_providers::AccountSealProviders::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::account_signature_providers`].
+    pub fn account_signature_providers(
+        &self,
+    ) -> account_signature_providers::AccountSignatureProviders {
+        account_signature_providers::AccountSignatureProviders::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::account_signatures`].
+    pub fn account_signatures(&self) -> account_signatures::AccountSignatures {
+        account_signatures::AccountSignatures::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::account_tab_settings`].
+    pub fn account_tab_settings(&self) -> account_tab_settings::AccountTabSettings {
+        account_tab_settings::AccountTabSettings::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::account_watermarks`].
+    pub fn account_watermarks(&self) -> account_watermarks::AccountWatermarks {
+        account_watermarks::AccountWatermarks::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::accounts`].
+    pub fn accounts(&self) -> accounts::Accounts {
+        accounts::Accounts::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::appliance_info`].
+    pub fn appliance_info(&self) -> appliance_info::ApplianceInfo {
+        appliance_info::ApplianceInfo::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::bcc_email_archive`].
+    pub fn bcc_email_archive(&self) -> bcc_email_archive::BccEmailArchive {
+        bcc_email_archive::BccEmailArchive::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::billing_plans`].
+    pub fn billing_plans(&self) -> billing_plans::BillingPlans {
+        billing_plans::BillingPlans::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::bulk_send`].
+    pub fn bulk_send(&self) -> bulk_send::BulkSend {
+        bulk_send::BulkSend::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::chunked_uploads`].
+    pub fn chunked_uploads(&self) -> chunked_uploads::ChunkedUploads {
+        chunked_uploads::ChunkedUploads::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::cloud_storage`].
+    pub fn cloud_storage(&self) -> cloud_storage::CloudStorage {
+        cloud_storage::CloudStorage::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::cloud_storage_providers`].
+    pub fn cloud_storage_providers(&self) -> cloud_storage_providers::CloudStorageProviders {
+        cloud_storage_providers::CloudStorageProviders::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::comments`].
+    pub fn comments(&self) -> comments::Comments {
+        comments::Comments::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::connect_configurations`].
+    pub fn connect_configurations(&self) -> connect_configurations::ConnectConfigurations {
+        connect_configurations::ConnectConfigurations::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::connect_events`].
+    pub fn connect_events(&self) -> connect_events::ConnectEvents {
+        connect_events::ConnectEvents::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::connect_secret`].
+    pub fn connect_secret(&self) -> connect_secret::ConnectSecret {
+        connect_secret::ConnectSecret::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::contacts`].
+    pub fn contacts(&self) -> contacts::Contacts {
+        contacts::Contacts::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::custom_tabs`].
+    pub fn custom_tabs(&self) -> custom_tabs::CustomTabs {
+        custom_tabs::CustomTabs::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::document_responsive_html_preview`].
+    pub fn document_responsive_html_preview(
+        &self,
+    ) -> document_responsive_html_preview::DocumentResponsiveHtmlPreview {
+        document_responsive_html_preview::DocumentResponsiveHtmlPreview::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::e_note_configurations`].
+    pub fn e_note_configurations(&self) -> e_note_configurations::ENoteConfigurations {
+        e_note_configurations::ENoteConfigurations::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::envelope_attachments`].
+    pub fn envelope_attachments(&self) -> envelope_attachments::EnvelopeAttachments {
+        envelope_attachments::EnvelopeAttachments::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::envelope_consumer_disclosures`].
+    pub fn envelope_consumer_disclosures(
+        &self,
+    ) -> envelope_consumer_disclosures::EnvelopeConsumerDisclosures {
+        envelope_consumer_disclosures::EnvelopeConsumerDisclosures::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::envelope_custom_fields`].
+    pub fn envelope_custom_fields(&self) -> envelope_custom_fields::EnvelopeCustomFields {
+        envelope_custom_fields::EnvelopeCustomFields::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::envelope_document_fields`].
+    pub fn envelope_document_fields(&self) -> envelope_document_fields::EnvelopeDocumentFields {
+        envelope_document_fields::EnvelopeDocumentFields::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::envelope_document_html_definitions`].
+    pub fn envelope_document_html_definitions(
+        &self,
+    ) -> envelope_document_html_definitions::EnvelopeDocumentHtmlDefinitions {
+        envelope_document_html_definitions::EnvelopeDocumentHtmlDefinitions::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::envelope_document_tabs`].
+    pub fn envelope_document_tabs(&self) -> envelope_document_tabs::EnvelopeDocumentTabs {
+        envelope_document_tabs::EnvelopeDocumentTabs::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::envelope_document_visibility`].
+    pub fn envelope_document_visibility(
+        &self,
+    ) -> envelope_document_visibility::EnvelopeDocumentVisibility {
+        envelope_document_visibility::EnvelopeDocumentVisibility::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::envelope_documents`].
+    pub fn envelope_documents(&self) -> envelope_documents::EnvelopeDocuments {
+        envelope_documents::EnvelopeDocuments::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::envelope_email_settings`].
+    pub fn envelope_email_settings(&self) -> envelope_email_settings::EnvelopeEmailSettings {
+        envelope_email_settings::EnvelopeEmailSettings::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::envelope_form_data`].
+    pub fn envelope_form_data(&self) -> envelope_form_data::EnvelopeFormData {
+        envelope_form_data::EnvelopeFormData::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::envelope_html_definitions`].
+    pub fn envelope_html_definitions(&self) -> envelope_html_definitions::EnvelopeHtmlDefinitions {
+        envelope_html_definitions::EnvelopeHtmlDefinitions::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::envelope_locks`].
+    pub fn envelope_locks(&self) -> envelope_locks::EnvelopeLocks {
+        envelope_locks::EnvelopeLocks::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::envelope_recipient_tabs`].
+    pub fn envelope_recipient_tabs(&self) -> envelope_recipient_tabs::EnvelopeRecipientTabs {
+        envelope_recipient_tabs::EnvelopeRecipientTabs::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::envelope_recipients`].
+    pub fn envelope_recipients(&self) -> envelope_recipients::EnvelopeRecipients {
+        envelope_recipients::EnvelopeRecipients::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::envelope_templates`].
+    pub fn envelope_templates(&self) -> envelope_templates::EnvelopeTemplates {
+        envelope_templates::EnvelopeTemplates::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::envelope_transfer_rules`].
+    pub fn envelope_transfer_rules(&self) -> envelope_transfer_rules::EnvelopeTransferRules {
+        envelope_transfer_rules::EnvelopeTransferRules::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::envelope_views`].
+    pub fn envelope_views(&self) -> envelope_views::EnvelopeViews {
+        envelope_views::EnvelopeViews::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::envelope_workflow_definition`].
+    pub fn envelope_workflow_definition(
+        &self,
+    ) -> envelope_workflow_definition::EnvelopeWorkflowDefinition {
+        envelope_workflow_definition::EnvelopeWorkflowDefinition::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::envelopes`].
+    pub fn envelopes(&self) -> envelopes::Envelopes {
+        envelopes::Envelopes::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::favorite_templates`].
+    pub fn favorite_templates(&self) -> favorite_templates::FavoriteTemplates {
+        favorite_templates::FavoriteTemplates::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::folders`].
+    pub fn folders(&self) -> folders::Folders {
+        folders::Folders::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::group_brands`].
+    pub fn group_brands(&self) -> group_brands::GroupBrands {
+        group_brands::GroupBrands::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::group_users`].
+    pub fn group_users(&self) -> group_users::GroupUsers {
+        group_users::GroupUsers::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::groups`].
+    pub fn groups(&self) -> groups::Groups {
+        groups::Groups::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::identity_verifications`].
+    pub fn identity_verifications(&self) -> identity_verifications::IdentityVerifications {
+        identity_verifications::IdentityVerifications::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::invoices`].
+    pub fn invoices(&self) -> invoices::Invoices {
+        invoices::Invoices::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::notary`].
+    pub fn notary(&self) -> notary::Notary {
+        notary::Notary::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::notary_journals`].
+    pub fn notary_journals(&self) -> notary_journals::NotaryJournals {
+        notary_journals::NotaryJournals::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::notary_jurisdiction`].
+    pub fn notary_jurisdiction(&self) -> notary_jurisdiction::NotaryJurisdiction {
+        notary_jurisdiction::NotaryJurisdiction::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::payment_gateway_accounts`].
+    pub fn payment_gateway_accounts(&self) -> payment_gateway_accounts::PaymentGatewayAccounts {
+        payment_gateway_accounts::PaymentGatewayAccounts::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::payments`].
+    pub fn payments(&self) -> payments::Payments {
+        payments::Payments::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::power_form_data`].
+    pub fn power_form_data(&self) -> power_form_data::PowerFormData {
+        power_form_data::PowerFormData::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::power_forms`].
+    pub fn power_forms(&self) -> power_forms::PowerForms {
+        power_forms::PowerForms::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::reports`].
+    pub fn reports(&self) -> reports::Reports {
+        reports::Reports::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::request_logs`].
+    pub fn request_logs(&self) -> request_logs::RequestLogs {
+        request_logs::RequestLogs::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::resources`].
+    pub fn resources(&self) -> resources::Resources {
+        resources::Resources::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::responsive_html_preview`].
+    pub fn responsive_html_preview(&self) -> responsive_html_preview::ResponsiveHtmlPreview {
+        responsive_html_preview::ResponsiveHtmlPreview::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::services`].
+    pub fn services(&self) -> services::Services {
+        services::Services::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::signing_group_users`].
+    pub fn signing_group_users(&self) -> signing_group_users::SigningGroupUsers {
+        signing_group_users::SigningGroupUsers::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::signing_groups`].
+    pub fn signing_groups(&self) -> signing_groups::SigningGroups {
+        signing_groups::SigningGroups::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::tabs_blob`].
+    pub fn tabs_blob(&self) -> tabs_blob::TabsBlob {
+        tabs_blob::TabsBlob::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::template_bulk_recipients`].
+    pub fn template_bulk_recipients(&self) -> template_bulk_recipients::TemplateBulkRecipients {
+        template_bulk_recipients::TemplateBulkRecipients::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::template_custom_fields`].
+    pub fn template_custom_fields(&self) -> template_custom_fields::TemplateCustomFields {
+        template_custom_fields::TemplateCustomFields::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::template_document_fields`].
+    pub fn template_document_fields(&self) -> template_document_fields::TemplateDocumentFields {
+        template_document_fields::TemplateDocumentFields::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::template_document_html_definitions`].
+    pub fn template_document_html_definitions(
+        &self,
+    ) -> template_document_html_definitions::TemplateDocumentHtmlDefinitions {
+        template_document_html_definitions::TemplateDocumentHtmlDefinitions::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::template_document_responsive_html_preview`].
+    pub fn template_document_responsive_html_preview(
+        &self,
+    ) -> template_document_responsive_html_preview::TemplateDocumentResponsiveHtmlPreview {
+        template_document_responsive_html_preview::TemplateDocumentResponsiveHtmlPreview::new(
+            self.clone(),
+        )
+    }
+
+    /// The blocking form of [`crate::Client::template_document_tabs`].
+    pub fn template_document_tabs(&self) -> template_document_tabs::TemplateDocumentTabs {
+        template_document_tabs::TemplateDocumentTabs::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::template_document_visibility`].
+    pub fn template_document_visibility(
+        &self,
+    ) -> template_document_visibility::TemplateDocumentVisibility {
+        template_document_visibility::TemplateDocumentVisibility::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::template_documents`].
+    pub fn template_documents(&self) -> template_documents::TemplateDocuments {
+        template_documents::TemplateDocuments::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::template_html_definitions`].
+    pub fn template_html_definitions(&self) -> template_html_definitions::TemplateHtmlDefinitions {
+        template_html_definitions::TemplateHtmlDefinitions::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::template_locks`].
+    pub fn template_locks(&self) -> template_locks::TemplateLocks {
+        template_locks::TemplateLocks::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::template_recipient_tabs`].
+    pub fn template_recipient_tabs(&self) -> template_recipient_tabs::TemplateRecipientTabs {
+        template_recipient_tabs::TemplateRecipientTabs::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::template_recipients`].
+    pub fn template_recipients(&self) -> template_recipients::TemplateRecipients {
+        template_recipients::TemplateRecipients::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::template_responsive_html_preview`].
+    pub fn template_responsive_html_preview(
+        &self,
+    ) -> template_responsive_html_preview::TemplateResponsiveHtmlPreview {
+        template_responsive_html_preview::TemplateResponsiveHtmlPreview::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::template_views`].
+    pub fn template_views(&self) -> template_views::TemplateViews {
+        template_views::TemplateViews::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::templates`].
+    pub fn templates(&self) -> templates::Templates {
+        templates::Templates::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::user_custom_settings`].
+    pub fn user_custom_settings(&self) -> user_custom_settings::UserCustomSettings {
+        user_custom_settings::UserCustomSettings::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::user_profiles`].
+    pub fn user_profiles(&self) -> user_profiles::UserProfiles {
+        user_profiles::UserProfiles::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::user_signatures`].
+    pub fn user_signatures(&self) -> user_signatures::UserSignatures {
+        user_signatures::UserSignatures::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::users`].
+    pub fn users(&self) -> users::Users {
+        users::Users::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::workspace_items`].
+    pub fn workspace_items(&self) -> workspace_items::WorkspaceItems {
+        workspace_items::WorkspaceItems::new(self.clone())
+    }
+
+    /// The blocking form of [`crate::Client::workspaces`].
+    pub fn workspaces(&self) -> workspaces::Workspaces {
+        workspaces::Workspaces::new(self.clone())
+    }
+}
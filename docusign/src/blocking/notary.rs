@@ -0,0 +1,41 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct Notary {
+    pub client: Client,
+}
+
+impl Notary {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        Notary { client }
+    }
+
+    /// The blocking form of [`crate::notary::Notary::get`].
+    pub fn get(&self, include_jurisdictions: &str) -> Result<crate::types::NotaryResult> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !include_jurisdictions.is_empty() {
+            query_args.push(("include_jurisdictions", include_jurisdictions.to_string()));
+        }
+        let url = crate::Client::url_with_query("/v2.1/current_user/notary", &query_args);
+
+        self.client
+            .get(&url, None)
+            .with_context(|| crate::utils::pretty_url("GET", "/v2.1/current_user/notary", &[]))
+    }
+
+    /// The blocking form of [`crate::notary::Notary::put`].
+    pub fn put(&self, body: &crate::types::NotaryData) -> Result<crate::types::NotaryData> {
+        let url = "/v2.1/current_user/notary".to_string();
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| crate::utils::pretty_url("PUT", "/v2.1/current_user/notary", &[]))
+    }
+
+    /// The blocking form of [`crate::notary::Notary::post`].
+    pub fn post(&self, body: &crate::types::NotaryData) -> Result<crate::types::NotaryData> {
+        let url = "/v2.1/current_user/notary".to_string();
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| crate::utils::pretty_url("POST", "/v2.1/current_user/notary", &[]))
+    }
+}
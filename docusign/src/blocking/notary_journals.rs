@@ -0,0 +1,36 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct NotaryJournals {
+    pub client: Client,
+}
+
+impl NotaryJournals {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        NotaryJournals { client }
+    }
+
+    /// The blocking form of [`crate::notary_journals::NotaryJournals::get`].
+    pub fn get(
+        &self,
+        count: &str,
+        search_text: &str,
+        start_position: &str,
+    ) -> Result<crate::types::NotaryJournalList> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !count.is_empty() {
+            query_args.push(("count", count.to_string()));
+        }
+        if !search_text.is_empty() {
+            query_args.push(("search_text", search_text.to_string()));
+        }
+        if !start_position.is_empty() {
+            query_args.push(("start_position", start_position.to_string()));
+        }
+        let url = crate::Client::url_with_query("/v2.1/current_user/notary/journals", &query_args);
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url("GET", "/v2.1/current_user/notary/journals", &[])
+        })
+    }
+}
@@ -0,0 +1,106 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct NotaryJurisdiction {
+    pub client: Client,
+}
+
+impl NotaryJurisdiction {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        NotaryJurisdiction { client }
+    }
+
+    /// The blocking form of [`crate::notary_jurisdiction::NotaryJurisdiction::s_get`].
+    pub fn s_get(&self) -> Result<crate::types::NotaryJurisdictionList> {
+        let url = "/v2.1/current_user/notary/jurisdictions".to_string();
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url("GET", "/v2.1/current_user/notary/jurisdictions", &[])
+        })
+    }
+
+    /// The blocking form of [`crate::notary_jurisdiction::NotaryJurisdiction::s_post`].
+    pub fn s_post(
+        &self,
+        body: &crate::types::NotaryJurisdictionData,
+    ) -> Result<crate::types::NotaryJurisdictionData> {
+        let url = "/v2.1/current_user/notary/jurisdictions".to_string();
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/v2.1/current_user/notary/jurisdictions", &[])
+            })
+    }
+
+    /// The blocking form of [`crate::notary_jurisdiction::NotaryJurisdiction::s_get_jurisdiction`].
+    pub fn s_get_jurisdiction(
+        &self,
+        jurisdiction_id: &str,
+    ) -> Result<crate::types::NotaryJurisdictionData> {
+        let url = format!(
+            "/v2.1/current_user/notary/jurisdictions/{}",
+            self.client.encode_path(jurisdiction_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/current_user/notary/jurisdictions/{jurisdictionId}",
+                &[("jurisdictionId", jurisdiction_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::notary_jurisdiction::NotaryJurisdiction::s_put_jurisdiction`].
+    pub fn s_put_jurisdiction(
+        &self,
+        jurisdiction_id: &str,
+        body: &crate::types::NotaryJurisdictionData,
+    ) -> Result<crate::types::NotaryJurisdictionData> {
+        let url = format!(
+            "/v2.1/current_user/notary/jurisdictions/{}",
+            self.client.encode_path(jurisdiction_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/current_user/notary/jurisdictions/{jurisdictionId}",
+                    &[("jurisdictionId", jurisdiction_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::notary_jurisdiction::NotaryJurisdiction::s_delete_jurisdiction`].
+    pub fn s_delete_jurisdiction(&self, jurisdiction_id: &str) -> Result<()> {
+        let url = format!(
+            "/v2.1/current_user/notary/jurisdictions/{}",
+            self.client.encode_path(jurisdiction_id),
+        );
+
+        self.client.delete(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/current_user/notary/jurisdictions/{jurisdictionId}",
+                &[("jurisdictionId", jurisdiction_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::notary_jurisdiction::NotaryJurisdiction::s_delete_jurisdiction_if_exists`].
+    pub fn s_delete_jurisdiction_if_exists(&self, jurisdiction_id: &str) -> Result<bool> {
+        let url = format!(
+            "/v2.1/current_user/notary/jurisdictions/{}",
+            self.client.encode_path(jurisdiction_id),
+        );
+
+        self.client.delete_if_exists(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/current_user/notary/jurisdictions/{jurisdictionId}",
+                &[("jurisdictionId", jurisdiction_id.to_string())],
+            )
+        })
+    }
+}
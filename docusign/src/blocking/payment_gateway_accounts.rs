@@ -0,0 +1,28 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct PaymentGatewayAccounts {
+    pub client: Client,
+}
+
+impl PaymentGatewayAccounts {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        PaymentGatewayAccounts { client }
+    }
+
+    /// The blocking form of [`crate::payment_gateway_accounts::PaymentGatewayAccounts::get_all`].
+    pub fn get_all(&self, account_id: &str) -> Result<crate::types::PaymentGatewayAccountsInfo> {
+        let url = format!(
+            "/v2.1/accounts/{}/payment_gateway_accounts",
+            self.client.encode_path(account_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/payment_gateway_accounts",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct Payments {
+    pub client: Client,
+}
+
+impl Payments {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        Payments { client }
+    }
+
+    /// The blocking form of [`crate::payments::Payments::billing_get_list`].
+    pub fn billing_get_list(
+        &self,
+        account_id: &str,
+        from_date: &str,
+        to_date: &str,
+    ) -> Result<crate::types::BillingPaymentsResponse> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from_date.is_empty() {
+            query_args.push(("from_date", from_date.to_string()));
+        }
+        if !to_date.is_empty() {
+            query_args.push(("to_date", to_date.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/billing_payments",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/billing_payments",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::payments::Payments::billing_post`].
+    pub fn billing_post(
+        &self,
+        account_id: &str,
+        body: &crate::types::BillingPaymentRequest,
+    ) -> Result<crate::types::BillingPaymentResponse> {
+        let url = format!(
+            "/v2.1/accounts/{}/billing_payments",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/billing_payments",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::payments::Payments::billing_get`].
+    pub fn billing_get(
+        &self,
+        account_id: &str,
+        payment_id: &str,
+    ) -> Result<crate::types::BillingPaymentItem> {
+        let url = format!(
+            "/v2.1/accounts/{}/billing_payments/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(payment_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/billing_payments/{paymentId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("paymentId", payment_id.to_string()),
+                ],
+            )
+        })
+    }
+}
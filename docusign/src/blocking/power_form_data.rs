@@ -0,0 +1,52 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct PowerFormData {
+    pub client: Client,
+}
+
+impl PowerFormData {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        PowerFormData { client }
+    }
+
+    /// The blocking form of [`crate::power_form_data::PowerFormData::power_forms_get_form_data`].
+    pub fn power_forms_get_form_data(
+        &self,
+        account_id: &str,
+        power_form_id: &str,
+        data_layout: &str,
+        from_date: &str,
+        to_date: &str,
+    ) -> Result<crate::types::PowerFormsFormDataResponse> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !data_layout.is_empty() {
+            query_args.push(("data_layout", data_layout.to_string()));
+        }
+        if !from_date.is_empty() {
+            query_args.push(("from_date", from_date.to_string()));
+        }
+        if !to_date.is_empty() {
+            query_args.push(("to_date", to_date.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/powerforms/{}/form_data",
+                self.client.encode_path(account_id),
+                self.client.encode_path(power_form_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/powerforms/{powerFormId}/form_data",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("powerFormId", power_form_id.to_string()),
+                ],
+            )
+        })
+    }
+}
@@ -0,0 +1,213 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct PowerForms {
+    pub client: Client,
+}
+
+impl PowerForms {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        PowerForms { client }
+    }
+
+    /// The blocking form of [`crate::power_forms::PowerForms::get_list`].
+    pub fn get_list(
+        &self,
+        account_id: &str,
+        from_date: &str,
+        order: &str,
+        order_by: &str,
+        to_date: &str,
+    ) -> Result<crate::types::PowerFormsResponse> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from_date.is_empty() {
+            query_args.push(("from_date", from_date.to_string()));
+        }
+        if !order.is_empty() {
+            query_args.push(("order", order.to_string()));
+        }
+        if !order_by.is_empty() {
+            query_args.push(("order_by", order_by.to_string()));
+        }
+        if !to_date.is_empty() {
+            query_args.push(("to_date", to_date.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/powerforms",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/powerforms",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::power_forms::PowerForms::post_form`].
+    pub fn post_form(
+        &self,
+        account_id: &str,
+        body: &crate::types::PowerForm,
+    ) -> Result<crate::types::PowerForm> {
+        let url = format!(
+            "/v2.1/accounts/{}/powerforms",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/powerforms",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::power_forms::PowerForms::delete_list`].
+    pub fn delete_list(
+        &self,
+        account_id: &str,
+        body: &crate::types::PowerFormsRequest,
+    ) -> Result<crate::types::PowerFormsResponse> {
+        let url = format!(
+            "/v2.1/accounts/{}/powerforms",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/powerforms",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::power_forms::PowerForms::get_senders`].
+    pub fn get_senders(
+        &self,
+        account_id: &str,
+        start_position: &str,
+    ) -> Result<crate::types::PowerFormSendersResponse> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !start_position.is_empty() {
+            query_args.push(("start_position", start_position.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/powerforms/senders",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/powerforms/senders",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::power_forms::PowerForms::get_form`].
+    pub fn get_form(
+        &self,
+        account_id: &str,
+        power_form_id: &str,
+    ) -> Result<crate::types::PowerForm> {
+        let url = format!(
+            "/v2.1/accounts/{}/powerforms/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(power_form_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/powerforms/{powerFormId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("powerFormId", power_form_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::power_forms::PowerForms::put_form`].
+    pub fn put_form(
+        &self,
+        account_id: &str,
+        power_form_id: &str,
+        body: &crate::types::PowerForm,
+    ) -> Result<crate::types::PowerForm> {
+        let url = format!(
+            "/v2.1/accounts/{}/powerforms/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(power_form_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/powerforms/{powerFormId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("powerFormId", power_form_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::power_forms::PowerForms::delete_form`].
+    pub fn delete_form(&self, account_id: &str, power_form_id: &str) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/powerforms/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(power_form_id),
+        );
+
+        self.client.delete(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/powerforms/{powerFormId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("powerFormId", power_form_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::power_forms::PowerForms::delete_form_if_exists`].
+    pub fn delete_form_if_exists(&self, account_id: &str, power_form_id: &str) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/powerforms/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(power_form_id),
+        );
+
+        self.client.delete_if_exists(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/powerforms/{powerFormId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("powerFormId", power_form_id.to_string()),
+                ],
+            )
+        })
+    }
+}
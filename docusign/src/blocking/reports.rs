@@ -0,0 +1,169 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct Reports {
+    pub client: Client,
+}
+
+impl Reports {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        Reports { client }
+    }
+
+    /// The blocking form of [`crate::reports::Reports::product_get_list`].
+    pub fn product_get_list(&self, account_id: &str) -> Result<crate::types::Reports> {
+        let url = format!(
+            "/v2.1/accounts/{}/reports",
+            self.client.encode_path(account_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/reports",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::reports::Reports::product_post_create`].
+    pub fn product_post_create(
+        &self,
+        account_id: &str,
+        body: &crate::types::ReportInProductRunRequest,
+    ) -> Result<crate::types::ReportInProductSaveResponse> {
+        let url = format!(
+            "/v2.1/accounts/{}/reports",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/reports",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::reports::Reports::product_put_run_results`].
+    pub fn product_put_run_results(
+        &self,
+        account_id: &str,
+        body: &crate::types::ReportInProductRunRequest,
+    ) -> Result<crate::types::ReportInProductRunResponse> {
+        let url = format!(
+            "/v2.1/accounts/{}/reports/report_results",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/reports/report_results",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::reports::Reports::product_put_results_csv`].
+    pub fn product_put_results_csv(
+        &self,
+        account_id: &str,
+        body: &crate::types::ReportInProductCsvRunRequest,
+    ) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/reports/report_results_csv",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/reports/report_results_csv",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::reports::Reports::product_get`].
+    pub fn product_get(
+        &self,
+        account_id: &str,
+        id: &str,
+    ) -> Result<crate::types::ReportInProductGet> {
+        let url = format!(
+            "/v2.1/accounts/{}/reports/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/reports/{id}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("id", id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::reports::Reports::product_put_save`].
+    pub fn product_put_save(
+        &self,
+        account_id: &str,
+        id: &str,
+        body: &crate::types::ReportInProductRunRequest,
+    ) -> Result<crate::types::ReportInProductSaveResponse> {
+        let url = format!(
+            "/v2.1/accounts/{}/reports/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/reports/{id}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("id", id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::reports::Reports::product_delete`].
+    pub fn product_delete(
+        &self,
+        account_id: &str,
+        id: &str,
+    ) -> Result<crate::types::ReportInProductSaveResponse> {
+        let url = format!(
+            "/v2.1/accounts/{}/reports/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(id),
+        );
+
+        self.client.delete(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/reports/{id}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("id", id.to_string()),
+                ],
+            )
+        })
+    }
+}
@@ -0,0 +1,78 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct RequestLogs {
+    pub client: Client,
+}
+
+impl RequestLogs {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        RequestLogs { client }
+    }
+
+    /// The blocking form of [`crate::request_logs::RequestLogs::api_get_log`].
+    pub fn api_get_log(&self, encoding: &str) -> Result<crate::types::ApiRequestLogsResult> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !encoding.is_empty() {
+            query_args.push(("encoding", encoding.to_string()));
+        }
+        let url = crate::Client::url_with_query("/v2.1/diagnostics/request_logs", &query_args);
+
+        self.client
+            .get(&url, None)
+            .with_context(|| crate::utils::pretty_url("GET", "/v2.1/diagnostics/request_logs", &[]))
+    }
+
+    /// The blocking form of [`crate::request_logs::RequestLogs::api_delete_logs`].
+    pub fn api_delete_logs(&self) -> Result<()> {
+        let url = "/v2.1/diagnostics/request_logs".to_string();
+        self.client.delete(&url, None).with_context(|| {
+            crate::utils::pretty_url("DELETE", "/v2.1/diagnostics/request_logs", &[])
+        })
+    }
+
+    /// The blocking form of [`crate::request_logs::RequestLogs::api_delete_logs_if_exists`].
+    pub fn api_delete_logs_if_exists(&self) -> Result<bool> {
+        let url = "/v2.1/diagnostics/request_logs".to_string();
+        self.client.delete_if_exists(&url, None).with_context(|| {
+            crate::utils::pretty_url("DELETE", "/v2.1/diagnostics/request_logs", &[])
+        })
+    }
+
+    /// The blocking form of [`crate::request_logs::RequestLogs::api_get`].
+    pub fn api_get(&self, request_log_id: &str) -> Result<bytes::Bytes> {
+        let url = format!(
+            "/v2.1/diagnostics/request_logs/{}",
+            self.client.encode_path(request_log_id),
+        );
+
+        self.client
+            .request_with_accept_mime(reqwest::Method::GET, &url, "text/plain")
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/v2.1/diagnostics/request_logs/{requestLogId}",
+                    &[("requestLogId", request_log_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::request_logs::RequestLogs::api_get_setting`].
+    pub fn api_get_setting(&self) -> Result<crate::types::DiagnosticsSettingsInformation> {
+        let url = "/v2.1/diagnostics/settings".to_string();
+        self.client
+            .get(&url, None)
+            .with_context(|| crate::utils::pretty_url("GET", "/v2.1/diagnostics/settings", &[]))
+    }
+
+    /// The blocking form of [`crate::request_logs::RequestLogs::api_put_settings`].
+    pub fn api_put_settings(
+        &self,
+        body: &crate::types::DiagnosticsSettingsInformation,
+    ) -> Result<crate::types::DiagnosticsSettingsInformation> {
+        let url = "/v2.1/diagnostics/settings".to_string();
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| crate::utils::pretty_url("PUT", "/v2.1/diagnostics/settings", &[]))
+    }
+}
@@ -0,0 +1,20 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct Resources {
+    pub client: Client,
+}
+
+impl Resources {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        Resources { client }
+    }
+
+    /// The blocking form of [`crate::resources::Resources::service_information_get`].
+    pub fn service_information_get(&self) -> Result<crate::types::ResourceInformation> {
+        let url = "/v2.1".to_string();
+        self.client
+            .get(&url, None)
+            .with_context(|| crate::utils::pretty_url("GET", "/v2.1", &[]))
+    }
+}
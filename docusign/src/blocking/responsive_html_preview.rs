@@ -0,0 +1,39 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct ResponsiveHtmlPreview {
+    pub client: Client,
+}
+
+impl ResponsiveHtmlPreview {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        ResponsiveHtmlPreview { client }
+    }
+
+    /// The blocking form of [`crate::responsive_html_preview::ResponsiveHtmlPreview::responsive_html_post_preview`].
+    pub fn responsive_html_post_preview(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::DocumentHtmlDefinition,
+    ) -> Result<crate::types::DocumentHtmlDefinitions> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/responsive_html_preview",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/responsive_html_preview",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
@@ -0,0 +1,20 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct Services {
+    pub client: Client,
+}
+
+impl Services {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        Services { client }
+    }
+
+    /// The blocking form of [`crate::services::Services::information_get`].
+    pub fn information_get(&self) -> Result<crate::types::ServiceInformation> {
+        let url = "/service_information".to_string();
+        self.client
+            .get(&url, None)
+            .with_context(|| crate::utils::pretty_url("GET", "/service_information", &[]))
+    }
+}
@@ -0,0 +1,90 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct SigningGroupUsers {
+    pub client: Client,
+}
+
+impl SigningGroupUsers {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        SigningGroupUsers { client }
+    }
+
+    /// The blocking form of [`crate::signing_group_users::SigningGroupUsers::signing_groups_get_group_user`].
+    pub fn signing_groups_get_group_user(
+        &self,
+        account_id: &str,
+        signing_group_id: &str,
+    ) -> Result<crate::types::SigningGroupUsersData> {
+        let url = format!(
+            "/v2.1/accounts/{}/signing_groups/{}/users",
+            self.client.encode_path(account_id),
+            self.client.encode_path(signing_group_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/signing_groups/{signingGroupId}/users",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("signingGroupId", signing_group_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::signing_group_users::SigningGroupUsers::signing_groups_put_group_users`].
+    pub fn signing_groups_put_group_users(
+        &self,
+        account_id: &str,
+        signing_group_id: &str,
+        body: &crate::types::SigningGroupUsersData,
+    ) -> Result<crate::types::SigningGroupUsersData> {
+        let url = format!(
+            "/v2.1/accounts/{}/signing_groups/{}/users",
+            self.client.encode_path(account_id),
+            self.client.encode_path(signing_group_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/signing_groups/{signingGroupId}/users",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("signingGroupId", signing_group_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::signing_group_users::SigningGroupUsers::signing_groups_delete_group_users`].
+    pub fn signing_groups_delete_group_users(
+        &self,
+        account_id: &str,
+        signing_group_id: &str,
+        body: &crate::types::SigningGroupUsersData,
+    ) -> Result<crate::types::SigningGroupUsersData> {
+        let url = format!(
+            "/v2.1/accounts/{}/signing_groups/{}/users",
+            self.client.encode_path(account_id),
+            self.client.encode_path(signing_group_id),
+        );
+
+        self.client
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/signing_groups/{signingGroupId}/users",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("signingGroupId", signing_group_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
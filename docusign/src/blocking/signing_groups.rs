@@ -0,0 +1,160 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct SigningGroups {
+    pub client: Client,
+}
+
+impl SigningGroups {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        SigningGroups { client }
+    }
+
+    /// The blocking form of [`crate::signing_groups::SigningGroups::get`].
+    pub fn get(
+        &self,
+        account_id: &str,
+        group_type: &str,
+        include_users: &str,
+    ) -> Result<crate::types::SigningGroupInformation> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !group_type.is_empty() {
+            query_args.push(("group_type", group_type.to_string()));
+        }
+        if !include_users.is_empty() {
+            query_args.push(("include_users", include_users.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/signing_groups",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/signing_groups",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::signing_groups::SigningGroups::put`].
+    pub fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::SigningGroupInformation,
+    ) -> Result<crate::types::SigningGroupInformation> {
+        let url = format!(
+            "/v2.1/accounts/{}/signing_groups",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/signing_groups",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::signing_groups::SigningGroups::post`].
+    pub fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::SigningGroupInformation,
+    ) -> Result<crate::types::SigningGroupInformation> {
+        let url = format!(
+            "/v2.1/accounts/{}/signing_groups",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/signing_groups",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::signing_groups::SigningGroups::delete`].
+    pub fn delete(
+        &self,
+        account_id: &str,
+        body: &crate::types::SigningGroupInformation,
+    ) -> Result<crate::types::SigningGroupInformation> {
+        let url = format!(
+            "/v2.1/accounts/{}/signing_groups",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/signing_groups",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::signing_groups::SigningGroups::get_group`].
+    pub fn get_group(
+        &self,
+        account_id: &str,
+        signing_group_id: &str,
+    ) -> Result<crate::types::SigningGroup> {
+        let url = format!(
+            "/v2.1/accounts/{}/signing_groups/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(signing_group_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/signing_groups/{signingGroupId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("signingGroupId", signing_group_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::signing_groups::SigningGroups::put_group`].
+    pub fn put_group(
+        &self,
+        account_id: &str,
+        signing_group_id: &str,
+        body: &crate::types::SigningGroup,
+    ) -> Result<crate::types::SigningGroup> {
+        let url = format!(
+            "/v2.1/accounts/{}/signing_groups/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(signing_group_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/signing_groups/{signingGroupId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("signingGroupId", signing_group_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
@@ -0,0 +1,52 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct TabsBlob {
+    pub client: Client,
+}
+
+impl TabsBlob {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        TabsBlob { client }
+    }
+
+    /// The blocking form of [`crate::tabs_blob::TabsBlob::get`].
+    pub fn get(&self, account_id: &str, envelope_id: &str) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/tabs_blob",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/tabs_blob",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::tabs_blob::TabsBlob::put`].
+    pub fn put(&self, account_id: &str, envelope_id: &str) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/tabs_blob",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client.put(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "PUT",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/tabs_blob",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
+    }
+}
@@ -0,0 +1,78 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct TemplateBulkRecipients {
+    pub client: Client,
+}
+
+impl TemplateBulkRecipients {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        TemplateBulkRecipients { client }
+    }
+
+    /// The blocking form of [`crate::template_bulk_recipients::TemplateBulkRecipients::recipients_get_template_bulk`].
+    pub fn recipients_get_template_bulk(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        include_tabs: &str,
+        start_position: &str,
+    ) -> Result<crate::types::BulkRecipientsResponse> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !include_tabs.is_empty() {
+            query_args.push(("include_tabs", include_tabs.to_string()));
+        }
+        if !start_position.is_empty() {
+            query_args.push(("start_position", start_position.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/templates/{}/recipients/{}/bulk_recipients",
+                self.client.encode_path(account_id),
+                self.client.encode_path(template_id),
+                self.client.encode_path(recipient_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}/bulk_recipients", &[("accountId", account_id.to_string()), ("templateId", template_id.to_string()), ("recipientId", recipient_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::template_bulk_recipients::TemplateBulkRecipients::recipients_put_template_bulk`].
+    pub fn recipients_put_template_bulk(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        body: &crate::types::BulkRecipientsRequest,
+    ) -> Result<crate::types::BulkRecipientsSummaryResponse> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/recipients/{}/bulk_recipients",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(recipient_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| crate::utils::pretty_url("PUT", "/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}/bulk_recipients", &[("accountId", account_id.to_string()), ("templateId", template_id.to_string()), ("recipientId", recipient_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::template_bulk_recipients::TemplateBulkRecipients::recipients_delete_template_bulk_file`].
+    pub fn recipients_delete_template_bulk_file(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::BulkRecipientsUpdateResponse> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/recipients/{}/bulk_recipients",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(recipient_id),
+        );
+
+        self.client.delete(&url, None).with_context(|| crate::utils::pretty_url("DELETE", "/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}/bulk_recipients", &[("accountId", account_id.to_string()), ("templateId", template_id.to_string()), ("recipientId", recipient_id.to_string())]))
+    }
+}
@@ -0,0 +1,117 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct TemplateCustomFields {
+    pub client: Client,
+}
+
+impl TemplateCustomFields {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        TemplateCustomFields { client }
+    }
+
+    /// The blocking form of [`crate::template_custom_fields::TemplateCustomFields::custom_fields_get_template`].
+    pub fn custom_fields_get_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::CustomFields> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/custom_fields",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/templates/{templateId}/custom_fields",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("templateId", template_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::template_custom_fields::TemplateCustomFields::custom_fields_put_template`].
+    pub fn custom_fields_put_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::CustomFieldsEnvelope,
+    ) -> Result<crate::types::CustomFields> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/custom_fields",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/custom_fields",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::template_custom_fields::TemplateCustomFields::custom_fields_post_template`].
+    pub fn custom_fields_post_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::CustomFieldsEnvelope,
+    ) -> Result<crate::types::CustomFields> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/custom_fields",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/custom_fields",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::template_custom_fields::TemplateCustomFields::custom_fields_delete_template`].
+    pub fn custom_fields_delete_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::CustomFieldsEnvelope,
+    ) -> Result<crate::types::CustomFields> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/custom_fields",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+        );
+
+        self.client
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/custom_fields",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
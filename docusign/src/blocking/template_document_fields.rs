@@ -0,0 +1,99 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct TemplateDocumentFields {
+    pub client: Client,
+}
+
+impl TemplateDocumentFields {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        TemplateDocumentFields { client }
+    }
+
+    /// The blocking form of [`crate::template_document_fields::TemplateDocumentFields::document_fields_get_template`].
+    pub fn document_fields_get_template(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::DocumentFieldsInformation> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/documents/{}/fields",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(document_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/fields",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("templateId", template_id.to_string()),
+                    ("documentId", document_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::template_document_fields::TemplateDocumentFields::document_fields_put_template`].
+    pub fn document_fields_put_template(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::DocumentFieldsInformation,
+    ) -> Result<crate::types::DocumentFieldsInformation> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/documents/{}/fields",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(document_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| crate::utils::pretty_url("PUT", "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/fields", &[("accountId", account_id.to_string()), ("templateId", template_id.to_string()), ("documentId", document_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::template_document_fields::TemplateDocumentFields::document_fields_post_template`].
+    pub fn document_fields_post_template(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::DocumentFieldsInformation,
+    ) -> Result<crate::types::DocumentFieldsInformation> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/documents/{}/fields",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(document_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| crate::utils::pretty_url("POST", "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/fields", &[("accountId", account_id.to_string()), ("templateId", template_id.to_string()), ("documentId", document_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::template_document_fields::TemplateDocumentFields::document_fields_delete_template`].
+    pub fn document_fields_delete_template(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::DocumentFieldsInformation,
+    ) -> Result<crate::types::DocumentFieldsInformation> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/documents/{}/fields",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(document_id),
+        );
+
+        self.client
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| crate::utils::pretty_url("DELETE", "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/fields", &[("accountId", account_id.to_string()), ("templateId", template_id.to_string()), ("documentId", document_id.to_string())]))
+    }
+}
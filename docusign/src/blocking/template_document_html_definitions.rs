@@ -0,0 +1,29 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct TemplateDocumentHtmlDefinitions {
+    pub client: Client,
+}
+
+impl TemplateDocumentHtmlDefinitions {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        TemplateDocumentHtmlDefinitions { client }
+    }
+
+    /// The blocking form of [`crate::template_document_html_definitions::TemplateDocumentHtmlDefinitions::responsive_html_get_template_document_definition`].
+    pub fn responsive_html_get_template_document_definition(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::EnvelopeHtmlDefinitions> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/documents/{}/html_definitions",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(document_id),
+        );
+
+        self.client.get(&url, None).with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/html_definitions", &[("accountId", account_id.to_string()), ("templateId", template_id.to_string()), ("documentId", document_id.to_string())]))
+    }
+}
@@ -0,0 +1,32 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct TemplateDocumentResponsiveHtmlPreview {
+    pub client: Client,
+}
+
+impl TemplateDocumentResponsiveHtmlPreview {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        TemplateDocumentResponsiveHtmlPreview { client }
+    }
+
+    /// The blocking form of [`crate::template_document_responsive_html_preview::TemplateDocumentResponsiveHtmlPreview::responsive_html_post_template_document_preview`].
+    pub fn responsive_html_post_template_document_preview(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::DocumentHtmlDefinition,
+    ) -> Result<crate::types::DocumentHtmlDefinitions> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/documents/{}/responsive_html_preview",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(document_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| crate::utils::pretty_url("POST", "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/responsive_html_preview", &[("accountId", account_id.to_string()), ("templateId", template_id.to_string()), ("documentId", document_id.to_string())]))
+    }
+}
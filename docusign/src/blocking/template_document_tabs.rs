@@ -0,0 +1,156 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct TemplateDocumentTabs {
+    pub client: Client,
+}
+
+impl TemplateDocumentTabs {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        TemplateDocumentTabs { client }
+    }
+
+    /// The blocking form of [`crate::template_document_tabs::TemplateDocumentTabs::tabs_get_template_page`].
+    pub fn tabs_get_template_page(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+    ) -> Result<crate::types::TemplateTabs> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/documents/{}/pages/{}/tabs",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(document_id),
+            self.client.encode_path(page_number),
+        );
+
+        self.client.get(&url, None).with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/pages/{pageNumber}/tabs", &[("accountId", account_id.to_string()), ("templateId", template_id.to_string()), ("documentId", document_id.to_string()), ("pageNumber", page_number.to_string())]))
+    }
+
+    /// The blocking form of [`crate::template_document_tabs::TemplateDocumentTabs::tabs_get_template_document`].
+    pub fn tabs_get_template_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        page_numbers: &str,
+    ) -> Result<crate::types::TemplateTabs> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !page_numbers.is_empty() {
+            query_args.push(("page_numbers", page_numbers.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/templates/{}/documents/{}/tabs",
+                self.client.encode_path(account_id),
+                self.client.encode_path(template_id),
+                self.client.encode_path(document_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/tabs",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("templateId", template_id.to_string()),
+                    ("documentId", document_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::template_document_tabs::TemplateDocumentTabs::tabs_put_template_document`].
+    pub fn tabs_put_template_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateTabs,
+    ) -> Result<crate::types::Tabs> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/documents/{}/tabs",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(document_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/tabs",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                        ("documentId", document_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::template_document_tabs::TemplateDocumentTabs::tabs_post_template_document`].
+    pub fn tabs_post_template_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateTabs,
+    ) -> Result<crate::types::Tabs> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/documents/{}/tabs",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(document_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/tabs",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                        ("documentId", document_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::template_document_tabs::TemplateDocumentTabs::tabs_delete_template_document`].
+    pub fn tabs_delete_template_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateTabs,
+    ) -> Result<crate::types::Tabs> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/documents/{}/tabs",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(document_id),
+        );
+
+        self.client
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/tabs",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                        ("documentId", document_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct TemplateDocumentVisibility {
+    pub client: Client,
+}
+
+impl TemplateDocumentVisibility {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        TemplateDocumentVisibility { client }
+    }
+
+    /// The blocking form of [`crate::template_document_visibility::TemplateDocumentVisibility::recipients_put`].
+    pub fn recipients_put(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateDocumentVisibilityList,
+    ) -> Result<crate::types::TemplateDocumentVisibilityList> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/recipients/document_visibility",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| crate::utils::pretty_url("PUT", "/v2.1/accounts/{accountId}/templates/{templateId}/recipients/document_visibility", &[("accountId", account_id.to_string()), ("templateId", template_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::template_document_visibility::TemplateDocumentVisibility::recipients_get_template_recipient_document_visibility`].
+    pub fn recipients_get_template_recipient_document_visibility(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::DocumentVisibilityList> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/recipients/{}/document_visibility",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(recipient_id),
+        );
+
+        self.client.get(&url, None).with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}/document_visibility", &[("accountId", account_id.to_string()), ("templateId", template_id.to_string()), ("recipientId", recipient_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::template_document_visibility::TemplateDocumentVisibility::recipients_put_template_recipient_document_visibility`].
+    pub fn recipients_put_template_recipient_document_visibility(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateDocumentVisibilityList,
+    ) -> Result<crate::types::TemplateDocumentVisibilityList> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/recipients/{}/document_visibility",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(recipient_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| crate::utils::pretty_url("PUT", "/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}/document_visibility", &[("accountId", account_id.to_string()), ("templateId", template_id.to_string()), ("recipientId", recipient_id.to_string())]))
+    }
+}
@@ -0,0 +1,177 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct TemplateDocuments {
+    pub client: Client,
+}
+
+impl TemplateDocuments {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        TemplateDocuments { client }
+    }
+
+    /// The blocking form of [`crate::template_documents::TemplateDocuments::documents_get_template`].
+    pub fn documents_get_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        include_tabs: &str,
+    ) -> Result<crate::types::TemplateDocumentsResult> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !include_tabs.is_empty() {
+            query_args.push(("include_tabs", include_tabs.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/templates/{}/documents",
+                self.client.encode_path(account_id),
+                self.client.encode_path(template_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/templates/{templateId}/documents",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("templateId", template_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::template_documents::TemplateDocuments::documents_put_template`].
+    pub fn documents_put_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> Result<crate::types::TemplateDocumentsResult> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/documents",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/documents",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::template_documents::TemplateDocuments::documents_delete_template`].
+    pub fn documents_delete_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> Result<crate::types::TemplateDocumentsResult> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/documents",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+        );
+
+        self.client
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/documents",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::template_documents::TemplateDocuments::documents_get`].
+    pub fn documents_get(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        encrypt: &str,
+        show_changes: &str,
+    ) -> Result<()> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !encrypt.is_empty() {
+            query_args.push(("encrypt", encrypt.to_string()));
+        }
+        if !show_changes.is_empty() {
+            query_args.push(("show_changes", show_changes.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/templates/{}/documents/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(template_id),
+                self.client.encode_path(document_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .request_with_accept_mime(reqwest::Method::GET, &url, "application/pdf")
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                        ("documentId", document_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::template_documents::TemplateDocuments::documents_put`].
+    pub fn documents_put(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        is_envelope_definition: &str,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> Result<crate::types::EnvelopeDocument> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !is_envelope_definition.is_empty() {
+            query_args.push(("is_envelope_definition", is_envelope_definition.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/templates/{}/documents/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(template_id),
+                self.client.encode_path(document_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                        ("documentId", document_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
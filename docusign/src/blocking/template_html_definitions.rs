@@ -0,0 +1,36 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct TemplateHtmlDefinitions {
+    pub client: Client,
+}
+
+impl TemplateHtmlDefinitions {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        TemplateHtmlDefinitions { client }
+    }
+
+    /// The blocking form of [`crate::template_html_definitions::TemplateHtmlDefinitions::responsive_html_get_template_definition`].
+    pub fn responsive_html_get_template_definition(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::EnvelopeHtmlDefinitions> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/html_definitions",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/templates/{templateId}/html_definitions",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("templateId", template_id.to_string()),
+                ],
+            )
+        })
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct TemplateRecipientTabs {
+    pub client: Client,
+}
+
+impl TemplateRecipientTabs {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        TemplateRecipientTabs { client }
+    }
+
+    /// The blocking form of [`crate::template_recipient_tabs::TemplateRecipientTabs::recipients_get`].
+    pub fn recipients_get(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        include_anchor_tab_locations: &str,
+        include_metadata: &str,
+    ) -> Result<crate::types::Tabs> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !include_anchor_tab_locations.is_empty() {
+            query_args.push((
+                "include_anchor_tab_locations",
+                include_anchor_tab_locations.to_string(),
+            ));
+        }
+        if !include_metadata.is_empty() {
+            query_args.push(("include_metadata", include_metadata.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/templates/{}/recipients/{}/tabs",
+                self.client.encode_path(account_id),
+                self.client.encode_path(template_id),
+                self.client.encode_path(recipient_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}/tabs",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("templateId", template_id.to_string()),
+                    ("recipientId", recipient_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::template_recipient_tabs::TemplateRecipientTabs::recipients_put`].
+    pub fn recipients_put(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateTabs,
+    ) -> Result<crate::types::Tabs> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/recipients/{}/tabs",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(recipient_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| crate::utils::pretty_url("PUT", "/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}/tabs", &[("accountId", account_id.to_string()), ("templateId", template_id.to_string()), ("recipientId", recipient_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::template_recipient_tabs::TemplateRecipientTabs::recipients_post`].
+    pub fn recipients_post(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateTabs,
+    ) -> Result<crate::types::Tabs> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/recipients/{}/tabs",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(recipient_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| crate::utils::pretty_url("POST", "/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}/tabs", &[("accountId", account_id.to_string()), ("templateId", template_id.to_string()), ("recipientId", recipient_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::template_recipient_tabs::TemplateRecipientTabs::recipients_delete`].
+    pub fn recipients_delete(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateTabs,
+    ) -> Result<crate::types::Tabs> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/recipients/{}/tabs",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(recipient_id),
+        );
+
+        self.client
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| crate::utils::pretty_url("DELETE", "/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}/tabs", &[("accountId", account_id.to_string()), ("templateId", template_id.to_string()), ("recipientId", recipient_id.to_string())]))
+    }
+}
@@ -0,0 +1,209 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct TemplateRecipients {
+    pub client: Client,
+}
+
+impl TemplateRecipients {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        TemplateRecipients { client }
+    }
+
+    /// The blocking form of [`crate::template_recipients::TemplateRecipients::recipients_get_template`].
+    pub fn recipients_get_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        include_anchor_tab_locations: &str,
+        include_extended: &str,
+        include_tabs: &str,
+    ) -> Result<crate::types::Recipients> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !include_anchor_tab_locations.is_empty() {
+            query_args.push((
+                "include_anchor_tab_locations",
+                include_anchor_tab_locations.to_string(),
+            ));
+        }
+        if !include_extended.is_empty() {
+            query_args.push(("include_extended", include_extended.to_string()));
+        }
+        if !include_tabs.is_empty() {
+            query_args.push(("include_tabs", include_tabs.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/templates/{}/recipients",
+                self.client.encode_path(account_id),
+                self.client.encode_path(template_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/templates/{templateId}/recipients",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("templateId", template_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::template_recipients::TemplateRecipients::recipients_put_template`].
+    pub fn recipients_put_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        resend_envelope: &str,
+        body: &crate::types::TemplateRecipientsData,
+    ) -> Result<crate::types::RecipientsUpdateSummary> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !resend_envelope.is_empty() {
+            query_args.push(("resend_envelope", resend_envelope.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/templates/{}/recipients",
+                self.client.encode_path(account_id),
+                self.client.encode_path(template_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/recipients",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::template_recipients::TemplateRecipients::recipients_post_template`].
+    pub fn recipients_post_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        resend_envelope: &str,
+        body: &crate::types::TemplateRecipientsData,
+    ) -> Result<crate::types::Recipients> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !resend_envelope.is_empty() {
+            query_args.push(("resend_envelope", resend_envelope.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/templates/{}/recipients",
+                self.client.encode_path(account_id),
+                self.client.encode_path(template_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/recipients",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::template_recipients::TemplateRecipients::recipients_delete_template`].
+    pub fn recipients_delete_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateRecipientsData,
+    ) -> Result<crate::types::Recipients> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/recipients",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+        );
+
+        self.client
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/recipients",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::template_recipients::TemplateRecipients::recipients_delete`].
+    pub fn recipients_delete(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateRecipientsData,
+    ) -> Result<crate::types::Recipients> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/recipients/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(recipient_id),
+        );
+
+        self.client
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                        ("recipientId", recipient_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::template_recipients::TemplateRecipients::views_post_preview`].
+    pub fn views_post_preview(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::RecipientPreviewRequest,
+    ) -> Result<crate::types::ViewUrl> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/views/recipient_preview",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/views/recipient_preview",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
@@ -0,0 +1,39 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct TemplateResponsiveHtmlPreview {
+    pub client: Client,
+}
+
+impl TemplateResponsiveHtmlPreview {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        TemplateResponsiveHtmlPreview { client }
+    }
+
+    /// The blocking form of [`crate::template_responsive_html_preview::TemplateResponsiveHtmlPreview::responsive_html_post_template_preview`].
+    pub fn responsive_html_post_template_preview(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::DocumentHtmlDefinition,
+    ) -> Result<crate::types::DocumentHtmlDefinitions> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/responsive_html_preview",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/responsive_html_preview",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
@@ -0,0 +1,39 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct TemplateViews {
+    pub client: Client,
+}
+
+impl TemplateViews {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        TemplateViews { client }
+    }
+
+    /// The blocking form of [`crate::template_views::TemplateViews::views_post_template_edit_view`].
+    pub fn views_post_template_edit_view(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::ReturnUrlRequest,
+    ) -> Result<crate::types::ViewUrl> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/views/edit",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/views/edit",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
@@ -0,0 +1,487 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct Templates {
+    pub client: Client,
+}
+
+impl Templates {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        Templates { client }
+    }
+
+    /// The blocking form of [`crate::templates::Templates::get`].
+    pub fn get(
+        &self,
+        account_id: &str,
+        count: &str,
+        created_from_date: &str,
+        created_to_date: &str,
+        folder_ids: &str,
+        folder_types: &str,
+        from_date: &str,
+        include: &str,
+        is_deleted_template_only: &str,
+        is_download: &str,
+        modified_from_date: &str,
+        modified_to_date: &str,
+        order: &str,
+        order_by: &str,
+        search_fields: &str,
+        search_text: &str,
+        shared_by_me: &str,
+        start_position: &str,
+        template_ids: &str,
+        to_date: &str,
+        used_from_date: &str,
+        used_to_date: &str,
+        user_filter: &str,
+        user_id: &str,
+    ) -> Result<crate::types::EnvelopeTemplateResults> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !count.is_empty() {
+            query_args.push(("count", count.to_string()));
+        }
+        if !created_from_date.is_empty() {
+            query_args.push(("created_from_date", created_from_date.to_string()));
+        }
+        if !created_to_date.is_empty() {
+            query_args.push(("created_to_date", created_to_date.to_string()));
+        }
+        if !folder_ids.is_empty() {
+            query_args.push(("folder_ids", folder_ids.to_string()));
+        }
+        if !folder_types.is_empty() {
+            query_args.push(("folder_types", folder_types.to_string()));
+        }
+        if !from_date.is_empty() {
+            query_args.push(("from_date", from_date.to_string()));
+        }
+        if !include.is_empty() {
+            query_args.push(("include", include.to_string()));
+        }
+        if !is_deleted_template_only.is_empty() {
+            query_args.push((
+                "is_deleted_template_only",
+                is_deleted_template_only.to_string(),
+            ));
+        }
+        if !is_download.is_empty() {
+            query_args.push(("is_download", is_download.to_string()));
+        }
+        if !modified_from_date.is_empty() {
+            query_args.push(("modified_from_date", modified_from_date.to_string()));
+        }
+        if !modified_to_date.is_empty() {
+            query_args.push(("modified_to_date", modified_to_date.to_string()));
+        }
+        if !order.is_empty() {
+            query_args.push(("order", order.to_string()));
+        }
+        if !order_by.is_empty() {
+            query_args.push(("order_by", order_by.to_string()));
+        }
+        if !search_fields.is_empty() {
+            query_args.push(("search_fields", search_fields.to_string()));
+        }
+        if !search_text.is_empty() {
+            query_args.push(("search_text", search_text.to_string()));
+        }
+        if !shared_by_me.is_empty() {
+            query_args.push(("shared_by_me", shared_by_me.to_string()));
+        }
+        if !start_position.is_empty() {
+            query_args.push(("start_position", start_position.to_string()));
+        }
+        if !template_ids.is_empty() {
+            query_args.push(("template_ids", template_ids.to_string()));
+        }
+        if !to_date.is_empty() {
+            query_args.push(("to_date", to_date.to_string()));
+        }
+        if !used_from_date.is_empty() {
+            query_args.push(("used_from_date", used_from_date.to_string()));
+        }
+        if !used_to_date.is_empty() {
+            query_args.push(("used_to_date", used_to_date.to_string()));
+        }
+        if !user_filter.is_empty() {
+            query_args.push(("user_filter", user_filter.to_string()));
+        }
+        if !user_id.is_empty() {
+            query_args.push(("user_id", user_id.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/templates",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/templates",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::templates::Templates::post`].
+    pub fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::EnvelopeTemplate,
+    ) -> Result<crate::types::TemplateSummary> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/templates",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::templates::Templates::get_templates`].
+    pub fn get_templates(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        include: &str,
+    ) -> Result<crate::types::EnvelopeTemplate> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !include.is_empty() {
+            query_args.push(("include", include.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/templates/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(template_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/templates/{templateId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("templateId", template_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::templates::Templates::put`].
+    pub fn put(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::EnvelopeTemplate,
+    ) -> Result<crate::types::EnvelopeUpdateSummary> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::templates::Templates::pages_get_page_image`].
+    pub fn pages_get_page_image(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        count: &str,
+        dpi: &str,
+        max_height: &str,
+        max_width: &str,
+        nocache: &str,
+        show_changes: &str,
+        start_position: &str,
+    ) -> Result<crate::types::PageImages> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !count.is_empty() {
+            query_args.push(("count", count.to_string()));
+        }
+        if !dpi.is_empty() {
+            query_args.push(("dpi", dpi.to_string()));
+        }
+        if !max_height.is_empty() {
+            query_args.push(("max_height", max_height.to_string()));
+        }
+        if !max_width.is_empty() {
+            query_args.push(("max_width", max_width.to_string()));
+        }
+        if !nocache.is_empty() {
+            query_args.push(("nocache", nocache.to_string()));
+        }
+        if !show_changes.is_empty() {
+            query_args.push(("show_changes", show_changes.to_string()));
+        }
+        if !start_position.is_empty() {
+            query_args.push(("start_position", start_position.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/templates/{}/documents/{}/pages",
+                self.client.encode_path(account_id),
+                self.client.encode_path(template_id),
+                self.client.encode_path(document_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/pages",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("templateId", template_id.to_string()),
+                    ("documentId", document_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::templates::Templates::pages_delete_page`].
+    pub fn pages_delete_page(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+        body: &crate::types::PageRequest,
+    ) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/documents/{}/pages/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(document_id),
+            self.client.encode_path(page_number),
+        );
+
+        self.client
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| crate::utils::pretty_url("DELETE", "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/pages/{pageNumber}", &[("accountId", account_id.to_string()), ("templateId", template_id.to_string()), ("documentId", document_id.to_string()), ("pageNumber", page_number.to_string())]))
+    }
+
+    /// The blocking form of [`crate::templates::Templates::pages_delete_page_if_exists`].
+    pub fn pages_delete_page_if_exists(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+        body: &crate::types::PageRequest,
+    ) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/documents/{}/pages/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(document_id),
+            self.client.encode_path(page_number),
+        );
+
+        self.client
+            .delete_if_exists(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| crate::utils::pretty_url("DELETE", "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/pages/{pageNumber}", &[("accountId", account_id.to_string()), ("templateId", template_id.to_string()), ("documentId", document_id.to_string()), ("pageNumber", page_number.to_string())]))
+    }
+
+    /// The blocking form of [`crate::templates::Templates::pages_get_page_image_templates`].
+    pub fn pages_get_page_image_templates(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+        dpi: &str,
+        max_height: &str,
+        max_width: &str,
+        show_changes: &str,
+    ) -> Result<()> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !dpi.is_empty() {
+            query_args.push(("dpi", dpi.to_string()));
+        }
+        if !max_height.is_empty() {
+            query_args.push(("max_height", max_height.to_string()));
+        }
+        if !max_width.is_empty() {
+            query_args.push(("max_width", max_width.to_string()));
+        }
+        if !show_changes.is_empty() {
+            query_args.push(("show_changes", show_changes.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/templates/{}/documents/{}/pages/{}/page_image",
+                self.client.encode_path(account_id),
+                self.client.encode_path(template_id),
+                self.client.encode_path(document_id),
+                self.client.encode_path(page_number)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .request_with_accept_mime(reqwest::Method::GET, &url, "image/png").with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/pages/{pageNumber}/page_image", &[("accountId", account_id.to_string()), ("templateId", template_id.to_string()), ("documentId", document_id.to_string()), ("pageNumber", page_number.to_string())]))
+    }
+
+    /// The blocking form of [`crate::templates::Templates::pages_put_page_image`].
+    pub fn pages_put_page_image(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+        body: &crate::types::PageRequest,
+    ) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/documents/{}/pages/{}/page_image",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(document_id),
+            self.client.encode_path(page_number),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| crate::utils::pretty_url("PUT", "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/pages/{pageNumber}/page_image", &[("accountId", account_id.to_string()), ("templateId", template_id.to_string()), ("documentId", document_id.to_string()), ("pageNumber", page_number.to_string())]))
+    }
+
+    /// The blocking form of [`crate::templates::Templates::notification_get`].
+    pub fn notification_get(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::Notification> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/notification",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/templates/{templateId}/notification",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("templateId", template_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::templates::Templates::notification_put`].
+    pub fn notification_put(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateNotificationRequest,
+    ) -> Result<crate::types::Notification> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/notification",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/notification",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::templates::Templates::put_part`].
+    pub fn put_part(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        template_part: &str,
+        body: &crate::types::GroupInformation,
+    ) -> Result<crate::types::GroupInformation> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(template_part),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/{templatePart}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                        ("templatePart", template_part.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::templates::Templates::delete_part`].
+    pub fn delete_part(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        template_part: &str,
+        body: &crate::types::GroupInformation,
+    ) -> Result<crate::types::GroupInformation> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(template_part),
+        );
+
+        self.client
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/{templatePart}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                        ("templatePart", template_part.to_string()),
+                    ],
+                )
+            })
+    }
+}
@@ -0,0 +1,90 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct UserCustomSettings {
+    pub client: Client,
+}
+
+impl UserCustomSettings {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        UserCustomSettings { client }
+    }
+
+    /// The blocking form of [`crate::user_custom_settings::UserCustomSettings::get`].
+    pub fn get(
+        &self,
+        account_id: &str,
+        user_id: &str,
+    ) -> Result<crate::types::CustomSettingsInformation> {
+        let url = format!(
+            "/v2.1/accounts/{}/users/{}/custom_settings",
+            self.client.encode_path(account_id),
+            self.client.encode_path(user_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/users/{userId}/custom_settings",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("userId", user_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::user_custom_settings::UserCustomSettings::put`].
+    pub fn put(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::CustomSettingsInformation,
+    ) -> Result<crate::types::CustomSettingsInformation> {
+        let url = format!(
+            "/v2.1/accounts/{}/users/{}/custom_settings",
+            self.client.encode_path(account_id),
+            self.client.encode_path(user_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/users/{userId}/custom_settings",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("userId", user_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::user_custom_settings::UserCustomSettings::delete`].
+    pub fn delete(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::CustomSettingsInformation,
+    ) -> Result<crate::types::CustomSettingsInformation> {
+        let url = format!(
+            "/v2.1/accounts/{}/users/{}/custom_settings",
+            self.client.encode_path(account_id),
+            self.client.encode_path(user_id),
+        );
+
+        self.client
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/users/{userId}/custom_settings",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("userId", user_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
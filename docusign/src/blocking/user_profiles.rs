@@ -0,0 +1,59 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct UserProfiles {
+    pub client: Client,
+}
+
+impl UserProfiles {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        UserProfiles { client }
+    }
+
+    /// The blocking form of [`crate::user_profiles::UserProfiles::get`].
+    pub fn get(&self, account_id: &str, user_id: &str) -> Result<crate::types::UserProfile> {
+        let url = format!(
+            "/v2.1/accounts/{}/users/{}/profile",
+            self.client.encode_path(account_id),
+            self.client.encode_path(user_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/users/{userId}/profile",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("userId", user_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::user_profiles::UserProfiles::put`].
+    pub fn put(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::UserProfile,
+    ) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/users/{}/profile",
+            self.client.encode_path(account_id),
+            self.client.encode_path(user_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/users/{userId}/profile",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("userId", user_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
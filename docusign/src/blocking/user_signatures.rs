@@ -0,0 +1,316 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct UserSignatures {
+    pub client: Client,
+}
+
+impl UserSignatures {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        UserSignatures { client }
+    }
+
+    /// The blocking form of [`crate::user_signatures::UserSignatures::get`].
+    pub fn get(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        stamp_type: &str,
+    ) -> Result<crate::types::UserSignaturesInformation> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !stamp_type.is_empty() {
+            query_args.push(("stamp_type", stamp_type.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/users/{}/signatures",
+                self.client.encode_path(account_id),
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/users/{userId}/signatures",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("userId", user_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::user_signatures::UserSignatures::put_signature`].
+    pub fn put_signature(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::UserSignaturesInformation,
+    ) -> Result<crate::types::UserSignaturesInformation> {
+        let url = format!(
+            "/v2.1/accounts/{}/users/{}/signatures",
+            self.client.encode_path(account_id),
+            self.client.encode_path(user_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/users/{userId}/signatures",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("userId", user_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::user_signatures::UserSignatures::post`].
+    pub fn post(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::UserSignaturesInformation,
+    ) -> Result<crate::types::UserSignaturesInformation> {
+        let url = format!(
+            "/v2.1/accounts/{}/users/{}/signatures",
+            self.client.encode_path(account_id),
+            self.client.encode_path(user_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/users/{userId}/signatures",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("userId", user_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::user_signatures::UserSignatures::get_signature`].
+    pub fn get_signature(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+        user_id: &str,
+    ) -> Result<crate::types::UserSignature> {
+        let url = format!(
+            "/v2.1/accounts/{}/users/{}/signatures/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(user_id),
+            self.client.encode_path(signature_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/users/{userId}/signatures/{signatureId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("userId", user_id.to_string()),
+                    ("signatureId", signature_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::user_signatures::UserSignatures::put_signature_user_signatures`].
+    pub fn put_signature_user_signatures(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+        user_id: &str,
+        close_existing_signature: &str,
+        body: &crate::types::UserSignatureDefinition,
+    ) -> Result<crate::types::UserSignature> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !close_existing_signature.is_empty() {
+            query_args.push((
+                "close_existing_signature",
+                close_existing_signature.to_string(),
+            ));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/users/{}/signatures/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(user_id),
+                self.client.encode_path(signature_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/users/{userId}/signatures/{signatureId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("userId", user_id.to_string()),
+                        ("signatureId", signature_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::user_signatures::UserSignatures::delete_signature`].
+    pub fn delete_signature(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+        user_id: &str,
+    ) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/users/{}/signatures/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(user_id),
+            self.client.encode_path(signature_id),
+        );
+
+        self.client.delete(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/users/{userId}/signatures/{signatureId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("userId", user_id.to_string()),
+                    ("signatureId", signature_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::user_signatures::UserSignatures::delete_signature_if_exists`].
+    pub fn delete_signature_if_exists(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+        user_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/users/{}/signatures/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(user_id),
+            self.client.encode_path(signature_id),
+        );
+
+        self.client.delete_if_exists(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/users/{userId}/signatures/{signatureId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("userId", user_id.to_string()),
+                    ("signatureId", signature_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::user_signatures::UserSignatures::get_signature_image`].
+    pub fn get_signature_image(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        user_id: &str,
+        include_chrome: &str,
+    ) -> Result<()> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !include_chrome.is_empty() {
+            query_args.push(("include_chrome", include_chrome.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/users/{}/signatures/{}/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(user_id),
+                self.client.encode_path(signature_id),
+                self.client.encode_path(image_type)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .request_with_accept_mime(reqwest::Method::GET, &url, "image/gif").with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/users/{userId}/signatures/{signatureId}/{imageType}", &[("accountId", account_id.to_string()), ("userId", user_id.to_string()), ("signatureId", signature_id.to_string()), ("imageType", image_type.to_string())]))
+    }
+
+    /// The blocking form of [`crate::user_signatures::UserSignatures::put_signature_image`].
+    pub fn put_signature_image(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        user_id: &str,
+        transparent_png: &str,
+    ) -> Result<crate::types::UserSignature> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !transparent_png.is_empty() {
+            query_args.push(("transparent_png", transparent_png.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/users/{}/signatures/{}/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(user_id),
+                self.client.encode_path(signature_id),
+                self.client.encode_path(image_type)
+            ),
+            &query_args,
+        );
+
+        self.client.put(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "PUT",
+                "/v2.1/accounts/{accountId}/users/{userId}/signatures/{signatureId}/{imageType}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("userId", user_id.to_string()),
+                    ("signatureId", signature_id.to_string()),
+                    ("imageType", image_type.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::user_signatures::UserSignatures::delete_signature_image`].
+    pub fn delete_signature_image(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        user_id: &str,
+    ) -> Result<crate::types::UserSignature> {
+        let url = format!(
+            "/v2.1/accounts/{}/users/{}/signatures/{}/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(user_id),
+            self.client.encode_path(signature_id),
+            self.client.encode_path(image_type),
+        );
+
+        self.client.delete(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/users/{userId}/signatures/{signatureId}/{imageType}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("userId", user_id.to_string()),
+                    ("signatureId", signature_id.to_string()),
+                    ("imageType", image_type.to_string()),
+                ],
+            )
+        })
+    }
+}
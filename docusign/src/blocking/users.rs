@@ -0,0 +1,359 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct Users {
+    pub client: Client,
+}
+
+impl Users {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        Users { client }
+    }
+
+    /// The blocking form of [`crate::users::Users::get`].
+    pub fn get(
+        &self,
+        account_id: &str,
+        additional_info: &str,
+        count: &str,
+        email: &str,
+        email_substring: &str,
+        group_id: &str,
+        include_usersettings_for_csv: &str,
+        login_status: &str,
+        not_group_id: &str,
+        start_position: &str,
+        status: &str,
+        user_name_substring: &str,
+    ) -> Result<crate::types::UserInformationList> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !additional_info.is_empty() {
+            query_args.push(("additional_info", additional_info.to_string()));
+        }
+        if !count.is_empty() {
+            query_args.push(("count", count.to_string()));
+        }
+        if !email.is_empty() {
+            query_args.push(("email", email.to_string()));
+        }
+        if !email_substring.is_empty() {
+            query_args.push(("email_substring", email_substring.to_string()));
+        }
+        if !group_id.is_empty() {
+            query_args.push(("group_id", group_id.to_string()));
+        }
+        if !include_usersettings_for_csv.is_empty() {
+            query_args.push((
+                "include_usersettings_for_csv",
+                include_usersettings_for_csv.to_string(),
+            ));
+        }
+        if !login_status.is_empty() {
+            query_args.push(("login_status", login_status.to_string()));
+        }
+        if !not_group_id.is_empty() {
+            query_args.push(("not_group_id", not_group_id.to_string()));
+        }
+        if !start_position.is_empty() {
+            query_args.push(("start_position", start_position.to_string()));
+        }
+        if !status.is_empty() {
+            query_args.push(("status", status.to_string()));
+        }
+        if !user_name_substring.is_empty() {
+            query_args.push(("user_name_substring", user_name_substring.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/users",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/users",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::users::Users::put`].
+    pub fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::UserInformationList,
+    ) -> Result<crate::types::UserInformationList> {
+        let url = format!(
+            "/v2.1/accounts/{}/users",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/users",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::users::Users::post`].
+    pub fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::NewUsersDefinition,
+    ) -> Result<crate::types::NewUsersSummary> {
+        let url = format!(
+            "/v2.1/accounts/{}/users",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/users",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::users::Users::delete`].
+    pub fn delete(
+        &self,
+        account_id: &str,
+        delete: &str,
+        body: &crate::types::UserInfoList,
+    ) -> Result<crate::types::UsersResponse> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !delete.is_empty() {
+            query_args.push(("delete", delete.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/users",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/users",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::users::Users::get_users`].
+    pub fn get_users(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        additional_info: &str,
+        email: &str,
+    ) -> Result<crate::types::UserInformation> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !additional_info.is_empty() {
+            query_args.push(("additional_info", additional_info.to_string()));
+        }
+        if !email.is_empty() {
+            query_args.push(("email", email.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/users/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/users/{userId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("userId", user_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::users::Users::put_users`].
+    pub fn put_users(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::UserInformation,
+    ) -> Result<crate::types::UserInformation> {
+        let url = format!(
+            "/v2.1/accounts/{}/users/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(user_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/users/{userId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("userId", user_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::users::Users::profile_image_get`].
+    pub fn profile_image_get(&self, account_id: &str, user_id: &str, encoding: &str) -> Result<()> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !encoding.is_empty() {
+            query_args.push(("encoding", encoding.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/users/{}/profile/image",
+                self.client.encode_path(account_id),
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .request_with_accept_mime(reqwest::Method::GET, &url, "image/gif")
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/v2.1/accounts/{accountId}/users/{userId}/profile/image",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("userId", user_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::users::Users::profile_image_put`].
+    pub fn profile_image_put(&self, account_id: &str, user_id: &str) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/users/{}/profile/image",
+            self.client.encode_path(account_id),
+            self.client.encode_path(user_id),
+        );
+
+        self.client.put(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "PUT",
+                "/v2.1/accounts/{accountId}/users/{userId}/profile/image",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("userId", user_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::users::Users::profile_image_delete`].
+    pub fn profile_image_delete(&self, account_id: &str, user_id: &str) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/users/{}/profile/image",
+            self.client.encode_path(account_id),
+            self.client.encode_path(user_id),
+        );
+
+        self.client.delete(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/users/{userId}/profile/image",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("userId", user_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::users::Users::profile_image_delete_if_exists`].
+    pub fn profile_image_delete_if_exists(&self, account_id: &str, user_id: &str) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/users/{}/profile/image",
+            self.client.encode_path(account_id),
+            self.client.encode_path(user_id),
+        );
+
+        self.client.delete_if_exists(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/users/{userId}/profile/image",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("userId", user_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::users::Users::settings_get`].
+    pub fn settings_get(
+        &self,
+        account_id: &str,
+        user_id: &str,
+    ) -> Result<crate::types::UserSettingsInformation> {
+        let url = format!(
+            "/v2.1/accounts/{}/users/{}/settings",
+            self.client.encode_path(account_id),
+            self.client.encode_path(user_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/users/{userId}/settings",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("userId", user_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::users::Users::settings_put`].
+    pub fn settings_put(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::UserSettingsInformation,
+    ) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/users/{}/settings",
+            self.client.encode_path(account_id),
+            self.client.encode_path(user_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/users/{userId}/settings",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("userId", user_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
@@ -0,0 +1,251 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct WorkspaceItems {
+    pub client: Client,
+}
+
+impl WorkspaceItems {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        WorkspaceItems { client }
+    }
+
+    /// The blocking form of [`crate::workspace_items::WorkspaceItems::workspace_folder_get`].
+    pub fn workspace_folder_get(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+        count: &str,
+        include_files: &str,
+        include_sub_folders: &str,
+        include_thumbnails: &str,
+        include_user_detail: &str,
+        start_position: &str,
+        workspace_user_id: &str,
+    ) -> Result<crate::types::WorkspaceFolderContents> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !count.is_empty() {
+            query_args.push(("count", count.to_string()));
+        }
+        if !include_files.is_empty() {
+            query_args.push(("include_files", include_files.to_string()));
+        }
+        if !include_sub_folders.is_empty() {
+            query_args.push(("include_sub_folders", include_sub_folders.to_string()));
+        }
+        if !include_thumbnails.is_empty() {
+            query_args.push(("include_thumbnails", include_thumbnails.to_string()));
+        }
+        if !include_user_detail.is_empty() {
+            query_args.push(("include_user_detail", include_user_detail.to_string()));
+        }
+        if !start_position.is_empty() {
+            query_args.push(("start_position", start_position.to_string()));
+        }
+        if !workspace_user_id.is_empty() {
+            query_args.push(("workspace_user_id", workspace_user_id.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/workspaces/{}/folders/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(workspace_id),
+                self.client.encode_path(folder_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/workspaces/{workspaceId}/folders/{folderId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("workspaceId", workspace_id.to_string()),
+                    ("folderId", folder_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::workspace_items::WorkspaceItems::workspace_folder_delete_items`].
+    pub fn workspace_folder_delete_items(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+        body: &crate::types::WorkspaceItemList,
+    ) -> Result<()> {
+        let url = format!(
+            "/v2.1/accounts/{}/workspaces/{}/folders/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(workspace_id),
+            self.client.encode_path(folder_id),
+        );
+
+        self.client
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/workspaces/{workspaceId}/folders/{folderId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("workspaceId", workspace_id.to_string()),
+                        ("folderId", folder_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::workspace_items::WorkspaceItems::workspace_folder_delete_items_if_exists`].
+    pub fn workspace_folder_delete_items_if_exists(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+        body: &crate::types::WorkspaceItemList,
+    ) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/workspaces/{}/folders/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(workspace_id),
+            self.client.encode_path(folder_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/workspaces/{workspaceId}/folders/{folderId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("workspaceId", workspace_id.to_string()),
+                        ("folderId", folder_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::workspace_items::WorkspaceItems::workspace_file_post_files`].
+    pub fn workspace_file_post_files(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+    ) -> Result<crate::types::WorkspaceItem> {
+        let url = format!(
+            "/v2.1/accounts/{}/workspaces/{}/folders/{}/files",
+            self.client.encode_path(account_id),
+            self.client.encode_path(workspace_id),
+            self.client.encode_path(folder_id),
+        );
+
+        self.client.post(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/v2.1/accounts/{accountId}/workspaces/{workspaceId}/folders/{folderId}/files",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("workspaceId", workspace_id.to_string()),
+                    ("folderId", folder_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::workspace_items::WorkspaceItems::workspace_file_get`].
+    pub fn workspace_file_get(
+        &self,
+        account_id: &str,
+        file_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+        is_download: &str,
+        pdf_version: &str,
+    ) -> Result<()> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !is_download.is_empty() {
+            query_args.push(("is_download", is_download.to_string()));
+        }
+        if !pdf_version.is_empty() {
+            query_args.push(("pdf_version", pdf_version.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/workspaces/{}/folders/{}/files/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(workspace_id),
+                self.client.encode_path(folder_id),
+                self.client.encode_path(file_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/workspaces/{workspaceId}/folders/{folderId}/files/{fileId}", &[("accountId", account_id.to_string()), ("workspaceId", workspace_id.to_string()), ("folderId", folder_id.to_string()), ("fileId", file_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::workspace_items::WorkspaceItems::workspace_file_put`].
+    pub fn workspace_file_put(
+        &self,
+        account_id: &str,
+        file_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+    ) -> Result<crate::types::WorkspaceItem> {
+        let url = format!(
+            "/v2.1/accounts/{}/workspaces/{}/folders/{}/files/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(workspace_id),
+            self.client.encode_path(folder_id),
+            self.client.encode_path(file_id),
+        );
+
+        self.client.put(&url, None).with_context(|| crate::utils::pretty_url("PUT", "/v2.1/accounts/{accountId}/workspaces/{workspaceId}/folders/{folderId}/files/{fileId}", &[("accountId", account_id.to_string()), ("workspaceId", workspace_id.to_string()), ("folderId", folder_id.to_string()), ("fileId", file_id.to_string())]))
+    }
+
+    /// The blocking form of [`crate::workspace_items::WorkspaceItems::workspace_file_pages_get`].
+    pub fn workspace_file_pages_get(
+        &self,
+        account_id: &str,
+        file_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+        count: &str,
+        dpi: &str,
+        max_height: &str,
+        max_width: &str,
+        start_position: &str,
+    ) -> Result<crate::types::PageImages> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !count.is_empty() {
+            query_args.push(("count", count.to_string()));
+        }
+        if !dpi.is_empty() {
+            query_args.push(("dpi", dpi.to_string()));
+        }
+        if !max_height.is_empty() {
+            query_args.push(("max_height", max_height.to_string()));
+        }
+        if !max_width.is_empty() {
+            query_args.push(("max_width", max_width.to_string()));
+        }
+        if !start_position.is_empty() {
+            query_args.push(("start_position", start_position.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/workspaces/{}/folders/{}/files/{}/pages",
+                self.client.encode_path(account_id),
+                self.client.encode_path(workspace_id),
+                self.client.encode_path(folder_id),
+                self.client.encode_path(file_id)
+            ),
+            &query_args,
+        );
+
+        self.client.get(&url, None).with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/workspaces/{workspaceId}/folders/{folderId}/files/{fileId}/pages", &[("accountId", account_id.to_string()), ("workspaceId", workspace_id.to_string()), ("folderId", folder_id.to_string()), ("fileId", file_id.to_string())]))
+    }
+}
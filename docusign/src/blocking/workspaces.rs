@@ -0,0 +1,121 @@
+use crate::{blocking::Client, CallContext, ClientResult as Result};
+
+pub struct Workspaces {
+    pub client: Client,
+}
+
+impl Workspaces {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        Workspaces { client }
+    }
+
+    /// The blocking form of [`crate::workspaces::Workspaces::get`].
+    pub fn get(&self, account_id: &str) -> Result<crate::types::WorkspaceList> {
+        let url = format!(
+            "/v2.1/accounts/{}/workspaces",
+            self.client.encode_path(account_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/workspaces",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::workspaces::Workspaces::post`].
+    pub fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::Workspace,
+    ) -> Result<crate::types::Workspace> {
+        let url = format!(
+            "/v2.1/accounts/{}/workspaces",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/workspaces",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::workspaces::Workspaces::get_workspaces`].
+    pub fn get_workspaces(
+        &self,
+        account_id: &str,
+        workspace_id: &str,
+    ) -> Result<crate::types::Workspace> {
+        let url = format!(
+            "/v2.1/accounts/{}/workspaces/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(workspace_id),
+        );
+
+        self.client.get(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/workspaces/{workspaceId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("workspaceId", workspace_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /// The blocking form of [`crate::workspaces::Workspaces::put`].
+    pub fn put(
+        &self,
+        account_id: &str,
+        workspace_id: &str,
+        body: &crate::types::Workspace,
+    ) -> Result<crate::types::Workspace> {
+        let url = format!(
+            "/v2.1/accounts/{}/workspaces/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(workspace_id),
+        );
+
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/workspaces/{workspaceId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("workspaceId", workspace_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The blocking form of [`crate::workspaces::Workspaces::delete`].
+    pub fn delete(&self, account_id: &str, workspace_id: &str) -> Result<crate::types::Workspace> {
+        let url = format!(
+            "/v2.1/accounts/{}/workspaces/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(workspace_id),
+        );
+
+        self.client.delete(&url, None).with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/workspaces/{workspaceId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("workspaceId", workspace_id.to_string()),
+                ],
+            )
+        })
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct BulkSend {
     pub client: Client,
@@ -12,6 +10,14 @@ impl BulkSend {
         BulkSend { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        BulkSend {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Returns a list of bulk send batch summaries. .
      *
@@ -44,27 +50,34 @@ impl BulkSend {
         start_position: &str,
         status: &str,
     ) -> Result<crate::types::BulkSendBatchSummaries> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !batch_ids.is_empty() {
-            query_args.push(("batch_ids".to_string(), batch_ids.to_string()));
+            query_args.push(("batch_ids", batch_ids.to_string()));
         }
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/bulk_send_batch?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/bulk_send_batch",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/bulk_send_batch",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -92,11 +105,20 @@ impl BulkSend {
     ) -> Result<crate::types::BulkSendBatchStatus> {
         let url = format!(
             "/v2.1/accounts/{}/bulk_send_batch/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&bulk_send_batch_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(bulk_send_batch_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/bulk_send_batch/{bulkSendBatchId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("bulkSendBatchId", bulk_send_batch_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -119,13 +141,23 @@ impl BulkSend {
     ) -> Result<crate::types::BulkSendBatchStatus> {
         let url = format!(
             "/v2.1/accounts/{}/bulk_send_batch/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&bulk_send_batch_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(bulk_send_batch_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/bulk_send_batch/{bulkSendBatchId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("bulkSendBatchId", bulk_send_batch_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -145,10 +177,16 @@ impl BulkSend {
     ) -> Result<crate::types::BulkSendingListSummaries> {
         let url = format!(
             "/v2.1/accounts/{}/bulk_send_lists",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/bulk_send_lists",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -195,12 +233,19 @@ impl BulkSend {
     ) -> Result<crate::types::BulkSendingList> {
         let url = format!(
             "/v2.1/accounts/{}/bulk_send_lists",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/bulk_send_lists",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -222,11 +267,20 @@ impl BulkSend {
     ) -> Result<crate::types::BulkSendingList> {
         let url = format!(
             "/v2.1/accounts/{}/bulk_send_lists/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&bulk_send_list_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(bulk_send_list_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/bulk_send_lists/{bulkSendListId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("bulkSendListId", bulk_send_list_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -249,13 +303,23 @@ impl BulkSend {
     ) -> Result<crate::types::BulkSendingList> {
         let url = format!(
             "/v2.1/accounts/{}/bulk_send_lists/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&bulk_send_list_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(bulk_send_list_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/bulk_send_lists/{bulkSendListId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("bulkSendListId", bulk_send_list_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -277,11 +341,20 @@ impl BulkSend {
     ) -> Result<crate::types::BulkSendingListSummaries> {
         let url = format!(
             "/v2.1/accounts/{}/bulk_send_lists/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&bulk_send_list_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(bulk_send_list_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/bulk_send_lists/{bulkSendListId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("bulkSendListId", bulk_send_list_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -341,13 +414,23 @@ impl BulkSend {
     ) -> Result<crate::types::BulkSendResponse> {
         let url = format!(
             "/v2.1/accounts/{}/bulk_send_lists/{}/send",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&bulk_send_list_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(bulk_send_list_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/bulk_send_lists/{bulkSendListId}/send",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("bulkSendListId", bulk_send_list_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -407,12 +490,167 @@ impl BulkSend {
     ) -> Result<crate::types::BulkSendTestResponse> {
         let url = format!(
             "/v2.1/accounts/{}/bulk_send_lists/{}/test",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&bulk_send_list_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(bulk_send_list_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/bulk_send_lists/{bulkSendListId}/test",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("bulkSendListId", bulk_send_list_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`BulkSend`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn BulkSendApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait BulkSendApi: Send + Sync {
+    async fn batch_get_batche(
+        &self,
+        account_id: &str,
+        batch_ids: &str,
+        count: &str,
+        start_position: &str,
+        status: &str,
+    ) -> Result<crate::types::BulkSendBatchSummaries>;
+    async fn batch_get_statu(
+        &self,
+        account_id: &str,
+        bulk_send_batch_id: &str,
+    ) -> Result<crate::types::BulkSendBatchStatus>;
+    async fn batch_put_status(
+        &self,
+        account_id: &str,
+        bulk_send_batch_id: &str,
+        body: &crate::types::BulkSendBatchRequest,
+    ) -> Result<crate::types::BulkSendBatchStatus>;
+    async fn v_2crud_get_list(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::BulkSendingListSummaries>;
+    async fn v_2crud_post_list(
+        &self,
+        account_id: &str,
+        body: &crate::types::BulkSendingList,
+    ) -> Result<crate::types::BulkSendingList>;
+    async fn v_2crud_get_list_bulk_send(
+        &self,
+        account_id: &str,
+        bulk_send_list_id: &str,
+    ) -> Result<crate::types::BulkSendingList>;
+    async fn v_2crud_put_list(
+        &self,
+        account_id: &str,
+        bulk_send_list_id: &str,
+        body: &crate::types::BulkSendingList,
+    ) -> Result<crate::types::BulkSendingList>;
+    async fn v_2crud_delete_list(
+        &self,
+        account_id: &str,
+        bulk_send_list_id: &str,
+    ) -> Result<crate::types::BulkSendingListSummaries>;
+    async fn post_request(
+        &self,
+        account_id: &str,
+        bulk_send_list_id: &str,
+        body: &crate::types::BulkSendRequest,
+    ) -> Result<crate::types::BulkSendResponse>;
+    async fn test_post_request(
+        &self,
+        account_id: &str,
+        bulk_send_list_id: &str,
+        body: &crate::types::BulkSendRequest,
+    ) -> Result<crate::types::BulkSendTestResponse>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl BulkSendApi for BulkSend {
+    async fn batch_get_batche(
+        &self,
+        account_id: &str,
+        batch_ids: &str,
+        count: &str,
+        start_position: &str,
+        status: &str,
+    ) -> Result<crate::types::BulkSendBatchSummaries> {
+        BulkSend::batch_get_batche(self, account_id, batch_ids, count, start_position, status).await
+    }
+    async fn batch_get_statu(
+        &self,
+        account_id: &str,
+        bulk_send_batch_id: &str,
+    ) -> Result<crate::types::BulkSendBatchStatus> {
+        BulkSend::batch_get_statu(self, account_id, bulk_send_batch_id).await
+    }
+    async fn batch_put_status(
+        &self,
+        account_id: &str,
+        bulk_send_batch_id: &str,
+        body: &crate::types::BulkSendBatchRequest,
+    ) -> Result<crate::types::BulkSendBatchStatus> {
+        BulkSend::batch_put_status(self, account_id, bulk_send_batch_id, body).await
+    }
+    async fn v_2crud_get_list(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::BulkSendingListSummaries> {
+        BulkSend::v_2crud_get_list(self, account_id).await
+    }
+    async fn v_2crud_post_list(
+        &self,
+        account_id: &str,
+        body: &crate::types::BulkSendingList,
+    ) -> Result<crate::types::BulkSendingList> {
+        BulkSend::v_2crud_post_list(self, account_id, body).await
+    }
+    async fn v_2crud_get_list_bulk_send(
+        &self,
+        account_id: &str,
+        bulk_send_list_id: &str,
+    ) -> Result<crate::types::BulkSendingList> {
+        BulkSend::v_2crud_get_list_bulk_send(self, account_id, bulk_send_list_id).await
+    }
+    async fn v_2crud_put_list(
+        &self,
+        account_id: &str,
+        bulk_send_list_id: &str,
+        body: &crate::types::BulkSendingList,
+    ) -> Result<crate::types::BulkSendingList> {
+        BulkSend::v_2crud_put_list(self, account_id, bulk_send_list_id, body).await
+    }
+    async fn v_2crud_delete_list(
+        &self,
+        account_id: &str,
+        bulk_send_list_id: &str,
+    ) -> Result<crate::types::BulkSendingListSummaries> {
+        BulkSend::v_2crud_delete_list(self, account_id, bulk_send_list_id).await
+    }
+    async fn post_request(
+        &self,
+        account_id: &str,
+        bulk_send_list_id: &str,
+        body: &crate::types::BulkSendRequest,
+    ) -> Result<crate::types::BulkSendResponse> {
+        BulkSend::post_request(self, account_id, bulk_send_list_id, body).await
+    }
+    async fn test_post_request(
+        &self,
+        account_id: &str,
+        bulk_send_list_id: &str,
+        body: &crate::types::BulkSendRequest,
+    ) -> Result<crate::types::BulkSendTestResponse> {
+        BulkSend::test_post_request(self, account_id, bulk_send_list_id, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct ChunkedUploads {
     pub client: Client,
@@ -12,6 +10,14 @@ impl ChunkedUploads {
         ChunkedUploads { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        ChunkedUploads {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Initiate a new chunked upload.
      *
@@ -30,12 +36,19 @@ impl ChunkedUploads {
     ) -> Result<crate::types::ChunkedUploadResponse> {
         let url = format!(
             "/v2.1/accounts/{}/chunked_uploads",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/chunked_uploads",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -59,19 +72,29 @@ impl ChunkedUploads {
         chunked_upload_id: &str,
         include: &str,
     ) -> Result<crate::types::ChunkedUploadResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include.is_empty() {
-            query_args.push(("include".to_string(), include.to_string()));
+            query_args.push(("include", include.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/chunked_uploads/{}?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&chunked_upload_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/chunked_uploads/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(chunked_upload_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/chunked_uploads/{chunkedUploadId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("chunkedUploadId", chunked_upload_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -97,19 +120,29 @@ impl ChunkedUploads {
         chunked_upload_id: &str,
         action: &str,
     ) -> Result<crate::types::ChunkedUploadResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !action.is_empty() {
-            query_args.push(("action".to_string(), action.to_string()));
+            query_args.push(("action", action.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/chunked_uploads/{}?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&chunked_upload_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/chunked_uploads/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(chunked_upload_id)
+            ),
+            &query_args,
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "PUT",
+                "/v2.1/accounts/{accountId}/chunked_uploads/{chunkedUploadId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("chunkedUploadId", chunked_upload_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -139,11 +172,20 @@ impl ChunkedUploads {
     ) -> Result<crate::types::ChunkedUploadResponse> {
         let url = format!(
             "/v2.1/accounts/{}/chunked_uploads/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&chunked_upload_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(chunked_upload_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/chunked_uploads/{chunkedUploadId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("chunkedUploadId", chunked_upload_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -184,13 +226,101 @@ impl ChunkedUploads {
     ) -> Result<crate::types::ChunkedUploadResponse> {
         let url = format!(
             "/v2.1/accounts/{}/chunked_uploads/{}/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&chunked_upload_id.to_string()),
-            crate::progenitor_support::encode_path(&chunked_upload_part_seq.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(chunked_upload_id),
+            self.client.encode_path(chunked_upload_part_seq),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("PUT", "/v2.1/accounts/{accountId}/chunked_uploads/{chunkedUploadId}/{chunkedUploadPartSeq}", &[("accountId", account_id.to_string()), ("chunkedUploadId", chunked_upload_id.to_string()), ("chunkedUploadPartSeq", chunked_upload_part_seq.to_string())]))
+    }
+}
+
+/// The functions of [`ChunkedUploads`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ChunkedUploadsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ChunkedUploadsApi: Send + Sync {
+    async fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::ChunkedUploadRequest,
+    ) -> Result<crate::types::ChunkedUploadResponse>;
+    async fn get_upload(
+        &self,
+        account_id: &str,
+        chunked_upload_id: &str,
+        include: &str,
+    ) -> Result<crate::types::ChunkedUploadResponse>;
+    async fn put(
+        &self,
+        account_id: &str,
+        chunked_upload_id: &str,
+        action: &str,
+    ) -> Result<crate::types::ChunkedUploadResponse>;
+    async fn delete_upload(
+        &self,
+        account_id: &str,
+        chunked_upload_id: &str,
+    ) -> Result<crate::types::ChunkedUploadResponse>;
+    async fn put_upload_part(
+        &self,
+        account_id: &str,
+        chunked_upload_id: &str,
+        chunked_upload_part_seq: &str,
+        body: &crate::types::ChunkedUploadRequest,
+    ) -> Result<crate::types::ChunkedUploadResponse>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ChunkedUploadsApi for ChunkedUploads {
+    async fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::ChunkedUploadRequest,
+    ) -> Result<crate::types::ChunkedUploadResponse> {
+        ChunkedUploads::post(self, account_id, body).await
+    }
+    async fn get_upload(
+        &self,
+        account_id: &str,
+        chunked_upload_id: &str,
+        include: &str,
+    ) -> Result<crate::types::ChunkedUploadResponse> {
+        ChunkedUploads::get_upload(self, account_id, chunked_upload_id, include).await
+    }
+    async fn put(
+        &self,
+        account_id: &str,
+        chunked_upload_id: &str,
+        action: &str,
+    ) -> Result<crate::types::ChunkedUploadResponse> {
+        ChunkedUploads::put(self, account_id, chunked_upload_id, action).await
+    }
+    async fn delete_upload(
+        &self,
+        account_id: &str,
+        chunked_upload_id: &str,
+    ) -> Result<crate::types::ChunkedUploadResponse> {
+        ChunkedUploads::delete_upload(self, account_id, chunked_upload_id).await
+    }
+    async fn put_upload_part(
+        &self,
+        account_id: &str,
+        chunked_upload_id: &str,
+        chunked_upload_part_seq: &str,
+        body: &crate::types::ChunkedUploadRequest,
+    ) -> Result<crate::types::ChunkedUploadResponse> {
+        ChunkedUploads::put_upload_part(
+            self,
+            account_id,
+            chunked_upload_id,
+            chunked_upload_part_seq,
+            body,
+        )
+        .await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct CloudStorage {
     pub client: Client,
@@ -12,6 +10,14 @@ impl CloudStorage {
         CloudStorage { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        CloudStorage {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieves a list of all the items in a specified folder from the specified cloud storage provider.
      *
@@ -59,38 +65,49 @@ impl CloudStorage {
         search_text: &str,
         start_position: &str,
     ) -> Result<crate::types::ExternalFolder> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cloud_storage_folder_path.is_empty() {
             query_args.push((
-                "cloud_storage_folder_path".to_string(),
+                "cloud_storage_folder_path",
                 cloud_storage_folder_path.to_string(),
             ));
         }
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !order_by.is_empty() {
-            query_args.push(("order_by".to_string(), order_by.to_string()));
+            query_args.push(("order_by", order_by.to_string()));
         }
         if !search_text.is_empty() {
-            query_args.push(("search_text".to_string(), search_text.to_string()));
+            query_args.push(("search_text", search_text.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/users/{}/cloud_storage/{}/folders?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&service_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/users/{}/cloud_storage/{}/folders",
+                self.client.encode_path(account_id),
+                self.client.encode_path(user_id),
+                self.client.encode_path(service_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/users/{userId}/cloud_storage/{serviceId}/folders",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("userId", user_id.to_string()),
+                    ("serviceId", service_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -146,44 +163,140 @@ impl CloudStorage {
         search_text: &str,
         start_position: &str,
     ) -> Result<crate::types::ExternalFolder> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cloud_storage_folder_path.is_empty() {
             query_args.push((
-                "cloud_storage_folder_path".to_string(),
+                "cloud_storage_folder_path",
                 cloud_storage_folder_path.to_string(),
             ));
         }
         if !cloud_storage_folderid_plain.is_empty() {
             query_args.push((
-                "cloud_storage_folderid_plain".to_string(),
+                "cloud_storage_folderid_plain",
                 cloud_storage_folderid_plain.to_string(),
             ));
         }
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !order_by.is_empty() {
-            query_args.push(("order_by".to_string(), order_by.to_string()));
+            query_args.push(("order_by", order_by.to_string()));
         }
         if !search_text.is_empty() {
-            query_args.push(("search_text".to_string(), search_text.to_string()));
+            query_args.push(("search_text", search_text.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/users/{}/cloud_storage/{}/folders/{}?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&service_id.to_string()),
-            crate::progenitor_support::encode_path(&folder_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/users/{}/cloud_storage/{}/folders/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(user_id),
+                self.client.encode_path(service_id),
+                self.client.encode_path(folder_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/users/{userId}/cloud_storage/{serviceId}/folders/{folderId}", &[("accountId", account_id.to_string()), ("userId", user_id.to_string()), ("serviceId", service_id.to_string()), ("folderId", folder_id.to_string())]))
+    }
+}
+
+/// The functions of [`CloudStorage`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn CloudStorageApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait CloudStorageApi: Send + Sync {
+    async fn folder_get_all(
+        &self,
+        account_id: &str,
+        service_id: &str,
+        user_id: &str,
+        cloud_storage_folder_path: &str,
+        count: &str,
+        order: &str,
+        order_by: &str,
+        search_text: &str,
+        start_position: &str,
+    ) -> Result<crate::types::ExternalFolder>;
+    async fn folder_get(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        service_id: &str,
+        user_id: &str,
+        cloud_storage_folder_path: &str,
+        cloud_storage_folderid_plain: &str,
+        count: &str,
+        order: &str,
+        order_by: &str,
+        search_text: &str,
+        start_position: &str,
+    ) -> Result<crate::types::ExternalFolder>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl CloudStorageApi for CloudStorage {
+    async fn folder_get_all(
+        &self,
+        account_id: &str,
+        service_id: &str,
+        user_id: &str,
+        cloud_storage_folder_path: &str,
+        count: &str,
+        order: &str,
+        order_by: &str,
+        search_text: &str,
+        start_position: &str,
+    ) -> Result<crate::types::ExternalFolder> {
+        CloudStorage::folder_get_all(
+            self,
+            account_id,
+            service_id,
+            user_id,
+            cloud_storage_folder_path,
+            count,
+            order,
+            order_by,
+            search_text,
+            start_position,
+        )
+        .await
+    }
+    async fn folder_get(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        service_id: &str,
+        user_id: &str,
+        cloud_storage_folder_path: &str,
+        cloud_storage_folderid_plain: &str,
+        count: &str,
+        order: &str,
+        order_by: &str,
+        search_text: &str,
+        start_position: &str,
+    ) -> Result<crate::types::ExternalFolder> {
+        CloudStorage::folder_get(
+            self,
+            account_id,
+            folder_id,
+            service_id,
+            user_id,
+            cloud_storage_folder_path,
+            cloud_storage_folderid_plain,
+            count,
+            order,
+            order_by,
+            search_text,
+            start_position,
+        )
+        .await
     }
 }
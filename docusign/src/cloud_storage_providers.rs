@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct CloudStorageProviders {
     pub client: Client,
@@ -12,6 +10,14 @@ impl CloudStorageProviders {
         CloudStorageProviders { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        CloudStorageProviders {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get the Cloud Storage Provider configuration for the specified user.
      *
@@ -36,19 +42,29 @@ impl CloudStorageProviders {
         user_id: &str,
         redirect_url: &str,
     ) -> Result<crate::types::CloudStorageProvidersData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !redirect_url.is_empty() {
-            query_args.push(("redirectUrl".to_string(), redirect_url.to_string()));
+            query_args.push(("redirectUrl", redirect_url.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/users/{}/cloud_storage?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/users/{}/cloud_storage",
+                self.client.encode_path(account_id),
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/users/{userId}/cloud_storage",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("userId", user_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -72,13 +88,23 @@ impl CloudStorageProviders {
     ) -> Result<crate::types::CloudStorageProvidersData> {
         let url = format!(
             "/v2.1/accounts/{}/users/{}/cloud_storage",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(user_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/users/{userId}/cloud_storage",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("userId", user_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -102,13 +128,23 @@ impl CloudStorageProviders {
     ) -> Result<crate::types::CloudStorageProvidersData> {
         let url = format!(
             "/v2.1/accounts/{}/users/{}/cloud_storage",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(user_id),
         );
 
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .delete(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/users/{userId}/cloud_storage",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("userId", user_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -138,20 +174,31 @@ impl CloudStorageProviders {
         user_id: &str,
         redirect_url: &str,
     ) -> Result<crate::types::CloudStorageProvidersData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !redirect_url.is_empty() {
-            query_args.push(("redirectUrl".to_string(), redirect_url.to_string()));
+            query_args.push(("redirectUrl", redirect_url.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/users/{}/cloud_storage/{}?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&service_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/users/{}/cloud_storage/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(user_id),
+                self.client.encode_path(service_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/users/{userId}/cloud_storage/{serviceId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("userId", user_id.to_string()),
+                    ("serviceId", service_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -178,11 +225,114 @@ impl CloudStorageProviders {
     ) -> Result<crate::types::CloudStorageProvidersData> {
         let url = format!(
             "/v2.1/accounts/{}/users/{}/cloud_storage/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&service_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(user_id),
+            self.client.encode_path(service_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/users/{userId}/cloud_storage/{serviceId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("userId", user_id.to_string()),
+                    ("serviceId", service_id.to_string()),
+                ],
+            )
+        })
+    }
+}
+
+/// The functions of [`CloudStorageProviders`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn CloudStorageProvidersApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait CloudStorageProvidersApi: Send + Sync {
+    async fn cloud_storage_get_provider(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        redirect_url: &str,
+    ) -> Result<crate::types::CloudStorageProvidersData>;
+    async fn cloud_storage_post(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::CloudStorageProvidersData,
+    ) -> Result<crate::types::CloudStorageProvidersData>;
+    async fn cloud_storage_delete_providers(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::CloudStorageProvidersData,
+    ) -> Result<crate::types::CloudStorageProvidersData>;
+    async fn cloud_storage_get(
+        &self,
+        account_id: &str,
+        service_id: &str,
+        user_id: &str,
+        redirect_url: &str,
+    ) -> Result<crate::types::CloudStorageProvidersData>;
+    async fn cloud_storage_delete(
+        &self,
+        account_id: &str,
+        service_id: &str,
+        user_id: &str,
+    ) -> Result<crate::types::CloudStorageProvidersData>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl CloudStorageProvidersApi for CloudStorageProviders {
+    async fn cloud_storage_get_provider(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        redirect_url: &str,
+    ) -> Result<crate::types::CloudStorageProvidersData> {
+        CloudStorageProviders::cloud_storage_get_provider(self, account_id, user_id, redirect_url)
+            .await
+    }
+    async fn cloud_storage_post(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::CloudStorageProvidersData,
+    ) -> Result<crate::types::CloudStorageProvidersData> {
+        CloudStorageProviders::cloud_storage_post(self, account_id, user_id, body).await
+    }
+    async fn cloud_storage_delete_providers(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::CloudStorageProvidersData,
+    ) -> Result<crate::types::CloudStorageProvidersData> {
+        CloudStorageProviders::cloud_storage_delete_providers(self, account_id, user_id, body).await
+    }
+    async fn cloud_storage_get(
+        &self,
+        account_id: &str,
+        service_id: &str,
+        user_id: &str,
+        redirect_url: &str,
+    ) -> Result<crate::types::CloudStorageProvidersData> {
+        CloudStorageProviders::cloud_storage_get(
+            self,
+            account_id,
+            service_id,
+            user_id,
+            redirect_url,
+        )
+        .await
+    }
+    async fn cloud_storage_delete(
+        &self,
+        account_id: &str,
+        service_id: &str,
+        user_id: &str,
+    ) -> Result<crate::types::CloudStorageProvidersData> {
+        CloudStorageProviders::cloud_storage_delete(self, account_id, service_id, user_id).await
     }
 }
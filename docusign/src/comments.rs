@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Comments {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Comments {
         Comments { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Comments {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets a PDF transcript of all of the comments in an envelope.
      *
@@ -33,18 +39,100 @@ impl Comments {
         envelope_id: &str,
         encoding: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !encoding.is_empty() {
+            query_args.push(("encoding", encoding.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/comments/transcript",
+                self.client.encode_path(account_id),
+                self.client.encode_path(envelope_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .request_with_accept_mime(reqwest::Method::GET, &url, "application/pdf")
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/comments/transcript",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * Gets a PDF transcript of all of the comments in an envelope.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/comments/transcript` endpoint.
+     *
+     * As opposed to `get_transcript`, this function asks for the PDF and streams its bytes as they arrive, rather than returning them all at once.
+     * This is useful for large documents that would otherwise need to be held in memory.
+     */
+    pub async fn get_transcript_stream(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        encoding: &str,
+    ) -> Result<impl futures::Stream<Item = Result<bytes::Bytes>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !encoding.is_empty() {
-            query_args.push(("encoding".to_string(), encoding.to_string()));
+            query_args.push(("encoding", encoding.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/{}/comments/transcript?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/comments/transcript",
+                self.client.encode_path(account_id),
+                self.client.encode_path(envelope_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client
+            .get_stream_bytes(&url, "application/pdf")
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/comments/transcript",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`Comments`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn CommentsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait CommentsApi: Send + Sync {
+    async fn get_transcript(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        encoding: &str,
+    ) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl CommentsApi for Comments {
+    async fn get_transcript(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        encoding: &str,
+    ) -> Result<()> {
+        Comments::get_transcript(self, account_id, envelope_id, encoding).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct ConnectConfigurations {
     pub client: Client,
@@ -12,6 +10,14 @@ impl ConnectConfigurations {
         ConnectConfigurations { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        ConnectConfigurations {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get Connect Configuration Information.
      *
@@ -31,10 +37,16 @@ impl ConnectConfigurations {
     ) -> Result<crate::types::ConnectConfigResults> {
         let url = format!(
             "/v2.1/accounts/{}/connect",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/connect",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -57,12 +69,19 @@ impl ConnectConfigurations {
     ) -> Result<crate::types::ConnectCustomConfiguration> {
         let url = format!(
             "/v2.1/accounts/{}/connect",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/connect",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -86,12 +105,19 @@ impl ConnectConfigurations {
     ) -> Result<crate::types::ConnectCustomConfiguration> {
         let url = format!(
             "/v2.1/accounts/{}/connect",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/connect",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -116,11 +142,20 @@ impl ConnectConfigurations {
     ) -> Result<crate::types::ConnectConfigResults> {
         let url = format!(
             "/v2.1/accounts/{}/connect/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&connect_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(connect_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/connect/{connectId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("connectId", connect_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -143,11 +178,53 @@ impl ConnectConfigurations {
     pub async fn connect_delete_config(&self, account_id: &str, connect_id: &str) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/connect/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&connect_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(connect_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/connect/{connectId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("connectId", connect_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Deletes the specified connect configuration.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/connect/{connectId}` endpoint.
+     *
+     * As opposed to `connect_delete_config`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn connect_delete_config_if_exists(
+        &self,
+        account_id: &str,
+        connect_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/connect/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(connect_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/connect/{connectId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("connectId", connect_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -190,39 +267,156 @@ impl ConnectConfigurations {
         status: &str,
         user_name_substring: &str,
     ) -> Result<crate::types::IntegratedUserInfoList> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !email_substring.is_empty() {
-            query_args.push(("email_substring".to_string(), email_substring.to_string()));
+            query_args.push(("email_substring", email_substring.to_string()));
         }
         if !list_included_users.is_empty() {
-            query_args.push((
-                "list_included_users".to_string(),
-                list_included_users.to_string(),
-            ));
+            query_args.push(("list_included_users", list_included_users.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !user_name_substring.is_empty() {
-            query_args.push((
-                "user_name_substring".to_string(),
-                user_name_substring.to_string(),
-            ));
+            query_args.push(("user_name_substring", user_name_substring.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/connect/{}/users?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&connect_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/connect/{}/users",
+                self.client.encode_path(account_id),
+                self.client.encode_path(connect_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/connect/{connectId}/users",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("connectId", connect_id.to_string()),
+                ],
+            )
+        })
+    }
+}
+
+/// The functions of [`ConnectConfigurations`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ConnectConfigurationsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ConnectConfigurationsApi: Send + Sync {
+    async fn connect_get_config(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::ConnectConfigResults>;
+    async fn connect_put_configuration(
+        &self,
+        account_id: &str,
+        body: &crate::types::ConnectCustomConfiguration,
+    ) -> Result<crate::types::ConnectCustomConfiguration>;
+    async fn connect_post_configuration(
+        &self,
+        account_id: &str,
+        body: &crate::types::ConnectCustomConfiguration,
+    ) -> Result<crate::types::ConnectCustomConfiguration>;
+    async fn connect_get_config_connect_configurations(
+        &self,
+        account_id: &str,
+        connect_id: &str,
+    ) -> Result<crate::types::ConnectConfigResults>;
+    async fn connect_delete_config(&self, account_id: &str, connect_id: &str) -> Result<()>;
+    async fn connect_delete_config_if_exists(
+        &self,
+        account_id: &str,
+        connect_id: &str,
+    ) -> Result<bool>;
+    async fn connect_get_user(
+        &self,
+        account_id: &str,
+        connect_id: &str,
+        count: &str,
+        email_substring: &str,
+        list_included_users: &str,
+        start_position: &str,
+        status: &str,
+        user_name_substring: &str,
+    ) -> Result<crate::types::IntegratedUserInfoList>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ConnectConfigurationsApi for ConnectConfigurations {
+    async fn connect_get_config(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::ConnectConfigResults> {
+        ConnectConfigurations::connect_get_config(self, account_id).await
+    }
+    async fn connect_put_configuration(
+        &self,
+        account_id: &str,
+        body: &crate::types::ConnectCustomConfiguration,
+    ) -> Result<crate::types::ConnectCustomConfiguration> {
+        ConnectConfigurations::connect_put_configuration(self, account_id, body).await
+    }
+    async fn connect_post_configuration(
+        &self,
+        account_id: &str,
+        body: &crate::types::ConnectCustomConfiguration,
+    ) -> Result<crate::types::ConnectCustomConfiguration> {
+        ConnectConfigurations::connect_post_configuration(self, account_id, body).await
+    }
+    async fn connect_get_config_connect_configurations(
+        &self,
+        account_id: &str,
+        connect_id: &str,
+    ) -> Result<crate::types::ConnectConfigResults> {
+        ConnectConfigurations::connect_get_config_connect_configurations(
+            self, account_id, connect_id,
+        )
+        .await
+    }
+    async fn connect_delete_config(&self, account_id: &str, connect_id: &str) -> Result<()> {
+        ConnectConfigurations::connect_delete_config(self, account_id, connect_id).await
+    }
+    async fn connect_delete_config_if_exists(
+        &self,
+        account_id: &str,
+        connect_id: &str,
+    ) -> Result<bool> {
+        ConnectConfigurations::connect_delete_config_if_exists(self, account_id, connect_id).await
+    }
+    async fn connect_get_user(
+        &self,
+        account_id: &str,
+        connect_id: &str,
+        count: &str,
+        email_substring: &str,
+        list_included_users: &str,
+        start_position: &str,
+        status: &str,
+        user_name_substring: &str,
+    ) -> Result<crate::types::IntegratedUserInfoList> {
+        ConnectConfigurations::connect_get_user(
+            self,
+            account_id,
+            connect_id,
+            count,
+            email_substring,
+            list_included_users,
+            start_position,
+            status,
+            user_name_substring,
+        )
+        .await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct ConnectEvents {
     pub client: Client,
@@ -12,6 +10,14 @@ impl ConnectEvents {
         ConnectEvents { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        ConnectEvents {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Republishes Connect information for multiple envelopes.
      *
@@ -31,12 +37,19 @@ impl ConnectEvents {
     ) -> Result<crate::types::ConnectFailureResults> {
         let url = format!(
             "/v2.1/accounts/{}/connect/envelopes/retry_queue",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/connect/envelopes/retry_queue",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -58,11 +71,20 @@ impl ConnectEvents {
     ) -> Result<crate::types::ConnectFailureResults> {
         let url = format!(
             "/v2.1/accounts/{}/connect/envelopes/{}/retry_queue",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "PUT",
+                "/v2.1/accounts/{accountId}/connect/envelopes/{envelopeId}/retry_queue",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -88,21 +110,28 @@ impl ConnectEvents {
         from_date: &str,
         to_date: &str,
     ) -> Result<crate::types::ConnectLogs> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from_date.is_empty() {
-            query_args.push(("from_date".to_string(), from_date.to_string()));
+            query_args.push(("from_date", from_date.to_string()));
         }
         if !to_date.is_empty() {
-            query_args.push(("to_date".to_string(), to_date.to_string()));
+            query_args.push(("to_date", to_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/connect/failures?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/connect/failures",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/connect/failures",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -124,11 +153,53 @@ impl ConnectEvents {
     ) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/connect/failures/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&failure_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(failure_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/connect/failures/{failureId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("failureId", failure_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Deletes a Connect failure log entry.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/connect/failures/{failureId}` endpoint.
+     *
+     * As opposed to `connect_failures_delete_failure_log`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn connect_failures_delete_failure_log_if_exists(
+        &self,
+        account_id: &str,
+        failure_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/connect/failures/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(failure_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/connect/failures/{failureId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("failureId", failure_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -156,21 +227,28 @@ impl ConnectEvents {
         from_date: &str,
         to_date: &str,
     ) -> Result<crate::types::ConnectLogs> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from_date.is_empty() {
-            query_args.push(("from_date".to_string(), from_date.to_string()));
+            query_args.push(("from_date", from_date.to_string()));
         }
         if !to_date.is_empty() {
-            query_args.push(("to_date".to_string(), to_date.to_string()));
+            query_args.push(("to_date", to_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/connect/logs?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/connect/logs",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/connect/logs",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -187,10 +265,41 @@ impl ConnectEvents {
     pub async fn connect_log_delete_logs(&self, account_id: &str) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/connect/logs",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/connect/logs",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a list of Connect log entries.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/connect/logs` endpoint.
+     *
+     * As opposed to `connect_log_delete_logs`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn connect_log_delete_logs_if_exists(&self, account_id: &str) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/connect/logs",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/connect/logs",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -214,19 +323,29 @@ impl ConnectEvents {
         log_id: &str,
         additional_info: &str,
     ) -> Result<crate::types::ConnectLog> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !additional_info.is_empty() {
-            query_args.push(("additional_info".to_string(), additional_info.to_string()));
+            query_args.push(("additional_info", additional_info.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/connect/logs/{}?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&log_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/connect/logs/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(log_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/connect/logs/{logId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("logId", log_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -245,10 +364,172 @@ impl ConnectEvents {
     pub async fn connect_log_delete(&self, account_id: &str, log_id: &str) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/connect/logs/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&log_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(log_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/connect/logs/{logId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("logId", log_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Deletes a specified Connect log entry.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/connect/logs/{logId}` endpoint.
+     *
+     * As opposed to `connect_log_delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn connect_log_delete_if_exists(
+        &self,
+        account_id: &str,
+        log_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/connect/logs/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(log_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/connect/logs/{logId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("logId", log_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`ConnectEvents`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ConnectEventsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ConnectEventsApi: Send + Sync {
+    async fn connect_publish_put_retry(
+        &self,
+        account_id: &str,
+        body: &crate::types::ConnectFailureFilter,
+    ) -> Result<crate::types::ConnectFailureResults>;
+    async fn connect_publish_put_retry_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::ConnectFailureResults>;
+    async fn connect_failures_get_log(
+        &self,
+        account_id: &str,
+        from_date: &str,
+        to_date: &str,
+    ) -> Result<crate::types::ConnectLogs>;
+    async fn connect_failures_delete_failure_log(
+        &self,
+        account_id: &str,
+        failure_id: &str,
+    ) -> Result<()>;
+    async fn connect_failures_delete_failure_log_if_exists(
+        &self,
+        account_id: &str,
+        failure_id: &str,
+    ) -> Result<bool>;
+    async fn connect_log_get_log(
+        &self,
+        account_id: &str,
+        from_date: &str,
+        to_date: &str,
+    ) -> Result<crate::types::ConnectLogs>;
+    async fn connect_log_delete_logs(&self, account_id: &str) -> Result<()>;
+    async fn connect_log_delete_logs_if_exists(&self, account_id: &str) -> Result<bool>;
+    async fn connect_log_get(
+        &self,
+        account_id: &str,
+        log_id: &str,
+        additional_info: &str,
+    ) -> Result<crate::types::ConnectLog>;
+    async fn connect_log_delete(&self, account_id: &str, log_id: &str) -> Result<()>;
+    async fn connect_log_delete_if_exists(&self, account_id: &str, log_id: &str) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ConnectEventsApi for ConnectEvents {
+    async fn connect_publish_put_retry(
+        &self,
+        account_id: &str,
+        body: &crate::types::ConnectFailureFilter,
+    ) -> Result<crate::types::ConnectFailureResults> {
+        ConnectEvents::connect_publish_put_retry(self, account_id, body).await
+    }
+    async fn connect_publish_put_retry_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::ConnectFailureResults> {
+        ConnectEvents::connect_publish_put_retry_envelope(self, account_id, envelope_id).await
+    }
+    async fn connect_failures_get_log(
+        &self,
+        account_id: &str,
+        from_date: &str,
+        to_date: &str,
+    ) -> Result<crate::types::ConnectLogs> {
+        ConnectEvents::connect_failures_get_log(self, account_id, from_date, to_date).await
+    }
+    async fn connect_failures_delete_failure_log(
+        &self,
+        account_id: &str,
+        failure_id: &str,
+    ) -> Result<()> {
+        ConnectEvents::connect_failures_delete_failure_log(self, account_id, failure_id).await
+    }
+    async fn connect_failures_delete_failure_log_if_exists(
+        &self,
+        account_id: &str,
+        failure_id: &str,
+    ) -> Result<bool> {
+        ConnectEvents::connect_failures_delete_failure_log_if_exists(self, account_id, failure_id)
+            .await
+    }
+    async fn connect_log_get_log(
+        &self,
+        account_id: &str,
+        from_date: &str,
+        to_date: &str,
+    ) -> Result<crate::types::ConnectLogs> {
+        ConnectEvents::connect_log_get_log(self, account_id, from_date, to_date).await
+    }
+    async fn connect_log_delete_logs(&self, account_id: &str) -> Result<()> {
+        ConnectEvents::connect_log_delete_logs(self, account_id).await
+    }
+    async fn connect_log_delete_logs_if_exists(&self, account_id: &str) -> Result<bool> {
+        ConnectEvents::connect_log_delete_logs_if_exists(self, account_id).await
+    }
+    async fn connect_log_get(
+        &self,
+        account_id: &str,
+        log_id: &str,
+        additional_info: &str,
+    ) -> Result<crate::types::ConnectLog> {
+        ConnectEvents::connect_log_get(self, account_id, log_id, additional_info).await
+    }
+    async fn connect_log_delete(&self, account_id: &str, log_id: &str) -> Result<()> {
+        ConnectEvents::connect_log_delete(self, account_id, log_id).await
+    }
+    async fn connect_log_delete_if_exists(&self, account_id: &str, log_id: &str) -> Result<bool> {
+        ConnectEvents::connect_log_delete_if_exists(self, account_id, log_id).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct ConnectSecret {
     pub client: Client,
@@ -12,6 +10,14 @@ impl ConnectSecret {
         ConnectSecret { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        ConnectSecret {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Deletes the connect HMAC Secret for specified account.
      *
@@ -27,10 +33,81 @@ impl ConnectSecret {
     pub async fn connect_hmac_delete_secret(&self, account_id: &str, key_id: &str) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/connect/secret/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&key_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(key_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/connect/secret/{keyId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("keyId", key_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Deletes the connect HMAC Secret for specified account.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/connect/secret/{keyId}` endpoint.
+     *
+     * As opposed to `connect_hmac_delete_secret`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn connect_hmac_delete_secret_if_exists(
+        &self,
+        account_id: &str,
+        key_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/connect/secret/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(key_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/connect/secret/{keyId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("keyId", key_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`ConnectSecret`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ConnectSecretApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ConnectSecretApi: Send + Sync {
+    async fn connect_hmac_delete_secret(&self, account_id: &str, key_id: &str) -> Result<()>;
+    async fn connect_hmac_delete_secret_if_exists(
+        &self,
+        account_id: &str,
+        key_id: &str,
+    ) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ConnectSecretApi for ConnectSecret {
+    async fn connect_hmac_delete_secret(&self, account_id: &str, key_id: &str) -> Result<()> {
+        ConnectSecret::connect_hmac_delete_secret(self, account_id, key_id).await
+    }
+    async fn connect_hmac_delete_secret_if_exists(
+        &self,
+        account_id: &str,
+        key_id: &str,
+    ) -> Result<bool> {
+        ConnectSecret::connect_hmac_delete_secret_if_exists(self, account_id, key_id).await
     }
 }
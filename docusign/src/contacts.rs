@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Contacts {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Contacts {
         Contacts { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Contacts {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Updates one or more contacts.
      *
@@ -30,12 +36,19 @@ impl Contacts {
     ) -> Result<crate::types::ContactUpdateResponse> {
         let url = format!(
             "/v2.1/accounts/{}/contacts",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/contacts",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -58,12 +71,19 @@ impl Contacts {
     ) -> Result<crate::types::ContactUpdateResponse> {
         let url = format!(
             "/v2.1/accounts/{}/contacts",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/contacts",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -84,12 +104,19 @@ impl Contacts {
     ) -> Result<crate::types::ContactUpdateResponse> {
         let url = format!(
             "/v2.1/accounts/{}/contacts",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .delete(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/contacts",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -125,19 +152,29 @@ impl Contacts {
         contact_id: &str,
         cloud_provider: &str,
     ) -> Result<crate::types::ContactGetResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cloud_provider.is_empty() {
-            query_args.push(("cloud_provider".to_string(), cloud_provider.to_string()));
+            query_args.push(("cloud_provider", cloud_provider.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/contacts/{}?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&contact_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/contacts/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(contact_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/contacts/{contactId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("contactId", contact_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -159,10 +196,94 @@ impl Contacts {
     ) -> Result<crate::types::ContactUpdateResponse> {
         let url = format!(
             "/v2.1/accounts/{}/contacts/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&contact_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(contact_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/contacts/{contactId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("contactId", contact_id.to_string()),
+                ],
+            )
+        })
+    }
+}
+
+/// The functions of [`Contacts`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ContactsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ContactsApi: Send + Sync {
+    async fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::ContactModRequest,
+    ) -> Result<crate::types::ContactUpdateResponse>;
+    async fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::ContactModRequest,
+    ) -> Result<crate::types::ContactUpdateResponse>;
+    async fn delete(
+        &self,
+        account_id: &str,
+        body: &crate::types::ContactModRequest,
+    ) -> Result<crate::types::ContactUpdateResponse>;
+    async fn get(
+        &self,
+        account_id: &str,
+        contact_id: &str,
+        cloud_provider: &str,
+    ) -> Result<crate::types::ContactGetResponse>;
+    async fn delete_contacts(
+        &self,
+        account_id: &str,
+        contact_id: &str,
+    ) -> Result<crate::types::ContactUpdateResponse>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ContactsApi for Contacts {
+    async fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::ContactModRequest,
+    ) -> Result<crate::types::ContactUpdateResponse> {
+        Contacts::put(self, account_id, body).await
+    }
+    async fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::ContactModRequest,
+    ) -> Result<crate::types::ContactUpdateResponse> {
+        Contacts::post(self, account_id, body).await
+    }
+    async fn delete(
+        &self,
+        account_id: &str,
+        body: &crate::types::ContactModRequest,
+    ) -> Result<crate::types::ContactUpdateResponse> {
+        Contacts::delete(self, account_id, body).await
+    }
+    async fn get(
+        &self,
+        account_id: &str,
+        contact_id: &str,
+        cloud_provider: &str,
+    ) -> Result<crate::types::ContactGetResponse> {
+        Contacts::get(self, account_id, contact_id, cloud_provider).await
+    }
+    async fn delete_contacts(
+        &self,
+        account_id: &str,
+        contact_id: &str,
+    ) -> Result<crate::types::ContactUpdateResponse> {
+        Contacts::delete_contacts(self, account_id, contact_id).await
     }
 }
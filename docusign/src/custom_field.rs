@@ -0,0 +1,105 @@
+use crate::{
+    custom_field_id::CustomFieldId,
+    types::{AccountCustomFields, CustomField, ListCustomField, TextCustomField},
+    ClientError, ClientResult as Result,
+};
+
+impl From<ListCustomField> for CustomField {
+    fn from(f: ListCustomField) -> Self {
+        CustomField {
+            custom_field_type: "list".to_string(),
+            error_details: f.error_details,
+            field_id: f.field_id,
+            list_items: f.list_items,
+            name: f.name,
+            required: f.required,
+            show: f.show,
+            value: f.value,
+        }
+    }
+}
+
+impl From<TextCustomField> for CustomField {
+    fn from(f: TextCustomField) -> Self {
+        CustomField {
+            custom_field_type: "text".to_string(),
+            error_details: f.error_details,
+            field_id: f.field_id,
+            list_items: Vec::new(),
+            name: f.name,
+            required: f.required,
+            show: f.show,
+            value: f.value,
+        }
+    }
+}
+
+impl AccountCustomFields {
+    /// The field with the id, list or text, as a `CustomField`.
+    pub fn field(&self, id: &CustomFieldId) -> Option<CustomField> {
+        self.find(|field_id, _| field_id == id.as_str())
+    }
+
+    /// The field with the name, list or text, as a `CustomField`.
+    pub fn field_named(&self, name: &str) -> Option<CustomField> {
+        self.find(|_, field_name| field_name == name)
+    }
+
+    fn find<F>(&self, matches: F) -> Option<CustomField>
+    where
+        F: Fn(&str, &str) -> bool,
+    {
+        if let Some(f) = self
+            .list_custom_fields
+            .iter()
+            .find(|f| matches(&f.field_id, &f.name))
+        {
+            return Some(f.clone().into());
+        }
+        self.text_custom_fields
+            .iter()
+            .find(|f| matches(&f.field_id, &f.name))
+            .map(|f| f.clone().into())
+    }
+}
+
+impl crate::account_custom_fields::AccountCustomFields {
+    /// Create an account custom field, as `post` does, and return the field
+    /// that was created rather than all of the account's. The new field is
+    /// found by its name, since the API gives it its id.
+    pub async fn post_field(
+        &self,
+        account_id: &str,
+        apply_to_templates: bool,
+        body: &CustomField,
+    ) -> Result<CustomField> {
+        let fields = self.post(account_id, apply_to_templates, body).await?;
+        fields
+            .field_named(&body.name)
+            .ok_or_else(|| ClientError::Other {
+                error: format!("the created custom field `{}` was not returned", body.name),
+            })
+    }
+
+    /// Update an account custom field, as `put` does, and return the field that
+    /// was updated rather than all of the account's.
+    pub async fn put_field(
+        &self,
+        account_id: &str,
+        custom_field_id: &CustomFieldId,
+        apply_to_templates: bool,
+        body: &CustomField,
+    ) -> Result<CustomField> {
+        let fields = self
+            .put(account_id, custom_field_id, apply_to_templates, body)
+            .await?;
+        fields
+            .field(custom_field_id)
+            .ok_or_else(|| ClientError::Other {
+                error: format!(
+                    "the updated custom field `{}` was not returned",
+                    custom_field_id
+                ),
+            })
+    }
+}
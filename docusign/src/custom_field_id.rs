@@ -0,0 +1,41 @@
+use std::fmt;
+
+/// The id of an account custom field, as the `AccountCustomFields` methods
+/// take it, so it cannot be passed where they take the account id.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CustomFieldId(String);
+
+impl CustomFieldId {
+    pub fn new<S: AsRef<str>>(id: S) -> Self {
+        CustomFieldId(id.as_ref().to_string())
+    }
+
+    /// The id, as it is sent to the API.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for CustomFieldId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for CustomFieldId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&str> for CustomFieldId {
+    fn from(id: &str) -> Self {
+        CustomFieldId::new(id)
+    }
+}
+
+impl From<String> for CustomFieldId {
+    fn from(id: String) -> Self {
+        CustomFieldId(id)
+    }
+}
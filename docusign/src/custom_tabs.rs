@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct CustomTabs {
     pub client: Client,
@@ -12,6 +10,14 @@ impl CustomTabs {
         CustomTabs { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        CustomTabs {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets a list of all account tabs.
      *
@@ -29,18 +35,25 @@ impl CustomTabs {
         account_id: &str,
         custom_tab_only: &str,
     ) -> Result<crate::types::TabMetadataList> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_tab_only.is_empty() {
-            query_args.push(("custom_tab_only".to_string(), custom_tab_only.to_string()));
+            query_args.push(("custom_tab_only", custom_tab_only.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/tab_definitions?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/tab_definitions",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/tab_definitions",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -63,12 +76,19 @@ impl CustomTabs {
     ) -> Result<crate::types::TabMetadata> {
         let url = format!(
             "/v2.1/accounts/{}/tab_definitions",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/tab_definitions",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -90,11 +110,20 @@ impl CustomTabs {
     ) -> Result<crate::types::TabMetadata> {
         let url = format!(
             "/v2.1/accounts/{}/tab_definitions/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&custom_tab_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(custom_tab_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/tab_definitions/{customTabId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("customTabId", custom_tab_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -119,13 +148,23 @@ impl CustomTabs {
     ) -> Result<crate::types::TabMetadata> {
         let url = format!(
             "/v2.1/accounts/{}/tab_definitions/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&custom_tab_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(custom_tab_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/tab_definitions/{customTabId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("customTabId", custom_tab_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -143,10 +182,131 @@ impl CustomTabs {
     pub async fn tab_delete_custom(&self, account_id: &str, custom_tab_id: &str) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/tab_definitions/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&custom_tab_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(custom_tab_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/tab_definitions/{customTabId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("customTabId", custom_tab_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Deletes custom tab information.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/tab_definitions/{customTabId}` endpoint.
+     *
+     * As opposed to `tab_delete_custom`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn tab_delete_custom_if_exists(
+        &self,
+        account_id: &str,
+        custom_tab_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/tab_definitions/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(custom_tab_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/tab_definitions/{customTabId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("customTabId", custom_tab_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`CustomTabs`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn CustomTabsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait CustomTabsApi: Send + Sync {
+    async fn tabs_get_tab_definition(
+        &self,
+        account_id: &str,
+        custom_tab_only: &str,
+    ) -> Result<crate::types::TabMetadataList>;
+    async fn tabs_post_tab_definitions(
+        &self,
+        account_id: &str,
+        body: &crate::types::TabMetadata,
+    ) -> Result<crate::types::TabMetadata>;
+    async fn tab_get_custom(
+        &self,
+        account_id: &str,
+        custom_tab_id: &str,
+    ) -> Result<crate::types::TabMetadata>;
+    async fn tab_put_custom(
+        &self,
+        account_id: &str,
+        custom_tab_id: &str,
+        body: &crate::types::TabMetadata,
+    ) -> Result<crate::types::TabMetadata>;
+    async fn tab_delete_custom(&self, account_id: &str, custom_tab_id: &str) -> Result<()>;
+    async fn tab_delete_custom_if_exists(
+        &self,
+        account_id: &str,
+        custom_tab_id: &str,
+    ) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl CustomTabsApi for CustomTabs {
+    async fn tabs_get_tab_definition(
+        &self,
+        account_id: &str,
+        custom_tab_only: &str,
+    ) -> Result<crate::types::TabMetadataList> {
+        CustomTabs::tabs_get_tab_definition(self, account_id, custom_tab_only).await
+    }
+    async fn tabs_post_tab_definitions(
+        &self,
+        account_id: &str,
+        body: &crate::types::TabMetadata,
+    ) -> Result<crate::types::TabMetadata> {
+        CustomTabs::tabs_post_tab_definitions(self, account_id, body).await
+    }
+    async fn tab_get_custom(
+        &self,
+        account_id: &str,
+        custom_tab_id: &str,
+    ) -> Result<crate::types::TabMetadata> {
+        CustomTabs::tab_get_custom(self, account_id, custom_tab_id).await
+    }
+    async fn tab_put_custom(
+        &self,
+        account_id: &str,
+        custom_tab_id: &str,
+        body: &crate::types::TabMetadata,
+    ) -> Result<crate::types::TabMetadata> {
+        CustomTabs::tab_put_custom(self, account_id, custom_tab_id, body).await
+    }
+    async fn tab_delete_custom(&self, account_id: &str, custom_tab_id: &str) -> Result<()> {
+        CustomTabs::tab_delete_custom(self, account_id, custom_tab_id).await
+    }
+    async fn tab_delete_custom_if_exists(
+        &self,
+        account_id: &str,
+        custom_tab_id: &str,
+    ) -> Result<bool> {
+        CustomTabs::tab_delete_custom_if_exists(self, account_id, custom_tab_id).await
     }
 }
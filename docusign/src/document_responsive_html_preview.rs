@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct DocumentResponsiveHtmlPreview {
     pub client: Client,
@@ -12,6 +10,14 @@ impl DocumentResponsiveHtmlPreview {
         DocumentResponsiveHtmlPreview { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        DocumentResponsiveHtmlPreview {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Creates a preview of the responsive version of a document.
      *
@@ -40,13 +46,49 @@ impl DocumentResponsiveHtmlPreview {
     ) -> Result<crate::types::DocumentHtmlDefinitions> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/documents/{}/responsive_html_preview",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            crate::progenitor_support::encode_path(&document_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(document_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/responsive_html_preview", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("documentId", document_id.to_string())]))
+    }
+}
+
+/// The functions of [`DocumentResponsiveHtmlPreview`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn DocumentResponsiveHtmlPreviewApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait DocumentResponsiveHtmlPreviewApi: Send + Sync {
+    async fn responsive_html_post_document_preview(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::DocumentHtmlDefinition,
+    ) -> Result<crate::types::DocumentHtmlDefinitions>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl DocumentResponsiveHtmlPreviewApi for DocumentResponsiveHtmlPreview {
+    async fn responsive_html_post_document_preview(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::DocumentHtmlDefinition,
+    ) -> Result<crate::types::DocumentHtmlDefinitions> {
+        DocumentResponsiveHtmlPreview::responsive_html_post_document_preview(
+            self,
+            account_id,
+            document_id,
+            envelope_id,
+            body,
+        )
+        .await
     }
 }
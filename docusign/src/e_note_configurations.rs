@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct ENoteConfigurations {
     pub client: Client,
@@ -12,6 +10,14 @@ impl ENoteConfigurations {
         ENoteConfigurations { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        ENoteConfigurations {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Returns the configuration information for the eNote eOriginal integration.
      *
@@ -26,10 +32,16 @@ impl ENoteConfigurations {
     pub async fn get(&self, account_id: &str) -> Result<crate::types::ENoteConfiguration> {
         let url = format!(
             "/v2.1/accounts/{}/settings/enote_configuration",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/settings/enote_configuration",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -50,12 +62,19 @@ impl ENoteConfigurations {
     ) -> Result<crate::types::ENoteConfiguration> {
         let url = format!(
             "/v2.1/accounts/{}/settings/enote_configuration",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/settings/enote_configuration",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -72,9 +91,77 @@ impl ENoteConfigurations {
     pub async fn delete(&self, account_id: &str) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/settings/enote_configuration",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/settings/enote_configuration",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes configuration information for the eNote eOriginal integration.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/settings/enote_configuration` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, account_id: &str) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/settings/enote_configuration",
+            self.client.encode_path(account_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/settings/enote_configuration",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`ENoteConfigurations`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ENoteConfigurationsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ENoteConfigurationsApi: Send + Sync {
+    async fn get(&self, account_id: &str) -> Result<crate::types::ENoteConfiguration>;
+    async fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::ENoteConfiguration,
+    ) -> Result<crate::types::ENoteConfiguration>;
+    async fn delete(&self, account_id: &str) -> Result<()>;
+    async fn delete_if_exists(&self, account_id: &str) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ENoteConfigurationsApi for ENoteConfigurations {
+    async fn get(&self, account_id: &str) -> Result<crate::types::ENoteConfiguration> {
+        ENoteConfigurations::get(self, account_id).await
+    }
+    async fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::ENoteConfiguration,
+    ) -> Result<crate::types::ENoteConfiguration> {
+        ENoteConfigurations::put(self, account_id, body).await
+    }
+    async fn delete(&self, account_id: &str) -> Result<()> {
+        ENoteConfigurations::delete(self, account_id).await
+    }
+    async fn delete_if_exists(&self, account_id: &str) -> Result<bool> {
+        ENoteConfigurations::delete_if_exists(self, account_id).await
     }
 }
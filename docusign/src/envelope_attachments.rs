@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct EnvelopeAttachments {
     pub client: Client,
@@ -12,6 +10,14 @@ impl EnvelopeAttachments {
         EnvelopeAttachments { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        EnvelopeAttachments {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Returns a list of attachments associated with the specified envelope.
      *
@@ -31,11 +37,20 @@ impl EnvelopeAttachments {
     ) -> Result<crate::types::EnvelopeAttachmentsResult> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/attachments",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/attachments",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -58,13 +73,23 @@ impl EnvelopeAttachments {
     ) -> Result<crate::types::EnvelopeAttachmentsResult> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/attachments",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/attachments",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -87,13 +112,23 @@ impl EnvelopeAttachments {
     ) -> Result<crate::types::EnvelopeAttachmentsResult> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/attachments",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .delete(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/attachments",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -117,12 +152,22 @@ impl EnvelopeAttachments {
     ) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/attachments/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            crate::progenitor_support::encode_path(&attachment_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(attachment_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/attachments/{attachmentId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                    ("attachmentId", attachment_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -147,13 +192,120 @@ impl EnvelopeAttachments {
     ) -> Result<crate::types::EnvelopeAttachmentsResult> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/attachments/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            crate::progenitor_support::encode_path(&attachment_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(attachment_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/attachments/{attachmentId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                        ("attachmentId", attachment_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`EnvelopeAttachments`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn EnvelopeAttachmentsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait EnvelopeAttachmentsApi: Send + Sync {
+    async fn attachments_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EnvelopeAttachmentsResult>;
+    async fn attachments_put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeAttachmentsRequest,
+    ) -> Result<crate::types::EnvelopeAttachmentsResult>;
+    async fn attachments_delete(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeAttachmentsRequest,
+    ) -> Result<crate::types::EnvelopeAttachmentsResult>;
+    async fn attachments_get_attachment(
+        &self,
+        account_id: &str,
+        attachment_id: &str,
+        envelope_id: &str,
+    ) -> Result<()>;
+    async fn attachments_put_attachment(
+        &self,
+        account_id: &str,
+        attachment_id: &str,
+        envelope_id: &str,
+        body: &crate::types::Attachment,
+    ) -> Result<crate::types::EnvelopeAttachmentsResult>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl EnvelopeAttachmentsApi for EnvelopeAttachments {
+    async fn attachments_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EnvelopeAttachmentsResult> {
+        EnvelopeAttachments::attachments_get(self, account_id, envelope_id).await
+    }
+    async fn attachments_put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeAttachmentsRequest,
+    ) -> Result<crate::types::EnvelopeAttachmentsResult> {
+        EnvelopeAttachments::attachments_put(self, account_id, envelope_id, body).await
+    }
+    async fn attachments_delete(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeAttachmentsRequest,
+    ) -> Result<crate::types::EnvelopeAttachmentsResult> {
+        EnvelopeAttachments::attachments_delete(self, account_id, envelope_id, body).await
+    }
+    async fn attachments_get_attachment(
+        &self,
+        account_id: &str,
+        attachment_id: &str,
+        envelope_id: &str,
+    ) -> Result<()> {
+        EnvelopeAttachments::attachments_get_attachment(
+            self,
+            account_id,
+            attachment_id,
+            envelope_id,
+        )
+        .await
+    }
+    async fn attachments_put_attachment(
+        &self,
+        account_id: &str,
+        attachment_id: &str,
+        envelope_id: &str,
+        body: &crate::types::Attachment,
+    ) -> Result<crate::types::EnvelopeAttachmentsResult> {
+        EnvelopeAttachments::attachments_put_attachment(
+            self,
+            account_id,
+            attachment_id,
+            envelope_id,
+            body,
+        )
+        .await
     }
 }
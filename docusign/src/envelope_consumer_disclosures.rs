@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct EnvelopeConsumerDisclosures {
     pub client: Client,
@@ -12,6 +10,14 @@ impl EnvelopeConsumerDisclosures {
         EnvelopeConsumerDisclosures { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        EnvelopeConsumerDisclosures {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets the default Electronic Record and Signature Disclosure for an envelope.
      *
@@ -83,20 +89,21 @@ impl EnvelopeConsumerDisclosures {
         recipient_id: &str,
         lang_code: &str,
     ) -> Result<crate::types::ConsumerDisclosure> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !lang_code.is_empty() {
-            query_args.push(("langCode".to_string(), lang_code.to_string()));
+            query_args.push(("langCode", lang_code.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/{}/recipients/{}/consumer_disclosure?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            crate::progenitor_support::encode_path(&recipient_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients/{}/consumer_disclosure",
+                self.client.encode_path(account_id),
+                self.client.encode_path(envelope_id),
+                self.client.encode_path(recipient_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/consumer_disclosure", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("recipientId", recipient_id.to_string())]))
     }
 
     /**
@@ -217,12 +224,71 @@ impl EnvelopeConsumerDisclosures {
     ) -> Result<crate::types::ConsumerDisclosure> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/recipients/{}/consumer_disclosure/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            crate::progenitor_support::encode_path(&recipient_id.to_string()),
-            crate::progenitor_support::encode_path(&lang_code.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(recipient_id),
+            self.client.encode_path(lang_code),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/consumer_disclosure/{langCode}", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("recipientId", recipient_id.to_string()), ("langCode", lang_code.to_string())]))
+    }
+}
+
+/// The functions of [`EnvelopeConsumerDisclosures`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn EnvelopeConsumerDisclosuresApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait EnvelopeConsumerDisclosuresApi: Send + Sync {
+    async fn consumer_disclosure_get_envelope_recipient(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        lang_code: &str,
+    ) -> Result<crate::types::ConsumerDisclosure>;
+    async fn consumer_disclosure_get_envelope_recipient_lang_code(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        lang_code: &str,
+        recipient_id: &str,
+    ) -> Result<crate::types::ConsumerDisclosure>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl EnvelopeConsumerDisclosuresApi for EnvelopeConsumerDisclosures {
+    async fn consumer_disclosure_get_envelope_recipient(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        lang_code: &str,
+    ) -> Result<crate::types::ConsumerDisclosure> {
+        EnvelopeConsumerDisclosures::consumer_disclosure_get_envelope_recipient(
+            self,
+            account_id,
+            envelope_id,
+            recipient_id,
+            lang_code,
+        )
+        .await
+    }
+    async fn consumer_disclosure_get_envelope_recipient_lang_code(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        lang_code: &str,
+        recipient_id: &str,
+    ) -> Result<crate::types::ConsumerDisclosure> {
+        EnvelopeConsumerDisclosures::consumer_disclosure_get_envelope_recipient_lang_code(
+            self,
+            account_id,
+            envelope_id,
+            lang_code,
+            recipient_id,
+        )
+        .await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct EnvelopeCustomFields {
     pub client: Client,
@@ -12,6 +10,14 @@ impl EnvelopeCustomFields {
         EnvelopeCustomFields { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        EnvelopeCustomFields {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets the custom field information for the specified envelope.
      *
@@ -33,11 +39,20 @@ impl EnvelopeCustomFields {
     ) -> Result<crate::types::CustomFieldsEnvelope> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/custom_fields",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/custom_fields",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -63,13 +78,23 @@ impl EnvelopeCustomFields {
     ) -> Result<crate::types::EnvelopeCustomFields> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/custom_fields",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/custom_fields",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -95,13 +120,23 @@ impl EnvelopeCustomFields {
     ) -> Result<crate::types::EnvelopeCustomFields> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/custom_fields",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/custom_fields",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -124,12 +159,89 @@ impl EnvelopeCustomFields {
     ) -> Result<crate::types::EnvelopeCustomFields> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/custom_fields",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .delete(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/custom_fields",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`EnvelopeCustomFields`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn EnvelopeCustomFieldsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait EnvelopeCustomFieldsApi: Send + Sync {
+    async fn custom_fields_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::CustomFieldsEnvelope>;
+    async fn custom_fields_put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeCustomFields,
+    ) -> Result<crate::types::EnvelopeCustomFields>;
+    async fn custom_fields_post(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeCustomFields,
+    ) -> Result<crate::types::EnvelopeCustomFields>;
+    async fn custom_fields_delete(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeCustomFields,
+    ) -> Result<crate::types::EnvelopeCustomFields>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl EnvelopeCustomFieldsApi for EnvelopeCustomFields {
+    async fn custom_fields_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::CustomFieldsEnvelope> {
+        EnvelopeCustomFields::custom_fields_get(self, account_id, envelope_id).await
+    }
+    async fn custom_fields_put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeCustomFields,
+    ) -> Result<crate::types::EnvelopeCustomFields> {
+        EnvelopeCustomFields::custom_fields_put(self, account_id, envelope_id, body).await
+    }
+    async fn custom_fields_post(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeCustomFields,
+    ) -> Result<crate::types::EnvelopeCustomFields> {
+        EnvelopeCustomFields::custom_fields_post(self, account_id, envelope_id, body).await
+    }
+    async fn custom_fields_delete(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeCustomFields,
+    ) -> Result<crate::types::EnvelopeCustomFields> {
+        EnvelopeCustomFields::custom_fields_delete(self, account_id, envelope_id, body).await
     }
 }
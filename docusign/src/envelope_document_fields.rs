@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct EnvelopeDocumentFields {
     pub client: Client,
@@ -12,6 +10,14 @@ impl EnvelopeDocumentFields {
         EnvelopeDocumentFields { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        EnvelopeDocumentFields {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets the custom document fields from an  existing envelope document.
      *
@@ -33,12 +39,22 @@ impl EnvelopeDocumentFields {
     ) -> Result<crate::types::EnvelopeDocumentFields> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/documents/{}/fields",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            crate::progenitor_support::encode_path(&document_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(document_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/fields",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                    ("documentId", document_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -63,14 +79,14 @@ impl EnvelopeDocumentFields {
     ) -> Result<crate::types::EnvelopeDocumentFields> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/documents/{}/fields",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            crate::progenitor_support::encode_path(&document_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(document_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("PUT", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/fields", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("documentId", document_id.to_string())]))
     }
 
     /**
@@ -95,14 +111,14 @@ impl EnvelopeDocumentFields {
     ) -> Result<crate::types::EnvelopeDocumentFields> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/documents/{}/fields",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            crate::progenitor_support::encode_path(&document_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(document_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/fields", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("documentId", document_id.to_string())]))
     }
 
     /**
@@ -127,13 +143,110 @@ impl EnvelopeDocumentFields {
     ) -> Result<crate::types::EnvelopeDocumentFields> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/documents/{}/fields",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            crate::progenitor_support::encode_path(&document_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(document_id),
         );
 
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("DELETE", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/fields", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("documentId", document_id.to_string())]))
+    }
+}
+
+/// The functions of [`EnvelopeDocumentFields`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn EnvelopeDocumentFieldsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait EnvelopeDocumentFieldsApi: Send + Sync {
+    async fn document_fields_get(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EnvelopeDocumentFields>;
+    async fn document_fields_put(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeDocumentFields,
+    ) -> Result<crate::types::EnvelopeDocumentFields>;
+    async fn document_fields_post(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeDocumentFields,
+    ) -> Result<crate::types::EnvelopeDocumentFields>;
+    async fn document_fields_delete(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeDocumentFields,
+    ) -> Result<crate::types::EnvelopeDocumentFields>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl EnvelopeDocumentFieldsApi for EnvelopeDocumentFields {
+    async fn document_fields_get(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EnvelopeDocumentFields> {
+        EnvelopeDocumentFields::document_fields_get(self, account_id, document_id, envelope_id)
             .await
     }
+    async fn document_fields_put(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeDocumentFields,
+    ) -> Result<crate::types::EnvelopeDocumentFields> {
+        EnvelopeDocumentFields::document_fields_put(
+            self,
+            account_id,
+            document_id,
+            envelope_id,
+            body,
+        )
+        .await
+    }
+    async fn document_fields_post(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeDocumentFields,
+    ) -> Result<crate::types::EnvelopeDocumentFields> {
+        EnvelopeDocumentFields::document_fields_post(
+            self,
+            account_id,
+            document_id,
+            envelope_id,
+            body,
+        )
+        .await
+    }
+    async fn document_fields_delete(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeDocumentFields,
+    ) -> Result<crate::types::EnvelopeDocumentFields> {
+        EnvelopeDocumentFields::document_fields_delete(
+            self,
+            account_id,
+            document_id,
+            envelope_id,
+            body,
+        )
+        .await
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct EnvelopeDocumentTabs {
     pub client: Client,
@@ -12,6 +10,14 @@ impl EnvelopeDocumentTabs {
         EnvelopeDocumentTabs { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        EnvelopeDocumentTabs {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Returns tabs on the specified page.
      *
@@ -35,13 +41,13 @@ impl EnvelopeDocumentTabs {
     ) -> Result<crate::types::EnvelopeDocumentTabs> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/documents/{}/pages/{}/tabs",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            crate::progenitor_support::encode_path(&document_id.to_string()),
-            crate::progenitor_support::encode_path(&page_number.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(document_id),
+            self.client.encode_path(page_number),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/pages/{pageNumber}/tabs", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("documentId", document_id.to_string()), ("pageNumber", page_number.to_string())]))
     }
 
     /**
@@ -71,23 +77,34 @@ impl EnvelopeDocumentTabs {
         include_metadata: &str,
         page_numbers: &str,
     ) -> Result<crate::types::EnvelopeDocumentTabs> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_metadata.is_empty() {
-            query_args.push(("include_metadata".to_string(), include_metadata.to_string()));
+            query_args.push(("include_metadata", include_metadata.to_string()));
         }
         if !page_numbers.is_empty() {
-            query_args.push(("page_numbers".to_string(), page_numbers.to_string()));
+            query_args.push(("page_numbers", page_numbers.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/{}/documents/{}/tabs?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            crate::progenitor_support::encode_path(&document_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}/tabs",
+                self.client.encode_path(account_id),
+                self.client.encode_path(envelope_id),
+                self.client.encode_path(document_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/tabs",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                    ("documentId", document_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -112,14 +129,25 @@ impl EnvelopeDocumentTabs {
     ) -> Result<crate::types::Tabs> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/documents/{}/tabs",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            crate::progenitor_support::encode_path(&document_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(document_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/tabs",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                        ("documentId", document_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -144,14 +172,25 @@ impl EnvelopeDocumentTabs {
     ) -> Result<crate::types::Tabs> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/documents/{}/tabs",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            crate::progenitor_support::encode_path(&document_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(document_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/tabs",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                        ("documentId", document_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -176,13 +215,131 @@ impl EnvelopeDocumentTabs {
     ) -> Result<crate::types::Tabs> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/documents/{}/tabs",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            crate::progenitor_support::encode_path(&document_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(document_id),
         );
 
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/tabs",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                        ("documentId", document_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`EnvelopeDocumentTabs`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn EnvelopeDocumentTabsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait EnvelopeDocumentTabsApi: Send + Sync {
+    async fn tabs_get_page(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+    ) -> Result<crate::types::EnvelopeDocumentTabs>;
+    async fn tabs_get_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        include_metadata: &str,
+        page_numbers: &str,
+    ) -> Result<crate::types::EnvelopeDocumentTabs>;
+    async fn tabs_put_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::Tabs,
+    ) -> Result<crate::types::Tabs>;
+    async fn tabs_post_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::Tabs,
+    ) -> Result<crate::types::Tabs>;
+    async fn tabs_delete_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::Tabs,
+    ) -> Result<crate::types::Tabs>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl EnvelopeDocumentTabsApi for EnvelopeDocumentTabs {
+    async fn tabs_get_page(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+    ) -> Result<crate::types::EnvelopeDocumentTabs> {
+        EnvelopeDocumentTabs::tabs_get_page(self, account_id, document_id, envelope_id, page_number)
+            .await
+    }
+    async fn tabs_get_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        include_metadata: &str,
+        page_numbers: &str,
+    ) -> Result<crate::types::EnvelopeDocumentTabs> {
+        EnvelopeDocumentTabs::tabs_get_document(
+            self,
+            account_id,
+            document_id,
+            envelope_id,
+            include_metadata,
+            page_numbers,
+        )
+        .await
+    }
+    async fn tabs_put_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::Tabs,
+    ) -> Result<crate::types::Tabs> {
+        EnvelopeDocumentTabs::tabs_put_document(self, account_id, document_id, envelope_id, body)
+            .await
+    }
+    async fn tabs_post_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::Tabs,
+    ) -> Result<crate::types::Tabs> {
+        EnvelopeDocumentTabs::tabs_post_document(self, account_id, document_id, envelope_id, body)
+            .await
+    }
+    async fn tabs_delete_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::Tabs,
+    ) -> Result<crate::types::Tabs> {
+        EnvelopeDocumentTabs::tabs_delete_document(self, account_id, document_id, envelope_id, body)
             .await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct EnvelopeDocumentVisibility {
     pub client: Client,
@@ -12,6 +10,14 @@ impl EnvelopeDocumentVisibility {
         EnvelopeDocumentVisibility { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        EnvelopeDocumentVisibility {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Returns document visibility for a recipient.
      *
@@ -33,12 +39,12 @@ impl EnvelopeDocumentVisibility {
     ) -> Result<crate::types::DocumentVisibilityList> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/recipients/{}/document_visibility",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            crate::progenitor_support::encode_path(&recipient_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(recipient_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/document_visibility", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("recipientId", recipient_id.to_string())]))
     }
 
     /**
@@ -65,13 +71,69 @@ impl EnvelopeDocumentVisibility {
     ) -> Result<crate::types::DocumentVisibilityList> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/recipients/{}/document_visibility",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            crate::progenitor_support::encode_path(&recipient_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(recipient_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("PUT", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/document_visibility", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("recipientId", recipient_id.to_string())]))
+    }
+}
+
+/// The functions of [`EnvelopeDocumentVisibility`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn EnvelopeDocumentVisibilityApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait EnvelopeDocumentVisibilityApi: Send + Sync {
+    async fn recipients_get_recipient_document_visibility(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> Result<crate::types::DocumentVisibilityList>;
+    async fn recipients_put_recipient_document_visibility(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        body: &crate::types::DocumentVisibilityList,
+    ) -> Result<crate::types::DocumentVisibilityList>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl EnvelopeDocumentVisibilityApi for EnvelopeDocumentVisibility {
+    async fn recipients_get_recipient_document_visibility(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> Result<crate::types::DocumentVisibilityList> {
+        EnvelopeDocumentVisibility::recipients_get_recipient_document_visibility(
+            self,
+            account_id,
+            envelope_id,
+            recipient_id,
+        )
+        .await
+    }
+    async fn recipients_put_recipient_document_visibility(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        body: &crate::types::DocumentVisibilityList,
+    ) -> Result<crate::types::DocumentVisibilityList> {
+        EnvelopeDocumentVisibility::recipients_put_recipient_document_visibility(
+            self,
+            account_id,
+            envelope_id,
+            recipient_id,
+            body,
+        )
+        .await
     }
 }
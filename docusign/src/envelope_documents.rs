@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct EnvelopeDocuments {
     pub client: Client,
@@ -12,6 +10,14 @@ impl EnvelopeDocuments {
         EnvelopeDocuments { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        EnvelopeDocuments {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets a list of envelope documents.
      *
@@ -41,40 +47,44 @@ impl EnvelopeDocuments {
         recipient_id: &str,
         shared_user_id: &str,
     ) -> Result<crate::types::EnvelopeDocumentsResult> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !documents_by_userid.is_empty() {
-            query_args.push((
-                "documents_by_userid".to_string(),
-                documents_by_userid.to_string(),
-            ));
+            query_args.push(("documents_by_userid", documents_by_userid.to_string()));
         }
         if !include_document_size.is_empty() {
-            query_args.push((
-                "include_document_size".to_string(),
-                include_document_size.to_string(),
-            ));
+            query_args.push(("include_document_size", include_document_size.to_string()));
         }
         if !include_metadata.is_empty() {
-            query_args.push(("include_metadata".to_string(), include_metadata.to_string()));
+            query_args.push(("include_metadata", include_metadata.to_string()));
         }
         if !include_tabs.is_empty() {
-            query_args.push(("include_tabs".to_string(), include_tabs.to_string()));
+            query_args.push(("include_tabs", include_tabs.to_string()));
         }
         if !recipient_id.is_empty() {
-            query_args.push(("recipient_id".to_string(), recipient_id.to_string()));
+            query_args.push(("recipient_id", recipient_id.to_string()));
         }
         if !shared_user_id.is_empty() {
-            query_args.push(("shared_user_id".to_string(), shared_user_id.to_string()));
+            query_args.push(("shared_user_id", shared_user_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/{}/documents?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents",
+                self.client.encode_path(account_id),
+                self.client.encode_path(envelope_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -106,13 +116,23 @@ impl EnvelopeDocuments {
     ) -> Result<crate::types::EnvelopeDocumentsResult> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/documents",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -158,13 +178,23 @@ impl EnvelopeDocuments {
     ) -> Result<crate::types::EnvelopeDocumentsResult> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/documents",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .delete(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -208,47 +238,135 @@ impl EnvelopeDocuments {
         show_changes: &str,
         watermark: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !certificate.is_empty() {
-            query_args.push(("certificate".to_string(), certificate.to_string()));
+            query_args.push(("certificate", certificate.to_string()));
         }
         if !documents_by_userid.is_empty() {
-            query_args.push((
-                "documents_by_userid".to_string(),
-                documents_by_userid.to_string(),
-            ));
+            query_args.push(("documents_by_userid", documents_by_userid.to_string()));
         }
         if !encoding.is_empty() {
-            query_args.push(("encoding".to_string(), encoding.to_string()));
+            query_args.push(("encoding", encoding.to_string()));
         }
         if !encrypt.is_empty() {
-            query_args.push(("encrypt".to_string(), encrypt.to_string()));
+            query_args.push(("encrypt", encrypt.to_string()));
         }
         if !language.is_empty() {
-            query_args.push(("language".to_string(), language.to_string()));
+            query_args.push(("language", language.to_string()));
         }
         if !recipient_id.is_empty() {
-            query_args.push(("recipient_id".to_string(), recipient_id.to_string()));
+            query_args.push(("recipient_id", recipient_id.to_string()));
         }
         if !shared_user_id.is_empty() {
-            query_args.push(("shared_user_id".to_string(), shared_user_id.to_string()));
+            query_args.push(("shared_user_id", shared_user_id.to_string()));
         }
         if !show_changes.is_empty() {
-            query_args.push(("show_changes".to_string(), show_changes.to_string()));
+            query_args.push(("show_changes", show_changes.to_string()));
         }
         if !watermark.is_empty() {
-            query_args.push(("watermark".to_string(), watermark.to_string()));
+            query_args.push(("watermark", watermark.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/{}/documents/{}?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            crate::progenitor_support::encode_path(&document_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(envelope_id),
+                self.client.encode_path(document_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client
+            .request_with_accept_mime(reqwest::Method::GET, &url, "application/pdf")
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                        ("documentId", document_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * Gets a document from an envelope.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}` endpoint.
+     *
+     * As opposed to `documents_get_document`, this function asks for the PDF and streams its bytes as they arrive, rather than returning them all at once.
+     * This is useful for large documents that would otherwise need to be held in memory.
+     */
+    pub async fn documents_get_document_stream(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        certificate: &str,
+        documents_by_userid: &str,
+        encoding: &str,
+        encrypt: &str,
+        language: &str,
+        recipient_id: &str,
+        shared_user_id: &str,
+        show_changes: &str,
+        watermark: &str,
+    ) -> Result<impl futures::Stream<Item = Result<bytes::Bytes>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !certificate.is_empty() {
+            query_args.push(("certificate", certificate.to_string()));
+        }
+        if !documents_by_userid.is_empty() {
+            query_args.push(("documents_by_userid", documents_by_userid.to_string()));
+        }
+        if !encoding.is_empty() {
+            query_args.push(("encoding", encoding.to_string()));
+        }
+        if !encrypt.is_empty() {
+            query_args.push(("encrypt", encrypt.to_string()));
+        }
+        if !language.is_empty() {
+            query_args.push(("language", language.to_string()));
+        }
+        if !recipient_id.is_empty() {
+            query_args.push(("recipient_id", recipient_id.to_string()));
+        }
+        if !shared_user_id.is_empty() {
+            query_args.push(("shared_user_id", shared_user_id.to_string()));
+        }
+        if !show_changes.is_empty() {
+            query_args.push(("show_changes", show_changes.to_string()));
+        }
+        if !watermark.is_empty() {
+            query_args.push(("watermark", watermark.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(envelope_id),
+                self.client.encode_path(document_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .get_stream_bytes(&url, "application/pdf")
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                        ("documentId", document_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -288,11 +406,158 @@ impl EnvelopeDocuments {
     ) -> Result<crate::types::EnvelopeDocument> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/documents/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            crate::progenitor_support::encode_path(&document_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(document_id),
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "PUT",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                    ("documentId", document_id.to_string()),
+                ],
+            )
+        })
+    }
+}
+
+/// The functions of [`EnvelopeDocuments`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn EnvelopeDocumentsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait EnvelopeDocumentsApi: Send + Sync {
+    async fn documents_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        documents_by_userid: &str,
+        include_document_size: &str,
+        include_metadata: &str,
+        include_tabs: &str,
+        recipient_id: &str,
+        shared_user_id: &str,
+    ) -> Result<crate::types::EnvelopeDocumentsResult>;
+    async fn documents_put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> Result<crate::types::EnvelopeDocumentsResult>;
+    async fn documents_delete(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> Result<crate::types::EnvelopeDocumentsResult>;
+    async fn documents_get_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        certificate: &str,
+        documents_by_userid: &str,
+        encoding: &str,
+        encrypt: &str,
+        language: &str,
+        recipient_id: &str,
+        shared_user_id: &str,
+        show_changes: &str,
+        watermark: &str,
+    ) -> Result<()>;
+    async fn documents_put_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EnvelopeDocument>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl EnvelopeDocumentsApi for EnvelopeDocuments {
+    async fn documents_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        documents_by_userid: &str,
+        include_document_size: &str,
+        include_metadata: &str,
+        include_tabs: &str,
+        recipient_id: &str,
+        shared_user_id: &str,
+    ) -> Result<crate::types::EnvelopeDocumentsResult> {
+        EnvelopeDocuments::documents_get(
+            self,
+            account_id,
+            envelope_id,
+            documents_by_userid,
+            include_document_size,
+            include_metadata,
+            include_tabs,
+            recipient_id,
+            shared_user_id,
+        )
+        .await
+    }
+    async fn documents_put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> Result<crate::types::EnvelopeDocumentsResult> {
+        EnvelopeDocuments::documents_put(self, account_id, envelope_id, body).await
+    }
+    async fn documents_delete(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> Result<crate::types::EnvelopeDocumentsResult> {
+        EnvelopeDocuments::documents_delete(self, account_id, envelope_id, body).await
+    }
+    async fn documents_get_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        certificate: &str,
+        documents_by_userid: &str,
+        encoding: &str,
+        encrypt: &str,
+        language: &str,
+        recipient_id: &str,
+        shared_user_id: &str,
+        show_changes: &str,
+        watermark: &str,
+    ) -> Result<()> {
+        EnvelopeDocuments::documents_get_document(
+            self,
+            account_id,
+            document_id,
+            envelope_id,
+            certificate,
+            documents_by_userid,
+            encoding,
+            encrypt,
+            language,
+            recipient_id,
+            shared_user_id,
+            show_changes,
+            watermark,
+        )
+        .await
+    }
+    async fn documents_put_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EnvelopeDocument> {
+        EnvelopeDocuments::documents_put_document(self, account_id, document_id, envelope_id).await
     }
 }
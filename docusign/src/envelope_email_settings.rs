@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct EnvelopeEmailSettings {
     pub client: Client,
@@ -12,6 +10,14 @@ impl EnvelopeEmailSettings {
         EnvelopeEmailSettings { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        EnvelopeEmailSettings {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets the email setting overrides for an envelope.
      *
@@ -31,11 +37,20 @@ impl EnvelopeEmailSettings {
     ) -> Result<crate::types::EmailSettings> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/email_settings",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/email_settings",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -60,13 +75,23 @@ impl EnvelopeEmailSettings {
     ) -> Result<crate::types::EmailSettings> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/email_settings",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/email_settings",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -91,13 +116,23 @@ impl EnvelopeEmailSettings {
     ) -> Result<crate::types::EmailSettings> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/email_settings",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/email_settings",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -119,10 +154,84 @@ impl EnvelopeEmailSettings {
     ) -> Result<crate::types::EmailSettings> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/email_settings",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/email_settings",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
+    }
+}
+
+/// The functions of [`EnvelopeEmailSettings`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn EnvelopeEmailSettingsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait EnvelopeEmailSettingsApi: Send + Sync {
+    async fn email_settings_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EmailSettings>;
+    async fn email_settings_put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EmailSettings,
+    ) -> Result<crate::types::EmailSettings>;
+    async fn email_settings_post(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EmailSettings,
+    ) -> Result<crate::types::EmailSettings>;
+    async fn email_settings_delete(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EmailSettings>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl EnvelopeEmailSettingsApi for EnvelopeEmailSettings {
+    async fn email_settings_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EmailSettings> {
+        EnvelopeEmailSettings::email_settings_get(self, account_id, envelope_id).await
+    }
+    async fn email_settings_put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EmailSettings,
+    ) -> Result<crate::types::EmailSettings> {
+        EnvelopeEmailSettings::email_settings_put(self, account_id, envelope_id, body).await
+    }
+    async fn email_settings_post(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EmailSettings,
+    ) -> Result<crate::types::EmailSettings> {
+        EnvelopeEmailSettings::email_settings_post(self, account_id, envelope_id, body).await
+    }
+    async fn email_settings_delete(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EmailSettings> {
+        EnvelopeEmailSettings::email_settings_delete(self, account_id, envelope_id).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct EnvelopeFormData {
     pub client: Client,
@@ -12,6 +10,14 @@ impl EnvelopeFormData {
         EnvelopeFormData { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        EnvelopeFormData {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Returns envelope form data for an existing envelope.
      *
@@ -33,10 +39,44 @@ impl EnvelopeFormData {
     ) -> Result<crate::types::EnvelopeFormDataType> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/form_data",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/form_data",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
+    }
+}
+
+/// The functions of [`EnvelopeFormData`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn EnvelopeFormDataApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait EnvelopeFormDataApi: Send + Sync {
+    async fn form_data_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EnvelopeFormDataType>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl EnvelopeFormDataApi for EnvelopeFormData {
+    async fn form_data_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EnvelopeFormDataType> {
+        EnvelopeFormData::form_data_get(self, account_id, envelope_id).await
     }
 }
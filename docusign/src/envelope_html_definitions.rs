@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct EnvelopeHtmlDefinitions {
     pub client: Client,
@@ -12,6 +10,14 @@ impl EnvelopeHtmlDefinitions {
         EnvelopeHtmlDefinitions { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        EnvelopeHtmlDefinitions {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets the Original HTML Definition used to generate the Responsive HTML for the envelope.
      *
@@ -31,10 +37,49 @@ impl EnvelopeHtmlDefinitions {
     ) -> Result<crate::types::EnvelopeHtmlDefinitions> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/html_definitions",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/html_definitions",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
+    }
+}
+
+/// The functions of [`EnvelopeHtmlDefinitions`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn EnvelopeHtmlDefinitionsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait EnvelopeHtmlDefinitionsApi: Send + Sync {
+    async fn responsive_html_get_envelope_definition(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EnvelopeHtmlDefinitions>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl EnvelopeHtmlDefinitionsApi for EnvelopeHtmlDefinitions {
+    async fn responsive_html_get_envelope_definition(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EnvelopeHtmlDefinitions> {
+        EnvelopeHtmlDefinitions::responsive_html_get_envelope_definition(
+            self,
+            account_id,
+            envelope_id,
+        )
+        .await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct EnvelopeLocks {
     pub client: Client,
@@ -12,6 +10,14 @@ impl EnvelopeLocks {
         EnvelopeLocks { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        EnvelopeLocks {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets envelope lock information.
      *
@@ -43,11 +49,20 @@ impl EnvelopeLocks {
     ) -> Result<crate::types::EnvelopeLocks> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/lock",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/lock",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -92,13 +107,23 @@ impl EnvelopeLocks {
     ) -> Result<crate::types::EnvelopeLocks> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/lock",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/lock",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -177,13 +202,23 @@ impl EnvelopeLocks {
     ) -> Result<crate::types::EnvelopeLocks> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/lock",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/lock",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -221,10 +256,84 @@ impl EnvelopeLocks {
     ) -> Result<crate::types::EnvelopeLocks> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/lock",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/lock",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
+    }
+}
+
+/// The functions of [`EnvelopeLocks`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn EnvelopeLocksApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait EnvelopeLocksApi: Send + Sync {
+    async fn lock_get_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EnvelopeLocks>;
+    async fn lock_put_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::LockRequest,
+    ) -> Result<crate::types::EnvelopeLocks>;
+    async fn lock_post_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::LockRequest,
+    ) -> Result<crate::types::EnvelopeLocks>;
+    async fn lock_delete_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EnvelopeLocks>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl EnvelopeLocksApi for EnvelopeLocks {
+    async fn lock_get_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EnvelopeLocks> {
+        EnvelopeLocks::lock_get_envelope(self, account_id, envelope_id).await
+    }
+    async fn lock_put_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::LockRequest,
+    ) -> Result<crate::types::EnvelopeLocks> {
+        EnvelopeLocks::lock_put_envelope(self, account_id, envelope_id, body).await
+    }
+    async fn lock_post_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::LockRequest,
+    ) -> Result<crate::types::EnvelopeLocks> {
+        EnvelopeLocks::lock_post_envelope(self, account_id, envelope_id, body).await
+    }
+    async fn lock_delete_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EnvelopeLocks> {
+        EnvelopeLocks::lock_delete_envelope(self, account_id, envelope_id).await
     }
 }
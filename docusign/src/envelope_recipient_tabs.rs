@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct EnvelopeRecipientTabs {
     pub client: Client,
@@ -12,6 +10,14 @@ impl EnvelopeRecipientTabs {
         EnvelopeRecipientTabs { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        EnvelopeRecipientTabs {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets the tabs information for a signer or sign-in-person recipient in an envelope.
      *
@@ -41,26 +47,37 @@ impl EnvelopeRecipientTabs {
         include_anchor_tab_locations: &str,
         include_metadata: &str,
     ) -> Result<crate::types::EnvelopeRecipientTabs> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_anchor_tab_locations.is_empty() {
             query_args.push((
-                "include_anchor_tab_locations".to_string(),
+                "include_anchor_tab_locations",
                 include_anchor_tab_locations.to_string(),
             ));
         }
         if !include_metadata.is_empty() {
-            query_args.push(("include_metadata".to_string(), include_metadata.to_string()));
+            query_args.push(("include_metadata", include_metadata.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/{}/recipients/{}/tabs?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            crate::progenitor_support::encode_path(&recipient_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients/{}/tabs",
+                self.client.encode_path(account_id),
+                self.client.encode_path(envelope_id),
+                self.client.encode_path(recipient_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/tabs",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                    ("recipientId", recipient_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -88,14 +105,14 @@ impl EnvelopeRecipientTabs {
     ) -> Result<crate::types::EnvelopeRecipientTabs> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/recipients/{}/tabs",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            crate::progenitor_support::encode_path(&recipient_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(recipient_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("PUT", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/tabs", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("recipientId", recipient_id.to_string())]))
     }
 
     /**
@@ -120,14 +137,14 @@ impl EnvelopeRecipientTabs {
     ) -> Result<crate::types::EnvelopeRecipientTabs> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/recipients/{}/tabs",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            crate::progenitor_support::encode_path(&recipient_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(recipient_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/tabs", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("recipientId", recipient_id.to_string())]))
     }
 
     /**
@@ -152,13 +169,121 @@ impl EnvelopeRecipientTabs {
     ) -> Result<crate::types::EnvelopeRecipientTabs> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/recipients/{}/tabs",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            crate::progenitor_support::encode_path(&recipient_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(recipient_id),
         );
 
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("DELETE", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/tabs", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("recipientId", recipient_id.to_string())]))
+    }
+}
+
+/// The functions of [`EnvelopeRecipientTabs`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn EnvelopeRecipientTabsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait EnvelopeRecipientTabsApi: Send + Sync {
+    async fn recipients_get_recipient_tab(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        include_anchor_tab_locations: &str,
+        include_metadata: &str,
+    ) -> Result<crate::types::EnvelopeRecipientTabs>;
+    async fn recipients_put_recipient_tabs(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        body: &crate::types::EnvelopeRecipientTabs,
+    ) -> Result<crate::types::EnvelopeRecipientTabs>;
+    async fn recipients_post_recipient_tabs(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        body: &crate::types::EnvelopeRecipientTabs,
+    ) -> Result<crate::types::EnvelopeRecipientTabs>;
+    async fn recipients_delete_recipient_tabs(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        body: &crate::types::EnvelopeRecipientTabs,
+    ) -> Result<crate::types::EnvelopeRecipientTabs>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl EnvelopeRecipientTabsApi for EnvelopeRecipientTabs {
+    async fn recipients_get_recipient_tab(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        include_anchor_tab_locations: &str,
+        include_metadata: &str,
+    ) -> Result<crate::types::EnvelopeRecipientTabs> {
+        EnvelopeRecipientTabs::recipients_get_recipient_tab(
+            self,
+            account_id,
+            envelope_id,
+            recipient_id,
+            include_anchor_tab_locations,
+            include_metadata,
+        )
+        .await
+    }
+    async fn recipients_put_recipient_tabs(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        body: &crate::types::EnvelopeRecipientTabs,
+    ) -> Result<crate::types::EnvelopeRecipientTabs> {
+        EnvelopeRecipientTabs::recipients_put_recipient_tabs(
+            self,
+            account_id,
+            envelope_id,
+            recipient_id,
+            body,
+        )
+        .await
+    }
+    async fn recipients_post_recipient_tabs(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        body: &crate::types::EnvelopeRecipientTabs,
+    ) -> Result<crate::types::EnvelopeRecipientTabs> {
+        EnvelopeRecipientTabs::recipients_post_recipient_tabs(
+            self,
+            account_id,
+            envelope_id,
+            recipient_id,
+            body,
+        )
+        .await
+    }
+    async fn recipients_delete_recipient_tabs(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        body: &crate::types::EnvelopeRecipientTabs,
+    ) -> Result<crate::types::EnvelopeRecipientTabs> {
+        EnvelopeRecipientTabs::recipients_delete_recipient_tabs(
+            self,
+            account_id,
+            envelope_id,
+            recipient_id,
+            body,
+        )
+        .await
     }
 }
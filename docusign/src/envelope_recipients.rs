@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct EnvelopeRecipients {
     pub client: Client,
@@ -12,6 +10,14 @@ impl EnvelopeRecipients {
         EnvelopeRecipients { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        EnvelopeRecipients {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets the status of recipients for an envelope.
      *
@@ -39,31 +45,41 @@ impl EnvelopeRecipients {
         include_metadata: &str,
         include_tabs: &str,
     ) -> Result<crate::types::EnvelopeRecipients> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_anchor_tab_locations.is_empty() {
             query_args.push((
-                "include_anchor_tab_locations".to_string(),
+                "include_anchor_tab_locations",
                 include_anchor_tab_locations.to_string(),
             ));
         }
         if !include_extended.is_empty() {
-            query_args.push(("include_extended".to_string(), include_extended.to_string()));
+            query_args.push(("include_extended", include_extended.to_string()));
         }
         if !include_metadata.is_empty() {
-            query_args.push(("include_metadata".to_string(), include_metadata.to_string()));
+            query_args.push(("include_metadata", include_metadata.to_string()));
         }
         if !include_tabs.is_empty() {
-            query_args.push(("include_tabs".to_string(), include_tabs.to_string()));
+            query_args.push(("include_tabs", include_tabs.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/{}/recipients?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients",
+                self.client.encode_path(account_id),
+                self.client.encode_path(envelope_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -119,30 +135,41 @@ impl EnvelopeRecipients {
         resend_envelope: &str,
         body: &crate::types::EnvelopeRecipients,
     ) -> Result<crate::types::RecipientsUpdateSummary> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !combine_same_order_recipients.is_empty() {
             query_args.push((
-                "combine_same_order_recipients".to_string(),
+                "combine_same_order_recipients",
                 combine_same_order_recipients.to_string(),
             ));
         }
         if !offline_signing.is_empty() {
-            query_args.push(("offline_signing".to_string(), offline_signing.to_string()));
+            query_args.push(("offline_signing", offline_signing.to_string()));
         }
         if !resend_envelope.is_empty() {
-            query_args.push(("resend_envelope".to_string(), resend_envelope.to_string()));
+            query_args.push(("resend_envelope", resend_envelope.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/{}/recipients?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients",
+                self.client.encode_path(account_id),
+                self.client.encode_path(envelope_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -167,21 +194,32 @@ impl EnvelopeRecipients {
         resend_envelope: &str,
         body: &crate::types::EnvelopeRecipients,
     ) -> Result<crate::types::EnvelopeRecipients> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !resend_envelope.is_empty() {
-            query_args.push(("resend_envelope".to_string(), resend_envelope.to_string()));
+            query_args.push(("resend_envelope", resend_envelope.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/{}/recipients?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients",
+                self.client.encode_path(account_id),
+                self.client.encode_path(envelope_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -206,13 +244,23 @@ impl EnvelopeRecipients {
     ) -> Result<crate::types::EnvelopeRecipients> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/recipients",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .delete(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -237,13 +285,13 @@ impl EnvelopeRecipients {
     ) -> Result<crate::types::DocumentVisibilityList> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/recipients/document_visibility",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("PUT", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/document_visibility", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string())]))
     }
 
     /**
@@ -269,12 +317,22 @@ impl EnvelopeRecipients {
     ) -> Result<crate::types::EnvelopeRecipients> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/recipients/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            crate::progenitor_support::encode_path(&recipient_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(recipient_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                    ("recipientId", recipient_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -298,12 +356,12 @@ impl EnvelopeRecipients {
     ) -> Result<crate::types::ProofServiceResourceToken> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/recipients/{}/identity_proof_token",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            crate::progenitor_support::encode_path(&recipient_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(recipient_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| crate::utils::pretty_url("POST", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/identity_proof_token", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("recipientId", recipient_id.to_string())]))
     }
 
     /**
@@ -340,12 +398,12 @@ impl EnvelopeRecipients {
     ) -> Result<crate::types::ViewUrl> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/recipients/{}/views/identity_manual_review",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            crate::progenitor_support::encode_path(&recipient_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(recipient_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| crate::utils::pretty_url("POST", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/views/identity_manual_review", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("recipientId", recipient_id.to_string())]))
     }
 
     /**
@@ -370,12 +428,208 @@ impl EnvelopeRecipients {
     ) -> Result<crate::types::ViewUrl> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/views/recipient_preview",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/views/recipient_preview",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`EnvelopeRecipients`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn EnvelopeRecipientsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait EnvelopeRecipientsApi: Send + Sync {
+    async fn recipients_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        include_anchor_tab_locations: &str,
+        include_extended: &str,
+        include_metadata: &str,
+        include_tabs: &str,
+    ) -> Result<crate::types::EnvelopeRecipients>;
+    async fn recipients_put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        combine_same_order_recipients: &str,
+        offline_signing: &str,
+        resend_envelope: &str,
+        body: &crate::types::EnvelopeRecipients,
+    ) -> Result<crate::types::RecipientsUpdateSummary>;
+    async fn recipients_post(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        resend_envelope: &str,
+        body: &crate::types::EnvelopeRecipients,
+    ) -> Result<crate::types::EnvelopeRecipients>;
+    async fn recipients_delete(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeRecipients,
+    ) -> Result<crate::types::EnvelopeRecipients>;
+    async fn recipients_put_document_visibility(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::DocumentVisibilityList,
+    ) -> Result<crate::types::DocumentVisibilityList>;
+    async fn recipients_delete_recipient(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> Result<crate::types::EnvelopeRecipients>;
+    async fn recipients_post_recipient_proof_file_resource_token(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> Result<crate::types::ProofServiceResourceToken>;
+    async fn views_post_recipient_manual_review_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> Result<crate::types::ViewUrl>;
+    async fn views_post_preview(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::RecipientPreviewRequest,
+    ) -> Result<crate::types::ViewUrl>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl EnvelopeRecipientsApi for EnvelopeRecipients {
+    async fn recipients_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        include_anchor_tab_locations: &str,
+        include_extended: &str,
+        include_metadata: &str,
+        include_tabs: &str,
+    ) -> Result<crate::types::EnvelopeRecipients> {
+        EnvelopeRecipients::recipients_get(
+            self,
+            account_id,
+            envelope_id,
+            include_anchor_tab_locations,
+            include_extended,
+            include_metadata,
+            include_tabs,
+        )
+        .await
+    }
+    async fn recipients_put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        combine_same_order_recipients: &str,
+        offline_signing: &str,
+        resend_envelope: &str,
+        body: &crate::types::EnvelopeRecipients,
+    ) -> Result<crate::types::RecipientsUpdateSummary> {
+        EnvelopeRecipients::recipients_put(
+            self,
+            account_id,
+            envelope_id,
+            combine_same_order_recipients,
+            offline_signing,
+            resend_envelope,
+            body,
+        )
+        .await
+    }
+    async fn recipients_post(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        resend_envelope: &str,
+        body: &crate::types::EnvelopeRecipients,
+    ) -> Result<crate::types::EnvelopeRecipients> {
+        EnvelopeRecipients::recipients_post(self, account_id, envelope_id, resend_envelope, body)
+            .await
+    }
+    async fn recipients_delete(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeRecipients,
+    ) -> Result<crate::types::EnvelopeRecipients> {
+        EnvelopeRecipients::recipients_delete(self, account_id, envelope_id, body).await
+    }
+    async fn recipients_put_document_visibility(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::DocumentVisibilityList,
+    ) -> Result<crate::types::DocumentVisibilityList> {
+        EnvelopeRecipients::recipients_put_document_visibility(self, account_id, envelope_id, body)
+            .await
+    }
+    async fn recipients_delete_recipient(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> Result<crate::types::EnvelopeRecipients> {
+        EnvelopeRecipients::recipients_delete_recipient(self, account_id, envelope_id, recipient_id)
+            .await
+    }
+    async fn recipients_post_recipient_proof_file_resource_token(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> Result<crate::types::ProofServiceResourceToken> {
+        EnvelopeRecipients::recipients_post_recipient_proof_file_resource_token(
+            self,
+            account_id,
+            envelope_id,
+            recipient_id,
+        )
+        .await
+    }
+    async fn views_post_recipient_manual_review_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> Result<crate::types::ViewUrl> {
+        EnvelopeRecipients::views_post_recipient_manual_review_view(
+            self,
+            account_id,
+            envelope_id,
+            recipient_id,
+        )
+        .await
+    }
+    async fn views_post_preview(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::RecipientPreviewRequest,
+    ) -> Result<crate::types::ViewUrl> {
+        EnvelopeRecipients::views_post_preview(self, account_id, envelope_id, body).await
     }
 }
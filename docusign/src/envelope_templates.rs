@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct EnvelopeTemplates {
     pub client: Client,
@@ -12,6 +10,14 @@ impl EnvelopeTemplates {
         EnvelopeTemplates { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        EnvelopeTemplates {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets the templates associated with a document in an existing envelope.
      *
@@ -38,20 +44,21 @@ impl EnvelopeTemplates {
         envelope_id: &str,
         include: &str,
     ) -> Result<crate::types::TemplateInformation> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include.is_empty() {
-            query_args.push(("include".to_string(), include.to_string()));
+            query_args.push(("include", include.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/{}/documents/{}/templates?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            crate::progenitor_support::encode_path(&document_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}/templates",
+                self.client.encode_path(account_id),
+                self.client.encode_path(envelope_id),
+                self.client.encode_path(document_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/templates", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("documentId", document_id.to_string())]))
     }
 
     /**
@@ -76,25 +83,26 @@ impl EnvelopeTemplates {
         preserve_template_recipient: &str,
         body: &crate::types::DocumentTemplateList,
     ) -> Result<crate::types::DocumentTemplateList> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !preserve_template_recipient.is_empty() {
             query_args.push((
-                "preserve_template_recipient".to_string(),
+                "preserve_template_recipient",
                 preserve_template_recipient.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/{}/documents/{}/templates?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            crate::progenitor_support::encode_path(&document_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}/templates",
+                self.client.encode_path(account_id),
+                self.client.encode_path(envelope_id),
+                self.client.encode_path(document_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/templates", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("documentId", document_id.to_string())]))
     }
 
     /**
@@ -120,13 +128,38 @@ impl EnvelopeTemplates {
     ) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/documents/{}/templates/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            crate::progenitor_support::encode_path(&document_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(document_id),
+            self.client.encode_path(template_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/templates/{templateId}", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("documentId", document_id.to_string()), ("templateId", template_id.to_string())]))
+    }
+
+    /**
+     * Deletes a template from a document in an existing envelope.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/templates/{templateId}` endpoint.
+     *
+     * As opposed to `templates_delete_document`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn templates_delete_document_if_exists(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        template_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/documents/{}/templates/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(document_id),
+            self.client.encode_path(template_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete_if_exists(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/templates/{templateId}", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("documentId", document_id.to_string()), ("templateId", template_id.to_string())]))
     }
 
     /**
@@ -149,19 +182,29 @@ impl EnvelopeTemplates {
         envelope_id: &str,
         include: &str,
     ) -> Result<crate::types::TemplateInformation> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include.is_empty() {
-            query_args.push(("include".to_string(), include.to_string()));
+            query_args.push(("include", include.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/{}/templates?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/templates",
+                self.client.encode_path(account_id),
+                self.client.encode_path(envelope_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/templates",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -184,23 +227,179 @@ impl EnvelopeTemplates {
         preserve_template_recipient: &str,
         body: &crate::types::DocumentTemplateList,
     ) -> Result<crate::types::DocumentTemplateList> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !preserve_template_recipient.is_empty() {
             query_args.push((
-                "preserve_template_recipient".to_string(),
+                "preserve_template_recipient",
                 preserve_template_recipient.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/{}/templates?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/templates",
+                self.client.encode_path(account_id),
+                self.client.encode_path(envelope_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/templates",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`EnvelopeTemplates`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn EnvelopeTemplatesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait EnvelopeTemplatesApi: Send + Sync {
+    async fn templates_get_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        include: &str,
+    ) -> Result<crate::types::TemplateInformation>;
+    async fn templates_post_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        preserve_template_recipient: &str,
+        body: &crate::types::DocumentTemplateList,
+    ) -> Result<crate::types::DocumentTemplateList>;
+    async fn templates_delete_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        template_id: &str,
+    ) -> Result<()>;
+    async fn templates_delete_document_if_exists(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        template_id: &str,
+    ) -> Result<bool>;
+    async fn templates_get_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        include: &str,
+    ) -> Result<crate::types::TemplateInformation>;
+    async fn templates_post_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        preserve_template_recipient: &str,
+        body: &crate::types::DocumentTemplateList,
+    ) -> Result<crate::types::DocumentTemplateList>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl EnvelopeTemplatesApi for EnvelopeTemplates {
+    async fn templates_get_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        include: &str,
+    ) -> Result<crate::types::TemplateInformation> {
+        EnvelopeTemplates::templates_get_document(
+            self,
+            account_id,
+            document_id,
+            envelope_id,
+            include,
+        )
+        .await
+    }
+    async fn templates_post_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        preserve_template_recipient: &str,
+        body: &crate::types::DocumentTemplateList,
+    ) -> Result<crate::types::DocumentTemplateList> {
+        EnvelopeTemplates::templates_post_document(
+            self,
+            account_id,
+            document_id,
+            envelope_id,
+            preserve_template_recipient,
+            body,
+        )
+        .await
+    }
+    async fn templates_delete_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        template_id: &str,
+    ) -> Result<()> {
+        EnvelopeTemplates::templates_delete_document(
+            self,
+            account_id,
+            document_id,
+            envelope_id,
+            template_id,
+        )
+        .await
+    }
+    async fn templates_delete_document_if_exists(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        template_id: &str,
+    ) -> Result<bool> {
+        EnvelopeTemplates::templates_delete_document_if_exists(
+            self,
+            account_id,
+            document_id,
+            envelope_id,
+            template_id,
+        )
+        .await
+    }
+    async fn templates_get_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        include: &str,
+    ) -> Result<crate::types::TemplateInformation> {
+        EnvelopeTemplates::templates_get_envelope(self, account_id, envelope_id, include).await
+    }
+    async fn templates_post_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        preserve_template_recipient: &str,
+        body: &crate::types::DocumentTemplateList,
+    ) -> Result<crate::types::DocumentTemplateList> {
+        EnvelopeTemplates::templates_post_envelope(
+            self,
+            account_id,
+            envelope_id,
+            preserve_template_recipient,
+            body,
+        )
+        .await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct EnvelopeTransferRules {
     pub client: Client,
@@ -12,6 +10,14 @@ impl EnvelopeTransferRules {
         EnvelopeTransferRules { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        EnvelopeTransferRules {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets envelope transfer rules.
      *
@@ -33,21 +39,28 @@ impl EnvelopeTransferRules {
         count: &str,
         start_position: &str,
     ) -> Result<crate::types::EnvelopeTransferRuleInformation> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/transfer_rules?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/transfer_rules",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/transfer_rules",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -70,12 +83,19 @@ impl EnvelopeTransferRules {
     ) -> Result<crate::types::EnvelopeTransferRuleInformation> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/transfer_rules",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/envelopes/transfer_rules",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -107,12 +127,19 @@ impl EnvelopeTransferRules {
     ) -> Result<crate::types::EnvelopeTransferRuleInformation> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/transfer_rules",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/envelopes/transfer_rules",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -139,13 +166,26 @@ impl EnvelopeTransferRules {
     ) -> Result<crate::types::EnvelopeTransferRule> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/transfer_rules/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_transfer_rule_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_transfer_rule_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/envelopes/transfer_rules/{envelopeTransferRuleId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        (
+                            "envelopeTransferRuleId",
+                            envelope_transfer_rule_id.to_string(),
+                        ),
+                    ],
+                )
+            })
     }
 
     /**
@@ -165,10 +205,139 @@ impl EnvelopeTransferRules {
     pub async fn delete(&self, account_id: &str, envelope_transfer_rule_id: &str) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/transfer_rules/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_transfer_rule_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_transfer_rule_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/envelopes/transfer_rules/{envelopeTransferRuleId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    (
+                        "envelopeTransferRuleId",
+                        envelope_transfer_rule_id.to_string(),
+                    ),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Deletes an envelope transfer rule.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/envelopes/transfer_rules/{envelopeTransferRuleId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(
+        &self,
+        account_id: &str,
+        envelope_transfer_rule_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/transfer_rules/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_transfer_rule_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/envelopes/transfer_rules/{envelopeTransferRuleId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        (
+                            "envelopeTransferRuleId",
+                            envelope_transfer_rule_id.to_string(),
+                        ),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`EnvelopeTransferRules`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn EnvelopeTransferRulesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait EnvelopeTransferRulesApi: Send + Sync {
+    async fn get(
+        &self,
+        account_id: &str,
+        count: &str,
+        start_position: &str,
+    ) -> Result<crate::types::EnvelopeTransferRuleInformation>;
+    async fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::EnvelopeTransferRuleInformation,
+    ) -> Result<crate::types::EnvelopeTransferRuleInformation>;
+    async fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::EnvelopeTransferRuleRequest,
+    ) -> Result<crate::types::EnvelopeTransferRuleInformation>;
+    async fn put_rule(
+        &self,
+        account_id: &str,
+        envelope_transfer_rule_id: &str,
+        body: &crate::types::EnvelopeTransferRule,
+    ) -> Result<crate::types::EnvelopeTransferRule>;
+    async fn delete(&self, account_id: &str, envelope_transfer_rule_id: &str) -> Result<()>;
+    async fn delete_if_exists(
+        &self,
+        account_id: &str,
+        envelope_transfer_rule_id: &str,
+    ) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl EnvelopeTransferRulesApi for EnvelopeTransferRules {
+    async fn get(
+        &self,
+        account_id: &str,
+        count: &str,
+        start_position: &str,
+    ) -> Result<crate::types::EnvelopeTransferRuleInformation> {
+        EnvelopeTransferRules::get(self, account_id, count, start_position).await
+    }
+    async fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::EnvelopeTransferRuleInformation,
+    ) -> Result<crate::types::EnvelopeTransferRuleInformation> {
+        EnvelopeTransferRules::put(self, account_id, body).await
+    }
+    async fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::EnvelopeTransferRuleRequest,
+    ) -> Result<crate::types::EnvelopeTransferRuleInformation> {
+        EnvelopeTransferRules::post(self, account_id, body).await
+    }
+    async fn put_rule(
+        &self,
+        account_id: &str,
+        envelope_transfer_rule_id: &str,
+        body: &crate::types::EnvelopeTransferRule,
+    ) -> Result<crate::types::EnvelopeTransferRule> {
+        EnvelopeTransferRules::put_rule(self, account_id, envelope_transfer_rule_id, body).await
+    }
+    async fn delete(&self, account_id: &str, envelope_transfer_rule_id: &str) -> Result<()> {
+        EnvelopeTransferRules::delete(self, account_id, envelope_transfer_rule_id).await
+    }
+    async fn delete_if_exists(
+        &self,
+        account_id: &str,
+        envelope_transfer_rule_id: &str,
+    ) -> Result<bool> {
+        EnvelopeTransferRules::delete_if_exists(self, account_id, envelope_transfer_rule_id).await
     }
 }
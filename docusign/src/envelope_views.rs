@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct EnvelopeViews {
     pub client: Client,
@@ -12,6 +10,14 @@ impl EnvelopeViews {
         EnvelopeViews { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        EnvelopeViews {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Returns a URL to the envelope correction UI.
      *
@@ -47,13 +53,23 @@ impl EnvelopeViews {
     ) -> Result<crate::types::EnvelopeViews> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/views/correct",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/views/correct",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -76,13 +92,57 @@ impl EnvelopeViews {
     ) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/views/correct",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/views/correct",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * Revokes the correction view URL to the Envelope UI.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/views/correct` endpoint.
+     *
+     * As opposed to `views_delete_envelope_correct_view`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn views_delete_envelope_correct_view_if_exists(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::CorrectViewRequest,
+    ) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/views/correct",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .delete_if_exists(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/views/correct",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -122,13 +182,23 @@ impl EnvelopeViews {
     ) -> Result<crate::types::EnvelopeViews> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/views/edit",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/views/edit",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -177,13 +247,23 @@ impl EnvelopeViews {
     ) -> Result<crate::types::EnvelopeViews> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/views/recipient",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/views/recipient",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -234,13 +314,23 @@ impl EnvelopeViews {
     ) -> Result<crate::types::EnvelopeViews> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/views/sender",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/views/sender",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -268,13 +358,23 @@ impl EnvelopeViews {
     ) -> Result<crate::types::ViewUrl> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/views/shared",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/views/shared",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -300,11 +400,153 @@ impl EnvelopeViews {
     ) -> Result<crate::types::EnvelopeViews> {
         let url = format!(
             "/v2.1/accounts/{}/views/console",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/views/console",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`EnvelopeViews`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn EnvelopeViewsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait EnvelopeViewsApi: Send + Sync {
+    async fn views_post_envelope_correct_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::CorrectViewRequest,
+    ) -> Result<crate::types::EnvelopeViews>;
+    async fn views_delete_envelope_correct_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::CorrectViewRequest,
+    ) -> Result<()>;
+    async fn views_delete_envelope_correct_view_if_exists(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::CorrectViewRequest,
+    ) -> Result<bool>;
+    async fn views_post_envelope_edit_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::ReturnUrlRequest,
+    ) -> Result<crate::types::EnvelopeViews>;
+    async fn views_post_envelope_recipient_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::RecipientViewRequest,
+    ) -> Result<crate::types::EnvelopeViews>;
+    async fn views_post_envelope_sender_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::ReturnUrlRequest,
+    ) -> Result<crate::types::EnvelopeViews>;
+    async fn views_post_envelope_recipient_shared_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::RecipientViewRequest,
+    ) -> Result<crate::types::ViewUrl>;
+    async fn views_post_account_console_view(
+        &self,
+        account_id: &str,
+        body: &crate::types::ConsoleViewRequest,
+    ) -> Result<crate::types::EnvelopeViews>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl EnvelopeViewsApi for EnvelopeViews {
+    async fn views_post_envelope_correct_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::CorrectViewRequest,
+    ) -> Result<crate::types::EnvelopeViews> {
+        EnvelopeViews::views_post_envelope_correct_view(self, account_id, envelope_id, body).await
+    }
+    async fn views_delete_envelope_correct_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::CorrectViewRequest,
+    ) -> Result<()> {
+        EnvelopeViews::views_delete_envelope_correct_view(self, account_id, envelope_id, body).await
+    }
+    async fn views_delete_envelope_correct_view_if_exists(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::CorrectViewRequest,
+    ) -> Result<bool> {
+        EnvelopeViews::views_delete_envelope_correct_view_if_exists(
+            self,
+            account_id,
+            envelope_id,
+            body,
+        )
+        .await
+    }
+    async fn views_post_envelope_edit_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::ReturnUrlRequest,
+    ) -> Result<crate::types::EnvelopeViews> {
+        EnvelopeViews::views_post_envelope_edit_view(self, account_id, envelope_id, body).await
+    }
+    async fn views_post_envelope_recipient_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::RecipientViewRequest,
+    ) -> Result<crate::types::EnvelopeViews> {
+        EnvelopeViews::views_post_envelope_recipient_view(self, account_id, envelope_id, body).await
+    }
+    async fn views_post_envelope_sender_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::ReturnUrlRequest,
+    ) -> Result<crate::types::EnvelopeViews> {
+        EnvelopeViews::views_post_envelope_sender_view(self, account_id, envelope_id, body).await
+    }
+    async fn views_post_envelope_recipient_shared_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::RecipientViewRequest,
+    ) -> Result<crate::types::ViewUrl> {
+        EnvelopeViews::views_post_envelope_recipient_shared_view(
+            self,
+            account_id,
+            envelope_id,
+            body,
+        )
+        .await
+    }
+    async fn views_post_account_console_view(
+        &self,
+        account_id: &str,
+        body: &crate::types::ConsoleViewRequest,
+    ) -> Result<crate::types::EnvelopeViews> {
+        EnvelopeViews::views_post_account_console_view(self, account_id, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct EnvelopeWorkflowDefinition {
     pub client: Client,
@@ -12,6 +10,14 @@ impl EnvelopeWorkflowDefinition {
         EnvelopeWorkflowDefinition { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        EnvelopeWorkflowDefinition {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets an envelope's workflow definition.
      *
@@ -27,11 +33,20 @@ impl EnvelopeWorkflowDefinition {
     pub async fn get(&self, account_id: &str, envelope_id: &str) -> Result<crate::types::Workflow> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/workflow",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/workflow",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -54,13 +69,23 @@ impl EnvelopeWorkflowDefinition {
     ) -> Result<crate::types::Workflow> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/workflow",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/workflow",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -79,11 +104,49 @@ impl EnvelopeWorkflowDefinition {
     pub async fn delete(&self, account_id: &str, envelope_id: &str) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/workflow",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/workflow",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Deletes an envelope's workflow definition.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/workflow` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, account_id: &str, envelope_id: &str) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/workflow",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/workflow",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -105,11 +168,20 @@ impl EnvelopeWorkflowDefinition {
     ) -> Result<crate::types::Workflow> {
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/workflow",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/templates/{templateId}/workflow",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("templateId", template_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -132,13 +204,23 @@ impl EnvelopeWorkflowDefinition {
     ) -> Result<crate::types::Workflow> {
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/workflow",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/workflow",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -156,10 +238,125 @@ impl EnvelopeWorkflowDefinition {
     pub async fn delete_template(&self, account_id: &str, template_id: &str) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/workflow",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/templates/{templateId}/workflow",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("templateId", template_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Deletes a template's workflow definition.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/templates/{templateId}/workflow` endpoint.
+     *
+     * As opposed to `delete_template`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_template_if_exists(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/workflow",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/workflow",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`EnvelopeWorkflowDefinition`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn EnvelopeWorkflowDefinitionApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait EnvelopeWorkflowDefinitionApi: Send + Sync {
+    async fn get(&self, account_id: &str, envelope_id: &str) -> Result<crate::types::Workflow>;
+    async fn put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::Workflow,
+    ) -> Result<crate::types::Workflow>;
+    async fn delete(&self, account_id: &str, envelope_id: &str) -> Result<()>;
+    async fn delete_if_exists(&self, account_id: &str, envelope_id: &str) -> Result<bool>;
+    async fn get_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::Workflow>;
+    async fn put_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::Workflow,
+    ) -> Result<crate::types::Workflow>;
+    async fn delete_template(&self, account_id: &str, template_id: &str) -> Result<()>;
+    async fn delete_template_if_exists(&self, account_id: &str, template_id: &str) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl EnvelopeWorkflowDefinitionApi for EnvelopeWorkflowDefinition {
+    async fn get(&self, account_id: &str, envelope_id: &str) -> Result<crate::types::Workflow> {
+        EnvelopeWorkflowDefinition::get(self, account_id, envelope_id).await
+    }
+    async fn put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::Workflow,
+    ) -> Result<crate::types::Workflow> {
+        EnvelopeWorkflowDefinition::put(self, account_id, envelope_id, body).await
+    }
+    async fn delete(&self, account_id: &str, envelope_id: &str) -> Result<()> {
+        EnvelopeWorkflowDefinition::delete(self, account_id, envelope_id).await
+    }
+    async fn delete_if_exists(&self, account_id: &str, envelope_id: &str) -> Result<bool> {
+        EnvelopeWorkflowDefinition::delete_if_exists(self, account_id, envelope_id).await
+    }
+    async fn get_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::Workflow> {
+        EnvelopeWorkflowDefinition::get_template(self, account_id, template_id).await
+    }
+    async fn put_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::Workflow,
+    ) -> Result<crate::types::Workflow> {
+        EnvelopeWorkflowDefinition::put_template(self, account_id, template_id, body).await
+    }
+    async fn delete_template(&self, account_id: &str, template_id: &str) -> Result<()> {
+        EnvelopeWorkflowDefinition::delete_template(self, account_id, template_id).await
+    }
+    async fn delete_template_if_exists(&self, account_id: &str, template_id: &str) -> Result<bool> {
+        EnvelopeWorkflowDefinition::delete_template_if_exists(self, account_id, template_id).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Envelopes {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Envelopes {
         Envelopes { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Envelopes {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets status changes for one or more envelopes.
      *
@@ -303,120 +309,118 @@ impl Envelopes {
         user_id: &str,
         user_name: &str,
     ) -> Result<crate::types::EnvelopesInformation> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ac_status.is_empty() {
-            query_args.push(("ac_status".to_string(), ac_status.to_string()));
+            query_args.push(("ac_status", ac_status.to_string()));
         }
         if !block.is_empty() {
-            query_args.push(("block".to_string(), block.to_string()));
+            query_args.push(("block", block.to_string()));
         }
         if !cdse_mode.is_empty() {
-            query_args.push(("cdse_mode".to_string(), cdse_mode.to_string()));
+            query_args.push(("cdse_mode", cdse_mode.to_string()));
         }
         if !continuation_token.is_empty() {
-            query_args.push((
-                "continuation_token".to_string(),
-                continuation_token.to_string(),
-            ));
+            query_args.push(("continuation_token", continuation_token.to_string()));
         }
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !custom_field.is_empty() {
-            query_args.push(("custom_field".to_string(), custom_field.to_string()));
+            query_args.push(("custom_field", custom_field.to_string()));
         }
         if !email.is_empty() {
-            query_args.push(("email".to_string(), email.to_string()));
+            query_args.push(("email", email.to_string()));
         }
         if !envelope_ids.is_empty() {
-            query_args.push(("envelope_ids".to_string(), envelope_ids.to_string()));
+            query_args.push(("envelope_ids", envelope_ids.to_string()));
         }
         if !exclude.is_empty() {
-            query_args.push(("exclude".to_string(), exclude.to_string()));
+            query_args.push(("exclude", exclude.to_string()));
         }
         if !folder_ids.is_empty() {
-            query_args.push(("folder_ids".to_string(), folder_ids.to_string()));
+            query_args.push(("folder_ids", folder_ids.to_string()));
         }
         if !folder_types.is_empty() {
-            query_args.push(("folder_types".to_string(), folder_types.to_string()));
+            query_args.push(("folder_types", folder_types.to_string()));
         }
         if !from_date.is_empty() {
-            query_args.push(("from_date".to_string(), from_date.to_string()));
+            query_args.push(("from_date", from_date.to_string()));
         }
         if !from_to_status.is_empty() {
-            query_args.push(("from_to_status".to_string(), from_to_status.to_string()));
+            query_args.push(("from_to_status", from_to_status.to_string()));
         }
         if !include.is_empty() {
-            query_args.push(("include".to_string(), include.to_string()));
+            query_args.push(("include", include.to_string()));
         }
         if !include_purge_information.is_empty() {
             query_args.push((
-                "include_purge_information".to_string(),
+                "include_purge_information",
                 include_purge_information.to_string(),
             ));
         }
         if !intersecting_folder_ids.is_empty() {
             query_args.push((
-                "intersecting_folder_ids".to_string(),
+                "intersecting_folder_ids",
                 intersecting_folder_ids.to_string(),
             ));
         }
         if !last_queried_date.is_empty() {
-            query_args.push((
-                "last_queried_date".to_string(),
-                last_queried_date.to_string(),
-            ));
+            query_args.push(("last_queried_date", last_queried_date.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !order_by.is_empty() {
-            query_args.push(("order_by".to_string(), order_by.to_string()));
+            query_args.push(("order_by", order_by.to_string()));
         }
         if !powerformids.is_empty() {
-            query_args.push(("powerformids".to_string(), powerformids.to_string()));
+            query_args.push(("powerformids", powerformids.to_string()));
         }
         if !query_budget.is_empty() {
-            query_args.push(("query_budget".to_string(), query_budget.to_string()));
+            query_args.push(("query_budget", query_budget.to_string()));
         }
         if !requester_date_format.is_empty() {
-            query_args.push((
-                "requester_date_format".to_string(),
-                requester_date_format.to_string(),
-            ));
+            query_args.push(("requester_date_format", requester_date_format.to_string()));
         }
         if !search_text.is_empty() {
-            query_args.push(("search_text".to_string(), search_text.to_string()));
+            query_args.push(("search_text", search_text.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !to_date.is_empty() {
-            query_args.push(("to_date".to_string(), to_date.to_string()));
+            query_args.push(("to_date", to_date.to_string()));
         }
         if !transaction_ids.is_empty() {
-            query_args.push(("transaction_ids".to_string(), transaction_ids.to_string()));
+            query_args.push(("transaction_ids", transaction_ids.to_string()));
         }
         if !user_filter.is_empty() {
-            query_args.push(("user_filter".to_string(), user_filter.to_string()));
+            query_args.push(("user_filter", user_filter.to_string()));
         }
         if !user_id.is_empty() {
-            query_args.push(("user_id".to_string(), user_id.to_string()));
+            query_args.push(("user_id", user_id.to_string()));
         }
         if !user_name.is_empty() {
-            query_args.push(("user_name".to_string(), user_name.to_string()));
+            query_args.push(("user_name", user_name.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -514,38 +518,40 @@ impl Envelopes {
         merge_roles_on_draft: &str,
         body: &crate::types::EnvelopeDefinition,
     ) -> Result<crate::types::EnvelopeSummary> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cdse_mode.is_empty() {
-            query_args.push(("cdse_mode".to_string(), cdse_mode.to_string()));
+            query_args.push(("cdse_mode", cdse_mode.to_string()));
         }
         if !change_routing_order.is_empty() {
-            query_args.push((
-                "change_routing_order".to_string(),
-                change_routing_order.to_string(),
-            ));
+            query_args.push(("change_routing_order", change_routing_order.to_string()));
         }
         if !completed_documents_only.is_empty() {
             query_args.push((
-                "completed_documents_only".to_string(),
+                "completed_documents_only",
                 completed_documents_only.to_string(),
             ));
         }
         if !merge_roles_on_draft.is_empty() {
-            query_args.push((
-                "merge_roles_on_draft".to_string(),
-                merge_roles_on_draft.to_string(),
-            ));
+            query_args.push(("merge_roles_on_draft", merge_roles_on_draft.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/envelopes",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -678,53 +684,61 @@ impl Envelopes {
         user_name: &str,
         body: &crate::types::EnvelopeIdsRequest,
     ) -> Result<crate::types::EnvelopesInformation> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ac_status.is_empty() {
-            query_args.push(("ac_status".to_string(), ac_status.to_string()));
+            query_args.push(("ac_status", ac_status.to_string()));
         }
         if !block.is_empty() {
-            query_args.push(("block".to_string(), block.to_string()));
+            query_args.push(("block", block.to_string()));
         }
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !email.is_empty() {
-            query_args.push(("email".to_string(), email.to_string()));
+            query_args.push(("email", email.to_string()));
         }
         if !envelope_ids.is_empty() {
-            query_args.push(("envelope_ids".to_string(), envelope_ids.to_string()));
+            query_args.push(("envelope_ids", envelope_ids.to_string()));
         }
         if !from_date.is_empty() {
-            query_args.push(("from_date".to_string(), from_date.to_string()));
+            query_args.push(("from_date", from_date.to_string()));
         }
         if !from_to_status.is_empty() {
-            query_args.push(("from_to_status".to_string(), from_to_status.to_string()));
+            query_args.push(("from_to_status", from_to_status.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !to_date.is_empty() {
-            query_args.push(("to_date".to_string(), to_date.to_string()));
+            query_args.push(("to_date", to_date.to_string()));
         }
         if !transaction_ids.is_empty() {
-            query_args.push(("transaction_ids".to_string(), transaction_ids.to_string()));
+            query_args.push(("transaction_ids", transaction_ids.to_string()));
         }
         if !user_name.is_empty() {
-            query_args.push(("user_name".to_string(), user_name.to_string()));
+            query_args.push(("user_name", user_name.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/status?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/status",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/envelopes/status",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -761,22 +775,32 @@ impl Envelopes {
         advanced_update: &str,
         include: &str,
     ) -> Result<crate::types::Envelope> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !advanced_update.is_empty() {
-            query_args.push(("advanced_update".to_string(), advanced_update.to_string()));
+            query_args.push(("advanced_update", advanced_update.to_string()));
         }
         if !include.is_empty() {
-            query_args.push(("include".to_string(), include.to_string()));
+            query_args.push(("include", include.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/{}?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(envelope_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -965,24 +989,35 @@ impl Envelopes {
         resend_envelope: &str,
         body: &crate::types::Envelope,
     ) -> Result<crate::types::EnvelopeUpdateSummary> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !advanced_update.is_empty() {
-            query_args.push(("advanced_update".to_string(), advanced_update.to_string()));
+            query_args.push(("advanced_update", advanced_update.to_string()));
         }
         if !resend_envelope.is_empty() {
-            query_args.push(("resend_envelope".to_string(), resend_envelope.to_string()));
+            query_args.push(("resend_envelope", resend_envelope.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/{}?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(envelope_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1004,11 +1039,20 @@ impl Envelopes {
     ) -> Result<crate::types::EnvelopeAuditEventResponse> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/audit_events",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/audit_events",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1044,38 +1088,49 @@ impl Envelopes {
         show_changes: &str,
         start_position: &str,
     ) -> Result<crate::types::PageImages> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !dpi.is_empty() {
-            query_args.push(("dpi".to_string(), dpi.to_string()));
+            query_args.push(("dpi", dpi.to_string()));
         }
         if !max_height.is_empty() {
-            query_args.push(("max_height".to_string(), max_height.to_string()));
+            query_args.push(("max_height", max_height.to_string()));
         }
         if !max_width.is_empty() {
-            query_args.push(("max_width".to_string(), max_width.to_string()));
+            query_args.push(("max_width", max_width.to_string()));
         }
         if !nocache.is_empty() {
-            query_args.push(("nocache".to_string(), nocache.to_string()));
+            query_args.push(("nocache", nocache.to_string()));
         }
         if !show_changes.is_empty() {
-            query_args.push(("show_changes".to_string(), show_changes.to_string()));
+            query_args.push(("show_changes", show_changes.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/{}/documents/{}/pages?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            crate::progenitor_support::encode_path(&document_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}/pages",
+                self.client.encode_path(account_id),
+                self.client.encode_path(envelope_id),
+                self.client.encode_path(document_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/pages",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                    ("documentId", document_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1101,13 +1156,38 @@ impl Envelopes {
     ) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/documents/{}/pages/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            crate::progenitor_support::encode_path(&document_id.to_string()),
-            crate::progenitor_support::encode_path(&page_number.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(document_id),
+            self.client.encode_path(page_number),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/pages/{pageNumber}", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("documentId", document_id.to_string()), ("pageNumber", page_number.to_string())]))
+    }
+
+    /**
+     * Deletes a page from a document in an envelope.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/pages/{pageNumber}` endpoint.
+     *
+     * As opposed to `pages_delete_page`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn pages_delete_page_if_exists(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/documents/{}/pages/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(document_id),
+            self.client.encode_path(page_number),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete_if_exists(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/pages/{pageNumber}", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("documentId", document_id.to_string()), ("pageNumber", page_number.to_string())]))
     }
 
     /**
@@ -1139,30 +1219,33 @@ impl Envelopes {
         max_width: &str,
         show_changes: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !dpi.is_empty() {
-            query_args.push(("dpi".to_string(), dpi.to_string()));
+            query_args.push(("dpi", dpi.to_string()));
         }
         if !max_height.is_empty() {
-            query_args.push(("max_height".to_string(), max_height.to_string()));
+            query_args.push(("max_height", max_height.to_string()));
         }
         if !max_width.is_empty() {
-            query_args.push(("max_width".to_string(), max_width.to_string()));
+            query_args.push(("max_width", max_width.to_string()));
         }
         if !show_changes.is_empty() {
-            query_args.push(("show_changes".to_string(), show_changes.to_string()));
+            query_args.push(("show_changes", show_changes.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/{}/documents/{}/pages/{}/page_image?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            crate::progenitor_support::encode_path(&document_id.to_string()),
-            crate::progenitor_support::encode_path(&page_number.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/documents/{}/pages/{}/page_image",
+                self.client.encode_path(account_id),
+                self.client.encode_path(envelope_id),
+                self.client.encode_path(document_id),
+                self.client.encode_path(page_number)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client
+            .request_with_accept_mime(reqwest::Method::GET, &url, "image/png")
+            .await.with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/pages/{pageNumber}/page_image", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("documentId", document_id.to_string()), ("pageNumber", page_number.to_string())]))
     }
 
     /**
@@ -1189,15 +1272,15 @@ impl Envelopes {
     ) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/documents/{}/pages/{}/page_image",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            crate::progenitor_support::encode_path(&document_id.to_string()),
-            crate::progenitor_support::encode_path(&page_number.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(document_id),
+            self.client.encode_path(page_number),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("PUT", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/pages/{pageNumber}/page_image", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("documentId", document_id.to_string()), ("pageNumber", page_number.to_string())]))
     }
 
     /**
@@ -1219,11 +1302,20 @@ impl Envelopes {
     ) -> Result<crate::types::Notification> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/notification",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/notification",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1248,13 +1340,23 @@ impl Envelopes {
     ) -> Result<crate::types::Notification> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/notification",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/notification",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("envelopeId", envelope_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1286,20 +1388,23 @@ impl Envelopes {
         recipient_id: &str,
         include_chrome: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_chrome.is_empty() {
-            query_args.push(("include_chrome".to_string(), include_chrome.to_string()));
+            query_args.push(("include_chrome", include_chrome.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/{}/recipients/{}/initials_image?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            crate::progenitor_support::encode_path(&recipient_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients/{}/initials_image",
+                self.client.encode_path(account_id),
+                self.client.encode_path(envelope_id),
+                self.client.encode_path(recipient_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client
+            .request_with_accept_mime(reqwest::Method::GET, &url, "image/gif")
+            .await.with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/initials_image", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("recipientId", recipient_id.to_string())]))
     }
 
     /**
@@ -1325,12 +1430,12 @@ impl Envelopes {
     ) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/recipients/{}/initials_image",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            crate::progenitor_support::encode_path(&recipient_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(recipient_id),
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| crate::utils::pretty_url("PUT", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/initials_image", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("recipientId", recipient_id.to_string())]))
     }
 
     /**
@@ -1354,12 +1459,12 @@ impl Envelopes {
     ) -> Result<crate::types::UserSignature> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/recipients/{}/signature",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            crate::progenitor_support::encode_path(&recipient_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(recipient_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/signature", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("recipientId", recipient_id.to_string())]))
     }
 
     /**
@@ -1391,20 +1496,23 @@ impl Envelopes {
         recipient_id: &str,
         include_chrome: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_chrome.is_empty() {
-            query_args.push(("include_chrome".to_string(), include_chrome.to_string()));
+            query_args.push(("include_chrome", include_chrome.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/envelopes/{}/recipients/{}/signature_image?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            crate::progenitor_support::encode_path(&recipient_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/envelopes/{}/recipients/{}/signature_image",
+                self.client.encode_path(account_id),
+                self.client.encode_path(envelope_id),
+                self.client.encode_path(recipient_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client
+            .request_with_accept_mime(reqwest::Method::GET, &url, "image/gif")
+            .await.with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/signature_image", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("recipientId", recipient_id.to_string())]))
     }
 
     /**
@@ -1430,11 +1538,532 @@ impl Envelopes {
     ) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/recipients/{}/signature_image",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
-            crate::progenitor_support::encode_path(&recipient_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
+            self.client.encode_path(recipient_id),
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| crate::utils::pretty_url("PUT", "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/signature_image", &[("accountId", account_id.to_string()), ("envelopeId", envelope_id.to_string()), ("recipientId", recipient_id.to_string())]))
+    }
+}
+
+/// The functions of [`Envelopes`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn EnvelopesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait EnvelopesApi: Send + Sync {
+    async fn get(
+        &self,
+        account_id: &str,
+        ac_status: &str,
+        block: &str,
+        cdse_mode: &str,
+        continuation_token: &str,
+        count: &str,
+        custom_field: &str,
+        email: &str,
+        envelope_ids: &str,
+        exclude: &str,
+        folder_ids: &str,
+        folder_types: &str,
+        from_date: &str,
+        from_to_status: &str,
+        include: &str,
+        include_purge_information: &str,
+        intersecting_folder_ids: &str,
+        last_queried_date: &str,
+        order: &str,
+        order_by: &str,
+        powerformids: &str,
+        query_budget: &str,
+        requester_date_format: &str,
+        search_text: &str,
+        start_position: &str,
+        status: &str,
+        to_date: &str,
+        transaction_ids: &str,
+        user_filter: &str,
+        user_id: &str,
+        user_name: &str,
+    ) -> Result<crate::types::EnvelopesInformation>;
+    async fn post(
+        &self,
+        account_id: &str,
+        cdse_mode: &str,
+        change_routing_order: &str,
+        completed_documents_only: &str,
+        merge_roles_on_draft: &str,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> Result<crate::types::EnvelopeSummary>;
+    async fn put_status(
+        &self,
+        account_id: &str,
+        ac_status: &str,
+        block: &str,
+        count: &str,
+        email: &str,
+        envelope_ids: &str,
+        from_date: &str,
+        from_to_status: &str,
+        start_position: &str,
+        status: &str,
+        to_date: &str,
+        transaction_ids: &str,
+        user_name: &str,
+        body: &crate::types::EnvelopeIdsRequest,
+    ) -> Result<crate::types::EnvelopesInformation>;
+    async fn get_envelopes(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        advanced_update: &str,
+        include: &str,
+    ) -> Result<crate::types::Envelope>;
+    async fn put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        advanced_update: &str,
+        resend_envelope: &str,
+        body: &crate::types::Envelope,
+    ) -> Result<crate::types::EnvelopeUpdateSummary>;
+    async fn audit_events_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EnvelopeAuditEventResponse>;
+    async fn pages_get_page_image(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        count: &str,
+        dpi: &str,
+        max_height: &str,
+        max_width: &str,
+        nocache: &str,
+        show_changes: &str,
+        start_position: &str,
+    ) -> Result<crate::types::PageImages>;
+    async fn pages_delete_page(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+    ) -> Result<()>;
+    async fn pages_delete_page_if_exists(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+    ) -> Result<bool>;
+    async fn pages_get_page_image_envelopes(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+        dpi: &str,
+        max_height: &str,
+        max_width: &str,
+        show_changes: &str,
+    ) -> Result<()>;
+    async fn pages_put_page_image(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+        body: &crate::types::PageRequest,
+    ) -> Result<()>;
+    async fn notification_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::Notification>;
+    async fn notification_put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::Notification,
+    ) -> Result<crate::types::Notification>;
+    async fn recipients_get_recipient_initials_image(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        include_chrome: &str,
+    ) -> Result<()>;
+    async fn recipients_put_recipient_initials_image(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> Result<()>;
+    async fn recipients_get_recipient_signature(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> Result<crate::types::UserSignature>;
+    async fn recipients_get_recipient_signature_image(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        include_chrome: &str,
+    ) -> Result<()>;
+    async fn recipients_put_recipient_signature_image(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl EnvelopesApi for Envelopes {
+    async fn get(
+        &self,
+        account_id: &str,
+        ac_status: &str,
+        block: &str,
+        cdse_mode: &str,
+        continuation_token: &str,
+        count: &str,
+        custom_field: &str,
+        email: &str,
+        envelope_ids: &str,
+        exclude: &str,
+        folder_ids: &str,
+        folder_types: &str,
+        from_date: &str,
+        from_to_status: &str,
+        include: &str,
+        include_purge_information: &str,
+        intersecting_folder_ids: &str,
+        last_queried_date: &str,
+        order: &str,
+        order_by: &str,
+        powerformids: &str,
+        query_budget: &str,
+        requester_date_format: &str,
+        search_text: &str,
+        start_position: &str,
+        status: &str,
+        to_date: &str,
+        transaction_ids: &str,
+        user_filter: &str,
+        user_id: &str,
+        user_name: &str,
+    ) -> Result<crate::types::EnvelopesInformation> {
+        Envelopes::get(
+            self,
+            account_id,
+            ac_status,
+            block,
+            cdse_mode,
+            continuation_token,
+            count,
+            custom_field,
+            email,
+            envelope_ids,
+            exclude,
+            folder_ids,
+            folder_types,
+            from_date,
+            from_to_status,
+            include,
+            include_purge_information,
+            intersecting_folder_ids,
+            last_queried_date,
+            order,
+            order_by,
+            powerformids,
+            query_budget,
+            requester_date_format,
+            search_text,
+            start_position,
+            status,
+            to_date,
+            transaction_ids,
+            user_filter,
+            user_id,
+            user_name,
+        )
+        .await
+    }
+    async fn post(
+        &self,
+        account_id: &str,
+        cdse_mode: &str,
+        change_routing_order: &str,
+        completed_documents_only: &str,
+        merge_roles_on_draft: &str,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> Result<crate::types::EnvelopeSummary> {
+        Envelopes::post(
+            self,
+            account_id,
+            cdse_mode,
+            change_routing_order,
+            completed_documents_only,
+            merge_roles_on_draft,
+            body,
+        )
+        .await
+    }
+    async fn put_status(
+        &self,
+        account_id: &str,
+        ac_status: &str,
+        block: &str,
+        count: &str,
+        email: &str,
+        envelope_ids: &str,
+        from_date: &str,
+        from_to_status: &str,
+        start_position: &str,
+        status: &str,
+        to_date: &str,
+        transaction_ids: &str,
+        user_name: &str,
+        body: &crate::types::EnvelopeIdsRequest,
+    ) -> Result<crate::types::EnvelopesInformation> {
+        Envelopes::put_status(
+            self,
+            account_id,
+            ac_status,
+            block,
+            count,
+            email,
+            envelope_ids,
+            from_date,
+            from_to_status,
+            start_position,
+            status,
+            to_date,
+            transaction_ids,
+            user_name,
+            body,
+        )
+        .await
+    }
+    async fn get_envelopes(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        advanced_update: &str,
+        include: &str,
+    ) -> Result<crate::types::Envelope> {
+        Envelopes::get_envelopes(self, account_id, envelope_id, advanced_update, include).await
+    }
+    async fn put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        advanced_update: &str,
+        resend_envelope: &str,
+        body: &crate::types::Envelope,
+    ) -> Result<crate::types::EnvelopeUpdateSummary> {
+        Envelopes::put(
+            self,
+            account_id,
+            envelope_id,
+            advanced_update,
+            resend_envelope,
+            body,
+        )
+        .await
+    }
+    async fn audit_events_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EnvelopeAuditEventResponse> {
+        Envelopes::audit_events_get(self, account_id, envelope_id).await
+    }
+    async fn pages_get_page_image(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        count: &str,
+        dpi: &str,
+        max_height: &str,
+        max_width: &str,
+        nocache: &str,
+        show_changes: &str,
+        start_position: &str,
+    ) -> Result<crate::types::PageImages> {
+        Envelopes::pages_get_page_image(
+            self,
+            account_id,
+            document_id,
+            envelope_id,
+            count,
+            dpi,
+            max_height,
+            max_width,
+            nocache,
+            show_changes,
+            start_position,
+        )
+        .await
+    }
+    async fn pages_delete_page(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+    ) -> Result<()> {
+        Envelopes::pages_delete_page(self, account_id, document_id, envelope_id, page_number).await
+    }
+    async fn pages_delete_page_if_exists(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+    ) -> Result<bool> {
+        Envelopes::pages_delete_page_if_exists(
+            self,
+            account_id,
+            document_id,
+            envelope_id,
+            page_number,
+        )
+        .await
+    }
+    async fn pages_get_page_image_envelopes(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+        dpi: &str,
+        max_height: &str,
+        max_width: &str,
+        show_changes: &str,
+    ) -> Result<()> {
+        Envelopes::pages_get_page_image_envelopes(
+            self,
+            account_id,
+            document_id,
+            envelope_id,
+            page_number,
+            dpi,
+            max_height,
+            max_width,
+            show_changes,
+        )
+        .await
+    }
+    async fn pages_put_page_image(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+        body: &crate::types::PageRequest,
+    ) -> Result<()> {
+        Envelopes::pages_put_page_image(
+            self,
+            account_id,
+            document_id,
+            envelope_id,
+            page_number,
+            body,
+        )
+        .await
+    }
+    async fn notification_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::Notification> {
+        Envelopes::notification_get(self, account_id, envelope_id).await
+    }
+    async fn notification_put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::Notification,
+    ) -> Result<crate::types::Notification> {
+        Envelopes::notification_put(self, account_id, envelope_id, body).await
+    }
+    async fn recipients_get_recipient_initials_image(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        include_chrome: &str,
+    ) -> Result<()> {
+        Envelopes::recipients_get_recipient_initials_image(
+            self,
+            account_id,
+            envelope_id,
+            recipient_id,
+            include_chrome,
+        )
+        .await
+    }
+    async fn recipients_put_recipient_initials_image(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> Result<()> {
+        Envelopes::recipients_put_recipient_initials_image(
+            self,
+            account_id,
+            envelope_id,
+            recipient_id,
+        )
+        .await
+    }
+    async fn recipients_get_recipient_signature(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> Result<crate::types::UserSignature> {
+        Envelopes::recipients_get_recipient_signature(self, account_id, envelope_id, recipient_id)
+            .await
+    }
+    async fn recipients_get_recipient_signature_image(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        include_chrome: &str,
+    ) -> Result<()> {
+        Envelopes::recipients_get_recipient_signature_image(
+            self,
+            account_id,
+            envelope_id,
+            recipient_id,
+            include_chrome,
+        )
+        .await
+    }
+    async fn recipients_put_recipient_signature_image(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> Result<()> {
+        Envelopes::recipients_put_recipient_signature_image(
+            self,
+            account_id,
+            envelope_id,
+            recipient_id,
+        )
+        .await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct FavoriteTemplates {
     pub client: Client,
@@ -12,6 +10,14 @@ impl FavoriteTemplates {
         FavoriteTemplates { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        FavoriteTemplates {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieves the list of favorited templates for this caller.
      *
@@ -26,10 +32,16 @@ impl FavoriteTemplates {
     pub async fn get(&self, account_id: &str) -> Result<crate::types::FavoriteTemplates> {
         let url = format!(
             "/v2.1/accounts/{}/favorite_templates",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/favorite_templates",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -51,12 +63,19 @@ impl FavoriteTemplates {
     ) -> Result<crate::types::FavoriteTemplates> {
         let url = format!(
             "/v2.1/accounts/{}/favorite_templates",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/favorite_templates",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -77,11 +96,59 @@ impl FavoriteTemplates {
     ) -> Result<crate::types::FavoriteTemplates> {
         let url = format!(
             "/v2.1/accounts/{}/favorite_templates",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .delete(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/favorite_templates",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`FavoriteTemplates`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn FavoriteTemplatesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait FavoriteTemplatesApi: Send + Sync {
+    async fn get(&self, account_id: &str) -> Result<crate::types::FavoriteTemplates>;
+    async fn put_template(
+        &self,
+        account_id: &str,
+        body: &crate::types::FavoriteTemplates,
+    ) -> Result<crate::types::FavoriteTemplates>;
+    async fn un_template(
+        &self,
+        account_id: &str,
+        body: &crate::types::FavoriteTemplates,
+    ) -> Result<crate::types::FavoriteTemplates>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl FavoriteTemplatesApi for FavoriteTemplates {
+    async fn get(&self, account_id: &str) -> Result<crate::types::FavoriteTemplates> {
+        FavoriteTemplates::get(self, account_id).await
+    }
+    async fn put_template(
+        &self,
+        account_id: &str,
+        body: &crate::types::FavoriteTemplates,
+    ) -> Result<crate::types::FavoriteTemplates> {
+        FavoriteTemplates::put_template(self, account_id, body).await
+    }
+    async fn un_template(
+        &self,
+        account_id: &str,
+        body: &crate::types::FavoriteTemplates,
+    ) -> Result<crate::types::FavoriteTemplates> {
+        FavoriteTemplates::un_template(self, account_id, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Folders {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Folders {
         Folders { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Folders {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets a list of the folders for the account.
      *
@@ -48,30 +54,37 @@ impl Folders {
         template: &str,
         user_filter: &str,
     ) -> Result<crate::types::FoldersResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include.is_empty() {
-            query_args.push(("include".to_string(), include.to_string()));
+            query_args.push(("include", include.to_string()));
         }
         if !include_items.is_empty() {
-            query_args.push(("include_items".to_string(), include_items.to_string()));
+            query_args.push(("include_items", include_items.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
         if !template.is_empty() {
-            query_args.push(("template".to_string(), template.to_string()));
+            query_args.push(("template", template.to_string()));
         }
         if !user_filter.is_empty() {
-            query_args.push(("user_filter".to_string(), user_filter.to_string()));
+            query_args.push(("user_filter", user_filter.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/folders?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/folders",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/folders",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -107,40 +120,50 @@ impl Folders {
         status: &str,
         to_date: &str,
     ) -> Result<crate::types::FoldersResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from_date.is_empty() {
-            query_args.push(("from_date".to_string(), from_date.to_string()));
+            query_args.push(("from_date", from_date.to_string()));
         }
         if !include_items.is_empty() {
-            query_args.push(("include_items".to_string(), include_items.to_string()));
+            query_args.push(("include_items", include_items.to_string()));
         }
         if !owner_email.is_empty() {
-            query_args.push(("owner_email".to_string(), owner_email.to_string()));
+            query_args.push(("owner_email", owner_email.to_string()));
         }
         if !owner_name.is_empty() {
-            query_args.push(("owner_name".to_string(), owner_name.to_string()));
+            query_args.push(("owner_name", owner_name.to_string()));
         }
         if !search_text.is_empty() {
-            query_args.push(("search_text".to_string(), search_text.to_string()));
+            query_args.push(("search_text", search_text.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !to_date.is_empty() {
-            query_args.push(("to_date".to_string(), to_date.to_string()));
+            query_args.push(("to_date", to_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/folders/{}?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&folder_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/folders/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(folder_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/folders/{folderId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("folderId", folder_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -168,13 +191,23 @@ impl Folders {
     ) -> Result<crate::types::FoldersResponse> {
         let url = format!(
             "/v2.1/accounts/{}/folders/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&folder_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(folder_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/folders/{folderId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("folderId", folder_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -216,42 +249,187 @@ impl Folders {
         start_position: &str,
         to_date: &str,
     ) -> Result<crate::types::FolderItemResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !all.is_empty() {
-            query_args.push(("all".to_string(), all.to_string()));
+            query_args.push(("all", all.to_string()));
         }
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !from_date.is_empty() {
-            query_args.push(("from_date".to_string(), from_date.to_string()));
+            query_args.push(("from_date", from_date.to_string()));
         }
         if !include_recipients.is_empty() {
-            query_args.push((
-                "include_recipients".to_string(),
-                include_recipients.to_string(),
-            ));
+            query_args.push(("include_recipients", include_recipients.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !order_by.is_empty() {
-            query_args.push(("order_by".to_string(), order_by.to_string()));
+            query_args.push(("order_by", order_by.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
         if !to_date.is_empty() {
-            query_args.push(("to_date".to_string(), to_date.to_string()));
+            query_args.push(("to_date", to_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/search_folders/{}?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&search_folder_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/search_folders/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(search_folder_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/search_folders/{searchFolderId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("searchFolderId", search_folder_id.to_string()),
+                ],
+            )
+        })
+    }
+}
+
+/// The functions of [`Folders`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn FoldersApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait FoldersApi: Send + Sync {
+    async fn get(
+        &self,
+        account_id: &str,
+        include: &str,
+        include_items: &str,
+        start_position: &str,
+        template: &str,
+        user_filter: &str,
+    ) -> Result<crate::types::FoldersResponse>;
+    async fn get_items(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        from_date: &str,
+        include_items: &str,
+        owner_email: &str,
+        owner_name: &str,
+        search_text: &str,
+        start_position: &str,
+        status: &str,
+        to_date: &str,
+    ) -> Result<crate::types::FoldersResponse>;
+    async fn put(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        body: &crate::types::FoldersRequest,
+    ) -> Result<crate::types::FoldersResponse>;
+    async fn search_get_contents(
+        &self,
+        account_id: &str,
+        search_folder_id: &str,
+        all: &str,
+        count: &str,
+        from_date: &str,
+        include_recipients: &str,
+        order: &str,
+        order_by: &str,
+        start_position: &str,
+        to_date: &str,
+    ) -> Result<crate::types::FolderItemResponse>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl FoldersApi for Folders {
+    async fn get(
+        &self,
+        account_id: &str,
+        include: &str,
+        include_items: &str,
+        start_position: &str,
+        template: &str,
+        user_filter: &str,
+    ) -> Result<crate::types::FoldersResponse> {
+        Folders::get(
+            self,
+            account_id,
+            include,
+            include_items,
+            start_position,
+            template,
+            user_filter,
+        )
+        .await
+    }
+    async fn get_items(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        from_date: &str,
+        include_items: &str,
+        owner_email: &str,
+        owner_name: &str,
+        search_text: &str,
+        start_position: &str,
+        status: &str,
+        to_date: &str,
+    ) -> Result<crate::types::FoldersResponse> {
+        Folders::get_items(
+            self,
+            account_id,
+            folder_id,
+            from_date,
+            include_items,
+            owner_email,
+            owner_name,
+            search_text,
+            start_position,
+            status,
+            to_date,
+        )
+        .await
+    }
+    async fn put(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        body: &crate::types::FoldersRequest,
+    ) -> Result<crate::types::FoldersResponse> {
+        Folders::put(self, account_id, folder_id, body).await
+    }
+    async fn search_get_contents(
+        &self,
+        account_id: &str,
+        search_folder_id: &str,
+        all: &str,
+        count: &str,
+        from_date: &str,
+        include_recipients: &str,
+        order: &str,
+        order_by: &str,
+        start_position: &str,
+        to_date: &str,
+    ) -> Result<crate::types::FolderItemResponse> {
+        Folders::search_get_contents(
+            self,
+            account_id,
+            search_folder_id,
+            all,
+            count,
+            from_date,
+            include_recipients,
+            order,
+            order_by,
+            start_position,
+            to_date,
+        )
+        .await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct GroupBrands {
     pub client: Client,
@@ -12,6 +10,14 @@ impl GroupBrands {
         GroupBrands { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        GroupBrands {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets the brand information for a group.
      *
@@ -31,11 +37,20 @@ impl GroupBrands {
     ) -> Result<crate::types::GroupBrands> {
         let url = format!(
             "/v2.1/accounts/{}/groups/{}/brands",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&group_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(group_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/groups/{groupId}/brands",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("groupId", group_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -58,13 +73,23 @@ impl GroupBrands {
     ) -> Result<crate::types::GroupBrands> {
         let url = format!(
             "/v2.1/accounts/{}/groups/{}/brands",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&group_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(group_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/groups/{groupId}/brands",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("groupId", group_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -87,12 +112,75 @@ impl GroupBrands {
     ) -> Result<crate::types::GroupBrands> {
         let url = format!(
             "/v2.1/accounts/{}/groups/{}/brands",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&group_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(group_id),
         );
 
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .delete(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/groups/{groupId}/brands",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("groupId", group_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`GroupBrands`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn GroupBrandsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait GroupBrandsApi: Send + Sync {
+    async fn brands_get_group(
+        &self,
+        account_id: &str,
+        group_id: &str,
+    ) -> Result<crate::types::GroupBrands>;
+    async fn brands_put_group(
+        &self,
+        account_id: &str,
+        group_id: &str,
+        body: &crate::types::BrandsRequest,
+    ) -> Result<crate::types::GroupBrands>;
+    async fn brands_delete_group(
+        &self,
+        account_id: &str,
+        group_id: &str,
+        body: &crate::types::BrandsRequest,
+    ) -> Result<crate::types::GroupBrands>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl GroupBrandsApi for GroupBrands {
+    async fn brands_get_group(
+        &self,
+        account_id: &str,
+        group_id: &str,
+    ) -> Result<crate::types::GroupBrands> {
+        GroupBrands::brands_get_group(self, account_id, group_id).await
+    }
+    async fn brands_put_group(
+        &self,
+        account_id: &str,
+        group_id: &str,
+        body: &crate::types::BrandsRequest,
+    ) -> Result<crate::types::GroupBrands> {
+        GroupBrands::brands_put_group(self, account_id, group_id, body).await
+    }
+    async fn brands_delete_group(
+        &self,
+        account_id: &str,
+        group_id: &str,
+        body: &crate::types::BrandsRequest,
+    ) -> Result<crate::types::GroupBrands> {
+        GroupBrands::brands_delete_group(self, account_id, group_id, body).await
     }
 }
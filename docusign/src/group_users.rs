@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct GroupUsers {
     pub client: Client,
@@ -12,6 +10,14 @@ impl GroupUsers {
         GroupUsers { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        GroupUsers {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets a list of users in a group.
      *
@@ -33,22 +39,32 @@ impl GroupUsers {
         count: &str,
         start_position: &str,
     ) -> Result<crate::types::UsersResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/groups/{}/users?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/groups/{}/users",
+                self.client.encode_path(account_id),
+                self.client.encode_path(group_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/groups/{groupId}/users",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("groupId", group_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -71,13 +87,23 @@ impl GroupUsers {
     ) -> Result<crate::types::UsersResponse> {
         let url = format!(
             "/v2.1/accounts/{}/groups/{}/users",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&group_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(group_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/groups/{groupId}/users",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("groupId", group_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -101,12 +127,79 @@ impl GroupUsers {
     ) -> Result<crate::types::UsersResponse> {
         let url = format!(
             "/v2.1/accounts/{}/groups/{}/users",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&group_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(group_id),
         );
 
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .delete(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/groups/{groupId}/users",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("groupId", group_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`GroupUsers`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn GroupUsersApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait GroupUsersApi: Send + Sync {
+    async fn groups_get(
+        &self,
+        account_id: &str,
+        group_id: &str,
+        count: &str,
+        start_position: &str,
+    ) -> Result<crate::types::UsersResponse>;
+    async fn groups_put(
+        &self,
+        account_id: &str,
+        group_id: &str,
+        body: &crate::types::UserInfoList,
+    ) -> Result<crate::types::UsersResponse>;
+    async fn groups_delete(
+        &self,
+        account_id: &str,
+        group_id: &str,
+        body: &crate::types::UserInfoList,
+    ) -> Result<crate::types::UsersResponse>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl GroupUsersApi for GroupUsers {
+    async fn groups_get(
+        &self,
+        account_id: &str,
+        group_id: &str,
+        count: &str,
+        start_position: &str,
+    ) -> Result<crate::types::UsersResponse> {
+        GroupUsers::groups_get(self, account_id, group_id, count, start_position).await
+    }
+    async fn groups_put(
+        &self,
+        account_id: &str,
+        group_id: &str,
+        body: &crate::types::UserInfoList,
+    ) -> Result<crate::types::UsersResponse> {
+        GroupUsers::groups_put(self, account_id, group_id, body).await
+    }
+    async fn groups_delete(
+        &self,
+        account_id: &str,
+        group_id: &str,
+        body: &crate::types::UserInfoList,
+    ) -> Result<crate::types::UsersResponse> {
+        GroupUsers::groups_delete(self, account_id, group_id, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Groups {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Groups {
         Groups { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Groups {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets information about groups associated with the account.
      *
@@ -37,33 +43,37 @@ impl Groups {
         search_text: &str,
         start_position: &str,
     ) -> Result<crate::types::GroupInformation> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !group_type.is_empty() {
-            query_args.push(("group_type".to_string(), group_type.to_string()));
+            query_args.push(("group_type", group_type.to_string()));
         }
         if !include_usercount.is_empty() {
-            query_args.push((
-                "include_usercount".to_string(),
-                include_usercount.to_string(),
-            ));
+            query_args.push(("include_usercount", include_usercount.to_string()));
         }
         if !search_text.is_empty() {
-            query_args.push(("search_text".to_string(), search_text.to_string()));
+            query_args.push(("search_text", search_text.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/groups?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/groups",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/groups",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -84,12 +94,19 @@ impl Groups {
     ) -> Result<crate::types::GroupInformation> {
         let url = format!(
             "/v2.1/accounts/{}/groups",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/groups",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -112,12 +129,19 @@ impl Groups {
     ) -> Result<crate::types::GroupInformation> {
         let url = format!(
             "/v2.1/accounts/{}/groups",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/groups",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -152,11 +176,96 @@ impl Groups {
     ) -> Result<crate::types::GroupInformation> {
         let url = format!(
             "/v2.1/accounts/{}/groups",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .delete(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/groups",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`Groups`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn GroupsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait GroupsApi: Send + Sync {
+    async fn get(
+        &self,
+        account_id: &str,
+        count: &str,
+        group_type: &str,
+        include_usercount: &str,
+        search_text: &str,
+        start_position: &str,
+    ) -> Result<crate::types::GroupInformation>;
+    async fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::GroupInformation,
+    ) -> Result<crate::types::GroupInformation>;
+    async fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::GroupInformation,
+    ) -> Result<crate::types::GroupInformation>;
+    async fn delete(
+        &self,
+        account_id: &str,
+        body: &crate::types::GroupInformation,
+    ) -> Result<crate::types::GroupInformation>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl GroupsApi for Groups {
+    async fn get(
+        &self,
+        account_id: &str,
+        count: &str,
+        group_type: &str,
+        include_usercount: &str,
+        search_text: &str,
+        start_position: &str,
+    ) -> Result<crate::types::GroupInformation> {
+        Groups::get(
+            self,
+            account_id,
+            count,
+            group_type,
+            include_usercount,
+            search_text,
+            start_position,
+        )
+        .await
+    }
+    async fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::GroupInformation,
+    ) -> Result<crate::types::GroupInformation> {
+        Groups::put(self, account_id, body).await
+    }
+    async fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::GroupInformation,
+    ) -> Result<crate::types::GroupInformation> {
+        Groups::post(self, account_id, body).await
+    }
+    async fn delete(
+        &self,
+        account_id: &str,
+        body: &crate::types::GroupInformation,
+    ) -> Result<crate::types::GroupInformation> {
+        Groups::delete(self, account_id, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct IdentityVerifications {
     pub client: Client,
@@ -12,6 +10,14 @@ impl IdentityVerifications {
         IdentityVerifications { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        IdentityVerifications {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieves the Identity Verification workflows available to an account.
      *
@@ -31,9 +37,38 @@ impl IdentityVerifications {
     ) -> Result<crate::types::AccountIdentityVerificationResponse> {
         let url = format!(
             "/v2.1/accounts/{}/identity_verification",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/identity_verification",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`IdentityVerifications`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn IdentityVerificationsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait IdentityVerificationsApi: Send + Sync {
+    async fn account_get(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::AccountIdentityVerificationResponse>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl IdentityVerificationsApi for IdentityVerifications {
+    async fn account_get(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::AccountIdentityVerificationResponse> {
+        IdentityVerifications::account_get(self, account_id).await
     }
 }
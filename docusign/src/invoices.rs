@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Invoices {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Invoices {
         Invoices { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Invoices {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get a List of Billing Invoices.
      *
@@ -33,21 +39,28 @@ impl Invoices {
         from_date: &str,
         to_date: &str,
     ) -> Result<crate::types::BillingInvoicesResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from_date.is_empty() {
-            query_args.push(("from_date".to_string(), from_date.to_string()));
+            query_args.push(("from_date", from_date.to_string()));
         }
         if !to_date.is_empty() {
-            query_args.push(("to_date".to_string(), to_date.to_string()));
+            query_args.push(("to_date", to_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/billing_invoices?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/billing_invoices",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/billing_invoices",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -102,11 +115,20 @@ impl Invoices {
     ) -> Result<crate::types::BillingInvoice> {
         let url = format!(
             "/v2.1/accounts/{}/billing_invoices/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&invoice_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(invoice_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/billing_invoices/{invoiceId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("invoiceId", invoice_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -128,9 +150,64 @@ impl Invoices {
     ) -> Result<crate::types::BillingInvoicesSummary> {
         let url = format!(
             "/v2.1/accounts/{}/billing_invoices_past_due",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/billing_invoices_past_due",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`Invoices`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn InvoicesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait InvoicesApi: Send + Sync {
+    async fn billing_get(
+        &self,
+        account_id: &str,
+        from_date: &str,
+        to_date: &str,
+    ) -> Result<crate::types::BillingInvoicesResponse>;
+    async fn billing_get_invoices(
+        &self,
+        account_id: &str,
+        invoice_id: &str,
+    ) -> Result<crate::types::BillingInvoice>;
+    async fn billing_get_past_due(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::BillingInvoicesSummary>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl InvoicesApi for Invoices {
+    async fn billing_get(
+        &self,
+        account_id: &str,
+        from_date: &str,
+        to_date: &str,
+    ) -> Result<crate::types::BillingInvoicesResponse> {
+        Invoices::billing_get(self, account_id, from_date, to_date).await
+    }
+    async fn billing_get_invoices(
+        &self,
+        account_id: &str,
+        invoice_id: &str,
+    ) -> Result<crate::types::BillingInvoice> {
+        Invoices::billing_get_invoices(self, account_id, invoice_id).await
+    }
+    async fn billing_get_past_due(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::BillingInvoicesSummary> {
+        Invoices::billing_get_past_due(self, account_id).await
     }
 }
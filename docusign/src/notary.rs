@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Notary {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Notary {
         Notary { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Notary {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets settings for a  notary user.
      *
@@ -25,17 +31,16 @@ impl Notary {
      * * `include_jurisdictions: &str` -- If **true**, the response will include a `jurisdiction` property that contains an array of all supported jurisdictions for the current user.
      */
     pub async fn get(&self, include_jurisdictions: &str) -> Result<crate::types::NotaryResult> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_jurisdictions.is_empty() {
-            query_args.push((
-                "include_jurisdictions".to_string(),
-                include_jurisdictions.to_string(),
-            ));
+            query_args.push(("include_jurisdictions", include_jurisdictions.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v2.1/current_user/notary?{}", query_);
+        let url = crate::Client::url_with_query("/v2.1/current_user/notary", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/v2.1/current_user/notary", &[]))
     }
 
     /**
@@ -48,8 +53,9 @@ impl Notary {
     pub async fn put(&self, body: &crate::types::NotaryData) -> Result<crate::types::NotaryData> {
         let url = "/v2.1/current_user/notary".to_string();
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("PUT", "/v2.1/current_user/notary", &[]))
     }
 
     /**
@@ -62,7 +68,33 @@ impl Notary {
     pub async fn post(&self, body: &crate::types::NotaryData) -> Result<crate::types::NotaryData> {
         let url = "/v2.1/current_user/notary".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/v2.1/current_user/notary", &[]))
+    }
+}
+
+/// The functions of [`Notary`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn NotaryApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait NotaryApi: Send + Sync {
+    async fn get(&self, include_jurisdictions: &str) -> Result<crate::types::NotaryResult>;
+    async fn put(&self, body: &crate::types::NotaryData) -> Result<crate::types::NotaryData>;
+    async fn post(&self, body: &crate::types::NotaryData) -> Result<crate::types::NotaryData>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl NotaryApi for Notary {
+    async fn get(&self, include_jurisdictions: &str) -> Result<crate::types::NotaryResult> {
+        Notary::get(self, include_jurisdictions).await
+    }
+    async fn put(&self, body: &crate::types::NotaryData) -> Result<crate::types::NotaryData> {
+        Notary::put(self, body).await
+    }
+    async fn post(&self, body: &crate::types::NotaryData) -> Result<crate::types::NotaryData> {
+        Notary::post(self, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct NotaryJournals {
     pub client: Client,
@@ -12,6 +10,14 @@ impl NotaryJournals {
         NotaryJournals { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        NotaryJournals {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets notary jurisdictions for a user.
      *
@@ -31,19 +37,47 @@ impl NotaryJournals {
         search_text: &str,
         start_position: &str,
     ) -> Result<crate::types::NotaryJournalList> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !search_text.is_empty() {
-            query_args.push(("search_text".to_string(), search_text.to_string()));
+            query_args.push(("search_text", search_text.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v2.1/current_user/notary/journals?{}", query_);
+        let url = crate::Client::url_with_query("/v2.1/current_user/notary/journals", &query_args);
+
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/v2.1/current_user/notary/journals", &[])
+        })
+    }
+}
+
+/// The functions of [`NotaryJournals`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn NotaryJournalsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait NotaryJournalsApi: Send + Sync {
+    async fn get(
+        &self,
+        count: &str,
+        search_text: &str,
+        start_position: &str,
+    ) -> Result<crate::types::NotaryJournalList>;
+}
 
-        self.client.get(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl NotaryJournalsApi for NotaryJournals {
+    async fn get(
+        &self,
+        count: &str,
+        search_text: &str,
+        start_position: &str,
+    ) -> Result<crate::types::NotaryJournalList> {
+        NotaryJournals::get(self, count, search_text, start_position).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct NotaryJurisdiction {
     pub client: Client,
@@ -12,6 +10,14 @@ impl NotaryJurisdiction {
         NotaryJurisdiction { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        NotaryJurisdiction {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Returns a list of jurisdictions that the notary is registered in.
      *
@@ -22,7 +28,9 @@ impl NotaryJurisdiction {
      */
     pub async fn s_get(&self) -> Result<crate::types::NotaryJurisdictionList> {
         let url = "/v2.1/current_user/notary/jurisdictions".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/v2.1/current_user/notary/jurisdictions", &[])
+        })
     }
 
     /**
@@ -38,8 +46,11 @@ impl NotaryJurisdiction {
     ) -> Result<crate::types::NotaryJurisdictionData> {
         let url = "/v2.1/current_user/notary/jurisdictions".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/v2.1/current_user/notary/jurisdictions", &[])
+            })
     }
 
     /**
@@ -91,10 +102,16 @@ impl NotaryJurisdiction {
     ) -> Result<crate::types::NotaryJurisdictionData> {
         let url = format!(
             "/v2.1/current_user/notary/jurisdictions/{}",
-            crate::progenitor_support::encode_path(&jurisdiction_id.to_string()),
+            self.client.encode_path(jurisdiction_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/current_user/notary/jurisdictions/{jurisdictionId}",
+                &[("jurisdictionId", jurisdiction_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -201,12 +218,19 @@ impl NotaryJurisdiction {
     ) -> Result<crate::types::NotaryJurisdictionData> {
         let url = format!(
             "/v2.1/current_user/notary/jurisdictions/{}",
-            crate::progenitor_support::encode_path(&jurisdiction_id.to_string()),
+            self.client.encode_path(jurisdiction_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/current_user/notary/jurisdictions/{jurisdictionId}",
+                    &[("jurisdictionId", jurisdiction_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -251,9 +275,97 @@ impl NotaryJurisdiction {
     pub async fn s_delete_jurisdiction(&self, jurisdiction_id: &str) -> Result<()> {
         let url = format!(
             "/v2.1/current_user/notary/jurisdictions/{}",
-            crate::progenitor_support::encode_path(&jurisdiction_id.to_string()),
+            self.client.encode_path(jurisdiction_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/current_user/notary/jurisdictions/{jurisdictionId}",
+                &[("jurisdictionId", jurisdiction_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes the specified jurisdiction.
+     *
+     * This function performs a `DELETE` to the `/v2.1/current_user/notary/jurisdictions/{jurisdictionId}` endpoint.
+     *
+     * As opposed to `s_delete_jurisdiction`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn s_delete_jurisdiction_if_exists(&self, jurisdiction_id: &str) -> Result<bool> {
+        let url = format!(
+            "/v2.1/current_user/notary/jurisdictions/{}",
+            self.client.encode_path(jurisdiction_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/current_user/notary/jurisdictions/{jurisdictionId}",
+                    &[("jurisdictionId", jurisdiction_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`NotaryJurisdiction`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn NotaryJurisdictionApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait NotaryJurisdictionApi: Send + Sync {
+    async fn s_get(&self) -> Result<crate::types::NotaryJurisdictionList>;
+    async fn s_post(
+        &self,
+        body: &crate::types::NotaryJurisdictionData,
+    ) -> Result<crate::types::NotaryJurisdictionData>;
+    async fn s_get_jurisdiction(
+        &self,
+        jurisdiction_id: &str,
+    ) -> Result<crate::types::NotaryJurisdictionData>;
+    async fn s_put_jurisdiction(
+        &self,
+        jurisdiction_id: &str,
+        body: &crate::types::NotaryJurisdictionData,
+    ) -> Result<crate::types::NotaryJurisdictionData>;
+    async fn s_delete_jurisdiction(&self, jurisdiction_id: &str) -> Result<()>;
+    async fn s_delete_jurisdiction_if_exists(&self, jurisdiction_id: &str) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl NotaryJurisdictionApi for NotaryJurisdiction {
+    async fn s_get(&self) -> Result<crate::types::NotaryJurisdictionList> {
+        NotaryJurisdiction::s_get(self).await
+    }
+    async fn s_post(
+        &self,
+        body: &crate::types::NotaryJurisdictionData,
+    ) -> Result<crate::types::NotaryJurisdictionData> {
+        NotaryJurisdiction::s_post(self, body).await
+    }
+    async fn s_get_jurisdiction(
+        &self,
+        jurisdiction_id: &str,
+    ) -> Result<crate::types::NotaryJurisdictionData> {
+        NotaryJurisdiction::s_get_jurisdiction(self, jurisdiction_id).await
+    }
+    async fn s_put_jurisdiction(
+        &self,
+        jurisdiction_id: &str,
+        body: &crate::types::NotaryJurisdictionData,
+    ) -> Result<crate::types::NotaryJurisdictionData> {
+        NotaryJurisdiction::s_put_jurisdiction(self, jurisdiction_id, body).await
+    }
+    async fn s_delete_jurisdiction(&self, jurisdiction_id: &str) -> Result<()> {
+        NotaryJurisdiction::s_delete_jurisdiction(self, jurisdiction_id).await
+    }
+    async fn s_delete_jurisdiction_if_exists(&self, jurisdiction_id: &str) -> Result<bool> {
+        NotaryJurisdiction::s_delete_jurisdiction_if_exists(self, jurisdiction_id).await
     }
 }
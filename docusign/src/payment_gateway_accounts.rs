@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct PaymentGatewayAccounts {
     pub client: Client,
@@ -12,6 +10,14 @@ impl PaymentGatewayAccounts {
         PaymentGatewayAccounts { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        PaymentGatewayAccounts {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * List payment gateway accounts.
      *
@@ -29,9 +35,32 @@ impl PaymentGatewayAccounts {
     ) -> Result<crate::types::PaymentGatewayAccountsInfo> {
         let url = format!(
             "/v2.1/accounts/{}/payment_gateway_accounts",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/payment_gateway_accounts",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`PaymentGatewayAccounts`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn PaymentGatewayAccountsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait PaymentGatewayAccountsApi: Send + Sync {
+    async fn get_all(&self, account_id: &str) -> Result<crate::types::PaymentGatewayAccountsInfo>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl PaymentGatewayAccountsApi for PaymentGatewayAccounts {
+    async fn get_all(&self, account_id: &str) -> Result<crate::types::PaymentGatewayAccountsInfo> {
+        PaymentGatewayAccounts::get_all(self, account_id).await
     }
 }
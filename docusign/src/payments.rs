@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Payments {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Payments {
         Payments { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Payments {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets payment information for one or more payments.
      *
@@ -33,21 +39,28 @@ impl Payments {
         from_date: &str,
         to_date: &str,
     ) -> Result<crate::types::BillingPaymentsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from_date.is_empty() {
-            query_args.push(("from_date".to_string(), from_date.to_string()));
+            query_args.push(("from_date", from_date.to_string()));
         }
         if !to_date.is_empty() {
-            query_args.push(("to_date".to_string(), to_date.to_string()));
+            query_args.push(("to_date", to_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/billing_payments?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/billing_payments",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/billing_payments",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -75,12 +88,19 @@ impl Payments {
     ) -> Result<crate::types::BillingPaymentResponse> {
         let url = format!(
             "/v2.1/accounts/{}/billing_payments",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/billing_payments",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -104,10 +124,70 @@ impl Payments {
     ) -> Result<crate::types::BillingPaymentItem> {
         let url = format!(
             "/v2.1/accounts/{}/billing_payments/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&payment_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(payment_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/billing_payments/{paymentId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("paymentId", payment_id.to_string()),
+                ],
+            )
+        })
+    }
+}
+
+/// The functions of [`Payments`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn PaymentsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait PaymentsApi: Send + Sync {
+    async fn billing_get_list(
+        &self,
+        account_id: &str,
+        from_date: &str,
+        to_date: &str,
+    ) -> Result<crate::types::BillingPaymentsResponse>;
+    async fn billing_post(
+        &self,
+        account_id: &str,
+        body: &crate::types::BillingPaymentRequest,
+    ) -> Result<crate::types::BillingPaymentResponse>;
+    async fn billing_get(
+        &self,
+        account_id: &str,
+        payment_id: &str,
+    ) -> Result<crate::types::BillingPaymentItem>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl PaymentsApi for Payments {
+    async fn billing_get_list(
+        &self,
+        account_id: &str,
+        from_date: &str,
+        to_date: &str,
+    ) -> Result<crate::types::BillingPaymentsResponse> {
+        Payments::billing_get_list(self, account_id, from_date, to_date).await
+    }
+    async fn billing_post(
+        &self,
+        account_id: &str,
+        body: &crate::types::BillingPaymentRequest,
+    ) -> Result<crate::types::BillingPaymentResponse> {
+        Payments::billing_post(self, account_id, body).await
+    }
+    async fn billing_get(
+        &self,
+        account_id: &str,
+        payment_id: &str,
+    ) -> Result<crate::types::BillingPaymentItem> {
+        Payments::billing_get(self, account_id, payment_id).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct PowerFormData {
     pub client: Client,
@@ -12,6 +10,14 @@ impl PowerFormData {
         PowerFormData { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        PowerFormData {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Returns the data that users entered in a PowerForm.
      *
@@ -51,24 +57,73 @@ impl PowerFormData {
         from_date: &str,
         to_date: &str,
     ) -> Result<crate::types::PowerFormsFormDataResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !data_layout.is_empty() {
-            query_args.push(("data_layout".to_string(), data_layout.to_string()));
+            query_args.push(("data_layout", data_layout.to_string()));
         }
         if !from_date.is_empty() {
-            query_args.push(("from_date".to_string(), from_date.to_string()));
+            query_args.push(("from_date", from_date.to_string()));
         }
         if !to_date.is_empty() {
-            query_args.push(("to_date".to_string(), to_date.to_string()));
+            query_args.push(("to_date", to_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/powerforms/{}/form_data?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&power_form_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/powerforms/{}/form_data",
+                self.client.encode_path(account_id),
+                self.client.encode_path(power_form_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/powerforms/{powerFormId}/form_data",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("powerFormId", power_form_id.to_string()),
+                ],
+            )
+        })
+    }
+}
+
+/// The functions of [`PowerFormData`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn PowerFormDataApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait PowerFormDataApi: Send + Sync {
+    async fn power_forms_get_form_data(
+        &self,
+        account_id: &str,
+        power_form_id: &str,
+        data_layout: &str,
+        from_date: &str,
+        to_date: &str,
+    ) -> Result<crate::types::PowerFormsFormDataResponse>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl PowerFormDataApi for PowerFormData {
+    async fn power_forms_get_form_data(
+        &self,
+        account_id: &str,
+        power_form_id: &str,
+        data_layout: &str,
+        from_date: &str,
+        to_date: &str,
+    ) -> Result<crate::types::PowerFormsFormDataResponse> {
+        PowerFormData::power_forms_get_form_data(
+            self,
+            account_id,
+            power_form_id,
+            data_layout,
+            from_date,
+            to_date,
+        )
+        .await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct PowerForms {
     pub client: Client,
@@ -12,6 +10,14 @@ impl PowerForms {
         PowerForms { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        PowerForms {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Returns a list of PowerForms.
      *
@@ -50,27 +56,34 @@ impl PowerForms {
         order_by: &str,
         to_date: &str,
     ) -> Result<crate::types::PowerFormsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from_date.is_empty() {
-            query_args.push(("from_date".to_string(), from_date.to_string()));
+            query_args.push(("from_date", from_date.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !order_by.is_empty() {
-            query_args.push(("order_by".to_string(), order_by.to_string()));
+            query_args.push(("order_by", order_by.to_string()));
         }
         if !to_date.is_empty() {
-            query_args.push(("to_date".to_string(), to_date.to_string()));
+            query_args.push(("to_date", to_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/powerforms?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/powerforms",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/powerforms",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -130,12 +143,19 @@ impl PowerForms {
     ) -> Result<crate::types::PowerForm> {
         let url = format!(
             "/v2.1/accounts/{}/powerforms",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/powerforms",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -156,12 +176,19 @@ impl PowerForms {
     ) -> Result<crate::types::PowerFormsResponse> {
         let url = format!(
             "/v2.1/accounts/{}/powerforms",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .delete(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/powerforms",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -181,18 +208,25 @@ impl PowerForms {
         account_id: &str,
         start_position: &str,
     ) -> Result<crate::types::PowerFormSendersResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/powerforms/senders?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/powerforms/senders",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/powerforms/senders",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -214,11 +248,20 @@ impl PowerForms {
     ) -> Result<crate::types::PowerForm> {
         let url = format!(
             "/v2.1/accounts/{}/powerforms/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&power_form_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(power_form_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/powerforms/{powerFormId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("powerFormId", power_form_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -241,13 +284,23 @@ impl PowerForms {
     ) -> Result<crate::types::PowerForm> {
         let url = format!(
             "/v2.1/accounts/{}/powerforms/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&power_form_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(power_form_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/powerforms/{powerFormId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("powerFormId", power_form_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -265,10 +318,153 @@ impl PowerForms {
     pub async fn delete_form(&self, account_id: &str, power_form_id: &str) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/powerforms/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&power_form_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(power_form_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/powerforms/{powerFormId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("powerFormId", power_form_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Deletes a PowerForm.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/powerforms/{powerFormId}` endpoint.
+     *
+     * As opposed to `delete_form`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_form_if_exists(
+        &self,
+        account_id: &str,
+        power_form_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/powerforms/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(power_form_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/powerforms/{powerFormId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("powerFormId", power_form_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`PowerForms`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn PowerFormsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait PowerFormsApi: Send + Sync {
+    async fn get_list(
+        &self,
+        account_id: &str,
+        from_date: &str,
+        order: &str,
+        order_by: &str,
+        to_date: &str,
+    ) -> Result<crate::types::PowerFormsResponse>;
+    async fn post_form(
+        &self,
+        account_id: &str,
+        body: &crate::types::PowerForm,
+    ) -> Result<crate::types::PowerForm>;
+    async fn delete_list(
+        &self,
+        account_id: &str,
+        body: &crate::types::PowerFormsRequest,
+    ) -> Result<crate::types::PowerFormsResponse>;
+    async fn get_senders(
+        &self,
+        account_id: &str,
+        start_position: &str,
+    ) -> Result<crate::types::PowerFormSendersResponse>;
+    async fn get_form(
+        &self,
+        account_id: &str,
+        power_form_id: &str,
+    ) -> Result<crate::types::PowerForm>;
+    async fn put_form(
+        &self,
+        account_id: &str,
+        power_form_id: &str,
+        body: &crate::types::PowerForm,
+    ) -> Result<crate::types::PowerForm>;
+    async fn delete_form(&self, account_id: &str, power_form_id: &str) -> Result<()>;
+    async fn delete_form_if_exists(&self, account_id: &str, power_form_id: &str) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl PowerFormsApi for PowerForms {
+    async fn get_list(
+        &self,
+        account_id: &str,
+        from_date: &str,
+        order: &str,
+        order_by: &str,
+        to_date: &str,
+    ) -> Result<crate::types::PowerFormsResponse> {
+        PowerForms::get_list(self, account_id, from_date, order, order_by, to_date).await
+    }
+    async fn post_form(
+        &self,
+        account_id: &str,
+        body: &crate::types::PowerForm,
+    ) -> Result<crate::types::PowerForm> {
+        PowerForms::post_form(self, account_id, body).await
+    }
+    async fn delete_list(
+        &self,
+        account_id: &str,
+        body: &crate::types::PowerFormsRequest,
+    ) -> Result<crate::types::PowerFormsResponse> {
+        PowerForms::delete_list(self, account_id, body).await
+    }
+    async fn get_senders(
+        &self,
+        account_id: &str,
+        start_position: &str,
+    ) -> Result<crate::types::PowerFormSendersResponse> {
+        PowerForms::get_senders(self, account_id, start_position).await
+    }
+    async fn get_form(
+        &self,
+        account_id: &str,
+        power_form_id: &str,
+    ) -> Result<crate::types::PowerForm> {
+        PowerForms::get_form(self, account_id, power_form_id).await
+    }
+    async fn put_form(
+        &self,
+        account_id: &str,
+        power_form_id: &str,
+        body: &crate::types::PowerForm,
+    ) -> Result<crate::types::PowerForm> {
+        PowerForms::put_form(self, account_id, power_form_id, body).await
+    }
+    async fn delete_form(&self, account_id: &str, power_form_id: &str) -> Result<()> {
+        PowerForms::delete_form(self, account_id, power_form_id).await
+    }
+    async fn delete_form_if_exists(&self, account_id: &str, power_form_id: &str) -> Result<bool> {
+        PowerForms::delete_form_if_exists(self, account_id, power_form_id).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Reports {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Reports {
         Reports { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Reports {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
     * Gets the descriptors for all of
     an account's active reports (for listings).
@@ -27,10 +33,16 @@ impl Reports {
     pub async fn product_get_list(&self, account_id: &str) -> Result<crate::types::Reports> {
         let url = format!(
             "/v2.1/accounts/{}/reports",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/reports",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -51,12 +63,19 @@ impl Reports {
     ) -> Result<crate::types::ReportInProductSaveResponse> {
         let url = format!(
             "/v2.1/accounts/{}/reports",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/reports",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -77,12 +96,19 @@ impl Reports {
     ) -> Result<crate::types::ReportInProductRunResponse> {
         let url = format!(
             "/v2.1/accounts/{}/reports/report_results",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/reports/report_results",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -103,12 +129,19 @@ impl Reports {
     ) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/reports/report_results_csv",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/reports/report_results_csv",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -130,11 +163,20 @@ impl Reports {
     ) -> Result<crate::types::ReportInProductGet> {
         let url = format!(
             "/v2.1/accounts/{}/reports/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/reports/{id}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("id", id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -157,13 +199,23 @@ impl Reports {
     ) -> Result<crate::types::ReportInProductSaveResponse> {
         let url = format!(
             "/v2.1/accounts/{}/reports/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/reports/{id}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("id", id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -185,10 +237,110 @@ impl Reports {
     ) -> Result<crate::types::ReportInProductSaveResponse> {
         let url = format!(
             "/v2.1/accounts/{}/reports/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/reports/{id}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("id", id.to_string()),
+                ],
+            )
+        })
+    }
+}
+
+/// The functions of [`Reports`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ReportsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ReportsApi: Send + Sync {
+    async fn product_get_list(&self, account_id: &str) -> Result<crate::types::Reports>;
+    async fn product_post_create(
+        &self,
+        account_id: &str,
+        body: &crate::types::ReportInProductRunRequest,
+    ) -> Result<crate::types::ReportInProductSaveResponse>;
+    async fn product_put_run_results(
+        &self,
+        account_id: &str,
+        body: &crate::types::ReportInProductRunRequest,
+    ) -> Result<crate::types::ReportInProductRunResponse>;
+    async fn product_put_results_csv(
+        &self,
+        account_id: &str,
+        body: &crate::types::ReportInProductCsvRunRequest,
+    ) -> Result<()>;
+    async fn product_get(
+        &self,
+        account_id: &str,
+        id: &str,
+    ) -> Result<crate::types::ReportInProductGet>;
+    async fn product_put_save(
+        &self,
+        account_id: &str,
+        id: &str,
+        body: &crate::types::ReportInProductRunRequest,
+    ) -> Result<crate::types::ReportInProductSaveResponse>;
+    async fn product_delete(
+        &self,
+        account_id: &str,
+        id: &str,
+    ) -> Result<crate::types::ReportInProductSaveResponse>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ReportsApi for Reports {
+    async fn product_get_list(&self, account_id: &str) -> Result<crate::types::Reports> {
+        Reports::product_get_list(self, account_id).await
+    }
+    async fn product_post_create(
+        &self,
+        account_id: &str,
+        body: &crate::types::ReportInProductRunRequest,
+    ) -> Result<crate::types::ReportInProductSaveResponse> {
+        Reports::product_post_create(self, account_id, body).await
+    }
+    async fn product_put_run_results(
+        &self,
+        account_id: &str,
+        body: &crate::types::ReportInProductRunRequest,
+    ) -> Result<crate::types::ReportInProductRunResponse> {
+        Reports::product_put_run_results(self, account_id, body).await
+    }
+    async fn product_put_results_csv(
+        &self,
+        account_id: &str,
+        body: &crate::types::ReportInProductCsvRunRequest,
+    ) -> Result<()> {
+        Reports::product_put_results_csv(self, account_id, body).await
+    }
+    async fn product_get(
+        &self,
+        account_id: &str,
+        id: &str,
+    ) -> Result<crate::types::ReportInProductGet> {
+        Reports::product_get(self, account_id, id).await
+    }
+    async fn product_put_save(
+        &self,
+        account_id: &str,
+        id: &str,
+        body: &crate::types::ReportInProductRunRequest,
+    ) -> Result<crate::types::ReportInProductSaveResponse> {
+        Reports::product_put_save(self, account_id, id, body).await
+    }
+    async fn product_delete(
+        &self,
+        account_id: &str,
+        id: &str,
+    ) -> Result<crate::types::ReportInProductSaveResponse> {
+        Reports::product_delete(self, account_id, id).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct RequestLogs {
     pub client: Client,
@@ -12,6 +10,14 @@ impl RequestLogs {
         RequestLogs { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        RequestLogs {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets the API request logging log files.
      *
@@ -28,14 +34,16 @@ impl RequestLogs {
      * * `encoding: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      */
     pub async fn api_get_log(&self, encoding: &str) -> Result<crate::types::ApiRequestLogsResult> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !encoding.is_empty() {
-            query_args.push(("encoding".to_string(), encoding.to_string()));
+            query_args.push(("encoding", encoding.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v2.1/diagnostics/request_logs?{}", query_);
+        let url = crate::Client::url_with_query("/v2.1/diagnostics/request_logs", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/v2.1/diagnostics/request_logs", &[]))
     }
 
     /**
@@ -47,7 +55,26 @@ impl RequestLogs {
      */
     pub async fn api_delete_logs(&self) -> Result<()> {
         let url = "/v2.1/diagnostics/request_logs".to_string();
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("DELETE", "/v2.1/diagnostics/request_logs", &[])
+        })
+    }
+
+    /**
+     * Deletes the request log files.
+     *
+     * This function performs a `DELETE` to the `/v2.1/diagnostics/request_logs` endpoint.
+     *
+     * As opposed to `api_delete_logs`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn api_delete_logs_if_exists(&self) -> Result<bool> {
+        let url = "/v2.1/diagnostics/request_logs".to_string();
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url("DELETE", "/v2.1/diagnostics/request_logs", &[])
+            })
     }
 
     /**
@@ -70,10 +97,19 @@ impl RequestLogs {
     pub async fn api_get(&self, request_log_id: &str) -> Result<bytes::Bytes> {
         let url = format!(
             "/v2.1/diagnostics/request_logs/{}",
-            crate::progenitor_support::encode_path(&request_log_id.to_string()),
+            self.client.encode_path(request_log_id),
         );
 
-        self.client.get(&url, None).await
+        self.client
+            .request_with_accept_mime(reqwest::Method::GET, &url, "text/plain")
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/v2.1/diagnostics/request_logs/{requestLogId}",
+                    &[("requestLogId", request_log_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -88,7 +124,10 @@ impl RequestLogs {
      */
     pub async fn api_get_setting(&self) -> Result<crate::types::DiagnosticsSettingsInformation> {
         let url = "/v2.1/diagnostics/settings".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/v2.1/diagnostics/settings", &[]))
     }
 
     /**
@@ -115,7 +154,51 @@ impl RequestLogs {
     ) -> Result<crate::types::DiagnosticsSettingsInformation> {
         let url = "/v2.1/diagnostics/settings".to_string();
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("PUT", "/v2.1/diagnostics/settings", &[]))
+    }
+}
+
+/// The functions of [`RequestLogs`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn RequestLogsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait RequestLogsApi: Send + Sync {
+    async fn api_get_log(&self, encoding: &str) -> Result<crate::types::ApiRequestLogsResult>;
+    async fn api_delete_logs(&self) -> Result<()>;
+    async fn api_delete_logs_if_exists(&self) -> Result<bool>;
+    async fn api_get(&self, request_log_id: &str) -> Result<bytes::Bytes>;
+    async fn api_get_setting(&self) -> Result<crate::types::DiagnosticsSettingsInformation>;
+    async fn api_put_settings(
+        &self,
+        body: &crate::types::DiagnosticsSettingsInformation,
+    ) -> Result<crate::types::DiagnosticsSettingsInformation>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl RequestLogsApi for RequestLogs {
+    async fn api_get_log(&self, encoding: &str) -> Result<crate::types::ApiRequestLogsResult> {
+        RequestLogs::api_get_log(self, encoding).await
+    }
+    async fn api_delete_logs(&self) -> Result<()> {
+        RequestLogs::api_delete_logs(self).await
+    }
+    async fn api_delete_logs_if_exists(&self) -> Result<bool> {
+        RequestLogs::api_delete_logs_if_exists(self).await
+    }
+    async fn api_get(&self, request_log_id: &str) -> Result<bytes::Bytes> {
+        RequestLogs::api_get(self, request_log_id).await
+    }
+    async fn api_get_setting(&self) -> Result<crate::types::DiagnosticsSettingsInformation> {
+        RequestLogs::api_get_setting(self).await
+    }
+    async fn api_put_settings(
+        &self,
+        body: &crate::types::DiagnosticsSettingsInformation,
+    ) -> Result<crate::types::DiagnosticsSettingsInformation> {
+        RequestLogs::api_put_settings(self, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Resources {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Resources {
         Resources { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Resources {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Lists resources for REST version specified.
      *
@@ -29,6 +35,26 @@ impl Resources {
      */
     pub async fn service_information_get(&self) -> Result<crate::types::ResourceInformation> {
         let url = "/v2.1".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/v2.1", &[]))
+    }
+}
+
+/// The functions of [`Resources`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ResourcesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ResourcesApi: Send + Sync {
+    async fn service_information_get(&self) -> Result<crate::types::ResourceInformation>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ResourcesApi for Resources {
+    async fn service_information_get(&self) -> Result<crate::types::ResourceInformation> {
+        Resources::service_information_get(self).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Services {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Services {
         Services { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Services {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieves the available REST API versions.
      *
@@ -26,6 +32,26 @@ impl Services {
      */
     pub async fn information_get(&self) -> Result<crate::types::ServiceInformation> {
         let url = "/service_information".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/service_information", &[]))
+    }
+}
+
+/// The functions of [`Services`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ServicesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ServicesApi: Send + Sync {
+    async fn information_get(&self) -> Result<crate::types::ServiceInformation>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ServicesApi for Services {
+    async fn information_get(&self) -> Result<crate::types::ServiceInformation> {
+        Services::information_get(self).await
     }
 }
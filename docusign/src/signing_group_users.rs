@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct SigningGroupUsers {
     pub client: Client,
@@ -12,6 +10,14 @@ impl SigningGroupUsers {
         SigningGroupUsers { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        SigningGroupUsers {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets a list of members in a Signing Group.
      *
@@ -33,11 +39,20 @@ impl SigningGroupUsers {
     ) -> Result<crate::types::SigningGroupUsersData> {
         let url = format!(
             "/v2.1/accounts/{}/signing_groups/{}/users",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&signing_group_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(signing_group_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/signing_groups/{signingGroupId}/users",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("signingGroupId", signing_group_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -62,13 +77,23 @@ impl SigningGroupUsers {
     ) -> Result<crate::types::SigningGroupUsersData> {
         let url = format!(
             "/v2.1/accounts/{}/signing_groups/{}/users",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&signing_group_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(signing_group_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/signing_groups/{signingGroupId}/users",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("signingGroupId", signing_group_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -93,12 +118,82 @@ impl SigningGroupUsers {
     ) -> Result<crate::types::SigningGroupUsersData> {
         let url = format!(
             "/v2.1/accounts/{}/signing_groups/{}/users",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&signing_group_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(signing_group_id),
         );
 
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/signing_groups/{signingGroupId}/users",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("signingGroupId", signing_group_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`SigningGroupUsers`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn SigningGroupUsersApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait SigningGroupUsersApi: Send + Sync {
+    async fn signing_groups_get_group_user(
+        &self,
+        account_id: &str,
+        signing_group_id: &str,
+    ) -> Result<crate::types::SigningGroupUsersData>;
+    async fn signing_groups_put_group_users(
+        &self,
+        account_id: &str,
+        signing_group_id: &str,
+        body: &crate::types::SigningGroupUsersData,
+    ) -> Result<crate::types::SigningGroupUsersData>;
+    async fn signing_groups_delete_group_users(
+        &self,
+        account_id: &str,
+        signing_group_id: &str,
+        body: &crate::types::SigningGroupUsersData,
+    ) -> Result<crate::types::SigningGroupUsersData>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl SigningGroupUsersApi for SigningGroupUsers {
+    async fn signing_groups_get_group_user(
+        &self,
+        account_id: &str,
+        signing_group_id: &str,
+    ) -> Result<crate::types::SigningGroupUsersData> {
+        SigningGroupUsers::signing_groups_get_group_user(self, account_id, signing_group_id).await
+    }
+    async fn signing_groups_put_group_users(
+        &self,
+        account_id: &str,
+        signing_group_id: &str,
+        body: &crate::types::SigningGroupUsersData,
+    ) -> Result<crate::types::SigningGroupUsersData> {
+        SigningGroupUsers::signing_groups_put_group_users(self, account_id, signing_group_id, body)
             .await
     }
+    async fn signing_groups_delete_group_users(
+        &self,
+        account_id: &str,
+        signing_group_id: &str,
+        body: &crate::types::SigningGroupUsersData,
+    ) -> Result<crate::types::SigningGroupUsersData> {
+        SigningGroupUsers::signing_groups_delete_group_users(
+            self,
+            account_id,
+            signing_group_id,
+            body,
+        )
+        .await
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct SigningGroups {
     pub client: Client,
@@ -12,6 +10,14 @@ impl SigningGroups {
         SigningGroups { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        SigningGroups {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets a list of the Signing Groups in an account.
      *
@@ -31,21 +37,28 @@ impl SigningGroups {
         group_type: &str,
         include_users: &str,
     ) -> Result<crate::types::SigningGroupInformation> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !group_type.is_empty() {
-            query_args.push(("group_type".to_string(), group_type.to_string()));
+            query_args.push(("group_type", group_type.to_string()));
         }
         if !include_users.is_empty() {
-            query_args.push(("include_users".to_string(), include_users.to_string()));
+            query_args.push(("include_users", include_users.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/signing_groups?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/signing_groups",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/signing_groups",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -66,12 +79,19 @@ impl SigningGroups {
     ) -> Result<crate::types::SigningGroupInformation> {
         let url = format!(
             "/v2.1/accounts/{}/signing_groups",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/signing_groups",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -98,12 +118,19 @@ impl SigningGroups {
     ) -> Result<crate::types::SigningGroupInformation> {
         let url = format!(
             "/v2.1/accounts/{}/signing_groups",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/signing_groups",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -124,12 +151,19 @@ impl SigningGroups {
     ) -> Result<crate::types::SigningGroupInformation> {
         let url = format!(
             "/v2.1/accounts/{}/signing_groups",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .delete(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/signing_groups",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -153,11 +187,20 @@ impl SigningGroups {
     ) -> Result<crate::types::SigningGroup> {
         let url = format!(
             "/v2.1/accounts/{}/signing_groups/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&signing_group_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(signing_group_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/signing_groups/{signingGroupId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("signingGroupId", signing_group_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -182,12 +225,111 @@ impl SigningGroups {
     ) -> Result<crate::types::SigningGroup> {
         let url = format!(
             "/v2.1/accounts/{}/signing_groups/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&signing_group_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(signing_group_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/signing_groups/{signingGroupId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("signingGroupId", signing_group_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`SigningGroups`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn SigningGroupsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait SigningGroupsApi: Send + Sync {
+    async fn get(
+        &self,
+        account_id: &str,
+        group_type: &str,
+        include_users: &str,
+    ) -> Result<crate::types::SigningGroupInformation>;
+    async fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::SigningGroupInformation,
+    ) -> Result<crate::types::SigningGroupInformation>;
+    async fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::SigningGroupInformation,
+    ) -> Result<crate::types::SigningGroupInformation>;
+    async fn delete(
+        &self,
+        account_id: &str,
+        body: &crate::types::SigningGroupInformation,
+    ) -> Result<crate::types::SigningGroupInformation>;
+    async fn get_group(
+        &self,
+        account_id: &str,
+        signing_group_id: &str,
+    ) -> Result<crate::types::SigningGroup>;
+    async fn put_group(
+        &self,
+        account_id: &str,
+        signing_group_id: &str,
+        body: &crate::types::SigningGroup,
+    ) -> Result<crate::types::SigningGroup>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl SigningGroupsApi for SigningGroups {
+    async fn get(
+        &self,
+        account_id: &str,
+        group_type: &str,
+        include_users: &str,
+    ) -> Result<crate::types::SigningGroupInformation> {
+        SigningGroups::get(self, account_id, group_type, include_users).await
+    }
+    async fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::SigningGroupInformation,
+    ) -> Result<crate::types::SigningGroupInformation> {
+        SigningGroups::put(self, account_id, body).await
+    }
+    async fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::SigningGroupInformation,
+    ) -> Result<crate::types::SigningGroupInformation> {
+        SigningGroups::post(self, account_id, body).await
+    }
+    async fn delete(
+        &self,
+        account_id: &str,
+        body: &crate::types::SigningGroupInformation,
+    ) -> Result<crate::types::SigningGroupInformation> {
+        SigningGroups::delete(self, account_id, body).await
+    }
+    async fn get_group(
+        &self,
+        account_id: &str,
+        signing_group_id: &str,
+    ) -> Result<crate::types::SigningGroup> {
+        SigningGroups::get_group(self, account_id, signing_group_id).await
+    }
+    async fn put_group(
+        &self,
+        account_id: &str,
+        signing_group_id: &str,
+        body: &crate::types::SigningGroup,
+    ) -> Result<crate::types::SigningGroup> {
+        SigningGroups::put_group(self, account_id, signing_group_id, body).await
     }
 }
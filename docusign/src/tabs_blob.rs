@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct TabsBlob {
     pub client: Client,
@@ -12,6 +10,14 @@ impl TabsBlob {
         TabsBlob { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        TabsBlob {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets encrypted tabs for envelope.
      *
@@ -27,11 +33,20 @@ impl TabsBlob {
     pub async fn get(&self, account_id: &str, envelope_id: &str) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/tabs_blob",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/tabs_blob",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -49,10 +64,40 @@ impl TabsBlob {
     pub async fn put(&self, account_id: &str, envelope_id: &str) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/tabs_blob",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&envelope_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(envelope_id),
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "PUT",
+                "/v2.1/accounts/{accountId}/envelopes/{envelopeId}/tabs_blob",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("envelopeId", envelope_id.to_string()),
+                ],
+            )
+        })
+    }
+}
+
+/// The functions of [`TabsBlob`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn TabsBlobApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait TabsBlobApi: Send + Sync {
+    async fn get(&self, account_id: &str, envelope_id: &str) -> Result<()>;
+    async fn put(&self, account_id: &str, envelope_id: &str) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl TabsBlobApi for TabsBlob {
+    async fn get(&self, account_id: &str, envelope_id: &str) -> Result<()> {
+        TabsBlob::get(self, account_id, envelope_id).await
+    }
+    async fn put(&self, account_id: &str, envelope_id: &str) -> Result<()> {
+        TabsBlob::put(self, account_id, envelope_id).await
     }
 }
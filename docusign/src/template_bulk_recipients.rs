@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct TemplateBulkRecipients {
     pub client: Client,
@@ -12,6 +10,14 @@ impl TemplateBulkRecipients {
         TemplateBulkRecipients { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        TemplateBulkRecipients {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets the bulk recipient file from a template.
      *
@@ -35,23 +41,24 @@ impl TemplateBulkRecipients {
         include_tabs: &str,
         start_position: &str,
     ) -> Result<crate::types::BulkRecipientsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_tabs.is_empty() {
-            query_args.push(("include_tabs".to_string(), include_tabs.to_string()));
+            query_args.push(("include_tabs", include_tabs.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/templates/{}/recipients/{}/bulk_recipients?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-            crate::progenitor_support::encode_path(&recipient_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/templates/{}/recipients/{}/bulk_recipients",
+                self.client.encode_path(account_id),
+                self.client.encode_path(template_id),
+                self.client.encode_path(recipient_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}/bulk_recipients", &[("accountId", account_id.to_string()), ("templateId", template_id.to_string()), ("recipientId", recipient_id.to_string())]))
     }
 
     /**
@@ -78,14 +85,14 @@ impl TemplateBulkRecipients {
     ) -> Result<crate::types::BulkRecipientsSummaryResponse> {
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/recipients/{}/bulk_recipients",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-            crate::progenitor_support::encode_path(&recipient_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(recipient_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("PUT", "/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}/bulk_recipients", &[("accountId", account_id.to_string()), ("templateId", template_id.to_string()), ("recipientId", recipient_id.to_string())]))
     }
 
     /**
@@ -109,11 +116,93 @@ impl TemplateBulkRecipients {
     ) -> Result<crate::types::BulkRecipientsUpdateResponse> {
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/recipients/{}/bulk_recipients",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-            crate::progenitor_support::encode_path(&recipient_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(recipient_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}/bulk_recipients", &[("accountId", account_id.to_string()), ("templateId", template_id.to_string()), ("recipientId", recipient_id.to_string())]))
+    }
+}
+
+/// The functions of [`TemplateBulkRecipients`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn TemplateBulkRecipientsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait TemplateBulkRecipientsApi: Send + Sync {
+    async fn recipients_get_template_bulk(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        include_tabs: &str,
+        start_position: &str,
+    ) -> Result<crate::types::BulkRecipientsResponse>;
+    async fn recipients_put_template_bulk(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        body: &crate::types::BulkRecipientsRequest,
+    ) -> Result<crate::types::BulkRecipientsSummaryResponse>;
+    async fn recipients_delete_template_bulk_file(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::BulkRecipientsUpdateResponse>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl TemplateBulkRecipientsApi for TemplateBulkRecipients {
+    async fn recipients_get_template_bulk(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        include_tabs: &str,
+        start_position: &str,
+    ) -> Result<crate::types::BulkRecipientsResponse> {
+        TemplateBulkRecipients::recipients_get_template_bulk(
+            self,
+            account_id,
+            recipient_id,
+            template_id,
+            include_tabs,
+            start_position,
+        )
+        .await
+    }
+    async fn recipients_put_template_bulk(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        body: &crate::types::BulkRecipientsRequest,
+    ) -> Result<crate::types::BulkRecipientsSummaryResponse> {
+        TemplateBulkRecipients::recipients_put_template_bulk(
+            self,
+            account_id,
+            recipient_id,
+            template_id,
+            body,
+        )
+        .await
+    }
+    async fn recipients_delete_template_bulk_file(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::BulkRecipientsUpdateResponse> {
+        TemplateBulkRecipients::recipients_delete_template_bulk_file(
+            self,
+            account_id,
+            recipient_id,
+            template_id,
+        )
+        .await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct TemplateCustomFields {
     pub client: Client,
@@ -12,6 +10,14 @@ impl TemplateCustomFields {
         TemplateCustomFields { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        TemplateCustomFields {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets the custom document fields from a template.
      *
@@ -31,11 +37,20 @@ impl TemplateCustomFields {
     ) -> Result<crate::types::CustomFields> {
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/custom_fields",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/templates/{templateId}/custom_fields",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("templateId", template_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -60,13 +75,23 @@ impl TemplateCustomFields {
     ) -> Result<crate::types::CustomFields> {
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/custom_fields",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/custom_fields",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -89,13 +114,23 @@ impl TemplateCustomFields {
     ) -> Result<crate::types::CustomFields> {
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/custom_fields",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/custom_fields",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -118,12 +153,90 @@ impl TemplateCustomFields {
     ) -> Result<crate::types::CustomFields> {
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/custom_fields",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
         );
 
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/custom_fields",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`TemplateCustomFields`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn TemplateCustomFieldsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait TemplateCustomFieldsApi: Send + Sync {
+    async fn custom_fields_get_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::CustomFields>;
+    async fn custom_fields_put_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::CustomFieldsEnvelope,
+    ) -> Result<crate::types::CustomFields>;
+    async fn custom_fields_post_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::CustomFieldsEnvelope,
+    ) -> Result<crate::types::CustomFields>;
+    async fn custom_fields_delete_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::CustomFieldsEnvelope,
+    ) -> Result<crate::types::CustomFields>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl TemplateCustomFieldsApi for TemplateCustomFields {
+    async fn custom_fields_get_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::CustomFields> {
+        TemplateCustomFields::custom_fields_get_template(self, account_id, template_id).await
+    }
+    async fn custom_fields_put_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::CustomFieldsEnvelope,
+    ) -> Result<crate::types::CustomFields> {
+        TemplateCustomFields::custom_fields_put_template(self, account_id, template_id, body).await
+    }
+    async fn custom_fields_post_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::CustomFieldsEnvelope,
+    ) -> Result<crate::types::CustomFields> {
+        TemplateCustomFields::custom_fields_post_template(self, account_id, template_id, body).await
+    }
+    async fn custom_fields_delete_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::CustomFieldsEnvelope,
+    ) -> Result<crate::types::CustomFields> {
+        TemplateCustomFields::custom_fields_delete_template(self, account_id, template_id, body)
             .await
     }
 }
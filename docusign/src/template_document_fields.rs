@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct TemplateDocumentFields {
     pub client: Client,
@@ -12,6 +10,14 @@ impl TemplateDocumentFields {
         TemplateDocumentFields { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        TemplateDocumentFields {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets the custom document fields for a an existing template document.
      *
@@ -33,12 +39,22 @@ impl TemplateDocumentFields {
     ) -> Result<crate::types::DocumentFieldsInformation> {
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/documents/{}/fields",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-            crate::progenitor_support::encode_path(&document_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(document_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/fields",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("templateId", template_id.to_string()),
+                    ("documentId", document_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -63,14 +79,14 @@ impl TemplateDocumentFields {
     ) -> Result<crate::types::DocumentFieldsInformation> {
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/documents/{}/fields",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-            crate::progenitor_support::encode_path(&document_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(document_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("PUT", "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/fields", &[("accountId", account_id.to_string()), ("templateId", template_id.to_string()), ("documentId", document_id.to_string())]))
     }
 
     /**
@@ -95,14 +111,14 @@ impl TemplateDocumentFields {
     ) -> Result<crate::types::DocumentFieldsInformation> {
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/documents/{}/fields",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-            crate::progenitor_support::encode_path(&document_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(document_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/fields", &[("accountId", account_id.to_string()), ("templateId", template_id.to_string()), ("documentId", document_id.to_string())]))
     }
 
     /**
@@ -127,13 +143,115 @@ impl TemplateDocumentFields {
     ) -> Result<crate::types::DocumentFieldsInformation> {
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/documents/{}/fields",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-            crate::progenitor_support::encode_path(&document_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(document_id),
         );
 
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("DELETE", "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/fields", &[("accountId", account_id.to_string()), ("templateId", template_id.to_string()), ("documentId", document_id.to_string())]))
+    }
+}
+
+/// The functions of [`TemplateDocumentFields`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn TemplateDocumentFieldsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait TemplateDocumentFieldsApi: Send + Sync {
+    async fn document_fields_get_template(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::DocumentFieldsInformation>;
+    async fn document_fields_put_template(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::DocumentFieldsInformation,
+    ) -> Result<crate::types::DocumentFieldsInformation>;
+    async fn document_fields_post_template(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::DocumentFieldsInformation,
+    ) -> Result<crate::types::DocumentFieldsInformation>;
+    async fn document_fields_delete_template(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::DocumentFieldsInformation,
+    ) -> Result<crate::types::DocumentFieldsInformation>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl TemplateDocumentFieldsApi for TemplateDocumentFields {
+    async fn document_fields_get_template(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::DocumentFieldsInformation> {
+        TemplateDocumentFields::document_fields_get_template(
+            self,
+            account_id,
+            document_id,
+            template_id,
+        )
+        .await
+    }
+    async fn document_fields_put_template(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::DocumentFieldsInformation,
+    ) -> Result<crate::types::DocumentFieldsInformation> {
+        TemplateDocumentFields::document_fields_put_template(
+            self,
+            account_id,
+            document_id,
+            template_id,
+            body,
+        )
+        .await
+    }
+    async fn document_fields_post_template(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::DocumentFieldsInformation,
+    ) -> Result<crate::types::DocumentFieldsInformation> {
+        TemplateDocumentFields::document_fields_post_template(
+            self,
+            account_id,
+            document_id,
+            template_id,
+            body,
+        )
+        .await
+    }
+    async fn document_fields_delete_template(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::DocumentFieldsInformation,
+    ) -> Result<crate::types::DocumentFieldsInformation> {
+        TemplateDocumentFields::document_fields_delete_template(
+            self,
+            account_id,
+            document_id,
+            template_id,
+            body,
+        )
+        .await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct TemplateDocumentHtmlDefinitions {
     pub client: Client,
@@ -12,6 +10,14 @@ impl TemplateDocumentHtmlDefinitions {
         TemplateDocumentHtmlDefinitions { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        TemplateDocumentHtmlDefinitions {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets the Original HTML Definition used to generate the Responsive HTML for a given document in a template.
      *
@@ -33,11 +39,44 @@ impl TemplateDocumentHtmlDefinitions {
     ) -> Result<crate::types::EnvelopeHtmlDefinitions> {
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/documents/{}/html_definitions",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-            crate::progenitor_support::encode_path(&document_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(document_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/html_definitions", &[("accountId", account_id.to_string()), ("templateId", template_id.to_string()), ("documentId", document_id.to_string())]))
+    }
+}
+
+/// The functions of [`TemplateDocumentHtmlDefinitions`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn TemplateDocumentHtmlDefinitionsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait TemplateDocumentHtmlDefinitionsApi: Send + Sync {
+    async fn responsive_html_get_template_document_definition(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::EnvelopeHtmlDefinitions>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl TemplateDocumentHtmlDefinitionsApi for TemplateDocumentHtmlDefinitions {
+    async fn responsive_html_get_template_document_definition(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::EnvelopeHtmlDefinitions> {
+        TemplateDocumentHtmlDefinitions::responsive_html_get_template_document_definition(
+            self,
+            account_id,
+            document_id,
+            template_id,
+        )
+        .await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct TemplateDocumentResponsiveHtmlPreview {
     pub client: Client,
@@ -12,6 +10,14 @@ impl TemplateDocumentResponsiveHtmlPreview {
         TemplateDocumentResponsiveHtmlPreview { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        TemplateDocumentResponsiveHtmlPreview {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Creates a preview of the responsive version of a template document.
      *
@@ -44,13 +50,49 @@ impl TemplateDocumentResponsiveHtmlPreview {
     ) -> Result<crate::types::DocumentHtmlDefinitions> {
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/documents/{}/responsive_html_preview",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-            crate::progenitor_support::encode_path(&document_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(document_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/responsive_html_preview", &[("accountId", account_id.to_string()), ("templateId", template_id.to_string()), ("documentId", document_id.to_string())]))
+    }
+}
+
+/// The functions of [`TemplateDocumentResponsiveHtmlPreview`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn TemplateDocumentResponsiveHtmlPreviewApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait TemplateDocumentResponsiveHtmlPreviewApi: Send + Sync {
+    async fn responsive_html_post_template_document_preview(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::DocumentHtmlDefinition,
+    ) -> Result<crate::types::DocumentHtmlDefinitions>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl TemplateDocumentResponsiveHtmlPreviewApi for TemplateDocumentResponsiveHtmlPreview {
+    async fn responsive_html_post_template_document_preview(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::DocumentHtmlDefinition,
+    ) -> Result<crate::types::DocumentHtmlDefinitions> {
+        TemplateDocumentResponsiveHtmlPreview::responsive_html_post_template_document_preview(
+            self,
+            account_id,
+            document_id,
+            template_id,
+            body,
+        )
+        .await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct TemplateDocumentTabs {
     pub client: Client,
@@ -12,6 +10,14 @@ impl TemplateDocumentTabs {
         TemplateDocumentTabs { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        TemplateDocumentTabs {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Returns tabs on the specified page.
      *
@@ -35,13 +41,13 @@ impl TemplateDocumentTabs {
     ) -> Result<crate::types::TemplateTabs> {
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/documents/{}/pages/{}/tabs",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-            crate::progenitor_support::encode_path(&document_id.to_string()),
-            crate::progenitor_support::encode_path(&page_number.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(document_id),
+            self.client.encode_path(page_number),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/pages/{pageNumber}/tabs", &[("accountId", account_id.to_string()), ("templateId", template_id.to_string()), ("documentId", document_id.to_string()), ("pageNumber", page_number.to_string())]))
     }
 
     /**
@@ -67,20 +73,31 @@ impl TemplateDocumentTabs {
         template_id: &str,
         page_numbers: &str,
     ) -> Result<crate::types::TemplateTabs> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !page_numbers.is_empty() {
-            query_args.push(("page_numbers".to_string(), page_numbers.to_string()));
+            query_args.push(("page_numbers", page_numbers.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/templates/{}/documents/{}/tabs?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-            crate::progenitor_support::encode_path(&document_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/templates/{}/documents/{}/tabs",
+                self.client.encode_path(account_id),
+                self.client.encode_path(template_id),
+                self.client.encode_path(document_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/tabs",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("templateId", template_id.to_string()),
+                    ("documentId", document_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -105,14 +122,25 @@ impl TemplateDocumentTabs {
     ) -> Result<crate::types::Tabs> {
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/documents/{}/tabs",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-            crate::progenitor_support::encode_path(&document_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(document_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/tabs",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                        ("documentId", document_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -137,14 +165,25 @@ impl TemplateDocumentTabs {
     ) -> Result<crate::types::Tabs> {
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/documents/{}/tabs",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-            crate::progenitor_support::encode_path(&document_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(document_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/tabs",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                        ("documentId", document_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -169,13 +208,152 @@ impl TemplateDocumentTabs {
     ) -> Result<crate::types::Tabs> {
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/documents/{}/tabs",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-            crate::progenitor_support::encode_path(&document_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(document_id),
         );
 
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .delete(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/tabs",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                        ("documentId", document_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`TemplateDocumentTabs`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn TemplateDocumentTabsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait TemplateDocumentTabsApi: Send + Sync {
+    async fn tabs_get_template_page(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+    ) -> Result<crate::types::TemplateTabs>;
+    async fn tabs_get_template_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        page_numbers: &str,
+    ) -> Result<crate::types::TemplateTabs>;
+    async fn tabs_put_template_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateTabs,
+    ) -> Result<crate::types::Tabs>;
+    async fn tabs_post_template_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateTabs,
+    ) -> Result<crate::types::Tabs>;
+    async fn tabs_delete_template_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateTabs,
+    ) -> Result<crate::types::Tabs>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl TemplateDocumentTabsApi for TemplateDocumentTabs {
+    async fn tabs_get_template_page(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+    ) -> Result<crate::types::TemplateTabs> {
+        TemplateDocumentTabs::tabs_get_template_page(
+            self,
+            account_id,
+            document_id,
+            page_number,
+            template_id,
+        )
+        .await
+    }
+    async fn tabs_get_template_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        page_numbers: &str,
+    ) -> Result<crate::types::TemplateTabs> {
+        TemplateDocumentTabs::tabs_get_template_document(
+            self,
+            account_id,
+            document_id,
+            template_id,
+            page_numbers,
+        )
+        .await
+    }
+    async fn tabs_put_template_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateTabs,
+    ) -> Result<crate::types::Tabs> {
+        TemplateDocumentTabs::tabs_put_template_document(
+            self,
+            account_id,
+            document_id,
+            template_id,
+            body,
+        )
+        .await
+    }
+    async fn tabs_post_template_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateTabs,
+    ) -> Result<crate::types::Tabs> {
+        TemplateDocumentTabs::tabs_post_template_document(
+            self,
+            account_id,
+            document_id,
+            template_id,
+            body,
+        )
+        .await
+    }
+    async fn tabs_delete_template_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateTabs,
+    ) -> Result<crate::types::Tabs> {
+        TemplateDocumentTabs::tabs_delete_template_document(
+            self,
+            account_id,
+            document_id,
+            template_id,
+            body,
+        )
+        .await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct TemplateDocumentVisibility {
     pub client: Client,
@@ -12,6 +10,14 @@ impl TemplateDocumentVisibility {
         TemplateDocumentVisibility { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        TemplateDocumentVisibility {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Updates document visibility for template recipients.
      *
@@ -35,13 +41,13 @@ impl TemplateDocumentVisibility {
     ) -> Result<crate::types::TemplateDocumentVisibilityList> {
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/recipients/document_visibility",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("PUT", "/v2.1/accounts/{accountId}/templates/{templateId}/recipients/document_visibility", &[("accountId", account_id.to_string()), ("templateId", template_id.to_string())]))
     }
 
     /**
@@ -65,12 +71,12 @@ impl TemplateDocumentVisibility {
     ) -> Result<crate::types::DocumentVisibilityList> {
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/recipients/{}/document_visibility",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-            crate::progenitor_support::encode_path(&recipient_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(recipient_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}/document_visibility", &[("accountId", account_id.to_string()), ("templateId", template_id.to_string()), ("recipientId", recipient_id.to_string())]))
     }
 
     /**
@@ -97,13 +103,83 @@ impl TemplateDocumentVisibility {
     ) -> Result<crate::types::TemplateDocumentVisibilityList> {
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/recipients/{}/document_visibility",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-            crate::progenitor_support::encode_path(&recipient_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(recipient_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("PUT", "/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}/document_visibility", &[("accountId", account_id.to_string()), ("templateId", template_id.to_string()), ("recipientId", recipient_id.to_string())]))
+    }
+}
+
+/// The functions of [`TemplateDocumentVisibility`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn TemplateDocumentVisibilityApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait TemplateDocumentVisibilityApi: Send + Sync {
+    async fn recipients_put(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateDocumentVisibilityList,
+    ) -> Result<crate::types::TemplateDocumentVisibilityList>;
+    async fn recipients_get_template_recipient_document_visibility(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::DocumentVisibilityList>;
+    async fn recipients_put_template_recipient_document_visibility(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateDocumentVisibilityList,
+    ) -> Result<crate::types::TemplateDocumentVisibilityList>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl TemplateDocumentVisibilityApi for TemplateDocumentVisibility {
+    async fn recipients_put(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateDocumentVisibilityList,
+    ) -> Result<crate::types::TemplateDocumentVisibilityList> {
+        TemplateDocumentVisibility::recipients_put(self, account_id, template_id, body).await
+    }
+    async fn recipients_get_template_recipient_document_visibility(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::DocumentVisibilityList> {
+        TemplateDocumentVisibility::recipients_get_template_recipient_document_visibility(
+            self,
+            account_id,
+            recipient_id,
+            template_id,
+        )
+        .await
+    }
+    async fn recipients_put_template_recipient_document_visibility(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateDocumentVisibilityList,
+    ) -> Result<crate::types::TemplateDocumentVisibilityList> {
+        TemplateDocumentVisibility::recipients_put_template_recipient_document_visibility(
+            self,
+            account_id,
+            recipient_id,
+            template_id,
+            body,
+        )
+        .await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct TemplateDocuments {
     pub client: Client,
@@ -12,6 +10,14 @@ impl TemplateDocuments {
         TemplateDocuments { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        TemplateDocuments {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets a list of documents associated with a template.
      *
@@ -31,19 +37,29 @@ impl TemplateDocuments {
         template_id: &str,
         include_tabs: &str,
     ) -> Result<crate::types::TemplateDocumentsResult> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_tabs.is_empty() {
-            query_args.push(("include_tabs".to_string(), include_tabs.to_string()));
+            query_args.push(("include_tabs", include_tabs.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/templates/{}/documents?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/templates/{}/documents",
+                self.client.encode_path(account_id),
+                self.client.encode_path(template_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/templates/{templateId}/documents",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("templateId", template_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -66,13 +82,23 @@ impl TemplateDocuments {
     ) -> Result<crate::types::TemplateDocumentsResult> {
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/documents",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/documents",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -109,13 +135,23 @@ impl TemplateDocuments {
     ) -> Result<crate::types::TemplateDocumentsResult> {
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/documents",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
         );
 
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .delete(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/documents",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -143,23 +179,86 @@ impl TemplateDocuments {
         encrypt: &str,
         show_changes: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !encrypt.is_empty() {
-            query_args.push(("encrypt".to_string(), encrypt.to_string()));
+            query_args.push(("encrypt", encrypt.to_string()));
         }
         if !show_changes.is_empty() {
-            query_args.push(("show_changes".to_string(), show_changes.to_string()));
+            query_args.push(("show_changes", show_changes.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/templates/{}/documents/{}?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-            crate::progenitor_support::encode_path(&document_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/templates/{}/documents/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(template_id),
+                self.client.encode_path(document_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client
+            .request_with_accept_mime(reqwest::Method::GET, &url, "application/pdf")
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                        ("documentId", document_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * Gets PDF documents from a template.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}` endpoint.
+     *
+     * As opposed to `documents_get`, this function asks for the PDF and streams its bytes as they arrive, rather than returning them all at once.
+     * This is useful for large documents that would otherwise need to be held in memory.
+     */
+    pub async fn documents_get_stream(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        encrypt: &str,
+        show_changes: &str,
+    ) -> Result<impl futures::Stream<Item = Result<bytes::Bytes>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !encrypt.is_empty() {
+            query_args.push(("encrypt", encrypt.to_string()));
+        }
+        if !show_changes.is_empty() {
+            query_args.push(("show_changes", show_changes.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/templates/{}/documents/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(template_id),
+                self.client.encode_path(document_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .get_stream_bytes(&url, "application/pdf")
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                        ("documentId", document_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -184,24 +283,140 @@ impl TemplateDocuments {
         is_envelope_definition: &str,
         body: &crate::types::EnvelopeDefinition,
     ) -> Result<crate::types::EnvelopeDocument> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !is_envelope_definition.is_empty() {
-            query_args.push((
-                "is_envelope_definition".to_string(),
-                is_envelope_definition.to_string(),
-            ));
+            query_args.push(("is_envelope_definition", is_envelope_definition.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/templates/{}/documents/{}?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-            crate::progenitor_support::encode_path(&document_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/templates/{}/documents/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(template_id),
+                self.client.encode_path(document_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                        ("documentId", document_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`TemplateDocuments`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn TemplateDocumentsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait TemplateDocumentsApi: Send + Sync {
+    async fn documents_get_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        include_tabs: &str,
+    ) -> Result<crate::types::TemplateDocumentsResult>;
+    async fn documents_put_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> Result<crate::types::TemplateDocumentsResult>;
+    async fn documents_delete_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> Result<crate::types::TemplateDocumentsResult>;
+    async fn documents_get(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        encrypt: &str,
+        show_changes: &str,
+    ) -> Result<()>;
+    async fn documents_put(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        is_envelope_definition: &str,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> Result<crate::types::EnvelopeDocument>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl TemplateDocumentsApi for TemplateDocuments {
+    async fn documents_get_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        include_tabs: &str,
+    ) -> Result<crate::types::TemplateDocumentsResult> {
+        TemplateDocuments::documents_get_template(self, account_id, template_id, include_tabs).await
+    }
+    async fn documents_put_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> Result<crate::types::TemplateDocumentsResult> {
+        TemplateDocuments::documents_put_template(self, account_id, template_id, body).await
+    }
+    async fn documents_delete_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> Result<crate::types::TemplateDocumentsResult> {
+        TemplateDocuments::documents_delete_template(self, account_id, template_id, body).await
+    }
+    async fn documents_get(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        encrypt: &str,
+        show_changes: &str,
+    ) -> Result<()> {
+        TemplateDocuments::documents_get(
+            self,
+            account_id,
+            document_id,
+            template_id,
+            encrypt,
+            show_changes,
+        )
+        .await
+    }
+    async fn documents_put(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        is_envelope_definition: &str,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> Result<crate::types::EnvelopeDocument> {
+        TemplateDocuments::documents_put(
+            self,
+            account_id,
+            document_id,
+            template_id,
+            is_envelope_definition,
+            body,
+        )
+        .await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct TemplateLocks {
     pub client: Client,
@@ -12,6 +10,14 @@ impl TemplateLocks {
         TemplateLocks { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        TemplateLocks {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets template lock information.
      *
@@ -44,11 +50,20 @@ impl TemplateLocks {
     ) -> Result<crate::types::LockInformation> {
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/lock",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/templates/{templateId}/lock",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("templateId", template_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -93,13 +108,23 @@ impl TemplateLocks {
     ) -> Result<crate::types::LockInformation> {
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/lock",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/lock",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -178,13 +203,23 @@ impl TemplateLocks {
     ) -> Result<crate::types::LockInformation> {
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/lock",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/lock",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -224,12 +259,89 @@ impl TemplateLocks {
     ) -> Result<crate::types::LockInformation> {
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/lock",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
         );
 
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .delete(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/lock",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`TemplateLocks`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn TemplateLocksApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait TemplateLocksApi: Send + Sync {
+    async fn lock_get_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::LockInformation>;
+    async fn lock_put_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::LockRequest,
+    ) -> Result<crate::types::LockInformation>;
+    async fn lock_post_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::LockRequest,
+    ) -> Result<crate::types::LockInformation>;
+    async fn lock_delete_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::LockRequest,
+    ) -> Result<crate::types::LockInformation>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl TemplateLocksApi for TemplateLocks {
+    async fn lock_get_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::LockInformation> {
+        TemplateLocks::lock_get_template(self, account_id, template_id).await
+    }
+    async fn lock_put_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::LockRequest,
+    ) -> Result<crate::types::LockInformation> {
+        TemplateLocks::lock_put_template(self, account_id, template_id, body).await
+    }
+    async fn lock_post_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::LockRequest,
+    ) -> Result<crate::types::LockInformation> {
+        TemplateLocks::lock_post_template(self, account_id, template_id, body).await
+    }
+    async fn lock_delete_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::LockRequest,
+    ) -> Result<crate::types::LockInformation> {
+        TemplateLocks::lock_delete_template(self, account_id, template_id, body).await
     }
 }
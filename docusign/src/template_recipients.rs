@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct TemplateRecipients {
     pub client: Client,
@@ -12,6 +10,14 @@ impl TemplateRecipients {
         TemplateRecipients { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        TemplateRecipients {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets recipient information from a template.
      *
@@ -35,28 +41,38 @@ impl TemplateRecipients {
         include_extended: &str,
         include_tabs: &str,
     ) -> Result<crate::types::Recipients> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_anchor_tab_locations.is_empty() {
             query_args.push((
-                "include_anchor_tab_locations".to_string(),
+                "include_anchor_tab_locations",
                 include_anchor_tab_locations.to_string(),
             ));
         }
         if !include_extended.is_empty() {
-            query_args.push(("include_extended".to_string(), include_extended.to_string()));
+            query_args.push(("include_extended", include_extended.to_string()));
         }
         if !include_tabs.is_empty() {
-            query_args.push(("include_tabs".to_string(), include_tabs.to_string()));
+            query_args.push(("include_tabs", include_tabs.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/templates/{}/recipients?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/templates/{}/recipients",
+                self.client.encode_path(account_id),
+                self.client.encode_path(template_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/templates/{templateId}/recipients",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("templateId", template_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -83,21 +99,32 @@ impl TemplateRecipients {
         resend_envelope: &str,
         body: &crate::types::TemplateRecipientsData,
     ) -> Result<crate::types::RecipientsUpdateSummary> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !resend_envelope.is_empty() {
-            query_args.push(("resend_envelope".to_string(), resend_envelope.to_string()));
+            query_args.push(("resend_envelope", resend_envelope.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/templates/{}/recipients?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/templates/{}/recipients",
+                self.client.encode_path(account_id),
+                self.client.encode_path(template_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/recipients",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -122,21 +149,32 @@ impl TemplateRecipients {
         resend_envelope: &str,
         body: &crate::types::TemplateRecipientsData,
     ) -> Result<crate::types::Recipients> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !resend_envelope.is_empty() {
-            query_args.push(("resend_envelope".to_string(), resend_envelope.to_string()));
+            query_args.push(("resend_envelope", resend_envelope.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/templates/{}/recipients?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/templates/{}/recipients",
+                self.client.encode_path(account_id),
+                self.client.encode_path(template_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/recipients",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -159,13 +197,23 @@ impl TemplateRecipients {
     ) -> Result<crate::types::Recipients> {
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/recipients",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
         );
 
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .delete(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/recipients",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -190,14 +238,25 @@ impl TemplateRecipients {
     ) -> Result<crate::types::Recipients> {
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/recipients/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-            crate::progenitor_support::encode_path(&recipient_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(recipient_id),
         );
 
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .delete(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/recipients/{recipientId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                        ("recipientId", recipient_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -222,12 +281,152 @@ impl TemplateRecipients {
     ) -> Result<crate::types::ViewUrl> {
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/views/recipient_preview",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/views/recipient_preview",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`TemplateRecipients`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn TemplateRecipientsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait TemplateRecipientsApi: Send + Sync {
+    async fn recipients_get_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        include_anchor_tab_locations: &str,
+        include_extended: &str,
+        include_tabs: &str,
+    ) -> Result<crate::types::Recipients>;
+    async fn recipients_put_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        resend_envelope: &str,
+        body: &crate::types::TemplateRecipientsData,
+    ) -> Result<crate::types::RecipientsUpdateSummary>;
+    async fn recipients_post_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        resend_envelope: &str,
+        body: &crate::types::TemplateRecipientsData,
+    ) -> Result<crate::types::Recipients>;
+    async fn recipients_delete_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateRecipientsData,
+    ) -> Result<crate::types::Recipients>;
+    async fn recipients_delete(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateRecipientsData,
+    ) -> Result<crate::types::Recipients>;
+    async fn views_post_preview(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::RecipientPreviewRequest,
+    ) -> Result<crate::types::ViewUrl>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl TemplateRecipientsApi for TemplateRecipients {
+    async fn recipients_get_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        include_anchor_tab_locations: &str,
+        include_extended: &str,
+        include_tabs: &str,
+    ) -> Result<crate::types::Recipients> {
+        TemplateRecipients::recipients_get_template(
+            self,
+            account_id,
+            template_id,
+            include_anchor_tab_locations,
+            include_extended,
+            include_tabs,
+        )
+        .await
+    }
+    async fn recipients_put_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        resend_envelope: &str,
+        body: &crate::types::TemplateRecipientsData,
+    ) -> Result<crate::types::RecipientsUpdateSummary> {
+        TemplateRecipients::recipients_put_template(
+            self,
+            account_id,
+            template_id,
+            resend_envelope,
+            body,
+        )
+        .await
+    }
+    async fn recipients_post_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        resend_envelope: &str,
+        body: &crate::types::TemplateRecipientsData,
+    ) -> Result<crate::types::Recipients> {
+        TemplateRecipients::recipients_post_template(
+            self,
+            account_id,
+            template_id,
+            resend_envelope,
+            body,
+        )
+        .await
+    }
+    async fn recipients_delete_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateRecipientsData,
+    ) -> Result<crate::types::Recipients> {
+        TemplateRecipients::recipients_delete_template(self, account_id, template_id, body).await
+    }
+    async fn recipients_delete(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateRecipientsData,
+    ) -> Result<crate::types::Recipients> {
+        TemplateRecipients::recipients_delete(self, account_id, recipient_id, template_id, body)
             .await
     }
+    async fn views_post_preview(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::RecipientPreviewRequest,
+    ) -> Result<crate::types::ViewUrl> {
+        TemplateRecipients::views_post_preview(self, account_id, template_id, body).await
+    }
 }
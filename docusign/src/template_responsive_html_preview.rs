@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct TemplateResponsiveHtmlPreview {
     pub client: Client,
@@ -12,6 +10,14 @@ impl TemplateResponsiveHtmlPreview {
         TemplateResponsiveHtmlPreview { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        TemplateResponsiveHtmlPreview {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Creates a preview of the responsive versions of all of the documents associated with a template.
      *
@@ -42,12 +48,55 @@ impl TemplateResponsiveHtmlPreview {
     ) -> Result<crate::types::DocumentHtmlDefinitions> {
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/responsive_html_preview",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/responsive_html_preview",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`TemplateResponsiveHtmlPreview`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn TemplateResponsiveHtmlPreviewApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait TemplateResponsiveHtmlPreviewApi: Send + Sync {
+    async fn responsive_html_post_template_preview(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::DocumentHtmlDefinition,
+    ) -> Result<crate::types::DocumentHtmlDefinitions>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl TemplateResponsiveHtmlPreviewApi for TemplateResponsiveHtmlPreview {
+    async fn responsive_html_post_template_preview(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::DocumentHtmlDefinition,
+    ) -> Result<crate::types::DocumentHtmlDefinitions> {
+        TemplateResponsiveHtmlPreview::responsive_html_post_template_preview(
+            self,
+            account_id,
+            template_id,
+            body,
+        )
+        .await
     }
 }
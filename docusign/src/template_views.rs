@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct TemplateViews {
     pub client: Client,
@@ -12,6 +10,14 @@ impl TemplateViews {
         TemplateViews { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        TemplateViews {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets a URL for a template edit view.
      *
@@ -32,12 +38,49 @@ impl TemplateViews {
     ) -> Result<crate::types::ViewUrl> {
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/views/edit",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/views/edit",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`TemplateViews`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn TemplateViewsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait TemplateViewsApi: Send + Sync {
+    async fn views_post_template_edit_view(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::ReturnUrlRequest,
+    ) -> Result<crate::types::ViewUrl>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl TemplateViewsApi for TemplateViews {
+    async fn views_post_template_edit_view(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::ReturnUrlRequest,
+    ) -> Result<crate::types::ViewUrl> {
+        TemplateViews::views_post_template_edit_view(self, account_id, template_id, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Templates {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Templates {
         Templates { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Templates {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Gets template definitions.
      *
@@ -118,93 +124,94 @@ impl Templates {
         user_filter: &str,
         user_id: &str,
     ) -> Result<crate::types::EnvelopeTemplateResults> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !created_from_date.is_empty() {
-            query_args.push((
-                "created_from_date".to_string(),
-                created_from_date.to_string(),
-            ));
+            query_args.push(("created_from_date", created_from_date.to_string()));
         }
         if !created_to_date.is_empty() {
-            query_args.push(("created_to_date".to_string(), created_to_date.to_string()));
+            query_args.push(("created_to_date", created_to_date.to_string()));
         }
         if !folder_ids.is_empty() {
-            query_args.push(("folder_ids".to_string(), folder_ids.to_string()));
+            query_args.push(("folder_ids", folder_ids.to_string()));
         }
         if !folder_types.is_empty() {
-            query_args.push(("folder_types".to_string(), folder_types.to_string()));
+            query_args.push(("folder_types", folder_types.to_string()));
         }
         if !from_date.is_empty() {
-            query_args.push(("from_date".to_string(), from_date.to_string()));
+            query_args.push(("from_date", from_date.to_string()));
         }
         if !include.is_empty() {
-            query_args.push(("include".to_string(), include.to_string()));
+            query_args.push(("include", include.to_string()));
         }
         if !is_deleted_template_only.is_empty() {
             query_args.push((
-                "is_deleted_template_only".to_string(),
+                "is_deleted_template_only",
                 is_deleted_template_only.to_string(),
             ));
         }
         if !is_download.is_empty() {
-            query_args.push(("is_download".to_string(), is_download.to_string()));
+            query_args.push(("is_download", is_download.to_string()));
         }
         if !modified_from_date.is_empty() {
-            query_args.push((
-                "modified_from_date".to_string(),
-                modified_from_date.to_string(),
-            ));
+            query_args.push(("modified_from_date", modified_from_date.to_string()));
         }
         if !modified_to_date.is_empty() {
-            query_args.push(("modified_to_date".to_string(), modified_to_date.to_string()));
+            query_args.push(("modified_to_date", modified_to_date.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !order_by.is_empty() {
-            query_args.push(("order_by".to_string(), order_by.to_string()));
+            query_args.push(("order_by", order_by.to_string()));
         }
         if !search_fields.is_empty() {
-            query_args.push(("search_fields".to_string(), search_fields.to_string()));
+            query_args.push(("search_fields", search_fields.to_string()));
         }
         if !search_text.is_empty() {
-            query_args.push(("search_text".to_string(), search_text.to_string()));
+            query_args.push(("search_text", search_text.to_string()));
         }
         if !shared_by_me.is_empty() {
-            query_args.push(("shared_by_me".to_string(), shared_by_me.to_string()));
+            query_args.push(("shared_by_me", shared_by_me.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
         if !template_ids.is_empty() {
-            query_args.push(("template_ids".to_string(), template_ids.to_string()));
+            query_args.push(("template_ids", template_ids.to_string()));
         }
         if !to_date.is_empty() {
-            query_args.push(("to_date".to_string(), to_date.to_string()));
+            query_args.push(("to_date", to_date.to_string()));
         }
         if !used_from_date.is_empty() {
-            query_args.push(("used_from_date".to_string(), used_from_date.to_string()));
+            query_args.push(("used_from_date", used_from_date.to_string()));
         }
         if !used_to_date.is_empty() {
-            query_args.push(("used_to_date".to_string(), used_to_date.to_string()));
+            query_args.push(("used_to_date", used_to_date.to_string()));
         }
         if !user_filter.is_empty() {
-            query_args.push(("user_filter".to_string(), user_filter.to_string()));
+            query_args.push(("user_filter", user_filter.to_string()));
         }
         if !user_id.is_empty() {
-            query_args.push(("user_id".to_string(), user_id.to_string()));
+            query_args.push(("user_id", user_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/templates?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/templates",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/templates",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -275,12 +282,19 @@ impl Templates {
     ) -> Result<crate::types::TemplateSummary> {
         let url = format!(
             "/v2.1/accounts/{}/templates",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/templates",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -310,19 +324,29 @@ impl Templates {
         template_id: &str,
         include: &str,
     ) -> Result<crate::types::EnvelopeTemplate> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include.is_empty() {
-            query_args.push(("include".to_string(), include.to_string()));
+            query_args.push(("include", include.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/templates/{}?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/templates/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(template_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/templates/{templateId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("templateId", template_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -345,13 +369,23 @@ impl Templates {
     ) -> Result<crate::types::EnvelopeUpdateSummary> {
         let url = format!(
             "/v2.1/accounts/{}/templates/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -387,38 +421,49 @@ impl Templates {
         show_changes: &str,
         start_position: &str,
     ) -> Result<crate::types::PageImages> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !dpi.is_empty() {
-            query_args.push(("dpi".to_string(), dpi.to_string()));
+            query_args.push(("dpi", dpi.to_string()));
         }
         if !max_height.is_empty() {
-            query_args.push(("max_height".to_string(), max_height.to_string()));
+            query_args.push(("max_height", max_height.to_string()));
         }
         if !max_width.is_empty() {
-            query_args.push(("max_width".to_string(), max_width.to_string()));
+            query_args.push(("max_width", max_width.to_string()));
         }
         if !nocache.is_empty() {
-            query_args.push(("nocache".to_string(), nocache.to_string()));
+            query_args.push(("nocache", nocache.to_string()));
         }
         if !show_changes.is_empty() {
-            query_args.push(("show_changes".to_string(), show_changes.to_string()));
+            query_args.push(("show_changes", show_changes.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/templates/{}/documents/{}/pages?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-            crate::progenitor_support::encode_path(&document_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/templates/{}/documents/{}/pages",
+                self.client.encode_path(account_id),
+                self.client.encode_path(template_id),
+                self.client.encode_path(document_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/pages",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("templateId", template_id.to_string()),
+                    ("documentId", document_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -445,15 +490,43 @@ impl Templates {
     ) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/documents/{}/pages/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-            crate::progenitor_support::encode_path(&document_id.to_string()),
-            crate::progenitor_support::encode_path(&page_number.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(document_id),
+            self.client.encode_path(page_number),
         );
 
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("DELETE", "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/pages/{pageNumber}", &[("accountId", account_id.to_string()), ("templateId", template_id.to_string()), ("documentId", document_id.to_string()), ("pageNumber", page_number.to_string())]))
+    }
+
+    /**
+     * Deletes a page from a document in an template.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/pages/{pageNumber}` endpoint.
+     *
+     * As opposed to `pages_delete_page`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn pages_delete_page_if_exists(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+        body: &crate::types::PageRequest,
+    ) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/documents/{}/pages/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(document_id),
+            self.client.encode_path(page_number),
+        );
+
+        self.client
+            .delete_if_exists(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("DELETE", "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/pages/{pageNumber}", &[("accountId", account_id.to_string()), ("templateId", template_id.to_string()), ("documentId", document_id.to_string()), ("pageNumber", page_number.to_string())]))
     }
 
     /**
@@ -485,30 +558,33 @@ impl Templates {
         max_width: &str,
         show_changes: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !dpi.is_empty() {
-            query_args.push(("dpi".to_string(), dpi.to_string()));
+            query_args.push(("dpi", dpi.to_string()));
         }
         if !max_height.is_empty() {
-            query_args.push(("max_height".to_string(), max_height.to_string()));
+            query_args.push(("max_height", max_height.to_string()));
         }
         if !max_width.is_empty() {
-            query_args.push(("max_width".to_string(), max_width.to_string()));
+            query_args.push(("max_width", max_width.to_string()));
         }
         if !show_changes.is_empty() {
-            query_args.push(("show_changes".to_string(), show_changes.to_string()));
+            query_args.push(("show_changes", show_changes.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/templates/{}/documents/{}/pages/{}/page_image?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-            crate::progenitor_support::encode_path(&document_id.to_string()),
-            crate::progenitor_support::encode_path(&page_number.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/templates/{}/documents/{}/pages/{}/page_image",
+                self.client.encode_path(account_id),
+                self.client.encode_path(template_id),
+                self.client.encode_path(document_id),
+                self.client.encode_path(page_number)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client
+            .request_with_accept_mime(reqwest::Method::GET, &url, "image/png")
+            .await.with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/pages/{pageNumber}/page_image", &[("accountId", account_id.to_string()), ("templateId", template_id.to_string()), ("documentId", document_id.to_string()), ("pageNumber", page_number.to_string())]))
     }
 
     /**
@@ -535,15 +611,15 @@ impl Templates {
     ) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/documents/{}/pages/{}/page_image",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-            crate::progenitor_support::encode_path(&document_id.to_string()),
-            crate::progenitor_support::encode_path(&page_number.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(document_id),
+            self.client.encode_path(page_number),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("PUT", "/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/pages/{pageNumber}/page_image", &[("accountId", account_id.to_string()), ("templateId", template_id.to_string()), ("documentId", document_id.to_string()), ("pageNumber", page_number.to_string())]))
     }
 
     /**
@@ -565,11 +641,20 @@ impl Templates {
     ) -> Result<crate::types::Notification> {
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/notification",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/templates/{templateId}/notification",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("templateId", template_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -592,13 +677,23 @@ impl Templates {
     ) -> Result<crate::types::Notification> {
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/notification",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/notification",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -625,14 +720,25 @@ impl Templates {
     ) -> Result<crate::types::GroupInformation> {
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-            crate::progenitor_support::encode_path(&template_part.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(template_part),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/{templatePart}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                        ("templatePart", template_part.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -657,13 +763,372 @@ impl Templates {
     ) -> Result<crate::types::GroupInformation> {
         let url = format!(
             "/v2.1/accounts/{}/templates/{}/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-            crate::progenitor_support::encode_path(&template_part.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(template_id),
+            self.client.encode_path(template_part),
         );
 
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .delete(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/templates/{templateId}/{templatePart}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("templateId", template_id.to_string()),
+                        ("templatePart", template_part.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`Templates`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn TemplatesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait TemplatesApi: Send + Sync {
+    async fn get(
+        &self,
+        account_id: &str,
+        count: &str,
+        created_from_date: &str,
+        created_to_date: &str,
+        folder_ids: &str,
+        folder_types: &str,
+        from_date: &str,
+        include: &str,
+        is_deleted_template_only: &str,
+        is_download: &str,
+        modified_from_date: &str,
+        modified_to_date: &str,
+        order: &str,
+        order_by: &str,
+        search_fields: &str,
+        search_text: &str,
+        shared_by_me: &str,
+        start_position: &str,
+        template_ids: &str,
+        to_date: &str,
+        used_from_date: &str,
+        used_to_date: &str,
+        user_filter: &str,
+        user_id: &str,
+    ) -> Result<crate::types::EnvelopeTemplateResults>;
+    async fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::EnvelopeTemplate,
+    ) -> Result<crate::types::TemplateSummary>;
+    async fn get_templates(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        include: &str,
+    ) -> Result<crate::types::EnvelopeTemplate>;
+    async fn put(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::EnvelopeTemplate,
+    ) -> Result<crate::types::EnvelopeUpdateSummary>;
+    async fn pages_get_page_image(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        count: &str,
+        dpi: &str,
+        max_height: &str,
+        max_width: &str,
+        nocache: &str,
+        show_changes: &str,
+        start_position: &str,
+    ) -> Result<crate::types::PageImages>;
+    async fn pages_delete_page(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+        body: &crate::types::PageRequest,
+    ) -> Result<()>;
+    async fn pages_delete_page_if_exists(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+        body: &crate::types::PageRequest,
+    ) -> Result<bool>;
+    async fn pages_get_page_image_templates(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+        dpi: &str,
+        max_height: &str,
+        max_width: &str,
+        show_changes: &str,
+    ) -> Result<()>;
+    async fn pages_put_page_image(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+        body: &crate::types::PageRequest,
+    ) -> Result<()>;
+    async fn notification_get(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::Notification>;
+    async fn notification_put(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateNotificationRequest,
+    ) -> Result<crate::types::Notification>;
+    async fn put_part(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        template_part: &str,
+        body: &crate::types::GroupInformation,
+    ) -> Result<crate::types::GroupInformation>;
+    async fn delete_part(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        template_part: &str,
+        body: &crate::types::GroupInformation,
+    ) -> Result<crate::types::GroupInformation>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl TemplatesApi for Templates {
+    async fn get(
+        &self,
+        account_id: &str,
+        count: &str,
+        created_from_date: &str,
+        created_to_date: &str,
+        folder_ids: &str,
+        folder_types: &str,
+        from_date: &str,
+        include: &str,
+        is_deleted_template_only: &str,
+        is_download: &str,
+        modified_from_date: &str,
+        modified_to_date: &str,
+        order: &str,
+        order_by: &str,
+        search_fields: &str,
+        search_text: &str,
+        shared_by_me: &str,
+        start_position: &str,
+        template_ids: &str,
+        to_date: &str,
+        used_from_date: &str,
+        used_to_date: &str,
+        user_filter: &str,
+        user_id: &str,
+    ) -> Result<crate::types::EnvelopeTemplateResults> {
+        Templates::get(
+            self,
+            account_id,
+            count,
+            created_from_date,
+            created_to_date,
+            folder_ids,
+            folder_types,
+            from_date,
+            include,
+            is_deleted_template_only,
+            is_download,
+            modified_from_date,
+            modified_to_date,
+            order,
+            order_by,
+            search_fields,
+            search_text,
+            shared_by_me,
+            start_position,
+            template_ids,
+            to_date,
+            used_from_date,
+            used_to_date,
+            user_filter,
+            user_id,
+        )
+        .await
+    }
+    async fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::EnvelopeTemplate,
+    ) -> Result<crate::types::TemplateSummary> {
+        Templates::post(self, account_id, body).await
+    }
+    async fn get_templates(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        include: &str,
+    ) -> Result<crate::types::EnvelopeTemplate> {
+        Templates::get_templates(self, account_id, template_id, include).await
+    }
+    async fn put(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::EnvelopeTemplate,
+    ) -> Result<crate::types::EnvelopeUpdateSummary> {
+        Templates::put(self, account_id, template_id, body).await
+    }
+    async fn pages_get_page_image(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        count: &str,
+        dpi: &str,
+        max_height: &str,
+        max_width: &str,
+        nocache: &str,
+        show_changes: &str,
+        start_position: &str,
+    ) -> Result<crate::types::PageImages> {
+        Templates::pages_get_page_image(
+            self,
+            account_id,
+            document_id,
+            template_id,
+            count,
+            dpi,
+            max_height,
+            max_width,
+            nocache,
+            show_changes,
+            start_position,
+        )
+        .await
+    }
+    async fn pages_delete_page(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+        body: &crate::types::PageRequest,
+    ) -> Result<()> {
+        Templates::pages_delete_page(
+            self,
+            account_id,
+            document_id,
+            page_number,
+            template_id,
+            body,
+        )
+        .await
+    }
+    async fn pages_delete_page_if_exists(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+        body: &crate::types::PageRequest,
+    ) -> Result<bool> {
+        Templates::pages_delete_page_if_exists(
+            self,
+            account_id,
+            document_id,
+            page_number,
+            template_id,
+            body,
+        )
+        .await
+    }
+    async fn pages_get_page_image_templates(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+        dpi: &str,
+        max_height: &str,
+        max_width: &str,
+        show_changes: &str,
+    ) -> Result<()> {
+        Templates::pages_get_page_image_templates(
+            self,
+            account_id,
+            document_id,
+            page_number,
+            template_id,
+            dpi,
+            max_height,
+            max_width,
+            show_changes,
+        )
+        .await
+    }
+    async fn pages_put_page_image(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+        body: &crate::types::PageRequest,
+    ) -> Result<()> {
+        Templates::pages_put_page_image(
+            self,
+            account_id,
+            document_id,
+            page_number,
+            template_id,
+            body,
+        )
+        .await
+    }
+    async fn notification_get(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::Notification> {
+        Templates::notification_get(self, account_id, template_id).await
+    }
+    async fn notification_put(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateNotificationRequest,
+    ) -> Result<crate::types::Notification> {
+        Templates::notification_put(self, account_id, template_id, body).await
+    }
+    async fn put_part(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        template_part: &str,
+        body: &crate::types::GroupInformation,
+    ) -> Result<crate::types::GroupInformation> {
+        Templates::put_part(self, account_id, template_id, template_part, body).await
+    }
+    async fn delete_part(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        template_part: &str,
+        body: &crate::types::GroupInformation,
+    ) -> Result<crate::types::GroupInformation> {
+        Templates::delete_part(self, account_id, template_id, template_part, body).await
     }
 }
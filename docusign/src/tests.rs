@@ -1 +1,708 @@
+#[tokio::test]
+async fn test_brand_logo_put_bytes() {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
 
+    let logo: Vec<u8> = vec![0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a];
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let host = format!("http://{}", listener.local_addr().unwrap());
+    let server = tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+
+        // Read the request until we have the headers and the whole body.
+        let mut request = Vec::new();
+        let mut buf = [0; 1024];
+        let body = loop {
+            let n = socket.read(&mut buf).await.unwrap();
+            request.extend_from_slice(&buf[..n]);
+
+            if let Some(i) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+                let headers = String::from_utf8_lossy(&request[..i]).to_lowercase();
+                let content_length = headers
+                    .lines()
+                    .find_map(|l| l.strip_prefix("content-length:"))
+                    .map(|v| v.trim().parse::<usize>().unwrap())
+                    .unwrap_or(0);
+                if request.len() >= i + 4 + content_length {
+                    break (headers, request[i + 4..].to_vec());
+                }
+            }
+            if n == 0 {
+                panic!("connection closed before the whole request was read");
+            }
+        };
+
+        socket
+            .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\nconnection: close\r\n\r\n")
+            .await
+            .unwrap();
+        body
+    });
+
+    let docusign = crate::Client::new("", "", "", "token", "").with_host(host);
+
+    // A `Vec<u8>` can be passed as is, as can a `&[u8]` or `Bytes`.
+    docusign
+        .account_brands()
+        .brand_logo_put("account", "brand", "primary", logo.clone())
+        .await
+        .unwrap();
+
+    let (headers, body) = server.await.unwrap();
+    assert!(headers.starts_with("put /v2.1/accounts/account/brands/brand/logos/primary "));
+    assert_eq!(body, logo);
+}
+
+#[tokio::test]
+async fn test_brand_logo_put_from_reader_over_body_limit() {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    // Bigger than the limit, and than one chunk.
+    let logo: Vec<u8> = (0..100_000).map(|i| (i % 251) as u8).collect();
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let host = format!("http://{}", listener.local_addr().unwrap());
+    let server = tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+
+        // Read the request until the last, empty, chunk.
+        let mut request = Vec::new();
+        let mut buf = [0; 1024];
+        while !request.ends_with(b"\r\n0\r\n\r\n") {
+            let n = socket.read(&mut buf).await.unwrap();
+            if n == 0 {
+                panic!("connection closed before the whole request was read");
+            }
+            request.extend_from_slice(&buf[..n]);
+        }
+
+        socket
+            .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\nconnection: close\r\n\r\n")
+            .await
+            .unwrap();
+
+        // Put the chunks back together.
+        let i = request.windows(4).position(|w| w == b"\r\n\r\n").unwrap();
+        let headers = String::from_utf8_lossy(&request[..i]).to_lowercase();
+        let mut chunks = &request[i + 4..];
+        let mut body = Vec::new();
+        loop {
+            let line = chunks.windows(2).position(|w| w == b"\r\n").unwrap();
+            let size =
+                usize::from_str_radix(std::str::from_utf8(&chunks[..line]).unwrap(), 16).unwrap();
+            if size == 0 {
+                break;
+            }
+            body.extend_from_slice(&chunks[line + 2..line + 2 + size]);
+            chunks = &chunks[line + 2 + size + 2..];
+        }
+        (headers, body)
+    });
+
+    let docusign = crate::Client::new("", "", "", "token", "")
+        .with_host(host)
+        .with_request_body_limit(1024);
+
+    // Past the limit, the rest of the reader is streamed as it is read.
+    docusign
+        .account_brands()
+        .brand_logo_put_from_reader(
+            "account",
+            "brand",
+            "primary",
+            std::io::Cursor::new(logo.clone()),
+        )
+        .await
+        .unwrap();
+
+    let (headers, body) = server.await.unwrap();
+    assert!(
+        headers.contains("transfer-encoding: chunked"),
+        "{}",
+        headers
+    );
+    assert!(!headers.contains("content-length:"), "{}", headers);
+    assert_eq!(body, logo);
+}
+
+#[tokio::test]
+async fn test_apply_to_templates_bool() {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let host = format!("http://{}", listener.local_addr().unwrap());
+    let server = tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+
+        // There is no body, so we only need the headers.
+        let mut request = Vec::new();
+        let mut buf = [0; 1024];
+        while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+            let n = socket.read(&mut buf).await.unwrap();
+            if n == 0 {
+                panic!("connection closed before the whole request was read");
+            }
+            request.extend_from_slice(&buf[..n]);
+        }
+
+        socket
+            .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\nconnection: close\r\n\r\n")
+            .await
+            .unwrap();
+        String::from_utf8_lossy(&request).to_string()
+    });
+
+    let docusign = crate::Client::new("", "", "", "token", "").with_host(host);
+    let field = crate::custom_field_id::CustomFieldId::new("field");
+    docusign
+        .account_custom_fields()
+        .delete("account", &field, true)
+        .await
+        .unwrap();
+
+    let request = server.await.unwrap();
+    assert!(request
+        .starts_with("DELETE /v2.1/accounts/account/custom_fields/field?apply_to_templates=true "));
+}
+
+#[tokio::test]
+async fn test_custom_field_id() {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let host = format!("http://{}", listener.local_addr().unwrap());
+    let server = tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+
+        // There is no body, so we only need the headers.
+        let mut request = Vec::new();
+        let mut buf = [0; 1024];
+        while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+            let n = socket.read(&mut buf).await.unwrap();
+            if n == 0 {
+                panic!("connection closed before the whole request was read");
+            }
+            request.extend_from_slice(&buf[..n]);
+        }
+
+        socket
+            .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\nconnection: close\r\n\r\n")
+            .await
+            .unwrap();
+        String::from_utf8_lossy(&request).to_string()
+    });
+
+    // The id goes in its own segment, after the account id.
+    let field: crate::custom_field_id::CustomFieldId = String::from("1 2").into();
+    assert_eq!(field.as_str(), "1 2");
+    let docusign = crate::Client::new("", "", "", "token", "").with_host(host);
+    assert!(docusign
+        .account_custom_fields()
+        .delete_if_exists("account", &field, false)
+        .await
+        .unwrap());
+
+    let request = server.await.unwrap();
+    assert!(request.starts_with("DELETE /v2.1/accounts/account/custom_fields/1%202 "));
+}
+
+#[tokio::test]
+async fn test_documents_get_document_stream() {
+    use futures::StreamExt;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let host = format!("http://{}", listener.local_addr().unwrap());
+    let server = tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+
+        // There is no body, so we only need the headers.
+        let mut request = Vec::new();
+        let mut buf = [0; 1024];
+        while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+            let n = socket.read(&mut buf).await.unwrap();
+            if n == 0 {
+                panic!("connection closed before the whole request was read");
+            }
+            request.extend_from_slice(&buf[..n]);
+        }
+
+        // Send the PDF in a few chunks, flushing each on its own.
+        socket
+            .write_all(
+                b"HTTP/1.1 200 OK\r\ncontent-type: application/pdf\r\n\
+                  transfer-encoding: chunked\r\nconnection: close\r\n\r\n",
+            )
+            .await
+            .unwrap();
+        for chunk in [&b"%PDF-1.7\n"[..], b"1 0 obj\n", b"%%EOF\n"] {
+            socket
+                .write_all(format!("{:x}\r\n", chunk.len()).as_bytes())
+                .await
+                .unwrap();
+            socket.write_all(chunk).await.unwrap();
+            socket.write_all(b"\r\n").await.unwrap();
+            socket.flush().await.unwrap();
+        }
+        socket.write_all(b"0\r\n\r\n").await.unwrap();
+
+        String::from_utf8_lossy(&request).to_lowercase()
+    });
+
+    let docusign = crate::Client::new("", "", "", "token", "").with_host(host);
+    let mut stream = Box::pin(
+        docusign
+            .envelope_documents()
+            .documents_get_document_stream(
+                "account", "combined", "envelope", "", "", "", "", "", "", "", "", "",
+            )
+            .await
+            .unwrap(),
+    );
+
+    let mut pdf = Vec::new();
+    while let Some(chunk) = stream.next().await {
+        pdf.extend_from_slice(&chunk.unwrap());
+    }
+    assert_eq!(pdf, b"%PDF-1.7\n1 0 obj\n%%EOF\n");
+
+    let request = server.await.unwrap();
+    // None of the query parameters are set, so there is no query.
+    assert!(
+        request.starts_with("get /v2.1/accounts/account/envelopes/envelope/documents/combined ")
+    );
+    assert!(request.contains("\r\naccept: application/pdf\r\n"));
+}
+
+#[tokio::test]
+async fn test_accept_matches_response_type() {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let host = format!("http://{}", listener.local_addr().unwrap());
+    let server = tokio::spawn(async move {
+        let mut requests = Vec::new();
+        for (content_type, body) in [
+            ("text/plain", "POST /v2.1/accounts\n"),
+            ("application/json", r#"{"apiRequestLogging":"true"}"#),
+        ] {
+            let (mut socket, _) = listener.accept().await.unwrap();
+
+            // There is no body, so we only need the headers.
+            let mut request = Vec::new();
+            let mut buf = [0; 1024];
+            while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                let n = socket.read(&mut buf).await.unwrap();
+                if n == 0 {
+                    panic!("connection closed before the whole request was read");
+                }
+                request.extend_from_slice(&buf[..n]);
+            }
+
+            socket
+                .write_all(
+                    format!(
+                        "HTTP/1.1 200 OK\r\ncontent-type: {}\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                        content_type,
+                        body.len(),
+                        body
+                    )
+                    .as_bytes(),
+                )
+                .await
+                .unwrap();
+            requests.push(String::from_utf8_lossy(&request).to_lowercase());
+        }
+        requests
+    });
+
+    let docusign = crate::Client::new("", "", "", "token", "").with_host(host);
+    let log = docusign.request_logs().api_get("log").await.unwrap();
+    assert_eq!(&log[..], b"POST /v2.1/accounts\n");
+    let settings = docusign.request_logs().api_get_setting().await.unwrap();
+    assert_eq!(settings.api_request_logging, "true");
+
+    let requests = server.await.unwrap();
+    assert!(requests[0].starts_with("get /v2.1/diagnostics/request_logs/log "));
+    assert!(requests[0].contains("\r\naccept: text/plain\r\n"));
+    assert!(requests[1].starts_with("get /v2.1/diagnostics/settings "));
+    assert!(requests[1].contains("\r\naccept: application/json\r\n"));
+}
+
+#[test]
+fn test_rename_all_camel_case() {
+    // Every field of `AccountBrands` is its property in camel case, so the
+    // struct renames them all at once.
+    let json = serde_json::json!({
+        "recipientBrandIdDefault": "recipient",
+        "senderBrandIdDefault": "sender",
+    });
+    let brands: crate::types::AccountBrands = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(brands.recipient_brand_id_default, "recipient");
+    assert_eq!(brands.sender_brand_id_default, "sender");
+    assert_eq!(serde_json::to_value(&brands).unwrap(), json);
+
+    // `proofBaseURI` is not, so `ProofServiceResourceToken` renames its fields
+    // one by one.
+    let json = serde_json::json!({
+        "proofBaseURI": "https://proof.example.com",
+        "resourceToken": "token",
+    });
+    let token: crate::types::ProofServiceResourceToken =
+        serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(token.proof_base_uri, "https://proof.example.com");
+    assert_eq!(token.resource_token, "token");
+    assert_eq!(serde_json::to_value(&token).unwrap(), json);
+}
+
+#[test]
+fn test_decimal_amounts() {
+    use std::str::FromStr;
+
+    // As the API sends it, a number too big for a float goes as it is.
+    let json = r#"{
+        "amount": "19.99",
+        "balance": "",
+        "nonTaxableAmount": 12345678901234567.89,
+        "taxableAmount": 0.1
+    }"#;
+    let invoice: crate::types::BillingInvoice = serde_json::from_str(json).unwrap();
+    assert_eq!(invoice.amount, Some(rust_decimal::Decimal::new(1999, 2)));
+    assert_eq!(invoice.balance, None);
+    assert_eq!(
+        invoice.non_taxable_amount,
+        Some(rust_decimal::Decimal::from_str("12345678901234567.89").unwrap())
+    );
+    assert_eq!(
+        invoice.taxable_amount,
+        Some(rust_decimal::Decimal::new(1, 1))
+    );
+
+    // The amounts go back as strings, the way DocuSign sends them.
+    let out = serde_json::to_value(&invoice).unwrap();
+    assert_eq!(out["amount"], "19.99");
+    assert_eq!(out["nonTaxableAmount"], "12345678901234567.89");
+    assert_eq!(out["taxableAmount"], "0.1");
+    assert!(out.get("balance").is_none());
+}
+
+// What the generator's `--serde-tests` flag emits for a `CustomField` example,
+// so we know the tests it generates compile and pass.
+#[cfg(test)]
+mod serde_tests {
+    /// Deserialize the example into `T` and serialize it again, checking every
+    /// field the example sets comes back with the same name and value.
+    fn round_trip<T>(example: serde_json::Value)
+    where
+        T: serde::de::DeserializeOwned + serde::Serialize,
+    {
+        let value: T = serde_json::from_value(example.clone()).unwrap();
+        let out = serde_json::to_value(&value).unwrap();
+        assert_contains(&out, &example, "");
+    }
+
+    fn assert_contains(out: &serde_json::Value, example: &serde_json::Value, path: &str) {
+        match example {
+            serde_json::Value::Object(fields) => {
+                for (k, v) in fields {
+                    let path = format!("{}.{}", path, k);
+                    match out.get(k) {
+                        Some(o) => assert_contains(o, v, &path),
+                        // Empty fields are skipped when we serialize.
+                        None => assert!(is_empty(v), "{} is missing", path),
+                    }
+                }
+            }
+            serde_json::Value::Array(items) => {
+                let out = out.as_array().unwrap();
+                assert_eq!(out.len(), items.len(), "{} has the wrong length", path);
+                for (i, (o, v)) in out.iter().zip(items).enumerate() {
+                    assert_contains(o, v, &format!("{}[{}]", path, i));
+                }
+            }
+            v => assert_eq!(out, v, "{} has the wrong value", path),
+        }
+    }
+
+    fn is_empty(v: &serde_json::Value) -> bool {
+        match v {
+            serde_json::Value::Null => true,
+            serde_json::Value::Bool(b) => !b,
+            serde_json::Value::Number(n) => n.as_f64() == Some(0.0),
+            serde_json::Value::String(s) => s.is_empty(),
+            serde_json::Value::Array(a) => a.is_empty(),
+            serde_json::Value::Object(o) => o.values().all(is_empty),
+        }
+    }
+
+    #[test]
+    fn custom_field_round_trip() {
+        round_trip::<crate::types::CustomField>(
+            serde_json::json!({"customFieldType":"list","fieldId":"8","listItems":["East","West"],"name":"Region","required":"true","show":"true","value":"East"}),
+        );
+    }
+}
+
+#[test]
+fn test_multipart_response() {
+    let body = b"preamble\r\n--b0undary\r\nContent-Type: application/json\r\n\r\n{\"documentId\":\"1\",\"name\":\"contract.pdf\"}\r\n--b0undary\r\nContent-Type: application/pdf\r\nContent-Disposition: file; filename=\"contract.pdf\"\r\n\r\n%PDF-1.4\r\n\x00\xff\r\n--b0undary--\r\n";
+    let multipart =
+        crate::Multipart::parse("multipart/mixed; boundary=b0undary", &body[..]).unwrap();
+
+    let parts: Vec<&crate::MultipartPart> = multipart.iter().collect();
+    assert_eq!(parts.len(), 2);
+
+    assert_eq!(parts[0].content_type(), Some("application/json"));
+    let metadata: serde_json::Value = parts[0].json().unwrap();
+    assert_eq!(metadata["name"], "contract.pdf");
+
+    assert_eq!(parts[1].content_type(), Some("application/pdf"));
+    assert_eq!(
+        parts[1].headers["content-disposition"],
+        "file; filename=\"contract.pdf\""
+    );
+    // The body is kept as it was sent, line breaks and all.
+    assert_eq!(&parts[1].body[..], &b"%PDF-1.4\r\n\x00\xff"[..]);
+
+    // Without a boundary there is nothing to split the body at.
+    assert!(crate::Multipart::parse("multipart/mixed", &body[..]).is_err());
+}
+
+#[tokio::test]
+async fn test_post_custom_field() {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let host = format!("http://{}", listener.local_addr().unwrap());
+    let server = tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+
+        // We only need the headers, the response does not depend on the body.
+        let mut request = Vec::new();
+        let mut buf = [0; 1024];
+        while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+            let n = socket.read(&mut buf).await.unwrap();
+            if n == 0 {
+                panic!("connection closed before the whole request was read");
+            }
+            request.extend_from_slice(&buf[..n]);
+        }
+
+        // The API returns all of the account's fields, the new one among them.
+        let body = r#"{"listCustomFields":[{"fieldId":"1","name":"Office","listItems":["North"]}],"textCustomFields":[{"fieldId":"2","name":"Region","value":"East"}]}"#;
+        socket
+            .write_all(
+                format!(
+                    "HTTP/1.1 201 Created\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                )
+                .as_bytes(),
+            )
+            .await
+            .unwrap();
+        String::from_utf8_lossy(&request).to_string()
+    });
+
+    let docusign = crate::Client::new("", "", "", "token", "").with_host(host);
+    let field = docusign
+        .account_custom_fields()
+        .post_field(
+            "account",
+            false,
+            &crate::types::CustomField {
+                custom_field_type: "text".to_string(),
+                error_details: None,
+                field_id: String::new(),
+                list_items: Vec::new(),
+                name: "Region".to_string(),
+                required: "false".to_string(),
+                show: "true".to_string(),
+                value: "East".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+
+    let request = server.await.unwrap();
+    assert!(request.starts_with("POST /v2.1/accounts/account/custom_fields "));
+    assert_eq!(field.field_id, "2");
+    assert_eq!(field.name, "Region");
+    assert_eq!(field.custom_field_type, "text");
+    assert_eq!(field.value, "East");
+}
+
+#[test]
+fn test_encode_path_cache() {
+    let docusign = crate::Client::new("", "", "", "token", "").with_path_cache(2);
+    let ids = ["account id", "a/b", "100%", "account id", "a/b", "other"];
+    // Each is encoded as it would be without the cache, whether it was cached
+    // or, with the least recently used dropped, encoded again.
+    for _ in 0..2 {
+        for id in ids.iter() {
+            assert_eq!(
+                &*docusign.encode_path(id),
+                crate::progenitor_support::encode_path(id)
+            );
+        }
+    }
+    assert_eq!(&*docusign.encode_path("a/b"), "a%2Fb");
+
+    // Once it is cached, the encoding is shared rather than made again.
+    match (docusign.encode_path("other"), docusign.encode_path("other")) {
+        (crate::EncodedPath::Cached(a), crate::EncodedPath::Cached(b)) => {
+            assert!(std::sync::Arc::ptr_eq(&a, &b))
+        }
+        encoded => panic!("not cached: {:?}", encoded),
+    }
+
+    let uncached = docusign.with_path_cache(0);
+    let encoded = uncached.encode_path("100%");
+    assert!(matches!(encoded, crate::EncodedPath::Fresh(_)));
+    assert_eq!(&*encoded, "100%25");
+}
+
+#[test]
+fn test_blocking_client() {
+    use std::io::{Read, Write};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let host = format!("http://{}", listener.local_addr().unwrap());
+    let server = std::thread::spawn(move || {
+        let (mut socket, _) = listener.accept().unwrap();
+
+        let mut request = Vec::new();
+        let mut buf = [0; 1024];
+        while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+            let n = socket.read(&mut buf).unwrap();
+            if n == 0 {
+                panic!("connection closed before the whole request was read");
+            }
+            request.extend_from_slice(&buf[..n]);
+        }
+
+        let body = r#"{"textCustomFields":[{"fieldId":"2","name":"Region","value":"East"}]}"#;
+        socket
+            .write_all(
+                format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                )
+                .as_bytes(),
+            )
+            .unwrap();
+        String::from_utf8_lossy(&request).to_string()
+    });
+
+    // No async runtime here, the blocking client sends the request itself.
+    let docusign = crate::blocking::Client::new("token").with_host(host);
+    let fields = docusign.account_custom_fields().get("account").unwrap();
+
+    let request = server.join().unwrap();
+    assert!(request.starts_with("GET /v2.1/accounts/account/custom_fields HTTP/1.1\r\n"));
+    assert!(request.contains("authorization: Bearer token\r\n"));
+    let field = fields.field_named("Region").unwrap();
+    assert_eq!(field.field_id, "2");
+    assert_eq!(field.value, "East");
+}
+
+/// The headers and the body of each request a mock server read.
+type MockRequests = Vec<(String, Vec<u8>)>;
+
+/// Serve `responses` to the requests of a blocking client in turn, and return
+/// the requests as they were read.
+fn blocking_mock_server(responses: Vec<String>) -> (String, std::thread::JoinHandle<MockRequests>) {
+    use std::io::{Read, Write};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let host = format!("http://{}", listener.local_addr().unwrap());
+    let server = std::thread::spawn(move || {
+        let mut requests = Vec::new();
+        for response in responses {
+            let (mut socket, _) = listener.accept().unwrap();
+
+            // Read the request until we have the headers and the whole body.
+            let mut request = Vec::new();
+            let mut buf = [0; 1024];
+            let (headers, body) = loop {
+                let n = socket.read(&mut buf).unwrap();
+                request.extend_from_slice(&buf[..n]);
+
+                if let Some(i) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+                    let headers = String::from_utf8_lossy(&request[..i]).to_lowercase();
+                    let content_length = headers
+                        .lines()
+                        .find_map(|l| l.strip_prefix("content-length:"))
+                        .map(|v| v.trim().parse::<usize>().unwrap())
+                        .unwrap_or(0);
+                    if request.len() >= i + 4 + content_length {
+                        break (headers, request[i + 4..].to_vec());
+                    }
+                }
+                if n == 0 {
+                    panic!("connection closed before the whole request was read");
+                }
+            };
+
+            socket.write_all(response.as_bytes()).unwrap();
+            requests.push((headers, body));
+        }
+        requests
+    });
+
+    (host, server)
+}
+
+#[test]
+fn test_blocking_client_form_and_bytes() {
+    let resources = r#"{"resourcesContentUri":"/resources/email"}"#;
+    let (host, server) = blocking_mock_server(vec![
+        format!(
+            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+            resources.len(),
+            resources
+        ),
+        "HTTP/1.1 503 Service Unavailable\r\nretry-after: 0\r\ncontent-length: 0\r\nconnection: close\r\n\r\n"
+            .to_string(),
+        "HTTP/1.1 200 OK\r\ncontent-type: text/plain\r\ncontent-length: 5\r\nconnection: close\r\n\r\nlog\r\n"
+            .to_string(),
+    ]);
+
+    let docusign = crate::blocking::Client::new("token")
+        .with_host(host)
+        .with_retry(crate::RetryPolicy::new(1, std::time::Duration::ZERO));
+
+    // A form goes as the form, not as JSON.
+    let uploaded = docusign
+        .account_brands()
+        .brand_resources_put("account", "brand", "email", b"<xml/>")
+        .unwrap();
+    assert_eq!(uploaded.resources_content_uri, "/resources/email");
+
+    // Bytes come back as they are, once the retry got past the 503.
+    let log = docusign.request_logs().api_get("log").unwrap();
+    assert_eq!(&log[..], b"log\r\n");
+
+    let requests = server.join().unwrap();
+    assert_eq!(requests.len(), 3);
+
+    let (headers, body) = &requests[0];
+    assert!(
+        headers.starts_with("put /v2.1/accounts/account/brands/brand/resources/email http/1.1\r\n")
+    );
+    assert!(
+        headers.contains("content-type: multipart/form-data; boundary="),
+        "{}",
+        headers
+    );
+    let body = String::from_utf8_lossy(body);
+    assert!(body.contains("filename=\"file.xml\""), "{}", body);
+    assert!(body.contains("<xml/>"), "{}", body);
+
+    for (headers, _) in &requests[1..] {
+        assert!(headers.starts_with("get /v2.1/diagnostics/request_logs/log http/1.1\r\n"));
+        assert!(headers.contains("accept: text/plain\r\n"), "{}", headers);
+        assert!(!headers.contains("content-type:"), "{}", headers);
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct UserCustomSettings {
     pub client: Client,
@@ -12,6 +10,14 @@ impl UserCustomSettings {
         UserCustomSettings { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        UserCustomSettings {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieves the custom user settings for a specified user.
      *
@@ -46,11 +52,20 @@ impl UserCustomSettings {
     ) -> Result<crate::types::CustomSettingsInformation> {
         let url = format!(
             "/v2.1/accounts/{}/users/{}/custom_settings",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(user_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/users/{userId}/custom_settings",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("userId", user_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -94,13 +109,23 @@ impl UserCustomSettings {
     ) -> Result<crate::types::CustomSettingsInformation> {
         let url = format!(
             "/v2.1/accounts/{}/users/{}/custom_settings",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(user_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/users/{userId}/custom_settings",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("userId", user_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -134,12 +159,75 @@ impl UserCustomSettings {
     ) -> Result<crate::types::CustomSettingsInformation> {
         let url = format!(
             "/v2.1/accounts/{}/users/{}/custom_settings",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(user_id),
         );
 
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .delete(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/users/{userId}/custom_settings",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("userId", user_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`UserCustomSettings`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn UserCustomSettingsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait UserCustomSettingsApi: Send + Sync {
+    async fn get(
+        &self,
+        account_id: &str,
+        user_id: &str,
+    ) -> Result<crate::types::CustomSettingsInformation>;
+    async fn put(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::CustomSettingsInformation,
+    ) -> Result<crate::types::CustomSettingsInformation>;
+    async fn delete(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::CustomSettingsInformation,
+    ) -> Result<crate::types::CustomSettingsInformation>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl UserCustomSettingsApi for UserCustomSettings {
+    async fn get(
+        &self,
+        account_id: &str,
+        user_id: &str,
+    ) -> Result<crate::types::CustomSettingsInformation> {
+        UserCustomSettings::get(self, account_id, user_id).await
+    }
+    async fn put(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::CustomSettingsInformation,
+    ) -> Result<crate::types::CustomSettingsInformation> {
+        UserCustomSettings::put(self, account_id, user_id, body).await
+    }
+    async fn delete(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::CustomSettingsInformation,
+    ) -> Result<crate::types::CustomSettingsInformation> {
+        UserCustomSettings::delete(self, account_id, user_id, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct UserProfiles {
     pub client: Client,
@@ -12,6 +10,14 @@ impl UserProfiles {
         UserProfiles { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        UserProfiles {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieves the user profile for a specified user.
      *
@@ -30,11 +36,20 @@ impl UserProfiles {
     pub async fn get(&self, account_id: &str, user_id: &str) -> Result<crate::types::UserProfile> {
         let url = format!(
             "/v2.1/accounts/{}/users/{}/profile",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(user_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/users/{userId}/profile",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("userId", user_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -60,12 +75,53 @@ impl UserProfiles {
     ) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/users/{}/profile",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(user_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/users/{userId}/profile",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("userId", user_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`UserProfiles`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn UserProfilesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait UserProfilesApi: Send + Sync {
+    async fn get(&self, account_id: &str, user_id: &str) -> Result<crate::types::UserProfile>;
+    async fn put(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::UserProfile,
+    ) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl UserProfilesApi for UserProfiles {
+    async fn get(&self, account_id: &str, user_id: &str) -> Result<crate::types::UserProfile> {
+        UserProfiles::get(self, account_id, user_id).await
+    }
+    async fn put(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::UserProfile,
+    ) -> Result<()> {
+        UserProfiles::put(self, account_id, user_id, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct UserSignatures {
     pub client: Client,
@@ -12,6 +10,14 @@ impl UserSignatures {
         UserSignatures { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        UserSignatures {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieves a list of signature definitions for a user.
      *
@@ -42,19 +48,29 @@ impl UserSignatures {
         user_id: &str,
         stamp_type: &str,
     ) -> Result<crate::types::UserSignaturesInformation> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !stamp_type.is_empty() {
-            query_args.push(("stamp_type".to_string(), stamp_type.to_string()));
+            query_args.push(("stamp_type", stamp_type.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/users/{}/signatures?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/users/{}/signatures",
+                self.client.encode_path(account_id),
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/users/{userId}/signatures",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("userId", user_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -78,13 +94,23 @@ impl UserSignatures {
     ) -> Result<crate::types::UserSignaturesInformation> {
         let url = format!(
             "/v2.1/accounts/{}/users/{}/signatures",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(user_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/users/{userId}/signatures",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("userId", user_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -126,13 +152,23 @@ impl UserSignatures {
     ) -> Result<crate::types::UserSignaturesInformation> {
         let url = format!(
             "/v2.1/accounts/{}/users/{}/signatures",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(user_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/users/{userId}/signatures",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("userId", user_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -163,12 +199,22 @@ impl UserSignatures {
     ) -> Result<crate::types::UserSignature> {
         let url = format!(
             "/v2.1/accounts/{}/users/{}/signatures/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&signature_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(user_id),
+            self.client.encode_path(signature_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/users/{userId}/signatures/{signatureId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("userId", user_id.to_string()),
+                    ("signatureId", signature_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -202,25 +248,37 @@ impl UserSignatures {
         close_existing_signature: &str,
         body: &crate::types::UserSignatureDefinition,
     ) -> Result<crate::types::UserSignature> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !close_existing_signature.is_empty() {
             query_args.push((
-                "close_existing_signature".to_string(),
+                "close_existing_signature",
                 close_existing_signature.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/users/{}/signatures/{}?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&signature_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/users/{}/signatures/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(user_id),
+                self.client.encode_path(signature_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/users/{userId}/signatures/{signatureId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("userId", user_id.to_string()),
+                        ("signatureId", signature_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -251,12 +309,58 @@ impl UserSignatures {
     ) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/users/{}/signatures/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&signature_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(user_id),
+            self.client.encode_path(signature_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/users/{userId}/signatures/{signatureId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("userId", user_id.to_string()),
+                    ("signatureId", signature_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Removes removes signature information for the specified user.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/users/{userId}/signatures/{signatureId}` endpoint.
+     *
+     * As opposed to `delete_signature`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_signature_if_exists(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+        user_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/users/{}/signatures/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(user_id),
+            self.client.encode_path(signature_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/users/{userId}/signatures/{signatureId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("userId", user_id.to_string()),
+                        ("signatureId", signature_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -294,21 +398,24 @@ impl UserSignatures {
         user_id: &str,
         include_chrome: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_chrome.is_empty() {
-            query_args.push(("include_chrome".to_string(), include_chrome.to_string()));
+            query_args.push(("include_chrome", include_chrome.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/users/{}/signatures/{}/{}?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&signature_id.to_string()),
-            crate::progenitor_support::encode_path(&image_type.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/users/{}/signatures/{}/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(user_id),
+                self.client.encode_path(signature_id),
+                self.client.encode_path(image_type)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client
+            .request_with_accept_mime(reqwest::Method::GET, &url, "image/gif")
+            .await.with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/users/{userId}/signatures/{signatureId}/{imageType}", &[("accountId", account_id.to_string()), ("userId", user_id.to_string()), ("signatureId", signature_id.to_string()), ("imageType", image_type.to_string())]))
     }
 
     /**
@@ -345,21 +452,33 @@ impl UserSignatures {
         user_id: &str,
         transparent_png: &str,
     ) -> Result<crate::types::UserSignature> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !transparent_png.is_empty() {
-            query_args.push(("transparent_png".to_string(), transparent_png.to_string()));
+            query_args.push(("transparent_png", transparent_png.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/users/{}/signatures/{}/{}?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&signature_id.to_string()),
-            crate::progenitor_support::encode_path(&image_type.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/users/{}/signatures/{}/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(user_id),
+                self.client.encode_path(signature_id),
+                self.client.encode_path(image_type)
+            ),
+            &query_args,
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "PUT",
+                "/v2.1/accounts/{accountId}/users/{userId}/signatures/{signatureId}/{imageType}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("userId", user_id.to_string()),
+                    ("signatureId", signature_id.to_string()),
+                    ("imageType", image_type.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -397,12 +516,215 @@ impl UserSignatures {
     ) -> Result<crate::types::UserSignature> {
         let url = format!(
             "/v2.1/accounts/{}/users/{}/signatures/{}/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&signature_id.to_string()),
-            crate::progenitor_support::encode_path(&image_type.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(user_id),
+            self.client.encode_path(signature_id),
+            self.client.encode_path(image_type),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/users/{userId}/signatures/{signatureId}/{imageType}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("userId", user_id.to_string()),
+                    ("signatureId", signature_id.to_string()),
+                    ("imageType", image_type.to_string()),
+                ],
+            )
+        })
+    }
+}
+
+/// The functions of [`UserSignatures`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn UserSignaturesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait UserSignaturesApi: Send + Sync {
+    async fn get(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        stamp_type: &str,
+    ) -> Result<crate::types::UserSignaturesInformation>;
+    async fn put_signature(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::UserSignaturesInformation,
+    ) -> Result<crate::types::UserSignaturesInformation>;
+    async fn post(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::UserSignaturesInformation,
+    ) -> Result<crate::types::UserSignaturesInformation>;
+    async fn get_signature(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+        user_id: &str,
+    ) -> Result<crate::types::UserSignature>;
+    async fn put_signature_user_signatures(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+        user_id: &str,
+        close_existing_signature: &str,
+        body: &crate::types::UserSignatureDefinition,
+    ) -> Result<crate::types::UserSignature>;
+    async fn delete_signature(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+        user_id: &str,
+    ) -> Result<()>;
+    async fn delete_signature_if_exists(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+        user_id: &str,
+    ) -> Result<bool>;
+    async fn get_signature_image(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        user_id: &str,
+        include_chrome: &str,
+    ) -> Result<()>;
+    async fn put_signature_image(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        user_id: &str,
+        transparent_png: &str,
+    ) -> Result<crate::types::UserSignature>;
+    async fn delete_signature_image(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        user_id: &str,
+    ) -> Result<crate::types::UserSignature>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl UserSignaturesApi for UserSignatures {
+    async fn get(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        stamp_type: &str,
+    ) -> Result<crate::types::UserSignaturesInformation> {
+        UserSignatures::get(self, account_id, user_id, stamp_type).await
+    }
+    async fn put_signature(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::UserSignaturesInformation,
+    ) -> Result<crate::types::UserSignaturesInformation> {
+        UserSignatures::put_signature(self, account_id, user_id, body).await
+    }
+    async fn post(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::UserSignaturesInformation,
+    ) -> Result<crate::types::UserSignaturesInformation> {
+        UserSignatures::post(self, account_id, user_id, body).await
+    }
+    async fn get_signature(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+        user_id: &str,
+    ) -> Result<crate::types::UserSignature> {
+        UserSignatures::get_signature(self, account_id, signature_id, user_id).await
+    }
+    async fn put_signature_user_signatures(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+        user_id: &str,
+        close_existing_signature: &str,
+        body: &crate::types::UserSignatureDefinition,
+    ) -> Result<crate::types::UserSignature> {
+        UserSignatures::put_signature_user_signatures(
+            self,
+            account_id,
+            signature_id,
+            user_id,
+            close_existing_signature,
+            body,
+        )
+        .await
+    }
+    async fn delete_signature(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+        user_id: &str,
+    ) -> Result<()> {
+        UserSignatures::delete_signature(self, account_id, signature_id, user_id).await
+    }
+    async fn delete_signature_if_exists(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+        user_id: &str,
+    ) -> Result<bool> {
+        UserSignatures::delete_signature_if_exists(self, account_id, signature_id, user_id).await
+    }
+    async fn get_signature_image(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        user_id: &str,
+        include_chrome: &str,
+    ) -> Result<()> {
+        UserSignatures::get_signature_image(
+            self,
+            account_id,
+            image_type,
+            signature_id,
+            user_id,
+            include_chrome,
+        )
+        .await
+    }
+    async fn put_signature_image(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        user_id: &str,
+        transparent_png: &str,
+    ) -> Result<crate::types::UserSignature> {
+        UserSignatures::put_signature_image(
+            self,
+            account_id,
+            image_type,
+            signature_id,
+            user_id,
+            transparent_png,
+        )
+        .await
+    }
+    async fn delete_signature_image(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        user_id: &str,
+    ) -> Result<crate::types::UserSignature> {
+        UserSignatures::delete_signature_image(self, account_id, image_type, signature_id, user_id)
+            .await
     }
 }
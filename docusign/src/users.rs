@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Users {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Users {
         Users { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Users {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieves the list of users for the specified account.
      *
@@ -64,54 +70,58 @@ impl Users {
         status: &str,
         user_name_substring: &str,
     ) -> Result<crate::types::UserInformationList> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !additional_info.is_empty() {
-            query_args.push(("additional_info".to_string(), additional_info.to_string()));
+            query_args.push(("additional_info", additional_info.to_string()));
         }
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !email.is_empty() {
-            query_args.push(("email".to_string(), email.to_string()));
+            query_args.push(("email", email.to_string()));
         }
         if !email_substring.is_empty() {
-            query_args.push(("email_substring".to_string(), email_substring.to_string()));
+            query_args.push(("email_substring", email_substring.to_string()));
         }
         if !group_id.is_empty() {
-            query_args.push(("group_id".to_string(), group_id.to_string()));
+            query_args.push(("group_id", group_id.to_string()));
         }
         if !include_usersettings_for_csv.is_empty() {
             query_args.push((
-                "include_usersettings_for_csv".to_string(),
+                "include_usersettings_for_csv",
                 include_usersettings_for_csv.to_string(),
             ));
         }
         if !login_status.is_empty() {
-            query_args.push(("login_status".to_string(), login_status.to_string()));
+            query_args.push(("login_status", login_status.to_string()));
         }
         if !not_group_id.is_empty() {
-            query_args.push(("not_group_id".to_string(), not_group_id.to_string()));
+            query_args.push(("not_group_id", not_group_id.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !user_name_substring.is_empty() {
-            query_args.push((
-                "user_name_substring".to_string(),
-                user_name_substring.to_string(),
-            ));
+            query_args.push(("user_name_substring", user_name_substring.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/users?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/users",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/users",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -132,12 +142,19 @@ impl Users {
     ) -> Result<crate::types::UserInformationList> {
         let url = format!(
             "/v2.1/accounts/{}/users",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/users",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -216,12 +233,19 @@ impl Users {
     ) -> Result<crate::types::NewUsersSummary> {
         let url = format!(
             "/v2.1/accounts/{}/users",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/users",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -244,20 +268,28 @@ impl Users {
         delete: &str,
         body: &crate::types::UserInfoList,
     ) -> Result<crate::types::UsersResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !delete.is_empty() {
-            query_args.push(("delete".to_string(), delete.to_string()));
+            query_args.push(("delete", delete.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/users?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/users",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .delete(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/users",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -284,22 +316,32 @@ impl Users {
         additional_info: &str,
         email: &str,
     ) -> Result<crate::types::UserInformation> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !additional_info.is_empty() {
-            query_args.push(("additional_info".to_string(), additional_info.to_string()));
+            query_args.push(("additional_info", additional_info.to_string()));
         }
         if !email.is_empty() {
-            query_args.push(("email".to_string(), email.to_string()));
+            query_args.push(("email", email.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/users/{}?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/users/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/users/{userId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("userId", user_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -323,13 +365,23 @@ impl Users {
     ) -> Result<crate::types::UserInformation> {
         let url = format!(
             "/v2.1/accounts/{}/users/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(user_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/users/{userId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("userId", user_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -356,19 +408,32 @@ impl Users {
         user_id: &str,
         encoding: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !encoding.is_empty() {
-            query_args.push(("encoding".to_string(), encoding.to_string()));
+            query_args.push(("encoding", encoding.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/users/{}/profile/image?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/users/{}/profile/image",
+                self.client.encode_path(account_id),
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client
+            .request_with_accept_mime(reqwest::Method::GET, &url, "image/gif")
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/v2.1/accounts/{accountId}/users/{userId}/profile/image",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("userId", user_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -389,11 +454,20 @@ impl Users {
     pub async fn profile_image_put(&self, account_id: &str, user_id: &str) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/users/{}/profile/image",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(user_id),
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "PUT",
+                "/v2.1/accounts/{accountId}/users/{userId}/profile/image",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("userId", user_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -414,11 +488,53 @@ impl Users {
     pub async fn profile_image_delete(&self, account_id: &str, user_id: &str) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/users/{}/profile/image",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(user_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/users/{userId}/profile/image",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("userId", user_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Deletes the user profile image for the specified user.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/users/{userId}/profile/image` endpoint.
+     *
+     * As opposed to `profile_image_delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn profile_image_delete_if_exists(
+        &self,
+        account_id: &str,
+        user_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/users/{}/profile/image",
+            self.client.encode_path(account_id),
+            self.client.encode_path(user_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/users/{userId}/profile/image",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("userId", user_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -451,11 +567,20 @@ impl Users {
     ) -> Result<crate::types::UserSettingsInformation> {
         let url = format!(
             "/v2.1/accounts/{}/users/{}/settings",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(user_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/users/{userId}/settings",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("userId", user_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -479,12 +604,207 @@ impl Users {
     ) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/users/{}/settings",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(user_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/users/{userId}/settings",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("userId", user_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`Users`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn UsersApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait UsersApi: Send + Sync {
+    async fn get(
+        &self,
+        account_id: &str,
+        additional_info: &str,
+        count: &str,
+        email: &str,
+        email_substring: &str,
+        group_id: &str,
+        include_usersettings_for_csv: &str,
+        login_status: &str,
+        not_group_id: &str,
+        start_position: &str,
+        status: &str,
+        user_name_substring: &str,
+    ) -> Result<crate::types::UserInformationList>;
+    async fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::UserInformationList,
+    ) -> Result<crate::types::UserInformationList>;
+    async fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::NewUsersDefinition,
+    ) -> Result<crate::types::NewUsersSummary>;
+    async fn delete(
+        &self,
+        account_id: &str,
+        delete: &str,
+        body: &crate::types::UserInfoList,
+    ) -> Result<crate::types::UsersResponse>;
+    async fn get_users(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        additional_info: &str,
+        email: &str,
+    ) -> Result<crate::types::UserInformation>;
+    async fn put_users(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::UserInformation,
+    ) -> Result<crate::types::UserInformation>;
+    async fn profile_image_get(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        encoding: &str,
+    ) -> Result<()>;
+    async fn profile_image_put(&self, account_id: &str, user_id: &str) -> Result<()>;
+    async fn profile_image_delete(&self, account_id: &str, user_id: &str) -> Result<()>;
+    async fn profile_image_delete_if_exists(&self, account_id: &str, user_id: &str)
+        -> Result<bool>;
+    async fn settings_get(
+        &self,
+        account_id: &str,
+        user_id: &str,
+    ) -> Result<crate::types::UserSettingsInformation>;
+    async fn settings_put(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::UserSettingsInformation,
+    ) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl UsersApi for Users {
+    async fn get(
+        &self,
+        account_id: &str,
+        additional_info: &str,
+        count: &str,
+        email: &str,
+        email_substring: &str,
+        group_id: &str,
+        include_usersettings_for_csv: &str,
+        login_status: &str,
+        not_group_id: &str,
+        start_position: &str,
+        status: &str,
+        user_name_substring: &str,
+    ) -> Result<crate::types::UserInformationList> {
+        Users::get(
+            self,
+            account_id,
+            additional_info,
+            count,
+            email,
+            email_substring,
+            group_id,
+            include_usersettings_for_csv,
+            login_status,
+            not_group_id,
+            start_position,
+            status,
+            user_name_substring,
+        )
+        .await
+    }
+    async fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::UserInformationList,
+    ) -> Result<crate::types::UserInformationList> {
+        Users::put(self, account_id, body).await
+    }
+    async fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::NewUsersDefinition,
+    ) -> Result<crate::types::NewUsersSummary> {
+        Users::post(self, account_id, body).await
+    }
+    async fn delete(
+        &self,
+        account_id: &str,
+        delete: &str,
+        body: &crate::types::UserInfoList,
+    ) -> Result<crate::types::UsersResponse> {
+        Users::delete(self, account_id, delete, body).await
+    }
+    async fn get_users(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        additional_info: &str,
+        email: &str,
+    ) -> Result<crate::types::UserInformation> {
+        Users::get_users(self, account_id, user_id, additional_info, email).await
+    }
+    async fn put_users(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::UserInformation,
+    ) -> Result<crate::types::UserInformation> {
+        Users::put_users(self, account_id, user_id, body).await
+    }
+    async fn profile_image_get(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        encoding: &str,
+    ) -> Result<()> {
+        Users::profile_image_get(self, account_id, user_id, encoding).await
+    }
+    async fn profile_image_put(&self, account_id: &str, user_id: &str) -> Result<()> {
+        Users::profile_image_put(self, account_id, user_id).await
+    }
+    async fn profile_image_delete(&self, account_id: &str, user_id: &str) -> Result<()> {
+        Users::profile_image_delete(self, account_id, user_id).await
+    }
+    async fn profile_image_delete_if_exists(
+        &self,
+        account_id: &str,
+        user_id: &str,
+    ) -> Result<bool> {
+        Users::profile_image_delete_if_exists(self, account_id, user_id).await
+    }
+    async fn settings_get(
+        &self,
+        account_id: &str,
+        user_id: &str,
+    ) -> Result<crate::types::UserSettingsInformation> {
+        Users::settings_get(self, account_id, user_id).await
+    }
+    async fn settings_put(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::UserSettingsInformation,
+    ) -> Result<()> {
+        Users::settings_put(self, account_id, user_id, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct WorkspaceItems {
     pub client: Client,
@@ -12,6 +10,14 @@ impl WorkspaceItems {
         WorkspaceItems { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        WorkspaceItems {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * List workspace folder contents.
      *
@@ -45,50 +51,49 @@ impl WorkspaceItems {
         start_position: &str,
         workspace_user_id: &str,
     ) -> Result<crate::types::WorkspaceFolderContents> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !include_files.is_empty() {
-            query_args.push(("include_files".to_string(), include_files.to_string()));
+            query_args.push(("include_files", include_files.to_string()));
         }
         if !include_sub_folders.is_empty() {
-            query_args.push((
-                "include_sub_folders".to_string(),
-                include_sub_folders.to_string(),
-            ));
+            query_args.push(("include_sub_folders", include_sub_folders.to_string()));
         }
         if !include_thumbnails.is_empty() {
-            query_args.push((
-                "include_thumbnails".to_string(),
-                include_thumbnails.to_string(),
-            ));
+            query_args.push(("include_thumbnails", include_thumbnails.to_string()));
         }
         if !include_user_detail.is_empty() {
-            query_args.push((
-                "include_user_detail".to_string(),
-                include_user_detail.to_string(),
-            ));
+            query_args.push(("include_user_detail", include_user_detail.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
         if !workspace_user_id.is_empty() {
-            query_args.push((
-                "workspace_user_id".to_string(),
-                workspace_user_id.to_string(),
-            ));
+            query_args.push(("workspace_user_id", workspace_user_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/workspaces/{}/folders/{}?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&workspace_id.to_string()),
-            crate::progenitor_support::encode_path(&folder_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/workspaces/{}/folders/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(workspace_id),
+                self.client.encode_path(folder_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/workspaces/{workspaceId}/folders/{folderId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("workspaceId", workspace_id.to_string()),
+                    ("folderId", folder_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -115,14 +120,62 @@ impl WorkspaceItems {
     ) -> Result<()> {
         let url = format!(
             "/v2.1/accounts/{}/workspaces/{}/folders/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&workspace_id.to_string()),
-            crate::progenitor_support::encode_path(&folder_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(workspace_id),
+            self.client.encode_path(folder_id),
         );
 
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .delete(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/workspaces/{workspaceId}/folders/{folderId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("workspaceId", workspace_id.to_string()),
+                        ("folderId", folder_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * Deletes files or sub-folders from a workspace.
+     *
+     * This function performs a `DELETE` to the `/v2.1/accounts/{accountId}/workspaces/{workspaceId}/folders/{folderId}` endpoint.
+     *
+     * As opposed to `workspace_folder_delete_items`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn workspace_folder_delete_items_if_exists(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+        body: &crate::types::WorkspaceItemList,
+    ) -> Result<bool> {
+        let url = format!(
+            "/v2.1/accounts/{}/workspaces/{}/folders/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(workspace_id),
+            self.client.encode_path(folder_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v2.1/accounts/{accountId}/workspaces/{workspaceId}/folders/{folderId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("workspaceId", workspace_id.to_string()),
+                        ("folderId", folder_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -146,12 +199,22 @@ impl WorkspaceItems {
     ) -> Result<crate::types::WorkspaceItem> {
         let url = format!(
             "/v2.1/accounts/{}/workspaces/{}/folders/{}/files",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&workspace_id.to_string()),
-            crate::progenitor_support::encode_path(&folder_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(workspace_id),
+            self.client.encode_path(folder_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/v2.1/accounts/{accountId}/workspaces/{workspaceId}/folders/{folderId}/files",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("workspaceId", workspace_id.to_string()),
+                    ("folderId", folder_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -179,24 +242,25 @@ impl WorkspaceItems {
         is_download: &str,
         pdf_version: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !is_download.is_empty() {
-            query_args.push(("is_download".to_string(), is_download.to_string()));
+            query_args.push(("is_download", is_download.to_string()));
         }
         if !pdf_version.is_empty() {
-            query_args.push(("pdf_version".to_string(), pdf_version.to_string()));
+            query_args.push(("pdf_version", pdf_version.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/workspaces/{}/folders/{}/files/{}?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&workspace_id.to_string()),
-            crate::progenitor_support::encode_path(&folder_id.to_string()),
-            crate::progenitor_support::encode_path(&file_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/workspaces/{}/folders/{}/files/{}",
+                self.client.encode_path(account_id),
+                self.client.encode_path(workspace_id),
+                self.client.encode_path(folder_id),
+                self.client.encode_path(file_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/workspaces/{workspaceId}/folders/{folderId}/files/{fileId}", &[("accountId", account_id.to_string()), ("workspaceId", workspace_id.to_string()), ("folderId", folder_id.to_string()), ("fileId", file_id.to_string())]))
     }
 
     /**
@@ -222,13 +286,13 @@ impl WorkspaceItems {
     ) -> Result<crate::types::WorkspaceItem> {
         let url = format!(
             "/v2.1/accounts/{}/workspaces/{}/folders/{}/files/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&workspace_id.to_string()),
-            crate::progenitor_support::encode_path(&folder_id.to_string()),
-            crate::progenitor_support::encode_path(&file_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(workspace_id),
+            self.client.encode_path(folder_id),
+            self.client.encode_path(file_id),
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| crate::utils::pretty_url("PUT", "/v2.1/accounts/{accountId}/workspaces/{workspaceId}/folders/{folderId}/files/{fileId}", &[("accountId", account_id.to_string()), ("workspaceId", workspace_id.to_string()), ("folderId", folder_id.to_string()), ("fileId", file_id.to_string())]))
     }
 
     /**
@@ -262,32 +326,230 @@ impl WorkspaceItems {
         max_width: &str,
         start_position: &str,
     ) -> Result<crate::types::PageImages> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !dpi.is_empty() {
-            query_args.push(("dpi".to_string(), dpi.to_string()));
+            query_args.push(("dpi", dpi.to_string()));
         }
         if !max_height.is_empty() {
-            query_args.push(("max_height".to_string(), max_height.to_string()));
+            query_args.push(("max_height", max_height.to_string()));
         }
         if !max_width.is_empty() {
-            query_args.push(("max_width".to_string(), max_width.to_string()));
+            query_args.push(("max_width", max_width.to_string()));
         }
         if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
+            query_args.push(("start_position", start_position.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2.1/accounts/{}/workspaces/{}/folders/{}/files/{}/pages?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&workspace_id.to_string()),
-            crate::progenitor_support::encode_path(&folder_id.to_string()),
-            crate::progenitor_support::encode_path(&file_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2.1/accounts/{}/workspaces/{}/folders/{}/files/{}/pages",
+                self.client.encode_path(account_id),
+                self.client.encode_path(workspace_id),
+                self.client.encode_path(folder_id),
+                self.client.encode_path(file_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/v2.1/accounts/{accountId}/workspaces/{workspaceId}/folders/{folderId}/files/{fileId}/pages", &[("accountId", account_id.to_string()), ("workspaceId", workspace_id.to_string()), ("folderId", folder_id.to_string()), ("fileId", file_id.to_string())]))
+    }
+}
+
+/// The functions of [`WorkspaceItems`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn WorkspaceItemsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait WorkspaceItemsApi: Send + Sync {
+    async fn workspace_folder_get(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+        count: &str,
+        include_files: &str,
+        include_sub_folders: &str,
+        include_thumbnails: &str,
+        include_user_detail: &str,
+        start_position: &str,
+        workspace_user_id: &str,
+    ) -> Result<crate::types::WorkspaceFolderContents>;
+    async fn workspace_folder_delete_items(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+        body: &crate::types::WorkspaceItemList,
+    ) -> Result<()>;
+    async fn workspace_folder_delete_items_if_exists(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+        body: &crate::types::WorkspaceItemList,
+    ) -> Result<bool>;
+    async fn workspace_file_post_files(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+    ) -> Result<crate::types::WorkspaceItem>;
+    async fn workspace_file_get(
+        &self,
+        account_id: &str,
+        file_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+        is_download: &str,
+        pdf_version: &str,
+    ) -> Result<()>;
+    async fn workspace_file_put(
+        &self,
+        account_id: &str,
+        file_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+    ) -> Result<crate::types::WorkspaceItem>;
+    async fn workspace_file_pages_get(
+        &self,
+        account_id: &str,
+        file_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+        count: &str,
+        dpi: &str,
+        max_height: &str,
+        max_width: &str,
+        start_position: &str,
+    ) -> Result<crate::types::PageImages>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl WorkspaceItemsApi for WorkspaceItems {
+    async fn workspace_folder_get(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+        count: &str,
+        include_files: &str,
+        include_sub_folders: &str,
+        include_thumbnails: &str,
+        include_user_detail: &str,
+        start_position: &str,
+        workspace_user_id: &str,
+    ) -> Result<crate::types::WorkspaceFolderContents> {
+        WorkspaceItems::workspace_folder_get(
+            self,
+            account_id,
+            folder_id,
+            workspace_id,
+            count,
+            include_files,
+            include_sub_folders,
+            include_thumbnails,
+            include_user_detail,
+            start_position,
+            workspace_user_id,
+        )
+        .await
+    }
+    async fn workspace_folder_delete_items(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+        body: &crate::types::WorkspaceItemList,
+    ) -> Result<()> {
+        WorkspaceItems::workspace_folder_delete_items(
+            self,
+            account_id,
+            folder_id,
+            workspace_id,
+            body,
+        )
+        .await
+    }
+    async fn workspace_folder_delete_items_if_exists(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+        body: &crate::types::WorkspaceItemList,
+    ) -> Result<bool> {
+        WorkspaceItems::workspace_folder_delete_items_if_exists(
+            self,
+            account_id,
+            folder_id,
+            workspace_id,
+            body,
+        )
+        .await
+    }
+    async fn workspace_file_post_files(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+    ) -> Result<crate::types::WorkspaceItem> {
+        WorkspaceItems::workspace_file_post_files(self, account_id, folder_id, workspace_id).await
+    }
+    async fn workspace_file_get(
+        &self,
+        account_id: &str,
+        file_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+        is_download: &str,
+        pdf_version: &str,
+    ) -> Result<()> {
+        WorkspaceItems::workspace_file_get(
+            self,
+            account_id,
+            file_id,
+            folder_id,
+            workspace_id,
+            is_download,
+            pdf_version,
+        )
+        .await
+    }
+    async fn workspace_file_put(
+        &self,
+        account_id: &str,
+        file_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+    ) -> Result<crate::types::WorkspaceItem> {
+        WorkspaceItems::workspace_file_put(self, account_id, file_id, folder_id, workspace_id).await
+    }
+    async fn workspace_file_pages_get(
+        &self,
+        account_id: &str,
+        file_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+        count: &str,
+        dpi: &str,
+        max_height: &str,
+        max_width: &str,
+        start_position: &str,
+    ) -> Result<crate::types::PageImages> {
+        WorkspaceItems::workspace_file_pages_get(
+            self,
+            account_id,
+            file_id,
+            folder_id,
+            workspace_id,
+            count,
+            dpi,
+            max_height,
+            max_width,
+            start_position,
+        )
+        .await
     }
 }
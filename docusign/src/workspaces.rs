@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Workspaces {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Workspaces {
         Workspaces { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Workspaces {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * List Workspaces.
      *
@@ -26,10 +32,16 @@ impl Workspaces {
     pub async fn get(&self, account_id: &str) -> Result<crate::types::WorkspaceList> {
         let url = format!(
             "/v2.1/accounts/{}/workspaces",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/workspaces",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -50,12 +62,19 @@ impl Workspaces {
     ) -> Result<crate::types::Workspace> {
         let url = format!(
             "/v2.1/accounts/{}/workspaces",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2.1/accounts/{accountId}/workspaces",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -77,11 +96,20 @@ impl Workspaces {
     ) -> Result<crate::types::Workspace> {
         let url = format!(
             "/v2.1/accounts/{}/workspaces/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&workspace_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(workspace_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v2.1/accounts/{accountId}/workspaces/{workspaceId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("workspaceId", workspace_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -104,13 +132,23 @@ impl Workspaces {
     ) -> Result<crate::types::Workspace> {
         let url = format!(
             "/v2.1/accounts/{}/workspaces/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&workspace_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(workspace_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v2.1/accounts/{accountId}/workspaces/{workspaceId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("workspaceId", workspace_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -132,10 +170,83 @@ impl Workspaces {
     ) -> Result<crate::types::Workspace> {
         let url = format!(
             "/v2.1/accounts/{}/workspaces/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&workspace_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(workspace_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v2.1/accounts/{accountId}/workspaces/{workspaceId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("workspaceId", workspace_id.to_string()),
+                ],
+            )
+        })
+    }
+}
+
+/// The functions of [`Workspaces`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn WorkspacesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait WorkspacesApi: Send + Sync {
+    async fn get(&self, account_id: &str) -> Result<crate::types::WorkspaceList>;
+    async fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::Workspace,
+    ) -> Result<crate::types::Workspace>;
+    async fn get_workspaces(
+        &self,
+        account_id: &str,
+        workspace_id: &str,
+    ) -> Result<crate::types::Workspace>;
+    async fn put(
+        &self,
+        account_id: &str,
+        workspace_id: &str,
+        body: &crate::types::Workspace,
+    ) -> Result<crate::types::Workspace>;
+    async fn delete(&self, account_id: &str, workspace_id: &str)
+        -> Result<crate::types::Workspace>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl WorkspacesApi for Workspaces {
+    async fn get(&self, account_id: &str) -> Result<crate::types::WorkspaceList> {
+        Workspaces::get(self, account_id).await
+    }
+    async fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::Workspace,
+    ) -> Result<crate::types::Workspace> {
+        Workspaces::post(self, account_id, body).await
+    }
+    async fn get_workspaces(
+        &self,
+        account_id: &str,
+        workspace_id: &str,
+    ) -> Result<crate::types::Workspace> {
+        Workspaces::get_workspaces(self, account_id, workspace_id).await
+    }
+    async fn put(
+        &self,
+        account_id: &str,
+        workspace_id: &str,
+        body: &crate::types::Workspace,
+    ) -> Result<crate::types::Workspace> {
+        Workspaces::put(self, account_id, workspace_id, body).await
+    }
+    async fn delete(
+        &self,
+        account_id: &str,
+        workspace_id: &str,
+    ) -> Result<crate::types::Workspace> {
+        Workspaces::delete(self, account_id, workspace_id).await
     }
 }
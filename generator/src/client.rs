@@ -108,6 +108,20 @@ impl Client {
         }
     }
 
+    /// Join the query parameters onto the path of a request, leaving out those
+    /// that are empty, as the generated functions push every parameter they
+    /// take whether or not it was set.
+    fn url_with_query(base: &str, args: &[(&str, String)]) -> String {
+        let args: Vec<&(&str, String)> = args.iter().filter(|(_, v)| !v.is_empty()).collect();
+        if args.is_empty() {
+            return base.to_string();
+        }
+        let query = url::form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(args)
+            .finish();
+        format!("{}?{}", base, query)
+    }
+
     async fn url_and_auth(
         &self,
         uri: &str,
@@ -198,7 +212,11 @@ impl Client {
         }
 
         if let Some(body) = body {
-            log::debug!("body: {:?}", String::from_utf8(body.as_bytes().unwrap().to_vec()).unwrap());
+            // A streaming body can not be read without sending it, so only log one
+            // that is in memory.
+            if let Some(bytes) = body.as_bytes() {
+                log::debug!("body: {:?}", String::from_utf8_lossy(bytes));
+            }
             req = req.body(body);
         }
         log::debug!("request: {:?}", &req);
@@ -468,246 +486,2406 @@ impl Client {
         Ok(global_items)
     }"#;
 
-pub fn generate_client_generic_token(
-    proper_name: &str,
-    token_endpoint: &str,
-    user_consent_endpoint: &str,
-    add_post_header: &str,
-) -> String {
-    let mut new_from_env = basic_new_from_env(proper_name, add_post_header);
-    if proper_name.starts_with("Google") {
-        new_from_env = GOOGLE_NEW_FROM_ENV_TEMPLATE.to_string();
+/*
+ * The settings the reqwest client is built with, for the generic clients.
+ */
+pub const HTTP_CONFIG_TEMPLATE: &str = r#"/// How the client handles redirects.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RedirectPolicy {
+    /// Follow at most this many redirects in a row.
+    Limited(usize),
+    /// Do not follow redirects, return them as they are.
+    None,
+}
+
+impl Default for RedirectPolicy {
+    fn default() -> Self {
+        RedirectPolicy::Limited(10)
     }
+}
 
-    let add_post_header_struct = if add_post_header.is_empty() {
-        "".to_string()
-    } else {
-        format!("{}: String,", to_snake_case(add_post_header))
-    };
+impl From<RedirectPolicy> for reqwest::redirect::Policy {
+    fn from(policy: RedirectPolicy) -> Self {
+        match policy {
+            RedirectPolicy::Limited(n) => reqwest::redirect::Policy::limited(n),
+            RedirectPolicy::None => reqwest::redirect::Policy::none(),
+        }
+    }
+}
 
-    let add_post_header_args = if add_post_header.is_empty() {
-        "".to_string()
-    } else {
-        format!("{}: P,", to_snake_case(add_post_header))
-    };
+/// How the client retries requests that fail with a connection error, a
+/// `429 Too Many Requests` or a server error. By default it does not retry.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RetryPolicy {
+    /// How many times to send a request again before giving up.
+    pub max_retries: u32,
+    /// How long to wait before the first retry. This doubles for each retry after.
+    /// When the server sends a `Retry-After`, that is how long we wait instead.
+    pub initial_backoff: std::time::Duration,
+    /// The longest to wait before a retry, however much the backoff doubled.
+    pub max_backoff: Option<std::time::Duration>,
+    /// The longest to wait before all the retries of a request together. Once
+    /// the next wait would go over it, we stop retrying and return the last error.
+    pub max_total_delay: Option<std::time::Duration>,
+    /// Wait a random part of each backoff, from half of it to all of it, so the
+    /// clients that were turned away together do not all retry together. A
+    /// `Retry-After` from the server is waited as it is.
+    pub jitter: bool,
+}
 
-    let add_post_header_args_where = if add_post_header.is_empty() {
-        "".to_string()
-    } else {
-        "P: ToString,".to_string()
-    };
+impl RetryPolicy {
+    /// Retry a request up to `max_retries` times, waiting `initial_backoff` before
+    /// the first retry.
+    pub fn new(max_retries: u32, initial_backoff: std::time::Duration) -> Self {
+        RetryPolicy {
+            max_retries,
+            initial_backoff,
+            ..Default::default()
+        }
+    }
 
-    let add_post_header_fn = if add_post_header.is_empty() {
-        "".to_string()
-    } else {
-        format!(
-            "{} : {}.to_string(),",
-            to_snake_case(add_post_header),
-            to_snake_case(add_post_header)
-        )
-    };
+    /// Do not retry requests, for example for calls that are not safe to repeat.
+    pub fn none() -> Self {
+        RetryPolicy::default()
+    }
 
-    let add_post_header_type = if !add_post_header.is_empty() {
-        ", P".to_string()
-    } else {
-        "".to_string()
-    };
+    /// Wait at most `max_backoff` before a retry.
+    pub fn with_max_backoff(mut self, max_backoff: std::time::Duration) -> Self {
+        self.max_backoff = Some(max_backoff);
+        self
+    }
 
-    format!(
-        r#"use std::env;
+    /// Stop retrying once the waits before the retries would add up to more than
+    /// `max_total_delay`, whatever `max_retries` is.
+    pub fn with_max_total_delay(mut self, max_total_delay: std::time::Duration) -> Self {
+        self.max_total_delay = Some(max_total_delay);
+        self
+    }
 
-const TOKEN_ENDPOINT: &str = "https://{}";
-const USER_CONSENT_ENDPOINT: &str = "https://{}";
+    /// Spread the waits before retries, see `jitter`.
+    pub fn with_jitter(mut self) -> Self {
+        self.jitter = true;
+        self
+    }
 
-/// Entrypoint for interacting with the API client.
-#[derive(Clone)]
-pub struct Client {{
-    host: String,
-    token: String,
-    // This will expire within a certain amount of time as determined by the
-    // expiration date passed back in the initial request.
-    refresh_token: String,
-    client_id: String,
-    client_secret: String,
-    redirect_uri: String,
-    {}
+    fn should_retry(status: reqwest::StatusCode) -> bool {
+        status == reqwest::StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+    }
 
-    client: reqwest::Client,
-}}
+    /// The backoff capped at `max_backoff`, if we have one.
+    fn capped(&self, backoff: std::time::Duration) -> std::time::Duration {
+        match self.max_backoff {
+            Some(max) => backoff.min(max),
+            None => backoff,
+        }
+    }
 
-{}
+    /// How long to wait before a retry with this backoff, capped and with jitter
+    /// if the policy says so.
+    fn delay(&self, backoff: std::time::Duration) -> std::time::Duration {
+        let backoff = self.capped(backoff);
+        if !self.jitter {
+            return backoff;
+        }
+        let random: f64 = rand::random();
+        backoff / 2 + (backoff / 2).mul_f64(random)
+    }
 
-impl Client {{
-    /// Create a new Client struct. It takes a type that can convert into
-    /// an &str (`String` or `Vec<u8>` for example). As long as the function is
-    /// given a valid API key your requests will work.
-    pub fn new<I, K, R, T, Q{}>(
-        client_id: I,
-        client_secret: K,
-        redirect_uri: R,
-        token: T,
-        refresh_token: Q,
-        {}
-    ) -> Self
-    where
-        I: ToString,
-        K: ToString,
-        R: ToString,
-        T: ToString,
-        Q: ToString,
-        {}
-    {{
-        let client = reqwest::Client::builder().build();
-        match client {{
-            Ok(c) => {{
-                // We do not refresh the access token here since we leave that up to the
-                // user to do so they can re-save it to their database.
-                // TODO: But in the future we should save the expires in date and refresh it
-                // if it needs to be refreshed.
-                //
-                Client {{
-                    host: DEFAULT_HOST.to_string(),
-                    client_id: client_id.to_string(),
-                    client_secret: client_secret.to_string(),
-                    redirect_uri: redirect_uri.to_string(),
-                    token: token.to_string(),
-                    refresh_token: refresh_token.to_string(),
-                    {}
+    /// How long the server asks us to wait with a `Retry-After`, in seconds or
+    /// as an HTTP date, if it does. A date is compared with `now`.
+    fn retry_after(
+        headers: &reqwest::header::HeaderMap,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Option<std::time::Duration> {
+        let value = headers.get(reqwest::header::RETRY_AFTER)?.to_str().ok()?.trim();
+        if let Ok(seconds) = value.parse::<u64>() {
+            return Some(std::time::Duration::from_secs(seconds));
+        }
 
-                    client: c,
-                }}
-            }}
-            Err(e) => panic!("creating reqwest client failed: {{:?}}", e),
-        }}
-    }}
+        let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+        // A date that already passed means we can retry now.
+        Some((date.with_timezone(&chrono::Utc) - now).to_std().unwrap_or_default())
+    }
+}
 
-    /// Override the default host for the client.
-    pub fn with_host<H>(&self, host: H) -> Self
-    where
-        H: ToString,
-    {{
-        let mut c = self.clone();
-        c.host = host.to_string();
-        c
-     }}
+/// The rate limit the server reported with a response, in its
+/// `X-RateLimit-Limit`, `X-RateLimit-Remaining` and `Retry-After` headers, as
+/// Zoom and DocuSign do, to slow down before the server turns requests away.
+/// See `Client::last_rate_limit`. A header that is missing, or is not a number,
+/// is `None`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RateLimitHeaders {
+    /// How many requests the server allows in the current window.
+    pub limit: Option<u64>,
+    /// How many of those are left.
+    pub remaining: Option<u64>,
+    /// How long the server asks us to wait before the next request.
+    pub retry_after: Option<std::time::Duration>,
+}
 
-    {}
+impl RateLimitHeaders {
+    /// The rate limit in `headers`, or `None` if they have none of its headers.
+    /// A `Retry-After` date is compared with `now`.
+    fn from_headers(
+        headers: &reqwest::header::HeaderMap,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Option<Self> {
+        let number = |name: &str| headers.get(name)?.to_str().ok()?.trim().parse::<u64>().ok();
+        let rate_limit = RateLimitHeaders {
+            limit: number("x-ratelimit-limit"),
+            remaining: number("x-ratelimit-remaining"),
+            retry_after: RetryPolicy::retry_after(headers, now),
+        };
+        if rate_limit == RateLimitHeaders::default() {
+            return None;
+        }
+        Some(rate_limit)
+    }
+}
 
+/// How many requests the client sends over time: a bucket of `requests` turns
+/// that refills over `per`. A request over the limit waits for its turn, unless
+/// the wait would be over `max_wait`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RateLimit {
+    /// How many requests can be sent at once, and over each `per`.
+    pub requests: u32,
+    /// How long the bucket takes to refill.
+    pub per: std::time::Duration,
+    /// The longest a request waits for its turn. When the wait would be over
+    /// it, the request fails at once with a `ClientError::RateLimited`.
+    pub max_wait: Option<std::time::Duration>,
+}
 
+impl RateLimit {
+    /// Send at most `requests` requests every `per`.
+    pub fn new(requests: u32, per: std::time::Duration) -> Self {
+        RateLimit {
+            requests,
+            per,
+            max_wait: None,
+        }
+    }
 
-    {}
+    /// Fail with a `ClientError::RateLimited` rather than wait for a turn when
+    /// the wait would be over `max_wait`, for calls that would rather fail than
+    /// be late. A `max_wait` of zero never waits.
+    pub fn with_max_wait(mut self, max_wait: std::time::Duration) -> Self {
+        self.max_wait = Some(max_wait);
+        self
+    }
+}
 
-    {}"#,
-        token_endpoint.trim_start_matches("https://"),
-        user_consent_endpoint.trim_start_matches("https://"),
-        add_post_header_struct,
-        ACCESS_TOKEN_STRUCT_TEMPLATE,
-        add_post_header_type,
-        add_post_header_args,
-        add_post_header_args_where,
-        add_post_header_fn,
-        new_from_env,
-        TOKEN_AUTH_TEMPLATE,
-        get_shared_functions(proper_name, add_post_header)
-    )
+/// The bucket of a `RateLimit`, shared by the clones of a client.
+#[derive(Debug)]
+struct RateLimiter {
+    limit: RateLimit,
+    /// When the bucket is full again, if turns were taken from it.
+    full_at: std::sync::Mutex<Option<std::time::Instant>>,
 }
 
-fn basic_new_from_env(proper_name: &str, add_post_header: &str) -> String {
-    let add_post_header_type = if !add_post_header.is_empty() {
-        ", P".to_string()
-    } else {
-        "".to_string()
-    };
-    let add_post_header_args = if !add_post_header.is_empty() {
-        format!(",{}: P", to_snake_case(add_post_header))
-    } else {
-        "".to_string()
-    };
-    let add_post_header_args_where = if add_post_header.is_empty() {
-        "".to_string()
-    } else {
-        "P: ToString,".to_string()
-    };
-    let add_post_header_fn = if !add_post_header.is_empty() {
-        format!("{},", to_snake_case(add_post_header))
-    } else {
-        "".to_string()
-    };
+impl RateLimiter {
+    /// Take the turn of a request sent at `now` and return how long it has to
+    /// wait for it. If that is over the max wait, the turn is not taken and the
+    /// wait is returned as the error.
+    fn take(
+        &self,
+        now: std::time::Instant,
+    ) -> std::result::Result<std::time::Duration, std::time::Duration> {
+        let interval = self.limit.per / self.limit.requests.max(1);
+        let mut full_at = self.full_at.lock().unwrap();
+        let start = full_at.map_or(now, |t| t.max(now));
+        let wait = (start + interval).saturating_duration_since(now + self.limit.per);
+        if matches!(self.limit.max_wait, Some(max) if wait > max) {
+            return Err(wait);
+        }
 
-    format!(
-        r#"
-/// Create a new Client struct from environment variables. It
-/// takes a type that can convert into
-/// an &str (`String` or `Vec<u8>` for example). As long as the function is
-/// given a valid API key and your requests will work.
-/// We pass in the token and refresh token to the client so if you are storing
-/// it in a database, you can get it first.
-pub fn new_from_env<T, R{}>(token: T, refresh_token: R{}) -> Self
-where
-    T: ToString,
-    R: ToString,
-    {}
-{{
-    let client_id = env::var("{}_CLIENT_ID").expect("must set {}_CLIENT_ID");
-    let client_secret = env::var("{}_CLIENT_SECRET").expect("must set {}_CLIENT_SECRET");
-    let redirect_uri = env::var("{}_REDIRECT_URI").expect("must set {}_REDIRECT_URI");
+        *full_at = Some(start + interval);
+        Ok(wait)
+    }
+}
 
-    Client::new(
-        client_id,
-        client_secret,
-        redirect_uri,
-        token,
-        refresh_token,
-        {}
-    )
-}}"#,
-        add_post_header_type,
-        add_post_header_args,
-        add_post_header_args_where,
-        proper_name.to_uppercase().replace('.', ""),
-        proper_name.to_uppercase().replace('.', ""),
-        proper_name.to_uppercase().replace('.', ""),
-        proper_name.to_uppercase().replace('.', ""),
-        proper_name.to_uppercase().replace('.', ""),
-        proper_name.to_uppercase().replace('.', ""),
-        add_post_header_fn,
-    )
+/// When the client stops calling an API that keeps failing: after
+/// `failure_threshold` failed calls in a row to a host, calls to it fail at once
+/// with a `ClientError::CircuitOpen` until `cooldown` has passed. A call fails
+/// if it could not connect or got a server error.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CircuitConfig {
+    /// How many failed calls in a row open the circuit.
+    pub failure_threshold: u32,
+    /// How long the circuit stays open. After it, one call is let through as a
+    /// probe while the others still fail: the circuit closes if the probe
+    /// succeeds, and opens again if it fails.
+    pub cooldown: std::time::Duration,
 }
 
-const GOOGLE_NEW_FROM_ENV_TEMPLATE: &str = r#"
-/// Create a new Client struct from environment variables. It
-/// takes a type that can convert into
-/// an &str (`String` or `Vec<u8>` for example). As long as the function is
-/// given a valid API key and your requests will work.
-/// We pass in the token and refresh token to the client so if you are storing
-/// it in a database, you can get it first.
-pub async fn new_from_env<T, R>(token: T, refresh_token: R) -> Self
-where
-    T: ToString,
-    R: ToString,
-{
-    let google_key = env::var("GOOGLE_KEY_ENCODED").unwrap_or_default();
-    let b = base64::decode(google_key).unwrap();
-    // Save the google key to a tmp file.
-    let mut file_path = env::temp_dir();
-    file_path.push("google_key.json");
-    // Create the file and write to it.
-    let mut file = std::fs::File::create(file_path.clone()).unwrap();
-    file.write_all(&b).unwrap();
-    // Set the Google credential file to the temp path.
-    let google_credential_file = file_path.to_str().unwrap().to_string();
+impl CircuitConfig {
+    /// Open the circuit for `cooldown` after `failure_threshold` failed calls in a row.
+    pub fn new(failure_threshold: u32, cooldown: std::time::Duration) -> Self {
+        CircuitConfig {
+            failure_threshold,
+            cooldown,
+        }
+    }
+}
 
-    let secret = yup_oauth2::read_application_secret(google_credential_file)
-        .await
-        .expect("failed to read google credential file");
+/// The circuits of a `CircuitConfig` by host, shared by the clones of a client.
+#[derive(Debug)]
+struct CircuitBreaker {
+    config: CircuitConfig,
+    circuits: std::sync::Mutex<std::collections::HashMap<String, Circuit>>,
+}
 
-    let client = reqwest::Client::builder().build();
-    match client {
-        Ok(c) => {
-            // We do not refresh the access token here since we leave that up to the
-            // user to do so they can re-save it to their database.
-            // TODO: But in the future we should save the expires in date and refresh it
-            // if it needs to be refreshed.
+/// The failed calls in a row to a host, until when its circuit is open, and
+/// since when a probe call has been let through after that.
+#[derive(Debug, Default)]
+struct Circuit {
+    failures: u32,
+    open_until: Option<std::time::Instant>,
+    probing_since: Option<std::time::Instant>,
+}
+
+impl CircuitBreaker {
+    /// Let a call to `host` through at `now`, or return how long until one may be.
+    /// Once the cooldown has passed, a single probe is let through until it is
+    /// recorded. A probe that never is, as its call was dropped, is given up on
+    /// after another cooldown.
+    fn admit(&self, host: &str, now: std::time::Instant) -> Result<(), std::time::Duration> {
+        let mut circuits = self.circuits.lock().unwrap();
+        let circuit = match circuits.get_mut(host) {
+            Some(circuit) => circuit,
+            None => return Ok(()),
+        };
+        let open_until = match circuit.open_until {
+            Some(open_until) => open_until,
+            None => return Ok(()),
+        };
+        if open_until > now {
+            return Err(open_until - now);
+        }
+        if let Some(probing_since) = circuit.probing_since {
+            let probe_until = probing_since + self.config.cooldown;
+            if probe_until > now {
+                return Err(probe_until - now);
+            }
+        }
+        circuit.probing_since = Some(now);
+        Ok(())
+    }
+
+    /// Count a call to `host` that ended at `now`.
+    fn record(&self, host: &str, now: std::time::Instant, failed: bool) {
+        let mut circuits = self.circuits.lock().unwrap();
+        let circuit = circuits.entry(host.to_string()).or_default();
+        if !failed {
+            *circuit = Circuit::default();
+            return;
+        }
+
+        circuit.failures = circuit.failures.saturating_add(1);
+        circuit.probing_since = None;
+        if circuit.failures >= self.config.failure_threshold {
+            circuit.open_until = Some(now + self.config.cooldown);
+        }
+    }
+}
+
+/// Responses replayed from, or recorded to, the fixture files in a directory.
+#[derive(Debug, Clone)]
+struct Fixtures {
+    dir: std::path::PathBuf,
+    record: bool,
+}
+
+/// A response as its fixture file holds it.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct Fixture {
+    status: u16,
+    #[serde(default)]
+    headers: std::collections::BTreeMap<String, String>,
+    #[serde(default)]
+    body: String,
+}
+
+impl Fixtures {
+    /// Record rather than replay, with the `record` feature and `RECORD_FIXTURES` set.
+    fn recording() -> bool {
+        cfg!(feature = "record")
+            && std::env::var_os("RECORD_FIXTURES").map_or(false, |v| !v.is_empty())
+    }
+
+    /// The fixture file of a request, named after its method, path and query.
+    fn path(&self, req: &reqwest::Request) -> std::path::PathBuf {
+        let url = req.url();
+        let name = format!(
+            "{} {}?{}",
+            req.method(),
+            url.path(),
+            url.query().unwrap_or_default()
+        );
+        let name: Vec<&str> = name
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|s| !s.is_empty())
+            .collect();
+        self.dir.join(format!("{}.json", name.join("_")))
+    }
+
+    /// The response recorded for a request.
+    fn replay(&self, req: &reqwest::Request) -> Result<reqwest::Response> {
+        let path = self.path(req);
+        let fixture = std::fs::read(&path)
+            .map_err(|e| anyhow!("reading fixture {}: {}", path.display(), e))?;
+        let fixture: Fixture = serde_json::from_slice(&fixture)?;
+        fixture.into_response()
+    }
+
+    /// Write the response to the fixture file at `path`, and give it back.
+    async fn record(
+        &self,
+        path: &std::path::Path,
+        resp: reqwest::Response,
+    ) -> Result<reqwest::Response> {
+        let fixture = Fixture {
+            status: resp.status().as_u16(),
+            headers: resp
+                .headers()
+                .iter()
+                .filter_map(|(k, v)| Some((k.to_string(), v.to_str().ok()?.to_string())))
+                .collect(),
+            body: String::from_utf8_lossy(&resp.bytes().await?).to_string(),
+        };
+        std::fs::create_dir_all(&self.dir)?;
+        std::fs::write(path, serde_json::to_vec_pretty(&fixture)?)?;
+        fixture.into_response()
+    }
+}
+
+impl Fixture {
+    fn into_response(self) -> Result<reqwest::Response> {
+        let mut resp = http::Response::builder().status(self.status);
+        for (k, v) in &self.headers {
+            resp = resp.header(k.as_str(), v.as_str());
+        }
+        Ok(resp.body(self.body)?.into())
+    }
+}
+
+/// A failure to inject into a request in place of its response, see
+/// `Failpoints`.
+#[cfg(any(test, feature = "failpoints"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "failpoints")))]
+#[derive(Clone, Debug)]
+pub enum Failpoint {
+    /// Time out, as a request the server never answers does.
+    Timeout,
+    /// Respond with the status and no body, say 429 to have the call retried.
+    Status(reqwest::StatusCode),
+    /// Respond `200 OK` with the body, say one that is not valid JSON.
+    Body(String),
+}
+
+/// Failures to inject into the requests of a client, to test how its callers
+/// handle them without a server that fails on cue. See `Client::with_failpoints`,
+/// which is only there with the `failpoints` feature, for tests.
+#[cfg(any(test, feature = "failpoints"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "failpoints")))]
+#[derive(Debug, Default)]
+pub struct Failpoints {
+    points: std::sync::Mutex<Vec<FailpointRule>>,
+}
+
+/// A failure to inject into the next attempts at the requests it matches.
+#[cfg(any(test, feature = "failpoints"))]
+#[derive(Debug)]
+struct FailpointRule {
+    method: reqwest::Method,
+    path: String,
+    failpoint: Failpoint,
+    remaining: usize,
+}
+
+#[cfg(any(test, feature = "failpoints"))]
+impl Failpoints {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Fail the next `times` attempts at `method` requests whose path ends with
+    /// `path`, such as `/phone/numbers`, with `failpoint`. Each retry is an
+    /// attempt of its own, so failing the first one lets a retried call succeed.
+    pub fn fail(
+        self,
+        method: reqwest::Method,
+        path: &str,
+        times: usize,
+        failpoint: Failpoint,
+    ) -> Self {
+        self.points.lock().unwrap().push(FailpointRule {
+            method,
+            path: path.to_string(),
+            failpoint,
+            remaining: times,
+        });
+        self
+    }
+
+    /// The failure to inject into the request, if one is left for it.
+    fn take(&self, req: &reqwest::Request) -> Option<Failpoint> {
+        let mut points = self.points.lock().unwrap();
+        let rule = points.iter_mut().find(|r| {
+            r.remaining > 0 && r.method == *req.method() && req.url().path().ends_with(&r.path)
+        })?;
+        rule.remaining -= 1;
+        Some(rule.failpoint.clone())
+    }
+}
+
+#[cfg(any(test, feature = "failpoints"))]
+impl Failpoint {
+    /// Fail the request as the failpoint says, rather than get its response.
+    async fn inject(
+        self,
+        client: &reqwest::Client,
+        mut req: reqwest::Request,
+    ) -> reqwest::Result<reqwest::Response> {
+        let (status, body) = match self {
+            // With no time for it, the request times out before it is sent.
+            Failpoint::Timeout => {
+                *req.timeout_mut() = Some(std::time::Duration::ZERO);
+                return client.execute(req).await;
+            }
+            Failpoint::Status(status) => (status, String::new()),
+            Failpoint::Body(body) => (reqwest::StatusCode::OK, body),
+        };
+        let mut resp = http::Response::new(body);
+        *resp.status_mut() = status;
+        Ok(resp.into())
+    }
+}
+
+/// How many encoded path parameters a client keeps by default, see
+/// `Client::with_path_cache`.
+const PATH_CACHE_CAPACITY: usize = 32;
+
+/// How many shards the path cache is split in, each with its own lock, so the
+/// requests sent from many threads do not all wait on the one lock.
+const PATH_CACHE_SHARDS: usize = 8;
+
+/// A percent-encoded path parameter, see `Client::encode_path`.
+#[derive(Clone, Debug)]
+pub enum EncodedPath {
+    /// The encoding the client's path cache has, shared with it.
+    Cached(std::sync::Arc<str>),
+    /// An encoding made for the one call, by a client without a path cache.
+    Fresh(String),
+}
+
+impl std::ops::Deref for EncodedPath {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        match self {
+            EncodedPath::Cached(s) => s,
+            EncodedPath::Fresh(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for EncodedPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self)
+    }
+}
+
+/// An encoding in the path cache, and when it was last used.
+struct PathCacheEntry {
+    encoded: std::sync::Arc<str>,
+    used: std::sync::atomic::AtomicU64,
+}
+
+/// A shard of the path cache, with the clock its entries are used by.
+#[derive(Default)]
+struct PathCacheShard {
+    entries: std::sync::RwLock<std::collections::HashMap<String, PathCacheEntry>>,
+    clock: std::sync::atomic::AtomicU64,
+}
+
+/// The encodings of the path parameters a client used last, so the ids that
+/// are in most of its requests, like an account id, are encoded once rather
+/// than for each request. The parameters are split in shards by their hash, and
+/// finding one only takes the read lock of its shard. The least recently used
+/// of a shard is dropped when the shard is full.
+struct PathCache {
+    shard_capacity: usize,
+    shards: Vec<PathCacheShard>,
+    hasher: std::collections::hash_map::RandomState,
+}
+
+impl PathCache {
+    fn new(capacity: usize) -> Self {
+        let shards = capacity.min(PATH_CACHE_SHARDS);
+        PathCache {
+            shard_capacity: if shards == 0 {
+                0
+            } else {
+                capacity.div_ceil(shards)
+            },
+            shards: (0..shards).map(|_| Default::default()).collect(),
+            hasher: Default::default(),
+        }
+    }
+
+    /// The encoding of `pc`, from the cache if it is there.
+    fn encode(&self, pc: &str) -> EncodedPath {
+        if self.shards.is_empty() {
+            return EncodedPath::Fresh(progenitor_support::encode_path(pc));
+        }
+
+        let hash = std::hash::BuildHasher::hash_one(&self.hasher, pc);
+        let shard = &self.shards[hash as usize % self.shards.len()];
+        let now = shard
+            .clock
+            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+
+        if let Some(entry) = shard.entries.read().unwrap().get(pc) {
+            entry.used.store(now, std::sync::atomic::Ordering::Relaxed);
+            return EncodedPath::Cached(entry.encoded.clone());
+        }
+
+        let encoded: std::sync::Arc<str> = progenitor_support::encode_path(pc).into();
+        let mut entries = shard.entries.write().unwrap();
+        if entries.len() >= self.shard_capacity && !entries.contains_key(pc) {
+            let oldest = entries
+                .iter()
+                .min_by_key(|(_, e)| e.used.load(std::sync::atomic::Ordering::Relaxed))
+                .map(|(p, _)| p.clone());
+            if let Some(oldest) = oldest {
+                entries.remove(&oldest);
+            }
+        }
+        entries.insert(
+            pc.to_string(),
+            PathCacheEntry {
+                encoded: encoded.clone(),
+                used: now.into(),
+            },
+        );
+        EncodedPath::Cached(encoded)
+    }
+}
+
+/// The header the client sends a generated id in with each request, and how
+/// it generates them.
+#[derive(Clone)]
+struct RequestId {
+    header: reqwest::header::HeaderName,
+    generate: std::sync::Arc<dyn Fn() -> String + Send + Sync>,
+}
+
+/// A request the client is about to send, as told to the observer set with
+/// `Client::with_on_request`.
+#[derive(Clone, Debug)]
+pub struct RequestInfo {
+    /// The method of the request.
+    pub method: reqwest::Method,
+    /// The url of the request, with its query.
+    pub url: reqwest::Url,
+    /// Which attempt at the request this is, counting from 1, so retries of it
+    /// have the same method and url but a higher attempt.
+    pub attempt: u32,
+}
+
+/// How an attempt at a request went, as told to the observer set with
+/// `Client::with_on_response`.
+#[derive(Clone, Debug)]
+pub struct ResponseInfo {
+    /// The request the attempt was at.
+    pub request: RequestInfo,
+    /// The status of the response, or `None` if the attempt failed without one,
+    /// say because the connection was refused.
+    pub status: Option<reqwest::StatusCode>,
+    /// How long the attempt took, by the client's clock.
+    pub elapsed: std::time::Duration,
+}
+
+/// How many retries a call used, as told to the observer set with
+/// `Client::with_on_retry_budget` once the call is done.
+#[derive(Clone, Debug)]
+pub struct RetryBudgetInfo {
+    /// The method of the request.
+    pub method: reqwest::Method,
+    /// The url of the request, with its query.
+    pub url: reqwest::Url,
+    /// How many times the request was sent again after its first attempt.
+    pub retries: u32,
+    /// Whether the client gave up on the request while it still wanted a retry,
+    /// because it ran out of retries, of time to wait for them under the retry
+    /// policy's `max_total_delay`, or of time before the deadline.
+    pub exhausted: bool,
+}
+
+/// How many bytes an attempt at a request sent and got back, as told to the
+/// observer set with `Client::with_on_body_size`.
+#[derive(Clone, Debug)]
+pub struct BodySizeInfo {
+    /// The request the attempt was at.
+    pub request: RequestInfo,
+    /// The status of the response.
+    pub status: reqwest::StatusCode,
+    /// The size of the request body, or 0 if it had none. A body streamed from
+    /// a reader is counted as it is sent.
+    pub sent: u64,
+    /// The size of the response body, as much of it as was read.
+    pub received: u64,
+}
+
+/// A function the client tells about its requests, or their responses.
+type Observer<T> = std::sync::Arc<dyn Fn(&T) + Send + Sync>;
+
+/// A stream of bytes that counts them as they go through, and calls `on_end`
+/// with the count once it ends, or is dropped before it does.
+struct CountingStream<S> {
+    stream: S,
+    count: u64,
+    on_end: Option<Box<dyn FnOnce(u64) + Send + Sync>>,
+}
+
+impl<S> CountingStream<S> {
+    fn end(&mut self) {
+        if let Some(on_end) = self.on_end.take() {
+            on_end(self.count);
+        }
+    }
+}
+
+impl<S, E> futures::Stream for CountingStream<S>
+where
+    S: futures::Stream<Item = std::result::Result<bytes::Bytes, E>> + Unpin,
+{
+    type Item = S::Item;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        let item = futures::ready!(std::pin::Pin::new(&mut self.stream).poll_next(cx));
+        match &item {
+            Some(Ok(chunk)) => self.count += chunk.len() as u64,
+            Some(Err(_)) | None => self.end(),
+        }
+        std::task::Poll::Ready(item)
+    }
+}
+
+impl<S> Drop for CountingStream<S> {
+    fn drop(&mut self) {
+        self.end();
+    }
+}
+
+/// A function that changes the url of each request before it is sent. See
+/// `Client::with_interceptor`.
+type Interceptor = std::sync::Arc<dyn Fn(&mut reqwest::Url) + Send + Sync>;
+
+/// The `GET`s in flight, by token and url, when the client sends identical
+/// ones only once. See `Client::with_single_flight`.
+type InFlight = std::sync::Mutex<
+    std::collections::HashMap<String, std::sync::Arc<tokio::sync::OnceCell<SharedResponse>>>,
+>;
+
+/// The status, request id and body of a response shared by identical `GET`s,
+/// or why it could not be had.
+type SharedResponse =
+    std::result::Result<(reqwest::StatusCode, Option<String>, bytes::Bytes), ClientError>;
+
+/// A `multipart/*` response, like a document along with its metadata as JSON,
+/// split into its parts.
+#[derive(Clone, Debug, Default)]
+pub struct Multipart {
+    parts: Vec<MultipartPart>,
+}
+
+/// A part of a `Multipart` response.
+#[derive(Clone, Debug, Default)]
+pub struct MultipartPart {
+    /// The headers of the part, like its `Content-Type`.
+    pub headers: reqwest::header::HeaderMap,
+    /// The body of the part, as it was sent.
+    pub body: bytes::Bytes,
+}
+
+impl Multipart {
+    /// Split `body` into its parts, at the boundary its `content_type` names.
+    pub fn parse(content_type: &str, body: &[u8]) -> Result<Self> {
+        let mime: mime::Mime = content_type.parse()?;
+        let boundary = mime
+            .get_param(mime::BOUNDARY)
+            .ok_or_else(|| anyhow!("{} does not name a boundary", content_type))?;
+        let delimiter = format!("--{}", boundary).into_bytes();
+
+        // Skip the preamble, before the first delimiter.
+        let start = find_bytes(body, &delimiter)
+            .ok_or_else(|| anyhow!("multipart body has no boundary {}", boundary))?;
+        let mut rest = &body[start + delimiter.len()..];
+
+        let mut parts = Vec::new();
+        // The last delimiter is followed by `--`.
+        while !rest.starts_with(b"--") {
+            let line_end = find_bytes(rest, b"\r\n")
+                .ok_or_else(|| anyhow!("multipart body ends before its last boundary"))?;
+            rest = &rest[line_end + 2..];
+
+            let end = find_bytes(rest, &delimiter)
+                .ok_or_else(|| anyhow!("multipart body ends before its last boundary"))?;
+            // The line break before a delimiter belongs to the delimiter.
+            let part = &rest[..end];
+            parts.push(MultipartPart::parse(part.strip_suffix(b"\r\n").unwrap_or(part))?);
+            rest = &rest[end + delimiter.len()..];
+        }
+
+        Ok(Multipart { parts })
+    }
+
+    /// The parts, in the order they were sent.
+    pub fn parts(&self) -> &[MultipartPart] {
+        &self.parts
+    }
+
+    /// Go through the parts, in the order they were sent.
+    pub fn iter(&self) -> std::slice::Iter<'_, MultipartPart> {
+        self.parts.iter()
+    }
+}
+
+impl IntoIterator for Multipart {
+    type Item = MultipartPart;
+    type IntoIter = std::vec::IntoIter<MultipartPart>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.parts.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Multipart {
+    type Item = &'a MultipartPart;
+    type IntoIter = std::slice::Iter<'a, MultipartPart>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.parts.iter()
+    }
+}
+
+impl MultipartPart {
+    /// Parse a part: its headers, a blank line, and its body.
+    fn parse(part: &[u8]) -> Result<Self> {
+        let (head, body) = if let Some(body) = part.strip_prefix(b"\r\n") {
+            (&b""[..], body)
+        } else {
+            let i = find_bytes(part, b"\r\n\r\n")
+                .ok_or_else(|| anyhow!("multipart part has no blank line after its headers"))?;
+            (&part[..i], &part[i + 4..])
+        };
+
+        let mut headers = reqwest::header::HeaderMap::new();
+        for line in head.split(|b| *b == b'\n') {
+            let line = line.strip_suffix(b"\r").unwrap_or(line);
+            if line.is_empty() {
+                continue;
+            }
+            let colon = line
+                .iter()
+                .position(|b| *b == b':')
+                .ok_or_else(|| anyhow!("bad multipart header {}", String::from_utf8_lossy(line)))?;
+            let value = String::from_utf8_lossy(&line[colon + 1..]);
+            headers.append(
+                reqwest::header::HeaderName::from_bytes(&line[..colon])?,
+                reqwest::header::HeaderValue::from_str(value.trim())?,
+            );
+        }
+
+        Ok(MultipartPart {
+            headers,
+            body: bytes::Bytes::copy_from_slice(body),
+        })
+    }
+
+    /// The `Content-Type` of the part, if it has one.
+    pub fn content_type(&self) -> Option<&str> {
+        self.headers
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|h| h.to_str().ok())
+    }
+
+    /// Parse the body of the part as JSON, say for the metadata of a document.
+    pub fn json<T: serde::de::DeserializeOwned>(&self) -> Result<T> {
+        Ok(serde_json::from_slice(&self.body)?)
+    }
+}
+
+/// Where `needle` first is in `haystack`.
+fn find_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+/// A request for `Client::execute_all`. It is given a clone of the client to
+/// make its call with, so requests to different endpoints can go in one batch.
+pub type BoxedRequest<T> = Box<
+    dyn FnOnce(Client) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<T>> + Send>>
+        + Send,
+>;
+
+/// Where the client gets the time from, for its deadlines, the waits before
+/// retries and when its access token expires. This is the system clock unless
+/// the client is given another with `Client::with_clock`.
+#[async_trait::async_trait]
+pub trait Clock: Send + Sync {
+    /// The time now, to measure how long things take.
+    fn now(&self) -> std::time::Instant;
+    /// The time now, as a date, to compare with the dates servers send.
+    fn now_utc(&self) -> chrono::DateTime<chrono::Utc>;
+    /// Wait until `duration` has passed.
+    async fn sleep(&self, duration: std::time::Duration);
+}
+
+/// The system clock.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+#[async_trait::async_trait]
+impl Clock for SystemClock {
+    fn now(&self) -> std::time::Instant {
+        std::time::Instant::now()
+    }
+
+    fn now_utc(&self) -> chrono::DateTime<chrono::Utc> {
+        chrono::Utc::now()
+    }
+
+    async fn sleep(&self, duration: std::time::Duration) {
+        tokio::time::sleep(duration).await
+    }
+}
+
+/// A clock that only moves when it is told to, for testing code that depends
+/// on the time. Sleeping on it moves it forward at once rather than waiting.
+/// Clones share the same time.
+#[derive(Clone, Debug)]
+pub struct MockClock {
+    start: std::time::Instant,
+    start_utc: chrono::DateTime<chrono::Utc>,
+    elapsed: std::sync::Arc<std::sync::Mutex<std::time::Duration>>,
+}
+
+impl MockClock {
+    /// A clock stopped at the current time.
+    pub fn new() -> Self {
+        MockClock {
+            start: std::time::Instant::now(),
+            start_utc: chrono::Utc::now(),
+            elapsed: Default::default(),
+        }
+    }
+
+    /// Move the clock forward by `duration`.
+    pub fn advance(&self, duration: std::time::Duration) {
+        *self.elapsed.lock().unwrap() += duration;
+    }
+
+    fn elapsed(&self) -> std::time::Duration {
+        *self.elapsed.lock().unwrap()
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        MockClock::new()
+    }
+}
+
+#[async_trait::async_trait]
+impl Clock for MockClock {
+    fn now(&self) -> std::time::Instant {
+        self.start + self.elapsed()
+    }
+
+    fn now_utc(&self) -> chrono::DateTime<chrono::Utc> {
+        self.start_utc + chrono::Duration::from_std(self.elapsed()).expect("mock clock moved too far")
+    }
+
+    async fn sleep(&self, duration: std::time::Duration) {
+        self.advance(duration)
+    }
+}
+
+/// How the client turns a JSON request body into bytes. Bodies go through
+/// `serde_json` unless the client is given another serializer with
+/// `Client::with_body_serializer`, like `CanonicalJson` for a signing scheme
+/// that needs the keys in order.
+pub trait BodySerializer: Send + Sync {
+    /// Serialize `body`, the request body as JSON.
+    fn serialize(&self, body: &serde_json::Value) -> Result<Vec<u8>>;
+}
+
+/// Canonical JSON: the keys of every object sorted, and no whitespace.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CanonicalJson;
+
+impl BodySerializer for CanonicalJson {
+    fn serialize(&self, body: &serde_json::Value) -> Result<Vec<u8>> {
+        let mut out = Vec::new();
+        write_canonical_json(body, &mut out)?;
+        Ok(out)
+    }
+}
+
+fn write_canonical_json(value: &serde_json::Value, out: &mut Vec<u8>) -> Result<()> {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort();
+            out.push(b'{');
+            for (i, key) in keys.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(b',');
+                }
+                serde_json::to_writer(&mut *out, key)?;
+                out.push(b':');
+                write_canonical_json(&map[key], out)?;
+            }
+            out.push(b'}');
+        }
+        serde_json::Value::Array(items) => {
+            out.push(b'[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(b',');
+                }
+                write_canonical_json(item, out)?;
+            }
+            out.push(b']');
+        }
+        _ => serde_json::to_writer(&mut *out, value)?,
+    }
+    Ok(())
+}
+
+#[cfg(not(any(feature = "rustls-tls", feature = "native-tls")))]
+compile_error!("enable the `rustls-tls` or the `native-tls` feature for the client to use TLS");
+
+/// The TLS implementation the client connects with. Each one is behind the
+/// cargo feature of the same name, `rustls-tls` is on by default.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TlsBackend {
+    /// rustls, with the Mozilla root certificates built in.
+    #[cfg(feature = "rustls-tls")]
+    Rustls,
+    /// The platform's TLS, with the system's trust store. Some enterprise
+    /// environments require this.
+    #[cfg(feature = "native-tls")]
+    NativeTls,
+}
+
+impl Default for TlsBackend {
+    #[allow(unreachable_code)]
+    fn default() -> Self {
+        #[cfg(feature = "rustls-tls")]
+        return TlsBackend::Rustls;
+        #[cfg(feature = "native-tls")]
+        return TlsBackend::NativeTls;
+    }
+}
+
+/// The settings the reqwest client is built with. We keep them around so the
+/// reqwest client can be rebuilt when one of them changes.
+#[derive(Clone, Debug, Default)]
+struct HttpConfig {
+    cookie_store: bool,
+    redirect_policy: RedirectPolicy,
+    // The proxies from the environment, unless they were set.
+    proxy: Option<ProxyConfig>,
+    tls_backend: TlsBackend,
+    // The pool settings, reqwest's defaults unless they were set.
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<std::time::Duration>,
+}
+
+impl HttpConfig {
+    fn build(&self) -> reqwest::Result<reqwest::Client> {
+        let proxy = self.proxy.clone().unwrap_or_else(ProxyConfig::from_env);
+        let mut builder = reqwest::Client::builder()
+            .cookie_store(self.cookie_store)
+            .redirect(self.redirect_policy.into())
+            // We pick the proxy ourselves, rather than leave it to reqwest.
+            .no_proxy();
+        builder = match self.tls_backend {
+            #[cfg(feature = "rustls-tls")]
+            TlsBackend::Rustls => builder.use_rustls_tls(),
+            #[cfg(feature = "native-tls")]
+            TlsBackend::NativeTls => builder.use_native_tls(),
+        };
+        if let Some(n) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(n);
+        }
+        if let Some(timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(timeout);
+        }
+        if proxy.http.is_some() || proxy.https.is_some() {
+            builder = builder.proxy(reqwest::Proxy::custom(move |url| proxy.proxy_for(url)));
+        }
+        builder.build()
+    }
+}
+
+/// The proxies requests are sent through, by the scheme of their URL, and the
+/// hosts that bypass them.
+#[derive(Clone, Debug, Default)]
+struct ProxyConfig {
+    http: Option<reqwest::Url>,
+    https: Option<reqwest::Url>,
+    no_proxy: Vec<String>,
+}
+
+impl ProxyConfig {
+    /// The proxies in `HTTP_PROXY`, `HTTPS_PROXY` and `NO_PROXY`, or in their
+    /// lowercase versions.
+    fn from_env() -> Self {
+        let var = |name: &str| {
+            std::env::var(name)
+                .or_else(|_| std::env::var(name.to_lowercase()))
+                .ok()
+                .filter(|v| !v.is_empty())
+        };
+
+        ProxyConfig {
+            http: var("HTTP_PROXY").and_then(|p| ProxyConfig::parse_url(&p)),
+            https: var("HTTPS_PROXY").and_then(|p| ProxyConfig::parse_url(&p)),
+            no_proxy: ProxyConfig::parse_no_proxy(&var("NO_PROXY").unwrap_or_default()),
+        }
+    }
+
+    /// A proxy URL, which defaults to `http` when it has no scheme, like
+    /// `proxy.local:3128`.
+    fn parse_url(url: &str) -> Option<reqwest::Url> {
+        if url.contains("://") {
+            url.parse().ok()
+        } else {
+            format!("http://{}", url).parse().ok()
+        }
+    }
+
+    /// The hosts in a comma separated list, in the format of `NO_PROXY`.
+    fn parse_no_proxy(no_proxy: &str) -> Vec<String> {
+        no_proxy
+            .split(',')
+            .map(|h| h.trim().trim_start_matches('.').to_lowercase())
+            .filter(|h| !h.is_empty())
+            .collect()
+    }
+
+    /// The proxy to send a request to the URL through, if any. A host in
+    /// `no_proxy` bypasses it, as do its subdomains, and `*` bypasses it for all.
+    fn proxy_for(&self, url: &reqwest::Url) -> Option<reqwest::Url> {
+        let proxy = match url.scheme() {
+            "http" => self.http.as_ref(),
+            "https" => self.https.as_ref(),
+            _ => None,
+        }?;
+
+        let host = url.host_str()?.trim_start_matches('[').trim_end_matches(']');
+        let bypass = self
+            .no_proxy
+            .iter()
+            .any(|h| h == "*" || h == host || host.ends_with(&format!(".{}", h)));
+        if bypass {
+            None
+        } else {
+            Some(proxy.clone())
+        }
+    }
+}
+
+/// What responses are parsed into. With the `validate-responses` feature they
+/// also need a JSON schema, to check the responses against.
+#[cfg(not(feature = "validate-responses"))]
+pub trait ResponseType: serde::de::DeserializeOwned + 'static + Send {}
+
+#[cfg(not(feature = "validate-responses"))]
+impl<T> ResponseType for T where T: serde::de::DeserializeOwned + 'static + Send {}
+
+/// What responses are parsed into. With the `validate-responses` feature they
+/// also need a JSON schema, to check the responses against.
+#[cfg(feature = "validate-responses")]
+pub trait ResponseType: serde::de::DeserializeOwned + schemars::JsonSchema + 'static + Send {}
+
+#[cfg(feature = "validate-responses")]
+impl<T> ResponseType for T where
+    T: serde::de::DeserializeOwned + schemars::JsonSchema + 'static + Send
+{
+}
+
+/// Check a JSON response body against the schema of the type it was parsed
+/// into, which is generated from the spec. Serde lets through more than the
+/// spec does, say unknown enum values or nulls for required fields, so this
+/// catches where the API and the spec part ways. Each difference is logged,
+/// and returned as a `ClientError::SchemaMismatch`.
+#[cfg(feature = "validate-responses")]
+pub fn validate_response<T>(body: &[u8]) -> Result<()>
+where
+    T: schemars::JsonSchema + 'static,
+{
+    if std::any::TypeId::of::<T>() == std::any::TypeId::of::<()>() {
+        return Ok(());
+    }
+    // Text and bytes are handed out as they are, there is no JSON to check.
+    let instance: serde_json::Value = match serde_json::from_slice(body) {
+        Ok(instance) => instance,
+        Err(_) => return Ok(()),
+    };
+
+    let schema = serde_json::to_value(schemars::schema_for!(T))?;
+    let schema = jsonschema::JSONSchema::compile(&schema).map_err(|e| {
+        anyhow!(
+            "invalid schema for {}: {}",
+            std::any::type_name::<T>(),
+            e
+        )
+    })?;
+    let errors: Vec<String> = match schema.validate(&instance) {
+        Ok(()) => return Ok(()),
+        Err(errors) => errors
+            .map(|e| format!("{}: {}", e.instance_path, e))
+            .collect(),
+    };
+
+    for e in &errors {
+        log::warn!(
+            "response for {} does not match its schema at {}",
+            std::any::type_name::<T>(),
+            e
+        );
+    }
+    Err(ClientError::SchemaMismatch { errors }.into())
+}"#;
+
+pub const CLIENT_ERROR_TEMPLATE: &str = r#"/// Errors returned by the client that callers may want to handle on their own.
+/// The functions of the API return them as they are, see `ClientResult`, so a
+/// caller can tell a rate limit from an expired token with a `match`. The other
+/// functions of the client return them wrapped in an `anyhow::Error`, use
+/// `downcast_ref` to get at them there.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClientError {
+    /// The token endpoint refused the grant, for example because the refresh
+    /// token expired or was revoked. The user needs to authorize the app again.
+    AuthExpired {
+        error: String,
+        error_description: String,
+    },
+    /// The API responded with an error status. `error` is the body of the
+    /// response, if there was one, and `request_id` the id the client sent
+    /// with the request, if it sends them. `call` is the call of the API that
+    /// failed, like `GET /users/{userId} [userId=me]`, once a function of the
+    /// API returns the error.
+    HttpError {
+        status: reqwest::StatusCode,
+        error: String,
+        request_id: Option<String>,
+        call: Option<String>,
+    },
+    /// The deadline of the call passed before it finished.
+    DeadlineExceeded,
+    /// The API refused the request because the token lacks a scope it needs, as
+    /// its `WWW-Authenticate` header says. `required` are the scopes it listed.
+    InsufficientScope { required: Vec<String> },
+    /// The client's rate limit would have the request wait over its max wait.
+    /// `retry_after` is how long until the request would get its turn.
+    RateLimited { retry_after: std::time::Duration },
+    /// The API failed too many calls in a row, so the client's circuit breaker
+    /// stopped calling it. `retry_after` is how long until it lets a call through.
+    CircuitOpen { retry_after: std::time::Duration },
+    /// The response does not match the schema of the type it was parsed into,
+    /// see `validate_response`. Only returned with the `validate-responses`
+    /// feature. `errors` says where and how, one for each difference.
+    SchemaMismatch { errors: Vec<String> },
+    /// The call was passed parameters the API takes one or the other of, not
+    /// both, so it was not sent. `params` are their names.
+    ConflictingParams { params: Vec<String> },
+    /// A download ended before all of the body the response said it had was
+    /// written, see `download_to_file`.
+    IncompleteDownload { expected: u64, written: u64 },
+    /// The request could not be sent, or its response read, say because the
+    /// connection was refused or dropped.
+    Transport { error: String },
+    /// A body could not be parsed as the JSON of its type, or a request body
+    /// serialized.
+    Decode { error: String },
+    /// Any other error, like a url that does not parse.
+    Other { error: String },
+}
+
+impl ClientError {
+    /// The HTTP status that best describes the error.
+    pub fn status(&self) -> reqwest::StatusCode {
+        match self {
+            ClientError::AuthExpired { .. } => reqwest::StatusCode::UNAUTHORIZED,
+            ClientError::HttpError { status, .. } => *status,
+            ClientError::DeadlineExceeded => reqwest::StatusCode::GATEWAY_TIMEOUT,
+            ClientError::InsufficientScope { .. } => reqwest::StatusCode::FORBIDDEN,
+            ClientError::RateLimited { .. } => reqwest::StatusCode::TOO_MANY_REQUESTS,
+            ClientError::CircuitOpen { .. } => reqwest::StatusCode::SERVICE_UNAVAILABLE,
+            ClientError::SchemaMismatch { .. } => reqwest::StatusCode::BAD_GATEWAY,
+            ClientError::ConflictingParams { .. } => reqwest::StatusCode::BAD_REQUEST,
+            ClientError::IncompleteDownload { .. } => reqwest::StatusCode::BAD_GATEWAY,
+            ClientError::Transport { .. } => reqwest::StatusCode::BAD_GATEWAY,
+            ClientError::Decode { .. } => reqwest::StatusCode::BAD_GATEWAY,
+            ClientError::Other { .. } => reqwest::StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    /// Say which call of the API failed, see `CallContext`.
+    fn with_call(self, call: String) -> Self {
+        match self {
+            ClientError::HttpError {
+                status,
+                error,
+                request_id,
+                ..
+            } => ClientError::HttpError {
+                status,
+                error,
+                request_id,
+                call: Some(call),
+            },
+            ClientError::Transport { error } => ClientError::Transport {
+                error: format!("{}: {}", call, error),
+            },
+            ClientError::Decode { error } => ClientError::Decode {
+                error: format!("{}: {}", call, error),
+            },
+            ClientError::Other { error } => ClientError::Other {
+                error: format!("{}: {}", call, error),
+            },
+            e => e,
+        }
+    }
+}
+
+impl std::fmt::Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::AuthExpired {
+                error,
+                error_description,
+            } => write!(f, "authorization expired: {}: {}", error, error_description),
+            ClientError::HttpError {
+                status,
+                error,
+                request_id,
+                call,
+            } => {
+                if let Some(call) = call {
+                    write!(f, "{}: ", call)?;
+                }
+                if error.is_empty() {
+                    write!(f, "code: {}, empty response", status)?;
+                } else {
+                    write!(f, "code: {}, error: {:?}", status, error)?;
+                }
+                if let Some(request_id) = request_id {
+                    write!(f, ", request id: {}", request_id)?;
+                }
+                Ok(())
+            }
+            ClientError::DeadlineExceeded => write!(f, "deadline exceeded"),
+            ClientError::InsufficientScope { required } => {
+                write!(f, "insufficient scope, requires: {}", required.join(" "))
+            }
+            ClientError::RateLimited { retry_after } => {
+                write!(f, "rate limited, retry after {:?}", retry_after)
+            }
+            ClientError::CircuitOpen { retry_after } => {
+                write!(f, "circuit open, retry after {:?}", retry_after)
+            }
+            ClientError::SchemaMismatch { errors } => {
+                write!(f, "response does not match its schema: {}", errors.join("; "))
+            }
+            ClientError::ConflictingParams { params } => {
+                write!(f, "pass only one of the parameters: {}", params.join(", "))
+            }
+            ClientError::IncompleteDownload { expected, written } => {
+                write!(f, "download incomplete, wrote {} of {} bytes", written, expected)
+            }
+            ClientError::Transport { error }
+            | ClientError::Decode { error }
+            | ClientError::Other { error } => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+/// Respond with the status of the error, so a handler can return the error
+/// of a call it passes through as is.
+#[cfg(feature = "axum")]
+impl axum_core::response::IntoResponse for ClientError {
+    fn into_response(self) -> axum_core::response::Response {
+        (self.status(), self.to_string()).into_response()
+    }
+}
+
+/// What the functions of the API return, so their errors can be matched on
+/// without a `downcast_ref`. A `ClientError` converts to an `anyhow::Error`
+/// with `?` as well.
+pub type ClientResult<T> = std::result::Result<T, ClientError>;
+
+/// Sort an error of the client into the variant it fits, with the
+/// `ClientError` it wraps as it is, if it wraps one.
+impl From<anyhow::Error> for ClientError {
+    fn from(e: anyhow::Error) -> Self {
+        if let Some(e) = e.downcast_ref::<ClientError>() {
+            return e.clone();
+        }
+
+        let error = format!("{:#}", e);
+        if let Some(r) = e.downcast_ref::<reqwest::Error>() {
+            if r.is_decode() {
+                return ClientError::Decode { error };
+            }
+            return ClientError::Transport { error };
+        }
+        if e.is::<serde_json::Error>() {
+            ClientError::Decode { error }
+        } else {
+            ClientError::Other { error }
+        }
+    }
+}
+
+/// Turn the error of a call into a `ClientError` that says which call it was,
+/// the way `anyhow::Context` would add it to an `anyhow::Error`.
+pub trait CallContext<T> {
+    fn with_context<C, F>(self, f: F) -> ClientResult<T>
+    where
+        C: std::fmt::Display,
+        F: FnOnce() -> C;
+}
+
+impl<T> CallContext<T> for anyhow::Result<T> {
+    fn with_context<C, F>(self, f: F) -> ClientResult<T>
+    where
+        C: std::fmt::Display,
+        F: FnOnce() -> C,
+    {
+        self.map_err(|e| ClientError::from(e).with_call(f().to_string()))
+    }
+}"#;
+
+/// The client of the `blocking` module, which sends its requests with
+/// `reqwest::blocking` rather than on an async runtime.
+pub const BLOCKING_CLIENT_TEMPLATE: &str = r#"use anyhow::{anyhow, Result};
+
+/// Entrypoint for interacting with the API without an async runtime.
+///
+/// It retries as the async client does, see `with_retry`, but has none
+/// of its other settings. Make one from an async client, with `From`, to send
+/// with the host, token and retry policy of that client, say once it has
+/// refreshed its token. It must not be used from async code, where it would
+/// block the runtime.
+#[derive(Clone)]
+pub struct Client {
+    host: String,
+    token: String,
+    retry_policy: crate::RetryPolicy,
+
+    client: reqwest::blocking::Client,
+}
+
+impl From<&crate::Client> for Client {
+    fn from(client: &crate::Client) -> Self {
+        Client {
+            host: client.host.clone(),
+            token: client.token.clone(),
+            retry_policy: client.retry_policy,
+            client: reqwest::blocking::Client::new(),
+        }
+    }
+}
+
+/// The blocking form of [`crate::utils::multipart_form`].
+#[allow(dead_code)]
+fn multipart_form<B>(body: &B) -> serde_json::Result<reqwest::blocking::multipart::Form>
+where
+    B: serde::Serialize + ?Sized,
+{
+    let mut form = reqwest::blocking::multipart::Form::new();
+    if let serde_json::Value::Object(fields) = serde_json::to_value(body)? {
+        for (name, value) in fields {
+            form = match value {
+                serde_json::Value::Null => form,
+                serde_json::Value::String(s) => form.text(name, s),
+                value => form.text(name, value.to_string()),
+            };
+        }
+    }
+    Ok(form)
+}
+
+impl Client {
+    /// Create a new blocking client, which sends `token` as the bearer token
+    /// of each request.
+    pub fn new<T>(token: T) -> Self
+    where
+        T: ToString,
+    {
+        Client {
+            host: crate::DEFAULT_HOST.to_string(),
+            token: token.to_string(),
+            retry_policy: crate::RetryPolicy::default(),
+            client: reqwest::blocking::Client::new(),
+        }
+    }
+
+    /// Override the default host for the client.
+    pub fn with_host<H>(&self, host: H) -> Self
+    where
+        H: ToString,
+    {
+        let mut c = self.clone();
+        c.host = host.to_string();
+        c
+    }
+
+    /// Send `token` as the bearer token instead, say one refreshed since the
+    /// client was made.
+    pub fn with_token<T>(&self, token: T) -> Self
+    where
+        T: ToString,
+    {
+        let mut c = self.clone();
+        c.token = token.to_string();
+        c
+    }
+
+    /// Retry the requests that fail with a connection error, a `429 Too Many
+    /// Requests` or a server error, as the async client does, with the thread
+    /// sleeping between the tries. A form upload only gets the one try.
+    pub fn with_retry(&self, policy: crate::RetryPolicy) -> Self {
+        let mut c = self.clone();
+        c.retry_policy = policy;
+        c
+    }
+
+    fn encode_path(&self, pc: &str) -> String {
+        crate::progenitor_support::encode_path(pc)
+    }
+
+    fn serialize_body<B>(&self, body: &B) -> Result<reqwest::Body>
+    where
+        B: serde::Serialize + ?Sized,
+    {
+        Ok(serde_json::to_vec(body)?.into())
+    }
+
+    /// Send the request, and send it again as the retry policy says while it
+    /// fails.
+    fn send(&self, req: reqwest::blocking::RequestBuilder) -> Result<reqwest::blocking::Response> {
+        let policy = &self.retry_policy;
+        let mut backoff = policy.initial_backoff;
+        let mut waited = std::time::Duration::ZERO;
+        for _ in 0..policy.max_retries {
+            // A form is read as it is sent, so it can not be sent again.
+            let r = match req.try_clone() {
+                Some(r) => r,
+                None => break,
+            };
+
+            let (last, wait) = match r.send() {
+                Ok(resp) if !crate::RetryPolicy::should_retry(resp.status()) => return Ok(resp),
+                Ok(resp) => {
+                    let wait = crate::RetryPolicy::retry_after(resp.headers(), chrono::Utc::now())
+                        .unwrap_or_else(|| policy.delay(backoff));
+                    (Ok(resp), wait)
+                }
+                Err(e) if e.is_connect() || e.is_timeout() => (Err(e), policy.delay(backoff)),
+                Err(e) => return Err(e.into()),
+            };
+
+            // Give up with what we got if the wait goes over the retry budget.
+            waited += wait;
+            if policy.max_total_delay.map_or(false, |max| waited > max) {
+                return Ok(last?);
+            }
+
+            std::thread::sleep(wait);
+            backoff = backoff.saturating_mul(2);
+        }
+
+        Ok(req.send()?)
+    }
+
+    fn request_raw(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        body: Option<reqwest::Body>,
+    ) -> Result<reqwest::blocking::Response> {
+        let mut req = self
+            .client
+            .request(method, &format!("{}{}", self.host, uri))
+            .header(reqwest::header::ACCEPT, "application/json")
+            .bearer_auth(&self.token);
+
+        if let Some(body) = body {
+            // The bodies of the functions are serialized JSON or the bytes of
+            // an upload, which are in memory, not a stream the blocking client
+            // would have to poll. They go with the same content type as from
+            // the async client.
+            let bytes = body
+                .as_bytes()
+                .ok_or_else(|| anyhow!("the blocking client can not send a streamed body"))?;
+            req = req
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(bytes.to_vec());
+        }
+
+        self.send(req)
+    }
+
+    fn request<Out>(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        body: Option<reqwest::Body>,
+    ) -> Result<Out>
+    where
+        Out: crate::ResponseType,
+    {
+        let response = self.request_raw(method, uri, body)?;
+
+        let status = response.status();
+        let response_body = response.bytes()?;
+
+        crate::Client::parse_response(status, None, &response_body)
+    }
+
+    /// Send a `multipart/form-data` body, with the content type and boundary
+    /// the form sets.
+    #[allow(dead_code)]
+    fn request_form<Out>(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        form: reqwest::blocking::multipart::Form,
+    ) -> Result<Out>
+    where
+        Out: crate::ResponseType,
+    {
+        let req = self
+            .client
+            .request(method, &format!("{}{}", self.host, uri))
+            .header(reqwest::header::ACCEPT, "application/json")
+            .bearer_auth(&self.token)
+            .multipart(form);
+        let response = self.send(req)?;
+
+        let status = response.status();
+        let response_body = response.bytes()?;
+
+        crate::Client::parse_text_response(status, None, &response_body)
+    }
+
+    /// Ask for a response that is not JSON, say a PDF, which is handed out as
+    /// the `bytes::Bytes` or `String` it is.
+    #[allow(dead_code)]
+    fn request_with_accept_mime<Out>(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        accept_mime_type: &str,
+    ) -> Result<Out>
+    where
+        Out: crate::ResponseType,
+    {
+        let req = self
+            .client
+            .request(method, &format!("{}{}", self.host, uri))
+            .header(reqwest::header::ACCEPT, accept_mime_type)
+            .bearer_auth(&self.token);
+        let response = self.send(req)?;
+
+        let status = response.status();
+        let response_body = response.bytes()?;
+
+        crate::Client::parse_text_response(status, None, &response_body)
+    }
+
+    #[allow(dead_code)]
+    fn get<D>(&self, uri: &str, message: Option<reqwest::Body>) -> Result<D>
+    where
+        D: crate::ResponseType,
+    {
+        self.request(reqwest::Method::GET, uri, message)
+    }
+
+    #[allow(dead_code)]
+    fn post<D>(&self, uri: &str, message: Option<reqwest::Body>) -> Result<D>
+    where
+        D: crate::ResponseType,
+    {
+        self.request(reqwest::Method::POST, uri, message)
+    }
+
+    #[allow(dead_code)]
+    fn patch<D>(&self, uri: &str, message: Option<reqwest::Body>) -> Result<D>
+    where
+        D: crate::ResponseType,
+    {
+        self.request(reqwest::Method::PATCH, uri, message)
+    }
+
+    #[allow(dead_code)]
+    fn put<D>(&self, uri: &str, message: Option<reqwest::Body>) -> Result<D>
+    where
+        D: crate::ResponseType,
+    {
+        self.request(reqwest::Method::PUT, uri, message)
+    }
+
+    #[allow(dead_code)]
+    fn delete<D>(&self, uri: &str, message: Option<reqwest::Body>) -> Result<D>
+    where
+        D: crate::ResponseType,
+    {
+        self.request(reqwest::Method::DELETE, uri, message)
+    }
+
+    /// Delete what `uri` is for, as `delete` does, and return whether there
+    /// was anything to delete, rather than an error if there was not.
+    #[allow(dead_code)]
+    fn delete_if_exists(&self, uri: &str, message: Option<reqwest::Body>) -> Result<bool> {
+        let response = self.request_raw(reqwest::Method::DELETE, uri, message)?;
+
+        let status = response.status();
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Ok(false);
+        }
+        let response_body = response.bytes()?;
+
+        crate::Client::parse_response::<()>(status, None, &response_body)?;
+        Ok(true)
+    }
+}
+"#;
+
+/// The `mod.rs` of the `blocking` module: the client, and an accessor on it
+/// for each tag with functions that can block.
+pub fn generate_blocking_client(proper_name: &str, tags: &[(String, String)]) -> String {
+    let mut out = format!(
+        "//! The {} API for callers that are not async, with the functions of the\n\
+         //! async client under the same names and with the same parameters, but\n\
+         //! for the ones streaming their upload or response, which need a runtime.\n\n",
+        proper_name
+    );
+
+    for (tag, _) in tags {
+        out.push_str(&format!("pub mod {};\n", tag));
+    }
+    out.push('\n');
+
+    out.push_str(BLOCKING_CLIENT_TEMPLATE);
+
+    out.push_str("\nimpl Client {\n");
+    for (tag, struct_name) in tags {
+        out.push_str(&format!(
+            "    /// The blocking form of [`crate::Client::{}`].\n    \
+             pub fn {}(&self) -> {}::{} {{\n        \
+             {}::{}::new(self.clone())\n    }}\n\n",
+            tag, tag, tag, struct_name, tag, struct_name
+        ));
+    }
+    out.push_str("}\n");
+
+    out
+}
+
+pub fn generate_client_generic_token(
+    proper_name: &str,
+    token_endpoint: &str,
+    user_consent_endpoint: &str,
+    add_post_header: &str,
+) -> String {
+    let mut new_from_env = basic_new_from_env(proper_name, add_post_header);
+    if proper_name.starts_with("Google") {
+        new_from_env = GOOGLE_NEW_FROM_ENV_TEMPLATE.to_string();
+    }
+
+    let add_post_header_struct = if add_post_header.is_empty() {
+        "".to_string()
+    } else {
+        format!("{}: String,", to_snake_case(add_post_header))
+    };
+
+    let add_post_header_args = if add_post_header.is_empty() {
+        "".to_string()
+    } else {
+        format!("{}: P,", to_snake_case(add_post_header))
+    };
+
+    let add_post_header_args_where = if add_post_header.is_empty() {
+        "".to_string()
+    } else {
+        "P: ToString,".to_string()
+    };
+
+    let add_post_header_fn = if add_post_header.is_empty() {
+        "".to_string()
+    } else {
+        format!(
+            "{} : {}.to_string(),",
+            to_snake_case(add_post_header),
+            to_snake_case(add_post_header)
+        )
+    };
+
+    let add_post_header_type = if !add_post_header.is_empty() {
+        ", P".to_string()
+    } else {
+        "".to_string()
+    };
+
+    // Zoom Phone accounts can have more than one site, see `scoped_for_site`.
+    // Unassigning a calling plan can check it first, see
+    // `calling_plan::with_calling_plan_check`.
+    // Zoom reads dates in the account's timezone, see `timezone::with_timezone`.
+    let (site_struct, site_fn, site_functions) = if proper_name == "Zoom" {
+        (
+            "site_id: Option<String>,\n    check_calling_plans: bool,\n    timezone: Option<chrono_tz::Tz>,",
+            "site_id: None,\n    check_calling_plans: false,\n    timezone: None,",
+            ZOOM_SITE_TEMPLATE,
+        )
+    } else {
+        ("", "", "")
+    };
+
+    // `health_with_scopes` needs a request any token may make: Zoom's users can
+    // all get their own profile, the other APIs are probed at their base URL.
+    let health_path = if proper_name == "Zoom" {
+        "/users/me"
+    } else {
+        ""
+    };
+
+    format!(
+        r#"use std::env;
+
+const TOKEN_ENDPOINT: &str = "https://{}";
+const USER_CONSENT_ENDPOINT: &str = "https://{}";
+const HEALTH_PATH: &str = "{}";
+
+/// Entrypoint for interacting with the API client.
+#[derive(Clone)]
+pub struct Client {{
+    host: String,
+    token: String,
+    // This will expire within a certain amount of time as determined by the
+    // expiration date passed back in the initial request.
+    refresh_token: String,
+    // The scopes granted to the token, as returned by the token endpoint.
+    scope: String,
+    // When the token expires by the client's clock, if the token endpoint said.
+    token_expires_at: Option<std::time::Instant>,
+    client_id: String,
+    client_secret: String,
+    redirect_uri: String,
+    {}
+    {}
+
+    client: reqwest::Client,
+    http_config: HttpConfig,
+    semaphore: Option<std::sync::Arc<tokio::sync::Semaphore>>,
+    default_query: Vec<(String, String)>,
+    retry_policy: RetryPolicy,
+    deadline: Option<std::time::Instant>,
+    deadline_header: bool,
+    request_id: Option<RequestId>,
+    clock: std::sync::Arc<dyn Clock>,
+    body_serializer: Option<std::sync::Arc<dyn BodySerializer>>,
+    in_flight: Option<std::sync::Arc<InFlight>>,
+    rate_limiter: Option<std::sync::Arc<RateLimiter>>,
+    circuit_breaker: Option<std::sync::Arc<CircuitBreaker>>,
+    fixtures: Option<Fixtures>,
+    on_request: Option<Observer<RequestInfo>>,
+    on_response: Option<Observer<ResponseInfo>>,
+    request_body_limit: Option<usize>,
+    default_page_size: Option<i64>,
+    on_retry_budget: Option<Observer<RetryBudgetInfo>>,
+    interceptor: Option<Interceptor>,
+    on_body_size: Option<Observer<BodySizeInfo>>,
+    streamed_sent: Option<std::sync::Arc<std::sync::atomic::AtomicU64>>,
+    #[cfg(any(test, feature = "failpoints"))]
+    failpoints: Option<std::sync::Arc<Failpoints>>,
+    path_cache: std::sync::Arc<PathCache>,
+    last_rate_limit: std::sync::Arc<std::sync::Mutex<Option<RateLimitHeaders>>>,
+}}
+
+{}
+
+impl Client {{
+    /// Create a new Client struct. It takes a type that can convert into
+    /// an &str (`String` or `Vec<u8>` for example). As long as the function is
+    /// given a valid API key your requests will work.
+    pub fn new<I, K, R, T, Q{}>(
+        client_id: I,
+        client_secret: K,
+        redirect_uri: R,
+        token: T,
+        refresh_token: Q,
+        {}
+    ) -> Self
+    where
+        I: ToString,
+        K: ToString,
+        R: ToString,
+        T: ToString,
+        Q: ToString,
+        {}
+    {{
+        let http_config = HttpConfig::default();
+        let client = http_config.build();
+        match client {{
+            Ok(c) => {{
+                // We do not refresh the access token here since we leave that up to the
+                // user to do so they can re-save it to their database.
+                // TODO: But in the future we should save the expires in date and refresh it
+                // if it needs to be refreshed.
+                //
+                Client {{
+                    host: DEFAULT_HOST.to_string(),
+                    client_id: client_id.to_string(),
+                    client_secret: client_secret.to_string(),
+                    redirect_uri: redirect_uri.to_string(),
+                    token: token.to_string(),
+                    refresh_token: refresh_token.to_string(),
+                    scope: String::new(),
+                    token_expires_at: None,
+                    {}
+                    {}
+
+                    client: c,
+                    http_config,
+                    semaphore: None,
+                    default_query: Vec::new(),
+                    retry_policy: RetryPolicy::default(),
+                    deadline: None,
+                    deadline_header: false,
+                    request_id: None,
+                    clock: std::sync::Arc::new(SystemClock),
+                    body_serializer: None,
+                    in_flight: None,
+                    rate_limiter: None,
+                    circuit_breaker: None,
+                    fixtures: None,
+                    on_request: None,
+                    on_response: None,
+                    request_body_limit: None,
+                    default_page_size: None,
+                    on_retry_budget: None,
+                    interceptor: None,
+                    on_body_size: None,
+                    streamed_sent: None,
+                    #[cfg(any(test, feature = "failpoints"))]
+                    failpoints: None,
+                    path_cache: std::sync::Arc::new(PathCache::new(PATH_CACHE_CAPACITY)),
+                    last_rate_limit: Default::default(),
+                }}
+            }}
+            Err(e) => panic!("creating reqwest client failed: {{:?}}", e),
+        }}
+    }}
+
+    /// Override the default host for the client.
+    pub fn with_host<H>(&self, host: H) -> Self
+    where
+        H: ToString,
+    {{
+        let mut c = self.clone();
+        c.host = host.to_string();
+        c
+     }}
+
+    /// Keep cookies set by the API and send them back on later requests.
+    /// This is needed for endpoints that rely on a session (or CSRF) cookie
+    /// rather than on the token alone.
+    pub fn with_cookie_store(&self) -> Self {{
+        let mut http_config = self.http_config.clone();
+        http_config.cookie_store = true;
+        self.with_http_config(http_config)
+     }}
+
+    /// Set how the client handles redirects. With `RedirectPolicy::None` the
+    /// redirect response is returned as is, so its `Location` can be inspected.
+    pub fn with_redirect_policy(&self, policy: RedirectPolicy) -> Self {{
+        let mut http_config = self.http_config.clone();
+        http_config.redirect_policy = policy;
+        self.with_http_config(http_config)
+     }}
+
+    /// Send requests through the proxy at `url`, whatever their scheme, rather
+    /// than through the ones in `HTTP_PROXY` and `HTTPS_PROXY`. The hosts in
+    /// `NO_PROXY` still bypass it. It fails if `url` is not a valid url.
+    pub fn with_proxy(&self, url: &str) -> Result<Self> {{
+        let proxy_url =
+            ProxyConfig::parse_url(url).ok_or_else(|| anyhow!("invalid proxy url {{:?}}", url))?;
+
+        let mut http_config = self.http_config.clone();
+        let mut proxy = http_config.proxy.take().unwrap_or_else(ProxyConfig::from_env);
+        proxy.http = Some(proxy_url.clone());
+        proxy.https = Some(proxy_url);
+        http_config.proxy = Some(proxy);
+        Ok(self.with_http_config(http_config))
+     }}
+
+    /// Bypass the proxy for the hosts in `no_proxy`, a comma separated list in
+    /// the format of `NO_PROXY`, rather than for the ones in `NO_PROXY`.
+    pub fn with_no_proxy(&self, no_proxy: &str) -> Self {{
+        let mut http_config = self.http_config.clone();
+        let mut proxy = http_config.proxy.take().unwrap_or_else(ProxyConfig::from_env);
+        proxy.no_proxy = ProxyConfig::parse_no_proxy(no_proxy);
+        http_config.proxy = Some(proxy);
+        self.with_http_config(http_config)
+     }}
+
+    /// Connect with the given TLS implementation, rather than the default one.
+    pub fn with_tls_backend(&self, backend: TlsBackend) -> Self {{
+        let mut http_config = self.http_config.clone();
+        http_config.tls_backend = backend;
+        self.with_http_config(http_config)
+     }}
+
+    /// Keep at most `n` idle connections to each host open for reuse. By
+    /// default all of them are kept, so a burst of concurrent calls can leave
+    /// many sockets open; a server making lots of calls may want a cap around
+    /// its usual concurrency, like 32.
+    pub fn with_max_idle_per_host(&self, n: usize) -> Self {{
+        let mut http_config = self.http_config.clone();
+        http_config.pool_max_idle_per_host = Some(n);
+        self.with_http_config(http_config)
+     }}
+
+    /// Close connections that have been idle for `timeout`, rather than the
+    /// default of 90 seconds.
+    pub fn with_pool_idle_timeout(&self, timeout: std::time::Duration) -> Self {{
+        let mut http_config = self.http_config.clone();
+        http_config.pool_idle_timeout = Some(timeout);
+        self.with_http_config(http_config)
+     }}
+
+    /// Limit the number of requests the client has in flight at once. Any
+    /// more wait for one of those to finish before they are sent. A limit of
+    /// 0 is no limit, as no request could ever be sent under it.
+    pub fn with_max_concurrent_requests(&self, n: usize) -> Self {{
+        let mut c = self.clone();
+        c.semaphore = match n {{
+            0 => None,
+            n => Some(std::sync::Arc::new(tokio::sync::Semaphore::new(n))),
+        }};
+        c
+     }}
+
+    /// Add the given query parameters to every request, for example to scope
+    /// requests to an account. A parameter a function sets itself wins over a
+    /// default one of the same name.
+    pub fn with_default_query(&self, query: &[(&str, &str)]) -> Self {{
+        let mut c = self.clone();
+        c.default_query = query
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        c
+     }}
+
+    /// Retry requests that fail with a connection error, a `429 Too Many Requests`
+    /// or a server error, as the policy says. Use `retry` on a tag to override
+    /// it for the calls made through that tag.
+    pub fn with_retry(&self, policy: RetryPolicy) -> Self {{
+        let mut c = self.clone();
+        c.retry_policy = policy;
+        c
+     }}
+
+    /// Give up on the calls made with the returned client once `deadline` passes,
+    /// for example to pass on the deadline of an incoming request. This bounds
+    /// the time of a call across its retries too. A call that is out of time
+    /// returns a `ClientError::DeadlineExceeded`.
+    pub fn with_deadline(&self, deadline: std::time::Instant) -> Self {{
+        let mut c = self.clone();
+        c.deadline = Some(deadline);
+        c
+     }}
+
+    /// Also tell the API about the deadline, in an `X-Request-Deadline` header
+    /// with the time it passes at.
+    pub fn with_deadline_header(&self) -> Self {{
+        let mut c = self.clone();
+        c.deadline_header = true;
+        c
+     }}
+
+    /// Send a random UUID with every request in the `header` header, so a call
+    /// can be traced end to end. The error for a response with an error status
+    /// carries the id of its request. It fails if `header` is not a valid header
+    /// name.
+    pub fn with_request_id(&self, header: &str) -> Result<Self> {{
+        self.with_request_id_generator(header, || uuid::Uuid::new_v4().to_string())
+     }}
+
+    /// Send an id made by `generate` with every request in the `header` header,
+    /// as `with_request_id` does with a random UUID. Retries of a request send
+    /// the same id. It fails if `header` is not a valid header name.
+    pub fn with_request_id_generator<F>(&self, header: &str, generate: F) -> Result<Self>
+    where
+        F: Fn() -> String + Send + Sync + 'static,
+    {{
+        let header = reqwest::header::HeaderName::from_bytes(header.as_bytes())
+            .map_err(|e| anyhow!("invalid request id header {{:?}}: {{}}", header, e))?;
+
+        let mut c = self.clone();
+        c.request_id = Some(RequestId {{
+            header,
+            generate: std::sync::Arc::new(generate),
+        }});
+        Ok(c)
+     }}
+
+    /// Use `clock` for the time rather than the system clock, for example a
+    /// `MockClock` to test how the client retries or when its token expires.
+    pub fn with_clock<C>(&self, clock: C) -> Self
+    where
+        C: Clock + 'static,
+    {{
+        let mut c = self.clone();
+        c.clock = std::sync::Arc::new(clock);
+        c
+     }}
+
+    /// Serialize JSON request bodies with `serializer` rather than
+    /// `serde_json`, for example with `CanonicalJson` ahead of signing them.
+    pub fn with_body_serializer<S>(&self, serializer: S) -> Self
+    where
+        S: BodySerializer + 'static,
+    {{
+        let mut c = self.clone();
+        c.body_serializer = Some(std::sync::Arc::new(serializer));
+        c
+     }}
+
+    /// Send identical `GET`s that are in flight at the same time only once, and
+    /// share the response between them. This saves calls when many tasks ask
+    /// for the same thing at once, like the settings of the same user.
+    pub fn with_single_flight(&self, enabled: bool) -> Self {{
+        let mut c = self.clone();
+        c.in_flight = if enabled {{
+            Some(Default::default())
+        }} else {{
+            None
+        }};
+        c
+     }}
+
+    /// Send at most as many requests as `limit` allows, shared with the clones
+    /// of the client made after this. Requests over it wait for their turn, or
+    /// fail with a `ClientError::RateLimited` if `limit` has a max wait they
+    /// would go over.
+    pub fn with_rate_limit(&self, limit: RateLimit) -> Self {{
+        let mut c = self.clone();
+        c.rate_limiter = Some(std::sync::Arc::new(RateLimiter {{
+            limit,
+            full_at: Default::default(),
+        }}));
+        c
+     }}
+
+    /// Stop calling a host that keeps failing, as `config` says: calls to it
+    /// fail at once with a `ClientError::CircuitOpen` while its circuit is open.
+    /// The circuits are shared with the clones of the client made after this.
+    pub fn with_circuit_breaker(&self, config: CircuitConfig) -> Self {{
+        let mut c = self.clone();
+        c.circuit_breaker = Some(std::sync::Arc::new(CircuitBreaker {{
+            config,
+            circuits: Default::default(),
+        }}));
+        c
+     }}
+
+    /// Replay the responses to requests from the fixture files in `dir`, rather
+    /// than sending them, to test against the API offline. With the `record`
+    /// feature and `RECORD_FIXTURES` set, the requests are sent and their
+    /// responses written to `dir` instead.
+    pub fn with_fixtures<P>(&self, dir: P) -> Self
+    where
+        P: Into<std::path::PathBuf>,
+    {{
+        let mut c = self.clone();
+        c.fixtures = Some(Fixtures {{
+            dir: dir.into(),
+            record: Fixtures::recording(),
+        }});
+        c
+     }}
+
+    /// Call `f` before each attempt at a request, retries included, with its
+    /// method, url and attempt, say to count calls for metrics. `f` runs on
+    /// the request's task, so it should be quick.
+    pub fn with_on_request<F>(&self, f: F) -> Self
+    where
+        F: Fn(&RequestInfo) + Send + Sync + 'static,
+    {{
+        let mut c = self.clone();
+        c.on_request = Some(std::sync::Arc::new(f));
+        c
+     }}
+
+    /// Call `f` after each attempt at a request, retries included, with its
+    /// status and how long it took, say for an audit log.
+    pub fn with_on_response<F>(&self, f: F) -> Self
+    where
+        F: Fn(&ResponseInfo) + Send + Sync + 'static,
+    {{
+        let mut c = self.clone();
+        c.on_response = Some(std::sync::Arc::new(f));
+        c
+     }}
+
+    /// Read uploads from a reader, with the `_from_reader` functions, into
+    /// memory only up to `limit` bytes, so the request can be sent again, and
+    /// stream the rest as it is read. A streamed upload only gets the one try,
+    /// it is not retried. Without a limit the whole reader is read first.
+    pub fn with_request_body_limit(&self, limit: usize) -> Self {{
+        let mut c = self.clone();
+        c.request_body_limit = Some(limit);
+        c
+     }}
+
+    /// Ask for pages of `size` items from the functions that take a `page_size`,
+    /// when they are passed `0` for it, rather than the API's default.
+    pub fn with_default_page_size(&self, size: i64) -> Self {{
+        let mut c = self.clone();
+        c.default_page_size = Some(size);
+        c
+     }}
+
+    /// Call `f` once a call is done with how many retries it used and whether
+    /// it ran out of them, say to alert on calls that exhaust their retry
+    /// budget. Uploads streamed past `with_request_body_limit` are never
+    /// retried and are not reported.
+    pub fn with_on_retry_budget<F>(&self, f: F) -> Self
+    where
+        F: Fn(&RetryBudgetInfo) + Send + Sync + 'static,
+    {{
+        let mut c = self.clone();
+        c.on_retry_budget = Some(std::sync::Arc::new(f));
+        c
+     }}
+
+    /// Call `f` with the url of each request, with its query, before it is
+    /// sent, to change it. Unlike `with_host`, it sees the whole url, say to
+    /// route an account's requests to the host of its region by an id in the
+    /// path. The change applies to the retries of the request too.
+    pub fn with_interceptor<F>(&self, f: F) -> Self
+    where
+        F: Fn(&mut reqwest::Url) + Send + Sync + 'static,
+    {{
+        let mut c = self.clone();
+        c.interceptor = Some(std::sync::Arc::new(f));
+        c
+     }}
+
+    /// Call `f` after each attempt at a request that got a response, retries
+    /// included, with the sizes of its request and response bodies, say to
+    /// watch bandwidth or what calls cost. The bodies are counted as they are
+    /// sent and read, not held in memory, and `f` is called once the response
+    /// body has been read to its end, or dropped.
+    pub fn with_on_body_size<F>(&self, f: F) -> Self
+    where
+        F: Fn(&BodySizeInfo) + Send + Sync + 'static,
+    {{
+        let mut c = self.clone();
+        c.on_body_size = Some(std::sync::Arc::new(f));
+        c
+     }}
+
+    /// Inject the failures of `failpoints` into the client's requests, in place
+    /// of their responses, to test how the caller handles them. The clones of
+    /// the client share them, so each failure is injected once in all.
+    #[cfg(any(test, feature = "failpoints"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "failpoints")))]
+    pub fn with_failpoints(&self, failpoints: Failpoints) -> Self {{
+        let mut c = self.clone();
+        c.failpoints = Some(std::sync::Arc::new(failpoints));
+        c
+     }}
+
+    /// Keep the encodings of about the last `capacity` path parameters the
+    /// client used, rather than the default of 32, or none with a capacity of 0.
+    /// The clones of the client share them.
+    pub fn with_path_cache(&self, capacity: usize) -> Self {{
+        let mut c = self.clone();
+        c.path_cache = std::sync::Arc::new(PathCache::new(capacity));
+        c
+    }}
+
+    /// Percent-encode a path parameter, as the functions do with each of theirs,
+    /// reusing the encoding if the parameter was used recently.
+    pub fn encode_path(&self, pc: &str) -> EncodedPath {{
+        self.path_cache.encode(pc)
+    }}
+
+    /// The rate limit the server reported with the last response that had one,
+    /// to any request of this client or of its clones, to throttle requests
+    /// before the server starts turning them away.
+    pub fn last_rate_limit(&self) -> Option<RateLimitHeaders> {{
+        *self.last_rate_limit.lock().unwrap()
+    }}
+
+    /// Create a client for another user's token. The new client shares the
+    /// connection pool and settings of this one, so serving many users does
+    /// not mean building a client for each of them.
+    pub fn clone_with_token<T, R>(&self, token: T, refresh_token: R) -> Self
+    where
+        T: ToString,
+        R: ToString,
+    {{
+        let mut c = self.clone();
+        c.token = token.to_string();
+        c.refresh_token = refresh_token.to_string();
+        c.scope = String::new();
+        c.token_expires_at = None;
+        c
+     }}
+
+    {}
+
+
+
+    {}
+
+    {}
+
+    {}"#,
+        token_endpoint.trim_start_matches("https://"),
+        user_consent_endpoint.trim_start_matches("https://"),
+        health_path,
+        add_post_header_struct,
+        site_struct,
+        ACCESS_TOKEN_STRUCT_TEMPLATE,
+        add_post_header_type,
+        add_post_header_args,
+        add_post_header_args_where,
+        add_post_header_fn,
+        site_fn,
+        new_from_env,
+        TOKEN_AUTH_TEMPLATE,
+        get_shared_functions(proper_name, add_post_header),
+        site_functions
+    )
+}
+
+fn basic_new_from_env(proper_name: &str, add_post_header: &str) -> String {
+    let add_post_header_type = if !add_post_header.is_empty() {
+        ", P".to_string()
+    } else {
+        "".to_string()
+    };
+    let add_post_header_args = if !add_post_header.is_empty() {
+        format!(",{}: P", to_snake_case(add_post_header))
+    } else {
+        "".to_string()
+    };
+    let add_post_header_args_where = if add_post_header.is_empty() {
+        "".to_string()
+    } else {
+        "P: ToString,".to_string()
+    };
+    let add_post_header_fn = if !add_post_header.is_empty() {
+        format!("{},", to_snake_case(add_post_header))
+    } else {
+        "".to_string()
+    };
+
+    format!(
+        r#"
+/// Create a new Client struct from environment variables. It
+/// takes a type that can convert into
+/// an &str (`String` or `Vec<u8>` for example). As long as the function is
+/// given a valid API key and your requests will work.
+/// We pass in the token and refresh token to the client so if you are storing
+/// it in a database, you can get it first.
+pub fn new_from_env<T, R{}>(token: T, refresh_token: R{}) -> Self
+where
+    T: ToString,
+    R: ToString,
+    {}
+{{
+    let client_id = env::var("{}_CLIENT_ID").expect("must set {}_CLIENT_ID");
+    let client_secret = env::var("{}_CLIENT_SECRET").expect("must set {}_CLIENT_SECRET");
+    let redirect_uri = env::var("{}_REDIRECT_URI").expect("must set {}_REDIRECT_URI");
+
+    Client::new(
+        client_id,
+        client_secret,
+        redirect_uri,
+        token,
+        refresh_token,
+        {}
+    )
+}}"#,
+        add_post_header_type,
+        add_post_header_args,
+        add_post_header_args_where,
+        proper_name.to_uppercase().replace('.', ""),
+        proper_name.to_uppercase().replace('.', ""),
+        proper_name.to_uppercase().replace('.', ""),
+        proper_name.to_uppercase().replace('.', ""),
+        proper_name.to_uppercase().replace('.', ""),
+        proper_name.to_uppercase().replace('.', ""),
+        add_post_header_fn,
+    )
+}
+
+const GOOGLE_NEW_FROM_ENV_TEMPLATE: &str = r#"
+/// Create a new Client struct from environment variables. It
+/// takes a type that can convert into
+/// an &str (`String` or `Vec<u8>` for example). As long as the function is
+/// given a valid API key and your requests will work.
+/// We pass in the token and refresh token to the client so if you are storing
+/// it in a database, you can get it first.
+pub async fn new_from_env<T, R>(token: T, refresh_token: R) -> Self
+where
+    T: ToString,
+    R: ToString,
+{
+    let google_key = env::var("GOOGLE_KEY_ENCODED").unwrap_or_default();
+    let b = base64::decode(google_key).unwrap();
+    // Save the google key to a tmp file.
+    let mut file_path = env::temp_dir();
+    file_path.push("google_key.json");
+    // Create the file and write to it.
+    let mut file = std::fs::File::create(file_path.clone()).unwrap();
+    file.write_all(&b).unwrap();
+    // Set the Google credential file to the temp path.
+    let google_credential_file = file_path.to_str().unwrap().to_string();
+
+    let secret = yup_oauth2::read_application_secret(google_credential_file)
+        .await
+        .expect("failed to read google credential file");
+
+    let http_config = HttpConfig::default();
+    let client = http_config.build();
+    match client {
+        Ok(c) => {
+            // We do not refresh the access token here since we leave that up to the
+            // user to do so they can re-save it to their database.
+            // TODO: But in the future we should save the expires in date and refresh it
+            // if it needs to be refreshed.
             //
             Client {
                 host: DEFAULT_HOST.to_string(),
@@ -716,8 +2894,35 @@ where
                 redirect_uri: secret.redirect_uris[0].to_string(),
                 token: token.to_string(),
                 refresh_token: refresh_token.to_string(),
+                scope: String::new(),
+                token_expires_at: None,
 
                 client: c,
+                http_config,
+                semaphore: None,
+                default_query: Vec::new(),
+                retry_policy: RetryPolicy::default(),
+                deadline: None,
+                deadline_header: false,
+                request_id: None,
+                clock: std::sync::Arc::new(SystemClock),
+                body_serializer: None,
+                in_flight: None,
+                rate_limiter: None,
+                circuit_breaker: None,
+                fixtures: None,
+                on_request: None,
+                on_response: None,
+                request_body_limit: None,
+                default_page_size: None,
+                on_retry_budget: None,
+                interceptor: None,
+                on_body_size: None,
+                streamed_sent: None,
+                #[cfg(any(test, feature = "failpoints"))]
+                failpoints: None,
+                path_cache: std::sync::Arc::new(PathCache::new(PATH_CACHE_CAPACITY)),
+                last_rate_limit: Default::default(),
             }
         },
         Err(e) => panic!("creating reqwest client failed: {:?}", e),
@@ -736,6 +2941,31 @@ pub struct Client {{
     token: String,
 
     client: reqwest::Client,
+    http_config: HttpConfig,
+    semaphore: Option<std::sync::Arc<tokio::sync::Semaphore>>,
+    default_query: Vec<(String, String)>,
+    retry_policy: RetryPolicy,
+    deadline: Option<std::time::Instant>,
+    deadline_header: bool,
+    request_id: Option<RequestId>,
+    clock: std::sync::Arc<dyn Clock>,
+    body_serializer: Option<std::sync::Arc<dyn BodySerializer>>,
+    in_flight: Option<std::sync::Arc<InFlight>>,
+    rate_limiter: Option<std::sync::Arc<RateLimiter>>,
+    circuit_breaker: Option<std::sync::Arc<CircuitBreaker>>,
+    fixtures: Option<Fixtures>,
+    on_request: Option<Observer<RequestInfo>>,
+    on_response: Option<Observer<ResponseInfo>>,
+    request_body_limit: Option<usize>,
+    default_page_size: Option<i64>,
+    on_retry_budget: Option<Observer<RetryBudgetInfo>>,
+    interceptor: Option<Interceptor>,
+    on_body_size: Option<Observer<BodySizeInfo>>,
+    streamed_sent: Option<std::sync::Arc<std::sync::atomic::AtomicU64>>,
+    #[cfg(any(test, feature = "failpoints"))]
+    failpoints: Option<std::sync::Arc<Failpoints>>,
+    path_cache: std::sync::Arc<PathCache>,
+    last_rate_limit: std::sync::Arc<std::sync::Mutex<Option<RateLimitHeaders>>>,
 }}
 
 impl Client {{
@@ -748,7 +2978,8 @@ impl Client {{
     where
         T: ToString,
     {{
-        let client = reqwest::Client::builder().build();
+        let http_config = HttpConfig::default();
+        let client = http_config.build();
         match client {{
             Ok(c) => {{
                 Client {{
@@ -756,6 +2987,31 @@ impl Client {{
                     token: token.to_string(),
 
                     client: c,
+                    http_config,
+                    semaphore: None,
+                    default_query: Vec::new(),
+                    retry_policy: RetryPolicy::default(),
+                    deadline: None,
+                    deadline_header: false,
+                    request_id: None,
+                    clock: std::sync::Arc::new(SystemClock),
+                    body_serializer: None,
+                    in_flight: None,
+                    rate_limiter: None,
+                    circuit_breaker: None,
+                    fixtures: None,
+                    on_request: None,
+                    on_response: None,
+                    request_body_limit: None,
+                    default_page_size: None,
+                    on_retry_budget: None,
+                    interceptor: None,
+                    on_body_size: None,
+                    streamed_sent: None,
+                    #[cfg(any(test, feature = "failpoints"))]
+                    failpoints: None,
+                    path_cache: std::sync::Arc::new(PathCache::new(PATH_CACHE_CAPACITY)),
+                    last_rate_limit: Default::default(),
                 }}
             }}
             Err(e) => panic!("creating reqwest client failed: {{:?}}", e),
@@ -772,6 +3028,352 @@ impl Client {{
         c
      }}
 
+    /// Keep cookies set by the API and send them back on later requests.
+    /// This is needed for endpoints that rely on a session (or CSRF) cookie
+    /// rather than on the token alone.
+    pub fn with_cookie_store(&self) -> Self {{
+        let mut http_config = self.http_config.clone();
+        http_config.cookie_store = true;
+        self.with_http_config(http_config)
+     }}
+
+    /// Set how the client handles redirects. With `RedirectPolicy::None` the
+    /// redirect response is returned as is, so its `Location` can be inspected.
+    pub fn with_redirect_policy(&self, policy: RedirectPolicy) -> Self {{
+        let mut http_config = self.http_config.clone();
+        http_config.redirect_policy = policy;
+        self.with_http_config(http_config)
+     }}
+
+    /// Send requests through the proxy at `url`, whatever their scheme, rather
+    /// than through the ones in `HTTP_PROXY` and `HTTPS_PROXY`. The hosts in
+    /// `NO_PROXY` still bypass it. It fails if `url` is not a valid url.
+    pub fn with_proxy(&self, url: &str) -> Result<Self> {{
+        let proxy_url =
+            ProxyConfig::parse_url(url).ok_or_else(|| anyhow!("invalid proxy url {{:?}}", url))?;
+
+        let mut http_config = self.http_config.clone();
+        let mut proxy = http_config.proxy.take().unwrap_or_else(ProxyConfig::from_env);
+        proxy.http = Some(proxy_url.clone());
+        proxy.https = Some(proxy_url);
+        http_config.proxy = Some(proxy);
+        Ok(self.with_http_config(http_config))
+     }}
+
+    /// Bypass the proxy for the hosts in `no_proxy`, a comma separated list in
+    /// the format of `NO_PROXY`, rather than for the ones in `NO_PROXY`.
+    pub fn with_no_proxy(&self, no_proxy: &str) -> Self {{
+        let mut http_config = self.http_config.clone();
+        let mut proxy = http_config.proxy.take().unwrap_or_else(ProxyConfig::from_env);
+        proxy.no_proxy = ProxyConfig::parse_no_proxy(no_proxy);
+        http_config.proxy = Some(proxy);
+        self.with_http_config(http_config)
+     }}
+
+    /// Connect with the given TLS implementation, rather than the default one.
+    pub fn with_tls_backend(&self, backend: TlsBackend) -> Self {{
+        let mut http_config = self.http_config.clone();
+        http_config.tls_backend = backend;
+        self.with_http_config(http_config)
+     }}
+
+    /// Keep at most `n` idle connections to each host open for reuse. By
+    /// default all of them are kept, so a burst of concurrent calls can leave
+    /// many sockets open; a server making lots of calls may want a cap around
+    /// its usual concurrency, like 32.
+    pub fn with_max_idle_per_host(&self, n: usize) -> Self {{
+        let mut http_config = self.http_config.clone();
+        http_config.pool_max_idle_per_host = Some(n);
+        self.with_http_config(http_config)
+     }}
+
+    /// Close connections that have been idle for `timeout`, rather than the
+    /// default of 90 seconds.
+    pub fn with_pool_idle_timeout(&self, timeout: std::time::Duration) -> Self {{
+        let mut http_config = self.http_config.clone();
+        http_config.pool_idle_timeout = Some(timeout);
+        self.with_http_config(http_config)
+     }}
+
+    /// Limit the number of requests the client has in flight at once. Any
+    /// more wait for one of those to finish before they are sent. A limit of
+    /// 0 is no limit, as no request could ever be sent under it.
+    pub fn with_max_concurrent_requests(&self, n: usize) -> Self {{
+        let mut c = self.clone();
+        c.semaphore = match n {{
+            0 => None,
+            n => Some(std::sync::Arc::new(tokio::sync::Semaphore::new(n))),
+        }};
+        c
+     }}
+
+    /// Add the given query parameters to every request, for example to scope
+    /// requests to an account. A parameter a function sets itself wins over a
+    /// default one of the same name.
+    pub fn with_default_query(&self, query: &[(&str, &str)]) -> Self {{
+        let mut c = self.clone();
+        c.default_query = query
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        c
+     }}
+
+    /// Retry requests that fail with a connection error, a `429 Too Many Requests`
+    /// or a server error, as the policy says. Use `retry` on a tag to override
+    /// it for the calls made through that tag.
+    pub fn with_retry(&self, policy: RetryPolicy) -> Self {{
+        let mut c = self.clone();
+        c.retry_policy = policy;
+        c
+     }}
+
+    /// Give up on the calls made with the returned client once `deadline` passes,
+    /// for example to pass on the deadline of an incoming request. This bounds
+    /// the time of a call across its retries too. A call that is out of time
+    /// returns a `ClientError::DeadlineExceeded`.
+    pub fn with_deadline(&self, deadline: std::time::Instant) -> Self {{
+        let mut c = self.clone();
+        c.deadline = Some(deadline);
+        c
+     }}
+
+    /// Also tell the API about the deadline, in an `X-Request-Deadline` header
+    /// with the time it passes at.
+    pub fn with_deadline_header(&self) -> Self {{
+        let mut c = self.clone();
+        c.deadline_header = true;
+        c
+     }}
+
+    /// Send a random UUID with every request in the `header` header, so a call
+    /// can be traced end to end. The error for a response with an error status
+    /// carries the id of its request. It fails if `header` is not a valid header
+    /// name.
+    pub fn with_request_id(&self, header: &str) -> Result<Self> {{
+        self.with_request_id_generator(header, || uuid::Uuid::new_v4().to_string())
+     }}
+
+    /// Send an id made by `generate` with every request in the `header` header,
+    /// as `with_request_id` does with a random UUID. Retries of a request send
+    /// the same id. It fails if `header` is not a valid header name.
+    pub fn with_request_id_generator<F>(&self, header: &str, generate: F) -> Result<Self>
+    where
+        F: Fn() -> String + Send + Sync + 'static,
+    {{
+        let header = reqwest::header::HeaderName::from_bytes(header.as_bytes())
+            .map_err(|e| anyhow!("invalid request id header {{:?}}: {{}}", header, e))?;
+
+        let mut c = self.clone();
+        c.request_id = Some(RequestId {{
+            header,
+            generate: std::sync::Arc::new(generate),
+        }});
+        Ok(c)
+     }}
+
+    /// Use `clock` for the time rather than the system clock, for example a
+    /// `MockClock` to test how the client retries or when its token expires.
+    pub fn with_clock<C>(&self, clock: C) -> Self
+    where
+        C: Clock + 'static,
+    {{
+        let mut c = self.clone();
+        c.clock = std::sync::Arc::new(clock);
+        c
+     }}
+
+    /// Serialize JSON request bodies with `serializer` rather than
+    /// `serde_json`, for example with `CanonicalJson` ahead of signing them.
+    pub fn with_body_serializer<S>(&self, serializer: S) -> Self
+    where
+        S: BodySerializer + 'static,
+    {{
+        let mut c = self.clone();
+        c.body_serializer = Some(std::sync::Arc::new(serializer));
+        c
+     }}
+
+    /// Send identical `GET`s that are in flight at the same time only once, and
+    /// share the response between them. This saves calls when many tasks ask
+    /// for the same thing at once, like the settings of the same user.
+    pub fn with_single_flight(&self, enabled: bool) -> Self {{
+        let mut c = self.clone();
+        c.in_flight = if enabled {{
+            Some(Default::default())
+        }} else {{
+            None
+        }};
+        c
+     }}
+
+    /// Send at most as many requests as `limit` allows, shared with the clones
+    /// of the client made after this. Requests over it wait for their turn, or
+    /// fail with a `ClientError::RateLimited` if `limit` has a max wait they
+    /// would go over.
+    pub fn with_rate_limit(&self, limit: RateLimit) -> Self {{
+        let mut c = self.clone();
+        c.rate_limiter = Some(std::sync::Arc::new(RateLimiter {{
+            limit,
+            full_at: Default::default(),
+        }}));
+        c
+     }}
+
+    /// Stop calling a host that keeps failing, as `config` says: calls to it
+    /// fail at once with a `ClientError::CircuitOpen` while its circuit is open.
+    /// The circuits are shared with the clones of the client made after this.
+    pub fn with_circuit_breaker(&self, config: CircuitConfig) -> Self {{
+        let mut c = self.clone();
+        c.circuit_breaker = Some(std::sync::Arc::new(CircuitBreaker {{
+            config,
+            circuits: Default::default(),
+        }}));
+        c
+     }}
+
+    /// Replay the responses to requests from the fixture files in `dir`, rather
+    /// than sending them, to test against the API offline. With the `record`
+    /// feature and `RECORD_FIXTURES` set, the requests are sent and their
+    /// responses written to `dir` instead.
+    pub fn with_fixtures<P>(&self, dir: P) -> Self
+    where
+        P: Into<std::path::PathBuf>,
+    {{
+        let mut c = self.clone();
+        c.fixtures = Some(Fixtures {{
+            dir: dir.into(),
+            record: Fixtures::recording(),
+        }});
+        c
+     }}
+
+    /// Call `f` before each attempt at a request, retries included, with its
+    /// method, url and attempt, say to count calls for metrics. `f` runs on
+    /// the request's task, so it should be quick.
+    pub fn with_on_request<F>(&self, f: F) -> Self
+    where
+        F: Fn(&RequestInfo) + Send + Sync + 'static,
+    {{
+        let mut c = self.clone();
+        c.on_request = Some(std::sync::Arc::new(f));
+        c
+     }}
+
+    /// Call `f` after each attempt at a request, retries included, with its
+    /// status and how long it took, say for an audit log.
+    pub fn with_on_response<F>(&self, f: F) -> Self
+    where
+        F: Fn(&ResponseInfo) + Send + Sync + 'static,
+    {{
+        let mut c = self.clone();
+        c.on_response = Some(std::sync::Arc::new(f));
+        c
+     }}
+
+    /// Read uploads from a reader, with the `_from_reader` functions, into
+    /// memory only up to `limit` bytes, so the request can be sent again, and
+    /// stream the rest as it is read. A streamed upload only gets the one try,
+    /// it is not retried. Without a limit the whole reader is read first.
+    pub fn with_request_body_limit(&self, limit: usize) -> Self {{
+        let mut c = self.clone();
+        c.request_body_limit = Some(limit);
+        c
+     }}
+
+    /// Ask for pages of `size` items from the functions that take a `page_size`,
+    /// when they are passed `0` for it, rather than the API's default.
+    pub fn with_default_page_size(&self, size: i64) -> Self {{
+        let mut c = self.clone();
+        c.default_page_size = Some(size);
+        c
+     }}
+
+    /// Call `f` once a call is done with how many retries it used and whether
+    /// it ran out of them, say to alert on calls that exhaust their retry
+    /// budget. Uploads streamed past `with_request_body_limit` are never
+    /// retried and are not reported.
+    pub fn with_on_retry_budget<F>(&self, f: F) -> Self
+    where
+        F: Fn(&RetryBudgetInfo) + Send + Sync + 'static,
+    {{
+        let mut c = self.clone();
+        c.on_retry_budget = Some(std::sync::Arc::new(f));
+        c
+     }}
+
+    /// Call `f` with the url of each request, with its query, before it is
+    /// sent, to change it. Unlike `with_host`, it sees the whole url, say to
+    /// route an account's requests to the host of its region by an id in the
+    /// path. The change applies to the retries of the request too.
+    pub fn with_interceptor<F>(&self, f: F) -> Self
+    where
+        F: Fn(&mut reqwest::Url) + Send + Sync + 'static,
+    {{
+        let mut c = self.clone();
+        c.interceptor = Some(std::sync::Arc::new(f));
+        c
+     }}
+
+    /// Call `f` after each attempt at a request that got a response, retries
+    /// included, with the sizes of its request and response bodies, say to
+    /// watch bandwidth or what calls cost. The bodies are counted as they are
+    /// sent and read, not held in memory, and `f` is called once the response
+    /// body has been read to its end, or dropped.
+    pub fn with_on_body_size<F>(&self, f: F) -> Self
+    where
+        F: Fn(&BodySizeInfo) + Send + Sync + 'static,
+    {{
+        let mut c = self.clone();
+        c.on_body_size = Some(std::sync::Arc::new(f));
+        c
+     }}
+
+    /// Inject the failures of `failpoints` into the client's requests, in place
+    /// of their responses, to test how the caller handles them. The clones of
+    /// the client share them, so each failure is injected once in all.
+    #[cfg(any(test, feature = "failpoints"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "failpoints")))]
+    pub fn with_failpoints(&self, failpoints: Failpoints) -> Self {{
+        let mut c = self.clone();
+        c.failpoints = Some(std::sync::Arc::new(failpoints));
+        c
+     }}
+
+    /// Keep the encodings of about the last `capacity` path parameters the
+    /// client used, rather than the default of 32, or none with a capacity of 0.
+    /// The clones of the client share them.
+    pub fn with_path_cache(&self, capacity: usize) -> Self {{
+        let mut c = self.clone();
+        c.path_cache = std::sync::Arc::new(PathCache::new(capacity));
+        c
+    }}
+
+    /// Percent-encode a path parameter, as the functions do with each of theirs,
+    /// reusing the encoding if the parameter was used recently.
+    pub fn encode_path(&self, pc: &str) -> EncodedPath {{
+        self.path_cache.encode(pc)
+    }}
+
+    /// The rate limit the server reported with the last response that had one,
+    /// to any request of this client or of its clones, to throttle requests
+    /// before the server starts turning them away.
+    pub fn last_rate_limit(&self) -> Option<RateLimitHeaders> {{
+        *self.last_rate_limit.lock().unwrap()
+    }}
+
+    /// Create a client for another token. The new client shares the
+    /// connection pool and settings of this one, so serving many users does
+    /// not mean building a client for each of them.
+    pub fn clone_with_token<T>(&self, token: T) -> Self
+    where
+        T: ToString,
+    {{
+        let mut c = self.clone();
+        c.token = token.to_string();
+        c
+     }}
+
     /// Create a new Client struct from environment variables. It
     /// takes a type that can convert into
     /// an &str (`String` or `Vec<u8>` for example). As long as the function is
@@ -816,57 +3418,459 @@ fn get_shared_functions(proper_name: &str, add_post_header: &str) -> String {
         "Bearer".to_string()
     };
 
-    format!(
-        r#"
-async fn url_and_auth(
-    &self,
-    uri: &str,
-) -> Result<(reqwest::Url, Option<String>)> {{
-    let parsed_url = uri.parse::<reqwest::Url>();
+    format!(
+        r#"
+/// Rebuild the reqwest client with the changed settings.
+fn with_http_config(&self, http_config: HttpConfig) -> Self {{
+    match http_config.build() {{
+        Ok(c) => {{
+            let mut s = self.clone();
+            s.client = c;
+            s.http_config = http_config;
+            s
+        }}
+        Err(e) => panic!("creating reqwest client failed: {{:?}}", e),
+    }}
+}}
+
+/// Join the query parameters onto the path of a request, leaving out those
+/// that are empty, as the generated functions push every parameter they
+/// take whether or not it was set.
+fn url_with_query(base: &str, args: &[(&str, String)]) -> String {{
+    let args: Vec<&(&str, String)> = args.iter().filter(|(_, v)| !v.is_empty()).collect();
+    if args.is_empty() {{
+        return base.to_string();
+    }}
+    let query = url::form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(args)
+        .finish();
+    format!("{{}}?{{}}", base, query)
+}}
+
+async fn url_and_auth(
+    &self,
+    uri: &str,
+) -> Result<(reqwest::Url, Option<String>)> {{
+    let mut url = uri.parse::<reqwest::Url>()?;
+
+    // Add the default query parameters the request does not set itself.
+    let defaults: Vec<&(String, String)> = self
+        .default_query
+        .iter()
+        .filter(|(k, _)| !url.query_pairs().any(|(q, _)| q == k.as_str()))
+        .collect();
+    if !defaults.is_empty() {{
+        url.query_pairs_mut().extend_pairs(defaults);
+    }}
+
+    if let Some(interceptor) = &self.interceptor {{
+        interceptor(&mut url);
+    }}
+
+    let auth = format!("{} {{}}", self.token);
+    Ok((url, Some(auth)))
+}}
+
+async fn request_raw(
+    &self,
+    method: reqwest::Method,
+    uri: &str,
+    body: Option<reqwest::Body>,
+) -> Result<reqwest::Response>
+{{
+    let u = if uri.starts_with("https://") || uri.starts_with("http://") {{
+        uri.to_string()
+    }} else {{
+        (self.host.clone() + uri).to_string()
+    }};
+    let (url, auth) = self.url_and_auth(&u).await?;
+
+    let instance = <&Client>::clone(&self);
+
+    let mut req = instance.client.request(method.clone(), url.clone());
+
+    // Set the default headers.
+    req = req.header(
+        reqwest::header::ACCEPT,
+        reqwest::header::HeaderValue::from_static("application/json"),
+    );
+    req = req.header(
+        reqwest::header::CONTENT_TYPE,
+        reqwest::header::HeaderValue::from_static("application/json"),
+    );
+    {}
+
+    if let Some(auth_str) = auth {{
+        req = req.header(http::header::AUTHORIZATION, &*auth_str);
+    }}
+
+    if let Some(body) = body {{
+        // A streaming body can not be read without sending it, so only log one
+        // that is in memory.
+        if let Some(bytes) = body.as_bytes() {{
+            log::debug!("body: {{:?}}", String::from_utf8_lossy(bytes));
+        }}
+        req = req.body(body);
+    }}
+    log::debug!("request: {{:?}}", &req);
+    self.send(&url, req).await
+}}
+
+async fn send(
+    &self,
+    url: &reqwest::Url,
+    req: reqwest::RequestBuilder,
+) -> Result<reqwest::Response> {{
+    let r = match &self.request_id {{
+        Some(r) => r,
+        None => return Self::check_scope(self.send_with_fixtures(url, req).await?),
+    }};
+    let request_id = (r.generate)();
+    log::debug!("request id: {{}}", request_id);
+
+    let mut resp = self
+        .send_with_fixtures(url, req.header(r.header.clone(), request_id.as_str()))
+        .await?;
+
+    // Put the id on the response too, so an error for it can carry the id.
+    resp.headers_mut().insert(
+        r.header.clone(),
+        reqwest::header::HeaderValue::from_str(&request_id)?,
+    );
+    Self::check_scope(resp)
+}}
+
+/// Fail with a `ClientError::InsufficientScope` if the API refused the request
+/// because the token lacks a scope, as the `WWW-Authenticate` header says.
+fn check_scope(resp: reqwest::Response) -> Result<reqwest::Response> {{
+    let status = resp.status();
+    if status != reqwest::StatusCode::UNAUTHORIZED && status != reqwest::StatusCode::FORBIDDEN {{
+        return Ok(resp);
+    }}
+
+    let required = resp
+        .headers()
+        .get(reqwest::header::WWW_AUTHENTICATE)
+        .and_then(|h| h.to_str().ok())
+        .and_then(crate::utils::insufficient_scope);
+    match required {{
+        Some(required) => Err(ClientError::InsufficientScope {{ required }}.into()),
+        None => Ok(resp),
+    }}
+}}
+
+/// Send the request, or replay its response if the client has fixtures. When
+/// they are recording, send it and record its response.
+async fn send_with_fixtures(
+    &self,
+    url: &reqwest::Url,
+    req: reqwest::RequestBuilder,
+) -> Result<reqwest::Response> {{
+    let fixtures = match &self.fixtures {{
+        Some(fixtures) => fixtures,
+        None => return self.send_with_circuit_breaker(url, req).await,
+    }};
+    if !fixtures.record {{
+        return fixtures.replay(&req.build()?);
+    }}
+
+    let path = match req.try_clone() {{
+        Some(r) => fixtures.path(&r.build()?),
+        None => return Err(anyhow!("can not record a request with a streaming body")),
+    }};
+    let resp = self.send_with_circuit_breaker(url, req).await?;
+    fixtures.record(&path, resp).await
+}}
+
+/// Send the request, unless the client's circuit breaker is open for the host
+/// of `url`, and count whether the call failed.
+async fn send_with_circuit_breaker(
+    &self,
+    url: &reqwest::Url,
+    req: reqwest::RequestBuilder,
+) -> Result<reqwest::Response> {{
+    let breaker = match &self.circuit_breaker {{
+        Some(breaker) => breaker,
+        None => return self.send_with_retries(req).await,
+    }};
+    let host = url.host_str().unwrap_or_default();
+    if let Err(retry_after) = breaker.admit(host, self.clock.now()) {{
+        return Err(ClientError::CircuitOpen {{ retry_after }}.into());
+    }}
+
+    let resp = self.send_with_retries(req).await;
+    let failed = match &resp {{
+        Ok(resp) => resp.status().is_server_error(),
+        // Only count errors from the connection, not ones of our own like a deadline.
+        Err(e) => e.downcast_ref::<reqwest::Error>().is_some(),
+    }};
+    breaker.record(host, self.clock.now(), failed);
+    resp
+}}
+
+/// The id the client sent with the request of the response, if it sends them.
+fn response_request_id(&self, resp: &reqwest::Response) -> Option<String> {{
+    let header = &self.request_id.as_ref()?.header;
+    let id = resp.headers().get(header)?.to_str().ok()?;
+    Some(id.to_string())
+}}
+
+async fn send_with_retries(&self, req: reqwest::RequestBuilder) -> Result<reqwest::Response> {{
+    let request = match &self.on_retry_budget {{
+        Some(_) => req.try_clone().and_then(|r| r.build().ok()),
+        None => None,
+    }};
+    let (mut retries, mut exhausted) = (0, false);
+    let resp = self
+        .send_with_retry_budget(req, &mut retries, &mut exhausted)
+        .await;
+    if let (Some(on_retry_budget), Some(request)) = (&self.on_retry_budget, request) {{
+        on_retry_budget(&RetryBudgetInfo {{
+            method: request.method().clone(),
+            url: request.url().clone(),
+            retries,
+            exhausted,
+        }});
+    }}
+    resp
+}}
+
+/// A permit to send a request, if the client limits the requests it has in
+/// flight.
+async fn acquire_permit(&self) -> Result<Option<tokio::sync::SemaphorePermit<'_>>> {{
+    Ok(match &self.semaphore {{
+        Some(s) => Some(s.acquire().await?),
+        None => None,
+    }})
+}}
+
+/// Send the request, retrying it under the client's retry policy. `retries`
+/// is set to how many retries were sent, and `exhausted` to whether the
+/// client gave up while the request still wanted a retry.
+async fn send_with_retry_budget(
+    &self,
+    req: reqwest::RequestBuilder,
+    retries: &mut u32,
+    exhausted: &mut bool,
+) -> Result<reqwest::Response> {{
+    let policy = &self.retry_policy;
+    let mut backoff = policy.initial_backoff;
+    let mut waited = std::time::Duration::ZERO;
+    let mut attempt = 0;
+    for _ in 0..policy.max_retries {{
+        // A request with a streaming body can not be sent again, it only gets the one try.
+        let r = match req.try_clone() {{
+            Some(r) => r,
+            None => break,
+        }};
+        // Wait for our turn under the rate limit first, so we do not hold a
+        // permit others could send with while we wait. Then hold one, if we
+        // have a limit, until we have the response, but not while waiting to
+        // retry.
+        self.wait_for_rate_limit().await?;
+        let permit = self.acquire_permit().await?;
+        attempt += 1;
+        *retries = attempt - 1;
+
+        let (last, wait) = match self
+            .send_attempt(self.with_deadline_timeout(r)?, attempt)
+            .await
+        {{
+            Ok(resp) if !RetryPolicy::should_retry(resp.status()) => return Ok(resp),
+            Ok(resp) => {{
+                let wait = RetryPolicy::retry_after(resp.headers(), self.clock.now_utc())
+                    .unwrap_or_else(|| policy.delay(backoff));
+                (Ok(resp), wait)
+            }}
+            Err(e) if e.is_connect() || e.is_timeout() => (Err(e), policy.delay(backoff)),
+            Err(e) => return Err(e.into()),
+        }};
+
+        // Give up with what we got if the wait goes over the retry budget.
+        waited += wait;
+        if policy.max_total_delay.map_or(false, |max| waited > max) {{
+            log::debug!("not retrying request, the retries would wait over {{:?}}", waited);
+            *exhausted = true;
+            return Ok(last?);
+        }}
+
+        // Do not wait for a retry we have no time left for.
+        if let Some(deadline) = self.deadline {{
+            if self.clock.now() + wait >= deadline {{
+                *exhausted = true;
+                return Err(ClientError::DeadlineExceeded.into());
+            }}
+        }}
+
+        match &last {{
+            Ok(resp) => log::debug!("retrying request after status {{}}", resp.status()),
+            Err(e) => log::debug!("retrying request after error: {{}}", e),
+        }}
+        drop(permit);
+        self.clock.sleep(wait).await;
+        backoff = backoff.saturating_mul(2);
+    }}
+
+    self.wait_for_rate_limit().await?;
+    let _permit = self.acquire_permit().await?;
+    let resp = self
+        .send_attempt(self.with_deadline_timeout(req)?, attempt + 1)
+        .await;
+    *retries = attempt;
+    // Out of retries, if the last one still wanted another.
+    *exhausted = attempt == policy.max_retries
+        && match &resp {{
+            Ok(resp) => RetryPolicy::should_retry(resp.status()),
+            Err(e) => e.is_connect() || e.is_timeout(),
+        }};
+    match resp {{
+        Err(e) if e.is_timeout() && self.deadline.is_some() => {{
+            Err(ClientError::DeadlineExceeded.into())
+        }}
+        resp => Ok(resp?),
+    }}
+}}
+
+/// Send one attempt at a request, and tell the client's observers about it.
+async fn send_attempt(
+    &self,
+    req: reqwest::RequestBuilder,
+    attempt: u32,
+) -> reqwest::Result<reqwest::Response> {{
+    if self.on_request.is_none() && self.on_response.is_none() && self.on_body_size.is_none()
+    {{
+        return self.execute(req.build()?).await;
+    }}
+
+    let req = req.build()?;
+    let info = RequestInfo {{
+        method: req.method().clone(),
+        url: req.url().clone(),
+        attempt,
+    }};
+    if let Some(on_request) = &self.on_request {{
+        on_request(&info);
+    }}
+
+    // A body streamed from a reader has no size up front, it is counted as
+    // it is sent, see `reader_body`.
+    let size = req
+        .body()
+        .and_then(|b| b.as_bytes())
+        .map(|b| b.len() as u64);
+    let streamed_sent = self.streamed_sent.clone();
+
+    let start = self.clock.now();
+    let resp = self.execute(req).await;
+    if let Some(on_response) = &self.on_response {{
+        on_response(&ResponseInfo {{
+            request: info.clone(),
+            status: resp.as_ref().ok().map(|r| r.status()),
+            elapsed: self.clock.now() - start,
+        }});
+    }}
+
+    match (&self.on_body_size, resp) {{
+        (Some(on_body_size), Ok(resp)) => {{
+            let on_body_size = on_body_size.clone();
+            let status = resp.status();
+            Ok(Self::count_response(resp, move |received| {{
+                let sent = size.or_else(|| {{
+                    let sent = streamed_sent?;
+                    Some(sent.load(std::sync::atomic::Ordering::SeqCst))
+                }});
+                on_body_size(&BodySizeInfo {{
+                    request: info,
+                    status,
+                    sent: sent.unwrap_or(0),
+                    received,
+                }})
+            }}))
+        }}
+        (_, resp) => resp,
+    }}
+}}
 
-    let auth = format!("{} {{}}", self.token);
-    parsed_url.map(|u| (u, Some(auth))).map_err(Error::from)
+/// Send the request, unless a failpoint of the client fails it first.
+async fn execute(&self, req: reqwest::Request) -> reqwest::Result<reqwest::Response> {{
+    #[cfg(any(test, feature = "failpoints"))]
+    if let Some(failpoint) = self.failpoints.as_ref().and_then(|f| f.take(&req)) {{
+        return failpoint.inject(&self.client, req).await;
+    }}
+    let resp = self.client.execute(req).await;
+    if let Ok(resp) = &resp {{
+        if let Some(rate_limit) =
+            RateLimitHeaders::from_headers(resp.headers(), self.clock.now_utc())
+        {{
+            *self.last_rate_limit.lock().unwrap() = Some(rate_limit);
+        }}
+    }}
+    resp
 }}
 
-async fn request_raw(
-    &self,
-    method: reqwest::Method,
-    uri: &str,
-    body: Option<reqwest::Body>,
-) -> Result<reqwest::Response>
+/// Make the response into one with the same status and headers, whose body
+/// is counted as the caller reads it, and `on_end` called with the count
+/// once it is read, or dropped.
+fn count_response<F>(resp: reqwest::Response, on_end: F) -> reqwest::Response
+where
+    F: FnOnce(u64) + Send + Sync + 'static,
 {{
-    let u = if uri.starts_with("https://") {{
-        uri.to_string()
-    }} else {{
-        (self.host.clone() + uri).to_string()
+    let status = resp.status();
+    let version = resp.version();
+    let headers = resp.headers().clone();
+    let body = CountingStream {{
+        stream: Box::pin(resp.bytes_stream()),
+        count: 0,
+        on_end: Some(Box::new(on_end)),
     }};
-    let (url, auth) = self.url_and_auth(&u).await?;
 
-    let instance = <&Client>::clone(&self);
+    let mut counted = http::Response::new(reqwest::Body::wrap_stream(body));
+    *counted.status_mut() = status;
+    *counted.version_mut() = version;
+    *counted.headers_mut() = headers;
+    counted.into()
+}}
 
-    let mut req = instance.client.request(method.clone(), url);
+/// Wait for the request's turn under the client's rate limit, if it has one.
+async fn wait_for_rate_limit(&self) -> Result<()> {{
+    let limiter = match &self.rate_limiter {{
+        Some(limiter) => limiter,
+        None => return Ok(()),
+    }};
 
-    // Set the default headers.
-    req = req.header(
-        reqwest::header::ACCEPT,
-        reqwest::header::HeaderValue::from_static("application/json"),
-    );
-    req = req.header(
-        reqwest::header::CONTENT_TYPE,
-        reqwest::header::HeaderValue::from_static("application/json"),
-    );
-    {}
+    match limiter.take(self.clock.now()) {{
+        Ok(wait) if wait.is_zero() => Ok(()),
+        Ok(wait) => {{
+            log::debug!("waiting {{:?}} for the rate limit", wait);
+            self.clock.sleep(wait).await;
+            Ok(())
+        }}
+        Err(retry_after) => Err(ClientError::RateLimited {{ retry_after }}.into()),
+    }}
+}}
 
-    if let Some(auth_str) = auth {{
-        req = req.header(http::header::AUTHORIZATION, &*auth_str);
+/// Time out the request when the client's deadline passes, if it has one. This
+/// fails without sending the request if the deadline already passed.
+fn with_deadline_timeout(&self, req: reqwest::RequestBuilder) -> Result<reqwest::RequestBuilder> {{
+    let deadline = match self.deadline {{
+        Some(deadline) => deadline,
+        None => return Ok(req),
+    }};
+
+    let remaining = deadline.saturating_duration_since(self.clock.now());
+    if remaining.is_zero() {{
+        return Err(ClientError::DeadlineExceeded.into());
     }}
 
-    if let Some(body) = body {{
-        log::debug!("body: {{:?}}", String::from_utf8(body.as_bytes().unwrap().to_vec()).unwrap());
-        req = req.body(body);
+    let mut req = req.timeout(remaining);
+    if self.deadline_header {{
+        let at = self.clock.now_utc() + chrono::Duration::from_std(remaining)?;
+        req = req.header(
+            "X-Request-Deadline",
+            at.to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+        );
     }}
-    log::debug!("request: {{:?}}", &req);
-    Ok(req.send().await?)
+    Ok(req)
 }}
 
 async fn request<Out>(
@@ -876,34 +3880,48 @@ async fn request<Out>(
     body: Option<reqwest::Body>,
 ) -> Result<Out>
     where
-    Out: serde::de::DeserializeOwned + 'static + Send,
+    Out: crate::ResponseType,
 {{
     let response = self.request_raw(method, uri, body).await?;
 
     let status = response.status();
+    let request_id = self.response_request_id(&response);
 
     let response_body = response.bytes().await?;
 
+    Self::parse_response(status, request_id, &response_body)
+}}
+
+/// Parse the body of a successful response, or make a failed one into a
+/// `ClientError::HttpError`.
+fn parse_response<Out>(
+    status: reqwest::StatusCode,
+    request_id: Option<String>,
+    response_body: &[u8],
+) -> Result<Out>
+    where
+    Out: crate::ResponseType,
+{{
     if status.is_success() {{
-        log::debug!("response payload {{}}", String::from_utf8_lossy(&response_body));
+        log::debug!("response payload {{}}", String::from_utf8_lossy(response_body));
         let parsed_response = if status == http::StatusCode::NO_CONTENT || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>(){{
             serde_json::from_str("null")
         }} else {{
-            serde_json::from_slice::<Out>(&response_body)
+            serde_json::from_slice::<Out>(response_body)
         }};
+        #[cfg(feature = "validate-responses")]
+        if parsed_response.is_ok() {{
+            validate_response::<Out>(&response_body)?;
+        }}
         parsed_response.map_err(Error::from)
     }} else {{
-        let error = if response_body.is_empty() {{
-            anyhow!("code: {{}}, empty response", status)
-        }} else {{
-            anyhow!(
-                "code: {{}}, error: {{:?}}",
-                status,
-                String::from_utf8_lossy(&response_body),
-            )
-        }};
-
-        Err(error)
+        Err(ClientError::HttpError {{
+            status,
+            error: String::from_utf8_lossy(response_body).to_string(),
+            request_id,
+            call: None,
+        }}
+        .into())
     }}
 }}
 
@@ -914,11 +3932,12 @@ async fn request_with_links<Out>(
     body: Option<reqwest::Body>,
 ) -> Result<(Option<hyperx::header::Link>, Out)>
 where
-    Out: serde::de::DeserializeOwned + 'static + Send,
+    Out: crate::ResponseType,
 {{
     let response = self.request_raw(method, uri, body).await?;
 
     let status = response.status();
+    let request_id = self.response_request_id(&response);
     let link = response
         .headers()
         .get(http::header::LINK)
@@ -935,32 +3954,34 @@ where
         }} else {{
             serde_json::from_slice::<Out>(&response_body)
         }};
+        #[cfg(feature = "validate-responses")]
+        if parsed_response.is_ok() {{
+            validate_response::<Out>(&response_body)?;
+        }}
         parsed_response.map(|out| (link, out)).map_err(Error::from)
     }} else {{
-        let error = if response_body.is_empty() {{
-            anyhow!("code: {{}}, empty response", status)
-        }} else {{
-            anyhow!(
-                "code: {{}}, error: {{:?}}",
-                status,
-                String::from_utf8_lossy(&response_body),
-            )
-        }};
-        Err(error)
+        Err(ClientError::HttpError {{
+            status,
+            error: String::from_utf8_lossy(&response_body).to_string(),
+            request_id,
+            call: None,
+        }}
+        .into())
     }}
 }}
 
 /* TODO: make this more DRY */
 #[allow(dead_code)]
-async fn post_form<Out>(
+async fn request_form<Out>(
     &self,
+    method: reqwest::Method,
     uri: &str,
     form: reqwest::multipart::Form,
 ) -> Result<Out>
     where
-    Out: serde::de::DeserializeOwned + 'static + Send,
+    Out: crate::ResponseType,
 {{
-    let u = if uri.starts_with("https://") {{
+    let u = if uri.starts_with("https://") || uri.starts_with("http://") {{
         uri.to_string()
     }} else {{
         (self.host.clone() + uri).to_string()
@@ -969,56 +3990,31 @@ async fn post_form<Out>(
 
     let instance = <&Client>::clone(&self);
 
-    let mut req = instance.client.request(http::Method::POST, url);
+    let mut req = instance.client.request(method, url.clone());
 
     // Set the default headers.
     req = req.header(
         reqwest::header::ACCEPT,
         reqwest::header::HeaderValue::from_static("application/json"),
     );
-    req = req.header(
-        reqwest::header::CONTENT_TYPE,
-        reqwest::header::HeaderValue::from_static("application/json"),
-    );
 
     if let Some(auth_str) = auth {{
         req = req.header(http::header::AUTHORIZATION, &*auth_str);
     }}
 
+    // The form sets the content type, with the boundary between its parts.
     log::debug!("form: {{:?}}", form);
     req = req.multipart(form);
 
     log::debug!("request: {{:?}}", &req);
-    let response = req.send().await?;
+    let response = self.send(&url, req).await?;
 
     let status = response.status();
+    let request_id = self.response_request_id(&response);
 
     let response_body = response.bytes().await?;
 
-    if status.is_success() {{
-        log::debug!("response payload {{}}", String::from_utf8_lossy(&response_body));
-        let parsed_response = if status == http::StatusCode::NO_CONTENT || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>(){{
-            serde_json::from_str("null")
-        }} else if std::any::TypeId::of::<Out>() == std::any::TypeId::of::<String>() {{
-            // Parse the output as a string.
-            serde_json::from_value(serde_json::json!(&String::from_utf8(response_body.to_vec())?))
-        }} else {{
-            serde_json::from_slice::<Out>(&response_body)
-        }};
-        parsed_response.map_err(Error::from)
-    }} else {{
-        let error = if response_body.is_empty() {{
-            anyhow!("code: {{}}, empty response", status)
-        }} else {{
-            anyhow!(
-                "code: {{}}, error: {{:?}}",
-                status,
-                String::from_utf8_lossy(&response_body),
-            )
-        }};
-
-        Err(error)
-    }}
+    Self::parse_text_response(status, request_id, &response_body)
 }}
 
 /* TODO: make this more DRY */
@@ -1030,9 +4026,9 @@ async fn request_with_accept_mime<Out>(
     accept_mime_type: &str,
 ) -> Result<Out>
     where
-    Out: serde::de::DeserializeOwned + 'static + Send,
+    Out: crate::ResponseType,
 {{
-    let u = if uri.starts_with("https://") {{
+    let u = if uri.starts_with("https://") || uri.starts_with("http://") {{
         uri.to_string()
     }} else {{
         (self.host.clone() + uri).to_string()
@@ -1041,7 +4037,7 @@ async fn request_with_accept_mime<Out>(
 
     let instance = <&Client>::clone(&self);
 
-    let mut req = instance.client.request(method, url);
+    let mut req = instance.client.request(method, url.clone());
 
     // Set the default headers.
     req = req.header(
@@ -1054,36 +4050,102 @@ async fn request_with_accept_mime<Out>(
     }}
 
     log::debug!("request: {{:?}}", &req);
-    let response = req.send().await?;
+    let response = self.send(&url, req).await?;
 
     let status = response.status();
+    let request_id = self.response_request_id(&response);
 
     let response_body = response.bytes().await?;
 
+    Self::parse_text_response(status, request_id, &response_body)
+}}
+
+/// Parse the body of a successful response as `parse_response` does, but hand
+/// out a `String` or `bytes::Bytes` as the text or bytes it is, for responses
+/// that are not JSON.
+fn parse_text_response<Out>(
+    status: reqwest::StatusCode,
+    request_id: Option<String>,
+    response_body: &[u8],
+) -> Result<Out>
+    where
+    Out: crate::ResponseType,
+{{
     if status.is_success() {{
-        log::debug!("response payload {{}}", String::from_utf8_lossy(&response_body));
+        log::debug!("response payload {{}}", String::from_utf8_lossy(response_body));
         let parsed_response = if status == http::StatusCode::NO_CONTENT || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>(){{
             serde_json::from_str("null")
         }} else if std::any::TypeId::of::<Out>() == std::any::TypeId::of::<String>() {{
             // Parse the output as a string.
             serde_json::from_value(serde_json::json!(&String::from_utf8(response_body.to_vec())?))
+        }} else if std::any::TypeId::of::<Out>() == std::any::TypeId::of::<bytes::Bytes>() {{
+            // Hand out the bytes as they are.
+            <Out as serde::Deserialize>::deserialize(serde::de::value::BytesDeserializer::<serde_json::Error>::new(response_body))
         }} else {{
-            serde_json::from_slice::<Out>(&response_body)
+            serde_json::from_slice::<Out>(response_body)
         }};
+        #[cfg(feature = "validate-responses")]
+        if parsed_response.is_ok() {{
+            validate_response::<Out>(response_body)?;
+        }}
         parsed_response.map_err(Error::from)
     }} else {{
-        let error = if response_body.is_empty() {{
-            anyhow!("code: {{}}, empty response", status)
-        }} else {{
-            anyhow!(
-                "code: {{}}, error: {{:?}}",
-                status,
-                String::from_utf8_lossy(&response_body),
-            )
-        }};
+        Err(ClientError::HttpError {{
+            status,
+            error: String::from_utf8_lossy(response_body).to_string(),
+            request_id,
+            call: None,
+        }}
+        .into())
+    }}
+}}
+
+/// Send a request for a `multipart/*` response, and split it into its parts.
+#[allow(dead_code)]
+async fn request_multipart(
+    &self,
+    method: reqwest::Method,
+    uri: &str,
+    accept_mime_type: &str,
+) -> Result<Multipart> {{
+    let u = if uri.starts_with("https://") || uri.starts_with("http://") {{
+        uri.to_string()
+    }} else {{
+        (self.host.clone() + uri).to_string()
+    }};
+    let (url, auth) = self.url_and_auth(&u).await?;
+
+    let mut req = self.client.request(method, url.clone()).header(
+        reqwest::header::ACCEPT,
+        reqwest::header::HeaderValue::from_str(accept_mime_type)?,
+    );
+    if let Some(auth_str) = auth {{
+        req = req.header(http::header::AUTHORIZATION, &*auth_str);
+    }}
+
+    log::debug!("request: {{:?}}", &req);
+    let response = self.send(&url, req).await?;
+
+    let status = response.status();
+    let request_id = self.response_request_id(&response);
+    let content_type = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|h| h.to_str().ok())
+        .unwrap_or_default()
+        .to_string();
+    let response_body = response.bytes().await?;
 
-        Err(error)
+    if !status.is_success() {{
+        return Err(ClientError::HttpError {{
+            status,
+            error: String::from_utf8_lossy(&response_body).to_string(),
+            request_id,
+            call: None,
+        }}
+        .into());
     }}
+    Multipart::parse(&content_type, &response_body)
 }}
 
 /* TODO: make this more DRY */
@@ -1096,9 +4158,9 @@ async fn request_with_mime<Out>(
     mime_type: &str,
 ) -> Result<Out>
     where
-    Out: serde::de::DeserializeOwned + 'static + Send,
+    Out: crate::ResponseType,
 {{
-    let u = if uri.starts_with("https://") {{
+    let u = if uri.starts_with("https://") || uri.starts_with("http://") {{
         uri.to_string()
     }} else {{
         (self.host.clone() + uri).to_string()
@@ -1107,7 +4169,7 @@ async fn request_with_mime<Out>(
 
     let instance = <&Client>::clone(&self);
 
-    let mut req = instance.client.request(method, url);
+    let mut req = instance.client.request(method, url.clone());
 
     // Set the default headers.
     req = req.header(
@@ -1139,9 +4201,10 @@ async fn request_with_mime<Out>(
     }}
 
     log::debug!("request: {{:?}}", &req);
-    let response = req.send().await?;
+    let response = self.send(&url, req).await?;
 
     let status = response.status();
+    let request_id = self.response_request_id(&response);
 
     let response_body = response.bytes().await?;
 
@@ -1152,19 +4215,19 @@ async fn request_with_mime<Out>(
         }} else {{
             serde_json::from_slice::<Out>(&response_body)
         }};
+        #[cfg(feature = "validate-responses")]
+        if parsed_response.is_ok() {{
+            validate_response::<Out>(&response_body)?;
+        }}
         parsed_response.map_err(Error::from)
     }} else {{
-        let error = if response_body.is_empty() {{
-            anyhow!("code: {{}}, empty response", status)
-        }} else {{
-            anyhow!(
-                "code: {{}}, error: {{:?}}",
-                status,
-                String::from_utf8_lossy(&response_body),
-            )
-        }};
-
-        Err(error)
+        Err(ClientError::HttpError {{
+            status,
+            error: String::from_utf8_lossy(&response_body).to_string(),
+            request_id,
+            call: None,
+        }}
+        .into())
     }}
 }}
 
@@ -1175,7 +4238,7 @@ async fn request_entity<D>(
     body: Option<reqwest::Body>,
 ) -> Result<D>
 where
-    D: serde::de::DeserializeOwned + 'static + Send,
+    D: crate::ResponseType,
 {{
     let r = self
         .request(method, uri, body)
@@ -1186,8 +4249,12 @@ where
 #[allow(dead_code)]
 async fn get<D>(&self, uri: &str,  message: Option<reqwest::Body>) -> Result<D>
 where
-    D: serde::de::DeserializeOwned + 'static + Send,
+    D: crate::ResponseType,
 {{
+    if let (Some(in_flight), None) = (&self.in_flight, &message) {{
+        return self.get_single_flight(in_flight, uri).await;
+    }}
+
     self.request_entity(
         http::Method::GET,
         &(self.host.to_string() + uri),
@@ -1195,10 +4262,99 @@ where
     ).await
 }}
 
+/// Get the uri, sharing the response with the identical `GET`s in flight at
+/// the same time rather than sending each of them. See `with_single_flight`.
+async fn get_single_flight<D>(&self, in_flight: &InFlight, uri: &str) -> Result<D>
+where
+    D: crate::ResponseType,
+{{
+    // Key on the url and auth the request is sent with, after the default
+    // query and the interceptor, as clients scoped to other accounts with
+    // `with_default_query` or `with_interceptor` share the flights.
+    let (url, auth) = self.url_and_auth(&(self.host.clone() + uri)).await?;
+    let key = format!("{{:?}} {{}}", auth, url);
+    let flight = in_flight
+        .lock()
+        .unwrap()
+        .entry(key.clone())
+        .or_default()
+        .clone();
+
+    let shared = flight
+        .get_or_init(|| async {{
+            let result = async {{
+                let response = self.request_raw(http::Method::GET, uri, None).await?;
+                let status = response.status();
+                let request_id = self.response_request_id(&response);
+                Ok::<_, Error>((status, request_id, response.bytes().await?))
+            }}
+            .await;
+
+            // Identical requests from here on get a response of their own.
+            let mut flights = in_flight.lock().unwrap();
+            if matches!(flights.get(&key), Some(f) if std::sync::Arc::ptr_eq(f, &flight)) {{
+                flights.remove(&key);
+            }}
+            result.map_err(ClientError::from)
+        }})
+        .await;
+
+    match shared {{
+        Ok((status, request_id, response_body)) => {{
+            Self::parse_response(*status, request_id.clone(), response_body)
+        }}
+        Err(e) => Err(e.clone().into()),
+    }}
+}}
+
+/// Get the uri, giving up once `deadline` passes. See `with_deadline`.
+pub async fn get_with_deadline<D>(&self, uri: &str, deadline: std::time::Instant) -> Result<D>
+where
+    D: crate::ResponseType,
+{{
+    self.with_deadline(deadline).get(uri, None).await
+}}
+
+/// Get the uri as untyped JSON, for the fields the types here do not have yet.
+/// It is sent like any other request, with the client's auth and retries.
+pub async fn get_json(&self, uri: &str) -> Result<serde_json::Value> {{
+    self.get(uri, None).await
+}}
+
+/// Run the requests, which may be to different endpoints, with at most
+/// `concurrency` of them in flight at once. The results are in the same order
+/// as the requests, and one failing does not stop the others.
+pub async fn execute_all<T>(&self, requests: Vec<BoxedRequest<T>>, concurrency: usize) -> Vec<Result<T>>
+where
+    T: Send + 'static,
+{{
+    let semaphore = std::sync::Arc::new(tokio::sync::Semaphore::new(concurrency.max(1)));
+    let handles: Vec<_> = requests
+        .into_iter()
+        .map(|request| {{
+            let client = self.clone();
+            let semaphore = semaphore.clone();
+            tokio::spawn(async move {{
+                let _permit = semaphore.acquire_owned().await?;
+                request(client).await
+            }})
+        }})
+        .collect();
+
+    let mut results = Vec::with_capacity(handles.len());
+    for handle in handles {{
+        results.push(match handle.await {{
+            Ok(result) => result,
+            Err(e) => Err(anyhow!("request task failed: {{}}", e)),
+        }});
+    }}
+    results
+}}
+
 #[allow(dead_code)]
 async fn get_all_pages<D>(&self, uri: &str,  _message: Option<reqwest::Body>) -> Result<Vec<D>>
 where
-    D: serde::de::DeserializeOwned + 'static + Send,
+    D: crate::ResponseType,
 {{
     // TODO: implement this.
     self.unfold(uri).await
@@ -1211,7 +4367,7 @@ async fn unfold<D>(
     uri: &str,
 ) -> Result<Vec<D>>
 where
-    D: serde::de::DeserializeOwned + 'static + Send,
+    D: crate::ResponseType,
 {{
     let mut global_items = Vec::new();
     let (new_link, mut items) = self.get_pages(uri).await?;
@@ -1227,61 +4383,428 @@ where
         }}
     }}
 
-    Ok(global_items)
+    Ok(global_items)
+}}
+
+#[allow(dead_code)]
+async fn get_pages<D>(&self, uri: &str) -> Result<(Option<hyperx::header::Link>, Vec<D>)>
+where
+    D: crate::ResponseType,
+{{
+    self.request_with_links(
+        http::Method::GET,
+        &(self.host.to_string() + uri),
+        None,
+    ).await
+}}
+
+#[allow(dead_code)]
+async fn get_pages_url<D>(&self, url: &reqwest::Url) -> Result<(Option<hyperx::header::Link>, Vec<D>)>
+where
+    D: crate::ResponseType,
+{{
+    self.request_with_links(
+        http::Method::GET,
+        url.as_str(),
+        None,
+    ).await
+}}
+
+/// Stream the items of the JSON array in a response body as they are parsed,
+/// rather than buffering and parsing the whole body at once. If `property` is
+/// not empty, the array is read from that field of the top-level object. Only
+/// the one response is read, this does not follow the pages of a list.
+#[allow(dead_code)]
+async fn get_stream_json<D>(
+    &self,
+    uri: &str,
+    property: &str,
+) -> Result<impl futures::Stream<Item = ClientResult<D>>>
+where
+    D: crate::ResponseType,
+{{
+    let mut response = self.request_raw(
+        http::Method::GET,
+        &(self.host.to_string() + uri),
+        None,
+    ).await?;
+
+    let status = response.status();
+    let request_id = self.response_request_id(&response);
+    if !status.is_success() {{
+        let response_body = response.bytes().await?;
+        return Err(ClientError::HttpError {{
+            status,
+            error: String::from_utf8_lossy(&response_body).to_string(),
+            request_id,
+            call: None,
+        }}
+        .into());
+    }}
+
+    // Both channels are bounded so neither the body nor the parsed items can
+    // run too far ahead of the caller.
+    let (chunk_tx, chunk_rx) = tokio::sync::mpsc::channel(16);
+    let (item_tx, item_rx) = tokio::sync::mpsc::channel(16);
+
+    // Feed the body to the parser as it arrives.
+    tokio::spawn(async move {{
+        loop {{
+            let chunk = match response.chunk().await {{
+                Ok(Some(chunk)) => Ok(chunk),
+                Ok(None) => break,
+                Err(e) => Err(std::io::Error::new(std::io::ErrorKind::Other, e)),
+            }};
+            let failed = chunk.is_err();
+            if chunk_tx.send(chunk).await.is_err() || failed {{
+                break;
+            }}
+        }}
+    }});
+
+    // The parser reads synchronously, so it gets a blocking thread of its own.
+    let property = property.to_string();
+    tokio::task::spawn_blocking(move || {{
+        let reader = crate::utils::ChunkReader::new(chunk_rx);
+        let result = crate::utils::stream_json_array(reader, &property, |item: D| {{
+            item_tx.blocking_send(Ok(item)).is_ok()
+        }});
+        if let Err(e) = result {{
+            let _ = item_tx.blocking_send(Err(ClientError::from(Error::from(e))));
+        }}
+    }});
+
+    Ok(futures::stream::unfold(item_rx, |mut items| async move {{
+        let item = items.recv().await?;
+        Some((item, items))
+    }}))
+}}
+
+/// Stream the items of all the pages of a list, getting the next page only
+/// once the items of the last one are used up, rather than holding them all at
+/// once. `items` picks the items and the token of the next page out of a page,
+/// which is passed back as `token_param`. The stream ends when the token is empty.
+#[allow(dead_code)]
+async fn get_stream_pages<D, T, F>(
+    &self,
+    uri: &str,
+    token_param: &'static str,
+    items: F,
+) -> Result<impl futures::Stream<Item = ClientResult<T>>>
+where
+    D: crate::ResponseType,
+    F: Fn(D) -> (Vec<T>, String),
+{{
+    // The token of the page to get next is `None` once there are no more.
+    let state = (
+        self.clone(),
+        uri.to_string(),
+        items,
+        std::collections::VecDeque::new(),
+        Some(String::new()),
+    );
+
+    Ok(futures::stream::unfold(state, move |(client, uri, items, mut buffered, mut token)| async move {{
+        loop {{
+            if let Some(item) = buffered.pop_front() {{
+                return Some((Ok(item), (client, uri, items, buffered, token)));
+            }}
+
+            let page = token?;
+            let page_uri = if page.is_empty() {{
+                uri.clone()
+            }} else {{
+                let query = url::form_urlencoded::Serializer::new(String::new())
+                    .append_pair(token_param, &page)
+                    .finish();
+                let separator = if uri.contains('?') {{ '&' }} else {{ '?' }};
+                format!("{{}}{{}}{{}}", uri, separator, query)
+            }};
+
+            match client.get::<D>(&page_uri, None).await {{
+                Ok(resp) => {{
+                    let (page_items, next) = items(resp);
+                    buffered.extend(page_items);
+
+                    // Stop rather than get the same page over again.
+                    token = if next.is_empty() || next == page {{
+                        None
+                    }} else {{
+                        Some(next)
+                    }};
+                }}
+                Err(e) => return Some((Err(ClientError::from(e)), (client, uri, items, buffered, None))),
+            }}
+        }}
+    }}))
+}}
+
+/// Stream the bytes of a response body as they arrive, rather than buffering
+/// the whole body at once, say for a large PDF. `accept` is the media type
+/// asked for.
+#[allow(dead_code)]
+async fn get_stream_bytes(
+    &self,
+    uri: &str,
+    accept: &str,
+) -> Result<impl futures::Stream<Item = ClientResult<bytes::Bytes>>> {{
+    let (url, auth) = self.url_and_auth(&(self.host.to_string() + uri)).await?;
+
+    let mut req = self.client.request(http::Method::GET, url.clone());
+    req = req.header(
+        reqwest::header::ACCEPT,
+        reqwest::header::HeaderValue::from_str(accept)?,
+    );
+
+    if let Some(auth_str) = auth {{
+        req = req.header(http::header::AUTHORIZATION, &*auth_str);
+    }}
+
+    log::debug!("request: {{:?}}", &req);
+    let response = self.send(&url, req).await?;
+
+    let status = response.status();
+    let request_id = self.response_request_id(&response);
+    if !status.is_success() {{
+        let response_body = response.bytes().await?;
+        return Err(ClientError::HttpError {{
+            status,
+            error: String::from_utf8_lossy(&response_body).to_string(),
+            request_id,
+            call: None,
+        }}
+        .into());
+    }}
+
+    // Hand out the chunks as they come, and stop after the first error.
+    Ok(futures::stream::unfold(Some(response), |response| async move {{
+        let mut response = response?;
+        match response.chunk().await {{
+            Ok(Some(chunk)) => Some((Ok(chunk), Some(response))),
+            Ok(None) => None,
+            Err(e) => Some((Err(ClientError::from(Error::from(e))), None)),
+        }}
+    }}))
+}}
+
+/// Download the body at the uri to a file at `path`, say a recording from its
+/// `download_url` or a document, streaming it to the file as it arrives. The
+/// uri can be a full url, as download links are. Returns the bytes written,
+/// or `ClientError::IncompleteDownload` if the body was cut short. The file is
+/// only at `path` once the whole body is: a failed download leaves none.
+pub async fn download_to_file<P: AsRef<std::path::Path>>(&self, uri: &str, path: P) -> Result<u64> {{
+    let url = if uri.starts_with("https://") || uri.starts_with("http://") {{
+        uri.to_string()
+    }} else {{
+        self.host.to_string() + uri
+    }};
+    let (url, auth) = self.url_and_auth(&url).await?;
+
+    let mut req = self.client.request(http::Method::GET, url.clone());
+    if let Some(auth_str) = auth {{
+        req = req.header(http::header::AUTHORIZATION, &*auth_str);
+    }}
+
+    log::debug!("request: {{:?}}", &req);
+    let mut response = self.send(&url, req).await?;
+
+    let status = response.status();
+    let request_id = self.response_request_id(&response);
+    if !status.is_success() {{
+        let response_body = response.bytes().await?;
+        return Err(ClientError::HttpError {{
+            status,
+            error: String::from_utf8_lossy(&response_body).to_string(),
+            request_id,
+            call: None,
+        }}
+        .into());
+    }}
+
+    // Write to a file next to it, moved into place only once the whole body
+    // is in, so a failed download leaves nothing at `path`.
+    let path = path.as_ref();
+    let mut part = path.as_os_str().to_owned();
+    part.push(".part");
+    let part = std::path::PathBuf::from(part);
+
+    // A body the client counts, for `with_on_body_size`, has no size of its
+    // own, so go by the header for it.
+    let expected = response.content_length().or_else(|| {{
+        let length = response.headers().get(http::header::CONTENT_LENGTH)?;
+        length.to_str().ok()?.parse().ok()
+    }});
+    let written: Result<u64> = async {{
+        let mut file = tokio::fs::File::create(&part).await?;
+        let mut written: u64 = 0;
+        while let Some(chunk) = response.chunk().await? {{
+            tokio::io::AsyncWriteExt::write_all(&mut file, &chunk).await?;
+            written += chunk.len() as u64;
+        }}
+        tokio::io::AsyncWriteExt::flush(&mut file).await?;
+
+        // A connection dropped mid-body can end the chunks early without an error.
+        if let Some(expected) = expected {{
+            if written != expected {{
+                return Err(ClientError::IncompleteDownload {{ expected, written }}.into());
+            }}
+        }}
+        Ok(written)
+    }}
+    .await;
+
+    let written = match written {{
+        Ok(written) => written,
+        Err(e) => {{
+            let _ = tokio::fs::remove_file(&part).await;
+            return Err(e);
+        }}
+    }};
+    if let Err(e) = tokio::fs::rename(&part, path).await {{
+        let _ = tokio::fs::remove_file(&part).await;
+        return Err(e.into());
+    }}
+
+    Ok(written)
 }}
 
 #[allow(dead_code)]
-async fn get_pages<D>(&self, uri: &str) -> Result<(Option<hyperx::header::Link>, Vec<D>)>
+async fn post<D>(&self, uri: &str, message: Option<reqwest::Body>) -> Result<D>
 where
-    D: serde::de::DeserializeOwned + 'static + Send,
+    D: crate::ResponseType,
 {{
-    self.request_with_links(
-        http::Method::GET,
+    self.request_entity(
+        http::Method::POST,
         &(self.host.to_string() + uri),
-        None,
+        message,
     ).await
 }}
 
+/// Post to the uri to create something, then get what it created from the uri
+/// with its id on the end, for the endpoints that only return the id.
 #[allow(dead_code)]
-async fn get_pages_url<D>(&self, url: &reqwest::Url) -> Result<(Option<hyperx::header::Link>, Vec<D>)>
+async fn post_and_get<D>(&self, uri: &str, message: Option<reqwest::Body>) -> Result<D>
 where
-    D: serde::de::DeserializeOwned + 'static + Send,
+    D: crate::ResponseType,
 {{
-    self.request_with_links(
-        http::Method::GET,
-        url.as_str(),
-        None,
-    ).await
+    let created: serde_json::Value = self.post(uri, message).await?;
+    let id = match &created["id"] {{
+        serde_json::Value::String(id) if !id.is_empty() => id.to_string(),
+        serde_json::Value::Number(id) => id.to_string(),
+        _ => return Err(anyhow!("creating {{}} did not return an id", uri)),
+    }};
+
+    // The id goes on the path, not on any query parameters of the post.
+    let path = uri.split('?').next().unwrap_or_default();
+    self.get(&format!("{{}}/{{}}", path, self.encode_path(&id)), None)
+        .await
 }}
 
 #[allow(dead_code)]
-async fn post<D>(&self, uri: &str, message: Option<reqwest::Body>) -> Result<D>
+async fn patch<D>(&self, uri: &str, message: Option<reqwest::Body>) -> Result<D>
 where
-    D: serde::de::DeserializeOwned + 'static + Send,
+    D: crate::ResponseType,
 {{
     self.request_entity(
-        http::Method::POST,
+        http::Method::PATCH,
         &(self.host.to_string() + uri),
         message,
     ).await
 }}
 
+/// Serialize a JSON request body, with the client's body serializer if it
+/// has one.
 #[allow(dead_code)]
-async fn patch<D>(&self, uri: &str, message: Option<reqwest::Body>) -> Result<D>
+fn serialize_body<B>(&self, body: &B) -> Result<reqwest::Body>
 where
-    D: serde::de::DeserializeOwned + 'static + Send,
+    B: serde::Serialize + ?Sized,
 {{
-    self.request_entity(
-        http::Method::PATCH,
-        &(self.host.to_string() + uri),
-        message,
-    ).await
+    match &self.body_serializer {{
+        Some(serializer) => Ok(serializer.serialize(&serde_json::to_value(body)?)?.into()),
+        None => Ok(serde_json::to_vec(body)?.into()),
+    }}
+}}
+
+/// The given page size, or the client's default one if it is `0`.
+#[allow(dead_code)]
+fn page_size_or(&self, page_size: i64) -> i64 {{
+    match self.default_page_size {{
+        Some(size) if page_size == 0 => size,
+        _ => page_size,
+    }}
+}}
+
+/// The body of an upload read from `reader`, with the client to send it with.
+/// Up to the client's request body limit it is read into memory, so the
+/// request can be sent again, past it the rest is streamed as it is read and
+/// the request only gets the one try. The client counts a streamed body as it
+/// is sent, for `with_on_body_size`.
+#[allow(dead_code)]
+async fn reader_body<R>(&self, mut reader: R) -> Result<(Client, reqwest::Body)>
+where
+    R: tokio::io::AsyncRead + Send + Sync + Unpin + 'static,
+{{
+    use futures::{{StreamExt, TryStreamExt}};
+    use tokio::io::AsyncReadExt;
+
+    const CHUNK_SIZE: usize = 64 * 1024;
+
+    let mut head = Vec::new();
+    let limit = match self.request_body_limit {{
+        Some(limit) => limit,
+        None => {{
+            reader.read_to_end(&mut head).await?;
+            return Ok((self.clone(), head.into()));
+        }}
+    }};
+    // Read one byte past the limit, to tell if there is more to stream.
+    (&mut reader).take(limit as u64 + 1).read_to_end(&mut head).await?;
+    if head.len() <= limit {{
+        return Ok((self.clone(), head.into()));
+    }}
+
+    let head = futures::stream::once(async move {{ Ok(bytes::Bytes::from(head)) }});
+    let rest = futures::stream::try_unfold(reader, |mut reader| async move {{
+        let mut chunk = vec![0; CHUNK_SIZE];
+        let n = reader.read(&mut chunk).await?;
+        if n == 0 {{
+            return Ok::<_, std::io::Error>(None);
+        }}
+        chunk.truncate(n);
+        Ok(Some((bytes::Bytes::from(chunk), reader)))
+    }});
+
+    let sent = std::sync::Arc::new(std::sync::atomic::AtomicU64::new(0));
+    let mut client = self.clone();
+    client.streamed_sent = Some(sent.clone());
+    let body = head.chain(rest).inspect_ok(move |chunk| {{
+        sent.fetch_add(chunk.len() as u64, std::sync::atomic::Ordering::SeqCst);
+    }});
+    Ok((client, reqwest::Body::wrap_stream(body)))
+}}
+
+/// Patch the entity at the uri with the fields of `desired` that differ from
+/// `current`. If none do, this returns `None` without sending anything.
+#[allow(dead_code)]
+async fn patch_if_changed<C, B, D>(&self, uri: &str, current: &C, desired: &B) -> Result<Option<D>>
+where
+    C: serde::Serialize + ?Sized,
+    B: serde::Serialize + ?Sized,
+    D: crate::ResponseType,
+{{
+    let changes = match crate::utils::changed_fields(current, desired)? {{
+        Some(changes) => changes,
+        None => return Ok(None),
+    }};
+
+    let message = self.serialize_body(&changes)?;
+    Ok(Some(self.patch(uri, Some(message)).await?))
 }}
 
 #[allow(dead_code)]
 async fn put<D>(&self, uri: &str, message: Option<reqwest::Body>) -> Result<D>
 where
-    D: serde::de::DeserializeOwned + 'static + Send,
+    D: crate::ResponseType,
 {{
     self.request_entity(
         http::Method::PUT,
@@ -1293,19 +4816,87 @@ where
 #[allow(dead_code)]
 async fn delete<D>(&self, uri: &str, message: Option<reqwest::Body>) -> Result<D>
 where
-    D: serde::de::DeserializeOwned + 'static + Send,
+    D: crate::ResponseType,
 {{
     self.request_entity(
         http::Method::DELETE,
         &(self.host.to_string() + uri),
         message,
     ).await
+}}
+
+/// Delete the entity at the uri, returning whether there was anything to
+/// delete. A `404 Not Found` returns `false` rather than an error.
+#[allow(dead_code)]
+async fn delete_if_exists(&self, uri: &str, message: Option<reqwest::Body>) -> Result<bool> {{
+    let response = self
+        .request_raw(http::Method::DELETE, &(self.host.to_string() + uri), message)
+        .await?;
+
+    let status = response.status();
+    let request_id = self.response_request_id(&response);
+    if status == http::StatusCode::NOT_FOUND {{
+        return Ok(false);
+    }}
+
+    let response_body = response.bytes().await?;
+
+    if status.is_success() {{
+        Ok(true)
+    }} else {{
+        Err(ClientError::HttpError {{
+            status,
+            error: String::from_utf8_lossy(&response_body).to_string(),
+            request_id,
+            call: None,
+        }}
+        .into())
+    }}
 }}"#,
         bearer, post_header_args
     )
 }
 
 const TOKEN_AUTH_TEMPLATE: &str = r#"
+/// Check that the API can be reached with the client's token, and return the
+/// scopes in `required` that the token was not granted. The granted scopes are
+/// known once the client has gotten or refreshed its access token, until then
+/// it returns an error rather than report them all missing.
+pub async fn health_with_scopes(&self, required: &[&str]) -> Result<Vec<String>> {
+    // The scopes are only known once the token endpoint has granted them, so
+    // until then every scope would look missing.
+    if self.scope.is_empty() {
+        return Err(ClientError::Other {
+            error: "the granted scopes are not known until the client gets or refreshes its \
+                    access token"
+                .to_string(),
+        }
+        .into());
+    }
+
+    // Any response tells us the API can be reached, unless it refused the token.
+    let resp = self
+        .request_raw(reqwest::Method::GET, HEALTH_PATH, None)
+        .await?;
+    let status = resp.status();
+    if status == reqwest::StatusCode::UNAUTHORIZED {
+        let request_id = self.response_request_id(&resp);
+        return Err(ClientError::HttpError {
+            status,
+            error: resp.text().await?,
+            request_id,
+            call: None,
+        }
+        .into());
+    }
+
+    Ok(required
+        .iter()
+        .filter(|r| !crate::utils::has_scope(&self.scope, &[r]))
+        .map(|r| r.to_string())
+        .collect())
+}
+
 /// Return a user consent url with an optional set of scopes.
 /// If no scopes are provided, they will not be passed in the url.
 pub fn user_consent_url(&self, scopes: &[String]) -> String {
@@ -1354,14 +4945,45 @@ pub async fn refresh_access_token(&mut self) -> Result<AccessToken> {
         .await?;
 
     // Unwrap the response.
-    let t: AccessToken = resp.json().await?;
+    let t = Self::parse_access_token(resp).await?;
 
     self.token = t.access_token.to_string();
     self.refresh_token = t.refresh_token.to_string();
+    // The token endpoint may leave out the scopes if they did not change.
+    if !t.scope.is_empty() {
+        self.scope = t.scope.to_string();
+    }
+    self.set_token_expiry(t.expires_in);
 
     Ok(t)
 }
 
+/// Whether the access token has expired, going by the `expires_in` the token
+/// endpoint sent with it and the client's clock. The client only knows this for
+/// tokens it got or refreshed itself.
+pub fn token_expired(&self) -> bool {
+    self.token_expires_at.map_or(false, |at| self.clock.now() >= at)
+}
+
+/// Refresh the access token if it has expired, see `token_expired`. This
+/// returns the new token, or `None` if the one the client has is still good.
+pub async fn refresh_access_token_if_expired(&mut self) -> Result<Option<AccessToken>> {
+    if !self.token_expired() {
+        return Ok(None);
+    }
+
+    Ok(Some(self.refresh_access_token().await?))
+}
+
+fn set_token_expiry(&mut self, expires_in: i64) {
+    // Zero means the token endpoint did not say.
+    self.token_expires_at = if expires_in > 0 {
+        Some(self.clock.now() + std::time::Duration::from_secs(expires_in as u64))
+    } else {
+        None
+    };
+}
+
 /// Get an access token from the code returned by the URL paramter sent to the
 /// redirect URL.
 pub async fn get_access_token(&mut self, code: &str, state: &str) -> Result<AccessToken> {
@@ -1389,12 +5011,65 @@ pub async fn get_access_token(&mut self, code: &str, state: &str) -> Result<Acce
         .await?;
 
     // Unwrap the response.
-    let t: AccessToken = resp.json().await?;
+    let t = Self::parse_access_token(resp).await?;
 
     self.token = t.access_token.to_string();
     self.refresh_token = t.refresh_token.to_string();
+    // The token endpoint may leave out the scopes if they did not change.
+    if !t.scope.is_empty() {
+        self.scope = t.scope.to_string();
+    }
+    self.set_token_expiry(t.expires_in);
 
     Ok(t)
+}
+
+/// Parse the response from the token endpoint into an access token. If the
+/// token endpoint refused the grant, this returns a `ClientError::AuthExpired`
+/// so the app knows to ask the user to authorize again.
+async fn parse_access_token(resp: reqwest::Response) -> Result<AccessToken> {
+    let status = resp.status();
+    if status.is_success() {
+        return Ok(resp.json().await?);
+    }
+
+    let body = resp.bytes().await?;
+    if let Ok(e) = serde_json::from_slice::<OAuthError>(&body) {
+        if e.error == "invalid_grant" {
+            return Err(ClientError::AuthExpired {
+                error: e.error,
+                error_description: e.error_description,
+            }
+            .into());
+        }
+    }
+
+    Err(anyhow!(
+        "code: {}, error: {:?}",
+        status,
+        String::from_utf8_lossy(&body),
+    ))
+}"#;
+
+const ZOOM_SITE_TEMPLATE: &str = r#"
+/// Scope the phone calls to a site, for accounts with more than one. The
+/// functions that take a `site_id` use this one when they are given an empty
+/// one, the others are sent as they are.
+pub fn scoped_for_site<S>(&self, site_id: S) -> Self
+where
+    S: ToString,
+{
+    let mut c = self.clone();
+    c.site_id = Some(site_id.to_string());
+    c
+}
+
+/// The given site id, or the one the client is scoped to if it is empty.
+fn site_id_or<'a>(&'a self, site_id: &'a str) -> &'a str {
+    match &self.site_id {
+        Some(s) if site_id.is_empty() => s,
+        _ => site_id,
+    }
 }"#;
 
 const CLIENT_AUTH_TEMPLATE: &str = r#"
@@ -1422,11 +5097,38 @@ pub async fn get_access_token(&mut self) -> Result<AccessToken> {
         .await?;
 
     // Unwrap the response.
-    let t: AccessToken = resp.json().await?;
+    let t = Self::parse_access_token(resp).await?;
 
     self.token = t.access_token.to_string();
 
     Ok(t)
+}
+
+/// Parse the response from the token endpoint into an access token. If the
+/// token endpoint refused the grant, this returns a `ClientError::AuthExpired`
+/// so the app knows to ask the user to authorize again.
+async fn parse_access_token(resp: reqwest::Response) -> Result<AccessToken> {
+    let status = resp.status();
+    if status.is_success() {
+        return Ok(resp.json().await?);
+    }
+
+    let body = resp.bytes().await?;
+    if let Ok(e) = serde_json::from_slice::<OAuthError>(&body) {
+        if e.error == "invalid_grant" {
+            return Err(ClientError::AuthExpired {
+                error: e.error,
+                error_description: e.error_description,
+            }
+            .into());
+        }
+    }
+
+    Err(anyhow!(
+        "code: {}, error: {:?}",
+        status,
+        String::from_utf8_lossy(&body),
+    ))
 }"#;
 
 pub fn generate_client_generic_client_credentials(
@@ -1449,6 +5151,31 @@ pub struct Client {{
     client_secret: String,
 
     client: reqwest::Client,
+    http_config: HttpConfig,
+    semaphore: Option<std::sync::Arc<tokio::sync::Semaphore>>,
+    default_query: Vec<(String, String)>,
+    retry_policy: RetryPolicy,
+    deadline: Option<std::time::Instant>,
+    deadline_header: bool,
+    request_id: Option<RequestId>,
+    clock: std::sync::Arc<dyn Clock>,
+    body_serializer: Option<std::sync::Arc<dyn BodySerializer>>,
+    in_flight: Option<std::sync::Arc<InFlight>>,
+    rate_limiter: Option<std::sync::Arc<RateLimiter>>,
+    circuit_breaker: Option<std::sync::Arc<CircuitBreaker>>,
+    fixtures: Option<Fixtures>,
+    on_request: Option<Observer<RequestInfo>>,
+    on_response: Option<Observer<ResponseInfo>>,
+    request_body_limit: Option<usize>,
+    default_page_size: Option<i64>,
+    on_retry_budget: Option<Observer<RetryBudgetInfo>>,
+    interceptor: Option<Interceptor>,
+    on_body_size: Option<Observer<BodySizeInfo>>,
+    streamed_sent: Option<std::sync::Arc<std::sync::atomic::AtomicU64>>,
+    #[cfg(any(test, feature = "failpoints"))]
+    failpoints: Option<std::sync::Arc<Failpoints>>,
+    path_cache: std::sync::Arc<PathCache>,
+    last_rate_limit: std::sync::Arc<std::sync::Mutex<Option<RateLimitHeaders>>>,
 }}
 
 {}
@@ -1467,7 +5194,8 @@ impl Client {{
         K: ToString,
         T: ToString,
     {{
-        let client = reqwest::Client::builder().build();
+        let http_config = HttpConfig::default();
+        let client = http_config.build();
         match client {{
             Ok(c) => {{
                 Client {{
@@ -1477,6 +5205,31 @@ impl Client {{
                     token: token.to_string(),
 
                     client: c,
+                    http_config,
+                    semaphore: None,
+                    default_query: Vec::new(),
+                    retry_policy: RetryPolicy::default(),
+                    deadline: None,
+                    deadline_header: false,
+                    request_id: None,
+                    clock: std::sync::Arc::new(SystemClock),
+                    body_serializer: None,
+                    in_flight: None,
+                    rate_limiter: None,
+                    circuit_breaker: None,
+                    fixtures: None,
+                    on_request: None,
+                    on_response: None,
+                    request_body_limit: None,
+                    default_page_size: None,
+                    on_retry_budget: None,
+                    interceptor: None,
+                    on_body_size: None,
+                    streamed_sent: None,
+                    #[cfg(any(test, feature = "failpoints"))]
+                    failpoints: None,
+                    path_cache: std::sync::Arc::new(PathCache::new(PATH_CACHE_CAPACITY)),
+                    last_rate_limit: Default::default(),
                 }}
             }}
             Err(e) => panic!("creating reqwest client failed: {{:?}}", e),
@@ -1493,6 +5246,352 @@ impl Client {{
         c
      }}
 
+    /// Keep cookies set by the API and send them back on later requests.
+    /// This is needed for endpoints that rely on a session (or CSRF) cookie
+    /// rather than on the token alone.
+    pub fn with_cookie_store(&self) -> Self {{
+        let mut http_config = self.http_config.clone();
+        http_config.cookie_store = true;
+        self.with_http_config(http_config)
+     }}
+
+    /// Set how the client handles redirects. With `RedirectPolicy::None` the
+    /// redirect response is returned as is, so its `Location` can be inspected.
+    pub fn with_redirect_policy(&self, policy: RedirectPolicy) -> Self {{
+        let mut http_config = self.http_config.clone();
+        http_config.redirect_policy = policy;
+        self.with_http_config(http_config)
+     }}
+
+    /// Send requests through the proxy at `url`, whatever their scheme, rather
+    /// than through the ones in `HTTP_PROXY` and `HTTPS_PROXY`. The hosts in
+    /// `NO_PROXY` still bypass it. It fails if `url` is not a valid url.
+    pub fn with_proxy(&self, url: &str) -> Result<Self> {{
+        let proxy_url =
+            ProxyConfig::parse_url(url).ok_or_else(|| anyhow!("invalid proxy url {{:?}}", url))?;
+
+        let mut http_config = self.http_config.clone();
+        let mut proxy = http_config.proxy.take().unwrap_or_else(ProxyConfig::from_env);
+        proxy.http = Some(proxy_url.clone());
+        proxy.https = Some(proxy_url);
+        http_config.proxy = Some(proxy);
+        Ok(self.with_http_config(http_config))
+     }}
+
+    /// Bypass the proxy for the hosts in `no_proxy`, a comma separated list in
+    /// the format of `NO_PROXY`, rather than for the ones in `NO_PROXY`.
+    pub fn with_no_proxy(&self, no_proxy: &str) -> Self {{
+        let mut http_config = self.http_config.clone();
+        let mut proxy = http_config.proxy.take().unwrap_or_else(ProxyConfig::from_env);
+        proxy.no_proxy = ProxyConfig::parse_no_proxy(no_proxy);
+        http_config.proxy = Some(proxy);
+        self.with_http_config(http_config)
+     }}
+
+    /// Connect with the given TLS implementation, rather than the default one.
+    pub fn with_tls_backend(&self, backend: TlsBackend) -> Self {{
+        let mut http_config = self.http_config.clone();
+        http_config.tls_backend = backend;
+        self.with_http_config(http_config)
+     }}
+
+    /// Keep at most `n` idle connections to each host open for reuse. By
+    /// default all of them are kept, so a burst of concurrent calls can leave
+    /// many sockets open; a server making lots of calls may want a cap around
+    /// its usual concurrency, like 32.
+    pub fn with_max_idle_per_host(&self, n: usize) -> Self {{
+        let mut http_config = self.http_config.clone();
+        http_config.pool_max_idle_per_host = Some(n);
+        self.with_http_config(http_config)
+     }}
+
+    /// Close connections that have been idle for `timeout`, rather than the
+    /// default of 90 seconds.
+    pub fn with_pool_idle_timeout(&self, timeout: std::time::Duration) -> Self {{
+        let mut http_config = self.http_config.clone();
+        http_config.pool_idle_timeout = Some(timeout);
+        self.with_http_config(http_config)
+     }}
+
+    /// Limit the number of requests the client has in flight at once. Any
+    /// more wait for one of those to finish before they are sent. A limit of
+    /// 0 is no limit, as no request could ever be sent under it.
+    pub fn with_max_concurrent_requests(&self, n: usize) -> Self {{
+        let mut c = self.clone();
+        c.semaphore = match n {{
+            0 => None,
+            n => Some(std::sync::Arc::new(tokio::sync::Semaphore::new(n))),
+        }};
+        c
+     }}
+
+    /// Add the given query parameters to every request, for example to scope
+    /// requests to an account. A parameter a function sets itself wins over a
+    /// default one of the same name.
+    pub fn with_default_query(&self, query: &[(&str, &str)]) -> Self {{
+        let mut c = self.clone();
+        c.default_query = query
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        c
+     }}
+
+    /// Retry requests that fail with a connection error, a `429 Too Many Requests`
+    /// or a server error, as the policy says. Use `retry` on a tag to override
+    /// it for the calls made through that tag.
+    pub fn with_retry(&self, policy: RetryPolicy) -> Self {{
+        let mut c = self.clone();
+        c.retry_policy = policy;
+        c
+     }}
+
+    /// Give up on the calls made with the returned client once `deadline` passes,
+    /// for example to pass on the deadline of an incoming request. This bounds
+    /// the time of a call across its retries too. A call that is out of time
+    /// returns a `ClientError::DeadlineExceeded`.
+    pub fn with_deadline(&self, deadline: std::time::Instant) -> Self {{
+        let mut c = self.clone();
+        c.deadline = Some(deadline);
+        c
+     }}
+
+    /// Also tell the API about the deadline, in an `X-Request-Deadline` header
+    /// with the time it passes at.
+    pub fn with_deadline_header(&self) -> Self {{
+        let mut c = self.clone();
+        c.deadline_header = true;
+        c
+     }}
+
+    /// Send a random UUID with every request in the `header` header, so a call
+    /// can be traced end to end. The error for a response with an error status
+    /// carries the id of its request. It fails if `header` is not a valid header
+    /// name.
+    pub fn with_request_id(&self, header: &str) -> Result<Self> {{
+        self.with_request_id_generator(header, || uuid::Uuid::new_v4().to_string())
+     }}
+
+    /// Send an id made by `generate` with every request in the `header` header,
+    /// as `with_request_id` does with a random UUID. Retries of a request send
+    /// the same id. It fails if `header` is not a valid header name.
+    pub fn with_request_id_generator<F>(&self, header: &str, generate: F) -> Result<Self>
+    where
+        F: Fn() -> String + Send + Sync + 'static,
+    {{
+        let header = reqwest::header::HeaderName::from_bytes(header.as_bytes())
+            .map_err(|e| anyhow!("invalid request id header {{:?}}: {{}}", header, e))?;
+
+        let mut c = self.clone();
+        c.request_id = Some(RequestId {{
+            header,
+            generate: std::sync::Arc::new(generate),
+        }});
+        Ok(c)
+     }}
+
+    /// Use `clock` for the time rather than the system clock, for example a
+    /// `MockClock` to test how the client retries or when its token expires.
+    pub fn with_clock<C>(&self, clock: C) -> Self
+    where
+        C: Clock + 'static,
+    {{
+        let mut c = self.clone();
+        c.clock = std::sync::Arc::new(clock);
+        c
+     }}
+
+    /// Serialize JSON request bodies with `serializer` rather than
+    /// `serde_json`, for example with `CanonicalJson` ahead of signing them.
+    pub fn with_body_serializer<S>(&self, serializer: S) -> Self
+    where
+        S: BodySerializer + 'static,
+    {{
+        let mut c = self.clone();
+        c.body_serializer = Some(std::sync::Arc::new(serializer));
+        c
+     }}
+
+    /// Send identical `GET`s that are in flight at the same time only once, and
+    /// share the response between them. This saves calls when many tasks ask
+    /// for the same thing at once, like the settings of the same user.
+    pub fn with_single_flight(&self, enabled: bool) -> Self {{
+        let mut c = self.clone();
+        c.in_flight = if enabled {{
+            Some(Default::default())
+        }} else {{
+            None
+        }};
+        c
+     }}
+
+    /// Send at most as many requests as `limit` allows, shared with the clones
+    /// of the client made after this. Requests over it wait for their turn, or
+    /// fail with a `ClientError::RateLimited` if `limit` has a max wait they
+    /// would go over.
+    pub fn with_rate_limit(&self, limit: RateLimit) -> Self {{
+        let mut c = self.clone();
+        c.rate_limiter = Some(std::sync::Arc::new(RateLimiter {{
+            limit,
+            full_at: Default::default(),
+        }}));
+        c
+     }}
+
+    /// Stop calling a host that keeps failing, as `config` says: calls to it
+    /// fail at once with a `ClientError::CircuitOpen` while its circuit is open.
+    /// The circuits are shared with the clones of the client made after this.
+    pub fn with_circuit_breaker(&self, config: CircuitConfig) -> Self {{
+        let mut c = self.clone();
+        c.circuit_breaker = Some(std::sync::Arc::new(CircuitBreaker {{
+            config,
+            circuits: Default::default(),
+        }}));
+        c
+     }}
+
+    /// Replay the responses to requests from the fixture files in `dir`, rather
+    /// than sending them, to test against the API offline. With the `record`
+    /// feature and `RECORD_FIXTURES` set, the requests are sent and their
+    /// responses written to `dir` instead.
+    pub fn with_fixtures<P>(&self, dir: P) -> Self
+    where
+        P: Into<std::path::PathBuf>,
+    {{
+        let mut c = self.clone();
+        c.fixtures = Some(Fixtures {{
+            dir: dir.into(),
+            record: Fixtures::recording(),
+        }});
+        c
+     }}
+
+    /// Call `f` before each attempt at a request, retries included, with its
+    /// method, url and attempt, say to count calls for metrics. `f` runs on
+    /// the request's task, so it should be quick.
+    pub fn with_on_request<F>(&self, f: F) -> Self
+    where
+        F: Fn(&RequestInfo) + Send + Sync + 'static,
+    {{
+        let mut c = self.clone();
+        c.on_request = Some(std::sync::Arc::new(f));
+        c
+     }}
+
+    /// Call `f` after each attempt at a request, retries included, with its
+    /// status and how long it took, say for an audit log.
+    pub fn with_on_response<F>(&self, f: F) -> Self
+    where
+        F: Fn(&ResponseInfo) + Send + Sync + 'static,
+    {{
+        let mut c = self.clone();
+        c.on_response = Some(std::sync::Arc::new(f));
+        c
+     }}
+
+    /// Read uploads from a reader, with the `_from_reader` functions, into
+    /// memory only up to `limit` bytes, so the request can be sent again, and
+    /// stream the rest as it is read. A streamed upload only gets the one try,
+    /// it is not retried. Without a limit the whole reader is read first.
+    pub fn with_request_body_limit(&self, limit: usize) -> Self {{
+        let mut c = self.clone();
+        c.request_body_limit = Some(limit);
+        c
+     }}
+
+    /// Ask for pages of `size` items from the functions that take a `page_size`,
+    /// when they are passed `0` for it, rather than the API's default.
+    pub fn with_default_page_size(&self, size: i64) -> Self {{
+        let mut c = self.clone();
+        c.default_page_size = Some(size);
+        c
+     }}
+
+    /// Call `f` once a call is done with how many retries it used and whether
+    /// it ran out of them, say to alert on calls that exhaust their retry
+    /// budget. Uploads streamed past `with_request_body_limit` are never
+    /// retried and are not reported.
+    pub fn with_on_retry_budget<F>(&self, f: F) -> Self
+    where
+        F: Fn(&RetryBudgetInfo) + Send + Sync + 'static,
+    {{
+        let mut c = self.clone();
+        c.on_retry_budget = Some(std::sync::Arc::new(f));
+        c
+     }}
+
+    /// Call `f` with the url of each request, with its query, before it is
+    /// sent, to change it. Unlike `with_host`, it sees the whole url, say to
+    /// route an account's requests to the host of its region by an id in the
+    /// path. The change applies to the retries of the request too.
+    pub fn with_interceptor<F>(&self, f: F) -> Self
+    where
+        F: Fn(&mut reqwest::Url) + Send + Sync + 'static,
+    {{
+        let mut c = self.clone();
+        c.interceptor = Some(std::sync::Arc::new(f));
+        c
+     }}
+
+    /// Call `f` after each attempt at a request that got a response, retries
+    /// included, with the sizes of its request and response bodies, say to
+    /// watch bandwidth or what calls cost. The bodies are counted as they are
+    /// sent and read, not held in memory, and `f` is called once the response
+    /// body has been read to its end, or dropped.
+    pub fn with_on_body_size<F>(&self, f: F) -> Self
+    where
+        F: Fn(&BodySizeInfo) + Send + Sync + 'static,
+    {{
+        let mut c = self.clone();
+        c.on_body_size = Some(std::sync::Arc::new(f));
+        c
+     }}
+
+    /// Inject the failures of `failpoints` into the client's requests, in place
+    /// of their responses, to test how the caller handles them. The clones of
+    /// the client share them, so each failure is injected once in all.
+    #[cfg(any(test, feature = "failpoints"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "failpoints")))]
+    pub fn with_failpoints(&self, failpoints: Failpoints) -> Self {{
+        let mut c = self.clone();
+        c.failpoints = Some(std::sync::Arc::new(failpoints));
+        c
+     }}
+
+    /// Keep the encodings of about the last `capacity` path parameters the
+    /// client used, rather than the default of 32, or none with a capacity of 0.
+    /// The clones of the client share them.
+    pub fn with_path_cache(&self, capacity: usize) -> Self {{
+        let mut c = self.clone();
+        c.path_cache = std::sync::Arc::new(PathCache::new(capacity));
+        c
+    }}
+
+    /// Percent-encode a path parameter, as the functions do with each of theirs,
+    /// reusing the encoding if the parameter was used recently.
+    pub fn encode_path(&self, pc: &str) -> EncodedPath {{
+        self.path_cache.encode(pc)
+    }}
+
+    /// The rate limit the server reported with the last response that had one,
+    /// to any request of this client or of its clones, to throttle requests
+    /// before the server starts turning them away.
+    pub fn last_rate_limit(&self) -> Option<RateLimitHeaders> {{
+        *self.last_rate_limit.lock().unwrap()
+    }}
+
+    /// Create a client for another token. The new client shares the
+    /// connection pool and settings of this one, so serving many users does
+    /// not mean building a client for each of them.
+    pub fn clone_with_token<T>(&self, token: T) -> Self
+    where
+        T: ToString,
+    {{
+        let mut c = self.clone();
+        c.token = token.to_string();
+        c
+     }}
+
     /// Create a new Client struct from environment variables. It
     /// takes a type that can convert into
     /// an &str (`String` or `Vec<u8>` for example). As long as the function is
@@ -1564,4 +5663,14 @@ pub struct AccessToken {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub scope: String,
-}"#;
+}
+
+/// The error body returned by an OAuth token endpoint.
+#[derive(Debug, Clone, Default, Deserialize)]
+struct OAuthError {
+    #[serde(default)]
+    error: String,
+    #[serde(default)]
+    error_description: String,
+}
+"#;
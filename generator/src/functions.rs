@@ -1,14 +1,53 @@
 use std::collections::BTreeMap;
 
-use anyhow::{bail, Result};
+use anyhow::{bail, Context, Result};
 use inflector::cases::snakecase::to_snake_case;
 
+use crate::types::struct_field_name;
 use crate::{
-    clean_fn_name, clean_name, get_parameter_data, make_plural, oid_to_object_name,
-    path_to_operation_id, struct_name, template::parse, ExtractJsonMediaType, ParameterDataExt,
-    ReferenceOrExt, TypeId, TypeSpace,
+    clean_fn_name, clean_name, get_parameter_data, legacy_fn_name, make_plural, oid_to_object_name,
+    path_to_operation_id, struct_name,
+    template::{parse, ArrayStyle},
+    BodyKind, ExtractJsonMediaType, ParameterDataExt, ReferenceOrExt, TypeId, TypeSpace,
 };
 
+/*
+ * How many query parameters a function can take by position before it also
+ * gets a builder for its request, see `query_builder`.
+ */
+const MAX_POSITIONAL_QUERY_PARAMS: usize = 5;
+
+/*
+ * The list endpoints, by API and path, whose pages can be too large to hold in
+ * memory at once, like the account-wide call logs. Only these also get a
+ * `_stream_json` function that hands out the items as they are parsed.
+ */
+const STREAM_JSON_PATHS: &[(&str, &[&str])] = &[
+    ("Google Admin", &["/admin/directory/v1/users"]),
+    ("Google Calendar", &["/calendars/{calendarId}/events"]),
+    ("Google Drive", &["/changes", "/files"]),
+    ("Okta", &["/api/v1/logs", "/api/v1/users"]),
+    ("Ramp", &["/transactions"]),
+    ("ShipBob", &["/order"]),
+    (
+        "Zoom",
+        &[
+            "/phone/call_logs",
+            "/phone/metrics/call_logs",
+            "/phone/users/{userId}/call_logs",
+        ],
+    ),
+];
+
+/*
+ * Dates the specs take as plain strings, by API, path and parameter. These are
+ * taken as a `chrono::NaiveDate`, or an `Option` of one if the API does not
+ * require them. The specs are downloaded as they are, so this is where they
+ * are marked.
+ */
+const DATE_PARAMS: &[(&str, &str, &[&str])] =
+    &[("Zoom", "/im/users/{userId}/chat/messages", &["date"])];
+
 /*
  * Generate a function for each Operation.
  */
@@ -17,8 +56,18 @@ pub fn generate_files(
     proper_name: &str,
     ts: &mut TypeSpace,
     parameters: &BTreeMap<String, &openapiv3::Parameter>,
-) -> Result<BTreeMap<String, String>> {
+) -> Result<(
+    BTreeMap<String, String>,
+    BTreeMap<String, String>,
+    BTreeMap<String, String>,
+)> {
     let mut tag_files: BTreeMap<String, String> = Default::default();
+    // The functions of each tag for the `blocking` module.
+    let mut tag_blocking: BTreeMap<String, String> = Default::default();
+    // The signatures for each tag's mock trait and the impl forwarding them.
+    let mut tag_traits: BTreeMap<String, (String, String)> = Default::default();
+    // The request builders of each tag, which go after its impl.
+    let mut tag_builders: BTreeMap<String, String> = Default::default();
 
     let mut fn_names: Vec<String> = Default::default();
     for (pn, p) in api.paths.iter() {
@@ -64,11 +113,21 @@ pub fn generate_files(
 
             let oid = clean_fn_name(proper_name, &od, &tag);
 
+            // The functions only a master account can call go in a file of their own.
+            let file_tag = master_tag(proper_name, &tag, o).unwrap_or_else(|| tag.to_string());
+
             let mut out = String::new();
-            if let Some(o) = tag_files.get(&tag) {
+            if let Some(o) = tag_files.get(&file_tag) {
                 out = o.to_string();
             }
 
+            let (mut trait_out, mut impl_out) =
+                tag_traits.get(&file_tag).cloned().unwrap_or_default();
+            let mut blocking_out = tag_blocking.get(&file_tag).cloned().unwrap_or_default();
+
+            // The path parameters, to say which call failed in its errors.
+            let url_args = parse(p)?.args();
+
             let mut a = |s: &str| {
                 out.push_str(s);
                 out.push('\n');
@@ -81,7 +140,8 @@ pub fn generate_files(
                                 response_type: &str,
                                 template: &str,
                                 fn_inner: &str,
-                                fn_name: &str| {
+                                fn_name: &str|
+             -> Result<()> {
                 // Print the function docs.
                 a(docs);
 
@@ -108,21 +168,120 @@ pub fn generate_files(
 
                 a(&format!(") -> Result<{}> {{", response_type));
 
-                a(template);
+                let mut body = String::new();
+
+                // Refuse parameters the docs say to pass one or the other of before
+                // we send anything.
+                if proper_name != "GitHub" {
+                    for (x, y) in exclusive_params(o, parameters, fn_params_str) {
+                        body.push_str(&format!(
+                            r#"if !{}.is_empty() && !{}.is_empty() {{
+                                return Err(crate::ClientError::ConflictingParams {{
+                                    params: vec!["{}".to_string(), "{}".to_string()],
+                                }});
+                            }}
+"#,
+                            x, y, x, y
+                        ));
+                    }
+                }
 
-                a(fn_inner);
+                body.push_str(template);
+                body.push('\n');
 
+                if proper_name != "GitHub" {
+                    body.push_str(&with_url_context(fn_inner, m, p, &url_args));
+                } else {
+                    body.push_str(fn_inner);
+                }
+
+                a(&body);
                 a("}");
                 a("");
+
+                // The same function for the `blocking` module. The ones streaming
+                // their upload or response need a runtime, so are left out of it, as
+                // are the deprecated aliases.
+                if proper_name != "GitHub"
+                    && !response_type.starts_with("impl ")
+                    && !bounds
+                        .iter()
+                        .any(|b| b.contains("tokio::") || b.contains("futures::"))
+                    && !docs.contains("#[deprecated(")
+                {
+                    let body = blocking_fn_body(&body)
+                        .with_context(|| format!("no blocking form of `{}`", fn_name))?;
+                    let mut params = fn_params_str.join(" ");
+                    if let Some(bp) = &body_param {
+                        params.push_str(&format!(" body: {}", bp));
+                    }
+                    let generics = if bounds.is_empty() {
+                        "".to_string()
+                    } else {
+                        format!("<{}>", bounds.join(", "))
+                    };
+                    blocking_out.push_str(&format!(
+                        "/// The blocking form of [`crate::{}::{}::{}`].\n\
+                         pub fn {}{}(&self, {}) -> Result<{}> {{\n{}\n}}\n\n",
+                        to_snake_case(&clean_name(&file_tag)),
+                        struct_name(&file_tag),
+                        fn_name,
+                        fn_name,
+                        generics,
+                        params,
+                        response_type,
+                        body
+                    ));
+                }
+
+                // Generic functions, and those returning an `impl Trait`, would keep
+                // the trait from being made into an object, so they are left out of
+                // it. So are the deprecated aliases, the trait never had them.
+                if proper_name != "GitHub"
+                    && bounds.is_empty()
+                    && !response_type.starts_with("impl ")
+                    && !docs.contains("#[deprecated(")
+                {
+                    let mut params = fn_params_str.join(" ");
+                    let mut args: Vec<&str> = fn_params_str
+                        .iter()
+                        .filter_map(|p| p.split(':').next())
+                        .collect();
+                    if let Some(bp) = &body_param {
+                        params.push_str(&format!(" body: {}", bp));
+                        args.push("body");
+                    }
+
+                    trait_out.push_str(&format!(
+                        "async fn {}(&self, {}) -> Result<{}>;\n",
+                        fn_name, params, response_type
+                    ));
+                    impl_out.push_str(&format!(
+                        "async fn {}(&self, {}) -> Result<{}> {{\n{}::{}(self, {}).await\n}}\n",
+                        fn_name,
+                        params,
+                        response_type,
+                        struct_name(&file_tag),
+                        fn_name,
+                        args.join(", ")
+                    ));
+                }
+
+                Ok(())
             };
 
-            let docs = get_fn_docs(o, m, p, parameters, ts)?;
+            let docs = get_fn_docs(o, m, p, parameters, ts, proper_name)?;
 
             let mut bounds: Vec<String> = Vec::new();
 
             let (body_param, body_func) = if let Some(b) = &o.request_body {
                 if let Ok(b) = b.item() {
-                    if b.is_binary()? {
+                    if b.is_binary()? && proper_name != "GitHub" {
+                        // Take anything we can borrow bytes from, so slices, vectors and
+                        // `Bytes` all work, and buffer it so the request can be sent again.
+                        bounds.push("B: AsRef<[u8]>".to_string());
+                        (Some("B".to_string()), Some("bytes".to_string()))
+                    } else if b.is_binary()? {
                         bounds.push("B: Into<reqwest::Body>".to_string());
                         (Some("B".to_string()), Some("body".to_string()))
                     } else {
@@ -133,7 +292,12 @@ pub fn generate_files(
                         {
                             if let Some(s) = &mt.schema {
                                 let object_name = format!("{} request", oid_to_object_name(&od));
-                                let id = ts.select(Some(&object_name), s, "")?;
+                                // Only JSON bodies were given their types up front.
+                                let id = if ct == "application/json" {
+                                    ts.select_body(api, &object_name, s, BodyKind::Request)?
+                                } else {
+                                    ts.select(Some(&object_name), s, "")?
+                                };
                                 let et = ts.id_to_entry.get(&id).unwrap();
                                 if let crate::TypeDetails::Object(p, _) = &et.details {
                                     // We want to make sure we actally have properties
@@ -172,6 +336,17 @@ pub fn generate_files(
                             } else {
                                 (None, None)
                             }
+                        } else if ct == "multipart/form-data" && proper_name != "GitHub" {
+                            // The files of the form are taken as bytes of their own, and
+                            // the rest of its fields from a request struct, if it has any.
+                            if let Some(s) = multipart_text_schema(o) {
+                                let object_name = format!("{} request", oid_to_object_name(&od));
+                                let id = ts.select(Some(&object_name), &s, "")?;
+                                let rt = ts.render_type(&id, false)?;
+                                (Some(format!("&{}", rt)), Some("multipart".to_string()))
+                            } else {
+                                (None, Some("multipart".to_string()))
+                            }
                         } else if ct == "multipart/form-data" {
                             println!("got multipart/formdata for {}", oid);
                             // Skip it for now.
@@ -185,10 +360,14 @@ pub fn generate_files(
                         } else if let Some(s) = &mt.schema {
                             let tid = ts.select(None, s, "")?;
                             let rt = ts.render_type(&tid, false)?;
-                            bounds.push("T: Into<reqwest::Body>".to_string());
-                            if rt == "String" {
+                            if rt == "bytes::Bytes" && proper_name != "GitHub" {
+                                bounds.push("B: AsRef<[u8]>".to_string());
+                                (Some("B".to_string()), Some("bytes".to_string()))
+                            } else if rt == "String" {
+                                bounds.push("T: Into<reqwest::Body>".to_string());
                                 (Some("T".to_string()), Some("body".to_string()))
                             } else {
+                                bounds.push("T: Into<reqwest::Body>".to_string());
                                 (Some(rt), Some("body".to_string()))
                             }
                         } else {
@@ -211,20 +390,36 @@ pub fn generate_files(
             /*
              * Get the function parameters.
              */
-            let (fn_params_str, query_params) =
-                get_fn_params(ts, o, parameters, false, op.parameters.clone(), proper_name)?;
+            let (mut fn_params_str, query_params) = get_fn_params(
+                ts,
+                o,
+                p,
+                parameters,
+                false,
+                op.parameters.clone(),
+                proper_name,
+            )?;
+            let files = if body_func.as_deref() == Some("multipart") {
+                multipart_files(o)
+            } else {
+                Vec::new()
+            };
+            for (name, _) in &files {
+                fn_params_str.push(format!("{}: &[u8],", struct_field_name(name)));
+            }
 
             /*
              * Generate the URL for the request.
              */
             let tmp = parse(p)?;
-            let template = tmp.compile(query_params);
+            let query_names: Vec<String> = query_params.keys().cloned().collect();
+            let template = tmp.compile(query_params, &fn_params_str, proper_name);
 
             /*
              * Get the response type.
              */
             let (mut response_type, tid, inner_response_type, pagination_property) =
-                get_response_type(&od, ts, o)?;
+                get_response_type(api, &od, ts, o)?;
 
             if proper_name == "GitHub" && response_type == "crate::types::Data" {
                 response_type = "()".to_string();
@@ -248,6 +443,28 @@ pub fn generate_files(
                 false,
             )?;
 
+            // A form is sent as a multipart body, with a text part for each field
+            // of the request struct and a part for each file, named as in the spec.
+            if body_func.as_deref() == Some("multipart") {
+                let mut form = if body_param.is_some() {
+                    "crate::utils::multipart_form(body)?".to_string()
+                } else {
+                    "reqwest::multipart::Form::new()".to_string()
+                };
+                for (name, _) in &files {
+                    form.push_str(&format!(
+                        ".part(\"{}\", reqwest::multipart::Part::bytes({}.to_vec()).file_name(\"{}\"))",
+                        name,
+                        struct_field_name(name),
+                        name
+                    ));
+                }
+                fn_inner = format!(
+                    "let form = {};\n\nself.client.request_form(reqwest::Method::{}, &url, form).await",
+                    form, m
+                );
+            }
+
             // TODO: don't special case this.
             if p == "/jobs/{id}/transcript" || p == "/jobs/{id}/captions" {
                 fn_inner =
@@ -256,6 +473,78 @@ pub fn generate_files(
                 response_type = "String".to_string();
             }
 
+            // A multipart response, like a document along with its metadata, is
+            // split into its parts rather than parsed as JSON.
+            if proper_name != "GitHub"
+                && response_type == "crate::Multipart"
+                && fn_inner == "self.client.get(&url, None).await"
+            {
+                if let Some(accept) = response_mime_type(o) {
+                    fn_inner = format!(
+                        r#"self.client.request_multipart(reqwest::Method::GET, &url, "{}").await"#,
+                        accept
+                    );
+                }
+            }
+
+            // The client asks for JSON by default, which the API may refuse if it
+            // only sends back, say, a PDF or plain text. Ask for that instead.
+            if proper_name != "GitHub" && fn_inner == "self.client.get(&url, None).await" {
+                if let Some(accept) = response_mime_type(o) {
+                    fn_inner = format!(
+                        r#"self.client.request_with_accept_mime(reqwest::Method::GET, &url, "{}").await"#,
+                        accept
+                    );
+                }
+            }
+
+            // The API takes any numbers to add, check them before we send them.
+            if proper_name == "Zoom" && p == "/phone/byoc_numbers" && http::Method::POST == m {
+                fn_inner = format!("body.validate()?;\n\n{}", fn_inner);
+            }
+
+            // The API answers an empty message with an error, so do not send one.
+            if proper_name == "Zoom" && p == "/im/users/me/chat/messages" && http::Method::POST == m
+            {
+                fn_inner = format!("body.validate()?;\n\n{}", fn_inner);
+            }
+
+            // Check the arrays of the body have as many items as the spec allows,
+            // rather than have the API refuse the request. `call` is the call
+            // without the checks, to tell what kind of call it is.
+            let call = fn_inner.clone();
+            let mut checks = String::new();
+            if proper_name != "GitHub" && body_func.as_deref() == Some("json") {
+                checks = body_item_bounds(api, o)
+                    .iter()
+                    .map(|(name, min, max)| {
+                        format!(
+                            "crate::utils::check_items(\"{}\", body.{}.len(), {:?}, {:?})?;\n",
+                            name,
+                            struct_field_name(name),
+                            min,
+                            max
+                        )
+                    })
+                    .collect();
+                if !checks.is_empty() {
+                    checks.push('\n');
+                    fn_inner = format!("{}{}", checks, fn_inner);
+                }
+            }
+
+            // Zoom fails to unassign a plan the user does not have with an error
+            // that does not say so, check it first if the client is set to.
+            if proper_name == "Zoom"
+                && p == "/phone/users/{userId}/calling_plans/{type}"
+                && http::Method::DELETE == m
+            {
+                fn_inner = format!(
+                    "self.client.check_calling_plan(user_id, type_).await?;\n\n{}",
+                    fn_inner
+                );
+            }
+
             if let Some(te) = ts.id_to_entry.get(&tid) {
                 // If we have a one of, we can generate a few different subfunctions to
                 // help as well.
@@ -275,7 +564,7 @@ pub fn generate_files(
                                 oid.trim_start_matches(&tag).trim_start_matches('_'),
                                 to_snake_case(&rt.replace("crate::types::", ""))
                             ))),
-                        );
+                        )?;
                     }
                 }
             }
@@ -287,6 +576,11 @@ pub fn generate_files(
             if !inner_response_type.is_empty() {
                 frt = inner_response_type.to_string();
             }
+            // A `204 No Content` parses as `null`, which only an `Option` takes.
+            let no_content = proper_name != "GitHub"
+                && inner_response_type.is_empty()
+                && frt != "()"
+                && may_answer_no_content(o);
 
             let mut fn_name = oid
                 .trim_start_matches(&tag)
@@ -322,6 +616,36 @@ pub fn generate_files(
             }
             fn_names.push(fn_name.clone() + &tag);
 
+            let docs = if no_content {
+                frt = format!("Option<{}>", frt);
+                format!(
+                    "{}\n*\n* It returns `None` if the API answers `204 No Content`.\n*/",
+                    docs.trim_end_matches("*/").trim_end()
+                )
+            } else {
+                docs
+            };
+
+            // If the docs tell us which OAuth scopes the operation needs, put them
+            // in a constant next to the function so they can be checked up front.
+            let scopes = get_fn_scopes(o);
+            let docs = if scopes.is_empty() {
+                docs
+            } else {
+                format!(
+                    "/// The OAuth scopes for `{}`, any one of them grants access.\npub const \
+                     {}_SCOPES: &[&str] = &[{}];\n\n{}",
+                    fn_name,
+                    fn_name.to_uppercase(),
+                    scopes
+                        .iter()
+                        .map(|s| format!("\"{}\"", s))
+                        .collect::<Vec<String>>()
+                        .join(", "),
+                    docs
+                )
+            };
+
             // Print our standard function.
             print_fn(
                 &docs,
@@ -332,7 +656,396 @@ pub fn generate_files(
                 &template,
                 &fn_inner,
                 &fn_name,
-            );
+            )?;
+
+            // If the function takes more query parameters than can be told apart by
+            // position, let's also generate a builder for its request, to set them
+            // by name and leave out the rest.
+            let mut request_builder = None;
+            if proper_name == "Zoom"
+                && query_names.len() > MAX_POSITIONAL_QUERY_PARAMS
+                && body_param.is_none()
+                && bounds.is_empty()
+            {
+                let request_fn_name = format!("{}_request", fn_name);
+                if !fn_names.contains(&(request_fn_name.clone() + &tag)) {
+                    fn_names.push(request_fn_name + &tag);
+
+                    request_builder = Some(query_builder(
+                        &struct_name(&file_tag),
+                        &fn_name,
+                        &fn_params_str,
+                        &query_names,
+                        &frt,
+                    ));
+                }
+            }
+
+            // If we used to generate this function with a stray article in its name,
+            // keep the old name around as a deprecated alias so we don't break anyone.
+            let legacy_name = legacy_fn_name(proper_name, &od, &tag)
+                .trim_start_matches(&tag)
+                .trim_start_matches('_')
+                .to_string();
+            if legacy_name != oid.trim_start_matches(&tag).trim_start_matches('_')
+                && !fn_names.contains(&(legacy_name.clone() + &tag))
+            {
+                fn_names.push(legacy_name.clone() + &tag);
+
+                print_fn(
+                    &format!(
+                        "/**\n* This function is an alias of `{}`.\n*/\n#[deprecated(note = \"use \
+                         `{}` instead\")]",
+                        fn_name, fn_name
+                    ),
+                    &bounds,
+                    &fn_params_str,
+                    &body_param,
+                    &frt,
+                    &template,
+                    &fn_inner,
+                    &legacy_name,
+                )?;
+            }
+
+            // If we are returning a list that can be huge, let's also generate a
+            // function that streams the items as they are parsed, rather than holding
+            // the whole response body and the resulting vector in memory at once.
+            if frt.starts_with("Vec<")
+                && http::Method::GET == m
+                && STREAM_JSON_PATHS
+                    .iter()
+                    .any(|(api, paths)| *api == proper_name && paths.contains(&p))
+            {
+                let docs = get_fn_docs_variant(
+                    o,
+                    m,
+                    p,
+                    &format!(
+                        "As opposed to `{}`, this function streams the items as they are parsed \
+                         from the response, rather than returning them all at once.\n\
+                         This is useful for very large responses that would otherwise need to be \
+                         held in memory.\n\
+                         Only the one page the parameters ask for is streamed, the next pages are \
+                         not fetched.",
+                        fn_name
+                    ),
+                )?;
+
+                let stream_fn_name = format!("{}_stream_json", fn_name);
+                if !fn_names.contains(&(stream_fn_name.clone() + &tag)) {
+                    fn_names.push(stream_fn_name.clone() + &tag);
+
+                    let item_type = &frt["Vec<".len()..frt.len() - 1];
+
+                    print_fn(
+                        &docs,
+                        &bounds,
+                        &fn_params_str,
+                        &body_param,
+                        &format!("impl futures::Stream<Item = Result<{}>>", item_type),
+                        &template,
+                        &format!(
+                            "self.client.get_stream_json(&url, \"{}\").await",
+                            pagination_property
+                        ),
+                        &stream_fn_name,
+                    )?;
+                }
+            }
+
+            // If we are returning a page of a list from Zoom, let's also generate a
+            // function that hands back the token of the next page with the items, for
+            // callers who get one page at a time without navigating the response.
+            if proper_name == "Zoom"
+                && http::Method::GET == m
+                && frt.starts_with("Vec<")
+                && !inner_response_type.is_empty()
+                && fn_inner.ends_with(&format!("Ok(resp.{})", to_snake_case(&pagination_property)))
+            {
+                let page_fn_name = if fn_name == "get_page" {
+                    "get_page_with_token".to_string()
+                } else {
+                    format!("{}_page", fn_name)
+                };
+                if !fn_names.contains(&(page_fn_name.clone() + &tag)) {
+                    fn_names.push(page_fn_name.clone() + &tag);
+
+                    let docs = get_fn_docs_variant(
+                        o,
+                        m,
+                        p,
+                        &format!(
+                            "As opposed to `{}`, this function also returns the token of the next \
+                             page, to pass as `next_page_token` to get it. The token is empty on \
+                             the last page.",
+                            fn_name
+                        ),
+                    )?;
+
+                    print_fn(
+                        &docs,
+                        &bounds,
+                        &fn_params_str,
+                        &body_param,
+                        &format!("({}, String)", frt),
+                        &template,
+                        &format!(
+                            "let resp: {} = self.client.get(&url, None).await?;\n\n// Return our \
+                             response data, and where the next page starts.\nOk((resp.{}, \
+                             resp.next_page_token))",
+                            response_type,
+                            to_snake_case(&pagination_property)
+                        ),
+                        &page_fn_name,
+                    )?;
+                }
+            }
+
+            // If the endpoint takes its time range as strings, which can be a date or
+            // a date and time, let's also generate a function that takes times and
+            // formats them the way the API wants, so callers do not have to.
+            if proper_name != "GitHub"
+                && fn_params_str.iter().any(|p| p == "from: &str,")
+                && fn_params_str.iter().any(|p| p == "to: &str,")
+            {
+                let between_fn_name = format!("{}_between", fn_name);
+                if !fn_names.contains(&(between_fn_name.clone() + &tag)) {
+                    fn_names.push(between_fn_name.clone() + &tag);
+
+                    let docs = get_fn_docs_variant(
+                        o,
+                        m,
+                        p,
+                        &format!(
+                            "As opposed to `{}`, this function takes `from` and `to` as times, and \
+                             sends them in UTC like `2021-08-15T01:52:41Z`.",
+                            fn_name
+                        ),
+                    )?;
+
+                    let between_params: Vec<String> = fn_params_str
+                        .iter()
+                        .map(|p| match p.as_str() {
+                            "from: &str," => "from: chrono::DateTime<chrono::Utc>,".to_string(),
+                            "to: &str," => "to: chrono::DateTime<chrono::Utc>,".to_string(),
+                            _ => p.to_string(),
+                        })
+                        .collect();
+
+                    print_fn(
+                        &docs,
+                        &bounds,
+                        &between_params,
+                        &body_param,
+                        &frt,
+                        &format!(
+                            "let from = crate::utils::date_time_param(&from);\nlet to = \
+                             crate::utils::date_time_param(&to);\n{}",
+                            template
+                        ),
+                        &fn_inner,
+                        &between_fn_name,
+                    )?;
+                }
+            }
+
+            // If we are getting a PDF, let's also generate a function that streams
+            // its bytes as they arrive, rather than holding the whole file in memory.
+            if proper_name != "GitHub" && http::Method::GET == m && returns_binary_pdf(o) {
+                let stream_fn_name = format!("{}_stream", fn_name);
+                if !fn_names.contains(&(stream_fn_name.clone() + &tag)) {
+                    fn_names.push(stream_fn_name.clone() + &tag);
+
+                    let docs = get_fn_docs_variant(
+                        o,
+                        m,
+                        p,
+                        &format!(
+                            "As opposed to `{}`, this function asks for the PDF and streams its \
+                             bytes as they arrive, rather than returning them all at once.\n\
+                             This is useful for large documents that would otherwise need to be \
+                             held in memory.",
+                            fn_name
+                        ),
+                    )?;
+
+                    print_fn(
+                        &docs,
+                        &bounds,
+                        &fn_params_str,
+                        &body_param,
+                        "impl futures::Stream<Item = Result<bytes::Bytes>>",
+                        &template,
+                        "self.client.get_stream_bytes(&url, \"application/pdf\").await",
+                        &stream_fn_name,
+                    )?;
+                }
+            }
+
+            // If we are uploading bytes, let's also generate a function that reads
+            // them from a file, say, streaming those over the client's request body
+            // limit rather than holding the whole upload in memory.
+            if proper_name != "GitHub" && body_func.as_deref() == Some("bytes") {
+                let from_reader_fn_name = format!("{}_from_reader", fn_name);
+                if !fn_names.contains(&(from_reader_fn_name.clone() + &tag)) {
+                    fn_names.push(from_reader_fn_name.clone() + &tag);
+
+                    let docs = get_fn_docs_variant(
+                        o,
+                        m,
+                        p,
+                        &format!(
+                            "As opposed to `{}`, this function reads the body from `body`, a file \
+                             say, and streams what is past the client's `with_request_body_limit` \
+                             as it is read.\n\
+                             A streamed upload is not retried.",
+                            fn_name
+                        ),
+                    )?;
+
+                    // The body is sent with the client `reader_body` gives back,
+                    // which counts it as it is streamed.
+                    let inner = fn_inner
+                        .replacen(
+                            "Some(bytes::Bytes::copy_from_slice(body.as_ref()).into())",
+                            "Some(body)",
+                            1,
+                        )
+                        .replacen("self.client.", "client.", 1);
+                    print_fn(
+                        &docs,
+                        &vec!["R: tokio::io::AsyncRead + Send + Sync + Unpin + 'static".to_string()],
+                        &fn_params_str,
+                        &Some("R".to_string()),
+                        &frt,
+                        &template,
+                        &format!(
+                            "let (client, body) = self.client.reader_body(body).await?;\n\n{}",
+                            inner
+                        ),
+                        &from_reader_fn_name,
+                    )?;
+                }
+            }
+
+            // If we are deleting something, let's also generate a function that
+            // does not mind if it is already gone, so cleanup can be run more than once.
+            if proper_name != "GitHub"
+                && frt == "()"
+                && http::Method::DELETE == m
+                && call.starts_with("self.client.delete(")
+            {
+                let if_exists_fn_name = format!("{}_if_exists", fn_name);
+                if !fn_names.contains(&(if_exists_fn_name.clone() + &tag)) {
+                    fn_names.push(if_exists_fn_name.clone() + &tag);
+
+                    let docs = get_fn_docs_variant(
+                        o,
+                        m,
+                        p,
+                        &format!(
+                            "As opposed to `{}`, this function returns `false` rather than an \
+                             error if there was nothing to delete, and `true` if there was.",
+                            fn_name
+                        ),
+                    )?;
+
+                    print_fn(
+                        &docs,
+                        &bounds,
+                        &fn_params_str,
+                        &body_param,
+                        "bool",
+                        &template,
+                        &fn_inner.replacen(
+                            "self.client.delete(",
+                            "self.client.delete_if_exists(",
+                            1,
+                        ),
+                        &if_exists_fn_name,
+                    )?;
+                }
+            }
+
+            // If we are patching something with JSON, let's also generate a function
+            // that only sends what changed, so updates that change nothing are not sent.
+            if proper_name != "GitHub"
+                && bounds.is_empty()
+                && call == "self.client.patch(&url, Some(self.client.serialize_body(body)?)).await"
+            {
+                let if_changed_fn_name = format!("{}_if_changed", fn_name);
+                if !fn_names.contains(&(if_changed_fn_name.clone() + &tag)) {
+                    fn_names.push(if_changed_fn_name.clone() + &tag);
+
+                    let docs = get_fn_docs_variant(
+                        o,
+                        m,
+                        p,
+                        &format!(
+                            "As opposed to `{}`, this function takes the `current` state of what \
+                             it updates and only sends the fields of `body` that differ from it. \
+                             It returns `None` without sending anything if none do.",
+                            fn_name
+                        ),
+                    )?;
+
+                    let mut if_changed_params = fn_params_str.clone();
+                    if_changed_params.push("current: &C,".to_string());
+
+                    print_fn(
+                        &docs,
+                        &vec!["C: serde::Serialize".to_string()],
+                        &if_changed_params,
+                        &body_param,
+                        &format!("Option<{}>", frt),
+                        &template,
+                        &format!(
+                            "{}self.client.patch_if_changed(&url, current, body).await",
+                            checks
+                        ),
+                        &if_changed_fn_name,
+                    )?;
+                }
+            }
+
+            // If we are creating something and only get its id back, let's also
+            // generate a function that gets what we created by that id.
+            if proper_name != "GitHub"
+                && http::Method::POST == m
+                && call.starts_with("self.client.post(")
+                && returns_only_id(ts, &tid)
+            {
+                if let Some((get_path, get_type)) = get_created_fetch(api, p, ts, parameters)? {
+                    let and_fetch_fn_name = format!("{}_and_fetch", fn_name);
+                    if get_type != frt && !fn_names.contains(&(and_fetch_fn_name.clone() + &tag)) {
+                        fn_names.push(and_fetch_fn_name.clone() + &tag);
+
+                        let docs = get_fn_docs_variant(
+                            o,
+                            m,
+                            p,
+                            &format!(
+                                "As opposed to `{}`, which only returns the id of what it creates, \
+                                 this function then does a `GET` to the `{}` endpoint and returns \
+                                 all of it.",
+                                fn_name, get_path
+                            ),
+                        )?;
+
+                        print_fn(
+                            &docs,
+                            &bounds,
+                            &fn_params_str,
+                            &body_param,
+                            &get_type,
+                            &template,
+                            &fn_inner.replacen("self.client.post(", "self.client.post_and_get(", 1),
+                            &and_fetch_fn_name,
+                        )?;
+                    }
+                }
+            }
 
             // If we are returning a list of things and we have page, etc as
             // params, let's get all the pages.
@@ -344,11 +1057,18 @@ pub fn generate_files(
                     oid.trim_start_matches(&tag).trim_start_matches('_'),
                 )?;
 
-                let (fn_params_str, query_params) =
-                    get_fn_params(ts, o, parameters, true, op.parameters.clone(), proper_name)?;
+                let (fn_params_str, query_params) = get_fn_params(
+                    ts,
+                    o,
+                    p,
+                    parameters,
+                    true,
+                    op.parameters.clone(),
+                    proper_name,
+                )?;
 
                 let tmp = parse(p)?;
-                let template = tmp.compile(query_params);
+                let template = tmp.compile(query_params, &fn_params_str, proper_name);
 
                 let fn_inner = get_fn_inner(
                     proper_name,
@@ -361,6 +1081,7 @@ pub fn generate_files(
                     true,
                 )?;
 
+                let list_fn_name = fn_name.clone();
                 let mut fn_name = oid
                     .replace("_get_", "_get_all_")
                     .replace("_list_", "_list_all_")
@@ -409,11 +1130,108 @@ pub fn generate_files(
                     &template,
                     &fn_inner,
                     &fn_name,
-                );
+                )?;
+
+                // If Zoom pages the list, let's also generate a function that streams
+                // the items a page at a time, for lists too long to hold all at once.
+                if proper_name == "Zoom"
+                    && !inner_response_type.is_empty()
+                    && fn_inner.contains("next_page_token={}")
+                {
+                    let stream_fn_name = format!("{}_stream", list_fn_name);
+                    if !fn_names.contains(&(stream_fn_name.clone() + &tag)) {
+                        fn_names.push(stream_fn_name.clone() + &tag);
+
+                        let docs = get_fn_docs_variant(
+                            o,
+                            m,
+                            p,
+                            &format!(
+                                "As opposed to `{}`, this function streams the items of all the \
+                                 pages, getting the next page only once the items of the last one \
+                                 are used up. The stream ends after the last page.",
+                                list_fn_name
+                            ),
+                        )?;
+
+                        let item_type = &frt["Vec<".len()..frt.len() - 1];
+
+                        print_fn(
+                            &docs,
+                            &bounds,
+                            &fn_params_str,
+                            &body_param,
+                            &format!("impl futures::Stream<Item = Result<{}>>", item_type),
+                            &template,
+                            &format!(
+                                "self.client.get_stream_pages(&url, \"next_page_token\", |resp: \
+                                 {}| (resp.{}, resp.next_page_token)).await",
+                                response_type,
+                                to_snake_case(&pagination_property)
+                            ),
+                            &stream_fn_name,
+                        )?;
+
+                        // And one that streams only the items a predicate keeps,
+                        // for looking through a list without holding all of it.
+                        let filter_fn_name = format!("{}_filter", list_fn_name);
+                        if !fn_names.contains(&(filter_fn_name.clone() + &tag)) {
+                            fn_names.push(filter_fn_name.clone() + &tag);
+
+                            let docs = get_fn_docs_variant(
+                                o,
+                                m,
+                                p,
+                                &format!(
+                                    "As opposed to `{}`, this function streams only the items \
+                                     `predicate` keeps. It too gets the next page only once the \
+                                     items of the last one are used up, so a stream that is cut \
+                                     short, for example with `take`, gets no more pages than it \
+                                     needs.",
+                                    stream_fn_name
+                                ),
+                            )?;
+
+                            let mut bounds = bounds.clone();
+                            bounds.push(format!("P: FnMut(&{}) -> bool", item_type));
+                            let mut fn_params_str = fn_params_str.clone();
+                            fn_params_str.push("mut predicate: P,".to_string());
+
+                            print_fn(
+                                &docs,
+                                &bounds,
+                                &fn_params_str,
+                                &body_param,
+                                &format!("impl futures::Stream<Item = Result<{}>>", item_type),
+                                &template,
+                                &format!(
+                                    "let items = self.client.get_stream_pages(&url, \
+                                     \"next_page_token\", |resp: {}| (resp.{}, \
+                                     resp.next_page_token)).await?;\n\
+                                     Ok(futures::TryStreamExt::try_filter(items, move |item| \
+                                     futures::future::ready(predicate(item))))",
+                                    response_type,
+                                    to_snake_case(&pagination_property)
+                                ),
+                                &filter_fn_name,
+                            )?;
+                        }
+                    }
+                }
+            }
+
+            if let Some((constructor, builder)) = request_builder {
+                out.push_str(&constructor);
+                tag_builders
+                    .entry(file_tag.to_string())
+                    .or_default()
+                    .push_str(&builder);
             }
 
             // Add this to our map of functions based on the tag name.
-            tag_files.insert(tag, out.to_string());
+            tag_files.insert(file_tag.to_string(), out.to_string());
+            tag_traits.insert(file_tag.to_string(), (trait_out, impl_out));
+            tag_blocking.insert(file_tag, blocking_out);
 
             Ok(())
         };
@@ -428,10 +1246,167 @@ pub fn generate_files(
         gen(pn.as_str(), "TRACE", op.trace.as_ref())?;
     }
 
-    Ok(tag_files)
+    let mut traits: BTreeMap<String, String> = tag_traits
+        .into_iter()
+        .filter(|(_, (trait_out, _))| !trait_out.is_empty())
+        .map(|(tag, (trait_out, impl_out))| {
+            let trait_out = render_mock_trait(&tag, &trait_out, &impl_out);
+            (tag, trait_out)
+        })
+        .collect();
+
+    // The builders go after the impl as well, before the trait.
+    for (tag, builders) in tag_builders {
+        let after = traits.entry(tag).or_default();
+        *after = format!("{}{}", builders, after);
+    }
+
+    let blocking = tag_blocking
+        .into_iter()
+        .filter(|(_, blocking_out)| !blocking_out.is_empty())
+        .collect();
+
+    Ok((tag_files, traits, blocking))
+}
+
+/// What the client of the `blocking` module has, so the functions that only
+/// call these can be made blocking.
+const BLOCKING_CLIENT_FNS: &[&str] = &[
+    "delete",
+    "delete_if_exists",
+    "encode_path",
+    "get",
+    "patch",
+    "post",
+    "put",
+    "request_form",
+    "request_with_accept_mime",
+    "serialize_body",
+];
+
+/*
+ * The body of a function for the `blocking` module, which is the body of the
+ * async function without the awaits. It is an error, rather than a function
+ * left out, if it calls something the blocking client does not have.
+ */
+fn blocking_fn_body(body: &str) -> Result<String> {
+    if body.contains("futures::") || body.contains("tokio::") {
+        bail!("it needs an async runtime");
+    }
+
+    for (i, _) in body.match_indices("self.") {
+        let call = &body[i + "self.".len()..];
+        let name: String = call
+            .strip_prefix("client.")
+            .unwrap_or(call)
+            .chars()
+            .take_while(|c| c.is_alphanumeric() || *c == '_')
+            .collect();
+        if !call.starts_with("client.") || !BLOCKING_CLIENT_FNS.contains(&name.as_str()) {
+            bail!("the blocking client has no `{}`", name);
+        }
+    }
+
+    // The forms of the blocking client are its own.
+    Ok(body
+        .replace(".await", "")
+        .replace(
+            "crate::utils::multipart_form(",
+            "crate::blocking::multipart_form(",
+        )
+        .replace("reqwest::multipart::", "reqwest::blocking::multipart::"))
+}
+
+/*
+ * Find the operations whose response we can't fully type: the ones that fall
+ * back to a `serde_json::Value`, or have a body we don't model at all.
+ */
+pub fn untyped_operations(api: &openapiv3::OpenAPI, ts: &TypeSpace) -> Result<Vec<String>> {
+    // Selecting the response types adds to the type space, so do it on a copy.
+    let mut ts = ts.clone();
+    let mut report: Vec<String> = Default::default();
+
+    for (pn, p) in api.paths.iter() {
+        let op = p.item().with_context(|| format!("bad path {}", pn))?;
+
+        let ops = [
+            ("GET", op.get.as_ref()),
+            ("PUT", op.put.as_ref()),
+            ("POST", op.post.as_ref()),
+            ("DELETE", op.delete.as_ref()),
+            ("OPTIONS", op.options.as_ref()),
+            ("HEAD", op.head.as_ref()),
+            ("PATCH", op.patch.as_ref()),
+            ("TRACE", op.trace.as_ref()),
+        ];
+        for (m, o) in ops {
+            let o = if let Some(o) = o {
+                o
+            } else {
+                continue;
+            };
+
+            let op_id = if let Some(id) = &o.operation_id {
+                id.to_string()
+            } else {
+                path_to_operation_id(pn, m)
+            };
+            let od = to_snake_case(&op_id);
+
+            let (rt, _, _, _) = get_response_type(api, &od, &mut ts, o)?;
+            if rt.contains("serde_json::Value") {
+                report.push(format!("{} {}: response falls back to `{}`", m, pn, rt));
+            } else if rt == "()" && returns_body(o) {
+                report.push(format!("{} {}: response body is not modeled", m, pn));
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+/// If the response `get_response_type` picks for the operation has a body.
+fn returns_body(o: &openapiv3::Operation) -> bool {
+    let first = o.responses.responses.values().next();
+    let ok = o.responses.responses.get(&openapiv3::StatusCode::Code(200));
+    first.map(response_has_body).unwrap_or_default()
+        || ok.map(response_has_body).unwrap_or_default()
+}
+
+/// If the operation may answer `204 No Content` as well as with the body
+/// `get_response_type` picks, so the function returns `None` for the former.
+fn may_answer_no_content(o: &openapiv3::Operation) -> bool {
+    o.responses
+        .responses
+        .contains_key(&openapiv3::StatusCode::Code(204))
+        && returns_body(o)
+}
+
+/// Render the trait mirroring a tag's functions, so callers can swap in a mock,
+/// say one made with `mockall::mock!`, for the tag's struct.
+fn render_mock_trait(tag: &str, trait_fns: &str, impl_fns: &str) -> String {
+    let name = struct_name(tag);
+    format!(
+        r#"
+/// The functions of [`{}`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn {}Api`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait {}Api: Send + Sync {{
+{}}}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl {}Api for {} {{
+{}}}
+"#,
+        name, name, name, trait_fns, name, name, impl_fns
+    )
 }
 
 fn get_response_type_from_object(
+    api: &openapiv3::OpenAPI,
     od: &str,
     ts: &mut TypeSpace,
     s: Option<&openapiv3::ReferenceOr<openapiv3::Schema>>,
@@ -446,11 +1421,7 @@ fn get_response_type_from_object(
     let mut tid = TypeId(0);
 
     if let Some(s) = s {
-        tid = ts.select(Some(&clean_name(&object_name)), s, "")?;
-
-        if let openapiv3::ReferenceOr::Reference { reference } = s {
-            tid = ts.select_ref(Some(&clean_name(&object_name)), reference)?;
-        }
+        tid = ts.select_body(api, &clean_name(&object_name), s, BodyKind::Response)?;
     }
 
     if let Some(openapiv3::ReferenceOr::Reference { reference }) = r {
@@ -469,68 +1440,95 @@ fn get_response_type_from_object(
     }
 
     if let crate::TypeDetails::Object(p, _) = &et.details {
-        // For Ramp, the pagination values are passed _in_ the resulting
-        // struct, so we want to ignore them and just get the data.
-        if let Some(pid) = p.get("page") {
-            let rt = ts.render_type(pid, false)?;
-            if rt == "crate::types::Page" || rt.ends_with("Page") {
-                if let Some(did) = p.get("data") {
-                    let rt = ts.render_type(did, false)?;
-                    return Ok((og_rt, did.clone(), rt, "data".to_string()));
-                } else if p.len() == 2 {
-                    // We know for the Ramp API there will only be two fields in
-                    // these structs. This should help prevent errors.
-                    // Let's find the value of the struct that is the vec!
-                    for (n, id) in p {
-                        let rt = ts.render_type(id, false)?;
-                        if rt.starts_with("Vec<") {
-                            return Ok((og_rt, id.clone(), rt, to_snake_case(n)));
-                        }
-                    }
-                }
-            }
+        if let Some((n, id, rt)) = get_paginated_property(ts, p)? {
+            return Ok((og_rt, id, rt, n));
         }
+    }
 
-        // For Zoom, the pagination values are passed _in_ the resulting
-        // struct, so we want to ignore them and just get the data.
-        if let Some(pid) = p.get("next_page_token") {
-            let rt = ts.render_type(pid, false)?;
-            if rt == "String" {
+    Ok((og_rt, tid, "".to_string(), "".to_string()))
+}
+
+/*
+ * Find the property of an object that holds the items of the list it pages
+ * through, if it is such a list. Returns the name of the property, its type id
+ * and its rendered type.
+ */
+pub fn get_paginated_property(
+    ts: &TypeSpace,
+    p: &BTreeMap<String, TypeId>,
+) -> Result<Option<(String, TypeId, String)>> {
+    // For Ramp, the pagination values are passed _in_ the resulting
+    // struct, so we want to ignore them and just get the data.
+    if let Some(pid) = p.get("page") {
+        let rt = ts.render_type(pid, false)?;
+        if rt == "crate::types::Page" || rt.ends_with("Page") {
+            if let Some(did) = p.get("data") {
+                let rt = ts.render_type(did, false)?;
+                return Ok(Some(("data".to_string(), did.clone(), rt)));
+            } else if p.len() == 2 {
+                // We know for the Ramp API there will only be two fields in
+                // these structs. This should help prevent errors.
+                // Let's find the value of the struct that is the vec!
                 for (n, id) in p {
-                    // Now we must find the property with the vector for this struct.
                     let rt = ts.render_type(id, false)?;
                     if rt.starts_with("Vec<") {
-                        return Ok((og_rt, id.clone(), rt, to_snake_case(n)));
+                        return Ok(Some((n.to_string(), id.clone(), rt)));
                     }
                 }
             }
         }
+    }
 
-        // For Google, the pagination values are passed _in_ the resulting
-        // struct, so we want to ignore them and just get the data.
-        if let Some(pid) = p.get("nextPageToken") {
-            let rt = ts.render_type(pid, false)?;
-            if rt == "String" {
-                if let Some(did) = p.get("items") {
-                    let rt = ts.render_type(did, false)?;
-                    return Ok((og_rt, did.clone(), rt, "items".to_string()));
-                } else {
-                    for (n, id) in p {
-                        // Now we must find the property with the vector for this struct.
-                        let rt = ts.render_type(id, false)?;
-                        if rt.starts_with("Vec<") {
-                            return Ok((og_rt, id.clone(), rt, to_snake_case(n)));
-                        }
+    // For Zoom, the pagination values are passed _in_ the resulting
+    // struct, so we want to ignore them and just get the data.
+    if let Some(pid) = p.get("next_page_token") {
+        let rt = ts.render_type(pid, false)?;
+        if rt == "String" {
+            for (n, id) in p {
+                // Now we must find the property with the vector for this struct.
+                let rt = ts.render_type(id, false)?;
+                if rt.starts_with("Vec<") {
+                    return Ok(Some((n.to_string(), id.clone(), rt)));
+                }
+            }
+        }
+    }
+
+    // For Google, the pagination values are passed _in_ the resulting
+    // struct, so we want to ignore them and just get the data.
+    if let Some(pid) = p.get("nextPageToken") {
+        let rt = ts.render_type(pid, false)?;
+        if rt == "String" {
+            if let Some(did) = p.get("items") {
+                let rt = ts.render_type(did, false)?;
+                return Ok(Some(("items".to_string(), did.clone(), rt)));
+            } else {
+                for (n, id) in p {
+                    // Now we must find the property with the vector for this struct.
+                    let rt = ts.render_type(id, false)?;
+                    if rt.starts_with("Vec<") {
+                        return Ok(Some((n.to_string(), id.clone(), rt)));
                     }
                 }
             }
         }
     }
 
-    Ok((og_rt, tid, "".to_string(), "".to_string()))
+    Ok(None)
+}
+
+/*
+ * Whether the response has a body, a reference to one is taken to have one.
+ */
+fn response_has_body(r: &openapiv3::ReferenceOr<openapiv3::Response>) -> bool {
+    match r.item() {
+        Ok(i) => !i.content.is_empty(),
+        Err(_) => true,
+    }
 }
 
 fn get_response_type(
+    api: &openapiv3::OpenAPI,
     od: &str,
     ts: &mut TypeSpace,
     o: &openapiv3::Operation,
@@ -540,8 +1538,21 @@ fn get_response_type(
     String,        // optional vec response type if this struct paginates
     String,        // optional name of vec response property if this struct paginates
 )> {
-    // Get the first response.
-    let first = o.responses.responses.first().unwrap();
+    // Get the first response. If it has no body but the `200` does, like an
+    // update that may answer with nothing or with what it updated, get the
+    // `200` instead, so the caller gets the resource without another `GET`.
+    let mut first = o.responses.responses.first().unwrap();
+    if !response_has_body(first.1) {
+        if let Some(ok) = o
+            .responses
+            .responses
+            .get_key_value(&openapiv3::StatusCode::Code(200))
+        {
+            if response_has_body(ok.1) {
+                first = ok;
+            }
+        }
+    }
     if let Ok(i) = first.1.item() {
         if i.content.is_empty() {
             // Return empty.
@@ -578,13 +1589,21 @@ fn get_response_type(
                 }
 
                 // Get response type from object.
-                return get_response_type_from_object(od, ts, Some(s), None);
+                return get_response_type_from_object(api, od, ts, Some(s), None);
             }
         }
 
         // Get the first response.
         let (ct, mt) = i.content.first().unwrap();
-        if ct == "text/plain"
+        if ct.starts_with("multipart/") {
+            // The parts can be anything, so they are handed out as they are.
+            return Ok((
+                "crate::Multipart".to_string(),
+                crate::TypeId(0),
+                "".to_string(),
+                "".to_string(),
+            ));
+        } else if ct == "text/plain"
             || ct == "text/html"
             || ct == "application/octocat-stream"
             || ct == "*/*"
@@ -608,7 +1627,7 @@ fn get_response_type(
         }
     } else if let openapiv3::ReferenceOr::Reference { reference: _ } = first.1 {
         // Get response type from object.
-        return get_response_type_from_object(od, ts, None, Some(first.1));
+        return get_response_type_from_object(api, od, ts, None, Some(first.1));
     }
 
     // Basically if we get here, likely its just an empty struct or something.
@@ -625,18 +1644,19 @@ fn get_response_type(
 fn get_fn_params(
     ts: &mut TypeSpace,
     o: &openapiv3::Operation,
+    p: &str,
     parameters: &BTreeMap<String, &openapiv3::Parameter>,
     all_pages: bool,
     global_params: Vec<openapiv3::ReferenceOr<openapiv3::Parameter>>,
     proper_name: &str,
-) -> Result<(Vec<String>, BTreeMap<String, (String, String)>)> {
+) -> Result<(Vec<String>, BTreeMap<String, (String, String, ArrayStyle)>)> {
     /*
      * Query parameters are sorted lexicographically to ensure a stable
      * order in the generated code.
      */
     let mut fn_params_str: Vec<String> = Default::default();
     let mut fn_params: Vec<String> = Default::default();
-    let mut query_params: BTreeMap<String, (String, String)> = Default::default();
+    let mut query_params: BTreeMap<String, (String, String, ArrayStyle)> = Default::default();
     let mut gp = global_params;
     let mut op = o.parameters.clone();
     gp.append(&mut op);
@@ -659,7 +1679,7 @@ fn get_fn_params(
         let nam = &to_snake_case(&parameter_data.name);
 
         if !fn_params.contains(nam) && !fn_params.contains(&format!("{}_", nam)) {
-            let typ = parameter_data.render_type(&param_name, ts)?;
+            let typ = render_param_type(parameter_data, &param_name, p, ts, proper_name)?;
             if nam == "ref"
                 || nam == "type"
                 || nam == "foo"
@@ -699,13 +1719,14 @@ fn get_fn_params(
             if let openapiv3::Parameter::Query {
                 parameter_data: _,
                 allow_reserved: _,
-                style: openapiv3::QueryStyle::Form,
+                style,
                 // We can ignore the allow empty value, we support this by default and
                 // aren't strict about not allowing empty values on other parameters
                 // merely because specs cannot be trusted.
                 allow_empty_value: _,
             } = item
             {
+                let array_style = ArrayStyle::new(style, parameter_data.explode);
                 if nam == "ref"
                     || nam == "type"
                     || nam == "foo"
@@ -715,12 +1736,20 @@ fn get_fn_params(
                 {
                     query_params.insert(
                         format!("{}_", nam),
-                        (typ.to_string(), parameter_data.name.to_string()),
+                        (
+                            typ.to_string(),
+                            parameter_data.name.to_string(),
+                            array_style,
+                        ),
                     );
                 } else if nam == "i_ds" {
                     query_params.insert(
                         "ids".to_string(),
-                        (typ.to_string(), parameter_data.name.to_string()),
+                        (
+                            typ.to_string(),
+                            parameter_data.name.to_string(),
+                            array_style,
+                        ),
                     );
                 } else if (!all_pages || !is_page_param(nam, proper_name))
                     && nam != "authorization"
@@ -735,12 +1764,20 @@ fn get_fn_params(
                     if typ == "chrono::DateTime<chrono::Utc>" {
                         query_params.insert(
                             nam.to_string(),
-                            (format!("Option<{}>", typ), parameter_data.name.to_string()),
+                            (
+                                format!("Option<{}>", typ),
+                                parameter_data.name.to_string(),
+                                array_style,
+                            ),
                         );
                     } else {
                         query_params.insert(
                             nam.to_string(),
-                            (typ.to_string(), parameter_data.name.to_string()),
+                            (
+                                typ.to_string(),
+                                parameter_data.name.to_string(),
+                                array_style,
+                            ),
                         );
                     }
                 }
@@ -751,6 +1788,103 @@ fn get_fn_params(
     Ok((fn_params_str, query_params))
 }
 
+/*
+ * Render the type of a parameter. A nullable enum is optional, since null has a
+ * meaning of its own there (usually "all"), `None` leaves it out of the request.
+ */
+fn render_param_type(
+    parameter_data: &openapiv3::ParameterData,
+    param_name: &str,
+    p: &str,
+    ts: &mut TypeSpace,
+    proper_name: &str,
+) -> Result<String> {
+    let typ = parameter_data.render_type(param_name, ts)?;
+
+    // Some strings are really booleans, take those as one.
+    if typ == "&str" && proper_name == "DocuSign" && is_docusign_bool_param(&parameter_data.name) {
+        return Ok("bool".to_string());
+    }
+
+    // Keep custom field ids apart from the account ids next to them.
+    if typ == "&str" && proper_name == "DocuSign" && parameter_data.name == "customFieldId" {
+        return Ok("&crate::custom_field_id::CustomFieldId".to_string());
+    }
+
+    // Keep phone number ids apart from the site and user ids of the other phone
+    // endpoints. The internal numbers of SIP connected audio have ids of their own.
+    if typ == "&str"
+        && proper_name == "Zoom"
+        && parameter_data.name == "numberId"
+        && p.starts_with("/phone/numbers/")
+    {
+        return Ok("&crate::number_id::NumberId".to_string());
+    }
+
+    // A date the spec has as a string, see `DATE_PARAMS`.
+    if typ == "&str"
+        && DATE_PARAMS.iter().any(|(api, path, params)| {
+            *api == proper_name && *path == p && params.contains(&parameter_data.name.as_str())
+        })
+    {
+        return Ok(if parameter_data.required {
+            "chrono::NaiveDate".to_string()
+        } else {
+            "Option<chrono::NaiveDate>".to_string()
+        });
+    }
+
+    // Zoom has more calling plans than the spec could list, so take the type of
+    // one as an enum that keeps any other type as it is.
+    if typ == "&str"
+        && proper_name == "Zoom"
+        && parameter_data.name == "type"
+        && p == "/phone/users/{userId}/calling_plans/{type}"
+    {
+        return Ok("&crate::calling_plan::CallingPlanType".to_string());
+    }
+
+    if let openapiv3::ParameterSchemaOrContent::Schema(openapiv3::ReferenceOr::Item(s)) =
+        &parameter_data.format
+    {
+        if s.schema_data.nullable && typ.starts_with("crate::types::") {
+            return Ok(format!("Option<{}>", typ));
+        }
+    }
+
+    if typ.starts_with("crate::types::") && is_omittable_enum(parameter_data, proper_name) {
+        return Ok(format!("Option<{}>", typ));
+    }
+
+    Ok(typ)
+}
+
+/*
+ * Whether a parameter is an optional Zoom enum without a default, like the
+ * `number_type` of `/phone/numbers`, that the API takes as "all" when it is
+ * left off. Those are taken as an `Option`, so `None` leaves them off rather
+ * than send whatever the enum displays as.
+ */
+fn is_omittable_enum(parameter_data: &openapiv3::ParameterData, proper_name: &str) -> bool {
+    use openapiv3::{ReferenceOr, SchemaKind, Type};
+
+    if proper_name != "Zoom" || parameter_data.required {
+        return false;
+    }
+
+    match &parameter_data.format {
+        openapiv3::ParameterSchemaOrContent::Schema(ReferenceOr::Item(s)) => {
+            s.schema_data.default.is_none()
+                && !s.schema_data.nullable
+                && matches!(
+                    &s.schema_kind,
+                    SchemaKind::Type(Type::String(st)) if !st.enumeration.is_empty()
+                )
+        }
+        _ => false,
+    }
+}
+
 /*
  * Perform the function.
  */
@@ -766,9 +1900,17 @@ fn get_fn_inner(
     pagination_property: &str,
     all_pages: bool,
 ) -> Result<String> {
+    // The pagination property is the name in the JSON, we want the field name.
+    let pagination_property = &to_snake_case(pagination_property);
+
     let body = if let Some(f) = &body_func {
-        if f == "json" {
+        if f == "json" && proper_name != "GitHub" {
+            // Through the client, which may have been given another serializer.
+            "Some(self.client.serialize_body(body)?)"
+        } else if f == "json" {
             "Some(reqwest::Body::from(serde_json::to_vec(body)?))"
+        } else if f == "bytes" {
+            "Some(bytes::Bytes::copy_from_slice(body.as_ref()).into())"
         } else {
             "Some(body.into())"
         }
@@ -845,7 +1987,7 @@ fn get_fn_inner(
                         if e.to_string().contains("404 Not Found") {{
                             page = "".to_string();
                         }} else {{
-                            anyhow::bail!(e);
+                            return Err(e.into());
                         }}
                     }}
                 }}
@@ -1010,6 +2152,7 @@ fn get_fn_docs(
     p: &str,
     parameters: &BTreeMap<String, &openapiv3::Parameter>,
     ts: &mut TypeSpace,
+    proper_name: &str,
 ) -> Result<String> {
     let mut out = String::new();
 
@@ -1035,7 +2178,13 @@ fn get_fn_docs(
         a("*");
         a(&format!("* FROM: <{}>", external_docs.url));
     }
-    if !o.parameters.is_empty() {
+    // The files of a form are taken as parameters too.
+    let files = if proper_name != "GitHub" {
+        multipart_files(o)
+    } else {
+        Vec::new()
+    };
+    if !o.parameters.is_empty() || !files.is_empty() {
         a("*");
         a("* **Parameters:**");
         a("*");
@@ -1077,7 +2226,17 @@ fn get_fn_docs(
         }
 
         let nam = &to_snake_case(&clean_name(&parameter_data.name));
-        let typ = parameter_data.render_type(&param_name, ts)?;
+        let typ = render_param_type(parameter_data, &param_name, p, ts, proper_name)?;
+        if is_omittable_enum(parameter_data, proper_name) {
+            docs = format!(
+                "{} `None` leaves it off the query, for the API's default.",
+                if docs.is_empty() {
+                    " --"
+                } else {
+                    docs.as_str()
+                }
+            );
+        }
 
         if nam == "ref"
             || nam == "type"
@@ -1091,11 +2250,72 @@ fn get_fn_docs(
             a(&format!("* * `{}: {}`{}", nam, typ, docs));
         }
     }
+    for (name, description) in files {
+        if description.is_empty() {
+            a(&format!("* * `{}: &[u8]`", struct_field_name(&name)));
+        } else {
+            a(&format!(
+                "* * `{}: &[u8]` -- {}.",
+                struct_field_name(&name),
+                description.trim().trim_end_matches('.')
+            ));
+        }
+    }
     a("*/");
 
     Ok(out.trim().to_string())
 }
 
+/**
+ * The tag of the file for a Zoom Phone operation only a master account can
+ * call, so it goes on `MasterPhone` rather than `Phone` and can't be called
+ * with a user's token by mistake.
+ */
+pub fn master_tag(proper_name: &str, tag: &str, o: &openapiv3::Operation) -> Option<String> {
+    if proper_name == "Zoom" && tag == "phone" && get_fn_scopes(o) == ["phone:master"] {
+        Some(format!("master_{}", tag))
+    } else {
+        None
+    }
+}
+
+/**
+ * Get the OAuth scopes an operation requires from the `**Scopes:**` line in
+ * its description, if it has one.
+ */
+fn get_fn_scopes(o: &openapiv3::Operation) -> Vec<String> {
+    let mut scopes: Vec<String> = Default::default();
+
+    let description = match &o.description {
+        Some(d) => d,
+        None => return scopes,
+    };
+
+    for marker in &["**Scopes:**", "**Scopes**:", "**Scope:**", "**Scope**:"] {
+        if let Some(i) = description.find(marker) {
+            // The scopes end with the line, or the first bit of html.
+            let line = description[i + marker.len()..]
+                .split(&['\n', '<'][..])
+                .next()
+                .unwrap_or_default();
+
+            for s in line.split(|c: char| c == '`' || c == ',' || c.is_whitespace()) {
+                let s = s.trim_end_matches('.');
+                if s.contains(':')
+                    && s.chars()
+                        .all(|c| c.is_ascii_lowercase() || c == '_' || c == ':')
+                    && !scopes.contains(&s.to_string())
+                {
+                    scopes.push(s.to_string());
+                }
+            }
+            break;
+        }
+    }
+
+    scopes
+}
+
 fn get_fn_docs_all(o: &openapiv3::Operation, m: &str, p: &str, fn_name: &str) -> Result<String> {
     let mut out = String::new();
 
@@ -1131,6 +2351,548 @@ fn get_fn_docs_all(o: &openapiv3::Operation, m: &str, p: &str, fn_name: &str) ->
     Ok(out.trim().to_string())
 }
 
+/*
+ * The docs of a variant of an operation's function: `extra` says how it differs
+ * from the function, and takes the place of the operation's description.
+ */
+fn get_fn_docs_variant(o: &openapiv3::Operation, m: &str, p: &str, extra: &str) -> Result<String> {
+    let mut out = String::new();
+
+    let mut a = |s: &str| {
+        out.push_str(s);
+        out.push('\n');
+    };
+
+    a("/**");
+    if let Some(summary) = &o.summary {
+        a(&format!("* {}.", summary.trim_end_matches('.')));
+        a("*");
+    }
+    a(&format!(
+        "* This function performs a `{}` to the `{}` endpoint.",
+        m, p
+    ));
+    a("*");
+    a(&format!("* {}", extra.replace('\n', "\n* ")));
+    if let Some(external_docs) = &o.external_docs {
+        a("*");
+        a(&format!("* FROM: <{}>", external_docs.url));
+    }
+    a("*/");
+
+    Ok(out.trim().to_string())
+}
+
+/*
+ * The schema of the operation's request body, if it is a `multipart/form-data`
+ * form.
+ */
+fn multipart_schema(
+    o: &openapiv3::Operation,
+) -> Option<&openapiv3::ReferenceOr<openapiv3::Schema>> {
+    let (ct, mt) = o.request_body.as_ref()?.item().ok()?.content.first()?;
+    if ct == "multipart/form-data" {
+        mt.schema.as_ref()
+    } else {
+        None
+    }
+}
+
+/*
+ * Whether the field of a form is a file, sent as a part of its own from bytes
+ * rather than as text.
+ */
+fn is_file_field(s: &openapiv3::ReferenceOr<Box<openapiv3::Schema>>) -> bool {
+    use openapiv3::{SchemaKind, StringFormat, Type, VariantOrUnknownOrEmpty::Item};
+
+    match s.item().map(|s| &s.schema_kind) {
+        Ok(SchemaKind::Type(Type::String(st))) => matches!(st.format, Item(StringFormat::Binary)),
+        _ => false,
+    }
+}
+
+/*
+ * The names and descriptions of the files of the operation's form, in the
+ * order of the spec.
+ */
+fn multipart_files(o: &openapiv3::Operation) -> Vec<(String, String)> {
+    use openapiv3::{SchemaKind, Type};
+
+    match multipart_schema(o)
+        .and_then(|s| s.item().ok())
+        .map(|s| &s.schema_kind)
+    {
+        Some(SchemaKind::Type(Type::Object(ob))) => ob
+            .properties
+            .iter()
+            .filter(|(_, s)| is_file_field(s))
+            .map(|(name, s)| {
+                let description = s
+                    .item()
+                    .ok()
+                    .and_then(|s| s.schema_data.description.clone())
+                    .unwrap_or_default();
+                (name.to_string(), description)
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/*
+ * The schema of the operation's form without its files, for the request struct
+ * of its text fields. This is `None` if the form only has files.
+ */
+fn multipart_text_schema(
+    o: &openapiv3::Operation,
+) -> Option<openapiv3::ReferenceOr<openapiv3::Schema>> {
+    use openapiv3::{SchemaKind, Type};
+
+    let s = multipart_schema(o)?;
+    let mut schema = match s.item() {
+        Ok(schema) => schema.clone(),
+        // We can not see into the schema, so all of it is text.
+        Err(_) => return Some(s.clone()),
+    };
+    if let SchemaKind::Type(Type::Object(ob)) = &mut schema.schema_kind {
+        let files: Vec<String> = ob
+            .properties
+            .iter()
+            .filter(|(_, s)| is_file_field(s))
+            .map(|(name, _)| name.to_string())
+            .collect();
+        ob.properties.retain(|name, _| !files.contains(name));
+        ob.required.retain(|name| !files.contains(name));
+        if ob.properties.is_empty() {
+            return None;
+        }
+    }
+    Some(openapiv3::ReferenceOr::Item(schema))
+}
+
+/*
+ * The builder for the request of the function `fn_name` of `group`, and the
+ * function of `group` that starts one. Its query parameters that can be left
+ * off, the text, numbers, flags, lists and `Option`s, are `None` until they are
+ * set, and sent as their empty values, which the function leaves off. The rest,
+ * like its path parameters, are taken up front. Sending it calls the function.
+ */
+fn query_builder(
+    group: &str,
+    fn_name: &str,
+    fn_params_str: &[String],
+    query_names: &[String],
+    response_type: &str,
+) -> (String, String) {
+    let builder = struct_name(&format!("{}_request", fn_name));
+
+    let mut required: Vec<String> = Default::default();
+    let mut init: Vec<String> = Default::default();
+    let mut fields: Vec<String> = Default::default();
+    let mut setters = String::new();
+    let mut args: Vec<String> = Default::default();
+    for param in fn_params_str {
+        let (name, typ) = match param.trim_end_matches(',').split_once(": ") {
+            Some(p) => p,
+            None => continue,
+        };
+        let is_query = query_names.iter().any(|q| q == name);
+        let primitive = ["bool", "i32", "i64", "u32", "u64", "f32", "f64"].contains(&typ);
+
+        if is_query && (typ == "&str" || primitive) {
+            let (field, arg, set) = if typ == "&str" {
+                (
+                    "String".to_string(),
+                    format!("self.{}.as_deref().unwrap_or_default()", name),
+                    format!("{}.to_string()", name),
+                )
+            } else {
+                (
+                    typ.to_string(),
+                    format!("self.{}.unwrap_or_default()", name),
+                    name.to_string(),
+                )
+            };
+            fields.push(format!("{}: Option<{}>,", name, field));
+            init.push(format!("{}: None,", name));
+            args.push(arg);
+            setters.push_str(&format!(
+                "/// Set `{}`.\npub fn {}(mut self, {}: {}) -> Self {{\nself.{} = Some({});\nself\n}}\n\n",
+                name, name, name, typ, name, set
+            ));
+        } else if is_query && typ.starts_with("Option<") {
+            let inner = &typ["Option<".len()..typ.len() - 1];
+            fields.push(format!("{}: {},", name, typ));
+            init.push(format!("{}: None,", name));
+            args.push(format!("self.{}", name));
+            setters.push_str(&format!(
+                "/// Set `{}`.\npub fn {}(mut self, {}: {}) -> Self {{\nself.{} = Some({});\nself\n}}\n\n",
+                name, name, name, inner, name, name
+            ));
+        } else if is_query && typ.starts_with("&[") {
+            let inner = &typ["&[".len()..typ.len() - 1];
+            fields.push(format!("{}: Option<Vec<{}>>,", name, inner));
+            init.push(format!("{}: None,", name));
+            args.push(format!("self.{}.as_deref().unwrap_or_default()", name));
+            setters.push_str(&format!(
+                "/// Set `{}`.\npub fn {}(mut self, {}: {}) -> Self {{\nself.{} = Some({}.to_vec());\nself\n}}\n\n",
+                name, name, name, typ, name, name
+            ));
+        } else if typ == "&str" {
+            required.push(param.to_string());
+            init.push(format!("{}: {}.to_string(),", name, name));
+            fields.push(format!("{}: String,", name));
+            args.push(format!("&self.{}", name));
+        } else if typ.starts_with("&[") {
+            required.push(param.to_string());
+            init.push(format!("{}: {}.to_vec(),", name, name));
+            fields.push(format!(
+                "{}: Vec<{}>,",
+                name,
+                &typ["&[".len()..typ.len() - 1]
+            ));
+            args.push(format!("&self.{}", name));
+        } else {
+            required.push(param.to_string());
+            init.push(format!("{},", name));
+            fields.push(format!("{},", param));
+            args.push(format!("self.{}", name));
+        }
+    }
+
+    let constructor = format!(
+        r#"/**
+* A request to `{}`, to set its query parameters by name rather than by position.
+* The ones left unset are left off the query.
+*/
+pub fn {}_request(&self, {}) -> {}<'_> {{
+    {} {{
+        api: self,
+        {}
+    }}
+}}
+"#,
+        fn_name,
+        fn_name,
+        required.join(" "),
+        builder,
+        builder,
+        init.join("\n"),
+    );
+
+    let builder = format!(
+        r#"
+/// A request to [`{}::{}`], see [`{}::{}_request`].
+pub struct {}<'a> {{
+    api: &'a {},
+    {}
+}}
+
+impl<'a> {}<'a> {{
+    {}/// Send the request.
+    pub async fn send(self) -> Result<{}> {{
+        self.api.{}({}).await
+    }}
+}}
+"#,
+        group,
+        fn_name,
+        group,
+        fn_name,
+        builder,
+        group,
+        fields.join("\n"),
+        builder,
+        setters,
+        response_type,
+        fn_name,
+        args.join(", "),
+    );
+
+    (constructor, builder)
+}
+
+/*
+ * Whether the operation answers with the bytes of a PDF, like a document or a
+ * transcript, rather than with JSON.
+ */
+fn returns_binary_pdf(o: &openapiv3::Operation) -> bool {
+    use openapiv3::{SchemaKind, StringFormat, Type, VariantOrUnknownOrEmpty::Item};
+
+    let schema = o
+        .responses
+        .responses
+        .first()
+        .and_then(|(_, r)| r.item().ok())
+        .and_then(|r| r.content.get("application/pdf"))
+        .and_then(|mt| mt.schema.as_ref())
+        .and_then(|s| s.item().ok());
+    match schema.map(|s| &s.schema_kind) {
+        Some(SchemaKind::Type(Type::String(st))) => {
+            matches!(st.format, Item(StringFormat::Binary))
+        }
+        _ => false,
+    }
+}
+
+/*
+ * Add the call, in a readable form such as `GET /phone/users/{userId} [userId=123]`
+ * rather than its encoded url, to the errors of the call to the client in `fn_inner`.
+ */
+fn with_url_context(fn_inner: &str, m: &str, p: &str, args: &str) -> String {
+    let context = format!(
+        r#".with_context(|| crate::utils::pretty_url("{}", "{}", &{}))"#,
+        m, p, args
+    );
+
+    // The function returns what the call does, through the client or a clone
+    // of it.
+    let last = fn_inner.rsplit(';').next().unwrap_or_default().trim();
+    if (last.starts_with("self.client.") || last.starts_with("client.")) && last.ends_with(".await")
+    {
+        return format!("{}{}", fn_inner, context);
+    }
+
+    // The function picks apart the response of its first call.
+    if let Some(i) = fn_inner.find("= self.client.") {
+        if let Some(j) = fn_inner[i..].find(".await?") {
+            let j = i + j + ".await".len();
+            return format!("{}{}{}", &fn_inner[..j], context, &fn_inner[j..]);
+        }
+    }
+
+    fn_inner.to_string()
+}
+
+/*
+ * The media type of the response of an operation, if it does not send back JSON.
+ */
+fn response_mime_type(o: &openapiv3::Operation) -> Option<String> {
+    let content = &o.responses.responses.first()?.1.item().ok()?.content;
+    if content.contains_key("application/json") {
+        return None;
+    }
+    content.keys().next().filter(|ct| *ct != "*/*").cloned()
+}
+
+/*
+ * The pairs of string parameters of a function that its docs say to pass one
+ * or the other of, like "You must provide either `to_contact` or `to_channel`".
+ * A name in the docs stands for the parameter of that name, or one ending in
+ * it, so `contact` can be the `to_contact` parameter.
+ */
+fn exclusive_params(
+    o: &openapiv3::Operation,
+    parameters: &BTreeMap<String, &openapiv3::Parameter>,
+    fn_params_str: &[String],
+) -> Vec<(String, String)> {
+    let mut texts: Vec<&str> = Vec::new();
+    texts.extend(o.summary.as_deref());
+    texts.extend(o.description.as_deref());
+    for par in o.parameters.iter() {
+        let item = match par {
+            openapiv3::ReferenceOr::Reference { reference } => {
+                match parameters.get(&struct_name(
+                    &reference.replace("#/components/parameters/", ""),
+                )) {
+                    Some(param) => *param,
+                    None => continue,
+                }
+            }
+            openapiv3::ReferenceOr::Item(item) => item,
+        };
+        if let Some(d) = get_parameter_data(item).and_then(|d| d.description.as_deref()) {
+            texts.push(d);
+        }
+    }
+
+    // Only strings, which are not sent when they are empty, can be left out.
+    let string_params: Vec<&str> = fn_params_str
+        .iter()
+        .filter_map(|p| p.strip_suffix(": &str,"))
+        .collect();
+    let param_for = |name: &str| {
+        let name = to_snake_case(name);
+        string_params
+            .iter()
+            .find(|p| **p == name || p.ends_with(&format!("_{}", name)))
+            .map(|p| p.to_string())
+    };
+
+    let mut pairs: Vec<(String, String)> = Vec::new();
+    for text in texts {
+        let lower = text.to_ascii_lowercase();
+        for (i, _) in lower.match_indices("provide either ") {
+            // Up to the end of the sentence.
+            let rest = &text[i..];
+            let end = ['.', '\n', '<']
+                .iter()
+                .filter_map(|c| rest.find(*c))
+                .min()
+                .unwrap_or(rest.len());
+            let sentence = &rest[..end];
+
+            let names: Vec<&str> = sentence.split('`').skip(1).step_by(2).collect();
+            if names.len() < 2 {
+                continue;
+            }
+            let between = sentence
+                .split('`')
+                .nth(2)
+                .map(|b| b.contains(" or "))
+                .unwrap_or(false);
+            if !between {
+                continue;
+            }
+
+            if let (Some(x), Some(y)) = (param_for(names[0]), param_for(names[1])) {
+                if x != y && !pairs.contains(&(x.clone(), y.clone())) {
+                    pairs.push((x, y));
+                }
+            }
+        }
+    }
+    pairs
+}
+
+/*
+ * The array properties of the JSON request body of an operation that the spec
+ * bounds with `minItems` or `maxItems`, with those bounds.
+ */
+fn body_item_bounds(
+    api: &openapiv3::OpenAPI,
+    o: &openapiv3::Operation,
+) -> Vec<(String, Option<usize>, Option<usize>)> {
+    use openapiv3::{ReferenceOr, SchemaKind, Type};
+
+    let components = match &api.components {
+        Some(components) => components,
+        None => return Vec::new(),
+    };
+    let schema_by_ref = |reference: &str| {
+        let name = reference.trim_start_matches("#/components/schemas/");
+        components.schemas.get(name).and_then(|s| s.item().ok())
+    };
+
+    let body = match &o.request_body {
+        Some(ReferenceOr::Item(b)) => Some(b),
+        Some(ReferenceOr::Reference { reference }) => {
+            let name = reference.trim_start_matches("#/components/requestBodies/");
+            components
+                .request_bodies
+                .get(name)
+                .and_then(|b| b.item().ok())
+        }
+        None => None,
+    };
+    let schema = body
+        .and_then(|b| b.content.first())
+        .filter(|(ct, _)| ct.contains("application/json"))
+        .and_then(|(_, mt)| mt.schema.as_ref())
+        .and_then(|s| match s {
+            ReferenceOr::Item(s) => Some(s),
+            ReferenceOr::Reference { reference } => schema_by_ref(reference),
+        });
+    let properties = match schema.map(|s| &s.schema_kind) {
+        Some(SchemaKind::Type(Type::Object(ot))) => &ot.properties,
+        _ => return Vec::new(),
+    };
+
+    properties
+        .iter()
+        .filter_map(|(name, p)| {
+            let p = match p {
+                ReferenceOr::Item(p) => p.as_ref(),
+                ReferenceOr::Reference { reference } => schema_by_ref(reference)?,
+            };
+            match &p.schema_kind {
+                SchemaKind::Type(Type::Array(at))
+                    if at.min_items.is_some() || at.max_items.is_some() =>
+                {
+                    Some((name.to_string(), at.min_items, at.max_items))
+                }
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/*
+ * Whether the response type of a `POST` only has the id of what it created, and
+ * maybe its name, like `{"id": "..."}`.
+ */
+fn returns_only_id(ts: &TypeSpace, tid: &TypeId) -> bool {
+    match ts.id_to_entry.get(tid).map(|te| &te.details) {
+        Some(crate::TypeDetails::Object(omap, _)) => {
+            omap.contains_key("id") && omap.keys().all(|k| k == "id" || k == "name")
+        }
+        _ => false,
+    }
+}
+
+/*
+ * Find the `GET` of what a `POST` to `p` creates, at `p` with the id on the end,
+ * and return its path and response type. We only use it if it takes nothing
+ * but the id, so no query parameters.
+ */
+fn get_created_fetch(
+    api: &openapiv3::OpenAPI,
+    p: &str,
+    ts: &mut TypeSpace,
+    parameters: &BTreeMap<String, &openapiv3::Parameter>,
+) -> Result<Option<(String, String)>> {
+    for (pn, item) in api.paths.iter() {
+        let id = match pn.strip_prefix(p).and_then(|s| s.strip_prefix('/')) {
+            Some(id) => id,
+            None => continue,
+        };
+        if !id.starts_with('{') || !id.ends_with('}') || id.contains('/') {
+            continue;
+        }
+
+        let item = item.item()?;
+        let o = match &item.get {
+            Some(o) => o,
+            None => continue,
+        };
+
+        for par in item.parameters.iter().chain(o.parameters.iter()) {
+            let par = match par {
+                openapiv3::ReferenceOr::Reference { reference } => {
+                    let name = struct_name(&reference.replace("#/components/parameters/", ""));
+                    match parameters.get(&name) {
+                        Some(par) => *par,
+                        None => bail!("could not find parameter with reference: {}", reference),
+                    }
+                }
+                openapiv3::ReferenceOr::Item(par) => par,
+            };
+            if let openapiv3::Parameter::Query { .. } = par {
+                return Ok(None);
+            }
+        }
+
+        let op_id = match &o.operation_id {
+            Some(op_id) => op_id.to_string(),
+            None => path_to_operation_id(pn, "GET"),
+        };
+        let (mut rt, _, inner_rt, _) = get_response_type(api, &to_snake_case(&op_id), ts, o)?;
+        if rt.starts_with("Option<") {
+            rt = rt
+                .trim_start_matches("Option<")
+                .trim_end_matches('>')
+                .to_string();
+        }
+        if rt.starts_with("Vec<") || rt == "()" || !inner_rt.is_empty() {
+            return Ok(None);
+        }
+
+        return Ok(Some((pn.to_string(), rt)));
+    }
+
+    Ok(None)
+}
+
 fn is_page_param(s: &str, proper_name: &str) -> bool {
     s == "page"
         || s == "per_page"
@@ -1186,3 +2948,7 @@ fn is_okta_unnecessary_param(s: &str) -> bool {
 fn is_shipbob_unnecessary_param(s: &str) -> bool {
     s == "shipbob_channel_id"
 }
+
+fn is_docusign_bool_param(s: &str) -> bool {
+    s == "apply_to_templates"
+}
@@ -1,5 +1,6 @@
 mod client;
 mod functions;
+mod overrides;
 mod template;
 mod types;
 mod utils;
@@ -536,6 +537,10 @@ pub enum TypeDetails {
     OneOf(Vec<TypeId>, openapiv3::SchemaData),
     AnyOf(Vec<TypeId>, openapiv3::SchemaData),
     AllOf(Vec<TypeId>, openapiv3::SchemaData),
+    /*
+     * Either one of the type or an array of them, see `OneOrMany`.
+     */
+    OneOrMany(TypeId, openapiv3::SchemaData),
 }
 
 #[allow(dead_code)]
@@ -593,6 +598,7 @@ impl TypeDetails {
             TypeDetails::OneOf(_, d) => d.description.as_ref(),
             TypeDetails::AnyOf(_, d) => d.description.as_ref(),
             TypeDetails::AllOf(_, d) => d.description.as_ref(),
+            TypeDetails::OneOrMany(_, d) => d.description.as_ref(),
             TypeDetails::Unknown => None,
         };
 
@@ -604,6 +610,20 @@ impl TypeDetails {
     }
 }
 
+/*
+ * The schema data we keep for a composite (all of, one of, any of) type. We only
+ * need to know if it can be null, so a property of this type becomes optional
+ * whether or not it is required.
+ */
+fn nullable_schema_data(schema_data: &openapiv3::SchemaData) -> openapiv3::SchemaData {
+    openapiv3::SchemaData {
+        nullable: schema_data.nullable,
+        // A one_of or any_of with a discriminator is rendered as a tagged enum.
+        discriminator: schema_data.discriminator.clone(),
+        ..Default::default()
+    }
+}
+
 impl PartialEq for TypeDetails {
     fn eq(&self, other: &Self) -> bool {
         if self.description() != other.description()
@@ -660,6 +680,11 @@ impl PartialEq for TypeDetails {
                     return s == os;
                 }
             }
+            TypeDetails::OneOrMany(i, _d) => {
+                if let TypeDetails::OneOrMany(oi, _od) = other {
+                    return i == oi;
+                }
+            }
             TypeDetails::Unknown => {
                 return self == other;
             }
@@ -697,9 +722,74 @@ impl PartialEq for TypeId {
     }
 }
 
+/*
+ * Which way a body goes. The properties the spec marks `readOnly` are left out
+ * of the bodies we send, and those it marks `writeOnly` out of those we get
+ * back. A property the object requires all the same is made optional instead,
+ * since some APIs do want it, like the `version` of a Gusto update, and
+ * others do not.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BodyKind {
+    Request,
+    Response,
+}
+
+impl BodyKind {
+    /// The properties of the schema, if it is an object, left out of its body.
+    fn left_out(self, s: &openapiv3::Schema) -> Vec<&str> {
+        self.other_way(s, false)
+    }
+
+    /// The properties of the schema, if it is an object, made optional in its
+    /// body rather than left out, as the object requires them.
+    fn made_optional(self, s: &openapiv3::Schema) -> Vec<&str> {
+        self.other_way(s, true)
+    }
+
+    /// The properties of the schema that go the other way, of those the object
+    /// requires or of the others.
+    fn other_way(self, s: &openapiv3::Schema, required: bool) -> Vec<&str> {
+        let o = match &s.schema_kind {
+            openapiv3::SchemaKind::Type(openapiv3::Type::Object(o)) => o,
+            _ => return Vec::new(),
+        };
+
+        o.properties
+            .iter()
+            .filter(|(n, p)| match p {
+                openapiv3::ReferenceOr::Item(p) => {
+                    self.leaves_out(&p.schema_data) && o.required.contains(n) == required
+                }
+                openapiv3::ReferenceOr::Reference { .. } => false,
+            })
+            .map(|(n, _)| n.as_str())
+            .collect()
+    }
+
+    fn leaves_out(self, schema_data: &openapiv3::SchemaData) -> bool {
+        match self {
+            BodyKind::Request => schema_data.read_only,
+            BodyKind::Response => schema_data.write_only,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            BodyKind::Request => "request",
+            BodyKind::Response => "response",
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TypeSpace {
     next_id: u64,
+    /*
+     * Basic types have no name of their own, they are given a numbered one
+     * instead, so that the same document always gives the same type space.
+     */
+    next_anonymous: u64,
     /*
      * Object types generally have a useful name, which we would like to match
      * with anywhere that name appears in the definition document.  Many other
@@ -708,14 +798,35 @@ pub struct TypeSpace {
      */
     name_to_id: BTreeMap<String, TypeId>,
     id_to_entry: BTreeMap<TypeId, TypeEntry>,
+    /*
+     * The kind of body the next object selected is, if it is one, so we know
+     * which of its properties to leave out.
+     */
+    body_kind: Option<BodyKind>,
+    /*
+     * The API the types are of, for what we only do for some.
+     */
+    proper_name: String,
 }
 
+/*
+ * Types that keep their own name even when another type has the exact same
+ * shape, by API.  Without this, a response that happens to look like a
+ * different resource is named after that resource, e.g. the `{id, name}`
+ * returned from creating a Zoom phone location would otherwise come out as a
+ * `Site`.
+ */
+const OWN_TYPES: &[(&str, &[&str])] = &[("Zoom", &["AddLocationResponse"])];
+
 impl TypeSpace {
-    fn new() -> TypeSpace {
+    fn new(proper_name: &str) -> TypeSpace {
         TypeSpace {
             next_id: 1,
+            next_anonymous: 1,
             name_to_id: BTreeMap::new(),
             id_to_entry: BTreeMap::new(),
+            body_kind: None,
+            proper_name: proper_name.to_string(),
         }
     }
 
@@ -800,6 +911,9 @@ impl TypeSpace {
                         format!("[ALL_OF {} !NONAME?]", tid.0)
                     }
                 }
+                TypeDetails::OneOrMany(itid, _) => {
+                    format!("one or many of {}", self.describe(itid))
+                }
                 TypeDetails::Unknown => {
                     format!("[UNKNOWN {}]", tid.0)
                 }
@@ -838,6 +952,7 @@ impl TypeSpace {
                 TypeDetails::OneOf(_, schema_data) => Some(schema_data),
                 TypeDetails::AnyOf(_, schema_data) => Some(schema_data),
                 TypeDetails::AllOf(_, schema_data) => Some(schema_data),
+                TypeDetails::OneOrMany(_, schema_data) => Some(schema_data),
                 TypeDetails::Unknown => None,
             }
         } else {
@@ -947,6 +1062,14 @@ impl TypeSpace {
                 TypeDetails::Array(itid, _) => {
                     Ok(format!("Vec<{}>", self.render_type(itid, in_mod)?))
                 }
+                TypeDetails::OneOrMany(itid, _) => {
+                    let rt = self.render_type(itid, in_mod)?;
+                    if in_mod {
+                        Ok(format!("OneOrMany<{}>", rt))
+                    } else {
+                        Ok(format!("crate::types::OneOrMany<{}>", rt))
+                    }
+                }
                 TypeDetails::Optional(itid, _) => {
                     let rt = self.render_type(itid, in_mod)?;
 
@@ -1013,6 +1136,12 @@ impl TypeSpace {
         id
     }
 
+    fn anonymous_name(&mut self) -> String {
+        let name = format!("<anonymous {}>", self.next_anonymous);
+        self.next_anonymous += 1;
+        name
+    }
+
     fn id_for_name(&mut self, name: &str) -> TypeId {
         let id = if let Some(id) = self.name_to_id.get(name) {
             id.clone()
@@ -1087,9 +1216,16 @@ impl TypeSpace {
          * but are duplicated all over. Let's ensure that we don't have a type with a different
          * name that is this exact same type.
          */
-        if !is_reference {
+        let proper_name = &self.proper_name;
+        let keeps_own_type = |n: &Option<String>| match n {
+            Some(n) => OWN_TYPES
+                .iter()
+                .any(|(p, t)| p == proper_name && t.contains(&n.as_str())),
+            None => false,
+        };
+        if !is_reference && !keeps_own_type(&name) {
             for (tid, te) in self.id_to_entry.iter() {
-                if te.details == details {
+                if te.details == details && !keeps_own_type(&te.name) {
                     let id = tid.clone();
 
                     // We have a match! Okay, now we want to keep the shorter
@@ -1361,6 +1497,56 @@ impl TypeSpace {
         }
     }
 
+    /*
+     * Select the type of a request or response body, leaving out the properties
+     * that go the other way, see `BodyKind`. A body that is a schema with any of
+     * them gets a type of its own, named after the schema and the kind of body.
+     */
+    fn select_body(
+        &mut self,
+        api: &OpenAPI,
+        name: &str,
+        s: &openapiv3::ReferenceOr<openapiv3::Schema>,
+        kind: BodyKind,
+    ) -> Result<TypeId> {
+        let (sn, schema) = match s {
+            openapiv3::ReferenceOr::Reference { reference } => {
+                let sn = reference.trim_start_matches("#/components/schemas/");
+                match api
+                    .components
+                    .as_ref()
+                    .and_then(|c| c.schemas.get(sn))
+                    .and_then(|s| s.item().ok())
+                {
+                    Some(schema)
+                        if !kind.left_out(schema).is_empty()
+                            || !kind.made_optional(schema).is_empty() =>
+                    {
+                        (sn, schema)
+                    }
+                    _ => return self.select(Some(name), s, ""),
+                }
+            }
+            openapiv3::ReferenceOr::Item(_) => {
+                self.body_kind = Some(kind);
+                let id = self.select(Some(name), s, "");
+                self.body_kind = None;
+                return id;
+            }
+        };
+
+        // Name the properties as the schema's own type does, so they share types.
+        self.body_kind = Some(kind);
+        let details = self.get_type_name_and_details(Some(&clean_name(sn)), schema, "", "");
+        self.body_kind = None;
+        self.add_if_not_exists(
+            Some(clean_name(&format!("{} {}", sn, kind.name()))),
+            details?.1,
+            "",
+            false,
+        )
+    }
+
     fn select_box(
         &mut self,
         name: Option<&str>,
@@ -1418,8 +1604,8 @@ impl TypeSpace {
             }
         }
 
-        // Generate a UUID for this type.
-        let uid = uuid::Uuid::new_v4();
+        // Name this type, in case it is a basic type without a name of its own.
+        let uid = self.anonymous_name();
 
         match &s.schema_kind {
             openapiv3::SchemaKind::Type(t) => match t {
@@ -1457,6 +1643,12 @@ impl TypeSpace {
                     }
                 }
                 openapiv3::Type::Object(o) => {
+                    // Only this object is the body, not the objects in it.
+                    let (left_out, made_optional) = match self.body_kind.take() {
+                        Some(kind) => (kind.left_out(&s), kind.made_optional(&s)),
+                        None => Default::default(),
+                    };
+
                     // Object types must have a consistent name.
                     let mut name = clean_name(match (name, s.schema_data.title.as_deref()) {
                         (Some(n), None) => n,
@@ -1533,6 +1725,12 @@ impl TypeSpace {
                             continue;
                         }
 
+                        if left_out.contains(&n.as_str()) {
+                            continue;
+                        }
+                        let required =
+                            o.required.contains(n) && !made_optional.contains(&n.as_str());
+
                         // If we have a unit struct where there is only one property in
                         // the object, call the object by that property name.
                         // This is Oxide exclusive.
@@ -1566,7 +1764,7 @@ impl TypeSpace {
                             }
 
                             // TODO: "page" is specific to ramp
-                            if (o.required.contains(n) || name == "page") && (n != "repo") {
+                            if (required || name == "page") && (n != "repo") {
                                 omap.insert(n.to_string(), itid.clone());
                             } else {
                                 // This is an optional member.
@@ -1578,7 +1776,7 @@ impl TypeSpace {
                         }
 
                         // TODO: "page" is specific to ramp
-                        if o.required.contains(n) || name == "page" {
+                        if required || name == "page" {
                             omap.insert(n.to_string(), itid);
                         } else {
                             // This is an optional member.
@@ -1645,30 +1843,30 @@ impl TypeSpace {
                         // otherwise you are just risking a panic when the vendor passes back
                         // a null.
                         Item(DateTime) => Ok((
-                            Some(uid.to_string()),
+                            Some(uid.clone()),
                             TypeDetails::Basic(
                                 "Option<chrono::DateTime<chrono::Utc>>".to_string(),
                                 s.schema_data.clone(),
                             ),
                         )),
                         Item(Date) => Ok((
-                            Some(uid.to_string()),
+                            Some(uid.clone()),
                             TypeDetails::Basic(
                                 "Option<chrono::NaiveDate>".to_string(),
                                 s.schema_data.clone(),
                             ),
                         )),
                         Item(Password) => Ok((
-                            Some(uid.to_string()),
+                            Some(uid.clone()),
                             TypeDetails::Basic("String".to_string(), s.schema_data.clone()),
                         )),
                         // TODO: as per the spec this is base64 encoded chars.
                         Item(Byte) => Ok((
-                            Some(uid.to_string()),
+                            Some(uid.clone()),
                             TypeDetails::Basic("bytes::Bytes".to_string(), s.schema_data.clone()),
                         )),
                         Item(Binary) => Ok((
-                            Some(uid.to_string()),
+                            Some(uid.clone()),
                             TypeDetails::Basic("bytes::Bytes".to_string(), s.schema_data.clone()),
                         )),
                         Empty => {
@@ -1685,7 +1883,7 @@ impl TypeSpace {
                             if name.starts_with("date ") || name.ends_with(" date") {
                                 // Gusto does not set the type as a NaiveDate but it should be so let's fix it.
                                 Ok((
-                                    Some(uid.to_string()),
+                                    Some(uid.clone()),
                                     TypeDetails::Basic(
                                         "Option<chrono::NaiveDate>".to_string(),
                                         s.schema_data.clone(),
@@ -1693,95 +1891,103 @@ impl TypeSpace {
                                 ))
                             } else {
                                 Ok((
-                                    Some(uid.to_string()),
+                                    Some(uid.clone()),
                                     TypeDetails::Basic("String".to_string(), s.schema_data.clone()),
                                 ))
                             }
                         }
                         Unknown(f) => match f.as_str() {
                             "float" => Ok((
-                                Some(uid.to_string()),
+                                Some(uid.clone()),
                                 TypeDetails::Basic("f64".to_string(), s.schema_data.clone()),
                             )),
                             "int64" => Ok((
-                                Some(uid.to_string()),
+                                Some(uid.clone()),
                                 TypeDetails::Basic("i64".to_string(), s.schema_data.clone()),
                             )),
                             "uint64" => Ok((
-                                Some(uid.to_string()),
+                                Some(uid.clone()),
                                 TypeDetails::Basic("u64".to_string(), s.schema_data.clone()),
                             )),
+                            // Money, which would lose its precision as a float.
+                            "decimal" => Ok((
+                                Some(uid.clone()),
+                                TypeDetails::Basic(
+                                    "Option<rust_decimal::Decimal>".to_string(),
+                                    s.schema_data.clone(),
+                                ),
+                            )),
                             "google-fieldmask" => Ok((
-                                Some(uid.to_string()),
+                                Some(uid.clone()),
                                 TypeDetails::Basic("String".to_string(), s.schema_data.clone()),
                             )),
                             "google-datetime" => Ok((
-                                Some(uid.to_string()),
+                                Some(uid.clone()),
                                 TypeDetails::Basic(
                                     "Option<chrono::DateTime<chrono::Utc>>".to_string(),
                                     s.schema_data.clone(),
                                 ),
                             )),
                             "ISO 8601 date-time" => Ok((
-                                Some(uid.to_string()),
+                                Some(uid.clone()),
                                 TypeDetails::Basic(
                                     "Option<chrono::DateTime<chrono::Utc>>".to_string(),
                                     s.schema_data.clone(),
                                 ),
                             )),
                             "Promo date-time" => Ok((
-                                Some(uid.to_string()),
+                                Some(uid.clone()),
                                 TypeDetails::Basic(
                                     "Option<chrono::DateTime<chrono::Utc>>".to_string(),
                                     s.schema_data.clone(),
                                 ),
                             )),
                             "dateTime" => Ok((
-                                Some(uid.to_string()),
+                                Some(uid.clone()),
                                 TypeDetails::Basic(
                                     "Option<chrono::DateTime<chrono::Utc>>".to_string(),
                                     s.schema_data.clone(),
                                 ),
                             )),
                             "ipv4" => Ok((
-                                Some(uid.to_string()),
+                                Some(uid.clone()),
                                 TypeDetails::Basic(
                                     "std::net::Ipv4Addr".to_string(),
                                     s.schema_data.clone(),
                                 ),
                             )),
                             "uri" => Ok((
-                                Some(uid.to_string()),
+                                Some(uid.clone()),
                                 TypeDetails::Basic(
                                     "Option<url::Url>".to_string(),
                                     s.schema_data.clone(),
                                 ),
                             )),
                             "uri-template" => Ok((
-                                Some(uid.to_string()),
+                                Some(uid.clone()),
                                 TypeDetails::Basic("String".to_string(), s.schema_data.clone()),
                             )),
                             "url" => Ok((
-                                Some(uid.to_string()),
+                                Some(uid.clone()),
                                 TypeDetails::Basic(
                                     "Option<url::Url>".to_string(),
                                     s.schema_data.clone(),
                                 ),
                             )),
                             "email" => Ok((
-                                Some(uid.to_string()),
+                                Some(uid.clone()),
                                 TypeDetails::Basic("String".to_string(), s.schema_data.clone()),
                             )),
                             "uuid" => Ok((
-                                Some(uid.to_string()),
+                                Some(uid.clone()),
                                 TypeDetails::Basic("String".to_string(), s.schema_data.clone()),
                             )),
                             "hostname" => Ok((
-                                Some(uid.to_string()),
+                                Some(uid.clone()),
                                 TypeDetails::Basic("String".to_string(), s.schema_data.clone()),
                             )),
                             "time" => Ok((
-                                Some(uid.to_string()),
+                                Some(uid.clone()),
                                 TypeDetails::Basic(
                                     "Option<chrono::NaiveTime>".to_string(),
                                     s.schema_data.clone(),
@@ -1792,15 +1998,24 @@ impl TypeSpace {
                     }
                 }
                 openapiv3::Type::Boolean {} => Ok((
-                    Some(uid.to_string()),
+                    Some(uid.clone()),
                     TypeDetails::Basic("bool".to_string(), s.schema_data.clone()),
                 )),
-                openapiv3::Type::Number(_) => Ok((
-                    Some(uid.to_string()),
-                    TypeDetails::Basic("f64".to_string(), s.schema_data.clone()),
-                )),
+                openapiv3::Type::Number(n) => match &n.format {
+                    openapiv3::VariantOrUnknownOrEmpty::Unknown(f) if f == "decimal" => Ok((
+                        Some(uid.clone()),
+                        TypeDetails::Basic(
+                            "Option<rust_decimal::Decimal>".to_string(),
+                            s.schema_data.clone(),
+                        ),
+                    )),
+                    _ => Ok((
+                        Some(uid.clone()),
+                        TypeDetails::Basic("f64".to_string(), s.schema_data.clone()),
+                    )),
+                },
                 openapiv3::Type::Integer(_) => Ok((
-                    Some(uid.to_string()),
+                    Some(uid.clone()),
                     TypeDetails::Basic("i64".to_string(), s.schema_data.clone()),
                 )),
             },
@@ -1850,7 +2065,7 @@ impl TypeSpace {
 
                 Ok((
                     Some(all_of_name),
-                    TypeDetails::AllOf(omap, Default::default()),
+                    TypeDetails::AllOf(omap, nullable_schema_data(&s.schema_data)),
                 ))
             }
             openapiv3::SchemaKind::OneOf { one_of } => {
@@ -1894,12 +2109,32 @@ impl TypeSpace {
                     omap.push(itid);
                 }
 
+                // A one_of of some type and an array of that same type is how a
+                // spec says "an object, or an array of them".
+                if let [a, b] = &omap[..] {
+                    for (one, many) in [(a, b), (b, a)] {
+                        if let Some(TypeDetails::Array(itid, _)) =
+                            self.id_to_entry.get(many).map(|et| &et.details)
+                        {
+                            if itid == one {
+                                return Ok((
+                                    None,
+                                    TypeDetails::OneOrMany(
+                                        one.clone(),
+                                        nullable_schema_data(&s.schema_data),
+                                    ),
+                                ));
+                            }
+                        }
+                    }
+                }
+
                 omap.sort_unstable();
                 omap.dedup();
 
                 Ok((
                     Some(one_of_name),
-                    TypeDetails::OneOf(omap, Default::default()),
+                    TypeDetails::OneOf(omap, nullable_schema_data(&s.schema_data)),
                 ))
             }
             openapiv3::SchemaKind::AnyOf { any_of } => {
@@ -1949,7 +2184,7 @@ impl TypeSpace {
 
                 Ok((
                     Some(any_of_name),
-                    TypeDetails::AnyOf(omap, Default::default()),
+                    TypeDetails::AnyOf(omap, nullable_schema_data(&s.schema_data)),
                 ))
             }
             openapiv3::SchemaKind::Any(any) => {
@@ -2115,7 +2350,7 @@ fn get_parameter_data(param: &openapiv3::Parameter) -> Option<&openapiv3::Parame
         openapiv3::Parameter::Query {
             parameter_data,
             allow_reserved: _,
-            style: openapiv3::QueryStyle::Form,
+            style: _,
             allow_empty_value: _,
         } => {
             return Some(parameter_data);
@@ -2160,13 +2395,18 @@ fn render_param(
         enums.push(e.to_string());
     }
 
+    // A filter that defaults to `all` does not filter at all, so it displays
+    // as empty and is left off the query, like a `Noop`.
+    let all_is_default = !required && default.map_or(false, |d| *d == "all");
+
     if !description.is_empty() {
         a("/**");
         a(&format!("* {}", description.replace("\n", "\n*   ")));
         a("*/");
     }
 
-    a("#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]");
+    // All the variants are unit, so the enum can be `Copy` and passed by value.
+    a("#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]");
 
     a(&format!("pub enum {} {{", sn));
     for e in &enums {
@@ -2197,6 +2437,10 @@ fn render_param(
             // TODO: do something for empty(?)
             continue;
         }
+        if all_is_default && e == "all" {
+            a(&format!(r#"{}::All => "","#, sn));
+            continue;
+        }
         a(&format!(r#"{}::{} => "{}","#, sn, struct_name(e), e));
     }
     if !required && default.is_none() {
@@ -2232,30 +2476,67 @@ fn render_param(
         a("}");
     }
 
+    a(&format!("impl {} {{", sn));
+
+    // The name the API has for each variant, the one it (de)serializes as.
+    a("/// The name of the variant in the API.");
+    a("pub fn as_str(&self) -> &'static str {");
+    a("match self {");
+    for e in &enums {
+        if struct_name(e).is_empty() {
+            continue;
+        }
+        a(&format!(r#"{}::{} => "{}","#, sn, struct_name(e), e));
+    }
+    if !required && default.is_none() {
+        a(&format!(r#"{}::Noop => "","#, sn));
+    }
+    a(&format!(r#"{}::FallthroughString => "*","#, sn));
+    a("}");
+    a("}");
+    a("");
+
+    // The variants the spec lists, say to render a choice of them, or to make
+    // sure each is handled.
+    a("/// All the variants the API lists, in order, without `Noop` or `FallthroughString`.");
+    a(&format!("pub fn all_variants() -> &'static [{}] {{", sn));
+    a("&[");
+    for e in &enums {
+        if struct_name(e).is_empty() {
+            continue;
+        }
+        a(&format!("{}::{},", sn, struct_name(e)));
+    }
+    a("]");
+    a("}");
+
     // Add a method to check if it is empty if it has this Noop state.
     if !required && default.is_none() {
+        a("");
         a(&format!(
-            r#"impl {} {{
-            pub fn is_noop(&self) -> bool {{
+            r#"pub fn is_noop(&self) -> bool {{
                 matches!(self, {}::Noop)
-            }}
-    }}"#,
-            sn, sn
+            }}"#,
+            sn
         ));
-        a("");
     }
+    a("}");
+    a("");
 
     out.to_string()
 }
 
+#[allow(clippy::too_many_arguments)]
 fn gen(
     api: &OpenAPI,
     proper_name: &str,
     host: &str,
+    spec_revision: &str,
     tags: Vec<String>,
     token_endpoint: &str,
     user_consent_endpoint: &str,
     add_post_header: &str,
+    blocking: bool,
 ) -> Result<String> {
     let mut out = String::new();
 
@@ -2285,9 +2566,33 @@ fn gen(
         || proper_name == "Google Sheets"
         || proper_name == "SendGrid"
         || proper_name == "Rev.ai"
+        || proper_name == "Zoom"
     {
         a("pub mod traits;");
     }
+    if proper_name == "DocuSign" {
+        a("pub mod custom_field;");
+        a("pub mod custom_field_id;");
+    }
+    if blocking {
+        a("/// The API without an async runtime, for callers that are not async.");
+        a(r#"#[cfg(any(test, feature = "blocking"))]"#);
+        a(r#"#[cfg_attr(docsrs, doc(cfg(feature = "blocking")))]"#);
+        a("pub mod blocking;");
+    }
+    if proper_name == "Zoom" {
+        a("pub mod byoc;");
+        a("pub mod calling_plan;");
+        a("pub mod error_code;");
+        a("pub mod external_contact_index;");
+        a("pub mod im_message;");
+        a("pub mod location_cache;");
+        if tags.contains(&"master_phone".to_string()) {
+            a("pub mod master_phone;");
+        }
+        a("pub mod number_id;");
+        a("pub mod timezone;");
+    }
     a("#[cfg(test)]");
     a("mod tests;");
     // Hopefully there is never a "tag" named after these reserved libs.
@@ -2352,6 +2657,29 @@ fn gen(
     ));
     a("");
 
+    a(&format!(
+        r#"/// The version of the spec this crate was generated from, as its `info` has it.
+pub const SPEC_VERSION: &str = {:?};
+
+/// The revision of the spec this crate was generated from, a hash of the
+/// document, to tell apart specs with the same version.
+pub const SPEC_REVISION: &str = "{}";
+
+/// What the crate was built from, say to report along with a bug, or to tell
+/// whether the spec has changed since. See `Client::build_info`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BuildInfo {{
+    /// The version of the crate.
+    pub version: &'static str,
+    /// The version of the spec, `SPEC_VERSION`.
+    pub spec_version: &'static str,
+    /// The revision of the spec, `SPEC_REVISION`.
+    pub spec_revision: &'static str,
+}}"#,
+        api.info.version, spec_revision
+    ));
+    a("");
+
     a("mod progenitor_support {");
     a("    use percent_encoding::{AsciiSet, CONTROLS, utf8_percent_encode};");
     a("");
@@ -2377,17 +2705,36 @@ fn gen(
     a("        .add(b'?')");
     a("        .add(b'`')");
     a("        .add(b'{')");
-    a("        .add(b'}');");
+    a("        .add(b'}')");
+    /*
+     * Each parameter fills a single path segment, so a slash or a percent sign
+     * in its value must be encoded too, rather than start a new segment or an
+     * escape.
+     */
+    a("        .add(b'/')");
+    a("        .add(b'%');");
     a("");
     a("    #[allow(dead_code)]");
     a("    pub(crate) fn encode_path(pc: &str) -> String {");
     a("        utf8_percent_encode(pc, PATH_SET).to_string()");
     a("    }");
+    a("");
+    a("    #[allow(dead_code)]");
+    a("    pub(crate) fn encode_resource_name(name: &str) -> String {");
+    a("        name.split('/').map(encode_path).collect::<Vec<_>>().join(\"/\")");
+    a("    }");
     a("}");
     a("");
 
     a("");
 
+    if proper_name != "GitHub" {
+        a(crate::client::HTTP_CONFIG_TEMPLATE);
+        a("");
+        a(crate::client::CLIENT_ERROR_TEMPLATE);
+        a("");
+    }
+
     // Print the client template.
     if proper_name == "GitHub" {
         a(crate::client::GITHUB_TEMPLATE);
@@ -2477,11 +2824,59 @@ fn gen(
         }
     }
 
+    if tags.contains(&"master_phone".to_string()) {
+        a(r#"/// The Zoom Phone operations only a master account can call, with the
+/// `phone:master` scope. They are not on [`Client::phone`], so they can't be
+/// called with a user's token by mistake:
+///
+/// ```compile_fail
+/// # async fn f(zoom: zoom_api::Client, body: &zoom_api::types::UpdatePhoneSettingsRequest) {
+/// let _ = zoom.phone().update_settings("", body).await;
+/// # }
+/// ```
+///
+/// ```no_run
+/// # async fn f(zoom: zoom_api::Client, body: &zoom_api::types::UpdatePhoneSettingsRequest) {
+/// let _ = zoom.master_phone().update_settings("", body).await;
+/// # }
+/// ```
+pub fn master_phone(&self) -> master_phone::MasterPhone {
+    master_phone::MasterPhone::new(self.clone())
+}"#);
+        a("");
+    }
+
+    a(r#"/// The version of the crate.
+pub fn version() -> &'static str {
+    env!("CARGO_PKG_VERSION")
+}
+
+/// The versions of the crate and of the spec it was generated from.
+pub fn build_info() -> BuildInfo {
+    BuildInfo {
+        version: Self::version(),
+        spec_version: SPEC_VERSION,
+        spec_revision: SPEC_REVISION,
+    }
+}"#);
+
     a("}");
 
     Ok(out)
 }
 
+/*
+ * The revision of a spec document, the 64-bit FNV-1a hash of its bytes in
+ * hex. Unlike the std hashers it is the same for every build of the generator,
+ * so the crate only changes when the spec does.
+ */
+fn spec_revision(spec: &[u8]) -> String {
+    let hash = spec.iter().fold(0xcbf2_9ce4_8422_2325_u64, |hash, b| {
+        (hash ^ u64::from(*b)).wrapping_mul(0x0000_0100_0000_01b3)
+    });
+    format!("{:016x}", hash)
+}
+
 pub fn make_plural(proper_name: &str, s: &str) -> String {
     // Only fix the ramp names.
     if proper_name != "Ramp" && proper_name != "Okta" {
@@ -2626,6 +3021,20 @@ pub fn path_to_operation_id(path: &str, method: &str) -> String {
 }
 
 pub fn clean_fn_name(proper_name: &str, oid: &str, tag: &str) -> String {
+    clean_fn_name_articles(proper_name, oid, tag, true)
+}
+
+/*
+ * The name we used to generate for an operation, before stray articles like the
+ * `an` in `getAnAccount` were dropped. A lone `a` always was, so it is only
+ * kept here for the other articles. Use this to keep the old name around as an
+ * alias.
+ */
+pub fn legacy_fn_name(proper_name: &str, oid: &str, tag: &str) -> String {
+    clean_fn_name_articles(proper_name, oid, tag, false)
+}
+
+fn clean_fn_name_articles(proper_name: &str, oid: &str, tag: &str, strip_articles: bool) -> String {
     if proper_name == "GitHub" {
         return to_snake_case(oid).trim_start_matches('_').to_string();
     }
@@ -2650,7 +3059,23 @@ pub fn clean_fn_name(proper_name: &str, oid: &str, tag: &str) -> String {
         o = "send im messages".to_string();
     }
 
-    let mut st = to_snake_case(&o)
+    let mut st = to_snake_case(&o);
+    if strip_articles {
+        // Drop any stray articles, there can be a few in a row.
+        while st.contains("_a_") || st.contains("_an_") || st.contains("_the_") {
+            st = st
+                .replace("_a_", "_")
+                .replace("_an_", "_")
+                .replace("_the_", "_");
+        }
+        st = st
+            .trim_start_matches("a_")
+            .trim_start_matches("the_")
+            .trim_end_matches("_a")
+            .to_string();
+    }
+
+    st = st
         .replace("v_1_", "")
         .replace("_in_", "_")
         .replace("_id_", "_")
@@ -2761,6 +3186,22 @@ fn oid_to_object_name(s: &str) -> String {
     cleaned
 }
 
+/// The example of a request body, from its media type or else from its schema.
+fn body_example(api: &OpenAPI, mt: &openapiv3::MediaType) -> Option<serde_json::Value> {
+    if let Some(example) = &mt.example {
+        return Some(example.clone());
+    }
+
+    let schema = match mt.schema.as_ref()? {
+        openapiv3::ReferenceOr::Item(s) => s,
+        openapiv3::ReferenceOr::Reference { reference } => {
+            let name = reference.trim_start_matches("#/components/schemas/");
+            api.components.as_ref()?.schemas.get(name)?.item().ok()?
+        }
+    };
+    schema.schema_data.example.clone()
+}
+
 fn main() -> Result<()> {
     let mut opts = getopts::Options::new();
     opts.parsing_style(getopts::ParsingStyle::StopAtFirstFree);
@@ -2800,6 +3241,21 @@ fn main() -> Result<()> {
         "A header to add to post requests",
         "ADD_POST_HEADER",
     );
+    opts.optflag(
+        "",
+        "serde-tests",
+        "Generate tests that request bodies serialize like their spec examples",
+    );
+    opts.optflag(
+        "",
+        "validate-against-spec",
+        "Fail if any operation's response can't be fully typed, listing them",
+    );
+    opts.optflag(
+        "",
+        "blocking",
+        "Generate a `blocking` module, with a client that does not need an async runtime",
+    );
     opts.optflag("", "debug", "Print debug output");
 
     let args = match opts.parse(std::env::args().skip(1)) {
@@ -2816,7 +3272,10 @@ fn main() -> Result<()> {
         }
     };
 
-    let api = load_api(&args.opt_str("i").unwrap())?;
+    let proper_name = args.opt_str("proper-name").unwrap();
+    let mut api = load_api(&args.opt_str("i").unwrap())?;
+    overrides::apply(&mut api, &proper_name)?;
+    let spec_revision = spec_revision(&std::fs::read(args.opt_str("i").unwrap())?);
 
     let debug = |s: &str| {
         if args.opt_present("debug") {
@@ -2827,8 +3286,10 @@ fn main() -> Result<()> {
     /*
      * Grab all the types defined by schemas and parameters.
      */
-    let mut ts = TypeSpace::new();
+    let mut ts = TypeSpace::new(&proper_name);
     let mut parameters: BTreeMap<String, &openapiv3::Parameter> = BTreeMap::new();
+    // The spec examples of the request bodies, by the type they are.
+    let mut examples: BTreeMap<String, serde_json::Value> = BTreeMap::new();
 
     if let Some(components) = &api.components {
         // Populate a type to describe each entry in the schemas section.
@@ -2896,12 +3357,23 @@ fn main() -> Result<()> {
                 {
                     if let Some(s) = &mt.schema {
                         let object_name = format!("{} request", name);
-                        let id = ts.select(Some(&clean_name(&object_name)), s, "")?;
+                        let id = ts.select_body(
+                            &api,
+                            &clean_name(&object_name),
+                            s,
+                            BodyKind::Request,
+                        )?;
 
                         // Insert the named type for our reference.
                         // DO NOT CLEAN THE NAME HERE.
                         ts.populate_ref(Some(rn.as_str()), Some(id.clone()), "requestBodies")?;
 
+                        if ct == "application/json" {
+                            if let Some(example) = body_example(&api, mt) {
+                                examples.insert(ts.render_type(&id, true)?, example);
+                            }
+                        }
+
                         debug(&format!("    -> {:?}", id));
                         debug("");
                     }
@@ -2944,7 +3416,12 @@ fn main() -> Result<()> {
                         }
 
                         let object_name = format!("{} response", name);
-                        let id = ts.select(Some(&clean_name(&object_name)), s, "")?;
+                        let id = ts.select_body(
+                            &api,
+                            &clean_name(&object_name),
+                            s,
+                            BodyKind::Response,
+                        )?;
 
                         // Insert the named type for our reference.
                         // DO NOT CLEAN THE NAME HERE.
@@ -2967,7 +3444,6 @@ fn main() -> Result<()> {
      * In addition to types defined in schemas, types may be defined inline in
      * request and response bodies.
      */
-    let proper_name = args.opt_str("proper-name").unwrap();
     let mut tags: Vec<String> = Default::default();
     for (pn, p) in api.paths.iter() {
         let op = p.item()?;
@@ -2983,7 +3459,8 @@ fn main() -> Result<()> {
         let grab = |pn: &str,
                     m: &str,
                     o: Option<&openapiv3::Operation>,
-                    ts: &mut TypeSpace|
+                    ts: &mut TypeSpace,
+                    examples: &mut BTreeMap<String, serde_json::Value>|
          -> Result<String> {
             if let Some(o) = o {
                 let op_id = if o.operation_id.is_none() {
@@ -3031,9 +3508,14 @@ fn main() -> Result<()> {
                         if ct == "application/json" {
                             if let Some(s) = &mt.schema {
                                 let object_name = format!("{} request", oid_to_object_name(&od));
-                                let id = ts.select(Some(&object_name), s, "")?;
+                                let id =
+                                    ts.select_body(&api, &object_name, s, BodyKind::Request)?;
                                 let rt = ts.render_type(&id, true)?;
                                 req.push(format!("{} {:?}", rt, id));
+
+                                if let Some(example) = body_example(&api, mt) {
+                                    examples.insert(rt, example);
+                                }
                             }
                         } else {
                             req.push(ct.to_string());
@@ -3091,8 +3573,12 @@ fn main() -> Result<()> {
                                         // Otherwise we can parse the object.
                                         let object_name =
                                             format!("{} response", oid_to_object_name(&od));
-                                        let id =
-                                            ts.select(Some(&clean_name(&object_name)), s, "")?;
+                                        let id = ts.select_body(
+                                            &api,
+                                            &clean_name(&object_name),
+                                            s,
+                                            BodyKind::Response,
+                                        )?;
                                         let rt = ts.render_type(&id, false)?;
                                         res.push(format!("{} {:?}", rt, id));
                                     }
@@ -3114,20 +3600,44 @@ fn main() -> Result<()> {
                     res.join(" | ")
                 ));
 
-                return Ok(tag);
+                return Ok(functions::master_tag(&proper_name, &tag, o).unwrap_or(tag));
             }
 
             Ok("".to_string())
         };
 
-        tags.push(grab(pn, "GET", op.get.as_ref(), &mut ts)?);
-        tags.push(grab(pn, "POST", op.post.as_ref(), &mut ts)?);
-        tags.push(grab(pn, "PUT", op.put.as_ref(), &mut ts)?);
-        tags.push(grab(pn, "DELETE", op.delete.as_ref(), &mut ts)?);
-        tags.push(grab(pn, "OPTIONS", op.options.as_ref(), &mut ts)?);
-        tags.push(grab(pn, "HEAD", op.head.as_ref(), &mut ts)?);
-        tags.push(grab(pn, "PATCH", op.patch.as_ref(), &mut ts)?);
-        tags.push(grab(pn, "TRACE", op.trace.as_ref(), &mut ts)?);
+        tags.push(grab(pn, "GET", op.get.as_ref(), &mut ts, &mut examples)?);
+        tags.push(grab(pn, "POST", op.post.as_ref(), &mut ts, &mut examples)?);
+        tags.push(grab(pn, "PUT", op.put.as_ref(), &mut ts, &mut examples)?);
+        tags.push(grab(
+            pn,
+            "DELETE",
+            op.delete.as_ref(),
+            &mut ts,
+            &mut examples,
+        )?);
+        tags.push(grab(
+            pn,
+            "OPTIONS",
+            op.options.as_ref(),
+            &mut ts,
+            &mut examples,
+        )?);
+        tags.push(grab(pn, "HEAD", op.head.as_ref(), &mut ts, &mut examples)?);
+        tags.push(grab(
+            pn,
+            "PATCH",
+            op.patch.as_ref(),
+            &mut ts,
+            &mut examples,
+        )?);
+        tags.push(grab(
+            pn,
+            "TRACE",
+            op.trace.as_ref(),
+            &mut ts,
+            &mut examples,
+        )?);
     }
     debug("");
 
@@ -3160,10 +3670,12 @@ fn main() -> Result<()> {
         &api,
         &proper_name,
         &host,
+        &spec_revision,
         tags,
         &token_endpoint,
         &user_consent_endpoint,
         &add_post_header,
+        args.opt_present("blocking"),
     ) {
         Ok(out) => {
             let description = args.opt_str("d").unwrap();
@@ -3174,18 +3686,111 @@ fn main() -> Result<()> {
             let root = PathBuf::from(&output_dir);
             std::fs::create_dir_all(&root)?;
 
+            /*
+             * Generate the types, the Cargo.toml and the utils only need
+             * rust_decimal if some of their fields are decimals, and the utils
+             * only tag enums if some are tagged.
+             */
+            let mut types = types::generate_types(&mut ts, &proper_name)?;
+            if args.opt_present("serde-tests") {
+                types.push_str(&types::generate_serde_tests(&examples));
+            }
+            let decimal = types.contains("rust_decimal::Decimal");
+            let tagged = types.contains("crate::utils::serialize_tagged");
+
             /*
              * Write the Cargo.toml file:
              */
             let mut uuid_lib = "".to_string();
             let mut yup_oauth2_lib = "".to_string();
+            let mut features = "".to_string();
+            let mut reqwest_default_features = "".to_string();
+            let mut tls_features = "".to_string();
             if proper_name != "GitHub" {
                 uuid_lib = r#"
+axum-core = { version = "0.2", optional = true }
+jsonschema = { version = "0.16", default-features = false, optional = true }
 bytes = { version = "1", features = ["serde"] }
+futures = "0.3"
+rand = "0.8"
 async-trait = "^0.1.51"
 urlencoding = "^1.3.3"
+tokio = { version = "1.8.0", features = ["fs", "io-util", "rt", "sync", "time"] }
 uuid = { version = "^0.8", features = ["serde", "v4"] }"#
                     .to_string();
+                features = r#"
+# enable converting a ClientError into an axum response
+axum = ["axum-core"]
+# enable a trait per tag, with the tag's functions, to mock them in tests
+mock = []
+# record the responses of clients with fixtures, when RECORD_FIXTURES is set
+record = []
+# check responses against the schema of the type they are parsed into, see `validate_response`
+validate-responses = ["jsonschema"]
+# inject failures into requests, to test handling them, see `Client::with_failpoints`
+failpoints = []"#
+                    .to_string();
+                tls_features = r#"default = ["rustls-tls"]
+# select the TLS backend, rustls by default or native-tls for the system trust store
+rustls-tls = ["reqwest/rustls-tls"]
+native-tls = ["reqwest/native-tls"]
+"#
+                    .to_string();
+                reqwest_default_features = " default-features = false,".to_string();
+            }
+
+            // The blocking client needs reqwest's, which the tests of the
+            // `blocking` module have without the feature.
+            let mut dev_lib = "".to_string();
+            if args.opt_present("blocking") {
+                features.push_str(
+                    r#"
+# enable the blocking module, a client that does not need an async runtime
+blocking = ["reqwest/blocking"]"#,
+                );
+                dev_lib = r#"
+reqwest = { version = "0.11", default-features = false, features = ["blocking"] }"#
+                    .to_string();
+            }
+
+            // The tests of Zoom mock the trait of a tag, with the `mock` feature.
+            if proper_name == "Zoom" {
+                dev_lib.push_str(
+                    r#"
+mockall = "0.11""#,
+                );
+            }
+
+            if proper_name == "Zoom" {
+                uuid_lib.push_str(
+                    r#"
+chrono-tz = "0.6""#,
+                );
+            }
+
+            // Decimals are read from the text of the JSON that was sent, which
+            // serde_json only hands out as a raw value.
+            let mut schemars_features = "";
+            let mut serde_json_lib = r#""1""#;
+            if decimal {
+                uuid_lib.push_str(
+                    r#"
+rust_decimal = "1""#,
+                );
+                schemars_features = r#" "rust_decimal","#;
+                serde_json_lib = r#"{ version = "1", features = ["raw_value"] }"#;
+            }
+
+            // The allocations of encoding an account id, with and without the
+            // client's path cache.
+            let mut benches = "".to_string();
+            if proper_name == "DocuSign" {
+                benches = r#"
+[[bench]]
+name = "path_cache"
+harness = false
+"#
+                .to_string();
             }
 
             if proper_name.starts_with("Google") {
@@ -3219,28 +3824,41 @@ jsonwebtoken = "7"
 log = {{ version = "^0.4", features = ["serde"] }}
 mime = "0.3"
 percent-encoding = "2.1"
-reqwest = {{ version = "0.11", features = ["json", "multipart"] }}
-schemars = {{ version = "0.8", features = ["bytes", "chrono", "url", "uuid"] }}
+reqwest = {{ version = "0.11",{} features = ["cookies", "json", "multipart", "stream"] }}
+schemars = {{ version = "0.8", features = ["bytes", "chrono",{} "url", "uuid"] }}
 serde = {{ version = "1", features = ["derive"] }}
-serde_json = "1"
+serde_json = {}
 serde_urlencoded = "^0.7"
 url = {{ version = "2", features = ["serde"] }}{}{}
 
 [dev-dependencies]
 base64 = "^0.12"
 dirs = "^3.0.2"
-nom_pem = "4"
+nom_pem = "4"{}
 tokio = {{ version = "1.8.0", features = ["full"] }}
 
 [features]
-# enable etag-based http_cache functionality
-httpcache = ["dirs"]
-
+{}# enable etag-based http_cache functionality
+httpcache = ["dirs"]{}
+{}
 [package.metadata.docs.rs]
 all-features = true
 rustdoc-args = ["--cfg", "docsrs"]
 "#,
-                name, description, version, name, output_dir, uuid_lib, yup_oauth2_lib
+                name,
+                description,
+                version,
+                name,
+                output_dir,
+                reqwest_default_features,
+                schemars_features,
+                serde_json_lib,
+                uuid_lib,
+                yup_oauth2_lib,
+                dev_lib,
+                tls_features,
+                features,
+                benches
             );
             save(&toml, tomlout.as_str())?;
 
@@ -3316,7 +3934,7 @@ rustdoc-args = ["--cfg", "docsrs"]
             /*
              * Create the Rust utils module:
              */
-            let utils = utils::generate_utils(&proper_name);
+            let utils = utils::generate_utils(&proper_name, decimal, tagged);
             let mut utilsrs = src.clone();
             utilsrs.push("utils.rs");
             save(utilsrs, utils.as_str())?;
@@ -3324,25 +3942,53 @@ rustdoc-args = ["--cfg", "docsrs"]
             /*
              * Create the Rust source types file containing the generated types:
              */
-            let types = types::generate_types(&mut ts, &proper_name)?;
             let mut typesrs = src.clone();
             typesrs.push("types.rs");
-            save(typesrs, types.as_str())?;
+            save(&typesrs, types.as_str())?;
 
             /*
              * Create the Rust source files for each of the tags functions:
              */
             let fail = match functions::generate_files(&api, &proper_name, &mut ts, &parameters) {
-                Ok(files) => {
+                Ok((files, traits, blocking)) => {
+                    // Now that we know which types each tag uses, group them by tag
+                    // at the end of the types.
+                    let types = format!("{}\n{}", types, types::generate_tag_modules(&files));
+                    save(&typesrs, types.as_str())?;
+
                     // We have a map of our files, let's write to them.
                     for (f, content) in files {
                         let mut tagrs = src.clone();
                         tagrs.push(format!("{}.rs", to_snake_case(&clean_name(&f))));
 
-                        let output = format!(
-                            r#"use anyhow::Result;
+                        // The GitHub client does not retry requests.
+                        let retry = if proper_name != "GitHub" {
+                            format!(
+                                r#"/// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self
+    {{
+        {} {{
+            client: self.client.with_retry(policy),
+        }}
+    }}
+"#,
+                                struct_name(&f)
+                            )
+                        } else {
+                            String::new()
+                        };
+
+                        // The calls return a `ClientError` that says which one failed,
+                        // see `functions::with_url_context`.
+                        let imports = if proper_name != "GitHub" {
+                            "use crate::{CallContext, Client, ClientResult as Result};"
+                        } else {
+                            "use anyhow::Result;\n\nuse crate::Client;"
+                        };
 
-use crate::Client;
+                        let output = format!(
+                            r#"{}
 
 pub struct {} {{
     pub client: Client,
@@ -3358,16 +4004,82 @@ impl {} {{
     }}
 
     {}
-}}"#,
+    {}
+}}
+{}"#,
+                            imports,
                             struct_name(&f),
                             struct_name(&f),
                             struct_name(&f),
+                            retry,
                             content,
+                            traits.get(&f).map(|t| t.as_str()).unwrap_or_default(),
                         );
                         save(tagrs, output.as_str())?;
                     }
 
-                    false
+                    /*
+                     * Create the `blocking` module, with the functions of each tag
+                     * the blocking client can send:
+                     */
+                    if args.opt_present("blocking") {
+                        let mut blocking_dir = src.clone();
+                        blocking_dir.push("blocking");
+                        std::fs::create_dir_all(&blocking_dir)?;
+
+                        let mut blocking_tags = Vec::new();
+                        for (f, content) in blocking {
+                            let tag = to_snake_case(&clean_name(&f));
+                            let mut tagrs = blocking_dir.clone();
+                            tagrs.push(format!("{}.rs", tag));
+
+                            let output = format!(
+                                r#"use crate::{{blocking::Client, CallContext, ClientResult as Result}};
+
+pub struct {} {{
+    pub client: Client,
+}}
+
+impl {} {{
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self
+    {{
+        {} {{
+            client,
+        }}
+    }}
+
+    {}
+}}
+"#,
+                                struct_name(&f),
+                                struct_name(&f),
+                                struct_name(&f),
+                                content,
+                            );
+                            save(tagrs, output.as_str())?;
+
+                            blocking_tags.push((tag, struct_name(&f)));
+                        }
+
+                        let mut modrs = blocking_dir;
+                        modrs.push("mod.rs");
+                        save(
+                            modrs,
+                            crate::client::generate_blocking_client(&proper_name, &blocking_tags)
+                                .as_str(),
+                        )?;
+                    }
+
+                    if args.opt_present("validate-against-spec") {
+                        let untyped = functions::untyped_operations(&api, &ts)?;
+                        for u in untyped.iter() {
+                            println!("untyped operation: {}", u);
+                        }
+                        !untyped.is_empty()
+                    } else {
+                        false
+                    }
                 }
                 Err(e) => {
                     println!("generate_files fail: {:?}", e);
@@ -3399,3 +4111,765 @@ impl {} {{
 
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+
+    use anyhow::Result;
+    use openapiv3::OpenAPI;
+
+    use super::{
+        clean_fn_name, clean_name, functions, legacy_fn_name, spec_revision, types, TypeSpace,
+    };
+
+    const PETSTORE: &str = r##"{
+        "openapi": "3.0.3",
+        "info": { "title": "Petstore", "version": "1.0.0" },
+        "paths": {
+            "/pets": {
+                "post": {
+                    "operationId": "createPet",
+                    "tags": ["pets"],
+                    "requestBody": {
+                        "content": {
+                            "application/json": {
+                                "schema": { "$ref": "#/components/schemas/Pet" }
+                            }
+                        }
+                    },
+                    "responses": {
+                        "201": {
+                            "description": "The pet.",
+                            "content": {
+                                "application/json": {
+                                    "schema": { "$ref": "#/components/schemas/Pet" }
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+            "/pets/{petId}": {
+                "get": {
+                    "operationId": "getPet",
+                    "tags": ["pets"],
+                    "parameters": [
+                        {
+                            "name": "petId",
+                            "in": "path",
+                            "required": true,
+                            "schema": { "type": "string" }
+                        }
+                    ],
+                    "responses": {
+                        "200": {
+                            "description": "The pet.",
+                            "content": {
+                                "application/json": {
+                                    "schema": { "$ref": "#/components/schemas/Pet" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        },
+        "components": {
+            "schemas": {
+                "Pet": {
+                    "type": "object",
+                    "properties": {
+                        "name": { "type": "string" },
+                        "id": { "type": "string", "format": "uuid" },
+                        "tags": { "type": "array", "items": { "type": "string" } },
+                        "born": { "type": "string", "format": "date-time" },
+                        "age": { "type": "integer" }
+                    }
+                }
+            }
+        }
+    }"##;
+
+    /// The functions of each tag, the traits of each tag and the blocking
+    /// functions of each tag, as `functions::generate_files` returns them.
+    type Files = (
+        BTreeMap<String, String>,
+        BTreeMap<String, String>,
+        BTreeMap<String, String>,
+    );
+
+    /// Parse the document, then generate its functions and its types for the
+    /// API called `proper_name`.
+    fn generate_parts(
+        spec: &str,
+        proper_name: &str,
+    ) -> Result<(OpenAPI, TypeSpace, Files, String)> {
+        let api: OpenAPI = serde_json::from_str(spec)?;
+
+        let mut ts = TypeSpace::new(proper_name);
+        for (sn, s) in api.components.as_ref().unwrap().schemas.iter() {
+            let id = ts.select(Some(clean_name(sn).as_str()), s, "")?;
+            ts.populate_ref(Some(sn.as_str()), Some(id), "schema")?;
+        }
+        let files = functions::generate_files(&api, proper_name, &mut ts, &BTreeMap::new())?;
+        let types = types::generate_types(&mut ts, proper_name)?;
+        Ok((api, ts, files, types))
+    }
+
+    /// Generate the types and the functions for the document, as `main` does.
+    fn generate(spec: &str) -> Result<String> {
+        let (_, _, (files, _, _), types) = generate_parts(spec, "Petstore")?;
+
+        let mut out = types;
+        for (tag, content) in files {
+            out.push_str(&format!("// {}\n{}", tag, content));
+        }
+        Ok(out)
+    }
+
+    #[test]
+    fn deterministic_output() -> Result<()> {
+        let first = generate(PETSTORE)?;
+        assert_eq!(first, generate(PETSTORE)?);
+
+        // Struct fields are sorted, whatever order the document has them in.
+        let fields: Vec<&str> = first
+            .lines()
+            .filter_map(|l| l.trim().strip_prefix("pub "))
+            .filter_map(|l| l.split(':').next())
+            .filter(|f| ["age", "born", "id", "name", "tags"].contains(f))
+            .collect();
+        assert_eq!(fields, vec!["age", "born", "id", "name", "tags"]);
+
+        // Functions are in the order of the document.
+        let create = first.find("pub async fn create_pet(").unwrap();
+        let get = first.find("pub async fn get_pet(").unwrap();
+        assert!(create < get);
+
+        Ok(())
+    }
+
+    #[test]
+    fn patch_returns_body() -> Result<()> {
+        // An update that may answer with nothing, or with the pet it updated.
+        let patch = r##""patch": {
+            "operationId": "updatePet",
+            "tags": ["pets"],
+            "parameters": [
+                {
+                    "name": "petId",
+                    "in": "path",
+                    "required": true,
+                    "schema": { "type": "string" }
+                }
+            ],
+            "requestBody": {
+                "content": {
+                    "application/json": {
+                        "schema": { "$ref": "#/components/schemas/Pet" }
+                    }
+                }
+            },
+            "responses": {
+                "204": { "description": "Nothing." },
+                "200": {
+                    "description": "The pet.",
+                    "content": {
+                        "application/json": {
+                            "schema": { "$ref": "#/components/schemas/Pet" }
+                        }
+                    }
+                }
+            }
+        },"##;
+        let spec = PETSTORE.replacen(
+            r#""/pets/{petId}": {"#,
+            &format!(r#""/pets/{{petId}}": {{ {}"#, patch),
+            1,
+        );
+
+        let out = generate(&spec)?;
+        let update = &out[out.find("pub async fn update_pet(").unwrap()..];
+        let signature = &update[..update.find('{').unwrap()];
+        // It answers 204 with nothing, so the pet is an `Option`.
+        assert!(
+            signature.ends_with(") -> Result<Option<crate::types::Pet>> "),
+            "{}",
+            signature
+        );
+        let docs = out[..out.find("pub async fn update_pet(").unwrap()]
+            .rsplit("/**")
+            .next()
+            .unwrap();
+        assert!(docs.contains("It returns `None` if the API answers `204 No Content`."));
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_reader_sent_with_reader_client() -> Result<()> {
+        let put = r##""put": {
+            "operationId": "uploadPhoto",
+            "tags": ["pets"],
+            "parameters": [
+                {
+                    "name": "petId",
+                    "in": "path",
+                    "required": true,
+                    "schema": { "type": "string" }
+                }
+            ],
+            "requestBody": {
+                "content": {
+                    "application/octet-stream": {
+                        "schema": { "type": "string", "format": "binary" }
+                    }
+                }
+            },
+            "responses": { "204": { "description": "Uploaded." } }
+        },"##;
+        let spec = PETSTORE.replacen(
+            r#""/pets/{petId}": {"#,
+            &format!(r#""/pets/{{petId}}": {{ {}"#, put),
+            1,
+        );
+
+        // The upload is sent with the client that counts the body it streams.
+        let out = generate(&spec)?;
+        let upload = &out[out.find("pub async fn upload_photo_from_reader<").unwrap()..];
+        let upload = &upload[..upload.find("\n}\n").unwrap()];
+        assert!(
+            upload.contains("let (client, body) = self.client.reader_body(body).await?;"),
+            "{}",
+            upload
+        );
+        assert!(upload.contains("client.put(&url, Some(body)).await.with_context("));
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_only_left_out_of_request() -> Result<()> {
+        // The API gives the pet its id, so we have no id to send.
+        let spec = PETSTORE.replace(
+            r#""id": { "type": "string", "format": "uuid" },"#,
+            r#""id": { "type": "string", "format": "uuid", "readOnly": true },"#,
+        );
+        let (_, _, (files, _, _), types) = generate_parts(&spec, "Petstore")?;
+
+        let request = &types[types.find("pub struct PetRequest {").unwrap()..];
+        let request = &request[..request.find('}').unwrap()];
+        assert!(!request.contains("pub id:"), "{}", request);
+        assert!(request.contains("pub name:"), "{}", request);
+
+        // The pet we get back still has it.
+        let pet = &types[types.find("pub struct Pet {").unwrap()..];
+        assert!(pet[..pet.find('}').unwrap()].contains("pub id:"));
+
+        let pets = files.values().cloned().collect::<String>();
+        assert!(
+            pets.contains("body: &crate::types::PetRequest")
+                && pets.contains("-> Result<crate::types::Pet>"),
+            "{}",
+            pets
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn required_read_only_optional_in_request() -> Result<()> {
+        // The pet must have been born, but we may not know when.
+        let spec = PETSTORE
+            .replace(
+                r#""born": { "type": "string", "format": "date-time" },"#,
+                r#""born": { "type": "string", "format": "date-time", "readOnly": true },"#,
+            )
+            .replace(
+                r#""type": "object",
+                    "properties": {"#,
+                r#""type": "object",
+                    "required": ["born"],
+                    "properties": {"#,
+            );
+        let (_, _, _, types) = generate_parts(&spec, "Petstore")?;
+
+        let request = &types[types.find("pub struct PetRequest {").unwrap()..];
+        let request = &request[..request.find('}').unwrap()];
+        assert!(
+            request.contains("pub born: Option<chrono::DateTime<chrono::Utc>>,"),
+            "{}",
+            request
+        );
+
+        let pet = &types[types.find("pub struct Pet {").unwrap()..];
+        let pet = &pet[..pet.find('}').unwrap()];
+        assert!(
+            pet.contains("pub born: chrono::DateTime<chrono::Utc>,"),
+            "{}",
+            pet
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn blocking_functions_mirror_async() -> Result<()> {
+        let (_, _, (files, _, blocking), _) = generate_parts(PETSTORE, "Petstore")?;
+
+        // The same function, with the same parameters, without awaiting.
+        let pets = &blocking["pets"];
+        assert!(
+            files["pets"].contains("pub async fn get_pet("),
+            "{}",
+            files["pets"]
+        );
+        assert!(pets.contains("pub fn get_pet("), "{}", pets);
+        assert!(pets.contains("self.client.get(&url, None)"), "{}", pets);
+        assert!(
+            !pets.contains("async") && !pets.contains(".await"),
+            "{}",
+            pets
+        );
+
+        let tags = vec![("pets".to_string(), "Pets".to_string())];
+        let client = crate::client::generate_blocking_client("Petstore", &tags);
+        assert!(client.contains("pub mod pets;"), "{}", client);
+        assert!(client.contains("reqwest::blocking::Client"), "{}", client);
+        assert!(
+            client.contains("pub fn pets(&self) -> pets::Pets {"),
+            "{}",
+            client
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn blocking_forms() -> Result<()> {
+        // A photo of the pet, with a caption for it.
+        let upload = r##""/pets/{petId}/photo": {
+            "post": {
+                "operationId": "uploadPetPhoto",
+                "tags": ["pets"],
+                "parameters": [
+                    {
+                        "name": "petId",
+                        "in": "path",
+                        "required": true,
+                        "schema": { "type": "string" }
+                    }
+                ],
+                "requestBody": {
+                    "content": {
+                        "multipart/form-data": {
+                            "schema": {
+                                "type": "object",
+                                "properties": {
+                                    "photoCaption": { "type": "string" },
+                                    "photo_file": { "type": "string", "format": "binary" }
+                                }
+                            }
+                        }
+                    }
+                },
+                "responses": {
+                    "204": { "description": "Nothing." }
+                }
+            }
+        },"##;
+        let spec = PETSTORE.replacen(r#""paths": {"#, &format!(r#""paths": {{ {}"#, upload), 1);
+        let (_, _, (_, _, blocking), _) = generate_parts(&spec, "Petstore")?;
+
+        // The upload is not left out, it is sent with a form of the blocking client.
+        let pets = &blocking["pets"];
+        assert!(pets.contains("pub fn upload_pet_photo("), "{}", pets);
+        assert!(
+            pets.contains(
+                r#"let form = crate::blocking::multipart_form(body)?.part("photo_file", reqwest::blocking::multipart::Part::bytes(photo_file.to_vec()).file_name("photo_file"));"#
+            ),
+            "{}",
+            pets
+        );
+        assert!(
+            pets.contains("self.client.request_form(reqwest::Method::POST, &url, form)"),
+            "{}",
+            pets
+        );
+
+        let tags = vec![("pets".to_string(), "Pets".to_string())];
+        let client = crate::client::generate_blocking_client("Petstore", &tags);
+        assert!(
+            client.contains("fn multipart_form<B>(body: &B) -> serde_json::Result<reqwest::blocking::multipart::Form>"),
+            "{}",
+            client
+        );
+        assert!(client.contains("fn request_form<Out>("), "{}", client);
+        assert!(
+            client.contains("fn request_with_accept_mime<Out>("),
+            "{}",
+            client
+        );
+        assert!(
+            client.contains("impl From<&crate::Client> for Client {"),
+            "{}",
+            client
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn untyped_operations_are_reported() -> Result<()> {
+        // A feed we have no model for.
+        let feed = r##""/pets/feed": {
+            "get": {
+                "operationId": "getFeed",
+                "tags": ["pets"],
+                "responses": {
+                    "200": {
+                        "description": "The feed.",
+                        "content": {
+                            "application/xml": {
+                                "schema": { "type": "string" }
+                            }
+                        }
+                    }
+                }
+            }
+        },"##;
+        let spec = PETSTORE.replacen(r#""paths": {"#, &format!(r#""paths": {{ {}"#, feed), 1);
+        let (api, ts, _, _) = generate_parts(&spec, "Petstore")?;
+
+        assert_eq!(
+            functions::untyped_operations(&api, &ts)?,
+            vec!["GET /pets/feed: response body is not modeled".to_string()]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn unit_enums_are_copy() {
+        let out = render_param(
+            "TimeType",
+            &["startTime".to_string(), "endTime".to_string()],
+            false,
+            "",
+            None,
+        );
+        assert!(
+            out.contains("Clone, Copy, JsonSchema)]\npub enum TimeType {"),
+            "{}",
+            out
+        );
+    }
+
+    #[test]
+    fn enum_variants() {
+        let out = render_param(
+            "TimeType",
+            &["startTime".to_string(), "endTime".to_string()],
+            false,
+            "",
+            None,
+        );
+        assert!(
+            out.contains(r#"TimeType::StartTime => "startTime","#),
+            "{}",
+            out
+        );
+        assert!(
+            out.contains("&[\nTimeType::EndTime,\nTimeType::StartTime,\n]"),
+            "{}",
+            out
+        );
+    }
+
+    #[test]
+    fn one_or_many() -> Result<()> {
+        let spec = PETSTORE.replace(
+            r#""schemas": {"#,
+            r##""schemas": {
+                "Pets": {
+                    "oneOf": [
+                        { "$ref": "#/components/schemas/Pet" },
+                        { "type": "array", "items": { "$ref": "#/components/schemas/Pet" } }
+                    ]
+                },"##,
+        );
+        let (_, ts, _, _) = generate_parts(&spec, "Petstore")?;
+
+        assert_eq!(
+            ts.render_type(&ts.name_to_id["Pets"], false)?,
+            "crate::types::OneOrMany<crate::types::Pet>"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn one_of_enums() -> Result<()> {
+        let spec = PETSTORE.replace(
+            r#""schemas": {"#,
+            r##""schemas": {
+                "Cat": {
+                    "type": "object",
+                    "properties": {
+                        "pet_type": { "type": "string", "enum": ["cat"] },
+                        "lives": { "type": "integer" }
+                    }
+                },
+                "Dog": {
+                    "type": "object",
+                    "properties": {
+                        "pet_type": { "type": "string" },
+                        "good": { "type": "boolean" }
+                    }
+                },
+                "Animal": {
+                    "oneOf": [
+                        { "$ref": "#/components/schemas/Cat" },
+                        { "$ref": "#/components/schemas/Dog" }
+                    ],
+                    "discriminator": {
+                        "propertyName": "pet_type",
+                        "mapping": { "doggo": "#/components/schemas/Dog" }
+                    }
+                },
+                "Critter": {
+                    "anyOf": [
+                        { "$ref": "#/components/schemas/Cat" },
+                        { "$ref": "#/components/schemas/Dog" }
+                    ]
+                },"##,
+        );
+        let (_, _, _, types) = generate_parts(&spec, "Petstore")?;
+
+        // The discriminator tags the enum, with the value from the mapping, or
+        // else the one the type allows.
+        assert!(
+            types.contains("#[serde(tag = \"pet_type\")]\npub enum AnimalOneOf {"),
+            "{}",
+            types
+        );
+        assert!(types.contains("#[serde(rename = \"cat\")]\nCat(Cat),"));
+        assert!(types.contains("#[serde(rename = \"doggo\")]\nDog(Dog),"));
+        // The enum writes and reads the tag itself, so the variants keep their
+        // field for it as it is.
+        assert!(!types.contains("#[serde(skip)]"));
+        assert!(types.contains("#[derive(PartialEq, Debug, Clone, JsonSchema)]\n#[serde(tag"));
+        assert!(types.contains(
+            r#"AnimalOneOf::Dog(v) => crate::utils::serialize_tagged(v, "pet_type", "doggo", serializer),"#
+        ));
+        assert!(types.contains(r#""cat" => serde_json::from_value(value).map(AnimalOneOf::Cat),"#));
+        assert!(types.contains(r#"crate::utils::deserialize_tagged(deserializer, "pet_type")?"#));
+
+        // Without one, an any_of is an enum too, of whichever type parses.
+        assert!(
+            types.contains("#[serde(untagged)]\npub enum CritterAnyOf {"),
+            "{}",
+            types
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn fn_name_articles() {
+        assert_eq!(
+            clean_fn_name("Zoom", "getAExternalContact", "phone"),
+            "get_external_contact"
+        );
+        assert_eq!(
+            clean_fn_name("Zoom", "deleteAExternalContact", "phone"),
+            "delete_external_contact"
+        );
+
+        // A lone `a` was always dropped, so there is no old name to alias.
+        assert_eq!(
+            legacy_fn_name("Zoom", "getAExternalContact", "phone"),
+            "get_external_contact"
+        );
+        assert_eq!(
+            legacy_fn_name("Zoom", "getAnAccountPlan", "billing"),
+            "get_an_account_plan"
+        );
+        assert_eq!(
+            clean_fn_name("Zoom", "getAnAccountPlan", "billing"),
+            "get_account_plan"
+        );
+    }
+
+    #[test]
+    fn array_query_styles() -> Result<()> {
+        let list = r##""get": {
+            "operationId": "listPets",
+            "tags": ["pets"],
+            "parameters": [
+                {
+                    "name": "tags",
+                    "in": "query",
+                    "schema": { "type": "array", "items": { "type": "string" } }STYLE
+                }
+            ],
+            "responses": {
+                "200": {
+                    "description": "The pet.",
+                    "content": {
+                        "application/json": {
+                            "schema": { "$ref": "#/components/schemas/Pet" }
+                        }
+                    }
+                }
+            }
+        },"##;
+
+        for (style, want) in &[
+            (
+                "",
+                r#"for v in tags { query_args.push(("tags", v.to_string())); }"#,
+            ),
+            (
+                r#", "style": "form", "explode": true"#,
+                r#"for v in tags { query_args.push(("tags", v.to_string())); }"#,
+            ),
+            (
+                r#", "style": "form", "explode": false"#,
+                r#"query_args.push(("tags", tags.join(",")));"#,
+            ),
+            (
+                r#", "style": "spaceDelimited""#,
+                r#"query_args.push(("tags", tags.join(" ")));"#,
+            ),
+            (
+                r#", "style": "pipeDelimited""#,
+                r#"query_args.push(("tags", tags.join("|")));"#,
+            ),
+        ] {
+            let spec = PETSTORE.replacen(
+                r#""/pets": {"#,
+                &format!(r#""/pets": {{ {}"#, list.replace("STYLE", style)),
+                1,
+            );
+
+            let out = generate(&spec)?;
+            assert!(out.contains("pub async fn list_pets("), "{}", out);
+            assert!(out.contains(want), "{}: {}", style, out);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn master_scope_functions_on_their_own() -> Result<()> {
+        // Only a master account can update the settings, anyone can get them.
+        let spec = PETSTORE
+            .replace(r#""tags": ["pets"]"#, r#""tags": ["Phone"]"#)
+            .replace(
+                r#""operationId": "createPet","#,
+                r#""operationId": "updateSettings",
+                "description": "**Scopes:** `phone:master`","#,
+            )
+            .replace(
+                r#""operationId": "getPet","#,
+                r#""operationId": "getSettings",
+                "description": "**Scopes:** `phone:read`, `phone:master`","#,
+            );
+        let (_, _, (files, _, _), _) = generate_parts(&spec, "Zoom")?;
+
+        assert!(files["master_phone"].contains("pub async fn update_settings("));
+        assert!(!files["phone"].contains("pub async fn update_settings("));
+        assert!(files["phone"].contains("pub async fn get_settings("));
+        Ok(())
+    }
+
+    #[test]
+    fn multipart_form_body() -> Result<()> {
+        // A photo of the pet, with a caption for it.
+        let upload = r##""/pets/{petId}/photo": {
+            "post": {
+                "operationId": "uploadPetPhoto",
+                "tags": ["pets"],
+                "parameters": [
+                    {
+                        "name": "petId",
+                        "in": "path",
+                        "required": true,
+                        "schema": { "type": "string" }
+                    }
+                ],
+                "requestBody": {
+                    "content": {
+                        "multipart/form-data": {
+                            "schema": {
+                                "type": "object",
+                                "properties": {
+                                    "photoCaption": { "type": "string" },
+                                    "photo_file": {
+                                        "description": "The photo.",
+                                        "type": "string",
+                                        "format": "binary"
+                                    }
+                                },
+                                "required": ["photo_file"]
+                            }
+                        }
+                    }
+                },
+                "responses": {
+                    "204": { "description": "Nothing." }
+                }
+            }
+        },"##;
+        let spec = PETSTORE.replacen(r#""paths": {"#, &format!(r#""paths": {{ {}"#, upload), 1);
+        let (_, _, (files, _, _), types) = generate_parts(&spec, "Petstore")?;
+
+        // The caption is a text field of the request, by its name in the spec.
+        let request = &types[types.find("pub struct UploadPetPhotoRequest {").unwrap()..];
+        let request = &request[..request.find('}').unwrap()];
+        assert!(
+            request.contains(r#"rename = "photoCaption""#),
+            "{}",
+            request
+        );
+        assert!(!request.contains("photo_file"), "{}", request);
+
+        // The photo is sent as a file of its own.
+        let pets = &files["pets"];
+        let upload = &pets[pets.find("pub async fn upload_pet_photo(").unwrap()..];
+        let upload = &upload[..upload.find("\n}\n").unwrap()];
+        assert!(upload.contains("photo_file: &[u8],"), "{}", upload);
+        assert!(
+            upload.contains("body: &crate::types::UploadPetPhotoRequest"),
+            "{}",
+            upload
+        );
+        assert!(
+            upload.contains(
+                r#"let form = crate::utils::multipart_form(body)?.part("photo_file", reqwest::multipart::Part::bytes(photo_file.to_vec()).file_name("photo_file"));"#
+            ),
+            "{}",
+            upload
+        );
+        assert!(
+            upload.contains("self.client.request_form(reqwest::Method::POST, &url, form).await"),
+            "{}",
+            upload
+        );
+        assert!(
+            pets.contains("* * `photo_file: &[u8]` -- The photo."),
+            "{}",
+            pets
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn spec_revision_of_the_document() {
+        // The test vectors of FNV-1a.
+        assert_eq!(spec_revision(b""), "cbf29ce484222325");
+        assert_eq!(spec_revision(b"a"), "af63dc4c8601ec8c");
+
+        // Any change to the document is a new revision, even with the same version.
+        let changed = PETSTORE.replace("The pet.", "A pet.");
+        assert_ne!(
+            spec_revision(PETSTORE.as_bytes()),
+            spec_revision(changed.as_bytes())
+        );
+    }
+}
@@ -0,0 +1,395 @@
+//! Fixes to the specs, by API, made as they are loaded. The specs are
+//! downloaded as they are, so this is where what they get wrong is put right.
+
+use anyhow::{bail, Result};
+use openapiv3::OpenAPI;
+
+/*
+ * Operations the spec has under the wrong path, by API, path and method, with
+ * the path they are really at. Zoom lists the update of a sub account's BYOC
+ * settings under the account's own `/phone/settings`.
+ */
+const MOVED_OPERATIONS: &[(&str, &str, &str, &str)] = &[(
+    "Zoom",
+    "/phone/settings",
+    "patch",
+    "/accounts/{accountId}/phone/settings",
+)];
+
+/*
+ * Operations the spec says return a list, by API, path and method, that return
+ * the one item. Zoom returns the `{id, name}` of the phone location it adds,
+ * as the example in its spec has it.
+ */
+const SINGLE_RESPONSES: &[(&str, &str, &str)] = &[("Zoom", "/phone/locations", "post")];
+
+/*
+ * Parameters the spec takes as any string, by API, path, method and name, with
+ * the values they can be. These are nullable too, as leaving them off has a
+ * meaning of its own, usually "all".
+ */
+const ENUM_PARAMS: &[(&str, &str, &str, &str, &[&str])] = &[
+    (
+        "Zoom",
+        "/phone/recordings",
+        "get",
+        "owner_type",
+        &["user", "callQueue"],
+    ),
+    (
+        "Zoom",
+        "/phone/recordings",
+        "get",
+        "recording_type",
+        &["OnDemand", "Automatic"],
+    ),
+];
+
+const METHODS: &[&str] = &[
+    "get", "put", "post", "delete", "options", "head", "patch", "trace",
+];
+
+/*
+ * Fix the spec of the API as the tables above say.
+ */
+pub fn apply(api: &mut OpenAPI, proper_name: &str) -> Result<()> {
+    for (_, from, method, to) in MOVED_OPERATIONS.iter().filter(|o| o.0 == proper_name) {
+        move_operation(api, from, method, to)?;
+    }
+
+    for (_, path, method) in SINGLE_RESPONSES.iter().filter(|o| o.0 == proper_name) {
+        single_response(api, path, method)?;
+    }
+
+    for (_, path, method, name, values) in ENUM_PARAMS.iter().filter(|o| o.0 == proper_name) {
+        enum_param(api, path, method, name, values)?;
+    }
+
+    Ok(())
+}
+
+/*
+ * Move the operation to the path, right after the one it was in. The
+ * parameters of the path it was in go along with it, if they are in the new
+ * path, and stay with the operations left behind all the same.
+ */
+fn move_operation(api: &mut OpenAPI, from: &str, method: &str, to: &str) -> Result<()> {
+    let item = match api.paths.get_mut(from) {
+        Some(openapiv3::ReferenceOr::Item(item)) => item,
+        _ => bail!("no path {} to move {} from", from, method),
+    };
+
+    let mut moved = item.clone();
+    for m in METHODS {
+        if *m != method {
+            *operation(&mut moved, m)? = None;
+        }
+    }
+    if operation(item, method)?.take().is_none() {
+        bail!("no {} of {} to move", method, from);
+    }
+    moved.parameters.retain(|p| in_path(p, to));
+
+    let mut moved = Some(moved);
+    for (pn, p) in std::mem::take(&mut api.paths) {
+        let here = pn == from;
+        api.paths.insert(pn, p);
+        if here {
+            if let Some(moved) = moved.take() {
+                let moved = openapiv3::ReferenceOr::Item(moved);
+                api.paths.insert(to.to_string(), moved);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/*
+ * Make the lists the operation returns the one item of them.
+ */
+fn single_response(api: &mut OpenAPI, path: &str, method: &str) -> Result<()> {
+    let o = operation_at(api, path, method)?;
+    for r in o.responses.responses.values_mut() {
+        let r = match r {
+            openapiv3::ReferenceOr::Item(r) => r,
+            openapiv3::ReferenceOr::Reference { .. } => continue,
+        };
+        for mt in r.content.values_mut() {
+            let items = match &mt.schema {
+                Some(openapiv3::ReferenceOr::Item(openapiv3::Schema {
+                    schema_kind: openapiv3::SchemaKind::Type(openapiv3::Type::Array(at)),
+                    ..
+                })) => at.items.clone(),
+                _ => None,
+            };
+            mt.schema = match items {
+                Some(openapiv3::ReferenceOr::Item(s)) => Some(openapiv3::ReferenceOr::Item(*s)),
+                Some(openapiv3::ReferenceOr::Reference { reference }) => {
+                    Some(openapiv3::ReferenceOr::Reference { reference })
+                }
+                None => continue,
+            };
+        }
+    }
+
+    Ok(())
+}
+
+/*
+ * Make the string parameter of the operation a nullable enum of the values.
+ */
+fn enum_param(
+    api: &mut OpenAPI,
+    path: &str,
+    method: &str,
+    name: &str,
+    values: &[&str],
+) -> Result<()> {
+    let o = operation_at(api, path, method)?;
+    for p in o.parameters.iter_mut() {
+        let parameter_data = match p {
+            openapiv3::ReferenceOr::Item(openapiv3::Parameter::Query {
+                parameter_data, ..
+            }) => parameter_data,
+            _ => continue,
+        };
+        if parameter_data.name != name {
+            continue;
+        }
+
+        if let openapiv3::ParameterSchemaOrContent::Schema(openapiv3::ReferenceOr::Item(s)) =
+            &mut parameter_data.format
+        {
+            if let openapiv3::SchemaKind::Type(openapiv3::Type::String(st)) = &mut s.schema_kind {
+                st.enumeration = values.iter().map(|v| Some(v.to_string())).collect();
+                // Null is what it defaults to, not whatever the spec says.
+                s.schema_data.nullable = true;
+                s.schema_data.default = None;
+                return Ok(());
+            }
+        }
+    }
+
+    bail!(
+        "no string parameter {} of {} {} to make an enum",
+        name,
+        method,
+        path
+    )
+}
+
+/*
+ * The operation of the API at the path for the method.
+ */
+fn operation_at<'a>(
+    api: &'a mut OpenAPI,
+    path: &str,
+    method: &str,
+) -> Result<&'a mut openapiv3::Operation> {
+    let o = match api.paths.get_mut(path) {
+        Some(openapiv3::ReferenceOr::Item(item)) => operation(item, method)?.as_mut(),
+        _ => None,
+    };
+    match o {
+        Some(o) => Ok(o),
+        None => bail!("no {} of {}", method, path),
+    }
+}
+
+/*
+ * The operation of the path item for the method.
+ */
+fn operation<'a>(
+    item: &'a mut openapiv3::PathItem,
+    method: &str,
+) -> Result<&'a mut Option<openapiv3::Operation>> {
+    Ok(match method {
+        "get" => &mut item.get,
+        "put" => &mut item.put,
+        "post" => &mut item.post,
+        "delete" => &mut item.delete,
+        "options" => &mut item.options,
+        "head" => &mut item.head,
+        "patch" => &mut item.patch,
+        "trace" => &mut item.trace,
+        m => bail!("unknown method {}", m),
+    })
+}
+
+/*
+ * Whether the parameter belongs with the path, as all but path parameters do.
+ */
+fn in_path(p: &openapiv3::ReferenceOr<openapiv3::Parameter>, path: &str) -> bool {
+    match p {
+        openapiv3::ReferenceOr::Item(openapiv3::Parameter::Path { parameter_data, .. }) => {
+            path.contains(&format!("{{{}}}", parameter_data.name))
+        }
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use anyhow::Result;
+    use openapiv3::OpenAPI;
+
+    const SETTINGS: &str = r##"{
+        "openapi": "3.0.3",
+        "info": { "title": "Zoom", "version": "2.0.0" },
+        "paths": {
+            "/phone/settings": {
+                "get": {
+                    "operationId": "phoneSetting",
+                    "responses": { "200": { "description": "OK" } }
+                },
+                "patch": {
+                    "operationId": "updatePhoneSettings",
+                    "responses": { "204": { "description": "Updated" } }
+                },
+                "parameters": [
+                    {
+                        "name": "accountId",
+                        "in": "path",
+                        "required": true,
+                        "schema": { "type": "string" }
+                    }
+                ]
+            },
+            "/phone/users": {
+                "get": {
+                    "operationId": "listPhoneUsers",
+                    "responses": { "200": { "description": "OK" } }
+                }
+            }
+        }
+    }"##;
+
+    #[test]
+    fn single_response() -> Result<()> {
+        let spec = r##"{
+            "openapi": "3.0.3",
+            "info": { "title": "Zoom", "version": "2.0.0" },
+            "paths": {
+                "/phone/locations": {
+                    "post": {
+                        "operationId": "addLocation",
+                        "responses": {
+                            "201": {
+                                "description": "Created",
+                                "content": {
+                                    "application/json": {
+                                        "schema": {
+                                            "type": "array",
+                                            "items": {
+                                                "type": "object",
+                                                "properties": { "id": { "type": "string" } }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }"##;
+        let mut api: OpenAPI = serde_json::from_str(spec)?;
+        super::apply(&mut api, "Zoom")?;
+
+        let post = api.paths["/phone/locations"].item()?.post.as_ref().unwrap();
+        let created = post.responses.responses.values().next().unwrap().item()?;
+        let schema = created.content["application/json"].schema.as_ref().unwrap();
+        assert!(matches!(
+            &schema.item()?.schema_kind,
+            openapiv3::SchemaKind::Type(openapiv3::Type::Object(o)) if o.properties.contains_key("id")
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn enum_param() -> Result<()> {
+        let spec = r##"{
+            "openapi": "3.0.3",
+            "info": { "title": "Zoom", "version": "2.0.0" },
+            "paths": {
+                "/phone/recordings": {
+                    "get": {
+                        "operationId": "getPhoneRecordings",
+                        "parameters": [
+                            {
+                                "in": "query",
+                                "name": "owner_type",
+                                "schema": { "type": "string", "default": "all" }
+                            },
+                            {
+                                "in": "query",
+                                "name": "recording_type",
+                                "schema": { "type": "string" }
+                            }
+                        ],
+                        "responses": { "200": { "description": "OK" } }
+                    }
+                }
+            }
+        }"##;
+        let mut api: OpenAPI = serde_json::from_str(spec)?;
+        super::apply(&mut api, "Zoom")?;
+
+        let get = api.paths["/phone/recordings"].item()?.get.as_ref().unwrap();
+        let owner_type = match &get.parameters[0] {
+            openapiv3::ReferenceOr::Item(openapiv3::Parameter::Query {
+                parameter_data, ..
+            }) => match &parameter_data.format {
+                openapiv3::ParameterSchemaOrContent::Schema(s) => s.item()?,
+                _ => panic!("no schema"),
+            },
+            p => panic!("unexpected {:?}", p),
+        };
+        assert!(owner_type.schema_data.nullable);
+        assert!(owner_type.schema_data.default.is_none());
+        match &owner_type.schema_kind {
+            openapiv3::SchemaKind::Type(openapiv3::Type::String(st)) => assert_eq!(
+                st.enumeration,
+                vec![Some("user".to_string()), Some("callQueue".to_string())]
+            ),
+            k => panic!("unexpected {:?}", k),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn moved_operation() -> Result<()> {
+        let mut api: OpenAPI = serde_json::from_str(SETTINGS)?;
+        super::apply(&mut api, "Zoom")?;
+
+        // The update is at the account's path, with the account id.
+        let paths = api.paths.keys().map(String::as_str).collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            vec![
+                "/phone/settings",
+                "/accounts/{accountId}/phone/settings",
+                "/phone/users"
+            ]
+        );
+        let moved = api.paths["/accounts/{accountId}/phone/settings"].item()?;
+        assert_eq!(
+            moved.patch.as_ref().unwrap().operation_id.as_deref(),
+            Some("updatePhoneSettings")
+        );
+        assert!(moved.get.is_none());
+        assert_eq!(moved.parameters.len(), 1);
+
+        // The settings of the account itself stay where they were.
+        let settings = api.paths["/phone/settings"].item()?;
+        assert!(settings.get.is_some() && settings.patch.is_none());
+        assert_eq!(settings.parameters.len(), 1);
+
+        // Other APIs are left as they are.
+        let mut api: OpenAPI = serde_json::from_str(SETTINGS)?;
+        super::apply(&mut api, "Petstore")?;
+        assert!(api.paths["/phone/settings"].item()?.patch.is_some());
+        Ok(())
+    }
+}
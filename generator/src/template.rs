@@ -14,8 +14,49 @@ pub struct Template {
     components: Vec<Component>,
 }
 
+/// How an array query parameter is sent, from its OpenAPI `style` and `explode`.
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub enum ArrayStyle {
+    /// `key=a&key=b`, the default for `form`.
+    Explode,
+    /// `key=a,b`, for `form` without `explode`.
+    Comma,
+    /// `key=a%20b`, for `spaceDelimited`.
+    Space,
+    /// `key=a|b`, for `pipeDelimited`.
+    Pipe,
+}
+
+impl ArrayStyle {
+    pub fn new(style: &openapiv3::QueryStyle, explode: Option<bool>) -> Self {
+        // Only `form` explodes unless the parameter says otherwise.
+        match (style, explode) {
+            (_, Some(true)) | (openapiv3::QueryStyle::Form, None) => ArrayStyle::Explode,
+            (openapiv3::QueryStyle::Form, Some(false)) => ArrayStyle::Comma,
+            (openapiv3::QueryStyle::SpaceDelimited, _) => ArrayStyle::Space,
+            (openapiv3::QueryStyle::PipeDelimited, _) => ArrayStyle::Pipe,
+            (openapiv3::QueryStyle::DeepObject, _) => ArrayStyle::Explode,
+        }
+    }
+
+    /// What the values are joined with, or `None` if each is sent on its own.
+    fn separator(self) -> Option<&'static str> {
+        match self {
+            ArrayStyle::Explode => None,
+            ArrayStyle::Comma => Some(","),
+            ArrayStyle::Space => Some(" "),
+            ArrayStyle::Pipe => Some("|"),
+        }
+    }
+}
+
 impl Template {
-    pub fn compile(&self, query_params: BTreeMap<String, (String, String)>) -> String {
+    pub fn compile(
+        &self,
+        query_params: BTreeMap<String, (String, String, ArrayStyle)>,
+        fn_params: &[String],
+        proper_name: &str,
+    ) -> String {
         let mut out = String::new();
 
         let mut a = |s: &str| {
@@ -25,62 +66,96 @@ impl Template {
 
         if !query_params.is_empty() {
             // Format the query params if they exist.
-            a("let mut query_args: Vec<(String, String)> = Default::default();");
+            a("let mut query_args: Vec<(&str, String)> = Default::default();");
 
-            for (nam, (value, prop)) in &query_params {
+            for (nam, (value, prop, style)) in &query_params {
                 if value == "Option<chrono::DateTime<chrono::Utc>>" {
                     a(&format!(
-                        r#"if let Some(date) = {} {{ query_args.push(("{}".to_string(), date.to_rfc3339())); }}"#,
+                        r#"if let Some(date) = {} {{ query_args.push(("{}", date.to_rfc3339())); }}"#,
+                        nam, prop
+                    ));
+                } else if value == "Option<chrono::NaiveDate>" {
+                    a(&format!(
+                        r#"if let Some(date) = {} {{ query_args.push(("{}", date.to_string())); }}"#,
                         nam, prop
                     ));
                 } else if value == "Option<uuid::Uuid>" {
                     a(&format!(
-                        r#"if let Some(u) = {} {{ query_args.push(("{}".to_string(), u.to_string())); }}"#,
+                        r#"if let Some(u) = {} {{ query_args.push(("{}", u.to_string())); }}"#,
+                        nam, prop
+                    ));
+                } else if value.starts_with("Option<crate::types::") {
+                    a(&format!(
+                        r#"if let Some(v) = {} {{ query_args.push(("{}", v.to_string())); }}"#,
                         nam, prop
                     ));
                 } else if value == "uuid::Uuid" {
                     a(&format!(
-                        r#"if {}.to_string() != uuid::Uuid::nil().to_string() {{ query_args.push(("{}".to_string(), {}.to_string())); }}"#,
+                        r#"if {}.to_string() != uuid::Uuid::nil().to_string() {{ query_args.push(("{}", {}.to_string())); }}"#,
+                        nam, prop, nam
+                    ));
+                } else if value == "i64" && proper_name != "GitHub" && nam == "page_size" {
+                    // Fall back to the client's default page size, if any.
+                    a(&format!("let {} = self.client.page_size_or({});", nam, nam));
+                    a(&format!(
+                        r#"if {} > 0 {{ query_args.push(("{}", {}.to_string())); }}"#,
                         nam, prop, nam
                     ));
                 } else if value == "i64" || value == "i32" {
                     a(&format!(
-                        r#"if {} > 0 {{ query_args.push(("{}".to_string(), {}.to_string())); }}"#,
+                        r#"if {} > 0 {{ query_args.push(("{}", {}.to_string())); }}"#,
                         nam, prop, nam
                     ));
                 } else if value == "bool" && prop == "sendNotificationEmail" {
                     a(&format!(
-                        r#"query_args.push(("{}".to_string(), {}.to_string()));"#,
+                        r#"query_args.push(("{}", {}.to_string()));"#,
                         prop, nam
                     ));
                 } else if value == "bool" {
                     a(&format!(
-                        r#"if {} {{ query_args.push(("{}".to_string(), {}.to_string())); }}"#,
+                        r#"if {} {{ query_args.push(("{}", {}.to_string())); }}"#,
                         nam, prop, nam
                     ));
-                } else if value == "&str" {
+                } else if value == "&str" && proper_name == "Zoom" && prop == "site_id" {
+                    // Fall back to the site the client is scoped to, if any.
+                    a(&format!("let {} = self.client.site_id_or({});", nam, nam));
                     a(&format!(
-                        r#"if !{}.is_empty() {{ query_args.push(("{}".to_string(), {}.to_string())); }}"#,
+                        r#"if !{}.is_empty() {{ query_args.push(("{}", {}.to_string())); }}"#,
                         nam, prop, nam
                     ));
-                } else if value == "&[String]" {
-                    // TODO: I have no idea how these should be seperated and the docs
-                    // don't give any answers either, for an array sent through query
-                    // params.
-                    // https://docs.github.com/en/rest/reference/migrations
+                } else if value == "&str" {
                     a(&format!(
-                        r#"if !{}.is_empty() {{ query_args.push(("{}".to_string(), {}.join(" "))); }}"#,
+                        r#"if !{}.is_empty() {{ query_args.push(("{}", {}.to_string())); }}"#,
                         nam, prop, nam
                     ));
+                } else if proper_name == "Zoom"
+                    && (prop == "time_type" || prop == "query_date_type")
+                    && value.starts_with("crate::types::")
+                {
+                    // Zoom takes these as their documented default when they are
+                    // left out, so only send them when they are something else.
+                    a(&format!(
+                        r#"if {} != {}::default() {{ query_args.push(("{}", {}.to_string())); }}"#,
+                        nam, value, prop, nam
+                    ));
+                } else if value == "&[String]" {
+                    match style.separator() {
+                        None => a(&format!(
+                            r#"for v in {} {{ query_args.push(("{}", v.to_string())); }}"#,
+                            nam, prop
+                        )),
+                        Some(separator) => a(&format!(
+                            r#"if !{}.is_empty() {{ query_args.push(("{}", {}.join("{}"))); }}"#,
+                            nam, prop, nam, separator
+                        )),
+                    }
                 } else {
                     a(&format!(
-                        r#"if !{}.to_string().is_empty() {{  query_args.push(("{}".to_string(), {}.to_string())); }}"#,
+                        r#"if !{}.to_string().is_empty() {{  query_args.push(("{}", {}.to_string())); }}"#,
                         nam, prop, nam
                     ));
                 }
             }
-
-            a("let query_ = serde_urlencoded::to_string(&query_args).unwrap();");
         }
 
         a("let url =");
@@ -101,7 +176,13 @@ impl Template {
             }
         }
 
-        if !has_params && query_params.is_empty() {
+        // The query, if any, is joined on by the client, which leaves out the
+        // parameters that were not set.
+        if !query_params.is_empty() {
+            out.push_str("crate::Client::url_with_query(");
+        }
+
+        if !has_params {
             out.push('"');
             for c in self.components.iter() {
                 out.push('/');
@@ -110,11 +191,18 @@ impl Template {
                     Component::Parameter(_) => (),
                 }
             }
-            out.push_str("\".to_string();");
+            if query_params.is_empty() {
+                out.push_str("\".to_string();");
+            } else {
+                out.push_str("\", &query_args);");
+            }
 
             return out.to_string();
         }
 
+        if !query_params.is_empty() {
+            out.push('&');
+        }
         out.push_str("format!(\"");
         for c in self.components.iter() {
             out.push('/');
@@ -126,41 +214,72 @@ impl Template {
             }
         }
 
-        if !query_params.is_empty() {
-            out.push_str("?{}");
-        }
-
         out.push_str("\",\n");
         for c in self.components.iter() {
             if let Component::Parameter(n) = &c {
-                if n == "type"
-                    || n == "ref"
-                    || n == "foo"
-                    || n == "enum"
-                    || n == "const"
-                    || n == "use"
-                {
+                if proper_name.starts_with("Google") && is_google_resource_name(n) {
                     out.push_str(&format!(
-                        "crate::progenitor_support::encode_path(&{}_.to_string()),",
-                        to_snake_case(n)
+                        "crate::progenitor_support::encode_resource_name(&{}.to_string()),",
+                        param_var(n)
                     ));
-                } else {
+                } else if proper_name == "GitHub" {
                     out.push_str(&format!(
                         "crate::progenitor_support::encode_path(&{}.to_string()),",
-                        to_snake_case(n)
+                        param_var(n)
+                    ));
+                } else if fn_params.contains(&format!("{}: &str,", param_var(n))) {
+                    // The client reuses the encodings of the ids it is given
+                    // over and over, so it looks them up as they are.
+                    out.push_str(&format!("self.client.encode_path({}),", param_var(n)));
+                } else {
+                    out.push_str(&format!(
+                        "self.client.encode_path(&{}.to_string()),",
+                        param_var(n)
                     ));
                 }
             }
         }
 
+        out.push(')');
         if !query_params.is_empty() {
-            out.push_str("query_");
+            out.push_str(", &query_args)");
         }
 
-        out.push_str(");\n");
+        out.push_str(";\n");
 
         out
     }
+
+    /// The parameters of the path with their values, as the generated functions
+    /// hold them, for `crate::utils::pretty_url`.
+    pub fn args(&self) -> String {
+        let args: Vec<String> = self
+            .components
+            .iter()
+            .filter_map(|c| match c {
+                Component::Parameter(n) => {
+                    Some(format!(r#"("{}", {}.to_string())"#, n, param_var(n)))
+                }
+                Component::Constant(_) => None,
+            })
+            .collect();
+        format!("[{}]", args.join(", "))
+    }
+}
+
+/// The variable a path parameter is held in, which can not be a keyword.
+fn param_var(n: &str) -> String {
+    if n == "type" || n == "ref" || n == "foo" || n == "enum" || n == "const" || n == "use" {
+        format!("{}_", to_snake_case(n))
+    } else {
+        to_snake_case(n)
+    }
+}
+
+/// Google passes resource names, like `folders/123`, as a single parameter
+/// that spans several path segments.
+fn is_google_resource_name(n: &str) -> bool {
+    n == "name" || n == "parent" || n == "resource"
 }
 
 pub fn parse(t: &str) -> Result<Template> {
@@ -248,7 +367,7 @@ fn parse_inner(t: &str) -> Result<Template> {
 mod test {
     use anyhow::{anyhow, Context, Result};
 
-    use super::{parse, Component, Template};
+    use super::{parse, ArrayStyle, Component, Template};
 
     #[test]
     fn basic() -> Result<()> {
@@ -291,13 +410,107 @@ mod test {
     #[test]
     fn compile() -> Result<()> {
         let t = parse("/measure/{number}")?;
-        let out = t.compile(Default::default());
+        let out = t.compile(Default::default(), &[], "Zoom");
         let want = "let url =
 format!(\"/measure/{}\",
-crate::progenitor_support::encode_path(&number.to_string()),);\n";
+self.client.encode_path(&number.to_string()),);\n";
         assert_eq!(want, &out);
         Ok(())
     }
+
+    #[test]
+    fn compile_encodes_every_parameter() -> Result<()> {
+        let t = parse("/accounts/{accountId}/{type}/{ref}/items")?;
+        let mut query_params = std::collections::BTreeMap::new();
+        query_params.insert(
+            "page_size".to_string(),
+            (
+                "i64".to_string(),
+                "page_size".to_string(),
+                ArrayStyle::Explode,
+            ),
+        );
+        let fn_params = vec![
+            "account_id: &str,".to_string(),
+            "type_: i64,".to_string(),
+            "ref_: &str,".to_string(),
+            "page_size: i64,".to_string(),
+        ];
+        let out = t.compile(query_params, &fn_params, "Zoom");
+        // The strings are looked up in the client's cache as they are.
+        let want = "let mut query_args: Vec<(&str, String)> = Default::default();
+let page_size = self.client.page_size_or(page_size);
+if page_size > 0 { query_args.push((\"page_size\", page_size.to_string())); }
+let url =
+crate::Client::url_with_query(&format!(\"/accounts/{}/{}/{}/items\",
+self.client.encode_path(account_id),\
+self.client.encode_path(&type_.to_string()),\
+self.client.encode_path(ref_),), &query_args);\n";
+        assert_eq!(want, &out);
+        Ok(())
+    }
+
+    #[test]
+    fn args() -> Result<()> {
+        let t = parse("/accounts/{accountId}/{type}/items")?;
+        assert_eq!(
+            t.args(),
+            r#"[("accountId", account_id.to_string()), ("type", type_.to_string())]"#
+        );
+        assert_eq!(parse("/phone/users")?.args(), "[]");
+        Ok(())
+    }
+
+    #[test]
+    fn compile_zoom_site_id() -> Result<()> {
+        let t = parse("/phone/users")?;
+        let mut query_params = std::collections::BTreeMap::new();
+        query_params.insert(
+            "site_id".to_string(),
+            (
+                "&str".to_string(),
+                "site_id".to_string(),
+                ArrayStyle::Explode,
+            ),
+        );
+        let out = t.compile(query_params.clone(), &[], "Zoom");
+        assert!(out.contains("let site_id = self.client.site_id_or(site_id);\n"));
+
+        let out = t.compile(query_params, &[], "Slack");
+        assert!(!out.contains("site_id_or"));
+        Ok(())
+    }
+
+    #[test]
+    fn compile_optional_date() -> Result<()> {
+        let t = parse("/im/users/{userId}/chat/messages")?;
+        let mut query_params = std::collections::BTreeMap::new();
+        query_params.insert(
+            "date".to_string(),
+            (
+                "Option<chrono::NaiveDate>".to_string(),
+                "date".to_string(),
+                ArrayStyle::Explode,
+            ),
+        );
+        // The date is only sent if there is one.
+        let out = t.compile(query_params, &[], "Zoom");
+        assert!(out.contains(
+            "if let Some(date) = date { query_args.push((\"date\", date.to_string())); }\n"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn compile_google_resource_name() -> Result<()> {
+        let t = parse("/v2/{name}:move")?;
+        let out = t.compile(Default::default(), &[], "Google Cloud Resource Manager");
+        assert!(out.contains("crate::progenitor_support::encode_resource_name(&name.to_string())"));
+
+        let out = t.compile(Default::default(), &[], "Zoom");
+        assert!(out.contains("self.client.encode_path(&name.to_string())"));
+        Ok(())
+    }
 }
 
 pub fn generate_docs_github(
@@ -1,9 +1,42 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use anyhow::{bail, Result};
 use inflector::cases::snakecase::to_snake_case;
 
-use crate::{render_param, struct_name, TypeDetails, TypeSpace};
+use crate::{clean_name, render_param, struct_name, TypeDetails, TypeSpace};
+
+/*
+ * Money the specs type as plain strings, by API, struct and property. These
+ * are read as decimals, like fields with `format: decimal`, so they keep
+ * their precision. The specs are downloaded as they are, so this is where
+ * they are marked.
+ */
+const DECIMAL_FIELDS: &[(&str, &str, &[&str])] = &[
+    (
+        "DocuSign",
+        "BillingInvoice",
+        &["amount", "balance", "nonTaxableAmount", "taxableAmount"],
+    ),
+    ("DocuSign", "BillingInvoiceItem", &["chargeAmount", "unitPrice"]),
+    ("DocuSign", "BillingInvoicesSummary", &["pastDueBalance"]),
+    ("DocuSign", "BillingPayment", &["amount"]),
+    ("DocuSign", "BillingPaymentItem", &["amount"]),
+    (
+        "DocuSign",
+        "Invoices",
+        &["amount", "balance", "nonTaxableAmount", "taxableAmount"],
+    ),
+    ("DocuSign", "Payments", &["amount"]),
+];
+
+/*
+ * Whether the property of the struct is money listed in `DECIMAL_FIELDS`.
+ */
+fn is_decimal_field(proper_name: &str, sn: &str, property: &str) -> bool {
+    DECIMAL_FIELDS
+        .iter()
+        .any(|(p, s, f)| *p == proper_name && *s == sn && f.contains(&property))
+}
 
 /*
  * Declare named types we know about:
@@ -20,6 +53,9 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
     a("    use schemars::JsonSchema;");
     a("    use serde::{Serialize, Deserialize};");
     a("");
+    if proper_name != "GitHub" {
+        a(ONE_OR_MANY_TEMPLATE);
+    }
 
     for te in ts.clone().id_to_entry.values() {
         if let Some(sn) = te.name.as_deref() {
@@ -40,8 +76,9 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                     );
                     a(&p);
                 }
-                TypeDetails::OneOf(omap, _) => a(&do_of_type(ts, omap, sn)),
-                TypeDetails::AnyOf(omap, _) => a(&do_all_of_type(ts, omap, sn)),
+                TypeDetails::OneOf(omap, schema_data) | TypeDetails::AnyOf(omap, schema_data) => a(
+                    &do_of_type(ts, omap, sn, schema_data.discriminator.as_ref()),
+                ),
                 TypeDetails::AllOf(omap, _) => a(&do_all_of_type(ts, omap, sn)),
                 TypeDetails::Object(omap, schema_data) => {
                     /*
@@ -99,36 +136,29 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                              JsonSchema)]",
                         );
                     }
+                    // If this is a page of a list, find the property holding the items
+                    // so we can iterate over the struct itself.
+                    let collection = crate::functions::get_paginated_property(ts, omap)?
+                        .filter(|(_, _, rt)| rt.starts_with("Vec<"))
+                        .map(|(n, _, _)| n);
+                    let mut collection_field: Option<(String, String)> = None;
+                    let mut has_total_records = false;
+
+                    // Rename the fields all at once if we can, rather than one by one.
+                    let rename_all = renames_to_camel_case(omap.keys());
+                    if rename_all {
+                        a(r#"#[serde(rename_all = "camelCase")]"#);
+                    }
+
                     a(&format!("pub struct {} {{", sn));
                     for (name, tid) in omap.iter() {
                         if let Ok(mut rt) = ts.render_type(tid, true) {
-                            let mut prop = name.trim().to_string();
+                            let prop = struct_field_name(name);
                             if prop == "next" {
                                 rt = "String".to_string();
                             }
-                            if prop == "ref"
-                                || prop == "type"
-                                || prop == "self"
-                                || prop == "box"
-                                || prop == "match"
-                                || prop == "foo"
-                                || prop == "enum"
-                                || prop == "const"
-                                || prop == "use"
-                            {
-                                prop = format!("{}_", name);
-                            } else if name == "$ref" {
-                                prop = format!("{}_", name.replace('$', ""));
-                            } else if name == "$type" {
-                                prop = format!("{}__", name.replace('$', ""));
-                            } else if name == "+1" {
-                                prop = "plus_one".to_string()
-                            } else if name == "-1" {
-                                prop = "minus_one".to_string()
-                            } else if name.starts_with('@') {
-                                prop = name.trim_start_matches('@').to_string();
-                            } else if name.starts_with('_') {
-                                prop = name.trim_start_matches('_').to_string();
+                            if rt == "String" && is_decimal_field(proper_name, &sn, name) {
+                                rt = "Option<rust_decimal::Decimal>".to_string();
                             }
 
                             // Try to render the docs.
@@ -161,6 +191,9 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                                 } else if rt.starts_with("Option<url::Url") {
                                     a(r#"skip_serializing_if = "Option::is_none",
                                       deserialize_with = "crate::utils::deserialize_empty_url::deserialize","#);
+                                } else if rt.starts_with("Option<rust_decimal::Decimal") {
+                                    a(r#"skip_serializing_if = "Option::is_none",
+                                      deserialize_with = "crate::utils::deserialize_decimal::deserialize","#);
                                 } else if rt.starts_with("Option<chrono::NaiveDate") {
                                     a(r#"skip_serializing_if = "Option::is_none",
                                       deserialize_with = "crate::utils::date_format::deserialize","#);
@@ -227,28 +260,10 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                                 a(r#"#[serde("#);
                             }
 
-                            if !prop.ends_with('_') {
-                                prop = to_snake_case(&prop);
-                            }
-
-                            // DO this again.
-                            // I know this is shit sue me, but sometimes we change the prop
-                            // so much it becomes one of these, ie. in the case of shipbob.
-                            if prop == "ref"
-                                || prop == "type"
-                                || prop == "self"
-                                || prop == "box"
-                                || prop == "match"
-                                || prop == "foo"
-                                || prop == "enum"
-                                || prop == "const"
-                                || prop == "use"
-                            {
-                                prop = format!("{}_", prop);
-                            }
-
-                            // Close the serde string.
-                            if *name != prop {
+                            // Close the serde string. With `rename_all`, only the fields
+                            // named after a keyword need their own rename.
+                            let renamed_by_struct = rename_all && !prop.ends_with('_');
+                            if *name != prop && !renamed_by_struct {
                                 a(&format!(r#"rename = "{}")]"#, name));
                             } else if rt == "Page" && prop == "page" || rt.ends_with("Page") {
                                 a(r#"default)]"#);
@@ -260,6 +275,13 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                                 println!("{} {}", sn, prop);
                             }
 
+                            if collection.as_deref() == Some(name.as_str()) {
+                                collection_field = Some((prop.to_string(), rt.to_string()));
+                            }
+                            if prop == "total_records" && rt == "i64" {
+                                has_total_records = true;
+                            }
+
                             a(&format!("pub {}: {},", prop, rt));
                         } else {
                             bail!("rendering type {} {:?} failed", name, tid);
@@ -267,12 +289,17 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                     }
                     a("}");
                     a("");
+
+                    if let Some((prop, rt)) = collection_field {
+                        a(&render_into_iterator(&sn, &prop, &rt, has_total_records));
+                    }
                 }
                 TypeDetails::Basic(..) => {}
                 TypeDetails::Unknown => {}
                 TypeDetails::NamedType(..) => {}
                 TypeDetails::Array(..) => {}
                 TypeDetails::Optional(..) => {}
+                TypeDetails::OneOrMany(..) => {}
             }
         }
     }
@@ -280,7 +307,332 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
     Ok(out.to_string())
 }
 
-fn do_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> String {
+/*
+ * The name of the struct field for the property `name`.
+ */
+pub(crate) fn struct_field_name(name: &str) -> String {
+    let mut prop = name.trim().to_string();
+    if prop == "ref"
+        || prop == "type"
+        || prop == "self"
+        || prop == "box"
+        || prop == "match"
+        || prop == "foo"
+        || prop == "enum"
+        || prop == "const"
+        || prop == "use"
+    {
+        prop = format!("{}_", name);
+    } else if name == "$ref" {
+        prop = format!("{}_", name.replace('$', ""));
+    } else if name == "$type" {
+        prop = format!("{}__", name.replace('$', ""));
+    } else if name == "+1" {
+        prop = "plus_one".to_string()
+    } else if name == "-1" {
+        prop = "minus_one".to_string()
+    } else if name.starts_with('@') {
+        prop = name.trim_start_matches('@').to_string();
+    } else if name.starts_with('_') {
+        prop = name.trim_start_matches('_').to_string();
+    }
+
+    if !prop.ends_with('_') {
+        prop = to_snake_case(&prop);
+    }
+
+    // DO this again.
+    // I know this is shit sue me, but sometimes we change the prop
+    // so much it becomes one of these, ie. in the case of shipbob.
+    if prop == "ref"
+        || prop == "type"
+        || prop == "self"
+        || prop == "box"
+        || prop == "match"
+        || prop == "foo"
+        || prop == "enum"
+        || prop == "const"
+        || prop == "use"
+    {
+        prop = format!("{}_", prop);
+    }
+
+    prop
+}
+
+/*
+ * Whether serde's `rename_all = "camelCase"` gives every field of a struct the
+ * name of its property, and renames at least one of them. Fields named after a
+ * keyword, like `type_`, are left out, they keep their own rename.
+ */
+fn renames_to_camel_case<'a, I>(names: I) -> bool
+where
+    I: IntoIterator<Item = &'a String>,
+{
+    let mut renamed = false;
+    for name in names {
+        let prop = struct_field_name(name);
+        if prop.ends_with('_') {
+            continue;
+        }
+        if to_serde_camel_case(&prop) != *name {
+            return false;
+        }
+        renamed |= prop != *name;
+    }
+    renamed
+}
+
+/*
+ * The name serde's `rename_all = "camelCase"` gives a field.
+ */
+fn to_serde_camel_case(field: &str) -> String {
+    let mut pascal = String::new();
+    let mut capitalize = true;
+    for c in field.chars() {
+        if c == '_' {
+            capitalize = true;
+        } else if capitalize {
+            pascal.push(c.to_ascii_uppercase());
+            capitalize = false;
+        } else {
+            pascal.push(c);
+        }
+    }
+
+    match pascal.chars().next() {
+        Some(first) => first.to_ascii_lowercase().to_string() + &pascal[first.len_utf8()..],
+        None => pascal,
+    }
+}
+
+/*
+ * Implement `IntoIterator` for a page of a list, so iterating over the struct
+ * iterates over the items in `prop`. If the page counts the records in the
+ * whole list, add `total` and `remaining` too.
+ */
+fn render_into_iterator(sn: &str, prop: &str, rt: &str, has_total_records: bool) -> String {
+    let item = &rt["Vec<".len()..rt.len() - 1];
+
+    let mut total = String::new();
+    if has_total_records {
+        total = format!(
+            r#"
+
+    /// The number of `{}` in the whole list, across all of its pages.
+    pub fn total(&self) -> i64 {{
+        self.total_records
+    }}
+
+    /// The number of `{}` left in the list once `seen` of them have been
+    /// read, say from a page or a stream, to report progress.
+    pub fn remaining(&self, seen: usize) -> i64 {{
+        (self.total_records - seen as i64).max(0)
+    }}"#,
+            prop, prop
+        );
+    }
+
+    format!(
+        r#"impl IntoIterator for {} {{
+    type Item = {};
+    type IntoIter = std::vec::IntoIter<{}>;
+
+    fn into_iter(self) -> Self::IntoIter {{
+        self.{}.into_iter()
+    }}
+}}
+
+impl<'a> IntoIterator for &'a {} {{
+    type Item = &'a {};
+    type IntoIter = std::slice::Iter<'a, {}>;
+
+    fn into_iter(self) -> Self::IntoIter {{
+        self.{}.iter()
+    }}
+}}
+
+impl {} {{
+    /// Iterate over the `{}`.
+    pub fn iter(&self) -> std::slice::Iter<'_, {}> {{
+        self.{}.iter()
+    }}{}
+}}
+"#,
+        sn, item, item, prop, sn, item, item, prop, sn, prop, item, prop, total
+    )
+}
+
+/*
+ * Generate a module for each tag that re-exports the types its functions take
+ * and return, so the types for `phone` can be found in `types::phone`.
+ */
+pub fn generate_tag_modules(files: &BTreeMap<String, String>) -> String {
+    let mut out = String::new();
+
+    let mut a = |s: &str| {
+        out.push_str(s);
+        out.push('\n');
+    };
+
+    for (tag, content) in files {
+        let mut names: BTreeSet<&str> = Default::default();
+        for (i, _) in content.match_indices("crate::types::") {
+            let rest = &content[i + "crate::types::".len()..];
+            let end = rest
+                .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+                .unwrap_or(rest.len());
+            if end > 0 {
+                names.insert(&rest[..end]);
+            }
+        }
+
+        if names.is_empty() {
+            continue;
+        }
+
+        let m = to_snake_case(&clean_name(tag));
+        a(&format!(
+            "/// The types taken and returned by the functions in [`crate::{}`].",
+            m
+        ));
+        a(&format!("pub mod {} {{", m));
+        a(&format!(
+            "    pub use super::{{{}}};",
+            names.into_iter().collect::<Vec<&str>>().join(", ")
+        ));
+        a("}");
+        a("");
+    }
+
+    out
+}
+
+/// Generate a test for each request body type with an example in the spec,
+/// which checks the example comes back out of it under the same names. This
+/// catches a field that serde renames differently than the spec names it.
+pub fn generate_serde_tests(examples: &BTreeMap<String, serde_json::Value>) -> String {
+    let mut tests = String::new();
+    for (rt, example) in examples {
+        // We can only name plain types in a test, not vectors and the like.
+        let name = match rt.strip_prefix("crate::types::") {
+            Some(name) if name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') => name,
+            _ => continue,
+        };
+
+        tests.push_str(&format!(
+            r#"
+    #[test]
+    fn {}_round_trip() {{
+        round_trip::<crate::types::{}>(serde_json::json!({}));
+    }}
+"#,
+            to_snake_case(name),
+            name,
+            example
+        ));
+    }
+
+    if tests.is_empty() {
+        return String::new();
+    }
+
+    let mut out = String::new();
+
+    let mut a = |s: &str| {
+        out.push_str(s);
+        out.push('\n');
+    };
+
+    a("#[cfg(test)]");
+    a("mod serde_tests {");
+    a(
+        r#"    /// Deserialize the example into `T` and serialize it again, checking every
+    /// field the example sets comes back with the same name and value.
+    fn round_trip<T>(example: serde_json::Value)
+    where
+        T: serde::de::DeserializeOwned + serde::Serialize,
+    {
+        let value: T = serde_json::from_value(example.clone()).unwrap();
+        let out = serde_json::to_value(&value).unwrap();
+        assert_contains(&out, &example, "");
+    }
+
+    fn assert_contains(out: &serde_json::Value, example: &serde_json::Value, path: &str) {
+        match example {
+            serde_json::Value::Object(fields) => {
+                for (k, v) in fields {
+                    let path = format!("{}.{}", path, k);
+                    match out.get(k) {
+                        Some(o) => assert_contains(o, v, &path),
+                        // Empty fields are skipped when we serialize.
+                        None => assert!(is_empty(v), "{} is missing", path),
+                    }
+                }
+            }
+            serde_json::Value::Array(items) => {
+                let out = out.as_array().unwrap();
+                assert_eq!(out.len(), items.len(), "{} has the wrong length", path);
+                for (i, (o, v)) in out.iter().zip(items).enumerate() {
+                    assert_contains(o, v, &format!("{}[{}]", path, i));
+                }
+            }
+            v => assert_eq!(out, v, "{} has the wrong value", path),
+        }
+    }
+
+    fn is_empty(v: &serde_json::Value) -> bool {
+        match v {
+            serde_json::Value::Null => true,
+            serde_json::Value::Bool(b) => !b,
+            serde_json::Value::Number(n) => n.as_f64() == Some(0.0),
+            serde_json::Value::String(s) => s.is_empty(),
+            serde_json::Value::Array(a) => a.is_empty(),
+            serde_json::Value::Object(o) => o.values().all(is_empty),
+        }
+    }"#,
+    );
+
+    a(tests.trim_end());
+    a("}");
+
+    out
+}
+
+const ONE_OR_MANY_TEMPLATE: &str = r#"/// Either one of something, or many of them. Some endpoints send back an
+/// object when there is one and an array when there are more, so this takes both.
+// Many is tried first: serde reads a struct from an array of its fields too,
+// so an array would otherwise be taken for One.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum OneOrMany<T> {
+    Many(Vec<T>),
+    One(T),
+}
+
+impl<T> OneOrMany<T> {
+    /// All of them, as a vector.
+    pub fn into_vec(self) -> Vec<T> {
+        match self {
+            OneOrMany::One(t) => vec![t],
+            OneOrMany::Many(v) => v,
+        }
+    }
+}
+
+impl<T> Default for OneOrMany<T> {
+    fn default() -> Self {
+        OneOrMany::Many(Vec::new())
+    }
+}
+"#;
+
+fn do_of_type(
+    ts: &mut TypeSpace,
+    omap: &[crate::TypeId],
+    sn: String,
+    discriminator: Option<&openapiv3::Discriminator>,
+) -> String {
     let mut out = String::new();
 
     let mut a = |s: &str| {
@@ -320,8 +672,18 @@ fn do_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> String
     description = format!("/// {}", description.replace('\n', "\n/// "));
     a(&description);
 
-    a("#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]");
-    if !flatten {
+    if let Some(d) = discriminator {
+        // The property says which type a value is, rather than us trying each
+        // type in turn until one parses. The variants are types of their own
+        // too, which keep their field for it, so the tag is written and read
+        // by the impls below rather than by serde, which would write it twice.
+        // The attributes are still what the schema of the enum goes by.
+        a("#[derive(PartialEq, Debug, Clone, JsonSchema)]");
+        a(&format!(r#"#[serde(tag = "{}")]"#, d.property_name));
+    } else {
+        a("#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]");
+    }
+    if discriminator.is_none() && !flatten {
         a("#[serde(untagged)]");
     }
     a(&format!("pub enum {} {{", sn));
@@ -330,6 +692,8 @@ fn do_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> String
     // sometimes parse the wrong value. It's better to instead use the functions we
     // inject that force the value to a specific type.
     let mut fns: Vec<String> = Default::default();
+    // The tag of each variant, if the enum is tagged.
+    let mut tags: Vec<(String, String)> = Default::default();
     for tid in omap.iter() {
         let name = ts.render_type(tid, true).unwrap();
 
@@ -355,6 +719,16 @@ fn do_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> String
                 a("*/");
             }
 
+            if let Some(d) = discriminator {
+                let value = discriminator_value(ts, tid, d);
+                if let Some(value) = &value {
+                    a(&format!(r#"#[serde(rename = "{}")]"#, value));
+                }
+                tags.push((
+                    fn_name.to_string(),
+                    value.unwrap_or_else(|| fn_name.to_string()),
+                ));
+            }
             a(&format!("{}({}),", fn_name, name));
             name_map.insert(fn_name.to_string(), name.to_string());
             fns.push(fn_name);
@@ -363,6 +737,10 @@ fn do_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> String
     a("}");
     a("");
 
+    if let Some(d) = discriminator {
+        a(&tagged_impls(&sn, &d.property_name, &tags));
+    }
+
     // Render the implementation to easily unpack these things for the end user.
     a(&format!("impl {} {{", sn));
     for (fn_name, name) in &name_map {
@@ -466,6 +844,94 @@ fn do_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> String
     out
 }
 
+/// The impls of `Serialize` and `Deserialize` for an enum tagged by the
+/// property, with the tag of each variant. The tag is written over whatever
+/// the variant has in its own field for it, and is left in what the variant
+/// reads, for that field.
+fn tagged_impls(sn: &str, property: &str, tags: &[(String, String)]) -> String {
+    let serialize = tags
+        .iter()
+        .map(|(fn_name, tag)| {
+            format!(
+                r#"{}::{}(v) => crate::utils::serialize_tagged(v, "{}", "{}", serializer),"#,
+                sn, fn_name, property, tag
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let deserialize = tags
+        .iter()
+        .map(|(fn_name, tag)| {
+            format!(
+                r#""{}" => serde_json::from_value(value).map({}::{}),"#,
+                tag, sn, fn_name
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let expected = tags
+        .iter()
+        .map(|(_, tag)| format!(r#""{}""#, tag))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!(
+        r#"impl Serialize for {} {{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{
+        match self {{
+            {}
+        }}
+    }}
+}}
+
+impl<'de> Deserialize<'de> for {} {{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{
+        let (tag, value) = crate::utils::deserialize_tagged(deserializer, "{}")?;
+        match tag.as_str() {{
+            {}
+            _ => return Err(serde::de::Error::unknown_variant(&tag, &[{}])),
+        }}
+        .map_err(serde::de::Error::custom)
+    }}
+}}
+"#,
+        sn, serialize, sn, property, deserialize, expected
+    )
+}
+
+/// The value of the discriminator property that says a value is of the type,
+/// from the discriminator's mapping or else the one value the type allows for
+/// the property. With neither, it is the name of the variant, which is the
+/// name of the schema, as OpenAPI does.
+fn discriminator_value(
+    ts: &TypeSpace,
+    tid: &crate::TypeId,
+    discriminator: &openapiv3::Discriminator,
+) -> Option<String> {
+    let name = ts.render_type(tid, true).ok()?;
+    for (value, schema) in &discriminator.mapping {
+        let schema = schema.rsplit('/').next().unwrap_or_default();
+        if struct_name(&clean_name(schema)) == name {
+            return Some(value.to_string());
+        }
+    }
+
+    let props = match &ts.id_to_entry.get(tid)?.details {
+        TypeDetails::Object(props, _) => props,
+        _ => return None,
+    };
+    let mut prop = ts
+        .id_to_entry
+        .get(props.get(&discriminator.property_name)?)?;
+    if let TypeDetails::Optional(itid, _) = &prop.details {
+        prop = ts.id_to_entry.get(itid)?;
+    }
+    match &prop.details {
+        TypeDetails::Enum(vals, _) if vals.len() == 1 => Some(vals[0].to_string()),
+        _ => None,
+    }
+}
+
 fn do_all_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> String {
     let mut out = String::new();
 
@@ -532,3 +998,60 @@ fn do_all_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> Str
 
     out
 }
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+
+    use super::{generate_serde_tests, renames_to_camel_case};
+
+    #[test]
+    fn serde_tests_custom_field() {
+        let mut examples = BTreeMap::new();
+        examples.insert(
+            "crate::types::CustomField".to_string(),
+            serde_json::json!({"fieldId": "1", "name": "Region", "show": "true"}),
+        );
+        // Only plain types get a test.
+        examples.insert(
+            "Vec<crate::types::CustomField>".to_string(),
+            serde_json::json!([{"name": "Region"}]),
+        );
+
+        let out = generate_serde_tests(&examples);
+        assert!(out.starts_with("#[cfg(test)]\nmod serde_tests {\n"));
+        assert!(out.contains(
+            r#"    #[test]
+    fn custom_field_round_trip() {
+        round_trip::<crate::types::CustomField>(serde_json::json!({"fieldId":"1","name":"Region","show":"true"}));
+    }"#
+        ));
+        assert_eq!(out.matches("#[test]").count(), 1);
+
+        // Without a test there is no module.
+        assert_eq!(generate_serde_tests(&BTreeMap::new()), "");
+    }
+
+    #[test]
+    fn rename_all_camel_case() {
+        let names = |n: &[&str]| n.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+
+        // Like DocuSign's `AccountBrands`, and the keyword keeps its own rename.
+        assert!(renames_to_camel_case(&names(&[
+            "recipientBrandIdDefault",
+            "senderBrandIdDefault",
+            "id",
+            "type",
+        ])));
+
+        // Like its `ProofServiceResourceToken`, serde would send `proofBaseUri`.
+        assert!(!renames_to_camel_case(&names(&[
+            "proofBaseURI",
+            "resourceToken"
+        ])));
+        // Snake case properties are renamed one by one.
+        assert!(!renames_to_camel_case(&names(&["groupId", "page_size"])));
+        // Nothing to rename.
+        assert!(!renames_to_camel_case(&names(&["id", "name", "type"])));
+    }
+}
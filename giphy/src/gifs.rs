@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Gifs {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Gifs {
         Gifs { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Gifs {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get GIFs by ID.
      *
@@ -25,14 +31,16 @@ impl Gifs {
      * * `ids: &str` -- Filters results by specified GIF IDs, separated by commas.
      */
     pub async fn get(&self, ids: &str) -> Result<crate::types::GetGifsByResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/gifs?{}", query_);
+        let url = crate::Client::url_with_query("/gifs", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/gifs", &[]))
     }
 
     /**
@@ -49,17 +57,19 @@ impl Gifs {
      * * `rating: &str` -- The unique bit.ly URL for this GIF.
      */
     pub async fn random(&self, tag: &str, rating: &str) -> Result<crate::types::RandomGifResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !rating.is_empty() {
-            query_args.push(("rating".to_string(), rating.to_string()));
+            query_args.push(("rating", rating.to_string()));
         }
         if !tag.is_empty() {
-            query_args.push(("tag".to_string(), tag.to_string()));
+            query_args.push(("tag", tag.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/gifs/random?{}", query_);
+        let url = crate::Client::url_with_query("/gifs/random", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/gifs/random", &[]))
     }
 
     /**
@@ -86,26 +96,28 @@ impl Gifs {
         rating: &str,
         lang: &str,
     ) -> Result<crate::types::GetGifsByResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !lang.is_empty() {
-            query_args.push(("lang".to_string(), lang.to_string()));
+            query_args.push(("lang", lang.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if !rating.is_empty() {
-            query_args.push(("rating".to_string(), rating.to_string()));
+            query_args.push(("rating", rating.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/gifs/search?{}", query_);
+        let url = crate::Client::url_with_query("/gifs/search", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/gifs/search", &[]))
     }
 
     /**
@@ -121,14 +133,16 @@ impl Gifs {
      * * `s: &str` -- The unique bit.ly URL for this GIF.
      */
     pub async fn translate(&self, s: &str) -> Result<crate::types::RandomGifResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !s.is_empty() {
-            query_args.push(("s".to_string(), s.to_string()));
+            query_args.push(("s", s.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/gifs/translate?{}", query_);
+        let url = crate::Client::url_with_query("/gifs/translate", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/gifs/translate", &[]))
     }
 
     /**
@@ -151,20 +165,22 @@ impl Gifs {
         offset: i64,
         rating: &str,
     ) -> Result<crate::types::GetGifsByResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !rating.is_empty() {
-            query_args.push(("rating".to_string(), rating.to_string()));
+            query_args.push(("rating", rating.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/gifs/trending?{}", query_);
+        let url = crate::Client::url_with_query("/gifs/trending", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/gifs/trending", &[]))
     }
 
     /**
@@ -180,11 +196,71 @@ impl Gifs {
      * * `gif_id: i64` -- Filters results by specified GIF ID.
      */
     pub async fn get_gifs(&self, gif_id: i64) -> Result<crate::types::RandomGifResponse> {
-        let url = format!(
-            "/gifs/{}",
-            crate::progenitor_support::encode_path(&gif_id.to_string()),
-        );
+        let url = format!("/gifs/{}", self.client.encode_path(&gif_id.to_string()),);
+
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/gifs/{gifId}", &[("gifId", gif_id.to_string())])
+        })
+    }
+}
+
+/// The functions of [`Gifs`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn GifsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait GifsApi: Send + Sync {
+    async fn get(&self, ids: &str) -> Result<crate::types::GetGifsByResponse>;
+    async fn random(&self, tag: &str, rating: &str) -> Result<crate::types::RandomGifResponse>;
+    async fn search(
+        &self,
+        q: &str,
+        limit: i64,
+        offset: i64,
+        rating: &str,
+        lang: &str,
+    ) -> Result<crate::types::GetGifsByResponse>;
+    async fn translate(&self, s: &str) -> Result<crate::types::RandomGifResponse>;
+    async fn trending(
+        &self,
+        limit: i64,
+        offset: i64,
+        rating: &str,
+    ) -> Result<crate::types::GetGifsByResponse>;
+    async fn get_gifs(&self, gif_id: i64) -> Result<crate::types::RandomGifResponse>;
+}
 
-        self.client.get(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl GifsApi for Gifs {
+    async fn get(&self, ids: &str) -> Result<crate::types::GetGifsByResponse> {
+        Gifs::get(self, ids).await
+    }
+    async fn random(&self, tag: &str, rating: &str) -> Result<crate::types::RandomGifResponse> {
+        Gifs::random(self, tag, rating).await
+    }
+    async fn search(
+        &self,
+        q: &str,
+        limit: i64,
+        offset: i64,
+        rating: &str,
+        lang: &str,
+    ) -> Result<crate::types::GetGifsByResponse> {
+        Gifs::search(self, q, limit, offset, rating, lang).await
+    }
+    async fn translate(&self, s: &str) -> Result<crate::types::RandomGifResponse> {
+        Gifs::translate(self, s).await
+    }
+    async fn trending(
+        &self,
+        limit: i64,
+        offset: i64,
+        rating: &str,
+    ) -> Result<crate::types::GetGifsByResponse> {
+        Gifs::trending(self, limit, offset, rating).await
+    }
+    async fn get_gifs(&self, gif_id: i64) -> Result<crate::types::RandomGifResponse> {
+        Gifs::get_gifs(self, gif_id).await
     }
 }
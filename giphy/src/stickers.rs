@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Stickers {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Stickers {
         Stickers { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Stickers {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Random Sticker.
      *
@@ -26,17 +32,19 @@ impl Stickers {
      * * `rating: &str` -- The unique bit.ly URL for this GIF.
      */
     pub async fn random(&self, tag: &str, rating: &str) -> Result<crate::types::RandomGifResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !rating.is_empty() {
-            query_args.push(("rating".to_string(), rating.to_string()));
+            query_args.push(("rating", rating.to_string()));
         }
         if !tag.is_empty() {
-            query_args.push(("tag".to_string(), tag.to_string()));
+            query_args.push(("tag", tag.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/stickers/random?{}", query_);
+        let url = crate::Client::url_with_query("/stickers/random", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/stickers/random", &[]))
     }
 
     /**
@@ -63,26 +71,28 @@ impl Stickers {
         rating: &str,
         lang: &str,
     ) -> Result<crate::types::GetGifsByResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !lang.is_empty() {
-            query_args.push(("lang".to_string(), lang.to_string()));
+            query_args.push(("lang", lang.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if !rating.is_empty() {
-            query_args.push(("rating".to_string(), rating.to_string()));
+            query_args.push(("rating", rating.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/stickers/search?{}", query_);
+        let url = crate::Client::url_with_query("/stickers/search", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/stickers/search", &[]))
     }
 
     /**
@@ -98,14 +108,16 @@ impl Stickers {
      * * `s: &str` -- The unique bit.ly URL for this GIF.
      */
     pub async fn translate(&self, s: &str) -> Result<crate::types::RandomGifResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !s.is_empty() {
-            query_args.push(("s".to_string(), s.to_string()));
+            query_args.push(("s", s.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/stickers/translate?{}", query_);
+        let url = crate::Client::url_with_query("/stickers/translate", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/stickers/translate", &[]))
     }
 
     /**
@@ -128,19 +140,74 @@ impl Stickers {
         offset: i64,
         rating: &str,
     ) -> Result<crate::types::GetGifsByResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !rating.is_empty() {
-            query_args.push(("rating".to_string(), rating.to_string()));
+            query_args.push(("rating", rating.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/stickers/trending?{}", query_);
+        let url = crate::Client::url_with_query("/stickers/trending", &query_args);
+
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/stickers/trending", &[]))
+    }
+}
+
+/// The functions of [`Stickers`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn StickersApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait StickersApi: Send + Sync {
+    async fn random(&self, tag: &str, rating: &str) -> Result<crate::types::RandomGifResponse>;
+    async fn search(
+        &self,
+        q: &str,
+        limit: i64,
+        offset: i64,
+        rating: &str,
+        lang: &str,
+    ) -> Result<crate::types::GetGifsByResponse>;
+    async fn translate(&self, s: &str) -> Result<crate::types::RandomGifResponse>;
+    async fn trending(
+        &self,
+        limit: i64,
+        offset: i64,
+        rating: &str,
+    ) -> Result<crate::types::GetGifsByResponse>;
+}
 
-        self.client.get(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl StickersApi for Stickers {
+    async fn random(&self, tag: &str, rating: &str) -> Result<crate::types::RandomGifResponse> {
+        Stickers::random(self, tag, rating).await
+    }
+    async fn search(
+        &self,
+        q: &str,
+        limit: i64,
+        offset: i64,
+        rating: &str,
+        lang: &str,
+    ) -> Result<crate::types::GetGifsByResponse> {
+        Stickers::search(self, q, limit, offset, rating, lang).await
+    }
+    async fn translate(&self, s: &str) -> Result<crate::types::RandomGifResponse> {
+        Stickers::translate(self, s).await
+    }
+    async fn trending(
+        &self,
+        limit: i64,
+        offset: i64,
+        rating: &str,
+    ) -> Result<crate::types::GetGifsByResponse> {
+        Stickers::trending(self, limit, offset, rating).await
     }
 }
@@ -2,6 +2,33 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+/// Either one of something, or many of them. Some endpoints send back an
+/// object when there is one and an array when there are more, so this takes both.
+// Many is tried first: serde reads a struct from an array of its fields too,
+// so an array would otherwise be taken for One.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum OneOrMany<T> {
+    Many(Vec<T>),
+    One(T),
+}
+
+impl<T> OneOrMany<T> {
+    /// All of them, as a vector.
+    pub fn into_vec(self) -> Vec<T> {
+        match self {
+            OneOrMany::One(t) => vec![t],
+            OneOrMany::Many(v) => v,
+        }
+    }
+}
+
+impl<T> Default for OneOrMany<T> {
+    fn default() -> Self {
+        OneOrMany::Many(Vec::new())
+    }
+}
+
 /// All of the following types are flattened into one object:
 ///
 /// - `Image`
@@ -125,7 +152,7 @@ pub struct Images {
 /**
  * Type of the gif. By default, this is almost always gif
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Type {
     #[serde(rename = "gif")]
     Gif,
@@ -149,6 +176,21 @@ impl Default for Type {
     }
 }
 
+impl Type {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Type::Gif => "gif",
+            Type::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Type] {
+        &[Type::Gif]
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Gif {
     /**
@@ -572,3 +614,13 @@ pub struct RandomGifResponse {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub meta: Option<Meta>,
 }
+
+/// The types taken and returned by the functions in [`crate::gifs`].
+pub mod gifs {
+    pub use super::{GetGifsByResponse, RandomGifResponse};
+}
+
+/// The types taken and returned by the functions in [`crate::stickers`].
+pub mod stickers {
+    pub use super::{GetGifsByResponse, RandomGifResponse};
+}
@@ -95,18 +95,19 @@ impl Actions {
         page: i64,
     ) -> Result<crate::types::ActionsListSelectedRepositoriesEnabledGithubOrganizationResponse>
     {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/actions/permissions/repositories?{}",
-            crate::progenitor_support::encode_path(&org.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/orgs/{}/actions/permissions/repositories",
+                crate::progenitor_support::encode_path(&org.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -288,18 +289,19 @@ impl Actions {
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::ActionsListSelfHostedRunnerGroupsOrgResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/actions/runner-groups?{}",
-            crate::progenitor_support::encode_path(&org.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/orgs/{}/actions/runner-groups",
+                crate::progenitor_support::encode_path(&org.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -463,19 +465,20 @@ impl Actions {
         page: i64,
         per_page: i64,
     ) -> Result<crate::types::ActionsListRepoAccessSelfHostedRunnerGroupInOrgResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/actions/runner-groups/{}/repositories?{}",
-            crate::progenitor_support::encode_path(&org.to_string()),
-            crate::progenitor_support::encode_path(&runner_group_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/orgs/{}/actions/runner-groups/{}/repositories",
+                crate::progenitor_support::encode_path(&org.to_string()),
+                crate::progenitor_support::encode_path(&runner_group_id.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -616,19 +619,20 @@ impl Actions {
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::ActionsListSelfHostedRunnersInGroupOrgResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/actions/runner-groups/{}/runners?{}",
-            crate::progenitor_support::encode_path(&org.to_string()),
-            crate::progenitor_support::encode_path(&runner_group_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/orgs/{}/actions/runner-groups/{}/runners",
+                crate::progenitor_support::encode_path(&org.to_string()),
+                crate::progenitor_support::encode_path(&runner_group_id.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -765,18 +769,19 @@ impl Actions {
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::ActionsListSelfHostedRunnersOrgResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/actions/runners?{}",
-            crate::progenitor_support::encode_path(&org.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/orgs/{}/actions/runners",
+                crate::progenitor_support::encode_path(&org.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -986,18 +991,19 @@ impl Actions {
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::ActionsListOrgSecretsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/actions/secrets?{}",
-            crate::progenitor_support::encode_path(&org.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/orgs/{}/actions/secrets",
+                crate::progenitor_support::encode_path(&org.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1205,19 +1211,20 @@ impl Actions {
         page: i64,
         per_page: i64,
     ) -> Result<crate::types::ActionsListSelectedReposOrgSecretResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/actions/secrets/{}/repositories?{}",
-            crate::progenitor_support::encode_path(&org.to_string()),
-            crate::progenitor_support::encode_path(&secret_name.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/orgs/{}/actions/secrets/{}/repositories",
+                crate::progenitor_support::encode_path(&org.to_string()),
+                crate::progenitor_support::encode_path(&secret_name.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1339,19 +1346,20 @@ impl Actions {
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::ActionsListArtifactsRepoResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/actions/artifacts?{}",
-            crate::progenitor_support::encode_path(&owner.to_string()),
-            crate::progenitor_support::encode_path(&repo.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/repos/{}/{}/actions/artifacts",
+                crate::progenitor_support::encode_path(&owner.to_string()),
+                crate::progenitor_support::encode_path(&repo.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1672,19 +1680,20 @@ impl Actions {
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::ActionsListSelfHostedRunnersOrgResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/actions/runners?{}",
-            crate::progenitor_support::encode_path(&owner.to_string()),
-            crate::progenitor_support::encode_path(&repo.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/repos/{}/{}/actions/runners",
+                crate::progenitor_support::encode_path(&owner.to_string()),
+                crate::progenitor_support::encode_path(&repo.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1924,34 +1933,35 @@ impl Actions {
         page: i64,
         created: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<crate::types::ActionsListWorkflowRunsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !actor.is_empty() {
-            query_args.push(("actor".to_string(), actor.to_string()));
+            query_args.push(("actor", actor.to_string()));
         }
         if !branch.is_empty() {
-            query_args.push(("branch".to_string(), branch.to_string()));
+            query_args.push(("branch", branch.to_string()));
         }
         if let Some(date) = created {
-            query_args.push(("created".to_string(), date.to_rfc3339()));
+            query_args.push(("created", date.to_rfc3339()));
         }
         if !event.is_empty() {
-            query_args.push(("event".to_string(), event.to_string()));
+            query_args.push(("event", event.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/actions/runs?{}",
-            crate::progenitor_support::encode_path(&owner.to_string()),
-            crate::progenitor_support::encode_path(&repo.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/repos/{}/{}/actions/runs",
+                crate::progenitor_support::encode_path(&owner.to_string()),
+                crate::progenitor_support::encode_path(&repo.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2127,20 +2137,21 @@ impl Actions {
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::ActionsListArtifactsRepoResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/actions/runs/{}/artifacts?{}",
-            crate::progenitor_support::encode_path(&owner.to_string()),
-            crate::progenitor_support::encode_path(&repo.to_string()),
-            crate::progenitor_support::encode_path(&run_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/repos/{}/{}/actions/runs/{}/artifacts",
+                crate::progenitor_support::encode_path(&owner.to_string()),
+                crate::progenitor_support::encode_path(&repo.to_string()),
+                crate::progenitor_support::encode_path(&run_id.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2201,23 +2212,24 @@ impl Actions {
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::ActionsListJobsWorkflowRunResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !filter.to_string().is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/actions/runs/{}/jobs?{}",
-            crate::progenitor_support::encode_path(&owner.to_string()),
-            crate::progenitor_support::encode_path(&repo.to_string()),
-            crate::progenitor_support::encode_path(&run_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/repos/{}/{}/actions/runs/{}/jobs",
+                crate::progenitor_support::encode_path(&owner.to_string()),
+                crate::progenitor_support::encode_path(&repo.to_string()),
+                crate::progenitor_support::encode_path(&run_id.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2468,19 +2480,20 @@ impl Actions {
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::ActionsListRepoSecretsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/actions/secrets?{}",
-            crate::progenitor_support::encode_path(&owner.to_string()),
-            crate::progenitor_support::encode_path(&repo.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/repos/{}/{}/actions/secrets",
+                crate::progenitor_support::encode_path(&owner.to_string()),
+                crate::progenitor_support::encode_path(&repo.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2707,19 +2720,20 @@ impl Actions {
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::ActionsListRepoWorkflowsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/actions/workflows?{}",
-            crate::progenitor_support::encode_path(&owner.to_string()),
-            crate::progenitor_support::encode_path(&repo.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/repos/{}/{}/actions/workflows",
+                crate::progenitor_support::encode_path(&owner.to_string()),
+                crate::progenitor_support::encode_path(&repo.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2887,35 +2901,36 @@ impl Actions {
         page: i64,
         created: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<crate::types::ActionsListWorkflowRunsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !actor.is_empty() {
-            query_args.push(("actor".to_string(), actor.to_string()));
+            query_args.push(("actor", actor.to_string()));
         }
         if !branch.is_empty() {
-            query_args.push(("branch".to_string(), branch.to_string()));
+            query_args.push(("branch", branch.to_string()));
         }
         if let Some(date) = created {
-            query_args.push(("created".to_string(), date.to_rfc3339()));
+            query_args.push(("created", date.to_rfc3339()));
         }
         if !event.is_empty() {
-            query_args.push(("event".to_string(), event.to_string()));
+            query_args.push(("event", event.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/actions/workflows/{}/runs?{}",
-            crate::progenitor_support::encode_path(&owner.to_string()),
-            crate::progenitor_support::encode_path(&repo.to_string()),
-            crate::progenitor_support::encode_path(&workflow_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/repos/{}/{}/actions/workflows/{}/runs",
+                crate::progenitor_support::encode_path(&owner.to_string()),
+                crate::progenitor_support::encode_path(&repo.to_string()),
+                crate::progenitor_support::encode_path(&workflow_id.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -2977,19 +2992,20 @@ impl Actions {
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::ActionsListRepoSecretsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repositories/{}/environments/{}/secrets?{}",
-            crate::progenitor_support::encode_path(&repository_id.to_string()),
-            crate::progenitor_support::encode_path(&environment_name.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/repositories/{}/environments/{}/secrets",
+                crate::progenitor_support::encode_path(&repository_id.to_string()),
+                crate::progenitor_support::encode_path(&environment_name.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -31,15 +31,14 @@ impl Activity {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Event>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/events?{}", query_);
+        let url = crate::Client::url_with_query("/events", &query_args);
 
         self.client.get(&url, None).await
     }
@@ -107,19 +106,20 @@ impl Activity {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Event>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/networks/{}/{}/events?{}",
-            crate::progenitor_support::encode_path(&owner.to_string()),
-            crate::progenitor_support::encode_path(&repo.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/networks/{}/{}/events",
+                crate::progenitor_support::encode_path(&owner.to_string()),
+                crate::progenitor_support::encode_path(&repo.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -177,27 +177,26 @@ impl Activity {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Thread>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if all {
-            query_args.push(("all".to_string(), all.to_string()));
+            query_args.push(("all", all.to_string()));
         }
         if let Some(date) = before {
-            query_args.push(("before".to_string(), date.to_rfc3339()));
+            query_args.push(("before", date.to_rfc3339()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if participating {
-            query_args.push(("participating".to_string(), participating.to_string()));
+            query_args.push(("participating", participating.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/notifications?{}", query_);
+        let url = crate::Client::url_with_query("/notifications", &query_args);
 
         self.client.get(&url, None).await
     }
@@ -220,21 +219,20 @@ impl Activity {
         since: Option<chrono::DateTime<chrono::Utc>>,
         before: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<Vec<crate::types::Thread>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if all {
-            query_args.push(("all".to_string(), all.to_string()));
+            query_args.push(("all", all.to_string()));
         }
         if let Some(date) = before {
-            query_args.push(("before".to_string(), date.to_rfc3339()));
+            query_args.push(("before", date.to_rfc3339()));
         }
         if participating {
-            query_args.push(("participating".to_string(), participating.to_string()));
+            query_args.push(("participating", participating.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/notifications?{}", query_);
+        let url = crate::Client::url_with_query("/notifications", &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -404,18 +402,19 @@ impl Activity {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Event>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/events?{}",
-            crate::progenitor_support::encode_path(&org.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/orgs/{}/events",
+                crate::progenitor_support::encode_path(&org.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -464,19 +463,20 @@ impl Activity {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Event>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/events?{}",
-            crate::progenitor_support::encode_path(&owner.to_string()),
-            crate::progenitor_support::encode_path(&repo.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/repos/{}/{}/events",
+                crate::progenitor_support::encode_path(&owner.to_string()),
+                crate::progenitor_support::encode_path(&repo.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -538,31 +538,32 @@ impl Activity {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Thread>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if all {
-            query_args.push(("all".to_string(), all.to_string()));
+            query_args.push(("all", all.to_string()));
         }
         if let Some(date) = before {
-            query_args.push(("before".to_string(), date.to_rfc3339()));
+            query_args.push(("before", date.to_rfc3339()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if participating {
-            query_args.push(("participating".to_string(), participating.to_string()));
+            query_args.push(("participating", participating.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/notifications?{}",
-            crate::progenitor_support::encode_path(&owner.to_string()),
-            crate::progenitor_support::encode_path(&repo.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/repos/{}/{}/notifications",
+                crate::progenitor_support::encode_path(&owner.to_string()),
+                crate::progenitor_support::encode_path(&repo.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -588,25 +589,26 @@ impl Activity {
         since: Option<chrono::DateTime<chrono::Utc>>,
         before: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<Vec<crate::types::Thread>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if all {
-            query_args.push(("all".to_string(), all.to_string()));
+            query_args.push(("all", all.to_string()));
         }
         if let Some(date) = before {
-            query_args.push(("before".to_string(), date.to_rfc3339()));
+            query_args.push(("before", date.to_rfc3339()));
         }
         if participating {
-            query_args.push(("participating".to_string(), participating.to_string()));
+            query_args.push(("participating", participating.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/notifications?{}",
-            crate::progenitor_support::encode_path(&owner.to_string()),
-            crate::progenitor_support::encode_path(&repo.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/repos/{}/{}/notifications",
+                crate::progenitor_support::encode_path(&owner.to_string()),
+                crate::progenitor_support::encode_path(&repo.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -668,19 +670,20 @@ impl Activity {
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::ActivityListStargazersRepoResponseAnyOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/stargazers?{}",
-            crate::progenitor_support::encode_path(&owner.to_string()),
-            crate::progenitor_support::encode_path(&repo.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/repos/{}/{}/stargazers",
+                crate::progenitor_support::encode_path(&owner.to_string()),
+                crate::progenitor_support::encode_path(&repo.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -709,19 +712,20 @@ impl Activity {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::SimpleUser>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/subscribers?{}",
-            crate::progenitor_support::encode_path(&owner.to_string()),
-            crate::progenitor_support::encode_path(&repo.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/repos/{}/{}/subscribers",
+                crate::progenitor_support::encode_path(&owner.to_string()),
+                crate::progenitor_support::encode_path(&repo.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -862,21 +866,20 @@ impl Activity {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Repository>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/user/starred?{}", query_);
+        let url = crate::Client::url_with_query("/user/starred", &query_args);
 
         self.client.get(&url, None).await
     }
@@ -899,15 +902,14 @@ impl Activity {
         sort: crate::types::Sort,
         direction: crate::types::Order,
     ) -> Result<Vec<crate::types::Repository>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/user/starred?{}", query_);
+        let url = crate::Client::url_with_query("/user/starred", &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -1007,15 +1009,14 @@ impl Activity {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/user/subscriptions?{}", query_);
+        let url = crate::Client::url_with_query("/user/subscriptions", &query_args);
 
         self.client.get(&url, None).await
     }
@@ -1059,18 +1060,19 @@ impl Activity {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Event>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/events?{}",
-            crate::progenitor_support::encode_path(&username.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/users/{}/events",
+                crate::progenitor_support::encode_path(&username.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1122,19 +1124,20 @@ impl Activity {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Event>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/events/orgs/{}?{}",
-            crate::progenitor_support::encode_path(&username.to_string()),
-            crate::progenitor_support::encode_path(&org.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/users/{}/events/orgs/{}",
+                crate::progenitor_support::encode_path(&username.to_string()),
+                crate::progenitor_support::encode_path(&org.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1186,18 +1189,19 @@ impl Activity {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Event>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/events/public?{}",
-            crate::progenitor_support::encode_path(&username.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/users/{}/events/public",
+                crate::progenitor_support::encode_path(&username.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1247,18 +1251,19 @@ impl Activity {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Event>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/received_events?{}",
-            crate::progenitor_support::encode_path(&username.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/users/{}/received_events",
+                crate::progenitor_support::encode_path(&username.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1308,18 +1313,19 @@ impl Activity {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Event>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/received_events/public?{}",
-            crate::progenitor_support::encode_path(&username.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/users/{}/received_events/public",
+                crate::progenitor_support::encode_path(&username.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1377,24 +1383,25 @@ impl Activity {
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::ActivityListReposStarredByUserResponseAnyOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/starred?{}",
-            crate::progenitor_support::encode_path(&username.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/users/{}/starred",
+                crate::progenitor_support::encode_path(&username.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1421,18 +1428,19 @@ impl Activity {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/subscriptions?{}",
-            crate::progenitor_support::encode_path(&username.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/users/{}/subscriptions",
+                crate::progenitor_support::encode_path(&username.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -111,15 +111,14 @@ impl Apps {
         per_page: i64,
         cursor: &str,
     ) -> Result<Vec<crate::types::HookDeliveryItem>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/app/hook/deliveries?{}", query_);
+        let url = crate::Client::url_with_query("/app/hook/deliveries", &query_args);
 
         self.client.get(&url, None).await
     }
@@ -141,12 +140,11 @@ impl Apps {
         &self,
         cursor: &str,
     ) -> Result<Vec<crate::types::HookDeliveryItem>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/app/hook/deliveries?{}", query_);
+        let url = crate::Client::url_with_query("/app/hook/deliveries", &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -227,21 +225,20 @@ impl Apps {
         since: Option<chrono::DateTime<chrono::Utc>>,
         outdated: &str,
     ) -> Result<Vec<crate::types::Installation>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !outdated.is_empty() {
-            query_args.push(("outdated".to_string(), outdated.to_string()));
+            query_args.push(("outdated", outdated.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/app/installations?{}", query_);
+        let url = crate::Client::url_with_query("/app/installations", &query_args);
 
         self.client.get(&url, None).await
     }
@@ -264,15 +261,14 @@ impl Apps {
         since: Option<chrono::DateTime<chrono::Utc>>,
         outdated: &str,
     ) -> Result<Vec<crate::types::Installation>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !outdated.is_empty() {
-            query_args.push(("outdated".to_string(), outdated.to_string()));
+            query_args.push(("outdated", outdated.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/app/installations?{}", query_);
+        let url = crate::Client::url_with_query("/app/installations", &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -720,15 +716,14 @@ impl Apps {
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::AppsListInstallationReposResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/installation/repositories?{}", query_);
+        let url = crate::Client::url_with_query("/installation/repositories", &query_args);
 
         self.client.get(&url, None).await
     }
@@ -799,15 +794,14 @@ impl Apps {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::MarketplaceListingPlan>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/marketplace_listing/plans?{}", query_);
+        let url = crate::Client::url_with_query("/marketplace_listing/plans", &query_args);
 
         self.client.get(&url, None).await
     }
@@ -859,24 +853,25 @@ impl Apps {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::MarketplacePurchaseData>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/marketplace_listing/plans/{}/accounts?{}",
-            crate::progenitor_support::encode_path(&plan_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/marketplace_listing/plans/{}/accounts",
+                crate::progenitor_support::encode_path(&plan_id.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -901,18 +896,19 @@ impl Apps {
         sort: crate::types::Sort,
         direction: crate::types::Order,
     ) -> Result<Vec<crate::types::MarketplacePurchaseData>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/marketplace_listing/plans/{}/accounts?{}",
-            crate::progenitor_support::encode_path(&plan_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/marketplace_listing/plans/{}/accounts",
+                crate::progenitor_support::encode_path(&plan_id.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -966,15 +962,14 @@ impl Apps {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::MarketplaceListingPlan>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/marketplace_listing/stubbed/plans?{}", query_);
+        let url = crate::Client::url_with_query("/marketplace_listing/stubbed/plans", &query_args);
 
         self.client.get(&url, None).await
     }
@@ -1028,24 +1023,25 @@ impl Apps {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::MarketplacePurchaseData>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/marketplace_listing/stubbed/plans/{}/accounts?{}",
-            crate::progenitor_support::encode_path(&plan_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/marketplace_listing/stubbed/plans/{}/accounts",
+                crate::progenitor_support::encode_path(&plan_id.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1070,18 +1066,19 @@ impl Apps {
         sort: crate::types::Sort,
         direction: crate::types::Order,
     ) -> Result<Vec<crate::types::MarketplacePurchaseData>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !direction.to_string().is_empty() {
-            query_args.push(("direction".to_string(), direction.to_string()));
+            query_args.push(("direction", direction.to_string()));
         }
         if !sort.to_string().is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/marketplace_listing/stubbed/plans/{}/accounts?{}",
-            crate::progenitor_support::encode_path(&plan_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/marketplace_listing/stubbed/plans/{}/accounts",
+                crate::progenitor_support::encode_path(&plan_id.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -1204,15 +1201,14 @@ impl Apps {
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::AppsListInstallationsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/user/installations?{}", query_);
+        let url = crate::Client::url_with_query("/user/installations", &query_args);
 
         self.client.get(&url, None).await
     }
@@ -1244,18 +1240,19 @@ impl Apps {
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::AppsListInstallationReposResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/user/installations/{}/repositories?{}",
-            crate::progenitor_support::encode_path(&installation_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/user/installations/{}/repositories",
+                crate::progenitor_support::encode_path(&installation_id.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1340,15 +1337,14 @@ impl Apps {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::UserMarketplacePurchase>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/user/marketplace_purchases?{}", query_);
+        let url = crate::Client::url_with_query("/user/marketplace_purchases", &query_args);
 
         self.client.get(&url, None).await
     }
@@ -1390,15 +1386,14 @@ impl Apps {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::UserMarketplacePurchase>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/user/marketplace_purchases/stubbed?{}", query_);
+        let url = crate::Client::url_with_query("/user/marketplace_purchases/stubbed", &query_args);
 
         self.client.get(&url, None).await
     }
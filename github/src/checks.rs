@@ -141,20 +141,21 @@ impl Checks {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::CheckAnnotation>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/check-runs/{}/annotations?{}",
-            crate::progenitor_support::encode_path(&owner.to_string()),
-            crate::progenitor_support::encode_path(&repo.to_string()),
-            crate::progenitor_support::encode_path(&check_run_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/repos/{}/{}/check-runs/{}/annotations",
+                crate::progenitor_support::encode_path(&owner.to_string()),
+                crate::progenitor_support::encode_path(&repo.to_string()),
+                crate::progenitor_support::encode_path(&check_run_id.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -319,29 +320,30 @@ impl Checks {
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::ChecksListRefResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !check_name.is_empty() {
-            query_args.push(("check_name".to_string(), check_name.to_string()));
+            query_args.push(("check_name", check_name.to_string()));
         }
         if !filter.to_string().is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/check-suites/{}/check-runs?{}",
-            crate::progenitor_support::encode_path(&owner.to_string()),
-            crate::progenitor_support::encode_path(&repo.to_string()),
-            crate::progenitor_support::encode_path(&check_suite_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/repos/{}/{}/check-suites/{}/check-runs",
+                crate::progenitor_support::encode_path(&owner.to_string()),
+                crate::progenitor_support::encode_path(&repo.to_string()),
+                crate::progenitor_support::encode_path(&check_suite_id.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -417,32 +419,33 @@ impl Checks {
         page: i64,
         app_id: i64,
     ) -> Result<crate::types::ChecksListRefResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if app_id > 0 {
-            query_args.push(("app_id".to_string(), app_id.to_string()));
+            query_args.push(("app_id", app_id.to_string()));
         }
         if !check_name.is_empty() {
-            query_args.push(("check_name".to_string(), check_name.to_string()));
+            query_args.push(("check_name", check_name.to_string()));
         }
         if !filter.to_string().is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/commits/{}/check-runs?{}",
-            crate::progenitor_support::encode_path(&owner.to_string()),
-            crate::progenitor_support::encode_path(&repo.to_string()),
-            crate::progenitor_support::encode_path(&ref_.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/repos/{}/{}/commits/{}/check-runs",
+                crate::progenitor_support::encode_path(&owner.to_string()),
+                crate::progenitor_support::encode_path(&repo.to_string()),
+                crate::progenitor_support::encode_path(&ref_.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -479,26 +482,27 @@ impl Checks {
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::ChecksListSuitesRefResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if app_id > 0 {
-            query_args.push(("app_id".to_string(), app_id.to_string()));
+            query_args.push(("app_id", app_id.to_string()));
         }
         if !check_name.is_empty() {
-            query_args.push(("check_name".to_string(), check_name.to_string()));
+            query_args.push(("check_name", check_name.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/commits/{}/check-suites?{}",
-            crate::progenitor_support::encode_path(&owner.to_string()),
-            crate::progenitor_support::encode_path(&repo.to_string()),
-            crate::progenitor_support::encode_path(&ref_.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/repos/{}/{}/commits/{}/check-suites",
+                crate::progenitor_support::encode_path(&owner.to_string()),
+                crate::progenitor_support::encode_path(&repo.to_string()),
+                crate::progenitor_support::encode_path(&ref_.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
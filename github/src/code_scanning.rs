@@ -51,31 +51,32 @@ impl CodeScanning {
         ref_: &str,
         state: crate::types::CodeScanningAlertState,
     ) -> Result<Vec<crate::types::CodeScanningAlertItems>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !ref_.is_empty() {
-            query_args.push(("ref".to_string(), ref_.to_string()));
+            query_args.push(("ref", ref_.to_string()));
         }
         if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+            query_args.push(("state", state.to_string()));
         }
         if !tool_guid.is_empty() {
-            query_args.push(("tool_guid".to_string(), tool_guid.to_string()));
+            query_args.push(("tool_guid", tool_guid.to_string()));
         }
         if !tool_name.is_empty() {
-            query_args.push(("tool_name".to_string(), tool_name.to_string()));
+            query_args.push(("tool_name", tool_name.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/code-scanning/alerts?{}",
-            crate::progenitor_support::encode_path(&owner.to_string()),
-            crate::progenitor_support::encode_path(&repo.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/repos/{}/{}/code-scanning/alerts",
+                crate::progenitor_support::encode_path(&owner.to_string()),
+                crate::progenitor_support::encode_path(&repo.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -109,25 +110,26 @@ impl CodeScanning {
         ref_: &str,
         state: crate::types::CodeScanningAlertState,
     ) -> Result<Vec<crate::types::CodeScanningAlertItems>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ref_.is_empty() {
-            query_args.push(("ref".to_string(), ref_.to_string()));
+            query_args.push(("ref", ref_.to_string()));
         }
         if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+            query_args.push(("state", state.to_string()));
         }
         if !tool_guid.is_empty() {
-            query_args.push(("tool_guid".to_string(), tool_guid.to_string()));
+            query_args.push(("tool_guid", tool_guid.to_string()));
         }
         if !tool_name.is_empty() {
-            query_args.push(("tool_name".to_string(), tool_name.to_string()));
+            query_args.push(("tool_name", tool_name.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/code-scanning/alerts?{}",
-            crate::progenitor_support::encode_path(&owner.to_string()),
-            crate::progenitor_support::encode_path(&repo.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/repos/{}/{}/code-scanning/alerts",
+                crate::progenitor_support::encode_path(&owner.to_string()),
+                crate::progenitor_support::encode_path(&repo.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -228,23 +230,24 @@ impl CodeScanning {
         per_page: i64,
         ref_: &str,
     ) -> Result<Vec<crate::types::CodeScanningAlertInstance>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !ref_.is_empty() {
-            query_args.push(("ref".to_string(), ref_.to_string()));
+            query_args.push(("ref", ref_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/code-scanning/alerts/{}/instances?{}",
-            crate::progenitor_support::encode_path(&owner.to_string()),
-            crate::progenitor_support::encode_path(&repo.to_string()),
-            crate::progenitor_support::encode_path(&alert_number.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/repos/{}/{}/code-scanning/alerts/{}/instances",
+                crate::progenitor_support::encode_path(&owner.to_string()),
+                crate::progenitor_support::encode_path(&repo.to_string()),
+                crate::progenitor_support::encode_path(&alert_number.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -268,17 +271,18 @@ impl CodeScanning {
         alert_number: i64,
         ref_: &str,
     ) -> Result<Vec<crate::types::CodeScanningAlertInstance>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ref_.is_empty() {
-            query_args.push(("ref".to_string(), ref_.to_string()));
+            query_args.push(("ref", ref_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/code-scanning/alerts/{}/instances?{}",
-            crate::progenitor_support::encode_path(&owner.to_string()),
-            crate::progenitor_support::encode_path(&repo.to_string()),
-            crate::progenitor_support::encode_path(&alert_number.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/repos/{}/{}/code-scanning/alerts/{}/instances",
+                crate::progenitor_support::encode_path(&owner.to_string()),
+                crate::progenitor_support::encode_path(&repo.to_string()),
+                crate::progenitor_support::encode_path(&alert_number.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -330,31 +334,32 @@ impl CodeScanning {
         ref_: &str,
         sarif_id: &str,
     ) -> Result<Vec<crate::types::CodeScanningAnalysis>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !ref_.is_empty() {
-            query_args.push(("ref".to_string(), ref_.to_string()));
+            query_args.push(("ref", ref_.to_string()));
         }
         if !sarif_id.is_empty() {
-            query_args.push(("sarif_id".to_string(), sarif_id.to_string()));
+            query_args.push(("sarif_id", sarif_id.to_string()));
         }
         if !tool_guid.is_empty() {
-            query_args.push(("tool_guid".to_string(), tool_guid.to_string()));
+            query_args.push(("tool_guid", tool_guid.to_string()));
         }
         if !tool_name.is_empty() {
-            query_args.push(("tool_name".to_string(), tool_name.to_string()));
+            query_args.push(("tool_name", tool_name.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/code-scanning/analyses?{}",
-            crate::progenitor_support::encode_path(&owner.to_string()),
-            crate::progenitor_support::encode_path(&repo.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/repos/{}/{}/code-scanning/analyses",
+                crate::progenitor_support::encode_path(&owner.to_string()),
+                crate::progenitor_support::encode_path(&repo.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -395,25 +400,26 @@ impl CodeScanning {
         ref_: &str,
         sarif_id: &str,
     ) -> Result<Vec<crate::types::CodeScanningAnalysis>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ref_.is_empty() {
-            query_args.push(("ref".to_string(), ref_.to_string()));
+            query_args.push(("ref", ref_.to_string()));
         }
         if !sarif_id.is_empty() {
-            query_args.push(("sarif_id".to_string(), sarif_id.to_string()));
+            query_args.push(("sarif_id", sarif_id.to_string()));
         }
         if !tool_guid.is_empty() {
-            query_args.push(("tool_guid".to_string(), tool_guid.to_string()));
+            query_args.push(("tool_guid", tool_guid.to_string()));
         }
         if !tool_name.is_empty() {
-            query_args.push(("tool_name".to_string(), tool_name.to_string()));
+            query_args.push(("tool_name", tool_name.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/code-scanning/analyses?{}",
-            crate::progenitor_support::encode_path(&owner.to_string()),
-            crate::progenitor_support::encode_path(&repo.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/repos/{}/{}/code-scanning/analyses",
+                crate::progenitor_support::encode_path(&owner.to_string()),
+                crate::progenitor_support::encode_path(&repo.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -556,17 +562,18 @@ impl CodeScanning {
         analysis_id: i64,
         confirm_delete: &str,
     ) -> Result<crate::types::AnalysisDeletion> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !confirm_delete.is_empty() {
-            query_args.push(("confirm_delete".to_string(), confirm_delete.to_string()));
+            query_args.push(("confirm_delete", confirm_delete.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/code-scanning/analyses/{}?{}",
-            crate::progenitor_support::encode_path(&owner.to_string()),
-            crate::progenitor_support::encode_path(&repo.to_string()),
-            crate::progenitor_support::encode_path(&analysis_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/repos/{}/{}/code-scanning/analyses/{}",
+                crate::progenitor_support::encode_path(&owner.to_string()),
+                crate::progenitor_support::encode_path(&repo.to_string()),
+                crate::progenitor_support::encode_path(&analysis_id.to_string())
+            ),
+            &query_args,
         );
 
         self.client.delete(&url, None).await
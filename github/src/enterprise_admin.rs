@@ -92,18 +92,19 @@ impl EnterpriseAdmin {
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::EnterpriseAdminListOrgAccessSelfHostedRunnerGroupInResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/enterprises/{}/actions/permissions/organizations?{}",
-            crate::progenitor_support::encode_path(&enterprise.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/enterprises/{}/actions/permissions/organizations",
+                crate::progenitor_support::encode_path(&enterprise.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -279,18 +280,19 @@ impl EnterpriseAdmin {
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::EnterpriseAdminListSelfHostedRunnerGroupsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/enterprises/{}/actions/runner-groups?{}",
-            crate::progenitor_support::encode_path(&enterprise.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/enterprises/{}/actions/runner-groups",
+                crate::progenitor_support::encode_path(&enterprise.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -444,19 +446,20 @@ impl EnterpriseAdmin {
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::EnterpriseAdminListOrgAccessSelfHostedRunnerGroupInResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/enterprises/{}/actions/runner-groups/{}/organizations?{}",
-            crate::progenitor_support::encode_path(&enterprise.to_string()),
-            crate::progenitor_support::encode_path(&runner_group_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/enterprises/{}/actions/runner-groups/{}/organizations",
+                crate::progenitor_support::encode_path(&enterprise.to_string()),
+                crate::progenitor_support::encode_path(&runner_group_id.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -586,19 +589,20 @@ impl EnterpriseAdmin {
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::ActionsListSelfHostedRunnersInGroupOrgResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/enterprises/{}/actions/runner-groups/{}/runners?{}",
-            crate::progenitor_support::encode_path(&enterprise.to_string()),
-            crate::progenitor_support::encode_path(&runner_group_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/enterprises/{}/actions/runner-groups/{}/runners",
+                crate::progenitor_support::encode_path(&enterprise.to_string()),
+                crate::progenitor_support::encode_path(&runner_group_id.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -727,18 +731,19 @@ impl EnterpriseAdmin {
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::EnterpriseAdminListSelfHostedRunnersResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/enterprises/{}/actions/runners?{}",
-            crate::progenitor_support::encode_path(&enterprise.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/enterprises/{}/actions/runners",
+                crate::progenitor_support::encode_path(&enterprise.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -966,33 +971,34 @@ impl EnterpriseAdmin {
         page: i64,
         per_page: i64,
     ) -> Result<Vec<crate::types::AuditLogEvent>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if !before.is_empty() {
-            query_args.push(("before".to_string(), before.to_string()));
+            query_args.push(("before", before.to_string()));
         }
         if !include.to_string().is_empty() {
-            query_args.push(("include".to_string(), include.to_string()));
+            query_args.push(("include", include.to_string()));
         }
         if !order.to_string().is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !phrase.is_empty() {
-            query_args.push(("phrase".to_string(), phrase.to_string()));
+            query_args.push(("phrase", phrase.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/enterprises/{}/audit-log?{}",
-            crate::progenitor_support::encode_path(&enterprise.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/enterprises/{}/audit-log",
+                crate::progenitor_support::encode_path(&enterprise.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1018,27 +1024,28 @@ impl EnterpriseAdmin {
         before: &str,
         order: crate::types::Order,
     ) -> Result<Vec<crate::types::AuditLogEvent>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if !before.is_empty() {
-            query_args.push(("before".to_string(), before.to_string()));
+            query_args.push(("before", before.to_string()));
         }
         if !include.to_string().is_empty() {
-            query_args.push(("include".to_string(), include.to_string()));
+            query_args.push(("include", include.to_string()));
         }
         if !order.to_string().is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !phrase.is_empty() {
-            query_args.push(("phrase".to_string(), phrase.to_string()));
+            query_args.push(("phrase", phrase.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/enterprises/{}/audit-log?{}",
-            crate::progenitor_support::encode_path(&enterprise.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/enterprises/{}/audit-log",
+                crate::progenitor_support::encode_path(&enterprise.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -1069,27 +1076,25 @@ impl EnterpriseAdmin {
         filter: &str,
         excluded_attributes: &str,
     ) -> Result<crate::types::ScimGroupListEnterprise> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !excluded_attributes.is_empty() {
-            query_args.push((
-                "excludedAttributes".to_string(),
-                excluded_attributes.to_string(),
-            ));
+            query_args.push(("excludedAttributes", excluded_attributes.to_string()));
         }
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if start_index > 0 {
-            query_args.push(("startIndex".to_string(), start_index.to_string()));
+            query_args.push(("startIndex", start_index.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/scim/v2/enterprises/{}/Groups?{}",
-            crate::progenitor_support::encode_path(&enterprise.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/scim/v2/enterprises/{}/Groups",
+                crate::progenitor_support::encode_path(&enterprise.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1146,19 +1151,17 @@ impl EnterpriseAdmin {
         scim_group_id: &str,
         excluded_attributes: &str,
     ) -> Result<crate::types::ScimEnterpriseGroup> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !excluded_attributes.is_empty() {
-            query_args.push((
-                "excludedAttributes".to_string(),
-                excluded_attributes.to_string(),
-            ));
+            query_args.push(("excludedAttributes", excluded_attributes.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/scim/v2/enterprises/{}/Groups/{}?{}",
-            crate::progenitor_support::encode_path(&enterprise.to_string()),
-            crate::progenitor_support::encode_path(&scim_group_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/scim/v2/enterprises/{}/Groups/{}",
+                crate::progenitor_support::encode_path(&enterprise.to_string()),
+                crate::progenitor_support::encode_path(&scim_group_id.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1298,21 +1301,22 @@ impl EnterpriseAdmin {
         count: i64,
         filter: &str,
     ) -> Result<crate::types::ScimUserListEnterprise> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if start_index > 0 {
-            query_args.push(("startIndex".to_string(), start_index.to_string()));
+            query_args.push(("startIndex", start_index.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/scim/v2/enterprises/{}/Users?{}",
-            crate::progenitor_support::encode_path(&enterprise.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/scim/v2/enterprises/{}/Users",
+                crate::progenitor_support::encode_path(&enterprise.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -33,18 +33,17 @@ impl Gists {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::BaseGist>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/gists?{}", query_);
+        let url = crate::Client::url_with_query("/gists", &query_args);
 
         self.client.get(&url, None).await
     }
@@ -64,12 +63,11 @@ impl Gists {
         &self,
         since: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<Vec<crate::types::BaseGist>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/gists?{}", query_);
+        let url = crate::Client::url_with_query("/gists", &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -118,18 +116,17 @@ impl Gists {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::BaseGist>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/gists/public?{}", query_);
+        let url = crate::Client::url_with_query("/gists/public", &query_args);
 
         self.client.get(&url, None).await
     }
@@ -151,12 +148,11 @@ impl Gists {
         &self,
         since: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<Vec<crate::types::BaseGist>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/gists/public?{}", query_);
+        let url = crate::Client::url_with_query("/gists/public", &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -182,18 +178,17 @@ impl Gists {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::BaseGist>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/gists/starred?{}", query_);
+        let url = crate::Client::url_with_query("/gists/starred", &query_args);
 
         self.client.get(&url, None).await
     }
@@ -213,12 +208,11 @@ impl Gists {
         &self,
         since: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<Vec<crate::types::BaseGist>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/gists/starred?{}", query_);
+        let url = crate::Client::url_with_query("/gists/starred", &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -316,18 +310,19 @@ impl Gists {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::GistComment>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/gists/{}/comments?{}",
-            crate::progenitor_support::encode_path(&gist_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/gists/{}/comments",
+                crate::progenitor_support::encode_path(&gist_id.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -485,18 +480,19 @@ impl Gists {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::GistCommit>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/gists/{}/commits?{}",
-            crate::progenitor_support::encode_path(&gist_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/gists/{}/commits",
+                crate::progenitor_support::encode_path(&gist_id.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -543,18 +539,19 @@ impl Gists {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::GistSimple>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/gists/{}/forks?{}",
-            crate::progenitor_support::encode_path(&gist_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/gists/{}/forks",
+                crate::progenitor_support::encode_path(&gist_id.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -715,21 +712,22 @@ impl Gists {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::BaseGist>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/gists?{}",
-            crate::progenitor_support::encode_path(&username.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/users/{}/gists",
+                crate::progenitor_support::encode_path(&username.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -751,15 +749,16 @@ impl Gists {
         username: &str,
         since: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<Vec<crate::types::BaseGist>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/gists?{}",
-            crate::progenitor_support::encode_path(&username.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/users/{}/gists",
+                crate::progenitor_support::encode_path(&username.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
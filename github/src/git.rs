@@ -227,20 +227,21 @@ impl Git {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::GitRef>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/git/matching-refs/{}?{}",
-            crate::progenitor_support::encode_path(&owner.to_string()),
-            crate::progenitor_support::encode_path(&repo.to_string()),
-            crate::progenitor_support::encode_path(&ref_.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/repos/{}/{}/git/matching-refs/{}",
+                crate::progenitor_support::encode_path(&owner.to_string()),
+                crate::progenitor_support::encode_path(&repo.to_string()),
+                crate::progenitor_support::encode_path(&ref_.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -579,17 +580,18 @@ impl Git {
         tree_sha: &str,
         recursive: &str,
     ) -> Result<crate::types::GitTreeData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !recursive.is_empty() {
-            query_args.push(("recursive".to_string(), recursive.to_string()));
+            query_args.push(("recursive", recursive.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/git/trees/{}?{}",
-            crate::progenitor_support::encode_path(&owner.to_string()),
-            crate::progenitor_support::encode_path(&repo.to_string()),
-            crate::progenitor_support::encode_path(&tree_sha.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/repos/{}/{}/git/trees/{}",
+                crate::progenitor_support::encode_path(&owner.to_string()),
+                crate::progenitor_support::encode_path(&repo.to_string()),
+                crate::progenitor_support::encode_path(&tree_sha.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
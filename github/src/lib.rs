@@ -248,6 +248,25 @@ use anyhow::{anyhow, Error, Result};
 
 pub const DEFAULT_HOST: &str = "https://api.github.com";
 
+/// The version of the spec this crate was generated from, as its `info` has it.
+pub const SPEC_VERSION: &str = "1.1.4";
+
+/// The revision of the spec this crate was generated from, a hash of the
+/// document, to tell apart specs with the same version.
+pub const SPEC_REVISION: &str = "c6115996899d6333";
+
+/// What the crate was built from, say to report along with a bug, or to tell
+/// whether the spec has changed since. See `Client::build_info`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BuildInfo {
+    /// The version of the crate.
+    pub version: &'static str,
+    /// The version of the spec, `SPEC_VERSION`.
+    pub spec_version: &'static str,
+    /// The revision of the spec, `SPEC_REVISION`.
+    pub spec_revision: &'static str,
+}
+
 mod progenitor_support {
     use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
 
@@ -379,6 +398,20 @@ impl Client {
         }
     }
 
+    /// Join the query parameters onto the path of a request, leaving out those
+    /// that are empty, as the generated functions push every parameter they
+    /// take whether or not it was set.
+    fn url_with_query(base: &str, args: &[(&str, String)]) -> String {
+        let args: Vec<&(&str, String)> = args.iter().filter(|(_, v)| !v.is_empty()).collect();
+        if args.is_empty() {
+            return base.to_string();
+        }
+        let query = url::form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(args)
+            .finish();
+        format!("{}?{}", base, query)
+    }
+
     async fn url_and_auth(
         &self,
         uri: &str,
@@ -477,10 +510,11 @@ impl Client {
         }
 
         if let Some(body) = body {
-            log::debug!(
-                "body: {:?}",
-                String::from_utf8(body.as_bytes().unwrap().to_vec()).unwrap()
-            );
+            // A streaming body can not be read without sending it, so only log one
+            // that is in memory.
+            if let Some(bytes) = body.as_bytes() {
+                log::debug!("body: {:?}", String::from_utf8_lossy(bytes));
+            }
             req = req.body(body);
         }
         log::debug!("request: {:?}", &req);
@@ -949,4 +983,18 @@ impl Client {
     pub fn users(&self) -> users::Users {
         users::Users::new(self.clone())
     }
+
+    /// The version of the crate.
+    pub fn version() -> &'static str {
+        env!("CARGO_PKG_VERSION")
+    }
+
+    /// The versions of the crate and of the spec it was generated from.
+    pub fn build_info() -> BuildInfo {
+        BuildInfo {
+            version: Self::version(),
+            spec_version: SPEC_VERSION,
+            spec_revision: SPEC_REVISION,
+        }
+    }
 }
@@ -33,18 +33,17 @@ impl Licenses {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::LicenseSimple>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if featured {
-            query_args.push(("featured".to_string(), featured.to_string()));
+            query_args.push(("featured", featured.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/licenses?{}", query_);
+        let url = crate::Client::url_with_query("/licenses", &query_args);
 
         self.client.get(&url, None).await
     }
@@ -64,12 +63,11 @@ impl Licenses {
         &self,
         featured: bool,
     ) -> Result<Vec<crate::types::LicenseSimple>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if featured {
-            query_args.push(("featured".to_string(), featured.to_string()));
+            query_args.push(("featured", featured.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/licenses?{}", query_);
+        let url = crate::Client::url_with_query("/licenses", &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
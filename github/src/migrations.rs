@@ -35,21 +35,22 @@ impl Migrations {
         page: i64,
         exclude: &[String],
     ) -> Result<Vec<crate::types::Migration>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude.is_empty() {
-            query_args.push(("exclude".to_string(), exclude.join(" ")));
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        for v in exclude {
+            query_args.push(("exclude", v.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/migrations?{}",
-            crate::progenitor_support::encode_path(&org.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/orgs/{}/migrations",
+                crate::progenitor_support::encode_path(&org.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -71,15 +72,16 @@ impl Migrations {
         org: &str,
         exclude: &[String],
     ) -> Result<Vec<crate::types::Migration>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude.is_empty() {
-            query_args.push(("exclude".to_string(), exclude.join(" ")));
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        for v in exclude {
+            query_args.push(("exclude", v.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/migrations?{}",
-            crate::progenitor_support::encode_path(&org.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/orgs/{}/migrations",
+                crate::progenitor_support::encode_path(&org.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -141,16 +143,17 @@ impl Migrations {
         migration_id: i64,
         exclude: &[String],
     ) -> Result<crate::types::Migration> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude.is_empty() {
-            query_args.push(("exclude".to_string(), exclude.join(" ")));
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        for v in exclude {
+            query_args.push(("exclude", v.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/migrations/{}?{}",
-            crate::progenitor_support::encode_path(&org.to_string()),
-            crate::progenitor_support::encode_path(&migration_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/orgs/{}/migrations/{}",
+                crate::progenitor_support::encode_path(&org.to_string()),
+                crate::progenitor_support::encode_path(&migration_id.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -258,19 +261,20 @@ impl Migrations {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/migrations/{}/repositories?{}",
-            crate::progenitor_support::encode_path(&org.to_string()),
-            crate::progenitor_support::encode_path(&migration_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/orgs/{}/migrations/{}/repositories",
+                crate::progenitor_support::encode_path(&org.to_string()),
+                crate::progenitor_support::encode_path(&migration_id.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -468,16 +472,17 @@ impl Migrations {
         repo: &str,
         since: i64,
     ) -> Result<Vec<crate::types::PorterAuthor>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if since > 0 {
-            query_args.push(("since".to_string(), since.to_string()));
+            query_args.push(("since", since.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/import/authors?{}",
-            crate::progenitor_support::encode_path(&owner.to_string()),
-            crate::progenitor_support::encode_path(&repo.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/repos/{}/{}/import/authors",
+                crate::progenitor_support::encode_path(&owner.to_string()),
+                crate::progenitor_support::encode_path(&repo.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -502,16 +507,17 @@ impl Migrations {
         repo: &str,
         since: i64,
     ) -> Result<Vec<crate::types::PorterAuthor>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if since > 0 {
-            query_args.push(("since".to_string(), since.to_string()));
+            query_args.push(("since", since.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/import/authors?{}",
-            crate::progenitor_support::encode_path(&owner.to_string()),
-            crate::progenitor_support::encode_path(&repo.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/repos/{}/{}/import/authors",
+                crate::progenitor_support::encode_path(&owner.to_string()),
+                crate::progenitor_support::encode_path(&repo.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -654,15 +660,14 @@ impl Migrations {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Migration>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/user/migrations?{}", query_);
+        let url = crate::Client::url_with_query("/user/migrations", &query_args);
 
         self.client.get(&url, None).await
     }
@@ -728,15 +733,16 @@ impl Migrations {
         migration_id: i64,
         exclude: &[String],
     ) -> Result<crate::types::Migration> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude.is_empty() {
-            query_args.push(("exclude".to_string(), exclude.join(" ")));
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        for v in exclude {
+            query_args.push(("exclude", v.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/user/migrations/{}?{}",
-            crate::progenitor_support::encode_path(&migration_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/user/migrations/{}",
+                crate::progenitor_support::encode_path(&migration_id.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -855,18 +861,19 @@ impl Migrations {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/user/migrations/{}/repositories?{}",
-            crate::progenitor_support::encode_path(&migration_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/user/migrations/{}/repositories",
+                crate::progenitor_support::encode_path(&migration_id.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
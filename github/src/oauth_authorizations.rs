@@ -35,18 +35,17 @@ impl OauthAuthorizations {
         page: i64,
         client_id: &str,
     ) -> Result<Vec<crate::types::ApplicationGrant>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !client_id.is_empty() {
-            query_args.push(("client_id".to_string(), client_id.to_string()));
+            query_args.push(("client_id", client_id.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/applications/grants?{}", query_);
+        let url = crate::Client::url_with_query("/applications/grants", &query_args);
 
         self.client.get(&url, None).await
     }
@@ -68,12 +67,11 @@ impl OauthAuthorizations {
         &self,
         client_id: &str,
     ) -> Result<Vec<crate::types::ApplicationGrant>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !client_id.is_empty() {
-            query_args.push(("client_id".to_string(), client_id.to_string()));
+            query_args.push(("client_id", client_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/applications/grants?{}", query_);
+        let url = crate::Client::url_with_query("/applications/grants", &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -145,18 +143,17 @@ impl OauthAuthorizations {
         page: i64,
         client_id: &str,
     ) -> Result<Vec<crate::types::Authorization>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !client_id.is_empty() {
-            query_args.push(("client_id".to_string(), client_id.to_string()));
+            query_args.push(("client_id", client_id.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/authorizations?{}", query_);
+        let url = crate::Client::url_with_query("/authorizations", &query_args);
 
         self.client.get(&url, None).await
     }
@@ -176,12 +173,11 @@ impl OauthAuthorizations {
         &self,
         client_id: &str,
     ) -> Result<Vec<crate::types::Authorization>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !client_id.is_empty() {
-            query_args.push(("client_id".to_string(), client_id.to_string()));
+            query_args.push(("client_id", client_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/authorizations?{}", query_);
+        let url = crate::Client::url_with_query("/authorizations", &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -33,15 +33,14 @@ impl Orgs {
         since: i64,
         per_page: i64,
     ) -> Result<Vec<crate::types::OrganizationSimple>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if since > 0 {
-            query_args.push(("since".to_string(), since.to_string()));
+            query_args.push(("since", since.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/organizations?{}", query_);
+        let url = crate::Client::url_with_query("/organizations", &query_args);
 
         self.client.get(&url, None).await
     }
@@ -60,12 +59,11 @@ impl Orgs {
      * FROM: <https://docs.github.com/rest/reference/orgs#list-organizations>
      */
     pub async fn list_all(&self, since: i64) -> Result<Vec<crate::types::OrganizationSimple>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if since > 0 {
-            query_args.push(("since".to_string(), since.to_string()));
+            query_args.push(("since", since.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/organizations?{}", query_);
+        let url = crate::Client::url_with_query("/organizations", &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -165,33 +163,34 @@ impl Orgs {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::AuditLogEvent>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if !before.is_empty() {
-            query_args.push(("before".to_string(), before.to_string()));
+            query_args.push(("before", before.to_string()));
         }
         if !include.to_string().is_empty() {
-            query_args.push(("include".to_string(), include.to_string()));
+            query_args.push(("include", include.to_string()));
         }
         if !order.to_string().is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !phrase.is_empty() {
-            query_args.push(("phrase".to_string(), phrase.to_string()));
+            query_args.push(("phrase", phrase.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/audit-log?{}",
-            crate::progenitor_support::encode_path(&org.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/orgs/{}/audit-log",
+                crate::progenitor_support::encode_path(&org.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -219,27 +218,28 @@ impl Orgs {
         before: &str,
         order: crate::types::Order,
     ) -> Result<Vec<crate::types::AuditLogEvent>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if !before.is_empty() {
-            query_args.push(("before".to_string(), before.to_string()));
+            query_args.push(("before", before.to_string()));
         }
         if !include.to_string().is_empty() {
-            query_args.push(("include".to_string(), include.to_string()));
+            query_args.push(("include", include.to_string()));
         }
         if !order.to_string().is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !phrase.is_empty() {
-            query_args.push(("phrase".to_string(), phrase.to_string()));
+            query_args.push(("phrase", phrase.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/audit-log?{}",
-            crate::progenitor_support::encode_path(&org.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/orgs/{}/audit-log",
+                crate::progenitor_support::encode_path(&org.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -458,18 +458,19 @@ impl Orgs {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::OrganizationInvitation>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/failed_invitations?{}",
-            crate::progenitor_support::encode_path(&org.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/orgs/{}/failed_invitations",
+                crate::progenitor_support::encode_path(&org.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -519,18 +520,19 @@ impl Orgs {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::OrgHook>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/hooks?{}",
-            crate::progenitor_support::encode_path(&org.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/orgs/{}/hooks",
+                crate::progenitor_support::encode_path(&org.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -749,19 +751,20 @@ impl Orgs {
         per_page: i64,
         cursor: &str,
     ) -> Result<Vec<crate::types::HookDeliveryItem>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/hooks/{}/deliveries?{}",
-            crate::progenitor_support::encode_path(&org.to_string()),
-            crate::progenitor_support::encode_path(&hook_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/orgs/{}/hooks/{}/deliveries",
+                crate::progenitor_support::encode_path(&org.to_string()),
+                crate::progenitor_support::encode_path(&hook_id.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -784,16 +787,17 @@ impl Orgs {
         hook_id: i64,
         cursor: &str,
     ) -> Result<Vec<crate::types::HookDeliveryItem>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/hooks/{}/deliveries?{}",
-            crate::progenitor_support::encode_path(&org.to_string()),
-            crate::progenitor_support::encode_path(&hook_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/orgs/{}/hooks/{}/deliveries",
+                crate::progenitor_support::encode_path(&org.to_string()),
+                crate::progenitor_support::encode_path(&hook_id.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -906,18 +910,19 @@ impl Orgs {
         per_page: i64,
         page: i64,
     ) -> Result<crate::types::AppsListInstallationsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/installations?{}",
-            crate::progenitor_support::encode_path(&org.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/orgs/{}/installations",
+                crate::progenitor_support::encode_path(&org.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -944,18 +949,19 @@ impl Orgs {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::OrganizationInvitation>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/invitations?{}",
-            crate::progenitor_support::encode_path(&org.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/orgs/{}/invitations",
+                crate::progenitor_support::encode_path(&org.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1063,19 +1069,20 @@ impl Orgs {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Team>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/invitations/{}/teams?{}",
-            crate::progenitor_support::encode_path(&org.to_string()),
-            crate::progenitor_support::encode_path(&invitation_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/orgs/{}/invitations/{}/teams",
+                crate::progenitor_support::encode_path(&org.to_string()),
+                crate::progenitor_support::encode_path(&invitation_id.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1136,24 +1143,25 @@ impl Orgs {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::SimpleUser>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !filter.to_string().is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !role.to_string().is_empty() {
-            query_args.push(("role".to_string(), role.to_string()));
+            query_args.push(("role", role.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/members?{}",
-            crate::progenitor_support::encode_path(&org.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/orgs/{}/members",
+                crate::progenitor_support::encode_path(&org.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1176,18 +1184,19 @@ impl Orgs {
         filter: crate::types::OrgsListMembersFilter,
         role: crate::types::OrgsListMembersRole,
     ) -> Result<Vec<crate::types::SimpleUser>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !filter.to_string().is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if !role.to_string().is_empty() {
-            query_args.push(("role".to_string(), role.to_string()));
+            query_args.push(("role", role.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/members?{}",
-            crate::progenitor_support::encode_path(&org.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/orgs/{}/members",
+                crate::progenitor_support::encode_path(&org.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -1359,21 +1368,22 @@ impl Orgs {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::SimpleUser>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !filter.to_string().is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/outside_collaborators?{}",
-            crate::progenitor_support::encode_path(&org.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/orgs/{}/outside_collaborators",
+                crate::progenitor_support::encode_path(&org.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1395,15 +1405,16 @@ impl Orgs {
         org: &str,
         filter: crate::types::OrgsListMembersFilter,
     ) -> Result<Vec<crate::types::SimpleUser>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !filter.to_string().is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/outside_collaborators?{}",
-            crate::progenitor_support::encode_path(&org.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/orgs/{}/outside_collaborators",
+                crate::progenitor_support::encode_path(&org.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -1482,18 +1493,19 @@ impl Orgs {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::SimpleUser>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/public_members?{}",
-            crate::progenitor_support::encode_path(&org.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/orgs/{}/public_members",
+                crate::progenitor_support::encode_path(&org.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -1625,18 +1637,17 @@ impl Orgs {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::OrgMembership>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+            query_args.push(("state", state.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/user/memberships/orgs?{}", query_);
+        let url = crate::Client::url_with_query("/user/memberships/orgs", &query_args);
 
         self.client.get(&url, None).await
     }
@@ -1656,12 +1667,11 @@ impl Orgs {
         &self,
         state: crate::types::OrgMembershipState,
     ) -> Result<Vec<crate::types::OrgMembership>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+            query_args.push(("state", state.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/user/memberships/orgs?{}", query_);
+        let url = crate::Client::url_with_query("/user/memberships/orgs", &query_args);
 
         self.client.get_all_pages(&url, None).await
     }
@@ -1742,15 +1752,14 @@ impl Orgs {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::OrganizationSimple>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/user/orgs?{}", query_);
+        let url = crate::Client::url_with_query("/user/orgs", &query_args);
 
         self.client.get(&url, None).await
     }
@@ -1800,18 +1809,19 @@ impl Orgs {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::OrganizationSimple>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/orgs?{}",
-            crate::progenitor_support::encode_path(&username.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/users/{}/orgs",
+                crate::progenitor_support::encode_path(&username.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -112,17 +112,18 @@ impl Packages {
         org: &str,
         token: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !token.is_empty() {
-            query_args.push(("token".to_string(), token.to_string()));
+            query_args.push(("token", token.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/packages/{}/{}/restore?{}",
-            crate::progenitor_support::encode_path(&org.to_string()),
-            crate::progenitor_support::encode_path(&package_type.to_string()),
-            crate::progenitor_support::encode_path(&package_name.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/orgs/{}/packages/{}/{}/restore",
+                crate::progenitor_support::encode_path(&org.to_string()),
+                crate::progenitor_support::encode_path(&package_type.to_string()),
+                crate::progenitor_support::encode_path(&package_name.to_string())
+            ),
+            &query_args,
         );
 
         self.client.post(&url, None).await
@@ -158,23 +159,24 @@ impl Packages {
         per_page: i64,
         state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
     ) -> Result<Vec<crate::types::PackageVersion>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+            query_args.push(("state", state.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/packages/{}/{}/versions?{}",
-            crate::progenitor_support::encode_path(&org.to_string()),
-            crate::progenitor_support::encode_path(&package_type.to_string()),
-            crate::progenitor_support::encode_path(&package_name.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/orgs/{}/packages/{}/{}/versions",
+                crate::progenitor_support::encode_path(&org.to_string()),
+                crate::progenitor_support::encode_path(&package_type.to_string()),
+                crate::progenitor_support::encode_path(&package_name.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -201,17 +203,18 @@ impl Packages {
         org: &str,
         state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
     ) -> Result<Vec<crate::types::PackageVersion>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+            query_args.push(("state", state.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/packages/{}/{}/versions?{}",
-            crate::progenitor_support::encode_path(&org.to_string()),
-            crate::progenitor_support::encode_path(&package_type.to_string()),
-            crate::progenitor_support::encode_path(&package_name.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/orgs/{}/packages/{}/{}/versions",
+                crate::progenitor_support::encode_path(&org.to_string()),
+                crate::progenitor_support::encode_path(&package_type.to_string()),
+                crate::progenitor_support::encode_path(&package_name.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -423,16 +426,17 @@ impl Packages {
         package_name: &str,
         token: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !token.is_empty() {
-            query_args.push(("token".to_string(), token.to_string()));
+            query_args.push(("token", token.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/user/packages/{}/{}/restore?{}",
-            crate::progenitor_support::encode_path(&package_type.to_string()),
-            crate::progenitor_support::encode_path(&package_name.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/user/packages/{}/{}/restore",
+                crate::progenitor_support::encode_path(&package_type.to_string()),
+                crate::progenitor_support::encode_path(&package_name.to_string())
+            ),
+            &query_args,
         );
 
         self.client.post(&url, None).await
@@ -466,22 +470,23 @@ impl Packages {
         per_page: i64,
         state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
     ) -> Result<Vec<crate::types::PackageVersion>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+            query_args.push(("state", state.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/user/packages/{}/{}/versions?{}",
-            crate::progenitor_support::encode_path(&package_type.to_string()),
-            crate::progenitor_support::encode_path(&package_name.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/user/packages/{}/{}/versions",
+                crate::progenitor_support::encode_path(&package_type.to_string()),
+                crate::progenitor_support::encode_path(&package_name.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -507,16 +512,17 @@ impl Packages {
         package_name: &str,
         state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
     ) -> Result<Vec<crate::types::PackageVersion>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+            query_args.push(("state", state.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/user/packages/{}/{}/versions?{}",
-            crate::progenitor_support::encode_path(&package_type.to_string()),
-            crate::progenitor_support::encode_path(&package_name.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/user/packages/{}/{}/versions",
+                crate::progenitor_support::encode_path(&package_type.to_string()),
+                crate::progenitor_support::encode_path(&package_name.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
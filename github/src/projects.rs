@@ -35,21 +35,22 @@ impl Projects {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Project>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+            query_args.push(("state", state.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/projects?{}",
-            crate::progenitor_support::encode_path(&org.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/orgs/{}/projects",
+                crate::progenitor_support::encode_path(&org.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -71,15 +72,16 @@ impl Projects {
         org: &str,
         state: crate::types::IssuesListState,
     ) -> Result<Vec<crate::types::Project>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+            query_args.push(("state", state.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/orgs/{}/projects?{}",
-            crate::progenitor_support::encode_path(&org.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/orgs/{}/projects",
+                crate::progenitor_support::encode_path(&org.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -308,21 +310,22 @@ impl Projects {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::ProjectCard>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !archived_state.to_string().is_empty() {
-            query_args.push(("archived_state".to_string(), archived_state.to_string()));
+            query_args.push(("archived_state", archived_state.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/projects/columns/{}/cards?{}",
-            crate::progenitor_support::encode_path(&column_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/projects/columns/{}/cards",
+                crate::progenitor_support::encode_path(&column_id.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -344,15 +347,16 @@ impl Projects {
         column_id: i64,
         archived_state: crate::types::ArchivedState,
     ) -> Result<Vec<crate::types::ProjectCard>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !archived_state.to_string().is_empty() {
-            query_args.push(("archived_state".to_string(), archived_state.to_string()));
+            query_args.push(("archived_state", archived_state.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/projects/columns/{}/cards?{}",
-            crate::progenitor_support::encode_path(&column_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/projects/columns/{}/cards",
+                crate::progenitor_support::encode_path(&column_id.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -512,21 +516,22 @@ impl Projects {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::SimpleUser>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !affiliation.to_string().is_empty() {
-            query_args.push(("affiliation".to_string(), affiliation.to_string()));
+            query_args.push(("affiliation", affiliation.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/projects/{}/collaborators?{}",
-            crate::progenitor_support::encode_path(&project_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/projects/{}/collaborators",
+                crate::progenitor_support::encode_path(&project_id.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -548,15 +553,16 @@ impl Projects {
         project_id: i64,
         affiliation: crate::types::Affiliation,
     ) -> Result<Vec<crate::types::SimpleUser>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !affiliation.to_string().is_empty() {
-            query_args.push(("affiliation".to_string(), affiliation.to_string()));
+            query_args.push(("affiliation", affiliation.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/projects/{}/collaborators?{}",
-            crate::progenitor_support::encode_path(&project_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/projects/{}/collaborators",
+                crate::progenitor_support::encode_path(&project_id.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -666,18 +672,19 @@ impl Projects {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::ProjectColumn>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/projects/{}/columns?{}",
-            crate::progenitor_support::encode_path(&project_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/projects/{}/columns",
+                crate::progenitor_support::encode_path(&project_id.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -759,22 +766,23 @@ impl Projects {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Project>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+            query_args.push(("state", state.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/projects?{}",
-            crate::progenitor_support::encode_path(&owner.to_string()),
-            crate::progenitor_support::encode_path(&repo.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/repos/{}/{}/projects",
+                crate::progenitor_support::encode_path(&owner.to_string()),
+                crate::progenitor_support::encode_path(&repo.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -797,16 +805,17 @@ impl Projects {
         repo: &str,
         state: crate::types::IssuesListState,
     ) -> Result<Vec<crate::types::Project>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+            query_args.push(("state", state.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/repos/{}/{}/projects?{}",
-            crate::progenitor_support::encode_path(&owner.to_string()),
-            crate::progenitor_support::encode_path(&repo.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/repos/{}/{}/projects",
+                crate::progenitor_support::encode_path(&owner.to_string()),
+                crate::progenitor_support::encode_path(&repo.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
@@ -885,21 +894,22 @@ impl Projects {
         per_page: i64,
         page: i64,
     ) -> Result<Vec<crate::types::Project>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
+            query_args.push(("per_page", per_page.to_string()));
         }
         if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+            query_args.push(("state", state.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/projects?{}",
-            crate::progenitor_support::encode_path(&username.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/users/{}/projects",
+                crate::progenitor_support::encode_path(&username.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get(&url, None).await
@@ -921,15 +931,16 @@ impl Projects {
         username: &str,
         state: crate::types::IssuesListState,
     ) -> Result<Vec<crate::types::Project>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !state.to_string().is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+            query_args.push(("state", state.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/projects?{}",
-            crate::progenitor_support::encode_path(&username.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/users/{}/projects",
+                crate::progenitor_support::encode_path(&username.to_string())
+            ),
+            &query_args,
         );
 
         self.client.get_all_pages(&url, None).await
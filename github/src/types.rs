@@ -849,7 +849,7 @@ pub struct Enterprise {
 /**
  * The level of permission to grant the access token to retrieve Pages statuses, configuration, and builds, as well as create new builds. Can be one of: `read` or `write`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Pages {
     #[serde(rename = "read")]
     Read,
@@ -878,7 +878,23 @@ impl Default for Pages {
         Pages::Noop
     }
 }
+
 impl Pages {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Pages::Read => "read",
+            Pages::Write => "write",
+            Pages::Noop => "",
+            Pages::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Pages] {
+        &[Pages::Read, Pages::Write]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Pages::Noop)
     }
@@ -887,7 +903,7 @@ impl Pages {
 /**
  * The level of permission to grant the access token to manage repository projects, columns, and cards. Can be one of: `read`, `write`, or `admin`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum RepositoryProjects {
     #[serde(rename = "admin")]
     Admin,
@@ -919,7 +935,28 @@ impl Default for RepositoryProjects {
         RepositoryProjects::Noop
     }
 }
+
 impl RepositoryProjects {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RepositoryProjects::Admin => "admin",
+            RepositoryProjects::Read => "read",
+            RepositoryProjects::Write => "write",
+            RepositoryProjects::Noop => "",
+            RepositoryProjects::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [RepositoryProjects] {
+        &[
+            RepositoryProjects::Admin,
+            RepositoryProjects::Read,
+            RepositoryProjects::Write,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, RepositoryProjects::Noop)
     }
@@ -928,7 +965,7 @@ impl RepositoryProjects {
 /**
  * The level of permission to grant the access token for viewing an organization's plan. Can be one of: `read`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum OrganizationPlan {
     #[serde(rename = "read")]
     Read,
@@ -954,7 +991,22 @@ impl Default for OrganizationPlan {
         OrganizationPlan::Noop
     }
 }
+
 impl OrganizationPlan {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrganizationPlan::Read => "read",
+            OrganizationPlan::Noop => "",
+            OrganizationPlan::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [OrganizationPlan] {
+        &[OrganizationPlan::Read]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, OrganizationPlan::Noop)
     }
@@ -963,7 +1015,7 @@ impl OrganizationPlan {
 /**
  * The level of permission to grant the access token to update GitHub Actions workflow files. Can be one of: `write`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Workflows {
     #[serde(rename = "write")]
     Write,
@@ -989,7 +1041,22 @@ impl Default for Workflows {
         Workflows::Noop
     }
 }
+
 impl Workflows {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Workflows::Write => "write",
+            Workflows::Noop => "",
+            Workflows::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Workflows] {
+        &[Workflows::Write]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Workflows::Noop)
     }
@@ -1155,28 +1222,45 @@ pub struct AppPermissions {
     pub workflows: Option<Workflows>,
 }
 
-/// All of the following types are flattened into one object:
+/// All of the following types:
 ///
 /// - `SimpleUser`
 /// - `Enterprise`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AccountAnyOf {
+#[serde(untagged)]
+pub enum AccountAnyOf {
     /**
      * Simple User
      */
-    #[serde(flatten)]
-    pub simple_user: SimpleUser,
+    SimpleUser(SimpleUser),
     /**
      * An enterprise account
      */
-    #[serde(flatten)]
-    pub enterprise: Enterprise,
+    Enterprise(Enterprise),
+}
+
+impl AccountAnyOf {
+    pub fn enterprise(&self) -> Option<&Enterprise> {
+        if let AccountAnyOf::Enterprise(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn simple_user(&self) -> Option<&SimpleUser> {
+        if let AccountAnyOf::SimpleUser(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
 }
 
 /**
  * Describe whether all repositories have been selected or there's a selection involved
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum RepositorySelection {
     #[serde(rename = "all")]
     All,
@@ -1205,7 +1289,23 @@ impl Default for RepositorySelection {
         RepositorySelection::Noop
     }
 }
+
 impl RepositorySelection {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RepositorySelection::All => "all",
+            RepositorySelection::Selected => "selected",
+            RepositorySelection::Noop => "",
+            RepositorySelection::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [RepositorySelection] {
+        &[RepositorySelection::All, RepositorySelection::Selected]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, RepositorySelection::Noop)
     }
@@ -1220,8 +1320,8 @@ pub struct Installation {
         deserialize_with = "crate::utils::deserialize_empty_url::deserialize"
     )]
     pub access_tokens_url: Option<url::Url>,
-    #[serde()]
-    pub account: AccountAnyOf,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account: Option<AccountAnyOf>,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -2825,7 +2925,7 @@ pub struct CodeOfConduct {
 /**
  * The policy that controls the repositories in the organization that are allowed to run GitHub Actions. Can be one of: `all`, `none`, or `selected`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum EnabledRepositories {
     #[serde(rename = "all")]
     All,
@@ -2857,7 +2957,28 @@ impl Default for EnabledRepositories {
         EnabledRepositories::Noop
     }
 }
+
 impl EnabledRepositories {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EnabledRepositories::All => "all",
+            EnabledRepositories::None => "none",
+            EnabledRepositories::Selected => "selected",
+            EnabledRepositories::Noop => "",
+            EnabledRepositories::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [EnabledRepositories] {
+        &[
+            EnabledRepositories::All,
+            EnabledRepositories::None,
+            EnabledRepositories::Selected,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, EnabledRepositories::Noop)
     }
@@ -2866,7 +2987,7 @@ impl EnabledRepositories {
 /**
  * The permissions policy that controls the actions that are allowed to run. Can be one of: `all`, `local_only`, or `selected`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum AllowedActions {
     #[serde(rename = "all")]
     All,
@@ -2898,7 +3019,28 @@ impl Default for AllowedActions {
         AllowedActions::Noop
     }
 }
+
 impl AllowedActions {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AllowedActions::All => "all",
+            AllowedActions::LocalOnly => "local_only",
+            AllowedActions::Selected => "selected",
+            AllowedActions::Noop => "",
+            AllowedActions::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [AllowedActions] {
+        &[
+            AllowedActions::All,
+            AllowedActions::LocalOnly,
+            AllowedActions::Selected,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, AllowedActions::Noop)
     }
@@ -3077,7 +3219,7 @@ pub struct RunnerGroupsEnterprise {
 /**
  * The type of label. Read-only labels are applied automatically when the runner is configured.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Type {
     #[serde(rename = "custom")]
     Custom,
@@ -3106,7 +3248,23 @@ impl Default for Type {
         Type::Noop
     }
 }
+
 impl Type {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Type::Custom => "custom",
+            Type::ReadOnly => "read-only",
+            Type::Noop => "",
+            Type::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Type] {
+        &[Type::Custom, Type::ReadOnly]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Type::Noop)
     }
@@ -3695,7 +3853,7 @@ pub struct Label {
 /**
  * The state of the milestone.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum State {
     #[serde(rename = "closed")]
     Closed,
@@ -3722,6 +3880,22 @@ impl Default for State {
     }
 }
 
+impl State {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            State::Closed => "closed",
+            State::Open => "open",
+            State::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [State] {
+        &[State::Closed, State::Open]
+    }
+}
+
 /// A collection of related issues and pull requests.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Milestone {
@@ -3821,7 +3995,7 @@ pub struct Milestone {
 /**
  * How the author is associated with the repository.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum AuthorAssociation {
     #[serde(rename = "COLLABORATOR")]
     Collaborator,
@@ -3868,7 +4042,38 @@ impl Default for AuthorAssociation {
         AuthorAssociation::Noop
     }
 }
+
 impl AuthorAssociation {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuthorAssociation::Collaborator => "COLLABORATOR",
+            AuthorAssociation::Contributor => "CONTRIBUTOR",
+            AuthorAssociation::FirstTimer => "FIRST_TIMER",
+            AuthorAssociation::FirstTimeContributor => "FIRST_TIME_CONTRIBUTOR",
+            AuthorAssociation::Mannequin => "MANNEQUIN",
+            AuthorAssociation::Member => "MEMBER",
+            AuthorAssociation::None => "NONE",
+            AuthorAssociation::Owner => "OWNER",
+            AuthorAssociation::Noop => "",
+            AuthorAssociation::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [AuthorAssociation] {
+        &[
+            AuthorAssociation::Collaborator,
+            AuthorAssociation::Contributor,
+            AuthorAssociation::FirstTimer,
+            AuthorAssociation::FirstTimeContributor,
+            AuthorAssociation::Mannequin,
+            AuthorAssociation::Member,
+            AuthorAssociation::None,
+            AuthorAssociation::Owner,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, AuthorAssociation::Noop)
     }
@@ -7254,7 +7459,7 @@ pub struct RunnerGroupsOrg {
 /**
  * Visibility of a secret
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Visibility {
     #[serde(rename = "all")]
     All,
@@ -7286,7 +7491,24 @@ impl Default for Visibility {
         Visibility::Noop
     }
 }
+
 impl Visibility {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Visibility::All => "all",
+            Visibility::Private => "private",
+            Visibility::Selected => "selected",
+            Visibility::Noop => "",
+            Visibility::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Visibility] {
+        &[Visibility::All, Visibility::Private, Visibility::Selected]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Visibility::Noop)
     }
@@ -7627,7 +7849,7 @@ pub struct OrgHook {
 /**
  * The type of GitHub user that can comment, open issues, or create pull requests while the interaction limit is in effect. Can be one of: `existing_users`, `contributors_only`, `collaborators_only`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum InteractionGroup {
     #[serde(rename = "collaborators_only")]
     CollaboratorsOnly,
@@ -7659,7 +7881,28 @@ impl Default for InteractionGroup {
         InteractionGroup::Noop
     }
 }
+
 impl InteractionGroup {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InteractionGroup::CollaboratorsOnly => "collaborators_only",
+            InteractionGroup::ContributorsOnly => "contributors_only",
+            InteractionGroup::ExistingUsers => "existing_users",
+            InteractionGroup::Noop => "",
+            InteractionGroup::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [InteractionGroup] {
+        &[
+            InteractionGroup::CollaboratorsOnly,
+            InteractionGroup::ContributorsOnly,
+            InteractionGroup::ExistingUsers,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, InteractionGroup::Noop)
     }
@@ -7690,7 +7933,7 @@ pub struct InteractionLimits {
 /**
  * The duration of the interaction restriction. Can be one of: `one_day`, `three_days`, `one_week`, `one_month`, `six_months`. Default: `one_day`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum InteractionExpiry {
     #[serde(rename = "one_day")]
     OneDay,
@@ -7728,7 +7971,32 @@ impl Default for InteractionExpiry {
         InteractionExpiry::Noop
     }
 }
+
 impl InteractionExpiry {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InteractionExpiry::OneDay => "one_day",
+            InteractionExpiry::OneMonth => "one_month",
+            InteractionExpiry::OneWeek => "one_week",
+            InteractionExpiry::SixMonths => "six_months",
+            InteractionExpiry::ThreeDays => "three_days",
+            InteractionExpiry::Noop => "",
+            InteractionExpiry::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [InteractionExpiry] {
+        &[
+            InteractionExpiry::OneDay,
+            InteractionExpiry::OneMonth,
+            InteractionExpiry::OneWeek,
+            InteractionExpiry::SixMonths,
+            InteractionExpiry::ThreeDays,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, InteractionExpiry::Noop)
     }
@@ -7936,7 +8204,7 @@ pub struct Team {
 /**
  * The state of the member in the organization. The `pending` state indicates the user has not yet accepted an invitation.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum OrgMembershipState {
     #[serde(rename = "active")]
     Active,
@@ -7965,7 +8233,23 @@ impl Default for OrgMembershipState {
         OrgMembershipState::Noop
     }
 }
+
 impl OrgMembershipState {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrgMembershipState::Active => "active",
+            OrgMembershipState::Pending => "pending",
+            OrgMembershipState::Noop => "",
+            OrgMembershipState::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [OrgMembershipState] {
+        &[OrgMembershipState::Active, OrgMembershipState::Pending]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, OrgMembershipState::Noop)
     }
@@ -7974,7 +8258,7 @@ impl OrgMembershipState {
 /**
  * The user's membership type in the organization.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Role {
     #[serde(rename = "admin")]
     Admin,
@@ -8006,7 +8290,24 @@ impl Default for Role {
         Role::Noop
     }
 }
+
 impl Role {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Admin => "admin",
+            Role::BillingManager => "billing_manager",
+            Role::Member => "member",
+            Role::Noop => "",
+            Role::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Role] {
+        &[Role::Admin, Role::BillingManager, Role::Member]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Role::Noop)
     }
@@ -8143,7 +8444,7 @@ pub struct Migration {
     pub url: Option<url::Url>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PackageType {
     #[serde(rename = "container")]
     Container,
@@ -8184,13 +8485,40 @@ impl Default for PackageType {
         PackageType::Noop
     }
 }
+
 impl PackageType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PackageType::Container => "container",
+            PackageType::Docker => "docker",
+            PackageType::Maven => "maven",
+            PackageType::Npm => "npm",
+            PackageType::Nuget => "nuget",
+            PackageType::Rubygems => "rubygems",
+            PackageType::Noop => "",
+            PackageType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PackageType] {
+        &[
+            PackageType::Container,
+            PackageType::Docker,
+            PackageType::Maven,
+            PackageType::Npm,
+            PackageType::Nuget,
+            PackageType::Rubygems,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PackageType::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PackageVisibility {
     #[serde(rename = "private")]
     Private,
@@ -8219,7 +8547,23 @@ impl Default for PackageVisibility {
         PackageVisibility::Noop
     }
 }
+
 impl PackageVisibility {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PackageVisibility::Private => "private",
+            PackageVisibility::Public => "public",
+            PackageVisibility::Noop => "",
+            PackageVisibility::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PackageVisibility] {
+        &[PackageVisibility::Private, PackageVisibility::Public]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PackageVisibility::Noop)
     }
@@ -8389,7 +8733,7 @@ pub struct PackageVersion {
 /**
  * The baseline permission that all organization members have on this project. Only present if owner is an organization.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum OrganizationPermission {
     #[serde(rename = "admin")]
     Admin,
@@ -8424,7 +8768,30 @@ impl Default for OrganizationPermission {
         OrganizationPermission::Noop
     }
 }
+
 impl OrganizationPermission {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrganizationPermission::Admin => "admin",
+            OrganizationPermission::None => "none",
+            OrganizationPermission::Read => "read",
+            OrganizationPermission::Write => "write",
+            OrganizationPermission::Noop => "",
+            OrganizationPermission::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [OrganizationPermission] {
+        &[
+            OrganizationPermission::Admin,
+            OrganizationPermission::None,
+            OrganizationPermission::Read,
+            OrganizationPermission::Write,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, OrganizationPermission::Noop)
     }
@@ -8573,7 +8940,7 @@ pub struct GroupMapping {
 /**
  * The level of privacy this team should have
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Privacy {
     #[serde(rename = "closed")]
     Closed,
@@ -8602,7 +8969,23 @@ impl Default for Privacy {
         Privacy::Noop
     }
 }
+
 impl Privacy {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Privacy::Closed => "closed",
+            Privacy::Secret => "secret",
+            Privacy::Noop => "",
+            Privacy::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Privacy] {
+        &[Privacy::Closed, Privacy::Secret]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Privacy::Noop)
     }
@@ -8902,7 +9285,7 @@ pub struct TeamDiscussionComment {
 /**
  * The reaction to use
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Content {
     #[serde(rename = "+1")]
     PlusOne,
@@ -8949,15 +9332,46 @@ impl Default for Content {
         Content::Noop
     }
 }
-impl Content {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Content::Noop)
-    }
-}
 
-/// Reactions to conversations provide a way to help people express their feelings more simply and effectively.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Reaction {
+impl Content {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Content::PlusOne => "+1",
+            Content::MinusOne => "-1",
+            Content::Confused => "confused",
+            Content::Eyes => "eyes",
+            Content::Heart => "heart",
+            Content::Hooray => "hooray",
+            Content::Laugh => "laugh",
+            Content::Rocket => "rocket",
+            Content::Noop => "",
+            Content::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Content] {
+        &[
+            Content::PlusOne,
+            Content::MinusOne,
+            Content::Confused,
+            Content::Eyes,
+            Content::Heart,
+            Content::Hooray,
+            Content::Laugh,
+            Content::Rocket,
+        ]
+    }
+
+    pub fn is_noop(&self) -> bool {
+        matches!(self, Content::Noop)
+    }
+}
+
+/// Reactions to conversations provide a way to help people express their feelings more simply and effectively.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Reaction {
     /**
      * The reaction to use
      */
@@ -8988,7 +9402,7 @@ pub struct Reaction {
 /**
  * The role of the user in the team.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum TeamMembershipRole {
     #[serde(rename = "maintainer")]
     Maintainer,
@@ -9015,6 +9429,22 @@ impl Default for TeamMembershipRole {
     }
 }
 
+impl TeamMembershipRole {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TeamMembershipRole::Maintainer => "maintainer",
+            TeamMembershipRole::Member => "member",
+            TeamMembershipRole::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [TeamMembershipRole] {
+        &[TeamMembershipRole::Maintainer, TeamMembershipRole::Member]
+    }
+}
+
 /// Team Membership
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TeamMembership {
@@ -9933,7 +10363,7 @@ pub struct FullRepositoryPermissions {
     pub push: bool,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Status {
     #[serde(rename = "disabled")]
     Disabled,
@@ -9962,7 +10392,23 @@ impl Default for Status {
         Status::Noop
     }
 }
+
 impl Status {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Status::Disabled => "disabled",
+            Status::Enabled => "enabled",
+            Status::Noop => "",
+            Status::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Status] {
+        &[Status::Disabled, Status::Enabled]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Status::Noop)
     }
@@ -10602,7 +11048,7 @@ pub struct Artifact {
 /**
  * The phase of the lifecycle that the job is currently in.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum JobStatus {
     #[serde(rename = "completed")]
     Completed,
@@ -10634,7 +11080,28 @@ impl Default for JobStatus {
         JobStatus::Noop
     }
 }
+
 impl JobStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            JobStatus::Completed => "completed",
+            JobStatus::InProgress => "in_progress",
+            JobStatus::Queued => "queued",
+            JobStatus::Noop => "",
+            JobStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [JobStatus] {
+        &[
+            JobStatus::Completed,
+            JobStatus::InProgress,
+            JobStatus::Queued,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, JobStatus::Noop)
     }
@@ -11128,7 +11595,7 @@ pub struct Environments {
 /**
  * Whether deployment to the environment(s) was approved or rejected
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum EnvironmentApprovalState {
     #[serde(rename = "approved")]
     Approved,
@@ -11157,7 +11624,26 @@ impl Default for EnvironmentApprovalState {
         EnvironmentApprovalState::Noop
     }
 }
+
 impl EnvironmentApprovalState {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EnvironmentApprovalState::Approved => "approved",
+            EnvironmentApprovalState::Rejected => "rejected",
+            EnvironmentApprovalState::Noop => "",
+            EnvironmentApprovalState::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [EnvironmentApprovalState] {
+        &[
+            EnvironmentApprovalState::Approved,
+            EnvironmentApprovalState::Rejected,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, EnvironmentApprovalState::Noop)
     }
@@ -11196,7 +11682,7 @@ pub struct EnvironmentApproval {
 /**
  * The type of reviewer. Must be one of: `User` or `Team`
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum DeploymentReviewerType {
     #[serde(rename = "Team")]
     Team,
@@ -11225,7 +11711,23 @@ impl Default for DeploymentReviewerType {
         DeploymentReviewerType::Noop
     }
 }
+
 impl DeploymentReviewerType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DeploymentReviewerType::Team => "Team",
+            DeploymentReviewerType::User => "User",
+            DeploymentReviewerType::Noop => "",
+            DeploymentReviewerType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [DeploymentReviewerType] {
+        &[DeploymentReviewerType::Team, DeploymentReviewerType::User]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, DeploymentReviewerType::Noop)
     }
@@ -11265,22 +11767,39 @@ pub struct Environment {
     pub url: String,
 }
 
-/// All of the following types are flattened into one object:
+/// All of the following types:
 ///
 /// - `SimpleUser`
 /// - `Team`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ReviewerAnyOf {
+#[serde(untagged)]
+pub enum ReviewerAnyOf {
     /**
      * Simple User
      */
-    #[serde(flatten)]
-    pub simple_user: SimpleUser,
+    SimpleUser(SimpleUser),
     /**
      * Groups of organization members that gives permissions on specified repositories.
      */
-    #[serde(flatten)]
-    pub team: Team,
+    Team(Team),
+}
+
+impl ReviewerAnyOf {
+    pub fn simple_user(&self) -> Option<&SimpleUser> {
+        if let ReviewerAnyOf::SimpleUser(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn team(&self) -> Option<&Team> {
+        if let ReviewerAnyOf::Team(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -11539,7 +12058,7 @@ pub struct ActionsSecret {
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum WorkflowState {
     #[serde(rename = "active")]
     Active,
@@ -11577,7 +12096,32 @@ impl Default for WorkflowState {
         WorkflowState::Noop
     }
 }
+
 impl WorkflowState {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WorkflowState::Active => "active",
+            WorkflowState::Deleted => "deleted",
+            WorkflowState::DisabledFork => "disabled_fork",
+            WorkflowState::DisabledInactivity => "disabled_inactivity",
+            WorkflowState::DisabledManually => "disabled_manually",
+            WorkflowState::Noop => "",
+            WorkflowState::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [WorkflowState] {
+        &[
+            WorkflowState::Active,
+            WorkflowState::Deleted,
+            WorkflowState::DisabledFork,
+            WorkflowState::DisabledInactivity,
+            WorkflowState::DisabledManually,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, WorkflowState::Noop)
     }
@@ -12875,7 +13419,7 @@ pub struct DeploymentSimple {
     pub url: Option<url::Url>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Conclusion {
     #[serde(rename = "action_required")]
     ActionRequired,
@@ -12919,7 +13463,36 @@ impl Default for Conclusion {
         Conclusion::Noop
     }
 }
+
 impl Conclusion {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Conclusion::ActionRequired => "action_required",
+            Conclusion::Cancelled => "cancelled",
+            Conclusion::Failure => "failure",
+            Conclusion::Neutral => "neutral",
+            Conclusion::Skipped => "skipped",
+            Conclusion::Success => "success",
+            Conclusion::TimedOut => "timed_out",
+            Conclusion::Noop => "",
+            Conclusion::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Conclusion] {
+        &[
+            Conclusion::ActionRequired,
+            Conclusion::Cancelled,
+            Conclusion::Failure,
+            Conclusion::Neutral,
+            Conclusion::Skipped,
+            Conclusion::Success,
+            Conclusion::TimedOut,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Conclusion::Noop)
     }
@@ -13259,7 +13832,7 @@ pub struct CheckSuitePreference {
 /**
  * State of a code scanning alert.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum CodeScanningAlertState {
     #[serde(rename = "closed")]
     Closed,
@@ -13294,7 +13867,30 @@ impl Default for CodeScanningAlertState {
         CodeScanningAlertState::Noop
     }
 }
+
 impl CodeScanningAlertState {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CodeScanningAlertState::Closed => "closed",
+            CodeScanningAlertState::Dismissed => "dismissed",
+            CodeScanningAlertState::Fixed => "fixed",
+            CodeScanningAlertState::Open => "open",
+            CodeScanningAlertState::Noop => "",
+            CodeScanningAlertState::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [CodeScanningAlertState] {
+        &[
+            CodeScanningAlertState::Closed,
+            CodeScanningAlertState::Dismissed,
+            CodeScanningAlertState::Fixed,
+            CodeScanningAlertState::Open,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, CodeScanningAlertState::Noop)
     }
@@ -13303,7 +13899,7 @@ impl CodeScanningAlertState {
 /**
  * **Required when the state is dismissed.** The reason for dismissing or closing the alert. Can be one of: `false positive`, `won't fix`, and `used in tests`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum CodeScanningAlertDismissedReason {
     #[serde(rename = "false positive")]
     FalsePositive,
@@ -13335,7 +13931,28 @@ impl Default for CodeScanningAlertDismissedReason {
         CodeScanningAlertDismissedReason::Noop
     }
 }
+
 impl CodeScanningAlertDismissedReason {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CodeScanningAlertDismissedReason::FalsePositive => "false positive",
+            CodeScanningAlertDismissedReason::UsedInTests => "used in tests",
+            CodeScanningAlertDismissedReason::WonTFix => "won't fix",
+            CodeScanningAlertDismissedReason::Noop => "",
+            CodeScanningAlertDismissedReason::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [CodeScanningAlertDismissedReason] {
+        &[
+            CodeScanningAlertDismissedReason::FalsePositive,
+            CodeScanningAlertDismissedReason::UsedInTests,
+            CodeScanningAlertDismissedReason::WonTFix,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, CodeScanningAlertDismissedReason::Noop)
     }
@@ -13344,7 +13961,7 @@ impl CodeScanningAlertDismissedReason {
 /**
  * The severity of the alert.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Severity {
     #[serde(rename = "error")]
     Error,
@@ -13379,7 +13996,30 @@ impl Default for Severity {
         Severity::Noop
     }
 }
+
 impl Severity {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::None => "none",
+            Severity::Note => "note",
+            Severity::Warning => "warning",
+            Severity::Noop => "",
+            Severity::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Severity] {
+        &[
+            Severity::Error,
+            Severity::None,
+            Severity::Note,
+            Severity::Warning,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Severity::Noop)
     }
@@ -13484,7 +14124,7 @@ pub struct CodeScanningAlertLocation {
 /**
  * A classification of the file. For example to identify it as generated.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum CodeScanningAlertClassification {
     #[serde(rename = "generated")]
     Generated,
@@ -13519,7 +14159,30 @@ impl Default for CodeScanningAlertClassification {
         CodeScanningAlertClassification::Noop
     }
 }
+
 impl CodeScanningAlertClassification {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CodeScanningAlertClassification::Generated => "generated",
+            CodeScanningAlertClassification::Library => "library",
+            CodeScanningAlertClassification::Source => "source",
+            CodeScanningAlertClassification::Test => "test",
+            CodeScanningAlertClassification::Noop => "",
+            CodeScanningAlertClassification::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [CodeScanningAlertClassification] {
+        &[
+            CodeScanningAlertClassification::Generated,
+            CodeScanningAlertClassification::Library,
+            CodeScanningAlertClassification::Source,
+            CodeScanningAlertClassification::Test,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, CodeScanningAlertClassification::Noop)
     }
@@ -13656,7 +14319,7 @@ pub struct CodeScanningAlertItems {
 /**
  * The security severity of the alert.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SecuritySeverityLevel {
     #[serde(rename = "critical")]
     Critical,
@@ -13691,7 +14354,30 @@ impl Default for SecuritySeverityLevel {
         SecuritySeverityLevel::Noop
     }
 }
+
 impl SecuritySeverityLevel {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SecuritySeverityLevel::Critical => "critical",
+            SecuritySeverityLevel::High => "high",
+            SecuritySeverityLevel::Low => "low",
+            SecuritySeverityLevel::Medium => "medium",
+            SecuritySeverityLevel::Noop => "",
+            SecuritySeverityLevel::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SecuritySeverityLevel] {
+        &[
+            SecuritySeverityLevel::Critical,
+            SecuritySeverityLevel::High,
+            SecuritySeverityLevel::Low,
+            SecuritySeverityLevel::Medium,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SecuritySeverityLevel::Noop)
     }
@@ -13816,7 +14502,7 @@ pub struct CodeScanningAlert {
 /**
  * Sets the state of the code scanning alert. Can be one of `open` or `dismissed`. You must provide `dismissed_reason` when you set the state to `dismissed`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum CodeScanningAlertSetState {
     #[serde(rename = "dismissed")]
     Dismissed,
@@ -13845,7 +14531,26 @@ impl Default for CodeScanningAlertSetState {
         CodeScanningAlertSetState::Noop
     }
 }
+
 impl CodeScanningAlertSetState {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CodeScanningAlertSetState::Dismissed => "dismissed",
+            CodeScanningAlertSetState::Open => "open",
+            CodeScanningAlertSetState::Noop => "",
+            CodeScanningAlertSetState::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [CodeScanningAlertSetState] {
+        &[
+            CodeScanningAlertSetState::Dismissed,
+            CodeScanningAlertSetState::Open,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, CodeScanningAlertSetState::Noop)
     }
@@ -13983,7 +14688,7 @@ pub struct CodeScanningSarifsReceipt {
 /**
  * `pending` files have not yet been processed, while `complete` means all results in the SARIF have been stored.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ProcessingStatus {
     #[serde(rename = "complete")]
     Complete,
@@ -14012,7 +14717,23 @@ impl Default for ProcessingStatus {
         ProcessingStatus::Noop
     }
 }
+
 impl ProcessingStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProcessingStatus::Complete => "complete",
+            ProcessingStatus::Pending => "pending",
+            ProcessingStatus::Noop => "",
+            ProcessingStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ProcessingStatus] {
+        &[ProcessingStatus::Complete, ProcessingStatus::Pending]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ProcessingStatus::Noop)
     }
@@ -14166,7 +14887,7 @@ pub struct Collaborator {
 /**
  * The permission associated with the invitation.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum RepositoryInvitationPermissions {
     #[serde(rename = "admin")]
     Admin,
@@ -14204,7 +14925,32 @@ impl Default for RepositoryInvitationPermissions {
         RepositoryInvitationPermissions::Noop
     }
 }
+
 impl RepositoryInvitationPermissions {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RepositoryInvitationPermissions::Admin => "admin",
+            RepositoryInvitationPermissions::Maintain => "maintain",
+            RepositoryInvitationPermissions::Read => "read",
+            RepositoryInvitationPermissions::Triage => "triage",
+            RepositoryInvitationPermissions::Write => "write",
+            RepositoryInvitationPermissions::Noop => "",
+            RepositoryInvitationPermissions::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [RepositoryInvitationPermissions] {
+        &[
+            RepositoryInvitationPermissions::Admin,
+            RepositoryInvitationPermissions::Maintain,
+            RepositoryInvitationPermissions::Read,
+            RepositoryInvitationPermissions::Triage,
+            RepositoryInvitationPermissions::Write,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, RepositoryInvitationPermissions::Noop)
     }
@@ -14405,7 +15151,7 @@ pub struct Link {
 /**
  * The merge method to use.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum MergeMethod {
     #[serde(rename = "merge")]
     Merge,
@@ -14437,7 +15183,24 @@ impl Default for MergeMethod {
         MergeMethod::Noop
     }
 }
+
 impl MergeMethod {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MergeMethod::Merge => "merge",
+            MergeMethod::Rebase => "rebase",
+            MergeMethod::Squash => "squash",
+            MergeMethod::Noop => "",
+            MergeMethod::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [MergeMethod] {
+        &[MergeMethod::Merge, MergeMethod::Rebase, MergeMethod::Squash]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, MergeMethod::Noop)
     }
@@ -15150,7 +15913,7 @@ pub struct DiffEntry {
     pub status: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum CommitComparisonStatus {
     #[serde(rename = "ahead")]
     Ahead,
@@ -15185,7 +15948,30 @@ impl Default for CommitComparisonStatus {
         CommitComparisonStatus::Noop
     }
 }
+
 impl CommitComparisonStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CommitComparisonStatus::Ahead => "ahead",
+            CommitComparisonStatus::Behind => "behind",
+            CommitComparisonStatus::Diverged => "diverged",
+            CommitComparisonStatus::Identical => "identical",
+            CommitComparisonStatus::Noop => "",
+            CommitComparisonStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [CommitComparisonStatus] {
+        &[
+            CommitComparisonStatus::Ahead,
+            CommitComparisonStatus::Behind,
+            CommitComparisonStatus::Diverged,
+            CommitComparisonStatus::Identical,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, CommitComparisonStatus::Noop)
     }
@@ -16040,7 +16826,7 @@ pub struct Contributor {
 /**
  * The state of the status.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum DeploymentStatusState {
     #[serde(rename = "error")]
     Error,
@@ -16084,9 +16870,38 @@ impl Default for DeploymentStatusState {
         DeploymentStatusState::Noop
     }
 }
+
 impl DeploymentStatusState {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, DeploymentStatusState::Noop)
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DeploymentStatusState::Error => "error",
+            DeploymentStatusState::Failure => "failure",
+            DeploymentStatusState::InProgress => "in_progress",
+            DeploymentStatusState::Inactive => "inactive",
+            DeploymentStatusState::Pending => "pending",
+            DeploymentStatusState::Queued => "queued",
+            DeploymentStatusState::Success => "success",
+            DeploymentStatusState::Noop => "",
+            DeploymentStatusState::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [DeploymentStatusState] {
+        &[
+            DeploymentStatusState::Error,
+            DeploymentStatusState::Failure,
+            DeploymentStatusState::InProgress,
+            DeploymentStatusState::Inactive,
+            DeploymentStatusState::Pending,
+            DeploymentStatusState::Queued,
+            DeploymentStatusState::Success,
+        ]
+    }
+
+    pub fn is_noop(&self) -> bool {
+        matches!(self, DeploymentStatusState::Noop)
     }
 }
 
@@ -16281,19 +17096,42 @@ pub struct ProtectionRulesDataType {
     pub type_: String,
 }
 
-/// All of the following types are flattened into one object:
+/// All of the following types:
 ///
 /// - `ProtectionRules`
 /// - `ProtectionRulesData`
 /// - `ProtectionRulesDataType`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ProtectionRulesAnyOf {
-    #[serde(flatten)]
-    pub protection_rules: ProtectionRules,
-    #[serde(flatten)]
-    pub protection_rules_data: ProtectionRulesData,
-    #[serde(flatten)]
-    pub protection_rules_data_type: ProtectionRulesDataType,
+#[serde(untagged)]
+pub enum ProtectionRulesAnyOf {
+    ProtectionRules(ProtectionRules),
+    ProtectionRulesData(ProtectionRulesData),
+    ProtectionRulesDataType(ProtectionRulesDataType),
+}
+
+impl ProtectionRulesAnyOf {
+    pub fn protection_rules(&self) -> Option<&ProtectionRules> {
+        if let ProtectionRulesAnyOf::ProtectionRules(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn protection_rules_data(&self) -> Option<&ProtectionRulesData> {
+        if let ProtectionRulesAnyOf::ProtectionRulesData(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn protection_rules_data_type(&self) -> Option<&ProtectionRulesDataType> {
+        if let ProtectionRulesAnyOf::ProtectionRulesDataType(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
 }
 
 /// Details of a deployment environment
@@ -16832,7 +17670,7 @@ pub struct Hook {
     pub url: Option<url::Url>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ImportStatus {
     #[serde(rename = "auth")]
     Auth,
@@ -16903,7 +17741,54 @@ impl Default for ImportStatus {
         ImportStatus::Noop
     }
 }
+
 impl ImportStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImportStatus::Auth => "auth",
+            ImportStatus::AuthFailed => "auth_failed",
+            ImportStatus::Choose => "choose",
+            ImportStatus::Complete => "complete",
+            ImportStatus::Detecting => "detecting",
+            ImportStatus::DetectionFoundMultiple => "detection_found_multiple",
+            ImportStatus::DetectionFoundNothing => "detection_found_nothing",
+            ImportStatus::DetectionNeedsAuth => "detection_needs_auth",
+            ImportStatus::Error => "error",
+            ImportStatus::Importing => "importing",
+            ImportStatus::Mapping => "mapping",
+            ImportStatus::None => "none",
+            ImportStatus::Pushing => "pushing",
+            ImportStatus::Setup => "setup",
+            ImportStatus::Unknown => "unknown",
+            ImportStatus::WaitingToPush => "waiting_to_push",
+            ImportStatus::Noop => "",
+            ImportStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ImportStatus] {
+        &[
+            ImportStatus::Auth,
+            ImportStatus::AuthFailed,
+            ImportStatus::Choose,
+            ImportStatus::Complete,
+            ImportStatus::Detecting,
+            ImportStatus::DetectionFoundMultiple,
+            ImportStatus::DetectionFoundNothing,
+            ImportStatus::DetectionNeedsAuth,
+            ImportStatus::Error,
+            ImportStatus::Importing,
+            ImportStatus::Mapping,
+            ImportStatus::None,
+            ImportStatus::Pushing,
+            ImportStatus::Setup,
+            ImportStatus::Unknown,
+            ImportStatus::WaitingToPush,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ImportStatus::Noop)
     }
@@ -18153,7 +19038,7 @@ pub struct ConvertedNoteIssueEvent {
     pub url: String,
 }
 
-/// All of the following types are flattened into one object:
+/// All of the following types:
 ///
 /// - `LabeledIssueEvent`
 /// - `LabeledIssueEvent`
@@ -18170,68 +19055,145 @@ pub struct ConvertedNoteIssueEvent {
 /// - `MovedColumnInProjectIssueEvent`
 /// - `RemovedFromProjectIssueEvent`
 /// - `ConvertedNoteIssueEvent`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct IssueEventAnyOf {
+#[serde(untagged)]
+pub enum IssueEventAnyOf {
     /**
      * Labeled Issue Event
      */
-    #[serde(flatten)]
-    pub labeled_issue_event: LabeledIssueEvent,
+    LabeledIssueEvent(LabeledIssueEvent),
     /**
      * Assigned Issue Event
      */
-    #[serde(flatten)]
-    pub assigned_issue_event: AssignedIssueEvent,
+    AssignedIssueEvent(AssignedIssueEvent),
     /**
      * Milestoned Issue Event
      */
-    #[serde(flatten)]
-    pub milestoned_issue_event: MilestonedIssueEvent,
+    MilestonedIssueEvent(MilestonedIssueEvent),
     /**
      * Renamed Issue Event
      */
-    #[serde(flatten)]
-    pub renamed_issue_event: RenamedIssueEvent,
+    RenamedIssueEvent(RenamedIssueEvent),
     /**
      * Review Requested Issue Event
      */
-    #[serde(flatten)]
-    pub review_requested_issue_event: ReviewRequestedIssueEvent,
+    ReviewRequestedIssueEvent(ReviewRequestedIssueEvent),
     /**
      * Review Request Removed Issue Event
      */
-    #[serde(flatten)]
-    pub review_request_removed_issue_event: ReviewRequestRemovedIssueEvent,
+    ReviewRequestRemovedIssueEvent(ReviewRequestRemovedIssueEvent),
     /**
      * Review Dismissed Issue Event
      */
-    #[serde(flatten)]
-    pub review_dismissed_issue_event: ReviewDismissedIssueEvent,
+    ReviewDismissedIssueEvent(ReviewDismissedIssueEvent),
     /**
      * Locked Issue Event
      */
-    #[serde(flatten)]
-    pub locked_issue_event: LockedIssueEvent,
+    LockedIssueEvent(LockedIssueEvent),
     /**
      * Added to Project Issue Event
      */
-    #[serde(flatten)]
-    pub added_project_issue_event: AddedProjectIssueEvent,
+    AddedProjectIssueEvent(AddedProjectIssueEvent),
     /**
      * Moved Column in Project Issue Event
      */
-    #[serde(flatten)]
-    pub moved_column_in_project_issue_event: MovedColumnInProjectIssueEvent,
+    MovedColumnInProjectIssueEvent(MovedColumnInProjectIssueEvent),
     /**
      * Removed from Project Issue Event
      */
-    #[serde(flatten)]
-    pub removed_from_project_issue_event: RemovedFromProjectIssueEvent,
+    RemovedFromProjectIssueEvent(RemovedFromProjectIssueEvent),
     /**
      * Converted Note to Issue Issue Event
      */
-    #[serde(flatten)]
-    pub converted_note_issue_event: ConvertedNoteIssueEvent,
+    ConvertedNoteIssueEvent(ConvertedNoteIssueEvent),
+}
+
+impl IssueEventAnyOf {
+    pub fn added_project_issue_event(&self) -> Option<&AddedProjectIssueEvent> {
+        if let IssueEventAnyOf::AddedProjectIssueEvent(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn assigned_issue_event(&self) -> Option<&AssignedIssueEvent> {
+        if let IssueEventAnyOf::AssignedIssueEvent(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn converted_note_issue_event(&self) -> Option<&ConvertedNoteIssueEvent> {
+        if let IssueEventAnyOf::ConvertedNoteIssueEvent(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn labeled_issue_event(&self) -> Option<&LabeledIssueEvent> {
+        if let IssueEventAnyOf::LabeledIssueEvent(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn locked_issue_event(&self) -> Option<&LockedIssueEvent> {
+        if let IssueEventAnyOf::LockedIssueEvent(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn milestoned_issue_event(&self) -> Option<&MilestonedIssueEvent> {
+        if let IssueEventAnyOf::MilestonedIssueEvent(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn moved_column_in_project_issue_event(&self) -> Option<&MovedColumnInProjectIssueEvent> {
+        if let IssueEventAnyOf::MovedColumnInProjectIssueEvent(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn removed_from_project_issue_event(&self) -> Option<&RemovedFromProjectIssueEvent> {
+        if let IssueEventAnyOf::RemovedFromProjectIssueEvent(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn renamed_issue_event(&self) -> Option<&RenamedIssueEvent> {
+        if let IssueEventAnyOf::RenamedIssueEvent(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn review_dismissed_issue_event(&self) -> Option<&ReviewDismissedIssueEvent> {
+        if let IssueEventAnyOf::ReviewDismissedIssueEvent(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn review_request_removed_issue_event(&self) -> Option<&ReviewRequestRemovedIssueEvent> {
+        if let IssueEventAnyOf::ReviewRequestRemovedIssueEvent(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn review_requested_issue_event(&self) -> Option<&ReviewRequestedIssueEvent> {
+        if let IssueEventAnyOf::ReviewRequestedIssueEvent(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
 }
 
 /// Timeline Comment Event
@@ -18560,7 +19522,7 @@ pub struct PullRequestReviewCommentLinks {
 /**
  * The side of the diff to which the comment applies. The side of the last line of the range for a multi-line comment
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Side {
     #[serde(rename = "LEFT")]
     Left,
@@ -18587,6 +19549,22 @@ impl Default for Side {
     }
 }
 
+impl Side {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Side::Left => "LEFT",
+            Side::Right => "RIGHT",
+            Side::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Side] {
+        &[Side::Left, Side::Right]
+    }
+}
+
 /// Pull Request Review Comments are comments on a portion of the Pull Request's diff.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PullRequestReviewComment {
@@ -19023,7 +20001,7 @@ pub struct PagesSourceHash {
     pub path: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PagesHttpsCertificateState {
     #[serde(rename = "approved")]
     Approved,
@@ -19082,7 +20060,46 @@ impl Default for PagesHttpsCertificateState {
         PagesHttpsCertificateState::Noop
     }
 }
+
 impl PagesHttpsCertificateState {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PagesHttpsCertificateState::Approved => "approved",
+            PagesHttpsCertificateState::AuthorizationCreated => "authorization_created",
+            PagesHttpsCertificateState::AuthorizationPending => "authorization_pending",
+            PagesHttpsCertificateState::AuthorizationRevoked => "authorization_revoked",
+            PagesHttpsCertificateState::Authorized => "authorized",
+            PagesHttpsCertificateState::BadAuthz => "bad_authz",
+            PagesHttpsCertificateState::DestroyPending => "destroy_pending",
+            PagesHttpsCertificateState::DnsChanged => "dns_changed",
+            PagesHttpsCertificateState::Errored => "errored",
+            PagesHttpsCertificateState::Issued => "issued",
+            PagesHttpsCertificateState::New => "new",
+            PagesHttpsCertificateState::Uploaded => "uploaded",
+            PagesHttpsCertificateState::Noop => "",
+            PagesHttpsCertificateState::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PagesHttpsCertificateState] {
+        &[
+            PagesHttpsCertificateState::Approved,
+            PagesHttpsCertificateState::AuthorizationCreated,
+            PagesHttpsCertificateState::AuthorizationPending,
+            PagesHttpsCertificateState::AuthorizationRevoked,
+            PagesHttpsCertificateState::Authorized,
+            PagesHttpsCertificateState::BadAuthz,
+            PagesHttpsCertificateState::DestroyPending,
+            PagesHttpsCertificateState::DnsChanged,
+            PagesHttpsCertificateState::Errored,
+            PagesHttpsCertificateState::Issued,
+            PagesHttpsCertificateState::New,
+            PagesHttpsCertificateState::Uploaded,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PagesHttpsCertificateState::Noop)
     }
@@ -19118,7 +20135,7 @@ pub struct PagesHttpsCertificate {
 /**
  * The status of the most recent build of the Page.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PageStatus {
     #[serde(rename = "building")]
     Building,
@@ -19150,7 +20167,24 @@ impl Default for PageStatus {
         PageStatus::Noop
     }
 }
+
 impl PageStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PageStatus::Building => "building",
+            PageStatus::Built => "built",
+            PageStatus::Errored => "errored",
+            PageStatus::Noop => "",
+            PageStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PageStatus] {
+        &[PageStatus::Building, PageStatus::Built, PageStatus::Errored]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PageStatus::Noop)
     }
@@ -21289,7 +22323,7 @@ pub struct ReviewComment {
 /**
  * State of the release asset.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ReleaseAssetState {
     #[serde(rename = "open")]
     Open,
@@ -21318,7 +22352,23 @@ impl Default for ReleaseAssetState {
         ReleaseAssetState::Noop
     }
 }
+
 impl ReleaseAssetState {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReleaseAssetState::Open => "open",
+            ReleaseAssetState::Uploaded => "uploaded",
+            ReleaseAssetState::Noop => "",
+            ReleaseAssetState::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ReleaseAssetState] {
+        &[ReleaseAssetState::Open, ReleaseAssetState::Uploaded]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ReleaseAssetState::Noop)
     }
@@ -21550,7 +22600,7 @@ pub struct Release {
 /**
  * Sets the state of the secret scanning alert. Can be either `open` or `resolved`. You must provide `resolution` when you set the state to `resolved`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SecretScanningAlertState {
     #[serde(rename = "open")]
     Open,
@@ -21579,7 +22629,26 @@ impl Default for SecretScanningAlertState {
         SecretScanningAlertState::Noop
     }
 }
+
 impl SecretScanningAlertState {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SecretScanningAlertState::Open => "open",
+            SecretScanningAlertState::Resolved => "resolved",
+            SecretScanningAlertState::Noop => "",
+            SecretScanningAlertState::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SecretScanningAlertState] {
+        &[
+            SecretScanningAlertState::Open,
+            SecretScanningAlertState::Resolved,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SecretScanningAlertState::Noop)
     }
@@ -21588,7 +22657,7 @@ impl SecretScanningAlertState {
 /**
  * **Required when the `state` is `resolved`.** The reason for resolving the alert. Can be one of `false_positive`, `wont_fix`, `revoked`, or `used_in_tests`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SecretScanningAlertResolution {
     #[serde(rename = "false_positive")]
     FalsePositive,
@@ -21623,7 +22692,30 @@ impl Default for SecretScanningAlertResolution {
         SecretScanningAlertResolution::Noop
     }
 }
+
 impl SecretScanningAlertResolution {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SecretScanningAlertResolution::FalsePositive => "false_positive",
+            SecretScanningAlertResolution::Revoked => "revoked",
+            SecretScanningAlertResolution::UsedInTests => "used_in_tests",
+            SecretScanningAlertResolution::WontFix => "wont_fix",
+            SecretScanningAlertResolution::Noop => "",
+            SecretScanningAlertResolution::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SecretScanningAlertResolution] {
+        &[
+            SecretScanningAlertResolution::FalsePositive,
+            SecretScanningAlertResolution::Revoked,
+            SecretScanningAlertResolution::UsedInTests,
+            SecretScanningAlertResolution::WontFix,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SecretScanningAlertResolution::Noop)
     }
@@ -22351,7 +23443,7 @@ pub struct ScimUserMeta {
     pub resource_type: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Op {
     #[serde(rename = "add")]
     Add,
@@ -22383,7 +23475,24 @@ impl Default for Op {
         Op::Noop
     }
 }
+
 impl Op {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Op::Add => "add",
+            Op::Remove => "remove",
+            Op::Replace => "replace",
+            Op::Noop => "",
+            Op::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Op] {
+        &[Op::Add, Op::Remove, Op::Replace]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Op::Noop)
     }
@@ -24651,7 +25760,7 @@ pub struct KeySimple {
  *   
  *   The default is `web`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Include {
     #[serde(rename = "all")]
     All,
@@ -24683,7 +25792,24 @@ impl Default for Include {
         Include::Noop
     }
 }
+
 impl Include {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Include::All => "all",
+            Include::Git => "git",
+            Include::Web => "web",
+            Include::Noop => "",
+            Include::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Include] {
+        &[Include::All, Include::Git, Include::Web]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Include::Noop)
     }
@@ -24694,7 +25820,7 @@ impl Include {
  *   
  *   The default is `desc`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Order {
     #[serde(rename = "asc")]
     Asc,
@@ -24723,7 +25849,23 @@ impl Default for Order {
         Order::Noop
     }
 }
+
 impl Order {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Order::Asc => "asc",
+            Order::Desc => "desc",
+            Order::Noop => "",
+            Order::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Order] {
+        &[Order::Asc, Order::Desc]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Order::Noop)
     }
@@ -24732,7 +25874,7 @@ impl Order {
 /**
  * One of `created` (when the repository was starred) or `updated` (when it was last pushed to).
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Sort {
     #[serde(rename = "created")]
     Created,
@@ -24759,10 +25901,26 @@ impl Default for Sort {
     }
 }
 
+impl Sort {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Sort::Created => "created",
+            Sort::Updated => "updated",
+            Sort::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Sort] {
+        &[Sort::Created, Sort::Updated]
+    }
+}
+
 /**
  * Returns workflow runs with the check run `status` or `conclusion` that you specify. For example, a conclusion can be `success` or a status can be `in_progress`. Only GitHub can set a status of `waiting` or `requested`. For a list of the possible `status` and `conclusion` options, see "[Create a check run](https://docs.github.com/rest/reference/checks#create-a-check-run)."
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum WorkflowRunStatus {
     #[serde(rename = "action_required")]
     ActionRequired,
@@ -24824,7 +25982,48 @@ impl Default for WorkflowRunStatus {
         WorkflowRunStatus::Noop
     }
 }
+
 impl WorkflowRunStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WorkflowRunStatus::ActionRequired => "action_required",
+            WorkflowRunStatus::Cancelled => "cancelled",
+            WorkflowRunStatus::Completed => "completed",
+            WorkflowRunStatus::Failure => "failure",
+            WorkflowRunStatus::InProgress => "in_progress",
+            WorkflowRunStatus::Neutral => "neutral",
+            WorkflowRunStatus::Queued => "queued",
+            WorkflowRunStatus::Requested => "requested",
+            WorkflowRunStatus::Skipped => "skipped",
+            WorkflowRunStatus::Stale => "stale",
+            WorkflowRunStatus::Success => "success",
+            WorkflowRunStatus::TimedOut => "timed_out",
+            WorkflowRunStatus::Waiting => "waiting",
+            WorkflowRunStatus::Noop => "",
+            WorkflowRunStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [WorkflowRunStatus] {
+        &[
+            WorkflowRunStatus::ActionRequired,
+            WorkflowRunStatus::Cancelled,
+            WorkflowRunStatus::Completed,
+            WorkflowRunStatus::Failure,
+            WorkflowRunStatus::InProgress,
+            WorkflowRunStatus::Neutral,
+            WorkflowRunStatus::Queued,
+            WorkflowRunStatus::Requested,
+            WorkflowRunStatus::Skipped,
+            WorkflowRunStatus::Stale,
+            WorkflowRunStatus::Success,
+            WorkflowRunStatus::TimedOut,
+            WorkflowRunStatus::Waiting,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, WorkflowRunStatus::Noop)
     }
@@ -24886,7 +26085,7 @@ impl std::convert::From<TitleOneOf> for String {
 /**
  * Must be one of: `day`, `week`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Per {
     #[serde(rename = "day")]
     Day,
@@ -24913,6 +26112,22 @@ impl Default for Per {
     }
 }
 
+impl Per {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Per::Day => "day",
+            Per::Week => "week",
+            Per::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Per] {
+        &[Per::Day, Per::Week]
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PullsMergeResponse {
     #[serde(
@@ -25665,7 +26880,7 @@ pub struct FilesAdditionalPropertiesData {
     pub content: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Public {
     #[serde(rename = "false")]
     False,
@@ -25692,6 +26907,22 @@ impl Default for Public {
     }
 }
 
+impl Public {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Public::False => "false",
+            Public::True => "true",
+            Public::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Public] {
+        &[Public::False, Public::True]
+    }
+}
+
 /// All of the following types:
 ///
 /// - `bool`
@@ -25819,7 +27050,7 @@ pub struct AppsListInstallationReposResponse {
  *   \* `subscribed`: Issues you're subscribed to updates for  
  *   \* `all` or `repos`: All issues the authenticated user can see, regardless of participation or creation
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Filter {
     #[serde(rename = "all")]
     All,
@@ -25858,10 +27089,37 @@ impl Default for Filter {
     }
 }
 
+impl Filter {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Filter::All => "all",
+            Filter::Assigned => "assigned",
+            Filter::Created => "created",
+            Filter::Mentioned => "mentioned",
+            Filter::Repos => "repos",
+            Filter::Subscribed => "subscribed",
+            Filter::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Filter] {
+        &[
+            Filter::All,
+            Filter::Assigned,
+            Filter::Created,
+            Filter::Mentioned,
+            Filter::Repos,
+            Filter::Subscribed,
+        ]
+    }
+}
+
 /**
  * Indicates the state of the issues to return. Can be either `open`, `closed`, or `all`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum IssuesListState {
     #[serde(rename = "all")]
     All,
@@ -25891,10 +27149,31 @@ impl Default for IssuesListState {
     }
 }
 
+impl IssuesListState {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IssuesListState::All => "all",
+            IssuesListState::Closed => "closed",
+            IssuesListState::Open => "open",
+            IssuesListState::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [IssuesListState] {
+        &[
+            IssuesListState::All,
+            IssuesListState::Closed,
+            IssuesListState::Open,
+        ]
+    }
+}
+
 /**
  * What to sort results by. Can be either `created`, `updated`, `comments`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum IssuesListSort {
     #[serde(rename = "comments")]
     Comments,
@@ -25924,10 +27203,31 @@ impl Default for IssuesListSort {
     }
 }
 
+impl IssuesListSort {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IssuesListSort::Comments => "comments",
+            IssuesListSort::Created => "created",
+            IssuesListSort::Updated => "updated",
+            IssuesListSort::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [IssuesListSort] {
+        &[
+            IssuesListSort::Comments,
+            IssuesListSort::Created,
+            IssuesListSort::Updated,
+        ]
+    }
+}
+
 /**
  * The rendering mode.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Mode {
     #[serde(rename = "gfm")]
     Gfm,
@@ -25954,6 +27254,22 @@ impl Default for Mode {
     }
 }
 
+impl Mode {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Mode::Gfm => "gfm",
+            Mode::Markdown => "markdown",
+            Mode::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Mode] {
+        &[Mode::Gfm, Mode::Markdown]
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MarkdownRenderRequest {
     #[serde(
@@ -26000,7 +27316,7 @@ pub struct ActivitySetThreadSubscriptionRequest {
  *   \* `none` - only admin members can create repositories.  
  *   **Note:** This parameter is deprecated and will be removed in the future. Its return value ignores internal repositories. Using this parameter overrides values set in `members_can_create_repositories`. See the parameter deprecation notice in the operation description for details.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum MembersAllowedRepositoryCreationType {
     #[serde(rename = "all")]
     All,
@@ -26032,7 +27348,28 @@ impl Default for MembersAllowedRepositoryCreationType {
         MembersAllowedRepositoryCreationType::Noop
     }
 }
+
 impl MembersAllowedRepositoryCreationType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MembersAllowedRepositoryCreationType::All => "all",
+            MembersAllowedRepositoryCreationType::None => "none",
+            MembersAllowedRepositoryCreationType::Private => "private",
+            MembersAllowedRepositoryCreationType::Noop => "",
+            MembersAllowedRepositoryCreationType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [MembersAllowedRepositoryCreationType] {
+        &[
+            MembersAllowedRepositoryCreationType::All,
+            MembersAllowedRepositoryCreationType::None,
+            MembersAllowedRepositoryCreationType::Private,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, MembersAllowedRepositoryCreationType::Noop)
     }
@@ -26507,19 +27844,36 @@ pub struct AppsListInstallationsResponse {
     pub total_count: i64,
 }
 
-/// All of the following types are flattened into one object:
+/// All of the following types:
 ///
 /// - `Data`
 /// - `InteractionLimits`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct InteractionsGetRestrictionsResponseAnyOf {
-    #[serde(flatten)]
-    pub data: Data,
+#[serde(untagged)]
+pub enum InteractionsGetRestrictionsResponseAnyOf {
+    Data(Data),
     /**
      * Interaction limit settings.
      */
-    #[serde(flatten)]
-    pub interaction_limits: InteractionLimits,
+    InteractionLimits(InteractionLimits),
+}
+
+impl InteractionsGetRestrictionsResponseAnyOf {
+    pub fn data(&self) -> Option<&Data> {
+        if let InteractionsGetRestrictionsResponseAnyOf::Data(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn interaction_limits(&self) -> Option<&InteractionLimits> {
+        if let InteractionsGetRestrictionsResponseAnyOf::InteractionLimits(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
 }
 
 /**
@@ -26528,7 +27882,7 @@ pub struct InteractionsGetRestrictionsResponseAnyOf {
  *   \* `direct_member` - Non-owner organization members with ability to see other members and join teams by invitation.  
  *   \* `billing_manager` - Non-owner organization members with ability to manage the billing settings of your organization.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum OrgsCreateInvitationRequestRole {
     #[serde(rename = "admin")]
     Admin,
@@ -26558,6 +27912,27 @@ impl Default for OrgsCreateInvitationRequestRole {
     }
 }
 
+impl OrgsCreateInvitationRequestRole {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrgsCreateInvitationRequestRole::Admin => "admin",
+            OrgsCreateInvitationRequestRole::BillingManager => "billing_manager",
+            OrgsCreateInvitationRequestRole::DirectMember => "direct_member",
+            OrgsCreateInvitationRequestRole::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [OrgsCreateInvitationRequestRole] {
+        &[
+            OrgsCreateInvitationRequestRole::Admin,
+            OrgsCreateInvitationRequestRole::BillingManager,
+            OrgsCreateInvitationRequestRole::DirectMember,
+        ]
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OrgsCreateInvitationRequest {
     #[serde(
@@ -26596,7 +27971,7 @@ pub struct OrgsCreateInvitationRequest {
  *   \* `2fa_disabled` - Members without [two-factor authentication](https://github.com/blog/1614-two-factor-authentication) enabled. Available for organization owners.  
  *   \* `all` - All members the authenticated user can see.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum OrgsListMembersFilter {
     #[serde(rename = "2fa_disabled")]
     TwoFaDisabled,
@@ -26623,13 +27998,32 @@ impl Default for OrgsListMembersFilter {
     }
 }
 
+impl OrgsListMembersFilter {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrgsListMembersFilter::TwoFaDisabled => "2fa_disabled",
+            OrgsListMembersFilter::All => "all",
+            OrgsListMembersFilter::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [OrgsListMembersFilter] {
+        &[
+            OrgsListMembersFilter::TwoFaDisabled,
+            OrgsListMembersFilter::All,
+        ]
+    }
+}
+
 /**
  * Filter members returned by their role. Can be one of:  
  *   \* `all` - All members of the organization, regardless of role.  
  *   \* `admin` - Organization owners.  
  *   \* `member` - Non-owner organization members.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum OrgsListMembersRole {
     #[serde(rename = "admin")]
     Admin,
@@ -26659,12 +28053,33 @@ impl Default for OrgsListMembersRole {
     }
 }
 
+impl OrgsListMembersRole {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrgsListMembersRole::Admin => "admin",
+            OrgsListMembersRole::All => "all",
+            OrgsListMembersRole::Member => "member",
+            OrgsListMembersRole::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [OrgsListMembersRole] {
+        &[
+            OrgsListMembersRole::Admin,
+            OrgsListMembersRole::All,
+            OrgsListMembersRole::Member,
+        ]
+    }
+}
+
 /**
  * The role to give the user in the organization. Can be one of:  
  *   \* `admin` - The user will become an owner of the organization.  
  *   \* `member` - The user will become a non-owner member of the organization.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum OrgsSetMembershipUserRequestRole {
     #[serde(rename = "admin")]
     Admin,
@@ -26691,6 +28106,25 @@ impl Default for OrgsSetMembershipUserRequestRole {
     }
 }
 
+impl OrgsSetMembershipUserRequestRole {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrgsSetMembershipUserRequestRole::Admin => "admin",
+            OrgsSetMembershipUserRequestRole::Member => "member",
+            OrgsSetMembershipUserRequestRole::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [OrgsSetMembershipUserRequestRole] {
+        &[
+            OrgsSetMembershipUserRequestRole::Admin,
+            OrgsSetMembershipUserRequestRole::Member,
+        ]
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OrgsSetMembershipUserRequest {
     /**
@@ -26705,7 +28139,7 @@ pub struct OrgsSetMembershipUserRequest {
 /**
  * Allowed values that can be passed to the exclude param.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Exclude {
     #[serde(rename = "repositories")]
     Repositories,
@@ -26731,7 +28165,22 @@ impl Default for Exclude {
         Exclude::Noop
     }
 }
+
 impl Exclude {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Exclude::Repositories => "repositories",
+            Exclude::Noop => "",
+            Exclude::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Exclude] {
+        &[Exclude::Repositories]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Exclude::Noop)
     }
@@ -26766,7 +28215,7 @@ pub struct MigrationsStartRequest {
 /**
  * The state of the package, either active or deleted.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PackagesGetAllPackageVersionsOwnedByOrgState {
     #[serde(rename = "active")]
     Active,
@@ -26793,6 +28242,25 @@ impl Default for PackagesGetAllPackageVersionsOwnedByOrgState {
     }
 }
 
+impl PackagesGetAllPackageVersionsOwnedByOrgState {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PackagesGetAllPackageVersionsOwnedByOrgState::Active => "active",
+            PackagesGetAllPackageVersionsOwnedByOrgState::Deleted => "deleted",
+            PackagesGetAllPackageVersionsOwnedByOrgState::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PackagesGetAllPackageVersionsOwnedByOrgState] {
+        &[
+            PackagesGetAllPackageVersionsOwnedByOrgState::Active,
+            PackagesGetAllPackageVersionsOwnedByOrgState::Deleted,
+        ]
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ProjectsCreateRequest {
     #[serde(
@@ -26812,7 +28280,7 @@ pub struct ProjectsCreateRequest {
 /**
  * Specifies the types of repositories you want returned. Can be one of `all`, `public`, `private`, `forks`, `sources`, `member`, `internal`. Note: For GitHub AE, can be one of `all`, `private`, `forks`, `sources`, `member`, `internal`. Default: `all`. If your organization is associated with an enterprise account using GitHub Enterprise Cloud or GitHub Enterprise Server 2.20+, `type` can also be `internal`. However, the `internal` value is not yet supported when a GitHub App calls this API with an installation access token.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ReposListOrgType {
     #[serde(rename = "all")]
     All,
@@ -26856,7 +28324,36 @@ impl Default for ReposListOrgType {
         ReposListOrgType::Noop
     }
 }
+
 impl ReposListOrgType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReposListOrgType::All => "all",
+            ReposListOrgType::Forks => "forks",
+            ReposListOrgType::Internal => "internal",
+            ReposListOrgType::Member => "member",
+            ReposListOrgType::Private => "private",
+            ReposListOrgType::Public => "public",
+            ReposListOrgType::Sources => "sources",
+            ReposListOrgType::Noop => "",
+            ReposListOrgType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ReposListOrgType] {
+        &[
+            ReposListOrgType::All,
+            ReposListOrgType::Forks,
+            ReposListOrgType::Internal,
+            ReposListOrgType::Member,
+            ReposListOrgType::Private,
+            ReposListOrgType::Public,
+            ReposListOrgType::Sources,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ReposListOrgType::Noop)
     }
@@ -26865,7 +28362,7 @@ impl ReposListOrgType {
 /**
  * Can be one of `created`, `updated`, `pushed`, `full_name`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ReposListOrgSort {
     #[serde(rename = "created")]
     Created,
@@ -26898,11 +28395,34 @@ impl Default for ReposListOrgSort {
     }
 }
 
+impl ReposListOrgSort {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReposListOrgSort::Created => "created",
+            ReposListOrgSort::FullName => "full_name",
+            ReposListOrgSort::Pushed => "pushed",
+            ReposListOrgSort::Updated => "updated",
+            ReposListOrgSort::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ReposListOrgSort] {
+        &[
+            ReposListOrgSort::Created,
+            ReposListOrgSort::FullName,
+            ReposListOrgSort::Pushed,
+            ReposListOrgSort::Updated,
+        ]
+    }
+}
+
 /**
  * Can be `public` or `private`. If your organization is associated with an enterprise account using GitHub Enterprise Cloud or GitHub Enterprise Server 2.20+, `visibility` can also be `internal`. Note: For GitHub Enterprise Server and GitHub AE, this endpoint will only list repositories available to all users on the enterprise. For more information, see "[Creating an internal repository](https://help.github.com/en/github/creating-cloning-and-archiving-repositories/about-repository-visibility#about-internal-repositories)" in the GitHub Help documentation.  
  *   The `visibility` parameter overrides the `private` parameter when you use both parameters with the `nebula-preview` preview header.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ReposCreateInOrgRequestVisibility {
     #[serde(rename = "internal")]
     Internal,
@@ -26937,7 +28457,30 @@ impl Default for ReposCreateInOrgRequestVisibility {
         ReposCreateInOrgRequestVisibility::Noop
     }
 }
+
 impl ReposCreateInOrgRequestVisibility {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReposCreateInOrgRequestVisibility::Internal => "internal",
+            ReposCreateInOrgRequestVisibility::Private => "private",
+            ReposCreateInOrgRequestVisibility::Public => "public",
+            ReposCreateInOrgRequestVisibility::Visibility => "visibility",
+            ReposCreateInOrgRequestVisibility::Noop => "",
+            ReposCreateInOrgRequestVisibility::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ReposCreateInOrgRequestVisibility] {
+        &[
+            ReposCreateInOrgRequestVisibility::Internal,
+            ReposCreateInOrgRequestVisibility::Private,
+            ReposCreateInOrgRequestVisibility::Public,
+            ReposCreateInOrgRequestVisibility::Visibility,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ReposCreateInOrgRequestVisibility::Noop)
     }
@@ -27017,7 +28560,7 @@ pub struct ReposCreateInOrgRequest {
  *   \* `push` - team members can pull and push, but not administer newly-added repositories.  
  *   \* `admin` - team members can pull, push and administer newly-added repositories.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Permission {
     #[serde(rename = "admin")]
     Admin,
@@ -27047,6 +28590,23 @@ impl Default for Permission {
     }
 }
 
+impl Permission {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Permission::Admin => "admin",
+            Permission::Pull => "pull",
+            Permission::Push => "push",
+            Permission::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Permission] {
+        &[Permission::Admin, Permission::Pull, Permission::Push]
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TeamsCreateRequest {
     #[serde(
@@ -27184,7 +28744,7 @@ pub struct ReactionsCreateIssueRequest {
  *   \* `maintainer` - team maintainers.  
  *   \* `all` - all members of the team.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum TeamsListMembersInOrgRole {
     #[serde(rename = "all")]
     All,
@@ -27214,6 +28774,27 @@ impl Default for TeamsListMembersInOrgRole {
     }
 }
 
+impl TeamsListMembersInOrgRole {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TeamsListMembersInOrgRole::All => "all",
+            TeamsListMembersInOrgRole::Maintainer => "maintainer",
+            TeamsListMembersInOrgRole::Member => "member",
+            TeamsListMembersInOrgRole::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [TeamsListMembersInOrgRole] {
+        &[
+            TeamsListMembersInOrgRole::All,
+            TeamsListMembersInOrgRole::Maintainer,
+            TeamsListMembersInOrgRole::Member,
+        ]
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TeamsAddUpdateMembershipUserInOrgRequest {
     /**
@@ -27239,7 +28820,7 @@ pub struct ProjectsAddCollaboratorRequest {
  *     
  *   If no permission is specified, the team's `permission` attribute will be used to determine what permission to grant the team on this repository.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum TeamsAddUpdateRepoPermissionsInOrgRequestPermission {
     #[serde(rename = "admin")]
     Admin,
@@ -27277,7 +28858,32 @@ impl Default for TeamsAddUpdateRepoPermissionsInOrgRequestPermission {
         TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Noop
     }
 }
+
 impl TeamsAddUpdateRepoPermissionsInOrgRequestPermission {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Admin => "admin",
+            TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Maintain => "maintain",
+            TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Pull => "pull",
+            TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Push => "push",
+            TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Triage => "triage",
+            TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Noop => "",
+            TeamsAddUpdateRepoPermissionsInOrgRequestPermission::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [TeamsAddUpdateRepoPermissionsInOrgRequestPermission] {
+        &[
+            TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Admin,
+            TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Maintain,
+            TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Pull,
+            TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Push,
+            TeamsAddUpdateRepoPermissionsInOrgRequestPermission::Triage,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(
             self,
@@ -27497,7 +29103,7 @@ pub struct ProjectsUpdateColumnRequest {
 /**
  * Filters the project cards that are returned by the card's state. Can be one of `all`,`archived`, or `not_archived`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ArchivedState {
     #[serde(rename = "all")]
     All,
@@ -27527,6 +29133,27 @@ impl Default for ArchivedState {
     }
 }
 
+impl ArchivedState {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ArchivedState::All => "all",
+            ArchivedState::Archived => "archived",
+            ArchivedState::NotArchived => "not_archived",
+            ArchivedState::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ArchivedState] {
+        &[
+            ArchivedState::All,
+            ArchivedState::Archived,
+            ArchivedState::NotArchived,
+        ]
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ProjectsCreateCardRequest {
     #[serde(
@@ -27627,7 +29254,7 @@ pub struct ProjectsUpdateRequest {
  *   \* `direct`: Collaborators with permissions to a project, regardless of organization membership status.  
  *   \* `all`: All collaborators the authenticated user can see.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Affiliation {
     #[serde(rename = "all")]
     All,
@@ -27657,6 +29284,23 @@ impl Default for Affiliation {
     }
 }
 
+impl Affiliation {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Affiliation::All => "all",
+            Affiliation::Direct => "direct",
+            Affiliation::Outside => "outside",
+            Affiliation::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Affiliation] {
+        &[Affiliation::All, Affiliation::Direct, Affiliation::Outside]
+    }
+}
+
 /// Use the `status` property to enable or disable GitHub Advanced Security for this repository. For more information, see "[About GitHub Advanced Security](/github/getting-started-with-github/learning-about-github/about-github-advanced-security)."
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposUpdateRequestSecurityAnalysisAdvanced {
@@ -27792,7 +29436,7 @@ pub struct ActionsListWorkflowRunsResponse {
  *   \* `latest`: Returns jobs from the most recent execution of the workflow run.  
  *   \* `all`: Returns all jobs for a workflow run, including from old executions of the workflow run.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ActionsListJobsWorkflowRunFilter {
     #[serde(rename = "all")]
     All,
@@ -27819,6 +29463,25 @@ impl Default for ActionsListJobsWorkflowRunFilter {
     }
 }
 
+impl ActionsListJobsWorkflowRunFilter {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ActionsListJobsWorkflowRunFilter::All => "all",
+            ActionsListJobsWorkflowRunFilter::Latest => "latest",
+            ActionsListJobsWorkflowRunFilter::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ActionsListJobsWorkflowRunFilter] {
+        &[
+            ActionsListJobsWorkflowRunFilter::All,
+            ActionsListJobsWorkflowRunFilter::Latest,
+        ]
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ActionsListJobsWorkflowRunResponse {
     #[serde(
@@ -28382,7 +30045,7 @@ pub struct ReposRenameBranchRequest {
  * **Required if you provide `completed_at` or a `status` of `completed`**. The final conclusion of the check. Can be one of `action_required`, `cancelled`, `failure`, `neutral`, `success`, `skipped`, `stale`, or `timed_out`. When the conclusion is `action_required`, additional details should be provided on the site specified by `details_url`.  
  *   **Note:** Providing `conclusion` will automatically set the `status` parameter to `completed`. You cannot change a check run conclusion to `stale`, only GitHub can set this.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ChecksCreateRequestConclusion {
     #[serde(rename = "action_required")]
     ActionRequired,
@@ -28429,7 +30092,38 @@ impl Default for ChecksCreateRequestConclusion {
         ChecksCreateRequestConclusion::Noop
     }
 }
+
 impl ChecksCreateRequestConclusion {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChecksCreateRequestConclusion::ActionRequired => "action_required",
+            ChecksCreateRequestConclusion::Cancelled => "cancelled",
+            ChecksCreateRequestConclusion::Failure => "failure",
+            ChecksCreateRequestConclusion::Neutral => "neutral",
+            ChecksCreateRequestConclusion::Skipped => "skipped",
+            ChecksCreateRequestConclusion::Stale => "stale",
+            ChecksCreateRequestConclusion::Success => "success",
+            ChecksCreateRequestConclusion::TimedOut => "timed_out",
+            ChecksCreateRequestConclusion::Noop => "",
+            ChecksCreateRequestConclusion::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ChecksCreateRequestConclusion] {
+        &[
+            ChecksCreateRequestConclusion::ActionRequired,
+            ChecksCreateRequestConclusion::Cancelled,
+            ChecksCreateRequestConclusion::Failure,
+            ChecksCreateRequestConclusion::Neutral,
+            ChecksCreateRequestConclusion::Skipped,
+            ChecksCreateRequestConclusion::Stale,
+            ChecksCreateRequestConclusion::Success,
+            ChecksCreateRequestConclusion::TimedOut,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ChecksCreateRequestConclusion::Noop)
     }
@@ -28438,7 +30132,7 @@ impl ChecksCreateRequestConclusion {
 /**
  * The level of the annotation. Can be one of `notice`, `warning`, or `failure`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum AnnotationLevel {
     #[serde(rename = "failure")]
     Failure,
@@ -28470,7 +30164,28 @@ impl Default for AnnotationLevel {
         AnnotationLevel::Noop
     }
 }
+
 impl AnnotationLevel {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AnnotationLevel::Failure => "failure",
+            AnnotationLevel::Notice => "notice",
+            AnnotationLevel::Warning => "warning",
+            AnnotationLevel::Noop => "",
+            AnnotationLevel::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [AnnotationLevel] {
+        &[
+            AnnotationLevel::Failure,
+            AnnotationLevel::Notice,
+            AnnotationLevel::Warning,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, AnnotationLevel::Noop)
     }
@@ -29105,7 +30820,7 @@ pub struct ReposCreateDeploymentRequest {
 /**
  * Name for the target deployment environment, which can be changed when setting a deploy status. For example, `production`, `staging`, or `qa`. **Note:** This parameter requires you to use the [`application/vnd.github.flash-preview+json`](https://docs.github.com/rest/overview/api-previews#deployment-statuses) custom media type.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ReposCreateDeploymentStatusRequestEnvironment {
     #[serde(rename = "production")]
     Production,
@@ -29137,7 +30852,28 @@ impl Default for ReposCreateDeploymentStatusRequestEnvironment {
         ReposCreateDeploymentStatusRequestEnvironment::Noop
     }
 }
+
 impl ReposCreateDeploymentStatusRequestEnvironment {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReposCreateDeploymentStatusRequestEnvironment::Production => "production",
+            ReposCreateDeploymentStatusRequestEnvironment::Qa => "qa",
+            ReposCreateDeploymentStatusRequestEnvironment::Staging => "staging",
+            ReposCreateDeploymentStatusRequestEnvironment::Noop => "",
+            ReposCreateDeploymentStatusRequestEnvironment::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ReposCreateDeploymentStatusRequestEnvironment] {
+        &[
+            ReposCreateDeploymentStatusRequestEnvironment::Production,
+            ReposCreateDeploymentStatusRequestEnvironment::Qa,
+            ReposCreateDeploymentStatusRequestEnvironment::Staging,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ReposCreateDeploymentStatusRequestEnvironment::Noop)
     }
@@ -29253,7 +30989,7 @@ pub struct ReposCreateUpdateEnvironmentRequest {
 /**
  * The sort order. Can be either `newest`, `oldest`, or `stargazers`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ReposListForksSort {
     #[serde(rename = "newest")]
     Newest,
@@ -29286,6 +31022,29 @@ impl Default for ReposListForksSort {
     }
 }
 
+impl ReposListForksSort {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReposListForksSort::Newest => "newest",
+            ReposListForksSort::Oldest => "oldest",
+            ReposListForksSort::Stargazers => "stargazers",
+            ReposListForksSort::Watchers => "watchers",
+            ReposListForksSort::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ReposListForksSort] {
+        &[
+            ReposListForksSort::Newest,
+            ReposListForksSort::Oldest,
+            ReposListForksSort::Stargazers,
+            ReposListForksSort::Watchers,
+        ]
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposCreateForkRequest {
     #[serde(
@@ -29443,7 +31202,7 @@ pub struct GitUpdateRefRequest {
 /**
  * The type of the object we're tagging. Normally this is a `commit` but it can also be a `tree` or a `blob`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GitCreateTagRequestType {
     #[serde(rename = "blob")]
     Blob,
@@ -29475,7 +31234,28 @@ impl Default for GitCreateTagRequestType {
         GitCreateTagRequestType::Noop
     }
 }
+
 impl GitCreateTagRequestType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GitCreateTagRequestType::Blob => "blob",
+            GitCreateTagRequestType::Commit => "commit",
+            GitCreateTagRequestType::Tree => "tree",
+            GitCreateTagRequestType::Noop => "",
+            GitCreateTagRequestType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GitCreateTagRequestType] {
+        &[
+            GitCreateTagRequestType::Blob,
+            GitCreateTagRequestType::Commit,
+            GitCreateTagRequestType::Tree,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, GitCreateTagRequestType::Noop)
     }
@@ -29546,7 +31326,7 @@ pub struct GitCreateTagRequest {
 /**
  * The file mode; one of `100644` for file (blob), `100755` for executable (blob), `040000` for subdirectory (tree), `160000` for submodule (commit), or `120000` for a blob that specifies the path of a symlink.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GitCreateTreeRequestMode {
     #[serde(rename = "040000")]
     SubdirectoryTree,
@@ -29584,7 +31364,32 @@ impl Default for GitCreateTreeRequestMode {
         GitCreateTreeRequestMode::Noop
     }
 }
+
 impl GitCreateTreeRequestMode {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GitCreateTreeRequestMode::SubdirectoryTree => "040000",
+            GitCreateTreeRequestMode::FileBlob => "100644",
+            GitCreateTreeRequestMode::ExecutableBlob => "100755",
+            GitCreateTreeRequestMode::SymlinkPathBlob => "120000",
+            GitCreateTreeRequestMode::SubmoduleCommit => "160000",
+            GitCreateTreeRequestMode::Noop => "",
+            GitCreateTreeRequestMode::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GitCreateTreeRequestMode] {
+        &[
+            GitCreateTreeRequestMode::SubdirectoryTree,
+            GitCreateTreeRequestMode::FileBlob,
+            GitCreateTreeRequestMode::ExecutableBlob,
+            GitCreateTreeRequestMode::SymlinkPathBlob,
+            GitCreateTreeRequestMode::SubmoduleCommit,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, GitCreateTreeRequestMode::Noop)
     }
@@ -29785,7 +31590,7 @@ pub struct ReposUpdateWebhookRequest {
 /**
  * The originating VCS type. Can be one of `subversion`, `git`, `mercurial`, or `tfvc`. Please be aware that without this parameter, the import job will take additional time to detect the VCS type before beginning the import. This detection step will be reflected in the response.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Vcs {
     #[serde(rename = "git")]
     Git,
@@ -29820,7 +31625,25 @@ impl Default for Vcs {
         Vcs::Noop
     }
 }
+
 impl Vcs {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Vcs::Git => "git",
+            Vcs::Mercurial => "mercurial",
+            Vcs::Subversion => "subversion",
+            Vcs::Tfvc => "tfvc",
+            Vcs::Noop => "",
+            Vcs::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Vcs] {
+        &[Vcs::Git, Vcs::Mercurial, Vcs::Subversion, Vcs::Tfvc]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Vcs::Noop)
     }
@@ -29890,7 +31713,7 @@ pub struct MigrationsUpdateImportRequest {
 /**
  * Can be one of `opt_in` (large files will be stored using Git LFS) or `opt_out` (large files will be removed during the import).
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum UseLfs {
     #[serde(rename = "opt_in")]
     OptIn,
@@ -29919,7 +31742,23 @@ impl Default for UseLfs {
         UseLfs::Noop
     }
 }
+
 impl UseLfs {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UseLfs::OptIn => "opt_in",
+            UseLfs::OptOut => "opt_out",
+            UseLfs::Noop => "",
+            UseLfs::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [UseLfs] {
+        &[UseLfs::OptIn, UseLfs::OptOut]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, UseLfs::Noop)
     }
@@ -30224,28 +32063,99 @@ impl std::convert::From<IssuesAddLabelsRequestOneOf> for Vec<String> {
     }
 }
 
-/// All of the following types are flattened into one object:
+/// All of the following types:
 ///
 /// - `String`
 /// - `Vec<String>`
 /// - `IssuesAddLabelsRequest`
 /// - `Vec<ProjectsUpdateColumnRequest>`
 /// - `IssuesSetLabelsRequest`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct IssuesSetLabelsRequestAnyOf {
-    #[serde(flatten)]
-    pub string: String,
+#[serde(untagged)]
+pub enum IssuesSetLabelsRequestAnyOf {
+    String(String),
     /**
      * The list of events for the GitHub app
      */
-    #[serde(flatten)]
-    pub string_vector: Vec<String>,
-    #[serde(flatten)]
-    pub issues_add_labels_request: IssuesAddLabelsRequest,
-    #[serde(flatten)]
-    pub projects_update_column_request_vector: Vec<ProjectsUpdateColumnRequest>,
-    #[serde(flatten)]
-    pub issues_set_labels_request: IssuesSetLabelsRequest,
+    StringVector(Vec<String>),
+    IssuesAddLabelsRequest(IssuesAddLabelsRequest),
+    ProjectsUpdateColumnRequestVector(Vec<ProjectsUpdateColumnRequest>),
+    IssuesSetLabelsRequest(IssuesSetLabelsRequest),
+}
+
+impl IssuesSetLabelsRequestAnyOf {
+    pub fn issues_add_labels_request(&self) -> Option<&IssuesAddLabelsRequest> {
+        if let IssuesSetLabelsRequestAnyOf::IssuesAddLabelsRequest(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn issues_set_labels_request(&self) -> Option<&IssuesSetLabelsRequest> {
+        if let IssuesSetLabelsRequestAnyOf::IssuesSetLabelsRequest(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn vec_projects_update_column_request(&self) -> Option<&Vec<ProjectsUpdateColumnRequest>> {
+        if let IssuesSetLabelsRequestAnyOf::ProjectsUpdateColumnRequestVector(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn string(&self) -> Option<&String> {
+        if let IssuesSetLabelsRequestAnyOf::String(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn vec_string(&self) -> Option<&Vec<String>> {
+        if let IssuesSetLabelsRequestAnyOf::StringVector(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+impl std::convert::From<Vec<ProjectsUpdateColumnRequest>> for IssuesSetLabelsRequestAnyOf {
+    fn from(f: Vec<ProjectsUpdateColumnRequest>) -> Self {
+        IssuesSetLabelsRequestAnyOf::ProjectsUpdateColumnRequestVector(f)
+    }
+}
+
+impl std::convert::From<String> for IssuesSetLabelsRequestAnyOf {
+    fn from(f: String) -> Self {
+        IssuesSetLabelsRequestAnyOf::String(f)
+    }
+}
+
+impl std::convert::From<Vec<String>> for IssuesSetLabelsRequestAnyOf {
+    fn from(f: Vec<String>) -> Self {
+        IssuesSetLabelsRequestAnyOf::StringVector(f)
+    }
+}
+
+impl std::convert::From<IssuesSetLabelsRequestAnyOf> for Vec<ProjectsUpdateColumnRequest> {
+    fn from(f: IssuesSetLabelsRequestAnyOf) -> Self {
+        f.vec_projects_update_column_request().unwrap().clone()
+    }
+}
+
+impl std::convert::From<IssuesSetLabelsRequestAnyOf> for String {
+    fn from(f: IssuesSetLabelsRequestAnyOf) -> Self {
+        f.string().unwrap().clone()
+    }
+}
+
+impl std::convert::From<IssuesSetLabelsRequestAnyOf> for Vec<String> {
+    fn from(f: IssuesSetLabelsRequestAnyOf) -> Self {
+        f.vec_string().unwrap().clone()
+    }
 }
 
 /**
@@ -30255,7 +32165,7 @@ pub struct IssuesSetLabelsRequestAnyOf {
  *   \* `resolved`  
  *   \* `spam`
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum LockReason {
     #[serde(rename = "off-topic")]
     OffTopic,
@@ -30290,7 +32200,30 @@ impl Default for LockReason {
         LockReason::Noop
     }
 }
+
 impl LockReason {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LockReason::OffTopic => "off-topic",
+            LockReason::Resolved => "resolved",
+            LockReason::Spam => "spam",
+            LockReason::TooHeated => "too heated",
+            LockReason::Noop => "",
+            LockReason::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [LockReason] {
+        &[
+            LockReason::OffTopic,
+            LockReason::Resolved,
+            LockReason::Spam,
+            LockReason::TooHeated,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, LockReason::Noop)
     }
@@ -30389,7 +32322,7 @@ pub struct ReposMergeRequest {
 /**
  * What to sort results by. Either `due_on` or `completeness`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum IssuesListMilestonesSort {
     #[serde(rename = "completeness")]
     Completeness,
@@ -30416,6 +32349,25 @@ impl Default for IssuesListMilestonesSort {
     }
 }
 
+impl IssuesListMilestonesSort {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IssuesListMilestonesSort::Completeness => "completeness",
+            IssuesListMilestonesSort::DueOn => "due_on",
+            IssuesListMilestonesSort::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [IssuesListMilestonesSort] {
+        &[
+            IssuesListMilestonesSort::Completeness,
+            IssuesListMilestonesSort::DueOn,
+        ]
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct IssuesCreateMilestoneRequest {
     #[serde(
@@ -30472,7 +32424,7 @@ pub struct PullsUpdateBranchResponse {
 /**
  * The repository directory that includes the source files for the Pages site. Allowed paths are `/` or `/docs`. Default: `/`
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Path {
     #[serde(rename = "/")]
     Root,
@@ -30499,6 +32451,22 @@ impl Default for Path {
     }
 }
 
+impl Path {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Path::Root => "/",
+            Path::Docs => "/docs",
+            Path::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Path] {
+        &[Path::Root, Path::Docs]
+    }
+}
+
 /// The source branch and directory used to publish your Pages site.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposCreatePagesSiteRequestSource {
@@ -30528,7 +32496,7 @@ pub struct ReposCreatePagesSiteRequest {
 /**
  * Update the source for the repository. Must include the branch name, and may optionally specify the subdirectory `/docs`. Possible values are `"gh-pages"`, `"master"`, and `"master /docs"`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SourceData {
     #[serde(rename = "gh-pages")]
     GhPages,
@@ -30560,10 +32528,31 @@ impl Default for SourceData {
         SourceData::Noop
     }
 }
+
 impl SourceData {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SourceData::Noop)
-    }
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SourceData::GhPages => "gh-pages",
+            SourceData::Master => "master",
+            SourceData::MasterDocs => "master /docs",
+            SourceData::Noop => "",
+            SourceData::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SourceData] {
+        &[
+            SourceData::GhPages,
+            SourceData::Master,
+            SourceData::MasterDocs,
+        ]
+    }
+
+    pub fn is_noop(&self) -> bool {
+        matches!(self, SourceData::Noop)
+    }
 }
 
 /// Update the source for the repository. Must include the branch name and path.
@@ -30582,22 +32571,39 @@ pub struct SourceDataType {
     pub path: Path,
 }
 
-/// All of the following types are flattened into one object:
+/// All of the following types:
 ///
 /// - `SourceData`
 /// - `SourceDataType`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SourceAnyOf {
+#[serde(untagged)]
+pub enum SourceAnyOf {
     /**
      * Update the source for the repository. Must include the branch name, and may optionally specify the subdirectory `/docs`. Possible values are `"gh-pages"`, `"master"`, and `"master /docs"`.
      */
-    #[serde(flatten)]
-    pub source_data: SourceData,
+    SourceData(SourceData),
     /**
      * Update the source for the repository. Must include the branch name and path.
      */
-    #[serde(flatten)]
-    pub source_data_type: SourceDataType,
+    SourceDataType(SourceDataType),
+}
+
+impl SourceAnyOf {
+    pub fn source_data(&self) -> Option<&SourceData> {
+        if let SourceAnyOf::SourceData(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn source_data_type(&self) -> Option<&SourceDataType> {
+        if let SourceAnyOf::SourceDataType(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -30619,7 +32625,7 @@ pub struct ReposUpdateInformationAboutPagesSiteRequest {
 /**
  * What to sort results by. Can be either `created`, `updated`, `popularity` (comment count) or `long-running` (age, filtering by pulls updated in the last month).
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PullsListSort {
     #[serde(rename = "created")]
     Created,
@@ -30652,6 +32658,29 @@ impl Default for PullsListSort {
     }
 }
 
+impl PullsListSort {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PullsListSort::Created => "created",
+            PullsListSort::LongRunning => "long-running",
+            PullsListSort::Popularity => "popularity",
+            PullsListSort::Updated => "updated",
+            PullsListSort::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PullsListSort] {
+        &[
+            PullsListSort::Created,
+            PullsListSort::LongRunning,
+            PullsListSort::Popularity,
+            PullsListSort::Updated,
+        ]
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PullsCreateRequest {
     #[serde(
@@ -30690,7 +32719,7 @@ pub struct PullsCreateRequest {
     pub title: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PullsListReviewCommentsRepoSort {
     #[serde(rename = "created")]
     Created,
@@ -30722,7 +32751,28 @@ impl Default for PullsListReviewCommentsRepoSort {
         PullsListReviewCommentsRepoSort::Noop
     }
 }
+
 impl PullsListReviewCommentsRepoSort {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PullsListReviewCommentsRepoSort::Created => "created",
+            PullsListReviewCommentsRepoSort::CreatedAt => "created_at",
+            PullsListReviewCommentsRepoSort::Updated => "updated",
+            PullsListReviewCommentsRepoSort::Noop => "",
+            PullsListReviewCommentsRepoSort::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PullsListReviewCommentsRepoSort] {
+        &[
+            PullsListReviewCommentsRepoSort::Created,
+            PullsListReviewCommentsRepoSort::CreatedAt,
+            PullsListReviewCommentsRepoSort::Updated,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PullsListReviewCommentsRepoSort::Noop)
     }
@@ -30760,7 +32810,7 @@ pub struct PullsUpdateRequest {
 /**
  * **Required when using multi-line comments**. To create multi-line comments, you must use the `comfort-fade` preview header. The `start_side` is the starting side of the diff that the comment applies to. Can be `LEFT` or `RIGHT`. To learn more about multi-line comments, see "[Commenting on a pull request](https://help.github.com/en/articles/commenting-on-a-pull-request#adding-line-comments-to-a-pull-request)" in the GitHub Help documentation. See `side` in this table for additional context.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PullsCreateReviewCommentRequestStartSide {
     #[serde(rename = "LEFT")]
     Left,
@@ -30792,7 +32842,28 @@ impl Default for PullsCreateReviewCommentRequestStartSide {
         PullsCreateReviewCommentRequestStartSide::Noop
     }
 }
+
 impl PullsCreateReviewCommentRequestStartSide {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PullsCreateReviewCommentRequestStartSide::Left => "LEFT",
+            PullsCreateReviewCommentRequestStartSide::Right => "RIGHT",
+            PullsCreateReviewCommentRequestStartSide::Side => "side",
+            PullsCreateReviewCommentRequestStartSide::Noop => "",
+            PullsCreateReviewCommentRequestStartSide::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PullsCreateReviewCommentRequestStartSide] {
+        &[
+            PullsCreateReviewCommentRequestStartSide::Left,
+            PullsCreateReviewCommentRequestStartSide::Right,
+            PullsCreateReviewCommentRequestStartSide::Side,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PullsCreateReviewCommentRequestStartSide::Noop)
     }
@@ -30925,7 +32996,7 @@ pub struct PullsRemoveRequestedReviewersRequest {
 /**
  * The review action you want to perform. The review actions include: `APPROVE`, `REQUEST_CHANGES`, or `COMMENT`. By leaving this blank, you set the review action state to `PENDING`, which means you will need to [submit the pull request review](https://docs.github.com/rest/reference/pulls#submit-a-review-for-a-pull-request) when you are ready.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PullsCreateReviewRequestEvent {
     #[serde(rename = "APPROVE")]
     Approve,
@@ -30957,7 +33028,28 @@ impl Default for PullsCreateReviewRequestEvent {
         PullsCreateReviewRequestEvent::Noop
     }
 }
+
 impl PullsCreateReviewRequestEvent {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PullsCreateReviewRequestEvent::Approve => "APPROVE",
+            PullsCreateReviewRequestEvent::Comment => "COMMENT",
+            PullsCreateReviewRequestEvent::RequestChanges => "REQUEST_CHANGES",
+            PullsCreateReviewRequestEvent::Noop => "",
+            PullsCreateReviewRequestEvent::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PullsCreateReviewRequestEvent] {
+        &[
+            PullsCreateReviewRequestEvent::Approve,
+            PullsCreateReviewRequestEvent::Comment,
+            PullsCreateReviewRequestEvent::RequestChanges,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PullsCreateReviewRequestEvent::Noop)
     }
@@ -31146,7 +33238,7 @@ pub struct ReposUpdateReleaseAssetRequest {
 /**
  * The [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types) to add to the release.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ReactionsCreateReleaseRequestContent {
     #[serde(rename = "+1")]
     PlusOne,
@@ -31187,7 +33279,34 @@ impl Default for ReactionsCreateReleaseRequestContent {
         ReactionsCreateReleaseRequestContent::Noop
     }
 }
+
 impl ReactionsCreateReleaseRequestContent {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReactionsCreateReleaseRequestContent::PlusOne => "+1",
+            ReactionsCreateReleaseRequestContent::Eyes => "eyes",
+            ReactionsCreateReleaseRequestContent::Heart => "heart",
+            ReactionsCreateReleaseRequestContent::Hooray => "hooray",
+            ReactionsCreateReleaseRequestContent::Laugh => "laugh",
+            ReactionsCreateReleaseRequestContent::Rocket => "rocket",
+            ReactionsCreateReleaseRequestContent::Noop => "",
+            ReactionsCreateReleaseRequestContent::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ReactionsCreateReleaseRequestContent] {
+        &[
+            ReactionsCreateReleaseRequestContent::PlusOne,
+            ReactionsCreateReleaseRequestContent::Eyes,
+            ReactionsCreateReleaseRequestContent::Heart,
+            ReactionsCreateReleaseRequestContent::Hooray,
+            ReactionsCreateReleaseRequestContent::Laugh,
+            ReactionsCreateReleaseRequestContent::Rocket,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ReactionsCreateReleaseRequestContent::Noop)
     }
@@ -31219,22 +33338,63 @@ pub struct SecretScanningUpdateAlertRequest {
     pub state: SecretScanningAlertState,
 }
 
-/// All of the following types are flattened into one object:
+/// All of the following types:
 ///
 /// - `Vec<SimpleUser>`
 /// - `Vec<Stargazer>`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ActivityListStargazersRepoResponseAnyOf {
-    #[serde(flatten)]
-    pub simple_user_vector: Vec<SimpleUser>,
-    #[serde(flatten)]
-    pub stargazer_vector: Vec<Stargazer>,
+#[serde(untagged)]
+pub enum ActivityListStargazersRepoResponseAnyOf {
+    SimpleUserVector(Vec<SimpleUser>),
+    StargazerVector(Vec<Stargazer>),
+}
+
+impl ActivityListStargazersRepoResponseAnyOf {
+    pub fn vec_simple_user(&self) -> Option<&Vec<SimpleUser>> {
+        if let ActivityListStargazersRepoResponseAnyOf::SimpleUserVector(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn vec_stargazer(&self) -> Option<&Vec<Stargazer>> {
+        if let ActivityListStargazersRepoResponseAnyOf::StargazerVector(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+impl std::convert::From<Vec<SimpleUser>> for ActivityListStargazersRepoResponseAnyOf {
+    fn from(f: Vec<SimpleUser>) -> Self {
+        ActivityListStargazersRepoResponseAnyOf::SimpleUserVector(f)
+    }
+}
+
+impl std::convert::From<Vec<Stargazer>> for ActivityListStargazersRepoResponseAnyOf {
+    fn from(f: Vec<Stargazer>) -> Self {
+        ActivityListStargazersRepoResponseAnyOf::StargazerVector(f)
+    }
+}
+
+impl std::convert::From<ActivityListStargazersRepoResponseAnyOf> for Vec<SimpleUser> {
+    fn from(f: ActivityListStargazersRepoResponseAnyOf) -> Self {
+        f.vec_simple_user().unwrap().clone()
+    }
+}
+
+impl std::convert::From<ActivityListStargazersRepoResponseAnyOf> for Vec<Stargazer> {
+    fn from(f: ActivityListStargazersRepoResponseAnyOf) -> Self {
+        f.vec_stargazer().unwrap().clone()
+    }
 }
 
 /**
  * The state of the status. Can be one of `error`, `failure`, `pending`, or `success`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ReposCreateCommitStatusRequestState {
     #[serde(rename = "error")]
     Error,
@@ -31269,7 +33429,30 @@ impl Default for ReposCreateCommitStatusRequestState {
         ReposCreateCommitStatusRequestState::Noop
     }
 }
+
 impl ReposCreateCommitStatusRequestState {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReposCreateCommitStatusRequestState::Error => "error",
+            ReposCreateCommitStatusRequestState::Failure => "failure",
+            ReposCreateCommitStatusRequestState::Pending => "pending",
+            ReposCreateCommitStatusRequestState::Success => "success",
+            ReposCreateCommitStatusRequestState::Noop => "",
+            ReposCreateCommitStatusRequestState::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ReposCreateCommitStatusRequestState] {
+        &[
+            ReposCreateCommitStatusRequestState::Error,
+            ReposCreateCommitStatusRequestState::Failure,
+            ReposCreateCommitStatusRequestState::Pending,
+            ReposCreateCommitStatusRequestState::Success,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ReposCreateCommitStatusRequestState::Noop)
     }
@@ -31384,7 +33567,7 @@ pub struct EnterpriseAdminProvisionInviteGroupRequest {
     pub schemas: Vec<String>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum EnterpriseAdminUpdateAttributeGroupRequestOperationsOp {
     #[serde(rename = "Add")]
     Add,
@@ -31416,7 +33599,28 @@ impl Default for EnterpriseAdminUpdateAttributeGroupRequestOperationsOp {
         EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Noop
     }
 }
+
 impl EnterpriseAdminUpdateAttributeGroupRequestOperationsOp {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Add => "Add",
+            EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Remove => "Remove",
+            EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Replace => "Replace",
+            EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Noop => "",
+            EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [EnterpriseAdminUpdateAttributeGroupRequestOperationsOp] {
+        &[
+            EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Add,
+            EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Remove,
+            EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Replace,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(
             self,
@@ -31747,7 +33951,7 @@ pub struct ScimUpdateAttributeUserRequest {
 /**
  * Sorts the results of your query. Can only be `indexed`, which indicates how recently a file has been indexed by the GitHub search infrastructure. Default: [best match](https://docs.github.com/rest/reference/search#ranking-search-results)
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SearchCodeSort {
     #[serde(rename = "indexed")]
     Indexed,
@@ -31773,7 +33977,22 @@ impl Default for SearchCodeSort {
         SearchCodeSort::Noop
     }
 }
+
 impl SearchCodeSort {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchCodeSort::Indexed => "indexed",
+            SearchCodeSort::Noop => "",
+            SearchCodeSort::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SearchCodeSort] {
+        &[SearchCodeSort::Indexed]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SearchCodeSort::Noop)
     }
@@ -31803,7 +34022,7 @@ pub struct SearchCodeResponse {
 /**
  * Sorts the results of your query by `author-date` or `committer-date`. Default: [best match](https://docs.github.com/rest/reference/search#ranking-search-results)
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SearchCommitsSort {
     #[serde(rename = "author-date")]
     AuthorDate,
@@ -31832,7 +34051,26 @@ impl Default for SearchCommitsSort {
         SearchCommitsSort::Noop
     }
 }
+
 impl SearchCommitsSort {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchCommitsSort::AuthorDate => "author-date",
+            SearchCommitsSort::CommitterDate => "committer-date",
+            SearchCommitsSort::Noop => "",
+            SearchCommitsSort::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SearchCommitsSort] {
+        &[
+            SearchCommitsSort::AuthorDate,
+            SearchCommitsSort::CommitterDate,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SearchCommitsSort::Noop)
     }
@@ -31862,7 +34100,7 @@ pub struct SearchCommitsResponse {
 /**
  * Sorts the results of your query by the number of `comments`, `reactions`, `reactions-+1`, `reactions--1`, `reactions-smile`, `reactions-thinking_face`, `reactions-heart`, `reactions-tada`, or `interactions`. You can also sort results by how recently the items were `created` or `updated`, Default: [best match](https://docs.github.com/rest/reference/search#ranking-search-results)
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SearchIssuesPullRequestsSort {
     #[serde(rename = "comments")]
     Comments,
@@ -31918,7 +34156,44 @@ impl Default for SearchIssuesPullRequestsSort {
         SearchIssuesPullRequestsSort::Noop
     }
 }
+
 impl SearchIssuesPullRequestsSort {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchIssuesPullRequestsSort::Comments => "comments",
+            SearchIssuesPullRequestsSort::Created => "created",
+            SearchIssuesPullRequestsSort::Interactions => "interactions",
+            SearchIssuesPullRequestsSort::Reactions => "reactions",
+            SearchIssuesPullRequestsSort::ReactionsPlusOne => "reactions-+1",
+            SearchIssuesPullRequestsSort::ReactionsMinusOne => "reactions--1",
+            SearchIssuesPullRequestsSort::ReactionsHeart => "reactions-heart",
+            SearchIssuesPullRequestsSort::ReactionsSmile => "reactions-smile",
+            SearchIssuesPullRequestsSort::ReactionsTada => "reactions-tada",
+            SearchIssuesPullRequestsSort::ReactionsThinkingFace => "reactions-thinking_face",
+            SearchIssuesPullRequestsSort::Updated => "updated",
+            SearchIssuesPullRequestsSort::Noop => "",
+            SearchIssuesPullRequestsSort::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SearchIssuesPullRequestsSort] {
+        &[
+            SearchIssuesPullRequestsSort::Comments,
+            SearchIssuesPullRequestsSort::Created,
+            SearchIssuesPullRequestsSort::Interactions,
+            SearchIssuesPullRequestsSort::Reactions,
+            SearchIssuesPullRequestsSort::ReactionsPlusOne,
+            SearchIssuesPullRequestsSort::ReactionsMinusOne,
+            SearchIssuesPullRequestsSort::ReactionsHeart,
+            SearchIssuesPullRequestsSort::ReactionsSmile,
+            SearchIssuesPullRequestsSort::ReactionsTada,
+            SearchIssuesPullRequestsSort::ReactionsThinkingFace,
+            SearchIssuesPullRequestsSort::Updated,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SearchIssuesPullRequestsSort::Noop)
     }
@@ -31969,7 +34244,7 @@ pub struct SearchLabelsResponse {
 /**
  * Sorts the results of your query by number of `stars`, `forks`, or `help-wanted-issues` or how recently the items were `updated`. Default: [best match](https://docs.github.com/rest/reference/search#ranking-search-results)
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SearchReposSort {
     #[serde(rename = "forks")]
     Forks,
@@ -32004,7 +34279,30 @@ impl Default for SearchReposSort {
         SearchReposSort::Noop
     }
 }
+
 impl SearchReposSort {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchReposSort::Forks => "forks",
+            SearchReposSort::HelpWantedIssues => "help-wanted-issues",
+            SearchReposSort::Stars => "stars",
+            SearchReposSort::Updated => "updated",
+            SearchReposSort::Noop => "",
+            SearchReposSort::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SearchReposSort] {
+        &[
+            SearchReposSort::Forks,
+            SearchReposSort::HelpWantedIssues,
+            SearchReposSort::Stars,
+            SearchReposSort::Updated,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SearchReposSort::Noop)
     }
@@ -32055,7 +34353,7 @@ pub struct SearchTopicsResponse {
 /**
  * Sorts the results of your query by number of `followers` or `repositories`, or when the person `joined` GitHub. Default: [best match](https://docs.github.com/rest/reference/search#ranking-search-results)
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SearchUsersSort {
     #[serde(rename = "followers")]
     Followers,
@@ -32087,7 +34385,28 @@ impl Default for SearchUsersSort {
         SearchUsersSort::Noop
     }
 }
+
 impl SearchUsersSort {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchUsersSort::Followers => "followers",
+            SearchUsersSort::Joined => "joined",
+            SearchUsersSort::Repositories => "repositories",
+            SearchUsersSort::Noop => "",
+            SearchUsersSort::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SearchUsersSort] {
+        &[
+            SearchUsersSort::Followers,
+            SearchUsersSort::Joined,
+            SearchUsersSort::Repositories,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SearchUsersSort::Noop)
     }
@@ -32394,7 +34713,7 @@ pub struct UsersCreatePublicSshKeyAuthenticatedRequest {
 /**
  * The state that the membership should be in. Only `"active"` will be accepted.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum OrgsUpdateMembershipRequestState {
     #[serde(rename = "active")]
     Active,
@@ -32420,7 +34739,22 @@ impl Default for OrgsUpdateMembershipRequestState {
         OrgsUpdateMembershipRequestState::Noop
     }
 }
+
 impl OrgsUpdateMembershipRequestState {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrgsUpdateMembershipRequestState::Active => "active",
+            OrgsUpdateMembershipRequestState::Noop => "",
+            OrgsUpdateMembershipRequestState::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [OrgsUpdateMembershipRequestState] {
+        &[OrgsUpdateMembershipRequestState::Active]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, OrgsUpdateMembershipRequestState::Noop)
     }
@@ -32441,7 +34775,7 @@ pub struct OrgsUpdateMembershipRequest {
 /**
  * Can be one of `all`, `public`, or `private`. Note: For GitHub AE, can be one of `all`, `internal`, or `private`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ReposListVisibility {
     #[serde(rename = "all")]
     All,
@@ -32471,12 +34805,33 @@ impl Default for ReposListVisibility {
     }
 }
 
+impl ReposListVisibility {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReposListVisibility::All => "all",
+            ReposListVisibility::Private => "private",
+            ReposListVisibility::Public => "public",
+            ReposListVisibility::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ReposListVisibility] {
+        &[
+            ReposListVisibility::All,
+            ReposListVisibility::Private,
+            ReposListVisibility::Public,
+        ]
+    }
+}
+
 /**
  * Can be one of `all`, `owner`, `public`, `private`, `member`. Note: For GitHub AE, can be one of `all`, `owner`, `internal`, `private`, `member`. Default: `all`  
  *     
  *   Will cause a `422` error if used in the same request as **visibility** or **affiliation**. Will cause a `422` error if used in the same request as **visibility** or **affiliation**.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ReposListType {
     #[serde(rename = "all")]
     All,
@@ -32512,6 +34867,31 @@ impl Default for ReposListType {
     }
 }
 
+impl ReposListType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReposListType::All => "all",
+            ReposListType::Member => "member",
+            ReposListType::Owner => "owner",
+            ReposListType::Private => "private",
+            ReposListType::Public => "public",
+            ReposListType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ReposListType] {
+        &[
+            ReposListType::All,
+            ReposListType::Member,
+            ReposListType::Owner,
+            ReposListType::Private,
+            ReposListType::Public,
+        ]
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReposCreateRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -32579,7 +34959,7 @@ pub struct ReposCreateRequest {
 /**
  * Identifies which additional information you'd like to receive about the person's hovercard. Can be `organization`, `repository`, `issue`, `pull_request`. **Required** when using `subject_id`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SubjectType {
     #[serde(rename = "issue")]
     Issue,
@@ -32614,7 +34994,30 @@ impl Default for SubjectType {
         SubjectType::Noop
     }
 }
+
 impl SubjectType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SubjectType::Issue => "issue",
+            SubjectType::Organization => "organization",
+            SubjectType::PullRequest => "pull_request",
+            SubjectType::Repository => "repository",
+            SubjectType::Noop => "",
+            SubjectType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SubjectType] {
+        &[
+            SubjectType::Issue,
+            SubjectType::Organization,
+            SubjectType::PullRequest,
+            SubjectType::Repository,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SubjectType::Noop)
     }
@@ -32623,7 +35026,7 @@ impl SubjectType {
 /**
  * Can be one of `all`, `owner`, `member`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ReposListUserType {
     #[serde(rename = "all")]
     All,
@@ -32653,14 +35056,385 @@ impl Default for ReposListUserType {
     }
 }
 
-/// All of the following types are flattened into one object:
+impl ReposListUserType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReposListUserType::All => "all",
+            ReposListUserType::Member => "member",
+            ReposListUserType::Owner => "owner",
+            ReposListUserType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ReposListUserType] {
+        &[
+            ReposListUserType::All,
+            ReposListUserType::Member,
+            ReposListUserType::Owner,
+        ]
+    }
+}
+
+/// All of the following types:
 ///
 /// - `Vec<Repository>`
 /// - `Vec<StarredRepository>`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ActivityListReposStarredByUserResponseAnyOf {
-    #[serde(flatten)]
-    pub repository_vector: Vec<Repository>,
-    #[serde(flatten)]
-    pub starred_repository_vector: Vec<StarredRepository>,
+#[serde(untagged)]
+pub enum ActivityListReposStarredByUserResponseAnyOf {
+    RepositoryVector(Vec<Repository>),
+    StarredRepositoryVector(Vec<StarredRepository>),
+}
+
+impl ActivityListReposStarredByUserResponseAnyOf {
+    pub fn vec_repository(&self) -> Option<&Vec<Repository>> {
+        if let ActivityListReposStarredByUserResponseAnyOf::RepositoryVector(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn vec_starred_repository(&self) -> Option<&Vec<StarredRepository>> {
+        if let ActivityListReposStarredByUserResponseAnyOf::StarredRepositoryVector(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+impl std::convert::From<Vec<Repository>> for ActivityListReposStarredByUserResponseAnyOf {
+    fn from(f: Vec<Repository>) -> Self {
+        ActivityListReposStarredByUserResponseAnyOf::RepositoryVector(f)
+    }
+}
+
+impl std::convert::From<Vec<StarredRepository>> for ActivityListReposStarredByUserResponseAnyOf {
+    fn from(f: Vec<StarredRepository>) -> Self {
+        ActivityListReposStarredByUserResponseAnyOf::StarredRepositoryVector(f)
+    }
+}
+
+impl std::convert::From<ActivityListReposStarredByUserResponseAnyOf> for Vec<Repository> {
+    fn from(f: ActivityListReposStarredByUserResponseAnyOf) -> Self {
+        f.vec_repository().unwrap().clone()
+    }
+}
+
+impl std::convert::From<ActivityListReposStarredByUserResponseAnyOf> for Vec<StarredRepository> {
+    fn from(f: ActivityListReposStarredByUserResponseAnyOf) -> Self {
+        f.vec_starred_repository().unwrap().clone()
+    }
+}
+
+/// The types taken and returned by the functions in [`crate::actions`].
+pub mod actions {
+    pub use super::{
+        ActionsCreateSelfHostedRunnerGroupOrgRequest, ActionsCreateUpdateOrgSecretRequest,
+        ActionsCreateUpdateRepoSecretRequest, ActionsCreateWorkflowDispatchRequest,
+        ActionsListArtifactsRepoResponse, ActionsListJobsWorkflowRunFilter,
+        ActionsListJobsWorkflowRunResponse, ActionsListOrgSecretsResponse,
+        ActionsListRepoAccessSelfHostedRunnerGroupInOrgResponse, ActionsListRepoSecretsResponse,
+        ActionsListRepoWorkflowsResponse, ActionsListSelectedReposOrgSecretResponse,
+        ActionsListSelectedRepositoriesEnabledGithubOrganizationResponse,
+        ActionsListSelfHostedRunnerGroupsOrgResponse,
+        ActionsListSelfHostedRunnersInGroupOrgResponse, ActionsListSelfHostedRunnersOrgResponse,
+        ActionsListWorkflowRunsResponse, ActionsOrganizationPermissions, ActionsPublicKey,
+        ActionsRepositoryPermissions, ActionsReviewPendingDeploymentsRunRequest, ActionsSecret,
+        ActionsSetGithubPermissionsOrganizationRequest,
+        ActionsSetGithubPermissionsRepositoryRequest,
+        ActionsSetRepoAccessSelfHostedRunnerGroupInOrgRequest,
+        ActionsSetSelectedReposOrgSecretRequest, ActionsSetSelfHostedRunnersInGroupOrgRequest,
+        ActionsUpdateSelfHostedRunnerGroupOrgRequest, Artifact, AuthenticationToken, Deployment,
+        EnvironmentApproval, Job, OrganizationActionsSecret, PendingDeployment, Runner,
+        RunnerApplication, RunnerGroupsOrg, SelectedActions, Workflow, WorkflowRun,
+        WorkflowRunStatus, WorkflowRunUsage, WorkflowUsage,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::activity`].
+pub mod activity {
+    pub use super::{
+        ActivityListReposStarredByUserResponseAnyOf, ActivityListStargazersRepoResponseAnyOf,
+        ActivityMarkNotificationsAsReadRequest, ActivityMarkRepoNotificationsAsReadRequest,
+        ActivitySetRepoSubscriptionRequest, ActivitySetThreadSubscriptionRequest, Error, Event,
+        Feed, MinimalRepository, Order, PullsUpdateBranchResponse, Repository,
+        RepositorySubscription, SimpleUser, Sort, Thread, ThreadSubscription,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::apps`].
+pub mod apps {
+    pub use super::{
+        AppsCheckTokenRequest, AppsCreateFromManifestResponseAllOf,
+        AppsCreateInstallationAccessTokenRequest, AppsListInstallationReposResponse,
+        AppsListInstallationsResponse, AppsScopeTokenRequest, AppsUpdateWebhookConfigAppRequest,
+        Authorization, ContentReferenceAttachment, GitHubApp, HookDelivery, HookDeliveryItem,
+        Installation, InstallationToken, MarketplaceListingPlan, MarketplacePurchaseData, Order,
+        Sort, TeamsUpdateDiscussionInOrgRequest, UserMarketplacePurchase, WebhookConfig,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::billing`].
+pub mod billing {
+    pub use super::{ActionsBillingUsage, CombinedBillingUsage, PackagesBillingUsage};
+}
+
+/// The types taken and returned by the functions in [`crate::checks`].
+pub mod checks {
+    pub use super::{
+        ActionsListJobsWorkflowRunFilter, CheckAnnotation, CheckRun, CheckSuiteData,
+        CheckSuitePreference, ChecksCreateRequest, ChecksCreateSuiteRequest, ChecksListRefResponse,
+        ChecksListSuitesRefResponse, ChecksUpdateRequest, JobStatus, Preferences,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::code_scanning`].
+pub mod code_scanning {
+    pub use super::{
+        AnalysisDeletion, CodeScanningAlert, CodeScanningAlertInstance, CodeScanningAlertItems,
+        CodeScanningAlertState, CodeScanningAnalysis, CodeScanningSarifsReceipt,
+        CodeScanningSarifsStatus, CodeScanningUpdateAlertRequest, CodeScanningUploadSarifRequest,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::codes_of_conduct`].
+pub mod codes_of_conduct {
+    pub use super::CodeOfConduct;
+}
+
+/// The types taken and returned by the functions in [`crate::enterprise_admin`].
+pub mod enterprise_admin {
+    pub use super::{
+        ActionsEnterprisePermissions, ActionsListSelfHostedRunnersInGroupOrgResponse,
+        ActionsSetSelfHostedRunnersInGroupOrgRequest, AuditLogEvent, AuthenticationToken,
+        EnterpriseAdminCreateSelfHostedRunnerGroupRequest,
+        EnterpriseAdminListOrgAccessSelfHostedRunnerGroupInResponse,
+        EnterpriseAdminListSelfHostedRunnerGroupsResponse,
+        EnterpriseAdminListSelfHostedRunnersResponse, EnterpriseAdminProvisionInviteGroupRequest,
+        EnterpriseAdminProvisionInviteUserRequest,
+        EnterpriseAdminSetGithubActionsPermissionsRequest,
+        EnterpriseAdminSetOrgAccessSelfHostedRunnerGroupInRequest,
+        EnterpriseAdminUpdateAttributeGroupRequest, EnterpriseAdminUpdateAttributeUserRequest,
+        EnterpriseAdminUpdateSelfHostedRunnerGroupRequest, Include, Order, Runner,
+        RunnerApplication, RunnerGroupsEnterprise, ScimEnterpriseGroup, ScimEnterpriseUser,
+        ScimGroupListEnterprise, ScimUserListEnterprise, SelectedActions,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::gists`].
+pub mod gists {
+    pub use super::{
+        BaseGist, GistComment, GistCommit, GistSimple, GistsCreateRequest, GistsUpdateRequest,
+        PullsUpdateReviewRequest,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::git`].
+pub mod git {
+    pub use super::{
+        Blob, GitCommit, GitCreateBlobRequest, GitCreateCommitRequest, GitCreateRefRequest,
+        GitCreateTagRequest, GitCreateTreeRequestData, GitRef, GitTag, GitTreeData,
+        GitUpdateRefRequest, ShortBlob,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::gitignore`].
+pub mod gitignore {
+    pub use super::GitignoreTemplate;
+}
+
+/// The types taken and returned by the functions in [`crate::interactions`].
+pub mod interactions {
+    pub use super::{
+        InteractionLimit, InteractionLimits, InteractionsGetRestrictionsResponseAnyOf,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::issues`].
+pub mod issues {
+    pub use super::{
+        Data, Filter, Issue, IssueComment, IssueEvent, IssueEventAnyOf, IssueSimple,
+        IssuesAddAssigneesRequest, IssuesAddLabelsRequestOneOf, IssuesCreateLabelRequest,
+        IssuesCreateMilestoneRequest, IssuesCreateRequest, IssuesListMilestonesSort,
+        IssuesListSort, IssuesListState, IssuesLockRequest, IssuesSetLabelsRequestAnyOf,
+        IssuesUpdateLabelRequest, IssuesUpdateRequest, Label, Milestone, Order,
+        PullsUpdateReviewRequest, SimpleUser, Sort,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::licenses`].
+pub mod licenses {
+    pub use super::{LicenseContent, LicenseData, LicenseSimple};
+}
+
+/// The types taken and returned by the functions in [`crate::markdown`].
+pub mod markdown {
+    pub use super::MarkdownRenderRequest;
+}
+
+/// The types taken and returned by the functions in [`crate::meta`].
+pub mod meta {
+    pub use super::{ApiOverview, MetaRootResponse};
+}
+
+/// The types taken and returned by the functions in [`crate::migrations`].
+pub mod migrations {
+    pub use super::{
+        Author, Import, Migration, MigrationsSetLfsPreferenceRequest, MigrationsStartImportRequest,
+        MigrationsStartRequest, MigrationsUpdateImportRequest, MinimalRepository, PorterAuthor,
+        PorterLargeFile,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::oauth_authorizations`].
+pub mod oauth_authorizations {
+    pub use super::{
+        ApplicationGrant, Authorization, OauthAuthorizationsCreateAuthorizationRequest,
+        OauthAuthorizationsGetCreateAuthorizationAppFingerprintRequest,
+        OauthAuthorizationsGetCreateAuthorizationAppRequest,
+        OauthAuthorizationsUpdateAuthorizationRequest,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::orgs`].
+pub mod orgs {
+    pub use super::{
+        AppsListInstallationsResponse, AppsUpdateWebhookConfigAppRequest, AuditLogEvent,
+        CredentialAuthorization, HookDelivery, HookDeliveryItem, Include, Order, OrgHook,
+        OrgMembership, OrgMembershipState, OrganizationFull, OrganizationInvitation,
+        OrganizationSimple, OrgsCreateInvitationRequest, OrgsCreateWebhookRequest,
+        OrgsListMembersFilter, OrgsListMembersRole, OrgsSetMembershipUserRequest,
+        OrgsUpdateMembershipRequest, OrgsUpdateRequest, OrgsUpdateWebhookRequest, SimpleUser, Team,
+        WebhookConfig,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::packages`].
+pub mod packages {
+    pub use super::{
+        Package, PackageType, PackageVersion, PackagesGetAllPackageVersionsOwnedByOrgState,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::projects`].
+pub mod projects {
+    pub use super::{
+        Affiliation, ArchivedState, IssuesListState, Project, ProjectCard, ProjectColumn,
+        ProjectsAddCollaboratorRequest, ProjectsCreateCardRequestOneOf, ProjectsCreateRequest,
+        ProjectsMoveCardRequest, ProjectsMoveColumnRequest, ProjectsUpdateCardRequest,
+        ProjectsUpdateColumnRequest, ProjectsUpdateRequest, RepositoryCollaboratorPermission,
+        SimpleUser,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::pulls`].
+pub mod pulls {
+    pub use super::{
+        CommitDataType, DiffEntry, IssuesListState, Order, PullRequestData, PullRequestMergeResult,
+        PullRequestReview, PullRequestReviewComment, PullRequestReviewData, PullRequestSimple,
+        PullsCreateRequest, PullsCreateReviewCommentRequest, PullsCreateReviewRequest,
+        PullsDismissReviewRequest, PullsListReviewCommentsRepoSort, PullsListSort,
+        PullsMergeRequest, PullsRemoveRequestedReviewersRequest, PullsRequestReviewers,
+        PullsSubmitReviewRequest, PullsUpdateBranchRequest, PullsUpdateBranchResponse,
+        PullsUpdateRequest, PullsUpdateReviewRequest, ReviewComment, Sort,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::rate_limit`].
+pub mod rate_limit {
+    pub use super::RateLimitOverview;
+}
+
+/// The types taken and returned by the functions in [`crate::reactions`].
+pub mod reactions {
+    pub use super::{
+        Content, Reaction, ReactionsCreateIssueRequest, ReactionsCreateReleaseRequest,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::repos`].
+pub mod repos {
+    pub use super::{
+        Affiliation, AppsUpdateWebhookConfigAppRequest, Autolink, BranchProtection,
+        BranchRestrictionPolicy, BranchShort, BranchWithProtection, CloneTraffic, Collaborator,
+        CombinedCommitStatus, CommitActivity, CommitComment, CommitComparison, CommitDataType,
+        CommunityProfile, ContentFile, ContentSubmodule, ContentTraffic, Contributor,
+        ContributorActivity, DeployKey, Deployment, DeploymentStatus, EnforceAdmins, Entries,
+        EnvironmentData, FileCommitData, FullRepository, GitHubApp, Hook, HookDelivery,
+        HookDeliveryItem, MinimalRepository, Order, Page, PageBuild, PageBuildStatus,
+        PagesHealthCheck, ParticipationStats, Per, ProtectedBranch,
+        ProtectedBranchPullRequestReview, PullRequestSimple, PullsUpdateReviewRequest,
+        ReferrerTraffic, Release, ReleaseAsset, ReposAddAppAccessRestrictionsRequestOneOf,
+        ReposAddCollaboratorRequest, ReposAddStatusCheckContextsRequestOneOf,
+        ReposAddTeamAccessRestrictionsRequestOneOf, ReposAddUserAccessRestrictionsRequestOneOf,
+        ReposCreateAutolinkRequest, ReposCreateCommitCommentRequest,
+        ReposCreateCommitStatusRequest, ReposCreateDeployKeyRequest, ReposCreateDeploymentRequest,
+        ReposCreateDeploymentStatusRequest, ReposCreateDispatchEventRequest,
+        ReposCreateForkRequest, ReposCreateInOrgRequest, ReposCreatePagesSiteRequest,
+        ReposCreateReleaseRequest, ReposCreateRequest, ReposCreateUpdateEnvironmentRequest,
+        ReposCreateUpdateFileContentsRequest, ReposCreateUsingTemplateRequest,
+        ReposCreateWebhookRequest, ReposDeleteFileRequest, ReposGetAllEnvironmentsResponse,
+        ReposGetContentResponseOneOf, ReposListForksSort, ReposListOrgSort, ReposListOrgType,
+        ReposListType, ReposListUserType, ReposListVisibility, ReposMergeRequest,
+        ReposRenameBranchRequest, ReposTransferRequest, ReposUpdateBranchProtectionRequest,
+        ReposUpdateInformationAboutPagesSiteRequest, ReposUpdateInvitationRequest,
+        ReposUpdatePullRequestReviewProtection, ReposUpdateReleaseAssetRequest, ReposUpdateRequest,
+        ReposUpdateStatusCheckProtectionRequest, ReposUpdateWebhookRequest, Repository,
+        RepositoryCollaboratorPermission, RepositoryInvitation, ShortBranch, SimpleUser,
+        StatusCheckPolicy, StatusData, SymlinkContent, Tag, Team, Topic, ViewTraffic,
+        WebhookConfig,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::scim`].
+pub mod scim {
+    pub use super::{
+        ScimProvisionInviteUserRequest, ScimUpdateAttributeUserRequest, ScimUser, ScimUserList,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::search`].
+pub mod search {
+    pub use super::{
+        Order, SearchCodeResponse, SearchCodeSort, SearchCommitsResponse, SearchCommitsSort,
+        SearchIssuesPullRequestsResponse, SearchIssuesPullRequestsSort, SearchLabelsResponse,
+        SearchReposResponse, SearchReposSort, SearchTopicsResponse, SearchUsersResponse,
+        SearchUsersSort, Sort,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::secret_scanning`].
+pub mod secret_scanning {
+    pub use super::{
+        SecretScanningAlert, SecretScanningAlertState, SecretScanningUpdateAlertRequest,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::teams`].
+pub mod teams {
+    pub use super::{
+        FullTeam, GroupMapping, MinimalRepository, Order, OrganizationInvitation,
+        ProjectsAddCollaboratorRequest, PullsUpdateReviewRequest, SimpleUser, Team, TeamDiscussion,
+        TeamDiscussionComment, TeamMembership, TeamProject, TeamRepository,
+        TeamsAddUpdateMembershipUserInOrgRequest, TeamsAddUpdateProjectPermissionsLegacyRequest,
+        TeamsAddUpdateRepoPermissionsInOrgRequest, TeamsAddUpdateRepoPermissionsLegacyRequest,
+        TeamsCreateDiscussionInOrgRequest, TeamsCreateRequest,
+        TeamsCreateUpdateIdpGroupConnectionsInOrgRequest,
+        TeamsCreateUpdateIdpGroupConnectionsLegacyRequest, TeamsListMembersInOrgRole,
+        TeamsUpdateDiscussionInOrgRequest, TeamsUpdateInOrgRequest,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::users`].
+pub mod users {
+    pub use super::{
+        Email, GpgKey, Hovercard, Key, KeySimple, PrivateUser, PublicUser, SimpleUser, SubjectType,
+        UsersAddEmailAuthenticatedRequestOneOf, UsersCreateGpgKeyAuthenticatedRequest,
+        UsersCreatePublicSshKeyAuthenticatedRequest, UsersGetByUsernameResponseOneOf,
+        UsersSetPrimaryEmailVisibilityAuthenticatedRequest, UsersUpdateAuthenticatedRequest,
+    };
 }
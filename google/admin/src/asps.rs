@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Asps {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Asps {
         Asps { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Asps {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/users/{userKey}/asps` endpoint.
      *
@@ -24,10 +30,16 @@ impl Asps {
     pub async fn list(&self, user_key: &str) -> Result<crate::types::Asps> {
         let url = format!(
             "/admin/directory/v1/users/{}/asps",
-            crate::progenitor_support::encode_path(&user_key.to_string()),
+            self.client.encode_path(user_key),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/directory/v1/users/{userKey}/asps",
+                &[("userKey", user_key.to_string())],
+            )
+        })
     }
 
     /**
@@ -43,11 +55,20 @@ impl Asps {
     pub async fn get(&self, user_key: &str, code_id: i64) -> Result<crate::types::Asp> {
         let url = format!(
             "/admin/directory/v1/users/{}/asps/{}",
-            crate::progenitor_support::encode_path(&user_key.to_string()),
-            crate::progenitor_support::encode_path(&code_id.to_string()),
+            self.client.encode_path(user_key),
+            self.client.encode_path(&code_id.to_string()),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/directory/v1/users/{userKey}/asps/{codeId}",
+                &[
+                    ("userKey", user_key.to_string()),
+                    ("codeId", code_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -63,10 +84,75 @@ impl Asps {
     pub async fn delete(&self, user_key: &str, code_id: i64) -> Result<()> {
         let url = format!(
             "/admin/directory/v1/users/{}/asps/{}",
-            crate::progenitor_support::encode_path(&user_key.to_string()),
-            crate::progenitor_support::encode_path(&code_id.to_string()),
+            self.client.encode_path(user_key),
+            self.client.encode_path(&code_id.to_string()),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/directory/v1/users/{userKey}/asps/{codeId}",
+                &[
+                    ("userKey", user_key.to_string()),
+                    ("codeId", code_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/admin/directory/v1/users/{userKey}/asps/{codeId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, user_key: &str, code_id: i64) -> Result<bool> {
+        let url = format!(
+            "/admin/directory/v1/users/{}/asps/{}",
+            self.client.encode_path(user_key),
+            self.client.encode_path(&code_id.to_string()),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/directory/v1/users/{userKey}/asps/{codeId}",
+                    &[
+                        ("userKey", user_key.to_string()),
+                        ("codeId", code_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`Asps`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AspsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AspsApi: Send + Sync {
+    async fn list(&self, user_key: &str) -> Result<crate::types::Asps>;
+    async fn get(&self, user_key: &str, code_id: i64) -> Result<crate::types::Asp>;
+    async fn delete(&self, user_key: &str, code_id: i64) -> Result<()>;
+    async fn delete_if_exists(&self, user_key: &str, code_id: i64) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AspsApi for Asps {
+    async fn list(&self, user_key: &str) -> Result<crate::types::Asps> {
+        Asps::list(self, user_key).await
+    }
+    async fn get(&self, user_key: &str, code_id: i64) -> Result<crate::types::Asp> {
+        Asps::get(self, user_key, code_id).await
+    }
+    async fn delete(&self, user_key: &str, code_id: i64) -> Result<()> {
+        Asps::delete(self, user_key, code_id).await
+    }
+    async fn delete_if_exists(&self, user_key: &str, code_id: i64) -> Result<bool> {
+        Asps::delete_if_exists(self, user_key, code_id).await
     }
 }
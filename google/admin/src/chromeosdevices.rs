@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Chromeosdevices {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Chromeosdevices {
         Chromeosdevices { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Chromeosdevices {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos` endpoint.
      *
@@ -39,36 +45,44 @@ impl Chromeosdevices {
         query: &str,
         sort_order: crate::types::SortOrder,
     ) -> Result<Vec<crate::types::ChromeOsDevice>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if max_results > 0 {
-            query_args.push(("maxResults".to_string(), max_results.to_string()));
+            query_args.push(("maxResults", max_results.to_string()));
         }
         if !order_by.to_string().is_empty() {
-            query_args.push(("orderBy".to_string(), order_by.to_string()));
+            query_args.push(("orderBy", order_by.to_string()));
         }
         if !org_unit_path.is_empty() {
-            query_args.push(("orgUnitPath".to_string(), org_unit_path.to_string()));
+            query_args.push(("orgUnitPath", org_unit_path.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if !projection.to_string().is_empty() {
-            query_args.push(("projection".to_string(), projection.to_string()));
+            query_args.push(("projection", projection.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
         if !sort_order.to_string().is_empty() {
-            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+            query_args.push(("sortOrder", sort_order.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/devices/chromeos?{}",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/directory/v1/customer/{}/devices/chromeos",
+                self.client.encode_path(customer_id)
+            ),
+            &query_args,
         );
 
-        let resp: crate::types::ChromeOsDevices = self.client.get(&url, None).await?;
+        let resp: crate::types::ChromeOsDevices =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/admin/directory/v1/customer/{customerId}/devices/chromeos",
+                    &[("customerId", customer_id.to_string())],
+                )
+            })?;
 
         // Return our response data.
         Ok(resp.chromeosdevices)
@@ -90,30 +104,38 @@ impl Chromeosdevices {
         query: &str,
         sort_order: crate::types::SortOrder,
     ) -> Result<Vec<crate::types::ChromeOsDevice>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !order_by.to_string().is_empty() {
-            query_args.push(("orderBy".to_string(), order_by.to_string()));
+            query_args.push(("orderBy", order_by.to_string()));
         }
         if !org_unit_path.is_empty() {
-            query_args.push(("orgUnitPath".to_string(), org_unit_path.to_string()));
+            query_args.push(("orgUnitPath", org_unit_path.to_string()));
         }
         if !projection.to_string().is_empty() {
-            query_args.push(("projection".to_string(), projection.to_string()));
+            query_args.push(("projection", projection.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
         if !sort_order.to_string().is_empty() {
-            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+            query_args.push(("sortOrder", sort_order.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/devices/chromeos?{}",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/directory/v1/customer/{}/devices/chromeos",
+                self.client.encode_path(customer_id)
+            ),
+            &query_args,
         );
 
-        let mut resp: crate::types::ChromeOsDevices = self.client.get(&url, None).await?;
+        let mut resp: crate::types::ChromeOsDevices =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/admin/directory/v1/customer/{customerId}/devices/chromeos",
+                    &[("customerId", customer_id.to_string())],
+                )
+            })?;
 
         let mut chromeosdevices = resp.chromeosdevices;
         let mut page = resp.next_page_token;
@@ -161,20 +183,28 @@ impl Chromeosdevices {
         org_unit_path: &str,
         body: &crate::types::ChromeOsMoveDevicesOu,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !org_unit_path.is_empty() {
-            query_args.push(("orgUnitPath".to_string(), org_unit_path.to_string()));
+            query_args.push(("orgUnitPath", org_unit_path.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/devices/chromeos/moveDevicesToOu?{}",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/directory/v1/customer/{}/devices/chromeos/moveDevicesToOu",
+                self.client.encode_path(customer_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/directory/v1/customer/{customerId}/devices/chromeos/moveDevicesToOu",
+                    &[("customerId", customer_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -194,19 +224,29 @@ impl Chromeosdevices {
         device_id: &str,
         projection: crate::types::Projection,
     ) -> Result<crate::types::ChromeOsDevice> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !projection.to_string().is_empty() {
-            query_args.push(("projection".to_string(), projection.to_string()));
+            query_args.push(("projection", projection.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/devices/chromeos/{}?{}",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            crate::progenitor_support::encode_path(&device_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/directory/v1/customer/{}/devices/chromeos/{}",
+                self.client.encode_path(customer_id),
+                self.client.encode_path(device_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/directory/v1/customer/{customerId}/devices/chromeos/{deviceId}",
+                &[
+                    ("customerId", customer_id.to_string()),
+                    ("deviceId", device_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -227,21 +267,32 @@ impl Chromeosdevices {
         projection: crate::types::Projection,
         body: &crate::types::ChromeOsDevice,
     ) -> Result<crate::types::ChromeOsDevice> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !projection.to_string().is_empty() {
-            query_args.push(("projection".to_string(), projection.to_string()));
+            query_args.push(("projection", projection.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/devices/chromeos/{}?{}",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            crate::progenitor_support::encode_path(&device_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/directory/v1/customer/{}/devices/chromeos/{}",
+                self.client.encode_path(customer_id),
+                self.client.encode_path(device_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/directory/v1/customer/{customerId}/devices/chromeos/{deviceId}",
+                    &[
+                        ("customerId", customer_id.to_string()),
+                        ("deviceId", device_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -262,21 +313,73 @@ impl Chromeosdevices {
         projection: crate::types::Projection,
         body: &crate::types::ChromeOsDevice,
     ) -> Result<crate::types::ChromeOsDevice> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !projection.to_string().is_empty() {
-            query_args.push(("projection".to_string(), projection.to_string()));
+            query_args.push(("projection", projection.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/devices/chromeos/{}?{}",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            crate::progenitor_support::encode_path(&device_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/directory/v1/customer/{}/devices/chromeos/{}",
+                self.client.encode_path(customer_id),
+                self.client.encode_path(device_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/admin/directory/v1/customer/{customerId}/devices/chromeos/{deviceId}",
+                    &[
+                        ("customerId", customer_id.to_string()),
+                        ("deviceId", device_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * This function performs a `PATCH` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos/{deviceId}` endpoint.
+     *
+     * As opposed to `patch`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_if_changed<C: serde::Serialize>(
+        &self,
+        customer_id: &str,
+        device_id: &str,
+        projection: crate::types::Projection,
+        current: &C,
+        body: &crate::types::ChromeOsDevice,
+    ) -> Result<Option<crate::types::ChromeOsDevice>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !projection.to_string().is_empty() {
+            query_args.push(("projection", projection.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/directory/v1/customer/{}/devices/chromeos/{}",
+                self.client.encode_path(customer_id),
+                self.client.encode_path(device_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/admin/directory/v1/customer/{customerId}/devices/chromeos/{deviceId}",
+                    &[
+                        ("customerId", customer_id.to_string()),
+                        ("deviceId", device_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -297,12 +400,163 @@ impl Chromeosdevices {
     ) -> Result<()> {
         let url = format!(
             "/admin/directory/v1/customer/{}/devices/chromeos/{}/action",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            crate::progenitor_support::encode_path(&resource_id.to_string()),
+            self.client.encode_path(customer_id),
+            self.client.encode_path(resource_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/directory/v1/customer/{customerId}/devices/chromeos/{resourceId}/action", &[("customerId", customer_id.to_string()), ("resourceId", resource_id.to_string())]))
+    }
+}
+
+/// The functions of [`Chromeosdevices`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ChromeosdevicesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ChromeosdevicesApi: Send + Sync {
+    async fn list(
+        &self,
+        customer_id: &str,
+        max_results: i64,
+        order_by: crate::types::OrderBy,
+        org_unit_path: &str,
+        page_token: &str,
+        projection: crate::types::Projection,
+        query: &str,
+        sort_order: crate::types::SortOrder,
+    ) -> Result<Vec<crate::types::ChromeOsDevice>>;
+    async fn list_all(
+        &self,
+        customer_id: &str,
+        order_by: crate::types::OrderBy,
+        org_unit_path: &str,
+        projection: crate::types::Projection,
+        query: &str,
+        sort_order: crate::types::SortOrder,
+    ) -> Result<Vec<crate::types::ChromeOsDevice>>;
+    async fn move_devices_ou(
+        &self,
+        customer_id: &str,
+        org_unit_path: &str,
+        body: &crate::types::ChromeOsMoveDevicesOu,
+    ) -> Result<()>;
+    async fn get(
+        &self,
+        customer_id: &str,
+        device_id: &str,
+        projection: crate::types::Projection,
+    ) -> Result<crate::types::ChromeOsDevice>;
+    async fn update(
+        &self,
+        customer_id: &str,
+        device_id: &str,
+        projection: crate::types::Projection,
+        body: &crate::types::ChromeOsDevice,
+    ) -> Result<crate::types::ChromeOsDevice>;
+    async fn patch(
+        &self,
+        customer_id: &str,
+        device_id: &str,
+        projection: crate::types::Projection,
+        body: &crate::types::ChromeOsDevice,
+    ) -> Result<crate::types::ChromeOsDevice>;
+    async fn action(
+        &self,
+        customer_id: &str,
+        resource_id: &str,
+        body: &crate::types::ChromeOsDeviceAction,
+    ) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ChromeosdevicesApi for Chromeosdevices {
+    async fn list(
+        &self,
+        customer_id: &str,
+        max_results: i64,
+        order_by: crate::types::OrderBy,
+        org_unit_path: &str,
+        page_token: &str,
+        projection: crate::types::Projection,
+        query: &str,
+        sort_order: crate::types::SortOrder,
+    ) -> Result<Vec<crate::types::ChromeOsDevice>> {
+        Chromeosdevices::list(
+            self,
+            customer_id,
+            max_results,
+            order_by,
+            org_unit_path,
+            page_token,
+            projection,
+            query,
+            sort_order,
+        )
+        .await
+    }
+    async fn list_all(
+        &self,
+        customer_id: &str,
+        order_by: crate::types::OrderBy,
+        org_unit_path: &str,
+        projection: crate::types::Projection,
+        query: &str,
+        sort_order: crate::types::SortOrder,
+    ) -> Result<Vec<crate::types::ChromeOsDevice>> {
+        Chromeosdevices::list_all(
+            self,
+            customer_id,
+            order_by,
+            org_unit_path,
+            projection,
+            query,
+            sort_order,
+        )
+        .await
+    }
+    async fn move_devices_ou(
+        &self,
+        customer_id: &str,
+        org_unit_path: &str,
+        body: &crate::types::ChromeOsMoveDevicesOu,
+    ) -> Result<()> {
+        Chromeosdevices::move_devices_ou(self, customer_id, org_unit_path, body).await
+    }
+    async fn get(
+        &self,
+        customer_id: &str,
+        device_id: &str,
+        projection: crate::types::Projection,
+    ) -> Result<crate::types::ChromeOsDevice> {
+        Chromeosdevices::get(self, customer_id, device_id, projection).await
+    }
+    async fn update(
+        &self,
+        customer_id: &str,
+        device_id: &str,
+        projection: crate::types::Projection,
+        body: &crate::types::ChromeOsDevice,
+    ) -> Result<crate::types::ChromeOsDevice> {
+        Chromeosdevices::update(self, customer_id, device_id, projection, body).await
+    }
+    async fn patch(
+        &self,
+        customer_id: &str,
+        device_id: &str,
+        projection: crate::types::Projection,
+        body: &crate::types::ChromeOsDevice,
+    ) -> Result<crate::types::ChromeOsDevice> {
+        Chromeosdevices::patch(self, customer_id, device_id, projection, body).await
+    }
+    async fn action(
+        &self,
+        customer_id: &str,
+        resource_id: &str,
+        body: &crate::types::ChromeOsDeviceAction,
+    ) -> Result<()> {
+        Chromeosdevices::action(self, customer_id, resource_id, body).await
     }
 }
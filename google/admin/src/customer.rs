@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Customer {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Customer {
         Customer { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Customer {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos/{deviceId}/commands/{commandId}` endpoint.
      *
@@ -31,12 +37,12 @@ impl Customer {
     ) -> Result<crate::types::DirectoryChromeosdevicesCommand> {
         let url = format!(
             "/admin/directory/v1/customer/{}/devices/chromeos/{}/commands/{}",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            crate::progenitor_support::encode_path(&device_id.to_string()),
-            crate::progenitor_support::encode_path(&command_id.to_string()),
+            self.client.encode_path(customer_id),
+            self.client.encode_path(device_id),
+            self.client.encode_path(command_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/directory/v1/customer/{customerId}/devices/chromeos/{deviceId}/commands/{commandId}", &[("customerId", customer_id.to_string()), ("deviceId", device_id.to_string()), ("commandId", command_id.to_string())]))
     }
 
     /**
@@ -57,12 +63,54 @@ impl Customer {
     ) -> Result<crate::types::DirectoryChromeosdevicesIssueCommandResponse> {
         let url = format!(
             "/admin/directory/v1/customer/{}/devices/chromeos/{}/issueCommand",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            crate::progenitor_support::encode_path(&device_id.to_string()),
+            self.client.encode_path(customer_id),
+            self.client.encode_path(device_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/directory/v1/customer/{customerId}/devices/chromeos/{deviceId}:issueCommand", &[("customerId", customer_id.to_string()), ("deviceId", device_id.to_string())]))
+    }
+}
+
+/// The functions of [`Customer`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn CustomerApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait CustomerApi: Send + Sync {
+    async fn admin_devices_chromeos_commands_get(
+        &self,
+        customer_id: &str,
+        device_id: &str,
+        command_id: &str,
+    ) -> Result<crate::types::DirectoryChromeosdevicesCommand>;
+    async fn admin_devices_chromeos_issue_command(
+        &self,
+        customer_id: &str,
+        device_id: &str,
+        body: &crate::types::DirectoryChromeosdevicesIssueCommandRequest,
+    ) -> Result<crate::types::DirectoryChromeosdevicesIssueCommandResponse>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl CustomerApi for Customer {
+    async fn admin_devices_chromeos_commands_get(
+        &self,
+        customer_id: &str,
+        device_id: &str,
+        command_id: &str,
+    ) -> Result<crate::types::DirectoryChromeosdevicesCommand> {
+        Customer::admin_devices_chromeos_commands_get(self, customer_id, device_id, command_id)
             .await
     }
+    async fn admin_devices_chromeos_issue_command(
+        &self,
+        customer_id: &str,
+        device_id: &str,
+        body: &crate::types::DirectoryChromeosdevicesIssueCommandRequest,
+    ) -> Result<crate::types::DirectoryChromeosdevicesIssueCommandResponse> {
+        Customer::admin_devices_chromeos_issue_command(self, customer_id, device_id, body).await
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Customers {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Customers {
         Customers { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Customers {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customers/{customerKey}` endpoint.
      *
@@ -24,10 +30,16 @@ impl Customers {
     pub async fn get(&self, customer_key: &str) -> Result<crate::types::Customer> {
         let url = format!(
             "/admin/directory/v1/customers/{}",
-            crate::progenitor_support::encode_path(&customer_key.to_string()),
+            self.client.encode_path(customer_key),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/directory/v1/customers/{customerKey}",
+                &[("customerKey", customer_key.to_string())],
+            )
+        })
     }
 
     /**
@@ -46,12 +58,19 @@ impl Customers {
     ) -> Result<crate::types::Customer> {
         let url = format!(
             "/admin/directory/v1/customers/{}",
-            crate::progenitor_support::encode_path(&customer_key.to_string()),
+            self.client.encode_path(customer_key),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/directory/v1/customers/{customerKey}",
+                    &[("customerKey", customer_key.to_string())],
+                )
+            })
     }
 
     /**
@@ -70,12 +89,47 @@ impl Customers {
     ) -> Result<crate::types::Customer> {
         let url = format!(
             "/admin/directory/v1/customers/{}",
-            crate::progenitor_support::encode_path(&customer_key.to_string()),
+            self.client.encode_path(customer_key),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/admin/directory/v1/customers/{customerKey}",
+                    &[("customerKey", customer_key.to_string())],
+                )
+            })
+    }
+
+    /**
+     * This function performs a `PATCH` to the `/admin/directory/v1/customers/{customerKey}` endpoint.
+     *
+     * As opposed to `patch`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_if_changed<C: serde::Serialize>(
+        &self,
+        customer_key: &str,
+        current: &C,
+        body: &crate::types::Customer,
+    ) -> Result<Option<crate::types::Customer>> {
+        let url = format!(
+            "/admin/directory/v1/customers/{}",
+            self.client.encode_path(customer_key),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/admin/directory/v1/customers/{customerKey}",
+                    &[("customerKey", customer_key.to_string())],
+                )
+            })
     }
 
     /**
@@ -90,10 +144,16 @@ impl Customers {
     pub async fn admin_chrome_printers_get(&self, name: &str) -> Result<crate::types::Printer> {
         let url = format!(
             "/admin/directory/v1/{}",
-            crate::progenitor_support::encode_path(&name.to_string()),
+            crate::progenitor_support::encode_resource_name(&name.to_string()),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/directory/v1/{name}",
+                &[("name", name.to_string())],
+            )
+        })
     }
 
     /**
@@ -108,10 +168,16 @@ impl Customers {
     pub async fn admin_chrome_printers_delete(&self, name: &str) -> Result<crate::types::Empty> {
         let url = format!(
             "/admin/directory/v1/{}",
-            crate::progenitor_support::encode_path(&name.to_string()),
+            crate::progenitor_support::encode_resource_name(&name.to_string()),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/directory/v1/{name}",
+                &[("name", name.to_string())],
+            )
+        })
     }
 
     /**
@@ -130,25 +196,73 @@ impl Customers {
         name: &str,
         clear_mask: &str,
         update_mask: &str,
-        body: &crate::types::Printer,
+        body: &crate::types::PrinterRequest,
     ) -> Result<crate::types::Printer> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !clear_mask.is_empty() {
-            query_args.push(("clearMask".to_string(), clear_mask.to_string()));
+            query_args.push(("clearMask", clear_mask.to_string()));
         }
         if !update_mask.is_empty() {
-            query_args.push(("updateMask".to_string(), update_mask.to_string()));
+            query_args.push(("updateMask", update_mask.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/{}?{}",
-            crate::progenitor_support::encode_path(&name.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/directory/v1/{}",
+                crate::progenitor_support::encode_resource_name(&name.to_string())
+            ),
+            &query_args,
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/admin/directory/v1/{name}",
+                    &[("name", name.to_string())],
+                )
+            })
+    }
+
+    /**
+     * This function performs a `PATCH` to the `/admin/directory/v1/{name}` endpoint.
+     *
+     * As opposed to `admin_chrome_printers_patch`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn admin_chrome_printers_patch_if_changed<C: serde::Serialize>(
+        &self,
+        name: &str,
+        clear_mask: &str,
+        update_mask: &str,
+        current: &C,
+        body: &crate::types::PrinterRequest,
+    ) -> Result<Option<crate::types::Printer>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !clear_mask.is_empty() {
+            query_args.push(("clearMask", clear_mask.to_string()));
+        }
+        if !update_mask.is_empty() {
+            query_args.push(("updateMask", update_mask.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/directory/v1/{}",
+                crate::progenitor_support::encode_resource_name(&name.to_string())
+            ),
+            &query_args,
+        );
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/admin/directory/v1/{name}",
+                    &[("name", name.to_string())],
+                )
+            })
     }
 
     /**
@@ -172,27 +286,36 @@ impl Customers {
         page_size: i64,
         page_token: &str,
     ) -> Result<Vec<crate::types::Printer>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if !org_unit_id.is_empty() {
-            query_args.push(("orgUnitId".to_string(), org_unit_id.to_string()));
+            query_args.push(("orgUnitId", org_unit_id.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("pageSize".to_string(), page_size.to_string()));
+            query_args.push(("pageSize", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/{}/chrome/printers?{}",
-            crate::progenitor_support::encode_path(&parent.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/directory/v1/{}/chrome/printers",
+                crate::progenitor_support::encode_resource_name(&parent.to_string())
+            ),
+            &query_args,
         );
 
-        let resp: crate::types::ListPrintersResponse = self.client.get(&url, None).await?;
+        let resp: crate::types::ListPrintersResponse =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/admin/directory/v1/{parent}/chrome/printers",
+                    &[("parent", parent.to_string())],
+                )
+            })?;
 
         // Return our response data.
         Ok(resp.printers)
@@ -211,21 +334,29 @@ impl Customers {
         filter: &str,
         org_unit_id: &str,
     ) -> Result<Vec<crate::types::Printer>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if !org_unit_id.is_empty() {
-            query_args.push(("orgUnitId".to_string(), org_unit_id.to_string()));
+            query_args.push(("orgUnitId", org_unit_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/{}/chrome/printers?{}",
-            crate::progenitor_support::encode_path(&parent.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/directory/v1/{}/chrome/printers",
+                crate::progenitor_support::encode_resource_name(&parent.to_string())
+            ),
+            &query_args,
         );
 
-        let mut resp: crate::types::ListPrintersResponse = self.client.get(&url, None).await?;
+        let mut resp: crate::types::ListPrintersResponse =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/admin/directory/v1/{parent}/chrome/printers",
+                    &[("parent", parent.to_string())],
+                )
+            })?;
 
         let mut printers = resp.printers;
         let mut page = resp.next_page_token;
@@ -269,16 +400,23 @@ impl Customers {
     pub async fn admin_chrome_printers_create(
         &self,
         parent: &str,
-        body: &crate::types::Printer,
+        body: &crate::types::PrinterRequest,
     ) -> Result<crate::types::Printer> {
         let url = format!(
             "/admin/directory/v1/{}/chrome/printers",
-            crate::progenitor_support::encode_path(&parent.to_string()),
+            crate::progenitor_support::encode_resource_name(&parent.to_string()),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/directory/v1/{parent}/chrome/printers",
+                    &[("parent", parent.to_string())],
+                )
+            })
     }
 
     /**
@@ -297,12 +435,19 @@ impl Customers {
     ) -> Result<crate::types::BatchCreatePrintersResponse> {
         let url = format!(
             "/admin/directory/v1/{}/chrome/printers:batchCreatePrinters",
-            crate::progenitor_support::encode_path(&parent.to_string()),
+            crate::progenitor_support::encode_resource_name(&parent.to_string()),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/directory/v1/{parent}/chrome/printers:batchCreatePrinters",
+                    &[("parent", parent.to_string())],
+                )
+            })
     }
 
     /**
@@ -321,12 +466,19 @@ impl Customers {
     ) -> Result<crate::types::BatchDeletePrintersResponse> {
         let url = format!(
             "/admin/directory/v1/{}/chrome/printers:batchDeletePrinters",
-            crate::progenitor_support::encode_path(&parent.to_string()),
+            crate::progenitor_support::encode_resource_name(&parent.to_string()),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/directory/v1/{parent}/chrome/printers:batchDeletePrinters",
+                    &[("parent", parent.to_string())],
+                )
+            })
     }
 
     /**
@@ -348,24 +500,33 @@ impl Customers {
         page_size: i64,
         page_token: &str,
     ) -> Result<Vec<crate::types::PrinterModel>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("pageSize".to_string(), page_size.to_string()));
+            query_args.push(("pageSize", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/{}/chrome/printers:listPrinterModels?{}",
-            crate::progenitor_support::encode_path(&parent.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/directory/v1/{}/chrome/printers:listPrinterModels",
+                crate::progenitor_support::encode_resource_name(&parent.to_string())
+            ),
+            &query_args,
         );
 
-        let resp: crate::types::ListPrinterModelsResponse = self.client.get(&url, None).await?;
+        let resp: crate::types::ListPrinterModelsResponse =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/admin/directory/v1/{parent}/chrome/printers:listPrinterModels",
+                    &[("parent", parent.to_string())],
+                )
+            })?;
 
         // Return our response data.
         Ok(resp.printer_models)
@@ -383,18 +544,26 @@ impl Customers {
         parent: &str,
         filter: &str,
     ) -> Result<Vec<crate::types::PrinterModel>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/{}/chrome/printers:listPrinterModels?{}",
-            crate::progenitor_support::encode_path(&parent.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/directory/v1/{}/chrome/printers:listPrinterModels",
+                crate::progenitor_support::encode_resource_name(&parent.to_string())
+            ),
+            &query_args,
         );
 
-        let mut resp: crate::types::ListPrinterModelsResponse = self.client.get(&url, None).await?;
+        let mut resp: crate::types::ListPrinterModelsResponse =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/admin/directory/v1/{parent}/chrome/printers:listPrinterModels",
+                    &[("parent", parent.to_string())],
+                )
+            })?;
 
         let mut printer_models = resp.printer_models;
         let mut page = resp.next_page_token;
@@ -426,3 +595,175 @@ impl Customers {
         Ok(printer_models)
     }
 }
+
+/// The functions of [`Customers`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn CustomersApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait CustomersApi: Send + Sync {
+    async fn get(&self, customer_key: &str) -> Result<crate::types::Customer>;
+    async fn update(
+        &self,
+        customer_key: &str,
+        body: &crate::types::Customer,
+    ) -> Result<crate::types::Customer>;
+    async fn patch(
+        &self,
+        customer_key: &str,
+        body: &crate::types::Customer,
+    ) -> Result<crate::types::Customer>;
+    async fn admin_chrome_printers_get(&self, name: &str) -> Result<crate::types::Printer>;
+    async fn admin_chrome_printers_delete(&self, name: &str) -> Result<crate::types::Empty>;
+    async fn admin_chrome_printers_patch(
+        &self,
+        name: &str,
+        clear_mask: &str,
+        update_mask: &str,
+        body: &crate::types::PrinterRequest,
+    ) -> Result<crate::types::Printer>;
+    async fn admin_chrome_printers_list(
+        &self,
+        parent: &str,
+        filter: &str,
+        org_unit_id: &str,
+        page_size: i64,
+        page_token: &str,
+    ) -> Result<Vec<crate::types::Printer>>;
+    async fn admin_chrome_printers_list_all(
+        &self,
+        parent: &str,
+        filter: &str,
+        org_unit_id: &str,
+    ) -> Result<Vec<crate::types::Printer>>;
+    async fn admin_chrome_printers_create(
+        &self,
+        parent: &str,
+        body: &crate::types::PrinterRequest,
+    ) -> Result<crate::types::Printer>;
+    async fn admin_chrome_printers_batch_create(
+        &self,
+        parent: &str,
+        body: &crate::types::BatchCreatePrintersRequest,
+    ) -> Result<crate::types::BatchCreatePrintersResponse>;
+    async fn admin_chrome_printers_batch_delete(
+        &self,
+        parent: &str,
+        body: &crate::types::BatchDeletePrintersRequest,
+    ) -> Result<crate::types::BatchDeletePrintersResponse>;
+    async fn admin_chrome_printers_list_printer_models(
+        &self,
+        parent: &str,
+        filter: &str,
+        page_size: i64,
+        page_token: &str,
+    ) -> Result<Vec<crate::types::PrinterModel>>;
+    async fn admin_chrome_printers_list_all_printer_models(
+        &self,
+        parent: &str,
+        filter: &str,
+    ) -> Result<Vec<crate::types::PrinterModel>>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl CustomersApi for Customers {
+    async fn get(&self, customer_key: &str) -> Result<crate::types::Customer> {
+        Customers::get(self, customer_key).await
+    }
+    async fn update(
+        &self,
+        customer_key: &str,
+        body: &crate::types::Customer,
+    ) -> Result<crate::types::Customer> {
+        Customers::update(self, customer_key, body).await
+    }
+    async fn patch(
+        &self,
+        customer_key: &str,
+        body: &crate::types::Customer,
+    ) -> Result<crate::types::Customer> {
+        Customers::patch(self, customer_key, body).await
+    }
+    async fn admin_chrome_printers_get(&self, name: &str) -> Result<crate::types::Printer> {
+        Customers::admin_chrome_printers_get(self, name).await
+    }
+    async fn admin_chrome_printers_delete(&self, name: &str) -> Result<crate::types::Empty> {
+        Customers::admin_chrome_printers_delete(self, name).await
+    }
+    async fn admin_chrome_printers_patch(
+        &self,
+        name: &str,
+        clear_mask: &str,
+        update_mask: &str,
+        body: &crate::types::PrinterRequest,
+    ) -> Result<crate::types::Printer> {
+        Customers::admin_chrome_printers_patch(self, name, clear_mask, update_mask, body).await
+    }
+    async fn admin_chrome_printers_list(
+        &self,
+        parent: &str,
+        filter: &str,
+        org_unit_id: &str,
+        page_size: i64,
+        page_token: &str,
+    ) -> Result<Vec<crate::types::Printer>> {
+        Customers::admin_chrome_printers_list(
+            self,
+            parent,
+            filter,
+            org_unit_id,
+            page_size,
+            page_token,
+        )
+        .await
+    }
+    async fn admin_chrome_printers_list_all(
+        &self,
+        parent: &str,
+        filter: &str,
+        org_unit_id: &str,
+    ) -> Result<Vec<crate::types::Printer>> {
+        Customers::admin_chrome_printers_list_all(self, parent, filter, org_unit_id).await
+    }
+    async fn admin_chrome_printers_create(
+        &self,
+        parent: &str,
+        body: &crate::types::PrinterRequest,
+    ) -> Result<crate::types::Printer> {
+        Customers::admin_chrome_printers_create(self, parent, body).await
+    }
+    async fn admin_chrome_printers_batch_create(
+        &self,
+        parent: &str,
+        body: &crate::types::BatchCreatePrintersRequest,
+    ) -> Result<crate::types::BatchCreatePrintersResponse> {
+        Customers::admin_chrome_printers_batch_create(self, parent, body).await
+    }
+    async fn admin_chrome_printers_batch_delete(
+        &self,
+        parent: &str,
+        body: &crate::types::BatchDeletePrintersRequest,
+    ) -> Result<crate::types::BatchDeletePrintersResponse> {
+        Customers::admin_chrome_printers_batch_delete(self, parent, body).await
+    }
+    async fn admin_chrome_printers_list_printer_models(
+        &self,
+        parent: &str,
+        filter: &str,
+        page_size: i64,
+        page_token: &str,
+    ) -> Result<Vec<crate::types::PrinterModel>> {
+        Customers::admin_chrome_printers_list_printer_models(
+            self, parent, filter, page_size, page_token,
+        )
+        .await
+    }
+    async fn admin_chrome_printers_list_all_printer_models(
+        &self,
+        parent: &str,
+        filter: &str,
+    ) -> Result<Vec<crate::types::PrinterModel>> {
+        Customers::admin_chrome_printers_list_all_printer_models(self, parent, filter).await
+    }
+}
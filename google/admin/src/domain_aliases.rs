@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct DomainAliases {
     pub client: Client,
@@ -12,6 +10,14 @@ impl DomainAliases {
         DomainAliases { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        DomainAliases {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/domainaliases` endpoint.
      *
@@ -27,21 +33,25 @@ impl DomainAliases {
         customer: &str,
         parent_domain_name: &str,
     ) -> Result<crate::types::DomainAliases> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !parent_domain_name.is_empty() {
-            query_args.push((
-                "parentDomainName".to_string(),
-                parent_domain_name.to_string(),
-            ));
+            query_args.push(("parentDomainName", parent_domain_name.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/domainaliases?{}",
-            crate::progenitor_support::encode_path(&customer.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/directory/v1/customer/{}/domainaliases",
+                self.client.encode_path(customer)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/directory/v1/customer/{customer}/domainaliases",
+                &[("customer", customer.to_string())],
+            )
+        })
     }
 
     /**
@@ -60,12 +70,19 @@ impl DomainAliases {
     ) -> Result<crate::types::DomainAlias> {
         let url = format!(
             "/admin/directory/v1/customer/{}/domainaliases",
-            crate::progenitor_support::encode_path(&customer.to_string()),
+            self.client.encode_path(customer),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/directory/v1/customer/{customer}/domainaliases",
+                    &[("customer", customer.to_string())],
+                )
+            })
     }
 
     /**
@@ -85,11 +102,20 @@ impl DomainAliases {
     ) -> Result<crate::types::DomainAlias> {
         let url = format!(
             "/admin/directory/v1/customer/{}/domainaliases/{}",
-            crate::progenitor_support::encode_path(&customer.to_string()),
-            crate::progenitor_support::encode_path(&domain_alias_name.to_string()),
+            self.client.encode_path(customer),
+            self.client.encode_path(domain_alias_name),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/directory/v1/customer/{customer}/domainaliases/{domainAliasName}",
+                &[
+                    ("customer", customer.to_string()),
+                    ("domainAliasName", domain_alias_name.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -105,10 +131,103 @@ impl DomainAliases {
     pub async fn delete(&self, customer: &str, domain_alias_name: &str) -> Result<()> {
         let url = format!(
             "/admin/directory/v1/customer/{}/domainaliases/{}",
-            crate::progenitor_support::encode_path(&customer.to_string()),
-            crate::progenitor_support::encode_path(&domain_alias_name.to_string()),
+            self.client.encode_path(customer),
+            self.client.encode_path(domain_alias_name),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/directory/v1/customer/{customer}/domainaliases/{domainAliasName}",
+                &[
+                    ("customer", customer.to_string()),
+                    ("domainAliasName", domain_alias_name.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customer}/domainaliases/{domainAliasName}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, customer: &str, domain_alias_name: &str) -> Result<bool> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/domainaliases/{}",
+            self.client.encode_path(customer),
+            self.client.encode_path(domain_alias_name),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/directory/v1/customer/{customer}/domainaliases/{domainAliasName}",
+                    &[
+                        ("customer", customer.to_string()),
+                        ("domainAliasName", domain_alias_name.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`DomainAliases`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn DomainAliasesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait DomainAliasesApi: Send + Sync {
+    async fn list(
+        &self,
+        customer: &str,
+        parent_domain_name: &str,
+    ) -> Result<crate::types::DomainAliases>;
+    async fn insert(
+        &self,
+        customer: &str,
+        body: &crate::types::DomainAlias,
+    ) -> Result<crate::types::DomainAlias>;
+    async fn get(
+        &self,
+        customer: &str,
+        domain_alias_name: &str,
+    ) -> Result<crate::types::DomainAlias>;
+    async fn delete(&self, customer: &str, domain_alias_name: &str) -> Result<()>;
+    async fn delete_if_exists(&self, customer: &str, domain_alias_name: &str) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl DomainAliasesApi for DomainAliases {
+    async fn list(
+        &self,
+        customer: &str,
+        parent_domain_name: &str,
+    ) -> Result<crate::types::DomainAliases> {
+        DomainAliases::list(self, customer, parent_domain_name).await
+    }
+    async fn insert(
+        &self,
+        customer: &str,
+        body: &crate::types::DomainAlias,
+    ) -> Result<crate::types::DomainAlias> {
+        DomainAliases::insert(self, customer, body).await
+    }
+    async fn get(
+        &self,
+        customer: &str,
+        domain_alias_name: &str,
+    ) -> Result<crate::types::DomainAlias> {
+        DomainAliases::get(self, customer, domain_alias_name).await
+    }
+    async fn delete(&self, customer: &str, domain_alias_name: &str) -> Result<()> {
+        DomainAliases::delete(self, customer, domain_alias_name).await
+    }
+    async fn delete_if_exists(&self, customer: &str, domain_alias_name: &str) -> Result<bool> {
+        DomainAliases::delete_if_exists(self, customer, domain_alias_name).await
     }
 }
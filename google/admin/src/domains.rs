@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Domains {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Domains {
         Domains { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Domains {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/domains` endpoint.
      *
@@ -24,10 +30,16 @@ impl Domains {
     pub async fn list(&self, customer: &str) -> Result<crate::types::Domains2> {
         let url = format!(
             "/admin/directory/v1/customer/{}/domains",
-            crate::progenitor_support::encode_path(&customer.to_string()),
+            self.client.encode_path(customer),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/directory/v1/customer/{customer}/domains",
+                &[("customer", customer.to_string())],
+            )
+        })
     }
 
     /**
@@ -46,12 +58,19 @@ impl Domains {
     ) -> Result<crate::types::Domains> {
         let url = format!(
             "/admin/directory/v1/customer/{}/domains",
-            crate::progenitor_support::encode_path(&customer.to_string()),
+            self.client.encode_path(customer),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/directory/v1/customer/{customer}/domains",
+                    &[("customer", customer.to_string())],
+                )
+            })
     }
 
     /**
@@ -67,11 +86,20 @@ impl Domains {
     pub async fn get(&self, customer: &str, domain_name: &str) -> Result<crate::types::Domains> {
         let url = format!(
             "/admin/directory/v1/customer/{}/domains/{}",
-            crate::progenitor_support::encode_path(&customer.to_string()),
-            crate::progenitor_support::encode_path(&domain_name.to_string()),
+            self.client.encode_path(customer),
+            self.client.encode_path(domain_name),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/directory/v1/customer/{customer}/domains/{domainName}",
+                &[
+                    ("customer", customer.to_string()),
+                    ("domainName", domain_name.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -87,10 +115,87 @@ impl Domains {
     pub async fn delete(&self, customer: &str, domain_name: &str) -> Result<()> {
         let url = format!(
             "/admin/directory/v1/customer/{}/domains/{}",
-            crate::progenitor_support::encode_path(&customer.to_string()),
-            crate::progenitor_support::encode_path(&domain_name.to_string()),
+            self.client.encode_path(customer),
+            self.client.encode_path(domain_name),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/directory/v1/customer/{customer}/domains/{domainName}",
+                &[
+                    ("customer", customer.to_string()),
+                    ("domainName", domain_name.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customer}/domains/{domainName}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, customer: &str, domain_name: &str) -> Result<bool> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/domains/{}",
+            self.client.encode_path(customer),
+            self.client.encode_path(domain_name),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/directory/v1/customer/{customer}/domains/{domainName}",
+                    &[
+                        ("customer", customer.to_string()),
+                        ("domainName", domain_name.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`Domains`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn DomainsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait DomainsApi: Send + Sync {
+    async fn list(&self, customer: &str) -> Result<crate::types::Domains2>;
+    async fn insert(
+        &self,
+        customer: &str,
+        body: &crate::types::Domains,
+    ) -> Result<crate::types::Domains>;
+    async fn get(&self, customer: &str, domain_name: &str) -> Result<crate::types::Domains>;
+    async fn delete(&self, customer: &str, domain_name: &str) -> Result<()>;
+    async fn delete_if_exists(&self, customer: &str, domain_name: &str) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl DomainsApi for Domains {
+    async fn list(&self, customer: &str) -> Result<crate::types::Domains2> {
+        Domains::list(self, customer).await
+    }
+    async fn insert(
+        &self,
+        customer: &str,
+        body: &crate::types::Domains,
+    ) -> Result<crate::types::Domains> {
+        Domains::insert(self, customer, body).await
+    }
+    async fn get(&self, customer: &str, domain_name: &str) -> Result<crate::types::Domains> {
+        Domains::get(self, customer, domain_name).await
+    }
+    async fn delete(&self, customer: &str, domain_name: &str) -> Result<()> {
+        Domains::delete(self, customer, domain_name).await
+    }
+    async fn delete_if_exists(&self, customer: &str, domain_name: &str) -> Result<bool> {
+        Domains::delete_if_exists(self, customer, domain_name).await
     }
 }
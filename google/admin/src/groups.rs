@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Groups {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Groups {
         Groups { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Groups {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/groups` endpoint.
      *
@@ -39,35 +45,37 @@ impl Groups {
         sort_order: crate::types::SortOrder,
         user_key: &str,
     ) -> Result<Vec<crate::types::Group>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !customer.is_empty() {
-            query_args.push(("customer".to_string(), customer.to_string()));
+            query_args.push(("customer", customer.to_string()));
         }
         if !domain.is_empty() {
-            query_args.push(("domain".to_string(), domain.to_string()));
+            query_args.push(("domain", domain.to_string()));
         }
         if max_results > 0 {
-            query_args.push(("maxResults".to_string(), max_results.to_string()));
+            query_args.push(("maxResults", max_results.to_string()));
         }
         if !order_by.to_string().is_empty() {
-            query_args.push(("orderBy".to_string(), order_by.to_string()));
+            query_args.push(("orderBy", order_by.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
         if !sort_order.to_string().is_empty() {
-            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+            query_args.push(("sortOrder", sort_order.to_string()));
         }
         if !user_key.is_empty() {
-            query_args.push(("userKey".to_string(), user_key.to_string()));
+            query_args.push(("userKey", user_key.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/directory/v1/groups?{}", query_);
+        let url = crate::Client::url_with_query("/admin/directory/v1/groups", &query_args);
 
-        let resp: crate::types::Groups = self.client.get(&url, None).await?;
+        let resp: crate::types::Groups =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url("GET", "/admin/directory/v1/groups", &[])
+            })?;
 
         // Return our response data.
         Ok(resp.groups)
@@ -89,29 +97,31 @@ impl Groups {
         sort_order: crate::types::SortOrder,
         user_key: &str,
     ) -> Result<Vec<crate::types::Group>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !customer.is_empty() {
-            query_args.push(("customer".to_string(), customer.to_string()));
+            query_args.push(("customer", customer.to_string()));
         }
         if !domain.is_empty() {
-            query_args.push(("domain".to_string(), domain.to_string()));
+            query_args.push(("domain", domain.to_string()));
         }
         if !order_by.to_string().is_empty() {
-            query_args.push(("orderBy".to_string(), order_by.to_string()));
+            query_args.push(("orderBy", order_by.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
         if !sort_order.to_string().is_empty() {
-            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+            query_args.push(("sortOrder", sort_order.to_string()));
         }
         if !user_key.is_empty() {
-            query_args.push(("userKey".to_string(), user_key.to_string()));
+            query_args.push(("userKey", user_key.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/directory/v1/groups?{}", query_);
+        let url = crate::Client::url_with_query("/admin/directory/v1/groups", &query_args);
 
-        let mut resp: crate::types::Groups = self.client.get(&url, None).await?;
+        let mut resp: crate::types::Groups =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url("GET", "/admin/directory/v1/groups", &[])
+            })?;
 
         let mut groups = resp.groups;
         let mut page = resp.next_page_token;
@@ -151,8 +161,9 @@ impl Groups {
     pub async fn insert(&self, body: &crate::types::Group) -> Result<crate::types::Group> {
         let url = "/admin/directory/v1/groups".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/admin/directory/v1/groups", &[]))
     }
 
     /**
@@ -167,10 +178,16 @@ impl Groups {
     pub async fn get(&self, group_key: &str) -> Result<crate::types::Group> {
         let url = format!(
             "/admin/directory/v1/groups/{}",
-            crate::progenitor_support::encode_path(&group_key.to_string()),
+            self.client.encode_path(group_key),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/directory/v1/groups/{groupKey}",
+                &[("groupKey", group_key.to_string())],
+            )
+        })
     }
 
     /**
@@ -189,12 +206,19 @@ impl Groups {
     ) -> Result<crate::types::Group> {
         let url = format!(
             "/admin/directory/v1/groups/{}",
-            crate::progenitor_support::encode_path(&group_key.to_string()),
+            self.client.encode_path(group_key),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/directory/v1/groups/{groupKey}",
+                    &[("groupKey", group_key.to_string())],
+                )
+            })
     }
 
     /**
@@ -209,10 +233,39 @@ impl Groups {
     pub async fn delete(&self, group_key: &str) -> Result<()> {
         let url = format!(
             "/admin/directory/v1/groups/{}",
-            crate::progenitor_support::encode_path(&group_key.to_string()),
+            self.client.encode_path(group_key),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/directory/v1/groups/{groupKey}",
+                &[("groupKey", group_key.to_string())],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/admin/directory/v1/groups/{groupKey}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, group_key: &str) -> Result<bool> {
+        let url = format!(
+            "/admin/directory/v1/groups/{}",
+            self.client.encode_path(group_key),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/directory/v1/groups/{groupKey}",
+                    &[("groupKey", group_key.to_string())],
+                )
+            })
     }
 
     /**
@@ -231,12 +284,47 @@ impl Groups {
     ) -> Result<crate::types::Group> {
         let url = format!(
             "/admin/directory/v1/groups/{}",
-            crate::progenitor_support::encode_path(&group_key.to_string()),
+            self.client.encode_path(group_key),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/admin/directory/v1/groups/{groupKey}",
+                    &[("groupKey", group_key.to_string())],
+                )
+            })
+    }
+
+    /**
+     * This function performs a `PATCH` to the `/admin/directory/v1/groups/{groupKey}` endpoint.
+     *
+     * As opposed to `patch`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_if_changed<C: serde::Serialize>(
+        &self,
+        group_key: &str,
+        current: &C,
+        body: &crate::types::Group,
+    ) -> Result<Option<crate::types::Group>> {
+        let url = format!(
+            "/admin/directory/v1/groups/{}",
+            self.client.encode_path(group_key),
+        );
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/admin/directory/v1/groups/{groupKey}",
+                    &[("groupKey", group_key.to_string())],
+                )
+            })
     }
 
     /**
@@ -251,10 +339,16 @@ impl Groups {
     pub async fn aliases_list(&self, group_key: &str) -> Result<crate::types::Aliases> {
         let url = format!(
             "/admin/directory/v1/groups/{}/aliases",
-            crate::progenitor_support::encode_path(&group_key.to_string()),
+            self.client.encode_path(group_key),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/directory/v1/groups/{groupKey}/aliases",
+                &[("groupKey", group_key.to_string())],
+            )
+        })
     }
 
     /**
@@ -273,12 +367,19 @@ impl Groups {
     ) -> Result<crate::types::Alias> {
         let url = format!(
             "/admin/directory/v1/groups/{}/aliases",
-            crate::progenitor_support::encode_path(&group_key.to_string()),
+            self.client.encode_path(group_key),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/directory/v1/groups/{groupKey}/aliases",
+                    &[("groupKey", group_key.to_string())],
+                )
+            })
     }
 
     /**
@@ -294,10 +395,181 @@ impl Groups {
     pub async fn aliases_delete(&self, group_key: &str, alias: &str) -> Result<()> {
         let url = format!(
             "/admin/directory/v1/groups/{}/aliases/{}",
-            crate::progenitor_support::encode_path(&group_key.to_string()),
-            crate::progenitor_support::encode_path(&alias.to_string()),
+            self.client.encode_path(group_key),
+            self.client.encode_path(alias),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/directory/v1/groups/{groupKey}/aliases/{alias}",
+                &[
+                    ("groupKey", group_key.to_string()),
+                    ("alias", alias.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/admin/directory/v1/groups/{groupKey}/aliases/{alias}` endpoint.
+     *
+     * As opposed to `aliases_delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn aliases_delete_if_exists(&self, group_key: &str, alias: &str) -> Result<bool> {
+        let url = format!(
+            "/admin/directory/v1/groups/{}/aliases/{}",
+            self.client.encode_path(group_key),
+            self.client.encode_path(alias),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/directory/v1/groups/{groupKey}/aliases/{alias}",
+                    &[
+                        ("groupKey", group_key.to_string()),
+                        ("alias", alias.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`Groups`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn GroupsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait GroupsApi: Send + Sync {
+    async fn list(
+        &self,
+        customer: &str,
+        domain: &str,
+        max_results: i64,
+        order_by: crate::types::DirectoryGroupsListOrderBy,
+        page_token: &str,
+        query: &str,
+        sort_order: crate::types::SortOrder,
+        user_key: &str,
+    ) -> Result<Vec<crate::types::Group>>;
+    async fn list_all(
+        &self,
+        customer: &str,
+        domain: &str,
+        order_by: crate::types::DirectoryGroupsListOrderBy,
+        query: &str,
+        sort_order: crate::types::SortOrder,
+        user_key: &str,
+    ) -> Result<Vec<crate::types::Group>>;
+    async fn insert(&self, body: &crate::types::Group) -> Result<crate::types::Group>;
+    async fn get(&self, group_key: &str) -> Result<crate::types::Group>;
+    async fn update(
+        &self,
+        group_key: &str,
+        body: &crate::types::Group,
+    ) -> Result<crate::types::Group>;
+    async fn delete(&self, group_key: &str) -> Result<()>;
+    async fn delete_if_exists(&self, group_key: &str) -> Result<bool>;
+    async fn patch(
+        &self,
+        group_key: &str,
+        body: &crate::types::Group,
+    ) -> Result<crate::types::Group>;
+    async fn aliases_list(&self, group_key: &str) -> Result<crate::types::Aliases>;
+    async fn aliases_insert(
+        &self,
+        group_key: &str,
+        body: &crate::types::Alias,
+    ) -> Result<crate::types::Alias>;
+    async fn aliases_delete(&self, group_key: &str, alias: &str) -> Result<()>;
+    async fn aliases_delete_if_exists(&self, group_key: &str, alias: &str) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl GroupsApi for Groups {
+    async fn list(
+        &self,
+        customer: &str,
+        domain: &str,
+        max_results: i64,
+        order_by: crate::types::DirectoryGroupsListOrderBy,
+        page_token: &str,
+        query: &str,
+        sort_order: crate::types::SortOrder,
+        user_key: &str,
+    ) -> Result<Vec<crate::types::Group>> {
+        Groups::list(
+            self,
+            customer,
+            domain,
+            max_results,
+            order_by,
+            page_token,
+            query,
+            sort_order,
+            user_key,
+        )
+        .await
+    }
+    async fn list_all(
+        &self,
+        customer: &str,
+        domain: &str,
+        order_by: crate::types::DirectoryGroupsListOrderBy,
+        query: &str,
+        sort_order: crate::types::SortOrder,
+        user_key: &str,
+    ) -> Result<Vec<crate::types::Group>> {
+        Groups::list_all(
+            self, customer, domain, order_by, query, sort_order, user_key,
+        )
+        .await
+    }
+    async fn insert(&self, body: &crate::types::Group) -> Result<crate::types::Group> {
+        Groups::insert(self, body).await
+    }
+    async fn get(&self, group_key: &str) -> Result<crate::types::Group> {
+        Groups::get(self, group_key).await
+    }
+    async fn update(
+        &self,
+        group_key: &str,
+        body: &crate::types::Group,
+    ) -> Result<crate::types::Group> {
+        Groups::update(self, group_key, body).await
+    }
+    async fn delete(&self, group_key: &str) -> Result<()> {
+        Groups::delete(self, group_key).await
+    }
+    async fn delete_if_exists(&self, group_key: &str) -> Result<bool> {
+        Groups::delete_if_exists(self, group_key).await
+    }
+    async fn patch(
+        &self,
+        group_key: &str,
+        body: &crate::types::Group,
+    ) -> Result<crate::types::Group> {
+        Groups::patch(self, group_key, body).await
+    }
+    async fn aliases_list(&self, group_key: &str) -> Result<crate::types::Aliases> {
+        Groups::aliases_list(self, group_key).await
+    }
+    async fn aliases_insert(
+        &self,
+        group_key: &str,
+        body: &crate::types::Alias,
+    ) -> Result<crate::types::Alias> {
+        Groups::aliases_insert(self, group_key, body).await
+    }
+    async fn aliases_delete(&self, group_key: &str, alias: &str) -> Result<()> {
+        Groups::aliases_delete(self, group_key, alias).await
+    }
+    async fn aliases_delete_if_exists(&self, group_key: &str, alias: &str) -> Result<bool> {
+        Groups::aliases_delete_if_exists(self, group_key, alias).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Members {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Members {
         Members { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Members {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/groups/{groupKey}/hasMember/{memberKey}` endpoint.
      *
@@ -29,11 +35,20 @@ impl Members {
     ) -> Result<crate::types::MembersHasMember> {
         let url = format!(
             "/admin/directory/v1/groups/{}/hasMember/{}",
-            crate::progenitor_support::encode_path(&group_key.to_string()),
-            crate::progenitor_support::encode_path(&member_key.to_string()),
+            self.client.encode_path(group_key),
+            self.client.encode_path(member_key),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/directory/v1/groups/{groupKey}/hasMember/{memberKey}",
+                &[
+                    ("groupKey", group_key.to_string()),
+                    ("memberKey", member_key.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -57,30 +72,37 @@ impl Members {
         page_token: &str,
         roles: &str,
     ) -> Result<Vec<crate::types::Member>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if include_derived_membership {
             query_args.push((
-                "includeDerivedMembership".to_string(),
+                "includeDerivedMembership",
                 include_derived_membership.to_string(),
             ));
         }
         if max_results > 0 {
-            query_args.push(("maxResults".to_string(), max_results.to_string()));
+            query_args.push(("maxResults", max_results.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if !roles.is_empty() {
-            query_args.push(("roles".to_string(), roles.to_string()));
+            query_args.push(("roles", roles.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/groups/{}/members?{}",
-            crate::progenitor_support::encode_path(&group_key.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/directory/v1/groups/{}/members",
+                self.client.encode_path(group_key)
+            ),
+            &query_args,
         );
 
-        let resp: crate::types::Members = self.client.get(&url, None).await?;
+        let resp: crate::types::Members = self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/directory/v1/groups/{groupKey}/members",
+                &[("groupKey", group_key.to_string())],
+            )
+        })?;
 
         // Return our response data.
         Ok(resp.members)
@@ -99,24 +121,32 @@ impl Members {
         include_derived_membership: bool,
         roles: &str,
     ) -> Result<Vec<crate::types::Member>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if include_derived_membership {
             query_args.push((
-                "includeDerivedMembership".to_string(),
+                "includeDerivedMembership",
                 include_derived_membership.to_string(),
             ));
         }
         if !roles.is_empty() {
-            query_args.push(("roles".to_string(), roles.to_string()));
+            query_args.push(("roles", roles.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/groups/{}/members?{}",
-            crate::progenitor_support::encode_path(&group_key.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/directory/v1/groups/{}/members",
+                self.client.encode_path(group_key)
+            ),
+            &query_args,
         );
 
-        let mut resp: crate::types::Members = self.client.get(&url, None).await?;
+        let mut resp: crate::types::Members =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/admin/directory/v1/groups/{groupKey}/members",
+                    &[("groupKey", group_key.to_string())],
+                )
+            })?;
 
         let mut members = resp.members;
         let mut page = resp.next_page_token;
@@ -164,12 +194,19 @@ impl Members {
     ) -> Result<crate::types::Member> {
         let url = format!(
             "/admin/directory/v1/groups/{}/members",
-            crate::progenitor_support::encode_path(&group_key.to_string()),
+            self.client.encode_path(group_key),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/directory/v1/groups/{groupKey}/members",
+                    &[("groupKey", group_key.to_string())],
+                )
+            })
     }
 
     /**
@@ -185,11 +222,20 @@ impl Members {
     pub async fn get(&self, group_key: &str, member_key: &str) -> Result<crate::types::Member> {
         let url = format!(
             "/admin/directory/v1/groups/{}/members/{}",
-            crate::progenitor_support::encode_path(&group_key.to_string()),
-            crate::progenitor_support::encode_path(&member_key.to_string()),
+            self.client.encode_path(group_key),
+            self.client.encode_path(member_key),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/directory/v1/groups/{groupKey}/members/{memberKey}",
+                &[
+                    ("groupKey", group_key.to_string()),
+                    ("memberKey", member_key.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -210,13 +256,23 @@ impl Members {
     ) -> Result<crate::types::Member> {
         let url = format!(
             "/admin/directory/v1/groups/{}/members/{}",
-            crate::progenitor_support::encode_path(&group_key.to_string()),
-            crate::progenitor_support::encode_path(&member_key.to_string()),
+            self.client.encode_path(group_key),
+            self.client.encode_path(member_key),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/directory/v1/groups/{groupKey}/members/{memberKey}",
+                    &[
+                        ("groupKey", group_key.to_string()),
+                        ("memberKey", member_key.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -232,11 +288,47 @@ impl Members {
     pub async fn delete(&self, group_key: &str, member_key: &str) -> Result<()> {
         let url = format!(
             "/admin/directory/v1/groups/{}/members/{}",
-            crate::progenitor_support::encode_path(&group_key.to_string()),
-            crate::progenitor_support::encode_path(&member_key.to_string()),
+            self.client.encode_path(group_key),
+            self.client.encode_path(member_key),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/directory/v1/groups/{groupKey}/members/{memberKey}",
+                &[
+                    ("groupKey", group_key.to_string()),
+                    ("memberKey", member_key.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/admin/directory/v1/groups/{groupKey}/members/{memberKey}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, group_key: &str, member_key: &str) -> Result<bool> {
+        let url = format!(
+            "/admin/directory/v1/groups/{}/members/{}",
+            self.client.encode_path(group_key),
+            self.client.encode_path(member_key),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/directory/v1/groups/{groupKey}/members/{memberKey}",
+                    &[
+                        ("groupKey", group_key.to_string()),
+                        ("memberKey", member_key.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -257,12 +349,172 @@ impl Members {
     ) -> Result<crate::types::Member> {
         let url = format!(
             "/admin/directory/v1/groups/{}/members/{}",
-            crate::progenitor_support::encode_path(&group_key.to_string()),
-            crate::progenitor_support::encode_path(&member_key.to_string()),
+            self.client.encode_path(group_key),
+            self.client.encode_path(member_key),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/admin/directory/v1/groups/{groupKey}/members/{memberKey}",
+                    &[
+                        ("groupKey", group_key.to_string()),
+                        ("memberKey", member_key.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * This function performs a `PATCH` to the `/admin/directory/v1/groups/{groupKey}/members/{memberKey}` endpoint.
+     *
+     * As opposed to `patch`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_if_changed<C: serde::Serialize>(
+        &self,
+        group_key: &str,
+        member_key: &str,
+        current: &C,
+        body: &crate::types::Member,
+    ) -> Result<Option<crate::types::Member>> {
+        let url = format!(
+            "/admin/directory/v1/groups/{}/members/{}",
+            self.client.encode_path(group_key),
+            self.client.encode_path(member_key),
+        );
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/admin/directory/v1/groups/{groupKey}/members/{memberKey}",
+                    &[
+                        ("groupKey", group_key.to_string()),
+                        ("memberKey", member_key.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`Members`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn MembersApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait MembersApi: Send + Sync {
+    async fn has(
+        &self,
+        group_key: &str,
+        member_key: &str,
+    ) -> Result<crate::types::MembersHasMember>;
+    async fn list(
+        &self,
+        group_key: &str,
+        include_derived_membership: bool,
+        max_results: i64,
+        page_token: &str,
+        roles: &str,
+    ) -> Result<Vec<crate::types::Member>>;
+    async fn list_all(
+        &self,
+        group_key: &str,
+        include_derived_membership: bool,
+        roles: &str,
+    ) -> Result<Vec<crate::types::Member>>;
+    async fn insert(
+        &self,
+        group_key: &str,
+        body: &crate::types::Member,
+    ) -> Result<crate::types::Member>;
+    async fn get(&self, group_key: &str, member_key: &str) -> Result<crate::types::Member>;
+    async fn update(
+        &self,
+        group_key: &str,
+        member_key: &str,
+        body: &crate::types::Member,
+    ) -> Result<crate::types::Member>;
+    async fn delete(&self, group_key: &str, member_key: &str) -> Result<()>;
+    async fn delete_if_exists(&self, group_key: &str, member_key: &str) -> Result<bool>;
+    async fn patch(
+        &self,
+        group_key: &str,
+        member_key: &str,
+        body: &crate::types::Member,
+    ) -> Result<crate::types::Member>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl MembersApi for Members {
+    async fn has(
+        &self,
+        group_key: &str,
+        member_key: &str,
+    ) -> Result<crate::types::MembersHasMember> {
+        Members::has(self, group_key, member_key).await
+    }
+    async fn list(
+        &self,
+        group_key: &str,
+        include_derived_membership: bool,
+        max_results: i64,
+        page_token: &str,
+        roles: &str,
+    ) -> Result<Vec<crate::types::Member>> {
+        Members::list(
+            self,
+            group_key,
+            include_derived_membership,
+            max_results,
+            page_token,
+            roles,
+        )
+        .await
+    }
+    async fn list_all(
+        &self,
+        group_key: &str,
+        include_derived_membership: bool,
+        roles: &str,
+    ) -> Result<Vec<crate::types::Member>> {
+        Members::list_all(self, group_key, include_derived_membership, roles).await
+    }
+    async fn insert(
+        &self,
+        group_key: &str,
+        body: &crate::types::Member,
+    ) -> Result<crate::types::Member> {
+        Members::insert(self, group_key, body).await
+    }
+    async fn get(&self, group_key: &str, member_key: &str) -> Result<crate::types::Member> {
+        Members::get(self, group_key, member_key).await
+    }
+    async fn update(
+        &self,
+        group_key: &str,
+        member_key: &str,
+        body: &crate::types::Member,
+    ) -> Result<crate::types::Member> {
+        Members::update(self, group_key, member_key, body).await
+    }
+    async fn delete(&self, group_key: &str, member_key: &str) -> Result<()> {
+        Members::delete(self, group_key, member_key).await
+    }
+    async fn delete_if_exists(&self, group_key: &str, member_key: &str) -> Result<bool> {
+        Members::delete_if_exists(self, group_key, member_key).await
+    }
+    async fn patch(
+        &self,
+        group_key: &str,
+        member_key: &str,
+        body: &crate::types::Member,
+    ) -> Result<crate::types::Member> {
+        Members::patch(self, group_key, member_key, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Mobiledevices {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Mobiledevices {
         Mobiledevices { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Mobiledevices {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/devices/mobile` endpoint.
      *
@@ -37,33 +43,41 @@ impl Mobiledevices {
         query: &str,
         sort_order: crate::types::SortOrder,
     ) -> Result<Vec<crate::types::MobileDevice>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if max_results > 0 {
-            query_args.push(("maxResults".to_string(), max_results.to_string()));
+            query_args.push(("maxResults", max_results.to_string()));
         }
         if !order_by.to_string().is_empty() {
-            query_args.push(("orderBy".to_string(), order_by.to_string()));
+            query_args.push(("orderBy", order_by.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if !projection.to_string().is_empty() {
-            query_args.push(("projection".to_string(), projection.to_string()));
+            query_args.push(("projection", projection.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
         if !sort_order.to_string().is_empty() {
-            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+            query_args.push(("sortOrder", sort_order.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/devices/mobile?{}",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/directory/v1/customer/{}/devices/mobile",
+                self.client.encode_path(customer_id)
+            ),
+            &query_args,
         );
 
-        let resp: crate::types::MobileDevices = self.client.get(&url, None).await?;
+        let resp: crate::types::MobileDevices =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/admin/directory/v1/customer/{customerId}/devices/mobile",
+                    &[("customerId", customer_id.to_string())],
+                )
+            })?;
 
         // Return our response data.
         Ok(resp.mobiledevices)
@@ -84,27 +98,35 @@ impl Mobiledevices {
         query: &str,
         sort_order: crate::types::SortOrder,
     ) -> Result<Vec<crate::types::MobileDevice>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !order_by.to_string().is_empty() {
-            query_args.push(("orderBy".to_string(), order_by.to_string()));
+            query_args.push(("orderBy", order_by.to_string()));
         }
         if !projection.to_string().is_empty() {
-            query_args.push(("projection".to_string(), projection.to_string()));
+            query_args.push(("projection", projection.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
         if !sort_order.to_string().is_empty() {
-            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+            query_args.push(("sortOrder", sort_order.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/devices/mobile?{}",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/directory/v1/customer/{}/devices/mobile",
+                self.client.encode_path(customer_id)
+            ),
+            &query_args,
         );
 
-        let mut resp: crate::types::MobileDevices = self.client.get(&url, None).await?;
+        let mut resp: crate::types::MobileDevices =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/admin/directory/v1/customer/{customerId}/devices/mobile",
+                    &[("customerId", customer_id.to_string())],
+                )
+            })?;
 
         let mut mobiledevices = resp.mobiledevices;
         let mut page = resp.next_page_token;
@@ -153,19 +175,29 @@ impl Mobiledevices {
         resource_id: &str,
         projection: crate::types::Projection,
     ) -> Result<crate::types::MobileDevice> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !projection.to_string().is_empty() {
-            query_args.push(("projection".to_string(), projection.to_string()));
+            query_args.push(("projection", projection.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/devices/mobile/{}?{}",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            crate::progenitor_support::encode_path(&resource_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/directory/v1/customer/{}/devices/mobile/{}",
+                self.client.encode_path(customer_id),
+                self.client.encode_path(resource_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/directory/v1/customer/{customerId}/devices/mobile/{resourceId}",
+                &[
+                    ("customerId", customer_id.to_string()),
+                    ("resourceId", resource_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -181,11 +213,47 @@ impl Mobiledevices {
     pub async fn delete(&self, customer_id: &str, resource_id: &str) -> Result<()> {
         let url = format!(
             "/admin/directory/v1/customer/{}/devices/mobile/{}",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            crate::progenitor_support::encode_path(&resource_id.to_string()),
+            self.client.encode_path(customer_id),
+            self.client.encode_path(resource_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/directory/v1/customer/{customerId}/devices/mobile/{resourceId}",
+                &[
+                    ("customerId", customer_id.to_string()),
+                    ("resourceId", resource_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customerId}/devices/mobile/{resourceId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, customer_id: &str, resource_id: &str) -> Result<bool> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/devices/mobile/{}",
+            self.client.encode_path(customer_id),
+            self.client.encode_path(resource_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/directory/v1/customer/{customerId}/devices/mobile/{resourceId}",
+                    &[
+                        ("customerId", customer_id.to_string()),
+                        ("resourceId", resource_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -206,12 +274,121 @@ impl Mobiledevices {
     ) -> Result<()> {
         let url = format!(
             "/admin/directory/v1/customer/{}/devices/mobile/{}/action",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            crate::progenitor_support::encode_path(&resource_id.to_string()),
+            self.client.encode_path(customer_id),
+            self.client.encode_path(resource_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/directory/v1/customer/{customerId}/devices/mobile/{resourceId}/action",
+                    &[
+                        ("customerId", customer_id.to_string()),
+                        ("resourceId", resource_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`Mobiledevices`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn MobiledevicesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait MobiledevicesApi: Send + Sync {
+    async fn list(
+        &self,
+        customer_id: &str,
+        max_results: i64,
+        order_by: crate::types::DirectoryMobiledevicesListOrderBy,
+        page_token: &str,
+        projection: crate::types::Projection,
+        query: &str,
+        sort_order: crate::types::SortOrder,
+    ) -> Result<Vec<crate::types::MobileDevice>>;
+    async fn list_all(
+        &self,
+        customer_id: &str,
+        order_by: crate::types::DirectoryMobiledevicesListOrderBy,
+        projection: crate::types::Projection,
+        query: &str,
+        sort_order: crate::types::SortOrder,
+    ) -> Result<Vec<crate::types::MobileDevice>>;
+    async fn get(
+        &self,
+        customer_id: &str,
+        resource_id: &str,
+        projection: crate::types::Projection,
+    ) -> Result<crate::types::MobileDevice>;
+    async fn delete(&self, customer_id: &str, resource_id: &str) -> Result<()>;
+    async fn delete_if_exists(&self, customer_id: &str, resource_id: &str) -> Result<bool>;
+    async fn action(
+        &self,
+        customer_id: &str,
+        resource_id: &str,
+        body: &crate::types::MobileDeviceAction,
+    ) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl MobiledevicesApi for Mobiledevices {
+    async fn list(
+        &self,
+        customer_id: &str,
+        max_results: i64,
+        order_by: crate::types::DirectoryMobiledevicesListOrderBy,
+        page_token: &str,
+        projection: crate::types::Projection,
+        query: &str,
+        sort_order: crate::types::SortOrder,
+    ) -> Result<Vec<crate::types::MobileDevice>> {
+        Mobiledevices::list(
+            self,
+            customer_id,
+            max_results,
+            order_by,
+            page_token,
+            projection,
+            query,
+            sort_order,
+        )
+        .await
+    }
+    async fn list_all(
+        &self,
+        customer_id: &str,
+        order_by: crate::types::DirectoryMobiledevicesListOrderBy,
+        projection: crate::types::Projection,
+        query: &str,
+        sort_order: crate::types::SortOrder,
+    ) -> Result<Vec<crate::types::MobileDevice>> {
+        Mobiledevices::list_all(self, customer_id, order_by, projection, query, sort_order).await
+    }
+    async fn get(
+        &self,
+        customer_id: &str,
+        resource_id: &str,
+        projection: crate::types::Projection,
+    ) -> Result<crate::types::MobileDevice> {
+        Mobiledevices::get(self, customer_id, resource_id, projection).await
+    }
+    async fn delete(&self, customer_id: &str, resource_id: &str) -> Result<()> {
+        Mobiledevices::delete(self, customer_id, resource_id).await
+    }
+    async fn delete_if_exists(&self, customer_id: &str, resource_id: &str) -> Result<bool> {
+        Mobiledevices::delete_if_exists(self, customer_id, resource_id).await
+    }
+    async fn action(
+        &self,
+        customer_id: &str,
+        resource_id: &str,
+        body: &crate::types::MobileDeviceAction,
+    ) -> Result<()> {
+        Mobiledevices::action(self, customer_id, resource_id, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Orgunits {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Orgunits {
         Orgunits { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Orgunits {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/orgunits` endpoint.
      *
@@ -29,21 +35,28 @@ impl Orgunits {
         org_unit_path: &str,
         type_: crate::types::DirectoryOrgunitsListType,
     ) -> Result<crate::types::OrgUnits> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !org_unit_path.is_empty() {
-            query_args.push(("orgUnitPath".to_string(), org_unit_path.to_string()));
+            query_args.push(("orgUnitPath", org_unit_path.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/orgunits?{}",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/directory/v1/customer/{}/orgunits",
+                self.client.encode_path(customer_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/directory/v1/customer/{customerId}/orgunits",
+                &[("customerId", customer_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -62,12 +75,19 @@ impl Orgunits {
     ) -> Result<crate::types::OrgUnit> {
         let url = format!(
             "/admin/directory/v1/customer/{}/orgunits",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/directory/v1/customer/{customerId}/orgunits",
+                    &[("customerId", customer_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -87,11 +107,20 @@ impl Orgunits {
     ) -> Result<crate::types::OrgUnit> {
         let url = format!(
             "/admin/directory/v1/customer/{}/orgunits/{}",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            crate::progenitor_support::encode_path(&org_unit_path.to_string()),
+            self.client.encode_path(customer_id),
+            self.client.encode_path(org_unit_path),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/directory/v1/customer/{customerId}/orgunits/{orgUnitPath}",
+                &[
+                    ("customerId", customer_id.to_string()),
+                    ("orgUnitPath", org_unit_path.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -112,13 +141,23 @@ impl Orgunits {
     ) -> Result<crate::types::OrgUnit> {
         let url = format!(
             "/admin/directory/v1/customer/{}/orgunits/{}",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            crate::progenitor_support::encode_path(&org_unit_path.to_string()),
+            self.client.encode_path(customer_id),
+            self.client.encode_path(org_unit_path),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/directory/v1/customer/{customerId}/orgunits/{orgUnitPath}",
+                    &[
+                        ("customerId", customer_id.to_string()),
+                        ("orgUnitPath", org_unit_path.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -134,11 +173,47 @@ impl Orgunits {
     pub async fn delete(&self, customer_id: &str, org_unit_path: &str) -> Result<()> {
         let url = format!(
             "/admin/directory/v1/customer/{}/orgunits/{}",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            crate::progenitor_support::encode_path(&org_unit_path.to_string()),
+            self.client.encode_path(customer_id),
+            self.client.encode_path(org_unit_path),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/directory/v1/customer/{customerId}/orgunits/{orgUnitPath}",
+                &[
+                    ("customerId", customer_id.to_string()),
+                    ("orgUnitPath", org_unit_path.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customerId}/orgunits/{orgUnitPath}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, customer_id: &str, org_unit_path: &str) -> Result<bool> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/orgunits/{}",
+            self.client.encode_path(customer_id),
+            self.client.encode_path(org_unit_path),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/directory/v1/customer/{customerId}/orgunits/{orgUnitPath}",
+                    &[
+                        ("customerId", customer_id.to_string()),
+                        ("orgUnitPath", org_unit_path.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -159,12 +234,134 @@ impl Orgunits {
     ) -> Result<crate::types::OrgUnit> {
         let url = format!(
             "/admin/directory/v1/customer/{}/orgunits/{}",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            crate::progenitor_support::encode_path(&org_unit_path.to_string()),
+            self.client.encode_path(customer_id),
+            self.client.encode_path(org_unit_path),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/admin/directory/v1/customer/{customerId}/orgunits/{orgUnitPath}",
+                    &[
+                        ("customerId", customer_id.to_string()),
+                        ("orgUnitPath", org_unit_path.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * This function performs a `PATCH` to the `/admin/directory/v1/customer/{customerId}/orgunits/{orgUnitPath}` endpoint.
+     *
+     * As opposed to `patch`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_if_changed<C: serde::Serialize>(
+        &self,
+        customer_id: &str,
+        org_unit_path: &str,
+        current: &C,
+        body: &crate::types::OrgUnit,
+    ) -> Result<Option<crate::types::OrgUnit>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/orgunits/{}",
+            self.client.encode_path(customer_id),
+            self.client.encode_path(org_unit_path),
+        );
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/admin/directory/v1/customer/{customerId}/orgunits/{orgUnitPath}",
+                    &[
+                        ("customerId", customer_id.to_string()),
+                        ("orgUnitPath", org_unit_path.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`Orgunits`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn OrgunitsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait OrgunitsApi: Send + Sync {
+    async fn list(
+        &self,
+        customer_id: &str,
+        org_unit_path: &str,
+        type_: crate::types::DirectoryOrgunitsListType,
+    ) -> Result<crate::types::OrgUnits>;
+    async fn insert(
+        &self,
+        customer_id: &str,
+        body: &crate::types::OrgUnit,
+    ) -> Result<crate::types::OrgUnit>;
+    async fn get(&self, customer_id: &str, org_unit_path: &str) -> Result<crate::types::OrgUnit>;
+    async fn update(
+        &self,
+        customer_id: &str,
+        org_unit_path: &str,
+        body: &crate::types::OrgUnit,
+    ) -> Result<crate::types::OrgUnit>;
+    async fn delete(&self, customer_id: &str, org_unit_path: &str) -> Result<()>;
+    async fn delete_if_exists(&self, customer_id: &str, org_unit_path: &str) -> Result<bool>;
+    async fn patch(
+        &self,
+        customer_id: &str,
+        org_unit_path: &str,
+        body: &crate::types::OrgUnit,
+    ) -> Result<crate::types::OrgUnit>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl OrgunitsApi for Orgunits {
+    async fn list(
+        &self,
+        customer_id: &str,
+        org_unit_path: &str,
+        type_: crate::types::DirectoryOrgunitsListType,
+    ) -> Result<crate::types::OrgUnits> {
+        Orgunits::list(self, customer_id, org_unit_path, type_).await
+    }
+    async fn insert(
+        &self,
+        customer_id: &str,
+        body: &crate::types::OrgUnit,
+    ) -> Result<crate::types::OrgUnit> {
+        Orgunits::insert(self, customer_id, body).await
+    }
+    async fn get(&self, customer_id: &str, org_unit_path: &str) -> Result<crate::types::OrgUnit> {
+        Orgunits::get(self, customer_id, org_unit_path).await
+    }
+    async fn update(
+        &self,
+        customer_id: &str,
+        org_unit_path: &str,
+        body: &crate::types::OrgUnit,
+    ) -> Result<crate::types::OrgUnit> {
+        Orgunits::update(self, customer_id, org_unit_path, body).await
+    }
+    async fn delete(&self, customer_id: &str, org_unit_path: &str) -> Result<()> {
+        Orgunits::delete(self, customer_id, org_unit_path).await
+    }
+    async fn delete_if_exists(&self, customer_id: &str, org_unit_path: &str) -> Result<bool> {
+        Orgunits::delete_if_exists(self, customer_id, org_unit_path).await
+    }
+    async fn patch(
+        &self,
+        customer_id: &str,
+        org_unit_path: &str,
+        body: &crate::types::OrgUnit,
+    ) -> Result<crate::types::OrgUnit> {
+        Orgunits::patch(self, customer_id, org_unit_path, body).await
     }
 }
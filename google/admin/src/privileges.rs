@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Privileges {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Privileges {
         Privileges { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Privileges {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/roles/ALL/privileges` endpoint.
      *
@@ -24,9 +30,32 @@ impl Privileges {
     pub async fn list(&self, customer: &str) -> Result<crate::types::Privileges> {
         let url = format!(
             "/admin/directory/v1/customer/{}/roles/ALL/privileges",
-            crate::progenitor_support::encode_path(&customer.to_string()),
+            self.client.encode_path(customer),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/directory/v1/customer/{customer}/roles/ALL/privileges",
+                &[("customer", customer.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`Privileges`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn PrivilegesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait PrivilegesApi: Send + Sync {
+    async fn list(&self, customer: &str) -> Result<crate::types::Privileges>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl PrivilegesApi for Privileges {
+    async fn list(&self, customer: &str) -> Result<crate::types::Privileges> {
+        Privileges::list(self, customer).await
     }
 }
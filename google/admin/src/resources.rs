@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Resources {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Resources {
         Resources { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Resources {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/buildings` endpoint.
      *
@@ -29,21 +35,29 @@ impl Resources {
         max_results: i64,
         page_token: &str,
     ) -> Result<Vec<crate::types::Building>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if max_results > 0 {
-            query_args.push(("maxResults".to_string(), max_results.to_string()));
+            query_args.push(("maxResults", max_results.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/resources/buildings?{}",
-            crate::progenitor_support::encode_path(&customer.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/directory/v1/customer/{}/resources/buildings",
+                self.client.encode_path(customer)
+            ),
+            &query_args,
         );
 
-        let resp: crate::types::Buildings = self.client.get(&url, None).await?;
+        let resp: crate::types::Buildings =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/admin/directory/v1/customer/{customer}/resources/buildings",
+                    &[("customer", customer.to_string())],
+                )
+            })?;
 
         // Return our response data.
         Ok(resp.buildings)
@@ -59,10 +73,17 @@ impl Resources {
     pub async fn buildings_list_all(&self, customer: &str) -> Result<Vec<crate::types::Building>> {
         let url = format!(
             "/admin/directory/v1/customer/{}/resources/buildings",
-            crate::progenitor_support::encode_path(&customer.to_string()),
+            self.client.encode_path(customer),
         );
 
-        let mut resp: crate::types::Buildings = self.client.get(&url, None).await?;
+        let mut resp: crate::types::Buildings =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/admin/directory/v1/customer/{customer}/resources/buildings",
+                    &[("customer", customer.to_string())],
+                )
+            })?;
 
         let mut buildings = resp.buildings;
         let mut page = resp.next_page_token;
@@ -110,23 +131,28 @@ impl Resources {
         coordinates_source: crate::types::CoordinatesSource,
         body: &crate::types::Building,
     ) -> Result<crate::types::Building> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !coordinates_source.to_string().is_empty() {
-            query_args.push((
-                "coordinatesSource".to_string(),
-                coordinates_source.to_string(),
-            ));
+            query_args.push(("coordinatesSource", coordinates_source.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/resources/buildings?{}",
-            crate::progenitor_support::encode_path(&customer.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/directory/v1/customer/{}/resources/buildings",
+                self.client.encode_path(customer)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/directory/v1/customer/{customer}/resources/buildings",
+                    &[("customer", customer.to_string())],
+                )
+            })
     }
 
     /**
@@ -146,11 +172,20 @@ impl Resources {
     ) -> Result<crate::types::Building> {
         let url = format!(
             "/admin/directory/v1/customer/{}/resources/buildings/{}",
-            crate::progenitor_support::encode_path(&customer.to_string()),
-            crate::progenitor_support::encode_path(&building_id.to_string()),
+            self.client.encode_path(customer),
+            self.client.encode_path(building_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/directory/v1/customer/{customer}/resources/buildings/{buildingId}",
+                &[
+                    ("customer", customer.to_string()),
+                    ("buildingId", building_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -171,24 +206,32 @@ impl Resources {
         coordinates_source: crate::types::CoordinatesSource,
         body: &crate::types::Building,
     ) -> Result<crate::types::Building> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !coordinates_source.to_string().is_empty() {
-            query_args.push((
-                "coordinatesSource".to_string(),
-                coordinates_source.to_string(),
-            ));
+            query_args.push(("coordinatesSource", coordinates_source.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/resources/buildings/{}?{}",
-            crate::progenitor_support::encode_path(&customer.to_string()),
-            crate::progenitor_support::encode_path(&building_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/directory/v1/customer/{}/resources/buildings/{}",
+                self.client.encode_path(customer),
+                self.client.encode_path(building_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/directory/v1/customer/{customer}/resources/buildings/{buildingId}",
+                    &[
+                        ("customer", customer.to_string()),
+                        ("buildingId", building_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -204,11 +247,51 @@ impl Resources {
     pub async fn buildings_delete(&self, customer: &str, building_id: &str) -> Result<()> {
         let url = format!(
             "/admin/directory/v1/customer/{}/resources/buildings/{}",
-            crate::progenitor_support::encode_path(&customer.to_string()),
-            crate::progenitor_support::encode_path(&building_id.to_string()),
+            self.client.encode_path(customer),
+            self.client.encode_path(building_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/directory/v1/customer/{customer}/resources/buildings/{buildingId}",
+                &[
+                    ("customer", customer.to_string()),
+                    ("buildingId", building_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customer}/resources/buildings/{buildingId}` endpoint.
+     *
+     * As opposed to `buildings_delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn buildings_delete_if_exists(
+        &self,
+        customer: &str,
+        building_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/resources/buildings/{}",
+            self.client.encode_path(customer),
+            self.client.encode_path(building_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/directory/v1/customer/{customer}/resources/buildings/{buildingId}",
+                    &[
+                        ("customer", customer.to_string()),
+                        ("buildingId", building_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -229,24 +312,73 @@ impl Resources {
         coordinates_source: crate::types::CoordinatesSource,
         body: &crate::types::Building,
     ) -> Result<crate::types::Building> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !coordinates_source.to_string().is_empty() {
-            query_args.push((
-                "coordinatesSource".to_string(),
-                coordinates_source.to_string(),
-            ));
+            query_args.push(("coordinatesSource", coordinates_source.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/resources/buildings/{}?{}",
-            crate::progenitor_support::encode_path(&customer.to_string()),
-            crate::progenitor_support::encode_path(&building_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/directory/v1/customer/{}/resources/buildings/{}",
+                self.client.encode_path(customer),
+                self.client.encode_path(building_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/admin/directory/v1/customer/{customer}/resources/buildings/{buildingId}",
+                    &[
+                        ("customer", customer.to_string()),
+                        ("buildingId", building_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * This function performs a `PATCH` to the `/admin/directory/v1/customer/{customer}/resources/buildings/{buildingId}` endpoint.
+     *
+     * As opposed to `buildings_patch`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn buildings_patch_if_changed<C: serde::Serialize>(
+        &self,
+        customer: &str,
+        building_id: &str,
+        coordinates_source: crate::types::CoordinatesSource,
+        current: &C,
+        body: &crate::types::Building,
+    ) -> Result<Option<crate::types::Building>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !coordinates_source.to_string().is_empty() {
+            query_args.push(("coordinatesSource", coordinates_source.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/directory/v1/customer/{}/resources/buildings/{}",
+                self.client.encode_path(customer),
+                self.client.encode_path(building_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/admin/directory/v1/customer/{customer}/resources/buildings/{buildingId}",
+                    &[
+                        ("customer", customer.to_string()),
+                        ("buildingId", building_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -270,27 +402,35 @@ impl Resources {
         page_token: &str,
         query: &str,
     ) -> Result<Vec<crate::types::CalendarResource>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if max_results > 0 {
-            query_args.push(("maxResults".to_string(), max_results.to_string()));
+            query_args.push(("maxResults", max_results.to_string()));
         }
         if !order_by.is_empty() {
-            query_args.push(("orderBy".to_string(), order_by.to_string()));
+            query_args.push(("orderBy", order_by.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/resources/calendars?{}",
-            crate::progenitor_support::encode_path(&customer.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/directory/v1/customer/{}/resources/calendars",
+                self.client.encode_path(customer)
+            ),
+            &query_args,
         );
 
-        let resp: crate::types::CalendarResources = self.client.get(&url, None).await?;
+        let resp: crate::types::CalendarResources =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/admin/directory/v1/customer/{customer}/resources/calendars",
+                    &[("customer", customer.to_string())],
+                )
+            })?;
 
         // Return our response data.
         Ok(resp.items)
@@ -309,21 +449,29 @@ impl Resources {
         order_by: &str,
         query: &str,
     ) -> Result<Vec<crate::types::CalendarResource>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !order_by.is_empty() {
-            query_args.push(("orderBy".to_string(), order_by.to_string()));
+            query_args.push(("orderBy", order_by.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/resources/calendars?{}",
-            crate::progenitor_support::encode_path(&customer.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/directory/v1/customer/{}/resources/calendars",
+                self.client.encode_path(customer)
+            ),
+            &query_args,
         );
 
-        let mut resp: crate::types::CalendarResources = self.client.get(&url, None).await?;
+        let mut resp: crate::types::CalendarResources =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/admin/directory/v1/customer/{customer}/resources/calendars",
+                    &[("customer", customer.to_string())],
+                )
+            })?;
 
         let mut items = resp.items;
         let mut page = resp.next_page_token;
@@ -371,12 +519,19 @@ impl Resources {
     ) -> Result<crate::types::CalendarResource> {
         let url = format!(
             "/admin/directory/v1/customer/{}/resources/calendars",
-            crate::progenitor_support::encode_path(&customer.to_string()),
+            self.client.encode_path(customer),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/directory/v1/customer/{customer}/resources/calendars",
+                    &[("customer", customer.to_string())],
+                )
+            })
     }
 
     /**
@@ -396,11 +551,20 @@ impl Resources {
     ) -> Result<crate::types::CalendarResource> {
         let url = format!(
             "/admin/directory/v1/customer/{}/resources/calendars/{}",
-            crate::progenitor_support::encode_path(&customer.to_string()),
-            crate::progenitor_support::encode_path(&calendar_resource_id.to_string()),
+            self.client.encode_path(customer),
+            self.client.encode_path(calendar_resource_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/directory/v1/customer/{customer}/resources/calendars/{calendarResourceId}",
+                &[
+                    ("customer", customer.to_string()),
+                    ("calendarResourceId", calendar_resource_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -421,13 +585,13 @@ impl Resources {
     ) -> Result<crate::types::CalendarResource> {
         let url = format!(
             "/admin/directory/v1/customer/{}/resources/calendars/{}",
-            crate::progenitor_support::encode_path(&customer.to_string()),
-            crate::progenitor_support::encode_path(&calendar_resource_id.to_string()),
+            self.client.encode_path(customer),
+            self.client.encode_path(calendar_resource_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("PUT", "/admin/directory/v1/customer/{customer}/resources/calendars/{calendarResourceId}", &[("customer", customer.to_string()), ("calendarResourceId", calendar_resource_id.to_string())]))
     }
 
     /**
@@ -443,11 +607,39 @@ impl Resources {
     pub async fn calendars_delete(&self, customer: &str, calendar_resource_id: &str) -> Result<()> {
         let url = format!(
             "/admin/directory/v1/customer/{}/resources/calendars/{}",
-            crate::progenitor_support::encode_path(&customer.to_string()),
-            crate::progenitor_support::encode_path(&calendar_resource_id.to_string()),
+            self.client.encode_path(customer),
+            self.client.encode_path(calendar_resource_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/directory/v1/customer/{customer}/resources/calendars/{calendarResourceId}",
+                &[
+                    ("customer", customer.to_string()),
+                    ("calendarResourceId", calendar_resource_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customer}/resources/calendars/{calendarResourceId}` endpoint.
+     *
+     * As opposed to `calendars_delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn calendars_delete_if_exists(
+        &self,
+        customer: &str,
+        calendar_resource_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/resources/calendars/{}",
+            self.client.encode_path(customer),
+            self.client.encode_path(calendar_resource_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete_if_exists(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/admin/directory/v1/customer/{customer}/resources/calendars/{calendarResourceId}", &[("customer", customer.to_string()), ("calendarResourceId", calendar_resource_id.to_string())]))
     }
 
     /**
@@ -468,13 +660,34 @@ impl Resources {
     ) -> Result<crate::types::CalendarResource> {
         let url = format!(
             "/admin/directory/v1/customer/{}/resources/calendars/{}",
-            crate::progenitor_support::encode_path(&customer.to_string()),
-            crate::progenitor_support::encode_path(&calendar_resource_id.to_string()),
+            self.client.encode_path(customer),
+            self.client.encode_path(calendar_resource_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("PATCH", "/admin/directory/v1/customer/{customer}/resources/calendars/{calendarResourceId}", &[("customer", customer.to_string()), ("calendarResourceId", calendar_resource_id.to_string())]))
+    }
+
+    /**
+     * This function performs a `PATCH` to the `/admin/directory/v1/customer/{customer}/resources/calendars/{calendarResourceId}` endpoint.
+     *
+     * As opposed to `calendars_patch`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn calendars_patch_if_changed<C: serde::Serialize>(
+        &self,
+        customer: &str,
+        calendar_resource_id: &str,
+        current: &C,
+        body: &crate::types::CalendarResource,
+    ) -> Result<Option<crate::types::CalendarResource>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/resources/calendars/{}",
+            self.client.encode_path(customer),
+            self.client.encode_path(calendar_resource_id),
+        );
+
+        self.client.patch_if_changed(&url, current, body).await.with_context(|| crate::utils::pretty_url("PATCH", "/admin/directory/v1/customer/{customer}/resources/calendars/{calendarResourceId}", &[("customer", customer.to_string()), ("calendarResourceId", calendar_resource_id.to_string())]))
     }
 
     /**
@@ -494,21 +707,29 @@ impl Resources {
         max_results: i64,
         page_token: &str,
     ) -> Result<Vec<crate::types::Feature>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if max_results > 0 {
-            query_args.push(("maxResults".to_string(), max_results.to_string()));
+            query_args.push(("maxResults", max_results.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/resources/features?{}",
-            crate::progenitor_support::encode_path(&customer.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/directory/v1/customer/{}/resources/features",
+                self.client.encode_path(customer)
+            ),
+            &query_args,
         );
 
-        let resp: crate::types::Features = self.client.get(&url, None).await?;
+        let resp: crate::types::Features =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/admin/directory/v1/customer/{customer}/resources/features",
+                    &[("customer", customer.to_string())],
+                )
+            })?;
 
         // Return our response data.
         Ok(resp.features)
@@ -524,10 +745,17 @@ impl Resources {
     pub async fn features_list_all(&self, customer: &str) -> Result<Vec<crate::types::Feature>> {
         let url = format!(
             "/admin/directory/v1/customer/{}/resources/features",
-            crate::progenitor_support::encode_path(&customer.to_string()),
+            self.client.encode_path(customer),
         );
 
-        let mut resp: crate::types::Features = self.client.get(&url, None).await?;
+        let mut resp: crate::types::Features =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/admin/directory/v1/customer/{customer}/resources/features",
+                    &[("customer", customer.to_string())],
+                )
+            })?;
 
         let mut features = resp.features;
         let mut page = resp.next_page_token;
@@ -575,12 +803,19 @@ impl Resources {
     ) -> Result<crate::types::Feature> {
         let url = format!(
             "/admin/directory/v1/customer/{}/resources/features",
-            crate::progenitor_support::encode_path(&customer.to_string()),
+            self.client.encode_path(customer),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/directory/v1/customer/{customer}/resources/features",
+                    &[("customer", customer.to_string())],
+                )
+            })
     }
 
     /**
@@ -600,11 +835,20 @@ impl Resources {
     ) -> Result<crate::types::Feature> {
         let url = format!(
             "/admin/directory/v1/customer/{}/resources/features/{}",
-            crate::progenitor_support::encode_path(&customer.to_string()),
-            crate::progenitor_support::encode_path(&feature_key.to_string()),
+            self.client.encode_path(customer),
+            self.client.encode_path(feature_key),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/directory/v1/customer/{customer}/resources/features/{featureKey}",
+                &[
+                    ("customer", customer.to_string()),
+                    ("featureKey", feature_key.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -625,13 +869,23 @@ impl Resources {
     ) -> Result<crate::types::Feature> {
         let url = format!(
             "/admin/directory/v1/customer/{}/resources/features/{}",
-            crate::progenitor_support::encode_path(&customer.to_string()),
-            crate::progenitor_support::encode_path(&feature_key.to_string()),
+            self.client.encode_path(customer),
+            self.client.encode_path(feature_key),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/directory/v1/customer/{customer}/resources/features/{featureKey}",
+                    &[
+                        ("customer", customer.to_string()),
+                        ("featureKey", feature_key.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -647,11 +901,51 @@ impl Resources {
     pub async fn features_delete(&self, customer: &str, feature_key: &str) -> Result<()> {
         let url = format!(
             "/admin/directory/v1/customer/{}/resources/features/{}",
-            crate::progenitor_support::encode_path(&customer.to_string()),
-            crate::progenitor_support::encode_path(&feature_key.to_string()),
+            self.client.encode_path(customer),
+            self.client.encode_path(feature_key),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/directory/v1/customer/{customer}/resources/features/{featureKey}",
+                &[
+                    ("customer", customer.to_string()),
+                    ("featureKey", feature_key.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customer}/resources/features/{featureKey}` endpoint.
+     *
+     * As opposed to `features_delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn features_delete_if_exists(
+        &self,
+        customer: &str,
+        feature_key: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/resources/features/{}",
+            self.client.encode_path(customer),
+            self.client.encode_path(feature_key),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/directory/v1/customer/{customer}/resources/features/{featureKey}",
+                    &[
+                        ("customer", customer.to_string()),
+                        ("featureKey", feature_key.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -672,13 +966,56 @@ impl Resources {
     ) -> Result<crate::types::Feature> {
         let url = format!(
             "/admin/directory/v1/customer/{}/resources/features/{}",
-            crate::progenitor_support::encode_path(&customer.to_string()),
-            crate::progenitor_support::encode_path(&feature_key.to_string()),
+            self.client.encode_path(customer),
+            self.client.encode_path(feature_key),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/admin/directory/v1/customer/{customer}/resources/features/{featureKey}",
+                    &[
+                        ("customer", customer.to_string()),
+                        ("featureKey", feature_key.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * This function performs a `PATCH` to the `/admin/directory/v1/customer/{customer}/resources/features/{featureKey}` endpoint.
+     *
+     * As opposed to `features_patch`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn features_patch_if_changed<C: serde::Serialize>(
+        &self,
+        customer: &str,
+        feature_key: &str,
+        current: &C,
+        body: &crate::types::Feature,
+    ) -> Result<Option<crate::types::Feature>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/resources/features/{}",
+            self.client.encode_path(customer),
+            self.client.encode_path(feature_key),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/admin/directory/v1/customer/{customer}/resources/features/{featureKey}",
+                    &[
+                        ("customer", customer.to_string()),
+                        ("featureKey", feature_key.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -699,12 +1036,311 @@ impl Resources {
     ) -> Result<()> {
         let url = format!(
             "/admin/directory/v1/customer/{}/resources/features/{}/rename",
-            crate::progenitor_support::encode_path(&customer.to_string()),
-            crate::progenitor_support::encode_path(&old_name.to_string()),
+            self.client.encode_path(customer),
+            self.client.encode_path(old_name),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/directory/v1/customer/{customer}/resources/features/{oldName}/rename",
+                    &[
+                        ("customer", customer.to_string()),
+                        ("oldName", old_name.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`Resources`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ResourcesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ResourcesApi: Send + Sync {
+    async fn buildings_list(
+        &self,
+        customer: &str,
+        max_results: i64,
+        page_token: &str,
+    ) -> Result<Vec<crate::types::Building>>;
+    async fn buildings_list_all(&self, customer: &str) -> Result<Vec<crate::types::Building>>;
+    async fn buildings_insert(
+        &self,
+        customer: &str,
+        coordinates_source: crate::types::CoordinatesSource,
+        body: &crate::types::Building,
+    ) -> Result<crate::types::Building>;
+    async fn buildings_get(
+        &self,
+        customer: &str,
+        building_id: &str,
+    ) -> Result<crate::types::Building>;
+    async fn buildings_update(
+        &self,
+        customer: &str,
+        building_id: &str,
+        coordinates_source: crate::types::CoordinatesSource,
+        body: &crate::types::Building,
+    ) -> Result<crate::types::Building>;
+    async fn buildings_delete(&self, customer: &str, building_id: &str) -> Result<()>;
+    async fn buildings_delete_if_exists(&self, customer: &str, building_id: &str) -> Result<bool>;
+    async fn buildings_patch(
+        &self,
+        customer: &str,
+        building_id: &str,
+        coordinates_source: crate::types::CoordinatesSource,
+        body: &crate::types::Building,
+    ) -> Result<crate::types::Building>;
+    async fn calendars_list(
+        &self,
+        customer: &str,
+        max_results: i64,
+        order_by: &str,
+        page_token: &str,
+        query: &str,
+    ) -> Result<Vec<crate::types::CalendarResource>>;
+    async fn calendars_list_all(
+        &self,
+        customer: &str,
+        order_by: &str,
+        query: &str,
+    ) -> Result<Vec<crate::types::CalendarResource>>;
+    async fn calendars_insert(
+        &self,
+        customer: &str,
+        body: &crate::types::CalendarResource,
+    ) -> Result<crate::types::CalendarResource>;
+    async fn calendars_get(
+        &self,
+        customer: &str,
+        calendar_resource_id: &str,
+    ) -> Result<crate::types::CalendarResource>;
+    async fn calendars_update(
+        &self,
+        customer: &str,
+        calendar_resource_id: &str,
+        body: &crate::types::CalendarResource,
+    ) -> Result<crate::types::CalendarResource>;
+    async fn calendars_delete(&self, customer: &str, calendar_resource_id: &str) -> Result<()>;
+    async fn calendars_delete_if_exists(
+        &self,
+        customer: &str,
+        calendar_resource_id: &str,
+    ) -> Result<bool>;
+    async fn calendars_patch(
+        &self,
+        customer: &str,
+        calendar_resource_id: &str,
+        body: &crate::types::CalendarResource,
+    ) -> Result<crate::types::CalendarResource>;
+    async fn features_list(
+        &self,
+        customer: &str,
+        max_results: i64,
+        page_token: &str,
+    ) -> Result<Vec<crate::types::Feature>>;
+    async fn features_list_all(&self, customer: &str) -> Result<Vec<crate::types::Feature>>;
+    async fn features_insert(
+        &self,
+        customer: &str,
+        body: &crate::types::Feature,
+    ) -> Result<crate::types::Feature>;
+    async fn features_get(
+        &self,
+        customer: &str,
+        feature_key: &str,
+    ) -> Result<crate::types::Feature>;
+    async fn features_update(
+        &self,
+        customer: &str,
+        feature_key: &str,
+        body: &crate::types::Feature,
+    ) -> Result<crate::types::Feature>;
+    async fn features_delete(&self, customer: &str, feature_key: &str) -> Result<()>;
+    async fn features_delete_if_exists(&self, customer: &str, feature_key: &str) -> Result<bool>;
+    async fn features_patch(
+        &self,
+        customer: &str,
+        feature_key: &str,
+        body: &crate::types::Feature,
+    ) -> Result<crate::types::Feature>;
+    async fn features_rename(
+        &self,
+        customer: &str,
+        old_name: &str,
+        body: &crate::types::FeatureRename,
+    ) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ResourcesApi for Resources {
+    async fn buildings_list(
+        &self,
+        customer: &str,
+        max_results: i64,
+        page_token: &str,
+    ) -> Result<Vec<crate::types::Building>> {
+        Resources::buildings_list(self, customer, max_results, page_token).await
+    }
+    async fn buildings_list_all(&self, customer: &str) -> Result<Vec<crate::types::Building>> {
+        Resources::buildings_list_all(self, customer).await
+    }
+    async fn buildings_insert(
+        &self,
+        customer: &str,
+        coordinates_source: crate::types::CoordinatesSource,
+        body: &crate::types::Building,
+    ) -> Result<crate::types::Building> {
+        Resources::buildings_insert(self, customer, coordinates_source, body).await
+    }
+    async fn buildings_get(
+        &self,
+        customer: &str,
+        building_id: &str,
+    ) -> Result<crate::types::Building> {
+        Resources::buildings_get(self, customer, building_id).await
+    }
+    async fn buildings_update(
+        &self,
+        customer: &str,
+        building_id: &str,
+        coordinates_source: crate::types::CoordinatesSource,
+        body: &crate::types::Building,
+    ) -> Result<crate::types::Building> {
+        Resources::buildings_update(self, customer, building_id, coordinates_source, body).await
+    }
+    async fn buildings_delete(&self, customer: &str, building_id: &str) -> Result<()> {
+        Resources::buildings_delete(self, customer, building_id).await
+    }
+    async fn buildings_delete_if_exists(&self, customer: &str, building_id: &str) -> Result<bool> {
+        Resources::buildings_delete_if_exists(self, customer, building_id).await
+    }
+    async fn buildings_patch(
+        &self,
+        customer: &str,
+        building_id: &str,
+        coordinates_source: crate::types::CoordinatesSource,
+        body: &crate::types::Building,
+    ) -> Result<crate::types::Building> {
+        Resources::buildings_patch(self, customer, building_id, coordinates_source, body).await
+    }
+    async fn calendars_list(
+        &self,
+        customer: &str,
+        max_results: i64,
+        order_by: &str,
+        page_token: &str,
+        query: &str,
+    ) -> Result<Vec<crate::types::CalendarResource>> {
+        Resources::calendars_list(self, customer, max_results, order_by, page_token, query).await
+    }
+    async fn calendars_list_all(
+        &self,
+        customer: &str,
+        order_by: &str,
+        query: &str,
+    ) -> Result<Vec<crate::types::CalendarResource>> {
+        Resources::calendars_list_all(self, customer, order_by, query).await
+    }
+    async fn calendars_insert(
+        &self,
+        customer: &str,
+        body: &crate::types::CalendarResource,
+    ) -> Result<crate::types::CalendarResource> {
+        Resources::calendars_insert(self, customer, body).await
+    }
+    async fn calendars_get(
+        &self,
+        customer: &str,
+        calendar_resource_id: &str,
+    ) -> Result<crate::types::CalendarResource> {
+        Resources::calendars_get(self, customer, calendar_resource_id).await
+    }
+    async fn calendars_update(
+        &self,
+        customer: &str,
+        calendar_resource_id: &str,
+        body: &crate::types::CalendarResource,
+    ) -> Result<crate::types::CalendarResource> {
+        Resources::calendars_update(self, customer, calendar_resource_id, body).await
+    }
+    async fn calendars_delete(&self, customer: &str, calendar_resource_id: &str) -> Result<()> {
+        Resources::calendars_delete(self, customer, calendar_resource_id).await
+    }
+    async fn calendars_delete_if_exists(
+        &self,
+        customer: &str,
+        calendar_resource_id: &str,
+    ) -> Result<bool> {
+        Resources::calendars_delete_if_exists(self, customer, calendar_resource_id).await
+    }
+    async fn calendars_patch(
+        &self,
+        customer: &str,
+        calendar_resource_id: &str,
+        body: &crate::types::CalendarResource,
+    ) -> Result<crate::types::CalendarResource> {
+        Resources::calendars_patch(self, customer, calendar_resource_id, body).await
+    }
+    async fn features_list(
+        &self,
+        customer: &str,
+        max_results: i64,
+        page_token: &str,
+    ) -> Result<Vec<crate::types::Feature>> {
+        Resources::features_list(self, customer, max_results, page_token).await
+    }
+    async fn features_list_all(&self, customer: &str) -> Result<Vec<crate::types::Feature>> {
+        Resources::features_list_all(self, customer).await
+    }
+    async fn features_insert(
+        &self,
+        customer: &str,
+        body: &crate::types::Feature,
+    ) -> Result<crate::types::Feature> {
+        Resources::features_insert(self, customer, body).await
+    }
+    async fn features_get(
+        &self,
+        customer: &str,
+        feature_key: &str,
+    ) -> Result<crate::types::Feature> {
+        Resources::features_get(self, customer, feature_key).await
+    }
+    async fn features_update(
+        &self,
+        customer: &str,
+        feature_key: &str,
+        body: &crate::types::Feature,
+    ) -> Result<crate::types::Feature> {
+        Resources::features_update(self, customer, feature_key, body).await
+    }
+    async fn features_delete(&self, customer: &str, feature_key: &str) -> Result<()> {
+        Resources::features_delete(self, customer, feature_key).await
+    }
+    async fn features_delete_if_exists(&self, customer: &str, feature_key: &str) -> Result<bool> {
+        Resources::features_delete_if_exists(self, customer, feature_key).await
+    }
+    async fn features_patch(
+        &self,
+        customer: &str,
+        feature_key: &str,
+        body: &crate::types::Feature,
+    ) -> Result<crate::types::Feature> {
+        Resources::features_patch(self, customer, feature_key, body).await
+    }
+    async fn features_rename(
+        &self,
+        customer: &str,
+        old_name: &str,
+        body: &crate::types::FeatureRename,
+    ) -> Result<()> {
+        Resources::features_rename(self, customer, old_name, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct RoleAssignments {
     pub client: Client,
@@ -12,6 +10,14 @@ impl RoleAssignments {
         RoleAssignments { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        RoleAssignments {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/roleassignments` endpoint.
      *
@@ -33,27 +39,35 @@ impl RoleAssignments {
         role_id: &str,
         user_key: &str,
     ) -> Result<Vec<crate::types::RoleAssignment>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if max_results > 0 {
-            query_args.push(("maxResults".to_string(), max_results.to_string()));
+            query_args.push(("maxResults", max_results.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if !role_id.is_empty() {
-            query_args.push(("roleId".to_string(), role_id.to_string()));
+            query_args.push(("roleId", role_id.to_string()));
         }
         if !user_key.is_empty() {
-            query_args.push(("userKey".to_string(), user_key.to_string()));
+            query_args.push(("userKey", user_key.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/roleassignments?{}",
-            crate::progenitor_support::encode_path(&customer.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/directory/v1/customer/{}/roleassignments",
+                self.client.encode_path(customer)
+            ),
+            &query_args,
         );
 
-        let resp: crate::types::RoleAssignments = self.client.get(&url, None).await?;
+        let resp: crate::types::RoleAssignments =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/admin/directory/v1/customer/{customer}/roleassignments",
+                    &[("customer", customer.to_string())],
+                )
+            })?;
 
         // Return our response data.
         Ok(resp.items)
@@ -72,21 +86,29 @@ impl RoleAssignments {
         role_id: &str,
         user_key: &str,
     ) -> Result<Vec<crate::types::RoleAssignment>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !role_id.is_empty() {
-            query_args.push(("roleId".to_string(), role_id.to_string()));
+            query_args.push(("roleId", role_id.to_string()));
         }
         if !user_key.is_empty() {
-            query_args.push(("userKey".to_string(), user_key.to_string()));
+            query_args.push(("userKey", user_key.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/roleassignments?{}",
-            crate::progenitor_support::encode_path(&customer.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/directory/v1/customer/{}/roleassignments",
+                self.client.encode_path(customer)
+            ),
+            &query_args,
         );
 
-        let mut resp: crate::types::RoleAssignments = self.client.get(&url, None).await?;
+        let mut resp: crate::types::RoleAssignments =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/admin/directory/v1/customer/{customer}/roleassignments",
+                    &[("customer", customer.to_string())],
+                )
+            })?;
 
         let mut items = resp.items;
         let mut page = resp.next_page_token;
@@ -134,12 +156,19 @@ impl RoleAssignments {
     ) -> Result<crate::types::RoleAssignment> {
         let url = format!(
             "/admin/directory/v1/customer/{}/roleassignments",
-            crate::progenitor_support::encode_path(&customer.to_string()),
+            self.client.encode_path(customer),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/directory/v1/customer/{customer}/roleassignments",
+                    &[("customer", customer.to_string())],
+                )
+            })
     }
 
     /**
@@ -159,11 +188,20 @@ impl RoleAssignments {
     ) -> Result<crate::types::RoleAssignment> {
         let url = format!(
             "/admin/directory/v1/customer/{}/roleassignments/{}",
-            crate::progenitor_support::encode_path(&customer.to_string()),
-            crate::progenitor_support::encode_path(&role_assignment_id.to_string()),
+            self.client.encode_path(customer),
+            self.client.encode_path(role_assignment_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/directory/v1/customer/{customer}/roleassignments/{roleAssignmentId}",
+                &[
+                    ("customer", customer.to_string()),
+                    ("roleAssignmentId", role_assignment_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -179,10 +217,123 @@ impl RoleAssignments {
     pub async fn delete(&self, customer: &str, role_assignment_id: &str) -> Result<()> {
         let url = format!(
             "/admin/directory/v1/customer/{}/roleassignments/{}",
-            crate::progenitor_support::encode_path(&customer.to_string()),
-            crate::progenitor_support::encode_path(&role_assignment_id.to_string()),
+            self.client.encode_path(customer),
+            self.client.encode_path(role_assignment_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/directory/v1/customer/{customer}/roleassignments/{roleAssignmentId}",
+                &[
+                    ("customer", customer.to_string()),
+                    ("roleAssignmentId", role_assignment_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customer}/roleassignments/{roleAssignmentId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, customer: &str, role_assignment_id: &str) -> Result<bool> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/roleassignments/{}",
+            self.client.encode_path(customer),
+            self.client.encode_path(role_assignment_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/directory/v1/customer/{customer}/roleassignments/{roleAssignmentId}",
+                    &[
+                        ("customer", customer.to_string()),
+                        ("roleAssignmentId", role_assignment_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`RoleAssignments`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn RoleAssignmentsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait RoleAssignmentsApi: Send + Sync {
+    async fn list(
+        &self,
+        customer: &str,
+        max_results: i64,
+        page_token: &str,
+        role_id: &str,
+        user_key: &str,
+    ) -> Result<Vec<crate::types::RoleAssignment>>;
+    async fn list_all(
+        &self,
+        customer: &str,
+        role_id: &str,
+        user_key: &str,
+    ) -> Result<Vec<crate::types::RoleAssignment>>;
+    async fn insert(
+        &self,
+        customer: &str,
+        body: &crate::types::RoleAssignment,
+    ) -> Result<crate::types::RoleAssignment>;
+    async fn get(
+        &self,
+        customer: &str,
+        role_assignment_id: &str,
+    ) -> Result<crate::types::RoleAssignment>;
+    async fn delete(&self, customer: &str, role_assignment_id: &str) -> Result<()>;
+    async fn delete_if_exists(&self, customer: &str, role_assignment_id: &str) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl RoleAssignmentsApi for RoleAssignments {
+    async fn list(
+        &self,
+        customer: &str,
+        max_results: i64,
+        page_token: &str,
+        role_id: &str,
+        user_key: &str,
+    ) -> Result<Vec<crate::types::RoleAssignment>> {
+        RoleAssignments::list(self, customer, max_results, page_token, role_id, user_key).await
+    }
+    async fn list_all(
+        &self,
+        customer: &str,
+        role_id: &str,
+        user_key: &str,
+    ) -> Result<Vec<crate::types::RoleAssignment>> {
+        RoleAssignments::list_all(self, customer, role_id, user_key).await
+    }
+    async fn insert(
+        &self,
+        customer: &str,
+        body: &crate::types::RoleAssignment,
+    ) -> Result<crate::types::RoleAssignment> {
+        RoleAssignments::insert(self, customer, body).await
+    }
+    async fn get(
+        &self,
+        customer: &str,
+        role_assignment_id: &str,
+    ) -> Result<crate::types::RoleAssignment> {
+        RoleAssignments::get(self, customer, role_assignment_id).await
+    }
+    async fn delete(&self, customer: &str, role_assignment_id: &str) -> Result<()> {
+        RoleAssignments::delete(self, customer, role_assignment_id).await
+    }
+    async fn delete_if_exists(&self, customer: &str, role_assignment_id: &str) -> Result<bool> {
+        RoleAssignments::delete_if_exists(self, customer, role_assignment_id).await
     }
 }
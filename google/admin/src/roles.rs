@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Roles {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Roles {
         Roles { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Roles {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/roles` endpoint.
      *
@@ -29,21 +35,28 @@ impl Roles {
         max_results: i64,
         page_token: &str,
     ) -> Result<Vec<crate::types::Role>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if max_results > 0 {
-            query_args.push(("maxResults".to_string(), max_results.to_string()));
+            query_args.push(("maxResults", max_results.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/customer/{}/roles?{}",
-            crate::progenitor_support::encode_path(&customer.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/directory/v1/customer/{}/roles",
+                self.client.encode_path(customer)
+            ),
+            &query_args,
         );
 
-        let resp: crate::types::Roles = self.client.get(&url, None).await?;
+        let resp: crate::types::Roles = self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/directory/v1/customer/{customer}/roles",
+                &[("customer", customer.to_string())],
+            )
+        })?;
 
         // Return our response data.
         Ok(resp.items)
@@ -59,10 +72,17 @@ impl Roles {
     pub async fn list_all(&self, customer: &str) -> Result<Vec<crate::types::Role>> {
         let url = format!(
             "/admin/directory/v1/customer/{}/roles",
-            crate::progenitor_support::encode_path(&customer.to_string()),
+            self.client.encode_path(customer),
         );
 
-        let mut resp: crate::types::Roles = self.client.get(&url, None).await?;
+        let mut resp: crate::types::Roles =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/admin/directory/v1/customer/{customer}/roles",
+                    &[("customer", customer.to_string())],
+                )
+            })?;
 
         let mut items = resp.items;
         let mut page = resp.next_page_token;
@@ -110,12 +130,19 @@ impl Roles {
     ) -> Result<crate::types::Role> {
         let url = format!(
             "/admin/directory/v1/customer/{}/roles",
-            crate::progenitor_support::encode_path(&customer.to_string()),
+            self.client.encode_path(customer),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/directory/v1/customer/{customer}/roles",
+                    &[("customer", customer.to_string())],
+                )
+            })
     }
 
     /**
@@ -131,11 +158,20 @@ impl Roles {
     pub async fn get(&self, customer: &str, role_id: &str) -> Result<crate::types::Role> {
         let url = format!(
             "/admin/directory/v1/customer/{}/roles/{}",
-            crate::progenitor_support::encode_path(&customer.to_string()),
-            crate::progenitor_support::encode_path(&role_id.to_string()),
+            self.client.encode_path(customer),
+            self.client.encode_path(role_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/directory/v1/customer/{customer}/roles/{roleId}",
+                &[
+                    ("customer", customer.to_string()),
+                    ("roleId", role_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -156,13 +192,23 @@ impl Roles {
     ) -> Result<crate::types::Role> {
         let url = format!(
             "/admin/directory/v1/customer/{}/roles/{}",
-            crate::progenitor_support::encode_path(&customer.to_string()),
-            crate::progenitor_support::encode_path(&role_id.to_string()),
+            self.client.encode_path(customer),
+            self.client.encode_path(role_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/directory/v1/customer/{customer}/roles/{roleId}",
+                    &[
+                        ("customer", customer.to_string()),
+                        ("roleId", role_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -178,11 +224,47 @@ impl Roles {
     pub async fn delete(&self, customer: &str, role_id: &str) -> Result<()> {
         let url = format!(
             "/admin/directory/v1/customer/{}/roles/{}",
-            crate::progenitor_support::encode_path(&customer.to_string()),
-            crate::progenitor_support::encode_path(&role_id.to_string()),
+            self.client.encode_path(customer),
+            self.client.encode_path(role_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/directory/v1/customer/{customer}/roles/{roleId}",
+                &[
+                    ("customer", customer.to_string()),
+                    ("roleId", role_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customer}/roles/{roleId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, customer: &str, role_id: &str) -> Result<bool> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/roles/{}",
+            self.client.encode_path(customer),
+            self.client.encode_path(role_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/directory/v1/customer/{customer}/roles/{roleId}",
+                    &[
+                        ("customer", customer.to_string()),
+                        ("roleId", role_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -203,12 +285,135 @@ impl Roles {
     ) -> Result<crate::types::Role> {
         let url = format!(
             "/admin/directory/v1/customer/{}/roles/{}",
-            crate::progenitor_support::encode_path(&customer.to_string()),
-            crate::progenitor_support::encode_path(&role_id.to_string()),
+            self.client.encode_path(customer),
+            self.client.encode_path(role_id),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/admin/directory/v1/customer/{customer}/roles/{roleId}",
+                    &[
+                        ("customer", customer.to_string()),
+                        ("roleId", role_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * This function performs a `PATCH` to the `/admin/directory/v1/customer/{customer}/roles/{roleId}` endpoint.
+     *
+     * As opposed to `patch`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_if_changed<C: serde::Serialize>(
+        &self,
+        customer: &str,
+        role_id: &str,
+        current: &C,
+        body: &crate::types::Role,
+    ) -> Result<Option<crate::types::Role>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/roles/{}",
+            self.client.encode_path(customer),
+            self.client.encode_path(role_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/admin/directory/v1/customer/{customer}/roles/{roleId}",
+                    &[
+                        ("customer", customer.to_string()),
+                        ("roleId", role_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`Roles`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn RolesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait RolesApi: Send + Sync {
+    async fn list(
+        &self,
+        customer: &str,
+        max_results: i64,
+        page_token: &str,
+    ) -> Result<Vec<crate::types::Role>>;
+    async fn list_all(&self, customer: &str) -> Result<Vec<crate::types::Role>>;
+    async fn insert(&self, customer: &str, body: &crate::types::Role)
+        -> Result<crate::types::Role>;
+    async fn get(&self, customer: &str, role_id: &str) -> Result<crate::types::Role>;
+    async fn update(
+        &self,
+        customer: &str,
+        role_id: &str,
+        body: &crate::types::Role,
+    ) -> Result<crate::types::Role>;
+    async fn delete(&self, customer: &str, role_id: &str) -> Result<()>;
+    async fn delete_if_exists(&self, customer: &str, role_id: &str) -> Result<bool>;
+    async fn patch(
+        &self,
+        customer: &str,
+        role_id: &str,
+        body: &crate::types::Role,
+    ) -> Result<crate::types::Role>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl RolesApi for Roles {
+    async fn list(
+        &self,
+        customer: &str,
+        max_results: i64,
+        page_token: &str,
+    ) -> Result<Vec<crate::types::Role>> {
+        Roles::list(self, customer, max_results, page_token).await
+    }
+    async fn list_all(&self, customer: &str) -> Result<Vec<crate::types::Role>> {
+        Roles::list_all(self, customer).await
+    }
+    async fn insert(
+        &self,
+        customer: &str,
+        body: &crate::types::Role,
+    ) -> Result<crate::types::Role> {
+        Roles::insert(self, customer, body).await
+    }
+    async fn get(&self, customer: &str, role_id: &str) -> Result<crate::types::Role> {
+        Roles::get(self, customer, role_id).await
+    }
+    async fn update(
+        &self,
+        customer: &str,
+        role_id: &str,
+        body: &crate::types::Role,
+    ) -> Result<crate::types::Role> {
+        Roles::update(self, customer, role_id, body).await
+    }
+    async fn delete(&self, customer: &str, role_id: &str) -> Result<()> {
+        Roles::delete(self, customer, role_id).await
+    }
+    async fn delete_if_exists(&self, customer: &str, role_id: &str) -> Result<bool> {
+        Roles::delete_if_exists(self, customer, role_id).await
+    }
+    async fn patch(
+        &self,
+        customer: &str,
+        role_id: &str,
+        body: &crate::types::Role,
+    ) -> Result<crate::types::Role> {
+        Roles::patch(self, customer, role_id, body).await
     }
 }
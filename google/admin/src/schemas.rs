@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Schemas {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Schemas {
         Schemas { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Schemas {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/schemas` endpoint.
      *
@@ -24,10 +30,16 @@ impl Schemas {
     pub async fn list(&self, customer_id: &str) -> Result<crate::types::Schemas> {
         let url = format!(
             "/admin/directory/v1/customer/{}/schemas",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/directory/v1/customer/{customerId}/schemas",
+                &[("customerId", customer_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -46,12 +58,19 @@ impl Schemas {
     ) -> Result<crate::types::Schema> {
         let url = format!(
             "/admin/directory/v1/customer/{}/schemas",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/directory/v1/customer/{customerId}/schemas",
+                    &[("customerId", customer_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -67,11 +86,20 @@ impl Schemas {
     pub async fn get(&self, customer_id: &str, schema_key: &str) -> Result<crate::types::Schema> {
         let url = format!(
             "/admin/directory/v1/customer/{}/schemas/{}",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            crate::progenitor_support::encode_path(&schema_key.to_string()),
+            self.client.encode_path(customer_id),
+            self.client.encode_path(schema_key),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/directory/v1/customer/{customerId}/schemas/{schemaKey}",
+                &[
+                    ("customerId", customer_id.to_string()),
+                    ("schemaKey", schema_key.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -92,13 +120,23 @@ impl Schemas {
     ) -> Result<crate::types::Schema> {
         let url = format!(
             "/admin/directory/v1/customer/{}/schemas/{}",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            crate::progenitor_support::encode_path(&schema_key.to_string()),
+            self.client.encode_path(customer_id),
+            self.client.encode_path(schema_key),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/directory/v1/customer/{customerId}/schemas/{schemaKey}",
+                    &[
+                        ("customerId", customer_id.to_string()),
+                        ("schemaKey", schema_key.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -114,11 +152,47 @@ impl Schemas {
     pub async fn delete(&self, customer_id: &str, schema_key: &str) -> Result<()> {
         let url = format!(
             "/admin/directory/v1/customer/{}/schemas/{}",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            crate::progenitor_support::encode_path(&schema_key.to_string()),
+            self.client.encode_path(customer_id),
+            self.client.encode_path(schema_key),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/directory/v1/customer/{customerId}/schemas/{schemaKey}",
+                &[
+                    ("customerId", customer_id.to_string()),
+                    ("schemaKey", schema_key.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customerId}/schemas/{schemaKey}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, customer_id: &str, schema_key: &str) -> Result<bool> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/schemas/{}",
+            self.client.encode_path(customer_id),
+            self.client.encode_path(schema_key),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/directory/v1/customer/{customerId}/schemas/{schemaKey}",
+                    &[
+                        ("customerId", customer_id.to_string()),
+                        ("schemaKey", schema_key.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -139,12 +213,124 @@ impl Schemas {
     ) -> Result<crate::types::Schema> {
         let url = format!(
             "/admin/directory/v1/customer/{}/schemas/{}",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            crate::progenitor_support::encode_path(&schema_key.to_string()),
+            self.client.encode_path(customer_id),
+            self.client.encode_path(schema_key),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/admin/directory/v1/customer/{customerId}/schemas/{schemaKey}",
+                    &[
+                        ("customerId", customer_id.to_string()),
+                        ("schemaKey", schema_key.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * This function performs a `PATCH` to the `/admin/directory/v1/customer/{customerId}/schemas/{schemaKey}` endpoint.
+     *
+     * As opposed to `patch`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_if_changed<C: serde::Serialize>(
+        &self,
+        customer_id: &str,
+        schema_key: &str,
+        current: &C,
+        body: &crate::types::Schema,
+    ) -> Result<Option<crate::types::Schema>> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/schemas/{}",
+            self.client.encode_path(customer_id),
+            self.client.encode_path(schema_key),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/admin/directory/v1/customer/{customerId}/schemas/{schemaKey}",
+                    &[
+                        ("customerId", customer_id.to_string()),
+                        ("schemaKey", schema_key.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`Schemas`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn SchemasApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait SchemasApi: Send + Sync {
+    async fn list(&self, customer_id: &str) -> Result<crate::types::Schemas>;
+    async fn insert(
+        &self,
+        customer_id: &str,
+        body: &crate::types::Schema,
+    ) -> Result<crate::types::Schema>;
+    async fn get(&self, customer_id: &str, schema_key: &str) -> Result<crate::types::Schema>;
+    async fn update(
+        &self,
+        customer_id: &str,
+        schema_key: &str,
+        body: &crate::types::Schema,
+    ) -> Result<crate::types::Schema>;
+    async fn delete(&self, customer_id: &str, schema_key: &str) -> Result<()>;
+    async fn delete_if_exists(&self, customer_id: &str, schema_key: &str) -> Result<bool>;
+    async fn patch(
+        &self,
+        customer_id: &str,
+        schema_key: &str,
+        body: &crate::types::Schema,
+    ) -> Result<crate::types::Schema>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl SchemasApi for Schemas {
+    async fn list(&self, customer_id: &str) -> Result<crate::types::Schemas> {
+        Schemas::list(self, customer_id).await
+    }
+    async fn insert(
+        &self,
+        customer_id: &str,
+        body: &crate::types::Schema,
+    ) -> Result<crate::types::Schema> {
+        Schemas::insert(self, customer_id, body).await
+    }
+    async fn get(&self, customer_id: &str, schema_key: &str) -> Result<crate::types::Schema> {
+        Schemas::get(self, customer_id, schema_key).await
+    }
+    async fn update(
+        &self,
+        customer_id: &str,
+        schema_key: &str,
+        body: &crate::types::Schema,
+    ) -> Result<crate::types::Schema> {
+        Schemas::update(self, customer_id, schema_key, body).await
+    }
+    async fn delete(&self, customer_id: &str, schema_key: &str) -> Result<()> {
+        Schemas::delete(self, customer_id, schema_key).await
+    }
+    async fn delete_if_exists(&self, customer_id: &str, schema_key: &str) -> Result<bool> {
+        Schemas::delete_if_exists(self, customer_id, schema_key).await
+    }
+    async fn patch(
+        &self,
+        customer_id: &str,
+        schema_key: &str,
+        body: &crate::types::Schema,
+    ) -> Result<crate::types::Schema> {
+        Schemas::patch(self, customer_id, schema_key, body).await
     }
 }
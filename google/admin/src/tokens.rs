@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Tokens {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Tokens {
         Tokens { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Tokens {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/users/{userKey}/tokens` endpoint.
      *
@@ -24,10 +30,16 @@ impl Tokens {
     pub async fn list(&self, user_key: &str) -> Result<crate::types::Tokens> {
         let url = format!(
             "/admin/directory/v1/users/{}/tokens",
-            crate::progenitor_support::encode_path(&user_key.to_string()),
+            self.client.encode_path(user_key),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/directory/v1/users/{userKey}/tokens",
+                &[("userKey", user_key.to_string())],
+            )
+        })
     }
 
     /**
@@ -43,11 +55,20 @@ impl Tokens {
     pub async fn get(&self, user_key: &str, client_id: &str) -> Result<crate::types::Token> {
         let url = format!(
             "/admin/directory/v1/users/{}/tokens/{}",
-            crate::progenitor_support::encode_path(&user_key.to_string()),
-            crate::progenitor_support::encode_path(&client_id.to_string()),
+            self.client.encode_path(user_key),
+            self.client.encode_path(client_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/directory/v1/users/{userKey}/tokens/{clientId}",
+                &[
+                    ("userKey", user_key.to_string()),
+                    ("clientId", client_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -63,10 +84,75 @@ impl Tokens {
     pub async fn delete(&self, user_key: &str, client_id: &str) -> Result<()> {
         let url = format!(
             "/admin/directory/v1/users/{}/tokens/{}",
-            crate::progenitor_support::encode_path(&user_key.to_string()),
-            crate::progenitor_support::encode_path(&client_id.to_string()),
+            self.client.encode_path(user_key),
+            self.client.encode_path(client_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/directory/v1/users/{userKey}/tokens/{clientId}",
+                &[
+                    ("userKey", user_key.to_string()),
+                    ("clientId", client_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/admin/directory/v1/users/{userKey}/tokens/{clientId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, user_key: &str, client_id: &str) -> Result<bool> {
+        let url = format!(
+            "/admin/directory/v1/users/{}/tokens/{}",
+            self.client.encode_path(user_key),
+            self.client.encode_path(client_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/directory/v1/users/{userKey}/tokens/{clientId}",
+                    &[
+                        ("userKey", user_key.to_string()),
+                        ("clientId", client_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`Tokens`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn TokensApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait TokensApi: Send + Sync {
+    async fn list(&self, user_key: &str) -> Result<crate::types::Tokens>;
+    async fn get(&self, user_key: &str, client_id: &str) -> Result<crate::types::Token>;
+    async fn delete(&self, user_key: &str, client_id: &str) -> Result<()>;
+    async fn delete_if_exists(&self, user_key: &str, client_id: &str) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl TokensApi for Tokens {
+    async fn list(&self, user_key: &str) -> Result<crate::types::Tokens> {
+        Tokens::list(self, user_key).await
+    }
+    async fn get(&self, user_key: &str, client_id: &str) -> Result<crate::types::Token> {
+        Tokens::get(self, user_key, client_id).await
+    }
+    async fn delete(&self, user_key: &str, client_id: &str) -> Result<()> {
+        Tokens::delete(self, user_key, client_id).await
+    }
+    async fn delete_if_exists(&self, user_key: &str, client_id: &str) -> Result<bool> {
+        Tokens::delete_if_exists(self, user_key, client_id).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct TwoStepVerification {
     pub client: Client,
@@ -12,6 +10,14 @@ impl TwoStepVerification {
         TwoStepVerification { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        TwoStepVerification {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `POST` to the `/admin/directory/v1/users/{userKey}/twoStepVerification/turnOff` endpoint.
      *
@@ -24,9 +30,32 @@ impl TwoStepVerification {
     pub async fn turn_off(&self, user_key: &str) -> Result<()> {
         let url = format!(
             "/admin/directory/v1/users/{}/twoStepVerification/turnOff",
-            crate::progenitor_support::encode_path(&user_key.to_string()),
+            self.client.encode_path(user_key),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/admin/directory/v1/users/{userKey}/twoStepVerification/turnOff",
+                &[("userKey", user_key.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`TwoStepVerification`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn TwoStepVerificationApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait TwoStepVerificationApi: Send + Sync {
+    async fn turn_off(&self, user_key: &str) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl TwoStepVerificationApi for TwoStepVerification {
+    async fn turn_off(&self, user_key: &str) -> Result<()> {
+        TwoStepVerification::turn_off(self, user_key).await
     }
 }
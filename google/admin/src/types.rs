@@ -2,8 +2,36 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+/// Either one of something, or many of them. Some endpoints send back an
+/// object when there is one and an array when there are more, so this takes both.
+// Many is tried first: serde reads a struct from an array of its fields too,
+// so an array would otherwise be taken for One.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum OneOrMany<T> {
+    Many(Vec<T>),
+    One(T),
+}
+
+impl<T> OneOrMany<T> {
+    /// All of them, as a vector.
+    pub fn into_vec(self) -> Vec<T> {
+        match self {
+            OneOrMany::One(t) => vec![t],
+            OneOrMany::Many(v) => v,
+        }
+    }
+}
+
+impl<T> Default for OneOrMany<T> {
+    fn default() -> Self {
+        OneOrMany::Many(Vec::new())
+    }
+}
+
 /// JSON template for Alias object in Directory API.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Alias {
     /**
      * JSON template for Alias object in Directory API.
@@ -47,8 +75,7 @@ pub struct Alias {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "primaryEmail"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub primary_email: String,
 }
@@ -87,6 +114,7 @@ pub struct Aliases {
 
 /// An application-specific password (ASP) is used with applications that do not accept a verification code when logging into the application on certain devices. The ASP access code is used instead of the login and password you commonly use when accessing an application through a browser. For more information about ASPs and how to create one, see the [help center](https://support.google.com/a/answer/2537800#asp).
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Asp {
     /**
      * An application-specific password (ASP) is used with applications that do not accept a verification code when logging into the application on certain devices. The ASP access code is used instead of the login and password you commonly use when accessing an application through a browser. For more information about ASPs and how to create one, see the [help center](https://support.google.com/a/answer/2537800#asp).
@@ -94,8 +122,7 @@ pub struct Asp {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "codeId"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub code_id: i64,
     /**
@@ -104,8 +131,7 @@ pub struct Asp {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "creationTime"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub creation_time: i64,
     /**
@@ -132,8 +158,7 @@ pub struct Asp {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "lastTimeUsed"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub last_time_used: i64,
     /**
@@ -151,8 +176,7 @@ pub struct Asp {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "userKey"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub user_key: String,
 }
@@ -185,7 +209,7 @@ pub struct Asps {
 /**
  * Message severity
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Severity {
     #[serde(rename = "SEVERITY_ERROR")]
     SeverityError,
@@ -220,7 +244,30 @@ impl Default for Severity {
         Severity::Noop
     }
 }
+
 impl Severity {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::SeverityError => "SEVERITY_ERROR",
+            Severity::SeverityInfo => "SEVERITY_INFO",
+            Severity::SeverityUnspecified => "SEVERITY_UNSPECIFIED",
+            Severity::SeverityWarning => "SEVERITY_WARNING",
+            Severity::Noop => "",
+            Severity::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Severity] {
+        &[
+            Severity::SeverityError,
+            Severity::SeverityInfo,
+            Severity::SeverityUnspecified,
+            Severity::SeverityWarning,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Severity::Noop)
     }
@@ -228,6 +275,7 @@ impl Severity {
 
 /// Auxiliary message about issues with printers or settings. Example: {message_type:AUXILIARY_MESSAGE_WARNING, field_mask:make_and_model, message:"Given printer is invalid or no longer supported."}
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct AuxiliaryMessage {
     /**
      * Auxiliary message about issues with printers or settings. Example: {message_type:AUXILIARY_MESSAGE_WARNING, field_mask:make_and_model, message:"Given printer is invalid or no longer supported."}
@@ -235,8 +283,7 @@ pub struct AuxiliaryMessage {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "auxiliaryMessage"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub auxiliary_message: String,
     /**
@@ -245,8 +292,7 @@ pub struct AuxiliaryMessage {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "fieldMask"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub field_mask: String,
     /**
@@ -295,6 +341,7 @@ pub struct BatchCreatePrintersResponse {
 
 /// Request for deleting existing printers in batch.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct BatchDeletePrintersRequest {
     /**
      * Request for deleting existing printers in batch.
@@ -302,14 +349,14 @@ pub struct BatchDeletePrintersRequest {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "printerIds"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub printer_ids: Vec<String>,
 }
 
 /// Response for deleting existing printers in batch.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct BatchDeletePrintersResponse {
     /**
      * Response for deleting existing printers in batch.
@@ -317,8 +364,7 @@ pub struct BatchDeletePrintersResponse {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "failedPrinters"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub failed_printers: Vec<FailureInfo>,
     /**
@@ -327,14 +373,14 @@ pub struct BatchDeletePrintersResponse {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "printerIds"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub printer_ids: Vec<String>,
 }
 
 /// Public API: Resources.buildings
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Building {
     /**
      * Public API: Resources.buildings
@@ -347,8 +393,7 @@ pub struct Building {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "buildingId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub building_id: String,
     /**
@@ -357,8 +402,7 @@ pub struct Building {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "buildingName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub building_name: String,
     /**
@@ -390,8 +434,7 @@ pub struct Building {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "floorNames"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub floor_names: Vec<String>,
     /**
@@ -407,6 +450,7 @@ pub struct Building {
 
 /// Public API: Resources.buildings
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct BuildingAddress {
     /**
      * Public API: Resources.buildings
@@ -414,8 +458,7 @@ pub struct BuildingAddress {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "addressLines"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub address_lines: Vec<String>,
     /**
@@ -424,8 +467,7 @@ pub struct BuildingAddress {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "administrativeArea"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub administrative_area: String,
     /**
@@ -434,8 +476,7 @@ pub struct BuildingAddress {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "languageCode"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub language_code: String,
     /**
@@ -453,8 +494,7 @@ pub struct BuildingAddress {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "postalCode"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub postal_code: String,
     /**
@@ -463,8 +503,7 @@ pub struct BuildingAddress {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "regionCode"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub region_code: String,
     /**
@@ -503,6 +542,7 @@ pub struct BuildingCoordinates {
 
 /// Public API: Resources.buildings
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Buildings {
     /**
      * Public API: Resources.buildings
@@ -537,14 +577,39 @@ pub struct Buildings {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub next_page_token: String,
 }
 
+impl IntoIterator for Buildings {
+    type Item = Building;
+    type IntoIter = std::vec::IntoIter<Building>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.buildings.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Buildings {
+    type Item = &'a Building;
+    type IntoIter = std::slice::Iter<'a, Building>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.buildings.iter()
+    }
+}
+
+impl Buildings {
+    /// Iterate over the `buildings`.
+    pub fn iter(&self) -> std::slice::Iter<'_, Building> {
+        self.buildings.iter()
+    }
+}
+
 /// Public API: Resources.calendars
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct CalendarResource {
     /**
      * Public API: Resources.calendars
@@ -552,8 +617,7 @@ pub struct CalendarResource {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "buildingId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub building_id: String,
     /**
@@ -577,11 +641,7 @@ pub struct CalendarResource {
     /**
      * Public API: Resources.calendars
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "featureInstances"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub feature_instances: Option<serde_json::Value>,
     /**
      * Public API: Resources.calendars
@@ -589,8 +649,7 @@ pub struct CalendarResource {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "floorName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub floor_name: String,
     /**
@@ -599,8 +658,7 @@ pub struct CalendarResource {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "floorSection"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub floor_section: String,
     /**
@@ -609,8 +667,7 @@ pub struct CalendarResource {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "generatedResourceName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub generated_resource_name: String,
     /**
@@ -628,8 +685,7 @@ pub struct CalendarResource {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "resourceCategory"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub resource_category: String,
     /**
@@ -638,8 +694,7 @@ pub struct CalendarResource {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "resourceDescription"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub resource_description: String,
     /**
@@ -648,8 +703,7 @@ pub struct CalendarResource {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "resourceEmail"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub resource_email: String,
     /**
@@ -658,8 +712,7 @@ pub struct CalendarResource {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "resourceId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub resource_id: String,
     /**
@@ -668,8 +721,7 @@ pub struct CalendarResource {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "resourceName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub resource_name: String,
     /**
@@ -678,8 +730,7 @@ pub struct CalendarResource {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "resourceType"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub resource_type: String,
     /**
@@ -688,14 +739,14 @@ pub struct CalendarResource {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "userVisibleDescription"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub user_visible_description: String,
 }
 
 /// Public API: Resources.calendars
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct CalendarResources {
     /**
      * Public API: Resources.calendars
@@ -730,14 +781,39 @@ pub struct CalendarResources {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub next_page_token: String,
 }
 
+impl IntoIterator for CalendarResources {
+    type Item = CalendarResource;
+    type IntoIter = std::vec::IntoIter<CalendarResource>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a CalendarResources {
+    type Item = &'a CalendarResource;
+    type IntoIter = std::slice::Iter<'a, CalendarResource>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+impl CalendarResources {
+    /// Iterate over the `items`.
+    pub fn iter(&self) -> std::slice::Iter<'_, CalendarResource> {
+        self.items.iter()
+    }
+}
+
 /// An notification channel used to watch for resource changes.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Channel {
     /**
      * An notification channel used to watch for resource changes.
@@ -798,8 +874,7 @@ pub struct Channel {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "resourceId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub resource_id: String,
     /**
@@ -808,8 +883,7 @@ pub struct Channel {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "resourceUri"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub resource_uri: String,
     /**
@@ -834,6 +908,7 @@ pub struct Channel {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ActiveTimeRanges {
     /**
      * The unique ID of the ASP.
@@ -841,8 +916,7 @@ pub struct ActiveTimeRanges {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "activeTime"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub active_time: i64,
     /**
@@ -876,6 +950,7 @@ pub struct CpuTemperatureInfo {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct CpuStatusReports {
     /**
      * List of CPU temperature samples.
@@ -883,15 +958,13 @@ pub struct CpuStatusReports {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "cpuTemperatureInfo"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub cpu_temperature_info: Vec<CpuTemperatureInfo>,
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "cpuUtilizationPercentageInfo"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub cpu_utilization_percentage_info: Vec<i64>,
     /**
@@ -900,13 +973,13 @@ pub struct CpuStatusReports {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "reportTime"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub report_time: Option<chrono::DateTime<chrono::Utc>>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DeviceFiles {
     /**
      * Date and time the report was received.
@@ -914,15 +987,13 @@ pub struct DeviceFiles {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "createTime"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub create_time: Option<chrono::DateTime<chrono::Utc>>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "downloadUrl"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub download_url: String,
     #[serde(
@@ -941,6 +1012,7 @@ pub struct DeviceFiles {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct VolumeInfo {
     /**
      * The unique ID of the ASP.
@@ -948,8 +1020,7 @@ pub struct VolumeInfo {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "storageFree"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub storage_free: i64,
     /**
@@ -958,20 +1029,19 @@ pub struct VolumeInfo {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "storageTotal"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub storage_total: i64,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "volumeId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub volume_id: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DiskVolumeReports {
     /**
      * Disk volumes
@@ -979,14 +1049,14 @@ pub struct DiskVolumeReports {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "volumeInfo"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub volume_info: Vec<VolumeInfo>,
 }
 
 /// Information for an ip address.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct LastKnownNetwork {
     /**
      * Information for an ip address.
@@ -994,8 +1064,7 @@ pub struct LastKnownNetwork {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "ipAddress"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub ip_address: String,
     /**
@@ -1004,8 +1073,7 @@ pub struct LastKnownNetwork {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "wanIpAddress"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub wan_ip_address: String,
 }
@@ -1035,6 +1103,7 @@ pub struct RecentUsers {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct SystemRamFreeReports {
     /**
      * Date and time the report was received.
@@ -1042,21 +1111,20 @@ pub struct SystemRamFreeReports {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "reportTime"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub report_time: Option<chrono::DateTime<chrono::Utc>>,
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "systemRamFreeInfo"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub system_ram_free_info: Vec<i64>,
 }
 
 /// Trusted Platform Module (TPM) (Read-only)
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct TpmVersionInfo {
     /**
      * Trusted Platform Module (TPM) (Read-only)
@@ -1073,8 +1141,7 @@ pub struct TpmVersionInfo {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "firmwareVersion"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub firmware_version: String,
     /**
@@ -1092,8 +1159,7 @@ pub struct TpmVersionInfo {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "specLevel"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub spec_level: String,
     /**
@@ -1102,8 +1168,7 @@ pub struct TpmVersionInfo {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "tpmModel"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub tpm_model: String,
     /**
@@ -1112,14 +1177,14 @@ pub struct TpmVersionInfo {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "vendorSpecific"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub vendor_specific: String,
 }
 
 /// Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ChromeOsDevice {
     /**
      * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
@@ -1127,8 +1192,7 @@ pub struct ChromeOsDevice {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "activeTimeRanges"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub active_time_ranges: Vec<ActiveTimeRanges>,
     /**
@@ -1137,8 +1201,7 @@ pub struct ChromeOsDevice {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "annotatedAssetId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub annotated_asset_id: String,
     /**
@@ -1147,8 +1210,7 @@ pub struct ChromeOsDevice {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "annotatedLocation"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub annotated_location: String,
     /**
@@ -1157,8 +1219,7 @@ pub struct ChromeOsDevice {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "annotatedUser"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub annotated_user: String,
     /**
@@ -1167,8 +1228,7 @@ pub struct ChromeOsDevice {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "autoUpdateExpiration"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub auto_update_expiration: i64,
     /**
@@ -1177,8 +1237,7 @@ pub struct ChromeOsDevice {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "bootMode"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub boot_mode: String,
     /**
@@ -1187,8 +1246,7 @@ pub struct ChromeOsDevice {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "cpuStatusReports"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub cpu_status_reports: Vec<CpuStatusReports>,
     /**
@@ -1197,8 +1255,7 @@ pub struct ChromeOsDevice {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "deviceFiles"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub device_files: Vec<DeviceFiles>,
     /**
@@ -1207,8 +1264,7 @@ pub struct ChromeOsDevice {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "deviceId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub device_id: String,
     /**
@@ -1217,8 +1273,7 @@ pub struct ChromeOsDevice {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "diskVolumeReports"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub disk_volume_reports: Vec<DiskVolumeReports>,
     /**
@@ -1227,8 +1282,7 @@ pub struct ChromeOsDevice {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "dockMacAddress"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub dock_mac_address: String,
     /**
@@ -1246,8 +1300,7 @@ pub struct ChromeOsDevice {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "ethernetMacAddress"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub ethernet_mac_address: String,
     /**
@@ -1256,8 +1309,7 @@ pub struct ChromeOsDevice {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "ethernetMacAddress0"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub ethernet_mac_address_0: String,
     /**
@@ -1266,8 +1318,7 @@ pub struct ChromeOsDevice {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "firmwareVersion"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub firmware_version: String,
     /**
@@ -1285,8 +1336,7 @@ pub struct ChromeOsDevice {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastEnrollmentTime"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub last_enrollment_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
@@ -1295,8 +1345,7 @@ pub struct ChromeOsDevice {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "lastKnownNetwork"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub last_known_network: Vec<LastKnownNetwork>,
     /**
@@ -1305,8 +1354,7 @@ pub struct ChromeOsDevice {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastSync"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub last_sync: Option<chrono::DateTime<chrono::Utc>>,
     /**
@@ -1315,8 +1363,7 @@ pub struct ChromeOsDevice {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "macAddress"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub mac_address: String,
     /**
@@ -1325,8 +1372,7 @@ pub struct ChromeOsDevice {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize",
-        rename = "manufactureDate"
+        deserialize_with = "crate::utils::date_format::deserialize"
     )]
     pub manufacture_date: Option<chrono::NaiveDate>,
     /**
@@ -1362,8 +1408,7 @@ pub struct ChromeOsDevice {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "orderNumber"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub order_number: String,
     /**
@@ -1372,8 +1417,7 @@ pub struct ChromeOsDevice {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "orgUnitPath"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub org_unit_path: String,
     /**
@@ -1382,8 +1426,7 @@ pub struct ChromeOsDevice {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "osVersion"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub os_version: String,
     /**
@@ -1392,8 +1435,7 @@ pub struct ChromeOsDevice {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "platformVersion"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub platform_version: String,
     /**
@@ -1402,8 +1444,7 @@ pub struct ChromeOsDevice {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "recentUsers"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub recent_users: Vec<RecentUsers>,
     /**
@@ -1412,8 +1453,7 @@ pub struct ChromeOsDevice {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "screenshotFiles"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub screenshot_files: Vec<DeviceFiles>,
     /**
@@ -1422,8 +1462,7 @@ pub struct ChromeOsDevice {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "serialNumber"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub serial_number: String,
     /**
@@ -1441,8 +1480,7 @@ pub struct ChromeOsDevice {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "supportEndDate"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub support_end_date: Option<chrono::DateTime<chrono::Utc>>,
     /**
@@ -1451,8 +1489,7 @@ pub struct ChromeOsDevice {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "systemRamFreeReports"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub system_ram_free_reports: Vec<SystemRamFreeReports>,
     /**
@@ -1461,31 +1498,26 @@ pub struct ChromeOsDevice {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "systemRamTotal"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub system_ram_total: i64,
     /**
      * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "tpmVersionInfo"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tpm_version_info: Option<TpmVersionInfo>,
     /**
      * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "willAutoRenew"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub will_auto_renew: bool,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ChromeOsDeviceAction {
     #[serde(
         default,
@@ -1496,13 +1528,13 @@ pub struct ChromeOsDeviceAction {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "deprovisionReason"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub deprovision_reason: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ChromeOsDevices {
     /**
      * List of Chrome OS Device objects.
@@ -1528,19 +1560,43 @@ pub struct ChromeOsDevices {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub next_page_token: String,
 }
 
+impl IntoIterator for ChromeOsDevices {
+    type Item = ChromeOsDevice;
+    type IntoIter = std::vec::IntoIter<ChromeOsDevice>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.chromeosdevices.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ChromeOsDevices {
+    type Item = &'a ChromeOsDevice;
+    type IntoIter = std::slice::Iter<'a, ChromeOsDevice>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.chromeosdevices.iter()
+    }
+}
+
+impl ChromeOsDevices {
+    /// Iterate over the `chromeosdevices`.
+    pub fn iter(&self) -> std::slice::Iter<'_, ChromeOsDevice> {
+        self.chromeosdevices.iter()
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ChromeOsMoveDevicesOu {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "deviceIds"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub device_ids: Vec<String>,
 }
@@ -1565,12 +1621,12 @@ pub struct CreatePrinterRequest {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Customer {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "alternateEmail"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub alternate_email: String,
     /**
@@ -1579,15 +1635,13 @@ pub struct Customer {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "customerCreationTime"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub customer_creation_time: Option<chrono::DateTime<chrono::Utc>>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "customerDomain"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub customer_domain: String,
     #[serde(
@@ -1617,53 +1671,44 @@ pub struct Customer {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "phoneNumber"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub phone_number: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "postalAddress"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub postal_address: Option<CustomerPostalAddress>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct CustomerPostalAddress {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "addressLine1"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub address_line_1: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "addressLine2"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub address_line_2: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "addressLine3"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub address_line_3: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "contactName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub contact_name: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "countryCode"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub country_code: String,
     #[serde(
@@ -1675,15 +1720,13 @@ pub struct CustomerPostalAddress {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "organizationName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub organization_name: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "postalCode"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub postal_code: String,
     #[serde(
@@ -1697,7 +1740,7 @@ pub struct CustomerPostalAddress {
 /**
  * Indicates the command state.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum State {
     #[serde(rename = "ACKED_BY_CLIENT")]
     AckedByClient,
@@ -1741,7 +1784,36 @@ impl Default for State {
         State::Noop
     }
 }
+
 impl State {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            State::AckedByClient => "ACKED_BY_CLIENT",
+            State::Cancelled => "CANCELLED",
+            State::ExecutedByClient => "EXECUTED_BY_CLIENT",
+            State::Expired => "EXPIRED",
+            State::Pending => "PENDING",
+            State::SentToClient => "SENT_TO_CLIENT",
+            State::StateUnspecified => "STATE_UNSPECIFIED",
+            State::Noop => "",
+            State::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [State] {
+        &[
+            State::AckedByClient,
+            State::Cancelled,
+            State::ExecutedByClient,
+            State::Expired,
+            State::Pending,
+            State::SentToClient,
+            State::StateUnspecified,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, State::Noop)
     }
@@ -1750,7 +1822,7 @@ impl State {
 /**
  * The type of the command.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Type {
     #[serde(rename = "COMMAND_TYPE_UNSPECIFIED")]
     CommandTypeUnspecified,
@@ -1791,7 +1863,34 @@ impl Default for Type {
         Type::Noop
     }
 }
+
 impl Type {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Type::CommandTypeUnspecified => "COMMAND_TYPE_UNSPECIFIED",
+            Type::Reboot => "REBOOT",
+            Type::RemotePowerwash => "REMOTE_POWERWASH",
+            Type::SetVolume => "SET_VOLUME",
+            Type::TakeAScreenshot => "TAKE_A_SCREENSHOT",
+            Type::WipeUsers => "WIPE_USERS",
+            Type::Noop => "",
+            Type::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Type] {
+        &[
+            Type::CommandTypeUnspecified,
+            Type::Reboot,
+            Type::RemotePowerwash,
+            Type::SetVolume,
+            Type::TakeAScreenshot,
+            Type::WipeUsers,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Type::Noop)
     }
@@ -1799,6 +1898,7 @@ impl Type {
 
 /// Information regarding a command that was issued to a device.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DirectoryChromeosdevicesCommand {
     /**
      * Information regarding a command that was issued to a device.
@@ -1806,8 +1906,7 @@ pub struct DirectoryChromeosdevicesCommand {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "commandExpireTime"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub command_expire_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
@@ -1816,18 +1915,13 @@ pub struct DirectoryChromeosdevicesCommand {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "commandId"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub command_id: i64,
     /**
      * Information regarding a command that was issued to a device.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "commandResult"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub command_result: Option<DirectoryChromeosdevicesCommandResult>,
     /**
      * Information regarding a command that was issued to a device.
@@ -1835,8 +1929,7 @@ pub struct DirectoryChromeosdevicesCommand {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "issueTime"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub issue_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
@@ -1863,7 +1956,7 @@ pub struct DirectoryChromeosdevicesCommand {
 /**
  * The result of the command.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Result {
     #[serde(rename = "COMMAND_RESULT_TYPE_UNSPECIFIED")]
     CommandResultTypeUnspecified,
@@ -1898,7 +1991,30 @@ impl Default for Result {
         Result::Noop
     }
 }
+
 impl Result {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Result::CommandResultTypeUnspecified => "COMMAND_RESULT_TYPE_UNSPECIFIED",
+            Result::Failure => "FAILURE",
+            Result::Ignored => "IGNORED",
+            Result::Success => "SUCCESS",
+            Result::Noop => "",
+            Result::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Result] {
+        &[
+            Result::CommandResultTypeUnspecified,
+            Result::Failure,
+            Result::Ignored,
+            Result::Success,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Result::Noop)
     }
@@ -1906,6 +2022,7 @@ impl Result {
 
 /// The result of executing a command.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DirectoryChromeosdevicesCommandResult {
     /**
      * The result of executing a command.
@@ -1913,8 +2030,7 @@ pub struct DirectoryChromeosdevicesCommandResult {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "errorMessage"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub error_message: String,
     /**
@@ -1923,8 +2039,7 @@ pub struct DirectoryChromeosdevicesCommandResult {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "executeTime"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub execute_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
@@ -1936,15 +2051,12 @@ pub struct DirectoryChromeosdevicesCommandResult {
 
 /// A request for issuing a command.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DirectoryChromeosdevicesIssueCommandRequest {
     /**
      * A request for issuing a command.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "commandType"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub command_type: Option<Type>,
     /**
      * A request for issuing a command.
@@ -1959,6 +2071,7 @@ pub struct DirectoryChromeosdevicesIssueCommandRequest {
 
 /// A response for issuing a command.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DirectoryChromeosdevicesIssueCommandResponse {
     /**
      * A response for issuing a command.
@@ -1966,13 +2079,13 @@ pub struct DirectoryChromeosdevicesIssueCommandResponse {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "commandId"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub command_id: i64,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DomainAlias {
     /**
      * The unique ID of the ASP.
@@ -1980,15 +2093,13 @@ pub struct DomainAlias {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "creationTime"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub creation_time: i64,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "domainAliasName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub domain_alias_name: String,
     #[serde(
@@ -2006,8 +2117,7 @@ pub struct DomainAlias {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "parentDomainName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub parent_domain_name: String,
     /**
@@ -2021,6 +2131,7 @@ pub struct DomainAlias {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DomainAliases {
     /**
      * List of domain alias objects.
@@ -2028,8 +2139,7 @@ pub struct DomainAliases {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "domainAliases"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub domain_aliases: Vec<DomainAlias>,
     #[serde(
@@ -2047,6 +2157,7 @@ pub struct DomainAliases {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Domains {
     /**
      * The unique ID of the ASP.
@@ -2054,8 +2165,7 @@ pub struct Domains {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "creationTime"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub creation_time: i64,
     /**
@@ -2064,15 +2174,13 @@ pub struct Domains {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "domainAliases"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub domain_aliases: Vec<DomainAlias>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "domainName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub domain_name: String,
     #[serde(
@@ -2086,8 +2194,7 @@ pub struct Domains {
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "isPrimary"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub is_primary: bool,
     #[serde(
@@ -2138,7 +2245,7 @@ pub struct Empty {}
 /**
  * Canonical code for why the update failed to apply.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ErrorCode {
     #[serde(rename = "ABORTED")]
     Aborted,
@@ -2212,7 +2319,56 @@ impl Default for ErrorCode {
         ErrorCode::Noop
     }
 }
+
 impl ErrorCode {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorCode::Aborted => "ABORTED",
+            ErrorCode::AlreadyExists => "ALREADY_EXISTS",
+            ErrorCode::Cancelled => "CANCELLED",
+            ErrorCode::DataLoss => "DATA_LOSS",
+            ErrorCode::DeadlineExceeded => "DEADLINE_EXCEEDED",
+            ErrorCode::FailedPrecondition => "FAILED_PRECONDITION",
+            ErrorCode::Internal => "INTERNAL",
+            ErrorCode::InvalidArgument => "INVALID_ARGUMENT",
+            ErrorCode::NotFound => "NOT_FOUND",
+            ErrorCode::Ok => "OK",
+            ErrorCode::OutOfRange => "OUT_OF_RANGE",
+            ErrorCode::PermissionDenied => "PERMISSION_DENIED",
+            ErrorCode::ResourceExhausted => "RESOURCE_EXHAUSTED",
+            ErrorCode::Unauthenticated => "UNAUTHENTICATED",
+            ErrorCode::Unavailable => "UNAVAILABLE",
+            ErrorCode::Unimplemented => "UNIMPLEMENTED",
+            ErrorCode::Unknown => "UNKNOWN",
+            ErrorCode::Noop => "",
+            ErrorCode::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ErrorCode] {
+        &[
+            ErrorCode::Aborted,
+            ErrorCode::AlreadyExists,
+            ErrorCode::Cancelled,
+            ErrorCode::DataLoss,
+            ErrorCode::DeadlineExceeded,
+            ErrorCode::FailedPrecondition,
+            ErrorCode::Internal,
+            ErrorCode::InvalidArgument,
+            ErrorCode::NotFound,
+            ErrorCode::Ok,
+            ErrorCode::OutOfRange,
+            ErrorCode::PermissionDenied,
+            ErrorCode::ResourceExhausted,
+            ErrorCode::Unauthenticated,
+            ErrorCode::Unavailable,
+            ErrorCode::Unimplemented,
+            ErrorCode::Unknown,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ErrorCode::Noop)
     }
@@ -2220,11 +2376,12 @@ impl ErrorCode {
 
 /// Info about failures
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct FailureInfo {
     /**
      * Info about failures
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "errorCode")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub error_code: Option<ErrorCode>,
     /**
      * Info about failures
@@ -2232,8 +2389,7 @@ pub struct FailureInfo {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "errorMessage"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub error_message: String,
     /**
@@ -2247,8 +2403,7 @@ pub struct FailureInfo {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "printerId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub printer_id: String,
 }
@@ -2296,18 +2451,19 @@ pub struct FeatureInstance {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct FeatureRename {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "newName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub new_name: String,
 }
 
 /// Public API: Resources.features
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Features {
     /**
      * Public API: Resources.features
@@ -2342,22 +2498,46 @@ pub struct Features {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub next_page_token: String,
 }
 
+impl IntoIterator for Features {
+    type Item = Feature;
+    type IntoIter = std::vec::IntoIter<Feature>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.features.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Features {
+    type Item = &'a Feature;
+    type IntoIter = std::slice::Iter<'a, Feature>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.features.iter()
+    }
+}
+
+impl Features {
+    /// Iterate over the `features`.
+    pub fn iter(&self) -> std::slice::Iter<'_, Feature> {
+        self.features.iter()
+    }
+}
+
 /// Google Groups provide your users the ability to send messages to groups of people using the group's email address. For more information about common tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-groups).
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Group {
     /**
      * Google Groups provide your users the ability to send messages to groups of people using the group's email address. For more information about common tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-groups).
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "adminCreated"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub admin_created: bool,
     /**
@@ -2384,8 +2564,7 @@ pub struct Group {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "directMembersCount"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub direct_members_count: i64,
     /**
@@ -2439,13 +2618,13 @@ pub struct Group {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "nonEditableAliases"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub non_editable_aliases: Vec<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Groups {
     #[serde(
         default,
@@ -2471,14 +2650,39 @@ pub struct Groups {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub next_page_token: String,
 }
 
+impl IntoIterator for Groups {
+    type Item = Group;
+    type IntoIter = std::vec::IntoIter<Group>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.groups.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Groups {
+    type Item = &'a Group;
+    type IntoIter = std::slice::Iter<'a, Group>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.groups.iter()
+    }
+}
+
+impl Groups {
+    /// Iterate over the `groups`.
+    pub fn iter(&self) -> std::slice::Iter<'_, Group> {
+        self.groups.iter()
+    }
+}
+
 /// Response for listing allowed printer models.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ListPrinterModelsResponse {
     /**
      * Response for listing allowed printer models.
@@ -2486,8 +2690,7 @@ pub struct ListPrinterModelsResponse {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub next_page_token: String,
     /**
@@ -2496,14 +2699,39 @@ pub struct ListPrinterModelsResponse {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "printerModels"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub printer_models: Vec<PrinterModel>,
 }
 
+impl IntoIterator for ListPrinterModelsResponse {
+    type Item = PrinterModel;
+    type IntoIter = std::vec::IntoIter<PrinterModel>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.printer_models.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ListPrinterModelsResponse {
+    type Item = &'a PrinterModel;
+    type IntoIter = std::slice::Iter<'a, PrinterModel>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.printer_models.iter()
+    }
+}
+
+impl ListPrinterModelsResponse {
+    /// Iterate over the `printer_models`.
+    pub fn iter(&self) -> std::slice::Iter<'_, PrinterModel> {
+        self.printer_models.iter()
+    }
+}
+
 /// Response for listing printers.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ListPrintersResponse {
     /**
      * Response for listing printers.
@@ -2511,8 +2739,7 @@ pub struct ListPrintersResponse {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub next_page_token: String,
     /**
@@ -2526,6 +2753,31 @@ pub struct ListPrintersResponse {
     pub printers: Vec<Printer>,
 }
 
+impl IntoIterator for ListPrintersResponse {
+    type Item = Printer;
+    type IntoIter = std::vec::IntoIter<Printer>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.printers.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ListPrintersResponse {
+    type Item = &'a Printer;
+    type IntoIter = std::slice::Iter<'a, Printer>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.printers.iter()
+    }
+}
+
+impl ListPrintersResponse {
+    /// Iterate over the `printers`.
+    pub fn iter(&self) -> std::slice::Iter<'_, Printer> {
+        self.printers.iter()
+    }
+}
+
 /// A Google Groups member can be a user or another group. This member can be inside or outside of your account's domains. For more information about common group member tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-group-members).
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Member {
@@ -2605,6 +2857,7 @@ pub struct Member {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Members {
     #[serde(
         default,
@@ -2630,40 +2883,63 @@ pub struct Members {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub next_page_token: String,
 }
 
+impl IntoIterator for Members {
+    type Item = Member;
+    type IntoIter = std::vec::IntoIter<Member>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.members.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Members {
+    type Item = &'a Member;
+    type IntoIter = std::slice::Iter<'a, Member>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.members.iter()
+    }
+}
+
+impl Members {
+    /// Iterate over the `members`.
+    pub fn iter(&self) -> std::slice::Iter<'_, Member> {
+        self.members.iter()
+    }
+}
+
 /// JSON template for Has Member response in Directory API.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct MembersHasMember {
     /**
      * JSON template for Has Member response in Directory API.
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "isMember"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub is_member: bool,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Applications {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "displayName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub display_name: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "packageName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub package_name: String,
     #[serde(
@@ -2678,29 +2954,27 @@ pub struct Applications {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "versionCode"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub version_code: i64,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "versionName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub version_name: String,
 }
 
 /// Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct MobileDevice {
     /**
      * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "adbStatus"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub adb_status: bool,
     /**
@@ -2718,8 +2992,7 @@ pub struct MobileDevice {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "basebandVersion"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub baseband_version: String,
     /**
@@ -2728,8 +3001,7 @@ pub struct MobileDevice {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "bootloaderVersion"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub bootloader_version: String,
     /**
@@ -2747,8 +3019,7 @@ pub struct MobileDevice {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "buildNumber"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub build_number: String,
     /**
@@ -2757,8 +3028,7 @@ pub struct MobileDevice {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "defaultLanguage"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub default_language: String,
     /**
@@ -2766,8 +3036,7 @@ pub struct MobileDevice {
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "developerOptionsStatus"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub developer_options_status: bool,
     /**
@@ -2776,8 +3045,7 @@ pub struct MobileDevice {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "deviceCompromisedStatus"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub device_compromised_status: String,
     /**
@@ -2786,8 +3054,7 @@ pub struct MobileDevice {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "deviceId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub device_id: String,
     /**
@@ -2796,8 +3063,7 @@ pub struct MobileDevice {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "devicePasswordStatus"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub device_password_status: String,
     /**
@@ -2815,8 +3081,7 @@ pub struct MobileDevice {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "encryptionStatus"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub encryption_status: String,
     /**
@@ -2834,8 +3099,7 @@ pub struct MobileDevice {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "firstSync"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub first_sync: Option<chrono::DateTime<chrono::Utc>>,
     /**
@@ -2853,8 +3117,7 @@ pub struct MobileDevice {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "hardwareId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub hardware_id: String,
     /**
@@ -2872,8 +3135,7 @@ pub struct MobileDevice {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "kernelVersion"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub kernel_version: String,
     /**
@@ -2891,8 +3153,7 @@ pub struct MobileDevice {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastSync"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub last_sync: Option<chrono::DateTime<chrono::Utc>>,
     /**
@@ -2900,8 +3161,7 @@ pub struct MobileDevice {
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "managedAccountIsOnOwnerProfile"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub managed_account_is_on_owner_profile: bool,
     /**
@@ -2946,8 +3206,7 @@ pub struct MobileDevice {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "networkOperator"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub network_operator: String,
     /**
@@ -2965,8 +3224,7 @@ pub struct MobileDevice {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "otherAccountsInfo"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub other_accounts_info: Vec<String>,
     /**
@@ -2984,8 +3242,7 @@ pub struct MobileDevice {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "releaseVersion"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub release_version: String,
     /**
@@ -2994,8 +3251,7 @@ pub struct MobileDevice {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "resourceId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub resource_id: String,
     /**
@@ -3004,8 +3260,7 @@ pub struct MobileDevice {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "securityPatchLevel"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub security_patch_level: i64,
     /**
@@ -3014,8 +3269,7 @@ pub struct MobileDevice {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "serialNumber"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub serial_number: String,
     /**
@@ -3032,8 +3286,7 @@ pub struct MobileDevice {
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "supportsWorkProfile"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub supports_work_profile: bool,
     /**
@@ -3051,8 +3304,7 @@ pub struct MobileDevice {
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "unknownSourcesStatus"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub unknown_sources_status: bool,
     /**
@@ -3061,8 +3313,7 @@ pub struct MobileDevice {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "userAgent"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub user_agent: String,
     /**
@@ -3071,8 +3322,7 @@ pub struct MobileDevice {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "wifiMacAddress"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub wifi_mac_address: String,
 }
@@ -3088,6 +3338,7 @@ pub struct MobileDeviceAction {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct MobileDevices {
     #[serde(
         default,
@@ -3113,22 +3364,46 @@ pub struct MobileDevices {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub next_page_token: String,
 }
 
+impl IntoIterator for MobileDevices {
+    type Item = MobileDevice;
+    type IntoIter = std::vec::IntoIter<MobileDevice>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.mobiledevices.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a MobileDevices {
+    type Item = &'a MobileDevice;
+    type IntoIter = std::slice::Iter<'a, MobileDevice>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.mobiledevices.iter()
+    }
+}
+
+impl MobileDevices {
+    /// Iterate over the `mobiledevices`.
+    pub fn iter(&self) -> std::slice::Iter<'_, MobileDevice> {
+        self.mobiledevices.iter()
+    }
+}
+
 /// Managing your account's organizational units allows you to configure your users' access to services and custom settings. For more information about common organizational unit tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-org-units.html).
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct OrgUnit {
     /**
      * Managing your account's organizational units allows you to configure your users' access to services and custom settings. For more information about common organizational unit tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-org-units.html).
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "blockInheritance"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub block_inheritance: bool,
     /**
@@ -3173,8 +3448,7 @@ pub struct OrgUnit {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "orgUnitId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub org_unit_id: String,
     /**
@@ -3183,8 +3457,7 @@ pub struct OrgUnit {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "orgUnitPath"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub org_unit_path: String,
     /**
@@ -3193,8 +3466,7 @@ pub struct OrgUnit {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "parentOrgUnitId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub parent_org_unit_id: String,
     /**
@@ -3203,13 +3475,13 @@ pub struct OrgUnit {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "parentOrgUnitPath"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub parent_org_unit_path: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct OrgUnits {
     #[serde(
         default,
@@ -3229,14 +3501,14 @@ pub struct OrgUnits {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "organizationUnits"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub organization_units: Vec<OrgUnit>,
 }
 
 /// Printer configuration.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Printer {
     /**
      * Printer configuration.
@@ -3244,8 +3516,7 @@ pub struct Printer {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "auxiliaryMessages"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub auxiliary_messages: Vec<AuxiliaryMessage>,
     /**
@@ -3254,8 +3525,7 @@ pub struct Printer {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "createTime"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub create_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
@@ -3273,8 +3543,7 @@ pub struct Printer {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "displayName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub display_name: String,
     /**
@@ -3292,8 +3561,7 @@ pub struct Printer {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "makeAndModel"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub make_and_model: String,
     /**
@@ -3311,8 +3579,7 @@ pub struct Printer {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "orgUnitId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub org_unit_id: String,
     /**
@@ -3329,14 +3596,14 @@ pub struct Printer {
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "useDriverlessConfig"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub use_driverless_config: bool,
 }
 
 /// Printer manufacturer and model
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct PrinterModel {
     /**
      * Printer manufacturer and model
@@ -3344,8 +3611,7 @@ pub struct PrinterModel {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "displayName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub display_name: String,
     /**
@@ -3354,8 +3620,7 @@ pub struct PrinterModel {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "makeAndModel"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub make_and_model: String,
     /**
@@ -3370,6 +3635,7 @@ pub struct PrinterModel {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Privilege {
     /**
      * A list of child privileges. Privileges for a service form a tree. Each privilege can have a list of child privileges; this list is empty for a leaf privilege.
@@ -3377,8 +3643,7 @@ pub struct Privilege {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "childPrivileges"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub child_privileges: Vec<Privilege>,
     #[serde(
@@ -3392,8 +3657,7 @@ pub struct Privilege {
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "isOuScopable"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub is_ou_scopable: bool,
     #[serde(
@@ -3405,22 +3669,19 @@ pub struct Privilege {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "privilegeName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub privilege_name: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "serviceId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub service_id: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "serviceName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub service_name: String,
 }
@@ -3451,24 +3712,24 @@ pub struct Privileges {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct RolePrivileges {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "privilegeName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub privilege_name: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "serviceId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub service_id: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Role {
     #[serde(
         default,
@@ -3481,8 +3742,7 @@ pub struct Role {
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "isSuperAdminRole"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub is_super_admin_role: bool,
     /**
@@ -3490,8 +3750,7 @@ pub struct Role {
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "isSystemRole"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub is_system_role: bool,
     #[serde(
@@ -3503,8 +3762,7 @@ pub struct Role {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "roleDescription"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub role_description: String,
     /**
@@ -3513,15 +3771,13 @@ pub struct Role {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "roleId"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub role_id: i64,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "roleName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub role_name: String,
     /**
@@ -3530,14 +3786,14 @@ pub struct Role {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "rolePrivileges"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub role_privileges: Vec<RolePrivileges>,
 }
 
 /// Defines an assignment of a role.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct RoleAssignment {
     /**
      * Defines an assignment of a role.
@@ -3545,8 +3801,7 @@ pub struct RoleAssignment {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "assignedTo"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub assigned_to: String,
     /**
@@ -3573,8 +3828,7 @@ pub struct RoleAssignment {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "orgUnitId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub org_unit_id: String,
     /**
@@ -3583,8 +3837,7 @@ pub struct RoleAssignment {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "roleAssignmentId"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub role_assignment_id: i64,
     /**
@@ -3593,8 +3846,7 @@ pub struct RoleAssignment {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "roleId"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub role_id: i64,
     /**
@@ -3603,13 +3855,13 @@ pub struct RoleAssignment {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "scopeType"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub scope_type: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct RoleAssignments {
     #[serde(
         default,
@@ -3635,14 +3887,39 @@ pub struct RoleAssignments {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub next_page_token: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Roles {
+impl IntoIterator for RoleAssignments {
+    type Item = RoleAssignment;
+    type IntoIter = std::vec::IntoIter<RoleAssignment>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a RoleAssignments {
+    type Item = &'a RoleAssignment;
+    type IntoIter = std::slice::Iter<'a, RoleAssignment>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+impl RoleAssignments {
+    /// Iterate over the `items`.
+    pub fn iter(&self) -> std::slice::Iter<'_, RoleAssignment> {
+        self.items.iter()
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Roles {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -3667,14 +3944,39 @@ pub struct Roles {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub next_page_token: String,
 }
 
+impl IntoIterator for Roles {
+    type Item = Role;
+    type IntoIter = std::vec::IntoIter<Role>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Roles {
+    type Item = &'a Role;
+    type IntoIter = std::slice::Iter<'a, Role>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+impl Roles {
+    /// Iterate over the `items`.
+    pub fn iter(&self) -> std::slice::Iter<'_, Role> {
+        self.items.iter()
+    }
+}
+
 /// The type of API resource. For Schema resources, this is always `admin#directory#schema`.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Schema {
     /**
      * The type of API resource. For Schema resources, this is always `admin#directory#schema`.
@@ -3682,8 +3984,7 @@ pub struct Schema {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "displayName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub display_name: String,
     /**
@@ -3719,8 +4020,7 @@ pub struct Schema {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "schemaId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub schema_id: String,
     /**
@@ -3729,14 +4029,14 @@ pub struct Schema {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "schemaName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub schema_name: String,
 }
 
 /// Indexing spec for a numeric field. By default, only exact match queries will be supported for numeric fields. Setting the `numericIndexingSpec` allows range queries to be supported.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct NumericIndexingSpec {
     /**
      * Indexing spec for a numeric field. By default, only exact match queries will be supported for numeric fields. Setting the `numericIndexingSpec` allows range queries to be supported.
@@ -3744,8 +4044,7 @@ pub struct NumericIndexingSpec {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "maxValue"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub max_value: f64,
     /**
@@ -3754,14 +4053,14 @@ pub struct NumericIndexingSpec {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "minValue"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub min_value: f64,
 }
 
 /// You can use schemas to add custom fields to user profiles. You can use these fields to store information such as the projects your users work on, their physical locations, their hire dates, or whatever else fits your business needs. For more information, see [Custom User Fields](/admin-sdk/directory/v1/guides/manage-schemas).
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct SchemaFieldSpec {
     /**
      * You can use schemas to add custom fields to user profiles. You can use these fields to store information such as the projects your users work on, their physical locations, their hire dates, or whatever else fits your business needs. For more information, see [Custom User Fields](/admin-sdk/directory/v1/guides/manage-schemas).
@@ -3769,8 +4068,7 @@ pub struct SchemaFieldSpec {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "displayName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub display_name: String,
     /**
@@ -3788,8 +4086,7 @@ pub struct SchemaFieldSpec {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "fieldId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub field_id: String,
     /**
@@ -3798,8 +4095,7 @@ pub struct SchemaFieldSpec {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "fieldName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub field_name: String,
     /**
@@ -3808,8 +4104,7 @@ pub struct SchemaFieldSpec {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "fieldType"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub field_type: String,
     /**
@@ -3834,18 +4129,13 @@ pub struct SchemaFieldSpec {
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "multiValued"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub multi_valued: bool,
     /**
      * You can use schemas to add custom fields to user profiles. You can use these fields to store information such as the projects your users work on, their physical locations, their hire dates, or whatever else fits your business needs. For more information, see [Custom User Fields](/admin-sdk/directory/v1/guides/manage-schemas).
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "numericIndexingSpec"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub numeric_indexing_spec: Option<NumericIndexingSpec>,
     /**
      * You can use schemas to add custom fields to user profiles. You can use these fields to store information such as the projects your users work on, their physical locations, their hire dates, or whatever else fits your business needs. For more information, see [Custom User Fields](/admin-sdk/directory/v1/guides/manage-schemas).
@@ -3853,8 +4143,7 @@ pub struct SchemaFieldSpec {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "readAccessType"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub read_access_type: String,
 }
@@ -3893,6 +4182,7 @@ pub struct Schemas {
 
 /// JSON template for token resource in Directory API.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Token {
     /**
      * JSON template for token resource in Directory API.
@@ -3908,8 +4198,7 @@ pub struct Token {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "clientId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub client_id: String,
     /**
@@ -3918,8 +4207,7 @@ pub struct Token {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "displayText"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub display_text: String,
     /**
@@ -3945,8 +4233,7 @@ pub struct Token {
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "nativeApp"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub native_app: bool,
     /**
@@ -3964,8 +4251,7 @@ pub struct Token {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "userKey"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub user_key: String,
 }
@@ -4003,19 +4289,18 @@ pub struct Tokens {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Ims {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "customProtocol"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub custom_protocol: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "customType"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub custom_type: String,
     #[serde(
@@ -4049,6 +4334,7 @@ pub struct Ims {
 
 /// The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct User {
     /**
      * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
@@ -4064,8 +4350,7 @@ pub struct User {
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "agreedToTerms"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub agreed_to_terms: bool,
     /**
@@ -4090,8 +4375,7 @@ pub struct User {
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "changePasswordAtNextLogin"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub change_password_at_next_login: bool,
     /**
@@ -4100,14 +4384,13 @@ pub struct User {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "creationTime"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub creation_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
      * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
      */
-    #[serde(rename = "customSchemas")]
+    #[serde()]
     pub custom_schemas:
         std::collections::HashMap<String, std::collections::HashMap<String, serde_json::Value>>,
     /**
@@ -4116,8 +4399,7 @@ pub struct User {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "customerId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub customer_id: String,
     /**
@@ -4126,8 +4408,7 @@ pub struct User {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "deletionTime"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub deletion_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
@@ -4151,11 +4432,7 @@ pub struct User {
     /**
      * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "externalIds"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub external_ids: Option<serde_json::Value>,
     /**
      * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
@@ -4168,8 +4445,7 @@ pub struct User {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "hashFunction"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub hash_function: String,
     /**
@@ -4195,8 +4471,7 @@ pub struct User {
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "includeInGlobalAddressList"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub include_in_global_address_list: bool,
     /**
@@ -4204,8 +4479,7 @@ pub struct User {
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "ipWhitelisted"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub ip_whitelisted: bool,
     /**
@@ -4213,8 +4487,7 @@ pub struct User {
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "isAdmin"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub is_admin: bool,
     /**
@@ -4222,8 +4495,7 @@ pub struct User {
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "isDelegatedAdmin"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub is_delegated_admin: bool,
     /**
@@ -4231,8 +4503,7 @@ pub struct User {
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "isEnforcedIn2Sv"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub is_enforced_in_2_sv: bool,
     /**
@@ -4240,8 +4511,7 @@ pub struct User {
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "isEnrolledIn2Sv"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub is_enrolled_in_2_sv: bool,
     /**
@@ -4249,8 +4519,7 @@ pub struct User {
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "isMailboxSetup"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub is_mailbox_setup: bool,
     /**
@@ -4278,8 +4547,7 @@ pub struct User {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastLoginTime"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub last_login_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
@@ -4302,8 +4570,7 @@ pub struct User {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "nonEditableAliases"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub non_editable_aliases: Vec<String>,
     /**
@@ -4317,8 +4584,7 @@ pub struct User {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "orgUnitPath"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub org_unit_path: String,
     /**
@@ -4347,11 +4613,7 @@ pub struct User {
     /**
      * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "posixAccounts"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub posix_accounts: Option<serde_json::Value>,
     /**
      * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
@@ -4359,8 +4621,7 @@ pub struct User {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "primaryEmail"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub primary_email: String,
     /**
@@ -4369,8 +4630,7 @@ pub struct User {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "recoveryEmail"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub recovery_email: String,
     /**
@@ -4379,8 +4639,7 @@ pub struct User {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "recoveryPhone"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub recovery_phone: String,
     /**
@@ -4394,8 +4653,7 @@ pub struct User {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "sshPublicKeys"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub ssh_public_keys: Vec<UserSshPublicKey>,
     /**
@@ -4412,8 +4670,7 @@ pub struct User {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "suspensionReason"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub suspension_reason: String,
     /**
@@ -4422,8 +4679,7 @@ pub struct User {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "thumbnailPhotoEtag"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub thumbnail_photo_etag: String,
     /**
@@ -4432,8 +4688,7 @@ pub struct User {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "thumbnailPhotoUrl"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub thumbnail_photo_url: String,
     /**
@@ -4445,6 +4700,7 @@ pub struct User {
 
 /// JSON template for About (notes) of a user in Directory API.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UserAbout {
     /**
      * JSON template for About (notes) of a user in Directory API.
@@ -4452,8 +4708,7 @@ pub struct UserAbout {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "contentType"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub content_type: String,
     /**
@@ -4469,6 +4724,7 @@ pub struct UserAbout {
 
 /// JSON template for address.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UserAddress {
     /**
      * JSON template for address.
@@ -4485,8 +4741,7 @@ pub struct UserAddress {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "countryCode"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub country_code: String,
     /**
@@ -4495,8 +4750,7 @@ pub struct UserAddress {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "customType"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub custom_type: String,
     /**
@@ -4505,8 +4759,7 @@ pub struct UserAddress {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "extendedAddress"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub extended_address: String,
     /**
@@ -4533,8 +4786,7 @@ pub struct UserAddress {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "poBox"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub po_box: String,
     /**
@@ -4543,8 +4795,7 @@ pub struct UserAddress {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "postalCode"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub postal_code: String,
     /**
@@ -4569,8 +4820,7 @@ pub struct UserAddress {
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "sourceIsStructured"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub source_is_structured: bool,
     /**
@@ -4579,8 +4829,7 @@ pub struct UserAddress {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "streetAddress"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub street_address: String,
     /**
@@ -4597,6 +4846,7 @@ pub struct UserAddress {
 
 /// JSON template for an email.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UserEmail {
     /**
      * JSON template for an email.
@@ -4613,8 +4863,7 @@ pub struct UserEmail {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "customType"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub custom_type: String,
     /**
@@ -4639,6 +4888,7 @@ pub struct UserEmail {
 
 /// JSON template for an externalId entry.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UserExternalId {
     /**
      * JSON template for an externalId entry.
@@ -4646,8 +4896,7 @@ pub struct UserExternalId {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "customType"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub custom_type: String,
     /**
@@ -4672,19 +4921,18 @@ pub struct UserExternalId {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UserGender {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "addressMeAs"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub address_me_as: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "customGender"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub custom_gender: String,
     #[serde(
@@ -4698,6 +4946,7 @@ pub struct UserGender {
 
 /// JSON template for instant messenger of an user.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UserIm {
     /**
      * JSON template for instant messenger of an user.
@@ -4705,8 +4954,7 @@ pub struct UserIm {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "customProtocol"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub custom_protocol: String,
     /**
@@ -4715,8 +4963,7 @@ pub struct UserIm {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "customType"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub custom_type: String,
     /**
@@ -4759,6 +5006,7 @@ pub struct UserIm {
 
 /// JSON template for a keyword entry.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UserKeyword {
     /**
      * JSON template for a keyword entry.
@@ -4766,8 +5014,7 @@ pub struct UserKeyword {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "customType"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub custom_type: String,
     /**
@@ -4793,6 +5040,7 @@ pub struct UserKeyword {
 
 /// JSON template for a language entry.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UserLanguage {
     /**
      * JSON template for a language entry.
@@ -4800,8 +5048,7 @@ pub struct UserLanguage {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "customLanguage"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub custom_language: String,
     /**
@@ -4810,14 +5057,14 @@ pub struct UserLanguage {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "languageCode"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub language_code: String,
 }
 
 /// JSON template for a location entry.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UserLocation {
     /**
      * JSON template for a location entry.
@@ -4834,8 +5081,7 @@ pub struct UserLocation {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "buildingId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub building_id: String,
     /**
@@ -4844,8 +5090,7 @@ pub struct UserLocation {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "customType"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub custom_type: String,
     /**
@@ -4854,8 +5099,7 @@ pub struct UserLocation {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "deskCode"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub desk_code: String,
     /**
@@ -4864,8 +5108,7 @@ pub struct UserLocation {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "floorName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub floor_name: String,
     /**
@@ -4874,8 +5117,7 @@ pub struct UserLocation {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "floorSection"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub floor_section: String,
     /**
@@ -4903,32 +5145,31 @@ pub struct UserMakeAdmin {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UserName {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "familyName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub family_name: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "fullName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub full_name: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "givenName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub given_name: String,
 }
 
 /// JSON template for an organization entry.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UserOrganization {
     /**
      * JSON template for an organization entry.
@@ -4936,8 +5177,7 @@ pub struct UserOrganization {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "costCenter"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub cost_center: String,
     /**
@@ -4946,8 +5186,7 @@ pub struct UserOrganization {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "customType"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub custom_type: String,
     /**
@@ -4983,8 +5222,7 @@ pub struct UserOrganization {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "fullTimeEquivalent"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub full_time_equivalent: i64,
     /**
@@ -5045,6 +5283,7 @@ pub struct UserOrganization {
 
 /// JSON template for a phone entry.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UserPhone {
     /**
      * JSON template for a phone entry.
@@ -5052,8 +5291,7 @@ pub struct UserPhone {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "customType"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub custom_type: String,
     /**
@@ -5086,6 +5324,7 @@ pub struct UserPhone {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UserPhoto {
     #[serde(
         default,
@@ -5117,20 +5356,18 @@ pub struct UserPhoto {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "mimeType"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub mime_type: String,
     /**
      * The user photo's upload data in [web-safe Base64](https://en.wikipedia.org/wiki/Base64#URL_applications) format in bytes. This means: \* The slash (/) character is replaced with the underscore (_) character. \* The plus sign (+) character is replaced with the hyphen (-) character. \* The equals sign (=) character is replaced with the asterisk (\*). \* For padding, the period (.) character is used instead of the RFC-4648 baseURL definition which uses the equals sign (=) for padding. This is done to simplify URL-parsing. \* Whatever the size of the photo being uploaded, the API downsizes it to 96x96 pixels.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "photoData")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub photo_data: Option<bytes::Bytes>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "primaryEmail"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub primary_email: String,
     /**
@@ -5146,6 +5383,7 @@ pub struct UserPhoto {
 
 /// JSON template for a POSIX account entry.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UserPosixAccount {
     /**
      * JSON template for a POSIX account entry.
@@ -5153,8 +5391,7 @@ pub struct UserPosixAccount {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "accountId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub account_id: String,
     /**
@@ -5177,8 +5414,7 @@ pub struct UserPosixAccount {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "homeDirectory"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub home_directory: String,
     /**
@@ -5187,8 +5423,7 @@ pub struct UserPosixAccount {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "operatingSystemType"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub operating_system_type: String,
     /**
@@ -5214,8 +5449,7 @@ pub struct UserPosixAccount {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "systemId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub system_id: String,
     /**
@@ -5236,6 +5470,7 @@ pub struct UserPosixAccount {
 
 /// JSON template for a relation entry.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UserRelation {
     /**
      * JSON template for a relation entry.
@@ -5243,8 +5478,7 @@ pub struct UserRelation {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "customType"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub custom_type: String,
     /**
@@ -5270,6 +5504,7 @@ pub struct UserRelation {
 
 /// JSON template for a POSIX account entry.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UserSshPublicKey {
     /**
      * JSON template for a POSIX account entry.
@@ -5277,8 +5512,7 @@ pub struct UserSshPublicKey {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "expirationTimeUsec"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub expiration_time_usec: i64,
     /**
@@ -5302,18 +5536,19 @@ pub struct UserSshPublicKey {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UserUndelete {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "orgUnitPath"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub org_unit_path: String,
 }
 
 /// JSON template for a website entry.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UserWebsite {
     /**
      * JSON template for a website entry.
@@ -5321,8 +5556,7 @@ pub struct UserWebsite {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "customType"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub custom_type: String,
     /**
@@ -5392,8 +5626,34 @@ pub struct Users {
     pub users: Vec<User>,
 }
 
+impl IntoIterator for Users {
+    type Item = User;
+    type IntoIter = std::vec::IntoIter<User>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.users.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Users {
+    type Item = &'a User;
+    type IntoIter = std::slice::Iter<'a, User>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.users.iter()
+    }
+}
+
+impl Users {
+    /// Iterate over the `users`.
+    pub fn iter(&self) -> std::slice::Iter<'_, User> {
+        self.users.iter()
+    }
+}
+
 /// The Directory API allows you to view, generate, and invalidate backup verification codes for a user.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct VerificationCode {
     /**
      * The Directory API allows you to view, generate, and invalidate backup verification codes for a user.
@@ -5419,8 +5679,7 @@ pub struct VerificationCode {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "userId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub user_id: String,
     /**
@@ -5429,8 +5688,7 @@ pub struct VerificationCode {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "verificationCode"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub verification_code: String,
 }
@@ -5470,7 +5728,7 @@ pub struct VerificationCodes {
 /**
  * V1 error format.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Xgafv {
     #[serde(rename = "1")]
     One,
@@ -5499,7 +5757,23 @@ impl Default for Xgafv {
         Xgafv::Noop
     }
 }
+
 impl Xgafv {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Xgafv::One => "1",
+            Xgafv::Two => "2",
+            Xgafv::Noop => "",
+            Xgafv::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Xgafv] {
+        &[Xgafv::One, Xgafv::Two]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Xgafv::Noop)
     }
@@ -5508,7 +5782,7 @@ impl Xgafv {
 /**
  * Data format for response.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Alt {
     #[serde(rename = "json")]
     Json,
@@ -5540,7 +5814,24 @@ impl Default for Alt {
         Alt::Noop
     }
 }
+
 impl Alt {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Alt::Json => "json",
+            Alt::Media => "media",
+            Alt::Proto => "proto",
+            Alt::Noop => "",
+            Alt::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Alt] {
+        &[Alt::Json, Alt::Media, Alt::Proto]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Alt::Noop)
     }
@@ -5549,7 +5840,7 @@ impl Alt {
 /**
  * Device property to use for sorting results.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum OrderBy {
     #[serde(rename = "annotatedLocation")]
     AnnotatedLocation,
@@ -5593,7 +5884,36 @@ impl Default for OrderBy {
         OrderBy::Noop
     }
 }
+
 impl OrderBy {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrderBy::AnnotatedLocation => "annotatedLocation",
+            OrderBy::AnnotatedUser => "annotatedUser",
+            OrderBy::LastSync => "lastSync",
+            OrderBy::Notes => "notes",
+            OrderBy::SerialNumber => "serialNumber",
+            OrderBy::Status => "status",
+            OrderBy::SupportEndDate => "supportEndDate",
+            OrderBy::Noop => "",
+            OrderBy::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [OrderBy] {
+        &[
+            OrderBy::AnnotatedLocation,
+            OrderBy::AnnotatedUser,
+            OrderBy::LastSync,
+            OrderBy::Notes,
+            OrderBy::SerialNumber,
+            OrderBy::Status,
+            OrderBy::SupportEndDate,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, OrderBy::Noop)
     }
@@ -5602,7 +5922,7 @@ impl OrderBy {
 /**
  * Restrict information returned to a set of selected fields.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Projection {
     #[serde(rename = "BASIC")]
     Basic,
@@ -5631,7 +5951,23 @@ impl Default for Projection {
         Projection::Noop
     }
 }
+
 impl Projection {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Projection::Basic => "BASIC",
+            Projection::Full => "FULL",
+            Projection::Noop => "",
+            Projection::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Projection] {
+        &[Projection::Basic, Projection::Full]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Projection::Noop)
     }
@@ -5640,7 +5976,7 @@ impl Projection {
 /**
  * Whether to return results in ascending or descending order. Must be used with the `orderBy` parameter.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SortOrder {
     #[serde(rename = "ASCENDING")]
     Ascending,
@@ -5669,7 +6005,23 @@ impl Default for SortOrder {
         SortOrder::Noop
     }
 }
+
 impl SortOrder {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SortOrder::Ascending => "ASCENDING",
+            SortOrder::Descending => "DESCENDING",
+            SortOrder::Noop => "",
+            SortOrder::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SortOrder] {
+        &[SortOrder::Ascending, SortOrder::Descending]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SortOrder::Noop)
     }
@@ -5678,7 +6030,7 @@ impl SortOrder {
 /**
  * Device property to use for sorting results.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum DirectoryMobiledevicesListOrderBy {
     #[serde(rename = "deviceId")]
     DeviceId,
@@ -5725,8 +6077,39 @@ impl Default for DirectoryMobiledevicesListOrderBy {
         DirectoryMobiledevicesListOrderBy::Noop
     }
 }
+
 impl DirectoryMobiledevicesListOrderBy {
-    pub fn is_noop(&self) -> bool {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DirectoryMobiledevicesListOrderBy::DeviceId => "deviceId",
+            DirectoryMobiledevicesListOrderBy::Email => "email",
+            DirectoryMobiledevicesListOrderBy::LastSync => "lastSync",
+            DirectoryMobiledevicesListOrderBy::Model => "model",
+            DirectoryMobiledevicesListOrderBy::Name => "name",
+            DirectoryMobiledevicesListOrderBy::Os => "os",
+            DirectoryMobiledevicesListOrderBy::Status => "status",
+            DirectoryMobiledevicesListOrderBy::Type => "type",
+            DirectoryMobiledevicesListOrderBy::Noop => "",
+            DirectoryMobiledevicesListOrderBy::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [DirectoryMobiledevicesListOrderBy] {
+        &[
+            DirectoryMobiledevicesListOrderBy::DeviceId,
+            DirectoryMobiledevicesListOrderBy::Email,
+            DirectoryMobiledevicesListOrderBy::LastSync,
+            DirectoryMobiledevicesListOrderBy::Model,
+            DirectoryMobiledevicesListOrderBy::Name,
+            DirectoryMobiledevicesListOrderBy::Os,
+            DirectoryMobiledevicesListOrderBy::Status,
+            DirectoryMobiledevicesListOrderBy::Type,
+        ]
+    }
+
+    pub fn is_noop(&self) -> bool {
         matches!(self, DirectoryMobiledevicesListOrderBy::Noop)
     }
 }
@@ -5734,7 +6117,7 @@ impl DirectoryMobiledevicesListOrderBy {
 /**
  * Whether to return all sub-organizations or just immediate children.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum DirectoryOrgunitsListType {
     #[serde(rename = "all")]
     All,
@@ -5763,7 +6146,26 @@ impl Default for DirectoryOrgunitsListType {
         DirectoryOrgunitsListType::Noop
     }
 }
+
 impl DirectoryOrgunitsListType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DirectoryOrgunitsListType::All => "all",
+            DirectoryOrgunitsListType::Children => "children",
+            DirectoryOrgunitsListType::Noop => "",
+            DirectoryOrgunitsListType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [DirectoryOrgunitsListType] {
+        &[
+            DirectoryOrgunitsListType::All,
+            DirectoryOrgunitsListType::Children,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, DirectoryOrgunitsListType::Noop)
     }
@@ -5772,7 +6174,7 @@ impl DirectoryOrgunitsListType {
 /**
  * Source from which Building.coordinates are derived.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum CoordinatesSource {
     #[serde(rename = "CLIENT_SPECIFIED")]
     ClientSpecified,
@@ -5804,7 +6206,28 @@ impl Default for CoordinatesSource {
         CoordinatesSource::Noop
     }
 }
+
 impl CoordinatesSource {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CoordinatesSource::ClientSpecified => "CLIENT_SPECIFIED",
+            CoordinatesSource::ResolvedFromAddress => "RESOLVED_FROM_ADDRESS",
+            CoordinatesSource::SourceUnspecified => "SOURCE_UNSPECIFIED",
+            CoordinatesSource::Noop => "",
+            CoordinatesSource::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [CoordinatesSource] {
+        &[
+            CoordinatesSource::ClientSpecified,
+            CoordinatesSource::ResolvedFromAddress,
+            CoordinatesSource::SourceUnspecified,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, CoordinatesSource::Noop)
     }
@@ -5813,7 +6236,7 @@ impl CoordinatesSource {
 /**
  * Column to use for sorting results
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum DirectoryGroupsListOrderBy {
     #[serde(rename = "email")]
     Email,
@@ -5839,7 +6262,22 @@ impl Default for DirectoryGroupsListOrderBy {
         DirectoryGroupsListOrderBy::Noop
     }
 }
+
 impl DirectoryGroupsListOrderBy {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DirectoryGroupsListOrderBy::Email => "email",
+            DirectoryGroupsListOrderBy::Noop => "",
+            DirectoryGroupsListOrderBy::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [DirectoryGroupsListOrderBy] {
+        &[DirectoryGroupsListOrderBy::Email]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, DirectoryGroupsListOrderBy::Noop)
     }
@@ -5848,7 +6286,7 @@ impl DirectoryGroupsListOrderBy {
 /**
  * Event on which subscription is intended (if subscribing)
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Event {
     #[serde(rename = "add")]
     Add,
@@ -5886,7 +6324,32 @@ impl Default for Event {
         Event::Noop
     }
 }
+
 impl Event {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Event::Add => "add",
+            Event::Delete => "delete",
+            Event::MakeAdmin => "makeAdmin",
+            Event::Undelete => "undelete",
+            Event::Update => "update",
+            Event::Noop => "",
+            Event::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Event] {
+        &[
+            Event::Add,
+            Event::Delete,
+            Event::MakeAdmin,
+            Event::Undelete,
+            Event::Update,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Event::Noop)
     }
@@ -5895,7 +6358,7 @@ impl Event {
 /**
  * Property to use for sorting results.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum DirectoryUsersListOrderBy {
     #[serde(rename = "email")]
     Email,
@@ -5927,7 +6390,28 @@ impl Default for DirectoryUsersListOrderBy {
         DirectoryUsersListOrderBy::Noop
     }
 }
+
 impl DirectoryUsersListOrderBy {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DirectoryUsersListOrderBy::Email => "email",
+            DirectoryUsersListOrderBy::FamilyName => "familyName",
+            DirectoryUsersListOrderBy::GivenName => "givenName",
+            DirectoryUsersListOrderBy::Noop => "",
+            DirectoryUsersListOrderBy::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [DirectoryUsersListOrderBy] {
+        &[
+            DirectoryUsersListOrderBy::Email,
+            DirectoryUsersListOrderBy::FamilyName,
+            DirectoryUsersListOrderBy::GivenName,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, DirectoryUsersListOrderBy::Noop)
     }
@@ -5936,7 +6420,7 @@ impl DirectoryUsersListOrderBy {
 /**
  * What subset of fields to fetch for this user.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum DirectoryUsersListProjection {
     #[serde(rename = "basic")]
     Basic,
@@ -5968,7 +6452,28 @@ impl Default for DirectoryUsersListProjection {
         DirectoryUsersListProjection::Noop
     }
 }
+
 impl DirectoryUsersListProjection {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DirectoryUsersListProjection::Basic => "basic",
+            DirectoryUsersListProjection::Custom => "custom",
+            DirectoryUsersListProjection::Full => "full",
+            DirectoryUsersListProjection::Noop => "",
+            DirectoryUsersListProjection::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [DirectoryUsersListProjection] {
+        &[
+            DirectoryUsersListProjection::Basic,
+            DirectoryUsersListProjection::Custom,
+            DirectoryUsersListProjection::Full,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, DirectoryUsersListProjection::Noop)
     }
@@ -5977,7 +6482,7 @@ impl DirectoryUsersListProjection {
 /**
  * Whether to fetch the administrator-only or domain-wide public view of the user. For more information, see [Retrieve a user as a non-administrator](/admin-sdk/directory/v1/guides/manage-users#retrieve_users_non_admin).
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ViewType {
     #[serde(rename = "admin_view")]
     AdminView,
@@ -6006,7 +6511,23 @@ impl Default for ViewType {
         ViewType::Noop
     }
 }
+
 impl ViewType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ViewType::AdminView => "admin_view",
+            ViewType::DomainPublic => "domain_public",
+            ViewType::Noop => "",
+            ViewType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ViewType] {
+        &[ViewType::AdminView, ViewType::DomainPublic]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ViewType::Noop)
     }
@@ -6015,7 +6536,7 @@ impl ViewType {
 /**
  * Events to watch for.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum DirectoryUsersAliasesListEvent {
     #[serde(rename = "add")]
     Add,
@@ -6044,8 +6565,457 @@ impl Default for DirectoryUsersAliasesListEvent {
         DirectoryUsersAliasesListEvent::Noop
     }
 }
+
 impl DirectoryUsersAliasesListEvent {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DirectoryUsersAliasesListEvent::Add => "add",
+            DirectoryUsersAliasesListEvent::Delete => "delete",
+            DirectoryUsersAliasesListEvent::Noop => "",
+            DirectoryUsersAliasesListEvent::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [DirectoryUsersAliasesListEvent] {
+        &[
+            DirectoryUsersAliasesListEvent::Add,
+            DirectoryUsersAliasesListEvent::Delete,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, DirectoryUsersAliasesListEvent::Noop)
     }
 }
+
+/// The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UserRequest {
+    /**
+     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub addresses: Vec<UserAddress>,
+    /**
+     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     */
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
+    /**
+     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     */
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub change_password_at_next_login: Option<bool>,
+    /**
+     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     */
+
+    #[serde()]
+    pub custom_schemas:
+        std::collections::HashMap<String, std::collections::HashMap<String, serde_json::Value>>,
+    /**
+     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub emails: Vec<UserEmail>,
+    /**
+     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     */
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_ids: Option<serde_json::Value>,
+    /**
+     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     */
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gender: Option<UserGender>,
+    /**
+     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub hash_function: String,
+    /**
+     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    /**
+     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub ims: Vec<Ims>,
+    /**
+     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     */
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include_in_global_address_list: Option<bool>,
+    /**
+     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     */
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip_whitelisted: Option<bool>,
+    /**
+     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     */
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keywords: Option<serde_json::Value>,
+    /**
+     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     */
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub languages: Option<serde_json::Value>,
+    /**
+     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub locations: Vec<UserLocation>,
+    /**
+     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     */
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<UserName>,
+    /**
+     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     */
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<serde_json::Value>,
+    /**
+     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub org_unit_path: String,
+    /**
+     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     */
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organizations: Option<serde_json::Value>,
+    /**
+     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub password: String,
+    /**
+     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub phones: Vec<UserPhone>,
+    /**
+     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     */
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub posix_accounts: Option<serde_json::Value>,
+    /**
+     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub primary_email: String,
+    /**
+     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub recovery_email: String,
+    /**
+     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub recovery_phone: String,
+    /**
+     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     */
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relations: Option<serde_json::Value>,
+    /**
+     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub ssh_public_keys: Vec<UserSshPublicKey>,
+    /**
+     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     */
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suspended: Option<bool>,
+    /**
+     * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
+     */
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub websites: Option<serde_json::Value>,
+}
+
+/// Printer configuration.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PrinterRequest {
+    /**
+     * Printer configuration.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    /**
+     * Printer configuration.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub display_name: String,
+    /**
+     * Printer configuration.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    /**
+     * Printer configuration.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub make_and_model: String,
+    /**
+     * Printer configuration.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    /**
+     * Printer configuration.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub org_unit_id: String,
+    /**
+     * Printer configuration.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub uri: String,
+    /**
+     * Printer configuration.
+     */
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub use_driverless_config: Option<bool>,
+}
+
+/// The types taken and returned by the functions in [`crate::asps`].
+pub mod asps {
+    pub use super::{Asp, Asps};
+}
+
+/// The types taken and returned by the functions in [`crate::channels`].
+pub mod channels {
+    pub use super::Channel;
+}
+
+/// The types taken and returned by the functions in [`crate::chromeosdevices`].
+pub mod chromeosdevices {
+    pub use super::{
+        ChromeOsDevice, ChromeOsDeviceAction, ChromeOsDevices, ChromeOsMoveDevicesOu, OrderBy,
+        Projection, SortOrder,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::customer`].
+pub mod customer {
+    pub use super::{
+        DirectoryChromeosdevicesCommand, DirectoryChromeosdevicesIssueCommandRequest,
+        DirectoryChromeosdevicesIssueCommandResponse,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::customers`].
+pub mod customers {
+    pub use super::{
+        BatchCreatePrintersRequest, BatchCreatePrintersResponse, BatchDeletePrintersRequest,
+        BatchDeletePrintersResponse, Customer, Empty, ListPrinterModelsResponse,
+        ListPrintersResponse, Printer, PrinterModel, PrinterRequest,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::domain_aliases`].
+pub mod domain_aliases {
+    pub use super::{DomainAlias, DomainAliases};
+}
+
+/// The types taken and returned by the functions in [`crate::domains`].
+pub mod domains {
+    pub use super::{Domains, Domains2};
+}
+
+/// The types taken and returned by the functions in [`crate::groups`].
+pub mod groups {
+    pub use super::{Alias, Aliases, DirectoryGroupsListOrderBy, Group, Groups, SortOrder};
+}
+
+/// The types taken and returned by the functions in [`crate::members`].
+pub mod members {
+    pub use super::{Member, Members, MembersHasMember};
+}
+
+/// The types taken and returned by the functions in [`crate::mobiledevices`].
+pub mod mobiledevices {
+    pub use super::{
+        DirectoryMobiledevicesListOrderBy, MobileDevice, MobileDeviceAction, MobileDevices,
+        Projection, SortOrder,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::orgunits`].
+pub mod orgunits {
+    pub use super::{DirectoryOrgunitsListType, OrgUnit, OrgUnits};
+}
+
+/// The types taken and returned by the functions in [`crate::privileges`].
+pub mod privileges {
+    pub use super::Privileges;
+}
+
+/// The types taken and returned by the functions in [`crate::resources`].
+pub mod resources {
+    pub use super::{
+        Building, Buildings, CalendarResource, CalendarResources, CoordinatesSource, Feature,
+        FeatureRename, Features,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::role_assignments`].
+pub mod role_assignments {
+    pub use super::{RoleAssignment, RoleAssignments};
+}
+
+/// The types taken and returned by the functions in [`crate::roles`].
+pub mod roles {
+    pub use super::{Role, Roles};
+}
+
+/// The types taken and returned by the functions in [`crate::schemas`].
+pub mod schemas {
+    pub use super::{Schema, Schemas};
+}
+
+/// The types taken and returned by the functions in [`crate::tokens`].
+pub mod tokens {
+    pub use super::{Token, Tokens};
+}
+
+/// The types taken and returned by the functions in [`crate::users`].
+pub mod users {
+    pub use super::{
+        Alias, Aliases, Channel, DirectoryUsersAliasesListEvent, DirectoryUsersListOrderBy,
+        DirectoryUsersListProjection, Event, SortOrder, User, UserMakeAdmin, UserPhoto,
+        UserRequest, UserUndelete, Users, ViewType,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::verification_codes`].
+pub mod verification_codes {
+    pub use super::VerificationCodes;
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Users {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Users {
         Users { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Users {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/users` endpoint.
      *
@@ -46,49 +52,114 @@ impl Users {
         sort_order: crate::types::SortOrder,
         view_type: crate::types::ViewType,
     ) -> Result<Vec<crate::types::User>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !customer.is_empty() {
-            query_args.push(("customer".to_string(), customer.to_string()));
+            query_args.push(("customer", customer.to_string()));
         }
         if !domain.is_empty() {
-            query_args.push(("domain".to_string(), domain.to_string()));
+            query_args.push(("domain", domain.to_string()));
         }
         if !event.to_string().is_empty() {
-            query_args.push(("event".to_string(), event.to_string()));
+            query_args.push(("event", event.to_string()));
         }
         if max_results > 0 {
-            query_args.push(("maxResults".to_string(), max_results.to_string()));
+            query_args.push(("maxResults", max_results.to_string()));
         }
         if !order_by.to_string().is_empty() {
-            query_args.push(("orderBy".to_string(), order_by.to_string()));
+            query_args.push(("orderBy", order_by.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if !projection.to_string().is_empty() {
-            query_args.push(("projection".to_string(), projection.to_string()));
+            query_args.push(("projection", projection.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
         if !show_deleted.is_empty() {
-            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+            query_args.push(("showDeleted", show_deleted.to_string()));
         }
         if !sort_order.to_string().is_empty() {
-            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+            query_args.push(("sortOrder", sort_order.to_string()));
         }
         if !view_type.to_string().is_empty() {
-            query_args.push(("viewType".to_string(), view_type.to_string()));
+            query_args.push(("viewType", view_type.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/directory/v1/users?{}", query_);
+        let url = crate::Client::url_with_query("/admin/directory/v1/users", &query_args);
 
-        let resp: crate::types::Users = self.client.get(&url, None).await?;
+        let resp: crate::types::Users =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url("GET", "/admin/directory/v1/users", &[])
+            })?;
 
         // Return our response data.
         Ok(resp.users)
     }
 
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/users` endpoint.
+     *
+     * As opposed to `list`, this function streams the items as they are parsed from the response, rather than returning them all at once.
+     * This is useful for very large responses that would otherwise need to be held in memory.
+     * Only the one page the parameters ask for is streamed, the next pages are not fetched.
+     */
+    pub async fn list_stream_json(
+        &self,
+        customer: &str,
+        domain: &str,
+        event: crate::types::Event,
+        max_results: i64,
+        order_by: crate::types::DirectoryUsersListOrderBy,
+        page_token: &str,
+        projection: crate::types::DirectoryUsersListProjection,
+        query: &str,
+        show_deleted: &str,
+        sort_order: crate::types::SortOrder,
+        view_type: crate::types::ViewType,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::User>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !customer.is_empty() {
+            query_args.push(("customer", customer.to_string()));
+        }
+        if !domain.is_empty() {
+            query_args.push(("domain", domain.to_string()));
+        }
+        if !event.to_string().is_empty() {
+            query_args.push(("event", event.to_string()));
+        }
+        if max_results > 0 {
+            query_args.push(("maxResults", max_results.to_string()));
+        }
+        if !order_by.to_string().is_empty() {
+            query_args.push(("orderBy", order_by.to_string()));
+        }
+        if !page_token.is_empty() {
+            query_args.push(("pageToken", page_token.to_string()));
+        }
+        if !projection.to_string().is_empty() {
+            query_args.push(("projection", projection.to_string()));
+        }
+        if !query.is_empty() {
+            query_args.push(("query", query.to_string()));
+        }
+        if !show_deleted.is_empty() {
+            query_args.push(("showDeleted", show_deleted.to_string()));
+        }
+        if !sort_order.to_string().is_empty() {
+            query_args.push(("sortOrder", sort_order.to_string()));
+        }
+        if !view_type.to_string().is_empty() {
+            query_args.push(("viewType", view_type.to_string()));
+        }
+        let url = crate::Client::url_with_query("/admin/directory/v1/users", &query_args);
+
+        self.client
+            .get_stream_json(&url, "users")
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/admin/directory/v1/users", &[]))
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/users` endpoint.
      *
@@ -108,38 +179,40 @@ impl Users {
         sort_order: crate::types::SortOrder,
         view_type: crate::types::ViewType,
     ) -> Result<Vec<crate::types::User>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !customer.is_empty() {
-            query_args.push(("customer".to_string(), customer.to_string()));
+            query_args.push(("customer", customer.to_string()));
         }
         if !domain.is_empty() {
-            query_args.push(("domain".to_string(), domain.to_string()));
+            query_args.push(("domain", domain.to_string()));
         }
         if !event.to_string().is_empty() {
-            query_args.push(("event".to_string(), event.to_string()));
+            query_args.push(("event", event.to_string()));
         }
         if !order_by.to_string().is_empty() {
-            query_args.push(("orderBy".to_string(), order_by.to_string()));
+            query_args.push(("orderBy", order_by.to_string()));
         }
         if !projection.to_string().is_empty() {
-            query_args.push(("projection".to_string(), projection.to_string()));
+            query_args.push(("projection", projection.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
         if !show_deleted.is_empty() {
-            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+            query_args.push(("showDeleted", show_deleted.to_string()));
         }
         if !sort_order.to_string().is_empty() {
-            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+            query_args.push(("sortOrder", sort_order.to_string()));
         }
         if !view_type.to_string().is_empty() {
-            query_args.push(("viewType".to_string(), view_type.to_string()));
+            query_args.push(("viewType", view_type.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/directory/v1/users?{}", query_);
+        let url = crate::Client::url_with_query("/admin/directory/v1/users", &query_args);
 
-        let mut resp: crate::types::Users = self.client.get(&url, None).await?;
+        let mut resp: crate::types::Users =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url("GET", "/admin/directory/v1/users", &[])
+            })?;
 
         let mut users = resp.users;
         let mut page = resp.next_page_token;
@@ -176,11 +249,12 @@ impl Users {
      *
      * Creates a user.
      */
-    pub async fn insert(&self, body: &crate::types::User) -> Result<crate::types::User> {
+    pub async fn insert(&self, body: &crate::types::UserRequest) -> Result<crate::types::User> {
         let url = "/admin/directory/v1/users".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/admin/directory/v1/users", &[]))
     }
 
     /**
@@ -218,46 +292,48 @@ impl Users {
         view_type: crate::types::ViewType,
         body: &crate::types::Channel,
     ) -> Result<crate::types::Channel> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !customer.is_empty() {
-            query_args.push(("customer".to_string(), customer.to_string()));
+            query_args.push(("customer", customer.to_string()));
         }
         if !domain.is_empty() {
-            query_args.push(("domain".to_string(), domain.to_string()));
+            query_args.push(("domain", domain.to_string()));
         }
         if !event.to_string().is_empty() {
-            query_args.push(("event".to_string(), event.to_string()));
+            query_args.push(("event", event.to_string()));
         }
         if max_results > 0 {
-            query_args.push(("maxResults".to_string(), max_results.to_string()));
+            query_args.push(("maxResults", max_results.to_string()));
         }
         if !order_by.to_string().is_empty() {
-            query_args.push(("orderBy".to_string(), order_by.to_string()));
+            query_args.push(("orderBy", order_by.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if !projection.to_string().is_empty() {
-            query_args.push(("projection".to_string(), projection.to_string()));
+            query_args.push(("projection", projection.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
         if !show_deleted.is_empty() {
-            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+            query_args.push(("showDeleted", show_deleted.to_string()));
         }
         if !sort_order.to_string().is_empty() {
-            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+            query_args.push(("sortOrder", sort_order.to_string()));
         }
         if !view_type.to_string().is_empty() {
-            query_args.push(("viewType".to_string(), view_type.to_string()));
+            query_args.push(("viewType", view_type.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/directory/v1/users/watch?{}", query_);
+        let url = crate::Client::url_with_query("/admin/directory/v1/users/watch", &query_args);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/directory/v1/users/watch", &[])
+            })
     }
 
     /**
@@ -278,21 +354,28 @@ impl Users {
         projection: crate::types::DirectoryUsersListProjection,
         view_type: crate::types::ViewType,
     ) -> Result<crate::types::User> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !projection.to_string().is_empty() {
-            query_args.push(("projection".to_string(), projection.to_string()));
+            query_args.push(("projection", projection.to_string()));
         }
         if !view_type.to_string().is_empty() {
-            query_args.push(("viewType".to_string(), view_type.to_string()));
+            query_args.push(("viewType", view_type.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/users/{}?{}",
-            crate::progenitor_support::encode_path(&user_key.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/directory/v1/users/{}",
+                self.client.encode_path(user_key)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/directory/v1/users/{userKey}",
+                &[("userKey", user_key.to_string())],
+            )
+        })
     }
 
     /**
@@ -307,16 +390,23 @@ impl Users {
     pub async fn update(
         &self,
         user_key: &str,
-        body: &crate::types::User,
+        body: &crate::types::UserRequest,
     ) -> Result<crate::types::User> {
         let url = format!(
             "/admin/directory/v1/users/{}",
-            crate::progenitor_support::encode_path(&user_key.to_string()),
+            self.client.encode_path(user_key),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/directory/v1/users/{userKey}",
+                    &[("userKey", user_key.to_string())],
+                )
+            })
     }
 
     /**
@@ -331,10 +421,39 @@ impl Users {
     pub async fn delete(&self, user_key: &str) -> Result<()> {
         let url = format!(
             "/admin/directory/v1/users/{}",
-            crate::progenitor_support::encode_path(&user_key.to_string()),
+            self.client.encode_path(user_key),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/directory/v1/users/{userKey}",
+                &[("userKey", user_key.to_string())],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/admin/directory/v1/users/{userKey}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, user_key: &str) -> Result<bool> {
+        let url = format!(
+            "/admin/directory/v1/users/{}",
+            self.client.encode_path(user_key),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/directory/v1/users/{userKey}",
+                    &[("userKey", user_key.to_string())],
+                )
+            })
     }
 
     /**
@@ -349,16 +468,51 @@ impl Users {
     pub async fn patch(
         &self,
         user_key: &str,
-        body: &crate::types::User,
+        body: &crate::types::UserRequest,
     ) -> Result<crate::types::User> {
         let url = format!(
             "/admin/directory/v1/users/{}",
-            crate::progenitor_support::encode_path(&user_key.to_string()),
+            self.client.encode_path(user_key),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/admin/directory/v1/users/{userKey}",
+                    &[("userKey", user_key.to_string())],
+                )
+            })
+    }
+
+    /**
+     * This function performs a `PATCH` to the `/admin/directory/v1/users/{userKey}` endpoint.
+     *
+     * As opposed to `patch`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_if_changed<C: serde::Serialize>(
+        &self,
+        user_key: &str,
+        current: &C,
+        body: &crate::types::UserRequest,
+    ) -> Result<Option<crate::types::User>> {
+        let url = format!(
+            "/admin/directory/v1/users/{}",
+            self.client.encode_path(user_key),
+        );
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/admin/directory/v1/users/{userKey}",
+                    &[("userKey", user_key.to_string())],
+                )
+            })
     }
 
     /**
@@ -376,18 +530,25 @@ impl Users {
         user_key: &str,
         event: crate::types::DirectoryUsersAliasesListEvent,
     ) -> Result<crate::types::Aliases> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !event.to_string().is_empty() {
-            query_args.push(("event".to_string(), event.to_string()));
+            query_args.push(("event", event.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/users/{}/aliases?{}",
-            crate::progenitor_support::encode_path(&user_key.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/directory/v1/users/{}/aliases",
+                self.client.encode_path(user_key)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/directory/v1/users/{userKey}/aliases",
+                &[("userKey", user_key.to_string())],
+            )
+        })
     }
 
     /**
@@ -406,12 +567,19 @@ impl Users {
     ) -> Result<crate::types::Alias> {
         let url = format!(
             "/admin/directory/v1/users/{}/aliases",
-            crate::progenitor_support::encode_path(&user_key.to_string()),
+            self.client.encode_path(user_key),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/directory/v1/users/{userKey}/aliases",
+                    &[("userKey", user_key.to_string())],
+                )
+            })
     }
 
     /**
@@ -430,20 +598,28 @@ impl Users {
         event: crate::types::DirectoryUsersAliasesListEvent,
         body: &crate::types::Channel,
     ) -> Result<crate::types::Channel> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !event.to_string().is_empty() {
-            query_args.push(("event".to_string(), event.to_string()));
+            query_args.push(("event", event.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/directory/v1/users/{}/aliases/watch?{}",
-            crate::progenitor_support::encode_path(&user_key.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/directory/v1/users/{}/aliases/watch",
+                self.client.encode_path(user_key)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/directory/v1/users/{userKey}/aliases/watch",
+                    &[("userKey", user_key.to_string())],
+                )
+            })
     }
 
     /**
@@ -459,11 +635,47 @@ impl Users {
     pub async fn aliases_delete(&self, user_key: &str, alias: &str) -> Result<()> {
         let url = format!(
             "/admin/directory/v1/users/{}/aliases/{}",
-            crate::progenitor_support::encode_path(&user_key.to_string()),
-            crate::progenitor_support::encode_path(&alias.to_string()),
+            self.client.encode_path(user_key),
+            self.client.encode_path(alias),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/directory/v1/users/{userKey}/aliases/{alias}",
+                &[
+                    ("userKey", user_key.to_string()),
+                    ("alias", alias.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/admin/directory/v1/users/{userKey}/aliases/{alias}` endpoint.
+     *
+     * As opposed to `aliases_delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn aliases_delete_if_exists(&self, user_key: &str, alias: &str) -> Result<bool> {
+        let url = format!(
+            "/admin/directory/v1/users/{}/aliases/{}",
+            self.client.encode_path(user_key),
+            self.client.encode_path(alias),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/directory/v1/users/{userKey}/aliases/{alias}",
+                    &[
+                        ("userKey", user_key.to_string()),
+                        ("alias", alias.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -482,12 +694,19 @@ impl Users {
     ) -> Result<()> {
         let url = format!(
             "/admin/directory/v1/users/{}/makeAdmin",
-            crate::progenitor_support::encode_path(&user_key.to_string()),
+            self.client.encode_path(user_key),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/directory/v1/users/{userKey}/makeAdmin",
+                    &[("userKey", user_key.to_string())],
+                )
+            })
     }
 
     /**
@@ -502,10 +721,16 @@ impl Users {
     pub async fn photos_get(&self, user_key: &str) -> Result<crate::types::UserPhoto> {
         let url = format!(
             "/admin/directory/v1/users/{}/photos/thumbnail",
-            crate::progenitor_support::encode_path(&user_key.to_string()),
+            self.client.encode_path(user_key),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/directory/v1/users/{userKey}/photos/thumbnail",
+                &[("userKey", user_key.to_string())],
+            )
+        })
     }
 
     /**
@@ -524,12 +749,19 @@ impl Users {
     ) -> Result<crate::types::UserPhoto> {
         let url = format!(
             "/admin/directory/v1/users/{}/photos/thumbnail",
-            crate::progenitor_support::encode_path(&user_key.to_string()),
+            self.client.encode_path(user_key),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/directory/v1/users/{userKey}/photos/thumbnail",
+                    &[("userKey", user_key.to_string())],
+                )
+            })
     }
 
     /**
@@ -544,10 +776,39 @@ impl Users {
     pub async fn photos_delete(&self, user_key: &str) -> Result<()> {
         let url = format!(
             "/admin/directory/v1/users/{}/photos/thumbnail",
-            crate::progenitor_support::encode_path(&user_key.to_string()),
+            self.client.encode_path(user_key),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/directory/v1/users/{userKey}/photos/thumbnail",
+                &[("userKey", user_key.to_string())],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/admin/directory/v1/users/{userKey}/photos/thumbnail` endpoint.
+     *
+     * As opposed to `photos_delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn photos_delete_if_exists(&self, user_key: &str) -> Result<bool> {
+        let url = format!(
+            "/admin/directory/v1/users/{}/photos/thumbnail",
+            self.client.encode_path(user_key),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/directory/v1/users/{userKey}/photos/thumbnail",
+                    &[("userKey", user_key.to_string())],
+                )
+            })
     }
 
     /**
@@ -566,12 +827,47 @@ impl Users {
     ) -> Result<crate::types::UserPhoto> {
         let url = format!(
             "/admin/directory/v1/users/{}/photos/thumbnail",
-            crate::progenitor_support::encode_path(&user_key.to_string()),
+            self.client.encode_path(user_key),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/admin/directory/v1/users/{userKey}/photos/thumbnail",
+                    &[("userKey", user_key.to_string())],
+                )
+            })
+    }
+
+    /**
+     * This function performs a `PATCH` to the `/admin/directory/v1/users/{userKey}/photos/thumbnail` endpoint.
+     *
+     * As opposed to `photos_patch`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn photos_patch_if_changed<C: serde::Serialize>(
+        &self,
+        user_key: &str,
+        current: &C,
+        body: &crate::types::UserPhoto,
+    ) -> Result<Option<crate::types::UserPhoto>> {
+        let url = format!(
+            "/admin/directory/v1/users/{}/photos/thumbnail",
+            self.client.encode_path(user_key),
+        );
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/admin/directory/v1/users/{userKey}/photos/thumbnail",
+                    &[("userKey", user_key.to_string())],
+                )
+            })
     }
 
     /**
@@ -586,10 +882,16 @@ impl Users {
     pub async fn sign_out(&self, user_key: &str) -> Result<()> {
         let url = format!(
             "/admin/directory/v1/users/{}/signOut",
-            crate::progenitor_support::encode_path(&user_key.to_string()),
+            self.client.encode_path(user_key),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/admin/directory/v1/users/{userKey}/signOut",
+                &[("userKey", user_key.to_string())],
+            )
+        })
     }
 
     /**
@@ -604,11 +906,304 @@ impl Users {
     pub async fn undelete(&self, user_key: &str, body: &crate::types::UserUndelete) -> Result<()> {
         let url = format!(
             "/admin/directory/v1/users/{}/undelete",
-            crate::progenitor_support::encode_path(&user_key.to_string()),
+            self.client.encode_path(user_key),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/directory/v1/users/{userKey}/undelete",
+                    &[("userKey", user_key.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`Users`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn UsersApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait UsersApi: Send + Sync {
+    async fn list(
+        &self,
+        customer: &str,
+        domain: &str,
+        event: crate::types::Event,
+        max_results: i64,
+        order_by: crate::types::DirectoryUsersListOrderBy,
+        page_token: &str,
+        projection: crate::types::DirectoryUsersListProjection,
+        query: &str,
+        show_deleted: &str,
+        sort_order: crate::types::SortOrder,
+        view_type: crate::types::ViewType,
+    ) -> Result<Vec<crate::types::User>>;
+    async fn list_all(
+        &self,
+        customer: &str,
+        domain: &str,
+        event: crate::types::Event,
+        order_by: crate::types::DirectoryUsersListOrderBy,
+        projection: crate::types::DirectoryUsersListProjection,
+        query: &str,
+        show_deleted: &str,
+        sort_order: crate::types::SortOrder,
+        view_type: crate::types::ViewType,
+    ) -> Result<Vec<crate::types::User>>;
+    async fn insert(&self, body: &crate::types::UserRequest) -> Result<crate::types::User>;
+    async fn watch(
+        &self,
+        customer: &str,
+        domain: &str,
+        event: crate::types::Event,
+        max_results: i64,
+        order_by: crate::types::DirectoryUsersListOrderBy,
+        page_token: &str,
+        projection: crate::types::DirectoryUsersListProjection,
+        query: &str,
+        show_deleted: &str,
+        sort_order: crate::types::SortOrder,
+        view_type: crate::types::ViewType,
+        body: &crate::types::Channel,
+    ) -> Result<crate::types::Channel>;
+    async fn get(
+        &self,
+        user_key: &str,
+        projection: crate::types::DirectoryUsersListProjection,
+        view_type: crate::types::ViewType,
+    ) -> Result<crate::types::User>;
+    async fn update(
+        &self,
+        user_key: &str,
+        body: &crate::types::UserRequest,
+    ) -> Result<crate::types::User>;
+    async fn delete(&self, user_key: &str) -> Result<()>;
+    async fn delete_if_exists(&self, user_key: &str) -> Result<bool>;
+    async fn patch(
+        &self,
+        user_key: &str,
+        body: &crate::types::UserRequest,
+    ) -> Result<crate::types::User>;
+    async fn aliases_list(
+        &self,
+        user_key: &str,
+        event: crate::types::DirectoryUsersAliasesListEvent,
+    ) -> Result<crate::types::Aliases>;
+    async fn aliases_insert(
+        &self,
+        user_key: &str,
+        body: &crate::types::Alias,
+    ) -> Result<crate::types::Alias>;
+    async fn aliases_watch(
+        &self,
+        user_key: &str,
+        event: crate::types::DirectoryUsersAliasesListEvent,
+        body: &crate::types::Channel,
+    ) -> Result<crate::types::Channel>;
+    async fn aliases_delete(&self, user_key: &str, alias: &str) -> Result<()>;
+    async fn aliases_delete_if_exists(&self, user_key: &str, alias: &str) -> Result<bool>;
+    async fn make_admin(&self, user_key: &str, body: &crate::types::UserMakeAdmin) -> Result<()>;
+    async fn photos_get(&self, user_key: &str) -> Result<crate::types::UserPhoto>;
+    async fn photos_update(
+        &self,
+        user_key: &str,
+        body: &crate::types::UserPhoto,
+    ) -> Result<crate::types::UserPhoto>;
+    async fn photos_delete(&self, user_key: &str) -> Result<()>;
+    async fn photos_delete_if_exists(&self, user_key: &str) -> Result<bool>;
+    async fn photos_patch(
+        &self,
+        user_key: &str,
+        body: &crate::types::UserPhoto,
+    ) -> Result<crate::types::UserPhoto>;
+    async fn sign_out(&self, user_key: &str) -> Result<()>;
+    async fn undelete(&self, user_key: &str, body: &crate::types::UserUndelete) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl UsersApi for Users {
+    async fn list(
+        &self,
+        customer: &str,
+        domain: &str,
+        event: crate::types::Event,
+        max_results: i64,
+        order_by: crate::types::DirectoryUsersListOrderBy,
+        page_token: &str,
+        projection: crate::types::DirectoryUsersListProjection,
+        query: &str,
+        show_deleted: &str,
+        sort_order: crate::types::SortOrder,
+        view_type: crate::types::ViewType,
+    ) -> Result<Vec<crate::types::User>> {
+        Users::list(
+            self,
+            customer,
+            domain,
+            event,
+            max_results,
+            order_by,
+            page_token,
+            projection,
+            query,
+            show_deleted,
+            sort_order,
+            view_type,
+        )
+        .await
+    }
+    async fn list_all(
+        &self,
+        customer: &str,
+        domain: &str,
+        event: crate::types::Event,
+        order_by: crate::types::DirectoryUsersListOrderBy,
+        projection: crate::types::DirectoryUsersListProjection,
+        query: &str,
+        show_deleted: &str,
+        sort_order: crate::types::SortOrder,
+        view_type: crate::types::ViewType,
+    ) -> Result<Vec<crate::types::User>> {
+        Users::list_all(
+            self,
+            customer,
+            domain,
+            event,
+            order_by,
+            projection,
+            query,
+            show_deleted,
+            sort_order,
+            view_type,
+        )
+        .await
+    }
+    async fn insert(&self, body: &crate::types::UserRequest) -> Result<crate::types::User> {
+        Users::insert(self, body).await
+    }
+    async fn watch(
+        &self,
+        customer: &str,
+        domain: &str,
+        event: crate::types::Event,
+        max_results: i64,
+        order_by: crate::types::DirectoryUsersListOrderBy,
+        page_token: &str,
+        projection: crate::types::DirectoryUsersListProjection,
+        query: &str,
+        show_deleted: &str,
+        sort_order: crate::types::SortOrder,
+        view_type: crate::types::ViewType,
+        body: &crate::types::Channel,
+    ) -> Result<crate::types::Channel> {
+        Users::watch(
+            self,
+            customer,
+            domain,
+            event,
+            max_results,
+            order_by,
+            page_token,
+            projection,
+            query,
+            show_deleted,
+            sort_order,
+            view_type,
+            body,
+        )
+        .await
+    }
+    async fn get(
+        &self,
+        user_key: &str,
+        projection: crate::types::DirectoryUsersListProjection,
+        view_type: crate::types::ViewType,
+    ) -> Result<crate::types::User> {
+        Users::get(self, user_key, projection, view_type).await
+    }
+    async fn update(
+        &self,
+        user_key: &str,
+        body: &crate::types::UserRequest,
+    ) -> Result<crate::types::User> {
+        Users::update(self, user_key, body).await
+    }
+    async fn delete(&self, user_key: &str) -> Result<()> {
+        Users::delete(self, user_key).await
+    }
+    async fn delete_if_exists(&self, user_key: &str) -> Result<bool> {
+        Users::delete_if_exists(self, user_key).await
+    }
+    async fn patch(
+        &self,
+        user_key: &str,
+        body: &crate::types::UserRequest,
+    ) -> Result<crate::types::User> {
+        Users::patch(self, user_key, body).await
+    }
+    async fn aliases_list(
+        &self,
+        user_key: &str,
+        event: crate::types::DirectoryUsersAliasesListEvent,
+    ) -> Result<crate::types::Aliases> {
+        Users::aliases_list(self, user_key, event).await
+    }
+    async fn aliases_insert(
+        &self,
+        user_key: &str,
+        body: &crate::types::Alias,
+    ) -> Result<crate::types::Alias> {
+        Users::aliases_insert(self, user_key, body).await
+    }
+    async fn aliases_watch(
+        &self,
+        user_key: &str,
+        event: crate::types::DirectoryUsersAliasesListEvent,
+        body: &crate::types::Channel,
+    ) -> Result<crate::types::Channel> {
+        Users::aliases_watch(self, user_key, event, body).await
+    }
+    async fn aliases_delete(&self, user_key: &str, alias: &str) -> Result<()> {
+        Users::aliases_delete(self, user_key, alias).await
+    }
+    async fn aliases_delete_if_exists(&self, user_key: &str, alias: &str) -> Result<bool> {
+        Users::aliases_delete_if_exists(self, user_key, alias).await
+    }
+    async fn make_admin(&self, user_key: &str, body: &crate::types::UserMakeAdmin) -> Result<()> {
+        Users::make_admin(self, user_key, body).await
+    }
+    async fn photos_get(&self, user_key: &str) -> Result<crate::types::UserPhoto> {
+        Users::photos_get(self, user_key).await
+    }
+    async fn photos_update(
+        &self,
+        user_key: &str,
+        body: &crate::types::UserPhoto,
+    ) -> Result<crate::types::UserPhoto> {
+        Users::photos_update(self, user_key, body).await
+    }
+    async fn photos_delete(&self, user_key: &str) -> Result<()> {
+        Users::photos_delete(self, user_key).await
+    }
+    async fn photos_delete_if_exists(&self, user_key: &str) -> Result<bool> {
+        Users::photos_delete_if_exists(self, user_key).await
+    }
+    async fn photos_patch(
+        &self,
+        user_key: &str,
+        body: &crate::types::UserPhoto,
+    ) -> Result<crate::types::UserPhoto> {
+        Users::photos_patch(self, user_key, body).await
+    }
+    async fn sign_out(&self, user_key: &str) -> Result<()> {
+        Users::sign_out(self, user_key).await
+    }
+    async fn undelete(&self, user_key: &str, body: &crate::types::UserUndelete) -> Result<()> {
+        Users::undelete(self, user_key, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct VerificationCodes {
     pub client: Client,
@@ -12,6 +10,14 @@ impl VerificationCodes {
         VerificationCodes { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        VerificationCodes {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/users/{userKey}/verificationCodes` endpoint.
      *
@@ -24,10 +30,16 @@ impl VerificationCodes {
     pub async fn list(&self, user_key: &str) -> Result<crate::types::VerificationCodes> {
         let url = format!(
             "/admin/directory/v1/users/{}/verificationCodes",
-            crate::progenitor_support::encode_path(&user_key.to_string()),
+            self.client.encode_path(user_key),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/directory/v1/users/{userKey}/verificationCodes",
+                &[("userKey", user_key.to_string())],
+            )
+        })
     }
 
     /**
@@ -42,10 +54,16 @@ impl VerificationCodes {
     pub async fn generate(&self, user_key: &str) -> Result<()> {
         let url = format!(
             "/admin/directory/v1/users/{}/verificationCodes/generate",
-            crate::progenitor_support::encode_path(&user_key.to_string()),
+            self.client.encode_path(user_key),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/admin/directory/v1/users/{userKey}/verificationCodes/generate",
+                &[("userKey", user_key.to_string())],
+            )
+        })
     }
 
     /**
@@ -60,9 +78,40 @@ impl VerificationCodes {
     pub async fn invalidate(&self, user_key: &str) -> Result<()> {
         let url = format!(
             "/admin/directory/v1/users/{}/verificationCodes/invalidate",
-            crate::progenitor_support::encode_path(&user_key.to_string()),
+            self.client.encode_path(user_key),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/admin/directory/v1/users/{userKey}/verificationCodes/invalidate",
+                &[("userKey", user_key.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`VerificationCodes`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn VerificationCodesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait VerificationCodesApi: Send + Sync {
+    async fn list(&self, user_key: &str) -> Result<crate::types::VerificationCodes>;
+    async fn generate(&self, user_key: &str) -> Result<()>;
+    async fn invalidate(&self, user_key: &str) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl VerificationCodesApi for VerificationCodes {
+    async fn list(&self, user_key: &str) -> Result<crate::types::VerificationCodes> {
+        VerificationCodes::list(self, user_key).await
+    }
+    async fn generate(&self, user_key: &str) -> Result<()> {
+        VerificationCodes::generate(self, user_key).await
+    }
+    async fn invalidate(&self, user_key: &str) -> Result<()> {
+        VerificationCodes::invalidate(self, user_key).await
     }
 }
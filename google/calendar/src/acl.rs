@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Acl {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Acl {
         Acl { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Acl {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/calendars/{calendarId}/acl` endpoint.
      *
@@ -35,24 +41,28 @@ impl Acl {
         page_token: &str,
         show_deleted: bool,
     ) -> Result<Vec<crate::types::AclRule>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if max_results > 0 {
-            query_args.push(("maxResults".to_string(), max_results.to_string()));
+            query_args.push(("maxResults", max_results.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if show_deleted {
-            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+            query_args.push(("showDeleted", show_deleted.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/calendars/{}/acl?{}",
-            crate::progenitor_support::encode_path(&calendar_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/calendars/{}/acl", self.client.encode_path(calendar_id)),
+            &query_args,
         );
 
-        let resp: crate::types::Acl = self.client.get(&url, None).await?;
+        let resp: crate::types::Acl = self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/calendars/{calendarId}/acl",
+                &[("calendarId", calendar_id.to_string())],
+            )
+        })?;
 
         // Return our response data.
         Ok(resp.items)
@@ -70,18 +80,22 @@ impl Acl {
         calendar_id: &str,
         show_deleted: bool,
     ) -> Result<Vec<crate::types::AclRule>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if show_deleted {
-            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+            query_args.push(("showDeleted", show_deleted.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/calendars/{}/acl?{}",
-            crate::progenitor_support::encode_path(&calendar_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/calendars/{}/acl", self.client.encode_path(calendar_id)),
+            &query_args,
         );
 
-        let mut resp: crate::types::Acl = self.client.get(&url, None).await?;
+        let mut resp: crate::types::Acl = self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/calendars/{calendarId}/acl",
+                &[("calendarId", calendar_id.to_string())],
+            )
+        })?;
 
         let mut items = resp.items;
         let mut page = resp.next_page_token;
@@ -129,23 +143,25 @@ impl Acl {
         send_notifications: bool,
         body: &crate::types::AclRule,
     ) -> Result<crate::types::AclRule> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if send_notifications {
-            query_args.push((
-                "sendNotifications".to_string(),
-                send_notifications.to_string(),
-            ));
+            query_args.push(("sendNotifications", send_notifications.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/calendars/{}/acl?{}",
-            crate::progenitor_support::encode_path(&calendar_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/calendars/{}/acl", self.client.encode_path(calendar_id)),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/calendars/{calendarId}/acl",
+                    &[("calendarId", calendar_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -172,26 +188,34 @@ impl Acl {
         show_deleted: bool,
         body: &crate::types::Channel,
     ) -> Result<crate::types::Channel> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if max_results > 0 {
-            query_args.push(("maxResults".to_string(), max_results.to_string()));
+            query_args.push(("maxResults", max_results.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if show_deleted {
-            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+            query_args.push(("showDeleted", show_deleted.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/calendars/{}/acl/watch?{}",
-            crate::progenitor_support::encode_path(&calendar_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/calendars/{}/acl/watch",
+                self.client.encode_path(calendar_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/calendars/{calendarId}/acl/watch",
+                    &[("calendarId", calendar_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -207,11 +231,20 @@ impl Acl {
     pub async fn get(&self, calendar_id: &str, rule_id: &str) -> Result<crate::types::AclRule> {
         let url = format!(
             "/calendars/{}/acl/{}",
-            crate::progenitor_support::encode_path(&calendar_id.to_string()),
-            crate::progenitor_support::encode_path(&rule_id.to_string()),
+            self.client.encode_path(calendar_id),
+            self.client.encode_path(rule_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/calendars/{calendarId}/acl/{ruleId}",
+                &[
+                    ("calendarId", calendar_id.to_string()),
+                    ("ruleId", rule_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -232,24 +265,32 @@ impl Acl {
         send_notifications: bool,
         body: &crate::types::AclRule,
     ) -> Result<crate::types::AclRule> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if send_notifications {
-            query_args.push((
-                "sendNotifications".to_string(),
-                send_notifications.to_string(),
-            ));
+            query_args.push(("sendNotifications", send_notifications.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/calendars/{}/acl/{}?{}",
-            crate::progenitor_support::encode_path(&calendar_id.to_string()),
-            crate::progenitor_support::encode_path(&rule_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/calendars/{}/acl/{}",
+                self.client.encode_path(calendar_id),
+                self.client.encode_path(rule_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/calendars/{calendarId}/acl/{ruleId}",
+                    &[
+                        ("calendarId", calendar_id.to_string()),
+                        ("ruleId", rule_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -265,11 +306,47 @@ impl Acl {
     pub async fn delete(&self, calendar_id: &str, rule_id: &str) -> Result<()> {
         let url = format!(
             "/calendars/{}/acl/{}",
-            crate::progenitor_support::encode_path(&calendar_id.to_string()),
-            crate::progenitor_support::encode_path(&rule_id.to_string()),
+            self.client.encode_path(calendar_id),
+            self.client.encode_path(rule_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/calendars/{calendarId}/acl/{ruleId}",
+                &[
+                    ("calendarId", calendar_id.to_string()),
+                    ("ruleId", rule_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/calendars/{calendarId}/acl/{ruleId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, calendar_id: &str, rule_id: &str) -> Result<bool> {
+        let url = format!(
+            "/calendars/{}/acl/{}",
+            self.client.encode_path(calendar_id),
+            self.client.encode_path(rule_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/calendars/{calendarId}/acl/{ruleId}",
+                    &[
+                        ("calendarId", calendar_id.to_string()),
+                        ("ruleId", rule_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -290,23 +367,197 @@ impl Acl {
         send_notifications: bool,
         body: &crate::types::AclRule,
     ) -> Result<crate::types::AclRule> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if send_notifications {
-            query_args.push((
-                "sendNotifications".to_string(),
-                send_notifications.to_string(),
-            ));
+            query_args.push(("sendNotifications", send_notifications.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/calendars/{}/acl/{}?{}",
-            crate::progenitor_support::encode_path(&calendar_id.to_string()),
-            crate::progenitor_support::encode_path(&rule_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/calendars/{}/acl/{}",
+                self.client.encode_path(calendar_id),
+                self.client.encode_path(rule_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/calendars/{calendarId}/acl/{ruleId}",
+                    &[
+                        ("calendarId", calendar_id.to_string()),
+                        ("ruleId", rule_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * This function performs a `PATCH` to the `/calendars/{calendarId}/acl/{ruleId}` endpoint.
+     *
+     * As opposed to `patch`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_if_changed<C: serde::Serialize>(
+        &self,
+        calendar_id: &str,
+        rule_id: &str,
+        send_notifications: bool,
+        current: &C,
+        body: &crate::types::AclRule,
+    ) -> Result<Option<crate::types::AclRule>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if send_notifications {
+            query_args.push(("sendNotifications", send_notifications.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/calendars/{}/acl/{}",
+                self.client.encode_path(calendar_id),
+                self.client.encode_path(rule_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/calendars/{calendarId}/acl/{ruleId}",
+                    &[
+                        ("calendarId", calendar_id.to_string()),
+                        ("ruleId", rule_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`Acl`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AclApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AclApi: Send + Sync {
+    async fn list(
+        &self,
+        calendar_id: &str,
+        max_results: i64,
+        page_token: &str,
+        show_deleted: bool,
+    ) -> Result<Vec<crate::types::AclRule>>;
+    async fn list_all(
+        &self,
+        calendar_id: &str,
+        show_deleted: bool,
+    ) -> Result<Vec<crate::types::AclRule>>;
+    async fn insert(
+        &self,
+        calendar_id: &str,
+        send_notifications: bool,
+        body: &crate::types::AclRule,
+    ) -> Result<crate::types::AclRule>;
+    async fn watch(
+        &self,
+        calendar_id: &str,
+        max_results: i64,
+        page_token: &str,
+        show_deleted: bool,
+        body: &crate::types::Channel,
+    ) -> Result<crate::types::Channel>;
+    async fn get(&self, calendar_id: &str, rule_id: &str) -> Result<crate::types::AclRule>;
+    async fn update(
+        &self,
+        calendar_id: &str,
+        rule_id: &str,
+        send_notifications: bool,
+        body: &crate::types::AclRule,
+    ) -> Result<crate::types::AclRule>;
+    async fn delete(&self, calendar_id: &str, rule_id: &str) -> Result<()>;
+    async fn delete_if_exists(&self, calendar_id: &str, rule_id: &str) -> Result<bool>;
+    async fn patch(
+        &self,
+        calendar_id: &str,
+        rule_id: &str,
+        send_notifications: bool,
+        body: &crate::types::AclRule,
+    ) -> Result<crate::types::AclRule>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AclApi for Acl {
+    async fn list(
+        &self,
+        calendar_id: &str,
+        max_results: i64,
+        page_token: &str,
+        show_deleted: bool,
+    ) -> Result<Vec<crate::types::AclRule>> {
+        Acl::list(self, calendar_id, max_results, page_token, show_deleted).await
+    }
+    async fn list_all(
+        &self,
+        calendar_id: &str,
+        show_deleted: bool,
+    ) -> Result<Vec<crate::types::AclRule>> {
+        Acl::list_all(self, calendar_id, show_deleted).await
+    }
+    async fn insert(
+        &self,
+        calendar_id: &str,
+        send_notifications: bool,
+        body: &crate::types::AclRule,
+    ) -> Result<crate::types::AclRule> {
+        Acl::insert(self, calendar_id, send_notifications, body).await
+    }
+    async fn watch(
+        &self,
+        calendar_id: &str,
+        max_results: i64,
+        page_token: &str,
+        show_deleted: bool,
+        body: &crate::types::Channel,
+    ) -> Result<crate::types::Channel> {
+        Acl::watch(
+            self,
+            calendar_id,
+            max_results,
+            page_token,
+            show_deleted,
+            body,
+        )
+        .await
+    }
+    async fn get(&self, calendar_id: &str, rule_id: &str) -> Result<crate::types::AclRule> {
+        Acl::get(self, calendar_id, rule_id).await
+    }
+    async fn update(
+        &self,
+        calendar_id: &str,
+        rule_id: &str,
+        send_notifications: bool,
+        body: &crate::types::AclRule,
+    ) -> Result<crate::types::AclRule> {
+        Acl::update(self, calendar_id, rule_id, send_notifications, body).await
+    }
+    async fn delete(&self, calendar_id: &str, rule_id: &str) -> Result<()> {
+        Acl::delete(self, calendar_id, rule_id).await
+    }
+    async fn delete_if_exists(&self, calendar_id: &str, rule_id: &str) -> Result<bool> {
+        Acl::delete_if_exists(self, calendar_id, rule_id).await
+    }
+    async fn patch(
+        &self,
+        calendar_id: &str,
+        rule_id: &str,
+        send_notifications: bool,
+        body: &crate::types::AclRule,
+    ) -> Result<crate::types::AclRule> {
+        Acl::patch(self, calendar_id, rule_id, send_notifications, body).await
     }
 }
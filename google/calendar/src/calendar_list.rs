@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct CalendarList {
     pub client: Client,
@@ -12,6 +10,14 @@ impl CalendarList {
         CalendarList { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        CalendarList {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/users/me/calendarList` endpoint.
      *
@@ -38,26 +44,29 @@ impl CalendarList {
         show_deleted: bool,
         show_hidden: bool,
     ) -> Result<Vec<crate::types::CalendarListEntry>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if max_results > 0 {
-            query_args.push(("maxResults".to_string(), max_results.to_string()));
+            query_args.push(("maxResults", max_results.to_string()));
         }
         if !min_access_role.to_string().is_empty() {
-            query_args.push(("minAccessRole".to_string(), min_access_role.to_string()));
+            query_args.push(("minAccessRole", min_access_role.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if show_deleted {
-            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+            query_args.push(("showDeleted", show_deleted.to_string()));
         }
         if show_hidden {
-            query_args.push(("showHidden".to_string(), show_hidden.to_string()));
+            query_args.push(("showHidden", show_hidden.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/users/me/calendarList?{}", query_);
+        let url = crate::Client::url_with_query("/users/me/calendarList", &query_args);
 
-        let resp: crate::types::CalendarList = self.client.get(&url, None).await?;
+        let resp: crate::types::CalendarList = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/users/me/calendarList", &[]))?;
 
         // Return our response data.
         Ok(resp.items)
@@ -76,20 +85,23 @@ impl CalendarList {
         show_deleted: bool,
         show_hidden: bool,
     ) -> Result<Vec<crate::types::CalendarListEntry>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !min_access_role.to_string().is_empty() {
-            query_args.push(("minAccessRole".to_string(), min_access_role.to_string()));
+            query_args.push(("minAccessRole", min_access_role.to_string()));
         }
         if show_deleted {
-            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+            query_args.push(("showDeleted", show_deleted.to_string()));
         }
         if show_hidden {
-            query_args.push(("showHidden".to_string(), show_hidden.to_string()));
+            query_args.push(("showHidden", show_hidden.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/users/me/calendarList?{}", query_);
+        let url = crate::Client::url_with_query("/users/me/calendarList", &query_args);
 
-        let mut resp: crate::types::CalendarList = self.client.get(&url, None).await?;
+        let mut resp: crate::types::CalendarList = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/users/me/calendarList", &[]))?;
 
         let mut items = resp.items;
         let mut page = resp.next_page_token;
@@ -135,16 +147,16 @@ impl CalendarList {
         color_rgb_format: bool,
         body: &crate::types::CalendarListEntry,
     ) -> Result<crate::types::CalendarListEntry> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if color_rgb_format {
-            query_args.push(("colorRgbFormat".to_string(), color_rgb_format.to_string()));
+            query_args.push(("colorRgbFormat", color_rgb_format.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/users/me/calendarList?{}", query_);
+        let url = crate::Client::url_with_query("/users/me/calendarList", &query_args);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/users/me/calendarList", &[]))
     }
 
     /**
@@ -174,28 +186,28 @@ impl CalendarList {
         show_hidden: bool,
         body: &crate::types::Channel,
     ) -> Result<crate::types::Channel> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if max_results > 0 {
-            query_args.push(("maxResults".to_string(), max_results.to_string()));
+            query_args.push(("maxResults", max_results.to_string()));
         }
         if !min_access_role.to_string().is_empty() {
-            query_args.push(("minAccessRole".to_string(), min_access_role.to_string()));
+            query_args.push(("minAccessRole", min_access_role.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if show_deleted {
-            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+            query_args.push(("showDeleted", show_deleted.to_string()));
         }
         if show_hidden {
-            query_args.push(("showHidden".to_string(), show_hidden.to_string()));
+            query_args.push(("showHidden", show_hidden.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/users/me/calendarList/watch?{}", query_);
+        let url = crate::Client::url_with_query("/users/me/calendarList/watch", &query_args);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/users/me/calendarList/watch", &[]))
     }
 
     /**
@@ -210,10 +222,16 @@ impl CalendarList {
     pub async fn list_get(&self, calendar_id: &str) -> Result<crate::types::CalendarListEntry> {
         let url = format!(
             "/users/me/calendarList/{}",
-            crate::progenitor_support::encode_path(&calendar_id.to_string()),
+            self.client.encode_path(calendar_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/users/me/calendarList/{calendarId}",
+                &[("calendarId", calendar_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -232,20 +250,28 @@ impl CalendarList {
         color_rgb_format: bool,
         body: &crate::types::CalendarListEntry,
     ) -> Result<crate::types::CalendarListEntry> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if color_rgb_format {
-            query_args.push(("colorRgbFormat".to_string(), color_rgb_format.to_string()));
+            query_args.push(("colorRgbFormat", color_rgb_format.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/me/calendarList/{}?{}",
-            crate::progenitor_support::encode_path(&calendar_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/users/me/calendarList/{}",
+                self.client.encode_path(calendar_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/users/me/calendarList/{calendarId}",
+                    &[("calendarId", calendar_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -260,10 +286,39 @@ impl CalendarList {
     pub async fn list_delete(&self, calendar_id: &str) -> Result<()> {
         let url = format!(
             "/users/me/calendarList/{}",
-            crate::progenitor_support::encode_path(&calendar_id.to_string()),
+            self.client.encode_path(calendar_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/users/me/calendarList/{calendarId}",
+                &[("calendarId", calendar_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/users/me/calendarList/{calendarId}` endpoint.
+     *
+     * As opposed to `list_delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn list_delete_if_exists(&self, calendar_id: &str) -> Result<bool> {
+        let url = format!(
+            "/users/me/calendarList/{}",
+            self.client.encode_path(calendar_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/users/me/calendarList/{calendarId}",
+                    &[("calendarId", calendar_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -282,19 +337,197 @@ impl CalendarList {
         color_rgb_format: bool,
         body: &crate::types::CalendarListEntry,
     ) -> Result<crate::types::CalendarListEntry> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if color_rgb_format {
-            query_args.push(("colorRgbFormat".to_string(), color_rgb_format.to_string()));
+            query_args.push(("colorRgbFormat", color_rgb_format.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/me/calendarList/{}?{}",
-            crate::progenitor_support::encode_path(&calendar_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/users/me/calendarList/{}",
+                self.client.encode_path(calendar_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/users/me/calendarList/{calendarId}",
+                    &[("calendarId", calendar_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * This function performs a `PATCH` to the `/users/me/calendarList/{calendarId}` endpoint.
+     *
+     * As opposed to `list_patch`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn list_patch_if_changed<C: serde::Serialize>(
+        &self,
+        calendar_id: &str,
+        color_rgb_format: bool,
+        current: &C,
+        body: &crate::types::CalendarListEntry,
+    ) -> Result<Option<crate::types::CalendarListEntry>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if color_rgb_format {
+            query_args.push(("colorRgbFormat", color_rgb_format.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/users/me/calendarList/{}",
+                self.client.encode_path(calendar_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/users/me/calendarList/{calendarId}",
+                    &[("calendarId", calendar_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`CalendarList`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn CalendarListApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait CalendarListApi: Send + Sync {
+    async fn list(
+        &self,
+        max_results: i64,
+        min_access_role: crate::types::MinAccessRole,
+        page_token: &str,
+        show_deleted: bool,
+        show_hidden: bool,
+    ) -> Result<Vec<crate::types::CalendarListEntry>>;
+    async fn list_all(
+        &self,
+        min_access_role: crate::types::MinAccessRole,
+        show_deleted: bool,
+        show_hidden: bool,
+    ) -> Result<Vec<crate::types::CalendarListEntry>>;
+    async fn list_insert(
+        &self,
+        color_rgb_format: bool,
+        body: &crate::types::CalendarListEntry,
+    ) -> Result<crate::types::CalendarListEntry>;
+    async fn list_watch(
+        &self,
+        max_results: i64,
+        min_access_role: crate::types::MinAccessRole,
+        page_token: &str,
+        show_deleted: bool,
+        show_hidden: bool,
+        body: &crate::types::Channel,
+    ) -> Result<crate::types::Channel>;
+    async fn list_get(&self, calendar_id: &str) -> Result<crate::types::CalendarListEntry>;
+    async fn list_update(
+        &self,
+        calendar_id: &str,
+        color_rgb_format: bool,
+        body: &crate::types::CalendarListEntry,
+    ) -> Result<crate::types::CalendarListEntry>;
+    async fn list_delete(&self, calendar_id: &str) -> Result<()>;
+    async fn list_delete_if_exists(&self, calendar_id: &str) -> Result<bool>;
+    async fn list_patch(
+        &self,
+        calendar_id: &str,
+        color_rgb_format: bool,
+        body: &crate::types::CalendarListEntry,
+    ) -> Result<crate::types::CalendarListEntry>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl CalendarListApi for CalendarList {
+    async fn list(
+        &self,
+        max_results: i64,
+        min_access_role: crate::types::MinAccessRole,
+        page_token: &str,
+        show_deleted: bool,
+        show_hidden: bool,
+    ) -> Result<Vec<crate::types::CalendarListEntry>> {
+        CalendarList::list(
+            self,
+            max_results,
+            min_access_role,
+            page_token,
+            show_deleted,
+            show_hidden,
+        )
+        .await
+    }
+    async fn list_all(
+        &self,
+        min_access_role: crate::types::MinAccessRole,
+        show_deleted: bool,
+        show_hidden: bool,
+    ) -> Result<Vec<crate::types::CalendarListEntry>> {
+        CalendarList::list_all(self, min_access_role, show_deleted, show_hidden).await
+    }
+    async fn list_insert(
+        &self,
+        color_rgb_format: bool,
+        body: &crate::types::CalendarListEntry,
+    ) -> Result<crate::types::CalendarListEntry> {
+        CalendarList::list_insert(self, color_rgb_format, body).await
+    }
+    async fn list_watch(
+        &self,
+        max_results: i64,
+        min_access_role: crate::types::MinAccessRole,
+        page_token: &str,
+        show_deleted: bool,
+        show_hidden: bool,
+        body: &crate::types::Channel,
+    ) -> Result<crate::types::Channel> {
+        CalendarList::list_watch(
+            self,
+            max_results,
+            min_access_role,
+            page_token,
+            show_deleted,
+            show_hidden,
+            body,
+        )
+        .await
+    }
+    async fn list_get(&self, calendar_id: &str) -> Result<crate::types::CalendarListEntry> {
+        CalendarList::list_get(self, calendar_id).await
+    }
+    async fn list_update(
+        &self,
+        calendar_id: &str,
+        color_rgb_format: bool,
+        body: &crate::types::CalendarListEntry,
+    ) -> Result<crate::types::CalendarListEntry> {
+        CalendarList::list_update(self, calendar_id, color_rgb_format, body).await
+    }
+    async fn list_delete(&self, calendar_id: &str) -> Result<()> {
+        CalendarList::list_delete(self, calendar_id).await
+    }
+    async fn list_delete_if_exists(&self, calendar_id: &str) -> Result<bool> {
+        CalendarList::list_delete_if_exists(self, calendar_id).await
+    }
+    async fn list_patch(
+        &self,
+        calendar_id: &str,
+        color_rgb_format: bool,
+        body: &crate::types::CalendarListEntry,
+    ) -> Result<crate::types::CalendarListEntry> {
+        CalendarList::list_patch(self, calendar_id, color_rgb_format, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Calendars {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Calendars {
         Calendars { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Calendars {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `POST` to the `/calendars` endpoint.
      *
@@ -20,8 +26,9 @@ impl Calendars {
     pub async fn insert(&self, body: &crate::types::Calendar) -> Result<crate::types::Calendar> {
         let url = "/calendars".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/calendars", &[]))
     }
 
     /**
@@ -34,12 +41,15 @@ impl Calendars {
      * * `calendar_id: &str` -- Calendar identifier. To retrieve calendar IDs call the calendarList.list method. If you want to access the primary calendar of the currently logged in user, use the "primary" keyword.
      */
     pub async fn get(&self, calendar_id: &str) -> Result<crate::types::Calendar> {
-        let url = format!(
-            "/calendars/{}",
-            crate::progenitor_support::encode_path(&calendar_id.to_string()),
-        );
+        let url = format!("/calendars/{}", self.client.encode_path(calendar_id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/calendars/{calendarId}",
+                &[("calendarId", calendar_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -56,14 +66,18 @@ impl Calendars {
         calendar_id: &str,
         body: &crate::types::Calendar,
     ) -> Result<crate::types::Calendar> {
-        let url = format!(
-            "/calendars/{}",
-            crate::progenitor_support::encode_path(&calendar_id.to_string()),
-        );
+        let url = format!("/calendars/{}", self.client.encode_path(calendar_id),);
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/calendars/{calendarId}",
+                    &[("calendarId", calendar_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -76,12 +90,35 @@ impl Calendars {
      * * `calendar_id: &str` -- Calendar identifier. To retrieve calendar IDs call the calendarList.list method. If you want to access the primary calendar of the currently logged in user, use the "primary" keyword.
      */
     pub async fn delete(&self, calendar_id: &str) -> Result<()> {
-        let url = format!(
-            "/calendars/{}",
-            crate::progenitor_support::encode_path(&calendar_id.to_string()),
-        );
+        let url = format!("/calendars/{}", self.client.encode_path(calendar_id),);
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/calendars/{calendarId}",
+                &[("calendarId", calendar_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/calendars/{calendarId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, calendar_id: &str) -> Result<bool> {
+        let url = format!("/calendars/{}", self.client.encode_path(calendar_id),);
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/calendars/{calendarId}",
+                    &[("calendarId", calendar_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -98,14 +135,43 @@ impl Calendars {
         calendar_id: &str,
         body: &crate::types::Calendar,
     ) -> Result<crate::types::Calendar> {
-        let url = format!(
-            "/calendars/{}",
-            crate::progenitor_support::encode_path(&calendar_id.to_string()),
-        );
+        let url = format!("/calendars/{}", self.client.encode_path(calendar_id),);
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/calendars/{calendarId}",
+                    &[("calendarId", calendar_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * This function performs a `PATCH` to the `/calendars/{calendarId}` endpoint.
+     *
+     * As opposed to `patch`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_if_changed<C: serde::Serialize>(
+        &self,
+        calendar_id: &str,
+        current: &C,
+        body: &crate::types::Calendar,
+    ) -> Result<Option<crate::types::Calendar>> {
+        let url = format!("/calendars/{}", self.client.encode_path(calendar_id),);
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/calendars/{calendarId}",
+                    &[("calendarId", calendar_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -118,11 +184,71 @@ impl Calendars {
      * * `calendar_id: &str` -- Calendar identifier. To retrieve calendar IDs call the calendarList.list method. If you want to access the primary calendar of the currently logged in user, use the "primary" keyword.
      */
     pub async fn clear(&self, calendar_id: &str) -> Result<()> {
-        let url = format!(
-            "/calendars/{}/clear",
-            crate::progenitor_support::encode_path(&calendar_id.to_string()),
-        );
+        let url = format!("/calendars/{}/clear", self.client.encode_path(calendar_id),);
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/calendars/{calendarId}/clear",
+                &[("calendarId", calendar_id.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`Calendars`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn CalendarsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait CalendarsApi: Send + Sync {
+    async fn insert(&self, body: &crate::types::Calendar) -> Result<crate::types::Calendar>;
+    async fn get(&self, calendar_id: &str) -> Result<crate::types::Calendar>;
+    async fn update(
+        &self,
+        calendar_id: &str,
+        body: &crate::types::Calendar,
+    ) -> Result<crate::types::Calendar>;
+    async fn delete(&self, calendar_id: &str) -> Result<()>;
+    async fn delete_if_exists(&self, calendar_id: &str) -> Result<bool>;
+    async fn patch(
+        &self,
+        calendar_id: &str,
+        body: &crate::types::Calendar,
+    ) -> Result<crate::types::Calendar>;
+    async fn clear(&self, calendar_id: &str) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl CalendarsApi for Calendars {
+    async fn insert(&self, body: &crate::types::Calendar) -> Result<crate::types::Calendar> {
+        Calendars::insert(self, body).await
+    }
+    async fn get(&self, calendar_id: &str) -> Result<crate::types::Calendar> {
+        Calendars::get(self, calendar_id).await
+    }
+    async fn update(
+        &self,
+        calendar_id: &str,
+        body: &crate::types::Calendar,
+    ) -> Result<crate::types::Calendar> {
+        Calendars::update(self, calendar_id, body).await
+    }
+    async fn delete(&self, calendar_id: &str) -> Result<()> {
+        Calendars::delete(self, calendar_id).await
+    }
+    async fn delete_if_exists(&self, calendar_id: &str) -> Result<bool> {
+        Calendars::delete_if_exists(self, calendar_id).await
+    }
+    async fn patch(
+        &self,
+        calendar_id: &str,
+        body: &crate::types::Calendar,
+    ) -> Result<crate::types::Calendar> {
+        Calendars::patch(self, calendar_id, body).await
+    }
+    async fn clear(&self, calendar_id: &str) -> Result<()> {
+        Calendars::clear(self, calendar_id).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Channels {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Channels {
         Channels { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Channels {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `POST` to the `/channels/stop` endpoint.
      *
@@ -20,7 +26,25 @@ impl Channels {
     pub async fn stop(&self, body: &crate::types::Channel) -> Result<()> {
         let url = "/channels/stop".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/channels/stop", &[]))
+    }
+}
+
+/// The functions of [`Channels`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ChannelsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ChannelsApi: Send + Sync {
+    async fn stop(&self, body: &crate::types::Channel) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ChannelsApi for Channels {
+    async fn stop(&self, body: &crate::types::Channel) -> Result<()> {
+        Channels::stop(self, body).await
     }
 }
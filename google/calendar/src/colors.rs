@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Colors {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Colors {
         Colors { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Colors {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/colors` endpoint.
      *
@@ -19,6 +25,26 @@ impl Colors {
      */
     pub async fn get(&self) -> Result<crate::types::Colors> {
         let url = "/colors".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/colors", &[]))
+    }
+}
+
+/// The functions of [`Colors`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ColorsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ColorsApi: Send + Sync {
+    async fn get(&self) -> Result<crate::types::Colors>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ColorsApi for Colors {
+    async fn get(&self) -> Result<crate::types::Colors> {
+        Colors::get(self).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Events {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Events {
         Events { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Events {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/calendars/{calendarId}/events` endpoint.
      *
@@ -70,74 +76,158 @@ impl Events {
         time_zone: &str,
         updated_min: &str,
     ) -> Result<Vec<crate::types::Event>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !i_cal_uid.is_empty() {
-            query_args.push(("iCalUID".to_string(), i_cal_uid.to_string()));
+            query_args.push(("iCalUID", i_cal_uid.to_string()));
         }
         if max_attendees > 0 {
-            query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
+            query_args.push(("maxAttendees", max_attendees.to_string()));
         }
         if max_results > 0 {
-            query_args.push(("maxResults".to_string(), max_results.to_string()));
+            query_args.push(("maxResults", max_results.to_string()));
         }
         if !order_by.to_string().is_empty() {
-            query_args.push(("orderBy".to_string(), order_by.to_string()));
+            query_args.push(("orderBy", order_by.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
-        if !private_extended_property.is_empty() {
-            query_args.push((
-                "privateExtendedProperty".to_string(),
-                private_extended_property.join(" "),
-            ));
+        for v in private_extended_property {
+            query_args.push(("privateExtendedProperty", v.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
-        if !shared_extended_property.is_empty() {
-            query_args.push((
-                "sharedExtendedProperty".to_string(),
-                shared_extended_property.join(" "),
-            ));
+        for v in shared_extended_property {
+            query_args.push(("sharedExtendedProperty", v.to_string()));
         }
         if show_deleted {
-            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+            query_args.push(("showDeleted", show_deleted.to_string()));
         }
         if show_hidden_invitations {
-            query_args.push((
-                "showHiddenInvitations".to_string(),
-                show_hidden_invitations.to_string(),
-            ));
+            query_args.push(("showHiddenInvitations", show_hidden_invitations.to_string()));
         }
         if single_events {
-            query_args.push(("singleEvents".to_string(), single_events.to_string()));
+            query_args.push(("singleEvents", single_events.to_string()));
         }
         if !time_max.is_empty() {
-            query_args.push(("timeMax".to_string(), time_max.to_string()));
+            query_args.push(("timeMax", time_max.to_string()));
         }
         if !time_min.is_empty() {
-            query_args.push(("timeMin".to_string(), time_min.to_string()));
+            query_args.push(("timeMin", time_min.to_string()));
         }
         if !time_zone.is_empty() {
-            query_args.push(("timeZone".to_string(), time_zone.to_string()));
+            query_args.push(("timeZone", time_zone.to_string()));
         }
         if !updated_min.is_empty() {
-            query_args.push(("updatedMin".to_string(), updated_min.to_string()));
+            query_args.push(("updatedMin", updated_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/calendars/{}/events?{}",
-            crate::progenitor_support::encode_path(&calendar_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/calendars/{}/events", self.client.encode_path(calendar_id)),
+            &query_args,
         );
 
-        let resp: crate::types::Events = self.client.get(&url, None).await?;
+        let resp: crate::types::Events = self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/calendars/{calendarId}/events",
+                &[("calendarId", calendar_id.to_string())],
+            )
+        })?;
 
         // Return our response data.
         Ok(resp.items)
     }
 
+    /**
+     * This function performs a `GET` to the `/calendars/{calendarId}/events` endpoint.
+     *
+     * As opposed to `list`, this function streams the items as they are parsed from the response, rather than returning them all at once.
+     * This is useful for very large responses that would otherwise need to be held in memory.
+     * Only the one page the parameters ask for is streamed, the next pages are not fetched.
+     */
+    pub async fn list_stream_json(
+        &self,
+        calendar_id: &str,
+        i_cal_uid: &str,
+        max_attendees: i64,
+        max_results: i64,
+        order_by: crate::types::OrderBy,
+        page_token: &str,
+        private_extended_property: &[String],
+        q: &str,
+        shared_extended_property: &[String],
+        show_deleted: bool,
+        show_hidden_invitations: bool,
+        single_events: bool,
+        time_max: &str,
+        time_min: &str,
+        time_zone: &str,
+        updated_min: &str,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::Event>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !i_cal_uid.is_empty() {
+            query_args.push(("iCalUID", i_cal_uid.to_string()));
+        }
+        if max_attendees > 0 {
+            query_args.push(("maxAttendees", max_attendees.to_string()));
+        }
+        if max_results > 0 {
+            query_args.push(("maxResults", max_results.to_string()));
+        }
+        if !order_by.to_string().is_empty() {
+            query_args.push(("orderBy", order_by.to_string()));
+        }
+        if !page_token.is_empty() {
+            query_args.push(("pageToken", page_token.to_string()));
+        }
+        for v in private_extended_property {
+            query_args.push(("privateExtendedProperty", v.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q", q.to_string()));
+        }
+        for v in shared_extended_property {
+            query_args.push(("sharedExtendedProperty", v.to_string()));
+        }
+        if show_deleted {
+            query_args.push(("showDeleted", show_deleted.to_string()));
+        }
+        if show_hidden_invitations {
+            query_args.push(("showHiddenInvitations", show_hidden_invitations.to_string()));
+        }
+        if single_events {
+            query_args.push(("singleEvents", single_events.to_string()));
+        }
+        if !time_max.is_empty() {
+            query_args.push(("timeMax", time_max.to_string()));
+        }
+        if !time_min.is_empty() {
+            query_args.push(("timeMin", time_min.to_string()));
+        }
+        if !time_zone.is_empty() {
+            query_args.push(("timeZone", time_zone.to_string()));
+        }
+        if !updated_min.is_empty() {
+            query_args.push(("updatedMin", updated_min.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!("/calendars/{}/events", self.client.encode_path(calendar_id)),
+            &query_args,
+        );
+
+        self.client
+            .get_stream_json(&url, "items")
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/calendars/{calendarId}/events",
+                    &[("calendarId", calendar_id.to_string())],
+                )
+            })
+    }
+
     /**
      * This function performs a `GET` to the `/calendars/{calendarId}/events` endpoint.
      *
@@ -162,63 +252,59 @@ impl Events {
         time_zone: &str,
         updated_min: &str,
     ) -> Result<Vec<crate::types::Event>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !i_cal_uid.is_empty() {
-            query_args.push(("iCalUID".to_string(), i_cal_uid.to_string()));
+            query_args.push(("iCalUID", i_cal_uid.to_string()));
         }
         if max_attendees > 0 {
-            query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
+            query_args.push(("maxAttendees", max_attendees.to_string()));
         }
         if !order_by.to_string().is_empty() {
-            query_args.push(("orderBy".to_string(), order_by.to_string()));
+            query_args.push(("orderBy", order_by.to_string()));
         }
-        if !private_extended_property.is_empty() {
-            query_args.push((
-                "privateExtendedProperty".to_string(),
-                private_extended_property.join(" "),
-            ));
+        for v in private_extended_property {
+            query_args.push(("privateExtendedProperty", v.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
-        if !shared_extended_property.is_empty() {
-            query_args.push((
-                "sharedExtendedProperty".to_string(),
-                shared_extended_property.join(" "),
-            ));
+        for v in shared_extended_property {
+            query_args.push(("sharedExtendedProperty", v.to_string()));
         }
         if show_deleted {
-            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+            query_args.push(("showDeleted", show_deleted.to_string()));
         }
         if show_hidden_invitations {
-            query_args.push((
-                "showHiddenInvitations".to_string(),
-                show_hidden_invitations.to_string(),
-            ));
+            query_args.push(("showHiddenInvitations", show_hidden_invitations.to_string()));
         }
         if single_events {
-            query_args.push(("singleEvents".to_string(), single_events.to_string()));
+            query_args.push(("singleEvents", single_events.to_string()));
         }
         if !time_max.is_empty() {
-            query_args.push(("timeMax".to_string(), time_max.to_string()));
+            query_args.push(("timeMax", time_max.to_string()));
         }
         if !time_min.is_empty() {
-            query_args.push(("timeMin".to_string(), time_min.to_string()));
+            query_args.push(("timeMin", time_min.to_string()));
         }
         if !time_zone.is_empty() {
-            query_args.push(("timeZone".to_string(), time_zone.to_string()));
+            query_args.push(("timeZone", time_zone.to_string()));
         }
         if !updated_min.is_empty() {
-            query_args.push(("updatedMin".to_string(), updated_min.to_string()));
+            query_args.push(("updatedMin", updated_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/calendars/{}/events?{}",
-            crate::progenitor_support::encode_path(&calendar_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/calendars/{}/events", self.client.encode_path(calendar_id)),
+            &query_args,
         );
 
-        let mut resp: crate::types::Events = self.client.get(&url, None).await?;
+        let mut resp: crate::types::Events =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/calendars/{calendarId}/events",
+                    &[("calendarId", calendar_id.to_string())],
+                )
+            })?;
 
         let mut items = resp.items;
         let mut page = resp.next_page_token;
@@ -276,41 +362,37 @@ impl Events {
         supports_attachments: bool,
         body: &crate::types::Event,
     ) -> Result<crate::types::Event> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !conference_data_version.to_string().is_empty() {
-            query_args.push((
-                "conferenceDataVersion".to_string(),
-                conference_data_version.to_string(),
-            ));
+            query_args.push(("conferenceDataVersion", conference_data_version.to_string()));
         }
         if max_attendees > 0 {
-            query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
+            query_args.push(("maxAttendees", max_attendees.to_string()));
         }
         if send_notifications {
-            query_args.push((
-                "sendNotifications".to_string(),
-                send_notifications.to_string(),
-            ));
+            query_args.push(("sendNotifications", send_notifications.to_string()));
         }
         if !send_updates.to_string().is_empty() {
-            query_args.push(("sendUpdates".to_string(), send_updates.to_string()));
+            query_args.push(("sendUpdates", send_updates.to_string()));
         }
         if supports_attachments {
-            query_args.push((
-                "supportsAttachments".to_string(),
-                supports_attachments.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/calendars/{}/events?{}",
-            crate::progenitor_support::encode_path(&calendar_id.to_string()),
-            query_
+            query_args.push(("supportsAttachments", supports_attachments.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!("/calendars/{}/events", self.client.encode_path(calendar_id)),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/calendars/{calendarId}/events",
+                    &[("calendarId", calendar_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -331,29 +413,31 @@ impl Events {
         supports_attachments: bool,
         body: &crate::types::Event,
     ) -> Result<crate::types::Event> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !conference_data_version.to_string().is_empty() {
-            query_args.push((
-                "conferenceDataVersion".to_string(),
-                conference_data_version.to_string(),
-            ));
+            query_args.push(("conferenceDataVersion", conference_data_version.to_string()));
         }
         if supports_attachments {
-            query_args.push((
-                "supportsAttachments".to_string(),
-                supports_attachments.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/calendars/{}/events/import?{}",
-            crate::progenitor_support::encode_path(&calendar_id.to_string()),
-            query_
+            query_args.push(("supportsAttachments", supports_attachments.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/calendars/{}/events/import",
+                self.client.encode_path(calendar_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/calendars/{calendarId}/events/import",
+                    &[("calendarId", calendar_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -377,27 +461,31 @@ impl Events {
         send_notifications: bool,
         send_updates: crate::types::SendUpdates,
     ) -> Result<crate::types::Event> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if send_notifications {
-            query_args.push((
-                "sendNotifications".to_string(),
-                send_notifications.to_string(),
-            ));
+            query_args.push(("sendNotifications", send_notifications.to_string()));
         }
         if !send_updates.to_string().is_empty() {
-            query_args.push(("sendUpdates".to_string(), send_updates.to_string()));
+            query_args.push(("sendUpdates", send_updates.to_string()));
         }
         if !text.is_empty() {
-            query_args.push(("text".to_string(), text.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/calendars/{}/events/quickAdd?{}",
-            crate::progenitor_support::encode_path(&calendar_id.to_string()),
-            query_
+            query_args.push(("text", text.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/calendars/{}/events/quickAdd",
+                self.client.encode_path(calendar_id)
+            ),
+            &query_args,
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/calendars/{calendarId}/events/quickAdd",
+                &[("calendarId", calendar_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -459,71 +547,70 @@ impl Events {
         updated_min: &str,
         body: &crate::types::Channel,
     ) -> Result<crate::types::Channel> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !i_cal_uid.is_empty() {
-            query_args.push(("iCalUID".to_string(), i_cal_uid.to_string()));
+            query_args.push(("iCalUID", i_cal_uid.to_string()));
         }
         if max_attendees > 0 {
-            query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
+            query_args.push(("maxAttendees", max_attendees.to_string()));
         }
         if max_results > 0 {
-            query_args.push(("maxResults".to_string(), max_results.to_string()));
+            query_args.push(("maxResults", max_results.to_string()));
         }
         if !order_by.to_string().is_empty() {
-            query_args.push(("orderBy".to_string(), order_by.to_string()));
+            query_args.push(("orderBy", order_by.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
-        if !private_extended_property.is_empty() {
-            query_args.push((
-                "privateExtendedProperty".to_string(),
-                private_extended_property.join(" "),
-            ));
+        for v in private_extended_property {
+            query_args.push(("privateExtendedProperty", v.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
-        if !shared_extended_property.is_empty() {
-            query_args.push((
-                "sharedExtendedProperty".to_string(),
-                shared_extended_property.join(" "),
-            ));
+        for v in shared_extended_property {
+            query_args.push(("sharedExtendedProperty", v.to_string()));
         }
         if show_deleted {
-            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+            query_args.push(("showDeleted", show_deleted.to_string()));
         }
         if show_hidden_invitations {
-            query_args.push((
-                "showHiddenInvitations".to_string(),
-                show_hidden_invitations.to_string(),
-            ));
+            query_args.push(("showHiddenInvitations", show_hidden_invitations.to_string()));
         }
         if single_events {
-            query_args.push(("singleEvents".to_string(), single_events.to_string()));
+            query_args.push(("singleEvents", single_events.to_string()));
         }
         if !time_max.is_empty() {
-            query_args.push(("timeMax".to_string(), time_max.to_string()));
+            query_args.push(("timeMax", time_max.to_string()));
         }
         if !time_min.is_empty() {
-            query_args.push(("timeMin".to_string(), time_min.to_string()));
+            query_args.push(("timeMin", time_min.to_string()));
         }
         if !time_zone.is_empty() {
-            query_args.push(("timeZone".to_string(), time_zone.to_string()));
+            query_args.push(("timeZone", time_zone.to_string()));
         }
         if !updated_min.is_empty() {
-            query_args.push(("updatedMin".to_string(), updated_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/calendars/{}/events/watch?{}",
-            crate::progenitor_support::encode_path(&calendar_id.to_string()),
-            query_
+            query_args.push(("updatedMin", updated_min.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/calendars/{}/events/watch",
+                self.client.encode_path(calendar_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/calendars/{calendarId}/events/watch",
+                    &[("calendarId", calendar_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -546,22 +633,32 @@ impl Events {
         max_attendees: i64,
         time_zone: &str,
     ) -> Result<crate::types::Event> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if max_attendees > 0 {
-            query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
+            query_args.push(("maxAttendees", max_attendees.to_string()));
         }
         if !time_zone.is_empty() {
-            query_args.push(("timeZone".to_string(), time_zone.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/calendars/{}/events/{}?{}",
-            crate::progenitor_support::encode_path(&calendar_id.to_string()),
-            crate::progenitor_support::encode_path(&event_id.to_string()),
-            query_
+            query_args.push(("timeZone", time_zone.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/calendars/{}/events/{}",
+                self.client.encode_path(calendar_id),
+                self.client.encode_path(event_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/calendars/{calendarId}/events/{eventId}",
+                &[
+                    ("calendarId", calendar_id.to_string()),
+                    ("eventId", event_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -593,42 +690,44 @@ impl Events {
         supports_attachments: bool,
         body: &crate::types::Event,
     ) -> Result<crate::types::Event> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !conference_data_version.to_string().is_empty() {
-            query_args.push((
-                "conferenceDataVersion".to_string(),
-                conference_data_version.to_string(),
-            ));
+            query_args.push(("conferenceDataVersion", conference_data_version.to_string()));
         }
         if max_attendees > 0 {
-            query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
+            query_args.push(("maxAttendees", max_attendees.to_string()));
         }
         if send_notifications {
-            query_args.push((
-                "sendNotifications".to_string(),
-                send_notifications.to_string(),
-            ));
+            query_args.push(("sendNotifications", send_notifications.to_string()));
         }
         if !send_updates.to_string().is_empty() {
-            query_args.push(("sendUpdates".to_string(), send_updates.to_string()));
+            query_args.push(("sendUpdates", send_updates.to_string()));
         }
         if supports_attachments {
-            query_args.push((
-                "supportsAttachments".to_string(),
-                supports_attachments.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/calendars/{}/events/{}?{}",
-            crate::progenitor_support::encode_path(&calendar_id.to_string()),
-            crate::progenitor_support::encode_path(&event_id.to_string()),
-            query_
+            query_args.push(("supportsAttachments", supports_attachments.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/calendars/{}/events/{}",
+                self.client.encode_path(calendar_id),
+                self.client.encode_path(event_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/calendars/{calendarId}/events/{eventId}",
+                    &[
+                        ("calendarId", calendar_id.to_string()),
+                        ("eventId", event_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -652,25 +751,75 @@ impl Events {
         send_notifications: bool,
         send_updates: crate::types::SendUpdates,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if send_notifications {
-            query_args.push((
-                "sendNotifications".to_string(),
-                send_notifications.to_string(),
-            ));
+            query_args.push(("sendNotifications", send_notifications.to_string()));
         }
         if !send_updates.to_string().is_empty() {
-            query_args.push(("sendUpdates".to_string(), send_updates.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/calendars/{}/events/{}?{}",
-            crate::progenitor_support::encode_path(&calendar_id.to_string()),
-            crate::progenitor_support::encode_path(&event_id.to_string()),
-            query_
+            query_args.push(("sendUpdates", send_updates.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/calendars/{}/events/{}",
+                self.client.encode_path(calendar_id),
+                self.client.encode_path(event_id)
+            ),
+            &query_args,
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/calendars/{calendarId}/events/{eventId}",
+                &[
+                    ("calendarId", calendar_id.to_string()),
+                    ("eventId", event_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/calendars/{calendarId}/events/{eventId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        send_notifications: bool,
+        send_updates: crate::types::SendUpdates,
+    ) -> Result<bool> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if send_notifications {
+            query_args.push(("sendNotifications", send_notifications.to_string()));
+        }
+        if !send_updates.to_string().is_empty() {
+            query_args.push(("sendUpdates", send_updates.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/calendars/{}/events/{}",
+                self.client.encode_path(calendar_id),
+                self.client.encode_path(event_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/calendars/{calendarId}/events/{eventId}",
+                    &[
+                        ("calendarId", calendar_id.to_string()),
+                        ("eventId", event_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -702,42 +851,101 @@ impl Events {
         supports_attachments: bool,
         body: &crate::types::Event,
     ) -> Result<crate::types::Event> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !conference_data_version.to_string().is_empty() {
-            query_args.push((
-                "conferenceDataVersion".to_string(),
-                conference_data_version.to_string(),
-            ));
+            query_args.push(("conferenceDataVersion", conference_data_version.to_string()));
         }
         if max_attendees > 0 {
-            query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
+            query_args.push(("maxAttendees", max_attendees.to_string()));
         }
         if send_notifications {
-            query_args.push((
-                "sendNotifications".to_string(),
-                send_notifications.to_string(),
-            ));
+            query_args.push(("sendNotifications", send_notifications.to_string()));
         }
         if !send_updates.to_string().is_empty() {
-            query_args.push(("sendUpdates".to_string(), send_updates.to_string()));
+            query_args.push(("sendUpdates", send_updates.to_string()));
         }
         if supports_attachments {
-            query_args.push((
-                "supportsAttachments".to_string(),
-                supports_attachments.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/calendars/{}/events/{}?{}",
-            crate::progenitor_support::encode_path(&calendar_id.to_string()),
-            crate::progenitor_support::encode_path(&event_id.to_string()),
-            query_
+            query_args.push(("supportsAttachments", supports_attachments.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/calendars/{}/events/{}",
+                self.client.encode_path(calendar_id),
+                self.client.encode_path(event_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/calendars/{calendarId}/events/{eventId}",
+                    &[
+                        ("calendarId", calendar_id.to_string()),
+                        ("eventId", event_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * This function performs a `PATCH` to the `/calendars/{calendarId}/events/{eventId}` endpoint.
+     *
+     * As opposed to `patch`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_if_changed<C: serde::Serialize>(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        conference_data_version: u64,
+        max_attendees: i64,
+        send_notifications: bool,
+        send_updates: crate::types::SendUpdates,
+        supports_attachments: bool,
+        current: &C,
+        body: &crate::types::Event,
+    ) -> Result<Option<crate::types::Event>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !conference_data_version.to_string().is_empty() {
+            query_args.push(("conferenceDataVersion", conference_data_version.to_string()));
+        }
+        if max_attendees > 0 {
+            query_args.push(("maxAttendees", max_attendees.to_string()));
+        }
+        if send_notifications {
+            query_args.push(("sendNotifications", send_notifications.to_string()));
+        }
+        if !send_updates.to_string().is_empty() {
+            query_args.push(("sendUpdates", send_updates.to_string()));
+        }
+        if supports_attachments {
+            query_args.push(("supportsAttachments", supports_attachments.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/calendars/{}/events/{}",
+                self.client.encode_path(calendar_id),
+                self.client.encode_path(event_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/calendars/{calendarId}/events/{eventId}",
+                    &[
+                        ("calendarId", calendar_id.to_string()),
+                        ("eventId", event_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -772,40 +980,50 @@ impl Events {
         time_min: &str,
         time_zone: &str,
     ) -> Result<Vec<crate::types::Event>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if max_attendees > 0 {
-            query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
+            query_args.push(("maxAttendees", max_attendees.to_string()));
         }
         if max_results > 0 {
-            query_args.push(("maxResults".to_string(), max_results.to_string()));
+            query_args.push(("maxResults", max_results.to_string()));
         }
         if !original_start.is_empty() {
-            query_args.push(("originalStart".to_string(), original_start.to_string()));
+            query_args.push(("originalStart", original_start.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if show_deleted {
-            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+            query_args.push(("showDeleted", show_deleted.to_string()));
         }
         if !time_max.is_empty() {
-            query_args.push(("timeMax".to_string(), time_max.to_string()));
+            query_args.push(("timeMax", time_max.to_string()));
         }
         if !time_min.is_empty() {
-            query_args.push(("timeMin".to_string(), time_min.to_string()));
+            query_args.push(("timeMin", time_min.to_string()));
         }
         if !time_zone.is_empty() {
-            query_args.push(("timeZone".to_string(), time_zone.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/calendars/{}/events/{}/instances?{}",
-            crate::progenitor_support::encode_path(&calendar_id.to_string()),
-            crate::progenitor_support::encode_path(&event_id.to_string()),
-            query_
+            query_args.push(("timeZone", time_zone.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/calendars/{}/events/{}/instances",
+                self.client.encode_path(calendar_id),
+                self.client.encode_path(event_id)
+            ),
+            &query_args,
         );
 
-        let resp: crate::types::Events = self.client.get(&url, None).await?;
+        let resp: crate::types::Events = self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/calendars/{calendarId}/events/{eventId}/instances",
+                &[
+                    ("calendarId", calendar_id.to_string()),
+                    ("eventId", event_id.to_string()),
+                ],
+            )
+        })?;
 
         // Return our response data.
         Ok(resp.items)
@@ -829,34 +1047,45 @@ impl Events {
         time_min: &str,
         time_zone: &str,
     ) -> Result<Vec<crate::types::Event>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if max_attendees > 0 {
-            query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
+            query_args.push(("maxAttendees", max_attendees.to_string()));
         }
         if !original_start.is_empty() {
-            query_args.push(("originalStart".to_string(), original_start.to_string()));
+            query_args.push(("originalStart", original_start.to_string()));
         }
         if show_deleted {
-            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+            query_args.push(("showDeleted", show_deleted.to_string()));
         }
         if !time_max.is_empty() {
-            query_args.push(("timeMax".to_string(), time_max.to_string()));
+            query_args.push(("timeMax", time_max.to_string()));
         }
         if !time_min.is_empty() {
-            query_args.push(("timeMin".to_string(), time_min.to_string()));
+            query_args.push(("timeMin", time_min.to_string()));
         }
         if !time_zone.is_empty() {
-            query_args.push(("timeZone".to_string(), time_zone.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/calendars/{}/events/{}/instances?{}",
-            crate::progenitor_support::encode_path(&calendar_id.to_string()),
-            crate::progenitor_support::encode_path(&event_id.to_string()),
-            query_
+            query_args.push(("timeZone", time_zone.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/calendars/{}/events/{}/instances",
+                self.client.encode_path(calendar_id),
+                self.client.encode_path(event_id)
+            ),
+            &query_args,
         );
 
-        let mut resp: crate::types::Events = self.client.get(&url, None).await?;
+        let mut resp: crate::types::Events =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/calendars/{calendarId}/events/{eventId}/instances",
+                    &[
+                        ("calendarId", calendar_id.to_string()),
+                        ("eventId", event_id.to_string()),
+                    ],
+                )
+            })?;
 
         let mut items = resp.items;
         let mut page = resp.next_page_token;
@@ -911,27 +1140,526 @@ impl Events {
         send_notifications: bool,
         send_updates: crate::types::SendUpdates,
     ) -> Result<crate::types::Event> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !destination.is_empty() {
-            query_args.push(("destination".to_string(), destination.to_string()));
+            query_args.push(("destination", destination.to_string()));
         }
         if send_notifications {
-            query_args.push((
-                "sendNotifications".to_string(),
-                send_notifications.to_string(),
-            ));
+            query_args.push(("sendNotifications", send_notifications.to_string()));
         }
         if !send_updates.to_string().is_empty() {
-            query_args.push(("sendUpdates".to_string(), send_updates.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/calendars/{}/events/{}/move?{}",
-            crate::progenitor_support::encode_path(&calendar_id.to_string()),
-            crate::progenitor_support::encode_path(&event_id.to_string()),
-            query_
+            query_args.push(("sendUpdates", send_updates.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/calendars/{}/events/{}/move",
+                self.client.encode_path(calendar_id),
+                self.client.encode_path(event_id)
+            ),
+            &query_args,
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/calendars/{calendarId}/events/{eventId}/move",
+                &[
+                    ("calendarId", calendar_id.to_string()),
+                    ("eventId", event_id.to_string()),
+                ],
+            )
+        })
+    }
+}
+
+/// The functions of [`Events`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn EventsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait EventsApi: Send + Sync {
+    async fn list(
+        &self,
+        calendar_id: &str,
+        i_cal_uid: &str,
+        max_attendees: i64,
+        max_results: i64,
+        order_by: crate::types::OrderBy,
+        page_token: &str,
+        private_extended_property: &[String],
+        q: &str,
+        shared_extended_property: &[String],
+        show_deleted: bool,
+        show_hidden_invitations: bool,
+        single_events: bool,
+        time_max: &str,
+        time_min: &str,
+        time_zone: &str,
+        updated_min: &str,
+    ) -> Result<Vec<crate::types::Event>>;
+    async fn list_all(
+        &self,
+        calendar_id: &str,
+        i_cal_uid: &str,
+        max_attendees: i64,
+        order_by: crate::types::OrderBy,
+        private_extended_property: &[String],
+        q: &str,
+        shared_extended_property: &[String],
+        show_deleted: bool,
+        show_hidden_invitations: bool,
+        single_events: bool,
+        time_max: &str,
+        time_min: &str,
+        time_zone: &str,
+        updated_min: &str,
+    ) -> Result<Vec<crate::types::Event>>;
+    async fn insert(
+        &self,
+        calendar_id: &str,
+        conference_data_version: u64,
+        max_attendees: i64,
+        send_notifications: bool,
+        send_updates: crate::types::SendUpdates,
+        supports_attachments: bool,
+        body: &crate::types::Event,
+    ) -> Result<crate::types::Event>;
+    async fn import(
+        &self,
+        calendar_id: &str,
+        conference_data_version: u64,
+        supports_attachments: bool,
+        body: &crate::types::Event,
+    ) -> Result<crate::types::Event>;
+    async fn quick_add(
+        &self,
+        calendar_id: &str,
+        text: &str,
+        send_notifications: bool,
+        send_updates: crate::types::SendUpdates,
+    ) -> Result<crate::types::Event>;
+    async fn watch(
+        &self,
+        calendar_id: &str,
+        i_cal_uid: &str,
+        max_attendees: i64,
+        max_results: i64,
+        order_by: crate::types::OrderBy,
+        page_token: &str,
+        private_extended_property: &[String],
+        q: &str,
+        shared_extended_property: &[String],
+        show_deleted: bool,
+        show_hidden_invitations: bool,
+        single_events: bool,
+        time_max: &str,
+        time_min: &str,
+        time_zone: &str,
+        updated_min: &str,
+        body: &crate::types::Channel,
+    ) -> Result<crate::types::Channel>;
+    async fn get(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        max_attendees: i64,
+        time_zone: &str,
+    ) -> Result<crate::types::Event>;
+    async fn update(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        conference_data_version: u64,
+        max_attendees: i64,
+        send_notifications: bool,
+        send_updates: crate::types::SendUpdates,
+        supports_attachments: bool,
+        body: &crate::types::Event,
+    ) -> Result<crate::types::Event>;
+    async fn delete(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        send_notifications: bool,
+        send_updates: crate::types::SendUpdates,
+    ) -> Result<()>;
+    async fn delete_if_exists(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        send_notifications: bool,
+        send_updates: crate::types::SendUpdates,
+    ) -> Result<bool>;
+    async fn patch(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        conference_data_version: u64,
+        max_attendees: i64,
+        send_notifications: bool,
+        send_updates: crate::types::SendUpdates,
+        supports_attachments: bool,
+        body: &crate::types::Event,
+    ) -> Result<crate::types::Event>;
+    async fn instances(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        max_attendees: i64,
+        max_results: i64,
+        original_start: &str,
+        page_token: &str,
+        show_deleted: bool,
+        time_max: &str,
+        time_min: &str,
+        time_zone: &str,
+    ) -> Result<Vec<crate::types::Event>>;
+    async fn get_all_instances(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        max_attendees: i64,
+        original_start: &str,
+        show_deleted: bool,
+        time_max: &str,
+        time_min: &str,
+        time_zone: &str,
+    ) -> Result<Vec<crate::types::Event>>;
+    async fn mv(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        destination: &str,
+        send_notifications: bool,
+        send_updates: crate::types::SendUpdates,
+    ) -> Result<crate::types::Event>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl EventsApi for Events {
+    async fn list(
+        &self,
+        calendar_id: &str,
+        i_cal_uid: &str,
+        max_attendees: i64,
+        max_results: i64,
+        order_by: crate::types::OrderBy,
+        page_token: &str,
+        private_extended_property: &[String],
+        q: &str,
+        shared_extended_property: &[String],
+        show_deleted: bool,
+        show_hidden_invitations: bool,
+        single_events: bool,
+        time_max: &str,
+        time_min: &str,
+        time_zone: &str,
+        updated_min: &str,
+    ) -> Result<Vec<crate::types::Event>> {
+        Events::list(
+            self,
+            calendar_id,
+            i_cal_uid,
+            max_attendees,
+            max_results,
+            order_by,
+            page_token,
+            private_extended_property,
+            q,
+            shared_extended_property,
+            show_deleted,
+            show_hidden_invitations,
+            single_events,
+            time_max,
+            time_min,
+            time_zone,
+            updated_min,
+        )
+        .await
+    }
+    async fn list_all(
+        &self,
+        calendar_id: &str,
+        i_cal_uid: &str,
+        max_attendees: i64,
+        order_by: crate::types::OrderBy,
+        private_extended_property: &[String],
+        q: &str,
+        shared_extended_property: &[String],
+        show_deleted: bool,
+        show_hidden_invitations: bool,
+        single_events: bool,
+        time_max: &str,
+        time_min: &str,
+        time_zone: &str,
+        updated_min: &str,
+    ) -> Result<Vec<crate::types::Event>> {
+        Events::list_all(
+            self,
+            calendar_id,
+            i_cal_uid,
+            max_attendees,
+            order_by,
+            private_extended_property,
+            q,
+            shared_extended_property,
+            show_deleted,
+            show_hidden_invitations,
+            single_events,
+            time_max,
+            time_min,
+            time_zone,
+            updated_min,
+        )
+        .await
+    }
+    async fn insert(
+        &self,
+        calendar_id: &str,
+        conference_data_version: u64,
+        max_attendees: i64,
+        send_notifications: bool,
+        send_updates: crate::types::SendUpdates,
+        supports_attachments: bool,
+        body: &crate::types::Event,
+    ) -> Result<crate::types::Event> {
+        Events::insert(
+            self,
+            calendar_id,
+            conference_data_version,
+            max_attendees,
+            send_notifications,
+            send_updates,
+            supports_attachments,
+            body,
+        )
+        .await
+    }
+    async fn import(
+        &self,
+        calendar_id: &str,
+        conference_data_version: u64,
+        supports_attachments: bool,
+        body: &crate::types::Event,
+    ) -> Result<crate::types::Event> {
+        Events::import(
+            self,
+            calendar_id,
+            conference_data_version,
+            supports_attachments,
+            body,
+        )
+        .await
+    }
+    async fn quick_add(
+        &self,
+        calendar_id: &str,
+        text: &str,
+        send_notifications: bool,
+        send_updates: crate::types::SendUpdates,
+    ) -> Result<crate::types::Event> {
+        Events::quick_add(self, calendar_id, text, send_notifications, send_updates).await
+    }
+    async fn watch(
+        &self,
+        calendar_id: &str,
+        i_cal_uid: &str,
+        max_attendees: i64,
+        max_results: i64,
+        order_by: crate::types::OrderBy,
+        page_token: &str,
+        private_extended_property: &[String],
+        q: &str,
+        shared_extended_property: &[String],
+        show_deleted: bool,
+        show_hidden_invitations: bool,
+        single_events: bool,
+        time_max: &str,
+        time_min: &str,
+        time_zone: &str,
+        updated_min: &str,
+        body: &crate::types::Channel,
+    ) -> Result<crate::types::Channel> {
+        Events::watch(
+            self,
+            calendar_id,
+            i_cal_uid,
+            max_attendees,
+            max_results,
+            order_by,
+            page_token,
+            private_extended_property,
+            q,
+            shared_extended_property,
+            show_deleted,
+            show_hidden_invitations,
+            single_events,
+            time_max,
+            time_min,
+            time_zone,
+            updated_min,
+            body,
+        )
+        .await
+    }
+    async fn get(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        max_attendees: i64,
+        time_zone: &str,
+    ) -> Result<crate::types::Event> {
+        Events::get(self, calendar_id, event_id, max_attendees, time_zone).await
+    }
+    async fn update(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        conference_data_version: u64,
+        max_attendees: i64,
+        send_notifications: bool,
+        send_updates: crate::types::SendUpdates,
+        supports_attachments: bool,
+        body: &crate::types::Event,
+    ) -> Result<crate::types::Event> {
+        Events::update(
+            self,
+            calendar_id,
+            event_id,
+            conference_data_version,
+            max_attendees,
+            send_notifications,
+            send_updates,
+            supports_attachments,
+            body,
+        )
+        .await
+    }
+    async fn delete(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        send_notifications: bool,
+        send_updates: crate::types::SendUpdates,
+    ) -> Result<()> {
+        Events::delete(
+            self,
+            calendar_id,
+            event_id,
+            send_notifications,
+            send_updates,
+        )
+        .await
+    }
+    async fn delete_if_exists(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        send_notifications: bool,
+        send_updates: crate::types::SendUpdates,
+    ) -> Result<bool> {
+        Events::delete_if_exists(
+            self,
+            calendar_id,
+            event_id,
+            send_notifications,
+            send_updates,
+        )
+        .await
+    }
+    async fn patch(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        conference_data_version: u64,
+        max_attendees: i64,
+        send_notifications: bool,
+        send_updates: crate::types::SendUpdates,
+        supports_attachments: bool,
+        body: &crate::types::Event,
+    ) -> Result<crate::types::Event> {
+        Events::patch(
+            self,
+            calendar_id,
+            event_id,
+            conference_data_version,
+            max_attendees,
+            send_notifications,
+            send_updates,
+            supports_attachments,
+            body,
+        )
+        .await
+    }
+    async fn instances(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        max_attendees: i64,
+        max_results: i64,
+        original_start: &str,
+        page_token: &str,
+        show_deleted: bool,
+        time_max: &str,
+        time_min: &str,
+        time_zone: &str,
+    ) -> Result<Vec<crate::types::Event>> {
+        Events::instances(
+            self,
+            calendar_id,
+            event_id,
+            max_attendees,
+            max_results,
+            original_start,
+            page_token,
+            show_deleted,
+            time_max,
+            time_min,
+            time_zone,
+        )
+        .await
+    }
+    async fn get_all_instances(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        max_attendees: i64,
+        original_start: &str,
+        show_deleted: bool,
+        time_max: &str,
+        time_min: &str,
+        time_zone: &str,
+    ) -> Result<Vec<crate::types::Event>> {
+        Events::get_all_instances(
+            self,
+            calendar_id,
+            event_id,
+            max_attendees,
+            original_start,
+            show_deleted,
+            time_max,
+            time_min,
+            time_zone,
+        )
+        .await
+    }
+    async fn mv(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        destination: &str,
+        send_notifications: bool,
+        send_updates: crate::types::SendUpdates,
+    ) -> Result<crate::types::Event> {
+        Events::mv(
+            self,
+            calendar_id,
+            event_id,
+            destination,
+            send_notifications,
+            send_updates,
+        )
+        .await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Freebusy {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Freebusy {
         Freebusy { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Freebusy {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `POST` to the `/freeBusy` endpoint.
      *
@@ -23,7 +29,31 @@ impl Freebusy {
     ) -> Result<crate::types::FreeBusyResponse> {
         let url = "/freeBusy".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/freeBusy", &[]))
+    }
+}
+
+/// The functions of [`Freebusy`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn FreebusyApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait FreebusyApi: Send + Sync {
+    async fn query(
+        &self,
+        body: &crate::types::FreeBusyRequest,
+    ) -> Result<crate::types::FreeBusyResponse>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl FreebusyApi for Freebusy {
+    async fn query(
+        &self,
+        body: &crate::types::FreeBusyRequest,
+    ) -> Result<crate::types::FreeBusyResponse> {
+        Freebusy::query(self, body).await
     }
 }
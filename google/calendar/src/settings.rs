@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Settings {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Settings {
         Settings { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Settings {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/users/me/settings` endpoint.
      *
@@ -31,17 +37,20 @@ impl Settings {
         max_results: i64,
         page_token: &str,
     ) -> Result<Vec<crate::types::Setting>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if max_results > 0 {
-            query_args.push(("maxResults".to_string(), max_results.to_string()));
+            query_args.push(("maxResults", max_results.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/users/me/settings?{}", query_);
+        let url = crate::Client::url_with_query("/users/me/settings", &query_args);
 
-        let resp: crate::types::Settings = self.client.get(&url, None).await?;
+        let resp: crate::types::Settings = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/users/me/settings", &[]))?;
 
         // Return our response data.
         Ok(resp.items)
@@ -56,7 +65,11 @@ impl Settings {
      */
     pub async fn list_all(&self) -> Result<Vec<crate::types::Setting>> {
         let url = "/users/me/settings".to_string();
-        let mut resp: crate::types::Settings = self.client.get(&url, None).await?;
+        let mut resp: crate::types::Settings = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/users/me/settings", &[]))?;
 
         let mut items = resp.items;
         let mut page = resp.next_page_token;
@@ -108,19 +121,19 @@ impl Settings {
         page_token: &str,
         body: &crate::types::Channel,
     ) -> Result<crate::types::Channel> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if max_results > 0 {
-            query_args.push(("maxResults".to_string(), max_results.to_string()));
+            query_args.push(("maxResults", max_results.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/users/me/settings/watch?{}", query_);
+        let url = crate::Client::url_with_query("/users/me/settings/watch", &query_args);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/users/me/settings/watch", &[]))
     }
 
     /**
@@ -133,11 +146,53 @@ impl Settings {
      * * `setting: &str` -- The id of the user setting.
      */
     pub async fn get(&self, setting: &str) -> Result<crate::types::Setting> {
-        let url = format!(
-            "/users/me/settings/{}",
-            crate::progenitor_support::encode_path(&setting.to_string()),
-        );
+        let url = format!("/users/me/settings/{}", self.client.encode_path(setting),);
+
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/users/me/settings/{setting}",
+                &[("setting", setting.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`Settings`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn SettingsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait SettingsApi: Send + Sync {
+    async fn list(&self, max_results: i64, page_token: &str) -> Result<Vec<crate::types::Setting>>;
+    async fn list_all(&self) -> Result<Vec<crate::types::Setting>>;
+    async fn watch(
+        &self,
+        max_results: i64,
+        page_token: &str,
+        body: &crate::types::Channel,
+    ) -> Result<crate::types::Channel>;
+    async fn get(&self, setting: &str) -> Result<crate::types::Setting>;
+}
 
-        self.client.get(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl SettingsApi for Settings {
+    async fn list(&self, max_results: i64, page_token: &str) -> Result<Vec<crate::types::Setting>> {
+        Settings::list(self, max_results, page_token).await
+    }
+    async fn list_all(&self) -> Result<Vec<crate::types::Setting>> {
+        Settings::list_all(self).await
+    }
+    async fn watch(
+        &self,
+        max_results: i64,
+        page_token: &str,
+        body: &crate::types::Channel,
+    ) -> Result<crate::types::Channel> {
+        Settings::watch(self, max_results, page_token, body).await
+    }
+    async fn get(&self, setting: &str) -> Result<crate::types::Setting> {
+        Settings::get(self, setting).await
     }
 }
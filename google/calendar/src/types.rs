@@ -2,7 +2,35 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+/// Either one of something, or many of them. Some endpoints send back an
+/// object when there is one and an array when there are more, so this takes both.
+// Many is tried first: serde reads a struct from an array of its fields too,
+// so an array would otherwise be taken for One.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum OneOrMany<T> {
+    Many(Vec<T>),
+    One(T),
+}
+
+impl<T> OneOrMany<T> {
+    /// All of them, as a vector.
+    pub fn into_vec(self) -> Vec<T> {
+        match self {
+            OneOrMany::One(t) => vec![t],
+            OneOrMany::Many(v) => v,
+        }
+    }
+}
+
+impl<T> Default for OneOrMany<T> {
+    fn default() -> Self {
+        OneOrMany::Many(Vec::new())
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Acl {
     /**
      * ETag of the collection.
@@ -37,8 +65,7 @@ pub struct Acl {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub next_page_token: String,
     /**
@@ -47,12 +74,36 @@ pub struct Acl {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextSyncToken"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub next_sync_token: String,
 }
 
+impl IntoIterator for Acl {
+    type Item = AclRule;
+    type IntoIter = std::vec::IntoIter<AclRule>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Acl {
+    type Item = &'a AclRule;
+    type IntoIter = std::slice::Iter<'a, AclRule>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+impl Acl {
+    /// Iterate over the `items`.
+    pub fn iter(&self) -> std::slice::Iter<'_, AclRule> {
+        self.items.iter()
+    }
+}
+
 /// The extent to which calendar access is granted by this ACL rule.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Scope {
@@ -123,12 +174,9 @@ pub struct AclRule {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Calendar {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "conferenceProperties"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub conference_properties: Option<ConferenceProperties>,
     /**
      * ETag of the collection.
@@ -190,13 +238,13 @@ pub struct Calendar {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "timeZone"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub time_zone: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct CalendarList {
     /**
      * ETag of the collection.
@@ -231,8 +279,7 @@ pub struct CalendarList {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub next_page_token: String,
     /**
@@ -241,12 +288,36 @@ pub struct CalendarList {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextSyncToken"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub next_sync_token: String,
 }
 
+impl IntoIterator for CalendarList {
+    type Item = CalendarListEntry;
+    type IntoIter = std::vec::IntoIter<CalendarListEntry>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a CalendarList {
+    type Item = &'a CalendarListEntry;
+    type IntoIter = std::slice::Iter<'a, CalendarListEntry>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+impl CalendarList {
+    /// Iterate over the `items`.
+    pub fn iter(&self) -> std::slice::Iter<'_, CalendarListEntry> {
+        self.items.iter()
+    }
+}
+
 /// The notifications that the authenticated user is receiving for this calendar.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct NotificationSettings {
@@ -262,6 +333,7 @@ pub struct NotificationSettings {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct CalendarListEntry {
     /**
      * ETag of the collection.
@@ -269,8 +341,7 @@ pub struct CalendarListEntry {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "accessRole"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub access_role: String,
     /**
@@ -279,8 +350,7 @@ pub struct CalendarListEntry {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "backgroundColor"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub background_color: String,
     /**
@@ -289,15 +359,10 @@ pub struct CalendarListEntry {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "colorId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub color_id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "conferenceProperties"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub conference_properties: Option<ConferenceProperties>,
     /**
      * The default reminders that the authenticated user has for this calendar.
@@ -305,8 +370,7 @@ pub struct CalendarListEntry {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "defaultReminders"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub default_reminders: Vec<EventReminder>,
     /**
@@ -341,8 +405,7 @@ pub struct CalendarListEntry {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "foregroundColor"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub foreground_color: String,
     /**
@@ -383,11 +446,7 @@ pub struct CalendarListEntry {
     /**
      * The notifications that the authenticated user is receiving for this calendar.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "notificationSettings"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub notification_settings: Option<NotificationSettings>,
     /**
      * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
@@ -420,8 +479,7 @@ pub struct CalendarListEntry {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "summaryOverride"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub summary_override: String,
     /**
@@ -430,8 +488,7 @@ pub struct CalendarListEntry {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "timeZone"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub time_zone: String,
 }
@@ -460,6 +517,7 @@ pub struct CalendarNotification {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Channel {
     /**
      * ETag of the collection.
@@ -520,8 +578,7 @@ pub struct Channel {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "resourceId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub resource_id: String,
     /**
@@ -530,8 +587,7 @@ pub struct Channel {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "resourceUri"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub resource_uri: String,
     /**
@@ -611,6 +667,7 @@ pub struct Colors {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ConferenceData {
     /**
      * ETag of the collection.
@@ -618,21 +675,12 @@ pub struct ConferenceData {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "conferenceId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub conference_id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "conferenceSolution"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub conference_solution: Option<ConferenceSolution>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "createRequest"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub create_request: Option<CreateConferenceRequest>,
     /**
      * Information about individual conference entry points, such as URLs or phone numbers.
@@ -642,8 +690,7 @@ pub struct ConferenceData {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "entryPoints"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub entry_points: Vec<EntryPoint>,
     /**
@@ -669,12 +716,9 @@ pub struct ConferenceData {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ConferenceParameters {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "addOnParameters"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub add_on_parameters: Option<ConferenceParametersAddOn>,
 }
 
@@ -692,6 +736,7 @@ pub struct ConferenceParametersAddOn {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ConferenceProperties {
     /**
      * The types of conference solutions that are supported for this calendar.
@@ -703,13 +748,13 @@ pub struct ConferenceProperties {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "allowedConferenceSolutionTypes"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub allowed_conference_solution_types: Vec<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ConferenceRequestStatus {
     /**
      * ETag of the collection.
@@ -717,13 +762,13 @@ pub struct ConferenceRequestStatus {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "statusCode"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub status_code: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ConferenceSolution {
     /**
      * ETag of the collection.
@@ -731,8 +776,7 @@ pub struct ConferenceSolution {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "iconUri"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub icon_uri: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -763,12 +807,9 @@ pub struct ConferenceSolutionKey {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct CreateConferenceRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "conferenceSolutionKey"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub conference_solution_key: Option<ConferenceSolutionKey>,
     /**
      * ETag of the collection.
@@ -776,8 +817,7 @@ pub struct CreateConferenceRequest {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "requestId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub request_id: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -785,6 +825,7 @@ pub struct CreateConferenceRequest {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct EntryPoint {
     /**
      * ETag of the collection.
@@ -792,8 +833,7 @@ pub struct EntryPoint {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "accessCode"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub access_code: String,
     /**
@@ -806,8 +846,7 @@ pub struct EntryPoint {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "entryPointFeatures"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub entry_point_features: Vec<String>,
     /**
@@ -816,8 +855,7 @@ pub struct EntryPoint {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "entryPointType"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub entry_point_type: String,
     /**
@@ -835,8 +873,7 @@ pub struct EntryPoint {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "meetingCode"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub meeting_code: String,
     /**
@@ -872,8 +909,7 @@ pub struct EntryPoint {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "regionCode"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub region_code: String,
     /**
@@ -911,6 +947,7 @@ pub struct Error {
 
 /// The creator of the event. Read-only.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Creator {
     /**
      * The creator of the event. Read-only.
@@ -918,8 +955,7 @@ pub struct Creator {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "displayName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub display_name: String,
     /**
@@ -968,6 +1004,7 @@ pub struct ExtendedProperties {
 
 /// A gadget that extends this event. Gadgets are deprecated; this structure is instead only used for returning birthday calendar metadata.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Gadget {
     /**
      * A gadget that extends this event. Gadgets are deprecated; this structure is instead only used for returning birthday calendar metadata.
@@ -993,8 +1030,7 @@ pub struct Gadget {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "iconLink"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub icon_link: String,
     /**
@@ -1047,6 +1083,7 @@ pub struct Gadget {
 
 /// The organizer of the event. If the organizer is also an attendee, this is indicated with a separate entry in attendees with the organizer field set to True. To change the organizer, use the move operation. Read-only, except when importing an event.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Organizer {
     /**
      * The organizer of the event. If the organizer is also an attendee, this is indicated with a separate entry in attendees with the organizer field set to True. To change the organizer, use the move operation. Read-only, except when importing an event.
@@ -1054,8 +1091,7 @@ pub struct Organizer {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "displayName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub display_name: String,
     /**
@@ -1089,6 +1125,7 @@ pub struct Organizer {
 
 /// Information about the event's reminders for the authenticated user.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Reminders {
     /**
      * Information about the event's reminders for the authenticated user.
@@ -1104,8 +1141,7 @@ pub struct Reminders {
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "useDefault"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub use_default: bool,
 }
@@ -1462,6 +1498,7 @@ pub struct Event {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct EventAttachment {
     /**
      * ETag of the collection.
@@ -1469,8 +1506,7 @@ pub struct EventAttachment {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "fileId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub file_id: String,
     /**
@@ -1479,8 +1515,7 @@ pub struct EventAttachment {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "fileUrl"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub file_url: String,
     /**
@@ -1489,8 +1524,7 @@ pub struct EventAttachment {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "iconLink"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub icon_link: String,
     /**
@@ -1499,8 +1533,7 @@ pub struct EventAttachment {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "mimeType"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub mime_type: String,
     /**
@@ -1515,6 +1548,7 @@ pub struct EventAttachment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct EventAttendee {
     /**
      * Date and time of notification channel expiration, expressed as a Unix timestamp, in milliseconds. Optional.
@@ -1522,8 +1556,7 @@ pub struct EventAttendee {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "additionalGuests"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub additional_guests: i64,
     /**
@@ -1541,8 +1574,7 @@ pub struct EventAttendee {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "displayName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub display_name: String,
     /**
@@ -1593,8 +1625,7 @@ pub struct EventAttendee {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "responseStatus"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub response_status: String,
     /**
@@ -1609,6 +1640,7 @@ pub struct EventAttendee {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct EventDateTime {
     /**
      * The date, in the format "yyyy-mm-dd", if this is an all-day event.
@@ -1626,8 +1658,7 @@ pub struct EventDateTime {
         default,
         skip_serializing_if = "Option::is_none",
         deserialize_with = "crate::utils::date_time_format::deserialize",
-        serialize_with = "crate::utils::google_calendar_date_time_format::serialize",
-        rename = "dateTime"
+        serialize_with = "crate::utils::google_calendar_date_time_format::serialize"
     )]
     pub date_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
@@ -1636,8 +1667,7 @@ pub struct EventDateTime {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "timeZone"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub time_zone: String,
 }
@@ -1665,6 +1695,7 @@ pub struct EventReminder {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Events {
     /**
      * ETag of the collection.
@@ -1672,8 +1703,7 @@ pub struct Events {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "accessRole"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub access_role: String,
     /**
@@ -1682,8 +1712,7 @@ pub struct Events {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "defaultReminders"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub default_reminders: Vec<EventReminder>,
     /**
@@ -1728,8 +1757,7 @@ pub struct Events {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub next_page_token: String,
     /**
@@ -1738,8 +1766,7 @@ pub struct Events {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextSyncToken"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub next_sync_token: String,
     /**
@@ -1757,8 +1784,7 @@ pub struct Events {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "timeZone"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub time_zone: String,
     /**
@@ -1773,6 +1799,31 @@ pub struct Events {
     pub updated: Option<chrono::DateTime<chrono::Utc>>,
 }
 
+impl IntoIterator for Events {
+    type Item = Event;
+    type IntoIter = std::vec::IntoIter<Event>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Events {
+    type Item = &'a Event;
+    type IntoIter = std::slice::Iter<'a, Event>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+impl Events {
+    /// Iterate over the `items`.
+    pub fn iter(&self) -> std::slice::Iter<'_, Event> {
+        self.items.iter()
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct FreeBusyCalendar {
     /**
@@ -1822,6 +1873,7 @@ pub struct FreeBusyGroup {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct FreeBusyRequest {
     /**
      * Date and time of notification channel expiration, expressed as a Unix timestamp, in milliseconds. Optional.
@@ -1829,8 +1881,7 @@ pub struct FreeBusyRequest {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "calendarExpansionMax"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub calendar_expansion_max: i64,
     /**
@@ -1839,8 +1890,7 @@ pub struct FreeBusyRequest {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "groupExpansionMax"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub group_expansion_max: i64,
     /**
@@ -1859,8 +1909,7 @@ pub struct FreeBusyRequest {
         default,
         skip_serializing_if = "Option::is_none",
         deserialize_with = "crate::utils::date_time_format::deserialize",
-        serialize_with = "crate::utils::google_calendar_date_time_format::serialize",
-        rename = "timeMax"
+        serialize_with = "crate::utils::google_calendar_date_time_format::serialize"
     )]
     pub time_max: Option<chrono::DateTime<chrono::Utc>>,
     /**
@@ -1870,8 +1919,7 @@ pub struct FreeBusyRequest {
         default,
         skip_serializing_if = "Option::is_none",
         deserialize_with = "crate::utils::date_time_format::deserialize",
-        serialize_with = "crate::utils::google_calendar_date_time_format::serialize",
-        rename = "timeMin"
+        serialize_with = "crate::utils::google_calendar_date_time_format::serialize"
     )]
     pub time_min: Option<chrono::DateTime<chrono::Utc>>,
     /**
@@ -1880,8 +1928,7 @@ pub struct FreeBusyRequest {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "timeZone"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub time_zone: String,
 }
@@ -1900,6 +1947,7 @@ pub struct FreeBusyRequestItem {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct FreeBusyResponse {
     /**
      * List of free/busy information for calendars.
@@ -1927,8 +1975,7 @@ pub struct FreeBusyResponse {
         default,
         skip_serializing_if = "Option::is_none",
         deserialize_with = "crate::utils::date_time_format::deserialize",
-        serialize_with = "crate::utils::google_calendar_date_time_format::serialize",
-        rename = "timeMax"
+        serialize_with = "crate::utils::google_calendar_date_time_format::serialize"
     )]
     pub time_max: Option<chrono::DateTime<chrono::Utc>>,
     /**
@@ -1938,8 +1985,7 @@ pub struct FreeBusyResponse {
         default,
         skip_serializing_if = "Option::is_none",
         deserialize_with = "crate::utils::date_time_format::deserialize",
-        serialize_with = "crate::utils::google_calendar_date_time_format::serialize",
-        rename = "timeMin"
+        serialize_with = "crate::utils::google_calendar_date_time_format::serialize"
     )]
     pub time_min: Option<chrono::DateTime<chrono::Utc>>,
 }
@@ -1985,6 +2031,7 @@ pub struct Setting {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Settings {
     /**
      * ETag of the collection.
@@ -2019,8 +2066,7 @@ pub struct Settings {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub next_page_token: String,
     /**
@@ -2029,12 +2075,36 @@ pub struct Settings {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextSyncToken"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub next_sync_token: String,
 }
 
+impl IntoIterator for Settings {
+    type Item = Setting;
+    type IntoIter = std::vec::IntoIter<Setting>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Settings {
+    type Item = &'a Setting;
+    type IntoIter = std::slice::Iter<'a, Setting>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+impl Settings {
+    /// Iterate over the `items`.
+    pub fn iter(&self) -> std::slice::Iter<'_, Setting> {
+        self.items.iter()
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TimePeriod {
     /**
@@ -2062,7 +2132,7 @@ pub struct TimePeriod {
 /**
  * Data format for the response.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Alt {
     #[serde(rename = "json")]
     Json,
@@ -2088,7 +2158,22 @@ impl Default for Alt {
         Alt::Noop
     }
 }
+
 impl Alt {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Alt::Json => "json",
+            Alt::Noop => "",
+            Alt::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Alt] {
+        &[Alt::Json]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Alt::Noop)
     }
@@ -2097,7 +2182,7 @@ impl Alt {
 /**
  * The order of the events returned in the result. Optional. The default is an unspecified, stable order.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum OrderBy {
     #[serde(rename = "startTime")]
     StartTime,
@@ -2126,7 +2211,23 @@ impl Default for OrderBy {
         OrderBy::Noop
     }
 }
+
 impl OrderBy {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrderBy::StartTime => "startTime",
+            OrderBy::Updated => "updated",
+            OrderBy::Noop => "",
+            OrderBy::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [OrderBy] {
+        &[OrderBy::StartTime, OrderBy::Updated]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, OrderBy::Noop)
     }
@@ -2135,7 +2236,7 @@ impl OrderBy {
 /**
  * Whether to send notifications about the creation of the new event. Note that some emails might still be sent. The default is false.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SendUpdates {
     #[serde(rename = "all")]
     All,
@@ -2167,7 +2268,28 @@ impl Default for SendUpdates {
         SendUpdates::Noop
     }
 }
+
 impl SendUpdates {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SendUpdates::All => "all",
+            SendUpdates::ExternalOnly => "externalOnly",
+            SendUpdates::None => "none",
+            SendUpdates::Noop => "",
+            SendUpdates::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SendUpdates] {
+        &[
+            SendUpdates::All,
+            SendUpdates::ExternalOnly,
+            SendUpdates::None,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SendUpdates::Noop)
     }
@@ -2176,7 +2298,7 @@ impl SendUpdates {
 /**
  * The minimum access role for the user in the returned entries. Optional. The default is no restriction.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum MinAccessRole {
     #[serde(rename = "freeBusyReader")]
     FreeBusyReader,
@@ -2211,8 +2333,71 @@ impl Default for MinAccessRole {
         MinAccessRole::Noop
     }
 }
+
 impl MinAccessRole {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MinAccessRole::FreeBusyReader => "freeBusyReader",
+            MinAccessRole::Owner => "owner",
+            MinAccessRole::Reader => "reader",
+            MinAccessRole::Writer => "writer",
+            MinAccessRole::Noop => "",
+            MinAccessRole::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [MinAccessRole] {
+        &[
+            MinAccessRole::FreeBusyReader,
+            MinAccessRole::Owner,
+            MinAccessRole::Reader,
+            MinAccessRole::Writer,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, MinAccessRole::Noop)
     }
 }
+
+/// The types taken and returned by the functions in [`crate::acl`].
+pub mod acl {
+    pub use super::{Acl, AclRule, Channel};
+}
+
+/// The types taken and returned by the functions in [`crate::calendar_list`].
+pub mod calendar_list {
+    pub use super::{CalendarList, CalendarListEntry, Channel, MinAccessRole};
+}
+
+/// The types taken and returned by the functions in [`crate::calendars`].
+pub mod calendars {
+    pub use super::Calendar;
+}
+
+/// The types taken and returned by the functions in [`crate::channels`].
+pub mod channels {
+    pub use super::Channel;
+}
+
+/// The types taken and returned by the functions in [`crate::colors`].
+pub mod colors {
+    pub use super::Colors;
+}
+
+/// The types taken and returned by the functions in [`crate::events`].
+pub mod events {
+    pub use super::{Channel, Event, Events, OrderBy, SendUpdates};
+}
+
+/// The types taken and returned by the functions in [`crate::freebusy`].
+pub mod freebusy {
+    pub use super::{FreeBusyRequest, FreeBusyResponse};
+}
+
+/// The types taken and returned by the functions in [`crate::settings`].
+pub mod settings {
+    pub use super::{Channel, Setting, Settings};
+}
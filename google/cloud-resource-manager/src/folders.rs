@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Folders {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Folders {
         Folders { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Folders {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/v2/folders` endpoint.
      *
@@ -31,23 +37,27 @@ impl Folders {
         parent: &str,
         show_deleted: bool,
     ) -> Result<Vec<crate::types::Folder>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("pageSize".to_string(), page_size.to_string()));
+            query_args.push(("pageSize", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if !parent.is_empty() {
-            query_args.push(("parent".to_string(), parent.to_string()));
+            query_args.push(("parent", parent.to_string()));
         }
         if show_deleted {
-            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+            query_args.push(("showDeleted", show_deleted.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v2/folders?{}", query_);
+        let url = crate::Client::url_with_query("/v2/folders", &query_args);
 
-        let resp: crate::types::ListFoldersResponse = self.client.get(&url, None).await?;
+        let resp: crate::types::ListFoldersResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/v2/folders", &[]))?;
 
         // Return our response data.
         Ok(resp.folders)
@@ -65,17 +75,20 @@ impl Folders {
         parent: &str,
         show_deleted: bool,
     ) -> Result<Vec<crate::types::Folder>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !parent.is_empty() {
-            query_args.push(("parent".to_string(), parent.to_string()));
+            query_args.push(("parent", parent.to_string()));
         }
         if show_deleted {
-            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+            query_args.push(("showDeleted", show_deleted.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v2/folders?{}", query_);
+        let url = crate::Client::url_with_query("/v2/folders", &query_args);
 
-        let mut resp: crate::types::ListFoldersResponse = self.client.get(&url, None).await?;
+        let mut resp: crate::types::ListFoldersResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/v2/folders", &[]))?;
 
         let mut folders = resp.folders;
         let mut page = resp.next_page_token;
@@ -119,18 +132,18 @@ impl Folders {
     pub async fn create(
         &self,
         parent: &str,
-        body: &crate::types::Folder,
+        body: &crate::types::FolderRequest,
     ) -> Result<crate::types::Operation> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !parent.is_empty() {
-            query_args.push(("parent".to_string(), parent.to_string()));
+            query_args.push(("parent", parent.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v2/folders?{}", query_);
+        let url = crate::Client::url_with_query("/v2/folders", &query_args);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/v2/folders", &[]))
     }
 
     /**
@@ -145,8 +158,9 @@ impl Folders {
         let url = "/v2/folders:search".to_string();
         let resp: crate::types::SearchFoldersResponse = self
             .client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await?;
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/v2/folders:search", &[]))?;
 
         // Return our response data.
         Ok(resp.folders)
@@ -164,10 +178,12 @@ impl Folders {
     pub async fn get(&self, name: &str) -> Result<crate::types::Folder> {
         let url = format!(
             "/v2/{}",
-            crate::progenitor_support::encode_path(&name.to_string()),
+            crate::progenitor_support::encode_resource_name(&name.to_string()),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/v2/{name}", &[("name", name.to_string())])
+        })
     }
 
     /**
@@ -182,10 +198,12 @@ impl Folders {
     pub async fn delete(&self, name: &str) -> Result<crate::types::Folder> {
         let url = format!(
             "/v2/{}",
-            crate::progenitor_support::encode_path(&name.to_string()),
+            crate::progenitor_support::encode_resource_name(&name.to_string()),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("DELETE", "/v2/{name}", &[("name", name.to_string())])
+        })
     }
 
     /**
@@ -202,22 +220,58 @@ impl Folders {
         &self,
         name: &str,
         update_mask: &str,
-        body: &crate::types::Folder,
+        body: &crate::types::FolderRequest,
     ) -> Result<crate::types::Folder> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !update_mask.is_empty() {
-            query_args.push(("updateMask".to_string(), update_mask.to_string()));
+            query_args.push(("updateMask", update_mask.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v2/{}?{}",
-            crate::progenitor_support::encode_path(&name.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2/{}",
+                crate::progenitor_support::encode_resource_name(&name.to_string())
+            ),
+            &query_args,
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("PATCH", "/v2/{name}", &[("name", name.to_string())])
+            })
+    }
+
+    /**
+     * This function performs a `PATCH` to the `/v2/{name}` endpoint.
+     *
+     * As opposed to `patch`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_if_changed<C: serde::Serialize>(
+        &self,
+        name: &str,
+        update_mask: &str,
+        current: &C,
+        body: &crate::types::FolderRequest,
+    ) -> Result<Option<crate::types::Folder>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !update_mask.is_empty() {
+            query_args.push(("updateMask", update_mask.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v2/{}",
+                crate::progenitor_support::encode_resource_name(&name.to_string())
+            ),
+            &query_args,
+        );
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url("PATCH", "/v2/{name}", &[("name", name.to_string())])
+            })
     }
 
     /**
@@ -236,12 +290,15 @@ impl Folders {
     ) -> Result<crate::types::Operation> {
         let url = format!(
             "/v2/{}/move",
-            crate::progenitor_support::encode_path(&name.to_string()),
+            crate::progenitor_support::encode_resource_name(&name.to_string()),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/v2/{name}:move", &[("name", name.to_string())])
+            })
     }
 
     /**
@@ -260,12 +317,19 @@ impl Folders {
     ) -> Result<crate::types::Folder> {
         let url = format!(
             "/v2/{}/undelete",
-            crate::progenitor_support::encode_path(&name.to_string()),
+            crate::progenitor_support::encode_resource_name(&name.to_string()),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2/{name}:undelete",
+                    &[("name", name.to_string())],
+                )
+            })
     }
 
     /**
@@ -284,12 +348,19 @@ impl Folders {
     ) -> Result<crate::types::Policy> {
         let url = format!(
             "/v2/{}/getIamPolicy",
-            crate::progenitor_support::encode_path(&resource.to_string()),
+            crate::progenitor_support::encode_resource_name(&resource.to_string()),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2/{resource}:getIamPolicy",
+                    &[("resource", resource.to_string())],
+                )
+            })
     }
 
     /**
@@ -308,12 +379,19 @@ impl Folders {
     ) -> Result<crate::types::Policy> {
         let url = format!(
             "/v2/{}/setIamPolicy",
-            crate::progenitor_support::encode_path(&resource.to_string()),
+            crate::progenitor_support::encode_resource_name(&resource.to_string()),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2/{resource}:setIamPolicy",
+                    &[("resource", resource.to_string())],
+                )
+            })
     }
 
     /**
@@ -332,11 +410,160 @@ impl Folders {
     ) -> Result<crate::types::TestIamPermissionsResponse> {
         let url = format!(
             "/v2/{}/testIamPermissions",
-            crate::progenitor_support::encode_path(&resource.to_string()),
+            crate::progenitor_support::encode_resource_name(&resource.to_string()),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v2/{resource}:testIamPermissions",
+                    &[("resource", resource.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`Folders`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn FoldersApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait FoldersApi: Send + Sync {
+    async fn list(
+        &self,
+        page_size: i64,
+        page_token: &str,
+        parent: &str,
+        show_deleted: bool,
+    ) -> Result<Vec<crate::types::Folder>>;
+    async fn list_all(&self, parent: &str, show_deleted: bool)
+        -> Result<Vec<crate::types::Folder>>;
+    async fn create(
+        &self,
+        parent: &str,
+        body: &crate::types::FolderRequest,
+    ) -> Result<crate::types::Operation>;
+    async fn search(
+        &self,
+        body: &crate::types::SearchFoldersRequest,
+    ) -> Result<Vec<crate::types::Folder>>;
+    async fn get(&self, name: &str) -> Result<crate::types::Folder>;
+    async fn delete(&self, name: &str) -> Result<crate::types::Folder>;
+    async fn patch(
+        &self,
+        name: &str,
+        update_mask: &str,
+        body: &crate::types::FolderRequest,
+    ) -> Result<crate::types::Folder>;
+    async fn mv(
+        &self,
+        name: &str,
+        body: &crate::types::MoveFolderRequest,
+    ) -> Result<crate::types::Operation>;
+    async fn undelete(
+        &self,
+        name: &str,
+        body: &crate::types::MoveProjectMetadata,
+    ) -> Result<crate::types::Folder>;
+    async fn get_iam_policy(
+        &self,
+        resource: &str,
+        body: &crate::types::GetIamPolicyRequest,
+    ) -> Result<crate::types::Policy>;
+    async fn set_iam_policy(
+        &self,
+        resource: &str,
+        body: &crate::types::SetIamPolicyRequest,
+    ) -> Result<crate::types::Policy>;
+    async fn test_iam_permissions(
+        &self,
+        resource: &str,
+        body: &crate::types::TestIamPermissionsRequest,
+    ) -> Result<crate::types::TestIamPermissionsResponse>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl FoldersApi for Folders {
+    async fn list(
+        &self,
+        page_size: i64,
+        page_token: &str,
+        parent: &str,
+        show_deleted: bool,
+    ) -> Result<Vec<crate::types::Folder>> {
+        Folders::list(self, page_size, page_token, parent, show_deleted).await
+    }
+    async fn list_all(
+        &self,
+        parent: &str,
+        show_deleted: bool,
+    ) -> Result<Vec<crate::types::Folder>> {
+        Folders::list_all(self, parent, show_deleted).await
+    }
+    async fn create(
+        &self,
+        parent: &str,
+        body: &crate::types::FolderRequest,
+    ) -> Result<crate::types::Operation> {
+        Folders::create(self, parent, body).await
+    }
+    async fn search(
+        &self,
+        body: &crate::types::SearchFoldersRequest,
+    ) -> Result<Vec<crate::types::Folder>> {
+        Folders::search(self, body).await
+    }
+    async fn get(&self, name: &str) -> Result<crate::types::Folder> {
+        Folders::get(self, name).await
+    }
+    async fn delete(&self, name: &str) -> Result<crate::types::Folder> {
+        Folders::delete(self, name).await
+    }
+    async fn patch(
+        &self,
+        name: &str,
+        update_mask: &str,
+        body: &crate::types::FolderRequest,
+    ) -> Result<crate::types::Folder> {
+        Folders::patch(self, name, update_mask, body).await
+    }
+    async fn mv(
+        &self,
+        name: &str,
+        body: &crate::types::MoveFolderRequest,
+    ) -> Result<crate::types::Operation> {
+        Folders::mv(self, name, body).await
+    }
+    async fn undelete(
+        &self,
+        name: &str,
+        body: &crate::types::MoveProjectMetadata,
+    ) -> Result<crate::types::Folder> {
+        Folders::undelete(self, name, body).await
+    }
+    async fn get_iam_policy(
+        &self,
+        resource: &str,
+        body: &crate::types::GetIamPolicyRequest,
+    ) -> Result<crate::types::Policy> {
+        Folders::get_iam_policy(self, resource, body).await
+    }
+    async fn set_iam_policy(
+        &self,
+        resource: &str,
+        body: &crate::types::SetIamPolicyRequest,
+    ) -> Result<crate::types::Policy> {
+        Folders::set_iam_policy(self, resource, body).await
+    }
+    async fn test_iam_permissions(
+        &self,
+        resource: &str,
+        body: &crate::types::TestIamPermissionsRequest,
+    ) -> Result<crate::types::TestIamPermissionsResponse> {
+        Folders::test_iam_permissions(self, resource, body).await
     }
 }
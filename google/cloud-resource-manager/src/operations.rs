@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Operations {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Operations {
         Operations { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Operations {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/v1/{name}` endpoint.
      *
@@ -24,9 +30,28 @@ impl Operations {
     pub async fn get(&self, name: &str) -> Result<crate::types::Operation> {
         let url = format!(
             "/v1/{}",
-            crate::progenitor_support::encode_path(&name.to_string()),
+            crate::progenitor_support::encode_resource_name(&name.to_string()),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/v1/{name}", &[("name", name.to_string())])
+        })
+    }
+}
+
+/// The functions of [`Operations`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn OperationsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait OperationsApi: Send + Sync {
+    async fn get(&self, name: &str) -> Result<crate::types::Operation>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl OperationsApi for Operations {
+    async fn get(&self, name: &str) -> Result<crate::types::Operation> {
+        Operations::get(self, name).await
     }
 }
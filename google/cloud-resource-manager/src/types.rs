@@ -2,8 +2,36 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+/// Either one of something, or many of them. Some endpoints send back an
+/// object when there is one and an array when there are more, so this takes both.
+// Many is tried first: serde reads a struct from an array of its fields too,
+// so an array would otherwise be taken for One.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum OneOrMany<T> {
+    Many(Vec<T>),
+    One(T),
+}
+
+impl<T> OneOrMany<T> {
+    /// All of them, as a vector.
+    pub fn into_vec(self) -> Vec<T> {
+        match self {
+            OneOrMany::One(t) => vec![t],
+            OneOrMany::Many(v) => v,
+        }
+    }
+}
+
+impl<T> Default for OneOrMany<T> {
+    fn default() -> Self {
+        OneOrMany::Many(Vec::new())
+    }
+}
+
 /// Specifies the audit configuration for a service. The configuration determines which permission types are logged, and what identities, if any, are exempted from logging. An AuditConfig must have one or more AuditLogConfigs. If there are AuditConfigs for both `allServices` and a specific service, the union of the two AuditConfigs is used for that service: the log_types specified in each AuditConfig are enabled, and the exempted_members in each AuditLogConfig are exempted. Example Policy with multiple AuditConfigs: { "audit_configs": [ { "service": "allServices", "audit_log_configs": [ { "log_type": "DATA_READ", "exempted_members": [ "user:jose@example.com" ] }, { "log_type": "DATA_WRITE" }, { "log_type": "ADMIN_READ" } ] }, { "service": "sampleservice.googleapis.com", "audit_log_configs": [ { "log_type": "DATA_READ" }, { "log_type": "DATA_WRITE", "exempted_members": [ "user:aliya@example.com" ] } ] } ] } For sampleservice, this policy enables DATA_READ, DATA_WRITE and ADMIN_READ logging. It also exempts jose@example.com from DATA_READ logging, and aliya@example.com from DATA_WRITE logging.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct AuditConfig {
     /**
      * Specifies the audit configuration for a service. The configuration determines which permission types are logged, and what identities, if any, are exempted from logging. An AuditConfig must have one or more AuditLogConfigs. If there are AuditConfigs for both `allServices` and a specific service, the union of the two AuditConfigs is used for that service: the log_types specified in each AuditConfig are enabled, and the exempted_members in each AuditLogConfig are exempted. Example Policy with multiple AuditConfigs: { "audit_configs": [ { "service": "allServices", "audit_log_configs": [ { "log_type": "DATA_READ", "exempted_members": [ "user:jose@example.com" ] }, { "log_type": "DATA_WRITE" }, { "log_type": "ADMIN_READ" } ] }, { "service": "sampleservice.googleapis.com", "audit_log_configs": [ { "log_type": "DATA_READ" }, { "log_type": "DATA_WRITE", "exempted_members": [ "user:aliya@example.com" ] } ] } ] } For sampleservice, this policy enables DATA_READ, DATA_WRITE and ADMIN_READ logging. It also exempts jose@example.com from DATA_READ logging, and aliya@example.com from DATA_WRITE logging.
@@ -11,8 +39,7 @@ pub struct AuditConfig {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "auditLogConfigs"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub audit_log_configs: Vec<AuditLogConfig>,
     /**
@@ -29,7 +56,7 @@ pub struct AuditConfig {
 /**
  * The log type that this config enables.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum LogType {
     #[serde(rename = "ADMIN_READ")]
     AdminRead,
@@ -64,7 +91,30 @@ impl Default for LogType {
         LogType::Noop
     }
 }
+
 impl LogType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LogType::AdminRead => "ADMIN_READ",
+            LogType::DataRead => "DATA_READ",
+            LogType::DataWrite => "DATA_WRITE",
+            LogType::LogTypeUnspecified => "LOG_TYPE_UNSPECIFIED",
+            LogType::Noop => "",
+            LogType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [LogType] {
+        &[
+            LogType::AdminRead,
+            LogType::DataRead,
+            LogType::DataWrite,
+            LogType::LogTypeUnspecified,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, LogType::Noop)
     }
@@ -72,6 +122,7 @@ impl LogType {
 
 /// Provides the configuration for logging a type of permissions. Example: { "audit_log_configs": [ { "log_type": "DATA_READ", "exempted_members": [ "user:jose@example.com" ] }, { "log_type": "DATA_WRITE" } ] } This enables 'DATA_READ' and 'DATA_WRITE' logging, while exempting jose@example.com from DATA_READ logging.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct AuditLogConfig {
     /**
      * Provides the configuration for logging a type of permissions. Example: { "audit_log_configs": [ { "log_type": "DATA_READ", "exempted_members": [ "user:jose@example.com" ] }, { "log_type": "DATA_WRITE" } ] } This enables 'DATA_READ' and 'DATA_WRITE' logging, while exempting jose@example.com from DATA_READ logging.
@@ -79,14 +130,13 @@ pub struct AuditLogConfig {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "exemptedMembers"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub exempted_members: Vec<String>,
     /**
      * Provides the configuration for logging a type of permissions. Example: { "audit_log_configs": [ { "log_type": "DATA_READ", "exempted_members": [ "user:jose@example.com" ] }, { "log_type": "DATA_WRITE" } ] } This enables 'DATA_READ' and 'DATA_WRITE' logging, while exempting jose@example.com from DATA_READ logging.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "logType")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub log_type: Option<LogType>,
 }
 
@@ -121,7 +171,7 @@ pub struct Binding {
 /**
  * The type of this operation.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum OperationType {
     #[serde(rename = "CREATE")]
     Create,
@@ -153,7 +203,28 @@ impl Default for OperationType {
         OperationType::Noop
     }
 }
+
 impl OperationType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OperationType::Create => "CREATE",
+            OperationType::Move => "MOVE",
+            OperationType::OperationTypeUnspecified => "OPERATION_TYPE_UNSPECIFIED",
+            OperationType::Noop => "",
+            OperationType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [OperationType] {
+        &[
+            OperationType::Create,
+            OperationType::Move,
+            OperationType::OperationTypeUnspecified,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, OperationType::Noop)
     }
@@ -161,6 +232,7 @@ impl OperationType {
 
 /// Metadata describing a long running folder operation
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct FolderOperation {
     /**
      * Metadata describing a long running folder operation
@@ -168,8 +240,7 @@ pub struct FolderOperation {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "destinationParent"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub destination_parent: String,
     /**
@@ -178,18 +249,13 @@ pub struct FolderOperation {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "displayName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub display_name: String,
     /**
      * Metadata describing a long running folder operation
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "operationType"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub operation_type: Option<OperationType>,
     /**
      * Metadata describing a long running folder operation
@@ -197,14 +263,14 @@ pub struct FolderOperation {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "sourceParent"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub source_parent: String,
 }
 
 /// Metadata pertaining to the Folder creation process.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct CreateFolderMetadata {
     /**
      * Metadata pertaining to the Folder creation process.
@@ -212,8 +278,7 @@ pub struct CreateFolderMetadata {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "displayName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub display_name: String,
     /**
@@ -229,6 +294,7 @@ pub struct CreateFolderMetadata {
 
 /// A status object which is used as the `metadata` field for the Operation returned by CreateProject. It provides insight for when significant phases of Project creation have completed.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct CreateProjectMetadata {
     /**
      * A status object which is used as the `metadata` field for the Operation returned by CreateProject. It provides insight for when significant phases of Project creation have completed.
@@ -236,8 +302,7 @@ pub struct CreateProjectMetadata {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "createTime"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub create_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
@@ -306,7 +371,7 @@ pub struct Expr {
 /**
  * Output only. The lifecycle state of the folder. Updates to the lifecycle_state must be performed via DeleteFolder and UndeleteFolder.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum LifecycleState {
     #[serde(rename = "ACTIVE")]
     Active,
@@ -338,7 +403,28 @@ impl Default for LifecycleState {
         LifecycleState::Noop
     }
 }
+
 impl LifecycleState {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LifecycleState::Active => "ACTIVE",
+            LifecycleState::DeleteRequested => "DELETE_REQUESTED",
+            LifecycleState::LifecycleStateUnspecified => "LIFECYCLE_STATE_UNSPECIFIED",
+            LifecycleState::Noop => "",
+            LifecycleState::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [LifecycleState] {
+        &[
+            LifecycleState::Active,
+            LifecycleState::DeleteRequested,
+            LifecycleState::LifecycleStateUnspecified,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, LifecycleState::Noop)
     }
@@ -346,6 +432,7 @@ impl LifecycleState {
 
 /// A Folder in an Organization's resource hierarchy, used to organize that Organization's resources.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Folder {
     /**
      * A Folder in an Organization's resource hierarchy, used to organize that Organization's resources.
@@ -353,8 +440,7 @@ pub struct Folder {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "createTime"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub create_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
@@ -363,18 +449,13 @@ pub struct Folder {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "displayName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub display_name: String,
     /**
      * A Folder in an Organization's resource hierarchy, used to organize that Organization's resources.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "lifecycleState"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub lifecycle_state: Option<LifecycleState>,
     /**
      * A Folder in an Organization's resource hierarchy, used to organize that Organization's resources.
@@ -399,7 +480,7 @@ pub struct Folder {
 /**
  * The type of operation error experienced.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ErrorMessageId {
     #[serde(rename = "ACTIVE_FOLDER_HEIGHT_VIOLATION")]
     ActiveFolderHeightViolation,
@@ -454,7 +535,44 @@ impl Default for ErrorMessageId {
         ErrorMessageId::Noop
     }
 }
+
 impl ErrorMessageId {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorMessageId::ActiveFolderHeightViolation => "ACTIVE_FOLDER_HEIGHT_VIOLATION",
+            ErrorMessageId::CycleIntroducedViolation => "CYCLE_INTRODUCED_VIOLATION",
+            ErrorMessageId::DeletedFolderHeightViolation => "DELETED_FOLDER_HEIGHT_VIOLATION",
+            ErrorMessageId::ErrorTypeUnspecified => "ERROR_TYPE_UNSPECIFIED",
+            ErrorMessageId::FolderBeingMovedViolation => "FOLDER_BEING_MOVED_VIOLATION",
+            ErrorMessageId::FolderNameUniquenessViolation => "FOLDER_NAME_UNIQUENESS_VIOLATION",
+            ErrorMessageId::FolderToDeleteNonEmptyViolation => {
+                "FOLDER_TO_DELETE_NON_EMPTY_VIOLATION"
+            }
+            ErrorMessageId::MaxChildFoldersViolation => "MAX_CHILD_FOLDERS_VIOLATION",
+            ErrorMessageId::ParentDeletedViolation => "PARENT_DELETED_VIOLATION",
+            ErrorMessageId::ResourceDeletedViolation => "RESOURCE_DELETED_VIOLATION",
+            ErrorMessageId::Noop => "",
+            ErrorMessageId::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ErrorMessageId] {
+        &[
+            ErrorMessageId::ActiveFolderHeightViolation,
+            ErrorMessageId::CycleIntroducedViolation,
+            ErrorMessageId::DeletedFolderHeightViolation,
+            ErrorMessageId::ErrorTypeUnspecified,
+            ErrorMessageId::FolderBeingMovedViolation,
+            ErrorMessageId::FolderNameUniquenessViolation,
+            ErrorMessageId::FolderToDeleteNonEmptyViolation,
+            ErrorMessageId::MaxChildFoldersViolation,
+            ErrorMessageId::ParentDeletedViolation,
+            ErrorMessageId::ResourceDeletedViolation,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ErrorMessageId::Noop)
     }
@@ -462,15 +580,12 @@ impl ErrorMessageId {
 
 /// A classification of the Folder Operation error.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct FolderOperationError {
     /**
      * A classification of the Folder Operation error.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "errorMessageId"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub error_message_id: Option<ErrorMessageId>,
 }
 
@@ -486,6 +601,7 @@ pub struct GetIamPolicyRequest {
 
 /// Encapsulates settings provided to GetIamPolicy.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct GetPolicyOptions {
     /**
      * Encapsulates settings provided to GetIamPolicy.
@@ -493,14 +609,14 @@ pub struct GetPolicyOptions {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "requestedPolicyVersion"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub requested_policy_version: i64,
 }
 
 /// The ListFolders response message.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ListFoldersResponse {
     /**
      * The ListFolders response message.
@@ -517,14 +633,39 @@ pub struct ListFoldersResponse {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub next_page_token: String,
 }
 
+impl IntoIterator for ListFoldersResponse {
+    type Item = Folder;
+    type IntoIter = std::vec::IntoIter<Folder>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.folders.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ListFoldersResponse {
+    type Item = &'a Folder;
+    type IntoIter = std::slice::Iter<'a, Folder>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.folders.iter()
+    }
+}
+
+impl ListFoldersResponse {
+    /// Iterate over the `folders`.
+    pub fn iter(&self) -> std::slice::Iter<'_, Folder> {
+        self.folders.iter()
+    }
+}
+
 /// Metadata pertaining to the folder move process.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct MoveFolderMetadata {
     /**
      * Metadata pertaining to the folder move process.
@@ -532,8 +673,7 @@ pub struct MoveFolderMetadata {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "destinationParent"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub destination_parent: String,
     /**
@@ -542,8 +682,7 @@ pub struct MoveFolderMetadata {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "displayName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub display_name: String,
     /**
@@ -552,14 +691,14 @@ pub struct MoveFolderMetadata {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "sourceParent"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub source_parent: String,
 }
 
 /// The MoveFolder request message.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct MoveFolderRequest {
     /**
      * The MoveFolder request message.
@@ -567,8 +706,7 @@ pub struct MoveFolderRequest {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "destinationParent"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub destination_parent: String,
 }
@@ -612,6 +750,7 @@ pub struct Operation {
 
 /// An Identity and Access Management (IAM) policy, which specifies access controls for Google Cloud resources. A `Policy` is a collection of `bindings`. A `binding` binds one or more `members` to a single `role`. Members can be user accounts, service accounts, Google groups, and domains (such as G Suite). A `role` is a named list of permissions; each `role` can be an IAM predefined role or a user-created custom role. For some types of Google Cloud resources, a `binding` can also specify a `condition`, which is a logical expression that allows access to a resource only if the expression evaluates to `true`. A condition can add constraints based on attributes of the request, the resource, or both. To learn which resources support conditions in their IAM policies, see the [IAM documentation](https://cloud.google.com/iam/help/conditions/resource-policies). **JSON example:** { "bindings": [ { "role": "roles/resourcemanager.organizationAdmin", "members": [ "user:mike@example.com", "group:admins@example.com", "domain:google.com", "serviceAccount:my-project-id@appspot.gserviceaccount.com" ] }, { "role": "roles/resourcemanager.organizationViewer", "members": [ "user:eve@example.com" ], "condition": { "title": "expirable access", "description": "Does not grant access after Sep 2020", "expression": "request.time < timestamp('2020-10-01T00:00:00.000Z')", } } ], "etag": "BwWWja0YfJA=", "version": 3 } **YAML example:** bindings: - members: - user:mike@example.com - group:admins@example.com - domain:google.com - serviceAccount:my-project-id@appspot.gserviceaccount.com role: roles/resourcemanager.organizationAdmin - members: - user:eve@example.com role: roles/resourcemanager.organizationViewer condition: title: expirable access description: Does not grant access after Sep 2020 expression: request.time < timestamp('2020-10-01T00:00:00.000Z') etag: BwWWja0YfJA= version: 3 For a description of IAM and its features, see the [IAM documentation](https://cloud.google.com/iam/docs/).
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Policy {
     /**
      * An Identity and Access Management (IAM) policy, which specifies access controls for Google Cloud resources. A `Policy` is a collection of `bindings`. A `binding` binds one or more `members` to a single `role`. Members can be user accounts, service accounts, Google groups, and domains (such as G Suite). A `role` is a named list of permissions; each `role` can be an IAM predefined role or a user-created custom role. For some types of Google Cloud resources, a `binding` can also specify a `condition`, which is a logical expression that allows access to a resource only if the expression evaluates to `true`. A condition can add constraints based on attributes of the request, the resource, or both. To learn which resources support conditions in their IAM policies, see the [IAM documentation](https://cloud.google.com/iam/help/conditions/resource-policies). \*\*JSON example:\*\* { "bindings": [ { "role": "roles/resourcemanager.organizationAdmin", "members": [ "user:mike@example.com", "group:admins@example.com", "domain:google.com", "serviceAccount:my-project-id@appspot.gserviceaccount.com" ] }, { "role": "roles/resourcemanager.organizationViewer", "members": [ "user:eve@example.com" ], "condition": { "title": "expirable access", "description": "Does not grant access after Sep 2020", "expression": "request.time < timestamp('2020-10-01T00:00:00.000Z')", } } ], "etag": "BwWWja0YfJA=", "version": 3 } \*\*YAML example:\*\* bindings: - members: - user:mike@example.com - group:admins@example.com - domain:google.com - serviceAccount:my-project-id@appspot.gserviceaccount.com role: roles/resourcemanager.organizationAdmin - members: - user:eve@example.com role: roles/resourcemanager.organizationViewer condition: title: expirable access description: Does not grant access after Sep 2020 expression: request.time < timestamp('2020-10-01T00:00:00.000Z') etag: BwWWja0YfJA= version: 3 For a description of IAM and its features, see the [IAM documentation](https://cloud.google.com/iam/docs/).
@@ -619,8 +758,7 @@ pub struct Policy {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "auditConfigs"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub audit_configs: Vec<AuditConfig>,
     /**
@@ -650,6 +788,7 @@ pub struct Policy {
 
 /// The request message for searching folders.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct SearchFoldersRequest {
     /**
      * The request message for searching folders.
@@ -657,8 +796,7 @@ pub struct SearchFoldersRequest {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "pageSize"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub page_size: i64,
     /**
@@ -667,8 +805,7 @@ pub struct SearchFoldersRequest {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "pageToken"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub page_token: String,
     /**
@@ -684,6 +821,7 @@ pub struct SearchFoldersRequest {
 
 /// The response message for searching folders.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct SearchFoldersResponse {
     /**
      * The response message for searching folders.
@@ -700,14 +838,39 @@ pub struct SearchFoldersResponse {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub next_page_token: String,
 }
 
+impl IntoIterator for SearchFoldersResponse {
+    type Item = Folder;
+    type IntoIter = std::vec::IntoIter<Folder>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.folders.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a SearchFoldersResponse {
+    type Item = &'a Folder;
+    type IntoIter = std::slice::Iter<'a, Folder>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.folders.iter()
+    }
+}
+
+impl SearchFoldersResponse {
+    /// Iterate over the `folders`.
+    pub fn iter(&self) -> std::slice::Iter<'_, Folder> {
+        self.folders.iter()
+    }
+}
+
 /// Request message for `SetIamPolicy` method.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct SetIamPolicyRequest {
     /**
      * Request message for `SetIamPolicy` method.
@@ -720,8 +883,7 @@ pub struct SetIamPolicyRequest {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "updateMask"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub update_mask: String,
 }
@@ -789,7 +951,7 @@ pub struct TestIamPermissionsResponse {
 /**
  * V1 error format.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Xgafv {
     #[serde(rename = "1")]
     One,
@@ -818,7 +980,23 @@ impl Default for Xgafv {
         Xgafv::Noop
     }
 }
+
 impl Xgafv {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Xgafv::One => "1",
+            Xgafv::Two => "2",
+            Xgafv::Noop => "",
+            Xgafv::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Xgafv] {
+        &[Xgafv::One, Xgafv::Two]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Xgafv::Noop)
     }
@@ -827,7 +1005,7 @@ impl Xgafv {
 /**
  * Data format for response.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Alt {
     #[serde(rename = "json")]
     Json,
@@ -859,8 +1037,65 @@ impl Default for Alt {
         Alt::Noop
     }
 }
+
 impl Alt {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Alt::Json => "json",
+            Alt::Media => "media",
+            Alt::Proto => "proto",
+            Alt::Noop => "",
+            Alt::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Alt] {
+        &[Alt::Json, Alt::Media, Alt::Proto]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Alt::Noop)
     }
 }
+
+/// A Folder in an Organization's resource hierarchy, used to organize that Organization's resources.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FolderRequest {
+    /**
+     * A Folder in an Organization's resource hierarchy, used to organize that Organization's resources.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub display_name: String,
+    /**
+     * A Folder in an Organization's resource hierarchy, used to organize that Organization's resources.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub parent: String,
+}
+
+/// The types taken and returned by the functions in [`crate::folders`].
+pub mod folders {
+    pub use super::{
+        Folder, FolderRequest, GetIamPolicyRequest, ListFoldersResponse, MoveFolderRequest,
+        MoveProjectMetadata, Operation, Policy, SearchFoldersRequest, SearchFoldersResponse,
+        SetIamPolicyRequest, TestIamPermissionsRequest, TestIamPermissionsResponse,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::operations`].
+pub mod operations {
+    pub use super::Operation;
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct About {
     pub client: Client,
@@ -12,6 +10,14 @@ impl About {
         About { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        About {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/about` endpoint.
      *
@@ -19,6 +25,26 @@ impl About {
      */
     pub async fn get(&self) -> Result<crate::types::About> {
         let url = "/about".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/about", &[]))
+    }
+}
+
+/// The functions of [`About`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AboutApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AboutApi: Send + Sync {
+    async fn get(&self) -> Result<crate::types::About>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AboutApi for About {
+    async fn get(&self) -> Result<crate::types::About> {
+        About::get(self).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Changes {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Changes {
         Changes { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Changes {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/changes` endpoint.
      *
@@ -49,76 +55,149 @@ impl Changes {
         supports_team_drives: bool,
         team_drive_id: &str,
     ) -> Result<Vec<crate::types::Change>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !drive_id.is_empty() {
-            query_args.push(("driveId".to_string(), drive_id.to_string()));
+            query_args.push(("driveId", drive_id.to_string()));
         }
         if include_corpus_removals {
-            query_args.push((
-                "includeCorpusRemovals".to_string(),
-                include_corpus_removals.to_string(),
-            ));
+            query_args.push(("includeCorpusRemovals", include_corpus_removals.to_string()));
         }
         if include_items_from_all_drives {
             query_args.push((
-                "includeItemsFromAllDrives".to_string(),
+                "includeItemsFromAllDrives",
                 include_items_from_all_drives.to_string(),
             ));
         }
         if !include_permissions_for_view.is_empty() {
             query_args.push((
-                "includePermissionsForView".to_string(),
+                "includePermissionsForView",
                 include_permissions_for_view.to_string(),
             ));
         }
         if include_removed {
-            query_args.push(("includeRemoved".to_string(), include_removed.to_string()));
+            query_args.push(("includeRemoved", include_removed.to_string()));
         }
         if include_team_drive_items {
             query_args.push((
-                "includeTeamDriveItems".to_string(),
+                "includeTeamDriveItems",
                 include_team_drive_items.to_string(),
             ));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("pageSize".to_string(), page_size.to_string()));
+            query_args.push(("pageSize", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if restrict_to_my_drive {
-            query_args.push((
-                "restrictToMyDrive".to_string(),
-                restrict_to_my_drive.to_string(),
-            ));
+            query_args.push(("restrictToMyDrive", restrict_to_my_drive.to_string()));
         }
         if !spaces.is_empty() {
-            query_args.push(("spaces".to_string(), spaces.to_string()));
+            query_args.push(("spaces", spaces.to_string()));
         }
         if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
         }
         if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
         }
         if !team_drive_id.is_empty() {
-            query_args.push(("teamDriveId".to_string(), team_drive_id.to_string()));
+            query_args.push(("teamDriveId", team_drive_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/changes?{}", query_);
+        let url = crate::Client::url_with_query("/changes", &query_args);
 
-        let resp: crate::types::ChangeList = self.client.get(&url, None).await?;
+        let resp: crate::types::ChangeList = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/changes", &[]))?;
 
         // Return our response data.
         Ok(resp.changes)
     }
 
+    /**
+     * This function performs a `GET` to the `/changes` endpoint.
+     *
+     * As opposed to `list`, this function streams the items as they are parsed from the response, rather than returning them all at once.
+     * This is useful for very large responses that would otherwise need to be held in memory.
+     * Only the one page the parameters ask for is streamed, the next pages are not fetched.
+     */
+    pub async fn list_stream_json(
+        &self,
+        page_token: &str,
+        drive_id: &str,
+        include_corpus_removals: bool,
+        include_items_from_all_drives: bool,
+        include_permissions_for_view: &str,
+        include_removed: bool,
+        include_team_drive_items: bool,
+        page_size: i64,
+        restrict_to_my_drive: bool,
+        spaces: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        team_drive_id: &str,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::Change>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !drive_id.is_empty() {
+            query_args.push(("driveId", drive_id.to_string()));
+        }
+        if include_corpus_removals {
+            query_args.push(("includeCorpusRemovals", include_corpus_removals.to_string()));
+        }
+        if include_items_from_all_drives {
+            query_args.push((
+                "includeItemsFromAllDrives",
+                include_items_from_all_drives.to_string(),
+            ));
+        }
+        if !include_permissions_for_view.is_empty() {
+            query_args.push((
+                "includePermissionsForView",
+                include_permissions_for_view.to_string(),
+            ));
+        }
+        if include_removed {
+            query_args.push(("includeRemoved", include_removed.to_string()));
+        }
+        if include_team_drive_items {
+            query_args.push((
+                "includeTeamDriveItems",
+                include_team_drive_items.to_string(),
+            ));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("pageSize", page_size.to_string()));
+        }
+        if !page_token.is_empty() {
+            query_args.push(("pageToken", page_token.to_string()));
+        }
+        if restrict_to_my_drive {
+            query_args.push(("restrictToMyDrive", restrict_to_my_drive.to_string()));
+        }
+        if !spaces.is_empty() {
+            query_args.push(("spaces", spaces.to_string()));
+        }
+        if supports_all_drives {
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
+        }
+        if supports_team_drives {
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
+        }
+        if !team_drive_id.is_empty() {
+            query_args.push(("teamDriveId", team_drive_id.to_string()));
+        }
+        let url = crate::Client::url_with_query("/changes", &query_args);
+
+        self.client
+            .get_stream_json(&url, "changes")
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/changes", &[]))
+    }
+
     /**
      * This function performs a `GET` to the `/changes` endpoint.
      *
@@ -140,65 +219,56 @@ impl Changes {
         supports_team_drives: bool,
         team_drive_id: &str,
     ) -> Result<Vec<crate::types::Change>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !drive_id.is_empty() {
-            query_args.push(("driveId".to_string(), drive_id.to_string()));
+            query_args.push(("driveId", drive_id.to_string()));
         }
         if include_corpus_removals {
-            query_args.push((
-                "includeCorpusRemovals".to_string(),
-                include_corpus_removals.to_string(),
-            ));
+            query_args.push(("includeCorpusRemovals", include_corpus_removals.to_string()));
         }
         if include_items_from_all_drives {
             query_args.push((
-                "includeItemsFromAllDrives".to_string(),
+                "includeItemsFromAllDrives",
                 include_items_from_all_drives.to_string(),
             ));
         }
         if !include_permissions_for_view.is_empty() {
             query_args.push((
-                "includePermissionsForView".to_string(),
+                "includePermissionsForView",
                 include_permissions_for_view.to_string(),
             ));
         }
         if include_removed {
-            query_args.push(("includeRemoved".to_string(), include_removed.to_string()));
+            query_args.push(("includeRemoved", include_removed.to_string()));
         }
         if include_team_drive_items {
             query_args.push((
-                "includeTeamDriveItems".to_string(),
+                "includeTeamDriveItems",
                 include_team_drive_items.to_string(),
             ));
         }
         if restrict_to_my_drive {
-            query_args.push((
-                "restrictToMyDrive".to_string(),
-                restrict_to_my_drive.to_string(),
-            ));
+            query_args.push(("restrictToMyDrive", restrict_to_my_drive.to_string()));
         }
         if !spaces.is_empty() {
-            query_args.push(("spaces".to_string(), spaces.to_string()));
+            query_args.push(("spaces", spaces.to_string()));
         }
         if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
         }
         if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
         }
         if !team_drive_id.is_empty() {
-            query_args.push(("teamDriveId".to_string(), team_drive_id.to_string()));
+            query_args.push(("teamDriveId", team_drive_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/changes?{}", query_);
+        let url = crate::Client::url_with_query("/changes", &query_args);
 
-        let mut resp: crate::types::ChangeList = self.client.get(&url, None).await?;
+        let mut resp: crate::types::ChangeList = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/changes", &[]))?;
 
         let mut changes = resp.changes;
         let mut page = resp.next_page_token;
@@ -249,29 +319,25 @@ impl Changes {
         supports_team_drives: bool,
         team_drive_id: &str,
     ) -> Result<crate::types::StartPageToken> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !drive_id.is_empty() {
-            query_args.push(("driveId".to_string(), drive_id.to_string()));
+            query_args.push(("driveId", drive_id.to_string()));
         }
         if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
         }
         if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
         }
         if !team_drive_id.is_empty() {
-            query_args.push(("teamDriveId".to_string(), team_drive_id.to_string()));
+            query_args.push(("teamDriveId", team_drive_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/changes/startPageToken?{}", query_);
+        let url = crate::Client::url_with_query("/changes/startPageToken", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/changes/startPageToken", &[]))
     }
 
     /**
@@ -312,72 +378,244 @@ impl Changes {
         team_drive_id: &str,
         body: &crate::types::Channel,
     ) -> Result<crate::types::Channel> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !drive_id.is_empty() {
-            query_args.push(("driveId".to_string(), drive_id.to_string()));
+            query_args.push(("driveId", drive_id.to_string()));
         }
         if include_corpus_removals {
-            query_args.push((
-                "includeCorpusRemovals".to_string(),
-                include_corpus_removals.to_string(),
-            ));
+            query_args.push(("includeCorpusRemovals", include_corpus_removals.to_string()));
         }
         if include_items_from_all_drives {
             query_args.push((
-                "includeItemsFromAllDrives".to_string(),
+                "includeItemsFromAllDrives",
                 include_items_from_all_drives.to_string(),
             ));
         }
         if !include_permissions_for_view.is_empty() {
             query_args.push((
-                "includePermissionsForView".to_string(),
+                "includePermissionsForView",
                 include_permissions_for_view.to_string(),
             ));
         }
         if include_removed {
-            query_args.push(("includeRemoved".to_string(), include_removed.to_string()));
+            query_args.push(("includeRemoved", include_removed.to_string()));
         }
         if include_team_drive_items {
             query_args.push((
-                "includeTeamDriveItems".to_string(),
+                "includeTeamDriveItems",
                 include_team_drive_items.to_string(),
             ));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("pageSize".to_string(), page_size.to_string()));
+            query_args.push(("pageSize", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if restrict_to_my_drive {
-            query_args.push((
-                "restrictToMyDrive".to_string(),
-                restrict_to_my_drive.to_string(),
-            ));
+            query_args.push(("restrictToMyDrive", restrict_to_my_drive.to_string()));
         }
         if !spaces.is_empty() {
-            query_args.push(("spaces".to_string(), spaces.to_string()));
+            query_args.push(("spaces", spaces.to_string()));
         }
         if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
         }
         if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
         }
         if !team_drive_id.is_empty() {
-            query_args.push(("teamDriveId".to_string(), team_drive_id.to_string()));
+            query_args.push(("teamDriveId", team_drive_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/changes/watch?{}", query_);
+        let url = crate::Client::url_with_query("/changes/watch", &query_args);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/changes/watch", &[]))
+    }
+}
+
+/// The functions of [`Changes`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ChangesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ChangesApi: Send + Sync {
+    async fn list(
+        &self,
+        page_token: &str,
+        drive_id: &str,
+        include_corpus_removals: bool,
+        include_items_from_all_drives: bool,
+        include_permissions_for_view: &str,
+        include_removed: bool,
+        include_team_drive_items: bool,
+        page_size: i64,
+        restrict_to_my_drive: bool,
+        spaces: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        team_drive_id: &str,
+    ) -> Result<Vec<crate::types::Change>>;
+    async fn list_all(
+        &self,
+        drive_id: &str,
+        include_corpus_removals: bool,
+        include_items_from_all_drives: bool,
+        include_permissions_for_view: &str,
+        include_removed: bool,
+        include_team_drive_items: bool,
+        restrict_to_my_drive: bool,
+        spaces: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        team_drive_id: &str,
+    ) -> Result<Vec<crate::types::Change>>;
+    async fn get_start_page_token(
+        &self,
+        drive_id: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        team_drive_id: &str,
+    ) -> Result<crate::types::StartPageToken>;
+    async fn watch(
+        &self,
+        page_token: &str,
+        drive_id: &str,
+        include_corpus_removals: bool,
+        include_items_from_all_drives: bool,
+        include_permissions_for_view: &str,
+        include_removed: bool,
+        include_team_drive_items: bool,
+        page_size: i64,
+        restrict_to_my_drive: bool,
+        spaces: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        team_drive_id: &str,
+        body: &crate::types::Channel,
+    ) -> Result<crate::types::Channel>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ChangesApi for Changes {
+    async fn list(
+        &self,
+        page_token: &str,
+        drive_id: &str,
+        include_corpus_removals: bool,
+        include_items_from_all_drives: bool,
+        include_permissions_for_view: &str,
+        include_removed: bool,
+        include_team_drive_items: bool,
+        page_size: i64,
+        restrict_to_my_drive: bool,
+        spaces: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        team_drive_id: &str,
+    ) -> Result<Vec<crate::types::Change>> {
+        Changes::list(
+            self,
+            page_token,
+            drive_id,
+            include_corpus_removals,
+            include_items_from_all_drives,
+            include_permissions_for_view,
+            include_removed,
+            include_team_drive_items,
+            page_size,
+            restrict_to_my_drive,
+            spaces,
+            supports_all_drives,
+            supports_team_drives,
+            team_drive_id,
+        )
+        .await
+    }
+    async fn list_all(
+        &self,
+        drive_id: &str,
+        include_corpus_removals: bool,
+        include_items_from_all_drives: bool,
+        include_permissions_for_view: &str,
+        include_removed: bool,
+        include_team_drive_items: bool,
+        restrict_to_my_drive: bool,
+        spaces: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        team_drive_id: &str,
+    ) -> Result<Vec<crate::types::Change>> {
+        Changes::list_all(
+            self,
+            drive_id,
+            include_corpus_removals,
+            include_items_from_all_drives,
+            include_permissions_for_view,
+            include_removed,
+            include_team_drive_items,
+            restrict_to_my_drive,
+            spaces,
+            supports_all_drives,
+            supports_team_drives,
+            team_drive_id,
+        )
+        .await
+    }
+    async fn get_start_page_token(
+        &self,
+        drive_id: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        team_drive_id: &str,
+    ) -> Result<crate::types::StartPageToken> {
+        Changes::get_start_page_token(
+            self,
+            drive_id,
+            supports_all_drives,
+            supports_team_drives,
+            team_drive_id,
+        )
+        .await
+    }
+    async fn watch(
+        &self,
+        page_token: &str,
+        drive_id: &str,
+        include_corpus_removals: bool,
+        include_items_from_all_drives: bool,
+        include_permissions_for_view: &str,
+        include_removed: bool,
+        include_team_drive_items: bool,
+        page_size: i64,
+        restrict_to_my_drive: bool,
+        spaces: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        team_drive_id: &str,
+        body: &crate::types::Channel,
+    ) -> Result<crate::types::Channel> {
+        Changes::watch(
+            self,
+            page_token,
+            drive_id,
+            include_corpus_removals,
+            include_items_from_all_drives,
+            include_permissions_for_view,
+            include_removed,
+            include_team_drive_items,
+            page_size,
+            restrict_to_my_drive,
+            spaces,
+            supports_all_drives,
+            supports_team_drives,
+            team_drive_id,
+            body,
+        )
+        .await
     }
 }
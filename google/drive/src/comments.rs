@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Comments {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Comments {
         Comments { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Comments {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/files/{fileId}/comments` endpoint.
      *
@@ -33,30 +39,33 @@ impl Comments {
         page_token: &str,
         start_modified_time: &str,
     ) -> Result<Vec<crate::types::Comment>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if include_deleted {
-            query_args.push(("includeDeleted".to_string(), include_deleted.to_string()));
+            query_args.push(("includeDeleted", include_deleted.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("pageSize".to_string(), page_size.to_string()));
+            query_args.push(("pageSize", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if !start_modified_time.is_empty() {
-            query_args.push((
-                "startModifiedTime".to_string(),
-                start_modified_time.to_string(),
-            ));
+            query_args.push(("startModifiedTime", start_modified_time.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/files/{}/comments?{}",
-            crate::progenitor_support::encode_path(&file_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/files/{}/comments", self.client.encode_path(file_id)),
+            &query_args,
         );
 
-        let resp: crate::types::CommentList = self.client.get(&url, None).await?;
+        let resp: crate::types::CommentList =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/files/{fileId}/comments",
+                    &[("fileId", file_id.to_string())],
+                )
+            })?;
 
         // Return our response data.
         Ok(resp.comments)
@@ -75,24 +84,26 @@ impl Comments {
         include_deleted: bool,
         start_modified_time: &str,
     ) -> Result<Vec<crate::types::Comment>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if include_deleted {
-            query_args.push(("includeDeleted".to_string(), include_deleted.to_string()));
+            query_args.push(("includeDeleted", include_deleted.to_string()));
         }
         if !start_modified_time.is_empty() {
-            query_args.push((
-                "startModifiedTime".to_string(),
-                start_modified_time.to_string(),
-            ));
+            query_args.push(("startModifiedTime", start_modified_time.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/files/{}/comments?{}",
-            crate::progenitor_support::encode_path(&file_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/files/{}/comments", self.client.encode_path(file_id)),
+            &query_args,
         );
 
-        let mut resp: crate::types::CommentList = self.client.get(&url, None).await?;
+        let mut resp: crate::types::CommentList =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/files/{fileId}/comments",
+                    &[("fileId", file_id.to_string())],
+                )
+            })?;
 
         let mut comments = resp.comments;
         let mut page = resp.next_page_token;
@@ -138,14 +149,18 @@ impl Comments {
         file_id: &str,
         body: &crate::types::Comment,
     ) -> Result<crate::types::Comment> {
-        let url = format!(
-            "/files/{}/comments",
-            crate::progenitor_support::encode_path(&file_id.to_string()),
-        );
+        let url = format!("/files/{}/comments", self.client.encode_path(file_id),);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/files/{fileId}/comments",
+                    &[("fileId", file_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -165,19 +180,29 @@ impl Comments {
         comment_id: &str,
         include_deleted: bool,
     ) -> Result<crate::types::Comment> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if include_deleted {
-            query_args.push(("includeDeleted".to_string(), include_deleted.to_string()));
+            query_args.push(("includeDeleted", include_deleted.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/files/{}/comments/{}?{}",
-            crate::progenitor_support::encode_path(&file_id.to_string()),
-            crate::progenitor_support::encode_path(&comment_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/files/{}/comments/{}",
+                self.client.encode_path(file_id),
+                self.client.encode_path(comment_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/files/{fileId}/comments/{commentId}",
+                &[
+                    ("fileId", file_id.to_string()),
+                    ("commentId", comment_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -193,11 +218,47 @@ impl Comments {
     pub async fn delete(&self, file_id: &str, comment_id: &str) -> Result<()> {
         let url = format!(
             "/files/{}/comments/{}",
-            crate::progenitor_support::encode_path(&file_id.to_string()),
-            crate::progenitor_support::encode_path(&comment_id.to_string()),
+            self.client.encode_path(file_id),
+            self.client.encode_path(comment_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/files/{fileId}/comments/{commentId}",
+                &[
+                    ("fileId", file_id.to_string()),
+                    ("commentId", comment_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/files/{fileId}/comments/{commentId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, file_id: &str, comment_id: &str) -> Result<bool> {
+        let url = format!(
+            "/files/{}/comments/{}",
+            self.client.encode_path(file_id),
+            self.client.encode_path(comment_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/files/{fileId}/comments/{commentId}",
+                    &[
+                        ("fileId", file_id.to_string()),
+                        ("commentId", comment_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -218,12 +279,156 @@ impl Comments {
     ) -> Result<crate::types::Comment> {
         let url = format!(
             "/files/{}/comments/{}",
-            crate::progenitor_support::encode_path(&file_id.to_string()),
-            crate::progenitor_support::encode_path(&comment_id.to_string()),
+            self.client.encode_path(file_id),
+            self.client.encode_path(comment_id),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/files/{fileId}/comments/{commentId}",
+                    &[
+                        ("fileId", file_id.to_string()),
+                        ("commentId", comment_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * This function performs a `PATCH` to the `/files/{fileId}/comments/{commentId}` endpoint.
+     *
+     * As opposed to `update`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn update_if_changed<C: serde::Serialize>(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        current: &C,
+        body: &crate::types::Comment,
+    ) -> Result<Option<crate::types::Comment>> {
+        let url = format!(
+            "/files/{}/comments/{}",
+            self.client.encode_path(file_id),
+            self.client.encode_path(comment_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/files/{fileId}/comments/{commentId}",
+                    &[
+                        ("fileId", file_id.to_string()),
+                        ("commentId", comment_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`Comments`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn CommentsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait CommentsApi: Send + Sync {
+    async fn list(
+        &self,
+        file_id: &str,
+        include_deleted: bool,
+        page_size: i64,
+        page_token: &str,
+        start_modified_time: &str,
+    ) -> Result<Vec<crate::types::Comment>>;
+    async fn list_all(
+        &self,
+        file_id: &str,
+        include_deleted: bool,
+        start_modified_time: &str,
+    ) -> Result<Vec<crate::types::Comment>>;
+    async fn create(
+        &self,
+        file_id: &str,
+        body: &crate::types::Comment,
+    ) -> Result<crate::types::Comment>;
+    async fn get(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        include_deleted: bool,
+    ) -> Result<crate::types::Comment>;
+    async fn delete(&self, file_id: &str, comment_id: &str) -> Result<()>;
+    async fn delete_if_exists(&self, file_id: &str, comment_id: &str) -> Result<bool>;
+    async fn update(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        body: &crate::types::Comment,
+    ) -> Result<crate::types::Comment>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl CommentsApi for Comments {
+    async fn list(
+        &self,
+        file_id: &str,
+        include_deleted: bool,
+        page_size: i64,
+        page_token: &str,
+        start_modified_time: &str,
+    ) -> Result<Vec<crate::types::Comment>> {
+        Comments::list(
+            self,
+            file_id,
+            include_deleted,
+            page_size,
+            page_token,
+            start_modified_time,
+        )
+        .await
+    }
+    async fn list_all(
+        &self,
+        file_id: &str,
+        include_deleted: bool,
+        start_modified_time: &str,
+    ) -> Result<Vec<crate::types::Comment>> {
+        Comments::list_all(self, file_id, include_deleted, start_modified_time).await
+    }
+    async fn create(
+        &self,
+        file_id: &str,
+        body: &crate::types::Comment,
+    ) -> Result<crate::types::Comment> {
+        Comments::create(self, file_id, body).await
+    }
+    async fn get(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        include_deleted: bool,
+    ) -> Result<crate::types::Comment> {
+        Comments::get(self, file_id, comment_id, include_deleted).await
+    }
+    async fn delete(&self, file_id: &str, comment_id: &str) -> Result<()> {
+        Comments::delete(self, file_id, comment_id).await
+    }
+    async fn delete_if_exists(&self, file_id: &str, comment_id: &str) -> Result<bool> {
+        Comments::delete_if_exists(self, file_id, comment_id).await
+    }
+    async fn update(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        body: &crate::types::Comment,
+    ) -> Result<crate::types::Comment> {
+        Comments::update(self, file_id, comment_id, body).await
     }
 }
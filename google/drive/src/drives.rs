@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Drives {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Drives {
         Drives { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Drives {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/drives` endpoint.
      *
@@ -31,26 +37,27 @@ impl Drives {
         q: &str,
         use_domain_admin_access: bool,
     ) -> Result<Vec<crate::types::Drive>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("pageSize".to_string(), page_size.to_string()));
+            query_args.push(("pageSize", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+            query_args.push(("useDomainAdminAccess", use_domain_admin_access.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/drives?{}", query_);
+        let url = crate::Client::url_with_query("/drives", &query_args);
 
-        let resp: crate::types::DriveList = self.client.get(&url, None).await?;
+        let resp: crate::types::DriveList = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/drives", &[]))?;
 
         // Return our response data.
         Ok(resp.drives)
@@ -68,20 +75,20 @@ impl Drives {
         q: &str,
         use_domain_admin_access: bool,
     ) -> Result<Vec<crate::types::Drive>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+            query_args.push(("useDomainAdminAccess", use_domain_admin_access.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/drives?{}", query_);
+        let url = crate::Client::url_with_query("/drives", &query_args);
 
-        let mut resp: crate::types::DriveList = self.client.get(&url, None).await?;
+        let mut resp: crate::types::DriveList = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/drives", &[]))?;
 
         let mut drives = resp.drives;
         let mut page = resp.next_page_token;
@@ -127,16 +134,16 @@ impl Drives {
         request_id: &str,
         body: &crate::types::Drive,
     ) -> Result<crate::types::Drive> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !request_id.is_empty() {
-            query_args.push(("requestId".to_string(), request_id.to_string()));
+            query_args.push(("requestId", request_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/drives?{}", query_);
+        let url = crate::Client::url_with_query("/drives", &query_args);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/drives", &[]))
     }
 
     /**
@@ -154,21 +161,22 @@ impl Drives {
         drive_id: &str,
         use_domain_admin_access: bool,
     ) -> Result<crate::types::Drive> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+            query_args.push(("useDomainAdminAccess", use_domain_admin_access.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/drives/{}?{}",
-            crate::progenitor_support::encode_path(&drive_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/drives/{}", self.client.encode_path(drive_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/drives/{driveId}",
+                &[("driveId", drive_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -181,12 +189,35 @@ impl Drives {
      * * `drive_id: &str` -- A link to this theme's background image.
      */
     pub async fn delete(&self, drive_id: &str) -> Result<()> {
-        let url = format!(
-            "/drives/{}",
-            crate::progenitor_support::encode_path(&drive_id.to_string()),
-        );
+        let url = format!("/drives/{}", self.client.encode_path(drive_id),);
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/drives/{driveId}",
+                &[("driveId", drive_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/drives/{driveId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, drive_id: &str) -> Result<bool> {
+        let url = format!("/drives/{}", self.client.encode_path(drive_id),);
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/drives/{driveId}",
+                    &[("driveId", drive_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -205,23 +236,58 @@ impl Drives {
         use_domain_admin_access: bool,
         body: &crate::types::Drive,
     ) -> Result<crate::types::Drive> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if use_domain_admin_access {
+            query_args.push(("useDomainAdminAccess", use_domain_admin_access.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!("/drives/{}", self.client.encode_path(drive_id)),
+            &query_args,
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/drives/{driveId}",
+                    &[("driveId", drive_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * This function performs a `PATCH` to the `/drives/{driveId}` endpoint.
+     *
+     * As opposed to `update`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn update_if_changed<C: serde::Serialize>(
+        &self,
+        drive_id: &str,
+        use_domain_admin_access: bool,
+        current: &C,
+        body: &crate::types::Drive,
+    ) -> Result<Option<crate::types::Drive>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+            query_args.push(("useDomainAdminAccess", use_domain_admin_access.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/drives/{}?{}",
-            crate::progenitor_support::encode_path(&drive_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/drives/{}", self.client.encode_path(drive_id)),
+            &query_args,
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/drives/{driveId}",
+                    &[("driveId", drive_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -234,12 +300,15 @@ impl Drives {
      * * `drive_id: &str` -- A link to this theme's background image.
      */
     pub async fn hide(&self, drive_id: &str) -> Result<crate::types::Drive> {
-        let url = format!(
-            "/drives/{}/hide",
-            crate::progenitor_support::encode_path(&drive_id.to_string()),
-        );
+        let url = format!("/drives/{}/hide", self.client.encode_path(drive_id),);
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/drives/{driveId}/hide",
+                &[("driveId", drive_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -252,11 +321,109 @@ impl Drives {
      * * `drive_id: &str` -- A link to this theme's background image.
      */
     pub async fn unhide(&self, drive_id: &str) -> Result<crate::types::Drive> {
-        let url = format!(
-            "/drives/{}/unhide",
-            crate::progenitor_support::encode_path(&drive_id.to_string()),
-        );
+        let url = format!("/drives/{}/unhide", self.client.encode_path(drive_id),);
+
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/drives/{driveId}/unhide",
+                &[("driveId", drive_id.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`Drives`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn DrivesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait DrivesApi: Send + Sync {
+    async fn list(
+        &self,
+        page_size: i64,
+        page_token: &str,
+        q: &str,
+        use_domain_admin_access: bool,
+    ) -> Result<Vec<crate::types::Drive>>;
+    async fn list_all(
+        &self,
+        q: &str,
+        use_domain_admin_access: bool,
+    ) -> Result<Vec<crate::types::Drive>>;
+    async fn create(
+        &self,
+        request_id: &str,
+        body: &crate::types::Drive,
+    ) -> Result<crate::types::Drive>;
+    async fn get(
+        &self,
+        drive_id: &str,
+        use_domain_admin_access: bool,
+    ) -> Result<crate::types::Drive>;
+    async fn delete(&self, drive_id: &str) -> Result<()>;
+    async fn delete_if_exists(&self, drive_id: &str) -> Result<bool>;
+    async fn update(
+        &self,
+        drive_id: &str,
+        use_domain_admin_access: bool,
+        body: &crate::types::Drive,
+    ) -> Result<crate::types::Drive>;
+    async fn hide(&self, drive_id: &str) -> Result<crate::types::Drive>;
+    async fn unhide(&self, drive_id: &str) -> Result<crate::types::Drive>;
+}
 
-        self.client.post(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl DrivesApi for Drives {
+    async fn list(
+        &self,
+        page_size: i64,
+        page_token: &str,
+        q: &str,
+        use_domain_admin_access: bool,
+    ) -> Result<Vec<crate::types::Drive>> {
+        Drives::list(self, page_size, page_token, q, use_domain_admin_access).await
+    }
+    async fn list_all(
+        &self,
+        q: &str,
+        use_domain_admin_access: bool,
+    ) -> Result<Vec<crate::types::Drive>> {
+        Drives::list_all(self, q, use_domain_admin_access).await
+    }
+    async fn create(
+        &self,
+        request_id: &str,
+        body: &crate::types::Drive,
+    ) -> Result<crate::types::Drive> {
+        Drives::create(self, request_id, body).await
+    }
+    async fn get(
+        &self,
+        drive_id: &str,
+        use_domain_admin_access: bool,
+    ) -> Result<crate::types::Drive> {
+        Drives::get(self, drive_id, use_domain_admin_access).await
+    }
+    async fn delete(&self, drive_id: &str) -> Result<()> {
+        Drives::delete(self, drive_id).await
+    }
+    async fn delete_if_exists(&self, drive_id: &str) -> Result<bool> {
+        Drives::delete_if_exists(self, drive_id).await
+    }
+    async fn update(
+        &self,
+        drive_id: &str,
+        use_domain_admin_access: bool,
+        body: &crate::types::Drive,
+    ) -> Result<crate::types::Drive> {
+        Drives::update(self, drive_id, use_domain_admin_access, body).await
+    }
+    async fn hide(&self, drive_id: &str) -> Result<crate::types::Drive> {
+        Drives::hide(self, drive_id).await
+    }
+    async fn unhide(&self, drive_id: &str) -> Result<crate::types::Drive> {
+        Drives::unhide(self, drive_id).await
     }
 }
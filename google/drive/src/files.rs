@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Files {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Files {
         Files { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Files {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/files` endpoint.
      *
@@ -50,70 +56,149 @@ impl Files {
         supports_team_drives: bool,
         team_drive_id: &str,
     ) -> Result<Vec<crate::types::File>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !corpora.is_empty() {
-            query_args.push(("corpora".to_string(), corpora.to_string()));
+            query_args.push(("corpora", corpora.to_string()));
         }
         if !drive_id.is_empty() {
-            query_args.push(("driveId".to_string(), drive_id.to_string()));
+            query_args.push(("driveId", drive_id.to_string()));
         }
         if include_items_from_all_drives {
             query_args.push((
-                "includeItemsFromAllDrives".to_string(),
+                "includeItemsFromAllDrives",
                 include_items_from_all_drives.to_string(),
             ));
         }
         if !include_permissions_for_view.is_empty() {
             query_args.push((
-                "includePermissionsForView".to_string(),
+                "includePermissionsForView",
                 include_permissions_for_view.to_string(),
             ));
         }
         if include_team_drive_items {
             query_args.push((
-                "includeTeamDriveItems".to_string(),
+                "includeTeamDriveItems",
                 include_team_drive_items.to_string(),
             ));
         }
         if !order_by.is_empty() {
-            query_args.push(("orderBy".to_string(), order_by.to_string()));
+            query_args.push(("orderBy", order_by.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("pageSize".to_string(), page_size.to_string()));
+            query_args.push(("pageSize", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if !spaces.is_empty() {
-            query_args.push(("spaces".to_string(), spaces.to_string()));
+            query_args.push(("spaces", spaces.to_string()));
         }
         if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
         }
         if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
         }
         if !team_drive_id.is_empty() {
-            query_args.push(("teamDriveId".to_string(), team_drive_id.to_string()));
+            query_args.push(("teamDriveId", team_drive_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/files?{}", query_);
+        let url = crate::Client::url_with_query("/files", &query_args);
 
-        let resp: crate::types::FileList = self.client.get(&url, None).await?;
+        let resp: crate::types::FileList = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/files", &[]))?;
 
         // Return our response data.
         Ok(resp.files)
     }
 
+    /**
+     * This function performs a `GET` to the `/files` endpoint.
+     *
+     * As opposed to `list`, this function streams the items as they are parsed from the response, rather than returning them all at once.
+     * This is useful for very large responses that would otherwise need to be held in memory.
+     * Only the one page the parameters ask for is streamed, the next pages are not fetched.
+     */
+    pub async fn list_stream_json(
+        &self,
+        corpora: &str,
+        drive_id: &str,
+        include_items_from_all_drives: bool,
+        include_permissions_for_view: &str,
+        include_team_drive_items: bool,
+        order_by: &str,
+        page_size: i64,
+        page_token: &str,
+        q: &str,
+        spaces: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        team_drive_id: &str,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::File>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !corpora.is_empty() {
+            query_args.push(("corpora", corpora.to_string()));
+        }
+        if !drive_id.is_empty() {
+            query_args.push(("driveId", drive_id.to_string()));
+        }
+        if include_items_from_all_drives {
+            query_args.push((
+                "includeItemsFromAllDrives",
+                include_items_from_all_drives.to_string(),
+            ));
+        }
+        if !include_permissions_for_view.is_empty() {
+            query_args.push((
+                "includePermissionsForView",
+                include_permissions_for_view.to_string(),
+            ));
+        }
+        if include_team_drive_items {
+            query_args.push((
+                "includeTeamDriveItems",
+                include_team_drive_items.to_string(),
+            ));
+        }
+        if !order_by.is_empty() {
+            query_args.push(("orderBy", order_by.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("pageSize", page_size.to_string()));
+        }
+        if !page_token.is_empty() {
+            query_args.push(("pageToken", page_token.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q", q.to_string()));
+        }
+        if !spaces.is_empty() {
+            query_args.push(("spaces", spaces.to_string()));
+        }
+        if supports_all_drives {
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
+        }
+        if supports_team_drives {
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
+        }
+        if !team_drive_id.is_empty() {
+            query_args.push(("teamDriveId", team_drive_id.to_string()));
+        }
+        let url = crate::Client::url_with_query("/files", &query_args);
+
+        self.client
+            .get_stream_json(&url, "files")
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/files", &[]))
+    }
+
     /**
      * This function performs a `GET` to the `/files` endpoint.
      *
@@ -135,59 +220,56 @@ impl Files {
         supports_team_drives: bool,
         team_drive_id: &str,
     ) -> Result<Vec<crate::types::File>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !corpora.is_empty() {
-            query_args.push(("corpora".to_string(), corpora.to_string()));
+            query_args.push(("corpora", corpora.to_string()));
         }
         if !drive_id.is_empty() {
-            query_args.push(("driveId".to_string(), drive_id.to_string()));
+            query_args.push(("driveId", drive_id.to_string()));
         }
         if include_items_from_all_drives {
             query_args.push((
-                "includeItemsFromAllDrives".to_string(),
+                "includeItemsFromAllDrives",
                 include_items_from_all_drives.to_string(),
             ));
         }
         if !include_permissions_for_view.is_empty() {
             query_args.push((
-                "includePermissionsForView".to_string(),
+                "includePermissionsForView",
                 include_permissions_for_view.to_string(),
             ));
         }
         if include_team_drive_items {
             query_args.push((
-                "includeTeamDriveItems".to_string(),
+                "includeTeamDriveItems",
                 include_team_drive_items.to_string(),
             ));
         }
         if !order_by.is_empty() {
-            query_args.push(("orderBy".to_string(), order_by.to_string()));
+            query_args.push(("orderBy", order_by.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if !spaces.is_empty() {
-            query_args.push(("spaces".to_string(), spaces.to_string()));
+            query_args.push(("spaces", spaces.to_string()));
         }
         if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
         }
         if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
         }
         if !team_drive_id.is_empty() {
-            query_args.push(("teamDriveId".to_string(), team_drive_id.to_string()));
+            query_args.push(("teamDriveId", team_drive_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/files?{}", query_);
+        let url = crate::Client::url_with_query("/files", &query_args);
 
-        let mut resp: crate::types::FileList = self.client.get(&url, None).await?;
+        let mut resp: crate::types::FileList = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/files", &[]))?;
 
         let mut files = resp.files;
         let mut page = resp.next_page_token;
@@ -246,52 +328,43 @@ impl Files {
         use_content_as_indexable_text: bool,
         body: &crate::types::File,
     ) -> Result<crate::types::File> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if ignore_default_visibility {
             query_args.push((
-                "ignoreDefaultVisibility".to_string(),
+                "ignoreDefaultVisibility",
                 ignore_default_visibility.to_string(),
             ));
         }
         if !include_permissions_for_view.is_empty() {
             query_args.push((
-                "includePermissionsForView".to_string(),
+                "includePermissionsForView",
                 include_permissions_for_view.to_string(),
             ));
         }
         if keep_revision_forever {
-            query_args.push((
-                "keepRevisionForever".to_string(),
-                keep_revision_forever.to_string(),
-            ));
+            query_args.push(("keepRevisionForever", keep_revision_forever.to_string()));
         }
         if !ocr_language.is_empty() {
-            query_args.push(("ocrLanguage".to_string(), ocr_language.to_string()));
+            query_args.push(("ocrLanguage", ocr_language.to_string()));
         }
         if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
         }
         if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
         }
         if use_content_as_indexable_text {
             query_args.push((
-                "useContentAsIndexableText".to_string(),
+                "useContentAsIndexableText",
                 use_content_as_indexable_text.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/files?{}", query_);
+        let url = crate::Client::url_with_query("/files", &query_args);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/files", &[]))
     }
 
     /**
@@ -311,20 +384,22 @@ impl Files {
         space: &str,
         type_: &str,
     ) -> Result<crate::types::GeneratedIds> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !space.is_empty() {
-            query_args.push(("space".to_string(), space.to_string()));
+            query_args.push(("space", space.to_string()));
         }
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/files/generateIds?{}", query_);
+        let url = crate::Client::url_with_query("/files/generateIds", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/files/generateIds", &[]))
     }
 
     /**
@@ -338,7 +413,23 @@ impl Files {
      */
     pub async fn empty_trash(&self) -> Result<()> {
         let url = "/files/trash".to_string();
-        self.client.delete(&url, None).await
+        self.client
+            .delete(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("DELETE", "/files/trash", &[]))
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/files/trash` endpoint.
+     *
+     * As opposed to `empty_trash`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn empty_trash_if_exists(&self) -> Result<bool> {
+        let url = "/files/trash".to_string();
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("DELETE", "/files/trash", &[]))
     }
 
     /**
@@ -362,39 +453,30 @@ impl Files {
         supports_all_drives: bool,
         supports_team_drives: bool,
     ) -> Result<crate::types::File> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if acknowledge_abuse {
-            query_args.push((
-                "acknowledgeAbuse".to_string(),
-                acknowledge_abuse.to_string(),
-            ));
+            query_args.push(("acknowledgeAbuse", acknowledge_abuse.to_string()));
         }
         if !include_permissions_for_view.is_empty() {
             query_args.push((
-                "includePermissionsForView".to_string(),
+                "includePermissionsForView",
                 include_permissions_for_view.to_string(),
             ));
         }
         if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
         }
         if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/files/{}?{}",
-            crate::progenitor_support::encode_path(&file_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/files/{}", self.client.encode_path(file_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/files/{fileId}", &[("fileId", file_id.to_string())])
+        })
     }
 
     /**
@@ -415,27 +497,60 @@ impl Files {
         supports_all_drives: bool,
         supports_team_drives: bool,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
         }
         if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/files/{}?{}",
-            crate::progenitor_support::encode_path(&file_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/files/{}", self.client.encode_path(file_id)),
+            &query_args,
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/files/{fileId}",
+                &[("fileId", file_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/files/{fileId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(
+        &self,
+        file_id: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+    ) -> Result<bool> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if supports_all_drives {
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
+        }
+        if supports_team_drives {
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!("/files/{}", self.client.encode_path(file_id)),
+            &query_args,
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/files/{fileId}",
+                    &[("fileId", file_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -469,56 +584,119 @@ impl Files {
         use_content_as_indexable_text: bool,
         body: &crate::types::File,
     ) -> Result<crate::types::File> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !add_parents.is_empty() {
-            query_args.push(("addParents".to_string(), add_parents.to_string()));
+            query_args.push(("addParents", add_parents.to_string()));
         }
         if !include_permissions_for_view.is_empty() {
             query_args.push((
-                "includePermissionsForView".to_string(),
+                "includePermissionsForView",
                 include_permissions_for_view.to_string(),
             ));
         }
         if keep_revision_forever {
-            query_args.push((
-                "keepRevisionForever".to_string(),
-                keep_revision_forever.to_string(),
-            ));
+            query_args.push(("keepRevisionForever", keep_revision_forever.to_string()));
         }
         if !ocr_language.is_empty() {
-            query_args.push(("ocrLanguage".to_string(), ocr_language.to_string()));
+            query_args.push(("ocrLanguage", ocr_language.to_string()));
         }
         if !remove_parents.is_empty() {
-            query_args.push(("removeParents".to_string(), remove_parents.to_string()));
+            query_args.push(("removeParents", remove_parents.to_string()));
         }
         if supports_all_drives {
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
+        }
+        if supports_team_drives {
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
+        }
+        if use_content_as_indexable_text {
             query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
+                "useContentAsIndexableText",
+                use_content_as_indexable_text.to_string(),
             ));
         }
-        if supports_team_drives {
+        let url = crate::Client::url_with_query(
+            &format!("/files/{}", self.client.encode_path(file_id)),
+            &query_args,
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/files/{fileId}",
+                    &[("fileId", file_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * This function performs a `PATCH` to the `/files/{fileId}` endpoint.
+     *
+     * As opposed to `update`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn update_if_changed<C: serde::Serialize>(
+        &self,
+        file_id: &str,
+        add_parents: &str,
+        include_permissions_for_view: &str,
+        keep_revision_forever: bool,
+        ocr_language: &str,
+        remove_parents: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        use_content_as_indexable_text: bool,
+        current: &C,
+        body: &crate::types::File,
+    ) -> Result<Option<crate::types::File>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !add_parents.is_empty() {
+            query_args.push(("addParents", add_parents.to_string()));
+        }
+        if !include_permissions_for_view.is_empty() {
             query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
+                "includePermissionsForView",
+                include_permissions_for_view.to_string(),
             ));
         }
+        if keep_revision_forever {
+            query_args.push(("keepRevisionForever", keep_revision_forever.to_string()));
+        }
+        if !ocr_language.is_empty() {
+            query_args.push(("ocrLanguage", ocr_language.to_string()));
+        }
+        if !remove_parents.is_empty() {
+            query_args.push(("removeParents", remove_parents.to_string()));
+        }
+        if supports_all_drives {
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
+        }
+        if supports_team_drives {
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
+        }
         if use_content_as_indexable_text {
             query_args.push((
-                "useContentAsIndexableText".to_string(),
+                "useContentAsIndexableText",
                 use_content_as_indexable_text.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/files/{}?{}",
-            crate::progenitor_support::encode_path(&file_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/files/{}", self.client.encode_path(file_id)),
+            &query_args,
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/files/{fileId}",
+                    &[("fileId", file_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -546,52 +724,48 @@ impl Files {
         ocr_language: &str,
         supports_all_drives: bool,
         supports_team_drives: bool,
-        body: &crate::types::File,
+        body: &crate::types::FileRequest,
     ) -> Result<crate::types::File> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if ignore_default_visibility {
             query_args.push((
-                "ignoreDefaultVisibility".to_string(),
+                "ignoreDefaultVisibility",
                 ignore_default_visibility.to_string(),
             ));
         }
         if !include_permissions_for_view.is_empty() {
             query_args.push((
-                "includePermissionsForView".to_string(),
+                "includePermissionsForView",
                 include_permissions_for_view.to_string(),
             ));
         }
         if keep_revision_forever {
-            query_args.push((
-                "keepRevisionForever".to_string(),
-                keep_revision_forever.to_string(),
-            ));
+            query_args.push(("keepRevisionForever", keep_revision_forever.to_string()));
         }
         if !ocr_language.is_empty() {
-            query_args.push(("ocrLanguage".to_string(), ocr_language.to_string()));
+            query_args.push(("ocrLanguage", ocr_language.to_string()));
         }
         if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
         }
         if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/files/{}/copy?{}",
-            crate::progenitor_support::encode_path(&file_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/files/{}/copy", self.client.encode_path(file_id)),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/files/{fileId}/copy",
+                    &[("fileId", file_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -605,18 +779,22 @@ impl Files {
      * * `mime_type: &str` -- The MIME type of the format requested for this export.
      */
     pub async fn export(&self, file_id: &str, mime_type: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !mime_type.is_empty() {
-            query_args.push(("mimeType".to_string(), mime_type.to_string()));
+            query_args.push(("mimeType", mime_type.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/files/{}/export?{}",
-            crate::progenitor_support::encode_path(&file_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/files/{}/export", self.client.encode_path(file_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/files/{fileId}/export",
+                &[("fileId", file_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -641,40 +819,363 @@ impl Files {
         supports_team_drives: bool,
         body: &crate::types::Channel,
     ) -> Result<crate::types::Channel> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if acknowledge_abuse {
-            query_args.push((
-                "acknowledgeAbuse".to_string(),
-                acknowledge_abuse.to_string(),
-            ));
+            query_args.push(("acknowledgeAbuse", acknowledge_abuse.to_string()));
         }
         if !include_permissions_for_view.is_empty() {
             query_args.push((
-                "includePermissionsForView".to_string(),
+                "includePermissionsForView",
                 include_permissions_for_view.to_string(),
             ));
         }
         if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
         }
         if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/files/{}/watch?{}",
-            crate::progenitor_support::encode_path(&file_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/files/{}/watch", self.client.encode_path(file_id)),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/files/{fileId}/watch",
+                    &[("fileId", file_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`Files`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn FilesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait FilesApi: Send + Sync {
+    async fn list(
+        &self,
+        corpora: &str,
+        drive_id: &str,
+        include_items_from_all_drives: bool,
+        include_permissions_for_view: &str,
+        include_team_drive_items: bool,
+        order_by: &str,
+        page_size: i64,
+        page_token: &str,
+        q: &str,
+        spaces: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        team_drive_id: &str,
+    ) -> Result<Vec<crate::types::File>>;
+    async fn list_all(
+        &self,
+        corpora: &str,
+        drive_id: &str,
+        include_items_from_all_drives: bool,
+        include_permissions_for_view: &str,
+        include_team_drive_items: bool,
+        order_by: &str,
+        q: &str,
+        spaces: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        team_drive_id: &str,
+    ) -> Result<Vec<crate::types::File>>;
+    async fn create(
+        &self,
+        ignore_default_visibility: bool,
+        include_permissions_for_view: &str,
+        keep_revision_forever: bool,
+        ocr_language: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        use_content_as_indexable_text: bool,
+        body: &crate::types::File,
+    ) -> Result<crate::types::File>;
+    async fn generate_id(
+        &self,
+        count: i64,
+        space: &str,
+        type_: &str,
+    ) -> Result<crate::types::GeneratedIds>;
+    async fn empty_trash(&self) -> Result<()>;
+    async fn empty_trash_if_exists(&self) -> Result<bool>;
+    async fn get(
+        &self,
+        file_id: &str,
+        acknowledge_abuse: bool,
+        include_permissions_for_view: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+    ) -> Result<crate::types::File>;
+    async fn delete(
+        &self,
+        file_id: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+    ) -> Result<()>;
+    async fn delete_if_exists(
+        &self,
+        file_id: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+    ) -> Result<bool>;
+    async fn update(
+        &self,
+        file_id: &str,
+        add_parents: &str,
+        include_permissions_for_view: &str,
+        keep_revision_forever: bool,
+        ocr_language: &str,
+        remove_parents: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        use_content_as_indexable_text: bool,
+        body: &crate::types::File,
+    ) -> Result<crate::types::File>;
+    async fn copy(
+        &self,
+        file_id: &str,
+        ignore_default_visibility: bool,
+        include_permissions_for_view: &str,
+        keep_revision_forever: bool,
+        ocr_language: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        body: &crate::types::FileRequest,
+    ) -> Result<crate::types::File>;
+    async fn export(&self, file_id: &str, mime_type: &str) -> Result<()>;
+    async fn watch(
+        &self,
+        file_id: &str,
+        acknowledge_abuse: bool,
+        include_permissions_for_view: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        body: &crate::types::Channel,
+    ) -> Result<crate::types::Channel>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl FilesApi for Files {
+    async fn list(
+        &self,
+        corpora: &str,
+        drive_id: &str,
+        include_items_from_all_drives: bool,
+        include_permissions_for_view: &str,
+        include_team_drive_items: bool,
+        order_by: &str,
+        page_size: i64,
+        page_token: &str,
+        q: &str,
+        spaces: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        team_drive_id: &str,
+    ) -> Result<Vec<crate::types::File>> {
+        Files::list(
+            self,
+            corpora,
+            drive_id,
+            include_items_from_all_drives,
+            include_permissions_for_view,
+            include_team_drive_items,
+            order_by,
+            page_size,
+            page_token,
+            q,
+            spaces,
+            supports_all_drives,
+            supports_team_drives,
+            team_drive_id,
+        )
+        .await
+    }
+    async fn list_all(
+        &self,
+        corpora: &str,
+        drive_id: &str,
+        include_items_from_all_drives: bool,
+        include_permissions_for_view: &str,
+        include_team_drive_items: bool,
+        order_by: &str,
+        q: &str,
+        spaces: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        team_drive_id: &str,
+    ) -> Result<Vec<crate::types::File>> {
+        Files::list_all(
+            self,
+            corpora,
+            drive_id,
+            include_items_from_all_drives,
+            include_permissions_for_view,
+            include_team_drive_items,
+            order_by,
+            q,
+            spaces,
+            supports_all_drives,
+            supports_team_drives,
+            team_drive_id,
+        )
+        .await
+    }
+    async fn create(
+        &self,
+        ignore_default_visibility: bool,
+        include_permissions_for_view: &str,
+        keep_revision_forever: bool,
+        ocr_language: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        use_content_as_indexable_text: bool,
+        body: &crate::types::File,
+    ) -> Result<crate::types::File> {
+        Files::create(
+            self,
+            ignore_default_visibility,
+            include_permissions_for_view,
+            keep_revision_forever,
+            ocr_language,
+            supports_all_drives,
+            supports_team_drives,
+            use_content_as_indexable_text,
+            body,
+        )
+        .await
+    }
+    async fn generate_id(
+        &self,
+        count: i64,
+        space: &str,
+        type_: &str,
+    ) -> Result<crate::types::GeneratedIds> {
+        Files::generate_id(self, count, space, type_).await
+    }
+    async fn empty_trash(&self) -> Result<()> {
+        Files::empty_trash(self).await
+    }
+    async fn empty_trash_if_exists(&self) -> Result<bool> {
+        Files::empty_trash_if_exists(self).await
+    }
+    async fn get(
+        &self,
+        file_id: &str,
+        acknowledge_abuse: bool,
+        include_permissions_for_view: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+    ) -> Result<crate::types::File> {
+        Files::get(
+            self,
+            file_id,
+            acknowledge_abuse,
+            include_permissions_for_view,
+            supports_all_drives,
+            supports_team_drives,
+        )
+        .await
+    }
+    async fn delete(
+        &self,
+        file_id: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+    ) -> Result<()> {
+        Files::delete(self, file_id, supports_all_drives, supports_team_drives).await
+    }
+    async fn delete_if_exists(
+        &self,
+        file_id: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+    ) -> Result<bool> {
+        Files::delete_if_exists(self, file_id, supports_all_drives, supports_team_drives).await
+    }
+    async fn update(
+        &self,
+        file_id: &str,
+        add_parents: &str,
+        include_permissions_for_view: &str,
+        keep_revision_forever: bool,
+        ocr_language: &str,
+        remove_parents: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        use_content_as_indexable_text: bool,
+        body: &crate::types::File,
+    ) -> Result<crate::types::File> {
+        Files::update(
+            self,
+            file_id,
+            add_parents,
+            include_permissions_for_view,
+            keep_revision_forever,
+            ocr_language,
+            remove_parents,
+            supports_all_drives,
+            supports_team_drives,
+            use_content_as_indexable_text,
+            body,
+        )
+        .await
+    }
+    async fn copy(
+        &self,
+        file_id: &str,
+        ignore_default_visibility: bool,
+        include_permissions_for_view: &str,
+        keep_revision_forever: bool,
+        ocr_language: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        body: &crate::types::FileRequest,
+    ) -> Result<crate::types::File> {
+        Files::copy(
+            self,
+            file_id,
+            ignore_default_visibility,
+            include_permissions_for_view,
+            keep_revision_forever,
+            ocr_language,
+            supports_all_drives,
+            supports_team_drives,
+            body,
+        )
+        .await
+    }
+    async fn export(&self, file_id: &str, mime_type: &str) -> Result<()> {
+        Files::export(self, file_id, mime_type).await
+    }
+    async fn watch(
+        &self,
+        file_id: &str,
+        acknowledge_abuse: bool,
+        include_permissions_for_view: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        body: &crate::types::Channel,
+    ) -> Result<crate::types::Channel> {
+        Files::watch(
+            self,
+            file_id,
+            acknowledge_abuse,
+            include_permissions_for_view,
+            supports_all_drives,
+            supports_team_drives,
+            body,
+        )
+        .await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Permissions {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Permissions {
         Permissions { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Permissions {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/files/{fileId}/permissions` endpoint.
      *
@@ -37,45 +43,42 @@ impl Permissions {
         supports_team_drives: bool,
         use_domain_admin_access: bool,
     ) -> Result<Vec<crate::types::Permission>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_permissions_for_view.is_empty() {
             query_args.push((
-                "includePermissionsForView".to_string(),
+                "includePermissionsForView",
                 include_permissions_for_view.to_string(),
             ));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("pageSize".to_string(), page_size.to_string()));
+            query_args.push(("pageSize", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
         }
         if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
         }
         if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+            query_args.push(("useDomainAdminAccess", use_domain_admin_access.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/files/{}/permissions?{}",
-            crate::progenitor_support::encode_path(&file_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/files/{}/permissions", self.client.encode_path(file_id)),
+            &query_args,
         );
 
-        let resp: crate::types::PermissionList = self.client.get(&url, None).await?;
+        let resp: crate::types::PermissionList =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/files/{fileId}/permissions",
+                    &[("fileId", file_id.to_string())],
+                )
+            })?;
 
         // Return our response data.
         Ok(resp.permissions)
@@ -96,39 +99,35 @@ impl Permissions {
         supports_team_drives: bool,
         use_domain_admin_access: bool,
     ) -> Result<Vec<crate::types::Permission>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_permissions_for_view.is_empty() {
             query_args.push((
-                "includePermissionsForView".to_string(),
+                "includePermissionsForView",
                 include_permissions_for_view.to_string(),
             ));
         }
         if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
         }
         if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
         }
         if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+            query_args.push(("useDomainAdminAccess", use_domain_admin_access.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/files/{}/permissions?{}",
-            crate::progenitor_support::encode_path(&file_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/files/{}/permissions", self.client.encode_path(file_id)),
+            &query_args,
         );
 
-        let mut resp: crate::types::PermissionList = self.client.get(&url, None).await?;
+        let mut resp: crate::types::PermissionList =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/files/{fileId}/permissions",
+                    &[("fileId", file_id.to_string())],
+                )
+            })?;
 
         let mut permissions = resp.permissions;
         let mut page = resp.next_page_token;
@@ -187,56 +186,43 @@ impl Permissions {
         supports_team_drives: bool,
         transfer_ownership: bool,
         use_domain_admin_access: bool,
-        body: &crate::types::Permission,
+        body: &crate::types::PermissionRequest,
     ) -> Result<crate::types::Permission> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !email_message.is_empty() {
-            query_args.push(("emailMessage".to_string(), email_message.to_string()));
+            query_args.push(("emailMessage", email_message.to_string()));
         }
         if move_to_new_owners_root {
-            query_args.push((
-                "moveToNewOwnersRoot".to_string(),
-                move_to_new_owners_root.to_string(),
-            ));
+            query_args.push(("moveToNewOwnersRoot", move_to_new_owners_root.to_string()));
         }
-        query_args.push((
-            "sendNotificationEmail".to_string(),
-            send_notification_email.to_string(),
-        ));
+        query_args.push(("sendNotificationEmail", send_notification_email.to_string()));
         if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
         }
         if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
         }
         if transfer_ownership {
-            query_args.push((
-                "transferOwnership".to_string(),
-                transfer_ownership.to_string(),
-            ));
+            query_args.push(("transferOwnership", transfer_ownership.to_string()));
         }
         if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+            query_args.push(("useDomainAdminAccess", use_domain_admin_access.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/files/{}/permissions?{}",
-            crate::progenitor_support::encode_path(&file_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/files/{}/permissions", self.client.encode_path(file_id)),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/files/{fileId}/permissions",
+                    &[("fileId", file_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -260,34 +246,35 @@ impl Permissions {
         supports_team_drives: bool,
         use_domain_admin_access: bool,
     ) -> Result<crate::types::Permission> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
         }
         if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
         }
         if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+            query_args.push(("useDomainAdminAccess", use_domain_admin_access.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/files/{}/permissions/{}?{}",
-            crate::progenitor_support::encode_path(&file_id.to_string()),
-            crate::progenitor_support::encode_path(&permission_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/files/{}/permissions/{}",
+                self.client.encode_path(file_id),
+                self.client.encode_path(permission_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/files/{fileId}/permissions/{permissionId}",
+                &[
+                    ("fileId", file_id.to_string()),
+                    ("permissionId", permission_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -311,34 +298,82 @@ impl Permissions {
         supports_team_drives: bool,
         use_domain_admin_access: bool,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
         }
         if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
         }
         if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+            query_args.push(("useDomainAdminAccess", use_domain_admin_access.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/files/{}/permissions/{}?{}",
-            crate::progenitor_support::encode_path(&file_id.to_string()),
-            crate::progenitor_support::encode_path(&permission_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/files/{}/permissions/{}",
+                self.client.encode_path(file_id),
+                self.client.encode_path(permission_id)
+            ),
+            &query_args,
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/files/{fileId}/permissions/{permissionId}",
+                &[
+                    ("fileId", file_id.to_string()),
+                    ("permissionId", permission_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/files/{fileId}/permissions/{permissionId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(
+        &self,
+        file_id: &str,
+        permission_id: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        use_domain_admin_access: bool,
+    ) -> Result<bool> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if supports_all_drives {
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
+        }
+        if supports_team_drives {
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
+        }
+        if use_domain_admin_access {
+            query_args.push(("useDomainAdminAccess", use_domain_admin_access.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/files/{}/permissions/{}",
+                self.client.encode_path(file_id),
+                self.client.encode_path(permission_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/files/{fileId}/permissions/{permissionId}",
+                    &[
+                        ("fileId", file_id.to_string()),
+                        ("permissionId", permission_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -365,49 +400,324 @@ impl Permissions {
         supports_team_drives: bool,
         transfer_ownership: bool,
         use_domain_admin_access: bool,
-        body: &crate::types::Permission,
+        body: &crate::types::PermissionRequest,
     ) -> Result<crate::types::Permission> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if remove_expiration {
-            query_args.push((
-                "removeExpiration".to_string(),
-                remove_expiration.to_string(),
-            ));
+            query_args.push(("removeExpiration", remove_expiration.to_string()));
         }
         if supports_all_drives {
-            query_args.push((
-                "supportsAllDrives".to_string(),
-                supports_all_drives.to_string(),
-            ));
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
         }
         if supports_team_drives {
-            query_args.push((
-                "supportsTeamDrives".to_string(),
-                supports_team_drives.to_string(),
-            ));
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
         }
         if transfer_ownership {
-            query_args.push((
-                "transferOwnership".to_string(),
-                transfer_ownership.to_string(),
-            ));
+            query_args.push(("transferOwnership", transfer_ownership.to_string()));
         }
         if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+            query_args.push(("useDomainAdminAccess", use_domain_admin_access.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/files/{}/permissions/{}?{}",
-            crate::progenitor_support::encode_path(&file_id.to_string()),
-            crate::progenitor_support::encode_path(&permission_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/files/{}/permissions/{}",
+                self.client.encode_path(file_id),
+                self.client.encode_path(permission_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/files/{fileId}/permissions/{permissionId}",
+                    &[
+                        ("fileId", file_id.to_string()),
+                        ("permissionId", permission_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * This function performs a `PATCH` to the `/files/{fileId}/permissions/{permissionId}` endpoint.
+     *
+     * As opposed to `update`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn update_if_changed<C: serde::Serialize>(
+        &self,
+        file_id: &str,
+        permission_id: &str,
+        remove_expiration: bool,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        transfer_ownership: bool,
+        use_domain_admin_access: bool,
+        current: &C,
+        body: &crate::types::PermissionRequest,
+    ) -> Result<Option<crate::types::Permission>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if remove_expiration {
+            query_args.push(("removeExpiration", remove_expiration.to_string()));
+        }
+        if supports_all_drives {
+            query_args.push(("supportsAllDrives", supports_all_drives.to_string()));
+        }
+        if supports_team_drives {
+            query_args.push(("supportsTeamDrives", supports_team_drives.to_string()));
+        }
+        if transfer_ownership {
+            query_args.push(("transferOwnership", transfer_ownership.to_string()));
+        }
+        if use_domain_admin_access {
+            query_args.push(("useDomainAdminAccess", use_domain_admin_access.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/files/{}/permissions/{}",
+                self.client.encode_path(file_id),
+                self.client.encode_path(permission_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/files/{fileId}/permissions/{permissionId}",
+                    &[
+                        ("fileId", file_id.to_string()),
+                        ("permissionId", permission_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`Permissions`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn PermissionsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait PermissionsApi: Send + Sync {
+    async fn list(
+        &self,
+        file_id: &str,
+        include_permissions_for_view: &str,
+        page_size: i64,
+        page_token: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        use_domain_admin_access: bool,
+    ) -> Result<Vec<crate::types::Permission>>;
+    async fn list_all(
+        &self,
+        file_id: &str,
+        include_permissions_for_view: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        use_domain_admin_access: bool,
+    ) -> Result<Vec<crate::types::Permission>>;
+    async fn create(
+        &self,
+        file_id: &str,
+        email_message: &str,
+        move_to_new_owners_root: bool,
+        send_notification_email: bool,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        transfer_ownership: bool,
+        use_domain_admin_access: bool,
+        body: &crate::types::PermissionRequest,
+    ) -> Result<crate::types::Permission>;
+    async fn get(
+        &self,
+        file_id: &str,
+        permission_id: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        use_domain_admin_access: bool,
+    ) -> Result<crate::types::Permission>;
+    async fn delete(
+        &self,
+        file_id: &str,
+        permission_id: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        use_domain_admin_access: bool,
+    ) -> Result<()>;
+    async fn delete_if_exists(
+        &self,
+        file_id: &str,
+        permission_id: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        use_domain_admin_access: bool,
+    ) -> Result<bool>;
+    async fn update(
+        &self,
+        file_id: &str,
+        permission_id: &str,
+        remove_expiration: bool,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        transfer_ownership: bool,
+        use_domain_admin_access: bool,
+        body: &crate::types::PermissionRequest,
+    ) -> Result<crate::types::Permission>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl PermissionsApi for Permissions {
+    async fn list(
+        &self,
+        file_id: &str,
+        include_permissions_for_view: &str,
+        page_size: i64,
+        page_token: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        use_domain_admin_access: bool,
+    ) -> Result<Vec<crate::types::Permission>> {
+        Permissions::list(
+            self,
+            file_id,
+            include_permissions_for_view,
+            page_size,
+            page_token,
+            supports_all_drives,
+            supports_team_drives,
+            use_domain_admin_access,
+        )
+        .await
+    }
+    async fn list_all(
+        &self,
+        file_id: &str,
+        include_permissions_for_view: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        use_domain_admin_access: bool,
+    ) -> Result<Vec<crate::types::Permission>> {
+        Permissions::list_all(
+            self,
+            file_id,
+            include_permissions_for_view,
+            supports_all_drives,
+            supports_team_drives,
+            use_domain_admin_access,
+        )
+        .await
+    }
+    async fn create(
+        &self,
+        file_id: &str,
+        email_message: &str,
+        move_to_new_owners_root: bool,
+        send_notification_email: bool,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        transfer_ownership: bool,
+        use_domain_admin_access: bool,
+        body: &crate::types::PermissionRequest,
+    ) -> Result<crate::types::Permission> {
+        Permissions::create(
+            self,
+            file_id,
+            email_message,
+            move_to_new_owners_root,
+            send_notification_email,
+            supports_all_drives,
+            supports_team_drives,
+            transfer_ownership,
+            use_domain_admin_access,
+            body,
+        )
+        .await
+    }
+    async fn get(
+        &self,
+        file_id: &str,
+        permission_id: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        use_domain_admin_access: bool,
+    ) -> Result<crate::types::Permission> {
+        Permissions::get(
+            self,
+            file_id,
+            permission_id,
+            supports_all_drives,
+            supports_team_drives,
+            use_domain_admin_access,
+        )
+        .await
+    }
+    async fn delete(
+        &self,
+        file_id: &str,
+        permission_id: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        use_domain_admin_access: bool,
+    ) -> Result<()> {
+        Permissions::delete(
+            self,
+            file_id,
+            permission_id,
+            supports_all_drives,
+            supports_team_drives,
+            use_domain_admin_access,
+        )
+        .await
+    }
+    async fn delete_if_exists(
+        &self,
+        file_id: &str,
+        permission_id: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        use_domain_admin_access: bool,
+    ) -> Result<bool> {
+        Permissions::delete_if_exists(
+            self,
+            file_id,
+            permission_id,
+            supports_all_drives,
+            supports_team_drives,
+            use_domain_admin_access,
+        )
+        .await
+    }
+    async fn update(
+        &self,
+        file_id: &str,
+        permission_id: &str,
+        remove_expiration: bool,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        transfer_ownership: bool,
+        use_domain_admin_access: bool,
+        body: &crate::types::PermissionRequest,
+    ) -> Result<crate::types::Permission> {
+        Permissions::update(
+            self,
+            file_id,
+            permission_id,
+            remove_expiration,
+            supports_all_drives,
+            supports_team_drives,
+            transfer_ownership,
+            use_domain_admin_access,
+            body,
+        )
+        .await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Replies {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Replies {
         Replies { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Replies {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/files/{fileId}/comments/{commentId}/replies` endpoint.
      *
@@ -33,25 +39,37 @@ impl Replies {
         page_size: i64,
         page_token: &str,
     ) -> Result<Vec<crate::types::Reply>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if include_deleted {
-            query_args.push(("includeDeleted".to_string(), include_deleted.to_string()));
+            query_args.push(("includeDeleted", include_deleted.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("pageSize".to_string(), page_size.to_string()));
+            query_args.push(("pageSize", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/files/{}/comments/{}/replies?{}",
-            crate::progenitor_support::encode_path(&file_id.to_string()),
-            crate::progenitor_support::encode_path(&comment_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/files/{}/comments/{}/replies",
+                self.client.encode_path(file_id),
+                self.client.encode_path(comment_id)
+            ),
+            &query_args,
         );
 
-        let resp: crate::types::ReplyList = self.client.get(&url, None).await?;
+        let resp: crate::types::ReplyList =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/files/{fileId}/comments/{commentId}/replies",
+                    &[
+                        ("fileId", file_id.to_string()),
+                        ("commentId", comment_id.to_string()),
+                    ],
+                )
+            })?;
 
         // Return our response data.
         Ok(resp.replies)
@@ -70,19 +88,30 @@ impl Replies {
         comment_id: &str,
         include_deleted: bool,
     ) -> Result<Vec<crate::types::Reply>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if include_deleted {
-            query_args.push(("includeDeleted".to_string(), include_deleted.to_string()));
+            query_args.push(("includeDeleted", include_deleted.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/files/{}/comments/{}/replies?{}",
-            crate::progenitor_support::encode_path(&file_id.to_string()),
-            crate::progenitor_support::encode_path(&comment_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/files/{}/comments/{}/replies",
+                self.client.encode_path(file_id),
+                self.client.encode_path(comment_id)
+            ),
+            &query_args,
         );
 
-        let mut resp: crate::types::ReplyList = self.client.get(&url, None).await?;
+        let mut resp: crate::types::ReplyList =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/files/{fileId}/comments/{commentId}/replies",
+                    &[
+                        ("fileId", file_id.to_string()),
+                        ("commentId", comment_id.to_string()),
+                    ],
+                )
+            })?;
 
         let mut replies = resp.replies;
         let mut page = resp.next_page_token;
@@ -132,13 +161,23 @@ impl Replies {
     ) -> Result<crate::types::Reply> {
         let url = format!(
             "/files/{}/comments/{}/replies",
-            crate::progenitor_support::encode_path(&file_id.to_string()),
-            crate::progenitor_support::encode_path(&comment_id.to_string()),
+            self.client.encode_path(file_id),
+            self.client.encode_path(comment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/files/{fileId}/comments/{commentId}/replies",
+                    &[
+                        ("fileId", file_id.to_string()),
+                        ("commentId", comment_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -160,20 +199,31 @@ impl Replies {
         reply_id: &str,
         include_deleted: bool,
     ) -> Result<crate::types::Reply> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if include_deleted {
-            query_args.push(("includeDeleted".to_string(), include_deleted.to_string()));
+            query_args.push(("includeDeleted", include_deleted.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/files/{}/comments/{}/replies/{}?{}",
-            crate::progenitor_support::encode_path(&file_id.to_string()),
-            crate::progenitor_support::encode_path(&comment_id.to_string()),
-            crate::progenitor_support::encode_path(&reply_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/files/{}/comments/{}/replies/{}",
+                self.client.encode_path(file_id),
+                self.client.encode_path(comment_id),
+                self.client.encode_path(reply_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/files/{fileId}/comments/{commentId}/replies/{replyId}",
+                &[
+                    ("fileId", file_id.to_string()),
+                    ("commentId", comment_id.to_string()),
+                    ("replyId", reply_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -190,12 +240,56 @@ impl Replies {
     pub async fn delete(&self, file_id: &str, comment_id: &str, reply_id: &str) -> Result<()> {
         let url = format!(
             "/files/{}/comments/{}/replies/{}",
-            crate::progenitor_support::encode_path(&file_id.to_string()),
-            crate::progenitor_support::encode_path(&comment_id.to_string()),
-            crate::progenitor_support::encode_path(&reply_id.to_string()),
+            self.client.encode_path(file_id),
+            self.client.encode_path(comment_id),
+            self.client.encode_path(reply_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/files/{fileId}/comments/{commentId}/replies/{replyId}",
+                &[
+                    ("fileId", file_id.to_string()),
+                    ("commentId", comment_id.to_string()),
+                    ("replyId", reply_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/files/{fileId}/comments/{commentId}/replies/{replyId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        reply_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/files/{}/comments/{}/replies/{}",
+            self.client.encode_path(file_id),
+            self.client.encode_path(comment_id),
+            self.client.encode_path(reply_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/files/{fileId}/comments/{commentId}/replies/{replyId}",
+                    &[
+                        ("fileId", file_id.to_string()),
+                        ("commentId", comment_id.to_string()),
+                        ("replyId", reply_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -218,13 +312,177 @@ impl Replies {
     ) -> Result<crate::types::Reply> {
         let url = format!(
             "/files/{}/comments/{}/replies/{}",
-            crate::progenitor_support::encode_path(&file_id.to_string()),
-            crate::progenitor_support::encode_path(&comment_id.to_string()),
-            crate::progenitor_support::encode_path(&reply_id.to_string()),
+            self.client.encode_path(file_id),
+            self.client.encode_path(comment_id),
+            self.client.encode_path(reply_id),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/files/{fileId}/comments/{commentId}/replies/{replyId}",
+                    &[
+                        ("fileId", file_id.to_string()),
+                        ("commentId", comment_id.to_string()),
+                        ("replyId", reply_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * This function performs a `PATCH` to the `/files/{fileId}/comments/{commentId}/replies/{replyId}` endpoint.
+     *
+     * As opposed to `update`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn update_if_changed<C: serde::Serialize>(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        reply_id: &str,
+        current: &C,
+        body: &crate::types::Reply,
+    ) -> Result<Option<crate::types::Reply>> {
+        let url = format!(
+            "/files/{}/comments/{}/replies/{}",
+            self.client.encode_path(file_id),
+            self.client.encode_path(comment_id),
+            self.client.encode_path(reply_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/files/{fileId}/comments/{commentId}/replies/{replyId}",
+                    &[
+                        ("fileId", file_id.to_string()),
+                        ("commentId", comment_id.to_string()),
+                        ("replyId", reply_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`Replies`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn RepliesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait RepliesApi: Send + Sync {
+    async fn list(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        include_deleted: bool,
+        page_size: i64,
+        page_token: &str,
+    ) -> Result<Vec<crate::types::Reply>>;
+    async fn list_all(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        include_deleted: bool,
+    ) -> Result<Vec<crate::types::Reply>>;
+    async fn create(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        body: &crate::types::Reply,
+    ) -> Result<crate::types::Reply>;
+    async fn get(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        reply_id: &str,
+        include_deleted: bool,
+    ) -> Result<crate::types::Reply>;
+    async fn delete(&self, file_id: &str, comment_id: &str, reply_id: &str) -> Result<()>;
+    async fn delete_if_exists(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        reply_id: &str,
+    ) -> Result<bool>;
+    async fn update(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        reply_id: &str,
+        body: &crate::types::Reply,
+    ) -> Result<crate::types::Reply>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl RepliesApi for Replies {
+    async fn list(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        include_deleted: bool,
+        page_size: i64,
+        page_token: &str,
+    ) -> Result<Vec<crate::types::Reply>> {
+        Replies::list(
+            self,
+            file_id,
+            comment_id,
+            include_deleted,
+            page_size,
+            page_token,
+        )
+        .await
+    }
+    async fn list_all(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        include_deleted: bool,
+    ) -> Result<Vec<crate::types::Reply>> {
+        Replies::list_all(self, file_id, comment_id, include_deleted).await
+    }
+    async fn create(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        body: &crate::types::Reply,
+    ) -> Result<crate::types::Reply> {
+        Replies::create(self, file_id, comment_id, body).await
+    }
+    async fn get(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        reply_id: &str,
+        include_deleted: bool,
+    ) -> Result<crate::types::Reply> {
+        Replies::get(self, file_id, comment_id, reply_id, include_deleted).await
+    }
+    async fn delete(&self, file_id: &str, comment_id: &str, reply_id: &str) -> Result<()> {
+        Replies::delete(self, file_id, comment_id, reply_id).await
+    }
+    async fn delete_if_exists(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        reply_id: &str,
+    ) -> Result<bool> {
+        Replies::delete_if_exists(self, file_id, comment_id, reply_id).await
+    }
+    async fn update(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        reply_id: &str,
+        body: &crate::types::Reply,
+    ) -> Result<crate::types::Reply> {
+        Replies::update(self, file_id, comment_id, reply_id, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Revisions {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Revisions {
         Revisions { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Revisions {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/files/{fileId}/revisions` endpoint.
      *
@@ -29,21 +35,27 @@ impl Revisions {
         page_size: i64,
         page_token: &str,
     ) -> Result<Vec<crate::types::Revision>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("pageSize".to_string(), page_size.to_string()));
+            query_args.push(("pageSize", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/files/{}/revisions?{}",
-            crate::progenitor_support::encode_path(&file_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/files/{}/revisions", self.client.encode_path(file_id)),
+            &query_args,
         );
 
-        let resp: crate::types::RevisionList = self.client.get(&url, None).await?;
+        let resp: crate::types::RevisionList =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/files/{fileId}/revisions",
+                    &[("fileId", file_id.to_string())],
+                )
+            })?;
 
         // Return our response data.
         Ok(resp.revisions)
@@ -57,12 +69,16 @@ impl Revisions {
      * Lists a file's revisions.
      */
     pub async fn list_all(&self, file_id: &str) -> Result<Vec<crate::types::Revision>> {
-        let url = format!(
-            "/files/{}/revisions",
-            crate::progenitor_support::encode_path(&file_id.to_string()),
-        );
+        let url = format!("/files/{}/revisions", self.client.encode_path(file_id),);
 
-        let mut resp: crate::types::RevisionList = self.client.get(&url, None).await?;
+        let mut resp: crate::types::RevisionList =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/files/{fileId}/revisions",
+                    &[("fileId", file_id.to_string())],
+                )
+            })?;
 
         let mut revisions = resp.revisions;
         let mut page = resp.next_page_token;
@@ -111,22 +127,29 @@ impl Revisions {
         revision_id: &str,
         acknowledge_abuse: bool,
     ) -> Result<crate::types::Revision> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if acknowledge_abuse {
-            query_args.push((
-                "acknowledgeAbuse".to_string(),
-                acknowledge_abuse.to_string(),
-            ));
+            query_args.push(("acknowledgeAbuse", acknowledge_abuse.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/files/{}/revisions/{}?{}",
-            crate::progenitor_support::encode_path(&file_id.to_string()),
-            crate::progenitor_support::encode_path(&revision_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/files/{}/revisions/{}",
+                self.client.encode_path(file_id),
+                self.client.encode_path(revision_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/files/{fileId}/revisions/{revisionId}",
+                &[
+                    ("fileId", file_id.to_string()),
+                    ("revisionId", revision_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -142,11 +165,47 @@ impl Revisions {
     pub async fn delete(&self, file_id: &str, revision_id: &str) -> Result<()> {
         let url = format!(
             "/files/{}/revisions/{}",
-            crate::progenitor_support::encode_path(&file_id.to_string()),
-            crate::progenitor_support::encode_path(&revision_id.to_string()),
+            self.client.encode_path(file_id),
+            self.client.encode_path(revision_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/files/{fileId}/revisions/{revisionId}",
+                &[
+                    ("fileId", file_id.to_string()),
+                    ("revisionId", revision_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/files/{fileId}/revisions/{revisionId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, file_id: &str, revision_id: &str) -> Result<bool> {
+        let url = format!(
+            "/files/{}/revisions/{}",
+            self.client.encode_path(file_id),
+            self.client.encode_path(revision_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/files/{fileId}/revisions/{revisionId}",
+                    &[
+                        ("fileId", file_id.to_string()),
+                        ("revisionId", revision_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -167,12 +226,122 @@ impl Revisions {
     ) -> Result<crate::types::Revision> {
         let url = format!(
             "/files/{}/revisions/{}",
-            crate::progenitor_support::encode_path(&file_id.to_string()),
-            crate::progenitor_support::encode_path(&revision_id.to_string()),
+            self.client.encode_path(file_id),
+            self.client.encode_path(revision_id),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/files/{fileId}/revisions/{revisionId}",
+                    &[
+                        ("fileId", file_id.to_string()),
+                        ("revisionId", revision_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * This function performs a `PATCH` to the `/files/{fileId}/revisions/{revisionId}` endpoint.
+     *
+     * As opposed to `update`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn update_if_changed<C: serde::Serialize>(
+        &self,
+        file_id: &str,
+        revision_id: &str,
+        current: &C,
+        body: &crate::types::Revision,
+    ) -> Result<Option<crate::types::Revision>> {
+        let url = format!(
+            "/files/{}/revisions/{}",
+            self.client.encode_path(file_id),
+            self.client.encode_path(revision_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/files/{fileId}/revisions/{revisionId}",
+                    &[
+                        ("fileId", file_id.to_string()),
+                        ("revisionId", revision_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`Revisions`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn RevisionsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait RevisionsApi: Send + Sync {
+    async fn list(
+        &self,
+        file_id: &str,
+        page_size: i64,
+        page_token: &str,
+    ) -> Result<Vec<crate::types::Revision>>;
+    async fn list_all(&self, file_id: &str) -> Result<Vec<crate::types::Revision>>;
+    async fn get(
+        &self,
+        file_id: &str,
+        revision_id: &str,
+        acknowledge_abuse: bool,
+    ) -> Result<crate::types::Revision>;
+    async fn delete(&self, file_id: &str, revision_id: &str) -> Result<()>;
+    async fn delete_if_exists(&self, file_id: &str, revision_id: &str) -> Result<bool>;
+    async fn update(
+        &self,
+        file_id: &str,
+        revision_id: &str,
+        body: &crate::types::Revision,
+    ) -> Result<crate::types::Revision>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl RevisionsApi for Revisions {
+    async fn list(
+        &self,
+        file_id: &str,
+        page_size: i64,
+        page_token: &str,
+    ) -> Result<Vec<crate::types::Revision>> {
+        Revisions::list(self, file_id, page_size, page_token).await
+    }
+    async fn list_all(&self, file_id: &str) -> Result<Vec<crate::types::Revision>> {
+        Revisions::list_all(self, file_id).await
+    }
+    async fn get(
+        &self,
+        file_id: &str,
+        revision_id: &str,
+        acknowledge_abuse: bool,
+    ) -> Result<crate::types::Revision> {
+        Revisions::get(self, file_id, revision_id, acknowledge_abuse).await
+    }
+    async fn delete(&self, file_id: &str, revision_id: &str) -> Result<()> {
+        Revisions::delete(self, file_id, revision_id).await
+    }
+    async fn delete_if_exists(&self, file_id: &str, revision_id: &str) -> Result<bool> {
+        Revisions::delete_if_exists(self, file_id, revision_id).await
+    }
+    async fn update(
+        &self,
+        file_id: &str,
+        revision_id: &str,
+        body: &crate::types::Revision,
+    ) -> Result<crate::types::Revision> {
+        Revisions::update(self, file_id, revision_id, body).await
     }
 }
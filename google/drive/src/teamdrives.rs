@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Teamdrives {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Teamdrives {
         Teamdrives { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Teamdrives {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/teamdrives` endpoint.
      *
@@ -31,26 +37,27 @@ impl Teamdrives {
         q: &str,
         use_domain_admin_access: bool,
     ) -> Result<Vec<crate::types::TeamDrive>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("pageSize".to_string(), page_size.to_string()));
+            query_args.push(("pageSize", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("pageToken".to_string(), page_token.to_string()));
+            query_args.push(("pageToken", page_token.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+            query_args.push(("useDomainAdminAccess", use_domain_admin_access.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/teamdrives?{}", query_);
+        let url = crate::Client::url_with_query("/teamdrives", &query_args);
 
-        let resp: crate::types::TeamDriveList = self.client.get(&url, None).await?;
+        let resp: crate::types::TeamDriveList = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/teamdrives", &[]))?;
 
         // Return our response data.
         Ok(resp.team_drives)
@@ -68,20 +75,20 @@ impl Teamdrives {
         q: &str,
         use_domain_admin_access: bool,
     ) -> Result<Vec<crate::types::TeamDrive>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+            query_args.push(("useDomainAdminAccess", use_domain_admin_access.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/teamdrives?{}", query_);
+        let url = crate::Client::url_with_query("/teamdrives", &query_args);
 
-        let mut resp: crate::types::TeamDriveList = self.client.get(&url, None).await?;
+        let mut resp: crate::types::TeamDriveList = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/teamdrives", &[]))?;
 
         let mut team_drives = resp.team_drives;
         let mut page = resp.next_page_token;
@@ -127,16 +134,16 @@ impl Teamdrives {
         request_id: &str,
         body: &crate::types::TeamDrive,
     ) -> Result<crate::types::TeamDrive> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !request_id.is_empty() {
-            query_args.push(("requestId".to_string(), request_id.to_string()));
+            query_args.push(("requestId", request_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/teamdrives?{}", query_);
+        let url = crate::Client::url_with_query("/teamdrives", &query_args);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/teamdrives", &[]))
     }
 
     /**
@@ -154,21 +161,22 @@ impl Teamdrives {
         team_drive_id: &str,
         use_domain_admin_access: bool,
     ) -> Result<crate::types::TeamDrive> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+            query_args.push(("useDomainAdminAccess", use_domain_admin_access.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/teamdrives/{}?{}",
-            crate::progenitor_support::encode_path(&team_drive_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/teamdrives/{}", self.client.encode_path(team_drive_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/teamdrives/{teamDriveId}",
+                &[("teamDriveId", team_drive_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -181,12 +189,35 @@ impl Teamdrives {
      * * `team_drive_id: &str` -- A link to this theme's background image.
      */
     pub async fn delete(&self, team_drive_id: &str) -> Result<()> {
-        let url = format!(
-            "/teamdrives/{}",
-            crate::progenitor_support::encode_path(&team_drive_id.to_string()),
-        );
+        let url = format!("/teamdrives/{}", self.client.encode_path(team_drive_id),);
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/teamdrives/{teamDriveId}",
+                &[("teamDriveId", team_drive_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/teamdrives/{teamDriveId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, team_drive_id: &str) -> Result<bool> {
+        let url = format!("/teamdrives/{}", self.client.encode_path(team_drive_id),);
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/teamdrives/{teamDriveId}",
+                    &[("teamDriveId", team_drive_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -205,22 +236,144 @@ impl Teamdrives {
         use_domain_admin_access: bool,
         body: &crate::types::TeamDrive,
     ) -> Result<crate::types::TeamDrive> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if use_domain_admin_access {
+            query_args.push(("useDomainAdminAccess", use_domain_admin_access.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!("/teamdrives/{}", self.client.encode_path(team_drive_id)),
+            &query_args,
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/teamdrives/{teamDriveId}",
+                    &[("teamDriveId", team_drive_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * This function performs a `PATCH` to the `/teamdrives/{teamDriveId}` endpoint.
+     *
+     * As opposed to `update`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn update_if_changed<C: serde::Serialize>(
+        &self,
+        team_drive_id: &str,
+        use_domain_admin_access: bool,
+        current: &C,
+        body: &crate::types::TeamDrive,
+    ) -> Result<Option<crate::types::TeamDrive>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if use_domain_admin_access {
-            query_args.push((
-                "useDomainAdminAccess".to_string(),
-                use_domain_admin_access.to_string(),
-            ));
+            query_args.push(("useDomainAdminAccess", use_domain_admin_access.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/teamdrives/{}?{}",
-            crate::progenitor_support::encode_path(&team_drive_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/teamdrives/{}", self.client.encode_path(team_drive_id)),
+            &query_args,
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/teamdrives/{teamDriveId}",
+                    &[("teamDriveId", team_drive_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`Teamdrives`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn TeamdrivesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait TeamdrivesApi: Send + Sync {
+    async fn list(
+        &self,
+        page_size: i64,
+        page_token: &str,
+        q: &str,
+        use_domain_admin_access: bool,
+    ) -> Result<Vec<crate::types::TeamDrive>>;
+    async fn list_all(
+        &self,
+        q: &str,
+        use_domain_admin_access: bool,
+    ) -> Result<Vec<crate::types::TeamDrive>>;
+    async fn create(
+        &self,
+        request_id: &str,
+        body: &crate::types::TeamDrive,
+    ) -> Result<crate::types::TeamDrive>;
+    async fn get(
+        &self,
+        team_drive_id: &str,
+        use_domain_admin_access: bool,
+    ) -> Result<crate::types::TeamDrive>;
+    async fn delete(&self, team_drive_id: &str) -> Result<()>;
+    async fn delete_if_exists(&self, team_drive_id: &str) -> Result<bool>;
+    async fn update(
+        &self,
+        team_drive_id: &str,
+        use_domain_admin_access: bool,
+        body: &crate::types::TeamDrive,
+    ) -> Result<crate::types::TeamDrive>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl TeamdrivesApi for Teamdrives {
+    async fn list(
+        &self,
+        page_size: i64,
+        page_token: &str,
+        q: &str,
+        use_domain_admin_access: bool,
+    ) -> Result<Vec<crate::types::TeamDrive>> {
+        Teamdrives::list(self, page_size, page_token, q, use_domain_admin_access).await
+    }
+    async fn list_all(
+        &self,
+        q: &str,
+        use_domain_admin_access: bool,
+    ) -> Result<Vec<crate::types::TeamDrive>> {
+        Teamdrives::list_all(self, q, use_domain_admin_access).await
+    }
+    async fn create(
+        &self,
+        request_id: &str,
+        body: &crate::types::TeamDrive,
+    ) -> Result<crate::types::TeamDrive> {
+        Teamdrives::create(self, request_id, body).await
+    }
+    async fn get(
+        &self,
+        team_drive_id: &str,
+        use_domain_admin_access: bool,
+    ) -> Result<crate::types::TeamDrive> {
+        Teamdrives::get(self, team_drive_id, use_domain_admin_access).await
+    }
+    async fn delete(&self, team_drive_id: &str) -> Result<()> {
+        Teamdrives::delete(self, team_drive_id).await
+    }
+    async fn delete_if_exists(&self, team_drive_id: &str) -> Result<bool> {
+        Teamdrives::delete_if_exists(self, team_drive_id).await
+    }
+    async fn update(
+        &self,
+        team_drive_id: &str,
+        use_domain_admin_access: bool,
+        body: &crate::types::TeamDrive,
+    ) -> Result<crate::types::TeamDrive> {
+        Teamdrives::update(self, team_drive_id, use_domain_admin_access, body).await
     }
 }
@@ -80,7 +80,7 @@ impl PermissionOps for crate::permissions::Permissions {
         }
 
         // If we got here we could not find the permission so let's create it.
-        let perm = crate::types::Permission {
+        let perm = crate::types::PermissionRequest {
             allow_file_discovery: None,
             deleted: None,
             display_name: String::new(),
@@ -89,27 +89,26 @@ impl PermissionOps for crate::permissions::Permissions {
             expiration_time: None,
             id: String::new(),
             kind: String::new(),
-            permission_details: Default::default(),
             photo_link: String::new(),
             role: role.to_string(),
-            team_drive_permission_details: Default::default(),
             type_: type_.to_string(),
             view: String::new(),
         };
 
         // Create the permission and return it.
-        self.create(
-            file_id,
-            email_message,
-            false, // move_to_new_owners_root
-            send_notification_email,
-            true,  // supports_all_drives
-            true,  // supports_team_drives
-            false, // transfer_ownership
-            use_domain_admin_access,
-            &perm,
-        )
-        .await
+        Ok(self
+            .create(
+                file_id,
+                email_message,
+                false, // move_to_new_owners_root
+                send_notification_email,
+                true,  // supports_all_drives
+                true,  // supports_team_drives
+                false, // transfer_ownership
+                use_domain_admin_access,
+                &perm,
+            )
+            .await?)
     }
 }
 
@@ -162,20 +161,21 @@ impl FileOps for crate::files::Files {
             query = format!("{} and '{}' in parents", query, parent_id);
         }
 
-        self.list_all(
-            "drive",  // corpora
-            drive_id, // drive id
-            true,     // include_items_from_all_drives
-            "",       // include_permissions_for_view
-            false,    // include_team_drive_items
-            "",       // order_by
-            &query,   // query
-            "",       // spaces
-            true,     // supports_all_drives
-            false,    // supports_team_drives
-            "",       // team_drive_id
-        )
-        .await
+        Ok(self
+            .list_all(
+                "drive",  // corpora
+                drive_id, // drive id
+                true,     // include_items_from_all_drives
+                "",       // include_permissions_for_view
+                false,    // include_team_drive_items
+                "",       // order_by
+                &query,   // query
+                "",       // spaces
+                true,     // supports_all_drives
+                false,    // supports_team_drives
+                "",       // team_drive_id
+            )
+            .await?)
     }
 
     /// Create or update a file in a drive.
@@ -230,11 +230,7 @@ impl FileOps for crate::files::Files {
         // Build the request to get the URL upload location if we need to create the file.
         let resp = self
             .client
-            .request_raw(
-                method,
-                &uri,
-                Some(reqwest::Body::from(serde_json::to_vec(&f).unwrap())),
-            )
+            .request_raw(method, &uri, Some(self.client.serialize_body(&f)?))
             .await
             .unwrap();
 
@@ -312,7 +308,7 @@ impl FileOps for crate::files::Files {
             .client
             .post(
                 "/files?supportsAllDrives=true&includeItemsFromAllDrives=true",
-                Some(reqwest::Body::from(serde_json::to_vec(&file).unwrap())),
+                Some(self.client.serialize_body(&file)?),
             )
             .await
             .unwrap();
@@ -323,18 +319,9 @@ impl FileOps for crate::files::Files {
     /// Get a file's contents by it's ID. Only works for Google Docs.
     // TODO: make binary content work in the actual library.
     async fn get_contents_by_id(&self, id: &str) -> Result<String> {
-        let mut query_ = String::new();
-        let query_args = vec!["mime_type=text/plain".to_string()];
-        for (i, n) in query_args.iter().enumerate() {
-            if i > 0 {
-                query_.push('&');
-            }
-            query_.push_str(n);
-        }
-        let url = format!(
-            "/files/{}/export?{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/files/{}/export", self.client.encode_path(id)),
+            &[("mime_type", "text/plain".to_string())],
         );
         let resp = self
             .client
@@ -358,12 +345,13 @@ impl FileOps for crate::files::Files {
         }
 
         // Delete the file.
-        self.delete(
-            &files.get(0).unwrap().id,
-            true, // supports all drives
-            true, // supports team drives
-        )
-        .await
+        Ok(self
+            .delete(
+                &files.get(0).unwrap().id,
+                true, // supports all drives
+                true, // supports team drives
+            )
+            .await?)
     }
 }
 
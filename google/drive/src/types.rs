@@ -2,7 +2,35 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+/// Either one of something, or many of them. Some endpoints send back an
+/// object when there is one and an array when there are more, so this takes both.
+// Many is tried first: serde reads a struct from an array of its fields too,
+// so an array would otherwise be taken for One.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum OneOrMany<T> {
+    Many(Vec<T>),
+    One(T),
+}
+
+impl<T> OneOrMany<T> {
+    /// All of them, as a vector.
+    pub fn into_vec(self) -> Vec<T> {
+        match self {
+            OneOrMany::One(t) => vec![t],
+            OneOrMany::Many(v) => v,
+        }
+    }
+}
+
+impl<T> Default for OneOrMany<T> {
+    fn default() -> Self {
+        OneOrMany::Many(Vec::new())
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DriveThemes {
     /**
      * A link to this theme's background image.
@@ -10,8 +38,7 @@ pub struct DriveThemes {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "backgroundImageLink"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub background_image_link: String,
     /**
@@ -20,8 +47,7 @@ pub struct DriveThemes {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "colorRgb"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub color_rgb: String,
     /**
@@ -37,6 +63,7 @@ pub struct DriveThemes {
 
 /// The user's storage quota limits and usage. All fields are measured in bytes.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct StorageQuota {
     /**
      * The user's storage quota limits and usage. All fields are measured in bytes.
@@ -62,8 +89,7 @@ pub struct StorageQuota {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "usageInDrive"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub usage_in_drive: i64,
     /**
@@ -72,41 +98,29 @@ pub struct StorageQuota {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "usageInDriveTrash"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub usage_in_drive_trash: i64,
 }
 
 /// Information about the user, the user's Drive, and system capabilities.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct About {
     /**
      * Information about the user, the user's Drive, and system capabilities.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "appInstalled"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub app_installed: Option<bool>,
     /**
      * Information about the user, the user's Drive, and system capabilities.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canCreateDrives"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_create_drives: Option<bool>,
     /**
      * Information about the user, the user's Drive, and system capabilities.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canCreateTeamDrives"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_create_team_drives: Option<bool>,
     /**
      * Information about the user, the user's Drive, and system capabilities.
@@ -114,8 +128,7 @@ pub struct About {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "driveThemes"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub drive_themes: Vec<DriveThemes>,
     /**
@@ -124,8 +137,7 @@ pub struct About {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "exportFormats"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub export_formats: Vec<String>,
     /**
@@ -134,8 +146,7 @@ pub struct About {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "folderColorPalette"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub folder_color_palette: Vec<String>,
     /**
@@ -144,8 +155,7 @@ pub struct About {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "importFormats"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub import_formats: Vec<String>,
     /**
@@ -163,8 +173,7 @@ pub struct About {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "maxImportSizes"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub max_import_sizes: i64,
     /**
@@ -173,18 +182,13 @@ pub struct About {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "maxUploadSize"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub max_upload_size: i64,
     /**
      * Information about the user, the user's Drive, and system capabilities.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "storageQuota"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub storage_quota: Option<StorageQuota>,
     /**
      * Information about the user, the user's Drive, and system capabilities.
@@ -192,8 +196,7 @@ pub struct About {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "teamDriveThemes"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub team_drive_themes: Vec<DriveThemes>,
     /**
@@ -205,6 +208,7 @@ pub struct About {
 
 /// A change to a file or shared drive.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Change {
     /**
      * A change to a file or shared drive.
@@ -212,8 +216,7 @@ pub struct Change {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "changeType"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub change_type: String,
     /**
@@ -227,8 +230,7 @@ pub struct Change {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "driveId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub drive_id: String,
     /**
@@ -242,8 +244,7 @@ pub struct Change {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "fileId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub file_id: String,
     /**
@@ -263,7 +264,7 @@ pub struct Change {
     /**
      * A change to a file or shared drive.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "teamDrive")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub team_drive: Option<TeamDrive>,
     /**
      * A change to a file or shared drive.
@@ -271,8 +272,7 @@ pub struct Change {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "teamDriveId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub team_drive_id: String,
     /**
@@ -298,6 +298,7 @@ pub struct Change {
 
 /// A list of changes for a user.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ChangeList {
     /**
      * A list of changes for a user.
@@ -323,8 +324,7 @@ pub struct ChangeList {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "newStartPageToken"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub new_start_page_token: String,
     /**
@@ -333,14 +333,39 @@ pub struct ChangeList {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub next_page_token: String,
 }
 
+impl IntoIterator for ChangeList {
+    type Item = Change;
+    type IntoIter = std::vec::IntoIter<Change>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.changes.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ChangeList {
+    type Item = &'a Change;
+    type IntoIter = std::slice::Iter<'a, Change>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.changes.iter()
+    }
+}
+
+impl ChangeList {
+    /// Iterate over the `changes`.
+    pub fn iter(&self) -> std::slice::Iter<'_, Change> {
+        self.changes.iter()
+    }
+}
+
 /// An notification channel used to watch for resource changes.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Channel {
     /**
      * An notification channel used to watch for resource changes.
@@ -398,8 +423,7 @@ pub struct Channel {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "resourceId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub resource_id: String,
     /**
@@ -408,8 +432,7 @@ pub struct Channel {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "resourceUri"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub resource_uri: String,
     /**
@@ -435,6 +458,7 @@ pub struct Channel {
 
 /// The file content to which the comment refers, typically within the anchor region. For a text file, for example, this would be the text at the location of the comment.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct QuotedFileContent {
     /**
      * The file content to which the comment refers, typically within the anchor region. For a text file, for example, this would be the text at the location of the comment.
@@ -442,8 +466,7 @@ pub struct QuotedFileContent {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "mimeType"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub mime_type: String,
     /**
@@ -459,6 +482,7 @@ pub struct QuotedFileContent {
 
 /// A comment on a file.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Comment {
     /**
      * A comment on a file.
@@ -489,8 +513,7 @@ pub struct Comment {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "createdTime"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub created_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
@@ -504,8 +527,7 @@ pub struct Comment {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "htmlContent"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub html_content: String,
     /**
@@ -532,18 +554,13 @@ pub struct Comment {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "modifiedTime"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub modified_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
      * A comment on a file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "quotedFileContent"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub quoted_file_content: Option<QuotedFileContent>,
     /**
      * A comment on a file.
@@ -563,6 +580,7 @@ pub struct Comment {
 
 /// A list of comments on a file.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct CommentList {
     /**
      * A list of comments on a file.
@@ -588,19 +606,44 @@ pub struct CommentList {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub next_page_token: String,
 }
 
+impl IntoIterator for CommentList {
+    type Item = Comment;
+    type IntoIter = std::vec::IntoIter<Comment>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.comments.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a CommentList {
+    type Item = &'a Comment;
+    type IntoIter = std::slice::Iter<'a, Comment>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.comments.iter()
+    }
+}
+
+impl CommentList {
+    /// Iterate over the `comments`.
+    pub fn iter(&self) -> std::slice::Iter<'_, Comment> {
+        self.comments.iter()
+    }
+}
+
 /// A restriction for accessing the content of the file.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ContentRestriction {
     /**
      * A restriction for accessing the content of the file.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "readOnly")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub read_only: Option<bool>,
     /**
      * A restriction for accessing the content of the file.
@@ -614,11 +657,7 @@ pub struct ContentRestriction {
     /**
      * A restriction for accessing the content of the file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "restrictingUser"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub restricting_user: Option<User>,
     /**
      * A restriction for accessing the content of the file.
@@ -626,8 +665,7 @@ pub struct ContentRestriction {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "restrictionTime"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub restriction_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
@@ -644,6 +682,7 @@ pub struct ContentRestriction {
 
 /// An image file and cropping parameters from which a background image for this shared drive is set. This is a write only field; it can only be set on drive.drives.update requests that don't set themeId. When specified, all fields of the backgroundImageFile must be set.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct BackgroundImageFile {
     /**
      * An image file and cropping parameters from which a background image for this shared drive is set. This is a write only field; it can only be set on drive.drives.update requests that don't set themeId. When specified, all fields of the backgroundImageFile must be set.
@@ -669,8 +708,7 @@ pub struct BackgroundImageFile {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "xCoordinate"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub x_coordinate: f64,
     /**
@@ -679,215 +717,141 @@ pub struct BackgroundImageFile {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "yCoordinate"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub y_coordinate: f64,
 }
 
 /// Capabilities the current user has on this shared drive.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Capabilities {
     /**
      * Capabilities the current user has on this shared drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canAddChildren"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_add_children: Option<bool>,
     /**
      * Capabilities the current user has on this shared drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canChangeCopyRequiresWriterPermissionRestriction"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_change_copy_requires_writer_permission_restriction: Option<bool>,
     /**
      * Capabilities the current user has on this shared drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canChangeDomainUsersOnlyRestriction"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_change_domain_users_only_restriction: Option<bool>,
     /**
      * Capabilities the current user has on this shared drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canChangeDriveBackground"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_change_drive_background: Option<bool>,
     /**
      * Capabilities the current user has on this shared drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canChangeDriveMembersOnlyRestriction"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_change_drive_members_only_restriction: Option<bool>,
     /**
      * Capabilities the current user has on this shared drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canComment"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_comment: Option<bool>,
     /**
      * Capabilities the current user has on this shared drive.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "canCopy")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_copy: Option<bool>,
     /**
      * Capabilities the current user has on this shared drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canDeleteChildren"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_delete_children: Option<bool>,
     /**
      * Capabilities the current user has on this shared drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canDeleteDrive"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_delete_drive: Option<bool>,
     /**
      * Capabilities the current user has on this shared drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canDownload"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_download: Option<bool>,
     /**
      * Capabilities the current user has on this shared drive.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "canEdit")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_edit: Option<bool>,
     /**
      * Capabilities the current user has on this shared drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canListChildren"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_list_children: Option<bool>,
     /**
      * Capabilities the current user has on this shared drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canManageMembers"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_manage_members: Option<bool>,
     /**
      * Capabilities the current user has on this shared drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canReadRevisions"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_read_revisions: Option<bool>,
     /**
      * Capabilities the current user has on this shared drive.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "canRename")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_rename: Option<bool>,
     /**
      * Capabilities the current user has on this shared drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canRenameDrive"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_rename_drive: Option<bool>,
     /**
      * Capabilities the current user has on this shared drive.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "canShare")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_share: Option<bool>,
     /**
      * Capabilities the current user has on this shared drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canTrashChildren"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_trash_children: Option<bool>,
 }
 
 /// A set of restrictions that apply to this shared drive or items inside this shared drive.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Restrictions {
     /**
      * A set of restrictions that apply to this shared drive or items inside this shared drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "adminManagedRestrictions"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub admin_managed_restrictions: Option<bool>,
     /**
      * A set of restrictions that apply to this shared drive or items inside this shared drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "copyRequiresWriterPermission"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub copy_requires_writer_permission: Option<bool>,
     /**
      * A set of restrictions that apply to this shared drive or items inside this shared drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "domainUsersOnly"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub domain_users_only: Option<bool>,
     /**
      * A set of restrictions that apply to this shared drive or items inside this shared drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "driveMembersOnly"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub drive_members_only: Option<bool>,
 }
 
 /// Representation of a shared drive.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Drive {
     /**
      * Representation of a shared drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "backgroundImageFile"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub background_image_file: Option<BackgroundImageFile>,
     /**
      * Representation of a shared drive.
@@ -895,8 +859,7 @@ pub struct Drive {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "backgroundImageLink"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub background_image_link: String,
     /**
@@ -910,8 +873,7 @@ pub struct Drive {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "colorRgb"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub color_rgb: String,
     /**
@@ -920,8 +882,7 @@ pub struct Drive {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "createdTime"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub created_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
@@ -967,14 +928,14 @@ pub struct Drive {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "themeId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub theme_id: String,
 }
 
 /// A list of shared drives.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DriveList {
     /**
      * A list of shared drives.
@@ -1000,310 +961,220 @@ pub struct DriveList {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub next_page_token: String,
 }
 
+impl IntoIterator for DriveList {
+    type Item = Drive;
+    type IntoIter = std::vec::IntoIter<Drive>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.drives.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a DriveList {
+    type Item = &'a Drive;
+    type IntoIter = std::slice::Iter<'a, Drive>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.drives.iter()
+    }
+}
+
+impl DriveList {
+    /// Iterate over the `drives`.
+    pub fn iter(&self) -> std::slice::Iter<'_, Drive> {
+        self.drives.iter()
+    }
+}
+
 /// Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct FileCapabilities {
     /**
      * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canAddChildren"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_add_children: Option<bool>,
     /**
      * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canAddFolderFromAnotherDrive"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_add_folder_from_another_drive: Option<bool>,
     /**
      * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canAddMyDriveParent"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_add_my_drive_parent: Option<bool>,
     /**
      * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canChangeCopyRequiresWriterPermission"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_change_copy_requires_writer_permission: Option<bool>,
     /**
      * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canChangeSecurityUpdateEnabled"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_change_security_update_enabled: Option<bool>,
     /**
      * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canChangeViewersCanCopyContent"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_change_viewers_can_copy_content: Option<bool>,
     /**
      * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canComment"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_comment: Option<bool>,
     /**
      * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "canCopy")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_copy: Option<bool>,
     /**
      * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "canDelete")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_delete: Option<bool>,
     /**
      * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canDeleteChildren"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_delete_children: Option<bool>,
     /**
      * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canDownload"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_download: Option<bool>,
     /**
      * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "canEdit")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_edit: Option<bool>,
     /**
      * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canListChildren"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_list_children: Option<bool>,
     /**
      * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canModifyContent"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_modify_content: Option<bool>,
     /**
      * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canModifyContentRestriction"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_modify_content_restriction: Option<bool>,
     /**
      * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canMoveChildrenOutOfDrive"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_move_children_out_of_drive: Option<bool>,
     /**
      * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canMoveChildrenOutOfTeamDrive"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_move_children_out_of_team_drive: Option<bool>,
     /**
      * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canMoveChildrenWithinDrive"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_move_children_within_drive: Option<bool>,
     /**
      * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canMoveChildrenWithinTeamDrive"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_move_children_within_team_drive: Option<bool>,
     /**
      * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canMoveItemIntoTeamDrive"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_move_item_into_team_drive: Option<bool>,
     /**
      * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canMoveItemOutOfDrive"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_move_item_out_of_drive: Option<bool>,
     /**
      * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canMoveItemOutOfTeamDrive"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_move_item_out_of_team_drive: Option<bool>,
     /**
      * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canMoveItemWithinDrive"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_move_item_within_drive: Option<bool>,
     /**
      * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canMoveItemWithinTeamDrive"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_move_item_within_team_drive: Option<bool>,
     /**
      * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canMoveTeamDriveItem"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_move_team_drive_item: Option<bool>,
     /**
      * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canReadDrive"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_read_drive: Option<bool>,
     /**
      * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canReadRevisions"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_read_revisions: Option<bool>,
     /**
      * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canReadTeamDrive"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_read_team_drive: Option<bool>,
     /**
      * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canRemoveChildren"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_remove_children: Option<bool>,
     /**
      * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canRemoveMyDriveParent"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_remove_my_drive_parent: Option<bool>,
     /**
      * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "canRename")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_rename: Option<bool>,
     /**
      * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "canShare")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_share: Option<bool>,
     /**
      * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "canTrash")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_trash: Option<bool>,
     /**
      * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canTrashChildren"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_trash_children: Option<bool>,
     /**
      * Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canUntrash"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_untrash: Option<bool>,
 }
 
 /// A thumbnail for the file. This will only be used if Google Drive cannot generate a standard thumbnail.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Thumbnail {
     /**
      * A thumbnail for the file. This will only be used if Google Drive cannot generate a standard thumbnail.
@@ -1316,14 +1187,14 @@ pub struct Thumbnail {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "mimeType"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub mime_type: String,
 }
 
 /// Additional information about the content of the file. These fields are never populated in responses.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ContentHints {
     /**
      * Additional information about the content of the file. These fields are never populated in responses.
@@ -1331,8 +1202,7 @@ pub struct ContentHints {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "indexableText"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub indexable_text: String,
     /**
@@ -1376,6 +1246,7 @@ pub struct Location {
 
 /// Additional metadata about image media, if available.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ImageMediaMetadata {
     /**
      * Additional metadata about image media, if available.
@@ -1392,8 +1263,7 @@ pub struct ImageMediaMetadata {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "cameraMake"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub camera_make: String,
     /**
@@ -1402,8 +1272,7 @@ pub struct ImageMediaMetadata {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "cameraModel"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub camera_model: String,
     /**
@@ -1412,8 +1281,7 @@ pub struct ImageMediaMetadata {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "colorSpace"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub color_space: String,
     /**
@@ -1422,8 +1290,7 @@ pub struct ImageMediaMetadata {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "exposureBias"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub exposure_bias: f64,
     /**
@@ -1432,8 +1299,7 @@ pub struct ImageMediaMetadata {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "exposureMode"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub exposure_mode: String,
     /**
@@ -1442,14 +1308,13 @@ pub struct ImageMediaMetadata {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "exposureTime"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub exposure_time: f64,
     /**
      * Additional metadata about image media, if available.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "flashUsed")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub flash_used: Option<bool>,
     /**
      * Additional metadata about image media, if available.
@@ -1457,8 +1322,7 @@ pub struct ImageMediaMetadata {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "focalLength"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub focal_length: f64,
     /**
@@ -1476,8 +1340,7 @@ pub struct ImageMediaMetadata {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "isoSpeed"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub iso_speed: i64,
     /**
@@ -1500,8 +1363,7 @@ pub struct ImageMediaMetadata {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "maxApertureValue"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub max_aperture_value: f64,
     /**
@@ -1510,8 +1372,7 @@ pub struct ImageMediaMetadata {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "meteringMode"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub metering_mode: String,
     /**
@@ -1538,8 +1399,7 @@ pub struct ImageMediaMetadata {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "subjectDistance"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub subject_distance: i64,
     /**
@@ -1557,8 +1417,7 @@ pub struct ImageMediaMetadata {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "whiteBalance"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub white_balance: String,
     /**
@@ -1574,29 +1433,23 @@ pub struct ImageMediaMetadata {
 
 /// Contains details about the link URLs that clients are using to refer to this item.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct LinkShareMetadata {
     /**
      * Contains details about the link URLs that clients are using to refer to this item.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "securityUpdateEligible"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub security_update_eligible: Option<bool>,
     /**
      * Contains details about the link URLs that clients are using to refer to this item.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "securityUpdateEnabled"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub security_update_enabled: Option<bool>,
 }
 
 /// Shortcut file details. Only populated for shortcut files, which have the mimeType field set to application/vnd.google-apps.shortcut.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ShortcutDetails {
     /**
      * Shortcut file details. Only populated for shortcut files, which have the mimeType field set to application/vnd.google-apps.shortcut.
@@ -1604,8 +1457,7 @@ pub struct ShortcutDetails {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "targetId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub target_id: String,
     /**
@@ -1614,8 +1466,7 @@ pub struct ShortcutDetails {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "targetMimeType"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub target_mime_type: String,
     /**
@@ -1624,14 +1475,14 @@ pub struct ShortcutDetails {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "targetResourceKey"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub target_resource_key: String,
 }
 
 /// Additional metadata about video media. This may not be available immediately upon upload.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct VideoMediaMetadata {
     /**
      * Additional metadata about video media. This may not be available immediately upon upload.
@@ -1639,8 +1490,7 @@ pub struct VideoMediaMetadata {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "durationMillis"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub duration_millis: i64,
     /**
@@ -1665,6 +1515,7 @@ pub struct VideoMediaMetadata {
 
 /// The metadata for a file.
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct File {
     /**
      * The metadata for a file.
@@ -1672,8 +1523,7 @@ pub struct File {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "appProperties"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub app_properties: String,
     /**
@@ -1684,11 +1534,7 @@ pub struct File {
     /**
      * The metadata for a file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "contentHints"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub content_hints: Option<ContentHints>,
     /**
      * The metadata for a file.
@@ -1696,18 +1542,13 @@ pub struct File {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "contentRestrictions"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub content_restrictions: Vec<ContentRestriction>,
     /**
      * The metadata for a file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "copyRequiresWriterPermission"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub copy_requires_writer_permission: Option<bool>,
     /**
      * The metadata for a file.
@@ -1715,8 +1556,7 @@ pub struct File {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "createdTime"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub created_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
@@ -1734,18 +1574,13 @@ pub struct File {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "driveId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub drive_id: String,
     /**
      * The metadata for a file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "explicitlyTrashed"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub explicitly_trashed: Option<bool>,
     /**
      * The metadata for a file.
@@ -1753,8 +1588,7 @@ pub struct File {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "exportLinks"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub export_links: String,
     /**
@@ -1763,8 +1597,7 @@ pub struct File {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "fileExtension"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub file_extension: String,
     /**
@@ -1773,8 +1606,7 @@ pub struct File {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "folderColorRgb"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub folder_color_rgb: String,
     /**
@@ -1783,27 +1615,18 @@ pub struct File {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "fullFileExtension"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub full_file_extension: String,
     /**
      * The metadata for a file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "hasAugmentedPermissions"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub has_augmented_permissions: Option<bool>,
     /**
      * The metadata for a file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "hasThumbnail"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub has_thumbnail: Option<bool>,
     /**
      * The metadata for a file.
@@ -1811,8 +1634,7 @@ pub struct File {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "headRevisionId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub head_revision_id: String,
     /**
@@ -1821,8 +1643,7 @@ pub struct File {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "iconLink"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub icon_link: String,
     /**
@@ -1837,20 +1658,12 @@ pub struct File {
     /**
      * The metadata for a file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "imageMediaMetadata"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub image_media_metadata: Option<ImageMediaMetadata>,
     /**
      * The metadata for a file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "isAppAuthorized"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub is_app_authorized: Option<bool>,
     /**
      * The metadata for a file.
@@ -1864,20 +1677,12 @@ pub struct File {
     /**
      * The metadata for a file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "lastModifyingUser"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub last_modifying_user: Option<User>,
     /**
      * The metadata for a file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "linkShareMetadata"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub link_share_metadata: Option<LinkShareMetadata>,
     /**
      * The metadata for a file.
@@ -1885,8 +1690,7 @@ pub struct File {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "md5Checksum"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub md_5_checksum: String,
     /**
@@ -1895,18 +1699,13 @@ pub struct File {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "mimeType"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub mime_type: String,
     /**
      * The metadata for a file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "modifiedByMe"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub modified_by_me: Option<bool>,
     /**
      * The metadata for a file.
@@ -1914,8 +1713,7 @@ pub struct File {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "modifiedByMeTime"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub modified_by_me_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
@@ -1924,8 +1722,7 @@ pub struct File {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "modifiedTime"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub modified_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
@@ -1943,14 +1740,13 @@ pub struct File {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "originalFilename"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub original_filename: String,
     /**
      * The metadata for a file.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "ownedByMe")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub owned_by_me: Option<bool>,
     /**
      * The metadata for a file.
@@ -1976,8 +1772,7 @@ pub struct File {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "permissionIds"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub permission_ids: Vec<String>,
     /**
@@ -2004,8 +1799,7 @@ pub struct File {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "quotaBytesUsed"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub quota_bytes_used: i64,
     /**
@@ -2014,8 +1808,7 @@ pub struct File {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "resourceKey"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub resource_key: String,
     /**
@@ -2029,27 +1822,18 @@ pub struct File {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "sharedWithMeTime"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub shared_with_me_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
      * The metadata for a file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "sharingUser"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub sharing_user: Option<User>,
     /**
      * The metadata for a file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "shortcutDetails"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shortcut_details: Option<ShortcutDetails>,
     /**
      * The metadata for a file.
@@ -2080,8 +1864,7 @@ pub struct File {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "teamDriveId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub team_drive_id: String,
     /**
@@ -2090,8 +1873,7 @@ pub struct File {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "thumbnailLink"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub thumbnail_link: String,
     /**
@@ -2100,8 +1882,7 @@ pub struct File {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "thumbnailVersion"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub thumbnail_version: i64,
     /**
@@ -2115,18 +1896,13 @@ pub struct File {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "trashedTime"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub trashed_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
      * The metadata for a file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "trashingUser"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub trashing_user: Option<User>,
     /**
      * The metadata for a file.
@@ -2140,20 +1916,12 @@ pub struct File {
     /**
      * The metadata for a file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "videoMediaMetadata"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub video_media_metadata: Option<VideoMediaMetadata>,
     /**
      * The metadata for a file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "viewedByMe"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub viewed_by_me: Option<bool>,
     /**
      * The metadata for a file.
@@ -2161,18 +1929,13 @@ pub struct File {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "viewedByMeTime"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub viewed_by_me_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
      * The metadata for a file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "viewersCanCopyContent"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub viewers_can_copy_content: Option<bool>,
     /**
      * The metadata for a file.
@@ -2180,8 +1943,7 @@ pub struct File {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "webContentLink"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub web_content_link: String,
     /**
@@ -2190,23 +1952,19 @@ pub struct File {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "webViewLink"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub web_view_link: String,
     /**
      * The metadata for a file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "writersCanShare"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub writers_can_share: Option<bool>,
 }
 
 /// A list of files.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct FileList {
     /**
      * A list of files.
@@ -2220,11 +1978,7 @@ pub struct FileList {
     /**
      * A list of files.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "incompleteSearch"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub incomplete_search: Option<bool>,
     /**
      * A list of files.
@@ -2241,12 +1995,36 @@ pub struct FileList {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub next_page_token: String,
 }
 
+impl IntoIterator for FileList {
+    type Item = File;
+    type IntoIter = std::vec::IntoIter<File>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.files.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a FileList {
+    type Item = &'a File;
+    type IntoIter = std::slice::Iter<'a, File>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.files.iter()
+    }
+}
+
+impl FileList {
+    /// Iterate over the `files`.
+    pub fn iter(&self) -> std::slice::Iter<'_, File> {
+        self.files.iter()
+    }
+}
+
 /// A list of generated file IDs which can be provided in create requests.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GeneratedIds {
@@ -2280,6 +2058,7 @@ pub struct GeneratedIds {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct PermissionDetails {
     /**
      * Whether the user has installed the requesting app.
@@ -2292,8 +2071,7 @@ pub struct PermissionDetails {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "inheritedFrom"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub inherited_from: String,
     /**
@@ -2302,8 +2080,7 @@ pub struct PermissionDetails {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "permissionType"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub permission_type: String,
     /**
@@ -2318,6 +2095,7 @@ pub struct PermissionDetails {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct TeamDrivePermissionDetails {
     /**
      * Whether the user has installed the requesting app.
@@ -2330,8 +2108,7 @@ pub struct TeamDrivePermissionDetails {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "inheritedFrom"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub inherited_from: String,
     /**
@@ -2349,23 +2126,19 @@ pub struct TeamDrivePermissionDetails {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "teamDrivePermissionType"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub team_drive_permission_type: String,
 }
 
 /// A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Permission {
     /**
      * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "allowFileDiscovery"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub allow_file_discovery: Option<bool>,
     /**
      * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
@@ -2378,8 +2151,7 @@ pub struct Permission {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "displayName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub display_name: String,
     /**
@@ -2397,8 +2169,7 @@ pub struct Permission {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "emailAddress"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email_address: String,
     /**
@@ -2407,8 +2178,7 @@ pub struct Permission {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "expirationTime"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub expiration_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
@@ -2435,8 +2205,7 @@ pub struct Permission {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "permissionDetails"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub permission_details: Vec<PermissionDetails>,
     /**
@@ -2445,8 +2214,7 @@ pub struct Permission {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "photoLink"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub photo_link: String,
     /**
@@ -2464,8 +2232,7 @@ pub struct Permission {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "teamDrivePermissionDetails"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub team_drive_permission_details: Vec<TeamDrivePermissionDetails>,
     /**
@@ -2491,6 +2258,7 @@ pub struct Permission {
 
 /// A list of permissions for a file.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct PermissionList {
     /**
      * A list of permissions for a file.
@@ -2507,8 +2275,7 @@ pub struct PermissionList {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub next_page_token: String,
     /**
@@ -2522,8 +2289,34 @@ pub struct PermissionList {
     pub permissions: Vec<Permission>,
 }
 
+impl IntoIterator for PermissionList {
+    type Item = Permission;
+    type IntoIter = std::vec::IntoIter<Permission>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.permissions.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a PermissionList {
+    type Item = &'a Permission;
+    type IntoIter = std::slice::Iter<'a, Permission>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.permissions.iter()
+    }
+}
+
+impl PermissionList {
+    /// Iterate over the `permissions`.
+    pub fn iter(&self) -> std::slice::Iter<'_, Permission> {
+        self.permissions.iter()
+    }
+}
+
 /// A reply to a comment on a file.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Reply {
     /**
      * A reply to a comment on a file.
@@ -2554,8 +2347,7 @@ pub struct Reply {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "createdTime"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub created_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
@@ -2569,8 +2361,7 @@ pub struct Reply {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "htmlContent"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub html_content: String,
     /**
@@ -2597,14 +2388,14 @@ pub struct Reply {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "modifiedTime"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub modified_time: Option<chrono::DateTime<chrono::Utc>>,
 }
 
 /// A list of replies to a comment on a file.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ReplyList {
     /**
      * A list of replies to a comment on a file.
@@ -2621,8 +2412,7 @@ pub struct ReplyList {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub next_page_token: String,
     /**
@@ -2636,8 +2426,34 @@ pub struct ReplyList {
     pub replies: Vec<Reply>,
 }
 
+impl IntoIterator for ReplyList {
+    type Item = Reply;
+    type IntoIter = std::vec::IntoIter<Reply>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.replies.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ReplyList {
+    type Item = &'a Reply;
+    type IntoIter = std::slice::Iter<'a, Reply>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.replies.iter()
+    }
+}
+
+impl ReplyList {
+    /// Iterate over the `replies`.
+    pub fn iter(&self) -> std::slice::Iter<'_, Reply> {
+        self.replies.iter()
+    }
+}
+
 /// The metadata for a revision to a file.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Revision {
     /**
      * The metadata for a revision to a file.
@@ -2645,8 +2461,7 @@ pub struct Revision {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "exportLinks"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub export_links: String,
     /**
@@ -2661,11 +2476,7 @@ pub struct Revision {
     /**
      * The metadata for a revision to a file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "keepForever"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub keep_forever: Option<bool>,
     /**
      * The metadata for a revision to a file.
@@ -2679,11 +2490,7 @@ pub struct Revision {
     /**
      * The metadata for a revision to a file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "lastModifyingUser"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub last_modifying_user: Option<User>,
     /**
      * The metadata for a revision to a file.
@@ -2691,8 +2498,7 @@ pub struct Revision {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "md5Checksum"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub md_5_checksum: String,
     /**
@@ -2701,8 +2507,7 @@ pub struct Revision {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "mimeType"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub mime_type: String,
     /**
@@ -2711,8 +2516,7 @@ pub struct Revision {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "modifiedTime"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub modified_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
@@ -2721,18 +2525,13 @@ pub struct Revision {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "originalFilename"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub original_filename: String,
     /**
      * The metadata for a revision to a file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "publishAuto"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub publish_auto: Option<bool>,
     /**
      * The metadata for a revision to a file.
@@ -2745,18 +2544,13 @@ pub struct Revision {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "publishedLink"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub published_link: String,
     /**
      * The metadata for a revision to a file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "publishedOutsideDomain"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub published_outside_domain: Option<bool>,
     /**
      * The metadata for a revision to a file.
@@ -2771,6 +2565,7 @@ pub struct Revision {
 
 /// A list of revisions of a file.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct RevisionList {
     /**
      * A list of revisions of a file.
@@ -2787,8 +2582,7 @@ pub struct RevisionList {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub next_page_token: String,
     /**
@@ -2802,7 +2596,33 @@ pub struct RevisionList {
     pub revisions: Vec<Revision>,
 }
 
+impl IntoIterator for RevisionList {
+    type Item = Revision;
+    type IntoIter = std::vec::IntoIter<Revision>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.revisions.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a RevisionList {
+    type Item = &'a Revision;
+    type IntoIter = std::slice::Iter<'a, Revision>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.revisions.iter()
+    }
+}
+
+impl RevisionList {
+    /// Iterate over the `revisions`.
+    pub fn iter(&self) -> std::slice::Iter<'_, Revision> {
+        self.revisions.iter()
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct StartPageToken {
     /**
      * A link to this theme's background image.
@@ -2819,14 +2639,14 @@ pub struct StartPageToken {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "startPageToken"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub start_page_token: String,
 }
 
 /// An image file and cropping parameters from which a background image for this Team Drive is set. This is a write only field; it can only be set on drive.teamdrives.update requests that don't set themeId. When specified, all fields of the backgroundImageFile must be set.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct TeamDriveBackgroundImageFile {
     /**
      * An image file and cropping parameters from which a background image for this Team Drive is set. This is a write only field; it can only be set on drive.teamdrives.update requests that don't set themeId. When specified, all fields of the backgroundImageFile must be set.
@@ -2852,8 +2672,7 @@ pub struct TeamDriveBackgroundImageFile {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "xCoordinate"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub x_coordinate: f64,
     /**
@@ -2862,224 +2681,146 @@ pub struct TeamDriveBackgroundImageFile {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "yCoordinate"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub y_coordinate: f64,
 }
 
 /// Capabilities the current user has on this Team Drive.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct TeamDriveCapabilities {
     /**
      * Capabilities the current user has on this Team Drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canAddChildren"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_add_children: Option<bool>,
     /**
      * Capabilities the current user has on this Team Drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canChangeCopyRequiresWriterPermissionRestriction"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_change_copy_requires_writer_permission_restriction: Option<bool>,
     /**
      * Capabilities the current user has on this Team Drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canChangeDomainUsersOnlyRestriction"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_change_domain_users_only_restriction: Option<bool>,
     /**
      * Capabilities the current user has on this Team Drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canChangeTeamDriveBackground"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_change_team_drive_background: Option<bool>,
     /**
      * Capabilities the current user has on this Team Drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canChangeTeamMembersOnlyRestriction"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_change_team_members_only_restriction: Option<bool>,
     /**
      * Capabilities the current user has on this Team Drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canComment"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_comment: Option<bool>,
     /**
      * Capabilities the current user has on this Team Drive.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "canCopy")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_copy: Option<bool>,
     /**
      * Capabilities the current user has on this Team Drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canDeleteChildren"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_delete_children: Option<bool>,
     /**
      * Capabilities the current user has on this Team Drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canDeleteTeamDrive"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_delete_team_drive: Option<bool>,
     /**
      * Capabilities the current user has on this Team Drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canDownload"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_download: Option<bool>,
     /**
      * Capabilities the current user has on this Team Drive.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "canEdit")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_edit: Option<bool>,
     /**
      * Capabilities the current user has on this Team Drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canListChildren"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_list_children: Option<bool>,
     /**
      * Capabilities the current user has on this Team Drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canManageMembers"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_manage_members: Option<bool>,
     /**
      * Capabilities the current user has on this Team Drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canReadRevisions"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_read_revisions: Option<bool>,
     /**
      * Capabilities the current user has on this Team Drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canRemoveChildren"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_remove_children: Option<bool>,
     /**
      * Capabilities the current user has on this Team Drive.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "canRename")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_rename: Option<bool>,
     /**
      * Capabilities the current user has on this Team Drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canRenameTeamDrive"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_rename_team_drive: Option<bool>,
     /**
      * Capabilities the current user has on this Team Drive.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "canShare")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_share: Option<bool>,
     /**
      * Capabilities the current user has on this Team Drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canTrashChildren"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub can_trash_children: Option<bool>,
 }
 
 /// A set of restrictions that apply to this Team Drive or items inside this Team Drive.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct TeamDriveRestrictions {
     /**
      * A set of restrictions that apply to this Team Drive or items inside this Team Drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "adminManagedRestrictions"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub admin_managed_restrictions: Option<bool>,
     /**
      * A set of restrictions that apply to this Team Drive or items inside this Team Drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "copyRequiresWriterPermission"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub copy_requires_writer_permission: Option<bool>,
     /**
      * A set of restrictions that apply to this Team Drive or items inside this Team Drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "domainUsersOnly"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub domain_users_only: Option<bool>,
     /**
      * A set of restrictions that apply to this Team Drive or items inside this Team Drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "teamMembersOnly"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub team_members_only: Option<bool>,
 }
 
 /// Deprecated: use the drive collection instead.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct TeamDrive {
     /**
      * Deprecated: use the drive collection instead.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "backgroundImageFile"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub background_image_file: Option<TeamDriveBackgroundImageFile>,
     /**
      * Deprecated: use the drive collection instead.
@@ -3087,8 +2828,7 @@ pub struct TeamDrive {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "backgroundImageLink"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub background_image_link: String,
     /**
@@ -3102,8 +2842,7 @@ pub struct TeamDrive {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "colorRgb"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub color_rgb: String,
     /**
@@ -3112,8 +2851,7 @@ pub struct TeamDrive {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "createdTime"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub created_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
@@ -3154,14 +2892,14 @@ pub struct TeamDrive {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "themeId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub theme_id: String,
 }
 
 /// A list of Team Drives.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct TeamDriveList {
     /**
      * A list of Team Drives.
@@ -3178,8 +2916,7 @@ pub struct TeamDriveList {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub next_page_token: String,
     /**
@@ -3188,14 +2925,39 @@ pub struct TeamDriveList {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "teamDrives"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub team_drives: Vec<TeamDrive>,
 }
 
+impl IntoIterator for TeamDriveList {
+    type Item = TeamDrive;
+    type IntoIter = std::vec::IntoIter<TeamDrive>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.team_drives.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a TeamDriveList {
+    type Item = &'a TeamDrive;
+    type IntoIter = std::slice::Iter<'a, TeamDrive>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.team_drives.iter()
+    }
+}
+
+impl TeamDriveList {
+    /// Iterate over the `team_drives`.
+    pub fn iter(&self) -> std::slice::Iter<'_, TeamDrive> {
+        self.team_drives.iter()
+    }
+}
+
 /// Information about a Drive user.
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct User {
     /**
      * Information about a Drive user.
@@ -3203,8 +2965,7 @@ pub struct User {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "displayName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub display_name: String,
     /**
@@ -3213,8 +2974,7 @@ pub struct User {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "emailAddress"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email_address: String,
     /**
@@ -3237,8 +2997,7 @@ pub struct User {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "permissionId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub permission_id: String,
     /**
@@ -3247,8 +3006,7 @@ pub struct User {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "photoLink"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub photo_link: String,
 }
@@ -3256,7 +3014,7 @@ pub struct User {
 /**
  * Data format for the response.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Alt {
     #[serde(rename = "json")]
     Json,
@@ -3282,7 +3040,22 @@ impl Default for Alt {
         Alt::Noop
     }
 }
+
 impl Alt {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Alt::Json => "json",
+            Alt::Noop => "",
+            Alt::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Alt] {
+        &[Alt::Json]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Alt::Noop)
     }
@@ -3291,7 +3064,7 @@ impl Alt {
 /**
  * The source of files to list. Deprecated: use 'corpora' instead.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Corpus {
     #[serde(rename = "domain")]
     Domain,
@@ -3320,8 +3093,691 @@ impl Default for Corpus {
         Corpus::Noop
     }
 }
+
 impl Corpus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Corpus::Domain => "domain",
+            Corpus::User => "user",
+            Corpus::Noop => "",
+            Corpus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Corpus] {
+        &[Corpus::Domain, Corpus::User]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Corpus::Noop)
     }
 }
+
+/// The metadata for a file.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FileRequest {
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub app_properties: String,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub capabilities: Option<FileCapabilities>,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_hints: Option<ContentHints>,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub content_restrictions: Vec<ContentRestriction>,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub copy_requires_writer_permission: Option<bool>,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_time: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub drive_id: String,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub explicitly_trashed: Option<bool>,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub file_extension: String,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub folder_color_rgb: String,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub full_file_extension: String,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_augmented_permissions: Option<bool>,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_thumbnail: Option<bool>,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub head_revision_id: String,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub icon_link: String,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image_media_metadata: Option<ImageMediaMetadata>,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_app_authorized: Option<bool>,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub kind: String,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_modifying_user: Option<User>,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub link_share_metadata: Option<LinkShareMetadata>,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub md_5_checksum: String,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub mime_type: String,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub modified_by_me: Option<bool>,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub modified_by_me_time: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub modified_time: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub original_filename: String,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owned_by_me: Option<bool>,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub owners: Vec<User>,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub parents: Vec<String>,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub permission_ids: Vec<String>,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub permissions: Vec<Permission>,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub properties: String,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub quota_bytes_used: i64,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub resource_key: String,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shared: Option<bool>,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub shared_with_me_time: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sharing_user: Option<User>,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shortcut_details: Option<ShortcutDetails>,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub size: i64,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub spaces: Vec<String>,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub starred: Option<bool>,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team_drive_id: String,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub thumbnail_link: String,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub thumbnail_version: i64,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trashed: Option<bool>,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub trashed_time: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trashing_user: Option<User>,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub version: i64,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub video_media_metadata: Option<VideoMediaMetadata>,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub viewed_by_me: Option<bool>,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub viewed_by_me_time: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub viewers_can_copy_content: Option<bool>,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub web_content_link: String,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub web_view_link: String,
+    /**
+     * The metadata for a file.
+     */
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub writers_can_share: Option<bool>,
+}
+
+/// A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PermissionRequest {
+    /**
+     * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
+     */
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_file_discovery: Option<bool>,
+    /**
+     * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
+     */
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted: Option<bool>,
+    /**
+     * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub display_name: String,
+    /**
+     * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub domain: String,
+    /**
+     * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub email_address: String,
+    /**
+     * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub expiration_time: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    /**
+     * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub kind: String,
+    /**
+     * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub photo_link: String,
+    /**
+     * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub role: String,
+    /**
+     * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+    /**
+     * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub view: String,
+}
+
+/// The types taken and returned by the functions in [`crate::about`].
+pub mod about {
+    pub use super::About;
+}
+
+/// The types taken and returned by the functions in [`crate::changes`].
+pub mod changes {
+    pub use super::{Change, ChangeList, Channel, StartPageToken};
+}
+
+/// The types taken and returned by the functions in [`crate::channels`].
+pub mod channels {
+    pub use super::Channel;
+}
+
+/// The types taken and returned by the functions in [`crate::comments`].
+pub mod comments {
+    pub use super::{Comment, CommentList};
+}
+
+/// The types taken and returned by the functions in [`crate::drives`].
+pub mod drives {
+    pub use super::{Drive, DriveList};
+}
+
+/// The types taken and returned by the functions in [`crate::files`].
+pub mod files {
+    pub use super::{Channel, Corpus, File, FileList, FileRequest, GeneratedIds};
+}
+
+/// The types taken and returned by the functions in [`crate::permissions`].
+pub mod permissions {
+    pub use super::{Permission, PermissionList, PermissionRequest};
+}
+
+/// The types taken and returned by the functions in [`crate::replies`].
+pub mod replies {
+    pub use super::{Reply, ReplyList};
+}
+
+/// The types taken and returned by the functions in [`crate::revisions`].
+pub mod revisions {
+    pub use super::{Revision, RevisionList};
+}
+
+/// The types taken and returned by the functions in [`crate::teamdrives`].
+pub mod teamdrives {
+    pub use super::{TeamDrive, TeamDriveList};
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Groups {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Groups {
         Groups { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Groups {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/{groupUniqueId}` endpoint.
      *
@@ -28,18 +34,22 @@ impl Groups {
         alt: crate::types::Alt,
         group_unique_id: &str,
     ) -> Result<crate::types::Groups> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !alt.to_string().is_empty() {
-            query_args.push(("alt".to_string(), alt.to_string()));
+            query_args.push(("alt", alt.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/{}?{}",
-            crate::progenitor_support::encode_path(&group_unique_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/{}", self.client.encode_path(group_unique_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/{groupUniqueId}",
+                &[("groupUniqueId", group_unique_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -59,20 +69,25 @@ impl Groups {
         group_unique_id: &str,
         body: &crate::types::Groups,
     ) -> Result<crate::types::Groups> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !alt.to_string().is_empty() {
-            query_args.push(("alt".to_string(), alt.to_string()));
+            query_args.push(("alt", alt.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/{}?{}",
-            crate::progenitor_support::encode_path(&group_unique_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/{}", self.client.encode_path(group_unique_id)),
+            &query_args,
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/{groupUniqueId}",
+                    &[("groupUniqueId", group_unique_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -92,19 +107,110 @@ impl Groups {
         group_unique_id: &str,
         body: &crate::types::Groups,
     ) -> Result<crate::types::Groups> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !alt.to_string().is_empty() {
+            query_args.push(("alt", alt.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!("/{}", self.client.encode_path(group_unique_id)),
+            &query_args,
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/{groupUniqueId}",
+                    &[("groupUniqueId", group_unique_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * This function performs a `PATCH` to the `/{groupUniqueId}` endpoint.
+     *
+     * As opposed to `patch`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_if_changed<C: serde::Serialize>(
+        &self,
+        alt: crate::types::Alt,
+        group_unique_id: &str,
+        current: &C,
+        body: &crate::types::Groups,
+    ) -> Result<Option<crate::types::Groups>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !alt.to_string().is_empty() {
-            query_args.push(("alt".to_string(), alt.to_string()));
+            query_args.push(("alt", alt.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/{}?{}",
-            crate::progenitor_support::encode_path(&group_unique_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/{}", self.client.encode_path(group_unique_id)),
+            &query_args,
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/{groupUniqueId}",
+                    &[("groupUniqueId", group_unique_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`Groups`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn GroupsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait GroupsApi: Send + Sync {
+    async fn get(
+        &self,
+        alt: crate::types::Alt,
+        group_unique_id: &str,
+    ) -> Result<crate::types::Groups>;
+    async fn update(
+        &self,
+        alt: crate::types::Alt,
+        group_unique_id: &str,
+        body: &crate::types::Groups,
+    ) -> Result<crate::types::Groups>;
+    async fn patch(
+        &self,
+        alt: crate::types::Alt,
+        group_unique_id: &str,
+        body: &crate::types::Groups,
+    ) -> Result<crate::types::Groups>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl GroupsApi for Groups {
+    async fn get(
+        &self,
+        alt: crate::types::Alt,
+        group_unique_id: &str,
+    ) -> Result<crate::types::Groups> {
+        Groups::get(self, alt, group_unique_id).await
+    }
+    async fn update(
+        &self,
+        alt: crate::types::Alt,
+        group_unique_id: &str,
+        body: &crate::types::Groups,
+    ) -> Result<crate::types::Groups> {
+        Groups::update(self, alt, group_unique_id, body).await
+    }
+    async fn patch(
+        &self,
+        alt: crate::types::Alt,
+        group_unique_id: &str,
+        body: &crate::types::Groups,
+    ) -> Result<crate::types::Groups> {
+        Groups::patch(self, alt, group_unique_id, body).await
     }
 }
@@ -2,6 +2,33 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+/// Either one of something, or many of them. Some endpoints send back an
+/// object when there is one and an array when there are more, so this takes both.
+// Many is tried first: serde reads a struct from an array of its fields too,
+// so an array would otherwise be taken for One.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum OneOrMany<T> {
+    Many(Vec<T>),
+    One(T),
+}
+
+impl<T> OneOrMany<T> {
+    /// All of them, as a vector.
+    pub fn into_vec(self) -> Vec<T> {
+        match self {
+            OneOrMany::One(t) => vec![t],
+            OneOrMany::Many(v) => v,
+        }
+    }
+}
+
+impl<T> Default for OneOrMany<T> {
+    fn default() -> Self {
+        OneOrMany::Many(Vec::new())
+    }
+}
+
 /// JSON template for Group resource
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Groups {
@@ -625,7 +652,7 @@ pub struct Groups {
 /**
  * Data format for the response.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Alt {
     #[serde(rename = "atom")]
     Atom,
@@ -654,8 +681,29 @@ impl Default for Alt {
         Alt::Noop
     }
 }
+
 impl Alt {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Alt::Atom => "atom",
+            Alt::Json => "json",
+            Alt::Noop => "",
+            Alt::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Alt] {
+        &[Alt::Atom, Alt::Json]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Alt::Noop)
     }
 }
+
+/// The types taken and returned by the functions in [`crate::groups`].
+pub mod groups {
+    pub use super::{Alt, Groups};
+}
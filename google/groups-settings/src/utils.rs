@@ -17,6 +17,179 @@ pub fn next_link(l: &hyperx::header::Link) -> Option<String> {
     })
 }
 
+/// Returns whether any of the `required` scopes, for example those in the
+/// `_SCOPES` constant of an operation, are among the `granted` scopes. The
+/// granted scopes are a space separated list, like the `scope` of an access token.
+pub fn has_scope(granted: &str, required: &[&str]) -> bool {
+    required.is_empty() || granted.split_whitespace().any(|g| required.contains(&g))
+}
+
+/// The scopes a `WWW-Authenticate` header says the token lacks, if it says the
+/// error is `insufficient_scope`, as in RFC 6750. They are empty if the header
+/// does not list them.
+pub fn insufficient_scope(www_authenticate: &str) -> Option<Vec<String>> {
+    let mut error = None;
+    let mut scope = None;
+    for (k, v) in auth_params(www_authenticate) {
+        match k.to_lowercase().as_str() {
+            "error" => error = Some(v),
+            "scope" => scope = Some(v),
+            _ => {}
+        }
+    }
+
+    if error.as_deref() != Some("insufficient_scope") {
+        return None;
+    }
+    Some(
+        scope
+            .unwrap_or_default()
+            .split_whitespace()
+            .map(String::from)
+            .collect(),
+    )
+}
+
+/// The `key=value` parameters of the challenges in a `WWW-Authenticate`
+/// header, with quoted values unquoted.
+fn auth_params(header: &str) -> Vec<(String, String)> {
+    let mut params = Vec::new();
+    let mut chars = header.chars().peekable();
+    loop {
+        while matches!(chars.peek(), Some(c) if c.is_whitespace() || *c == ',') {
+            chars.next();
+        }
+
+        let mut key = String::new();
+        while let Some(&c) = chars.peek() {
+            if c.is_whitespace() || c == ',' || c == '=' {
+                break;
+            }
+            key.push(c);
+            chars.next();
+        }
+        if chars.peek().is_none() {
+            break;
+        }
+        if chars.peek() != Some(&'=') {
+            // The scheme of a challenge, like `Bearer`, rather than a parameter.
+            continue;
+        }
+        chars.next();
+
+        let mut value = String::new();
+        if chars.peek() == Some(&'"') {
+            chars.next();
+            while let Some(c) = chars.next() {
+                match c {
+                    '\\' => value.extend(chars.next()),
+                    '"' => break,
+                    c => value.push(c),
+                }
+            }
+        } else {
+            while let Some(&c) = chars.peek() {
+                if c.is_whitespace() || c == ',' {
+                    break;
+                }
+                value.push(c);
+                chars.next();
+            }
+        }
+        params.push((key, value));
+    }
+    params
+}
+
+/// The top-level fields of `desired` that `current` does not have, or has with
+/// another value, as a JSON object. This is `None` if there are none, so there
+/// is nothing to update. The fields `desired` leaves out are left out, as they
+/// are in any patch.
+pub fn changed_fields<C, D>(
+    current: &C,
+    desired: &D,
+) -> serde_json::Result<Option<serde_json::Value>>
+where
+    C: serde::Serialize + ?Sized,
+    D: serde::Serialize + ?Sized,
+{
+    let current = serde_json::to_value(current)?;
+    let desired = match serde_json::to_value(desired)? {
+        serde_json::Value::Object(desired) => desired,
+        // Anything else can only be sent whole.
+        desired if desired == current => return Ok(None),
+        desired => return Ok(Some(desired)),
+    };
+
+    let changed: serde_json::Map<String, serde_json::Value> = desired
+        .into_iter()
+        .filter(|(k, v)| current.get(k) != Some(v))
+        .collect();
+    if changed.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(serde_json::Value::Object(changed)))
+    }
+}
+
+/// Check the array `field` of a request body has `len` items, between the `min`
+/// and `max` the spec allows, before the request is sent.
+pub fn check_items(
+    field: &str,
+    len: usize,
+    min: Option<usize>,
+    max: Option<usize>,
+) -> anyhow::Result<()> {
+    if let Some(min) = min {
+        if len < min {
+            anyhow::bail!("{} must have at least {} items, it has {}", field, min, len);
+        }
+    }
+    if let Some(max) = max {
+        if len > max {
+            anyhow::bail!("{} must have at most {} items, it has {}", field, max, len);
+        }
+    }
+    Ok(())
+}
+
+/// A call in a readable form for errors, such as
+/// `GET /phone/users/{userId} [userId=123]`, rather than its encoded url.
+pub fn pretty_url(method: &str, template: &str, args: &[(&str, String)]) -> String {
+    if args.is_empty() {
+        return format!("{} {}", method, template);
+    }
+
+    let args: Vec<String> = args.iter().map(|(k, v)| format!("{}={}", k, v)).collect();
+    format!("{} {} [{}]", method, template, args.join(", "))
+}
+
+/// A time as the endpoints that take a date or a date and time want it: in
+/// UTC with a `Z`, such as `2021-08-15T01:52:41Z`.
+pub fn date_time_param(t: &chrono::DateTime<chrono::Utc>) -> String {
+    t.to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+}
+
+/// A `multipart/form-data` form with a text part for each field of `body`, by
+/// its name in JSON. Strings are sent as they are, other values as JSON, and
+/// the fields without a value are left out.
+pub fn multipart_form<B>(body: &B) -> serde_json::Result<reqwest::multipart::Form>
+where
+    B: serde::Serialize + ?Sized,
+{
+    let mut form = reqwest::multipart::Form::new();
+    if let serde_json::Value::Object(fields) = serde_json::to_value(body)? {
+        for (name, value) in fields {
+            form = match value {
+                serde_json::Value::Null => form,
+                serde_json::Value::String(s) => form.text(name, s),
+                value => form.text(name, value.to_string()),
+            };
+        }
+    }
+    Ok(form)
+}
+
 pub mod date_format {
     use chrono::NaiveDate;
     use serde::{self, Deserialize, Deserializer};
@@ -638,3 +811,173 @@ pub mod deserialize_null_vector {
         Ok(Default::default())
     }
 }
+
+/// A blocking reader over the chunks of a response body that are sent to it
+/// from an async task. This lets `serde_json::Deserializer::from_reader` parse
+/// a body as it arrives, rather than once it has been buffered in full.
+pub struct ChunkReader {
+    rx: tokio::sync::mpsc::Receiver<std::io::Result<bytes::Bytes>>,
+    chunk: bytes::Bytes,
+}
+
+impl ChunkReader {
+    pub fn new(rx: tokio::sync::mpsc::Receiver<std::io::Result<bytes::Bytes>>) -> Self {
+        ChunkReader {
+            rx,
+            chunk: bytes::Bytes::new(),
+        }
+    }
+}
+
+impl std::io::Read for ChunkReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        while self.chunk.is_empty() {
+            match self.rx.blocking_recv() {
+                Some(chunk) => self.chunk = chunk?,
+                None => return Ok(0),
+            }
+        }
+
+        let n = std::cmp::min(buf.len(), self.chunk.len());
+        buf[..n].copy_from_slice(&self.chunk.split_to(n));
+        Ok(n)
+    }
+}
+
+/// Deserialize the items of a JSON array one at a time, handing each to `f`
+/// as soon as it has been parsed so only one item is held in memory at once.
+/// If `property` is not empty, the array is read from that field of the
+/// top-level object and every other field is skipped.
+///
+/// Parsing stops early, without error, once `f` returns `false`.
+pub fn stream_json_array<R, T, F>(reader: R, property: &str, mut f: F) -> serde_json::Result<()>
+where
+    R: std::io::Read,
+    T: de::DeserializeOwned,
+    F: FnMut(T) -> bool,
+{
+    let mut stopped = false;
+    let mut each = |item: T| {
+        stopped = !f(item);
+        !stopped
+    };
+
+    let mut deserializer = serde_json::Deserializer::from_reader(reader);
+    let items = ArrayItems {
+        f: &mut each,
+        marker: std::marker::PhantomData,
+    };
+    let result = if property.is_empty() {
+        de::DeserializeSeed::deserialize(items, &mut deserializer)
+    } else {
+        de::DeserializeSeed::deserialize(PropertyItems { property, items }, &mut deserializer)
+    };
+
+    match result {
+        // We bailed out part way through the array on purpose, so whatever
+        // the deserializer thinks of the remaining input does not matter.
+        _ if stopped => Ok(()),
+        Ok(_) => deserializer.end(),
+        Err(e) => Err(e),
+    }
+}
+
+struct ArrayItems<'a, T, F> {
+    f: &'a mut F,
+    marker: std::marker::PhantomData<fn() -> T>,
+}
+
+impl<'de, 'a, T, F> de::DeserializeSeed<'de> for ArrayItems<'a, T, F>
+where
+    T: de::Deserialize<'de>,
+    F: FnMut(T) -> bool,
+{
+    type Value = bool;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        // Use `deserialize_any` so a `null` array is treated as empty.
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de, 'a, T, F> Visitor<'de> for ArrayItems<'a, T, F>
+where
+    T: de::Deserialize<'de>,
+    F: FnMut(T) -> bool,
+{
+    type Value = bool;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an array")
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(true)
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+        while let Some(t) = access.next_element::<T>()? {
+            if !(self.f)(t) {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+}
+
+struct PropertyItems<'p, 'a, T, F> {
+    property: &'p str,
+    items: ArrayItems<'a, T, F>,
+}
+
+impl<'de, 'p, 'a, T, F> de::DeserializeSeed<'de> for PropertyItems<'p, 'a, T, F>
+where
+    T: de::Deserialize<'de>,
+    F: FnMut(T) -> bool,
+{
+    type Value = bool;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de, 'p, 'a, T, F> Visitor<'de> for PropertyItems<'p, 'a, T, F>
+where
+    T: de::Deserialize<'de>,
+    F: FnMut(T) -> bool,
+{
+    type Value = bool;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "an object with an array in `{}`", self.property)
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+        let mut items = Some(self.items);
+        while let Some(key) = access.next_key::<String>()? {
+            if key == self.property {
+                if let Some(items) = items.take() {
+                    if !access.next_value_seed(items)? {
+                        return Ok(false);
+                    }
+                    continue;
+                }
+            }
+
+            access.next_value::<de::IgnoredAny>()?;
+        }
+
+        Ok(true)
+    }
+}
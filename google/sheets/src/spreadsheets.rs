@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Spreadsheets {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Spreadsheets {
         Spreadsheets { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Spreadsheets {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `POST` to the `/v4/spreadsheets` endpoint.
      *
@@ -19,12 +25,13 @@ impl Spreadsheets {
      */
     pub async fn create(
         &self,
-        body: &crate::types::Spreadsheet,
+        body: &crate::types::SpreadsheetRequest,
     ) -> Result<crate::types::Spreadsheet> {
         let url = "/v4/spreadsheets".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/v4/spreadsheets", &[]))
     }
 
     /**
@@ -44,21 +51,28 @@ impl Spreadsheets {
         include_grid_data: bool,
         ranges: &[String],
     ) -> Result<crate::types::Spreadsheet> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if include_grid_data {
-            query_args.push(("includeGridData".to_string(), include_grid_data.to_string()));
+            query_args.push(("includeGridData", include_grid_data.to_string()));
         }
-        if !ranges.is_empty() {
-            query_args.push(("ranges".to_string(), ranges.join(" ")));
+        for v in ranges {
+            query_args.push(("ranges", v.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v4/spreadsheets/{}?{}",
-            crate::progenitor_support::encode_path(&spreadsheet_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v4/spreadsheets/{}",
+                self.client.encode_path(spreadsheet_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v4/spreadsheets/{spreadsheetId}",
+                &[("spreadsheetId", spreadsheet_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -78,11 +92,20 @@ impl Spreadsheets {
     ) -> Result<crate::types::DeveloperMetadata> {
         let url = format!(
             "/v4/spreadsheets/{}/developerMetadata/{}",
-            crate::progenitor_support::encode_path(&spreadsheet_id.to_string()),
-            crate::progenitor_support::encode_path(&metadata_id.to_string()),
+            self.client.encode_path(spreadsheet_id),
+            self.client.encode_path(&metadata_id.to_string()),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v4/spreadsheets/{spreadsheetId}/developerMetadata/{metadataId}",
+                &[
+                    ("spreadsheetId", spreadsheet_id.to_string()),
+                    ("metadataId", metadata_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -101,12 +124,19 @@ impl Spreadsheets {
     ) -> Result<crate::types::SearchDeveloperMetadataResponse> {
         let url = format!(
             "/v4/spreadsheets/{}/developerMetadata:search",
-            crate::progenitor_support::encode_path(&spreadsheet_id.to_string()),
+            self.client.encode_path(spreadsheet_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v4/spreadsheets/{spreadsheetId}/developerMetadata:search",
+                    &[("spreadsheetId", spreadsheet_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -127,13 +157,23 @@ impl Spreadsheets {
     ) -> Result<crate::types::SheetProperties> {
         let url = format!(
             "/v4/spreadsheets/{}/sheets/{}/copyTo",
-            crate::progenitor_support::encode_path(&spreadsheet_id.to_string()),
-            crate::progenitor_support::encode_path(&sheet_id.to_string()),
+            self.client.encode_path(spreadsheet_id),
+            self.client.encode_path(&sheet_id.to_string()),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v4/spreadsheets/{spreadsheetId}/sheets/{sheetId}:copyTo",
+                    &[
+                        ("spreadsheetId", spreadsheet_id.to_string()),
+                        ("sheetId", sheet_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -157,31 +197,35 @@ impl Spreadsheets {
         major_dimension: crate::types::Dimension,
         value_render_option: crate::types::ValueRenderOption,
     ) -> Result<crate::types::ValueRange> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !date_time_render_option.to_string().is_empty() {
-            query_args.push((
-                "dateTimeRenderOption".to_string(),
-                date_time_render_option.to_string(),
-            ));
+            query_args.push(("dateTimeRenderOption", date_time_render_option.to_string()));
         }
         if !major_dimension.to_string().is_empty() {
-            query_args.push(("majorDimension".to_string(), major_dimension.to_string()));
+            query_args.push(("majorDimension", major_dimension.to_string()));
         }
         if !value_render_option.to_string().is_empty() {
-            query_args.push((
-                "valueRenderOption".to_string(),
-                value_render_option.to_string(),
-            ));
+            query_args.push(("valueRenderOption", value_render_option.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v4/spreadsheets/{}/values/{}?{}",
-            crate::progenitor_support::encode_path(&spreadsheet_id.to_string()),
-            crate::progenitor_support::encode_path(&range.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v4/spreadsheets/{}/values/{}",
+                self.client.encode_path(spreadsheet_id),
+                self.client.encode_path(range)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v4/spreadsheets/{spreadsheetId}/values/{range}",
+                &[
+                    ("spreadsheetId", spreadsheet_id.to_string()),
+                    ("range", range.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -208,42 +252,50 @@ impl Spreadsheets {
         value_input_option: crate::types::ValueInputOption,
         body: &crate::types::ValueRange,
     ) -> Result<crate::types::UpdateValuesResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if include_values_in_response {
             query_args.push((
-                "includeValuesInResponse".to_string(),
+                "includeValuesInResponse",
                 include_values_in_response.to_string(),
             ));
         }
         if !response_date_time_render_option.to_string().is_empty() {
             query_args.push((
-                "responseDateTimeRenderOption".to_string(),
+                "responseDateTimeRenderOption",
                 response_date_time_render_option.to_string(),
             ));
         }
         if !response_value_render_option.to_string().is_empty() {
             query_args.push((
-                "responseValueRenderOption".to_string(),
+                "responseValueRenderOption",
                 response_value_render_option.to_string(),
             ));
         }
         if !value_input_option.to_string().is_empty() {
-            query_args.push((
-                "valueInputOption".to_string(),
-                value_input_option.to_string(),
-            ));
+            query_args.push(("valueInputOption", value_input_option.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v4/spreadsheets/{}/values/{}?{}",
-            crate::progenitor_support::encode_path(&spreadsheet_id.to_string()),
-            crate::progenitor_support::encode_path(&range.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v4/spreadsheets/{}/values/{}",
+                self.client.encode_path(spreadsheet_id),
+                self.client.encode_path(range)
+            ),
+            &query_args,
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v4/spreadsheets/{spreadsheetId}/values/{range}",
+                    &[
+                        ("spreadsheetId", spreadsheet_id.to_string()),
+                        ("range", range.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -272,48 +324,53 @@ impl Spreadsheets {
         value_input_option: crate::types::ValueInputOption,
         body: &crate::types::ValueRange,
     ) -> Result<crate::types::AppendValuesResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if include_values_in_response {
             query_args.push((
-                "includeValuesInResponse".to_string(),
+                "includeValuesInResponse",
                 include_values_in_response.to_string(),
             ));
         }
         if !insert_data_option.to_string().is_empty() {
-            query_args.push((
-                "insertDataOption".to_string(),
-                insert_data_option.to_string(),
-            ));
+            query_args.push(("insertDataOption", insert_data_option.to_string()));
         }
         if !response_date_time_render_option.to_string().is_empty() {
             query_args.push((
-                "responseDateTimeRenderOption".to_string(),
+                "responseDateTimeRenderOption",
                 response_date_time_render_option.to_string(),
             ));
         }
         if !response_value_render_option.to_string().is_empty() {
             query_args.push((
-                "responseValueRenderOption".to_string(),
+                "responseValueRenderOption",
                 response_value_render_option.to_string(),
             ));
         }
         if !value_input_option.to_string().is_empty() {
-            query_args.push((
-                "valueInputOption".to_string(),
-                value_input_option.to_string(),
-            ));
+            query_args.push(("valueInputOption", value_input_option.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v4/spreadsheets/{}/values/{}/append?{}",
-            crate::progenitor_support::encode_path(&spreadsheet_id.to_string()),
-            crate::progenitor_support::encode_path(&range.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v4/spreadsheets/{}/values/{}/append",
+                self.client.encode_path(spreadsheet_id),
+                self.client.encode_path(range)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v4/spreadsheets/{spreadsheetId}/values/{range}:append",
+                    &[
+                        ("spreadsheetId", spreadsheet_id.to_string()),
+                        ("range", range.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -334,13 +391,23 @@ impl Spreadsheets {
     ) -> Result<crate::types::ClearValuesResponse> {
         let url = format!(
             "/v4/spreadsheets/{}/values/{}/clear",
-            crate::progenitor_support::encode_path(&spreadsheet_id.to_string()),
-            crate::progenitor_support::encode_path(&range.to_string()),
+            self.client.encode_path(spreadsheet_id),
+            self.client.encode_path(range),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v4/spreadsheets/{spreadsheetId}/values/{range}:clear",
+                    &[
+                        ("spreadsheetId", spreadsheet_id.to_string()),
+                        ("range", range.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -359,12 +426,19 @@ impl Spreadsheets {
     ) -> Result<crate::types::BatchClearValuesResponse> {
         let url = format!(
             "/v4/spreadsheets/{}/values:batchClear",
-            crate::progenitor_support::encode_path(&spreadsheet_id.to_string()),
+            self.client.encode_path(spreadsheet_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v4/spreadsheets/{spreadsheetId}/values:batchClear",
+                    &[("spreadsheetId", spreadsheet_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -383,12 +457,19 @@ impl Spreadsheets {
     ) -> Result<crate::types::BatchClearValuesByDataFilterResponse> {
         let url = format!(
             "/v4/spreadsheets/{}/values:batchClearByDataFilter",
-            crate::progenitor_support::encode_path(&spreadsheet_id.to_string()),
+            self.client.encode_path(spreadsheet_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v4/spreadsheets/{spreadsheetId}/values:batchClearByDataFilter",
+                    &[("spreadsheetId", spreadsheet_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -412,33 +493,34 @@ impl Spreadsheets {
         ranges: &[String],
         value_render_option: crate::types::ValueRenderOption,
     ) -> Result<crate::types::BatchGetValuesResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !date_time_render_option.to_string().is_empty() {
-            query_args.push((
-                "dateTimeRenderOption".to_string(),
-                date_time_render_option.to_string(),
-            ));
+            query_args.push(("dateTimeRenderOption", date_time_render_option.to_string()));
         }
         if !major_dimension.to_string().is_empty() {
-            query_args.push(("majorDimension".to_string(), major_dimension.to_string()));
+            query_args.push(("majorDimension", major_dimension.to_string()));
         }
-        if !ranges.is_empty() {
-            query_args.push(("ranges".to_string(), ranges.join(" ")));
+        for v in ranges {
+            query_args.push(("ranges", v.to_string()));
         }
         if !value_render_option.to_string().is_empty() {
-            query_args.push((
-                "valueRenderOption".to_string(),
-                value_render_option.to_string(),
-            ));
+            query_args.push(("valueRenderOption", value_render_option.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v4/spreadsheets/{}/values:batchGet?{}",
-            crate::progenitor_support::encode_path(&spreadsheet_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v4/spreadsheets/{}/values:batchGet",
+                self.client.encode_path(spreadsheet_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v4/spreadsheets/{spreadsheetId}/values:batchGet",
+                &[("spreadsheetId", spreadsheet_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -457,12 +539,19 @@ impl Spreadsheets {
     ) -> Result<crate::types::BatchGetValuesByDataFilterResponse> {
         let url = format!(
             "/v4/spreadsheets/{}/values:batchGetByDataFilter",
-            crate::progenitor_support::encode_path(&spreadsheet_id.to_string()),
+            self.client.encode_path(spreadsheet_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v4/spreadsheets/{spreadsheetId}/values:batchGetByDataFilter",
+                    &[("spreadsheetId", spreadsheet_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -481,12 +570,19 @@ impl Spreadsheets {
     ) -> Result<crate::types::BatchUpdateValuesResponse> {
         let url = format!(
             "/v4/spreadsheets/{}/values:batchUpdate",
-            crate::progenitor_support::encode_path(&spreadsheet_id.to_string()),
+            self.client.encode_path(spreadsheet_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v4/spreadsheets/{spreadsheetId}/values:batchUpdate",
+                    &[("spreadsheetId", spreadsheet_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -505,12 +601,19 @@ impl Spreadsheets {
     ) -> Result<crate::types::BatchUpdateValuesByDataFilterResponse> {
         let url = format!(
             "/v4/spreadsheets/{}/values:batchUpdateByDataFilter",
-            crate::progenitor_support::encode_path(&spreadsheet_id.to_string()),
+            self.client.encode_path(spreadsheet_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v4/spreadsheets/{spreadsheetId}/values:batchUpdateByDataFilter",
+                    &[("spreadsheetId", spreadsheet_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -529,12 +632,19 @@ impl Spreadsheets {
     ) -> Result<crate::types::BatchUpdateSpreadsheetResponse> {
         let url = format!(
             "/v4/spreadsheets/{}/batchUpdate",
-            crate::progenitor_support::encode_path(&spreadsheet_id.to_string()),
+            self.client.encode_path(spreadsheet_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v4/spreadsheets/{spreadsheetId}:batchUpdate",
+                    &[("spreadsheetId", spreadsheet_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -553,11 +663,310 @@ impl Spreadsheets {
     ) -> Result<crate::types::Spreadsheet> {
         let url = format!(
             "/v4/spreadsheets/{}/getByDataFilter",
-            crate::progenitor_support::encode_path(&spreadsheet_id.to_string()),
+            self.client.encode_path(spreadsheet_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v4/spreadsheets/{spreadsheetId}:getByDataFilter",
+                    &[("spreadsheetId", spreadsheet_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`Spreadsheets`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn SpreadsheetsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait SpreadsheetsApi: Send + Sync {
+    async fn create(
+        &self,
+        body: &crate::types::SpreadsheetRequest,
+    ) -> Result<crate::types::Spreadsheet>;
+    async fn get(
+        &self,
+        spreadsheet_id: &str,
+        include_grid_data: bool,
+        ranges: &[String],
+    ) -> Result<crate::types::Spreadsheet>;
+    async fn developer_metadata_get(
+        &self,
+        spreadsheet_id: &str,
+        metadata_id: i64,
+    ) -> Result<crate::types::DeveloperMetadata>;
+    async fn developer_metadata_search(
+        &self,
+        spreadsheet_id: &str,
+        body: &crate::types::SearchDeveloperMetadataRequest,
+    ) -> Result<crate::types::SearchDeveloperMetadataResponse>;
+    async fn sheets_copy(
+        &self,
+        spreadsheet_id: &str,
+        sheet_id: i64,
+        body: &crate::types::CopySheetAnotherSpreadsheetRequest,
+    ) -> Result<crate::types::SheetProperties>;
+    async fn values_get(
+        &self,
+        spreadsheet_id: &str,
+        range: &str,
+        date_time_render_option: crate::types::DateTimeRenderOption,
+        major_dimension: crate::types::Dimension,
+        value_render_option: crate::types::ValueRenderOption,
+    ) -> Result<crate::types::ValueRange>;
+    async fn values_update(
+        &self,
+        spreadsheet_id: &str,
+        range: &str,
+        include_values_in_response: bool,
+        response_date_time_render_option: crate::types::DateTimeRenderOption,
+        response_value_render_option: crate::types::ValueRenderOption,
+        value_input_option: crate::types::ValueInputOption,
+        body: &crate::types::ValueRange,
+    ) -> Result<crate::types::UpdateValuesResponse>;
+    async fn values_append(
+        &self,
+        spreadsheet_id: &str,
+        range: &str,
+        include_values_in_response: bool,
+        insert_data_option: crate::types::InsertDataOption,
+        response_date_time_render_option: crate::types::DateTimeRenderOption,
+        response_value_render_option: crate::types::ValueRenderOption,
+        value_input_option: crate::types::ValueInputOption,
+        body: &crate::types::ValueRange,
+    ) -> Result<crate::types::AppendValuesResponse>;
+    async fn values_clear(
+        &self,
+        spreadsheet_id: &str,
+        range: &str,
+        body: &crate::types::ClearValuesRequest,
+    ) -> Result<crate::types::ClearValuesResponse>;
+    async fn values_batch_clear(
+        &self,
+        spreadsheet_id: &str,
+        body: &crate::types::BatchClearValuesRequest,
+    ) -> Result<crate::types::BatchClearValuesResponse>;
+    async fn values_batch_clear_data_filter(
+        &self,
+        spreadsheet_id: &str,
+        body: &crate::types::BatchClearValuesByDataFilterRequest,
+    ) -> Result<crate::types::BatchClearValuesByDataFilterResponse>;
+    async fn values_batch_get(
+        &self,
+        spreadsheet_id: &str,
+        date_time_render_option: crate::types::DateTimeRenderOption,
+        major_dimension: crate::types::Dimension,
+        ranges: &[String],
+        value_render_option: crate::types::ValueRenderOption,
+    ) -> Result<crate::types::BatchGetValuesResponse>;
+    async fn values_batch_get_data_filter(
+        &self,
+        spreadsheet_id: &str,
+        body: &crate::types::BatchGetValuesByDataFilterRequest,
+    ) -> Result<crate::types::BatchGetValuesByDataFilterResponse>;
+    async fn values_batch_update(
+        &self,
+        spreadsheet_id: &str,
+        body: &crate::types::BatchUpdateValuesRequest,
+    ) -> Result<crate::types::BatchUpdateValuesResponse>;
+    async fn values_batch_update_data_filter(
+        &self,
+        spreadsheet_id: &str,
+        body: &crate::types::BatchUpdateValuesByDataFilterRequest,
+    ) -> Result<crate::types::BatchUpdateValuesByDataFilterResponse>;
+    async fn batch_update(
+        &self,
+        spreadsheet_id: &str,
+        body: &crate::types::BatchUpdateSpreadsheetRequest,
+    ) -> Result<crate::types::BatchUpdateSpreadsheetResponse>;
+    async fn get_data_filter(
+        &self,
+        spreadsheet_id: &str,
+        body: &crate::types::GetSpreadsheetByDataFilterRequest,
+    ) -> Result<crate::types::Spreadsheet>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl SpreadsheetsApi for Spreadsheets {
+    async fn create(
+        &self,
+        body: &crate::types::SpreadsheetRequest,
+    ) -> Result<crate::types::Spreadsheet> {
+        Spreadsheets::create(self, body).await
+    }
+    async fn get(
+        &self,
+        spreadsheet_id: &str,
+        include_grid_data: bool,
+        ranges: &[String],
+    ) -> Result<crate::types::Spreadsheet> {
+        Spreadsheets::get(self, spreadsheet_id, include_grid_data, ranges).await
+    }
+    async fn developer_metadata_get(
+        &self,
+        spreadsheet_id: &str,
+        metadata_id: i64,
+    ) -> Result<crate::types::DeveloperMetadata> {
+        Spreadsheets::developer_metadata_get(self, spreadsheet_id, metadata_id).await
+    }
+    async fn developer_metadata_search(
+        &self,
+        spreadsheet_id: &str,
+        body: &crate::types::SearchDeveloperMetadataRequest,
+    ) -> Result<crate::types::SearchDeveloperMetadataResponse> {
+        Spreadsheets::developer_metadata_search(self, spreadsheet_id, body).await
+    }
+    async fn sheets_copy(
+        &self,
+        spreadsheet_id: &str,
+        sheet_id: i64,
+        body: &crate::types::CopySheetAnotherSpreadsheetRequest,
+    ) -> Result<crate::types::SheetProperties> {
+        Spreadsheets::sheets_copy(self, spreadsheet_id, sheet_id, body).await
+    }
+    async fn values_get(
+        &self,
+        spreadsheet_id: &str,
+        range: &str,
+        date_time_render_option: crate::types::DateTimeRenderOption,
+        major_dimension: crate::types::Dimension,
+        value_render_option: crate::types::ValueRenderOption,
+    ) -> Result<crate::types::ValueRange> {
+        Spreadsheets::values_get(
+            self,
+            spreadsheet_id,
+            range,
+            date_time_render_option,
+            major_dimension,
+            value_render_option,
+        )
+        .await
+    }
+    async fn values_update(
+        &self,
+        spreadsheet_id: &str,
+        range: &str,
+        include_values_in_response: bool,
+        response_date_time_render_option: crate::types::DateTimeRenderOption,
+        response_value_render_option: crate::types::ValueRenderOption,
+        value_input_option: crate::types::ValueInputOption,
+        body: &crate::types::ValueRange,
+    ) -> Result<crate::types::UpdateValuesResponse> {
+        Spreadsheets::values_update(
+            self,
+            spreadsheet_id,
+            range,
+            include_values_in_response,
+            response_date_time_render_option,
+            response_value_render_option,
+            value_input_option,
+            body,
+        )
+        .await
+    }
+    async fn values_append(
+        &self,
+        spreadsheet_id: &str,
+        range: &str,
+        include_values_in_response: bool,
+        insert_data_option: crate::types::InsertDataOption,
+        response_date_time_render_option: crate::types::DateTimeRenderOption,
+        response_value_render_option: crate::types::ValueRenderOption,
+        value_input_option: crate::types::ValueInputOption,
+        body: &crate::types::ValueRange,
+    ) -> Result<crate::types::AppendValuesResponse> {
+        Spreadsheets::values_append(
+            self,
+            spreadsheet_id,
+            range,
+            include_values_in_response,
+            insert_data_option,
+            response_date_time_render_option,
+            response_value_render_option,
+            value_input_option,
+            body,
+        )
+        .await
+    }
+    async fn values_clear(
+        &self,
+        spreadsheet_id: &str,
+        range: &str,
+        body: &crate::types::ClearValuesRequest,
+    ) -> Result<crate::types::ClearValuesResponse> {
+        Spreadsheets::values_clear(self, spreadsheet_id, range, body).await
+    }
+    async fn values_batch_clear(
+        &self,
+        spreadsheet_id: &str,
+        body: &crate::types::BatchClearValuesRequest,
+    ) -> Result<crate::types::BatchClearValuesResponse> {
+        Spreadsheets::values_batch_clear(self, spreadsheet_id, body).await
+    }
+    async fn values_batch_clear_data_filter(
+        &self,
+        spreadsheet_id: &str,
+        body: &crate::types::BatchClearValuesByDataFilterRequest,
+    ) -> Result<crate::types::BatchClearValuesByDataFilterResponse> {
+        Spreadsheets::values_batch_clear_data_filter(self, spreadsheet_id, body).await
+    }
+    async fn values_batch_get(
+        &self,
+        spreadsheet_id: &str,
+        date_time_render_option: crate::types::DateTimeRenderOption,
+        major_dimension: crate::types::Dimension,
+        ranges: &[String],
+        value_render_option: crate::types::ValueRenderOption,
+    ) -> Result<crate::types::BatchGetValuesResponse> {
+        Spreadsheets::values_batch_get(
+            self,
+            spreadsheet_id,
+            date_time_render_option,
+            major_dimension,
+            ranges,
+            value_render_option,
+        )
+        .await
+    }
+    async fn values_batch_get_data_filter(
+        &self,
+        spreadsheet_id: &str,
+        body: &crate::types::BatchGetValuesByDataFilterRequest,
+    ) -> Result<crate::types::BatchGetValuesByDataFilterResponse> {
+        Spreadsheets::values_batch_get_data_filter(self, spreadsheet_id, body).await
+    }
+    async fn values_batch_update(
+        &self,
+        spreadsheet_id: &str,
+        body: &crate::types::BatchUpdateValuesRequest,
+    ) -> Result<crate::types::BatchUpdateValuesResponse> {
+        Spreadsheets::values_batch_update(self, spreadsheet_id, body).await
+    }
+    async fn values_batch_update_data_filter(
+        &self,
+        spreadsheet_id: &str,
+        body: &crate::types::BatchUpdateValuesByDataFilterRequest,
+    ) -> Result<crate::types::BatchUpdateValuesByDataFilterResponse> {
+        Spreadsheets::values_batch_update_data_filter(self, spreadsheet_id, body).await
+    }
+    async fn batch_update(
+        &self,
+        spreadsheet_id: &str,
+        body: &crate::types::BatchUpdateSpreadsheetRequest,
+    ) -> Result<crate::types::BatchUpdateSpreadsheetResponse> {
+        Spreadsheets::batch_update(self, spreadsheet_id, body).await
+    }
+    async fn get_data_filter(
+        &self,
+        spreadsheet_id: &str,
+        body: &crate::types::GetSpreadsheetByDataFilterRequest,
+    ) -> Result<crate::types::Spreadsheet> {
+        Spreadsheets::get_data_filter(self, spreadsheet_id, body).await
     }
 }
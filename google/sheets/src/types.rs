@@ -2,31 +2,52 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+/// Either one of something, or many of them. Some endpoints send back an
+/// object when there is one and an array when there are more, so this takes both.
+// Many is tried first: serde reads a struct from an array of its fields too,
+// so an array would otherwise be taken for One.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum OneOrMany<T> {
+    Many(Vec<T>),
+    One(T),
+}
+
+impl<T> OneOrMany<T> {
+    /// All of them, as a vector.
+    pub fn into_vec(self) -> Vec<T> {
+        match self {
+            OneOrMany::One(t) => vec![t],
+            OneOrMany::Many(v) => v,
+        }
+    }
+}
+
+impl<T> Default for OneOrMany<T> {
+    fn default() -> Self {
+        OneOrMany::Many(Vec::new())
+    }
+}
+
 /// Adds a new banded range to the spreadsheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct AddBandingRequest {
     /**
      * Adds a new banded range to the spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "bandedRange"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub banded_range: Option<BandedRange>,
 }
 
 /// The result of adding a banded range.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct AddBandingResponse {
     /**
      * The result of adding a banded range.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "bandedRange"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub banded_range: Option<BandedRange>,
 }
 
@@ -71,38 +92,28 @@ pub struct AddConditionalFormatRuleRequest {
 
 /// Adds a data source. After the data source is added successfully, an associated DATA_SOURCE sheet is created and an execution is triggered to refresh the sheet to read data from the data source. The request requires an additional `bigquery.readonly` OAuth scope.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct AddDataSourceRequest {
     /**
      * Adds a data source. After the data source is added successfully, an associated DATA_SOURCE sheet is created and an execution is triggered to refresh the sheet to read data from the data source. The request requires an additional `bigquery.readonly` OAuth scope.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "dataSource"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data_source: Option<DataSource>,
 }
 
 /// The result of adding a data source.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct AddDataSourceResponse {
     /**
      * The result of adding a data source.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "dataExecutionStatus"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data_execution_status: Option<DataExecutionStatus>,
     /**
      * The result of adding a data source.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "dataSource"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data_source: Option<DataSource>,
 }
 
@@ -118,6 +129,7 @@ pub struct AddDimensionGroupRequest {
 
 /// The result of adding a group.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct AddDimensionGroupResponse {
     /**
      * The result of adding a group.
@@ -125,8 +137,7 @@ pub struct AddDimensionGroupResponse {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "dimensionGroups"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub dimension_groups: Vec<DimensionGroup>,
 }
@@ -153,57 +164,45 @@ pub struct AddFilterViewResponse {
 
 /// Adds a named range to the spreadsheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct AddNamedRangeRequest {
     /**
      * Adds a named range to the spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "namedRange"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub named_range: Option<NamedRange>,
 }
 
 /// The result of adding a named range.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct AddNamedRangeResponse {
     /**
      * The result of adding a named range.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "namedRange"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub named_range: Option<NamedRange>,
 }
 
 /// Adds a new protected range.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct AddProtectedRangeRequest {
     /**
      * Adds a new protected range.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "protectedRange"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub protected_range: Option<ProtectedRange>,
 }
 
 /// The result of adding a new protected range.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct AddProtectedRangeResponse {
     /**
      * The result of adding a new protected range.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "protectedRange"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub protected_range: Option<ProtectedRange>,
 }
 
@@ -249,6 +248,7 @@ pub struct AddSlicerResponse {
 
 /// Adds new cells after the last row with data in a sheet, inserting new rows into the sheet if necessary.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct AppendCellsRequest {
     /**
      * Adds new cells after the last row with data in a sheet, inserting new rows into the sheet if necessary.
@@ -274,8 +274,7 @@ pub struct AppendCellsRequest {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "sheetId"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub sheet_id: i64,
 }
@@ -283,7 +282,7 @@ pub struct AppendCellsRequest {
 /**
  * Whether rows or columns should be appended.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Dimension {
     #[serde(rename = "COLUMNS")]
     Columns,
@@ -315,7 +314,28 @@ impl Default for Dimension {
         Dimension::Noop
     }
 }
+
 impl Dimension {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Dimension::Columns => "COLUMNS",
+            Dimension::DimensionUnspecified => "DIMENSION_UNSPECIFIED",
+            Dimension::Rows => "ROWS",
+            Dimension::Noop => "",
+            Dimension::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Dimension] {
+        &[
+            Dimension::Columns,
+            Dimension::DimensionUnspecified,
+            Dimension::Rows,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Dimension::Noop)
     }
@@ -323,6 +343,7 @@ impl Dimension {
 
 /// Appends rows or columns to the end of a sheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct AppendDimensionRequest {
     /**
      * Appends rows or columns to the end of a sheet.
@@ -344,14 +365,14 @@ pub struct AppendDimensionRequest {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "sheetId"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub sheet_id: i64,
 }
 
 /// The response when updating a range of values in a spreadsheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct AppendValuesResponse {
     /**
      * The response when updating a range of values in a spreadsheet.
@@ -359,8 +380,7 @@ pub struct AppendValuesResponse {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "spreadsheetId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub spreadsheet_id: String,
     /**
@@ -369,8 +389,7 @@ pub struct AppendValuesResponse {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "tableRange"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub table_range: String,
     /**
@@ -382,6 +401,7 @@ pub struct AppendValuesResponse {
 
 /// Fills in more data based on existing data.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct AutoFillRequest {
     /**
      * Fills in more data based on existing data.
@@ -391,34 +411,23 @@ pub struct AutoFillRequest {
     /**
      * Fills in more data based on existing data.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "sourceAndDestination"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub source_and_destination: Option<SourceDestination>,
     /**
      * Fills in more data based on existing data.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "useAlternateSeries"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub use_alternate_series: Option<bool>,
 }
 
 /// Automatically resizes one or more dimensions based on the contents of the cells in that dimension.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct AutoResizeDimensionsRequest {
     /**
      * Automatically resizes one or more dimensions based on the contents of the cells in that dimension.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "dataSourceSheetDimensions"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data_source_sheet_dimensions: Option<DataSourceSheetDimensionRange>,
     /**
      * Automatically resizes one or more dimensions based on the contents of the cells in that dimension.
@@ -429,6 +438,7 @@ pub struct AutoResizeDimensionsRequest {
 
 /// A banded (alternating colors) range in a sheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct BandedRange {
     /**
      * A banded (alternating colors) range in a sheet.
@@ -436,18 +446,13 @@ pub struct BandedRange {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "bandedRangeId"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub banded_range_id: i64,
     /**
      * A banded (alternating colors) range in a sheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "columnProperties"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub column_properties: Option<BandingProperties>,
     /**
      * A banded (alternating colors) range in a sheet.
@@ -457,95 +462,60 @@ pub struct BandedRange {
     /**
      * A banded (alternating colors) range in a sheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "rowProperties"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub row_properties: Option<BandingProperties>,
 }
 
 /// Properties referring a single dimension (either row or column). If both BandedRange.row_properties and BandedRange.column_properties are set, the fill colors are applied to cells according to the following rules: * header_color and footer_color take priority over band colors. * first_band_color takes priority over second_band_color. * row_properties takes priority over column_properties. For example, the first row color takes priority over the first column color, but the first column color takes priority over the second row color. Similarly, the row header takes priority over the column header in the top left cell, but the column header takes priority over the first row color if the row header is not set.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct BandingProperties {
     /**
      * Properties referring a single dimension (either row or column). If both BandedRange.row_properties and BandedRange.column_properties are set, the fill colors are applied to cells according to the following rules: \* header_color and footer_color take priority over band colors. \* first_band_color takes priority over second_band_color. \* row_properties takes priority over column_properties. For example, the first row color takes priority over the first column color, but the first column color takes priority over the second row color. Similarly, the row header takes priority over the column header in the top left cell, but the column header takes priority over the first row color if the row header is not set.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "firstBandColor"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub first_band_color: Option<Color>,
     /**
      * Properties referring a single dimension (either row or column). If both BandedRange.row_properties and BandedRange.column_properties are set, the fill colors are applied to cells according to the following rules: \* header_color and footer_color take priority over band colors. \* first_band_color takes priority over second_band_color. \* row_properties takes priority over column_properties. For example, the first row color takes priority over the first column color, but the first column color takes priority over the second row color. Similarly, the row header takes priority over the column header in the top left cell, but the column header takes priority over the first row color if the row header is not set.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "firstBandColorStyle"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub first_band_color_style: Option<ColorStyle>,
     /**
      * Properties referring a single dimension (either row or column). If both BandedRange.row_properties and BandedRange.column_properties are set, the fill colors are applied to cells according to the following rules: \* header_color and footer_color take priority over band colors. \* first_band_color takes priority over second_band_color. \* row_properties takes priority over column_properties. For example, the first row color takes priority over the first column color, but the first column color takes priority over the second row color. Similarly, the row header takes priority over the column header in the top left cell, but the column header takes priority over the first row color if the row header is not set.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "footerColor"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub footer_color: Option<Color>,
     /**
      * Properties referring a single dimension (either row or column). If both BandedRange.row_properties and BandedRange.column_properties are set, the fill colors are applied to cells according to the following rules: \* header_color and footer_color take priority over band colors. \* first_band_color takes priority over second_band_color. \* row_properties takes priority over column_properties. For example, the first row color takes priority over the first column color, but the first column color takes priority over the second row color. Similarly, the row header takes priority over the column header in the top left cell, but the column header takes priority over the first row color if the row header is not set.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "footerColorStyle"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub footer_color_style: Option<ColorStyle>,
     /**
      * Properties referring a single dimension (either row or column). If both BandedRange.row_properties and BandedRange.column_properties are set, the fill colors are applied to cells according to the following rules: \* header_color and footer_color take priority over band colors. \* first_band_color takes priority over second_band_color. \* row_properties takes priority over column_properties. For example, the first row color takes priority over the first column color, but the first column color takes priority over the second row color. Similarly, the row header takes priority over the column header in the top left cell, but the column header takes priority over the first row color if the row header is not set.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "headerColor"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub header_color: Option<Color>,
     /**
      * Properties referring a single dimension (either row or column). If both BandedRange.row_properties and BandedRange.column_properties are set, the fill colors are applied to cells according to the following rules: \* header_color and footer_color take priority over band colors. \* first_band_color takes priority over second_band_color. \* row_properties takes priority over column_properties. For example, the first row color takes priority over the first column color, but the first column color takes priority over the second row color. Similarly, the row header takes priority over the column header in the top left cell, but the column header takes priority over the first row color if the row header is not set.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "headerColorStyle"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub header_color_style: Option<ColorStyle>,
     /**
      * Properties referring a single dimension (either row or column). If both BandedRange.row_properties and BandedRange.column_properties are set, the fill colors are applied to cells according to the following rules: \* header_color and footer_color take priority over band colors. \* first_band_color takes priority over second_band_color. \* row_properties takes priority over column_properties. For example, the first row color takes priority over the first column color, but the first column color takes priority over the second row color. Similarly, the row header takes priority over the column header in the top left cell, but the column header takes priority over the first row color if the row header is not set.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "secondBandColor"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub second_band_color: Option<Color>,
     /**
      * Properties referring a single dimension (either row or column). If both BandedRange.row_properties and BandedRange.column_properties are set, the fill colors are applied to cells according to the following rules: \* header_color and footer_color take priority over band colors. \* first_band_color takes priority over second_band_color. \* row_properties takes priority over column_properties. For example, the first row color takes priority over the first column color, but the first column color takes priority over the second row color. Similarly, the row header takes priority over the column header in the top left cell, but the column header takes priority over the first row color if the row header is not set.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "secondBandColorStyle"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub second_band_color_style: Option<ColorStyle>,
 }
 
 /**
  * The comparison type of key value with baseline value.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ComparisonType {
     #[serde(rename = "ABSOLUTE_DIFFERENCE")]
     AbsoluteDifference,
@@ -577,7 +547,28 @@ impl Default for ComparisonType {
         ComparisonType::Noop
     }
 }
+
 impl ComparisonType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ComparisonType::AbsoluteDifference => "ABSOLUTE_DIFFERENCE",
+            ComparisonType::ComparisonTypeUndefined => "COMPARISON_TYPE_UNDEFINED",
+            ComparisonType::PercentageDifference => "PERCENTAGE_DIFFERENCE",
+            ComparisonType::Noop => "",
+            ComparisonType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ComparisonType] {
+        &[
+            ComparisonType::AbsoluteDifference,
+            ComparisonType::ComparisonTypeUndefined,
+            ComparisonType::PercentageDifference,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ComparisonType::Noop)
     }
@@ -585,15 +576,12 @@ impl ComparisonType {
 
 /// Formatting options for baseline value.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct BaselineValueFormat {
     /**
      * Formatting options for baseline value.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "comparisonType"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub comparison_type: Option<ComparisonType>,
     /**
      * Formatting options for baseline value.
@@ -607,20 +595,12 @@ pub struct BaselineValueFormat {
     /**
      * Formatting options for baseline value.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "negativeColor"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub negative_color: Option<Color>,
     /**
      * Formatting options for baseline value.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "negativeColorStyle"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub negative_color_style: Option<ColorStyle>,
     /**
      * Formatting options for baseline value.
@@ -630,36 +610,24 @@ pub struct BaselineValueFormat {
     /**
      * Formatting options for baseline value.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "positiveColor"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub positive_color: Option<Color>,
     /**
      * Formatting options for baseline value.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "positiveColorStyle"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub positive_color_style: Option<ColorStyle>,
     /**
      * Formatting options for baseline value.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "textFormat"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub text_format: Option<TextFormat>,
 }
 
 /**
  * The position of this axis.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Position {
     #[serde(rename = "BASIC_CHART_AXIS_POSITION_UNSPECIFIED")]
     BasicChartAxisPositionUnspecified,
@@ -694,7 +662,30 @@ impl Default for Position {
         Position::Noop
     }
 }
+
 impl Position {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Position::BasicChartAxisPositionUnspecified => "BASIC_CHART_AXIS_POSITION_UNSPECIFIED",
+            Position::BottomAxis => "BOTTOM_AXIS",
+            Position::LeftAxis => "LEFT_AXIS",
+            Position::RightAxis => "RIGHT_AXIS",
+            Position::Noop => "",
+            Position::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Position] {
+        &[
+            Position::BasicChartAxisPositionUnspecified,
+            Position::BottomAxis,
+            Position::LeftAxis,
+            Position::RightAxis,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Position::Noop)
     }
@@ -702,6 +693,7 @@ impl Position {
 
 /// An axis of the chart. A chart may not have more than one axis per axis position.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct BasicChartAxis {
     /**
      * An axis of the chart. A chart may not have more than one axis per axis position.
@@ -725,20 +717,12 @@ pub struct BasicChartAxis {
     /**
      * An axis of the chart. A chart may not have more than one axis per axis position.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "titleTextPosition"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub title_text_position: Option<TextPosition>,
     /**
      * An axis of the chart. A chart may not have more than one axis per axis position.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "viewWindowOptions"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub view_window_options: Option<ChartAxisViewWindowOptions>,
 }
 
@@ -763,7 +747,7 @@ pub struct BasicChartDomain {
 /**
  * The type of this series. Valid only if the chartType is COMBO. Different types will change the way the series is visualized. Only LINE, AREA, and COLUMN are supported.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Type {
     #[serde(rename = "AREA")]
     Area,
@@ -810,7 +794,38 @@ impl Default for Type {
         Type::Noop
     }
 }
+
 impl Type {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Type::Area => "AREA",
+            Type::Bar => "BAR",
+            Type::BasicChartTypeUnspecified => "BASIC_CHART_TYPE_UNSPECIFIED",
+            Type::Column => "COLUMN",
+            Type::Combo => "COMBO",
+            Type::Line => "LINE",
+            Type::Scatter => "SCATTER",
+            Type::SteppedArea => "STEPPED_AREA",
+            Type::Noop => "",
+            Type::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Type] {
+        &[
+            Type::Area,
+            Type::Bar,
+            Type::BasicChartTypeUnspecified,
+            Type::Column,
+            Type::Combo,
+            Type::Line,
+            Type::Scatter,
+            Type::SteppedArea,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Type::Noop)
     }
@@ -818,6 +833,7 @@ impl Type {
 
 /// A single series of data in a chart. For example, if charting stock prices over time, multiple series may exist, one for the "Open Price", "High Price", "Low Price" and "Close Price".
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct BasicChartSeries {
     /**
      * A single series of data in a chart. For example, if charting stock prices over time, multiple series may exist, one for the "Open Price", "High Price", "Low Price" and "Close Price".
@@ -827,30 +843,22 @@ pub struct BasicChartSeries {
     /**
      * A single series of data in a chart. For example, if charting stock prices over time, multiple series may exist, one for the "Open Price", "High Price", "Low Price" and "Close Price".
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "colorStyle"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub color_style: Option<ColorStyle>,
     /**
      * A single series of data in a chart. For example, if charting stock prices over time, multiple series may exist, one for the "Open Price", "High Price", "Low Price" and "Close Price".
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "dataLabel")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data_label: Option<DataLabel>,
     /**
      * A single series of data in a chart. For example, if charting stock prices over time, multiple series may exist, one for the "Open Price", "High Price", "Low Price" and "Close Price".
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "lineStyle")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub line_style: Option<LineStyle>,
     /**
      * A single series of data in a chart. For example, if charting stock prices over time, multiple series may exist, one for the "Open Price", "High Price", "Low Price" and "Close Price".
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "pointStyle"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub point_style: Option<PointStyle>,
     /**
      * A single series of data in a chart. For example, if charting stock prices over time, multiple series may exist, one for the "Open Price", "High Price", "Low Price" and "Close Price".
@@ -863,18 +871,13 @@ pub struct BasicChartSeries {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "styleOverrides"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub style_overrides: Vec<BasicSeriesDataPointStyleOverride>,
     /**
      * A single series of data in a chart. For example, if charting stock prices over time, multiple series may exist, one for the "Open Price", "High Price", "Low Price" and "Close Price".
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "targetAxis"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub target_axis: Option<Position>,
     /**
      * A single series of data in a chart. For example, if charting stock prices over time, multiple series may exist, one for the "Open Price", "High Price", "Low Price" and "Close Price".
@@ -886,7 +889,7 @@ pub struct BasicChartSeries {
 /**
  * The behavior of tooltips and data highlighting when hovering on data and chart area.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum CompareMode {
     #[serde(rename = "BASIC_CHART_COMPARE_MODE_UNSPECIFIED")]
     BasicChartCompareModeUnspecified,
@@ -918,7 +921,28 @@ impl Default for CompareMode {
         CompareMode::Noop
     }
 }
+
 impl CompareMode {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CompareMode::BasicChartCompareModeUnspecified => "BASIC_CHART_COMPARE_MODE_UNSPECIFIED",
+            CompareMode::Category => "CATEGORY",
+            CompareMode::Datum => "DATUM",
+            CompareMode::Noop => "",
+            CompareMode::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [CompareMode] {
+        &[
+            CompareMode::BasicChartCompareModeUnspecified,
+            CompareMode::Category,
+            CompareMode::Datum,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, CompareMode::Noop)
     }
@@ -927,7 +951,7 @@ impl CompareMode {
 /**
  * The position of the chart legend.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum LegendPosition {
     #[serde(rename = "BASIC_CHART_LEGEND_POSITION_UNSPECIFIED")]
     BasicChartLegendPositionUnspecified,
@@ -970,16 +994,45 @@ impl Default for LegendPosition {
         LegendPosition::Noop
     }
 }
-impl LegendPosition {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, LegendPosition::Noop)
-    }
-}
 
-/**
- * The stacked type for charts that support vertical stacking. Applies to Area, Bar, Column, Combo, and Stepped Area charts.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl LegendPosition {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LegendPosition::BasicChartLegendPositionUnspecified => {
+                "BASIC_CHART_LEGEND_POSITION_UNSPECIFIED"
+            }
+            LegendPosition::BottomLegend => "BOTTOM_LEGEND",
+            LegendPosition::LeftLegend => "LEFT_LEGEND",
+            LegendPosition::NoLegend => "NO_LEGEND",
+            LegendPosition::RightLegend => "RIGHT_LEGEND",
+            LegendPosition::TopLegend => "TOP_LEGEND",
+            LegendPosition::Noop => "",
+            LegendPosition::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [LegendPosition] {
+        &[
+            LegendPosition::BasicChartLegendPositionUnspecified,
+            LegendPosition::BottomLegend,
+            LegendPosition::LeftLegend,
+            LegendPosition::NoLegend,
+            LegendPosition::RightLegend,
+            LegendPosition::TopLegend,
+        ]
+    }
+
+    pub fn is_noop(&self) -> bool {
+        matches!(self, LegendPosition::Noop)
+    }
+}
+
+/**
+ * The stacked type for charts that support vertical stacking. Applies to Area, Bar, Column, Combo, and Stepped Area charts.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum StackedType {
     #[serde(rename = "BASIC_CHART_STACKED_TYPE_UNSPECIFIED")]
     BasicChartStackedTypeUnspecified,
@@ -1014,7 +1067,30 @@ impl Default for StackedType {
         StackedType::Noop
     }
 }
+
 impl StackedType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StackedType::BasicChartStackedTypeUnspecified => "BASIC_CHART_STACKED_TYPE_UNSPECIFIED",
+            StackedType::NotStacked => "NOT_STACKED",
+            StackedType::PercentStacked => "PERCENT_STACKED",
+            StackedType::Stacked => "STACKED",
+            StackedType::Noop => "",
+            StackedType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [StackedType] {
+        &[
+            StackedType::BasicChartStackedTypeUnspecified,
+            StackedType::NotStacked,
+            StackedType::PercentStacked,
+            StackedType::Stacked,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, StackedType::Noop)
     }
@@ -1022,6 +1098,7 @@ impl StackedType {
 
 /// The specification for a basic chart. See BasicChartType for the list of charts this supports.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct BasicChartSpec {
     /**
      * The specification for a basic chart. See BasicChartType for the list of charts this supports.
@@ -1035,16 +1112,12 @@ pub struct BasicChartSpec {
     /**
      * The specification for a basic chart. See BasicChartType for the list of charts this supports.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "chartType")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub chart_type: Option<Type>,
     /**
      * The specification for a basic chart. See BasicChartType for the list of charts this supports.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "compareMode"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub compare_mode: Option<CompareMode>,
     /**
      * The specification for a basic chart. See BasicChartType for the list of charts this supports.
@@ -1061,8 +1134,7 @@ pub struct BasicChartSpec {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "headerCount"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub header_count: i64,
     /**
@@ -1070,26 +1142,20 @@ pub struct BasicChartSpec {
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "interpolateNulls"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub interpolate_nulls: bool,
     /**
      * The specification for a basic chart. See BasicChartType for the list of charts this supports.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "legendPosition"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub legend_position: Option<LegendPosition>,
     /**
      * The specification for a basic chart. See BasicChartType for the list of charts this supports.
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "lineSmoothing"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub line_smoothing: bool,
     /**
@@ -1104,34 +1170,26 @@ pub struct BasicChartSpec {
     /**
      * The specification for a basic chart. See BasicChartType for the list of charts this supports.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "stackedType"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub stacked_type: Option<StackedType>,
     /**
      * The specification for a basic chart. See BasicChartType for the list of charts this supports.
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "threeDimensional"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub three_dimensional: bool,
     /**
      * The specification for a basic chart. See BasicChartType for the list of charts this supports.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "totalDataLabel"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub total_data_label: Option<DataLabel>,
 }
 
 /// The default filter associated with a sheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct BasicFilter {
     /**
      * The default filter associated with a sheet.
@@ -1144,8 +1202,7 @@ pub struct BasicFilter {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "filterSpecs"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub filter_specs: Vec<FilterSpec>,
     /**
@@ -1159,14 +1216,14 @@ pub struct BasicFilter {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "sortSpecs"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub sort_specs: Vec<SortSpec>,
 }
 
 /// Style override settings for a single series data point.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct BasicSeriesDataPointStyleOverride {
     /**
      * Style override settings for a single series data point.
@@ -1176,11 +1233,7 @@ pub struct BasicSeriesDataPointStyleOverride {
     /**
      * Style override settings for a single series data point.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "colorStyle"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub color_style: Option<ColorStyle>,
     /**
      * Style override settings for a single series data point.
@@ -1194,16 +1247,13 @@ pub struct BasicSeriesDataPointStyleOverride {
     /**
      * Style override settings for a single series data point.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "pointStyle"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub point_style: Option<PointStyle>,
 }
 
 /// The request for clearing more than one range selected by a DataFilter in a spreadsheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct BatchClearValuesByDataFilterRequest {
     /**
      * The request for clearing more than one range selected by a DataFilter in a spreadsheet.
@@ -1211,14 +1261,14 @@ pub struct BatchClearValuesByDataFilterRequest {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "dataFilters"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub data_filters: Vec<DataFilter>,
 }
 
 /// The response when clearing a range of values selected with DataFilters in a spreadsheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct BatchClearValuesByDataFilterResponse {
     /**
      * The response when clearing a range of values selected with DataFilters in a spreadsheet.
@@ -1226,8 +1276,7 @@ pub struct BatchClearValuesByDataFilterResponse {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "clearedRanges"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub cleared_ranges: Vec<String>,
     /**
@@ -1236,8 +1285,7 @@ pub struct BatchClearValuesByDataFilterResponse {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "spreadsheetId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub spreadsheet_id: String,
 }
@@ -1258,6 +1306,7 @@ pub struct BatchClearValuesRequest {
 
 /// The response when clearing a range of values in a spreadsheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct BatchClearValuesResponse {
     /**
      * The response when clearing a range of values in a spreadsheet.
@@ -1265,8 +1314,7 @@ pub struct BatchClearValuesResponse {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "clearedRanges"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub cleared_ranges: Vec<String>,
     /**
@@ -1275,8 +1323,7 @@ pub struct BatchClearValuesResponse {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "spreadsheetId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub spreadsheet_id: String,
 }
@@ -1284,7 +1331,7 @@ pub struct BatchClearValuesResponse {
 /**
  * How dates, times, and durations should be represented in the output. This is ignored if value_render_option is FORMATTED_VALUE. The default dateTime render option is SERIAL_NUMBER.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum DateTimeRenderOption {
     #[serde(rename = "FORMATTED_STRING")]
     FormattedString,
@@ -1313,7 +1360,26 @@ impl Default for DateTimeRenderOption {
         DateTimeRenderOption::Noop
     }
 }
+
 impl DateTimeRenderOption {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DateTimeRenderOption::FormattedString => "FORMATTED_STRING",
+            DateTimeRenderOption::SerialNumber => "SERIAL_NUMBER",
+            DateTimeRenderOption::Noop => "",
+            DateTimeRenderOption::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [DateTimeRenderOption] {
+        &[
+            DateTimeRenderOption::FormattedString,
+            DateTimeRenderOption::SerialNumber,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, DateTimeRenderOption::Noop)
     }
@@ -1322,7 +1388,7 @@ impl DateTimeRenderOption {
 /**
  * How values should be represented in the output. The default render option is FORMATTED_VALUE.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ValueRenderOption {
     #[serde(rename = "FORMATTED_VALUE")]
     FormattedValue,
@@ -1354,7 +1420,28 @@ impl Default for ValueRenderOption {
         ValueRenderOption::Noop
     }
 }
+
 impl ValueRenderOption {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ValueRenderOption::FormattedValue => "FORMATTED_VALUE",
+            ValueRenderOption::Formula => "FORMULA",
+            ValueRenderOption::UnformattedValue => "UNFORMATTED_VALUE",
+            ValueRenderOption::Noop => "",
+            ValueRenderOption::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ValueRenderOption] {
+        &[
+            ValueRenderOption::FormattedValue,
+            ValueRenderOption::Formula,
+            ValueRenderOption::UnformattedValue,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ValueRenderOption::Noop)
     }
@@ -1362,6 +1449,7 @@ impl ValueRenderOption {
 
 /// The request for retrieving a range of values in a spreadsheet selected by a set of DataFilters.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct BatchGetValuesByDataFilterRequest {
     /**
      * The request for retrieving a range of values in a spreadsheet selected by a set of DataFilters.
@@ -1369,41 +1457,29 @@ pub struct BatchGetValuesByDataFilterRequest {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "dataFilters"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub data_filters: Vec<DataFilter>,
     /**
      * The request for retrieving a range of values in a spreadsheet selected by a set of DataFilters.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "dateTimeRenderOption"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub date_time_render_option: Option<DateTimeRenderOption>,
     /**
      * The request for retrieving a range of values in a spreadsheet selected by a set of DataFilters.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "majorDimension"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub major_dimension: Option<Dimension>,
     /**
      * The request for retrieving a range of values in a spreadsheet selected by a set of DataFilters.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "valueRenderOption"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub value_render_option: Option<ValueRenderOption>,
 }
 
 /// The response when retrieving more than one range of values in a spreadsheet selected by DataFilters.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct BatchGetValuesByDataFilterResponse {
     /**
      * The response when retrieving more than one range of values in a spreadsheet selected by DataFilters.
@@ -1411,8 +1487,7 @@ pub struct BatchGetValuesByDataFilterResponse {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "spreadsheetId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub spreadsheet_id: String,
     /**
@@ -1421,14 +1496,14 @@ pub struct BatchGetValuesByDataFilterResponse {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "valueRanges"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub value_ranges: Vec<MatchedValueRange>,
 }
 
 /// The response when retrieving more than one range of values in a spreadsheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct BatchGetValuesResponse {
     /**
      * The response when retrieving more than one range of values in a spreadsheet.
@@ -1436,8 +1511,7 @@ pub struct BatchGetValuesResponse {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "spreadsheetId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub spreadsheet_id: String,
     /**
@@ -1446,23 +1520,19 @@ pub struct BatchGetValuesResponse {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "valueRanges"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub value_ranges: Vec<ValueRange>,
 }
 
 /// The request for updating any aspect of a spreadsheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct BatchUpdateSpreadsheetRequest {
     /**
      * The request for updating any aspect of a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "includeSpreadsheetInResponse"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub include_spreadsheet_in_response: Option<bool>,
     /**
      * The request for updating any aspect of a spreadsheet.
@@ -1476,11 +1546,7 @@ pub struct BatchUpdateSpreadsheetRequest {
     /**
      * The request for updating any aspect of a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "responseIncludeGridData"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub response_include_grid_data: Option<bool>,
     /**
      * The request for updating any aspect of a spreadsheet.
@@ -1488,14 +1554,14 @@ pub struct BatchUpdateSpreadsheetRequest {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "responseRanges"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub response_ranges: Vec<String>,
 }
 
 /// The reply for batch updating a spreadsheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct BatchUpdateSpreadsheetResponse {
     /**
      * The reply for batch updating a spreadsheet.
@@ -1512,25 +1578,20 @@ pub struct BatchUpdateSpreadsheetResponse {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "spreadsheetId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub spreadsheet_id: String,
     /**
      * The reply for batch updating a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "updatedSpreadsheet"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub updated_spreadsheet: Option<Spreadsheet>,
 }
 
 /**
  * How the input data should be interpreted.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ValueInputOption {
     #[serde(rename = "INPUT_VALUE_OPTION_UNSPECIFIED")]
     InputValueOptionUnspecified,
@@ -1562,7 +1623,28 @@ impl Default for ValueInputOption {
         ValueInputOption::Noop
     }
 }
+
 impl ValueInputOption {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ValueInputOption::InputValueOptionUnspecified => "INPUT_VALUE_OPTION_UNSPECIFIED",
+            ValueInputOption::Raw => "RAW",
+            ValueInputOption::UserEntered => "USER_ENTERED",
+            ValueInputOption::Noop => "",
+            ValueInputOption::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ValueInputOption] {
+        &[
+            ValueInputOption::InputValueOptionUnspecified,
+            ValueInputOption::Raw,
+            ValueInputOption::UserEntered,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ValueInputOption::Noop)
     }
@@ -1570,6 +1652,7 @@ impl ValueInputOption {
 
 /// The request for updating more than one range of values in a spreadsheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct BatchUpdateValuesByDataFilterRequest {
     /**
      * The request for updating more than one range of values in a spreadsheet.
@@ -1583,43 +1666,28 @@ pub struct BatchUpdateValuesByDataFilterRequest {
     /**
      * The request for updating more than one range of values in a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "includeValuesInResponse"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub include_values_in_response: Option<bool>,
     /**
      * The request for updating more than one range of values in a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "responseDateTimeRenderOption"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub response_date_time_render_option: Option<DateTimeRenderOption>,
     /**
      * The request for updating more than one range of values in a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "responseValueRenderOption"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub response_value_render_option: Option<ValueRenderOption>,
     /**
      * The request for updating more than one range of values in a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "valueInputOption"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub value_input_option: Option<ValueInputOption>,
 }
 
 /// The response when updating a range of values in a spreadsheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct BatchUpdateValuesByDataFilterResponse {
     /**
      * The response when updating a range of values in a spreadsheet.
@@ -1636,8 +1704,7 @@ pub struct BatchUpdateValuesByDataFilterResponse {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "spreadsheetId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub spreadsheet_id: String,
     /**
@@ -1646,8 +1713,7 @@ pub struct BatchUpdateValuesByDataFilterResponse {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "totalUpdatedCells"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_updated_cells: i64,
     /**
@@ -1656,8 +1722,7 @@ pub struct BatchUpdateValuesByDataFilterResponse {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "totalUpdatedColumns"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_updated_columns: i64,
     /**
@@ -1666,8 +1731,7 @@ pub struct BatchUpdateValuesByDataFilterResponse {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "totalUpdatedRows"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_updated_rows: i64,
     /**
@@ -1676,14 +1740,14 @@ pub struct BatchUpdateValuesByDataFilterResponse {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "totalUpdatedSheets"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_updated_sheets: i64,
 }
 
 /// The request for updating more than one range of values in a spreadsheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct BatchUpdateValuesRequest {
     /**
      * The request for updating more than one range of values in a spreadsheet.
@@ -1697,43 +1761,28 @@ pub struct BatchUpdateValuesRequest {
     /**
      * The request for updating more than one range of values in a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "includeValuesInResponse"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub include_values_in_response: Option<bool>,
     /**
      * The request for updating more than one range of values in a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "responseDateTimeRenderOption"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub response_date_time_render_option: Option<DateTimeRenderOption>,
     /**
      * The request for updating more than one range of values in a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "responseValueRenderOption"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub response_value_render_option: Option<ValueRenderOption>,
     /**
      * The request for updating more than one range of values in a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "valueInputOption"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub value_input_option: Option<ValueInputOption>,
 }
 
 /// The response when updating a range of values in a spreadsheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct BatchUpdateValuesResponse {
     /**
      * The response when updating a range of values in a spreadsheet.
@@ -1750,8 +1799,7 @@ pub struct BatchUpdateValuesResponse {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "spreadsheetId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub spreadsheet_id: String,
     /**
@@ -1760,8 +1808,7 @@ pub struct BatchUpdateValuesResponse {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "totalUpdatedCells"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_updated_cells: i64,
     /**
@@ -1770,8 +1817,7 @@ pub struct BatchUpdateValuesResponse {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "totalUpdatedColumns"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_updated_columns: i64,
     /**
@@ -1780,8 +1826,7 @@ pub struct BatchUpdateValuesResponse {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "totalUpdatedRows"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_updated_rows: i64,
     /**
@@ -1790,14 +1835,14 @@ pub struct BatchUpdateValuesResponse {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "totalUpdatedSheets"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_updated_sheets: i64,
 }
 
 /// The specification of a BigQuery data source that's connected to a sheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct BigQueryDataSourceSpec {
     /**
      * The specification of a BigQuery data source that's connected to a sheet.
@@ -1805,24 +1850,24 @@ pub struct BigQueryDataSourceSpec {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "projectId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub project_id: String,
     /**
      * The specification of a BigQuery data source that's connected to a sheet.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "querySpec")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub query_spec: Option<BigQuerySpec>,
     /**
      * The specification of a BigQuery data source that's connected to a sheet.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "tableSpec")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub table_spec: Option<BigQueryTableSpec>,
 }
 
 /// Specifies a custom BigQuery query.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct BigQuerySpec {
     /**
      * Specifies a custom BigQuery query.
@@ -1830,14 +1875,14 @@ pub struct BigQuerySpec {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "rawQuery"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub raw_query: String,
 }
 
 /// Specifies a BigQuery table definition. Only [native tables](https://cloud.google.com/bigquery/docs/tables-intro) is allowed.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct BigQueryTableSpec {
     /**
      * Specifies a BigQuery table definition. Only [native tables](https://cloud.google.com/bigquery/docs/tables-intro) is allowed.
@@ -1845,8 +1890,7 @@ pub struct BigQueryTableSpec {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "datasetId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub dataset_id: String,
     /**
@@ -1855,8 +1899,7 @@ pub struct BigQueryTableSpec {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "tableId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub table_id: String,
     /**
@@ -1865,8 +1908,7 @@ pub struct BigQueryTableSpec {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "tableProjectId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub table_project_id: String,
 }
@@ -1874,7 +1916,7 @@ pub struct BigQueryTableSpec {
 /**
  * The type of condition.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum BooleanConditionType {
     #[serde(rename = "BLANK")]
     Blank,
@@ -1993,7 +2035,86 @@ impl Default for BooleanConditionType {
         BooleanConditionType::Noop
     }
 }
+
 impl BooleanConditionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BooleanConditionType::Blank => "BLANK",
+            BooleanConditionType::Boolean => "BOOLEAN",
+            BooleanConditionType::ConditionTypeUnspecified => "CONDITION_TYPE_UNSPECIFIED",
+            BooleanConditionType::CustomFormula => "CUSTOM_FORMULA",
+            BooleanConditionType::DateAfter => "DATE_AFTER",
+            BooleanConditionType::DateBefore => "DATE_BEFORE",
+            BooleanConditionType::DateBetween => "DATE_BETWEEN",
+            BooleanConditionType::DateEq => "DATE_EQ",
+            BooleanConditionType::DateIsValid => "DATE_IS_VALID",
+            BooleanConditionType::DateNotBetween => "DATE_NOT_BETWEEN",
+            BooleanConditionType::DateNotEq => "DATE_NOT_EQ",
+            BooleanConditionType::DateOnOrAfter => "DATE_ON_OR_AFTER",
+            BooleanConditionType::DateOnOrBefore => "DATE_ON_OR_BEFORE",
+            BooleanConditionType::NotBlank => "NOT_BLANK",
+            BooleanConditionType::NumberBetween => "NUMBER_BETWEEN",
+            BooleanConditionType::NumberEq => "NUMBER_EQ",
+            BooleanConditionType::NumberGreater => "NUMBER_GREATER",
+            BooleanConditionType::NumberGreaterThanEq => "NUMBER_GREATER_THAN_EQ",
+            BooleanConditionType::NumberLess => "NUMBER_LESS",
+            BooleanConditionType::NumberLessThanEq => "NUMBER_LESS_THAN_EQ",
+            BooleanConditionType::NumberNotBetween => "NUMBER_NOT_BETWEEN",
+            BooleanConditionType::NumberNotEq => "NUMBER_NOT_EQ",
+            BooleanConditionType::OneOfList => "ONE_OF_LIST",
+            BooleanConditionType::OneOfRange => "ONE_OF_RANGE",
+            BooleanConditionType::TextContains => "TEXT_CONTAINS",
+            BooleanConditionType::TextEndsWith => "TEXT_ENDS_WITH",
+            BooleanConditionType::TextEq => "TEXT_EQ",
+            BooleanConditionType::TextIsEmail => "TEXT_IS_EMAIL",
+            BooleanConditionType::TextIsUrl => "TEXT_IS_URL",
+            BooleanConditionType::TextNotContains => "TEXT_NOT_CONTAINS",
+            BooleanConditionType::TextNotEq => "TEXT_NOT_EQ",
+            BooleanConditionType::TextStartsWith => "TEXT_STARTS_WITH",
+            BooleanConditionType::Noop => "",
+            BooleanConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [BooleanConditionType] {
+        &[
+            BooleanConditionType::Blank,
+            BooleanConditionType::Boolean,
+            BooleanConditionType::ConditionTypeUnspecified,
+            BooleanConditionType::CustomFormula,
+            BooleanConditionType::DateAfter,
+            BooleanConditionType::DateBefore,
+            BooleanConditionType::DateBetween,
+            BooleanConditionType::DateEq,
+            BooleanConditionType::DateIsValid,
+            BooleanConditionType::DateNotBetween,
+            BooleanConditionType::DateNotEq,
+            BooleanConditionType::DateOnOrAfter,
+            BooleanConditionType::DateOnOrBefore,
+            BooleanConditionType::NotBlank,
+            BooleanConditionType::NumberBetween,
+            BooleanConditionType::NumberEq,
+            BooleanConditionType::NumberGreater,
+            BooleanConditionType::NumberGreaterThanEq,
+            BooleanConditionType::NumberLess,
+            BooleanConditionType::NumberLessThanEq,
+            BooleanConditionType::NumberNotBetween,
+            BooleanConditionType::NumberNotEq,
+            BooleanConditionType::OneOfList,
+            BooleanConditionType::OneOfRange,
+            BooleanConditionType::TextContains,
+            BooleanConditionType::TextEndsWith,
+            BooleanConditionType::TextEq,
+            BooleanConditionType::TextIsEmail,
+            BooleanConditionType::TextIsUrl,
+            BooleanConditionType::TextNotContains,
+            BooleanConditionType::TextNotEq,
+            BooleanConditionType::TextStartsWith,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, BooleanConditionType::Noop)
     }
@@ -2036,7 +2157,7 @@ pub struct BooleanRule {
 /**
  * The style of the border.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Style {
     #[serde(rename = "DASHED")]
     Dashed,
@@ -2083,14 +2204,46 @@ impl Default for Style {
         Style::Noop
     }
 }
-impl Style {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Style::Noop)
-    }
-}
 
-/// A border along a cell.
+impl Style {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Style::Dashed => "DASHED",
+            Style::Dotted => "DOTTED",
+            Style::Double => "DOUBLE",
+            Style::None => "NONE",
+            Style::Solid => "SOLID",
+            Style::SolidMedium => "SOLID_MEDIUM",
+            Style::SolidThick => "SOLID_THICK",
+            Style::StyleUnspecified => "STYLE_UNSPECIFIED",
+            Style::Noop => "",
+            Style::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Style] {
+        &[
+            Style::Dashed,
+            Style::Dotted,
+            Style::Double,
+            Style::None,
+            Style::Solid,
+            Style::SolidMedium,
+            Style::SolidThick,
+            Style::StyleUnspecified,
+        ]
+    }
+
+    pub fn is_noop(&self) -> bool {
+        matches!(self, Style::Noop)
+    }
+}
+
+/// A border along a cell.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Border {
     /**
      * A border along a cell.
@@ -2100,11 +2253,7 @@ pub struct Border {
     /**
      * A border along a cell.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "colorStyle"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub color_style: Option<ColorStyle>,
     /**
      * A border along a cell.
@@ -2150,7 +2299,7 @@ pub struct Borders {
 /**
  * Where the legend of the chart should be drawn.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum BubbleChartSpecLegendPosition {
     #[serde(rename = "BOTTOM_LEGEND")]
     BottomLegend,
@@ -2196,7 +2345,38 @@ impl Default for BubbleChartSpecLegendPosition {
         BubbleChartSpecLegendPosition::Noop
     }
 }
+
 impl BubbleChartSpecLegendPosition {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BubbleChartSpecLegendPosition::BottomLegend => "BOTTOM_LEGEND",
+            BubbleChartSpecLegendPosition::BubbleChartLegendPositionUnspecified => {
+                "BUBBLE_CHART_LEGEND_POSITION_UNSPECIFIED"
+            }
+            BubbleChartSpecLegendPosition::InsideLegend => "INSIDE_LEGEND",
+            BubbleChartSpecLegendPosition::LeftLegend => "LEFT_LEGEND",
+            BubbleChartSpecLegendPosition::NoLegend => "NO_LEGEND",
+            BubbleChartSpecLegendPosition::RightLegend => "RIGHT_LEGEND",
+            BubbleChartSpecLegendPosition::TopLegend => "TOP_LEGEND",
+            BubbleChartSpecLegendPosition::Noop => "",
+            BubbleChartSpecLegendPosition::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [BubbleChartSpecLegendPosition] {
+        &[
+            BubbleChartSpecLegendPosition::BottomLegend,
+            BubbleChartSpecLegendPosition::BubbleChartLegendPositionUnspecified,
+            BubbleChartSpecLegendPosition::InsideLegend,
+            BubbleChartSpecLegendPosition::LeftLegend,
+            BubbleChartSpecLegendPosition::NoLegend,
+            BubbleChartSpecLegendPosition::RightLegend,
+            BubbleChartSpecLegendPosition::TopLegend,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, BubbleChartSpecLegendPosition::Noop)
     }
@@ -2204,33 +2384,22 @@ impl BubbleChartSpecLegendPosition {
 
 /// A bubble chart.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct BubbleChartSpec {
     /**
      * A bubble chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "bubbleBorderColor"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub bubble_border_color: Option<Color>,
     /**
      * A bubble chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "bubbleBorderColorStyle"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub bubble_border_color_style: Option<ColorStyle>,
     /**
      * A bubble chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "bubbleLabels"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub bubble_labels: Option<ChartData>,
     /**
      * A bubble chart.
@@ -2238,8 +2407,7 @@ pub struct BubbleChartSpec {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "bubbleMaxRadiusSize"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub bubble_max_radius_size: i64,
     /**
@@ -2248,8 +2416,7 @@ pub struct BubbleChartSpec {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "bubbleMinRadiusSize"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub bubble_min_radius_size: i64,
     /**
@@ -2258,27 +2425,18 @@ pub struct BubbleChartSpec {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "bubbleOpacity"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub bubble_opacity: f64,
     /**
      * A bubble chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "bubbleSizes"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub bubble_sizes: Option<ChartData>,
     /**
      * A bubble chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "bubbleTextStyle"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub bubble_text_style: Option<TextFormat>,
     /**
      * A bubble chart.
@@ -2288,16 +2446,12 @@ pub struct BubbleChartSpec {
     /**
      * A bubble chart.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "groupIds")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub group_ids: Option<ChartData>,
     /**
      * A bubble chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "legendPosition"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub legend_position: Option<BubbleChartSpecLegendPosition>,
     /**
      * A bubble chart.
@@ -2327,38 +2481,27 @@ pub struct CandlestickChartSpec {
 
 /// The Candlestick chart data, each containing the low, open, close, and high values for a series.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct CandlestickData {
     /**
      * The Candlestick chart data, each containing the low, open, close, and high values for a series.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "closeSeries"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub close_series: Option<CandlestickSeries>,
     /**
      * The Candlestick chart data, each containing the low, open, close, and high values for a series.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "highSeries"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub high_series: Option<CandlestickSeries>,
     /**
      * The Candlestick chart data, each containing the low, open, close, and high values for a series.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "lowSeries")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub low_series: Option<CandlestickSeries>,
     /**
      * The Candlestick chart data, each containing the low, open, close, and high values for a series.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "openSeries"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub open_series: Option<CandlestickSeries>,
 }
 
@@ -2392,51 +2535,32 @@ pub struct CandlestickSeries {
 
 /// Data about a specific cell.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct CellData {
     /**
      * Data about a specific cell.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "dataSourceFormula"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data_source_formula: Option<DataSourceFormula>,
     /**
      * Data about a specific cell.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "dataSourceTable"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data_source_table: Option<DataSourceTable>,
     /**
      * Data about a specific cell.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "dataValidation"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data_validation: Option<DataValidationRule>,
     /**
      * Data about a specific cell.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "effectiveFormat"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub effective_format: Option<CellFormat>,
     /**
      * Data about a specific cell.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "effectiveValue"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub effective_value: Option<ExtendedValue>,
     /**
      * Data about a specific cell.
@@ -2444,8 +2568,7 @@ pub struct CellData {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "formattedValue"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub formatted_value: String,
     /**
@@ -2469,11 +2592,7 @@ pub struct CellData {
     /**
      * Data about a specific cell.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "pivotTable"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub pivot_table: Option<PivotTable>,
     /**
      * Data about a specific cell.
@@ -2481,34 +2600,25 @@ pub struct CellData {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "textFormatRuns"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub text_format_runs: Vec<TextFormatRun>,
     /**
      * Data about a specific cell.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "userEnteredFormat"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user_entered_format: Option<CellFormat>,
     /**
      * Data about a specific cell.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "userEnteredValue"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user_entered_value: Option<ExtendedValue>,
 }
 
 /**
  * The horizontal alignment of the value in the cell.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum HorizontalAlignment {
     #[serde(rename = "CENTER")]
     Center,
@@ -2543,7 +2653,30 @@ impl Default for HorizontalAlignment {
         HorizontalAlignment::Noop
     }
 }
+
 impl HorizontalAlignment {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HorizontalAlignment::Center => "CENTER",
+            HorizontalAlignment::HorizontalAlignUnspecified => "HORIZONTAL_ALIGN_UNSPECIFIED",
+            HorizontalAlignment::Left => "LEFT",
+            HorizontalAlignment::Right => "RIGHT",
+            HorizontalAlignment::Noop => "",
+            HorizontalAlignment::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [HorizontalAlignment] {
+        &[
+            HorizontalAlignment::Center,
+            HorizontalAlignment::HorizontalAlignUnspecified,
+            HorizontalAlignment::Left,
+            HorizontalAlignment::Right,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, HorizontalAlignment::Noop)
     }
@@ -2552,7 +2685,7 @@ impl HorizontalAlignment {
 /**
  * How a hyperlink, if it exists, should be displayed in the cell.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum HyperlinkDisplayType {
     #[serde(rename = "HYPERLINK_DISPLAY_TYPE_UNSPECIFIED")]
     HyperlinkDisplayTypeUnspecified,
@@ -2586,7 +2719,30 @@ impl Default for HyperlinkDisplayType {
         HyperlinkDisplayType::Noop
     }
 }
+
 impl HyperlinkDisplayType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HyperlinkDisplayType::HyperlinkDisplayTypeUnspecified => {
+                "HYPERLINK_DISPLAY_TYPE_UNSPECIFIED"
+            }
+            HyperlinkDisplayType::Linked => "LINKED",
+            HyperlinkDisplayType::PlainText => "PLAIN_TEXT",
+            HyperlinkDisplayType::Noop => "",
+            HyperlinkDisplayType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [HyperlinkDisplayType] {
+        &[
+            HyperlinkDisplayType::HyperlinkDisplayTypeUnspecified,
+            HyperlinkDisplayType::Linked,
+            HyperlinkDisplayType::PlainText,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, HyperlinkDisplayType::Noop)
     }
@@ -2595,7 +2751,7 @@ impl HyperlinkDisplayType {
 /**
  * The direction of the text in the cell.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum TextDirection {
     #[serde(rename = "LEFT_TO_RIGHT")]
     LeftToRight,
@@ -2627,7 +2783,28 @@ impl Default for TextDirection {
         TextDirection::Noop
     }
 }
+
 impl TextDirection {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TextDirection::LeftToRight => "LEFT_TO_RIGHT",
+            TextDirection::RightToLeft => "RIGHT_TO_LEFT",
+            TextDirection::TextDirectionUnspecified => "TEXT_DIRECTION_UNSPECIFIED",
+            TextDirection::Noop => "",
+            TextDirection::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [TextDirection] {
+        &[
+            TextDirection::LeftToRight,
+            TextDirection::RightToLeft,
+            TextDirection::TextDirectionUnspecified,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, TextDirection::Noop)
     }
@@ -2636,7 +2813,7 @@ impl TextDirection {
 /**
  * The vertical alignment of the value in the cell.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum VerticalAlignment {
     #[serde(rename = "BOTTOM")]
     Bottom,
@@ -2671,7 +2848,30 @@ impl Default for VerticalAlignment {
         VerticalAlignment::Noop
     }
 }
+
 impl VerticalAlignment {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VerticalAlignment::Bottom => "BOTTOM",
+            VerticalAlignment::Middle => "MIDDLE",
+            VerticalAlignment::Top => "TOP",
+            VerticalAlignment::VerticalAlignUnspecified => "VERTICAL_ALIGN_UNSPECIFIED",
+            VerticalAlignment::Noop => "",
+            VerticalAlignment::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [VerticalAlignment] {
+        &[
+            VerticalAlignment::Bottom,
+            VerticalAlignment::Middle,
+            VerticalAlignment::Top,
+            VerticalAlignment::VerticalAlignUnspecified,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, VerticalAlignment::Noop)
     }
@@ -2680,7 +2880,7 @@ impl VerticalAlignment {
 /**
  * The wrap strategy for the value in the cell.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum WrapStrategy {
     #[serde(rename = "CLIP")]
     Clip,
@@ -2718,7 +2918,32 @@ impl Default for WrapStrategy {
         WrapStrategy::Noop
     }
 }
+
 impl WrapStrategy {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WrapStrategy::Clip => "CLIP",
+            WrapStrategy::LegacyWrap => "LEGACY_WRAP",
+            WrapStrategy::OverflowCell => "OVERFLOW_CELL",
+            WrapStrategy::Wrap => "WRAP",
+            WrapStrategy::WrapStrategyUnspecified => "WRAP_STRATEGY_UNSPECIFIED",
+            WrapStrategy::Noop => "",
+            WrapStrategy::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [WrapStrategy] {
+        &[
+            WrapStrategy::Clip,
+            WrapStrategy::LegacyWrap,
+            WrapStrategy::OverflowCell,
+            WrapStrategy::Wrap,
+            WrapStrategy::WrapStrategyUnspecified,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, WrapStrategy::Noop)
     }
@@ -2726,24 +2951,17 @@ impl WrapStrategy {
 
 /// The format of a cell.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct CellFormat {
     /**
      * The format of a cell.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "backgroundColor"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub background_color: Option<Color>,
     /**
      * The format of a cell.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "backgroundColorStyle"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub background_color_style: Option<ColorStyle>,
     /**
      * The format of a cell.
@@ -2753,29 +2971,17 @@ pub struct CellFormat {
     /**
      * The format of a cell.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "horizontalAlignment"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub horizontal_alignment: Option<HorizontalAlignment>,
     /**
      * The format of a cell.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "hyperlinkDisplayType"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub hyperlink_display_type: Option<HyperlinkDisplayType>,
     /**
      * The format of a cell.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "numberFormat"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub number_format: Option<NumberFormat>,
     /**
      * The format of a cell.
@@ -2785,54 +2991,34 @@ pub struct CellFormat {
     /**
      * The format of a cell.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "textDirection"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub text_direction: Option<TextDirection>,
     /**
      * The format of a cell.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "textFormat"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub text_format: Option<TextFormat>,
     /**
      * The format of a cell.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "textRotation"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub text_rotation: Option<TextRotation>,
     /**
      * The format of a cell.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "verticalAlignment"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub vertical_alignment: Option<VerticalAlignment>,
     /**
      * The format of a cell.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "wrapStrategy"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub wrap_strategy: Option<WrapStrategy>,
 }
 
 /**
  * The view window's mode.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ViewWindowMode {
     #[serde(rename = "DEFAULT_VIEW_WINDOW_MODE")]
     DefaultViewWindowMode,
@@ -2867,14 +3053,38 @@ impl Default for ViewWindowMode {
         ViewWindowMode::Noop
     }
 }
+
 impl ViewWindowMode {
-    pub fn is_noop(&self) -> bool {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ViewWindowMode::DefaultViewWindowMode => "DEFAULT_VIEW_WINDOW_MODE",
+            ViewWindowMode::Explicit => "EXPLICIT",
+            ViewWindowMode::Pretty => "PRETTY",
+            ViewWindowMode::ViewWindowModeUnsupported => "VIEW_WINDOW_MODE_UNSUPPORTED",
+            ViewWindowMode::Noop => "",
+            ViewWindowMode::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ViewWindowMode] {
+        &[
+            ViewWindowMode::DefaultViewWindowMode,
+            ViewWindowMode::Explicit,
+            ViewWindowMode::Pretty,
+            ViewWindowMode::ViewWindowModeUnsupported,
+        ]
+    }
+
+    pub fn is_noop(&self) -> bool {
         matches!(self, ViewWindowMode::Noop)
     }
 }
 
 /// The options that define a "view window" for a chart (such as the visible values in an axis).
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ChartAxisViewWindowOptions {
     /**
      * The options that define a "view window" for a chart (such as the visible values in an axis).
@@ -2882,8 +3092,7 @@ pub struct ChartAxisViewWindowOptions {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "viewWindowMax"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub view_window_max: f64,
     /**
@@ -2892,18 +3101,13 @@ pub struct ChartAxisViewWindowOptions {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "viewWindowMin"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub view_window_min: f64,
     /**
      * The options that define a "view window" for a chart (such as the visible values in an axis).
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "viewWindowMode"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub view_window_mode: Option<ViewWindowMode>,
 }
 
@@ -2933,7 +3137,7 @@ pub struct ChartCustomNumberFormatOptions {
 /**
  * The aggregation type for the series of a data source chart. Only supported for data source charts.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum AggregateType {
     #[serde(rename = "AVERAGE")]
     Average,
@@ -2977,7 +3181,36 @@ impl Default for AggregateType {
         AggregateType::Noop
     }
 }
+
 impl AggregateType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AggregateType::Average => "AVERAGE",
+            AggregateType::ChartAggregateTypeUnspecified => "CHART_AGGREGATE_TYPE_UNSPECIFIED",
+            AggregateType::Count => "COUNT",
+            AggregateType::Max => "MAX",
+            AggregateType::Median => "MEDIAN",
+            AggregateType::Min => "MIN",
+            AggregateType::Sum => "SUM",
+            AggregateType::Noop => "",
+            AggregateType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [AggregateType] {
+        &[
+            AggregateType::Average,
+            AggregateType::ChartAggregateTypeUnspecified,
+            AggregateType::Count,
+            AggregateType::Max,
+            AggregateType::Median,
+            AggregateType::Min,
+            AggregateType::Sum,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, AggregateType::Noop)
     }
@@ -2985,45 +3218,34 @@ impl AggregateType {
 
 /// The data included in a domain or series.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ChartData {
     /**
      * The data included in a domain or series.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "aggregateType"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub aggregate_type: Option<AggregateType>,
     /**
      * The data included in a domain or series.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "columnReference"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub column_reference: Option<DataSourceColumnReference>,
     /**
      * The data included in a domain or series.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "groupRule")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub group_rule: Option<ChartGroupRule>,
     /**
      * The data included in a domain or series.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "sourceRange"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub source_range: Option<ChartSourceRange>,
 }
 
 /**
  * The type of date-time grouping to apply.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ChartDateTimeRuleType {
     #[serde(rename = "CHART_DATE_TIME_RULE_TYPE_UNSPECIFIED")]
     ChartDateTimeRuleTypeUnspecified,
@@ -3096,7 +3318,56 @@ impl Default for ChartDateTimeRuleType {
         ChartDateTimeRuleType::Noop
     }
 }
+
 impl ChartDateTimeRuleType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChartDateTimeRuleType::ChartDateTimeRuleTypeUnspecified => {
+                "CHART_DATE_TIME_RULE_TYPE_UNSPECIFIED"
+            }
+            ChartDateTimeRuleType::DayMonth => "DAY_MONTH",
+            ChartDateTimeRuleType::DayOfMonth => "DAY_OF_MONTH",
+            ChartDateTimeRuleType::DayOfWeek => "DAY_OF_WEEK",
+            ChartDateTimeRuleType::DayOfYear => "DAY_OF_YEAR",
+            ChartDateTimeRuleType::Hour => "HOUR",
+            ChartDateTimeRuleType::HourMinute => "HOUR_MINUTE",
+            ChartDateTimeRuleType::HourMinuteAmpm => "HOUR_MINUTE_AMPM",
+            ChartDateTimeRuleType::Minute => "MINUTE",
+            ChartDateTimeRuleType::Month => "MONTH",
+            ChartDateTimeRuleType::Quarter => "QUARTER",
+            ChartDateTimeRuleType::Second => "SECOND",
+            ChartDateTimeRuleType::Year => "YEAR",
+            ChartDateTimeRuleType::YearMonth => "YEAR_MONTH",
+            ChartDateTimeRuleType::YearMonthDay => "YEAR_MONTH_DAY",
+            ChartDateTimeRuleType::YearQuarter => "YEAR_QUARTER",
+            ChartDateTimeRuleType::Noop => "",
+            ChartDateTimeRuleType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ChartDateTimeRuleType] {
+        &[
+            ChartDateTimeRuleType::ChartDateTimeRuleTypeUnspecified,
+            ChartDateTimeRuleType::DayMonth,
+            ChartDateTimeRuleType::DayOfMonth,
+            ChartDateTimeRuleType::DayOfWeek,
+            ChartDateTimeRuleType::DayOfYear,
+            ChartDateTimeRuleType::Hour,
+            ChartDateTimeRuleType::HourMinute,
+            ChartDateTimeRuleType::HourMinuteAmpm,
+            ChartDateTimeRuleType::Minute,
+            ChartDateTimeRuleType::Month,
+            ChartDateTimeRuleType::Quarter,
+            ChartDateTimeRuleType::Second,
+            ChartDateTimeRuleType::Year,
+            ChartDateTimeRuleType::YearMonth,
+            ChartDateTimeRuleType::YearMonthDay,
+            ChartDateTimeRuleType::YearQuarter,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ChartDateTimeRuleType::Noop)
     }
@@ -3114,29 +3385,23 @@ pub struct ChartDateTimeRule {
 
 /// An optional setting on the ChartData of the domain of a data source chart that defines buckets for the values in the domain rather than breaking out each individual value. For example, when plotting a data source chart, you can specify a histogram rule on the domain (it should only contain numeric values), grouping its values into buckets. Any values of a chart series that fall into the same bucket are aggregated based on the aggregate_type.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ChartGroupRule {
     /**
      * An optional setting on the ChartData of the domain of a data source chart that defines buckets for the values in the domain rather than breaking out each individual value. For example, when plotting a data source chart, you can specify a histogram rule on the domain (it should only contain numeric values), grouping its values into buckets. Any values of a chart series that fall into the same bucket are aggregated based on the aggregate_type.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "dateTimeRule"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub date_time_rule: Option<ChartDateTimeRule>,
     /**
      * An optional setting on the ChartData of the domain of a data source chart that defines buckets for the values in the domain rather than breaking out each individual value. For example, when plotting a data source chart, you can specify a histogram rule on the domain (it should only contain numeric values), grouping its values into buckets. Any values of a chart series that fall into the same bucket are aggregated based on the aggregate_type.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "histogramRule"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub histogram_rule: Option<ChartHistogramRule>,
 }
 
 /// Allows you to organize numeric values in a source data column into buckets of constant size.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ChartHistogramRule {
     /**
      * Allows you to organize numeric values in a source data column into buckets of constant size.
@@ -3144,8 +3409,7 @@ pub struct ChartHistogramRule {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "intervalSize"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub interval_size: f64,
     /**
@@ -3154,8 +3418,7 @@ pub struct ChartHistogramRule {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "maxValue"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub max_value: f64,
     /**
@@ -3164,8 +3427,7 @@ pub struct ChartHistogramRule {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "minValue"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub min_value: f64,
 }
@@ -3187,7 +3449,7 @@ pub struct ChartSourceRange {
 /**
  * Determines how the charts will use hidden rows or columns.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum HiddenDimensionStrategy {
     #[serde(rename = "CHART_HIDDEN_DIMENSION_STRATEGY_UNSPECIFIED")]
     ChartHiddenDimensionStrategyUnspecified,
@@ -3227,7 +3489,34 @@ impl Default for HiddenDimensionStrategy {
         HiddenDimensionStrategy::Noop
     }
 }
+
 impl HiddenDimensionStrategy {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HiddenDimensionStrategy::ChartHiddenDimensionStrategyUnspecified => {
+                "CHART_HIDDEN_DIMENSION_STRATEGY_UNSPECIFIED"
+            }
+            HiddenDimensionStrategy::ShowAll => "SHOW_ALL",
+            HiddenDimensionStrategy::SkipHiddenColumns => "SKIP_HIDDEN_COLUMNS",
+            HiddenDimensionStrategy::SkipHiddenRows => "SKIP_HIDDEN_ROWS",
+            HiddenDimensionStrategy::SkipHiddenRowsAndColumns => "SKIP_HIDDEN_ROWS_AND_COLUMNS",
+            HiddenDimensionStrategy::Noop => "",
+            HiddenDimensionStrategy::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [HiddenDimensionStrategy] {
+        &[
+            HiddenDimensionStrategy::ChartHiddenDimensionStrategyUnspecified,
+            HiddenDimensionStrategy::ShowAll,
+            HiddenDimensionStrategy::SkipHiddenColumns,
+            HiddenDimensionStrategy::SkipHiddenRows,
+            HiddenDimensionStrategy::SkipHiddenRowsAndColumns,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, HiddenDimensionStrategy::Noop)
     }
@@ -3235,6 +3524,7 @@ impl HiddenDimensionStrategy {
 
 /// The specifications of a chart.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ChartSpec {
     /**
      * The specifications of a chart.
@@ -3242,63 +3532,38 @@ pub struct ChartSpec {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "altText"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub alt_text: String,
     /**
      * The specifications of a chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "backgroundColor"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub background_color: Option<Color>,
     /**
      * The specifications of a chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "backgroundColorStyle"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub background_color_style: Option<ColorStyle>,
     /**
      * The specifications of a chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "basicChart"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub basic_chart: Option<BasicChartSpec>,
     /**
      * The specifications of a chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "bubbleChart"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub bubble_chart: Option<BubbleChartSpec>,
     /**
      * The specifications of a chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "candlestickChart"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub candlestick_chart: Option<CandlestickChartSpec>,
     /**
      * The specifications of a chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "dataSourceChartProperties"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data_source_chart_properties: Option<DataSourceChartProperties>,
     /**
      * The specifications of a chart.
@@ -3306,8 +3571,7 @@ pub struct ChartSpec {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "filterSpecs"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub filter_specs: Vec<FilterSpec>,
     /**
@@ -3316,27 +3580,18 @@ pub struct ChartSpec {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "fontName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub font_name: String,
     /**
      * The specifications of a chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "hiddenDimensionStrategy"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub hidden_dimension_strategy: Option<HiddenDimensionStrategy>,
     /**
      * The specifications of a chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "histogramChart"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub histogram_chart: Option<HistogramChartSpec>,
     /**
      * The specifications of a chart.
@@ -3349,21 +3604,17 @@ pub struct ChartSpec {
     /**
      * The specifications of a chart.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "orgChart")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub org_chart: Option<OrgChartSpec>,
     /**
      * The specifications of a chart.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "pieChart")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub pie_chart: Option<PieChartSpec>,
     /**
      * The specifications of a chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "scorecardChart"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub scorecard_chart: Option<ScorecardChartSpec>,
     /**
      * The specifications of a chart.
@@ -3371,8 +3622,7 @@ pub struct ChartSpec {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "sortSpecs"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub sort_specs: Vec<SortSpec>,
     /**
@@ -3387,20 +3637,12 @@ pub struct ChartSpec {
     /**
      * The specifications of a chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "subtitleTextFormat"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub subtitle_text_format: Option<TextFormat>,
     /**
      * The specifications of a chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "subtitleTextPosition"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub subtitle_text_position: Option<TextPosition>,
     /**
      * The specifications of a chart.
@@ -3414,43 +3656,28 @@ pub struct ChartSpec {
     /**
      * The specifications of a chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "titleTextFormat"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub title_text_format: Option<TextFormat>,
     /**
      * The specifications of a chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "titleTextPosition"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub title_text_position: Option<TextPosition>,
     /**
      * The specifications of a chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "treemapChart"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub treemap_chart: Option<TreemapChartSpec>,
     /**
      * The specifications of a chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "waterfallChart"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub waterfall_chart: Option<WaterfallChartSpec>,
 }
 
 /// Clears the basic filter, if any exists on the sheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ClearBasicFilterRequest {
     /**
      * Clears the basic filter, if any exists on the sheet.
@@ -3458,8 +3685,7 @@ pub struct ClearBasicFilterRequest {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "sheetId"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub sheet_id: i64,
 }
@@ -3470,6 +3696,7 @@ pub struct ClearValuesRequest {}
 
 /// The response when clearing a range of values in a spreadsheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ClearValuesResponse {
     /**
      * The response when clearing a range of values in a spreadsheet.
@@ -3477,8 +3704,7 @@ pub struct ClearValuesResponse {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "clearedRange"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub cleared_range: String,
     /**
@@ -3487,8 +3713,7 @@ pub struct ClearValuesResponse {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "spreadsheetId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub spreadsheet_id: String,
 }
@@ -3537,7 +3762,7 @@ pub struct Color {
 /**
  * The type of the spreadsheet theme color.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ColorType {
     #[serde(rename = "ACCENT1")]
     Accent1,
@@ -3590,7 +3815,42 @@ impl Default for ColorType {
         ColorType::Noop
     }
 }
+
 impl ColorType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ColorType::Accent1 => "ACCENT1",
+            ColorType::Accent2 => "ACCENT2",
+            ColorType::Accent3 => "ACCENT3",
+            ColorType::Accent4 => "ACCENT4",
+            ColorType::Accent5 => "ACCENT5",
+            ColorType::Accent6 => "ACCENT6",
+            ColorType::Background => "BACKGROUND",
+            ColorType::Link => "LINK",
+            ColorType::Text => "TEXT",
+            ColorType::ThemeColorTypeUnspecified => "THEME_COLOR_TYPE_UNSPECIFIED",
+            ColorType::Noop => "",
+            ColorType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ColorType] {
+        &[
+            ColorType::Accent1,
+            ColorType::Accent2,
+            ColorType::Accent3,
+            ColorType::Accent4,
+            ColorType::Accent5,
+            ColorType::Accent6,
+            ColorType::Background,
+            ColorType::Link,
+            ColorType::Text,
+            ColorType::ThemeColorTypeUnspecified,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ColorType::Noop)
     }
@@ -3598,27 +3858,24 @@ impl ColorType {
 
 /// A color value.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ColorStyle {
     /**
      * A color value.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "rgbColor")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub rgb_color: Option<Color>,
     /**
      * A color value.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "themeColor"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub theme_color: Option<ColorType>,
 }
 
 /**
  * A relative date (based on the current date). Valid only if the type is DATE_BEFORE, DATE_AFTER, DATE_ON_OR_BEFORE or DATE_ON_OR_AFTER. Relative dates are not supported in data validation. They are supported only in conditional formatting and conditional filters.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum RelativeDate {
     #[serde(rename = "PAST_MONTH")]
     PastMonth,
@@ -3662,7 +3919,36 @@ impl Default for RelativeDate {
         RelativeDate::Noop
     }
 }
+
 impl RelativeDate {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RelativeDate::PastMonth => "PAST_MONTH",
+            RelativeDate::PastWeek => "PAST_WEEK",
+            RelativeDate::PastYear => "PAST_YEAR",
+            RelativeDate::RelativeDateUnspecified => "RELATIVE_DATE_UNSPECIFIED",
+            RelativeDate::Today => "TODAY",
+            RelativeDate::Tomorrow => "TOMORROW",
+            RelativeDate::Yesterday => "YESTERDAY",
+            RelativeDate::Noop => "",
+            RelativeDate::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [RelativeDate] {
+        &[
+            RelativeDate::PastMonth,
+            RelativeDate::PastWeek,
+            RelativeDate::PastYear,
+            RelativeDate::RelativeDateUnspecified,
+            RelativeDate::Today,
+            RelativeDate::Tomorrow,
+            RelativeDate::Yesterday,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, RelativeDate::Noop)
     }
@@ -3670,15 +3956,12 @@ impl RelativeDate {
 
 /// The value of the condition.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ConditionValue {
     /**
      * The value of the condition.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "relativeDate"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub relative_date: Option<RelativeDate>,
     /**
      * The value of the condition.
@@ -3686,32 +3969,24 @@ pub struct ConditionValue {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "userEnteredValue"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub user_entered_value: String,
 }
 
 /// A rule describing a conditional format.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ConditionalFormatRule {
     /**
      * A rule describing a conditional format.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "booleanRule"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub boolean_rule: Option<BooleanRule>,
     /**
      * A rule describing a conditional format.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "gradientRule"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub gradient_rule: Option<GradientRule>,
     /**
      * A rule describing a conditional format.
@@ -3727,7 +4002,7 @@ pub struct ConditionalFormatRule {
 /**
  * How that data should be oriented when pasting.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PasteOrientation {
     #[serde(rename = "NORMAL")]
     Normal,
@@ -3756,16 +4031,32 @@ impl Default for PasteOrientation {
         PasteOrientation::Noop
     }
 }
-impl PasteOrientation {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, PasteOrientation::Noop)
-    }
-}
 
-/**
- * What kind of data to paste.
+impl PasteOrientation {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PasteOrientation::Normal => "NORMAL",
+            PasteOrientation::Transpose => "TRANSPOSE",
+            PasteOrientation::Noop => "",
+            PasteOrientation::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PasteOrientation] {
+        &[PasteOrientation::Normal, PasteOrientation::Transpose]
+    }
+
+    pub fn is_noop(&self) -> bool {
+        matches!(self, PasteOrientation::Noop)
+    }
+}
+
+/**
+ * What kind of data to paste.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PasteType {
     #[serde(rename = "PASTE_CONDITIONAL_FORMATTING")]
     PasteConditionalFormatting,
@@ -3809,7 +4100,36 @@ impl Default for PasteType {
         PasteType::Noop
     }
 }
+
 impl PasteType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PasteType::PasteConditionalFormatting => "PASTE_CONDITIONAL_FORMATTING",
+            PasteType::PasteDataValidation => "PASTE_DATA_VALIDATION",
+            PasteType::PasteFormat => "PASTE_FORMAT",
+            PasteType::PasteFormula => "PASTE_FORMULA",
+            PasteType::PasteNormal => "PASTE_NORMAL",
+            PasteType::PasteNoBorders => "PASTE_NO_BORDERS",
+            PasteType::PasteValues => "PASTE_VALUES",
+            PasteType::Noop => "",
+            PasteType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PasteType] {
+        &[
+            PasteType::PasteConditionalFormatting,
+            PasteType::PasteDataValidation,
+            PasteType::PasteFormat,
+            PasteType::PasteFormula,
+            PasteType::PasteNormal,
+            PasteType::PasteNoBorders,
+            PasteType::PasteValues,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PasteType::Noop)
     }
@@ -3817,6 +4137,7 @@ impl PasteType {
 
 /// Copies data from the source to the destination.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct CopyPasteRequest {
     /**
      * Copies data from the source to the destination.
@@ -3826,16 +4147,12 @@ pub struct CopyPasteRequest {
     /**
      * Copies data from the source to the destination.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "pasteOrientation"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub paste_orientation: Option<PasteOrientation>,
     /**
      * Copies data from the source to the destination.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "pasteType")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub paste_type: Option<PasteType>,
     /**
      * Copies data from the source to the destination.
@@ -3846,6 +4163,7 @@ pub struct CopyPasteRequest {
 
 /// The request to copy a sheet across spreadsheets.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct CopySheetAnotherSpreadsheetRequest {
     /**
      * The request to copy a sheet across spreadsheets.
@@ -3853,42 +4171,36 @@ pub struct CopySheetAnotherSpreadsheetRequest {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "destinationSpreadsheetId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub destination_spreadsheet_id: String,
 }
 
 /// A request to create developer metadata.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct CreateDeveloperMetadataRequest {
     /**
      * A request to create developer metadata.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "developerMetadata"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub developer_metadata: Option<DeveloperMetadata>,
 }
 
 /// The response from creating developer metadata.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct CreateDeveloperMetadataResponse {
     /**
      * The response from creating developer metadata.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "developerMetadata"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub developer_metadata: Option<DeveloperMetadata>,
 }
 
 /// Moves data from the source to the destination.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct CutPasteRequest {
     /**
      * Moves data from the source to the destination.
@@ -3898,7 +4210,7 @@ pub struct CutPasteRequest {
     /**
      * Moves data from the source to the destination.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "pasteType")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub paste_type: Option<PasteType>,
     /**
      * Moves data from the source to the destination.
@@ -3910,7 +4222,7 @@ pub struct CutPasteRequest {
 /**
  * The error code.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ErrorCode {
     #[serde(rename = "CONCURRENT_QUERY")]
     ConcurrentQuery,
@@ -3987,7 +4299,58 @@ impl Default for ErrorCode {
         ErrorCode::Noop
     }
 }
+
 impl ErrorCode {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorCode::ConcurrentQuery => "CONCURRENT_QUERY",
+            ErrorCode::DataExecutionErrorCodeUnspecified => "DATA_EXECUTION_ERROR_CODE_UNSPECIFIED",
+            ErrorCode::DataNotFound => "DATA_NOT_FOUND",
+            ErrorCode::DuplicateColumnNames => "DUPLICATE_COLUMN_NAMES",
+            ErrorCode::Engine => "ENGINE",
+            ErrorCode::Interrupted => "INTERRUPTED",
+            ErrorCode::MissingColumnAlias => "MISSING_COLUMN_ALIAS",
+            ErrorCode::ObjectInErrorState => "OBJECT_IN_ERROR_STATE",
+            ErrorCode::ObjectNotFound => "OBJECT_NOT_FOUND",
+            ErrorCode::ObjectSpecInvalid => "OBJECT_SPEC_INVALID",
+            ErrorCode::Other => "OTHER",
+            ErrorCode::ParameterInvalid => "PARAMETER_INVALID",
+            ErrorCode::PermissionDenied => "PERMISSION_DENIED",
+            ErrorCode::TimedOut => "TIMED_OUT",
+            ErrorCode::TooManyCells => "TOO_MANY_CELLS",
+            ErrorCode::TooManyCharsPerCell => "TOO_MANY_CHARS_PER_CELL",
+            ErrorCode::TooManyRows => "TOO_MANY_ROWS",
+            ErrorCode::UnsupportedDataType => "UNSUPPORTED_DATA_TYPE",
+            ErrorCode::Noop => "",
+            ErrorCode::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ErrorCode] {
+        &[
+            ErrorCode::ConcurrentQuery,
+            ErrorCode::DataExecutionErrorCodeUnspecified,
+            ErrorCode::DataNotFound,
+            ErrorCode::DuplicateColumnNames,
+            ErrorCode::Engine,
+            ErrorCode::Interrupted,
+            ErrorCode::MissingColumnAlias,
+            ErrorCode::ObjectInErrorState,
+            ErrorCode::ObjectNotFound,
+            ErrorCode::ObjectSpecInvalid,
+            ErrorCode::Other,
+            ErrorCode::ParameterInvalid,
+            ErrorCode::PermissionDenied,
+            ErrorCode::TimedOut,
+            ErrorCode::TooManyCells,
+            ErrorCode::TooManyCharsPerCell,
+            ErrorCode::TooManyRows,
+            ErrorCode::UnsupportedDataType,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ErrorCode::Noop)
     }
@@ -3996,7 +4359,7 @@ impl ErrorCode {
 /**
  * The state of the data execution.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum State {
     #[serde(rename = "DATA_EXECUTION_STATE_UNSPECIFIED")]
     DataExecutionStateUnspecified,
@@ -4034,7 +4397,32 @@ impl Default for State {
         State::Noop
     }
 }
+
 impl State {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            State::DataExecutionStateUnspecified => "DATA_EXECUTION_STATE_UNSPECIFIED",
+            State::Failed => "FAILED",
+            State::NotStarted => "NOT_STARTED",
+            State::Running => "RUNNING",
+            State::Succeeded => "SUCCEEDED",
+            State::Noop => "",
+            State::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [State] {
+        &[
+            State::DataExecutionStateUnspecified,
+            State::Failed,
+            State::NotStarted,
+            State::Running,
+            State::Succeeded,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, State::Noop)
     }
@@ -4042,11 +4430,12 @@ impl State {
 
 /// The data execution status. A data execution is created to sync a data source object with the latest data from a DataSource. It is usually scheduled to run at background, you can check its state to tell if an execution completes There are several scenarios where a data execution is triggered to run: * Adding a data source creates an associated data source sheet as well as a data execution to sync the data from the data source to the sheet. * Updating a data source creates a data execution to refresh the associated data source sheet similarly. * You can send refresh request to explicitly refresh one or multiple data source objects.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DataExecutionStatus {
     /**
      * The data execution status. A data execution is created to sync a data source object with the latest data from a DataSource. It is usually scheduled to run at background, you can check its state to tell if an execution completes There are several scenarios where a data execution is triggered to run: \* Adding a data source creates an associated data source sheet as well as a data execution to sync the data from the data source to the sheet. \* Updating a data source creates a data execution to refresh the associated data source sheet similarly. \* You can send refresh request to explicitly refresh one or multiple data source objects.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "errorCode")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub error_code: Option<ErrorCode>,
     /**
      * The data execution status. A data execution is created to sync a data source object with the latest data from a DataSource. It is usually scheduled to run at background, you can check its state to tell if an execution completes There are several scenarios where a data execution is triggered to run: \* Adding a data source creates an associated data source sheet as well as a data execution to sync the data from the data source to the sheet. \* Updating a data source creates a data execution to refresh the associated data source sheet similarly. \* You can send refresh request to explicitly refresh one or multiple data source objects.
@@ -4054,8 +4443,7 @@ pub struct DataExecutionStatus {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "errorMessage"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub error_message: String,
     /**
@@ -4064,8 +4452,7 @@ pub struct DataExecutionStatus {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastRefreshTime"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub last_refresh_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
@@ -4077,6 +4464,7 @@ pub struct DataExecutionStatus {
 
 /// Filter that describes what data should be selected or returned from a request.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DataFilter {
     /**
      * Filter that describes what data should be selected or returned from a request.
@@ -4084,46 +4472,34 @@ pub struct DataFilter {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "a1Range"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub a_1_range: String,
     /**
      * Filter that describes what data should be selected or returned from a request.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "developerMetadataLookup"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub developer_metadata_lookup: Option<DeveloperMetadataLookup>,
     /**
      * Filter that describes what data should be selected or returned from a request.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "gridRange")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub grid_range: Option<GridRange>,
 }
 
 /// A range of values whose location is specified by a DataFilter.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DataFilterValueRange {
     /**
      * A range of values whose location is specified by a DataFilter.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "dataFilter"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data_filter: Option<DataFilter>,
     /**
      * A range of values whose location is specified by a DataFilter.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "majorDimension"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub major_dimension: Option<Dimension>,
     /**
      * A range of values whose location is specified by a DataFilter.
@@ -4139,7 +4515,7 @@ pub struct DataFilterValueRange {
 /**
  * The placement of the data label relative to the labeled data.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Placement {
     #[serde(rename = "ABOVE")]
     Above,
@@ -4189,7 +4565,40 @@ impl Default for Placement {
         Placement::Noop
     }
 }
+
 impl Placement {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Placement::Above => "ABOVE",
+            Placement::Below => "BELOW",
+            Placement::Center => "CENTER",
+            Placement::DataLabelPlacementUnspecified => "DATA_LABEL_PLACEMENT_UNSPECIFIED",
+            Placement::InsideBase => "INSIDE_BASE",
+            Placement::InsideEnd => "INSIDE_END",
+            Placement::Left => "LEFT",
+            Placement::OutsideEnd => "OUTSIDE_END",
+            Placement::Right => "RIGHT",
+            Placement::Noop => "",
+            Placement::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Placement] {
+        &[
+            Placement::Above,
+            Placement::Below,
+            Placement::Center,
+            Placement::DataLabelPlacementUnspecified,
+            Placement::InsideBase,
+            Placement::InsideEnd,
+            Placement::Left,
+            Placement::OutsideEnd,
+            Placement::Right,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Placement::Noop)
     }
@@ -4198,7 +4607,7 @@ impl Placement {
 /**
  * The type of the data label.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum DataLabelType {
     #[serde(rename = "CUSTOM")]
     Custom,
@@ -4233,7 +4642,30 @@ impl Default for DataLabelType {
         DataLabelType::Noop
     }
 }
+
 impl DataLabelType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DataLabelType::Custom => "CUSTOM",
+            DataLabelType::Data => "DATA",
+            DataLabelType::DataLabelTypeUnspecified => "DATA_LABEL_TYPE_UNSPECIFIED",
+            DataLabelType::None => "NONE",
+            DataLabelType::Noop => "",
+            DataLabelType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [DataLabelType] {
+        &[
+            DataLabelType::Custom,
+            DataLabelType::Data,
+            DataLabelType::DataLabelTypeUnspecified,
+            DataLabelType::None,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, DataLabelType::Noop)
     }
@@ -4241,15 +4673,12 @@ impl DataLabelType {
 
 /// Settings for one set of data labels. Data labels are annotations that appear next to a set of data, such as the points on a line chart, and provide additional information about what the data represents, such as a text representation of the value behind that point on the graph.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DataLabel {
     /**
      * Settings for one set of data labels. Data labels are annotations that appear next to a set of data, such as the points on a line chart, and provide additional information about what the data represents, such as a text representation of the value behind that point on the graph.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "customLabelData"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub custom_label_data: Option<ChartData>,
     /**
      * Settings for one set of data labels. Data labels are annotations that appear next to a set of data, such as the points on a line chart, and provide additional information about what the data represents, such as a text representation of the value behind that point on the graph.
@@ -4259,11 +4688,7 @@ pub struct DataLabel {
     /**
      * Settings for one set of data labels. Data labels are annotations that appear next to a set of data, such as the points on a line chart, and provide additional information about what the data represents, such as a text representation of the value behind that point on the graph.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "textFormat"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub text_format: Option<TextFormat>,
     /**
      * Settings for one set of data labels. Data labels are annotations that appear next to a set of data, such as the points on a line chart, and provide additional information about what the data represents, such as a text representation of the value behind that point on the graph.
@@ -4274,6 +4699,7 @@ pub struct DataLabel {
 
 /// Information about an external data source in the spreadsheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DataSource {
     /**
      * Information about an external data source in the spreadsheet.
@@ -4281,8 +4707,7 @@ pub struct DataSource {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "calculatedColumns"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub calculated_columns: Vec<DataSourceColumn>,
     /**
@@ -4291,8 +4716,7 @@ pub struct DataSource {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "dataSourceId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub data_source_id: String,
     /**
@@ -4301,8 +4725,7 @@ pub struct DataSource {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "sheetId"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub sheet_id: i64,
     /**
@@ -4314,15 +4737,12 @@ pub struct DataSource {
 
 /// Properties of a data source chart.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DataSourceChartProperties {
     /**
      * Properties of a data source chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "dataExecutionStatus"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data_execution_status: Option<DataExecutionStatus>,
     /**
      * Properties of a data source chart.
@@ -4330,8 +4750,7 @@ pub struct DataSourceChartProperties {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "dataSourceId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub data_source_id: String,
 }
@@ -4371,15 +4790,12 @@ pub struct DataSourceColumnReference {
 
 /// A data source formula.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DataSourceFormula {
     /**
      * A data source formula.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "dataExecutionStatus"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data_execution_status: Option<DataExecutionStatus>,
     /**
      * A data source formula.
@@ -4387,14 +4803,14 @@ pub struct DataSourceFormula {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "dataSourceId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub data_source_id: String,
 }
 
 /// Reference to a data source object.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DataSourceObjectReference {
     /**
      * Reference to a data source object.
@@ -4402,36 +4818,23 @@ pub struct DataSourceObjectReference {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "chartId"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub chart_id: i64,
     /**
      * Reference to a data source object.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "dataSourceFormulaCell"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data_source_formula_cell: Option<GridCoordinate>,
     /**
      * Reference to a data source object.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "dataSourcePivotTableAnchorCell"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data_source_pivot_table_anchor_cell: Option<GridCoordinate>,
     /**
      * Reference to a data source object.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "dataSourceTableAnchorCell"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data_source_table_anchor_cell: Option<GridCoordinate>,
     /**
      * Reference to a data source object.
@@ -4439,8 +4842,7 @@ pub struct DataSourceObjectReference {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "sheetId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub sheet_id: String,
 }
@@ -4461,6 +4863,7 @@ pub struct DataSourceObjectReferences {
 
 /// A parameter in a data source's query. The parameter allows the user to pass in values from the spreadsheet into a query.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DataSourceParameter {
     /**
      * A parameter in a data source's query. The parameter allows the user to pass in values from the spreadsheet into a query.
@@ -4477,8 +4880,7 @@ pub struct DataSourceParameter {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "namedRangeId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub named_range_id: String,
     /**
@@ -4490,16 +4892,18 @@ pub struct DataSourceParameter {
 
 /// A schedule for data to refresh every day in a given time interval.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DataSourceRefreshDailySchedule {
     /**
      * A schedule for data to refresh every day in a given time interval.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "startTime")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub start_time: Option<TimeOfDay>,
 }
 
 /// A monthly schedule for data to refresh on specific days in the month in a given time interval.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DataSourceRefreshMonthlySchedule {
     /**
      * A monthly schedule for data to refresh on specific days in the month in a given time interval.
@@ -4507,21 +4911,20 @@ pub struct DataSourceRefreshMonthlySchedule {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "daysOfMonth"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub days_of_month: Vec<i64>,
     /**
      * A monthly schedule for data to refresh on specific days in the month in a given time interval.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "startTime")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub start_time: Option<TimeOfDay>,
 }
 
 /**
  * The scope of the refresh. Must be ALL_DATA_SOURCES.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum RefreshScope {
     #[serde(rename = "ALL_DATA_SOURCES")]
     AllDataSources,
@@ -4552,7 +4955,28 @@ impl Default for RefreshScope {
         RefreshScope::Noop
     }
 }
+
 impl RefreshScope {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RefreshScope::AllDataSources => "ALL_DATA_SOURCES",
+            RefreshScope::DataSourceRefreshScopeUnspecified => {
+                "DATA_SOURCE_REFRESH_SCOPE_UNSPECIFIED"
+            }
+            RefreshScope::Noop => "",
+            RefreshScope::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [RefreshScope] {
+        &[
+            RefreshScope::AllDataSources,
+            RefreshScope::DataSourceRefreshScopeUnspecified,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, RefreshScope::Noop)
     }
@@ -4560,15 +4984,12 @@ impl RefreshScope {
 
 /// Schedule for refreshing the data source. Data sources in the spreadsheet are refreshed within a time interval. You can specify the start time by clicking the Scheduled Refresh button in the Sheets editor, but the interval is fixed at 4 hours. For example, if you specify a start time of 8am , the refresh will take place between 8am and 12pm every day.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DataSourceRefreshSchedule {
     /**
      * Schedule for refreshing the data source. Data sources in the spreadsheet are refreshed within a time interval. You can specify the start time by clicking the Scheduled Refresh button in the Sheets editor, but the interval is fixed at 4 hours. For example, if you specify a start time of 8am , the refresh will take place between 8am and 12pm every day.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "dailySchedule"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub daily_schedule: Option<DataSourceRefreshDailySchedule>,
     /**
      * Schedule for refreshing the data source. Data sources in the spreadsheet are refreshed within a time interval. You can specify the start time by clicking the Scheduled Refresh button in the Sheets editor, but the interval is fixed at 4 hours. For example, if you specify a start time of 8am , the refresh will take place between 8am and 12pm every day.
@@ -4581,38 +5002,26 @@ pub struct DataSourceRefreshSchedule {
     /**
      * Schedule for refreshing the data source. Data sources in the spreadsheet are refreshed within a time interval. You can specify the start time by clicking the Scheduled Refresh button in the Sheets editor, but the interval is fixed at 4 hours. For example, if you specify a start time of 8am , the refresh will take place between 8am and 12pm every day.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "monthlySchedule"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub monthly_schedule: Option<DataSourceRefreshMonthlySchedule>,
     /**
      * Schedule for refreshing the data source. Data sources in the spreadsheet are refreshed within a time interval. You can specify the start time by clicking the Scheduled Refresh button in the Sheets editor, but the interval is fixed at 4 hours. For example, if you specify a start time of 8am , the refresh will take place between 8am and 12pm every day.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "nextRun")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub next_run: Option<Interval>,
     /**
      * Schedule for refreshing the data source. Data sources in the spreadsheet are refreshed within a time interval. You can specify the start time by clicking the Scheduled Refresh button in the Sheets editor, but the interval is fixed at 4 hours. For example, if you specify a start time of 8am , the refresh will take place between 8am and 12pm every day.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "refreshScope"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub refresh_scope: Option<RefreshScope>,
     /**
      * Schedule for refreshing the data source. Data sources in the spreadsheet are refreshed within a time interval. You can specify the start time by clicking the Scheduled Refresh button in the Sheets editor, but the interval is fixed at 4 hours. For example, if you specify a start time of 8am , the refresh will take place between 8am and 12pm every day.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "weeklySchedule"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub weekly_schedule: Option<DataSourceRefreshWeeklySchedule>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum DaysOfWeek {
     #[serde(rename = "DAY_OF_WEEK_UNSPECIFIED")]
     DayOfWeekUnspecified,
@@ -4659,7 +5068,38 @@ impl Default for DaysOfWeek {
         DaysOfWeek::Noop
     }
 }
+
 impl DaysOfWeek {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DaysOfWeek::DayOfWeekUnspecified => "DAY_OF_WEEK_UNSPECIFIED",
+            DaysOfWeek::Friday => "FRIDAY",
+            DaysOfWeek::Monday => "MONDAY",
+            DaysOfWeek::Saturday => "SATURDAY",
+            DaysOfWeek::Sunday => "SUNDAY",
+            DaysOfWeek::Thursday => "THURSDAY",
+            DaysOfWeek::Tuesday => "TUESDAY",
+            DaysOfWeek::Wednesday => "WEDNESDAY",
+            DaysOfWeek::Noop => "",
+            DaysOfWeek::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [DaysOfWeek] {
+        &[
+            DaysOfWeek::DayOfWeekUnspecified,
+            DaysOfWeek::Friday,
+            DaysOfWeek::Monday,
+            DaysOfWeek::Saturday,
+            DaysOfWeek::Sunday,
+            DaysOfWeek::Thursday,
+            DaysOfWeek::Tuesday,
+            DaysOfWeek::Wednesday,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, DaysOfWeek::Noop)
     }
@@ -4667,6 +5107,7 @@ impl DaysOfWeek {
 
 /// A weekly schedule for data to refresh on specific days in a given time interval.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DataSourceRefreshWeeklySchedule {
     /**
      * A weekly schedule for data to refresh on specific days in a given time interval.
@@ -4674,19 +5115,19 @@ pub struct DataSourceRefreshWeeklySchedule {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "daysOfWeek"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub days_of_week: Vec<DaysOfWeek>,
     /**
      * A weekly schedule for data to refresh on specific days in a given time interval.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "startTime")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub start_time: Option<TimeOfDay>,
 }
 
 /// A range along a single dimension on a DATA_SOURCE sheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DataSourceSheetDimensionRange {
     /**
      * A range along a single dimension on a DATA_SOURCE sheet.
@@ -4694,8 +5135,7 @@ pub struct DataSourceSheetDimensionRange {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "columnReferences"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub column_references: Vec<DataSourceColumnReference>,
     /**
@@ -4704,14 +5144,14 @@ pub struct DataSourceSheetDimensionRange {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "sheetId"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub sheet_id: i64,
 }
 
 /// Additional properties of a DATA_SOURCE sheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DataSourceSheetProperties {
     /**
      * Additional properties of a DATA_SOURCE sheet.
@@ -4725,11 +5165,7 @@ pub struct DataSourceSheetProperties {
     /**
      * Additional properties of a DATA_SOURCE sheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "dataExecutionStatus"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data_execution_status: Option<DataExecutionStatus>,
     /**
      * Additional properties of a DATA_SOURCE sheet.
@@ -4737,19 +5173,19 @@ pub struct DataSourceSheetProperties {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "dataSourceId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub data_source_id: String,
 }
 
 /// This specifies the details of the data source. For example, for BigQuery, this specifies information about the BigQuery source.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DataSourceSpec {
     /**
      * This specifies the details of the data source. For example, for BigQuery, this specifies information about the BigQuery source.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "bigQuery")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub big_query: Option<BigQueryDataSourceSpec>,
     /**
      * This specifies the details of the data source. For example, for BigQuery, this specifies information about the BigQuery source.
@@ -4765,7 +5201,7 @@ pub struct DataSourceSpec {
 /**
  * The type to select columns for the data source table. Defaults to SELECTED.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ColumnSelectionType {
     #[serde(rename = "DATA_SOURCE_TABLE_COLUMN_SELECTION_TYPE_UNSPECIFIED")]
     DataSourceTableColumnSelectionTypeUnspecified,
@@ -4799,23 +5235,43 @@ impl Default for ColumnSelectionType {
         ColumnSelectionType::Noop
     }
 }
-impl ColumnSelectionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ColumnSelectionType::Noop)
-    }
-}
+
+impl ColumnSelectionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ColumnSelectionType::DataSourceTableColumnSelectionTypeUnspecified => {
+                "DATA_SOURCE_TABLE_COLUMN_SELECTION_TYPE_UNSPECIFIED"
+            }
+            ColumnSelectionType::Selected => "SELECTED",
+            ColumnSelectionType::SyncAll => "SYNC_ALL",
+            ColumnSelectionType::Noop => "",
+            ColumnSelectionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ColumnSelectionType] {
+        &[
+            ColumnSelectionType::DataSourceTableColumnSelectionTypeUnspecified,
+            ColumnSelectionType::Selected,
+            ColumnSelectionType::SyncAll,
+        ]
+    }
+
+    pub fn is_noop(&self) -> bool {
+        matches!(self, ColumnSelectionType::Noop)
+    }
+}
 
 /// A data source table, which allows the user to import a static table of data from the DataSource into Sheets. This is also known as "Extract" in the Sheets editor.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DataSourceTable {
     /**
      * A data source table, which allows the user to import a static table of data from the DataSource into Sheets. This is also known as "Extract" in the Sheets editor.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "columnSelectionType"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub column_selection_type: Option<ColumnSelectionType>,
     /**
      * A data source table, which allows the user to import a static table of data from the DataSource into Sheets. This is also known as "Extract" in the Sheets editor.
@@ -4829,11 +5285,7 @@ pub struct DataSourceTable {
     /**
      * A data source table, which allows the user to import a static table of data from the DataSource into Sheets. This is also known as "Extract" in the Sheets editor.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "dataExecutionStatus"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data_execution_status: Option<DataExecutionStatus>,
     /**
      * A data source table, which allows the user to import a static table of data from the DataSource into Sheets. This is also known as "Extract" in the Sheets editor.
@@ -4841,8 +5293,7 @@ pub struct DataSourceTable {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "dataSourceId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub data_source_id: String,
     /**
@@ -4851,8 +5302,7 @@ pub struct DataSourceTable {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "filterSpecs"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub filter_specs: Vec<FilterSpec>,
     /**
@@ -4861,8 +5311,7 @@ pub struct DataSourceTable {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "rowLimit"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub row_limit: i64,
     /**
@@ -4871,14 +5320,14 @@ pub struct DataSourceTable {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "sortSpecs"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub sort_specs: Vec<SortSpec>,
 }
 
 /// A data validation rule.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DataValidationRule {
     /**
      * A data validation rule.
@@ -4891,8 +5340,7 @@ pub struct DataValidationRule {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "inputMessage"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub input_message: String,
     /**
@@ -4900,8 +5348,7 @@ pub struct DataValidationRule {
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "showCustomUi"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub show_custom_ui: bool,
     /**
@@ -4917,7 +5364,7 @@ pub struct DataValidationRule {
 /**
  * The type of date-time grouping to apply.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum DateTimeRuleType {
     #[serde(rename = "DATE_TIME_RULE_TYPE_UNSPECIFIED")]
     DateTimeRuleTypeUnspecified,
@@ -4988,7 +5435,54 @@ impl Default for DateTimeRuleType {
         DateTimeRuleType::Noop
     }
 }
+
 impl DateTimeRuleType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DateTimeRuleType::DateTimeRuleTypeUnspecified => "DATE_TIME_RULE_TYPE_UNSPECIFIED",
+            DateTimeRuleType::DayMonth => "DAY_MONTH",
+            DateTimeRuleType::DayOfMonth => "DAY_OF_MONTH",
+            DateTimeRuleType::DayOfWeek => "DAY_OF_WEEK",
+            DateTimeRuleType::DayOfYear => "DAY_OF_YEAR",
+            DateTimeRuleType::Hour => "HOUR",
+            DateTimeRuleType::HourMinute => "HOUR_MINUTE",
+            DateTimeRuleType::HourMinuteAmpm => "HOUR_MINUTE_AMPM",
+            DateTimeRuleType::Minute => "MINUTE",
+            DateTimeRuleType::Month => "MONTH",
+            DateTimeRuleType::Quarter => "QUARTER",
+            DateTimeRuleType::Second => "SECOND",
+            DateTimeRuleType::Year => "YEAR",
+            DateTimeRuleType::YearMonth => "YEAR_MONTH",
+            DateTimeRuleType::YearMonthDay => "YEAR_MONTH_DAY",
+            DateTimeRuleType::YearQuarter => "YEAR_QUARTER",
+            DateTimeRuleType::Noop => "",
+            DateTimeRuleType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [DateTimeRuleType] {
+        &[
+            DateTimeRuleType::DateTimeRuleTypeUnspecified,
+            DateTimeRuleType::DayMonth,
+            DateTimeRuleType::DayOfMonth,
+            DateTimeRuleType::DayOfWeek,
+            DateTimeRuleType::DayOfYear,
+            DateTimeRuleType::Hour,
+            DateTimeRuleType::HourMinute,
+            DateTimeRuleType::HourMinuteAmpm,
+            DateTimeRuleType::Minute,
+            DateTimeRuleType::Month,
+            DateTimeRuleType::Quarter,
+            DateTimeRuleType::Second,
+            DateTimeRuleType::Year,
+            DateTimeRuleType::YearMonth,
+            DateTimeRuleType::YearMonthDay,
+            DateTimeRuleType::YearQuarter,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, DateTimeRuleType::Noop)
     }
@@ -5006,6 +5500,7 @@ pub struct DateTimeRule {
 
 /// Removes the banded range with the given ID from the spreadsheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DeleteBandingRequest {
     /**
      * Removes the banded range with the given ID from the spreadsheet.
@@ -5013,14 +5508,14 @@ pub struct DeleteBandingRequest {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "bandedRangeId"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub banded_range_id: i64,
 }
 
 /// Deletes a conditional format rule at the given index. All subsequent rules' indexes are decremented.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DeleteConditionalFormatRuleRequest {
     /**
      * Deletes a conditional format rule at the given index. All subsequent rules' indexes are decremented.
@@ -5037,8 +5532,7 @@ pub struct DeleteConditionalFormatRuleRequest {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "sheetId"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub sheet_id: i64,
 }
@@ -5055,6 +5549,7 @@ pub struct DeleteConditionalFormatRuleResponse {
 
 /// Deletes a data source. The request also deletes the associated data source sheet, and unlinks all associated data source objects.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DeleteDataSourceRequest {
     /**
      * Deletes a data source. The request also deletes the associated data source sheet, and unlinks all associated data source objects.
@@ -5062,28 +5557,25 @@ pub struct DeleteDataSourceRequest {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "dataSourceId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub data_source_id: String,
 }
 
 /// A request to delete developer metadata.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DeleteDeveloperMetadataRequest {
     /**
      * A request to delete developer metadata.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "dataFilter"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data_filter: Option<DataFilter>,
 }
 
 /// The response from deleting developer metadata.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DeleteDeveloperMetadataResponse {
     /**
      * The response from deleting developer metadata.
@@ -5091,8 +5583,7 @@ pub struct DeleteDeveloperMetadataResponse {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "deletedDeveloperMetadata"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub deleted_developer_metadata: Vec<DeveloperMetadata>,
 }
@@ -5109,6 +5600,7 @@ pub struct DeleteDimensionGroupRequest {
 
 /// The result of deleting a group.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DeleteDimensionGroupResponse {
     /**
      * The result of deleting a group.
@@ -5116,8 +5608,7 @@ pub struct DeleteDimensionGroupResponse {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "dimensionGroups"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub dimension_groups: Vec<DimensionGroup>,
 }
@@ -5134,6 +5625,7 @@ pub struct DeleteDimensionRequest {
 
 /// Removes rows within this range that contain values in the specified columns that are duplicates of values in any previous row. Rows with identical values but different letter cases, formatting, or formulas are considered to be duplicates. This request also removes duplicate rows hidden from view (for example, due to a filter). When removing duplicates, the first instance of each duplicate row scanning from the top downwards is kept in the resulting range. Content outside of the specified range isn't removed, and rows considered duplicates do not have to be adjacent to each other in the range.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DeleteDuplicatesRequest {
     /**
      * Removes rows within this range that contain values in the specified columns that are duplicates of values in any previous row. Rows with identical values but different letter cases, formatting, or formulas are considered to be duplicates. This request also removes duplicate rows hidden from view (for example, due to a filter). When removing duplicates, the first instance of each duplicate row scanning from the top downwards is kept in the resulting range. Content outside of the specified range isn't removed, and rows considered duplicates do not have to be adjacent to each other in the range.
@@ -5141,8 +5633,7 @@ pub struct DeleteDuplicatesRequest {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "comparisonColumns"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub comparison_columns: Vec<DimensionRange>,
     /**
@@ -5154,6 +5645,7 @@ pub struct DeleteDuplicatesRequest {
 
 /// The result of removing duplicates in a range.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DeleteDuplicatesResponse {
     /**
      * The result of removing duplicates in a range.
@@ -5161,14 +5653,14 @@ pub struct DeleteDuplicatesResponse {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "duplicatesRemovedCount"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub duplicates_removed_count: i64,
 }
 
 /// Deletes the embedded object with the given ID.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DeleteEmbeddedObjectRequest {
     /**
      * Deletes the embedded object with the given ID.
@@ -5176,14 +5668,14 @@ pub struct DeleteEmbeddedObjectRequest {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "objectId"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub object_id: i64,
 }
 
 /// Deletes a particular filter view.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DeleteFilterViewRequest {
     /**
      * Deletes a particular filter view.
@@ -5191,14 +5683,14 @@ pub struct DeleteFilterViewRequest {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "filterId"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub filter_id: i64,
 }
 
 /// Removes the named range with the given ID from the spreadsheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DeleteNamedRangeRequest {
     /**
      * Removes the named range with the given ID from the spreadsheet.
@@ -5206,14 +5698,14 @@ pub struct DeleteNamedRangeRequest {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "namedRangeId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub named_range_id: String,
 }
 
 /// Deletes the protected range with the given ID.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DeleteProtectedRangeRequest {
     /**
      * Deletes the protected range with the given ID.
@@ -5221,14 +5713,14 @@ pub struct DeleteProtectedRangeRequest {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "protectedRangeId"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub protected_range_id: i64,
 }
 
 /// Deletes a range of cells, shifting other cells into the deleted area.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DeleteRangeRequest {
     /**
      * Deletes a range of cells, shifting other cells into the deleted area.
@@ -5238,16 +5730,13 @@ pub struct DeleteRangeRequest {
     /**
      * Deletes a range of cells, shifting other cells into the deleted area.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "shiftDimension"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shift_dimension: Option<Dimension>,
 }
 
 /// Deletes the requested sheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DeleteSheetRequest {
     /**
      * Deletes the requested sheet.
@@ -5255,8 +5744,7 @@ pub struct DeleteSheetRequest {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "sheetId"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub sheet_id: i64,
 }
@@ -5264,7 +5752,7 @@ pub struct DeleteSheetRequest {
 /**
  * The metadata visibility. Developer metadata must always have a visibility specified.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Visibility {
     #[serde(rename = "DEVELOPER_METADATA_VISIBILITY_UNSPECIFIED")]
     DeveloperMetadataVisibilityUnspecified,
@@ -5298,7 +5786,30 @@ impl Default for Visibility {
         Visibility::Noop
     }
 }
+
 impl Visibility {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Visibility::DeveloperMetadataVisibilityUnspecified => {
+                "DEVELOPER_METADATA_VISIBILITY_UNSPECIFIED"
+            }
+            Visibility::Document => "DOCUMENT",
+            Visibility::Project => "PROJECT",
+            Visibility::Noop => "",
+            Visibility::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Visibility] {
+        &[
+            Visibility::DeveloperMetadataVisibilityUnspecified,
+            Visibility::Document,
+            Visibility::Project,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Visibility::Noop)
     }
@@ -5306,6 +5817,7 @@ impl Visibility {
 
 /// Developer metadata associated with a location or object in a spreadsheet. Developer metadata may be used to associate arbitrary data with various parts of a spreadsheet and will remain associated at those locations as they move around and the spreadsheet is edited. For example, if developer metadata is associated with row 5 and another row is then subsequently inserted above row 5, that original metadata will still be associated with the row it was first associated with (what is now row 6). If the associated object is deleted its metadata is deleted too.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DeveloperMetadata {
     /**
      * Developer metadata associated with a location or object in a spreadsheet. Developer metadata may be used to associate arbitrary data with various parts of a spreadsheet and will remain associated at those locations as they move around and the spreadsheet is edited. For example, if developer metadata is associated with row 5 and another row is then subsequently inserted above row 5, that original metadata will still be associated with the row it was first associated with (what is now row 6). If the associated object is deleted its metadata is deleted too.
@@ -5318,8 +5830,7 @@ pub struct DeveloperMetadata {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "metadataId"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub metadata_id: i64,
     /**
@@ -5328,8 +5839,7 @@ pub struct DeveloperMetadata {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "metadataKey"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub metadata_key: String,
     /**
@@ -5338,8 +5848,7 @@ pub struct DeveloperMetadata {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "metadataValue"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub metadata_value: String,
     /**
@@ -5352,7 +5861,7 @@ pub struct DeveloperMetadata {
 /**
  * The type of location this object represents. This field is read-only.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum LocationType {
     #[serde(rename = "COLUMN")]
     Column,
@@ -5392,7 +5901,34 @@ impl Default for LocationType {
         LocationType::Noop
     }
 }
+
 impl LocationType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LocationType::Column => "COLUMN",
+            LocationType::DeveloperMetadataLocationTypeUnspecified => {
+                "DEVELOPER_METADATA_LOCATION_TYPE_UNSPECIFIED"
+            }
+            LocationType::Row => "ROW",
+            LocationType::Sheet => "SHEET",
+            LocationType::Spreadsheet => "SPREADSHEET",
+            LocationType::Noop => "",
+            LocationType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [LocationType] {
+        &[
+            LocationType::Column,
+            LocationType::DeveloperMetadataLocationTypeUnspecified,
+            LocationType::Row,
+            LocationType::Sheet,
+            LocationType::Spreadsheet,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, LocationType::Noop)
     }
@@ -5400,24 +5936,17 @@ impl LocationType {
 
 /// A location where metadata may be associated in a spreadsheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DeveloperMetadataLocation {
     /**
      * A location where metadata may be associated in a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "dimensionRange"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub dimension_range: Option<DimensionRange>,
     /**
      * A location where metadata may be associated in a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "locationType"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub location_type: Option<LocationType>,
     /**
      * A location where metadata may be associated in a spreadsheet.
@@ -5425,8 +5954,7 @@ pub struct DeveloperMetadataLocation {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "sheetId"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub sheet_id: i64,
     /**
@@ -5442,7 +5970,7 @@ pub struct DeveloperMetadataLocation {
 /**
  * Determines how this lookup matches the location. If this field is specified as EXACT, only developer metadata associated on the exact location specified is matched. If this field is specified to INTERSECTING, developer metadata associated on intersecting locations is also matched. If left unspecified, this field assumes a default value of INTERSECTING. If this field is specified, a metadataLocation must also be specified.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum LocationMatchingStrategy {
     #[serde(rename = "DEVELOPER_METADATA_LOCATION_MATCHING_STRATEGY_UNSPECIFIED")]
     DeveloperMetadataLocationMatchingStrategyUnspecified,
@@ -5476,7 +6004,30 @@ impl Default for LocationMatchingStrategy {
         LocationMatchingStrategy::Noop
     }
 }
+
 impl LocationMatchingStrategy {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LocationMatchingStrategy::DeveloperMetadataLocationMatchingStrategyUnspecified => {
+                "DEVELOPER_METADATA_LOCATION_MATCHING_STRATEGY_UNSPECIFIED"
+            }
+            LocationMatchingStrategy::ExactLocation => "EXACT_LOCATION",
+            LocationMatchingStrategy::IntersectingLocation => "INTERSECTING_LOCATION",
+            LocationMatchingStrategy::Noop => "",
+            LocationMatchingStrategy::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [LocationMatchingStrategy] {
+        &[
+            LocationMatchingStrategy::DeveloperMetadataLocationMatchingStrategyUnspecified,
+            LocationMatchingStrategy::ExactLocation,
+            LocationMatchingStrategy::IntersectingLocation,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, LocationMatchingStrategy::Noop)
     }
@@ -5484,24 +6035,17 @@ impl LocationMatchingStrategy {
 
 /// Selects DeveloperMetadata that matches all of the specified fields. For example, if only a metadata ID is specified this considers the DeveloperMetadata with that particular unique ID. If a metadata key is specified, this considers all developer metadata with that key. If a key, visibility, and location type are all specified, this considers all developer metadata with that key and visibility that are associated with a location of that type. In general, this selects all DeveloperMetadata that matches the intersection of all the specified fields; any field or combination of fields may be specified.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DeveloperMetadataLookup {
     /**
      * Selects DeveloperMetadata that matches all of the specified fields. For example, if only a metadata ID is specified this considers the DeveloperMetadata with that particular unique ID. If a metadata key is specified, this considers all developer metadata with that key. If a key, visibility, and location type are all specified, this considers all developer metadata with that key and visibility that are associated with a location of that type. In general, this selects all DeveloperMetadata that matches the intersection of all the specified fields; any field or combination of fields may be specified.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "locationMatchingStrategy"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub location_matching_strategy: Option<LocationMatchingStrategy>,
     /**
      * Selects DeveloperMetadata that matches all of the specified fields. For example, if only a metadata ID is specified this considers the DeveloperMetadata with that particular unique ID. If a metadata key is specified, this considers all developer metadata with that key. If a key, visibility, and location type are all specified, this considers all developer metadata with that key and visibility that are associated with a location of that type. In general, this selects all DeveloperMetadata that matches the intersection of all the specified fields; any field or combination of fields may be specified.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "locationType"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub location_type: Option<LocationType>,
     /**
      * Selects DeveloperMetadata that matches all of the specified fields. For example, if only a metadata ID is specified this considers the DeveloperMetadata with that particular unique ID. If a metadata key is specified, this considers all developer metadata with that key. If a key, visibility, and location type are all specified, this considers all developer metadata with that key and visibility that are associated with a location of that type. In general, this selects all DeveloperMetadata that matches the intersection of all the specified fields; any field or combination of fields may be specified.
@@ -5509,8 +6053,7 @@ pub struct DeveloperMetadataLookup {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "metadataId"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub metadata_id: i64,
     /**
@@ -5519,18 +6062,13 @@ pub struct DeveloperMetadataLookup {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "metadataKey"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub metadata_key: String,
     /**
      * Selects DeveloperMetadata that matches all of the specified fields. For example, if only a metadata ID is specified this considers the DeveloperMetadata with that particular unique ID. If a metadata key is specified, this considers all developer metadata with that key. If a key, visibility, and location type are all specified, this considers all developer metadata with that key and visibility that are associated with a location of that type. In general, this selects all DeveloperMetadata that matches the intersection of all the specified fields; any field or combination of fields may be specified.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "metadataLocation"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub metadata_location: Option<DeveloperMetadataLocation>,
     /**
      * Selects DeveloperMetadata that matches all of the specified fields. For example, if only a metadata ID is specified this considers the DeveloperMetadata with that particular unique ID. If a metadata key is specified, this considers all developer metadata with that key. If a key, visibility, and location type are all specified, this considers all developer metadata with that key and visibility that are associated with a location of that type. In general, this selects all DeveloperMetadata that matches the intersection of all the specified fields; any field or combination of fields may be specified.
@@ -5538,8 +6076,7 @@ pub struct DeveloperMetadataLookup {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "metadataValue"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub metadata_value: String,
     /**
@@ -5578,15 +6115,12 @@ pub struct DimensionGroup {
 
 /// Properties about a dimension.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DimensionProperties {
     /**
      * Properties about a dimension.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "dataSourceColumnReference"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data_source_column_reference: Option<DataSourceColumnReference>,
     /**
      * Properties about a dimension.
@@ -5594,8 +6128,7 @@ pub struct DimensionProperties {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "developerMetadata"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub developer_metadata: Vec<DeveloperMetadata>,
     /**
@@ -5603,8 +6136,7 @@ pub struct DimensionProperties {
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "hiddenByFilter"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub hidden_by_filter: bool,
     /**
@@ -5612,8 +6144,7 @@ pub struct DimensionProperties {
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "hiddenByUser"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub hidden_by_user: bool,
     /**
@@ -5622,14 +6153,14 @@ pub struct DimensionProperties {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "pixelSize"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub pixel_size: i64,
 }
 
 /// A range along a single dimension on a sheet. All indexes are zero-based. Indexes are half open: the start index is inclusive and the end index is exclusive. Missing indexes indicate the range is unbounded on that side.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DimensionRange {
     /**
      * A range along a single dimension on a sheet. All indexes are zero-based. Indexes are half open: the start index is inclusive and the end index is exclusive. Missing indexes indicate the range is unbounded on that side.
@@ -5642,8 +6173,7 @@ pub struct DimensionRange {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "endIndex"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub end_index: i64,
     /**
@@ -5652,8 +6182,7 @@ pub struct DimensionRange {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "sheetId"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub sheet_id: i64,
     /**
@@ -5662,14 +6191,14 @@ pub struct DimensionRange {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "startIndex"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub start_index: i64,
 }
 
 /// Duplicates a particular filter view.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DuplicateFilterViewRequest {
     /**
      * Duplicates a particular filter view.
@@ -5677,8 +6206,7 @@ pub struct DuplicateFilterViewRequest {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "filterId"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub filter_id: i64,
 }
@@ -5695,6 +6223,7 @@ pub struct DuplicateFilterViewResponse {
 
 /// Duplicates the contents of a sheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DuplicateSheetRequest {
     /**
      * Duplicates the contents of a sheet.
@@ -5702,8 +6231,7 @@ pub struct DuplicateSheetRequest {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "insertSheetIndex"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub insert_sheet_index: i64,
     /**
@@ -5712,8 +6240,7 @@ pub struct DuplicateSheetRequest {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "newSheetId"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub new_sheet_id: i64,
     /**
@@ -5722,8 +6249,7 @@ pub struct DuplicateSheetRequest {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "newSheetName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub new_sheet_name: String,
     /**
@@ -5732,8 +6258,7 @@ pub struct DuplicateSheetRequest {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "sourceSheetId"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub source_sheet_id: i64,
 }
@@ -5750,14 +6275,14 @@ pub struct DuplicateSheetResponse {
 
 /// The editors of a protected range.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Editors {
     /**
      * The editors of a protected range.
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "domainUsersCanEdit"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub domain_users_can_edit: bool,
     /**
@@ -5782,6 +6307,7 @@ pub struct Editors {
 
 /// A chart embedded in a sheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct EmbeddedChart {
     /**
      * A chart embedded in a sheet.
@@ -5794,8 +6320,7 @@ pub struct EmbeddedChart {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "chartId"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub chart_id: i64,
     /**
@@ -5812,6 +6337,7 @@ pub struct EmbeddedChart {
 
 /// A border along an embedded object.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct EmbeddedObjectBorder {
     /**
      * A border along an embedded object.
@@ -5821,34 +6347,26 @@ pub struct EmbeddedObjectBorder {
     /**
      * A border along an embedded object.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "colorStyle"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub color_style: Option<ColorStyle>,
 }
 
 /// The position of an embedded object such as a chart.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct EmbeddedObjectPosition {
     /**
      * The position of an embedded object such as a chart.
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "newSheet"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub new_sheet: bool,
     /**
      * The position of an embedded object such as a chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "overlayPosition"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub overlay_position: Option<OverlayPosition>,
     /**
      * The position of an embedded object such as a chart.
@@ -5856,8 +6374,7 @@ pub struct EmbeddedObjectPosition {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "sheetId"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub sheet_id: i64,
 }
@@ -5865,7 +6382,7 @@ pub struct EmbeddedObjectPosition {
 /**
  * The type of error.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ErrorValueType {
     #[serde(rename = "DIVIDE_BY_ZERO")]
     DivideByZero,
@@ -5918,7 +6435,42 @@ impl Default for ErrorValueType {
         ErrorValueType::Noop
     }
 }
+
 impl ErrorValueType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorValueType::DivideByZero => "DIVIDE_BY_ZERO",
+            ErrorValueType::Error => "ERROR",
+            ErrorValueType::ErrorTypeUnspecified => "ERROR_TYPE_UNSPECIFIED",
+            ErrorValueType::Loading => "LOADING",
+            ErrorValueType::Name => "NAME",
+            ErrorValueType::NullValue => "NULL_VALUE",
+            ErrorValueType::Num => "NUM",
+            ErrorValueType::NA => "N_A",
+            ErrorValueType::Ref => "REF",
+            ErrorValueType::Value => "VALUE",
+            ErrorValueType::Noop => "",
+            ErrorValueType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ErrorValueType] {
+        &[
+            ErrorValueType::DivideByZero,
+            ErrorValueType::Error,
+            ErrorValueType::ErrorTypeUnspecified,
+            ErrorValueType::Loading,
+            ErrorValueType::Name,
+            ErrorValueType::NullValue,
+            ErrorValueType::Num,
+            ErrorValueType::NA,
+            ErrorValueType::Ref,
+            ErrorValueType::Value,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ErrorValueType::Noop)
     }
@@ -5945,24 +6497,20 @@ pub struct ErrorValue {
 
 /// The kinds of value that a cell in a spreadsheet can have.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ExtendedValue {
     /**
      * The kinds of value that a cell in a spreadsheet can have.
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "boolValue"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub bool_value: bool,
     /**
      * The kinds of value that a cell in a spreadsheet can have.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "errorValue"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub error_value: Option<ErrorValue>,
     /**
      * The kinds of value that a cell in a spreadsheet can have.
@@ -5970,8 +6518,7 @@ pub struct ExtendedValue {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "formulaValue"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub formula_value: String,
     /**
@@ -5980,8 +6527,7 @@ pub struct ExtendedValue {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "numberValue"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub number_value: f64,
     /**
@@ -5990,14 +6536,14 @@ pub struct ExtendedValue {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "stringValue"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub string_value: String,
 }
 
 /// Criteria for showing/hiding rows in a filter or filter view.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct FilterCriteria {
     /**
      * Criteria for showing/hiding rows in a filter or filter view.
@@ -6010,50 +6556,34 @@ pub struct FilterCriteria {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "hiddenValues"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub hidden_values: Vec<String>,
     /**
      * Criteria for showing/hiding rows in a filter or filter view.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "visibleBackgroundColor"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub visible_background_color: Option<Color>,
     /**
      * Criteria for showing/hiding rows in a filter or filter view.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "visibleBackgroundColorStyle"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub visible_background_color_style: Option<ColorStyle>,
     /**
      * Criteria for showing/hiding rows in a filter or filter view.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "visibleForegroundColor"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub visible_foreground_color: Option<Color>,
     /**
      * Criteria for showing/hiding rows in a filter or filter view.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "visibleForegroundColorStyle"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub visible_foreground_color_style: Option<ColorStyle>,
 }
 
 /// The filter criteria associated with a specific column.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct FilterSpec {
     /**
      * The filter criteria associated with a specific column.
@@ -6061,32 +6591,24 @@ pub struct FilterSpec {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "columnIndex"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub column_index: i64,
     /**
      * The filter criteria associated with a specific column.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "dataSourceColumnReference"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data_source_column_reference: Option<DataSourceColumnReference>,
     /**
      * The filter criteria associated with a specific column.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "filterCriteria"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub filter_criteria: Option<FilterCriteria>,
 }
 
 /// A filter view.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct FilterView {
     /**
      * A filter view.
@@ -6099,8 +6621,7 @@ pub struct FilterView {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "filterSpecs"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub filter_specs: Vec<FilterSpec>,
     /**
@@ -6109,8 +6630,7 @@ pub struct FilterView {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "filterViewId"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub filter_view_id: i64,
     /**
@@ -6119,8 +6639,7 @@ pub struct FilterView {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "namedRangeId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub named_range_id: String,
     /**
@@ -6134,8 +6653,7 @@ pub struct FilterView {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "sortSpecs"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub sort_specs: Vec<SortSpec>,
     /**
@@ -6151,11 +6669,12 @@ pub struct FilterView {
 
 /// Finds and replaces data in cells over a range, sheet, or all sheets.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct FindReplaceRequest {
     /**
      * Finds and replaces data in cells over a range, sheet, or all sheets.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "allSheets")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub all_sheets: Option<bool>,
     /**
      * Finds and replaces data in cells over a range, sheet, or all sheets.
@@ -6169,25 +6688,17 @@ pub struct FindReplaceRequest {
     /**
      * Finds and replaces data in cells over a range, sheet, or all sheets.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "includeFormulas"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub include_formulas: Option<bool>,
     /**
      * Finds and replaces data in cells over a range, sheet, or all sheets.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "matchCase")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub match_case: Option<bool>,
     /**
      * Finds and replaces data in cells over a range, sheet, or all sheets.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "matchEntireCell"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub match_entire_cell: Option<bool>,
     /**
      * Finds and replaces data in cells over a range, sheet, or all sheets.
@@ -6206,11 +6717,7 @@ pub struct FindReplaceRequest {
     /**
      * Finds and replaces data in cells over a range, sheet, or all sheets.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "searchByRegex"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub search_by_regex: Option<bool>,
     /**
      * Finds and replaces data in cells over a range, sheet, or all sheets.
@@ -6218,14 +6725,14 @@ pub struct FindReplaceRequest {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "sheetId"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub sheet_id: i64,
 }
 
 /// The result of the find/replace.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct FindReplaceResponse {
     /**
      * The result of the find/replace.
@@ -6233,8 +6740,7 @@ pub struct FindReplaceResponse {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "formulasChanged"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub formulas_changed: i64,
     /**
@@ -6243,8 +6749,7 @@ pub struct FindReplaceResponse {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "occurrencesChanged"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub occurrences_changed: i64,
     /**
@@ -6253,8 +6758,7 @@ pub struct FindReplaceResponse {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "rowsChanged"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub rows_changed: i64,
     /**
@@ -6263,8 +6767,7 @@ pub struct FindReplaceResponse {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "sheetsChanged"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub sheets_changed: i64,
     /**
@@ -6273,14 +6776,14 @@ pub struct FindReplaceResponse {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "valuesChanged"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub values_changed: i64,
 }
 
 /// The request for retrieving a Spreadsheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct GetSpreadsheetByDataFilterRequest {
     /**
      * The request for retrieving a Spreadsheet.
@@ -6288,18 +6791,13 @@ pub struct GetSpreadsheetByDataFilterRequest {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "dataFilters"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub data_filters: Vec<DataFilter>,
     /**
      * The request for retrieving a Spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "includeGridData"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub include_grid_data: Option<bool>,
 }
 
@@ -6325,6 +6823,7 @@ pub struct GradientRule {
 
 /// A coordinate in a sheet. All indexes are zero-based.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct GridCoordinate {
     /**
      * A coordinate in a sheet. All indexes are zero-based.
@@ -6332,8 +6831,7 @@ pub struct GridCoordinate {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "columnIndex"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub column_index: i64,
     /**
@@ -6342,8 +6840,7 @@ pub struct GridCoordinate {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "rowIndex"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub row_index: i64,
     /**
@@ -6352,14 +6849,14 @@ pub struct GridCoordinate {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "sheetId"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub sheet_id: i64,
 }
 
 /// Data in the grid, as well as metadata about the dimensions.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct GridData {
     /**
      * Data in the grid, as well as metadata about the dimensions.
@@ -6367,8 +6864,7 @@ pub struct GridData {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "columnMetadata"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub column_metadata: Vec<DimensionProperties>,
     /**
@@ -6377,8 +6873,7 @@ pub struct GridData {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "rowData"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub row_data: Vec<RowData>,
     /**
@@ -6387,8 +6882,7 @@ pub struct GridData {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "rowMetadata"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub row_metadata: Vec<DimensionProperties>,
     /**
@@ -6397,8 +6891,7 @@ pub struct GridData {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "startColumn"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub start_column: i64,
     /**
@@ -6407,14 +6900,14 @@ pub struct GridData {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "startRow"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub start_row: i64,
 }
 
 /// Properties of a grid.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct GridProperties {
     /**
      * Properties of a grid.
@@ -6422,8 +6915,7 @@ pub struct GridProperties {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "columnCount"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub column_count: i64,
     /**
@@ -6431,8 +6923,7 @@ pub struct GridProperties {
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "columnGroupControlAfter"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub column_group_control_after: bool,
     /**
@@ -6441,8 +6932,7 @@ pub struct GridProperties {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "frozenColumnCount"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub frozen_column_count: i64,
     /**
@@ -6451,8 +6941,7 @@ pub struct GridProperties {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "frozenRowCount"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub frozen_row_count: i64,
     /**
@@ -6460,8 +6949,7 @@ pub struct GridProperties {
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "hideGridlines"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub hide_gridlines: bool,
     /**
@@ -6470,8 +6958,7 @@ pub struct GridProperties {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "rowCount"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub row_count: i64,
     /**
@@ -6479,14 +6966,14 @@ pub struct GridProperties {
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "rowGroupControlAfter"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub row_group_control_after: bool,
 }
 
 /// A range on a sheet. All indexes are zero-based. Indexes are half open, i.e. the start index is inclusive and the end index is exclusive -- [start_index, end_index). Missing indexes indicate the range is unbounded on that side. For example, if `"Sheet1"` is sheet ID 0, then: `Sheet1!A1:A1 == sheet_id: 0, start_row_index: 0, end_row_index: 1, start_column_index: 0, end_column_index: 1` `Sheet1!A3:B4 == sheet_id: 0, start_row_index: 2, end_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1!A:B == sheet_id: 0, start_column_index: 0, end_column_index: 2` `Sheet1!A5:B == sheet_id: 0, start_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1 == sheet_id:0` The start index must always be less than or equal to the end index. If the start index equals the end index, then the range is empty. Empty ranges are typically not meaningful and are usually rendered in the UI as `#REF!`.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct GridRange {
     /**
      * A range on a sheet. All indexes are zero-based. Indexes are half open, i.e. the start index is inclusive and the end index is exclusive -- [start_index, end_index). Missing indexes indicate the range is unbounded on that side. For example, if `"Sheet1"` is sheet ID 0, then: `Sheet1!A1:A1 == sheet_id: 0, start_row_index: 0, end_row_index: 1, start_column_index: 0, end_column_index: 1` `Sheet1!A3:B4 == sheet_id: 0, start_row_index: 2, end_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1!A:B == sheet_id: 0, start_column_index: 0, end_column_index: 2` `Sheet1!A5:B == sheet_id: 0, start_row_index: 4, start_column_index: 0, end_column_index: 2` `Sheet1 == sheet_id:0` The start index must always be less than or equal to the end index. If the start index equals the end index, then the range is empty. Empty ranges are typically not meaningful and are usually rendered in the UI as `#REF!`.
@@ -6494,8 +6981,7 @@ pub struct GridRange {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "endColumnIndex"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub end_column_index: i64,
     /**
@@ -6504,8 +6990,7 @@ pub struct GridRange {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "endRowIndex"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub end_row_index: i64,
     /**
@@ -6514,8 +6999,7 @@ pub struct GridRange {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "sheetId"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub sheet_id: i64,
     /**
@@ -6524,8 +7008,7 @@ pub struct GridRange {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "startColumnIndex"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub start_column_index: i64,
     /**
@@ -6534,8 +7017,7 @@ pub struct GridRange {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "startRowIndex"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub start_row_index: i64,
 }
@@ -6543,7 +7025,7 @@ pub struct GridRange {
 /**
  * The position of the chart legend.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum HistogramChartSpecLegendPosition {
     #[serde(rename = "BOTTOM_LEGEND")]
     BottomLegend,
@@ -6589,7 +7071,38 @@ impl Default for HistogramChartSpecLegendPosition {
         HistogramChartSpecLegendPosition::Noop
     }
 }
+
 impl HistogramChartSpecLegendPosition {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HistogramChartSpecLegendPosition::BottomLegend => "BOTTOM_LEGEND",
+            HistogramChartSpecLegendPosition::HistogramChartLegendPositionUnspecified => {
+                "HISTOGRAM_CHART_LEGEND_POSITION_UNSPECIFIED"
+            }
+            HistogramChartSpecLegendPosition::InsideLegend => "INSIDE_LEGEND",
+            HistogramChartSpecLegendPosition::LeftLegend => "LEFT_LEGEND",
+            HistogramChartSpecLegendPosition::NoLegend => "NO_LEGEND",
+            HistogramChartSpecLegendPosition::RightLegend => "RIGHT_LEGEND",
+            HistogramChartSpecLegendPosition::TopLegend => "TOP_LEGEND",
+            HistogramChartSpecLegendPosition::Noop => "",
+            HistogramChartSpecLegendPosition::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [HistogramChartSpecLegendPosition] {
+        &[
+            HistogramChartSpecLegendPosition::BottomLegend,
+            HistogramChartSpecLegendPosition::HistogramChartLegendPositionUnspecified,
+            HistogramChartSpecLegendPosition::InsideLegend,
+            HistogramChartSpecLegendPosition::LeftLegend,
+            HistogramChartSpecLegendPosition::NoLegend,
+            HistogramChartSpecLegendPosition::RightLegend,
+            HistogramChartSpecLegendPosition::TopLegend,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, HistogramChartSpecLegendPosition::Noop)
     }
@@ -6597,6 +7110,7 @@ impl HistogramChartSpecLegendPosition {
 
 /// A histogram chart. A histogram chart groups data items into bins, displaying each bin as a column of stacked items. Histograms are used to display the distribution of a dataset. Each column of items represents a range into which those items fall. The number of bins can be chosen automatically or specified explicitly.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct HistogramChartSpec {
     /**
      * A histogram chart. A histogram chart groups data items into bins, displaying each bin as a column of stacked items. Histograms are used to display the distribution of a dataset. Each column of items represents a range into which those items fall. The number of bins can be chosen automatically or specified explicitly.
@@ -6604,18 +7118,13 @@ pub struct HistogramChartSpec {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "bucketSize"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub bucket_size: f64,
     /**
      * A histogram chart. A histogram chart groups data items into bins, displaying each bin as a column of stacked items. Histograms are used to display the distribution of a dataset. Each column of items represents a range into which those items fall. The number of bins can be chosen automatically or specified explicitly.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "legendPosition"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub legend_position: Option<HistogramChartSpecLegendPosition>,
     /**
      * A histogram chart. A histogram chart groups data items into bins, displaying each bin as a column of stacked items. Histograms are used to display the distribution of a dataset. Each column of items represents a range into which those items fall. The number of bins can be chosen automatically or specified explicitly.
@@ -6623,8 +7132,7 @@ pub struct HistogramChartSpec {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "outlierPercentile"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub outlier_percentile: f64,
     /**
@@ -6641,8 +7149,7 @@ pub struct HistogramChartSpec {
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "showItemDividers"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub show_item_dividers: bool,
 }
@@ -6681,20 +7188,17 @@ pub struct HistogramRule {
 
 /// A histogram series containing the series color and data.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct HistogramSeries {
     /**
      * A histogram series containing the series color and data.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "barColor")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub bar_color: Option<Color>,
     /**
      * A histogram series containing the series color and data.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "barColorStyle"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub bar_color_style: Option<ColorStyle>,
     /**
      * A histogram series containing the series color and data.
@@ -6705,15 +7209,12 @@ pub struct HistogramSeries {
 
 /// Inserts rows or columns in a sheet at a particular index.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct InsertDimensionRequest {
     /**
      * Inserts rows or columns in a sheet at a particular index.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "inheritFromBefore"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub inherit_from_before: Option<bool>,
     /**
      * Inserts rows or columns in a sheet at a particular index.
@@ -6724,6 +7225,7 @@ pub struct InsertDimensionRequest {
 
 /// Inserts cells into a range, shifting the existing cells over or down.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct InsertRangeRequest {
     /**
      * Inserts cells into a range, shifting the existing cells over or down.
@@ -6733,18 +7235,14 @@ pub struct InsertRangeRequest {
     /**
      * Inserts cells into a range, shifting the existing cells over or down.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "shiftDimension"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shift_dimension: Option<Dimension>,
 }
 
 /**
  * How the value should be interpreted.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum InterpolationPointType {
     #[serde(rename = "INTERPOLATION_POINT_TYPE_UNSPECIFIED")]
     InterpolationPointTypeUnspecified,
@@ -6787,7 +7285,36 @@ impl Default for InterpolationPointType {
         InterpolationPointType::Noop
     }
 }
+
 impl InterpolationPointType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InterpolationPointType::InterpolationPointTypeUnspecified => {
+                "INTERPOLATION_POINT_TYPE_UNSPECIFIED"
+            }
+            InterpolationPointType::Max => "MAX",
+            InterpolationPointType::Min => "MIN",
+            InterpolationPointType::Number => "NUMBER",
+            InterpolationPointType::Percent => "PERCENT",
+            InterpolationPointType::Percentile => "PERCENTILE",
+            InterpolationPointType::Noop => "",
+            InterpolationPointType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [InterpolationPointType] {
+        &[
+            InterpolationPointType::InterpolationPointTypeUnspecified,
+            InterpolationPointType::Max,
+            InterpolationPointType::Min,
+            InterpolationPointType::Number,
+            InterpolationPointType::Percent,
+            InterpolationPointType::Percentile,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, InterpolationPointType::Noop)
     }
@@ -6795,6 +7322,7 @@ impl InterpolationPointType {
 
 /// A single interpolation point on a gradient conditional format. These pin the gradient color scale according to the color, type and value chosen.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct InterpolationPoint {
     /**
      * A single interpolation point on a gradient conditional format. These pin the gradient color scale according to the color, type and value chosen.
@@ -6804,11 +7332,7 @@ pub struct InterpolationPoint {
     /**
      * A single interpolation point on a gradient conditional format. These pin the gradient color scale according to the color, type and value chosen.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "colorStyle"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub color_style: Option<ColorStyle>,
     /**
      * A single interpolation point on a gradient conditional format. These pin the gradient color scale according to the color, type and value chosen.
@@ -6828,6 +7352,7 @@ pub struct InterpolationPoint {
 
 /// Represents a time interval, encoded as a Timestamp start (inclusive) and a Timestamp end (exclusive). The start must be less than or equal to the end. When the start equals the end, the interval is empty (matches no time). When both start and end are unspecified, the interval matches any time.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Interval {
     /**
      * Represents a time interval, encoded as a Timestamp start (inclusive) and a Timestamp end (exclusive). The start must be less than or equal to the end. When the start equals the end, the interval is empty (matches no time). When both start and end are unspecified, the interval matches any time.
@@ -6835,8 +7360,7 @@ pub struct Interval {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "endTime"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub end_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
@@ -6845,14 +7369,14 @@ pub struct Interval {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "startTime"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub start_time: Option<chrono::DateTime<chrono::Utc>>,
 }
 
 /// Settings to control how circular dependencies are resolved with iterative calculation.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct IterativeCalculationSettings {
     /**
      * Settings to control how circular dependencies are resolved with iterative calculation.
@@ -6860,8 +7384,7 @@ pub struct IterativeCalculationSettings {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "convergenceThreshold"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub convergence_threshold: f64,
     /**
@@ -6870,14 +7393,14 @@ pub struct IterativeCalculationSettings {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "maxIterations"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub max_iterations: i64,
 }
 
 /// Formatting options for key value.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct KeyValueFormat {
     /**
      * Formatting options for key value.
@@ -6887,18 +7410,14 @@ pub struct KeyValueFormat {
     /**
      * Formatting options for key value.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "textFormat"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub text_format: Option<TextFormat>,
 }
 
 /**
  * The dash type of the line.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum LineStyleType {
     #[serde(rename = "CUSTOM")]
     Custom,
@@ -6948,7 +7467,40 @@ impl Default for LineStyleType {
         LineStyleType::Noop
     }
 }
+
 impl LineStyleType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LineStyleType::Custom => "CUSTOM",
+            LineStyleType::Dotted => "DOTTED",
+            LineStyleType::Invisible => "INVISIBLE",
+            LineStyleType::LineDashTypeUnspecified => "LINE_DASH_TYPE_UNSPECIFIED",
+            LineStyleType::LongDashed => "LONG_DASHED",
+            LineStyleType::LongDashedDotted => "LONG_DASHED_DOTTED",
+            LineStyleType::MediumDashed => "MEDIUM_DASHED",
+            LineStyleType::MediumDashedDotted => "MEDIUM_DASHED_DOTTED",
+            LineStyleType::Solid => "SOLID",
+            LineStyleType::Noop => "",
+            LineStyleType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [LineStyleType] {
+        &[
+            LineStyleType::Custom,
+            LineStyleType::Dotted,
+            LineStyleType::Invisible,
+            LineStyleType::LineDashTypeUnspecified,
+            LineStyleType::LongDashed,
+            LineStyleType::LongDashedDotted,
+            LineStyleType::MediumDashed,
+            LineStyleType::MediumDashedDotted,
+            LineStyleType::Solid,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, LineStyleType::Noop)
     }
@@ -7003,11 +7555,12 @@ pub struct ManualRule {
 
 /// A group name and a list of items from the source data that should be placed in the group with this name.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ManualRuleGroup {
     /**
      * A group name and a list of items from the source data that should be placed in the group with this name.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "groupName")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub group_name: Option<ExtendedValue>,
     /**
      * A group name and a list of items from the source data that should be placed in the group with this name.
@@ -7022,6 +7575,7 @@ pub struct ManualRuleGroup {
 
 /// A developer metadata entry and the data filters specified in the original request that matched it.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct MatchedDeveloperMetadata {
     /**
      * A developer metadata entry and the data filters specified in the original request that matched it.
@@ -7029,23 +7583,19 @@ pub struct MatchedDeveloperMetadata {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "dataFilters"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub data_filters: Vec<DataFilter>,
     /**
      * A developer metadata entry and the data filters specified in the original request that matched it.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "developerMetadata"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub developer_metadata: Option<DeveloperMetadata>,
 }
 
 /// A value range that was matched by one or more data filers.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct MatchedValueRange {
     /**
      * A value range that was matched by one or more data filers.
@@ -7053,25 +7603,20 @@ pub struct MatchedValueRange {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "dataFilters"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub data_filters: Vec<DataFilter>,
     /**
      * A value range that was matched by one or more data filers.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "valueRange"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub value_range: Option<ValueRange>,
 }
 
 /**
  * How the cells should be merged.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum MergeType {
     #[serde(rename = "MERGE_ALL")]
     MergeAll,
@@ -7103,7 +7648,28 @@ impl Default for MergeType {
         MergeType::Noop
     }
 }
+
 impl MergeType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MergeType::MergeAll => "MERGE_ALL",
+            MergeType::MergeColumns => "MERGE_COLUMNS",
+            MergeType::MergeRows => "MERGE_ROWS",
+            MergeType::Noop => "",
+            MergeType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [MergeType] {
+        &[
+            MergeType::MergeAll,
+            MergeType::MergeColumns,
+            MergeType::MergeRows,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, MergeType::Noop)
     }
@@ -7111,11 +7677,12 @@ impl MergeType {
 
 /// Merges all cells in the range.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct MergeCellsRequest {
     /**
      * Merges all cells in the range.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "mergeType")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub merge_type: Option<MergeType>,
     /**
      * Merges all cells in the range.
@@ -7126,6 +7693,7 @@ pub struct MergeCellsRequest {
 
 /// Moves one or more rows or columns.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct MoveDimensionRequest {
     /**
      * Moves one or more rows or columns.
@@ -7133,8 +7701,7 @@ pub struct MoveDimensionRequest {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "destinationIndex"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub destination_index: i64,
     /**
@@ -7146,6 +7713,7 @@ pub struct MoveDimensionRequest {
 
 /// A named range.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct NamedRange {
     /**
      * A named range.
@@ -7162,8 +7730,7 @@ pub struct NamedRange {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "namedRangeId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub named_range_id: String,
     /**
@@ -7176,7 +7743,7 @@ pub struct NamedRange {
 /**
  * The type of the number format. When writing, this field must be set.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum NumberFormatType {
     #[serde(rename = "CURRENCY")]
     Currency,
@@ -7226,7 +7793,40 @@ impl Default for NumberFormatType {
         NumberFormatType::Noop
     }
 }
+
 impl NumberFormatType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NumberFormatType::Currency => "CURRENCY",
+            NumberFormatType::Date => "DATE",
+            NumberFormatType::DateTime => "DATE_TIME",
+            NumberFormatType::Number => "NUMBER",
+            NumberFormatType::NumberFormatTypeUnspecified => "NUMBER_FORMAT_TYPE_UNSPECIFIED",
+            NumberFormatType::Percent => "PERCENT",
+            NumberFormatType::Scientific => "SCIENTIFIC",
+            NumberFormatType::Text => "TEXT",
+            NumberFormatType::Time => "TIME",
+            NumberFormatType::Noop => "",
+            NumberFormatType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [NumberFormatType] {
+        &[
+            NumberFormatType::Currency,
+            NumberFormatType::Date,
+            NumberFormatType::DateTime,
+            NumberFormatType::Number,
+            NumberFormatType::NumberFormatTypeUnspecified,
+            NumberFormatType::Percent,
+            NumberFormatType::Scientific,
+            NumberFormatType::Text,
+            NumberFormatType::Time,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, NumberFormatType::Noop)
     }
@@ -7254,7 +7854,7 @@ pub struct NumberFormat {
 /**
  * The size of the org chart nodes.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum NodeSize {
     #[serde(rename = "LARGE")]
     Large,
@@ -7289,7 +7889,30 @@ impl Default for NodeSize {
         NodeSize::Noop
     }
 }
+
 impl NodeSize {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NodeSize::Large => "LARGE",
+            NodeSize::Medium => "MEDIUM",
+            NodeSize::OrgChartLabelSizeUnspecified => "ORG_CHART_LABEL_SIZE_UNSPECIFIED",
+            NodeSize::Small => "SMALL",
+            NodeSize::Noop => "",
+            NodeSize::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [NodeSize] {
+        &[
+            NodeSize::Large,
+            NodeSize::Medium,
+            NodeSize::OrgChartLabelSizeUnspecified,
+            NodeSize::Small,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, NodeSize::Noop)
     }
@@ -7297,6 +7920,7 @@ impl NodeSize {
 
 /// An org chart. Org charts require a unique set of labels in labels and may optionally include parent_labels and tooltips. parent_labels contain, for each node, the label identifying the parent node. tooltips contain, for each node, an optional tooltip. For example, to describe an OrgChart with Alice as the CEO, Bob as the President (reporting to Alice) and Cathy as VP of Sales (also reporting to Alice), have labels contain "Alice", "Bob", "Cathy", parent_labels contain "", "Alice", "Alice" and tooltips contain "CEO", "President", "VP Sales".
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct OrgChartSpec {
     /**
      * An org chart. Org charts require a unique set of labels in labels and may optionally include parent_labels and tooltips. parent_labels contain, for each node, the label identifying the parent node. tooltips contain, for each node, an optional tooltip. For example, to describe an OrgChart with Alice as the CEO, Bob as the President (reporting to Alice) and Cathy as VP of Sales (also reporting to Alice), have labels contain "Alice", "Bob", "Cathy", parent_labels contain "", "Alice", "Alice" and tooltips contain "CEO", "President", "VP Sales".
@@ -7306,48 +7930,32 @@ pub struct OrgChartSpec {
     /**
      * An org chart. Org charts require a unique set of labels in labels and may optionally include parent_labels and tooltips. parent_labels contain, for each node, the label identifying the parent node. tooltips contain, for each node, an optional tooltip. For example, to describe an OrgChart with Alice as the CEO, Bob as the President (reporting to Alice) and Cathy as VP of Sales (also reporting to Alice), have labels contain "Alice", "Bob", "Cathy", parent_labels contain "", "Alice", "Alice" and tooltips contain "CEO", "President", "VP Sales".
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "nodeColor")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub node_color: Option<Color>,
     /**
      * An org chart. Org charts require a unique set of labels in labels and may optionally include parent_labels and tooltips. parent_labels contain, for each node, the label identifying the parent node. tooltips contain, for each node, an optional tooltip. For example, to describe an OrgChart with Alice as the CEO, Bob as the President (reporting to Alice) and Cathy as VP of Sales (also reporting to Alice), have labels contain "Alice", "Bob", "Cathy", parent_labels contain "", "Alice", "Alice" and tooltips contain "CEO", "President", "VP Sales".
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "nodeColorStyle"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub node_color_style: Option<ColorStyle>,
     /**
      * An org chart. Org charts require a unique set of labels in labels and may optionally include parent_labels and tooltips. parent_labels contain, for each node, the label identifying the parent node. tooltips contain, for each node, an optional tooltip. For example, to describe an OrgChart with Alice as the CEO, Bob as the President (reporting to Alice) and Cathy as VP of Sales (also reporting to Alice), have labels contain "Alice", "Bob", "Cathy", parent_labels contain "", "Alice", "Alice" and tooltips contain "CEO", "President", "VP Sales".
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "nodeSize")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub node_size: Option<NodeSize>,
     /**
      * An org chart. Org charts require a unique set of labels in labels and may optionally include parent_labels and tooltips. parent_labels contain, for each node, the label identifying the parent node. tooltips contain, for each node, an optional tooltip. For example, to describe an OrgChart with Alice as the CEO, Bob as the President (reporting to Alice) and Cathy as VP of Sales (also reporting to Alice), have labels contain "Alice", "Bob", "Cathy", parent_labels contain "", "Alice", "Alice" and tooltips contain "CEO", "President", "VP Sales".
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "parentLabels"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub parent_labels: Option<ChartData>,
     /**
      * An org chart. Org charts require a unique set of labels in labels and may optionally include parent_labels and tooltips. parent_labels contain, for each node, the label identifying the parent node. tooltips contain, for each node, an optional tooltip. For example, to describe an OrgChart with Alice as the CEO, Bob as the President (reporting to Alice) and Cathy as VP of Sales (also reporting to Alice), have labels contain "Alice", "Bob", "Cathy", parent_labels contain "", "Alice", "Alice" and tooltips contain "CEO", "President", "VP Sales".
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "selectedNodeColor"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub selected_node_color: Option<Color>,
     /**
      * An org chart. Org charts require a unique set of labels in labels and may optionally include parent_labels and tooltips. parent_labels contain, for each node, the label identifying the parent node. tooltips contain, for each node, an optional tooltip. For example, to describe an OrgChart with Alice as the CEO, Bob as the President (reporting to Alice) and Cathy as VP of Sales (also reporting to Alice), have labels contain "Alice", "Bob", "Cathy", parent_labels contain "", "Alice", "Alice" and tooltips contain "CEO", "President", "VP Sales".
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "selectedNodeColorStyle"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub selected_node_color_style: Option<ColorStyle>,
     /**
      * An org chart. Org charts require a unique set of labels in labels and may optionally include parent_labels and tooltips. parent_labels contain, for each node, the label identifying the parent node. tooltips contain, for each node, an optional tooltip. For example, to describe an OrgChart with Alice as the CEO, Bob as the President (reporting to Alice) and Cathy as VP of Sales (also reporting to Alice), have labels contain "Alice", "Bob", "Cathy", parent_labels contain "", "Alice", "Alice" and tooltips contain "CEO", "President", "VP Sales".
@@ -7358,15 +7966,12 @@ pub struct OrgChartSpec {
 
 /// The location an object is overlaid on top of a grid.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct OverlayPosition {
     /**
      * The location an object is overlaid on top of a grid.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "anchorCell"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub anchor_cell: Option<GridCoordinate>,
     /**
      * The location an object is overlaid on top of a grid.
@@ -7374,8 +7979,7 @@ pub struct OverlayPosition {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "heightPixels"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub height_pixels: i64,
     /**
@@ -7384,8 +7988,7 @@ pub struct OverlayPosition {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "offsetXPixels"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub offset_x_pixels: i64,
     /**
@@ -7394,8 +7997,7 @@ pub struct OverlayPosition {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "offsetYPixels"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub offset_y_pixels: i64,
     /**
@@ -7404,8 +8006,7 @@ pub struct OverlayPosition {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "widthPixels"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub width_pixels: i64,
 }
@@ -7492,7 +8093,7 @@ pub struct PasteDataRequest {
 /**
  * Where the legend of the pie chart should be drawn.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PieChartSpecLegendPosition {
     #[serde(rename = "BOTTOM_LEGEND")]
     BottomLegend,
@@ -7538,7 +8139,38 @@ impl Default for PieChartSpecLegendPosition {
         PieChartSpecLegendPosition::Noop
     }
 }
+
 impl PieChartSpecLegendPosition {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PieChartSpecLegendPosition::BottomLegend => "BOTTOM_LEGEND",
+            PieChartSpecLegendPosition::LabeledLegend => "LABELED_LEGEND",
+            PieChartSpecLegendPosition::LeftLegend => "LEFT_LEGEND",
+            PieChartSpecLegendPosition::NoLegend => "NO_LEGEND",
+            PieChartSpecLegendPosition::PieChartLegendPositionUnspecified => {
+                "PIE_CHART_LEGEND_POSITION_UNSPECIFIED"
+            }
+            PieChartSpecLegendPosition::RightLegend => "RIGHT_LEGEND",
+            PieChartSpecLegendPosition::TopLegend => "TOP_LEGEND",
+            PieChartSpecLegendPosition::Noop => "",
+            PieChartSpecLegendPosition::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PieChartSpecLegendPosition] {
+        &[
+            PieChartSpecLegendPosition::BottomLegend,
+            PieChartSpecLegendPosition::LabeledLegend,
+            PieChartSpecLegendPosition::LeftLegend,
+            PieChartSpecLegendPosition::NoLegend,
+            PieChartSpecLegendPosition::PieChartLegendPositionUnspecified,
+            PieChartSpecLegendPosition::RightLegend,
+            PieChartSpecLegendPosition::TopLegend,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PieChartSpecLegendPosition::Noop)
     }
@@ -7546,6 +8178,7 @@ impl PieChartSpecLegendPosition {
 
 /// A pie chart.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct PieChartSpec {
     /**
      * A pie chart.
@@ -7555,11 +8188,7 @@ pub struct PieChartSpec {
     /**
      * A pie chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "legendPosition"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub legend_position: Option<PieChartSpecLegendPosition>,
     /**
      * A pie chart.
@@ -7567,8 +8196,7 @@ pub struct PieChartSpec {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "pieHole"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub pie_hole: f64,
     /**
@@ -7581,14 +8209,14 @@ pub struct PieChartSpec {
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "threeDimensional"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub three_dimensional: bool,
 }
 
 /// Criteria for showing/hiding rows in a pivot table.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct PivotFilterCriteria {
     /**
      * Criteria for showing/hiding rows in a pivot table.
@@ -7600,8 +8228,7 @@ pub struct PivotFilterCriteria {
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "visibleByDefault"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub visible_by_default: bool,
     /**
@@ -7610,14 +8237,14 @@ pub struct PivotFilterCriteria {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "visibleValues"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub visible_values: Vec<String>,
 }
 
 /// The pivot table filter criteria associated with a specific source column offset.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct PivotFilterSpec {
     /**
      * The pivot table filter criteria associated with a specific source column offset.
@@ -7625,34 +8252,25 @@ pub struct PivotFilterSpec {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "columnOffsetIndex"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub column_offset_index: i64,
     /**
      * The pivot table filter criteria associated with a specific source column offset.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "dataSourceColumnReference"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data_source_column_reference: Option<DataSourceColumnReference>,
     /**
      * The pivot table filter criteria associated with a specific source column offset.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "filterCriteria"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub filter_criteria: Option<PivotFilterCriteria>,
 }
 
 /**
  * The order the values in this group should be sorted.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SortOrder {
     #[serde(rename = "ASCENDING")]
     Ascending,
@@ -7684,7 +8302,28 @@ impl Default for SortOrder {
         SortOrder::Noop
     }
 }
+
 impl SortOrder {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SortOrder::Ascending => "ASCENDING",
+            SortOrder::Descending => "DESCENDING",
+            SortOrder::SortOrderUnspecified => "SORT_ORDER_UNSPECIFIED",
+            SortOrder::Noop => "",
+            SortOrder::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SortOrder] {
+        &[
+            SortOrder::Ascending,
+            SortOrder::Descending,
+            SortOrder::SortOrderUnspecified,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SortOrder::Noop)
     }
@@ -7692,29 +8331,22 @@ impl SortOrder {
 
 /// A single grouping (either row or column) in a pivot table.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct PivotGroup {
     /**
      * A single grouping (either row or column) in a pivot table.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "dataSourceColumnReference"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data_source_column_reference: Option<DataSourceColumnReference>,
     /**
      * A single grouping (either row or column) in a pivot table.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "groupLimit"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub group_limit: Option<PivotGroupLimit>,
     /**
      * A single grouping (either row or column) in a pivot table.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "groupRule")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub group_rule: Option<PivotGroupRule>,
     /**
      * A single grouping (either row or column) in a pivot table.
@@ -7730,8 +8362,7 @@ pub struct PivotGroup {
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "repeatHeadings"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub repeat_headings: bool,
     /**
@@ -7739,14 +8370,13 @@ pub struct PivotGroup {
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "showTotals"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub show_totals: bool,
     /**
      * A single grouping (either row or column) in a pivot table.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "sortOrder")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub sort_order: Option<SortOrder>,
     /**
      * A single grouping (either row or column) in a pivot table.
@@ -7754,18 +8384,13 @@ pub struct PivotGroup {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "sourceColumnOffset"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub source_column_offset: i64,
     /**
      * A single grouping (either row or column) in a pivot table.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "valueBucket"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub value_bucket: Option<PivotGroupSortValueBucket>,
     /**
      * A single grouping (either row or column) in a pivot table.
@@ -7773,14 +8398,14 @@ pub struct PivotGroup {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "valueMetadata"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub value_metadata: Vec<PivotGroupValueMetadata>,
 }
 
 /// The count limit on rows or columns in the pivot group.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct PivotGroupLimit {
     /**
      * The count limit on rows or columns in the pivot group.
@@ -7788,8 +8413,7 @@ pub struct PivotGroupLimit {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "applyOrder"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub apply_order: i64,
     /**
@@ -7798,46 +8422,35 @@ pub struct PivotGroupLimit {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "countLimit"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub count_limit: i64,
 }
 
 /// An optional setting on a PivotGroup that defines buckets for the values in the source data column rather than breaking out each individual value. Only one PivotGroup with a group rule may be added for each column in the source data, though on any given column you may add both a PivotGroup that has a rule and a PivotGroup that does not.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct PivotGroupRule {
     /**
      * An optional setting on a PivotGroup that defines buckets for the values in the source data column rather than breaking out each individual value. Only one PivotGroup with a group rule may be added for each column in the source data, though on any given column you may add both a PivotGroup that has a rule and a PivotGroup that does not.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "dateTimeRule"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub date_time_rule: Option<DateTimeRule>,
     /**
      * An optional setting on a PivotGroup that defines buckets for the values in the source data column rather than breaking out each individual value. Only one PivotGroup with a group rule may be added for each column in the source data, though on any given column you may add both a PivotGroup that has a rule and a PivotGroup that does not.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "histogramRule"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub histogram_rule: Option<HistogramRule>,
     /**
      * An optional setting on a PivotGroup that defines buckets for the values in the source data column rather than breaking out each individual value. Only one PivotGroup with a group rule may be added for each column in the source data, though on any given column you may add both a PivotGroup that has a rule and a PivotGroup that does not.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "manualRule"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub manual_rule: Option<ManualRule>,
 }
 
 /// Information about which values in a pivot group should be used for sorting.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct PivotGroupSortValueBucket {
     /**
      * Information about which values in a pivot group should be used for sorting.
@@ -7854,8 +8467,7 @@ pub struct PivotGroupSortValueBucket {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "valuesIndex"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub values_index: i64,
 }
@@ -7881,7 +8493,7 @@ pub struct PivotGroupValueMetadata {
 /**
  * Whether values should be listed horizontally (as columns) or vertically (as rows).
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ValueLayout {
     #[serde(rename = "HORIZONTAL")]
     Horizontal,
@@ -7910,7 +8522,23 @@ impl Default for ValueLayout {
         ValueLayout::Noop
     }
 }
+
 impl ValueLayout {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ValueLayout::Horizontal => "HORIZONTAL",
+            ValueLayout::Vertical => "VERTICAL",
+            ValueLayout::Noop => "",
+            ValueLayout::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ValueLayout] {
+        &[ValueLayout::Horizontal, ValueLayout::Vertical]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ValueLayout::Noop)
     }
@@ -7918,6 +8546,7 @@ impl ValueLayout {
 
 /// A pivot table.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct PivotTable {
     /**
      * A pivot table.
@@ -7936,11 +8565,7 @@ pub struct PivotTable {
     /**
      * A pivot table.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "dataExecutionStatus"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data_execution_status: Option<DataExecutionStatus>,
     /**
      * A pivot table.
@@ -7948,8 +8573,7 @@ pub struct PivotTable {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "dataSourceId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub data_source_id: String,
     /**
@@ -7958,8 +8582,7 @@ pub struct PivotTable {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "filterSpecs"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub filter_specs: Vec<PivotFilterSpec>,
     /**
@@ -7979,11 +8602,7 @@ pub struct PivotTable {
     /**
      * A pivot table.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "valueLayout"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub value_layout: Option<ValueLayout>,
     /**
      * A pivot table.
@@ -7999,7 +8618,7 @@ pub struct PivotTable {
 /**
  * If specified, indicates that pivot values should be displayed as the result of a calculation with another pivot value. For example, if calculated_display_type is specified as PERCENT_OF_GRAND_TOTAL, all the pivot values are displayed as the percentage of the grand total. In the Sheets editor, this is referred to as "Show As" in the value section of a pivot table.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum CalculatedDisplayType {
     #[serde(rename = "PERCENT_OF_COLUMN_TOTAL")]
     PercentOfColumnTotal,
@@ -8036,7 +8655,32 @@ impl Default for CalculatedDisplayType {
         CalculatedDisplayType::Noop
     }
 }
+
 impl CalculatedDisplayType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CalculatedDisplayType::PercentOfColumnTotal => "PERCENT_OF_COLUMN_TOTAL",
+            CalculatedDisplayType::PercentOfGrandTotal => "PERCENT_OF_GRAND_TOTAL",
+            CalculatedDisplayType::PercentOfRowTotal => "PERCENT_OF_ROW_TOTAL",
+            CalculatedDisplayType::PivotValueCalculatedDisplayTypeUnspecified => {
+                "PIVOT_VALUE_CALCULATED_DISPLAY_TYPE_UNSPECIFIED"
+            }
+            CalculatedDisplayType::Noop => "",
+            CalculatedDisplayType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [CalculatedDisplayType] {
+        &[
+            CalculatedDisplayType::PercentOfColumnTotal,
+            CalculatedDisplayType::PercentOfGrandTotal,
+            CalculatedDisplayType::PercentOfRowTotal,
+            CalculatedDisplayType::PivotValueCalculatedDisplayTypeUnspecified,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, CalculatedDisplayType::Noop)
     }
@@ -8045,7 +8689,7 @@ impl CalculatedDisplayType {
 /**
  * A function to summarize the value. If formula is set, the only supported values are SUM and CUSTOM. If sourceColumnOffset is set, then `CUSTOM` is not supported.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SummarizeFunction {
     #[serde(rename = "AVERAGE")]
     Average,
@@ -8115,7 +8759,54 @@ impl Default for SummarizeFunction {
         SummarizeFunction::Noop
     }
 }
+
 impl SummarizeFunction {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SummarizeFunction::Average => "AVERAGE",
+            SummarizeFunction::Count => "COUNT",
+            SummarizeFunction::Counta => "COUNTA",
+            SummarizeFunction::Countunique => "COUNTUNIQUE",
+            SummarizeFunction::Custom => "CUSTOM",
+            SummarizeFunction::Max => "MAX",
+            SummarizeFunction::Median => "MEDIAN",
+            SummarizeFunction::Min => "MIN",
+            SummarizeFunction::PivotStandardValueFunctionUnspecified => {
+                "PIVOT_STANDARD_VALUE_FUNCTION_UNSPECIFIED"
+            }
+            SummarizeFunction::Product => "PRODUCT",
+            SummarizeFunction::Stdev => "STDEV",
+            SummarizeFunction::Stdevp => "STDEVP",
+            SummarizeFunction::Sum => "SUM",
+            SummarizeFunction::Var => "VAR",
+            SummarizeFunction::Varp => "VARP",
+            SummarizeFunction::Noop => "",
+            SummarizeFunction::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SummarizeFunction] {
+        &[
+            SummarizeFunction::Average,
+            SummarizeFunction::Count,
+            SummarizeFunction::Counta,
+            SummarizeFunction::Countunique,
+            SummarizeFunction::Custom,
+            SummarizeFunction::Max,
+            SummarizeFunction::Median,
+            SummarizeFunction::Min,
+            SummarizeFunction::PivotStandardValueFunctionUnspecified,
+            SummarizeFunction::Product,
+            SummarizeFunction::Stdev,
+            SummarizeFunction::Stdevp,
+            SummarizeFunction::Sum,
+            SummarizeFunction::Var,
+            SummarizeFunction::Varp,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SummarizeFunction::Noop)
     }
@@ -8123,24 +8814,17 @@ impl SummarizeFunction {
 
 /// The definition of how a value in a pivot table should be calculated.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct PivotValue {
     /**
      * The definition of how a value in a pivot table should be calculated.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "calculatedDisplayType"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub calculated_display_type: Option<CalculatedDisplayType>,
     /**
      * The definition of how a value in a pivot table should be calculated.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "dataSourceColumnReference"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data_source_column_reference: Option<DataSourceColumnReference>,
     /**
      * The definition of how a value in a pivot table should be calculated.
@@ -8166,25 +8850,20 @@ pub struct PivotValue {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "sourceColumnOffset"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub source_column_offset: i64,
     /**
      * The definition of how a value in a pivot table should be calculated.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "summarizeFunction"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub summarize_function: Option<SummarizeFunction>,
 }
 
 /**
  * The point shape. If empty or unspecified, a default shape is used.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Shape {
     #[serde(rename = "CIRCLE")]
     Circle,
@@ -8234,7 +8913,40 @@ impl Default for Shape {
         Shape::Noop
     }
 }
+
 impl Shape {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Shape::Circle => "CIRCLE",
+            Shape::Diamond => "DIAMOND",
+            Shape::Hexagon => "HEXAGON",
+            Shape::Pentagon => "PENTAGON",
+            Shape::PointShapeUnspecified => "POINT_SHAPE_UNSPECIFIED",
+            Shape::Square => "SQUARE",
+            Shape::Star => "STAR",
+            Shape::Triangle => "TRIANGLE",
+            Shape::XMark => "X_MARK",
+            Shape::Noop => "",
+            Shape::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Shape] {
+        &[
+            Shape::Circle,
+            Shape::Diamond,
+            Shape::Hexagon,
+            Shape::Pentagon,
+            Shape::PointShapeUnspecified,
+            Shape::Square,
+            Shape::Star,
+            Shape::Triangle,
+            Shape::XMark,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Shape::Noop)
     }
@@ -8261,6 +8973,7 @@ pub struct PointStyle {
 
 /// A protected range.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ProtectedRange {
     /**
      * A protected range.
@@ -8282,8 +8995,7 @@ pub struct ProtectedRange {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "namedRangeId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub named_range_id: String,
     /**
@@ -8292,8 +9004,7 @@ pub struct ProtectedRange {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "protectedRangeId"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub protected_range_id: i64,
     /**
@@ -8306,8 +9017,7 @@ pub struct ProtectedRange {
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "requestingUserCanEdit"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub requesting_user_can_edit: bool,
     /**
@@ -8316,8 +9026,7 @@ pub struct ProtectedRange {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "unprotectedRanges"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub unprotected_ranges: Vec<GridRange>,
     /**
@@ -8325,8 +9034,7 @@ pub struct ProtectedRange {
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "warningOnly"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub warning_only: bool,
 }
@@ -8343,15 +9051,12 @@ pub struct RandomizeRangeRequest {
 
 /// The execution status of refreshing one data source object.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct RefreshDataSourceObjectExecutionStatus {
     /**
      * The execution status of refreshing one data source object.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "dataExecutionStatus"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data_execution_status: Option<DataExecutionStatus>,
     /**
      * The execution status of refreshing one data source object.
@@ -8362,6 +9067,7 @@ pub struct RefreshDataSourceObjectExecutionStatus {
 
 /// Refreshes one or multiple data source objects in the spreadsheet by the specified references. The request requires an additional `bigquery.readonly` OAuth scope. If there are multiple refresh requests referencing the same data source objects in one batch, only the last refresh request is processed, and all those requests will have the same response accordingly.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct RefreshDataSourceRequest {
     /**
      * Refreshes one or multiple data source objects in the spreadsheet by the specified references. The request requires an additional `bigquery.readonly` OAuth scope. If there are multiple refresh requests referencing the same data source objects in one batch, only the last refresh request is processed, and all those requests will have the same response accordingly.
@@ -8369,8 +9075,7 @@ pub struct RefreshDataSourceRequest {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "dataSourceId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub data_source_id: String,
     /**
@@ -8381,7 +9086,7 @@ pub struct RefreshDataSourceRequest {
     /**
      * Refreshes one or multiple data source objects in the spreadsheet by the specified references. The request requires an additional `bigquery.readonly` OAuth scope. If there are multiple refresh requests referencing the same data source objects in one batch, only the last refresh request is processed, and all those requests will have the same response accordingly.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "isAll")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub is_all: Option<bool>,
     /**
      * Refreshes one or multiple data source objects in the spreadsheet by the specified references. The request requires an additional `bigquery.readonly` OAuth scope. If there are multiple refresh requests referencing the same data source objects in one batch, only the last refresh request is processed, and all those requests will have the same response accordingly.
@@ -8430,759 +9135,453 @@ pub struct RepeatCellRequest {
 
 /// A single kind of update to apply to a spreadsheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Request {
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "addBanding"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub add_banding: Option<AddBandingRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "addChart")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub add_chart: Option<AddChartRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "addConditionalFormatRule"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub add_conditional_format_rule: Option<AddConditionalFormatRuleRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "addDataSource"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub add_data_source: Option<AddDataSourceRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "addDimensionGroup"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub add_dimension_group: Option<AddDimensionGroupRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "addFilterView"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub add_filter_view: Option<AddFilterViewRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "addNamedRange"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub add_named_range: Option<AddNamedRangeRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "addProtectedRange"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub add_protected_range: Option<AddProtectedRangeRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "addSheet")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub add_sheet: Option<AddSheetRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "addSlicer")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub add_slicer: Option<AddSlicerRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "appendCells"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub append_cells: Option<AppendCellsRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "appendDimension"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub append_dimension: Option<AppendDimensionRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "autoFill")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub auto_fill: Option<AutoFillRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "autoResizeDimensions"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub auto_resize_dimensions: Option<AutoResizeDimensionsRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "clearBasicFilter"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub clear_basic_filter: Option<ClearBasicFilterRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "copyPaste")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub copy_paste: Option<CopyPasteRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "createDeveloperMetadata"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub create_developer_metadata: Option<CreateDeveloperMetadataRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "cutPaste")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub cut_paste: Option<CutPasteRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "deleteBanding"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub delete_banding: Option<DeleteBandingRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "deleteConditionalFormatRule"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub delete_conditional_format_rule: Option<DeleteConditionalFormatRuleRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "deleteDataSource"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub delete_data_source: Option<DeleteDataSourceRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "deleteDeveloperMetadata"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub delete_developer_metadata: Option<DeleteDeveloperMetadataRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "deleteDimension"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub delete_dimension: Option<DeleteDimensionRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "deleteDimensionGroup"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub delete_dimension_group: Option<DeleteDimensionGroupRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "deleteDuplicates"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub delete_duplicates: Option<DeleteDuplicatesRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "deleteEmbeddedObject"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub delete_embedded_object: Option<DeleteEmbeddedObjectRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "deleteFilterView"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub delete_filter_view: Option<DeleteFilterViewRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "deleteNamedRange"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub delete_named_range: Option<DeleteNamedRangeRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "deleteProtectedRange"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub delete_protected_range: Option<DeleteProtectedRangeRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "deleteRange"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub delete_range: Option<DeleteRangeRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "deleteSheet"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub delete_sheet: Option<DeleteSheetRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "duplicateFilterView"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub duplicate_filter_view: Option<DuplicateFilterViewRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "duplicateSheet"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub duplicate_sheet: Option<DuplicateSheetRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "findReplace"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub find_replace: Option<FindReplaceRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "insertDimension"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub insert_dimension: Option<InsertDimensionRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "insertRange"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub insert_range: Option<InsertRangeRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "mergeCells"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub merge_cells: Option<MergeCellsRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "moveDimension"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub move_dimension: Option<MoveDimensionRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "pasteData")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub paste_data: Option<PasteDataRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "randomizeRange"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub randomize_range: Option<RandomizeRangeRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "refreshDataSource"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub refresh_data_source: Option<RefreshDataSourceRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "repeatCell"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub repeat_cell: Option<RepeatCellRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "setBasicFilter"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub set_basic_filter: Option<SetBasicFilterRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "setDataValidation"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub set_data_validation: Option<SetDataValidationRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "sortRange")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub sort_range: Option<SortRangeRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "textToColumns"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub text_to_columns: Option<TextColumnsRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "trimWhitespace"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub trim_whitespace: Option<TrimWhitespaceRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "unmergeCells"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub unmerge_cells: Option<UnmergeCellsRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "updateBanding"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub update_banding: Option<UpdateBandingRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "updateBorders"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub update_borders: Option<UpdateBordersRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "updateCells"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub update_cells: Option<UpdateCellsRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "updateChartSpec"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub update_chart_spec: Option<UpdateChartSpecRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "updateConditionalFormatRule"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub update_conditional_format_rule: Option<UpdateConditionalFormatRuleRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "updateDataSource"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub update_data_source: Option<UpdateDataSourceRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "updateDeveloperMetadata"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub update_developer_metadata: Option<UpdateDeveloperMetadataRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "updateDimensionGroup"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub update_dimension_group: Option<UpdateDimensionGroupRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "updateDimensionProperties"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub update_dimension_properties: Option<UpdateDimensionPropertiesRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "updateEmbeddedObjectBorder"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub update_embedded_object_border: Option<UpdateEmbeddedObjectBorderRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "updateEmbeddedObjectPosition"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub update_embedded_object_position: Option<UpdateEmbeddedObjectPositionRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "updateFilterView"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub update_filter_view: Option<UpdateFilterViewRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "updateNamedRange"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub update_named_range: Option<UpdateNamedRangeRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "updateProtectedRange"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub update_protected_range: Option<UpdateProtectedRangeRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "updateSheetProperties"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub update_sheet_properties: Option<UpdateSheetPropertiesRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "updateSlicerSpec"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub update_slicer_spec: Option<UpdateSlicerSpecRequest>,
     /**
      * A single kind of update to apply to a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "updateSpreadsheetProperties"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub update_spreadsheet_properties: Option<UpdateSpreadsheetPropertiesRequest>,
 }
 
 /// A single response from an update.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Response {
     /**
      * A single response from an update.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "addBanding"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub add_banding: Option<AddBandingResponse>,
     /**
      * A single response from an update.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "addChart")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub add_chart: Option<AddChartResponse>,
     /**
      * A single response from an update.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "addDataSource"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub add_data_source: Option<AddDataSourceResponse>,
     /**
      * A single response from an update.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "addDimensionGroup"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub add_dimension_group: Option<AddDimensionGroupResponse>,
     /**
      * A single response from an update.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "addFilterView"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub add_filter_view: Option<AddFilterViewResponse>,
     /**
      * A single response from an update.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "addNamedRange"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub add_named_range: Option<AddNamedRangeResponse>,
     /**
      * A single response from an update.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "addProtectedRange"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub add_protected_range: Option<AddProtectedRangeResponse>,
     /**
      * A single response from an update.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "addSheet")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub add_sheet: Option<AddSheetResponse>,
     /**
      * A single response from an update.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "addSlicer")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub add_slicer: Option<AddSlicerResponse>,
     /**
      * A single response from an update.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "createDeveloperMetadata"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub create_developer_metadata: Option<CreateDeveloperMetadataResponse>,
     /**
      * A single response from an update.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "deleteConditionalFormatRule"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub delete_conditional_format_rule: Option<DeleteConditionalFormatRuleResponse>,
     /**
      * A single response from an update.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "deleteDeveloperMetadata"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub delete_developer_metadata: Option<DeleteDeveloperMetadataResponse>,
     /**
      * A single response from an update.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "deleteDimensionGroup"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub delete_dimension_group: Option<DeleteDimensionGroupResponse>,
     /**
      * A single response from an update.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "deleteDuplicates"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub delete_duplicates: Option<DeleteDuplicatesResponse>,
     /**
      * A single response from an update.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "duplicateFilterView"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub duplicate_filter_view: Option<DuplicateFilterViewResponse>,
     /**
      * A single response from an update.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "duplicateSheet"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub duplicate_sheet: Option<DuplicateSheetResponse>,
     /**
      * A single response from an update.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "findReplace"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub find_replace: Option<FindReplaceResponse>,
     /**
      * A single response from an update.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "refreshDataSource"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub refresh_data_source: Option<RefreshDataSourceResponse>,
     /**
      * A single response from an update.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "trimWhitespace"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub trim_whitespace: Option<TrimWhitespaceResponse>,
     /**
      * A single response from an update.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "updateConditionalFormatRule"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub update_conditional_format_rule: Option<UpdateConditionalFormatRuleResponse>,
     /**
      * A single response from an update.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "updateDataSource"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub update_data_source: Option<UpdateDataSourceResponse>,
     /**
      * A single response from an update.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "updateDeveloperMetadata"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub update_developer_metadata: Option<UpdateDeveloperMetadataResponse>,
     /**
      * A single response from an update.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "updateEmbeddedObjectPosition"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub update_embedded_object_position: Option<UpdateEmbeddedObjectPositionResponse>,
 }
 
@@ -9203,7 +9602,7 @@ pub struct RowData {
 /**
  * The number format source used in the scorecard chart. This field is optional.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum NumberFormatSource {
     #[serde(rename = "CHART_NUMBER_FORMAT_SOURCE_UNDEFINED")]
     ChartNumberFormatSourceUndefined,
@@ -9237,7 +9636,30 @@ impl Default for NumberFormatSource {
         NumberFormatSource::Noop
     }
 }
+
 impl NumberFormatSource {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NumberFormatSource::ChartNumberFormatSourceUndefined => {
+                "CHART_NUMBER_FORMAT_SOURCE_UNDEFINED"
+            }
+            NumberFormatSource::Custom => "CUSTOM",
+            NumberFormatSource::FromData => "FROM_DATA",
+            NumberFormatSource::Noop => "",
+            NumberFormatSource::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [NumberFormatSource] {
+        &[
+            NumberFormatSource::ChartNumberFormatSourceUndefined,
+            NumberFormatSource::Custom,
+            NumberFormatSource::FromData,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, NumberFormatSource::Noop)
     }
@@ -9245,69 +9667,42 @@ impl NumberFormatSource {
 
 /// A scorecard chart. Scorecard charts are used to highlight key performance indicators, known as KPIs, on the spreadsheet. A scorecard chart can represent things like total sales, average cost, or a top selling item. You can specify a single data value, or aggregate over a range of data. Percentage or absolute difference from a baseline value can be highlighted, like changes over time.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ScorecardChartSpec {
     /**
      * A scorecard chart. Scorecard charts are used to highlight key performance indicators, known as KPIs, on the spreadsheet. A scorecard chart can represent things like total sales, average cost, or a top selling item. You can specify a single data value, or aggregate over a range of data. Percentage or absolute difference from a baseline value can be highlighted, like changes over time.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "aggregateType"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub aggregate_type: Option<AggregateType>,
     /**
      * A scorecard chart. Scorecard charts are used to highlight key performance indicators, known as KPIs, on the spreadsheet. A scorecard chart can represent things like total sales, average cost, or a top selling item. You can specify a single data value, or aggregate over a range of data. Percentage or absolute difference from a baseline value can be highlighted, like changes over time.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "baselineValueData"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub baseline_value_data: Option<ChartData>,
     /**
      * A scorecard chart. Scorecard charts are used to highlight key performance indicators, known as KPIs, on the spreadsheet. A scorecard chart can represent things like total sales, average cost, or a top selling item. You can specify a single data value, or aggregate over a range of data. Percentage or absolute difference from a baseline value can be highlighted, like changes over time.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "baselineValueFormat"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub baseline_value_format: Option<BaselineValueFormat>,
     /**
      * A scorecard chart. Scorecard charts are used to highlight key performance indicators, known as KPIs, on the spreadsheet. A scorecard chart can represent things like total sales, average cost, or a top selling item. You can specify a single data value, or aggregate over a range of data. Percentage or absolute difference from a baseline value can be highlighted, like changes over time.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "customFormatOptions"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub custom_format_options: Option<ChartCustomNumberFormatOptions>,
     /**
      * A scorecard chart. Scorecard charts are used to highlight key performance indicators, known as KPIs, on the spreadsheet. A scorecard chart can represent things like total sales, average cost, or a top selling item. You can specify a single data value, or aggregate over a range of data. Percentage or absolute difference from a baseline value can be highlighted, like changes over time.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "keyValueData"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub key_value_data: Option<ChartData>,
     /**
      * A scorecard chart. Scorecard charts are used to highlight key performance indicators, known as KPIs, on the spreadsheet. A scorecard chart can represent things like total sales, average cost, or a top selling item. You can specify a single data value, or aggregate over a range of data. Percentage or absolute difference from a baseline value can be highlighted, like changes over time.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "keyValueFormat"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub key_value_format: Option<KeyValueFormat>,
     /**
      * A scorecard chart. Scorecard charts are used to highlight key performance indicators, known as KPIs, on the spreadsheet. A scorecard chart can represent things like total sales, average cost, or a top selling item. You can specify a single data value, or aggregate over a range of data. Percentage or absolute difference from a baseline value can be highlighted, like changes over time.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "numberFormatSource"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub number_format_source: Option<NumberFormatSource>,
     /**
      * A scorecard chart. Scorecard charts are used to highlight key performance indicators, known as KPIs, on the spreadsheet. A scorecard chart can represent things like total sales, average cost, or a top selling item. You can specify a single data value, or aggregate over a range of data. Percentage or absolute difference from a baseline value can be highlighted, like changes over time.
@@ -9315,14 +9710,14 @@ pub struct ScorecardChartSpec {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "scaleFactor"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub scale_factor: f64,
 }
 
 /// A request to retrieve all developer metadata matching the set of specified criteria.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct SearchDeveloperMetadataRequest {
     /**
      * A request to retrieve all developer metadata matching the set of specified criteria.
@@ -9330,14 +9725,14 @@ pub struct SearchDeveloperMetadataRequest {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "dataFilters"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub data_filters: Vec<DataFilter>,
 }
 
 /// A reply to a developer metadata search request.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct SearchDeveloperMetadataResponse {
     /**
      * A reply to a developer metadata search request.
@@ -9345,8 +9740,7 @@ pub struct SearchDeveloperMetadataResponse {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "matchedDeveloperMetadata"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub matched_developer_metadata: Vec<MatchedDeveloperMetadata>,
 }
@@ -9378,6 +9772,7 @@ pub struct SetDataValidationRequest {
 
 /// A sheet in a spreadsheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Sheet {
     /**
      * A sheet in a spreadsheet.
@@ -9385,18 +9780,13 @@ pub struct Sheet {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "bandedRanges"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub banded_ranges: Vec<BandedRange>,
     /**
      * A sheet in a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "basicFilter"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub basic_filter: Option<BasicFilter>,
     /**
      * A sheet in a spreadsheet.
@@ -9413,8 +9803,7 @@ pub struct Sheet {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "columnGroups"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub column_groups: Vec<DimensionGroup>,
     /**
@@ -9423,8 +9812,7 @@ pub struct Sheet {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "conditionalFormats"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub conditional_formats: Vec<ConditionalFormatRule>,
     /**
@@ -9442,8 +9830,7 @@ pub struct Sheet {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "developerMetadata"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub developer_metadata: Vec<DeveloperMetadata>,
     /**
@@ -9452,8 +9839,7 @@ pub struct Sheet {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "filterViews"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub filter_views: Vec<FilterView>,
     /**
@@ -9476,8 +9862,7 @@ pub struct Sheet {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "protectedRanges"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub protected_ranges: Vec<ProtectedRange>,
     /**
@@ -9486,8 +9871,7 @@ pub struct Sheet {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "rowGroups"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub row_groups: Vec<DimensionGroup>,
     /**
@@ -9504,7 +9888,7 @@ pub struct Sheet {
 /**
  * The type of sheet. Defaults to GRID. This field cannot be changed once set.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SheetType {
     #[serde(rename = "DATA_SOURCE")]
     DataSource,
@@ -9539,7 +9923,30 @@ impl Default for SheetType {
         SheetType::Noop
     }
 }
+
 impl SheetType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SheetType::DataSource => "DATA_SOURCE",
+            SheetType::Grid => "GRID",
+            SheetType::Object => "OBJECT",
+            SheetType::SheetTypeUnspecified => "SHEET_TYPE_UNSPECIFIED",
+            SheetType::Noop => "",
+            SheetType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SheetType] {
+        &[
+            SheetType::DataSource,
+            SheetType::Grid,
+            SheetType::Object,
+            SheetType::SheetTypeUnspecified,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SheetType::Noop)
     }
@@ -9547,24 +9954,17 @@ impl SheetType {
 
 /// Properties of a sheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct SheetProperties {
     /**
      * Properties of a sheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "dataSourceSheetProperties"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data_source_sheet_properties: Option<DataSourceSheetProperties>,
     /**
      * Properties of a sheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "gridProperties"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub grid_properties: Option<GridProperties>,
     /**
      * Properties of a sheet.
@@ -9588,8 +9988,7 @@ pub struct SheetProperties {
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "rightToLeft"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub right_to_left: bool,
     /**
@@ -9598,28 +9997,23 @@ pub struct SheetProperties {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "sheetId"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub sheet_id: i64,
     /**
      * Properties of a sheet.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "sheetType")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub sheet_type: Option<SheetType>,
     /**
      * Properties of a sheet.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "tabColor")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tab_color: Option<Color>,
     /**
      * Properties of a sheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "tabColorStyle"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tab_color_style: Option<ColorStyle>,
     /**
      * Properties of a sheet.
@@ -9634,6 +10028,7 @@ pub struct SheetProperties {
 
 /// A slicer in a sheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Slicer {
     /**
      * A slicer in a sheet.
@@ -9646,8 +10041,7 @@ pub struct Slicer {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "slicerId"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub slicer_id: i64,
     /**
@@ -9659,33 +10053,25 @@ pub struct Slicer {
 
 /// The specifications of a slicer.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct SlicerSpec {
     /**
      * The specifications of a slicer.
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "applyToPivotTables"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub apply_to_pivot_tables: bool,
     /**
      * The specifications of a slicer.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "backgroundColor"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub background_color: Option<Color>,
     /**
      * The specifications of a slicer.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "backgroundColorStyle"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub background_color_style: Option<ColorStyle>,
     /**
      * The specifications of a slicer.
@@ -9693,41 +10079,28 @@ pub struct SlicerSpec {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "columnIndex"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub column_index: i64,
     /**
      * The specifications of a slicer.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "dataRange")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data_range: Option<GridRange>,
     /**
      * The specifications of a slicer.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "filterCriteria"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub filter_criteria: Option<FilterCriteria>,
     /**
      * The specifications of a slicer.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "horizontalAlignment"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub horizontal_alignment: Option<HorizontalAlignment>,
     /**
      * The specifications of a slicer.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "textFormat"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub text_format: Option<TextFormat>,
     /**
      * The specifications of a slicer.
@@ -9742,6 +10115,7 @@ pub struct SlicerSpec {
 
 /// Sorts data in rows based on a sort order per column.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct SortRangeRequest {
     /**
      * Sorts data in rows based on a sort order per column.
@@ -9754,41 +10128,29 @@ pub struct SortRangeRequest {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "sortSpecs"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub sort_specs: Vec<SortSpec>,
 }
 
 /// A sort order associated with a specific column or row.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct SortSpec {
     /**
      * A sort order associated with a specific column or row.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "backgroundColor"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub background_color: Option<Color>,
     /**
      * A sort order associated with a specific column or row.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "backgroundColorStyle"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub background_color_style: Option<ColorStyle>,
     /**
      * A sort order associated with a specific column or row.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "dataSourceColumnReference"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data_source_column_reference: Option<DataSourceColumnReference>,
     /**
      * A sort order associated with a specific column or row.
@@ -9796,37 +10158,29 @@ pub struct SortSpec {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "dimensionIndex"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub dimension_index: i64,
     /**
      * A sort order associated with a specific column or row.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "foregroundColor"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub foreground_color: Option<Color>,
     /**
      * A sort order associated with a specific column or row.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "foregroundColorStyle"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub foreground_color_style: Option<ColorStyle>,
     /**
      * A sort order associated with a specific column or row.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "sortOrder")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub sort_order: Option<SortOrder>,
 }
 
 /// A combination of a source range and how to extend that source.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct SourceDestination {
     /**
      * A combination of a source range and how to extend that source.
@@ -9839,8 +10193,7 @@ pub struct SourceDestination {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "fillLength"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub fill_length: i64,
     /**
@@ -9852,6 +10205,7 @@ pub struct SourceDestination {
 
 /// Resource that represents a spreadsheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Spreadsheet {
     /**
      * Resource that represents a spreadsheet.
@@ -9859,8 +10213,7 @@ pub struct Spreadsheet {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "dataSourceSchedules"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub data_source_schedules: Vec<DataSourceRefreshSchedule>,
     /**
@@ -9869,8 +10222,7 @@ pub struct Spreadsheet {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "dataSources"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub data_sources: Vec<DataSource>,
     /**
@@ -9879,8 +10231,7 @@ pub struct Spreadsheet {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "developerMetadata"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub developer_metadata: Vec<DeveloperMetadata>,
     /**
@@ -9889,8 +10240,7 @@ pub struct Spreadsheet {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "namedRanges"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub named_ranges: Vec<NamedRange>,
     /**
@@ -9913,8 +10263,7 @@ pub struct Spreadsheet {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "spreadsheetId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub spreadsheet_id: String,
     /**
@@ -9923,8 +10272,7 @@ pub struct Spreadsheet {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "spreadsheetUrl"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub spreadsheet_url: String,
 }
@@ -9932,7 +10280,7 @@ pub struct Spreadsheet {
 /**
  * The amount of time to wait before volatile functions are recalculated.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum AutoRecalc {
     #[serde(rename = "HOUR")]
     Hour,
@@ -9967,7 +10315,30 @@ impl Default for AutoRecalc {
         AutoRecalc::Noop
     }
 }
+
 impl AutoRecalc {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AutoRecalc::Hour => "HOUR",
+            AutoRecalc::Minute => "MINUTE",
+            AutoRecalc::OnChange => "ON_CHANGE",
+            AutoRecalc::RecalculationIntervalUnspecified => "RECALCULATION_INTERVAL_UNSPECIFIED",
+            AutoRecalc::Noop => "",
+            AutoRecalc::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [AutoRecalc] {
+        &[
+            AutoRecalc::Hour,
+            AutoRecalc::Minute,
+            AutoRecalc::OnChange,
+            AutoRecalc::RecalculationIntervalUnspecified,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, AutoRecalc::Noop)
     }
@@ -9975,33 +10346,22 @@ impl AutoRecalc {
 
 /// Properties of a spreadsheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct SpreadsheetProperties {
     /**
      * Properties of a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "autoRecalc"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub auto_recalc: Option<AutoRecalc>,
     /**
      * Properties of a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "defaultFormat"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub default_format: Option<CellFormat>,
     /**
      * Properties of a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "iterativeCalculationSettings"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub iterative_calculation_settings: Option<IterativeCalculationSettings>,
     /**
      * Properties of a spreadsheet.
@@ -10015,11 +10375,7 @@ pub struct SpreadsheetProperties {
     /**
      * Properties of a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "spreadsheetTheme"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub spreadsheet_theme: Option<SpreadsheetTheme>,
     /**
      * Properties of a spreadsheet.
@@ -10027,8 +10383,7 @@ pub struct SpreadsheetProperties {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "timeZone"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub time_zone: String,
     /**
@@ -10044,6 +10399,7 @@ pub struct SpreadsheetProperties {
 
 /// Represents spreadsheet theme
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct SpreadsheetTheme {
     /**
      * Represents spreadsheet theme
@@ -10051,8 +10407,7 @@ pub struct SpreadsheetTheme {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "primaryFontFamily"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub primary_font_family: String,
     /**
@@ -10061,14 +10416,14 @@ pub struct SpreadsheetTheme {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "themeColors"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub theme_colors: Vec<ThemeColorPair>,
 }
 
 /// The format of a run of text in a cell. Absent values indicate that the field isn't specified.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct TextFormat {
     /**
      * The format of a run of text in a cell. Absent values indicate that the field isn't specified.
@@ -10084,8 +10439,7 @@ pub struct TextFormat {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "fontFamily"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub font_family: String,
     /**
@@ -10094,27 +10448,18 @@ pub struct TextFormat {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "fontSize"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub font_size: i64,
     /**
      * The format of a run of text in a cell. Absent values indicate that the field isn't specified.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "foregroundColor"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub foreground_color: Option<Color>,
     /**
      * The format of a run of text in a cell. Absent values indicate that the field isn't specified.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "foregroundColorStyle"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub foreground_color_style: Option<ColorStyle>,
     /**
      * The format of a run of text in a cell. Absent values indicate that the field isn't specified.
@@ -10149,6 +10494,7 @@ pub struct TextFormat {
 
 /// A run of a text format. The format of this run continues until the start index of the next run. When updating, all fields must be set.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct TextFormatRun {
     /**
      * A run of a text format. The format of this run continues until the start index of the next run. When updating, all fields must be set.
@@ -10161,23 +10507,19 @@ pub struct TextFormatRun {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "startIndex"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub start_index: i64,
 }
 
 /// Position settings for text.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct TextPosition {
     /**
      * Position settings for text.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "horizontalAlignment"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub horizontal_alignment: Option<HorizontalAlignment>,
 }
 
@@ -10206,7 +10548,7 @@ pub struct TextRotation {
 /**
  * The delimiter type to use.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum DelimiterType {
     #[serde(rename = "AUTODETECT")]
     Autodetect,
@@ -10250,7 +10592,36 @@ impl Default for DelimiterType {
         DelimiterType::Noop
     }
 }
+
 impl DelimiterType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DelimiterType::Autodetect => "AUTODETECT",
+            DelimiterType::Comma => "COMMA",
+            DelimiterType::Custom => "CUSTOM",
+            DelimiterType::DelimiterTypeUnspecified => "DELIMITER_TYPE_UNSPECIFIED",
+            DelimiterType::Period => "PERIOD",
+            DelimiterType::Semicolon => "SEMICOLON",
+            DelimiterType::Space => "SPACE",
+            DelimiterType::Noop => "",
+            DelimiterType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [DelimiterType] {
+        &[
+            DelimiterType::Autodetect,
+            DelimiterType::Comma,
+            DelimiterType::Custom,
+            DelimiterType::DelimiterTypeUnspecified,
+            DelimiterType::Period,
+            DelimiterType::Semicolon,
+            DelimiterType::Space,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, DelimiterType::Noop)
     }
@@ -10258,6 +10629,7 @@ impl DelimiterType {
 
 /// Splits a column of text into multiple columns, based on a delimiter in each cell.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct TextColumnsRequest {
     /**
      * Splits a column of text into multiple columns, based on a delimiter in each cell.
@@ -10271,11 +10643,7 @@ pub struct TextColumnsRequest {
     /**
      * Splits a column of text into multiple columns, based on a delimiter in each cell.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "delimiterType"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub delimiter_type: Option<DelimiterType>,
     /**
      * Splits a column of text into multiple columns, based on a delimiter in each cell.
@@ -10286,6 +10654,7 @@ pub struct TextColumnsRequest {
 
 /// A pair mapping a spreadsheet theme color type to the concrete color it represents.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ThemeColorPair {
     /**
      * A pair mapping a spreadsheet theme color type to the concrete color it represents.
@@ -10295,7 +10664,7 @@ pub struct ThemeColorPair {
     /**
      * A pair mapping a spreadsheet theme color type to the concrete color it represents.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "colorType")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub color_type: Option<ColorType>,
 }
 
@@ -10342,123 +10711,80 @@ pub struct TimeOfDay {
 
 /// A color scale for a treemap chart.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct TreemapChartColorScale {
     /**
      * A color scale for a treemap chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "maxValueColor"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub max_value_color: Option<Color>,
     /**
      * A color scale for a treemap chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "maxValueColorStyle"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub max_value_color_style: Option<ColorStyle>,
     /**
      * A color scale for a treemap chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "midValueColor"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub mid_value_color: Option<Color>,
     /**
      * A color scale for a treemap chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "midValueColorStyle"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub mid_value_color_style: Option<ColorStyle>,
     /**
      * A color scale for a treemap chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "minValueColor"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub min_value_color: Option<Color>,
     /**
      * A color scale for a treemap chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "minValueColorStyle"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub min_value_color_style: Option<ColorStyle>,
     /**
      * A color scale for a treemap chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "noDataColor"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub no_data_color: Option<Color>,
     /**
      * A color scale for a treemap chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "noDataColorStyle"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub no_data_color_style: Option<ColorStyle>,
 }
 
 /// A Treemap chart.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct TreemapChartSpec {
     /**
      * A Treemap chart.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "colorData")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub color_data: Option<ChartData>,
     /**
      * A Treemap chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "colorScale"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub color_scale: Option<TreemapChartColorScale>,
     /**
      * A Treemap chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "headerColor"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub header_color: Option<Color>,
     /**
      * A Treemap chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "headerColorStyle"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub header_color_style: Option<ColorStyle>,
     /**
      * A Treemap chart.
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "hideTooltips"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub hide_tooltips: bool,
     /**
@@ -10467,8 +10793,7 @@ pub struct TreemapChartSpec {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "hintedLevels"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub hinted_levels: i64,
     /**
@@ -10491,8 +10816,7 @@ pub struct TreemapChartSpec {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "maxValue"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub max_value: f64,
     /**
@@ -10501,32 +10825,23 @@ pub struct TreemapChartSpec {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "minValue"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub min_value: f64,
     /**
      * A Treemap chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "parentLabels"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub parent_labels: Option<ChartData>,
     /**
      * A Treemap chart.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "sizeData")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub size_data: Option<ChartData>,
     /**
      * A Treemap chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "textFormat"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub text_format: Option<TextFormat>,
 }
 
@@ -10542,6 +10857,7 @@ pub struct TrimWhitespaceRequest {
 
 /// The result of trimming whitespace in cells.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct TrimWhitespaceResponse {
     /**
      * The result of trimming whitespace in cells.
@@ -10549,8 +10865,7 @@ pub struct TrimWhitespaceResponse {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "cellsChangedCount"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub cells_changed_count: i64,
 }
@@ -10567,15 +10882,12 @@ pub struct UnmergeCellsRequest {
 
 /// Updates properties of the supplied banded range.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UpdateBandingRequest {
     /**
      * Updates properties of the supplied banded range.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "bandedRange"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub banded_range: Option<BandedRange>,
     /**
      * Updates properties of the supplied banded range.
@@ -10590,6 +10902,7 @@ pub struct UpdateBandingRequest {
 
 /// Updates the borders of a range. If a field is not set in the request, that means the border remains as-is. For example, with two subsequent UpdateBordersRequest: 1. range: A1:A5 `{ top: RED, bottom: WHITE }` 2. range: A1:A5 `{ left: BLUE }` That would result in A1:A5 having a borders of `{ top: RED, bottom: WHITE, left: BLUE }`. If you want to clear a border, explicitly set the style to NONE.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UpdateBordersRequest {
     /**
      * Updates the borders of a range. If a field is not set in the request, that means the border remains as-is. For example, with two subsequent UpdateBordersRequest: 1. range: A1:A5 `{ top: RED, bottom: WHITE }` 2. range: A1:A5 `{ left: BLUE }` That would result in A1:A5 having a borders of `{ top: RED, bottom: WHITE, left: BLUE }`. If you want to clear a border, explicitly set the style to NONE.
@@ -10597,22 +10910,14 @@ pub struct UpdateBordersRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub bottom: Option<Border>,
     /**
-     * Updates the borders of a range. If a field is not set in the request, that means the border remains as-is. For example, with two subsequent UpdateBordersRequest: 1. range: A1:A5 `{ top: RED, bottom: WHITE }` 2. range: A1:A5 `{ left: BLUE }` That would result in A1:A5 having a borders of `{ top: RED, bottom: WHITE, left: BLUE }`. If you want to clear a border, explicitly set the style to NONE.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "innerHorizontal"
-    )]
+     * Updates the borders of a range. If a field is not set in the request, that means the border remains as-is. For example, with two subsequent UpdateBordersRequest: 1. range: A1:A5 `{ top: RED, bottom: WHITE }` 2. range: A1:A5 `{ left: BLUE }` That would result in A1:A5 having a borders of `{ top: RED, bottom: WHITE, left: BLUE }`. If you want to clear a border, explicitly set the style to NONE.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub inner_horizontal: Option<Border>,
     /**
      * Updates the borders of a range. If a field is not set in the request, that means the border remains as-is. For example, with two subsequent UpdateBordersRequest: 1. range: A1:A5 `{ top: RED, bottom: WHITE }` 2. range: A1:A5 `{ left: BLUE }` That would result in A1:A5 having a borders of `{ top: RED, bottom: WHITE, left: BLUE }`. If you want to clear a border, explicitly set the style to NONE.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "innerVertical"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub inner_vertical: Option<Border>,
     /**
      * Updates the borders of a range. If a field is not set in the request, that means the border remains as-is. For example, with two subsequent UpdateBordersRequest: 1. range: A1:A5 `{ top: RED, bottom: WHITE }` 2. range: A1:A5 `{ left: BLUE }` That would result in A1:A5 having a borders of `{ top: RED, bottom: WHITE, left: BLUE }`. If you want to clear a border, explicitly set the style to NONE.
@@ -10671,6 +10976,7 @@ pub struct UpdateCellsRequest {
 
 /// Updates a chart's specifications. (This does not move or resize a chart. To move or resize a chart, use UpdateEmbeddedObjectPositionRequest.)
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UpdateChartSpecRequest {
     /**
      * Updates a chart's specifications. (This does not move or resize a chart. To move or resize a chart, use UpdateEmbeddedObjectPositionRequest.)
@@ -10678,8 +10984,7 @@ pub struct UpdateChartSpecRequest {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "chartId"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub chart_id: i64,
     /**
@@ -10691,6 +10996,7 @@ pub struct UpdateChartSpecRequest {
 
 /// Updates a conditional format rule at the given index, or moves a conditional format rule to another index.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UpdateConditionalFormatRuleRequest {
     /**
      * Updates a conditional format rule at the given index, or moves a conditional format rule to another index.
@@ -10707,8 +11013,7 @@ pub struct UpdateConditionalFormatRuleRequest {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "newIndex"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub new_index: i64,
     /**
@@ -10722,14 +11027,14 @@ pub struct UpdateConditionalFormatRuleRequest {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "sheetId"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub sheet_id: i64,
 }
 
 /// The result of updating a conditional format rule.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UpdateConditionalFormatRuleResponse {
     /**
      * The result of updating a conditional format rule.
@@ -10737,14 +11042,13 @@ pub struct UpdateConditionalFormatRuleResponse {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "newIndex"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub new_index: i64,
     /**
      * The result of updating a conditional format rule.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "newRule")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub new_rule: Option<ConditionalFormatRule>,
     /**
      * The result of updating a conditional format rule.
@@ -10752,28 +11056,24 @@ pub struct UpdateConditionalFormatRuleResponse {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "oldIndex"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub old_index: i64,
     /**
      * The result of updating a conditional format rule.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "oldRule")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub old_rule: Option<ConditionalFormatRule>,
 }
 
 /// Updates a data source. After the data source is updated successfully, an execution is triggered to refresh the associated DATA_SOURCE sheet to read data from the updated data source. The request requires an additional `bigquery.readonly` OAuth scope.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UpdateDataSourceRequest {
     /**
      * Updates a data source. After the data source is updated successfully, an execution is triggered to refresh the associated DATA_SOURCE sheet to read data from the updated data source. The request requires an additional `bigquery.readonly` OAuth scope.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "dataSource"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data_source: Option<DataSource>,
     /**
      * Updates a data source. After the data source is updated successfully, an execution is triggered to refresh the associated DATA_SOURCE sheet to read data from the updated data source. The request requires an additional `bigquery.readonly` OAuth scope.
@@ -10788,29 +11088,23 @@ pub struct UpdateDataSourceRequest {
 
 /// The response from updating data source.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UpdateDataSourceResponse {
     /**
      * The response from updating data source.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "dataExecutionStatus"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data_execution_status: Option<DataExecutionStatus>,
     /**
      * The response from updating data source.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "dataSource"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data_source: Option<DataSource>,
 }
 
 /// A request to update properties of developer metadata. Updates the properties of the developer metadata selected by the filters to the values provided in the DeveloperMetadata resource. Callers must specify the properties they wish to update in the fields parameter, as well as specify at least one DataFilter matching the metadata they wish to update.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UpdateDeveloperMetadataRequest {
     /**
      * A request to update properties of developer metadata. Updates the properties of the developer metadata selected by the filters to the values provided in the DeveloperMetadata resource. Callers must specify the properties they wish to update in the fields parameter, as well as specify at least one DataFilter matching the metadata they wish to update.
@@ -10818,18 +11112,13 @@ pub struct UpdateDeveloperMetadataRequest {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "dataFilters"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub data_filters: Vec<DataFilter>,
     /**
      * A request to update properties of developer metadata. Updates the properties of the developer metadata selected by the filters to the values provided in the DeveloperMetadata resource. Callers must specify the properties they wish to update in the fields parameter, as well as specify at least one DataFilter matching the metadata they wish to update.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "developerMetadata"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub developer_metadata: Option<DeveloperMetadata>,
     /**
      * A request to update properties of developer metadata. Updates the properties of the developer metadata selected by the filters to the values provided in the DeveloperMetadata resource. Callers must specify the properties they wish to update in the fields parameter, as well as specify at least one DataFilter matching the metadata they wish to update.
@@ -10844,6 +11133,7 @@ pub struct UpdateDeveloperMetadataRequest {
 
 /// The response from updating developer metadata.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UpdateDeveloperMetadataResponse {
     /**
      * The response from updating developer metadata.
@@ -10851,23 +11141,19 @@ pub struct UpdateDeveloperMetadataResponse {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "developerMetadata"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub developer_metadata: Vec<DeveloperMetadata>,
 }
 
 /// Updates the state of the specified group.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UpdateDimensionGroupRequest {
     /**
      * Updates the state of the specified group.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "dimensionGroup"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub dimension_group: Option<DimensionGroup>,
     /**
      * Updates the state of the specified group.
@@ -10882,15 +11168,12 @@ pub struct UpdateDimensionGroupRequest {
 
 /// Updates properties of dimensions within the specified range.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UpdateDimensionPropertiesRequest {
     /**
      * Updates properties of dimensions within the specified range.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "dataSourceSheetRange"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data_source_sheet_range: Option<DataSourceSheetDimensionRange>,
     /**
      * Updates properties of dimensions within the specified range.
@@ -10915,6 +11198,7 @@ pub struct UpdateDimensionPropertiesRequest {
 
 /// Updates an embedded object's border property.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UpdateEmbeddedObjectBorderRequest {
     /**
      * Updates an embedded object's border property.
@@ -10936,14 +11220,14 @@ pub struct UpdateEmbeddedObjectBorderRequest {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "objectId"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub object_id: i64,
 }
 
 /// Update an embedded object's position (such as a moving or resizing a chart or image).
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UpdateEmbeddedObjectPositionRequest {
     /**
      * Update an embedded object's position (such as a moving or resizing a chart or image).
@@ -10957,11 +11241,7 @@ pub struct UpdateEmbeddedObjectPositionRequest {
     /**
      * Update an embedded object's position (such as a moving or resizing a chart or image).
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "newPosition"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub new_position: Option<EmbeddedObjectPosition>,
     /**
      * Update an embedded object's position (such as a moving or resizing a chart or image).
@@ -10969,8 +11249,7 @@ pub struct UpdateEmbeddedObjectPositionRequest {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "objectId"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub object_id: i64,
 }
@@ -11006,6 +11285,7 @@ pub struct UpdateFilterViewRequest {
 
 /// Updates properties of the named range with the specified namedRangeId.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UpdateNamedRangeRequest {
     /**
      * Updates properties of the named range with the specified namedRangeId.
@@ -11019,16 +11299,13 @@ pub struct UpdateNamedRangeRequest {
     /**
      * Updates properties of the named range with the specified namedRangeId.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "namedRange"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub named_range: Option<NamedRange>,
 }
 
 /// Updates an existing protected range with the specified protectedRangeId.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UpdateProtectedRangeRequest {
     /**
      * Updates an existing protected range with the specified protectedRangeId.
@@ -11042,11 +11319,7 @@ pub struct UpdateProtectedRangeRequest {
     /**
      * Updates an existing protected range with the specified protectedRangeId.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "protectedRange"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub protected_range: Option<ProtectedRange>,
 }
 
@@ -11071,6 +11344,7 @@ pub struct UpdateSheetPropertiesRequest {
 
 /// Updates a slicer's specifications. (This does not move or resize a slicer. To move or resize a slicer use UpdateEmbeddedObjectPositionRequest.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UpdateSlicerSpecRequest {
     /**
      * Updates a slicer's specifications. (This does not move or resize a slicer. To move or resize a slicer use UpdateEmbeddedObjectPositionRequest.
@@ -11087,8 +11361,7 @@ pub struct UpdateSlicerSpecRequest {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "slicerId"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub slicer_id: i64,
     /**
@@ -11119,15 +11392,12 @@ pub struct UpdateSpreadsheetPropertiesRequest {
 
 /// The response when updating a range of values by a data filter in a spreadsheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UpdateValuesByDataFilterResponse {
     /**
      * The response when updating a range of values by a data filter in a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "dataFilter"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data_filter: Option<DataFilter>,
     /**
      * The response when updating a range of values by a data filter in a spreadsheet.
@@ -11135,8 +11405,7 @@ pub struct UpdateValuesByDataFilterResponse {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "updatedCells"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub updated_cells: i64,
     /**
@@ -11145,18 +11414,13 @@ pub struct UpdateValuesByDataFilterResponse {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "updatedColumns"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub updated_columns: i64,
     /**
      * The response when updating a range of values by a data filter in a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "updatedData"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub updated_data: Option<ValueRange>,
     /**
      * The response when updating a range of values by a data filter in a spreadsheet.
@@ -11164,8 +11428,7 @@ pub struct UpdateValuesByDataFilterResponse {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "updatedRange"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub updated_range: String,
     /**
@@ -11174,14 +11437,14 @@ pub struct UpdateValuesByDataFilterResponse {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "updatedRows"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub updated_rows: i64,
 }
 
 /// The response when updating a range of values in a spreadsheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UpdateValuesResponse {
     /**
      * The response when updating a range of values in a spreadsheet.
@@ -11189,8 +11452,7 @@ pub struct UpdateValuesResponse {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "spreadsheetId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub spreadsheet_id: String,
     /**
@@ -11199,8 +11461,7 @@ pub struct UpdateValuesResponse {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "updatedCells"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub updated_cells: i64,
     /**
@@ -11209,18 +11470,13 @@ pub struct UpdateValuesResponse {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "updatedColumns"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub updated_columns: i64,
     /**
      * The response when updating a range of values in a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "updatedData"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub updated_data: Option<ValueRange>,
     /**
      * The response when updating a range of values in a spreadsheet.
@@ -11228,8 +11484,7 @@ pub struct UpdateValuesResponse {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "updatedRange"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub updated_range: String,
     /**
@@ -11238,23 +11493,19 @@ pub struct UpdateValuesResponse {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "updatedRows"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub updated_rows: i64,
 }
 
 /// Data within a range of the spreadsheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ValueRange {
     /**
      * Data within a range of the spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "majorDimension"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub major_dimension: Option<Dimension>,
     /**
      * Data within a range of the spreadsheet.
@@ -11278,6 +11529,7 @@ pub struct ValueRange {
 
 /// Styles for a waterfall chart column.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct WaterfallChartColumnStyle {
     /**
      * Styles for a waterfall chart column.
@@ -11287,11 +11539,7 @@ pub struct WaterfallChartColumnStyle {
     /**
      * Styles for a waterfall chart column.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "colorStyle"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub color_style: Option<ColorStyle>,
     /**
      * Styles for a waterfall chart column.
@@ -11306,14 +11554,14 @@ pub struct WaterfallChartColumnStyle {
 
 /// A custom subtotal column for a waterfall chart series.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct WaterfallChartCustomSubtotal {
     /**
      * A custom subtotal column for a waterfall chart series.
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "dataIsSubtotal"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub data_is_subtotal: bool,
     /**
@@ -11331,8 +11579,7 @@ pub struct WaterfallChartCustomSubtotal {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "subtotalIndex"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub subtotal_index: i64,
 }
@@ -11357,6 +11604,7 @@ pub struct WaterfallChartDomain {
 
 /// A single series of data for a waterfall chart.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct WaterfallChartSeries {
     /**
      * A single series of data for a waterfall chart.
@@ -11364,8 +11612,7 @@ pub struct WaterfallChartSeries {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "customSubtotals"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub custom_subtotals: Vec<WaterfallChartCustomSubtotal>,
     /**
@@ -11376,50 +11623,37 @@ pub struct WaterfallChartSeries {
     /**
      * A single series of data for a waterfall chart.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "dataLabel")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data_label: Option<DataLabel>,
     /**
      * A single series of data for a waterfall chart.
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "hideTrailingSubtotal"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub hide_trailing_subtotal: bool,
     /**
      * A single series of data for a waterfall chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "negativeColumnsStyle"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub negative_columns_style: Option<WaterfallChartColumnStyle>,
     /**
      * A single series of data for a waterfall chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "positiveColumnsStyle"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub positive_columns_style: Option<WaterfallChartColumnStyle>,
     /**
      * A single series of data for a waterfall chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "subtotalColumnsStyle"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub subtotal_columns_style: Option<WaterfallChartColumnStyle>,
 }
 
 /**
  * The stacked type.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum WaterfallChartSpecStackedType {
     #[serde(rename = "SEQUENTIAL")]
     Sequential,
@@ -11453,7 +11687,30 @@ impl Default for WaterfallChartSpecStackedType {
         WaterfallChartSpecStackedType::Noop
     }
 }
+
 impl WaterfallChartSpecStackedType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WaterfallChartSpecStackedType::Sequential => "SEQUENTIAL",
+            WaterfallChartSpecStackedType::Stacked => "STACKED",
+            WaterfallChartSpecStackedType::WaterfallStackedTypeUnspecified => {
+                "WATERFALL_STACKED_TYPE_UNSPECIFIED"
+            }
+            WaterfallChartSpecStackedType::Noop => "",
+            WaterfallChartSpecStackedType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [WaterfallChartSpecStackedType] {
+        &[
+            WaterfallChartSpecStackedType::Sequential,
+            WaterfallChartSpecStackedType::Stacked,
+            WaterfallChartSpecStackedType::WaterfallStackedTypeUnspecified,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, WaterfallChartSpecStackedType::Noop)
     }
@@ -11461,15 +11718,12 @@ impl WaterfallChartSpecStackedType {
 
 /// A waterfall chart.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct WaterfallChartSpec {
     /**
      * A waterfall chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "connectorLineStyle"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub connector_line_style: Option<LineStyle>,
     /**
      * A waterfall chart.
@@ -11481,8 +11735,7 @@ pub struct WaterfallChartSpec {
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "firstValueIsTotal"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub first_value_is_total: bool,
     /**
@@ -11490,8 +11743,7 @@ pub struct WaterfallChartSpec {
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "hideConnectorLines"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub hide_connector_lines: bool,
     /**
@@ -11506,27 +11758,19 @@ pub struct WaterfallChartSpec {
     /**
      * A waterfall chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "stackedType"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub stacked_type: Option<WaterfallChartSpecStackedType>,
     /**
      * A waterfall chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "totalDataLabel"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub total_data_label: Option<DataLabel>,
 }
 
 /**
  * V1 error format.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Xgafv {
     #[serde(rename = "1")]
     One,
@@ -11555,7 +11799,23 @@ impl Default for Xgafv {
         Xgafv::Noop
     }
 }
+
 impl Xgafv {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Xgafv::One => "1",
+            Xgafv::Two => "2",
+            Xgafv::Noop => "",
+            Xgafv::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Xgafv] {
+        &[Xgafv::One, Xgafv::Two]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Xgafv::Noop)
     }
@@ -11564,7 +11824,7 @@ impl Xgafv {
 /**
  * Data format for response.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Alt {
     #[serde(rename = "json")]
     Json,
@@ -11596,7 +11856,24 @@ impl Default for Alt {
         Alt::Noop
     }
 }
+
 impl Alt {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Alt::Json => "json",
+            Alt::Media => "media",
+            Alt::Proto => "proto",
+            Alt::Noop => "",
+            Alt::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Alt] {
+        &[Alt::Json, Alt::Media, Alt::Proto]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Alt::Noop)
     }
@@ -11605,7 +11882,7 @@ impl Alt {
 /**
  * How the input data should be inserted.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum InsertDataOption {
     #[serde(rename = "INSERT_ROWS")]
     InsertRows,
@@ -11634,8 +11911,113 @@ impl Default for InsertDataOption {
         InsertDataOption::Noop
     }
 }
+
 impl InsertDataOption {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InsertDataOption::InsertRows => "INSERT_ROWS",
+            InsertDataOption::Overwrite => "OVERWRITE",
+            InsertDataOption::Noop => "",
+            InsertDataOption::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [InsertDataOption] {
+        &[InsertDataOption::InsertRows, InsertDataOption::Overwrite]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, InsertDataOption::Noop)
     }
 }
+
+/// Resource that represents a spreadsheet.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SpreadsheetRequest {
+    /**
+     * Resource that represents a spreadsheet.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub data_sources: Vec<DataSource>,
+    /**
+     * Resource that represents a spreadsheet.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub developer_metadata: Vec<DeveloperMetadata>,
+    /**
+     * Resource that represents a spreadsheet.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub named_ranges: Vec<NamedRange>,
+    /**
+     * Resource that represents a spreadsheet.
+     */
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub properties: Option<SpreadsheetProperties>,
+    /**
+     * Resource that represents a spreadsheet.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub sheets: Vec<Sheet>,
+    /**
+     * Resource that represents a spreadsheet.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub spreadsheet_id: String,
+    /**
+     * Resource that represents a spreadsheet.
+     */
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub spreadsheet_url: String,
+}
+
+/// The types taken and returned by the functions in [`crate::spreadsheets`].
+pub mod spreadsheets {
+    pub use super::{
+        AppendValuesResponse, BatchClearValuesByDataFilterRequest,
+        BatchClearValuesByDataFilterResponse, BatchClearValuesRequest, BatchClearValuesResponse,
+        BatchGetValuesByDataFilterRequest, BatchGetValuesByDataFilterResponse,
+        BatchGetValuesResponse, BatchUpdateSpreadsheetRequest, BatchUpdateSpreadsheetResponse,
+        BatchUpdateValuesByDataFilterRequest, BatchUpdateValuesByDataFilterResponse,
+        BatchUpdateValuesRequest, BatchUpdateValuesResponse, ClearValuesRequest,
+        ClearValuesResponse, CopySheetAnotherSpreadsheetRequest, DateTimeRenderOption,
+        DeveloperMetadata, Dimension, GetSpreadsheetByDataFilterRequest, InsertDataOption,
+        SearchDeveloperMetadataRequest, SearchDeveloperMetadataResponse, SheetProperties,
+        Spreadsheet, SpreadsheetRequest, UpdateValuesResponse, ValueInputOption, ValueRange,
+        ValueRenderOption,
+    };
+}
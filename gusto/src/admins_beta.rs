@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct AdminsBeta {
     pub client: Client,
@@ -12,6 +10,14 @@ impl AdminsBeta {
         AdminsBeta { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        AdminsBeta {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get all the admins at a company.
      *
@@ -24,10 +30,16 @@ impl AdminsBeta {
     pub async fn get_company_admins(&self, company_id: &str) -> Result<Vec<crate::types::Admin>> {
         let url = format!(
             "/v1/companies/{}/admins",
-            crate::progenitor_support::encode_path(&company_id.to_string()),
+            self.client.encode_path(company_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v1/companies/{company_id}/admins",
+                &[("company_id", company_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -47,10 +59,19 @@ impl AdminsBeta {
     ) -> Result<Vec<crate::types::Admin>> {
         let url = format!(
             "/v1/companies/{}/admins",
-            crate::progenitor_support::encode_path(&company_id.to_string()),
+            self.client.encode_path(company_id),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/v1/companies/{company_id}/admins",
+                    &[("company_id", company_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -69,11 +90,51 @@ impl AdminsBeta {
     ) -> Result<crate::types::Admin> {
         let url = format!(
             "/v1/companies/{}/admins",
-            crate::progenitor_support::encode_path(&company_id.to_string()),
+            self.client.encode_path(company_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v1/companies/{company_id}/admins",
+                    &[("company_id", company_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`AdminsBeta`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AdminsBetaApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AdminsBetaApi: Send + Sync {
+    async fn get_company_admins(&self, company_id: &str) -> Result<Vec<crate::types::Admin>>;
+    async fn get_all_company_admins(&self, company_id: &str) -> Result<Vec<crate::types::Admin>>;
+    async fn post_company_admin(
+        &self,
+        company_id: &str,
+        body: &crate::types::PostCompanyAdminsRequest,
+    ) -> Result<crate::types::Admin>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AdminsBetaApi for AdminsBeta {
+    async fn get_company_admins(&self, company_id: &str) -> Result<Vec<crate::types::Admin>> {
+        AdminsBeta::get_company_admins(self, company_id).await
+    }
+    async fn get_all_company_admins(&self, company_id: &str) -> Result<Vec<crate::types::Admin>> {
+        AdminsBeta::get_all_company_admins(self, company_id).await
+    }
+    async fn post_company_admin(
+        &self,
+        company_id: &str,
+        body: &crate::types::PostCompanyAdminsRequest,
+    ) -> Result<crate::types::Admin> {
+        AdminsBeta::post_company_admin(self, company_id, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Benefits {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Benefits {
         Benefits { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Benefits {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get all benefits supported by Gusto.
      *
@@ -23,7 +29,10 @@ impl Benefits {
      */
     pub async fn get_page(&self) -> Result<Vec<crate::types::SupportedBenefit>> {
         let url = "/v1/benefits".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/v1/benefits", &[]))
     }
 
     /**
@@ -39,7 +48,10 @@ impl Benefits {
      */
     pub async fn get_all(&self) -> Result<Vec<crate::types::SupportedBenefit>> {
         let url = "/v1/benefits".to_string();
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/v1/benefits", &[]))
     }
 
     /**
@@ -52,12 +64,15 @@ impl Benefits {
      * The benefit object in Gusto contains high level information about a particular benefit type and its tax considerations. When companies choose to offer a benefit, they are creating a Company Benefit object associated with a particular benefit.
      */
     pub async fn get(&self, benefit_id: &str) -> Result<crate::types::SupportedBenefit> {
-        let url = format!(
-            "/v1/benefits/{}",
-            crate::progenitor_support::encode_path(&benefit_id.to_string()),
-        );
+        let url = format!("/v1/benefits/{}", self.client.encode_path(benefit_id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v1/benefits/{benefit_id}",
+                &[("benefit_id", benefit_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -72,10 +87,16 @@ impl Benefits {
     pub async fn get_company(&self, company_id: &str) -> Result<Vec<crate::types::CompanyBenefit>> {
         let url = format!(
             "/v1/companies/{}/company_benefits",
-            crate::progenitor_support::encode_path(&company_id.to_string()),
+            self.client.encode_path(company_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v1/companies/{company_id}/company_benefits",
+                &[("company_id", company_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -95,10 +116,19 @@ impl Benefits {
     ) -> Result<Vec<crate::types::CompanyBenefit>> {
         let url = format!(
             "/v1/companies/{}/company_benefits",
-            crate::progenitor_support::encode_path(&company_id.to_string()),
+            self.client.encode_path(company_id),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/v1/companies/{company_id}/company_benefits",
+                    &[("company_id", company_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -117,12 +147,19 @@ impl Benefits {
     ) -> Result<crate::types::CompanyBenefit> {
         let url = format!(
             "/v1/companies/{}/company_benefits",
-            crate::progenitor_support::encode_path(&company_id.to_string()),
+            self.client.encode_path(company_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v1/companies/{company_id}/company_benefits",
+                    &[("company_id", company_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -140,10 +177,16 @@ impl Benefits {
     ) -> Result<crate::types::CompanyBenefit> {
         let url = format!(
             "/v1/company_benefits/{}",
-            crate::progenitor_support::encode_path(&company_benefit_id.to_string()),
+            self.client.encode_path(company_benefit_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v1/company_benefits/{company_benefit_id}",
+                &[("company_benefit_id", company_benefit_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -162,12 +205,19 @@ impl Benefits {
     ) -> Result<crate::types::CompanyBenefit> {
         let url = format!(
             "/v1/company_benefits/{}",
-            crate::progenitor_support::encode_path(&company_benefit_id.to_string()),
+            self.client.encode_path(company_benefit_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v1/company_benefits/{company_benefit_id}",
+                    &[("company_benefit_id", company_benefit_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -185,10 +235,16 @@ impl Benefits {
     ) -> Result<Vec<crate::types::EmployeeBenefit>> {
         let url = format!(
             "/v1/employees/{}/employee_benefits",
-            crate::progenitor_support::encode_path(&employee_id.to_string()),
+            self.client.encode_path(employee_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v1/employees/{employee_id}/employee_benefits",
+                &[("employee_id", employee_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -208,10 +264,19 @@ impl Benefits {
     ) -> Result<Vec<crate::types::EmployeeBenefit>> {
         let url = format!(
             "/v1/employees/{}/employee_benefits",
-            crate::progenitor_support::encode_path(&employee_id.to_string()),
+            self.client.encode_path(employee_id),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/v1/employees/{employee_id}/employee_benefits",
+                    &[("employee_id", employee_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -228,12 +293,19 @@ impl Benefits {
     ) -> Result<crate::types::EmployeeBenefit> {
         let url = format!(
             "/v1/employees/{}/employee_benefits",
-            crate::progenitor_support::encode_path(&employee_id.to_string()),
+            self.client.encode_path(employee_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v1/employees/{employee_id}/employee_benefits",
+                    &[("employee_id", employee_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -250,12 +322,19 @@ impl Benefits {
     ) -> Result<()> {
         let url = format!(
             "/v1/employees/{}/ytd_benefit_amounts_from_different_company",
-            crate::progenitor_support::encode_path(&employee_id.to_string()),
+            self.client.encode_path(employee_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v1/employees/{employee_id}/ytd_benefit_amounts_from_different_company",
+                    &[("employee_id", employee_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -271,10 +350,16 @@ impl Benefits {
     ) -> Result<crate::types::EmployeeBenefit> {
         let url = format!(
             "/v1/employee_benefits/{}",
-            crate::progenitor_support::encode_path(&employee_benefit_id.to_string()),
+            self.client.encode_path(employee_benefit_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v1/employee_benefits/{employee_benefit_id}",
+                &[("employee_benefit_id", employee_benefit_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -291,12 +376,19 @@ impl Benefits {
     ) -> Result<crate::types::EmployeeBenefit> {
         let url = format!(
             "/v1/employee_benefits/{}",
-            crate::progenitor_support::encode_path(&employee_benefit_id.to_string()),
+            self.client.encode_path(employee_benefit_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v1/employee_benefits/{employee_benefit_id}",
+                    &[("employee_benefit_id", employee_benefit_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -309,9 +401,175 @@ impl Benefits {
     pub async fn delete_employee(&self, employee_benefit_id: &str) -> Result<()> {
         let url = format!(
             "/v1/employee_benefits/{}",
-            crate::progenitor_support::encode_path(&employee_benefit_id.to_string()),
+            self.client.encode_path(employee_benefit_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v1/employee_benefits/{employee_benefit_id}",
+                &[("employee_benefit_id", employee_benefit_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete an employee benefit.
+     *
+     * This function performs a `DELETE` to the `/v1/employee_benefits/{employee_benefit_id}` endpoint.
+     *
+     * As opposed to `delete_employee`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_employee_if_exists(&self, employee_benefit_id: &str) -> Result<bool> {
+        let url = format!(
+            "/v1/employee_benefits/{}",
+            self.client.encode_path(employee_benefit_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v1/employee_benefits/{employee_benefit_id}",
+                    &[("employee_benefit_id", employee_benefit_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`Benefits`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn BenefitsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait BenefitsApi: Send + Sync {
+    async fn get_page(&self) -> Result<Vec<crate::types::SupportedBenefit>>;
+    async fn get_all(&self) -> Result<Vec<crate::types::SupportedBenefit>>;
+    async fn get(&self, benefit_id: &str) -> Result<crate::types::SupportedBenefit>;
+    async fn get_company(&self, company_id: &str) -> Result<Vec<crate::types::CompanyBenefit>>;
+    async fn get_all_company(&self, company_id: &str) -> Result<Vec<crate::types::CompanyBenefit>>;
+    async fn post_company(
+        &self,
+        company_id: &str,
+        body: &crate::types::PostCompanyBenefitsRequest,
+    ) -> Result<crate::types::CompanyBenefit>;
+    async fn get_company_benefits(
+        &self,
+        company_benefit_id: &str,
+    ) -> Result<crate::types::CompanyBenefit>;
+    async fn put_company(
+        &self,
+        company_benefit_id: &str,
+        body: &crate::types::PutCompanyBenefitRequest,
+    ) -> Result<crate::types::CompanyBenefit>;
+    async fn get_employee(&self, employee_id: &str) -> Result<Vec<crate::types::EmployeeBenefit>>;
+    async fn get_all_employee(
+        &self,
+        employee_id: &str,
+    ) -> Result<Vec<crate::types::EmployeeBenefit>>;
+    async fn post_employee(
+        &self,
+        employee_id: &str,
+        body: &crate::types::PostEmployeeBenefitsRequest,
+    ) -> Result<crate::types::EmployeeBenefit>;
+    async fn post_employee_ytd_amounts_from_different_company(
+        &self,
+        employee_id: &str,
+        body: &crate::types::PostEmployeeYtdBenefitAmountsFromDifferentCompanyRequest,
+    ) -> Result<()>;
+    async fn get_employee_benefits(
+        &self,
+        employee_benefit_id: &str,
+    ) -> Result<crate::types::EmployeeBenefit>;
+    async fn put_employee(
+        &self,
+        employee_benefit_id: &str,
+        body: &crate::types::PutEmployeeBenefitRequest,
+    ) -> Result<crate::types::EmployeeBenefit>;
+    async fn delete_employee(&self, employee_benefit_id: &str) -> Result<()>;
+    async fn delete_employee_if_exists(&self, employee_benefit_id: &str) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl BenefitsApi for Benefits {
+    async fn get_page(&self) -> Result<Vec<crate::types::SupportedBenefit>> {
+        Benefits::get_page(self).await
+    }
+    async fn get_all(&self) -> Result<Vec<crate::types::SupportedBenefit>> {
+        Benefits::get_all(self).await
+    }
+    async fn get(&self, benefit_id: &str) -> Result<crate::types::SupportedBenefit> {
+        Benefits::get(self, benefit_id).await
+    }
+    async fn get_company(&self, company_id: &str) -> Result<Vec<crate::types::CompanyBenefit>> {
+        Benefits::get_company(self, company_id).await
+    }
+    async fn get_all_company(&self, company_id: &str) -> Result<Vec<crate::types::CompanyBenefit>> {
+        Benefits::get_all_company(self, company_id).await
+    }
+    async fn post_company(
+        &self,
+        company_id: &str,
+        body: &crate::types::PostCompanyBenefitsRequest,
+    ) -> Result<crate::types::CompanyBenefit> {
+        Benefits::post_company(self, company_id, body).await
+    }
+    async fn get_company_benefits(
+        &self,
+        company_benefit_id: &str,
+    ) -> Result<crate::types::CompanyBenefit> {
+        Benefits::get_company_benefits(self, company_benefit_id).await
+    }
+    async fn put_company(
+        &self,
+        company_benefit_id: &str,
+        body: &crate::types::PutCompanyBenefitRequest,
+    ) -> Result<crate::types::CompanyBenefit> {
+        Benefits::put_company(self, company_benefit_id, body).await
+    }
+    async fn get_employee(&self, employee_id: &str) -> Result<Vec<crate::types::EmployeeBenefit>> {
+        Benefits::get_employee(self, employee_id).await
+    }
+    async fn get_all_employee(
+        &self,
+        employee_id: &str,
+    ) -> Result<Vec<crate::types::EmployeeBenefit>> {
+        Benefits::get_all_employee(self, employee_id).await
+    }
+    async fn post_employee(
+        &self,
+        employee_id: &str,
+        body: &crate::types::PostEmployeeBenefitsRequest,
+    ) -> Result<crate::types::EmployeeBenefit> {
+        Benefits::post_employee(self, employee_id, body).await
+    }
+    async fn post_employee_ytd_amounts_from_different_company(
+        &self,
+        employee_id: &str,
+        body: &crate::types::PostEmployeeYtdBenefitAmountsFromDifferentCompanyRequest,
+    ) -> Result<()> {
+        Benefits::post_employee_ytd_amounts_from_different_company(self, employee_id, body).await
+    }
+    async fn get_employee_benefits(
+        &self,
+        employee_benefit_id: &str,
+    ) -> Result<crate::types::EmployeeBenefit> {
+        Benefits::get_employee_benefits(self, employee_benefit_id).await
+    }
+    async fn put_employee(
+        &self,
+        employee_benefit_id: &str,
+        body: &crate::types::PutEmployeeBenefitRequest,
+    ) -> Result<crate::types::EmployeeBenefit> {
+        Benefits::put_employee(self, employee_benefit_id, body).await
+    }
+    async fn delete_employee(&self, employee_benefit_id: &str) -> Result<()> {
+        Benefits::delete_employee(self, employee_benefit_id).await
+    }
+    async fn delete_employee_if_exists(&self, employee_benefit_id: &str) -> Result<bool> {
+        Benefits::delete_employee_if_exists(self, employee_benefit_id).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Companies {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Companies {
         Companies { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Companies {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get a company.
      *
@@ -22,10 +28,16 @@ impl Companies {
     pub async fn get(&self, company_id_or_uuid: &str) -> Result<crate::types::Company> {
         let url = format!(
             "/v1/companies/{}",
-            crate::progenitor_support::encode_path(&company_id_or_uuid.to_string()),
+            self.client.encode_path(company_id_or_uuid),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v1/companies/{company_id_or_uuid}",
+                &[("company_id_or_uuid", company_id_or_uuid.to_string())],
+            )
+        })
     }
 
     /**
@@ -65,8 +77,9 @@ impl Companies {
     ) -> Result<crate::types::PostPartnerManagedCompaniesResponse> {
         let url = "/v1/partner_managed_companies".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/v1/partner_managed_companies", &[]))
     }
 
     /**
@@ -100,7 +113,45 @@ impl Companies {
     ) -> Result<crate::types::PostProvisionResponse> {
         let url = "/v1/provision".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/v1/provision", &[]))
+    }
+}
+
+/// The functions of [`Companies`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn CompaniesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait CompaniesApi: Send + Sync {
+    async fn get(&self, company_id_or_uuid: &str) -> Result<crate::types::Company>;
+    async fn post_partner_managed(
+        &self,
+        body: &crate::types::PostPartnerManagedCompaniesRequest,
+    ) -> Result<crate::types::PostPartnerManagedCompaniesResponse>;
+    async fn post_provision(
+        &self,
+        body: &crate::types::PostProvisionRequest,
+    ) -> Result<crate::types::PostProvisionResponse>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl CompaniesApi for Companies {
+    async fn get(&self, company_id_or_uuid: &str) -> Result<crate::types::Company> {
+        Companies::get(self, company_id_or_uuid).await
+    }
+    async fn post_partner_managed(
+        &self,
+        body: &crate::types::PostPartnerManagedCompaniesRequest,
+    ) -> Result<crate::types::PostPartnerManagedCompaniesResponse> {
+        Companies::post_partner_managed(self, body).await
+    }
+    async fn post_provision(
+        &self,
+        body: &crate::types::PostProvisionRequest,
+    ) -> Result<crate::types::PostProvisionResponse> {
+        Companies::post_provision(self, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct CompanyBankAccountsBeta {
     pub client: Client,
@@ -12,6 +10,14 @@ impl CompanyBankAccountsBeta {
         CompanyBankAccountsBeta { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        CompanyBankAccountsBeta {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get all company bank accounts.
      *
@@ -27,10 +33,16 @@ impl CompanyBankAccountsBeta {
     ) -> Result<Vec<crate::types::CompanyBankAccount>> {
         let url = format!(
             "/v1/companies/{}/bank_accounts",
-            crate::progenitor_support::encode_path(&company_id_or_uuid.to_string()),
+            self.client.encode_path(company_id_or_uuid),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v1/companies/{company_id_or_uuid}/bank_accounts",
+                &[("company_id_or_uuid", company_id_or_uuid.to_string())],
+            )
+        })
     }
 
     /**
@@ -50,10 +62,19 @@ impl CompanyBankAccountsBeta {
     ) -> Result<Vec<crate::types::CompanyBankAccount>> {
         let url = format!(
             "/v1/companies/{}/bank_accounts",
-            crate::progenitor_support::encode_path(&company_id_or_uuid.to_string()),
+            self.client.encode_path(company_id_or_uuid),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/v1/companies/{company_id_or_uuid}/bank_accounts",
+                    &[("company_id_or_uuid", company_id_or_uuid.to_string())],
+                )
+            })
     }
 
     /**
@@ -72,12 +93,19 @@ impl CompanyBankAccountsBeta {
     ) -> Result<crate::types::CompanyBankAccount> {
         let url = format!(
             "/v1/companies/{}/bank_accounts",
-            crate::progenitor_support::encode_path(&company_id_or_uuid.to_string()),
+            self.client.encode_path(company_id_or_uuid),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v1/companies/{company_id_or_uuid}/bank_accounts",
+                    &[("company_id_or_uuid", company_id_or_uuid.to_string())],
+                )
+            })
     }
 
     /**
@@ -97,12 +125,87 @@ impl CompanyBankAccountsBeta {
     ) -> Result<crate::types::CompanyBankAccount> {
         let url = format!(
             "/v1/companies/{}/bank_accounts/{}/verify",
-            crate::progenitor_support::encode_path(&company_id_or_uuid.to_string()),
-            crate::progenitor_support::encode_path(&bank_account_uuid.to_string()),
+            self.client.encode_path(company_id_or_uuid),
+            self.client.encode_path(bank_account_uuid),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v1/companies/{company_id_or_uuid}/bank_accounts/{bank_account_uuid}/verify",
+                    &[
+                        ("company_id_or_uuid", company_id_or_uuid.to_string()),
+                        ("bank_account_uuid", bank_account_uuid.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`CompanyBankAccountsBeta`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn CompanyBankAccountsBetaApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait CompanyBankAccountsBetaApi: Send + Sync {
+    async fn get_company_bank_accounts(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> Result<Vec<crate::types::CompanyBankAccount>>;
+    async fn get_all_company_bank_accounts(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> Result<Vec<crate::types::CompanyBankAccount>>;
+    async fn post_company_bank_account(
+        &self,
+        company_id_or_uuid: &str,
+        body: &crate::types::PostCompanyBankAccountsRequest,
+    ) -> Result<crate::types::CompanyBankAccount>;
+    async fn put_company_bank_accounts_verify(
+        &self,
+        company_id_or_uuid: &str,
+        bank_account_uuid: &str,
+        body: &crate::types::PutCompanyBankAccountsVerifyRequest,
+    ) -> Result<crate::types::CompanyBankAccount>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl CompanyBankAccountsBetaApi for CompanyBankAccountsBeta {
+    async fn get_company_bank_accounts(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> Result<Vec<crate::types::CompanyBankAccount>> {
+        CompanyBankAccountsBeta::get_company_bank_accounts(self, company_id_or_uuid).await
+    }
+    async fn get_all_company_bank_accounts(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> Result<Vec<crate::types::CompanyBankAccount>> {
+        CompanyBankAccountsBeta::get_all_company_bank_accounts(self, company_id_or_uuid).await
+    }
+    async fn post_company_bank_account(
+        &self,
+        company_id_or_uuid: &str,
+        body: &crate::types::PostCompanyBankAccountsRequest,
+    ) -> Result<crate::types::CompanyBankAccount> {
+        CompanyBankAccountsBeta::post_company_bank_account(self, company_id_or_uuid, body).await
+    }
+    async fn put_company_bank_accounts_verify(
+        &self,
+        company_id_or_uuid: &str,
+        bank_account_uuid: &str,
+        body: &crate::types::PutCompanyBankAccountsVerifyRequest,
+    ) -> Result<crate::types::CompanyBankAccount> {
+        CompanyBankAccountsBeta::put_company_bank_accounts_verify(
+            self,
+            company_id_or_uuid,
+            bank_account_uuid,
+            body,
+        )
+        .await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Compensations {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Compensations {
         Compensations { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Compensations {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get a compensation.
      *
@@ -25,10 +31,16 @@ impl Compensations {
     pub async fn get(&self, compensation_id: &str) -> Result<crate::types::Compensation> {
         let url = format!(
             "/v1/compensations/{}",
-            crate::progenitor_support::encode_path(&compensation_id.to_string()),
+            self.client.encode_path(compensation_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v1/compensations/{compensation_id}",
+                &[("compensation_id", compensation_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -47,12 +59,19 @@ impl Compensations {
     ) -> Result<crate::types::Compensation> {
         let url = format!(
             "/v1/compensations/{}",
-            crate::progenitor_support::encode_path(&compensation_id.to_string()),
+            self.client.encode_path(compensation_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v1/compensations/{compensation_id}",
+                    &[("compensation_id", compensation_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -67,12 +86,15 @@ impl Compensations {
      * Use the `flsa_status` to determine if an employee is elibgle for overtime.
      */
     pub async fn get_job(&self, job_id: &str) -> Result<Vec<crate::types::Compensation>> {
-        let url = format!(
-            "/v1/jobs/{}/compensations",
-            crate::progenitor_support::encode_path(&job_id.to_string()),
-        );
+        let url = format!("/v1/jobs/{}/compensations", self.client.encode_path(job_id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v1/jobs/{job_id}/compensations",
+                &[("job_id", job_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -89,11 +111,54 @@ impl Compensations {
      * Use the `flsa_status` to determine if an employee is elibgle for overtime.
      */
     pub async fn get_all_job(&self, job_id: &str) -> Result<Vec<crate::types::Compensation>> {
-        let url = format!(
-            "/v1/jobs/{}/compensations",
-            crate::progenitor_support::encode_path(&job_id.to_string()),
-        );
+        let url = format!("/v1/jobs/{}/compensations", self.client.encode_path(job_id),);
+
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/v1/jobs/{job_id}/compensations",
+                    &[("job_id", job_id.to_string())],
+                )
+            })
+    }
+}
 
-        self.client.get_all_pages(&url, None).await
+/// The functions of [`Compensations`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn CompensationsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait CompensationsApi: Send + Sync {
+    async fn get(&self, compensation_id: &str) -> Result<crate::types::Compensation>;
+    async fn put(
+        &self,
+        compensation_id: &str,
+        body: &crate::types::PutCompensationRequest,
+    ) -> Result<crate::types::Compensation>;
+    async fn get_job(&self, job_id: &str) -> Result<Vec<crate::types::Compensation>>;
+    async fn get_all_job(&self, job_id: &str) -> Result<Vec<crate::types::Compensation>>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl CompensationsApi for Compensations {
+    async fn get(&self, compensation_id: &str) -> Result<crate::types::Compensation> {
+        Compensations::get(self, compensation_id).await
+    }
+    async fn put(
+        &self,
+        compensation_id: &str,
+        body: &crate::types::PutCompensationRequest,
+    ) -> Result<crate::types::Compensation> {
+        Compensations::put(self, compensation_id, body).await
+    }
+    async fn get_job(&self, job_id: &str) -> Result<Vec<crate::types::Compensation>> {
+        Compensations::get_job(self, job_id).await
+    }
+    async fn get_all_job(&self, job_id: &str) -> Result<Vec<crate::types::Compensation>> {
+        Compensations::get_all_job(self, job_id).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct ContractorPayments {
     pub client: Client,
@@ -12,6 +10,14 @@ impl ContractorPayments {
         ContractorPayments { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        ContractorPayments {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get contractor payments for a company.
      *
@@ -30,21 +36,28 @@ impl ContractorPayments {
         start_date: &str,
         end_date: &str,
     ) -> Result<crate::types::ContractorPaymentSummary> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/companies/{}/contractor_payments?{}",
-            crate::progenitor_support::encode_path(&company_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v1/companies/{}/contractor_payments",
+                self.client.encode_path(company_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v1/companies/{company_id}/contractor_payments",
+                &[("company_id", company_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -75,33 +88,40 @@ impl ContractorPayments {
         bonus: f64,
         reimbursement: f64,
     ) -> Result<crate::types::ContractorPayment> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !bonus.to_string().is_empty() {
-            query_args.push(("bonus".to_string(), bonus.to_string()));
+            query_args.push(("bonus", bonus.to_string()));
         }
         if !contractor_id.to_string().is_empty() {
-            query_args.push(("contractor_id".to_string(), contractor_id.to_string()));
+            query_args.push(("contractor_id", contractor_id.to_string()));
         }
         if !date.is_empty() {
-            query_args.push(("date".to_string(), date.to_string()));
+            query_args.push(("date", date.to_string()));
         }
         if !hours.to_string().is_empty() {
-            query_args.push(("hours".to_string(), hours.to_string()));
+            query_args.push(("hours", hours.to_string()));
         }
         if !reimbursement.to_string().is_empty() {
-            query_args.push(("reimbursement".to_string(), reimbursement.to_string()));
+            query_args.push(("reimbursement", reimbursement.to_string()));
         }
         if !wage.to_string().is_empty() {
-            query_args.push(("wage".to_string(), wage.to_string()));
+            query_args.push(("wage", wage.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/companies/{}/contractor_payments?{}",
-            crate::progenitor_support::encode_path(&company_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v1/companies/{}/contractor_payments",
+                self.client.encode_path(company_id)
+            ),
+            &query_args,
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/v1/companies/{company_id}/contractor_payments",
+                &[("company_id", company_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -118,11 +138,23 @@ impl ContractorPayments {
     ) -> Result<crate::types::ContractorPayment> {
         let url = format!(
             "/v1/companies/{}/contractor_payments/{}",
-            crate::progenitor_support::encode_path(&company_id.to_string()),
-            crate::progenitor_support::encode_path(&contractor_payment_id_or_uuid.to_string()),
+            self.client.encode_path(company_id),
+            self.client.encode_path(contractor_payment_id_or_uuid),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v1/companies/{company_id}/contractor_payments/{contractor_payment_id_or_uuid}",
+                &[
+                    ("company_id", company_id.to_string()),
+                    (
+                        "contractor_payment_id_or_uuid",
+                        contractor_payment_id_or_uuid.to_string(),
+                    ),
+                ],
+            )
+        })
     }
 
     /**
@@ -141,10 +173,148 @@ impl ContractorPayments {
     ) -> Result<()> {
         let url = format!(
             "/v1/companies/{}/contractor_payments/{}",
-            crate::progenitor_support::encode_path(&company_id.to_string()),
-            crate::progenitor_support::encode_path(&contractor_payment_id_or_uuid.to_string()),
+            self.client.encode_path(company_id),
+            self.client.encode_path(contractor_payment_id_or_uuid),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v1/companies/{company_id}/contractor_payments/{contractor_payment_id_or_uuid}",
+                &[
+                    ("company_id", company_id.to_string()),
+                    (
+                        "contractor_payment_id_or_uuid",
+                        contractor_payment_id_or_uuid.to_string(),
+                    ),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Cancel a contractor payment (Beta).
+     *
+     * This function performs a `DELETE` to the `/v1/companies/{company_id}/contractor_payments/{contractor_payment_id_or_uuid}` endpoint.
+     *
+     * As opposed to `delete_company`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_company_if_exists(
+        &self,
+        company_id: &str,
+        contractor_payment_id_or_uuid: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/v1/companies/{}/contractor_payments/{}",
+            self.client.encode_path(company_id),
+            self.client.encode_path(contractor_payment_id_or_uuid),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete_if_exists(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/v1/companies/{company_id}/contractor_payments/{contractor_payment_id_or_uuid}", &[("company_id", company_id.to_string()), ("contractor_payment_id_or_uuid", contractor_payment_id_or_uuid.to_string())]))
+    }
+}
+
+/// The functions of [`ContractorPayments`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ContractorPaymentsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ContractorPaymentsApi: Send + Sync {
+    async fn get_company(
+        &self,
+        company_id: &str,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<crate::types::ContractorPaymentSummary>;
+    async fn post_company(
+        &self,
+        company_id: &str,
+        date: &str,
+        contractor_id: f64,
+        wage: f64,
+        hours: f64,
+        bonus: f64,
+        reimbursement: f64,
+    ) -> Result<crate::types::ContractorPayment>;
+    async fn get_company_contractor_payments(
+        &self,
+        company_id: &str,
+        contractor_payment_id_or_uuid: &str,
+    ) -> Result<crate::types::ContractorPayment>;
+    async fn delete_company(
+        &self,
+        company_id: &str,
+        contractor_payment_id_or_uuid: &str,
+    ) -> Result<()>;
+    async fn delete_company_if_exists(
+        &self,
+        company_id: &str,
+        contractor_payment_id_or_uuid: &str,
+    ) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ContractorPaymentsApi for ContractorPayments {
+    async fn get_company(
+        &self,
+        company_id: &str,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<crate::types::ContractorPaymentSummary> {
+        ContractorPayments::get_company(self, company_id, start_date, end_date).await
+    }
+    async fn post_company(
+        &self,
+        company_id: &str,
+        date: &str,
+        contractor_id: f64,
+        wage: f64,
+        hours: f64,
+        bonus: f64,
+        reimbursement: f64,
+    ) -> Result<crate::types::ContractorPayment> {
+        ContractorPayments::post_company(
+            self,
+            company_id,
+            date,
+            contractor_id,
+            wage,
+            hours,
+            bonus,
+            reimbursement,
+        )
+        .await
+    }
+    async fn get_company_contractor_payments(
+        &self,
+        company_id: &str,
+        contractor_payment_id_or_uuid: &str,
+    ) -> Result<crate::types::ContractorPayment> {
+        ContractorPayments::get_company_contractor_payments(
+            self,
+            company_id,
+            contractor_payment_id_or_uuid,
+        )
+        .await
+    }
+    async fn delete_company(
+        &self,
+        company_id: &str,
+        contractor_payment_id_or_uuid: &str,
+    ) -> Result<()> {
+        ContractorPayments::delete_company(self, company_id, contractor_payment_id_or_uuid).await
+    }
+    async fn delete_company_if_exists(
+        &self,
+        company_id: &str,
+        contractor_payment_id_or_uuid: &str,
+    ) -> Result<bool> {
+        ContractorPayments::delete_company_if_exists(
+            self,
+            company_id,
+            contractor_payment_id_or_uuid,
+        )
+        .await
     }
 }
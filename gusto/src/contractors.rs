@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Contractors {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Contractors {
         Contractors { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Contractors {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get a contractor.
      *
@@ -22,10 +28,16 @@ impl Contractors {
     pub async fn get(&self, contractor_id_or_uuid: &str) -> Result<crate::types::Contractor> {
         let url = format!(
             "/v1/contractors/{}",
-            crate::progenitor_support::encode_path(&contractor_id_or_uuid.to_string()),
+            self.client.encode_path(contractor_id_or_uuid),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v1/contractors/{contractor_id_or_uuid}",
+                &[("contractor_id_or_uuid", contractor_id_or_uuid.to_string())],
+            )
+        })
     }
 
     /**
@@ -42,12 +54,19 @@ impl Contractors {
     ) -> Result<crate::types::Contractor> {
         let url = format!(
             "/v1/contractors/{}",
-            crate::progenitor_support::encode_path(&contractor_id_or_uuid.to_string()),
+            self.client.encode_path(contractor_id_or_uuid),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v1/contractors/{contractor_id_or_uuid}",
+                    &[("contractor_id_or_uuid", contractor_id_or_uuid.to_string())],
+                )
+            })
     }
 
     /**
@@ -63,10 +82,16 @@ impl Contractors {
     ) -> Result<Vec<crate::types::Contractor>> {
         let url = format!(
             "/v1/companies/{}/contractors",
-            crate::progenitor_support::encode_path(&company_id_or_uuid.to_string()),
+            self.client.encode_path(company_id_or_uuid),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v1/companies/{company_id_or_uuid}/contractors",
+                &[("company_id_or_uuid", company_id_or_uuid.to_string())],
+            )
+        })
     }
 
     /**
@@ -84,10 +109,19 @@ impl Contractors {
     ) -> Result<Vec<crate::types::Contractor>> {
         let url = format!(
             "/v1/companies/{}/contractors",
-            crate::progenitor_support::encode_path(&company_id_or_uuid.to_string()),
+            self.client.encode_path(company_id_or_uuid),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/v1/companies/{company_id_or_uuid}/contractors",
+                    &[("company_id_or_uuid", company_id_or_uuid.to_string())],
+                )
+            })
     }
 
     /**
@@ -104,11 +138,73 @@ impl Contractors {
     ) -> Result<crate::types::Contractor> {
         let url = format!(
             "/v1/companies/{}/contractors",
-            crate::progenitor_support::encode_path(&company_id_or_uuid.to_string()),
+            self.client.encode_path(company_id_or_uuid),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v1/companies/{company_id_or_uuid}/contractors",
+                    &[("company_id_or_uuid", company_id_or_uuid.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`Contractors`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ContractorsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ContractorsApi: Send + Sync {
+    async fn get(&self, contractor_id_or_uuid: &str) -> Result<crate::types::Contractor>;
+    async fn put(
+        &self,
+        contractor_id_or_uuid: &str,
+        body: &crate::types::PutComntractorRequest,
+    ) -> Result<crate::types::Contractor>;
+    async fn get_company(&self, company_id_or_uuid: &str) -> Result<Vec<crate::types::Contractor>>;
+    async fn get_all_company(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> Result<Vec<crate::types::Contractor>>;
+    async fn post_company(
+        &self,
+        company_id_or_uuid: &str,
+        body: &crate::types::PostCompanyContractorsRequest,
+    ) -> Result<crate::types::Contractor>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ContractorsApi for Contractors {
+    async fn get(&self, contractor_id_or_uuid: &str) -> Result<crate::types::Contractor> {
+        Contractors::get(self, contractor_id_or_uuid).await
+    }
+    async fn put(
+        &self,
+        contractor_id_or_uuid: &str,
+        body: &crate::types::PutComntractorRequest,
+    ) -> Result<crate::types::Contractor> {
+        Contractors::put(self, contractor_id_or_uuid, body).await
+    }
+    async fn get_company(&self, company_id_or_uuid: &str) -> Result<Vec<crate::types::Contractor>> {
+        Contractors::get_company(self, company_id_or_uuid).await
+    }
+    async fn get_all_company(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> Result<Vec<crate::types::Contractor>> {
+        Contractors::get_all_company(self, company_id_or_uuid).await
+    }
+    async fn post_company(
+        &self,
+        company_id_or_uuid: &str,
+        body: &crate::types::PostCompanyContractorsRequest,
+    ) -> Result<crate::types::Contractor> {
+        Contractors::post_company(self, company_id_or_uuid, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct CurrentUser {
     pub client: Client,
@@ -12,6 +10,14 @@ impl CurrentUser {
         CurrentUser { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        CurrentUser {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get the current user.
      *
@@ -21,6 +27,26 @@ impl CurrentUser {
      */
     pub async fn get_me(&self) -> Result<crate::types::CurrentUser> {
         let url = "/v1/me".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/v1/me", &[]))
+    }
+}
+
+/// The functions of [`CurrentUser`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn CurrentUserApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait CurrentUserApi: Send + Sync {
+    async fn get_me(&self) -> Result<crate::types::CurrentUser>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl CurrentUserApi for CurrentUser {
+    async fn get_me(&self) -> Result<crate::types::CurrentUser> {
+        CurrentUser::get_me(self).await
     }
 }
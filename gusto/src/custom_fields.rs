@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct CustomFields {
     pub client: Client,
@@ -12,6 +10,14 @@ impl CustomFields {
         CustomFields { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        CustomFields {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get an employee's custom fields.
      *
@@ -25,10 +31,16 @@ impl CustomFields {
     ) -> Result<crate::types::GetEmployeeCustomFieldsResponse> {
         let url = format!(
             "/v1/employees/{}/custom_fields",
-            crate::progenitor_support::encode_path(&employee_id.to_string()),
+            self.client.encode_path(employee_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v1/employees/{employee_id}/custom_fields",
+                &[("employee_id", employee_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -44,9 +56,48 @@ impl CustomFields {
     ) -> Result<crate::types::GetCompanyCustomFieldsResponse> {
         let url = format!(
             "/v1/companies/{}/custom_fields",
-            crate::progenitor_support::encode_path(&company_id.to_string()),
+            self.client.encode_path(company_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v1/companies/{company_id}/custom_fields",
+                &[("company_id", company_id.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`CustomFields`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn CustomFieldsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait CustomFieldsApi: Send + Sync {
+    async fn get_employee(
+        &self,
+        employee_id: &str,
+    ) -> Result<crate::types::GetEmployeeCustomFieldsResponse>;
+    async fn get_company(
+        &self,
+        company_id: &str,
+    ) -> Result<crate::types::GetCompanyCustomFieldsResponse>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl CustomFieldsApi for CustomFields {
+    async fn get_employee(
+        &self,
+        employee_id: &str,
+    ) -> Result<crate::types::GetEmployeeCustomFieldsResponse> {
+        CustomFields::get_employee(self, employee_id).await
+    }
+    async fn get_company(
+        &self,
+        company_id: &str,
+    ) -> Result<crate::types::GetCompanyCustomFieldsResponse> {
+        CustomFields::get_company(self, company_id).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct EarningType {
     pub client: Client,
@@ -12,6 +10,14 @@ impl EarningType {
         EarningType { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        EarningType {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get all earning types for a company.
      *
@@ -31,10 +37,16 @@ impl EarningType {
     ) -> Result<crate::types::EarningTypeListResponse> {
         let url = format!(
             "/v1/companies/{}/earning_types",
-            crate::progenitor_support::encode_path(&company_id.to_string()),
+            self.client.encode_path(company_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v1/companies/{company_id}/earning_types",
+                &[("company_id", company_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -53,12 +65,19 @@ impl EarningType {
     ) -> Result<crate::types::EarningType> {
         let url = format!(
             "/v1/companies/{}/earning_types",
-            crate::progenitor_support::encode_path(&company_id.to_string()),
+            self.client.encode_path(company_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v1/companies/{company_id}/earning_types",
+                    &[("company_id", company_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -76,13 +95,23 @@ impl EarningType {
     ) -> Result<crate::types::EarningType> {
         let url = format!(
             "/v1/companies/{}/earning_types/{}",
-            crate::progenitor_support::encode_path(&company_id.to_string()),
-            crate::progenitor_support::encode_path(&earning_type_uuid.to_string()),
+            self.client.encode_path(company_id),
+            self.client.encode_path(earning_type_uuid),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v1/companies/{company_id}/earning_types/{earning_type_uuid}",
+                    &[
+                        ("company_id", company_id.to_string()),
+                        ("earning_type_uuid", earning_type_uuid.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -99,10 +128,111 @@ impl EarningType {
     ) -> Result<()> {
         let url = format!(
             "/v1/companies/{}/earning_types/{}",
-            crate::progenitor_support::encode_path(&company_id.to_string()),
-            crate::progenitor_support::encode_path(&earning_type_uuid.to_string()),
+            self.client.encode_path(company_id),
+            self.client.encode_path(earning_type_uuid),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v1/companies/{company_id}/earning_types/{earning_type_uuid}",
+                &[
+                    ("company_id", company_id.to_string()),
+                    ("earning_type_uuid", earning_type_uuid.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Deactivate an earning type.
+     *
+     * This function performs a `DELETE` to the `/v1/companies/{company_id}/earning_types/{earning_type_uuid}` endpoint.
+     *
+     * As opposed to `delete_company_type`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_company_type_if_exists(
+        &self,
+        company_id: &str,
+        earning_type_uuid: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/v1/companies/{}/earning_types/{}",
+            self.client.encode_path(company_id),
+            self.client.encode_path(earning_type_uuid),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v1/companies/{company_id}/earning_types/{earning_type_uuid}",
+                    &[
+                        ("company_id", company_id.to_string()),
+                        ("earning_type_uuid", earning_type_uuid.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`EarningType`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn EarningTypeApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait EarningTypeApi: Send + Sync {
+    async fn get_company(&self, company_id: &str) -> Result<crate::types::EarningTypeListResponse>;
+    async fn post_company(
+        &self,
+        company_id: &str,
+        body: &crate::types::PostCompanyEarningTypesRequest,
+    ) -> Result<crate::types::EarningType>;
+    async fn put_company_type(
+        &self,
+        company_id: &str,
+        earning_type_uuid: &str,
+        body: &crate::types::PutCompanyEarningTypeRequest,
+    ) -> Result<crate::types::EarningType>;
+    async fn delete_company_type(&self, company_id: &str, earning_type_uuid: &str) -> Result<()>;
+    async fn delete_company_type_if_exists(
+        &self,
+        company_id: &str,
+        earning_type_uuid: &str,
+    ) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl EarningTypeApi for EarningType {
+    async fn get_company(&self, company_id: &str) -> Result<crate::types::EarningTypeListResponse> {
+        EarningType::get_company(self, company_id).await
+    }
+    async fn post_company(
+        &self,
+        company_id: &str,
+        body: &crate::types::PostCompanyEarningTypesRequest,
+    ) -> Result<crate::types::EarningType> {
+        EarningType::post_company(self, company_id, body).await
+    }
+    async fn put_company_type(
+        &self,
+        company_id: &str,
+        earning_type_uuid: &str,
+        body: &crate::types::PutCompanyEarningTypeRequest,
+    ) -> Result<crate::types::EarningType> {
+        EarningType::put_company_type(self, company_id, earning_type_uuid, body).await
+    }
+    async fn delete_company_type(&self, company_id: &str, earning_type_uuid: &str) -> Result<()> {
+        EarningType::delete_company_type(self, company_id, earning_type_uuid).await
+    }
+    async fn delete_company_type_if_exists(
+        &self,
+        company_id: &str,
+        earning_type_uuid: &str,
+    ) -> Result<bool> {
+        EarningType::delete_company_type_if_exists(self, company_id, earning_type_uuid).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Employees {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Employees {
         Employees { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Employees {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get an employee.
      *
@@ -28,18 +34,25 @@ impl Employees {
         employee_id_or_uuid: &str,
         include: &[String],
     ) -> Result<crate::types::Employee> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !include.is_empty() {
-            query_args.push(("include".to_string(), include.join(" ")));
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        for v in include {
+            query_args.push(("include", v.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/employees/{}?{}",
-            crate::progenitor_support::encode_path(&employee_id_or_uuid.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v1/employees/{}",
+                self.client.encode_path(employee_id_or_uuid)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v1/employees/{employee_id_or_uuid}",
+                &[("employee_id_or_uuid", employee_id_or_uuid.to_string())],
+            )
+        })
     }
 
     /**
@@ -56,12 +69,19 @@ impl Employees {
     ) -> Result<crate::types::Employee> {
         let url = format!(
             "/v1/employees/{}",
-            crate::progenitor_support::encode_path(&employee_id_or_uuid.to_string()),
+            self.client.encode_path(employee_id_or_uuid),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v1/employees/{employee_id_or_uuid}",
+                    &[("employee_id_or_uuid", employee_id_or_uuid.to_string())],
+                )
+            })
     }
 
     /**
@@ -86,27 +106,34 @@ impl Employees {
         per: f64,
         include: &[String],
     ) -> Result<Vec<crate::types::Employee>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !include.is_empty() {
-            query_args.push(("include".to_string(), include.join(" ")));
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        for v in include {
+            query_args.push(("include", v.to_string()));
         }
         if !page.to_string().is_empty() {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if !per.to_string().is_empty() {
-            query_args.push(("per".to_string(), per.to_string()));
+            query_args.push(("per", per.to_string()));
         }
         if terminated {
-            query_args.push(("terminated".to_string(), terminated.to_string()));
+            query_args.push(("terminated", terminated.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/companies/{}/employees?{}",
-            crate::progenitor_support::encode_path(&company_id_or_uuid.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v1/companies/{}/employees",
+                self.client.encode_path(company_id_or_uuid)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v1/companies/{company_id_or_uuid}/employees",
+                &[("company_id_or_uuid", company_id_or_uuid.to_string())],
+            )
+        })
     }
 
     /**
@@ -124,21 +151,31 @@ impl Employees {
         terminated: bool,
         include: &[String],
     ) -> Result<Vec<crate::types::Employee>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !include.is_empty() {
-            query_args.push(("include".to_string(), include.join(" ")));
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        for v in include {
+            query_args.push(("include", v.to_string()));
         }
         if terminated {
-            query_args.push(("terminated".to_string(), terminated.to_string()));
+            query_args.push(("terminated", terminated.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/companies/{}/employees?{}",
-            crate::progenitor_support::encode_path(&company_id_or_uuid.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v1/companies/{}/employees",
+                self.client.encode_path(company_id_or_uuid)
+            ),
+            &query_args,
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/v1/companies/{company_id_or_uuid}/employees",
+                    &[("company_id_or_uuid", company_id_or_uuid.to_string())],
+                )
+            })
     }
 
     /**
@@ -155,12 +192,19 @@ impl Employees {
     ) -> Result<crate::types::Employee> {
         let url = format!(
             "/v1/companies/{}/employees",
-            crate::progenitor_support::encode_path(&company_id_or_uuid.to_string()),
+            self.client.encode_path(company_id_or_uuid),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v1/companies/{company_id_or_uuid}/employees",
+                    &[("company_id_or_uuid", company_id_or_uuid.to_string())],
+                )
+            })
     }
 
     /**
@@ -173,10 +217,16 @@ impl Employees {
     pub async fn get_home_address(&self, employee_id: &str) -> Result<crate::types::Location> {
         let url = format!(
             "/v1/employees/{}/home_address",
-            crate::progenitor_support::encode_path(&employee_id.to_string()),
+            self.client.encode_path(employee_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v1/employees/{employee_id}/home_address",
+                &[("employee_id", employee_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -193,11 +243,115 @@ impl Employees {
     ) -> Result<crate::types::Location> {
         let url = format!(
             "/v1/employees/{}/home_address",
-            crate::progenitor_support::encode_path(&employee_id.to_string()),
+            self.client.encode_path(employee_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v1/employees/{employee_id}/home_address",
+                    &[("employee_id", employee_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`Employees`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn EmployeesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait EmployeesApi: Send + Sync {
+    async fn get(
+        &self,
+        employee_id_or_uuid: &str,
+        include: &[String],
+    ) -> Result<crate::types::Employee>;
+    async fn put(
+        &self,
+        employee_id_or_uuid: &str,
+        body: &crate::types::PutEmployeesRequest,
+    ) -> Result<crate::types::Employee>;
+    async fn get_company(
+        &self,
+        company_id_or_uuid: &str,
+        terminated: bool,
+        page: f64,
+        per: f64,
+        include: &[String],
+    ) -> Result<Vec<crate::types::Employee>>;
+    async fn get_all_company(
+        &self,
+        company_id_or_uuid: &str,
+        terminated: bool,
+        include: &[String],
+    ) -> Result<Vec<crate::types::Employee>>;
+    async fn post(
+        &self,
+        company_id_or_uuid: &str,
+        body: &crate::types::PostEmployeesRequest,
+    ) -> Result<crate::types::Employee>;
+    async fn get_home_address(&self, employee_id: &str) -> Result<crate::types::Location>;
+    async fn put_home_address(
+        &self,
+        employee_id: &str,
+        body: &crate::types::PutEmployeeHomeAddressRequest,
+    ) -> Result<crate::types::Location>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl EmployeesApi for Employees {
+    async fn get(
+        &self,
+        employee_id_or_uuid: &str,
+        include: &[String],
+    ) -> Result<crate::types::Employee> {
+        Employees::get(self, employee_id_or_uuid, include).await
+    }
+    async fn put(
+        &self,
+        employee_id_or_uuid: &str,
+        body: &crate::types::PutEmployeesRequest,
+    ) -> Result<crate::types::Employee> {
+        Employees::put(self, employee_id_or_uuid, body).await
+    }
+    async fn get_company(
+        &self,
+        company_id_or_uuid: &str,
+        terminated: bool,
+        page: f64,
+        per: f64,
+        include: &[String],
+    ) -> Result<Vec<crate::types::Employee>> {
+        Employees::get_company(self, company_id_or_uuid, terminated, page, per, include).await
+    }
+    async fn get_all_company(
+        &self,
+        company_id_or_uuid: &str,
+        terminated: bool,
+        include: &[String],
+    ) -> Result<Vec<crate::types::Employee>> {
+        Employees::get_all_company(self, company_id_or_uuid, terminated, include).await
+    }
+    async fn post(
+        &self,
+        company_id_or_uuid: &str,
+        body: &crate::types::PostEmployeesRequest,
+    ) -> Result<crate::types::Employee> {
+        Employees::post(self, company_id_or_uuid, body).await
+    }
+    async fn get_home_address(&self, employee_id: &str) -> Result<crate::types::Location> {
+        Employees::get_home_address(self, employee_id).await
+    }
+    async fn put_home_address(
+        &self,
+        employee_id: &str,
+        body: &crate::types::PutEmployeeHomeAddressRequest,
+    ) -> Result<crate::types::Location> {
+        Employees::put_home_address(self, employee_id, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Garnishments {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Garnishments {
         Garnishments { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Garnishments {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get garnishments for an employee.
      *
@@ -22,10 +28,16 @@ impl Garnishments {
     pub async fn get_employee(&self, employee_id: &str) -> Result<Vec<crate::types::Garnishment>> {
         let url = format!(
             "/v1/employees/{}/garnishments",
-            crate::progenitor_support::encode_path(&employee_id.to_string()),
+            self.client.encode_path(employee_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v1/employees/{employee_id}/garnishments",
+                &[("employee_id", employee_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -43,10 +55,19 @@ impl Garnishments {
     ) -> Result<Vec<crate::types::Garnishment>> {
         let url = format!(
             "/v1/employees/{}/garnishments",
-            crate::progenitor_support::encode_path(&employee_id.to_string()),
+            self.client.encode_path(employee_id),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/v1/employees/{employee_id}/garnishments",
+                    &[("employee_id", employee_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -63,12 +84,19 @@ impl Garnishments {
     ) -> Result<crate::types::Garnishment> {
         let url = format!(
             "/v1/employees/{}/garnishments",
-            crate::progenitor_support::encode_path(&employee_id.to_string()),
+            self.client.encode_path(employee_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v1/employees/{employee_id}/garnishments",
+                    &[("employee_id", employee_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -81,10 +109,16 @@ impl Garnishments {
     pub async fn get(&self, garnishment_id: &str) -> Result<crate::types::Garnishment> {
         let url = format!(
             "/v1/garnishments/{}",
-            crate::progenitor_support::encode_path(&garnishment_id.to_string()),
+            self.client.encode_path(garnishment_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v1/garnishments/{garnishment_id}",
+                &[("garnishment_id", garnishment_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -101,11 +135,67 @@ impl Garnishments {
     ) -> Result<crate::types::Garnishment> {
         let url = format!(
             "/v1/garnishments/{}",
-            crate::progenitor_support::encode_path(&garnishment_id.to_string()),
+            self.client.encode_path(garnishment_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v1/garnishments/{garnishment_id}",
+                    &[("garnishment_id", garnishment_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`Garnishments`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn GarnishmentsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait GarnishmentsApi: Send + Sync {
+    async fn get_employee(&self, employee_id: &str) -> Result<Vec<crate::types::Garnishment>>;
+    async fn get_all_employee(&self, employee_id: &str) -> Result<Vec<crate::types::Garnishment>>;
+    async fn post_employee(
+        &self,
+        employee_id: &str,
+        body: &crate::types::PostEmployeeGarnishmentsRequest,
+    ) -> Result<crate::types::Garnishment>;
+    async fn get(&self, garnishment_id: &str) -> Result<crate::types::Garnishment>;
+    async fn put(
+        &self,
+        garnishment_id: &str,
+        body: &crate::types::PutGarnishmentRequest,
+    ) -> Result<crate::types::Garnishment>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl GarnishmentsApi for Garnishments {
+    async fn get_employee(&self, employee_id: &str) -> Result<Vec<crate::types::Garnishment>> {
+        Garnishments::get_employee(self, employee_id).await
+    }
+    async fn get_all_employee(&self, employee_id: &str) -> Result<Vec<crate::types::Garnishment>> {
+        Garnishments::get_all_employee(self, employee_id).await
+    }
+    async fn post_employee(
+        &self,
+        employee_id: &str,
+        body: &crate::types::PostEmployeeGarnishmentsRequest,
+    ) -> Result<crate::types::Garnishment> {
+        Garnishments::post_employee(self, employee_id, body).await
+    }
+    async fn get(&self, garnishment_id: &str) -> Result<crate::types::Garnishment> {
+        Garnishments::get(self, garnishment_id).await
+    }
+    async fn put(
+        &self,
+        garnishment_id: &str,
+        body: &crate::types::PutGarnishmentRequest,
+    ) -> Result<crate::types::Garnishment> {
+        Garnishments::put(self, garnishment_id, body).await
     }
 }
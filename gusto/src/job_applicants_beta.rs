@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct JobApplicantsBeta {
     pub client: Client,
@@ -12,6 +10,14 @@ impl JobApplicantsBeta {
         JobApplicantsBeta { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        JobApplicantsBeta {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get all job applicants for a company.
      *
@@ -27,10 +33,16 @@ impl JobApplicantsBeta {
     ) -> Result<Vec<crate::types::JobApplicant>> {
         let url = format!(
             "/v1/companies/{}/job_applicants",
-            crate::progenitor_support::encode_path(&company_id.to_string()),
+            self.client.encode_path(company_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v1/companies/{company_id}/job_applicants",
+                &[("company_id", company_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -50,10 +62,19 @@ impl JobApplicantsBeta {
     ) -> Result<Vec<crate::types::JobApplicant>> {
         let url = format!(
             "/v1/companies/{}/job_applicants",
-            crate::progenitor_support::encode_path(&company_id.to_string()),
+            self.client.encode_path(company_id),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/v1/companies/{company_id}/job_applicants",
+                    &[("company_id", company_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -72,12 +93,19 @@ impl JobApplicantsBeta {
     ) -> Result<crate::types::JobApplicant> {
         let url = format!(
             "/v1/companies/{}/job_applicants",
-            crate::progenitor_support::encode_path(&company_id.to_string()),
+            self.client.encode_path(company_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v1/companies/{company_id}/job_applicants",
+                    &[("company_id", company_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -96,11 +124,20 @@ impl JobApplicantsBeta {
     ) -> Result<crate::types::JobApplicant> {
         let url = format!(
             "/v1/companies/{}/job_applicants/{}",
-            crate::progenitor_support::encode_path(&company_id.to_string()),
-            crate::progenitor_support::encode_path(&job_applicant_uuid.to_string()),
+            self.client.encode_path(company_id),
+            self.client.encode_path(job_applicant_uuid),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v1/companies/{company_id}/job_applicants/{job_applicant_uuid}",
+                &[
+                    ("company_id", company_id.to_string()),
+                    ("job_applicant_uuid", job_applicant_uuid.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -120,13 +157,23 @@ impl JobApplicantsBeta {
     ) -> Result<crate::types::JobApplicant> {
         let url = format!(
             "/v1/companies/{}/job_applicants/{}",
-            crate::progenitor_support::encode_path(&company_id.to_string()),
-            crate::progenitor_support::encode_path(&job_applicant_uuid.to_string()),
+            self.client.encode_path(company_id),
+            self.client.encode_path(job_applicant_uuid),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v1/companies/{company_id}/job_applicants/{job_applicant_uuid}",
+                    &[
+                        ("company_id", company_id.to_string()),
+                        ("job_applicant_uuid", job_applicant_uuid.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -145,10 +192,168 @@ impl JobApplicantsBeta {
     ) -> Result<()> {
         let url = format!(
             "/v1/companies/{}/job_applicants/{}",
-            crate::progenitor_support::encode_path(&company_id.to_string()),
-            crate::progenitor_support::encode_path(&job_applicant_uuid.to_string()),
+            self.client.encode_path(company_id),
+            self.client.encode_path(job_applicant_uuid),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v1/companies/{company_id}/job_applicants/{job_applicant_uuid}",
+                &[
+                    ("company_id", company_id.to_string()),
+                    ("job_applicant_uuid", job_applicant_uuid.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Delete a job applicant.
+     *
+     * This function performs a `DELETE` to the `/v1/companies/{company_id}/job_applicants/{job_applicant_uuid}` endpoint.
+     *
+     * As opposed to `delete_company_job_applicants_applicant`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_company_job_applicants_applicant_if_exists(
+        &self,
+        company_id: &str,
+        job_applicant_uuid: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/v1/companies/{}/job_applicants/{}",
+            self.client.encode_path(company_id),
+            self.client.encode_path(job_applicant_uuid),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v1/companies/{company_id}/job_applicants/{job_applicant_uuid}",
+                    &[
+                        ("company_id", company_id.to_string()),
+                        ("job_applicant_uuid", job_applicant_uuid.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`JobApplicantsBeta`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn JobApplicantsBetaApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait JobApplicantsBetaApi: Send + Sync {
+    async fn get_company_job_applicants(
+        &self,
+        company_id: &str,
+    ) -> Result<Vec<crate::types::JobApplicant>>;
+    async fn get_all_company_job_applicants(
+        &self,
+        company_id: &str,
+    ) -> Result<Vec<crate::types::JobApplicant>>;
+    async fn post_company_job_applicant(
+        &self,
+        company_id: &str,
+        body: &crate::types::PostCompanyJobApplicantsRequest,
+    ) -> Result<crate::types::JobApplicant>;
+    async fn get_company_job_applicants_applicant(
+        &self,
+        company_id: &str,
+        job_applicant_uuid: &str,
+    ) -> Result<crate::types::JobApplicant>;
+    async fn put_company_job_applicants_applicant(
+        &self,
+        company_id: &str,
+        job_applicant_uuid: &str,
+        body: &crate::types::PutCompanyJobApplicantRequest,
+    ) -> Result<crate::types::JobApplicant>;
+    async fn delete_company_job_applicants_applicant(
+        &self,
+        company_id: &str,
+        job_applicant_uuid: &str,
+    ) -> Result<()>;
+    async fn delete_company_job_applicants_applicant_if_exists(
+        &self,
+        company_id: &str,
+        job_applicant_uuid: &str,
+    ) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl JobApplicantsBetaApi for JobApplicantsBeta {
+    async fn get_company_job_applicants(
+        &self,
+        company_id: &str,
+    ) -> Result<Vec<crate::types::JobApplicant>> {
+        JobApplicantsBeta::get_company_job_applicants(self, company_id).await
+    }
+    async fn get_all_company_job_applicants(
+        &self,
+        company_id: &str,
+    ) -> Result<Vec<crate::types::JobApplicant>> {
+        JobApplicantsBeta::get_all_company_job_applicants(self, company_id).await
+    }
+    async fn post_company_job_applicant(
+        &self,
+        company_id: &str,
+        body: &crate::types::PostCompanyJobApplicantsRequest,
+    ) -> Result<crate::types::JobApplicant> {
+        JobApplicantsBeta::post_company_job_applicant(self, company_id, body).await
+    }
+    async fn get_company_job_applicants_applicant(
+        &self,
+        company_id: &str,
+        job_applicant_uuid: &str,
+    ) -> Result<crate::types::JobApplicant> {
+        JobApplicantsBeta::get_company_job_applicants_applicant(
+            self,
+            company_id,
+            job_applicant_uuid,
+        )
+        .await
+    }
+    async fn put_company_job_applicants_applicant(
+        &self,
+        company_id: &str,
+        job_applicant_uuid: &str,
+        body: &crate::types::PutCompanyJobApplicantRequest,
+    ) -> Result<crate::types::JobApplicant> {
+        JobApplicantsBeta::put_company_job_applicants_applicant(
+            self,
+            company_id,
+            job_applicant_uuid,
+            body,
+        )
+        .await
+    }
+    async fn delete_company_job_applicants_applicant(
+        &self,
+        company_id: &str,
+        job_applicant_uuid: &str,
+    ) -> Result<()> {
+        JobApplicantsBeta::delete_company_job_applicants_applicant(
+            self,
+            company_id,
+            job_applicant_uuid,
+        )
+        .await
+    }
+    async fn delete_company_job_applicants_applicant_if_exists(
+        &self,
+        company_id: &str,
+        job_applicant_uuid: &str,
+    ) -> Result<bool> {
+        JobApplicantsBeta::delete_company_job_applicants_applicant_if_exists(
+            self,
+            company_id,
+            job_applicant_uuid,
+        )
+        .await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Jobs {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Jobs {
         Jobs { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Jobs {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get a job.
      *
@@ -20,12 +26,15 @@ impl Jobs {
      * Get a job.
      */
     pub async fn get(&self, job_id: &str) -> Result<crate::types::Job> {
-        let url = format!(
-            "/v1/jobs/{}",
-            crate::progenitor_support::encode_path(&job_id.to_string()),
-        );
+        let url = format!("/v1/jobs/{}", self.client.encode_path(job_id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v1/jobs/{job_id}",
+                &[("job_id", job_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -40,14 +49,18 @@ impl Jobs {
         job_id: &str,
         body: &crate::types::PutJobRequest,
     ) -> Result<crate::types::Job> {
-        let url = format!(
-            "/v1/jobs/{}",
-            crate::progenitor_support::encode_path(&job_id.to_string()),
-        );
+        let url = format!("/v1/jobs/{}", self.client.encode_path(job_id),);
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v1/jobs/{job_id}",
+                    &[("job_id", job_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -58,12 +71,37 @@ impl Jobs {
      * Deletes a specific job that an employee holds.
      */
     pub async fn delete(&self, job_id: &str) -> Result<()> {
-        let url = format!(
-            "/v1/jobs/{}",
-            crate::progenitor_support::encode_path(&job_id.to_string()),
-        );
+        let url = format!("/v1/jobs/{}", self.client.encode_path(job_id),);
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/v1/jobs/{job_id}",
+                &[("job_id", job_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete an individual job.
+     *
+     * This function performs a `DELETE` to the `/v1/jobs/{job_id}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, job_id: &str) -> Result<bool> {
+        let url = format!("/v1/jobs/{}", self.client.encode_path(job_id),);
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/v1/jobs/{job_id}",
+                    &[("job_id", job_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -76,10 +114,16 @@ impl Jobs {
     pub async fn get_employee(&self, employee_id: &str) -> Result<Vec<crate::types::Job>> {
         let url = format!(
             "/v1/employees/{}/jobs",
-            crate::progenitor_support::encode_path(&employee_id.to_string()),
+            self.client.encode_path(employee_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v1/employees/{employee_id}/jobs",
+                &[("employee_id", employee_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -94,10 +138,19 @@ impl Jobs {
     pub async fn get_all_employee(&self, employee_id: &str) -> Result<Vec<crate::types::Job>> {
         let url = format!(
             "/v1/employees/{}/jobs",
-            crate::progenitor_support::encode_path(&employee_id.to_string()),
+            self.client.encode_path(employee_id),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/v1/employees/{employee_id}/jobs",
+                    &[("employee_id", employee_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -114,12 +167,19 @@ impl Jobs {
     ) -> Result<crate::types::Job> {
         let url = format!(
             "/v1/employees/{}/jobs",
-            crate::progenitor_support::encode_path(&employee_id.to_string()),
+            self.client.encode_path(employee_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v1/employees/{employee_id}/jobs",
+                    &[("employee_id", employee_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -136,13 +196,86 @@ impl Jobs {
         job_id: &str,
         body: &crate::types::PostJobCompensationsRequest,
     ) -> Result<crate::types::Compensation> {
-        let url = format!(
-            "/v1/jobs/{}/compensations",
-            crate::progenitor_support::encode_path(&job_id.to_string()),
-        );
+        let url = format!("/v1/jobs/{}/compensations", self.client.encode_path(job_id),);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v1/jobs/{job_id}/compensations",
+                    &[("job_id", job_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`Jobs`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn JobsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait JobsApi: Send + Sync {
+    async fn get(&self, job_id: &str) -> Result<crate::types::Job>;
+    async fn put(
+        &self,
+        job_id: &str,
+        body: &crate::types::PutJobRequest,
+    ) -> Result<crate::types::Job>;
+    async fn delete(&self, job_id: &str) -> Result<()>;
+    async fn delete_if_exists(&self, job_id: &str) -> Result<bool>;
+    async fn get_employee(&self, employee_id: &str) -> Result<Vec<crate::types::Job>>;
+    async fn get_all_employee(&self, employee_id: &str) -> Result<Vec<crate::types::Job>>;
+    async fn post(
+        &self,
+        employee_id: &str,
+        body: &crate::types::PostJobRequest,
+    ) -> Result<crate::types::Job>;
+    async fn post_compensation(
+        &self,
+        job_id: &str,
+        body: &crate::types::PostJobCompensationsRequest,
+    ) -> Result<crate::types::Compensation>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl JobsApi for Jobs {
+    async fn get(&self, job_id: &str) -> Result<crate::types::Job> {
+        Jobs::get(self, job_id).await
+    }
+    async fn put(
+        &self,
+        job_id: &str,
+        body: &crate::types::PutJobRequest,
+    ) -> Result<crate::types::Job> {
+        Jobs::put(self, job_id, body).await
+    }
+    async fn delete(&self, job_id: &str) -> Result<()> {
+        Jobs::delete(self, job_id).await
+    }
+    async fn delete_if_exists(&self, job_id: &str) -> Result<bool> {
+        Jobs::delete_if_exists(self, job_id).await
+    }
+    async fn get_employee(&self, employee_id: &str) -> Result<Vec<crate::types::Job>> {
+        Jobs::get_employee(self, employee_id).await
+    }
+    async fn get_all_employee(&self, employee_id: &str) -> Result<Vec<crate::types::Job>> {
+        Jobs::get_all_employee(self, employee_id).await
+    }
+    async fn post(
+        &self,
+        employee_id: &str,
+        body: &crate::types::PostJobRequest,
+    ) -> Result<crate::types::Job> {
+        Jobs::post(self, employee_id, body).await
+    }
+    async fn post_compensation(
+        &self,
+        job_id: &str,
+        body: &crate::types::PostJobCompensationsRequest,
+    ) -> Result<crate::types::Compensation> {
+        Jobs::post_compensation(self, job_id, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Locations {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Locations {
         Locations { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Locations {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get company locations.
      *
@@ -27,10 +33,16 @@ impl Locations {
     ) -> Result<Vec<crate::types::Location>> {
         let url = format!(
             "/v1/companies/{}/locations",
-            crate::progenitor_support::encode_path(&company_id_or_uuid.to_string()),
+            self.client.encode_path(company_id_or_uuid),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v1/companies/{company_id_or_uuid}/locations",
+                &[("company_id_or_uuid", company_id_or_uuid.to_string())],
+            )
+        })
     }
 
     /**
@@ -50,10 +62,19 @@ impl Locations {
     ) -> Result<Vec<crate::types::Location>> {
         let url = format!(
             "/v1/companies/{}/locations",
-            crate::progenitor_support::encode_path(&company_id_or_uuid.to_string()),
+            self.client.encode_path(company_id_or_uuid),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/v1/companies/{company_id_or_uuid}/locations",
+                    &[("company_id_or_uuid", company_id_or_uuid.to_string())],
+                )
+            })
     }
 
     /**
@@ -72,12 +93,19 @@ impl Locations {
     ) -> Result<crate::types::Location> {
         let url = format!(
             "/v1/companies/{}/locations",
-            crate::progenitor_support::encode_path(&company_id_or_uuid.to_string()),
+            self.client.encode_path(company_id_or_uuid),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v1/companies/{company_id_or_uuid}/locations",
+                    &[("company_id_or_uuid", company_id_or_uuid.to_string())],
+                )
+            })
     }
 
     /**
@@ -88,12 +116,15 @@ impl Locations {
      * Get a location.
      */
     pub async fn get(&self, location_id: &str) -> Result<crate::types::Location> {
-        let url = format!(
-            "/v1/locations/{}",
-            crate::progenitor_support::encode_path(&location_id.to_string()),
-        );
+        let url = format!("/v1/locations/{}", self.client.encode_path(location_id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v1/locations/{location_id}",
+                &[("location_id", location_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -108,13 +139,72 @@ impl Locations {
         location_id: &str,
         body: &crate::types::PutLocationRequest,
     ) -> Result<crate::types::Location> {
-        let url = format!(
-            "/v1/locations/{}",
-            crate::progenitor_support::encode_path(&location_id.to_string()),
-        );
+        let url = format!("/v1/locations/{}", self.client.encode_path(location_id),);
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v1/locations/{location_id}",
+                    &[("location_id", location_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`Locations`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn LocationsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait LocationsApi: Send + Sync {
+    async fn get_company(&self, company_id_or_uuid: &str) -> Result<Vec<crate::types::Location>>;
+    async fn get_all_company(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> Result<Vec<crate::types::Location>>;
+    async fn post_company(
+        &self,
+        company_id_or_uuid: &str,
+        body: &crate::types::PostCompanyLocationsRequest,
+    ) -> Result<crate::types::Location>;
+    async fn get(&self, location_id: &str) -> Result<crate::types::Location>;
+    async fn put(
+        &self,
+        location_id: &str,
+        body: &crate::types::PutLocationRequest,
+    ) -> Result<crate::types::Location>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl LocationsApi for Locations {
+    async fn get_company(&self, company_id_or_uuid: &str) -> Result<Vec<crate::types::Location>> {
+        Locations::get_company(self, company_id_or_uuid).await
+    }
+    async fn get_all_company(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> Result<Vec<crate::types::Location>> {
+        Locations::get_all_company(self, company_id_or_uuid).await
+    }
+    async fn post_company(
+        &self,
+        company_id_or_uuid: &str,
+        body: &crate::types::PostCompanyLocationsRequest,
+    ) -> Result<crate::types::Location> {
+        Locations::post_company(self, company_id_or_uuid, body).await
+    }
+    async fn get(&self, location_id: &str) -> Result<crate::types::Location> {
+        Locations::get(self, location_id).await
+    }
+    async fn put(
+        &self,
+        location_id: &str,
+        body: &crate::types::PutLocationRequest,
+    ) -> Result<crate::types::Location> {
+        Locations::put(self, location_id, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct PaySchedules {
     pub client: Client,
@@ -12,6 +10,14 @@ impl PaySchedules {
         PaySchedules { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        PaySchedules {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get the pay schedules for a company.
      *
@@ -22,10 +28,16 @@ impl PaySchedules {
     pub async fn get_company(&self, company_id: &str) -> Result<Vec<crate::types::PaySchedule>> {
         let url = format!(
             "/v1/companies/{}/pay_schedules",
-            crate::progenitor_support::encode_path(&company_id.to_string()),
+            self.client.encode_path(company_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v1/companies/{company_id}/pay_schedules",
+                &[("company_id", company_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -43,10 +55,19 @@ impl PaySchedules {
     ) -> Result<Vec<crate::types::PaySchedule>> {
         let url = format!(
             "/v1/companies/{}/pay_schedules",
-            crate::progenitor_support::encode_path(&company_id.to_string()),
+            self.client.encode_path(company_id),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/v1/companies/{company_id}/pay_schedules",
+                    &[("company_id", company_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -63,11 +84,23 @@ impl PaySchedules {
     ) -> Result<crate::types::PaySchedule> {
         let url = format!(
             "/v1/companies/{}/pay_schedules/{}",
-            crate::progenitor_support::encode_path(&company_id_or_uuid.to_string()),
-            crate::progenitor_support::encode_path(&pay_schedule_id_or_uuid.to_string()),
+            self.client.encode_path(company_id_or_uuid),
+            self.client.encode_path(pay_schedule_id_or_uuid),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v1/companies/{company_id_or_uuid}/pay_schedules/{pay_schedule_id_or_uuid}",
+                &[
+                    ("company_id_or_uuid", company_id_or_uuid.to_string()),
+                    (
+                        "pay_schedule_id_or_uuid",
+                        pay_schedule_id_or_uuid.to_string(),
+                    ),
+                ],
+            )
+        })
     }
 
     /**
@@ -87,12 +120,73 @@ impl PaySchedules {
     ) -> Result<crate::types::PaySchedule> {
         let url = format!(
             "/v1/companies/{}/pay_schedules/{}",
-            crate::progenitor_support::encode_path(&company_id_or_uuid.to_string()),
-            crate::progenitor_support::encode_path(&pay_schedule_id_or_uuid.to_string()),
+            self.client.encode_path(company_id_or_uuid),
+            self.client.encode_path(pay_schedule_id_or_uuid),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v1/companies/{company_id_or_uuid}/pay_schedules/{pay_schedule_id_or_uuid}",
+                    &[
+                        ("company_id_or_uuid", company_id_or_uuid.to_string()),
+                        (
+                            "pay_schedule_id_or_uuid",
+                            pay_schedule_id_or_uuid.to_string(),
+                        ),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`PaySchedules`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn PaySchedulesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait PaySchedulesApi: Send + Sync {
+    async fn get_company(&self, company_id: &str) -> Result<Vec<crate::types::PaySchedule>>;
+    async fn get_all_company(&self, company_id: &str) -> Result<Vec<crate::types::PaySchedule>>;
+    async fn get_company_schedule(
+        &self,
+        company_id_or_uuid: &str,
+        pay_schedule_id_or_uuid: &str,
+    ) -> Result<crate::types::PaySchedule>;
+    async fn put_company_schedule(
+        &self,
+        company_id_or_uuid: &str,
+        pay_schedule_id_or_uuid: &str,
+        body: &crate::types::PutCompanyPaySchedulesScheduleRequest,
+    ) -> Result<crate::types::PaySchedule>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl PaySchedulesApi for PaySchedules {
+    async fn get_company(&self, company_id: &str) -> Result<Vec<crate::types::PaySchedule>> {
+        PaySchedules::get_company(self, company_id).await
+    }
+    async fn get_all_company(&self, company_id: &str) -> Result<Vec<crate::types::PaySchedule>> {
+        PaySchedules::get_all_company(self, company_id).await
+    }
+    async fn get_company_schedule(
+        &self,
+        company_id_or_uuid: &str,
+        pay_schedule_id_or_uuid: &str,
+    ) -> Result<crate::types::PaySchedule> {
+        PaySchedules::get_company_schedule(self, company_id_or_uuid, pay_schedule_id_or_uuid).await
+    }
+    async fn put_company_schedule(
+        &self,
+        company_id_or_uuid: &str,
+        pay_schedule_id_or_uuid: &str,
+        body: &crate::types::PutCompanyPaySchedulesScheduleRequest,
+    ) -> Result<crate::types::PaySchedule> {
+        PaySchedules::put_company_schedule(self, company_id_or_uuid, pay_schedule_id_or_uuid, body)
             .await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Payroll {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Payroll {
         Payroll { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Payroll {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get pay periods for a company.
      *
@@ -33,21 +39,28 @@ impl Payroll {
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::PayPeriod>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/companies/{}/pay_periods?{}",
-            crate::progenitor_support::encode_path(&company_id_or_uuid.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v1/companies/{}/pay_periods",
+                self.client.encode_path(company_id_or_uuid)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v1/companies/{company_id_or_uuid}/pay_periods",
+                &[("company_id_or_uuid", company_id_or_uuid.to_string())],
+            )
+        })
     }
 
     /**
@@ -68,21 +81,31 @@ impl Payroll {
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::PayPeriod>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/companies/{}/pay_periods?{}",
-            crate::progenitor_support::encode_path(&company_id_or_uuid.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v1/companies/{}/pay_periods",
+                self.client.encode_path(company_id_or_uuid)
+            ),
+            &query_args,
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/v1/companies/{company_id_or_uuid}/pay_periods",
+                    &[("company_id_or_uuid", company_id_or_uuid.to_string())],
+                )
+            })
     }
 
     /**
@@ -114,33 +137,37 @@ impl Payroll {
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::PayrollData>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
-        if !include.is_empty() {
-            query_args.push(("include".to_string(), include.join(" ")));
+        for v in include {
+            query_args.push(("include", v.to_string()));
         }
         if include_off_cycle {
-            query_args.push((
-                "include_off_cycle".to_string(),
-                include_off_cycle.to_string(),
-            ));
+            query_args.push(("include_off_cycle", include_off_cycle.to_string()));
         }
         if processed {
-            query_args.push(("processed".to_string(), processed.to_string()));
+            query_args.push(("processed", processed.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/companies/{}/payrolls?{}",
-            crate::progenitor_support::encode_path(&company_id_or_uuid.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v1/companies/{}/payrolls",
+                self.client.encode_path(company_id_or_uuid)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v1/companies/{company_id_or_uuid}/payrolls",
+                &[("company_id_or_uuid", company_id_or_uuid.to_string())],
+            )
+        })
     }
 
     /**
@@ -166,33 +193,40 @@ impl Payroll {
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::PayrollData>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
-        if !include.is_empty() {
-            query_args.push(("include".to_string(), include.join(" ")));
+        for v in include {
+            query_args.push(("include", v.to_string()));
         }
         if include_off_cycle {
-            query_args.push((
-                "include_off_cycle".to_string(),
-                include_off_cycle.to_string(),
-            ));
+            query_args.push(("include_off_cycle", include_off_cycle.to_string()));
         }
         if processed {
-            query_args.push(("processed".to_string(), processed.to_string()));
+            query_args.push(("processed", processed.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/companies/{}/payrolls?{}",
-            crate::progenitor_support::encode_path(&company_id_or_uuid.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v1/companies/{}/payrolls",
+                self.client.encode_path(company_id_or_uuid)
+            ),
+            &query_args,
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/v1/companies/{company_id_or_uuid}/payrolls",
+                    &[("company_id_or_uuid", company_id_or_uuid.to_string())],
+                )
+            })
     }
 
     /**
@@ -211,12 +245,19 @@ impl Payroll {
     ) -> Result<crate::types::PayrollData> {
         let url = format!(
             "/v1/companies/{}/payrolls",
-            crate::progenitor_support::encode_path(&company_id_or_uuid.to_string()),
+            self.client.encode_path(company_id_or_uuid),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v1/companies/{company_id_or_uuid}/payrolls",
+                    &[("company_id_or_uuid", company_id_or_uuid.to_string())],
+                )
+            })
     }
 
     /**
@@ -243,22 +284,32 @@ impl Payroll {
         include: crate::types::GetCompanyPayrollsInclude,
         show_calculation: &str,
     ) -> Result<crate::types::PayrollData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include.to_string().is_empty() {
-            query_args.push(("include".to_string(), include.to_string()));
+            query_args.push(("include", include.to_string()));
         }
         if !show_calculation.is_empty() {
-            query_args.push(("show_calculation".to_string(), show_calculation.to_string()));
+            query_args.push(("show_calculation", show_calculation.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/companies/{}/payrolls/{}?{}",
-            crate::progenitor_support::encode_path(&company_id_or_uuid.to_string()),
-            crate::progenitor_support::encode_path(&payroll_id_or_uuid.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v1/companies/{}/payrolls/{}",
+                self.client.encode_path(company_id_or_uuid),
+                self.client.encode_path(payroll_id_or_uuid)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v1/companies/{company_id_or_uuid}/payrolls/{payroll_id_or_uuid}",
+                &[
+                    ("company_id_or_uuid", company_id_or_uuid.to_string()),
+                    ("payroll_id_or_uuid", payroll_id_or_uuid.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -276,13 +327,23 @@ impl Payroll {
     ) -> Result<crate::types::PayrollData> {
         let url = format!(
             "/v1/companies/{}/payrolls/{}",
-            crate::progenitor_support::encode_path(&company_id_or_uuid.to_string()),
-            crate::progenitor_support::encode_path(&payroll_id_or_uuid.to_string()),
+            self.client.encode_path(company_id_or_uuid),
+            self.client.encode_path(payroll_id_or_uuid),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/v1/companies/{company_id_or_uuid}/payrolls/{payroll_id_or_uuid}",
+                    &[
+                        ("company_id_or_uuid", company_id_or_uuid.to_string()),
+                        ("payroll_id_or_uuid", payroll_id_or_uuid.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -303,14 +364,14 @@ impl Payroll {
     ) -> Result<crate::types::PayrollData> {
         let url = format!(
             "/v1/companies/{}/payrolls/{}/{}",
-            crate::progenitor_support::encode_path(&company_id_or_uuid.to_string()),
-            crate::progenitor_support::encode_path(&pay_period_start_date.to_string()),
-            crate::progenitor_support::encode_path(&pay_period_end_date.to_string()),
+            self.client.encode_path(company_id_or_uuid),
+            self.client.encode_path(pay_period_start_date),
+            self.client.encode_path(pay_period_end_date),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("PUT", "/v1/companies/{company_id_or_uuid}/payrolls/{pay_period_start_date}/{pay_period_end_date}", &[("company_id_or_uuid", company_id_or_uuid.to_string()), ("pay_period_start_date", pay_period_start_date.to_string()), ("pay_period_end_date", pay_period_end_date.to_string())]))
     }
 
     /**
@@ -327,11 +388,20 @@ impl Payroll {
     pub async fn put_company_calculate(&self, company_id: &str, payroll_id: &str) -> Result<()> {
         let url = format!(
             "/v1/companies/{}/payrolls/{}/calculate",
-            crate::progenitor_support::encode_path(&company_id.to_string()),
-            crate::progenitor_support::encode_path(&payroll_id.to_string()),
+            self.client.encode_path(company_id),
+            self.client.encode_path(payroll_id),
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "PUT",
+                "/v1/companies/{company_id}/payrolls/{payroll_id}/calculate",
+                &[
+                    ("company_id", company_id.to_string()),
+                    ("payroll_id", payroll_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -346,11 +416,20 @@ impl Payroll {
     pub async fn put_company_submit(&self, company_id: &str, payroll_id: &str) -> Result<()> {
         let url = format!(
             "/v1/companies/{}/payrolls/{}/submit",
-            crate::progenitor_support::encode_path(&company_id.to_string()),
-            crate::progenitor_support::encode_path(&payroll_id.to_string()),
+            self.client.encode_path(company_id),
+            self.client.encode_path(payroll_id),
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "PUT",
+                "/v1/companies/{company_id}/payrolls/{payroll_Id}/submit",
+                &[
+                    ("company_id", company_id.to_string()),
+                    ("payroll_Id", payroll_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -370,11 +449,20 @@ impl Payroll {
     ) -> Result<crate::types::PayrollData> {
         let url = format!(
             "/v1/companies/{}/payrolls/{}/cancel",
-            crate::progenitor_support::encode_path(&company_id.to_string()),
-            crate::progenitor_support::encode_path(&payroll_id.to_string()),
+            self.client.encode_path(company_id),
+            self.client.encode_path(payroll_id),
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "PUT",
+                "/v1/companies/{company_id}/payrolls/{payroll_id}/cancel",
+                &[
+                    ("company_id", company_id.to_string()),
+                    ("payroll_id", payroll_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -390,9 +478,216 @@ impl Payroll {
     ) -> Result<crate::types::GetCompanyPayrollReversalsResponse> {
         let url = format!(
             "/v1/companies/{}/payroll_reversals",
-            crate::progenitor_support::encode_path(&company_id_or_uuid.to_string()),
+            self.client.encode_path(company_id_or_uuid),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v1/companies/{company_id_or_uuid}/payroll_reversals",
+                &[("company_id_or_uuid", company_id_or_uuid.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`Payroll`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn PayrollApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait PayrollApi: Send + Sync {
+    async fn get_company_pay_periods(
+        &self,
+        company_id_or_uuid: &str,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::PayPeriod>>;
+    async fn get_all_company_pay_periods(
+        &self,
+        company_id_or_uuid: &str,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::PayPeriod>>;
+    async fn get_company(
+        &self,
+        company_id_or_uuid: &str,
+        processed: bool,
+        include_off_cycle: bool,
+        include: &[String],
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::PayrollData>>;
+    async fn get_all_company(
+        &self,
+        company_id_or_uuid: &str,
+        processed: bool,
+        include_off_cycle: bool,
+        include: &[String],
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::PayrollData>>;
+    async fn post_company(
+        &self,
+        company_id_or_uuid: &str,
+        body: &crate::types::PostCompanyPayrollsRequest,
+    ) -> Result<crate::types::PayrollData>;
+    async fn get_company_payroll(
+        &self,
+        company_id_or_uuid: &str,
+        payroll_id_or_uuid: &str,
+        include: crate::types::GetCompanyPayrollsInclude,
+        show_calculation: &str,
+    ) -> Result<crate::types::PayrollData>;
+    async fn put_company(
+        &self,
+        company_id_or_uuid: &str,
+        payroll_id_or_uuid: &str,
+        body: &crate::types::PutCompanyPayrollsRequest,
+    ) -> Result<crate::types::PayrollData>;
+    async fn put_company_pay_period_start_date_end(
+        &self,
+        company_id_or_uuid: &str,
+        pay_period_start_date: &str,
+        pay_period_end_date: &str,
+        body: &crate::types::PutCompanyPayrollsRequest,
+    ) -> Result<crate::types::PayrollData>;
+    async fn put_company_calculate(&self, company_id: &str, payroll_id: &str) -> Result<()>;
+    async fn put_company_submit(&self, company_id: &str, payroll_id: &str) -> Result<()>;
+    async fn put_company_cancel(
+        &self,
+        company_id: &str,
+        payroll_id: &str,
+    ) -> Result<crate::types::PayrollData>;
+    async fn get_company_or_reversals(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> Result<crate::types::GetCompanyPayrollReversalsResponse>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl PayrollApi for Payroll {
+    async fn get_company_pay_periods(
+        &self,
+        company_id_or_uuid: &str,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::PayPeriod>> {
+        Payroll::get_company_pay_periods(self, company_id_or_uuid, start_date, end_date).await
+    }
+    async fn get_all_company_pay_periods(
+        &self,
+        company_id_or_uuid: &str,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::PayPeriod>> {
+        Payroll::get_all_company_pay_periods(self, company_id_or_uuid, start_date, end_date).await
+    }
+    async fn get_company(
+        &self,
+        company_id_or_uuid: &str,
+        processed: bool,
+        include_off_cycle: bool,
+        include: &[String],
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::PayrollData>> {
+        Payroll::get_company(
+            self,
+            company_id_or_uuid,
+            processed,
+            include_off_cycle,
+            include,
+            start_date,
+            end_date,
+        )
+        .await
+    }
+    async fn get_all_company(
+        &self,
+        company_id_or_uuid: &str,
+        processed: bool,
+        include_off_cycle: bool,
+        include: &[String],
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::PayrollData>> {
+        Payroll::get_all_company(
+            self,
+            company_id_or_uuid,
+            processed,
+            include_off_cycle,
+            include,
+            start_date,
+            end_date,
+        )
+        .await
+    }
+    async fn post_company(
+        &self,
+        company_id_or_uuid: &str,
+        body: &crate::types::PostCompanyPayrollsRequest,
+    ) -> Result<crate::types::PayrollData> {
+        Payroll::post_company(self, company_id_or_uuid, body).await
+    }
+    async fn get_company_payroll(
+        &self,
+        company_id_or_uuid: &str,
+        payroll_id_or_uuid: &str,
+        include: crate::types::GetCompanyPayrollsInclude,
+        show_calculation: &str,
+    ) -> Result<crate::types::PayrollData> {
+        Payroll::get_company_payroll(
+            self,
+            company_id_or_uuid,
+            payroll_id_or_uuid,
+            include,
+            show_calculation,
+        )
+        .await
+    }
+    async fn put_company(
+        &self,
+        company_id_or_uuid: &str,
+        payroll_id_or_uuid: &str,
+        body: &crate::types::PutCompanyPayrollsRequest,
+    ) -> Result<crate::types::PayrollData> {
+        Payroll::put_company(self, company_id_or_uuid, payroll_id_or_uuid, body).await
+    }
+    async fn put_company_pay_period_start_date_end(
+        &self,
+        company_id_or_uuid: &str,
+        pay_period_start_date: &str,
+        pay_period_end_date: &str,
+        body: &crate::types::PutCompanyPayrollsRequest,
+    ) -> Result<crate::types::PayrollData> {
+        Payroll::put_company_pay_period_start_date_end(
+            self,
+            company_id_or_uuid,
+            pay_period_start_date,
+            pay_period_end_date,
+            body,
+        )
+        .await
+    }
+    async fn put_company_calculate(&self, company_id: &str, payroll_id: &str) -> Result<()> {
+        Payroll::put_company_calculate(self, company_id, payroll_id).await
+    }
+    async fn put_company_submit(&self, company_id: &str, payroll_id: &str) -> Result<()> {
+        Payroll::put_company_submit(self, company_id, payroll_id).await
+    }
+    async fn put_company_cancel(
+        &self,
+        company_id: &str,
+        payroll_id: &str,
+    ) -> Result<crate::types::PayrollData> {
+        Payroll::put_company_cancel(self, company_id, payroll_id).await
+    }
+    async fn get_company_or_reversals(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> Result<crate::types::GetCompanyPayrollReversalsResponse> {
+        Payroll::get_company_or_reversals(self, company_id_or_uuid).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Terminations {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Terminations {
         Terminations { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Terminations {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get terminations for an employee.
      *
@@ -24,10 +30,16 @@ impl Terminations {
     pub async fn get_employee(&self, employee_id: &str) -> Result<Vec<crate::types::Termination>> {
         let url = format!(
             "/v1/employees/{}/terminations",
-            crate::progenitor_support::encode_path(&employee_id.to_string()),
+            self.client.encode_path(employee_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v1/employees/{employee_id}/terminations",
+                &[("employee_id", employee_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -47,10 +59,19 @@ impl Terminations {
     ) -> Result<Vec<crate::types::Termination>> {
         let url = format!(
             "/v1/employees/{}/terminations",
-            crate::progenitor_support::encode_path(&employee_id.to_string()),
+            self.client.encode_path(employee_id),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/v1/employees/{employee_id}/terminations",
+                    &[("employee_id", employee_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -69,11 +90,51 @@ impl Terminations {
     ) -> Result<crate::types::Termination> {
         let url = format!(
             "/v1/employees/{}/terminations",
-            crate::progenitor_support::encode_path(&employee_id.to_string()),
+            self.client.encode_path(employee_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/v1/employees/{employee_id}/terminations",
+                    &[("employee_id", employee_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`Terminations`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn TerminationsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait TerminationsApi: Send + Sync {
+    async fn get_employee(&self, employee_id: &str) -> Result<Vec<crate::types::Termination>>;
+    async fn get_all_employee(&self, employee_id: &str) -> Result<Vec<crate::types::Termination>>;
+    async fn post_employee(
+        &self,
+        employee_id: &str,
+        body: &crate::types::PostEmployeeTerminationsRequest,
+    ) -> Result<crate::types::Termination>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl TerminationsApi for Terminations {
+    async fn get_employee(&self, employee_id: &str) -> Result<Vec<crate::types::Termination>> {
+        Terminations::get_employee(self, employee_id).await
+    }
+    async fn get_all_employee(&self, employee_id: &str) -> Result<Vec<crate::types::Termination>> {
+        Terminations::get_all_employee(self, employee_id).await
+    }
+    async fn post_employee(
+        &self,
+        employee_id: &str,
+        body: &crate::types::PostEmployeeTerminationsRequest,
+    ) -> Result<crate::types::Termination> {
+        Terminations::post_employee(self, employee_id, body).await
     }
 }
@@ -129,18 +129,13 @@ fn test_deserialize_employee() {
     let first_job = deserialized.jobs.first().unwrap();
     let first_compensation = first_job.compensations.first().unwrap();
     assert_eq!(
-        first_compensation
-            .flsa_status
-            .as_ref()
-            .unwrap()
-            .clone()
-            .to_string(),
+        first_compensation.flsa_status.unwrap().to_string(),
         // TODO: fix this better.
         //"Commission Only Exempt".to_string()
         "*".to_string()
     );
     assert_eq!(
-        first_compensation.payment_unit.as_ref().unwrap().clone(),
+        first_compensation.payment_unit.unwrap(),
         crate::types::PaymentUnit::Year
     );
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct TimeOffRequests {
     pub client: Client,
@@ -12,6 +10,14 @@ impl TimeOffRequests {
         TimeOffRequests { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        TimeOffRequests {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get time off requests for a company.
      *
@@ -47,21 +53,28 @@ impl TimeOffRequests {
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::TimeOffRequest>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/companies/{}/time_off_requests?{}",
-            crate::progenitor_support::encode_path(&company_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v1/companies/{}/time_off_requests",
+                self.client.encode_path(company_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v1/companies/{company_id}/time_off_requests",
+                &[("company_id", company_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -96,21 +109,31 @@ impl TimeOffRequests {
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::TimeOffRequest>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/v1/companies/{}/time_off_requests?{}",
-            crate::progenitor_support::encode_path(&company_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/v1/companies/{}/time_off_requests",
+                self.client.encode_path(company_id)
+            ),
+            &query_args,
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/v1/companies/{company_id}/time_off_requests",
+                    &[("company_id", company_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -127,10 +150,72 @@ impl TimeOffRequests {
     ) -> Result<crate::types::TimeOffRequest> {
         let url = format!(
             "/v1/companies/{}/time_off_requests/{}",
-            crate::progenitor_support::encode_path(&company_id.to_string()),
-            crate::progenitor_support::encode_path(&time_off_request_id.to_string()),
+            self.client.encode_path(company_id),
+            self.client.encode_path(time_off_request_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/v1/companies/{company_id}/time_off_requests/{time_off_request_id}",
+                &[
+                    ("company_id", company_id.to_string()),
+                    ("time_off_request_id", time_off_request_id.to_string()),
+                ],
+            )
+        })
+    }
+}
+
+/// The functions of [`TimeOffRequests`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn TimeOffRequestsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait TimeOffRequestsApi: Send + Sync {
+    async fn get_company(
+        &self,
+        company_id: &str,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::TimeOffRequest>>;
+    async fn get_all_company(
+        &self,
+        company_id: &str,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::TimeOffRequest>>;
+    async fn get_company_request(
+        &self,
+        company_id: &str,
+        time_off_request_id: &str,
+    ) -> Result<crate::types::TimeOffRequest>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl TimeOffRequestsApi for TimeOffRequests {
+    async fn get_company(
+        &self,
+        company_id: &str,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::TimeOffRequest>> {
+        TimeOffRequests::get_company(self, company_id, start_date, end_date).await
+    }
+    async fn get_all_company(
+        &self,
+        company_id: &str,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::TimeOffRequest>> {
+        TimeOffRequests::get_all_company(self, company_id, start_date, end_date).await
+    }
+    async fn get_company_request(
+        &self,
+        company_id: &str,
+        time_off_request_id: &str,
+    ) -> Result<crate::types::TimeOffRequest> {
+        TimeOffRequests::get_company_request(self, company_id, time_off_request_id).await
     }
 }
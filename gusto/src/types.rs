@@ -2,6 +2,33 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+/// Either one of something, or many of them. Some endpoints send back an
+/// object when there is one and an array when there are more, so this takes both.
+// Many is tried first: serde reads a struct from an array of its fields too,
+// so an array would otherwise be taken for One.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum OneOrMany<T> {
+    Many(Vec<T>),
+    One(T),
+}
+
+impl<T> OneOrMany<T> {
+    /// All of them, as a vector.
+    pub fn into_vec(self) -> Vec<T> {
+        match self {
+            OneOrMany::One(t) => vec![t],
+            OneOrMany::Many(v) => v,
+        }
+    }
+}
+
+impl<T> Default for OneOrMany<T> {
+    fn default() -> Self {
+        OneOrMany::Many(Vec::new())
+    }
+}
+
 /// The representation of an employee in Gusto.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Employee {
@@ -591,7 +618,7 @@ pub struct Termination {
 /**
  * The unit accompanying the compensation rate. If the employee is an owner, rate should be 'Paycheck'.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PaymentUnit {
     #[serde(rename = "Hour")]
     Hour,
@@ -629,7 +656,32 @@ impl Default for PaymentUnit {
         PaymentUnit::Noop
     }
 }
+
 impl PaymentUnit {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PaymentUnit::Hour => "Hour",
+            PaymentUnit::Month => "Month",
+            PaymentUnit::Paycheck => "Paycheck",
+            PaymentUnit::Week => "Week",
+            PaymentUnit::Year => "Year",
+            PaymentUnit::Noop => "",
+            PaymentUnit::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PaymentUnit] {
+        &[
+            PaymentUnit::Hour,
+            PaymentUnit::Month,
+            PaymentUnit::Paycheck,
+            PaymentUnit::Week,
+            PaymentUnit::Year,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PaymentUnit::Noop)
     }
@@ -638,7 +690,7 @@ impl PaymentUnit {
 /**
  * The FLSA status for this compensation. Salaried ('Exempt') employees are paid a fixed salary every pay period. Salaried with overtime ('Salaried Nonexempt') employees are paid a fixed salary every pay period, and receive overtime pay when applicable. Hourly ('Nonexempt') employees are paid for the hours they work, and receive overtime pay when applicable. Owners ('Owner') are employees that own at least twenty percent of the company.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum FlsaStatus {
     #[serde(rename = "Exempt")]
     Exempt,
@@ -673,7 +725,30 @@ impl Default for FlsaStatus {
         FlsaStatus::Noop
     }
 }
+
 impl FlsaStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FlsaStatus::Exempt => "Exempt",
+            FlsaStatus::Nonexempt => "Nonexempt",
+            FlsaStatus::Owner => "Owner",
+            FlsaStatus::SalariedNonexempt => "Salaried Nonexempt",
+            FlsaStatus::Noop => "",
+            FlsaStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [FlsaStatus] {
+        &[
+            FlsaStatus::Exempt,
+            FlsaStatus::Nonexempt,
+            FlsaStatus::Owner,
+            FlsaStatus::SalariedNonexempt,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, FlsaStatus::Noop)
     }
@@ -951,7 +1026,7 @@ pub struct Admin {
 /**
  * The tax payer type of the company.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum EntityType {
     #[serde(rename = "Association")]
     Association,
@@ -1010,7 +1085,46 @@ impl Default for EntityType {
         EntityType::Noop
     }
 }
+
 impl EntityType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EntityType::Association => "Association",
+            EntityType::CCorporation => "C-Corporation",
+            EntityType::CoOwnership => "Co-ownership",
+            EntityType::GeneralPartnership => "General partnership",
+            EntityType::JointVenture => "Joint venture",
+            EntityType::Llc => "LLC",
+            EntityType::Llp => "LLP",
+            EntityType::LimitedPartnership => "Limited partnership",
+            EntityType::NonProfit => "Non-Profit",
+            EntityType::SCorporation => "S-Corporation",
+            EntityType::SoleProprietor => "Sole proprietor",
+            EntityType::Trusteeship => "Trusteeship",
+            EntityType::Noop => "",
+            EntityType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [EntityType] {
+        &[
+            EntityType::Association,
+            EntityType::CCorporation,
+            EntityType::CoOwnership,
+            EntityType::GeneralPartnership,
+            EntityType::JointVenture,
+            EntityType::Llc,
+            EntityType::Llp,
+            EntityType::LimitedPartnership,
+            EntityType::NonProfit,
+            EntityType::SCorporation,
+            EntityType::SoleProprietor,
+            EntityType::Trusteeship,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, EntityType::Noop)
     }
@@ -1019,7 +1133,7 @@ impl EntityType {
 /**
  * The Gusto product tier of the company.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Tier {
     #[serde(rename = "basic")]
     Basic,
@@ -1057,7 +1171,32 @@ impl Default for Tier {
         Tier::Noop
     }
 }
+
 impl Tier {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Tier::Basic => "basic",
+            Tier::Complete => "complete",
+            Tier::Concierge => "concierge",
+            Tier::ContractorOnly => "contractor_only",
+            Tier::Core => "core",
+            Tier::Noop => "",
+            Tier::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Tier] {
+        &[
+            Tier::Basic,
+            Tier::Complete,
+            Tier::Concierge,
+            Tier::ContractorOnly,
+            Tier::Core,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Tier::Noop)
     }
@@ -1066,7 +1205,7 @@ impl Tier {
 /**
  * The status of the company in Gusto. "Approved" companies may run payroll with Gusto. "Not Approved" companies may not yet run payroll with Gusto. In order to run payroll, the company may need to complete onboarding or contact support. "Suspended" companies may not run payroll with Gusto. In order to unsuspend their account, the company must contact support.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum CompanyStatus {
     #[serde(rename = "Approved")]
     Approved,
@@ -1098,7 +1237,28 @@ impl Default for CompanyStatus {
         CompanyStatus::Noop
     }
 }
+
 impl CompanyStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CompanyStatus::Approved => "Approved",
+            CompanyStatus::NotApproved => "Not Approved",
+            CompanyStatus::Suspended => "Suspended",
+            CompanyStatus::Noop => "",
+            CompanyStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [CompanyStatus] {
+        &[
+            CompanyStatus::Approved,
+            CompanyStatus::NotApproved,
+            CompanyStatus::Suspended,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, CompanyStatus::Noop)
     }
@@ -1398,7 +1558,7 @@ pub struct Company {
 /**
  * The contractor's wage type, either "Fixed" or "Hourly".
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum WageType {
     #[serde(rename = "Fixed")]
     Fixed,
@@ -1427,7 +1587,23 @@ impl Default for WageType {
         WageType::Noop
     }
 }
+
 impl WageType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WageType::Fixed => "Fixed",
+            WageType::Hourly => "Hourly",
+            WageType::Noop => "",
+            WageType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [WageType] {
+        &[WageType::Fixed, WageType::Hourly]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, WageType::Noop)
     }
@@ -1436,7 +1612,7 @@ impl WageType {
 /**
  * The contractor's type, either "Individual" or "Business".
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Type {
     #[serde(rename = "Business")]
     Business,
@@ -1465,7 +1641,23 @@ impl Default for Type {
         Type::Noop
     }
 }
+
 impl Type {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Type::Business => "Business",
+            Type::Individual => "Individual",
+            Type::Noop => "",
+            Type::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Type] {
+        &[Type::Business, Type::Individual]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Type::Noop)
     }
@@ -1651,7 +1843,7 @@ pub struct Contractor {
 /**
  * The payment method.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PaymentMethod {
     #[serde(rename = "Check")]
     Check,
@@ -1686,7 +1878,30 @@ impl Default for PaymentMethod {
         PaymentMethod::Noop
     }
 }
+
 impl PaymentMethod {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PaymentMethod::Check => "Check",
+            PaymentMethod::CorrectionPayment => "Correction Payment",
+            PaymentMethod::DirectDeposit => "Direct Deposit",
+            PaymentMethod::HistoricalPayment => "Historical Payment",
+            PaymentMethod::Noop => "",
+            PaymentMethod::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PaymentMethod] {
+        &[
+            PaymentMethod::Check,
+            PaymentMethod::CorrectionPayment,
+            PaymentMethod::DirectDeposit,
+            PaymentMethod::HistoricalPayment,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PaymentMethod::Noop)
     }
@@ -1874,7 +2089,7 @@ pub struct ContractorPaymentSummary {
 /**
  * The status of the time off request.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Status {
     #[serde(rename = "approved")]
     Approved,
@@ -1906,7 +2121,24 @@ impl Default for Status {
         Status::Noop
     }
 }
+
 impl Status {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Status::Approved => "approved",
+            Status::Denied => "denied",
+            Status::Pending => "pending",
+            Status::Noop => "",
+            Status::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Status] {
+        &[Status::Approved, Status::Denied, Status::Pending]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Status::Noop)
     }
@@ -1915,7 +2147,7 @@ impl Status {
 /**
  * The type of time off request.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum RequestType {
     #[serde(rename = "sick")]
     Sick,
@@ -1944,7 +2176,23 @@ impl Default for RequestType {
         RequestType::Noop
     }
 }
+
 impl RequestType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RequestType::Sick => "sick",
+            RequestType::Vacation => "vacation",
+            RequestType::Noop => "",
+            RequestType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [RequestType] {
+        &[RequestType::Sick, RequestType::Vacation]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, RequestType::Noop)
     }
@@ -2161,7 +2409,7 @@ pub struct CurrentUser {
     pub roles: Option<Roles>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Frequency {
     #[serde(rename = "Every other week")]
     EveryOtherWeek,
@@ -2196,7 +2444,30 @@ impl Default for Frequency {
         Frequency::Noop
     }
 }
+
 impl Frequency {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Frequency::EveryOtherWeek => "Every other week",
+            Frequency::EveryWeek => "Every week",
+            Frequency::Monthly => "Monthly",
+            Frequency::TwicePerMonth => "Twice per month",
+            Frequency::Noop => "",
+            Frequency::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Frequency] {
+        &[
+            Frequency::EveryOtherWeek,
+            Frequency::EveryWeek,
+            Frequency::Monthly,
+            Frequency::TwicePerMonth,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Frequency::Noop)
     }
@@ -2274,7 +2545,7 @@ pub struct PaySchedule {
 /**
  * Bank account type
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum AccountType {
     #[serde(rename = "Checking")]
     Checking,
@@ -2303,7 +2574,23 @@ impl Default for AccountType {
         AccountType::Noop
     }
 }
+
 impl AccountType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AccountType::Checking => "Checking",
+            AccountType::Savings => "Savings",
+            AccountType::Noop => "",
+            AccountType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [AccountType] {
+        &[AccountType::Checking, AccountType::Savings]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, AccountType::Noop)
     }
@@ -2316,7 +2603,7 @@ impl AccountType {
  *   'ready_for_verification' means the micro-deposits are completed and the verification process can begin by using the verify endpoint.
  *   'verified' means the bank account is verified.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum VerificationStatus {
     #[serde(rename = "awaiting_deposits")]
     AwaitingDeposits,
@@ -2348,7 +2635,28 @@ impl Default for VerificationStatus {
         VerificationStatus::Noop
     }
 }
+
 impl VerificationStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VerificationStatus::AwaitingDeposits => "awaiting_deposits",
+            VerificationStatus::ReadyForVerification => "ready_for_verification",
+            VerificationStatus::Verified => "verified",
+            VerificationStatus::Noop => "",
+            VerificationStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [VerificationStatus] {
+        &[
+            VerificationStatus::AwaitingDeposits,
+            VerificationStatus::ReadyForVerification,
+            VerificationStatus::Verified,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, VerificationStatus::Noop)
     }
@@ -2594,7 +2902,7 @@ pub struct EarningType {
 /**
  * Whether the employee deduction reduces taxable income or not. Only valid for Group Term Life benefits. Note: when the value is not "unset", coverage amount and coverage salary multiplier are ignored.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum DeductionReducesTaxableIncome {
     #[serde(rename = "does_not_reduce_taxable_income")]
     DoesNotReduceTaxableIncome,
@@ -2626,6 +2934,29 @@ impl Default for DeductionReducesTaxableIncome {
     }
 }
 
+impl DeductionReducesTaxableIncome {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DeductionReducesTaxableIncome::DoesNotReduceTaxableIncome => {
+                "does_not_reduce_taxable_income"
+            }
+            DeductionReducesTaxableIncome::ReducesTaxableIncome => "reduces_taxable_income",
+            DeductionReducesTaxableIncome::Unset => "unset",
+            DeductionReducesTaxableIncome::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [DeductionReducesTaxableIncome] {
+        &[
+            DeductionReducesTaxableIncome::DoesNotReduceTaxableIncome,
+            DeductionReducesTaxableIncome::ReducesTaxableIncome,
+            DeductionReducesTaxableIncome::Unset,
+        ]
+    }
+}
+
 /// The representation of an employee benefit.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EmployeeBenefit {
@@ -3070,7 +3401,7 @@ pub struct Totals {
 /**
  * The employee's compensation payment method. This value is only available for processed payrolls.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PayrollEmployeeCompensationsPaymentMethod {
     #[serde(rename = "Check")]
     Check,
@@ -3099,7 +3430,26 @@ impl Default for PayrollEmployeeCompensationsPaymentMethod {
         PayrollEmployeeCompensationsPaymentMethod::Noop
     }
 }
+
 impl PayrollEmployeeCompensationsPaymentMethod {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PayrollEmployeeCompensationsPaymentMethod::Check => "Check",
+            PayrollEmployeeCompensationsPaymentMethod::DirectDeposit => "Direct Deposit",
+            PayrollEmployeeCompensationsPaymentMethod::Noop => "",
+            PayrollEmployeeCompensationsPaymentMethod::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PayrollEmployeeCompensationsPaymentMethod] {
+        &[
+            PayrollEmployeeCompensationsPaymentMethod::Check,
+            PayrollEmployeeCompensationsPaymentMethod::DirectDeposit,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PayrollEmployeeCompensationsPaymentMethod::Noop)
     }
@@ -3468,7 +3818,7 @@ pub struct PayrollData {
     pub version: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum CustomFieldType {
     #[serde(rename = "currency")]
     Currency,
@@ -3506,7 +3856,32 @@ impl Default for CustomFieldType {
         CustomFieldType::Noop
     }
 }
+
 impl CustomFieldType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CustomFieldType::Currency => "currency",
+            CustomFieldType::Date => "date",
+            CustomFieldType::Number => "number",
+            CustomFieldType::Radio => "radio",
+            CustomFieldType::Text => "text",
+            CustomFieldType::Noop => "",
+            CustomFieldType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [CustomFieldType] {
+        &[
+            CustomFieldType::Currency,
+            CustomFieldType::Date,
+            CustomFieldType::Number,
+            CustomFieldType::Radio,
+            CustomFieldType::Text,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, CustomFieldType::Noop)
     }
@@ -3616,7 +3991,7 @@ pub struct CompanyCustomField {
     pub type_: CustomFieldType,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GustoPersonType {
     #[serde(rename = "Candidate")]
     Candidate,
@@ -3648,7 +4023,28 @@ impl Default for GustoPersonType {
         GustoPersonType::Noop
     }
 }
+
 impl GustoPersonType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GustoPersonType::Candidate => "Candidate",
+            GustoPersonType::Contractor => "Contractor",
+            GustoPersonType::Employee => "Employee",
+            GustoPersonType::Noop => "",
+            GustoPersonType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GustoPersonType] {
+        &[
+            GustoPersonType::Candidate,
+            GustoPersonType::Contractor,
+            GustoPersonType::Employee,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, GustoPersonType::Noop)
     }
@@ -3870,7 +4266,7 @@ pub struct EarningTypeListResponse {
     pub default: Vec<EarningType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Include {
     #[serde(rename = "custom_fields")]
     CustomFields,
@@ -3896,7 +4292,22 @@ impl Default for Include {
         Include::Noop
     }
 }
+
 impl Include {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Include::CustomFields => "custom_fields",
+            Include::Noop => "",
+            Include::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Include] {
+        &[Include::CustomFields]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Include::Noop)
     }
@@ -5065,7 +5476,7 @@ pub struct PutEmployeeBenefitRequest {
     pub version: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GetCompanyPayrollsInclude {
     #[serde(rename = "benefits")]
     Benefits,
@@ -5097,13 +5508,34 @@ impl Default for GetCompanyPayrollsInclude {
         GetCompanyPayrollsInclude::Noop
     }
 }
+
 impl GetCompanyPayrollsInclude {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GetCompanyPayrollsInclude::Benefits => "benefits",
+            GetCompanyPayrollsInclude::Deductions => "deductions",
+            GetCompanyPayrollsInclude::Taxes => "taxes",
+            GetCompanyPayrollsInclude::Noop => "",
+            GetCompanyPayrollsInclude::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GetCompanyPayrollsInclude] {
+        &[
+            GetCompanyPayrollsInclude::Benefits,
+            GetCompanyPayrollsInclude::Deductions,
+            GetCompanyPayrollsInclude::Taxes,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, GetCompanyPayrollsInclude::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum OffCycleReason {
     #[serde(rename = "Bonus")]
     Bonus,
@@ -5132,7 +5564,23 @@ impl Default for OffCycleReason {
         OffCycleReason::Noop
     }
 }
+
 impl OffCycleReason {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OffCycleReason::Bonus => "Bonus",
+            OffCycleReason::Correction => "Correction",
+            OffCycleReason::Noop => "",
+            OffCycleReason::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [OffCycleReason] {
+        &[OffCycleReason::Bonus, OffCycleReason::Correction]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, OffCycleReason::Noop)
     }
@@ -5594,7 +6042,7 @@ pub struct GetCompanyCustomFieldsResponse {
 /**
  * Must be "Employee" if send_offer is set to true.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum OnboardingPersonType {
     #[serde(rename = "Contractor")]
     Contractor,
@@ -5623,7 +6071,26 @@ impl Default for OnboardingPersonType {
         OnboardingPersonType::Noop
     }
 }
+
 impl OnboardingPersonType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OnboardingPersonType::Contractor => "Contractor",
+            OnboardingPersonType::Employee => "Employee",
+            OnboardingPersonType::Noop => "",
+            OnboardingPersonType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [OnboardingPersonType] {
+        &[
+            OnboardingPersonType::Contractor,
+            OnboardingPersonType::Employee,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, OnboardingPersonType::Noop)
     }
@@ -5978,3 +6445,123 @@ pub struct PutCompanyFederalTaxDetailsRequest {
     )]
     pub version: String,
 }
+
+/// The types taken and returned by the functions in [`crate::admins_beta`].
+pub mod admins_beta {
+    pub use super::{Admin, PostCompanyAdminsRequest};
+}
+
+/// The types taken and returned by the functions in [`crate::benefits`].
+pub mod benefits {
+    pub use super::{
+        CompanyBenefit, EmployeeBenefit, PostCompanyBenefitsRequest, PostEmployeeBenefitsRequest,
+        PostEmployeeYtdBenefitAmountsFromDifferentCompanyRequest, PutCompanyBenefitRequest,
+        PutEmployeeBenefitRequest, SupportedBenefit,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::companies`].
+pub mod companies {
+    pub use super::{
+        Company, PostPartnerManagedCompaniesRequest, PostPartnerManagedCompaniesResponse,
+        PostProvisionRequest, PostProvisionResponse,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::company_bank_accounts_beta`].
+pub mod company_bank_accounts_beta {
+    pub use super::{
+        CompanyBankAccount, PostCompanyBankAccountsRequest, PutCompanyBankAccountsVerifyRequest,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::compensations`].
+pub mod compensations {
+    pub use super::{Compensation, PutCompensationRequest};
+}
+
+/// The types taken and returned by the functions in [`crate::contractor_payments`].
+pub mod contractor_payments {
+    pub use super::{ContractorPayment, ContractorPaymentSummary};
+}
+
+/// The types taken and returned by the functions in [`crate::contractors`].
+pub mod contractors {
+    pub use super::{Contractor, PostCompanyContractorsRequest, PutComntractorRequest};
+}
+
+/// The types taken and returned by the functions in [`crate::current_user`].
+pub mod current_user {
+    pub use super::CurrentUser;
+}
+
+/// The types taken and returned by the functions in [`crate::custom_fields`].
+pub mod custom_fields {
+    pub use super::{GetCompanyCustomFieldsResponse, GetEmployeeCustomFieldsResponse};
+}
+
+/// The types taken and returned by the functions in [`crate::earning_type`].
+pub mod earning_type {
+    pub use super::{
+        EarningType, EarningTypeListResponse, PostCompanyEarningTypesRequest,
+        PutCompanyEarningTypeRequest,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::employees`].
+pub mod employees {
+    pub use super::{
+        Employee, Location, PostEmployeesRequest, PutEmployeeHomeAddressRequest,
+        PutEmployeesRequest,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::federal_tax_details_beta`].
+pub mod federal_tax_details_beta {
+    pub use super::{GetCompanyFederalTaxDetailsResponse, PutCompanyFederalTaxDetailsRequest};
+}
+
+/// The types taken and returned by the functions in [`crate::garnishments`].
+pub mod garnishments {
+    pub use super::{Garnishment, PostEmployeeGarnishmentsRequest, PutGarnishmentRequest};
+}
+
+/// The types taken and returned by the functions in [`crate::job_applicants_beta`].
+pub mod job_applicants_beta {
+    pub use super::{JobApplicant, PostCompanyJobApplicantsRequest, PutCompanyJobApplicantRequest};
+}
+
+/// The types taken and returned by the functions in [`crate::jobs`].
+pub mod jobs {
+    pub use super::{
+        Compensation, Job, PostJobCompensationsRequest, PostJobRequest, PutJobRequest,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::locations`].
+pub mod locations {
+    pub use super::{Location, PostCompanyLocationsRequest, PutLocationRequest};
+}
+
+/// The types taken and returned by the functions in [`crate::pay_schedules`].
+pub mod pay_schedules {
+    pub use super::{PaySchedule, PutCompanyPaySchedulesScheduleRequest};
+}
+
+/// The types taken and returned by the functions in [`crate::payroll`].
+pub mod payroll {
+    pub use super::{
+        GetCompanyPayrollReversalsResponse, GetCompanyPayrollsInclude, PayPeriod, PayrollData,
+        PostCompanyPayrollsRequest, PutCompanyPayrollsRequest,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::terminations`].
+pub mod terminations {
+    pub use super::{PostEmployeeTerminationsRequest, Termination};
+}
+
+/// The types taken and returned by the functions in [`crate::time_off_requests`].
+pub mod time_off_requests {
+    pub use super::TimeOffRequest;
+}
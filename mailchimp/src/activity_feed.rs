@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct ActivityFeed {
     pub client: Client,
@@ -12,6 +10,14 @@ impl ActivityFeed {
         ActivityFeed { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        ActivityFeed {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get latest chimp chatter.
      *
@@ -29,16 +35,43 @@ impl ActivityFeed {
         count: i64,
         offset: i64,
     ) -> Result<crate::types::GetActivityFeedChimpChatterResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/activity-feed/chimp-chatter?{}", query_);
+        let url = crate::Client::url_with_query("/activity-feed/chimp-chatter", &query_args);
+
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/activity-feed/chimp-chatter", &[]))
+    }
+}
+
+/// The functions of [`ActivityFeed`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ActivityFeedApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ActivityFeedApi: Send + Sync {
+    async fn get_chimp_chatter(
+        &self,
+        count: i64,
+        offset: i64,
+    ) -> Result<crate::types::GetActivityFeedChimpChatterResponse>;
+}
 
-        self.client.get(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ActivityFeedApi for ActivityFeed {
+    async fn get_chimp_chatter(
+        &self,
+        count: i64,
+        offset: i64,
+    ) -> Result<crate::types::GetActivityFeedChimpChatterResponse> {
+        ActivityFeed::get_chimp_chatter(self, count, offset).await
     }
 }
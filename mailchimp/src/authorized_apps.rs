@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct AuthorizedApps {
     pub client: Client,
@@ -12,6 +10,14 @@ impl AuthorizedApps {
         AuthorizedApps { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        AuthorizedApps {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * List authorized apps.
      *
@@ -33,23 +39,25 @@ impl AuthorizedApps {
         count: i64,
         offset: i64,
     ) -> Result<crate::types::GetAuthorizedAppsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/authorized-apps?{}", query_);
+        let url = crate::Client::url_with_query("/authorized-apps", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/authorized-apps", &[]))
     }
 
     /**
@@ -71,20 +79,67 @@ impl AuthorizedApps {
         exclude_fields: &[String],
         app_id: &str,
     ) -> Result<crate::types::Apps> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/authorized-apps/{}?{}",
-            crate::progenitor_support::encode_path(&app_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/authorized-apps/{}", self.client.encode_path(app_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/authorized-apps/{app_id}",
+                &[("app_id", app_id.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`AuthorizedApps`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AuthorizedAppsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AuthorizedAppsApi: Send + Sync {
+    async fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> Result<crate::types::GetAuthorizedAppsResponse>;
+    async fn get_authorized_apps(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        app_id: &str,
+    ) -> Result<crate::types::Apps>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AuthorizedAppsApi for AuthorizedApps {
+    async fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> Result<crate::types::GetAuthorizedAppsResponse> {
+        AuthorizedApps::get(self, fields, exclude_fields, count, offset).await
+    }
+    async fn get_authorized_apps(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        app_id: &str,
+    ) -> Result<crate::types::Apps> {
+        AuthorizedApps::get_authorized_apps(self, fields, exclude_fields, app_id).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Automations {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Automations {
         Automations { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Automations {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * List automations.
      *
@@ -43,38 +49,40 @@ impl Automations {
         since_start_time: Option<chrono::DateTime<chrono::Utc>>,
         status: crate::types::Status,
     ) -> Result<crate::types::GetAutomationsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if let Some(date) = before_create_time {
-            query_args.push(("before_create_time".to_string(), date.to_rfc3339()));
+            query_args.push(("before_create_time", date.to_rfc3339()));
         }
         if let Some(date) = before_start_time {
-            query_args.push(("before_start_time".to_string(), date.to_rfc3339()));
+            query_args.push(("before_start_time", date.to_rfc3339()));
         }
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if let Some(date) = since_create_time {
-            query_args.push(("since_create_time".to_string(), date.to_rfc3339()));
+            query_args.push(("since_create_time", date.to_rfc3339()));
         }
         if let Some(date) = since_start_time {
-            query_args.push(("since_start_time".to_string(), date.to_rfc3339()));
+            query_args.push(("since_start_time", date.to_rfc3339()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/automations?{}", query_);
+        let url = crate::Client::url_with_query("/automations", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/automations", &[]))
     }
 
     /**
@@ -90,8 +98,9 @@ impl Automations {
     ) -> Result<crate::types::Automations> {
         let url = "/automations".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/automations", &[]))
     }
 
     /**
@@ -113,21 +122,25 @@ impl Automations {
         exclude_fields: &[String],
         workflow_id: &str,
     ) -> Result<crate::types::Automations> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/automations/{}?{}",
-            crate::progenitor_support::encode_path(&workflow_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/automations/{}", self.client.encode_path(workflow_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/automations/{workflow_id}",
+                &[("workflow_id", workflow_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -144,10 +157,16 @@ impl Automations {
     pub async fn post_actions_pause_all_email(&self, workflow_id: &str) -> Result<()> {
         let url = format!(
             "/automations/{}/actions/pause-all-emails",
-            crate::progenitor_support::encode_path(&workflow_id.to_string()),
+            self.client.encode_path(workflow_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/automations/{workflow_id}/actions/pause-all-emails",
+                &[("workflow_id", workflow_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -164,10 +183,16 @@ impl Automations {
     pub async fn post_actions_start_all_email(&self, workflow_id: &str) -> Result<()> {
         let url = format!(
             "/automations/{}/actions/start-all-emails",
-            crate::progenitor_support::encode_path(&workflow_id.to_string()),
+            self.client.encode_path(workflow_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/automations/{workflow_id}/actions/start-all-emails",
+                &[("workflow_id", workflow_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -184,10 +209,16 @@ impl Automations {
     pub async fn archive(&self, workflow_id: &str) -> Result<()> {
         let url = format!(
             "/automations/{}/actions/archive",
-            crate::progenitor_support::encode_path(&workflow_id.to_string()),
+            self.client.encode_path(workflow_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/automations/{workflow_id}/actions/archive",
+                &[("workflow_id", workflow_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -204,10 +235,16 @@ impl Automations {
     pub async fn get_email(&self, workflow_id: &str) -> Result<crate::types::AutomationEmails> {
         let url = format!(
             "/automations/{}/emails",
-            crate::progenitor_support::encode_path(&workflow_id.to_string()),
+            self.client.encode_path(workflow_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/automations/{workflow_id}/emails",
+                &[("workflow_id", workflow_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -229,11 +266,20 @@ impl Automations {
     ) -> Result<crate::types::Emails> {
         let url = format!(
             "/automations/{}/emails/{}",
-            crate::progenitor_support::encode_path(&workflow_id.to_string()),
-            crate::progenitor_support::encode_path(&workflow_email_id.to_string()),
+            self.client.encode_path(workflow_id),
+            self.client.encode_path(workflow_email_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/automations/{workflow_id}/emails/{workflow_email_id}",
+                &[
+                    ("workflow_id", workflow_id.to_string()),
+                    ("workflow_email_id", workflow_email_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -251,11 +297,53 @@ impl Automations {
     pub async fn delete_emails(&self, workflow_id: &str, workflow_email_id: &str) -> Result<()> {
         let url = format!(
             "/automations/{}/emails/{}",
-            crate::progenitor_support::encode_path(&workflow_id.to_string()),
-            crate::progenitor_support::encode_path(&workflow_email_id.to_string()),
+            self.client.encode_path(workflow_id),
+            self.client.encode_path(workflow_email_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/automations/{workflow_id}/emails/{workflow_email_id}",
+                &[
+                    ("workflow_id", workflow_id.to_string()),
+                    ("workflow_email_id", workflow_email_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Delete workflow email.
+     *
+     * This function performs a `DELETE` to the `/automations/{workflow_id}/emails/{workflow_email_id}` endpoint.
+     *
+     * As opposed to `delete_emails`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_emails_if_exists(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/automations/{}/emails/{}",
+            self.client.encode_path(workflow_id),
+            self.client.encode_path(workflow_email_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/automations/{workflow_id}/emails/{workflow_email_id}",
+                    &[
+                        ("workflow_id", workflow_id.to_string()),
+                        ("workflow_email_id", workflow_email_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -278,13 +366,58 @@ impl Automations {
     ) -> Result<crate::types::Emails> {
         let url = format!(
             "/automations/{}/emails/{}",
-            crate::progenitor_support::encode_path(&workflow_id.to_string()),
-            crate::progenitor_support::encode_path(&workflow_email_id.to_string()),
+            self.client.encode_path(workflow_id),
+            self.client.encode_path(workflow_email_id),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/automations/{workflow_id}/emails/{workflow_email_id}",
+                    &[
+                        ("workflow_id", workflow_id.to_string()),
+                        ("workflow_email_id", workflow_email_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * Update workflow email.
+     *
+     * This function performs a `PATCH` to the `/automations/{workflow_id}/emails/{workflow_email_id}` endpoint.
+     *
+     * As opposed to `patch_email_workflow`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_email_workflow_if_changed<C: serde::Serialize>(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+        current: &C,
+        body: &crate::types::UpdateInformationAboutASpecificWorkflowEmail,
+    ) -> Result<Option<crate::types::Emails>> {
+        let url = format!(
+            "/automations/{}/emails/{}",
+            self.client.encode_path(workflow_id),
+            self.client.encode_path(workflow_email_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/automations/{workflow_id}/emails/{workflow_email_id}",
+                    &[
+                        ("workflow_id", workflow_id.to_string()),
+                        ("workflow_email_id", workflow_email_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -306,11 +439,20 @@ impl Automations {
     ) -> Result<crate::types::GetAutomationsEmailsQueueResponse> {
         let url = format!(
             "/automations/{}/emails/{}/queue",
-            crate::progenitor_support::encode_path(&workflow_id.to_string()),
-            crate::progenitor_support::encode_path(&workflow_email_id.to_string()),
+            self.client.encode_path(workflow_id),
+            self.client.encode_path(workflow_email_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/automations/{workflow_id}/emails/{workflow_email_id}/queue",
+                &[
+                    ("workflow_id", workflow_id.to_string()),
+                    ("workflow_email_id", workflow_email_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -333,13 +475,23 @@ impl Automations {
     ) -> Result<crate::types::SubscriberInAutomationQueueData> {
         let url = format!(
             "/automations/{}/emails/{}/queue",
-            crate::progenitor_support::encode_path(&workflow_id.to_string()),
-            crate::progenitor_support::encode_path(&workflow_email_id.to_string()),
+            self.client.encode_path(workflow_id),
+            self.client.encode_path(workflow_email_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/automations/{workflow_id}/emails/{workflow_email_id}/queue",
+                    &[
+                        ("workflow_id", workflow_id.to_string()),
+                        ("workflow_email_id", workflow_email_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -363,12 +515,22 @@ impl Automations {
     ) -> Result<crate::types::SubscriberInAutomationQueueData> {
         let url = format!(
             "/automations/{}/emails/{}/queue/{}",
-            crate::progenitor_support::encode_path(&workflow_id.to_string()),
-            crate::progenitor_support::encode_path(&workflow_email_id.to_string()),
-            crate::progenitor_support::encode_path(&subscriber_hash.to_string()),
+            self.client.encode_path(workflow_id),
+            self.client.encode_path(workflow_email_id),
+            self.client.encode_path(subscriber_hash),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/automations/{workflow_id}/emails/{workflow_email_id}/queue/{subscriber_hash}",
+                &[
+                    ("workflow_id", workflow_id.to_string()),
+                    ("workflow_email_id", workflow_email_id.to_string()),
+                    ("subscriber_hash", subscriber_hash.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -390,11 +552,20 @@ impl Automations {
     ) -> Result<()> {
         let url = format!(
             "/automations/{}/emails/{}/actions/pause",
-            crate::progenitor_support::encode_path(&workflow_id.to_string()),
-            crate::progenitor_support::encode_path(&workflow_email_id.to_string()),
+            self.client.encode_path(workflow_id),
+            self.client.encode_path(workflow_email_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/automations/{workflow_id}/emails/{workflow_email_id}/actions/pause",
+                &[
+                    ("workflow_id", workflow_id.to_string()),
+                    ("workflow_email_id", workflow_email_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -416,11 +587,20 @@ impl Automations {
     ) -> Result<()> {
         let url = format!(
             "/automations/{}/emails/{}/actions/start",
-            crate::progenitor_support::encode_path(&workflow_id.to_string()),
-            crate::progenitor_support::encode_path(&workflow_email_id.to_string()),
+            self.client.encode_path(workflow_id),
+            self.client.encode_path(workflow_email_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/automations/{workflow_id}/emails/{workflow_email_id}/actions/start",
+                &[
+                    ("workflow_id", workflow_id.to_string()),
+                    ("workflow_email_id", workflow_email_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -440,10 +620,16 @@ impl Automations {
     ) -> Result<crate::types::RemovedSubscribers> {
         let url = format!(
             "/automations/{}/removed-subscribers",
-            crate::progenitor_support::encode_path(&workflow_id.to_string()),
+            self.client.encode_path(workflow_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/automations/{workflow_id}/removed-subscribers",
+                &[("workflow_id", workflow_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -464,12 +650,19 @@ impl Automations {
     ) -> Result<crate::types::Subscribers> {
         let url = format!(
             "/automations/{}/removed-subscribers",
-            crate::progenitor_support::encode_path(&workflow_id.to_string()),
+            self.client.encode_path(workflow_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/automations/{workflow_id}/removed-subscribers",
+                    &[("workflow_id", workflow_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -491,10 +684,256 @@ impl Automations {
     ) -> Result<crate::types::Subscribers> {
         let url = format!(
             "/automations/{}/removed-subscribers/{}",
-            crate::progenitor_support::encode_path(&workflow_id.to_string()),
-            crate::progenitor_support::encode_path(&subscriber_hash.to_string()),
+            self.client.encode_path(workflow_id),
+            self.client.encode_path(subscriber_hash),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/automations/{workflow_id}/removed-subscribers/{subscriber_hash}",
+                &[
+                    ("workflow_id", workflow_id.to_string()),
+                    ("subscriber_hash", subscriber_hash.to_string()),
+                ],
+            )
+        })
+    }
+}
+
+/// The functions of [`Automations`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AutomationsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AutomationsApi: Send + Sync {
+    async fn get(
+        &self,
+        count: i64,
+        offset: i64,
+        fields: &[String],
+        exclude_fields: &[String],
+        before_create_time: Option<chrono::DateTime<chrono::Utc>>,
+        since_create_time: Option<chrono::DateTime<chrono::Utc>>,
+        before_start_time: Option<chrono::DateTime<chrono::Utc>>,
+        since_start_time: Option<chrono::DateTime<chrono::Utc>>,
+        status: crate::types::Status,
+    ) -> Result<crate::types::GetAutomationsResponse>;
+    async fn post(
+        &self,
+        body: &crate::types::AutomationWorkflow,
+    ) -> Result<crate::types::Automations>;
+    async fn get_automations(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        workflow_id: &str,
+    ) -> Result<crate::types::Automations>;
+    async fn post_actions_pause_all_email(&self, workflow_id: &str) -> Result<()>;
+    async fn post_actions_start_all_email(&self, workflow_id: &str) -> Result<()>;
+    async fn archive(&self, workflow_id: &str) -> Result<()>;
+    async fn get_email(&self, workflow_id: &str) -> Result<crate::types::AutomationEmails>;
+    async fn get_email_automations(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+    ) -> Result<crate::types::Emails>;
+    async fn delete_emails(&self, workflow_id: &str, workflow_email_id: &str) -> Result<()>;
+    async fn delete_emails_if_exists(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+    ) -> Result<bool>;
+    async fn patch_email_workflow(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+        body: &crate::types::UpdateInformationAboutASpecificWorkflowEmail,
+    ) -> Result<crate::types::Emails>;
+    async fn get_emails_queue(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+    ) -> Result<crate::types::GetAutomationsEmailsQueueResponse>;
+    async fn post_emails_queue(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+        body: &crate::types::SubscriberInAutomationQueue,
+    ) -> Result<crate::types::SubscriberInAutomationQueueData>;
+    async fn get_emails_queue_automations(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+        subscriber_hash: &str,
+    ) -> Result<crate::types::SubscriberInAutomationQueueData>;
+    async fn post_emails_actions_pause(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+    ) -> Result<()>;
+    async fn post_emails_actions_start(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+    ) -> Result<()>;
+    async fn get_removed_subscriber(
+        &self,
+        workflow_id: &str,
+    ) -> Result<crate::types::RemovedSubscribers>;
+    async fn post_removed_subscriber(
+        &self,
+        workflow_id: &str,
+        body: &crate::types::SubscriberInAutomationQueue,
+    ) -> Result<crate::types::Subscribers>;
+    async fn get_removed_subscriber_automations(
+        &self,
+        workflow_id: &str,
+        subscriber_hash: &str,
+    ) -> Result<crate::types::Subscribers>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AutomationsApi for Automations {
+    async fn get(
+        &self,
+        count: i64,
+        offset: i64,
+        fields: &[String],
+        exclude_fields: &[String],
+        before_create_time: Option<chrono::DateTime<chrono::Utc>>,
+        since_create_time: Option<chrono::DateTime<chrono::Utc>>,
+        before_start_time: Option<chrono::DateTime<chrono::Utc>>,
+        since_start_time: Option<chrono::DateTime<chrono::Utc>>,
+        status: crate::types::Status,
+    ) -> Result<crate::types::GetAutomationsResponse> {
+        Automations::get(
+            self,
+            count,
+            offset,
+            fields,
+            exclude_fields,
+            before_create_time,
+            since_create_time,
+            before_start_time,
+            since_start_time,
+            status,
+        )
+        .await
+    }
+    async fn post(
+        &self,
+        body: &crate::types::AutomationWorkflow,
+    ) -> Result<crate::types::Automations> {
+        Automations::post(self, body).await
+    }
+    async fn get_automations(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        workflow_id: &str,
+    ) -> Result<crate::types::Automations> {
+        Automations::get_automations(self, fields, exclude_fields, workflow_id).await
+    }
+    async fn post_actions_pause_all_email(&self, workflow_id: &str) -> Result<()> {
+        Automations::post_actions_pause_all_email(self, workflow_id).await
+    }
+    async fn post_actions_start_all_email(&self, workflow_id: &str) -> Result<()> {
+        Automations::post_actions_start_all_email(self, workflow_id).await
+    }
+    async fn archive(&self, workflow_id: &str) -> Result<()> {
+        Automations::archive(self, workflow_id).await
+    }
+    async fn get_email(&self, workflow_id: &str) -> Result<crate::types::AutomationEmails> {
+        Automations::get_email(self, workflow_id).await
+    }
+    async fn get_email_automations(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+    ) -> Result<crate::types::Emails> {
+        Automations::get_email_automations(self, workflow_id, workflow_email_id).await
+    }
+    async fn delete_emails(&self, workflow_id: &str, workflow_email_id: &str) -> Result<()> {
+        Automations::delete_emails(self, workflow_id, workflow_email_id).await
+    }
+    async fn delete_emails_if_exists(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+    ) -> Result<bool> {
+        Automations::delete_emails_if_exists(self, workflow_id, workflow_email_id).await
+    }
+    async fn patch_email_workflow(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+        body: &crate::types::UpdateInformationAboutASpecificWorkflowEmail,
+    ) -> Result<crate::types::Emails> {
+        Automations::patch_email_workflow(self, workflow_id, workflow_email_id, body).await
+    }
+    async fn get_emails_queue(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+    ) -> Result<crate::types::GetAutomationsEmailsQueueResponse> {
+        Automations::get_emails_queue(self, workflow_id, workflow_email_id).await
+    }
+    async fn post_emails_queue(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+        body: &crate::types::SubscriberInAutomationQueue,
+    ) -> Result<crate::types::SubscriberInAutomationQueueData> {
+        Automations::post_emails_queue(self, workflow_id, workflow_email_id, body).await
+    }
+    async fn get_emails_queue_automations(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+        subscriber_hash: &str,
+    ) -> Result<crate::types::SubscriberInAutomationQueueData> {
+        Automations::get_emails_queue_automations(
+            self,
+            workflow_id,
+            workflow_email_id,
+            subscriber_hash,
+        )
+        .await
+    }
+    async fn post_emails_actions_pause(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+    ) -> Result<()> {
+        Automations::post_emails_actions_pause(self, workflow_id, workflow_email_id).await
+    }
+    async fn post_emails_actions_start(
+        &self,
+        workflow_id: &str,
+        workflow_email_id: &str,
+    ) -> Result<()> {
+        Automations::post_emails_actions_start(self, workflow_id, workflow_email_id).await
+    }
+    async fn get_removed_subscriber(
+        &self,
+        workflow_id: &str,
+    ) -> Result<crate::types::RemovedSubscribers> {
+        Automations::get_removed_subscriber(self, workflow_id).await
+    }
+    async fn post_removed_subscriber(
+        &self,
+        workflow_id: &str,
+        body: &crate::types::SubscriberInAutomationQueue,
+    ) -> Result<crate::types::Subscribers> {
+        Automations::post_removed_subscriber(self, workflow_id, body).await
+    }
+    async fn get_removed_subscriber_automations(
+        &self,
+        workflow_id: &str,
+        subscriber_hash: &str,
+    ) -> Result<crate::types::Subscribers> {
+        Automations::get_removed_subscriber_automations(self, workflow_id, subscriber_hash).await
     }
 }
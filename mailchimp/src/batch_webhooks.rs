@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct BatchWebhooks {
     pub client: Client,
@@ -12,6 +10,14 @@ impl BatchWebhooks {
         BatchWebhooks { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        BatchWebhooks {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * List batch webhooks.
      *
@@ -33,23 +39,25 @@ impl BatchWebhooks {
         count: i64,
         offset: i64,
     ) -> Result<crate::types::BatchWebhooks> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/batch-webhooks?{}", query_);
+        let url = crate::Client::url_with_query("/batch-webhooks", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/batch-webhooks", &[]))
     }
 
     /**
@@ -62,8 +70,9 @@ impl BatchWebhooks {
     pub async fn post(&self, body: &crate::types::BatchWebhook) -> Result<crate::types::Webhooks> {
         let url = "/batch-webhooks".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/batch-webhooks", &[]))
     }
 
     /**
@@ -85,21 +94,28 @@ impl BatchWebhooks {
         exclude_fields: &[String],
         batch_webhook_id: &str,
     ) -> Result<crate::types::Webhooks> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/batch-webhooks/{}?{}",
-            crate::progenitor_support::encode_path(&batch_webhook_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/batch-webhooks/{}",
+                self.client.encode_path(batch_webhook_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/batch-webhooks/{batch_webhook_id}",
+                &[("batch_webhook_id", batch_webhook_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -116,10 +132,41 @@ impl BatchWebhooks {
     pub async fn delete(&self, batch_webhook_id: &str) -> Result<()> {
         let url = format!(
             "/batch-webhooks/{}",
-            crate::progenitor_support::encode_path(&batch_webhook_id.to_string()),
+            self.client.encode_path(batch_webhook_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/batch-webhooks/{batch_webhook_id}",
+                &[("batch_webhook_id", batch_webhook_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete batch webhook.
+     *
+     * This function performs a `DELETE` to the `/batch-webhooks/{batch_webhook_id}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, batch_webhook_id: &str) -> Result<bool> {
+        let url = format!(
+            "/batch-webhooks/{}",
+            self.client.encode_path(batch_webhook_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/batch-webhooks/{batch_webhook_id}",
+                    &[("batch_webhook_id", batch_webhook_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -140,11 +187,115 @@ impl BatchWebhooks {
     ) -> Result<crate::types::Webhooks> {
         let url = format!(
             "/batch-webhooks/{}",
-            crate::progenitor_support::encode_path(&batch_webhook_id.to_string()),
+            self.client.encode_path(batch_webhook_id),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/batch-webhooks/{batch_webhook_id}",
+                    &[("batch_webhook_id", batch_webhook_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update batch webhook.
+     *
+     * This function performs a `PATCH` to the `/batch-webhooks/{batch_webhook_id}` endpoint.
+     *
+     * As opposed to `patch`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_if_changed<C: serde::Serialize>(
+        &self,
+        batch_webhook_id: &str,
+        current: &C,
+        body: &crate::types::BatchWebhook,
+    ) -> Result<Option<crate::types::Webhooks>> {
+        let url = format!(
+            "/batch-webhooks/{}",
+            self.client.encode_path(batch_webhook_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/batch-webhooks/{batch_webhook_id}",
+                    &[("batch_webhook_id", batch_webhook_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`BatchWebhooks`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn BatchWebhooksApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait BatchWebhooksApi: Send + Sync {
+    async fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> Result<crate::types::BatchWebhooks>;
+    async fn post(&self, body: &crate::types::BatchWebhook) -> Result<crate::types::Webhooks>;
+    async fn get_batch_webhooks(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        batch_webhook_id: &str,
+    ) -> Result<crate::types::Webhooks>;
+    async fn delete(&self, batch_webhook_id: &str) -> Result<()>;
+    async fn delete_if_exists(&self, batch_webhook_id: &str) -> Result<bool>;
+    async fn patch(
+        &self,
+        batch_webhook_id: &str,
+        body: &crate::types::BatchWebhook,
+    ) -> Result<crate::types::Webhooks>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl BatchWebhooksApi for BatchWebhooks {
+    async fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> Result<crate::types::BatchWebhooks> {
+        BatchWebhooks::get(self, fields, exclude_fields, count, offset).await
+    }
+    async fn post(&self, body: &crate::types::BatchWebhook) -> Result<crate::types::Webhooks> {
+        BatchWebhooks::post(self, body).await
+    }
+    async fn get_batch_webhooks(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        batch_webhook_id: &str,
+    ) -> Result<crate::types::Webhooks> {
+        BatchWebhooks::get_batch_webhooks(self, fields, exclude_fields, batch_webhook_id).await
+    }
+    async fn delete(&self, batch_webhook_id: &str) -> Result<()> {
+        BatchWebhooks::delete(self, batch_webhook_id).await
+    }
+    async fn delete_if_exists(&self, batch_webhook_id: &str) -> Result<bool> {
+        BatchWebhooks::delete_if_exists(self, batch_webhook_id).await
+    }
+    async fn patch(
+        &self,
+        batch_webhook_id: &str,
+        body: &crate::types::BatchWebhook,
+    ) -> Result<crate::types::Webhooks> {
+        BatchWebhooks::patch(self, batch_webhook_id, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Batches {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Batches {
         Batches { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Batches {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * List batch requests.
      *
@@ -33,23 +39,25 @@ impl Batches {
         count: i64,
         offset: i64,
     ) -> Result<crate::types::BatchOperations> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/batches?{}", query_);
+        let url = crate::Client::url_with_query("/batches", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/batches", &[]))
     }
 
     /**
@@ -65,8 +73,9 @@ impl Batches {
     ) -> Result<crate::types::Batch> {
         let url = "/batches".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/batches", &[]))
     }
 
     /**
@@ -88,21 +97,25 @@ impl Batches {
         exclude_fields: &[String],
         batch_id: &str,
     ) -> Result<crate::types::Batch> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/batches/{}?{}",
-            crate::progenitor_support::encode_path(&batch_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/batches/{}", self.client.encode_path(batch_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/batches/{batch_id}",
+                &[("batch_id", batch_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -117,11 +130,91 @@ impl Batches {
      * * `batch_id: &str` -- The unique id for the batch operation.
      */
     pub async fn delete(&self, batch_id: &str) -> Result<()> {
-        let url = format!(
-            "/batches/{}",
-            crate::progenitor_support::encode_path(&batch_id.to_string()),
-        );
+        let url = format!("/batches/{}", self.client.encode_path(batch_id),);
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/batches/{batch_id}",
+                &[("batch_id", batch_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete batch request.
+     *
+     * This function performs a `DELETE` to the `/batches/{batch_id}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, batch_id: &str) -> Result<bool> {
+        let url = format!("/batches/{}", self.client.encode_path(batch_id),);
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/batches/{batch_id}",
+                    &[("batch_id", batch_id.to_string())],
+                )
+            })
+    }
+}
 
-        self.client.delete(&url, None).await
+/// The functions of [`Batches`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn BatchesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait BatchesApi: Send + Sync {
+    async fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> Result<crate::types::BatchOperations>;
+    async fn post(&self, body: &crate::types::PostBatchesRequest) -> Result<crate::types::Batch>;
+    async fn get_batches(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        batch_id: &str,
+    ) -> Result<crate::types::Batch>;
+    async fn delete(&self, batch_id: &str) -> Result<()>;
+    async fn delete_if_exists(&self, batch_id: &str) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl BatchesApi for Batches {
+    async fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> Result<crate::types::BatchOperations> {
+        Batches::get(self, fields, exclude_fields, count, offset).await
+    }
+    async fn post(&self, body: &crate::types::PostBatchesRequest) -> Result<crate::types::Batch> {
+        Batches::post(self, body).await
+    }
+    async fn get_batches(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        batch_id: &str,
+    ) -> Result<crate::types::Batch> {
+        Batches::get_batches(self, fields, exclude_fields, batch_id).await
+    }
+    async fn delete(&self, batch_id: &str) -> Result<()> {
+        Batches::delete(self, batch_id).await
+    }
+    async fn delete_if_exists(&self, batch_id: &str) -> Result<bool> {
+        Batches::delete_if_exists(self, batch_id).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct CampaignFolders {
     pub client: Client,
@@ -12,6 +10,14 @@ impl CampaignFolders {
         CampaignFolders { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        CampaignFolders {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * List campaign folders.
      *
@@ -33,23 +39,25 @@ impl CampaignFolders {
         count: i64,
         offset: i64,
     ) -> Result<crate::types::CampaignFolders> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/campaign-folders?{}", query_);
+        let url = crate::Client::url_with_query("/campaign-folders", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/campaign-folders", &[]))
     }
 
     /**
@@ -65,8 +73,9 @@ impl CampaignFolders {
     ) -> Result<crate::types::CampaignFolder> {
         let url = "/campaign-folders".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/campaign-folders", &[]))
     }
 
     /**
@@ -88,21 +97,25 @@ impl CampaignFolders {
         exclude_fields: &[String],
         folder_id: &str,
     ) -> Result<crate::types::CampaignFolder> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/campaign-folders/{}?{}",
-            crate::progenitor_support::encode_path(&folder_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/campaign-folders/{}", self.client.encode_path(folder_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/campaign-folders/{folder_id}",
+                &[("folder_id", folder_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -117,12 +130,37 @@ impl CampaignFolders {
      * * `folder_id: &str` -- The unique id for the campaign folder.
      */
     pub async fn delete(&self, folder_id: &str) -> Result<()> {
-        let url = format!(
-            "/campaign-folders/{}",
-            crate::progenitor_support::encode_path(&folder_id.to_string()),
-        );
+        let url = format!("/campaign-folders/{}", self.client.encode_path(folder_id),);
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/campaign-folders/{folder_id}",
+                &[("folder_id", folder_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete campaign folder.
+     *
+     * This function performs a `DELETE` to the `/campaign-folders/{folder_id}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, folder_id: &str) -> Result<bool> {
+        let url = format!("/campaign-folders/{}", self.client.encode_path(folder_id),);
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/campaign-folders/{folder_id}",
+                    &[("folder_id", folder_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -141,13 +179,117 @@ impl CampaignFolders {
         folder_id: &str,
         body: &crate::types::GalleryFolder,
     ) -> Result<crate::types::CampaignFolder> {
-        let url = format!(
-            "/campaign-folders/{}",
-            crate::progenitor_support::encode_path(&folder_id.to_string()),
-        );
+        let url = format!("/campaign-folders/{}", self.client.encode_path(folder_id),);
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/campaign-folders/{folder_id}",
+                    &[("folder_id", folder_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update campaign folder.
+     *
+     * This function performs a `PATCH` to the `/campaign-folders/{folder_id}` endpoint.
+     *
+     * As opposed to `patch`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_if_changed<C: serde::Serialize>(
+        &self,
+        folder_id: &str,
+        current: &C,
+        body: &crate::types::GalleryFolder,
+    ) -> Result<Option<crate::types::CampaignFolder>> {
+        let url = format!("/campaign-folders/{}", self.client.encode_path(folder_id),);
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/campaign-folders/{folder_id}",
+                    &[("folder_id", folder_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`CampaignFolders`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn CampaignFoldersApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait CampaignFoldersApi: Send + Sync {
+    async fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> Result<crate::types::CampaignFolders>;
+    async fn post(
+        &self,
+        body: &crate::types::GalleryFolder,
+    ) -> Result<crate::types::CampaignFolder>;
+    async fn get_campaign_folders(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        folder_id: &str,
+    ) -> Result<crate::types::CampaignFolder>;
+    async fn delete(&self, folder_id: &str) -> Result<()>;
+    async fn delete_if_exists(&self, folder_id: &str) -> Result<bool>;
+    async fn patch(
+        &self,
+        folder_id: &str,
+        body: &crate::types::GalleryFolder,
+    ) -> Result<crate::types::CampaignFolder>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl CampaignFoldersApi for CampaignFolders {
+    async fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> Result<crate::types::CampaignFolders> {
+        CampaignFolders::get(self, fields, exclude_fields, count, offset).await
+    }
+    async fn post(
+        &self,
+        body: &crate::types::GalleryFolder,
+    ) -> Result<crate::types::CampaignFolder> {
+        CampaignFolders::post(self, body).await
+    }
+    async fn get_campaign_folders(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        folder_id: &str,
+    ) -> Result<crate::types::CampaignFolder> {
+        CampaignFolders::get_campaign_folders(self, fields, exclude_fields, folder_id).await
+    }
+    async fn delete(&self, folder_id: &str) -> Result<()> {
+        CampaignFolders::delete(self, folder_id).await
+    }
+    async fn delete_if_exists(&self, folder_id: &str) -> Result<bool> {
+        CampaignFolders::delete_if_exists(self, folder_id).await
+    }
+    async fn patch(
+        &self,
+        folder_id: &str,
+        body: &crate::types::GalleryFolder,
+    ) -> Result<crate::types::CampaignFolder> {
+        CampaignFolders::patch(self, folder_id, body).await
     }
 }
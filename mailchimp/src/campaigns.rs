@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Campaigns {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Campaigns {
         Campaigns { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Campaigns {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * List campaigns.
      *
@@ -55,56 +61,58 @@ impl Campaigns {
         sort_field: crate::types::SortField,
         sort_dir: crate::types::SortDir,
     ) -> Result<crate::types::GetCampaignsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if let Some(date) = before_create_time {
-            query_args.push(("before_create_time".to_string(), date.to_rfc3339()));
+            query_args.push(("before_create_time", date.to_rfc3339()));
         }
         if let Some(date) = before_send_time {
-            query_args.push(("before_send_time".to_string(), date.to_rfc3339()));
+            query_args.push(("before_send_time", date.to_rfc3339()));
         }
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if !folder_id.is_empty() {
-            query_args.push(("folder_id".to_string(), folder_id.to_string()));
+            query_args.push(("folder_id", folder_id.to_string()));
         }
         if !list_id.is_empty() {
-            query_args.push(("list_id".to_string(), list_id.to_string()));
+            query_args.push(("list_id", list_id.to_string()));
         }
         if !member_id.is_empty() {
-            query_args.push(("member_id".to_string(), member_id.to_string()));
+            query_args.push(("member_id", member_id.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if let Some(date) = since_create_time {
-            query_args.push(("since_create_time".to_string(), date.to_rfc3339()));
+            query_args.push(("since_create_time", date.to_rfc3339()));
         }
         if let Some(date) = since_send_time {
-            query_args.push(("since_send_time".to_string(), date.to_rfc3339()));
+            query_args.push(("since_send_time", date.to_rfc3339()));
         }
         if !sort_dir.to_string().is_empty() {
-            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+            query_args.push(("sort_dir", sort_dir.to_string()));
         }
         if !sort_field.to_string().is_empty() {
-            query_args.push(("sort_field".to_string(), sort_field.to_string()));
+            query_args.push(("sort_field", sort_field.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/campaigns?{}", query_);
+        let url = crate::Client::url_with_query("/campaigns", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/campaigns", &[]))
     }
 
     /**
@@ -120,8 +128,9 @@ impl Campaigns {
     ) -> Result<crate::types::Campaign> {
         let url = "/campaigns".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/campaigns", &[]))
     }
 
     /**
@@ -143,21 +152,25 @@ impl Campaigns {
         exclude_fields: &[String],
         campaign_id: &str,
     ) -> Result<crate::types::Campaign> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/campaigns/{}?{}",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/campaigns/{}", self.client.encode_path(campaign_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/campaigns/{campaign_id}",
+                &[("campaign_id", campaign_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -172,12 +185,37 @@ impl Campaigns {
      * * `campaign_id: &str` -- The unique id for the campaign.
      */
     pub async fn delete(&self, campaign_id: &str) -> Result<()> {
-        let url = format!(
-            "/campaigns/{}",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
-        );
+        let url = format!("/campaigns/{}", self.client.encode_path(campaign_id),);
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/campaigns/{campaign_id}",
+                &[("campaign_id", campaign_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete campaign.
+     *
+     * This function performs a `DELETE` to the `/campaigns/{campaign_id}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, campaign_id: &str) -> Result<bool> {
+        let url = format!("/campaigns/{}", self.client.encode_path(campaign_id),);
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/campaigns/{campaign_id}",
+                    &[("campaign_id", campaign_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -196,14 +234,45 @@ impl Campaigns {
         campaign_id: &str,
         body: &crate::types::CampaignData,
     ) -> Result<crate::types::Campaign> {
-        let url = format!(
-            "/campaigns/{}",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
-        );
+        let url = format!("/campaigns/{}", self.client.encode_path(campaign_id),);
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/campaigns/{campaign_id}",
+                    &[("campaign_id", campaign_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update campaign settings.
+     *
+     * This function performs a `PATCH` to the `/campaigns/{campaign_id}` endpoint.
+     *
+     * As opposed to `patch`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_if_changed<C: serde::Serialize>(
+        &self,
+        campaign_id: &str,
+        current: &C,
+        body: &crate::types::CampaignData,
+    ) -> Result<Option<crate::types::Campaign>> {
+        let url = format!("/campaigns/{}", self.client.encode_path(campaign_id),);
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/campaigns/{campaign_id}",
+                    &[("campaign_id", campaign_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -220,10 +289,16 @@ impl Campaigns {
     pub async fn post_actions_cancel_send(&self, campaign_id: &str) -> Result<()> {
         let url = format!(
             "/campaigns/{}/actions/cancel-send",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
+            self.client.encode_path(campaign_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/campaigns/{campaign_id}/actions/cancel-send",
+                &[("campaign_id", campaign_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -243,10 +318,16 @@ impl Campaigns {
     ) -> Result<crate::types::CampaignDataType> {
         let url = format!(
             "/campaigns/{}/actions/replicate",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
+            self.client.encode_path(campaign_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/campaigns/{campaign_id}/actions/replicate",
+                &[("campaign_id", campaign_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -263,10 +344,16 @@ impl Campaigns {
     pub async fn post_actions_send(&self, campaign_id: &str) -> Result<()> {
         let url = format!(
             "/campaigns/{}/actions/send",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
+            self.client.encode_path(campaign_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/campaigns/{campaign_id}/actions/send",
+                &[("campaign_id", campaign_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -287,12 +374,19 @@ impl Campaigns {
     ) -> Result<()> {
         let url = format!(
             "/campaigns/{}/actions/schedule",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
+            self.client.encode_path(campaign_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/campaigns/{campaign_id}/actions/schedule",
+                    &[("campaign_id", campaign_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -309,10 +403,16 @@ impl Campaigns {
     pub async fn post_actions_unschedule(&self, campaign_id: &str) -> Result<()> {
         let url = format!(
             "/campaigns/{}/actions/unschedule",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
+            self.client.encode_path(campaign_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/campaigns/{campaign_id}/actions/unschedule",
+                &[("campaign_id", campaign_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -333,12 +433,19 @@ impl Campaigns {
     ) -> Result<()> {
         let url = format!(
             "/campaigns/{}/actions/test",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
+            self.client.encode_path(campaign_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/campaigns/{campaign_id}/actions/test",
+                    &[("campaign_id", campaign_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -355,10 +462,16 @@ impl Campaigns {
     pub async fn post_actions_pause(&self, campaign_id: &str) -> Result<()> {
         let url = format!(
             "/campaigns/{}/actions/pause",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
+            self.client.encode_path(campaign_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/campaigns/{campaign_id}/actions/pause",
+                &[("campaign_id", campaign_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -375,10 +488,16 @@ impl Campaigns {
     pub async fn post_actions_resume(&self, campaign_id: &str) -> Result<()> {
         let url = format!(
             "/campaigns/{}/actions/resume",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
+            self.client.encode_path(campaign_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/campaigns/{campaign_id}/actions/resume",
+                &[("campaign_id", campaign_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -398,10 +517,16 @@ impl Campaigns {
     ) -> Result<crate::types::CampaignDataType> {
         let url = format!(
             "/campaigns/{}/actions/create-resend",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
+            self.client.encode_path(campaign_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/campaigns/{campaign_id}/actions/create-resend",
+                &[("campaign_id", campaign_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -423,21 +548,28 @@ impl Campaigns {
         exclude_fields: &[String],
         campaign_id: &str,
     ) -> Result<crate::types::CampaignContent> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/campaigns/{}/content?{}",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/campaigns/{}/content",
+                self.client.encode_path(campaign_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/campaigns/{campaign_id}/content",
+                &[("campaign_id", campaign_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -458,12 +590,19 @@ impl Campaigns {
     ) -> Result<crate::types::CampaignContent> {
         let url = format!(
             "/campaigns/{}/content",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
+            self.client.encode_path(campaign_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/campaigns/{campaign_id}/content",
+                    &[("campaign_id", campaign_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -485,21 +624,28 @@ impl Campaigns {
         exclude_fields: &[String],
         campaign_id: &str,
     ) -> Result<crate::types::CampaignReports> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/campaigns/{}/feedback?{}",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/campaigns/{}/feedback",
+                self.client.encode_path(campaign_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/campaigns/{campaign_id}/feedback",
+                &[("campaign_id", campaign_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -520,12 +666,19 @@ impl Campaigns {
     ) -> Result<crate::types::CampaignFeedbackData> {
         let url = format!(
             "/campaigns/{}/feedback",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
+            self.client.encode_path(campaign_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/campaigns/{campaign_id}/feedback",
+                    &[("campaign_id", campaign_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -549,22 +702,32 @@ impl Campaigns {
         campaign_id: &str,
         feedback_id: &str,
     ) -> Result<crate::types::CampaignFeedbackData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/campaigns/{}/feedback/{}?{}",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
-            crate::progenitor_support::encode_path(&feedback_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/campaigns/{}/feedback/{}",
+                self.client.encode_path(campaign_id),
+                self.client.encode_path(feedback_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/campaigns/{campaign_id}/feedback/{feedback_id}",
+                &[
+                    ("campaign_id", campaign_id.to_string()),
+                    ("feedback_id", feedback_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -582,11 +745,53 @@ impl Campaigns {
     pub async fn delete_feedback(&self, campaign_id: &str, feedback_id: &str) -> Result<()> {
         let url = format!(
             "/campaigns/{}/feedback/{}",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
-            crate::progenitor_support::encode_path(&feedback_id.to_string()),
+            self.client.encode_path(campaign_id),
+            self.client.encode_path(feedback_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/campaigns/{campaign_id}/feedback/{feedback_id}",
+                &[
+                    ("campaign_id", campaign_id.to_string()),
+                    ("feedback_id", feedback_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Delete campaign feedback message.
+     *
+     * This function performs a `DELETE` to the `/campaigns/{campaign_id}/feedback/{feedback_id}` endpoint.
+     *
+     * As opposed to `delete_feedback`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_feedback_if_exists(
+        &self,
+        campaign_id: &str,
+        feedback_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/campaigns/{}/feedback/{}",
+            self.client.encode_path(campaign_id),
+            self.client.encode_path(feedback_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/campaigns/{campaign_id}/feedback/{feedback_id}",
+                    &[
+                        ("campaign_id", campaign_id.to_string()),
+                        ("feedback_id", feedback_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -609,13 +814,58 @@ impl Campaigns {
     ) -> Result<crate::types::CampaignFeedbackData> {
         let url = format!(
             "/campaigns/{}/feedback/{}",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
-            crate::progenitor_support::encode_path(&feedback_id.to_string()),
+            self.client.encode_path(campaign_id),
+            self.client.encode_path(feedback_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/campaigns/{campaign_id}/feedback/{feedback_id}",
+                    &[
+                        ("campaign_id", campaign_id.to_string()),
+                        ("feedback_id", feedback_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * Update campaign feedback message.
+     *
+     * This function performs a `PATCH` to the `/campaigns/{campaign_id}/feedback/{feedback_id}` endpoint.
+     *
+     * As opposed to `patch_feedback`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_feedback_if_changed<C: serde::Serialize>(
+        &self,
+        campaign_id: &str,
+        feedback_id: &str,
+        current: &C,
+        body: &crate::types::CampaignFeedbackDataType,
+    ) -> Result<Option<crate::types::CampaignFeedbackData>> {
+        let url = format!(
+            "/campaigns/{}/feedback/{}",
+            self.client.encode_path(campaign_id),
+            self.client.encode_path(feedback_id),
+        );
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/campaigns/{campaign_id}/feedback/{feedback_id}",
+                    &[
+                        ("campaign_id", campaign_id.to_string()),
+                        ("feedback_id", feedback_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -637,20 +887,308 @@ impl Campaigns {
         exclude_fields: &[String],
         campaign_id: &str,
     ) -> Result<crate::types::SendChecklist> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/campaigns/{}/send-checklist?{}",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/campaigns/{}/send-checklist",
+                self.client.encode_path(campaign_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/campaigns/{campaign_id}/send-checklist",
+                &[("campaign_id", campaign_id.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`Campaigns`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn CampaignsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait CampaignsApi: Send + Sync {
+    async fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        type_: crate::types::CampaignType,
+        status: crate::types::GetCampaignsStatus,
+        before_send_time: Option<chrono::DateTime<chrono::Utc>>,
+        since_send_time: Option<chrono::DateTime<chrono::Utc>>,
+        before_create_time: Option<chrono::DateTime<chrono::Utc>>,
+        since_create_time: Option<chrono::DateTime<chrono::Utc>>,
+        list_id: &str,
+        folder_id: &str,
+        member_id: &str,
+        sort_field: crate::types::SortField,
+        sort_dir: crate::types::SortDir,
+    ) -> Result<crate::types::GetCampaignsResponse>;
+    async fn post(&self, body: &crate::types::CreatedCampaign) -> Result<crate::types::Campaign>;
+    async fn get_campaigns(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> Result<crate::types::Campaign>;
+    async fn delete(&self, campaign_id: &str) -> Result<()>;
+    async fn delete_if_exists(&self, campaign_id: &str) -> Result<bool>;
+    async fn patch(
+        &self,
+        campaign_id: &str,
+        body: &crate::types::CampaignData,
+    ) -> Result<crate::types::Campaign>;
+    async fn post_actions_cancel_send(&self, campaign_id: &str) -> Result<()>;
+    async fn post_actions_replicate(
+        &self,
+        campaign_id: &str,
+    ) -> Result<crate::types::CampaignDataType>;
+    async fn post_actions_send(&self, campaign_id: &str) -> Result<()>;
+    async fn post_actions_schedule(
+        &self,
+        campaign_id: &str,
+        body: &crate::types::PostCampaignsActionsScheduleRequest,
+    ) -> Result<()>;
+    async fn post_actions_unschedule(&self, campaign_id: &str) -> Result<()>;
+    async fn post_actions_test(
+        &self,
+        campaign_id: &str,
+        body: &crate::types::PostCampaignsActionsTestRequest,
+    ) -> Result<()>;
+    async fn post_actions_pause(&self, campaign_id: &str) -> Result<()>;
+    async fn post_actions_resume(&self, campaign_id: &str) -> Result<()>;
+    async fn post_actions_create_resend(
+        &self,
+        campaign_id: &str,
+    ) -> Result<crate::types::CampaignDataType>;
+    async fn get_content(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> Result<crate::types::CampaignContent>;
+    async fn put_content(
+        &self,
+        campaign_id: &str,
+        body: &crate::types::CampaignContentData,
+    ) -> Result<crate::types::CampaignContent>;
+    async fn get_feedback(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> Result<crate::types::CampaignReports>;
+    async fn post_feedback(
+        &self,
+        campaign_id: &str,
+        body: &crate::types::CampaignFeedback,
+    ) -> Result<crate::types::CampaignFeedbackData>;
+    async fn get_feedback_campaigns(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        feedback_id: &str,
+    ) -> Result<crate::types::CampaignFeedbackData>;
+    async fn delete_feedback(&self, campaign_id: &str, feedback_id: &str) -> Result<()>;
+    async fn delete_feedback_if_exists(&self, campaign_id: &str, feedback_id: &str)
+        -> Result<bool>;
+    async fn patch_feedback(
+        &self,
+        campaign_id: &str,
+        feedback_id: &str,
+        body: &crate::types::CampaignFeedbackDataType,
+    ) -> Result<crate::types::CampaignFeedbackData>;
+    async fn get_send_checklist(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> Result<crate::types::SendChecklist>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl CampaignsApi for Campaigns {
+    async fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        type_: crate::types::CampaignType,
+        status: crate::types::GetCampaignsStatus,
+        before_send_time: Option<chrono::DateTime<chrono::Utc>>,
+        since_send_time: Option<chrono::DateTime<chrono::Utc>>,
+        before_create_time: Option<chrono::DateTime<chrono::Utc>>,
+        since_create_time: Option<chrono::DateTime<chrono::Utc>>,
+        list_id: &str,
+        folder_id: &str,
+        member_id: &str,
+        sort_field: crate::types::SortField,
+        sort_dir: crate::types::SortDir,
+    ) -> Result<crate::types::GetCampaignsResponse> {
+        Campaigns::get(
+            self,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            type_,
+            status,
+            before_send_time,
+            since_send_time,
+            before_create_time,
+            since_create_time,
+            list_id,
+            folder_id,
+            member_id,
+            sort_field,
+            sort_dir,
+        )
+        .await
+    }
+    async fn post(&self, body: &crate::types::CreatedCampaign) -> Result<crate::types::Campaign> {
+        Campaigns::post(self, body).await
+    }
+    async fn get_campaigns(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> Result<crate::types::Campaign> {
+        Campaigns::get_campaigns(self, fields, exclude_fields, campaign_id).await
+    }
+    async fn delete(&self, campaign_id: &str) -> Result<()> {
+        Campaigns::delete(self, campaign_id).await
+    }
+    async fn delete_if_exists(&self, campaign_id: &str) -> Result<bool> {
+        Campaigns::delete_if_exists(self, campaign_id).await
+    }
+    async fn patch(
+        &self,
+        campaign_id: &str,
+        body: &crate::types::CampaignData,
+    ) -> Result<crate::types::Campaign> {
+        Campaigns::patch(self, campaign_id, body).await
+    }
+    async fn post_actions_cancel_send(&self, campaign_id: &str) -> Result<()> {
+        Campaigns::post_actions_cancel_send(self, campaign_id).await
+    }
+    async fn post_actions_replicate(
+        &self,
+        campaign_id: &str,
+    ) -> Result<crate::types::CampaignDataType> {
+        Campaigns::post_actions_replicate(self, campaign_id).await
+    }
+    async fn post_actions_send(&self, campaign_id: &str) -> Result<()> {
+        Campaigns::post_actions_send(self, campaign_id).await
+    }
+    async fn post_actions_schedule(
+        &self,
+        campaign_id: &str,
+        body: &crate::types::PostCampaignsActionsScheduleRequest,
+    ) -> Result<()> {
+        Campaigns::post_actions_schedule(self, campaign_id, body).await
+    }
+    async fn post_actions_unschedule(&self, campaign_id: &str) -> Result<()> {
+        Campaigns::post_actions_unschedule(self, campaign_id).await
+    }
+    async fn post_actions_test(
+        &self,
+        campaign_id: &str,
+        body: &crate::types::PostCampaignsActionsTestRequest,
+    ) -> Result<()> {
+        Campaigns::post_actions_test(self, campaign_id, body).await
+    }
+    async fn post_actions_pause(&self, campaign_id: &str) -> Result<()> {
+        Campaigns::post_actions_pause(self, campaign_id).await
+    }
+    async fn post_actions_resume(&self, campaign_id: &str) -> Result<()> {
+        Campaigns::post_actions_resume(self, campaign_id).await
+    }
+    async fn post_actions_create_resend(
+        &self,
+        campaign_id: &str,
+    ) -> Result<crate::types::CampaignDataType> {
+        Campaigns::post_actions_create_resend(self, campaign_id).await
+    }
+    async fn get_content(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> Result<crate::types::CampaignContent> {
+        Campaigns::get_content(self, fields, exclude_fields, campaign_id).await
+    }
+    async fn put_content(
+        &self,
+        campaign_id: &str,
+        body: &crate::types::CampaignContentData,
+    ) -> Result<crate::types::CampaignContent> {
+        Campaigns::put_content(self, campaign_id, body).await
+    }
+    async fn get_feedback(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> Result<crate::types::CampaignReports> {
+        Campaigns::get_feedback(self, fields, exclude_fields, campaign_id).await
+    }
+    async fn post_feedback(
+        &self,
+        campaign_id: &str,
+        body: &crate::types::CampaignFeedback,
+    ) -> Result<crate::types::CampaignFeedbackData> {
+        Campaigns::post_feedback(self, campaign_id, body).await
+    }
+    async fn get_feedback_campaigns(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        feedback_id: &str,
+    ) -> Result<crate::types::CampaignFeedbackData> {
+        Campaigns::get_feedback_campaigns(self, fields, exclude_fields, campaign_id, feedback_id)
+            .await
+    }
+    async fn delete_feedback(&self, campaign_id: &str, feedback_id: &str) -> Result<()> {
+        Campaigns::delete_feedback(self, campaign_id, feedback_id).await
+    }
+    async fn delete_feedback_if_exists(
+        &self,
+        campaign_id: &str,
+        feedback_id: &str,
+    ) -> Result<bool> {
+        Campaigns::delete_feedback_if_exists(self, campaign_id, feedback_id).await
+    }
+    async fn patch_feedback(
+        &self,
+        campaign_id: &str,
+        feedback_id: &str,
+        body: &crate::types::CampaignFeedbackDataType,
+    ) -> Result<crate::types::CampaignFeedbackData> {
+        Campaigns::patch_feedback(self, campaign_id, feedback_id, body).await
+    }
+    async fn get_send_checklist(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> Result<crate::types::SendChecklist> {
+        Campaigns::get_send_checklist(self, fields, exclude_fields, campaign_id).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct ConnectedSites {
     pub client: Client,
@@ -12,6 +10,14 @@ impl ConnectedSites {
         ConnectedSites { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        ConnectedSites {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * List connected sites.
      *
@@ -33,23 +39,25 @@ impl ConnectedSites {
         count: i64,
         offset: i64,
     ) -> Result<crate::types::ConnectedSites> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/connected-sites?{}", query_);
+        let url = crate::Client::url_with_query("/connected-sites", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/connected-sites", &[]))
     }
 
     /**
@@ -62,8 +70,9 @@ impl ConnectedSites {
     pub async fn post(&self, body: &crate::types::ConnectedSite) -> Result<crate::types::Sites> {
         let url = "/connected-sites".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/connected-sites", &[]))
     }
 
     /**
@@ -85,21 +94,28 @@ impl ConnectedSites {
         exclude_fields: &[String],
         connected_site_id: &str,
     ) -> Result<crate::types::Sites> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/connected-sites/{}?{}",
-            crate::progenitor_support::encode_path(&connected_site_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/connected-sites/{}",
+                self.client.encode_path(connected_site_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/connected-sites/{connected_site_id}",
+                &[("connected_site_id", connected_site_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -116,10 +132,41 @@ impl ConnectedSites {
     pub async fn delete(&self, connected_site_id: &str) -> Result<()> {
         let url = format!(
             "/connected-sites/{}",
-            crate::progenitor_support::encode_path(&connected_site_id.to_string()),
+            self.client.encode_path(connected_site_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/connected-sites/{connected_site_id}",
+                &[("connected_site_id", connected_site_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete connected site.
+     *
+     * This function performs a `DELETE` to the `/connected-sites/{connected_site_id}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, connected_site_id: &str) -> Result<bool> {
+        let url = format!(
+            "/connected-sites/{}",
+            self.client.encode_path(connected_site_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/connected-sites/{connected_site_id}",
+                    &[("connected_site_id", connected_site_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -139,9 +186,74 @@ impl ConnectedSites {
     ) -> Result<()> {
         let url = format!(
             "/connected-sites/{}/actions/verify-script-installation",
-            crate::progenitor_support::encode_path(&connected_site_id.to_string()),
+            self.client.encode_path(connected_site_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/connected-sites/{connected_site_id}/actions/verify-script-installation",
+                &[("connected_site_id", connected_site_id.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`ConnectedSites`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ConnectedSitesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ConnectedSitesApi: Send + Sync {
+    async fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> Result<crate::types::ConnectedSites>;
+    async fn post(&self, body: &crate::types::ConnectedSite) -> Result<crate::types::Sites>;
+    async fn get_connected_sites(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        connected_site_id: &str,
+    ) -> Result<crate::types::Sites>;
+    async fn delete(&self, connected_site_id: &str) -> Result<()>;
+    async fn delete_if_exists(&self, connected_site_id: &str) -> Result<bool>;
+    async fn post_actions_verify_script_installation(&self, connected_site_id: &str) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ConnectedSitesApi for ConnectedSites {
+    async fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> Result<crate::types::ConnectedSites> {
+        ConnectedSites::get(self, fields, exclude_fields, count, offset).await
+    }
+    async fn post(&self, body: &crate::types::ConnectedSite) -> Result<crate::types::Sites> {
+        ConnectedSites::post(self, body).await
+    }
+    async fn get_connected_sites(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        connected_site_id: &str,
+    ) -> Result<crate::types::Sites> {
+        ConnectedSites::get_connected_sites(self, fields, exclude_fields, connected_site_id).await
+    }
+    async fn delete(&self, connected_site_id: &str) -> Result<()> {
+        ConnectedSites::delete(self, connected_site_id).await
+    }
+    async fn delete_if_exists(&self, connected_site_id: &str) -> Result<bool> {
+        ConnectedSites::delete_if_exists(self, connected_site_id).await
+    }
+    async fn post_actions_verify_script_installation(&self, connected_site_id: &str) -> Result<()> {
+        ConnectedSites::post_actions_verify_script_installation(self, connected_site_id).await
     }
 }
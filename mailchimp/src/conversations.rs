@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Conversations {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Conversations {
         Conversations { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Conversations {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * List conversations.
      *
@@ -39,35 +45,34 @@ impl Conversations {
         list_id: &str,
         campaign_id: &str,
     ) -> Result<crate::types::TrackedConversations> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !campaign_id.is_empty() {
-            query_args.push(("campaign_id".to_string(), campaign_id.to_string()));
+            query_args.push(("campaign_id", campaign_id.to_string()));
         }
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if !has_unread_messages.to_string().is_empty() {
-            query_args.push((
-                "has_unread_messages".to_string(),
-                has_unread_messages.to_string(),
-            ));
+            query_args.push(("has_unread_messages", has_unread_messages.to_string()));
         }
         if !list_id.is_empty() {
-            query_args.push(("list_id".to_string(), list_id.to_string()));
+            query_args.push(("list_id", list_id.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/conversations?{}", query_);
+        let url = crate::Client::url_with_query("/conversations", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/conversations", &[]))
     }
 
     /**
@@ -89,21 +94,28 @@ impl Conversations {
         exclude_fields: &[String],
         conversation_id: &str,
     ) -> Result<crate::types::Conversation> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/conversations/{}?{}",
-            crate::progenitor_support::encode_path(&conversation_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/conversations/{}",
+                self.client.encode_path(conversation_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/conversations/{conversation_id}",
+                &[("conversation_id", conversation_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -131,30 +143,37 @@ impl Conversations {
         before_timestamp: Option<chrono::DateTime<chrono::Utc>>,
         since_timestamp: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<crate::types::CollectionOfConversationMessages> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if let Some(date) = before_timestamp {
-            query_args.push(("before_timestamp".to_string(), date.to_rfc3339()));
+            query_args.push(("before_timestamp", date.to_rfc3339()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if !is_read.to_string().is_empty() {
-            query_args.push(("is_read".to_string(), is_read.to_string()));
+            query_args.push(("is_read", is_read.to_string()));
         }
         if let Some(date) = since_timestamp {
-            query_args.push(("since_timestamp".to_string(), date.to_rfc3339()));
+            query_args.push(("since_timestamp", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/conversations/{}/messages?{}",
-            crate::progenitor_support::encode_path(&conversation_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/conversations/{}/messages",
+                self.client.encode_path(conversation_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/conversations/{conversation_id}/messages",
+                &[("conversation_id", conversation_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -178,21 +197,142 @@ impl Conversations {
         conversation_id: &str,
         message_id: &str,
     ) -> Result<crate::types::ConversationMessage> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/conversations/{}/messages/{}?{}",
-            crate::progenitor_support::encode_path(&conversation_id.to_string()),
-            crate::progenitor_support::encode_path(&message_id.to_string()),
-            query_
+            query_args.push(("fields", fields.join(",")));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/conversations/{}/messages/{}",
+                self.client.encode_path(conversation_id),
+                self.client.encode_path(message_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/conversations/{conversation_id}/messages/{message_id}",
+                &[
+                    ("conversation_id", conversation_id.to_string()),
+                    ("message_id", message_id.to_string()),
+                ],
+            )
+        })
+    }
+}
+
+/// The functions of [`Conversations`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ConversationsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ConversationsApi: Send + Sync {
+    async fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        has_unread_messages: crate::types::IsRead,
+        list_id: &str,
+        campaign_id: &str,
+    ) -> Result<crate::types::TrackedConversations>;
+    async fn get_conversations(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        conversation_id: &str,
+    ) -> Result<crate::types::Conversation>;
+    async fn get_message(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        conversation_id: &str,
+        is_read: crate::types::IsRead,
+        before_timestamp: Option<chrono::DateTime<chrono::Utc>>,
+        since_timestamp: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<crate::types::CollectionOfConversationMessages>;
+    async fn get_message_conversations(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        conversation_id: &str,
+        message_id: &str,
+    ) -> Result<crate::types::ConversationMessage>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ConversationsApi for Conversations {
+    async fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        has_unread_messages: crate::types::IsRead,
+        list_id: &str,
+        campaign_id: &str,
+    ) -> Result<crate::types::TrackedConversations> {
+        Conversations::get(
+            self,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            has_unread_messages,
+            list_id,
+            campaign_id,
+        )
+        .await
+    }
+    async fn get_conversations(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        conversation_id: &str,
+    ) -> Result<crate::types::Conversation> {
+        Conversations::get_conversations(self, fields, exclude_fields, conversation_id).await
+    }
+    async fn get_message(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        conversation_id: &str,
+        is_read: crate::types::IsRead,
+        before_timestamp: Option<chrono::DateTime<chrono::Utc>>,
+        since_timestamp: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<crate::types::CollectionOfConversationMessages> {
+        Conversations::get_message(
+            self,
+            fields,
+            exclude_fields,
+            conversation_id,
+            is_read,
+            before_timestamp,
+            since_timestamp,
+        )
+        .await
+    }
+    async fn get_message_conversations(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        conversation_id: &str,
+        message_id: &str,
+    ) -> Result<crate::types::ConversationMessage> {
+        Conversations::get_message_conversations(
+            self,
+            fields,
+            exclude_fields,
+            conversation_id,
+            message_id,
+        )
+        .await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct CustomerJourneys {
     pub client: Client,
@@ -12,6 +10,14 @@ impl CustomerJourneys {
         CustomerJourneys { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        CustomerJourneys {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Customer Journeys API trigger for a contact.
      *
@@ -32,12 +38,49 @@ impl CustomerJourneys {
     ) -> Result<()> {
         let url = format!(
             "/customer-journeys/journeys/{}/steps/{}/actions/trigger",
-            crate::progenitor_support::encode_path(&journey_id.to_string()),
-            crate::progenitor_support::encode_path(&step_id.to_string()),
+            self.client.encode_path(&journey_id.to_string()),
+            self.client.encode_path(&step_id.to_string()),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/customer-journeys/journeys/{journey_id}/steps/{step_id}/actions/trigger",
+                    &[
+                        ("journey_id", journey_id.to_string()),
+                        ("step_id", step_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`CustomerJourneys`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn CustomerJourneysApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait CustomerJourneysApi: Send + Sync {
+    async fn post_steps_actions_trigger(
+        &self,
+        journey_id: i64,
+        step_id: i64,
+        body: &crate::types::SubscriberInAutomationQueue,
+    ) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl CustomerJourneysApi for CustomerJourneys {
+    async fn post_steps_actions_trigger(
+        &self,
+        journey_id: i64,
+        step_id: i64,
+        body: &crate::types::SubscriberInAutomationQueue,
+    ) -> Result<()> {
+        CustomerJourneys::post_steps_actions_trigger(self, journey_id, step_id, body).await
     }
 }
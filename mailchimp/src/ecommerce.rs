@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Ecommerce {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Ecommerce {
         Ecommerce { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Ecommerce {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * List account orders.
      *
@@ -41,35 +47,37 @@ impl Ecommerce {
         customer_id: &str,
         has_outreach: bool,
     ) -> Result<crate::types::OrdersData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !campaign_id.is_empty() {
-            query_args.push(("campaign_id".to_string(), campaign_id.to_string()));
+            query_args.push(("campaign_id", campaign_id.to_string()));
         }
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !customer_id.is_empty() {
-            query_args.push(("customer_id".to_string(), customer_id.to_string()));
+            query_args.push(("customer_id", customer_id.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if has_outreach {
-            query_args.push(("has_outreach".to_string(), has_outreach.to_string()));
+            query_args.push(("has_outreach", has_outreach.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !outreach_id.is_empty() {
-            query_args.push(("outreach_id".to_string(), outreach_id.to_string()));
+            query_args.push(("outreach_id", outreach_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/ecommerce/orders?{}", query_);
+        let url = crate::Client::url_with_query("/ecommerce/orders", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/ecommerce/orders", &[]))
     }
 
     /**
@@ -93,23 +101,25 @@ impl Ecommerce {
         count: i64,
         offset: i64,
     ) -> Result<crate::types::ECommerceStores> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/ecommerce/stores?{}", query_);
+        let url = crate::Client::url_with_query("/ecommerce/stores", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/ecommerce/stores", &[]))
     }
 
     /**
@@ -125,8 +135,9 @@ impl Ecommerce {
     ) -> Result<crate::types::Stores> {
         let url = "/ecommerce/stores".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/ecommerce/stores", &[]))
     }
 
     /**
@@ -148,21 +159,25 @@ impl Ecommerce {
         exclude_fields: &[String],
         store_id: &str,
     ) -> Result<crate::types::Stores> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}?{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/ecommerce/stores/{}", self.client.encode_path(store_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/ecommerce/stores/{store_id}",
+                &[("store_id", store_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -177,12 +192,37 @@ impl Ecommerce {
      * * `store_id: &str` -- The name of the folder.
      */
     pub async fn delete_stores(&self, store_id: &str) -> Result<()> {
-        let url = format!(
-            "/ecommerce/stores/{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-        );
+        let url = format!("/ecommerce/stores/{}", self.client.encode_path(store_id),);
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/ecommerce/stores/{store_id}",
+                &[("store_id", store_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete store.
+     *
+     * This function performs a `DELETE` to the `/ecommerce/stores/{store_id}` endpoint.
+     *
+     * As opposed to `delete_stores`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_stores_if_exists(&self, store_id: &str) -> Result<bool> {
+        let url = format!("/ecommerce/stores/{}", self.client.encode_path(store_id),);
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/ecommerce/stores/{store_id}",
+                    &[("store_id", store_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -201,14 +241,45 @@ impl Ecommerce {
         store_id: &str,
         body: &crate::types::ECommerceStoreData,
     ) -> Result<crate::types::Stores> {
-        let url = format!(
-            "/ecommerce/stores/{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-        );
+        let url = format!("/ecommerce/stores/{}", self.client.encode_path(store_id),);
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/ecommerce/stores/{store_id}",
+                    &[("store_id", store_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update store.
+     *
+     * This function performs a `PATCH` to the `/ecommerce/stores/{store_id}` endpoint.
+     *
+     * As opposed to `patch_stores`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_stores_if_changed<C: serde::Serialize>(
+        &self,
+        store_id: &str,
+        current: &C,
+        body: &crate::types::ECommerceStoreData,
+    ) -> Result<Option<crate::types::Stores>> {
+        let url = format!("/ecommerce/stores/{}", self.client.encode_path(store_id),);
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/ecommerce/stores/{store_id}",
+                    &[("store_id", store_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -234,27 +305,34 @@ impl Ecommerce {
         offset: i64,
         store_id: &str,
     ) -> Result<crate::types::CartsData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}/carts?{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/ecommerce/stores/{}/carts",
+                self.client.encode_path(store_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/ecommerce/stores/{store_id}/carts",
+                &[("store_id", store_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -275,12 +353,19 @@ impl Ecommerce {
     ) -> Result<crate::types::Carts> {
         let url = format!(
             "/ecommerce/stores/{}/carts",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
+            self.client.encode_path(store_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/ecommerce/stores/{store_id}/carts",
+                    &[("store_id", store_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -304,22 +389,32 @@ impl Ecommerce {
         store_id: &str,
         cart_id: &str,
     ) -> Result<crate::types::Carts> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}/carts/{}?{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&cart_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/ecommerce/stores/{}/carts/{}",
+                self.client.encode_path(store_id),
+                self.client.encode_path(cart_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/ecommerce/stores/{store_id}/carts/{cart_id}",
+                &[
+                    ("store_id", store_id.to_string()),
+                    ("cart_id", cart_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -337,11 +432,53 @@ impl Ecommerce {
     pub async fn delete_stores_carts(&self, store_id: &str, cart_id: &str) -> Result<()> {
         let url = format!(
             "/ecommerce/stores/{}/carts/{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&cart_id.to_string()),
+            self.client.encode_path(store_id),
+            self.client.encode_path(cart_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/ecommerce/stores/{store_id}/carts/{cart_id}",
+                &[
+                    ("store_id", store_id.to_string()),
+                    ("cart_id", cart_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Delete cart.
+     *
+     * This function performs a `DELETE` to the `/ecommerce/stores/{store_id}/carts/{cart_id}` endpoint.
+     *
+     * As opposed to `delete_stores_carts`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_stores_carts_if_exists(
+        &self,
+        store_id: &str,
+        cart_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/ecommerce/stores/{}/carts/{}",
+            self.client.encode_path(store_id),
+            self.client.encode_path(cart_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/ecommerce/stores/{store_id}/carts/{cart_id}",
+                    &[
+                        ("store_id", store_id.to_string()),
+                        ("cart_id", cart_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -364,13 +501,58 @@ impl Ecommerce {
     ) -> Result<crate::types::Carts> {
         let url = format!(
             "/ecommerce/stores/{}/carts/{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&cart_id.to_string()),
+            self.client.encode_path(store_id),
+            self.client.encode_path(cart_id),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/ecommerce/stores/{store_id}/carts/{cart_id}",
+                    &[
+                        ("store_id", store_id.to_string()),
+                        ("cart_id", cart_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * Update cart.
+     *
+     * This function performs a `PATCH` to the `/ecommerce/stores/{store_id}/carts/{cart_id}` endpoint.
+     *
+     * As opposed to `patch_stores_carts`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_stores_carts_if_changed<C: serde::Serialize>(
+        &self,
+        store_id: &str,
+        cart_id: &str,
+        current: &C,
+        body: &crate::types::ECommerceCartData,
+    ) -> Result<Option<crate::types::Carts>> {
+        let url = format!(
+            "/ecommerce/stores/{}/carts/{}",
+            self.client.encode_path(store_id),
+            self.client.encode_path(cart_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/ecommerce/stores/{store_id}/carts/{cart_id}",
+                    &[
+                        ("store_id", store_id.to_string()),
+                        ("cart_id", cart_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -398,28 +580,38 @@ impl Ecommerce {
         store_id: &str,
         cart_id: &str,
     ) -> Result<crate::types::CartLines> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}/carts/{}/lines?{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&cart_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/ecommerce/stores/{}/carts/{}/lines",
+                self.client.encode_path(store_id),
+                self.client.encode_path(cart_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/ecommerce/stores/{store_id}/carts/{cart_id}/lines",
+                &[
+                    ("store_id", store_id.to_string()),
+                    ("cart_id", cart_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -442,13 +634,23 @@ impl Ecommerce {
     ) -> Result<crate::types::ECommerceCartLineItem> {
         let url = format!(
             "/ecommerce/stores/{}/carts/{}/lines",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&cart_id.to_string()),
+            self.client.encode_path(store_id),
+            self.client.encode_path(cart_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/ecommerce/stores/{store_id}/carts/{cart_id}/lines",
+                    &[
+                        ("store_id", store_id.to_string()),
+                        ("cart_id", cart_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -474,23 +676,34 @@ impl Ecommerce {
         cart_id: &str,
         line_id: &str,
     ) -> Result<crate::types::ECommerceCartLineItem> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}/carts/{}/lines/{}?{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&cart_id.to_string()),
-            crate::progenitor_support::encode_path(&line_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/ecommerce/stores/{}/carts/{}/lines/{}",
+                self.client.encode_path(store_id),
+                self.client.encode_path(cart_id),
+                self.client.encode_path(line_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/ecommerce/stores/{store_id}/carts/{cart_id}/lines/{line_id}",
+                &[
+                    ("store_id", store_id.to_string()),
+                    ("cart_id", cart_id.to_string()),
+                    ("line_id", line_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -514,12 +727,58 @@ impl Ecommerce {
     ) -> Result<()> {
         let url = format!(
             "/ecommerce/stores/{}/carts/{}/lines/{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&cart_id.to_string()),
-            crate::progenitor_support::encode_path(&line_id.to_string()),
+            self.client.encode_path(store_id),
+            self.client.encode_path(cart_id),
+            self.client.encode_path(line_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/ecommerce/stores/{store_id}/carts/{cart_id}/lines/{line_id}",
+                &[
+                    ("store_id", store_id.to_string()),
+                    ("cart_id", cart_id.to_string()),
+                    ("line_id", line_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Delete cart line item.
+     *
+     * This function performs a `DELETE` to the `/ecommerce/stores/{store_id}/carts/{cart_id}/lines/{line_id}` endpoint.
+     *
+     * As opposed to `delete_stores_carts_lines`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_stores_carts_lines_if_exists(
+        &self,
+        store_id: &str,
+        cart_id: &str,
+        line_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/ecommerce/stores/{}/carts/{}/lines/{}",
+            self.client.encode_path(store_id),
+            self.client.encode_path(cart_id),
+            self.client.encode_path(line_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/ecommerce/stores/{store_id}/carts/{cart_id}/lines/{line_id}",
+                    &[
+                        ("store_id", store_id.to_string()),
+                        ("cart_id", cart_id.to_string()),
+                        ("line_id", line_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -544,14 +803,63 @@ impl Ecommerce {
     ) -> Result<crate::types::ECommerceCartLineItem> {
         let url = format!(
             "/ecommerce/stores/{}/carts/{}/lines/{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&cart_id.to_string()),
-            crate::progenitor_support::encode_path(&line_id.to_string()),
+            self.client.encode_path(store_id),
+            self.client.encode_path(cart_id),
+            self.client.encode_path(line_id),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/ecommerce/stores/{store_id}/carts/{cart_id}/lines/{line_id}",
+                    &[
+                        ("store_id", store_id.to_string()),
+                        ("cart_id", cart_id.to_string()),
+                        ("line_id", line_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * Update cart line item.
+     *
+     * This function performs a `PATCH` to the `/ecommerce/stores/{store_id}/carts/{cart_id}/lines/{line_id}` endpoint.
+     *
+     * As opposed to `patch_stores_carts_lines`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_stores_carts_lines_if_changed<C: serde::Serialize>(
+        &self,
+        store_id: &str,
+        cart_id: &str,
+        line_id: &str,
+        current: &C,
+        body: &crate::types::ECommerceCartLineItemDataType,
+    ) -> Result<Option<crate::types::ECommerceCartLineItem>> {
+        let url = format!(
+            "/ecommerce/stores/{}/carts/{}/lines/{}",
+            self.client.encode_path(store_id),
+            self.client.encode_path(cart_id),
+            self.client.encode_path(line_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/ecommerce/stores/{store_id}/carts/{cart_id}/lines/{line_id}",
+                    &[
+                        ("store_id", store_id.to_string()),
+                        ("cart_id", cart_id.to_string()),
+                        ("line_id", line_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -579,30 +887,37 @@ impl Ecommerce {
         store_id: &str,
         email_address: &str,
     ) -> Result<crate::types::Customers> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !email_address.is_empty() {
-            query_args.push(("email_address".to_string(), email_address.to_string()));
+            query_args.push(("email_address", email_address.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}/customers?{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/ecommerce/stores/{}/customers",
+                self.client.encode_path(store_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/ecommerce/stores/{store_id}/customers",
+                &[("store_id", store_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -623,12 +938,19 @@ impl Ecommerce {
     ) -> Result<crate::types::Customer> {
         let url = format!(
             "/ecommerce/stores/{}/customers",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
+            self.client.encode_path(store_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/ecommerce/stores/{store_id}/customers",
+                    &[("store_id", store_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -652,22 +974,32 @@ impl Ecommerce {
         store_id: &str,
         customer_id: &str,
     ) -> Result<crate::types::Customer> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}/customers/{}?{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/ecommerce/stores/{}/customers/{}",
+                self.client.encode_path(store_id),
+                self.client.encode_path(customer_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/ecommerce/stores/{store_id}/customers/{customer_id}",
+                &[
+                    ("store_id", store_id.to_string()),
+                    ("customer_id", customer_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -690,13 +1022,23 @@ impl Ecommerce {
     ) -> Result<crate::types::Customer> {
         let url = format!(
             "/ecommerce/stores/{}/customers/{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(store_id),
+            self.client.encode_path(customer_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/ecommerce/stores/{store_id}/customers/{customer_id}",
+                    &[
+                        ("store_id", store_id.to_string()),
+                        ("customer_id", customer_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -714,11 +1056,53 @@ impl Ecommerce {
     pub async fn delete_stores_customers(&self, store_id: &str, customer_id: &str) -> Result<()> {
         let url = format!(
             "/ecommerce/stores/{}/customers/{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(store_id),
+            self.client.encode_path(customer_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/ecommerce/stores/{store_id}/customers/{customer_id}",
+                &[
+                    ("store_id", store_id.to_string()),
+                    ("customer_id", customer_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Delete customer.
+     *
+     * This function performs a `DELETE` to the `/ecommerce/stores/{store_id}/customers/{customer_id}` endpoint.
+     *
+     * As opposed to `delete_stores_customers`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_stores_customers_if_exists(
+        &self,
+        store_id: &str,
+        customer_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/ecommerce/stores/{}/customers/{}",
+            self.client.encode_path(store_id),
+            self.client.encode_path(customer_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/ecommerce/stores/{store_id}/customers/{customer_id}",
+                    &[
+                        ("store_id", store_id.to_string()),
+                        ("customer_id", customer_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -741,13 +1125,58 @@ impl Ecommerce {
     ) -> Result<crate::types::Customer> {
         let url = format!(
             "/ecommerce/stores/{}/customers/{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(store_id),
+            self.client.encode_path(customer_id),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/ecommerce/stores/{store_id}/customers/{customer_id}",
+                    &[
+                        ("store_id", store_id.to_string()),
+                        ("customer_id", customer_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * Update customer.
+     *
+     * This function performs a `PATCH` to the `/ecommerce/stores/{store_id}/customers/{customer_id}` endpoint.
+     *
+     * As opposed to `patch_stores_customers`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_stores_customers_if_changed<C: serde::Serialize>(
+        &self,
+        store_id: &str,
+        customer_id: &str,
+        current: &C,
+        body: &crate::types::ECommerceCartCustomer,
+    ) -> Result<Option<crate::types::Customer>> {
+        let url = format!(
+            "/ecommerce/stores/{}/customers/{}",
+            self.client.encode_path(store_id),
+            self.client.encode_path(customer_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/ecommerce/stores/{store_id}/customers/{customer_id}",
+                    &[
+                        ("store_id", store_id.to_string()),
+                        ("customer_id", customer_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -773,27 +1202,34 @@ impl Ecommerce {
         offset: i64,
         store_id: &str,
     ) -> Result<crate::types::PromoRulesData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}/promo-rules?{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/ecommerce/stores/{}/promo-rules",
+                self.client.encode_path(store_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/ecommerce/stores/{store_id}/promo-rules",
+                &[("store_id", store_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -814,12 +1250,19 @@ impl Ecommerce {
     ) -> Result<crate::types::PromoRules> {
         let url = format!(
             "/ecommerce/stores/{}/promo-rules",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
+            self.client.encode_path(store_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/ecommerce/stores/{store_id}/promo-rules",
+                    &[("store_id", store_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -843,22 +1286,32 @@ impl Ecommerce {
         store_id: &str,
         promo_rule_id: &str,
     ) -> Result<crate::types::PromoRules> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}/promo-rules/{}?{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&promo_rule_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/ecommerce/stores/{}/promo-rules/{}",
+                self.client.encode_path(store_id),
+                self.client.encode_path(promo_rule_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}",
+                &[
+                    ("store_id", store_id.to_string()),
+                    ("promo_rule_id", promo_rule_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -880,11 +1333,53 @@ impl Ecommerce {
     ) -> Result<()> {
         let url = format!(
             "/ecommerce/stores/{}/promo-rules/{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&promo_rule_id.to_string()),
+            self.client.encode_path(store_id),
+            self.client.encode_path(promo_rule_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}",
+                &[
+                    ("store_id", store_id.to_string()),
+                    ("promo_rule_id", promo_rule_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Delete promo rule.
+     *
+     * This function performs a `DELETE` to the `/ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}` endpoint.
+     *
+     * As opposed to `delete_stores_promorules`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_stores_promorules_if_exists(
+        &self,
+        store_id: &str,
+        promo_rule_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/ecommerce/stores/{}/promo-rules/{}",
+            self.client.encode_path(store_id),
+            self.client.encode_path(promo_rule_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}",
+                    &[
+                        ("store_id", store_id.to_string()),
+                        ("promo_rule_id", promo_rule_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -907,13 +1402,58 @@ impl Ecommerce {
     ) -> Result<crate::types::PromoRules> {
         let url = format!(
             "/ecommerce/stores/{}/promo-rules/{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&promo_rule_id.to_string()),
+            self.client.encode_path(store_id),
+            self.client.encode_path(promo_rule_id),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}",
+                    &[
+                        ("store_id", store_id.to_string()),
+                        ("promo_rule_id", promo_rule_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * Update promo rule.
+     *
+     * This function performs a `PATCH` to the `/ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}` endpoint.
+     *
+     * As opposed to `patch_stores_promorules`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_stores_promorules_if_changed<C: serde::Serialize>(
+        &self,
+        store_id: &str,
+        promo_rule_id: &str,
+        current: &C,
+        body: &crate::types::ECommercePromoRuleData,
+    ) -> Result<Option<crate::types::PromoRules>> {
+        let url = format!(
+            "/ecommerce/stores/{}/promo-rules/{}",
+            self.client.encode_path(store_id),
+            self.client.encode_path(promo_rule_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}",
+                    &[
+                        ("store_id", store_id.to_string()),
+                        ("promo_rule_id", promo_rule_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -941,28 +1481,38 @@ impl Ecommerce {
         promo_rule_id: &str,
         store_id: &str,
     ) -> Result<crate::types::PromoCodesData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}/promo-rules/{}/promo-codes?{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&promo_rule_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/ecommerce/stores/{}/promo-rules/{}/promo-codes",
+                self.client.encode_path(store_id),
+                self.client.encode_path(promo_rule_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}/promo-codes",
+                &[
+                    ("store_id", store_id.to_string()),
+                    ("promo_rule_id", promo_rule_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -985,13 +1535,23 @@ impl Ecommerce {
     ) -> Result<crate::types::PromoCodes> {
         let url = format!(
             "/ecommerce/stores/{}/promo-rules/{}/promo-codes",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&promo_rule_id.to_string()),
+            self.client.encode_path(store_id),
+            self.client.encode_path(promo_rule_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}/promo-codes",
+                    &[
+                        ("store_id", store_id.to_string()),
+                        ("promo_rule_id", promo_rule_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1017,23 +1577,24 @@ impl Ecommerce {
         promo_rule_id: &str,
         promo_code_id: &str,
     ) -> Result<crate::types::PromoCodes> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}/promo-rules/{}/promo-codes/{}?{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&promo_rule_id.to_string()),
-            crate::progenitor_support::encode_path(&promo_code_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/ecommerce/stores/{}/promo-rules/{}/promo-codes/{}",
+                self.client.encode_path(store_id),
+                self.client.encode_path(promo_rule_id),
+                self.client.encode_path(promo_code_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}/promo-codes/{promo_code_id}", &[("store_id", store_id.to_string()), ("promo_rule_id", promo_rule_id.to_string()), ("promo_code_id", promo_code_id.to_string())]))
     }
 
     /**
@@ -1057,12 +1618,35 @@ impl Ecommerce {
     ) -> Result<()> {
         let url = format!(
             "/ecommerce/stores/{}/promo-rules/{}/promo-codes/{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&promo_rule_id.to_string()),
-            crate::progenitor_support::encode_path(&promo_code_id.to_string()),
+            self.client.encode_path(store_id),
+            self.client.encode_path(promo_rule_id),
+            self.client.encode_path(promo_code_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}/promo-codes/{promo_code_id}", &[("store_id", store_id.to_string()), ("promo_rule_id", promo_rule_id.to_string()), ("promo_code_id", promo_code_id.to_string())]))
+    }
+
+    /**
+     * Delete promo code.
+     *
+     * This function performs a `DELETE` to the `/ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}/promo-codes/{promo_code_id}` endpoint.
+     *
+     * As opposed to `delete_stores_promocodes`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_stores_promocodes_if_exists(
+        &self,
+        store_id: &str,
+        promo_rule_id: &str,
+        promo_code_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/ecommerce/stores/{}/promo-rules/{}/promo-codes/{}",
+            self.client.encode_path(store_id),
+            self.client.encode_path(promo_rule_id),
+            self.client.encode_path(promo_code_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete_if_exists(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}/promo-codes/{promo_code_id}", &[("store_id", store_id.to_string()), ("promo_rule_id", promo_rule_id.to_string()), ("promo_code_id", promo_code_id.to_string())]))
     }
 
     /**
@@ -1087,14 +1671,39 @@ impl Ecommerce {
     ) -> Result<crate::types::PromoCodes> {
         let url = format!(
             "/ecommerce/stores/{}/promo-rules/{}/promo-codes/{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&promo_rule_id.to_string()),
-            crate::progenitor_support::encode_path(&promo_code_id.to_string()),
+            self.client.encode_path(store_id),
+            self.client.encode_path(promo_rule_id),
+            self.client.encode_path(promo_code_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("PATCH", "/ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}/promo-codes/{promo_code_id}", &[("store_id", store_id.to_string()), ("promo_rule_id", promo_rule_id.to_string()), ("promo_code_id", promo_code_id.to_string())]))
+    }
+
+    /**
+     * Update promo code.
+     *
+     * This function performs a `PATCH` to the `/ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}/promo-codes/{promo_code_id}` endpoint.
+     *
+     * As opposed to `patch_stores_promocodes`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_stores_promocodes_if_changed<C: serde::Serialize>(
+        &self,
+        store_id: &str,
+        promo_rule_id: &str,
+        promo_code_id: &str,
+        current: &C,
+        body: &crate::types::ECommercePromoCodeData,
+    ) -> Result<Option<crate::types::PromoCodes>> {
+        let url = format!(
+            "/ecommerce/stores/{}/promo-rules/{}/promo-codes/{}",
+            self.client.encode_path(store_id),
+            self.client.encode_path(promo_rule_id),
+            self.client.encode_path(promo_code_id),
+        );
+
+        self.client.patch_if_changed(&url, current, body).await.with_context(|| crate::utils::pretty_url("PATCH", "/ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}/promo-codes/{promo_code_id}", &[("store_id", store_id.to_string()), ("promo_rule_id", promo_rule_id.to_string()), ("promo_code_id", promo_code_id.to_string())]))
     }
 
     /**
@@ -1128,39 +1737,46 @@ impl Ecommerce {
         campaign_id: &str,
         outreach_id: &str,
     ) -> Result<crate::types::OrdersDataType> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !campaign_id.is_empty() {
-            query_args.push(("campaign_id".to_string(), campaign_id.to_string()));
+            query_args.push(("campaign_id", campaign_id.to_string()));
         }
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !customer_id.is_empty() {
-            query_args.push(("customer_id".to_string(), customer_id.to_string()));
+            query_args.push(("customer_id", customer_id.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if has_outreach {
-            query_args.push(("has_outreach".to_string(), has_outreach.to_string()));
+            query_args.push(("has_outreach", has_outreach.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !outreach_id.is_empty() {
-            query_args.push(("outreach_id".to_string(), outreach_id.to_string()));
+            query_args.push(("outreach_id", outreach_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}/orders?{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/ecommerce/stores/{}/orders",
+                self.client.encode_path(store_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/ecommerce/stores/{store_id}/orders",
+                &[("store_id", store_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1181,12 +1797,19 @@ impl Ecommerce {
     ) -> Result<crate::types::Orders> {
         let url = format!(
             "/ecommerce/stores/{}/orders",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
+            self.client.encode_path(store_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/ecommerce/stores/{store_id}/orders",
+                    &[("store_id", store_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1210,22 +1833,32 @@ impl Ecommerce {
         store_id: &str,
         order_id: &str,
     ) -> Result<crate::types::Orders> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}/orders/{}?{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/ecommerce/stores/{}/orders/{}",
+                self.client.encode_path(store_id),
+                self.client.encode_path(order_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/ecommerce/stores/{store_id}/orders/{order_id}",
+                &[
+                    ("store_id", store_id.to_string()),
+                    ("order_id", order_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1243,11 +1876,53 @@ impl Ecommerce {
     pub async fn delete_stores_orders(&self, store_id: &str, order_id: &str) -> Result<()> {
         let url = format!(
             "/ecommerce/stores/{}/orders/{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            self.client.encode_path(store_id),
+            self.client.encode_path(order_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/ecommerce/stores/{store_id}/orders/{order_id}",
+                &[
+                    ("store_id", store_id.to_string()),
+                    ("order_id", order_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Delete order.
+     *
+     * This function performs a `DELETE` to the `/ecommerce/stores/{store_id}/orders/{order_id}` endpoint.
+     *
+     * As opposed to `delete_stores_orders`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_stores_orders_if_exists(
+        &self,
+        store_id: &str,
+        order_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/ecommerce/stores/{}/orders/{}",
+            self.client.encode_path(store_id),
+            self.client.encode_path(order_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/ecommerce/stores/{store_id}/orders/{order_id}",
+                    &[
+                        ("store_id", store_id.to_string()),
+                        ("order_id", order_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1270,29 +1945,74 @@ impl Ecommerce {
     ) -> Result<crate::types::Orders> {
         let url = format!(
             "/ecommerce/stores/{}/orders/{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            self.client.encode_path(store_id),
+            self.client.encode_path(order_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/ecommerce/stores/{store_id}/orders/{order_id}",
+                    &[
+                        ("store_id", store_id.to_string()),
+                        ("order_id", order_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
-     * List order line items.
-     *
-     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/orders/{order_id}/lines` endpoint.
-     *
-     * Get information about an order's line items.
+     * Update order.
      *
-     * **Parameters:**
+     * This function performs a `PATCH` to the `/ecommerce/stores/{store_id}/orders/{order_id}` endpoint.
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
-     * * `store_id: &str` -- The name of the folder.
+     * As opposed to `patch_stores_orders`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_stores_orders_if_changed<C: serde::Serialize>(
+        &self,
+        store_id: &str,
+        order_id: &str,
+        current: &C,
+        body: &crate::types::ECommerceOrderData,
+    ) -> Result<Option<crate::types::Orders>> {
+        let url = format!(
+            "/ecommerce/stores/{}/orders/{}",
+            self.client.encode_path(store_id),
+            self.client.encode_path(order_id),
+        );
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/ecommerce/stores/{store_id}/orders/{order_id}",
+                    &[
+                        ("store_id", store_id.to_string()),
+                        ("order_id", order_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * List order line items.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/orders/{order_id}/lines` endpoint.
+     *
+     * Get information about an order's line items.
+     *
+     * **Parameters:**
+     *
+     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
+     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+     * * `store_id: &str` -- The name of the folder.
      * * `order_id: &str` -- The id for the order in a store.
      */
     pub async fn get_stores_orders_line(
@@ -1304,28 +2024,38 @@ impl Ecommerce {
         store_id: &str,
         order_id: &str,
     ) -> Result<crate::types::OrderLines> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}/orders/{}/lines?{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/ecommerce/stores/{}/orders/{}/lines",
+                self.client.encode_path(store_id),
+                self.client.encode_path(order_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/ecommerce/stores/{store_id}/orders/{order_id}/lines",
+                &[
+                    ("store_id", store_id.to_string()),
+                    ("order_id", order_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1348,13 +2078,23 @@ impl Ecommerce {
     ) -> Result<crate::types::Lines> {
         let url = format!(
             "/ecommerce/stores/{}/orders/{}/lines",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            self.client.encode_path(store_id),
+            self.client.encode_path(order_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/ecommerce/stores/{store_id}/orders/{order_id}/lines",
+                    &[
+                        ("store_id", store_id.to_string()),
+                        ("order_id", order_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1380,23 +2120,34 @@ impl Ecommerce {
         order_id: &str,
         line_id: &str,
     ) -> Result<crate::types::Lines> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}/orders/{}/lines/{}?{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&line_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/ecommerce/stores/{}/orders/{}/lines/{}",
+                self.client.encode_path(store_id),
+                self.client.encode_path(order_id),
+                self.client.encode_path(line_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/ecommerce/stores/{store_id}/orders/{order_id}/lines/{line_id}",
+                &[
+                    ("store_id", store_id.to_string()),
+                    ("order_id", order_id.to_string()),
+                    ("line_id", line_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1420,12 +2171,58 @@ impl Ecommerce {
     ) -> Result<()> {
         let url = format!(
             "/ecommerce/stores/{}/orders/{}/lines/{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&line_id.to_string()),
+            self.client.encode_path(store_id),
+            self.client.encode_path(order_id),
+            self.client.encode_path(line_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/ecommerce/stores/{store_id}/orders/{order_id}/lines/{line_id}",
+                &[
+                    ("store_id", store_id.to_string()),
+                    ("order_id", order_id.to_string()),
+                    ("line_id", line_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Delete order line item.
+     *
+     * This function performs a `DELETE` to the `/ecommerce/stores/{store_id}/orders/{order_id}/lines/{line_id}` endpoint.
+     *
+     * As opposed to `delete_stores_orders_lines`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_stores_orders_lines_if_exists(
+        &self,
+        store_id: &str,
+        order_id: &str,
+        line_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/ecommerce/stores/{}/orders/{}/lines/{}",
+            self.client.encode_path(store_id),
+            self.client.encode_path(order_id),
+            self.client.encode_path(line_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/ecommerce/stores/{store_id}/orders/{order_id}/lines/{line_id}",
+                    &[
+                        ("store_id", store_id.to_string()),
+                        ("order_id", order_id.to_string()),
+                        ("line_id", line_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1450,14 +2247,63 @@ impl Ecommerce {
     ) -> Result<crate::types::Lines> {
         let url = format!(
             "/ecommerce/stores/{}/orders/{}/lines/{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&line_id.to_string()),
+            self.client.encode_path(store_id),
+            self.client.encode_path(order_id),
+            self.client.encode_path(line_id),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/ecommerce/stores/{store_id}/orders/{order_id}/lines/{line_id}",
+                    &[
+                        ("store_id", store_id.to_string()),
+                        ("order_id", order_id.to_string()),
+                        ("line_id", line_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * Update order line item.
+     *
+     * This function performs a `PATCH` to the `/ecommerce/stores/{store_id}/orders/{order_id}/lines/{line_id}` endpoint.
+     *
+     * As opposed to `patch_stores_orders_lines`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_stores_orders_lines_if_changed<C: serde::Serialize>(
+        &self,
+        store_id: &str,
+        order_id: &str,
+        line_id: &str,
+        current: &C,
+        body: &crate::types::ECommerceOrderLineItemData,
+    ) -> Result<Option<crate::types::Lines>> {
+        let url = format!(
+            "/ecommerce/stores/{}/orders/{}/lines/{}",
+            self.client.encode_path(store_id),
+            self.client.encode_path(order_id),
+            self.client.encode_path(line_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/ecommerce/stores/{store_id}/orders/{order_id}/lines/{line_id}",
+                    &[
+                        ("store_id", store_id.to_string()),
+                        ("order_id", order_id.to_string()),
+                        ("line_id", line_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1483,27 +2329,34 @@ impl Ecommerce {
         offset: i64,
         store_id: &str,
     ) -> Result<crate::types::ProductsData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}/products?{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/ecommerce/stores/{}/products",
+                self.client.encode_path(store_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/ecommerce/stores/{store_id}/products",
+                &[("store_id", store_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1524,12 +2377,19 @@ impl Ecommerce {
     ) -> Result<crate::types::ECommerceProduct> {
         let url = format!(
             "/ecommerce/stores/{}/products",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
+            self.client.encode_path(store_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/ecommerce/stores/{store_id}/products",
+                    &[("store_id", store_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1553,22 +2413,32 @@ impl Ecommerce {
         store_id: &str,
         product_id: &str,
     ) -> Result<crate::types::ECommerceProduct> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}/products/{}?{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/ecommerce/stores/{}/products/{}",
+                self.client.encode_path(store_id),
+                self.client.encode_path(product_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/ecommerce/stores/{store_id}/products/{product_id}",
+                &[
+                    ("store_id", store_id.to_string()),
+                    ("product_id", product_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1586,11 +2456,53 @@ impl Ecommerce {
     pub async fn delete_stores_products(&self, store_id: &str, product_id: &str) -> Result<()> {
         let url = format!(
             "/ecommerce/stores/{}/products/{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&product_id.to_string()),
+            self.client.encode_path(store_id),
+            self.client.encode_path(product_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/ecommerce/stores/{store_id}/products/{product_id}",
+                &[
+                    ("store_id", store_id.to_string()),
+                    ("product_id", product_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Delete product.
+     *
+     * This function performs a `DELETE` to the `/ecommerce/stores/{store_id}/products/{product_id}` endpoint.
+     *
+     * As opposed to `delete_stores_products`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_stores_products_if_exists(
+        &self,
+        store_id: &str,
+        product_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/ecommerce/stores/{}/products/{}",
+            self.client.encode_path(store_id),
+            self.client.encode_path(product_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/ecommerce/stores/{store_id}/products/{product_id}",
+                    &[
+                        ("store_id", store_id.to_string()),
+                        ("product_id", product_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1613,13 +2525,58 @@ impl Ecommerce {
     ) -> Result<crate::types::ECommerceProduct> {
         let url = format!(
             "/ecommerce/stores/{}/products/{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&product_id.to_string()),
+            self.client.encode_path(store_id),
+            self.client.encode_path(product_id),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/ecommerce/stores/{store_id}/products/{product_id}",
+                    &[
+                        ("store_id", store_id.to_string()),
+                        ("product_id", product_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * Update product.
+     *
+     * This function performs a `PATCH` to the `/ecommerce/stores/{store_id}/products/{product_id}` endpoint.
+     *
+     * As opposed to `patch_stores_products`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_stores_products_if_changed<C: serde::Serialize>(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        current: &C,
+        body: &crate::types::ECommerceProductDataType,
+    ) -> Result<Option<crate::types::ECommerceProduct>> {
+        let url = format!(
+            "/ecommerce/stores/{}/products/{}",
+            self.client.encode_path(store_id),
+            self.client.encode_path(product_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/ecommerce/stores/{store_id}/products/{product_id}",
+                    &[
+                        ("store_id", store_id.to_string()),
+                        ("product_id", product_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1647,28 +2604,38 @@ impl Ecommerce {
         store_id: &str,
         product_id: &str,
     ) -> Result<crate::types::EcommerceProductVariants> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}/products/{}/variants?{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/ecommerce/stores/{}/products/{}/variants",
+                self.client.encode_path(store_id),
+                self.client.encode_path(product_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/ecommerce/stores/{store_id}/products/{product_id}/variants",
+                &[
+                    ("store_id", store_id.to_string()),
+                    ("product_id", product_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1691,13 +2658,23 @@ impl Ecommerce {
     ) -> Result<crate::types::Variants> {
         let url = format!(
             "/ecommerce/stores/{}/products/{}/variants",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&product_id.to_string()),
+            self.client.encode_path(store_id),
+            self.client.encode_path(product_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/ecommerce/stores/{store_id}/products/{product_id}/variants",
+                    &[
+                        ("store_id", store_id.to_string()),
+                        ("product_id", product_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1723,23 +2700,34 @@ impl Ecommerce {
         product_id: &str,
         variant_id: &str,
     ) -> Result<crate::types::Variants> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}/products/{}/variants/{}?{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            crate::progenitor_support::encode_path(&variant_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/ecommerce/stores/{}/products/{}/variants/{}",
+                self.client.encode_path(store_id),
+                self.client.encode_path(product_id),
+                self.client.encode_path(variant_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/ecommerce/stores/{store_id}/products/{product_id}/variants/{variant_id}",
+                &[
+                    ("store_id", store_id.to_string()),
+                    ("product_id", product_id.to_string()),
+                    ("variant_id", variant_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1764,14 +2752,25 @@ impl Ecommerce {
     ) -> Result<crate::types::Variants> {
         let url = format!(
             "/ecommerce/stores/{}/products/{}/variants/{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            crate::progenitor_support::encode_path(&variant_id.to_string()),
+            self.client.encode_path(store_id),
+            self.client.encode_path(product_id),
+            self.client.encode_path(variant_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/ecommerce/stores/{store_id}/products/{product_id}/variants/{variant_id}",
+                    &[
+                        ("store_id", store_id.to_string()),
+                        ("product_id", product_id.to_string()),
+                        ("variant_id", variant_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1795,12 +2794,58 @@ impl Ecommerce {
     ) -> Result<()> {
         let url = format!(
             "/ecommerce/stores/{}/products/{}/variants/{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            crate::progenitor_support::encode_path(&variant_id.to_string()),
+            self.client.encode_path(store_id),
+            self.client.encode_path(product_id),
+            self.client.encode_path(variant_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/ecommerce/stores/{store_id}/products/{product_id}/variants/{variant_id}",
+                &[
+                    ("store_id", store_id.to_string()),
+                    ("product_id", product_id.to_string()),
+                    ("variant_id", variant_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Delete product variant.
+     *
+     * This function performs a `DELETE` to the `/ecommerce/stores/{store_id}/products/{product_id}/variants/{variant_id}` endpoint.
+     *
+     * As opposed to `delete_stores_products_variants`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_stores_products_variants_if_exists(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        variant_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/ecommerce/stores/{}/products/{}/variants/{}",
+            self.client.encode_path(store_id),
+            self.client.encode_path(product_id),
+            self.client.encode_path(variant_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/ecommerce/stores/{store_id}/products/{product_id}/variants/{variant_id}",
+                    &[
+                        ("store_id", store_id.to_string()),
+                        ("product_id", product_id.to_string()),
+                        ("variant_id", variant_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1825,14 +2870,63 @@ impl Ecommerce {
     ) -> Result<crate::types::Variants> {
         let url = format!(
             "/ecommerce/stores/{}/products/{}/variants/{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            crate::progenitor_support::encode_path(&variant_id.to_string()),
+            self.client.encode_path(store_id),
+            self.client.encode_path(product_id),
+            self.client.encode_path(variant_id),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/ecommerce/stores/{store_id}/products/{product_id}/variants/{variant_id}",
+                    &[
+                        ("store_id", store_id.to_string()),
+                        ("product_id", product_id.to_string()),
+                        ("variant_id", variant_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * Update product variant.
+     *
+     * This function performs a `PATCH` to the `/ecommerce/stores/{store_id}/products/{product_id}/variants/{variant_id}` endpoint.
+     *
+     * As opposed to `patch_stores_products_variants`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_stores_products_variants_if_changed<C: serde::Serialize>(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        variant_id: &str,
+        current: &C,
+        body: &crate::types::ECommerceProductVariantData,
+    ) -> Result<Option<crate::types::Variants>> {
+        let url = format!(
+            "/ecommerce/stores/{}/products/{}/variants/{}",
+            self.client.encode_path(store_id),
+            self.client.encode_path(product_id),
+            self.client.encode_path(variant_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/ecommerce/stores/{store_id}/products/{product_id}/variants/{variant_id}",
+                    &[
+                        ("store_id", store_id.to_string()),
+                        ("product_id", product_id.to_string()),
+                        ("variant_id", variant_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1860,28 +2954,38 @@ impl Ecommerce {
         store_id: &str,
         product_id: &str,
     ) -> Result<crate::types::EcommerceProductImages> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}/products/{}/images?{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/ecommerce/stores/{}/products/{}/images",
+                self.client.encode_path(store_id),
+                self.client.encode_path(product_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/ecommerce/stores/{store_id}/products/{product_id}/images",
+                &[
+                    ("store_id", store_id.to_string()),
+                    ("product_id", product_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1904,13 +3008,23 @@ impl Ecommerce {
     ) -> Result<crate::types::Images> {
         let url = format!(
             "/ecommerce/stores/{}/products/{}/images",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&product_id.to_string()),
+            self.client.encode_path(store_id),
+            self.client.encode_path(product_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/ecommerce/stores/{store_id}/products/{product_id}/images",
+                    &[
+                        ("store_id", store_id.to_string()),
+                        ("product_id", product_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1936,23 +3050,34 @@ impl Ecommerce {
         product_id: &str,
         image_id: &str,
     ) -> Result<crate::types::Images> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/ecommerce/stores/{}/products/{}/images/{}?{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            crate::progenitor_support::encode_path(&image_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/ecommerce/stores/{}/products/{}/images/{}",
+                self.client.encode_path(store_id),
+                self.client.encode_path(product_id),
+                self.client.encode_path(image_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/ecommerce/stores/{store_id}/products/{product_id}/images/{image_id}",
+                &[
+                    ("store_id", store_id.to_string()),
+                    ("product_id", product_id.to_string()),
+                    ("image_id", image_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1976,12 +3101,58 @@ impl Ecommerce {
     ) -> Result<()> {
         let url = format!(
             "/ecommerce/stores/{}/products/{}/images/{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            crate::progenitor_support::encode_path(&image_id.to_string()),
+            self.client.encode_path(store_id),
+            self.client.encode_path(product_id),
+            self.client.encode_path(image_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/ecommerce/stores/{store_id}/products/{product_id}/images/{image_id}",
+                &[
+                    ("store_id", store_id.to_string()),
+                    ("product_id", product_id.to_string()),
+                    ("image_id", image_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Delete product image.
+     *
+     * This function performs a `DELETE` to the `/ecommerce/stores/{store_id}/products/{product_id}/images/{image_id}` endpoint.
+     *
+     * As opposed to `delete_stores_products_images`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_stores_products_images_if_exists(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/ecommerce/stores/{}/products/{}/images/{}",
+            self.client.encode_path(store_id),
+            self.client.encode_path(product_id),
+            self.client.encode_path(image_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/ecommerce/stores/{store_id}/products/{product_id}/images/{image_id}",
+                    &[
+                        ("store_id", store_id.to_string()),
+                        ("product_id", product_id.to_string()),
+                        ("image_id", image_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -2006,13 +3177,1178 @@ impl Ecommerce {
     ) -> Result<crate::types::Images> {
         let url = format!(
             "/ecommerce/stores/{}/products/{}/images/{}",
-            crate::progenitor_support::encode_path(&store_id.to_string()),
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            crate::progenitor_support::encode_path(&image_id.to_string()),
+            self.client.encode_path(store_id),
+            self.client.encode_path(product_id),
+            self.client.encode_path(image_id),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/ecommerce/stores/{store_id}/products/{product_id}/images/{image_id}",
+                    &[
+                        ("store_id", store_id.to_string()),
+                        ("product_id", product_id.to_string()),
+                        ("image_id", image_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * Update product image.
+     *
+     * This function performs a `PATCH` to the `/ecommerce/stores/{store_id}/products/{product_id}/images/{image_id}` endpoint.
+     *
+     * As opposed to `patch_stores_products_images`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_stores_products_images_if_changed<C: serde::Serialize>(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        image_id: &str,
+        current: &C,
+        body: &crate::types::ECommerceProductImageData,
+    ) -> Result<Option<crate::types::Images>> {
+        let url = format!(
+            "/ecommerce/stores/{}/products/{}/images/{}",
+            self.client.encode_path(store_id),
+            self.client.encode_path(product_id),
+            self.client.encode_path(image_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/ecommerce/stores/{store_id}/products/{product_id}/images/{image_id}",
+                    &[
+                        ("store_id", store_id.to_string()),
+                        ("product_id", product_id.to_string()),
+                        ("image_id", image_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`Ecommerce`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn EcommerceApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait EcommerceApi: Send + Sync {
+    async fn get_order(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        campaign_id: &str,
+        outreach_id: &str,
+        customer_id: &str,
+        has_outreach: bool,
+    ) -> Result<crate::types::OrdersData>;
+    async fn get_store(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> Result<crate::types::ECommerceStores>;
+    async fn post_store(&self, body: &crate::types::ECommerceStore)
+        -> Result<crate::types::Stores>;
+    async fn get_store_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+    ) -> Result<crate::types::Stores>;
+    async fn delete_stores(&self, store_id: &str) -> Result<()>;
+    async fn delete_stores_if_exists(&self, store_id: &str) -> Result<bool>;
+    async fn patch_stores(
+        &self,
+        store_id: &str,
+        body: &crate::types::ECommerceStoreData,
+    ) -> Result<crate::types::Stores>;
+    async fn get_stores_cart(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+    ) -> Result<crate::types::CartsData>;
+    async fn post_stores_cart(
+        &self,
+        store_id: &str,
+        body: &crate::types::ECommerceCart,
+    ) -> Result<crate::types::Carts>;
+    async fn get_stores_cart_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        cart_id: &str,
+    ) -> Result<crate::types::Carts>;
+    async fn delete_stores_carts(&self, store_id: &str, cart_id: &str) -> Result<()>;
+    async fn delete_stores_carts_if_exists(&self, store_id: &str, cart_id: &str) -> Result<bool>;
+    async fn patch_stores_carts(
+        &self,
+        store_id: &str,
+        cart_id: &str,
+        body: &crate::types::ECommerceCartData,
+    ) -> Result<crate::types::Carts>;
+    async fn get_stores_carts_line(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+        cart_id: &str,
+    ) -> Result<crate::types::CartLines>;
+    async fn post_stores_carts_line(
+        &self,
+        store_id: &str,
+        cart_id: &str,
+        body: &crate::types::ECommerceCartLineItemData,
+    ) -> Result<crate::types::ECommerceCartLineItem>;
+    async fn get_stores_carts_line_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        cart_id: &str,
+        line_id: &str,
+    ) -> Result<crate::types::ECommerceCartLineItem>;
+    async fn delete_stores_carts_lines(
+        &self,
+        store_id: &str,
+        cart_id: &str,
+        line_id: &str,
+    ) -> Result<()>;
+    async fn delete_stores_carts_lines_if_exists(
+        &self,
+        store_id: &str,
+        cart_id: &str,
+        line_id: &str,
+    ) -> Result<bool>;
+    async fn patch_stores_carts_lines(
+        &self,
+        store_id: &str,
+        cart_id: &str,
+        line_id: &str,
+        body: &crate::types::ECommerceCartLineItemDataType,
+    ) -> Result<crate::types::ECommerceCartLineItem>;
+    async fn get_stores_customer(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+        email_address: &str,
+    ) -> Result<crate::types::Customers>;
+    async fn post_stores_customer(
+        &self,
+        store_id: &str,
+        body: &crate::types::ECommerceCustomerData,
+    ) -> Result<crate::types::Customer>;
+    async fn get_stores_customer_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        customer_id: &str,
+    ) -> Result<crate::types::Customer>;
+    async fn put_stores_customers(
+        &self,
+        store_id: &str,
+        customer_id: &str,
+        body: &crate::types::ECommerceCustomerDataType,
+    ) -> Result<crate::types::Customer>;
+    async fn delete_stores_customers(&self, store_id: &str, customer_id: &str) -> Result<()>;
+    async fn delete_stores_customers_if_exists(
+        &self,
+        store_id: &str,
+        customer_id: &str,
+    ) -> Result<bool>;
+    async fn patch_stores_customers(
+        &self,
+        store_id: &str,
+        customer_id: &str,
+        body: &crate::types::ECommerceCartCustomer,
+    ) -> Result<crate::types::Customer>;
+    async fn get_stores_promorule(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+    ) -> Result<crate::types::PromoRulesData>;
+    async fn post_stores_promorule(
+        &self,
+        store_id: &str,
+        body: &crate::types::ECommercePromoRule,
+    ) -> Result<crate::types::PromoRules>;
+    async fn get_stores_promorule_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        promo_rule_id: &str,
+    ) -> Result<crate::types::PromoRules>;
+    async fn delete_stores_promorules(&self, store_id: &str, promo_rule_id: &str) -> Result<()>;
+    async fn delete_stores_promorules_if_exists(
+        &self,
+        store_id: &str,
+        promo_rule_id: &str,
+    ) -> Result<bool>;
+    async fn patch_stores_promorules(
+        &self,
+        store_id: &str,
+        promo_rule_id: &str,
+        body: &crate::types::ECommercePromoRuleData,
+    ) -> Result<crate::types::PromoRules>;
+    async fn get_stores_promocode(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        promo_rule_id: &str,
+        store_id: &str,
+    ) -> Result<crate::types::PromoCodesData>;
+    async fn post_stores_promocode(
+        &self,
+        store_id: &str,
+        promo_rule_id: &str,
+        body: &crate::types::ECommercePromoCode,
+    ) -> Result<crate::types::PromoCodes>;
+    async fn get_stores_promocode_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        promo_rule_id: &str,
+        promo_code_id: &str,
+    ) -> Result<crate::types::PromoCodes>;
+    async fn delete_stores_promocodes(
+        &self,
+        store_id: &str,
+        promo_rule_id: &str,
+        promo_code_id: &str,
+    ) -> Result<()>;
+    async fn delete_stores_promocodes_if_exists(
+        &self,
+        store_id: &str,
+        promo_rule_id: &str,
+        promo_code_id: &str,
+    ) -> Result<bool>;
+    async fn patch_stores_promocodes(
+        &self,
+        store_id: &str,
+        promo_rule_id: &str,
+        promo_code_id: &str,
+        body: &crate::types::ECommercePromoCodeData,
+    ) -> Result<crate::types::PromoCodes>;
+    async fn get_stores_order(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+        customer_id: &str,
+        has_outreach: bool,
+        campaign_id: &str,
+        outreach_id: &str,
+    ) -> Result<crate::types::OrdersDataType>;
+    async fn post_stores_order(
+        &self,
+        store_id: &str,
+        body: &crate::types::ECommerceOrder,
+    ) -> Result<crate::types::Orders>;
+    async fn get_stores_order_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        order_id: &str,
+    ) -> Result<crate::types::Orders>;
+    async fn delete_stores_orders(&self, store_id: &str, order_id: &str) -> Result<()>;
+    async fn delete_stores_orders_if_exists(&self, store_id: &str, order_id: &str) -> Result<bool>;
+    async fn patch_stores_orders(
+        &self,
+        store_id: &str,
+        order_id: &str,
+        body: &crate::types::ECommerceOrderData,
+    ) -> Result<crate::types::Orders>;
+    async fn get_stores_orders_line(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+        order_id: &str,
+    ) -> Result<crate::types::OrderLines>;
+    async fn post_stores_orders_line(
+        &self,
+        store_id: &str,
+        order_id: &str,
+        body: &crate::types::ECommerceOrderLineItem,
+    ) -> Result<crate::types::Lines>;
+    async fn get_stores_orders_line_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        order_id: &str,
+        line_id: &str,
+    ) -> Result<crate::types::Lines>;
+    async fn delete_stores_orders_lines(
+        &self,
+        store_id: &str,
+        order_id: &str,
+        line_id: &str,
+    ) -> Result<()>;
+    async fn delete_stores_orders_lines_if_exists(
+        &self,
+        store_id: &str,
+        order_id: &str,
+        line_id: &str,
+    ) -> Result<bool>;
+    async fn patch_stores_orders_lines(
+        &self,
+        store_id: &str,
+        order_id: &str,
+        line_id: &str,
+        body: &crate::types::ECommerceOrderLineItemData,
+    ) -> Result<crate::types::Lines>;
+    async fn get_stores_product(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+    ) -> Result<crate::types::ProductsData>;
+    async fn post_stores_product(
+        &self,
+        store_id: &str,
+        body: &crate::types::ECommerceProductData,
+    ) -> Result<crate::types::ECommerceProduct>;
+    async fn get_stores_product_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        product_id: &str,
+    ) -> Result<crate::types::ECommerceProduct>;
+    async fn delete_stores_products(&self, store_id: &str, product_id: &str) -> Result<()>;
+    async fn delete_stores_products_if_exists(
+        &self,
+        store_id: &str,
+        product_id: &str,
+    ) -> Result<bool>;
+    async fn patch_stores_products(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        body: &crate::types::ECommerceProductDataType,
+    ) -> Result<crate::types::ECommerceProduct>;
+    async fn get_stores_products_variant(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+        product_id: &str,
+    ) -> Result<crate::types::EcommerceProductVariants>;
+    async fn post_stores_products_variant(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        body: &crate::types::ECommerceProductVariant,
+    ) -> Result<crate::types::Variants>;
+    async fn get_stores_products_variant_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        product_id: &str,
+        variant_id: &str,
+    ) -> Result<crate::types::Variants>;
+    async fn put_stores_products_variants(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        variant_id: &str,
+        body: &crate::types::ECommerceProductVariant,
+    ) -> Result<crate::types::Variants>;
+    async fn delete_stores_products_variants(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        variant_id: &str,
+    ) -> Result<()>;
+    async fn delete_stores_products_variants_if_exists(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        variant_id: &str,
+    ) -> Result<bool>;
+    async fn patch_stores_products_variants(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        variant_id: &str,
+        body: &crate::types::ECommerceProductVariantData,
+    ) -> Result<crate::types::Variants>;
+    async fn get_stores_products_image(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+        product_id: &str,
+    ) -> Result<crate::types::EcommerceProductImages>;
+    async fn post_stores_products_image(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        body: &crate::types::ECommerceProductImage,
+    ) -> Result<crate::types::Images>;
+    async fn get_stores_products_image_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<crate::types::Images>;
+    async fn delete_stores_products_images(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<()>;
+    async fn delete_stores_products_images_if_exists(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<bool>;
+    async fn patch_stores_products_images(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        image_id: &str,
+        body: &crate::types::ECommerceProductImageData,
+    ) -> Result<crate::types::Images>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl EcommerceApi for Ecommerce {
+    async fn get_order(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        campaign_id: &str,
+        outreach_id: &str,
+        customer_id: &str,
+        has_outreach: bool,
+    ) -> Result<crate::types::OrdersData> {
+        Ecommerce::get_order(
+            self,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            campaign_id,
+            outreach_id,
+            customer_id,
+            has_outreach,
+        )
+        .await
+    }
+    async fn get_store(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> Result<crate::types::ECommerceStores> {
+        Ecommerce::get_store(self, fields, exclude_fields, count, offset).await
+    }
+    async fn post_store(
+        &self,
+        body: &crate::types::ECommerceStore,
+    ) -> Result<crate::types::Stores> {
+        Ecommerce::post_store(self, body).await
+    }
+    async fn get_store_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+    ) -> Result<crate::types::Stores> {
+        Ecommerce::get_store_ecommerce(self, fields, exclude_fields, store_id).await
+    }
+    async fn delete_stores(&self, store_id: &str) -> Result<()> {
+        Ecommerce::delete_stores(self, store_id).await
+    }
+    async fn delete_stores_if_exists(&self, store_id: &str) -> Result<bool> {
+        Ecommerce::delete_stores_if_exists(self, store_id).await
+    }
+    async fn patch_stores(
+        &self,
+        store_id: &str,
+        body: &crate::types::ECommerceStoreData,
+    ) -> Result<crate::types::Stores> {
+        Ecommerce::patch_stores(self, store_id, body).await
+    }
+    async fn get_stores_cart(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+    ) -> Result<crate::types::CartsData> {
+        Ecommerce::get_stores_cart(self, fields, exclude_fields, count, offset, store_id).await
+    }
+    async fn post_stores_cart(
+        &self,
+        store_id: &str,
+        body: &crate::types::ECommerceCart,
+    ) -> Result<crate::types::Carts> {
+        Ecommerce::post_stores_cart(self, store_id, body).await
+    }
+    async fn get_stores_cart_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        cart_id: &str,
+    ) -> Result<crate::types::Carts> {
+        Ecommerce::get_stores_cart_ecommerce(self, fields, exclude_fields, store_id, cart_id).await
+    }
+    async fn delete_stores_carts(&self, store_id: &str, cart_id: &str) -> Result<()> {
+        Ecommerce::delete_stores_carts(self, store_id, cart_id).await
+    }
+    async fn delete_stores_carts_if_exists(&self, store_id: &str, cart_id: &str) -> Result<bool> {
+        Ecommerce::delete_stores_carts_if_exists(self, store_id, cart_id).await
+    }
+    async fn patch_stores_carts(
+        &self,
+        store_id: &str,
+        cart_id: &str,
+        body: &crate::types::ECommerceCartData,
+    ) -> Result<crate::types::Carts> {
+        Ecommerce::patch_stores_carts(self, store_id, cart_id, body).await
+    }
+    async fn get_stores_carts_line(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+        cart_id: &str,
+    ) -> Result<crate::types::CartLines> {
+        Ecommerce::get_stores_carts_line(
+            self,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            store_id,
+            cart_id,
+        )
+        .await
+    }
+    async fn post_stores_carts_line(
+        &self,
+        store_id: &str,
+        cart_id: &str,
+        body: &crate::types::ECommerceCartLineItemData,
+    ) -> Result<crate::types::ECommerceCartLineItem> {
+        Ecommerce::post_stores_carts_line(self, store_id, cart_id, body).await
+    }
+    async fn get_stores_carts_line_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        cart_id: &str,
+        line_id: &str,
+    ) -> Result<crate::types::ECommerceCartLineItem> {
+        Ecommerce::get_stores_carts_line_ecommerce(
+            self,
+            fields,
+            exclude_fields,
+            store_id,
+            cart_id,
+            line_id,
+        )
+        .await
+    }
+    async fn delete_stores_carts_lines(
+        &self,
+        store_id: &str,
+        cart_id: &str,
+        line_id: &str,
+    ) -> Result<()> {
+        Ecommerce::delete_stores_carts_lines(self, store_id, cart_id, line_id).await
+    }
+    async fn delete_stores_carts_lines_if_exists(
+        &self,
+        store_id: &str,
+        cart_id: &str,
+        line_id: &str,
+    ) -> Result<bool> {
+        Ecommerce::delete_stores_carts_lines_if_exists(self, store_id, cart_id, line_id).await
+    }
+    async fn patch_stores_carts_lines(
+        &self,
+        store_id: &str,
+        cart_id: &str,
+        line_id: &str,
+        body: &crate::types::ECommerceCartLineItemDataType,
+    ) -> Result<crate::types::ECommerceCartLineItem> {
+        Ecommerce::patch_stores_carts_lines(self, store_id, cart_id, line_id, body).await
+    }
+    async fn get_stores_customer(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+        email_address: &str,
+    ) -> Result<crate::types::Customers> {
+        Ecommerce::get_stores_customer(
+            self,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            store_id,
+            email_address,
+        )
+        .await
+    }
+    async fn post_stores_customer(
+        &self,
+        store_id: &str,
+        body: &crate::types::ECommerceCustomerData,
+    ) -> Result<crate::types::Customer> {
+        Ecommerce::post_stores_customer(self, store_id, body).await
+    }
+    async fn get_stores_customer_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        customer_id: &str,
+    ) -> Result<crate::types::Customer> {
+        Ecommerce::get_stores_customer_ecommerce(
+            self,
+            fields,
+            exclude_fields,
+            store_id,
+            customer_id,
+        )
+        .await
+    }
+    async fn put_stores_customers(
+        &self,
+        store_id: &str,
+        customer_id: &str,
+        body: &crate::types::ECommerceCustomerDataType,
+    ) -> Result<crate::types::Customer> {
+        Ecommerce::put_stores_customers(self, store_id, customer_id, body).await
+    }
+    async fn delete_stores_customers(&self, store_id: &str, customer_id: &str) -> Result<()> {
+        Ecommerce::delete_stores_customers(self, store_id, customer_id).await
+    }
+    async fn delete_stores_customers_if_exists(
+        &self,
+        store_id: &str,
+        customer_id: &str,
+    ) -> Result<bool> {
+        Ecommerce::delete_stores_customers_if_exists(self, store_id, customer_id).await
+    }
+    async fn patch_stores_customers(
+        &self,
+        store_id: &str,
+        customer_id: &str,
+        body: &crate::types::ECommerceCartCustomer,
+    ) -> Result<crate::types::Customer> {
+        Ecommerce::patch_stores_customers(self, store_id, customer_id, body).await
+    }
+    async fn get_stores_promorule(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+    ) -> Result<crate::types::PromoRulesData> {
+        Ecommerce::get_stores_promorule(self, fields, exclude_fields, count, offset, store_id).await
+    }
+    async fn post_stores_promorule(
+        &self,
+        store_id: &str,
+        body: &crate::types::ECommercePromoRule,
+    ) -> Result<crate::types::PromoRules> {
+        Ecommerce::post_stores_promorule(self, store_id, body).await
+    }
+    async fn get_stores_promorule_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        promo_rule_id: &str,
+    ) -> Result<crate::types::PromoRules> {
+        Ecommerce::get_stores_promorule_ecommerce(
+            self,
+            fields,
+            exclude_fields,
+            store_id,
+            promo_rule_id,
+        )
+        .await
+    }
+    async fn delete_stores_promorules(&self, store_id: &str, promo_rule_id: &str) -> Result<()> {
+        Ecommerce::delete_stores_promorules(self, store_id, promo_rule_id).await
+    }
+    async fn delete_stores_promorules_if_exists(
+        &self,
+        store_id: &str,
+        promo_rule_id: &str,
+    ) -> Result<bool> {
+        Ecommerce::delete_stores_promorules_if_exists(self, store_id, promo_rule_id).await
+    }
+    async fn patch_stores_promorules(
+        &self,
+        store_id: &str,
+        promo_rule_id: &str,
+        body: &crate::types::ECommercePromoRuleData,
+    ) -> Result<crate::types::PromoRules> {
+        Ecommerce::patch_stores_promorules(self, store_id, promo_rule_id, body).await
+    }
+    async fn get_stores_promocode(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        promo_rule_id: &str,
+        store_id: &str,
+    ) -> Result<crate::types::PromoCodesData> {
+        Ecommerce::get_stores_promocode(
+            self,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            promo_rule_id,
+            store_id,
+        )
+        .await
+    }
+    async fn post_stores_promocode(
+        &self,
+        store_id: &str,
+        promo_rule_id: &str,
+        body: &crate::types::ECommercePromoCode,
+    ) -> Result<crate::types::PromoCodes> {
+        Ecommerce::post_stores_promocode(self, store_id, promo_rule_id, body).await
+    }
+    async fn get_stores_promocode_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        promo_rule_id: &str,
+        promo_code_id: &str,
+    ) -> Result<crate::types::PromoCodes> {
+        Ecommerce::get_stores_promocode_ecommerce(
+            self,
+            fields,
+            exclude_fields,
+            store_id,
+            promo_rule_id,
+            promo_code_id,
+        )
+        .await
+    }
+    async fn delete_stores_promocodes(
+        &self,
+        store_id: &str,
+        promo_rule_id: &str,
+        promo_code_id: &str,
+    ) -> Result<()> {
+        Ecommerce::delete_stores_promocodes(self, store_id, promo_rule_id, promo_code_id).await
+    }
+    async fn delete_stores_promocodes_if_exists(
+        &self,
+        store_id: &str,
+        promo_rule_id: &str,
+        promo_code_id: &str,
+    ) -> Result<bool> {
+        Ecommerce::delete_stores_promocodes_if_exists(self, store_id, promo_rule_id, promo_code_id)
+            .await
+    }
+    async fn patch_stores_promocodes(
+        &self,
+        store_id: &str,
+        promo_rule_id: &str,
+        promo_code_id: &str,
+        body: &crate::types::ECommercePromoCodeData,
+    ) -> Result<crate::types::PromoCodes> {
+        Ecommerce::patch_stores_promocodes(self, store_id, promo_rule_id, promo_code_id, body).await
+    }
+    async fn get_stores_order(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+        customer_id: &str,
+        has_outreach: bool,
+        campaign_id: &str,
+        outreach_id: &str,
+    ) -> Result<crate::types::OrdersDataType> {
+        Ecommerce::get_stores_order(
+            self,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            store_id,
+            customer_id,
+            has_outreach,
+            campaign_id,
+            outreach_id,
+        )
+        .await
+    }
+    async fn post_stores_order(
+        &self,
+        store_id: &str,
+        body: &crate::types::ECommerceOrder,
+    ) -> Result<crate::types::Orders> {
+        Ecommerce::post_stores_order(self, store_id, body).await
+    }
+    async fn get_stores_order_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        order_id: &str,
+    ) -> Result<crate::types::Orders> {
+        Ecommerce::get_stores_order_ecommerce(self, fields, exclude_fields, store_id, order_id)
+            .await
+    }
+    async fn delete_stores_orders(&self, store_id: &str, order_id: &str) -> Result<()> {
+        Ecommerce::delete_stores_orders(self, store_id, order_id).await
+    }
+    async fn delete_stores_orders_if_exists(&self, store_id: &str, order_id: &str) -> Result<bool> {
+        Ecommerce::delete_stores_orders_if_exists(self, store_id, order_id).await
+    }
+    async fn patch_stores_orders(
+        &self,
+        store_id: &str,
+        order_id: &str,
+        body: &crate::types::ECommerceOrderData,
+    ) -> Result<crate::types::Orders> {
+        Ecommerce::patch_stores_orders(self, store_id, order_id, body).await
+    }
+    async fn get_stores_orders_line(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+        order_id: &str,
+    ) -> Result<crate::types::OrderLines> {
+        Ecommerce::get_stores_orders_line(
+            self,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            store_id,
+            order_id,
+        )
+        .await
+    }
+    async fn post_stores_orders_line(
+        &self,
+        store_id: &str,
+        order_id: &str,
+        body: &crate::types::ECommerceOrderLineItem,
+    ) -> Result<crate::types::Lines> {
+        Ecommerce::post_stores_orders_line(self, store_id, order_id, body).await
+    }
+    async fn get_stores_orders_line_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        order_id: &str,
+        line_id: &str,
+    ) -> Result<crate::types::Lines> {
+        Ecommerce::get_stores_orders_line_ecommerce(
+            self,
+            fields,
+            exclude_fields,
+            store_id,
+            order_id,
+            line_id,
+        )
+        .await
+    }
+    async fn delete_stores_orders_lines(
+        &self,
+        store_id: &str,
+        order_id: &str,
+        line_id: &str,
+    ) -> Result<()> {
+        Ecommerce::delete_stores_orders_lines(self, store_id, order_id, line_id).await
+    }
+    async fn delete_stores_orders_lines_if_exists(
+        &self,
+        store_id: &str,
+        order_id: &str,
+        line_id: &str,
+    ) -> Result<bool> {
+        Ecommerce::delete_stores_orders_lines_if_exists(self, store_id, order_id, line_id).await
+    }
+    async fn patch_stores_orders_lines(
+        &self,
+        store_id: &str,
+        order_id: &str,
+        line_id: &str,
+        body: &crate::types::ECommerceOrderLineItemData,
+    ) -> Result<crate::types::Lines> {
+        Ecommerce::patch_stores_orders_lines(self, store_id, order_id, line_id, body).await
+    }
+    async fn get_stores_product(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+    ) -> Result<crate::types::ProductsData> {
+        Ecommerce::get_stores_product(self, fields, exclude_fields, count, offset, store_id).await
+    }
+    async fn post_stores_product(
+        &self,
+        store_id: &str,
+        body: &crate::types::ECommerceProductData,
+    ) -> Result<crate::types::ECommerceProduct> {
+        Ecommerce::post_stores_product(self, store_id, body).await
+    }
+    async fn get_stores_product_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        product_id: &str,
+    ) -> Result<crate::types::ECommerceProduct> {
+        Ecommerce::get_stores_product_ecommerce(self, fields, exclude_fields, store_id, product_id)
+            .await
+    }
+    async fn delete_stores_products(&self, store_id: &str, product_id: &str) -> Result<()> {
+        Ecommerce::delete_stores_products(self, store_id, product_id).await
+    }
+    async fn delete_stores_products_if_exists(
+        &self,
+        store_id: &str,
+        product_id: &str,
+    ) -> Result<bool> {
+        Ecommerce::delete_stores_products_if_exists(self, store_id, product_id).await
+    }
+    async fn patch_stores_products(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        body: &crate::types::ECommerceProductDataType,
+    ) -> Result<crate::types::ECommerceProduct> {
+        Ecommerce::patch_stores_products(self, store_id, product_id, body).await
+    }
+    async fn get_stores_products_variant(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+        product_id: &str,
+    ) -> Result<crate::types::EcommerceProductVariants> {
+        Ecommerce::get_stores_products_variant(
+            self,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            store_id,
+            product_id,
+        )
+        .await
+    }
+    async fn post_stores_products_variant(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        body: &crate::types::ECommerceProductVariant,
+    ) -> Result<crate::types::Variants> {
+        Ecommerce::post_stores_products_variant(self, store_id, product_id, body).await
+    }
+    async fn get_stores_products_variant_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        product_id: &str,
+        variant_id: &str,
+    ) -> Result<crate::types::Variants> {
+        Ecommerce::get_stores_products_variant_ecommerce(
+            self,
+            fields,
+            exclude_fields,
+            store_id,
+            product_id,
+            variant_id,
+        )
+        .await
+    }
+    async fn put_stores_products_variants(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        variant_id: &str,
+        body: &crate::types::ECommerceProductVariant,
+    ) -> Result<crate::types::Variants> {
+        Ecommerce::put_stores_products_variants(self, store_id, product_id, variant_id, body).await
+    }
+    async fn delete_stores_products_variants(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        variant_id: &str,
+    ) -> Result<()> {
+        Ecommerce::delete_stores_products_variants(self, store_id, product_id, variant_id).await
+    }
+    async fn delete_stores_products_variants_if_exists(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        variant_id: &str,
+    ) -> Result<bool> {
+        Ecommerce::delete_stores_products_variants_if_exists(self, store_id, product_id, variant_id)
+            .await
+    }
+    async fn patch_stores_products_variants(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        variant_id: &str,
+        body: &crate::types::ECommerceProductVariantData,
+    ) -> Result<crate::types::Variants> {
+        Ecommerce::patch_stores_products_variants(self, store_id, product_id, variant_id, body)
+            .await
+    }
+    async fn get_stores_products_image(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+        product_id: &str,
+    ) -> Result<crate::types::EcommerceProductImages> {
+        Ecommerce::get_stores_products_image(
+            self,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            store_id,
+            product_id,
+        )
+        .await
+    }
+    async fn post_stores_products_image(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        body: &crate::types::ECommerceProductImage,
+    ) -> Result<crate::types::Images> {
+        Ecommerce::post_stores_products_image(self, store_id, product_id, body).await
+    }
+    async fn get_stores_products_image_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<crate::types::Images> {
+        Ecommerce::get_stores_products_image_ecommerce(
+            self,
+            fields,
+            exclude_fields,
+            store_id,
+            product_id,
+            image_id,
+        )
+        .await
+    }
+    async fn delete_stores_products_images(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<()> {
+        Ecommerce::delete_stores_products_images(self, store_id, product_id, image_id).await
+    }
+    async fn delete_stores_products_images_if_exists(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<bool> {
+        Ecommerce::delete_stores_products_images_if_exists(self, store_id, product_id, image_id)
+            .await
+    }
+    async fn patch_stores_products_images(
+        &self,
+        store_id: &str,
+        product_id: &str,
+        image_id: &str,
+        body: &crate::types::ECommerceProductImageData,
+    ) -> Result<crate::types::Images> {
+        Ecommerce::patch_stores_products_images(self, store_id, product_id, image_id, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct FacebookAds {
     pub client: Client,
@@ -12,6 +10,14 @@ impl FacebookAds {
         FacebookAds { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        FacebookAds {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * List facebook ads.
      *
@@ -37,29 +43,31 @@ impl FacebookAds {
         sort_field: crate::types::GetAllFacebookAdsSortField,
         sort_dir: crate::types::SortDir,
     ) -> Result<crate::types::GetAllFacebookAdsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !sort_dir.to_string().is_empty() {
-            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+            query_args.push(("sort_dir", sort_dir.to_string()));
         }
         if !sort_field.to_string().is_empty() {
-            query_args.push(("sort_field".to_string(), sort_field.to_string()));
+            query_args.push(("sort_field", sort_field.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/facebook-ads?{}", query_);
+        let url = crate::Client::url_with_query("/facebook-ads", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/facebook-ads", &[]))
     }
 
     /**
@@ -81,20 +89,80 @@ impl FacebookAds {
         outreach_id: &str,
         exclude_fields: &[String],
     ) -> Result<crate::types::FacebookAdsAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/facebook-ads/{}?{}",
-            crate::progenitor_support::encode_path(&outreach_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/facebook-ads/{}", self.client.encode_path(outreach_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/facebook-ads/{outreach_id}",
+                &[("outreach_id", outreach_id.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`FacebookAds`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn FacebookAdsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait FacebookAdsApi: Send + Sync {
+    async fn get_all(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        sort_field: crate::types::GetAllFacebookAdsSortField,
+        sort_dir: crate::types::SortDir,
+    ) -> Result<crate::types::GetAllFacebookAdsResponse>;
+    async fn get(
+        &self,
+        fields: &[String],
+        outreach_id: &str,
+        exclude_fields: &[String],
+    ) -> Result<crate::types::FacebookAdsAllOf>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl FacebookAdsApi for FacebookAds {
+    async fn get_all(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        sort_field: crate::types::GetAllFacebookAdsSortField,
+        sort_dir: crate::types::SortDir,
+    ) -> Result<crate::types::GetAllFacebookAdsResponse> {
+        FacebookAds::get_all(
+            self,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            sort_field,
+            sort_dir,
+        )
+        .await
+    }
+    async fn get(
+        &self,
+        fields: &[String],
+        outreach_id: &str,
+        exclude_fields: &[String],
+    ) -> Result<crate::types::FacebookAdsAllOf> {
+        FacebookAds::get(self, fields, outreach_id, exclude_fields).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct FileManager {
     pub client: Client,
@@ -12,6 +10,14 @@ impl FileManager {
         FileManager { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        FileManager {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * List stored files.
      *
@@ -45,44 +51,43 @@ impl FileManager {
         sort_field: crate::types::GetFileManagerFilesSortField,
         sort_dir: crate::types::SortDir,
     ) -> Result<crate::types::FileManager> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !before_created_at.is_empty() {
-            query_args.push((
-                "before_created_at".to_string(),
-                before_created_at.to_string(),
-            ));
+            query_args.push(("before_created_at", before_created_at.to_string()));
         }
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !created_by.is_empty() {
-            query_args.push(("created_by".to_string(), created_by.to_string()));
+            query_args.push(("created_by", created_by.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !since_created_at.is_empty() {
-            query_args.push(("since_created_at".to_string(), since_created_at.to_string()));
+            query_args.push(("since_created_at", since_created_at.to_string()));
         }
         if !sort_dir.to_string().is_empty() {
-            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+            query_args.push(("sort_dir", sort_dir.to_string()));
         }
         if !sort_field.to_string().is_empty() {
-            query_args.push(("sort_field".to_string(), sort_field.to_string()));
+            query_args.push(("sort_field", sort_field.to_string()));
         }
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/file-manager/files?{}", query_);
+        let url = crate::Client::url_with_query("/file-manager/files", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/file-manager/files", &[]))
     }
 
     /**
@@ -95,8 +100,9 @@ impl FileManager {
     pub async fn post(&self, body: &crate::types::GalleryFile) -> Result<crate::types::Files> {
         let url = "/file-manager/files".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/file-manager/files", &[]))
     }
 
     /**
@@ -118,21 +124,25 @@ impl FileManager {
         exclude_fields: &[String],
         file_id: &str,
     ) -> Result<crate::types::Files> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/file-manager/files/{}?{}",
-            crate::progenitor_support::encode_path(&file_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/file-manager/files/{}", self.client.encode_path(file_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/file-manager/files/{file_id}",
+                &[("file_id", file_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -147,12 +157,37 @@ impl FileManager {
      * * `file_id: &str` -- The unique id for the File Manager file.
      */
     pub async fn delete_files(&self, file_id: &str) -> Result<()> {
-        let url = format!(
-            "/file-manager/files/{}",
-            crate::progenitor_support::encode_path(&file_id.to_string()),
-        );
+        let url = format!("/file-manager/files/{}", self.client.encode_path(file_id),);
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/file-manager/files/{file_id}",
+                &[("file_id", file_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete file.
+     *
+     * This function performs a `DELETE` to the `/file-manager/files/{file_id}` endpoint.
+     *
+     * As opposed to `delete_files`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_files_if_exists(&self, file_id: &str) -> Result<bool> {
+        let url = format!("/file-manager/files/{}", self.client.encode_path(file_id),);
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/file-manager/files/{file_id}",
+                    &[("file_id", file_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -171,14 +206,45 @@ impl FileManager {
         file_id: &str,
         body: &crate::types::GalleryFileData,
     ) -> Result<crate::types::Files> {
-        let url = format!(
-            "/file-manager/files/{}",
-            crate::progenitor_support::encode_path(&file_id.to_string()),
-        );
+        let url = format!("/file-manager/files/{}", self.client.encode_path(file_id),);
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/file-manager/files/{file_id}",
+                    &[("file_id", file_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update file.
+     *
+     * This function performs a `PATCH` to the `/file-manager/files/{file_id}` endpoint.
+     *
+     * As opposed to `patch_files`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_files_if_changed<C: serde::Serialize>(
+        &self,
+        file_id: &str,
+        current: &C,
+        body: &crate::types::GalleryFileData,
+    ) -> Result<Option<crate::types::Files>> {
+        let url = format!("/file-manager/files/{}", self.client.encode_path(file_id),);
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/file-manager/files/{file_id}",
+                    &[("file_id", file_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -208,35 +274,34 @@ impl FileManager {
         before_created_at: &str,
         since_created_at: &str,
     ) -> Result<crate::types::FileManagerFolders> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !before_created_at.is_empty() {
-            query_args.push((
-                "before_created_at".to_string(),
-                before_created_at.to_string(),
-            ));
+            query_args.push(("before_created_at", before_created_at.to_string()));
         }
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !created_by.is_empty() {
-            query_args.push(("created_by".to_string(), created_by.to_string()));
+            query_args.push(("created_by", created_by.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !since_created_at.is_empty() {
-            query_args.push(("since_created_at".to_string(), since_created_at.to_string()));
+            query_args.push(("since_created_at", since_created_at.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/file-manager/folders?{}", query_);
+        let url = crate::Client::url_with_query("/file-manager/folders", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/file-manager/folders", &[]))
     }
 
     /**
@@ -252,8 +317,9 @@ impl FileManager {
     ) -> Result<crate::types::FileManagerFoldersGalleryFolder> {
         let url = "/file-manager/folders".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/file-manager/folders", &[]))
     }
 
     /**
@@ -275,21 +341,28 @@ impl FileManager {
         exclude_fields: &[String],
         folder_id: &str,
     ) -> Result<crate::types::FileManagerFoldersGalleryFolder> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/file-manager/folders/{}?{}",
-            crate::progenitor_support::encode_path(&folder_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/file-manager/folders/{}",
+                self.client.encode_path(folder_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/file-manager/folders/{folder_id}",
+                &[("folder_id", folder_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -306,10 +379,41 @@ impl FileManager {
     pub async fn delete_folders(&self, folder_id: &str) -> Result<()> {
         let url = format!(
             "/file-manager/folders/{}",
-            crate::progenitor_support::encode_path(&folder_id.to_string()),
+            self.client.encode_path(folder_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/file-manager/folders/{folder_id}",
+                &[("folder_id", folder_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete folder.
+     *
+     * This function performs a `DELETE` to the `/file-manager/folders/{folder_id}` endpoint.
+     *
+     * As opposed to `delete_folders`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_folders_if_exists(&self, folder_id: &str) -> Result<bool> {
+        let url = format!(
+            "/file-manager/folders/{}",
+            self.client.encode_path(folder_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/file-manager/folders/{folder_id}",
+                    &[("folder_id", folder_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -330,11 +434,216 @@ impl FileManager {
     ) -> Result<crate::types::FileManagerFoldersGalleryFolder> {
         let url = format!(
             "/file-manager/folders/{}",
-            crate::progenitor_support::encode_path(&folder_id.to_string()),
+            self.client.encode_path(folder_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/file-manager/folders/{folder_id}",
+                    &[("folder_id", folder_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update folder.
+     *
+     * This function performs a `PATCH` to the `/file-manager/folders/{folder_id}` endpoint.
+     *
+     * As opposed to `patch_folders`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_folders_if_changed<C: serde::Serialize>(
+        &self,
+        folder_id: &str,
+        current: &C,
+        body: &crate::types::GalleryFolder,
+    ) -> Result<Option<crate::types::FileManagerFoldersGalleryFolder>> {
+        let url = format!(
+            "/file-manager/folders/{}",
+            self.client.encode_path(folder_id),
+        );
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/file-manager/folders/{folder_id}",
+                    &[("folder_id", folder_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`FileManager`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn FileManagerApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait FileManagerApi: Send + Sync {
+    async fn get_file(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        type_: &str,
+        created_by: &str,
+        before_created_at: &str,
+        since_created_at: &str,
+        sort_field: crate::types::GetFileManagerFilesSortField,
+        sort_dir: crate::types::SortDir,
+    ) -> Result<crate::types::FileManager>;
+    async fn post(&self, body: &crate::types::GalleryFile) -> Result<crate::types::Files>;
+    async fn get_file_file_manager(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        file_id: &str,
+    ) -> Result<crate::types::Files>;
+    async fn delete_files(&self, file_id: &str) -> Result<()>;
+    async fn delete_files_if_exists(&self, file_id: &str) -> Result<bool>;
+    async fn patch_files(
+        &self,
+        file_id: &str,
+        body: &crate::types::GalleryFileData,
+    ) -> Result<crate::types::Files>;
+    async fn get_folder(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        created_by: &str,
+        before_created_at: &str,
+        since_created_at: &str,
+    ) -> Result<crate::types::FileManagerFolders>;
+    async fn post_folder(
+        &self,
+        body: &crate::types::GalleryFolder,
+    ) -> Result<crate::types::FileManagerFoldersGalleryFolder>;
+    async fn get_folder_file_manager(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        folder_id: &str,
+    ) -> Result<crate::types::FileManagerFoldersGalleryFolder>;
+    async fn delete_folders(&self, folder_id: &str) -> Result<()>;
+    async fn delete_folders_if_exists(&self, folder_id: &str) -> Result<bool>;
+    async fn patch_folders(
+        &self,
+        folder_id: &str,
+        body: &crate::types::GalleryFolder,
+    ) -> Result<crate::types::FileManagerFoldersGalleryFolder>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl FileManagerApi for FileManager {
+    async fn get_file(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        type_: &str,
+        created_by: &str,
+        before_created_at: &str,
+        since_created_at: &str,
+        sort_field: crate::types::GetFileManagerFilesSortField,
+        sort_dir: crate::types::SortDir,
+    ) -> Result<crate::types::FileManager> {
+        FileManager::get_file(
+            self,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            type_,
+            created_by,
+            before_created_at,
+            since_created_at,
+            sort_field,
+            sort_dir,
+        )
+        .await
+    }
+    async fn post(&self, body: &crate::types::GalleryFile) -> Result<crate::types::Files> {
+        FileManager::post(self, body).await
+    }
+    async fn get_file_file_manager(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        file_id: &str,
+    ) -> Result<crate::types::Files> {
+        FileManager::get_file_file_manager(self, fields, exclude_fields, file_id).await
+    }
+    async fn delete_files(&self, file_id: &str) -> Result<()> {
+        FileManager::delete_files(self, file_id).await
+    }
+    async fn delete_files_if_exists(&self, file_id: &str) -> Result<bool> {
+        FileManager::delete_files_if_exists(self, file_id).await
+    }
+    async fn patch_files(
+        &self,
+        file_id: &str,
+        body: &crate::types::GalleryFileData,
+    ) -> Result<crate::types::Files> {
+        FileManager::patch_files(self, file_id, body).await
+    }
+    async fn get_folder(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        created_by: &str,
+        before_created_at: &str,
+        since_created_at: &str,
+    ) -> Result<crate::types::FileManagerFolders> {
+        FileManager::get_folder(
+            self,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            created_by,
+            before_created_at,
+            since_created_at,
+        )
+        .await
+    }
+    async fn post_folder(
+        &self,
+        body: &crate::types::GalleryFolder,
+    ) -> Result<crate::types::FileManagerFoldersGalleryFolder> {
+        FileManager::post_folder(self, body).await
+    }
+    async fn get_folder_file_manager(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        folder_id: &str,
+    ) -> Result<crate::types::FileManagerFoldersGalleryFolder> {
+        FileManager::get_folder_file_manager(self, fields, exclude_fields, folder_id).await
+    }
+    async fn delete_folders(&self, folder_id: &str) -> Result<()> {
+        FileManager::delete_folders(self, folder_id).await
+    }
+    async fn delete_folders_if_exists(&self, folder_id: &str) -> Result<bool> {
+        FileManager::delete_folders_if_exists(self, folder_id).await
+    }
+    async fn patch_folders(
+        &self,
+        folder_id: &str,
+        body: &crate::types::GalleryFolder,
+    ) -> Result<crate::types::FileManagerFoldersGalleryFolder> {
+        FileManager::patch_folders(self, folder_id, body).await
     }
 }
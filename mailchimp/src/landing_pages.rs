@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct LandingPages {
     pub client: Client,
@@ -12,6 +10,14 @@ impl LandingPages {
         LandingPages { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        LandingPages {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * List landing pages.
      *
@@ -35,26 +41,28 @@ impl LandingPages {
         exclude_fields: &[String],
         count: i64,
     ) -> Result<crate::types::GetAllLandingPagesResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if !sort_dir.to_string().is_empty() {
-            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+            query_args.push(("sort_dir", sort_dir.to_string()));
         }
         if !sort_field.to_string().is_empty() {
-            query_args.push(("sort_field".to_string(), sort_field.to_string()));
+            query_args.push(("sort_field", sort_field.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/landing-pages?{}", query_);
+        let url = crate::Client::url_with_query("/landing-pages", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/landing-pages", &[]))
     }
 
     /**
@@ -73,16 +81,16 @@ impl LandingPages {
         use_default_list: bool,
         body: &crate::types::LandingPageData,
     ) -> Result<crate::types::LandingPage> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if use_default_list {
-            query_args.push(("use_default_list".to_string(), use_default_list.to_string()));
+            query_args.push(("use_default_list", use_default_list.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/landing-pages?{}", query_);
+        let url = crate::Client::url_with_query("/landing-pages", &query_args);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/landing-pages", &[]))
     }
 
     /**
@@ -104,21 +112,25 @@ impl LandingPages {
         exclude_fields: &[String],
         page_id: &str,
     ) -> Result<crate::types::LandingPage> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/landing-pages/{}?{}",
-            crate::progenitor_support::encode_path(&page_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/landing-pages/{}", self.client.encode_path(page_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/landing-pages/{page_id}",
+                &[("page_id", page_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -133,12 +145,37 @@ impl LandingPages {
      * * `page_id: &str` -- The unique id for the page.
      */
     pub async fn delete(&self, page_id: &str) -> Result<()> {
-        let url = format!(
-            "/landing-pages/{}",
-            crate::progenitor_support::encode_path(&page_id.to_string()),
-        );
+        let url = format!("/landing-pages/{}", self.client.encode_path(page_id),);
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/landing-pages/{page_id}",
+                &[("page_id", page_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete landing page.
+     *
+     * This function performs a `DELETE` to the `/landing-pages/{page_id}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, page_id: &str) -> Result<bool> {
+        let url = format!("/landing-pages/{}", self.client.encode_path(page_id),);
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/landing-pages/{page_id}",
+                    &[("page_id", page_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -157,14 +194,45 @@ impl LandingPages {
         page_id: &str,
         body: &crate::types::LandingPageDataType,
     ) -> Result<crate::types::LandingPage> {
-        let url = format!(
-            "/landing-pages/{}",
-            crate::progenitor_support::encode_path(&page_id.to_string()),
-        );
+        let url = format!("/landing-pages/{}", self.client.encode_path(page_id),);
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/landing-pages/{page_id}",
+                    &[("page_id", page_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update landing page.
+     *
+     * This function performs a `PATCH` to the `/landing-pages/{page_id}` endpoint.
+     *
+     * As opposed to `patch`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_if_changed<C: serde::Serialize>(
+        &self,
+        page_id: &str,
+        current: &C,
+        body: &crate::types::LandingPageDataType,
+    ) -> Result<Option<crate::types::LandingPage>> {
+        let url = format!("/landing-pages/{}", self.client.encode_path(page_id),);
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/landing-pages/{page_id}",
+                    &[("page_id", page_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -181,10 +249,16 @@ impl LandingPages {
     pub async fn post_actions_publish(&self, page_id: &str) -> Result<crate::types::LandingPage> {
         let url = format!(
             "/landing-pages/{}/actions/publish",
-            crate::progenitor_support::encode_path(&page_id.to_string()),
+            self.client.encode_path(page_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/landing-pages/{page_id}/actions/publish",
+                &[("page_id", page_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -201,10 +275,16 @@ impl LandingPages {
     pub async fn post_actions_unpublish(&self, page_id: &str) -> Result<()> {
         let url = format!(
             "/landing-pages/{}/actions/unpublish",
-            crate::progenitor_support::encode_path(&page_id.to_string()),
+            self.client.encode_path(page_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/landing-pages/{page_id}/actions/unpublish",
+                &[("page_id", page_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -226,20 +306,126 @@ impl LandingPages {
         exclude_fields: &[String],
         page_id: &str,
     ) -> Result<crate::types::LandingPageContent> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/landing-pages/{}/content?{}",
-            crate::progenitor_support::encode_path(&page_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/landing-pages/{}/content",
+                self.client.encode_path(page_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/landing-pages/{page_id}/content",
+                &[("page_id", page_id.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`LandingPages`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn LandingPagesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait LandingPagesApi: Send + Sync {
+    async fn get_all(
+        &self,
+        sort_dir: crate::types::SortDir,
+        sort_field: crate::types::GetAllLandingPagesSortField,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+    ) -> Result<crate::types::GetAllLandingPagesResponse>;
+    async fn post_all(
+        &self,
+        use_default_list: bool,
+        body: &crate::types::LandingPageData,
+    ) -> Result<crate::types::LandingPage>;
+    async fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        page_id: &str,
+    ) -> Result<crate::types::LandingPage>;
+    async fn delete(&self, page_id: &str) -> Result<()>;
+    async fn delete_if_exists(&self, page_id: &str) -> Result<bool>;
+    async fn patch(
+        &self,
+        page_id: &str,
+        body: &crate::types::LandingPageDataType,
+    ) -> Result<crate::types::LandingPage>;
+    async fn post_actions_publish(&self, page_id: &str) -> Result<crate::types::LandingPage>;
+    async fn post_actions_unpublish(&self, page_id: &str) -> Result<()>;
+    async fn get_content(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        page_id: &str,
+    ) -> Result<crate::types::LandingPageContent>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl LandingPagesApi for LandingPages {
+    async fn get_all(
+        &self,
+        sort_dir: crate::types::SortDir,
+        sort_field: crate::types::GetAllLandingPagesSortField,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+    ) -> Result<crate::types::GetAllLandingPagesResponse> {
+        LandingPages::get_all(self, sort_dir, sort_field, fields, exclude_fields, count).await
+    }
+    async fn post_all(
+        &self,
+        use_default_list: bool,
+        body: &crate::types::LandingPageData,
+    ) -> Result<crate::types::LandingPage> {
+        LandingPages::post_all(self, use_default_list, body).await
+    }
+    async fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        page_id: &str,
+    ) -> Result<crate::types::LandingPage> {
+        LandingPages::get(self, fields, exclude_fields, page_id).await
+    }
+    async fn delete(&self, page_id: &str) -> Result<()> {
+        LandingPages::delete(self, page_id).await
+    }
+    async fn delete_if_exists(&self, page_id: &str) -> Result<bool> {
+        LandingPages::delete_if_exists(self, page_id).await
+    }
+    async fn patch(
+        &self,
+        page_id: &str,
+        body: &crate::types::LandingPageDataType,
+    ) -> Result<crate::types::LandingPage> {
+        LandingPages::patch(self, page_id, body).await
+    }
+    async fn post_actions_publish(&self, page_id: &str) -> Result<crate::types::LandingPage> {
+        LandingPages::post_actions_publish(self, page_id).await
+    }
+    async fn post_actions_unpublish(&self, page_id: &str) -> Result<()> {
+        LandingPages::post_actions_unpublish(self, page_id).await
+    }
+    async fn get_content(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        page_id: &str,
+    ) -> Result<crate::types::LandingPageContent> {
+        LandingPages::get_content(self, fields, exclude_fields, page_id).await
     }
 }
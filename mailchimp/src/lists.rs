@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Lists {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Lists {
         Lists { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Lists {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get lists info.
      *
@@ -51,68 +57,58 @@ impl Lists {
         has_ecommerce_store: bool,
         include_total_contacts: bool,
     ) -> Result<crate::types::SubscriberLists> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !before_campaign_last_sent.is_empty() {
             query_args.push((
-                "before_campaign_last_sent".to_string(),
+                "before_campaign_last_sent",
                 before_campaign_last_sent.to_string(),
             ));
         }
         if !before_date_created.is_empty() {
-            query_args.push((
-                "before_date_created".to_string(),
-                before_date_created.to_string(),
-            ));
+            query_args.push(("before_date_created", before_date_created.to_string()));
         }
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !email.is_empty() {
-            query_args.push(("email".to_string(), email.to_string()));
+            query_args.push(("email", email.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if has_ecommerce_store {
-            query_args.push((
-                "has_ecommerce_store".to_string(),
-                has_ecommerce_store.to_string(),
-            ));
+            query_args.push(("has_ecommerce_store", has_ecommerce_store.to_string()));
         }
         if include_total_contacts {
-            query_args.push((
-                "include_total_contacts".to_string(),
-                include_total_contacts.to_string(),
-            ));
+            query_args.push(("include_total_contacts", include_total_contacts.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !since_campaign_last_sent.is_empty() {
             query_args.push((
-                "since_campaign_last_sent".to_string(),
+                "since_campaign_last_sent",
                 since_campaign_last_sent.to_string(),
             ));
         }
         if !since_date_created.is_empty() {
-            query_args.push((
-                "since_date_created".to_string(),
-                since_date_created.to_string(),
-            ));
+            query_args.push(("since_date_created", since_date_created.to_string()));
         }
         if !sort_dir.to_string().is_empty() {
-            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+            query_args.push(("sort_dir", sort_dir.to_string()));
         }
         if !sort_field.to_string().is_empty() {
-            query_args.push(("sort_field".to_string(), sort_field.to_string()));
+            query_args.push(("sort_field", sort_field.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/lists?{}", query_);
+        let url = crate::Client::url_with_query("/lists", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/lists", &[]))
     }
 
     /**
@@ -125,8 +121,9 @@ impl Lists {
     pub async fn post(&self, body: &crate::types::SubscriberList) -> Result<crate::types::Lists> {
         let url = "/lists".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/lists", &[]))
     }
 
     /**
@@ -150,27 +147,28 @@ impl Lists {
         list_id: &str,
         include_total_contacts: bool,
     ) -> Result<crate::types::Lists> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if include_total_contacts {
-            query_args.push((
-                "include_total_contacts".to_string(),
-                include_total_contacts.to_string(),
-            ));
+            query_args.push(("include_total_contacts", include_total_contacts.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}?{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/lists/{}", self.client.encode_path(list_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/lists/{list_id}",
+                &[("list_id", list_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -193,29 +191,28 @@ impl Lists {
         skip_duplicate_check: bool,
         body: &crate::types::MembersSubscribeUnsubscribeFromAListInBatch,
     ) -> Result<crate::types::BatchUpdateListMembers> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if skip_duplicate_check {
-            query_args.push((
-                "skip_duplicate_check".to_string(),
-                skip_duplicate_check.to_string(),
-            ));
+            query_args.push(("skip_duplicate_check", skip_duplicate_check.to_string()));
         }
         if skip_merge_validation {
-            query_args.push((
-                "skip_merge_validation".to_string(),
-                skip_merge_validation.to_string(),
-            ));
+            query_args.push(("skip_merge_validation", skip_merge_validation.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}?{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/lists/{}", self.client.encode_path(list_id)),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/lists/{list_id}",
+                    &[("list_id", list_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -230,12 +227,37 @@ impl Lists {
      * * `list_id: &str` -- The unique ID for the list.
      */
     pub async fn delete(&self, list_id: &str) -> Result<()> {
-        let url = format!(
-            "/lists/{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-        );
+        let url = format!("/lists/{}", self.client.encode_path(list_id),);
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/lists/{list_id}",
+                &[("list_id", list_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete list.
+     *
+     * This function performs a `DELETE` to the `/lists/{list_id}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, list_id: &str) -> Result<bool> {
+        let url = format!("/lists/{}", self.client.encode_path(list_id),);
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/lists/{list_id}",
+                    &[("list_id", list_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -254,14 +276,45 @@ impl Lists {
         list_id: &str,
         body: &crate::types::SubscriberListData,
     ) -> Result<crate::types::Lists> {
-        let url = format!(
-            "/lists/{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-        );
+        let url = format!("/lists/{}", self.client.encode_path(list_id),);
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/lists/{list_id}",
+                    &[("list_id", list_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update lists.
+     *
+     * This function performs a `PATCH` to the `/lists/{list_id}` endpoint.
+     *
+     * As opposed to `patch`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_if_changed<C: serde::Serialize>(
+        &self,
+        list_id: &str,
+        current: &C,
+        body: &crate::types::SubscriberListData,
+    ) -> Result<Option<crate::types::Lists>> {
+        let url = format!("/lists/{}", self.client.encode_path(list_id),);
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/lists/{list_id}",
+                    &[("list_id", list_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -287,27 +340,31 @@ impl Lists {
         offset: i64,
         list_id: &str,
     ) -> Result<crate::types::AbuseComplaints> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/abuse-reports?{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/lists/{}/abuse-reports", self.client.encode_path(list_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/lists/{list_id}/abuse-reports",
+                &[("list_id", list_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -335,28 +392,38 @@ impl Lists {
         list_id: &str,
         report_id: &str,
     ) -> Result<crate::types::AbuseReports> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/abuse-reports/{}?{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&report_id.to_string()),
-            query_
+            query_args.push(("offset", offset.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/lists/{}/abuse-reports/{}",
+                self.client.encode_path(list_id),
+                self.client.encode_path(report_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/lists/{list_id}/abuse-reports/{report_id}",
+                &[
+                    ("list_id", list_id.to_string()),
+                    ("report_id", report_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -378,21 +445,25 @@ impl Lists {
         exclude_fields: &[String],
         list_id: &str,
     ) -> Result<crate::types::ListActivity> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/activity?{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/lists/{}/activity", self.client.encode_path(list_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/lists/{list_id}/activity",
+                &[("list_id", list_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -414,21 +485,25 @@ impl Lists {
         exclude_fields: &[String],
         list_id: &str,
     ) -> Result<crate::types::EmailClients> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/clients?{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/lists/{}/clients", self.client.encode_path(list_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/lists/{list_id}/clients",
+                &[("list_id", list_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -458,33 +533,37 @@ impl Lists {
         sort_field: crate::types::GetListsGrowthHistorySortField,
         sort_dir: crate::types::SortDir,
     ) -> Result<crate::types::GrowthHistory> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !sort_dir.to_string().is_empty() {
-            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+            query_args.push(("sort_dir", sort_dir.to_string()));
         }
         if !sort_field.to_string().is_empty() {
-            query_args.push(("sort_field".to_string(), sort_field.to_string()));
+            query_args.push(("sort_field", sort_field.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/growth-history?{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/lists/{}/growth-history", self.client.encode_path(list_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/lists/{list_id}/growth-history",
+                &[("list_id", list_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -508,22 +587,32 @@ impl Lists {
         list_id: &str,
         month: &str,
     ) -> Result<crate::types::History> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/growth-history/{}?{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&month.to_string()),
-            query_
+            query_args.push(("fields", fields.join(",")));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/lists/{}/growth-history/{}",
+                self.client.encode_path(list_id),
+                self.client.encode_path(month)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/lists/{list_id}/growth-history/{month}",
+                &[
+                    ("list_id", list_id.to_string()),
+                    ("month", month.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -551,30 +640,37 @@ impl Lists {
         offset: i64,
         type_: &str,
     ) -> Result<crate::types::InterestGroupings> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/interest-categories?{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            query_
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/lists/{}/interest-categories",
+                self.client.encode_path(list_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/lists/{list_id}/interest-categories",
+                &[("list_id", list_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -595,12 +691,19 @@ impl Lists {
     ) -> Result<crate::types::Categories> {
         let url = format!(
             "/lists/{}/interest-categories",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
+            self.client.encode_path(list_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/lists/{list_id}/interest-categories",
+                    &[("list_id", list_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -624,22 +727,32 @@ impl Lists {
         fields: &[String],
         exclude_fields: &[String],
     ) -> Result<crate::types::Categories> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/interest-categories/{}?{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&interest_category_id.to_string()),
-            query_
+            query_args.push(("fields", fields.join(",")));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/lists/{}/interest-categories/{}",
+                self.client.encode_path(list_id),
+                self.client.encode_path(interest_category_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/lists/{list_id}/interest-categories/{interest_category_id}",
+                &[
+                    ("list_id", list_id.to_string()),
+                    ("interest_category_id", interest_category_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -661,11 +774,53 @@ impl Lists {
     ) -> Result<()> {
         let url = format!(
             "/lists/{}/interest-categories/{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&interest_category_id.to_string()),
+            self.client.encode_path(list_id),
+            self.client.encode_path(interest_category_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/lists/{list_id}/interest-categories/{interest_category_id}",
+                &[
+                    ("list_id", list_id.to_string()),
+                    ("interest_category_id", interest_category_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Delete interest category.
+     *
+     * This function performs a `DELETE` to the `/lists/{list_id}/interest-categories/{interest_category_id}` endpoint.
+     *
+     * As opposed to `delete_interest_categories`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_interest_categories_if_exists(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/lists/{}/interest-categories/{}",
+            self.client.encode_path(list_id),
+            self.client.encode_path(interest_category_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/lists/{list_id}/interest-categories/{interest_category_id}",
+                    &[
+                        ("list_id", list_id.to_string()),
+                        ("interest_category_id", interest_category_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -688,13 +843,58 @@ impl Lists {
     ) -> Result<crate::types::Categories> {
         let url = format!(
             "/lists/{}/interest-categories/{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&interest_category_id.to_string()),
+            self.client.encode_path(list_id),
+            self.client.encode_path(interest_category_id),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/lists/{list_id}/interest-categories/{interest_category_id}",
+                    &[
+                        ("list_id", list_id.to_string()),
+                        ("interest_category_id", interest_category_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * Update interest category.
+     *
+     * This function performs a `PATCH` to the `/lists/{list_id}/interest-categories/{interest_category_id}` endpoint.
+     *
+     * As opposed to `patch_interest_categories`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_interest_categories_if_changed<C: serde::Serialize>(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+        current: &C,
+        body: &crate::types::InterestCategory,
+    ) -> Result<Option<crate::types::Categories>> {
+        let url = format!(
+            "/lists/{}/interest-categories/{}",
+            self.client.encode_path(list_id),
+            self.client.encode_path(interest_category_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/lists/{list_id}/interest-categories/{interest_category_id}",
+                    &[
+                        ("list_id", list_id.to_string()),
+                        ("interest_category_id", interest_category_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -722,28 +922,38 @@ impl Lists {
         count: i64,
         offset: i64,
     ) -> Result<crate::types::InterestsData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/interest-categories/{}/interests?{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&interest_category_id.to_string()),
-            query_
+            query_args.push(("offset", offset.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/lists/{}/interest-categories/{}/interests",
+                self.client.encode_path(list_id),
+                self.client.encode_path(interest_category_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/lists/{list_id}/interest-categories/{interest_category_id}/interests",
+                &[
+                    ("list_id", list_id.to_string()),
+                    ("interest_category_id", interest_category_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -766,13 +976,23 @@ impl Lists {
     ) -> Result<crate::types::InterestsInterest> {
         let url = format!(
             "/lists/{}/interest-categories/{}/interests",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&interest_category_id.to_string()),
+            self.client.encode_path(list_id),
+            self.client.encode_path(interest_category_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/lists/{list_id}/interest-categories/{interest_category_id}/interests",
+                    &[
+                        ("list_id", list_id.to_string()),
+                        ("interest_category_id", interest_category_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -798,23 +1018,24 @@ impl Lists {
         fields: &[String],
         exclude_fields: &[String],
     ) -> Result<crate::types::InterestsInterest> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/interest-categories/{}/interests/{}?{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&interest_category_id.to_string()),
-            crate::progenitor_support::encode_path(&interest_id.to_string()),
-            query_
+            query_args.push(("fields", fields.join(",")));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/lists/{}/interest-categories/{}/interests/{}",
+                self.client.encode_path(list_id),
+                self.client.encode_path(interest_category_id),
+                self.client.encode_path(interest_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/lists/{list_id}/interest-categories/{interest_category_id}/interests/{interest_id}", &[("list_id", list_id.to_string()), ("interest_category_id", interest_category_id.to_string()), ("interest_id", interest_id.to_string())]))
     }
 
     /**
@@ -838,12 +1059,35 @@ impl Lists {
     ) -> Result<()> {
         let url = format!(
             "/lists/{}/interest-categories/{}/interests/{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&interest_category_id.to_string()),
-            crate::progenitor_support::encode_path(&interest_id.to_string()),
+            self.client.encode_path(list_id),
+            self.client.encode_path(interest_category_id),
+            self.client.encode_path(interest_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/lists/{list_id}/interest-categories/{interest_category_id}/interests/{interest_id}", &[("list_id", list_id.to_string()), ("interest_category_id", interest_category_id.to_string()), ("interest_id", interest_id.to_string())]))
+    }
+
+    /**
+     * Delete interest in category.
+     *
+     * This function performs a `DELETE` to the `/lists/{list_id}/interest-categories/{interest_category_id}/interests/{interest_id}` endpoint.
+     *
+     * As opposed to `delete_interest_categories_interests`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_interest_categories_interests_if_exists(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+        interest_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/lists/{}/interest-categories/{}/interests/{}",
+            self.client.encode_path(list_id),
+            self.client.encode_path(interest_category_id),
+            self.client.encode_path(interest_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete_if_exists(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/lists/{list_id}/interest-categories/{interest_category_id}/interests/{interest_id}", &[("list_id", list_id.to_string()), ("interest_category_id", interest_category_id.to_string()), ("interest_id", interest_id.to_string())]))
     }
 
     /**
@@ -868,14 +1112,39 @@ impl Lists {
     ) -> Result<crate::types::InterestsInterest> {
         let url = format!(
             "/lists/{}/interest-categories/{}/interests/{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&interest_category_id.to_string()),
-            crate::progenitor_support::encode_path(&interest_id.to_string()),
+            self.client.encode_path(list_id),
+            self.client.encode_path(interest_category_id),
+            self.client.encode_path(interest_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("PATCH", "/lists/{list_id}/interest-categories/{interest_category_id}/interests/{interest_id}", &[("list_id", list_id.to_string()), ("interest_category_id", interest_category_id.to_string()), ("interest_id", interest_id.to_string())]))
+    }
+
+    /**
+     * Update interest in category.
+     *
+     * This function performs a `PATCH` to the `/lists/{list_id}/interest-categories/{interest_category_id}/interests/{interest_id}` endpoint.
+     *
+     * As opposed to `patch_interest_categories_interests`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_interest_categories_interests_if_changed<C: serde::Serialize>(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+        interest_id: &str,
+        current: &C,
+        body: &crate::types::Interest,
+    ) -> Result<Option<crate::types::InterestsInterest>> {
+        let url = format!(
+            "/lists/{}/interest-categories/{}/interests/{}",
+            self.client.encode_path(list_id),
+            self.client.encode_path(interest_category_id),
+            self.client.encode_path(interest_id),
+        );
+
+        self.client.patch_if_changed(&url, current, body).await.with_context(|| crate::utils::pretty_url("PATCH", "/lists/{list_id}/interest-categories/{interest_category_id}/interests/{interest_id}", &[("list_id", list_id.to_string()), ("interest_category_id", interest_category_id.to_string()), ("interest_id", interest_id.to_string())]))
     }
 
     /**
@@ -917,63 +1186,55 @@ impl Lists {
         since_updated_at: &str,
         before_updated_at: &str,
     ) -> Result<crate::types::CollectionOfSegments> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !before_created_at.is_empty() {
-            query_args.push((
-                "before_created_at".to_string(),
-                before_created_at.to_string(),
-            ));
+            query_args.push(("before_created_at", before_created_at.to_string()));
         }
         if !before_updated_at.is_empty() {
-            query_args.push((
-                "before_updated_at".to_string(),
-                before_updated_at.to_string(),
-            ));
+            query_args.push(("before_updated_at", before_updated_at.to_string()));
         }
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if include_cleaned {
-            query_args.push(("include_cleaned".to_string(), include_cleaned.to_string()));
+            query_args.push(("include_cleaned", include_cleaned.to_string()));
         }
         if include_transactional {
-            query_args.push((
-                "include_transactional".to_string(),
-                include_transactional.to_string(),
-            ));
+            query_args.push(("include_transactional", include_transactional.to_string()));
         }
         if include_unsubscribed {
-            query_args.push((
-                "include_unsubscribed".to_string(),
-                include_unsubscribed.to_string(),
-            ));
+            query_args.push(("include_unsubscribed", include_unsubscribed.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !since_created_at.is_empty() {
-            query_args.push(("since_created_at".to_string(), since_created_at.to_string()));
+            query_args.push(("since_created_at", since_created_at.to_string()));
         }
         if !since_updated_at.is_empty() {
-            query_args.push(("since_updated_at".to_string(), since_updated_at.to_string()));
+            query_args.push(("since_updated_at", since_updated_at.to_string()));
         }
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/segments?{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/lists/{}/segments", self.client.encode_path(list_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/lists/{list_id}/segments",
+                &[("list_id", list_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -992,14 +1253,18 @@ impl Lists {
         list_id: &str,
         body: &crate::types::ListData,
     ) -> Result<crate::types::Segments> {
-        let url = format!(
-            "/lists/{}/segments",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-        );
+        let url = format!("/lists/{}/segments", self.client.encode_path(list_id),);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/lists/{list_id}/segments",
+                    &[("list_id", list_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1029,37 +1294,41 @@ impl Lists {
         include_transactional: bool,
         include_unsubscribed: bool,
     ) -> Result<crate::types::Segments> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if include_cleaned {
-            query_args.push(("include_cleaned".to_string(), include_cleaned.to_string()));
+            query_args.push(("include_cleaned", include_cleaned.to_string()));
         }
         if include_transactional {
-            query_args.push((
-                "include_transactional".to_string(),
-                include_transactional.to_string(),
-            ));
+            query_args.push(("include_transactional", include_transactional.to_string()));
         }
         if include_unsubscribed {
-            query_args.push((
-                "include_unsubscribed".to_string(),
-                include_unsubscribed.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/segments/{}?{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&segment_id.to_string()),
-            query_
+            query_args.push(("include_unsubscribed", include_unsubscribed.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/lists/{}/segments/{}",
+                self.client.encode_path(list_id),
+                self.client.encode_path(segment_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/lists/{list_id}/segments/{segment_id}",
+                &[
+                    ("list_id", list_id.to_string()),
+                    ("segment_id", segment_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1082,13 +1351,23 @@ impl Lists {
     ) -> Result<crate::types::BatchAddRemoveListMembersFromStaticSegment> {
         let url = format!(
             "/lists/{}/segments/{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&segment_id.to_string()),
+            self.client.encode_path(list_id),
+            self.client.encode_path(segment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/lists/{list_id}/segments/{segment_id}",
+                    &[
+                        ("list_id", list_id.to_string()),
+                        ("segment_id", segment_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1106,11 +1385,49 @@ impl Lists {
     pub async fn delete_segments(&self, list_id: &str, segment_id: &str) -> Result<()> {
         let url = format!(
             "/lists/{}/segments/{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&segment_id.to_string()),
+            self.client.encode_path(list_id),
+            self.client.encode_path(segment_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/lists/{list_id}/segments/{segment_id}",
+                &[
+                    ("list_id", list_id.to_string()),
+                    ("segment_id", segment_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Delete segment.
+     *
+     * This function performs a `DELETE` to the `/lists/{list_id}/segments/{segment_id}` endpoint.
+     *
+     * As opposed to `delete_segments`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_segments_if_exists(&self, list_id: &str, segment_id: &str) -> Result<bool> {
+        let url = format!(
+            "/lists/{}/segments/{}",
+            self.client.encode_path(list_id),
+            self.client.encode_path(segment_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/lists/{list_id}/segments/{segment_id}",
+                    &[
+                        ("list_id", list_id.to_string()),
+                        ("segment_id", segment_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1133,13 +1450,58 @@ impl Lists {
     ) -> Result<crate::types::Segments> {
         let url = format!(
             "/lists/{}/segments/{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&segment_id.to_string()),
+            self.client.encode_path(list_id),
+            self.client.encode_path(segment_id),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/lists/{list_id}/segments/{segment_id}",
+                    &[
+                        ("list_id", list_id.to_string()),
+                        ("segment_id", segment_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * Update segment.
+     *
+     * This function performs a `PATCH` to the `/lists/{list_id}/segments/{segment_id}` endpoint.
+     *
+     * As opposed to `patch_segments`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_segments_if_changed<C: serde::Serialize>(
+        &self,
+        list_id: &str,
+        segment_id: &str,
+        current: &C,
+        body: &crate::types::ListDataType,
+    ) -> Result<Option<crate::types::Segments>> {
+        let url = format!(
+            "/lists/{}/segments/{}",
+            self.client.encode_path(list_id),
+            self.client.encode_path(segment_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/lists/{list_id}/segments/{segment_id}",
+                    &[
+                        ("list_id", list_id.to_string()),
+                        ("segment_id", segment_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1173,43 +1535,47 @@ impl Lists {
         include_transactional: bool,
         include_unsubscribed: bool,
     ) -> Result<crate::types::SegmentMembers> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if include_cleaned {
-            query_args.push(("include_cleaned".to_string(), include_cleaned.to_string()));
+            query_args.push(("include_cleaned", include_cleaned.to_string()));
         }
         if include_transactional {
-            query_args.push((
-                "include_transactional".to_string(),
-                include_transactional.to_string(),
-            ));
+            query_args.push(("include_transactional", include_transactional.to_string()));
         }
         if include_unsubscribed {
-            query_args.push((
-                "include_unsubscribed".to_string(),
-                include_unsubscribed.to_string(),
-            ));
+            query_args.push(("include_unsubscribed", include_unsubscribed.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/segments/{}/members?{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&segment_id.to_string()),
-            query_
+            query_args.push(("offset", offset.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/lists/{}/segments/{}/members",
+                self.client.encode_path(list_id),
+                self.client.encode_path(segment_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/lists/{list_id}/segments/{segment_id}/members",
+                &[
+                    ("list_id", list_id.to_string()),
+                    ("segment_id", segment_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1232,13 +1598,23 @@ impl Lists {
     ) -> Result<crate::types::ListMembers> {
         let url = format!(
             "/lists/{}/segments/{}/members",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&segment_id.to_string()),
+            self.client.encode_path(list_id),
+            self.client.encode_path(segment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/lists/{list_id}/segments/{segment_id}/members",
+                    &[
+                        ("list_id", list_id.to_string()),
+                        ("segment_id", segment_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1262,12 +1638,58 @@ impl Lists {
     ) -> Result<()> {
         let url = format!(
             "/lists/{}/segments/{}/members/{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&segment_id.to_string()),
-            crate::progenitor_support::encode_path(&subscriber_hash.to_string()),
+            self.client.encode_path(list_id),
+            self.client.encode_path(segment_id),
+            self.client.encode_path(subscriber_hash),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/lists/{list_id}/segments/{segment_id}/members/{subscriber_hash}",
+                &[
+                    ("list_id", list_id.to_string()),
+                    ("segment_id", segment_id.to_string()),
+                    ("subscriber_hash", subscriber_hash.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Remove list member from segment.
+     *
+     * This function performs a `DELETE` to the `/lists/{list_id}/segments/{segment_id}/members/{subscriber_hash}` endpoint.
+     *
+     * As opposed to `delete_segments_members`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_segments_members_if_exists(
+        &self,
+        list_id: &str,
+        segment_id: &str,
+        subscriber_hash: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/lists/{}/segments/{}/members/{}",
+            self.client.encode_path(list_id),
+            self.client.encode_path(segment_id),
+            self.client.encode_path(subscriber_hash),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/lists/{list_id}/segments/{segment_id}/members/{subscriber_hash}",
+                    &[
+                        ("list_id", list_id.to_string()),
+                        ("segment_id", segment_id.to_string()),
+                        ("subscriber_hash", subscriber_hash.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1287,18 +1709,22 @@ impl Lists {
         list_id: &str,
         name: &str,
     ) -> Result<crate::types::TagSearchResults> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !name.is_empty() {
-            query_args.push(("name".to_string(), name.to_string()));
+            query_args.push(("name", name.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/tag-search?{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/lists/{}/tag-search", self.client.encode_path(list_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/lists/{list_id}/tag-search",
+                &[("list_id", list_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1354,93 +1780,76 @@ impl Lists {
         since_last_campaign: bool,
         unsubscribed_since: &str,
     ) -> Result<crate::types::ListMembersDataType> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !before_last_changed.is_empty() {
-            query_args.push((
-                "before_last_changed".to_string(),
-                before_last_changed.to_string(),
-            ));
+            query_args.push(("before_last_changed", before_last_changed.to_string()));
         }
         if !before_timestamp_opt.is_empty() {
-            query_args.push((
-                "before_timestamp_opt".to_string(),
-                before_timestamp_opt.to_string(),
-            ));
+            query_args.push(("before_timestamp_opt", before_timestamp_opt.to_string()));
         }
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !email_type.is_empty() {
-            query_args.push(("email_type".to_string(), email_type.to_string()));
+            query_args.push(("email_type", email_type.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if !interest_category_id.is_empty() {
-            query_args.push((
-                "interest_category_id".to_string(),
-                interest_category_id.to_string(),
-            ));
+            query_args.push(("interest_category_id", interest_category_id.to_string()));
         }
         if !interest_ids.is_empty() {
-            query_args.push(("interest_ids".to_string(), interest_ids.to_string()));
+            query_args.push(("interest_ids", interest_ids.to_string()));
         }
         if !interest_match.to_string().is_empty() {
-            query_args.push(("interest_match".to_string(), interest_match.to_string()));
+            query_args.push(("interest_match", interest_match.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if since_last_campaign {
-            query_args.push((
-                "since_last_campaign".to_string(),
-                since_last_campaign.to_string(),
-            ));
+            query_args.push(("since_last_campaign", since_last_campaign.to_string()));
         }
         if !since_last_changed.is_empty() {
-            query_args.push((
-                "since_last_changed".to_string(),
-                since_last_changed.to_string(),
-            ));
+            query_args.push(("since_last_changed", since_last_changed.to_string()));
         }
         if !since_timestamp_opt.is_empty() {
-            query_args.push((
-                "since_timestamp_opt".to_string(),
-                since_timestamp_opt.to_string(),
-            ));
+            query_args.push(("since_timestamp_opt", since_timestamp_opt.to_string()));
         }
         if !sort_dir.to_string().is_empty() {
-            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+            query_args.push(("sort_dir", sort_dir.to_string()));
         }
         if !sort_field.to_string().is_empty() {
-            query_args.push(("sort_field".to_string(), sort_field.to_string()));
+            query_args.push(("sort_field", sort_field.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !unique_email_id.is_empty() {
-            query_args.push(("unique_email_id".to_string(), unique_email_id.to_string()));
+            query_args.push(("unique_email_id", unique_email_id.to_string()));
         }
         if !unsubscribed_since.is_empty() {
-            query_args.push((
-                "unsubscribed_since".to_string(),
-                unsubscribed_since.to_string(),
-            ));
+            query_args.push(("unsubscribed_since", unsubscribed_since.to_string()));
         }
         if vip_only {
-            query_args.push(("vip_only".to_string(), vip_only.to_string()));
+            query_args.push(("vip_only", vip_only.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/members?{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/lists/{}/members", self.client.encode_path(list_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/lists/{list_id}/members",
+                &[("list_id", list_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1461,23 +1870,25 @@ impl Lists {
         skip_merge_validation: bool,
         body: &crate::types::AddListMembers,
     ) -> Result<crate::types::ListMembersData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if skip_merge_validation {
-            query_args.push((
-                "skip_merge_validation".to_string(),
-                skip_merge_validation.to_string(),
-            ));
+            query_args.push(("skip_merge_validation", skip_merge_validation.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/members?{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/lists/{}/members", self.client.encode_path(list_id)),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/lists/{list_id}/members",
+                    &[("list_id", list_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1501,22 +1912,32 @@ impl Lists {
         list_id: &str,
         subscriber_hash: &str,
     ) -> Result<crate::types::ListMembersData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/members/{}?{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&subscriber_hash.to_string()),
-            query_
+            query_args.push(("fields", fields.join(",")));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/lists/{}/members/{}",
+                self.client.encode_path(list_id),
+                self.client.encode_path(subscriber_hash)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/lists/{list_id}/members/{subscriber_hash}",
+                &[
+                    ("list_id", list_id.to_string()),
+                    ("subscriber_hash", subscriber_hash.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1539,24 +1960,32 @@ impl Lists {
         skip_merge_validation: bool,
         body: &crate::types::AddListMembersData,
     ) -> Result<crate::types::ListMembersData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if skip_merge_validation {
-            query_args.push((
-                "skip_merge_validation".to_string(),
-                skip_merge_validation.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/members/{}?{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&subscriber_hash.to_string()),
-            query_
+            query_args.push(("skip_merge_validation", skip_merge_validation.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/lists/{}/members/{}",
+                self.client.encode_path(list_id),
+                self.client.encode_path(subscriber_hash)
+            ),
+            &query_args,
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/lists/{list_id}/members/{subscriber_hash}",
+                    &[
+                        ("list_id", list_id.to_string()),
+                        ("subscriber_hash", subscriber_hash.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1574,11 +2003,53 @@ impl Lists {
     pub async fn delete_members(&self, list_id: &str, subscriber_hash: &str) -> Result<()> {
         let url = format!(
             "/lists/{}/members/{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&subscriber_hash.to_string()),
+            self.client.encode_path(list_id),
+            self.client.encode_path(subscriber_hash),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/lists/{list_id}/members/{subscriber_hash}",
+                &[
+                    ("list_id", list_id.to_string()),
+                    ("subscriber_hash", subscriber_hash.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Archive list member.
+     *
+     * This function performs a `DELETE` to the `/lists/{list_id}/members/{subscriber_hash}` endpoint.
+     *
+     * As opposed to `delete_members`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_members_if_exists(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/lists/{}/members/{}",
+            self.client.encode_path(list_id),
+            self.client.encode_path(subscriber_hash),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/lists/{list_id}/members/{subscriber_hash}",
+                    &[
+                        ("list_id", list_id.to_string()),
+                        ("subscriber_hash", subscriber_hash.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1601,28 +2072,79 @@ impl Lists {
         skip_merge_validation: bool,
         body: &crate::types::AddListMembersDataType,
     ) -> Result<crate::types::ListMembersData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if skip_merge_validation {
-            query_args.push((
-                "skip_merge_validation".to_string(),
-                skip_merge_validation.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/members/{}?{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&subscriber_hash.to_string()),
-            query_
+            query_args.push(("skip_merge_validation", skip_merge_validation.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/lists/{}/members/{}",
+                self.client.encode_path(list_id),
+                self.client.encode_path(subscriber_hash)
+            ),
+            &query_args,
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/lists/{list_id}/members/{subscriber_hash}",
+                    &[
+                        ("list_id", list_id.to_string()),
+                        ("subscriber_hash", subscriber_hash.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
-     * View recent activity 50.
+     * Update list member.
+     *
+     * This function performs a `PATCH` to the `/lists/{list_id}/members/{subscriber_hash}` endpoint.
+     *
+     * As opposed to `patch_members`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_members_if_changed<C: serde::Serialize>(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        skip_merge_validation: bool,
+        current: &C,
+        body: &crate::types::AddListMembersDataType,
+    ) -> Result<Option<crate::types::ListMembersData>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if skip_merge_validation {
+            query_args.push(("skip_merge_validation", skip_merge_validation.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/lists/{}/members/{}",
+                self.client.encode_path(list_id),
+                self.client.encode_path(subscriber_hash)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/lists/{list_id}/members/{subscriber_hash}",
+                    &[
+                        ("list_id", list_id.to_string()),
+                        ("subscriber_hash", subscriber_hash.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * View recent activity 50.
      *
      * This function performs a `GET` to the `/lists/{list_id}/members/{subscriber_hash}/activity` endpoint.
      *
@@ -1644,25 +2166,35 @@ impl Lists {
         exclude_fields: &[String],
         action: &[String],
     ) -> Result<crate::types::MemberActivityEvents> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !action.is_empty() {
-            query_args.push(("action".to_string(), action.join(" ")));
+            query_args.push(("action", action.join(",")));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/members/{}/activity?{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&subscriber_hash.to_string()),
-            query_
+            query_args.push(("fields", fields.join(",")));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/lists/{}/members/{}/activity",
+                self.client.encode_path(list_id),
+                self.client.encode_path(subscriber_hash)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/lists/{list_id}/members/{subscriber_hash}/activity",
+                &[
+                    ("list_id", list_id.to_string()),
+                    ("subscriber_hash", subscriber_hash.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1692,31 +2224,41 @@ impl Lists {
         offset: i64,
         activity_filters: &[String],
     ) -> Result<crate::types::MemberActivityEventsData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !activity_filters.is_empty() {
-            query_args.push(("activity_filters".to_string(), activity_filters.join(" ")));
+            query_args.push(("activity_filters", activity_filters.join(",")));
         }
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/members/{}/activity-feed?{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&subscriber_hash.to_string()),
-            query_
+            query_args.push(("offset", offset.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/lists/{}/members/{}/activity-feed",
+                self.client.encode_path(list_id),
+                self.client.encode_path(subscriber_hash)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/lists/{list_id}/members/{subscriber_hash}/activity-feed",
+                &[
+                    ("list_id", list_id.to_string()),
+                    ("subscriber_hash", subscriber_hash.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1744,28 +2286,38 @@ impl Lists {
         count: i64,
         offset: i64,
     ) -> Result<crate::types::CollectionOfTags> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/members/{}/tags?{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&subscriber_hash.to_string()),
-            query_
+            query_args.push(("offset", offset.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/lists/{}/members/{}/tags",
+                self.client.encode_path(list_id),
+                self.client.encode_path(subscriber_hash)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/lists/{list_id}/members/{subscriber_hash}/tags",
+                &[
+                    ("list_id", list_id.to_string()),
+                    ("subscriber_hash", subscriber_hash.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1788,13 +2340,23 @@ impl Lists {
     ) -> Result<()> {
         let url = format!(
             "/lists/{}/members/{}/tags",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&subscriber_hash.to_string()),
+            self.client.encode_path(list_id),
+            self.client.encode_path(subscriber_hash),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/lists/{list_id}/members/{subscriber_hash}/tags",
+                    &[
+                        ("list_id", list_id.to_string()),
+                        ("subscriber_hash", subscriber_hash.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1822,28 +2384,38 @@ impl Lists {
         fields: &[String],
         exclude_fields: &[String],
     ) -> Result<crate::types::CollectionOfEvents> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/members/{}/events?{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&subscriber_hash.to_string()),
-            query_
+            query_args.push(("offset", offset.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/lists/{}/members/{}/events",
+                self.client.encode_path(list_id),
+                self.client.encode_path(subscriber_hash)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/lists/{list_id}/members/{subscriber_hash}/events",
+                &[
+                    ("list_id", list_id.to_string()),
+                    ("subscriber_hash", subscriber_hash.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1866,13 +2438,23 @@ impl Lists {
     ) -> Result<()> {
         let url = format!(
             "/lists/{}/members/{}/events",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&subscriber_hash.to_string()),
+            self.client.encode_path(list_id),
+            self.client.encode_path(subscriber_hash),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/lists/{list_id}/members/{subscriber_hash}/events",
+                    &[
+                        ("list_id", list_id.to_string()),
+                        ("subscriber_hash", subscriber_hash.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1896,22 +2478,32 @@ impl Lists {
         fields: &[String],
         exclude_fields: &[String],
     ) -> Result<crate::types::CollectionOfMemberActivityEvents> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/members/{}/goals?{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&subscriber_hash.to_string()),
-            query_
+            query_args.push(("fields", fields.join(",")));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/lists/{}/members/{}/goals",
+                self.client.encode_path(list_id),
+                self.client.encode_path(subscriber_hash)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/lists/{list_id}/members/{subscriber_hash}/goals",
+                &[
+                    ("list_id", list_id.to_string()),
+                    ("subscriber_hash", subscriber_hash.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1943,34 +2535,44 @@ impl Lists {
         count: i64,
         offset: i64,
     ) -> Result<crate::types::CollectionOfNotes> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !sort_dir.to_string().is_empty() {
-            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+            query_args.push(("sort_dir", sort_dir.to_string()));
         }
         if !sort_field.to_string().is_empty() {
-            query_args.push(("sort_field".to_string(), sort_field.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/members/{}/notes?{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&subscriber_hash.to_string()),
-            query_
+            query_args.push(("sort_field", sort_field.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/lists/{}/members/{}/notes",
+                self.client.encode_path(list_id),
+                self.client.encode_path(subscriber_hash)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/lists/{list_id}/members/{subscriber_hash}/notes",
+                &[
+                    ("list_id", list_id.to_string()),
+                    ("subscriber_hash", subscriber_hash.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1993,13 +2595,23 @@ impl Lists {
     ) -> Result<crate::types::CollectionOfNotesMember> {
         let url = format!(
             "/lists/{}/members/{}/notes",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&subscriber_hash.to_string()),
+            self.client.encode_path(list_id),
+            self.client.encode_path(subscriber_hash),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/lists/{list_id}/members/{subscriber_hash}/notes",
+                    &[
+                        ("list_id", list_id.to_string()),
+                        ("subscriber_hash", subscriber_hash.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -2025,23 +2637,34 @@ impl Lists {
         fields: &[String],
         exclude_fields: &[String],
     ) -> Result<crate::types::CollectionOfNotesMember> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/members/{}/notes/{}?{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&subscriber_hash.to_string()),
-            crate::progenitor_support::encode_path(&note_id.to_string()),
-            query_
+            query_args.push(("fields", fields.join(",")));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/lists/{}/members/{}/notes/{}",
+                self.client.encode_path(list_id),
+                self.client.encode_path(subscriber_hash),
+                self.client.encode_path(note_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/lists/{list_id}/members/{subscriber_hash}/notes/{note_id}",
+                &[
+                    ("list_id", list_id.to_string()),
+                    ("subscriber_hash", subscriber_hash.to_string()),
+                    ("note_id", note_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -2065,12 +2688,58 @@ impl Lists {
     ) -> Result<()> {
         let url = format!(
             "/lists/{}/members/{}/notes/{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&subscriber_hash.to_string()),
-            crate::progenitor_support::encode_path(&note_id.to_string()),
+            self.client.encode_path(list_id),
+            self.client.encode_path(subscriber_hash),
+            self.client.encode_path(note_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/lists/{list_id}/members/{subscriber_hash}/notes/{note_id}",
+                &[
+                    ("list_id", list_id.to_string()),
+                    ("subscriber_hash", subscriber_hash.to_string()),
+                    ("note_id", note_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Delete note.
+     *
+     * This function performs a `DELETE` to the `/lists/{list_id}/members/{subscriber_hash}/notes/{note_id}` endpoint.
+     *
+     * As opposed to `delete_members_notes`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_members_notes_if_exists(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        note_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/lists/{}/members/{}/notes/{}",
+            self.client.encode_path(list_id),
+            self.client.encode_path(subscriber_hash),
+            self.client.encode_path(note_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/lists/{list_id}/members/{subscriber_hash}/notes/{note_id}",
+                    &[
+                        ("list_id", list_id.to_string()),
+                        ("subscriber_hash", subscriber_hash.to_string()),
+                        ("note_id", note_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -2095,14 +2764,63 @@ impl Lists {
     ) -> Result<crate::types::CollectionOfNotesMember> {
         let url = format!(
             "/lists/{}/members/{}/notes/{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&subscriber_hash.to_string()),
-            crate::progenitor_support::encode_path(&note_id.to_string()),
+            self.client.encode_path(list_id),
+            self.client.encode_path(subscriber_hash),
+            self.client.encode_path(note_id),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/lists/{list_id}/members/{subscriber_hash}/notes/{note_id}",
+                    &[
+                        ("list_id", list_id.to_string()),
+                        ("subscriber_hash", subscriber_hash.to_string()),
+                        ("note_id", note_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * Update note.
+     *
+     * This function performs a `PATCH` to the `/lists/{list_id}/members/{subscriber_hash}/notes/{note_id}` endpoint.
+     *
+     * As opposed to `patch_members_notes`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_members_notes_if_changed<C: serde::Serialize>(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        note_id: &str,
+        current: &C,
+        body: &crate::types::MemberNotes,
+    ) -> Result<Option<crate::types::CollectionOfNotesMember>> {
+        let url = format!(
+            "/lists/{}/members/{}/notes/{}",
+            self.client.encode_path(list_id),
+            self.client.encode_path(subscriber_hash),
+            self.client.encode_path(note_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/lists/{list_id}/members/{subscriber_hash}/notes/{note_id}",
+                    &[
+                        ("list_id", list_id.to_string()),
+                        ("subscriber_hash", subscriber_hash.to_string()),
+                        ("note_id", note_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -2124,11 +2842,20 @@ impl Lists {
     ) -> Result<()> {
         let url = format!(
             "/lists/{}/members/{}/actions/delete-permanent",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&subscriber_hash.to_string()),
+            self.client.encode_path(list_id),
+            self.client.encode_path(subscriber_hash),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/lists/{list_id}/members/{subscriber_hash}/actions/delete-permanent",
+                &[
+                    ("list_id", list_id.to_string()),
+                    ("subscriber_hash", subscriber_hash.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -2158,33 +2885,37 @@ impl Lists {
         type_: &str,
         required: bool,
     ) -> Result<crate::types::CollectionOfMergeFields> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if required {
-            query_args.push(("required".to_string(), required.to_string()));
+            query_args.push(("required", required.to_string()));
         }
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/merge-fields?{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/lists/{}/merge-fields", self.client.encode_path(list_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/lists/{list_id}/merge-fields",
+                &[("list_id", list_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2203,14 +2934,18 @@ impl Lists {
         list_id: &str,
         body: &crate::types::MergeFieldData,
     ) -> Result<crate::types::MergeField> {
-        let url = format!(
-            "/lists/{}/merge-fields",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-        );
+        let url = format!("/lists/{}/merge-fields", self.client.encode_path(list_id),);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/lists/{list_id}/merge-fields",
+                    &[("list_id", list_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2234,22 +2969,32 @@ impl Lists {
         exclude_fields: &[String],
         fields: &[String],
     ) -> Result<crate::types::MergeField> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/merge-fields/{}?{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&merge_id.to_string()),
-            query_
+            query_args.push(("fields", fields.join(",")));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/lists/{}/merge-fields/{}",
+                self.client.encode_path(list_id),
+                self.client.encode_path(merge_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/lists/{list_id}/merge-fields/{merge_id}",
+                &[
+                    ("list_id", list_id.to_string()),
+                    ("merge_id", merge_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -2267,11 +3012,53 @@ impl Lists {
     pub async fn delete_merge_fields(&self, list_id: &str, merge_id: &str) -> Result<()> {
         let url = format!(
             "/lists/{}/merge-fields/{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&merge_id.to_string()),
+            self.client.encode_path(list_id),
+            self.client.encode_path(merge_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/lists/{list_id}/merge-fields/{merge_id}",
+                &[
+                    ("list_id", list_id.to_string()),
+                    ("merge_id", merge_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Delete merge field.
+     *
+     * This function performs a `DELETE` to the `/lists/{list_id}/merge-fields/{merge_id}` endpoint.
+     *
+     * As opposed to `delete_merge_fields`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_merge_fields_if_exists(
+        &self,
+        list_id: &str,
+        merge_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/lists/{}/merge-fields/{}",
+            self.client.encode_path(list_id),
+            self.client.encode_path(merge_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/lists/{list_id}/merge-fields/{merge_id}",
+                    &[
+                        ("list_id", list_id.to_string()),
+                        ("merge_id", merge_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -2294,13 +3081,58 @@ impl Lists {
     ) -> Result<crate::types::MergeField> {
         let url = format!(
             "/lists/{}/merge-fields/{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&merge_id.to_string()),
+            self.client.encode_path(list_id),
+            self.client.encode_path(merge_id),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/lists/{list_id}/merge-fields/{merge_id}",
+                    &[
+                        ("list_id", list_id.to_string()),
+                        ("merge_id", merge_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * Update merge field.
+     *
+     * This function performs a `PATCH` to the `/lists/{list_id}/merge-fields/{merge_id}` endpoint.
+     *
+     * As opposed to `patch_merge_fields`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_merge_fields_if_changed<C: serde::Serialize>(
+        &self,
+        list_id: &str,
+        merge_id: &str,
+        current: &C,
+        body: &crate::types::MergeFieldDataType,
+    ) -> Result<Option<crate::types::MergeField>> {
+        let url = format!(
+            "/lists/{}/merge-fields/{}",
+            self.client.encode_path(list_id),
+            self.client.encode_path(merge_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/lists/{list_id}/merge-fields/{merge_id}",
+                    &[
+                        ("list_id", list_id.to_string()),
+                        ("merge_id", merge_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -2315,12 +3147,15 @@ impl Lists {
      * * `list_id: &str` -- The unique ID for the list.
      */
     pub async fn get_webhook(&self, list_id: &str) -> Result<crate::types::ListWebhooksData> {
-        let url = format!(
-            "/lists/{}/webhooks",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-        );
-
-        self.client.get(&url, None).await
+        let url = format!("/lists/{}/webhooks", self.client.encode_path(list_id),);
+
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/lists/{list_id}/webhooks",
+                &[("list_id", list_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2339,14 +3174,18 @@ impl Lists {
         list_id: &str,
         body: &crate::types::AddWebhook,
     ) -> Result<crate::types::ListWebhooks> {
-        let url = format!(
-            "/lists/{}/webhooks",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-        );
+        let url = format!("/lists/{}/webhooks", self.client.encode_path(list_id),);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/lists/{list_id}/webhooks",
+                    &[("list_id", list_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2368,11 +3207,20 @@ impl Lists {
     ) -> Result<crate::types::ListWebhooks> {
         let url = format!(
             "/lists/{}/webhooks/{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&webhook_id.to_string()),
+            self.client.encode_path(list_id),
+            self.client.encode_path(webhook_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/lists/{list_id}/webhooks/{webhook_id}",
+                &[
+                    ("list_id", list_id.to_string()),
+                    ("webhook_id", webhook_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -2390,11 +3238,49 @@ impl Lists {
     pub async fn delete_webhooks(&self, list_id: &str, webhook_id: &str) -> Result<()> {
         let url = format!(
             "/lists/{}/webhooks/{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&webhook_id.to_string()),
+            self.client.encode_path(list_id),
+            self.client.encode_path(webhook_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/lists/{list_id}/webhooks/{webhook_id}",
+                &[
+                    ("list_id", list_id.to_string()),
+                    ("webhook_id", webhook_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Delete webhook.
+     *
+     * This function performs a `DELETE` to the `/lists/{list_id}/webhooks/{webhook_id}` endpoint.
+     *
+     * As opposed to `delete_webhooks`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_webhooks_if_exists(&self, list_id: &str, webhook_id: &str) -> Result<bool> {
+        let url = format!(
+            "/lists/{}/webhooks/{}",
+            self.client.encode_path(list_id),
+            self.client.encode_path(webhook_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/lists/{list_id}/webhooks/{webhook_id}",
+                    &[
+                        ("list_id", list_id.to_string()),
+                        ("webhook_id", webhook_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -2417,13 +3303,58 @@ impl Lists {
     ) -> Result<crate::types::ListWebhooks> {
         let url = format!(
             "/lists/{}/webhooks/{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&webhook_id.to_string()),
+            self.client.encode_path(list_id),
+            self.client.encode_path(webhook_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/lists/{list_id}/webhooks/{webhook_id}",
+                    &[
+                        ("list_id", list_id.to_string()),
+                        ("webhook_id", webhook_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * Update webhook.
+     *
+     * This function performs a `PATCH` to the `/lists/{list_id}/webhooks/{webhook_id}` endpoint.
+     *
+     * As opposed to `patch_webhooks`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_webhooks_if_changed<C: serde::Serialize>(
+        &self,
+        list_id: &str,
+        webhook_id: &str,
+        current: &C,
+        body: &crate::types::AddWebhook,
+    ) -> Result<Option<crate::types::ListWebhooks>> {
+        let url = format!(
+            "/lists/{}/webhooks/{}",
+            self.client.encode_path(list_id),
+            self.client.encode_path(webhook_id),
+        );
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/lists/{list_id}/webhooks/{webhook_id}",
+                    &[
+                        ("list_id", list_id.to_string()),
+                        ("webhook_id", webhook_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -2438,12 +3369,15 @@ impl Lists {
      * * `list_id: &str` -- The unique ID for the list.
      */
     pub async fn get_signup_form(&self, list_id: &str) -> Result<crate::types::ListSignupForms> {
-        let url = format!(
-            "/lists/{}/signup-forms",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-        );
-
-        self.client.get(&url, None).await
+        let url = format!("/lists/{}/signup-forms", self.client.encode_path(list_id),);
+
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/lists/{list_id}/signup-forms",
+                &[("list_id", list_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2462,14 +3396,18 @@ impl Lists {
         list_id: &str,
         body: &crate::types::SignupFormData,
     ) -> Result<crate::types::SignupForm> {
-        let url = format!(
-            "/lists/{}/signup-forms",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-        );
+        let url = format!("/lists/{}/signup-forms", self.client.encode_path(list_id),);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/lists/{list_id}/signup-forms",
+                    &[("list_id", list_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2491,20 +3429,1322 @@ impl Lists {
         exclude_fields: &[String],
         list_id: &str,
     ) -> Result<crate::types::ListLocations> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/lists/{}/locations?{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/lists/{}/locations", self.client.encode_path(list_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/lists/{list_id}/locations",
+                &[("list_id", list_id.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`Lists`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ListsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ListsApi: Send + Sync {
+    async fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        before_date_created: &str,
+        since_date_created: &str,
+        before_campaign_last_sent: &str,
+        since_campaign_last_sent: &str,
+        email: &str,
+        sort_field: crate::types::GetListsSortField,
+        sort_dir: crate::types::SortDir,
+        has_ecommerce_store: bool,
+        include_total_contacts: bool,
+    ) -> Result<crate::types::SubscriberLists>;
+    async fn post(&self, body: &crate::types::SubscriberList) -> Result<crate::types::Lists>;
+    async fn get_lists(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+        include_total_contacts: bool,
+    ) -> Result<crate::types::Lists>;
+    async fn post_lists(
+        &self,
+        list_id: &str,
+        skip_merge_validation: bool,
+        skip_duplicate_check: bool,
+        body: &crate::types::MembersSubscribeUnsubscribeFromAListInBatch,
+    ) -> Result<crate::types::BatchUpdateListMembers>;
+    async fn delete(&self, list_id: &str) -> Result<()>;
+    async fn delete_if_exists(&self, list_id: &str) -> Result<bool>;
+    async fn patch(
+        &self,
+        list_id: &str,
+        body: &crate::types::SubscriberListData,
+    ) -> Result<crate::types::Lists>;
+    async fn get_abuse_report(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        list_id: &str,
+    ) -> Result<crate::types::AbuseComplaints>;
+    async fn get_abuse_report_lists(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        list_id: &str,
+        report_id: &str,
+    ) -> Result<crate::types::AbuseReports>;
+    async fn get_activity(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+    ) -> Result<crate::types::ListActivity>;
+    async fn get_client(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+    ) -> Result<crate::types::EmailClients>;
+    async fn get_growth_history(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        list_id: &str,
+        sort_field: crate::types::GetListsGrowthHistorySortField,
+        sort_dir: crate::types::SortDir,
+    ) -> Result<crate::types::GrowthHistory>;
+    async fn get_growth_history_lists(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+        month: &str,
+    ) -> Result<crate::types::History>;
+    async fn get_interest_categorie(
+        &self,
+        list_id: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        type_: &str,
+    ) -> Result<crate::types::InterestGroupings>;
+    async fn post_interest_categorie(
+        &self,
+        list_id: &str,
+        body: &crate::types::InterestCategory,
+    ) -> Result<crate::types::Categories>;
+    async fn get_interest_categorie_lists(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> Result<crate::types::Categories>;
+    async fn delete_interest_categories(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+    ) -> Result<()>;
+    async fn delete_interest_categories_if_exists(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+    ) -> Result<bool>;
+    async fn patch_interest_categories(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+        body: &crate::types::InterestCategory,
+    ) -> Result<crate::types::Categories>;
+    async fn get_interest_categories_interest(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> Result<crate::types::InterestsData>;
+    async fn post_interest_categories(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+        body: &crate::types::Interest,
+    ) -> Result<crate::types::InterestsInterest>;
+    async fn get_interest_categories_interest_lists(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+        interest_id: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> Result<crate::types::InterestsInterest>;
+    async fn delete_interest_categories_interests(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+        interest_id: &str,
+    ) -> Result<()>;
+    async fn delete_interest_categories_interests_if_exists(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+        interest_id: &str,
+    ) -> Result<bool>;
+    async fn patch_interest_categories_interests(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+        interest_id: &str,
+        body: &crate::types::Interest,
+    ) -> Result<crate::types::InterestsInterest>;
+    async fn preview_segment(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        list_id: &str,
+        type_: &str,
+        since_created_at: &str,
+        before_created_at: &str,
+        include_cleaned: bool,
+        include_transactional: bool,
+        include_unsubscribed: bool,
+        since_updated_at: &str,
+        before_updated_at: &str,
+    ) -> Result<crate::types::CollectionOfSegments>;
+    async fn post_segment(
+        &self,
+        list_id: &str,
+        body: &crate::types::ListData,
+    ) -> Result<crate::types::Segments>;
+    async fn get_segment(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+        segment_id: &str,
+        include_cleaned: bool,
+        include_transactional: bool,
+        include_unsubscribed: bool,
+    ) -> Result<crate::types::Segments>;
+    async fn post_segment_lists(
+        &self,
+        list_id: &str,
+        segment_id: &str,
+        body: &crate::types::MembersAddRemoveFromAStaticSegment,
+    ) -> Result<crate::types::BatchAddRemoveListMembersFromStaticSegment>;
+    async fn delete_segments(&self, list_id: &str, segment_id: &str) -> Result<()>;
+    async fn delete_segments_if_exists(&self, list_id: &str, segment_id: &str) -> Result<bool>;
+    async fn patch_segments(
+        &self,
+        list_id: &str,
+        segment_id: &str,
+        body: &crate::types::ListDataType,
+    ) -> Result<crate::types::Segments>;
+    async fn get_segments_member(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        list_id: &str,
+        segment_id: &str,
+        include_cleaned: bool,
+        include_transactional: bool,
+        include_unsubscribed: bool,
+    ) -> Result<crate::types::SegmentMembers>;
+    async fn post_segments_member(
+        &self,
+        list_id: &str,
+        segment_id: &str,
+        body: &crate::types::SubscriberInAutomationQueue,
+    ) -> Result<crate::types::ListMembers>;
+    async fn delete_segments_members(
+        &self,
+        list_id: &str,
+        segment_id: &str,
+        subscriber_hash: &str,
+    ) -> Result<()>;
+    async fn delete_segments_members_if_exists(
+        &self,
+        list_id: &str,
+        segment_id: &str,
+        subscriber_hash: &str,
+    ) -> Result<bool>;
+    async fn search_tags_name(
+        &self,
+        list_id: &str,
+        name: &str,
+    ) -> Result<crate::types::TagSearchResults>;
+    async fn get_member(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        list_id: &str,
+        email_type: &str,
+        status: crate::types::GetListsMembersStatus,
+        since_timestamp_opt: &str,
+        before_timestamp_opt: &str,
+        since_last_changed: &str,
+        before_last_changed: &str,
+        unique_email_id: &str,
+        vip_only: bool,
+        interest_category_id: &str,
+        interest_ids: &str,
+        interest_match: crate::types::InterestMatch,
+        sort_field: crate::types::GetListsMembersSortField,
+        sort_dir: crate::types::SortDir,
+        since_last_campaign: bool,
+        unsubscribed_since: &str,
+    ) -> Result<crate::types::ListMembersDataType>;
+    async fn post_member(
+        &self,
+        list_id: &str,
+        skip_merge_validation: bool,
+        body: &crate::types::AddListMembers,
+    ) -> Result<crate::types::ListMembersData>;
+    async fn get_member_lists(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+        subscriber_hash: &str,
+    ) -> Result<crate::types::ListMembersData>;
+    async fn put_members(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        skip_merge_validation: bool,
+        body: &crate::types::AddListMembersData,
+    ) -> Result<crate::types::ListMembersData>;
+    async fn delete_members(&self, list_id: &str, subscriber_hash: &str) -> Result<()>;
+    async fn delete_members_if_exists(&self, list_id: &str, subscriber_hash: &str) -> Result<bool>;
+    async fn patch_members(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        skip_merge_validation: bool,
+        body: &crate::types::AddListMembersDataType,
+    ) -> Result<crate::types::ListMembersData>;
+    async fn get_members_activity(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+        action: &[String],
+    ) -> Result<crate::types::MemberActivityEvents>;
+    async fn get_members_activity_feed(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        activity_filters: &[String],
+    ) -> Result<crate::types::MemberActivityEventsData>;
+    async fn get_member_tag(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> Result<crate::types::CollectionOfTags>;
+    async fn post_member_tag(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        body: &crate::types::MemberTags,
+    ) -> Result<()>;
+    async fn get_members_event(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        count: i64,
+        offset: i64,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> Result<crate::types::CollectionOfEvents>;
+    async fn post_member_event(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        body: &crate::types::EventsData,
+    ) -> Result<()>;
+    async fn get_members_goal(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> Result<crate::types::CollectionOfMemberActivityEvents>;
+    async fn get_members_note(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        sort_field: crate::types::GetListsMembersNotesSortField,
+        sort_dir: crate::types::SortDir,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> Result<crate::types::CollectionOfNotes>;
+    async fn post_members_note(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        body: &crate::types::MemberNotes,
+    ) -> Result<crate::types::CollectionOfNotesMember>;
+    async fn get_members_note_lists(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        note_id: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> Result<crate::types::CollectionOfNotesMember>;
+    async fn delete_members_notes(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        note_id: &str,
+    ) -> Result<()>;
+    async fn delete_members_notes_if_exists(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        note_id: &str,
+    ) -> Result<bool>;
+    async fn patch_members_notes(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        note_id: &str,
+        body: &crate::types::MemberNotes,
+    ) -> Result<crate::types::CollectionOfNotesMember>;
+    async fn post_members_hash_actions_delete_permanent(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+    ) -> Result<()>;
+    async fn get_merge_field(
+        &self,
+        list_id: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        type_: &str,
+        required: bool,
+    ) -> Result<crate::types::CollectionOfMergeFields>;
+    async fn post_merge_field(
+        &self,
+        list_id: &str,
+        body: &crate::types::MergeFieldData,
+    ) -> Result<crate::types::MergeField>;
+    async fn get_merge_field_lists(
+        &self,
+        list_id: &str,
+        merge_id: &str,
+        exclude_fields: &[String],
+        fields: &[String],
+    ) -> Result<crate::types::MergeField>;
+    async fn delete_merge_fields(&self, list_id: &str, merge_id: &str) -> Result<()>;
+    async fn delete_merge_fields_if_exists(&self, list_id: &str, merge_id: &str) -> Result<bool>;
+    async fn patch_merge_fields(
+        &self,
+        list_id: &str,
+        merge_id: &str,
+        body: &crate::types::MergeFieldDataType,
+    ) -> Result<crate::types::MergeField>;
+    async fn get_webhook(&self, list_id: &str) -> Result<crate::types::ListWebhooksData>;
+    async fn post_webhook(
+        &self,
+        list_id: &str,
+        body: &crate::types::AddWebhook,
+    ) -> Result<crate::types::ListWebhooks>;
+    async fn get_webhook_lists(
+        &self,
+        list_id: &str,
+        webhook_id: &str,
+    ) -> Result<crate::types::ListWebhooks>;
+    async fn delete_webhooks(&self, list_id: &str, webhook_id: &str) -> Result<()>;
+    async fn delete_webhooks_if_exists(&self, list_id: &str, webhook_id: &str) -> Result<bool>;
+    async fn patch_webhooks(
+        &self,
+        list_id: &str,
+        webhook_id: &str,
+        body: &crate::types::AddWebhook,
+    ) -> Result<crate::types::ListWebhooks>;
+    async fn get_signup_form(&self, list_id: &str) -> Result<crate::types::ListSignupForms>;
+    async fn post_signup_form(
+        &self,
+        list_id: &str,
+        body: &crate::types::SignupFormData,
+    ) -> Result<crate::types::SignupForm>;
+    async fn get_location(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+    ) -> Result<crate::types::ListLocations>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ListsApi for Lists {
+    async fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        before_date_created: &str,
+        since_date_created: &str,
+        before_campaign_last_sent: &str,
+        since_campaign_last_sent: &str,
+        email: &str,
+        sort_field: crate::types::GetListsSortField,
+        sort_dir: crate::types::SortDir,
+        has_ecommerce_store: bool,
+        include_total_contacts: bool,
+    ) -> Result<crate::types::SubscriberLists> {
+        Lists::get(
+            self,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            before_date_created,
+            since_date_created,
+            before_campaign_last_sent,
+            since_campaign_last_sent,
+            email,
+            sort_field,
+            sort_dir,
+            has_ecommerce_store,
+            include_total_contacts,
+        )
+        .await
+    }
+    async fn post(&self, body: &crate::types::SubscriberList) -> Result<crate::types::Lists> {
+        Lists::post(self, body).await
+    }
+    async fn get_lists(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+        include_total_contacts: bool,
+    ) -> Result<crate::types::Lists> {
+        Lists::get_lists(
+            self,
+            fields,
+            exclude_fields,
+            list_id,
+            include_total_contacts,
+        )
+        .await
+    }
+    async fn post_lists(
+        &self,
+        list_id: &str,
+        skip_merge_validation: bool,
+        skip_duplicate_check: bool,
+        body: &crate::types::MembersSubscribeUnsubscribeFromAListInBatch,
+    ) -> Result<crate::types::BatchUpdateListMembers> {
+        Lists::post_lists(
+            self,
+            list_id,
+            skip_merge_validation,
+            skip_duplicate_check,
+            body,
+        )
+        .await
+    }
+    async fn delete(&self, list_id: &str) -> Result<()> {
+        Lists::delete(self, list_id).await
+    }
+    async fn delete_if_exists(&self, list_id: &str) -> Result<bool> {
+        Lists::delete_if_exists(self, list_id).await
+    }
+    async fn patch(
+        &self,
+        list_id: &str,
+        body: &crate::types::SubscriberListData,
+    ) -> Result<crate::types::Lists> {
+        Lists::patch(self, list_id, body).await
+    }
+    async fn get_abuse_report(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        list_id: &str,
+    ) -> Result<crate::types::AbuseComplaints> {
+        Lists::get_abuse_report(self, fields, exclude_fields, count, offset, list_id).await
+    }
+    async fn get_abuse_report_lists(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        list_id: &str,
+        report_id: &str,
+    ) -> Result<crate::types::AbuseReports> {
+        Lists::get_abuse_report_lists(
+            self,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            list_id,
+            report_id,
+        )
+        .await
+    }
+    async fn get_activity(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+    ) -> Result<crate::types::ListActivity> {
+        Lists::get_activity(self, fields, exclude_fields, list_id).await
+    }
+    async fn get_client(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+    ) -> Result<crate::types::EmailClients> {
+        Lists::get_client(self, fields, exclude_fields, list_id).await
+    }
+    async fn get_growth_history(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        list_id: &str,
+        sort_field: crate::types::GetListsGrowthHistorySortField,
+        sort_dir: crate::types::SortDir,
+    ) -> Result<crate::types::GrowthHistory> {
+        Lists::get_growth_history(
+            self,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            list_id,
+            sort_field,
+            sort_dir,
+        )
+        .await
+    }
+    async fn get_growth_history_lists(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+        month: &str,
+    ) -> Result<crate::types::History> {
+        Lists::get_growth_history_lists(self, fields, exclude_fields, list_id, month).await
+    }
+    async fn get_interest_categorie(
+        &self,
+        list_id: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        type_: &str,
+    ) -> Result<crate::types::InterestGroupings> {
+        Lists::get_interest_categorie(self, list_id, fields, exclude_fields, count, offset, type_)
+            .await
+    }
+    async fn post_interest_categorie(
+        &self,
+        list_id: &str,
+        body: &crate::types::InterestCategory,
+    ) -> Result<crate::types::Categories> {
+        Lists::post_interest_categorie(self, list_id, body).await
+    }
+    async fn get_interest_categorie_lists(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> Result<crate::types::Categories> {
+        Lists::get_interest_categorie_lists(
+            self,
+            list_id,
+            interest_category_id,
+            fields,
+            exclude_fields,
+        )
+        .await
+    }
+    async fn delete_interest_categories(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+    ) -> Result<()> {
+        Lists::delete_interest_categories(self, list_id, interest_category_id).await
+    }
+    async fn delete_interest_categories_if_exists(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+    ) -> Result<bool> {
+        Lists::delete_interest_categories_if_exists(self, list_id, interest_category_id).await
+    }
+    async fn patch_interest_categories(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+        body: &crate::types::InterestCategory,
+    ) -> Result<crate::types::Categories> {
+        Lists::patch_interest_categories(self, list_id, interest_category_id, body).await
+    }
+    async fn get_interest_categories_interest(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> Result<crate::types::InterestsData> {
+        Lists::get_interest_categories_interest(
+            self,
+            list_id,
+            interest_category_id,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+        )
+        .await
+    }
+    async fn post_interest_categories(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+        body: &crate::types::Interest,
+    ) -> Result<crate::types::InterestsInterest> {
+        Lists::post_interest_categories(self, list_id, interest_category_id, body).await
+    }
+    async fn get_interest_categories_interest_lists(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+        interest_id: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> Result<crate::types::InterestsInterest> {
+        Lists::get_interest_categories_interest_lists(
+            self,
+            list_id,
+            interest_category_id,
+            interest_id,
+            fields,
+            exclude_fields,
+        )
+        .await
+    }
+    async fn delete_interest_categories_interests(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+        interest_id: &str,
+    ) -> Result<()> {
+        Lists::delete_interest_categories_interests(
+            self,
+            list_id,
+            interest_category_id,
+            interest_id,
+        )
+        .await
+    }
+    async fn delete_interest_categories_interests_if_exists(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+        interest_id: &str,
+    ) -> Result<bool> {
+        Lists::delete_interest_categories_interests_if_exists(
+            self,
+            list_id,
+            interest_category_id,
+            interest_id,
+        )
+        .await
+    }
+    async fn patch_interest_categories_interests(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+        interest_id: &str,
+        body: &crate::types::Interest,
+    ) -> Result<crate::types::InterestsInterest> {
+        Lists::patch_interest_categories_interests(
+            self,
+            list_id,
+            interest_category_id,
+            interest_id,
+            body,
+        )
+        .await
+    }
+    async fn preview_segment(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        list_id: &str,
+        type_: &str,
+        since_created_at: &str,
+        before_created_at: &str,
+        include_cleaned: bool,
+        include_transactional: bool,
+        include_unsubscribed: bool,
+        since_updated_at: &str,
+        before_updated_at: &str,
+    ) -> Result<crate::types::CollectionOfSegments> {
+        Lists::preview_segment(
+            self,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            list_id,
+            type_,
+            since_created_at,
+            before_created_at,
+            include_cleaned,
+            include_transactional,
+            include_unsubscribed,
+            since_updated_at,
+            before_updated_at,
+        )
+        .await
+    }
+    async fn post_segment(
+        &self,
+        list_id: &str,
+        body: &crate::types::ListData,
+    ) -> Result<crate::types::Segments> {
+        Lists::post_segment(self, list_id, body).await
+    }
+    async fn get_segment(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+        segment_id: &str,
+        include_cleaned: bool,
+        include_transactional: bool,
+        include_unsubscribed: bool,
+    ) -> Result<crate::types::Segments> {
+        Lists::get_segment(
+            self,
+            fields,
+            exclude_fields,
+            list_id,
+            segment_id,
+            include_cleaned,
+            include_transactional,
+            include_unsubscribed,
+        )
+        .await
+    }
+    async fn post_segment_lists(
+        &self,
+        list_id: &str,
+        segment_id: &str,
+        body: &crate::types::MembersAddRemoveFromAStaticSegment,
+    ) -> Result<crate::types::BatchAddRemoveListMembersFromStaticSegment> {
+        Lists::post_segment_lists(self, list_id, segment_id, body).await
+    }
+    async fn delete_segments(&self, list_id: &str, segment_id: &str) -> Result<()> {
+        Lists::delete_segments(self, list_id, segment_id).await
+    }
+    async fn delete_segments_if_exists(&self, list_id: &str, segment_id: &str) -> Result<bool> {
+        Lists::delete_segments_if_exists(self, list_id, segment_id).await
+    }
+    async fn patch_segments(
+        &self,
+        list_id: &str,
+        segment_id: &str,
+        body: &crate::types::ListDataType,
+    ) -> Result<crate::types::Segments> {
+        Lists::patch_segments(self, list_id, segment_id, body).await
+    }
+    async fn get_segments_member(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        list_id: &str,
+        segment_id: &str,
+        include_cleaned: bool,
+        include_transactional: bool,
+        include_unsubscribed: bool,
+    ) -> Result<crate::types::SegmentMembers> {
+        Lists::get_segments_member(
+            self,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            list_id,
+            segment_id,
+            include_cleaned,
+            include_transactional,
+            include_unsubscribed,
+        )
+        .await
+    }
+    async fn post_segments_member(
+        &self,
+        list_id: &str,
+        segment_id: &str,
+        body: &crate::types::SubscriberInAutomationQueue,
+    ) -> Result<crate::types::ListMembers> {
+        Lists::post_segments_member(self, list_id, segment_id, body).await
+    }
+    async fn delete_segments_members(
+        &self,
+        list_id: &str,
+        segment_id: &str,
+        subscriber_hash: &str,
+    ) -> Result<()> {
+        Lists::delete_segments_members(self, list_id, segment_id, subscriber_hash).await
+    }
+    async fn delete_segments_members_if_exists(
+        &self,
+        list_id: &str,
+        segment_id: &str,
+        subscriber_hash: &str,
+    ) -> Result<bool> {
+        Lists::delete_segments_members_if_exists(self, list_id, segment_id, subscriber_hash).await
+    }
+    async fn search_tags_name(
+        &self,
+        list_id: &str,
+        name: &str,
+    ) -> Result<crate::types::TagSearchResults> {
+        Lists::search_tags_name(self, list_id, name).await
+    }
+    async fn get_member(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        list_id: &str,
+        email_type: &str,
+        status: crate::types::GetListsMembersStatus,
+        since_timestamp_opt: &str,
+        before_timestamp_opt: &str,
+        since_last_changed: &str,
+        before_last_changed: &str,
+        unique_email_id: &str,
+        vip_only: bool,
+        interest_category_id: &str,
+        interest_ids: &str,
+        interest_match: crate::types::InterestMatch,
+        sort_field: crate::types::GetListsMembersSortField,
+        sort_dir: crate::types::SortDir,
+        since_last_campaign: bool,
+        unsubscribed_since: &str,
+    ) -> Result<crate::types::ListMembersDataType> {
+        Lists::get_member(
+            self,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            list_id,
+            email_type,
+            status,
+            since_timestamp_opt,
+            before_timestamp_opt,
+            since_last_changed,
+            before_last_changed,
+            unique_email_id,
+            vip_only,
+            interest_category_id,
+            interest_ids,
+            interest_match,
+            sort_field,
+            sort_dir,
+            since_last_campaign,
+            unsubscribed_since,
+        )
+        .await
+    }
+    async fn post_member(
+        &self,
+        list_id: &str,
+        skip_merge_validation: bool,
+        body: &crate::types::AddListMembers,
+    ) -> Result<crate::types::ListMembersData> {
+        Lists::post_member(self, list_id, skip_merge_validation, body).await
+    }
+    async fn get_member_lists(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+        subscriber_hash: &str,
+    ) -> Result<crate::types::ListMembersData> {
+        Lists::get_member_lists(self, fields, exclude_fields, list_id, subscriber_hash).await
+    }
+    async fn put_members(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        skip_merge_validation: bool,
+        body: &crate::types::AddListMembersData,
+    ) -> Result<crate::types::ListMembersData> {
+        Lists::put_members(self, list_id, subscriber_hash, skip_merge_validation, body).await
+    }
+    async fn delete_members(&self, list_id: &str, subscriber_hash: &str) -> Result<()> {
+        Lists::delete_members(self, list_id, subscriber_hash).await
+    }
+    async fn delete_members_if_exists(&self, list_id: &str, subscriber_hash: &str) -> Result<bool> {
+        Lists::delete_members_if_exists(self, list_id, subscriber_hash).await
+    }
+    async fn patch_members(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        skip_merge_validation: bool,
+        body: &crate::types::AddListMembersDataType,
+    ) -> Result<crate::types::ListMembersData> {
+        Lists::patch_members(self, list_id, subscriber_hash, skip_merge_validation, body).await
+    }
+    async fn get_members_activity(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+        action: &[String],
+    ) -> Result<crate::types::MemberActivityEvents> {
+        Lists::get_members_activity(
+            self,
+            list_id,
+            subscriber_hash,
+            fields,
+            exclude_fields,
+            action,
+        )
+        .await
+    }
+    async fn get_members_activity_feed(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        activity_filters: &[String],
+    ) -> Result<crate::types::MemberActivityEventsData> {
+        Lists::get_members_activity_feed(
+            self,
+            list_id,
+            subscriber_hash,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            activity_filters,
+        )
+        .await
+    }
+    async fn get_member_tag(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> Result<crate::types::CollectionOfTags> {
+        Lists::get_member_tag(
+            self,
+            list_id,
+            subscriber_hash,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+        )
+        .await
+    }
+    async fn post_member_tag(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        body: &crate::types::MemberTags,
+    ) -> Result<()> {
+        Lists::post_member_tag(self, list_id, subscriber_hash, body).await
+    }
+    async fn get_members_event(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        count: i64,
+        offset: i64,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> Result<crate::types::CollectionOfEvents> {
+        Lists::get_members_event(
+            self,
+            list_id,
+            subscriber_hash,
+            count,
+            offset,
+            fields,
+            exclude_fields,
+        )
+        .await
+    }
+    async fn post_member_event(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        body: &crate::types::EventsData,
+    ) -> Result<()> {
+        Lists::post_member_event(self, list_id, subscriber_hash, body).await
+    }
+    async fn get_members_goal(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> Result<crate::types::CollectionOfMemberActivityEvents> {
+        Lists::get_members_goal(self, list_id, subscriber_hash, fields, exclude_fields).await
+    }
+    async fn get_members_note(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        sort_field: crate::types::GetListsMembersNotesSortField,
+        sort_dir: crate::types::SortDir,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> Result<crate::types::CollectionOfNotes> {
+        Lists::get_members_note(
+            self,
+            list_id,
+            subscriber_hash,
+            sort_field,
+            sort_dir,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+        )
+        .await
+    }
+    async fn post_members_note(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        body: &crate::types::MemberNotes,
+    ) -> Result<crate::types::CollectionOfNotesMember> {
+        Lists::post_members_note(self, list_id, subscriber_hash, body).await
+    }
+    async fn get_members_note_lists(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        note_id: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> Result<crate::types::CollectionOfNotesMember> {
+        Lists::get_members_note_lists(
+            self,
+            list_id,
+            subscriber_hash,
+            note_id,
+            fields,
+            exclude_fields,
+        )
+        .await
+    }
+    async fn delete_members_notes(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        note_id: &str,
+    ) -> Result<()> {
+        Lists::delete_members_notes(self, list_id, subscriber_hash, note_id).await
+    }
+    async fn delete_members_notes_if_exists(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        note_id: &str,
+    ) -> Result<bool> {
+        Lists::delete_members_notes_if_exists(self, list_id, subscriber_hash, note_id).await
+    }
+    async fn patch_members_notes(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        note_id: &str,
+        body: &crate::types::MemberNotes,
+    ) -> Result<crate::types::CollectionOfNotesMember> {
+        Lists::patch_members_notes(self, list_id, subscriber_hash, note_id, body).await
+    }
+    async fn post_members_hash_actions_delete_permanent(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+    ) -> Result<()> {
+        Lists::post_members_hash_actions_delete_permanent(self, list_id, subscriber_hash).await
+    }
+    async fn get_merge_field(
+        &self,
+        list_id: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        type_: &str,
+        required: bool,
+    ) -> Result<crate::types::CollectionOfMergeFields> {
+        Lists::get_merge_field(
+            self,
+            list_id,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            type_,
+            required,
+        )
+        .await
+    }
+    async fn post_merge_field(
+        &self,
+        list_id: &str,
+        body: &crate::types::MergeFieldData,
+    ) -> Result<crate::types::MergeField> {
+        Lists::post_merge_field(self, list_id, body).await
+    }
+    async fn get_merge_field_lists(
+        &self,
+        list_id: &str,
+        merge_id: &str,
+        exclude_fields: &[String],
+        fields: &[String],
+    ) -> Result<crate::types::MergeField> {
+        Lists::get_merge_field_lists(self, list_id, merge_id, exclude_fields, fields).await
+    }
+    async fn delete_merge_fields(&self, list_id: &str, merge_id: &str) -> Result<()> {
+        Lists::delete_merge_fields(self, list_id, merge_id).await
+    }
+    async fn delete_merge_fields_if_exists(&self, list_id: &str, merge_id: &str) -> Result<bool> {
+        Lists::delete_merge_fields_if_exists(self, list_id, merge_id).await
+    }
+    async fn patch_merge_fields(
+        &self,
+        list_id: &str,
+        merge_id: &str,
+        body: &crate::types::MergeFieldDataType,
+    ) -> Result<crate::types::MergeField> {
+        Lists::patch_merge_fields(self, list_id, merge_id, body).await
+    }
+    async fn get_webhook(&self, list_id: &str) -> Result<crate::types::ListWebhooksData> {
+        Lists::get_webhook(self, list_id).await
+    }
+    async fn post_webhook(
+        &self,
+        list_id: &str,
+        body: &crate::types::AddWebhook,
+    ) -> Result<crate::types::ListWebhooks> {
+        Lists::post_webhook(self, list_id, body).await
+    }
+    async fn get_webhook_lists(
+        &self,
+        list_id: &str,
+        webhook_id: &str,
+    ) -> Result<crate::types::ListWebhooks> {
+        Lists::get_webhook_lists(self, list_id, webhook_id).await
+    }
+    async fn delete_webhooks(&self, list_id: &str, webhook_id: &str) -> Result<()> {
+        Lists::delete_webhooks(self, list_id, webhook_id).await
+    }
+    async fn delete_webhooks_if_exists(&self, list_id: &str, webhook_id: &str) -> Result<bool> {
+        Lists::delete_webhooks_if_exists(self, list_id, webhook_id).await
+    }
+    async fn patch_webhooks(
+        &self,
+        list_id: &str,
+        webhook_id: &str,
+        body: &crate::types::AddWebhook,
+    ) -> Result<crate::types::ListWebhooks> {
+        Lists::patch_webhooks(self, list_id, webhook_id, body).await
+    }
+    async fn get_signup_form(&self, list_id: &str) -> Result<crate::types::ListSignupForms> {
+        Lists::get_signup_form(self, list_id).await
+    }
+    async fn post_signup_form(
+        &self,
+        list_id: &str,
+        body: &crate::types::SignupFormData,
+    ) -> Result<crate::types::SignupForm> {
+        Lists::post_signup_form(self, list_id, body).await
+    }
+    async fn get_location(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+    ) -> Result<crate::types::ListLocations> {
+        Lists::get_location(self, fields, exclude_fields, list_id).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Ping {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Ping {
         Ping { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Ping {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Ping.
      *
@@ -21,6 +27,26 @@ impl Ping {
      */
     pub async fn get(&self) -> Result<crate::types::ApiHealthStatus> {
         let url = "/ping".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/ping", &[]))
+    }
+}
+
+/// The functions of [`Ping`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn PingApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait PingApi: Send + Sync {
+    async fn get(&self) -> Result<crate::types::ApiHealthStatus>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl PingApi for Ping {
+    async fn get(&self) -> Result<crate::types::ApiHealthStatus> {
+        Ping::get(self).await
     }
 }
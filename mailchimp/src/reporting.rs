@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Reporting {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Reporting {
         Reporting { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Reporting {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * List facebook ads reports.
      *
@@ -37,29 +43,31 @@ impl Reporting {
         sort_field: crate::types::GetAllFacebookAdsSortField,
         sort_dir: crate::types::SortDir,
     ) -> Result<crate::types::GetReportingFacebookAdsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !sort_dir.to_string().is_empty() {
-            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+            query_args.push(("sort_dir", sort_dir.to_string()));
         }
         if !sort_field.to_string().is_empty() {
-            query_args.push(("sort_field".to_string(), sort_field.to_string()));
+            query_args.push(("sort_field", sort_field.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/reporting/facebook-ads?{}", query_);
+        let url = crate::Client::url_with_query("/reporting/facebook-ads", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/reporting/facebook-ads", &[]))
     }
 
     /**
@@ -81,21 +89,28 @@ impl Reporting {
         outreach_id: &str,
         exclude_fields: &[String],
     ) -> Result<crate::types::GetReportingFacebookAdsResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reporting/facebook-ads/{}?{}",
-            crate::progenitor_support::encode_path(&outreach_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/reporting/facebook-ads/{}",
+                self.client.encode_path(outreach_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/reporting/facebook-ads/{outreach_id}",
+                &[("outreach_id", outreach_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -123,30 +138,37 @@ impl Reporting {
         outreach_id: &str,
         sort_field: crate::types::GetReportsEcommerceProductActivitySortField,
     ) -> Result<crate::types::GetReportsEcommerceProductActivityResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !sort_field.to_string().is_empty() {
-            query_args.push(("sort_field".to_string(), sort_field.to_string()));
+            query_args.push(("sort_field", sort_field.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reporting/facebook-ads/{}/ecommerce-product-activity?{}",
-            crate::progenitor_support::encode_path(&outreach_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/reporting/facebook-ads/{}/ecommerce-product-activity",
+                self.client.encode_path(outreach_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/reporting/facebook-ads/{outreach_id}/ecommerce-product-activity",
+                &[("outreach_id", outreach_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -168,21 +190,28 @@ impl Reporting {
         outreach_id: &str,
         exclude_fields: &[String],
     ) -> Result<crate::types::LandingPages> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reporting/landing-pages/{}?{}",
-            crate::progenitor_support::encode_path(&outreach_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/reporting/landing-pages/{}",
+                self.client.encode_path(outreach_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/reporting/landing-pages/{outreach_id}",
+                &[("outreach_id", outreach_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -206,22 +235,139 @@ impl Reporting {
         count: i64,
         offset: i64,
     ) -> Result<crate::types::GetReportingLandingPagesResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/reporting/landing-pages?{}", query_);
+        let url = crate::Client::url_with_query("/reporting/landing-pages", &query_args);
+
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/reporting/landing-pages", &[]))
+    }
+}
+
+/// The functions of [`Reporting`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ReportingApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ReportingApi: Send + Sync {
+    async fn get_facebook_ads(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        sort_field: crate::types::GetAllFacebookAdsSortField,
+        sort_dir: crate::types::SortDir,
+    ) -> Result<crate::types::GetReportingFacebookAdsResponse>;
+    async fn get_facebook_ad(
+        &self,
+        fields: &[String],
+        outreach_id: &str,
+        exclude_fields: &[String],
+    ) -> Result<crate::types::GetReportingFacebookAdsResponseAllOf>;
+    async fn get_facebook_ads_ecommerce_product_activity(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        outreach_id: &str,
+        sort_field: crate::types::GetReportsEcommerceProductActivitySortField,
+    ) -> Result<crate::types::GetReportsEcommerceProductActivityResponse>;
+    async fn get_landing_page(
+        &self,
+        fields: &[String],
+        outreach_id: &str,
+        exclude_fields: &[String],
+    ) -> Result<crate::types::LandingPages>;
+    async fn get_landing_pages(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> Result<crate::types::GetReportingLandingPagesResponse>;
+}
 
-        self.client.get(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ReportingApi for Reporting {
+    async fn get_facebook_ads(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        sort_field: crate::types::GetAllFacebookAdsSortField,
+        sort_dir: crate::types::SortDir,
+    ) -> Result<crate::types::GetReportingFacebookAdsResponse> {
+        Reporting::get_facebook_ads(
+            self,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            sort_field,
+            sort_dir,
+        )
+        .await
+    }
+    async fn get_facebook_ad(
+        &self,
+        fields: &[String],
+        outreach_id: &str,
+        exclude_fields: &[String],
+    ) -> Result<crate::types::GetReportingFacebookAdsResponseAllOf> {
+        Reporting::get_facebook_ad(self, fields, outreach_id, exclude_fields).await
+    }
+    async fn get_facebook_ads_ecommerce_product_activity(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        outreach_id: &str,
+        sort_field: crate::types::GetReportsEcommerceProductActivitySortField,
+    ) -> Result<crate::types::GetReportsEcommerceProductActivityResponse> {
+        Reporting::get_facebook_ads_ecommerce_product_activity(
+            self,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            outreach_id,
+            sort_field,
+        )
+        .await
+    }
+    async fn get_landing_page(
+        &self,
+        fields: &[String],
+        outreach_id: &str,
+        exclude_fields: &[String],
+    ) -> Result<crate::types::LandingPages> {
+        Reporting::get_landing_page(self, fields, outreach_id, exclude_fields).await
+    }
+    async fn get_landing_pages(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> Result<crate::types::GetReportingLandingPagesResponse> {
+        Reporting::get_landing_pages(self, fields, exclude_fields, count, offset).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Reports {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Reports {
         Reports { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Reports {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * List campaign reports.
      *
@@ -39,32 +45,34 @@ impl Reports {
         before_send_time: Option<chrono::DateTime<chrono::Utc>>,
         since_send_time: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<crate::types::CampaignReportsData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if let Some(date) = before_send_time {
-            query_args.push(("before_send_time".to_string(), date.to_rfc3339()));
+            query_args.push(("before_send_time", date.to_rfc3339()));
         }
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if let Some(date) = since_send_time {
-            query_args.push(("since_send_time".to_string(), date.to_rfc3339()));
+            query_args.push(("since_send_time", date.to_rfc3339()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/reports?{}", query_);
+        let url = crate::Client::url_with_query("/reports", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/reports", &[]))
     }
 
     /**
@@ -86,21 +94,25 @@ impl Reports {
         exclude_fields: &[String],
         campaign_id: &str,
     ) -> Result<crate::types::Reports> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}?{}",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/reports/{}", self.client.encode_path(campaign_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/reports/{campaign_id}",
+                &[("campaign_id", campaign_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -122,21 +134,28 @@ impl Reports {
         exclude_fields: &[String],
         campaign_id: &str,
     ) -> Result<crate::types::AbuseComplaintsData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}/abuse-reports?{}",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
-            query_
+            query_args.push(("fields", fields.join(",")));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/reports/{}/abuse-reports",
+                self.client.encode_path(campaign_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/reports/{campaign_id}/abuse-reports",
+                &[("campaign_id", campaign_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -160,22 +179,32 @@ impl Reports {
         campaign_id: &str,
         report_id: &str,
     ) -> Result<crate::types::AbuseComplaint> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}/abuse-reports/{}?{}",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
-            crate::progenitor_support::encode_path(&report_id.to_string()),
-            query_
+            query_args.push(("fields", fields.join(",")));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/reports/{}/abuse-reports/{}",
+                self.client.encode_path(campaign_id),
+                self.client.encode_path(report_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/reports/{campaign_id}/abuse-reports/{report_id}",
+                &[
+                    ("campaign_id", campaign_id.to_string()),
+                    ("report_id", report_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -197,21 +226,25 @@ impl Reports {
         exclude_fields: &[String],
         campaign_id: &str,
     ) -> Result<crate::types::CampaignAdviceReport> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}/advice?{}",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/reports/{}/advice", self.client.encode_path(campaign_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/reports/{campaign_id}/advice",
+                &[("campaign_id", campaign_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -237,27 +270,34 @@ impl Reports {
         offset: i64,
         campaign_id: &str,
     ) -> Result<crate::types::ClickDetailReport> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}/click-details?{}",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
-            query_
+            query_args.push(("offset", offset.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/reports/{}/click-details",
+                self.client.encode_path(campaign_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/reports/{campaign_id}/click-details",
+                &[("campaign_id", campaign_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -281,22 +321,32 @@ impl Reports {
         campaign_id: &str,
         link_id: &str,
     ) -> Result<crate::types::UrlsClicked> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}/click-details/{}?{}",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
-            crate::progenitor_support::encode_path(&link_id.to_string()),
-            query_
+            query_args.push(("fields", fields.join(",")));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/reports/{}/click-details/{}",
+                self.client.encode_path(campaign_id),
+                self.client.encode_path(link_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/reports/{campaign_id}/click-details/{link_id}",
+                &[
+                    ("campaign_id", campaign_id.to_string()),
+                    ("link_id", link_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -324,28 +374,38 @@ impl Reports {
         campaign_id: &str,
         link_id: &str,
     ) -> Result<crate::types::ClickDetailMembers> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}/click-details/{}/members?{}",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
-            crate::progenitor_support::encode_path(&link_id.to_string()),
-            query_
+            query_args.push(("offset", offset.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/reports/{}/click-details/{}/members",
+                self.client.encode_path(campaign_id),
+                self.client.encode_path(link_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/reports/{campaign_id}/click-details/{link_id}/members",
+                &[
+                    ("campaign_id", campaign_id.to_string()),
+                    ("link_id", link_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -371,23 +431,34 @@ impl Reports {
         link_id: &str,
         subscriber_hash: &str,
     ) -> Result<crate::types::ClickDetailMember> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}/click-details/{}/members/{}?{}",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
-            crate::progenitor_support::encode_path(&link_id.to_string()),
-            crate::progenitor_support::encode_path(&subscriber_hash.to_string()),
-            query_
+            query_args.push(("fields", fields.join(",")));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/reports/{}/click-details/{}/members/{}",
+                self.client.encode_path(campaign_id),
+                self.client.encode_path(link_id),
+                self.client.encode_path(subscriber_hash)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/reports/{campaign_id}/click-details/{link_id}/members/{subscriber_hash}",
+                &[
+                    ("campaign_id", campaign_id.to_string()),
+                    ("link_id", link_id.to_string()),
+                    ("subscriber_hash", subscriber_hash.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -415,30 +486,37 @@ impl Reports {
         campaign_id: &str,
         since: &str,
     ) -> Result<crate::types::OpenDetailReport> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !since.is_empty() {
-            query_args.push(("since".to_string(), since.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}/open-details?{}",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
-            query_
+            query_args.push(("since", since.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/reports/{}/open-details",
+                self.client.encode_path(campaign_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/reports/{campaign_id}/open-details",
+                &[("campaign_id", campaign_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -462,22 +540,32 @@ impl Reports {
         campaign_id: &str,
         subscriber_hash: &str,
     ) -> Result<crate::types::OpenActivity> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}/open-details/{}?{}",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
-            crate::progenitor_support::encode_path(&subscriber_hash.to_string()),
-            query_
+            query_args.push(("fields", fields.join(",")));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/reports/{}/open-details/{}",
+                self.client.encode_path(campaign_id),
+                self.client.encode_path(subscriber_hash)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/reports/{campaign_id}/open-details/{subscriber_hash}",
+                &[
+                    ("campaign_id", campaign_id.to_string()),
+                    ("subscriber_hash", subscriber_hash.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -499,21 +587,28 @@ impl Reports {
         exclude_fields: &[String],
         campaign_id: &str,
     ) -> Result<crate::types::DomainPerformance> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}/domain-performance?{}",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
-            query_
+            query_args.push(("fields", fields.join(",")));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/reports/{}/domain-performance",
+                self.client.encode_path(campaign_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/reports/{campaign_id}/domain-performance",
+                &[("campaign_id", campaign_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -535,21 +630,25 @@ impl Reports {
         exclude_fields: &[String],
         campaign_id: &str,
     ) -> Result<crate::types::EepurlActivity> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}/eepurl?{}",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/reports/{}/eepurl", self.client.encode_path(campaign_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/reports/{campaign_id}/eepurl",
+                &[("campaign_id", campaign_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -577,30 +676,37 @@ impl Reports {
         campaign_id: &str,
         since: &str,
     ) -> Result<crate::types::EmailActivityData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !since.is_empty() {
-            query_args.push(("since".to_string(), since.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}/email-activity?{}",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
-            query_
+            query_args.push(("since", since.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/reports/{}/email-activity",
+                self.client.encode_path(campaign_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/reports/{campaign_id}/email-activity",
+                &[("campaign_id", campaign_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -626,25 +732,35 @@ impl Reports {
         subscriber_hash: &str,
         since: &str,
     ) -> Result<crate::types::EmailActivity> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if !since.is_empty() {
-            query_args.push(("since".to_string(), since.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}/email-activity/{}?{}",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
-            crate::progenitor_support::encode_path(&subscriber_hash.to_string()),
-            query_
+            query_args.push(("since", since.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/reports/{}/email-activity/{}",
+                self.client.encode_path(campaign_id),
+                self.client.encode_path(subscriber_hash)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/reports/{campaign_id}/email-activity/{subscriber_hash}",
+                &[
+                    ("campaign_id", campaign_id.to_string()),
+                    ("subscriber_hash", subscriber_hash.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -670,27 +786,34 @@ impl Reports {
         count: i64,
         offset: i64,
     ) -> Result<crate::types::OpenLocationsData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}/locations?{}",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
-            query_
+            query_args.push(("offset", offset.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/reports/{}/locations",
+                self.client.encode_path(campaign_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/reports/{campaign_id}/locations",
+                &[("campaign_id", campaign_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -716,27 +839,31 @@ impl Reports {
         offset: i64,
         campaign_id: &str,
     ) -> Result<crate::types::SentData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}/sent-to?{}",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/reports/{}/sent-to", self.client.encode_path(campaign_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/reports/{campaign_id}/sent-to",
+                &[("campaign_id", campaign_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -760,22 +887,32 @@ impl Reports {
         campaign_id: &str,
         subscriber_hash: &str,
     ) -> Result<crate::types::SentTo> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}/sent-to/{}?{}",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
-            crate::progenitor_support::encode_path(&subscriber_hash.to_string()),
-            query_
+            query_args.push(("fields", fields.join(",")));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/reports/{}/sent-to/{}",
+                self.client.encode_path(campaign_id),
+                self.client.encode_path(subscriber_hash)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/reports/{campaign_id}/sent-to/{subscriber_hash}",
+                &[
+                    ("campaign_id", campaign_id.to_string()),
+                    ("subscriber_hash", subscriber_hash.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -797,21 +934,28 @@ impl Reports {
         exclude_fields: &[String],
         campaign_id: &str,
     ) -> Result<crate::types::CampaignSubReports> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}/sub-reports?{}",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
-            query_
+            query_args.push(("fields", fields.join(",")));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/reports/{}/sub-reports",
+                self.client.encode_path(campaign_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/reports/{campaign_id}/sub-reports",
+                &[("campaign_id", campaign_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -837,27 +981,34 @@ impl Reports {
         offset: i64,
         campaign_id: &str,
     ) -> Result<crate::types::UnsubscribesData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}/unsubscribed?{}",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
-            query_
+            query_args.push(("offset", offset.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/reports/{}/unsubscribed",
+                self.client.encode_path(campaign_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/reports/{campaign_id}/unsubscribed",
+                &[("campaign_id", campaign_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -881,22 +1032,32 @@ impl Reports {
         campaign_id: &str,
         subscriber_hash: &str,
     ) -> Result<crate::types::Unsubscribes> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}/unsubscribed/{}?{}",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
-            crate::progenitor_support::encode_path(&subscriber_hash.to_string()),
-            query_
+            query_args.push(("fields", fields.join(",")));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/reports/{}/unsubscribed/{}",
+                self.client.encode_path(campaign_id),
+                self.client.encode_path(subscriber_hash)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/reports/{campaign_id}/unsubscribed/{subscriber_hash}",
+                &[
+                    ("campaign_id", campaign_id.to_string()),
+                    ("subscriber_hash", subscriber_hash.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -924,29 +1085,493 @@ impl Reports {
         campaign_id: &str,
         sort_field: crate::types::GetReportsEcommerceProductActivitySortField,
     ) -> Result<crate::types::GetReportsEcommerceProductActivityResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !sort_field.to_string().is_empty() {
-            query_args.push(("sort_field".to_string(), sort_field.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/reports/{}/ecommerce-product-activity?{}",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
-            query_
+            query_args.push(("sort_field", sort_field.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/reports/{}/ecommerce-product-activity",
+                self.client.encode_path(campaign_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/reports/{campaign_id}/ecommerce-product-activity",
+                &[("campaign_id", campaign_id.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`Reports`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ReportsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ReportsApi: Send + Sync {
+    async fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        type_: crate::types::CampaignType,
+        before_send_time: Option<chrono::DateTime<chrono::Utc>>,
+        since_send_time: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<crate::types::CampaignReportsData>;
+    async fn get_reports(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> Result<crate::types::Reports>;
+    async fn get_abuse(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> Result<crate::types::AbuseComplaintsData>;
+    async fn get_abuse_reports(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        report_id: &str,
+    ) -> Result<crate::types::AbuseComplaint>;
+    async fn get_advice(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> Result<crate::types::CampaignAdviceReport>;
+    async fn get_click_detail(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        campaign_id: &str,
+    ) -> Result<crate::types::ClickDetailReport>;
+    async fn get_click_detail_reports(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        link_id: &str,
+    ) -> Result<crate::types::UrlsClicked>;
+    async fn get_click_details_member(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        campaign_id: &str,
+        link_id: &str,
+    ) -> Result<crate::types::ClickDetailMembers>;
+    async fn get_click_details_member_reports(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        link_id: &str,
+        subscriber_hash: &str,
+    ) -> Result<crate::types::ClickDetailMember>;
+    async fn get_open_detail(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        campaign_id: &str,
+        since: &str,
+    ) -> Result<crate::types::OpenDetailReport>;
+    async fn get_open_details_member(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        subscriber_hash: &str,
+    ) -> Result<crate::types::OpenActivity>;
+    async fn get_domain_performance(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> Result<crate::types::DomainPerformance>;
+    async fn get_eepurl(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> Result<crate::types::EepurlActivity>;
+    async fn get_email_activity(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        campaign_id: &str,
+        since: &str,
+    ) -> Result<crate::types::EmailActivityData>;
+    async fn get_email_activity_reports(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        subscriber_hash: &str,
+        since: &str,
+    ) -> Result<crate::types::EmailActivity>;
+    async fn get_location(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        count: i64,
+        offset: i64,
+    ) -> Result<crate::types::OpenLocationsData>;
+    async fn get_sent(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        campaign_id: &str,
+    ) -> Result<crate::types::SentData>;
+    async fn get_sent_reports(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        subscriber_hash: &str,
+    ) -> Result<crate::types::SentTo>;
+    async fn get_sub(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> Result<crate::types::CampaignSubReports>;
+    async fn get_unsubscribed(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        campaign_id: &str,
+    ) -> Result<crate::types::UnsubscribesData>;
+    async fn get_unsubscribed_reports(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        subscriber_hash: &str,
+    ) -> Result<crate::types::Unsubscribes>;
+    async fn get_ecommerce_product_activity(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        campaign_id: &str,
+        sort_field: crate::types::GetReportsEcommerceProductActivitySortField,
+    ) -> Result<crate::types::GetReportsEcommerceProductActivityResponse>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ReportsApi for Reports {
+    async fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        type_: crate::types::CampaignType,
+        before_send_time: Option<chrono::DateTime<chrono::Utc>>,
+        since_send_time: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<crate::types::CampaignReportsData> {
+        Reports::get(
+            self,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            type_,
+            before_send_time,
+            since_send_time,
+        )
+        .await
+    }
+    async fn get_reports(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> Result<crate::types::Reports> {
+        Reports::get_reports(self, fields, exclude_fields, campaign_id).await
+    }
+    async fn get_abuse(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> Result<crate::types::AbuseComplaintsData> {
+        Reports::get_abuse(self, fields, exclude_fields, campaign_id).await
+    }
+    async fn get_abuse_reports(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        report_id: &str,
+    ) -> Result<crate::types::AbuseComplaint> {
+        Reports::get_abuse_reports(self, fields, exclude_fields, campaign_id, report_id).await
+    }
+    async fn get_advice(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> Result<crate::types::CampaignAdviceReport> {
+        Reports::get_advice(self, fields, exclude_fields, campaign_id).await
+    }
+    async fn get_click_detail(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        campaign_id: &str,
+    ) -> Result<crate::types::ClickDetailReport> {
+        Reports::get_click_detail(self, fields, exclude_fields, count, offset, campaign_id).await
+    }
+    async fn get_click_detail_reports(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        link_id: &str,
+    ) -> Result<crate::types::UrlsClicked> {
+        Reports::get_click_detail_reports(self, fields, exclude_fields, campaign_id, link_id).await
+    }
+    async fn get_click_details_member(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        campaign_id: &str,
+        link_id: &str,
+    ) -> Result<crate::types::ClickDetailMembers> {
+        Reports::get_click_details_member(
+            self,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            campaign_id,
+            link_id,
+        )
+        .await
+    }
+    async fn get_click_details_member_reports(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        link_id: &str,
+        subscriber_hash: &str,
+    ) -> Result<crate::types::ClickDetailMember> {
+        Reports::get_click_details_member_reports(
+            self,
+            fields,
+            exclude_fields,
+            campaign_id,
+            link_id,
+            subscriber_hash,
+        )
+        .await
+    }
+    async fn get_open_detail(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        campaign_id: &str,
+        since: &str,
+    ) -> Result<crate::types::OpenDetailReport> {
+        Reports::get_open_detail(
+            self,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            campaign_id,
+            since,
+        )
+        .await
+    }
+    async fn get_open_details_member(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        subscriber_hash: &str,
+    ) -> Result<crate::types::OpenActivity> {
+        Reports::get_open_details_member(self, fields, exclude_fields, campaign_id, subscriber_hash)
+            .await
+    }
+    async fn get_domain_performance(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> Result<crate::types::DomainPerformance> {
+        Reports::get_domain_performance(self, fields, exclude_fields, campaign_id).await
+    }
+    async fn get_eepurl(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> Result<crate::types::EepurlActivity> {
+        Reports::get_eepurl(self, fields, exclude_fields, campaign_id).await
+    }
+    async fn get_email_activity(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        campaign_id: &str,
+        since: &str,
+    ) -> Result<crate::types::EmailActivityData> {
+        Reports::get_email_activity(
+            self,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            campaign_id,
+            since,
+        )
+        .await
+    }
+    async fn get_email_activity_reports(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        subscriber_hash: &str,
+        since: &str,
+    ) -> Result<crate::types::EmailActivity> {
+        Reports::get_email_activity_reports(
+            self,
+            fields,
+            exclude_fields,
+            campaign_id,
+            subscriber_hash,
+            since,
+        )
+        .await
+    }
+    async fn get_location(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        count: i64,
+        offset: i64,
+    ) -> Result<crate::types::OpenLocationsData> {
+        Reports::get_location(self, fields, exclude_fields, campaign_id, count, offset).await
+    }
+    async fn get_sent(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        campaign_id: &str,
+    ) -> Result<crate::types::SentData> {
+        Reports::get_sent(self, fields, exclude_fields, count, offset, campaign_id).await
+    }
+    async fn get_sent_reports(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        subscriber_hash: &str,
+    ) -> Result<crate::types::SentTo> {
+        Reports::get_sent_reports(self, fields, exclude_fields, campaign_id, subscriber_hash).await
+    }
+    async fn get_sub(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> Result<crate::types::CampaignSubReports> {
+        Reports::get_sub(self, fields, exclude_fields, campaign_id).await
+    }
+    async fn get_unsubscribed(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        campaign_id: &str,
+    ) -> Result<crate::types::UnsubscribesData> {
+        Reports::get_unsubscribed(self, fields, exclude_fields, count, offset, campaign_id).await
+    }
+    async fn get_unsubscribed_reports(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        subscriber_hash: &str,
+    ) -> Result<crate::types::Unsubscribes> {
+        Reports::get_unsubscribed_reports(
+            self,
+            fields,
+            exclude_fields,
+            campaign_id,
+            subscriber_hash,
+        )
+        .await
+    }
+    async fn get_ecommerce_product_activity(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        campaign_id: &str,
+        sort_field: crate::types::GetReportsEcommerceProductActivitySortField,
+    ) -> Result<crate::types::GetReportsEcommerceProductActivityResponse> {
+        Reports::get_ecommerce_product_activity(
+            self,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            campaign_id,
+            sort_field,
+        )
+        .await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Root {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Root {
         Root { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Root {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * List api root resources.
      *
@@ -29,16 +35,43 @@ impl Root {
         fields: &[String],
         exclude_fields: &[String],
     ) -> Result<crate::types::ApiRoot> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("?{}", query_);
+        let url = crate::Client::url_with_query("", &query_args);
+
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/", &[]))
+    }
+}
+
+/// The functions of [`Root`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn RootApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait RootApi: Send + Sync {
+    async fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> Result<crate::types::ApiRoot>;
+}
 
-        self.client.get(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl RootApi for Root {
+    async fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> Result<crate::types::ApiRoot> {
+        Root::get(self, fields, exclude_fields).await
     }
 }
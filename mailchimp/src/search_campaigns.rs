@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct SearchCampaigns {
     pub client: Client,
@@ -12,6 +10,14 @@ impl SearchCampaigns {
         SearchCampaigns { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        SearchCampaigns {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Search campaigns.
      *
@@ -31,19 +37,48 @@ impl SearchCampaigns {
         exclude_fields: &[String],
         query: &str,
     ) -> Result<crate::types::Campaigns> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/search-campaigns?{}", query_);
+        let url = crate::Client::url_with_query("/search-campaigns", &query_args);
+
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/search-campaigns", &[]))
+    }
+}
+
+/// The functions of [`SearchCampaigns`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn SearchCampaignsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait SearchCampaignsApi: Send + Sync {
+    async fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        query: &str,
+    ) -> Result<crate::types::Campaigns>;
+}
 
-        self.client.get(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl SearchCampaignsApi for SearchCampaigns {
+    async fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        query: &str,
+    ) -> Result<crate::types::Campaigns> {
+        SearchCampaigns::get(self, fields, exclude_fields, query).await
     }
 }
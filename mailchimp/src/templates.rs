@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Templates {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Templates {
         Templates { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Templates {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * List templates.
      *
@@ -49,53 +55,49 @@ impl Templates {
         sort_field: crate::types::GetTemplatesSortField,
         sort_dir: crate::types::SortDir,
     ) -> Result<crate::types::TemplatesData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !before_date_created.is_empty() {
-            query_args.push((
-                "before_date_created".to_string(),
-                before_date_created.to_string(),
-            ));
+            query_args.push(("before_date_created", before_date_created.to_string()));
         }
         if !category.is_empty() {
-            query_args.push(("category".to_string(), category.to_string()));
+            query_args.push(("category", category.to_string()));
         }
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !created_by.is_empty() {
-            query_args.push(("created_by".to_string(), created_by.to_string()));
+            query_args.push(("created_by", created_by.to_string()));
         }
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
         if !folder_id.is_empty() {
-            query_args.push(("folder_id".to_string(), folder_id.to_string()));
+            query_args.push(("folder_id", folder_id.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !since_date_created.is_empty() {
-            query_args.push((
-                "since_date_created".to_string(),
-                since_date_created.to_string(),
-            ));
+            query_args.push(("since_date_created", since_date_created.to_string()));
         }
         if !sort_dir.to_string().is_empty() {
-            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+            query_args.push(("sort_dir", sort_dir.to_string()));
         }
         if !sort_field.to_string().is_empty() {
-            query_args.push(("sort_field".to_string(), sort_field.to_string()));
+            query_args.push(("sort_field", sort_field.to_string()));
         }
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/templates?{}", query_);
+        let url = crate::Client::url_with_query("/templates", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/templates", &[]))
     }
 
     /**
@@ -111,8 +113,9 @@ impl Templates {
     ) -> Result<crate::types::Templates> {
         let url = "/templates".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/templates", &[]))
     }
 
     /**
@@ -134,21 +137,25 @@ impl Templates {
         exclude_fields: &[String],
         template_id: &str,
     ) -> Result<crate::types::Templates> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/templates/{}?{}",
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/templates/{}", self.client.encode_path(template_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/templates/{template_id}",
+                &[("template_id", template_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -163,12 +170,37 @@ impl Templates {
      * * `template_id: &str` -- The unique id for the template.
      */
     pub async fn delete(&self, template_id: &str) -> Result<()> {
-        let url = format!(
-            "/templates/{}",
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-        );
+        let url = format!("/templates/{}", self.client.encode_path(template_id),);
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/templates/{template_id}",
+                &[("template_id", template_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete template.
+     *
+     * This function performs a `DELETE` to the `/templates/{template_id}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, template_id: &str) -> Result<bool> {
+        let url = format!("/templates/{}", self.client.encode_path(template_id),);
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/templates/{template_id}",
+                    &[("template_id", template_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -187,14 +219,45 @@ impl Templates {
         template_id: &str,
         body: &crate::types::TemplateInstance,
     ) -> Result<crate::types::Templates> {
-        let url = format!(
-            "/templates/{}",
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-        );
+        let url = format!("/templates/{}", self.client.encode_path(template_id),);
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/templates/{template_id}",
+                    &[("template_id", template_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update template.
+     *
+     * This function performs a `PATCH` to the `/templates/{template_id}` endpoint.
+     *
+     * As opposed to `patch`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_if_changed<C: serde::Serialize>(
+        &self,
+        template_id: &str,
+        current: &C,
+        body: &crate::types::TemplateInstance,
+    ) -> Result<Option<crate::types::Templates>> {
+        let url = format!("/templates/{}", self.client.encode_path(template_id),);
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/templates/{template_id}",
+                    &[("template_id", template_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -216,20 +279,139 @@ impl Templates {
         exclude_fields: &[String],
         template_id: &str,
     ) -> Result<crate::types::TemplateDefaultContent> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !exclude_fields.is_empty() {
-            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+            query_args.push(("exclude_fields", exclude_fields.join(",")));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.join(" ")));
+            query_args.push(("fields", fields.join(",")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/templates/{}/default-content?{}",
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/templates/{}/default-content",
+                self.client.encode_path(template_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/templates/{template_id}/default-content",
+                &[("template_id", template_id.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`Templates`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn TemplatesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait TemplatesApi: Send + Sync {
+    async fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        created_by: &str,
+        since_date_created: &str,
+        before_date_created: &str,
+        type_: &str,
+        category: &str,
+        folder_id: &str,
+        sort_field: crate::types::GetTemplatesSortField,
+        sort_dir: crate::types::SortDir,
+    ) -> Result<crate::types::TemplatesData>;
+    async fn post(&self, body: &crate::types::TemplateInstance) -> Result<crate::types::Templates>;
+    async fn get_templates(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        template_id: &str,
+    ) -> Result<crate::types::Templates>;
+    async fn delete(&self, template_id: &str) -> Result<()>;
+    async fn delete_if_exists(&self, template_id: &str) -> Result<bool>;
+    async fn patch(
+        &self,
+        template_id: &str,
+        body: &crate::types::TemplateInstance,
+    ) -> Result<crate::types::Templates>;
+    async fn get_default_content(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        template_id: &str,
+    ) -> Result<crate::types::TemplateDefaultContent>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl TemplatesApi for Templates {
+    async fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        created_by: &str,
+        since_date_created: &str,
+        before_date_created: &str,
+        type_: &str,
+        category: &str,
+        folder_id: &str,
+        sort_field: crate::types::GetTemplatesSortField,
+        sort_dir: crate::types::SortDir,
+    ) -> Result<crate::types::TemplatesData> {
+        Templates::get(
+            self,
+            fields,
+            exclude_fields,
+            count,
+            offset,
+            created_by,
+            since_date_created,
+            before_date_created,
+            type_,
+            category,
+            folder_id,
+            sort_field,
+            sort_dir,
+        )
+        .await
+    }
+    async fn post(&self, body: &crate::types::TemplateInstance) -> Result<crate::types::Templates> {
+        Templates::post(self, body).await
+    }
+    async fn get_templates(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        template_id: &str,
+    ) -> Result<crate::types::Templates> {
+        Templates::get_templates(self, fields, exclude_fields, template_id).await
+    }
+    async fn delete(&self, template_id: &str) -> Result<()> {
+        Templates::delete(self, template_id).await
+    }
+    async fn delete_if_exists(&self, template_id: &str) -> Result<bool> {
+        Templates::delete_if_exists(self, template_id).await
+    }
+    async fn patch(
+        &self,
+        template_id: &str,
+        body: &crate::types::TemplateInstance,
+    ) -> Result<crate::types::Templates> {
+        Templates::patch(self, template_id, body).await
+    }
+    async fn get_default_content(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        template_id: &str,
+    ) -> Result<crate::types::TemplateDefaultContent> {
+        Templates::get_default_content(self, fields, exclude_fields, template_id).await
     }
 }
@@ -1 +1,37 @@
+#[test]
+fn test_tagged_enum_round_trip() {
+    let condition = crate::types::Conditions {
+        condition_type: Some(crate::types::ConditionType::Aim),
+        field: Some(crate::types::Field::Aim),
+        op: Some(crate::types::Op::Click),
+        value: "x".to_string(),
+    };
+    let conditions = crate::types::ConditionsOneOf::Conditions(condition.clone());
 
+    // The enum writes the tag, once, and reads it back to the same variant,
+    // with the variant's own field for it.
+    let json = serde_json::to_string(&conditions).unwrap();
+    assert_eq!(
+        json,
+        r#"{"condition_type":"Aim","field":"aim","op":"click","value":"x"}"#
+    );
+    let parsed: crate::types::ConditionsOneOf = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, conditions);
+
+    // The variant on its own still writes its field.
+    assert_eq!(serde_json::to_string(&condition).unwrap(), json);
+
+    // The enum writes the tag of the variant even if the field is not set.
+    let unset = crate::types::ConditionsOneOf::Conditions(crate::types::Conditions {
+        condition_type: None,
+        ..condition
+    });
+    assert_eq!(serde_json::to_string(&unset).unwrap(), json);
+
+    // A value without a tag, or with one no variant has, is not read.
+    assert!(serde_json::from_str::<crate::types::ConditionsOneOf>(r#"{"value":"x"}"#).is_err());
+    assert!(serde_json::from_str::<crate::types::ConditionsOneOf>(
+        r#"{"condition_type":"Nope","value":"x"}"#
+    )
+    .is_err());
+}
@@ -2,6 +2,33 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+/// Either one of something, or many of them. Some endpoints send back an
+/// object when there is one and an array when there are more, so this takes both.
+// Many is tried first: serde reads a struct from an array of its fields too,
+// so an array would otherwise be taken for One.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum OneOrMany<T> {
+    Many(Vec<T>),
+    One(T),
+}
+
+impl<T> OneOrMany<T> {
+    /// All of them, as a vector.
+    pub fn into_vec(self) -> Vec<T> {
+        match self {
+            OneOrMany::One(t) => vec![t],
+            OneOrMany::Many(v) => v,
+        }
+    }
+}
+
+impl<T> Default for OneOrMany<T> {
+    fn default() -> Self {
+        OneOrMany::Many(Vec::new())
+    }
+}
+
 /// An individual folder listed in the File Manager.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GalleryFolder {
@@ -88,7 +115,7 @@ pub struct Interest {
 /**
  * Determines how this category’s interests appear on signup forms.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Type {
     #[serde(rename = "checkboxes")]
     Checkboxes,
@@ -123,7 +150,25 @@ impl Default for Type {
         Type::Noop
     }
 }
+
 impl Type {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Type::Checkboxes => "checkboxes",
+            Type::Dropdown => "dropdown",
+            Type::Hidden => "hidden",
+            Type::Radio => "radio",
+            Type::Noop => "",
+            Type::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Type] {
+        &[Type::Checkboxes, Type::Dropdown, Type::Hidden, Type::Radio]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Type::Noop)
     }
@@ -366,7 +411,7 @@ pub struct ECommerceProductVariant {
 /**
  * The type of pricing plan the account is on.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PricingPlanType {
     #[serde(rename = "forever_free")]
     ForeverFree,
@@ -398,7 +443,28 @@ impl Default for PricingPlanType {
         PricingPlanType::Noop
     }
 }
+
 impl PricingPlanType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PricingPlanType::ForeverFree => "forever_free",
+            PricingPlanType::Monthly => "monthly",
+            PricingPlanType::PayAsYouGo => "pay_as_you_go",
+            PricingPlanType::Noop => "",
+            PricingPlanType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PricingPlanType] {
+        &[
+            PricingPlanType::ForeverFree,
+            PricingPlanType::Monthly,
+            PricingPlanType::PayAsYouGo,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PricingPlanType::Noop)
     }
@@ -406,6 +472,7 @@ impl PricingPlanType {
 
 /// Information about the account contact.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Contact {
     /**
      * Information about the account contact.
@@ -413,8 +480,7 @@ pub struct Contact {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "addr1"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub addr_1: String,
     /**
@@ -423,8 +489,7 @@ pub struct Contact {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "addr2"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub addr_2: String,
     /**
@@ -509,7 +574,7 @@ pub struct IndustryStats {
 /**
  * The HTTP method that should be used when accessing the URL defined in 'href'.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Method {
     #[serde(rename = "DELETE")]
     Delete,
@@ -553,7 +618,36 @@ impl Default for Method {
         Method::Noop
     }
 }
+
 impl Method {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Method::Delete => "DELETE",
+            Method::Get => "GET",
+            Method::Head => "HEAD",
+            Method::Options => "OPTIONS",
+            Method::Patch => "PATCH",
+            Method::Post => "POST",
+            Method::Put => "PUT",
+            Method::Noop => "",
+            Method::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Method] {
+        &[
+            Method::Delete,
+            Method::Get,
+            Method::Head,
+            Method::Options,
+            Method::Patch,
+            Method::Post,
+            Method::Put,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Method::Noop)
     }
@@ -561,6 +655,7 @@ impl Method {
 
 /// This object represents a link from the resource where it is found to another resource or action that may be performed.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Links {
     /**
      * This object represents a link from the resource where it is found to another resource or action that may be performed.
@@ -600,8 +695,7 @@ pub struct Links {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "targetSchema"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub target_schema: String,
 }
@@ -782,7 +876,7 @@ pub struct ApiRoot {
 /**
  * The type of activity
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GetActivityFeedChimpChatterResponseType {
     #[serde(rename = "campaigns:facebook-likes")]
     CampaignsFacebookLikes,
@@ -827,7 +921,38 @@ impl Default for GetActivityFeedChimpChatterResponseType {
         GetActivityFeedChimpChatterResponseType::Noop
     }
 }
+
 impl GetActivityFeedChimpChatterResponseType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GetActivityFeedChimpChatterResponseType::CampaignsFacebookLikes => {
+                "campaigns:facebook-likes"
+            }
+            GetActivityFeedChimpChatterResponseType::CampaignsForwardToFriend => {
+                "campaigns:forward-to-friend"
+            }
+            GetActivityFeedChimpChatterResponseType::ListsImports => "lists:imports",
+            GetActivityFeedChimpChatterResponseType::ListsNewSubscriber => "lists:new-subscriber",
+            GetActivityFeedChimpChatterResponseType::ListsProfileUpdates => "lists:profile-updates",
+            GetActivityFeedChimpChatterResponseType::ListsUnsubscribes => "lists:unsubscribes",
+            GetActivityFeedChimpChatterResponseType::Noop => "",
+            GetActivityFeedChimpChatterResponseType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GetActivityFeedChimpChatterResponseType] {
+        &[
+            GetActivityFeedChimpChatterResponseType::CampaignsFacebookLikes,
+            GetActivityFeedChimpChatterResponseType::CampaignsForwardToFriend,
+            GetActivityFeedChimpChatterResponseType::ListsImports,
+            GetActivityFeedChimpChatterResponseType::ListsNewSubscriber,
+            GetActivityFeedChimpChatterResponseType::ListsProfileUpdates,
+            GetActivityFeedChimpChatterResponseType::ListsUnsubscribes,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, GetActivityFeedChimpChatterResponseType::Noop)
     }
@@ -1017,7 +1142,7 @@ pub struct GetAuthorizedAppsResponse {
 /**
  * Restrict the results to automations with the specified status.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Status {
     #[serde(rename = "paused")]
     Paused,
@@ -1049,7 +1174,24 @@ impl Default for Status {
         Status::Noop
     }
 }
+
 impl Status {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Status::Paused => "paused",
+            Status::Save => "save",
+            Status::Sending => "sending",
+            Status::Noop => "",
+            Status::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Status] {
+        &[Status::Paused, Status::Save, Status::Sending]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Status::Noop)
     }
@@ -1058,7 +1200,7 @@ impl Status {
 /**
  * Segment match type.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Match {
     #[serde(rename = "all")]
     All,
@@ -1087,13 +1229,29 @@ impl Default for Match {
         Match::Noop
     }
 }
+
 impl Match {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Match::All => "all",
+            Match::Any => "any",
+            Match::Noop => "",
+            Match::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Match] {
+        &[Match::All, Match::Any]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Match::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ConditionType {
     #[serde(rename = "Aim")]
     Aim,
@@ -1119,7 +1277,22 @@ impl Default for ConditionType {
         ConditionType::Noop
     }
 }
+
 impl ConditionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConditionType::Aim => "Aim",
+            ConditionType::Noop => "",
+            ConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ConditionType] {
+        &[ConditionType::Aim]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ConditionType::Noop)
     }
@@ -1128,7 +1301,7 @@ impl ConditionType {
 /**
  * Segment by interaction with a specific campaign.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Field {
     #[serde(rename = "aim")]
     Aim,
@@ -1154,7 +1327,22 @@ impl Default for Field {
         Field::Noop
     }
 }
+
 impl Field {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Field::Aim => "aim",
+            Field::Noop => "",
+            Field::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Field] {
+        &[Field::Aim]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Field::Noop)
     }
@@ -1163,7 +1351,7 @@ impl Field {
 /**
  * The status of the member with regard to their campaign interaction. One of the following: opened, clicked, was sent, didn't open, didn't click, or was not sent.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Op {
     #[serde(rename = "click")]
     Click,
@@ -1204,7 +1392,34 @@ impl Default for Op {
         Op::Noop
     }
 }
+
 impl Op {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Op::Click => "click",
+            Op::Noclick => "noclick",
+            Op::Noopen => "noopen",
+            Op::Nosent => "nosent",
+            Op::Open => "open",
+            Op::Sent => "sent",
+            Op::Noop => "",
+            Op::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Op] {
+        &[
+            Op::Click,
+            Op::Noclick,
+            Op::Noopen,
+            Op::Nosent,
+            Op::Open,
+            Op::Sent,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Op::Noop)
     }
@@ -1239,7 +1454,7 @@ pub struct Conditions {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum AutomationSegmentConditionType {
     #[serde(rename = "Automation")]
     Automation,
@@ -1265,7 +1480,22 @@ impl Default for AutomationSegmentConditionType {
         AutomationSegmentConditionType::Noop
     }
 }
+
 impl AutomationSegmentConditionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AutomationSegmentConditionType::Automation => "Automation",
+            AutomationSegmentConditionType::Noop => "",
+            AutomationSegmentConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [AutomationSegmentConditionType] {
+        &[AutomationSegmentConditionType::Automation]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, AutomationSegmentConditionType::Noop)
     }
@@ -1274,7 +1504,7 @@ impl AutomationSegmentConditionType {
 /**
  * Segment by interaction with an Automation workflow.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SegmentField {
     #[serde(rename = "automation")]
     Automation,
@@ -1300,7 +1530,22 @@ impl Default for SegmentField {
         SegmentField::Noop
     }
 }
+
 impl SegmentField {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SegmentField::Automation => "automation",
+            SegmentField::Noop => "",
+            SegmentField::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SegmentField] {
+        &[SegmentField::Automation]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SegmentField::Noop)
     }
@@ -1309,7 +1554,7 @@ impl SegmentField {
 /**
  * The status of the member with regard to the automation workflow. One of the following: has started the workflow, has completed the workflow, has not started the workflow, or has not completed the workflow.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SegmentOperator {
     #[serde(rename = "completed")]
     Completed,
@@ -1344,7 +1589,30 @@ impl Default for SegmentOperator {
         SegmentOperator::Noop
     }
 }
+
 impl SegmentOperator {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SegmentOperator::Completed => "completed",
+            SegmentOperator::NotCompleted => "not_completed",
+            SegmentOperator::NotStarted => "not_started",
+            SegmentOperator::Started => "started",
+            SegmentOperator::Noop => "",
+            SegmentOperator::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SegmentOperator] {
+        &[
+            SegmentOperator::Completed,
+            SegmentOperator::NotCompleted,
+            SegmentOperator::NotStarted,
+            SegmentOperator::Started,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SegmentOperator::Noop)
     }
@@ -1379,7 +1647,7 @@ pub struct AutomationSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PollActivitySegmentConditionType {
     #[serde(rename = "CampaignPoll")]
     CampaignPoll,
@@ -1405,7 +1673,22 @@ impl Default for PollActivitySegmentConditionType {
         PollActivitySegmentConditionType::Noop
     }
 }
+
 impl PollActivitySegmentConditionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PollActivitySegmentConditionType::CampaignPoll => "CampaignPoll",
+            PollActivitySegmentConditionType::Noop => "",
+            PollActivitySegmentConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PollActivitySegmentConditionType] {
+        &[PollActivitySegmentConditionType::CampaignPoll]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PollActivitySegmentConditionType::Noop)
     }
@@ -1414,7 +1697,7 @@ impl PollActivitySegmentConditionType {
 /**
  * Segment by poll activity.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PollActivitySegmentField {
     #[serde(rename = "poll")]
     Poll,
@@ -1440,7 +1723,22 @@ impl Default for PollActivitySegmentField {
         PollActivitySegmentField::Noop
     }
 }
+
 impl PollActivitySegmentField {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PollActivitySegmentField::Poll => "poll",
+            PollActivitySegmentField::Noop => "",
+            PollActivitySegmentField::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PollActivitySegmentField] {
+        &[PollActivitySegmentField::Poll]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PollActivitySegmentField::Noop)
     }
@@ -1449,7 +1747,7 @@ impl PollActivitySegmentField {
 /**
  * Members have/have not interacted with a specific poll in a Mailchimp email.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PollActivitySegmentOperator {
     #[serde(rename = "member")]
     Member,
@@ -1478,7 +1776,26 @@ impl Default for PollActivitySegmentOperator {
         PollActivitySegmentOperator::Noop
     }
 }
+
 impl PollActivitySegmentOperator {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PollActivitySegmentOperator::Member => "member",
+            PollActivitySegmentOperator::Notmember => "notmember",
+            PollActivitySegmentOperator::Noop => "",
+            PollActivitySegmentOperator::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PollActivitySegmentOperator] {
+        &[
+            PollActivitySegmentOperator::Member,
+            PollActivitySegmentOperator::Notmember,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PollActivitySegmentOperator::Noop)
     }
@@ -1513,7 +1830,7 @@ pub struct PollActivitySegment {
     pub value: f64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ConversationSegmentConditionType {
     #[serde(rename = "Conversation")]
     Conversation,
@@ -1539,7 +1856,22 @@ impl Default for ConversationSegmentConditionType {
         ConversationSegmentConditionType::Noop
     }
 }
+
 impl ConversationSegmentConditionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConversationSegmentConditionType::Conversation => "Conversation",
+            ConversationSegmentConditionType::Noop => "",
+            ConversationSegmentConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ConversationSegmentConditionType] {
+        &[ConversationSegmentConditionType::Conversation]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ConversationSegmentConditionType::Noop)
     }
@@ -1548,7 +1880,7 @@ impl ConversationSegmentConditionType {
 /**
  * Segment by interaction with a campaign via Conversations.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ConversationSegmentField {
     #[serde(rename = "conversation")]
     Conversation,
@@ -1574,7 +1906,22 @@ impl Default for ConversationSegmentField {
         ConversationSegmentField::Noop
     }
 }
+
 impl ConversationSegmentField {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConversationSegmentField::Conversation => "conversation",
+            ConversationSegmentField::Noop => "",
+            ConversationSegmentField::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ConversationSegmentField] {
+        &[ConversationSegmentField::Conversation]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ConversationSegmentField::Noop)
     }
@@ -1609,7 +1956,7 @@ pub struct ConversationSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum DateSegmentConditionType {
     #[serde(rename = "Date")]
     Date,
@@ -1635,7 +1982,22 @@ impl Default for DateSegmentConditionType {
         DateSegmentConditionType::Noop
     }
 }
+
 impl DateSegmentConditionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DateSegmentConditionType::Date => "Date",
+            DateSegmentConditionType::Noop => "",
+            DateSegmentConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [DateSegmentConditionType] {
+        &[DateSegmentConditionType::Date]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, DateSegmentConditionType::Noop)
     }
@@ -1644,7 +2006,7 @@ impl DateSegmentConditionType {
 /**
  * The type of date field to segment on: The opt-in time for a signup, the date the subscriber was last updated, or the date of their last ecomm purchase.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum DateSegmentField {
     #[serde(rename = "ecomm_date")]
     EcommDate,
@@ -1676,7 +2038,28 @@ impl Default for DateSegmentField {
         DateSegmentField::Noop
     }
 }
+
 impl DateSegmentField {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DateSegmentField::EcommDate => "ecomm_date",
+            DateSegmentField::InfoChanged => "info_changed",
+            DateSegmentField::TimestampOpt => "timestamp_opt",
+            DateSegmentField::Noop => "",
+            DateSegmentField::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [DateSegmentField] {
+        &[
+            DateSegmentField::EcommDate,
+            DateSegmentField::InfoChanged,
+            DateSegmentField::TimestampOpt,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, DateSegmentField::Noop)
     }
@@ -1685,7 +2068,7 @@ impl DateSegmentField {
 /**
  * When the event took place:  Before, after, is a specific date, is not a specific date, is blank, or is not blank.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum DateSegmentOperator {
     #[serde(rename = "blank")]
     Blank,
@@ -1732,9 +2115,40 @@ impl Default for DateSegmentOperator {
         DateSegmentOperator::Noop
     }
 }
+
 impl DateSegmentOperator {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, DateSegmentOperator::Noop)
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DateSegmentOperator::Blank => "blank",
+            DateSegmentOperator::BlankNot => "blank_not",
+            DateSegmentOperator::Greater => "greater",
+            DateSegmentOperator::Is => "is",
+            DateSegmentOperator::Less => "less",
+            DateSegmentOperator::Not => "not",
+            DateSegmentOperator::Notwithin => "notwithin",
+            DateSegmentOperator::Within => "within",
+            DateSegmentOperator::Noop => "",
+            DateSegmentOperator::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [DateSegmentOperator] {
+        &[
+            DateSegmentOperator::Blank,
+            DateSegmentOperator::BlankNot,
+            DateSegmentOperator::Greater,
+            DateSegmentOperator::Is,
+            DateSegmentOperator::Less,
+            DateSegmentOperator::Not,
+            DateSegmentOperator::Notwithin,
+            DateSegmentOperator::Within,
+        ]
+    }
+
+    pub fn is_noop(&self) -> bool {
+        matches!(self, DateSegmentOperator::Noop)
     }
 }
 
@@ -1776,7 +2190,7 @@ pub struct DateSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum EmailClientSegmentConditionType {
     #[serde(rename = "EmailClient")]
     EmailClient,
@@ -1802,7 +2216,22 @@ impl Default for EmailClientSegmentConditionType {
         EmailClientSegmentConditionType::Noop
     }
 }
+
 impl EmailClientSegmentConditionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EmailClientSegmentConditionType::EmailClient => "EmailClient",
+            EmailClientSegmentConditionType::Noop => "",
+            EmailClientSegmentConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [EmailClientSegmentConditionType] {
+        &[EmailClientSegmentConditionType::EmailClient]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, EmailClientSegmentConditionType::Noop)
     }
@@ -1811,7 +2240,7 @@ impl EmailClientSegmentConditionType {
 /**
  * Segment by use of a particular email client.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum EmailClientSegmentField {
     #[serde(rename = "email_client")]
     EmailClient,
@@ -1837,7 +2266,22 @@ impl Default for EmailClientSegmentField {
         EmailClientSegmentField::Noop
     }
 }
+
 impl EmailClientSegmentField {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EmailClientSegmentField::EmailClient => "email_client",
+            EmailClientSegmentField::Noop => "",
+            EmailClientSegmentField::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [EmailClientSegmentField] {
+        &[EmailClientSegmentField::EmailClient]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, EmailClientSegmentField::Noop)
     }
@@ -1846,7 +2290,7 @@ impl EmailClientSegmentField {
 /**
  * The operation to determine whether we select clients that match the value, or clients that do not match the value.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum EmailClientSegmentOperator {
     #[serde(rename = "client_is")]
     ClientIs,
@@ -1875,7 +2319,26 @@ impl Default for EmailClientSegmentOperator {
         EmailClientSegmentOperator::Noop
     }
 }
+
 impl EmailClientSegmentOperator {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EmailClientSegmentOperator::ClientIs => "client_is",
+            EmailClientSegmentOperator::ClientNot => "client_not",
+            EmailClientSegmentOperator::Noop => "",
+            EmailClientSegmentOperator::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [EmailClientSegmentOperator] {
+        &[
+            EmailClientSegmentOperator::ClientIs,
+            EmailClientSegmentOperator::ClientNot,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, EmailClientSegmentOperator::Noop)
     }
@@ -1910,7 +2373,7 @@ pub struct EmailClientSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum LanguageSegmentConditionType {
     #[serde(rename = "Language")]
     Language,
@@ -1936,7 +2399,22 @@ impl Default for LanguageSegmentConditionType {
         LanguageSegmentConditionType::Noop
     }
 }
+
 impl LanguageSegmentConditionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LanguageSegmentConditionType::Language => "Language",
+            LanguageSegmentConditionType::Noop => "",
+            LanguageSegmentConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [LanguageSegmentConditionType] {
+        &[LanguageSegmentConditionType::Language]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, LanguageSegmentConditionType::Noop)
     }
@@ -1945,7 +2423,7 @@ impl LanguageSegmentConditionType {
 /**
  * Segmenting based off of a subscriber's language.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum LanguageSegmentField {
     #[serde(rename = "language")]
     Language,
@@ -1971,7 +2449,22 @@ impl Default for LanguageSegmentField {
         LanguageSegmentField::Noop
     }
 }
+
 impl LanguageSegmentField {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LanguageSegmentField::Language => "language",
+            LanguageSegmentField::Noop => "",
+            LanguageSegmentField::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [LanguageSegmentField] {
+        &[LanguageSegmentField::Language]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, LanguageSegmentField::Noop)
     }
@@ -1980,7 +2473,7 @@ impl LanguageSegmentField {
 /**
  * Whether the member's language is or is not set to a specific language.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum LanguageSegmentOperator {
     #[serde(rename = "is")]
     Is,
@@ -2009,7 +2502,23 @@ impl Default for LanguageSegmentOperator {
         LanguageSegmentOperator::Noop
     }
 }
+
 impl LanguageSegmentOperator {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LanguageSegmentOperator::Is => "is",
+            LanguageSegmentOperator::Not => "not",
+            LanguageSegmentOperator::Noop => "",
+            LanguageSegmentOperator::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [LanguageSegmentOperator] {
+        &[LanguageSegmentOperator::Is, LanguageSegmentOperator::Not]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, LanguageSegmentOperator::Noop)
     }
@@ -2044,7 +2553,7 @@ pub struct LanguageSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum MemberRatingSegmentConditionType {
     #[serde(rename = "MemberRating")]
     MemberRating,
@@ -2070,7 +2579,22 @@ impl Default for MemberRatingSegmentConditionType {
         MemberRatingSegmentConditionType::Noop
     }
 }
+
 impl MemberRatingSegmentConditionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MemberRatingSegmentConditionType::MemberRating => "MemberRating",
+            MemberRatingSegmentConditionType::Noop => "",
+            MemberRatingSegmentConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [MemberRatingSegmentConditionType] {
+        &[MemberRatingSegmentConditionType::MemberRating]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, MemberRatingSegmentConditionType::Noop)
     }
@@ -2079,7 +2603,7 @@ impl MemberRatingSegmentConditionType {
 /**
  * Segment by member rating.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum MemberRatingSegmentField {
     #[serde(rename = "rating")]
     Rating,
@@ -2105,7 +2629,22 @@ impl Default for MemberRatingSegmentField {
         MemberRatingSegmentField::Noop
     }
 }
+
 impl MemberRatingSegmentField {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MemberRatingSegmentField::Rating => "rating",
+            MemberRatingSegmentField::Noop => "",
+            MemberRatingSegmentField::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [MemberRatingSegmentField] {
+        &[MemberRatingSegmentField::Rating]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, MemberRatingSegmentField::Noop)
     }
@@ -2114,7 +2653,7 @@ impl MemberRatingSegmentField {
 /**
  * Members who have have a rating that is/not exactly a given number or members who have a rating greater/less than a given number.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum MemberRatingSegmentOperator {
     #[serde(rename = "greater")]
     Greater,
@@ -2149,7 +2688,30 @@ impl Default for MemberRatingSegmentOperator {
         MemberRatingSegmentOperator::Noop
     }
 }
+
 impl MemberRatingSegmentOperator {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MemberRatingSegmentOperator::Greater => "greater",
+            MemberRatingSegmentOperator::Is => "is",
+            MemberRatingSegmentOperator::Less => "less",
+            MemberRatingSegmentOperator::Not => "not",
+            MemberRatingSegmentOperator::Noop => "",
+            MemberRatingSegmentOperator::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [MemberRatingSegmentOperator] {
+        &[
+            MemberRatingSegmentOperator::Greater,
+            MemberRatingSegmentOperator::Is,
+            MemberRatingSegmentOperator::Less,
+            MemberRatingSegmentOperator::Not,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, MemberRatingSegmentOperator::Noop)
     }
@@ -2184,7 +2746,7 @@ pub struct MemberRatingSegment {
     pub value: f64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SignupSourceSegmentType {
     #[serde(rename = "SignupSource")]
     SignupSource,
@@ -2210,13 +2772,28 @@ impl Default for SignupSourceSegmentType {
         SignupSourceSegmentType::Noop
     }
 }
+
 impl SignupSourceSegmentType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SignupSourceSegmentType::SignupSource => "SignupSource",
+            SignupSourceSegmentType::Noop => "",
+            SignupSourceSegmentType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SignupSourceSegmentType] {
+        &[SignupSourceSegmentType::SignupSource]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SignupSourceSegmentType::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SignupSourceSegmentField {
     #[serde(rename = "source")]
     Source,
@@ -2242,7 +2819,22 @@ impl Default for SignupSourceSegmentField {
         SignupSourceSegmentField::Noop
     }
 }
+
 impl SignupSourceSegmentField {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SignupSourceSegmentField::Source => "source",
+            SignupSourceSegmentField::Noop => "",
+            SignupSourceSegmentField::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SignupSourceSegmentField] {
+        &[SignupSourceSegmentField::Source]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SignupSourceSegmentField::Noop)
     }
@@ -2251,7 +2843,7 @@ impl SignupSourceSegmentField {
 /**
  * Whether the member's signup source was/was not a particular value.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SignupSourceSegmentOperator {
     #[serde(rename = "source_is")]
     SourceIs,
@@ -2280,7 +2872,26 @@ impl Default for SignupSourceSegmentOperator {
         SignupSourceSegmentOperator::Noop
     }
 }
+
 impl SignupSourceSegmentOperator {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SignupSourceSegmentOperator::SourceIs => "source_is",
+            SignupSourceSegmentOperator::SourceNot => "source_not",
+            SignupSourceSegmentOperator::Noop => "",
+            SignupSourceSegmentOperator::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SignupSourceSegmentOperator] {
+        &[
+            SignupSourceSegmentOperator::SourceIs,
+            SignupSourceSegmentOperator::SourceNot,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SignupSourceSegmentOperator::Noop)
     }
@@ -2309,7 +2920,7 @@ pub struct SignupSourceSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SurveyMonkeySegmentConditionType {
     #[serde(rename = "SurveyMonkey")]
     SurveyMonkey,
@@ -2335,7 +2946,22 @@ impl Default for SurveyMonkeySegmentConditionType {
         SurveyMonkeySegmentConditionType::Noop
     }
 }
+
 impl SurveyMonkeySegmentConditionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SurveyMonkeySegmentConditionType::SurveyMonkey => "SurveyMonkey",
+            SurveyMonkeySegmentConditionType::Noop => "",
+            SurveyMonkeySegmentConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SurveyMonkeySegmentConditionType] {
+        &[SurveyMonkeySegmentConditionType::SurveyMonkey]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SurveyMonkeySegmentConditionType::Noop)
     }
@@ -2344,7 +2970,7 @@ impl SurveyMonkeySegmentConditionType {
 /**
  * Segment by interaction with a SurveyMonkey survey.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SurveyMonkeySegmentField {
     #[serde(rename = "survey_monkey")]
     SurveyMonkey,
@@ -2370,7 +2996,22 @@ impl Default for SurveyMonkeySegmentField {
         SurveyMonkeySegmentField::Noop
     }
 }
+
 impl SurveyMonkeySegmentField {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SurveyMonkeySegmentField::SurveyMonkey => "survey_monkey",
+            SurveyMonkeySegmentField::Noop => "",
+            SurveyMonkeySegmentField::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SurveyMonkeySegmentField] {
+        &[SurveyMonkeySegmentField::SurveyMonkey]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SurveyMonkeySegmentField::Noop)
     }
@@ -2405,7 +3046,7 @@ pub struct SurveyMonkeySegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum VipSegmentConditionType {
     #[serde(rename = "VIP")]
     Vip,
@@ -2431,7 +3072,22 @@ impl Default for VipSegmentConditionType {
         VipSegmentConditionType::Noop
     }
 }
+
 impl VipSegmentConditionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VipSegmentConditionType::Vip => "VIP",
+            VipSegmentConditionType::Noop => "",
+            VipSegmentConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [VipSegmentConditionType] {
+        &[VipSegmentConditionType::Vip]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, VipSegmentConditionType::Noop)
     }
@@ -2440,7 +3096,7 @@ impl VipSegmentConditionType {
 /**
  * Segment by VIP status.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum VipSegmentField {
     #[serde(rename = "gmonkey")]
     Gmonkey,
@@ -2466,7 +3122,22 @@ impl Default for VipSegmentField {
         VipSegmentField::Noop
     }
 }
+
 impl VipSegmentField {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VipSegmentField::Gmonkey => "gmonkey",
+            VipSegmentField::Noop => "",
+            VipSegmentField::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [VipSegmentField] {
+        &[VipSegmentField::Gmonkey]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, VipSegmentField::Noop)
     }
@@ -2492,7 +3163,7 @@ pub struct VipSegment {
     pub op: PollActivitySegmentOperator,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum InterestsSegmentConditionType {
     #[serde(rename = "Interests")]
     Interests,
@@ -2518,7 +3189,22 @@ impl Default for InterestsSegmentConditionType {
         InterestsSegmentConditionType::Noop
     }
 }
+
 impl InterestsSegmentConditionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InterestsSegmentConditionType::Interests => "Interests",
+            InterestsSegmentConditionType::Noop => "",
+            InterestsSegmentConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [InterestsSegmentConditionType] {
+        &[InterestsSegmentConditionType::Interests]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, InterestsSegmentConditionType::Noop)
     }
@@ -2527,7 +3213,7 @@ impl InterestsSegmentConditionType {
 /**
  * Whether the member is a part of one, all, or none of the groups.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum InterestsSegmentOperator {
     #[serde(rename = "interestcontains")]
     Interestcontains,
@@ -2559,7 +3245,28 @@ impl Default for InterestsSegmentOperator {
         InterestsSegmentOperator::Noop
     }
 }
+
 impl InterestsSegmentOperator {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InterestsSegmentOperator::Interestcontains => "interestcontains",
+            InterestsSegmentOperator::Interestcontainsall => "interestcontainsall",
+            InterestsSegmentOperator::Interestnotcontains => "interestnotcontains",
+            InterestsSegmentOperator::Noop => "",
+            InterestsSegmentOperator::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [InterestsSegmentOperator] {
+        &[
+            InterestsSegmentOperator::Interestcontains,
+            InterestsSegmentOperator::Interestcontainsall,
+            InterestsSegmentOperator::Interestnotcontains,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, InterestsSegmentOperator::Noop)
     }
@@ -2598,7 +3305,7 @@ pub struct InterestsSegment {
     pub value: Vec<String>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum EcommerceCategorySegmentConditionType {
     #[serde(rename = "EcommCategory")]
     EcommCategory,
@@ -2624,7 +3331,22 @@ impl Default for EcommerceCategorySegmentConditionType {
         EcommerceCategorySegmentConditionType::Noop
     }
 }
+
 impl EcommerceCategorySegmentConditionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EcommerceCategorySegmentConditionType::EcommCategory => "EcommCategory",
+            EcommerceCategorySegmentConditionType::Noop => "",
+            EcommerceCategorySegmentConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [EcommerceCategorySegmentConditionType] {
+        &[EcommerceCategorySegmentConditionType::EcommCategory]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, EcommerceCategorySegmentConditionType::Noop)
     }
@@ -2633,7 +3355,7 @@ impl EcommerceCategorySegmentConditionType {
 /**
  * Segment by purchases in specific items or categories.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum EcommerceCategorySegmentField {
     #[serde(rename = "ecomm_cat")]
     EcommCat,
@@ -2662,7 +3384,26 @@ impl Default for EcommerceCategorySegmentField {
         EcommerceCategorySegmentField::Noop
     }
 }
+
 impl EcommerceCategorySegmentField {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EcommerceCategorySegmentField::EcommCat => "ecomm_cat",
+            EcommerceCategorySegmentField::EcommProd => "ecomm_prod",
+            EcommerceCategorySegmentField::Noop => "",
+            EcommerceCategorySegmentField::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [EcommerceCategorySegmentField] {
+        &[
+            EcommerceCategorySegmentField::EcommCat,
+            EcommerceCategorySegmentField::EcommProd,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, EcommerceCategorySegmentField::Noop)
     }
@@ -2671,7 +3412,7 @@ impl EcommerceCategorySegmentField {
 /**
  * A member who has purchased from a category/specific item that is/is not a specific name, where the category/item name contains/doesn't contain a specific phrase or string, or a category/item name that starts/ends with a string.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum EcommerceCategorySegmentOperator {
     #[serde(rename = "contains")]
     Contains,
@@ -2712,7 +3453,34 @@ impl Default for EcommerceCategorySegmentOperator {
         EcommerceCategorySegmentOperator::Noop
     }
 }
+
 impl EcommerceCategorySegmentOperator {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EcommerceCategorySegmentOperator::Contains => "contains",
+            EcommerceCategorySegmentOperator::Ends => "ends",
+            EcommerceCategorySegmentOperator::Is => "is",
+            EcommerceCategorySegmentOperator::Not => "not",
+            EcommerceCategorySegmentOperator::Notcontain => "notcontain",
+            EcommerceCategorySegmentOperator::Starts => "starts",
+            EcommerceCategorySegmentOperator::Noop => "",
+            EcommerceCategorySegmentOperator::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [EcommerceCategorySegmentOperator] {
+        &[
+            EcommerceCategorySegmentOperator::Contains,
+            EcommerceCategorySegmentOperator::Ends,
+            EcommerceCategorySegmentOperator::Is,
+            EcommerceCategorySegmentOperator::Not,
+            EcommerceCategorySegmentOperator::Notcontain,
+            EcommerceCategorySegmentOperator::Starts,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, EcommerceCategorySegmentOperator::Noop)
     }
@@ -2747,7 +3515,7 @@ pub struct EcommerceCategorySegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum EcommerceNumberSegmentConditionType {
     #[serde(rename = "EcommNumber")]
     EcommNumber,
@@ -2773,7 +3541,22 @@ impl Default for EcommerceNumberSegmentConditionType {
         EcommerceNumberSegmentConditionType::Noop
     }
 }
+
 impl EcommerceNumberSegmentConditionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EcommerceNumberSegmentConditionType::EcommNumber => "EcommNumber",
+            EcommerceNumberSegmentConditionType::Noop => "",
+            EcommerceNumberSegmentConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [EcommerceNumberSegmentConditionType] {
+        &[EcommerceNumberSegmentConditionType::EcommNumber]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, EcommerceNumberSegmentConditionType::Noop)
     }
@@ -2782,7 +3565,7 @@ impl EcommerceNumberSegmentConditionType {
 /**
  * Segment by average spent total, number of orders, total number of products purchased, or average number of products per order.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum EcommerceNumberSegmentField {
     #[serde(rename = "ecomm_avg_ord")]
     EcommAvgOrd,
@@ -2817,7 +3600,30 @@ impl Default for EcommerceNumberSegmentField {
         EcommerceNumberSegmentField::Noop
     }
 }
+
 impl EcommerceNumberSegmentField {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EcommerceNumberSegmentField::EcommAvgOrd => "ecomm_avg_ord",
+            EcommerceNumberSegmentField::EcommOrders => "ecomm_orders",
+            EcommerceNumberSegmentField::EcommProdAll => "ecomm_prod_all",
+            EcommerceNumberSegmentField::EcommSpentAvg => "ecomm_spent_avg",
+            EcommerceNumberSegmentField::Noop => "",
+            EcommerceNumberSegmentField::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [EcommerceNumberSegmentField] {
+        &[
+            EcommerceNumberSegmentField::EcommAvgOrd,
+            EcommerceNumberSegmentField::EcommOrders,
+            EcommerceNumberSegmentField::EcommProdAll,
+            EcommerceNumberSegmentField::EcommSpentAvg,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, EcommerceNumberSegmentField::Noop)
     }
@@ -2852,7 +3658,7 @@ pub struct EcommerceNumberSegment {
     pub value: f64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum EcommercePurchasedSegmentConditionType {
     #[serde(rename = "EcommPurchased")]
     EcommPurchased,
@@ -2878,8 +3684,23 @@ impl Default for EcommercePurchasedSegmentConditionType {
         EcommercePurchasedSegmentConditionType::Noop
     }
 }
+
 impl EcommercePurchasedSegmentConditionType {
-    pub fn is_noop(&self) -> bool {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EcommercePurchasedSegmentConditionType::EcommPurchased => "EcommPurchased",
+            EcommercePurchasedSegmentConditionType::Noop => "",
+            EcommercePurchasedSegmentConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [EcommercePurchasedSegmentConditionType] {
+        &[EcommercePurchasedSegmentConditionType::EcommPurchased]
+    }
+
+    pub fn is_noop(&self) -> bool {
         matches!(self, EcommercePurchasedSegmentConditionType::Noop)
     }
 }
@@ -2887,7 +3708,7 @@ impl EcommercePurchasedSegmentConditionType {
 /**
  * Segment by whether someone has purchased anything.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum EcommercePurchasedSegmentField {
     #[serde(rename = "ecomm_purchased")]
     EcommPurchased,
@@ -2913,7 +3734,22 @@ impl Default for EcommercePurchasedSegmentField {
         EcommercePurchasedSegmentField::Noop
     }
 }
+
 impl EcommercePurchasedSegmentField {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EcommercePurchasedSegmentField::EcommPurchased => "ecomm_purchased",
+            EcommercePurchasedSegmentField::Noop => "",
+            EcommercePurchasedSegmentField::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [EcommercePurchasedSegmentField] {
+        &[EcommercePurchasedSegmentField::EcommPurchased]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, EcommercePurchasedSegmentField::Noop)
     }
@@ -2939,7 +3775,7 @@ pub struct EcommercePurchasedSegment {
     pub op: Option<PollActivitySegmentOperator>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum EcommerceSpentSegmentConditionType {
     #[serde(rename = "EcommSpent")]
     EcommSpent,
@@ -2965,7 +3801,22 @@ impl Default for EcommerceSpentSegmentConditionType {
         EcommerceSpentSegmentConditionType::Noop
     }
 }
+
 impl EcommerceSpentSegmentConditionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EcommerceSpentSegmentConditionType::EcommSpent => "EcommSpent",
+            EcommerceSpentSegmentConditionType::Noop => "",
+            EcommerceSpentSegmentConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [EcommerceSpentSegmentConditionType] {
+        &[EcommerceSpentSegmentConditionType::EcommSpent]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, EcommerceSpentSegmentConditionType::Noop)
     }
@@ -2974,7 +3825,7 @@ impl EcommerceSpentSegmentConditionType {
 /**
  * Segment by amount spent on a single order or across all orders.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum EcommerceSpentSegmentField {
     #[serde(rename = "ecomm_spent_all")]
     EcommSpentAll,
@@ -3003,7 +3854,26 @@ impl Default for EcommerceSpentSegmentField {
         EcommerceSpentSegmentField::Noop
     }
 }
+
 impl EcommerceSpentSegmentField {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EcommerceSpentSegmentField::EcommSpentAll => "ecomm_spent_all",
+            EcommerceSpentSegmentField::EcommSpentOne => "ecomm_spent_one",
+            EcommerceSpentSegmentField::Noop => "",
+            EcommerceSpentSegmentField::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [EcommerceSpentSegmentField] {
+        &[
+            EcommerceSpentSegmentField::EcommSpentAll,
+            EcommerceSpentSegmentField::EcommSpentOne,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, EcommerceSpentSegmentField::Noop)
     }
@@ -3012,7 +3882,7 @@ impl EcommerceSpentSegmentField {
 /**
  * Members who have spent 'more' or 'less' than then specified value.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum EcommerceSpentSegmentOperator {
     #[serde(rename = "greater")]
     Greater,
@@ -3041,7 +3911,26 @@ impl Default for EcommerceSpentSegmentOperator {
         EcommerceSpentSegmentOperator::Noop
     }
 }
+
 impl EcommerceSpentSegmentOperator {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EcommerceSpentSegmentOperator::Greater => "greater",
+            EcommerceSpentSegmentOperator::Less => "less",
+            EcommerceSpentSegmentOperator::Noop => "",
+            EcommerceSpentSegmentOperator::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [EcommerceSpentSegmentOperator] {
+        &[
+            EcommerceSpentSegmentOperator::Greater,
+            EcommerceSpentSegmentOperator::Less,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, EcommerceSpentSegmentOperator::Noop)
     }
@@ -3076,7 +3965,7 @@ pub struct EcommerceSpentSegment {
     pub value: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum EcommercePurchasedStoreSegmentConditionType {
     #[serde(rename = "EcommStore")]
     EcommStore,
@@ -3102,7 +3991,22 @@ impl Default for EcommercePurchasedStoreSegmentConditionType {
         EcommercePurchasedStoreSegmentConditionType::Noop
     }
 }
+
 impl EcommercePurchasedStoreSegmentConditionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EcommercePurchasedStoreSegmentConditionType::EcommStore => "EcommStore",
+            EcommercePurchasedStoreSegmentConditionType::Noop => "",
+            EcommercePurchasedStoreSegmentConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [EcommercePurchasedStoreSegmentConditionType] {
+        &[EcommercePurchasedStoreSegmentConditionType::EcommStore]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, EcommercePurchasedStoreSegmentConditionType::Noop)
     }
@@ -3111,7 +4015,7 @@ impl EcommercePurchasedStoreSegmentConditionType {
 /**
  * Segment by purchases from a specific store.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum EcommercePurchasedStoreSegmentField {
     #[serde(rename = "ecomm_store")]
     EcommStore,
@@ -3137,7 +4041,22 @@ impl Default for EcommercePurchasedStoreSegmentField {
         EcommercePurchasedStoreSegmentField::Noop
     }
 }
+
 impl EcommercePurchasedStoreSegmentField {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EcommercePurchasedStoreSegmentField::EcommStore => "ecomm_store",
+            EcommercePurchasedStoreSegmentField::Noop => "",
+            EcommercePurchasedStoreSegmentField::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [EcommercePurchasedStoreSegmentField] {
+        &[EcommercePurchasedStoreSegmentField::EcommStore]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, EcommercePurchasedStoreSegmentField::Noop)
     }
@@ -3172,7 +4091,7 @@ pub struct EcommercePurchasedStoreSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GoalActivitySegmentConditionType {
     #[serde(rename = "GoalActivity")]
     GoalActivity,
@@ -3198,7 +4117,22 @@ impl Default for GoalActivitySegmentConditionType {
         GoalActivitySegmentConditionType::Noop
     }
 }
+
 impl GoalActivitySegmentConditionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GoalActivitySegmentConditionType::GoalActivity => "GoalActivity",
+            GoalActivitySegmentConditionType::Noop => "",
+            GoalActivitySegmentConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GoalActivitySegmentConditionType] {
+        &[GoalActivitySegmentConditionType::GoalActivity]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, GoalActivitySegmentConditionType::Noop)
     }
@@ -3207,7 +4141,7 @@ impl GoalActivitySegmentConditionType {
 /**
  * Segment by Goal activity.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GoalActivitySegmentField {
     #[serde(rename = "goal")]
     Goal,
@@ -3233,7 +4167,22 @@ impl Default for GoalActivitySegmentField {
         GoalActivitySegmentField::Noop
     }
 }
+
 impl GoalActivitySegmentField {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GoalActivitySegmentField::Goal => "goal",
+            GoalActivitySegmentField::Noop => "",
+            GoalActivitySegmentField::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GoalActivitySegmentField] {
+        &[GoalActivitySegmentField::Goal]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, GoalActivitySegmentField::Noop)
     }
@@ -3242,7 +4191,7 @@ impl GoalActivitySegmentField {
 /**
  * Whether the website URL is/not exactly, contains/doesn't contain, starts with/ends with a string.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GoalActivitySegmentOperator {
     #[serde(rename = "contains")]
     Contains,
@@ -3283,7 +4232,34 @@ impl Default for GoalActivitySegmentOperator {
         GoalActivitySegmentOperator::Noop
     }
 }
+
 impl GoalActivitySegmentOperator {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GoalActivitySegmentOperator::Contains => "contains",
+            GoalActivitySegmentOperator::Ends => "ends",
+            GoalActivitySegmentOperator::GoalNot => "goal_not",
+            GoalActivitySegmentOperator::GoalNotcontain => "goal_notcontain",
+            GoalActivitySegmentOperator::Is => "is",
+            GoalActivitySegmentOperator::Starts => "starts",
+            GoalActivitySegmentOperator::Noop => "",
+            GoalActivitySegmentOperator::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GoalActivitySegmentOperator] {
+        &[
+            GoalActivitySegmentOperator::Contains,
+            GoalActivitySegmentOperator::Ends,
+            GoalActivitySegmentOperator::GoalNot,
+            GoalActivitySegmentOperator::GoalNotcontain,
+            GoalActivitySegmentOperator::Is,
+            GoalActivitySegmentOperator::Starts,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, GoalActivitySegmentOperator::Noop)
     }
@@ -3318,7 +4294,7 @@ pub struct GoalActivitySegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GoalTimestampSegmentConditionType {
     #[serde(rename = "GoalTimestamp")]
     GoalTimestamp,
@@ -3344,7 +4320,22 @@ impl Default for GoalTimestampSegmentConditionType {
         GoalTimestampSegmentConditionType::Noop
     }
 }
+
 impl GoalTimestampSegmentConditionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GoalTimestampSegmentConditionType::GoalTimestamp => "GoalTimestamp",
+            GoalTimestampSegmentConditionType::Noop => "",
+            GoalTimestampSegmentConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GoalTimestampSegmentConditionType] {
+        &[GoalTimestampSegmentConditionType::GoalTimestamp]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, GoalTimestampSegmentConditionType::Noop)
     }
@@ -3353,7 +4344,7 @@ impl GoalTimestampSegmentConditionType {
 /**
  * Segment by most recent interaction with a website.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GoalTimestampSegmentField {
     #[serde(rename = "goal_last_visited")]
     GoalLastVisited,
@@ -3379,7 +4370,22 @@ impl Default for GoalTimestampSegmentField {
         GoalTimestampSegmentField::Noop
     }
 }
+
 impl GoalTimestampSegmentField {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GoalTimestampSegmentField::GoalLastVisited => "goal_last_visited",
+            GoalTimestampSegmentField::Noop => "",
+            GoalTimestampSegmentField::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GoalTimestampSegmentField] {
+        &[GoalTimestampSegmentField::GoalLastVisited]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, GoalTimestampSegmentField::Noop)
     }
@@ -3388,7 +4394,7 @@ impl GoalTimestampSegmentField {
 /**
  * Whether the website activity happened after, before, or at a given timestamp.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GoalTimestampSegmentOperator {
     #[serde(rename = "greater")]
     Greater,
@@ -3420,7 +4426,28 @@ impl Default for GoalTimestampSegmentOperator {
         GoalTimestampSegmentOperator::Noop
     }
 }
+
 impl GoalTimestampSegmentOperator {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GoalTimestampSegmentOperator::Greater => "greater",
+            GoalTimestampSegmentOperator::Is => "is",
+            GoalTimestampSegmentOperator::Less => "less",
+            GoalTimestampSegmentOperator::Noop => "",
+            GoalTimestampSegmentOperator::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GoalTimestampSegmentOperator] {
+        &[
+            GoalTimestampSegmentOperator::Greater,
+            GoalTimestampSegmentOperator::Is,
+            GoalTimestampSegmentOperator::Less,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, GoalTimestampSegmentOperator::Noop)
     }
@@ -3455,7 +4482,7 @@ pub struct GoalTimestampSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SimilarSubscribersSegmentMemberConditionType {
     #[serde(rename = "FuzzySegment")]
     FuzzySegment,
@@ -3481,7 +4508,22 @@ impl Default for SimilarSubscribersSegmentMemberConditionType {
         SimilarSubscribersSegmentMemberConditionType::Noop
     }
 }
+
 impl SimilarSubscribersSegmentMemberConditionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SimilarSubscribersSegmentMemberConditionType::FuzzySegment => "FuzzySegment",
+            SimilarSubscribersSegmentMemberConditionType::Noop => "",
+            SimilarSubscribersSegmentMemberConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SimilarSubscribersSegmentMemberConditionType] {
+        &[SimilarSubscribersSegmentMemberConditionType::FuzzySegment]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SimilarSubscribersSegmentMemberConditionType::Noop)
     }
@@ -3490,7 +4532,7 @@ impl SimilarSubscribersSegmentMemberConditionType {
 /**
  * Segment by similar subscribers.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SimilarSubscribersSegmentMemberField {
     #[serde(rename = "fuzzy_segment")]
     FuzzySegment,
@@ -3516,7 +4558,22 @@ impl Default for SimilarSubscribersSegmentMemberField {
         SimilarSubscribersSegmentMemberField::Noop
     }
 }
+
 impl SimilarSubscribersSegmentMemberField {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SimilarSubscribersSegmentMemberField::FuzzySegment => "fuzzy_segment",
+            SimilarSubscribersSegmentMemberField::Noop => "",
+            SimilarSubscribersSegmentMemberField::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SimilarSubscribersSegmentMemberField] {
+        &[SimilarSubscribersSegmentMemberField::FuzzySegment]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SimilarSubscribersSegmentMemberField::Noop)
     }
@@ -3525,7 +4582,7 @@ impl SimilarSubscribersSegmentMemberField {
 /**
  * Members who are/are not apart of a 'similar subscribers' segment.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SimilarSubscribersSegmentMemberOperator {
     #[serde(rename = "fuzzy_is")]
     FuzzyIs,
@@ -3554,7 +4611,26 @@ impl Default for SimilarSubscribersSegmentMemberOperator {
         SimilarSubscribersSegmentMemberOperator::Noop
     }
 }
+
 impl SimilarSubscribersSegmentMemberOperator {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SimilarSubscribersSegmentMemberOperator::FuzzyIs => "fuzzy_is",
+            SimilarSubscribersSegmentMemberOperator::FuzzyNot => "fuzzy_not",
+            SimilarSubscribersSegmentMemberOperator::Noop => "",
+            SimilarSubscribersSegmentMemberOperator::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SimilarSubscribersSegmentMemberOperator] {
+        &[
+            SimilarSubscribersSegmentMemberOperator::FuzzyIs,
+            SimilarSubscribersSegmentMemberOperator::FuzzyNot,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SimilarSubscribersSegmentMemberOperator::Noop)
     }
@@ -3595,7 +4671,7 @@ pub struct SimilarSubscribersSegmentMember {
     pub value: f64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum StaticSegmentMemberConditionType {
     #[serde(rename = "StaticSegment")]
     StaticSegment,
@@ -3621,7 +4697,22 @@ impl Default for StaticSegmentMemberConditionType {
         StaticSegmentMemberConditionType::Noop
     }
 }
+
 impl StaticSegmentMemberConditionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StaticSegmentMemberConditionType::StaticSegment => "StaticSegment",
+            StaticSegmentMemberConditionType::Noop => "",
+            StaticSegmentMemberConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [StaticSegmentMemberConditionType] {
+        &[StaticSegmentMemberConditionType::StaticSegment]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, StaticSegmentMemberConditionType::Noop)
     }
@@ -3630,7 +4721,7 @@ impl StaticSegmentMemberConditionType {
 /**
  * Segment by a given static segment.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum StaticSegmentMemberField {
     #[serde(rename = "static_segment")]
     StaticSegment,
@@ -3656,7 +4747,22 @@ impl Default for StaticSegmentMemberField {
         StaticSegmentMemberField::Noop
     }
 }
+
 impl StaticSegmentMemberField {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StaticSegmentMemberField::StaticSegment => "static_segment",
+            StaticSegmentMemberField::Noop => "",
+            StaticSegmentMemberField::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [StaticSegmentMemberField] {
+        &[StaticSegmentMemberField::StaticSegment]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, StaticSegmentMemberField::Noop)
     }
@@ -3665,7 +4771,7 @@ impl StaticSegmentMemberField {
 /**
  * Members who are/are not apart of a static segment.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum StaticSegmentMemberOperator {
     #[serde(rename = "static_is")]
     StaticIs,
@@ -3694,7 +4800,26 @@ impl Default for StaticSegmentMemberOperator {
         StaticSegmentMemberOperator::Noop
     }
 }
+
 impl StaticSegmentMemberOperator {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StaticSegmentMemberOperator::StaticIs => "static_is",
+            StaticSegmentMemberOperator::StaticNot => "static_not",
+            StaticSegmentMemberOperator::Noop => "",
+            StaticSegmentMemberOperator::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [StaticSegmentMemberOperator] {
+        &[
+            StaticSegmentMemberOperator::StaticIs,
+            StaticSegmentMemberOperator::StaticNot,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, StaticSegmentMemberOperator::Noop)
     }
@@ -3729,7 +4854,7 @@ pub struct StaticSegmentMember {
     pub value: f64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum LocationBasedSegmentConditionType {
     #[serde(rename = "IPGeoCountryState")]
     IpGeoCountryState,
@@ -3755,7 +4880,22 @@ impl Default for LocationBasedSegmentConditionType {
         LocationBasedSegmentConditionType::Noop
     }
 }
+
 impl LocationBasedSegmentConditionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LocationBasedSegmentConditionType::IpGeoCountryState => "IPGeoCountryState",
+            LocationBasedSegmentConditionType::Noop => "",
+            LocationBasedSegmentConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [LocationBasedSegmentConditionType] {
+        &[LocationBasedSegmentConditionType::IpGeoCountryState]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, LocationBasedSegmentConditionType::Noop)
     }
@@ -3764,7 +4904,7 @@ impl LocationBasedSegmentConditionType {
 /**
  * Segmenting subscribers who are within a specific location.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum LocationBasedSegmentField {
     #[serde(rename = "ipgeo")]
     Ipgeo,
@@ -3790,7 +4930,22 @@ impl Default for LocationBasedSegmentField {
         LocationBasedSegmentField::Noop
     }
 }
+
 impl LocationBasedSegmentField {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LocationBasedSegmentField::Ipgeo => "ipgeo",
+            LocationBasedSegmentField::Noop => "",
+            LocationBasedSegmentField::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [LocationBasedSegmentField] {
+        &[LocationBasedSegmentField::Ipgeo]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, LocationBasedSegmentField::Noop)
     }
@@ -3799,7 +4954,7 @@ impl LocationBasedSegmentField {
 /**
  * Segment members who are within a specific country or US state.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum LocationBasedSegmentOperator {
     #[serde(rename = "ipgeocountry")]
     Ipgeocountry,
@@ -3834,7 +4989,30 @@ impl Default for LocationBasedSegmentOperator {
         LocationBasedSegmentOperator::Noop
     }
 }
+
 impl LocationBasedSegmentOperator {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LocationBasedSegmentOperator::Ipgeocountry => "ipgeocountry",
+            LocationBasedSegmentOperator::Ipgeonotcountry => "ipgeonotcountry",
+            LocationBasedSegmentOperator::Ipgeonotstate => "ipgeonotstate",
+            LocationBasedSegmentOperator::Ipgeostate => "ipgeostate",
+            LocationBasedSegmentOperator::Noop => "",
+            LocationBasedSegmentOperator::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [LocationBasedSegmentOperator] {
+        &[
+            LocationBasedSegmentOperator::Ipgeocountry,
+            LocationBasedSegmentOperator::Ipgeonotcountry,
+            LocationBasedSegmentOperator::Ipgeonotstate,
+            LocationBasedSegmentOperator::Ipgeostate,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, LocationBasedSegmentOperator::Noop)
     }
@@ -3869,7 +5047,7 @@ pub struct LocationBasedSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GeolocationSegmentConditionType {
     #[serde(rename = "IPGeoIn")]
     IpGeoIn,
@@ -3895,7 +5073,22 @@ impl Default for GeolocationSegmentConditionType {
         GeolocationSegmentConditionType::Noop
     }
 }
+
 impl GeolocationSegmentConditionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GeolocationSegmentConditionType::IpGeoIn => "IPGeoIn",
+            GeolocationSegmentConditionType::Noop => "",
+            GeolocationSegmentConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GeolocationSegmentConditionType] {
+        &[GeolocationSegmentConditionType::IpGeoIn]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, GeolocationSegmentConditionType::Noop)
     }
@@ -3904,7 +5097,7 @@ impl GeolocationSegmentConditionType {
 /**
  * Segment members who are within a specific geographic region.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GeolocationSegmentOperator {
     #[serde(rename = "ipgeoin")]
     Ipgeoin,
@@ -3933,7 +5126,26 @@ impl Default for GeolocationSegmentOperator {
         GeolocationSegmentOperator::Noop
     }
 }
+
 impl GeolocationSegmentOperator {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GeolocationSegmentOperator::Ipgeoin => "ipgeoin",
+            GeolocationSegmentOperator::Ipgeonotin => "ipgeonotin",
+            GeolocationSegmentOperator::Noop => "",
+            GeolocationSegmentOperator::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GeolocationSegmentOperator] {
+        &[
+            GeolocationSegmentOperator::Ipgeoin,
+            GeolocationSegmentOperator::Ipgeonotin,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, GeolocationSegmentOperator::Noop)
     }
@@ -3995,7 +5207,7 @@ pub struct GeolocationSegment {
     pub value: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum UsZipCodeSegmentConditionType {
     #[serde(rename = "IPGeoInZip")]
     IpGeoInZip,
@@ -4021,7 +5233,22 @@ impl Default for UsZipCodeSegmentConditionType {
         UsZipCodeSegmentConditionType::Noop
     }
 }
+
 impl UsZipCodeSegmentConditionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UsZipCodeSegmentConditionType::IpGeoInZip => "IPGeoInZip",
+            UsZipCodeSegmentConditionType::Noop => "",
+            UsZipCodeSegmentConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [UsZipCodeSegmentConditionType] {
+        &[UsZipCodeSegmentConditionType::IpGeoInZip]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, UsZipCodeSegmentConditionType::Noop)
     }
@@ -4030,7 +5257,7 @@ impl UsZipCodeSegmentConditionType {
 /**
  * Segment members who are within a specific US zip code.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum UsZipCodeSegmentOperator {
     #[serde(rename = "ipgeoinzip")]
     Ipgeoinzip,
@@ -4056,7 +5283,22 @@ impl Default for UsZipCodeSegmentOperator {
         UsZipCodeSegmentOperator::Noop
     }
 }
+
 impl UsZipCodeSegmentOperator {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UsZipCodeSegmentOperator::Ipgeoinzip => "ipgeoinzip",
+            UsZipCodeSegmentOperator::Noop => "",
+            UsZipCodeSegmentOperator::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [UsZipCodeSegmentOperator] {
+        &[UsZipCodeSegmentOperator::Ipgeoinzip]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, UsZipCodeSegmentOperator::Noop)
     }
@@ -4100,7 +5342,7 @@ pub struct UsZipCodeSegment {
     pub value: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum UnknownLocationBasedSegmentConditionType {
     #[serde(rename = "IPGeoUnknown")]
     IpGeoUnknown,
@@ -4126,7 +5368,22 @@ impl Default for UnknownLocationBasedSegmentConditionType {
         UnknownLocationBasedSegmentConditionType::Noop
     }
 }
+
 impl UnknownLocationBasedSegmentConditionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UnknownLocationBasedSegmentConditionType::IpGeoUnknown => "IPGeoUnknown",
+            UnknownLocationBasedSegmentConditionType::Noop => "",
+            UnknownLocationBasedSegmentConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [UnknownLocationBasedSegmentConditionType] {
+        &[UnknownLocationBasedSegmentConditionType::IpGeoUnknown]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, UnknownLocationBasedSegmentConditionType::Noop)
     }
@@ -4135,7 +5392,7 @@ impl UnknownLocationBasedSegmentConditionType {
 /**
  * Segment members for which location information is unknown.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum UnknownLocationBasedSegmentOperator {
     #[serde(rename = "ipgeounknown")]
     Ipgeounknown,
@@ -4161,7 +5418,22 @@ impl Default for UnknownLocationBasedSegmentOperator {
         UnknownLocationBasedSegmentOperator::Noop
     }
 }
+
 impl UnknownLocationBasedSegmentOperator {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UnknownLocationBasedSegmentOperator::Ipgeounknown => "ipgeounknown",
+            UnknownLocationBasedSegmentOperator::Noop => "",
+            UnknownLocationBasedSegmentOperator::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [UnknownLocationBasedSegmentOperator] {
+        &[UnknownLocationBasedSegmentOperator::Ipgeounknown]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, UnknownLocationBasedSegmentOperator::Noop)
     }
@@ -4190,7 +5462,7 @@ pub struct UnknownLocationBasedSegment {
     pub op: UnknownLocationBasedSegmentOperator,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ZipCodeLocationBasedSegmentConditionType {
     #[serde(rename = "IPGeoZip")]
     IpGeoZip,
@@ -4216,7 +5488,22 @@ impl Default for ZipCodeLocationBasedSegmentConditionType {
         ZipCodeLocationBasedSegmentConditionType::Noop
     }
 }
+
 impl ZipCodeLocationBasedSegmentConditionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ZipCodeLocationBasedSegmentConditionType::IpGeoZip => "IPGeoZip",
+            ZipCodeLocationBasedSegmentConditionType::Noop => "",
+            ZipCodeLocationBasedSegmentConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ZipCodeLocationBasedSegmentConditionType] {
+        &[ZipCodeLocationBasedSegmentConditionType::IpGeoZip]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ZipCodeLocationBasedSegmentConditionType::Noop)
     }
@@ -4225,7 +5512,7 @@ impl ZipCodeLocationBasedSegmentConditionType {
 /**
  * Segment members who are/are not within a specific US zip code.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ZipCodeLocationBasedSegmentOperator {
     #[serde(rename = "ipgeoiszip")]
     Ipgeoiszip,
@@ -4254,7 +5541,26 @@ impl Default for ZipCodeLocationBasedSegmentOperator {
         ZipCodeLocationBasedSegmentOperator::Noop
     }
 }
+
 impl ZipCodeLocationBasedSegmentOperator {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ZipCodeLocationBasedSegmentOperator::Ipgeoiszip => "ipgeoiszip",
+            ZipCodeLocationBasedSegmentOperator::Ipgeonotzip => "ipgeonotzip",
+            ZipCodeLocationBasedSegmentOperator::Noop => "",
+            ZipCodeLocationBasedSegmentOperator::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ZipCodeLocationBasedSegmentOperator] {
+        &[
+            ZipCodeLocationBasedSegmentOperator::Ipgeoiszip,
+            ZipCodeLocationBasedSegmentOperator::Ipgeonotzip,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ZipCodeLocationBasedSegmentOperator::Noop)
     }
@@ -4292,7 +5598,7 @@ pub struct ZipCodeLocationBasedSegment {
     pub value: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SocialProfilesAgeSegmentConditionType {
     #[serde(rename = "SocialAge")]
     SocialAge,
@@ -4318,7 +5624,22 @@ impl Default for SocialProfilesAgeSegmentConditionType {
         SocialProfilesAgeSegmentConditionType::Noop
     }
 }
+
 impl SocialProfilesAgeSegmentConditionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SocialProfilesAgeSegmentConditionType::SocialAge => "SocialAge",
+            SocialProfilesAgeSegmentConditionType::Noop => "",
+            SocialProfilesAgeSegmentConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SocialProfilesAgeSegmentConditionType] {
+        &[SocialProfilesAgeSegmentConditionType::SocialAge]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SocialProfilesAgeSegmentConditionType::Noop)
     }
@@ -4327,7 +5648,7 @@ impl SocialProfilesAgeSegmentConditionType {
 /**
  * Segment by age ranges in Social Profiles data.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SocialProfilesAgeSegmentField {
     #[serde(rename = "social_age")]
     SocialAge,
@@ -4353,7 +5674,22 @@ impl Default for SocialProfilesAgeSegmentField {
         SocialProfilesAgeSegmentField::Noop
     }
 }
+
 impl SocialProfilesAgeSegmentField {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SocialProfilesAgeSegmentField::SocialAge => "social_age",
+            SocialProfilesAgeSegmentField::Noop => "",
+            SocialProfilesAgeSegmentField::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SocialProfilesAgeSegmentField] {
+        &[SocialProfilesAgeSegmentField::SocialAge]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SocialProfilesAgeSegmentField::Noop)
     }
@@ -4362,7 +5698,7 @@ impl SocialProfilesAgeSegmentField {
 /**
  * The age range to segment.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Value {
     #[serde(rename = "18-24")]
     OneThousandEightHundredAndTwentyFour,
@@ -4397,7 +5733,30 @@ impl Default for Value {
         Value::Noop
     }
 }
+
 impl Value {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Value::OneThousandEightHundredAndTwentyFour => "18-24",
+            Value::TwoThousandFiveHundredAndThirtyFour => "25-34",
+            Value::ThreeThousandFiveHundredAndFiftyFour => "35-54",
+            Value::FiftyFive => "55+",
+            Value::Noop => "",
+            Value::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Value] {
+        &[
+            Value::OneThousandEightHundredAndTwentyFour,
+            Value::TwoThousandFiveHundredAndThirtyFour,
+            Value::ThreeThousandFiveHundredAndFiftyFour,
+            Value::FiftyFive,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Value::Noop)
     }
@@ -4431,7 +5790,7 @@ pub struct SocialProfilesAgeSegment {
     pub value: Value,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SocialProfilesGenderSegmentConditionType {
     #[serde(rename = "SocialGender")]
     SocialGender,
@@ -4457,7 +5816,22 @@ impl Default for SocialProfilesGenderSegmentConditionType {
         SocialProfilesGenderSegmentConditionType::Noop
     }
 }
+
 impl SocialProfilesGenderSegmentConditionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SocialProfilesGenderSegmentConditionType::SocialGender => "SocialGender",
+            SocialProfilesGenderSegmentConditionType::Noop => "",
+            SocialProfilesGenderSegmentConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SocialProfilesGenderSegmentConditionType] {
+        &[SocialProfilesGenderSegmentConditionType::SocialGender]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SocialProfilesGenderSegmentConditionType::Noop)
     }
@@ -4466,7 +5840,7 @@ impl SocialProfilesGenderSegmentConditionType {
 /**
  * Segment by listed gender in Social Profiles data.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SocialProfilesGenderSegmentField {
     #[serde(rename = "social_gender")]
     SocialGender,
@@ -4492,7 +5866,22 @@ impl Default for SocialProfilesGenderSegmentField {
         SocialProfilesGenderSegmentField::Noop
     }
 }
+
 impl SocialProfilesGenderSegmentField {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SocialProfilesGenderSegmentField::SocialGender => "social_gender",
+            SocialProfilesGenderSegmentField::Noop => "",
+            SocialProfilesGenderSegmentField::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SocialProfilesGenderSegmentField] {
+        &[SocialProfilesGenderSegmentField::SocialGender]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SocialProfilesGenderSegmentField::Noop)
     }
@@ -4501,7 +5890,7 @@ impl SocialProfilesGenderSegmentField {
 /**
  * The Social Profiles gender to segment.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SocialProfilesGenderSegmentOperator {
     #[serde(rename = "female")]
     Female,
@@ -4530,7 +5919,26 @@ impl Default for SocialProfilesGenderSegmentOperator {
         SocialProfilesGenderSegmentOperator::Noop
     }
 }
+
 impl SocialProfilesGenderSegmentOperator {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SocialProfilesGenderSegmentOperator::Female => "female",
+            SocialProfilesGenderSegmentOperator::Male => "male",
+            SocialProfilesGenderSegmentOperator::Noop => "",
+            SocialProfilesGenderSegmentOperator::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SocialProfilesGenderSegmentOperator] {
+        &[
+            SocialProfilesGenderSegmentOperator::Female,
+            SocialProfilesGenderSegmentOperator::Male,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SocialProfilesGenderSegmentOperator::Noop)
     }
@@ -4567,7 +5975,7 @@ pub struct SocialProfilesGenderSegment {
     pub value: SocialProfilesGenderSegmentOperator,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SocialProfilesInfluenceSegmentConditionType {
     #[serde(rename = "SocialInfluence")]
     SocialInfluence,
@@ -4593,7 +6001,22 @@ impl Default for SocialProfilesInfluenceSegmentConditionType {
         SocialProfilesInfluenceSegmentConditionType::Noop
     }
 }
+
 impl SocialProfilesInfluenceSegmentConditionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SocialProfilesInfluenceSegmentConditionType::SocialInfluence => "SocialInfluence",
+            SocialProfilesInfluenceSegmentConditionType::Noop => "",
+            SocialProfilesInfluenceSegmentConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SocialProfilesInfluenceSegmentConditionType] {
+        &[SocialProfilesInfluenceSegmentConditionType::SocialInfluence]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SocialProfilesInfluenceSegmentConditionType::Noop)
     }
@@ -4602,7 +6025,7 @@ impl SocialProfilesInfluenceSegmentConditionType {
 /**
  * Segment by influence rating in Social Profiles data.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SocialProfilesInfluenceSegmentField {
     #[serde(rename = "social_influence")]
     SocialInfluence,
@@ -4628,7 +6051,22 @@ impl Default for SocialProfilesInfluenceSegmentField {
         SocialProfilesInfluenceSegmentField::Noop
     }
 }
+
 impl SocialProfilesInfluenceSegmentField {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SocialProfilesInfluenceSegmentField::SocialInfluence => "social_influence",
+            SocialProfilesInfluenceSegmentField::Noop => "",
+            SocialProfilesInfluenceSegmentField::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SocialProfilesInfluenceSegmentField] {
+        &[SocialProfilesInfluenceSegmentField::SocialInfluence]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SocialProfilesInfluenceSegmentField::Noop)
     }
@@ -4666,7 +6104,7 @@ pub struct SocialProfilesInfluenceSegment {
     pub value: f64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SocialProfilesNetworkSegmentConditionType {
     #[serde(rename = "SocialNetworkMember")]
     SocialNetworkMember,
@@ -4692,7 +6130,22 @@ impl Default for SocialProfilesNetworkSegmentConditionType {
         SocialProfilesNetworkSegmentConditionType::Noop
     }
 }
+
 impl SocialProfilesNetworkSegmentConditionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SocialProfilesNetworkSegmentConditionType::SocialNetworkMember => "SocialNetworkMember",
+            SocialProfilesNetworkSegmentConditionType::Noop => "",
+            SocialProfilesNetworkSegmentConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SocialProfilesNetworkSegmentConditionType] {
+        &[SocialProfilesNetworkSegmentConditionType::SocialNetworkMember]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SocialProfilesNetworkSegmentConditionType::Noop)
     }
@@ -4701,7 +6154,7 @@ impl SocialProfilesNetworkSegmentConditionType {
 /**
  * Segment by social network in Social Profiles data.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SocialProfilesNetworkSegmentField {
     #[serde(rename = "social_network")]
     SocialNetwork,
@@ -4727,7 +6180,22 @@ impl Default for SocialProfilesNetworkSegmentField {
         SocialProfilesNetworkSegmentField::Noop
     }
 }
+
 impl SocialProfilesNetworkSegmentField {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SocialProfilesNetworkSegmentField::SocialNetwork => "social_network",
+            SocialProfilesNetworkSegmentField::Noop => "",
+            SocialProfilesNetworkSegmentField::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SocialProfilesNetworkSegmentField] {
+        &[SocialProfilesNetworkSegmentField::SocialNetwork]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SocialProfilesNetworkSegmentField::Noop)
     }
@@ -4736,7 +6204,7 @@ impl SocialProfilesNetworkSegmentField {
 /**
  * The social network to segment against.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SocialProfilesNetworkSegmentOperator {
     #[serde(rename = "facebook")]
     Facebook,
@@ -4792,7 +6260,44 @@ impl Default for SocialProfilesNetworkSegmentOperator {
         SocialProfilesNetworkSegmentOperator::Noop
     }
 }
+
 impl SocialProfilesNetworkSegmentOperator {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SocialProfilesNetworkSegmentOperator::Facebook => "facebook",
+            SocialProfilesNetworkSegmentOperator::Flickr => "flickr",
+            SocialProfilesNetworkSegmentOperator::Foursquare => "foursquare",
+            SocialProfilesNetworkSegmentOperator::Lastfm => "lastfm",
+            SocialProfilesNetworkSegmentOperator::Linkedin => "linkedin",
+            SocialProfilesNetworkSegmentOperator::Myspace => "myspace",
+            SocialProfilesNetworkSegmentOperator::Quora => "quora",
+            SocialProfilesNetworkSegmentOperator::Twitter => "twitter",
+            SocialProfilesNetworkSegmentOperator::Vimeo => "vimeo",
+            SocialProfilesNetworkSegmentOperator::Yelp => "yelp",
+            SocialProfilesNetworkSegmentOperator::Youtube => "youtube",
+            SocialProfilesNetworkSegmentOperator::Noop => "",
+            SocialProfilesNetworkSegmentOperator::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SocialProfilesNetworkSegmentOperator] {
+        &[
+            SocialProfilesNetworkSegmentOperator::Facebook,
+            SocialProfilesNetworkSegmentOperator::Flickr,
+            SocialProfilesNetworkSegmentOperator::Foursquare,
+            SocialProfilesNetworkSegmentOperator::Lastfm,
+            SocialProfilesNetworkSegmentOperator::Linkedin,
+            SocialProfilesNetworkSegmentOperator::Myspace,
+            SocialProfilesNetworkSegmentOperator::Quora,
+            SocialProfilesNetworkSegmentOperator::Twitter,
+            SocialProfilesNetworkSegmentOperator::Vimeo,
+            SocialProfilesNetworkSegmentOperator::Yelp,
+            SocialProfilesNetworkSegmentOperator::Youtube,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SocialProfilesNetworkSegmentOperator::Noop)
     }
@@ -4829,7 +6334,7 @@ pub struct SocialProfilesNetworkSegment {
     pub value: SocialProfilesNetworkSegmentOperator,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SocialProfilesNetworkFollowSegmentConditionType {
     #[serde(rename = "SocialNetworkFollow")]
     SocialNetworkFollow,
@@ -4857,7 +6362,24 @@ impl Default for SocialProfilesNetworkFollowSegmentConditionType {
         SocialProfilesNetworkFollowSegmentConditionType::Noop
     }
 }
+
 impl SocialProfilesNetworkFollowSegmentConditionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SocialProfilesNetworkFollowSegmentConditionType::SocialNetworkFollow => {
+                "SocialNetworkFollow"
+            }
+            SocialProfilesNetworkFollowSegmentConditionType::Noop => "",
+            SocialProfilesNetworkFollowSegmentConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SocialProfilesNetworkFollowSegmentConditionType] {
+        &[SocialProfilesNetworkFollowSegmentConditionType::SocialNetworkFollow]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SocialProfilesNetworkFollowSegmentConditionType::Noop)
     }
@@ -4866,7 +6388,7 @@ impl SocialProfilesNetworkFollowSegmentConditionType {
 /**
  * Members who are/not following a linked account on a given social network.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SocialProfilesNetworkFollowSegmentOperator {
     #[serde(rename = "follow")]
     Follow,
@@ -4895,7 +6417,26 @@ impl Default for SocialProfilesNetworkFollowSegmentOperator {
         SocialProfilesNetworkFollowSegmentOperator::Noop
     }
 }
+
 impl SocialProfilesNetworkFollowSegmentOperator {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SocialProfilesNetworkFollowSegmentOperator::Follow => "follow",
+            SocialProfilesNetworkFollowSegmentOperator::Notfollow => "notfollow",
+            SocialProfilesNetworkFollowSegmentOperator::Noop => "",
+            SocialProfilesNetworkFollowSegmentOperator::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SocialProfilesNetworkFollowSegmentOperator] {
+        &[
+            SocialProfilesNetworkFollowSegmentOperator::Follow,
+            SocialProfilesNetworkFollowSegmentOperator::Notfollow,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SocialProfilesNetworkFollowSegmentOperator::Noop)
     }
@@ -4904,7 +6445,7 @@ impl SocialProfilesNetworkFollowSegmentOperator {
 /**
  * The social network to segment against.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SocialProfilesNetworkFollowSegmentOperatorData {
     #[serde(rename = "twitter_follow")]
     TwitterFollow,
@@ -4930,7 +6471,22 @@ impl Default for SocialProfilesNetworkFollowSegmentOperatorData {
         SocialProfilesNetworkFollowSegmentOperatorData::Noop
     }
 }
+
 impl SocialProfilesNetworkFollowSegmentOperatorData {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SocialProfilesNetworkFollowSegmentOperatorData::TwitterFollow => "twitter_follow",
+            SocialProfilesNetworkFollowSegmentOperatorData::Noop => "",
+            SocialProfilesNetworkFollowSegmentOperatorData::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SocialProfilesNetworkFollowSegmentOperatorData] {
+        &[SocialProfilesNetworkFollowSegmentOperatorData::TwitterFollow]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SocialProfilesNetworkFollowSegmentOperatorData::Noop)
     }
@@ -4970,7 +6526,7 @@ pub struct SocialProfilesNetworkFollowSegment {
     pub value: SocialProfilesNetworkFollowSegmentOperatorData,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum AddressMergeFieldSegmentConditionType {
     #[serde(rename = "AddressMerge")]
     AddressMerge,
@@ -4996,7 +6552,22 @@ impl Default for AddressMergeFieldSegmentConditionType {
         AddressMergeFieldSegmentConditionType::Noop
     }
 }
+
 impl AddressMergeFieldSegmentConditionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AddressMergeFieldSegmentConditionType::AddressMerge => "AddressMerge",
+            AddressMergeFieldSegmentConditionType::Noop => "",
+            AddressMergeFieldSegmentConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [AddressMergeFieldSegmentConditionType] {
+        &[AddressMergeFieldSegmentConditionType::AddressMerge]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, AddressMergeFieldSegmentConditionType::Noop)
     }
@@ -5005,7 +6576,7 @@ impl AddressMergeFieldSegmentConditionType {
 /**
  * Whether the member's address merge field contains/does not contain a value or is/is not blank.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum AddressMergeFieldSegmentOperator {
     #[serde(rename = "blank")]
     Blank,
@@ -5031,16 +6602,39 @@ impl std::fmt::Display for AddressMergeFieldSegmentOperator {
             AddressMergeFieldSegmentOperator::Noop => "",
             AddressMergeFieldSegmentOperator::FallthroughString => "*",
         }
-        .fmt(f)
+        .fmt(f)
+    }
+}
+
+impl Default for AddressMergeFieldSegmentOperator {
+    fn default() -> AddressMergeFieldSegmentOperator {
+        AddressMergeFieldSegmentOperator::Noop
+    }
+}
+
+impl AddressMergeFieldSegmentOperator {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AddressMergeFieldSegmentOperator::Blank => "blank",
+            AddressMergeFieldSegmentOperator::BlankNot => "blank_not",
+            AddressMergeFieldSegmentOperator::Contains => "contains",
+            AddressMergeFieldSegmentOperator::Notcontain => "notcontain",
+            AddressMergeFieldSegmentOperator::Noop => "",
+            AddressMergeFieldSegmentOperator::FallthroughString => "*",
+        }
     }
-}
 
-impl Default for AddressMergeFieldSegmentOperator {
-    fn default() -> AddressMergeFieldSegmentOperator {
-        AddressMergeFieldSegmentOperator::Noop
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [AddressMergeFieldSegmentOperator] {
+        &[
+            AddressMergeFieldSegmentOperator::Blank,
+            AddressMergeFieldSegmentOperator::BlankNot,
+            AddressMergeFieldSegmentOperator::Contains,
+            AddressMergeFieldSegmentOperator::Notcontain,
+        ]
     }
-}
-impl AddressMergeFieldSegmentOperator {
+
     pub fn is_noop(&self) -> bool {
         matches!(self, AddressMergeFieldSegmentOperator::Noop)
     }
@@ -5082,7 +6676,7 @@ pub struct AddressMergeFieldSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum AddressZipMergeFieldSegmentConditionType {
     #[serde(rename = "ZipMerge")]
     ZipMerge,
@@ -5108,7 +6702,22 @@ impl Default for AddressZipMergeFieldSegmentConditionType {
         AddressZipMergeFieldSegmentConditionType::Noop
     }
 }
+
 impl AddressZipMergeFieldSegmentConditionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AddressZipMergeFieldSegmentConditionType::ZipMerge => "ZipMerge",
+            AddressZipMergeFieldSegmentConditionType::Noop => "",
+            AddressZipMergeFieldSegmentConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [AddressZipMergeFieldSegmentConditionType] {
+        &[AddressZipMergeFieldSegmentConditionType::ZipMerge]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, AddressZipMergeFieldSegmentConditionType::Noop)
     }
@@ -5117,7 +6726,7 @@ impl AddressZipMergeFieldSegmentConditionType {
 /**
  * Whether the member's address merge field is within a given distance from a city or zip.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum AddressZipMergeFieldSegmentOperator {
     #[serde(rename = "geoin")]
     Geoin,
@@ -5143,7 +6752,22 @@ impl Default for AddressZipMergeFieldSegmentOperator {
         AddressZipMergeFieldSegmentOperator::Noop
     }
 }
+
 impl AddressZipMergeFieldSegmentOperator {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AddressZipMergeFieldSegmentOperator::Geoin => "geoin",
+            AddressZipMergeFieldSegmentOperator::Noop => "",
+            AddressZipMergeFieldSegmentOperator::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [AddressZipMergeFieldSegmentOperator] {
+        &[AddressZipMergeFieldSegmentOperator::Geoin]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, AddressZipMergeFieldSegmentOperator::Noop)
     }
@@ -5194,7 +6818,7 @@ pub struct AddressZipMergeFieldSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum BirthdayMergeFieldSegmentConditionType {
     #[serde(rename = "BirthdayMerge")]
     BirthdayMerge,
@@ -5220,7 +6844,22 @@ impl Default for BirthdayMergeFieldSegmentConditionType {
         BirthdayMergeFieldSegmentConditionType::Noop
     }
 }
+
 impl BirthdayMergeFieldSegmentConditionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BirthdayMergeFieldSegmentConditionType::BirthdayMerge => "BirthdayMerge",
+            BirthdayMergeFieldSegmentConditionType::Noop => "",
+            BirthdayMergeFieldSegmentConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [BirthdayMergeFieldSegmentConditionType] {
+        &[BirthdayMergeFieldSegmentConditionType::BirthdayMerge]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, BirthdayMergeFieldSegmentConditionType::Noop)
     }
@@ -5229,7 +6868,7 @@ impl BirthdayMergeFieldSegmentConditionType {
 /**
  * Whether the member's birthday merge information is/is not a certain date or is/is not blank.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum BirthdayMergeFieldSegmentOperator {
     #[serde(rename = "blank")]
     Blank,
@@ -5264,7 +6903,30 @@ impl Default for BirthdayMergeFieldSegmentOperator {
         BirthdayMergeFieldSegmentOperator::Noop
     }
 }
+
 impl BirthdayMergeFieldSegmentOperator {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BirthdayMergeFieldSegmentOperator::Blank => "blank",
+            BirthdayMergeFieldSegmentOperator::BlankNot => "blank_not",
+            BirthdayMergeFieldSegmentOperator::Is => "is",
+            BirthdayMergeFieldSegmentOperator::Not => "not",
+            BirthdayMergeFieldSegmentOperator::Noop => "",
+            BirthdayMergeFieldSegmentOperator::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [BirthdayMergeFieldSegmentOperator] {
+        &[
+            BirthdayMergeFieldSegmentOperator::Blank,
+            BirthdayMergeFieldSegmentOperator::BlankNot,
+            BirthdayMergeFieldSegmentOperator::Is,
+            BirthdayMergeFieldSegmentOperator::Not,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, BirthdayMergeFieldSegmentOperator::Noop)
     }
@@ -5306,7 +6968,7 @@ pub struct BirthdayMergeFieldSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum DateMergeFieldSegmentConditionType {
     #[serde(rename = "DateMerge")]
     DateMerge,
@@ -5332,7 +6994,22 @@ impl Default for DateMergeFieldSegmentConditionType {
         DateMergeFieldSegmentConditionType::Noop
     }
 }
+
 impl DateMergeFieldSegmentConditionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DateMergeFieldSegmentConditionType::DateMerge => "DateMerge",
+            DateMergeFieldSegmentConditionType::Noop => "",
+            DateMergeFieldSegmentConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [DateMergeFieldSegmentConditionType] {
+        &[DateMergeFieldSegmentConditionType::DateMerge]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, DateMergeFieldSegmentConditionType::Noop)
     }
@@ -5341,7 +7018,7 @@ impl DateMergeFieldSegmentConditionType {
 /**
  * Whether the member's merge information is/is not, is greater/less than a value or is/is not blank.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum DateMergeFieldSegmentOperator {
     #[serde(rename = "blank")]
     Blank,
@@ -5382,7 +7059,34 @@ impl Default for DateMergeFieldSegmentOperator {
         DateMergeFieldSegmentOperator::Noop
     }
 }
+
 impl DateMergeFieldSegmentOperator {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DateMergeFieldSegmentOperator::Blank => "blank",
+            DateMergeFieldSegmentOperator::BlankNot => "blank_not",
+            DateMergeFieldSegmentOperator::Greater => "greater",
+            DateMergeFieldSegmentOperator::Is => "is",
+            DateMergeFieldSegmentOperator::Less => "less",
+            DateMergeFieldSegmentOperator::Not => "not",
+            DateMergeFieldSegmentOperator::Noop => "",
+            DateMergeFieldSegmentOperator::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [DateMergeFieldSegmentOperator] {
+        &[
+            DateMergeFieldSegmentOperator::Blank,
+            DateMergeFieldSegmentOperator::BlankNot,
+            DateMergeFieldSegmentOperator::Greater,
+            DateMergeFieldSegmentOperator::Is,
+            DateMergeFieldSegmentOperator::Less,
+            DateMergeFieldSegmentOperator::Not,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, DateMergeFieldSegmentOperator::Noop)
     }
@@ -5424,7 +7128,7 @@ pub struct DateMergeFieldSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum DropdownRadioMergeFieldSegmentConditionType {
     #[serde(rename = "SelectMerge")]
     SelectMerge,
@@ -5450,7 +7154,22 @@ impl Default for DropdownRadioMergeFieldSegmentConditionType {
         DropdownRadioMergeFieldSegmentConditionType::Noop
     }
 }
+
 impl DropdownRadioMergeFieldSegmentConditionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DropdownRadioMergeFieldSegmentConditionType::SelectMerge => "SelectMerge",
+            DropdownRadioMergeFieldSegmentConditionType::Noop => "",
+            DropdownRadioMergeFieldSegmentConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [DropdownRadioMergeFieldSegmentConditionType] {
+        &[DropdownRadioMergeFieldSegmentConditionType::SelectMerge]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, DropdownRadioMergeFieldSegmentConditionType::Noop)
     }
@@ -5459,7 +7178,7 @@ impl DropdownRadioMergeFieldSegmentConditionType {
 /**
  * Whether the member's merge information is/is not a value or is/is not blank.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum DropdownRadioMergeFieldSegmentOperator {
     #[serde(rename = "blank")]
     Blank,
@@ -5500,7 +7219,34 @@ impl Default for DropdownRadioMergeFieldSegmentOperator {
         DropdownRadioMergeFieldSegmentOperator::Noop
     }
 }
+
 impl DropdownRadioMergeFieldSegmentOperator {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DropdownRadioMergeFieldSegmentOperator::Blank => "blank",
+            DropdownRadioMergeFieldSegmentOperator::BlankNot => "blank_not",
+            DropdownRadioMergeFieldSegmentOperator::Contains => "contains",
+            DropdownRadioMergeFieldSegmentOperator::Is => "is",
+            DropdownRadioMergeFieldSegmentOperator::Not => "not",
+            DropdownRadioMergeFieldSegmentOperator::Notcontain => "notcontain",
+            DropdownRadioMergeFieldSegmentOperator::Noop => "",
+            DropdownRadioMergeFieldSegmentOperator::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [DropdownRadioMergeFieldSegmentOperator] {
+        &[
+            DropdownRadioMergeFieldSegmentOperator::Blank,
+            DropdownRadioMergeFieldSegmentOperator::BlankNot,
+            DropdownRadioMergeFieldSegmentOperator::Contains,
+            DropdownRadioMergeFieldSegmentOperator::Is,
+            DropdownRadioMergeFieldSegmentOperator::Not,
+            DropdownRadioMergeFieldSegmentOperator::Notcontain,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, DropdownRadioMergeFieldSegmentOperator::Noop)
     }
@@ -5542,7 +7288,7 @@ pub struct DropdownRadioMergeFieldSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum TextNumberMergeFieldSegmentConditionType {
     #[serde(rename = "TextMerge")]
     TextMerge,
@@ -5568,7 +7314,22 @@ impl Default for TextNumberMergeFieldSegmentConditionType {
         TextNumberMergeFieldSegmentConditionType::Noop
     }
 }
+
 impl TextNumberMergeFieldSegmentConditionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TextNumberMergeFieldSegmentConditionType::TextMerge => "TextMerge",
+            TextNumberMergeFieldSegmentConditionType::Noop => "",
+            TextNumberMergeFieldSegmentConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [TextNumberMergeFieldSegmentConditionType] {
+        &[TextNumberMergeFieldSegmentConditionType::TextMerge]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, TextNumberMergeFieldSegmentConditionType::Noop)
     }
@@ -5577,7 +7338,7 @@ impl TextNumberMergeFieldSegmentConditionType {
 /**
  * Whether the member's merge information is/is not, contains/does not contain, starts/ends with, or is greater/less than a value
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum TextNumberMergeFieldSegmentOperator {
     #[serde(rename = "blank")]
     Blank,
@@ -5630,7 +7391,42 @@ impl Default for TextNumberMergeFieldSegmentOperator {
         TextNumberMergeFieldSegmentOperator::Noop
     }
 }
+
 impl TextNumberMergeFieldSegmentOperator {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TextNumberMergeFieldSegmentOperator::Blank => "blank",
+            TextNumberMergeFieldSegmentOperator::BlankNot => "blank_not",
+            TextNumberMergeFieldSegmentOperator::Contains => "contains",
+            TextNumberMergeFieldSegmentOperator::Ends => "ends",
+            TextNumberMergeFieldSegmentOperator::Greater => "greater",
+            TextNumberMergeFieldSegmentOperator::Is => "is",
+            TextNumberMergeFieldSegmentOperator::Less => "less",
+            TextNumberMergeFieldSegmentOperator::Not => "not",
+            TextNumberMergeFieldSegmentOperator::Notcontain => "notcontain",
+            TextNumberMergeFieldSegmentOperator::Starts => "starts",
+            TextNumberMergeFieldSegmentOperator::Noop => "",
+            TextNumberMergeFieldSegmentOperator::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [TextNumberMergeFieldSegmentOperator] {
+        &[
+            TextNumberMergeFieldSegmentOperator::Blank,
+            TextNumberMergeFieldSegmentOperator::BlankNot,
+            TextNumberMergeFieldSegmentOperator::Contains,
+            TextNumberMergeFieldSegmentOperator::Ends,
+            TextNumberMergeFieldSegmentOperator::Greater,
+            TextNumberMergeFieldSegmentOperator::Is,
+            TextNumberMergeFieldSegmentOperator::Less,
+            TextNumberMergeFieldSegmentOperator::Not,
+            TextNumberMergeFieldSegmentOperator::Notcontain,
+            TextNumberMergeFieldSegmentOperator::Starts,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, TextNumberMergeFieldSegmentOperator::Noop)
     }
@@ -5672,7 +7468,7 @@ pub struct TextNumberMergeFieldSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum EmailSegmentConditionType {
     #[serde(rename = "EmailAddress")]
     EmailAddress,
@@ -5698,7 +7494,22 @@ impl Default for EmailSegmentConditionType {
         EmailSegmentConditionType::Noop
     }
 }
+
 impl EmailSegmentConditionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EmailSegmentConditionType::EmailAddress => "EmailAddress",
+            EmailSegmentConditionType::Noop => "",
+            EmailSegmentConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [EmailSegmentConditionType] {
+        &[EmailSegmentConditionType::EmailAddress]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, EmailSegmentConditionType::Noop)
     }
@@ -5707,7 +7518,7 @@ impl EmailSegmentConditionType {
 /**
  * Segmenting based off of a subscriber's email address.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum EmailSegmentField {
     #[serde(rename = "EMAIL")]
     Email,
@@ -5736,7 +7547,23 @@ impl Default for EmailSegmentField {
         EmailSegmentField::Noop
     }
 }
+
 impl EmailSegmentField {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EmailSegmentField::Email => "EMAIL",
+            EmailSegmentField::Merge0 => "merge0",
+            EmailSegmentField::Noop => "",
+            EmailSegmentField::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [EmailSegmentField] {
+        &[EmailSegmentField::Email, EmailSegmentField::Merge0]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, EmailSegmentField::Noop)
     }
@@ -5745,7 +7572,7 @@ impl EmailSegmentField {
 /**
  * Whether the email address is/not exactly, contains/doesn't contain, starts/ends with a string.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum EmailSegmentOperator {
     #[serde(rename = "contains")]
     Contains,
@@ -5792,7 +7619,38 @@ impl Default for EmailSegmentOperator {
         EmailSegmentOperator::Noop
     }
 }
+
 impl EmailSegmentOperator {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EmailSegmentOperator::Contains => "contains",
+            EmailSegmentOperator::Ends => "ends",
+            EmailSegmentOperator::Greater => "greater",
+            EmailSegmentOperator::Is => "is",
+            EmailSegmentOperator::Less => "less",
+            EmailSegmentOperator::Not => "not",
+            EmailSegmentOperator::Notcontain => "notcontain",
+            EmailSegmentOperator::Starts => "starts",
+            EmailSegmentOperator::Noop => "",
+            EmailSegmentOperator::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [EmailSegmentOperator] {
+        &[
+            EmailSegmentOperator::Contains,
+            EmailSegmentOperator::Ends,
+            EmailSegmentOperator::Greater,
+            EmailSegmentOperator::Is,
+            EmailSegmentOperator::Less,
+            EmailSegmentOperator::Not,
+            EmailSegmentOperator::Notcontain,
+            EmailSegmentOperator::Starts,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, EmailSegmentOperator::Noop)
     }
@@ -5827,7 +7685,7 @@ pub struct EmailSegment {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PredictedGenderSegmentConditionType {
     #[serde(rename = "PredictedGender")]
     PredictedGender,
@@ -5853,7 +7711,22 @@ impl Default for PredictedGenderSegmentConditionType {
         PredictedGenderSegmentConditionType::Noop
     }
 }
+
 impl PredictedGenderSegmentConditionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PredictedGenderSegmentConditionType::PredictedGender => "PredictedGender",
+            PredictedGenderSegmentConditionType::Noop => "",
+            PredictedGenderSegmentConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PredictedGenderSegmentConditionType] {
+        &[PredictedGenderSegmentConditionType::PredictedGender]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PredictedGenderSegmentConditionType::Noop)
     }
@@ -5862,7 +7735,7 @@ impl PredictedGenderSegmentConditionType {
 /**
  * Segment by predicted gender.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PredictedGenderSegmentField {
     #[serde(rename = "predicted_gender")]
     PredictedGender,
@@ -5888,7 +7761,22 @@ impl Default for PredictedGenderSegmentField {
         PredictedGenderSegmentField::Noop
     }
 }
+
 impl PredictedGenderSegmentField {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PredictedGenderSegmentField::PredictedGender => "predicted_gender",
+            PredictedGenderSegmentField::Noop => "",
+            PredictedGenderSegmentField::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PredictedGenderSegmentField] {
+        &[PredictedGenderSegmentField::PredictedGender]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PredictedGenderSegmentField::Noop)
     }
@@ -5922,7 +7810,7 @@ pub struct PredictedGenderSegment {
     pub value: SocialProfilesGenderSegmentOperator,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PredictedAgeSegmentConditionType {
     #[serde(rename = "PredictedAge")]
     PredictedAge,
@@ -5948,7 +7836,22 @@ impl Default for PredictedAgeSegmentConditionType {
         PredictedAgeSegmentConditionType::Noop
     }
 }
+
 impl PredictedAgeSegmentConditionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PredictedAgeSegmentConditionType::PredictedAge => "PredictedAge",
+            PredictedAgeSegmentConditionType::Noop => "",
+            PredictedAgeSegmentConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PredictedAgeSegmentConditionType] {
+        &[PredictedAgeSegmentConditionType::PredictedAge]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PredictedAgeSegmentConditionType::Noop)
     }
@@ -5957,7 +7860,7 @@ impl PredictedAgeSegmentConditionType {
 /**
  * Segment by predicted age.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PredictedAgeSegmentField {
     #[serde(rename = "predicted_age_range")]
     PredictedAgeRange,
@@ -5983,7 +7886,22 @@ impl Default for PredictedAgeSegmentField {
         PredictedAgeSegmentField::Noop
     }
 }
+
 impl PredictedAgeSegmentField {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PredictedAgeSegmentField::PredictedAgeRange => "predicted_age_range",
+            PredictedAgeSegmentField::Noop => "",
+            PredictedAgeSegmentField::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PredictedAgeSegmentField] {
+        &[PredictedAgeSegmentField::PredictedAgeRange]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PredictedAgeSegmentField::Noop)
     }
@@ -5992,7 +7910,7 @@ impl PredictedAgeSegmentField {
 /**
  * Members who are/not the exact criteria listed.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PredictedAgeSegmentOperator {
     #[serde(rename = "is")]
     Is,
@@ -6018,7 +7936,22 @@ impl Default for PredictedAgeSegmentOperator {
         PredictedAgeSegmentOperator::Noop
     }
 }
+
 impl PredictedAgeSegmentOperator {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PredictedAgeSegmentOperator::Is => "is",
+            PredictedAgeSegmentOperator::Noop => "",
+            PredictedAgeSegmentOperator::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PredictedAgeSegmentOperator] {
+        &[PredictedAgeSegmentOperator::Is]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PredictedAgeSegmentOperator::Noop)
     }
@@ -6027,7 +7960,7 @@ impl PredictedAgeSegmentOperator {
 /**
  * The predicted age to segment.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PredictedAgeSegmentOperatorData {
     #[serde(rename = "18-24")]
     OneThousandEightHundredAndTwentyFour,
@@ -6068,7 +8001,34 @@ impl Default for PredictedAgeSegmentOperatorData {
         PredictedAgeSegmentOperatorData::Noop
     }
 }
+
 impl PredictedAgeSegmentOperatorData {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PredictedAgeSegmentOperatorData::OneThousandEightHundredAndTwentyFour => "18-24",
+            PredictedAgeSegmentOperatorData::TwoThousandFiveHundredAndThirtyFour => "25-34",
+            PredictedAgeSegmentOperatorData::ThreeThousandFiveHundredAndFortyFour => "35-44",
+            PredictedAgeSegmentOperatorData::FourThousandFiveHundredAndFiftyFour => "45-54",
+            PredictedAgeSegmentOperatorData::FiveThousandFiveHundredAndSixtyFour => "55-64",
+            PredictedAgeSegmentOperatorData::SixtyFive => "65+",
+            PredictedAgeSegmentOperatorData::Noop => "",
+            PredictedAgeSegmentOperatorData::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PredictedAgeSegmentOperatorData] {
+        &[
+            PredictedAgeSegmentOperatorData::OneThousandEightHundredAndTwentyFour,
+            PredictedAgeSegmentOperatorData::TwoThousandFiveHundredAndThirtyFour,
+            PredictedAgeSegmentOperatorData::ThreeThousandFiveHundredAndFortyFour,
+            PredictedAgeSegmentOperatorData::FourThousandFiveHundredAndFiftyFour,
+            PredictedAgeSegmentOperatorData::FiveThousandFiveHundredAndSixtyFour,
+            PredictedAgeSegmentOperatorData::SixtyFive,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PredictedAgeSegmentOperatorData::Noop)
     }
@@ -6102,7 +8062,7 @@ pub struct PredictedAgeSegment {
     pub value: PredictedAgeSegmentOperatorData,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum NewSubscribersPrebuiltSegmentConditionType {
     #[serde(rename = "NewSubscribers")]
     NewSubscribers,
@@ -6128,7 +8088,22 @@ impl Default for NewSubscribersPrebuiltSegmentConditionType {
         NewSubscribersPrebuiltSegmentConditionType::Noop
     }
 }
+
 impl NewSubscribersPrebuiltSegmentConditionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NewSubscribersPrebuiltSegmentConditionType::NewSubscribers => "NewSubscribers",
+            NewSubscribersPrebuiltSegmentConditionType::Noop => "",
+            NewSubscribersPrebuiltSegmentConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [NewSubscribersPrebuiltSegmentConditionType] {
+        &[NewSubscribersPrebuiltSegmentConditionType::NewSubscribers]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, NewSubscribersPrebuiltSegmentConditionType::Noop)
     }
@@ -6137,7 +8112,7 @@ impl NewSubscribersPrebuiltSegmentConditionType {
 /**
  * Segment by when people subscribed.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum NewSubscribersPrebuiltSegmentField {
     #[serde(rename = "timestamp_opt")]
     TimestampOpt,
@@ -6163,7 +8138,22 @@ impl Default for NewSubscribersPrebuiltSegmentField {
         NewSubscribersPrebuiltSegmentField::Noop
     }
 }
+
 impl NewSubscribersPrebuiltSegmentField {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NewSubscribersPrebuiltSegmentField::TimestampOpt => "timestamp_opt",
+            NewSubscribersPrebuiltSegmentField::Noop => "",
+            NewSubscribersPrebuiltSegmentField::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [NewSubscribersPrebuiltSegmentField] {
+        &[NewSubscribersPrebuiltSegmentField::TimestampOpt]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, NewSubscribersPrebuiltSegmentField::Noop)
     }
@@ -6172,7 +8162,7 @@ impl NewSubscribersPrebuiltSegmentField {
 /**
  * Whe the event took place, namely within a time frame.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum NewSubscribersPrebuiltSegmentOperator {
     #[serde(rename = "date_within")]
     DateWithin,
@@ -6198,7 +8188,22 @@ impl Default for NewSubscribersPrebuiltSegmentOperator {
         NewSubscribersPrebuiltSegmentOperator::Noop
     }
 }
+
 impl NewSubscribersPrebuiltSegmentOperator {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NewSubscribersPrebuiltSegmentOperator::DateWithin => "date_within",
+            NewSubscribersPrebuiltSegmentOperator::Noop => "",
+            NewSubscribersPrebuiltSegmentOperator::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [NewSubscribersPrebuiltSegmentOperator] {
+        &[NewSubscribersPrebuiltSegmentOperator::DateWithin]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, NewSubscribersPrebuiltSegmentOperator::Noop)
     }
@@ -6278,175 +8283,513 @@ pub struct NewSubscribersPrebuiltSegment {
 /// - `NewSubscribersPrebuiltSegment`
 ///
 /// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-#[serde(untagged)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[serde(tag = "condition_type")]
 pub enum ConditionsOneOf {
     /**
      * Segment by interaction with a specific campaign.
      */
+    #[serde(rename = "Aim")]
     Conditions(Conditions),
     /**
      * Segment by interaction with an Automation workflow.
      */
+    #[serde(rename = "Automation")]
     AutomationSegment(AutomationSegment),
     /**
      * Segment by poll activity.
      */
+    #[serde(rename = "CampaignPoll")]
     PollActivitySegment(PollActivitySegment),
     /**
      * Segment by interaction with a campaign via Conversations.
      */
+    #[serde(rename = "Conversation")]
     ConversationSegment(ConversationSegment),
     /**
      * Segment by a specific date field.
      */
+    #[serde(rename = "Date")]
     DateSegment(DateSegment),
     /**
      * Segment by use of a particular email client.
      */
+    #[serde(rename = "EmailClient")]
     EmailClientSegment(EmailClientSegment),
     /**
      * Segment by language.
      */
+    #[serde(rename = "Language")]
     LanguageSegment(LanguageSegment),
     /**
      * Segment by member rating.
      */
+    #[serde(rename = "MemberRating")]
     MemberRatingSegment(MemberRatingSegment),
     /**
      * Segment by signup source.
      */
+    #[serde(rename = "SignupSource")]
     SignupSourceSegment(SignupSourceSegment),
     /**
      * Segment by interaction with a SurveyMonkey survey.
      */
+    #[serde(rename = "SurveyMonkey")]
     SurveyMonkeySegment(SurveyMonkeySegment),
     /**
      * Segment by VIP status.
      */
+    #[serde(rename = "VIP")]
     VipSegment(VipSegment),
     /**
      * Segment by an interest group merge field.
      */
+    #[serde(rename = "Interests")]
     InterestsSegment(InterestsSegment),
     /**
      * Segment by purchases in specific items or categories.
      */
+    #[serde(rename = "EcommCategory")]
     EcommerceCategorySegment(EcommerceCategorySegment),
     /**
      * Segment by average spent total, number of orders, total number of products purchased, or average number of products per order.
      */
+    #[serde(rename = "EcommNumber")]
     EcommerceNumberSegment(EcommerceNumberSegment),
     /**
      * Segment by whether someone has purchased anything.
      */
+    #[serde(rename = "EcommPurchased")]
     EcommercePurchasedSegment(EcommercePurchasedSegment),
     /**
      * Segment by amount spent on a single order or across all orders.
      */
+    #[serde(rename = "EcommSpent")]
     EcommerceSpentSegment(EcommerceSpentSegment),
     /**
      * Segment by purchases from a specific store.
      */
+    #[serde(rename = "EcommStore")]
     EcommercePurchasedStoreSegment(EcommercePurchasedStoreSegment),
     /**
      * Segment by Goal activity.
      */
+    #[serde(rename = "GoalActivity")]
     GoalActivitySegment(GoalActivitySegment),
     /**
      * Segment by most recent interaction with a website.
      */
+    #[serde(rename = "GoalTimestamp")]
     GoalTimestampSegment(GoalTimestampSegment),
     /**
      * Segment by similar subscribers.
      */
+    #[serde(rename = "FuzzySegment")]
     SimilarSubscribersSegmentMember(SimilarSubscribersSegmentMember),
     /**
      * Segment by a given static segment.
      */
+    #[serde(rename = "StaticSegment")]
     StaticSegmentMember(StaticSegmentMember),
     /**
      * Segment by a specific country or US state.
      */
+    #[serde(rename = "IPGeoCountryState")]
     LocationBasedSegment(LocationBasedSegment),
     /**
      * Segment by a specific geographic region.
      */
+    #[serde(rename = "IPGeoIn")]
     GeolocationSegment(GeolocationSegment),
     /**
      * Segment by a specific US ZIP code.
      */
+    #[serde(rename = "IPGeoInZip")]
     UsZipCodeSegment(UsZipCodeSegment),
     /**
      * Segment members whose location information is unknown.
      */
+    #[serde(rename = "IPGeoUnknown")]
     UnknownLocationBasedSegment(UnknownLocationBasedSegment),
     /**
      * Segment by a specific US ZIP code.
      */
+    #[serde(rename = "IPGeoZip")]
     ZipCodeLocationBasedSegment(ZipCodeLocationBasedSegment),
     /**
      * Segment by age ranges in Social Profiles data.
      */
+    #[serde(rename = "SocialAge")]
     SocialProfilesAgeSegment(SocialProfilesAgeSegment),
     /**
      * Segment by listed gender in Social Profiles data.
      */
+    #[serde(rename = "SocialGender")]
     SocialProfilesGenderSegment(SocialProfilesGenderSegment),
     /**
      * Segment by influence rating in Social Profiles data.
      */
+    #[serde(rename = "SocialInfluence")]
     SocialProfilesInfluenceSegment(SocialProfilesInfluenceSegment),
     /**
      * Segment by social network in Social Profiles data.
      */
+    #[serde(rename = "SocialNetworkMember")]
     SocialProfilesNetworkSegment(SocialProfilesNetworkSegment),
     /**
      * Segment by social network in Social Profiles data.
      */
+    #[serde(rename = "SocialNetworkFollow")]
     SocialProfilesNetworkFollowSegment(SocialProfilesNetworkFollowSegment),
     /**
      * Segment by an address-type merge field.
      */
+    #[serde(rename = "AddressMerge")]
     AddressMergeFieldSegment(AddressMergeFieldSegment),
     /**
      * Segment by an address-type merge field within a given distance.
      */
+    #[serde(rename = "ZipMerge")]
     AddressZipMergeFieldSegment(AddressZipMergeFieldSegment),
     /**
      * Segment by a contact's birthday.
      */
+    #[serde(rename = "BirthdayMerge")]
     BirthdayMergeFieldSegment(BirthdayMergeFieldSegment),
     /**
      * Segment by a given date merge field.
      */
+    #[serde(rename = "DateMerge")]
     DateMergeFieldSegment(DateMergeFieldSegment),
     /**
      * An individual segment condition
      */
+    #[serde(rename = "SelectMerge")]
     DropdownRadioMergeFieldSegment(DropdownRadioMergeFieldSegment),
     /**
      * Segment by a given text or number merge field.
      */
+    #[serde(rename = "TextMerge")]
     TextNumberMergeFieldSegment(TextNumberMergeFieldSegment),
     /**
      * Segment by email address.
      */
+    #[serde(rename = "EmailAddress")]
     EmailSegment(EmailSegment),
     /**
      * Segment by predicted gender.
      */
+    #[serde(rename = "PredictedGender")]
     PredictedGenderSegment(PredictedGenderSegment),
     /**
      * Segment by predicted age.
      */
+    #[serde(rename = "PredictedAge")]
     PredictedAgeSegment(PredictedAgeSegment),
     /**
      * Segment by when people subscribed.
      */
+    #[serde(rename = "NewSubscribers")]
     NewSubscribersPrebuiltSegment(NewSubscribersPrebuiltSegment),
 }
 
+impl Serialize for ConditionsOneOf {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            ConditionsOneOf::Conditions(v) => {
+                crate::utils::serialize_tagged(v, "condition_type", "Aim", serializer)
+            }
+            ConditionsOneOf::AutomationSegment(v) => {
+                crate::utils::serialize_tagged(v, "condition_type", "Automation", serializer)
+            }
+            ConditionsOneOf::PollActivitySegment(v) => {
+                crate::utils::serialize_tagged(v, "condition_type", "CampaignPoll", serializer)
+            }
+            ConditionsOneOf::ConversationSegment(v) => {
+                crate::utils::serialize_tagged(v, "condition_type", "Conversation", serializer)
+            }
+            ConditionsOneOf::DateSegment(v) => {
+                crate::utils::serialize_tagged(v, "condition_type", "Date", serializer)
+            }
+            ConditionsOneOf::EmailClientSegment(v) => {
+                crate::utils::serialize_tagged(v, "condition_type", "EmailClient", serializer)
+            }
+            ConditionsOneOf::LanguageSegment(v) => {
+                crate::utils::serialize_tagged(v, "condition_type", "Language", serializer)
+            }
+            ConditionsOneOf::MemberRatingSegment(v) => {
+                crate::utils::serialize_tagged(v, "condition_type", "MemberRating", serializer)
+            }
+            ConditionsOneOf::SignupSourceSegment(v) => {
+                crate::utils::serialize_tagged(v, "condition_type", "SignupSource", serializer)
+            }
+            ConditionsOneOf::SurveyMonkeySegment(v) => {
+                crate::utils::serialize_tagged(v, "condition_type", "SurveyMonkey", serializer)
+            }
+            ConditionsOneOf::VipSegment(v) => {
+                crate::utils::serialize_tagged(v, "condition_type", "VIP", serializer)
+            }
+            ConditionsOneOf::InterestsSegment(v) => {
+                crate::utils::serialize_tagged(v, "condition_type", "Interests", serializer)
+            }
+            ConditionsOneOf::EcommerceCategorySegment(v) => {
+                crate::utils::serialize_tagged(v, "condition_type", "EcommCategory", serializer)
+            }
+            ConditionsOneOf::EcommerceNumberSegment(v) => {
+                crate::utils::serialize_tagged(v, "condition_type", "EcommNumber", serializer)
+            }
+            ConditionsOneOf::EcommercePurchasedSegment(v) => {
+                crate::utils::serialize_tagged(v, "condition_type", "EcommPurchased", serializer)
+            }
+            ConditionsOneOf::EcommerceSpentSegment(v) => {
+                crate::utils::serialize_tagged(v, "condition_type", "EcommSpent", serializer)
+            }
+            ConditionsOneOf::EcommercePurchasedStoreSegment(v) => {
+                crate::utils::serialize_tagged(v, "condition_type", "EcommStore", serializer)
+            }
+            ConditionsOneOf::GoalActivitySegment(v) => {
+                crate::utils::serialize_tagged(v, "condition_type", "GoalActivity", serializer)
+            }
+            ConditionsOneOf::GoalTimestampSegment(v) => {
+                crate::utils::serialize_tagged(v, "condition_type", "GoalTimestamp", serializer)
+            }
+            ConditionsOneOf::SimilarSubscribersSegmentMember(v) => {
+                crate::utils::serialize_tagged(v, "condition_type", "FuzzySegment", serializer)
+            }
+            ConditionsOneOf::StaticSegmentMember(v) => {
+                crate::utils::serialize_tagged(v, "condition_type", "StaticSegment", serializer)
+            }
+            ConditionsOneOf::LocationBasedSegment(v) => {
+                crate::utils::serialize_tagged(v, "condition_type", "IPGeoCountryState", serializer)
+            }
+            ConditionsOneOf::GeolocationSegment(v) => {
+                crate::utils::serialize_tagged(v, "condition_type", "IPGeoIn", serializer)
+            }
+            ConditionsOneOf::UsZipCodeSegment(v) => {
+                crate::utils::serialize_tagged(v, "condition_type", "IPGeoInZip", serializer)
+            }
+            ConditionsOneOf::UnknownLocationBasedSegment(v) => {
+                crate::utils::serialize_tagged(v, "condition_type", "IPGeoUnknown", serializer)
+            }
+            ConditionsOneOf::ZipCodeLocationBasedSegment(v) => {
+                crate::utils::serialize_tagged(v, "condition_type", "IPGeoZip", serializer)
+            }
+            ConditionsOneOf::SocialProfilesAgeSegment(v) => {
+                crate::utils::serialize_tagged(v, "condition_type", "SocialAge", serializer)
+            }
+            ConditionsOneOf::SocialProfilesGenderSegment(v) => {
+                crate::utils::serialize_tagged(v, "condition_type", "SocialGender", serializer)
+            }
+            ConditionsOneOf::SocialProfilesInfluenceSegment(v) => {
+                crate::utils::serialize_tagged(v, "condition_type", "SocialInfluence", serializer)
+            }
+            ConditionsOneOf::SocialProfilesNetworkSegment(v) => crate::utils::serialize_tagged(
+                v,
+                "condition_type",
+                "SocialNetworkMember",
+                serializer,
+            ),
+            ConditionsOneOf::SocialProfilesNetworkFollowSegment(v) => {
+                crate::utils::serialize_tagged(
+                    v,
+                    "condition_type",
+                    "SocialNetworkFollow",
+                    serializer,
+                )
+            }
+            ConditionsOneOf::AddressMergeFieldSegment(v) => {
+                crate::utils::serialize_tagged(v, "condition_type", "AddressMerge", serializer)
+            }
+            ConditionsOneOf::AddressZipMergeFieldSegment(v) => {
+                crate::utils::serialize_tagged(v, "condition_type", "ZipMerge", serializer)
+            }
+            ConditionsOneOf::BirthdayMergeFieldSegment(v) => {
+                crate::utils::serialize_tagged(v, "condition_type", "BirthdayMerge", serializer)
+            }
+            ConditionsOneOf::DateMergeFieldSegment(v) => {
+                crate::utils::serialize_tagged(v, "condition_type", "DateMerge", serializer)
+            }
+            ConditionsOneOf::DropdownRadioMergeFieldSegment(v) => {
+                crate::utils::serialize_tagged(v, "condition_type", "SelectMerge", serializer)
+            }
+            ConditionsOneOf::TextNumberMergeFieldSegment(v) => {
+                crate::utils::serialize_tagged(v, "condition_type", "TextMerge", serializer)
+            }
+            ConditionsOneOf::EmailSegment(v) => {
+                crate::utils::serialize_tagged(v, "condition_type", "EmailAddress", serializer)
+            }
+            ConditionsOneOf::PredictedGenderSegment(v) => {
+                crate::utils::serialize_tagged(v, "condition_type", "PredictedGender", serializer)
+            }
+            ConditionsOneOf::PredictedAgeSegment(v) => {
+                crate::utils::serialize_tagged(v, "condition_type", "PredictedAge", serializer)
+            }
+            ConditionsOneOf::NewSubscribersPrebuiltSegment(v) => {
+                crate::utils::serialize_tagged(v, "condition_type", "NewSubscribers", serializer)
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ConditionsOneOf {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (tag, value) = crate::utils::deserialize_tagged(deserializer, "condition_type")?;
+        match tag.as_str() {
+            "Aim" => serde_json::from_value(value).map(ConditionsOneOf::Conditions),
+            "Automation" => serde_json::from_value(value).map(ConditionsOneOf::AutomationSegment),
+            "CampaignPoll" => {
+                serde_json::from_value(value).map(ConditionsOneOf::PollActivitySegment)
+            }
+            "Conversation" => {
+                serde_json::from_value(value).map(ConditionsOneOf::ConversationSegment)
+            }
+            "Date" => serde_json::from_value(value).map(ConditionsOneOf::DateSegment),
+            "EmailClient" => serde_json::from_value(value).map(ConditionsOneOf::EmailClientSegment),
+            "Language" => serde_json::from_value(value).map(ConditionsOneOf::LanguageSegment),
+            "MemberRating" => {
+                serde_json::from_value(value).map(ConditionsOneOf::MemberRatingSegment)
+            }
+            "SignupSource" => {
+                serde_json::from_value(value).map(ConditionsOneOf::SignupSourceSegment)
+            }
+            "SurveyMonkey" => {
+                serde_json::from_value(value).map(ConditionsOneOf::SurveyMonkeySegment)
+            }
+            "VIP" => serde_json::from_value(value).map(ConditionsOneOf::VipSegment),
+            "Interests" => serde_json::from_value(value).map(ConditionsOneOf::InterestsSegment),
+            "EcommCategory" => {
+                serde_json::from_value(value).map(ConditionsOneOf::EcommerceCategorySegment)
+            }
+            "EcommNumber" => {
+                serde_json::from_value(value).map(ConditionsOneOf::EcommerceNumberSegment)
+            }
+            "EcommPurchased" => {
+                serde_json::from_value(value).map(ConditionsOneOf::EcommercePurchasedSegment)
+            }
+            "EcommSpent" => {
+                serde_json::from_value(value).map(ConditionsOneOf::EcommerceSpentSegment)
+            }
+            "EcommStore" => {
+                serde_json::from_value(value).map(ConditionsOneOf::EcommercePurchasedStoreSegment)
+            }
+            "GoalActivity" => {
+                serde_json::from_value(value).map(ConditionsOneOf::GoalActivitySegment)
+            }
+            "GoalTimestamp" => {
+                serde_json::from_value(value).map(ConditionsOneOf::GoalTimestampSegment)
+            }
+            "FuzzySegment" => {
+                serde_json::from_value(value).map(ConditionsOneOf::SimilarSubscribersSegmentMember)
+            }
+            "StaticSegment" => {
+                serde_json::from_value(value).map(ConditionsOneOf::StaticSegmentMember)
+            }
+            "IPGeoCountryState" => {
+                serde_json::from_value(value).map(ConditionsOneOf::LocationBasedSegment)
+            }
+            "IPGeoIn" => serde_json::from_value(value).map(ConditionsOneOf::GeolocationSegment),
+            "IPGeoInZip" => serde_json::from_value(value).map(ConditionsOneOf::UsZipCodeSegment),
+            "IPGeoUnknown" => {
+                serde_json::from_value(value).map(ConditionsOneOf::UnknownLocationBasedSegment)
+            }
+            "IPGeoZip" => {
+                serde_json::from_value(value).map(ConditionsOneOf::ZipCodeLocationBasedSegment)
+            }
+            "SocialAge" => {
+                serde_json::from_value(value).map(ConditionsOneOf::SocialProfilesAgeSegment)
+            }
+            "SocialGender" => {
+                serde_json::from_value(value).map(ConditionsOneOf::SocialProfilesGenderSegment)
+            }
+            "SocialInfluence" => {
+                serde_json::from_value(value).map(ConditionsOneOf::SocialProfilesInfluenceSegment)
+            }
+            "SocialNetworkMember" => {
+                serde_json::from_value(value).map(ConditionsOneOf::SocialProfilesNetworkSegment)
+            }
+            "SocialNetworkFollow" => serde_json::from_value(value)
+                .map(ConditionsOneOf::SocialProfilesNetworkFollowSegment),
+            "AddressMerge" => {
+                serde_json::from_value(value).map(ConditionsOneOf::AddressMergeFieldSegment)
+            }
+            "ZipMerge" => {
+                serde_json::from_value(value).map(ConditionsOneOf::AddressZipMergeFieldSegment)
+            }
+            "BirthdayMerge" => {
+                serde_json::from_value(value).map(ConditionsOneOf::BirthdayMergeFieldSegment)
+            }
+            "DateMerge" => {
+                serde_json::from_value(value).map(ConditionsOneOf::DateMergeFieldSegment)
+            }
+            "SelectMerge" => {
+                serde_json::from_value(value).map(ConditionsOneOf::DropdownRadioMergeFieldSegment)
+            }
+            "TextMerge" => {
+                serde_json::from_value(value).map(ConditionsOneOf::TextNumberMergeFieldSegment)
+            }
+            "EmailAddress" => serde_json::from_value(value).map(ConditionsOneOf::EmailSegment),
+            "PredictedGender" => {
+                serde_json::from_value(value).map(ConditionsOneOf::PredictedGenderSegment)
+            }
+            "PredictedAge" => {
+                serde_json::from_value(value).map(ConditionsOneOf::PredictedAgeSegment)
+            }
+            "NewSubscribers" => {
+                serde_json::from_value(value).map(ConditionsOneOf::NewSubscribersPrebuiltSegment)
+            }
+            _ => {
+                return Err(serde::de::Error::unknown_variant(
+                    &tag,
+                    &[
+                        "Aim",
+                        "Automation",
+                        "CampaignPoll",
+                        "Conversation",
+                        "Date",
+                        "EmailClient",
+                        "Language",
+                        "MemberRating",
+                        "SignupSource",
+                        "SurveyMonkey",
+                        "VIP",
+                        "Interests",
+                        "EcommCategory",
+                        "EcommNumber",
+                        "EcommPurchased",
+                        "EcommSpent",
+                        "EcommStore",
+                        "GoalActivity",
+                        "GoalTimestamp",
+                        "FuzzySegment",
+                        "StaticSegment",
+                        "IPGeoCountryState",
+                        "IPGeoIn",
+                        "IPGeoInZip",
+                        "IPGeoUnknown",
+                        "IPGeoZip",
+                        "SocialAge",
+                        "SocialGender",
+                        "SocialInfluence",
+                        "SocialNetworkMember",
+                        "SocialNetworkFollow",
+                        "AddressMerge",
+                        "ZipMerge",
+                        "BirthdayMerge",
+                        "DateMerge",
+                        "SelectMerge",
+                        "TextMerge",
+                        "EmailAddress",
+                        "PredictedGender",
+                        "PredictedAge",
+                        "NewSubscribers",
+                    ],
+                ))
+            }
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+
 impl ConditionsOneOf {
     pub fn address_merge_field_segment(&self) -> Option<&AddressMergeFieldSegment> {
         if let ConditionsOneOf::AddressMergeFieldSegment(ref_) = self {
@@ -6995,7 +9338,7 @@ pub struct Tracking {
 /**
  * The type of Automation workflow.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum WorkflowType {
     #[serde(rename = "abandonedBrowse")]
     AbandonedBrowse,
@@ -7069,13 +9412,62 @@ impl Default for WorkflowType {
         WorkflowType::Noop
     }
 }
+
 impl WorkflowType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WorkflowType::AbandonedBrowse => "abandonedBrowse",
+            WorkflowType::AbandonedCart => "abandonedCart",
+            WorkflowType::Api => "api",
+            WorkflowType::BestCustomers => "bestCustomers",
+            WorkflowType::CategoryFollowup => "categoryFollowup",
+            WorkflowType::DateAdded => "dateAdded",
+            WorkflowType::EmailFollowup => "emailFollowup",
+            WorkflowType::EmailSeries => "emailSeries",
+            WorkflowType::GroupAdd => "groupAdd",
+            WorkflowType::GroupRemove => "groupRemove",
+            WorkflowType::Mandrill => "mandrill",
+            WorkflowType::ProductFollowup => "productFollowup",
+            WorkflowType::PurchaseFollowup => "purchaseFollowup",
+            WorkflowType::RecurringEvent => "recurringEvent",
+            WorkflowType::SpecialEvent => "specialEvent",
+            WorkflowType::VisitUrl => "visitUrl",
+            WorkflowType::WelcomeSeries => "welcomeSeries",
+            WorkflowType::Noop => "",
+            WorkflowType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [WorkflowType] {
+        &[
+            WorkflowType::AbandonedBrowse,
+            WorkflowType::AbandonedCart,
+            WorkflowType::Api,
+            WorkflowType::BestCustomers,
+            WorkflowType::CategoryFollowup,
+            WorkflowType::DateAdded,
+            WorkflowType::EmailFollowup,
+            WorkflowType::EmailSeries,
+            WorkflowType::GroupAdd,
+            WorkflowType::GroupRemove,
+            WorkflowType::Mandrill,
+            WorkflowType::ProductFollowup,
+            WorkflowType::PurchaseFollowup,
+            WorkflowType::RecurringEvent,
+            WorkflowType::SpecialEvent,
+            WorkflowType::VisitUrl,
+            WorkflowType::WelcomeSeries,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, WorkflowType::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Days {
     #[serde(rename = "friday")]
     Friday,
@@ -7119,7 +9511,36 @@ impl Default for Days {
         Days::Noop
     }
 }
+
 impl Days {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Days::Friday => "friday",
+            Days::Monday => "monday",
+            Days::Saturday => "saturday",
+            Days::Sunday => "sunday",
+            Days::Thursday => "thursday",
+            Days::Tuesday => "tuesday",
+            Days::Wednesday => "wednesday",
+            Days::Noop => "",
+            Days::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Days] {
+        &[
+            Days::Friday,
+            Days::Monday,
+            Days::Saturday,
+            Days::Sunday,
+            Days::Thursday,
+            Days::Tuesday,
+            Days::Wednesday,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Days::Noop)
     }
@@ -7128,7 +9549,7 @@ impl Days {
 /**
  * When to send the Automation email.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum EmailSendTimeSettings {
     #[serde(rename = "send_asap")]
     SendAsap,
@@ -7160,7 +9581,28 @@ impl Default for EmailSendTimeSettings {
         EmailSendTimeSettings::Noop
     }
 }
+
 impl EmailSendTimeSettings {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EmailSendTimeSettings::SendAsap => "send_asap",
+            EmailSendTimeSettings::SendAt => "send_at",
+            EmailSendTimeSettings::SendBetween => "send_between",
+            EmailSendTimeSettings::Noop => "",
+            EmailSendTimeSettings::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [EmailSendTimeSettings] {
+        &[
+            EmailSendTimeSettings::SendAsap,
+            EmailSendTimeSettings::SendAt,
+            EmailSendTimeSettings::SendBetween,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, EmailSendTimeSettings::Noop)
     }
@@ -7488,7 +9930,7 @@ pub struct AutomationWorkflow {
 /**
  * The type of delay for an Automation email.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum DelayType {
     #[serde(rename = "day")]
     Day,
@@ -7523,7 +9965,30 @@ impl Default for DelayType {
         DelayType::Noop
     }
 }
+
 impl DelayType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DelayType::Day => "day",
+            DelayType::Hour => "hour",
+            DelayType::Now => "now",
+            DelayType::Week => "week",
+            DelayType::Noop => "",
+            DelayType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [DelayType] {
+        &[
+            DelayType::Day,
+            DelayType::Hour,
+            DelayType::Now,
+            DelayType::Week,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, DelayType::Noop)
     }
@@ -7532,7 +9997,7 @@ impl DelayType {
 /**
  * Whether the delay settings describe before or after the delay action of an Automation email.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Direction {
     #[serde(rename = "after")]
     After,
@@ -7561,7 +10026,23 @@ impl Default for Direction {
         Direction::Noop
     }
 }
+
 impl Direction {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Direction::After => "after",
+            Direction::Before => "before",
+            Direction::Noop => "",
+            Direction::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Direction] {
+        &[Direction::After, Direction::Before]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Direction::Noop)
     }
@@ -7570,7 +10051,7 @@ impl Direction {
 /**
  * The action that triggers the delay of an Automation email.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Action {
     #[serde(rename = "annual")]
     Annual,
@@ -7644,9 +10125,59 @@ pub enum Action {
     FallthroughString,
 }
 
-impl std::fmt::Display for Action {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl std::fmt::Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            Action::Annual => "annual",
+            Action::Api => "api",
+            Action::Birthday => "birthday",
+            Action::CampaignSent => "campaign_sent",
+            Action::CampaignSpecificClicked => "campaign_specific_clicked",
+            Action::ClickedEmail => "clicked_email",
+            Action::Date => "date",
+            Action::DateAdded => "date_added",
+            Action::EcommAbandonedCart => "ecomm_abandoned_cart",
+            Action::EcommBoughtAny => "ecomm_bought_any",
+            Action::EcommBoughtCategory => "ecomm_bought_category",
+            Action::EcommBoughtProduct => "ecomm_bought_product",
+            Action::EcommNotBoughtAny => "ecomm_not_bought_any",
+            Action::Goal => "goal",
+            Action::GroupAdd => "group_add",
+            Action::GroupRemove => "group_remove",
+            Action::MandrillAny => "mandrill_any",
+            Action::MandrillClicked => "mandrill_clicked",
+            Action::MandrillOpened => "mandrill_opened",
+            Action::MandrillSent => "mandrill_sent",
+            Action::Manual => "manual",
+            Action::MergeChanged => "merge_changed",
+            Action::NotClickedEmail => "not_clicked_email",
+            Action::NotOpenedEmail => "not_opened_email",
+            Action::OpenedEmail => "opened_email",
+            Action::PreviousCampaignClickedAny => "previous_campaign_clicked_any",
+            Action::PreviousCampaignNotClickedAny => "previous_campaign_not_clicked_any",
+            Action::PreviousCampaignNotOpened => "previous_campaign_not_opened",
+            Action::PreviousCampaignOpened => "previous_campaign_opened",
+            Action::PreviousCampaignSent => "previous_campaign_sent",
+            Action::PreviousCampaignSpecificClicked => "previous_campaign_specific_clicked",
+            Action::Signup => "signup",
+            Action::TagAdd => "tag_add",
+            Action::Noop => "",
+            Action::FallthroughString => "*",
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for Action {
+    fn default() -> Action {
+        Action::Noop
+    }
+}
+
+impl Action {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
             Action::Annual => "annual",
             Action::Api => "api",
             Action::Birthday => "birthday",
@@ -7683,16 +10214,47 @@ impl std::fmt::Display for Action {
             Action::Noop => "",
             Action::FallthroughString => "*",
         }
-        .fmt(f)
     }
-}
 
-impl Default for Action {
-    fn default() -> Action {
-        Action::Noop
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Action] {
+        &[
+            Action::Annual,
+            Action::Api,
+            Action::Birthday,
+            Action::CampaignSent,
+            Action::CampaignSpecificClicked,
+            Action::ClickedEmail,
+            Action::Date,
+            Action::DateAdded,
+            Action::EcommAbandonedCart,
+            Action::EcommBoughtAny,
+            Action::EcommBoughtCategory,
+            Action::EcommBoughtProduct,
+            Action::EcommNotBoughtAny,
+            Action::Goal,
+            Action::GroupAdd,
+            Action::GroupRemove,
+            Action::MandrillAny,
+            Action::MandrillClicked,
+            Action::MandrillOpened,
+            Action::MandrillSent,
+            Action::Manual,
+            Action::MergeChanged,
+            Action::NotClickedEmail,
+            Action::NotOpenedEmail,
+            Action::OpenedEmail,
+            Action::PreviousCampaignClickedAny,
+            Action::PreviousCampaignNotClickedAny,
+            Action::PreviousCampaignNotOpened,
+            Action::PreviousCampaignOpened,
+            Action::PreviousCampaignSent,
+            Action::PreviousCampaignSpecificClicked,
+            Action::Signup,
+            Action::TagAdd,
+        ]
     }
-}
-impl Action {
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Action::Noop)
     }
@@ -8364,7 +10926,7 @@ pub struct UpdateInformationAboutASpecificWorkflowEmailCampaignSettings {
 /**
  * Whether the delay settings describe before or after the delay action of an automation email.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum DelayDirection {
     #[serde(rename = "after")]
     After,
@@ -8390,7 +10952,22 @@ impl Default for DelayDirection {
         DelayDirection::Noop
     }
 }
+
 impl DelayDirection {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DelayDirection::After => "after",
+            DelayDirection::Noop => "",
+            DelayDirection::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [DelayDirection] {
+        &[DelayDirection::After]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, DelayDirection::Noop)
     }
@@ -8399,7 +10976,7 @@ impl DelayDirection {
 /**
  * The action that triggers the delay of an automation emails.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum DelayAction {
     #[serde(rename = "ecomm_abandoned_browse")]
     EcommAbandonedBrowse,
@@ -8431,7 +11008,28 @@ impl Default for DelayAction {
         DelayAction::Noop
     }
 }
+
 impl DelayAction {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DelayAction::EcommAbandonedBrowse => "ecomm_abandoned_browse",
+            DelayAction::EcommAbandonedCart => "ecomm_abandoned_cart",
+            DelayAction::Signup => "signup",
+            DelayAction::Noop => "",
+            DelayAction::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [DelayAction] {
+        &[
+            DelayAction::EcommAbandonedBrowse,
+            DelayAction::EcommAbandonedCart,
+            DelayAction::Signup,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, DelayAction::Noop)
     }
@@ -8774,7 +11372,7 @@ pub struct RemovedSubscribers {
 /**
  * The status of the batch call. [Learn more](https://mailchimp.com/developer/marketing/guides/run-async-requests-batch-endpoint/#check-the-status-of-a-batch-operation) about the batch operation status.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum BatchOperationsStatus {
     #[serde(rename = "finalizing")]
     Finalizing,
@@ -8812,7 +11410,32 @@ impl Default for BatchOperationsStatus {
         BatchOperationsStatus::Noop
     }
 }
+
 impl BatchOperationsStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BatchOperationsStatus::Finalizing => "finalizing",
+            BatchOperationsStatus::Finished => "finished",
+            BatchOperationsStatus::Pending => "pending",
+            BatchOperationsStatus::Preprocessing => "preprocessing",
+            BatchOperationsStatus::Started => "started",
+            BatchOperationsStatus::Noop => "",
+            BatchOperationsStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [BatchOperationsStatus] {
+        &[
+            BatchOperationsStatus::Finalizing,
+            BatchOperationsStatus::Finished,
+            BatchOperationsStatus::Pending,
+            BatchOperationsStatus::Preprocessing,
+            BatchOperationsStatus::Started,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, BatchOperationsStatus::Noop)
     }
@@ -8937,7 +11560,7 @@ pub struct BatchOperations {
 /**
  * The HTTP method to use for the operation.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum HttpMethod {
     #[serde(rename = "DELETE")]
     Delete,
@@ -8975,7 +11598,32 @@ impl Default for HttpMethod {
         HttpMethod::Noop
     }
 }
+
 impl HttpMethod {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HttpMethod::Delete => "DELETE",
+            HttpMethod::Get => "GET",
+            HttpMethod::Patch => "PATCH",
+            HttpMethod::Post => "POST",
+            HttpMethod::Put => "PUT",
+            HttpMethod::Noop => "",
+            HttpMethod::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [HttpMethod] {
+        &[
+            HttpMethod::Delete,
+            HttpMethod::Get,
+            HttpMethod::Patch,
+            HttpMethod::Post,
+            HttpMethod::Put,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, HttpMethod::Noop)
     }
@@ -9263,7 +11911,7 @@ pub struct CampaignFolders {
 /**
  * There are four types of [campaigns](https://mailchimp.com/help/getting-started-with-campaigns/) you can create in Mailchimp. A/B Split campaigns have been deprecated and variate campaigns should be used instead.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum CampaignType {
     #[serde(rename = "absplit")]
     Absplit,
@@ -9301,7 +11949,32 @@ impl Default for CampaignType {
         CampaignType::Noop
     }
 }
+
 impl CampaignType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CampaignType::Absplit => "absplit",
+            CampaignType::Plaintext => "plaintext",
+            CampaignType::Regular => "regular",
+            CampaignType::Rss => "rss",
+            CampaignType::Variate => "variate",
+            CampaignType::Noop => "",
+            CampaignType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [CampaignType] {
+        &[
+            CampaignType::Absplit,
+            CampaignType::Plaintext,
+            CampaignType::Regular,
+            CampaignType::Rss,
+            CampaignType::Variate,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, CampaignType::Noop)
     }
@@ -9310,7 +11983,7 @@ impl CampaignType {
 /**
  * The status of the campaign.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GetCampaignsStatus {
     #[serde(rename = "paused")]
     Paused,
@@ -9348,7 +12021,32 @@ impl Default for GetCampaignsStatus {
         GetCampaignsStatus::Noop
     }
 }
+
 impl GetCampaignsStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GetCampaignsStatus::Paused => "paused",
+            GetCampaignsStatus::Save => "save",
+            GetCampaignsStatus::Schedule => "schedule",
+            GetCampaignsStatus::Sending => "sending",
+            GetCampaignsStatus::Sent => "sent",
+            GetCampaignsStatus::Noop => "",
+            GetCampaignsStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GetCampaignsStatus] {
+        &[
+            GetCampaignsStatus::Paused,
+            GetCampaignsStatus::Save,
+            GetCampaignsStatus::Schedule,
+            GetCampaignsStatus::Sending,
+            GetCampaignsStatus::Sent,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, GetCampaignsStatus::Noop)
     }
@@ -9357,7 +12055,7 @@ impl GetCampaignsStatus {
 /**
  * Returns files sorted by the specified field.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SortField {
     #[serde(rename = "create_time")]
     CreateTime,
@@ -9386,7 +12084,23 @@ impl Default for SortField {
         SortField::Noop
     }
 }
+
 impl SortField {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SortField::CreateTime => "create_time",
+            SortField::SendTime => "send_time",
+            SortField::Noop => "",
+            SortField::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SortField] {
+        &[SortField::CreateTime, SortField::SendTime]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SortField::Noop)
     }
@@ -9395,7 +12109,7 @@ impl SortField {
 /**
  * Determines the order direction for sorted results.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SortDir {
     #[serde(rename = "ASC")]
     Asc,
@@ -9424,7 +12138,23 @@ impl Default for SortDir {
         SortDir::Noop
     }
 }
+
 impl SortDir {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SortDir::Asc => "ASC",
+            SortDir::Desc => "DESC",
+            SortDir::Noop => "",
+            SortDir::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SortDir] {
+        &[SortDir::Asc, SortDir::Desc]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SortDir::Noop)
     }
@@ -9433,7 +12163,7 @@ impl SortDir {
 /**
  * The current status of the campaign.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum CampaignStatus {
     #[serde(rename = "archived")]
     Archived,
@@ -9480,7 +12210,38 @@ impl Default for CampaignStatus {
         CampaignStatus::Noop
     }
 }
+
 impl CampaignStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CampaignStatus::Archived => "archived",
+            CampaignStatus::Canceled => "canceled",
+            CampaignStatus::Canceling => "canceling",
+            CampaignStatus::Paused => "paused",
+            CampaignStatus::Save => "save",
+            CampaignStatus::Schedule => "schedule",
+            CampaignStatus::Sending => "sending",
+            CampaignStatus::Sent => "sent",
+            CampaignStatus::Noop => "",
+            CampaignStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [CampaignStatus] {
+        &[
+            CampaignStatus::Archived,
+            CampaignStatus::Canceled,
+            CampaignStatus::Canceling,
+            CampaignStatus::Paused,
+            CampaignStatus::Save,
+            CampaignStatus::Schedule,
+            CampaignStatus::Sending,
+            CampaignStatus::Sent,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, CampaignStatus::Noop)
     }
@@ -9489,7 +12250,7 @@ impl CampaignStatus {
 /**
  * How the campaign's content is put together.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ContentType {
     #[serde(rename = "html")]
     Html,
@@ -9524,7 +12285,30 @@ impl Default for ContentType {
         ContentType::Noop
     }
 }
+
 impl ContentType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ContentType::Html => "html",
+            ContentType::Multichannel => "multichannel",
+            ContentType::Template => "template",
+            ContentType::Url => "url",
+            ContentType::Noop => "",
+            ContentType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ContentType] {
+        &[
+            ContentType::Html,
+            ContentType::Multichannel,
+            ContentType::Template,
+            ContentType::Url,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ContentType::Noop)
     }
@@ -9683,7 +12467,7 @@ pub struct GetCampaignsResponseCampaignSettings {
 /**
  * The combination that performs the best. This may be determined automatically by click rate, open rate, or total revenue -- or you may choose manually based on the reporting data you find the most valuable. For Multivariate Campaigns testing send_time, winner_criteria is ignored. For Multivariate Campaigns with 'manual' as the winner_criteria, the winner must be chosen in the Mailchimp web application.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum WinnerCriteria {
     #[serde(rename = "clicks")]
     Clicks,
@@ -9718,7 +12502,30 @@ impl Default for WinnerCriteria {
         WinnerCriteria::Noop
     }
 }
+
 impl WinnerCriteria {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WinnerCriteria::Clicks => "clicks",
+            WinnerCriteria::Manual => "manual",
+            WinnerCriteria::Opens => "opens",
+            WinnerCriteria::TotalRevenue => "total_revenue",
+            WinnerCriteria::Noop => "",
+            WinnerCriteria::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [WinnerCriteria] {
+        &[
+            WinnerCriteria::Clicks,
+            WinnerCriteria::Manual,
+            WinnerCriteria::Opens,
+            WinnerCriteria::TotalRevenue,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, WinnerCriteria::Noop)
     }
@@ -9894,7 +12701,7 @@ pub struct ABTestOptions {
 /**
  * The frequency of the RSS Campaign.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Frequency {
     #[serde(rename = "daily")]
     Daily,
@@ -9926,7 +12733,24 @@ impl Default for Frequency {
         Frequency::Noop
     }
 }
+
 impl Frequency {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Frequency::Daily => "daily",
+            Frequency::Monthly => "monthly",
+            Frequency::Weekly => "weekly",
+            Frequency::Noop => "",
+            Frequency::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Frequency] {
+        &[Frequency::Daily, Frequency::Monthly, Frequency::Weekly]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Frequency::Noop)
     }
@@ -10070,7 +12894,7 @@ pub struct RssOpts {
 /**
  * The type of AB split to run.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SplitTest {
     #[serde(rename = "from_name")]
     FromName,
@@ -10102,7 +12926,24 @@ impl Default for SplitTest {
         SplitTest::Noop
     }
 }
+
 impl SplitTest {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SplitTest::FromName => "from_name",
+            SplitTest::Schedule => "schedule",
+            SplitTest::Subject => "subject",
+            SplitTest::Noop => "",
+            SplitTest::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SplitTest] {
+        &[SplitTest::FromName, SplitTest::Schedule, SplitTest::Subject]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SplitTest::Noop)
     }
@@ -10111,7 +12952,7 @@ impl SplitTest {
 /**
  * How we should evaluate a winner. Based on 'opens', 'clicks', or 'manual'.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PickWinner {
     #[serde(rename = "clicks")]
     Clicks,
@@ -10143,7 +12984,24 @@ impl Default for PickWinner {
         PickWinner::Noop
     }
 }
+
 impl PickWinner {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PickWinner::Clicks => "clicks",
+            PickWinner::Manual => "manual",
+            PickWinner::Opens => "opens",
+            PickWinner::Noop => "",
+            PickWinner::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PickWinner] {
+        &[PickWinner::Clicks, PickWinner::Manual, PickWinner::Opens]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PickWinner::Noop)
     }
@@ -10152,7 +13010,7 @@ impl PickWinner {
 /**
  * How unit of time for measuring the winner ('hours' or 'days'). This cannot be changed after a campaign is sent.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum WaitTime {
     #[serde(rename = "days")]
     Days,
@@ -10181,7 +13039,23 @@ impl Default for WaitTime {
         WaitTime::Noop
     }
 }
+
 impl WaitTime {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WaitTime::Days => "days",
+            WaitTime::Hours => "hours",
+            WaitTime::Noop => "",
+            WaitTime::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [WaitTime] {
+        &[WaitTime::Days, WaitTime::Hours]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, WaitTime::Noop)
     }
@@ -10405,7 +13279,7 @@ pub struct GetCampaignsResponseCampaignReportSummary {
 /**
  * The current state of a campaign delivery.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum CampaignDeliveryStatus {
     #[serde(rename = "canceled")]
     Canceled,
@@ -10440,7 +13314,30 @@ impl Default for CampaignDeliveryStatus {
         CampaignDeliveryStatus::Noop
     }
 }
+
 impl CampaignDeliveryStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CampaignDeliveryStatus::Canceled => "canceled",
+            CampaignDeliveryStatus::Canceling => "canceling",
+            CampaignDeliveryStatus::Delivered => "delivered",
+            CampaignDeliveryStatus::Delivering => "delivering",
+            CampaignDeliveryStatus::Noop => "",
+            CampaignDeliveryStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [CampaignDeliveryStatus] {
+        &[
+            CampaignDeliveryStatus::Canceled,
+            CampaignDeliveryStatus::Canceling,
+            CampaignDeliveryStatus::Delivered,
+            CampaignDeliveryStatus::Delivering,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, CampaignDeliveryStatus::Noop)
     }
@@ -10966,7 +13863,7 @@ pub struct RssOptions {
 /**
  * How the campaign's content is put together. The old drag and drop editor uses 'template' while the new editor uses 'multichannel'. Defaults to template.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum CreatedCampaignContentType {
     #[serde(rename = "multichannel")]
     Multichannel,
@@ -10995,7 +13892,26 @@ impl Default for CreatedCampaignContentType {
         CreatedCampaignContentType::Noop
     }
 }
+
 impl CreatedCampaignContentType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CreatedCampaignContentType::Multichannel => "multichannel",
+            CreatedCampaignContentType::Template => "template",
+            CreatedCampaignContentType::Noop => "",
+            CreatedCampaignContentType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [CreatedCampaignContentType] {
+        &[
+            CreatedCampaignContentType::Multichannel,
+            CreatedCampaignContentType::Template,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, CreatedCampaignContentType::Noop)
     }
@@ -11741,7 +14657,7 @@ pub struct PostCampaignsActionsScheduleRequest {
 /**
  * Choose the type of test email to send.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SendType {
     #[serde(rename = "html")]
     Html,
@@ -11770,7 +14686,23 @@ impl Default for SendType {
         SendType::Noop
     }
 }
+
 impl SendType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SendType::Html => "html",
+            SendType::Plaintext => "plaintext",
+            SendType::Noop => "",
+            SendType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SendType] {
+        &[SendType::Html, SendType::Plaintext]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SendType::Noop)
     }
@@ -11898,7 +14830,7 @@ pub struct Template {
 /**
  * The type of encoded file. Defaults to zip.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ArchiveType {
     #[serde(rename = "tar")]
     Tar,
@@ -11938,8 +14870,35 @@ impl Default for ArchiveType {
     fn default() -> ArchiveType {
         ArchiveType::Noop
     }
-}
-impl ArchiveType {
+}
+
+impl ArchiveType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ArchiveType::Tar => "tar",
+            ArchiveType::TarBz2 => "tar.bz2",
+            ArchiveType::TarGz => "tar.gz",
+            ArchiveType::Tbz => "tbz",
+            ArchiveType::Tgz => "tgz",
+            ArchiveType::Zip => "zip",
+            ArchiveType::Noop => "",
+            ArchiveType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ArchiveType] {
+        &[
+            ArchiveType::Tar,
+            ArchiveType::TarBz2,
+            ArchiveType::TarGz,
+            ArchiveType::Tbz,
+            ArchiveType::Tgz,
+            ArchiveType::Zip,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ArchiveType::Noop)
     }
@@ -12087,7 +15046,7 @@ pub struct CampaignContentData {
 /**
  * The source of the feedback.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Source {
     #[serde(rename = "android")]
     Android,
@@ -12128,7 +15087,34 @@ impl Default for Source {
         Source::Noop
     }
 }
+
 impl Source {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Source::Android => "android",
+            Source::Api => "api",
+            Source::Email => "email",
+            Source::Ios => "ios",
+            Source::Sms => "sms",
+            Source::Web => "web",
+            Source::Noop => "",
+            Source::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Source] {
+        &[
+            Source::Android,
+            Source::Api,
+            Source::Email,
+            Source::Ios,
+            Source::Sms,
+            Source::Web,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Source::Noop)
     }
@@ -12441,7 +15427,7 @@ pub struct CampaignFeedbackDataType {
 /**
  * The item type.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SendChecklistItemsType {
     #[serde(rename = "error")]
     Error,
@@ -12473,7 +15459,28 @@ impl Default for SendChecklistItemsType {
         SendChecklistItemsType::Noop
     }
 }
+
 impl SendChecklistItemsType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SendChecklistItemsType::Error => "error",
+            SendChecklistItemsType::Success => "success",
+            SendChecklistItemsType::Warning => "warning",
+            SendChecklistItemsType::Noop => "",
+            SendChecklistItemsType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SendChecklistItemsType] {
+        &[
+            SendChecklistItemsType::Error,
+            SendChecklistItemsType::Success,
+            SendChecklistItemsType::Warning,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SendChecklistItemsType::Noop)
     }
@@ -12703,7 +15710,7 @@ pub struct ConnectedSite {
 /**
  * Whether a conversation message has been marked as read.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum IsRead {
     #[serde(rename = "false")]
     False,
@@ -12732,7 +15739,23 @@ impl Default for IsRead {
         IsRead::Noop
     }
 }
+
 impl IsRead {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IsRead::False => "false",
+            IsRead::True => "true",
+            IsRead::Noop => "",
+            IsRead::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [IsRead] {
+        &[IsRead::False, IsRead::True]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, IsRead::Noop)
     }
@@ -13061,7 +16084,7 @@ pub struct CollectionOfConversationMessages {
 /**
  * Returns files sorted by the specified field.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GetFileManagerFilesSortField {
     #[serde(rename = "added_date")]
     AddedDate,
@@ -13087,7 +16110,22 @@ impl Default for GetFileManagerFilesSortField {
         GetFileManagerFilesSortField::Noop
     }
 }
+
 impl GetFileManagerFilesSortField {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GetFileManagerFilesSortField::AddedDate => "added_date",
+            GetFileManagerFilesSortField::Noop => "",
+            GetFileManagerFilesSortField::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GetFileManagerFilesSortField] {
+        &[GetFileManagerFilesSortField::AddedDate]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, GetFileManagerFilesSortField::Noop)
     }
@@ -13096,7 +16134,7 @@ impl GetFileManagerFilesSortField {
 /**
  * The type of file in the File Manager.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum FileType {
     #[serde(rename = "file")]
     File,
@@ -13125,7 +16163,23 @@ impl Default for FileType {
         FileType::Noop
     }
 }
+
 impl FileType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FileType::File => "file",
+            FileType::Image => "image",
+            FileType::Noop => "",
+            FileType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [FileType] {
+        &[FileType::File, FileType::Image]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, FileType::Noop)
     }
@@ -13434,7 +16488,7 @@ pub struct FileManagerFolders {
 /**
  * Returns files sorted by the specified field.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GetListsSortField {
     #[serde(rename = "date_created")]
     DateCreated,
@@ -13460,7 +16514,22 @@ impl Default for GetListsSortField {
         GetListsSortField::Noop
     }
 }
+
 impl GetListsSortField {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GetListsSortField::DateCreated => "date_created",
+            GetListsSortField::Noop => "",
+            GetListsSortField::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GetListsSortField] {
+        &[GetListsSortField::DateCreated]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, GetListsSortField::Noop)
     }
@@ -13468,6 +16537,7 @@ impl GetListsSortField {
 
 /// [Contact information displayed in campaign footers](https://mailchimp.com/help/about-campaign-footers/) to comply with international spam laws.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ListContact {
     /**
      * [Contact information displayed in campaign footers](https://mailchimp.com/help/about-campaign-footers/) to comply with international spam laws.
@@ -13475,8 +16545,7 @@ pub struct ListContact {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "address1"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub address_1: String,
     /**
@@ -13485,8 +16554,7 @@ pub struct ListContact {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "address2"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub address_2: String,
     /**
@@ -13589,7 +16657,7 @@ pub struct CampaignDefaults {
 /**
  * Legacy - visibility settings are no longer used
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Visibility {
     #[serde(rename = "prv")]
     Prv,
@@ -13618,7 +16686,23 @@ impl Default for Visibility {
         Visibility::Noop
     }
 }
+
 impl Visibility {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Visibility::Prv => "prv",
+            Visibility::Pub => "pub",
+            Visibility::Noop => "",
+            Visibility::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Visibility] {
+        &[Visibility::Prv, Visibility::Pub]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Visibility::Noop)
     }
@@ -14036,6 +17120,7 @@ pub struct SubscriberLists {
 
 /// [Contact information displayed in campaign footers](https://mailchimp.com/help/about-campaign-footers/) to comply with international spam laws.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct SubscriberListContact {
     /**
      * The name of the folder.
@@ -14043,8 +17128,7 @@ pub struct SubscriberListContact {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "address1"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub address_1: String,
     /**
@@ -14053,8 +17137,7 @@ pub struct SubscriberListContact {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "address2"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub address_2: String,
     /**
@@ -14240,7 +17323,7 @@ pub struct SubscriberList {
 /**
  * Subscriber's current status.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum MembersSubscribeUnsubscribeFromAListInBatchStatus {
     #[serde(rename = "cleaned")]
     Cleaned,
@@ -14275,7 +17358,30 @@ impl Default for MembersSubscribeUnsubscribeFromAListInBatchStatus {
         MembersSubscribeUnsubscribeFromAListInBatchStatus::Noop
     }
 }
+
 impl MembersSubscribeUnsubscribeFromAListInBatchStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MembersSubscribeUnsubscribeFromAListInBatchStatus::Cleaned => "cleaned",
+            MembersSubscribeUnsubscribeFromAListInBatchStatus::Pending => "pending",
+            MembersSubscribeUnsubscribeFromAListInBatchStatus::Subscribed => "subscribed",
+            MembersSubscribeUnsubscribeFromAListInBatchStatus::Unsubscribed => "unsubscribed",
+            MembersSubscribeUnsubscribeFromAListInBatchStatus::Noop => "",
+            MembersSubscribeUnsubscribeFromAListInBatchStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [MembersSubscribeUnsubscribeFromAListInBatchStatus] {
+        &[
+            MembersSubscribeUnsubscribeFromAListInBatchStatus::Cleaned,
+            MembersSubscribeUnsubscribeFromAListInBatchStatus::Pending,
+            MembersSubscribeUnsubscribeFromAListInBatchStatus::Subscribed,
+            MembersSubscribeUnsubscribeFromAListInBatchStatus::Unsubscribed,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(
             self,
@@ -14431,7 +17537,7 @@ pub struct MembersSubscribeUnsubscribeFromAListInBatch {
 /**
  * Subscriber's status. This value is required only if the email address is not already present on the list.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum StatusIfNew {
     #[serde(rename = "cleaned")]
     Cleaned,
@@ -14469,7 +17575,32 @@ impl Default for StatusIfNew {
         StatusIfNew::Noop
     }
 }
+
 impl StatusIfNew {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StatusIfNew::Cleaned => "cleaned",
+            StatusIfNew::Pending => "pending",
+            StatusIfNew::Subscribed => "subscribed",
+            StatusIfNew::Transactional => "transactional",
+            StatusIfNew::Unsubscribed => "unsubscribed",
+            StatusIfNew::Noop => "",
+            StatusIfNew::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [StatusIfNew] {
+        &[
+            StatusIfNew::Cleaned,
+            StatusIfNew::Pending,
+            StatusIfNew::Subscribed,
+            StatusIfNew::Transactional,
+            StatusIfNew::Unsubscribed,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, StatusIfNew::Noop)
     }
@@ -14814,7 +17945,7 @@ pub struct NewMembers {
 /**
  * A unique code that identifies this specifc error.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ErrorCode {
     #[serde(rename = "ERROR_CONTACT_EXISTS")]
     ErrorContactExists,
@@ -14843,7 +17974,23 @@ impl Default for ErrorCode {
         ErrorCode::Noop
     }
 }
+
 impl ErrorCode {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorCode::ErrorContactExists => "ERROR_CONTACT_EXISTS",
+            ErrorCode::ErrorGeneric => "ERROR_GENERIC",
+            ErrorCode::Noop => "",
+            ErrorCode::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ErrorCode] {
+        &[ErrorCode::ErrorContactExists, ErrorCode::ErrorGeneric]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ErrorCode::Noop)
     }
@@ -14947,6 +18094,7 @@ pub struct BatchUpdateListMembers {
 
 /// [Contact information displayed in campaign footers](https://mailchimp.com/help/about-campaign-footers/) to comply with international spam laws.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct SubscriberListContactData {
     /**
      * The name of the folder.
@@ -14954,8 +18102,7 @@ pub struct SubscriberListContactData {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "address1"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub address_1: String,
     /**
@@ -14964,8 +18111,7 @@ pub struct SubscriberListContactData {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "address2"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub address_2: String,
     /**
@@ -15446,7 +18592,7 @@ pub struct EmailClients {
 /**
  * Returns files sorted by the specified field.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GetListsGrowthHistorySortField {
     #[serde(rename = "month")]
     Month,
@@ -15472,7 +18618,22 @@ impl Default for GetListsGrowthHistorySortField {
         GetListsGrowthHistorySortField::Noop
     }
 }
+
 impl GetListsGrowthHistorySortField {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GetListsGrowthHistorySortField::Month => "month",
+            GetListsGrowthHistorySortField::Noop => "",
+            GetListsGrowthHistorySortField::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GetListsGrowthHistorySortField] {
+        &[GetListsGrowthHistorySortField::Month]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, GetListsGrowthHistorySortField::Noop)
     }
@@ -15864,7 +19025,7 @@ pub struct InterestsData {
 /**
  * The type of segment. Static segments are now known as tags. Learn more about [tags](https://mailchimp.com/help/getting-started-tags?utm_source=mc-api&utm_medium=docs&utm_campaign=apidocs).
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum CollectionOfSegmentsType {
     #[serde(rename = "fuzzy")]
     Fuzzy,
@@ -15896,7 +19057,28 @@ impl Default for CollectionOfSegmentsType {
         CollectionOfSegmentsType::Noop
     }
 }
+
 impl CollectionOfSegmentsType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CollectionOfSegmentsType::Fuzzy => "fuzzy",
+            CollectionOfSegmentsType::Saved => "saved",
+            CollectionOfSegmentsType::Static => "static",
+            CollectionOfSegmentsType::Noop => "",
+            CollectionOfSegmentsType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [CollectionOfSegmentsType] {
+        &[
+            CollectionOfSegmentsType::Fuzzy,
+            CollectionOfSegmentsType::Saved,
+            CollectionOfSegmentsType::Static,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, CollectionOfSegmentsType::Noop)
     }
@@ -16482,7 +19664,7 @@ pub struct TagSearchResults {
 /**
  * The subscriber's status.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GetListsMembersStatus {
     #[serde(rename = "archived")]
     Archived,
@@ -16523,7 +19705,34 @@ impl Default for GetListsMembersStatus {
         GetListsMembersStatus::Noop
     }
 }
+
 impl GetListsMembersStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GetListsMembersStatus::Archived => "archived",
+            GetListsMembersStatus::Cleaned => "cleaned",
+            GetListsMembersStatus::Pending => "pending",
+            GetListsMembersStatus::Subscribed => "subscribed",
+            GetListsMembersStatus::Transactional => "transactional",
+            GetListsMembersStatus::Unsubscribed => "unsubscribed",
+            GetListsMembersStatus::Noop => "",
+            GetListsMembersStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GetListsMembersStatus] {
+        &[
+            GetListsMembersStatus::Archived,
+            GetListsMembersStatus::Cleaned,
+            GetListsMembersStatus::Pending,
+            GetListsMembersStatus::Subscribed,
+            GetListsMembersStatus::Transactional,
+            GetListsMembersStatus::Unsubscribed,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, GetListsMembersStatus::Noop)
     }
@@ -16532,7 +19741,7 @@ impl GetListsMembersStatus {
 /**
  * Used to filter list members by interests. Must be accompanied by interest_category_id and interest_ids. "any" will match a member with any of the interest supplied, "all" will only match members with every interest supplied, and "none" will match members without any of the interest supplied.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum InterestMatch {
     #[serde(rename = "all")]
     All,
@@ -16564,7 +19773,24 @@ impl Default for InterestMatch {
         InterestMatch::Noop
     }
 }
+
 impl InterestMatch {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InterestMatch::All => "all",
+            InterestMatch::Any => "any",
+            InterestMatch::None => "none",
+            InterestMatch::Noop => "",
+            InterestMatch::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [InterestMatch] {
+        &[InterestMatch::All, InterestMatch::Any, InterestMatch::None]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, InterestMatch::Noop)
     }
@@ -16573,7 +19799,7 @@ impl InterestMatch {
 /**
  * Returns files sorted by the specified field.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GetListsMembersSortField {
     #[serde(rename = "last_changed")]
     LastChanged,
@@ -16605,7 +19831,28 @@ impl Default for GetListsMembersSortField {
         GetListsMembersSortField::Noop
     }
 }
+
 impl GetListsMembersSortField {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GetListsMembersSortField::LastChanged => "last_changed",
+            GetListsMembersSortField::TimestampOpt => "timestamp_opt",
+            GetListsMembersSortField::TimestampSignup => "timestamp_signup",
+            GetListsMembersSortField::Noop => "",
+            GetListsMembersSortField::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GetListsMembersSortField] {
+        &[
+            GetListsMembersSortField::LastChanged,
+            GetListsMembersSortField::TimestampOpt,
+            GetListsMembersSortField::TimestampSignup,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, GetListsMembersSortField::Noop)
     }
@@ -17463,7 +20710,7 @@ pub struct MemberActivityEvents {
 /**
  * The type of event activity.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ActivityType {
     #[serde(rename = "open")]
     Open,
@@ -17489,7 +20736,22 @@ impl Default for ActivityType {
         ActivityType::Noop
     }
 }
+
 impl ActivityType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ActivityType::Open => "open",
+            ActivityType::Noop => "",
+            ActivityType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ActivityType] {
+        &[ActivityType::Open]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ActivityType::Noop)
     }
@@ -17535,7 +20797,7 @@ pub struct EmailOpens {
 /**
  * The type of event activity.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum EmailClicksActivityType {
     #[serde(rename = "click")]
     Click,
@@ -17561,7 +20823,22 @@ impl Default for EmailClicksActivityType {
         EmailClicksActivityType::Noop
     }
 }
+
 impl EmailClicksActivityType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EmailClicksActivityType::Click => "click",
+            EmailClicksActivityType::Noop => "",
+            EmailClicksActivityType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [EmailClicksActivityType] {
+        &[EmailClicksActivityType::Click]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, EmailClicksActivityType::Noop)
     }
@@ -17616,7 +20893,7 @@ pub struct EmailClicks {
 /**
  * The type of event activity.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum EmailBouncedActivityType {
     #[serde(rename = "bounce")]
     Bounce,
@@ -17642,7 +20919,22 @@ impl Default for EmailBouncedActivityType {
         EmailBouncedActivityType::Noop
     }
 }
+
 impl EmailBouncedActivityType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EmailBouncedActivityType::Bounce => "bounce",
+            EmailBouncedActivityType::Noop => "",
+            EmailBouncedActivityType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [EmailBouncedActivityType] {
+        &[EmailBouncedActivityType::Bounce]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, EmailBouncedActivityType::Noop)
     }
@@ -17651,7 +20943,7 @@ impl EmailBouncedActivityType {
 /**
  * The type of bounce.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum BounceType {
     #[serde(rename = "hard")]
     Hard,
@@ -17680,7 +20972,23 @@ impl Default for BounceType {
         BounceType::Noop
     }
 }
+
 impl BounceType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BounceType::Hard => "hard",
+            BounceType::Soft => "soft",
+            BounceType::Noop => "",
+            BounceType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [BounceType] {
+        &[BounceType::Hard, BounceType::Soft]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, BounceType::Noop)
     }
@@ -17739,7 +21047,7 @@ pub struct EmailBounced {
 /**
  * The type of event activity.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ListUnsubscribedActivityType {
     #[serde(rename = "unsub")]
     Unsub,
@@ -17758,14 +21066,29 @@ impl std::fmt::Display for ListUnsubscribedActivityType {
         }
         .fmt(f)
     }
-}
+}
+
+impl Default for ListUnsubscribedActivityType {
+    fn default() -> ListUnsubscribedActivityType {
+        ListUnsubscribedActivityType::Noop
+    }
+}
+
+impl ListUnsubscribedActivityType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ListUnsubscribedActivityType::Unsub => "unsub",
+            ListUnsubscribedActivityType::Noop => "",
+            ListUnsubscribedActivityType::FallthroughString => "*",
+        }
+    }
 
-impl Default for ListUnsubscribedActivityType {
-    fn default() -> ListUnsubscribedActivityType {
-        ListUnsubscribedActivityType::Noop
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ListUnsubscribedActivityType] {
+        &[ListUnsubscribedActivityType::Unsub]
     }
-}
-impl ListUnsubscribedActivityType {
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ListUnsubscribedActivityType::Noop)
     }
@@ -17828,7 +21151,7 @@ pub struct ListUnsubscribed {
 /**
  * The type of event activity.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum EmailSentActivityType {
     #[serde(rename = "sent")]
     Sent,
@@ -17854,7 +21177,22 @@ impl Default for EmailSentActivityType {
         EmailSentActivityType::Noop
     }
 }
+
 impl EmailSentActivityType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EmailSentActivityType::Sent => "sent",
+            EmailSentActivityType::Noop => "",
+            EmailSentActivityType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [EmailSentActivityType] {
+        &[EmailSentActivityType::Sent]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, EmailSentActivityType::Noop)
     }
@@ -17998,7 +21336,7 @@ pub struct EmailConversation {
 /**
  * The type of event activity.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum NoteActivityType {
     #[serde(rename = "note")]
     Note,
@@ -18024,7 +21362,22 @@ impl Default for NoteActivityType {
         NoteActivityType::Noop
     }
 }
+
 impl NoteActivityType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NoteActivityType::Note => "note",
+            NoteActivityType::Noop => "",
+            NoteActivityType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [NoteActivityType] {
+        &[NoteActivityType::Note]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, NoteActivityType::Noop)
     }
@@ -18097,7 +21450,7 @@ pub struct Note {
 /**
  * The type of event activity.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum MarketingPermissionActivityType {
     #[serde(rename = "marketing_permission")]
     MarketingPermission,
@@ -18123,7 +21476,22 @@ impl Default for MarketingPermissionActivityType {
         MarketingPermissionActivityType::Noop
     }
 }
+
 impl MarketingPermissionActivityType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MarketingPermissionActivityType::MarketingPermission => "marketing_permission",
+            MarketingPermissionActivityType::Noop => "",
+            MarketingPermissionActivityType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [MarketingPermissionActivityType] {
+        &[MarketingPermissionActivityType::MarketingPermission]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, MarketingPermissionActivityType::Noop)
     }
@@ -18177,7 +21545,7 @@ pub struct MarketingPermissionData {
 /**
  * The type of event activity.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PostcardSentActivityType {
     #[serde(rename = "postcard_sent")]
     PostcardSent,
@@ -18203,7 +21571,22 @@ impl Default for PostcardSentActivityType {
         PostcardSentActivityType::Noop
     }
 }
+
 impl PostcardSentActivityType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PostcardSentActivityType::PostcardSent => "postcard_sent",
+            PostcardSentActivityType::Noop => "",
+            PostcardSentActivityType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PostcardSentActivityType] {
+        &[PostcardSentActivityType::PostcardSent]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PostcardSentActivityType::Noop)
     }
@@ -18249,7 +21632,7 @@ pub struct PostcardSent {
 /**
  * The type of event activity.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SquatterSignupActivityType {
     #[serde(rename = "squatter_signup")]
     SquatterSignup,
@@ -18275,7 +21658,22 @@ impl Default for SquatterSignupActivityType {
         SquatterSignupActivityType::Noop
     }
 }
+
 impl SquatterSignupActivityType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SquatterSignupActivityType::SquatterSignup => "squatter_signup",
+            SquatterSignupActivityType::Noop => "",
+            SquatterSignupActivityType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SquatterSignupActivityType] {
+        &[SquatterSignupActivityType::SquatterSignup]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SquatterSignupActivityType::Noop)
     }
@@ -18321,7 +21719,7 @@ pub struct SquatterSignup {
 /**
  * The type of event activity.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum WebsiteSignupActivityType {
     #[serde(rename = "website_signup")]
     WebsiteSignup,
@@ -18347,7 +21745,22 @@ impl Default for WebsiteSignupActivityType {
         WebsiteSignupActivityType::Noop
     }
 }
+
 impl WebsiteSignupActivityType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebsiteSignupActivityType::WebsiteSignup => "website_signup",
+            WebsiteSignupActivityType::Noop => "",
+            WebsiteSignupActivityType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [WebsiteSignupActivityType] {
+        &[WebsiteSignupActivityType::WebsiteSignup]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, WebsiteSignupActivityType::Noop)
     }
@@ -18393,7 +21806,7 @@ pub struct WebsiteSignup {
 /**
  * The type of event activity.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum LandingPageSignupActivityType {
     #[serde(rename = "landing_page_signup")]
     LandingPageSignup,
@@ -18419,7 +21832,22 @@ impl Default for LandingPageSignupActivityType {
         LandingPageSignupActivityType::Noop
     }
 }
+
 impl LandingPageSignupActivityType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LandingPageSignupActivityType::LandingPageSignup => "landing_page_signup",
+            LandingPageSignupActivityType::Noop => "",
+            LandingPageSignupActivityType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [LandingPageSignupActivityType] {
+        &[LandingPageSignupActivityType::LandingPageSignup]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, LandingPageSignupActivityType::Noop)
     }
@@ -18465,7 +21893,7 @@ pub struct LandingPageSignup {
 /**
  * The type of event activity.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum EcommerceSignupActivityType {
     #[serde(rename = "ecommerce_signup")]
     EcommerceSignup,
@@ -18491,7 +21919,22 @@ impl Default for EcommerceSignupActivityType {
         EcommerceSignupActivityType::Noop
     }
 }
+
 impl EcommerceSignupActivityType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EcommerceSignupActivityType::EcommerceSignup => "ecommerce_signup",
+            EcommerceSignupActivityType::Noop => "",
+            EcommerceSignupActivityType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [EcommerceSignupActivityType] {
+        &[EcommerceSignupActivityType::EcommerceSignup]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, EcommerceSignupActivityType::Noop)
     }
@@ -18528,7 +21971,7 @@ pub struct EcommerceSignup {
 /**
  * The type of event activity.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GenericSignupActivityType {
     #[serde(rename = "generic_signup")]
     GenericSignup,
@@ -18554,7 +21997,22 @@ impl Default for GenericSignupActivityType {
         GenericSignupActivityType::Noop
     }
 }
+
 impl GenericSignupActivityType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GenericSignupActivityType::GenericSignup => "generic_signup",
+            GenericSignupActivityType::Noop => "",
+            GenericSignupActivityType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GenericSignupActivityType] {
+        &[GenericSignupActivityType::GenericSignup]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, GenericSignupActivityType::Noop)
     }
@@ -18591,7 +22049,7 @@ pub struct GenericSignup {
 /**
  * The type of event activity.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum EcommerceOrderActivityType {
     #[serde(rename = "order")]
     Order,
@@ -18617,7 +22075,22 @@ impl Default for EcommerceOrderActivityType {
         EcommerceOrderActivityType::Noop
     }
 }
+
 impl EcommerceOrderActivityType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EcommerceOrderActivityType::Order => "order",
+            EcommerceOrderActivityType::Noop => "",
+            EcommerceOrderActivityType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [EcommerceOrderActivityType] {
+        &[EcommerceOrderActivityType::Order]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, EcommerceOrderActivityType::Noop)
     }
@@ -18813,7 +22286,7 @@ pub struct EcommerceOrder {
 /**
  * The type of event activity.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ContactActivityEventType {
     #[serde(rename = "event")]
     Event,
@@ -18839,7 +22312,22 @@ impl Default for ContactActivityEventType {
         ContactActivityEventType::Noop
     }
 }
+
 impl ContactActivityEventType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ContactActivityEventType::Event => "event",
+            ContactActivityEventType::Noop => "",
+            ContactActivityEventType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ContactActivityEventType] {
+        &[ContactActivityEventType::Event]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ContactActivityEventType::Noop)
     }
@@ -18885,7 +22373,7 @@ pub struct ContactActivityEvent {
 /**
  * The type of event activity.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SurveyResponseActivityType {
     #[serde(rename = "survey_response")]
     SurveyResponse,
@@ -18911,7 +22399,22 @@ impl Default for SurveyResponseActivityType {
         SurveyResponseActivityType::Noop
     }
 }
+
 impl SurveyResponseActivityType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SurveyResponseActivityType::SurveyResponse => "survey_response",
+            SurveyResponseActivityType::Noop => "",
+            SurveyResponseActivityType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SurveyResponseActivityType] {
+        &[SurveyResponseActivityType::SurveyResponse]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SurveyResponseActivityType::Noop)
     }
@@ -18975,79 +22478,214 @@ pub struct SurveyResponse {
 /// - `SurveyResponse`
 ///
 /// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-#[serde(untagged)]
+#[derive(PartialEq, Debug, Clone, JsonSchema)]
+#[serde(tag = "activity_type")]
 pub enum ActivityOneOf {
     /**
      * Activity feed item representing opening an email.
      */
+    #[serde(rename = "open")]
     EmailOpens(EmailOpens),
     /**
      * Activity feed item representing having a link clicked by a contact.
      */
+    #[serde(rename = "click")]
     EmailClicks(EmailClicks),
     /**
      * Activity feed item representing an email to this contact bouncing.
      */
+    #[serde(rename = "bounce")]
     EmailBounced(EmailBounced),
     /**
      * Activity feed item representing this contact unsubscribing from a list.
      */
+    #[serde(rename = "unsub")]
     ListUnsubscribed(ListUnsubscribed),
     /**
      * Activity feed item representing having an email sent to the contact.
      */
+    #[serde(rename = "sent")]
     EmailSent(EmailSent),
     /**
      * Activity feed item representing an individual reply in a conversation.
      */
+    #[serde(rename = "conversation")]
     EmailConversation(EmailConversation),
     /**
      * Activity feed item representing a note on the contact record.
      */
+    #[serde(rename = "note")]
     Note(Note),
     /**
      * Activity feed item indicating if a marketing permission was added or updated.
      */
+    #[serde(rename = "marketing_permission")]
     MarketingPermissionData(MarketingPermissionData),
     /**
      * Activity feed item representing a time when a contact was sent a particular postcard.
      */
+    #[serde(rename = "postcard_sent")]
     PostcardSent(PostcardSent),
     /**
      * Activity feed item to representing a contact signing up for the audience from a squatter page.
      */
+    #[serde(rename = "squatter_signup")]
     SquatterSignup(SquatterSignup),
     /**
      * Activity feed item to representing a contact signing up for the contact through a website page.
      */
+    #[serde(rename = "website_signup")]
     WebsiteSignup(WebsiteSignup),
     /**
      * Activity feed item to representing a contact signing up for the list via a landing page.
      */
+    #[serde(rename = "landing_page_signup")]
     LandingPageSignup(LandingPageSignup),
     /**
      * Activity feed item to representing a contact signing up for the list via a ecommerce store.
      */
+    #[serde(rename = "ecommerce_signup")]
     EcommerceSignup(EcommerceSignup),
     /**
      * Activity feed item that represents a contact signing up for the audience via a generic some generic method (specifically, one we can't link to).
      */
+    #[serde(rename = "generic_signup")]
     GenericSignup(GenericSignup),
     /**
      * Activity feed item that represents an order.
      */
+    #[serde(rename = "order")]
     EcommerceOrder(EcommerceOrder),
     /**
      * Activity feed item that represents a generic event.
      */
+    #[serde(rename = "event")]
     ContactActivityEvent(ContactActivityEvent),
     /**
      * Represents when a contact completes and submits a survey
      */
+    #[serde(rename = "survey_response")]
     SurveyResponse(SurveyResponse),
 }
 
+impl Serialize for ActivityOneOf {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            ActivityOneOf::EmailOpens(v) => {
+                crate::utils::serialize_tagged(v, "activity_type", "open", serializer)
+            }
+            ActivityOneOf::EmailClicks(v) => {
+                crate::utils::serialize_tagged(v, "activity_type", "click", serializer)
+            }
+            ActivityOneOf::EmailBounced(v) => {
+                crate::utils::serialize_tagged(v, "activity_type", "bounce", serializer)
+            }
+            ActivityOneOf::ListUnsubscribed(v) => {
+                crate::utils::serialize_tagged(v, "activity_type", "unsub", serializer)
+            }
+            ActivityOneOf::EmailSent(v) => {
+                crate::utils::serialize_tagged(v, "activity_type", "sent", serializer)
+            }
+            ActivityOneOf::EmailConversation(v) => {
+                crate::utils::serialize_tagged(v, "activity_type", "conversation", serializer)
+            }
+            ActivityOneOf::Note(v) => {
+                crate::utils::serialize_tagged(v, "activity_type", "note", serializer)
+            }
+            ActivityOneOf::MarketingPermissionData(v) => crate::utils::serialize_tagged(
+                v,
+                "activity_type",
+                "marketing_permission",
+                serializer,
+            ),
+            ActivityOneOf::PostcardSent(v) => {
+                crate::utils::serialize_tagged(v, "activity_type", "postcard_sent", serializer)
+            }
+            ActivityOneOf::SquatterSignup(v) => {
+                crate::utils::serialize_tagged(v, "activity_type", "squatter_signup", serializer)
+            }
+            ActivityOneOf::WebsiteSignup(v) => {
+                crate::utils::serialize_tagged(v, "activity_type", "website_signup", serializer)
+            }
+            ActivityOneOf::LandingPageSignup(v) => crate::utils::serialize_tagged(
+                v,
+                "activity_type",
+                "landing_page_signup",
+                serializer,
+            ),
+            ActivityOneOf::EcommerceSignup(v) => {
+                crate::utils::serialize_tagged(v, "activity_type", "ecommerce_signup", serializer)
+            }
+            ActivityOneOf::GenericSignup(v) => {
+                crate::utils::serialize_tagged(v, "activity_type", "generic_signup", serializer)
+            }
+            ActivityOneOf::EcommerceOrder(v) => {
+                crate::utils::serialize_tagged(v, "activity_type", "order", serializer)
+            }
+            ActivityOneOf::ContactActivityEvent(v) => {
+                crate::utils::serialize_tagged(v, "activity_type", "event", serializer)
+            }
+            ActivityOneOf::SurveyResponse(v) => {
+                crate::utils::serialize_tagged(v, "activity_type", "survey_response", serializer)
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ActivityOneOf {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (tag, value) = crate::utils::deserialize_tagged(deserializer, "activity_type")?;
+        match tag.as_str() {
+            "open" => serde_json::from_value(value).map(ActivityOneOf::EmailOpens),
+            "click" => serde_json::from_value(value).map(ActivityOneOf::EmailClicks),
+            "bounce" => serde_json::from_value(value).map(ActivityOneOf::EmailBounced),
+            "unsub" => serde_json::from_value(value).map(ActivityOneOf::ListUnsubscribed),
+            "sent" => serde_json::from_value(value).map(ActivityOneOf::EmailSent),
+            "conversation" => serde_json::from_value(value).map(ActivityOneOf::EmailConversation),
+            "note" => serde_json::from_value(value).map(ActivityOneOf::Note),
+            "marketing_permission" => {
+                serde_json::from_value(value).map(ActivityOneOf::MarketingPermissionData)
+            }
+            "postcard_sent" => serde_json::from_value(value).map(ActivityOneOf::PostcardSent),
+            "squatter_signup" => serde_json::from_value(value).map(ActivityOneOf::SquatterSignup),
+            "website_signup" => serde_json::from_value(value).map(ActivityOneOf::WebsiteSignup),
+            "landing_page_signup" => {
+                serde_json::from_value(value).map(ActivityOneOf::LandingPageSignup)
+            }
+            "ecommerce_signup" => serde_json::from_value(value).map(ActivityOneOf::EcommerceSignup),
+            "generic_signup" => serde_json::from_value(value).map(ActivityOneOf::GenericSignup),
+            "order" => serde_json::from_value(value).map(ActivityOneOf::EcommerceOrder),
+            "event" => serde_json::from_value(value).map(ActivityOneOf::ContactActivityEvent),
+            "survey_response" => serde_json::from_value(value).map(ActivityOneOf::SurveyResponse),
+            _ => {
+                return Err(serde::de::Error::unknown_variant(
+                    &tag,
+                    &[
+                        "open",
+                        "click",
+                        "bounce",
+                        "unsub",
+                        "sent",
+                        "conversation",
+                        "note",
+                        "marketing_permission",
+                        "postcard_sent",
+                        "squatter_signup",
+                        "website_signup",
+                        "landing_page_signup",
+                        "ecommerce_signup",
+                        "generic_signup",
+                        "order",
+                        "event",
+                        "survey_response",
+                    ],
+                ))
+            }
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+
 impl ActivityOneOf {
     pub fn contact_activity_event(&self) -> Option<&ContactActivityEvent> {
         if let ActivityOneOf::ContactActivityEvent(ref_) = self {
@@ -19247,7 +22885,7 @@ pub struct CollectionOfTags {
 /**
  * The status for the tag on the member, pass in active to add a tag or inactive to remove it.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum MemberTagsTagStatus {
     #[serde(rename = "active")]
     Active,
@@ -19276,7 +22914,23 @@ impl Default for MemberTagsTagStatus {
         MemberTagsTagStatus::Noop
     }
 }
+
 impl MemberTagsTagStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MemberTagsTagStatus::Active => "active",
+            MemberTagsTagStatus::Inactive => "inactive",
+            MemberTagsTagStatus::Noop => "",
+            MemberTagsTagStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [MemberTagsTagStatus] {
+        &[MemberTagsTagStatus::Active, MemberTagsTagStatus::Inactive]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, MemberTagsTagStatus::Noop)
     }
@@ -19523,7 +23177,7 @@ pub struct CollectionOfMemberActivityEvents {
 /**
  * Returns notes sorted by the specified field.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GetListsMembersNotesSortField {
     #[serde(rename = "created_at")]
     CreatedAt,
@@ -19555,7 +23209,28 @@ impl Default for GetListsMembersNotesSortField {
         GetListsMembersNotesSortField::Noop
     }
 }
+
 impl GetListsMembersNotesSortField {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GetListsMembersNotesSortField::CreatedAt => "created_at",
+            GetListsMembersNotesSortField::NoteId => "note_id",
+            GetListsMembersNotesSortField::UpdatedAt => "updated_at",
+            GetListsMembersNotesSortField::Noop => "",
+            GetListsMembersNotesSortField::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GetListsMembersNotesSortField] {
+        &[
+            GetListsMembersNotesSortField::CreatedAt,
+            GetListsMembersNotesSortField::NoteId,
+            GetListsMembersNotesSortField::UpdatedAt,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, GetListsMembersNotesSortField::Noop)
     }
@@ -19693,7 +23368,7 @@ pub struct CollectionOfNotes {
 /**
  * The [type](https://mailchimp.com/help/manage-audience-signup-form-fields/#Audience_field_types) for the merge field.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum MergeFieldType {
     #[serde(rename = "address")]
     Address,
@@ -19749,7 +23424,44 @@ impl Default for MergeFieldType {
         MergeFieldType::Noop
     }
 }
+
 impl MergeFieldType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MergeFieldType::Address => "address",
+            MergeFieldType::Birthday => "birthday",
+            MergeFieldType::Date => "date",
+            MergeFieldType::Dropdown => "dropdown",
+            MergeFieldType::Imageurl => "imageurl",
+            MergeFieldType::Number => "number",
+            MergeFieldType::Phone => "phone",
+            MergeFieldType::Radio => "radio",
+            MergeFieldType::Text => "text",
+            MergeFieldType::Url => "url",
+            MergeFieldType::Zip => "zip",
+            MergeFieldType::Noop => "",
+            MergeFieldType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [MergeFieldType] {
+        &[
+            MergeFieldType::Address,
+            MergeFieldType::Birthday,
+            MergeFieldType::Date,
+            MergeFieldType::Dropdown,
+            MergeFieldType::Imageurl,
+            MergeFieldType::Number,
+            MergeFieldType::Phone,
+            MergeFieldType::Radio,
+            MergeFieldType::Text,
+            MergeFieldType::Url,
+            MergeFieldType::Zip,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, MergeFieldType::Noop)
     }
@@ -20322,7 +24034,7 @@ pub struct ListWebhooksData {
 /**
  * Image alignment.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ImageAlign {
     #[serde(rename = "center")]
     Center,
@@ -20357,7 +24069,30 @@ impl Default for ImageAlign {
         ImageAlign::Noop
     }
 }
+
 impl ImageAlign {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImageAlign::Center => "center",
+            ImageAlign::Left => "left",
+            ImageAlign::None => "none",
+            ImageAlign::Right => "right",
+            ImageAlign::Noop => "",
+            ImageAlign::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ImageAlign] {
+        &[
+            ImageAlign::Center,
+            ImageAlign::Left,
+            ImageAlign::None,
+            ImageAlign::Right,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ImageAlign::Noop)
     }
@@ -20366,7 +24101,7 @@ impl ImageAlign {
 /**
  * Image border style.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ImageBorderStyle {
     #[serde(rename = "dashed")]
     Dashed,
@@ -20416,7 +24151,40 @@ impl Default for ImageBorderStyle {
         ImageBorderStyle::Noop
     }
 }
+
 impl ImageBorderStyle {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImageBorderStyle::Dashed => "dashed",
+            ImageBorderStyle::Dotted => "dotted",
+            ImageBorderStyle::Double => "double",
+            ImageBorderStyle::Groove => "groove",
+            ImageBorderStyle::Inset => "inset",
+            ImageBorderStyle::None => "none",
+            ImageBorderStyle::Outset => "outset",
+            ImageBorderStyle::Ridge => "ridge",
+            ImageBorderStyle::Solid => "solid",
+            ImageBorderStyle::Noop => "",
+            ImageBorderStyle::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ImageBorderStyle] {
+        &[
+            ImageBorderStyle::Dashed,
+            ImageBorderStyle::Dotted,
+            ImageBorderStyle::Double,
+            ImageBorderStyle::Groove,
+            ImageBorderStyle::Inset,
+            ImageBorderStyle::None,
+            ImageBorderStyle::Outset,
+            ImageBorderStyle::Ridge,
+            ImageBorderStyle::Solid,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ImageBorderStyle::Noop)
     }
@@ -20425,7 +24193,7 @@ impl ImageBorderStyle {
 /**
  * Image link target.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ImageTarget {
     #[serde(rename = "_blank")]
     Blank,
@@ -20454,7 +24222,23 @@ impl Default for ImageTarget {
         ImageTarget::Noop
     }
 }
+
 impl ImageTarget {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImageTarget::Blank => "_blank",
+            ImageTarget::Null => "null",
+            ImageTarget::Noop => "",
+            ImageTarget::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ImageTarget] {
+        &[ImageTarget::Blank, ImageTarget::Null]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ImageTarget::Noop)
     }
@@ -20555,7 +24339,7 @@ pub struct Header {
 /**
  * The content section name.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Section {
     #[serde(rename = "signup_message")]
     SignupMessage,
@@ -20587,7 +24371,28 @@ impl Default for Section {
         Section::Noop
     }
 }
+
 impl Section {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Section::SignupMessage => "signup_message",
+            Section::SignupThankYouTitle => "signup_thank_you_title",
+            Section::UnsubMessage => "unsub_message",
+            Section::Noop => "",
+            Section::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Section] {
+        &[
+            Section::SignupMessage,
+            Section::SignupThankYouTitle,
+            Section::UnsubMessage,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Section::Noop)
     }
@@ -20615,7 +24420,7 @@ pub struct Contents {
 /**
  * A string that identifies the element selector.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Selector {
     #[serde(rename = "body_background")]
     BodyBackground,
@@ -20680,7 +24485,50 @@ impl Default for Selector {
         Selector::Noop
     }
 }
+
 impl Selector {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Selector::BodyBackground => "body_background",
+            Selector::BodyLinkStyle => "body_link_style",
+            Selector::FormsButtons => "forms_buttons",
+            Selector::FormsButtonsHovered => "forms_buttons_hovered",
+            Selector::FormsErrors => "forms_errors",
+            Selector::FormsFieldLabel => "forms_field_label",
+            Selector::FormsFieldText => "forms_field_text",
+            Selector::FormsHelpText => "forms_help_text",
+            Selector::FormsRequired => "forms_required",
+            Selector::FormsRequiredLegend => "forms_required_legend",
+            Selector::MonkeyRewardsBadge => "monkey_rewards_badge",
+            Selector::PageBackground => "page_background",
+            Selector::PageHeader => "page_header",
+            Selector::PageOuterWrapper => "page_outer_wrapper",
+            Selector::Noop => "",
+            Selector::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Selector] {
+        &[
+            Selector::BodyBackground,
+            Selector::BodyLinkStyle,
+            Selector::FormsButtons,
+            Selector::FormsButtonsHovered,
+            Selector::FormsErrors,
+            Selector::FormsFieldLabel,
+            Selector::FormsFieldText,
+            Selector::FormsHelpText,
+            Selector::FormsRequired,
+            Selector::FormsRequiredLegend,
+            Selector::MonkeyRewardsBadge,
+            Selector::PageBackground,
+            Selector::PageHeader,
+            Selector::PageOuterWrapper,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Selector::Noop)
     }
@@ -20927,7 +24775,7 @@ pub struct ListLocations {
 /**
  * Returns files sorted by the specified field.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GetAllLandingPagesSortField {
     #[serde(rename = "created_at")]
     CreatedAt,
@@ -20956,7 +24804,26 @@ impl Default for GetAllLandingPagesSortField {
         GetAllLandingPagesSortField::Noop
     }
 }
+
 impl GetAllLandingPagesSortField {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GetAllLandingPagesSortField::CreatedAt => "created_at",
+            GetAllLandingPagesSortField::UpdatedAt => "updated_at",
+            GetAllLandingPagesSortField::Noop => "",
+            GetAllLandingPagesSortField::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GetAllLandingPagesSortField] {
+        &[
+            GetAllLandingPagesSortField::CreatedAt,
+            GetAllLandingPagesSortField::UpdatedAt,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, GetAllLandingPagesSortField::Noop)
     }
@@ -20965,7 +24832,7 @@ impl GetAllLandingPagesSortField {
 /**
  * The status of this landing page.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum LandingPageStatus {
     #[serde(rename = "draft")]
     Draft,
@@ -20997,7 +24864,28 @@ impl Default for LandingPageStatus {
         LandingPageStatus::Noop
     }
 }
+
 impl LandingPageStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LandingPageStatus::Draft => "draft",
+            LandingPageStatus::Published => "published",
+            LandingPageStatus::Unpublished => "unpublished",
+            LandingPageStatus::Noop => "",
+            LandingPageStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [LandingPageStatus] {
+        &[
+            LandingPageStatus::Draft,
+            LandingPageStatus::Published,
+            LandingPageStatus::Unpublished,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, LandingPageStatus::Noop)
     }
@@ -21211,7 +25099,7 @@ pub struct GetAllLandingPagesResponse {
 /**
  * The type of template the landing page has.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum LandingPageTemplateType {
     #[serde(rename = "product")]
     Product,
@@ -21240,7 +25128,26 @@ impl Default for LandingPageTemplateType {
         LandingPageTemplateType::Noop
     }
 }
+
 impl LandingPageTemplateType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LandingPageTemplateType::Product => "product",
+            LandingPageTemplateType::Signup => "signup",
+            LandingPageTemplateType::Noop => "",
+            LandingPageTemplateType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [LandingPageTemplateType] {
+        &[
+            LandingPageTemplateType::Product,
+            LandingPageTemplateType::Signup,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, LandingPageTemplateType::Noop)
     }
@@ -22431,7 +26338,7 @@ pub struct AbuseComplaintsData {
 /**
  * The sentiment type for a feedback message.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum AdviceType {
     #[serde(rename = "negative")]
     Negative,
@@ -22463,7 +26370,28 @@ impl Default for AdviceType {
         AdviceType::Noop
     }
 }
+
 impl AdviceType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AdviceType::Negative => "negative",
+            AdviceType::Neutral => "neutral",
+            AdviceType::Positive => "positive",
+            AdviceType::Noop => "",
+            AdviceType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [AdviceType] {
+        &[
+            AdviceType::Negative,
+            AdviceType::Neutral,
+            AdviceType::Positive,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, AdviceType::Noop)
     }
@@ -24084,7 +28012,7 @@ pub struct UnsubscribesData {
 /**
  * Returns files sorted by the specified field.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GetReportsEcommerceProductActivitySortField {
     #[serde(rename = "title")]
     Title,
@@ -24116,7 +28044,28 @@ impl Default for GetReportsEcommerceProductActivitySortField {
         GetReportsEcommerceProductActivitySortField::Noop
     }
 }
+
 impl GetReportsEcommerceProductActivitySortField {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GetReportsEcommerceProductActivitySortField::Title => "title",
+            GetReportsEcommerceProductActivitySortField::TotalPurchased => "total_purchased",
+            GetReportsEcommerceProductActivitySortField::TotalRevenue => "total_revenue",
+            GetReportsEcommerceProductActivitySortField::Noop => "",
+            GetReportsEcommerceProductActivitySortField::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GetReportsEcommerceProductActivitySortField] {
+        &[
+            GetReportsEcommerceProductActivitySortField::Title,
+            GetReportsEcommerceProductActivitySortField::TotalPurchased,
+            GetReportsEcommerceProductActivitySortField::TotalRevenue,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, GetReportsEcommerceProductActivitySortField::Noop)
     }
@@ -24234,7 +28183,7 @@ pub struct GetReportsEcommerceProductActivityResponse {
 /**
  * Returns user templates sorted by the specified field.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GetTemplatesSortField {
     #[serde(rename = "date_created")]
     DateCreated,
@@ -24266,7 +28215,28 @@ impl Default for GetTemplatesSortField {
         GetTemplatesSortField::Noop
     }
 }
+
 impl GetTemplatesSortField {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GetTemplatesSortField::DateCreated => "date_created",
+            GetTemplatesSortField::DateEdited => "date_edited",
+            GetTemplatesSortField::Name => "name",
+            GetTemplatesSortField::Noop => "",
+            GetTemplatesSortField::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GetTemplatesSortField] {
+        &[
+            GetTemplatesSortField::DateCreated,
+            GetTemplatesSortField::DateEdited,
+            GetTemplatesSortField::Name,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, GetTemplatesSortField::Noop)
     }
@@ -24655,7 +28625,7 @@ pub struct Customer {
 /**
  * The Mailchimp tracking code for the order. Uses the 'mc_tc' parameter in E-Commerce tracking URLs.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum TrackingCode {
     #[serde(rename = "prec")]
     Prec,
@@ -24681,7 +28651,22 @@ impl Default for TrackingCode {
         TrackingCode::Noop
     }
 }
+
 impl TrackingCode {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TrackingCode::Prec => "prec",
+            TrackingCode::Noop => "",
+            TrackingCode::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [TrackingCode] {
+        &[TrackingCode::Prec]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, TrackingCode::Noop)
     }
@@ -24938,7 +28923,7 @@ pub struct BillingAddress {
 /**
  * Type of discount. For free shipping set type to fixed
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum OrdersPromosType {
     #[serde(rename = "fixed")]
     Fixed,
@@ -24967,7 +28952,23 @@ impl Default for OrdersPromosType {
         OrdersPromosType::Noop
     }
 }
+
 impl OrdersPromosType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrdersPromosType::Fixed => "fixed",
+            OrdersPromosType::Percentage => "percentage",
+            OrdersPromosType::Noop => "",
+            OrdersPromosType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [OrdersPromosType] {
+        &[OrdersPromosType::Fixed, OrdersPromosType::Percentage]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, OrdersPromosType::Noop)
     }
@@ -26644,7 +30645,7 @@ pub struct ECommerceCustomerDataType {
 /**
  * The target that the discount applies to.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Target {
     #[serde(rename = "per_item")]
     PerItem,
@@ -26676,7 +30677,24 @@ impl Default for Target {
         Target::Noop
     }
 }
+
 impl Target {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Target::PerItem => "per_item",
+            Target::Shipping => "shipping",
+            Target::Total => "total",
+            Target::Noop => "",
+            Target::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Target] {
+        &[Target::PerItem, Target::Shipping, Target::Total]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Target::Noop)
     }
@@ -28749,7 +32767,7 @@ pub struct ApiHealthStatus {
 /**
  * Returns files sorted by the specified field.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GetAllFacebookAdsSortField {
     #[serde(rename = "created_at")]
     CreatedAt,
@@ -28781,7 +32799,28 @@ impl Default for GetAllFacebookAdsSortField {
         GetAllFacebookAdsSortField::Noop
     }
 }
+
 impl GetAllFacebookAdsSortField {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GetAllFacebookAdsSortField::CreatedAt => "created_at",
+            GetAllFacebookAdsSortField::EndTime => "end_time",
+            GetAllFacebookAdsSortField::UpdatedAt => "updated_at",
+            GetAllFacebookAdsSortField::Noop => "",
+            GetAllFacebookAdsSortField::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GetAllFacebookAdsSortField] {
+        &[
+            GetAllFacebookAdsSortField::CreatedAt,
+            GetAllFacebookAdsSortField::EndTime,
+            GetAllFacebookAdsSortField::UpdatedAt,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, GetAllFacebookAdsSortField::Noop)
     }
@@ -28790,7 +32829,7 @@ impl GetAllFacebookAdsSortField {
 /**
  * Supported Campaign, Ad, Page type
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum OutreachType {
     #[serde(rename = "absplit")]
     Absplit,
@@ -28855,7 +32894,50 @@ impl Default for OutreachType {
         OutreachType::Noop
     }
 }
+
 impl OutreachType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OutreachType::Absplit => "absplit",
+            OutreachType::Automation => "automation",
+            OutreachType::Autoresponder => "autoresponder",
+            OutreachType::Facebook => "facebook",
+            OutreachType::Google => "google",
+            OutreachType::Page => "page",
+            OutreachType::Plaintext => "plaintext",
+            OutreachType::Reconfirm => "reconfirm",
+            OutreachType::Regular => "regular",
+            OutreachType::Rss => "rss",
+            OutreachType::Survey => "survey",
+            OutreachType::Transactional => "transactional",
+            OutreachType::Variate => "variate",
+            OutreachType::Website => "website",
+            OutreachType::Noop => "",
+            OutreachType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [OutreachType] {
+        &[
+            OutreachType::Absplit,
+            OutreachType::Automation,
+            OutreachType::Autoresponder,
+            OutreachType::Facebook,
+            OutreachType::Google,
+            OutreachType::Page,
+            OutreachType::Plaintext,
+            OutreachType::Reconfirm,
+            OutreachType::Regular,
+            OutreachType::Rss,
+            OutreachType::Survey,
+            OutreachType::Transactional,
+            OutreachType::Variate,
+            OutreachType::Website,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, OutreachType::Noop)
     }
@@ -28864,7 +32946,7 @@ impl OutreachType {
 /**
  * Campaign, Ad, or Page status
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum OutreachStatus {
     #[serde(rename = "active")]
     Active,
@@ -28938,7 +33020,56 @@ impl Default for OutreachStatus {
         OutreachStatus::Noop
     }
 }
+
 impl OutreachStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OutreachStatus::Active => "active",
+            OutreachStatus::Canceled => "canceled",
+            OutreachStatus::Canceling => "canceling",
+            OutreachStatus::Completed => "completed",
+            OutreachStatus::Disconnected => "disconnected",
+            OutreachStatus::Draft => "draft",
+            OutreachStatus::PartialRejected => "partialRejected",
+            OutreachStatus::Paused => "paused",
+            OutreachStatus::Pending => "pending",
+            OutreachStatus::Published => "published",
+            OutreachStatus::Rejected => "rejected",
+            OutreachStatus::Save => "save",
+            OutreachStatus::Schedule => "schedule",
+            OutreachStatus::Sending => "sending",
+            OutreachStatus::Sent => "sent",
+            OutreachStatus::Somepaused => "somepaused",
+            OutreachStatus::Unpublished => "unpublished",
+            OutreachStatus::Noop => "",
+            OutreachStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [OutreachStatus] {
+        &[
+            OutreachStatus::Active,
+            OutreachStatus::Canceled,
+            OutreachStatus::Canceling,
+            OutreachStatus::Completed,
+            OutreachStatus::Disconnected,
+            OutreachStatus::Draft,
+            OutreachStatus::PartialRejected,
+            OutreachStatus::Paused,
+            OutreachStatus::Pending,
+            OutreachStatus::Published,
+            OutreachStatus::Rejected,
+            OutreachStatus::Save,
+            OutreachStatus::Schedule,
+            OutreachStatus::Sending,
+            OutreachStatus::Sent,
+            OutreachStatus::Somepaused,
+            OutreachStatus::Unpublished,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, OutreachStatus::Noop)
     }
@@ -29431,7 +33562,7 @@ pub struct Site {
 /**
  * Type of the audience
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum FacebookAdsAudienceType {
     #[serde(rename = "Custom Audience")]
     CustomAudience,
@@ -29463,7 +33594,28 @@ impl Default for FacebookAdsAudienceType {
         FacebookAdsAudienceType::Noop
     }
 }
+
 impl FacebookAdsAudienceType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FacebookAdsAudienceType::CustomAudience => "Custom Audience",
+            FacebookAdsAudienceType::InterestBasedAudience => "Interest-based Audience",
+            FacebookAdsAudienceType::LookalikeAudience => "Lookalike Audience",
+            FacebookAdsAudienceType::Noop => "",
+            FacebookAdsAudienceType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [FacebookAdsAudienceType] {
+        &[
+            FacebookAdsAudienceType::CustomAudience,
+            FacebookAdsAudienceType::InterestBasedAudience,
+            FacebookAdsAudienceType::LookalikeAudience,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, FacebookAdsAudienceType::Noop)
     }
@@ -29472,7 +33624,7 @@ impl FacebookAdsAudienceType {
 /**
  * List or Facebook based audience
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SourceType {
     #[serde(rename = "facebook")]
     Facebook,
@@ -29501,7 +33653,23 @@ impl Default for SourceType {
         SourceType::Noop
     }
 }
+
 impl SourceType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SourceType::Facebook => "facebook",
+            SourceType::List => "list",
+            SourceType::Noop => "",
+            SourceType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SourceType] {
+        &[SourceType::Facebook, SourceType::List]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SourceType::Noop)
     }
@@ -30791,3 +34959,188 @@ pub struct VerifiedDomainsDataType {
     )]
     pub verification_email: String,
 }
+
+/// The types taken and returned by the functions in [`crate::activity_feed`].
+pub mod activity_feed {
+    pub use super::GetActivityFeedChimpChatterResponse;
+}
+
+/// The types taken and returned by the functions in [`crate::authorized_apps`].
+pub mod authorized_apps {
+    pub use super::{Apps, GetAuthorizedAppsResponse};
+}
+
+/// The types taken and returned by the functions in [`crate::automations`].
+pub mod automations {
+    pub use super::{
+        AutomationEmails, AutomationWorkflow, Automations, Emails,
+        GetAutomationsEmailsQueueResponse, GetAutomationsResponse, RemovedSubscribers, Status,
+        SubscriberInAutomationQueue, SubscriberInAutomationQueueData, Subscribers,
+        UpdateInformationAboutASpecificWorkflowEmail,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::batch_webhooks`].
+pub mod batch_webhooks {
+    pub use super::{BatchWebhook, BatchWebhooks, Webhooks};
+}
+
+/// The types taken and returned by the functions in [`crate::batches`].
+pub mod batches {
+    pub use super::{Batch, BatchOperations, PostBatchesRequest};
+}
+
+/// The types taken and returned by the functions in [`crate::campaign_folders`].
+pub mod campaign_folders {
+    pub use super::{CampaignFolder, CampaignFolders, GalleryFolder};
+}
+
+/// The types taken and returned by the functions in [`crate::campaigns`].
+pub mod campaigns {
+    pub use super::{
+        Campaign, CampaignContent, CampaignContentData, CampaignData, CampaignDataType,
+        CampaignFeedback, CampaignFeedbackData, CampaignFeedbackDataType, CampaignReports,
+        CampaignType, CreatedCampaign, GetCampaignsResponse, GetCampaignsStatus,
+        PostCampaignsActionsScheduleRequest, PostCampaignsActionsTestRequest, SendChecklist,
+        SortDir, SortField,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::connected_sites`].
+pub mod connected_sites {
+    pub use super::{ConnectedSite, ConnectedSites, Sites};
+}
+
+/// The types taken and returned by the functions in [`crate::conversations`].
+pub mod conversations {
+    pub use super::{
+        CollectionOfConversationMessages, Conversation, ConversationMessage, IsRead,
+        TrackedConversations,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::customer_journeys`].
+pub mod customer_journeys {
+    pub use super::SubscriberInAutomationQueue;
+}
+
+/// The types taken and returned by the functions in [`crate::ecommerce`].
+pub mod ecommerce {
+    pub use super::{
+        CartLines, Carts, CartsData, Customer, Customers, ECommerceCart, ECommerceCartCustomer,
+        ECommerceCartData, ECommerceCartLineItem, ECommerceCartLineItemData,
+        ECommerceCartLineItemDataType, ECommerceCustomerData, ECommerceCustomerDataType,
+        ECommerceOrder, ECommerceOrderData, ECommerceOrderLineItem, ECommerceOrderLineItemData,
+        ECommerceProduct, ECommerceProductData, ECommerceProductDataType, ECommerceProductImage,
+        ECommerceProductImageData, ECommerceProductVariant, ECommerceProductVariantData,
+        ECommercePromoCode, ECommercePromoCodeData, ECommercePromoRule, ECommercePromoRuleData,
+        ECommerceStore, ECommerceStoreData, ECommerceStores, EcommerceProductImages,
+        EcommerceProductVariants, Images, Lines, OrderLines, Orders, OrdersData, OrdersDataType,
+        ProductsData, PromoCodes, PromoCodesData, PromoRules, PromoRulesData, Stores, Variants,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::facebook_ads`].
+pub mod facebook_ads {
+    pub use super::{
+        FacebookAdsAllOf, GetAllFacebookAdsResponse, GetAllFacebookAdsSortField, SortDir,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::file_manager`].
+pub mod file_manager {
+    pub use super::{
+        FileManager, FileManagerFolders, FileManagerFoldersGalleryFolder, Files, GalleryFile,
+        GalleryFileData, GalleryFolder, GetFileManagerFilesSortField, SortDir,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::landing_pages`].
+pub mod landing_pages {
+    pub use super::{
+        GetAllLandingPagesResponse, GetAllLandingPagesSortField, LandingPage, LandingPageContent,
+        LandingPageData, LandingPageDataType, SortDir,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::lists`].
+pub mod lists {
+    pub use super::{
+        AbuseComplaints, AbuseReports, AddListMembers, AddListMembersData, AddListMembersDataType,
+        AddWebhook, BatchAddRemoveListMembersFromStaticSegment, BatchUpdateListMembers, Categories,
+        CollectionOfEvents, CollectionOfMemberActivityEvents, CollectionOfMergeFields,
+        CollectionOfNotes, CollectionOfNotesMember, CollectionOfSegments, CollectionOfTags,
+        EmailClients, EventsData, GetListsGrowthHistorySortField, GetListsMembersNotesSortField,
+        GetListsMembersSortField, GetListsMembersStatus, GetListsSortField, GrowthHistory, History,
+        Interest, InterestCategory, InterestGroupings, InterestMatch, InterestsData,
+        InterestsInterest, ListActivity, ListData, ListDataType, ListLocations, ListMembers,
+        ListMembersData, ListMembersDataType, ListSignupForms, ListWebhooks, ListWebhooksData,
+        Lists, MemberActivityEvents, MemberActivityEventsData, MemberNotes, MemberTags,
+        MembersAddRemoveFromAStaticSegment, MembersSubscribeUnsubscribeFromAListInBatch,
+        MergeField, MergeFieldData, MergeFieldDataType, SegmentMembers, Segments, SignupForm,
+        SignupFormData, SortDir, SubscriberInAutomationQueue, SubscriberList, SubscriberListData,
+        SubscriberLists, TagSearchResults,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::ping`].
+pub mod ping {
+    pub use super::ApiHealthStatus;
+}
+
+/// The types taken and returned by the functions in [`crate::reporting`].
+pub mod reporting {
+    pub use super::{
+        GetAllFacebookAdsSortField, GetReportingFacebookAdsResponse,
+        GetReportingFacebookAdsResponseAllOf, GetReportingLandingPagesResponse,
+        GetReportsEcommerceProductActivityResponse, GetReportsEcommerceProductActivitySortField,
+        LandingPages, SortDir,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::reports`].
+pub mod reports {
+    pub use super::{
+        AbuseComplaint, AbuseComplaintsData, CampaignAdviceReport, CampaignReportsData,
+        CampaignSubReports, CampaignType, ClickDetailMember, ClickDetailMembers, ClickDetailReport,
+        DomainPerformance, EepurlActivity, EmailActivity, EmailActivityData,
+        GetReportsEcommerceProductActivityResponse, GetReportsEcommerceProductActivitySortField,
+        OpenActivity, OpenDetailReport, OpenLocationsData, Reports, SentData, SentTo, Unsubscribes,
+        UnsubscribesData, UrlsClicked,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::root`].
+pub mod root {
+    pub use super::ApiRoot;
+}
+
+/// The types taken and returned by the functions in [`crate::search_campaigns`].
+pub mod search_campaigns {
+    pub use super::Campaigns;
+}
+
+/// The types taken and returned by the functions in [`crate::search_members`].
+pub mod search_members {
+    pub use super::MembersData;
+}
+
+/// The types taken and returned by the functions in [`crate::template_folders`].
+pub mod template_folders {
+    pub use super::{Folders, GalleryFolder, TemplateFolders};
+}
+
+/// The types taken and returned by the functions in [`crate::templates`].
+pub mod templates {
+    pub use super::{
+        GetTemplatesSortField, SortDir, TemplateDefaultContent, TemplateInstance, Templates,
+        TemplatesData,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::verified_domains`].
+pub mod verified_domains {
+    pub use super::{
+        VerifiedDomains, VerifiedDomainsData, VerifiedDomainsDataType, VerifyADomainSending,
+    };
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct VerifiedDomains {
     pub client: Client,
@@ -12,6 +10,14 @@ impl VerifiedDomains {
         VerifiedDomains { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        VerifiedDomains {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get domain info.
      *
@@ -24,12 +30,15 @@ impl VerifiedDomains {
      * * `domain_name: &str` -- The name of the folder.
      */
     pub async fn get(&self, domain_name: &str) -> Result<crate::types::VerifiedDomains> {
-        let url = format!(
-            "/verified-domains/{}",
-            crate::progenitor_support::encode_path(&domain_name.to_string()),
-        );
+        let url = format!("/verified-domains/{}", self.client.encode_path(domain_name),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/verified-domains/{domain_name}",
+                &[("domain_name", domain_name.to_string())],
+            )
+        })
     }
 
     /**
@@ -44,12 +53,37 @@ impl VerifiedDomains {
      * * `domain_name: &str` -- The name of the folder.
      */
     pub async fn delete(&self, domain_name: &str) -> Result<()> {
-        let url = format!(
-            "/verified-domains/{}",
-            crate::progenitor_support::encode_path(&domain_name.to_string()),
-        );
+        let url = format!("/verified-domains/{}", self.client.encode_path(domain_name),);
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/verified-domains/{domain_name}",
+                &[("domain_name", domain_name.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete domain.
+     *
+     * This function performs a `DELETE` to the `/verified-domains/{domain_name}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, domain_name: &str) -> Result<bool> {
+        let url = format!("/verified-domains/{}", self.client.encode_path(domain_name),);
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/verified-domains/{domain_name}",
+                    &[("domain_name", domain_name.to_string())],
+                )
+            })
     }
 
     /**
@@ -70,12 +104,19 @@ impl VerifiedDomains {
     ) -> Result<crate::types::VerifiedDomains> {
         let url = format!(
             "/verified-domains/{}/actions/verify",
-            crate::progenitor_support::encode_path(&domain_name.to_string()),
+            self.client.encode_path(domain_name),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/verified-domains/{domain_name}/actions/verify",
+                    &[("domain_name", domain_name.to_string())],
+                )
+            })
     }
 
     /**
@@ -87,7 +128,10 @@ impl VerifiedDomains {
      */
     pub async fn get_verified_domains(&self) -> Result<crate::types::VerifiedDomainsData> {
         let url = "/verified-domains".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/verified-domains", &[]))
     }
 
     /**
@@ -103,7 +147,59 @@ impl VerifiedDomains {
     ) -> Result<crate::types::VerifiedDomains> {
         let url = "/verified-domains".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/verified-domains", &[]))
+    }
+}
+
+/// The functions of [`VerifiedDomains`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn VerifiedDomainsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait VerifiedDomainsApi: Send + Sync {
+    async fn get(&self, domain_name: &str) -> Result<crate::types::VerifiedDomains>;
+    async fn delete(&self, domain_name: &str) -> Result<()>;
+    async fn delete_if_exists(&self, domain_name: &str) -> Result<bool>;
+    async fn verify_domain(
+        &self,
+        domain_name: &str,
+        body: &crate::types::VerifyADomainSending,
+    ) -> Result<crate::types::VerifiedDomains>;
+    async fn get_verified_domains(&self) -> Result<crate::types::VerifiedDomainsData>;
+    async fn create(
+        &self,
+        body: &crate::types::VerifiedDomainsDataType,
+    ) -> Result<crate::types::VerifiedDomains>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl VerifiedDomainsApi for VerifiedDomains {
+    async fn get(&self, domain_name: &str) -> Result<crate::types::VerifiedDomains> {
+        VerifiedDomains::get(self, domain_name).await
+    }
+    async fn delete(&self, domain_name: &str) -> Result<()> {
+        VerifiedDomains::delete(self, domain_name).await
+    }
+    async fn delete_if_exists(&self, domain_name: &str) -> Result<bool> {
+        VerifiedDomains::delete_if_exists(self, domain_name).await
+    }
+    async fn verify_domain(
+        &self,
+        domain_name: &str,
+        body: &crate::types::VerifyADomainSending,
+    ) -> Result<crate::types::VerifiedDomains> {
+        VerifiedDomains::verify_domain(self, domain_name, body).await
+    }
+    async fn get_verified_domains(&self) -> Result<crate::types::VerifiedDomainsData> {
+        VerifiedDomains::get_verified_domains(self).await
+    }
+    async fn create(
+        &self,
+        body: &crate::types::VerifiedDomainsDataType,
+    ) -> Result<crate::types::VerifiedDomains> {
+        VerifiedDomains::create(self, body).await
     }
 }
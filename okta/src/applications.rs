@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Applications {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Applications {
         Applications { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Applications {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * List Applications.
      *
@@ -37,32 +43,31 @@ impl Applications {
         expand: &str,
         include_non_deleted: bool,
     ) -> Result<Vec<crate::types::Application>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if include_non_deleted {
-            query_args.push((
-                "includeNonDeleted".to_string(),
-                include_non_deleted.to_string(),
-            ));
+            query_args.push(("includeNonDeleted", include_non_deleted.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/apps?{}", query_);
+        let url = crate::Client::url_with_query("/api/v1/apps", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/api/v1/apps", &[]))
     }
 
     /**
@@ -81,26 +86,25 @@ impl Applications {
         expand: &str,
         include_non_deleted: bool,
     ) -> Result<Vec<crate::types::Application>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if include_non_deleted {
-            query_args.push((
-                "includeNonDeleted".to_string(),
-                include_non_deleted.to_string(),
-            ));
+            query_args.push(("includeNonDeleted", include_non_deleted.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/apps?{}", query_);
+        let url = crate::Client::url_with_query("/api/v1/apps", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/api/v1/apps", &[]))
     }
 
     /**
@@ -118,18 +122,18 @@ impl Applications {
     pub async fn create(
         &self,
         activate: bool,
-        body: &crate::types::Application,
+        body: &crate::types::ApplicationRequest,
     ) -> Result<crate::types::Application> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if activate {
-            query_args.push(("activate".to_string(), activate.to_string()));
+            query_args.push(("activate", activate.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/apps?{}", query_);
+        let url = crate::Client::url_with_query("/api/v1/apps", &query_args);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/api/v1/apps", &[]))
     }
 
     /**
@@ -145,18 +149,22 @@ impl Applications {
      * * `expand: &str`
      */
     pub async fn get(&self, app_id: &str, expand: &str) -> Result<crate::types::Application> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/apps/{}?{}",
-            crate::progenitor_support::encode_path(&app_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/api/v1/apps/{}", self.client.encode_path(app_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/apps/{appId}",
+                &[("appId", app_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -173,16 +181,20 @@ impl Applications {
     pub async fn update(
         &self,
         app_id: &str,
-        body: &crate::types::Application,
+        body: &crate::types::ApplicationRequest,
     ) -> Result<crate::types::Application> {
-        let url = format!(
-            "/api/v1/apps/{}",
-            crate::progenitor_support::encode_path(&app_id.to_string()),
-        );
+        let url = format!("/api/v1/apps/{}", self.client.encode_path(app_id),);
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/api/v1/apps/{appId}",
+                    &[("appId", app_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -197,12 +209,37 @@ impl Applications {
      * * `app_id: &str`
      */
     pub async fn delete(&self, app_id: &str) -> Result<()> {
-        let url = format!(
-            "/api/v1/apps/{}",
-            crate::progenitor_support::encode_path(&app_id.to_string()),
-        );
+        let url = format!("/api/v1/apps/{}", self.client.encode_path(app_id),);
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/apps/{appId}",
+                &[("appId", app_id.to_string())],
+            )
+        })
+    }
 
-        self.client.delete(&url, None).await
+    /**
+     * Delete Application.
+     *
+     * This function performs a `DELETE` to the `/api/v1/apps/{appId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, app_id: &str) -> Result<bool> {
+        let url = format!("/api/v1/apps/{}", self.client.encode_path(app_id),);
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/apps/{appId}",
+                    &[("appId", app_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -219,10 +256,16 @@ impl Applications {
     pub async fn list_csrs_fors(&self, app_id: &str) -> Result<Vec<crate::types::Csr>> {
         let url = format!(
             "/api/v1/apps/{}/credentials/csrs",
-            crate::progenitor_support::encode_path(&app_id.to_string()),
+            self.client.encode_path(app_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/apps/{appId}/credentials/csrs",
+                &[("appId", app_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -237,10 +280,19 @@ impl Applications {
     pub async fn list_all_csrs_fors(&self, app_id: &str) -> Result<Vec<crate::types::Csr>> {
         let url = format!(
             "/api/v1/apps/{}/credentials/csrs",
-            crate::progenitor_support::encode_path(&app_id.to_string()),
+            self.client.encode_path(app_id),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/api/v1/apps/{appId}/credentials/csrs",
+                    &[("appId", app_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -261,12 +313,19 @@ impl Applications {
     ) -> Result<crate::types::Csr> {
         let url = format!(
             "/api/v1/apps/{}/credentials/csrs",
-            crate::progenitor_support::encode_path(&app_id.to_string()),
+            self.client.encode_path(app_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/api/v1/apps/{appId}/credentials/csrs",
+                    &[("appId", app_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -280,11 +339,17 @@ impl Applications {
     pub async fn get_csr_for(&self, app_id: &str, csr_id: &str) -> Result<crate::types::Csr> {
         let url = format!(
             "/api/v1/apps/{}/credentials/csrs/{}",
-            crate::progenitor_support::encode_path(&app_id.to_string()),
-            crate::progenitor_support::encode_path(&csr_id.to_string()),
+            self.client.encode_path(app_id),
+            self.client.encode_path(csr_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/apps/{appId}/credentials/csrs/{csrId}",
+                &[("appId", app_id.to_string()), ("csrId", csr_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -298,11 +363,41 @@ impl Applications {
     pub async fn revoke_csr_from(&self, app_id: &str, csr_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/apps/{}/credentials/csrs/{}",
-            crate::progenitor_support::encode_path(&app_id.to_string()),
-            crate::progenitor_support::encode_path(&csr_id.to_string()),
+            self.client.encode_path(app_id),
+            self.client.encode_path(csr_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/apps/{appId}/credentials/csrs/{csrId}",
+                &[("appId", app_id.to_string()), ("csrId", csr_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/api/v1/apps/{appId}/credentials/csrs/{csrId}` endpoint.
+     *
+     * As opposed to `revoke_csr_from`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn revoke_csr_from_if_exists(&self, app_id: &str, csr_id: &str) -> Result<bool> {
+        let url = format!(
+            "/api/v1/apps/{}/credentials/csrs/{}",
+            self.client.encode_path(app_id),
+            self.client.encode_path(csr_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/apps/{appId}/credentials/csrs/{csrId}",
+                    &[("appId", app_id.to_string()), ("csrId", csr_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -320,11 +415,17 @@ impl Applications {
     ) -> Result<crate::types::JsonWebKey> {
         let url = format!(
             "/api/v1/apps/{}/credentials/csrs/{}/lifecycle/publish",
-            crate::progenitor_support::encode_path(&app_id.to_string()),
-            crate::progenitor_support::encode_path(&csr_id.to_string()),
+            self.client.encode_path(app_id),
+            self.client.encode_path(csr_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/api/v1/apps/{appId}/credentials/csrs/{csrId}/lifecycle/publish",
+                &[("appId", app_id.to_string()), ("csrId", csr_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -341,10 +442,16 @@ impl Applications {
     pub async fn list_keys(&self, app_id: &str) -> Result<Vec<crate::types::JsonWebKey>> {
         let url = format!(
             "/api/v1/apps/{}/credentials/keys",
-            crate::progenitor_support::encode_path(&app_id.to_string()),
+            self.client.encode_path(app_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/apps/{appId}/credentials/keys",
+                &[("appId", app_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -359,10 +466,19 @@ impl Applications {
     pub async fn list_all_keys(&self, app_id: &str) -> Result<Vec<crate::types::JsonWebKey>> {
         let url = format!(
             "/api/v1/apps/{}/credentials/keys",
-            crate::progenitor_support::encode_path(&app_id.to_string()),
+            self.client.encode_path(app_id),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/api/v1/apps/{appId}/credentials/keys",
+                    &[("appId", app_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -380,18 +496,25 @@ impl Applications {
         app_id: &str,
         validity_years: i64,
     ) -> Result<crate::types::JsonWebKey> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if validity_years > 0 {
-            query_args.push(("validityYears".to_string(), validity_years.to_string()));
+            query_args.push(("validityYears", validity_years.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/apps/{}/credentials/keys/generate?{}",
-            crate::progenitor_support::encode_path(&app_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/api/v1/apps/{}/credentials/keys/generate",
+                self.client.encode_path(app_id)
+            ),
+            &query_args,
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/api/v1/apps/{appId}/credentials/keys/generate",
+                &[("appId", app_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -409,11 +532,17 @@ impl Applications {
     pub async fn get_key(&self, app_id: &str, key_id: &str) -> Result<crate::types::JsonWebKey> {
         let url = format!(
             "/api/v1/apps/{}/credentials/keys/{}",
-            crate::progenitor_support::encode_path(&app_id.to_string()),
-            crate::progenitor_support::encode_path(&key_id.to_string()),
+            self.client.encode_path(app_id),
+            self.client.encode_path(key_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/apps/{appId}/credentials/keys/{keyId}",
+                &[("appId", app_id.to_string()), ("keyId", key_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -435,19 +564,26 @@ impl Applications {
         key_id: &str,
         target_aid: &str,
     ) -> Result<crate::types::JsonWebKey> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !target_aid.is_empty() {
-            query_args.push(("targetAid".to_string(), target_aid.to_string()));
+            query_args.push(("targetAid", target_aid.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/apps/{}/credentials/keys/{}/clone?{}",
-            crate::progenitor_support::encode_path(&app_id.to_string()),
-            crate::progenitor_support::encode_path(&key_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/api/v1/apps/{}/credentials/keys/{}/clone",
+                self.client.encode_path(app_id),
+                self.client.encode_path(key_id)
+            ),
+            &query_args,
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/api/v1/apps/{appId}/credentials/keys/{keyId}/clone",
+                &[("appId", app_id.to_string()), ("keyId", key_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -465,18 +601,22 @@ impl Applications {
         app_id: &str,
         expand: &str,
     ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/apps/{}/grants?{}",
-            crate::progenitor_support::encode_path(&app_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/api/v1/apps/{}/grants", self.client.encode_path(app_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/apps/{appId}/grants",
+                &[("appId", app_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -491,18 +631,25 @@ impl Applications {
         app_id: &str,
         expand: &str,
     ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/apps/{}/grants?{}",
-            crate::progenitor_support::encode_path(&app_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/api/v1/apps/{}/grants", self.client.encode_path(app_id)),
+            &query_args,
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/api/v1/apps/{appId}/grants",
+                    &[("appId", app_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -517,16 +664,20 @@ impl Applications {
     pub async fn grant_consent_scope(
         &self,
         app_id: &str,
-        body: &crate::types::OAuth2ScopeConsentGrant,
+        body: &crate::types::OAuth2ScopeConsentGrantRequest,
     ) -> Result<crate::types::OAuth2ScopeConsentGrant> {
-        let url = format!(
-            "/api/v1/apps/{}/grants",
-            crate::progenitor_support::encode_path(&app_id.to_string()),
-        );
+        let url = format!("/api/v1/apps/{}/grants", self.client.encode_path(app_id),);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/api/v1/apps/{appId}/grants",
+                    &[("appId", app_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -546,19 +697,29 @@ impl Applications {
         grant_id: &str,
         expand: &str,
     ) -> Result<crate::types::OAuth2ScopeConsentGrant> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/apps/{}/grants/{}?{}",
-            crate::progenitor_support::encode_path(&app_id.to_string()),
-            crate::progenitor_support::encode_path(&grant_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/api/v1/apps/{}/grants/{}",
+                self.client.encode_path(app_id),
+                self.client.encode_path(grant_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/apps/{appId}/grants/{grantId}",
+                &[
+                    ("appId", app_id.to_string()),
+                    ("grantId", grant_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -574,11 +735,51 @@ impl Applications {
     pub async fn revoke_scope_consent_grant(&self, app_id: &str, grant_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/apps/{}/grants/{}",
-            crate::progenitor_support::encode_path(&app_id.to_string()),
-            crate::progenitor_support::encode_path(&grant_id.to_string()),
+            self.client.encode_path(app_id),
+            self.client.encode_path(grant_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/apps/{appId}/grants/{grantId}",
+                &[
+                    ("appId", app_id.to_string()),
+                    ("grantId", grant_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/api/v1/apps/{appId}/grants/{grantId}` endpoint.
+     *
+     * As opposed to `revoke_scope_consent_grant`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn revoke_scope_consent_grant_if_exists(
+        &self,
+        app_id: &str,
+        grant_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/api/v1/apps/{}/grants/{}",
+            self.client.encode_path(app_id),
+            self.client.encode_path(grant_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/apps/{appId}/grants/{grantId}",
+                    &[
+                        ("appId", app_id.to_string()),
+                        ("grantId", grant_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -604,27 +805,31 @@ impl Applications {
         limit: i64,
         expand: &str,
     ) -> Result<Vec<crate::types::ApplicationGroupAssignment>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/apps/{}/groups?{}",
-            crate::progenitor_support::encode_path(&app_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/api/v1/apps/{}/groups", self.client.encode_path(app_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/apps/{appId}/groups",
+                &[("appId", app_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -642,21 +847,28 @@ impl Applications {
         q: &str,
         expand: &str,
     ) -> Result<Vec<crate::types::ApplicationGroupAssignment>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/apps/{}/groups?{}",
-            crate::progenitor_support::encode_path(&app_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/api/v1/apps/{}/groups", self.client.encode_path(app_id)),
+            &query_args,
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/api/v1/apps/{appId}/groups",
+                    &[("appId", app_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -678,19 +890,29 @@ impl Applications {
         group_id: &str,
         expand: &str,
     ) -> Result<crate::types::ApplicationGroupAssignment> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/apps/{}/groups/{}?{}",
-            crate::progenitor_support::encode_path(&app_id.to_string()),
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/api/v1/apps/{}/groups/{}",
+                self.client.encode_path(app_id),
+                self.client.encode_path(group_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/apps/{appId}/groups/{groupId}",
+                &[
+                    ("appId", app_id.to_string()),
+                    ("groupId", group_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -709,17 +931,27 @@ impl Applications {
         &self,
         app_id: &str,
         group_id: &str,
-        body: &crate::types::ApplicationGroupAssignment,
+        body: &crate::types::ApplicationGroupAssignmentRequest,
     ) -> Result<crate::types::ApplicationGroupAssignment> {
         let url = format!(
             "/api/v1/apps/{}/groups/{}",
-            crate::progenitor_support::encode_path(&app_id.to_string()),
-            crate::progenitor_support::encode_path(&group_id.to_string()),
+            self.client.encode_path(app_id),
+            self.client.encode_path(group_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/api/v1/apps/{appId}/groups/{groupId}",
+                    &[
+                        ("appId", app_id.to_string()),
+                        ("groupId", group_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -737,11 +969,53 @@ impl Applications {
     pub async fn delete_group_assignment(&self, app_id: &str, group_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/apps/{}/groups/{}",
-            crate::progenitor_support::encode_path(&app_id.to_string()),
-            crate::progenitor_support::encode_path(&group_id.to_string()),
+            self.client.encode_path(app_id),
+            self.client.encode_path(group_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/apps/{appId}/groups/{groupId}",
+                &[
+                    ("appId", app_id.to_string()),
+                    ("groupId", group_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Remove Group from Application.
+     *
+     * This function performs a `DELETE` to the `/api/v1/apps/{appId}/groups/{groupId}` endpoint.
+     *
+     * As opposed to `delete_group_assignment`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_group_assignment_if_exists(
+        &self,
+        app_id: &str,
+        group_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/api/v1/apps/{}/groups/{}",
+            self.client.encode_path(app_id),
+            self.client.encode_path(group_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/apps/{appId}/groups/{groupId}",
+                    &[
+                        ("appId", app_id.to_string()),
+                        ("groupId", group_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -758,10 +1032,16 @@ impl Applications {
     pub async fn activate(&self, app_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/apps/{}/lifecycle/activate",
-            crate::progenitor_support::encode_path(&app_id.to_string()),
+            self.client.encode_path(app_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/api/v1/apps/{appId}/lifecycle/activate",
+                &[("appId", app_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -778,10 +1058,16 @@ impl Applications {
     pub async fn deactivate(&self, app_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/apps/{}/lifecycle/deactivate",
-            crate::progenitor_support::encode_path(&app_id.to_string()),
+            self.client.encode_path(app_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/api/v1/apps/{appId}/lifecycle/deactivate",
+                &[("appId", app_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -803,24 +1089,28 @@ impl Applications {
         after: &str,
         limit: i64,
     ) -> Result<Vec<crate::types::OAuth2Token>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/apps/{}/tokens?{}",
-            crate::progenitor_support::encode_path(&app_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/api/v1/apps/{}/tokens", self.client.encode_path(app_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/apps/{appId}/tokens",
+                &[("appId", app_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -835,18 +1125,25 @@ impl Applications {
         app_id: &str,
         expand: &str,
     ) -> Result<Vec<crate::types::OAuth2Token>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/apps/{}/tokens?{}",
-            crate::progenitor_support::encode_path(&app_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/api/v1/apps/{}/tokens", self.client.encode_path(app_id)),
+            &query_args,
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/api/v1/apps/{appId}/tokens",
+                    &[("appId", app_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -859,12 +1156,35 @@ impl Applications {
      * * `app_id: &str`
      */
     pub async fn revoke_o_auth_2_tokens_for(&self, app_id: &str) -> Result<()> {
-        let url = format!(
-            "/api/v1/apps/{}/tokens",
-            crate::progenitor_support::encode_path(&app_id.to_string()),
-        );
+        let url = format!("/api/v1/apps/{}/tokens", self.client.encode_path(app_id),);
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/apps/{appId}/tokens",
+                &[("appId", app_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/api/v1/apps/{appId}/tokens` endpoint.
+     *
+     * As opposed to `revoke_o_auth_2_tokens_for`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn revoke_o_auth_2_tokens_for_if_exists(&self, app_id: &str) -> Result<bool> {
+        let url = format!("/api/v1/apps/{}/tokens", self.client.encode_path(app_id),);
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/apps/{appId}/tokens",
+                    &[("appId", app_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -884,19 +1204,29 @@ impl Applications {
         token_id: &str,
         expand: &str,
     ) -> Result<crate::types::OAuth2Token> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/apps/{}/tokens/{}?{}",
-            crate::progenitor_support::encode_path(&app_id.to_string()),
-            crate::progenitor_support::encode_path(&token_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/api/v1/apps/{}/tokens/{}",
+                self.client.encode_path(app_id),
+                self.client.encode_path(token_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/apps/{appId}/tokens/{tokenId}",
+                &[
+                    ("appId", app_id.to_string()),
+                    ("tokenId", token_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -912,11 +1242,51 @@ impl Applications {
     pub async fn revoke_o_auth_2_token_for(&self, app_id: &str, token_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/apps/{}/tokens/{}",
-            crate::progenitor_support::encode_path(&app_id.to_string()),
-            crate::progenitor_support::encode_path(&token_id.to_string()),
+            self.client.encode_path(app_id),
+            self.client.encode_path(token_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/apps/{appId}/tokens/{tokenId}",
+                &[
+                    ("appId", app_id.to_string()),
+                    ("tokenId", token_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/api/v1/apps/{appId}/tokens/{tokenId}` endpoint.
+     *
+     * As opposed to `revoke_o_auth_2_token_for`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn revoke_o_auth_2_token_for_if_exists(
+        &self,
+        app_id: &str,
+        token_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/api/v1/apps/{}/tokens/{}",
+            self.client.encode_path(app_id),
+            self.client.encode_path(token_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/apps/{appId}/tokens/{tokenId}",
+                    &[
+                        ("appId", app_id.to_string()),
+                        ("tokenId", token_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -946,33 +1316,37 @@ impl Applications {
         filter: &str,
         expand: &str,
     ) -> Result<Vec<crate::types::AppUser>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if !query_scope.is_empty() {
-            query_args.push(("query_scope".to_string(), query_scope.to_string()));
+            query_args.push(("query_scope", query_scope.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/apps/{}/users?{}",
-            crate::progenitor_support::encode_path(&app_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/api/v1/apps/{}/users", self.client.encode_path(app_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/apps/{appId}/users",
+                &[("appId", app_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -992,27 +1366,34 @@ impl Applications {
         filter: &str,
         expand: &str,
     ) -> Result<Vec<crate::types::AppUser>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if !query_scope.is_empty() {
-            query_args.push(("query_scope".to_string(), query_scope.to_string()));
+            query_args.push(("query_scope", query_scope.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/apps/{}/users?{}",
-            crate::progenitor_support::encode_path(&app_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/api/v1/apps/{}/users", self.client.encode_path(app_id)),
+            &query_args,
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/api/v1/apps/{appId}/users",
+                    &[("appId", app_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1029,16 +1410,20 @@ impl Applications {
     pub async fn assign_user(
         &self,
         app_id: &str,
-        body: &crate::types::AppUser,
+        body: &crate::types::AppUserRequest,
     ) -> Result<crate::types::AppUser> {
-        let url = format!(
-            "/api/v1/apps/{}/users",
-            crate::progenitor_support::encode_path(&app_id.to_string()),
-        );
+        let url = format!("/api/v1/apps/{}/users", self.client.encode_path(app_id),);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/api/v1/apps/{appId}/users",
+                    &[("appId", app_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1060,19 +1445,29 @@ impl Applications {
         user_id: &str,
         expand: &str,
     ) -> Result<crate::types::AppUser> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/apps/{}/users/{}?{}",
-            crate::progenitor_support::encode_path(&app_id.to_string()),
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/api/v1/apps/{}/users/{}",
+                self.client.encode_path(app_id),
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/apps/{appId}/users/{userId}",
+                &[
+                    ("appId", app_id.to_string()),
+                    ("userId", user_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1091,17 +1486,27 @@ impl Applications {
         &self,
         app_id: &str,
         user_id: &str,
-        body: &crate::types::AppUser,
+        body: &crate::types::AppUserRequest,
     ) -> Result<crate::types::AppUser> {
         let url = format!(
             "/api/v1/apps/{}/users/{}",
-            crate::progenitor_support::encode_path(&app_id.to_string()),
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(app_id),
+            self.client.encode_path(user_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/api/v1/apps/{appId}/users/{userId}",
+                    &[
+                        ("appId", app_id.to_string()),
+                        ("userId", user_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1118,18 +1523,547 @@ impl Applications {
      * * `send_email: bool`
      */
     pub async fn delete_user(&self, app_id: &str, user_id: &str, send_email: bool) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if send_email {
-            query_args.push(("sendEmail".to_string(), send_email.to_string()));
+            query_args.push(("sendEmail", send_email.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/apps/{}/users/{}?{}",
-            crate::progenitor_support::encode_path(&app_id.to_string()),
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/api/v1/apps/{}/users/{}",
+                self.client.encode_path(app_id),
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/apps/{appId}/users/{userId}",
+                &[
+                    ("appId", app_id.to_string()),
+                    ("userId", user_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Remove User from Application.
+     *
+     * This function performs a `DELETE` to the `/api/v1/apps/{appId}/users/{userId}` endpoint.
+     *
+     * As opposed to `delete_user`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_user_if_exists(
+        &self,
+        app_id: &str,
+        user_id: &str,
+        send_email: bool,
+    ) -> Result<bool> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if send_email {
+            query_args.push(("sendEmail", send_email.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/api/v1/apps/{}/users/{}",
+                self.client.encode_path(app_id),
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/apps/{appId}/users/{userId}",
+                    &[
+                        ("appId", app_id.to_string()),
+                        ("userId", user_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`Applications`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ApplicationsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ApplicationsApi: Send + Sync {
+    async fn list(
+        &self,
+        q: &str,
+        after: &str,
+        limit: i64,
+        filter: &str,
+        expand: &str,
+        include_non_deleted: bool,
+    ) -> Result<Vec<crate::types::Application>>;
+    async fn list_all(
+        &self,
+        q: &str,
+        filter: &str,
+        expand: &str,
+        include_non_deleted: bool,
+    ) -> Result<Vec<crate::types::Application>>;
+    async fn create(
+        &self,
+        activate: bool,
+        body: &crate::types::ApplicationRequest,
+    ) -> Result<crate::types::Application>;
+    async fn get(&self, app_id: &str, expand: &str) -> Result<crate::types::Application>;
+    async fn update(
+        &self,
+        app_id: &str,
+        body: &crate::types::ApplicationRequest,
+    ) -> Result<crate::types::Application>;
+    async fn delete(&self, app_id: &str) -> Result<()>;
+    async fn delete_if_exists(&self, app_id: &str) -> Result<bool>;
+    async fn list_csrs_fors(&self, app_id: &str) -> Result<Vec<crate::types::Csr>>;
+    async fn list_all_csrs_fors(&self, app_id: &str) -> Result<Vec<crate::types::Csr>>;
+    async fn generate_csr_for(
+        &self,
+        app_id: &str,
+        body: &crate::types::CsrMetadata,
+    ) -> Result<crate::types::Csr>;
+    async fn get_csr_for(&self, app_id: &str, csr_id: &str) -> Result<crate::types::Csr>;
+    async fn revoke_csr_from(&self, app_id: &str, csr_id: &str) -> Result<()>;
+    async fn revoke_csr_from_if_exists(&self, app_id: &str, csr_id: &str) -> Result<bool>;
+    async fn post_app_credentials_csr_lifecycle_publish(
+        &self,
+        app_id: &str,
+        csr_id: &str,
+    ) -> Result<crate::types::JsonWebKey>;
+    async fn list_keys(&self, app_id: &str) -> Result<Vec<crate::types::JsonWebKey>>;
+    async fn list_all_keys(&self, app_id: &str) -> Result<Vec<crate::types::JsonWebKey>>;
+    async fn generate_key(
+        &self,
+        app_id: &str,
+        validity_years: i64,
+    ) -> Result<crate::types::JsonWebKey>;
+    async fn get_key(&self, app_id: &str, key_id: &str) -> Result<crate::types::JsonWebKey>;
+    async fn clone_key(
+        &self,
+        app_id: &str,
+        key_id: &str,
+        target_aid: &str,
+    ) -> Result<crate::types::JsonWebKey>;
+    async fn list_scope_consent_grants(
+        &self,
+        app_id: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>>;
+    async fn list_all_scope_consent_grants(
+        &self,
+        app_id: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>>;
+    async fn grant_consent_scope(
+        &self,
+        app_id: &str,
+        body: &crate::types::OAuth2ScopeConsentGrantRequest,
+    ) -> Result<crate::types::OAuth2ScopeConsentGrant>;
+    async fn get_scope_consent_grant(
+        &self,
+        app_id: &str,
+        grant_id: &str,
+        expand: &str,
+    ) -> Result<crate::types::OAuth2ScopeConsentGrant>;
+    async fn revoke_scope_consent_grant(&self, app_id: &str, grant_id: &str) -> Result<()>;
+    async fn revoke_scope_consent_grant_if_exists(
+        &self,
+        app_id: &str,
+        grant_id: &str,
+    ) -> Result<bool>;
+    async fn list_group_assignments(
+        &self,
+        app_id: &str,
+        q: &str,
+        after: &str,
+        limit: i64,
+        expand: &str,
+    ) -> Result<Vec<crate::types::ApplicationGroupAssignment>>;
+    async fn list_all_group_assignments(
+        &self,
+        app_id: &str,
+        q: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::ApplicationGroupAssignment>>;
+    async fn get_group_assignment(
+        &self,
+        app_id: &str,
+        group_id: &str,
+        expand: &str,
+    ) -> Result<crate::types::ApplicationGroupAssignment>;
+    async fn create_group_assignment(
+        &self,
+        app_id: &str,
+        group_id: &str,
+        body: &crate::types::ApplicationGroupAssignmentRequest,
+    ) -> Result<crate::types::ApplicationGroupAssignment>;
+    async fn delete_group_assignment(&self, app_id: &str, group_id: &str) -> Result<()>;
+    async fn delete_group_assignment_if_exists(&self, app_id: &str, group_id: &str)
+        -> Result<bool>;
+    async fn activate(&self, app_id: &str) -> Result<()>;
+    async fn deactivate(&self, app_id: &str) -> Result<()>;
+    async fn list_o_auth_2_tokens_fors(
+        &self,
+        app_id: &str,
+        expand: &str,
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::OAuth2Token>>;
+    async fn list_all_o_auth_2_tokens_fors(
+        &self,
+        app_id: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::OAuth2Token>>;
+    async fn revoke_o_auth_2_tokens_for(&self, app_id: &str) -> Result<()>;
+    async fn revoke_o_auth_2_tokens_for_if_exists(&self, app_id: &str) -> Result<bool>;
+    async fn get_o_auth_2_token_for(
+        &self,
+        app_id: &str,
+        token_id: &str,
+        expand: &str,
+    ) -> Result<crate::types::OAuth2Token>;
+    async fn revoke_o_auth_2_token_for(&self, app_id: &str, token_id: &str) -> Result<()>;
+    async fn revoke_o_auth_2_token_for_if_exists(
+        &self,
+        app_id: &str,
+        token_id: &str,
+    ) -> Result<bool>;
+    async fn list_users(
+        &self,
+        app_id: &str,
+        q: &str,
+        query_scope: &str,
+        after: &str,
+        limit: i64,
+        filter: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::AppUser>>;
+    async fn list_all_users(
+        &self,
+        app_id: &str,
+        q: &str,
+        query_scope: &str,
+        filter: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::AppUser>>;
+    async fn assign_user(
+        &self,
+        app_id: &str,
+        body: &crate::types::AppUserRequest,
+    ) -> Result<crate::types::AppUser>;
+    async fn get_user(
+        &self,
+        app_id: &str,
+        user_id: &str,
+        expand: &str,
+    ) -> Result<crate::types::AppUser>;
+    async fn update_user(
+        &self,
+        app_id: &str,
+        user_id: &str,
+        body: &crate::types::AppUserRequest,
+    ) -> Result<crate::types::AppUser>;
+    async fn delete_user(&self, app_id: &str, user_id: &str, send_email: bool) -> Result<()>;
+    async fn delete_user_if_exists(
+        &self,
+        app_id: &str,
+        user_id: &str,
+        send_email: bool,
+    ) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ApplicationsApi for Applications {
+    async fn list(
+        &self,
+        q: &str,
+        after: &str,
+        limit: i64,
+        filter: &str,
+        expand: &str,
+        include_non_deleted: bool,
+    ) -> Result<Vec<crate::types::Application>> {
+        Applications::list(self, q, after, limit, filter, expand, include_non_deleted).await
+    }
+    async fn list_all(
+        &self,
+        q: &str,
+        filter: &str,
+        expand: &str,
+        include_non_deleted: bool,
+    ) -> Result<Vec<crate::types::Application>> {
+        Applications::list_all(self, q, filter, expand, include_non_deleted).await
+    }
+    async fn create(
+        &self,
+        activate: bool,
+        body: &crate::types::ApplicationRequest,
+    ) -> Result<crate::types::Application> {
+        Applications::create(self, activate, body).await
+    }
+    async fn get(&self, app_id: &str, expand: &str) -> Result<crate::types::Application> {
+        Applications::get(self, app_id, expand).await
+    }
+    async fn update(
+        &self,
+        app_id: &str,
+        body: &crate::types::ApplicationRequest,
+    ) -> Result<crate::types::Application> {
+        Applications::update(self, app_id, body).await
+    }
+    async fn delete(&self, app_id: &str) -> Result<()> {
+        Applications::delete(self, app_id).await
+    }
+    async fn delete_if_exists(&self, app_id: &str) -> Result<bool> {
+        Applications::delete_if_exists(self, app_id).await
+    }
+    async fn list_csrs_fors(&self, app_id: &str) -> Result<Vec<crate::types::Csr>> {
+        Applications::list_csrs_fors(self, app_id).await
+    }
+    async fn list_all_csrs_fors(&self, app_id: &str) -> Result<Vec<crate::types::Csr>> {
+        Applications::list_all_csrs_fors(self, app_id).await
+    }
+    async fn generate_csr_for(
+        &self,
+        app_id: &str,
+        body: &crate::types::CsrMetadata,
+    ) -> Result<crate::types::Csr> {
+        Applications::generate_csr_for(self, app_id, body).await
+    }
+    async fn get_csr_for(&self, app_id: &str, csr_id: &str) -> Result<crate::types::Csr> {
+        Applications::get_csr_for(self, app_id, csr_id).await
+    }
+    async fn revoke_csr_from(&self, app_id: &str, csr_id: &str) -> Result<()> {
+        Applications::revoke_csr_from(self, app_id, csr_id).await
+    }
+    async fn revoke_csr_from_if_exists(&self, app_id: &str, csr_id: &str) -> Result<bool> {
+        Applications::revoke_csr_from_if_exists(self, app_id, csr_id).await
+    }
+    async fn post_app_credentials_csr_lifecycle_publish(
+        &self,
+        app_id: &str,
+        csr_id: &str,
+    ) -> Result<crate::types::JsonWebKey> {
+        Applications::post_app_credentials_csr_lifecycle_publish(self, app_id, csr_id).await
+    }
+    async fn list_keys(&self, app_id: &str) -> Result<Vec<crate::types::JsonWebKey>> {
+        Applications::list_keys(self, app_id).await
+    }
+    async fn list_all_keys(&self, app_id: &str) -> Result<Vec<crate::types::JsonWebKey>> {
+        Applications::list_all_keys(self, app_id).await
+    }
+    async fn generate_key(
+        &self,
+        app_id: &str,
+        validity_years: i64,
+    ) -> Result<crate::types::JsonWebKey> {
+        Applications::generate_key(self, app_id, validity_years).await
+    }
+    async fn get_key(&self, app_id: &str, key_id: &str) -> Result<crate::types::JsonWebKey> {
+        Applications::get_key(self, app_id, key_id).await
+    }
+    async fn clone_key(
+        &self,
+        app_id: &str,
+        key_id: &str,
+        target_aid: &str,
+    ) -> Result<crate::types::JsonWebKey> {
+        Applications::clone_key(self, app_id, key_id, target_aid).await
+    }
+    async fn list_scope_consent_grants(
+        &self,
+        app_id: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>> {
+        Applications::list_scope_consent_grants(self, app_id, expand).await
+    }
+    async fn list_all_scope_consent_grants(
+        &self,
+        app_id: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>> {
+        Applications::list_all_scope_consent_grants(self, app_id, expand).await
+    }
+    async fn grant_consent_scope(
+        &self,
+        app_id: &str,
+        body: &crate::types::OAuth2ScopeConsentGrantRequest,
+    ) -> Result<crate::types::OAuth2ScopeConsentGrant> {
+        Applications::grant_consent_scope(self, app_id, body).await
+    }
+    async fn get_scope_consent_grant(
+        &self,
+        app_id: &str,
+        grant_id: &str,
+        expand: &str,
+    ) -> Result<crate::types::OAuth2ScopeConsentGrant> {
+        Applications::get_scope_consent_grant(self, app_id, grant_id, expand).await
+    }
+    async fn revoke_scope_consent_grant(&self, app_id: &str, grant_id: &str) -> Result<()> {
+        Applications::revoke_scope_consent_grant(self, app_id, grant_id).await
+    }
+    async fn revoke_scope_consent_grant_if_exists(
+        &self,
+        app_id: &str,
+        grant_id: &str,
+    ) -> Result<bool> {
+        Applications::revoke_scope_consent_grant_if_exists(self, app_id, grant_id).await
+    }
+    async fn list_group_assignments(
+        &self,
+        app_id: &str,
+        q: &str,
+        after: &str,
+        limit: i64,
+        expand: &str,
+    ) -> Result<Vec<crate::types::ApplicationGroupAssignment>> {
+        Applications::list_group_assignments(self, app_id, q, after, limit, expand).await
+    }
+    async fn list_all_group_assignments(
+        &self,
+        app_id: &str,
+        q: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::ApplicationGroupAssignment>> {
+        Applications::list_all_group_assignments(self, app_id, q, expand).await
+    }
+    async fn get_group_assignment(
+        &self,
+        app_id: &str,
+        group_id: &str,
+        expand: &str,
+    ) -> Result<crate::types::ApplicationGroupAssignment> {
+        Applications::get_group_assignment(self, app_id, group_id, expand).await
+    }
+    async fn create_group_assignment(
+        &self,
+        app_id: &str,
+        group_id: &str,
+        body: &crate::types::ApplicationGroupAssignmentRequest,
+    ) -> Result<crate::types::ApplicationGroupAssignment> {
+        Applications::create_group_assignment(self, app_id, group_id, body).await
+    }
+    async fn delete_group_assignment(&self, app_id: &str, group_id: &str) -> Result<()> {
+        Applications::delete_group_assignment(self, app_id, group_id).await
+    }
+    async fn delete_group_assignment_if_exists(
+        &self,
+        app_id: &str,
+        group_id: &str,
+    ) -> Result<bool> {
+        Applications::delete_group_assignment_if_exists(self, app_id, group_id).await
+    }
+    async fn activate(&self, app_id: &str) -> Result<()> {
+        Applications::activate(self, app_id).await
+    }
+    async fn deactivate(&self, app_id: &str) -> Result<()> {
+        Applications::deactivate(self, app_id).await
+    }
+    async fn list_o_auth_2_tokens_fors(
+        &self,
+        app_id: &str,
+        expand: &str,
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::OAuth2Token>> {
+        Applications::list_o_auth_2_tokens_fors(self, app_id, expand, after, limit).await
+    }
+    async fn list_all_o_auth_2_tokens_fors(
+        &self,
+        app_id: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::OAuth2Token>> {
+        Applications::list_all_o_auth_2_tokens_fors(self, app_id, expand).await
+    }
+    async fn revoke_o_auth_2_tokens_for(&self, app_id: &str) -> Result<()> {
+        Applications::revoke_o_auth_2_tokens_for(self, app_id).await
+    }
+    async fn revoke_o_auth_2_tokens_for_if_exists(&self, app_id: &str) -> Result<bool> {
+        Applications::revoke_o_auth_2_tokens_for_if_exists(self, app_id).await
+    }
+    async fn get_o_auth_2_token_for(
+        &self,
+        app_id: &str,
+        token_id: &str,
+        expand: &str,
+    ) -> Result<crate::types::OAuth2Token> {
+        Applications::get_o_auth_2_token_for(self, app_id, token_id, expand).await
+    }
+    async fn revoke_o_auth_2_token_for(&self, app_id: &str, token_id: &str) -> Result<()> {
+        Applications::revoke_o_auth_2_token_for(self, app_id, token_id).await
+    }
+    async fn revoke_o_auth_2_token_for_if_exists(
+        &self,
+        app_id: &str,
+        token_id: &str,
+    ) -> Result<bool> {
+        Applications::revoke_o_auth_2_token_for_if_exists(self, app_id, token_id).await
+    }
+    async fn list_users(
+        &self,
+        app_id: &str,
+        q: &str,
+        query_scope: &str,
+        after: &str,
+        limit: i64,
+        filter: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::AppUser>> {
+        Applications::list_users(self, app_id, q, query_scope, after, limit, filter, expand).await
+    }
+    async fn list_all_users(
+        &self,
+        app_id: &str,
+        q: &str,
+        query_scope: &str,
+        filter: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::AppUser>> {
+        Applications::list_all_users(self, app_id, q, query_scope, filter, expand).await
+    }
+    async fn assign_user(
+        &self,
+        app_id: &str,
+        body: &crate::types::AppUserRequest,
+    ) -> Result<crate::types::AppUser> {
+        Applications::assign_user(self, app_id, body).await
+    }
+    async fn get_user(
+        &self,
+        app_id: &str,
+        user_id: &str,
+        expand: &str,
+    ) -> Result<crate::types::AppUser> {
+        Applications::get_user(self, app_id, user_id, expand).await
+    }
+    async fn update_user(
+        &self,
+        app_id: &str,
+        user_id: &str,
+        body: &crate::types::AppUserRequest,
+    ) -> Result<crate::types::AppUser> {
+        Applications::update_user(self, app_id, user_id, body).await
+    }
+    async fn delete_user(&self, app_id: &str, user_id: &str, send_email: bool) -> Result<()> {
+        Applications::delete_user(self, app_id, user_id, send_email).await
+    }
+    async fn delete_user_if_exists(
+        &self,
+        app_id: &str,
+        user_id: &str,
+        send_email: bool,
+    ) -> Result<bool> {
+        Applications::delete_user_if_exists(self, app_id, user_id, send_email).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct AuthorizationServers {
     pub client: Client,
@@ -12,6 +10,14 @@ impl AuthorizationServers {
         AuthorizationServers { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        AuthorizationServers {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/authorizationServers` endpoint.
      *
@@ -29,20 +35,22 @@ impl AuthorizationServers {
         limit: &str,
         after: &str,
     ) -> Result<Vec<crate::types::AuthorizationServer>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/authorizationServers?{}", query_);
+        let url = crate::Client::url_with_query("/api/v1/authorizationServers", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/api/v1/authorizationServers", &[]))
     }
 
     /**
@@ -53,14 +61,16 @@ impl AuthorizationServers {
      * Success
      */
     pub async fn list_all(&self, q: &str) -> Result<Vec<crate::types::AuthorizationServer>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/authorizationServers?{}", query_);
+        let url = crate::Client::url_with_query("/api/v1/authorizationServers", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/api/v1/authorizationServers", &[]))
     }
 
     /**
@@ -70,12 +80,13 @@ impl AuthorizationServers {
      */
     pub async fn create(
         &self,
-        body: &crate::types::AuthorizationServer,
+        body: &crate::types::AuthorizationServerRequest,
     ) -> Result<crate::types::AuthorizationServer> {
         let url = "/api/v1/authorizationServers".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/api/v1/authorizationServers", &[]))
     }
 
     /**
@@ -90,10 +101,16 @@ impl AuthorizationServers {
     pub async fn get(&self, auth_server_id: &str) -> Result<crate::types::AuthorizationServer> {
         let url = format!(
             "/api/v1/authorizationServers/{}",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
+            self.client.encode_path(auth_server_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/authorizationServers/{authServerId}",
+                &[("authServerId", auth_server_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -108,16 +125,23 @@ impl AuthorizationServers {
     pub async fn update(
         &self,
         auth_server_id: &str,
-        body: &crate::types::AuthorizationServer,
+        body: &crate::types::AuthorizationServerRequest,
     ) -> Result<crate::types::AuthorizationServer> {
         let url = format!(
             "/api/v1/authorizationServers/{}",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
+            self.client.encode_path(auth_server_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/api/v1/authorizationServers/{authServerId}",
+                    &[("authServerId", auth_server_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -132,10 +156,39 @@ impl AuthorizationServers {
     pub async fn delete(&self, auth_server_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/authorizationServers/{}",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
+            self.client.encode_path(auth_server_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/authorizationServers/{authServerId}",
+                &[("authServerId", auth_server_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/api/v1/authorizationServers/{authServerId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, auth_server_id: &str) -> Result<bool> {
+        let url = format!(
+            "/api/v1/authorizationServers/{}",
+            self.client.encode_path(auth_server_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/authorizationServers/{authServerId}",
+                    &[("authServerId", auth_server_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -153,10 +206,16 @@ impl AuthorizationServers {
     ) -> Result<Vec<crate::types::OAuth2Claim>> {
         let url = format!(
             "/api/v1/authorizationServers/{}/claims",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
+            self.client.encode_path(auth_server_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/authorizationServers/{authServerId}/claims",
+                &[("authServerId", auth_server_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -172,10 +231,19 @@ impl AuthorizationServers {
     ) -> Result<Vec<crate::types::OAuth2Claim>> {
         let url = format!(
             "/api/v1/authorizationServers/{}/claims",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
+            self.client.encode_path(auth_server_id),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/api/v1/authorizationServers/{authServerId}/claims",
+                    &[("authServerId", auth_server_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -190,16 +258,23 @@ impl AuthorizationServers {
     pub async fn create_o_auth_2_claim(
         &self,
         auth_server_id: &str,
-        body: &crate::types::OAuth2Claim,
+        body: &crate::types::OAuth2ClaimRequest,
     ) -> Result<crate::types::OAuth2Claim> {
         let url = format!(
             "/api/v1/authorizationServers/{}/claims",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
+            self.client.encode_path(auth_server_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/api/v1/authorizationServers/{authServerId}/claims",
+                    &[("authServerId", auth_server_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -219,11 +294,20 @@ impl AuthorizationServers {
     ) -> Result<crate::types::OAuth2Claim> {
         let url = format!(
             "/api/v1/authorizationServers/{}/claims/{}",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
-            crate::progenitor_support::encode_path(&claim_id.to_string()),
+            self.client.encode_path(auth_server_id),
+            self.client.encode_path(claim_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/authorizationServers/{authServerId}/claims/{claimId}",
+                &[
+                    ("authServerId", auth_server_id.to_string()),
+                    ("claimId", claim_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -240,17 +324,27 @@ impl AuthorizationServers {
         &self,
         auth_server_id: &str,
         claim_id: &str,
-        body: &crate::types::OAuth2Claim,
+        body: &crate::types::OAuth2ClaimRequest,
     ) -> Result<crate::types::OAuth2Claim> {
         let url = format!(
             "/api/v1/authorizationServers/{}/claims/{}",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
-            crate::progenitor_support::encode_path(&claim_id.to_string()),
+            self.client.encode_path(auth_server_id),
+            self.client.encode_path(claim_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/api/v1/authorizationServers/{authServerId}/claims/{claimId}",
+                    &[
+                        ("authServerId", auth_server_id.to_string()),
+                        ("claimId", claim_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -266,11 +360,51 @@ impl AuthorizationServers {
     pub async fn delete_o_auth_2_claim(&self, auth_server_id: &str, claim_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/authorizationServers/{}/claims/{}",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
-            crate::progenitor_support::encode_path(&claim_id.to_string()),
+            self.client.encode_path(auth_server_id),
+            self.client.encode_path(claim_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/authorizationServers/{authServerId}/claims/{claimId}",
+                &[
+                    ("authServerId", auth_server_id.to_string()),
+                    ("claimId", claim_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/api/v1/authorizationServers/{authServerId}/claims/{claimId}` endpoint.
+     *
+     * As opposed to `delete_o_auth_2_claim`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_o_auth_2_claim_if_exists(
+        &self,
+        auth_server_id: &str,
+        claim_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/api/v1/authorizationServers/{}/claims/{}",
+            self.client.encode_path(auth_server_id),
+            self.client.encode_path(claim_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/authorizationServers/{authServerId}/claims/{claimId}",
+                    &[
+                        ("authServerId", auth_server_id.to_string()),
+                        ("claimId", claim_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -288,10 +422,16 @@ impl AuthorizationServers {
     ) -> Result<Vec<crate::types::OAuth2Client>> {
         let url = format!(
             "/api/v1/authorizationServers/{}/clients",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
+            self.client.encode_path(auth_server_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/authorizationServers/{authServerId}/clients",
+                &[("authServerId", auth_server_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -307,10 +447,19 @@ impl AuthorizationServers {
     ) -> Result<Vec<crate::types::OAuth2Client>> {
         let url = format!(
             "/api/v1/authorizationServers/{}/clients",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
+            self.client.encode_path(auth_server_id),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/api/v1/authorizationServers/{authServerId}/clients",
+                    &[("authServerId", auth_server_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -334,25 +483,35 @@ impl AuthorizationServers {
         after: &str,
         limit: i64,
     ) -> Result<Vec<crate::types::OAuth2RefreshToken>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/authorizationServers/{}/clients/{}/tokens?{}",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
-            crate::progenitor_support::encode_path(&client_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/api/v1/authorizationServers/{}/clients/{}/tokens",
+                self.client.encode_path(auth_server_id),
+                self.client.encode_path(client_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/authorizationServers/{authServerId}/clients/{clientId}/tokens",
+                &[
+                    ("authServerId", auth_server_id.to_string()),
+                    ("clientId", client_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -368,19 +527,32 @@ impl AuthorizationServers {
         client_id: &str,
         expand: &str,
     ) -> Result<Vec<crate::types::OAuth2RefreshToken>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/authorizationServers/{}/clients/{}/tokens?{}",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
-            crate::progenitor_support::encode_path(&client_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/api/v1/authorizationServers/{}/clients/{}/tokens",
+                self.client.encode_path(auth_server_id),
+                self.client.encode_path(client_id)
+            ),
+            &query_args,
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/api/v1/authorizationServers/{authServerId}/clients/{clientId}/tokens",
+                    &[
+                        ("authServerId", auth_server_id.to_string()),
+                        ("clientId", client_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -400,11 +572,51 @@ impl AuthorizationServers {
     ) -> Result<()> {
         let url = format!(
             "/api/v1/authorizationServers/{}/clients/{}/tokens",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
-            crate::progenitor_support::encode_path(&client_id.to_string()),
+            self.client.encode_path(auth_server_id),
+            self.client.encode_path(client_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/authorizationServers/{authServerId}/clients/{clientId}/tokens",
+                &[
+                    ("authServerId", auth_server_id.to_string()),
+                    ("clientId", client_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/api/v1/authorizationServers/{authServerId}/clients/{clientId}/tokens` endpoint.
+     *
+     * As opposed to `revoke_refresh_tokens_for_and_client`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn revoke_refresh_tokens_for_and_client_if_exists(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/api/v1/authorizationServers/{}/clients/{}/tokens",
+            self.client.encode_path(auth_server_id),
+            self.client.encode_path(client_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/authorizationServers/{authServerId}/clients/{clientId}/tokens",
+                    &[
+                        ("authServerId", auth_server_id.to_string()),
+                        ("clientId", client_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -426,20 +638,31 @@ impl AuthorizationServers {
         token_id: &str,
         expand: &str,
     ) -> Result<crate::types::OAuth2RefreshToken> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/authorizationServers/{}/clients/{}/tokens/{}?{}",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
-            crate::progenitor_support::encode_path(&client_id.to_string()),
-            crate::progenitor_support::encode_path(&token_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/api/v1/authorizationServers/{}/clients/{}/tokens/{}",
+                self.client.encode_path(auth_server_id),
+                self.client.encode_path(client_id),
+                self.client.encode_path(token_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/authorizationServers/{authServerId}/clients/{clientId}/tokens/{tokenId}",
+                &[
+                    ("authServerId", auth_server_id.to_string()),
+                    ("clientId", client_id.to_string()),
+                    ("tokenId", token_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -461,12 +684,43 @@ impl AuthorizationServers {
     ) -> Result<()> {
         let url = format!(
             "/api/v1/authorizationServers/{}/clients/{}/tokens/{}",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
-            crate::progenitor_support::encode_path(&client_id.to_string()),
-            crate::progenitor_support::encode_path(&token_id.to_string()),
+            self.client.encode_path(auth_server_id),
+            self.client.encode_path(client_id),
+            self.client.encode_path(token_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/authorizationServers/{authServerId}/clients/{clientId}/tokens/{tokenId}",
+                &[
+                    ("authServerId", auth_server_id.to_string()),
+                    ("clientId", client_id.to_string()),
+                    ("tokenId", token_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/api/v1/authorizationServers/{authServerId}/clients/{clientId}/tokens/{tokenId}` endpoint.
+     *
+     * As opposed to `revoke_refresh_token_for_and_client`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn revoke_refresh_token_for_and_client_if_exists(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+        token_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/api/v1/authorizationServers/{}/clients/{}/tokens/{}",
+            self.client.encode_path(auth_server_id),
+            self.client.encode_path(client_id),
+            self.client.encode_path(token_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete_if_exists(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/api/v1/authorizationServers/{authServerId}/clients/{clientId}/tokens/{tokenId}", &[("authServerId", auth_server_id.to_string()), ("clientId", client_id.to_string()), ("tokenId", token_id.to_string())]))
     }
 
     /**
@@ -481,10 +735,16 @@ impl AuthorizationServers {
     pub async fn list_keys(&self, auth_server_id: &str) -> Result<Vec<crate::types::JsonWebKey>> {
         let url = format!(
             "/api/v1/authorizationServers/{}/credentials/keys",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
+            self.client.encode_path(auth_server_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/authorizationServers/{authServerId}/credentials/keys",
+                &[("authServerId", auth_server_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -500,10 +760,19 @@ impl AuthorizationServers {
     ) -> Result<Vec<crate::types::JsonWebKey>> {
         let url = format!(
             "/api/v1/authorizationServers/{}/credentials/keys",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
+            self.client.encode_path(auth_server_id),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/api/v1/authorizationServers/{authServerId}/credentials/keys",
+                    &[("authServerId", auth_server_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -522,12 +791,19 @@ impl AuthorizationServers {
     ) -> Result<Vec<crate::types::JsonWebKey>> {
         let url = format!(
             "/api/v1/authorizationServers/{}/credentials/lifecycle/keyRotate",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
+            self.client.encode_path(auth_server_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/api/v1/authorizationServers/{authServerId}/credentials/lifecycle/keyRotate",
+                    &[("authServerId", auth_server_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -542,10 +818,16 @@ impl AuthorizationServers {
     pub async fn activate(&self, auth_server_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/authorizationServers/{}/lifecycle/activate",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
+            self.client.encode_path(auth_server_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/api/v1/authorizationServers/{authServerId}/lifecycle/activate",
+                &[("authServerId", auth_server_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -560,10 +842,16 @@ impl AuthorizationServers {
     pub async fn deactivate(&self, auth_server_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/authorizationServers/{}/lifecycle/deactivate",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
+            self.client.encode_path(auth_server_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/api/v1/authorizationServers/{authServerId}/lifecycle/deactivate",
+                &[("authServerId", auth_server_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -578,10 +866,16 @@ impl AuthorizationServers {
     pub async fn list_policies(&self, auth_server_id: &str) -> Result<Vec<crate::types::Policy>> {
         let url = format!(
             "/api/v1/authorizationServers/{}/policies",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
+            self.client.encode_path(auth_server_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/authorizationServers/{authServerId}/policies",
+                &[("authServerId", auth_server_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -597,10 +891,19 @@ impl AuthorizationServers {
     ) -> Result<Vec<crate::types::Policy>> {
         let url = format!(
             "/api/v1/authorizationServers/{}/policies",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
+            self.client.encode_path(auth_server_id),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/api/v1/authorizationServers/{authServerId}/policies",
+                    &[("authServerId", auth_server_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -615,16 +918,23 @@ impl AuthorizationServers {
     pub async fn create_policy(
         &self,
         auth_server_id: &str,
-        body: &crate::types::Policy,
+        body: &crate::types::PolicyRequest,
     ) -> Result<crate::types::Policy> {
         let url = format!(
             "/api/v1/authorizationServers/{}/policies",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
+            self.client.encode_path(auth_server_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/api/v1/authorizationServers/{authServerId}/policies",
+                    &[("authServerId", auth_server_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -644,11 +954,20 @@ impl AuthorizationServers {
     ) -> Result<crate::types::Policy> {
         let url = format!(
             "/api/v1/authorizationServers/{}/policies/{}",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
-            crate::progenitor_support::encode_path(&policy_id.to_string()),
+            self.client.encode_path(auth_server_id),
+            self.client.encode_path(policy_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/authorizationServers/{authServerId}/policies/{policyId}",
+                &[
+                    ("authServerId", auth_server_id.to_string()),
+                    ("policyId", policy_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -665,17 +984,27 @@ impl AuthorizationServers {
         &self,
         auth_server_id: &str,
         policy_id: &str,
-        body: &crate::types::Policy,
+        body: &crate::types::PolicyRequest,
     ) -> Result<crate::types::Policy> {
         let url = format!(
             "/api/v1/authorizationServers/{}/policies/{}",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
-            crate::progenitor_support::encode_path(&policy_id.to_string()),
+            self.client.encode_path(auth_server_id),
+            self.client.encode_path(policy_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/api/v1/authorizationServers/{authServerId}/policies/{policyId}",
+                    &[
+                        ("authServerId", auth_server_id.to_string()),
+                        ("policyId", policy_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -691,11 +1020,51 @@ impl AuthorizationServers {
     pub async fn delete_policy(&self, auth_server_id: &str, policy_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/authorizationServers/{}/policies/{}",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
-            crate::progenitor_support::encode_path(&policy_id.to_string()),
+            self.client.encode_path(auth_server_id),
+            self.client.encode_path(policy_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/authorizationServers/{authServerId}/policies/{policyId}",
+                &[
+                    ("authServerId", auth_server_id.to_string()),
+                    ("policyId", policy_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/api/v1/authorizationServers/{authServerId}/policies/{policyId}` endpoint.
+     *
+     * As opposed to `delete_policy`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_policy_if_exists(
+        &self,
+        auth_server_id: &str,
+        policy_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/api/v1/authorizationServers/{}/policies/{}",
+            self.client.encode_path(auth_server_id),
+            self.client.encode_path(policy_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/authorizationServers/{authServerId}/policies/{policyId}",
+                    &[
+                        ("authServerId", auth_server_id.to_string()),
+                        ("policyId", policy_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -711,11 +1080,11 @@ impl AuthorizationServers {
     pub async fn activate_policy(&self, auth_server_id: &str, policy_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/authorizationServers/{}/policies/{}/lifecycle/activate",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
-            crate::progenitor_support::encode_path(&policy_id.to_string()),
+            self.client.encode_path(auth_server_id),
+            self.client.encode_path(policy_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| crate::utils::pretty_url("POST", "/api/v1/authorizationServers/{authServerId}/policies/{policyId}/lifecycle/activate", &[("authServerId", auth_server_id.to_string()), ("policyId", policy_id.to_string())]))
     }
 
     /**
@@ -731,11 +1100,11 @@ impl AuthorizationServers {
     pub async fn deactivate_policy(&self, auth_server_id: &str, policy_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/authorizationServers/{}/policies/{}/lifecycle/deactivate",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
-            crate::progenitor_support::encode_path(&policy_id.to_string()),
+            self.client.encode_path(auth_server_id),
+            self.client.encode_path(policy_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| crate::utils::pretty_url("POST", "/api/v1/authorizationServers/{authServerId}/policies/{policyId}/lifecycle/deactivate", &[("authServerId", auth_server_id.to_string()), ("policyId", policy_id.to_string())]))
     }
 
     /**
@@ -755,11 +1124,20 @@ impl AuthorizationServers {
     ) -> Result<Vec<crate::types::AuthorizationServerPolicyRule>> {
         let url = format!(
             "/api/v1/authorizationServers/{}/policies/{}/rules",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
-            crate::progenitor_support::encode_path(&policy_id.to_string()),
+            self.client.encode_path(auth_server_id),
+            self.client.encode_path(policy_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/authorizationServers/{authServerId}/policies/{policyId}/rules",
+                &[
+                    ("authServerId", auth_server_id.to_string()),
+                    ("policyId", policy_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -776,11 +1154,23 @@ impl AuthorizationServers {
     ) -> Result<Vec<crate::types::AuthorizationServerPolicyRule>> {
         let url = format!(
             "/api/v1/authorizationServers/{}/policies/{}/rules",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
-            crate::progenitor_support::encode_path(&policy_id.to_string()),
+            self.client.encode_path(auth_server_id),
+            self.client.encode_path(policy_id),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/api/v1/authorizationServers/{authServerId}/policies/{policyId}/rules",
+                    &[
+                        ("authServerId", auth_server_id.to_string()),
+                        ("policyId", policy_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -797,17 +1187,27 @@ impl AuthorizationServers {
         &self,
         policy_id: &str,
         auth_server_id: &str,
-        body: &crate::types::AuthorizationServerPolicyRule,
+        body: &crate::types::AuthorizationServerPolicyRuleRequest,
     ) -> Result<crate::types::AuthorizationServerPolicyRule> {
         let url = format!(
             "/api/v1/authorizationServers/{}/policies/{}/rules",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
-            crate::progenitor_support::encode_path(&policy_id.to_string()),
+            self.client.encode_path(auth_server_id),
+            self.client.encode_path(policy_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/api/v1/authorizationServers/{authServerId}/policies/{policyId}/rules",
+                    &[
+                        ("authServerId", auth_server_id.to_string()),
+                        ("policyId", policy_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -829,12 +1229,22 @@ impl AuthorizationServers {
     ) -> Result<crate::types::AuthorizationServerPolicyRule> {
         let url = format!(
             "/api/v1/authorizationServers/{}/policies/{}/rules/{}",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
-            crate::progenitor_support::encode_path(&policy_id.to_string()),
-            crate::progenitor_support::encode_path(&rule_id.to_string()),
+            self.client.encode_path(auth_server_id),
+            self.client.encode_path(policy_id),
+            self.client.encode_path(rule_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/authorizationServers/{authServerId}/policies/{policyId}/rules/{ruleId}",
+                &[
+                    ("authServerId", auth_server_id.to_string()),
+                    ("policyId", policy_id.to_string()),
+                    ("ruleId", rule_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -853,18 +1263,18 @@ impl AuthorizationServers {
         policy_id: &str,
         auth_server_id: &str,
         rule_id: &str,
-        body: &crate::types::AuthorizationServerPolicyRule,
+        body: &crate::types::AuthorizationServerPolicyRuleRequest,
     ) -> Result<crate::types::AuthorizationServerPolicyRule> {
         let url = format!(
             "/api/v1/authorizationServers/{}/policies/{}/rules/{}",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
-            crate::progenitor_support::encode_path(&policy_id.to_string()),
-            crate::progenitor_support::encode_path(&rule_id.to_string()),
+            self.client.encode_path(auth_server_id),
+            self.client.encode_path(policy_id),
+            self.client.encode_path(rule_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("PUT", "/api/v1/authorizationServers/{authServerId}/policies/{policyId}/rules/{ruleId}", &[("authServerId", auth_server_id.to_string()), ("policyId", policy_id.to_string()), ("ruleId", rule_id.to_string())]))
     }
 
     /**
@@ -886,12 +1296,43 @@ impl AuthorizationServers {
     ) -> Result<()> {
         let url = format!(
             "/api/v1/authorizationServers/{}/policies/{}/rules/{}",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
-            crate::progenitor_support::encode_path(&policy_id.to_string()),
-            crate::progenitor_support::encode_path(&rule_id.to_string()),
+            self.client.encode_path(auth_server_id),
+            self.client.encode_path(policy_id),
+            self.client.encode_path(rule_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/authorizationServers/{authServerId}/policies/{policyId}/rules/{ruleId}",
+                &[
+                    ("authServerId", auth_server_id.to_string()),
+                    ("policyId", policy_id.to_string()),
+                    ("ruleId", rule_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/api/v1/authorizationServers/{authServerId}/policies/{policyId}/rules/{ruleId}` endpoint.
+     *
+     * As opposed to `delete_policy_rule`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_policy_rule_if_exists(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+        rule_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/api/v1/authorizationServers/{}/policies/{}/rules/{}",
+            self.client.encode_path(auth_server_id),
+            self.client.encode_path(policy_id),
+            self.client.encode_path(rule_id),
+        );
+
+        self.client.delete_if_exists(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/api/v1/authorizationServers/{authServerId}/policies/{policyId}/rules/{ruleId}", &[("authServerId", auth_server_id.to_string()), ("policyId", policy_id.to_string()), ("ruleId", rule_id.to_string())]))
     }
 
     /**
@@ -913,12 +1354,12 @@ impl AuthorizationServers {
     ) -> Result<()> {
         let url = format!(
             "/api/v1/authorizationServers/{}/policies/{}/rules/{}/lifecycle/activate",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
-            crate::progenitor_support::encode_path(&policy_id.to_string()),
-            crate::progenitor_support::encode_path(&rule_id.to_string()),
+            self.client.encode_path(auth_server_id),
+            self.client.encode_path(policy_id),
+            self.client.encode_path(rule_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| crate::utils::pretty_url("POST", "/api/v1/authorizationServers/{authServerId}/policies/{policyId}/rules/{ruleId}/lifecycle/activate", &[("authServerId", auth_server_id.to_string()), ("policyId", policy_id.to_string()), ("ruleId", rule_id.to_string())]))
     }
 
     /**
@@ -940,12 +1381,12 @@ impl AuthorizationServers {
     ) -> Result<()> {
         let url = format!(
             "/api/v1/authorizationServers/{}/policies/{}/rules/{}/lifecycle/deactivate",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
-            crate::progenitor_support::encode_path(&policy_id.to_string()),
-            crate::progenitor_support::encode_path(&rule_id.to_string()),
+            self.client.encode_path(auth_server_id),
+            self.client.encode_path(policy_id),
+            self.client.encode_path(rule_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| crate::utils::pretty_url("POST", "/api/v1/authorizationServers/{authServerId}/policies/{policyId}/rules/{ruleId}/lifecycle/deactivate", &[("authServerId", auth_server_id.to_string()), ("policyId", policy_id.to_string()), ("ruleId", rule_id.to_string())]))
     }
 
     /**
@@ -969,27 +1410,34 @@ impl AuthorizationServers {
         cursor: &str,
         limit: i64,
     ) -> Result<Vec<crate::types::OAuth2Scope>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/authorizationServers/{}/scopes?{}",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/api/v1/authorizationServers/{}/scopes",
+                self.client.encode_path(auth_server_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/authorizationServers/{authServerId}/scopes",
+                &[("authServerId", auth_server_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1006,24 +1454,34 @@ impl AuthorizationServers {
         filter: &str,
         cursor: &str,
     ) -> Result<Vec<crate::types::OAuth2Scope>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/authorizationServers/{}/scopes?{}",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/api/v1/authorizationServers/{}/scopes",
+                self.client.encode_path(auth_server_id)
+            ),
+            &query_args,
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/api/v1/authorizationServers/{authServerId}/scopes",
+                    &[("authServerId", auth_server_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1038,16 +1496,23 @@ impl AuthorizationServers {
     pub async fn create_o_auth_2_scope(
         &self,
         auth_server_id: &str,
-        body: &crate::types::OAuth2Scope,
+        body: &crate::types::OAuth2ScopeRequest,
     ) -> Result<crate::types::OAuth2Scope> {
         let url = format!(
             "/api/v1/authorizationServers/{}/scopes",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
+            self.client.encode_path(auth_server_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/api/v1/authorizationServers/{authServerId}/scopes",
+                    &[("authServerId", auth_server_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1067,11 +1532,20 @@ impl AuthorizationServers {
     ) -> Result<crate::types::OAuth2Scope> {
         let url = format!(
             "/api/v1/authorizationServers/{}/scopes/{}",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
-            crate::progenitor_support::encode_path(&scope_id.to_string()),
+            self.client.encode_path(auth_server_id),
+            self.client.encode_path(scope_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/authorizationServers/{authServerId}/scopes/{scopeId}",
+                &[
+                    ("authServerId", auth_server_id.to_string()),
+                    ("scopeId", scope_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1088,17 +1562,27 @@ impl AuthorizationServers {
         &self,
         auth_server_id: &str,
         scope_id: &str,
-        body: &crate::types::OAuth2Scope,
+        body: &crate::types::OAuth2ScopeRequest,
     ) -> Result<crate::types::OAuth2Scope> {
         let url = format!(
             "/api/v1/authorizationServers/{}/scopes/{}",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
-            crate::progenitor_support::encode_path(&scope_id.to_string()),
+            self.client.encode_path(auth_server_id),
+            self.client.encode_path(scope_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/api/v1/authorizationServers/{authServerId}/scopes/{scopeId}",
+                    &[
+                        ("authServerId", auth_server_id.to_string()),
+                        ("scopeId", scope_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1114,10 +1598,654 @@ impl AuthorizationServers {
     pub async fn delete_o_auth_2_scope(&self, auth_server_id: &str, scope_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/authorizationServers/{}/scopes/{}",
-            crate::progenitor_support::encode_path(&auth_server_id.to_string()),
-            crate::progenitor_support::encode_path(&scope_id.to_string()),
+            self.client.encode_path(auth_server_id),
+            self.client.encode_path(scope_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/authorizationServers/{authServerId}/scopes/{scopeId}",
+                &[
+                    ("authServerId", auth_server_id.to_string()),
+                    ("scopeId", scope_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/api/v1/authorizationServers/{authServerId}/scopes/{scopeId}` endpoint.
+     *
+     * As opposed to `delete_o_auth_2_scope`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_o_auth_2_scope_if_exists(
+        &self,
+        auth_server_id: &str,
+        scope_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/api/v1/authorizationServers/{}/scopes/{}",
+            self.client.encode_path(auth_server_id),
+            self.client.encode_path(scope_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/authorizationServers/{authServerId}/scopes/{scopeId}",
+                    &[
+                        ("authServerId", auth_server_id.to_string()),
+                        ("scopeId", scope_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`AuthorizationServers`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AuthorizationServersApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AuthorizationServersApi: Send + Sync {
+    async fn list(
+        &self,
+        q: &str,
+        limit: &str,
+        after: &str,
+    ) -> Result<Vec<crate::types::AuthorizationServer>>;
+    async fn list_all(&self, q: &str) -> Result<Vec<crate::types::AuthorizationServer>>;
+    async fn create(
+        &self,
+        body: &crate::types::AuthorizationServerRequest,
+    ) -> Result<crate::types::AuthorizationServer>;
+    async fn get(&self, auth_server_id: &str) -> Result<crate::types::AuthorizationServer>;
+    async fn update(
+        &self,
+        auth_server_id: &str,
+        body: &crate::types::AuthorizationServerRequest,
+    ) -> Result<crate::types::AuthorizationServer>;
+    async fn delete(&self, auth_server_id: &str) -> Result<()>;
+    async fn delete_if_exists(&self, auth_server_id: &str) -> Result<bool>;
+    async fn list_o_auth_2_claims(
+        &self,
+        auth_server_id: &str,
+    ) -> Result<Vec<crate::types::OAuth2Claim>>;
+    async fn list_all_o_auth_2_claims(
+        &self,
+        auth_server_id: &str,
+    ) -> Result<Vec<crate::types::OAuth2Claim>>;
+    async fn create_o_auth_2_claim(
+        &self,
+        auth_server_id: &str,
+        body: &crate::types::OAuth2ClaimRequest,
+    ) -> Result<crate::types::OAuth2Claim>;
+    async fn get_o_auth_2_claim(
+        &self,
+        auth_server_id: &str,
+        claim_id: &str,
+    ) -> Result<crate::types::OAuth2Claim>;
+    async fn update_o_auth_2_claim(
+        &self,
+        auth_server_id: &str,
+        claim_id: &str,
+        body: &crate::types::OAuth2ClaimRequest,
+    ) -> Result<crate::types::OAuth2Claim>;
+    async fn delete_o_auth_2_claim(&self, auth_server_id: &str, claim_id: &str) -> Result<()>;
+    async fn delete_o_auth_2_claim_if_exists(
+        &self,
+        auth_server_id: &str,
+        claim_id: &str,
+    ) -> Result<bool>;
+    async fn list_o_auth_2_clients_fors(
+        &self,
+        auth_server_id: &str,
+    ) -> Result<Vec<crate::types::OAuth2Client>>;
+    async fn list_all_o_auth_2_clients_fors(
+        &self,
+        auth_server_id: &str,
+    ) -> Result<Vec<crate::types::OAuth2Client>>;
+    async fn list_refresh_tokens_for_and_clients(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+        expand: &str,
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::OAuth2RefreshToken>>;
+    async fn list_all_refresh_tokens_for_and_clients(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::OAuth2RefreshToken>>;
+    async fn revoke_refresh_tokens_for_and_client(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+    ) -> Result<()>;
+    async fn revoke_refresh_tokens_for_and_client_if_exists(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+    ) -> Result<bool>;
+    async fn get_refresh_token_for_and_client(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+        token_id: &str,
+        expand: &str,
+    ) -> Result<crate::types::OAuth2RefreshToken>;
+    async fn revoke_refresh_token_for_and_client(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+        token_id: &str,
+    ) -> Result<()>;
+    async fn revoke_refresh_token_for_and_client_if_exists(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+        token_id: &str,
+    ) -> Result<bool>;
+    async fn list_keys(&self, auth_server_id: &str) -> Result<Vec<crate::types::JsonWebKey>>;
+    async fn list_all_keys(&self, auth_server_id: &str) -> Result<Vec<crate::types::JsonWebKey>>;
+    async fn rotate_keys(
+        &self,
+        auth_server_id: &str,
+        body: &crate::types::JwkUse,
+    ) -> Result<Vec<crate::types::JsonWebKey>>;
+    async fn activate(&self, auth_server_id: &str) -> Result<()>;
+    async fn deactivate(&self, auth_server_id: &str) -> Result<()>;
+    async fn list_policies(&self, auth_server_id: &str) -> Result<Vec<crate::types::Policy>>;
+    async fn list_all_policies(&self, auth_server_id: &str) -> Result<Vec<crate::types::Policy>>;
+    async fn create_policy(
+        &self,
+        auth_server_id: &str,
+        body: &crate::types::PolicyRequest,
+    ) -> Result<crate::types::Policy>;
+    async fn get_policy(
+        &self,
+        auth_server_id: &str,
+        policy_id: &str,
+    ) -> Result<crate::types::Policy>;
+    async fn update_policy(
+        &self,
+        auth_server_id: &str,
+        policy_id: &str,
+        body: &crate::types::PolicyRequest,
+    ) -> Result<crate::types::Policy>;
+    async fn delete_policy(&self, auth_server_id: &str, policy_id: &str) -> Result<()>;
+    async fn delete_policy_if_exists(&self, auth_server_id: &str, policy_id: &str) -> Result<bool>;
+    async fn activate_policy(&self, auth_server_id: &str, policy_id: &str) -> Result<()>;
+    async fn deactivate_policy(&self, auth_server_id: &str, policy_id: &str) -> Result<()>;
+    async fn list_policy_rules(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+    ) -> Result<Vec<crate::types::AuthorizationServerPolicyRule>>;
+    async fn list_all_policy_rules(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+    ) -> Result<Vec<crate::types::AuthorizationServerPolicyRule>>;
+    async fn create_policy_rule(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+        body: &crate::types::AuthorizationServerPolicyRuleRequest,
+    ) -> Result<crate::types::AuthorizationServerPolicyRule>;
+    async fn get_policy_rule(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+        rule_id: &str,
+    ) -> Result<crate::types::AuthorizationServerPolicyRule>;
+    async fn update_policy_rule(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+        rule_id: &str,
+        body: &crate::types::AuthorizationServerPolicyRuleRequest,
+    ) -> Result<crate::types::AuthorizationServerPolicyRule>;
+    async fn delete_policy_rule(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+        rule_id: &str,
+    ) -> Result<()>;
+    async fn delete_policy_rule_if_exists(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+        rule_id: &str,
+    ) -> Result<bool>;
+    async fn activate_policy_rule(
+        &self,
+        auth_server_id: &str,
+        policy_id: &str,
+        rule_id: &str,
+    ) -> Result<()>;
+    async fn deactivate_policy_rule(
+        &self,
+        auth_server_id: &str,
+        policy_id: &str,
+        rule_id: &str,
+    ) -> Result<()>;
+    async fn list_o_auth_2_scopes(
+        &self,
+        auth_server_id: &str,
+        q: &str,
+        filter: &str,
+        cursor: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::OAuth2Scope>>;
+    async fn list_all_o_auth_2_scopes(
+        &self,
+        auth_server_id: &str,
+        q: &str,
+        filter: &str,
+        cursor: &str,
+    ) -> Result<Vec<crate::types::OAuth2Scope>>;
+    async fn create_o_auth_2_scope(
+        &self,
+        auth_server_id: &str,
+        body: &crate::types::OAuth2ScopeRequest,
+    ) -> Result<crate::types::OAuth2Scope>;
+    async fn get_o_auth_2_scope(
+        &self,
+        auth_server_id: &str,
+        scope_id: &str,
+    ) -> Result<crate::types::OAuth2Scope>;
+    async fn update_o_auth_2_scope(
+        &self,
+        auth_server_id: &str,
+        scope_id: &str,
+        body: &crate::types::OAuth2ScopeRequest,
+    ) -> Result<crate::types::OAuth2Scope>;
+    async fn delete_o_auth_2_scope(&self, auth_server_id: &str, scope_id: &str) -> Result<()>;
+    async fn delete_o_auth_2_scope_if_exists(
+        &self,
+        auth_server_id: &str,
+        scope_id: &str,
+    ) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AuthorizationServersApi for AuthorizationServers {
+    async fn list(
+        &self,
+        q: &str,
+        limit: &str,
+        after: &str,
+    ) -> Result<Vec<crate::types::AuthorizationServer>> {
+        AuthorizationServers::list(self, q, limit, after).await
+    }
+    async fn list_all(&self, q: &str) -> Result<Vec<crate::types::AuthorizationServer>> {
+        AuthorizationServers::list_all(self, q).await
+    }
+    async fn create(
+        &self,
+        body: &crate::types::AuthorizationServerRequest,
+    ) -> Result<crate::types::AuthorizationServer> {
+        AuthorizationServers::create(self, body).await
+    }
+    async fn get(&self, auth_server_id: &str) -> Result<crate::types::AuthorizationServer> {
+        AuthorizationServers::get(self, auth_server_id).await
+    }
+    async fn update(
+        &self,
+        auth_server_id: &str,
+        body: &crate::types::AuthorizationServerRequest,
+    ) -> Result<crate::types::AuthorizationServer> {
+        AuthorizationServers::update(self, auth_server_id, body).await
+    }
+    async fn delete(&self, auth_server_id: &str) -> Result<()> {
+        AuthorizationServers::delete(self, auth_server_id).await
+    }
+    async fn delete_if_exists(&self, auth_server_id: &str) -> Result<bool> {
+        AuthorizationServers::delete_if_exists(self, auth_server_id).await
+    }
+    async fn list_o_auth_2_claims(
+        &self,
+        auth_server_id: &str,
+    ) -> Result<Vec<crate::types::OAuth2Claim>> {
+        AuthorizationServers::list_o_auth_2_claims(self, auth_server_id).await
+    }
+    async fn list_all_o_auth_2_claims(
+        &self,
+        auth_server_id: &str,
+    ) -> Result<Vec<crate::types::OAuth2Claim>> {
+        AuthorizationServers::list_all_o_auth_2_claims(self, auth_server_id).await
+    }
+    async fn create_o_auth_2_claim(
+        &self,
+        auth_server_id: &str,
+        body: &crate::types::OAuth2ClaimRequest,
+    ) -> Result<crate::types::OAuth2Claim> {
+        AuthorizationServers::create_o_auth_2_claim(self, auth_server_id, body).await
+    }
+    async fn get_o_auth_2_claim(
+        &self,
+        auth_server_id: &str,
+        claim_id: &str,
+    ) -> Result<crate::types::OAuth2Claim> {
+        AuthorizationServers::get_o_auth_2_claim(self, auth_server_id, claim_id).await
+    }
+    async fn update_o_auth_2_claim(
+        &self,
+        auth_server_id: &str,
+        claim_id: &str,
+        body: &crate::types::OAuth2ClaimRequest,
+    ) -> Result<crate::types::OAuth2Claim> {
+        AuthorizationServers::update_o_auth_2_claim(self, auth_server_id, claim_id, body).await
+    }
+    async fn delete_o_auth_2_claim(&self, auth_server_id: &str, claim_id: &str) -> Result<()> {
+        AuthorizationServers::delete_o_auth_2_claim(self, auth_server_id, claim_id).await
+    }
+    async fn delete_o_auth_2_claim_if_exists(
+        &self,
+        auth_server_id: &str,
+        claim_id: &str,
+    ) -> Result<bool> {
+        AuthorizationServers::delete_o_auth_2_claim_if_exists(self, auth_server_id, claim_id).await
+    }
+    async fn list_o_auth_2_clients_fors(
+        &self,
+        auth_server_id: &str,
+    ) -> Result<Vec<crate::types::OAuth2Client>> {
+        AuthorizationServers::list_o_auth_2_clients_fors(self, auth_server_id).await
+    }
+    async fn list_all_o_auth_2_clients_fors(
+        &self,
+        auth_server_id: &str,
+    ) -> Result<Vec<crate::types::OAuth2Client>> {
+        AuthorizationServers::list_all_o_auth_2_clients_fors(self, auth_server_id).await
+    }
+    async fn list_refresh_tokens_for_and_clients(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+        expand: &str,
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::OAuth2RefreshToken>> {
+        AuthorizationServers::list_refresh_tokens_for_and_clients(
+            self,
+            auth_server_id,
+            client_id,
+            expand,
+            after,
+            limit,
+        )
+        .await
+    }
+    async fn list_all_refresh_tokens_for_and_clients(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::OAuth2RefreshToken>> {
+        AuthorizationServers::list_all_refresh_tokens_for_and_clients(
+            self,
+            auth_server_id,
+            client_id,
+            expand,
+        )
+        .await
+    }
+    async fn revoke_refresh_tokens_for_and_client(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+    ) -> Result<()> {
+        AuthorizationServers::revoke_refresh_tokens_for_and_client(self, auth_server_id, client_id)
+            .await
+    }
+    async fn revoke_refresh_tokens_for_and_client_if_exists(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+    ) -> Result<bool> {
+        AuthorizationServers::revoke_refresh_tokens_for_and_client_if_exists(
+            self,
+            auth_server_id,
+            client_id,
+        )
+        .await
+    }
+    async fn get_refresh_token_for_and_client(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+        token_id: &str,
+        expand: &str,
+    ) -> Result<crate::types::OAuth2RefreshToken> {
+        AuthorizationServers::get_refresh_token_for_and_client(
+            self,
+            auth_server_id,
+            client_id,
+            token_id,
+            expand,
+        )
+        .await
+    }
+    async fn revoke_refresh_token_for_and_client(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+        token_id: &str,
+    ) -> Result<()> {
+        AuthorizationServers::revoke_refresh_token_for_and_client(
+            self,
+            auth_server_id,
+            client_id,
+            token_id,
+        )
+        .await
+    }
+    async fn revoke_refresh_token_for_and_client_if_exists(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+        token_id: &str,
+    ) -> Result<bool> {
+        AuthorizationServers::revoke_refresh_token_for_and_client_if_exists(
+            self,
+            auth_server_id,
+            client_id,
+            token_id,
+        )
+        .await
+    }
+    async fn list_keys(&self, auth_server_id: &str) -> Result<Vec<crate::types::JsonWebKey>> {
+        AuthorizationServers::list_keys(self, auth_server_id).await
+    }
+    async fn list_all_keys(&self, auth_server_id: &str) -> Result<Vec<crate::types::JsonWebKey>> {
+        AuthorizationServers::list_all_keys(self, auth_server_id).await
+    }
+    async fn rotate_keys(
+        &self,
+        auth_server_id: &str,
+        body: &crate::types::JwkUse,
+    ) -> Result<Vec<crate::types::JsonWebKey>> {
+        AuthorizationServers::rotate_keys(self, auth_server_id, body).await
+    }
+    async fn activate(&self, auth_server_id: &str) -> Result<()> {
+        AuthorizationServers::activate(self, auth_server_id).await
+    }
+    async fn deactivate(&self, auth_server_id: &str) -> Result<()> {
+        AuthorizationServers::deactivate(self, auth_server_id).await
+    }
+    async fn list_policies(&self, auth_server_id: &str) -> Result<Vec<crate::types::Policy>> {
+        AuthorizationServers::list_policies(self, auth_server_id).await
+    }
+    async fn list_all_policies(&self, auth_server_id: &str) -> Result<Vec<crate::types::Policy>> {
+        AuthorizationServers::list_all_policies(self, auth_server_id).await
+    }
+    async fn create_policy(
+        &self,
+        auth_server_id: &str,
+        body: &crate::types::PolicyRequest,
+    ) -> Result<crate::types::Policy> {
+        AuthorizationServers::create_policy(self, auth_server_id, body).await
+    }
+    async fn get_policy(
+        &self,
+        auth_server_id: &str,
+        policy_id: &str,
+    ) -> Result<crate::types::Policy> {
+        AuthorizationServers::get_policy(self, auth_server_id, policy_id).await
+    }
+    async fn update_policy(
+        &self,
+        auth_server_id: &str,
+        policy_id: &str,
+        body: &crate::types::PolicyRequest,
+    ) -> Result<crate::types::Policy> {
+        AuthorizationServers::update_policy(self, auth_server_id, policy_id, body).await
+    }
+    async fn delete_policy(&self, auth_server_id: &str, policy_id: &str) -> Result<()> {
+        AuthorizationServers::delete_policy(self, auth_server_id, policy_id).await
+    }
+    async fn delete_policy_if_exists(&self, auth_server_id: &str, policy_id: &str) -> Result<bool> {
+        AuthorizationServers::delete_policy_if_exists(self, auth_server_id, policy_id).await
+    }
+    async fn activate_policy(&self, auth_server_id: &str, policy_id: &str) -> Result<()> {
+        AuthorizationServers::activate_policy(self, auth_server_id, policy_id).await
+    }
+    async fn deactivate_policy(&self, auth_server_id: &str, policy_id: &str) -> Result<()> {
+        AuthorizationServers::deactivate_policy(self, auth_server_id, policy_id).await
+    }
+    async fn list_policy_rules(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+    ) -> Result<Vec<crate::types::AuthorizationServerPolicyRule>> {
+        AuthorizationServers::list_policy_rules(self, policy_id, auth_server_id).await
+    }
+    async fn list_all_policy_rules(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+    ) -> Result<Vec<crate::types::AuthorizationServerPolicyRule>> {
+        AuthorizationServers::list_all_policy_rules(self, policy_id, auth_server_id).await
+    }
+    async fn create_policy_rule(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+        body: &crate::types::AuthorizationServerPolicyRuleRequest,
+    ) -> Result<crate::types::AuthorizationServerPolicyRule> {
+        AuthorizationServers::create_policy_rule(self, policy_id, auth_server_id, body).await
+    }
+    async fn get_policy_rule(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+        rule_id: &str,
+    ) -> Result<crate::types::AuthorizationServerPolicyRule> {
+        AuthorizationServers::get_policy_rule(self, policy_id, auth_server_id, rule_id).await
+    }
+    async fn update_policy_rule(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+        rule_id: &str,
+        body: &crate::types::AuthorizationServerPolicyRuleRequest,
+    ) -> Result<crate::types::AuthorizationServerPolicyRule> {
+        AuthorizationServers::update_policy_rule(self, policy_id, auth_server_id, rule_id, body)
+            .await
+    }
+    async fn delete_policy_rule(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+        rule_id: &str,
+    ) -> Result<()> {
+        AuthorizationServers::delete_policy_rule(self, policy_id, auth_server_id, rule_id).await
+    }
+    async fn delete_policy_rule_if_exists(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+        rule_id: &str,
+    ) -> Result<bool> {
+        AuthorizationServers::delete_policy_rule_if_exists(self, policy_id, auth_server_id, rule_id)
+            .await
+    }
+    async fn activate_policy_rule(
+        &self,
+        auth_server_id: &str,
+        policy_id: &str,
+        rule_id: &str,
+    ) -> Result<()> {
+        AuthorizationServers::activate_policy_rule(self, auth_server_id, policy_id, rule_id).await
+    }
+    async fn deactivate_policy_rule(
+        &self,
+        auth_server_id: &str,
+        policy_id: &str,
+        rule_id: &str,
+    ) -> Result<()> {
+        AuthorizationServers::deactivate_policy_rule(self, auth_server_id, policy_id, rule_id).await
+    }
+    async fn list_o_auth_2_scopes(
+        &self,
+        auth_server_id: &str,
+        q: &str,
+        filter: &str,
+        cursor: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::OAuth2Scope>> {
+        AuthorizationServers::list_o_auth_2_scopes(self, auth_server_id, q, filter, cursor, limit)
+            .await
+    }
+    async fn list_all_o_auth_2_scopes(
+        &self,
+        auth_server_id: &str,
+        q: &str,
+        filter: &str,
+        cursor: &str,
+    ) -> Result<Vec<crate::types::OAuth2Scope>> {
+        AuthorizationServers::list_all_o_auth_2_scopes(self, auth_server_id, q, filter, cursor)
+            .await
+    }
+    async fn create_o_auth_2_scope(
+        &self,
+        auth_server_id: &str,
+        body: &crate::types::OAuth2ScopeRequest,
+    ) -> Result<crate::types::OAuth2Scope> {
+        AuthorizationServers::create_o_auth_2_scope(self, auth_server_id, body).await
+    }
+    async fn get_o_auth_2_scope(
+        &self,
+        auth_server_id: &str,
+        scope_id: &str,
+    ) -> Result<crate::types::OAuth2Scope> {
+        AuthorizationServers::get_o_auth_2_scope(self, auth_server_id, scope_id).await
+    }
+    async fn update_o_auth_2_scope(
+        &self,
+        auth_server_id: &str,
+        scope_id: &str,
+        body: &crate::types::OAuth2ScopeRequest,
+    ) -> Result<crate::types::OAuth2Scope> {
+        AuthorizationServers::update_o_auth_2_scope(self, auth_server_id, scope_id, body).await
+    }
+    async fn delete_o_auth_2_scope(&self, auth_server_id: &str, scope_id: &str) -> Result<()> {
+        AuthorizationServers::delete_o_auth_2_scope(self, auth_server_id, scope_id).await
+    }
+    async fn delete_o_auth_2_scope_if_exists(
+        &self,
+        auth_server_id: &str,
+        scope_id: &str,
+    ) -> Result<bool> {
+        AuthorizationServers::delete_o_auth_2_scope_if_exists(self, auth_server_id, scope_id).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Domains {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Domains {
         Domains { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Domains {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * List Domains.
      *
@@ -21,7 +27,10 @@ impl Domains {
      */
     pub async fn list(&self) -> Result<crate::types::DomainListResponse> {
         let url = "/api/v1/domains".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/api/v1/domains", &[]))
     }
 
     /**
@@ -31,11 +40,12 @@ impl Domains {
      *
      * Creates your domain.
      */
-    pub async fn create(&self, body: &crate::types::Domain) -> Result<crate::types::Domain> {
+    pub async fn create(&self, body: &crate::types::DomainRequest) -> Result<crate::types::Domain> {
         let url = "/api/v1/domains".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/api/v1/domains", &[]))
     }
 
     /**
@@ -50,12 +60,15 @@ impl Domains {
      * * `domain_id: &str`
      */
     pub async fn get(&self, domain_id: &str) -> Result<crate::types::Domain> {
-        let url = format!(
-            "/api/v1/domains/{}",
-            crate::progenitor_support::encode_path(&domain_id.to_string()),
-        );
+        let url = format!("/api/v1/domains/{}", self.client.encode_path(domain_id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/domains/{domainId}",
+                &[("domainId", domain_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -70,12 +83,37 @@ impl Domains {
      * * `domain_id: &str`
      */
     pub async fn delete(&self, domain_id: &str) -> Result<()> {
-        let url = format!(
-            "/api/v1/domains/{}",
-            crate::progenitor_support::encode_path(&domain_id.to_string()),
-        );
+        let url = format!("/api/v1/domains/{}", self.client.encode_path(domain_id),);
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/domains/{domainId}",
+                &[("domainId", domain_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete Domain.
+     *
+     * This function performs a `DELETE` to the `/api/v1/domains/{domainId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, domain_id: &str) -> Result<bool> {
+        let url = format!("/api/v1/domains/{}", self.client.encode_path(domain_id),);
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/domains/{domainId}",
+                    &[("domainId", domain_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -96,12 +134,19 @@ impl Domains {
     ) -> Result<()> {
         let url = format!(
             "/api/v1/domains/{}/certificate",
-            crate::progenitor_support::encode_path(&domain_id.to_string()),
+            self.client.encode_path(domain_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/api/v1/domains/{domainId}/certificate",
+                    &[("domainId", domain_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -118,9 +163,64 @@ impl Domains {
     pub async fn verify(&self, domain_id: &str) -> Result<crate::types::Domain> {
         let url = format!(
             "/api/v1/domains/{}/verify",
-            crate::progenitor_support::encode_path(&domain_id.to_string()),
+            self.client.encode_path(domain_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/api/v1/domains/{domainId}/verify",
+                &[("domainId", domain_id.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`Domains`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn DomainsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait DomainsApi: Send + Sync {
+    async fn list(&self) -> Result<crate::types::DomainListResponse>;
+    async fn create(&self, body: &crate::types::DomainRequest) -> Result<crate::types::Domain>;
+    async fn get(&self, domain_id: &str) -> Result<crate::types::Domain>;
+    async fn delete(&self, domain_id: &str) -> Result<()>;
+    async fn delete_if_exists(&self, domain_id: &str) -> Result<bool>;
+    async fn create_certificate(
+        &self,
+        domain_id: &str,
+        body: &crate::types::DomainCertificate,
+    ) -> Result<()>;
+    async fn verify(&self, domain_id: &str) -> Result<crate::types::Domain>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl DomainsApi for Domains {
+    async fn list(&self) -> Result<crate::types::DomainListResponse> {
+        Domains::list(self).await
+    }
+    async fn create(&self, body: &crate::types::DomainRequest) -> Result<crate::types::Domain> {
+        Domains::create(self, body).await
+    }
+    async fn get(&self, domain_id: &str) -> Result<crate::types::Domain> {
+        Domains::get(self, domain_id).await
+    }
+    async fn delete(&self, domain_id: &str) -> Result<()> {
+        Domains::delete(self, domain_id).await
+    }
+    async fn delete_if_exists(&self, domain_id: &str) -> Result<bool> {
+        Domains::delete_if_exists(self, domain_id).await
+    }
+    async fn create_certificate(
+        &self,
+        domain_id: &str,
+        body: &crate::types::DomainCertificate,
+    ) -> Result<()> {
+        Domains::create_certificate(self, domain_id, body).await
+    }
+    async fn verify(&self, domain_id: &str) -> Result<crate::types::Domain> {
+        Domains::verify(self, domain_id).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct EventHooks {
     pub client: Client,
@@ -12,6 +10,14 @@ impl EventHooks {
         EventHooks { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        EventHooks {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/eventHooks` endpoint.
      *
@@ -19,7 +25,10 @@ impl EventHooks {
      */
     pub async fn list(&self) -> Result<Vec<crate::types::EventHook>> {
         let url = "/api/v1/eventHooks".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/api/v1/eventHooks", &[]))
     }
 
     /**
@@ -31,7 +40,10 @@ impl EventHooks {
      */
     pub async fn list_all(&self) -> Result<Vec<crate::types::EventHook>> {
         let url = "/api/v1/eventHooks".to_string();
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/api/v1/eventHooks", &[]))
     }
 
     /**
@@ -39,11 +51,15 @@ impl EventHooks {
      *
      * Success
      */
-    pub async fn create(&self, body: &crate::types::EventHook) -> Result<crate::types::EventHook> {
+    pub async fn create(
+        &self,
+        body: &crate::types::EventHookRequest,
+    ) -> Result<crate::types::EventHook> {
         let url = "/api/v1/eventHooks".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/api/v1/eventHooks", &[]))
     }
 
     /**
@@ -58,10 +74,16 @@ impl EventHooks {
     pub async fn get(&self, event_hook_id: &str) -> Result<crate::types::EventHook> {
         let url = format!(
             "/api/v1/eventHooks/{}",
-            crate::progenitor_support::encode_path(&event_hook_id.to_string()),
+            self.client.encode_path(event_hook_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/eventHooks/{eventHookId}",
+                &[("eventHookId", event_hook_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -76,16 +98,23 @@ impl EventHooks {
     pub async fn update(
         &self,
         event_hook_id: &str,
-        body: &crate::types::EventHook,
+        body: &crate::types::EventHookRequest,
     ) -> Result<crate::types::EventHook> {
         let url = format!(
             "/api/v1/eventHooks/{}",
-            crate::progenitor_support::encode_path(&event_hook_id.to_string()),
+            self.client.encode_path(event_hook_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/api/v1/eventHooks/{eventHookId}",
+                    &[("eventHookId", event_hook_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -100,10 +129,39 @@ impl EventHooks {
     pub async fn delete(&self, event_hook_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/eventHooks/{}",
-            crate::progenitor_support::encode_path(&event_hook_id.to_string()),
+            self.client.encode_path(event_hook_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/eventHooks/{eventHookId}",
+                &[("eventHookId", event_hook_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/api/v1/eventHooks/{eventHookId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, event_hook_id: &str) -> Result<bool> {
+        let url = format!(
+            "/api/v1/eventHooks/{}",
+            self.client.encode_path(event_hook_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/eventHooks/{eventHookId}",
+                    &[("eventHookId", event_hook_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -118,10 +176,16 @@ impl EventHooks {
     pub async fn activate(&self, event_hook_id: &str) -> Result<crate::types::EventHook> {
         let url = format!(
             "/api/v1/eventHooks/{}/lifecycle/activate",
-            crate::progenitor_support::encode_path(&event_hook_id.to_string()),
+            self.client.encode_path(event_hook_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/api/v1/eventHooks/{eventHookId}/lifecycle/activate",
+                &[("eventHookId", event_hook_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -136,10 +200,16 @@ impl EventHooks {
     pub async fn deactivate(&self, event_hook_id: &str) -> Result<crate::types::EventHook> {
         let url = format!(
             "/api/v1/eventHooks/{}/lifecycle/deactivate",
-            crate::progenitor_support::encode_path(&event_hook_id.to_string()),
+            self.client.encode_path(event_hook_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/api/v1/eventHooks/{eventHookId}/lifecycle/deactivate",
+                &[("eventHookId", event_hook_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -154,9 +224,82 @@ impl EventHooks {
     pub async fn verify(&self, event_hook_id: &str) -> Result<crate::types::EventHook> {
         let url = format!(
             "/api/v1/eventHooks/{}/lifecycle/verify",
-            crate::progenitor_support::encode_path(&event_hook_id.to_string()),
+            self.client.encode_path(event_hook_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/api/v1/eventHooks/{eventHookId}/lifecycle/verify",
+                &[("eventHookId", event_hook_id.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`EventHooks`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn EventHooksApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait EventHooksApi: Send + Sync {
+    async fn list(&self) -> Result<Vec<crate::types::EventHook>>;
+    async fn list_all(&self) -> Result<Vec<crate::types::EventHook>>;
+    async fn create(
+        &self,
+        body: &crate::types::EventHookRequest,
+    ) -> Result<crate::types::EventHook>;
+    async fn get(&self, event_hook_id: &str) -> Result<crate::types::EventHook>;
+    async fn update(
+        &self,
+        event_hook_id: &str,
+        body: &crate::types::EventHookRequest,
+    ) -> Result<crate::types::EventHook>;
+    async fn delete(&self, event_hook_id: &str) -> Result<()>;
+    async fn delete_if_exists(&self, event_hook_id: &str) -> Result<bool>;
+    async fn activate(&self, event_hook_id: &str) -> Result<crate::types::EventHook>;
+    async fn deactivate(&self, event_hook_id: &str) -> Result<crate::types::EventHook>;
+    async fn verify(&self, event_hook_id: &str) -> Result<crate::types::EventHook>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl EventHooksApi for EventHooks {
+    async fn list(&self) -> Result<Vec<crate::types::EventHook>> {
+        EventHooks::list(self).await
+    }
+    async fn list_all(&self) -> Result<Vec<crate::types::EventHook>> {
+        EventHooks::list_all(self).await
+    }
+    async fn create(
+        &self,
+        body: &crate::types::EventHookRequest,
+    ) -> Result<crate::types::EventHook> {
+        EventHooks::create(self, body).await
+    }
+    async fn get(&self, event_hook_id: &str) -> Result<crate::types::EventHook> {
+        EventHooks::get(self, event_hook_id).await
+    }
+    async fn update(
+        &self,
+        event_hook_id: &str,
+        body: &crate::types::EventHookRequest,
+    ) -> Result<crate::types::EventHook> {
+        EventHooks::update(self, event_hook_id, body).await
+    }
+    async fn delete(&self, event_hook_id: &str) -> Result<()> {
+        EventHooks::delete(self, event_hook_id).await
+    }
+    async fn delete_if_exists(&self, event_hook_id: &str) -> Result<bool> {
+        EventHooks::delete_if_exists(self, event_hook_id).await
+    }
+    async fn activate(&self, event_hook_id: &str) -> Result<crate::types::EventHook> {
+        EventHooks::activate(self, event_hook_id).await
+    }
+    async fn deactivate(&self, event_hook_id: &str) -> Result<crate::types::EventHook> {
+        EventHooks::deactivate(self, event_hook_id).await
+    }
+    async fn verify(&self, event_hook_id: &str) -> Result<crate::types::EventHook> {
+        EventHooks::verify(self, event_hook_id).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Features {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Features {
         Features { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Features {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/features` endpoint.
      *
@@ -19,7 +25,10 @@ impl Features {
      */
     pub async fn list(&self) -> Result<Vec<crate::types::Feature>> {
         let url = "/api/v1/features".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/api/v1/features", &[]))
     }
 
     /**
@@ -31,7 +40,10 @@ impl Features {
      */
     pub async fn list_all(&self) -> Result<Vec<crate::types::Feature>> {
         let url = "/api/v1/features".to_string();
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/api/v1/features", &[]))
     }
 
     /**
@@ -44,12 +56,15 @@ impl Features {
      * * `feature_id: &str`
      */
     pub async fn get(&self, feature_id: &str) -> Result<crate::types::Feature> {
-        let url = format!(
-            "/api/v1/features/{}",
-            crate::progenitor_support::encode_path(&feature_id.to_string()),
-        );
+        let url = format!("/api/v1/features/{}", self.client.encode_path(feature_id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/features/{featureId}",
+                &[("featureId", feature_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -64,10 +79,16 @@ impl Features {
     pub async fn list_dependencies(&self, feature_id: &str) -> Result<Vec<crate::types::Feature>> {
         let url = format!(
             "/api/v1/features/{}/dependencies",
-            crate::progenitor_support::encode_path(&feature_id.to_string()),
+            self.client.encode_path(feature_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/features/{featureId}/dependencies",
+                &[("featureId", feature_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -83,10 +104,19 @@ impl Features {
     ) -> Result<Vec<crate::types::Feature>> {
         let url = format!(
             "/api/v1/features/{}/dependencies",
-            crate::progenitor_support::encode_path(&feature_id.to_string()),
+            self.client.encode_path(feature_id),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/api/v1/features/{featureId}/dependencies",
+                    &[("featureId", feature_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -101,10 +131,16 @@ impl Features {
     pub async fn list_dependents(&self, feature_id: &str) -> Result<Vec<crate::types::Feature>> {
         let url = format!(
             "/api/v1/features/{}/dependents",
-            crate::progenitor_support::encode_path(&feature_id.to_string()),
+            self.client.encode_path(feature_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/features/{featureId}/dependents",
+                &[("featureId", feature_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -120,10 +156,19 @@ impl Features {
     ) -> Result<Vec<crate::types::Feature>> {
         let url = format!(
             "/api/v1/features/{}/dependents",
-            crate::progenitor_support::encode_path(&feature_id.to_string()),
+            self.client.encode_path(feature_id),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/api/v1/features/{featureId}/dependents",
+                    &[("featureId", feature_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -143,18 +188,83 @@ impl Features {
         lifecycle: &str,
         mode: &str,
     ) -> Result<crate::types::Feature> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !mode.is_empty() {
-            query_args.push(("mode".to_string(), mode.to_string()));
+            query_args.push(("mode", mode.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/features/{}/{}?{}",
-            crate::progenitor_support::encode_path(&feature_id.to_string()),
-            crate::progenitor_support::encode_path(&lifecycle.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/api/v1/features/{}/{}",
+                self.client.encode_path(feature_id),
+                self.client.encode_path(lifecycle)
+            ),
+            &query_args,
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/api/v1/features/{featureId}/{lifecycle}",
+                &[
+                    ("featureId", feature_id.to_string()),
+                    ("lifecycle", lifecycle.to_string()),
+                ],
+            )
+        })
+    }
+}
+
+/// The functions of [`Features`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn FeaturesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait FeaturesApi: Send + Sync {
+    async fn list(&self) -> Result<Vec<crate::types::Feature>>;
+    async fn list_all(&self) -> Result<Vec<crate::types::Feature>>;
+    async fn get(&self, feature_id: &str) -> Result<crate::types::Feature>;
+    async fn list_dependencies(&self, feature_id: &str) -> Result<Vec<crate::types::Feature>>;
+    async fn list_all_dependencies(&self, feature_id: &str) -> Result<Vec<crate::types::Feature>>;
+    async fn list_dependents(&self, feature_id: &str) -> Result<Vec<crate::types::Feature>>;
+    async fn list_all_dependents(&self, feature_id: &str) -> Result<Vec<crate::types::Feature>>;
+    async fn update_lifecycle(
+        &self,
+        feature_id: &str,
+        lifecycle: &str,
+        mode: &str,
+    ) -> Result<crate::types::Feature>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl FeaturesApi for Features {
+    async fn list(&self) -> Result<Vec<crate::types::Feature>> {
+        Features::list(self).await
+    }
+    async fn list_all(&self) -> Result<Vec<crate::types::Feature>> {
+        Features::list_all(self).await
+    }
+    async fn get(&self, feature_id: &str) -> Result<crate::types::Feature> {
+        Features::get(self, feature_id).await
+    }
+    async fn list_dependencies(&self, feature_id: &str) -> Result<Vec<crate::types::Feature>> {
+        Features::list_dependencies(self, feature_id).await
+    }
+    async fn list_all_dependencies(&self, feature_id: &str) -> Result<Vec<crate::types::Feature>> {
+        Features::list_all_dependencies(self, feature_id).await
+    }
+    async fn list_dependents(&self, feature_id: &str) -> Result<Vec<crate::types::Feature>> {
+        Features::list_dependents(self, feature_id).await
+    }
+    async fn list_all_dependents(&self, feature_id: &str) -> Result<Vec<crate::types::Feature>> {
+        Features::list_all_dependents(self, feature_id).await
+    }
+    async fn update_lifecycle(
+        &self,
+        feature_id: &str,
+        lifecycle: &str,
+        mode: &str,
+    ) -> Result<crate::types::Feature> {
+        Features::update_lifecycle(self, feature_id, lifecycle, mode).await
     }
 }
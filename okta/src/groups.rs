@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Groups {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Groups {
         Groups { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Groups {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * List Groups.
      *
@@ -35,26 +41,28 @@ impl Groups {
         limit: i64,
         expand: &str,
     ) -> Result<Vec<crate::types::Group>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if !search.is_empty() {
-            query_args.push(("search".to_string(), search.to_string()));
+            query_args.push(("search", search.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/groups?{}", query_);
+        let url = crate::Client::url_with_query("/api/v1/groups", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/api/v1/groups", &[]))
     }
 
     /**
@@ -72,20 +80,22 @@ impl Groups {
         search: &str,
         expand: &str,
     ) -> Result<Vec<crate::types::Group>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if !search.is_empty() {
-            query_args.push(("search".to_string(), search.to_string()));
+            query_args.push(("search", search.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/groups?{}", query_);
+        let url = crate::Client::url_with_query("/api/v1/groups", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/api/v1/groups", &[]))
     }
 
     /**
@@ -95,11 +105,12 @@ impl Groups {
      *
      * Adds a new group with `OKTA_GROUP` type to your organization.
      */
-    pub async fn create(&self, body: &crate::types::Group) -> Result<crate::types::Group> {
+    pub async fn create(&self, body: &crate::types::GroupRequest) -> Result<crate::types::Group> {
         let url = "/api/v1/groups".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/api/v1/groups", &[]))
     }
 
     /**
@@ -123,23 +134,25 @@ impl Groups {
         search: &str,
         expand: &str,
     ) -> Result<Vec<crate::types::GroupRule>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !search.is_empty() {
-            query_args.push(("search".to_string(), search.to_string()));
+            query_args.push(("search", search.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/groups/rules?{}", query_);
+        let url = crate::Client::url_with_query("/api/v1/groups/rules", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/api/v1/groups/rules", &[]))
     }
 
     /**
@@ -156,17 +169,19 @@ impl Groups {
         search: &str,
         expand: &str,
     ) -> Result<Vec<crate::types::GroupRule>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
         if !search.is_empty() {
-            query_args.push(("search".to_string(), search.to_string()));
+            query_args.push(("search", search.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/groups/rules?{}", query_);
+        let url = crate::Client::url_with_query("/api/v1/groups/rules", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/api/v1/groups/rules", &[]))
     }
 
     /**
@@ -178,12 +193,13 @@ impl Groups {
      */
     pub async fn create_rule(
         &self,
-        body: &crate::types::GroupRule,
+        body: &crate::types::GroupRuleRequest,
     ) -> Result<crate::types::GroupRule> {
         let url = "/api/v1/groups/rules".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/api/v1/groups/rules", &[]))
     }
 
     /**
@@ -199,18 +215,22 @@ impl Groups {
      * * `expand: &str`
      */
     pub async fn get_rule(&self, rule_id: &str, expand: &str) -> Result<crate::types::GroupRule> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/groups/rules/{}?{}",
-            crate::progenitor_support::encode_path(&rule_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/api/v1/groups/rules/{}", self.client.encode_path(rule_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/groups/rules/{ruleId}",
+                &[("ruleId", rule_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -225,16 +245,20 @@ impl Groups {
     pub async fn update_rule(
         &self,
         rule_id: &str,
-        body: &crate::types::GroupRule,
+        body: &crate::types::GroupRuleRequest,
     ) -> Result<crate::types::GroupRule> {
-        let url = format!(
-            "/api/v1/groups/rules/{}",
-            crate::progenitor_support::encode_path(&rule_id.to_string()),
-        );
+        let url = format!("/api/v1/groups/rules/{}", self.client.encode_path(rule_id),);
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/api/v1/groups/rules/{ruleId}",
+                    &[("ruleId", rule_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -250,18 +274,51 @@ impl Groups {
      * * `remove_users: bool` -- Indicates whether to keep or remove users from groups assigned by this rule.
      */
     pub async fn delete_rule(&self, rule_id: &str, remove_users: bool) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if remove_users {
-            query_args.push(("removeUsers".to_string(), remove_users.to_string()));
+            query_args.push(("removeUsers", remove_users.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/groups/rules/{}?{}",
-            crate::progenitor_support::encode_path(&rule_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/api/v1/groups/rules/{}", self.client.encode_path(rule_id)),
+            &query_args,
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/groups/rules/{ruleId}",
+                &[("ruleId", rule_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete a group Rule.
+     *
+     * This function performs a `DELETE` to the `/api/v1/groups/rules/{ruleId}` endpoint.
+     *
+     * As opposed to `delete_rule`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_rule_if_exists(&self, rule_id: &str, remove_users: bool) -> Result<bool> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if remove_users {
+            query_args.push(("removeUsers", remove_users.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!("/api/v1/groups/rules/{}", self.client.encode_path(rule_id)),
+            &query_args,
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/groups/rules/{ruleId}",
+                    &[("ruleId", rule_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -278,10 +335,16 @@ impl Groups {
     pub async fn activate_rule(&self, rule_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/groups/rules/{}/lifecycle/activate",
-            crate::progenitor_support::encode_path(&rule_id.to_string()),
+            self.client.encode_path(rule_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/api/v1/groups/rules/{ruleId}/lifecycle/activate",
+                &[("ruleId", rule_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -298,10 +361,16 @@ impl Groups {
     pub async fn deactivate_rule(&self, rule_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/groups/rules/{}/lifecycle/deactivate",
-            crate::progenitor_support::encode_path(&rule_id.to_string()),
+            self.client.encode_path(rule_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/api/v1/groups/rules/{ruleId}/lifecycle/deactivate",
+                &[("ruleId", rule_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -316,12 +385,15 @@ impl Groups {
      * * `group_id: &str`
      */
     pub async fn get(&self, group_id: &str) -> Result<crate::types::Group> {
-        let url = format!(
-            "/api/v1/groups/{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-        );
-
-        self.client.get(&url, None).await
+        let url = format!("/api/v1/groups/{}", self.client.encode_path(group_id),);
+
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/groups/{groupId}",
+                &[("groupId", group_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -338,16 +410,20 @@ impl Groups {
     pub async fn update(
         &self,
         group_id: &str,
-        body: &crate::types::Group,
+        body: &crate::types::GroupRequest,
     ) -> Result<crate::types::Group> {
-        let url = format!(
-            "/api/v1/groups/{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-        );
+        let url = format!("/api/v1/groups/{}", self.client.encode_path(group_id),);
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/api/v1/groups/{groupId}",
+                    &[("groupId", group_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -362,12 +438,37 @@ impl Groups {
      * * `group_id: &str`
      */
     pub async fn delete(&self, group_id: &str) -> Result<()> {
-        let url = format!(
-            "/api/v1/groups/{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-        );
+        let url = format!("/api/v1/groups/{}", self.client.encode_path(group_id),);
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/groups/{groupId}",
+                &[("groupId", group_id.to_string())],
+            )
+        })
+    }
 
-        self.client.delete(&url, None).await
+    /**
+     * Remove Group.
+     *
+     * This function performs a `DELETE` to the `/api/v1/groups/{groupId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, group_id: &str) -> Result<bool> {
+        let url = format!("/api/v1/groups/{}", self.client.encode_path(group_id),);
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/groups/{groupId}",
+                    &[("groupId", group_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -389,21 +490,25 @@ impl Groups {
         after: &str,
         limit: i64,
     ) -> Result<Vec<crate::types::Application>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/groups/{}/apps?{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/api/v1/groups/{}/apps", self.client.encode_path(group_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/groups/{groupId}/apps",
+                &[("groupId", group_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -419,12 +524,18 @@ impl Groups {
         &self,
         group_id: &str,
     ) -> Result<Vec<crate::types::Application>> {
-        let url = format!(
-            "/api/v1/groups/{}/apps",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-        );
+        let url = format!("/api/v1/groups/{}/apps", self.client.encode_path(group_id),);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/api/v1/groups/{groupId}/apps",
+                    &[("groupId", group_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -442,18 +553,22 @@ impl Groups {
         group_id: &str,
         expand: &str,
     ) -> Result<Vec<crate::types::Role>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/groups/{}/roles?{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/api/v1/groups/{}/roles", self.client.encode_path(group_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/groups/{groupId}/roles",
+                &[("groupId", group_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -468,18 +583,25 @@ impl Groups {
         group_id: &str,
         expand: &str,
     ) -> Result<Vec<crate::types::Role>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/groups/{}/roles?{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/api/v1/groups/{}/roles", self.client.encode_path(group_id)),
+            &query_args,
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/api/v1/groups/{groupId}/roles",
+                    &[("groupId", group_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -498,23 +620,25 @@ impl Groups {
         disable_notifications: &str,
         body: &crate::types::AssignRoleRequest,
     ) -> Result<crate::types::Role> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !disable_notifications.is_empty() {
-            query_args.push((
-                "disableNotifications".to_string(),
-                disable_notifications.to_string(),
-            ));
+            query_args.push(("disableNotifications", disable_notifications.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/groups/{}/roles?{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/api/v1/groups/{}/roles", self.client.encode_path(group_id)),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/api/v1/groups/{groupId}/roles",
+                    &[("groupId", group_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -530,11 +654,20 @@ impl Groups {
     pub async fn get_role(&self, group_id: &str, role_id: &str) -> Result<crate::types::Role> {
         let url = format!(
             "/api/v1/groups/{}/roles/{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-            crate::progenitor_support::encode_path(&role_id.to_string()),
+            self.client.encode_path(group_id),
+            self.client.encode_path(role_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/groups/{groupId}/roles/{roleId}",
+                &[
+                    ("groupId", group_id.to_string()),
+                    ("roleId", role_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -550,11 +683,47 @@ impl Groups {
     pub async fn remove_role_from(&self, group_id: &str, role_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/groups/{}/roles/{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-            crate::progenitor_support::encode_path(&role_id.to_string()),
+            self.client.encode_path(group_id),
+            self.client.encode_path(role_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/groups/{groupId}/roles/{roleId}",
+                &[
+                    ("groupId", group_id.to_string()),
+                    ("roleId", role_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/api/v1/groups/{groupId}/roles/{roleId}` endpoint.
+     *
+     * As opposed to `remove_role_from`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn remove_role_from_if_exists(&self, group_id: &str, role_id: &str) -> Result<bool> {
+        let url = format!(
+            "/api/v1/groups/{}/roles/{}",
+            self.client.encode_path(group_id),
+            self.client.encode_path(role_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/groups/{groupId}/roles/{roleId}",
+                    &[
+                        ("groupId", group_id.to_string()),
+                        ("roleId", role_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -576,22 +745,32 @@ impl Groups {
         after: &str,
         limit: i64,
     ) -> Result<Vec<crate::types::CatalogApplication>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/groups/{}/roles/{}/targets/catalog/apps?{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-            crate::progenitor_support::encode_path(&role_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/api/v1/groups/{}/roles/{}/targets/catalog/apps",
+                self.client.encode_path(group_id),
+                self.client.encode_path(role_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/groups/{groupId}/roles/{roleId}/targets/catalog/apps",
+                &[
+                    ("groupId", group_id.to_string()),
+                    ("roleId", role_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -608,11 +787,23 @@ impl Groups {
     ) -> Result<Vec<crate::types::CatalogApplication>> {
         let url = format!(
             "/api/v1/groups/{}/roles/{}/targets/catalog/apps",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-            crate::progenitor_support::encode_path(&role_id.to_string()),
+            self.client.encode_path(group_id),
+            self.client.encode_path(role_id),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/api/v1/groups/{groupId}/roles/{roleId}/targets/catalog/apps",
+                    &[
+                        ("groupId", group_id.to_string()),
+                        ("roleId", role_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -634,12 +825,22 @@ impl Groups {
     ) -> Result<()> {
         let url = format!(
             "/api/v1/groups/{}/roles/{}/targets/catalog/apps/{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-            crate::progenitor_support::encode_path(&role_id.to_string()),
-            crate::progenitor_support::encode_path(&app_name.to_string()),
+            self.client.encode_path(group_id),
+            self.client.encode_path(role_id),
+            self.client.encode_path(app_name),
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "PUT",
+                "/api/v1/groups/{groupId}/roles/{roleId}/targets/catalog/apps/{appName}",
+                &[
+                    ("groupId", group_id.to_string()),
+                    ("roleId", role_id.to_string()),
+                    ("appName", app_name.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -661,12 +862,56 @@ impl Groups {
     ) -> Result<()> {
         let url = format!(
             "/api/v1/groups/{}/roles/{}/targets/catalog/apps/{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-            crate::progenitor_support::encode_path(&role_id.to_string()),
-            crate::progenitor_support::encode_path(&app_name.to_string()),
+            self.client.encode_path(group_id),
+            self.client.encode_path(role_id),
+            self.client.encode_path(app_name),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/groups/{groupId}/roles/{roleId}/targets/catalog/apps/{appName}",
+                &[
+                    ("groupId", group_id.to_string()),
+                    ("roleId", role_id.to_string()),
+                    ("appName", app_name.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/api/v1/groups/{groupId}/roles/{roleId}/targets/catalog/apps/{appName}` endpoint.
+     *
+     * As opposed to `remove_application_target_from_administrator_role_given`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn remove_application_target_from_administrator_role_given_if_exists(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        app_name: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/api/v1/groups/{}/roles/{}/targets/catalog/apps/{}",
+            self.client.encode_path(group_id),
+            self.client.encode_path(role_id),
+            self.client.encode_path(app_name),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/groups/{groupId}/roles/{roleId}/targets/catalog/apps/{appName}",
+                    &[
+                        ("groupId", group_id.to_string()),
+                        ("roleId", role_id.to_string()),
+                        ("appName", app_name.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -692,13 +937,13 @@ impl Groups {
     ) -> Result<()> {
         let url = format!(
             "/api/v1/groups/{}/roles/{}/targets/catalog/apps/{}/{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-            crate::progenitor_support::encode_path(&role_id.to_string()),
-            crate::progenitor_support::encode_path(&app_name.to_string()),
-            crate::progenitor_support::encode_path(&application_id.to_string()),
+            self.client.encode_path(group_id),
+            self.client.encode_path(role_id),
+            self.client.encode_path(app_name),
+            self.client.encode_path(application_id),
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| crate::utils::pretty_url("PUT", "/api/v1/groups/{groupId}/roles/{roleId}/targets/catalog/apps/{appName}/{applicationId}", &[("groupId", group_id.to_string()), ("roleId", role_id.to_string()), ("appName", app_name.to_string()), ("applicationId", application_id.to_string())]))
     }
 
     /**
@@ -724,13 +969,38 @@ impl Groups {
     ) -> Result<()> {
         let url = format!(
             "/api/v1/groups/{}/roles/{}/targets/catalog/apps/{}/{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-            crate::progenitor_support::encode_path(&role_id.to_string()),
-            crate::progenitor_support::encode_path(&app_name.to_string()),
-            crate::progenitor_support::encode_path(&application_id.to_string()),
+            self.client.encode_path(group_id),
+            self.client.encode_path(role_id),
+            self.client.encode_path(app_name),
+            self.client.encode_path(application_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/api/v1/groups/{groupId}/roles/{roleId}/targets/catalog/apps/{appName}/{applicationId}", &[("groupId", group_id.to_string()), ("roleId", role_id.to_string()), ("appName", app_name.to_string()), ("applicationId", application_id.to_string())]))
+    }
+
+    /**
+     * Remove App Instance Target to App Administrator Role given to a Group.
+     *
+     * This function performs a `DELETE` to the `/api/v1/groups/{groupId}/roles/{roleId}/targets/catalog/apps/{appName}/{applicationId}` endpoint.
+     *
+     * As opposed to `remove_application_target_from_administrator_role_given_groups`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn remove_application_target_from_administrator_role_given_groups_if_exists(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        app_name: &str,
+        application_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/api/v1/groups/{}/roles/{}/targets/catalog/apps/{}/{}",
+            self.client.encode_path(group_id),
+            self.client.encode_path(role_id),
+            self.client.encode_path(app_name),
+            self.client.encode_path(application_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete_if_exists(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/api/v1/groups/{groupId}/roles/{roleId}/targets/catalog/apps/{appName}/{applicationId}", &[("groupId", group_id.to_string()), ("roleId", role_id.to_string()), ("appName", app_name.to_string()), ("applicationId", application_id.to_string())]))
     }
 
     /**
@@ -752,22 +1022,32 @@ impl Groups {
         after: &str,
         limit: i64,
     ) -> Result<Vec<crate::types::Group>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/groups/{}/roles/{}/targets/groups?{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-            crate::progenitor_support::encode_path(&role_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/api/v1/groups/{}/roles/{}/targets/groups",
+                self.client.encode_path(group_id),
+                self.client.encode_path(role_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/groups/{groupId}/roles/{roleId}/targets/groups",
+                &[
+                    ("groupId", group_id.to_string()),
+                    ("roleId", role_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -784,11 +1064,23 @@ impl Groups {
     ) -> Result<Vec<crate::types::Group>> {
         let url = format!(
             "/api/v1/groups/{}/roles/{}/targets/groups",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-            crate::progenitor_support::encode_path(&role_id.to_string()),
+            self.client.encode_path(group_id),
+            self.client.encode_path(role_id),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/api/v1/groups/{groupId}/roles/{roleId}/targets/groups",
+                    &[
+                        ("groupId", group_id.to_string()),
+                        ("roleId", role_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -810,12 +1102,22 @@ impl Groups {
     ) -> Result<()> {
         let url = format!(
             "/api/v1/groups/{}/roles/{}/targets/groups/{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-            crate::progenitor_support::encode_path(&role_id.to_string()),
-            crate::progenitor_support::encode_path(&target_group_id.to_string()),
+            self.client.encode_path(group_id),
+            self.client.encode_path(role_id),
+            self.client.encode_path(target_group_id),
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "PUT",
+                "/api/v1/groups/{groupId}/roles/{roleId}/targets/groups/{targetGroupId}",
+                &[
+                    ("groupId", group_id.to_string()),
+                    ("roleId", role_id.to_string()),
+                    ("targetGroupId", target_group_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -837,12 +1139,56 @@ impl Groups {
     ) -> Result<()> {
         let url = format!(
             "/api/v1/groups/{}/roles/{}/targets/groups/{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-            crate::progenitor_support::encode_path(&role_id.to_string()),
-            crate::progenitor_support::encode_path(&target_group_id.to_string()),
+            self.client.encode_path(group_id),
+            self.client.encode_path(role_id),
+            self.client.encode_path(target_group_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/groups/{groupId}/roles/{roleId}/targets/groups/{targetGroupId}",
+                &[
+                    ("groupId", group_id.to_string()),
+                    ("roleId", role_id.to_string()),
+                    ("targetGroupId", target_group_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/api/v1/groups/{groupId}/roles/{roleId}/targets/groups/{targetGroupId}` endpoint.
+     *
+     * As opposed to `remove_target_from_administrator_role_given`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn remove_target_from_administrator_role_given_if_exists(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        target_group_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/api/v1/groups/{}/roles/{}/targets/groups/{}",
+            self.client.encode_path(group_id),
+            self.client.encode_path(role_id),
+            self.client.encode_path(target_group_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/groups/{groupId}/roles/{roleId}/targets/groups/{targetGroupId}",
+                    &[
+                        ("groupId", group_id.to_string()),
+                        ("roleId", role_id.to_string()),
+                        ("targetGroupId", target_group_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -864,21 +1210,25 @@ impl Groups {
         after: &str,
         limit: i64,
     ) -> Result<Vec<crate::types::User>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/groups/{}/users?{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/api/v1/groups/{}/users", self.client.encode_path(group_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/groups/{groupId}/users",
+                &[("groupId", group_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -891,12 +1241,18 @@ impl Groups {
      * Enumerates all users that are a member of a group.
      */
     pub async fn list_all_users(&self, group_id: &str) -> Result<Vec<crate::types::User>> {
-        let url = format!(
-            "/api/v1/groups/{}/users",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-        );
+        let url = format!("/api/v1/groups/{}/users", self.client.encode_path(group_id),);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/api/v1/groups/{groupId}/users",
+                    &[("groupId", group_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -914,11 +1270,20 @@ impl Groups {
     pub async fn add_user(&self, group_id: &str, user_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/groups/{}/users/{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(group_id),
+            self.client.encode_path(user_id),
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "PUT",
+                "/api/v1/groups/{groupId}/users/{userId}",
+                &[
+                    ("groupId", group_id.to_string()),
+                    ("userId", user_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -936,10 +1301,525 @@ impl Groups {
     pub async fn remove_user_from(&self, group_id: &str, user_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/groups/{}/users/{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(group_id),
+            self.client.encode_path(user_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/groups/{groupId}/users/{userId}",
+                &[
+                    ("groupId", group_id.to_string()),
+                    ("userId", user_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Remove User from Group.
+     *
+     * This function performs a `DELETE` to the `/api/v1/groups/{groupId}/users/{userId}` endpoint.
+     *
+     * As opposed to `remove_user_from`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn remove_user_from_if_exists(&self, group_id: &str, user_id: &str) -> Result<bool> {
+        let url = format!(
+            "/api/v1/groups/{}/users/{}",
+            self.client.encode_path(group_id),
+            self.client.encode_path(user_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/groups/{groupId}/users/{userId}",
+                    &[
+                        ("groupId", group_id.to_string()),
+                        ("userId", user_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`Groups`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn GroupsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait GroupsApi: Send + Sync {
+    async fn list(
+        &self,
+        q: &str,
+        search: &str,
+        after: &str,
+        limit: i64,
+        expand: &str,
+    ) -> Result<Vec<crate::types::Group>>;
+    async fn list_all(
+        &self,
+        q: &str,
+        search: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::Group>>;
+    async fn create(&self, body: &crate::types::GroupRequest) -> Result<crate::types::Group>;
+    async fn list_rules(
+        &self,
+        limit: i64,
+        after: &str,
+        search: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::GroupRule>>;
+    async fn list_all_rules(
+        &self,
+        search: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::GroupRule>>;
+    async fn create_rule(
+        &self,
+        body: &crate::types::GroupRuleRequest,
+    ) -> Result<crate::types::GroupRule>;
+    async fn get_rule(&self, rule_id: &str, expand: &str) -> Result<crate::types::GroupRule>;
+    async fn update_rule(
+        &self,
+        rule_id: &str,
+        body: &crate::types::GroupRuleRequest,
+    ) -> Result<crate::types::GroupRule>;
+    async fn delete_rule(&self, rule_id: &str, remove_users: bool) -> Result<()>;
+    async fn delete_rule_if_exists(&self, rule_id: &str, remove_users: bool) -> Result<bool>;
+    async fn activate_rule(&self, rule_id: &str) -> Result<()>;
+    async fn deactivate_rule(&self, rule_id: &str) -> Result<()>;
+    async fn get(&self, group_id: &str) -> Result<crate::types::Group>;
+    async fn update(
+        &self,
+        group_id: &str,
+        body: &crate::types::GroupRequest,
+    ) -> Result<crate::types::Group>;
+    async fn delete(&self, group_id: &str) -> Result<()>;
+    async fn delete_if_exists(&self, group_id: &str) -> Result<bool>;
+    async fn list_assigned_applications_fors(
+        &self,
+        group_id: &str,
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::Application>>;
+    async fn list_all_assigned_applications_fors(
+        &self,
+        group_id: &str,
+    ) -> Result<Vec<crate::types::Application>>;
+    async fn list_assigned_roles(
+        &self,
+        group_id: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::Role>>;
+    async fn list_all_assigned_roles(
+        &self,
+        group_id: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::Role>>;
+    async fn assign_role(
+        &self,
+        group_id: &str,
+        disable_notifications: &str,
+        body: &crate::types::AssignRoleRequest,
+    ) -> Result<crate::types::Role>;
+    async fn get_role(&self, group_id: &str, role_id: &str) -> Result<crate::types::Role>;
+    async fn remove_role_from(&self, group_id: &str, role_id: &str) -> Result<()>;
+    async fn remove_role_from_if_exists(&self, group_id: &str, role_id: &str) -> Result<bool>;
+    async fn list_application_targets_for_administrator_roles(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::CatalogApplication>>;
+    async fn list_all_application_targets_for_administrator_roles(
+        &self,
+        group_id: &str,
+        role_id: &str,
+    ) -> Result<Vec<crate::types::CatalogApplication>>;
+    async fn add_application_target_admin_role_given(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        app_name: &str,
+    ) -> Result<()>;
+    async fn remove_application_target_from_administrator_role_given(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        app_name: &str,
+    ) -> Result<()>;
+    async fn remove_application_target_from_administrator_role_given_if_exists(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        app_name: &str,
+    ) -> Result<bool>;
+    async fn add_application_instance_target_app_admin_role_given(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        app_name: &str,
+        application_id: &str,
+    ) -> Result<()>;
+    async fn remove_application_target_from_administrator_role_given_groups(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        app_name: &str,
+        application_id: &str,
+    ) -> Result<()>;
+    async fn remove_application_target_from_administrator_role_given_groups_if_exists(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        app_name: &str,
+        application_id: &str,
+    ) -> Result<bool>;
+    async fn list_targets_for_roles(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::Group>>;
+    async fn list_all_targets_for_roles(
+        &self,
+        group_id: &str,
+        role_id: &str,
+    ) -> Result<Vec<crate::types::Group>>;
+    async fn add_target_administrator_role_for(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        target_group_id: &str,
+    ) -> Result<()>;
+    async fn remove_target_from_administrator_role_given(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        target_group_id: &str,
+    ) -> Result<()>;
+    async fn remove_target_from_administrator_role_given_if_exists(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        target_group_id: &str,
+    ) -> Result<bool>;
+    async fn list_users(
+        &self,
+        group_id: &str,
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::User>>;
+    async fn list_all_users(&self, group_id: &str) -> Result<Vec<crate::types::User>>;
+    async fn add_user(&self, group_id: &str, user_id: &str) -> Result<()>;
+    async fn remove_user_from(&self, group_id: &str, user_id: &str) -> Result<()>;
+    async fn remove_user_from_if_exists(&self, group_id: &str, user_id: &str) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl GroupsApi for Groups {
+    async fn list(
+        &self,
+        q: &str,
+        search: &str,
+        after: &str,
+        limit: i64,
+        expand: &str,
+    ) -> Result<Vec<crate::types::Group>> {
+        Groups::list(self, q, search, after, limit, expand).await
+    }
+    async fn list_all(
+        &self,
+        q: &str,
+        search: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::Group>> {
+        Groups::list_all(self, q, search, expand).await
+    }
+    async fn create(&self, body: &crate::types::GroupRequest) -> Result<crate::types::Group> {
+        Groups::create(self, body).await
+    }
+    async fn list_rules(
+        &self,
+        limit: i64,
+        after: &str,
+        search: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::GroupRule>> {
+        Groups::list_rules(self, limit, after, search, expand).await
+    }
+    async fn list_all_rules(
+        &self,
+        search: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::GroupRule>> {
+        Groups::list_all_rules(self, search, expand).await
+    }
+    async fn create_rule(
+        &self,
+        body: &crate::types::GroupRuleRequest,
+    ) -> Result<crate::types::GroupRule> {
+        Groups::create_rule(self, body).await
+    }
+    async fn get_rule(&self, rule_id: &str, expand: &str) -> Result<crate::types::GroupRule> {
+        Groups::get_rule(self, rule_id, expand).await
+    }
+    async fn update_rule(
+        &self,
+        rule_id: &str,
+        body: &crate::types::GroupRuleRequest,
+    ) -> Result<crate::types::GroupRule> {
+        Groups::update_rule(self, rule_id, body).await
+    }
+    async fn delete_rule(&self, rule_id: &str, remove_users: bool) -> Result<()> {
+        Groups::delete_rule(self, rule_id, remove_users).await
+    }
+    async fn delete_rule_if_exists(&self, rule_id: &str, remove_users: bool) -> Result<bool> {
+        Groups::delete_rule_if_exists(self, rule_id, remove_users).await
+    }
+    async fn activate_rule(&self, rule_id: &str) -> Result<()> {
+        Groups::activate_rule(self, rule_id).await
+    }
+    async fn deactivate_rule(&self, rule_id: &str) -> Result<()> {
+        Groups::deactivate_rule(self, rule_id).await
+    }
+    async fn get(&self, group_id: &str) -> Result<crate::types::Group> {
+        Groups::get(self, group_id).await
+    }
+    async fn update(
+        &self,
+        group_id: &str,
+        body: &crate::types::GroupRequest,
+    ) -> Result<crate::types::Group> {
+        Groups::update(self, group_id, body).await
+    }
+    async fn delete(&self, group_id: &str) -> Result<()> {
+        Groups::delete(self, group_id).await
+    }
+    async fn delete_if_exists(&self, group_id: &str) -> Result<bool> {
+        Groups::delete_if_exists(self, group_id).await
+    }
+    async fn list_assigned_applications_fors(
+        &self,
+        group_id: &str,
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::Application>> {
+        Groups::list_assigned_applications_fors(self, group_id, after, limit).await
+    }
+    async fn list_all_assigned_applications_fors(
+        &self,
+        group_id: &str,
+    ) -> Result<Vec<crate::types::Application>> {
+        Groups::list_all_assigned_applications_fors(self, group_id).await
+    }
+    async fn list_assigned_roles(
+        &self,
+        group_id: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::Role>> {
+        Groups::list_assigned_roles(self, group_id, expand).await
+    }
+    async fn list_all_assigned_roles(
+        &self,
+        group_id: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::Role>> {
+        Groups::list_all_assigned_roles(self, group_id, expand).await
+    }
+    async fn assign_role(
+        &self,
+        group_id: &str,
+        disable_notifications: &str,
+        body: &crate::types::AssignRoleRequest,
+    ) -> Result<crate::types::Role> {
+        Groups::assign_role(self, group_id, disable_notifications, body).await
+    }
+    async fn get_role(&self, group_id: &str, role_id: &str) -> Result<crate::types::Role> {
+        Groups::get_role(self, group_id, role_id).await
+    }
+    async fn remove_role_from(&self, group_id: &str, role_id: &str) -> Result<()> {
+        Groups::remove_role_from(self, group_id, role_id).await
+    }
+    async fn remove_role_from_if_exists(&self, group_id: &str, role_id: &str) -> Result<bool> {
+        Groups::remove_role_from_if_exists(self, group_id, role_id).await
+    }
+    async fn list_application_targets_for_administrator_roles(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::CatalogApplication>> {
+        Groups::list_application_targets_for_administrator_roles(
+            self, group_id, role_id, after, limit,
+        )
+        .await
+    }
+    async fn list_all_application_targets_for_administrator_roles(
+        &self,
+        group_id: &str,
+        role_id: &str,
+    ) -> Result<Vec<crate::types::CatalogApplication>> {
+        Groups::list_all_application_targets_for_administrator_roles(self, group_id, role_id).await
+    }
+    async fn add_application_target_admin_role_given(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        app_name: &str,
+    ) -> Result<()> {
+        Groups::add_application_target_admin_role_given(self, group_id, role_id, app_name).await
+    }
+    async fn remove_application_target_from_administrator_role_given(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        app_name: &str,
+    ) -> Result<()> {
+        Groups::remove_application_target_from_administrator_role_given(
+            self, group_id, role_id, app_name,
+        )
+        .await
+    }
+    async fn remove_application_target_from_administrator_role_given_if_exists(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        app_name: &str,
+    ) -> Result<bool> {
+        Groups::remove_application_target_from_administrator_role_given_if_exists(
+            self, group_id, role_id, app_name,
+        )
+        .await
+    }
+    async fn add_application_instance_target_app_admin_role_given(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        app_name: &str,
+        application_id: &str,
+    ) -> Result<()> {
+        Groups::add_application_instance_target_app_admin_role_given(
+            self,
+            group_id,
+            role_id,
+            app_name,
+            application_id,
+        )
+        .await
+    }
+    async fn remove_application_target_from_administrator_role_given_groups(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        app_name: &str,
+        application_id: &str,
+    ) -> Result<()> {
+        Groups::remove_application_target_from_administrator_role_given_groups(
+            self,
+            group_id,
+            role_id,
+            app_name,
+            application_id,
+        )
+        .await
+    }
+    async fn remove_application_target_from_administrator_role_given_groups_if_exists(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        app_name: &str,
+        application_id: &str,
+    ) -> Result<bool> {
+        Groups::remove_application_target_from_administrator_role_given_groups_if_exists(
+            self,
+            group_id,
+            role_id,
+            app_name,
+            application_id,
+        )
+        .await
+    }
+    async fn list_targets_for_roles(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::Group>> {
+        Groups::list_targets_for_roles(self, group_id, role_id, after, limit).await
+    }
+    async fn list_all_targets_for_roles(
+        &self,
+        group_id: &str,
+        role_id: &str,
+    ) -> Result<Vec<crate::types::Group>> {
+        Groups::list_all_targets_for_roles(self, group_id, role_id).await
+    }
+    async fn add_target_administrator_role_for(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        target_group_id: &str,
+    ) -> Result<()> {
+        Groups::add_target_administrator_role_for(self, group_id, role_id, target_group_id).await
+    }
+    async fn remove_target_from_administrator_role_given(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        target_group_id: &str,
+    ) -> Result<()> {
+        Groups::remove_target_from_administrator_role_given(
+            self,
+            group_id,
+            role_id,
+            target_group_id,
+        )
+        .await
+    }
+    async fn remove_target_from_administrator_role_given_if_exists(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        target_group_id: &str,
+    ) -> Result<bool> {
+        Groups::remove_target_from_administrator_role_given_if_exists(
+            self,
+            group_id,
+            role_id,
+            target_group_id,
+        )
+        .await
+    }
+    async fn list_users(
+        &self,
+        group_id: &str,
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::User>> {
+        Groups::list_users(self, group_id, after, limit).await
+    }
+    async fn list_all_users(&self, group_id: &str) -> Result<Vec<crate::types::User>> {
+        Groups::list_all_users(self, group_id).await
+    }
+    async fn add_user(&self, group_id: &str, user_id: &str) -> Result<()> {
+        Groups::add_user(self, group_id, user_id).await
+    }
+    async fn remove_user_from(&self, group_id: &str, user_id: &str) -> Result<()> {
+        Groups::remove_user_from(self, group_id, user_id).await
+    }
+    async fn remove_user_from_if_exists(&self, group_id: &str, user_id: &str) -> Result<bool> {
+        Groups::remove_user_from_if_exists(self, group_id, user_id).await
     }
 }
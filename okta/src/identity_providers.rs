@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct IdentityProviders {
     pub client: Client,
@@ -12,6 +10,14 @@ impl IdentityProviders {
         IdentityProviders { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        IdentityProviders {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * List Identity Providers.
      *
@@ -33,23 +39,25 @@ impl IdentityProviders {
         limit: i64,
         type_: &str,
     ) -> Result<Vec<crate::types::IdentityProvider>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/idps?{}", query_);
+        let url = crate::Client::url_with_query("/api/v1/idps", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/api/v1/idps", &[]))
     }
 
     /**
@@ -66,17 +74,19 @@ impl IdentityProviders {
         q: &str,
         type_: &str,
     ) -> Result<Vec<crate::types::IdentityProvider>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/idps?{}", query_);
+        let url = crate::Client::url_with_query("/api/v1/idps", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/api/v1/idps", &[]))
     }
 
     /**
@@ -88,12 +98,13 @@ impl IdentityProviders {
      */
     pub async fn create(
         &self,
-        body: &crate::types::IdentityProvider,
+        body: &crate::types::IdentityProviderRequest,
     ) -> Result<crate::types::IdentityProvider> {
         let url = "/api/v1/idps".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/api/v1/idps", &[]))
     }
 
     /**
@@ -113,17 +124,19 @@ impl IdentityProviders {
         after: &str,
         limit: i64,
     ) -> Result<Vec<crate::types::JsonWebKey>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/idps/credentials/keys?{}", query_);
+        let url = crate::Client::url_with_query("/api/v1/idps/credentials/keys", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/api/v1/idps/credentials/keys", &[]))
     }
 
     /**
@@ -137,7 +150,10 @@ impl IdentityProviders {
      */
     pub async fn list_all_keys(&self) -> Result<Vec<crate::types::JsonWebKey>> {
         let url = "/api/v1/idps/credentials/keys".to_string();
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/api/v1/idps/credentials/keys", &[]))
     }
 
     /**
@@ -149,12 +165,13 @@ impl IdentityProviders {
      */
     pub async fn create_key(
         &self,
-        body: &crate::types::JsonWebKey,
+        body: &crate::types::JsonWebKeyRequest,
     ) -> Result<crate::types::JsonWebKey> {
         let url = "/api/v1/idps/credentials/keys".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/api/v1/idps/credentials/keys", &[]))
     }
 
     /**
@@ -171,10 +188,16 @@ impl IdentityProviders {
     pub async fn get_key(&self, key_id: &str) -> Result<crate::types::JsonWebKey> {
         let url = format!(
             "/api/v1/idps/credentials/keys/{}",
-            crate::progenitor_support::encode_path(&key_id.to_string()),
+            self.client.encode_path(key_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/idps/credentials/keys/{keyId}",
+                &[("keyId", key_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -191,10 +214,41 @@ impl IdentityProviders {
     pub async fn delete_key(&self, key_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/idps/credentials/keys/{}",
-            crate::progenitor_support::encode_path(&key_id.to_string()),
+            self.client.encode_path(key_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/idps/credentials/keys/{keyId}",
+                &[("keyId", key_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete Key.
+     *
+     * This function performs a `DELETE` to the `/api/v1/idps/credentials/keys/{keyId}` endpoint.
+     *
+     * As opposed to `delete_key`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_key_if_exists(&self, key_id: &str) -> Result<bool> {
+        let url = format!(
+            "/api/v1/idps/credentials/keys/{}",
+            self.client.encode_path(key_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/idps/credentials/keys/{keyId}",
+                    &[("keyId", key_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -209,12 +263,15 @@ impl IdentityProviders {
      * * `idp_id: &str`
      */
     pub async fn get(&self, idp_id: &str) -> Result<crate::types::IdentityProvider> {
-        let url = format!(
-            "/api/v1/idps/{}",
-            crate::progenitor_support::encode_path(&idp_id.to_string()),
-        );
+        let url = format!("/api/v1/idps/{}", self.client.encode_path(idp_id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/idps/{idpId}",
+                &[("idpId", idp_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -231,16 +288,20 @@ impl IdentityProviders {
     pub async fn update(
         &self,
         idp_id: &str,
-        body: &crate::types::IdentityProvider,
+        body: &crate::types::IdentityProviderRequest,
     ) -> Result<crate::types::IdentityProvider> {
-        let url = format!(
-            "/api/v1/idps/{}",
-            crate::progenitor_support::encode_path(&idp_id.to_string()),
-        );
+        let url = format!("/api/v1/idps/{}", self.client.encode_path(idp_id),);
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/api/v1/idps/{idpId}",
+                    &[("idpId", idp_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -255,12 +316,37 @@ impl IdentityProviders {
      * * `idp_id: &str`
      */
     pub async fn delete(&self, idp_id: &str) -> Result<()> {
-        let url = format!(
-            "/api/v1/idps/{}",
-            crate::progenitor_support::encode_path(&idp_id.to_string()),
-        );
+        let url = format!("/api/v1/idps/{}", self.client.encode_path(idp_id),);
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/idps/{idpId}",
+                &[("idpId", idp_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete Identity Provider.
+     *
+     * This function performs a `DELETE` to the `/api/v1/idps/{idpId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, idp_id: &str) -> Result<bool> {
+        let url = format!("/api/v1/idps/{}", self.client.encode_path(idp_id),);
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/idps/{idpId}",
+                    &[("idpId", idp_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -277,10 +363,16 @@ impl IdentityProviders {
     pub async fn list_csrs_fors(&self, idp_id: &str) -> Result<Vec<crate::types::Csr>> {
         let url = format!(
             "/api/v1/idps/{}/credentials/csrs",
-            crate::progenitor_support::encode_path(&idp_id.to_string()),
+            self.client.encode_path(idp_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/idps/{idpId}/credentials/csrs",
+                &[("idpId", idp_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -295,10 +387,19 @@ impl IdentityProviders {
     pub async fn list_all_csrs_fors(&self, idp_id: &str) -> Result<Vec<crate::types::Csr>> {
         let url = format!(
             "/api/v1/idps/{}/credentials/csrs",
-            crate::progenitor_support::encode_path(&idp_id.to_string()),
+            self.client.encode_path(idp_id),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/api/v1/idps/{idpId}/credentials/csrs",
+                    &[("idpId", idp_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -319,12 +420,19 @@ impl IdentityProviders {
     ) -> Result<crate::types::Csr> {
         let url = format!(
             "/api/v1/idps/{}/credentials/csrs",
-            crate::progenitor_support::encode_path(&idp_id.to_string()),
+            self.client.encode_path(idp_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/api/v1/idps/{idpId}/credentials/csrs",
+                    &[("idpId", idp_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -340,11 +448,17 @@ impl IdentityProviders {
     pub async fn get_csr_for(&self, idp_id: &str, csr_id: &str) -> Result<crate::types::Csr> {
         let url = format!(
             "/api/v1/idps/{}/credentials/csrs/{}",
-            crate::progenitor_support::encode_path(&idp_id.to_string()),
-            crate::progenitor_support::encode_path(&csr_id.to_string()),
+            self.client.encode_path(idp_id),
+            self.client.encode_path(csr_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/idps/{idpId}/credentials/csrs/{csrId}",
+                &[("idpId", idp_id.to_string()), ("csrId", csr_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -360,11 +474,41 @@ impl IdentityProviders {
     pub async fn revoke_csr_for(&self, idp_id: &str, csr_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/idps/{}/credentials/csrs/{}",
-            crate::progenitor_support::encode_path(&idp_id.to_string()),
-            crate::progenitor_support::encode_path(&csr_id.to_string()),
+            self.client.encode_path(idp_id),
+            self.client.encode_path(csr_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/idps/{idpId}/credentials/csrs/{csrId}",
+                &[("idpId", idp_id.to_string()), ("csrId", csr_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/api/v1/idps/{idpId}/credentials/csrs/{csrId}` endpoint.
+     *
+     * As opposed to `revoke_csr_for`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn revoke_csr_for_if_exists(&self, idp_id: &str, csr_id: &str) -> Result<bool> {
+        let url = format!(
+            "/api/v1/idps/{}/credentials/csrs/{}",
+            self.client.encode_path(idp_id),
+            self.client.encode_path(csr_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/idps/{idpId}/credentials/csrs/{csrId}",
+                    &[("idpId", idp_id.to_string()), ("csrId", csr_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -384,11 +528,17 @@ impl IdentityProviders {
     ) -> Result<crate::types::JsonWebKey> {
         let url = format!(
             "/api/v1/idps/{}/credentials/csrs/{}/lifecycle/publish",
-            crate::progenitor_support::encode_path(&idp_id.to_string()),
-            crate::progenitor_support::encode_path(&csr_id.to_string()),
+            self.client.encode_path(idp_id),
+            self.client.encode_path(csr_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/api/v1/idps/{idpId}/credentials/csrs/{csrId}/lifecycle/publish",
+                &[("idpId", idp_id.to_string()), ("csrId", csr_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -405,10 +555,16 @@ impl IdentityProviders {
     pub async fn list_signing_keys(&self, idp_id: &str) -> Result<Vec<crate::types::JsonWebKey>> {
         let url = format!(
             "/api/v1/idps/{}/credentials/keys",
-            crate::progenitor_support::encode_path(&idp_id.to_string()),
+            self.client.encode_path(idp_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/idps/{idpId}/credentials/keys",
+                &[("idpId", idp_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -426,10 +582,19 @@ impl IdentityProviders {
     ) -> Result<Vec<crate::types::JsonWebKey>> {
         let url = format!(
             "/api/v1/idps/{}/credentials/keys",
-            crate::progenitor_support::encode_path(&idp_id.to_string()),
+            self.client.encode_path(idp_id),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/api/v1/idps/{idpId}/credentials/keys",
+                    &[("idpId", idp_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -449,18 +614,25 @@ impl IdentityProviders {
         idp_id: &str,
         validity_years: i64,
     ) -> Result<crate::types::JsonWebKey> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if validity_years > 0 {
-            query_args.push(("validityYears".to_string(), validity_years.to_string()));
+            query_args.push(("validityYears", validity_years.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/idps/{}/credentials/keys/generate?{}",
-            crate::progenitor_support::encode_path(&idp_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/api/v1/idps/{}/credentials/keys/generate",
+                self.client.encode_path(idp_id)
+            ),
+            &query_args,
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/api/v1/idps/{idpId}/credentials/keys/generate",
+                &[("idpId", idp_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -482,11 +654,17 @@ impl IdentityProviders {
     ) -> Result<crate::types::JsonWebKey> {
         let url = format!(
             "/api/v1/idps/{}/credentials/keys/{}",
-            crate::progenitor_support::encode_path(&idp_id.to_string()),
-            crate::progenitor_support::encode_path(&key_id.to_string()),
+            self.client.encode_path(idp_id),
+            self.client.encode_path(key_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/idps/{idpId}/credentials/keys/{keyId}",
+                &[("idpId", idp_id.to_string()), ("keyId", key_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -508,19 +686,26 @@ impl IdentityProviders {
         key_id: &str,
         target_idp_id: &str,
     ) -> Result<crate::types::JsonWebKey> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !target_idp_id.is_empty() {
-            query_args.push(("targetIdpId".to_string(), target_idp_id.to_string()));
+            query_args.push(("targetIdpId", target_idp_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/idps/{}/credentials/keys/{}/clone?{}",
-            crate::progenitor_support::encode_path(&idp_id.to_string()),
-            crate::progenitor_support::encode_path(&key_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/api/v1/idps/{}/credentials/keys/{}/clone",
+                self.client.encode_path(idp_id),
+                self.client.encode_path(key_id)
+            ),
+            &query_args,
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/api/v1/idps/{idpId}/credentials/keys/{keyId}/clone",
+                &[("idpId", idp_id.to_string()), ("keyId", key_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -537,10 +722,16 @@ impl IdentityProviders {
     pub async fn activate(&self, idp_id: &str) -> Result<crate::types::IdentityProvider> {
         let url = format!(
             "/api/v1/idps/{}/lifecycle/activate",
-            crate::progenitor_support::encode_path(&idp_id.to_string()),
+            self.client.encode_path(idp_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/api/v1/idps/{idpId}/lifecycle/activate",
+                &[("idpId", idp_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -557,10 +748,16 @@ impl IdentityProviders {
     pub async fn deactivate(&self, idp_id: &str) -> Result<crate::types::IdentityProvider> {
         let url = format!(
             "/api/v1/idps/{}/lifecycle/deactivate",
-            crate::progenitor_support::encode_path(&idp_id.to_string()),
+            self.client.encode_path(idp_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/api/v1/idps/{idpId}/lifecycle/deactivate",
+                &[("idpId", idp_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -578,12 +775,15 @@ impl IdentityProviders {
         &self,
         idp_id: &str,
     ) -> Result<Vec<crate::types::IdentityProviderApplicationUser>> {
-        let url = format!(
-            "/api/v1/idps/{}/users",
-            crate::progenitor_support::encode_path(&idp_id.to_string()),
-        );
+        let url = format!("/api/v1/idps/{}/users", self.client.encode_path(idp_id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/idps/{idpId}/users",
+                &[("idpId", idp_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -599,12 +799,18 @@ impl IdentityProviders {
         &self,
         idp_id: &str,
     ) -> Result<Vec<crate::types::IdentityProviderApplicationUser>> {
-        let url = format!(
-            "/api/v1/idps/{}/users",
-            crate::progenitor_support::encode_path(&idp_id.to_string()),
-        );
+        let url = format!("/api/v1/idps/{}/users", self.client.encode_path(idp_id),);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/api/v1/idps/{idpId}/users",
+                    &[("idpId", idp_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -624,11 +830,20 @@ impl IdentityProviders {
     ) -> Result<crate::types::IdentityProviderApplicationUser> {
         let url = format!(
             "/api/v1/idps/{}/users/{}",
-            crate::progenitor_support::encode_path(&idp_id.to_string()),
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(idp_id),
+            self.client.encode_path(user_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/idps/{idpId}/users/{userId}",
+                &[
+                    ("idpId", idp_id.to_string()),
+                    ("userId", user_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -651,13 +866,23 @@ impl IdentityProviders {
     ) -> Result<crate::types::IdentityProviderApplicationUser> {
         let url = format!(
             "/api/v1/idps/{}/users/{}",
-            crate::progenitor_support::encode_path(&idp_id.to_string()),
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(idp_id),
+            self.client.encode_path(user_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/api/v1/idps/{idpId}/users/{userId}",
+                    &[
+                        ("idpId", idp_id.to_string()),
+                        ("userId", user_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -675,11 +900,49 @@ impl IdentityProviders {
     pub async fn unlink_user_from(&self, idp_id: &str, user_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/idps/{}/users/{}",
-            crate::progenitor_support::encode_path(&idp_id.to_string()),
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(idp_id),
+            self.client.encode_path(user_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/idps/{idpId}/users/{userId}",
+                &[
+                    ("idpId", idp_id.to_string()),
+                    ("userId", user_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Unlink User from IdP.
+     *
+     * This function performs a `DELETE` to the `/api/v1/idps/{idpId}/users/{userId}` endpoint.
+     *
+     * As opposed to `unlink_user_from`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn unlink_user_from_if_exists(&self, idp_id: &str, user_id: &str) -> Result<bool> {
+        let url = format!(
+            "/api/v1/idps/{}/users/{}",
+            self.client.encode_path(idp_id),
+            self.client.encode_path(user_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/idps/{idpId}/users/{userId}",
+                    &[
+                        ("idpId", idp_id.to_string()),
+                        ("userId", user_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -701,11 +964,20 @@ impl IdentityProviders {
     ) -> Result<Vec<crate::types::SocialAuthToken>> {
         let url = format!(
             "/api/v1/idps/{}/users/{}/credentials/tokens",
-            crate::progenitor_support::encode_path(&idp_id.to_string()),
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(idp_id),
+            self.client.encode_path(user_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/idps/{idpId}/users/{userId}/credentials/tokens",
+                &[
+                    ("idpId", idp_id.to_string()),
+                    ("userId", user_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -724,10 +996,292 @@ impl IdentityProviders {
     ) -> Result<Vec<crate::types::SocialAuthToken>> {
         let url = format!(
             "/api/v1/idps/{}/users/{}/credentials/tokens",
-            crate::progenitor_support::encode_path(&idp_id.to_string()),
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(idp_id),
+            self.client.encode_path(user_id),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/api/v1/idps/{idpId}/users/{userId}/credentials/tokens",
+                    &[
+                        ("idpId", idp_id.to_string()),
+                        ("userId", user_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`IdentityProviders`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn IdentityProvidersApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait IdentityProvidersApi: Send + Sync {
+    async fn list(
+        &self,
+        q: &str,
+        after: &str,
+        limit: i64,
+        type_: &str,
+    ) -> Result<Vec<crate::types::IdentityProvider>>;
+    async fn list_all(&self, q: &str, type_: &str) -> Result<Vec<crate::types::IdentityProvider>>;
+    async fn create(
+        &self,
+        body: &crate::types::IdentityProviderRequest,
+    ) -> Result<crate::types::IdentityProvider>;
+    async fn list_keys(&self, after: &str, limit: i64) -> Result<Vec<crate::types::JsonWebKey>>;
+    async fn list_all_keys(&self) -> Result<Vec<crate::types::JsonWebKey>>;
+    async fn create_key(
+        &self,
+        body: &crate::types::JsonWebKeyRequest,
+    ) -> Result<crate::types::JsonWebKey>;
+    async fn get_key(&self, key_id: &str) -> Result<crate::types::JsonWebKey>;
+    async fn delete_key(&self, key_id: &str) -> Result<()>;
+    async fn delete_key_if_exists(&self, key_id: &str) -> Result<bool>;
+    async fn get(&self, idp_id: &str) -> Result<crate::types::IdentityProvider>;
+    async fn update(
+        &self,
+        idp_id: &str,
+        body: &crate::types::IdentityProviderRequest,
+    ) -> Result<crate::types::IdentityProvider>;
+    async fn delete(&self, idp_id: &str) -> Result<()>;
+    async fn delete_if_exists(&self, idp_id: &str) -> Result<bool>;
+    async fn list_csrs_fors(&self, idp_id: &str) -> Result<Vec<crate::types::Csr>>;
+    async fn list_all_csrs_fors(&self, idp_id: &str) -> Result<Vec<crate::types::Csr>>;
+    async fn generate_csr_for(
+        &self,
+        idp_id: &str,
+        body: &crate::types::CsrMetadata,
+    ) -> Result<crate::types::Csr>;
+    async fn get_csr_for(&self, idp_id: &str, csr_id: &str) -> Result<crate::types::Csr>;
+    async fn revoke_csr_for(&self, idp_id: &str, csr_id: &str) -> Result<()>;
+    async fn revoke_csr_for_if_exists(&self, idp_id: &str, csr_id: &str) -> Result<bool>;
+    async fn post_idp_credentials_csr_lifecycle_publish(
+        &self,
+        idp_id: &str,
+        csr_id: &str,
+    ) -> Result<crate::types::JsonWebKey>;
+    async fn list_signing_keys(&self, idp_id: &str) -> Result<Vec<crate::types::JsonWebKey>>;
+    async fn list_all_signing_keys(&self, idp_id: &str) -> Result<Vec<crate::types::JsonWebKey>>;
+    async fn generate_signing_key(
+        &self,
+        idp_id: &str,
+        validity_years: i64,
+    ) -> Result<crate::types::JsonWebKey>;
+    async fn get_signing_key(&self, idp_id: &str, key_id: &str)
+        -> Result<crate::types::JsonWebKey>;
+    async fn clone_key(
+        &self,
+        idp_id: &str,
+        key_id: &str,
+        target_idp_id: &str,
+    ) -> Result<crate::types::JsonWebKey>;
+    async fn activate(&self, idp_id: &str) -> Result<crate::types::IdentityProvider>;
+    async fn deactivate(&self, idp_id: &str) -> Result<crate::types::IdentityProvider>;
+    async fn list_application_users(
+        &self,
+        idp_id: &str,
+    ) -> Result<Vec<crate::types::IdentityProviderApplicationUser>>;
+    async fn list_all_application_users(
+        &self,
+        idp_id: &str,
+    ) -> Result<Vec<crate::types::IdentityProviderApplicationUser>>;
+    async fn get_application_user(
+        &self,
+        idp_id: &str,
+        user_id: &str,
+    ) -> Result<crate::types::IdentityProviderApplicationUser>;
+    async fn link_user(
+        &self,
+        idp_id: &str,
+        user_id: &str,
+        body: &crate::types::UserIdentityProviderLinkRequest,
+    ) -> Result<crate::types::IdentityProviderApplicationUser>;
+    async fn unlink_user_from(&self, idp_id: &str, user_id: &str) -> Result<()>;
+    async fn unlink_user_from_if_exists(&self, idp_id: &str, user_id: &str) -> Result<bool>;
+    async fn list_social_auth_tokens(
+        &self,
+        idp_id: &str,
+        user_id: &str,
+    ) -> Result<Vec<crate::types::SocialAuthToken>>;
+    async fn list_all_social_auth_tokens(
+        &self,
+        idp_id: &str,
+        user_id: &str,
+    ) -> Result<Vec<crate::types::SocialAuthToken>>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl IdentityProvidersApi for IdentityProviders {
+    async fn list(
+        &self,
+        q: &str,
+        after: &str,
+        limit: i64,
+        type_: &str,
+    ) -> Result<Vec<crate::types::IdentityProvider>> {
+        IdentityProviders::list(self, q, after, limit, type_).await
+    }
+    async fn list_all(&self, q: &str, type_: &str) -> Result<Vec<crate::types::IdentityProvider>> {
+        IdentityProviders::list_all(self, q, type_).await
+    }
+    async fn create(
+        &self,
+        body: &crate::types::IdentityProviderRequest,
+    ) -> Result<crate::types::IdentityProvider> {
+        IdentityProviders::create(self, body).await
+    }
+    async fn list_keys(&self, after: &str, limit: i64) -> Result<Vec<crate::types::JsonWebKey>> {
+        IdentityProviders::list_keys(self, after, limit).await
+    }
+    async fn list_all_keys(&self) -> Result<Vec<crate::types::JsonWebKey>> {
+        IdentityProviders::list_all_keys(self).await
+    }
+    async fn create_key(
+        &self,
+        body: &crate::types::JsonWebKeyRequest,
+    ) -> Result<crate::types::JsonWebKey> {
+        IdentityProviders::create_key(self, body).await
+    }
+    async fn get_key(&self, key_id: &str) -> Result<crate::types::JsonWebKey> {
+        IdentityProviders::get_key(self, key_id).await
+    }
+    async fn delete_key(&self, key_id: &str) -> Result<()> {
+        IdentityProviders::delete_key(self, key_id).await
+    }
+    async fn delete_key_if_exists(&self, key_id: &str) -> Result<bool> {
+        IdentityProviders::delete_key_if_exists(self, key_id).await
+    }
+    async fn get(&self, idp_id: &str) -> Result<crate::types::IdentityProvider> {
+        IdentityProviders::get(self, idp_id).await
+    }
+    async fn update(
+        &self,
+        idp_id: &str,
+        body: &crate::types::IdentityProviderRequest,
+    ) -> Result<crate::types::IdentityProvider> {
+        IdentityProviders::update(self, idp_id, body).await
+    }
+    async fn delete(&self, idp_id: &str) -> Result<()> {
+        IdentityProviders::delete(self, idp_id).await
+    }
+    async fn delete_if_exists(&self, idp_id: &str) -> Result<bool> {
+        IdentityProviders::delete_if_exists(self, idp_id).await
+    }
+    async fn list_csrs_fors(&self, idp_id: &str) -> Result<Vec<crate::types::Csr>> {
+        IdentityProviders::list_csrs_fors(self, idp_id).await
+    }
+    async fn list_all_csrs_fors(&self, idp_id: &str) -> Result<Vec<crate::types::Csr>> {
+        IdentityProviders::list_all_csrs_fors(self, idp_id).await
+    }
+    async fn generate_csr_for(
+        &self,
+        idp_id: &str,
+        body: &crate::types::CsrMetadata,
+    ) -> Result<crate::types::Csr> {
+        IdentityProviders::generate_csr_for(self, idp_id, body).await
+    }
+    async fn get_csr_for(&self, idp_id: &str, csr_id: &str) -> Result<crate::types::Csr> {
+        IdentityProviders::get_csr_for(self, idp_id, csr_id).await
+    }
+    async fn revoke_csr_for(&self, idp_id: &str, csr_id: &str) -> Result<()> {
+        IdentityProviders::revoke_csr_for(self, idp_id, csr_id).await
+    }
+    async fn revoke_csr_for_if_exists(&self, idp_id: &str, csr_id: &str) -> Result<bool> {
+        IdentityProviders::revoke_csr_for_if_exists(self, idp_id, csr_id).await
+    }
+    async fn post_idp_credentials_csr_lifecycle_publish(
+        &self,
+        idp_id: &str,
+        csr_id: &str,
+    ) -> Result<crate::types::JsonWebKey> {
+        IdentityProviders::post_idp_credentials_csr_lifecycle_publish(self, idp_id, csr_id).await
+    }
+    async fn list_signing_keys(&self, idp_id: &str) -> Result<Vec<crate::types::JsonWebKey>> {
+        IdentityProviders::list_signing_keys(self, idp_id).await
+    }
+    async fn list_all_signing_keys(&self, idp_id: &str) -> Result<Vec<crate::types::JsonWebKey>> {
+        IdentityProviders::list_all_signing_keys(self, idp_id).await
+    }
+    async fn generate_signing_key(
+        &self,
+        idp_id: &str,
+        validity_years: i64,
+    ) -> Result<crate::types::JsonWebKey> {
+        IdentityProviders::generate_signing_key(self, idp_id, validity_years).await
+    }
+    async fn get_signing_key(
+        &self,
+        idp_id: &str,
+        key_id: &str,
+    ) -> Result<crate::types::JsonWebKey> {
+        IdentityProviders::get_signing_key(self, idp_id, key_id).await
+    }
+    async fn clone_key(
+        &self,
+        idp_id: &str,
+        key_id: &str,
+        target_idp_id: &str,
+    ) -> Result<crate::types::JsonWebKey> {
+        IdentityProviders::clone_key(self, idp_id, key_id, target_idp_id).await
+    }
+    async fn activate(&self, idp_id: &str) -> Result<crate::types::IdentityProvider> {
+        IdentityProviders::activate(self, idp_id).await
+    }
+    async fn deactivate(&self, idp_id: &str) -> Result<crate::types::IdentityProvider> {
+        IdentityProviders::deactivate(self, idp_id).await
+    }
+    async fn list_application_users(
+        &self,
+        idp_id: &str,
+    ) -> Result<Vec<crate::types::IdentityProviderApplicationUser>> {
+        IdentityProviders::list_application_users(self, idp_id).await
+    }
+    async fn list_all_application_users(
+        &self,
+        idp_id: &str,
+    ) -> Result<Vec<crate::types::IdentityProviderApplicationUser>> {
+        IdentityProviders::list_all_application_users(self, idp_id).await
+    }
+    async fn get_application_user(
+        &self,
+        idp_id: &str,
+        user_id: &str,
+    ) -> Result<crate::types::IdentityProviderApplicationUser> {
+        IdentityProviders::get_application_user(self, idp_id, user_id).await
+    }
+    async fn link_user(
+        &self,
+        idp_id: &str,
+        user_id: &str,
+        body: &crate::types::UserIdentityProviderLinkRequest,
+    ) -> Result<crate::types::IdentityProviderApplicationUser> {
+        IdentityProviders::link_user(self, idp_id, user_id, body).await
+    }
+    async fn unlink_user_from(&self, idp_id: &str, user_id: &str) -> Result<()> {
+        IdentityProviders::unlink_user_from(self, idp_id, user_id).await
+    }
+    async fn unlink_user_from_if_exists(&self, idp_id: &str, user_id: &str) -> Result<bool> {
+        IdentityProviders::unlink_user_from_if_exists(self, idp_id, user_id).await
+    }
+    async fn list_social_auth_tokens(
+        &self,
+        idp_id: &str,
+        user_id: &str,
+    ) -> Result<Vec<crate::types::SocialAuthToken>> {
+        IdentityProviders::list_social_auth_tokens(self, idp_id, user_id).await
+    }
+    async fn list_all_social_auth_tokens(
+        &self,
+        idp_id: &str,
+        user_id: &str,
+    ) -> Result<Vec<crate::types::SocialAuthToken>> {
+        IdentityProviders::list_all_social_auth_tokens(self, idp_id, user_id).await
     }
 }
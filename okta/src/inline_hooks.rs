@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct InlineHooks {
     pub client: Client,
@@ -12,6 +10,14 @@ impl InlineHooks {
         InlineHooks { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        InlineHooks {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/inlineHooks` endpoint.
      *
@@ -22,14 +28,16 @@ impl InlineHooks {
      * * `type_: &str`
      */
     pub async fn list(&self, type_: &str) -> Result<Vec<crate::types::InlineHook>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/inlineHooks?{}", query_);
+        let url = crate::Client::url_with_query("/api/v1/inlineHooks", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/api/v1/inlineHooks", &[]))
     }
 
     /**
@@ -40,14 +48,16 @@ impl InlineHooks {
      * Success
      */
     pub async fn list_all(&self, type_: &str) -> Result<Vec<crate::types::InlineHook>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/inlineHooks?{}", query_);
+        let url = crate::Client::url_with_query("/api/v1/inlineHooks", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/api/v1/inlineHooks", &[]))
     }
 
     /**
@@ -57,12 +67,13 @@ impl InlineHooks {
      */
     pub async fn create(
         &self,
-        body: &crate::types::InlineHook,
+        body: &crate::types::InlineHookRequest,
     ) -> Result<crate::types::InlineHook> {
         let url = "/api/v1/inlineHooks".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/api/v1/inlineHooks", &[]))
     }
 
     /**
@@ -77,10 +88,16 @@ impl InlineHooks {
     pub async fn get(&self, inline_hook_id: &str) -> Result<crate::types::InlineHook> {
         let url = format!(
             "/api/v1/inlineHooks/{}",
-            crate::progenitor_support::encode_path(&inline_hook_id.to_string()),
+            self.client.encode_path(inline_hook_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/inlineHooks/{inlineHookId}",
+                &[("inlineHookId", inline_hook_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -95,16 +112,23 @@ impl InlineHooks {
     pub async fn update(
         &self,
         inline_hook_id: &str,
-        body: &crate::types::InlineHook,
+        body: &crate::types::InlineHookRequest,
     ) -> Result<crate::types::InlineHook> {
         let url = format!(
             "/api/v1/inlineHooks/{}",
-            crate::progenitor_support::encode_path(&inline_hook_id.to_string()),
+            self.client.encode_path(inline_hook_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/api/v1/inlineHooks/{inlineHookId}",
+                    &[("inlineHookId", inline_hook_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -119,10 +143,39 @@ impl InlineHooks {
     pub async fn delete(&self, inline_hook_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/inlineHooks/{}",
-            crate::progenitor_support::encode_path(&inline_hook_id.to_string()),
+            self.client.encode_path(inline_hook_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/inlineHooks/{inlineHookId}",
+                &[("inlineHookId", inline_hook_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/api/v1/inlineHooks/{inlineHookId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, inline_hook_id: &str) -> Result<bool> {
+        let url = format!(
+            "/api/v1/inlineHooks/{}",
+            self.client.encode_path(inline_hook_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/inlineHooks/{inlineHookId}",
+                    &[("inlineHookId", inline_hook_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -141,12 +194,19 @@ impl InlineHooks {
     ) -> Result<crate::types::InlineHookResponse> {
         let url = format!(
             "/api/v1/inlineHooks/{}/execute",
-            crate::progenitor_support::encode_path(&inline_hook_id.to_string()),
+            self.client.encode_path(inline_hook_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/api/v1/inlineHooks/{inlineHookId}/execute",
+                    &[("inlineHookId", inline_hook_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -161,10 +221,16 @@ impl InlineHooks {
     pub async fn activate(&self, inline_hook_id: &str) -> Result<crate::types::InlineHook> {
         let url = format!(
             "/api/v1/inlineHooks/{}/lifecycle/activate",
-            crate::progenitor_support::encode_path(&inline_hook_id.to_string()),
+            self.client.encode_path(inline_hook_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/api/v1/inlineHooks/{inlineHookId}/lifecycle/activate",
+                &[("inlineHookId", inline_hook_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -179,9 +245,90 @@ impl InlineHooks {
     pub async fn deactivate(&self, inline_hook_id: &str) -> Result<crate::types::InlineHook> {
         let url = format!(
             "/api/v1/inlineHooks/{}/lifecycle/deactivate",
-            crate::progenitor_support::encode_path(&inline_hook_id.to_string()),
+            self.client.encode_path(inline_hook_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/api/v1/inlineHooks/{inlineHookId}/lifecycle/deactivate",
+                &[("inlineHookId", inline_hook_id.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`InlineHooks`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn InlineHooksApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait InlineHooksApi: Send + Sync {
+    async fn list(&self, type_: &str) -> Result<Vec<crate::types::InlineHook>>;
+    async fn list_all(&self, type_: &str) -> Result<Vec<crate::types::InlineHook>>;
+    async fn create(
+        &self,
+        body: &crate::types::InlineHookRequest,
+    ) -> Result<crate::types::InlineHook>;
+    async fn get(&self, inline_hook_id: &str) -> Result<crate::types::InlineHook>;
+    async fn update(
+        &self,
+        inline_hook_id: &str,
+        body: &crate::types::InlineHookRequest,
+    ) -> Result<crate::types::InlineHook>;
+    async fn delete(&self, inline_hook_id: &str) -> Result<()>;
+    async fn delete_if_exists(&self, inline_hook_id: &str) -> Result<bool>;
+    async fn execute(
+        &self,
+        inline_hook_id: &str,
+        body: &crate::types::Links,
+    ) -> Result<crate::types::InlineHookResponse>;
+    async fn activate(&self, inline_hook_id: &str) -> Result<crate::types::InlineHook>;
+    async fn deactivate(&self, inline_hook_id: &str) -> Result<crate::types::InlineHook>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl InlineHooksApi for InlineHooks {
+    async fn list(&self, type_: &str) -> Result<Vec<crate::types::InlineHook>> {
+        InlineHooks::list(self, type_).await
+    }
+    async fn list_all(&self, type_: &str) -> Result<Vec<crate::types::InlineHook>> {
+        InlineHooks::list_all(self, type_).await
+    }
+    async fn create(
+        &self,
+        body: &crate::types::InlineHookRequest,
+    ) -> Result<crate::types::InlineHook> {
+        InlineHooks::create(self, body).await
+    }
+    async fn get(&self, inline_hook_id: &str) -> Result<crate::types::InlineHook> {
+        InlineHooks::get(self, inline_hook_id).await
+    }
+    async fn update(
+        &self,
+        inline_hook_id: &str,
+        body: &crate::types::InlineHookRequest,
+    ) -> Result<crate::types::InlineHook> {
+        InlineHooks::update(self, inline_hook_id, body).await
+    }
+    async fn delete(&self, inline_hook_id: &str) -> Result<()> {
+        InlineHooks::delete(self, inline_hook_id).await
+    }
+    async fn delete_if_exists(&self, inline_hook_id: &str) -> Result<bool> {
+        InlineHooks::delete_if_exists(self, inline_hook_id).await
+    }
+    async fn execute(
+        &self,
+        inline_hook_id: &str,
+        body: &crate::types::Links,
+    ) -> Result<crate::types::InlineHookResponse> {
+        InlineHooks::execute(self, inline_hook_id, body).await
+    }
+    async fn activate(&self, inline_hook_id: &str) -> Result<crate::types::InlineHook> {
+        InlineHooks::activate(self, inline_hook_id).await
+    }
+    async fn deactivate(&self, inline_hook_id: &str) -> Result<crate::types::InlineHook> {
+        InlineHooks::deactivate(self, inline_hook_id).await
     }
 }
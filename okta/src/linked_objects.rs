@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct LinkedObjects {
     pub client: Client,
@@ -12,6 +10,14 @@ impl LinkedObjects {
         LinkedObjects { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        LinkedObjects {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/meta/schemas/user/linkedObjects` endpoint.
      *
@@ -19,7 +25,9 @@ impl LinkedObjects {
      */
     pub async fn list_definitions(&self) -> Result<Vec<crate::types::LinkedObject>> {
         let url = "/api/v1/meta/schemas/user/linkedObjects".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/api/v1/meta/schemas/user/linkedObjects", &[])
+        })
     }
 
     /**
@@ -31,7 +39,12 @@ impl LinkedObjects {
      */
     pub async fn list_all_definitions(&self) -> Result<Vec<crate::types::LinkedObject>> {
         let url = "/api/v1/meta/schemas/user/linkedObjects".to_string();
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url("GET", "/api/v1/meta/schemas/user/linkedObjects", &[])
+            })
     }
 
     /**
@@ -41,12 +54,15 @@ impl LinkedObjects {
      */
     pub async fn add_definition(
         &self,
-        body: &crate::types::LinkedObject,
+        body: &crate::types::LinkedObjectRequest,
     ) -> Result<crate::types::LinkedObject> {
         let url = "/api/v1/meta/schemas/user/linkedObjects".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/api/v1/meta/schemas/user/linkedObjects", &[])
+            })
     }
 
     /**
@@ -64,10 +80,16 @@ impl LinkedObjects {
     ) -> Result<crate::types::LinkedObject> {
         let url = format!(
             "/api/v1/meta/schemas/user/linkedObjects/{}",
-            crate::progenitor_support::encode_path(&linked_object_name.to_string()),
+            self.client.encode_path(linked_object_name),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/meta/schemas/user/linkedObjects/{linkedObjectName}",
+                &[("linkedObjectName", linked_object_name.to_string())],
+            )
+        })
     }
 
     /**
@@ -82,9 +104,81 @@ impl LinkedObjects {
     pub async fn delete_definition(&self, linked_object_name: &str) -> Result<()> {
         let url = format!(
             "/api/v1/meta/schemas/user/linkedObjects/{}",
-            crate::progenitor_support::encode_path(&linked_object_name.to_string()),
+            self.client.encode_path(linked_object_name),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/meta/schemas/user/linkedObjects/{linkedObjectName}",
+                &[("linkedObjectName", linked_object_name.to_string())],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/api/v1/meta/schemas/user/linkedObjects/{linkedObjectName}` endpoint.
+     *
+     * As opposed to `delete_definition`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_definition_if_exists(&self, linked_object_name: &str) -> Result<bool> {
+        let url = format!(
+            "/api/v1/meta/schemas/user/linkedObjects/{}",
+            self.client.encode_path(linked_object_name),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/meta/schemas/user/linkedObjects/{linkedObjectName}",
+                    &[("linkedObjectName", linked_object_name.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`LinkedObjects`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn LinkedObjectsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait LinkedObjectsApi: Send + Sync {
+    async fn list_definitions(&self) -> Result<Vec<crate::types::LinkedObject>>;
+    async fn list_all_definitions(&self) -> Result<Vec<crate::types::LinkedObject>>;
+    async fn add_definition(
+        &self,
+        body: &crate::types::LinkedObjectRequest,
+    ) -> Result<crate::types::LinkedObject>;
+    async fn get_definition(&self, linked_object_name: &str) -> Result<crate::types::LinkedObject>;
+    async fn delete_definition(&self, linked_object_name: &str) -> Result<()>;
+    async fn delete_definition_if_exists(&self, linked_object_name: &str) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl LinkedObjectsApi for LinkedObjects {
+    async fn list_definitions(&self) -> Result<Vec<crate::types::LinkedObject>> {
+        LinkedObjects::list_definitions(self).await
+    }
+    async fn list_all_definitions(&self) -> Result<Vec<crate::types::LinkedObject>> {
+        LinkedObjects::list_all_definitions(self).await
+    }
+    async fn add_definition(
+        &self,
+        body: &crate::types::LinkedObjectRequest,
+    ) -> Result<crate::types::LinkedObject> {
+        LinkedObjects::add_definition(self, body).await
+    }
+    async fn get_definition(&self, linked_object_name: &str) -> Result<crate::types::LinkedObject> {
+        LinkedObjects::get_definition(self, linked_object_name).await
+    }
+    async fn delete_definition(&self, linked_object_name: &str) -> Result<()> {
+        LinkedObjects::delete_definition(self, linked_object_name).await
+    }
+    async fn delete_definition_if_exists(&self, linked_object_name: &str) -> Result<bool> {
+        LinkedObjects::delete_definition_if_exists(self, linked_object_name).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Logs {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Logs {
         Logs { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Logs {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Fetch a list of events from your Okta organization system log.
      *
@@ -39,32 +45,83 @@ impl Logs {
         sort_order: &str,
         after: &str,
     ) -> Result<Vec<crate::types::LogEvent>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !after.is_empty() {
+            query_args.push(("after", after.to_string()));
+        }
+        if !filter.is_empty() {
+            query_args.push(("filter", filter.to_string()));
+        }
+        if limit > 0 {
+            query_args.push(("limit", limit.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q", q.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since", date.to_rfc3339()));
+        }
+        if !sort_order.is_empty() {
+            query_args.push(("sortOrder", sort_order.to_string()));
+        }
+        if let Some(date) = until {
+            query_args.push(("until", date.to_rfc3339()));
+        }
+        let url = crate::Client::url_with_query("/api/v1/logs", &query_args);
+
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/api/v1/logs", &[]))
+    }
+
+    /**
+     * Fetch a list of events from your Okta organization system log.
+     *
+     * This function performs a `GET` to the `/api/v1/logs` endpoint.
+     *
+     * As opposed to `get_page`, this function streams the items as they are parsed from the response, rather than returning them all at once.
+     * This is useful for very large responses that would otherwise need to be held in memory.
+     * Only the one page the parameters ask for is streamed, the next pages are not fetched.
+     */
+    pub async fn get_page_stream_json(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        until: Option<chrono::DateTime<chrono::Utc>>,
+        filter: &str,
+        q: &str,
+        limit: i64,
+        sort_order: &str,
+        after: &str,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::LogEvent>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
         if !sort_order.is_empty() {
-            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+            query_args.push(("sortOrder", sort_order.to_string()));
         }
         if let Some(date) = until {
-            query_args.push(("until".to_string(), date.to_rfc3339()));
+            query_args.push(("until", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/logs?{}", query_);
+        let url = crate::Client::url_with_query("/api/v1/logs", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get_stream_json(&url, "")
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/api/v1/logs", &[]))
     }
 
     /**
@@ -84,25 +141,80 @@ impl Logs {
         q: &str,
         sort_order: &str,
     ) -> Result<Vec<crate::types::LogEvent>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+            query_args.push(("since", date.to_rfc3339()));
         }
         if !sort_order.is_empty() {
-            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+            query_args.push(("sortOrder", sort_order.to_string()));
         }
         if let Some(date) = until {
-            query_args.push(("until".to_string(), date.to_rfc3339()));
+            query_args.push(("until", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/logs?{}", query_);
+        let url = crate::Client::url_with_query("/api/v1/logs", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/api/v1/logs", &[]))
+    }
+}
+
+/// The functions of [`Logs`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn LogsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait LogsApi: Send + Sync {
+    async fn get_page(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        until: Option<chrono::DateTime<chrono::Utc>>,
+        filter: &str,
+        q: &str,
+        limit: i64,
+        sort_order: &str,
+        after: &str,
+    ) -> Result<Vec<crate::types::LogEvent>>;
+    async fn get_all(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        until: Option<chrono::DateTime<chrono::Utc>>,
+        filter: &str,
+        q: &str,
+        sort_order: &str,
+    ) -> Result<Vec<crate::types::LogEvent>>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl LogsApi for Logs {
+    async fn get_page(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        until: Option<chrono::DateTime<chrono::Utc>>,
+        filter: &str,
+        q: &str,
+        limit: i64,
+        sort_order: &str,
+        after: &str,
+    ) -> Result<Vec<crate::types::LogEvent>> {
+        Logs::get_page(self, since, until, filter, q, limit, sort_order, after).await
+    }
+    async fn get_all(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        until: Option<chrono::DateTime<chrono::Utc>>,
+        filter: &str,
+        q: &str,
+        sort_order: &str,
+    ) -> Result<Vec<crate::types::LogEvent>> {
+        Logs::get_all(self, since, until, filter, q, sort_order).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct NetworkZones {
     pub client: Client,
@@ -12,6 +10,14 @@ impl NetworkZones {
         NetworkZones { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        NetworkZones {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * List Network Zones.
      *
@@ -31,20 +37,22 @@ impl NetworkZones {
         limit: i64,
         filter: &str,
     ) -> Result<Vec<crate::types::NetworkZone>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/zones?{}", query_);
+        let url = crate::Client::url_with_query("/api/v1/zones", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/api/v1/zones", &[]))
     }
 
     /**
@@ -57,14 +65,16 @@ impl NetworkZones {
      * Enumerates network zones added to your organization with pagination. A subset of zones can be returned that match a supported filter expression or query.
      */
     pub async fn list_all(&self, filter: &str) -> Result<Vec<crate::types::NetworkZone>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/zones?{}", query_);
+        let url = crate::Client::url_with_query("/api/v1/zones", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/api/v1/zones", &[]))
     }
 
     /**
@@ -76,12 +86,13 @@ impl NetworkZones {
      */
     pub async fn create(
         &self,
-        body: &crate::types::NetworkZone,
+        body: &crate::types::NetworkZoneRequest,
     ) -> Result<crate::types::NetworkZone> {
         let url = "/api/v1/zones".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/api/v1/zones", &[]))
     }
 
     /**
@@ -96,12 +107,15 @@ impl NetworkZones {
      * * `zone_id: &str`
      */
     pub async fn get(&self, zone_id: &str) -> Result<crate::types::NetworkZone> {
-        let url = format!(
-            "/api/v1/zones/{}",
-            crate::progenitor_support::encode_path(&zone_id.to_string()),
-        );
+        let url = format!("/api/v1/zones/{}", self.client.encode_path(zone_id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/zones/{zoneId}",
+                &[("zoneId", zone_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -118,16 +132,20 @@ impl NetworkZones {
     pub async fn update(
         &self,
         zone_id: &str,
-        body: &crate::types::NetworkZone,
+        body: &crate::types::NetworkZoneRequest,
     ) -> Result<crate::types::NetworkZone> {
-        let url = format!(
-            "/api/v1/zones/{}",
-            crate::progenitor_support::encode_path(&zone_id.to_string()),
-        );
+        let url = format!("/api/v1/zones/{}", self.client.encode_path(zone_id),);
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/api/v1/zones/{zoneId}",
+                    &[("zoneId", zone_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -142,12 +160,37 @@ impl NetworkZones {
      * * `zone_id: &str`
      */
     pub async fn delete(&self, zone_id: &str) -> Result<()> {
-        let url = format!(
-            "/api/v1/zones/{}",
-            crate::progenitor_support::encode_path(&zone_id.to_string()),
-        );
+        let url = format!("/api/v1/zones/{}", self.client.encode_path(zone_id),);
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/zones/{zoneId}",
+                &[("zoneId", zone_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete Network Zone.
+     *
+     * This function performs a `DELETE` to the `/api/v1/zones/{zoneId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, zone_id: &str) -> Result<bool> {
+        let url = format!("/api/v1/zones/{}", self.client.encode_path(zone_id),);
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/zones/{zoneId}",
+                    &[("zoneId", zone_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -164,10 +207,16 @@ impl NetworkZones {
     pub async fn activate(&self, zone_id: &str) -> Result<crate::types::NetworkZone> {
         let url = format!(
             "/api/v1/zones/{}/lifecycle/activate",
-            crate::progenitor_support::encode_path(&zone_id.to_string()),
+            self.client.encode_path(zone_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/api/v1/zones/{zoneId}/lifecycle/activate",
+                &[("zoneId", zone_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -184,9 +233,88 @@ impl NetworkZones {
     pub async fn deactivate(&self, zone_id: &str) -> Result<crate::types::NetworkZone> {
         let url = format!(
             "/api/v1/zones/{}/lifecycle/deactivate",
-            crate::progenitor_support::encode_path(&zone_id.to_string()),
+            self.client.encode_path(zone_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/api/v1/zones/{zoneId}/lifecycle/deactivate",
+                &[("zoneId", zone_id.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`NetworkZones`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn NetworkZonesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait NetworkZonesApi: Send + Sync {
+    async fn list(
+        &self,
+        after: &str,
+        limit: i64,
+        filter: &str,
+    ) -> Result<Vec<crate::types::NetworkZone>>;
+    async fn list_all(&self, filter: &str) -> Result<Vec<crate::types::NetworkZone>>;
+    async fn create(
+        &self,
+        body: &crate::types::NetworkZoneRequest,
+    ) -> Result<crate::types::NetworkZone>;
+    async fn get(&self, zone_id: &str) -> Result<crate::types::NetworkZone>;
+    async fn update(
+        &self,
+        zone_id: &str,
+        body: &crate::types::NetworkZoneRequest,
+    ) -> Result<crate::types::NetworkZone>;
+    async fn delete(&self, zone_id: &str) -> Result<()>;
+    async fn delete_if_exists(&self, zone_id: &str) -> Result<bool>;
+    async fn activate(&self, zone_id: &str) -> Result<crate::types::NetworkZone>;
+    async fn deactivate(&self, zone_id: &str) -> Result<crate::types::NetworkZone>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl NetworkZonesApi for NetworkZones {
+    async fn list(
+        &self,
+        after: &str,
+        limit: i64,
+        filter: &str,
+    ) -> Result<Vec<crate::types::NetworkZone>> {
+        NetworkZones::list(self, after, limit, filter).await
+    }
+    async fn list_all(&self, filter: &str) -> Result<Vec<crate::types::NetworkZone>> {
+        NetworkZones::list_all(self, filter).await
+    }
+    async fn create(
+        &self,
+        body: &crate::types::NetworkZoneRequest,
+    ) -> Result<crate::types::NetworkZone> {
+        NetworkZones::create(self, body).await
+    }
+    async fn get(&self, zone_id: &str) -> Result<crate::types::NetworkZone> {
+        NetworkZones::get(self, zone_id).await
+    }
+    async fn update(
+        &self,
+        zone_id: &str,
+        body: &crate::types::NetworkZoneRequest,
+    ) -> Result<crate::types::NetworkZone> {
+        NetworkZones::update(self, zone_id, body).await
+    }
+    async fn delete(&self, zone_id: &str) -> Result<()> {
+        NetworkZones::delete(self, zone_id).await
+    }
+    async fn delete_if_exists(&self, zone_id: &str) -> Result<bool> {
+        NetworkZones::delete_if_exists(self, zone_id).await
+    }
+    async fn activate(&self, zone_id: &str) -> Result<crate::types::NetworkZone> {
+        NetworkZones::activate(self, zone_id).await
+    }
+    async fn deactivate(&self, zone_id: &str) -> Result<crate::types::NetworkZone> {
+        NetworkZones::deactivate(self, zone_id).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Policies {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Policies {
         Policies { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Policies {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/policies` endpoint.
      *
@@ -29,20 +35,22 @@ impl Policies {
         status: &str,
         expand: &str,
     ) -> Result<Vec<crate::types::Policy>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/policies?{}", query_);
+        let url = crate::Client::url_with_query("/api/v1/policies", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/api/v1/policies", &[]))
     }
 
     /**
@@ -58,20 +66,22 @@ impl Policies {
         status: &str,
         expand: &str,
     ) -> Result<Vec<crate::types::Policy>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/policies?{}", query_);
+        let url = crate::Client::url_with_query("/api/v1/policies", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/api/v1/policies", &[]))
     }
 
     /**
@@ -86,18 +96,18 @@ impl Policies {
     pub async fn create_policy(
         &self,
         activate: bool,
-        body: &crate::types::Policy,
+        body: &crate::types::PolicyRequest,
     ) -> Result<crate::types::Policy> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if activate {
-            query_args.push(("activate".to_string(), activate.to_string()));
+            query_args.push(("activate", activate.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/policies?{}", query_);
+        let url = crate::Client::url_with_query("/api/v1/policies", &query_args);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/api/v1/policies", &[]))
     }
 
     /**
@@ -111,18 +121,22 @@ impl Policies {
      * * `expand: &str`
      */
     pub async fn get_policy(&self, policy_id: &str, expand: &str) -> Result<crate::types::Policy> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/policies/{}?{}",
-            crate::progenitor_support::encode_path(&policy_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/api/v1/policies/{}", self.client.encode_path(policy_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/policies/{policyId}",
+                &[("policyId", policy_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -137,16 +151,20 @@ impl Policies {
     pub async fn update_policy(
         &self,
         policy_id: &str,
-        body: &crate::types::Policy,
+        body: &crate::types::PolicyRequest,
     ) -> Result<crate::types::Policy> {
-        let url = format!(
-            "/api/v1/policies/{}",
-            crate::progenitor_support::encode_path(&policy_id.to_string()),
-        );
+        let url = format!("/api/v1/policies/{}", self.client.encode_path(policy_id),);
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/api/v1/policies/{policyId}",
+                    &[("policyId", policy_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -159,12 +177,35 @@ impl Policies {
      * * `policy_id: &str`
      */
     pub async fn delete_policy(&self, policy_id: &str) -> Result<()> {
-        let url = format!(
-            "/api/v1/policies/{}",
-            crate::progenitor_support::encode_path(&policy_id.to_string()),
-        );
+        let url = format!("/api/v1/policies/{}", self.client.encode_path(policy_id),);
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/policies/{policyId}",
+                &[("policyId", policy_id.to_string())],
+            )
+        })
+    }
 
-        self.client.delete(&url, None).await
+    /**
+     * This function performs a `DELETE` to the `/api/v1/policies/{policyId}` endpoint.
+     *
+     * As opposed to `delete_policy`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_policy_if_exists(&self, policy_id: &str) -> Result<bool> {
+        let url = format!("/api/v1/policies/{}", self.client.encode_path(policy_id),);
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/policies/{policyId}",
+                    &[("policyId", policy_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -179,10 +220,16 @@ impl Policies {
     pub async fn activate_policy(&self, policy_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/policies/{}/lifecycle/activate",
-            crate::progenitor_support::encode_path(&policy_id.to_string()),
+            self.client.encode_path(policy_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/api/v1/policies/{policyId}/lifecycle/activate",
+                &[("policyId", policy_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -197,10 +244,16 @@ impl Policies {
     pub async fn deactivate_policy(&self, policy_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/policies/{}/lifecycle/deactivate",
-            crate::progenitor_support::encode_path(&policy_id.to_string()),
+            self.client.encode_path(policy_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/api/v1/policies/{policyId}/lifecycle/deactivate",
+                &[("policyId", policy_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -218,10 +271,16 @@ impl Policies {
     ) -> Result<Vec<crate::types::PolicyRule>> {
         let url = format!(
             "/api/v1/policies/{}/rules",
-            crate::progenitor_support::encode_path(&policy_id.to_string()),
+            self.client.encode_path(policy_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/policies/{policyId}/rules",
+                &[("policyId", policy_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -237,10 +296,19 @@ impl Policies {
     ) -> Result<Vec<crate::types::PolicyRule>> {
         let url = format!(
             "/api/v1/policies/{}/rules",
-            crate::progenitor_support::encode_path(&policy_id.to_string()),
+            self.client.encode_path(policy_id),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/api/v1/policies/{policyId}/rules",
+                    &[("policyId", policy_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -255,16 +323,23 @@ impl Policies {
     pub async fn create_policy_rule(
         &self,
         policy_id: &str,
-        body: &crate::types::PolicyRule,
+        body: &crate::types::PolicyRuleRequest,
     ) -> Result<crate::types::PolicyRule> {
         let url = format!(
             "/api/v1/policies/{}/rules",
-            crate::progenitor_support::encode_path(&policy_id.to_string()),
+            self.client.encode_path(policy_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/api/v1/policies/{policyId}/rules",
+                    &[("policyId", policy_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -284,11 +359,20 @@ impl Policies {
     ) -> Result<crate::types::PolicyRule> {
         let url = format!(
             "/api/v1/policies/{}/rules/{}",
-            crate::progenitor_support::encode_path(&policy_id.to_string()),
-            crate::progenitor_support::encode_path(&rule_id.to_string()),
+            self.client.encode_path(policy_id),
+            self.client.encode_path(rule_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/policies/{policyId}/rules/{ruleId}",
+                &[
+                    ("policyId", policy_id.to_string()),
+                    ("ruleId", rule_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -305,17 +389,27 @@ impl Policies {
         &self,
         policy_id: &str,
         rule_id: &str,
-        body: &crate::types::PolicyRule,
+        body: &crate::types::PolicyRuleRequest,
     ) -> Result<crate::types::PolicyRule> {
         let url = format!(
             "/api/v1/policies/{}/rules/{}",
-            crate::progenitor_support::encode_path(&policy_id.to_string()),
-            crate::progenitor_support::encode_path(&rule_id.to_string()),
+            self.client.encode_path(policy_id),
+            self.client.encode_path(rule_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/api/v1/policies/{policyId}/rules/{ruleId}",
+                    &[
+                        ("policyId", policy_id.to_string()),
+                        ("ruleId", rule_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -331,11 +425,51 @@ impl Policies {
     pub async fn delete_policy_rule(&self, policy_id: &str, rule_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/policies/{}/rules/{}",
-            crate::progenitor_support::encode_path(&policy_id.to_string()),
-            crate::progenitor_support::encode_path(&rule_id.to_string()),
+            self.client.encode_path(policy_id),
+            self.client.encode_path(rule_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/policies/{policyId}/rules/{ruleId}",
+                &[
+                    ("policyId", policy_id.to_string()),
+                    ("ruleId", rule_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/api/v1/policies/{policyId}/rules/{ruleId}` endpoint.
+     *
+     * As opposed to `delete_policy_rule`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_policy_rule_if_exists(
+        &self,
+        policy_id: &str,
+        rule_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/api/v1/policies/{}/rules/{}",
+            self.client.encode_path(policy_id),
+            self.client.encode_path(rule_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/policies/{policyId}/rules/{ruleId}",
+                    &[
+                        ("policyId", policy_id.to_string()),
+                        ("ruleId", rule_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -351,11 +485,20 @@ impl Policies {
     pub async fn activate_policy_rule(&self, policy_id: &str, rule_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/policies/{}/rules/{}/lifecycle/activate",
-            crate::progenitor_support::encode_path(&policy_id.to_string()),
-            crate::progenitor_support::encode_path(&rule_id.to_string()),
+            self.client.encode_path(policy_id),
+            self.client.encode_path(rule_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/api/v1/policies/{policyId}/rules/{ruleId}/lifecycle/activate",
+                &[
+                    ("policyId", policy_id.to_string()),
+                    ("ruleId", rule_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -371,10 +514,170 @@ impl Policies {
     pub async fn deactivate_policy_rule(&self, policy_id: &str, rule_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/policies/{}/rules/{}/lifecycle/deactivate",
-            crate::progenitor_support::encode_path(&policy_id.to_string()),
-            crate::progenitor_support::encode_path(&rule_id.to_string()),
+            self.client.encode_path(policy_id),
+            self.client.encode_path(rule_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/api/v1/policies/{policyId}/rules/{ruleId}/lifecycle/deactivate",
+                &[
+                    ("policyId", policy_id.to_string()),
+                    ("ruleId", rule_id.to_string()),
+                ],
+            )
+        })
+    }
+}
+
+/// The functions of [`Policies`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn PoliciesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait PoliciesApi: Send + Sync {
+    async fn list(
+        &self,
+        type_: &str,
+        status: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::Policy>>;
+    async fn list_all(
+        &self,
+        type_: &str,
+        status: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::Policy>>;
+    async fn create_policy(
+        &self,
+        activate: bool,
+        body: &crate::types::PolicyRequest,
+    ) -> Result<crate::types::Policy>;
+    async fn get_policy(&self, policy_id: &str, expand: &str) -> Result<crate::types::Policy>;
+    async fn update_policy(
+        &self,
+        policy_id: &str,
+        body: &crate::types::PolicyRequest,
+    ) -> Result<crate::types::Policy>;
+    async fn delete_policy(&self, policy_id: &str) -> Result<()>;
+    async fn delete_policy_if_exists(&self, policy_id: &str) -> Result<bool>;
+    async fn activate_policy(&self, policy_id: &str) -> Result<()>;
+    async fn deactivate_policy(&self, policy_id: &str) -> Result<()>;
+    async fn list_policy_rules(&self, policy_id: &str) -> Result<Vec<crate::types::PolicyRule>>;
+    async fn list_all_policy_rules(&self, policy_id: &str)
+        -> Result<Vec<crate::types::PolicyRule>>;
+    async fn create_policy_rule(
+        &self,
+        policy_id: &str,
+        body: &crate::types::PolicyRuleRequest,
+    ) -> Result<crate::types::PolicyRule>;
+    async fn get_policy_rule(
+        &self,
+        policy_id: &str,
+        rule_id: &str,
+    ) -> Result<crate::types::PolicyRule>;
+    async fn update_policy_rule(
+        &self,
+        policy_id: &str,
+        rule_id: &str,
+        body: &crate::types::PolicyRuleRequest,
+    ) -> Result<crate::types::PolicyRule>;
+    async fn delete_policy_rule(&self, policy_id: &str, rule_id: &str) -> Result<()>;
+    async fn delete_policy_rule_if_exists(&self, policy_id: &str, rule_id: &str) -> Result<bool>;
+    async fn activate_policy_rule(&self, policy_id: &str, rule_id: &str) -> Result<()>;
+    async fn deactivate_policy_rule(&self, policy_id: &str, rule_id: &str) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl PoliciesApi for Policies {
+    async fn list(
+        &self,
+        type_: &str,
+        status: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::Policy>> {
+        Policies::list(self, type_, status, expand).await
+    }
+    async fn list_all(
+        &self,
+        type_: &str,
+        status: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::Policy>> {
+        Policies::list_all(self, type_, status, expand).await
+    }
+    async fn create_policy(
+        &self,
+        activate: bool,
+        body: &crate::types::PolicyRequest,
+    ) -> Result<crate::types::Policy> {
+        Policies::create_policy(self, activate, body).await
+    }
+    async fn get_policy(&self, policy_id: &str, expand: &str) -> Result<crate::types::Policy> {
+        Policies::get_policy(self, policy_id, expand).await
+    }
+    async fn update_policy(
+        &self,
+        policy_id: &str,
+        body: &crate::types::PolicyRequest,
+    ) -> Result<crate::types::Policy> {
+        Policies::update_policy(self, policy_id, body).await
+    }
+    async fn delete_policy(&self, policy_id: &str) -> Result<()> {
+        Policies::delete_policy(self, policy_id).await
+    }
+    async fn delete_policy_if_exists(&self, policy_id: &str) -> Result<bool> {
+        Policies::delete_policy_if_exists(self, policy_id).await
+    }
+    async fn activate_policy(&self, policy_id: &str) -> Result<()> {
+        Policies::activate_policy(self, policy_id).await
+    }
+    async fn deactivate_policy(&self, policy_id: &str) -> Result<()> {
+        Policies::deactivate_policy(self, policy_id).await
+    }
+    async fn list_policy_rules(&self, policy_id: &str) -> Result<Vec<crate::types::PolicyRule>> {
+        Policies::list_policy_rules(self, policy_id).await
+    }
+    async fn list_all_policy_rules(
+        &self,
+        policy_id: &str,
+    ) -> Result<Vec<crate::types::PolicyRule>> {
+        Policies::list_all_policy_rules(self, policy_id).await
+    }
+    async fn create_policy_rule(
+        &self,
+        policy_id: &str,
+        body: &crate::types::PolicyRuleRequest,
+    ) -> Result<crate::types::PolicyRule> {
+        Policies::create_policy_rule(self, policy_id, body).await
+    }
+    async fn get_policy_rule(
+        &self,
+        policy_id: &str,
+        rule_id: &str,
+    ) -> Result<crate::types::PolicyRule> {
+        Policies::get_policy_rule(self, policy_id, rule_id).await
+    }
+    async fn update_policy_rule(
+        &self,
+        policy_id: &str,
+        rule_id: &str,
+        body: &crate::types::PolicyRuleRequest,
+    ) -> Result<crate::types::PolicyRule> {
+        Policies::update_policy_rule(self, policy_id, rule_id, body).await
+    }
+    async fn delete_policy_rule(&self, policy_id: &str, rule_id: &str) -> Result<()> {
+        Policies::delete_policy_rule(self, policy_id, rule_id).await
+    }
+    async fn delete_policy_rule_if_exists(&self, policy_id: &str, rule_id: &str) -> Result<bool> {
+        Policies::delete_policy_rule_if_exists(self, policy_id, rule_id).await
+    }
+    async fn activate_policy_rule(&self, policy_id: &str, rule_id: &str) -> Result<()> {
+        Policies::activate_policy_rule(self, policy_id, rule_id).await
+    }
+    async fn deactivate_policy_rule(&self, policy_id: &str, rule_id: &str) -> Result<()> {
+        Policies::deactivate_policy_rule(self, policy_id, rule_id).await
     }
 }
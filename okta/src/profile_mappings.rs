@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct ProfileMappings {
     pub client: Client,
@@ -12,6 +10,14 @@ impl ProfileMappings {
         ProfileMappings { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        ProfileMappings {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/mappings` endpoint.
      *
@@ -31,23 +37,25 @@ impl ProfileMappings {
         source_id: &str,
         target_id: &str,
     ) -> Result<Vec<crate::types::ProfileMapping>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !source_id.is_empty() {
-            query_args.push(("sourceId".to_string(), source_id.to_string()));
+            query_args.push(("sourceId", source_id.to_string()));
         }
         if !target_id.is_empty() {
-            query_args.push(("targetId".to_string(), target_id.to_string()));
+            query_args.push(("targetId", target_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/mappings?{}", query_);
+        let url = crate::Client::url_with_query("/api/v1/mappings", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/api/v1/mappings", &[]))
     }
 
     /**
@@ -62,17 +70,19 @@ impl ProfileMappings {
         source_id: &str,
         target_id: &str,
     ) -> Result<Vec<crate::types::ProfileMapping>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !source_id.is_empty() {
-            query_args.push(("sourceId".to_string(), source_id.to_string()));
+            query_args.push(("sourceId", source_id.to_string()));
         }
         if !target_id.is_empty() {
-            query_args.push(("targetId".to_string(), target_id.to_string()));
+            query_args.push(("targetId", target_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/mappings?{}", query_);
+        let url = crate::Client::url_with_query("/api/v1/mappings", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/api/v1/mappings", &[]))
     }
 
     /**
@@ -87,12 +97,15 @@ impl ProfileMappings {
      * * `mapping_id: &str`
      */
     pub async fn get(&self, mapping_id: &str) -> Result<crate::types::ProfileMapping> {
-        let url = format!(
-            "/api/v1/mappings/{}",
-            crate::progenitor_support::encode_path(&mapping_id.to_string()),
-        );
+        let url = format!("/api/v1/mappings/{}", self.client.encode_path(mapping_id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/mappings/{mappingId}",
+                &[("mappingId", mapping_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -109,15 +122,76 @@ impl ProfileMappings {
     pub async fn update(
         &self,
         mapping_id: &str,
-        body: &crate::types::ProfileMapping,
+        body: &crate::types::ProfileMappingRequest,
     ) -> Result<crate::types::ProfileMapping> {
-        let url = format!(
-            "/api/v1/mappings/{}",
-            crate::progenitor_support::encode_path(&mapping_id.to_string()),
-        );
+        let url = format!("/api/v1/mappings/{}", self.client.encode_path(mapping_id),);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/api/v1/mappings/{mappingId}",
+                    &[("mappingId", mapping_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`ProfileMappings`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ProfileMappingsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ProfileMappingsApi: Send + Sync {
+    async fn list(
+        &self,
+        after: &str,
+        limit: i64,
+        source_id: &str,
+        target_id: &str,
+    ) -> Result<Vec<crate::types::ProfileMapping>>;
+    async fn list_all(
+        &self,
+        source_id: &str,
+        target_id: &str,
+    ) -> Result<Vec<crate::types::ProfileMapping>>;
+    async fn get(&self, mapping_id: &str) -> Result<crate::types::ProfileMapping>;
+    async fn update(
+        &self,
+        mapping_id: &str,
+        body: &crate::types::ProfileMappingRequest,
+    ) -> Result<crate::types::ProfileMapping>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ProfileMappingsApi for ProfileMappings {
+    async fn list(
+        &self,
+        after: &str,
+        limit: i64,
+        source_id: &str,
+        target_id: &str,
+    ) -> Result<Vec<crate::types::ProfileMapping>> {
+        ProfileMappings::list(self, after, limit, source_id, target_id).await
+    }
+    async fn list_all(
+        &self,
+        source_id: &str,
+        target_id: &str,
+    ) -> Result<Vec<crate::types::ProfileMapping>> {
+        ProfileMappings::list_all(self, source_id, target_id).await
+    }
+    async fn get(&self, mapping_id: &str) -> Result<crate::types::ProfileMapping> {
+        ProfileMappings::get(self, mapping_id).await
+    }
+    async fn update(
+        &self,
+        mapping_id: &str,
+        body: &crate::types::ProfileMappingRequest,
+    ) -> Result<crate::types::ProfileMapping> {
+        ProfileMappings::update(self, mapping_id, body).await
     }
 }
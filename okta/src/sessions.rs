@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Sessions {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Sessions {
         Sessions { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Sessions {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Create Session with Session Token.
      *
@@ -25,8 +31,9 @@ impl Sessions {
     ) -> Result<crate::types::Session> {
         let url = "/api/v1/sessions".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/api/v1/sessions", &[]))
     }
 
     /**
@@ -39,12 +46,15 @@ impl Sessions {
      * * `session_id: &str`
      */
     pub async fn get(&self, session_id: &str) -> Result<crate::types::Session> {
-        let url = format!(
-            "/api/v1/sessions/{}",
-            crate::progenitor_support::encode_path(&session_id.to_string()),
-        );
+        let url = format!("/api/v1/sessions/{}", self.client.encode_path(session_id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/sessions/{sessionId}",
+                &[("sessionId", session_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -59,12 +69,37 @@ impl Sessions {
      * * `session_id: &str`
      */
     pub async fn end(&self, session_id: &str) -> Result<()> {
-        let url = format!(
-            "/api/v1/sessions/{}",
-            crate::progenitor_support::encode_path(&session_id.to_string()),
-        );
+        let url = format!("/api/v1/sessions/{}", self.client.encode_path(session_id),);
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/sessions/{sessionId}",
+                &[("sessionId", session_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Close Session.
+     *
+     * This function performs a `DELETE` to the `/api/v1/sessions/{sessionId}` endpoint.
+     *
+     * As opposed to `end`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn end_if_exists(&self, session_id: &str) -> Result<bool> {
+        let url = format!("/api/v1/sessions/{}", self.client.encode_path(session_id),);
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/sessions/{sessionId}",
+                    &[("sessionId", session_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -81,9 +116,54 @@ impl Sessions {
     pub async fn refresh(&self, session_id: &str) -> Result<crate::types::Session> {
         let url = format!(
             "/api/v1/sessions/{}/lifecycle/refresh",
-            crate::progenitor_support::encode_path(&session_id.to_string()),
+            self.client.encode_path(session_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/api/v1/sessions/{sessionId}/lifecycle/refresh",
+                &[("sessionId", session_id.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`Sessions`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn SessionsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait SessionsApi: Send + Sync {
+    async fn create(
+        &self,
+        body: &crate::types::CreateSessionRequest,
+    ) -> Result<crate::types::Session>;
+    async fn get(&self, session_id: &str) -> Result<crate::types::Session>;
+    async fn end(&self, session_id: &str) -> Result<()>;
+    async fn end_if_exists(&self, session_id: &str) -> Result<bool>;
+    async fn refresh(&self, session_id: &str) -> Result<crate::types::Session>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl SessionsApi for Sessions {
+    async fn create(
+        &self,
+        body: &crate::types::CreateSessionRequest,
+    ) -> Result<crate::types::Session> {
+        Sessions::create(self, body).await
+    }
+    async fn get(&self, session_id: &str) -> Result<crate::types::Session> {
+        Sessions::get(self, session_id).await
+    }
+    async fn end(&self, session_id: &str) -> Result<()> {
+        Sessions::end(self, session_id).await
+    }
+    async fn end_if_exists(&self, session_id: &str) -> Result<bool> {
+        Sessions::end_if_exists(self, session_id).await
+    }
+    async fn refresh(&self, session_id: &str) -> Result<crate::types::Session> {
+        Sessions::refresh(self, session_id).await
     }
 }
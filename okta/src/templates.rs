@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Templates {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Templates {
         Templates { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Templates {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * List SMS Templates.
      *
@@ -24,14 +30,16 @@ impl Templates {
      * * `template_type: &str`
      */
     pub async fn list_sms(&self, template_type: &str) -> Result<Vec<crate::types::SmsTemplate>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !template_type.is_empty() {
-            query_args.push(("templateType".to_string(), template_type.to_string()));
+            query_args.push(("templateType", template_type.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/templates/sms?{}", query_);
+        let url = crate::Client::url_with_query("/api/v1/templates/sms", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/api/v1/templates/sms", &[]))
     }
 
     /**
@@ -47,14 +55,16 @@ impl Templates {
         &self,
         template_type: &str,
     ) -> Result<Vec<crate::types::SmsTemplate>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !template_type.is_empty() {
-            query_args.push(("templateType".to_string(), template_type.to_string()));
+            query_args.push(("templateType", template_type.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/templates/sms?{}", query_);
+        let url = crate::Client::url_with_query("/api/v1/templates/sms", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/api/v1/templates/sms", &[]))
     }
 
     /**
@@ -66,12 +76,13 @@ impl Templates {
      */
     pub async fn create_sms(
         &self,
-        body: &crate::types::SmsTemplate,
+        body: &crate::types::SmsTemplateRequest,
     ) -> Result<crate::types::SmsTemplate> {
         let url = "/api/v1/templates/sms".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/api/v1/templates/sms", &[]))
     }
 
     /**
@@ -88,10 +99,16 @@ impl Templates {
     pub async fn get_sm(&self, template_id: &str) -> Result<crate::types::SmsTemplate> {
         let url = format!(
             "/api/v1/templates/sms/{}",
-            crate::progenitor_support::encode_path(&template_id.to_string()),
+            self.client.encode_path(template_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/templates/sms/{templateId}",
+                &[("templateId", template_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -108,16 +125,23 @@ impl Templates {
     pub async fn update_sms(
         &self,
         template_id: &str,
-        body: &crate::types::SmsTemplate,
+        body: &crate::types::SmsTemplateRequest,
     ) -> Result<crate::types::SmsTemplate> {
         let url = format!(
             "/api/v1/templates/sms/{}",
-            crate::progenitor_support::encode_path(&template_id.to_string()),
+            self.client.encode_path(template_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/api/v1/templates/sms/{templateId}",
+                    &[("templateId", template_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -134,16 +158,23 @@ impl Templates {
     pub async fn partial_update_sms(
         &self,
         template_id: &str,
-        body: &crate::types::SmsTemplate,
+        body: &crate::types::SmsTemplateRequest,
     ) -> Result<crate::types::SmsTemplate> {
         let url = format!(
             "/api/v1/templates/sms/{}",
-            crate::progenitor_support::encode_path(&template_id.to_string()),
+            self.client.encode_path(template_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/api/v1/templates/sms/{templateId}",
+                    &[("templateId", template_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -160,9 +191,107 @@ impl Templates {
     pub async fn delete_sms(&self, template_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/templates/sms/{}",
-            crate::progenitor_support::encode_path(&template_id.to_string()),
+            self.client.encode_path(template_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/templates/sms/{templateId}",
+                &[("templateId", template_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Remove SMS Template.
+     *
+     * This function performs a `DELETE` to the `/api/v1/templates/sms/{templateId}` endpoint.
+     *
+     * As opposed to `delete_sms`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_sms_if_exists(&self, template_id: &str) -> Result<bool> {
+        let url = format!(
+            "/api/v1/templates/sms/{}",
+            self.client.encode_path(template_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/templates/sms/{templateId}",
+                    &[("templateId", template_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`Templates`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn TemplatesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait TemplatesApi: Send + Sync {
+    async fn list_sms(&self, template_type: &str) -> Result<Vec<crate::types::SmsTemplate>>;
+    async fn list_all_sms(&self, template_type: &str) -> Result<Vec<crate::types::SmsTemplate>>;
+    async fn create_sms(
+        &self,
+        body: &crate::types::SmsTemplateRequest,
+    ) -> Result<crate::types::SmsTemplate>;
+    async fn get_sm(&self, template_id: &str) -> Result<crate::types::SmsTemplate>;
+    async fn update_sms(
+        &self,
+        template_id: &str,
+        body: &crate::types::SmsTemplateRequest,
+    ) -> Result<crate::types::SmsTemplate>;
+    async fn partial_update_sms(
+        &self,
+        template_id: &str,
+        body: &crate::types::SmsTemplateRequest,
+    ) -> Result<crate::types::SmsTemplate>;
+    async fn delete_sms(&self, template_id: &str) -> Result<()>;
+    async fn delete_sms_if_exists(&self, template_id: &str) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl TemplatesApi for Templates {
+    async fn list_sms(&self, template_type: &str) -> Result<Vec<crate::types::SmsTemplate>> {
+        Templates::list_sms(self, template_type).await
+    }
+    async fn list_all_sms(&self, template_type: &str) -> Result<Vec<crate::types::SmsTemplate>> {
+        Templates::list_all_sms(self, template_type).await
+    }
+    async fn create_sms(
+        &self,
+        body: &crate::types::SmsTemplateRequest,
+    ) -> Result<crate::types::SmsTemplate> {
+        Templates::create_sms(self, body).await
+    }
+    async fn get_sm(&self, template_id: &str) -> Result<crate::types::SmsTemplate> {
+        Templates::get_sm(self, template_id).await
+    }
+    async fn update_sms(
+        &self,
+        template_id: &str,
+        body: &crate::types::SmsTemplateRequest,
+    ) -> Result<crate::types::SmsTemplate> {
+        Templates::update_sms(self, template_id, body).await
+    }
+    async fn partial_update_sms(
+        &self,
+        template_id: &str,
+        body: &crate::types::SmsTemplateRequest,
+    ) -> Result<crate::types::SmsTemplate> {
+        Templates::partial_update_sms(self, template_id, body).await
+    }
+    async fn delete_sms(&self, template_id: &str) -> Result<()> {
+        Templates::delete_sms(self, template_id).await
+    }
+    async fn delete_sms_if_exists(&self, template_id: &str) -> Result<bool> {
+        Templates::delete_sms_if_exists(self, template_id).await
     }
 }
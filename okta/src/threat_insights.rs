@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct ThreatInsights {
     pub client: Client,
@@ -12,6 +10,14 @@ impl ThreatInsights {
         ThreatInsights { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        ThreatInsights {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/threats/configuration` endpoint.
      *
@@ -21,7 +27,10 @@ impl ThreatInsights {
         &self,
     ) -> Result<crate::types::ThreatInsightConfiguration> {
         let url = "/api/v1/threats/configuration".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/api/v1/threats/configuration", &[]))
     }
 
     /**
@@ -31,11 +40,39 @@ impl ThreatInsights {
      */
     pub async fn update_configuration(
         &self,
-        body: &crate::types::ThreatInsightConfiguration,
+        body: &crate::types::ThreatInsightConfigurationRequest,
     ) -> Result<crate::types::ThreatInsightConfiguration> {
         let url = "/api/v1/threats/configuration".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/api/v1/threats/configuration", &[]))
+    }
+}
+
+/// The functions of [`ThreatInsights`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ThreatInsightsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ThreatInsightsApi: Send + Sync {
+    async fn get_current_configuration(&self) -> Result<crate::types::ThreatInsightConfiguration>;
+    async fn update_configuration(
+        &self,
+        body: &crate::types::ThreatInsightConfigurationRequest,
+    ) -> Result<crate::types::ThreatInsightConfiguration>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ThreatInsightsApi for ThreatInsights {
+    async fn get_current_configuration(&self) -> Result<crate::types::ThreatInsightConfiguration> {
+        ThreatInsights::get_current_configuration(self).await
+    }
+    async fn update_configuration(
+        &self,
+        body: &crate::types::ThreatInsightConfigurationRequest,
+    ) -> Result<crate::types::ThreatInsightConfiguration> {
+        ThreatInsights::update_configuration(self, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct TrustedOrigins {
     pub client: Client,
@@ -12,6 +10,14 @@ impl TrustedOrigins {
         TrustedOrigins { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        TrustedOrigins {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/trustedOrigins` endpoint.
      *
@@ -31,23 +37,25 @@ impl TrustedOrigins {
         after: &str,
         limit: i64,
     ) -> Result<Vec<crate::types::TrustedOrigin>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/trustedOrigins?{}", query_);
+        let url = crate::Client::url_with_query("/api/v1/trustedOrigins", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/api/v1/trustedOrigins", &[]))
     }
 
     /**
@@ -62,17 +70,19 @@ impl TrustedOrigins {
         q: &str,
         filter: &str,
     ) -> Result<Vec<crate::types::TrustedOrigin>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/trustedOrigins?{}", query_);
+        let url = crate::Client::url_with_query("/api/v1/trustedOrigins", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/api/v1/trustedOrigins", &[]))
     }
 
     /**
@@ -82,12 +92,13 @@ impl TrustedOrigins {
      */
     pub async fn create_origin(
         &self,
-        body: &crate::types::TrustedOrigin,
+        body: &crate::types::TrustedOriginRequest,
     ) -> Result<crate::types::TrustedOrigin> {
         let url = "/api/v1/trustedOrigins".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/api/v1/trustedOrigins", &[]))
     }
 
     /**
@@ -102,10 +113,16 @@ impl TrustedOrigins {
     pub async fn get_origin(&self, trusted_origin_id: &str) -> Result<crate::types::TrustedOrigin> {
         let url = format!(
             "/api/v1/trustedOrigins/{}",
-            crate::progenitor_support::encode_path(&trusted_origin_id.to_string()),
+            self.client.encode_path(trusted_origin_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/trustedOrigins/{trustedOriginId}",
+                &[("trustedOriginId", trusted_origin_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -120,16 +137,23 @@ impl TrustedOrigins {
     pub async fn update_origin(
         &self,
         trusted_origin_id: &str,
-        body: &crate::types::TrustedOrigin,
+        body: &crate::types::TrustedOriginRequest,
     ) -> Result<crate::types::TrustedOrigin> {
         let url = format!(
             "/api/v1/trustedOrigins/{}",
-            crate::progenitor_support::encode_path(&trusted_origin_id.to_string()),
+            self.client.encode_path(trusted_origin_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/api/v1/trustedOrigins/{trustedOriginId}",
+                    &[("trustedOriginId", trusted_origin_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -144,10 +168,39 @@ impl TrustedOrigins {
     pub async fn delete_origin(&self, trusted_origin_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/trustedOrigins/{}",
-            crate::progenitor_support::encode_path(&trusted_origin_id.to_string()),
+            self.client.encode_path(trusted_origin_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/trustedOrigins/{trustedOriginId}",
+                &[("trustedOriginId", trusted_origin_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/api/v1/trustedOrigins/{trustedOriginId}` endpoint.
+     *
+     * As opposed to `delete_origin`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_origin_if_exists(&self, trusted_origin_id: &str) -> Result<bool> {
+        let url = format!(
+            "/api/v1/trustedOrigins/{}",
+            self.client.encode_path(trusted_origin_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/trustedOrigins/{trustedOriginId}",
+                    &[("trustedOriginId", trusted_origin_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -165,10 +218,16 @@ impl TrustedOrigins {
     ) -> Result<crate::types::TrustedOrigin> {
         let url = format!(
             "/api/v1/trustedOrigins/{}/lifecycle/activate",
-            crate::progenitor_support::encode_path(&trusted_origin_id.to_string()),
+            self.client.encode_path(trusted_origin_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/api/v1/trustedOrigins/{trustedOriginId}/lifecycle/activate",
+                &[("trustedOriginId", trusted_origin_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -186,9 +245,108 @@ impl TrustedOrigins {
     ) -> Result<crate::types::TrustedOrigin> {
         let url = format!(
             "/api/v1/trustedOrigins/{}/lifecycle/deactivate",
-            crate::progenitor_support::encode_path(&trusted_origin_id.to_string()),
+            self.client.encode_path(trusted_origin_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/api/v1/trustedOrigins/{trustedOriginId}/lifecycle/deactivate",
+                &[("trustedOriginId", trusted_origin_id.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`TrustedOrigins`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn TrustedOriginsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait TrustedOriginsApi: Send + Sync {
+    async fn list_origins(
+        &self,
+        q: &str,
+        filter: &str,
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::TrustedOrigin>>;
+    async fn list_all_origins(
+        &self,
+        q: &str,
+        filter: &str,
+    ) -> Result<Vec<crate::types::TrustedOrigin>>;
+    async fn create_origin(
+        &self,
+        body: &crate::types::TrustedOriginRequest,
+    ) -> Result<crate::types::TrustedOrigin>;
+    async fn get_origin(&self, trusted_origin_id: &str) -> Result<crate::types::TrustedOrigin>;
+    async fn update_origin(
+        &self,
+        trusted_origin_id: &str,
+        body: &crate::types::TrustedOriginRequest,
+    ) -> Result<crate::types::TrustedOrigin>;
+    async fn delete_origin(&self, trusted_origin_id: &str) -> Result<()>;
+    async fn delete_origin_if_exists(&self, trusted_origin_id: &str) -> Result<bool>;
+    async fn activate_origin(&self, trusted_origin_id: &str)
+        -> Result<crate::types::TrustedOrigin>;
+    async fn deactivate_origin(
+        &self,
+        trusted_origin_id: &str,
+    ) -> Result<crate::types::TrustedOrigin>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl TrustedOriginsApi for TrustedOrigins {
+    async fn list_origins(
+        &self,
+        q: &str,
+        filter: &str,
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::TrustedOrigin>> {
+        TrustedOrigins::list_origins(self, q, filter, after, limit).await
+    }
+    async fn list_all_origins(
+        &self,
+        q: &str,
+        filter: &str,
+    ) -> Result<Vec<crate::types::TrustedOrigin>> {
+        TrustedOrigins::list_all_origins(self, q, filter).await
+    }
+    async fn create_origin(
+        &self,
+        body: &crate::types::TrustedOriginRequest,
+    ) -> Result<crate::types::TrustedOrigin> {
+        TrustedOrigins::create_origin(self, body).await
+    }
+    async fn get_origin(&self, trusted_origin_id: &str) -> Result<crate::types::TrustedOrigin> {
+        TrustedOrigins::get_origin(self, trusted_origin_id).await
+    }
+    async fn update_origin(
+        &self,
+        trusted_origin_id: &str,
+        body: &crate::types::TrustedOriginRequest,
+    ) -> Result<crate::types::TrustedOrigin> {
+        TrustedOrigins::update_origin(self, trusted_origin_id, body).await
+    }
+    async fn delete_origin(&self, trusted_origin_id: &str) -> Result<()> {
+        TrustedOrigins::delete_origin(self, trusted_origin_id).await
+    }
+    async fn delete_origin_if_exists(&self, trusted_origin_id: &str) -> Result<bool> {
+        TrustedOrigins::delete_origin_if_exists(self, trusted_origin_id).await
+    }
+    async fn activate_origin(
+        &self,
+        trusted_origin_id: &str,
+    ) -> Result<crate::types::TrustedOrigin> {
+        TrustedOrigins::activate_origin(self, trusted_origin_id).await
+    }
+    async fn deactivate_origin(
+        &self,
+        trusted_origin_id: &str,
+    ) -> Result<crate::types::TrustedOrigin> {
+        TrustedOrigins::deactivate_origin(self, trusted_origin_id).await
     }
 }
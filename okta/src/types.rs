@@ -2,6 +2,33 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+/// Either one of something, or many of them. Some endpoints send back an
+/// object when there is one and an array when there are more, so this takes both.
+// Many is tried first: serde reads a struct from an array of its fields too,
+// so an array would otherwise be taken for One.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum OneOrMany<T> {
+    Many(Vec<T>),
+    One(T),
+}
+
+impl<T> OneOrMany<T> {
+    /// All of them, as a vector.
+    pub fn into_vec(self) -> Vec<T> {
+        match self {
+            OneOrMany::One(t) => vec![t],
+            OneOrMany::Many(v) => v,
+        }
+    }
+}
+
+impl<T> Default for OneOrMany<T> {
+    fn default() -> Self {
+        OneOrMany::Many(Vec::new())
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AcsEndpoint {
     #[serde(
@@ -19,6 +46,7 @@ pub struct AcsEndpoint {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ActivateFactorRequest {
     #[serde(
         default,
@@ -29,34 +57,30 @@ pub struct ActivateFactorRequest {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "clientData"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub client_data: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "passCode"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub pass_code: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "registrationData"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub registration_data: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "stateToken"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub state_token: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Type {
     #[serde(rename = "APP")]
     App,
@@ -85,7 +109,23 @@ impl Default for Type {
         Type::Noop
     }
 }
+
 impl Type {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Type::App => "APP",
+            Type::AppType => "APP_TYPE",
+            Type::Noop => "",
+            Type::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Type] {
+        &[Type::App, Type::AppType]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Type::Noop)
     }
@@ -142,32 +182,29 @@ pub struct UserCondition {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct AppLink {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "appAssignmentId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub app_assignment_id: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "appInstanceId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub app_instance_id: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "appName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub app_name: String,
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "credentialsSetup"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub credentials_setup: bool,
     #[serde(
@@ -190,22 +227,19 @@ pub struct AppLink {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "linkUrl"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub link_url: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "logoUrl"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub logo_url: String,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "sortOrder"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub sort_order: i64,
 }
@@ -292,14 +326,14 @@ pub struct AppUser {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct AppUserCredentials {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub password: Option<AppUserPasswordCredential>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "userName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub user_name: String,
 }
@@ -314,7 +348,7 @@ pub struct AppUserPasswordCredential {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Status {
     #[serde(rename = "ACTIVE")]
     Active,
@@ -346,7 +380,24 @@ impl Default for Status {
         Status::Noop
     }
 }
+
 impl Status {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Status::Active => "ACTIVE",
+            Status::Deleted => "DELETED",
+            Status::Inactive => "INACTIVE",
+            Status::Noop => "",
+            Status::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Status] {
+        &[Status::Active, Status::Deleted, Status::Inactive]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Status::Noop)
     }
@@ -418,38 +469,33 @@ pub struct Application {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ApplicationAccessibility {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "errorRedirectUrl"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub error_redirect_url: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "loginRedirectUrl"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub login_redirect_url: String,
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "selfService"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub self_service: bool,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ApplicationCredentials {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub signing: Option<ApplicationCredentialsSigning>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "userNameTemplate"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user_name_template: Option<ApplicationCredentialsUsernameTemplate>,
 }
 
@@ -477,7 +523,7 @@ pub struct ApplicationCredentialsOAuthClient {
     pub token_endpoint_auth_method: Option<OAuthEndpointAuthenticationMethod>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ApplicationCredentialsScheme {
     #[serde(rename = "ADMIN_SETS_CREDENTIALS")]
     AdminSetsCredentials,
@@ -517,13 +563,41 @@ impl Default for ApplicationCredentialsScheme {
         ApplicationCredentialsScheme::Noop
     }
 }
+
 impl ApplicationCredentialsScheme {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApplicationCredentialsScheme::AdminSetsCredentials => "ADMIN_SETS_CREDENTIALS",
+            ApplicationCredentialsScheme::EditPasswordOnly => "EDIT_PASSWORD_ONLY",
+            ApplicationCredentialsScheme::EditUsernameAndPassword => "EDIT_USERNAME_AND_PASSWORD",
+            ApplicationCredentialsScheme::ExternalPasswordSync => "EXTERNAL_PASSWORD_SYNC",
+            ApplicationCredentialsScheme::SharedUsernameAndPassword => {
+                "SHARED_USERNAME_AND_PASSWORD"
+            }
+            ApplicationCredentialsScheme::Noop => "",
+            ApplicationCredentialsScheme::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ApplicationCredentialsScheme] {
+        &[
+            ApplicationCredentialsScheme::AdminSetsCredentials,
+            ApplicationCredentialsScheme::EditPasswordOnly,
+            ApplicationCredentialsScheme::EditUsernameAndPassword,
+            ApplicationCredentialsScheme::ExternalPasswordSync,
+            ApplicationCredentialsScheme::SharedUsernameAndPassword,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ApplicationCredentialsScheme::Noop)
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ApplicationCredentialsSigning {
     #[serde(
         default,
@@ -534,29 +608,26 @@ pub struct ApplicationCredentialsSigning {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastRotated"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub last_rotated: Option<chrono::DateTime<chrono::Utc>>,
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "nextRotation"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub next_rotation: Option<chrono::DateTime<chrono::Utc>>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "rotationMode"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub rotation_mode: String,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "use")]
     pub use_: Option<Use>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Use {
     #[serde(rename = "sig")]
     Sig,
@@ -582,7 +653,22 @@ impl Default for Use {
         Use::Noop
     }
 }
+
 impl Use {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Use::Sig => "sig",
+            Use::Noop => "",
+            Use::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Use] {
+        &[Use::Sig]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Use::Noop)
     }
@@ -641,31 +727,30 @@ pub struct ApplicationGroupAssignment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ApplicationLicensing {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "seatCount"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub seat_count: i64,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ApplicationSettings {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub app: Option<serde_json::Value>,
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "implicitAssignment"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub implicit_assignment: bool,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "inlineHookId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub inline_hook_id: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -679,12 +764,12 @@ pub struct ApplicationSettingsNotifications {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ApplicationSettingsNotificationsVpn {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "helpUrl"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub help_url: String,
     #[serde(
@@ -719,7 +804,7 @@ pub struct ApplicationSettingsNotificationsVpnNetwork {
     pub include: Vec<String>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ApplicationSignOnMode {
     #[serde(rename = "AUTO_LOGIN")]
     AutoLogin,
@@ -769,24 +854,56 @@ impl Default for ApplicationSignOnMode {
         ApplicationSignOnMode::Noop
     }
 }
+
 impl ApplicationSignOnMode {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApplicationSignOnMode::AutoLogin => "AUTO_LOGIN",
+            ApplicationSignOnMode::BasicAuth => "BASIC_AUTH",
+            ApplicationSignOnMode::Bookmark => "BOOKMARK",
+            ApplicationSignOnMode::BrowserPlugin => "BROWSER_PLUGIN",
+            ApplicationSignOnMode::OpenidConnect => "OPENID_CONNECT",
+            ApplicationSignOnMode::Saml1 => "SAML_1_1",
+            ApplicationSignOnMode::Saml20 => "SAML_2_0",
+            ApplicationSignOnMode::SecurePasswordStore => "SECURE_PASSWORD_STORE",
+            ApplicationSignOnMode::WsFederation => "WS_FEDERATION",
+            ApplicationSignOnMode::Noop => "",
+            ApplicationSignOnMode::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ApplicationSignOnMode] {
+        &[
+            ApplicationSignOnMode::AutoLogin,
+            ApplicationSignOnMode::BasicAuth,
+            ApplicationSignOnMode::Bookmark,
+            ApplicationSignOnMode::BrowserPlugin,
+            ApplicationSignOnMode::OpenidConnect,
+            ApplicationSignOnMode::Saml1,
+            ApplicationSignOnMode::Saml20,
+            ApplicationSignOnMode::SecurePasswordStore,
+            ApplicationSignOnMode::WsFederation,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ApplicationSignOnMode::Noop)
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ApplicationVisibility {
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "appLinks"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub app_links: bool,
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "autoSubmitToolbar"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub auto_submit_toolbar: bool,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -826,7 +943,7 @@ pub struct AuthenticationProvider {
     pub type_: Option<AuthenticationProviderType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum AuthenticationProviderType {
     #[serde(rename = "ACTIVE_DIRECTORY")]
     ActiveDirectory,
@@ -867,13 +984,40 @@ impl Default for AuthenticationProviderType {
         AuthenticationProviderType::Noop
     }
 }
+
 impl AuthenticationProviderType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuthenticationProviderType::ActiveDirectory => "ACTIVE_DIRECTORY",
+            AuthenticationProviderType::Federation => "FEDERATION",
+            AuthenticationProviderType::Import => "IMPORT",
+            AuthenticationProviderType::Ldap => "LDAP",
+            AuthenticationProviderType::Okta => "OKTA",
+            AuthenticationProviderType::Social => "SOCIAL",
+            AuthenticationProviderType::Noop => "",
+            AuthenticationProviderType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [AuthenticationProviderType] {
+        &[
+            AuthenticationProviderType::ActiveDirectory,
+            AuthenticationProviderType::Federation,
+            AuthenticationProviderType::Import,
+            AuthenticationProviderType::Ldap,
+            AuthenticationProviderType::Okta,
+            AuthenticationProviderType::Social,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, AuthenticationProviderType::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum IssuerMode {
     #[serde(rename = "CUSTOM_URL")]
     CustomUrl,
@@ -902,13 +1046,29 @@ impl Default for IssuerMode {
         IssuerMode::Noop
     }
 }
+
 impl IssuerMode {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IssuerMode::CustomUrl => "CUSTOM_URL",
+            IssuerMode::OrgUrl => "ORG_URL",
+            IssuerMode::Noop => "",
+            IssuerMode::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [IssuerMode] {
+        &[IssuerMode::CustomUrl, IssuerMode::OrgUrl]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, IssuerMode::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum RoleStatus {
     #[serde(rename = "ACTIVE")]
     Active,
@@ -937,7 +1097,23 @@ impl Default for RoleStatus {
         RoleStatus::Noop
     }
 }
+
 impl RoleStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RoleStatus::Active => "ACTIVE",
+            RoleStatus::Inactive => "INACTIVE",
+            RoleStatus::Noop => "",
+            RoleStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [RoleStatus] {
+        &[RoleStatus::Active, RoleStatus::Inactive]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, RoleStatus::Noop)
     }
@@ -1008,7 +1184,7 @@ pub struct AuthorizationServerCredentials {
     pub signing: Option<AuthorizationServerCredentialsSigningConfig>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum AuthorizationServerCredentialsRotationMode {
     #[serde(rename = "AUTO")]
     Auto,
@@ -1037,13 +1213,33 @@ impl Default for AuthorizationServerCredentialsRotationMode {
         AuthorizationServerCredentialsRotationMode::Noop
     }
 }
+
 impl AuthorizationServerCredentialsRotationMode {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuthorizationServerCredentialsRotationMode::Auto => "AUTO",
+            AuthorizationServerCredentialsRotationMode::Manual => "MANUAL",
+            AuthorizationServerCredentialsRotationMode::Noop => "",
+            AuthorizationServerCredentialsRotationMode::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [AuthorizationServerCredentialsRotationMode] {
+        &[
+            AuthorizationServerCredentialsRotationMode::Auto,
+            AuthorizationServerCredentialsRotationMode::Manual,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, AuthorizationServerCredentialsRotationMode::Noop)
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct AuthorizationServerCredentialsSigningConfig {
     #[serde(
         default,
@@ -1054,22 +1250,16 @@ pub struct AuthorizationServerCredentialsSigningConfig {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastRotated"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub last_rotated: Option<chrono::DateTime<chrono::Utc>>,
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "nextRotation"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub next_rotation: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "rotationMode"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub rotation_mode: Option<AuthorizationServerCredentialsRotationMode>,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "use")]
     pub use_: Option<Use>,
@@ -1131,7 +1321,7 @@ pub struct Policy {
     pub type_: Option<PolicyType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum AuthorizationServerPolicyRuleType {
     #[serde(rename = "RESOURCE_ACCESS")]
     ResourceAccess,
@@ -1157,13 +1347,29 @@ impl Default for AuthorizationServerPolicyRuleType {
         AuthorizationServerPolicyRuleType::Noop
     }
 }
+
 impl AuthorizationServerPolicyRuleType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuthorizationServerPolicyRuleType::ResourceAccess => "RESOURCE_ACCESS",
+            AuthorizationServerPolicyRuleType::Noop => "",
+            AuthorizationServerPolicyRuleType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [AuthorizationServerPolicyRuleType] {
+        &[AuthorizationServerPolicyRuleType::ResourceAccess]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, AuthorizationServerPolicyRuleType::Noop)
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct AuthorizationServerPolicyRule {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub actions: Option<AuthorizationServerPolicyRuleActions>,
@@ -1184,8 +1390,7 @@ pub struct AuthorizationServerPolicyRule {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastUpdated"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
     #[serde(
@@ -1218,14 +1423,11 @@ pub struct AuthorizationServerPolicyRuleActions {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct AuthorizationServerPolicyRuleConditions {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub clients: Option<ClientPolicyCondition>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "grantTypes"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub grant_types: Option<ClientPolicyCondition>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub people: Option<PolicyPeopleCondition>,
@@ -1242,25 +1444,25 @@ pub struct AutoLoginApplication {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct AutoLoginApplicationSettings {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "signOn")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub sign_on: Option<AutoLoginApplicationSettingsSignOn>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct AutoLoginApplicationSettingsSignOn {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "loginUrl"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub login_url: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "redirectUrl"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub redirect_url: String,
 }
@@ -1299,14 +1501,11 @@ pub struct BasicAuthApplication {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct BeforeScheduledActionPolicyRuleCondition {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub duration: Option<Duration>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "lifecycleAction"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub lifecycle_action: Option<ScheduledUserLifecycleAction>,
 }
 
@@ -1325,11 +1524,11 @@ pub struct BookmarkApplicationSettings {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct BookmarkApplicationSettingsData {
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "requestIntegration"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub request_integration: bool,
     #[serde(
@@ -1353,19 +1552,18 @@ pub struct CallUserFactor {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct CallUserFactorProfile {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "phoneExtension"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub phone_extension: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "phoneNumber"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub phone_number: String,
 }
@@ -1443,18 +1641,11 @@ pub struct CatalogApplication {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ChangePasswordRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "newPassword"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub new_password: Option<PasswordCredential>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "oldPassword"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub old_password: Option<PasswordCredential>,
 }
 
@@ -1479,25 +1670,25 @@ pub struct ContextPolicyRuleCondition {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct CreateSessionRequest {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "sessionToken"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub session_token: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct CreateUserRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub credentials: Option<UserCredentials>,
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "groupIds"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub group_ids: Vec<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1535,81 +1726,73 @@ pub struct Csr {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct CsrMetadata {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub subject: Option<CsrMetadataSubject>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "subjectAltNames"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub subject_alt_names: Option<CsrMetadataSubjectAltNames>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct CsrMetadataSubject {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "commonName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub common_name: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "countryName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub country_name: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "localityName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub locality_name: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "organizationName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub organization_name: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "organizationalUnitName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub organizational_unit_name: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "stateOrProvinceName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub state_or_province_name: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct CsrMetadataSubjectAltNames {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "dnsNames"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub dns_names: Vec<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct CustomHotpUserFactor {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "factorProfileId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub factor_profile_id: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1617,17 +1800,18 @@ pub struct CustomHotpUserFactor {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct CustomHotpUserFactorProfile {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "sharedSecret"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub shared_secret: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DnsRecord {
     #[serde(
         default,
@@ -1641,12 +1825,8 @@ pub struct DnsRecord {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub fqdn: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "recordType"
-    )]
-    pub record_type: Option<DnsRecordType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub record_type: Option<DnsRecordType>,
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -1655,7 +1835,7 @@ pub struct DnsRecord {
     pub values: Vec<String>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum DnsRecordType {
     #[serde(rename = "CNAME")]
     Cname,
@@ -1684,13 +1864,29 @@ impl Default for DnsRecordType {
         DnsRecordType::Noop
     }
 }
+
 impl DnsRecordType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DnsRecordType::Cname => "CNAME",
+            DnsRecordType::Txt => "TXT",
+            DnsRecordType::Noop => "",
+            DnsRecordType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [DnsRecordType] {
+        &[DnsRecordType::Cname, DnsRecordType::Txt]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, DnsRecordType::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum TrustLevel {
     #[serde(rename = "ANY")]
     Any,
@@ -1719,13 +1915,30 @@ impl Default for TrustLevel {
         TrustLevel::Noop
     }
 }
+
 impl TrustLevel {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TrustLevel::Any => "ANY",
+            TrustLevel::Trusted => "TRUSTED",
+            TrustLevel::Noop => "",
+            TrustLevel::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [TrustLevel] {
+        &[TrustLevel::Any, TrustLevel::Trusted]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, TrustLevel::Noop)
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DevicePolicyRuleCondition {
     #[serde(
         default,
@@ -1739,15 +1952,11 @@ pub struct DevicePolicyRuleCondition {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub rooted: bool,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "trustLevel"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub trust_level: Option<TrustLevel>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SupportedMdmFrameworks {
     #[serde(rename = "AFW")]
     Afw,
@@ -1779,13 +1988,34 @@ impl Default for SupportedMdmFrameworks {
         SupportedMdmFrameworks::Noop
     }
 }
+
 impl SupportedMdmFrameworks {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SupportedMdmFrameworks::Afw => "AFW",
+            SupportedMdmFrameworks::Native => "NATIVE",
+            SupportedMdmFrameworks::Safe => "SAFE",
+            SupportedMdmFrameworks::Noop => "",
+            SupportedMdmFrameworks::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SupportedMdmFrameworks] {
+        &[
+            SupportedMdmFrameworks::Afw,
+            SupportedMdmFrameworks::Native,
+            SupportedMdmFrameworks::Safe,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SupportedMdmFrameworks::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Types {
     #[serde(rename = "ANDROID")]
     Android,
@@ -1820,7 +2050,25 @@ impl Default for Types {
         Types::Noop
     }
 }
+
 impl Types {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Types::Android => "ANDROID",
+            Types::Ios => "IOS",
+            Types::Osx => "OSX",
+            Types::Windows => "WINDOWS",
+            Types::Noop => "",
+            Types::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Types] {
+        &[Types::Android, Types::Ios, Types::Osx, Types::Windows]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Types::Noop)
     }
@@ -1844,18 +2092,14 @@ pub struct DevicePolicyRuleConditionPlatform {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Domain {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "certificateSourceType"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub certificate_source_type: Option<DomainCertificateSourceType>,
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "dnsRecords"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub dns_records: Vec<DnsRecord>,
     #[serde(
@@ -1870,21 +2114,14 @@ pub struct Domain {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "publicCertificate"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub public_certificate: Option<DomainCertificateMetadata>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "validationStatus"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub validation_status: Option<DomainValidationStatus>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct DomainCertificate {
     #[serde(
         default,
@@ -1895,15 +2132,13 @@ pub struct DomainCertificate {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "certificateChain"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub certificate_chain: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "privateKey"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub private_key: String,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
@@ -1932,7 +2167,7 @@ pub struct DomainCertificateMetadata {
     pub subject: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum DomainCertificateSourceType {
     #[serde(rename = "MANUAL")]
     Manual,
@@ -1958,13 +2193,28 @@ impl Default for DomainCertificateSourceType {
         DomainCertificateSourceType::Noop
     }
 }
+
 impl DomainCertificateSourceType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DomainCertificateSourceType::Manual => "MANUAL",
+            DomainCertificateSourceType::Noop => "",
+            DomainCertificateSourceType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [DomainCertificateSourceType] {
+        &[DomainCertificateSourceType::Manual]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, DomainCertificateSourceType::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum DomainCertificateType {
     #[serde(rename = "PEM")]
     Pem,
@@ -1990,7 +2240,22 @@ impl Default for DomainCertificateType {
         DomainCertificateType::Noop
     }
 }
+
 impl DomainCertificateType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DomainCertificateType::Pem => "PEM",
+            DomainCertificateType::Noop => "",
+            DomainCertificateType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [DomainCertificateType] {
+        &[DomainCertificateType::Pem]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, DomainCertificateType::Noop)
     }
@@ -2006,7 +2271,7 @@ pub struct DomainListResponse {
     pub domains: Vec<Domain>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum DomainValidationStatus {
     #[serde(rename = "COMPLETED")]
     Completed,
@@ -2041,7 +2306,30 @@ impl Default for DomainValidationStatus {
         DomainValidationStatus::Noop
     }
 }
+
 impl DomainValidationStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DomainValidationStatus::Completed => "COMPLETED",
+            DomainValidationStatus::InProgress => "IN_PROGRESS",
+            DomainValidationStatus::NotStarted => "NOT_STARTED",
+            DomainValidationStatus::Verified => "VERIFIED",
+            DomainValidationStatus::Noop => "",
+            DomainValidationStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [DomainValidationStatus] {
+        &[
+            DomainValidationStatus::Completed,
+            DomainValidationStatus::InProgress,
+            DomainValidationStatus::NotStarted,
+            DomainValidationStatus::Verified,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, DomainValidationStatus::Noop)
     }
@@ -2079,7 +2367,7 @@ pub struct EmailUserFactorProfile {
     pub email: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum EnabledStatus {
     #[serde(rename = "DISABLED")]
     Disabled,
@@ -2108,13 +2396,29 @@ impl Default for EnabledStatus {
         EnabledStatus::Noop
     }
 }
+
 impl EnabledStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EnabledStatus::Disabled => "DISABLED",
+            EnabledStatus::Enabled => "ENABLED",
+            EnabledStatus::Noop => "",
+            EnabledStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [EnabledStatus] {
+        &[EnabledStatus::Disabled, EnabledStatus::Enabled]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, EnabledStatus::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum VerificationStatus {
     #[serde(rename = "UNVERIFIED")]
     Unverified,
@@ -2143,7 +2447,23 @@ impl Default for VerificationStatus {
         VerificationStatus::Noop
     }
 }
+
 impl VerificationStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VerificationStatus::Unverified => "UNVERIFIED",
+            VerificationStatus::Verified => "VERIFIED",
+            VerificationStatus::Noop => "",
+            VerificationStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [VerificationStatus] {
+        &[VerificationStatus::Unverified, VerificationStatus::Verified]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, VerificationStatus::Noop)
     }
@@ -2199,7 +2519,7 @@ pub struct EventHook {
     pub verification_status: Option<VerificationStatus>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum EventHookChannelType {
     #[serde(rename = "HTTP")]
     Http,
@@ -2225,7 +2545,22 @@ impl Default for EventHookChannelType {
         EventHookChannelType::Noop
     }
 }
+
 impl EventHookChannelType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EventHookChannelType::Http => "HTTP",
+            EventHookChannelType::Noop => "",
+            EventHookChannelType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [EventHookChannelType] {
+        &[EventHookChannelType::Http]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, EventHookChannelType::Noop)
     }
@@ -2246,12 +2581,9 @@ pub struct EventHookChannel {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct EventHookChannelConfig {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "authScheme"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub auth_scheme: Option<EventHookChannelConfigAuthScheme>,
     #[serde(
         default,
@@ -2285,7 +2617,7 @@ pub struct EventHookChannelConfigAuthScheme {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum EventHookChannelConfigAuthSchemeType {
     #[serde(rename = "HEADER")]
     Header,
@@ -2311,7 +2643,22 @@ impl Default for EventHookChannelConfigAuthSchemeType {
         EventHookChannelConfigAuthSchemeType::Noop
     }
 }
+
 impl EventHookChannelConfigAuthSchemeType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EventHookChannelConfigAuthSchemeType::Header => "HEADER",
+            EventHookChannelConfigAuthSchemeType::Noop => "",
+            EventHookChannelConfigAuthSchemeType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [EventHookChannelConfigAuthSchemeType] {
+        &[EventHookChannelConfigAuthSchemeType::Header]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, EventHookChannelConfigAuthSchemeType::Noop)
     }
@@ -2333,7 +2680,7 @@ pub struct EventHookChannelConfigHeader {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum EventSubscriptionsType {
     #[serde(rename = "EVENT_TYPE")]
     EventType,
@@ -2362,7 +2709,26 @@ impl Default for EventSubscriptionsType {
         EventSubscriptionsType::Noop
     }
 }
+
 impl EventSubscriptionsType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EventSubscriptionsType::EventType => "EVENT_TYPE",
+            EventSubscriptionsType::FlowEvent => "FLOW_EVENT",
+            EventSubscriptionsType::Noop => "",
+            EventSubscriptionsType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [EventSubscriptionsType] {
+        &[
+            EventSubscriptionsType::EventType,
+            EventSubscriptionsType::FlowEvent,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, EventSubscriptionsType::Noop)
     }
@@ -2380,7 +2746,7 @@ pub struct EventSubscriptions {
     pub type_: Option<EventSubscriptionsType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum FactorProvider {
     #[serde(rename = "CUSTOM")]
     Custom,
@@ -2427,13 +2793,44 @@ impl Default for FactorProvider {
         FactorProvider::Noop
     }
 }
+
 impl FactorProvider {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FactorProvider::Custom => "CUSTOM",
+            FactorProvider::Duo => "DUO",
+            FactorProvider::Fido => "FIDO",
+            FactorProvider::Google => "GOOGLE",
+            FactorProvider::Okta => "OKTA",
+            FactorProvider::Rsa => "RSA",
+            FactorProvider::Symantec => "SYMANTEC",
+            FactorProvider::Yubico => "YUBICO",
+            FactorProvider::Noop => "",
+            FactorProvider::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [FactorProvider] {
+        &[
+            FactorProvider::Custom,
+            FactorProvider::Duo,
+            FactorProvider::Fido,
+            FactorProvider::Google,
+            FactorProvider::Okta,
+            FactorProvider::Rsa,
+            FactorProvider::Symantec,
+            FactorProvider::Yubico,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, FactorProvider::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum FactorResultType {
     #[serde(rename = "CANCELLED")]
     Cancelled,
@@ -2486,13 +2883,48 @@ impl Default for FactorResultType {
         FactorResultType::Noop
     }
 }
+
 impl FactorResultType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FactorResultType::Cancelled => "CANCELLED",
+            FactorResultType::Challenge => "CHALLENGE",
+            FactorResultType::Error => "ERROR",
+            FactorResultType::Failed => "FAILED",
+            FactorResultType::PasscodeReplayed => "PASSCODE_REPLAYED",
+            FactorResultType::Rejected => "REJECTED",
+            FactorResultType::Success => "SUCCESS",
+            FactorResultType::Timeout => "TIMEOUT",
+            FactorResultType::TimeWindowExceeded => "TIME_WINDOW_EXCEEDED",
+            FactorResultType::Waiting => "WAITING",
+            FactorResultType::Noop => "",
+            FactorResultType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [FactorResultType] {
+        &[
+            FactorResultType::Cancelled,
+            FactorResultType::Challenge,
+            FactorResultType::Error,
+            FactorResultType::Failed,
+            FactorResultType::PasscodeReplayed,
+            FactorResultType::Rejected,
+            FactorResultType::Success,
+            FactorResultType::Timeout,
+            FactorResultType::TimeWindowExceeded,
+            FactorResultType::Waiting,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, FactorResultType::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum FactorStatus {
     #[serde(rename = "ACTIVE")]
     Active,
@@ -2536,13 +2968,42 @@ impl Default for FactorStatus {
         FactorStatus::Noop
     }
 }
+
 impl FactorStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FactorStatus::Active => "ACTIVE",
+            FactorStatus::Disabled => "DISABLED",
+            FactorStatus::Enrolled => "ENROLLED",
+            FactorStatus::Expired => "EXPIRED",
+            FactorStatus::Inactive => "INACTIVE",
+            FactorStatus::NotSetup => "NOT_SETUP",
+            FactorStatus::PendingActivation => "PENDING_ACTIVATION",
+            FactorStatus::Noop => "",
+            FactorStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [FactorStatus] {
+        &[
+            FactorStatus::Active,
+            FactorStatus::Disabled,
+            FactorStatus::Enrolled,
+            FactorStatus::Expired,
+            FactorStatus::Inactive,
+            FactorStatus::NotSetup,
+            FactorStatus::PendingActivation,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, FactorStatus::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum FactorType {
     #[serde(rename = "call")]
     Call,
@@ -2604,7 +3065,48 @@ impl Default for FactorType {
         FactorType::Noop
     }
 }
+
 impl FactorType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FactorType::Call => "call",
+            FactorType::Email => "email",
+            FactorType::Hotp => "hotp",
+            FactorType::Push => "push",
+            FactorType::Question => "question",
+            FactorType::Sms => "sms",
+            FactorType::Token => "token",
+            FactorType::TokenHardware => "token:hardware",
+            FactorType::TokenHotp => "token:hotp",
+            FactorType::TokenSoftwareTotp => "token:software:totp",
+            FactorType::U2F => "u2f",
+            FactorType::Web => "web",
+            FactorType::Webauthn => "webauthn",
+            FactorType::Noop => "",
+            FactorType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [FactorType] {
+        &[
+            FactorType::Call,
+            FactorType::Email,
+            FactorType::Hotp,
+            FactorType::Push,
+            FactorType::Question,
+            FactorType::Sms,
+            FactorType::Token,
+            FactorType::TokenHardware,
+            FactorType::TokenHotp,
+            FactorType::TokenSoftwareTotp,
+            FactorType::U2F,
+            FactorType::Web,
+            FactorType::Webauthn,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, FactorType::Noop)
     }
@@ -2648,7 +3150,7 @@ pub struct FeatureStage {
     pub value: Option<FeatureStageValue>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum FeatureStageState {
     #[serde(rename = "CLOSED")]
     Closed,
@@ -2677,13 +3179,29 @@ impl Default for FeatureStageState {
         FeatureStageState::Noop
     }
 }
+
 impl FeatureStageState {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FeatureStageState::Closed => "CLOSED",
+            FeatureStageState::Open => "OPEN",
+            FeatureStageState::Noop => "",
+            FeatureStageState::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [FeatureStageState] {
+        &[FeatureStageState::Closed, FeatureStageState::Open]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, FeatureStageState::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum FeatureStageValue {
     #[serde(rename = "BETA")]
     Beta,
@@ -2712,13 +3230,29 @@ impl Default for FeatureStageValue {
         FeatureStageValue::Noop
     }
 }
+
 impl FeatureStageValue {
-    pub fn is_noop(&self) -> bool {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FeatureStageValue::Beta => "BETA",
+            FeatureStageValue::Ea => "EA",
+            FeatureStageValue::Noop => "",
+            FeatureStageValue::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [FeatureStageValue] {
+        &[FeatureStageValue::Beta, FeatureStageValue::Ea]
+    }
+
+    pub fn is_noop(&self) -> bool {
         matches!(self, FeatureStageValue::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum FeatureType {
     #[serde(rename = "self-service")]
     SelfService,
@@ -2744,19 +3278,34 @@ impl Default for FeatureType {
         FeatureType::Noop
     }
 }
+
 impl FeatureType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FeatureType::SelfService => "self-service",
+            FeatureType::Noop => "",
+            FeatureType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [FeatureType] {
+        &[FeatureType::SelfService]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, FeatureType::Noop)
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ResetPasswordToken {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "resetPasswordUrl"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub reset_password_url: String,
 }
@@ -2823,6 +3372,7 @@ pub struct GroupProfile {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct GroupRule {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub actions: Option<GroupRuleAction>,
@@ -2843,8 +3393,7 @@ pub struct GroupRule {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastUpdated"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
     #[serde(
@@ -2865,12 +3414,9 @@ pub struct GroupRule {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct GroupRuleAction {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "assignUserToGroups"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub assign_user_to_groups: Option<GroupRuleAssignment>,
 }
 
@@ -2900,12 +3446,12 @@ pub struct GroupRuleExpression {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct GroupRuleAssignment {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "groupIds"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub group_ids: Vec<String>,
 }
@@ -2918,7 +3464,7 @@ pub struct GroupRulePeopleCondition {
     pub users: Option<UserCondition>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GroupRuleStatus {
     #[serde(rename = "ACTIVE")]
     Active,
@@ -2950,13 +3496,34 @@ impl Default for GroupRuleStatus {
         GroupRuleStatus::Noop
     }
 }
+
 impl GroupRuleStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GroupRuleStatus::Active => "ACTIVE",
+            GroupRuleStatus::Inactive => "INACTIVE",
+            GroupRuleStatus::Invalid => "INVALID",
+            GroupRuleStatus::Noop => "",
+            GroupRuleStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GroupRuleStatus] {
+        &[
+            GroupRuleStatus::Active,
+            GroupRuleStatus::Inactive,
+            GroupRuleStatus::Invalid,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, GroupRuleStatus::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GroupType {
     #[serde(rename = "APP_GROUP")]
     AppGroup,
@@ -2988,7 +3555,28 @@ impl Default for GroupType {
         GroupType::Noop
     }
 }
+
 impl GroupType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GroupType::AppGroup => "APP_GROUP",
+            GroupType::BuiltIn => "BUILT_IN",
+            GroupType::OktaGroup => "OKTA_GROUP",
+            GroupType::Noop => "",
+            GroupType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GroupType] {
+        &[
+            GroupType::AppGroup,
+            GroupType::BuiltIn,
+            GroupType::OktaGroup,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, GroupType::Noop)
     }
@@ -3001,17 +3589,17 @@ pub struct HardwareUserFactor {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct WebUserFactorProfile {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "credentialId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub credential_id: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum IdentityProviderIssuerMode {
     #[serde(rename = "CUSTOM_URL_DOMAIN")]
     CustomUrlDomain,
@@ -3040,13 +3628,32 @@ impl Default for IdentityProviderIssuerMode {
         IdentityProviderIssuerMode::Noop
     }
 }
+
 impl IdentityProviderIssuerMode {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IdentityProviderIssuerMode::CustomUrlDomain => "CUSTOM_URL_DOMAIN",
+            IdentityProviderIssuerMode::OrgUrl => "ORG_URL",
+            IdentityProviderIssuerMode::Noop => "",
+            IdentityProviderIssuerMode::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [IdentityProviderIssuerMode] {
+        &[
+            IdentityProviderIssuerMode::CustomUrlDomain,
+            IdentityProviderIssuerMode::OrgUrl,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, IdentityProviderIssuerMode::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum IdentityProviderType {
     #[serde(rename = "AgentlessDSSO")]
     AgentlessDsso,
@@ -3099,7 +3706,42 @@ impl Default for IdentityProviderType {
         IdentityProviderType::Noop
     }
 }
+
 impl IdentityProviderType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IdentityProviderType::AgentlessDsso => "AgentlessDSSO",
+            IdentityProviderType::Facebook => "FACEBOOK",
+            IdentityProviderType::Google => "GOOGLE",
+            IdentityProviderType::Iwa => "IWA",
+            IdentityProviderType::Linkedin => "LINKEDIN",
+            IdentityProviderType::Microsoft => "MICROSOFT",
+            IdentityProviderType::Oidc => "OIDC",
+            IdentityProviderType::Okta => "OKTA",
+            IdentityProviderType::Saml2 => "SAML2",
+            IdentityProviderType::X509 => "X509",
+            IdentityProviderType::Noop => "",
+            IdentityProviderType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [IdentityProviderType] {
+        &[
+            IdentityProviderType::AgentlessDsso,
+            IdentityProviderType::Facebook,
+            IdentityProviderType::Google,
+            IdentityProviderType::Iwa,
+            IdentityProviderType::Linkedin,
+            IdentityProviderType::Microsoft,
+            IdentityProviderType::Oidc,
+            IdentityProviderType::Okta,
+            IdentityProviderType::Saml2,
+            IdentityProviderType::X509,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, IdentityProviderType::Noop)
     }
@@ -3222,7 +3864,7 @@ pub struct IdentityProviderCredentialsSigning {
     pub kid: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Revocation {
     #[serde(rename = "CRL")]
     Crl,
@@ -3254,13 +3896,31 @@ impl Default for Revocation {
         Revocation::Noop
     }
 }
+
 impl Revocation {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Revocation::Crl => "CRL",
+            Revocation::DeltaCrl => "DELTA_CRL",
+            Revocation::Ocsp => "OCSP",
+            Revocation::Noop => "",
+            Revocation::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Revocation] {
+        &[Revocation::Crl, Revocation::DeltaCrl, Revocation::Ocsp]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Revocation::Noop)
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct IdentityProviderCredentialsTrust {
     #[serde(
         default,
@@ -3285,25 +3945,20 @@ pub struct IdentityProviderCredentialsTrust {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "revocationCacheLifetime"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub revocation_cache_lifetime: i64,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct IdentityProviderPolicy {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "accountLink"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub account_link: Option<PolicyAccountLink>,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "maxClockSkew"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub max_clock_skew: i64,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -3312,7 +3967,7 @@ pub struct IdentityProviderPolicy {
     pub subject: Option<PolicySubject>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Provider {
     #[serde(rename = "ANY")]
     Any,
@@ -3344,19 +3999,36 @@ impl Default for Provider {
         Provider::Noop
     }
 }
+
 impl Provider {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Provider::Any => "ANY",
+            Provider::Okta => "OKTA",
+            Provider::SpecificIdp => "SPECIFIC_IDP",
+            Provider::Noop => "",
+            Provider::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Provider] {
+        &[Provider::Any, Provider::Okta, Provider::SpecificIdp]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Provider::Noop)
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct IdentityProviderPolicyRuleCondition {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "idpIds"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub idp_ids: Vec<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -3421,12 +4093,9 @@ pub struct InlineHookChannel {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct InlineHookChannelConfig {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "authScheme"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub auth_scheme: Option<InlineHookChannelConfigAuthScheme>,
     #[serde(
         default,
@@ -3520,7 +4189,7 @@ pub struct InlineHookResponseCommands {
     pub value: Vec<InlineHookResponseCommandValue>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum InlineHookType {
     #[serde(rename = "com.okta.import.transform")]
     ComOktaImportTransform,
@@ -3560,7 +4229,34 @@ impl Default for InlineHookType {
         InlineHookType::Noop
     }
 }
+
 impl InlineHookType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InlineHookType::ComOktaImportTransform => "com.okta.import.transform",
+            InlineHookType::ComOktaOauth2TokensTransform => "com.okta.oauth2.tokens.transform",
+            InlineHookType::ComOktaSamlTokensTransform => "com.okta.saml.tokens.transform",
+            InlineHookType::ComOktaUserCredentialPasswordImport => {
+                "com.okta.user.credential.password.import"
+            }
+            InlineHookType::ComOktaUserPreRegistration => "com.okta.user.pre-registration",
+            InlineHookType::Noop => "",
+            InlineHookType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [InlineHookType] {
+        &[
+            InlineHookType::ComOktaImportTransform,
+            InlineHookType::ComOktaOauth2TokensTransform,
+            InlineHookType::ComOktaSamlTokensTransform,
+            InlineHookType::ComOktaUserCredentialPasswordImport,
+            InlineHookType::ComOktaUserPreRegistration,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, InlineHookType::Noop)
     }
@@ -3614,6 +4310,7 @@ pub struct IonField {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct IonForm {
     #[serde(
         default,
@@ -3660,8 +4357,7 @@ pub struct IonForm {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "relatesTo"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub relates_to: Vec<String>,
     #[serde(
@@ -3782,12 +4478,12 @@ pub struct JwkUse {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct LifecycleExpirationPolicyRuleCondition {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "lifecycleStatus"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub lifecycle_status: String,
     #[serde(
@@ -3838,7 +4534,7 @@ pub struct LinkedObjectDetails {
     pub type_: Option<LinkedObjectDetailsType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum LinkedObjectDetailsType {
     #[serde(rename = "USER")]
     User,
@@ -3864,19 +4560,34 @@ impl Default for LinkedObjectDetailsType {
         LinkedObjectDetailsType::Noop
     }
 }
+
 impl LinkedObjectDetailsType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LinkedObjectDetailsType::User => "USER",
+            LinkedObjectDetailsType::Noop => "",
+            LinkedObjectDetailsType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [LinkedObjectDetailsType] {
+        &[LinkedObjectDetailsType::User]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, LinkedObjectDetailsType::Noop)
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct LogActor {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "alternateId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub alternate_id: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -3884,8 +4595,7 @@ pub struct LogActor {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "displayName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub display_name: String,
     #[serde(
@@ -3904,37 +4614,24 @@ pub struct LogActor {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct LogAuthenticationContext {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "authenticationProvider"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub authentication_provider: Option<LogAuthenticationProvider>,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "authenticationStep"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub authentication_step: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "credentialProvider"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub credential_provider: Option<LogCredentialProvider>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "credentialType"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub credential_type: Option<LogCredentialType>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "externalSessionId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub external_session_id: String,
     #[serde(
@@ -3947,7 +4644,7 @@ pub struct LogAuthenticationContext {
     pub issuer: Option<LogIssuer>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum LogAuthenticationProvider {
     #[serde(rename = "ACTIVE_DIRECTORY")]
     ActiveDirectory,
@@ -3988,13 +4685,41 @@ impl Default for LogAuthenticationProvider {
         LogAuthenticationProvider::Noop
     }
 }
+
 impl LogAuthenticationProvider {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LogAuthenticationProvider::ActiveDirectory => "ACTIVE_DIRECTORY",
+            LogAuthenticationProvider::FactorProvider => "FACTOR_PROVIDER",
+            LogAuthenticationProvider::Federation => "FEDERATION",
+            LogAuthenticationProvider::Ldap => "LDAP",
+            LogAuthenticationProvider::OktaAuthenticationProvider => "OKTA_AUTHENTICATION_PROVIDER",
+            LogAuthenticationProvider::Social => "SOCIAL",
+            LogAuthenticationProvider::Noop => "",
+            LogAuthenticationProvider::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [LogAuthenticationProvider] {
+        &[
+            LogAuthenticationProvider::ActiveDirectory,
+            LogAuthenticationProvider::FactorProvider,
+            LogAuthenticationProvider::Federation,
+            LogAuthenticationProvider::Ldap,
+            LogAuthenticationProvider::OktaAuthenticationProvider,
+            LogAuthenticationProvider::Social,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, LogAuthenticationProvider::Noop)
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct LogClient {
     #[serde(
         default,
@@ -4002,11 +4727,7 @@ pub struct LogClient {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub device: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "geographicalContext"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub geographical_context: Option<LogGeographicalContext>,
     #[serde(
         default,
@@ -4017,11 +4738,10 @@ pub struct LogClient {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "ipAddress"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub ip_address: String,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "userAgent")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user_agent: Option<LogUserAgent>,
     #[serde(
         default,
@@ -4031,7 +4751,7 @@ pub struct LogClient {
     pub zone: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum LogCredentialProvider {
     #[serde(rename = "DUO")]
     Duo,
@@ -4075,13 +4795,42 @@ impl Default for LogCredentialProvider {
         LogCredentialProvider::Noop
     }
 }
+
 impl LogCredentialProvider {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LogCredentialProvider::Duo => "DUO",
+            LogCredentialProvider::Google => "GOOGLE",
+            LogCredentialProvider::OktaAuthenticationProvider => "OKTA_AUTHENTICATION_PROVIDER",
+            LogCredentialProvider::OktaCredentialProvider => "OKTA_CREDENTIAL_PROVIDER",
+            LogCredentialProvider::Rsa => "RSA",
+            LogCredentialProvider::Symantec => "SYMANTEC",
+            LogCredentialProvider::Yubikey => "YUBIKEY",
+            LogCredentialProvider::Noop => "",
+            LogCredentialProvider::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [LogCredentialProvider] {
+        &[
+            LogCredentialProvider::Duo,
+            LogCredentialProvider::Google,
+            LogCredentialProvider::OktaAuthenticationProvider,
+            LogCredentialProvider::OktaCredentialProvider,
+            LogCredentialProvider::Rsa,
+            LogCredentialProvider::Symantec,
+            LogCredentialProvider::Yubikey,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, LogCredentialProvider::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum LogCredentialType {
     #[serde(rename = "ASSERTION")]
     Assertion,
@@ -4128,55 +4877,77 @@ impl Default for LogCredentialType {
         LogCredentialType::Noop
     }
 }
+
 impl LogCredentialType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LogCredentialType::Assertion => "ASSERTION",
+            LogCredentialType::Email => "EMAIL",
+            LogCredentialType::Iwa => "IWA",
+            LogCredentialType::Jwt => "JWT",
+            LogCredentialType::Oauth2 => "OAUTH2",
+            LogCredentialType::Otp => "OTP",
+            LogCredentialType::Password => "PASSWORD",
+            LogCredentialType::Sms => "SMS",
+            LogCredentialType::Noop => "",
+            LogCredentialType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [LogCredentialType] {
+        &[
+            LogCredentialType::Assertion,
+            LogCredentialType::Email,
+            LogCredentialType::Iwa,
+            LogCredentialType::Jwt,
+            LogCredentialType::Oauth2,
+            LogCredentialType::Otp,
+            LogCredentialType::Password,
+            LogCredentialType::Sms,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, LogCredentialType::Noop)
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct LogDebugContext {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "debugData")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub debug_data: Option<Links>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct LogEvent {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub actor: Option<LogActor>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "authenticationContext"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub authentication_context: Option<LogAuthenticationContext>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub client: Option<LogClient>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "debugContext"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub debug_context: Option<LogDebugContext>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "displayMessage"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub display_message: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "eventType"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub event_type: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "legacyEventType"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub legacy_event_type: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -4189,14 +4960,10 @@ pub struct LogEvent {
     pub published: Option<chrono::DateTime<chrono::Utc>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub request: Option<LogRequest>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "securityContext"
-    )]
-    pub security_context: Option<LogSecurityContext>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub severity: Option<LogSeverity>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub security_context: Option<LogSecurityContext>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<LogSeverity>,
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -4220,6 +4987,7 @@ pub struct LogEvent {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct LogGeographicalContext {
     #[serde(
         default,
@@ -4238,8 +5006,7 @@ pub struct LogGeographicalContext {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "postalCode"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub postal_code: String,
     #[serde(
@@ -4267,12 +5034,9 @@ pub struct LogGeolocation {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct LogIpAddress {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "geographicalContext"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub geographical_context: Option<LogGeographicalContext>,
     #[serde(
         default,
@@ -4328,30 +5092,29 @@ pub struct LogOutcome {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct LogRequest {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "ipChain"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub ip_chain: Vec<LogIpAddress>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct LogSecurityContext {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "asNumber"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub as_number: i64,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "asOrg"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub as_org: String,
     #[serde(
@@ -4362,8 +5125,7 @@ pub struct LogSecurityContext {
     pub domain: String,
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "isProxy"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub is_proxy: bool,
     #[serde(
@@ -4374,7 +5136,7 @@ pub struct LogSecurityContext {
     pub isp: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum LogSeverity {
     #[serde(rename = "DEBUG")]
     Debug,
@@ -4409,32 +5171,50 @@ impl Default for LogSeverity {
         LogSeverity::Noop
     }
 }
+
 impl LogSeverity {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LogSeverity::Debug => "DEBUG",
+            LogSeverity::Error => "ERROR",
+            LogSeverity::Info => "INFO",
+            LogSeverity::Warn => "WARN",
+            LogSeverity::Noop => "",
+            LogSeverity::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [LogSeverity] {
+        &[
+            LogSeverity::Debug,
+            LogSeverity::Error,
+            LogSeverity::Info,
+            LogSeverity::Warn,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, LogSeverity::Noop)
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct LogTarget {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "alternateId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub alternate_id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "detailEntry"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub detail_entry: Option<Links>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "displayName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub display_name: String,
     #[serde(
@@ -4472,6 +5252,7 @@ pub struct LogTransaction {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct LogUserAgent {
     #[serde(
         default,
@@ -4488,13 +5269,12 @@ pub struct LogUserAgent {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "rawUserAgent"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub raw_user_agent: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Enrollment {
     #[serde(rename = "ANY_OR_NONE")]
     AnyOrNone,
@@ -4523,18 +5303,34 @@ impl Default for Enrollment {
         Enrollment::Noop
     }
 }
+
 impl Enrollment {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Enrollment::AnyOrNone => "ANY_OR_NONE",
+            Enrollment::Omm => "OMM",
+            Enrollment::Noop => "",
+            Enrollment::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Enrollment] {
+        &[Enrollment::AnyOrNone, Enrollment::Omm]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Enrollment::Noop)
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct MdmEnrollmentPolicyRuleCondition {
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "blockNonSafeAndroid"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub block_non_safe_android: bool,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -4626,7 +5422,7 @@ pub struct NetworkZoneAddress {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum NetworkZoneAddressType {
     #[serde(rename = "CIDR")]
     Cidr,
@@ -4655,7 +5451,23 @@ impl Default for NetworkZoneAddressType {
         NetworkZoneAddressType::Noop
     }
 }
+
 impl NetworkZoneAddressType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NetworkZoneAddressType::Cidr => "CIDR",
+            NetworkZoneAddressType::Range => "RANGE",
+            NetworkZoneAddressType::Noop => "",
+            NetworkZoneAddressType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [NetworkZoneAddressType] {
+        &[NetworkZoneAddressType::Cidr, NetworkZoneAddressType::Range]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, NetworkZoneAddressType::Noop)
     }
@@ -4677,7 +5489,7 @@ pub struct NetworkZoneLocation {
     pub region: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum NetworkZoneType {
     #[serde(rename = "DYNAMIC")]
     Dynamic,
@@ -4706,13 +5518,29 @@ impl Default for NetworkZoneType {
         NetworkZoneType::Noop
     }
 }
+
 impl NetworkZoneType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NetworkZoneType::Dynamic => "DYNAMIC",
+            NetworkZoneType::Ip => "IP",
+            NetworkZoneType::Noop => "",
+            NetworkZoneType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [NetworkZoneType] {
+        &[NetworkZoneType::Dynamic, NetworkZoneType::Ip]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, NetworkZoneType::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum NetworkZoneUsage {
     #[serde(rename = "BLOCKLIST")]
     Blocklist,
@@ -4741,13 +5569,29 @@ impl Default for NetworkZoneUsage {
         NetworkZoneUsage::Noop
     }
 }
+
 impl NetworkZoneUsage {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NetworkZoneUsage::Blocklist => "BLOCKLIST",
+            NetworkZoneUsage::Policy => "POLICY",
+            NetworkZoneUsage::Noop => "",
+            NetworkZoneUsage::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [NetworkZoneUsage] {
+        &[NetworkZoneUsage::Blocklist, NetworkZoneUsage::Policy]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, NetworkZoneUsage::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ClaimType {
     #[serde(rename = "IDENTITY")]
     Identity,
@@ -4776,13 +5620,29 @@ impl Default for ClaimType {
         ClaimType::Noop
     }
 }
+
 impl ClaimType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ClaimType::Identity => "IDENTITY",
+            ClaimType::Resource => "RESOURCE",
+            ClaimType::Noop => "",
+            ClaimType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ClaimType] {
+        &[ClaimType::Identity, ClaimType::Resource]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ClaimType::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GroupFilterType {
     #[serde(rename = "CONTAINS")]
     Contains,
@@ -4817,13 +5677,36 @@ impl Default for GroupFilterType {
         GroupFilterType::Noop
     }
 }
+
 impl GroupFilterType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GroupFilterType::Contains => "CONTAINS",
+            GroupFilterType::Equals => "EQUALS",
+            GroupFilterType::Regex => "REGEX",
+            GroupFilterType::StartsWith => "STARTS_WITH",
+            GroupFilterType::Noop => "",
+            GroupFilterType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GroupFilterType] {
+        &[
+            GroupFilterType::Contains,
+            GroupFilterType::Equals,
+            GroupFilterType::Regex,
+            GroupFilterType::StartsWith,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, GroupFilterType::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ValueType {
     #[serde(rename = "EXPRESSION")]
     Expression,
@@ -4855,7 +5738,24 @@ impl Default for ValueType {
         ValueType::Noop
     }
 }
+
 impl ValueType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ValueType::Expression => "EXPRESSION",
+            ValueType::Groups => "GROUPS",
+            ValueType::System => "SYSTEM",
+            ValueType::Noop => "",
+            ValueType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ValueType] {
+        &[ValueType::Expression, ValueType::Groups, ValueType::System]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ValueType::Noop)
     }
@@ -4946,7 +5846,7 @@ pub struct OAuth2Client {
     pub logo_uri: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum OAuth2RefreshTokenStatus {
     #[serde(rename = "ACTIVE")]
     Active,
@@ -4975,7 +5875,26 @@ impl Default for OAuth2RefreshTokenStatus {
         OAuth2RefreshTokenStatus::Noop
     }
 }
+
 impl OAuth2RefreshTokenStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OAuth2RefreshTokenStatus::Active => "ACTIVE",
+            OAuth2RefreshTokenStatus::Revoked => "REVOKED",
+            OAuth2RefreshTokenStatus::Noop => "",
+            OAuth2RefreshTokenStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [OAuth2RefreshTokenStatus] {
+        &[
+            OAuth2RefreshTokenStatus::Active,
+            OAuth2RefreshTokenStatus::Revoked,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, OAuth2RefreshTokenStatus::Noop)
     }
@@ -5045,7 +5964,7 @@ pub struct OAuth2RefreshToken {
     pub user_id: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Consent {
     #[serde(rename = "ADMIN")]
     Admin,
@@ -5077,13 +5996,30 @@ impl Default for Consent {
         Consent::Noop
     }
 }
+
 impl Consent {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Consent::Admin => "ADMIN",
+            Consent::Implicit => "IMPLICIT",
+            Consent::Required => "REQUIRED",
+            Consent::Noop => "",
+            Consent::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Consent] {
+        &[Consent::Admin, Consent::Implicit, Consent::Required]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Consent::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum MetadataPublish {
     #[serde(rename = "ALL_CLIENTS")]
     AllClients,
@@ -5112,13 +6048,30 @@ impl Default for MetadataPublish {
         MetadataPublish::Noop
     }
 }
+
 impl MetadataPublish {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MetadataPublish::AllClients => "ALL_CLIENTS",
+            MetadataPublish::NoClients => "NO_CLIENTS",
+            MetadataPublish::Noop => "",
+            MetadataPublish::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [MetadataPublish] {
+        &[MetadataPublish::AllClients, MetadataPublish::NoClients]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, MetadataPublish::Noop)
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct OAuth2Scope {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub consent: Option<Consent>,
@@ -5136,8 +6089,7 @@ pub struct OAuth2Scope {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "displayName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub display_name: String,
     #[serde(
@@ -5146,11 +6098,7 @@ pub struct OAuth2Scope {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "metadataPublish"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub metadata_publish: Option<MetadataPublish>,
     #[serde(
         default,
@@ -5225,7 +6173,7 @@ pub struct OAuth2ScopeConsentGrant {
     pub user_id: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum OAuth2ScopeConsentGrantSource {
     #[serde(rename = "ADMIN")]
     Admin,
@@ -5254,7 +6202,26 @@ impl Default for OAuth2ScopeConsentGrantSource {
         OAuth2ScopeConsentGrantSource::Noop
     }
 }
+
 impl OAuth2ScopeConsentGrantSource {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OAuth2ScopeConsentGrantSource::Admin => "ADMIN",
+            OAuth2ScopeConsentGrantSource::EndUser => "END_USER",
+            OAuth2ScopeConsentGrantSource::Noop => "",
+            OAuth2ScopeConsentGrantSource::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [OAuth2ScopeConsentGrantSource] {
+        &[
+            OAuth2ScopeConsentGrantSource::Admin,
+            OAuth2ScopeConsentGrantSource::EndUser,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, OAuth2ScopeConsentGrantSource::Noop)
     }
@@ -5323,16 +6290,13 @@ pub struct OAuth2Token {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct OAuthApplicationCredentials {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "oauthClient"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub oauth_client: Option<ApplicationCredentialsOAuthClient>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum OAuthEndpointAuthenticationMethod {
     #[serde(rename = "client_secret_basic")]
     ClientSecretBasic,
@@ -5370,13 +6334,38 @@ impl Default for OAuthEndpointAuthenticationMethod {
         OAuthEndpointAuthenticationMethod::Noop
     }
 }
+
 impl OAuthEndpointAuthenticationMethod {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OAuthEndpointAuthenticationMethod::ClientSecretBasic => "client_secret_basic",
+            OAuthEndpointAuthenticationMethod::ClientSecretJwt => "client_secret_jwt",
+            OAuthEndpointAuthenticationMethod::ClientSecretPost => "client_secret_post",
+            OAuthEndpointAuthenticationMethod::None => "none",
+            OAuthEndpointAuthenticationMethod::PrivateKeyJwt => "private_key_jwt",
+            OAuthEndpointAuthenticationMethod::Noop => "",
+            OAuthEndpointAuthenticationMethod::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [OAuthEndpointAuthenticationMethod] {
+        &[
+            OAuthEndpointAuthenticationMethod::ClientSecretBasic,
+            OAuthEndpointAuthenticationMethod::ClientSecretJwt,
+            OAuthEndpointAuthenticationMethod::ClientSecretPost,
+            OAuthEndpointAuthenticationMethod::None,
+            OAuthEndpointAuthenticationMethod::PrivateKeyJwt,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, OAuthEndpointAuthenticationMethod::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum OAuthGrantType {
     #[serde(rename = "authorization_code")]
     AuthorizationCode,
@@ -5414,13 +6403,38 @@ impl Default for OAuthGrantType {
         OAuthGrantType::Noop
     }
 }
+
 impl OAuthGrantType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OAuthGrantType::AuthorizationCode => "authorization_code",
+            OAuthGrantType::ClientCredentials => "client_credentials",
+            OAuthGrantType::Implicit => "implicit",
+            OAuthGrantType::Password => "password",
+            OAuthGrantType::RefreshToken => "refresh_token",
+            OAuthGrantType::Noop => "",
+            OAuthGrantType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [OAuthGrantType] {
+        &[
+            OAuthGrantType::AuthorizationCode,
+            OAuthGrantType::ClientCredentials,
+            OAuthGrantType::Implicit,
+            OAuthGrantType::Password,
+            OAuthGrantType::RefreshToken,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, OAuthGrantType::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum OAuthResponseType {
     #[serde(rename = "code")]
     Code,
@@ -5452,7 +6466,28 @@ impl Default for OAuthResponseType {
         OAuthResponseType::Noop
     }
 }
+
 impl OAuthResponseType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OAuthResponseType::Code => "code",
+            OAuthResponseType::IdToken => "id_token",
+            OAuthResponseType::Token => "token",
+            OAuthResponseType::Noop => "",
+            OAuthResponseType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [OAuthResponseType] {
+        &[
+            OAuthResponseType::Code,
+            OAuthResponseType::IdToken,
+            OAuthResponseType::Token,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, OAuthResponseType::Noop)
     }
@@ -5491,12 +6526,9 @@ pub struct OktaSignOnPolicyRuleActions {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct OktaSignOnPolicyRuleConditions {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "authContext"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub auth_context: Option<PolicyRuleAuthContextCondition>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub network: Option<PolicyNetworkCondition>,
@@ -5504,7 +6536,7 @@ pub struct OktaSignOnPolicyRuleConditions {
     pub people: Option<PolicyPeopleCondition>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Access {
     #[serde(rename = "ALLOW")]
     Allow,
@@ -5533,13 +6565,29 @@ impl Default for Access {
         Access::Noop
     }
 }
+
 impl Access {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Access::Allow => "ALLOW",
+            Access::Deny => "DENY",
+            Access::Noop => "",
+            Access::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Access] {
+        &[Access::Allow, Access::Deny]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Access::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum FactorPromptMode {
     #[serde(rename = "ALWAYS")]
     Always,
@@ -5571,39 +6619,54 @@ impl Default for FactorPromptMode {
         FactorPromptMode::Noop
     }
 }
+
 impl FactorPromptMode {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FactorPromptMode::Always => "ALWAYS",
+            FactorPromptMode::Device => "DEVICE",
+            FactorPromptMode::Session => "SESSION",
+            FactorPromptMode::Noop => "",
+            FactorPromptMode::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [FactorPromptMode] {
+        &[
+            FactorPromptMode::Always,
+            FactorPromptMode::Device,
+            FactorPromptMode::Session,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, FactorPromptMode::Noop)
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct OktaSignOnPolicyRuleSignonActions {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub access: Option<Access>,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "factorLifetime"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub factor_lifetime: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "factorPromptMode"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub factor_prompt_mode: Option<FactorPromptMode>,
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "rememberDeviceByDefault"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub remember_device_by_default: bool,
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "requireFactor"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub require_factor: bool,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -5611,25 +6674,23 @@ pub struct OktaSignOnPolicyRuleSignonActions {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct OktaSignOnPolicyRuleSignonSessionActions {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "maxSessionIdleMinutes"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub max_session_idle_minutes: i64,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "maxSessionLifetimeMinutes"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub max_session_lifetime_minutes: i64,
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "usePersistentCookie"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub use_persistent_cookie: bool,
 }
@@ -5644,7 +6705,7 @@ pub struct OpenConnectApplication {
     pub settings: Option<OpenConnectApplicationSettings>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum OpenConnectApplicationConsentMethod {
     #[serde(rename = "REQUIRED")]
     Required,
@@ -5673,7 +6734,26 @@ impl Default for OpenConnectApplicationConsentMethod {
         OpenConnectApplicationConsentMethod::Noop
     }
 }
+
 impl OpenConnectApplicationConsentMethod {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OpenConnectApplicationConsentMethod::Required => "REQUIRED",
+            OpenConnectApplicationConsentMethod::Trusted => "TRUSTED",
+            OpenConnectApplicationConsentMethod::Noop => "",
+            OpenConnectApplicationConsentMethod::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [OpenConnectApplicationConsentMethod] {
+        &[
+            OpenConnectApplicationConsentMethod::Required,
+            OpenConnectApplicationConsentMethod::Trusted,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, OpenConnectApplicationConsentMethod::Noop)
     }
@@ -5696,12 +6776,9 @@ pub struct OpenConnectApplicationIdpInitiatedLogin {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct OpenConnectApplicationSettings {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "oauthClient"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub oauth_client: Option<OpenConnectApplicationSettingsClient>,
 }
 
@@ -5803,7 +6880,7 @@ pub struct OpenConnectApplicationSettingsRefreshToken {
     pub rotation_type: Option<OpenConnectRefreshTokenRotationType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum OpenConnectApplicationType {
     #[serde(rename = "browser")]
     Browser,
@@ -5838,13 +6915,36 @@ impl Default for OpenConnectApplicationType {
         OpenConnectApplicationType::Noop
     }
 }
+
 impl OpenConnectApplicationType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OpenConnectApplicationType::Browser => "browser",
+            OpenConnectApplicationType::Native => "native",
+            OpenConnectApplicationType::Service => "service",
+            OpenConnectApplicationType::Web => "web",
+            OpenConnectApplicationType::Noop => "",
+            OpenConnectApplicationType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [OpenConnectApplicationType] {
+        &[
+            OpenConnectApplicationType::Browser,
+            OpenConnectApplicationType::Native,
+            OpenConnectApplicationType::Service,
+            OpenConnectApplicationType::Web,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, OpenConnectApplicationType::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum OpenConnectRefreshTokenRotationType {
     #[serde(rename = "rotate")]
     Rotate,
@@ -5873,7 +6973,26 @@ impl Default for OpenConnectRefreshTokenRotationType {
         OpenConnectRefreshTokenRotationType::Noop
     }
 }
+
 impl OpenConnectRefreshTokenRotationType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OpenConnectRefreshTokenRotationType::Rotate => "rotate",
+            OpenConnectRefreshTokenRotationType::Static => "static",
+            OpenConnectRefreshTokenRotationType::Noop => "",
+            OpenConnectRefreshTokenRotationType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [OpenConnectRefreshTokenRotationType] {
+        &[
+            OpenConnectRefreshTokenRotationType::Rotate,
+            OpenConnectRefreshTokenRotationType::Static,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, OpenConnectRefreshTokenRotationType::Noop)
     }
@@ -5894,6 +7013,7 @@ pub struct PasswordCredential {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct PasswordCredentialHash {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub algorithm: Option<PasswordCredentialHashAlgorithm>,
@@ -5906,8 +7026,7 @@ pub struct PasswordCredentialHash {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "saltOrder"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub salt_order: String,
     #[serde(
@@ -5919,13 +7038,12 @@ pub struct PasswordCredentialHash {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "workFactor"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub work_factor: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PasswordCredentialHashAlgorithm {
     #[serde(rename = "BCRYPT")]
     Bcrypt,
@@ -5963,7 +7081,32 @@ impl Default for PasswordCredentialHashAlgorithm {
         PasswordCredentialHashAlgorithm::Noop
     }
 }
+
 impl PasswordCredentialHashAlgorithm {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PasswordCredentialHashAlgorithm::Bcrypt => "BCRYPT",
+            PasswordCredentialHashAlgorithm::Md5 => "MD5",
+            PasswordCredentialHashAlgorithm::ShAminusOne => "SHA-1",
+            PasswordCredentialHashAlgorithm::Sha256 => "SHA-256",
+            PasswordCredentialHashAlgorithm::Sha512 => "SHA-512",
+            PasswordCredentialHashAlgorithm::Noop => "",
+            PasswordCredentialHashAlgorithm::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PasswordCredentialHashAlgorithm] {
+        &[
+            PasswordCredentialHashAlgorithm::Bcrypt,
+            PasswordCredentialHashAlgorithm::Md5,
+            PasswordCredentialHashAlgorithm::ShAminusOne,
+            PasswordCredentialHashAlgorithm::Sha256,
+            PasswordCredentialHashAlgorithm::Sha512,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PasswordCredentialHashAlgorithm::Noop)
     }
@@ -6003,7 +7146,7 @@ pub struct PasswordPolicy {
     pub settings: Option<PasswordPolicySettingsData>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PasswordPolicyAuthenticationProviderCondition {
     #[serde(rename = "ACTIVE_DIRECTORY")]
     ActiveDirectory,
@@ -6038,7 +7181,30 @@ impl Default for PasswordPolicyAuthenticationProviderCondition {
         PasswordPolicyAuthenticationProviderCondition::Noop
     }
 }
+
 impl PasswordPolicyAuthenticationProviderCondition {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PasswordPolicyAuthenticationProviderCondition::ActiveDirectory => "ACTIVE_DIRECTORY",
+            PasswordPolicyAuthenticationProviderCondition::Any => "ANY",
+            PasswordPolicyAuthenticationProviderCondition::Ldap => "LDAP",
+            PasswordPolicyAuthenticationProviderCondition::Okta => "OKTA",
+            PasswordPolicyAuthenticationProviderCondition::Noop => "",
+            PasswordPolicyAuthenticationProviderCondition::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PasswordPolicyAuthenticationProviderCondition] {
+        &[
+            PasswordPolicyAuthenticationProviderCondition::ActiveDirectory,
+            PasswordPolicyAuthenticationProviderCondition::Any,
+            PasswordPolicyAuthenticationProviderCondition::Ldap,
+            PasswordPolicyAuthenticationProviderCondition::Okta,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PasswordPolicyAuthenticationProviderCondition::Noop)
     }
@@ -6057,12 +7223,9 @@ pub struct PasswordPolicyAuthenticationProviderConditionData {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct PasswordPolicyConditions {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "authProvider"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub auth_provider: Option<PasswordPolicyAuthenticationProviderConditionData>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub people: Option<PolicyPeopleCondition>,
@@ -6075,11 +7238,11 @@ pub struct PasswordPolicyDelegationSettings {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct PasswordPolicyDelegationSettingsOptions {
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "skipUnlock"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub skip_unlock: bool,
 }
@@ -6095,118 +7258,106 @@ pub struct PasswordPolicySettings {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct PasswordPolicySettingsAge {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "expireWarnDays"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub expire_warn_days: i64,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "historyCount"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub history_count: i64,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "maxAgeDays"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub max_age_days: i64,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "minAgeMinutes"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub min_age_minutes: i64,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct PasswordPolicySettingsComplexity {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub dictionary: Option<PasswordDictionary>,
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "excludeAttributes"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub exclude_attributes: Vec<String>,
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "excludeUsername"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub exclude_username: bool,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "minLength"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub min_length: i64,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "minLowerCase"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub min_lower_case: i64,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "minNumber"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub min_number: i64,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "minSymbol"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub min_symbol: i64,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "minUpperCase"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub min_upper_case: i64,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct PasswordPolicySettingsLockout {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "autoUnlockMinutes"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub auto_unlock_minutes: i64,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "maxAttempts"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub max_attempts: i64,
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "showLockoutFailures"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub show_lockout_failures: bool,
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "userLockoutNotificationChannels"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub user_lockout_notification_channels: Vec<String>,
 }
@@ -6220,22 +7371,19 @@ pub struct PasswordPolicyRecoveryEmail {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct PasswordPolicyRecoveryEmailProperties {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "recoveryToken"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub recovery_token: Option<PasswordPolicyRecoveryEmailToken>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct PasswordPolicyRecoveryEmailToken {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "tokenLifetimeMinutes"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub token_lifetime_minutes: i64,
 }
@@ -6267,12 +7415,12 @@ pub struct PasswordPolicyRecoveryQuestion {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct PasswordPolicyRecoveryQuestionComplexity {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "minLength"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub min_length: i64,
 }
@@ -6310,24 +7458,13 @@ pub struct PasswordPolicyRuleAction {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct PasswordPolicyRuleActions {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "passwordChange"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub password_change: Option<PasswordPolicyRuleAction>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "selfServicePasswordReset"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub self_service_password_reset: Option<PasswordPolicyRuleAction>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "selfServiceUnlock"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub self_service_unlock: Option<PasswordPolicyRuleAction>,
 }
 
@@ -6349,7 +7486,7 @@ pub struct PasswordPolicySettingsData {
     pub recovery: Option<PasswordPolicyRecoverySettings>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PlatformConditionEvaluatorType {
     #[serde(rename = "ANY")]
     Any,
@@ -6384,7 +7521,30 @@ impl Default for PlatformConditionEvaluatorType {
         PlatformConditionEvaluatorType::Noop
     }
 }
+
 impl PlatformConditionEvaluatorType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PlatformConditionEvaluatorType::Any => "ANY",
+            PlatformConditionEvaluatorType::Desktop => "DESKTOP",
+            PlatformConditionEvaluatorType::Mobile => "MOBILE",
+            PlatformConditionEvaluatorType::Other => "OTHER",
+            PlatformConditionEvaluatorType::Noop => "",
+            PlatformConditionEvaluatorType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PlatformConditionEvaluatorType] {
+        &[
+            PlatformConditionEvaluatorType::Any,
+            PlatformConditionEvaluatorType::Desktop,
+            PlatformConditionEvaluatorType::Mobile,
+            PlatformConditionEvaluatorType::Other,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PlatformConditionEvaluatorType::Noop)
     }
@@ -6398,7 +7558,7 @@ pub struct PlatformConditionEvaluator {
     pub type_: Option<PlatformConditionEvaluatorType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PlatformConditionEvaluatorOperatingSystemType {
     #[serde(rename = "ANDROID")]
     Android,
@@ -6439,7 +7599,34 @@ impl Default for PlatformConditionEvaluatorOperatingSystemType {
         PlatformConditionEvaluatorOperatingSystemType::Noop
     }
 }
+
 impl PlatformConditionEvaluatorOperatingSystemType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PlatformConditionEvaluatorOperatingSystemType::Android => "ANDROID",
+            PlatformConditionEvaluatorOperatingSystemType::Any => "ANY",
+            PlatformConditionEvaluatorOperatingSystemType::Ios => "IOS",
+            PlatformConditionEvaluatorOperatingSystemType::Osx => "OSX",
+            PlatformConditionEvaluatorOperatingSystemType::Other => "OTHER",
+            PlatformConditionEvaluatorOperatingSystemType::Windows => "WINDOWS",
+            PlatformConditionEvaluatorOperatingSystemType::Noop => "",
+            PlatformConditionEvaluatorOperatingSystemType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PlatformConditionEvaluatorOperatingSystemType] {
+        &[
+            PlatformConditionEvaluatorOperatingSystemType::Android,
+            PlatformConditionEvaluatorOperatingSystemType::Any,
+            PlatformConditionEvaluatorOperatingSystemType::Ios,
+            PlatformConditionEvaluatorOperatingSystemType::Osx,
+            PlatformConditionEvaluatorOperatingSystemType::Other,
+            PlatformConditionEvaluatorOperatingSystemType::Windows,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PlatformConditionEvaluatorOperatingSystemType::Noop)
     }
@@ -6459,7 +7646,7 @@ pub struct PlatformConditionEvaluatorOperatingSystem {
     pub version: Option<PlatformConditionEvaluatorOperatingSystemVersion>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum MatchType {
     #[serde(rename = "EXPRESSION")]
     Expression,
@@ -6488,15 +7675,32 @@ impl Default for MatchType {
         MatchType::Noop
     }
 }
+
 impl MatchType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MatchType::Expression => "EXPRESSION",
+            MatchType::Semver => "SEMVER",
+            MatchType::Noop => "",
+            MatchType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [MatchType] {
+        &[MatchType::Expression, MatchType::Semver]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, MatchType::Noop)
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct PlatformConditionEvaluatorOperatingSystemVersion {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "matchType")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub match_type: Option<MatchType>,
     #[serde(
         default,
@@ -6522,7 +7726,7 @@ pub struct PlatformPolicyRuleCondition {
     pub include: Vec<PlatformConditionEvaluator>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Action {
     #[serde(rename = "AUTO")]
     Auto,
@@ -6551,7 +7755,23 @@ impl Default for Action {
         Action::Noop
     }
 }
+
 impl Action {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Action::Auto => "AUTO",
+            Action::Disabled => "DISABLED",
+            Action::Noop => "",
+            Action::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Action] {
+        &[Action::Auto, Action::Disabled]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Action::Noop)
     }
@@ -6571,7 +7791,7 @@ pub struct PolicyAccountLinkFilter {
     pub groups: Option<ClientPolicyCondition>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Connection {
     #[serde(rename = "ANYWHERE")]
     Anywhere,
@@ -6600,7 +7820,23 @@ impl Default for Connection {
         Connection::Noop
     }
 }
+
 impl Connection {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Connection::Anywhere => "ANYWHERE",
+            Connection::Zone => "ZONE",
+            Connection::Noop => "",
+            Connection::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Connection] {
+        &[Connection::Anywhere, Connection::Zone]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Connection::Noop)
     }
@@ -6632,7 +7868,7 @@ pub struct PolicyPeopleCondition {
     pub users: Option<UserCondition>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PolicyRuleType {
     #[serde(rename = "PASSWORD")]
     Password,
@@ -6661,13 +7897,30 @@ impl Default for PolicyRuleType {
         PolicyRuleType::Noop
     }
 }
+
 impl PolicyRuleType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PolicyRuleType::Password => "PASSWORD",
+            PolicyRuleType::SignOn => "SIGN_ON",
+            PolicyRuleType::Noop => "",
+            PolicyRuleType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PolicyRuleType] {
+        &[PolicyRuleType::Password, PolicyRuleType::SignOn]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PolicyRuleType::Noop)
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct PolicyRule {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub actions: Option<PolicyRuleActions>,
@@ -6688,8 +7941,7 @@ pub struct PolicyRule {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastUpdated"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
     #[serde(
@@ -6716,26 +7968,15 @@ pub struct PolicyRule {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct PolicyRuleActions {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub enroll: Option<PolicyRuleActionsEnroll>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "passwordChange"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub password_change: Option<PasswordPolicyRuleAction>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "selfServicePasswordReset"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub self_service_password_reset: Option<PasswordPolicyRuleAction>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "selfServiceUnlock"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub self_service_unlock: Option<PasswordPolicyRuleAction>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub signon: Option<OktaSignOnPolicyRuleSignonActions>,
@@ -6747,7 +7988,7 @@ pub struct PolicyRuleActionsEnroll {
     pub self_: Option<PolicyRuleActionsEnrollSelf>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PolicyRuleActionsEnrollSelf {
     #[serde(rename = "CHALLENGE")]
     Challenge,
@@ -6779,13 +8020,34 @@ impl Default for PolicyRuleActionsEnrollSelf {
         PolicyRuleActionsEnrollSelf::Noop
     }
 }
+
 impl PolicyRuleActionsEnrollSelf {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PolicyRuleActionsEnrollSelf::Challenge => "CHALLENGE",
+            PolicyRuleActionsEnrollSelf::Login => "LOGIN",
+            PolicyRuleActionsEnrollSelf::Never => "NEVER",
+            PolicyRuleActionsEnrollSelf::Noop => "",
+            PolicyRuleActionsEnrollSelf::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PolicyRuleActionsEnrollSelf] {
+        &[
+            PolicyRuleActionsEnrollSelf::Challenge,
+            PolicyRuleActionsEnrollSelf::Login,
+            PolicyRuleActionsEnrollSelf::Never,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PolicyRuleActionsEnrollSelf::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum AuthType {
     #[serde(rename = "ANY")]
     Any,
@@ -6814,41 +8076,47 @@ impl Default for AuthType {
         AuthType::Noop
     }
 }
+
 impl AuthType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuthType::Any => "ANY",
+            AuthType::Radius => "RADIUS",
+            AuthType::Noop => "",
+            AuthType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [AuthType] {
+        &[AuthType::Any, AuthType::Radius]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, AuthType::Noop)
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct PolicyRuleAuthContextCondition {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "authType")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub auth_type: Option<AuthType>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct PolicyRuleConditions {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub app: Option<AppInstancePolicyRuleCondition>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub apps: Option<UserCondition>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "authContext"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub auth_context: Option<PolicyRuleAuthContextCondition>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "authProvider"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub auth_provider: Option<PasswordPolicyAuthenticationProviderConditionData>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "beforeScheduledAction"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub before_scheduled_action: Option<BeforeScheduledActionPolicyRuleCondition>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub clients: Option<ClientPolicyCondition>,
@@ -6856,25 +8124,13 @@ pub struct PolicyRuleConditions {
     pub context: Option<ContextPolicyRuleCondition>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub device: Option<DevicePolicyRuleCondition>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "grantTypes"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub grant_types: Option<ClientPolicyCondition>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub groups: Option<UserCondition>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "identityProvider"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub identity_provider: Option<IdentityProviderPolicyRuleCondition>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "mdmEnrollment"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub mdm_enrollment: Option<MdmEnrollmentPolicyRuleCondition>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub network: Option<PolicyNetworkCondition>,
@@ -6884,27 +8140,20 @@ pub struct PolicyRuleConditions {
     pub platform: Option<PlatformPolicyRuleCondition>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub risk: Option<RiskPolicyRuleCondition>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "riskScore")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub risk_score: Option<RiskScorePolicyRuleCondition>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub scopes: Option<ClientPolicyCondition>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "userIdentifier"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user_identifier: Option<UserIdentifierPolicyRuleCondition>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "userStatus"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user_status: Option<UserStatusPolicyRuleCondition>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub users: Option<UserPolicyRuleCondition>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct PolicySubject {
     #[serde(
         default,
@@ -6921,21 +8170,16 @@ pub struct PolicySubject {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "matchAttribute"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub match_attribute: String,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "matchType")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub match_type: Option<PolicySubjectMatchType>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "userNameTemplate"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user_name_template: Option<PolicyUserNameTemplate>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PolicySubjectMatchType {
     #[serde(rename = "CUSTOM_ATTRIBUTE")]
     CustomAttribute,
@@ -6970,13 +8214,36 @@ impl Default for PolicySubjectMatchType {
         PolicySubjectMatchType::Noop
     }
 }
+
 impl PolicySubjectMatchType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PolicySubjectMatchType::CustomAttribute => "CUSTOM_ATTRIBUTE",
+            PolicySubjectMatchType::Email => "EMAIL",
+            PolicySubjectMatchType::Username => "USERNAME",
+            PolicySubjectMatchType::UsernameOrEmail => "USERNAME_OR_EMAIL",
+            PolicySubjectMatchType::Noop => "",
+            PolicySubjectMatchType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PolicySubjectMatchType] {
+        &[
+            PolicySubjectMatchType::CustomAttribute,
+            PolicySubjectMatchType::Email,
+            PolicySubjectMatchType::Username,
+            PolicySubjectMatchType::UsernameOrEmail,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PolicySubjectMatchType::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PolicyType {
     #[serde(rename = "IDP_DISCOVERY")]
     IdpDiscovery,
@@ -7011,7 +8278,30 @@ impl Default for PolicyType {
         PolicyType::Noop
     }
 }
+
 impl PolicyType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PolicyType::IdpDiscovery => "IDP_DISCOVERY",
+            PolicyType::OauthAuthorizationPolicy => "OAUTH_AUTHORIZATION_POLICY",
+            PolicyType::OktaSignOn => "OKTA_SIGN_ON",
+            PolicyType::Password => "PASSWORD",
+            PolicyType::Noop => "",
+            PolicyType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PolicyType] {
+        &[
+            PolicyType::IdpDiscovery,
+            PolicyType::OauthAuthorizationPolicy,
+            PolicyType::OktaSignOn,
+            PolicyType::Password,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PolicyType::Noop)
     }
@@ -7046,6 +8336,7 @@ pub struct ProfileMapping {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ProfileMappingProperty {
     #[serde(
         default,
@@ -7053,11 +8344,7 @@ pub struct ProfileMappingProperty {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub expression: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "pushStatus"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub push_status: Option<Links>,
 }
 
@@ -7086,7 +8373,7 @@ pub struct ProfileMappingSource {
     pub type_: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ProtocolType {
     #[serde(rename = "MTLS")]
     Mtls,
@@ -7121,13 +8408,37 @@ impl Default for ProtocolType {
         ProtocolType::Noop
     }
 }
+
 impl ProtocolType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProtocolType::Mtls => "MTLS",
+            ProtocolType::Oauth2 => "OAUTH2",
+            ProtocolType::Oidc => "OIDC",
+            ProtocolType::Saml2 => "SAML2",
+            ProtocolType::Noop => "",
+            ProtocolType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ProtocolType] {
+        &[
+            ProtocolType::Mtls,
+            ProtocolType::Oauth2,
+            ProtocolType::Oidc,
+            ProtocolType::Saml2,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ProtocolType::Noop)
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Protocol {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub algorithms: Option<ProtocolAlgorithms>,
@@ -7137,11 +8448,7 @@ pub struct Protocol {
     pub endpoints: Option<ProtocolEndpoints>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub issuer: Option<ProtocolEndpoint>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "relayState"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub relay_state: Option<ProtocolRelayState>,
     #[serde(
         default,
@@ -7161,7 +8468,7 @@ pub struct ProtocolAlgorithmType {
     pub signature: Option<ProtocolAlgorithmTypeSignature>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Scope {
     #[serde(rename = "ANY")]
     Any,
@@ -7199,7 +8506,32 @@ impl Default for Scope {
         Scope::Noop
     }
 }
+
 impl Scope {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Scope::Any => "ANY",
+            Scope::None => "NONE",
+            Scope::Request => "REQUEST",
+            Scope::Response => "RESPONSE",
+            Scope::Token => "TOKEN",
+            Scope::Noop => "",
+            Scope::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Scope] {
+        &[
+            Scope::Any,
+            Scope::None,
+            Scope::Request,
+            Scope::Response,
+            Scope::Token,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Scope::Noop)
     }
@@ -7225,7 +8557,7 @@ pub struct ProtocolAlgorithms {
     pub response: Option<ProtocolAlgorithmType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Binding {
     #[serde(rename = "HTTP-POST")]
     HttpPost,
@@ -7254,13 +8586,29 @@ impl Default for Binding {
         Binding::Noop
     }
 }
+
 impl Binding {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Binding::HttpPost => "HTTP-POST",
+            Binding::HttpRedirect => "HTTP-REDIRECT",
+            Binding::Noop => "",
+            Binding::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Binding] {
+        &[Binding::HttpPost, Binding::HttpRedirect]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Binding::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ProtocolEndpointType {
     #[serde(rename = "INSTANCE")]
     Instance,
@@ -7289,7 +8637,23 @@ impl Default for ProtocolEndpointType {
         ProtocolEndpointType::Noop
     }
 }
+
 impl ProtocolEndpointType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProtocolEndpointType::Instance => "INSTANCE",
+            ProtocolEndpointType::Org => "ORG",
+            ProtocolEndpointType::Noop => "",
+            ProtocolEndpointType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ProtocolEndpointType] {
+        &[ProtocolEndpointType::Instance, ProtocolEndpointType::Org]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ProtocolEndpointType::Noop)
     }
@@ -7316,6 +8680,7 @@ pub struct ProtocolEndpoint {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ProtocolEndpoints {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub acs: Option<ProtocolEndpoint>,
@@ -7331,7 +8696,7 @@ pub struct ProtocolEndpoints {
     pub sso: Option<ProtocolEndpoint>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub token: Option<ProtocolEndpoint>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "userInfo")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user_info: Option<ProtocolEndpoint>,
 }
 
@@ -7341,7 +8706,7 @@ pub struct ProtocolRelayState {
     pub format: Option<ProtocolRelayStateFormat>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ProtocolRelayStateFormat {
     #[serde(rename = "FROM_URL")]
     FromUrl,
@@ -7370,24 +8735,43 @@ impl Default for ProtocolRelayStateFormat {
         ProtocolRelayStateFormat::Noop
     }
 }
+
 impl ProtocolRelayStateFormat {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProtocolRelayStateFormat::FromUrl => "FROM_URL",
+            ProtocolRelayStateFormat::Opaque => "OPAQUE",
+            ProtocolRelayStateFormat::Noop => "",
+            ProtocolRelayStateFormat::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ProtocolRelayStateFormat] {
+        &[
+            ProtocolRelayStateFormat::FromUrl,
+            ProtocolRelayStateFormat::Opaque,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ProtocolRelayStateFormat::Noop)
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ProtocolSettings {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nameFormat"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name_format: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ProvisioningAction {
     #[serde(rename = "AUTO")]
     Auto,
@@ -7419,13 +8803,35 @@ impl Default for ProvisioningAction {
         ProvisioningAction::Noop
     }
 }
+
 impl ProvisioningAction {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProvisioningAction::Auto => "AUTO",
+            ProvisioningAction::Callout => "CALLOUT",
+            ProvisioningAction::Disabled => "DISABLED",
+            ProvisioningAction::Noop => "",
+            ProvisioningAction::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ProvisioningAction] {
+        &[
+            ProvisioningAction::Auto,
+            ProvisioningAction::Callout,
+            ProvisioningAction::Disabled,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ProvisioningAction::Noop)
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Provisioning {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub action: Option<ProvisioningAction>,
@@ -7435,8 +8841,7 @@ pub struct Provisioning {
     pub groups: Option<ProvisioningGroups>,
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "profileMaster"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub profile_master: bool,
 }
@@ -7449,7 +8854,7 @@ pub struct ProvisioningConditions {
     pub suspended: Option<ProvisioningSuspendedCondition>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ProvisioningDeprovisionedConditionAction {
     #[serde(rename = "NONE")]
     None,
@@ -7478,7 +8883,26 @@ impl Default for ProvisioningDeprovisionedConditionAction {
         ProvisioningDeprovisionedConditionAction::Noop
     }
 }
+
 impl ProvisioningDeprovisionedConditionAction {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProvisioningDeprovisionedConditionAction::None => "NONE",
+            ProvisioningDeprovisionedConditionAction::Reactivate => "REACTIVATE",
+            ProvisioningDeprovisionedConditionAction::Noop => "",
+            ProvisioningDeprovisionedConditionAction::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ProvisioningDeprovisionedConditionAction] {
+        &[
+            ProvisioningDeprovisionedConditionAction::None,
+            ProvisioningDeprovisionedConditionAction::Reactivate,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ProvisioningDeprovisionedConditionAction::Noop)
     }
@@ -7490,7 +8914,7 @@ pub struct ProvisioningDeprovisionedCondition {
     pub action: Option<ProvisioningDeprovisionedConditionAction>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ProvisioningGroupsAction {
     #[serde(rename = "APPEND")]
     Append,
@@ -7525,13 +8949,37 @@ impl Default for ProvisioningGroupsAction {
         ProvisioningGroupsAction::Noop
     }
 }
+
 impl ProvisioningGroupsAction {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProvisioningGroupsAction::Append => "APPEND",
+            ProvisioningGroupsAction::Assign => "ASSIGN",
+            ProvisioningGroupsAction::None => "NONE",
+            ProvisioningGroupsAction::Sync => "SYNC",
+            ProvisioningGroupsAction::Noop => "",
+            ProvisioningGroupsAction::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ProvisioningGroupsAction] {
+        &[
+            ProvisioningGroupsAction::Append,
+            ProvisioningGroupsAction::Assign,
+            ProvisioningGroupsAction::None,
+            ProvisioningGroupsAction::Sync,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ProvisioningGroupsAction::Noop)
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ProvisioningGroups {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub action: Option<ProvisioningGroupsAction>,
@@ -7550,13 +8998,12 @@ pub struct ProvisioningGroups {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "sourceAttributeName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub source_attribute_name: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ProvisioningSuspendedConditionAction {
     #[serde(rename = "NONE")]
     None,
@@ -7585,7 +9032,26 @@ impl Default for ProvisioningSuspendedConditionAction {
         ProvisioningSuspendedConditionAction::Noop
     }
 }
+
 impl ProvisioningSuspendedConditionAction {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProvisioningSuspendedConditionAction::None => "NONE",
+            ProvisioningSuspendedConditionAction::Unsuspend => "UNSUSPEND",
+            ProvisioningSuspendedConditionAction::Noop => "",
+            ProvisioningSuspendedConditionAction::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ProvisioningSuspendedConditionAction] {
+        &[
+            ProvisioningSuspendedConditionAction::None,
+            ProvisioningSuspendedConditionAction::Unsuspend,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ProvisioningSuspendedConditionAction::Noop)
     }
@@ -7598,45 +9064,39 @@ pub struct ProvisioningSuspendedCondition {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct PushUserFactor {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "expiresAt"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "factorResult"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub factor_result: Option<FactorResultType>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub profile: Option<PushUserFactorProfile>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct PushUserFactorProfile {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "credentialId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub credential_id: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "deviceToken"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub device_token: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "deviceType"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub device_type: String,
     #[serde(
@@ -7744,7 +9204,7 @@ pub struct Role {
     pub type_: Option<RoleType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum RoleAssignmentType {
     #[serde(rename = "GROUP")]
     Group,
@@ -7773,13 +9233,29 @@ impl Default for RoleAssignmentType {
         RoleAssignmentType::Noop
     }
 }
+
 impl RoleAssignmentType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RoleAssignmentType::Group => "GROUP",
+            RoleAssignmentType::User => "USER",
+            RoleAssignmentType::Noop => "",
+            RoleAssignmentType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [RoleAssignmentType] {
+        &[RoleAssignmentType::Group, RoleAssignmentType::User]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, RoleAssignmentType::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum RoleType {
     #[serde(rename = "API_ACCESS_MANAGEMENT_ADMIN")]
     ApiAccessManagementAdmin,
@@ -7829,7 +9305,40 @@ impl Default for RoleType {
         RoleType::Noop
     }
 }
+
 impl RoleType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RoleType::ApiAccessManagementAdmin => "API_ACCESS_MANAGEMENT_ADMIN",
+            RoleType::AppAdmin => "APP_ADMIN",
+            RoleType::HelpDeskAdmin => "HELP_DESK_ADMIN",
+            RoleType::MobileAdmin => "MOBILE_ADMIN",
+            RoleType::OrgAdmin => "ORG_ADMIN",
+            RoleType::ReadOnlyAdmin => "READ_ONLY_ADMIN",
+            RoleType::ReportAdmin => "REPORT_ADMIN",
+            RoleType::SuperAdmin => "SUPER_ADMIN",
+            RoleType::UserAdmin => "USER_ADMIN",
+            RoleType::Noop => "",
+            RoleType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [RoleType] {
+        &[
+            RoleType::ApiAccessManagementAdmin,
+            RoleType::AppAdmin,
+            RoleType::HelpDeskAdmin,
+            RoleType::MobileAdmin,
+            RoleType::OrgAdmin,
+            RoleType::ReadOnlyAdmin,
+            RoleType::ReportAdmin,
+            RoleType::SuperAdmin,
+            RoleType::UserAdmin,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, RoleType::Noop)
     }
@@ -7842,37 +9351,35 @@ pub struct SamlApplication {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct SamlApplicationSettings {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "signOn")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub sign_on: Option<SamlApplicationSettingsSignOn>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct SamlApplicationSettingsSignOn {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "acsEndpoints"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub acs_endpoints: Vec<AcsEndpoint>,
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "allowMultipleAcsEndpoints"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub allow_multiple_acs_endpoints: bool,
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "assertionSigned"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub assertion_signed: bool,
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "attributeStatements"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub attribute_statements: Vec<SamlAttributeStatement>,
     #[serde(
@@ -7884,22 +9391,19 @@ pub struct SamlApplicationSettingsSignOn {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "audienceOverride"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub audience_override: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "authnContextClassRef"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub authn_context_class_ref: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "defaultRelayState"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub default_relay_state: String,
     #[serde(
@@ -7911,35 +9415,30 @@ pub struct SamlApplicationSettingsSignOn {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "destinationOverride"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub destination_override: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "digestAlgorithm"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub digest_algorithm: String,
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "honorForceAuthn"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub honor_force_authn: bool,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "idpIssuer"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub idp_issuer: String,
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "inlineHooks"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub inline_hooks: Vec<SignOnInlineHook>,
     #[serde(
@@ -7951,88 +9450,74 @@ pub struct SamlApplicationSettingsSignOn {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "recipientOverride"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub recipient_override: String,
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "requestCompressed"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub request_compressed: bool,
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "responseSigned"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub response_signed: bool,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "signatureAlgorithm"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub signature_algorithm: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub slo: Option<SingleLogout>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "spCertificate"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub sp_certificate: Option<SpCertificate>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "spIssuer"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub sp_issuer: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "ssoAcsUrl"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub sso_acs_url: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "ssoAcsUrlOverride"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub sso_acs_url_override: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "subjectNameIdFormat"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub subject_name_id_format: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "subjectNameIdTemplate"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub subject_name_id_template: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct SamlAttributeStatement {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "filterType"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub filter_type: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "filterValue"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub filter_value: String,
     #[serde(
@@ -8062,7 +9547,7 @@ pub struct SamlAttributeStatement {
     pub values: Vec<String>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Value {
     #[serde(rename = "ACTIVATING")]
     Activating,
@@ -8109,7 +9594,38 @@ impl Default for Value {
         Value::Noop
     }
 }
+
 impl Value {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Value::Activating => "ACTIVATING",
+            Value::Active => "ACTIVE",
+            Value::Deleted => "DELETED",
+            Value::Deleting => "DELETING",
+            Value::ExpiredPassword => "EXPIRED_PASSWORD",
+            Value::Inactive => "INACTIVE",
+            Value::Pending => "PENDING",
+            Value::Suspended => "SUSPENDED",
+            Value::Noop => "",
+            Value::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Value] {
+        &[
+            Value::Activating,
+            Value::Active,
+            Value::Deleted,
+            Value::Deleting,
+            Value::ExpiredPassword,
+            Value::Inactive,
+            Value::Pending,
+            Value::Suspended,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Value::Noop)
     }
@@ -8122,13 +9638,13 @@ pub struct ScheduledUserLifecycleAction {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct SchemeApplicationCredentials {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub password: Option<PasswordCredential>,
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "revealPassword"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub reveal_password: bool,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -8138,26 +9654,25 @@ pub struct SchemeApplicationCredentials {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "userName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub user_name: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct ScopeData {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "stringValue"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub string_value: String,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<ScopeType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ScopeType {
     #[serde(rename = "CORS")]
     Cors,
@@ -8186,7 +9701,23 @@ impl Default for ScopeType {
         ScopeType::Noop
     }
 }
+
 impl ScopeType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ScopeType::Cors => "CORS",
+            ScopeType::Redirect => "REDIRECT",
+            ScopeType::Noop => "",
+            ScopeType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ScopeType] {
+        &[ScopeType::Cors, ScopeType::Redirect]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ScopeType::Noop)
     }
@@ -8209,54 +9740,48 @@ pub struct SecurePasswordStoreApplicationSettings {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct SecurePasswordStoreApplicationSettingsData {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "optionalField1"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub optional_field_1: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "optionalField1Value"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub optional_field_1_value: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "optionalField2"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub optional_field_2: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "optionalField2Value"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub optional_field_2_value: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "optionalField3"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub optional_field_3: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "optionalField3Value"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub optional_field_3_value: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "passwordField"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub password_field: String,
     #[serde(
@@ -8268,13 +9793,13 @@ pub struct SecurePasswordStoreApplicationSettingsData {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "usernameField"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub username_field: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct SecurityQuestion {
     #[serde(
         default,
@@ -8291,8 +9816,7 @@ pub struct SecurityQuestion {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "questionText"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub question_text: String,
 }
@@ -8366,7 +9890,7 @@ pub struct Session {
     pub user_id: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SessionAuthenticationMethod {
     #[serde(rename = "fpt")]
     Fpt,
@@ -8419,7 +9943,42 @@ impl Default for SessionAuthenticationMethod {
         SessionAuthenticationMethod::Noop
     }
 }
+
 impl SessionAuthenticationMethod {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SessionAuthenticationMethod::Fpt => "fpt",
+            SessionAuthenticationMethod::Geo => "geo",
+            SessionAuthenticationMethod::Hwk => "hwk",
+            SessionAuthenticationMethod::Kba => "kba",
+            SessionAuthenticationMethod::Mfa => "mfa",
+            SessionAuthenticationMethod::Otp => "otp",
+            SessionAuthenticationMethod::Pwd => "pwd",
+            SessionAuthenticationMethod::Sms => "sms",
+            SessionAuthenticationMethod::Swk => "swk",
+            SessionAuthenticationMethod::Tel => "tel",
+            SessionAuthenticationMethod::Noop => "",
+            SessionAuthenticationMethod::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SessionAuthenticationMethod] {
+        &[
+            SessionAuthenticationMethod::Fpt,
+            SessionAuthenticationMethod::Geo,
+            SessionAuthenticationMethod::Hwk,
+            SessionAuthenticationMethod::Kba,
+            SessionAuthenticationMethod::Mfa,
+            SessionAuthenticationMethod::Otp,
+            SessionAuthenticationMethod::Pwd,
+            SessionAuthenticationMethod::Sms,
+            SessionAuthenticationMethod::Swk,
+            SessionAuthenticationMethod::Tel,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SessionAuthenticationMethod::Noop)
     }
@@ -8437,7 +9996,7 @@ pub struct SessionIdentityProvider {
     pub type_: Option<SessionIdentityProviderType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SessionIdentityProviderType {
     #[serde(rename = "ACTIVE_DIRECTORY")]
     ActiveDirectory,
@@ -8475,13 +10034,38 @@ impl Default for SessionIdentityProviderType {
         SessionIdentityProviderType::Noop
     }
 }
+
 impl SessionIdentityProviderType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SessionIdentityProviderType::ActiveDirectory => "ACTIVE_DIRECTORY",
+            SessionIdentityProviderType::Federation => "FEDERATION",
+            SessionIdentityProviderType::Ldap => "LDAP",
+            SessionIdentityProviderType::Okta => "OKTA",
+            SessionIdentityProviderType::Social => "SOCIAL",
+            SessionIdentityProviderType::Noop => "",
+            SessionIdentityProviderType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SessionIdentityProviderType] {
+        &[
+            SessionIdentityProviderType::ActiveDirectory,
+            SessionIdentityProviderType::Federation,
+            SessionIdentityProviderType::Ldap,
+            SessionIdentityProviderType::Okta,
+            SessionIdentityProviderType::Social,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SessionIdentityProviderType::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SessionStatus {
     #[serde(rename = "ACTIVE")]
     Active,
@@ -8513,7 +10097,28 @@ impl Default for SessionStatus {
         SessionStatus::Noop
     }
 }
+
 impl SessionStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SessionStatus::Active => "ACTIVE",
+            SessionStatus::MfaEnroll => "MFA_ENROLL",
+            SessionStatus::MfaRequired => "MFA_REQUIRED",
+            SessionStatus::Noop => "",
+            SessionStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SessionStatus] {
+        &[
+            SessionStatus::Active,
+            SessionStatus::MfaEnroll,
+            SessionStatus::MfaRequired,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SessionStatus::Noop)
     }
@@ -8530,6 +10135,7 @@ pub struct SignOnInlineHook {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct SingleLogout {
     #[serde(
         default,
@@ -8545,13 +10151,13 @@ pub struct SingleLogout {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "logoutUrl"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub logout_url: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct SmsTemplate {
     #[serde(
         default,
@@ -8568,8 +10174,7 @@ pub struct SmsTemplate {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastUpdated"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
     #[serde(
@@ -8590,7 +10195,7 @@ pub struct SmsTemplate {
     pub type_: Option<SmsTemplateType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SmsTemplateType {
     #[serde(rename = "SMS_VERIFY_CODE")]
     SmsVerifyCode,
@@ -8616,7 +10221,22 @@ impl Default for SmsTemplateType {
         SmsTemplateType::Noop
     }
 }
+
 impl SmsTemplateType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SmsTemplateType::SmsVerifyCode => "SMS_VERIFY_CODE",
+            SmsTemplateType::Noop => "",
+            SmsTemplateType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SmsTemplateType] {
+        &[SmsTemplateType::SmsVerifyCode]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SmsTemplateType::Noop)
     }
@@ -8629,17 +10249,17 @@ pub struct SmsUserFactor {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct SmsUserFactorProfile {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "phoneNumber"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub phone_number: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum TokenType {
     #[serde(rename = "ACCESS")]
     Access,
@@ -8668,19 +10288,35 @@ impl Default for TokenType {
         TokenType::Noop
     }
 }
+
 impl TokenType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TokenType::Access => "ACCESS",
+            TokenType::Refresh => "REFRESH",
+            TokenType::Noop => "",
+            TokenType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [TokenType] {
+        &[TokenType::Access, TokenType::Refresh]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, TokenType::Noop)
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct SocialAuthToken {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "expiresAt"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
     #[serde(
@@ -8704,21 +10340,20 @@ pub struct SocialAuthToken {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "tokenAuthScheme"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub token_auth_scheme: String,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "tokenType")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub token_type: Option<TokenType>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct SpCertificate {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "x5c"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub x_5c: Vec<String>,
 }
@@ -8738,26 +10373,24 @@ pub struct SwaApplicationSettings {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct SwaApplicationSettingsData {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "buttonField"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub button_field: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "loginUrlRegex"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub login_url_regex: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "passwordField"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub password_field: String,
     #[serde(
@@ -8769,8 +10402,7 @@ pub struct SwaApplicationSettingsData {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "usernameField"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub username_field: String,
 }
@@ -8843,12 +10475,12 @@ pub struct SwaThreeFieldApplicationSettingsData {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct TempPassword {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "tempPassword"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub temp_password: String,
 }
@@ -8886,32 +10518,26 @@ pub struct ThreatInsightConfiguration {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct TokenAuthorizationServerPolicyRuleAction {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "accessTokenLifetimeMinutes"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub access_token_lifetime_minutes: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "inlineHook"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub inline_hook: Option<SignOnInlineHook>,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "refreshTokenLifetimeMinutes"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub refresh_token_lifetime_minutes: i64,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "refreshTokenWindowMinutes"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub refresh_token_window_minutes: i64,
 }
@@ -9066,19 +10692,18 @@ pub struct User {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UserActivationToken {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "activationToken"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub activation_token: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "activationUrl"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub activation_url: String,
 }
@@ -9132,7 +10757,7 @@ pub struct UserFactor {
     pub verify: Option<VerifyFactorRequest>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum UserIdentifierConditionEvaluatorPatternMatchType {
     #[serde(rename = "CONTAINS")]
     Contains,
@@ -9170,15 +10795,41 @@ impl Default for UserIdentifierConditionEvaluatorPatternMatchType {
         UserIdentifierConditionEvaluatorPatternMatchType::Noop
     }
 }
+
 impl UserIdentifierConditionEvaluatorPatternMatchType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UserIdentifierConditionEvaluatorPatternMatchType::Contains => "CONTAINS",
+            UserIdentifierConditionEvaluatorPatternMatchType::Equals => "EQUALS",
+            UserIdentifierConditionEvaluatorPatternMatchType::Expression => "EXPRESSION",
+            UserIdentifierConditionEvaluatorPatternMatchType::StartsWith => "STARTS_WITH",
+            UserIdentifierConditionEvaluatorPatternMatchType::Suffix => "SUFFIX",
+            UserIdentifierConditionEvaluatorPatternMatchType::Noop => "",
+            UserIdentifierConditionEvaluatorPatternMatchType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [UserIdentifierConditionEvaluatorPatternMatchType] {
+        &[
+            UserIdentifierConditionEvaluatorPatternMatchType::Contains,
+            UserIdentifierConditionEvaluatorPatternMatchType::Equals,
+            UserIdentifierConditionEvaluatorPatternMatchType::Expression,
+            UserIdentifierConditionEvaluatorPatternMatchType::StartsWith,
+            UserIdentifierConditionEvaluatorPatternMatchType::Suffix,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, UserIdentifierConditionEvaluatorPatternMatchType::Noop)
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UserIdentifierConditionEvaluatorPattern {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "matchType")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub match_type: Option<UserIdentifierConditionEvaluatorPatternMatchType>,
     #[serde(
         default,
@@ -9188,7 +10839,7 @@ pub struct UserIdentifierConditionEvaluatorPattern {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum UserIdentifierPolicyRuleConditionType {
     #[serde(rename = "ATTRIBUTE")]
     Attribute,
@@ -9217,7 +10868,26 @@ impl Default for UserIdentifierPolicyRuleConditionType {
         UserIdentifierPolicyRuleConditionType::Noop
     }
 }
+
 impl UserIdentifierPolicyRuleConditionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UserIdentifierPolicyRuleConditionType::Attribute => "ATTRIBUTE",
+            UserIdentifierPolicyRuleConditionType::Identifier => "IDENTIFIER",
+            UserIdentifierPolicyRuleConditionType::Noop => "",
+            UserIdentifierPolicyRuleConditionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [UserIdentifierPolicyRuleConditionType] {
+        &[
+            UserIdentifierPolicyRuleConditionType::Attribute,
+            UserIdentifierPolicyRuleConditionType::Identifier,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, UserIdentifierPolicyRuleConditionType::Noop)
     }
@@ -9242,35 +10912,34 @@ pub struct UserIdentifierPolicyRuleCondition {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UserIdentityProviderLinkRequest {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "externalId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub external_id: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UserLifecycleAttributePolicyRuleCondition {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "attributeName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub attribute_name: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "matchingValue"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub matching_value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum UserNextLogin {
     #[serde(rename = "changePassword")]
     ChangePassword,
@@ -9296,13 +10965,29 @@ impl Default for UserNextLogin {
         UserNextLogin::Noop
     }
 }
+
 impl UserNextLogin {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UserNextLogin::ChangePassword => "changePassword",
+            UserNextLogin::Noop => "",
+            UserNextLogin::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [UserNextLogin] {
+        &[UserNextLogin::ChangePassword]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, UserNextLogin::Noop)
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UserPolicyRuleCondition {
     #[serde(
         default,
@@ -9318,33 +11003,21 @@ pub struct UserPolicyRuleCondition {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub include: Vec<String>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "lifecycleExpiration"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub lifecycle_expiration: Option<LifecycleExpirationPolicyRuleCondition>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "passwordExpiration"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub password_expiration: Option<Duration>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "userLifecycleAttribute"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user_lifecycle_attribute: Option<UserLifecycleAttributePolicyRuleCondition>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UserProfile {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "awsRole"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub aws_role: String,
     #[serde(
@@ -9362,15 +11035,13 @@ pub struct UserProfile {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "costCenter"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub cost_center: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "countryCode"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub country_code: String,
     #[serde(
@@ -9382,8 +11053,7 @@ pub struct UserProfile {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "displayName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub display_name: String,
     #[serde(
@@ -9401,50 +11071,43 @@ pub struct UserProfile {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "emailAliases"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub email_aliases: Vec<String>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "employeeNumber"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub employee_number: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "firstName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub first_name: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "githubUsername"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub github_username: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "honorificPrefix"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub honorific_prefix: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "honorificSuffix"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub honorific_suffix: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "lastName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub last_name: String,
     #[serde(
@@ -9468,36 +11131,31 @@ pub struct UserProfile {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "managerId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub manager_id: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "matrixUsername"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub matrix_username: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "middleName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub middle_name: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "mobilePhone"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub mobile_phone: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nickName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub nick_name: String,
     #[serde(
@@ -9509,43 +11167,37 @@ pub struct UserProfile {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "postalAddress"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub postal_address: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "preferredLanguage"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub preferred_language: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "primaryPhone"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub primary_phone: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "profileUrl"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub profile_url: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "secondEmail"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub second_email: String,
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize",
-        rename = "startDate"
+        deserialize_with = "crate::utils::date_format::deserialize"
     )]
     pub start_date: Option<chrono::NaiveDate>,
     #[serde(
@@ -9557,8 +11209,7 @@ pub struct UserProfile {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "streetAddress"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub street_address: String,
     #[serde(
@@ -9576,57 +11227,49 @@ pub struct UserProfile {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "userType"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub user_type: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "workCity"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub work_city: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "workCountryCode"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub work_country_code: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "workPostalAddress"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub work_postal_address: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "workState"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub work_state: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "workStreetAddress"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub work_street_address: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "workZipCode"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub work_zip_code: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "zipCode"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub zip_code: String,
 }
@@ -9687,6 +11330,7 @@ pub struct UserSchema {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UserSchemaAttribute {
     #[serde(
         default,
@@ -9704,15 +11348,13 @@ pub struct UserSchemaAttribute {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "externalName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub external_name: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "externalNamespace"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub external_namespace: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -9722,15 +11364,13 @@ pub struct UserSchemaAttribute {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "maxLength"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub max_length: i64,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "minLength"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub min_length: i64,
     #[serde(
@@ -9742,8 +11382,7 @@ pub struct UserSchemaAttribute {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "oneOf"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub one_of: Vec<UserSchemaAttributeEnum>,
     #[serde(
@@ -9801,6 +11440,7 @@ pub struct UserSchemaAttributeEnum {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UserSchemaAttributeItems {
     #[serde(
         default,
@@ -9812,8 +11452,7 @@ pub struct UserSchemaAttributeItems {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "oneOf"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub one_of: Vec<UserSchemaAttributeEnum>,
     #[serde(
@@ -9837,7 +11476,7 @@ pub struct UserSchemaAttributeMaster {
     pub type_: Option<UserSchemaAttributeMasterType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum UserSchemaAttributeMasterType {
     #[serde(rename = "OKTA")]
     Okta,
@@ -9869,7 +11508,28 @@ impl Default for UserSchemaAttributeMasterType {
         UserSchemaAttributeMasterType::Noop
     }
 }
+
 impl UserSchemaAttributeMasterType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UserSchemaAttributeMasterType::Okta => "OKTA",
+            UserSchemaAttributeMasterType::Override => "OVERRIDE",
+            UserSchemaAttributeMasterType::ProfileMaster => "PROFILE_MASTER",
+            UserSchemaAttributeMasterType::Noop => "",
+            UserSchemaAttributeMasterType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [UserSchemaAttributeMasterType] {
+        &[
+            UserSchemaAttributeMasterType::Okta,
+            UserSchemaAttributeMasterType::Override,
+            UserSchemaAttributeMasterType::ProfileMaster,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, UserSchemaAttributeMasterType::Noop)
     }
@@ -9891,7 +11551,7 @@ pub struct UserSchemaAttributePermission {
     pub principal: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum UserSchemaAttributeType {
     #[serde(rename = "array")]
     Array,
@@ -9929,7 +11589,32 @@ impl Default for UserSchemaAttributeType {
         UserSchemaAttributeType::Noop
     }
 }
+
 impl UserSchemaAttributeType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UserSchemaAttributeType::Array => "array",
+            UserSchemaAttributeType::Boolean => "boolean",
+            UserSchemaAttributeType::Integer => "integer",
+            UserSchemaAttributeType::Number => "number",
+            UserSchemaAttributeType::String => "string",
+            UserSchemaAttributeType::Noop => "",
+            UserSchemaAttributeType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [UserSchemaAttributeType] {
+        &[
+            UserSchemaAttributeType::Array,
+            UserSchemaAttributeType::Boolean,
+            UserSchemaAttributeType::Integer,
+            UserSchemaAttributeType::Number,
+            UserSchemaAttributeType::String,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, UserSchemaAttributeType::Noop)
     }
@@ -9961,54 +11646,31 @@ pub struct UserSchemaBase {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UserSchemaBaseProperties {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub city: Option<UserSchemaAttribute>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "costCenter"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub cost_center: Option<UserSchemaAttribute>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "countryCode"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub country_code: Option<UserSchemaAttribute>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub department: Option<UserSchemaAttribute>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "displayName"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub display_name: Option<UserSchemaAttribute>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub division: Option<UserSchemaAttribute>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub email: Option<UserSchemaAttribute>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "employeeNumber"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub employee_number: Option<UserSchemaAttribute>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "firstName")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub first_name: Option<UserSchemaAttribute>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "honorificPrefix"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub honorific_prefix: Option<UserSchemaAttribute>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "honorificSuffix"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub honorific_suffix: Option<UserSchemaAttribute>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "lastName")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub last_name: Option<UserSchemaAttribute>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub locale: Option<UserSchemaAttribute>,
@@ -10016,69 +11678,37 @@ pub struct UserSchemaBaseProperties {
     pub login: Option<UserSchemaAttribute>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub manager: Option<UserSchemaAttribute>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "managerId")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub manager_id: Option<UserSchemaAttribute>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "middleName"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub middle_name: Option<UserSchemaAttribute>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "mobilePhone"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub mobile_phone: Option<UserSchemaAttribute>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "nickName")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub nick_name: Option<UserSchemaAttribute>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub organization: Option<UserSchemaAttribute>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "postalAddress"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub postal_address: Option<UserSchemaAttribute>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "preferredLanguage"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub preferred_language: Option<UserSchemaAttribute>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "primaryPhone"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub primary_phone: Option<UserSchemaAttribute>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "profileUrl"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub profile_url: Option<UserSchemaAttribute>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "secondEmail"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub second_email: Option<UserSchemaAttribute>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub state: Option<UserSchemaAttribute>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "streetAddress"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub street_address: Option<UserSchemaAttribute>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub timezone: Option<UserSchemaAttribute>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub title: Option<UserSchemaAttribute>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "userType")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user_type: Option<UserSchemaAttribute>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "zipCode")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub zip_code: Option<UserSchemaAttribute>,
 }
 
@@ -10097,12 +11727,12 @@ pub struct UserSchemaProperties {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct UserSchemaPropertiesProfile {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "allOf"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub all_of: Vec<UserSchemaPropertiesProfileItem>,
 }
@@ -10143,7 +11773,7 @@ pub struct UserSchemaPublic {
     pub type_: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum UserStatus {
     #[serde(rename = "ACTIVE")]
     Active,
@@ -10190,7 +11820,38 @@ impl Default for UserStatus {
         UserStatus::Noop
     }
 }
+
 impl UserStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UserStatus::Active => "ACTIVE",
+            UserStatus::Deprovisioned => "DEPROVISIONED",
+            UserStatus::LockedOut => "LOCKED_OUT",
+            UserStatus::PasswordExpired => "PASSWORD_EXPIRED",
+            UserStatus::Provisioned => "PROVISIONED",
+            UserStatus::Recovery => "RECOVERY",
+            UserStatus::Staged => "STAGED",
+            UserStatus::Suspended => "SUSPENDED",
+            UserStatus::Noop => "",
+            UserStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [UserStatus] {
+        &[
+            UserStatus::Active,
+            UserStatus::Deprovisioned,
+            UserStatus::LockedOut,
+            UserStatus::PasswordExpired,
+            UserStatus::Provisioned,
+            UserStatus::Recovery,
+            UserStatus::Staged,
+            UserStatus::Suspended,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, UserStatus::Noop)
     }
@@ -10266,12 +11927,12 @@ pub struct UserType {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct VerifyFactorRequest {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "activationToken"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub activation_token: String,
     #[serde(
@@ -10289,41 +11950,36 @@ pub struct VerifyFactorRequest {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "clientData"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub client_data: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPassCode"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub next_pass_code: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "passCode"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub pass_code: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "registrationData"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub registration_data: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "stateToken"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub state_token: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum FactorResult {
     #[serde(rename = "CHALLENGE")]
     Challenge,
@@ -10376,7 +12032,42 @@ impl Default for FactorResult {
         FactorResult::Noop
     }
 }
+
 impl FactorResult {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FactorResult::Challenge => "CHALLENGE",
+            FactorResult::Error => "ERROR",
+            FactorResult::Expired => "EXPIRED",
+            FactorResult::Failed => "FAILED",
+            FactorResult::PasscodeReplayed => "PASSCODE_REPLAYED",
+            FactorResult::Rejected => "REJECTED",
+            FactorResult::Success => "SUCCESS",
+            FactorResult::Timeout => "TIMEOUT",
+            FactorResult::TimeWindowExceeded => "TIME_WINDOW_EXCEEDED",
+            FactorResult::Waiting => "WAITING",
+            FactorResult::Noop => "",
+            FactorResult::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [FactorResult] {
+        &[
+            FactorResult::Challenge,
+            FactorResult::Error,
+            FactorResult::Expired,
+            FactorResult::Failed,
+            FactorResult::PasscodeReplayed,
+            FactorResult::Rejected,
+            FactorResult::Success,
+            FactorResult::Timeout,
+            FactorResult::TimeWindowExceeded,
+            FactorResult::Waiting,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, FactorResult::Noop)
     }
@@ -10417,19 +12108,18 @@ pub struct WebAuthnUserFactor {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct WebAuthnUserFactorProfile {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "authenticatorName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub authenticator_name: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "credentialId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub credential_id: String,
 }
@@ -10539,3 +12229,848 @@ pub struct WsFederationApplicationSettingsData {
     )]
     pub w_reply_url: String,
 }
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AuthorizationServerPolicyRuleRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actions: Option<AuthorizationServerPolicyRuleActions>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<AuthorizationServerPolicyRuleConditions>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub priority: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<RoleStatus>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub system: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<AuthorizationServerPolicyRuleType>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SmsTemplateRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub template: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub translations: Option<Links>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<SmsTemplateType>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PolicyRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<PolicyRuleConditions>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub priority: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<RoleStatus>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub system: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<PolicyType>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UserTypeRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub display_name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct InlineHookRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<InlineHookChannel>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<RoleStatus>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<InlineHookType>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub version: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplicationRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accessibility: Option<ApplicationAccessibility>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub credentials: Option<ApplicationCredentials>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub features: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub label: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub licensing: Option<ApplicationLicensing>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<Links>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub settings: Option<ApplicationSettings>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sign_on_mode: Option<ApplicationSignOnMode>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<ApplicationVisibility>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AppUserRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub credentials: Option<AppUserCredentials>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub scope: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorizationServerRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub audiences: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub credentials: Option<AuthorizationServerCredentials>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub issuer: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issuer_mode: Option<IssuerMode>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<RoleStatus>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OAuth2ClaimRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "alwaysIncludeInToken"
+    )]
+    pub always_include_in_token: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "claimType")]
+    pub claim_type: Option<ClaimType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<OAuth2ClaimConditions>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group_filter_type: Option<GroupFilterType>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<RoleStatus>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub system: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub value: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "valueType")]
+    pub value_type: Option<ValueType>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OAuth2ScopeRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub consent: Option<Consent>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub display_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata_publish: Option<MetadataPublish>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub system: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EventHookRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<EventHookChannel>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created_by: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub events: Option<EventSubscriptions>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<RoleStatus>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verification_status: Option<VerificationStatus>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GroupRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<GroupProfile>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<GroupType>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GroupRuleRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actions: Option<GroupRuleAction>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<GroupRuleConditions>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<GroupRuleStatus>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct IdentityProviderRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issuer_mode: Option<IdentityProviderIssuerMode>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub policy: Option<IdentityProviderPolicy>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<Protocol>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<RoleStatus>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<IdentityProviderType>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PolicyRuleRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actions: Option<PolicyRuleActions>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<PolicyRuleConditions>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub priority: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<RoleStatus>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub system: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<PolicyRuleType>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TrustedOriginRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created_by: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub last_updated_by: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub origin: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub scopes: Vec<ScopeData>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub status: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UserRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub credentials: Option<UserCredentials>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<UserProfile>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<UserStatus>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transitioning_to_status: Option<UserStatus>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<UserType>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkZoneRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub asns: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub gateways: Vec<NetworkZoneAddress>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub locations: Vec<NetworkZoneLocation>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub proxies: Vec<NetworkZoneAddress>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub proxy_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<RoleStatus>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub system: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<NetworkZoneType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub usage: Option<NetworkZoneUsage>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OAuth2ScopeConsentGrantRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub client_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<LogIssuer>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub issuer: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub scope_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<OAuth2ScopeConsentGrantSource>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<OAuth2RefreshTokenStatus>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub user_id: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ApplicationGroupAssignmentRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub priority: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<Links>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DomainRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub certificate_source_type: Option<DomainCertificateSourceType>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub dns_records: Vec<DnsRecord>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub domain: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub public_certificate: Option<DomainCertificateMetadata>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validation_status: Option<DomainValidationStatus>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct JsonWebKeyRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub alg: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub e: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "expiresAt"
+    )]
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub key_ops: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub kid: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub kty: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "lastUpdated"
+    )]
+    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub n: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub status: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "use"
+    )]
+    pub use_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "x5c"
+    )]
+    pub x_5c: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "x5t"
+    )]
+    pub x_5t: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "x5t#S256"
+    )]
+    pub x_5t_s256: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "x5u"
+    )]
+    pub x_5u: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProfileMappingRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<ProfileMappingSource>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<ProfileMappingSource>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UserSchemaRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub definitions: Option<UserSchemaDefinitions>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub properties: Option<UserSchemaProperties>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub title: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct LinkedObjectRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub associated: Option<LinkedObjectDetails>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub primary: Option<LinkedObjectDetails>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ThreatInsightConfigurationRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub action: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub exclude_zones: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UserFactorRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub factor_type: Option<FactorType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider: Option<FactorProvider>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<FactorStatus>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verify: Option<VerifyFactorRequest>,
+}
+
+/// The types taken and returned by the functions in [`crate::applications`].
+pub mod applications {
+    pub use super::{
+        AppUser, AppUserRequest, Application, ApplicationGroupAssignment,
+        ApplicationGroupAssignmentRequest, ApplicationRequest, Csr, CsrMetadata, JsonWebKey,
+        OAuth2ScopeConsentGrant, OAuth2ScopeConsentGrantRequest, OAuth2Token,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::authorization_servers`].
+pub mod authorization_servers {
+    pub use super::{
+        AuthorizationServer, AuthorizationServerPolicyRule, AuthorizationServerPolicyRuleRequest,
+        AuthorizationServerRequest, JsonWebKey, JwkUse, OAuth2Claim, OAuth2ClaimRequest,
+        OAuth2Client, OAuth2RefreshToken, OAuth2Scope, OAuth2ScopeRequest, Policy, PolicyRequest,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::domains`].
+pub mod domains {
+    pub use super::{Domain, DomainCertificate, DomainListResponse, DomainRequest};
+}
+
+/// The types taken and returned by the functions in [`crate::event_hooks`].
+pub mod event_hooks {
+    pub use super::{EventHook, EventHookRequest};
+}
+
+/// The types taken and returned by the functions in [`crate::features`].
+pub mod features {
+    pub use super::Feature;
+}
+
+/// The types taken and returned by the functions in [`crate::groups`].
+pub mod groups {
+    pub use super::{
+        Application, AssignRoleRequest, CatalogApplication, Group, GroupRequest, GroupRule,
+        GroupRuleRequest, Role, User,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::identity_providers`].
+pub mod identity_providers {
+    pub use super::{
+        Csr, CsrMetadata, IdentityProvider, IdentityProviderApplicationUser,
+        IdentityProviderRequest, JsonWebKey, JsonWebKeyRequest, SocialAuthToken,
+        UserIdentityProviderLinkRequest,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::inline_hooks`].
+pub mod inline_hooks {
+    pub use super::{InlineHook, InlineHookRequest, InlineHookResponse, Links};
+}
+
+/// The types taken and returned by the functions in [`crate::linked_objects`].
+pub mod linked_objects {
+    pub use super::{LinkedObject, LinkedObjectRequest};
+}
+
+/// The types taken and returned by the functions in [`crate::logs`].
+pub mod logs {
+    pub use super::LogEvent;
+}
+
+/// The types taken and returned by the functions in [`crate::network_zones`].
+pub mod network_zones {
+    pub use super::{NetworkZone, NetworkZoneRequest};
+}
+
+/// The types taken and returned by the functions in [`crate::policies`].
+pub mod policies {
+    pub use super::{Policy, PolicyRequest, PolicyRule, PolicyRuleRequest};
+}
+
+/// The types taken and returned by the functions in [`crate::profile_mappings`].
+pub mod profile_mappings {
+    pub use super::{ProfileMapping, ProfileMappingRequest};
+}
+
+/// The types taken and returned by the functions in [`crate::sessions`].
+pub mod sessions {
+    pub use super::{CreateSessionRequest, Session};
+}
+
+/// The types taken and returned by the functions in [`crate::templates`].
+pub mod templates {
+    pub use super::{SmsTemplate, SmsTemplateRequest};
+}
+
+/// The types taken and returned by the functions in [`crate::threat_insights`].
+pub mod threat_insights {
+    pub use super::{ThreatInsightConfiguration, ThreatInsightConfigurationRequest};
+}
+
+/// The types taken and returned by the functions in [`crate::trusted_origins`].
+pub mod trusted_origins {
+    pub use super::{TrustedOrigin, TrustedOriginRequest};
+}
+
+/// The types taken and returned by the functions in [`crate::user_factors`].
+pub mod user_factors {
+    pub use super::{
+        ActivateFactorRequest, SecurityQuestion, UserFactor, UserFactorRequest,
+        VerifyFactorRequest, VerifyUserFactorResponse,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::user_schemas`].
+pub mod user_schemas {
+    pub use super::{UserSchema, UserSchemaRequest};
+}
+
+/// The types taken and returned by the functions in [`crate::user_types`].
+pub mod user_types {
+    pub use super::{UserType, UserTypeRequest};
+}
+
+/// The types taken and returned by the functions in [`crate::users`].
+pub mod users {
+    pub use super::{
+        AppLink, AssignRoleRequest, CatalogApplication, ChangePasswordRequest, CreateUserRequest,
+        Group, IdentityProvider, Links, OAuth2Client, OAuth2RefreshToken, OAuth2ScopeConsentGrant,
+        ResetPasswordToken, Role, TempPassword, User, UserActivationToken, UserCredentials,
+        UserRequest,
+    };
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct UserFactors {
     pub client: Client,
@@ -12,6 +10,14 @@ impl UserFactors {
         UserFactors { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        UserFactors {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/users/{userId}/factors` endpoint.
      *
@@ -22,12 +28,15 @@ impl UserFactors {
      * * `user_id: &str`
      */
     pub async fn list_factors(&self, user_id: &str) -> Result<Vec<crate::types::UserFactor>> {
-        let url = format!(
-            "/api/v1/users/{}/factors",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-        );
+        let url = format!("/api/v1/users/{}/factors", self.client.encode_path(user_id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/users/{userId}/factors",
+                &[("userId", user_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -38,12 +47,18 @@ impl UserFactors {
      * Enumerates all the enrolled factors for the specified user
      */
     pub async fn list_all_factors(&self, user_id: &str) -> Result<Vec<crate::types::UserFactor>> {
-        let url = format!(
-            "/api/v1/users/{}/factors",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-        );
+        let url = format!("/api/v1/users/{}/factors", self.client.encode_path(user_id),);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/api/v1/users/{userId}/factors",
+                    &[("userId", user_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -68,34 +83,36 @@ impl UserFactors {
         template_id: &str,
         token_lifetime_seconds: i64,
         activate: bool,
-        body: &crate::types::UserFactor,
+        body: &crate::types::UserFactorRequest,
     ) -> Result<crate::types::UserFactor> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if activate {
-            query_args.push(("activate".to_string(), activate.to_string()));
+            query_args.push(("activate", activate.to_string()));
         }
         if !template_id.is_empty() {
-            query_args.push(("templateId".to_string(), template_id.to_string()));
+            query_args.push(("templateId", template_id.to_string()));
         }
         if token_lifetime_seconds > 0 {
-            query_args.push((
-                "tokenLifetimeSeconds".to_string(),
-                token_lifetime_seconds.to_string(),
-            ));
+            query_args.push(("tokenLifetimeSeconds", token_lifetime_seconds.to_string()));
         }
         if update_phone {
-            query_args.push(("updatePhone".to_string(), update_phone.to_string()));
+            query_args.push(("updatePhone", update_phone.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/factors?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/api/v1/users/{}/factors", self.client.encode_path(user_id)),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/api/v1/users/{userId}/factors",
+                    &[("userId", user_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -113,10 +130,16 @@ impl UserFactors {
     ) -> Result<Vec<crate::types::UserFactor>> {
         let url = format!(
             "/api/v1/users/{}/factors/catalog",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(user_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/users/{userId}/factors/catalog",
+                &[("userId", user_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -132,10 +155,19 @@ impl UserFactors {
     ) -> Result<Vec<crate::types::UserFactor>> {
         let url = format!(
             "/api/v1/users/{}/factors/catalog",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(user_id),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/api/v1/users/{userId}/factors/catalog",
+                    &[("userId", user_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -153,10 +185,16 @@ impl UserFactors {
     ) -> Result<Vec<crate::types::SecurityQuestion>> {
         let url = format!(
             "/api/v1/users/{}/factors/questions",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(user_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/users/{userId}/factors/questions",
+                &[("userId", user_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -172,10 +210,19 @@ impl UserFactors {
     ) -> Result<Vec<crate::types::SecurityQuestion>> {
         let url = format!(
             "/api/v1/users/{}/factors/questions",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(user_id),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/api/v1/users/{userId}/factors/questions",
+                    &[("userId", user_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -195,11 +242,20 @@ impl UserFactors {
     ) -> Result<crate::types::UserFactor> {
         let url = format!(
             "/api/v1/users/{}/factors/{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&factor_id.to_string()),
+            self.client.encode_path(user_id),
+            self.client.encode_path(factor_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/users/{userId}/factors/{factorId}",
+                &[
+                    ("userId", user_id.to_string()),
+                    ("factorId", factor_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -215,11 +271,47 @@ impl UserFactors {
     pub async fn delete_factor(&self, user_id: &str, factor_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/users/{}/factors/{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&factor_id.to_string()),
+            self.client.encode_path(user_id),
+            self.client.encode_path(factor_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/users/{userId}/factors/{factorId}",
+                &[
+                    ("userId", user_id.to_string()),
+                    ("factorId", factor_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/api/v1/users/{userId}/factors/{factorId}` endpoint.
+     *
+     * As opposed to `delete_factor`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_factor_if_exists(&self, user_id: &str, factor_id: &str) -> Result<bool> {
+        let url = format!(
+            "/api/v1/users/{}/factors/{}",
+            self.client.encode_path(user_id),
+            self.client.encode_path(factor_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/users/{userId}/factors/{factorId}",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("factorId", factor_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -242,13 +334,23 @@ impl UserFactors {
     ) -> Result<crate::types::UserFactor> {
         let url = format!(
             "/api/v1/users/{}/factors/{}/lifecycle/activate",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&factor_id.to_string()),
+            self.client.encode_path(user_id),
+            self.client.encode_path(factor_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/api/v1/users/{userId}/factors/{factorId}/lifecycle/activate",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("factorId", factor_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -270,12 +372,22 @@ impl UserFactors {
     ) -> Result<crate::types::VerifyUserFactorResponse> {
         let url = format!(
             "/api/v1/users/{}/factors/{}/transactions/{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&factor_id.to_string()),
-            crate::progenitor_support::encode_path(&transaction_id.to_string()),
+            self.client.encode_path(user_id),
+            self.client.encode_path(factor_id),
+            self.client.encode_path(transaction_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/users/{userId}/factors/{factorId}/transactions/{transactionId}",
+                &[
+                    ("userId", user_id.to_string()),
+                    ("factorId", factor_id.to_string()),
+                    ("transactionId", transaction_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -303,26 +415,184 @@ impl UserFactors {
         token_lifetime_seconds: i64,
         body: &crate::types::VerifyFactorRequest,
     ) -> Result<crate::types::VerifyUserFactorResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !template_id.is_empty() {
-            query_args.push(("templateId".to_string(), template_id.to_string()));
+            query_args.push(("templateId", template_id.to_string()));
         }
         if token_lifetime_seconds > 0 {
-            query_args.push((
-                "tokenLifetimeSeconds".to_string(),
-                token_lifetime_seconds.to_string(),
-            ));
+            query_args.push(("tokenLifetimeSeconds", token_lifetime_seconds.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/factors/{}/verify?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&factor_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/api/v1/users/{}/factors/{}/verify",
+                self.client.encode_path(user_id),
+                self.client.encode_path(factor_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/api/v1/users/{userId}/factors/{factorId}/verify",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("factorId", factor_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`UserFactors`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn UserFactorsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait UserFactorsApi: Send + Sync {
+    async fn list_factors(&self, user_id: &str) -> Result<Vec<crate::types::UserFactor>>;
+    async fn list_all_factors(&self, user_id: &str) -> Result<Vec<crate::types::UserFactor>>;
+    async fn enroll_factor(
+        &self,
+        user_id: &str,
+        update_phone: bool,
+        template_id: &str,
+        token_lifetime_seconds: i64,
+        activate: bool,
+        body: &crate::types::UserFactorRequest,
+    ) -> Result<crate::types::UserFactor>;
+    async fn list_supported_factors(&self, user_id: &str) -> Result<Vec<crate::types::UserFactor>>;
+    async fn list_all_supported_factors(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<crate::types::UserFactor>>;
+    async fn list_supported_security_questions(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<crate::types::SecurityQuestion>>;
+    async fn list_all_supported_security_questions(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<crate::types::SecurityQuestion>>;
+    async fn get_factor(&self, user_id: &str, factor_id: &str) -> Result<crate::types::UserFactor>;
+    async fn delete_factor(&self, user_id: &str, factor_id: &str) -> Result<()>;
+    async fn delete_factor_if_exists(&self, user_id: &str, factor_id: &str) -> Result<bool>;
+    async fn activate_factor(
+        &self,
+        user_id: &str,
+        factor_id: &str,
+        body: &crate::types::ActivateFactorRequest,
+    ) -> Result<crate::types::UserFactor>;
+    async fn get_factor_transaction_status(
+        &self,
+        user_id: &str,
+        factor_id: &str,
+        transaction_id: &str,
+    ) -> Result<crate::types::VerifyUserFactorResponse>;
+    async fn verify_factor(
+        &self,
+        user_id: &str,
+        factor_id: &str,
+        template_id: &str,
+        token_lifetime_seconds: i64,
+        body: &crate::types::VerifyFactorRequest,
+    ) -> Result<crate::types::VerifyUserFactorResponse>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl UserFactorsApi for UserFactors {
+    async fn list_factors(&self, user_id: &str) -> Result<Vec<crate::types::UserFactor>> {
+        UserFactors::list_factors(self, user_id).await
+    }
+    async fn list_all_factors(&self, user_id: &str) -> Result<Vec<crate::types::UserFactor>> {
+        UserFactors::list_all_factors(self, user_id).await
+    }
+    async fn enroll_factor(
+        &self,
+        user_id: &str,
+        update_phone: bool,
+        template_id: &str,
+        token_lifetime_seconds: i64,
+        activate: bool,
+        body: &crate::types::UserFactorRequest,
+    ) -> Result<crate::types::UserFactor> {
+        UserFactors::enroll_factor(
+            self,
+            user_id,
+            update_phone,
+            template_id,
+            token_lifetime_seconds,
+            activate,
+            body,
+        )
+        .await
+    }
+    async fn list_supported_factors(&self, user_id: &str) -> Result<Vec<crate::types::UserFactor>> {
+        UserFactors::list_supported_factors(self, user_id).await
+    }
+    async fn list_all_supported_factors(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<crate::types::UserFactor>> {
+        UserFactors::list_all_supported_factors(self, user_id).await
+    }
+    async fn list_supported_security_questions(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<crate::types::SecurityQuestion>> {
+        UserFactors::list_supported_security_questions(self, user_id).await
+    }
+    async fn list_all_supported_security_questions(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<crate::types::SecurityQuestion>> {
+        UserFactors::list_all_supported_security_questions(self, user_id).await
+    }
+    async fn get_factor(&self, user_id: &str, factor_id: &str) -> Result<crate::types::UserFactor> {
+        UserFactors::get_factor(self, user_id, factor_id).await
+    }
+    async fn delete_factor(&self, user_id: &str, factor_id: &str) -> Result<()> {
+        UserFactors::delete_factor(self, user_id, factor_id).await
+    }
+    async fn delete_factor_if_exists(&self, user_id: &str, factor_id: &str) -> Result<bool> {
+        UserFactors::delete_factor_if_exists(self, user_id, factor_id).await
+    }
+    async fn activate_factor(
+        &self,
+        user_id: &str,
+        factor_id: &str,
+        body: &crate::types::ActivateFactorRequest,
+    ) -> Result<crate::types::UserFactor> {
+        UserFactors::activate_factor(self, user_id, factor_id, body).await
+    }
+    async fn get_factor_transaction_status(
+        &self,
+        user_id: &str,
+        factor_id: &str,
+        transaction_id: &str,
+    ) -> Result<crate::types::VerifyUserFactorResponse> {
+        UserFactors::get_factor_transaction_status(self, user_id, factor_id, transaction_id).await
+    }
+    async fn verify_factor(
+        &self,
+        user_id: &str,
+        factor_id: &str,
+        template_id: &str,
+        token_lifetime_seconds: i64,
+        body: &crate::types::VerifyFactorRequest,
+    ) -> Result<crate::types::VerifyUserFactorResponse> {
+        UserFactors::verify_factor(
+            self,
+            user_id,
+            factor_id,
+            template_id,
+            token_lifetime_seconds,
+            body,
+        )
+        .await
     }
 }
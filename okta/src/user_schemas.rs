@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct UserSchemas {
     pub client: Client,
@@ -12,6 +10,14 @@ impl UserSchemas {
         UserSchemas { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        UserSchemas {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Fetches the Schema for an App User.
      *
@@ -26,10 +32,16 @@ impl UserSchemas {
     pub async fn get_application(&self, app_instance_id: &str) -> Result<crate::types::UserSchema> {
         let url = format!(
             "/api/v1/meta/schemas/apps/{}/default",
-            crate::progenitor_support::encode_path(&app_instance_id.to_string()),
+            self.client.encode_path(app_instance_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/meta/schemas/apps/{appInstanceId}/default",
+                &[("appInstanceId", app_instance_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -46,16 +58,23 @@ impl UserSchemas {
     pub async fn update_application_user_profile(
         &self,
         app_instance_id: &str,
-        body: &crate::types::UserSchema,
+        body: &crate::types::UserSchemaRequest,
     ) -> Result<crate::types::UserSchema> {
         let url = format!(
             "/api/v1/meta/schemas/apps/{}/default",
-            crate::progenitor_support::encode_path(&app_instance_id.to_string()),
+            self.client.encode_path(app_instance_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/api/v1/meta/schemas/apps/{appInstanceId}/default",
+                    &[("appInstanceId", app_instance_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -72,10 +91,16 @@ impl UserSchemas {
     pub async fn get(&self, schema_id: &str) -> Result<crate::types::UserSchema> {
         let url = format!(
             "/api/v1/meta/schemas/user/{}",
-            crate::progenitor_support::encode_path(&schema_id.to_string()),
+            self.client.encode_path(schema_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/meta/schemas/user/{schemaId}",
+                &[("schemaId", schema_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -90,15 +115,67 @@ impl UserSchemas {
     pub async fn update_user_profile(
         &self,
         schema_id: &str,
-        body: &crate::types::UserSchema,
+        body: &crate::types::UserSchemaRequest,
     ) -> Result<crate::types::UserSchema> {
         let url = format!(
             "/api/v1/meta/schemas/user/{}",
-            crate::progenitor_support::encode_path(&schema_id.to_string()),
+            self.client.encode_path(schema_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/api/v1/meta/schemas/user/{schemaId}",
+                    &[("schemaId", schema_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`UserSchemas`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn UserSchemasApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait UserSchemasApi: Send + Sync {
+    async fn get_application(&self, app_instance_id: &str) -> Result<crate::types::UserSchema>;
+    async fn update_application_user_profile(
+        &self,
+        app_instance_id: &str,
+        body: &crate::types::UserSchemaRequest,
+    ) -> Result<crate::types::UserSchema>;
+    async fn get(&self, schema_id: &str) -> Result<crate::types::UserSchema>;
+    async fn update_user_profile(
+        &self,
+        schema_id: &str,
+        body: &crate::types::UserSchemaRequest,
+    ) -> Result<crate::types::UserSchema>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl UserSchemasApi for UserSchemas {
+    async fn get_application(&self, app_instance_id: &str) -> Result<crate::types::UserSchema> {
+        UserSchemas::get_application(self, app_instance_id).await
+    }
+    async fn update_application_user_profile(
+        &self,
+        app_instance_id: &str,
+        body: &crate::types::UserSchemaRequest,
+    ) -> Result<crate::types::UserSchema> {
+        UserSchemas::update_application_user_profile(self, app_instance_id, body).await
+    }
+    async fn get(&self, schema_id: &str) -> Result<crate::types::UserSchema> {
+        UserSchemas::get(self, schema_id).await
+    }
+    async fn update_user_profile(
+        &self,
+        schema_id: &str,
+        body: &crate::types::UserSchemaRequest,
+    ) -> Result<crate::types::UserSchema> {
+        UserSchemas::update_user_profile(self, schema_id, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct UserTypes {
     pub client: Client,
@@ -12,6 +10,14 @@ impl UserTypes {
         UserTypes { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        UserTypes {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/meta/types/user` endpoint.
      *
@@ -19,7 +25,10 @@ impl UserTypes {
      */
     pub async fn list(&self) -> Result<Vec<crate::types::UserType>> {
         let url = "/api/v1/meta/types/user".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/api/v1/meta/types/user", &[]))
     }
 
     /**
@@ -31,7 +40,10 @@ impl UserTypes {
      */
     pub async fn list_all(&self) -> Result<Vec<crate::types::UserType>> {
         let url = "/api/v1/meta/types/user".to_string();
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/api/v1/meta/types/user", &[]))
     }
 
     /**
@@ -39,11 +51,15 @@ impl UserTypes {
      *
      * Creates a new User Type. A default User Type is automatically created along with your org, and you may add another 9 User Types for a maximum of 10.
      */
-    pub async fn create(&self, body: &crate::types::UserType) -> Result<crate::types::UserType> {
+    pub async fn create(
+        &self,
+        body: &crate::types::UserTypeRequest,
+    ) -> Result<crate::types::UserType> {
         let url = "/api/v1/meta/types/user".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/api/v1/meta/types/user", &[]))
     }
 
     /**
@@ -58,10 +74,16 @@ impl UserTypes {
     pub async fn get(&self, type_id: &str) -> Result<crate::types::UserType> {
         let url = format!(
             "/api/v1/meta/types/user/{}",
-            crate::progenitor_support::encode_path(&type_id.to_string()),
+            self.client.encode_path(type_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/meta/types/user/{typeId}",
+                &[("typeId", type_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -76,16 +98,23 @@ impl UserTypes {
     pub async fn replace(
         &self,
         type_id: &str,
-        body: &crate::types::UserType,
+        body: &crate::types::UserTypeRequest,
     ) -> Result<crate::types::UserType> {
         let url = format!(
             "/api/v1/meta/types/user/{}",
-            crate::progenitor_support::encode_path(&type_id.to_string()),
+            self.client.encode_path(type_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/api/v1/meta/types/user/{typeId}",
+                    &[("typeId", type_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -100,16 +129,23 @@ impl UserTypes {
     pub async fn update(
         &self,
         type_id: &str,
-        body: &crate::types::UserType,
+        body: &crate::types::UserTypeRequest,
     ) -> Result<crate::types::UserType> {
         let url = format!(
             "/api/v1/meta/types/user/{}",
-            crate::progenitor_support::encode_path(&type_id.to_string()),
+            self.client.encode_path(type_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/api/v1/meta/types/user/{typeId}",
+                    &[("typeId", type_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -124,9 +160,99 @@ impl UserTypes {
     pub async fn delete(&self, type_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/meta/types/user/{}",
-            crate::progenitor_support::encode_path(&type_id.to_string()),
+            self.client.encode_path(type_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/meta/types/user/{typeId}",
+                &[("typeId", type_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/api/v1/meta/types/user/{typeId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, type_id: &str) -> Result<bool> {
+        let url = format!(
+            "/api/v1/meta/types/user/{}",
+            self.client.encode_path(type_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/meta/types/user/{typeId}",
+                    &[("typeId", type_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`UserTypes`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn UserTypesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait UserTypesApi: Send + Sync {
+    async fn list(&self) -> Result<Vec<crate::types::UserType>>;
+    async fn list_all(&self) -> Result<Vec<crate::types::UserType>>;
+    async fn create(&self, body: &crate::types::UserTypeRequest) -> Result<crate::types::UserType>;
+    async fn get(&self, type_id: &str) -> Result<crate::types::UserType>;
+    async fn replace(
+        &self,
+        type_id: &str,
+        body: &crate::types::UserTypeRequest,
+    ) -> Result<crate::types::UserType>;
+    async fn update(
+        &self,
+        type_id: &str,
+        body: &crate::types::UserTypeRequest,
+    ) -> Result<crate::types::UserType>;
+    async fn delete(&self, type_id: &str) -> Result<()>;
+    async fn delete_if_exists(&self, type_id: &str) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl UserTypesApi for UserTypes {
+    async fn list(&self) -> Result<Vec<crate::types::UserType>> {
+        UserTypes::list(self).await
+    }
+    async fn list_all(&self) -> Result<Vec<crate::types::UserType>> {
+        UserTypes::list_all(self).await
+    }
+    async fn create(&self, body: &crate::types::UserTypeRequest) -> Result<crate::types::UserType> {
+        UserTypes::create(self, body).await
+    }
+    async fn get(&self, type_id: &str) -> Result<crate::types::UserType> {
+        UserTypes::get(self, type_id).await
+    }
+    async fn replace(
+        &self,
+        type_id: &str,
+        body: &crate::types::UserTypeRequest,
+    ) -> Result<crate::types::UserType> {
+        UserTypes::replace(self, type_id, body).await
+    }
+    async fn update(
+        &self,
+        type_id: &str,
+        body: &crate::types::UserTypeRequest,
+    ) -> Result<crate::types::UserType> {
+        UserTypes::update(self, type_id, body).await
+    }
+    async fn delete(&self, type_id: &str) -> Result<()> {
+        UserTypes::delete(self, type_id).await
+    }
+    async fn delete_if_exists(&self, type_id: &str) -> Result<bool> {
+        UserTypes::delete_if_exists(self, type_id).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Users {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Users {
         Users { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Users {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * List Users.
      *
@@ -39,32 +45,83 @@ impl Users {
         sort_by: &str,
         sort_order: &str,
     ) -> Result<Vec<crate::types::User>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !after.is_empty() {
+            query_args.push(("after", after.to_string()));
+        }
+        if !filter.is_empty() {
+            query_args.push(("filter", filter.to_string()));
+        }
+        if limit > 0 {
+            query_args.push(("limit", limit.to_string()));
+        }
+        if !q.is_empty() {
+            query_args.push(("q", q.to_string()));
+        }
+        if !search.is_empty() {
+            query_args.push(("search", search.to_string()));
+        }
+        if !sort_by.is_empty() {
+            query_args.push(("sortBy", sort_by.to_string()));
+        }
+        if !sort_order.is_empty() {
+            query_args.push(("sortOrder", sort_order.to_string()));
+        }
+        let url = crate::Client::url_with_query("/api/v1/users", &query_args);
+
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/api/v1/users", &[]))
+    }
+
+    /**
+     * List Users.
+     *
+     * This function performs a `GET` to the `/api/v1/users` endpoint.
+     *
+     * As opposed to `list`, this function streams the items as they are parsed from the response, rather than returning them all at once.
+     * This is useful for very large responses that would otherwise need to be held in memory.
+     * Only the one page the parameters ask for is streamed, the next pages are not fetched.
+     */
+    pub async fn list_stream_json(
+        &self,
+        q: &str,
+        after: &str,
+        limit: i64,
+        filter: &str,
+        search: &str,
+        sort_by: &str,
+        sort_order: &str,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::User>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if !search.is_empty() {
-            query_args.push(("search".to_string(), search.to_string()));
+            query_args.push(("search", search.to_string()));
         }
         if !sort_by.is_empty() {
-            query_args.push(("sortBy".to_string(), sort_by.to_string()));
+            query_args.push(("sortBy", sort_by.to_string()));
         }
         if !sort_order.is_empty() {
-            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+            query_args.push(("sortOrder", sort_order.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/users?{}", query_);
+        let url = crate::Client::url_with_query("/api/v1/users", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get_stream_json(&url, "")
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/api/v1/users", &[]))
     }
 
     /**
@@ -84,26 +141,28 @@ impl Users {
         sort_by: &str,
         sort_order: &str,
     ) -> Result<Vec<crate::types::User>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if !q.is_empty() {
-            query_args.push(("q".to_string(), q.to_string()));
+            query_args.push(("q", q.to_string()));
         }
         if !search.is_empty() {
-            query_args.push(("search".to_string(), search.to_string()));
+            query_args.push(("search", search.to_string()));
         }
         if !sort_by.is_empty() {
-            query_args.push(("sortBy".to_string(), sort_by.to_string()));
+            query_args.push(("sortBy", sort_by.to_string()));
         }
         if !sort_order.is_empty() {
-            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+            query_args.push(("sortOrder", sort_order.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/users?{}", query_);
+        let url = crate::Client::url_with_query("/api/v1/users", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/api/v1/users", &[]))
     }
 
     /**
@@ -126,22 +185,22 @@ impl Users {
         next_login: &str,
         body: &crate::types::CreateUserRequest,
     ) -> Result<crate::types::User> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if activate {
-            query_args.push(("activate".to_string(), activate.to_string()));
+            query_args.push(("activate", activate.to_string()));
         }
         if !next_login.is_empty() {
-            query_args.push(("nextLogin".to_string(), next_login.to_string()));
+            query_args.push(("nextLogin", next_login.to_string()));
         }
         if provider {
-            query_args.push(("provider".to_string(), provider.to_string()));
+            query_args.push(("provider", provider.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api/v1/users?{}", query_);
+        let url = crate::Client::url_with_query("/api/v1/users", &query_args);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/api/v1/users", &[]))
     }
 
     /**
@@ -161,12 +220,12 @@ impl Users {
     ) -> Result<()> {
         let url = format!(
             "/api/v1/users/{}/linkedObjects/{}/{}",
-            crate::progenitor_support::encode_path(&associated_user_id.to_string()),
-            crate::progenitor_support::encode_path(&primary_relationship_name.to_string()),
-            crate::progenitor_support::encode_path(&primary_user_id.to_string()),
+            self.client.encode_path(associated_user_id),
+            self.client.encode_path(primary_relationship_name),
+            self.client.encode_path(primary_user_id),
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| crate::utils::pretty_url("PUT", "/api/v1/users/{associatedUserId}/linkedObjects/{primaryRelationshipName}/{primaryUserId}", &[("associatedUserId", associated_user_id.to_string()), ("primaryRelationshipName", primary_relationship_name.to_string()), ("primaryUserId", primary_user_id.to_string())]))
     }
 
     /**
@@ -181,12 +240,15 @@ impl Users {
      * * `user_id: &str`
      */
     pub async fn get(&self, user_id: &str) -> Result<crate::types::User> {
-        let url = format!(
-            "/api/v1/users/{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-        );
-
-        self.client.get(&url, None).await
+        let url = format!("/api/v1/users/{}", self.client.encode_path(user_id),);
+
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/users/{userId}",
+                &[("userId", user_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -205,22 +267,27 @@ impl Users {
         &self,
         user_id: &str,
         strict: bool,
-        body: &crate::types::User,
+        body: &crate::types::UserRequest,
     ) -> Result<crate::types::User> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if strict {
-            query_args.push(("strict".to_string(), strict.to_string()));
+            query_args.push(("strict", strict.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/api/v1/users/{}", self.client.encode_path(user_id)),
+            &query_args,
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/api/v1/users/{userId}",
+                    &[("userId", user_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -237,22 +304,27 @@ impl Users {
         &self,
         user_id: &str,
         strict: bool,
-        body: &crate::types::User,
+        body: &crate::types::UserRequest,
     ) -> Result<crate::types::User> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if strict {
-            query_args.push(("strict".to_string(), strict.to_string()));
+            query_args.push(("strict", strict.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/api/v1/users/{}", self.client.encode_path(user_id)),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/api/v1/users/{userId}",
+                    &[("userId", user_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -268,18 +340,55 @@ impl Users {
      * * `send_email: bool`
      */
     pub async fn deactivate_or_delete(&self, user_id: &str, send_email: bool) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if send_email {
-            query_args.push(("sendEmail".to_string(), send_email.to_string()));
+            query_args.push(("sendEmail", send_email.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/api/v1/users/{}", self.client.encode_path(user_id)),
+            &query_args,
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/users/{userId}",
+                &[("userId", user_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete User.
+     *
+     * This function performs a `DELETE` to the `/api/v1/users/{userId}` endpoint.
+     *
+     * As opposed to `deactivate_or_delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deactivate_or_delete_if_exists(
+        &self,
+        user_id: &str,
+        send_email: bool,
+    ) -> Result<bool> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if send_email {
+            query_args.push(("sendEmail", send_email.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!("/api/v1/users/{}", self.client.encode_path(user_id)),
+            &query_args,
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/users/{userId}",
+                    &[("userId", user_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -296,10 +405,16 @@ impl Users {
     pub async fn list_app_links(&self, user_id: &str) -> Result<Vec<crate::types::AppLink>> {
         let url = format!(
             "/api/v1/users/{}/appLinks",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(user_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/users/{userId}/appLinks",
+                &[("userId", user_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -314,10 +429,19 @@ impl Users {
     pub async fn list_all_app_links(&self, user_id: &str) -> Result<Vec<crate::types::AppLink>> {
         let url = format!(
             "/api/v1/users/{}/appLinks",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(user_id),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/api/v1/users/{userId}/appLinks",
+                    &[("userId", user_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -330,12 +454,15 @@ impl Users {
      * * `user_id: &str`
      */
     pub async fn list_clients(&self, user_id: &str) -> Result<Vec<crate::types::OAuth2Client>> {
-        let url = format!(
-            "/api/v1/users/{}/clients",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-        );
-
-        self.client.get(&url, None).await
+        let url = format!("/api/v1/users/{}/clients", self.client.encode_path(user_id),);
+
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/users/{userId}/clients",
+                &[("userId", user_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -346,12 +473,18 @@ impl Users {
      * Lists all client resources for which the specified user has grants or tokens.
      */
     pub async fn list_all_clients(&self, user_id: &str) -> Result<Vec<crate::types::OAuth2Client>> {
-        let url = format!(
-            "/api/v1/users/{}/clients",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-        );
+        let url = format!("/api/v1/users/{}/clients", self.client.encode_path(user_id),);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/api/v1/users/{userId}/clients",
+                    &[("userId", user_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -375,25 +508,35 @@ impl Users {
         after: &str,
         limit: i64,
     ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/clients/{}/grants?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&client_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/api/v1/users/{}/clients/{}/grants",
+                self.client.encode_path(user_id),
+                self.client.encode_path(client_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/users/{userId}/clients/{clientId}/grants",
+                &[
+                    ("userId", user_id.to_string()),
+                    ("clientId", client_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -409,19 +552,32 @@ impl Users {
         client_id: &str,
         expand: &str,
     ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/clients/{}/grants?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&client_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/api/v1/users/{}/clients/{}/grants",
+                self.client.encode_path(user_id),
+                self.client.encode_path(client_id)
+            ),
+            &query_args,
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/api/v1/users/{userId}/clients/{clientId}/grants",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("clientId", client_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -437,11 +593,51 @@ impl Users {
     pub async fn revoke_grants_for_and_client(&self, user_id: &str, client_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/users/{}/clients/{}/grants",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&client_id.to_string()),
+            self.client.encode_path(user_id),
+            self.client.encode_path(client_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/users/{userId}/clients/{clientId}/grants",
+                &[
+                    ("userId", user_id.to_string()),
+                    ("clientId", client_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/api/v1/users/{userId}/clients/{clientId}/grants` endpoint.
+     *
+     * As opposed to `revoke_grants_for_and_client`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn revoke_grants_for_and_client_if_exists(
+        &self,
+        user_id: &str,
+        client_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/api/v1/users/{}/clients/{}/grants",
+            self.client.encode_path(user_id),
+            self.client.encode_path(client_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/users/{userId}/clients/{clientId}/grants",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("clientId", client_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -465,25 +661,35 @@ impl Users {
         after: &str,
         limit: i64,
     ) -> Result<Vec<crate::types::OAuth2RefreshToken>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/clients/{}/tokens?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&client_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/api/v1/users/{}/clients/{}/tokens",
+                self.client.encode_path(user_id),
+                self.client.encode_path(client_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/users/{userId}/clients/{clientId}/tokens",
+                &[
+                    ("userId", user_id.to_string()),
+                    ("clientId", client_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -499,19 +705,32 @@ impl Users {
         client_id: &str,
         expand: &str,
     ) -> Result<Vec<crate::types::OAuth2RefreshToken>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/clients/{}/tokens?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&client_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/api/v1/users/{}/clients/{}/tokens",
+                self.client.encode_path(user_id),
+                self.client.encode_path(client_id)
+            ),
+            &query_args,
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/api/v1/users/{userId}/clients/{clientId}/tokens",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("clientId", client_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -527,11 +746,51 @@ impl Users {
     pub async fn revoke_tokens_for_and_client(&self, user_id: &str, client_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/users/{}/clients/{}/tokens",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&client_id.to_string()),
+            self.client.encode_path(user_id),
+            self.client.encode_path(client_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/users/{userId}/clients/{clientId}/tokens",
+                &[
+                    ("userId", user_id.to_string()),
+                    ("clientId", client_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/api/v1/users/{userId}/clients/{clientId}/tokens` endpoint.
+     *
+     * As opposed to `revoke_tokens_for_and_client`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn revoke_tokens_for_and_client_if_exists(
+        &self,
+        user_id: &str,
+        client_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/api/v1/users/{}/clients/{}/tokens",
+            self.client.encode_path(user_id),
+            self.client.encode_path(client_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/users/{userId}/clients/{clientId}/tokens",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("clientId", client_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -557,26 +816,37 @@ impl Users {
         limit: i64,
         after: &str,
     ) -> Result<crate::types::OAuth2RefreshToken> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/clients/{}/tokens/{}?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&client_id.to_string()),
-            crate::progenitor_support::encode_path(&token_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/api/v1/users/{}/clients/{}/tokens/{}",
+                self.client.encode_path(user_id),
+                self.client.encode_path(client_id),
+                self.client.encode_path(token_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/users/{userId}/clients/{clientId}/tokens/{tokenId}",
+                &[
+                    ("userId", user_id.to_string()),
+                    ("clientId", client_id.to_string()),
+                    ("tokenId", token_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -598,12 +868,56 @@ impl Users {
     ) -> Result<()> {
         let url = format!(
             "/api/v1/users/{}/clients/{}/tokens/{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&client_id.to_string()),
-            crate::progenitor_support::encode_path(&token_id.to_string()),
+            self.client.encode_path(user_id),
+            self.client.encode_path(client_id),
+            self.client.encode_path(token_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/users/{userId}/clients/{clientId}/tokens/{tokenId}",
+                &[
+                    ("userId", user_id.to_string()),
+                    ("clientId", client_id.to_string()),
+                    ("tokenId", token_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/api/v1/users/{userId}/clients/{clientId}/tokens/{tokenId}` endpoint.
+     *
+     * As opposed to `revoke_token_for_and_client`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn revoke_token_for_and_client_if_exists(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        token_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/api/v1/users/{}/clients/{}/tokens/{}",
+            self.client.encode_path(user_id),
+            self.client.encode_path(client_id),
+            self.client.encode_path(token_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/users/{userId}/clients/{clientId}/tokens/{tokenId}",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("clientId", client_id.to_string()),
+                        ("tokenId", token_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -624,20 +938,28 @@ impl Users {
         strict: bool,
         body: &crate::types::ChangePasswordRequest,
     ) -> Result<crate::types::UserCredentials> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if strict {
-            query_args.push(("strict".to_string(), strict.to_string()));
+            query_args.push(("strict", strict.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/credentials/change_password?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/api/v1/users/{}/credentials/change_password",
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/api/v1/users/{userId}/credentials/change_password",
+                    &[("userId", user_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -658,12 +980,19 @@ impl Users {
     ) -> Result<crate::types::UserCredentials> {
         let url = format!(
             "/api/v1/users/{}/credentials/change_recovery_question",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(user_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/api/v1/users/{userId}/credentials/change_recovery_question",
+                    &[("userId", user_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -681,10 +1010,16 @@ impl Users {
     ) -> Result<crate::types::ResetPasswordToken> {
         let url = format!(
             "/api/v1/users/{}/credentials/forgot_password",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(user_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/api/v1/users/{userId}/credentials/forgot_password",
+                &[("userId", user_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -708,27 +1043,31 @@ impl Users {
         after: &str,
         limit: i64,
     ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !scope_id.is_empty() {
-            query_args.push(("scopeId".to_string(), scope_id.to_string()));
+            query_args.push(("scopeId", scope_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/grants?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/api/v1/users/{}/grants", self.client.encode_path(user_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/users/{userId}/grants",
+                &[("userId", user_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -744,21 +1083,28 @@ impl Users {
         scope_id: &str,
         expand: &str,
     ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
         if !scope_id.is_empty() {
-            query_args.push(("scopeId".to_string(), scope_id.to_string()));
+            query_args.push(("scopeId", scope_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/grants?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/api/v1/users/{}/grants", self.client.encode_path(user_id)),
+            &query_args,
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/api/v1/users/{userId}/grants",
+                    &[("userId", user_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -771,12 +1117,35 @@ impl Users {
      * * `user_id: &str`
      */
     pub async fn revoke_grants(&self, user_id: &str) -> Result<()> {
-        let url = format!(
-            "/api/v1/users/{}/grants",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-        );
+        let url = format!("/api/v1/users/{}/grants", self.client.encode_path(user_id),);
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/users/{userId}/grants",
+                &[("userId", user_id.to_string())],
+            )
+        })
+    }
 
-        self.client.delete(&url, None).await
+    /**
+     * This function performs a `DELETE` to the `/api/v1/users/{userId}/grants` endpoint.
+     *
+     * As opposed to `revoke_grants`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn revoke_grants_if_exists(&self, user_id: &str) -> Result<bool> {
+        let url = format!("/api/v1/users/{}/grants", self.client.encode_path(user_id),);
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/users/{userId}/grants",
+                    &[("userId", user_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -796,19 +1165,29 @@ impl Users {
         grant_id: &str,
         expand: &str,
     ) -> Result<crate::types::OAuth2ScopeConsentGrant> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/grants/{}?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&grant_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/api/v1/users/{}/grants/{}",
+                self.client.encode_path(user_id),
+                self.client.encode_path(grant_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/users/{userId}/grants/{grantId}",
+                &[
+                    ("userId", user_id.to_string()),
+                    ("grantId", grant_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -824,11 +1203,47 @@ impl Users {
     pub async fn revoke_grant(&self, user_id: &str, grant_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/users/{}/grants/{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&grant_id.to_string()),
+            self.client.encode_path(user_id),
+            self.client.encode_path(grant_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/users/{userId}/grants/{grantId}",
+                &[
+                    ("userId", user_id.to_string()),
+                    ("grantId", grant_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/api/v1/users/{userId}/grants/{grantId}` endpoint.
+     *
+     * As opposed to `revoke_grant`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn revoke_grant_if_exists(&self, user_id: &str, grant_id: &str) -> Result<bool> {
+        let url = format!(
+            "/api/v1/users/{}/grants/{}",
+            self.client.encode_path(user_id),
+            self.client.encode_path(grant_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/users/{userId}/grants/{grantId}",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("grantId", grant_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -843,12 +1258,15 @@ impl Users {
      * * `user_id: &str`
      */
     pub async fn list_groups(&self, user_id: &str) -> Result<Vec<crate::types::Group>> {
-        let url = format!(
-            "/api/v1/users/{}/groups",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-        );
-
-        self.client.get(&url, None).await
+        let url = format!("/api/v1/users/{}/groups", self.client.encode_path(user_id),);
+
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/users/{userId}/groups",
+                &[("userId", user_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -861,12 +1279,18 @@ impl Users {
      * Fetches the groups of which the user is a member.
      */
     pub async fn list_all_groups(&self, user_id: &str) -> Result<Vec<crate::types::Group>> {
-        let url = format!(
-            "/api/v1/users/{}/groups",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-        );
+        let url = format!("/api/v1/users/{}/groups", self.client.encode_path(user_id),);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/api/v1/users/{userId}/groups",
+                    &[("userId", user_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -884,12 +1308,15 @@ impl Users {
         &self,
         user_id: &str,
     ) -> Result<Vec<crate::types::IdentityProvider>> {
-        let url = format!(
-            "/api/v1/users/{}/idps",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-        );
-
-        self.client.get(&url, None).await
+        let url = format!("/api/v1/users/{}/idps", self.client.encode_path(user_id),);
+
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/users/{userId}/idps",
+                &[("userId", user_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -905,12 +1332,18 @@ impl Users {
         &self,
         user_id: &str,
     ) -> Result<Vec<crate::types::IdentityProvider>> {
-        let url = format!(
-            "/api/v1/users/{}/idps",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-        );
+        let url = format!("/api/v1/users/{}/idps", self.client.encode_path(user_id),);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/api/v1/users/{userId}/idps",
+                    &[("userId", user_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -930,18 +1363,25 @@ impl Users {
         user_id: &str,
         send_email: bool,
     ) -> Result<crate::types::UserActivationToken> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if send_email {
-            query_args.push(("sendEmail".to_string(), send_email.to_string()));
+            query_args.push(("sendEmail", send_email.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/lifecycle/activate?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/api/v1/users/{}/lifecycle/activate",
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/api/v1/users/{userId}/lifecycle/activate",
+                &[("userId", user_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -957,18 +1397,25 @@ impl Users {
      * * `send_email: bool`
      */
     pub async fn deactivate(&self, user_id: &str, send_email: bool) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if send_email {
-            query_args.push(("sendEmail".to_string(), send_email.to_string()));
+            query_args.push(("sendEmail", send_email.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/lifecycle/deactivate?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/api/v1/users/{}/lifecycle/deactivate",
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/api/v1/users/{userId}/lifecycle/deactivate",
+                &[("userId", user_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -985,10 +1432,16 @@ impl Users {
     pub async fn expire_password(&self, user_id: &str) -> Result<crate::types::User> {
         let url = format!(
             "/api/v1/users/{}/lifecycle/expire_password?tempPassword=false",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(user_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/api/v1/users/{userId}/lifecycle/expire_password?tempPassword=false",
+                &[("userId", user_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1008,10 +1461,16 @@ impl Users {
     ) -> Result<crate::types::TempPassword> {
         let url = format!(
             "/api/v1/users/{}/lifecycle/expire_password?tempPassword=true",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(user_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/api/v1/users/{userId}/lifecycle/expire_password?tempPassword=true",
+                &[("userId", user_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1031,18 +1490,25 @@ impl Users {
         user_id: &str,
         send_email: bool,
     ) -> Result<crate::types::UserActivationToken> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if send_email {
-            query_args.push(("sendEmail".to_string(), send_email.to_string()));
+            query_args.push(("sendEmail", send_email.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/lifecycle/reactivate?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/api/v1/users/{}/lifecycle/reactivate",
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/api/v1/users/{userId}/lifecycle/reactivate",
+                &[("userId", user_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1059,10 +1525,16 @@ impl Users {
     pub async fn reset_factors(&self, user_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/users/{}/lifecycle/reset_factors",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(user_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/api/v1/users/{userId}/lifecycle/reset_factors",
+                &[("userId", user_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1082,18 +1554,25 @@ impl Users {
         user_id: &str,
         send_email: bool,
     ) -> Result<crate::types::ResetPasswordToken> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if send_email {
-            query_args.push(("sendEmail".to_string(), send_email.to_string()));
+            query_args.push(("sendEmail", send_email.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/lifecycle/reset_password?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/api/v1/users/{}/lifecycle/reset_password",
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/api/v1/users/{userId}/lifecycle/reset_password",
+                &[("userId", user_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1110,10 +1589,16 @@ impl Users {
     pub async fn suspend(&self, user_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/users/{}/lifecycle/suspend",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(user_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/api/v1/users/{userId}/lifecycle/suspend",
+                &[("userId", user_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1130,10 +1615,16 @@ impl Users {
     pub async fn unlock(&self, user_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/users/{}/lifecycle/unlock",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(user_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/api/v1/users/{userId}/lifecycle/unlock",
+                &[("userId", user_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1150,10 +1641,16 @@ impl Users {
     pub async fn unsuspend(&self, user_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/users/{}/lifecycle/unsuspend",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(user_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/api/v1/users/{userId}/lifecycle/unsuspend",
+                &[("userId", user_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1175,22 +1672,32 @@ impl Users {
         after: &str,
         limit: i64,
     ) -> Result<Vec<crate::types::Links>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/linkedObjects/{}?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&relationship_name.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/api/v1/users/{}/linkedObjects/{}",
+                self.client.encode_path(user_id),
+                self.client.encode_path(relationship_name)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/users/{userId}/linkedObjects/{relationshipName}",
+                &[
+                    ("userId", user_id.to_string()),
+                    ("relationshipName", relationship_name.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1207,11 +1714,23 @@ impl Users {
     ) -> Result<Vec<crate::types::Links>> {
         let url = format!(
             "/api/v1/users/{}/linkedObjects/{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&relationship_name.to_string()),
+            self.client.encode_path(user_id),
+            self.client.encode_path(relationship_name),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/api/v1/users/{userId}/linkedObjects/{relationshipName}",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("relationshipName", relationship_name.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1231,11 +1750,51 @@ impl Users {
     ) -> Result<()> {
         let url = format!(
             "/api/v1/users/{}/linkedObjects/{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&relationship_name.to_string()),
+            self.client.encode_path(user_id),
+            self.client.encode_path(relationship_name),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/users/{userId}/linkedObjects/{relationshipName}",
+                &[
+                    ("userId", user_id.to_string()),
+                    ("relationshipName", relationship_name.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/api/v1/users/{userId}/linkedObjects/{relationshipName}` endpoint.
+     *
+     * As opposed to `remove_linked_object_for`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn remove_linked_object_for_if_exists(
+        &self,
+        user_id: &str,
+        relationship_name: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/api/v1/users/{}/linkedObjects/{}",
+            self.client.encode_path(user_id),
+            self.client.encode_path(relationship_name),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/users/{userId}/linkedObjects/{relationshipName}",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("relationshipName", relationship_name.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1253,18 +1812,22 @@ impl Users {
         user_id: &str,
         expand: &str,
     ) -> Result<Vec<crate::types::Role>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/roles?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/api/v1/users/{}/roles", self.client.encode_path(user_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/users/{userId}/roles",
+                &[("userId", user_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1279,18 +1842,25 @@ impl Users {
         user_id: &str,
         expand: &str,
     ) -> Result<Vec<crate::types::Role>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !expand.is_empty() {
-            query_args.push(("expand".to_string(), expand.to_string()));
+            query_args.push(("expand", expand.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/roles?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/api/v1/users/{}/roles", self.client.encode_path(user_id)),
+            &query_args,
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/api/v1/users/{userId}/roles",
+                    &[("userId", user_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1309,23 +1879,25 @@ impl Users {
         disable_notifications: &str,
         body: &crate::types::AssignRoleRequest,
     ) -> Result<crate::types::Role> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !disable_notifications.is_empty() {
-            query_args.push((
-                "disableNotifications".to_string(),
-                disable_notifications.to_string(),
-            ));
+            query_args.push(("disableNotifications", disable_notifications.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/roles?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/api/v1/users/{}/roles", self.client.encode_path(user_id)),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/api/v1/users/{userId}/roles",
+                    &[("userId", user_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1341,11 +1913,47 @@ impl Users {
     pub async fn remove_role_from(&self, user_id: &str, role_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/users/{}/roles/{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&role_id.to_string()),
+            self.client.encode_path(user_id),
+            self.client.encode_path(role_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/users/{userId}/roles/{roleId}",
+                &[
+                    ("userId", user_id.to_string()),
+                    ("roleId", role_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/api/v1/users/{userId}/roles/{roleId}` endpoint.
+     *
+     * As opposed to `remove_role_from`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn remove_role_from_if_exists(&self, user_id: &str, role_id: &str) -> Result<bool> {
+        let url = format!(
+            "/api/v1/users/{}/roles/{}",
+            self.client.encode_path(user_id),
+            self.client.encode_path(role_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/users/{userId}/roles/{roleId}",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("roleId", role_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1367,22 +1975,32 @@ impl Users {
         after: &str,
         limit: i64,
     ) -> Result<Vec<crate::types::CatalogApplication>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/roles/{}/targets/catalog/apps?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&role_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/api/v1/users/{}/roles/{}/targets/catalog/apps",
+                self.client.encode_path(user_id),
+                self.client.encode_path(role_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/users/{userId}/roles/{roleId}/targets/catalog/apps",
+                &[
+                    ("userId", user_id.to_string()),
+                    ("roleId", role_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1399,11 +2017,23 @@ impl Users {
     ) -> Result<Vec<crate::types::CatalogApplication>> {
         let url = format!(
             "/api/v1/users/{}/roles/{}/targets/catalog/apps",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&role_id.to_string()),
+            self.client.encode_path(user_id),
+            self.client.encode_path(role_id),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/api/v1/users/{userId}/roles/{roleId}/targets/catalog/apps",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("roleId", role_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1419,11 +2049,20 @@ impl Users {
     pub async fn add_all_apps_as_target_role(&self, user_id: &str, role_id: &str) -> Result<()> {
         let url = format!(
             "/api/v1/users/{}/roles/{}/targets/catalog/apps",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&role_id.to_string()),
+            self.client.encode_path(user_id),
+            self.client.encode_path(role_id),
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "PUT",
+                "/api/v1/users/{userId}/roles/{roleId}/targets/catalog/apps",
+                &[
+                    ("userId", user_id.to_string()),
+                    ("roleId", role_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1445,12 +2084,22 @@ impl Users {
     ) -> Result<()> {
         let url = format!(
             "/api/v1/users/{}/roles/{}/targets/catalog/apps/{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&role_id.to_string()),
-            crate::progenitor_support::encode_path(&app_name.to_string()),
+            self.client.encode_path(user_id),
+            self.client.encode_path(role_id),
+            self.client.encode_path(app_name),
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "PUT",
+                "/api/v1/users/{userId}/roles/{roleId}/targets/catalog/apps/{appName}",
+                &[
+                    ("userId", user_id.to_string()),
+                    ("roleId", role_id.to_string()),
+                    ("appName", app_name.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1472,12 +2121,56 @@ impl Users {
     ) -> Result<()> {
         let url = format!(
             "/api/v1/users/{}/roles/{}/targets/catalog/apps/{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&role_id.to_string()),
-            crate::progenitor_support::encode_path(&app_name.to_string()),
+            self.client.encode_path(user_id),
+            self.client.encode_path(role_id),
+            self.client.encode_path(app_name),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/users/{userId}/roles/{roleId}/targets/catalog/apps/{appName}",
+                &[
+                    ("userId", user_id.to_string()),
+                    ("roleId", role_id.to_string()),
+                    ("appName", app_name.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/api/v1/users/{userId}/roles/{roleId}/targets/catalog/apps/{appName}` endpoint.
+     *
+     * As opposed to `remove_application_target_from_administrator_role_for`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn remove_application_target_from_administrator_role_for_if_exists(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        app_name: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/api/v1/users/{}/roles/{}/targets/catalog/apps/{}",
+            self.client.encode_path(user_id),
+            self.client.encode_path(role_id),
+            self.client.encode_path(app_name),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/users/{userId}/roles/{roleId}/targets/catalog/apps/{appName}",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("roleId", role_id.to_string()),
+                        ("appName", app_name.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1503,13 +2196,13 @@ impl Users {
     ) -> Result<()> {
         let url = format!(
             "/api/v1/users/{}/roles/{}/targets/catalog/apps/{}/{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&role_id.to_string()),
-            crate::progenitor_support::encode_path(&app_name.to_string()),
-            crate::progenitor_support::encode_path(&application_id.to_string()),
+            self.client.encode_path(user_id),
+            self.client.encode_path(role_id),
+            self.client.encode_path(app_name),
+            self.client.encode_path(application_id),
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| crate::utils::pretty_url("PUT", "/api/v1/users/{userId}/roles/{roleId}/targets/catalog/apps/{appName}/{applicationId}", &[("userId", user_id.to_string()), ("roleId", role_id.to_string()), ("appName", app_name.to_string()), ("applicationId", application_id.to_string())]))
     }
 
     /**
@@ -1535,13 +2228,38 @@ impl Users {
     ) -> Result<()> {
         let url = format!(
             "/api/v1/users/{}/roles/{}/targets/catalog/apps/{}/{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&role_id.to_string()),
-            crate::progenitor_support::encode_path(&app_name.to_string()),
-            crate::progenitor_support::encode_path(&application_id.to_string()),
+            self.client.encode_path(user_id),
+            self.client.encode_path(role_id),
+            self.client.encode_path(app_name),
+            self.client.encode_path(application_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/api/v1/users/{userId}/roles/{roleId}/targets/catalog/apps/{appName}/{applicationId}", &[("userId", user_id.to_string()), ("roleId", role_id.to_string()), ("appName", app_name.to_string()), ("applicationId", application_id.to_string())]))
+    }
+
+    /**
+     * Remove App Instance Target to App Administrator Role given to a User.
+     *
+     * This function performs a `DELETE` to the `/api/v1/users/{userId}/roles/{roleId}/targets/catalog/apps/{appName}/{applicationId}` endpoint.
+     *
+     * As opposed to `remove_application_target_from_administrator_role_for_users`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn remove_application_target_from_administrator_role_for_users_if_exists(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        app_name: &str,
+        application_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/api/v1/users/{}/roles/{}/targets/catalog/apps/{}/{}",
+            self.client.encode_path(user_id),
+            self.client.encode_path(role_id),
+            self.client.encode_path(app_name),
+            self.client.encode_path(application_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete_if_exists(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/api/v1/users/{userId}/roles/{roleId}/targets/catalog/apps/{appName}/{applicationId}", &[("userId", user_id.to_string()), ("roleId", role_id.to_string()), ("appName", app_name.to_string()), ("applicationId", application_id.to_string())]))
     }
 
     /**
@@ -1563,22 +2281,32 @@ impl Users {
         after: &str,
         limit: i64,
     ) -> Result<Vec<crate::types::Group>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !after.is_empty() {
-            query_args.push(("after".to_string(), after.to_string()));
+            query_args.push(("after", after.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/roles/{}/targets/groups?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&role_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/api/v1/users/{}/roles/{}/targets/groups",
+                self.client.encode_path(user_id),
+                self.client.encode_path(role_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/v1/users/{userId}/roles/{roleId}/targets/groups",
+                &[
+                    ("userId", user_id.to_string()),
+                    ("roleId", role_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1595,11 +2323,23 @@ impl Users {
     ) -> Result<Vec<crate::types::Group>> {
         let url = format!(
             "/api/v1/users/{}/roles/{}/targets/groups",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&role_id.to_string()),
+            self.client.encode_path(user_id),
+            self.client.encode_path(role_id),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/api/v1/users/{userId}/roles/{roleId}/targets/groups",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("roleId", role_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1621,12 +2361,22 @@ impl Users {
     ) -> Result<()> {
         let url = format!(
             "/api/v1/users/{}/roles/{}/targets/groups/{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&role_id.to_string()),
-            crate::progenitor_support::encode_path(&group_id.to_string()),
+            self.client.encode_path(user_id),
+            self.client.encode_path(role_id),
+            self.client.encode_path(group_id),
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "PUT",
+                "/api/v1/users/{userId}/roles/{roleId}/targets/groups/{groupId}",
+                &[
+                    ("userId", user_id.to_string()),
+                    ("roleId", role_id.to_string()),
+                    ("groupId", group_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1648,36 +2398,935 @@ impl Users {
     ) -> Result<()> {
         let url = format!(
             "/api/v1/users/{}/roles/{}/targets/groups/{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&role_id.to_string()),
-            crate::progenitor_support::encode_path(&group_id.to_string()),
+            self.client.encode_path(user_id),
+            self.client.encode_path(role_id),
+            self.client.encode_path(group_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/users/{userId}/roles/{roleId}/targets/groups/{groupId}",
+                &[
+                    ("userId", user_id.to_string()),
+                    ("roleId", role_id.to_string()),
+                    ("groupId", group_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
-     * This function performs a `DELETE` to the `/api/v1/users/{userId}/sessions` endpoint.
-     *
-     * Removes all active identity provider sessions. This forces the user to authenticate on the next operation. Optionally revokes OpenID Connect and OAuth refresh and access tokens issued to the user.
-     *
-     * **Parameters:**
+     * This function performs a `DELETE` to the `/api/v1/users/{userId}/roles/{roleId}/targets/groups/{groupId}` endpoint.
      *
-     * * `user_id: &str`
-     * * `oauth_tokens: bool` -- Revoke issued OpenID Connect and OAuth refresh and access tokens.
+     * As opposed to `remove_group_target_from_role`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn remove_group_target_from_role_if_exists(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        group_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/api/v1/users/{}/roles/{}/targets/groups/{}",
+            self.client.encode_path(user_id),
+            self.client.encode_path(role_id),
+            self.client.encode_path(group_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/users/{userId}/roles/{roleId}/targets/groups/{groupId}",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("roleId", role_id.to_string()),
+                        ("groupId", group_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/api/v1/users/{userId}/sessions` endpoint.
+     *
+     * Removes all active identity provider sessions. This forces the user to authenticate on the next operation. Optionally revokes OpenID Connect and OAuth refresh and access tokens issued to the user.
+     *
+     * **Parameters:**
+     *
+     * * `user_id: &str`
+     * * `oauth_tokens: bool` -- Revoke issued OpenID Connect and OAuth refresh and access tokens.
      */
     pub async fn clear_sessions(&self, user_id: &str, oauth_tokens: bool) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if oauth_tokens {
-            query_args.push(("oauthTokens".to_string(), oauth_tokens.to_string()));
+            query_args.push(("oauthTokens", oauth_tokens.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/api/v1/users/{}/sessions?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/api/v1/users/{}/sessions",
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api/v1/users/{userId}/sessions",
+                &[("userId", user_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/api/v1/users/{userId}/sessions` endpoint.
+     *
+     * As opposed to `clear_sessions`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn clear_sessions_if_exists(
+        &self,
+        user_id: &str,
+        oauth_tokens: bool,
+    ) -> Result<bool> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if oauth_tokens {
+            query_args.push(("oauthTokens", oauth_tokens.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/api/v1/users/{}/sessions",
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api/v1/users/{userId}/sessions",
+                    &[("userId", user_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`Users`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn UsersApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait UsersApi: Send + Sync {
+    async fn list(
+        &self,
+        q: &str,
+        after: &str,
+        limit: i64,
+        filter: &str,
+        search: &str,
+        sort_by: &str,
+        sort_order: &str,
+    ) -> Result<Vec<crate::types::User>>;
+    async fn list_all(
+        &self,
+        q: &str,
+        filter: &str,
+        search: &str,
+        sort_by: &str,
+        sort_order: &str,
+    ) -> Result<Vec<crate::types::User>>;
+    async fn create(
+        &self,
+        activate: bool,
+        provider: bool,
+        next_login: &str,
+        body: &crate::types::CreateUserRequest,
+    ) -> Result<crate::types::User>;
+    async fn set_linked_object_for(
+        &self,
+        associated_user_id: &str,
+        primary_relationship_name: &str,
+        primary_user_id: &str,
+    ) -> Result<()>;
+    async fn get(&self, user_id: &str) -> Result<crate::types::User>;
+    async fn update(
+        &self,
+        user_id: &str,
+        strict: bool,
+        body: &crate::types::UserRequest,
+    ) -> Result<crate::types::User>;
+    async fn partial_update(
+        &self,
+        user_id: &str,
+        strict: bool,
+        body: &crate::types::UserRequest,
+    ) -> Result<crate::types::User>;
+    async fn deactivate_or_delete(&self, user_id: &str, send_email: bool) -> Result<()>;
+    async fn deactivate_or_delete_if_exists(&self, user_id: &str, send_email: bool)
+        -> Result<bool>;
+    async fn list_app_links(&self, user_id: &str) -> Result<Vec<crate::types::AppLink>>;
+    async fn list_all_app_links(&self, user_id: &str) -> Result<Vec<crate::types::AppLink>>;
+    async fn list_clients(&self, user_id: &str) -> Result<Vec<crate::types::OAuth2Client>>;
+    async fn list_all_clients(&self, user_id: &str) -> Result<Vec<crate::types::OAuth2Client>>;
+    async fn list_grants_for_and_clients(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        expand: &str,
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>>;
+    async fn list_all_grants_for_and_clients(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>>;
+    async fn revoke_grants_for_and_client(&self, user_id: &str, client_id: &str) -> Result<()>;
+    async fn revoke_grants_for_and_client_if_exists(
+        &self,
+        user_id: &str,
+        client_id: &str,
+    ) -> Result<bool>;
+    async fn list_refresh_tokens_for_and_clients(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        expand: &str,
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::OAuth2RefreshToken>>;
+    async fn list_all_refresh_tokens_for_and_clients(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::OAuth2RefreshToken>>;
+    async fn revoke_tokens_for_and_client(&self, user_id: &str, client_id: &str) -> Result<()>;
+    async fn revoke_tokens_for_and_client_if_exists(
+        &self,
+        user_id: &str,
+        client_id: &str,
+    ) -> Result<bool>;
+    async fn get_refresh_token_for_and_client(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        token_id: &str,
+        expand: &str,
+        limit: i64,
+        after: &str,
+    ) -> Result<crate::types::OAuth2RefreshToken>;
+    async fn revoke_token_for_and_client(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        token_id: &str,
+    ) -> Result<()>;
+    async fn revoke_token_for_and_client_if_exists(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        token_id: &str,
+    ) -> Result<bool>;
+    async fn change_password(
+        &self,
+        user_id: &str,
+        strict: bool,
+        body: &crate::types::ChangePasswordRequest,
+    ) -> Result<crate::types::UserCredentials>;
+    async fn change_recovery_question(
+        &self,
+        user_id: &str,
+        body: &crate::types::UserCredentials,
+    ) -> Result<crate::types::UserCredentials>;
+    async fn post_credentials_forgot_password(
+        &self,
+        user_id: &str,
+    ) -> Result<crate::types::ResetPasswordToken>;
+    async fn list_grants(
+        &self,
+        user_id: &str,
+        scope_id: &str,
+        expand: &str,
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>>;
+    async fn list_all_grants(
+        &self,
+        user_id: &str,
+        scope_id: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>>;
+    async fn revoke_grants(&self, user_id: &str) -> Result<()>;
+    async fn revoke_grants_if_exists(&self, user_id: &str) -> Result<bool>;
+    async fn get_grant(
+        &self,
+        user_id: &str,
+        grant_id: &str,
+        expand: &str,
+    ) -> Result<crate::types::OAuth2ScopeConsentGrant>;
+    async fn revoke_grant(&self, user_id: &str, grant_id: &str) -> Result<()>;
+    async fn revoke_grant_if_exists(&self, user_id: &str, grant_id: &str) -> Result<bool>;
+    async fn list_groups(&self, user_id: &str) -> Result<Vec<crate::types::Group>>;
+    async fn list_all_groups(&self, user_id: &str) -> Result<Vec<crate::types::Group>>;
+    async fn list_identity_providers(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<crate::types::IdentityProvider>>;
+    async fn list_all_identity_providers(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<crate::types::IdentityProvider>>;
+    async fn activate(
+        &self,
+        user_id: &str,
+        send_email: bool,
+    ) -> Result<crate::types::UserActivationToken>;
+    async fn deactivate(&self, user_id: &str, send_email: bool) -> Result<()>;
+    async fn expire_password(&self, user_id: &str) -> Result<crate::types::User>;
+    async fn expire_password_and_get_temporary(
+        &self,
+        user_id: &str,
+    ) -> Result<crate::types::TempPassword>;
+    async fn reactivate(
+        &self,
+        user_id: &str,
+        send_email: bool,
+    ) -> Result<crate::types::UserActivationToken>;
+    async fn reset_factors(&self, user_id: &str) -> Result<()>;
+    async fn reset_password(
+        &self,
+        user_id: &str,
+        send_email: bool,
+    ) -> Result<crate::types::ResetPasswordToken>;
+    async fn suspend(&self, user_id: &str) -> Result<()>;
+    async fn unlock(&self, user_id: &str) -> Result<()>;
+    async fn unsuspend(&self, user_id: &str) -> Result<()>;
+    async fn get_linked_objects_fors(
+        &self,
+        user_id: &str,
+        relationship_name: &str,
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::Links>>;
+    async fn get_all_linked_objects_fors(
+        &self,
+        user_id: &str,
+        relationship_name: &str,
+    ) -> Result<Vec<crate::types::Links>>;
+    async fn remove_linked_object_for(&self, user_id: &str, relationship_name: &str) -> Result<()>;
+    async fn remove_linked_object_for_if_exists(
+        &self,
+        user_id: &str,
+        relationship_name: &str,
+    ) -> Result<bool>;
+    async fn list_assigned_roles_fors(
+        &self,
+        user_id: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::Role>>;
+    async fn list_all_assigned_roles_fors(
+        &self,
+        user_id: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::Role>>;
+    async fn assign_role(
+        &self,
+        user_id: &str,
+        disable_notifications: &str,
+        body: &crate::types::AssignRoleRequest,
+    ) -> Result<crate::types::Role>;
+    async fn remove_role_from(&self, user_id: &str, role_id: &str) -> Result<()>;
+    async fn remove_role_from_if_exists(&self, user_id: &str, role_id: &str) -> Result<bool>;
+    async fn list_application_targets_for_administrator_roles(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::CatalogApplication>>;
+    async fn list_all_application_targets_for_administrator_roles(
+        &self,
+        user_id: &str,
+        role_id: &str,
+    ) -> Result<Vec<crate::types::CatalogApplication>>;
+    async fn add_all_apps_as_target_role(&self, user_id: &str, role_id: &str) -> Result<()>;
+    async fn add_application_target_admin_role_for(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        app_name: &str,
+    ) -> Result<()>;
+    async fn remove_application_target_from_administrator_role_for(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        app_name: &str,
+    ) -> Result<()>;
+    async fn remove_application_target_from_administrator_role_for_if_exists(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        app_name: &str,
+    ) -> Result<bool>;
+    async fn add_application_target_app_admin_role_for(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        app_name: &str,
+        application_id: &str,
+    ) -> Result<()>;
+    async fn remove_application_target_from_administrator_role_for_users(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        app_name: &str,
+        application_id: &str,
+    ) -> Result<()>;
+    async fn remove_application_target_from_administrator_role_for_users_if_exists(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        app_name: &str,
+        application_id: &str,
+    ) -> Result<bool>;
+    async fn list_group_targets_for_roles(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::Group>>;
+    async fn list_all_group_targets_for_roles(
+        &self,
+        user_id: &str,
+        role_id: &str,
+    ) -> Result<Vec<crate::types::Group>>;
+    async fn add_group_target_role(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        group_id: &str,
+    ) -> Result<()>;
+    async fn remove_group_target_from_role(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        group_id: &str,
+    ) -> Result<()>;
+    async fn remove_group_target_from_role_if_exists(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        group_id: &str,
+    ) -> Result<bool>;
+    async fn clear_sessions(&self, user_id: &str, oauth_tokens: bool) -> Result<()>;
+    async fn clear_sessions_if_exists(&self, user_id: &str, oauth_tokens: bool) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl UsersApi for Users {
+    async fn list(
+        &self,
+        q: &str,
+        after: &str,
+        limit: i64,
+        filter: &str,
+        search: &str,
+        sort_by: &str,
+        sort_order: &str,
+    ) -> Result<Vec<crate::types::User>> {
+        Users::list(self, q, after, limit, filter, search, sort_by, sort_order).await
+    }
+    async fn list_all(
+        &self,
+        q: &str,
+        filter: &str,
+        search: &str,
+        sort_by: &str,
+        sort_order: &str,
+    ) -> Result<Vec<crate::types::User>> {
+        Users::list_all(self, q, filter, search, sort_by, sort_order).await
+    }
+    async fn create(
+        &self,
+        activate: bool,
+        provider: bool,
+        next_login: &str,
+        body: &crate::types::CreateUserRequest,
+    ) -> Result<crate::types::User> {
+        Users::create(self, activate, provider, next_login, body).await
+    }
+    async fn set_linked_object_for(
+        &self,
+        associated_user_id: &str,
+        primary_relationship_name: &str,
+        primary_user_id: &str,
+    ) -> Result<()> {
+        Users::set_linked_object_for(
+            self,
+            associated_user_id,
+            primary_relationship_name,
+            primary_user_id,
+        )
+        .await
+    }
+    async fn get(&self, user_id: &str) -> Result<crate::types::User> {
+        Users::get(self, user_id).await
+    }
+    async fn update(
+        &self,
+        user_id: &str,
+        strict: bool,
+        body: &crate::types::UserRequest,
+    ) -> Result<crate::types::User> {
+        Users::update(self, user_id, strict, body).await
+    }
+    async fn partial_update(
+        &self,
+        user_id: &str,
+        strict: bool,
+        body: &crate::types::UserRequest,
+    ) -> Result<crate::types::User> {
+        Users::partial_update(self, user_id, strict, body).await
+    }
+    async fn deactivate_or_delete(&self, user_id: &str, send_email: bool) -> Result<()> {
+        Users::deactivate_or_delete(self, user_id, send_email).await
+    }
+    async fn deactivate_or_delete_if_exists(
+        &self,
+        user_id: &str,
+        send_email: bool,
+    ) -> Result<bool> {
+        Users::deactivate_or_delete_if_exists(self, user_id, send_email).await
+    }
+    async fn list_app_links(&self, user_id: &str) -> Result<Vec<crate::types::AppLink>> {
+        Users::list_app_links(self, user_id).await
+    }
+    async fn list_all_app_links(&self, user_id: &str) -> Result<Vec<crate::types::AppLink>> {
+        Users::list_all_app_links(self, user_id).await
+    }
+    async fn list_clients(&self, user_id: &str) -> Result<Vec<crate::types::OAuth2Client>> {
+        Users::list_clients(self, user_id).await
+    }
+    async fn list_all_clients(&self, user_id: &str) -> Result<Vec<crate::types::OAuth2Client>> {
+        Users::list_all_clients(self, user_id).await
+    }
+    async fn list_grants_for_and_clients(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        expand: &str,
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>> {
+        Users::list_grants_for_and_clients(self, user_id, client_id, expand, after, limit).await
+    }
+    async fn list_all_grants_for_and_clients(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>> {
+        Users::list_all_grants_for_and_clients(self, user_id, client_id, expand).await
+    }
+    async fn revoke_grants_for_and_client(&self, user_id: &str, client_id: &str) -> Result<()> {
+        Users::revoke_grants_for_and_client(self, user_id, client_id).await
+    }
+    async fn revoke_grants_for_and_client_if_exists(
+        &self,
+        user_id: &str,
+        client_id: &str,
+    ) -> Result<bool> {
+        Users::revoke_grants_for_and_client_if_exists(self, user_id, client_id).await
+    }
+    async fn list_refresh_tokens_for_and_clients(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        expand: &str,
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::OAuth2RefreshToken>> {
+        Users::list_refresh_tokens_for_and_clients(self, user_id, client_id, expand, after, limit)
+            .await
+    }
+    async fn list_all_refresh_tokens_for_and_clients(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::OAuth2RefreshToken>> {
+        Users::list_all_refresh_tokens_for_and_clients(self, user_id, client_id, expand).await
+    }
+    async fn revoke_tokens_for_and_client(&self, user_id: &str, client_id: &str) -> Result<()> {
+        Users::revoke_tokens_for_and_client(self, user_id, client_id).await
+    }
+    async fn revoke_tokens_for_and_client_if_exists(
+        &self,
+        user_id: &str,
+        client_id: &str,
+    ) -> Result<bool> {
+        Users::revoke_tokens_for_and_client_if_exists(self, user_id, client_id).await
+    }
+    async fn get_refresh_token_for_and_client(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        token_id: &str,
+        expand: &str,
+        limit: i64,
+        after: &str,
+    ) -> Result<crate::types::OAuth2RefreshToken> {
+        Users::get_refresh_token_for_and_client(
+            self, user_id, client_id, token_id, expand, limit, after,
+        )
+        .await
+    }
+    async fn revoke_token_for_and_client(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        token_id: &str,
+    ) -> Result<()> {
+        Users::revoke_token_for_and_client(self, user_id, client_id, token_id).await
+    }
+    async fn revoke_token_for_and_client_if_exists(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        token_id: &str,
+    ) -> Result<bool> {
+        Users::revoke_token_for_and_client_if_exists(self, user_id, client_id, token_id).await
+    }
+    async fn change_password(
+        &self,
+        user_id: &str,
+        strict: bool,
+        body: &crate::types::ChangePasswordRequest,
+    ) -> Result<crate::types::UserCredentials> {
+        Users::change_password(self, user_id, strict, body).await
+    }
+    async fn change_recovery_question(
+        &self,
+        user_id: &str,
+        body: &crate::types::UserCredentials,
+    ) -> Result<crate::types::UserCredentials> {
+        Users::change_recovery_question(self, user_id, body).await
+    }
+    async fn post_credentials_forgot_password(
+        &self,
+        user_id: &str,
+    ) -> Result<crate::types::ResetPasswordToken> {
+        Users::post_credentials_forgot_password(self, user_id).await
+    }
+    async fn list_grants(
+        &self,
+        user_id: &str,
+        scope_id: &str,
+        expand: &str,
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>> {
+        Users::list_grants(self, user_id, scope_id, expand, after, limit).await
+    }
+    async fn list_all_grants(
+        &self,
+        user_id: &str,
+        scope_id: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>> {
+        Users::list_all_grants(self, user_id, scope_id, expand).await
+    }
+    async fn revoke_grants(&self, user_id: &str) -> Result<()> {
+        Users::revoke_grants(self, user_id).await
+    }
+    async fn revoke_grants_if_exists(&self, user_id: &str) -> Result<bool> {
+        Users::revoke_grants_if_exists(self, user_id).await
+    }
+    async fn get_grant(
+        &self,
+        user_id: &str,
+        grant_id: &str,
+        expand: &str,
+    ) -> Result<crate::types::OAuth2ScopeConsentGrant> {
+        Users::get_grant(self, user_id, grant_id, expand).await
+    }
+    async fn revoke_grant(&self, user_id: &str, grant_id: &str) -> Result<()> {
+        Users::revoke_grant(self, user_id, grant_id).await
+    }
+    async fn revoke_grant_if_exists(&self, user_id: &str, grant_id: &str) -> Result<bool> {
+        Users::revoke_grant_if_exists(self, user_id, grant_id).await
+    }
+    async fn list_groups(&self, user_id: &str) -> Result<Vec<crate::types::Group>> {
+        Users::list_groups(self, user_id).await
+    }
+    async fn list_all_groups(&self, user_id: &str) -> Result<Vec<crate::types::Group>> {
+        Users::list_all_groups(self, user_id).await
+    }
+    async fn list_identity_providers(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<crate::types::IdentityProvider>> {
+        Users::list_identity_providers(self, user_id).await
+    }
+    async fn list_all_identity_providers(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<crate::types::IdentityProvider>> {
+        Users::list_all_identity_providers(self, user_id).await
+    }
+    async fn activate(
+        &self,
+        user_id: &str,
+        send_email: bool,
+    ) -> Result<crate::types::UserActivationToken> {
+        Users::activate(self, user_id, send_email).await
+    }
+    async fn deactivate(&self, user_id: &str, send_email: bool) -> Result<()> {
+        Users::deactivate(self, user_id, send_email).await
+    }
+    async fn expire_password(&self, user_id: &str) -> Result<crate::types::User> {
+        Users::expire_password(self, user_id).await
+    }
+    async fn expire_password_and_get_temporary(
+        &self,
+        user_id: &str,
+    ) -> Result<crate::types::TempPassword> {
+        Users::expire_password_and_get_temporary(self, user_id).await
+    }
+    async fn reactivate(
+        &self,
+        user_id: &str,
+        send_email: bool,
+    ) -> Result<crate::types::UserActivationToken> {
+        Users::reactivate(self, user_id, send_email).await
+    }
+    async fn reset_factors(&self, user_id: &str) -> Result<()> {
+        Users::reset_factors(self, user_id).await
+    }
+    async fn reset_password(
+        &self,
+        user_id: &str,
+        send_email: bool,
+    ) -> Result<crate::types::ResetPasswordToken> {
+        Users::reset_password(self, user_id, send_email).await
+    }
+    async fn suspend(&self, user_id: &str) -> Result<()> {
+        Users::suspend(self, user_id).await
+    }
+    async fn unlock(&self, user_id: &str) -> Result<()> {
+        Users::unlock(self, user_id).await
+    }
+    async fn unsuspend(&self, user_id: &str) -> Result<()> {
+        Users::unsuspend(self, user_id).await
+    }
+    async fn get_linked_objects_fors(
+        &self,
+        user_id: &str,
+        relationship_name: &str,
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::Links>> {
+        Users::get_linked_objects_fors(self, user_id, relationship_name, after, limit).await
+    }
+    async fn get_all_linked_objects_fors(
+        &self,
+        user_id: &str,
+        relationship_name: &str,
+    ) -> Result<Vec<crate::types::Links>> {
+        Users::get_all_linked_objects_fors(self, user_id, relationship_name).await
+    }
+    async fn remove_linked_object_for(&self, user_id: &str, relationship_name: &str) -> Result<()> {
+        Users::remove_linked_object_for(self, user_id, relationship_name).await
+    }
+    async fn remove_linked_object_for_if_exists(
+        &self,
+        user_id: &str,
+        relationship_name: &str,
+    ) -> Result<bool> {
+        Users::remove_linked_object_for_if_exists(self, user_id, relationship_name).await
+    }
+    async fn list_assigned_roles_fors(
+        &self,
+        user_id: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::Role>> {
+        Users::list_assigned_roles_fors(self, user_id, expand).await
+    }
+    async fn list_all_assigned_roles_fors(
+        &self,
+        user_id: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::Role>> {
+        Users::list_all_assigned_roles_fors(self, user_id, expand).await
+    }
+    async fn assign_role(
+        &self,
+        user_id: &str,
+        disable_notifications: &str,
+        body: &crate::types::AssignRoleRequest,
+    ) -> Result<crate::types::Role> {
+        Users::assign_role(self, user_id, disable_notifications, body).await
+    }
+    async fn remove_role_from(&self, user_id: &str, role_id: &str) -> Result<()> {
+        Users::remove_role_from(self, user_id, role_id).await
+    }
+    async fn remove_role_from_if_exists(&self, user_id: &str, role_id: &str) -> Result<bool> {
+        Users::remove_role_from_if_exists(self, user_id, role_id).await
+    }
+    async fn list_application_targets_for_administrator_roles(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::CatalogApplication>> {
+        Users::list_application_targets_for_administrator_roles(
+            self, user_id, role_id, after, limit,
+        )
+        .await
+    }
+    async fn list_all_application_targets_for_administrator_roles(
+        &self,
+        user_id: &str,
+        role_id: &str,
+    ) -> Result<Vec<crate::types::CatalogApplication>> {
+        Users::list_all_application_targets_for_administrator_roles(self, user_id, role_id).await
+    }
+    async fn add_all_apps_as_target_role(&self, user_id: &str, role_id: &str) -> Result<()> {
+        Users::add_all_apps_as_target_role(self, user_id, role_id).await
+    }
+    async fn add_application_target_admin_role_for(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        app_name: &str,
+    ) -> Result<()> {
+        Users::add_application_target_admin_role_for(self, user_id, role_id, app_name).await
+    }
+    async fn remove_application_target_from_administrator_role_for(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        app_name: &str,
+    ) -> Result<()> {
+        Users::remove_application_target_from_administrator_role_for(
+            self, user_id, role_id, app_name,
+        )
+        .await
+    }
+    async fn remove_application_target_from_administrator_role_for_if_exists(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        app_name: &str,
+    ) -> Result<bool> {
+        Users::remove_application_target_from_administrator_role_for_if_exists(
+            self, user_id, role_id, app_name,
+        )
+        .await
+    }
+    async fn add_application_target_app_admin_role_for(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        app_name: &str,
+        application_id: &str,
+    ) -> Result<()> {
+        Users::add_application_target_app_admin_role_for(
+            self,
+            user_id,
+            role_id,
+            app_name,
+            application_id,
+        )
+        .await
+    }
+    async fn remove_application_target_from_administrator_role_for_users(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        app_name: &str,
+        application_id: &str,
+    ) -> Result<()> {
+        Users::remove_application_target_from_administrator_role_for_users(
+            self,
+            user_id,
+            role_id,
+            app_name,
+            application_id,
+        )
+        .await
+    }
+    async fn remove_application_target_from_administrator_role_for_users_if_exists(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        app_name: &str,
+        application_id: &str,
+    ) -> Result<bool> {
+        Users::remove_application_target_from_administrator_role_for_users_if_exists(
+            self,
+            user_id,
+            role_id,
+            app_name,
+            application_id,
+        )
+        .await
+    }
+    async fn list_group_targets_for_roles(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::Group>> {
+        Users::list_group_targets_for_roles(self, user_id, role_id, after, limit).await
+    }
+    async fn list_all_group_targets_for_roles(
+        &self,
+        user_id: &str,
+        role_id: &str,
+    ) -> Result<Vec<crate::types::Group>> {
+        Users::list_all_group_targets_for_roles(self, user_id, role_id).await
+    }
+    async fn add_group_target_role(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        group_id: &str,
+    ) -> Result<()> {
+        Users::add_group_target_role(self, user_id, role_id, group_id).await
+    }
+    async fn remove_group_target_from_role(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        group_id: &str,
+    ) -> Result<()> {
+        Users::remove_group_target_from_role(self, user_id, role_id, group_id).await
+    }
+    async fn remove_group_target_from_role_if_exists(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        group_id: &str,
+    ) -> Result<bool> {
+        Users::remove_group_target_from_role_if_exists(self, user_id, role_id, group_id).await
+    }
+    async fn clear_sessions(&self, user_id: &str, oauth_tokens: bool) -> Result<()> {
+        Users::clear_sessions(self, user_id, oauth_tokens).await
+    }
+    async fn clear_sessions_if_exists(&self, user_id: &str, oauth_tokens: bool) -> Result<bool> {
+        Users::clear_sessions_if_exists(self, user_id, oauth_tokens).await
     }
 }
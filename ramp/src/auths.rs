@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Auths {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Auths {
         Auths { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Auths {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get OAuth2 token.
      *
@@ -54,6 +60,26 @@ impl Auths {
      */
     pub async fn post_token(&self) -> Result<crate::types::OAuth2Token> {
         let url = "/token".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/token", &[]))
+    }
+}
+
+/// The functions of [`Auths`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AuthsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AuthsApi: Send + Sync {
+    async fn post_token(&self) -> Result<crate::types::OAuth2Token>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AuthsApi for Auths {
+    async fn post_token(&self) -> Result<crate::types::OAuth2Token> {
+        Auths::post_token(self).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Businesses {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Businesses {
         Businesses { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Businesses {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * GET business metadata.
      *
@@ -25,7 +31,10 @@ impl Businesses {
      */
     pub async fn get_resources_busine(&self) -> Result<crate::types::Business> {
         let url = "/business".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/business", &[]))
     }
 
     /**
@@ -43,6 +52,30 @@ impl Businesses {
         &self,
     ) -> Result<crate::types::BusinessCurrentStatus> {
         let url = "/business/balance".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/business/balance", &[]))
+    }
+}
+
+/// The functions of [`Businesses`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn BusinessesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait BusinessesApi: Send + Sync {
+    async fn get_resources_busine(&self) -> Result<crate::types::Business>;
+    async fn get_resources_business_current(&self) -> Result<crate::types::BusinessCurrentStatus>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl BusinessesApi for Businesses {
+    async fn get_resources_busine(&self) -> Result<crate::types::Business> {
+        Businesses::get_resources_busine(self).await
+    }
+    async fn get_resources_business_current(&self) -> Result<crate::types::BusinessCurrentStatus> {
+        Businesses::get_resources_business_current(self).await
     }
 }
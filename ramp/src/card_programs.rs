@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct CardPrograms {
     pub client: Client,
@@ -12,6 +10,14 @@ impl CardPrograms {
         CardPrograms { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        CardPrograms {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * List card programs.
      *
@@ -30,17 +36,20 @@ impl CardPrograms {
         start: &str,
         page_size: f64,
     ) -> Result<Vec<crate::types::CardProgram>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !page_size.to_string().is_empty() {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !start.is_empty() {
-            query_args.push(("start".to_string(), start.to_string()));
+            query_args.push(("start", start.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/card-programs?{}", query_);
+        let url = crate::Client::url_with_query("/card-programs", &query_args);
 
-        let resp: crate::types::GetCardProgramsResponse = self.client.get(&url, None).await?;
+        let resp: crate::types::GetCardProgramsResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/card-programs", &[]))?;
 
         // Return our response data.
         Ok(resp.card_programs)
@@ -57,7 +66,11 @@ impl CardPrograms {
      */
     pub async fn get_all(&self) -> Result<Vec<crate::types::CardProgram>> {
         let url = "/card-programs".to_string();
-        let resp: crate::types::GetCardProgramsResponse = self.client.get(&url, None).await?;
+        let resp: crate::types::GetCardProgramsResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/card-programs", &[]))?;
 
         let mut card_programs = resp.card_programs;
         let mut page = resp.page.next.to_string();
@@ -85,7 +98,7 @@ impl CardPrograms {
                     if e.to_string().contains("404 Not Found") {
                         page = "".to_string();
                     } else {
-                        anyhow::bail!(e);
+                        return Err(e.into());
                     }
                 }
             }
@@ -112,8 +125,9 @@ impl CardPrograms {
     ) -> Result<crate::types::CardProgram> {
         let url = "/card-programs".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/card-programs", &[]))
     }
 
     /**
@@ -128,11 +142,50 @@ impl CardPrograms {
      * * `authorization: &str` -- The OAuth2 token header.
      */
     pub async fn get_program(&self, id: &str) -> Result<crate::types::CardProgram> {
-        let url = format!(
-            "/card-programs/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/card-programs/{}", self.client.encode_path(id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/card-programs/{id}", &[("id", id.to_string())])
+        })
+    }
+}
+
+/// The functions of [`CardPrograms`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn CardProgramsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait CardProgramsApi: Send + Sync {
+    async fn get_page(&self, start: &str, page_size: f64)
+        -> Result<Vec<crate::types::CardProgram>>;
+    async fn get_all(&self) -> Result<Vec<crate::types::CardProgram>>;
+    async fn post_resources(
+        &self,
+        body: &crate::types::PostResourcesCardProgramRequest,
+    ) -> Result<crate::types::CardProgram>;
+    async fn get_program(&self, id: &str) -> Result<crate::types::CardProgram>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl CardProgramsApi for CardPrograms {
+    async fn get_page(
+        &self,
+        start: &str,
+        page_size: f64,
+    ) -> Result<Vec<crate::types::CardProgram>> {
+        CardPrograms::get_page(self, start, page_size).await
+    }
+    async fn get_all(&self) -> Result<Vec<crate::types::CardProgram>> {
+        CardPrograms::get_all(self).await
+    }
+    async fn post_resources(
+        &self,
+        body: &crate::types::PostResourcesCardProgramRequest,
+    ) -> Result<crate::types::CardProgram> {
+        CardPrograms::post_resources(self, body).await
+    }
+    async fn get_program(&self, id: &str) -> Result<crate::types::CardProgram> {
+        CardPrograms::get_program(self, id).await
     }
 }
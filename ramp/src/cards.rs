@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Cards {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Cards {
         Cards { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Cards {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * List cards.
      *
@@ -34,23 +40,26 @@ impl Cards {
         user_id: &str,
         card_program_id: &str,
     ) -> Result<Vec<crate::types::Card>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !card_program_id.is_empty() {
-            query_args.push(("card_program_id".to_string(), card_program_id.to_string()));
+            query_args.push(("card_program_id", card_program_id.to_string()));
         }
         if !page_size.to_string().is_empty() {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !start.is_empty() {
-            query_args.push(("start".to_string(), start.to_string()));
+            query_args.push(("start", start.to_string()));
         }
         if !user_id.is_empty() {
-            query_args.push(("user_id".to_string(), user_id.to_string()));
+            query_args.push(("user_id", user_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/cards?{}", query_);
+        let url = crate::Client::url_with_query("/cards", &query_args);
 
-        let resp: crate::types::GetCardsResponse = self.client.get(&url, None).await?;
+        let resp: crate::types::GetCardsResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/cards", &[]))?;
 
         // Return our response data.
         Ok(resp.cards)
@@ -70,17 +79,20 @@ impl Cards {
         user_id: &str,
         card_program_id: &str,
     ) -> Result<Vec<crate::types::Card>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !card_program_id.is_empty() {
-            query_args.push(("card_program_id".to_string(), card_program_id.to_string()));
+            query_args.push(("card_program_id", card_program_id.to_string()));
         }
         if !user_id.is_empty() {
-            query_args.push(("user_id".to_string(), user_id.to_string()));
+            query_args.push(("user_id", user_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/cards?{}", query_);
+        let url = crate::Client::url_with_query("/cards", &query_args);
 
-        let resp: crate::types::GetCardsResponse = self.client.get(&url, None).await?;
+        let resp: crate::types::GetCardsResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/cards", &[]))?;
 
         let mut cards = resp.cards;
         let mut page = resp.page.next.to_string();
@@ -108,7 +120,7 @@ impl Cards {
                     if e.to_string().contains("404 Not Found") {
                         page = "".to_string();
                     } else {
-                        anyhow::bail!(e);
+                        return Err(e.into());
                     }
                 }
             }
@@ -130,12 +142,11 @@ impl Cards {
      * * `authorization: &str` -- The OAuth2 token header.
      */
     pub async fn get(&self, id: &str) -> Result<crate::types::Card> {
-        let url = format!(
-            "/cards/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/cards/{}", self.client.encode_path(id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/cards/{id}", &[("id", id.to_string())])
+        })
     }
 
     /**
@@ -154,14 +165,37 @@ impl Cards {
         id: &str,
         body: &crate::types::PatchResourcesCardsCardRequest,
     ) -> Result<()> {
-        let url = format!(
-            "/cards/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/cards/{}", self.client.encode_path(id),);
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("PATCH", "/cards/{id}", &[("id", id.to_string())])
+            })
+    }
+
+    /**
+     * Update card.
+     *
+     * This function performs a `PATCH` to the `/cards/{id}` endpoint.
+     *
+     * As opposed to `patch_resources`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_resources_if_changed<C: serde::Serialize>(
+        &self,
+        id: &str,
+        current: &C,
+        body: &crate::types::PatchResourcesCardsCardRequest,
+    ) -> Result<Option<()>> {
+        let url = format!("/cards/{}", self.client.encode_path(id),);
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url("PATCH", "/cards/{id}", &[("id", id.to_string())])
+            })
     }
 
     /**
@@ -181,8 +215,9 @@ impl Cards {
     ) -> Result<crate::types::TaskResponse> {
         let url = "/cards/deferred/physical".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/cards/deferred/physical", &[]))
     }
 
     /**
@@ -202,8 +237,9 @@ impl Cards {
     ) -> Result<crate::types::TaskResponse> {
         let url = "/cards/deferred/virtual".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/cards/deferred/virtual", &[]))
     }
 
     /**
@@ -220,12 +256,19 @@ impl Cards {
     ) -> Result<crate::types::TaskResponse> {
         let url = format!(
             "/cards/{}/deferred/termination",
-            crate::progenitor_support::encode_path(&id.to_string()),
+            self.client.encode_path(id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/cards/{id}/deferred/termination",
+                    &[("id", id.to_string())],
+                )
+            })
     }
 
     /**
@@ -240,14 +283,18 @@ impl Cards {
         id: &str,
         body: &crate::types::PostResourcesCardsCardSuspensionRequest,
     ) -> Result<crate::types::TaskResponse> {
-        let url = format!(
-            "/cards/{}/deferred/suspension",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/cards/{}/deferred/suspension", self.client.encode_path(id),);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/cards/{id}/deferred/suspension",
+                    &[("id", id.to_string())],
+                )
+            })
     }
 
     /**
@@ -264,12 +311,19 @@ impl Cards {
     ) -> Result<crate::types::TaskResponse> {
         let url = format!(
             "/cards/{}/deferred/unsuspension",
-            crate::progenitor_support::encode_path(&id.to_string()),
+            self.client.encode_path(id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/cards/{id}/deferred/unsuspension",
+                    &[("id", id.to_string())],
+                )
+            })
     }
 
     /**
@@ -287,11 +341,137 @@ impl Cards {
         &self,
         id: &str,
     ) -> Result<crate::types::GetResourcesCardsDeferredResponse> {
-        let url = format!(
-            "/cards/deferred/status/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/cards/deferred/status/{}", self.client.encode_path(id),);
+
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/cards/deferred/status/{id}",
+                &[("id", id.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`Cards`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn CardsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait CardsApi: Send + Sync {
+    async fn get_page(
+        &self,
+        start: &str,
+        page_size: f64,
+        user_id: &str,
+        card_program_id: &str,
+    ) -> Result<Vec<crate::types::Card>>;
+    async fn get_all(
+        &self,
+        user_id: &str,
+        card_program_id: &str,
+    ) -> Result<Vec<crate::types::Card>>;
+    async fn get(&self, id: &str) -> Result<crate::types::Card>;
+    async fn patch_resources(
+        &self,
+        id: &str,
+        body: &crate::types::PatchResourcesCardsCardRequest,
+    ) -> Result<()>;
+    async fn post_resources_physical(
+        &self,
+        body: &crate::types::PostResourcesCardPhysicalRequest,
+    ) -> Result<crate::types::TaskResponse>;
+    async fn post_resources_virtual(
+        &self,
+        body: &crate::types::PostResourcesCardVirtualRequest,
+    ) -> Result<crate::types::TaskResponse>;
+    async fn post_resources_termination(
+        &self,
+        id: &str,
+        body: &crate::types::PostResourcesCardsCardSuspensionRequest,
+    ) -> Result<crate::types::TaskResponse>;
+    async fn post_resources_suspension(
+        &self,
+        id: &str,
+        body: &crate::types::PostResourcesCardsCardSuspensionRequest,
+    ) -> Result<crate::types::TaskResponse>;
+    async fn post_resources_unsuspension(
+        &self,
+        id: &str,
+        body: &crate::types::PostResourcesCardsCardSuspensionRequest,
+    ) -> Result<crate::types::TaskResponse>;
+    async fn get_resources_deferred(
+        &self,
+        id: &str,
+    ) -> Result<crate::types::GetResourcesCardsDeferredResponse>;
+}
 
-        self.client.get(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl CardsApi for Cards {
+    async fn get_page(
+        &self,
+        start: &str,
+        page_size: f64,
+        user_id: &str,
+        card_program_id: &str,
+    ) -> Result<Vec<crate::types::Card>> {
+        Cards::get_page(self, start, page_size, user_id, card_program_id).await
+    }
+    async fn get_all(
+        &self,
+        user_id: &str,
+        card_program_id: &str,
+    ) -> Result<Vec<crate::types::Card>> {
+        Cards::get_all(self, user_id, card_program_id).await
+    }
+    async fn get(&self, id: &str) -> Result<crate::types::Card> {
+        Cards::get(self, id).await
+    }
+    async fn patch_resources(
+        &self,
+        id: &str,
+        body: &crate::types::PatchResourcesCardsCardRequest,
+    ) -> Result<()> {
+        Cards::patch_resources(self, id, body).await
+    }
+    async fn post_resources_physical(
+        &self,
+        body: &crate::types::PostResourcesCardPhysicalRequest,
+    ) -> Result<crate::types::TaskResponse> {
+        Cards::post_resources_physical(self, body).await
+    }
+    async fn post_resources_virtual(
+        &self,
+        body: &crate::types::PostResourcesCardVirtualRequest,
+    ) -> Result<crate::types::TaskResponse> {
+        Cards::post_resources_virtual(self, body).await
+    }
+    async fn post_resources_termination(
+        &self,
+        id: &str,
+        body: &crate::types::PostResourcesCardsCardSuspensionRequest,
+    ) -> Result<crate::types::TaskResponse> {
+        Cards::post_resources_termination(self, id, body).await
+    }
+    async fn post_resources_suspension(
+        &self,
+        id: &str,
+        body: &crate::types::PostResourcesCardsCardSuspensionRequest,
+    ) -> Result<crate::types::TaskResponse> {
+        Cards::post_resources_suspension(self, id, body).await
+    }
+    async fn post_resources_unsuspension(
+        &self,
+        id: &str,
+        body: &crate::types::PostResourcesCardsCardSuspensionRequest,
+    ) -> Result<crate::types::TaskResponse> {
+        Cards::post_resources_unsuspension(self, id, body).await
+    }
+    async fn get_resources_deferred(
+        &self,
+        id: &str,
+    ) -> Result<crate::types::GetResourcesCardsDeferredResponse> {
+        Cards::get_resources_deferred(self, id).await
     }
 }
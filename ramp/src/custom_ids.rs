@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct CustomIds {
     pub client: Client,
@@ -12,6 +10,14 @@ impl CustomIds {
         CustomIds { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        CustomIds {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * GET the Custom ID provider linked to the current OAuth token.
      *
@@ -23,7 +29,10 @@ impl CustomIds {
      */
     pub async fn get_custom_provider(&self) -> Result<crate::types::GetCustomProviderResponse> {
         let url = "/custom-id-provider".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/custom-id-provider", &[]))
     }
 
     /**
@@ -39,7 +48,10 @@ impl CustomIds {
      */
     pub async fn postcustom_provider(&self) -> Result<crate::types::PostcustomProviderResponse> {
         let url = "/custom-id-provider".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/custom-id-provider", &[]))
     }
 
     /**
@@ -55,8 +67,11 @@ impl CustomIds {
     ) -> Result<()> {
         let url = "/custom-id-provider/application-link".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/custom-id-provider/application-link", &[])
+            })
     }
 
     /**
@@ -77,11 +92,20 @@ impl CustomIds {
     ) -> Result<crate::types::GetEntityTypeCustomRampResponse> {
         let url = format!(
             "/custom-id-provider/{}/{}/ramp-id",
-            crate::progenitor_support::encode_path(&entity_type.to_string()),
-            crate::progenitor_support::encode_path(&custom_id.to_string()),
+            self.client.encode_path(entity_type),
+            self.client.encode_path(custom_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/custom-id-provider/{entity_type}/{custom_id}/ramp-id",
+                &[
+                    ("entity_type", entity_type.to_string()),
+                    ("custom_id", custom_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -100,11 +124,20 @@ impl CustomIds {
     ) -> Result<crate::types::GetEntityTypeRampCustomResponse> {
         let url = format!(
             "/custom-id-provider/{}/{}/custom-id",
-            crate::progenitor_support::encode_path(&entity_type.to_string()),
-            crate::progenitor_support::encode_path(&ramp_id.to_string()),
+            self.client.encode_path(entity_type),
+            self.client.encode_path(ramp_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/custom-id-provider/{entity_type}/{ramp_id}/custom-id",
+                &[
+                    ("entity_type", entity_type.to_string()),
+                    ("ramp_id", ramp_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -121,11 +154,85 @@ impl CustomIds {
     ) -> Result<()> {
         let url = format!(
             "/custom-id-provider/{}/custom-id-link",
-            crate::progenitor_support::encode_path(&entity_type.to_string()),
+            self.client.encode_path(entity_type),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/custom-id-provider/{entity_type}/custom-id-link",
+                    &[("entity_type", entity_type.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`CustomIds`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn CustomIdsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait CustomIdsApi: Send + Sync {
+    async fn get_custom_provider(&self) -> Result<crate::types::GetCustomProviderResponse>;
+    async fn postcustom_provider(&self) -> Result<crate::types::PostcustomProviderResponse>;
+    async fn post_custom_provider_application_link(
+        &self,
+        body: &crate::types::GetCustomProviderResponse,
+    ) -> Result<()>;
+    async fn get_entity_type_custom_ramp(
+        &self,
+        entity_type: &str,
+        custom_id: &str,
+    ) -> Result<crate::types::GetEntityTypeCustomRampResponse>;
+    async fn get_entity_type_ramp_custom(
+        &self,
+        entity_type: &str,
+        ramp_id: &str,
+    ) -> Result<crate::types::GetEntityTypeRampCustomResponse>;
+    async fn post_custom_provider_entity_type_link(
+        &self,
+        entity_type: &str,
+        body: &crate::types::PostCustomProviderEntityTypeLinkRequest,
+    ) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl CustomIdsApi for CustomIds {
+    async fn get_custom_provider(&self) -> Result<crate::types::GetCustomProviderResponse> {
+        CustomIds::get_custom_provider(self).await
+    }
+    async fn postcustom_provider(&self) -> Result<crate::types::PostcustomProviderResponse> {
+        CustomIds::postcustom_provider(self).await
+    }
+    async fn post_custom_provider_application_link(
+        &self,
+        body: &crate::types::GetCustomProviderResponse,
+    ) -> Result<()> {
+        CustomIds::post_custom_provider_application_link(self, body).await
+    }
+    async fn get_entity_type_custom_ramp(
+        &self,
+        entity_type: &str,
+        custom_id: &str,
+    ) -> Result<crate::types::GetEntityTypeCustomRampResponse> {
+        CustomIds::get_entity_type_custom_ramp(self, entity_type, custom_id).await
+    }
+    async fn get_entity_type_ramp_custom(
+        &self,
+        entity_type: &str,
+        ramp_id: &str,
+    ) -> Result<crate::types::GetEntityTypeRampCustomResponse> {
+        CustomIds::get_entity_type_ramp_custom(self, entity_type, ramp_id).await
+    }
+    async fn post_custom_provider_entity_type_link(
+        &self,
+        entity_type: &str,
+        body: &crate::types::PostCustomProviderEntityTypeLinkRequest,
+    ) -> Result<()> {
+        CustomIds::post_custom_provider_entity_type_link(self, entity_type, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Departments {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Departments {
         Departments { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Departments {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * List departments.
      *
@@ -30,17 +36,20 @@ impl Departments {
         start: &str,
         page_size: f64,
     ) -> Result<Vec<crate::types::Department>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !page_size.to_string().is_empty() {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !start.is_empty() {
-            query_args.push(("start".to_string(), start.to_string()));
+            query_args.push(("start", start.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/departments?{}", query_);
+        let url = crate::Client::url_with_query("/departments", &query_args);
 
-        let resp: crate::types::GetDepartmentsResponse = self.client.get(&url, None).await?;
+        let resp: crate::types::GetDepartmentsResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/departments", &[]))?;
 
         // Return our response data.
         Ok(resp.data)
@@ -57,7 +66,11 @@ impl Departments {
      */
     pub async fn get_all(&self) -> Result<Vec<crate::types::Department>> {
         let url = "/departments".to_string();
-        let resp: crate::types::GetDepartmentsResponse = self.client.get(&url, None).await?;
+        let resp: crate::types::GetDepartmentsResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/departments", &[]))?;
 
         let mut data = resp.data;
         let mut page = resp.page.next.to_string();
@@ -85,7 +98,7 @@ impl Departments {
                     if e.to_string().contains("404 Not Found") {
                         page = "".to_string();
                     } else {
-                        anyhow::bail!(e);
+                        return Err(e.into());
                     }
                 }
             }
@@ -108,8 +121,9 @@ impl Departments {
     ) -> Result<crate::types::Department> {
         let url = "/departments".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/departments", &[]))
     }
 
     /**
@@ -124,12 +138,11 @@ impl Departments {
      * * `authorization: &str` -- The OAuth2 token header.
      */
     pub async fn get(&self, id: &str) -> Result<crate::types::Department> {
-        let url = format!(
-            "/departments/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/departments/{}", self.client.encode_path(id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/departments/{id}", &[("id", id.to_string())])
+        })
     }
 
     /**
@@ -144,13 +157,83 @@ impl Departments {
         id: &str,
         body: &crate::types::PostLocationRequest,
     ) -> Result<crate::types::Department> {
-        let url = format!(
-            "/departments/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/departments/{}", self.client.encode_path(id),);
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("PATCH", "/departments/{id}", &[("id", id.to_string())])
+            })
+    }
+
+    /**
+     * Update department.
+     *
+     * This function performs a `PATCH` to the `/departments/{id}` endpoint.
+     *
+     * As opposed to `patch`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_if_changed<C: serde::Serialize>(
+        &self,
+        id: &str,
+        current: &C,
+        body: &crate::types::PostLocationRequest,
+    ) -> Result<Option<crate::types::Department>> {
+        let url = format!("/departments/{}", self.client.encode_path(id),);
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url("PATCH", "/departments/{id}", &[("id", id.to_string())])
+            })
+    }
+}
+
+/// The functions of [`Departments`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn DepartmentsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait DepartmentsApi: Send + Sync {
+    async fn get_page(&self, start: &str, page_size: f64) -> Result<Vec<crate::types::Department>>;
+    async fn get_all(&self) -> Result<Vec<crate::types::Department>>;
+    async fn post(
+        &self,
+        body: &crate::types::PostLocationRequest,
+    ) -> Result<crate::types::Department>;
+    async fn get(&self, id: &str) -> Result<crate::types::Department>;
+    async fn patch(
+        &self,
+        id: &str,
+        body: &crate::types::PostLocationRequest,
+    ) -> Result<crate::types::Department>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl DepartmentsApi for Departments {
+    async fn get_page(&self, start: &str, page_size: f64) -> Result<Vec<crate::types::Department>> {
+        Departments::get_page(self, start, page_size).await
+    }
+    async fn get_all(&self) -> Result<Vec<crate::types::Department>> {
+        Departments::get_all(self).await
+    }
+    async fn post(
+        &self,
+        body: &crate::types::PostLocationRequest,
+    ) -> Result<crate::types::Department> {
+        Departments::post(self, body).await
+    }
+    async fn get(&self, id: &str) -> Result<crate::types::Department> {
+        Departments::get(self, id).await
+    }
+    async fn patch(
+        &self,
+        id: &str,
+        body: &crate::types::PostLocationRequest,
+    ) -> Result<crate::types::Department> {
+        Departments::patch(self, id, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Locations {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Locations {
         Locations { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Locations {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * List locations.
      *
@@ -30,17 +36,20 @@ impl Locations {
         start: &str,
         page_size: f64,
     ) -> Result<Vec<crate::types::Location>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !page_size.to_string().is_empty() {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !start.is_empty() {
-            query_args.push(("start".to_string(), start.to_string()));
+            query_args.push(("start", start.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/locations?{}", query_);
+        let url = crate::Client::url_with_query("/locations", &query_args);
 
-        let resp: crate::types::GetLocationResponse = self.client.get(&url, None).await?;
+        let resp: crate::types::GetLocationResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/locations", &[]))?;
 
         // Return our response data.
         Ok(resp.data)
@@ -57,7 +66,11 @@ impl Locations {
      */
     pub async fn get_all(&self) -> Result<Vec<crate::types::Location>> {
         let url = "/locations".to_string();
-        let resp: crate::types::GetLocationResponse = self.client.get(&url, None).await?;
+        let resp: crate::types::GetLocationResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/locations", &[]))?;
 
         let mut data = resp.data;
         let mut page = resp.page.next.to_string();
@@ -85,7 +98,7 @@ impl Locations {
                     if e.to_string().contains("404 Not Found") {
                         page = "".to_string();
                     } else {
-                        anyhow::bail!(e);
+                        return Err(e.into());
                     }
                 }
             }
@@ -112,8 +125,9 @@ impl Locations {
     ) -> Result<crate::types::Location> {
         let url = "/locations".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/locations", &[]))
     }
 
     /**
@@ -128,12 +142,11 @@ impl Locations {
      * * `authorization: &str` -- The OAuth2 token header.
      */
     pub async fn get(&self, id: &str) -> Result<crate::types::Location> {
-        let url = format!(
-            "/locations/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/locations/{}", self.client.encode_path(id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/locations/{id}", &[("id", id.to_string())])
+        })
     }
 
     /**
@@ -148,13 +161,83 @@ impl Locations {
         id: &str,
         body: &crate::types::PostLocationRequest,
     ) -> Result<crate::types::Location> {
-        let url = format!(
-            "/locations/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/locations/{}", self.client.encode_path(id),);
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("PATCH", "/locations/{id}", &[("id", id.to_string())])
+            })
+    }
+
+    /**
+     * Update location.
+     *
+     * This function performs a `PATCH` to the `/locations/{id}` endpoint.
+     *
+     * As opposed to `patch`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_if_changed<C: serde::Serialize>(
+        &self,
+        id: &str,
+        current: &C,
+        body: &crate::types::PostLocationRequest,
+    ) -> Result<Option<crate::types::Location>> {
+        let url = format!("/locations/{}", self.client.encode_path(id),);
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url("PATCH", "/locations/{id}", &[("id", id.to_string())])
+            })
+    }
+}
+
+/// The functions of [`Locations`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn LocationsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait LocationsApi: Send + Sync {
+    async fn get_page(&self, start: &str, page_size: f64) -> Result<Vec<crate::types::Location>>;
+    async fn get_all(&self) -> Result<Vec<crate::types::Location>>;
+    async fn post(
+        &self,
+        body: &crate::types::PostLocationRequest,
+    ) -> Result<crate::types::Location>;
+    async fn get(&self, id: &str) -> Result<crate::types::Location>;
+    async fn patch(
+        &self,
+        id: &str,
+        body: &crate::types::PostLocationRequest,
+    ) -> Result<crate::types::Location>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl LocationsApi for Locations {
+    async fn get_page(&self, start: &str, page_size: f64) -> Result<Vec<crate::types::Location>> {
+        Locations::get_page(self, start, page_size).await
+    }
+    async fn get_all(&self) -> Result<Vec<crate::types::Location>> {
+        Locations::get_all(self).await
+    }
+    async fn post(
+        &self,
+        body: &crate::types::PostLocationRequest,
+    ) -> Result<crate::types::Location> {
+        Locations::post(self, body).await
+    }
+    async fn get(&self, id: &str) -> Result<crate::types::Location> {
+        Locations::get(self, id).await
+    }
+    async fn patch(
+        &self,
+        id: &str,
+        body: &crate::types::PostLocationRequest,
+    ) -> Result<crate::types::Location> {
+        Locations::patch(self, id, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Receipts {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Receipts {
         Receipts { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Receipts {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * List receipts.
      *
@@ -37,29 +43,32 @@ impl Receipts {
         start: &str,
         page_size: f64,
     ) -> Result<Vec<crate::types::Receipt>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if let Some(date) = created_after {
-            query_args.push(("created_after".to_string(), date.to_rfc3339()));
+            query_args.push(("created_after", date.to_rfc3339()));
         }
         if let Some(date) = created_before {
-            query_args.push(("created_before".to_string(), date.to_rfc3339()));
+            query_args.push(("created_before", date.to_rfc3339()));
         }
         if let Some(date) = from_date {
-            query_args.push(("from_date".to_string(), date.to_rfc3339()));
+            query_args.push(("from_date", date.to_rfc3339()));
         }
         if !page_size.to_string().is_empty() {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !start.is_empty() {
-            query_args.push(("start".to_string(), start.to_string()));
+            query_args.push(("start", start.to_string()));
         }
         if let Some(date) = to_date {
-            query_args.push(("to_date".to_string(), date.to_rfc3339()));
+            query_args.push(("to_date", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/receipts?{}", query_);
+        let url = crate::Client::url_with_query("/receipts", &query_args);
 
-        let resp: crate::types::GetReceiptsResponse = self.client.get(&url, None).await?;
+        let resp: crate::types::GetReceiptsResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/receipts", &[]))?;
 
         // Return our response data.
         Ok(resp.data)
@@ -81,23 +90,26 @@ impl Receipts {
         created_after: Option<chrono::DateTime<chrono::Utc>>,
         created_before: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<Vec<crate::types::Receipt>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if let Some(date) = created_after {
-            query_args.push(("created_after".to_string(), date.to_rfc3339()));
+            query_args.push(("created_after", date.to_rfc3339()));
         }
         if let Some(date) = created_before {
-            query_args.push(("created_before".to_string(), date.to_rfc3339()));
+            query_args.push(("created_before", date.to_rfc3339()));
         }
         if let Some(date) = from_date {
-            query_args.push(("from_date".to_string(), date.to_rfc3339()));
+            query_args.push(("from_date", date.to_rfc3339()));
         }
         if let Some(date) = to_date {
-            query_args.push(("to_date".to_string(), date.to_rfc3339()));
+            query_args.push(("to_date", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/receipts?{}", query_);
+        let url = crate::Client::url_with_query("/receipts", &query_args);
 
-        let resp: crate::types::GetReceiptsResponse = self.client.get(&url, None).await?;
+        let resp: crate::types::GetReceiptsResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/receipts", &[]))?;
 
         let mut data = resp.data;
         let mut page = resp.page.next.to_string();
@@ -125,7 +137,7 @@ impl Receipts {
                     if e.to_string().contains("404 Not Found") {
                         page = "".to_string();
                     } else {
-                        anyhow::bail!(e);
+                        return Err(e.into());
                     }
                 }
             }
@@ -143,11 +155,72 @@ impl Receipts {
      *
      */
     pub async fn get(&self, id: &str) -> Result<crate::types::Receipt> {
-        let url = format!(
-            "/receipts/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/receipts/{}", self.client.encode_path(id),);
+
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/receipts/{id}", &[("id", id.to_string())])
+        })
+    }
+}
 
-        self.client.get(&url, None).await
+/// The functions of [`Receipts`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ReceiptsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ReceiptsApi: Send + Sync {
+    async fn get_page(
+        &self,
+        from_date: Option<chrono::DateTime<chrono::Utc>>,
+        to_date: Option<chrono::DateTime<chrono::Utc>>,
+        created_after: Option<chrono::DateTime<chrono::Utc>>,
+        created_before: Option<chrono::DateTime<chrono::Utc>>,
+        start: &str,
+        page_size: f64,
+    ) -> Result<Vec<crate::types::Receipt>>;
+    async fn get_all(
+        &self,
+        from_date: Option<chrono::DateTime<chrono::Utc>>,
+        to_date: Option<chrono::DateTime<chrono::Utc>>,
+        created_after: Option<chrono::DateTime<chrono::Utc>>,
+        created_before: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<crate::types::Receipt>>;
+    async fn get(&self, id: &str) -> Result<crate::types::Receipt>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ReceiptsApi for Receipts {
+    async fn get_page(
+        &self,
+        from_date: Option<chrono::DateTime<chrono::Utc>>,
+        to_date: Option<chrono::DateTime<chrono::Utc>>,
+        created_after: Option<chrono::DateTime<chrono::Utc>>,
+        created_before: Option<chrono::DateTime<chrono::Utc>>,
+        start: &str,
+        page_size: f64,
+    ) -> Result<Vec<crate::types::Receipt>> {
+        Receipts::get_page(
+            self,
+            from_date,
+            to_date,
+            created_after,
+            created_before,
+            start,
+            page_size,
+        )
+        .await
+    }
+    async fn get_all(
+        &self,
+        from_date: Option<chrono::DateTime<chrono::Utc>>,
+        to_date: Option<chrono::DateTime<chrono::Utc>>,
+        created_after: Option<chrono::DateTime<chrono::Utc>>,
+        created_before: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<crate::types::Receipt>> {
+        Receipts::get_all(self, from_date, to_date, created_after, created_before).await
+    }
+    async fn get(&self, id: &str) -> Result<crate::types::Receipt> {
+        Receipts::get(self, id).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Reimbursements {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Reimbursements {
         Reimbursements { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Reimbursements {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * List Reimbursements.
      *
@@ -27,17 +33,20 @@ impl Reimbursements {
         start: &str,
         page_size: f64,
     ) -> Result<Vec<crate::types::Reimbursement>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !page_size.to_string().is_empty() {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !start.is_empty() {
-            query_args.push(("start".to_string(), start.to_string()));
+            query_args.push(("start", start.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/reimbursements?{}", query_);
+        let url = crate::Client::url_with_query("/reimbursements", &query_args);
 
-        let resp: crate::types::GetReimbursementsResponse = self.client.get(&url, None).await?;
+        let resp: crate::types::GetReimbursementsResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/reimbursements", &[]))?;
 
         // Return our response data.
         Ok(resp.data)
@@ -52,7 +61,11 @@ impl Reimbursements {
      */
     pub async fn get_all(&self) -> Result<Vec<crate::types::Reimbursement>> {
         let url = "/reimbursements".to_string();
-        let resp: crate::types::GetReimbursementsResponse = self.client.get(&url, None).await?;
+        let resp: crate::types::GetReimbursementsResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/reimbursements", &[]))?;
 
         let mut data = resp.data;
         let mut page = resp.page.next.to_string();
@@ -80,7 +93,7 @@ impl Reimbursements {
                     if e.to_string().contains("404 Not Found") {
                         page = "".to_string();
                     } else {
-                        anyhow::bail!(e);
+                        return Err(e.into());
                     }
                 }
             }
@@ -96,11 +109,43 @@ impl Reimbursements {
      * This function performs a `GET` to the `/reimbursements/{id}` endpoint.
      */
     pub async fn get(&self, id: &str) -> Result<crate::types::Reimbursement> {
-        let url = format!(
-            "/reimbursements/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/reimbursements/{}", self.client.encode_path(id),);
+
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/reimbursements/{id}", &[("id", id.to_string())])
+        })
+    }
+}
+
+/// The functions of [`Reimbursements`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ReimbursementsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ReimbursementsApi: Send + Sync {
+    async fn get_page(
+        &self,
+        start: &str,
+        page_size: f64,
+    ) -> Result<Vec<crate::types::Reimbursement>>;
+    async fn get_all(&self) -> Result<Vec<crate::types::Reimbursement>>;
+    async fn get(&self, id: &str) -> Result<crate::types::Reimbursement>;
+}
 
-        self.client.get(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ReimbursementsApi for Reimbursements {
+    async fn get_page(
+        &self,
+        start: &str,
+        page_size: f64,
+    ) -> Result<Vec<crate::types::Reimbursement>> {
+        Reimbursements::get_page(self, start, page_size).await
+    }
+    async fn get_all(&self) -> Result<Vec<crate::types::Reimbursement>> {
+        Reimbursements::get_all(self).await
+    }
+    async fn get(&self, id: &str) -> Result<crate::types::Reimbursement> {
+        Reimbursements::get(self, id).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Transactions {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Transactions {
         Transactions { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Transactions {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * List transactions.
      *
@@ -58,76 +64,152 @@ impl Transactions {
         page_size: f64,
         requires_memo: bool,
     ) -> Result<Vec<crate::types::Data>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !department_id.is_empty() {
-            query_args.push(("department_id".to_string(), department_id.to_string()));
+            query_args.push(("department_id", department_id.to_string()));
         }
         if let Some(date) = from_date {
-            query_args.push(("from_date".to_string(), date.to_rfc3339()));
+            query_args.push(("from_date", date.to_rfc3339()));
         }
         if !location_id.is_empty() {
-            query_args.push(("location_id".to_string(), location_id.to_string()));
+            query_args.push(("location_id", location_id.to_string()));
         }
         if !max_amount.to_string().is_empty() {
-            query_args.push(("max_amount".to_string(), max_amount.to_string()));
+            query_args.push(("max_amount", max_amount.to_string()));
         }
         if !merchant_id.is_empty() {
-            query_args.push(("merchant_id".to_string(), merchant_id.to_string()));
+            query_args.push(("merchant_id", merchant_id.to_string()));
         }
         if !min_amount.to_string().is_empty() {
-            query_args.push(("min_amount".to_string(), min_amount.to_string()));
+            query_args.push(("min_amount", min_amount.to_string()));
         }
         if order_by_amount_asc {
-            query_args.push((
-                "order_by_amount_asc".to_string(),
-                order_by_amount_asc.to_string(),
-            ));
+            query_args.push(("order_by_amount_asc", order_by_amount_asc.to_string()));
         }
         if order_by_amount_desc {
-            query_args.push((
-                "order_by_amount_desc".to_string(),
-                order_by_amount_desc.to_string(),
-            ));
+            query_args.push(("order_by_amount_desc", order_by_amount_desc.to_string()));
         }
         if order_by_date_asc {
-            query_args.push((
-                "order_by_date_asc".to_string(),
-                order_by_date_asc.to_string(),
-            ));
+            query_args.push(("order_by_date_asc", order_by_date_asc.to_string()));
         }
         if order_by_date_desc {
-            query_args.push((
-                "order_by_date_desc".to_string(),
-                order_by_date_desc.to_string(),
-            ));
+            query_args.push(("order_by_date_desc", order_by_date_desc.to_string()));
         }
         if !page_size.to_string().is_empty() {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if requires_memo {
-            query_args.push(("requires_memo".to_string(), requires_memo.to_string()));
+            query_args.push(("requires_memo", requires_memo.to_string()));
         }
         if !sk_category_id.is_empty() {
-            query_args.push(("sk_category_id".to_string(), sk_category_id.to_string()));
+            query_args.push(("sk_category_id", sk_category_id.to_string()));
         }
         if !start.is_empty() {
-            query_args.push(("start".to_string(), start.to_string()));
+            query_args.push(("start", start.to_string()));
         }
         if !state.is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+            query_args.push(("state", state.to_string()));
         }
         if let Some(date) = to_date {
-            query_args.push(("to_date".to_string(), date.to_rfc3339()));
+            query_args.push(("to_date", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/transactions?{}", query_);
+        let url = crate::Client::url_with_query("/transactions", &query_args);
 
-        let resp: crate::types::GetTransactionResponse = self.client.get(&url, None).await?;
+        let resp: crate::types::GetTransactionResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/transactions", &[]))?;
 
         // Return our response data.
         Ok(resp.data)
     }
 
+    /**
+     * List transactions.
+     *
+     * This function performs a `GET` to the `/transactions` endpoint.
+     *
+     * As opposed to `get_page`, this function streams the items as they are parsed from the response, rather than returning them all at once.
+     * This is useful for very large responses that would otherwise need to be held in memory.
+     * Only the one page the parameters ask for is streamed, the next pages are not fetched.
+     */
+    pub async fn get_page_stream_json(
+        &self,
+        department_id: &str,
+        location_id: &str,
+        from_date: Option<chrono::DateTime<chrono::Utc>>,
+        to_date: Option<chrono::DateTime<chrono::Utc>>,
+        merchant_id: &str,
+        sk_category_id: &str,
+        order_by_date_desc: bool,
+        order_by_date_asc: bool,
+        order_by_amount_desc: bool,
+        order_by_amount_asc: bool,
+        state: &str,
+        min_amount: f64,
+        max_amount: f64,
+        start: &str,
+        page_size: f64,
+        requires_memo: bool,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::Data>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !department_id.is_empty() {
+            query_args.push(("department_id", department_id.to_string()));
+        }
+        if let Some(date) = from_date {
+            query_args.push(("from_date", date.to_rfc3339()));
+        }
+        if !location_id.is_empty() {
+            query_args.push(("location_id", location_id.to_string()));
+        }
+        if !max_amount.to_string().is_empty() {
+            query_args.push(("max_amount", max_amount.to_string()));
+        }
+        if !merchant_id.is_empty() {
+            query_args.push(("merchant_id", merchant_id.to_string()));
+        }
+        if !min_amount.to_string().is_empty() {
+            query_args.push(("min_amount", min_amount.to_string()));
+        }
+        if order_by_amount_asc {
+            query_args.push(("order_by_amount_asc", order_by_amount_asc.to_string()));
+        }
+        if order_by_amount_desc {
+            query_args.push(("order_by_amount_desc", order_by_amount_desc.to_string()));
+        }
+        if order_by_date_asc {
+            query_args.push(("order_by_date_asc", order_by_date_asc.to_string()));
+        }
+        if order_by_date_desc {
+            query_args.push(("order_by_date_desc", order_by_date_desc.to_string()));
+        }
+        if !page_size.to_string().is_empty() {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        if requires_memo {
+            query_args.push(("requires_memo", requires_memo.to_string()));
+        }
+        if !sk_category_id.is_empty() {
+            query_args.push(("sk_category_id", sk_category_id.to_string()));
+        }
+        if !start.is_empty() {
+            query_args.push(("start", start.to_string()));
+        }
+        if !state.is_empty() {
+            query_args.push(("state", state.to_string()));
+        }
+        if let Some(date) = to_date {
+            query_args.push(("to_date", date.to_rfc3339()));
+        }
+        let url = crate::Client::url_with_query("/transactions", &query_args);
+
+        self.client
+            .get_stream_json(&url, "data")
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/transactions", &[]))
+    }
+
     /**
      * List transactions.
      *
@@ -154,65 +236,56 @@ impl Transactions {
         max_amount: f64,
         requires_memo: bool,
     ) -> Result<Vec<crate::types::Data>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !department_id.is_empty() {
-            query_args.push(("department_id".to_string(), department_id.to_string()));
+            query_args.push(("department_id", department_id.to_string()));
         }
         if let Some(date) = from_date {
-            query_args.push(("from_date".to_string(), date.to_rfc3339()));
+            query_args.push(("from_date", date.to_rfc3339()));
         }
         if !location_id.is_empty() {
-            query_args.push(("location_id".to_string(), location_id.to_string()));
+            query_args.push(("location_id", location_id.to_string()));
         }
         if !max_amount.to_string().is_empty() {
-            query_args.push(("max_amount".to_string(), max_amount.to_string()));
+            query_args.push(("max_amount", max_amount.to_string()));
         }
         if !merchant_id.is_empty() {
-            query_args.push(("merchant_id".to_string(), merchant_id.to_string()));
+            query_args.push(("merchant_id", merchant_id.to_string()));
         }
         if !min_amount.to_string().is_empty() {
-            query_args.push(("min_amount".to_string(), min_amount.to_string()));
+            query_args.push(("min_amount", min_amount.to_string()));
         }
         if order_by_amount_asc {
-            query_args.push((
-                "order_by_amount_asc".to_string(),
-                order_by_amount_asc.to_string(),
-            ));
+            query_args.push(("order_by_amount_asc", order_by_amount_asc.to_string()));
         }
         if order_by_amount_desc {
-            query_args.push((
-                "order_by_amount_desc".to_string(),
-                order_by_amount_desc.to_string(),
-            ));
+            query_args.push(("order_by_amount_desc", order_by_amount_desc.to_string()));
         }
         if order_by_date_asc {
-            query_args.push((
-                "order_by_date_asc".to_string(),
-                order_by_date_asc.to_string(),
-            ));
+            query_args.push(("order_by_date_asc", order_by_date_asc.to_string()));
         }
         if order_by_date_desc {
-            query_args.push((
-                "order_by_date_desc".to_string(),
-                order_by_date_desc.to_string(),
-            ));
+            query_args.push(("order_by_date_desc", order_by_date_desc.to_string()));
         }
         if requires_memo {
-            query_args.push(("requires_memo".to_string(), requires_memo.to_string()));
+            query_args.push(("requires_memo", requires_memo.to_string()));
         }
         if !sk_category_id.is_empty() {
-            query_args.push(("sk_category_id".to_string(), sk_category_id.to_string()));
+            query_args.push(("sk_category_id", sk_category_id.to_string()));
         }
         if !state.is_empty() {
-            query_args.push(("state".to_string(), state.to_string()));
+            query_args.push(("state", state.to_string()));
         }
         if let Some(date) = to_date {
-            query_args.push(("to_date".to_string(), date.to_rfc3339()));
+            query_args.push(("to_date", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/transactions?{}", query_);
+        let url = crate::Client::url_with_query("/transactions", &query_args);
 
-        let resp: crate::types::GetTransactionResponse = self.client.get(&url, None).await?;
+        let resp: crate::types::GetTransactionResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/transactions", &[]))?;
 
         let mut data = resp.data;
         let mut page = resp.page.next.to_string();
@@ -240,7 +313,7 @@ impl Transactions {
                     if e.to_string().contains("404 Not Found") {
                         page = "".to_string();
                     } else {
-                        anyhow::bail!(e);
+                        return Err(e.into());
                     }
                 }
             }
@@ -262,11 +335,139 @@ impl Transactions {
      * * `authorization: &str` -- The OAuth2 token header.
      */
     pub async fn get_resource(&self, id: &str) -> Result<crate::types::Data> {
-        let url = format!(
-            "/transactions/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/transactions/{}", self.client.encode_path(id),);
+
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/transactions/{id}", &[("id", id.to_string())])
+        })
+    }
+}
 
-        self.client.get(&url, None).await
+/// The functions of [`Transactions`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn TransactionsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait TransactionsApi: Send + Sync {
+    async fn get_page(
+        &self,
+        department_id: &str,
+        location_id: &str,
+        from_date: Option<chrono::DateTime<chrono::Utc>>,
+        to_date: Option<chrono::DateTime<chrono::Utc>>,
+        merchant_id: &str,
+        sk_category_id: &str,
+        order_by_date_desc: bool,
+        order_by_date_asc: bool,
+        order_by_amount_desc: bool,
+        order_by_amount_asc: bool,
+        state: &str,
+        min_amount: f64,
+        max_amount: f64,
+        start: &str,
+        page_size: f64,
+        requires_memo: bool,
+    ) -> Result<Vec<crate::types::Data>>;
+    async fn get_all(
+        &self,
+        department_id: &str,
+        location_id: &str,
+        from_date: Option<chrono::DateTime<chrono::Utc>>,
+        to_date: Option<chrono::DateTime<chrono::Utc>>,
+        merchant_id: &str,
+        sk_category_id: &str,
+        order_by_date_desc: bool,
+        order_by_date_asc: bool,
+        order_by_amount_desc: bool,
+        order_by_amount_asc: bool,
+        state: &str,
+        min_amount: f64,
+        max_amount: f64,
+        requires_memo: bool,
+    ) -> Result<Vec<crate::types::Data>>;
+    async fn get_resource(&self, id: &str) -> Result<crate::types::Data>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl TransactionsApi for Transactions {
+    async fn get_page(
+        &self,
+        department_id: &str,
+        location_id: &str,
+        from_date: Option<chrono::DateTime<chrono::Utc>>,
+        to_date: Option<chrono::DateTime<chrono::Utc>>,
+        merchant_id: &str,
+        sk_category_id: &str,
+        order_by_date_desc: bool,
+        order_by_date_asc: bool,
+        order_by_amount_desc: bool,
+        order_by_amount_asc: bool,
+        state: &str,
+        min_amount: f64,
+        max_amount: f64,
+        start: &str,
+        page_size: f64,
+        requires_memo: bool,
+    ) -> Result<Vec<crate::types::Data>> {
+        Transactions::get_page(
+            self,
+            department_id,
+            location_id,
+            from_date,
+            to_date,
+            merchant_id,
+            sk_category_id,
+            order_by_date_desc,
+            order_by_date_asc,
+            order_by_amount_desc,
+            order_by_amount_asc,
+            state,
+            min_amount,
+            max_amount,
+            start,
+            page_size,
+            requires_memo,
+        )
+        .await
+    }
+    async fn get_all(
+        &self,
+        department_id: &str,
+        location_id: &str,
+        from_date: Option<chrono::DateTime<chrono::Utc>>,
+        to_date: Option<chrono::DateTime<chrono::Utc>>,
+        merchant_id: &str,
+        sk_category_id: &str,
+        order_by_date_desc: bool,
+        order_by_date_asc: bool,
+        order_by_amount_desc: bool,
+        order_by_amount_asc: bool,
+        state: &str,
+        min_amount: f64,
+        max_amount: f64,
+        requires_memo: bool,
+    ) -> Result<Vec<crate::types::Data>> {
+        Transactions::get_all(
+            self,
+            department_id,
+            location_id,
+            from_date,
+            to_date,
+            merchant_id,
+            sk_category_id,
+            order_by_date_desc,
+            order_by_date_asc,
+            order_by_amount_desc,
+            order_by_amount_asc,
+            state,
+            min_amount,
+            max_amount,
+            requires_memo,
+        )
+        .await
+    }
+    async fn get_resource(&self, id: &str) -> Result<crate::types::Data> {
+        Transactions::get_resource(self, id).await
     }
 }
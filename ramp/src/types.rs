@@ -2,6 +2,33 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+/// Either one of something, or many of them. Some endpoints send back an
+/// object when there is one and an array when there are more, so this takes both.
+// Many is tried first: serde reads a struct from an array of its fields too,
+// so an array would otherwise be taken for One.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum OneOrMany<T> {
+    Many(Vec<T>),
+    One(T),
+}
+
+impl<T> OneOrMany<T> {
+    /// All of them, as a vector.
+    pub fn into_vec(self) -> Vec<T> {
+        match self {
+            OneOrMany::One(t) => vec![t],
+            OneOrMany::Many(v) => v,
+        }
+    }
+}
+
+impl<T> Default for OneOrMany<T> {
+    fn default() -> Self {
+        OneOrMany::Many(Vec::new())
+    }
+}
+
 /// Extra metadata about the error, may be empty. Usually depends on the error type.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Details {}
@@ -43,7 +70,7 @@ pub struct TaskResponse {
     pub id: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Role {
     #[serde(rename = "BUSINESS_ADMIN")]
     BusinessAdmin,
@@ -78,7 +105,30 @@ impl Default for Role {
         Role::Noop
     }
 }
+
 impl Role {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::BusinessAdmin => "BUSINESS_ADMIN",
+            Role::BusinessBookkeeper => "BUSINESS_BOOKKEEPER",
+            Role::BusinessOwner => "BUSINESS_OWNER",
+            Role::BusinessUser => "BUSINESS_USER",
+            Role::Noop => "",
+            Role::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Role] {
+        &[
+            Role::BusinessAdmin,
+            Role::BusinessBookkeeper,
+            Role::BusinessOwner,
+            Role::BusinessUser,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Role::Noop)
     }
@@ -304,7 +354,7 @@ pub struct AccountingCategories {
     pub category_name: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Type {
     #[serde(rename = "POLICY_VIOLATION_FROM_ADMIN")]
     PolicyViolationFromAdmin,
@@ -333,7 +383,26 @@ impl Default for Type {
         Type::Noop
     }
 }
+
 impl Type {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Type::PolicyViolationFromAdmin => "POLICY_VIOLATION_FROM_ADMIN",
+            Type::PolicyViolationFromUser => "POLICY_VIOLATION_FROM_USER",
+            Type::Noop => "",
+            Type::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Type] {
+        &[
+            Type::PolicyViolationFromAdmin,
+            Type::PolicyViolationFromUser,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Type::Noop)
     }
@@ -369,7 +438,7 @@ pub struct PolicyViolations {
     pub type_: Option<Type>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GetTransactionResponseDataDisputesType {
     #[serde(rename = "DISPUTE_CANCELLED")]
     DisputeCancelled,
@@ -404,7 +473,30 @@ impl Default for GetTransactionResponseDataDisputesType {
         GetTransactionResponseDataDisputesType::Noop
     }
 }
+
 impl GetTransactionResponseDataDisputesType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GetTransactionResponseDataDisputesType::DisputeCancelled => "DISPUTE_CANCELLED",
+            GetTransactionResponseDataDisputesType::MerchantError => "MERCHANT_ERROR",
+            GetTransactionResponseDataDisputesType::Unknown => "UNKNOWN",
+            GetTransactionResponseDataDisputesType::UnrecognizedCharge => "UNRECOGNIZED_CHARGE",
+            GetTransactionResponseDataDisputesType::Noop => "",
+            GetTransactionResponseDataDisputesType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GetTransactionResponseDataDisputesType] {
+        &[
+            GetTransactionResponseDataDisputesType::DisputeCancelled,
+            GetTransactionResponseDataDisputesType::MerchantError,
+            GetTransactionResponseDataDisputesType::Unknown,
+            GetTransactionResponseDataDisputesType::UnrecognizedCharge,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, GetTransactionResponseDataDisputesType::Noop)
     }
@@ -582,6 +674,31 @@ pub struct GetTransactionResponse {
     pub page: Page,
 }
 
+impl IntoIterator for GetTransactionResponse {
+    type Item = Data;
+    type IntoIter = std::vec::IntoIter<Data>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a GetTransactionResponse {
+    type Item = &'a Data;
+    type IntoIter = std::slice::Iter<'a, Data>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
+impl GetTransactionResponse {
+    /// Iterate over the `data`.
+    pub fn iter(&self) -> std::slice::Iter<'_, Data> {
+        self.data.iter()
+    }
+}
+
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetLocationResponsePage {
     /**
@@ -630,6 +747,31 @@ pub struct GetLocationResponse {
     pub page: GetLocationResponsePage,
 }
 
+impl IntoIterator for GetLocationResponse {
+    type Item = Location;
+    type IntoIter = std::vec::IntoIter<Location>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a GetLocationResponse {
+    type Item = &'a Location;
+    type IntoIter = std::slice::Iter<'a, Location>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
+impl GetLocationResponse {
+    /// Iterate over the `data`.
+    pub fn iter(&self) -> std::slice::Iter<'_, Location> {
+        self.data.iter()
+    }
+}
+
 ///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostLocationRequest {
@@ -657,6 +799,31 @@ pub struct GetUsersResponse {
     pub page: Page,
 }
 
+impl IntoIterator for GetUsersResponse {
+    type Item = User;
+    type IntoIter = std::vec::IntoIter<User>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a GetUsersResponse {
+    type Item = &'a User;
+    type IntoIter = std::slice::Iter<'a, User>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
+impl GetUsersResponse {
+    /// Iterate over the `data`.
+    pub fn iter(&self) -> std::slice::Iter<'_, User> {
+        self.data.iter()
+    }
+}
+
 /// Ramp Department
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Department {
@@ -692,6 +859,31 @@ pub struct GetDepartmentsResponse {
     pub page: GetLocationResponsePage,
 }
 
+impl IntoIterator for GetDepartmentsResponse {
+    type Item = Department;
+    type IntoIter = std::vec::IntoIter<Department>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a GetDepartmentsResponse {
+    type Item = &'a Department;
+    type IntoIter = std::slice::Iter<'a, Department>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
+impl GetDepartmentsResponse {
+    /// Iterate over the `data`.
+    pub fn iter(&self) -> std::slice::Iter<'_, Department> {
+        self.data.iter()
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct RecipientAddress {
     /**
@@ -779,7 +971,7 @@ pub struct Fulfillment {
 /**
  * Time interval to apply limit to.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Interval {
     #[serde(rename = "DAILY")]
     Daily,
@@ -814,7 +1006,30 @@ impl Default for Interval {
         Interval::Noop
     }
 }
+
 impl Interval {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Interval::Daily => "DAILY",
+            Interval::Monthly => "MONTHLY",
+            Interval::Total => "TOTAL",
+            Interval::Yearly => "YEARLY",
+            Interval::Noop => "",
+            Interval::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Interval] {
+        &[
+            Interval::Daily,
+            Interval::Monthly,
+            Interval::Total,
+            Interval::Yearly,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Interval::Noop)
     }
@@ -972,6 +1187,31 @@ pub struct GetCardsResponse {
     pub page: GetLocationResponsePage,
 }
 
+impl IntoIterator for GetCardsResponse {
+    type Item = Card;
+    type IntoIter = std::vec::IntoIter<Card>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.cards.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a GetCardsResponse {
+    type Item = &'a Card;
+    type IntoIter = std::slice::Iter<'a, Card>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.cards.iter()
+    }
+}
+
+impl GetCardsResponse {
+    /// Iterate over the `cards`.
+    pub fn iter(&self) -> std::slice::Iter<'_, Card> {
+        self.cards.iter()
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PatchResourcesCardsCardRequest {
     /**
@@ -1285,7 +1525,7 @@ pub struct PostResourcesCardVirtualRequest {
     pub user_id: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum TokenType {
     #[serde(rename = "Bearer")]
     Bearer,
@@ -1311,7 +1551,22 @@ impl Default for TokenType {
         TokenType::Noop
     }
 }
+
 impl TokenType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TokenType::Bearer => "Bearer",
+            TokenType::Noop => "",
+            TokenType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [TokenType] {
+        &[TokenType::Bearer]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, TokenType::Noop)
     }
@@ -1498,7 +1753,7 @@ pub struct GetResourcesCardsDeferredResponseData {
     pub misc: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Status {
     #[serde(rename = "ERROR")]
     Error,
@@ -1533,7 +1788,30 @@ impl Default for Status {
         Status::Noop
     }
 }
+
 impl Status {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Status::Error => "ERROR",
+            Status::InProgress => "IN_PROGRESS",
+            Status::Started => "STARTED",
+            Status::Success => "SUCCESS",
+            Status::Noop => "",
+            Status::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Status] {
+        &[
+            Status::Error,
+            Status::InProgress,
+            Status::Started,
+            Status::Success,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Status::Noop)
     }
@@ -1556,7 +1834,7 @@ pub struct GetResourcesCardsDeferredResponse {
     pub status: Option<Status>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Icon {
     #[serde(rename = "AdvertisingIcon")]
     AdvertisingIcon,
@@ -1609,7 +1887,42 @@ impl Default for Icon {
         Icon::Noop
     }
 }
+
 impl Icon {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Icon::AdvertisingIcon => "AdvertisingIcon",
+            Icon::CardIcon => "CardIcon",
+            Icon::EducationStipendIcon => "EducationStipendIcon",
+            Icon::LunchOrderingIcon => "LunchOrderingIcon",
+            Icon::OnboardingIcon => "OnboardingIcon",
+            Icon::PerDiemCardIcon => "PerDiemCardIcon",
+            Icon::SaasSubscriptionIcon => "SaasSubscriptionIcon",
+            Icon::SoftwareTrialIcon => "SoftwareTrialIcon",
+            Icon::TravelExpensesIcon => "TravelExpensesIcon",
+            Icon::WellnessIcon => "WellnessIcon",
+            Icon::Noop => "",
+            Icon::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Icon] {
+        &[
+            Icon::AdvertisingIcon,
+            Icon::CardIcon,
+            Icon::EducationStipendIcon,
+            Icon::LunchOrderingIcon,
+            Icon::OnboardingIcon,
+            Icon::PerDiemCardIcon,
+            Icon::SaasSubscriptionIcon,
+            Icon::SoftwareTrialIcon,
+            Icon::TravelExpensesIcon,
+            Icon::WellnessIcon,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Icon::Noop)
     }
@@ -1686,6 +1999,31 @@ pub struct GetCardProgramsResponse {
     pub page: GetLocationResponsePage,
 }
 
+impl IntoIterator for GetCardProgramsResponse {
+    type Item = CardProgram;
+    type IntoIter = std::vec::IntoIter<CardProgram>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.card_programs.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a GetCardProgramsResponse {
+    type Item = &'a CardProgram;
+    type IntoIter = std::slice::Iter<'a, CardProgram>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.card_programs.iter()
+    }
+}
+
+impl GetCardProgramsResponse {
+    /// Iterate over the `card_programs`.
+    pub fn iter(&self) -> std::slice::Iter<'_, CardProgram> {
+        self.card_programs.iter()
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostResourcesCardProgramRequest {
     /**
@@ -1912,6 +2250,31 @@ pub struct GetReceiptsResponse {
     pub page: Page,
 }
 
+impl IntoIterator for GetReceiptsResponse {
+    type Item = Receipt;
+    type IntoIter = std::vec::IntoIter<Receipt>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a GetReceiptsResponse {
+    type Item = &'a Receipt;
+    type IntoIter = std::slice::Iter<'a, Receipt>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
+impl GetReceiptsResponse {
+    /// Iterate over the `data`.
+    pub fn iter(&self) -> std::slice::Iter<'_, Receipt> {
+        self.data.iter()
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Reimbursement {
     #[serde(
@@ -1976,6 +2339,31 @@ pub struct GetReimbursementsResponse {
     pub page: Page,
 }
 
+impl IntoIterator for GetReimbursementsResponse {
+    type Item = Reimbursement;
+    type IntoIter = std::vec::IntoIter<Reimbursement>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a GetReimbursementsResponse {
+    type Item = &'a Reimbursement;
+    type IntoIter = std::slice::Iter<'a, Reimbursement>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
+impl GetReimbursementsResponse {
+    /// Iterate over the `data`.
+    pub fn iter(&self) -> std::slice::Iter<'_, Reimbursement> {
+        self.data.iter()
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostCustomProviderEntityTypeLinkRequest {
     /**
@@ -1997,3 +2385,69 @@ pub struct PostCustomProviderEntityTypeLinkRequest {
     )]
     pub ramp_id: String,
 }
+
+/// The types taken and returned by the functions in [`crate::auths`].
+pub mod auths {
+    pub use super::OAuth2Token;
+}
+
+/// The types taken and returned by the functions in [`crate::businesses`].
+pub mod businesses {
+    pub use super::{Business, BusinessCurrentStatus};
+}
+
+/// The types taken and returned by the functions in [`crate::card_programs`].
+pub mod card_programs {
+    pub use super::{CardProgram, GetCardProgramsResponse, PostResourcesCardProgramRequest};
+}
+
+/// The types taken and returned by the functions in [`crate::cards`].
+pub mod cards {
+    pub use super::{
+        Card, GetCardsResponse, GetResourcesCardsDeferredResponse, PatchResourcesCardsCardRequest,
+        PostResourcesCardPhysicalRequest, PostResourcesCardVirtualRequest,
+        PostResourcesCardsCardSuspensionRequest, TaskResponse,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::custom_ids`].
+pub mod custom_ids {
+    pub use super::{
+        GetCustomProviderResponse, GetEntityTypeCustomRampResponse,
+        GetEntityTypeRampCustomResponse, PostCustomProviderEntityTypeLinkRequest,
+        PostcustomProviderResponse,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::departments`].
+pub mod departments {
+    pub use super::{Department, GetDepartmentsResponse, PostLocationRequest};
+}
+
+/// The types taken and returned by the functions in [`crate::locations`].
+pub mod locations {
+    pub use super::{GetLocationResponse, Location, PostLocationRequest};
+}
+
+/// The types taken and returned by the functions in [`crate::receipts`].
+pub mod receipts {
+    pub use super::{GetReceiptsResponse, Receipt};
+}
+
+/// The types taken and returned by the functions in [`crate::reimbursements`].
+pub mod reimbursements {
+    pub use super::{GetReimbursementsResponse, Reimbursement};
+}
+
+/// The types taken and returned by the functions in [`crate::transactions`].
+pub mod transactions {
+    pub use super::{Data, GetTransactionResponse};
+}
+
+/// The types taken and returned by the functions in [`crate::users`].
+pub mod users {
+    pub use super::{
+        GetUsersDeferredStatusResponse, GetUsersResponse, PatchUsersRequest,
+        PostUsersDeferredRequest, User,
+    };
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Users {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Users {
         Users { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Users {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get User Info by User ID.
      *
@@ -24,12 +30,11 @@ impl Users {
      * * `authorization: &str` -- The OAuth2 token header.
      */
     pub async fn get(&self, id: &str) -> Result<crate::types::User> {
-        let url = format!(
-            "/users/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/users/{}", self.client.encode_path(id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/users/{id}", &[("id", id.to_string())])
+        })
     }
 
     /**
@@ -40,12 +45,29 @@ impl Users {
      * Suspends a user. Does not delete the user's cards. Currently this action is not reversible.
      */
     pub async fn delete(&self, id: &str) -> Result<()> {
-        let url = format!(
-            "/users/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/users/{}", self.client.encode_path(id),);
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("DELETE", "/users/{id}", &[("id", id.to_string())])
+        })
+    }
+
+    /**
+     * Suspend a user.
+     *
+     * This function performs a `DELETE` to the `/users/{id}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, id: &str) -> Result<bool> {
+        let url = format!("/users/{}", self.client.encode_path(id),);
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url("DELETE", "/users/{id}", &[("id", id.to_string())])
+            })
     }
 
     /**
@@ -56,14 +78,37 @@ impl Users {
      * Modify information about a user.
      */
     pub async fn patch(&self, id: &str, body: &crate::types::PatchUsersRequest) -> Result<()> {
-        let url = format!(
-            "/users/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/users/{}", self.client.encode_path(id),);
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("PATCH", "/users/{id}", &[("id", id.to_string())])
+            })
+    }
+
+    /**
+     * Modify Existing User.
+     *
+     * This function performs a `PATCH` to the `/users/{id}` endpoint.
+     *
+     * As opposed to `patch`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_if_changed<C: serde::Serialize>(
+        &self,
+        id: &str,
+        current: &C,
+        body: &crate::types::PatchUsersRequest,
+    ) -> Result<Option<()>> {
+        let url = format!("/users/{}", self.client.encode_path(id),);
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url("PATCH", "/users/{id}", &[("id", id.to_string())])
+            })
     }
 
     /**
@@ -88,23 +133,26 @@ impl Users {
         department_id: &str,
         location_id: &str,
     ) -> Result<Vec<crate::types::User>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !department_id.is_empty() {
-            query_args.push(("department_id".to_string(), department_id.to_string()));
+            query_args.push(("department_id", department_id.to_string()));
         }
         if !location_id.is_empty() {
-            query_args.push(("location_id".to_string(), location_id.to_string()));
+            query_args.push(("location_id", location_id.to_string()));
         }
         if !page_size.to_string().is_empty() {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !start.is_empty() {
-            query_args.push(("start".to_string(), start.to_string()));
+            query_args.push(("start", start.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/users?{}", query_);
+        let url = crate::Client::url_with_query("/users", &query_args);
 
-        let resp: crate::types::GetUsersResponse = self.client.get(&url, None).await?;
+        let resp: crate::types::GetUsersResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/users", &[]))?;
 
         // Return our response data.
         Ok(resp.data)
@@ -124,17 +172,20 @@ impl Users {
         department_id: &str,
         location_id: &str,
     ) -> Result<Vec<crate::types::User>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !department_id.is_empty() {
-            query_args.push(("department_id".to_string(), department_id.to_string()));
+            query_args.push(("department_id", department_id.to_string()));
         }
         if !location_id.is_empty() {
-            query_args.push(("location_id".to_string(), location_id.to_string()));
+            query_args.push(("location_id", location_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/users?{}", query_);
+        let url = crate::Client::url_with_query("/users", &query_args);
 
-        let resp: crate::types::GetUsersResponse = self.client.get(&url, None).await?;
+        let resp: crate::types::GetUsersResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/users", &[]))?;
 
         let mut data = resp.data;
         let mut page = resp.page.next.to_string();
@@ -162,7 +213,7 @@ impl Users {
                     if e.to_string().contains("404 Not Found") {
                         page = "".to_string();
                     } else {
-                        anyhow::bail!(e);
+                        return Err(e.into());
                     }
                 }
             }
@@ -185,8 +236,9 @@ impl Users {
     ) -> Result<crate::types::User> {
         let url = "/users/deferred".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/users/deferred", &[]))
     }
 
     /**
@@ -200,11 +252,91 @@ impl Users {
         &self,
         id: &str,
     ) -> Result<crate::types::GetUsersDeferredStatusResponse> {
-        let url = format!(
-            "/users/deferred/status/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/users/deferred/status/{}", self.client.encode_path(id),);
+
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/users/deferred/status/{id}",
+                &[("id", id.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`Users`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn UsersApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait UsersApi: Send + Sync {
+    async fn get(&self, id: &str) -> Result<crate::types::User>;
+    async fn delete(&self, id: &str) -> Result<()>;
+    async fn delete_if_exists(&self, id: &str) -> Result<bool>;
+    async fn patch(&self, id: &str, body: &crate::types::PatchUsersRequest) -> Result<()>;
+    async fn get_page(
+        &self,
+        start: &str,
+        page_size: f64,
+        department_id: &str,
+        location_id: &str,
+    ) -> Result<Vec<crate::types::User>>;
+    async fn get_all(
+        &self,
+        department_id: &str,
+        location_id: &str,
+    ) -> Result<Vec<crate::types::User>>;
+    async fn post_deferred(
+        &self,
+        body: &crate::types::PostUsersDeferredRequest,
+    ) -> Result<crate::types::User>;
+    async fn get_deferred_status(
+        &self,
+        id: &str,
+    ) -> Result<crate::types::GetUsersDeferredStatusResponse>;
+}
 
-        self.client.get(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl UsersApi for Users {
+    async fn get(&self, id: &str) -> Result<crate::types::User> {
+        Users::get(self, id).await
+    }
+    async fn delete(&self, id: &str) -> Result<()> {
+        Users::delete(self, id).await
+    }
+    async fn delete_if_exists(&self, id: &str) -> Result<bool> {
+        Users::delete_if_exists(self, id).await
+    }
+    async fn patch(&self, id: &str, body: &crate::types::PatchUsersRequest) -> Result<()> {
+        Users::patch(self, id, body).await
+    }
+    async fn get_page(
+        &self,
+        start: &str,
+        page_size: f64,
+        department_id: &str,
+        location_id: &str,
+    ) -> Result<Vec<crate::types::User>> {
+        Users::get_page(self, start, page_size, department_id, location_id).await
+    }
+    async fn get_all(
+        &self,
+        department_id: &str,
+        location_id: &str,
+    ) -> Result<Vec<crate::types::User>> {
+        Users::get_all(self, department_id, location_id).await
+    }
+    async fn post_deferred(
+        &self,
+        body: &crate::types::PostUsersDeferredRequest,
+    ) -> Result<crate::types::User> {
+        Users::post_deferred(self, body).await
+    }
+    async fn get_deferred_status(
+        &self,
+        id: &str,
+    ) -> Result<crate::types::GetUsersDeferredStatusResponse> {
+        Users::get_deferred_status(self, id).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Account {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Account {
         Account { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Account {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get Account.
      *
@@ -21,6 +27,26 @@ impl Account {
      */
     pub async fn get(&self) -> Result<crate::types::Account> {
         let url = "/account".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/account", &[]))
+    }
+}
+
+/// The functions of [`Account`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AccountApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AccountApi: Send + Sync {
+    async fn get(&self) -> Result<crate::types::Account>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AccountApi for Account {
+    async fn get(&self) -> Result<crate::types::Account> {
+        Account::get(self).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Captions {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Captions {
         Captions { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Captions {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get Captions.
      *
@@ -34,19 +40,47 @@ impl Captions {
         accept: crate::types::Accept,
         speaker_channel: i64,
     ) -> Result<String> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if speaker_channel > 0 {
-            query_args.push(("speaker_channel".to_string(), speaker_channel.to_string()));
+            query_args.push(("speaker_channel", speaker_channel.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/jobs/{}/captions?{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/jobs/{}/captions", self.client.encode_path(id)),
+            &query_args,
         );
 
         self.client
             .request_with_accept_mime(reqwest::Method::GET, &url, &accept.to_string())
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("GET", "/jobs/{id}/captions", &[("id", id.to_string())])
+            })
+    }
+}
+
+/// The functions of [`Captions`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn CaptionsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait CaptionsApi: Send + Sync {
+    async fn get(
+        &self,
+        id: &str,
+        accept: crate::types::Accept,
+        speaker_channel: i64,
+    ) -> Result<String>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl CaptionsApi for Captions {
+    async fn get(
+        &self,
+        id: &str,
+        accept: crate::types::Accept,
+        speaker_channel: i64,
+    ) -> Result<String> {
+        Captions::get(self, id, accept, speaker_channel).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Jobs {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Jobs {
         Jobs { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Jobs {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get List of Jobs.
      *
@@ -29,17 +35,19 @@ impl Jobs {
         limit: i64,
         starting_after: &str,
     ) -> Result<Vec<crate::types::JobAllOf>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/jobs?{}", query_);
+        let url = crate::Client::url_with_query("/jobs", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/jobs", &[]))
     }
 
     /**
@@ -55,14 +63,16 @@ impl Jobs {
         &self,
         starting_after: &str,
     ) -> Result<Vec<crate::types::JobAllOf>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !starting_after.is_empty() {
-            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+            query_args.push(("starting_after", starting_after.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/jobs?{}", query_);
+        let url = crate::Client::url_with_query("/jobs", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/jobs", &[]))
     }
 
     /**
@@ -78,8 +88,9 @@ impl Jobs {
     ) -> Result<crate::types::JobAllOf> {
         let url = "/jobs".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/jobs", &[]))
     }
 
     /**
@@ -90,12 +101,11 @@ impl Jobs {
      * Returns information about a transcription job
      */
     pub async fn get(&self, id: &str) -> Result<crate::types::JobAllOf> {
-        let url = format!(
-            "/jobs/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/jobs/{}", self.client.encode_path(id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/jobs/{id}", &[("id", id.to_string())])
+        })
     }
 
     /**
@@ -106,11 +116,85 @@ impl Jobs {
      * Deletes a transcription job. All data related to the job, such as input media and transcript, will be permanently deleted. A job can only be deleted once it's completed (either with success or failure).
      */
     pub async fn delete(&self, id: &str) -> Result<()> {
-        let url = format!(
-            "/jobs/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/jobs/{}", self.client.encode_path(id),);
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("DELETE", "/jobs/{id}", &[("id", id.to_string())])
+        })
+    }
+
+    /**
+     * Delete Job by Id.
+     *
+     * This function performs a `DELETE` to the `/jobs/{id}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, id: &str) -> Result<bool> {
+        let url = format!("/jobs/{}", self.client.encode_path(id),);
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url("DELETE", "/jobs/{id}", &[("id", id.to_string())])
+            })
+    }
+}
+
+/// The functions of [`Jobs`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn JobsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait JobsApi: Send + Sync {
+    async fn get_list_of(
+        &self,
+        limit: i64,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::JobAllOf>>;
+    async fn get_all_list_all_of(
+        &self,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::JobAllOf>>;
+    async fn submit_transcription(
+        &self,
+        body: &crate::types::SubmitJobMediaUrlOptionsAllOf,
+    ) -> Result<crate::types::JobAllOf>;
+    async fn get(&self, id: &str) -> Result<crate::types::JobAllOf>;
+    async fn delete(&self, id: &str) -> Result<()>;
+    async fn delete_if_exists(&self, id: &str) -> Result<bool>;
+}
 
-        self.client.delete(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl JobsApi for Jobs {
+    async fn get_list_of(
+        &self,
+        limit: i64,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::JobAllOf>> {
+        Jobs::get_list_of(self, limit, starting_after).await
+    }
+    async fn get_all_list_all_of(
+        &self,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::JobAllOf>> {
+        Jobs::get_all_list_all_of(self, starting_after).await
+    }
+    async fn submit_transcription(
+        &self,
+        body: &crate::types::SubmitJobMediaUrlOptionsAllOf,
+    ) -> Result<crate::types::JobAllOf> {
+        Jobs::submit_transcription(self, body).await
+    }
+    async fn get(&self, id: &str) -> Result<crate::types::JobAllOf> {
+        Jobs::get(self, id).await
+    }
+    async fn delete(&self, id: &str) -> Result<()> {
+        Jobs::delete(self, id).await
+    }
+    async fn delete_if_exists(&self, id: &str) -> Result<bool> {
+        Jobs::delete_if_exists(self, id).await
     }
 }
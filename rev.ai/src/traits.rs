@@ -22,6 +22,8 @@ impl JobOps for crate::jobs::Jobs {
             )
             .text("options", "{}");
 
-        self.client.post_form("/jobs", form).await
+        self.client
+            .request_form(reqwest::Method::POST, "/jobs", form)
+            .await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Transcript {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Transcript {
         Transcript { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Transcript {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get Transcript By Id.
      *
@@ -27,13 +33,30 @@ impl Transcript {
      * * `accept: crate::types::AcceptTranscript` -- MIME type specifying the transcription output format.
      */
     pub async fn get(&self, id: &str, accept: crate::types::AcceptTranscript) -> Result<String> {
-        let url = format!(
-            "/jobs/{}/transcript",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/jobs/{}/transcript", self.client.encode_path(id),);
 
         self.client
             .request_with_accept_mime(reqwest::Method::GET, &url, &accept.to_string())
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("GET", "/jobs/{id}/transcript", &[("id", id.to_string())])
+            })
+    }
+}
+
+/// The functions of [`Transcript`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn TranscriptApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait TranscriptApi: Send + Sync {
+    async fn get(&self, id: &str, accept: crate::types::AcceptTranscript) -> Result<String>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl TranscriptApi for Transcript {
+    async fn get(&self, id: &str, accept: crate::types::AcceptTranscript) -> Result<String> {
+        Transcript::get(self, id, accept).await
     }
 }
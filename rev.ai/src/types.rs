@@ -2,6 +2,33 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+/// Either one of something, or many of them. Some endpoints send back an
+/// object when there is one and an array when there are more, so this takes both.
+// Many is tried first: serde reads a struct from an array of its fields too,
+// so an array would otherwise be taken for One.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum OneOrMany<T> {
+    Many(Vec<T>),
+    One(T),
+}
+
+impl<T> OneOrMany<T> {
+    /// All of them, as a vector.
+    pub fn into_vec(self) -> Vec<T> {
+        match self {
+            OneOrMany::One(t) => vec![t],
+            OneOrMany::Many(v) => v,
+        }
+    }
+}
+
+impl<T> Default for OneOrMany<T> {
+    fn default() -> Self {
+        OneOrMany::Many(Vec::new())
+    }
+}
+
 /// Rev.ai Account Model
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Account {
@@ -51,7 +78,7 @@ pub struct DescriptionlessJobOptionsData {
     pub callback_url: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Language {
     #[serde(rename = "ar")]
     Ar,
@@ -165,6 +192,83 @@ impl Default for Language {
     }
 }
 
+impl Language {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Language::Ar => "ar",
+            Language::Bg => "bg",
+            Language::Ca => "ca",
+            Language::Cmn => "cmn",
+            Language::Cs => "cs",
+            Language::Da => "da",
+            Language::De => "de",
+            Language::El => "el",
+            Language::En => "en",
+            Language::Es => "es",
+            Language::Fi => "fi",
+            Language::Fr => "fr",
+            Language::Hi => "hi",
+            Language::Hr => "hr",
+            Language::Hu => "hu",
+            Language::It => "it",
+            Language::Ja => "ja",
+            Language::Ko => "ko",
+            Language::Lt => "lt",
+            Language::Lv => "lv",
+            Language::Ms => "ms",
+            Language::Nl => "nl",
+            Language::No => "no",
+            Language::Pl => "pl",
+            Language::Pt => "pt",
+            Language::Ro => "ro",
+            Language::Ru => "ru",
+            Language::Sk => "sk",
+            Language::Sl => "sl",
+            Language::Sv => "sv",
+            Language::Tr => "tr",
+            Language::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Language] {
+        &[
+            Language::Ar,
+            Language::Bg,
+            Language::Ca,
+            Language::Cmn,
+            Language::Cs,
+            Language::Da,
+            Language::De,
+            Language::El,
+            Language::En,
+            Language::Es,
+            Language::Fi,
+            Language::Fr,
+            Language::Hi,
+            Language::Hr,
+            Language::Hu,
+            Language::It,
+            Language::Ja,
+            Language::Ko,
+            Language::Lt,
+            Language::Lv,
+            Language::Ms,
+            Language::Nl,
+            Language::No,
+            Language::Pl,
+            Language::Pt,
+            Language::Ro,
+            Language::Ru,
+            Language::Sk,
+            Language::Sl,
+            Language::Sv,
+            Language::Tr,
+        ]
+    }
+}
+
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DescriptionlessJobOptionsDataType {
     /**
@@ -245,7 +349,7 @@ pub struct DescriptionlessJobOptionsAllOf {
 /**
  * Simple reason of why the transcription job failed. Check `failure_detail` for specific details and solutions
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Failure {
     #[serde(rename = "download_failure")]
     DownloadFailure,
@@ -295,7 +399,40 @@ impl Default for Failure {
         Failure::Noop
     }
 }
+
 impl Failure {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Failure::DownloadFailure => "download_failure",
+            Failure::DurationExceeded => "duration_exceeded",
+            Failure::DurationTooShort => "duration_too_short",
+            Failure::EmptyMedia => "empty_media",
+            Failure::InsufficientBalance => "insufficient_balance",
+            Failure::InternalProcessing => "internal_processing",
+            Failure::InvalidMedia => "invalid_media",
+            Failure::InvoicingLimitExceeded => "invoicing_limit_exceeded",
+            Failure::Transcription => "transcription",
+            Failure::Noop => "",
+            Failure::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Failure] {
+        &[
+            Failure::DownloadFailure,
+            Failure::DurationExceeded,
+            Failure::DurationTooShort,
+            Failure::EmptyMedia,
+            Failure::InsufficientBalance,
+            Failure::InternalProcessing,
+            Failure::InvalidMedia,
+            Failure::InvoicingLimitExceeded,
+            Failure::Transcription,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Failure::Noop)
     }
@@ -304,7 +441,7 @@ impl Failure {
 /**
  * Current status of the job
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Status {
     #[serde(rename = "failed")]
     Failed,
@@ -336,7 +473,24 @@ impl Default for Status {
         Status::Noop
     }
 }
+
 impl Status {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Status::Failed => "failed",
+            Status::InProgress => "in_progress",
+            Status::Transcribed => "transcribed",
+            Status::Noop => "",
+            Status::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Status] {
+        &[Status::Failed, Status::InProgress, Status::Transcribed]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Status::Noop)
     }
@@ -345,7 +499,7 @@ impl Status {
 /**
  * Type of speech recognition performed. Currently the only supported values are 'async' for asynchronous jobs and `stream` for streaming jobs
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Type {
     #[serde(rename = "async")]
     Async,
@@ -371,7 +525,22 @@ impl Default for Type {
         Type::Noop
     }
 }
+
 impl Type {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Type::Async => "async",
+            Type::Noop => "",
+            Type::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Type] {
+        &[Type::Async]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Type::Noop)
     }
@@ -704,7 +873,7 @@ pub struct SubmitJobOptionsAllOf {
 /**
  * Type of transcript element. If Rev.ai was unable to determine the spoken word, the `type` will be `unknown`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum TranscriptMonologuesElementsType {
     #[serde(rename = "punct")]
     Punct,
@@ -736,7 +905,28 @@ impl Default for TranscriptMonologuesElementsType {
         TranscriptMonologuesElementsType::Noop
     }
 }
+
 impl TranscriptMonologuesElementsType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TranscriptMonologuesElementsType::Punct => "punct",
+            TranscriptMonologuesElementsType::Text => "text",
+            TranscriptMonologuesElementsType::Unknown => "unknown",
+            TranscriptMonologuesElementsType::Noop => "",
+            TranscriptMonologuesElementsType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [TranscriptMonologuesElementsType] {
+        &[
+            TranscriptMonologuesElementsType::Punct,
+            TranscriptMonologuesElementsType::Text,
+            TranscriptMonologuesElementsType::Unknown,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, TranscriptMonologuesElementsType::Noop)
     }
@@ -835,7 +1025,7 @@ pub struct Transcript {
 /**
  * MIME type specifying the caption output format
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Accept {
     #[serde(rename = "application/x-subrip")]
     ApplicationXSubrip,
@@ -864,7 +1054,23 @@ impl Default for Accept {
         Accept::Noop
     }
 }
+
 impl Accept {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Accept::ApplicationXSubrip => "application/x-subrip",
+            Accept::TextVtt => "text/vtt",
+            Accept::Noop => "",
+            Accept::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Accept] {
+        &[Accept::ApplicationXSubrip, Accept::TextVtt]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Accept::Noop)
     }
@@ -873,7 +1079,7 @@ impl Accept {
 /**
  * MIME type specifying the transcription output format
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum AcceptTranscript {
     #[serde(rename = "application/vnd.rev.transcript.v1.0+json")]
     ApplicationVndRevTranscript0Json,
@@ -904,8 +1110,49 @@ impl Default for AcceptTranscript {
         AcceptTranscript::Noop
     }
 }
+
 impl AcceptTranscript {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AcceptTranscript::ApplicationVndRevTranscript0Json => {
+                "application/vnd.rev.transcript.v1.0+json"
+            }
+            AcceptTranscript::TextPlain => "text/plain",
+            AcceptTranscript::Noop => "",
+            AcceptTranscript::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [AcceptTranscript] {
+        &[
+            AcceptTranscript::ApplicationVndRevTranscript0Json,
+            AcceptTranscript::TextPlain,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, AcceptTranscript::Noop)
     }
 }
+
+/// The types taken and returned by the functions in [`crate::account`].
+pub mod account {
+    pub use super::Account;
+}
+
+/// The types taken and returned by the functions in [`crate::captions`].
+pub mod captions {
+    pub use super::Accept;
+}
+
+/// The types taken and returned by the functions in [`crate::jobs`].
+pub mod jobs {
+    pub use super::{JobAllOf, SubmitJobMediaUrlOptionsAllOf};
+}
+
+/// The types taken and returned by the functions in [`crate::transcript`].
+pub mod transcript {
+    pub use super::AcceptTranscript;
+}
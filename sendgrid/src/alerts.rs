@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Alerts {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Alerts {
         Alerts { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Alerts {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieve all alerts.
      *
@@ -32,7 +38,10 @@ impl Alerts {
      */
     pub async fn get_page(&self) -> Result<Vec<crate::types::GetAlertsResponse>> {
         let url = "/alerts".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/alerts", &[]))
     }
 
     /**
@@ -52,7 +61,10 @@ impl Alerts {
      */
     pub async fn get_all(&self) -> Result<Vec<crate::types::GetAlertsResponse>> {
         let url = "/alerts".to_string();
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/alerts", &[]))
     }
 
     /**
@@ -80,8 +92,9 @@ impl Alerts {
     ) -> Result<crate::types::PostAlertsResponse> {
         let url = "/alerts".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/alerts", &[]))
     }
 
     /**
@@ -103,12 +116,15 @@ impl Alerts {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn get(&self, alert_id: i64) -> Result<crate::types::GetAlertsAlertResponse> {
-        let url = format!(
-            "/alerts/{}",
-            crate::progenitor_support::encode_path(&alert_id.to_string()),
-        );
+        let url = format!("/alerts/{}", self.client.encode_path(&alert_id.to_string()),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/alerts/{alert_id}",
+                &[("alert_id", alert_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -129,12 +145,15 @@ impl Alerts {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn delete(&self, alert_id: i64) -> Result<crate::types::Help> {
-        let url = format!(
-            "/alerts/{}",
-            crate::progenitor_support::encode_path(&alert_id.to_string()),
-        );
+        let url = format!("/alerts/{}", self.client.encode_path(&alert_id.to_string()),);
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/alerts/{alert_id}",
+                &[("alert_id", alert_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -159,13 +178,95 @@ impl Alerts {
         alert_id: i64,
         body: &crate::types::PatchAlertsAlertRequest,
     ) -> Result<crate::types::GetAlertsAlertResponse> {
-        let url = format!(
-            "/alerts/{}",
-            crate::progenitor_support::encode_path(&alert_id.to_string()),
-        );
+        let url = format!("/alerts/{}", self.client.encode_path(&alert_id.to_string()),);
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/alerts/{alert_id}",
+                    &[("alert_id", alert_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update an alert.
+     *
+     * This function performs a `PATCH` to the `/alerts/{alert_id}` endpoint.
+     *
+     * As opposed to `patch`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_if_changed<C: serde::Serialize>(
+        &self,
+        alert_id: i64,
+        current: &C,
+        body: &crate::types::PatchAlertsAlertRequest,
+    ) -> Result<Option<crate::types::GetAlertsAlertResponse>> {
+        let url = format!("/alerts/{}", self.client.encode_path(&alert_id.to_string()),);
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/alerts/{alert_id}",
+                    &[("alert_id", alert_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`Alerts`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AlertsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AlertsApi: Send + Sync {
+    async fn get_page(&self) -> Result<Vec<crate::types::GetAlertsResponse>>;
+    async fn get_all(&self) -> Result<Vec<crate::types::GetAlertsResponse>>;
+    async fn post(
+        &self,
+        body: &crate::types::PostAlertsRequest,
+    ) -> Result<crate::types::PostAlertsResponse>;
+    async fn get(&self, alert_id: i64) -> Result<crate::types::GetAlertsAlertResponse>;
+    async fn delete(&self, alert_id: i64) -> Result<crate::types::Help>;
+    async fn patch(
+        &self,
+        alert_id: i64,
+        body: &crate::types::PatchAlertsAlertRequest,
+    ) -> Result<crate::types::GetAlertsAlertResponse>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AlertsApi for Alerts {
+    async fn get_page(&self) -> Result<Vec<crate::types::GetAlertsResponse>> {
+        Alerts::get_page(self).await
+    }
+    async fn get_all(&self) -> Result<Vec<crate::types::GetAlertsResponse>> {
+        Alerts::get_all(self).await
+    }
+    async fn post(
+        &self,
+        body: &crate::types::PostAlertsRequest,
+    ) -> Result<crate::types::PostAlertsResponse> {
+        Alerts::post(self, body).await
+    }
+    async fn get(&self, alert_id: i64) -> Result<crate::types::GetAlertsAlertResponse> {
+        Alerts::get(self, alert_id).await
+    }
+    async fn delete(&self, alert_id: i64) -> Result<crate::types::Help> {
+        Alerts::delete(self, alert_id).await
+    }
+    async fn patch(
+        &self,
+        alert_id: i64,
+        body: &crate::types::PatchAlertsAlertRequest,
+    ) -> Result<crate::types::GetAlertsAlertResponse> {
+        Alerts::patch(self, alert_id, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct ApiKeyPermissions {
     pub client: Client,
@@ -12,6 +10,14 @@ impl ApiKeyPermissions {
         ApiKeyPermissions { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        ApiKeyPermissions {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieve a list of scopes for which this user has access.
      *
@@ -33,6 +39,26 @@ impl ApiKeyPermissions {
      */
     pub async fn get_scopes(&self) -> Result<crate::types::GetScopesResponse> {
         let url = "/scopes".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/scopes", &[]))
+    }
+}
+
+/// The functions of [`ApiKeyPermissions`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ApiKeyPermissionsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ApiKeyPermissionsApi: Send + Sync {
+    async fn get_scopes(&self) -> Result<crate::types::GetScopesResponse>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ApiKeyPermissionsApi for ApiKeyPermissions {
+    async fn get_scopes(&self) -> Result<crate::types::GetScopesResponse> {
+        ApiKeyPermissions::get_scopes(self).await
     }
 }
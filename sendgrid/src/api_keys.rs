@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct ApiKeys {
     pub client: Client,
@@ -12,6 +10,14 @@ impl ApiKeys {
         ApiKeys { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        ApiKeys {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieve all API Keys belonging to the authenticated user.
      *
@@ -31,14 +37,16 @@ impl ApiKeys {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn get(&self, limit: i64) -> Result<crate::types::GetApiKeysResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api_keys?{}", query_);
+        let url = crate::Client::url_with_query("/api_keys", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/api_keys", &[]))
     }
 
     /**
@@ -70,8 +78,9 @@ impl ApiKeys {
     ) -> Result<crate::types::CreateApiKeysResponse> {
         let url = "/api_keys".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/api_keys", &[]))
     }
 
     /**
@@ -90,12 +99,15 @@ impl ApiKeys {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn get_key(&self, api_key_id: &str) -> Result<crate::types::GetApiKeysKeyResponse> {
-        let url = format!(
-            "/api_keys/{}",
-            crate::progenitor_support::encode_path(&api_key_id.to_string()),
-        );
+        let url = format!("/api_keys/{}", self.client.encode_path(api_key_id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api_keys/{api_key_id}",
+                &[("api_key_id", api_key_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -120,14 +132,18 @@ impl ApiKeys {
         api_key_id: &str,
         body: &crate::types::PutApiKeysKeyRequest,
     ) -> Result<crate::types::ApiKeyNameScopesAllOf> {
-        let url = format!(
-            "/api_keys/{}",
-            crate::progenitor_support::encode_path(&api_key_id.to_string()),
-        );
+        let url = format!("/api_keys/{}", self.client.encode_path(api_key_id),);
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/api_keys/{api_key_id}",
+                    &[("api_key_id", api_key_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -144,12 +160,37 @@ impl ApiKeys {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn delete_key(&self, api_key_id: &str) -> Result<()> {
-        let url = format!(
-            "/api_keys/{}",
-            crate::progenitor_support::encode_path(&api_key_id.to_string()),
-        );
+        let url = format!("/api_keys/{}", self.client.encode_path(api_key_id),);
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/api_keys/{api_key_id}",
+                &[("api_key_id", api_key_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete API keys.
+     *
+     * This function performs a `DELETE` to the `/api_keys/{api_key_id}` endpoint.
+     *
+     * As opposed to `delete_key`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_key_if_exists(&self, api_key_id: &str) -> Result<bool> {
+        let url = format!("/api_keys/{}", self.client.encode_path(api_key_id),);
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/api_keys/{api_key_id}",
+                    &[("api_key_id", api_key_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -170,13 +211,107 @@ impl ApiKeys {
         api_key_id: &str,
         body: &crate::types::IpPool,
     ) -> Result<crate::types::ApiKeyNameId> {
-        let url = format!(
-            "/api_keys/{}",
-            crate::progenitor_support::encode_path(&api_key_id.to_string()),
-        );
+        let url = format!("/api_keys/{}", self.client.encode_path(api_key_id),);
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/api_keys/{api_key_id}",
+                    &[("api_key_id", api_key_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update API key name.
+     *
+     * This function performs a `PATCH` to the `/api_keys/{api_key_id}` endpoint.
+     *
+     * As opposed to `patch_key`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_key_if_changed<C: serde::Serialize>(
+        &self,
+        api_key_id: &str,
+        current: &C,
+        body: &crate::types::IpPool,
+    ) -> Result<Option<crate::types::ApiKeyNameId>> {
+        let url = format!("/api_keys/{}", self.client.encode_path(api_key_id),);
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/api_keys/{api_key_id}",
+                    &[("api_key_id", api_key_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`ApiKeys`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ApiKeysApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ApiKeysApi: Send + Sync {
+    async fn get(&self, limit: i64) -> Result<crate::types::GetApiKeysResponse>;
+    async fn create(
+        &self,
+        body: &crate::types::CreateApiKeysRequest,
+    ) -> Result<crate::types::CreateApiKeysResponse>;
+    async fn get_key(&self, api_key_id: &str) -> Result<crate::types::GetApiKeysKeyResponse>;
+    async fn put_key(
+        &self,
+        api_key_id: &str,
+        body: &crate::types::PutApiKeysKeyRequest,
+    ) -> Result<crate::types::ApiKeyNameScopesAllOf>;
+    async fn delete_key(&self, api_key_id: &str) -> Result<()>;
+    async fn delete_key_if_exists(&self, api_key_id: &str) -> Result<bool>;
+    async fn patch_key(
+        &self,
+        api_key_id: &str,
+        body: &crate::types::IpPool,
+    ) -> Result<crate::types::ApiKeyNameId>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ApiKeysApi for ApiKeys {
+    async fn get(&self, limit: i64) -> Result<crate::types::GetApiKeysResponse> {
+        ApiKeys::get(self, limit).await
+    }
+    async fn create(
+        &self,
+        body: &crate::types::CreateApiKeysRequest,
+    ) -> Result<crate::types::CreateApiKeysResponse> {
+        ApiKeys::create(self, body).await
+    }
+    async fn get_key(&self, api_key_id: &str) -> Result<crate::types::GetApiKeysKeyResponse> {
+        ApiKeys::get_key(self, api_key_id).await
+    }
+    async fn put_key(
+        &self,
+        api_key_id: &str,
+        body: &crate::types::PutApiKeysKeyRequest,
+    ) -> Result<crate::types::ApiKeyNameScopesAllOf> {
+        ApiKeys::put_key(self, api_key_id, body).await
+    }
+    async fn delete_key(&self, api_key_id: &str) -> Result<()> {
+        ApiKeys::delete_key(self, api_key_id).await
+    }
+    async fn delete_key_if_exists(&self, api_key_id: &str) -> Result<bool> {
+        ApiKeys::delete_key_if_exists(self, api_key_id).await
+    }
+    async fn patch_key(
+        &self,
+        api_key_id: &str,
+        body: &crate::types::IpPool,
+    ) -> Result<crate::types::ApiKeyNameId> {
+        ApiKeys::patch_key(self, api_key_id, body).await
     }
 }
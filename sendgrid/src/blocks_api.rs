@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct BlocksApi {
     pub client: Client,
@@ -12,6 +10,14 @@ impl BlocksApi {
         BlocksApi { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        BlocksApi {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieve all blocks.
      *
@@ -34,23 +40,25 @@ impl BlocksApi {
         limit: i64,
         offset: i64,
     ) -> Result<Vec<crate::types::BlocksResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if end_time > 0 {
-            query_args.push(("end_time".to_string(), end_time.to_string()));
+            query_args.push(("end_time", end_time.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if start_time > 0 {
-            query_args.push(("start_time".to_string(), start_time.to_string()));
+            query_args.push(("start_time", start_time.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/suppression/blocks?{}", query_);
+        let url = crate::Client::url_with_query("/suppression/blocks", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/suppression/blocks", &[]))
     }
 
     /**
@@ -68,20 +76,22 @@ impl BlocksApi {
         end_time: i64,
         offset: i64,
     ) -> Result<Vec<crate::types::BlocksResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if end_time > 0 {
-            query_args.push(("end_time".to_string(), end_time.to_string()));
+            query_args.push(("end_time", end_time.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if start_time > 0 {
-            query_args.push(("start_time".to_string(), start_time.to_string()));
+            query_args.push(("start_time", start_time.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/suppression/blocks?{}", query_);
+        let url = crate::Client::url_with_query("/suppression/blocks", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/suppression/blocks", &[]))
     }
 
     /**
@@ -106,8 +116,9 @@ impl BlocksApi {
     ) -> Result<crate::types::Help> {
         let url = "/suppression/blocks".to_string();
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .delete(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("DELETE", "/suppression/blocks", &[]))
     }
 
     /**
@@ -125,12 +136,15 @@ impl BlocksApi {
         &self,
         email: &str,
     ) -> Result<Vec<crate::types::BlocksResponse>> {
-        let url = format!(
-            "/suppression/blocks/{}",
-            crate::progenitor_support::encode_path(&email.to_string()),
-        );
+        let url = format!("/suppression/blocks/{}", self.client.encode_path(email),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/suppression/blocks/{email}",
+                &[("email", email.to_string())],
+            )
+        })
     }
 
     /**
@@ -146,12 +160,18 @@ impl BlocksApi {
         &self,
         email: &str,
     ) -> Result<Vec<crate::types::BlocksResponse>> {
-        let url = format!(
-            "/suppression/blocks/{}",
-            crate::progenitor_support::encode_path(&email.to_string()),
-        );
+        let url = format!("/suppression/blocks/{}", self.client.encode_path(email),);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/suppression/blocks/{email}",
+                    &[("email", email.to_string())],
+                )
+            })
     }
 
     /**
@@ -166,11 +186,91 @@ impl BlocksApi {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn delete_suppression_blocks_email(&self, email: &str) -> Result<crate::types::Help> {
-        let url = format!(
-            "/suppression/blocks/{}",
-            crate::progenitor_support::encode_path(&email.to_string()),
-        );
+        let url = format!("/suppression/blocks/{}", self.client.encode_path(email),);
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/suppression/blocks/{email}",
+                &[("email", email.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`BlocksApi`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn BlocksApiApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait BlocksApiApi: Send + Sync {
+    async fn get_suppression_blocks(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<crate::types::BlocksResponse>>;
+    async fn get_all_suppression_blocks(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        offset: i64,
+    ) -> Result<Vec<crate::types::BlocksResponse>>;
+    async fn delete_suppression_blocks(
+        &self,
+        body: &crate::types::DeleteSuppressionBlocksRequest,
+    ) -> Result<crate::types::Help>;
+    async fn get_suppression_blocks_email(
+        &self,
+        email: &str,
+    ) -> Result<Vec<crate::types::BlocksResponse>>;
+    async fn get_all_suppression_blocks_email(
+        &self,
+        email: &str,
+    ) -> Result<Vec<crate::types::BlocksResponse>>;
+    async fn delete_suppression_blocks_email(&self, email: &str) -> Result<crate::types::Help>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl BlocksApiApi for BlocksApi {
+    async fn get_suppression_blocks(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<crate::types::BlocksResponse>> {
+        BlocksApi::get_suppression_blocks(self, start_time, end_time, limit, offset).await
+    }
+    async fn get_all_suppression_blocks(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        offset: i64,
+    ) -> Result<Vec<crate::types::BlocksResponse>> {
+        BlocksApi::get_all_suppression_blocks(self, start_time, end_time, offset).await
+    }
+    async fn delete_suppression_blocks(
+        &self,
+        body: &crate::types::DeleteSuppressionBlocksRequest,
+    ) -> Result<crate::types::Help> {
+        BlocksApi::delete_suppression_blocks(self, body).await
+    }
+    async fn get_suppression_blocks_email(
+        &self,
+        email: &str,
+    ) -> Result<Vec<crate::types::BlocksResponse>> {
+        BlocksApi::get_suppression_blocks_email(self, email).await
+    }
+    async fn get_all_suppression_blocks_email(
+        &self,
+        email: &str,
+    ) -> Result<Vec<crate::types::BlocksResponse>> {
+        BlocksApi::get_all_suppression_blocks_email(self, email).await
+    }
+    async fn delete_suppression_blocks_email(&self, email: &str) -> Result<crate::types::Help> {
+        BlocksApi::delete_suppression_blocks_email(self, email).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct BouncesApi {
     pub client: Client,
@@ -12,6 +10,14 @@ impl BouncesApi {
         BouncesApi { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        BouncesApi {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieve all bounces.
      *
@@ -31,17 +37,19 @@ impl BouncesApi {
         start_time: i64,
         end_time: i64,
     ) -> Result<Vec<crate::types::BounceResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if end_time > 0 {
-            query_args.push(("end_time".to_string(), end_time.to_string()));
+            query_args.push(("end_time", end_time.to_string()));
         }
         if start_time > 0 {
-            query_args.push(("start_time".to_string(), start_time.to_string()));
+            query_args.push(("start_time", start_time.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/suppression/bounces?{}", query_);
+        let url = crate::Client::url_with_query("/suppression/bounces", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/suppression/bounces", &[]))
     }
 
     /**
@@ -58,17 +66,19 @@ impl BouncesApi {
         start_time: i64,
         end_time: i64,
     ) -> Result<Vec<crate::types::BounceResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if end_time > 0 {
-            query_args.push(("end_time".to_string(), end_time.to_string()));
+            query_args.push(("end_time", end_time.to_string()));
         }
         if start_time > 0 {
-            query_args.push(("start_time".to_string(), start_time.to_string()));
+            query_args.push(("start_time", start_time.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/suppression/bounces?{}", query_);
+        let url = crate::Client::url_with_query("/suppression/bounces", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/suppression/bounces", &[]))
     }
 
     /**
@@ -93,8 +103,27 @@ impl BouncesApi {
     ) -> Result<()> {
         let url = "/suppression/bounces".to_string();
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .delete(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("DELETE", "/suppression/bounces", &[]))
+    }
+
+    /**
+     * Delete bounces.
+     *
+     * This function performs a `DELETE` to the `/suppression/bounces` endpoint.
+     *
+     * As opposed to `delete_suppression_bounces`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_suppression_bounces_if_exists(
+        &self,
+        body: &crate::types::DeleteSuppressionBouncesRequest,
+    ) -> Result<bool> {
+        let url = "/suppression/bounces".to_string();
+        self.client
+            .delete_if_exists(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| crate::utils::pretty_url("DELETE", "/suppression/bounces", &[]))
     }
 
     /**
@@ -112,12 +141,15 @@ impl BouncesApi {
         &self,
         email: &str,
     ) -> Result<Vec<crate::types::BounceResponse>> {
-        let url = format!(
-            "/suppression/bounces/{}",
-            crate::progenitor_support::encode_path(&email.to_string()),
-        );
+        let url = format!("/suppression/bounces/{}", self.client.encode_path(email),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/suppression/bounces/{email}",
+                &[("email", email.to_string())],
+            )
+        })
     }
 
     /**
@@ -133,12 +165,18 @@ impl BouncesApi {
         &self,
         email: &str,
     ) -> Result<Vec<crate::types::BounceResponse>> {
-        let url = format!(
-            "/suppression/bounces/{}",
-            crate::progenitor_support::encode_path(&email.to_string()),
-        );
+        let url = format!("/suppression/bounces/{}", self.client.encode_path(email),);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/suppression/bounces/{email}",
+                    &[("email", email.to_string())],
+                )
+            })
     }
 
     /**
@@ -159,19 +197,115 @@ impl BouncesApi {
         email_address: &str,
         body: &serde_json::Value,
     ) -> Result<crate::types::Help> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !email_address.is_empty() {
-            query_args.push(("email_address".to_string(), email_address.to_string()));
+            query_args.push(("email_address", email_address.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/suppression/bounces/{}?{}",
-            crate::progenitor_support::encode_path(&email.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/suppression/bounces/{}", self.client.encode_path(email)),
+            &query_args,
         );
 
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .delete(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/suppression/bounces/{email}",
+                    &[("email", email.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`BouncesApi`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn BouncesApiApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait BouncesApiApi: Send + Sync {
+    async fn get_suppression_bounces(
+        &self,
+        start_time: i64,
+        end_time: i64,
+    ) -> Result<Vec<crate::types::BounceResponse>>;
+    async fn get_all_suppression_bounces(
+        &self,
+        start_time: i64,
+        end_time: i64,
+    ) -> Result<Vec<crate::types::BounceResponse>>;
+    async fn delete_suppression_bounces(
+        &self,
+        body: &crate::types::DeleteSuppressionBouncesRequest,
+    ) -> Result<()>;
+    async fn delete_suppression_bounces_if_exists(
+        &self,
+        body: &crate::types::DeleteSuppressionBouncesRequest,
+    ) -> Result<bool>;
+    async fn get_suppression_bounces_email(
+        &self,
+        email: &str,
+    ) -> Result<Vec<crate::types::BounceResponse>>;
+    async fn get_all_suppression_bounces_email(
+        &self,
+        email: &str,
+    ) -> Result<Vec<crate::types::BounceResponse>>;
+    async fn delete_suppression_bounces_email(
+        &self,
+        email: &str,
+        email_address: &str,
+        body: &serde_json::Value,
+    ) -> Result<crate::types::Help>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl BouncesApiApi for BouncesApi {
+    async fn get_suppression_bounces(
+        &self,
+        start_time: i64,
+        end_time: i64,
+    ) -> Result<Vec<crate::types::BounceResponse>> {
+        BouncesApi::get_suppression_bounces(self, start_time, end_time).await
+    }
+    async fn get_all_suppression_bounces(
+        &self,
+        start_time: i64,
+        end_time: i64,
+    ) -> Result<Vec<crate::types::BounceResponse>> {
+        BouncesApi::get_all_suppression_bounces(self, start_time, end_time).await
+    }
+    async fn delete_suppression_bounces(
+        &self,
+        body: &crate::types::DeleteSuppressionBouncesRequest,
+    ) -> Result<()> {
+        BouncesApi::delete_suppression_bounces(self, body).await
+    }
+    async fn delete_suppression_bounces_if_exists(
+        &self,
+        body: &crate::types::DeleteSuppressionBouncesRequest,
+    ) -> Result<bool> {
+        BouncesApi::delete_suppression_bounces_if_exists(self, body).await
+    }
+    async fn get_suppression_bounces_email(
+        &self,
+        email: &str,
+    ) -> Result<Vec<crate::types::BounceResponse>> {
+        BouncesApi::get_suppression_bounces_email(self, email).await
+    }
+    async fn get_all_suppression_bounces_email(
+        &self,
+        email: &str,
+    ) -> Result<Vec<crate::types::BounceResponse>> {
+        BouncesApi::get_all_suppression_bounces_email(self, email).await
+    }
+    async fn delete_suppression_bounces_email(
+        &self,
+        email: &str,
+        email_address: &str,
+        body: &serde_json::Value,
+    ) -> Result<crate::types::Help> {
+        BouncesApi::delete_suppression_bounces_email(self, email, email_address, body).await
     }
 }
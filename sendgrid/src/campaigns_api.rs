@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct CampaignsApi {
     pub client: Client,
@@ -12,6 +10,14 @@ impl CampaignsApi {
         CampaignsApi { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        CampaignsApi {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieve all Campaigns.
      *
@@ -34,17 +40,19 @@ impl CampaignsApi {
         limit: i64,
         offset: i64,
     ) -> Result<crate::types::GetCampaignsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/campaigns?{}", query_);
+        let url = crate::Client::url_with_query("/campaigns", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/campaigns", &[]))
     }
 
     /**
@@ -66,8 +74,9 @@ impl CampaignsApi {
     ) -> Result<crate::types::CampaignResponseAllOf> {
         let url = "/campaigns".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/campaigns", &[]))
     }
 
     /**
@@ -87,10 +96,16 @@ impl CampaignsApi {
     ) -> Result<crate::types::GetCampaignsCampaignResponse> {
         let url = format!(
             "/campaigns/{}",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
+            self.client.encode_path(&campaign_id.to_string()),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/campaigns/{campaign_id}",
+                &[("campaign_id", campaign_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -107,10 +122,41 @@ impl CampaignsApi {
     pub async fn delete_campaigns_campaign(&self, campaign_id: i64) -> Result<()> {
         let url = format!(
             "/campaigns/{}",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
+            self.client.encode_path(&campaign_id.to_string()),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/campaigns/{campaign_id}",
+                &[("campaign_id", campaign_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete a Campaign.
+     *
+     * This function performs a `DELETE` to the `/campaigns/{campaign_id}` endpoint.
+     *
+     * As opposed to `delete_campaigns_campaign`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_campaigns_campaign_if_exists(&self, campaign_id: i64) -> Result<bool> {
+        let url = format!(
+            "/campaigns/{}",
+            self.client.encode_path(&campaign_id.to_string()),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/campaigns/{campaign_id}",
+                    &[("campaign_id", campaign_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -133,12 +179,49 @@ impl CampaignsApi {
     ) -> Result<crate::types::CampaignResponseAllOf> {
         let url = format!(
             "/campaigns/{}",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
+            self.client.encode_path(&campaign_id.to_string()),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/campaigns/{campaign_id}",
+                    &[("campaign_id", campaign_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update a Campaign.
+     *
+     * This function performs a `PATCH` to the `/campaigns/{campaign_id}` endpoint.
+     *
+     * As opposed to `patch_campaigns_campaign`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_campaigns_campaign_if_changed<C: serde::Serialize>(
+        &self,
+        campaign_id: i64,
+        current: &C,
+        body: &crate::types::UpdateACampaignRequest,
+    ) -> Result<Option<crate::types::CampaignResponseAllOf>> {
+        let url = format!(
+            "/campaigns/{}",
+            self.client.encode_path(&campaign_id.to_string()),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/campaigns/{campaign_id}",
+                    &[("campaign_id", campaign_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -160,10 +243,16 @@ impl CampaignsApi {
     ) -> Result<crate::types::SendACampaignResponse> {
         let url = format!(
             "/campaigns/{}/schedules/now",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
+            self.client.encode_path(&campaign_id.to_string()),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/campaigns/{campaign_id}/schedules/now",
+                &[("campaign_id", campaign_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -183,10 +272,16 @@ impl CampaignsApi {
     ) -> Result<crate::types::ScheduleACampaignRequest> {
         let url = format!(
             "/campaigns/{}/schedules",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
+            self.client.encode_path(&campaign_id.to_string()),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/campaigns/{campaign_id}/schedules",
+                &[("campaign_id", campaign_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -209,12 +304,19 @@ impl CampaignsApi {
     ) -> Result<crate::types::ScheduleACampaignResponse> {
         let url = format!(
             "/campaigns/{}/schedules",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
+            self.client.encode_path(&campaign_id.to_string()),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/campaigns/{campaign_id}/schedules",
+                    &[("campaign_id", campaign_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -234,10 +336,44 @@ impl CampaignsApi {
     pub async fn delete_campaigns_campaign_schedules(&self, campaign_id: i64) -> Result<()> {
         let url = format!(
             "/campaigns/{}/schedules",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
+            self.client.encode_path(&campaign_id.to_string()),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/campaigns/{campaign_id}/schedules",
+                &[("campaign_id", campaign_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Unschedule a Scheduled Campaign.
+     *
+     * This function performs a `DELETE` to the `/campaigns/{campaign_id}/schedules` endpoint.
+     *
+     * As opposed to `delete_campaigns_campaign_schedules`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_campaigns_campaign_schedules_if_exists(
+        &self,
+        campaign_id: i64,
+    ) -> Result<bool> {
+        let url = format!(
+            "/campaigns/{}/schedules",
+            self.client.encode_path(&campaign_id.to_string()),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/campaigns/{campaign_id}/schedules",
+                    &[("campaign_id", campaign_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -258,12 +394,49 @@ impl CampaignsApi {
     ) -> Result<crate::types::UpdateAScheduledCampaignResponse> {
         let url = format!(
             "/campaigns/{}/schedules",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
+            self.client.encode_path(&campaign_id.to_string()),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/campaigns/{campaign_id}/schedules",
+                    &[("campaign_id", campaign_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update a Scheduled Campaign.
+     *
+     * This function performs a `PATCH` to the `/campaigns/{campaign_id}/schedules` endpoint.
+     *
+     * As opposed to `patch_campaigns_campaign_schedules`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_campaigns_campaign_schedules_if_changed<C: serde::Serialize>(
+        &self,
+        campaign_id: i64,
+        current: &C,
+        body: &crate::types::ScheduleACampaignRequest,
+    ) -> Result<Option<crate::types::UpdateAScheduledCampaignResponse>> {
+        let url = format!(
+            "/campaigns/{}/schedules",
+            self.client.encode_path(&campaign_id.to_string()),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/campaigns/{campaign_id}/schedules",
+                    &[("campaign_id", campaign_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -286,11 +459,151 @@ impl CampaignsApi {
     ) -> Result<crate::types::SendATestCampaignRequest> {
         let url = format!(
             "/campaigns/{}/schedules/test",
-            crate::progenitor_support::encode_path(&campaign_id.to_string()),
+            self.client.encode_path(&campaign_id.to_string()),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/campaigns/{campaign_id}/schedules/test",
+                    &[("campaign_id", campaign_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`CampaignsApi`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn CampaignsApiApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait CampaignsApiApi: Send + Sync {
+    async fn get_campaigns(
+        &self,
+        limit: i64,
+        offset: i64,
+    ) -> Result<crate::types::GetCampaignsResponse>;
+    async fn post_campaign(
+        &self,
+        body: &crate::types::CampaignsRequest,
+    ) -> Result<crate::types::CampaignResponseAllOf>;
+    async fn get_campaigns_campaign(
+        &self,
+        campaign_id: i64,
+    ) -> Result<crate::types::GetCampaignsCampaignResponse>;
+    async fn delete_campaigns_campaign(&self, campaign_id: i64) -> Result<()>;
+    async fn delete_campaigns_campaign_if_exists(&self, campaign_id: i64) -> Result<bool>;
+    async fn patch_campaigns_campaign(
+        &self,
+        campaign_id: i64,
+        body: &crate::types::UpdateACampaignRequest,
+    ) -> Result<crate::types::CampaignResponseAllOf>;
+    async fn post_campaigns_campaign_schedules_now(
+        &self,
+        campaign_id: i64,
+    ) -> Result<crate::types::SendACampaignResponse>;
+    async fn get_campaigns_campaign_schedule(
+        &self,
+        campaign_id: i64,
+    ) -> Result<crate::types::ScheduleACampaignRequest>;
+    async fn post_campaigns_campaign_schedule(
+        &self,
+        campaign_id: i64,
+        body: &crate::types::ScheduleACampaignRequest,
+    ) -> Result<crate::types::ScheduleACampaignResponse>;
+    async fn delete_campaigns_campaign_schedules(&self, campaign_id: i64) -> Result<()>;
+    async fn delete_campaigns_campaign_schedules_if_exists(&self, campaign_id: i64)
+        -> Result<bool>;
+    async fn patch_campaigns_campaign_schedules(
+        &self,
+        campaign_id: i64,
+        body: &crate::types::ScheduleACampaignRequest,
+    ) -> Result<crate::types::UpdateAScheduledCampaignResponse>;
+    async fn post_campaigns_campaign_schedules_test(
+        &self,
+        campaign_id: i64,
+        body: &crate::types::SendATestCampaignRequest,
+    ) -> Result<crate::types::SendATestCampaignRequest>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl CampaignsApiApi for CampaignsApi {
+    async fn get_campaigns(
+        &self,
+        limit: i64,
+        offset: i64,
+    ) -> Result<crate::types::GetCampaignsResponse> {
+        CampaignsApi::get_campaigns(self, limit, offset).await
+    }
+    async fn post_campaign(
+        &self,
+        body: &crate::types::CampaignsRequest,
+    ) -> Result<crate::types::CampaignResponseAllOf> {
+        CampaignsApi::post_campaign(self, body).await
+    }
+    async fn get_campaigns_campaign(
+        &self,
+        campaign_id: i64,
+    ) -> Result<crate::types::GetCampaignsCampaignResponse> {
+        CampaignsApi::get_campaigns_campaign(self, campaign_id).await
+    }
+    async fn delete_campaigns_campaign(&self, campaign_id: i64) -> Result<()> {
+        CampaignsApi::delete_campaigns_campaign(self, campaign_id).await
+    }
+    async fn delete_campaigns_campaign_if_exists(&self, campaign_id: i64) -> Result<bool> {
+        CampaignsApi::delete_campaigns_campaign_if_exists(self, campaign_id).await
+    }
+    async fn patch_campaigns_campaign(
+        &self,
+        campaign_id: i64,
+        body: &crate::types::UpdateACampaignRequest,
+    ) -> Result<crate::types::CampaignResponseAllOf> {
+        CampaignsApi::patch_campaigns_campaign(self, campaign_id, body).await
+    }
+    async fn post_campaigns_campaign_schedules_now(
+        &self,
+        campaign_id: i64,
+    ) -> Result<crate::types::SendACampaignResponse> {
+        CampaignsApi::post_campaigns_campaign_schedules_now(self, campaign_id).await
+    }
+    async fn get_campaigns_campaign_schedule(
+        &self,
+        campaign_id: i64,
+    ) -> Result<crate::types::ScheduleACampaignRequest> {
+        CampaignsApi::get_campaigns_campaign_schedule(self, campaign_id).await
+    }
+    async fn post_campaigns_campaign_schedule(
+        &self,
+        campaign_id: i64,
+        body: &crate::types::ScheduleACampaignRequest,
+    ) -> Result<crate::types::ScheduleACampaignResponse> {
+        CampaignsApi::post_campaigns_campaign_schedule(self, campaign_id, body).await
+    }
+    async fn delete_campaigns_campaign_schedules(&self, campaign_id: i64) -> Result<()> {
+        CampaignsApi::delete_campaigns_campaign_schedules(self, campaign_id).await
+    }
+    async fn delete_campaigns_campaign_schedules_if_exists(
+        &self,
+        campaign_id: i64,
+    ) -> Result<bool> {
+        CampaignsApi::delete_campaigns_campaign_schedules_if_exists(self, campaign_id).await
+    }
+    async fn patch_campaigns_campaign_schedules(
+        &self,
+        campaign_id: i64,
+        body: &crate::types::ScheduleACampaignRequest,
+    ) -> Result<crate::types::UpdateAScheduledCampaignResponse> {
+        CampaignsApi::patch_campaigns_campaign_schedules(self, campaign_id, body).await
+    }
+    async fn post_campaigns_campaign_schedules_test(
+        &self,
+        campaign_id: i64,
+        body: &crate::types::SendATestCampaignRequest,
+    ) -> Result<crate::types::SendATestCampaignRequest> {
+        CampaignsApi::post_campaigns_campaign_schedules_test(self, campaign_id, body).await
     }
 }
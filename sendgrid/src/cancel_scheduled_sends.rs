@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct CancelScheduledSends {
     pub client: Client,
@@ -12,6 +10,14 @@ impl CancelScheduledSends {
         CancelScheduledSends { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        CancelScheduledSends {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Create a batch ID.
      *
@@ -29,7 +35,10 @@ impl CancelScheduledSends {
      */
     pub async fn post_mail_batch(&self) -> Result<crate::types::MailBatchId> {
         let url = "/mail/batch".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/mail/batch", &[]))
     }
 
     /**
@@ -49,7 +58,10 @@ impl CancelScheduledSends {
         &self,
     ) -> Result<Vec<crate::types::UserScheduledSendStatusAllOf>> {
         let url = "/user/scheduled_sends".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/user/scheduled_sends", &[]))
     }
 
     /**
@@ -67,7 +79,10 @@ impl CancelScheduledSends {
         &self,
     ) -> Result<Vec<crate::types::UserScheduledSendStatusAllOf>> {
         let url = "/user/scheduled_sends".to_string();
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/user/scheduled_sends", &[]))
     }
 
     /**
@@ -93,8 +108,9 @@ impl CancelScheduledSends {
     ) -> Result<crate::types::UserScheduledSendStatusAllOf> {
         let url = "/user/scheduled_sends".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/user/scheduled_sends", &[]))
     }
 
     /**
@@ -115,12 +131,15 @@ impl CancelScheduledSends {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn get_mail_batch(&self, batch_id: &str) -> Result<crate::types::MailBatchId> {
-        let url = format!(
-            "/mail/batch/{}",
-            crate::progenitor_support::encode_path(&batch_id.to_string()),
-        );
+        let url = format!("/mail/batch/{}", self.client.encode_path(batch_id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/mail/batch/{batch_id}",
+                &[("batch_id", batch_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -140,10 +159,16 @@ impl CancelScheduledSends {
     ) -> Result<Vec<crate::types::UserScheduledSendStatusAllOf>> {
         let url = format!(
             "/user/scheduled_sends/{}",
-            crate::progenitor_support::encode_path(&batch_id.to_string()),
+            self.client.encode_path(batch_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/user/scheduled_sends/{batch_id}",
+                &[("batch_id", batch_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -161,10 +186,19 @@ impl CancelScheduledSends {
     ) -> Result<Vec<crate::types::UserScheduledSendStatusAllOf>> {
         let url = format!(
             "/user/scheduled_sends/{}",
-            crate::progenitor_support::encode_path(&batch_id.to_string()),
+            self.client.encode_path(batch_id),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/user/scheduled_sends/{batch_id}",
+                    &[("batch_id", batch_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -183,10 +217,44 @@ impl CancelScheduledSends {
     pub async fn delete_user_scheduled_sends_batch(&self, batch_id: &str) -> Result<()> {
         let url = format!(
             "/user/scheduled_sends/{}",
-            crate::progenitor_support::encode_path(&batch_id.to_string()),
+            self.client.encode_path(batch_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/user/scheduled_sends/{batch_id}",
+                &[("batch_id", batch_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete a cancellation or pause from a scheduled send.
+     *
+     * This function performs a `DELETE` to the `/user/scheduled_sends/{batch_id}` endpoint.
+     *
+     * As opposed to `delete_user_scheduled_sends_batch`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_user_scheduled_sends_batch_if_exists(
+        &self,
+        batch_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/user/scheduled_sends/{}",
+            self.client.encode_path(batch_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/user/scheduled_sends/{batch_id}",
+                    &[("batch_id", batch_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -209,11 +277,135 @@ impl CancelScheduledSends {
     ) -> Result<()> {
         let url = format!(
             "/user/scheduled_sends/{}",
-            crate::progenitor_support::encode_path(&batch_id.to_string()),
+            self.client.encode_path(batch_id),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/user/scheduled_sends/{batch_id}",
+                    &[("batch_id", batch_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update a scheduled send.
+     *
+     * This function performs a `PATCH` to the `/user/scheduled_sends/{batch_id}` endpoint.
+     *
+     * As opposed to `patch_user_scheduled_sends_batch`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_user_scheduled_sends_batch_if_changed<C: serde::Serialize>(
+        &self,
+        batch_id: &str,
+        current: &C,
+        body: &crate::types::UserScheduledSendStatus,
+    ) -> Result<Option<()>> {
+        let url = format!(
+            "/user/scheduled_sends/{}",
+            self.client.encode_path(batch_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/user/scheduled_sends/{batch_id}",
+                    &[("batch_id", batch_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`CancelScheduledSends`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn CancelScheduledSendsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait CancelScheduledSendsApi: Send + Sync {
+    async fn post_mail_batch(&self) -> Result<crate::types::MailBatchId>;
+    async fn get_user_scheduled_sends(
+        &self,
+    ) -> Result<Vec<crate::types::UserScheduledSendStatusAllOf>>;
+    async fn get_all_user_scheduled_sends(
+        &self,
+    ) -> Result<Vec<crate::types::UserScheduledSendStatusAllOf>>;
+    async fn post_user_scheduled_send(
+        &self,
+        body: &crate::types::CancelPauseAScheduledSendRequest,
+    ) -> Result<crate::types::UserScheduledSendStatusAllOf>;
+    async fn get_mail_batch(&self, batch_id: &str) -> Result<crate::types::MailBatchId>;
+    async fn get_user_scheduled_sends_batch(
+        &self,
+        batch_id: &str,
+    ) -> Result<Vec<crate::types::UserScheduledSendStatusAllOf>>;
+    async fn get_all_user_scheduled_sends_batch(
+        &self,
+        batch_id: &str,
+    ) -> Result<Vec<crate::types::UserScheduledSendStatusAllOf>>;
+    async fn delete_user_scheduled_sends_batch(&self, batch_id: &str) -> Result<()>;
+    async fn delete_user_scheduled_sends_batch_if_exists(&self, batch_id: &str) -> Result<bool>;
+    async fn patch_user_scheduled_sends_batch(
+        &self,
+        batch_id: &str,
+        body: &crate::types::UserScheduledSendStatus,
+    ) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl CancelScheduledSendsApi for CancelScheduledSends {
+    async fn post_mail_batch(&self) -> Result<crate::types::MailBatchId> {
+        CancelScheduledSends::post_mail_batch(self).await
+    }
+    async fn get_user_scheduled_sends(
+        &self,
+    ) -> Result<Vec<crate::types::UserScheduledSendStatusAllOf>> {
+        CancelScheduledSends::get_user_scheduled_sends(self).await
+    }
+    async fn get_all_user_scheduled_sends(
+        &self,
+    ) -> Result<Vec<crate::types::UserScheduledSendStatusAllOf>> {
+        CancelScheduledSends::get_all_user_scheduled_sends(self).await
+    }
+    async fn post_user_scheduled_send(
+        &self,
+        body: &crate::types::CancelPauseAScheduledSendRequest,
+    ) -> Result<crate::types::UserScheduledSendStatusAllOf> {
+        CancelScheduledSends::post_user_scheduled_send(self, body).await
+    }
+    async fn get_mail_batch(&self, batch_id: &str) -> Result<crate::types::MailBatchId> {
+        CancelScheduledSends::get_mail_batch(self, batch_id).await
+    }
+    async fn get_user_scheduled_sends_batch(
+        &self,
+        batch_id: &str,
+    ) -> Result<Vec<crate::types::UserScheduledSendStatusAllOf>> {
+        CancelScheduledSends::get_user_scheduled_sends_batch(self, batch_id).await
+    }
+    async fn get_all_user_scheduled_sends_batch(
+        &self,
+        batch_id: &str,
+    ) -> Result<Vec<crate::types::UserScheduledSendStatusAllOf>> {
+        CancelScheduledSends::get_all_user_scheduled_sends_batch(self, batch_id).await
+    }
+    async fn delete_user_scheduled_sends_batch(&self, batch_id: &str) -> Result<()> {
+        CancelScheduledSends::delete_user_scheduled_sends_batch(self, batch_id).await
+    }
+    async fn delete_user_scheduled_sends_batch_if_exists(&self, batch_id: &str) -> Result<bool> {
+        CancelScheduledSends::delete_user_scheduled_sends_batch_if_exists(self, batch_id).await
+    }
+    async fn patch_user_scheduled_sends_batch(
+        &self,
+        batch_id: &str,
+        body: &crate::types::UserScheduledSendStatus,
+    ) -> Result<()> {
+        CancelScheduledSends::patch_user_scheduled_sends_batch(self, batch_id, body).await
     }
 }
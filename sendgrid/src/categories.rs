@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Categories {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Categories {
         Categories { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Categories {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieve all categories.
      *
@@ -32,20 +38,22 @@ impl Categories {
         category: &str,
         offset: i64,
     ) -> Result<Vec<crate::types::GetCategoriesResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !category.is_empty() {
-            query_args.push(("category".to_string(), category.to_string()));
+            query_args.push(("category", category.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/categories?{}", query_);
+        let url = crate::Client::url_with_query("/categories", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/categories", &[]))
     }
 
     /**
@@ -62,17 +70,19 @@ impl Categories {
         category: &str,
         offset: i64,
     ) -> Result<Vec<crate::types::GetCategoriesResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !category.is_empty() {
-            query_args.push(("category".to_string(), category.to_string()));
+            query_args.push(("category", category.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/categories?{}", query_);
+        let url = crate::Client::url_with_query("/categories", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/categories", &[]))
     }
 
     /**
@@ -105,35 +115,34 @@ impl Categories {
         offset: i64,
         aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
     ) -> Result<crate::types::CategoryStats> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !sort_by_direction.to_string().is_empty() {
-            query_args.push((
-                "sort_by_direction".to_string(),
-                sort_by_direction.to_string(),
-            ));
+            query_args.push(("sort_by_direction", sort_by_direction.to_string()));
         }
         if !sort_by_metric.is_empty() {
-            query_args.push(("sort_by_metric".to_string(), sort_by_metric.to_string()));
+            query_args.push(("sort_by_metric", sort_by_metric.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/categories/stats/sums?{}", query_);
+        let url = crate::Client::url_with_query("/categories/stats/sums", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/categories/stats/sums", &[]))
     }
 
     /**
@@ -164,29 +173,31 @@ impl Categories {
         offset: i64,
         aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
     ) -> Result<Vec<crate::types::CategoryStats>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !categories.is_empty() {
-            query_args.push(("categories".to_string(), categories.to_string()));
+            query_args.push(("categories", categories.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/categories/stats?{}", query_);
+        let url = crate::Client::url_with_query("/categories/stats", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/categories/stats", &[]))
     }
 
     /**
@@ -208,25 +219,153 @@ impl Categories {
         offset: i64,
         aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
     ) -> Result<Vec<crate::types::CategoryStats>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !categories.is_empty() {
-            query_args.push(("categories".to_string(), categories.to_string()));
+            query_args.push(("categories", categories.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/categories/stats?{}", query_);
+        let url = crate::Client::url_with_query("/categories/stats", &query_args);
+
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/categories/stats", &[]))
+    }
+}
+
+/// The functions of [`Categories`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn CategoriesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait CategoriesApi: Send + Sync {
+    async fn get_page(
+        &self,
+        limit: i64,
+        category: &str,
+        offset: i64,
+    ) -> Result<Vec<crate::types::GetCategoriesResponse>>;
+    async fn get_all(
+        &self,
+        category: &str,
+        offset: i64,
+    ) -> Result<Vec<crate::types::GetCategoriesResponse>>;
+    async fn get_stats_sum(
+        &self,
+        sort_by_metric: &str,
+        sort_by_direction: crate::types::SortByDirection,
+        start_date: &str,
+        end_date: &str,
+        limit: i64,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> Result<crate::types::CategoryStats>;
+    async fn get_stats(
+        &self,
+        start_date: &str,
+        end_date: &str,
+        categories: &str,
+        limit: i64,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> Result<Vec<crate::types::CategoryStats>>;
+    async fn get_all_stats(
+        &self,
+        start_date: &str,
+        end_date: &str,
+        categories: &str,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> Result<Vec<crate::types::CategoryStats>>;
+}
 
-        self.client.get_all_pages(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl CategoriesApi for Categories {
+    async fn get_page(
+        &self,
+        limit: i64,
+        category: &str,
+        offset: i64,
+    ) -> Result<Vec<crate::types::GetCategoriesResponse>> {
+        Categories::get_page(self, limit, category, offset).await
+    }
+    async fn get_all(
+        &self,
+        category: &str,
+        offset: i64,
+    ) -> Result<Vec<crate::types::GetCategoriesResponse>> {
+        Categories::get_all(self, category, offset).await
+    }
+    async fn get_stats_sum(
+        &self,
+        sort_by_metric: &str,
+        sort_by_direction: crate::types::SortByDirection,
+        start_date: &str,
+        end_date: &str,
+        limit: i64,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> Result<crate::types::CategoryStats> {
+        Categories::get_stats_sum(
+            self,
+            sort_by_metric,
+            sort_by_direction,
+            start_date,
+            end_date,
+            limit,
+            offset,
+            aggregated_by,
+        )
+        .await
+    }
+    async fn get_stats(
+        &self,
+        start_date: &str,
+        end_date: &str,
+        categories: &str,
+        limit: i64,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> Result<Vec<crate::types::CategoryStats>> {
+        Categories::get_stats(
+            self,
+            start_date,
+            end_date,
+            categories,
+            limit,
+            offset,
+            aggregated_by,
+        )
+        .await
+    }
+    async fn get_all_stats(
+        &self,
+        start_date: &str,
+        end_date: &str,
+        categories: &str,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> Result<Vec<crate::types::CategoryStats>> {
+        Categories::get_all_stats(
+            self,
+            start_date,
+            end_date,
+            categories,
+            offset,
+            aggregated_by,
+        )
+        .await
     }
 }
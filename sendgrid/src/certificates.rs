@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Certificates {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Certificates {
         Certificates { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Certificates {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Create an SSO Certificate.
      *
@@ -25,8 +31,9 @@ impl Certificates {
     ) -> Result<crate::types::SsoCertificateBody> {
         let url = "/sso/certificates".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/sso/certificates", &[]))
     }
 
     /**
@@ -44,10 +51,16 @@ impl Certificates {
     ) -> Result<Vec<crate::types::SsoCertificateBody>> {
         let url = format!(
             "/sso/integrations/{}/certificates",
-            crate::progenitor_support::encode_path(&integration_id.to_string()),
+            self.client.encode_path(integration_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/sso/integrations/{integration_id}/certificates",
+                &[("integration_id", integration_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -67,10 +80,19 @@ impl Certificates {
     ) -> Result<Vec<crate::types::SsoCertificateBody>> {
         let url = format!(
             "/sso/integrations/{}/certificates",
-            crate::progenitor_support::encode_path(&integration_id.to_string()),
+            self.client.encode_path(integration_id),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/sso/integrations/{integration_id}/certificates",
+                    &[("integration_id", integration_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -81,12 +103,15 @@ impl Certificates {
      * **This endpoint allows you to retrieve an individual SSO certificate.**
      */
     pub async fn get_sso_cert(&self, cert_id: &str) -> Result<crate::types::SsoCertificateBody> {
-        let url = format!(
-            "/sso/certificates/{}",
-            crate::progenitor_support::encode_path(&cert_id.to_string()),
-        );
+        let url = format!("/sso/certificates/{}", self.client.encode_path(cert_id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/sso/certificates/{cert_id}",
+                &[("cert_id", cert_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -99,12 +124,15 @@ impl Certificates {
      * You can retrieve a certificate's ID from the response provided by the "Get All SSO Integrations" endpoint.
      */
     pub async fn delete_sso_cert(&self, cert_id: &str) -> Result<crate::types::SsoCertificateBody> {
-        let url = format!(
-            "/sso/certificates/{}",
-            crate::progenitor_support::encode_path(&cert_id.to_string()),
-        );
+        let url = format!("/sso/certificates/{}", self.client.encode_path(cert_id),);
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/sso/certificates/{cert_id}",
+                &[("cert_id", cert_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -121,13 +149,107 @@ impl Certificates {
         cert_id: &str,
         body: &crate::types::PatchSsoCertificatesCertRequest,
     ) -> Result<Vec<crate::types::SsoErrorResponse>> {
-        let url = format!(
-            "/sso/certificates/{}",
-            crate::progenitor_support::encode_path(&cert_id.to_string()),
-        );
+        let url = format!("/sso/certificates/{}", self.client.encode_path(cert_id),);
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/sso/certificates/{cert_id}",
+                    &[("cert_id", cert_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update SSO Certificate.
+     *
+     * This function performs a `PATCH` to the `/sso/certificates/{cert_id}` endpoint.
+     *
+     * As opposed to `patch_sso_cert`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_sso_cert_if_changed<C: serde::Serialize>(
+        &self,
+        cert_id: &str,
+        current: &C,
+        body: &crate::types::PatchSsoCertificatesCertRequest,
+    ) -> Result<Option<Vec<crate::types::SsoErrorResponse>>> {
+        let url = format!("/sso/certificates/{}", self.client.encode_path(cert_id),);
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/sso/certificates/{cert_id}",
+                    &[("cert_id", cert_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`Certificates`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn CertificatesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait CertificatesApi: Send + Sync {
+    async fn post_sso(
+        &self,
+        body: &crate::types::PostSsoCertificatesRequest,
+    ) -> Result<crate::types::SsoCertificateBody>;
+    async fn get_sso_integrations_integration(
+        &self,
+        integration_id: &str,
+    ) -> Result<Vec<crate::types::SsoCertificateBody>>;
+    async fn get_all_sso_integrations_integration(
+        &self,
+        integration_id: &str,
+    ) -> Result<Vec<crate::types::SsoCertificateBody>>;
+    async fn get_sso_cert(&self, cert_id: &str) -> Result<crate::types::SsoCertificateBody>;
+    async fn delete_sso_cert(&self, cert_id: &str) -> Result<crate::types::SsoCertificateBody>;
+    async fn patch_sso_cert(
+        &self,
+        cert_id: &str,
+        body: &crate::types::PatchSsoCertificatesCertRequest,
+    ) -> Result<Vec<crate::types::SsoErrorResponse>>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl CertificatesApi for Certificates {
+    async fn post_sso(
+        &self,
+        body: &crate::types::PostSsoCertificatesRequest,
+    ) -> Result<crate::types::SsoCertificateBody> {
+        Certificates::post_sso(self, body).await
+    }
+    async fn get_sso_integrations_integration(
+        &self,
+        integration_id: &str,
+    ) -> Result<Vec<crate::types::SsoCertificateBody>> {
+        Certificates::get_sso_integrations_integration(self, integration_id).await
+    }
+    async fn get_all_sso_integrations_integration(
+        &self,
+        integration_id: &str,
+    ) -> Result<Vec<crate::types::SsoCertificateBody>> {
+        Certificates::get_all_sso_integrations_integration(self, integration_id).await
+    }
+    async fn get_sso_cert(&self, cert_id: &str) -> Result<crate::types::SsoCertificateBody> {
+        Certificates::get_sso_cert(self, cert_id).await
+    }
+    async fn delete_sso_cert(&self, cert_id: &str) -> Result<crate::types::SsoCertificateBody> {
+        Certificates::delete_sso_cert(self, cert_id).await
+    }
+    async fn patch_sso_cert(
+        &self,
+        cert_id: &str,
+        body: &crate::types::PatchSsoCertificatesCertRequest,
+    ) -> Result<Vec<crate::types::SsoErrorResponse>> {
+        Certificates::patch_sso_cert(self, cert_id, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Contacts {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Contacts {
         Contacts { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Contacts {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get Sample Contacts.
      *
@@ -27,7 +33,10 @@ impl Contacts {
      */
     pub async fn get_mc_contats(&self) -> Result<crate::types::GetMcContatsResponse> {
         let url = "/marketing/contacts".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/marketing/contacts", &[]))
     }
 
     /**
@@ -52,9 +61,12 @@ impl Contacts {
         body: &crate::types::PutMcContactsRequest,
     ) -> Result<crate::types::PutMcContactsResponse> {
         let url = "/marketing/contacts".to_string();
+        crate::utils::check_items("contacts", body.contacts.len(), Some(1), Some(30000))?;
+
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("PUT", "/marketing/contacts", &[]))
     }
 
     /**
@@ -82,20 +94,19 @@ impl Contacts {
         delete_all_contacts: &str,
         ids: &str,
     ) -> Result<crate::types::DeleteMcContactsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !delete_all_contacts.is_empty() {
-            query_args.push((
-                "delete_all_contacts".to_string(),
-                delete_all_contacts.to_string(),
-            ));
+            query_args.push(("delete_all_contacts", delete_all_contacts.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/marketing/contacts?{}", query_);
+        let url = crate::Client::url_with_query("/marketing/contacts", &query_args);
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("DELETE", "/marketing/contacts", &[]))
     }
 
     /**
@@ -107,7 +118,10 @@ impl Contacts {
      */
     pub async fn get_mc_count(&self) -> Result<crate::types::GetMcContactsCountResponse> {
         let url = "/marketing/contacts/count".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/marketing/contacts/count", &[]))
     }
 
     /**
@@ -127,7 +141,10 @@ impl Contacts {
         &self,
     ) -> Result<crate::types::GetMarketingContactsExportsResponse> {
         let url = "/marketing/contacts/exports".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/marketing/contacts/exports", &[]))
     }
 
     /**
@@ -153,8 +170,9 @@ impl Contacts {
     ) -> Result<crate::types::PostMcContactsExportsResponse> {
         let url = "/marketing/contacts/exports".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/marketing/contacts/exports", &[]))
     }
 
     /**
@@ -167,12 +185,11 @@ impl Contacts {
      * The "Get Contacts by Emails" endpoint can be used to get the ID of a contact.
      */
     pub async fn get_mc(&self, id: &str) -> Result<crate::types::ContactDetails3> {
-        let url = format!(
-            "/marketing/contacts/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/marketing/contacts/{}", self.client.encode_path(id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/marketing/contacts/{id}", &[("id", id.to_string())])
+        })
     }
 
     /**
@@ -198,8 +215,9 @@ impl Contacts {
     ) -> Result<crate::types::PostMcContactsSearchResponse> {
         let url = "/marketing/contacts/search".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/marketing/contacts/search", &[]))
     }
 
     /**
@@ -230,9 +248,12 @@ impl Contacts {
         body: &crate::types::PutMcContactsImportsRequest,
     ) -> Result<crate::types::PutMcContactsImportsResponse> {
         let url = "/marketing/contacts/imports".to_string();
+        crate::utils::check_items("field_mappings", body.field_mappings.len(), Some(1), None)?;
+
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("PUT", "/marketing/contacts/imports", &[]))
     }
 
     /**
@@ -255,10 +276,16 @@ impl Contacts {
     pub async fn get_marketing_import(&self, id: &str) -> Result<crate::types::ContactImport> {
         let url = format!(
             "/marketing/contacts/imports/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
+            self.client.encode_path(id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/marketing/contacts/imports/{id}",
+                &[("id", id.to_string())],
+            )
+        })
     }
 
     /**
@@ -275,10 +302,16 @@ impl Contacts {
     pub async fn get_mc_export(&self, id: &str) -> Result<crate::types::ContactExport> {
         let url = format!(
             "/marketing/contacts/exports/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
+            self.client.encode_path(id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/marketing/contacts/exports/{id}",
+                &[("id", id.to_string())],
+            )
+        })
     }
 
     /**
@@ -297,9 +330,12 @@ impl Contacts {
         body: &crate::types::PostMarketingContactsBatchRequest,
     ) -> Result<crate::types::PostMarketingContactsBatchResponse> {
         let url = "/marketing/contacts/batch".to_string();
+        crate::utils::check_items("ids", body.ids.len(), None, Some(100))?;
+
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/marketing/contacts/batch", &[]))
     }
 
     /**
@@ -329,7 +365,123 @@ impl Contacts {
     ) -> Result<crate::types::PostMarketingContactsSearchEmailsResponse> {
         let url = "/marketing/contacts/search/emails".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/marketing/contacts/search/emails", &[])
+            })
+    }
+}
+
+/// The functions of [`Contacts`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ContactsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ContactsApi: Send + Sync {
+    async fn get_mc_contats(&self) -> Result<crate::types::GetMcContatsResponse>;
+    async fn put_mc(
+        &self,
+        body: &crate::types::PutMcContactsRequest,
+    ) -> Result<crate::types::PutMcContactsResponse>;
+    async fn delete_mc(
+        &self,
+        delete_all_contacts: &str,
+        ids: &str,
+    ) -> Result<crate::types::DeleteMcContactsResponse>;
+    async fn get_mc_count(&self) -> Result<crate::types::GetMcContactsCountResponse>;
+    async fn get_marketing_exports(
+        &self,
+    ) -> Result<crate::types::GetMarketingContactsExportsResponse>;
+    async fn post_mc_export(
+        &self,
+        body: &crate::types::PostMcContactsExportsRequest,
+    ) -> Result<crate::types::PostMcContactsExportsResponse>;
+    async fn get_mc(&self, id: &str) -> Result<crate::types::ContactDetails3>;
+    async fn post_mc_search(
+        &self,
+        body: &crate::types::PostMcContactsSearchRequest,
+    ) -> Result<crate::types::PostMcContactsSearchResponse>;
+    async fn put_mc_imports(
+        &self,
+        body: &crate::types::PutMcContactsImportsRequest,
+    ) -> Result<crate::types::PutMcContactsImportsResponse>;
+    async fn get_marketing_import(&self, id: &str) -> Result<crate::types::ContactImport>;
+    async fn get_mc_export(&self, id: &str) -> Result<crate::types::ContactExport>;
+    async fn post_marketing_batch(
+        &self,
+        body: &crate::types::PostMarketingContactsBatchRequest,
+    ) -> Result<crate::types::PostMarketingContactsBatchResponse>;
+    async fn post_marketing_search_email(
+        &self,
+        body: &crate::types::PostMarketingContactsSearchEmailsRequest,
+    ) -> Result<crate::types::PostMarketingContactsSearchEmailsResponse>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ContactsApi for Contacts {
+    async fn get_mc_contats(&self) -> Result<crate::types::GetMcContatsResponse> {
+        Contacts::get_mc_contats(self).await
+    }
+    async fn put_mc(
+        &self,
+        body: &crate::types::PutMcContactsRequest,
+    ) -> Result<crate::types::PutMcContactsResponse> {
+        Contacts::put_mc(self, body).await
+    }
+    async fn delete_mc(
+        &self,
+        delete_all_contacts: &str,
+        ids: &str,
+    ) -> Result<crate::types::DeleteMcContactsResponse> {
+        Contacts::delete_mc(self, delete_all_contacts, ids).await
+    }
+    async fn get_mc_count(&self) -> Result<crate::types::GetMcContactsCountResponse> {
+        Contacts::get_mc_count(self).await
+    }
+    async fn get_marketing_exports(
+        &self,
+    ) -> Result<crate::types::GetMarketingContactsExportsResponse> {
+        Contacts::get_marketing_exports(self).await
+    }
+    async fn post_mc_export(
+        &self,
+        body: &crate::types::PostMcContactsExportsRequest,
+    ) -> Result<crate::types::PostMcContactsExportsResponse> {
+        Contacts::post_mc_export(self, body).await
+    }
+    async fn get_mc(&self, id: &str) -> Result<crate::types::ContactDetails3> {
+        Contacts::get_mc(self, id).await
+    }
+    async fn post_mc_search(
+        &self,
+        body: &crate::types::PostMcContactsSearchRequest,
+    ) -> Result<crate::types::PostMcContactsSearchResponse> {
+        Contacts::post_mc_search(self, body).await
+    }
+    async fn put_mc_imports(
+        &self,
+        body: &crate::types::PutMcContactsImportsRequest,
+    ) -> Result<crate::types::PutMcContactsImportsResponse> {
+        Contacts::put_mc_imports(self, body).await
+    }
+    async fn get_marketing_import(&self, id: &str) -> Result<crate::types::ContactImport> {
+        Contacts::get_marketing_import(self, id).await
+    }
+    async fn get_mc_export(&self, id: &str) -> Result<crate::types::ContactExport> {
+        Contacts::get_mc_export(self, id).await
+    }
+    async fn post_marketing_batch(
+        &self,
+        body: &crate::types::PostMarketingContactsBatchRequest,
+    ) -> Result<crate::types::PostMarketingContactsBatchResponse> {
+        Contacts::post_marketing_batch(self, body).await
+    }
+    async fn post_marketing_search_email(
+        &self,
+        body: &crate::types::PostMarketingContactsSearchEmailsRequest,
+    ) -> Result<crate::types::PostMarketingContactsSearchEmailsResponse> {
+        Contacts::post_marketing_search_email(self, body).await
     }
 }
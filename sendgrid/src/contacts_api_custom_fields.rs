@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct ContactsApiCustomFields {
     pub client: Client,
@@ -12,6 +10,14 @@ impl ContactsApiCustomFields {
         ContactsApiCustomFields { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        ContactsApiCustomFields {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieve all custom fields.
      *
@@ -27,7 +33,10 @@ impl ContactsApiCustomFields {
         &self,
     ) -> Result<crate::types::ListAllCustomFieldsResponse> {
         let url = "/contactdb/custom_fields".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/contactdb/custom_fields", &[]))
     }
 
     /**
@@ -49,8 +58,9 @@ impl ContactsApiCustomFields {
     ) -> Result<crate::types::ContactdbCustomFieldWithAllOf> {
         let url = "/contactdb/custom_fields".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/contactdb/custom_fields", &[]))
     }
 
     /**
@@ -70,10 +80,16 @@ impl ContactsApiCustomFields {
     ) -> Result<crate::types::ContactdbCustomFieldWithAllOf> {
         let url = format!(
             "/contactdb/custom_fields/{}",
-            crate::progenitor_support::encode_path(&custom_field_id.to_string()),
+            self.client.encode_path(&custom_field_id.to_string()),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/contactdb/custom_fields/{custom_field_id}",
+                &[("custom_field_id", custom_field_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -93,10 +109,16 @@ impl ContactsApiCustomFields {
     ) -> Result<crate::types::GlobalErrorResponseSchema> {
         let url = format!(
             "/contactdb/custom_fields/{}",
-            crate::progenitor_support::encode_path(&custom_field_id.to_string()),
+            self.client.encode_path(&custom_field_id.to_string()),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/contactdb/custom_fields/{custom_field_id}",
+                &[("custom_field_id", custom_field_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -114,6 +136,68 @@ impl ContactsApiCustomFields {
         &self,
     ) -> Result<crate::types::GetContactdbReservedFieldsResponse> {
         let url = "/contactdb/reserved_fields".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/contactdb/reserved_fields", &[]))
+    }
+}
+
+/// The functions of [`ContactsApiCustomFields`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ContactsApiCustomFieldsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ContactsApiCustomFieldsApi: Send + Sync {
+    async fn get_contactdb_custom_fields(
+        &self,
+    ) -> Result<crate::types::ListAllCustomFieldsResponse>;
+    async fn post_contactdb_custom_field(
+        &self,
+        body: &crate::types::PostContactdbCustomFieldsRequest,
+    ) -> Result<crate::types::ContactdbCustomFieldWithAllOf>;
+    async fn get_contactdb_custom_fields_field(
+        &self,
+        custom_field_id: i64,
+    ) -> Result<crate::types::ContactdbCustomFieldWithAllOf>;
+    async fn delete_contactdb_custom_fields_field(
+        &self,
+        custom_field_id: i64,
+    ) -> Result<crate::types::GlobalErrorResponseSchema>;
+    async fn get_contactdb_reserved_fields(
+        &self,
+    ) -> Result<crate::types::GetContactdbReservedFieldsResponse>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ContactsApiCustomFieldsApi for ContactsApiCustomFields {
+    async fn get_contactdb_custom_fields(
+        &self,
+    ) -> Result<crate::types::ListAllCustomFieldsResponse> {
+        ContactsApiCustomFields::get_contactdb_custom_fields(self).await
+    }
+    async fn post_contactdb_custom_field(
+        &self,
+        body: &crate::types::PostContactdbCustomFieldsRequest,
+    ) -> Result<crate::types::ContactdbCustomFieldWithAllOf> {
+        ContactsApiCustomFields::post_contactdb_custom_field(self, body).await
+    }
+    async fn get_contactdb_custom_fields_field(
+        &self,
+        custom_field_id: i64,
+    ) -> Result<crate::types::ContactdbCustomFieldWithAllOf> {
+        ContactsApiCustomFields::get_contactdb_custom_fields_field(self, custom_field_id).await
+    }
+    async fn delete_contactdb_custom_fields_field(
+        &self,
+        custom_field_id: i64,
+    ) -> Result<crate::types::GlobalErrorResponseSchema> {
+        ContactsApiCustomFields::delete_contactdb_custom_fields_field(self, custom_field_id).await
+    }
+    async fn get_contactdb_reserved_fields(
+        &self,
+    ) -> Result<crate::types::GetContactdbReservedFieldsResponse> {
+        ContactsApiCustomFields::get_contactdb_reserved_fields(self).await
     }
 }
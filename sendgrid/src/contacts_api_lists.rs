@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct ContactsApiLists {
     pub client: Client,
@@ -12,6 +10,14 @@ impl ContactsApiLists {
         ContactsApiLists { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        ContactsApiLists {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieve all lists.
      *
@@ -25,7 +31,10 @@ impl ContactsApiLists {
      */
     pub async fn get_contactdb_lists(&self) -> Result<crate::types::ListAllListsResponse> {
         let url = "/contactdb/lists".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/contactdb/lists", &[]))
     }
 
     /**
@@ -45,8 +54,9 @@ impl ContactsApiLists {
     ) -> Result<crate::types::ContactdbList> {
         let url = "/contactdb/lists".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/contactdb/lists", &[]))
     }
 
     /**
@@ -63,8 +73,24 @@ impl ContactsApiLists {
     pub async fn delete_contactdb_lists(&self, body: &[i64]) -> Result<()> {
         let url = "/contactdb/lists".to_string();
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .delete(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("DELETE", "/contactdb/lists", &[]))
+    }
+
+    /**
+     * Delete Multiple lists.
+     *
+     * This function performs a `DELETE` to the `/contactdb/lists` endpoint.
+     *
+     * As opposed to `delete_contactdb_lists`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_contactdb_lists_if_exists(&self, body: &[i64]) -> Result<bool> {
+        let url = "/contactdb/lists".to_string();
+        self.client
+            .delete_if_exists(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| crate::utils::pretty_url("DELETE", "/contactdb/lists", &[]))
     }
 
     /**
@@ -83,12 +109,15 @@ impl ContactsApiLists {
         &self,
         list_id: &str,
     ) -> Result<crate::types::ContactdbList> {
-        let url = format!(
-            "/contactdb/lists/{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-        );
+        let url = format!("/contactdb/lists/{}", self.client.encode_path(list_id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/contactdb/lists/{list_id}",
+                &[("list_id", list_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -109,20 +138,59 @@ impl ContactsApiLists {
         delete_contacts: bool,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if delete_contacts {
-            query_args.push(("delete_contacts".to_string(), delete_contacts.to_string()));
+            query_args.push(("delete_contacts", delete_contacts.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/contactdb/lists/{}?{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/contactdb/lists/{}", self.client.encode_path(list_id)),
+            &query_args,
+        );
+
+        self.client
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/contactdb/lists/{list_id}",
+                    &[("list_id", list_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Delete a List.
+     *
+     * This function performs a `DELETE` to the `/contactdb/lists/{list_id}` endpoint.
+     *
+     * As opposed to `delete_contactdb_lists_list`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_contactdb_lists_list_if_exists(
+        &self,
+        list_id: &str,
+        delete_contacts: bool,
+        body: &serde_json::Value,
+    ) -> Result<bool> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if delete_contacts {
+            query_args.push(("delete_contacts", delete_contacts.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!("/contactdb/lists/{}", self.client.encode_path(list_id)),
+            &query_args,
         );
 
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .delete_if_exists(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/contactdb/lists/{list_id}",
+                    &[("list_id", list_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -142,14 +210,45 @@ impl ContactsApiLists {
         list_id: &str,
         body: &crate::types::IpPool,
     ) -> Result<crate::types::PatchContactdbListsListResponse> {
-        let url = format!(
-            "/contactdb/lists/{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-        );
+        let url = format!("/contactdb/lists/{}", self.client.encode_path(list_id),);
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/contactdb/lists/{list_id}",
+                    &[("list_id", list_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update a List.
+     *
+     * This function performs a `PATCH` to the `/contactdb/lists/{list_id}` endpoint.
+     *
+     * As opposed to `patch_contactdb_lists_list`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_contactdb_lists_list_if_changed<C: serde::Serialize>(
+        &self,
+        list_id: &str,
+        current: &C,
+        body: &crate::types::IpPool,
+    ) -> Result<Option<crate::types::PatchContactdbListsListResponse>> {
+        let url = format!("/contactdb/lists/{}", self.client.encode_path(list_id),);
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/contactdb/lists/{list_id}",
+                    &[("list_id", list_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -172,21 +271,29 @@ impl ContactsApiLists {
         page: i64,
         page_size: i64,
     ) -> Result<crate::types::GetContactdbRecipientsSearchResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/contactdb/lists/{}/recipients?{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/contactdb/lists/{}/recipients",
+                self.client.encode_path(&list_id.to_string())
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/contactdb/lists/{list_id}/recipients",
+                &[("list_id", list_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -209,12 +316,19 @@ impl ContactsApiLists {
     ) -> Result<()> {
         let url = format!(
             "/contactdb/lists/{}/recipients",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
+            self.client.encode_path(&list_id.to_string()),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/contactdb/lists/{list_id}/recipients",
+                    &[("list_id", list_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -235,11 +349,20 @@ impl ContactsApiLists {
     ) -> Result<()> {
         let url = format!(
             "/contactdb/lists/{}/recipients/{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&recipient_id.to_string()),
+            self.client.encode_path(&list_id.to_string()),
+            self.client.encode_path(recipient_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/contactdb/lists/{list_id}/recipients/{recipient_id}",
+                &[
+                    ("list_id", list_id.to_string()),
+                    ("recipient_id", recipient_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -263,12 +386,216 @@ impl ContactsApiLists {
     ) -> Result<()> {
         let url = format!(
             "/contactdb/lists/{}/recipients/{}",
-            crate::progenitor_support::encode_path(&list_id.to_string()),
-            crate::progenitor_support::encode_path(&recipient_id.to_string()),
+            self.client.encode_path(&list_id.to_string()),
+            self.client.encode_path(recipient_id),
+        );
+
+        self.client
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/contactdb/lists/{list_id}/recipients/{recipient_id}",
+                    &[
+                        ("list_id", list_id.to_string()),
+                        ("recipient_id", recipient_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * Delete a Single Recipient from a Single List.
+     *
+     * This function performs a `DELETE` to the `/contactdb/lists/{list_id}/recipients/{recipient_id}` endpoint.
+     *
+     * As opposed to `delete_contactdb_lists_list_recipients_recipient`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_contactdb_lists_list_recipients_recipient_if_exists(
+        &self,
+        list_id: i64,
+        recipient_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<bool> {
+        let url = format!(
+            "/contactdb/lists/{}/recipients/{}",
+            self.client.encode_path(&list_id.to_string()),
+            self.client.encode_path(recipient_id),
         );
 
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .delete_if_exists(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/contactdb/lists/{list_id}/recipients/{recipient_id}",
+                    &[
+                        ("list_id", list_id.to_string()),
+                        ("recipient_id", recipient_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`ContactsApiLists`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ContactsApiListsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ContactsApiListsApi: Send + Sync {
+    async fn get_contactdb_lists(&self) -> Result<crate::types::ListAllListsResponse>;
+    async fn post_contactdb_list(
+        &self,
+        body: &crate::types::IpPool,
+    ) -> Result<crate::types::ContactdbList>;
+    async fn delete_contactdb_lists(&self, body: &[i64]) -> Result<()>;
+    async fn delete_contactdb_lists_if_exists(&self, body: &[i64]) -> Result<bool>;
+    async fn get_contactdb_lists_list(&self, list_id: &str) -> Result<crate::types::ContactdbList>;
+    async fn delete_contactdb_lists_list(
+        &self,
+        list_id: &str,
+        delete_contacts: bool,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn delete_contactdb_lists_list_if_exists(
+        &self,
+        list_id: &str,
+        delete_contacts: bool,
+        body: &serde_json::Value,
+    ) -> Result<bool>;
+    async fn patch_contactdb_lists_list(
+        &self,
+        list_id: &str,
+        body: &crate::types::IpPool,
+    ) -> Result<crate::types::PatchContactdbListsListResponse>;
+    async fn get_contactdb_lists_list_recipients(
+        &self,
+        list_id: i64,
+        page: i64,
+        page_size: i64,
+    ) -> Result<crate::types::GetContactdbRecipientsSearchResponse>;
+    async fn post_contactdb_lists_list_recipient(&self, list_id: i64, body: &[i64]) -> Result<()>;
+    async fn post_contactdb_lists_list_recipients_recipient(
+        &self,
+        list_id: i64,
+        recipient_id: &str,
+    ) -> Result<()>;
+    async fn delete_contactdb_lists_list_recipients_recipient(
+        &self,
+        list_id: i64,
+        recipient_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn delete_contactdb_lists_list_recipients_recipient_if_exists(
+        &self,
+        list_id: i64,
+        recipient_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ContactsApiListsApi for ContactsApiLists {
+    async fn get_contactdb_lists(&self) -> Result<crate::types::ListAllListsResponse> {
+        ContactsApiLists::get_contactdb_lists(self).await
+    }
+    async fn post_contactdb_list(
+        &self,
+        body: &crate::types::IpPool,
+    ) -> Result<crate::types::ContactdbList> {
+        ContactsApiLists::post_contactdb_list(self, body).await
+    }
+    async fn delete_contactdb_lists(&self, body: &[i64]) -> Result<()> {
+        ContactsApiLists::delete_contactdb_lists(self, body).await
+    }
+    async fn delete_contactdb_lists_if_exists(&self, body: &[i64]) -> Result<bool> {
+        ContactsApiLists::delete_contactdb_lists_if_exists(self, body).await
+    }
+    async fn get_contactdb_lists_list(&self, list_id: &str) -> Result<crate::types::ContactdbList> {
+        ContactsApiLists::get_contactdb_lists_list(self, list_id).await
+    }
+    async fn delete_contactdb_lists_list(
+        &self,
+        list_id: &str,
+        delete_contacts: bool,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ContactsApiLists::delete_contactdb_lists_list(self, list_id, delete_contacts, body).await
+    }
+    async fn delete_contactdb_lists_list_if_exists(
+        &self,
+        list_id: &str,
+        delete_contacts: bool,
+        body: &serde_json::Value,
+    ) -> Result<bool> {
+        ContactsApiLists::delete_contactdb_lists_list_if_exists(
+            self,
+            list_id,
+            delete_contacts,
+            body,
+        )
+        .await
+    }
+    async fn patch_contactdb_lists_list(
+        &self,
+        list_id: &str,
+        body: &crate::types::IpPool,
+    ) -> Result<crate::types::PatchContactdbListsListResponse> {
+        ContactsApiLists::patch_contactdb_lists_list(self, list_id, body).await
+    }
+    async fn get_contactdb_lists_list_recipients(
+        &self,
+        list_id: i64,
+        page: i64,
+        page_size: i64,
+    ) -> Result<crate::types::GetContactdbRecipientsSearchResponse> {
+        ContactsApiLists::get_contactdb_lists_list_recipients(self, list_id, page, page_size).await
+    }
+    async fn post_contactdb_lists_list_recipient(&self, list_id: i64, body: &[i64]) -> Result<()> {
+        ContactsApiLists::post_contactdb_lists_list_recipient(self, list_id, body).await
+    }
+    async fn post_contactdb_lists_list_recipients_recipient(
+        &self,
+        list_id: i64,
+        recipient_id: &str,
+    ) -> Result<()> {
+        ContactsApiLists::post_contactdb_lists_list_recipients_recipient(
+            self,
+            list_id,
+            recipient_id,
+        )
+        .await
+    }
+    async fn delete_contactdb_lists_list_recipients_recipient(
+        &self,
+        list_id: i64,
+        recipient_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ContactsApiLists::delete_contactdb_lists_list_recipients_recipient(
+            self,
+            list_id,
+            recipient_id,
+            body,
+        )
+        .await
+    }
+    async fn delete_contactdb_lists_list_recipients_recipient_if_exists(
+        &self,
+        list_id: i64,
+        recipient_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<bool> {
+        ContactsApiLists::delete_contactdb_lists_list_recipients_recipient_if_exists(
+            self,
+            list_id,
+            recipient_id,
+            body,
+        )
+        .await
     }
 }
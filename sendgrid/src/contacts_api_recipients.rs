@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct ContactsApiRecipients {
     pub client: Client,
@@ -12,6 +10,14 @@ impl ContactsApiRecipients {
         ContactsApiRecipients { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        ContactsApiRecipients {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieve recipients.
      *
@@ -33,17 +39,20 @@ impl ContactsApiRecipients {
         page: i64,
         page_size: i64,
     ) -> Result<crate::types::ListRecipientsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/contactdb/recipients?{}", query_);
+        let url = crate::Client::url_with_query("/contactdb/recipients", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/contactdb/recipients", &[]))
     }
 
     /**
@@ -67,8 +76,9 @@ impl ContactsApiRecipients {
     ) -> Result<crate::types::ContactDbRecipientResponse> {
         let url = "/contactdb/recipients".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/contactdb/recipients", &[]))
     }
 
     /**
@@ -87,8 +97,9 @@ impl ContactsApiRecipients {
     pub async fn delete_contactdb_recipients(&self, body: &[String]) -> Result<crate::types::Help> {
         let url = "/contactdb/recipients".to_string();
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .delete(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("DELETE", "/contactdb/recipients", &[]))
     }
 
     /**
@@ -112,8 +123,28 @@ impl ContactsApiRecipients {
     ) -> Result<crate::types::ContactDbRecipientResponse> {
         let url = "/contactdb/recipients".to_string();
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| crate::utils::pretty_url("PATCH", "/contactdb/recipients", &[]))
+    }
+
+    /**
+     * Update Recipient.
+     *
+     * This function performs a `PATCH` to the `/contactdb/recipients` endpoint.
+     *
+     * As opposed to `patch_contactdb_recipients`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_contactdb_recipients_if_changed<C: serde::Serialize>(
+        &self,
+        current: &C,
+        body: &[crate::types::PatchContactdbRecipientsRequest],
+    ) -> Result<Option<crate::types::ContactDbRecipientResponse>> {
+        let url = "/contactdb/recipients".to_string();
+        self.client
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| crate::utils::pretty_url("PATCH", "/contactdb/recipients", &[]))
     }
 
     /**
@@ -131,7 +162,10 @@ impl ContactsApiRecipients {
         &self,
     ) -> Result<crate::types::GetContactdbStatusResponseData> {
         let url = "/contactdb/status".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/contactdb/status", &[]))
     }
 
     /**
@@ -151,10 +185,16 @@ impl ContactsApiRecipients {
     ) -> Result<crate::types::ContactdbRecipient> {
         let url = format!(
             "/contactdb/recipients/{}",
-            crate::progenitor_support::encode_path(&recipient_id.to_string()),
+            self.client.encode_path(recipient_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/contactdb/recipients/{recipient_id}",
+                &[("recipient_id", recipient_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -176,10 +216,16 @@ impl ContactsApiRecipients {
     ) -> Result<crate::types::Help> {
         let url = format!(
             "/contactdb/recipients/{}",
-            crate::progenitor_support::encode_path(&recipient_id.to_string()),
+            self.client.encode_path(recipient_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/contactdb/recipients/{recipient_id}",
+                &[("recipient_id", recipient_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -201,10 +247,16 @@ impl ContactsApiRecipients {
     ) -> Result<crate::types::GetContactdbRecipientsRecipientListsResponse> {
         let url = format!(
             "/contactdb/recipients/{}/lists",
-            crate::progenitor_support::encode_path(&recipient_id.to_string()),
+            self.client.encode_path(recipient_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/contactdb/recipients/{recipient_id}/lists",
+                &[("recipient_id", recipient_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -224,7 +276,9 @@ impl ContactsApiRecipients {
         &self,
     ) -> Result<crate::types::ContactdbRecipientCount> {
         let url = "/contactdb/recipients/billable_count".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/contactdb/recipients/billable_count", &[])
+        })
     }
 
     /**
@@ -242,7 +296,10 @@ impl ContactsApiRecipients {
         &self,
     ) -> Result<crate::types::ContactdbRecipientCount> {
         let url = "/contactdb/recipients/count".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/contactdb/recipients/count", &[]))
     }
 
     /**
@@ -270,14 +327,16 @@ impl ContactsApiRecipients {
         &self,
         field_name: &str,
     ) -> Result<crate::types::GetContactdbRecipientsSearchResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !field_name.is_empty() {
-            query_args.push(("{field_name}".to_string(), field_name.to_string()));
+            query_args.push(("{field_name}", field_name.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/contactdb/recipients/search?{}", query_);
+        let url = crate::Client::url_with_query("/contactdb/recipients/search", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/contactdb/recipients/search", &[]))
     }
 
     /**
@@ -335,7 +394,126 @@ impl ContactsApiRecipients {
     ) -> Result<crate::types::PostContactdbRecipientsSearchResponseData> {
         let url = "/contactdb/recipients/search".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/contactdb/recipients/search", &[]))
+    }
+}
+
+/// The functions of [`ContactsApiRecipients`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ContactsApiRecipientsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ContactsApiRecipientsApi: Send + Sync {
+    async fn get_contactdb_recipients(
+        &self,
+        page: i64,
+        page_size: i64,
+    ) -> Result<crate::types::ListRecipientsResponse>;
+    async fn post_contactdb_recipient(
+        &self,
+        body: &[crate::types::PostContactdbRecipientsRequest],
+    ) -> Result<crate::types::ContactDbRecipientResponse>;
+    async fn delete_contactdb_recipients(&self, body: &[String]) -> Result<crate::types::Help>;
+    async fn patch_contactdb_recipients(
+        &self,
+        body: &[crate::types::PatchContactdbRecipientsRequest],
+    ) -> Result<crate::types::ContactDbRecipientResponse>;
+    async fn get_contactdb_statu(&self) -> Result<crate::types::GetContactdbStatusResponseData>;
+    async fn get_contactdb_recipients_recipient(
+        &self,
+        recipient_id: &str,
+    ) -> Result<crate::types::ContactdbRecipient>;
+    async fn delete_contactdb_recipients_recipient(
+        &self,
+        recipient_id: &str,
+    ) -> Result<crate::types::Help>;
+    async fn get_contactdb_recipients_recipient_lists(
+        &self,
+        recipient_id: &str,
+    ) -> Result<crate::types::GetContactdbRecipientsRecipientListsResponse>;
+    async fn get_contactdb_recipients_billable_count(
+        &self,
+    ) -> Result<crate::types::ContactdbRecipientCount>;
+    async fn get_contactdb_recipients_count(&self)
+        -> Result<crate::types::ContactdbRecipientCount>;
+    async fn get_contactdb_recipients_search(
+        &self,
+        field_name: &str,
+    ) -> Result<crate::types::GetContactdbRecipientsSearchResponse>;
+    async fn post_contactdb_recipients_search(
+        &self,
+        body: &crate::types::PostContactdbRecipientsSearchRequest,
+    ) -> Result<crate::types::PostContactdbRecipientsSearchResponseData>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ContactsApiRecipientsApi for ContactsApiRecipients {
+    async fn get_contactdb_recipients(
+        &self,
+        page: i64,
+        page_size: i64,
+    ) -> Result<crate::types::ListRecipientsResponse> {
+        ContactsApiRecipients::get_contactdb_recipients(self, page, page_size).await
+    }
+    async fn post_contactdb_recipient(
+        &self,
+        body: &[crate::types::PostContactdbRecipientsRequest],
+    ) -> Result<crate::types::ContactDbRecipientResponse> {
+        ContactsApiRecipients::post_contactdb_recipient(self, body).await
+    }
+    async fn delete_contactdb_recipients(&self, body: &[String]) -> Result<crate::types::Help> {
+        ContactsApiRecipients::delete_contactdb_recipients(self, body).await
+    }
+    async fn patch_contactdb_recipients(
+        &self,
+        body: &[crate::types::PatchContactdbRecipientsRequest],
+    ) -> Result<crate::types::ContactDbRecipientResponse> {
+        ContactsApiRecipients::patch_contactdb_recipients(self, body).await
+    }
+    async fn get_contactdb_statu(&self) -> Result<crate::types::GetContactdbStatusResponseData> {
+        ContactsApiRecipients::get_contactdb_statu(self).await
+    }
+    async fn get_contactdb_recipients_recipient(
+        &self,
+        recipient_id: &str,
+    ) -> Result<crate::types::ContactdbRecipient> {
+        ContactsApiRecipients::get_contactdb_recipients_recipient(self, recipient_id).await
+    }
+    async fn delete_contactdb_recipients_recipient(
+        &self,
+        recipient_id: &str,
+    ) -> Result<crate::types::Help> {
+        ContactsApiRecipients::delete_contactdb_recipients_recipient(self, recipient_id).await
+    }
+    async fn get_contactdb_recipients_recipient_lists(
+        &self,
+        recipient_id: &str,
+    ) -> Result<crate::types::GetContactdbRecipientsRecipientListsResponse> {
+        ContactsApiRecipients::get_contactdb_recipients_recipient_lists(self, recipient_id).await
+    }
+    async fn get_contactdb_recipients_billable_count(
+        &self,
+    ) -> Result<crate::types::ContactdbRecipientCount> {
+        ContactsApiRecipients::get_contactdb_recipients_billable_count(self).await
+    }
+    async fn get_contactdb_recipients_count(
+        &self,
+    ) -> Result<crate::types::ContactdbRecipientCount> {
+        ContactsApiRecipients::get_contactdb_recipients_count(self).await
+    }
+    async fn get_contactdb_recipients_search(
+        &self,
+        field_name: &str,
+    ) -> Result<crate::types::GetContactdbRecipientsSearchResponse> {
+        ContactsApiRecipients::get_contactdb_recipients_search(self, field_name).await
+    }
+    async fn post_contactdb_recipients_search(
+        &self,
+        body: &crate::types::PostContactdbRecipientsSearchRequest,
+    ) -> Result<crate::types::PostContactdbRecipientsSearchResponseData> {
+        ContactsApiRecipients::post_contactdb_recipients_search(self, body).await
     }
 }
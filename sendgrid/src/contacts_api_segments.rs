@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct ContactsApiSegments {
     pub client: Client,
@@ -12,6 +10,14 @@ impl ContactsApiSegments {
         ContactsApiSegments { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        ContactsApiSegments {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieve all segments.
      *
@@ -25,7 +31,10 @@ impl ContactsApiSegments {
      */
     pub async fn get_contactdb_segments(&self) -> Result<crate::types::ListAllSegmentsResponse> {
         let url = "/contactdb/segments".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/contactdb/segments", &[]))
     }
 
     /**
@@ -84,8 +93,9 @@ impl ContactsApiSegments {
     ) -> Result<crate::types::ContactdbSegmentsWithAllOf> {
         let url = "/contactdb/segments".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/contactdb/segments", &[]))
     }
 
     /**
@@ -106,10 +116,16 @@ impl ContactsApiSegments {
     ) -> Result<crate::types::ContactdbSegments> {
         let url = format!(
             "/contactdb/segments/{}",
-            crate::progenitor_support::encode_path(&segment_id.to_string()),
+            self.client.encode_path(segment_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/contactdb/segments/{segment_id}",
+                &[("segment_id", segment_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -132,20 +148,65 @@ impl ContactsApiSegments {
         delete_contacts: bool,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if delete_contacts {
-            query_args.push(("delete_contacts".to_string(), delete_contacts.to_string()));
+            query_args.push(("delete_contacts", delete_contacts.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/contactdb/segments/{}?{}",
-            crate::progenitor_support::encode_path(&segment_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/contactdb/segments/{}",
+                self.client.encode_path(segment_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .delete(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/contactdb/segments/{segment_id}",
+                    &[("segment_id", segment_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Delete a segment.
+     *
+     * This function performs a `DELETE` to the `/contactdb/segments/{segment_id}` endpoint.
+     *
+     * As opposed to `delete_contactdb_segments_segment`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_contactdb_segments_segment_if_exists(
+        &self,
+        segment_id: &str,
+        delete_contacts: bool,
+        body: &serde_json::Value,
+    ) -> Result<bool> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if delete_contacts {
+            query_args.push(("delete_contacts", delete_contacts.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/contactdb/segments/{}",
+                self.client.encode_path(segment_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .delete_if_exists(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/contactdb/segments/{segment_id}",
+                    &[("segment_id", segment_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -167,12 +228,49 @@ impl ContactsApiSegments {
     ) -> Result<crate::types::ContactdbSegments> {
         let url = format!(
             "/contactdb/segments/{}",
-            crate::progenitor_support::encode_path(&segment_id.to_string()),
+            self.client.encode_path(segment_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/contactdb/segments/{segment_id}",
+                    &[("segment_id", segment_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update a segment.
+     *
+     * This function performs a `PATCH` to the `/contactdb/segments/{segment_id}` endpoint.
+     *
+     * As opposed to `patch_contactdb_segments_segment`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_contactdb_segments_segment_if_changed<C: serde::Serialize>(
+        &self,
+        segment_id: &str,
+        current: &C,
+        body: &crate::types::PatchContactdbSegmentsSegmentRequest,
+    ) -> Result<Option<crate::types::ContactdbSegments>> {
+        let url = format!(
+            "/contactdb/segments/{}",
+            self.client.encode_path(segment_id),
+        );
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/contactdb/segments/{segment_id}",
+                    &[("segment_id", segment_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -194,20 +292,134 @@ impl ContactsApiSegments {
         page: i64,
         page_size: i64,
     ) -> Result<crate::types::ListRecipientsOnASegmentResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/contactdb/segments/{}/recipients?{}",
-            crate::progenitor_support::encode_path(&segment_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/contactdb/segments/{}/recipients",
+                self.client.encode_path(&segment_id.to_string())
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/contactdb/segments/{segment_id}/recipients",
+                &[("segment_id", segment_id.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`ContactsApiSegments`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ContactsApiSegmentsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ContactsApiSegmentsApi: Send + Sync {
+    async fn get_contactdb_segments(&self) -> Result<crate::types::ListAllSegmentsResponse>;
+    async fn post_contactdb_segment(
+        &self,
+        body: &crate::types::ContactdbSegments,
+    ) -> Result<crate::types::ContactdbSegmentsWithAllOf>;
+    async fn get_contactdb_segments_segment(
+        &self,
+        segment_id: &str,
+    ) -> Result<crate::types::ContactdbSegments>;
+    async fn delete_contactdb_segments_segment(
+        &self,
+        segment_id: &str,
+        delete_contacts: bool,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn delete_contactdb_segments_segment_if_exists(
+        &self,
+        segment_id: &str,
+        delete_contacts: bool,
+        body: &serde_json::Value,
+    ) -> Result<bool>;
+    async fn patch_contactdb_segments_segment(
+        &self,
+        segment_id: &str,
+        body: &crate::types::PatchContactdbSegmentsSegmentRequest,
+    ) -> Result<crate::types::ContactdbSegments>;
+    async fn get_contactdb_segments_segment_recipients(
+        &self,
+        segment_id: i64,
+        page: i64,
+        page_size: i64,
+    ) -> Result<crate::types::ListRecipientsOnASegmentResponse>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ContactsApiSegmentsApi for ContactsApiSegments {
+    async fn get_contactdb_segments(&self) -> Result<crate::types::ListAllSegmentsResponse> {
+        ContactsApiSegments::get_contactdb_segments(self).await
+    }
+    async fn post_contactdb_segment(
+        &self,
+        body: &crate::types::ContactdbSegments,
+    ) -> Result<crate::types::ContactdbSegmentsWithAllOf> {
+        ContactsApiSegments::post_contactdb_segment(self, body).await
+    }
+    async fn get_contactdb_segments_segment(
+        &self,
+        segment_id: &str,
+    ) -> Result<crate::types::ContactdbSegments> {
+        ContactsApiSegments::get_contactdb_segments_segment(self, segment_id).await
+    }
+    async fn delete_contactdb_segments_segment(
+        &self,
+        segment_id: &str,
+        delete_contacts: bool,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ContactsApiSegments::delete_contactdb_segments_segment(
+            self,
+            segment_id,
+            delete_contacts,
+            body,
+        )
+        .await
+    }
+    async fn delete_contactdb_segments_segment_if_exists(
+        &self,
+        segment_id: &str,
+        delete_contacts: bool,
+        body: &serde_json::Value,
+    ) -> Result<bool> {
+        ContactsApiSegments::delete_contactdb_segments_segment_if_exists(
+            self,
+            segment_id,
+            delete_contacts,
+            body,
+        )
+        .await
+    }
+    async fn patch_contactdb_segments_segment(
+        &self,
+        segment_id: &str,
+        body: &crate::types::PatchContactdbSegmentsSegmentRequest,
+    ) -> Result<crate::types::ContactdbSegments> {
+        ContactsApiSegments::patch_contactdb_segments_segment(self, segment_id, body).await
+    }
+    async fn get_contactdb_segments_segment_recipients(
+        &self,
+        segment_id: i64,
+        page: i64,
+        page_size: i64,
+    ) -> Result<crate::types::ListRecipientsOnASegmentResponse> {
+        ContactsApiSegments::get_contactdb_segments_segment_recipients(
+            self, segment_id, page, page_size,
+        )
+        .await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct CsvUiOnly {
     pub client: Client,
@@ -12,6 +10,14 @@ impl CsvUiOnly {
         CsvUiOnly { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        CsvUiOnly {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Request CSV.
      *
@@ -34,14 +40,16 @@ impl CsvUiOnly {
         &self,
         query: &str,
     ) -> Result<crate::types::PostMessagesDownloadResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/messages/download?{}", query_);
+        let url = crate::Client::url_with_query("/messages/download", &query_args);
 
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/messages/download", &[]))
     }
 
     /**
@@ -61,9 +69,48 @@ impl CsvUiOnly {
     ) -> Result<crate::types::GetMessagesDownloadResponse> {
         let url = format!(
             "/messages/download/{}",
-            crate::progenitor_support::encode_path(&download_uuid.to_string()),
+            self.client.encode_path(download_uuid),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/messages/download/{download_uuid}",
+                &[("download_uuid", download_uuid.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`CsvUiOnly`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn CsvUiOnlyApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait CsvUiOnlyApi: Send + Sync {
+    async fn post_messages_download(
+        &self,
+        query: &str,
+    ) -> Result<crate::types::PostMessagesDownloadResponse>;
+    async fn get_messages_download(
+        &self,
+        download_uuid: &str,
+    ) -> Result<crate::types::GetMessagesDownloadResponse>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl CsvUiOnlyApi for CsvUiOnly {
+    async fn post_messages_download(
+        &self,
+        query: &str,
+    ) -> Result<crate::types::PostMessagesDownloadResponse> {
+        CsvUiOnly::post_messages_download(self, query).await
+    }
+    async fn get_messages_download(
+        &self,
+        download_uuid: &str,
+    ) -> Result<crate::types::GetMessagesDownloadResponse> {
+        CsvUiOnly::get_messages_download(self, download_uuid).await
     }
 }
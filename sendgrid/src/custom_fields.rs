@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct CustomFields {
     pub client: Client,
@@ -12,6 +10,14 @@ impl CustomFields {
         CustomFields { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        CustomFields {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get All Field Definitions.
      *
@@ -23,7 +29,10 @@ impl CustomFields {
         &self,
     ) -> Result<crate::types::GetMcFieldDefinitionsResponse> {
         let url = "/marketing/field_definitions".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/marketing/field_definitions", &[]))
     }
 
     /**
@@ -45,8 +54,9 @@ impl CustomFields {
     ) -> Result<crate::types::PostMcFieldDefinitionsResponseAllOf> {
         let url = "/marketing/field_definitions".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/marketing/field_definitions", &[]))
     }
 
     /**
@@ -61,10 +71,44 @@ impl CustomFields {
     pub async fn delete_mc_field_definitions_custom(&self, custom_field_id: &str) -> Result<()> {
         let url = format!(
             "/marketing/field_definitions/{}",
-            crate::progenitor_support::encode_path(&custom_field_id.to_string()),
+            self.client.encode_path(custom_field_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/marketing/field_definitions/{custom_field_id}",
+                &[("custom_field_id", custom_field_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete Custom Field Definition.
+     *
+     * This function performs a `DELETE` to the `/marketing/field_definitions/{custom_field_id}` endpoint.
+     *
+     * As opposed to `delete_mc_field_definitions_custom`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_mc_field_definitions_custom_if_exists(
+        &self,
+        custom_field_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/marketing/field_definitions/{}",
+            self.client.encode_path(custom_field_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/marketing/field_definitions/{custom_field_id}",
+                    &[("custom_field_id", custom_field_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -83,11 +127,104 @@ impl CustomFields {
     ) -> Result<crate::types::PostMcFieldDefinitionsResponseAllOf> {
         let url = format!(
             "/marketing/field_definitions/{}",
-            crate::progenitor_support::encode_path(&custom_field_id.to_string()),
+            self.client.encode_path(custom_field_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/marketing/field_definitions/{custom_field_id}",
+                    &[("custom_field_id", custom_field_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update Custom Field Definition.
+     *
+     * This function performs a `PATCH` to the `/marketing/field_definitions/{custom_field_id}` endpoint.
+     *
+     * As opposed to `patch_mc_field_definitions_custom`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_mc_field_definitions_custom_if_changed<C: serde::Serialize>(
+        &self,
+        custom_field_id: &str,
+        current: &C,
+        body: &crate::types::IpPool,
+    ) -> Result<Option<crate::types::PostMcFieldDefinitionsResponseAllOf>> {
+        let url = format!(
+            "/marketing/field_definitions/{}",
+            self.client.encode_path(custom_field_id),
+        );
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/marketing/field_definitions/{custom_field_id}",
+                    &[("custom_field_id", custom_field_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`CustomFields`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn CustomFieldsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait CustomFieldsApi: Send + Sync {
+    async fn get_mc_field_definitions(&self)
+        -> Result<crate::types::GetMcFieldDefinitionsResponse>;
+    async fn post_mc_field_definition(
+        &self,
+        body: &crate::types::PostMcFieldDefinitionsRequest,
+    ) -> Result<crate::types::PostMcFieldDefinitionsResponseAllOf>;
+    async fn delete_mc_field_definitions_custom(&self, custom_field_id: &str) -> Result<()>;
+    async fn delete_mc_field_definitions_custom_if_exists(
+        &self,
+        custom_field_id: &str,
+    ) -> Result<bool>;
+    async fn patch_mc_field_definitions_custom(
+        &self,
+        custom_field_id: &str,
+        body: &crate::types::IpPool,
+    ) -> Result<crate::types::PostMcFieldDefinitionsResponseAllOf>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl CustomFieldsApi for CustomFields {
+    async fn get_mc_field_definitions(
+        &self,
+    ) -> Result<crate::types::GetMcFieldDefinitionsResponse> {
+        CustomFields::get_mc_field_definitions(self).await
+    }
+    async fn post_mc_field_definition(
+        &self,
+        body: &crate::types::PostMcFieldDefinitionsRequest,
+    ) -> Result<crate::types::PostMcFieldDefinitionsResponseAllOf> {
+        CustomFields::post_mc_field_definition(self, body).await
+    }
+    async fn delete_mc_field_definitions_custom(&self, custom_field_id: &str) -> Result<()> {
+        CustomFields::delete_mc_field_definitions_custom(self, custom_field_id).await
+    }
+    async fn delete_mc_field_definitions_custom_if_exists(
+        &self,
+        custom_field_id: &str,
+    ) -> Result<bool> {
+        CustomFields::delete_mc_field_definitions_custom_if_exists(self, custom_field_id).await
+    }
+    async fn patch_mc_field_definitions_custom(
+        &self,
+        custom_field_id: &str,
+        body: &crate::types::IpPool,
+    ) -> Result<crate::types::PostMcFieldDefinitionsResponseAllOf> {
+        CustomFields::patch_mc_field_definitions_custom(self, custom_field_id, body).await
     }
 }
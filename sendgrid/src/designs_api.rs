@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct DesignsApi {
     pub client: Client,
@@ -12,6 +10,14 @@ impl DesignsApi {
         DesignsApi { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        DesignsApi {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get Design.
      *
@@ -24,12 +30,11 @@ impl DesignsApi {
      * This endpoint is valuable when retrieving information stored in a field that you wish to update using a PATCH request.
      */
     pub async fn get_design(&self, id: &str) -> Result<crate::types::DesignOutputAllOf> {
-        let url = format!(
-            "/designs/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/designs/{}", self.client.encode_path(id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/designs/{id}", &[("id", id.to_string())])
+        })
     }
 
     /**
@@ -51,14 +56,14 @@ impl DesignsApi {
         id: &str,
         body: &crate::types::DesignDuplicateInput,
     ) -> Result<crate::types::DesignOutputAllOf> {
-        let url = format!(
-            "/designs/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/designs/{}", self.client.encode_path(id),);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/designs/{id}", &[("id", id.to_string())])
+            })
     }
 
     /**
@@ -71,12 +76,11 @@ impl DesignsApi {
      * Be sure to check the ID of the design you intend to delete before making this request; deleting a design is a permanent action.
      */
     pub async fn delete_design(&self, id: &str) -> Result<crate::types::Help> {
-        let url = format!(
-            "/designs/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/designs/{}", self.client.encode_path(id),);
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("DELETE", "/designs/{id}", &[("id", id.to_string())])
+        })
     }
 
     /**
@@ -101,14 +105,39 @@ impl DesignsApi {
         id: &str,
         body: &crate::types::PutDesignRequest,
     ) -> Result<crate::types::DesignOutputAllOf> {
-        let url = format!(
-            "/designs/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/designs/{}", self.client.encode_path(id),);
+        crate::utils::check_items("categories", body.categories.len(), None, Some(10))?;
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("PATCH", "/designs/{id}", &[("id", id.to_string())])
+            })
+    }
+
+    /**
+     * Update Design.
+     *
+     * This function performs a `PATCH` to the `/designs/{id}` endpoint.
+     *
+     * As opposed to `put_design`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn put_design_if_changed<C: serde::Serialize>(
+        &self,
+        id: &str,
+        current: &C,
+        body: &crate::types::PutDesignRequest,
+    ) -> Result<Option<crate::types::DesignOutputAllOf>> {
+        let url = format!("/designs/{}", self.client.encode_path(id),);
+        crate::utils::check_items("categories", body.categories.len(), None, Some(10))?;
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url("PATCH", "/designs/{id}", &[("id", id.to_string())])
+            })
     }
 
     /**
@@ -134,20 +163,22 @@ impl DesignsApi {
         page_token: &str,
         summary: bool,
     ) -> Result<crate::types::ListDesignsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !page_size.to_string().is_empty() {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("page_token".to_string(), page_token.to_string()));
+            query_args.push(("page_token", page_token.to_string()));
         }
         if summary {
-            query_args.push(("summary".to_string(), summary.to_string()));
+            query_args.push(("summary", summary.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/designs?{}", query_);
+        let url = crate::Client::url_with_query("/designs", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/designs", &[]))
     }
 
     /**
@@ -169,8 +200,9 @@ impl DesignsApi {
     ) -> Result<crate::types::DesignOutputAllOf> {
         let url = "/designs".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/designs", &[]))
     }
 
     /**
@@ -188,12 +220,11 @@ impl DesignsApi {
         &self,
         id: &str,
     ) -> Result<crate::types::DesignOutputAllOf> {
-        let url = format!(
-            "/designs/pre-builts/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/designs/pre-builts/{}", self.client.encode_path(id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/designs/pre-builts/{id}", &[("id", id.to_string())])
+        })
     }
 
     /**
@@ -213,14 +244,18 @@ impl DesignsApi {
         id: &str,
         body: &crate::types::DesignDuplicateInput,
     ) -> Result<crate::types::DesignOutputAllOf> {
-        let url = format!(
-            "/designs/pre-builts/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/designs/pre-builts/{}", self.client.encode_path(id),);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/designs/pre-builts/{id}",
+                    &[("id", id.to_string())],
+                )
+            })
     }
 
     /**
@@ -248,19 +283,126 @@ impl DesignsApi {
         page_token: &str,
         summary: bool,
     ) -> Result<crate::types::ListDesignsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !page_size.to_string().is_empty() {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("page_token".to_string(), page_token.to_string()));
+            query_args.push(("page_token", page_token.to_string()));
         }
         if summary {
-            query_args.push(("summary".to_string(), summary.to_string()));
+            query_args.push(("summary", summary.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/designs/pre-builts?{}", query_);
+        let url = crate::Client::url_with_query("/designs/pre-builts", &query_args);
+
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/designs/pre-builts", &[]))
+    }
+}
+
+/// The functions of [`DesignsApi`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn DesignsApiApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait DesignsApiApi: Send + Sync {
+    async fn get_design(&self, id: &str) -> Result<crate::types::DesignOutputAllOf>;
+    async fn post_duplicate_design(
+        &self,
+        id: &str,
+        body: &crate::types::DesignDuplicateInput,
+    ) -> Result<crate::types::DesignOutputAllOf>;
+    async fn delete_design(&self, id: &str) -> Result<crate::types::Help>;
+    async fn put_design(
+        &self,
+        id: &str,
+        body: &crate::types::PutDesignRequest,
+    ) -> Result<crate::types::DesignOutputAllOf>;
+    async fn list_designs(
+        &self,
+        page_size: u64,
+        page_token: &str,
+        summary: bool,
+    ) -> Result<crate::types::ListDesignsResponse>;
+    async fn post_design(
+        &self,
+        body: &crate::types::DesignInputAllOf,
+    ) -> Result<crate::types::DesignOutputAllOf>;
+    async fn get_sendgrid_pre_built_design(
+        &self,
+        id: &str,
+    ) -> Result<crate::types::DesignOutputAllOf>;
+    async fn post_sendgrid_pre_built_design(
+        &self,
+        id: &str,
+        body: &crate::types::DesignDuplicateInput,
+    ) -> Result<crate::types::DesignOutputAllOf>;
+    async fn list_sendgrid_pre_built_designs(
+        &self,
+        page_size: u64,
+        page_token: &str,
+        summary: bool,
+    ) -> Result<crate::types::ListDesignsResponse>;
+}
 
-        self.client.get(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl DesignsApiApi for DesignsApi {
+    async fn get_design(&self, id: &str) -> Result<crate::types::DesignOutputAllOf> {
+        DesignsApi::get_design(self, id).await
+    }
+    async fn post_duplicate_design(
+        &self,
+        id: &str,
+        body: &crate::types::DesignDuplicateInput,
+    ) -> Result<crate::types::DesignOutputAllOf> {
+        DesignsApi::post_duplicate_design(self, id, body).await
+    }
+    async fn delete_design(&self, id: &str) -> Result<crate::types::Help> {
+        DesignsApi::delete_design(self, id).await
+    }
+    async fn put_design(
+        &self,
+        id: &str,
+        body: &crate::types::PutDesignRequest,
+    ) -> Result<crate::types::DesignOutputAllOf> {
+        DesignsApi::put_design(self, id, body).await
+    }
+    async fn list_designs(
+        &self,
+        page_size: u64,
+        page_token: &str,
+        summary: bool,
+    ) -> Result<crate::types::ListDesignsResponse> {
+        DesignsApi::list_designs(self, page_size, page_token, summary).await
+    }
+    async fn post_design(
+        &self,
+        body: &crate::types::DesignInputAllOf,
+    ) -> Result<crate::types::DesignOutputAllOf> {
+        DesignsApi::post_design(self, body).await
+    }
+    async fn get_sendgrid_pre_built_design(
+        &self,
+        id: &str,
+    ) -> Result<crate::types::DesignOutputAllOf> {
+        DesignsApi::get_sendgrid_pre_built_design(self, id).await
+    }
+    async fn post_sendgrid_pre_built_design(
+        &self,
+        id: &str,
+        body: &crate::types::DesignDuplicateInput,
+    ) -> Result<crate::types::DesignOutputAllOf> {
+        DesignsApi::post_sendgrid_pre_built_design(self, id, body).await
+    }
+    async fn list_sendgrid_pre_built_designs(
+        &self,
+        page_size: u64,
+        page_token: &str,
+        summary: bool,
+    ) -> Result<crate::types::ListDesignsResponse> {
+        DesignsApi::list_sendgrid_pre_built_designs(self, page_size, page_token, summary).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct DomainAuthentication {
     pub client: Client,
@@ -12,6 +10,14 @@ impl DomainAuthentication {
         DomainAuthentication { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        DomainAuthentication {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * List all authenticated domains.
      *
@@ -36,26 +42,28 @@ impl DomainAuthentication {
         username: &str,
         domain: &str,
     ) -> Result<Vec<crate::types::DomainAuthentication200ResponseAllOf>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !domain.is_empty() {
-            query_args.push(("domain".to_string(), domain.to_string()));
+            query_args.push(("domain", domain.to_string()));
         }
         if exclude_subusers {
-            query_args.push(("exclude_subusers".to_string(), exclude_subusers.to_string()));
+            query_args.push(("exclude_subusers", exclude_subusers.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !username.is_empty() {
-            query_args.push(("username".to_string(), username.to_string()));
+            query_args.push(("username", username.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/whitelabel/domains?{}", query_);
+        let url = crate::Client::url_with_query("/whitelabel/domains", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/whitelabel/domains", &[]))
     }
 
     /**
@@ -74,23 +82,25 @@ impl DomainAuthentication {
         username: &str,
         domain: &str,
     ) -> Result<Vec<crate::types::DomainAuthentication200ResponseAllOf>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !domain.is_empty() {
-            query_args.push(("domain".to_string(), domain.to_string()));
+            query_args.push(("domain", domain.to_string()));
         }
         if exclude_subusers {
-            query_args.push(("exclude_subusers".to_string(), exclude_subusers.to_string()));
+            query_args.push(("exclude_subusers", exclude_subusers.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !username.is_empty() {
-            query_args.push(("username".to_string(), username.to_string()));
+            query_args.push(("username", username.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/whitelabel/domains?{}", query_);
+        let url = crate::Client::url_with_query("/whitelabel/domains", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/whitelabel/domains", &[]))
     }
 
     /**
@@ -114,8 +124,9 @@ impl DomainAuthentication {
     ) -> Result<crate::types::AuthenticationDomain> {
         let url = "/whitelabel/domains".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/whitelabel/domains", &[]))
     }
 
     /**
@@ -133,12 +144,15 @@ impl DomainAuthentication {
         &self,
         domain_id: &str,
     ) -> Result<crate::types::AuthenticationDomain> {
-        let url = format!(
-            "/whitelabel/domains/{}",
-            crate::progenitor_support::encode_path(&domain_id.to_string()),
-        );
+        let url = format!("/whitelabel/domains/{}", self.client.encode_path(domain_id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/whitelabel/domains/{domain_id}",
+                &[("domain_id", domain_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -156,12 +170,15 @@ impl DomainAuthentication {
         &self,
         domain_id: &str,
     ) -> Result<crate::types::Help> {
-        let url = format!(
-            "/whitelabel/domains/{}",
-            crate::progenitor_support::encode_path(&domain_id.to_string()),
-        );
+        let url = format!("/whitelabel/domains/{}", self.client.encode_path(domain_id),);
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/whitelabel/domains/{domain_id}",
+                &[("domain_id", domain_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -180,14 +197,45 @@ impl DomainAuthentication {
         domain_id: &str,
         body: &crate::types::PatchWhitelabelDomainsDomainRequest,
     ) -> Result<Vec<crate::types::DomainAuthentication200ResponseAllOf>> {
-        let url = format!(
-            "/whitelabel/domains/{}",
-            crate::progenitor_support::encode_path(&domain_id.to_string()),
-        );
+        let url = format!("/whitelabel/domains/{}", self.client.encode_path(domain_id),);
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/whitelabel/domains/{domain_id}",
+                    &[("domain_id", domain_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update an authenticated domain.
+     *
+     * This function performs a `PATCH` to the `/whitelabel/domains/{domain_id}` endpoint.
+     *
+     * As opposed to `patch_whitelabel_domains_domain`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_whitelabel_domains_domain_if_changed<C: serde::Serialize>(
+        &self,
+        domain_id: &str,
+        current: &C,
+        body: &crate::types::PatchWhitelabelDomainsDomainRequest,
+    ) -> Result<Option<Vec<crate::types::DomainAuthentication200ResponseAllOf>>> {
+        let url = format!("/whitelabel/domains/{}", self.client.encode_path(domain_id),);
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/whitelabel/domains/{domain_id}",
+                    &[("domain_id", domain_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -210,14 +258,16 @@ impl DomainAuthentication {
         &self,
         domain: &str,
     ) -> Result<Vec<crate::types::DomainAuthentication200ResponseAllOf>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !domain.is_empty() {
-            query_args.push(("domain".to_string(), domain.to_string()));
+            query_args.push(("domain", domain.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/whitelabel/domains/default?{}", query_);
+        let url = crate::Client::url_with_query("/whitelabel/domains/default", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/whitelabel/domains/default", &[]))
     }
 
     /**
@@ -237,14 +287,16 @@ impl DomainAuthentication {
         &self,
         domain: &str,
     ) -> Result<Vec<crate::types::DomainAuthentication200ResponseAllOf>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !domain.is_empty() {
-            query_args.push(("domain".to_string(), domain.to_string()));
+            query_args.push(("domain", domain.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/whitelabel/domains/default?{}", query_);
+        let url = crate::Client::url_with_query("/whitelabel/domains/default", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/whitelabel/domains/default", &[]))
     }
 
     /**
@@ -265,12 +317,19 @@ impl DomainAuthentication {
     ) -> Result<crate::types::DomainAuthentication> {
         let url = format!(
             "/whitelabel/domains/{}/ips",
-            crate::progenitor_support::encode_path(&id.to_string()),
+            self.client.encode_path(&id.to_string()),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/whitelabel/domains/{id}/ips",
+                    &[("id", id.to_string())],
+                )
+            })
     }
 
     /**
@@ -291,11 +350,17 @@ impl DomainAuthentication {
     ) -> Result<crate::types::DomainAuthentication> {
         let url = format!(
             "/whitelabel/domains/{}/ips/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-            crate::progenitor_support::encode_path(&ip.to_string()),
+            self.client.encode_path(&id.to_string()),
+            self.client.encode_path(ip),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/whitelabel/domains/{id}/ips/{ip}",
+                &[("id", id.to_string()), ("ip", ip.to_string())],
+            )
+        })
     }
 
     /**
@@ -315,10 +380,16 @@ impl DomainAuthentication {
     ) -> Result<crate::types::PostWhitelabelDomainsValidateResponse> {
         let url = format!(
             "/whitelabel/domains/{}/validate",
-            crate::progenitor_support::encode_path(&id.to_string()),
+            self.client.encode_path(&id.to_string()),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/whitelabel/domains/{id}/validate",
+                &[("id", id.to_string())],
+            )
+        })
     }
 
     /**
@@ -338,14 +409,16 @@ impl DomainAuthentication {
         &self,
         username: &str,
     ) -> Result<crate::types::DomainAuthentication> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !username.is_empty() {
-            query_args.push(("username".to_string(), username.to_string()));
+            query_args.push(("username", username.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/whitelabel/domains/subuser?{}", query_);
+        let url = crate::Client::url_with_query("/whitelabel/domains/subuser", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/whitelabel/domains/subuser", &[]))
     }
 
     /**
@@ -365,14 +438,16 @@ impl DomainAuthentication {
         &self,
         username: &str,
     ) -> Result<crate::types::Help> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !username.is_empty() {
-            query_args.push(("username".to_string(), username.to_string()));
+            query_args.push(("username", username.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/whitelabel/domains/subuser?{}", query_);
+        let url = crate::Client::url_with_query("/whitelabel/domains/subuser", &query_args);
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("DELETE", "/whitelabel/domains/subuser", &[]))
     }
 
     /**
@@ -391,11 +466,204 @@ impl DomainAuthentication {
     ) -> Result<crate::types::DomainAuthentication> {
         let url = format!(
             "/whitelabel/domains/{}/subuser",
-            crate::progenitor_support::encode_path(&domain_id.to_string()),
+            self.client.encode_path(&domain_id.to_string()),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/whitelabel/domains/{domain_id}/subuser",
+                    &[("domain_id", domain_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`DomainAuthentication`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn DomainAuthenticationApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait DomainAuthenticationApi: Send + Sync {
+    async fn get_whitelabel_domains(
+        &self,
+        limit: i64,
+        offset: i64,
+        exclude_subusers: bool,
+        username: &str,
+        domain: &str,
+    ) -> Result<Vec<crate::types::DomainAuthentication200ResponseAllOf>>;
+    async fn get_all_whitelabel_domains(
+        &self,
+        offset: i64,
+        exclude_subusers: bool,
+        username: &str,
+        domain: &str,
+    ) -> Result<Vec<crate::types::DomainAuthentication200ResponseAllOf>>;
+    async fn post_whitelabel_domain(
+        &self,
+        body: &crate::types::PostWhitelabelDomainsRequest,
+    ) -> Result<crate::types::AuthenticationDomain>;
+    async fn get_whitelabel_domains_domain(
+        &self,
+        domain_id: &str,
+    ) -> Result<crate::types::AuthenticationDomain>;
+    async fn delete_whitelabel_domains_domain(&self, domain_id: &str)
+        -> Result<crate::types::Help>;
+    async fn patch_whitelabel_domains_domain(
+        &self,
+        domain_id: &str,
+        body: &crate::types::PatchWhitelabelDomainsDomainRequest,
+    ) -> Result<Vec<crate::types::DomainAuthentication200ResponseAllOf>>;
+    async fn get_whitelabel_domains_default(
+        &self,
+        domain: &str,
+    ) -> Result<Vec<crate::types::DomainAuthentication200ResponseAllOf>>;
+    async fn get_all_whitelabel_domains_default(
+        &self,
+        domain: &str,
+    ) -> Result<Vec<crate::types::DomainAuthentication200ResponseAllOf>>;
+    async fn post_whitelabel_domains_ip(
+        &self,
+        id: i64,
+        body: &crate::types::Ips,
+    ) -> Result<crate::types::DomainAuthentication>;
+    async fn delete_whitelabel_domains_ips_ip(
+        &self,
+        id: i64,
+        ip: &str,
+    ) -> Result<crate::types::DomainAuthentication>;
+    async fn post_whitelabel_domains_validate(
+        &self,
+        id: i64,
+    ) -> Result<crate::types::PostWhitelabelDomainsValidateResponse>;
+    async fn get_whitelabel_domains_subuser(
+        &self,
+        username: &str,
+    ) -> Result<crate::types::DomainAuthentication>;
+    async fn delete_whitelabel_domains_subuser(&self, username: &str)
+        -> Result<crate::types::Help>;
+    async fn post_whitelabel_domains_domain_subuser(
+        &self,
+        domain_id: i64,
+        body: &crate::types::PutUserUsernameResponse,
+    ) -> Result<crate::types::DomainAuthentication>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl DomainAuthenticationApi for DomainAuthentication {
+    async fn get_whitelabel_domains(
+        &self,
+        limit: i64,
+        offset: i64,
+        exclude_subusers: bool,
+        username: &str,
+        domain: &str,
+    ) -> Result<Vec<crate::types::DomainAuthentication200ResponseAllOf>> {
+        DomainAuthentication::get_whitelabel_domains(
+            self,
+            limit,
+            offset,
+            exclude_subusers,
+            username,
+            domain,
+        )
+        .await
+    }
+    async fn get_all_whitelabel_domains(
+        &self,
+        offset: i64,
+        exclude_subusers: bool,
+        username: &str,
+        domain: &str,
+    ) -> Result<Vec<crate::types::DomainAuthentication200ResponseAllOf>> {
+        DomainAuthentication::get_all_whitelabel_domains(
+            self,
+            offset,
+            exclude_subusers,
+            username,
+            domain,
+        )
+        .await
+    }
+    async fn post_whitelabel_domain(
+        &self,
+        body: &crate::types::PostWhitelabelDomainsRequest,
+    ) -> Result<crate::types::AuthenticationDomain> {
+        DomainAuthentication::post_whitelabel_domain(self, body).await
+    }
+    async fn get_whitelabel_domains_domain(
+        &self,
+        domain_id: &str,
+    ) -> Result<crate::types::AuthenticationDomain> {
+        DomainAuthentication::get_whitelabel_domains_domain(self, domain_id).await
+    }
+    async fn delete_whitelabel_domains_domain(
+        &self,
+        domain_id: &str,
+    ) -> Result<crate::types::Help> {
+        DomainAuthentication::delete_whitelabel_domains_domain(self, domain_id).await
+    }
+    async fn patch_whitelabel_domains_domain(
+        &self,
+        domain_id: &str,
+        body: &crate::types::PatchWhitelabelDomainsDomainRequest,
+    ) -> Result<Vec<crate::types::DomainAuthentication200ResponseAllOf>> {
+        DomainAuthentication::patch_whitelabel_domains_domain(self, domain_id, body).await
+    }
+    async fn get_whitelabel_domains_default(
+        &self,
+        domain: &str,
+    ) -> Result<Vec<crate::types::DomainAuthentication200ResponseAllOf>> {
+        DomainAuthentication::get_whitelabel_domains_default(self, domain).await
+    }
+    async fn get_all_whitelabel_domains_default(
+        &self,
+        domain: &str,
+    ) -> Result<Vec<crate::types::DomainAuthentication200ResponseAllOf>> {
+        DomainAuthentication::get_all_whitelabel_domains_default(self, domain).await
+    }
+    async fn post_whitelabel_domains_ip(
+        &self,
+        id: i64,
+        body: &crate::types::Ips,
+    ) -> Result<crate::types::DomainAuthentication> {
+        DomainAuthentication::post_whitelabel_domains_ip(self, id, body).await
+    }
+    async fn delete_whitelabel_domains_ips_ip(
+        &self,
+        id: i64,
+        ip: &str,
+    ) -> Result<crate::types::DomainAuthentication> {
+        DomainAuthentication::delete_whitelabel_domains_ips_ip(self, id, ip).await
+    }
+    async fn post_whitelabel_domains_validate(
+        &self,
+        id: i64,
+    ) -> Result<crate::types::PostWhitelabelDomainsValidateResponse> {
+        DomainAuthentication::post_whitelabel_domains_validate(self, id).await
+    }
+    async fn get_whitelabel_domains_subuser(
+        &self,
+        username: &str,
+    ) -> Result<crate::types::DomainAuthentication> {
+        DomainAuthentication::get_whitelabel_domains_subuser(self, username).await
+    }
+    async fn delete_whitelabel_domains_subuser(
+        &self,
+        username: &str,
+    ) -> Result<crate::types::Help> {
+        DomainAuthentication::delete_whitelabel_domains_subuser(self, username).await
+    }
+    async fn post_whitelabel_domains_domain_subuser(
+        &self,
+        domain_id: i64,
+        body: &crate::types::PutUserUsernameResponse,
+    ) -> Result<crate::types::DomainAuthentication> {
+        DomainAuthentication::post_whitelabel_domains_domain_subuser(self, domain_id, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct EmailAddressValidation {
     pub client: Client,
@@ -12,6 +10,14 @@ impl EmailAddressValidation {
         EmailAddressValidation { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        EmailAddressValidation {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Validate an email.
      *
@@ -25,7 +31,31 @@ impl EmailAddressValidation {
     ) -> Result<crate::types::PostValidationsEmailResponse> {
         let url = "/validations/email".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/validations/email", &[]))
+    }
+}
+
+/// The functions of [`EmailAddressValidation`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn EmailAddressValidationApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait EmailAddressValidationApi: Send + Sync {
+    async fn post_validations_email(
+        &self,
+        body: &crate::types::PostValidationsEmailRequest,
+    ) -> Result<crate::types::PostValidationsEmailResponse>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl EmailAddressValidationApi for EmailAddressValidation {
+    async fn post_validations_email(
+        &self,
+        body: &crate::types::PostValidationsEmailRequest,
+    ) -> Result<crate::types::PostValidationsEmailResponse> {
+        EmailAddressValidation::post_validations_email(self, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct EmailCnameRecords {
     pub client: Client,
@@ -12,6 +10,14 @@ impl EmailCnameRecords {
         EmailCnameRecords { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        EmailCnameRecords {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Email DNS records to a co-worker.
      *
@@ -33,7 +39,31 @@ impl EmailCnameRecords {
     ) -> Result<()> {
         let url = "/whitelabel/dns/email".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/whitelabel/dns/email", &[]))
+    }
+}
+
+/// The functions of [`EmailCnameRecords`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn EmailCnameRecordsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait EmailCnameRecordsApi: Send + Sync {
+    async fn post_whitelabel_dns_email(
+        &self,
+        body: &crate::types::PostWhitelabelDnsEmailRequest,
+    ) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl EmailCnameRecordsApi for EmailCnameRecords {
+    async fn post_whitelabel_dns_email(
+        &self,
+        body: &crate::types::PostWhitelabelDnsEmailRequest,
+    ) -> Result<()> {
+        EmailCnameRecords::post_whitelabel_dns_email(self, body).await
     }
 }
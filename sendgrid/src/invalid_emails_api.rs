@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct InvalidEmailsApi {
     pub client: Client,
@@ -12,6 +10,14 @@ impl InvalidEmailsApi {
         InvalidEmailsApi { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        InvalidEmailsApi {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieve all invalid emails.
      *
@@ -34,23 +40,25 @@ impl InvalidEmailsApi {
         limit: i64,
         offset: i64,
     ) -> Result<Vec<crate::types::InvalidEmail>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if end_time > 0 {
-            query_args.push(("end_time".to_string(), end_time.to_string()));
+            query_args.push(("end_time", end_time.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if start_time > 0 {
-            query_args.push(("start_time".to_string(), start_time.to_string()));
+            query_args.push(("start_time", start_time.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/suppression/invalid_emails?{}", query_);
+        let url = crate::Client::url_with_query("/suppression/invalid_emails", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/suppression/invalid_emails", &[]))
     }
 
     /**
@@ -68,20 +76,22 @@ impl InvalidEmailsApi {
         end_time: i64,
         offset: i64,
     ) -> Result<Vec<crate::types::InvalidEmail>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if end_time > 0 {
-            query_args.push(("end_time".to_string(), end_time.to_string()));
+            query_args.push(("end_time", end_time.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if start_time > 0 {
-            query_args.push(("start_time".to_string(), start_time.to_string()));
+            query_args.push(("start_time", start_time.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/suppression/invalid_emails?{}", query_);
+        let url = crate::Client::url_with_query("/suppression/invalid_emails", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/suppression/invalid_emails", &[]))
     }
 
     /**
@@ -106,8 +116,9 @@ impl InvalidEmailsApi {
     ) -> Result<crate::types::Help> {
         let url = "/suppression/invalid_emails".to_string();
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .delete(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("DELETE", "/suppression/invalid_emails", &[]))
     }
 
     /**
@@ -127,10 +138,16 @@ impl InvalidEmailsApi {
     ) -> Result<Vec<crate::types::InvalidEmail>> {
         let url = format!(
             "/suppression/invalid_emails/{}",
-            crate::progenitor_support::encode_path(&email.to_string()),
+            self.client.encode_path(email),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/suppression/invalid_emails/{email}",
+                &[("email", email.to_string())],
+            )
+        })
     }
 
     /**
@@ -148,10 +165,19 @@ impl InvalidEmailsApi {
     ) -> Result<Vec<crate::types::InvalidEmail>> {
         let url = format!(
             "/suppression/invalid_emails/{}",
-            crate::progenitor_support::encode_path(&email.to_string()),
+            self.client.encode_path(email),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/suppression/invalid_emails/{email}",
+                    &[("email", email.to_string())],
+                )
+            })
     }
 
     /**
@@ -171,9 +197,100 @@ impl InvalidEmailsApi {
     ) -> Result<crate::types::Help> {
         let url = format!(
             "/suppression/invalid_emails/{}",
-            crate::progenitor_support::encode_path(&email.to_string()),
+            self.client.encode_path(email),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/suppression/invalid_emails/{email}",
+                &[("email", email.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`InvalidEmailsApi`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn InvalidEmailsApiApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait InvalidEmailsApiApi: Send + Sync {
+    async fn get_suppression_invalid_emails(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<crate::types::InvalidEmail>>;
+    async fn get_all_suppression_invalid_emails(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        offset: i64,
+    ) -> Result<Vec<crate::types::InvalidEmail>>;
+    async fn delete_suppression_invalid_emails(
+        &self,
+        body: &crate::types::DeleteSuppressionBlocksRequest,
+    ) -> Result<crate::types::Help>;
+    async fn get_suppression_invalid_emails_email(
+        &self,
+        email: &str,
+    ) -> Result<Vec<crate::types::InvalidEmail>>;
+    async fn get_all_suppression_invalid_emails_email(
+        &self,
+        email: &str,
+    ) -> Result<Vec<crate::types::InvalidEmail>>;
+    async fn delete_suppression_invalid_emails_email(
+        &self,
+        email: &str,
+    ) -> Result<crate::types::Help>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl InvalidEmailsApiApi for InvalidEmailsApi {
+    async fn get_suppression_invalid_emails(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<crate::types::InvalidEmail>> {
+        InvalidEmailsApi::get_suppression_invalid_emails(self, start_time, end_time, limit, offset)
+            .await
+    }
+    async fn get_all_suppression_invalid_emails(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        offset: i64,
+    ) -> Result<Vec<crate::types::InvalidEmail>> {
+        InvalidEmailsApi::get_all_suppression_invalid_emails(self, start_time, end_time, offset)
+            .await
+    }
+    async fn delete_suppression_invalid_emails(
+        &self,
+        body: &crate::types::DeleteSuppressionBlocksRequest,
+    ) -> Result<crate::types::Help> {
+        InvalidEmailsApi::delete_suppression_invalid_emails(self, body).await
+    }
+    async fn get_suppression_invalid_emails_email(
+        &self,
+        email: &str,
+    ) -> Result<Vec<crate::types::InvalidEmail>> {
+        InvalidEmailsApi::get_suppression_invalid_emails_email(self, email).await
+    }
+    async fn get_all_suppression_invalid_emails_email(
+        &self,
+        email: &str,
+    ) -> Result<Vec<crate::types::InvalidEmail>> {
+        InvalidEmailsApi::get_all_suppression_invalid_emails_email(self, email).await
+    }
+    async fn delete_suppression_invalid_emails_email(
+        &self,
+        email: &str,
+    ) -> Result<crate::types::Help> {
+        InvalidEmailsApi::delete_suppression_invalid_emails_email(self, email).await
     }
 }
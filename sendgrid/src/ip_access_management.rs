@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct IpAccessManagement {
     pub client: Client,
@@ -12,6 +10,14 @@ impl IpAccessManagement {
         IpAccessManagement { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        IpAccessManagement {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieve a list of currently allowed IPs.
      *
@@ -27,7 +33,10 @@ impl IpAccessManagement {
      */
     pub async fn get_access_settings_whitelist(&self) -> Result<crate::types::IpAccessResponse> {
         let url = "/access_settings/whitelist".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/access_settings/whitelist", &[]))
     }
 
     /**
@@ -49,8 +58,9 @@ impl IpAccessManagement {
     ) -> Result<crate::types::IpAccessResponse> {
         let url = "/access_settings/whitelist".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/access_settings/whitelist", &[]))
     }
 
     /**
@@ -74,8 +84,9 @@ impl IpAccessManagement {
     ) -> Result<crate::types::Help> {
         let url = "/access_settings/whitelist".to_string();
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .delete(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("DELETE", "/access_settings/whitelist", &[]))
     }
 
     /**
@@ -94,14 +105,16 @@ impl IpAccessManagement {
         &self,
         limit: i64,
     ) -> Result<crate::types::GetAccessSettingsActivityResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/access_settings/activity?{}", query_);
+        let url = crate::Client::url_with_query("/access_settings/activity", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/access_settings/activity", &[]))
     }
 
     /**
@@ -123,10 +136,16 @@ impl IpAccessManagement {
     ) -> Result<crate::types::IpAccessResponse> {
         let url = format!(
             "/access_settings/whitelist/{}",
-            crate::progenitor_support::encode_path(&rule_id.to_string()),
+            self.client.encode_path(rule_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/access_settings/whitelist/{rule_id}",
+                &[("rule_id", rule_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -148,9 +167,82 @@ impl IpAccessManagement {
     ) -> Result<crate::types::Help> {
         let url = format!(
             "/access_settings/whitelist/{}",
-            crate::progenitor_support::encode_path(&rule_id.to_string()),
+            self.client.encode_path(rule_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/access_settings/whitelist/{rule_id}",
+                &[("rule_id", rule_id.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`IpAccessManagement`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn IpAccessManagementApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait IpAccessManagementApi: Send + Sync {
+    async fn get_access_settings_whitelist(&self) -> Result<crate::types::IpAccessResponse>;
+    async fn post_access_settings_whitelist(
+        &self,
+        body: &crate::types::PostAccessSettingsWhitelistRequest,
+    ) -> Result<crate::types::IpAccessResponse>;
+    async fn delete_access_settings_whitelist(
+        &self,
+        body: &crate::types::DeleteAccessSettingsWhitelistRequest,
+    ) -> Result<crate::types::Help>;
+    async fn get_access_settings_activity(
+        &self,
+        limit: i64,
+    ) -> Result<crate::types::GetAccessSettingsActivityResponse>;
+    async fn get_access_settings_whitelist_rule(
+        &self,
+        rule_id: &str,
+    ) -> Result<crate::types::IpAccessResponse>;
+    async fn delete_access_settings_whitelist_rule(
+        &self,
+        rule_id: &str,
+    ) -> Result<crate::types::Help>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl IpAccessManagementApi for IpAccessManagement {
+    async fn get_access_settings_whitelist(&self) -> Result<crate::types::IpAccessResponse> {
+        IpAccessManagement::get_access_settings_whitelist(self).await
+    }
+    async fn post_access_settings_whitelist(
+        &self,
+        body: &crate::types::PostAccessSettingsWhitelistRequest,
+    ) -> Result<crate::types::IpAccessResponse> {
+        IpAccessManagement::post_access_settings_whitelist(self, body).await
+    }
+    async fn delete_access_settings_whitelist(
+        &self,
+        body: &crate::types::DeleteAccessSettingsWhitelistRequest,
+    ) -> Result<crate::types::Help> {
+        IpAccessManagement::delete_access_settings_whitelist(self, body).await
+    }
+    async fn get_access_settings_activity(
+        &self,
+        limit: i64,
+    ) -> Result<crate::types::GetAccessSettingsActivityResponse> {
+        IpAccessManagement::get_access_settings_activity(self, limit).await
+    }
+    async fn get_access_settings_whitelist_rule(
+        &self,
+        rule_id: &str,
+    ) -> Result<crate::types::IpAccessResponse> {
+        IpAccessManagement::get_access_settings_whitelist_rule(self, rule_id).await
+    }
+    async fn delete_access_settings_whitelist_rule(
+        &self,
+        rule_id: &str,
+    ) -> Result<crate::types::Help> {
+        IpAccessManagement::delete_access_settings_whitelist_rule(self, rule_id).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct IpAddresses {
     pub client: Client,
@@ -12,6 +10,14 @@ impl IpAddresses {
         IpAddresses { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        IpAddresses {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieve all IP addresses.
      *
@@ -41,35 +47,31 @@ impl IpAddresses {
         subuser: &str,
         sort_by_direction: crate::types::SortByDirection,
     ) -> Result<Vec<crate::types::GetIpsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if exclude_whitelabels {
-            query_args.push((
-                "exclude_whitelabels".to_string(),
-                exclude_whitelabels.to_string(),
-            ));
+            query_args.push(("exclude_whitelabels", exclude_whitelabels.to_string()));
         }
         if !ip.is_empty() {
-            query_args.push(("ip".to_string(), ip.to_string()));
+            query_args.push(("ip", ip.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !sort_by_direction.to_string().is_empty() {
-            query_args.push((
-                "sort_by_direction".to_string(),
-                sort_by_direction.to_string(),
-            ));
+            query_args.push(("sort_by_direction", sort_by_direction.to_string()));
         }
         if !subuser.is_empty() {
-            query_args.push(("subuser".to_string(), subuser.to_string()));
+            query_args.push(("subuser", subuser.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/ips?{}", query_);
+        let url = crate::Client::url_with_query("/ips", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/ips", &[]))
     }
 
     /**
@@ -93,32 +95,28 @@ impl IpAddresses {
         subuser: &str,
         sort_by_direction: crate::types::SortByDirection,
     ) -> Result<Vec<crate::types::GetIpsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if exclude_whitelabels {
-            query_args.push((
-                "exclude_whitelabels".to_string(),
-                exclude_whitelabels.to_string(),
-            ));
+            query_args.push(("exclude_whitelabels", exclude_whitelabels.to_string()));
         }
         if !ip.is_empty() {
-            query_args.push(("ip".to_string(), ip.to_string()));
+            query_args.push(("ip", ip.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !sort_by_direction.to_string().is_empty() {
-            query_args.push((
-                "sort_by_direction".to_string(),
-                sort_by_direction.to_string(),
-            ));
+            query_args.push(("sort_by_direction", sort_by_direction.to_string()));
         }
         if !subuser.is_empty() {
-            query_args.push(("subuser".to_string(), subuser.to_string()));
+            query_args.push(("subuser", subuser.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/ips?{}", query_);
+        let url = crate::Client::url_with_query("/ips", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/ips", &[]))
     }
 
     /**
@@ -134,8 +132,9 @@ impl IpAddresses {
     ) -> Result<crate::types::PostIpsResponseData> {
         let url = "/ips".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/ips", &[]))
     }
 
     /**
@@ -147,7 +146,10 @@ impl IpAddresses {
      */
     pub async fn get_ips_remaining(&self) -> Result<crate::types::GetIpsRemainingResponse> {
         let url = "/ips/remaining".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/ips/remaining", &[]))
     }
 
     /**
@@ -161,7 +163,10 @@ impl IpAddresses {
      */
     pub async fn get_ips_assigned(&self) -> Result<Vec<crate::types::GetIpsAssignedResponse>> {
         let url = "/ips/assigned".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/ips/assigned", &[]))
     }
 
     /**
@@ -177,7 +182,10 @@ impl IpAddresses {
      */
     pub async fn get_all_ips_assigned(&self) -> Result<Vec<crate::types::GetIpsAssignedResponse>> {
         let url = "/ips/assigned".to_string();
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/ips/assigned", &[]))
     }
 
     /**
@@ -195,11 +203,114 @@ impl IpAddresses {
         &self,
         ip_address: &str,
     ) -> Result<crate::types::GetIpsIpAddressResponse> {
-        let url = format!(
-            "/ips/{}",
-            crate::progenitor_support::encode_path(&ip_address.to_string()),
-        );
+        let url = format!("/ips/{}", self.client.encode_path(ip_address),);
+
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/ips/{ip_address}",
+                &[("ip_address", ip_address.to_string())],
+            )
+        })
+    }
+}
 
-        self.client.get(&url, None).await
+/// The functions of [`IpAddresses`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn IpAddressesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait IpAddressesApi: Send + Sync {
+    async fn get_ips(
+        &self,
+        ip: &str,
+        exclude_whitelabels: bool,
+        limit: i64,
+        offset: i64,
+        subuser: &str,
+        sort_by_direction: crate::types::SortByDirection,
+    ) -> Result<Vec<crate::types::GetIpsResponse>>;
+    async fn get_all_ips(
+        &self,
+        ip: &str,
+        exclude_whitelabels: bool,
+        offset: i64,
+        subuser: &str,
+        sort_by_direction: crate::types::SortByDirection,
+    ) -> Result<Vec<crate::types::GetIpsResponse>>;
+    async fn post_ip(
+        &self,
+        body: &crate::types::PostIpsRequest,
+    ) -> Result<crate::types::PostIpsResponseData>;
+    async fn get_ips_remaining(&self) -> Result<crate::types::GetIpsRemainingResponse>;
+    async fn get_ips_assigned(&self) -> Result<Vec<crate::types::GetIpsAssignedResponse>>;
+    async fn get_all_ips_assigned(&self) -> Result<Vec<crate::types::GetIpsAssignedResponse>>;
+    async fn get_ips_ip_address(
+        &self,
+        ip_address: &str,
+    ) -> Result<crate::types::GetIpsIpAddressResponse>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl IpAddressesApi for IpAddresses {
+    async fn get_ips(
+        &self,
+        ip: &str,
+        exclude_whitelabels: bool,
+        limit: i64,
+        offset: i64,
+        subuser: &str,
+        sort_by_direction: crate::types::SortByDirection,
+    ) -> Result<Vec<crate::types::GetIpsResponse>> {
+        IpAddresses::get_ips(
+            self,
+            ip,
+            exclude_whitelabels,
+            limit,
+            offset,
+            subuser,
+            sort_by_direction,
+        )
+        .await
+    }
+    async fn get_all_ips(
+        &self,
+        ip: &str,
+        exclude_whitelabels: bool,
+        offset: i64,
+        subuser: &str,
+        sort_by_direction: crate::types::SortByDirection,
+    ) -> Result<Vec<crate::types::GetIpsResponse>> {
+        IpAddresses::get_all_ips(
+            self,
+            ip,
+            exclude_whitelabels,
+            offset,
+            subuser,
+            sort_by_direction,
+        )
+        .await
+    }
+    async fn post_ip(
+        &self,
+        body: &crate::types::PostIpsRequest,
+    ) -> Result<crate::types::PostIpsResponseData> {
+        IpAddresses::post_ip(self, body).await
+    }
+    async fn get_ips_remaining(&self) -> Result<crate::types::GetIpsRemainingResponse> {
+        IpAddresses::get_ips_remaining(self).await
+    }
+    async fn get_ips_assigned(&self) -> Result<Vec<crate::types::GetIpsAssignedResponse>> {
+        IpAddresses::get_ips_assigned(self).await
+    }
+    async fn get_all_ips_assigned(&self) -> Result<Vec<crate::types::GetIpsAssignedResponse>> {
+        IpAddresses::get_all_ips_assigned(self).await
+    }
+    async fn get_ips_ip_address(
+        &self,
+        ip_address: &str,
+    ) -> Result<crate::types::GetIpsIpAddressResponse> {
+        IpAddresses::get_ips_ip_address(self, ip_address).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct IpPools {
     pub client: Client,
@@ -12,6 +10,14 @@ impl IpPools {
         IpPools { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        IpPools {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieve all IP pools.
      *
@@ -21,7 +27,10 @@ impl IpPools {
      */
     pub async fn get_ips_pools(&self) -> Result<Vec<crate::types::IpPoolsPoolResp>> {
         let url = "/ips/pools".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/ips/pools", &[]))
     }
 
     /**
@@ -35,7 +44,10 @@ impl IpPools {
      */
     pub async fn get_all_ips_pools(&self) -> Result<Vec<crate::types::IpPoolsPoolResp>> {
         let url = "/ips/pools".to_string();
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/ips/pools", &[]))
     }
 
     /**
@@ -59,8 +71,9 @@ impl IpPools {
     ) -> Result<crate::types::IpPoolsPoolResp> {
         let url = "/ips/pools".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/ips/pools", &[]))
     }
 
     /**
@@ -87,14 +100,18 @@ impl IpPools {
         pool_name: &str,
         body: &crate::types::PostIpsWarmupRequest,
     ) -> Result<crate::types::GetIpsAssignedResponse> {
-        let url = format!(
-            "/ips/pools/{}/ips",
-            crate::progenitor_support::encode_path(&pool_name.to_string()),
-        );
+        let url = format!("/ips/pools/{}/ips", self.client.encode_path(pool_name),);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/ips/pools/{pool_name}/ips",
+                    &[("pool_name", pool_name.to_string())],
+                )
+            })
     }
 
     /**
@@ -108,12 +125,15 @@ impl IpPools {
         &self,
         pool_name: &str,
     ) -> Result<crate::types::GetIpsPoolsPoolNameResponse> {
-        let url = format!(
-            "/ips/pools/{}",
-            crate::progenitor_support::encode_path(&pool_name.to_string()),
-        );
+        let url = format!("/ips/pools/{}", self.client.encode_path(pool_name),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/ips/pools/{pool_name}",
+                &[("pool_name", pool_name.to_string())],
+            )
+        })
     }
 
     /**
@@ -128,14 +148,18 @@ impl IpPools {
         pool_name: &str,
         body: &crate::types::PutIpsPoolsPoolNameRequest,
     ) -> Result<crate::types::IpPoolsPoolResp> {
-        let url = format!(
-            "/ips/pools/{}",
-            crate::progenitor_support::encode_path(&pool_name.to_string()),
-        );
+        let url = format!("/ips/pools/{}", self.client.encode_path(pool_name),);
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/ips/pools/{pool_name}",
+                    &[("pool_name", pool_name.to_string())],
+                )
+            })
     }
 
     /**
@@ -146,12 +170,15 @@ impl IpPools {
      * **This endpoint allows you to delete an IP pool.**
      */
     pub async fn delete_ips_pools_pool_name(&self, pool_name: &str) -> Result<crate::types::Help> {
-        let url = format!(
-            "/ips/pools/{}",
-            crate::progenitor_support::encode_path(&pool_name.to_string()),
-        );
+        let url = format!("/ips/pools/{}", self.client.encode_path(pool_name),);
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/ips/pools/{pool_name}",
+                &[("pool_name", pool_name.to_string())],
+            )
+        })
     }
 
     /**
@@ -168,10 +195,97 @@ impl IpPools {
     ) -> Result<crate::types::Help> {
         let url = format!(
             "/ips/pools/{}/ips/{}",
-            crate::progenitor_support::encode_path(&pool_name.to_string()),
-            crate::progenitor_support::encode_path(&ip.to_string()),
+            self.client.encode_path(pool_name),
+            self.client.encode_path(ip),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/ips/pools/{pool_name}/ips/{ip}",
+                &[("pool_name", pool_name.to_string()), ("ip", ip.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`IpPools`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn IpPoolsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait IpPoolsApi: Send + Sync {
+    async fn get_ips_pools(&self) -> Result<Vec<crate::types::IpPoolsPoolResp>>;
+    async fn get_all_ips_pools(&self) -> Result<Vec<crate::types::IpPoolsPoolResp>>;
+    async fn post_ips_pool(
+        &self,
+        body: &crate::types::IpPool,
+    ) -> Result<crate::types::IpPoolsPoolResp>;
+    async fn post_ips_pools_pool_name_ip(
+        &self,
+        pool_name: &str,
+        body: &crate::types::PostIpsWarmupRequest,
+    ) -> Result<crate::types::GetIpsAssignedResponse>;
+    async fn get_ips_pools_pool_name(
+        &self,
+        pool_name: &str,
+    ) -> Result<crate::types::GetIpsPoolsPoolNameResponse>;
+    async fn put_ips_pools_pool_name(
+        &self,
+        pool_name: &str,
+        body: &crate::types::PutIpsPoolsPoolNameRequest,
+    ) -> Result<crate::types::IpPoolsPoolResp>;
+    async fn delete_ips_pools_pool_name(&self, pool_name: &str) -> Result<crate::types::Help>;
+    async fn delete_ips_pools_pool_name_ip(
+        &self,
+        pool_name: &str,
+        ip: &str,
+    ) -> Result<crate::types::Help>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl IpPoolsApi for IpPools {
+    async fn get_ips_pools(&self) -> Result<Vec<crate::types::IpPoolsPoolResp>> {
+        IpPools::get_ips_pools(self).await
+    }
+    async fn get_all_ips_pools(&self) -> Result<Vec<crate::types::IpPoolsPoolResp>> {
+        IpPools::get_all_ips_pools(self).await
+    }
+    async fn post_ips_pool(
+        &self,
+        body: &crate::types::IpPool,
+    ) -> Result<crate::types::IpPoolsPoolResp> {
+        IpPools::post_ips_pool(self, body).await
+    }
+    async fn post_ips_pools_pool_name_ip(
+        &self,
+        pool_name: &str,
+        body: &crate::types::PostIpsWarmupRequest,
+    ) -> Result<crate::types::GetIpsAssignedResponse> {
+        IpPools::post_ips_pools_pool_name_ip(self, pool_name, body).await
+    }
+    async fn get_ips_pools_pool_name(
+        &self,
+        pool_name: &str,
+    ) -> Result<crate::types::GetIpsPoolsPoolNameResponse> {
+        IpPools::get_ips_pools_pool_name(self, pool_name).await
+    }
+    async fn put_ips_pools_pool_name(
+        &self,
+        pool_name: &str,
+        body: &crate::types::PutIpsPoolsPoolNameRequest,
+    ) -> Result<crate::types::IpPoolsPoolResp> {
+        IpPools::put_ips_pools_pool_name(self, pool_name, body).await
+    }
+    async fn delete_ips_pools_pool_name(&self, pool_name: &str) -> Result<crate::types::Help> {
+        IpPools::delete_ips_pools_pool_name(self, pool_name).await
+    }
+    async fn delete_ips_pools_pool_name_ip(
+        &self,
+        pool_name: &str,
+        ip: &str,
+    ) -> Result<crate::types::Help> {
+        IpPools::delete_ips_pools_pool_name_ip(self, pool_name, ip).await
     }
 }
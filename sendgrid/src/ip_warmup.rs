@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct IpWarmup {
     pub client: Client,
@@ -12,6 +10,14 @@ impl IpWarmup {
         IpWarmup { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        IpWarmup {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieve all IPs currently in warmup.
      *
@@ -21,7 +27,10 @@ impl IpWarmup {
      */
     pub async fn get_ips_warmup(&self) -> Result<Vec<crate::types::IpWarmupResponse>> {
         let url = "/ips/warmup".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/ips/warmup", &[]))
     }
 
     /**
@@ -35,7 +44,10 @@ impl IpWarmup {
      */
     pub async fn get_all_ips_warmup(&self) -> Result<Vec<crate::types::IpWarmupResponse>> {
         let url = "/ips/warmup".to_string();
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/ips/warmup", &[]))
     }
 
     /**
@@ -51,8 +63,9 @@ impl IpWarmup {
     ) -> Result<Vec<crate::types::IpWarmupResponse>> {
         let url = "/ips/warmup".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/ips/warmup", &[]))
     }
 
     /**
@@ -68,12 +81,15 @@ impl IpWarmup {
         &self,
         ip_address: &str,
     ) -> Result<Vec<crate::types::IpWarmupResponse>> {
-        let url = format!(
-            "/ips/warmup/{}",
-            crate::progenitor_support::encode_path(&ip_address.to_string()),
-        );
+        let url = format!("/ips/warmup/{}", self.client.encode_path(ip_address),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/ips/warmup/{ip_address}",
+                &[("ip_address", ip_address.to_string())],
+            )
+        })
     }
 
     /**
@@ -91,12 +107,18 @@ impl IpWarmup {
         &self,
         ip_address: &str,
     ) -> Result<Vec<crate::types::IpWarmupResponse>> {
-        let url = format!(
-            "/ips/warmup/{}",
-            crate::progenitor_support::encode_path(&ip_address.to_string()),
-        );
+        let url = format!("/ips/warmup/{}", self.client.encode_path(ip_address),);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/ips/warmup/{ip_address}",
+                    &[("ip_address", ip_address.to_string())],
+                )
+            })
     }
 
     /**
@@ -112,11 +134,69 @@ impl IpWarmup {
         &self,
         ip_address: &str,
     ) -> Result<crate::types::Help> {
-        let url = format!(
-            "/ips/warmup/{}",
-            crate::progenitor_support::encode_path(&ip_address.to_string()),
-        );
+        let url = format!("/ips/warmup/{}", self.client.encode_path(ip_address),);
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/ips/warmup/{ip_address}",
+                &[("ip_address", ip_address.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`IpWarmup`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn IpWarmupApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait IpWarmupApi: Send + Sync {
+    async fn get_ips_warmup(&self) -> Result<Vec<crate::types::IpWarmupResponse>>;
+    async fn get_all_ips_warmup(&self) -> Result<Vec<crate::types::IpWarmupResponse>>;
+    async fn post_ips_warmup(
+        &self,
+        body: &crate::types::PostIpsWarmupRequest,
+    ) -> Result<Vec<crate::types::IpWarmupResponse>>;
+    async fn get_ips_warmup_ip_address(
+        &self,
+        ip_address: &str,
+    ) -> Result<Vec<crate::types::IpWarmupResponse>>;
+    async fn get_all_ips_warmup_ip_address(
+        &self,
+        ip_address: &str,
+    ) -> Result<Vec<crate::types::IpWarmupResponse>>;
+    async fn delete_ips_warmup_ip_address(&self, ip_address: &str) -> Result<crate::types::Help>;
+}
 
-        self.client.delete(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl IpWarmupApi for IpWarmup {
+    async fn get_ips_warmup(&self) -> Result<Vec<crate::types::IpWarmupResponse>> {
+        IpWarmup::get_ips_warmup(self).await
+    }
+    async fn get_all_ips_warmup(&self) -> Result<Vec<crate::types::IpWarmupResponse>> {
+        IpWarmup::get_all_ips_warmup(self).await
+    }
+    async fn post_ips_warmup(
+        &self,
+        body: &crate::types::PostIpsWarmupRequest,
+    ) -> Result<Vec<crate::types::IpWarmupResponse>> {
+        IpWarmup::post_ips_warmup(self, body).await
+    }
+    async fn get_ips_warmup_ip_address(
+        &self,
+        ip_address: &str,
+    ) -> Result<Vec<crate::types::IpWarmupResponse>> {
+        IpWarmup::get_ips_warmup_ip_address(self, ip_address).await
+    }
+    async fn get_all_ips_warmup_ip_address(
+        &self,
+        ip_address: &str,
+    ) -> Result<Vec<crate::types::IpWarmupResponse>> {
+        IpWarmup::get_all_ips_warmup_ip_address(self, ip_address).await
+    }
+    async fn delete_ips_warmup_ip_address(&self, ip_address: &str) -> Result<crate::types::Help> {
+        IpWarmup::delete_ips_warmup_ip_address(self, ip_address).await
     }
 }
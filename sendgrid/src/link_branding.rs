@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct LinkBranding {
     pub client: Client,
@@ -12,6 +10,14 @@ impl LinkBranding {
         LinkBranding { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        LinkBranding {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieve all branded links.
      *
@@ -30,14 +36,16 @@ impl LinkBranding {
         &self,
         limit: i64,
     ) -> Result<Vec<crate::types::LinkBranding200Response>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/whitelabel/links?{}", query_);
+        let url = crate::Client::url_with_query("/whitelabel/links", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/whitelabel/links", &[]))
     }
 
     /**
@@ -55,7 +63,10 @@ impl LinkBranding {
         &self,
     ) -> Result<Vec<crate::types::LinkBranding200Response>> {
         let url = "/whitelabel/links".to_string();
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/whitelabel/links", &[]))
     }
 
     /**
@@ -79,8 +90,9 @@ impl LinkBranding {
     ) -> Result<crate::types::LinkBranding200Response> {
         let url = "/whitelabel/links".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/whitelabel/links", &[]))
     }
 
     /**
@@ -102,10 +114,16 @@ impl LinkBranding {
     ) -> Result<crate::types::PostWhitelabelLinksValidateResponse> {
         let url = format!(
             "/whitelabel/links/{}/validate",
-            crate::progenitor_support::encode_path(&id.to_string()),
+            self.client.encode_path(&id.to_string()),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/whitelabel/links/{id}/validate",
+                &[("id", id.to_string())],
+            )
+        })
     }
 
     /**
@@ -124,12 +142,19 @@ impl LinkBranding {
     ) -> Result<crate::types::LinkBranding200Response> {
         let url = format!(
             "/whitelabel/links/{}/subuser",
-            crate::progenitor_support::encode_path(&link_id.to_string()),
+            self.client.encode_path(&link_id.to_string()),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/whitelabel/links/{link_id}/subuser",
+                    &[("link_id", link_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -151,10 +176,12 @@ impl LinkBranding {
     ) -> Result<crate::types::LinkBranding200Response> {
         let url = format!(
             "/whitelabel/links/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
+            self.client.encode_path(&id.to_string()),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/whitelabel/links/{id}", &[("id", id.to_string())])
+        })
     }
 
     /**
@@ -175,10 +202,16 @@ impl LinkBranding {
     pub async fn delete_whitelabel_links(&self, id: i64) -> Result<crate::types::Help> {
         let url = format!(
             "/whitelabel/links/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
+            self.client.encode_path(&id.to_string()),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/whitelabel/links/{id}",
+                &[("id", id.to_string())],
+            )
+        })
     }
 
     /**
@@ -201,12 +234,49 @@ impl LinkBranding {
     ) -> Result<crate::types::LinkBranding200Response> {
         let url = format!(
             "/whitelabel/links/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
+            self.client.encode_path(&id.to_string()),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/whitelabel/links/{id}",
+                    &[("id", id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update a branded link.
+     *
+     * This function performs a `PATCH` to the `/whitelabel/links/{id}` endpoint.
+     *
+     * As opposed to `patch_whitelabel_links`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_whitelabel_links_if_changed<C: serde::Serialize>(
+        &self,
+        id: i64,
+        current: &C,
+        body: &crate::types::PatchWhitelabelLinksRequest,
+    ) -> Result<Option<crate::types::LinkBranding200Response>> {
+        let url = format!(
+            "/whitelabel/links/{}",
+            self.client.encode_path(&id.to_string()),
+        );
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/whitelabel/links/{id}",
+                    &[("id", id.to_string())],
+                )
+            })
     }
 
     /**
@@ -233,14 +303,16 @@ impl LinkBranding {
         &self,
         domain: &str,
     ) -> Result<crate::types::LinkBranding200Response> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !domain.is_empty() {
-            query_args.push(("domain".to_string(), domain.to_string()));
+            query_args.push(("domain", domain.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/whitelabel/links/default?{}", query_);
+        let url = crate::Client::url_with_query("/whitelabel/links/default", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/whitelabel/links/default", &[]))
     }
 
     /**
@@ -260,14 +332,16 @@ impl LinkBranding {
         &self,
         username: &str,
     ) -> Result<crate::types::LinkBranding200Response> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !username.is_empty() {
-            query_args.push(("username".to_string(), username.to_string()));
+            query_args.push(("username", username.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/whitelabel/links/subuser?{}", query_);
+        let url = crate::Client::url_with_query("/whitelabel/links/subuser", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/whitelabel/links/subuser", &[]))
     }
 
     /**
@@ -289,13 +363,124 @@ impl LinkBranding {
         &self,
         username: &str,
     ) -> Result<crate::types::Help> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !username.is_empty() {
-            query_args.push(("username".to_string(), username.to_string()));
+            query_args.push(("username", username.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/whitelabel/links/subuser?{}", query_);
+        let url = crate::Client::url_with_query("/whitelabel/links/subuser", &query_args);
+
+        self.client
+            .delete(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("DELETE", "/whitelabel/links/subuser", &[]))
+    }
+}
+
+/// The functions of [`LinkBranding`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn LinkBrandingApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait LinkBrandingApi: Send + Sync {
+    async fn get_whitelabel_links(
+        &self,
+        limit: i64,
+    ) -> Result<Vec<crate::types::LinkBranding200Response>>;
+    async fn get_all_whitelabel_links(&self) -> Result<Vec<crate::types::LinkBranding200Response>>;
+    async fn post_whitelabel_link(
+        &self,
+        body: &crate::types::PostWhitelabelLinksRequest,
+    ) -> Result<crate::types::LinkBranding200Response>;
+    async fn post_whitelabel_links_validate(
+        &self,
+        id: i64,
+    ) -> Result<crate::types::PostWhitelabelLinksValidateResponse>;
+    async fn post_whitelabel_links_link_subuser(
+        &self,
+        link_id: i64,
+        body: &crate::types::PostWhitelabelLinksLinkSubuserRequest,
+    ) -> Result<crate::types::LinkBranding200Response>;
+    async fn get_whitelabel_links_link_branding(
+        &self,
+        id: i64,
+    ) -> Result<crate::types::LinkBranding200Response>;
+    async fn delete_whitelabel_links(&self, id: i64) -> Result<crate::types::Help>;
+    async fn patch_whitelabel_links(
+        &self,
+        id: i64,
+        body: &crate::types::PatchWhitelabelLinksRequest,
+    ) -> Result<crate::types::LinkBranding200Response>;
+    async fn get_whitelabel_links_default(
+        &self,
+        domain: &str,
+    ) -> Result<crate::types::LinkBranding200Response>;
+    async fn get_whitelabel_links_subuser(
+        &self,
+        username: &str,
+    ) -> Result<crate::types::LinkBranding200Response>;
+    async fn delete_whitelabel_links_subuser(&self, username: &str) -> Result<crate::types::Help>;
+}
 
-        self.client.delete(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl LinkBrandingApi for LinkBranding {
+    async fn get_whitelabel_links(
+        &self,
+        limit: i64,
+    ) -> Result<Vec<crate::types::LinkBranding200Response>> {
+        LinkBranding::get_whitelabel_links(self, limit).await
+    }
+    async fn get_all_whitelabel_links(&self) -> Result<Vec<crate::types::LinkBranding200Response>> {
+        LinkBranding::get_all_whitelabel_links(self).await
+    }
+    async fn post_whitelabel_link(
+        &self,
+        body: &crate::types::PostWhitelabelLinksRequest,
+    ) -> Result<crate::types::LinkBranding200Response> {
+        LinkBranding::post_whitelabel_link(self, body).await
+    }
+    async fn post_whitelabel_links_validate(
+        &self,
+        id: i64,
+    ) -> Result<crate::types::PostWhitelabelLinksValidateResponse> {
+        LinkBranding::post_whitelabel_links_validate(self, id).await
+    }
+    async fn post_whitelabel_links_link_subuser(
+        &self,
+        link_id: i64,
+        body: &crate::types::PostWhitelabelLinksLinkSubuserRequest,
+    ) -> Result<crate::types::LinkBranding200Response> {
+        LinkBranding::post_whitelabel_links_link_subuser(self, link_id, body).await
+    }
+    async fn get_whitelabel_links_link_branding(
+        &self,
+        id: i64,
+    ) -> Result<crate::types::LinkBranding200Response> {
+        LinkBranding::get_whitelabel_links_link_branding(self, id).await
+    }
+    async fn delete_whitelabel_links(&self, id: i64) -> Result<crate::types::Help> {
+        LinkBranding::delete_whitelabel_links(self, id).await
+    }
+    async fn patch_whitelabel_links(
+        &self,
+        id: i64,
+        body: &crate::types::PatchWhitelabelLinksRequest,
+    ) -> Result<crate::types::LinkBranding200Response> {
+        LinkBranding::patch_whitelabel_links(self, id, body).await
+    }
+    async fn get_whitelabel_links_default(
+        &self,
+        domain: &str,
+    ) -> Result<crate::types::LinkBranding200Response> {
+        LinkBranding::get_whitelabel_links_default(self, domain).await
+    }
+    async fn get_whitelabel_links_subuser(
+        &self,
+        username: &str,
+    ) -> Result<crate::types::LinkBranding200Response> {
+        LinkBranding::get_whitelabel_links_subuser(self, username).await
+    }
+    async fn delete_whitelabel_links_subuser(&self, username: &str) -> Result<crate::types::Help> {
+        LinkBranding::delete_whitelabel_links_subuser(self, username).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Lists {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Lists {
         Lists { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Lists {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get All Lists.
      *
@@ -29,17 +35,19 @@ impl Lists {
         page_size: f64,
         page_token: &str,
     ) -> Result<crate::types::GetMcListsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !page_size.to_string().is_empty() {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("page_token".to_string(), page_token.to_string()));
+            query_args.push(("page_token", page_token.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/marketing/lists?{}", query_);
+        let url = crate::Client::url_with_query("/marketing/lists", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/marketing/lists", &[]))
     }
 
     /**
@@ -56,8 +64,9 @@ impl Lists {
     pub async fn post_mc(&self, body: &crate::types::IpPool) -> Result<crate::types::List> {
         let url = "/marketing/lists".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/marketing/lists", &[]))
     }
 
     /**
@@ -73,10 +82,16 @@ impl Lists {
     ) -> Result<crate::types::GetMcListsContactsCountResponse> {
         let url = format!(
             "/marketing/lists/{}/contacts/count",
-            crate::progenitor_support::encode_path(&id.to_string()),
+            self.client.encode_path(id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/marketing/lists/{id}/contacts/count",
+                &[("id", id.to_string())],
+            )
+        })
     }
 
     /**
@@ -99,18 +114,18 @@ impl Lists {
         id: &str,
         contact_sample: bool,
     ) -> Result<crate::types::GetMcListsResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if contact_sample {
-            query_args.push(("contact_sample".to_string(), contact_sample.to_string()));
+            query_args.push(("contact_sample", contact_sample.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/marketing/lists/{}?{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/marketing/lists/{}", self.client.encode_path(id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/marketing/lists/{id}", &[("id", id.to_string())])
+        })
     }
 
     /**
@@ -131,18 +146,18 @@ impl Lists {
         id: &str,
         delete_contacts: bool,
     ) -> Result<crate::types::DeleteListsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if delete_contacts {
-            query_args.push(("delete_contacts".to_string(), delete_contacts.to_string()));
+            query_args.push(("delete_contacts", delete_contacts.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/marketing/lists/{}?{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/marketing/lists/{}", self.client.encode_path(id)),
+            &query_args,
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("DELETE", "/marketing/lists/{id}", &[("id", id.to_string())])
+        })
     }
 
     /**
@@ -157,14 +172,45 @@ impl Lists {
         id: &str,
         body: &crate::types::PatchMcListsRequest,
     ) -> Result<crate::types::List> {
-        let url = format!(
-            "/marketing/lists/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/marketing/lists/{}", self.client.encode_path(id),);
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/marketing/lists/{id}",
+                    &[("id", id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update List.
+     *
+     * This function performs a `PATCH` to the `/marketing/lists/{id}` endpoint.
+     *
+     * As opposed to `patch_mc`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_mc_if_changed<C: serde::Serialize>(
+        &self,
+        id: &str,
+        current: &C,
+        body: &crate::types::PatchMcListsRequest,
+    ) -> Result<Option<crate::types::List>> {
+        let url = format!("/marketing/lists/{}", self.client.encode_path(id),);
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/marketing/lists/{id}",
+                    &[("id", id.to_string())],
+                )
+            })
     }
 
     /**
@@ -185,17 +231,108 @@ impl Lists {
         id: &str,
         contact_ids: &str,
     ) -> Result<crate::types::DeleteMcListsContactsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !contact_ids.is_empty() {
-            query_args.push(("contact_ids".to_string(), contact_ids.to_string()));
+            query_args.push(("contact_ids", contact_ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/marketing/lists/{}/contacts?{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/marketing/lists/{}/contacts", self.client.encode_path(id)),
+            &query_args,
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/marketing/lists/{id}/contacts",
+                &[("id", id.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`Lists`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ListsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ListsApi: Send + Sync {
+    async fn get_mc(
+        &self,
+        page_size: f64,
+        page_token: &str,
+    ) -> Result<crate::types::GetMcListsResponse>;
+    async fn post_mc(&self, body: &crate::types::IpPool) -> Result<crate::types::List>;
+    async fn get_mc_contacts_count(
+        &self,
+        id: &str,
+    ) -> Result<crate::types::GetMcListsContactsCountResponse>;
+    async fn get_mc_lists(
+        &self,
+        id: &str,
+        contact_sample: bool,
+    ) -> Result<crate::types::GetMcListsResponseAllOf>;
+    async fn delete(
+        &self,
+        id: &str,
+        delete_contacts: bool,
+    ) -> Result<crate::types::DeleteListsResponse>;
+    async fn patch_mc(
+        &self,
+        id: &str,
+        body: &crate::types::PatchMcListsRequest,
+    ) -> Result<crate::types::List>;
+    async fn delete_mc_contacts(
+        &self,
+        id: &str,
+        contact_ids: &str,
+    ) -> Result<crate::types::DeleteMcListsContactsResponse>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ListsApi for Lists {
+    async fn get_mc(
+        &self,
+        page_size: f64,
+        page_token: &str,
+    ) -> Result<crate::types::GetMcListsResponse> {
+        Lists::get_mc(self, page_size, page_token).await
+    }
+    async fn post_mc(&self, body: &crate::types::IpPool) -> Result<crate::types::List> {
+        Lists::post_mc(self, body).await
+    }
+    async fn get_mc_contacts_count(
+        &self,
+        id: &str,
+    ) -> Result<crate::types::GetMcListsContactsCountResponse> {
+        Lists::get_mc_contacts_count(self, id).await
+    }
+    async fn get_mc_lists(
+        &self,
+        id: &str,
+        contact_sample: bool,
+    ) -> Result<crate::types::GetMcListsResponseAllOf> {
+        Lists::get_mc_lists(self, id, contact_sample).await
+    }
+    async fn delete(
+        &self,
+        id: &str,
+        delete_contacts: bool,
+    ) -> Result<crate::types::DeleteListsResponse> {
+        Lists::delete(self, id, delete_contacts).await
+    }
+    async fn patch_mc(
+        &self,
+        id: &str,
+        body: &crate::types::PatchMcListsRequest,
+    ) -> Result<crate::types::List> {
+        Lists::patch_mc(self, id, body).await
+    }
+    async fn delete_mc_contacts(
+        &self,
+        id: &str,
+        contact_ids: &str,
+    ) -> Result<crate::types::DeleteMcListsContactsResponse> {
+        Lists::delete_mc_contacts(self, id, contact_ids).await
     }
 }
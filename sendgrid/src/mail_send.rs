@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct MailSend {
     pub client: Client,
@@ -12,6 +10,14 @@ impl MailSend {
         MailSend { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        MailSend {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * v3 Mail Send.
      *
@@ -66,8 +72,34 @@ impl MailSend {
      */
     pub async fn post(&self, body: &crate::types::PostMailSendRequest) -> Result<()> {
         let url = "/mail/send".to_string();
+        crate::utils::check_items(
+            "personalizations",
+            body.personalizations.len(),
+            None,
+            Some(1000),
+        )?;
+        crate::utils::check_items("categories", body.categories.len(), None, Some(10))?;
+
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/mail/send", &[]))
+    }
+}
+
+/// The functions of [`MailSend`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn MailSendApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait MailSendApi: Send + Sync {
+    async fn post(&self, body: &crate::types::PostMailSendRequest) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl MailSendApi for MailSend {
+    async fn post(&self, body: &crate::types::PostMailSendRequest) -> Result<()> {
+        MailSend::post(self, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct MarketingCampaignsStats {
     pub client: Client,
@@ -12,6 +10,14 @@ impl MarketingCampaignsStats {
         MarketingCampaignsStats { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        MarketingCampaignsStats {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get All Automation Stats.
      *
@@ -37,20 +43,23 @@ impl MarketingCampaignsStats {
         page_size: i64,
         page_token: &str,
     ) -> Result<crate::types::AutomationsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !automation_ids.is_empty() {
-            query_args.push(("automation_ids".to_string(), automation_ids.join(" ")));
+            query_args.push(("automation_ids", automation_ids.join(",")));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("page_token".to_string(), page_token.to_string()));
+            query_args.push(("page_token", page_token.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/marketing/stats/automations?{}", query_);
+        let url = crate::Client::url_with_query("/marketing/stats/automations", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/marketing/stats/automations", &[]))
     }
 
     /**
@@ -87,39 +96,47 @@ impl MarketingCampaignsStats {
         page_size: i64,
         page_token: &str,
     ) -> Result<crate::types::AutomationsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !end_date.to_string().is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if !group_by.is_empty() {
-            query_args.push(("group_by".to_string(), group_by.join(" ")));
+            query_args.push(("group_by", group_by.join(",")));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("page_token".to_string(), page_token.to_string()));
+            query_args.push(("page_token", page_token.to_string()));
         }
         if !start_date.to_string().is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
         if !step_ids.is_empty() {
-            query_args.push(("step_ids".to_string(), step_ids.join(" ")));
+            query_args.push(("step_ids", step_ids.join(",")));
         }
         if !timezone.is_empty() {
-            query_args.push(("timezone".to_string(), timezone.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/marketing/stats/automations/{}?{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-            query_
+            query_args.push(("timezone", timezone.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/marketing/stats/automations/{}",
+                self.client.encode_path(id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/marketing/stats/automations/{id}",
+                &[("id", id.to_string())],
+            )
+        })
     }
 
     /**
@@ -147,20 +164,23 @@ impl MarketingCampaignsStats {
         page_size: i64,
         page_token: &str,
     ) -> Result<crate::types::SinglesendsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("page_token".to_string(), page_token.to_string()));
+            query_args.push(("page_token", page_token.to_string()));
         }
         if !singlesend_ids.is_empty() {
-            query_args.push(("singlesend_ids".to_string(), singlesend_ids.join(" ")));
+            query_args.push(("singlesend_ids", singlesend_ids.join(",")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/marketing/stats/singlesends?{}", query_);
+        let url = crate::Client::url_with_query("/marketing/stats/singlesends", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/marketing/stats/singlesends", &[]))
     }
 
     /**
@@ -195,36 +215,44 @@ impl MarketingCampaignsStats {
         page_token: &str,
         group_by: &[String],
     ) -> Result<crate::types::SinglesendsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !end_date.to_string().is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if !group_by.is_empty() {
-            query_args.push(("group_by".to_string(), group_by.join(" ")));
+            query_args.push(("group_by", group_by.join(",")));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("page_token".to_string(), page_token.to_string()));
+            query_args.push(("page_token", page_token.to_string()));
         }
         if !start_date.to_string().is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
         if !timezone.is_empty() {
-            query_args.push(("timezone".to_string(), timezone.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/marketing/stats/singlesends/{}?{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-            query_
+            query_args.push(("timezone", timezone.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/marketing/stats/singlesends/{}",
+                self.client.encode_path(id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/marketing/stats/singlesends/{id}",
+                &[("id", id.to_string())],
+            )
+        })
     }
 
     /**
@@ -255,27 +283,35 @@ impl MarketingCampaignsStats {
         page_size: i64,
         page_token: &str,
     ) -> Result<crate::types::AutomationsLinkStatsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !group_by.is_empty() {
-            query_args.push(("group_by".to_string(), group_by.join(" ")));
+            query_args.push(("group_by", group_by.join(",")));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("page_token".to_string(), page_token.to_string()));
+            query_args.push(("page_token", page_token.to_string()));
         }
         if !step_ids.is_empty() {
-            query_args.push(("step_ids".to_string(), step_ids.join(" ")));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/marketing/stats/automations/{}/links?{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-            query_
+            query_args.push(("step_ids", step_ids.join(",")));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/marketing/stats/automations/{}/links",
+                self.client.encode_path(id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/marketing/stats/automations/{id}/links",
+                &[("id", id.to_string())],
+            )
+        })
     }
 
     /**
@@ -308,30 +344,38 @@ impl MarketingCampaignsStats {
         ab_variation_id: &str,
         ab_phase_id: crate::types::AbPhaseId,
     ) -> Result<crate::types::SinglesendsLinkStatsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ab_phase_id.to_string().is_empty() {
-            query_args.push(("ab_phase_id".to_string(), ab_phase_id.to_string()));
+            query_args.push(("ab_phase_id", ab_phase_id.to_string()));
         }
         if !ab_variation_id.is_empty() {
-            query_args.push(("ab_variation_id".to_string(), ab_variation_id.to_string()));
+            query_args.push(("ab_variation_id", ab_variation_id.to_string()));
         }
         if !group_by.is_empty() {
-            query_args.push(("group_by".to_string(), group_by.join(" ")));
+            query_args.push(("group_by", group_by.join(",")));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("page_token".to_string(), page_token.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/marketing/stats/singlesends/{}/links?{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-            query_
+            query_args.push(("page_token", page_token.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/marketing/stats/singlesends/{}/links",
+                self.client.encode_path(id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/marketing/stats/singlesends/{id}/links",
+                &[("id", id.to_string())],
+            )
+        })
     }
 
     /**
@@ -355,17 +399,18 @@ impl MarketingCampaignsStats {
         ids: &[String],
         timezone: &str,
     ) -> Result<String> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.join(" ")));
+            query_args.push(("ids", ids.join(",")));
         }
         if !timezone.is_empty() {
-            query_args.push(("timezone".to_string(), timezone.to_string()));
+            query_args.push(("timezone", timezone.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/marketing/stats/singlesends/export?{}", query_);
+        let url = crate::Client::url_with_query("/marketing/stats/singlesends/export", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/marketing/stats/singlesends/export", &[])
+        })
     }
 
     /**
@@ -389,16 +434,201 @@ impl MarketingCampaignsStats {
         ids: &[String],
         timezone: &str,
     ) -> Result<String> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.join(" ")));
+            query_args.push(("ids", ids.join(",")));
         }
         if !timezone.is_empty() {
-            query_args.push(("timezone".to_string(), timezone.to_string()));
+            query_args.push(("timezone", timezone.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/marketing/stats/automations/export?{}", query_);
+        let url = crate::Client::url_with_query("/marketing/stats/automations/export", &query_args);
+
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/marketing/stats/automations/export", &[])
+        })
+    }
+}
+
+/// The functions of [`MarketingCampaignsStats`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn MarketingCampaignsStatsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait MarketingCampaignsStatsApi: Send + Sync {
+    async fn getall_automation_stats(
+        &self,
+        automation_ids: &[String],
+        page_size: i64,
+        page_token: &str,
+    ) -> Result<crate::types::AutomationsResponse>;
+    async fn get_automation_stat(
+        &self,
+        id: &str,
+        group_by: &[String],
+        step_ids: &[String],
+        aggregated_by: crate::types::AggregatedBy,
+        start_date: chrono::NaiveDate,
+        end_date: chrono::NaiveDate,
+        timezone: &str,
+        page_size: i64,
+        page_token: &str,
+    ) -> Result<crate::types::AutomationsResponse>;
+    async fn getall_singlesend_stats(
+        &self,
+        singlesend_ids: &[String],
+        page_size: i64,
+        page_token: &str,
+    ) -> Result<crate::types::SinglesendsResponse>;
+    async fn get_singlesend_stat(
+        &self,
+        id: &str,
+        aggregated_by: crate::types::AggregatedBy,
+        start_date: chrono::NaiveDate,
+        end_date: chrono::NaiveDate,
+        timezone: &str,
+        page_size: i64,
+        page_token: &str,
+        group_by: &[String],
+    ) -> Result<crate::types::SinglesendsResponse>;
+    async fn get_automation_link_stat(
+        &self,
+        id: &str,
+        group_by: &[String],
+        step_ids: &[String],
+        page_size: i64,
+        page_token: &str,
+    ) -> Result<crate::types::AutomationsLinkStatsResponse>;
+    async fn get_singlesend_link_stat(
+        &self,
+        id: &str,
+        page_size: i64,
+        page_token: &str,
+        group_by: &[String],
+        ab_variation_id: &str,
+        ab_phase_id: crate::types::AbPhaseId,
+    ) -> Result<crate::types::SinglesendsLinkStatsResponse>;
+    async fn get_singlesend_stats_export(&self, ids: &[String], timezone: &str) -> Result<String>;
+    async fn get_automations_stats_export(&self, ids: &[String], timezone: &str) -> Result<String>;
+}
 
-        self.client.get(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl MarketingCampaignsStatsApi for MarketingCampaignsStats {
+    async fn getall_automation_stats(
+        &self,
+        automation_ids: &[String],
+        page_size: i64,
+        page_token: &str,
+    ) -> Result<crate::types::AutomationsResponse> {
+        MarketingCampaignsStats::getall_automation_stats(
+            self,
+            automation_ids,
+            page_size,
+            page_token,
+        )
+        .await
+    }
+    async fn get_automation_stat(
+        &self,
+        id: &str,
+        group_by: &[String],
+        step_ids: &[String],
+        aggregated_by: crate::types::AggregatedBy,
+        start_date: chrono::NaiveDate,
+        end_date: chrono::NaiveDate,
+        timezone: &str,
+        page_size: i64,
+        page_token: &str,
+    ) -> Result<crate::types::AutomationsResponse> {
+        MarketingCampaignsStats::get_automation_stat(
+            self,
+            id,
+            group_by,
+            step_ids,
+            aggregated_by,
+            start_date,
+            end_date,
+            timezone,
+            page_size,
+            page_token,
+        )
+        .await
+    }
+    async fn getall_singlesend_stats(
+        &self,
+        singlesend_ids: &[String],
+        page_size: i64,
+        page_token: &str,
+    ) -> Result<crate::types::SinglesendsResponse> {
+        MarketingCampaignsStats::getall_singlesend_stats(
+            self,
+            singlesend_ids,
+            page_size,
+            page_token,
+        )
+        .await
+    }
+    async fn get_singlesend_stat(
+        &self,
+        id: &str,
+        aggregated_by: crate::types::AggregatedBy,
+        start_date: chrono::NaiveDate,
+        end_date: chrono::NaiveDate,
+        timezone: &str,
+        page_size: i64,
+        page_token: &str,
+        group_by: &[String],
+    ) -> Result<crate::types::SinglesendsResponse> {
+        MarketingCampaignsStats::get_singlesend_stat(
+            self,
+            id,
+            aggregated_by,
+            start_date,
+            end_date,
+            timezone,
+            page_size,
+            page_token,
+            group_by,
+        )
+        .await
+    }
+    async fn get_automation_link_stat(
+        &self,
+        id: &str,
+        group_by: &[String],
+        step_ids: &[String],
+        page_size: i64,
+        page_token: &str,
+    ) -> Result<crate::types::AutomationsLinkStatsResponse> {
+        MarketingCampaignsStats::get_automation_link_stat(
+            self, id, group_by, step_ids, page_size, page_token,
+        )
+        .await
+    }
+    async fn get_singlesend_link_stat(
+        &self,
+        id: &str,
+        page_size: i64,
+        page_token: &str,
+        group_by: &[String],
+        ab_variation_id: &str,
+        ab_phase_id: crate::types::AbPhaseId,
+    ) -> Result<crate::types::SinglesendsLinkStatsResponse> {
+        MarketingCampaignsStats::get_singlesend_link_stat(
+            self,
+            id,
+            page_size,
+            page_token,
+            group_by,
+            ab_variation_id,
+            ab_phase_id,
+        )
+        .await
+    }
+    async fn get_singlesend_stats_export(&self, ids: &[String], timezone: &str) -> Result<String> {
+        MarketingCampaignsStats::get_singlesend_stats_export(self, ids, timezone).await
+    }
+    async fn get_automations_stats_export(&self, ids: &[String], timezone: &str) -> Result<String> {
+        MarketingCampaignsStats::get_automations_stats_export(self, ids, timezone).await
     }
 }
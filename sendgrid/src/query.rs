@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Query {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Query {
         Query { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Query {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Filter all messages.
      *
@@ -71,17 +77,19 @@ impl Query {
         query: &str,
         limit: f64,
     ) -> Result<crate::types::GetMessagesResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.to_string().is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/messages?{}", query_);
+        let url = crate::Client::url_with_query("/messages", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/messages", &[]))
     }
 
     /**
@@ -98,11 +106,43 @@ impl Query {
      * * `authorization: &str` -- The license key provided with your New Relic account.
      */
     pub async fn get_messages_msg(&self, msg_id: &str) -> Result<crate::types::Message> {
-        let url = format!(
-            "/messages/{}",
-            crate::progenitor_support::encode_path(&msg_id.to_string()),
-        );
+        let url = format!("/messages/{}", self.client.encode_path(msg_id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/messages/{msg_id}",
+                &[("msg_id", msg_id.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`Query`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn QueryApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait QueryApi: Send + Sync {
+    async fn get_messages(
+        &self,
+        query: &str,
+        limit: f64,
+    ) -> Result<crate::types::GetMessagesResponse>;
+    async fn get_messages_msg(&self, msg_id: &str) -> Result<crate::types::Message>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl QueryApi for Query {
+    async fn get_messages(
+        &self,
+        query: &str,
+        limit: f64,
+    ) -> Result<crate::types::GetMessagesResponse> {
+        Query::get_messages(self, query, limit).await
+    }
+    async fn get_messages_msg(&self, msg_id: &str) -> Result<crate::types::Message> {
+        Query::get_messages_msg(self, msg_id).await
     }
 }
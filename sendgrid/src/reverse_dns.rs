@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct ReverseDns {
     pub client: Client,
@@ -12,6 +10,14 @@ impl ReverseDns {
         ReverseDns { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        ReverseDns {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieve all reverse DNS records.
      *
@@ -38,20 +44,22 @@ impl ReverseDns {
         offset: i64,
         ip: &str,
     ) -> Result<Vec<crate::types::ReverseDns>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ip.is_empty() {
-            query_args.push(("ip".to_string(), ip.to_string()));
+            query_args.push(("ip", ip.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/whitelabel/ips?{}", query_);
+        let url = crate::Client::url_with_query("/whitelabel/ips", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/whitelabel/ips", &[]))
     }
 
     /**
@@ -74,17 +82,19 @@ impl ReverseDns {
         offset: i64,
         ip: &str,
     ) -> Result<Vec<crate::types::ReverseDns>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ip.is_empty() {
-            query_args.push(("ip".to_string(), ip.to_string()));
+            query_args.push(("ip", ip.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/whitelabel/ips?{}", query_);
+        let url = crate::Client::url_with_query("/whitelabel/ips", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/whitelabel/ips", &[]))
     }
 
     /**
@@ -104,8 +114,9 @@ impl ReverseDns {
     ) -> Result<crate::types::ReverseDns> {
         let url = "/whitelabel/ips".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/whitelabel/ips", &[]))
     }
 
     /**
@@ -129,12 +140,15 @@ impl ReverseDns {
         &self,
         id: &str,
     ) -> Result<crate::types::PostWhitelabelIpsValidateResponse> {
-        let url = format!(
-            "/whitelabel/ips/{}/validate",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/whitelabel/ips/{}/validate", self.client.encode_path(id),);
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/whitelabel/ips/{id}/validate",
+                &[("id", id.to_string())],
+            )
+        })
     }
 
     /**
@@ -151,12 +165,11 @@ impl ReverseDns {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn get_whitelabel_ip(&self, id: &str) -> Result<crate::types::ReverseDns> {
-        let url = format!(
-            "/whitelabel/ips/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/whitelabel/ips/{}", self.client.encode_path(id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/whitelabel/ips/{id}", &[("id", id.to_string())])
+        })
     }
 
     /**
@@ -175,11 +188,77 @@ impl ReverseDns {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn delete_whitelabel_ips(&self, id: &str) -> Result<crate::types::Help> {
-        let url = format!(
-            "/whitelabel/ips/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/whitelabel/ips/{}", self.client.encode_path(id),);
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("DELETE", "/whitelabel/ips/{id}", &[("id", id.to_string())])
+        })
+    }
+}
+
+/// The functions of [`ReverseDns`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ReverseDnsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ReverseDnsApi: Send + Sync {
+    async fn get_whitelabel_ips(
+        &self,
+        limit: i64,
+        offset: i64,
+        ip: &str,
+    ) -> Result<Vec<crate::types::ReverseDns>>;
+    async fn get_all_whitelabel_ips(
+        &self,
+        offset: i64,
+        ip: &str,
+    ) -> Result<Vec<crate::types::ReverseDns>>;
+    async fn post_whitelabel_ip(
+        &self,
+        body: &crate::types::PostWhitelabelIpsRequest,
+    ) -> Result<crate::types::ReverseDns>;
+    async fn post_whitelabel_ips_validate(
+        &self,
+        id: &str,
+    ) -> Result<crate::types::PostWhitelabelIpsValidateResponse>;
+    async fn get_whitelabel_ip(&self, id: &str) -> Result<crate::types::ReverseDns>;
+    async fn delete_whitelabel_ips(&self, id: &str) -> Result<crate::types::Help>;
+}
 
-        self.client.delete(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ReverseDnsApi for ReverseDns {
+    async fn get_whitelabel_ips(
+        &self,
+        limit: i64,
+        offset: i64,
+        ip: &str,
+    ) -> Result<Vec<crate::types::ReverseDns>> {
+        ReverseDns::get_whitelabel_ips(self, limit, offset, ip).await
+    }
+    async fn get_all_whitelabel_ips(
+        &self,
+        offset: i64,
+        ip: &str,
+    ) -> Result<Vec<crate::types::ReverseDns>> {
+        ReverseDns::get_all_whitelabel_ips(self, offset, ip).await
+    }
+    async fn post_whitelabel_ip(
+        &self,
+        body: &crate::types::PostWhitelabelIpsRequest,
+    ) -> Result<crate::types::ReverseDns> {
+        ReverseDns::post_whitelabel_ip(self, body).await
+    }
+    async fn post_whitelabel_ips_validate(
+        &self,
+        id: &str,
+    ) -> Result<crate::types::PostWhitelabelIpsValidateResponse> {
+        ReverseDns::post_whitelabel_ips_validate(self, id).await
+    }
+    async fn get_whitelabel_ip(&self, id: &str) -> Result<crate::types::ReverseDns> {
+        ReverseDns::get_whitelabel_ip(self, id).await
+    }
+    async fn delete_whitelabel_ips(&self, id: &str) -> Result<crate::types::Help> {
+        ReverseDns::delete_whitelabel_ips(self, id).await
     }
 }
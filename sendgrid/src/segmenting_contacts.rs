@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct SegmentingContacts {
     pub client: Client,
@@ -12,6 +10,14 @@ impl SegmentingContacts {
         SegmentingContacts { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        SegmentingContacts {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get List of Segments.
      *
@@ -38,20 +44,19 @@ impl SegmentingContacts {
         parent_list_ids: &str,
         no_parent_list_id: bool,
     ) -> Result<crate::types::GetMarketingSegmentsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if no_parent_list_id {
-            query_args.push((
-                "no_parent_list_id".to_string(),
-                no_parent_list_id.to_string(),
-            ));
+            query_args.push(("no_parent_list_id", no_parent_list_id.to_string()));
         }
         if !parent_list_ids.is_empty() {
-            query_args.push(("parent_list_ids".to_string(), parent_list_ids.to_string()));
+            query_args.push(("parent_list_ids", parent_list_ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/marketing/segments?{}", query_);
+        let url = crate::Client::url_with_query("/marketing/segments", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/marketing/segments", &[]))
     }
 
     /**
@@ -67,8 +72,9 @@ impl SegmentingContacts {
     ) -> Result<crate::types::FullSegmentAllOf> {
         let url = "/marketing/segments".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/marketing/segments", &[]))
     }
 
     /**
@@ -87,18 +93,25 @@ impl SegmentingContacts {
         segment_id: &str,
         query_json: bool,
     ) -> Result<crate::types::FullSegmentAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if query_json {
-            query_args.push(("query_json".to_string(), query_json.to_string()));
+            query_args.push(("query_json", query_json.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/marketing/segments/{}?{}",
-            crate::progenitor_support::encode_path(&segment_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/marketing/segments/{}",
+                self.client.encode_path(segment_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/marketing/segments/{segment_id}",
+                &[("segment_id", segment_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -116,10 +129,16 @@ impl SegmentingContacts {
     ) -> Result<crate::types::Help> {
         let url = format!(
             "/marketing/segments/{}",
-            crate::progenitor_support::encode_path(&segment_id.to_string()),
+            self.client.encode_path(segment_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/marketing/segments/{segment_id}",
+                &[("segment_id", segment_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -138,12 +157,49 @@ impl SegmentingContacts {
     ) -> Result<crate::types::FullSegmentAllOf> {
         let url = format!(
             "/marketing/segments/{}",
-            crate::progenitor_support::encode_path(&segment_id.to_string()),
+            self.client.encode_path(segment_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/marketing/segments/{segment_id}",
+                    &[("segment_id", segment_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update Segment.
+     *
+     * This function performs a `PATCH` to the `/marketing/segments/{segment_id}` endpoint.
+     *
+     * As opposed to `patch_marketing_segments_segment`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_marketing_segments_segment_if_changed<C: serde::Serialize>(
+        &self,
+        segment_id: &str,
+        current: &C,
+        body: &crate::types::SegmentWriteV2,
+    ) -> Result<Option<crate::types::FullSegmentAllOf>> {
+        let url = format!(
+            "/marketing/segments/{}",
+            self.client.encode_path(segment_id),
+        );
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/marketing/segments/{segment_id}",
+                    &[("segment_id", segment_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -161,7 +217,87 @@ impl SegmentingContacts {
     ) -> Result<crate::types::PostMarketingSegmentsDeleteResponse> {
         let url = "/marketing/segments/delete".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/marketing/segments/delete", &[]))
+    }
+}
+
+/// The functions of [`SegmentingContacts`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn SegmentingContactsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait SegmentingContactsApi: Send + Sync {
+    async fn get_marketing_segments(
+        &self,
+        parent_list_ids: &str,
+        no_parent_list_id: bool,
+    ) -> Result<crate::types::GetMarketingSegmentsResponse>;
+    async fn post_marketing_segment(
+        &self,
+        body: &crate::types::PostMarketingSegmentsRequestAllOf,
+    ) -> Result<crate::types::FullSegmentAllOf>;
+    async fn get_marketing_segments_segment(
+        &self,
+        segment_id: &str,
+        query_json: bool,
+    ) -> Result<crate::types::FullSegmentAllOf>;
+    async fn delete_marketing_segments_segment(
+        &self,
+        segment_id: &str,
+    ) -> Result<crate::types::Help>;
+    async fn patch_marketing_segments_segment(
+        &self,
+        segment_id: &str,
+        body: &crate::types::SegmentWriteV2,
+    ) -> Result<crate::types::FullSegmentAllOf>;
+    async fn post_marketing_segments_delete(
+        &self,
+        body: &crate::types::PostMarketingSegmentsDeleteRequest,
+    ) -> Result<crate::types::PostMarketingSegmentsDeleteResponse>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl SegmentingContactsApi for SegmentingContacts {
+    async fn get_marketing_segments(
+        &self,
+        parent_list_ids: &str,
+        no_parent_list_id: bool,
+    ) -> Result<crate::types::GetMarketingSegmentsResponse> {
+        SegmentingContacts::get_marketing_segments(self, parent_list_ids, no_parent_list_id).await
+    }
+    async fn post_marketing_segment(
+        &self,
+        body: &crate::types::PostMarketingSegmentsRequestAllOf,
+    ) -> Result<crate::types::FullSegmentAllOf> {
+        SegmentingContacts::post_marketing_segment(self, body).await
+    }
+    async fn get_marketing_segments_segment(
+        &self,
+        segment_id: &str,
+        query_json: bool,
+    ) -> Result<crate::types::FullSegmentAllOf> {
+        SegmentingContacts::get_marketing_segments_segment(self, segment_id, query_json).await
+    }
+    async fn delete_marketing_segments_segment(
+        &self,
+        segment_id: &str,
+    ) -> Result<crate::types::Help> {
+        SegmentingContacts::delete_marketing_segments_segment(self, segment_id).await
+    }
+    async fn patch_marketing_segments_segment(
+        &self,
+        segment_id: &str,
+        body: &crate::types::SegmentWriteV2,
+    ) -> Result<crate::types::FullSegmentAllOf> {
+        SegmentingContacts::patch_marketing_segments_segment(self, segment_id, body).await
+    }
+    async fn post_marketing_segments_delete(
+        &self,
+        body: &crate::types::PostMarketingSegmentsDeleteRequest,
+    ) -> Result<crate::types::PostMarketingSegmentsDeleteResponse> {
+        SegmentingContacts::post_marketing_segments_delete(self, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct SegmentingContactsBeta {
     pub client: Client,
@@ -12,6 +10,14 @@ impl SegmentingContactsBeta {
         SegmentingContactsBeta { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        SegmentingContactsBeta {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get List of Segments.
      *
@@ -38,20 +44,19 @@ impl SegmentingContactsBeta {
         parent_list_ids: &str,
         no_parent_list_id: bool,
     ) -> Result<crate::types::AllSegmentsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if no_parent_list_id {
-            query_args.push((
-                "no_parent_list_id".to_string(),
-                no_parent_list_id.to_string(),
-            ));
+            query_args.push(("no_parent_list_id", no_parent_list_id.to_string()));
         }
         if !parent_list_ids.is_empty() {
-            query_args.push(("parent_list_ids".to_string(), parent_list_ids.to_string()));
+            query_args.push(("parent_list_ids", parent_list_ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/marketing/segments/2.0?{}", query_);
+        let url = crate::Client::url_with_query("/marketing/segments/2.0", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/marketing/segments/2.0", &[]))
     }
 
     /**
@@ -69,8 +74,9 @@ impl SegmentingContactsBeta {
     ) -> Result<crate::types::SegmentResponse> {
         let url = "/marketing/segments/2.0".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/marketing/segments/2.0", &[]))
     }
 
     /**
@@ -89,18 +95,25 @@ impl SegmentingContactsBeta {
         segment_id: &str,
         contacts_sample: bool,
     ) -> Result<crate::types::SegmentResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if contacts_sample {
-            query_args.push(("contacts_sample".to_string(), contacts_sample.to_string()));
+            query_args.push(("contacts_sample", contacts_sample.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/marketing/segments/2.0/{}?{}",
-            crate::progenitor_support::encode_path(&segment_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/marketing/segments/2.0/{}",
+                self.client.encode_path(segment_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/marketing/segments/2.0/{segment_id}",
+                &[("segment_id", segment_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -113,10 +126,41 @@ impl SegmentingContactsBeta {
     pub async fn delete_segments_segment(&self, segment_id: &str) -> Result<()> {
         let url = format!(
             "/marketing/segments/2.0/{}",
-            crate::progenitor_support::encode_path(&segment_id.to_string()),
+            self.client.encode_path(segment_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/marketing/segments/2.0/{segment_id}",
+                &[("segment_id", segment_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete segment.
+     *
+     * This function performs a `DELETE` to the `/marketing/segments/2.0/{segment_id}` endpoint.
+     *
+     * As opposed to `delete_segments_segment`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_segments_segment_if_exists(&self, segment_id: &str) -> Result<bool> {
+        let url = format!(
+            "/marketing/segments/2.0/{}",
+            self.client.encode_path(segment_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/marketing/segments/2.0/{segment_id}",
+                    &[("segment_id", segment_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -135,11 +179,115 @@ impl SegmentingContactsBeta {
     ) -> Result<crate::types::SegmentResponse> {
         let url = format!(
             "/marketing/segments/2.0/{}",
-            crate::progenitor_support::encode_path(&segment_id.to_string()),
+            self.client.encode_path(segment_id),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/marketing/segments/2.0/{segment_id}",
+                    &[("segment_id", segment_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update Segment.
+     *
+     * This function performs a `PATCH` to the `/marketing/segments/2.0/{segment_id}` endpoint.
+     *
+     * As opposed to `patch_segments_segment`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_segments_segment_if_changed<C: serde::Serialize>(
+        &self,
+        segment_id: &str,
+        current: &C,
+        body: &crate::types::SegmentUpdate,
+    ) -> Result<Option<crate::types::SegmentResponse>> {
+        let url = format!(
+            "/marketing/segments/2.0/{}",
+            self.client.encode_path(segment_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/marketing/segments/2.0/{segment_id}",
+                    &[("segment_id", segment_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`SegmentingContactsBeta`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn SegmentingContactsBetaApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait SegmentingContactsBetaApi: Send + Sync {
+    async fn get_segments(
+        &self,
+        parent_list_ids: &str,
+        no_parent_list_id: bool,
+    ) -> Result<crate::types::AllSegmentsResponse>;
+    async fn post_segment(
+        &self,
+        body: &crate::types::SegmentWriteV2,
+    ) -> Result<crate::types::SegmentResponse>;
+    async fn get_segments_segment(
+        &self,
+        segment_id: &str,
+        contacts_sample: bool,
+    ) -> Result<crate::types::SegmentResponse>;
+    async fn delete_segments_segment(&self, segment_id: &str) -> Result<()>;
+    async fn delete_segments_segment_if_exists(&self, segment_id: &str) -> Result<bool>;
+    async fn patch_segments_segment(
+        &self,
+        segment_id: &str,
+        body: &crate::types::SegmentUpdate,
+    ) -> Result<crate::types::SegmentResponse>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl SegmentingContactsBetaApi for SegmentingContactsBeta {
+    async fn get_segments(
+        &self,
+        parent_list_ids: &str,
+        no_parent_list_id: bool,
+    ) -> Result<crate::types::AllSegmentsResponse> {
+        SegmentingContactsBeta::get_segments(self, parent_list_ids, no_parent_list_id).await
+    }
+    async fn post_segment(
+        &self,
+        body: &crate::types::SegmentWriteV2,
+    ) -> Result<crate::types::SegmentResponse> {
+        SegmentingContactsBeta::post_segment(self, body).await
+    }
+    async fn get_segments_segment(
+        &self,
+        segment_id: &str,
+        contacts_sample: bool,
+    ) -> Result<crate::types::SegmentResponse> {
+        SegmentingContactsBeta::get_segments_segment(self, segment_id, contacts_sample).await
+    }
+    async fn delete_segments_segment(&self, segment_id: &str) -> Result<()> {
+        SegmentingContactsBeta::delete_segments_segment(self, segment_id).await
+    }
+    async fn delete_segments_segment_if_exists(&self, segment_id: &str) -> Result<bool> {
+        SegmentingContactsBeta::delete_segments_segment_if_exists(self, segment_id).await
+    }
+    async fn patch_segments_segment(
+        &self,
+        segment_id: &str,
+        body: &crate::types::SegmentUpdate,
+    ) -> Result<crate::types::SegmentResponse> {
+        SegmentingContactsBeta::patch_segments_segment(self, segment_id, body).await
     }
 }
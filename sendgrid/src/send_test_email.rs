@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct SendTestEmail {
     pub client: Client,
@@ -12,6 +10,14 @@ impl SendTestEmail {
         SendTestEmail { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        SendTestEmail {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Send a Test Marketing Email.
      *
@@ -32,8 +38,34 @@ impl SendTestEmail {
         body: &crate::types::PostMarketingTestSendEmailRequest,
     ) -> Result<crate::types::Help> {
         let url = "/marketing/test/send_email".to_string();
+        crate::utils::check_items("emails", body.emails.len(), Some(1), Some(10))?;
+
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/marketing/test/send_email", &[]))
+    }
+}
+
+/// The functions of [`SendTestEmail`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn SendTestEmailApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait SendTestEmailApi: Send + Sync {
+    async fn post_marketing_test_send_email(
+        &self,
+        body: &crate::types::PostMarketingTestSendEmailRequest,
+    ) -> Result<crate::types::Help>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl SendTestEmailApi for SendTestEmail {
+    async fn post_marketing_test_send_email(
+        &self,
+        body: &crate::types::PostMarketingTestSendEmailRequest,
+    ) -> Result<crate::types::Help> {
+        SendTestEmail::post_marketing_test_send_email(self, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct SenderIdentitiesApi {
     pub client: Client,
@@ -12,6 +10,14 @@ impl SenderIdentitiesApi {
         SenderIdentitiesApi { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        SenderIdentitiesApi {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get all Sender Identities.
      *
@@ -25,7 +31,10 @@ impl SenderIdentitiesApi {
      */
     pub async fn get_senders(&self) -> Result<crate::types::GetSendersResponse> {
         let url = "/senders".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/senders", &[]))
     }
 
     /**
@@ -47,8 +56,9 @@ impl SenderIdentitiesApi {
     ) -> Result<crate::types::SenderAllOf> {
         let url = "/senders".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/senders", &[]))
     }
 
     /**
@@ -65,10 +75,16 @@ impl SenderIdentitiesApi {
     pub async fn get_senders_sender(&self, sender_id: i64) -> Result<crate::types::SenderAllOf> {
         let url = format!(
             "/senders/{}",
-            crate::progenitor_support::encode_path(&sender_id.to_string()),
+            self.client.encode_path(&sender_id.to_string()),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/senders/{sender_id}",
+                &[("sender_id", sender_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -85,10 +101,16 @@ impl SenderIdentitiesApi {
     pub async fn delete_senders_sender(&self, sender_id: i64) -> Result<crate::types::Help> {
         let url = format!(
             "/senders/{}",
-            crate::progenitor_support::encode_path(&sender_id.to_string()),
+            self.client.encode_path(&sender_id.to_string()),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/senders/{sender_id}",
+                &[("sender_id", sender_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -113,12 +135,49 @@ impl SenderIdentitiesApi {
     ) -> Result<crate::types::SenderAllOf> {
         let url = format!(
             "/senders/{}",
-            crate::progenitor_support::encode_path(&sender_id.to_string()),
+            self.client.encode_path(&sender_id.to_string()),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/senders/{sender_id}",
+                    &[("sender_id", sender_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update a Sender Identity.
+     *
+     * This function performs a `PATCH` to the `/senders/{sender_id}` endpoint.
+     *
+     * As opposed to `patch_senders_sender`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_senders_sender_if_changed<C: serde::Serialize>(
+        &self,
+        sender_id: i64,
+        current: &C,
+        body: &crate::types::SenderRequest,
+    ) -> Result<Option<crate::types::SenderAllOf>> {
+        let url = format!(
+            "/senders/{}",
+            self.client.encode_path(&sender_id.to_string()),
+        );
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/senders/{sender_id}",
+                    &[("sender_id", sender_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -138,9 +197,72 @@ impl SenderIdentitiesApi {
     ) -> Result<crate::types::Help> {
         let url = format!(
             "/senders/{}/resend_verification",
-            crate::progenitor_support::encode_path(&sender_id.to_string()),
+            self.client.encode_path(&sender_id.to_string()),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/senders/{sender_id}/resend_verification",
+                &[("sender_id", sender_id.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`SenderIdentitiesApi`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn SenderIdentitiesApiApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait SenderIdentitiesApiApi: Send + Sync {
+    async fn get_senders(&self) -> Result<crate::types::GetSendersResponse>;
+    async fn post_sender(
+        &self,
+        body: &crate::types::PostSendersRequestAllOf,
+    ) -> Result<crate::types::SenderAllOf>;
+    async fn get_senders_sender(&self, sender_id: i64) -> Result<crate::types::SenderAllOf>;
+    async fn delete_senders_sender(&self, sender_id: i64) -> Result<crate::types::Help>;
+    async fn patch_senders_sender(
+        &self,
+        sender_id: i64,
+        body: &crate::types::SenderRequest,
+    ) -> Result<crate::types::SenderAllOf>;
+    async fn post_senders_sender_resend_verification(
+        &self,
+        sender_id: i64,
+    ) -> Result<crate::types::Help>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl SenderIdentitiesApiApi for SenderIdentitiesApi {
+    async fn get_senders(&self) -> Result<crate::types::GetSendersResponse> {
+        SenderIdentitiesApi::get_senders(self).await
+    }
+    async fn post_sender(
+        &self,
+        body: &crate::types::PostSendersRequestAllOf,
+    ) -> Result<crate::types::SenderAllOf> {
+        SenderIdentitiesApi::post_sender(self, body).await
+    }
+    async fn get_senders_sender(&self, sender_id: i64) -> Result<crate::types::SenderAllOf> {
+        SenderIdentitiesApi::get_senders_sender(self, sender_id).await
+    }
+    async fn delete_senders_sender(&self, sender_id: i64) -> Result<crate::types::Help> {
+        SenderIdentitiesApi::delete_senders_sender(self, sender_id).await
+    }
+    async fn patch_senders_sender(
+        &self,
+        sender_id: i64,
+        body: &crate::types::SenderRequest,
+    ) -> Result<crate::types::SenderAllOf> {
+        SenderIdentitiesApi::patch_senders_sender(self, sender_id, body).await
+    }
+    async fn post_senders_sender_resend_verification(
+        &self,
+        sender_id: i64,
+    ) -> Result<crate::types::Help> {
+        SenderIdentitiesApi::post_senders_sender_resend_verification(self, sender_id).await
     }
 }
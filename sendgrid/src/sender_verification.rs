@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct SenderVerification {
     pub client: Client,
@@ -12,6 +10,14 @@ impl SenderVerification {
         SenderVerification { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        SenderVerification {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Domain Warn List.
      *
@@ -27,7 +33,10 @@ impl SenderVerification {
         &self,
     ) -> Result<crate::types::GetVerifiedSendersDomainsResponse> {
         let url = "/verified_senders/domains".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/verified_senders/domains", &[]))
     }
 
     /**
@@ -45,7 +54,9 @@ impl SenderVerification {
         &self,
     ) -> Result<crate::types::GetVerifiedSendersStepsCompletedResponse> {
         let url = "/verified_senders/steps_completed".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/verified_senders/steps_completed", &[])
+        })
     }
 
     /**
@@ -75,20 +86,22 @@ impl SenderVerification {
         last_seen_id: f64,
         id: i64,
     ) -> Result<crate::types::GetVerifiedSendersResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if id > 0 {
-            query_args.push(("id".to_string(), id.to_string()));
+            query_args.push(("id", id.to_string()));
         }
         if !last_seen_id.to_string().is_empty() {
-            query_args.push(("lastSeenID".to_string(), last_seen_id.to_string()));
+            query_args.push(("lastSeenID", last_seen_id.to_string()));
         }
         if !limit.to_string().is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/verified_senders?{}", query_);
+        let url = crate::Client::url_with_query("/verified_senders", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/verified_senders", &[]))
     }
 
     /**
@@ -110,8 +123,9 @@ impl SenderVerification {
     ) -> Result<crate::types::VerifiedSenderResponseSchema> {
         let url = "/verified_senders".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/verified_senders", &[]))
     }
 
     /**
@@ -126,10 +140,16 @@ impl SenderVerification {
     pub async fn get_verified_senders_verify_token(&self, token: &str) -> Result<()> {
         let url = format!(
             "/verified_senders/verify/{}",
-            crate::progenitor_support::encode_path(&token.to_string()),
+            self.client.encode_path(token),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/verified_senders/verify/{token}",
+                &[("token", token.to_string())],
+            )
+        })
     }
 
     /**
@@ -144,12 +164,15 @@ impl SenderVerification {
      * You can retrieve the IDs associated with Sender Identities using the "Get All Verified Senders" endpoint.
      */
     pub async fn delete_verified_senders(&self, id: &str) -> Result<crate::types::Help> {
-        let url = format!(
-            "/verified_senders/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/verified_senders/{}", self.client.encode_path(id),);
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/verified_senders/{id}",
+                &[("id", id.to_string())],
+            )
+        })
     }
 
     /**
@@ -170,14 +193,45 @@ impl SenderVerification {
         id: &str,
         body: &crate::types::VerifiedSenderRequestSchema,
     ) -> Result<crate::types::VerifiedSenderResponseSchema> {
-        let url = format!(
-            "/verified_senders/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/verified_senders/{}", self.client.encode_path(id),);
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/verified_senders/{id}",
+                    &[("id", id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Edit Verified Sender.
+     *
+     * This function performs a `PATCH` to the `/verified_senders/{id}` endpoint.
+     *
+     * As opposed to `patch_verified_senders`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_verified_senders_if_changed<C: serde::Serialize>(
+        &self,
+        id: &str,
+        current: &C,
+        body: &crate::types::VerifiedSenderRequestSchema,
+    ) -> Result<Option<crate::types::VerifiedSenderResponseSchema>> {
+        let url = format!("/verified_senders/{}", self.client.encode_path(id),);
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/verified_senders/{id}",
+                    &[("id", id.to_string())],
+                )
+            })
     }
 
     /**
@@ -192,11 +246,91 @@ impl SenderVerification {
      * You can retrieve the IDs associated with Sender Identities by passing a "Get All Verified Senders" endpoint.
      */
     pub async fn post_verified_senders_resend(&self, id: &str) -> Result<crate::types::Help> {
-        let url = format!(
-            "/verified_senders/resend/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/verified_senders/resend/{}", self.client.encode_path(id),);
+
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/verified_senders/resend/{id}",
+                &[("id", id.to_string())],
+            )
+        })
+    }
+}
 
-        self.client.post(&url, None).await
+/// The functions of [`SenderVerification`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn SenderVerificationApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait SenderVerificationApi: Send + Sync {
+    async fn get_verified_senders_domains(
+        &self,
+    ) -> Result<crate::types::GetVerifiedSendersDomainsResponse>;
+    async fn get_verified_senders_steps_completed(
+        &self,
+    ) -> Result<crate::types::GetVerifiedSendersStepsCompletedResponse>;
+    async fn get_verified_senders(
+        &self,
+        limit: f64,
+        last_seen_id: f64,
+        id: i64,
+    ) -> Result<crate::types::GetVerifiedSendersResponse>;
+    async fn post_verified_sender(
+        &self,
+        body: &crate::types::VerifiedSenderRequestSchema,
+    ) -> Result<crate::types::VerifiedSenderResponseSchema>;
+    async fn get_verified_senders_verify_token(&self, token: &str) -> Result<()>;
+    async fn delete_verified_senders(&self, id: &str) -> Result<crate::types::Help>;
+    async fn patch_verified_senders(
+        &self,
+        id: &str,
+        body: &crate::types::VerifiedSenderRequestSchema,
+    ) -> Result<crate::types::VerifiedSenderResponseSchema>;
+    async fn post_verified_senders_resend(&self, id: &str) -> Result<crate::types::Help>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl SenderVerificationApi for SenderVerification {
+    async fn get_verified_senders_domains(
+        &self,
+    ) -> Result<crate::types::GetVerifiedSendersDomainsResponse> {
+        SenderVerification::get_verified_senders_domains(self).await
+    }
+    async fn get_verified_senders_steps_completed(
+        &self,
+    ) -> Result<crate::types::GetVerifiedSendersStepsCompletedResponse> {
+        SenderVerification::get_verified_senders_steps_completed(self).await
+    }
+    async fn get_verified_senders(
+        &self,
+        limit: f64,
+        last_seen_id: f64,
+        id: i64,
+    ) -> Result<crate::types::GetVerifiedSendersResponse> {
+        SenderVerification::get_verified_senders(self, limit, last_seen_id, id).await
+    }
+    async fn post_verified_sender(
+        &self,
+        body: &crate::types::VerifiedSenderRequestSchema,
+    ) -> Result<crate::types::VerifiedSenderResponseSchema> {
+        SenderVerification::post_verified_sender(self, body).await
+    }
+    async fn get_verified_senders_verify_token(&self, token: &str) -> Result<()> {
+        SenderVerification::get_verified_senders_verify_token(self, token).await
+    }
+    async fn delete_verified_senders(&self, id: &str) -> Result<crate::types::Help> {
+        SenderVerification::delete_verified_senders(self, id).await
+    }
+    async fn patch_verified_senders(
+        &self,
+        id: &str,
+        body: &crate::types::VerifiedSenderRequestSchema,
+    ) -> Result<crate::types::VerifiedSenderResponseSchema> {
+        SenderVerification::patch_verified_senders(self, id, body).await
+    }
+    async fn post_verified_senders_resend(&self, id: &str) -> Result<crate::types::Help> {
+        SenderVerification::post_verified_senders_resend(self, id).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Senders {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Senders {
         Senders { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Senders {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Create a Sender Identity.
      *
@@ -33,7 +39,31 @@ impl Senders {
     ) -> Result<crate::types::SenderAllOf> {
         let url = "/marketing/senders".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/marketing/senders", &[]))
+    }
+}
+
+/// The functions of [`Senders`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn SendersApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait SendersApi: Send + Sync {
+    async fn post_marketing(
+        &self,
+        body: &crate::types::PostMarketingSendersRequest,
+    ) -> Result<crate::types::SenderAllOf>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl SendersApi for Senders {
+    async fn post_marketing(
+        &self,
+        body: &crate::types::PostMarketingSendersRequest,
+    ) -> Result<crate::types::SenderAllOf> {
+        Senders::post_marketing(self, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct SettingsEnforcedTls {
     pub client: Client,
@@ -12,6 +10,14 @@ impl SettingsEnforcedTls {
         SettingsEnforcedTls { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        SettingsEnforcedTls {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieve current Enforced TLS settings.
      *
@@ -29,7 +35,10 @@ impl SettingsEnforcedTls {
      */
     pub async fn get_user(&self) -> Result<crate::types::EnforcedTlsRequestResponse> {
         let url = "/user/settings/enforced_tls".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/user/settings/enforced_tls", &[]))
     }
 
     /**
@@ -53,7 +62,54 @@ impl SettingsEnforcedTls {
     ) -> Result<crate::types::EnforcedTlsRequestResponse> {
         let url = "/user/settings/enforced_tls".to_string();
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("PATCH", "/user/settings/enforced_tls", &[]))
+    }
+
+    /**
+     * Update Enforced TLS settings.
+     *
+     * This function performs a `PATCH` to the `/user/settings/enforced_tls` endpoint.
+     *
+     * As opposed to `patch_user`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_user_if_changed<C: serde::Serialize>(
+        &self,
+        current: &C,
+        body: &crate::types::EnforcedTlsRequestResponse,
+    ) -> Result<Option<crate::types::EnforcedTlsRequestResponse>> {
+        let url = "/user/settings/enforced_tls".to_string();
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| crate::utils::pretty_url("PATCH", "/user/settings/enforced_tls", &[]))
+    }
+}
+
+/// The functions of [`SettingsEnforcedTls`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn SettingsEnforcedTlsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait SettingsEnforcedTlsApi: Send + Sync {
+    async fn get_user(&self) -> Result<crate::types::EnforcedTlsRequestResponse>;
+    async fn patch_user(
+        &self,
+        body: &crate::types::EnforcedTlsRequestResponse,
+    ) -> Result<crate::types::EnforcedTlsRequestResponse>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl SettingsEnforcedTlsApi for SettingsEnforcedTls {
+    async fn get_user(&self) -> Result<crate::types::EnforcedTlsRequestResponse> {
+        SettingsEnforcedTls::get_user(self).await
+    }
+    async fn patch_user(
+        &self,
+        body: &crate::types::EnforcedTlsRequestResponse,
+    ) -> Result<crate::types::EnforcedTlsRequestResponse> {
+        SettingsEnforcedTls::patch_user(self, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct SettingsInboundParse {
     pub client: Client,
@@ -12,6 +10,14 @@ impl SettingsInboundParse {
         SettingsInboundParse { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        SettingsInboundParse {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Create a parse setting.
      *
@@ -37,8 +43,9 @@ impl SettingsInboundParse {
     ) -> Result<crate::types::ParseSetting> {
         let url = "/user/webhooks/parse/settings".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/user/webhooks/parse/settings", &[]))
     }
 
     /**
@@ -60,10 +67,16 @@ impl SettingsInboundParse {
     ) -> Result<crate::types::ParseSetting> {
         let url = format!(
             "/user/webhooks/parse/settings/{}",
-            crate::progenitor_support::encode_path(&hostname.to_string()),
+            self.client.encode_path(hostname),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/user/webhooks/parse/settings/{hostname}",
+                &[("hostname", hostname.to_string())],
+            )
+        })
     }
 
     /**
@@ -85,10 +98,16 @@ impl SettingsInboundParse {
     ) -> Result<crate::types::Help> {
         let url = format!(
             "/user/webhooks/parse/settings/{}",
-            crate::progenitor_support::encode_path(&hostname.to_string()),
+            self.client.encode_path(hostname),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/user/webhooks/parse/settings/{hostname}",
+                &[("hostname", hostname.to_string())],
+            )
+        })
     }
 
     /**
@@ -111,11 +130,104 @@ impl SettingsInboundParse {
     ) -> Result<crate::types::ParseSetting> {
         let url = format!(
             "/user/webhooks/parse/settings/{}",
-            crate::progenitor_support::encode_path(&hostname.to_string()),
+            self.client.encode_path(hostname),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/user/webhooks/parse/settings/{hostname}",
+                    &[("hostname", hostname.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update a parse setting.
+     *
+     * This function performs a `PATCH` to the `/user/webhooks/parse/settings/{hostname}` endpoint.
+     *
+     * As opposed to `patch_user_webhooks_parse_settings_hostname`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_user_webhooks_parse_settings_hostname_if_changed<C: serde::Serialize>(
+        &self,
+        hostname: &str,
+        current: &C,
+        body: &crate::types::ParseSetting,
+    ) -> Result<Option<crate::types::ParseSetting>> {
+        let url = format!(
+            "/user/webhooks/parse/settings/{}",
+            self.client.encode_path(hostname),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/user/webhooks/parse/settings/{hostname}",
+                    &[("hostname", hostname.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`SettingsInboundParse`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn SettingsInboundParseApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait SettingsInboundParseApi: Send + Sync {
+    async fn post_user_webhooks_parse_setting(
+        &self,
+        body: &crate::types::ParseSetting,
+    ) -> Result<crate::types::ParseSetting>;
+    async fn get_user_webhooks_parse_settings_hostname(
+        &self,
+        hostname: &str,
+    ) -> Result<crate::types::ParseSetting>;
+    async fn delete_user_webhooks_parse_settings_hostname(
+        &self,
+        hostname: &str,
+    ) -> Result<crate::types::Help>;
+    async fn patch_user_webhooks_parse_settings_hostname(
+        &self,
+        hostname: &str,
+        body: &crate::types::ParseSetting,
+    ) -> Result<crate::types::ParseSetting>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl SettingsInboundParseApi for SettingsInboundParse {
+    async fn post_user_webhooks_parse_setting(
+        &self,
+        body: &crate::types::ParseSetting,
+    ) -> Result<crate::types::ParseSetting> {
+        SettingsInboundParse::post_user_webhooks_parse_setting(self, body).await
+    }
+    async fn get_user_webhooks_parse_settings_hostname(
+        &self,
+        hostname: &str,
+    ) -> Result<crate::types::ParseSetting> {
+        SettingsInboundParse::get_user_webhooks_parse_settings_hostname(self, hostname).await
+    }
+    async fn delete_user_webhooks_parse_settings_hostname(
+        &self,
+        hostname: &str,
+    ) -> Result<crate::types::Help> {
+        SettingsInboundParse::delete_user_webhooks_parse_settings_hostname(self, hostname).await
+    }
+    async fn patch_user_webhooks_parse_settings_hostname(
+        &self,
+        hostname: &str,
+        body: &crate::types::ParseSetting,
+    ) -> Result<crate::types::ParseSetting> {
+        SettingsInboundParse::patch_user_webhooks_parse_settings_hostname(self, hostname, body)
             .await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct SettingsMail {
     pub client: Client,
@@ -12,6 +10,14 @@ impl SettingsMail {
         SettingsMail { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        SettingsMail {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieve all mail settings.
      *
@@ -32,17 +38,19 @@ impl SettingsMail {
         limit: i64,
         offset: i64,
     ) -> Result<crate::types::GetMailSettingsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/mail_settings?{}", query_);
+        let url = crate::Client::url_with_query("/mail_settings", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/mail_settings", &[]))
     }
 
     /**
@@ -64,7 +72,9 @@ impl SettingsMail {
         &self,
     ) -> Result<crate::types::MailSettingsAddressWhitelabel> {
         let url = "/mail_settings/address_whitelist".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/mail_settings/address_whitelist", &[])
+        })
     }
 
     /**
@@ -94,8 +104,32 @@ impl SettingsMail {
     ) -> Result<crate::types::MailSettingsAddressWhitelabel> {
         let url = "/mail_settings/address_whitelist".to_string();
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url("PATCH", "/mail_settings/address_whitelist", &[])
+            })
+    }
+
+    /**
+     * Update address whitelist mail settings.
+     *
+     * This function performs a `PATCH` to the `/mail_settings/address_whitelist` endpoint.
+     *
+     * As opposed to `patch_mail_settings_address_whitelist`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_mail_settings_address_whitelist_if_changed<C: serde::Serialize>(
+        &self,
+        current: &C,
+        body: &crate::types::PatchMailSettingsAddressWhitelistRequest,
+    ) -> Result<Option<crate::types::MailSettingsAddressWhitelabel>> {
+        let url = "/mail_settings/address_whitelist".to_string();
+        self.client
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("PATCH", "/mail_settings/address_whitelist", &[])
+            })
     }
 
     /**
@@ -115,7 +149,10 @@ impl SettingsMail {
      */
     pub async fn get_mail_settings_footer(&self) -> Result<crate::types::MailSettingsFooter> {
         let url = "/mail_settings/footer".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/mail_settings/footer", &[]))
     }
 
     /**
@@ -139,8 +176,28 @@ impl SettingsMail {
     ) -> Result<crate::types::MailSettingsFooter> {
         let url = "/mail_settings/footer".to_string();
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| crate::utils::pretty_url("PATCH", "/mail_settings/footer", &[]))
+    }
+
+    /**
+     * Update footer mail settings.
+     *
+     * This function performs a `PATCH` to the `/mail_settings/footer` endpoint.
+     *
+     * As opposed to `patch_mail_settings_footer`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_mail_settings_footer_if_changed<C: serde::Serialize>(
+        &self,
+        current: &C,
+        body: &crate::types::MailSettingsFooter,
+    ) -> Result<Option<crate::types::MailSettingsFooter>> {
+        let url = "/mail_settings/footer".to_string();
+        self.client
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| crate::utils::pretty_url("PATCH", "/mail_settings/footer", &[]))
     }
 
     /**
@@ -160,7 +217,10 @@ impl SettingsMail {
         &self,
     ) -> Result<crate::types::MailSettingsForwardSpam> {
         let url = "/mail_settings/forward_spam".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/mail_settings/forward_spam", &[]))
     }
 
     /**
@@ -195,8 +255,28 @@ impl SettingsMail {
     ) -> Result<crate::types::MailSettingsForwardSpam> {
         let url = "/mail_settings/forward_spam".to_string();
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("PATCH", "/mail_settings/forward_spam", &[]))
+    }
+
+    /**
+     * Update forward spam mail settings.
+     *
+     * This function performs a `PATCH` to the `/mail_settings/forward_spam` endpoint.
+     *
+     * As opposed to `patch_mail_settings_forward_spam`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_mail_settings_forward_spam_if_changed<C: serde::Serialize>(
+        &self,
+        current: &C,
+        body: &crate::types::MailSettingsForwardSpam,
+    ) -> Result<Option<crate::types::MailSettingsForwardSpam>> {
+        let url = "/mail_settings/forward_spam".to_string();
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| crate::utils::pretty_url("PATCH", "/mail_settings/forward_spam", &[]))
     }
 
     /**
@@ -216,7 +296,10 @@ impl SettingsMail {
      */
     pub async fn get_mail_settings_template(&self) -> Result<crate::types::MailSettingsTemplate> {
         let url = "/mail_settings/template".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/mail_settings/template", &[]))
     }
 
     /**
@@ -240,8 +323,28 @@ impl SettingsMail {
     ) -> Result<crate::types::PatchMailSettingsTemplateResponse> {
         let url = "/mail_settings/template".to_string();
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("PATCH", "/mail_settings/template", &[]))
+    }
+
+    /**
+     * Update template mail settings.
+     *
+     * This function performs a `PATCH` to the `/mail_settings/template` endpoint.
+     *
+     * As opposed to `patch_mail_settings_template`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_mail_settings_template_if_changed<C: serde::Serialize>(
+        &self,
+        current: &C,
+        body: &crate::types::PatchMailSettingsTemplateRequest,
+    ) -> Result<Option<crate::types::PatchMailSettingsTemplateResponse>> {
+        let url = "/mail_settings/template".to_string();
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| crate::utils::pretty_url("PATCH", "/mail_settings/template", &[]))
     }
 
     /**
@@ -267,7 +370,10 @@ impl SettingsMail {
         &self,
     ) -> Result<crate::types::MailSettingsBouncePurge> {
         let url = "/mail_settings/bounce_purge".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/mail_settings/bounce_purge", &[]))
     }
 
     /**
@@ -295,8 +401,28 @@ impl SettingsMail {
     ) -> Result<crate::types::MailSettingsBouncePurge> {
         let url = "/mail_settings/bounce_purge".to_string();
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| crate::utils::pretty_url("PATCH", "/mail_settings/bounce_purge", &[]))
+    }
+
+    /**
+     * Update bounce purge mail settings.
+     *
+     * This function performs a `PATCH` to the `/mail_settings/bounce_purge` endpoint.
+     *
+     * As opposed to `patch_mail_settings_bounce_purge`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_mail_settings_bounce_purge_if_changed<C: serde::Serialize>(
+        &self,
+        current: &C,
+        body: &crate::types::MailSettingsBouncePurge,
+    ) -> Result<Option<crate::types::MailSettingsBouncePurge>> {
+        let url = "/mail_settings/bounce_purge".to_string();
+        self.client
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| crate::utils::pretty_url("PATCH", "/mail_settings/bounce_purge", &[]))
     }
 
     /**
@@ -316,7 +442,10 @@ impl SettingsMail {
         &self,
     ) -> Result<crate::types::MailSettingsForwardBounce> {
         let url = "/mail_settings/forward_bounce".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/mail_settings/forward_bounce", &[]))
     }
 
     /**
@@ -340,7 +469,154 @@ impl SettingsMail {
     ) -> Result<crate::types::MailSettingsForwardBounce> {
         let url = "/mail_settings/forward_bounce".to_string();
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url("PATCH", "/mail_settings/forward_bounce", &[])
+            })
+    }
+
+    /**
+     * Update forward bounce mail settings.
+     *
+     * This function performs a `PATCH` to the `/mail_settings/forward_bounce` endpoint.
+     *
+     * As opposed to `patch_mail_settings_forward_bounce`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_mail_settings_forward_bounce_if_changed<C: serde::Serialize>(
+        &self,
+        current: &C,
+        body: &crate::types::MailSettingsForwardBounce,
+    ) -> Result<Option<crate::types::MailSettingsForwardBounce>> {
+        let url = "/mail_settings/forward_bounce".to_string();
+        self.client
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("PATCH", "/mail_settings/forward_bounce", &[])
+            })
+    }
+}
+
+/// The functions of [`SettingsMail`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn SettingsMailApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait SettingsMailApi: Send + Sync {
+    async fn get_mail_settings(
+        &self,
+        limit: i64,
+        offset: i64,
+    ) -> Result<crate::types::GetMailSettingsResponse>;
+    async fn get_mail_settings_address_whitelist(
+        &self,
+    ) -> Result<crate::types::MailSettingsAddressWhitelabel>;
+    async fn patch_mail_settings_address_whitelist(
+        &self,
+        body: &crate::types::PatchMailSettingsAddressWhitelistRequest,
+    ) -> Result<crate::types::MailSettingsAddressWhitelabel>;
+    async fn get_mail_settings_footer(&self) -> Result<crate::types::MailSettingsFooter>;
+    async fn patch_mail_settings_footer(
+        &self,
+        body: &crate::types::MailSettingsFooter,
+    ) -> Result<crate::types::MailSettingsFooter>;
+    async fn get_mail_settings_forward_spam(&self)
+        -> Result<crate::types::MailSettingsForwardSpam>;
+    async fn patch_mail_settings_forward_spam(
+        &self,
+        body: &crate::types::MailSettingsForwardSpam,
+    ) -> Result<crate::types::MailSettingsForwardSpam>;
+    async fn get_mail_settings_template(&self) -> Result<crate::types::MailSettingsTemplate>;
+    async fn patch_mail_settings_template(
+        &self,
+        body: &crate::types::PatchMailSettingsTemplateRequest,
+    ) -> Result<crate::types::PatchMailSettingsTemplateResponse>;
+    async fn get_mail_settings_bounce_purge(&self)
+        -> Result<crate::types::MailSettingsBouncePurge>;
+    async fn patch_mail_settings_bounce_purge(
+        &self,
+        body: &crate::types::MailSettingsBouncePurge,
+    ) -> Result<crate::types::MailSettingsBouncePurge>;
+    async fn get_mail_settings_forward_bounce(
+        &self,
+    ) -> Result<crate::types::MailSettingsForwardBounce>;
+    async fn patch_mail_settings_forward_bounce(
+        &self,
+        body: &crate::types::MailSettingsForwardBounce,
+    ) -> Result<crate::types::MailSettingsForwardBounce>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl SettingsMailApi for SettingsMail {
+    async fn get_mail_settings(
+        &self,
+        limit: i64,
+        offset: i64,
+    ) -> Result<crate::types::GetMailSettingsResponse> {
+        SettingsMail::get_mail_settings(self, limit, offset).await
+    }
+    async fn get_mail_settings_address_whitelist(
+        &self,
+    ) -> Result<crate::types::MailSettingsAddressWhitelabel> {
+        SettingsMail::get_mail_settings_address_whitelist(self).await
+    }
+    async fn patch_mail_settings_address_whitelist(
+        &self,
+        body: &crate::types::PatchMailSettingsAddressWhitelistRequest,
+    ) -> Result<crate::types::MailSettingsAddressWhitelabel> {
+        SettingsMail::patch_mail_settings_address_whitelist(self, body).await
+    }
+    async fn get_mail_settings_footer(&self) -> Result<crate::types::MailSettingsFooter> {
+        SettingsMail::get_mail_settings_footer(self).await
+    }
+    async fn patch_mail_settings_footer(
+        &self,
+        body: &crate::types::MailSettingsFooter,
+    ) -> Result<crate::types::MailSettingsFooter> {
+        SettingsMail::patch_mail_settings_footer(self, body).await
+    }
+    async fn get_mail_settings_forward_spam(
+        &self,
+    ) -> Result<crate::types::MailSettingsForwardSpam> {
+        SettingsMail::get_mail_settings_forward_spam(self).await
+    }
+    async fn patch_mail_settings_forward_spam(
+        &self,
+        body: &crate::types::MailSettingsForwardSpam,
+    ) -> Result<crate::types::MailSettingsForwardSpam> {
+        SettingsMail::patch_mail_settings_forward_spam(self, body).await
+    }
+    async fn get_mail_settings_template(&self) -> Result<crate::types::MailSettingsTemplate> {
+        SettingsMail::get_mail_settings_template(self).await
+    }
+    async fn patch_mail_settings_template(
+        &self,
+        body: &crate::types::PatchMailSettingsTemplateRequest,
+    ) -> Result<crate::types::PatchMailSettingsTemplateResponse> {
+        SettingsMail::patch_mail_settings_template(self, body).await
+    }
+    async fn get_mail_settings_bounce_purge(
+        &self,
+    ) -> Result<crate::types::MailSettingsBouncePurge> {
+        SettingsMail::get_mail_settings_bounce_purge(self).await
+    }
+    async fn patch_mail_settings_bounce_purge(
+        &self,
+        body: &crate::types::MailSettingsBouncePurge,
+    ) -> Result<crate::types::MailSettingsBouncePurge> {
+        SettingsMail::patch_mail_settings_bounce_purge(self, body).await
+    }
+    async fn get_mail_settings_forward_bounce(
+        &self,
+    ) -> Result<crate::types::MailSettingsForwardBounce> {
+        SettingsMail::get_mail_settings_forward_bounce(self).await
+    }
+    async fn patch_mail_settings_forward_bounce(
+        &self,
+        body: &crate::types::MailSettingsForwardBounce,
+    ) -> Result<crate::types::MailSettingsForwardBounce> {
+        SettingsMail::patch_mail_settings_forward_bounce(self, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct SettingsPartner {
     pub client: Client,
@@ -12,6 +10,14 @@ impl SettingsPartner {
         SettingsPartner { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        SettingsPartner {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Returns all New Relic partner settings.
      *
@@ -31,7 +37,10 @@ impl SettingsPartner {
         &self,
     ) -> Result<crate::types::PartnerSettingsNewRelic> {
         let url = "/partner_settings/new_relic".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/partner_settings/new_relic", &[]))
     }
 
     /**
@@ -55,8 +64,28 @@ impl SettingsPartner {
     ) -> Result<crate::types::PartnerSettingsNewRelic> {
         let url = "/partner_settings/new_relic".to_string();
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("PATCH", "/partner_settings/new_relic", &[]))
+    }
+
+    /**
+     * Updates New Relic partner settings.
+     *
+     * This function performs a `PATCH` to the `/partner_settings/new_relic` endpoint.
+     *
+     * As opposed to `patch_partner_settings_new_relic`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_partner_settings_new_relic_if_changed<C: serde::Serialize>(
+        &self,
+        current: &C,
+        body: &crate::types::PatchPartnerSettingsNewRelicRequest,
+    ) -> Result<Option<crate::types::PartnerSettingsNewRelic>> {
+        let url = "/partner_settings/new_relic".to_string();
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| crate::utils::pretty_url("PATCH", "/partner_settings/new_relic", &[]))
     }
 
     /**
@@ -79,16 +108,60 @@ impl SettingsPartner {
         limit: i64,
         offset: i64,
     ) -> Result<crate::types::GetPartnerSettingsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/partner_settings?{}", query_);
+        let url = crate::Client::url_with_query("/partner_settings", &query_args);
+
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/partner_settings", &[]))
+    }
+}
 
-        self.client.get(&url, None).await
+/// The functions of [`SettingsPartner`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn SettingsPartnerApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait SettingsPartnerApi: Send + Sync {
+    async fn get_partner_settings_new_relic(&self)
+        -> Result<crate::types::PartnerSettingsNewRelic>;
+    async fn patch_partner_settings_new_relic(
+        &self,
+        body: &crate::types::PatchPartnerSettingsNewRelicRequest,
+    ) -> Result<crate::types::PartnerSettingsNewRelic>;
+    async fn get_partner_settings(
+        &self,
+        limit: i64,
+        offset: i64,
+    ) -> Result<crate::types::GetPartnerSettingsResponse>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl SettingsPartnerApi for SettingsPartner {
+    async fn get_partner_settings_new_relic(
+        &self,
+    ) -> Result<crate::types::PartnerSettingsNewRelic> {
+        SettingsPartner::get_partner_settings_new_relic(self).await
+    }
+    async fn patch_partner_settings_new_relic(
+        &self,
+        body: &crate::types::PatchPartnerSettingsNewRelicRequest,
+    ) -> Result<crate::types::PartnerSettingsNewRelic> {
+        SettingsPartner::patch_partner_settings_new_relic(self, body).await
+    }
+    async fn get_partner_settings(
+        &self,
+        limit: i64,
+        offset: i64,
+    ) -> Result<crate::types::GetPartnerSettingsResponse> {
+        SettingsPartner::get_partner_settings(self, limit, offset).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct SettingsTracking {
     pub client: Client,
@@ -12,6 +10,14 @@ impl SettingsTracking {
         SettingsTracking { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        SettingsTracking {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieve Tracking Settings.
      *
@@ -25,7 +31,10 @@ impl SettingsTracking {
      */
     pub async fn get_tracking_settings(&self) -> Result<crate::types::GetTrackingSettingsResponse> {
         let url = "/tracking_settings".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/tracking_settings", &[]))
     }
 
     /**
@@ -45,7 +54,10 @@ impl SettingsTracking {
      */
     pub async fn get_tracking_settings_click(&self) -> Result<crate::types::ClickTracking> {
         let url = "/tracking_settings/click".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/tracking_settings/click", &[]))
     }
 
     /**
@@ -69,8 +81,28 @@ impl SettingsTracking {
     ) -> Result<crate::types::ClickTracking> {
         let url = "/tracking_settings/click".to_string();
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| crate::utils::pretty_url("PATCH", "/tracking_settings/click", &[]))
+    }
+
+    /**
+     * Update Click Tracking Settings.
+     *
+     * This function performs a `PATCH` to the `/tracking_settings/click` endpoint.
+     *
+     * As opposed to `patch_tracking_settings_click`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_tracking_settings_click_if_changed<C: serde::Serialize>(
+        &self,
+        current: &C,
+        body: &crate::types::PatchTrackingSettingsOpenRequest,
+    ) -> Result<Option<crate::types::ClickTracking>> {
+        let url = "/tracking_settings/click".to_string();
+        self.client
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| crate::utils::pretty_url("PATCH", "/tracking_settings/click", &[]))
     }
 
     /**
@@ -93,7 +125,9 @@ impl SettingsTracking {
         &self,
     ) -> Result<crate::types::GoogleAnalyticsSettings> {
         let url = "/tracking_settings/google_analytics".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/tracking_settings/google_analytics", &[])
+        })
     }
 
     /**
@@ -117,8 +151,32 @@ impl SettingsTracking {
     ) -> Result<crate::types::GoogleAnalyticsSettings> {
         let url = "/tracking_settings/google_analytics".to_string();
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url("PATCH", "/tracking_settings/google_analytics", &[])
+            })
+    }
+
+    /**
+     * Update Google Analytics Settings.
+     *
+     * This function performs a `PATCH` to the `/tracking_settings/google_analytics` endpoint.
+     *
+     * As opposed to `patch_tracking_settings_google_analytics`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_tracking_settings_google_analytics_if_changed<C: serde::Serialize>(
+        &self,
+        current: &C,
+        body: &crate::types::GoogleAnalyticsSettings,
+    ) -> Result<Option<crate::types::GoogleAnalyticsSettings>> {
+        let url = "/tracking_settings/google_analytics".to_string();
+        self.client
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("PATCH", "/tracking_settings/google_analytics", &[])
+            })
     }
 
     /**
@@ -142,7 +200,10 @@ impl SettingsTracking {
         &self,
     ) -> Result<crate::types::GetTrackingSettingsOpenResponse> {
         let url = "/tracking_settings/open".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/tracking_settings/open", &[]))
     }
 
     /**
@@ -168,8 +229,28 @@ impl SettingsTracking {
     ) -> Result<crate::types::GetTrackingSettingsOpenResponse> {
         let url = "/tracking_settings/open".to_string();
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| crate::utils::pretty_url("PATCH", "/tracking_settings/open", &[]))
+    }
+
+    /**
+     * Update Open Tracking Settings.
+     *
+     * This function performs a `PATCH` to the `/tracking_settings/open` endpoint.
+     *
+     * As opposed to `patch_tracking_settings_open`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_tracking_settings_open_if_changed<C: serde::Serialize>(
+        &self,
+        current: &C,
+        body: &crate::types::PatchTrackingSettingsOpenRequest,
+    ) -> Result<Option<crate::types::GetTrackingSettingsOpenResponse>> {
+        let url = "/tracking_settings/open".to_string();
+        self.client
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| crate::utils::pretty_url("PATCH", "/tracking_settings/open", &[]))
     }
 
     /**
@@ -189,7 +270,9 @@ impl SettingsTracking {
         &self,
     ) -> Result<crate::types::SubscriptionTrackingSettings> {
         let url = "/tracking_settings/subscription".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/tracking_settings/subscription", &[])
+        })
     }
 
     /**
@@ -211,7 +294,116 @@ impl SettingsTracking {
     ) -> Result<crate::types::SubscriptionTrackingSettings> {
         let url = "/tracking_settings/subscription".to_string();
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("PATCH", "/tracking_settings/subscription", &[])
+            })
+    }
+
+    /**
+     * Update Subscription Tracking Settings.
+     *
+     * This function performs a `PATCH` to the `/tracking_settings/subscription` endpoint.
+     *
+     * As opposed to `patch_tracking_settings_subscription`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_tracking_settings_subscription_if_changed<C: serde::Serialize>(
+        &self,
+        current: &C,
+        body: &crate::types::SubscriptionTrackingSettings,
+    ) -> Result<Option<crate::types::SubscriptionTrackingSettings>> {
+        let url = "/tracking_settings/subscription".to_string();
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url("PATCH", "/tracking_settings/subscription", &[])
+            })
+    }
+}
+
+/// The functions of [`SettingsTracking`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn SettingsTrackingApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait SettingsTrackingApi: Send + Sync {
+    async fn get_tracking_settings(&self) -> Result<crate::types::GetTrackingSettingsResponse>;
+    async fn get_tracking_settings_click(&self) -> Result<crate::types::ClickTracking>;
+    async fn patch_tracking_settings_click(
+        &self,
+        body: &crate::types::PatchTrackingSettingsOpenRequest,
+    ) -> Result<crate::types::ClickTracking>;
+    async fn get_tracking_settings_google_analytic(
+        &self,
+    ) -> Result<crate::types::GoogleAnalyticsSettings>;
+    async fn patch_tracking_settings_google_analytics(
+        &self,
+        body: &crate::types::GoogleAnalyticsSettings,
+    ) -> Result<crate::types::GoogleAnalyticsSettings>;
+    async fn get_tracking_settings_open(
+        &self,
+    ) -> Result<crate::types::GetTrackingSettingsOpenResponse>;
+    async fn patch_tracking_settings_open(
+        &self,
+        body: &crate::types::PatchTrackingSettingsOpenRequest,
+    ) -> Result<crate::types::GetTrackingSettingsOpenResponse>;
+    async fn get_tracking_settings_subscription(
+        &self,
+    ) -> Result<crate::types::SubscriptionTrackingSettings>;
+    async fn patch_tracking_settings_subscription(
+        &self,
+        body: &crate::types::SubscriptionTrackingSettings,
+    ) -> Result<crate::types::SubscriptionTrackingSettings>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl SettingsTrackingApi for SettingsTracking {
+    async fn get_tracking_settings(&self) -> Result<crate::types::GetTrackingSettingsResponse> {
+        SettingsTracking::get_tracking_settings(self).await
+    }
+    async fn get_tracking_settings_click(&self) -> Result<crate::types::ClickTracking> {
+        SettingsTracking::get_tracking_settings_click(self).await
+    }
+    async fn patch_tracking_settings_click(
+        &self,
+        body: &crate::types::PatchTrackingSettingsOpenRequest,
+    ) -> Result<crate::types::ClickTracking> {
+        SettingsTracking::patch_tracking_settings_click(self, body).await
+    }
+    async fn get_tracking_settings_google_analytic(
+        &self,
+    ) -> Result<crate::types::GoogleAnalyticsSettings> {
+        SettingsTracking::get_tracking_settings_google_analytic(self).await
+    }
+    async fn patch_tracking_settings_google_analytics(
+        &self,
+        body: &crate::types::GoogleAnalyticsSettings,
+    ) -> Result<crate::types::GoogleAnalyticsSettings> {
+        SettingsTracking::patch_tracking_settings_google_analytics(self, body).await
+    }
+    async fn get_tracking_settings_open(
+        &self,
+    ) -> Result<crate::types::GetTrackingSettingsOpenResponse> {
+        SettingsTracking::get_tracking_settings_open(self).await
+    }
+    async fn patch_tracking_settings_open(
+        &self,
+        body: &crate::types::PatchTrackingSettingsOpenRequest,
+    ) -> Result<crate::types::GetTrackingSettingsOpenResponse> {
+        SettingsTracking::patch_tracking_settings_open(self, body).await
+    }
+    async fn get_tracking_settings_subscription(
+        &self,
+    ) -> Result<crate::types::SubscriptionTrackingSettings> {
+        SettingsTracking::get_tracking_settings_subscription(self).await
+    }
+    async fn patch_tracking_settings_subscription(
+        &self,
+        body: &crate::types::SubscriptionTrackingSettings,
+    ) -> Result<crate::types::SubscriptionTrackingSettings> {
+        SettingsTracking::patch_tracking_settings_subscription(self, body).await
     }
 }
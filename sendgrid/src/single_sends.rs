@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct SingleSends {
     pub client: Client,
@@ -12,6 +10,14 @@ impl SingleSends {
         SingleSends { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        SingleSends {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get All Single Sends.
      *
@@ -31,17 +37,20 @@ impl SingleSends {
         page_size: i64,
         page_token: &str,
     ) -> Result<crate::types::GetMarketingSinglesendsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("page_token".to_string(), page_token.to_string()));
+            query_args.push(("page_token", page_token.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/marketing/singlesends?{}", query_);
+        let url = crate::Client::url_with_query("/marketing/singlesends", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/marketing/singlesends", &[]))
     }
 
     /**
@@ -58,9 +67,12 @@ impl SingleSends {
         body: &crate::types::SinglesendRequest,
     ) -> Result<crate::types::SinglesendResponseAllOf> {
         let url = "/marketing/singlesends".to_string();
+        crate::utils::check_items("categories", body.categories.len(), None, Some(10))?;
+
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/marketing/singlesends", &[]))
     }
 
     /**
@@ -79,14 +91,36 @@ impl SingleSends {
      * * `ids: &[String]` -- The recipient IDs of the recipients that already existed from this request.
      */
     pub async fn delete_marketing_singlesends(&self, ids: &[String]) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.join(" ")));
+            query_args.push(("ids", ids.join(",")));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/marketing/singlesends?{}", query_);
+        let url = crate::Client::url_with_query("/marketing/singlesends", &query_args);
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("DELETE", "/marketing/singlesends", &[]))
+    }
+
+    /**
+     * Bulk Delete Single Sends.
+     *
+     * This function performs a `DELETE` to the `/marketing/singlesends` endpoint.
+     *
+     * As opposed to `delete_marketing_singlesends`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_marketing_singlesends_if_exists(&self, ids: &[String]) -> Result<bool> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !ids.is_empty() {
+            query_args.push(("ids", ids.join(",")));
+        }
+        let url = crate::Client::url_with_query("/marketing/singlesends", &query_args);
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("DELETE", "/marketing/singlesends", &[]))
     }
 
     /**
@@ -102,12 +136,15 @@ impl SingleSends {
         &self,
         id: &str,
     ) -> Result<crate::types::SinglesendResponseAllOf> {
-        let url = format!(
-            "/marketing/singlesends/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/marketing/singlesends/{}", self.client.encode_path(id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/marketing/singlesends/{id}",
+                &[("id", id.to_string())],
+            )
+        })
     }
 
     /**
@@ -126,14 +163,18 @@ impl SingleSends {
         id: &str,
         body: &crate::types::PostMarketingSinglesendsRequest,
     ) -> Result<crate::types::SinglesendResponseAllOf> {
-        let url = format!(
-            "/marketing/singlesends/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/marketing/singlesends/{}", self.client.encode_path(id),);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/marketing/singlesends/{id}",
+                    &[("id", id.to_string())],
+                )
+            })
     }
 
     /**
@@ -148,12 +189,40 @@ impl SingleSends {
      * Please note that a `DELETE` request is permanent, and your Single Send will not be recoverable after deletion.
      */
     pub async fn delete_marketing_singlesends_single_sends(&self, id: &str) -> Result<()> {
-        let url = format!(
-            "/marketing/singlesends/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/marketing/singlesends/{}", self.client.encode_path(id),);
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/marketing/singlesends/{id}",
+                &[("id", id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete Single Send by ID.
+     *
+     * This function performs a `DELETE` to the `/marketing/singlesends/{id}` endpoint.
+     *
+     * As opposed to `delete_marketing_singlesends_single_sends`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_marketing_singlesends_single_sends_if_exists(
+        &self,
+        id: &str,
+    ) -> Result<bool> {
+        let url = format!("/marketing/singlesends/{}", self.client.encode_path(id),);
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/marketing/singlesends/{id}",
+                    &[("id", id.to_string())],
+                )
+            })
     }
 
     /**
@@ -170,14 +239,47 @@ impl SingleSends {
         id: &str,
         body: &crate::types::SinglesendRequest,
     ) -> Result<crate::types::SinglesendResponseAllOf> {
-        let url = format!(
-            "/marketing/singlesends/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/marketing/singlesends/{}", self.client.encode_path(id),);
+        crate::utils::check_items("categories", body.categories.len(), None, Some(10))?;
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/marketing/singlesends/{id}",
+                    &[("id", id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update Single Send.
+     *
+     * This function performs a `PATCH` to the `/marketing/singlesends/{id}` endpoint.
+     *
+     * As opposed to `patch_marketing_singlesends`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_marketing_singlesends_if_changed<C: serde::Serialize>(
+        &self,
+        id: &str,
+        current: &C,
+        body: &crate::types::SinglesendRequest,
+    ) -> Result<Option<crate::types::SinglesendResponseAllOf>> {
+        let url = format!("/marketing/singlesends/{}", self.client.encode_path(id),);
+        crate::utils::check_items("categories", body.categories.len(), None, Some(10))?;
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/marketing/singlesends/{id}",
+                    &[("id", id.to_string())],
+                )
+            })
     }
 
     /**
@@ -214,19 +316,20 @@ impl SingleSends {
         page_token: &str,
         body: &crate::types::SinglesendSearch,
     ) -> Result<crate::types::GetMarketingSinglesendsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("page_token".to_string(), page_token.to_string()));
+            query_args.push(("page_token", page_token.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/marketing/singlesends/search?{}", query_);
+        let url = crate::Client::url_with_query("/marketing/singlesends/search", &query_args);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/marketing/singlesends/search", &[]))
     }
 
     /**
@@ -245,12 +348,19 @@ impl SingleSends {
     ) -> Result<crate::types::PutMarketingSinglesendsScheduleResponse> {
         let url = format!(
             "/marketing/singlesends/{}/schedule",
-            crate::progenitor_support::encode_path(&id.to_string()),
+            self.client.encode_path(id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/marketing/singlesends/{id}/schedule",
+                    &[("id", id.to_string())],
+                )
+            })
     }
 
     /**
@@ -268,10 +378,16 @@ impl SingleSends {
     ) -> Result<crate::types::SinglesendSchedule> {
         let url = format!(
             "/marketing/singlesends/{}/schedule",
-            crate::progenitor_support::encode_path(&id.to_string()),
+            self.client.encode_path(id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/marketing/singlesends/{id}/schedule",
+                &[("id", id.to_string())],
+            )
+        })
     }
 
     /**
@@ -287,6 +403,137 @@ impl SingleSends {
         &self,
     ) -> Result<crate::types::GetMarketingSinglesendsCategoriesResponse> {
         let url = "/marketing/singlesends/categories".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/marketing/singlesends/categories", &[])
+        })
+    }
+}
+
+/// The functions of [`SingleSends`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn SingleSendsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait SingleSendsApi: Send + Sync {
+    async fn get_marketing_singlesends(
+        &self,
+        page_size: i64,
+        page_token: &str,
+    ) -> Result<crate::types::GetMarketingSinglesendsResponse>;
+    async fn post_marketing_singlesend(
+        &self,
+        body: &crate::types::SinglesendRequest,
+    ) -> Result<crate::types::SinglesendResponseAllOf>;
+    async fn delete_marketing_singlesends(&self, ids: &[String]) -> Result<()>;
+    async fn delete_marketing_singlesends_if_exists(&self, ids: &[String]) -> Result<bool>;
+    async fn get_marketing_singlesend(
+        &self,
+        id: &str,
+    ) -> Result<crate::types::SinglesendResponseAllOf>;
+    async fn post_marketing_singlesend_single_sends(
+        &self,
+        id: &str,
+        body: &crate::types::PostMarketingSinglesendsRequest,
+    ) -> Result<crate::types::SinglesendResponseAllOf>;
+    async fn delete_marketing_singlesends_single_sends(&self, id: &str) -> Result<()>;
+    async fn delete_marketing_singlesends_single_sends_if_exists(&self, id: &str) -> Result<bool>;
+    async fn patch_marketing_singlesends(
+        &self,
+        id: &str,
+        body: &crate::types::SinglesendRequest,
+    ) -> Result<crate::types::SinglesendResponseAllOf>;
+    async fn post_marketing_singlesends_search(
+        &self,
+        page_size: i64,
+        page_token: &str,
+        body: &crate::types::SinglesendSearch,
+    ) -> Result<crate::types::GetMarketingSinglesendsResponse>;
+    async fn put_marketing_singlesends_schedule(
+        &self,
+        id: &str,
+        body: &crate::types::PutMarketingSinglesendsScheduleRequest,
+    ) -> Result<crate::types::PutMarketingSinglesendsScheduleResponse>;
+    async fn delete_marketing_singlesends_schedule(
+        &self,
+        id: &str,
+    ) -> Result<crate::types::SinglesendSchedule>;
+    async fn get_marketing_singlesends_categories(
+        &self,
+    ) -> Result<crate::types::GetMarketingSinglesendsCategoriesResponse>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl SingleSendsApi for SingleSends {
+    async fn get_marketing_singlesends(
+        &self,
+        page_size: i64,
+        page_token: &str,
+    ) -> Result<crate::types::GetMarketingSinglesendsResponse> {
+        SingleSends::get_marketing_singlesends(self, page_size, page_token).await
+    }
+    async fn post_marketing_singlesend(
+        &self,
+        body: &crate::types::SinglesendRequest,
+    ) -> Result<crate::types::SinglesendResponseAllOf> {
+        SingleSends::post_marketing_singlesend(self, body).await
+    }
+    async fn delete_marketing_singlesends(&self, ids: &[String]) -> Result<()> {
+        SingleSends::delete_marketing_singlesends(self, ids).await
+    }
+    async fn delete_marketing_singlesends_if_exists(&self, ids: &[String]) -> Result<bool> {
+        SingleSends::delete_marketing_singlesends_if_exists(self, ids).await
+    }
+    async fn get_marketing_singlesend(
+        &self,
+        id: &str,
+    ) -> Result<crate::types::SinglesendResponseAllOf> {
+        SingleSends::get_marketing_singlesend(self, id).await
+    }
+    async fn post_marketing_singlesend_single_sends(
+        &self,
+        id: &str,
+        body: &crate::types::PostMarketingSinglesendsRequest,
+    ) -> Result<crate::types::SinglesendResponseAllOf> {
+        SingleSends::post_marketing_singlesend_single_sends(self, id, body).await
+    }
+    async fn delete_marketing_singlesends_single_sends(&self, id: &str) -> Result<()> {
+        SingleSends::delete_marketing_singlesends_single_sends(self, id).await
+    }
+    async fn delete_marketing_singlesends_single_sends_if_exists(&self, id: &str) -> Result<bool> {
+        SingleSends::delete_marketing_singlesends_single_sends_if_exists(self, id).await
+    }
+    async fn patch_marketing_singlesends(
+        &self,
+        id: &str,
+        body: &crate::types::SinglesendRequest,
+    ) -> Result<crate::types::SinglesendResponseAllOf> {
+        SingleSends::patch_marketing_singlesends(self, id, body).await
+    }
+    async fn post_marketing_singlesends_search(
+        &self,
+        page_size: i64,
+        page_token: &str,
+        body: &crate::types::SinglesendSearch,
+    ) -> Result<crate::types::GetMarketingSinglesendsResponse> {
+        SingleSends::post_marketing_singlesends_search(self, page_size, page_token, body).await
+    }
+    async fn put_marketing_singlesends_schedule(
+        &self,
+        id: &str,
+        body: &crate::types::PutMarketingSinglesendsScheduleRequest,
+    ) -> Result<crate::types::PutMarketingSinglesendsScheduleResponse> {
+        SingleSends::put_marketing_singlesends_schedule(self, id, body).await
+    }
+    async fn delete_marketing_singlesends_schedule(
+        &self,
+        id: &str,
+    ) -> Result<crate::types::SinglesendSchedule> {
+        SingleSends::delete_marketing_singlesends_schedule(self, id).await
+    }
+    async fn get_marketing_singlesends_categories(
+        &self,
+    ) -> Result<crate::types::GetMarketingSinglesendsCategoriesResponse> {
+        SingleSends::get_marketing_singlesends_categories(self).await
     }
 }
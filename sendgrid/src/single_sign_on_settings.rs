@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct SingleSignOnSettings {
     pub client: Client,
@@ -12,6 +10,14 @@ impl SingleSignOnSettings {
         SingleSignOnSettings { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        SingleSignOnSettings {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get All SSO Integrations.
      *
@@ -29,14 +35,16 @@ impl SingleSignOnSettings {
         &self,
         si: bool,
     ) -> Result<Vec<crate::types::SsoIntegrationAllOf>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if si {
-            query_args.push(("si".to_string(), si.to_string()));
+            query_args.push(("si", si.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/sso/integrations?{}", query_);
+        let url = crate::Client::url_with_query("/sso/integrations", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/sso/integrations", &[]))
     }
 
     /**
@@ -54,14 +62,16 @@ impl SingleSignOnSettings {
         &self,
         si: bool,
     ) -> Result<Vec<crate::types::SsoIntegrationAllOf>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if si {
-            query_args.push(("si".to_string(), si.to_string()));
+            query_args.push(("si", si.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/sso/integrations?{}", query_);
+        let url = crate::Client::url_with_query("/sso/integrations", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/sso/integrations", &[]))
     }
 
     /**
@@ -77,8 +87,9 @@ impl SingleSignOnSettings {
     ) -> Result<crate::types::SsoIntegrationAllOf> {
         let url = "/sso/integrations".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/sso/integrations", &[]))
     }
 
     /**
@@ -99,18 +110,18 @@ impl SingleSignOnSettings {
         id: &str,
         si: bool,
     ) -> Result<crate::types::SsoIntegrationAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if si {
-            query_args.push(("si".to_string(), si.to_string()));
+            query_args.push(("si", si.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/sso/integrations/{}?{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/sso/integrations/{}", self.client.encode_path(id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/sso/integrations/{id}", &[("id", id.to_string())])
+        })
     }
 
     /**
@@ -123,12 +134,37 @@ impl SingleSignOnSettings {
      * You can retrieve the IDs for your configurations from the response provided by the "Get All SSO Integrations" endpoint.
      */
     pub async fn delete_sso_integrations(&self, id: &str) -> Result<()> {
-        let url = format!(
-            "/sso/integrations/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/sso/integrations/{}", self.client.encode_path(id),);
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/sso/integrations/{id}",
+                &[("id", id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete an SSO Integration.
+     *
+     * This function performs a `DELETE` to the `/sso/integrations/{id}` endpoint.
+     *
+     * As opposed to `delete_sso_integrations`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_sso_integrations_if_exists(&self, id: &str) -> Result<bool> {
+        let url = format!("/sso/integrations/{}", self.client.encode_path(id),);
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/sso/integrations/{id}",
+                    &[("id", id.to_string())],
+                )
+            })
     }
 
     /**
@@ -150,19 +186,136 @@ impl SingleSignOnSettings {
         si: bool,
         body: &crate::types::CreateIntegrationRequest,
     ) -> Result<crate::types::SsoIntegrationAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if si {
-            query_args.push(("si".to_string(), si.to_string()));
+            query_args.push(("si", si.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/sso/integrations/{}?{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/sso/integrations/{}", self.client.encode_path(id)),
+            &query_args,
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/sso/integrations/{id}",
+                    &[("id", id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update an SSO Integration.
+     *
+     * This function performs a `PATCH` to the `/sso/integrations/{id}` endpoint.
+     *
+     * As opposed to `patch_sso_integrations`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_sso_integrations_if_changed<C: serde::Serialize>(
+        &self,
+        id: &str,
+        si: bool,
+        current: &C,
+        body: &crate::types::CreateIntegrationRequest,
+    ) -> Result<Option<crate::types::SsoIntegrationAllOf>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if si {
+            query_args.push(("si", si.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!("/sso/integrations/{}", self.client.encode_path(id)),
+            &query_args,
+        );
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/sso/integrations/{id}",
+                    &[("id", id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`SingleSignOnSettings`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn SingleSignOnSettingsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait SingleSignOnSettingsApi: Send + Sync {
+    async fn get_sso_integrations(
+        &self,
+        si: bool,
+    ) -> Result<Vec<crate::types::SsoIntegrationAllOf>>;
+    async fn get_all_sso_integrations(
+        &self,
+        si: bool,
+    ) -> Result<Vec<crate::types::SsoIntegrationAllOf>>;
+    async fn post_sso_integration(
+        &self,
+        body: &crate::types::CreateIntegrationRequest,
+    ) -> Result<crate::types::SsoIntegrationAllOf>;
+    async fn get_sso_integration(
+        &self,
+        id: &str,
+        si: bool,
+    ) -> Result<crate::types::SsoIntegrationAllOf>;
+    async fn delete_sso_integrations(&self, id: &str) -> Result<()>;
+    async fn delete_sso_integrations_if_exists(&self, id: &str) -> Result<bool>;
+    async fn patch_sso_integrations(
+        &self,
+        id: &str,
+        si: bool,
+        body: &crate::types::CreateIntegrationRequest,
+    ) -> Result<crate::types::SsoIntegrationAllOf>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl SingleSignOnSettingsApi for SingleSignOnSettings {
+    async fn get_sso_integrations(
+        &self,
+        si: bool,
+    ) -> Result<Vec<crate::types::SsoIntegrationAllOf>> {
+        SingleSignOnSettings::get_sso_integrations(self, si).await
+    }
+    async fn get_all_sso_integrations(
+        &self,
+        si: bool,
+    ) -> Result<Vec<crate::types::SsoIntegrationAllOf>> {
+        SingleSignOnSettings::get_all_sso_integrations(self, si).await
+    }
+    async fn post_sso_integration(
+        &self,
+        body: &crate::types::CreateIntegrationRequest,
+    ) -> Result<crate::types::SsoIntegrationAllOf> {
+        SingleSignOnSettings::post_sso_integration(self, body).await
+    }
+    async fn get_sso_integration(
+        &self,
+        id: &str,
+        si: bool,
+    ) -> Result<crate::types::SsoIntegrationAllOf> {
+        SingleSignOnSettings::get_sso_integration(self, id, si).await
+    }
+    async fn delete_sso_integrations(&self, id: &str) -> Result<()> {
+        SingleSignOnSettings::delete_sso_integrations(self, id).await
+    }
+    async fn delete_sso_integrations_if_exists(&self, id: &str) -> Result<bool> {
+        SingleSignOnSettings::delete_sso_integrations_if_exists(self, id).await
+    }
+    async fn patch_sso_integrations(
+        &self,
+        id: &str,
+        si: bool,
+        body: &crate::types::CreateIntegrationRequest,
+    ) -> Result<crate::types::SsoIntegrationAllOf> {
+        SingleSignOnSettings::patch_sso_integrations(self, id, si, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct SingleSignOnTeammates {
     pub client: Client,
@@ -12,6 +10,14 @@ impl SingleSignOnTeammates {
         SingleSignOnTeammates { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        SingleSignOnTeammates {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Create SSO Teammate.
      *
@@ -27,8 +33,9 @@ impl SingleSignOnTeammates {
     ) -> Result<crate::types::SsoTeammateResponseAllOf> {
         let url = "/sso/teammates".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/sso/teammates", &[]))
     }
 
     /**
@@ -47,13 +54,79 @@ impl SingleSignOnTeammates {
         username: &str,
         body: &crate::types::PatchSsoTeammatesUsernameRequest,
     ) -> Result<crate::types::SsoTeammatesPatchResponseAllOf> {
-        let url = format!(
-            "/sso/teammates/{}",
-            crate::progenitor_support::encode_path(&username.to_string()),
-        );
+        let url = format!("/sso/teammates/{}", self.client.encode_path(username),);
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/sso/teammates/{username}",
+                    &[("username", username.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Edit an SSO Teammate.
+     *
+     * This function performs a `PATCH` to the `/sso/teammates/{username}` endpoint.
+     *
+     * As opposed to `patch_sso_teammates_username`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_sso_teammates_username_if_changed<C: serde::Serialize>(
+        &self,
+        username: &str,
+        current: &C,
+        body: &crate::types::PatchSsoTeammatesUsernameRequest,
+    ) -> Result<Option<crate::types::SsoTeammatesPatchResponseAllOf>> {
+        let url = format!("/sso/teammates/{}", self.client.encode_path(username),);
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/sso/teammates/{username}",
+                    &[("username", username.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`SingleSignOnTeammates`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn SingleSignOnTeammatesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait SingleSignOnTeammatesApi: Send + Sync {
+    async fn post_sso_teammate(
+        &self,
+        body: &crate::types::SsoTeammateRequestAllOf,
+    ) -> Result<crate::types::SsoTeammateResponseAllOf>;
+    async fn patch_sso_teammates_username(
+        &self,
+        username: &str,
+        body: &crate::types::PatchSsoTeammatesUsernameRequest,
+    ) -> Result<crate::types::SsoTeammatesPatchResponseAllOf>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl SingleSignOnTeammatesApi for SingleSignOnTeammates {
+    async fn post_sso_teammate(
+        &self,
+        body: &crate::types::SsoTeammateRequestAllOf,
+    ) -> Result<crate::types::SsoTeammateResponseAllOf> {
+        SingleSignOnTeammates::post_sso_teammate(self, body).await
+    }
+    async fn patch_sso_teammates_username(
+        &self,
+        username: &str,
+        body: &crate::types::PatchSsoTeammatesUsernameRequest,
+    ) -> Result<crate::types::SsoTeammatesPatchResponseAllOf> {
+        SingleSignOnTeammates::patch_sso_teammates_username(self, username, body).await
     }
 }
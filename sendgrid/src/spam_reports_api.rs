@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct SpamReportsApi {
     pub client: Client,
@@ -12,6 +10,14 @@ impl SpamReportsApi {
         SpamReportsApi { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        SpamReportsApi {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieve all spam reports.
      *
@@ -34,23 +40,25 @@ impl SpamReportsApi {
         limit: i64,
         offset: i64,
     ) -> Result<Vec<crate::types::SpamReportsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if end_time > 0 {
-            query_args.push(("end_time".to_string(), end_time.to_string()));
+            query_args.push(("end_time", end_time.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if start_time > 0 {
-            query_args.push(("start_time".to_string(), start_time.to_string()));
+            query_args.push(("start_time", start_time.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/suppression/spam_reports?{}", query_);
+        let url = crate::Client::url_with_query("/suppression/spam_reports", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/suppression/spam_reports", &[]))
     }
 
     /**
@@ -68,20 +76,22 @@ impl SpamReportsApi {
         end_time: i64,
         offset: i64,
     ) -> Result<Vec<crate::types::SpamReportsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if end_time > 0 {
-            query_args.push(("end_time".to_string(), end_time.to_string()));
+            query_args.push(("end_time", end_time.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if start_time > 0 {
-            query_args.push(("start_time".to_string(), start_time.to_string()));
+            query_args.push(("start_time", start_time.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/suppression/spam_reports?{}", query_);
+        let url = crate::Client::url_with_query("/suppression/spam_reports", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/suppression/spam_reports", &[]))
     }
 
     /**
@@ -108,8 +118,9 @@ impl SpamReportsApi {
     ) -> Result<crate::types::Help> {
         let url = "/suppression/spam_reports".to_string();
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .delete(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("DELETE", "/suppression/spam_reports", &[]))
     }
 
     /**
@@ -129,10 +140,16 @@ impl SpamReportsApi {
     ) -> Result<Vec<crate::types::SpamReportsResponse>> {
         let url = format!(
             "/suppression/spam_reports/{}",
-            crate::progenitor_support::encode_path(&email.to_string()),
+            self.client.encode_path(email),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/suppression/spam_reports/{email}",
+                &[("email", email.to_string())],
+            )
+        })
     }
 
     /**
@@ -150,10 +167,19 @@ impl SpamReportsApi {
     ) -> Result<Vec<crate::types::SpamReportsResponse>> {
         let url = format!(
             "/suppression/spam_reports/{}",
-            crate::progenitor_support::encode_path(&email.to_string()),
+            self.client.encode_path(email),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/suppression/spam_reports/{email}",
+                    &[("email", email.to_string())],
+                )
+            })
     }
 
     /**
@@ -175,9 +201,99 @@ impl SpamReportsApi {
     ) -> Result<crate::types::Help> {
         let url = format!(
             "/suppression/spam_reports/{}",
-            crate::progenitor_support::encode_path(&email.to_string()),
+            self.client.encode_path(email),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/suppression/spam_reports/{email}",
+                &[("email", email.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`SpamReportsApi`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn SpamReportsApiApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait SpamReportsApiApi: Send + Sync {
+    async fn get_suppression_spam_reports(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<crate::types::SpamReportsResponse>>;
+    async fn get_all_suppression_spam_reports(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        offset: i64,
+    ) -> Result<Vec<crate::types::SpamReportsResponse>>;
+    async fn delete_suppression_spam_reports(
+        &self,
+        body: &crate::types::DeleteSuppressionBlocksRequest,
+    ) -> Result<crate::types::Help>;
+    async fn get_suppression_spam_reports_email(
+        &self,
+        email: &str,
+    ) -> Result<Vec<crate::types::SpamReportsResponse>>;
+    async fn get_all_suppression_spam_reports_email(
+        &self,
+        email: &str,
+    ) -> Result<Vec<crate::types::SpamReportsResponse>>;
+    async fn delete_suppression_spam_reports_email(
+        &self,
+        email: &str,
+    ) -> Result<crate::types::Help>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl SpamReportsApiApi for SpamReportsApi {
+    async fn get_suppression_spam_reports(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<crate::types::SpamReportsResponse>> {
+        SpamReportsApi::get_suppression_spam_reports(self, start_time, end_time, limit, offset)
+            .await
+    }
+    async fn get_all_suppression_spam_reports(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        offset: i64,
+    ) -> Result<Vec<crate::types::SpamReportsResponse>> {
+        SpamReportsApi::get_all_suppression_spam_reports(self, start_time, end_time, offset).await
+    }
+    async fn delete_suppression_spam_reports(
+        &self,
+        body: &crate::types::DeleteSuppressionBlocksRequest,
+    ) -> Result<crate::types::Help> {
+        SpamReportsApi::delete_suppression_spam_reports(self, body).await
+    }
+    async fn get_suppression_spam_reports_email(
+        &self,
+        email: &str,
+    ) -> Result<Vec<crate::types::SpamReportsResponse>> {
+        SpamReportsApi::get_suppression_spam_reports_email(self, email).await
+    }
+    async fn get_all_suppression_spam_reports_email(
+        &self,
+        email: &str,
+    ) -> Result<Vec<crate::types::SpamReportsResponse>> {
+        SpamReportsApi::get_all_suppression_spam_reports_email(self, email).await
+    }
+    async fn delete_suppression_spam_reports_email(
+        &self,
+        email: &str,
+    ) -> Result<crate::types::Help> {
+        SpamReportsApi::delete_suppression_spam_reports_email(self, email).await
     }
 }
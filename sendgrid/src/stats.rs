@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Stats {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Stats {
         Stats { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Stats {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieve global email statistics.
      *
@@ -37,23 +43,25 @@ impl Stats {
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::GetStatsResponseData>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/stats?{}", query_);
+        let url = crate::Client::url_with_query("/stats", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/stats", &[]))
     }
 
     /**
@@ -74,23 +82,25 @@ impl Stats {
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::GetStatsResponseData>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/stats?{}", query_);
+        let url = crate::Client::url_with_query("/stats", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/stats", &[]))
     }
 
     /**
@@ -122,26 +132,28 @@ impl Stats {
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::GetGeoStatsResponseData>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !country.to_string().is_empty() {
-            query_args.push(("country".to_string(), country.to_string()));
+            query_args.push(("country", country.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/geo/stats?{}", query_);
+        let url = crate::Client::url_with_query("/geo/stats", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/geo/stats", &[]))
     }
 
     /**
@@ -165,26 +177,28 @@ impl Stats {
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::GetGeoStatsResponseData>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !country.to_string().is_empty() {
-            query_args.push(("country".to_string(), country.to_string()));
+            query_args.push(("country", country.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/geo/stats?{}", query_);
+        let url = crate::Client::url_with_query("/geo/stats", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/geo/stats", &[]))
     }
 
     /**
@@ -223,23 +237,25 @@ impl Stats {
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::GetClientsStatsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/devices/stats?{}", query_);
+        let url = crate::Client::url_with_query("/devices/stats", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/devices/stats", &[]))
     }
 
     /**
@@ -271,23 +287,25 @@ impl Stats {
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::GetClientsStatsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/devices/stats?{}", query_);
+        let url = crate::Client::url_with_query("/devices/stats", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/devices/stats", &[]))
     }
 
     /**
@@ -314,20 +332,22 @@ impl Stats {
         end_date: &str,
         aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
     ) -> Result<Vec<crate::types::GetClientsStatsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/clients/stats?{}", query_);
+        let url = crate::Client::url_with_query("/clients/stats", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/clients/stats", &[]))
     }
 
     /**
@@ -349,20 +369,22 @@ impl Stats {
         end_date: &str,
         aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
     ) -> Result<Vec<crate::types::GetClientsStatsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/clients/stats?{}", query_);
+        let url = crate::Client::url_with_query("/clients/stats", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/clients/stats", &[]))
     }
 
     /**
@@ -396,24 +418,31 @@ impl Stats {
         end_date: &str,
         aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
     ) -> Result<Vec<crate::types::GetClientsStatsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/clients/{}/stats?{}",
-            crate::progenitor_support::encode_path(&client_type.to_string()),
-            query_
+            query_args.push(("start_date", start_date.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/clients/{}/stats",
+                self.client.encode_path(&client_type.to_string())
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/clients/{client_type}/stats",
+                &[("client_type", client_type.to_string())],
+            )
+        })
     }
 
     /**
@@ -442,24 +471,34 @@ impl Stats {
         end_date: &str,
         aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
     ) -> Result<Vec<crate::types::GetClientsStatsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/clients/{}/stats?{}",
-            crate::progenitor_support::encode_path(&client_type.to_string()),
-            query_
+            query_args.push(("start_date", start_date.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/clients/{}/stats",
+                self.client.encode_path(&client_type.to_string())
+            ),
+            &query_args,
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/clients/{client_type}/stats",
+                    &[("client_type", client_type.to_string())],
+                )
+            })
     }
 
     /**
@@ -491,29 +530,28 @@ impl Stats {
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::GetMailboxProvidersStatsResponseData>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if !mailbox_providers.is_empty() {
-            query_args.push((
-                "mailbox_providers".to_string(),
-                mailbox_providers.to_string(),
-            ));
+            query_args.push(("mailbox_providers", mailbox_providers.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/mailbox_providers/stats?{}", query_);
+        let url = crate::Client::url_with_query("/mailbox_providers/stats", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/mailbox_providers/stats", &[]))
     }
 
     /**
@@ -537,29 +575,28 @@ impl Stats {
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::GetMailboxProvidersStatsResponseData>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if !mailbox_providers.is_empty() {
-            query_args.push((
-                "mailbox_providers".to_string(),
-                mailbox_providers.to_string(),
-            ));
+            query_args.push(("mailbox_providers", mailbox_providers.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/mailbox_providers/stats?{}", query_);
+        let url = crate::Client::url_with_query("/mailbox_providers/stats", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/mailbox_providers/stats", &[]))
     }
 
     /**
@@ -591,26 +628,28 @@ impl Stats {
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::GetBrowsersStatsResponseData>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !browsers.is_empty() {
-            query_args.push(("browsers".to_string(), browsers.to_string()));
+            query_args.push(("browsers", browsers.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/browsers/stats?{}", query_);
+        let url = crate::Client::url_with_query("/browsers/stats", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/browsers/stats", &[]))
     }
 
     /**
@@ -634,25 +673,289 @@ impl Stats {
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::GetBrowsersStatsResponseData>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !browsers.is_empty() {
-            query_args.push(("browsers".to_string(), browsers.to_string()));
+            query_args.push(("browsers", browsers.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/browsers/stats?{}", query_);
+        let url = crate::Client::url_with_query("/browsers/stats", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/browsers/stats", &[]))
+    }
+}
+
+/// The functions of [`Stats`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn StatsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait StatsApi: Send + Sync {
+    async fn get_page(
+        &self,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::GetStatsResponseData>>;
+    async fn get_all(
+        &self,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::GetStatsResponseData>>;
+    async fn get_geo(
+        &self,
+        country: crate::types::Country,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::GetGeoStatsResponseData>>;
+    async fn get_all_geo(
+        &self,
+        country: crate::types::Country,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::GetGeoStatsResponseData>>;
+    async fn get_devices(
+        &self,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::GetClientsStatsResponse>>;
+    async fn get_all_devices(
+        &self,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::GetClientsStatsResponse>>;
+    async fn get_clients(
+        &self,
+        start_date: &str,
+        end_date: &str,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> Result<Vec<crate::types::GetClientsStatsResponse>>;
+    async fn get_all_clients(
+        &self,
+        start_date: &str,
+        end_date: &str,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> Result<Vec<crate::types::GetClientsStatsResponse>>;
+    async fn get_clients_client_type(
+        &self,
+        client_type: crate::types::ClientType,
+        start_date: &str,
+        end_date: &str,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> Result<Vec<crate::types::GetClientsStatsResponse>>;
+    async fn get_all_clients_client_type(
+        &self,
+        client_type: crate::types::ClientType,
+        start_date: &str,
+        end_date: &str,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> Result<Vec<crate::types::GetClientsStatsResponse>>;
+    async fn get_mailbox_providers(
+        &self,
+        mailbox_providers: &str,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::GetMailboxProvidersStatsResponseData>>;
+    async fn get_all_mailbox_providers(
+        &self,
+        mailbox_providers: &str,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::GetMailboxProvidersStatsResponseData>>;
+    async fn get_browsers(
+        &self,
+        browsers: &str,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::GetBrowsersStatsResponseData>>;
+    async fn get_all_browsers(
+        &self,
+        browsers: &str,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::GetBrowsersStatsResponseData>>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl StatsApi for Stats {
+    async fn get_page(
+        &self,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::GetStatsResponseData>> {
+        Stats::get_page(self, offset, aggregated_by, start_date, end_date).await
+    }
+    async fn get_all(
+        &self,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::GetStatsResponseData>> {
+        Stats::get_all(self, offset, aggregated_by, start_date, end_date).await
+    }
+    async fn get_geo(
+        &self,
+        country: crate::types::Country,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::GetGeoStatsResponseData>> {
+        Stats::get_geo(self, country, offset, aggregated_by, start_date, end_date).await
+    }
+    async fn get_all_geo(
+        &self,
+        country: crate::types::Country,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::GetGeoStatsResponseData>> {
+        Stats::get_all_geo(self, country, offset, aggregated_by, start_date, end_date).await
+    }
+    async fn get_devices(
+        &self,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::GetClientsStatsResponse>> {
+        Stats::get_devices(self, offset, aggregated_by, start_date, end_date).await
+    }
+    async fn get_all_devices(
+        &self,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::GetClientsStatsResponse>> {
+        Stats::get_all_devices(self, offset, aggregated_by, start_date, end_date).await
+    }
+    async fn get_clients(
+        &self,
+        start_date: &str,
+        end_date: &str,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> Result<Vec<crate::types::GetClientsStatsResponse>> {
+        Stats::get_clients(self, start_date, end_date, aggregated_by).await
+    }
+    async fn get_all_clients(
+        &self,
+        start_date: &str,
+        end_date: &str,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> Result<Vec<crate::types::GetClientsStatsResponse>> {
+        Stats::get_all_clients(self, start_date, end_date, aggregated_by).await
+    }
+    async fn get_clients_client_type(
+        &self,
+        client_type: crate::types::ClientType,
+        start_date: &str,
+        end_date: &str,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> Result<Vec<crate::types::GetClientsStatsResponse>> {
+        Stats::get_clients_client_type(self, client_type, start_date, end_date, aggregated_by).await
+    }
+    async fn get_all_clients_client_type(
+        &self,
+        client_type: crate::types::ClientType,
+        start_date: &str,
+        end_date: &str,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> Result<Vec<crate::types::GetClientsStatsResponse>> {
+        Stats::get_all_clients_client_type(self, client_type, start_date, end_date, aggregated_by)
+            .await
+    }
+    async fn get_mailbox_providers(
+        &self,
+        mailbox_providers: &str,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::GetMailboxProvidersStatsResponseData>> {
+        Stats::get_mailbox_providers(
+            self,
+            mailbox_providers,
+            offset,
+            aggregated_by,
+            start_date,
+            end_date,
+        )
+        .await
+    }
+    async fn get_all_mailbox_providers(
+        &self,
+        mailbox_providers: &str,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::GetMailboxProvidersStatsResponseData>> {
+        Stats::get_all_mailbox_providers(
+            self,
+            mailbox_providers,
+            offset,
+            aggregated_by,
+            start_date,
+            end_date,
+        )
+        .await
+    }
+    async fn get_browsers(
+        &self,
+        browsers: &str,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::GetBrowsersStatsResponseData>> {
+        Stats::get_browsers(self, browsers, offset, aggregated_by, start_date, end_date).await
+    }
+    async fn get_all_browsers(
+        &self,
+        browsers: &str,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::GetBrowsersStatsResponseData>> {
+        Stats::get_all_browsers(self, browsers, offset, aggregated_by, start_date, end_date).await
     }
 }
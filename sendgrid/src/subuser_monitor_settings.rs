@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct SubuserMonitorSettings {
     pub client: Client,
@@ -12,6 +10,14 @@ impl SubuserMonitorSettings {
         SubuserMonitorSettings { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        SubuserMonitorSettings {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieve monitor settings for a subuser.
      *
@@ -23,10 +29,16 @@ impl SubuserMonitorSettings {
     ) -> Result<crate::types::Monitor> {
         let url = format!(
             "/subusers/{}/monitor",
-            crate::progenitor_support::encode_path(&subuser_name.to_string()),
+            self.client.encode_path(subuser_name),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/subusers/{subuser_name}/monitor",
+                &[("subuser_name", subuser_name.to_string())],
+            )
+        })
     }
 
     /**
@@ -41,12 +53,19 @@ impl SubuserMonitorSettings {
     ) -> Result<crate::types::Monitor> {
         let url = format!(
             "/subusers/{}/monitor",
-            crate::progenitor_support::encode_path(&subuser_name.to_string()),
+            self.client.encode_path(subuser_name),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/subusers/{subuser_name}/monitor",
+                    &[("subuser_name", subuser_name.to_string())],
+                )
+            })
     }
 
     /**
@@ -61,12 +80,19 @@ impl SubuserMonitorSettings {
     ) -> Result<crate::types::Monitor> {
         let url = format!(
             "/subusers/{}/monitor",
-            crate::progenitor_support::encode_path(&subuser_name.to_string()),
+            self.client.encode_path(subuser_name),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/subusers/{subuser_name}/monitor",
+                    &[("subuser_name", subuser_name.to_string())],
+                )
+            })
     }
 
     /**
@@ -80,9 +106,72 @@ impl SubuserMonitorSettings {
     ) -> Result<crate::types::Help> {
         let url = format!(
             "/subusers/{}/monitor",
-            crate::progenitor_support::encode_path(&subuser_name.to_string()),
+            self.client.encode_path(subuser_name),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/subusers/{subuser_name}/monitor",
+                &[("subuser_name", subuser_name.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`SubuserMonitorSettings`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn SubuserMonitorSettingsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait SubuserMonitorSettingsApi: Send + Sync {
+    async fn get_subusers_subuser_name_monitor(
+        &self,
+        subuser_name: &str,
+    ) -> Result<crate::types::Monitor>;
+    async fn put_subusers_subuser_name_monitor(
+        &self,
+        subuser_name: &str,
+        body: &crate::types::Monitor,
+    ) -> Result<crate::types::Monitor>;
+    async fn post_subusers_subuser_name_monitor(
+        &self,
+        subuser_name: &str,
+        body: &crate::types::Monitor,
+    ) -> Result<crate::types::Monitor>;
+    async fn delete_subusers_subuser_name_monitor(
+        &self,
+        subuser_name: &str,
+    ) -> Result<crate::types::Help>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl SubuserMonitorSettingsApi for SubuserMonitorSettings {
+    async fn get_subusers_subuser_name_monitor(
+        &self,
+        subuser_name: &str,
+    ) -> Result<crate::types::Monitor> {
+        SubuserMonitorSettings::get_subusers_subuser_name_monitor(self, subuser_name).await
+    }
+    async fn put_subusers_subuser_name_monitor(
+        &self,
+        subuser_name: &str,
+        body: &crate::types::Monitor,
+    ) -> Result<crate::types::Monitor> {
+        SubuserMonitorSettings::put_subusers_subuser_name_monitor(self, subuser_name, body).await
+    }
+    async fn post_subusers_subuser_name_monitor(
+        &self,
+        subuser_name: &str,
+        body: &crate::types::Monitor,
+    ) -> Result<crate::types::Monitor> {
+        SubuserMonitorSettings::post_subusers_subuser_name_monitor(self, subuser_name, body).await
+    }
+    async fn delete_subusers_subuser_name_monitor(
+        &self,
+        subuser_name: &str,
+    ) -> Result<crate::types::Help> {
+        SubuserMonitorSettings::delete_subusers_subuser_name_monitor(self, subuser_name).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct SubuserStatistics {
     pub client: Client,
@@ -12,6 +10,14 @@ impl SubuserStatistics {
         SubuserStatistics { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        SubuserStatistics {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieve the monthly email statistics for a single subuser.
      *
@@ -39,33 +45,37 @@ impl SubuserStatistics {
         limit: i64,
         offset: i64,
     ) -> Result<crate::types::SubuserStatsData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !date.is_empty() {
-            query_args.push(("date".to_string(), date.to_string()));
+            query_args.push(("date", date.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !sort_by_direction.to_string().is_empty() {
-            query_args.push((
-                "sort_by_direction".to_string(),
-                sort_by_direction.to_string(),
-            ));
+            query_args.push(("sort_by_direction", sort_by_direction.to_string()));
         }
         if !sort_by_metric.is_empty() {
-            query_args.push(("sort_by_metric".to_string(), sort_by_metric.to_string()));
+            query_args.push(("sort_by_metric", sort_by_metric.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/subusers/{}/stats/monthly?{}",
-            crate::progenitor_support::encode_path(&subuser_name.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/subusers/{}/stats/monthly",
+                self.client.encode_path(subuser_name)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/subusers/{subuser_name}/stats/monthly",
+                &[("subuser_name", subuser_name.to_string())],
+            )
+        })
     }
 
     /**
@@ -96,32 +106,31 @@ impl SubuserStatistics {
         limit: i64,
         offset: i64,
     ) -> Result<crate::types::SubuserStatsData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !date.is_empty() {
-            query_args.push(("date".to_string(), date.to_string()));
+            query_args.push(("date", date.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !sort_by_direction.to_string().is_empty() {
-            query_args.push((
-                "sort_by_direction".to_string(),
-                sort_by_direction.to_string(),
-            ));
+            query_args.push(("sort_by_direction", sort_by_direction.to_string()));
         }
         if !sort_by_metric.to_string().is_empty() {
-            query_args.push(("sort_by_metric".to_string(), sort_by_metric.to_string()));
+            query_args.push(("sort_by_metric", sort_by_metric.to_string()));
         }
         if !subuser.is_empty() {
-            query_args.push(("subuser".to_string(), subuser.to_string()));
+            query_args.push(("subuser", subuser.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/subusers/stats/monthly?{}", query_);
+        let url = crate::Client::url_with_query("/subusers/stats/monthly", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/subusers/stats/monthly", &[]))
     }
 
     /**
@@ -151,35 +160,34 @@ impl SubuserStatistics {
         aggregated_by: &str,
         sort_by_metric: &str,
     ) -> Result<crate::types::CategoryStats> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !sort_by_direction.to_string().is_empty() {
-            query_args.push((
-                "sort_by_direction".to_string(),
-                sort_by_direction.to_string(),
-            ));
+            query_args.push(("sort_by_direction", sort_by_direction.to_string()));
         }
         if !sort_by_metric.is_empty() {
-            query_args.push(("sort_by_metric".to_string(), sort_by_metric.to_string()));
+            query_args.push(("sort_by_metric", sort_by_metric.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/subusers/stats/sums?{}", query_);
+        let url = crate::Client::url_with_query("/subusers/stats/sums", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/subusers/stats/sums", &[]))
     }
 
     /**
@@ -209,28 +217,162 @@ impl SubuserStatistics {
         start_date: &str,
         end_date: &str,
     ) -> Result<crate::types::CategoryStats> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
         if !subusers.is_empty() {
-            query_args.push(("subusers".to_string(), subusers.to_string()));
+            query_args.push(("subusers", subusers.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/subusers/stats?{}", query_);
+        let url = crate::Client::url_with_query("/subusers/stats", &query_args);
+
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/subusers/stats", &[]))
+    }
+}
+
+/// The functions of [`SubuserStatistics`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn SubuserStatisticsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait SubuserStatisticsApi: Send + Sync {
+    async fn get_subusers_subuser_name_stats_monthly(
+        &self,
+        subuser_name: &str,
+        date: &str,
+        sort_by_metric: &str,
+        sort_by_direction: crate::types::SortByDirection,
+        limit: i64,
+        offset: i64,
+    ) -> Result<crate::types::SubuserStatsData>;
+    async fn get_subusers_stats_monthly(
+        &self,
+        date: &str,
+        subuser: &str,
+        sort_by_metric: crate::types::SortByMetric,
+        sort_by_direction: crate::types::SortByDirection,
+        limit: i64,
+        offset: i64,
+    ) -> Result<crate::types::SubuserStatsData>;
+    async fn get_subusers_stats_sum(
+        &self,
+        sort_by_direction: crate::types::SortByDirection,
+        start_date: &str,
+        end_date: &str,
+        limit: i64,
+        offset: i64,
+        aggregated_by: &str,
+        sort_by_metric: &str,
+    ) -> Result<crate::types::CategoryStats>;
+    async fn get_subusers_stat(
+        &self,
+        limit: i64,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        subusers: &str,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<crate::types::CategoryStats>;
+}
 
-        self.client.get(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl SubuserStatisticsApi for SubuserStatistics {
+    async fn get_subusers_subuser_name_stats_monthly(
+        &self,
+        subuser_name: &str,
+        date: &str,
+        sort_by_metric: &str,
+        sort_by_direction: crate::types::SortByDirection,
+        limit: i64,
+        offset: i64,
+    ) -> Result<crate::types::SubuserStatsData> {
+        SubuserStatistics::get_subusers_subuser_name_stats_monthly(
+            self,
+            subuser_name,
+            date,
+            sort_by_metric,
+            sort_by_direction,
+            limit,
+            offset,
+        )
+        .await
+    }
+    async fn get_subusers_stats_monthly(
+        &self,
+        date: &str,
+        subuser: &str,
+        sort_by_metric: crate::types::SortByMetric,
+        sort_by_direction: crate::types::SortByDirection,
+        limit: i64,
+        offset: i64,
+    ) -> Result<crate::types::SubuserStatsData> {
+        SubuserStatistics::get_subusers_stats_monthly(
+            self,
+            date,
+            subuser,
+            sort_by_metric,
+            sort_by_direction,
+            limit,
+            offset,
+        )
+        .await
+    }
+    async fn get_subusers_stats_sum(
+        &self,
+        sort_by_direction: crate::types::SortByDirection,
+        start_date: &str,
+        end_date: &str,
+        limit: i64,
+        offset: i64,
+        aggregated_by: &str,
+        sort_by_metric: &str,
+    ) -> Result<crate::types::CategoryStats> {
+        SubuserStatistics::get_subusers_stats_sum(
+            self,
+            sort_by_direction,
+            start_date,
+            end_date,
+            limit,
+            offset,
+            aggregated_by,
+            sort_by_metric,
+        )
+        .await
+    }
+    async fn get_subusers_stat(
+        &self,
+        limit: i64,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        subusers: &str,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<crate::types::CategoryStats> {
+        SubuserStatistics::get_subusers_stat(
+            self,
+            limit,
+            offset,
+            aggregated_by,
+            subusers,
+            start_date,
+            end_date,
+        )
+        .await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct SubusersApi {
     pub client: Client,
@@ -12,6 +10,14 @@ impl SubusersApi {
         SubusersApi { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        SubusersApi {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * List all Subusers.
      *
@@ -33,20 +39,22 @@ impl SubusersApi {
         limit: i64,
         offset: i64,
     ) -> Result<Vec<crate::types::Subuser>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !username.is_empty() {
-            query_args.push(("username".to_string(), username.to_string()));
+            query_args.push(("username", username.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/subusers?{}", query_);
+        let url = crate::Client::url_with_query("/subusers", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/subusers", &[]))
     }
 
     /**
@@ -65,17 +73,19 @@ impl SubusersApi {
         username: &str,
         offset: i64,
     ) -> Result<Vec<crate::types::Subuser>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !username.is_empty() {
-            query_args.push(("username".to_string(), username.to_string()));
+            query_args.push(("username", username.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/subusers?{}", query_);
+        let url = crate::Client::url_with_query("/subusers", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/subusers", &[]))
     }
 
     /**
@@ -91,8 +101,9 @@ impl SubusersApi {
     ) -> Result<crate::types::SubuserPost> {
         let url = "/subusers".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/subusers", &[]))
     }
 
     /**
@@ -108,12 +119,15 @@ impl SubusersApi {
         &self,
         subuser_name: &str,
     ) -> Result<crate::types::Help> {
-        let url = format!(
-            "/subusers/{}",
-            crate::progenitor_support::encode_path(&subuser_name.to_string()),
-        );
+        let url = format!("/subusers/{}", self.client.encode_path(subuser_name),);
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/subusers/{subuser_name}",
+                &[("subuser_name", subuser_name.to_string())],
+            )
+        })
     }
 
     /**
@@ -128,14 +142,45 @@ impl SubusersApi {
         subuser_name: &str,
         body: &crate::types::PatchSubusersSubuserNameRequest,
     ) -> Result<crate::types::Help> {
-        let url = format!(
-            "/subusers/{}",
-            crate::progenitor_support::encode_path(&subuser_name.to_string()),
-        );
+        let url = format!("/subusers/{}", self.client.encode_path(subuser_name),);
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/subusers/{subuser_name}",
+                    &[("subuser_name", subuser_name.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Enable/disable a subuser.
+     *
+     * This function performs a `PATCH` to the `/subusers/{subuser_name}` endpoint.
+     *
+     * As opposed to `patch_subusers_subuser_name`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_subusers_subuser_name_if_changed<C: serde::Serialize>(
+        &self,
+        subuser_name: &str,
+        current: &C,
+        body: &crate::types::PatchSubusersSubuserNameRequest,
+    ) -> Result<Option<crate::types::Help>> {
+        let url = format!("/subusers/{}", self.client.encode_path(subuser_name),);
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/subusers/{subuser_name}",
+                    &[("subuser_name", subuser_name.to_string())],
+                )
+            })
     }
 
     /**
@@ -155,14 +200,16 @@ impl SubusersApi {
         &self,
         usernames: &str,
     ) -> Result<Vec<crate::types::GetSubusersReputationsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !usernames.is_empty() {
-            query_args.push(("usernames".to_string(), usernames.to_string()));
+            query_args.push(("usernames", usernames.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/subusers/reputations?{}", query_);
+        let url = crate::Client::url_with_query("/subusers/reputations", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/subusers/reputations", &[]))
     }
 
     /**
@@ -180,14 +227,16 @@ impl SubusersApi {
         &self,
         usernames: &str,
     ) -> Result<Vec<crate::types::GetSubusersReputationsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !usernames.is_empty() {
-            query_args.push(("usernames".to_string(), usernames.to_string()));
+            query_args.push(("usernames", usernames.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/subusers/reputations?{}", query_);
+        let url = crate::Client::url_with_query("/subusers/reputations", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/subusers/reputations", &[]))
     }
 
     /**
@@ -209,13 +258,114 @@ impl SubusersApi {
         subuser_name: &str,
         body: &[std::net::Ipv4Addr],
     ) -> Result<crate::types::PutSubusersSubuserNameIpsResponse> {
-        let url = format!(
-            "/subusers/{}/ips",
-            crate::progenitor_support::encode_path(&subuser_name.to_string()),
-        );
+        let url = format!("/subusers/{}/ips", self.client.encode_path(subuser_name),);
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/subusers/{subuser_name}/ips",
+                    &[("subuser_name", subuser_name.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`SubusersApi`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn SubusersApiApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait SubusersApiApi: Send + Sync {
+    async fn get_subusers(
+        &self,
+        username: &str,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<crate::types::Subuser>>;
+    async fn get_all_subusers(
+        &self,
+        username: &str,
+        offset: i64,
+    ) -> Result<Vec<crate::types::Subuser>>;
+    async fn post_subuser(
+        &self,
+        body: &crate::types::PostSubusersRequest,
+    ) -> Result<crate::types::SubuserPost>;
+    async fn delete_subusers_subuser_name(&self, subuser_name: &str) -> Result<crate::types::Help>;
+    async fn patch_subusers_subuser_name(
+        &self,
+        subuser_name: &str,
+        body: &crate::types::PatchSubusersSubuserNameRequest,
+    ) -> Result<crate::types::Help>;
+    async fn get_subusers_reputations(
+        &self,
+        usernames: &str,
+    ) -> Result<Vec<crate::types::GetSubusersReputationsResponse>>;
+    async fn get_all_subusers_reputations(
+        &self,
+        usernames: &str,
+    ) -> Result<Vec<crate::types::GetSubusersReputationsResponse>>;
+    async fn put_subusers_subuser_name_ips(
+        &self,
+        subuser_name: &str,
+        body: &[std::net::Ipv4Addr],
+    ) -> Result<crate::types::PutSubusersSubuserNameIpsResponse>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl SubusersApiApi for SubusersApi {
+    async fn get_subusers(
+        &self,
+        username: &str,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<crate::types::Subuser>> {
+        SubusersApi::get_subusers(self, username, limit, offset).await
+    }
+    async fn get_all_subusers(
+        &self,
+        username: &str,
+        offset: i64,
+    ) -> Result<Vec<crate::types::Subuser>> {
+        SubusersApi::get_all_subusers(self, username, offset).await
+    }
+    async fn post_subuser(
+        &self,
+        body: &crate::types::PostSubusersRequest,
+    ) -> Result<crate::types::SubuserPost> {
+        SubusersApi::post_subuser(self, body).await
+    }
+    async fn delete_subusers_subuser_name(&self, subuser_name: &str) -> Result<crate::types::Help> {
+        SubusersApi::delete_subusers_subuser_name(self, subuser_name).await
+    }
+    async fn patch_subusers_subuser_name(
+        &self,
+        subuser_name: &str,
+        body: &crate::types::PatchSubusersSubuserNameRequest,
+    ) -> Result<crate::types::Help> {
+        SubusersApi::patch_subusers_subuser_name(self, subuser_name, body).await
+    }
+    async fn get_subusers_reputations(
+        &self,
+        usernames: &str,
+    ) -> Result<Vec<crate::types::GetSubusersReputationsResponse>> {
+        SubusersApi::get_subusers_reputations(self, usernames).await
+    }
+    async fn get_all_subusers_reputations(
+        &self,
+        usernames: &str,
+    ) -> Result<Vec<crate::types::GetSubusersReputationsResponse>> {
+        SubusersApi::get_all_subusers_reputations(self, usernames).await
+    }
+    async fn put_subusers_subuser_name_ips(
+        &self,
+        subuser_name: &str,
+        body: &[std::net::Ipv4Addr],
+    ) -> Result<crate::types::PutSubusersSubuserNameIpsResponse> {
+        SubusersApi::put_subusers_subuser_name_ips(self, subuser_name, body).await
     }
 }
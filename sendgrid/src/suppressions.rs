@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Suppressions {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Suppressions {
         Suppressions { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Suppressions {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieve all suppressions for a suppression group.
      *
@@ -26,10 +32,16 @@ impl Suppressions {
     pub async fn get_asm_groups_group(&self, group_id: &str) -> Result<Vec<String>> {
         let url = format!(
             "/asm/groups/{}/suppressions",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
+            self.client.encode_path(group_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/asm/groups/{group_id}/suppressions",
+                &[("group_id", group_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -44,10 +56,19 @@ impl Suppressions {
     pub async fn get_all_asm_groups_group(&self, group_id: &str) -> Result<Vec<String>> {
         let url = format!(
             "/asm/groups/{}/suppressions",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
+            self.client.encode_path(group_id),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/asm/groups/{group_id}/suppressions",
+                    &[("group_id", group_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -70,12 +91,19 @@ impl Suppressions {
     ) -> Result<crate::types::PostAsmGroupsGroupSuppressionsResponse> {
         let url = format!(
             "/asm/groups/{}/suppressions",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
+            self.client.encode_path(group_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/asm/groups/{group_id}/suppressions",
+                    &[("group_id", group_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -98,12 +126,19 @@ impl Suppressions {
     ) -> Result<Vec<String>> {
         let url = format!(
             "/asm/groups/{}/suppressions/search",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
+            self.client.encode_path(group_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/asm/groups/{group_id}/suppressions/search",
+                    &[("group_id", group_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -119,7 +154,10 @@ impl Suppressions {
      */
     pub async fn get_asm(&self) -> Result<Vec<crate::types::GetAsmSuppressionsResponse>> {
         let url = "/asm/suppressions".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/asm/suppressions", &[]))
     }
 
     /**
@@ -133,7 +171,10 @@ impl Suppressions {
      */
     pub async fn get_all_asm(&self) -> Result<Vec<crate::types::GetAsmSuppressionsResponse>> {
         let url = "/asm/suppressions".to_string();
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/asm/suppressions", &[]))
     }
 
     /**
@@ -151,12 +192,15 @@ impl Suppressions {
         &self,
         email: &str,
     ) -> Result<crate::types::GetAsmSuppressionsEmailResponse> {
-        let url = format!(
-            "/asm/suppressions/{}",
-            crate::progenitor_support::encode_path(&email.to_string()),
-        );
+        let url = format!("/asm/suppressions/{}", self.client.encode_path(email),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/asm/suppressions/{email}",
+                &[("email", email.to_string())],
+            )
+        })
     }
 
     /**
@@ -175,10 +219,131 @@ impl Suppressions {
     pub async fn delete_asm_groups_group_email(&self, group_id: &str, email: &str) -> Result<()> {
         let url = format!(
             "/asm/groups/{}/suppressions/{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-            crate::progenitor_support::encode_path(&email.to_string()),
+            self.client.encode_path(group_id),
+            self.client.encode_path(email),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/asm/groups/{group_id}/suppressions/{email}",
+                &[
+                    ("group_id", group_id.to_string()),
+                    ("email", email.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Delete a suppression from a suppression group.
+     *
+     * This function performs a `DELETE` to the `/asm/groups/{group_id}/suppressions/{email}` endpoint.
+     *
+     * As opposed to `delete_asm_groups_group_email`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_asm_groups_group_email_if_exists(
+        &self,
+        group_id: &str,
+        email: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/asm/groups/{}/suppressions/{}",
+            self.client.encode_path(group_id),
+            self.client.encode_path(email),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/asm/groups/{group_id}/suppressions/{email}",
+                    &[
+                        ("group_id", group_id.to_string()),
+                        ("email", email.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`Suppressions`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn SuppressionsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait SuppressionsApi: Send + Sync {
+    async fn get_asm_groups_group(&self, group_id: &str) -> Result<Vec<String>>;
+    async fn get_all_asm_groups_group(&self, group_id: &str) -> Result<Vec<String>>;
+    async fn post_asm_groups_group(
+        &self,
+        group_id: &str,
+        body: &crate::types::SuppressionsRequestBody,
+    ) -> Result<crate::types::PostAsmGroupsGroupSuppressionsResponse>;
+    async fn post_asm_groups_group_search(
+        &self,
+        group_id: &str,
+        body: &crate::types::SuppressionsRequestBody,
+    ) -> Result<Vec<String>>;
+    async fn get_asm(&self) -> Result<Vec<crate::types::GetAsmSuppressionsResponse>>;
+    async fn get_all_asm(&self) -> Result<Vec<crate::types::GetAsmSuppressionsResponse>>;
+    async fn get_asm_email(
+        &self,
+        email: &str,
+    ) -> Result<crate::types::GetAsmSuppressionsEmailResponse>;
+    async fn delete_asm_groups_group_email(&self, group_id: &str, email: &str) -> Result<()>;
+    async fn delete_asm_groups_group_email_if_exists(
+        &self,
+        group_id: &str,
+        email: &str,
+    ) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl SuppressionsApi for Suppressions {
+    async fn get_asm_groups_group(&self, group_id: &str) -> Result<Vec<String>> {
+        Suppressions::get_asm_groups_group(self, group_id).await
+    }
+    async fn get_all_asm_groups_group(&self, group_id: &str) -> Result<Vec<String>> {
+        Suppressions::get_all_asm_groups_group(self, group_id).await
+    }
+    async fn post_asm_groups_group(
+        &self,
+        group_id: &str,
+        body: &crate::types::SuppressionsRequestBody,
+    ) -> Result<crate::types::PostAsmGroupsGroupSuppressionsResponse> {
+        Suppressions::post_asm_groups_group(self, group_id, body).await
+    }
+    async fn post_asm_groups_group_search(
+        &self,
+        group_id: &str,
+        body: &crate::types::SuppressionsRequestBody,
+    ) -> Result<Vec<String>> {
+        Suppressions::post_asm_groups_group_search(self, group_id, body).await
+    }
+    async fn get_asm(&self) -> Result<Vec<crate::types::GetAsmSuppressionsResponse>> {
+        Suppressions::get_asm(self).await
+    }
+    async fn get_all_asm(&self) -> Result<Vec<crate::types::GetAsmSuppressionsResponse>> {
+        Suppressions::get_all_asm(self).await
+    }
+    async fn get_asm_email(
+        &self,
+        email: &str,
+    ) -> Result<crate::types::GetAsmSuppressionsEmailResponse> {
+        Suppressions::get_asm_email(self, email).await
+    }
+    async fn delete_asm_groups_group_email(&self, group_id: &str, email: &str) -> Result<()> {
+        Suppressions::delete_asm_groups_group_email(self, group_id, email).await
+    }
+    async fn delete_asm_groups_group_email_if_exists(
+        &self,
+        group_id: &str,
+        email: &str,
+    ) -> Result<bool> {
+        Suppressions::delete_asm_groups_group_email_if_exists(self, group_id, email).await
     }
 }
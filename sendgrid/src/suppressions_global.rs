@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct SuppressionsGlobal {
     pub client: Client,
@@ -12,6 +10,14 @@ impl SuppressionsGlobal {
         SuppressionsGlobal { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        SuppressionsGlobal {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Add recipient addresses to the global suppression group.
      *
@@ -29,8 +35,9 @@ impl SuppressionsGlobal {
     ) -> Result<crate::types::SuppressionsRequestBody> {
         let url = "/asm/suppressions/global".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/asm/suppressions/global", &[]))
     }
 
     /**
@@ -55,23 +62,25 @@ impl SuppressionsGlobal {
         limit: i64,
         offset: i64,
     ) -> Result<Vec<crate::types::GetSuppressionUnsubscribesResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if end_time > 0 {
-            query_args.push(("end_time".to_string(), end_time.to_string()));
+            query_args.push(("end_time", end_time.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if start_time > 0 {
-            query_args.push(("start_time".to_string(), start_time.to_string()));
+            query_args.push(("start_time", start_time.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/suppression/unsubscribes?{}", query_);
+        let url = crate::Client::url_with_query("/suppression/unsubscribes", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/suppression/unsubscribes", &[]))
     }
 
     /**
@@ -89,20 +98,22 @@ impl SuppressionsGlobal {
         end_time: i64,
         offset: i64,
     ) -> Result<Vec<crate::types::GetSuppressionUnsubscribesResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if end_time > 0 {
-            query_args.push(("end_time".to_string(), end_time.to_string()));
+            query_args.push(("end_time", end_time.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if start_time > 0 {
-            query_args.push(("start_time".to_string(), start_time.to_string()));
+            query_args.push(("start_time", start_time.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/suppression/unsubscribes?{}", query_);
+        let url = crate::Client::url_with_query("/suppression/unsubscribes", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/suppression/unsubscribes", &[]))
     }
 
     /**
@@ -124,10 +135,16 @@ impl SuppressionsGlobal {
     ) -> Result<crate::types::RetrieveAGlobalSuppressionResponse> {
         let url = format!(
             "/asm/suppressions/global/{}",
-            crate::progenitor_support::encode_path(&email.to_string()),
+            self.client.encode_path(email),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/asm/suppressions/global/{email}",
+                &[("email", email.to_string())],
+            )
+        })
     }
 
     /**
@@ -146,9 +163,84 @@ impl SuppressionsGlobal {
     pub async fn delete_asm_email(&self, email: &str) -> Result<crate::types::Help> {
         let url = format!(
             "/asm/suppressions/global/{}",
-            crate::progenitor_support::encode_path(&email.to_string()),
+            self.client.encode_path(email),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/asm/suppressions/global/{email}",
+                &[("email", email.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`SuppressionsGlobal`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn SuppressionsGlobalApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait SuppressionsGlobalApi: Send + Sync {
+    async fn post_asm(
+        &self,
+        body: &crate::types::SuppressionsRequestBody,
+    ) -> Result<crate::types::SuppressionsRequestBody>;
+    async fn get_suppression_unsubscribes(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<crate::types::GetSuppressionUnsubscribesResponse>>;
+    async fn get_all_suppression_unsubscribes(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        offset: i64,
+    ) -> Result<Vec<crate::types::GetSuppressionUnsubscribesResponse>>;
+    async fn get_asm_email(
+        &self,
+        email: &str,
+    ) -> Result<crate::types::RetrieveAGlobalSuppressionResponse>;
+    async fn delete_asm_email(&self, email: &str) -> Result<crate::types::Help>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl SuppressionsGlobalApi for SuppressionsGlobal {
+    async fn post_asm(
+        &self,
+        body: &crate::types::SuppressionsRequestBody,
+    ) -> Result<crate::types::SuppressionsRequestBody> {
+        SuppressionsGlobal::post_asm(self, body).await
+    }
+    async fn get_suppression_unsubscribes(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<crate::types::GetSuppressionUnsubscribesResponse>> {
+        SuppressionsGlobal::get_suppression_unsubscribes(self, start_time, end_time, limit, offset)
+            .await
+    }
+    async fn get_all_suppression_unsubscribes(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        offset: i64,
+    ) -> Result<Vec<crate::types::GetSuppressionUnsubscribesResponse>> {
+        SuppressionsGlobal::get_all_suppression_unsubscribes(self, start_time, end_time, offset)
+            .await
+    }
+    async fn get_asm_email(
+        &self,
+        email: &str,
+    ) -> Result<crate::types::RetrieveAGlobalSuppressionResponse> {
+        SuppressionsGlobal::get_asm_email(self, email).await
+    }
+    async fn delete_asm_email(&self, email: &str) -> Result<crate::types::Help> {
+        SuppressionsGlobal::delete_asm_email(self, email).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct SuppressionsUnsubscribeGroups {
     pub client: Client,
@@ -12,6 +10,14 @@ impl SuppressionsUnsubscribeGroups {
         SuppressionsUnsubscribeGroups { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        SuppressionsUnsubscribeGroups {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieve all suppression groups associated with the user.
      *
@@ -27,14 +33,16 @@ impl SuppressionsUnsubscribeGroups {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn get_asm_groups(&self, id: i64) -> Result<Vec<crate::types::SuppressionGroup>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if id > 0 {
-            query_args.push(("id".to_string(), id.to_string()));
+            query_args.push(("id", id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/asm/groups?{}", query_);
+        let url = crate::Client::url_with_query("/asm/groups", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/asm/groups", &[]))
     }
 
     /**
@@ -49,14 +57,16 @@ impl SuppressionsUnsubscribeGroups {
      * This endpoint can also return information for multiple group IDs that you include in your request. To add a group ID to your request, simply append `?id=123456&id=123456`, with the appropriate group IDs.
      */
     pub async fn get_all_asm_groups(&self, id: i64) -> Result<Vec<crate::types::SuppressionGroup>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if id > 0 {
-            query_args.push(("id".to_string(), id.to_string()));
+            query_args.push(("id", id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/asm/groups?{}", query_);
+        let url = crate::Client::url_with_query("/asm/groups", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/asm/groups", &[]))
     }
 
     /**
@@ -78,8 +88,9 @@ impl SuppressionsUnsubscribeGroups {
     ) -> Result<crate::types::PostAsmGroupsResponse> {
         let url = "/asm/groups".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/asm/groups", &[]))
     }
 
     /**
@@ -97,12 +108,15 @@ impl SuppressionsUnsubscribeGroups {
         &self,
         group_id: &str,
     ) -> Result<crate::types::GetAsmGroupsGroupResponseAllOf> {
-        let url = format!(
-            "/asm/groups/{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-        );
+        let url = format!("/asm/groups/{}", self.client.encode_path(group_id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/asm/groups/{group_id}",
+                &[("group_id", group_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -121,12 +135,15 @@ impl SuppressionsUnsubscribeGroups {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn delete_asm_groups_group(&self, group_id: &str) -> Result<crate::types::Help> {
-        let url = format!(
-            "/asm/groups/{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-        );
+        let url = format!("/asm/groups/{}", self.client.encode_path(group_id),);
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/asm/groups/{group_id}",
+                &[("group_id", group_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -145,13 +162,101 @@ impl SuppressionsUnsubscribeGroups {
         group_id: &str,
         body: &crate::types::SuppressionGroupRequestBase,
     ) -> Result<crate::types::SuppressionGroup> {
-        let url = format!(
-            "/asm/groups/{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-        );
+        let url = format!("/asm/groups/{}", self.client.encode_path(group_id),);
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/asm/groups/{group_id}",
+                    &[("group_id", group_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update a suppression group.
+     *
+     * This function performs a `PATCH` to the `/asm/groups/{group_id}` endpoint.
+     *
+     * As opposed to `patch_asm_groups_group`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_asm_groups_group_if_changed<C: serde::Serialize>(
+        &self,
+        group_id: &str,
+        current: &C,
+        body: &crate::types::SuppressionGroupRequestBase,
+    ) -> Result<Option<crate::types::SuppressionGroup>> {
+        let url = format!("/asm/groups/{}", self.client.encode_path(group_id),);
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/asm/groups/{group_id}",
+                    &[("group_id", group_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`SuppressionsUnsubscribeGroups`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn SuppressionsUnsubscribeGroupsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait SuppressionsUnsubscribeGroupsApi: Send + Sync {
+    async fn get_asm_groups(&self, id: i64) -> Result<Vec<crate::types::SuppressionGroup>>;
+    async fn get_all_asm_groups(&self, id: i64) -> Result<Vec<crate::types::SuppressionGroup>>;
+    async fn post_asm_group(
+        &self,
+        body: &crate::types::SuppressionGroupRequestBase,
+    ) -> Result<crate::types::PostAsmGroupsResponse>;
+    async fn get_asm_groups_group(
+        &self,
+        group_id: &str,
+    ) -> Result<crate::types::GetAsmGroupsGroupResponseAllOf>;
+    async fn delete_asm_groups_group(&self, group_id: &str) -> Result<crate::types::Help>;
+    async fn patch_asm_groups_group(
+        &self,
+        group_id: &str,
+        body: &crate::types::SuppressionGroupRequestBase,
+    ) -> Result<crate::types::SuppressionGroup>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl SuppressionsUnsubscribeGroupsApi for SuppressionsUnsubscribeGroups {
+    async fn get_asm_groups(&self, id: i64) -> Result<Vec<crate::types::SuppressionGroup>> {
+        SuppressionsUnsubscribeGroups::get_asm_groups(self, id).await
+    }
+    async fn get_all_asm_groups(&self, id: i64) -> Result<Vec<crate::types::SuppressionGroup>> {
+        SuppressionsUnsubscribeGroups::get_all_asm_groups(self, id).await
+    }
+    async fn post_asm_group(
+        &self,
+        body: &crate::types::SuppressionGroupRequestBase,
+    ) -> Result<crate::types::PostAsmGroupsResponse> {
+        SuppressionsUnsubscribeGroups::post_asm_group(self, body).await
+    }
+    async fn get_asm_groups_group(
+        &self,
+        group_id: &str,
+    ) -> Result<crate::types::GetAsmGroupsGroupResponseAllOf> {
+        SuppressionsUnsubscribeGroups::get_asm_groups_group(self, group_id).await
+    }
+    async fn delete_asm_groups_group(&self, group_id: &str) -> Result<crate::types::Help> {
+        SuppressionsUnsubscribeGroups::delete_asm_groups_group(self, group_id).await
+    }
+    async fn patch_asm_groups_group(
+        &self,
+        group_id: &str,
+        body: &crate::types::SuppressionGroupRequestBase,
+    ) -> Result<crate::types::SuppressionGroup> {
+        SuppressionsUnsubscribeGroups::patch_asm_groups_group(self, group_id, body).await
     }
 }
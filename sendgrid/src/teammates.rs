@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Teammates {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Teammates {
         Teammates { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Teammates {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieve all teammates.
      *
@@ -28,17 +34,19 @@ impl Teammates {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn get(&self, limit: u64, offset: u64) -> Result<crate::types::GetTeammatesResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.to_string().is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !offset.to_string().is_empty() {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/teammates?{}", query_);
+        let url = crate::Client::url_with_query("/teammates", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/teammates", &[]))
     }
 
     /**
@@ -62,8 +70,9 @@ impl Teammates {
     ) -> Result<crate::types::PostTeammatesResponse> {
         let url = "/teammates".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/teammates", &[]))
     }
 
     /**
@@ -85,10 +94,16 @@ impl Teammates {
     ) -> Result<crate::types::PostTeammatesResponse> {
         let url = format!(
             "/teammates/pending/{}/resend",
-            crate::progenitor_support::encode_path(&token.to_string()),
+            self.client.encode_path(token),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/teammates/pending/{token}/resend",
+                &[("token", token.to_string())],
+            )
+        })
     }
 
     /**
@@ -110,17 +125,19 @@ impl Teammates {
         limit: i64,
         offset: i64,
     ) -> Result<Vec<crate::types::GetScopesRequestsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/scopes/requests?{}", query_);
+        let url = crate::Client::url_with_query("/scopes/requests", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/scopes/requests", &[]))
     }
 
     /**
@@ -138,14 +155,16 @@ impl Teammates {
         &self,
         offset: i64,
     ) -> Result<Vec<crate::types::GetScopesRequestsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/scopes/requests?{}", query_);
+        let url = crate::Client::url_with_query("/scopes/requests", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/scopes/requests", &[]))
     }
 
     /**
@@ -163,7 +182,10 @@ impl Teammates {
      */
     pub async fn get_pending(&self) -> Result<crate::types::GetTeammatesPendingResponse> {
         let url = "/teammates/pending".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/teammates/pending", &[]))
     }
 
     /**
@@ -183,12 +205,15 @@ impl Teammates {
         &self,
         username: &str,
     ) -> Result<crate::types::GetTeammatesUsernameResponse> {
-        let url = format!(
-            "/teammates/{}",
-            crate::progenitor_support::encode_path(&username.to_string()),
-        );
+        let url = format!("/teammates/{}", self.client.encode_path(username),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/teammates/{username}",
+                &[("username", username.to_string())],
+            )
+        })
     }
 
     /**
@@ -208,12 +233,15 @@ impl Teammates {
         &self,
         username: &str,
     ) -> Result<crate::types::PostSendersResponse> {
-        let url = format!(
-            "/teammates/{}",
-            crate::progenitor_support::encode_path(&username.to_string()),
-        );
+        let url = format!("/teammates/{}", self.client.encode_path(username),);
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/teammates/{username}",
+                &[("username", username.to_string())],
+            )
+        })
     }
 
     /**
@@ -238,14 +266,45 @@ impl Teammates {
         username: &str,
         body: &crate::types::PatchTeammatesUsernameRequest,
     ) -> Result<crate::types::GetTeammatesUsernameResponse> {
-        let url = format!(
-            "/teammates/{}",
-            crate::progenitor_support::encode_path(&username.to_string()),
-        );
+        let url = format!("/teammates/{}", self.client.encode_path(username),);
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/teammates/{username}",
+                    &[("username", username.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update teammate's permissions.
+     *
+     * This function performs a `PATCH` to the `/teammates/{username}` endpoint.
+     *
+     * As opposed to `patch_username`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_username_if_changed<C: serde::Serialize>(
+        &self,
+        username: &str,
+        current: &C,
+        body: &crate::types::PatchTeammatesUsernameRequest,
+    ) -> Result<Option<crate::types::GetTeammatesUsernameResponse>> {
+        let url = format!("/teammates/{}", self.client.encode_path(username),);
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/teammates/{username}",
+                    &[("username", username.to_string())],
+                )
+            })
     }
 
     /**
@@ -263,10 +322,16 @@ impl Teammates {
     ) -> Result<crate::types::PatchScopesRequestsApproveResponse> {
         let url = format!(
             "/scopes/requests/{}/approve",
-            crate::progenitor_support::encode_path(&request_id.to_string()),
+            self.client.encode_path(request_id),
         );
 
-        self.client.patch(&url, None).await
+        self.client.patch(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "PATCH",
+                "/scopes/requests/{request_id}/approve",
+                &[("request_id", request_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -279,12 +344,37 @@ impl Teammates {
      * **Note:** Only teammate admins may delete a teammate's access request.
      */
     pub async fn delete_scopes_requests_request(&self, request_id: &str) -> Result<()> {
-        let url = format!(
-            "/scopes/requests/{}",
-            crate::progenitor_support::encode_path(&request_id.to_string()),
-        );
+        let url = format!("/scopes/requests/{}", self.client.encode_path(request_id),);
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/scopes/requests/{request_id}",
+                &[("request_id", request_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deny access request.
+     *
+     * This function performs a `DELETE` to the `/scopes/requests/{request_id}` endpoint.
+     *
+     * As opposed to `delete_scopes_requests_request`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_scopes_requests_request_if_exists(&self, request_id: &str) -> Result<bool> {
+        let url = format!("/scopes/requests/{}", self.client.encode_path(request_id),);
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/scopes/requests/{request_id}",
+                    &[("request_id", request_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -299,11 +389,151 @@ impl Teammates {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn delete_pending_token(&self, token: &str) -> Result<()> {
-        let url = format!(
-            "/teammates/pending/{}",
-            crate::progenitor_support::encode_path(&token.to_string()),
-        );
+        let url = format!("/teammates/pending/{}", self.client.encode_path(token),);
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/teammates/pending/{token}",
+                &[("token", token.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete pending teammate.
+     *
+     * This function performs a `DELETE` to the `/teammates/pending/{token}` endpoint.
+     *
+     * As opposed to `delete_pending_token`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_pending_token_if_exists(&self, token: &str) -> Result<bool> {
+        let url = format!("/teammates/pending/{}", self.client.encode_path(token),);
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/teammates/pending/{token}",
+                    &[("token", token.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`Teammates`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn TeammatesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait TeammatesApi: Send + Sync {
+    async fn get(&self, limit: u64, offset: u64) -> Result<crate::types::GetTeammatesResponse>;
+    async fn post(
+        &self,
+        body: &crate::types::PostTeammatesRequest,
+    ) -> Result<crate::types::PostTeammatesResponse>;
+    async fn post_pending_token_resend(
+        &self,
+        token: &str,
+    ) -> Result<crate::types::PostTeammatesResponse>;
+    async fn get_scopes_requests(
+        &self,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<crate::types::GetScopesRequestsResponse>>;
+    async fn get_all_scopes_requests(
+        &self,
+        offset: i64,
+    ) -> Result<Vec<crate::types::GetScopesRequestsResponse>>;
+    async fn get_pending(&self) -> Result<crate::types::GetTeammatesPendingResponse>;
+    async fn get_username(
+        &self,
+        username: &str,
+    ) -> Result<crate::types::GetTeammatesUsernameResponse>;
+    async fn delete_username(&self, username: &str) -> Result<crate::types::PostSendersResponse>;
+    async fn patch_username(
+        &self,
+        username: &str,
+        body: &crate::types::PatchTeammatesUsernameRequest,
+    ) -> Result<crate::types::GetTeammatesUsernameResponse>;
+    async fn patch_scopes_requests_approve(
+        &self,
+        request_id: &str,
+    ) -> Result<crate::types::PatchScopesRequestsApproveResponse>;
+    async fn delete_scopes_requests_request(&self, request_id: &str) -> Result<()>;
+    async fn delete_scopes_requests_request_if_exists(&self, request_id: &str) -> Result<bool>;
+    async fn delete_pending_token(&self, token: &str) -> Result<()>;
+    async fn delete_pending_token_if_exists(&self, token: &str) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl TeammatesApi for Teammates {
+    async fn get(&self, limit: u64, offset: u64) -> Result<crate::types::GetTeammatesResponse> {
+        Teammates::get(self, limit, offset).await
+    }
+    async fn post(
+        &self,
+        body: &crate::types::PostTeammatesRequest,
+    ) -> Result<crate::types::PostTeammatesResponse> {
+        Teammates::post(self, body).await
+    }
+    async fn post_pending_token_resend(
+        &self,
+        token: &str,
+    ) -> Result<crate::types::PostTeammatesResponse> {
+        Teammates::post_pending_token_resend(self, token).await
+    }
+    async fn get_scopes_requests(
+        &self,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<crate::types::GetScopesRequestsResponse>> {
+        Teammates::get_scopes_requests(self, limit, offset).await
+    }
+    async fn get_all_scopes_requests(
+        &self,
+        offset: i64,
+    ) -> Result<Vec<crate::types::GetScopesRequestsResponse>> {
+        Teammates::get_all_scopes_requests(self, offset).await
+    }
+    async fn get_pending(&self) -> Result<crate::types::GetTeammatesPendingResponse> {
+        Teammates::get_pending(self).await
+    }
+    async fn get_username(
+        &self,
+        username: &str,
+    ) -> Result<crate::types::GetTeammatesUsernameResponse> {
+        Teammates::get_username(self, username).await
+    }
+    async fn delete_username(&self, username: &str) -> Result<crate::types::PostSendersResponse> {
+        Teammates::delete_username(self, username).await
+    }
+    async fn patch_username(
+        &self,
+        username: &str,
+        body: &crate::types::PatchTeammatesUsernameRequest,
+    ) -> Result<crate::types::GetTeammatesUsernameResponse> {
+        Teammates::patch_username(self, username, body).await
+    }
+    async fn patch_scopes_requests_approve(
+        &self,
+        request_id: &str,
+    ) -> Result<crate::types::PatchScopesRequestsApproveResponse> {
+        Teammates::patch_scopes_requests_approve(self, request_id).await
+    }
+    async fn delete_scopes_requests_request(&self, request_id: &str) -> Result<()> {
+        Teammates::delete_scopes_requests_request(self, request_id).await
+    }
+    async fn delete_scopes_requests_request_if_exists(&self, request_id: &str) -> Result<bool> {
+        Teammates::delete_scopes_requests_request_if_exists(self, request_id).await
+    }
+    async fn delete_pending_token(&self, token: &str) -> Result<()> {
+        Teammates::delete_pending_token(self, token).await
+    }
+    async fn delete_pending_token_if_exists(&self, token: &str) -> Result<bool> {
+        Teammates::delete_pending_token_if_exists(self, token).await
     }
 }
@@ -1 +1,54 @@
+#[test]
+fn test_deserialize_any_of() {
+    // The value is either a string or a number, and parses as whichever it is.
+    let value: crate::types::ValueAnyOf = serde_json::from_str(r#""Ghostbusters""#).unwrap();
+    assert_eq!(
+        value,
+        crate::types::ValueAnyOf::String("Ghostbusters".to_string())
+    );
 
+    let value: crate::types::ValueAnyOf = serde_json::from_str("1984").unwrap();
+    assert_eq!(value, crate::types::ValueAnyOf::I64(1984));
+    assert_eq!(serde_json::to_string(&value).unwrap(), "1984");
+}
+
+#[tokio::test]
+async fn test_serialize_body_error() {
+    use crate::traits::MailOps;
+
+    // Fail every body, as a serializer that cannot handle it would.
+    struct Failing;
+
+    impl crate::BodySerializer for Failing {
+        fn serialize(&self, _body: &serde_json::Value) -> anyhow::Result<Vec<u8>> {
+            anyhow::bail!("cannot serialize the body")
+        }
+    }
+
+    let sendgrid = crate::Client::new("token").with_body_serializer(Failing);
+
+    // The hand-written helpers return the error before sending anything.
+    let err = sendgrid
+        .mail_send()
+        .send_plain_text(
+            "subject",
+            "message",
+            &["to@example.com".to_string()],
+            &[],
+            &[],
+            "from@example.com",
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err.to_string(), "cannot serialize the body");
+
+    let body = crate::types::SuppressionsRequestBody {
+        recipient_emails: vec!["to@example.com".to_string()],
+    };
+    let err = sendgrid
+        .suppressions_global()
+        .post_asm(&body)
+        .await
+        .unwrap_err();
+    assert!(format!("{:#}", err).contains("cannot serialize the body"));
+}
@@ -68,7 +68,7 @@ impl MailOps for crate::mail_send::MailSend {
             .request_raw(
                 reqwest::Method::POST,
                 "/mail/send",
-                Some(reqwest::Body::from(serde_json::to_vec(&mail).unwrap())),
+                Some(self.client.serialize_body(&mail)?),
             )
             .await?;
 
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct TransactionalTemplates {
     pub client: Client,
@@ -12,6 +10,14 @@ impl TransactionalTemplates {
         TransactionalTemplates { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        TransactionalTemplates {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieve paged transactional templates.
      *
@@ -32,20 +38,22 @@ impl TransactionalTemplates {
         page_size: f64,
         page_token: &str,
     ) -> Result<crate::types::GetTemplatesResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !generations.to_string().is_empty() {
-            query_args.push(("generations".to_string(), generations.to_string()));
+            query_args.push(("generations", generations.to_string()));
         }
         if !page_size.to_string().is_empty() {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !page_token.is_empty() {
-            query_args.push(("page_token".to_string(), page_token.to_string()));
+            query_args.push(("page_token", page_token.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/templates?{}", query_);
+        let url = crate::Client::url_with_query("/templates", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/templates", &[]))
     }
 
     /**
@@ -65,8 +73,9 @@ impl TransactionalTemplates {
     ) -> Result<crate::types::TransactionalTemplateAllOf> {
         let url = "/templates".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/templates", &[]))
     }
 
     /**
@@ -84,12 +93,15 @@ impl TransactionalTemplates {
         &self,
         template_id: &str,
     ) -> Result<crate::types::TransactionalTemplateAllOf> {
-        let url = format!(
-            "/templates/{}",
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-        );
+        let url = format!("/templates/{}", self.client.encode_path(template_id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/templates/{template_id}",
+                &[("template_id", template_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -108,14 +120,18 @@ impl TransactionalTemplates {
         template_id: &str,
         body: &crate::types::PostTemplatesTemplateRequest,
     ) -> Result<crate::types::TransactionalTemplateAllOf> {
-        let url = format!(
-            "/templates/{}",
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-        );
+        let url = format!("/templates/{}", self.client.encode_path(template_id),);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/templates/{template_id}",
+                    &[("template_id", template_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -130,12 +146,15 @@ impl TransactionalTemplates {
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
     pub async fn delete_templates_template(&self, template_id: &str) -> Result<crate::types::Help> {
-        let url = format!(
-            "/templates/{}",
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-        );
+        let url = format!("/templates/{}", self.client.encode_path(template_id),);
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/templates/{template_id}",
+                &[("template_id", template_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -156,13 +175,119 @@ impl TransactionalTemplates {
         template_id: &str,
         body: &crate::types::PatchTemplatesTemplateRequest,
     ) -> Result<crate::types::TransactionalTemplateAllOf> {
-        let url = format!(
-            "/templates/{}",
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-        );
+        let url = format!("/templates/{}", self.client.encode_path(template_id),);
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/templates/{template_id}",
+                    &[("template_id", template_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Edit a transactional template.
+     *
+     * This function performs a `PATCH` to the `/templates/{template_id}` endpoint.
+     *
+     * As opposed to `patch_templates_template`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_templates_template_if_changed<C: serde::Serialize>(
+        &self,
+        template_id: &str,
+        current: &C,
+        body: &crate::types::PatchTemplatesTemplateRequest,
+    ) -> Result<Option<crate::types::TransactionalTemplateAllOf>> {
+        let url = format!("/templates/{}", self.client.encode_path(template_id),);
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/templates/{template_id}",
+                    &[("template_id", template_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`TransactionalTemplates`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn TransactionalTemplatesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait TransactionalTemplatesApi: Send + Sync {
+    async fn get_templates(
+        &self,
+        generations: crate::types::Generations,
+        page_size: f64,
+        page_token: &str,
+    ) -> Result<crate::types::GetTemplatesResponse>;
+    async fn post_template(
+        &self,
+        body: &crate::types::PostTemplatesRequest,
+    ) -> Result<crate::types::TransactionalTemplateAllOf>;
+    async fn get_templates_template(
+        &self,
+        template_id: &str,
+    ) -> Result<crate::types::TransactionalTemplateAllOf>;
+    async fn post_templates_template(
+        &self,
+        template_id: &str,
+        body: &crate::types::PostTemplatesTemplateRequest,
+    ) -> Result<crate::types::TransactionalTemplateAllOf>;
+    async fn delete_templates_template(&self, template_id: &str) -> Result<crate::types::Help>;
+    async fn patch_templates_template(
+        &self,
+        template_id: &str,
+        body: &crate::types::PatchTemplatesTemplateRequest,
+    ) -> Result<crate::types::TransactionalTemplateAllOf>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl TransactionalTemplatesApi for TransactionalTemplates {
+    async fn get_templates(
+        &self,
+        generations: crate::types::Generations,
+        page_size: f64,
+        page_token: &str,
+    ) -> Result<crate::types::GetTemplatesResponse> {
+        TransactionalTemplates::get_templates(self, generations, page_size, page_token).await
+    }
+    async fn post_template(
+        &self,
+        body: &crate::types::PostTemplatesRequest,
+    ) -> Result<crate::types::TransactionalTemplateAllOf> {
+        TransactionalTemplates::post_template(self, body).await
+    }
+    async fn get_templates_template(
+        &self,
+        template_id: &str,
+    ) -> Result<crate::types::TransactionalTemplateAllOf> {
+        TransactionalTemplates::get_templates_template(self, template_id).await
+    }
+    async fn post_templates_template(
+        &self,
+        template_id: &str,
+        body: &crate::types::PostTemplatesTemplateRequest,
+    ) -> Result<crate::types::TransactionalTemplateAllOf> {
+        TransactionalTemplates::post_templates_template(self, template_id, body).await
+    }
+    async fn delete_templates_template(&self, template_id: &str) -> Result<crate::types::Help> {
+        TransactionalTemplates::delete_templates_template(self, template_id).await
+    }
+    async fn patch_templates_template(
+        &self,
+        template_id: &str,
+        body: &crate::types::PatchTemplatesTemplateRequest,
+    ) -> Result<crate::types::TransactionalTemplateAllOf> {
+        TransactionalTemplates::patch_templates_template(self, template_id, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct TransactionalTemplatesVersions {
     pub client: Client,
@@ -12,6 +10,14 @@ impl TransactionalTemplatesVersions {
         TransactionalTemplatesVersions { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        TransactionalTemplatesVersions {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Create a new transactional template version.
      *
@@ -30,12 +36,19 @@ impl TransactionalTemplatesVersions {
     ) -> Result<crate::types::TransactionalTemplateVersionOutputAllOf> {
         let url = format!(
             "/templates/{}/versions",
-            crate::progenitor_support::encode_path(&template_id.to_string()),
+            self.client.encode_path(template_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/templates/{template_id}/versions",
+                    &[("template_id", template_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -56,11 +69,20 @@ impl TransactionalTemplatesVersions {
     ) -> Result<crate::types::TransactionalTemplateVersionOutputAllOf> {
         let url = format!(
             "/templates/{}/versions/{}/activate",
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-            crate::progenitor_support::encode_path(&version_id.to_string()),
+            self.client.encode_path(template_id),
+            self.client.encode_path(version_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/templates/{template_id}/versions/{version_id}/activate",
+                &[
+                    ("template_id", template_id.to_string()),
+                    ("version_id", version_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -81,11 +103,20 @@ impl TransactionalTemplatesVersions {
     ) -> Result<crate::types::TransactionalTemplateVersionOutputAllOf> {
         let url = format!(
             "/templates/{}/versions/{}",
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-            crate::progenitor_support::encode_path(&version_id.to_string()),
+            self.client.encode_path(template_id),
+            self.client.encode_path(version_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/templates/{template_id}/versions/{version_id}",
+                &[
+                    ("template_id", template_id.to_string()),
+                    ("version_id", version_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -106,11 +137,53 @@ impl TransactionalTemplatesVersions {
     ) -> Result<()> {
         let url = format!(
             "/templates/{}/versions/{}",
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-            crate::progenitor_support::encode_path(&version_id.to_string()),
+            self.client.encode_path(template_id),
+            self.client.encode_path(version_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/templates/{template_id}/versions/{version_id}",
+                &[
+                    ("template_id", template_id.to_string()),
+                    ("version_id", version_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Delete a transactional template version.
+     *
+     * This function performs a `DELETE` to the `/templates/{template_id}/versions/{version_id}` endpoint.
+     *
+     * As opposed to `delete_templates_template_versions_version`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_templates_template_versions_version_if_exists(
+        &self,
+        template_id: &str,
+        version_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/templates/{}/versions/{}",
+            self.client.encode_path(template_id),
+            self.client.encode_path(version_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/templates/{template_id}/versions/{version_id}",
+                    &[
+                        ("template_id", template_id.to_string()),
+                        ("version_id", version_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -132,12 +205,171 @@ impl TransactionalTemplatesVersions {
     ) -> Result<crate::types::TransactionalTemplateVersionOutputAllOf> {
         let url = format!(
             "/templates/{}/versions/{}",
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-            crate::progenitor_support::encode_path(&version_id.to_string()),
+            self.client.encode_path(template_id),
+            self.client.encode_path(version_id),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/templates/{template_id}/versions/{version_id}",
+                    &[
+                        ("template_id", template_id.to_string()),
+                        ("version_id", version_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * Edit a transactional template version.
+     *
+     * This function performs a `PATCH` to the `/templates/{template_id}/versions/{version_id}` endpoint.
+     *
+     * As opposed to `patch_templates_template_versions_version`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_templates_template_versions_version_if_changed<C: serde::Serialize>(
+        &self,
+        template_id: &str,
+        version_id: &str,
+        current: &C,
+        body: &crate::types::TransactionalTemplateVersionCreate,
+    ) -> Result<Option<crate::types::TransactionalTemplateVersionOutputAllOf>> {
+        let url = format!(
+            "/templates/{}/versions/{}",
+            self.client.encode_path(template_id),
+            self.client.encode_path(version_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/templates/{template_id}/versions/{version_id}",
+                    &[
+                        ("template_id", template_id.to_string()),
+                        ("version_id", version_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`TransactionalTemplatesVersions`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn TransactionalTemplatesVersionsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait TransactionalTemplatesVersionsApi: Send + Sync {
+    async fn post_templates_template_version(
+        &self,
+        template_id: &str,
+        body: &crate::types::TransactionalTemplateVersionCreate,
+    ) -> Result<crate::types::TransactionalTemplateVersionOutputAllOf>;
+    async fn post_templates_template_versions_version_activate(
+        &self,
+        template_id: &str,
+        version_id: &str,
+    ) -> Result<crate::types::TransactionalTemplateVersionOutputAllOf>;
+    async fn get_templates_template_versions_version(
+        &self,
+        template_id: &str,
+        version_id: &str,
+    ) -> Result<crate::types::TransactionalTemplateVersionOutputAllOf>;
+    async fn delete_templates_template_versions_version(
+        &self,
+        template_id: &str,
+        version_id: &str,
+    ) -> Result<()>;
+    async fn delete_templates_template_versions_version_if_exists(
+        &self,
+        template_id: &str,
+        version_id: &str,
+    ) -> Result<bool>;
+    async fn patch_templates_template_versions_version(
+        &self,
+        template_id: &str,
+        version_id: &str,
+        body: &crate::types::TransactionalTemplateVersionCreate,
+    ) -> Result<crate::types::TransactionalTemplateVersionOutputAllOf>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl TransactionalTemplatesVersionsApi for TransactionalTemplatesVersions {
+    async fn post_templates_template_version(
+        &self,
+        template_id: &str,
+        body: &crate::types::TransactionalTemplateVersionCreate,
+    ) -> Result<crate::types::TransactionalTemplateVersionOutputAllOf> {
+        TransactionalTemplatesVersions::post_templates_template_version(self, template_id, body)
             .await
     }
+    async fn post_templates_template_versions_version_activate(
+        &self,
+        template_id: &str,
+        version_id: &str,
+    ) -> Result<crate::types::TransactionalTemplateVersionOutputAllOf> {
+        TransactionalTemplatesVersions::post_templates_template_versions_version_activate(
+            self,
+            template_id,
+            version_id,
+        )
+        .await
+    }
+    async fn get_templates_template_versions_version(
+        &self,
+        template_id: &str,
+        version_id: &str,
+    ) -> Result<crate::types::TransactionalTemplateVersionOutputAllOf> {
+        TransactionalTemplatesVersions::get_templates_template_versions_version(
+            self,
+            template_id,
+            version_id,
+        )
+        .await
+    }
+    async fn delete_templates_template_versions_version(
+        &self,
+        template_id: &str,
+        version_id: &str,
+    ) -> Result<()> {
+        TransactionalTemplatesVersions::delete_templates_template_versions_version(
+            self,
+            template_id,
+            version_id,
+        )
+        .await
+    }
+    async fn delete_templates_template_versions_version_if_exists(
+        &self,
+        template_id: &str,
+        version_id: &str,
+    ) -> Result<bool> {
+        TransactionalTemplatesVersions::delete_templates_template_versions_version_if_exists(
+            self,
+            template_id,
+            version_id,
+        )
+        .await
+    }
+    async fn patch_templates_template_versions_version(
+        &self,
+        template_id: &str,
+        version_id: &str,
+        body: &crate::types::TransactionalTemplateVersionCreate,
+    ) -> Result<crate::types::TransactionalTemplateVersionOutputAllOf> {
+        TransactionalTemplatesVersions::patch_templates_template_versions_version(
+            self,
+            template_id,
+            version_id,
+            body,
+        )
+        .await
+    }
 }
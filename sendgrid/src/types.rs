@@ -2,6 +2,33 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+/// Either one of something, or many of them. Some endpoints send back an
+/// object when there is one and an array when there are more, so this takes both.
+// Many is tried first: serde reads a struct from an array of its fields too,
+// so an array would otherwise be taken for One.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum OneOrMany<T> {
+    Many(Vec<T>),
+    One(T),
+}
+
+impl<T> OneOrMany<T> {
+    /// All of them, as a vector.
+    pub fn into_vec(self) -> Vec<T> {
+        match self {
+            OneOrMany::One(t) => vec![t],
+            OneOrMany::Many(v) => v,
+        }
+    }
+}
+
+impl<T> Default for OneOrMany<T> {
+    fn default() -> Self {
+        OneOrMany::Many(Vec::new())
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PartnerSettingsNewRelic {
     #[serde(
@@ -172,7 +199,7 @@ pub struct CampaignResponseAllOf {
     pub campaign_response: CampaignResponse,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Operator {
     #[serde(rename = "contains")]
     Contains,
@@ -210,13 +237,38 @@ impl Default for Operator {
         Operator::Noop
     }
 }
+
 impl Operator {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Operator::Contains => "contains",
+            Operator::Eq => "eq",
+            Operator::Gt => "gt",
+            Operator::Lt => "lt",
+            Operator::Ne => "ne",
+            Operator::Noop => "",
+            Operator::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Operator] {
+        &[
+            Operator::Contains,
+            Operator::Eq,
+            Operator::Gt,
+            Operator::Lt,
+            Operator::Ne,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Operator::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum AndOr {
     #[serde(rename = "and")]
     And,
@@ -245,7 +297,23 @@ impl Default for AndOr {
         AndOr::Noop
     }
 }
+
 impl AndOr {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AndOr::And => "and",
+            AndOr::Or => "or",
+            AndOr::Noop => "",
+            AndOr::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [AndOr] {
+        &[AndOr::And, AndOr::Or]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, AndOr::Noop)
     }
@@ -573,7 +641,7 @@ pub struct SenderAllOf {
 /**
  * The type of the field.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Type {
     #[serde(rename = "date")]
     Date,
@@ -605,7 +673,24 @@ impl Default for Type {
         Type::Noop
     }
 }
+
 impl Type {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Type::Date => "date",
+            Type::Number => "number",
+            Type::Text => "text",
+            Type::Noop => "",
+            Type::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Type] {
+        &[Type::Date, Type::Number, Type::Text]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Type::Noop)
     }
@@ -680,7 +765,7 @@ pub struct MailSettingsAddressWhitelabel {
 /**
  * The type of DNS record that was generated.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum LinkBranding200ResponseDnsDomainCnameType {
     #[serde(rename = "cname")]
     Cname,
@@ -712,7 +797,28 @@ impl Default for LinkBranding200ResponseDnsDomainCnameType {
         LinkBranding200ResponseDnsDomainCnameType::Noop
     }
 }
+
 impl LinkBranding200ResponseDnsDomainCnameType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LinkBranding200ResponseDnsDomainCnameType::Cname => "cname",
+            LinkBranding200ResponseDnsDomainCnameType::Mx => "mx",
+            LinkBranding200ResponseDnsDomainCnameType::Txt => "txt",
+            LinkBranding200ResponseDnsDomainCnameType::Noop => "",
+            LinkBranding200ResponseDnsDomainCnameType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [LinkBranding200ResponseDnsDomainCnameType] {
+        &[
+            LinkBranding200ResponseDnsDomainCnameType::Cname,
+            LinkBranding200ResponseDnsDomainCnameType::Mx,
+            LinkBranding200ResponseDnsDomainCnameType::Txt,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, LinkBranding200ResponseDnsDomainCnameType::Noop)
     }
@@ -1840,7 +1946,7 @@ pub struct MailSettingsForwardSpam {
 /**
  * The editor used in the UI.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Editor {
     #[serde(rename = "code")]
     Code,
@@ -1869,7 +1975,23 @@ impl Default for Editor {
         Editor::Noop
     }
 }
+
 impl Editor {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Editor::Code => "code",
+            Editor::Design => "design",
+            Editor::Noop => "",
+            Editor::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Editor] {
+        &[Editor::Code, Editor::Design]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Editor::Noop)
     }
@@ -2118,7 +2240,7 @@ pub struct SubuserStatsData {
 /**
  * The status of the scheduled send.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Status {
     #[serde(rename = "cancel")]
     Cancel,
@@ -2147,7 +2269,23 @@ impl Default for Status {
         Status::Noop
     }
 }
+
 impl Status {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Status::Cancel => "cancel",
+            Status::Pause => "pause",
+            Status::Noop => "",
+            Status::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Status] {
+        &[Status::Cancel, Status::Pause]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Status::Noop)
     }
@@ -2597,7 +2735,7 @@ pub struct TransactionalTemplatesVersionOutputLean {
 /**
  * Defines the generation of the template.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Generation {
     #[serde(rename = "dynamic")]
     Dynamic,
@@ -2626,7 +2764,23 @@ impl Default for Generation {
         Generation::Noop
     }
 }
+
 impl Generation {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Generation::Dynamic => "dynamic",
+            Generation::Legacy => "legacy",
+            Generation::Noop => "",
+            Generation::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Generation] {
+        &[Generation::Dynamic, Generation::Legacy]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Generation::Noop)
     }
@@ -3009,7 +3163,7 @@ pub struct SingleContactRequest {
 /**
  * The export job's status. Allowed values: `pending`, `ready`, or `failure`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ContactExportStatus {
     #[serde(rename = "failure")]
     Failure,
@@ -3041,7 +3195,28 @@ impl Default for ContactExportStatus {
         ContactExportStatus::Noop
     }
 }
+
 impl ContactExportStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ContactExportStatus::Failure => "failure",
+            ContactExportStatus::Pending => "pending",
+            ContactExportStatus::Ready => "ready",
+            ContactExportStatus::Noop => "",
+            ContactExportStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ContactExportStatus] {
+        &[
+            ContactExportStatus::Failure,
+            ContactExportStatus::Pending,
+            ContactExportStatus::Ready,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ContactExportStatus::Noop)
     }
@@ -3514,7 +3689,7 @@ pub struct List {
     pub name: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum FieldType {
     #[serde(rename = "Date")]
     Date,
@@ -3546,7 +3721,24 @@ impl Default for FieldType {
         FieldType::Noop
     }
 }
+
 impl FieldType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FieldType::Date => "Date",
+            FieldType::Number => "Number",
+            FieldType::Text => "Text",
+            FieldType::Noop => "",
+            FieldType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [FieldType] {
+        &[FieldType::Date, FieldType::Number, FieldType::Text]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, FieldType::Noop)
     }
@@ -4435,7 +4627,7 @@ pub struct ErrorsDataType {
 /**
  * This is the A/B phase of the Single Send stat returned. If the `group_by` parameter doesn't include `ab_phase` in the request, then the value is "all".
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum AbPhase {
     #[serde(rename = "all")]
     All,
@@ -4465,6 +4657,23 @@ impl Default for AbPhase {
     }
 }
 
+impl AbPhase {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AbPhase::All => "all",
+            AbPhase::Send => "send",
+            AbPhase::Test => "test",
+            AbPhase::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [AbPhase] {
+        &[AbPhase::All, AbPhase::Send, AbPhase::Test]
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SinglesendsResponseResults {
     /**
@@ -4636,7 +4845,7 @@ pub struct MetricsData {
     pub unsubscribes: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SinglesendSearchStatus {
     #[serde(rename = "draft")]
     Draft,
@@ -4668,7 +4877,28 @@ impl Default for SinglesendSearchStatus {
         SinglesendSearchStatus::Noop
     }
 }
+
 impl SinglesendSearchStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SinglesendSearchStatus::Draft => "draft",
+            SinglesendSearchStatus::Scheduled => "scheduled",
+            SinglesendSearchStatus::Triggered => "triggered",
+            SinglesendSearchStatus::Noop => "",
+            SinglesendSearchStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SinglesendSearchStatus] {
+        &[
+            SinglesendSearchStatus::Draft,
+            SinglesendSearchStatus::Scheduled,
+            SinglesendSearchStatus::Triggered,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SinglesendSearchStatus::Noop)
     }
@@ -5302,7 +5532,7 @@ pub struct DomainAuthentication200ResponseAllOf {
 /**
  * What differs between the A/B tests
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum AbTestSummaryType {
     #[serde(rename = "content")]
     Content,
@@ -5331,7 +5561,23 @@ impl Default for AbTestSummaryType {
         AbTestSummaryType::Noop
     }
 }
+
 impl AbTestSummaryType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AbTestSummaryType::Content => "content",
+            AbTestSummaryType::Subject => "subject",
+            AbTestSummaryType::Noop => "",
+            AbTestSummaryType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [AbTestSummaryType] {
+        &[AbTestSummaryType::Content, AbTestSummaryType::Subject]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, AbTestSummaryType::Noop)
     }
@@ -5340,7 +5586,7 @@ impl AbTestSummaryType {
 /**
  * How the winner will be decided
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum WinnerCriteria {
     #[serde(rename = "click")]
     Click,
@@ -5372,7 +5618,28 @@ impl Default for WinnerCriteria {
         WinnerCriteria::Noop
     }
 }
+
 impl WinnerCriteria {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WinnerCriteria::Click => "click",
+            WinnerCriteria::Manual => "manual",
+            WinnerCriteria::Open => "open",
+            WinnerCriteria::Noop => "",
+            WinnerCriteria::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [WinnerCriteria] {
+        &[
+            WinnerCriteria::Click,
+            WinnerCriteria::Manual,
+            WinnerCriteria::Open,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, WinnerCriteria::Noop)
     }
@@ -6085,7 +6352,7 @@ pub struct InvalidEmail {
 /**
  * The message's status.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum EmailActivityResponseCommonFieldsStatus {
     #[serde(rename = "delivered")]
     Delivered,
@@ -6117,7 +6384,28 @@ impl Default for EmailActivityResponseCommonFieldsStatus {
         EmailActivityResponseCommonFieldsStatus::Noop
     }
 }
+
 impl EmailActivityResponseCommonFieldsStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EmailActivityResponseCommonFieldsStatus::Delivered => "delivered",
+            EmailActivityResponseCommonFieldsStatus::NotDelivered => "not delivered",
+            EmailActivityResponseCommonFieldsStatus::Processed => "processed",
+            EmailActivityResponseCommonFieldsStatus::Noop => "",
+            EmailActivityResponseCommonFieldsStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [EmailActivityResponseCommonFieldsStatus] {
+        &[
+            EmailActivityResponseCommonFieldsStatus::Delivered,
+            EmailActivityResponseCommonFieldsStatus::NotDelivered,
+            EmailActivityResponseCommonFieldsStatus::Processed,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, EmailActivityResponseCommonFieldsStatus::Noop)
     }
@@ -6381,7 +6669,7 @@ pub struct SsoTeammateRequestAllOf {
 /**
  * A Teammate can be an “admin,” “owner,” or “teammate.” Each role is associated with the scope of the Teammate’s permissions.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum UserType {
     #[serde(rename = "admin")]
     Admin,
@@ -6413,7 +6701,24 @@ impl Default for UserType {
         UserType::Noop
     }
 }
+
 impl UserType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UserType::Admin => "admin",
+            UserType::Owner => "owner",
+            UserType::Teammate => "teammate",
+            UserType::Noop => "",
+            UserType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [UserType] {
+        &[UserType::Admin, UserType::Owner, UserType::Teammate]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, UserType::Noop)
     }
@@ -7084,7 +7389,7 @@ pub struct SegmentUpdate {
     pub query_dsl: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum AbbvMessageStatus {
     #[serde(rename = "delivered")]
     Delivered,
@@ -7116,7 +7421,28 @@ impl Default for AbbvMessageStatus {
         AbbvMessageStatus::Noop
     }
 }
+
 impl AbbvMessageStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AbbvMessageStatus::Delivered => "delivered",
+            AbbvMessageStatus::NotDelivered => "not_delivered",
+            AbbvMessageStatus::Processed => "processed",
+            AbbvMessageStatus::Noop => "",
+            AbbvMessageStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [AbbvMessageStatus] {
+        &[
+            AbbvMessageStatus::Delivered,
+            AbbvMessageStatus::NotDelivered,
+            AbbvMessageStatus::Processed,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, AbbvMessageStatus::Noop)
     }
@@ -7188,7 +7514,7 @@ pub struct Messages {
 /**
  * Name of event
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum EventName {
     #[serde(rename = "bounced")]
     Bounced,
@@ -7244,7 +7570,44 @@ impl Default for EventName {
         EventName::Noop
     }
 }
+
 impl EventName {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EventName::Bounced => "bounced",
+            EventName::Clicked => "clicked",
+            EventName::Deferred => "deferred",
+            EventName::Delivered => "delivered",
+            EventName::Dropped => "dropped",
+            EventName::GroupResubscribe => "group_resubscribe",
+            EventName::GroupUnsubscribe => "group_unsubscribe",
+            EventName::Opened => "opened",
+            EventName::Processed => "processed",
+            EventName::SpamReport => "spam_report",
+            EventName::Unsubscribe => "unsubscribe",
+            EventName::Noop => "",
+            EventName::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [EventName] {
+        &[
+            EventName::Bounced,
+            EventName::Clicked,
+            EventName::Deferred,
+            EventName::Delivered,
+            EventName::Dropped,
+            EventName::GroupResubscribe,
+            EventName::GroupUnsubscribe,
+            EventName::Opened,
+            EventName::Processed,
+            EventName::SpamReport,
+            EventName::Unsubscribe,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, EventName::Noop)
     }
@@ -7253,7 +7616,7 @@ impl EventName {
 /**
  * Use to distinguish between types of bounces
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum BounceType {
     #[serde(rename = "blocked")]
     Blocked,
@@ -7285,7 +7648,28 @@ impl Default for BounceType {
         BounceType::Noop
     }
 }
+
 impl BounceType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BounceType::Blocked => "blocked",
+            BounceType::Bounced => "bounced",
+            BounceType::Expired => "expired",
+            BounceType::Noop => "",
+            BounceType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [BounceType] {
+        &[
+            BounceType::Blocked,
+            BounceType::Bounced,
+            BounceType::Expired,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, BounceType::Noop)
     }
@@ -7356,7 +7740,7 @@ pub struct Event {
 /**
  * Whether or not the outbound IP is dedicated vs shared
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum OutboundIpType {
     #[serde(rename = "dedicated")]
     Dedicated,
@@ -7385,7 +7769,23 @@ impl Default for OutboundIpType {
         OutboundIpType::Noop
     }
 }
+
 impl OutboundIpType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OutboundIpType::Dedicated => "dedicated",
+            OutboundIpType::Shared => "shared",
+            OutboundIpType::Noop => "",
+            OutboundIpType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [OutboundIpType] {
+        &[OutboundIpType::Dedicated, OutboundIpType::Shared]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, OutboundIpType::Noop)
     }
@@ -7759,7 +8159,7 @@ pub struct AuthenticationDomain {
 /**
  * Dictates how the stats are time-sliced. Currently, `"total"` and `"day"` are supported.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum AggregatedBy {
     #[serde(rename = "day")]
     Day,
@@ -7786,7 +8186,23 @@ impl Default for AggregatedBy {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl AggregatedBy {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AggregatedBy::Day => "day",
+            AggregatedBy::Total => "total",
+            AggregatedBy::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [AggregatedBy] {
+        &[AggregatedBy::Day, AggregatedBy::Total]
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GroupBy {
     #[serde(rename = "ab_phase")]
     AbPhase,
@@ -7815,14 +8231,30 @@ impl Default for GroupBy {
         GroupBy::Noop
     }
 }
-impl GroupBy {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, GroupBy::Noop)
-    }
-}
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum TraitAutomationQueryParamsGroupBy {
+impl GroupBy {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GroupBy::AbPhase => "ab_phase",
+            GroupBy::AbVariation => "ab_variation",
+            GroupBy::Noop => "",
+            GroupBy::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GroupBy] {
+        &[GroupBy::AbPhase, GroupBy::AbVariation]
+    }
+
+    pub fn is_noop(&self) -> bool {
+        matches!(self, GroupBy::Noop)
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
+pub enum TraitAutomationQueryParamsGroupBy {
     #[serde(rename = "step_id")]
     StepId,
     #[serde(rename = "")]
@@ -7847,13 +8279,28 @@ impl Default for TraitAutomationQueryParamsGroupBy {
         TraitAutomationQueryParamsGroupBy::Noop
     }
 }
+
 impl TraitAutomationQueryParamsGroupBy {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TraitAutomationQueryParamsGroupBy::StepId => "step_id",
+            TraitAutomationQueryParamsGroupBy::Noop => "",
+            TraitAutomationQueryParamsGroupBy::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [TraitAutomationQueryParamsGroupBy] {
+        &[TraitAutomationQueryParamsGroupBy::StepId]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, TraitAutomationQueryParamsGroupBy::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum AbPhaseId {
     #[serde(rename = "send")]
     Send,
@@ -7882,7 +8329,23 @@ impl Default for AbPhaseId {
         AbPhaseId::Noop
     }
 }
+
 impl AbPhaseId {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AbPhaseId::Send => "send",
+            AbPhaseId::Test => "test",
+            AbPhaseId::Noop => "",
+            AbPhaseId::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [AbPhaseId] {
+        &[AbPhaseId::Send, AbPhaseId::Test]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, AbPhaseId::Noop)
     }
@@ -7891,7 +8354,7 @@ impl AbPhaseId {
 /**
  * How to group the statistics. Must be either "day", "week", or "month".
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum TraitStatsAdvancedBaseQueryStringsAggregatedBy {
     #[serde(rename = "day")]
     Day,
@@ -7923,7 +8386,28 @@ impl Default for TraitStatsAdvancedBaseQueryStringsAggregatedBy {
         TraitStatsAdvancedBaseQueryStringsAggregatedBy::Noop
     }
 }
+
 impl TraitStatsAdvancedBaseQueryStringsAggregatedBy {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TraitStatsAdvancedBaseQueryStringsAggregatedBy::Day => "day",
+            TraitStatsAdvancedBaseQueryStringsAggregatedBy::Month => "month",
+            TraitStatsAdvancedBaseQueryStringsAggregatedBy::Week => "week",
+            TraitStatsAdvancedBaseQueryStringsAggregatedBy::Noop => "",
+            TraitStatsAdvancedBaseQueryStringsAggregatedBy::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [TraitStatsAdvancedBaseQueryStringsAggregatedBy] {
+        &[
+            TraitStatsAdvancedBaseQueryStringsAggregatedBy::Day,
+            TraitStatsAdvancedBaseQueryStringsAggregatedBy::Month,
+            TraitStatsAdvancedBaseQueryStringsAggregatedBy::Week,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, TraitStatsAdvancedBaseQueryStringsAggregatedBy::Noop)
     }
@@ -8145,7 +8629,7 @@ pub struct Content {
 /**
  * The attachment's content-disposition, specifying how you would like the attachment to be displayed. For example, `“inline”` results in the attached file are displayed automatically within the message while `“attachment”` results in the attached file require some action to be taken before it is displayed, such as opening or downloading the file.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Disposition {
     #[serde(rename = "attachment")]
     Attachment,
@@ -8172,6 +8656,22 @@ impl Default for Disposition {
     }
 }
 
+impl Disposition {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Disposition::Attachment => "attachment",
+            Disposition::Inline => "inline",
+            Disposition::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Disposition] {
+        &[Disposition::Attachment, Disposition::Inline]
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Attachments {
     /**
@@ -9574,7 +10074,7 @@ pub struct PatchScopesRequestsApproveResponse {
 /**
  * The type of alert.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GetAlertsResponseType {
     #[serde(rename = "stats_notification")]
     StatsNotification,
@@ -9603,7 +10103,26 @@ impl Default for GetAlertsResponseType {
         GetAlertsResponseType::Noop
     }
 }
+
 impl GetAlertsResponseType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GetAlertsResponseType::StatsNotification => "stats_notification",
+            GetAlertsResponseType::UsageLimit => "usage_limit",
+            GetAlertsResponseType::Noop => "",
+            GetAlertsResponseType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GetAlertsResponseType] {
+        &[
+            GetAlertsResponseType::StatsNotification,
+            GetAlertsResponseType::UsageLimit,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, GetAlertsResponseType::Noop)
     }
@@ -9757,7 +10276,7 @@ pub struct PostAlertsResponse {
 /**
  * The type of alert.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GetAlertsAlertResponseType {
     #[serde(rename = "stats_notification")]
     StatsNotification,
@@ -9786,7 +10305,26 @@ impl Default for GetAlertsAlertResponseType {
         GetAlertsAlertResponseType::Noop
     }
 }
+
 impl GetAlertsAlertResponseType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GetAlertsAlertResponseType::StatsNotification => "stats_notification",
+            GetAlertsAlertResponseType::UsageAlert => "usage_alert",
+            GetAlertsAlertResponseType::Noop => "",
+            GetAlertsAlertResponseType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GetAlertsAlertResponseType] {
+        &[
+            GetAlertsAlertResponseType::StatsNotification,
+            GetAlertsAlertResponseType::UsageAlert,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, GetAlertsAlertResponseType::Noop)
     }
@@ -9973,7 +10511,7 @@ pub struct GetUserProfileResponse {
 /**
  * The type of account for this user.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GetUserAccountResponseType {
     #[serde(rename = "free")]
     Free,
@@ -10002,7 +10540,26 @@ impl Default for GetUserAccountResponseType {
         GetUserAccountResponseType::Noop
     }
 }
+
 impl GetUserAccountResponseType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GetUserAccountResponseType::Free => "free",
+            GetUserAccountResponseType::Paid => "paid",
+            GetUserAccountResponseType::Noop => "",
+            GetUserAccountResponseType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GetUserAccountResponseType] {
+        &[
+            GetUserAccountResponseType::Free,
+            GetUserAccountResponseType::Paid,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, GetUserAccountResponseType::Noop)
     }
@@ -10237,7 +10794,7 @@ pub struct PutSubusersSubuserNameIpsResponse {
 /**
  * The direction you want to sort.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SortByDirection {
     #[serde(rename = "asc")]
     Asc,
@@ -10264,10 +10821,26 @@ impl Default for SortByDirection {
     }
 }
 
+impl SortByDirection {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SortByDirection::Asc => "asc",
+            SortByDirection::Desc => "desc",
+            SortByDirection::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SortByDirection] {
+        &[SortByDirection::Asc, SortByDirection::Desc]
+    }
+}
+
 /**
  * The metric that you want to sort by. Metrics that you can sort by are: `blocks`, `bounces`, `clicks`, `delivered`, `opens`, `requests`, `unique_clicks`, `unique_opens`, and `unsubscribes`.'
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SortByMetric {
     #[serde(rename = "blocks")]
     Blocks,
@@ -10315,6 +10888,39 @@ impl Default for SortByMetric {
     }
 }
 
+impl SortByMetric {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SortByMetric::Blocks => "blocks",
+            SortByMetric::Bounces => "bounces",
+            SortByMetric::Clicks => "clicks",
+            SortByMetric::Delivered => "delivered",
+            SortByMetric::Opens => "opens",
+            SortByMetric::Requests => "requests",
+            SortByMetric::UniqueClicks => "unique_clicks",
+            SortByMetric::UniqueOpens => "unique_opens",
+            SortByMetric::Unsubscribes => "unsubscribes",
+            SortByMetric::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SortByMetric] {
+        &[
+            SortByMetric::Blocks,
+            SortByMetric::Bounces,
+            SortByMetric::Clicks,
+            SortByMetric::Delivered,
+            SortByMetric::Opens,
+            SortByMetric::Requests,
+            SortByMetric::UniqueClicks,
+            SortByMetric::UniqueOpens,
+            SortByMetric::Unsubscribes,
+        ]
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostWhitelabelLinksRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -10559,7 +11165,7 @@ pub struct PostValidationsEmailRequest {
 /**
  * A generic classification of whether or not the email address is valid.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Verdict {
     #[serde(rename = "Invalid")]
     Invalid,
@@ -10591,7 +11197,24 @@ impl Default for Verdict {
         Verdict::Noop
     }
 }
+
 impl Verdict {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Verdict::Invalid => "Invalid",
+            Verdict::Risky => "Risky",
+            Verdict::Valid => "Valid",
+            Verdict::Noop => "",
+            Verdict::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Verdict] {
+        &[Verdict::Invalid, Verdict::Risky, Verdict::Valid]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Verdict::Noop)
     }
@@ -11842,7 +12465,7 @@ pub struct Notifications {
 /**
  * File type for export file. Choose from `json` or `csv`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum FileType {
     #[serde(rename = "csv")]
     Csv,
@@ -11869,6 +12492,22 @@ impl Default for FileType {
     }
 }
 
+impl FileType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FileType::Csv => "csv",
+            FileType::Json => "json",
+            FileType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [FileType] {
+        &[FileType::Csv, FileType::Json]
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PostMcContactsExportsRequest {
     /**
@@ -11956,7 +12595,7 @@ pub struct PostMcContactsSearchResponse {
 /**
  * Upload file type.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PutMcContactsImportsRequestFileType {
     #[serde(rename = "csv")]
     Csv,
@@ -11982,25 +12621,69 @@ impl Default for PutMcContactsImportsRequestFileType {
         PutMcContactsImportsRequestFileType::Noop
     }
 }
+
 impl PutMcContactsImportsRequestFileType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PutMcContactsImportsRequestFileType::Csv => "csv",
+            PutMcContactsImportsRequestFileType::Noop => "",
+            PutMcContactsImportsRequestFileType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PutMcContactsImportsRequestFileType] {
+        &[PutMcContactsImportsRequestFileType::Csv]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PutMcContactsImportsRequestFileType::Noop)
     }
 }
 
-/// All of the following types are flattened into one object:
+/// All of the following types:
 ///
 /// - `String`
 /// - `serde_json::Value`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct FieldMappingsAnyOf {
+#[serde(untagged)]
+pub enum FieldMappingsAnyOf {
     /**
      * The license key provided with your New Relic account.
      */
-    #[serde(flatten)]
-    pub string: String,
-    #[serde(flatten)]
-    pub value: serde_json::Value,
+    String(String),
+    Value(serde_json::Value),
+}
+
+impl FieldMappingsAnyOf {
+    pub fn string(&self) -> Option<&String> {
+        if let FieldMappingsAnyOf::String(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn serde_json_value(&self) -> Option<&serde_json::Value> {
+        if let FieldMappingsAnyOf::Value(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+impl std::convert::From<String> for FieldMappingsAnyOf {
+    fn from(f: String) -> Self {
+        FieldMappingsAnyOf::String(f)
+    }
+}
+
+impl std::convert::From<FieldMappingsAnyOf> for String {
+    fn from(f: FieldMappingsAnyOf) -> Self {
+        f.string().unwrap().clone()
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12490,7 +13173,7 @@ pub struct PostMarketingSinglesendsRequest {
 /**
  * This is the ISO 8601 time at which to send the Single Send; must be in future, or the string "now"
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SendAt {
     #[serde(rename = "now")]
     Now,
@@ -12516,7 +13199,22 @@ impl Default for SendAt {
         SendAt::Noop
     }
 }
+
 impl SendAt {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SendAt::Now => "now",
+            SendAt::Noop => "",
+            SendAt::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SendAt] {
+        &[SendAt::Now]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SendAt::Noop)
     }
@@ -12531,7 +13229,7 @@ pub struct PutMarketingSinglesendsScheduleRequest {
     pub send_at: SendAt,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PutMarketingSinglesendsScheduleResponseStatus {
     #[serde(rename = "scheduled")]
     Scheduled,
@@ -12557,7 +13255,22 @@ impl Default for PutMarketingSinglesendsScheduleResponseStatus {
         PutMarketingSinglesendsScheduleResponseStatus::Noop
     }
 }
+
 impl PutMarketingSinglesendsScheduleResponseStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PutMarketingSinglesendsScheduleResponseStatus::Scheduled => "scheduled",
+            PutMarketingSinglesendsScheduleResponseStatus::Noop => "",
+            PutMarketingSinglesendsScheduleResponseStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PutMarketingSinglesendsScheduleResponseStatus] {
+        &[PutMarketingSinglesendsScheduleResponseStatus::Scheduled]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PutMarketingSinglesendsScheduleResponseStatus::Noop)
     }
@@ -12860,19 +13573,60 @@ pub struct PostContactdbRecipientsSearchRequest {
     pub list_id: i64,
 }
 
-/// All of the following types are flattened into one object:
+/// All of the following types:
 ///
 /// - `String`
 /// - `i64`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ValueAnyOf {
+#[serde(untagged)]
+pub enum ValueAnyOf {
     /**
      * The license key provided with your New Relic account.
      */
-    #[serde(flatten)]
-    pub string: String,
-    #[serde(flatten)]
-    pub i64: i64,
+    String(String),
+    I64(i64),
+}
+
+impl ValueAnyOf {
+    pub fn i64(&self) -> Option<&i64> {
+        if let ValueAnyOf::I64(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn string(&self) -> Option<&String> {
+        if let ValueAnyOf::String(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+impl std::convert::From<i64> for ValueAnyOf {
+    fn from(f: i64) -> Self {
+        ValueAnyOf::I64(f)
+    }
+}
+
+impl std::convert::From<String> for ValueAnyOf {
+    fn from(f: String) -> Self {
+        ValueAnyOf::String(f)
+    }
+}
+
+impl std::convert::From<ValueAnyOf> for i64 {
+    fn from(f: ValueAnyOf) -> Self {
+        *f.i64().unwrap()
+    }
+}
+
+impl std::convert::From<ValueAnyOf> for String {
+    fn from(f: ValueAnyOf) -> Self {
+        f.string().unwrap().clone()
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -13320,7 +14074,7 @@ pub struct ScheduleACampaignRequest {
 /**
  * The status of your campaign.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ScheduleACampaignResponseStatus {
     #[serde(rename = "Scheduled")]
     Scheduled,
@@ -13346,7 +14100,22 @@ impl Default for ScheduleACampaignResponseStatus {
         ScheduleACampaignResponseStatus::Noop
     }
 }
+
 impl ScheduleACampaignResponseStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ScheduleACampaignResponseStatus::Scheduled => "Scheduled",
+            ScheduleACampaignResponseStatus::Noop => "",
+            ScheduleACampaignResponseStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ScheduleACampaignResponseStatus] {
+        &[ScheduleACampaignResponseStatus::Scheduled]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ScheduleACampaignResponseStatus::Noop)
     }
@@ -13417,7 +14186,7 @@ pub struct SendATestCampaignRequest {
 /**
  * Comma-delimited list specifying which generations of templates to return. Options are `legacy`, `dynamic` or `legacy,dynamic`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Generations {
     #[serde(rename = "dynamic")]
     Dynamic,
@@ -13447,6 +14216,27 @@ impl Default for Generations {
     }
 }
 
+impl Generations {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Generations::Dynamic => "dynamic",
+            Generations::Legacy => "legacy",
+            Generations::LegacyDynamic => "legacy,dynamic",
+            Generations::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Generations] {
+        &[
+            Generations::Dynamic,
+            Generations::Legacy,
+            Generations::LegacyDynamic,
+        ]
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetTemplatesResponse {
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
@@ -13678,7 +14468,7 @@ pub struct GetMessagesResponse {
     pub messages: Vec<Messages>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PostMessagesDownloadResponseStatus {
     #[serde(rename = "pending")]
     Pending,
@@ -13704,7 +14494,22 @@ impl Default for PostMessagesDownloadResponseStatus {
         PostMessagesDownloadResponseStatus::Noop
     }
 }
+
 impl PostMessagesDownloadResponseStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PostMessagesDownloadResponseStatus::Pending => "pending",
+            PostMessagesDownloadResponseStatus::Noop => "",
+            PostMessagesDownloadResponseStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PostMessagesDownloadResponseStatus] {
+        &[PostMessagesDownloadResponseStatus::Pending]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PostMessagesDownloadResponseStatus::Noop)
     }
@@ -13836,7 +14641,7 @@ pub struct GetStatsResponseData {
 /**
  * The country you would like to see statistics for. Currently only supported for US and CA.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Country {
     #[serde(rename = "CA")]
     Ca,
@@ -13865,7 +14670,23 @@ impl Default for Country {
         Country::Noop
     }
 }
+
 impl Country {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Country::Ca => "CA",
+            Country::Us => "US",
+            Country::Noop => "",
+            Country::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Country] {
+        &[Country::Ca, Country::Us]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Country::Noop)
     }
@@ -13971,7 +14792,7 @@ pub struct GetClientsStatsResponse {
 /**
  * Specifies the type of client to retrieve stats for. Must be either "phone", "tablet", "webmail", or "desktop".
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ClientType {
     #[serde(rename = "desktop")]
     Desktop,
@@ -14006,7 +14827,30 @@ impl Default for ClientType {
         ClientType::Noop
     }
 }
+
 impl ClientType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ClientType::Desktop => "desktop",
+            ClientType::Phone => "phone",
+            ClientType::Tablet => "tablet",
+            ClientType::Webmail => "webmail",
+            ClientType::Noop => "",
+            ClientType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ClientType] {
+        &[
+            ClientType::Desktop,
+            ClientType::Phone,
+            ClientType::Tablet,
+            ClientType::Webmail,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ClientType::Noop)
     }
@@ -14341,3 +15185,430 @@ pub struct GetAsmSuppressionsEmailResponse {
     )]
     pub suppressions: Vec<Suppressions>,
 }
+
+/// The types taken and returned by the functions in [`crate::alerts`].
+pub mod alerts {
+    pub use super::{
+        GetAlertsAlertResponse, GetAlertsResponse, Help, PatchAlertsAlertRequest,
+        PostAlertsRequest, PostAlertsResponse,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::api_key_permissions`].
+pub mod api_key_permissions {
+    pub use super::GetScopesResponse;
+}
+
+/// The types taken and returned by the functions in [`crate::api_keys`].
+pub mod api_keys {
+    pub use super::{
+        ApiKeyNameId, ApiKeyNameScopesAllOf, CreateApiKeysRequest, CreateApiKeysResponse,
+        GetApiKeysKeyResponse, GetApiKeysResponse, IpPool, PutApiKeysKeyRequest,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::blocks_api`].
+pub mod blocks_api {
+    pub use super::{BlocksResponse, DeleteSuppressionBlocksRequest, Help};
+}
+
+/// The types taken and returned by the functions in [`crate::bounces_api`].
+pub mod bounces_api {
+    pub use super::{BounceResponse, DeleteSuppressionBouncesRequest, Help};
+}
+
+/// The types taken and returned by the functions in [`crate::campaigns_api`].
+pub mod campaigns_api {
+    pub use super::{
+        CampaignResponseAllOf, CampaignsRequest, GetCampaignsCampaignResponse,
+        GetCampaignsResponse, ScheduleACampaignRequest, ScheduleACampaignResponse,
+        SendACampaignResponse, SendATestCampaignRequest, UpdateACampaignRequest,
+        UpdateAScheduledCampaignResponse,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::cancel_scheduled_sends`].
+pub mod cancel_scheduled_sends {
+    pub use super::{
+        CancelPauseAScheduledSendRequest, MailBatchId, UserScheduledSendStatus,
+        UserScheduledSendStatusAllOf,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::categories`].
+pub mod categories {
+    pub use super::{
+        CategoryStats, GetCategoriesResponse, SortByDirection,
+        TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::certificates`].
+pub mod certificates {
+    pub use super::{
+        PatchSsoCertificatesCertRequest, PostSsoCertificatesRequest, SsoCertificateBody,
+        SsoErrorResponse,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::contacts`].
+pub mod contacts {
+    pub use super::{
+        ContactDetails3, ContactExport, ContactImport, DeleteMcContactsResponse,
+        GetMarketingContactsExportsResponse, GetMcContactsCountResponse, GetMcContatsResponse,
+        PostMarketingContactsBatchRequest, PostMarketingContactsBatchResponse,
+        PostMarketingContactsSearchEmailsRequest, PostMarketingContactsSearchEmailsResponse,
+        PostMcContactsExportsRequest, PostMcContactsExportsResponse, PostMcContactsSearchRequest,
+        PostMcContactsSearchResponse, PutMcContactsImportsRequest, PutMcContactsImportsResponse,
+        PutMcContactsRequest, PutMcContactsResponse,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::contacts_api_custom_fields`].
+pub mod contacts_api_custom_fields {
+    pub use super::{
+        ContactdbCustomFieldWithAllOf, GetContactdbReservedFieldsResponse,
+        GlobalErrorResponseSchema, ListAllCustomFieldsResponse, PostContactdbCustomFieldsRequest,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::contacts_api_lists`].
+pub mod contacts_api_lists {
+    pub use super::{
+        ContactdbList, GetContactdbRecipientsSearchResponse, IpPool, ListAllListsResponse,
+        PatchContactdbListsListResponse,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::contacts_api_recipients`].
+pub mod contacts_api_recipients {
+    pub use super::{
+        ContactDbRecipientResponse, ContactdbRecipient, ContactdbRecipientCount,
+        GetContactdbRecipientsRecipientListsResponse, GetContactdbRecipientsSearchResponse,
+        GetContactdbStatusResponseData, Help, ListRecipientsResponse,
+        PatchContactdbRecipientsRequest, PostContactdbRecipientsRequest,
+        PostContactdbRecipientsSearchRequest, PostContactdbRecipientsSearchResponseData,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::contacts_api_segments`].
+pub mod contacts_api_segments {
+    pub use super::{
+        ContactdbSegments, ContactdbSegmentsWithAllOf, ListAllSegmentsResponse,
+        ListRecipientsOnASegmentResponse, PatchContactdbSegmentsSegmentRequest,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::csv_ui_only`].
+pub mod csv_ui_only {
+    pub use super::{GetMessagesDownloadResponse, PostMessagesDownloadResponse};
+}
+
+/// The types taken and returned by the functions in [`crate::custom_fields`].
+pub mod custom_fields {
+    pub use super::{
+        GetMcFieldDefinitionsResponse, IpPool, PostMcFieldDefinitionsRequest,
+        PostMcFieldDefinitionsResponseAllOf,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::designs_api`].
+pub mod designs_api {
+    pub use super::{
+        DesignDuplicateInput, DesignInputAllOf, DesignOutputAllOf, Help, ListDesignsResponse,
+        PutDesignRequest,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::domain_authentication`].
+pub mod domain_authentication {
+    pub use super::{
+        AuthenticationDomain, DomainAuthentication, DomainAuthentication200ResponseAllOf, Help,
+        Ips, PatchWhitelabelDomainsDomainRequest, PostWhitelabelDomainsRequest,
+        PostWhitelabelDomainsValidateResponse, PutUserUsernameResponse,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::email_address_validation`].
+pub mod email_address_validation {
+    pub use super::{PostValidationsEmailRequest, PostValidationsEmailResponse};
+}
+
+/// The types taken and returned by the functions in [`crate::email_cname_records`].
+pub mod email_cname_records {
+    pub use super::PostWhitelabelDnsEmailRequest;
+}
+
+/// The types taken and returned by the functions in [`crate::invalid_emails_api`].
+pub mod invalid_emails_api {
+    pub use super::{DeleteSuppressionBlocksRequest, Help, InvalidEmail};
+}
+
+/// The types taken and returned by the functions in [`crate::ip_access_management`].
+pub mod ip_access_management {
+    pub use super::{
+        DeleteAccessSettingsWhitelistRequest, GetAccessSettingsActivityResponse, Help,
+        IpAccessResponse, PostAccessSettingsWhitelistRequest,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::ip_addresses`].
+pub mod ip_addresses {
+    pub use super::{
+        GetIpsAssignedResponse, GetIpsIpAddressResponse, GetIpsRemainingResponse, GetIpsResponse,
+        PostIpsRequest, PostIpsResponseData, SortByDirection,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::ip_pools`].
+pub mod ip_pools {
+    pub use super::{
+        GetIpsAssignedResponse, GetIpsPoolsPoolNameResponse, Help, IpPool, IpPoolsPoolResp,
+        PostIpsWarmupRequest, PutIpsPoolsPoolNameRequest,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::ip_warmup`].
+pub mod ip_warmup {
+    pub use super::{Help, IpWarmupResponse, PostIpsWarmupRequest};
+}
+
+/// The types taken and returned by the functions in [`crate::link_branding`].
+pub mod link_branding {
+    pub use super::{
+        Help, LinkBranding200Response, PatchWhitelabelLinksRequest,
+        PostWhitelabelLinksLinkSubuserRequest, PostWhitelabelLinksRequest,
+        PostWhitelabelLinksValidateResponse,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::lists`].
+pub mod lists {
+    pub use super::{
+        DeleteListsResponse, DeleteMcListsContactsResponse, GetMcListsContactsCountResponse,
+        GetMcListsResponse, GetMcListsResponseAllOf, IpPool, List, PatchMcListsRequest,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::mail_send`].
+pub mod mail_send {
+    pub use super::PostMailSendRequest;
+}
+
+/// The types taken and returned by the functions in [`crate::marketing_campaigns_stats`].
+pub mod marketing_campaigns_stats {
+    pub use super::{
+        AbPhaseId, AggregatedBy, AutomationsLinkStatsResponse, AutomationsResponse,
+        SinglesendsLinkStatsResponse, SinglesendsResponse,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::query`].
+pub mod query {
+    pub use super::{GetMessagesResponse, Message};
+}
+
+/// The types taken and returned by the functions in [`crate::reverse_dns`].
+pub mod reverse_dns {
+    pub use super::{
+        Help, PostWhitelabelIpsRequest, PostWhitelabelIpsValidateResponse, ReverseDns,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::segmenting_contacts`].
+pub mod segmenting_contacts {
+    pub use super::{
+        FullSegmentAllOf, GetMarketingSegmentsResponse, Help, PostMarketingSegmentsDeleteRequest,
+        PostMarketingSegmentsDeleteResponse, PostMarketingSegmentsRequestAllOf, SegmentWriteV2,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::segmenting_contacts_beta`].
+pub mod segmenting_contacts_beta {
+    pub use super::{AllSegmentsResponse, SegmentResponse, SegmentUpdate, SegmentWriteV2};
+}
+
+/// The types taken and returned by the functions in [`crate::send_test_email`].
+pub mod send_test_email {
+    pub use super::{Help, PostMarketingTestSendEmailRequest};
+}
+
+/// The types taken and returned by the functions in [`crate::sender_identities_api`].
+pub mod sender_identities_api {
+    pub use super::{
+        GetSendersResponse, Help, PostSendersRequestAllOf, SenderAllOf, SenderRequest,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::sender_verification`].
+pub mod sender_verification {
+    pub use super::{
+        GetVerifiedSendersDomainsResponse, GetVerifiedSendersResponse,
+        GetVerifiedSendersStepsCompletedResponse, Help, VerifiedSenderRequestSchema,
+        VerifiedSenderResponseSchema,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::senders`].
+pub mod senders {
+    pub use super::{PostMarketingSendersRequest, SenderAllOf};
+}
+
+/// The types taken and returned by the functions in [`crate::settings_enforced_tls`].
+pub mod settings_enforced_tls {
+    pub use super::EnforcedTlsRequestResponse;
+}
+
+/// The types taken and returned by the functions in [`crate::settings_inbound_parse`].
+pub mod settings_inbound_parse {
+    pub use super::{Help, ParseSetting};
+}
+
+/// The types taken and returned by the functions in [`crate::settings_mail`].
+pub mod settings_mail {
+    pub use super::{
+        GetMailSettingsResponse, MailSettingsAddressWhitelabel, MailSettingsBouncePurge,
+        MailSettingsFooter, MailSettingsForwardBounce, MailSettingsForwardSpam,
+        MailSettingsTemplate, PatchMailSettingsAddressWhitelistRequest,
+        PatchMailSettingsTemplateRequest, PatchMailSettingsTemplateResponse,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::settings_partner`].
+pub mod settings_partner {
+    pub use super::{
+        GetPartnerSettingsResponse, PartnerSettingsNewRelic, PatchPartnerSettingsNewRelicRequest,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::settings_tracking`].
+pub mod settings_tracking {
+    pub use super::{
+        ClickTracking, GetTrackingSettingsOpenResponse, GetTrackingSettingsResponse,
+        GoogleAnalyticsSettings, PatchTrackingSettingsOpenRequest, SubscriptionTrackingSettings,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::single_sends`].
+pub mod single_sends {
+    pub use super::{
+        GetMarketingSinglesendsCategoriesResponse, GetMarketingSinglesendsResponse,
+        PostMarketingSinglesendsRequest, PutMarketingSinglesendsScheduleRequest,
+        PutMarketingSinglesendsScheduleResponse, SinglesendRequest, SinglesendResponseAllOf,
+        SinglesendSchedule, SinglesendSearch,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::single_sign_on_settings`].
+pub mod single_sign_on_settings {
+    pub use super::{CreateIntegrationRequest, SsoIntegrationAllOf};
+}
+
+/// The types taken and returned by the functions in [`crate::single_sign_on_teammates`].
+pub mod single_sign_on_teammates {
+    pub use super::{
+        PatchSsoTeammatesUsernameRequest, SsoTeammateRequestAllOf, SsoTeammateResponseAllOf,
+        SsoTeammatesPatchResponseAllOf,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::spam_reports_api`].
+pub mod spam_reports_api {
+    pub use super::{DeleteSuppressionBlocksRequest, Help, SpamReportsResponse};
+}
+
+/// The types taken and returned by the functions in [`crate::stats`].
+pub mod stats {
+    pub use super::{
+        ClientType, Country, GetBrowsersStatsResponseData, GetClientsStatsResponse,
+        GetGeoStatsResponseData, GetMailboxProvidersStatsResponseData, GetStatsResponseData,
+        TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::subuser_monitor_settings`].
+pub mod subuser_monitor_settings {
+    pub use super::{Help, Monitor};
+}
+
+/// The types taken and returned by the functions in [`crate::subuser_statistics`].
+pub mod subuser_statistics {
+    pub use super::{
+        CategoryStats, SortByDirection, SortByMetric, SubuserStatsData,
+        TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::subusers_api`].
+pub mod subusers_api {
+    pub use super::{
+        GetSubusersReputationsResponse, Help, PatchSubusersSubuserNameRequest, PostSubusersRequest,
+        PutSubusersSubuserNameIpsResponse, Subuser, SubuserPost,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::suppressions`].
+pub mod suppressions {
+    pub use super::{
+        GetAsmSuppressionsEmailResponse, GetAsmSuppressionsResponse,
+        PostAsmGroupsGroupSuppressionsResponse, SuppressionsRequestBody,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::suppressions_global`].
+pub mod suppressions_global {
+    pub use super::{
+        GetSuppressionUnsubscribesResponse, Help, RetrieveAGlobalSuppressionResponse,
+        SuppressionsRequestBody,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::suppressions_unsubscribe_groups`].
+pub mod suppressions_unsubscribe_groups {
+    pub use super::{
+        GetAsmGroupsGroupResponseAllOf, Help, PostAsmGroupsResponse, SuppressionGroup,
+        SuppressionGroupRequestBase,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::teammates`].
+pub mod teammates {
+    pub use super::{
+        GetScopesRequestsResponse, GetTeammatesPendingResponse, GetTeammatesResponse,
+        GetTeammatesUsernameResponse, PatchScopesRequestsApproveResponse,
+        PatchTeammatesUsernameRequest, PostSendersResponse, PostTeammatesRequest,
+        PostTeammatesResponse,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::transactional_templates`].
+pub mod transactional_templates {
+    pub use super::{
+        Generations, GetTemplatesResponse, Help, PatchTemplatesTemplateRequest,
+        PostTemplatesRequest, PostTemplatesTemplateRequest, TransactionalTemplateAllOf,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::transactional_templates_versions`].
+pub mod transactional_templates_versions {
+    pub use super::{TransactionalTemplateVersionCreate, TransactionalTemplateVersionOutputAllOf};
+}
+
+/// The types taken and returned by the functions in [`crate::users_api`].
+pub mod users_api {
+    pub use super::{
+        GetUserAccountResponse, GetUserCreditsResponse, GetUserEmailResponse,
+        GetUserProfileResponse, Help, PutUserEmailRequest, PutUserPasswordRequest,
+        PutUserUsernameRequest, PutUserUsernameResponse, UserProfile, Users,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::webhooks`].
+pub mod webhooks {
+    pub use super::{
+        GetTrackingSettingsOpenResponse, GetUserWebhooksEventSettingsSignedResponse,
+        GetUserWebhooksParseSettingsResponse, GetUserWebhooksParseStatsResponseData,
+        PostUserWebhooksEventTestRequest, TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        WebhooksEventWebhookResponse, WebhooksEventWebhookUpdateWithOAuthRequest,
+    };
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct UsersApi {
     pub client: Client,
@@ -12,6 +10,14 @@ impl UsersApi {
         UsersApi { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        UsersApi {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get a user's profile.
      *
@@ -23,7 +29,10 @@ impl UsersApi {
      */
     pub async fn get_user_profile(&self) -> Result<crate::types::GetUserProfileResponse> {
         let url = "/user/profile".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/user/profile", &[]))
     }
 
     /**
@@ -45,8 +54,28 @@ impl UsersApi {
     ) -> Result<crate::types::UserProfile> {
         let url = "/user/profile".to_string();
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| crate::utils::pretty_url("PATCH", "/user/profile", &[]))
+    }
+
+    /**
+     * Update a user's profile.
+     *
+     * This function performs a `PATCH` to the `/user/profile` endpoint.
+     *
+     * As opposed to `patch_user_profile`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_user_profile_if_changed<C: serde::Serialize>(
+        &self,
+        current: &C,
+        body: &crate::types::UserProfile,
+    ) -> Result<Option<crate::types::UserProfile>> {
+        let url = "/user/profile".to_string();
+        self.client
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| crate::utils::pretty_url("PATCH", "/user/profile", &[]))
     }
 
     /**
@@ -64,7 +93,10 @@ impl UsersApi {
      */
     pub async fn get_user_account(&self) -> Result<crate::types::GetUserAccountResponse> {
         let url = "/user/account".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/user/account", &[]))
     }
 
     /**
@@ -80,7 +112,10 @@ impl UsersApi {
      */
     pub async fn get_user_email(&self) -> Result<crate::types::GetUserEmailResponse> {
         let url = "/user/email".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/user/email", &[]))
     }
 
     /**
@@ -100,8 +135,9 @@ impl UsersApi {
     ) -> Result<crate::types::GetUserEmailResponse> {
         let url = "/user/email".to_string();
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("PUT", "/user/email", &[]))
     }
 
     /**
@@ -117,7 +153,10 @@ impl UsersApi {
      */
     pub async fn get_user_username(&self) -> Result<crate::types::Users> {
         let url = "/user/username".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/user/username", &[]))
     }
 
     /**
@@ -137,8 +176,9 @@ impl UsersApi {
     ) -> Result<crate::types::PutUserUsernameResponse> {
         let url = "/user/username".to_string();
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("PUT", "/user/username", &[]))
     }
 
     /**
@@ -156,7 +196,10 @@ impl UsersApi {
      */
     pub async fn get_user_credits(&self) -> Result<crate::types::GetUserCreditsResponse> {
         let url = "/user/credits".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/user/credits", &[]))
     }
 
     /**
@@ -176,7 +219,81 @@ impl UsersApi {
     ) -> Result<crate::types::Help> {
         let url = "/user/password".to_string();
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("PUT", "/user/password", &[]))
+    }
+}
+
+/// The functions of [`UsersApi`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn UsersApiApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait UsersApiApi: Send + Sync {
+    async fn get_user_profile(&self) -> Result<crate::types::GetUserProfileResponse>;
+    async fn patch_user_profile(
+        &self,
+        body: &crate::types::UserProfile,
+    ) -> Result<crate::types::UserProfile>;
+    async fn get_user_account(&self) -> Result<crate::types::GetUserAccountResponse>;
+    async fn get_user_email(&self) -> Result<crate::types::GetUserEmailResponse>;
+    async fn put_user_email(
+        &self,
+        body: &crate::types::PutUserEmailRequest,
+    ) -> Result<crate::types::GetUserEmailResponse>;
+    async fn get_user_username(&self) -> Result<crate::types::Users>;
+    async fn put_user_username(
+        &self,
+        body: &crate::types::PutUserUsernameRequest,
+    ) -> Result<crate::types::PutUserUsernameResponse>;
+    async fn get_user_credits(&self) -> Result<crate::types::GetUserCreditsResponse>;
+    async fn put_user_password(
+        &self,
+        body: &crate::types::PutUserPasswordRequest,
+    ) -> Result<crate::types::Help>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl UsersApiApi for UsersApi {
+    async fn get_user_profile(&self) -> Result<crate::types::GetUserProfileResponse> {
+        UsersApi::get_user_profile(self).await
+    }
+    async fn patch_user_profile(
+        &self,
+        body: &crate::types::UserProfile,
+    ) -> Result<crate::types::UserProfile> {
+        UsersApi::patch_user_profile(self, body).await
+    }
+    async fn get_user_account(&self) -> Result<crate::types::GetUserAccountResponse> {
+        UsersApi::get_user_account(self).await
+    }
+    async fn get_user_email(&self) -> Result<crate::types::GetUserEmailResponse> {
+        UsersApi::get_user_email(self).await
+    }
+    async fn put_user_email(
+        &self,
+        body: &crate::types::PutUserEmailRequest,
+    ) -> Result<crate::types::GetUserEmailResponse> {
+        UsersApi::put_user_email(self, body).await
+    }
+    async fn get_user_username(&self) -> Result<crate::types::Users> {
+        UsersApi::get_user_username(self).await
+    }
+    async fn put_user_username(
+        &self,
+        body: &crate::types::PutUserUsernameRequest,
+    ) -> Result<crate::types::PutUserUsernameResponse> {
+        UsersApi::put_user_username(self, body).await
+    }
+    async fn get_user_credits(&self) -> Result<crate::types::GetUserCreditsResponse> {
+        UsersApi::get_user_credits(self).await
+    }
+    async fn put_user_password(
+        &self,
+        body: &crate::types::PutUserPasswordRequest,
+    ) -> Result<crate::types::Help> {
+        UsersApi::put_user_password(self, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Webhooks {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Webhooks {
         Webhooks { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Webhooks {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieve Event Webhook settings.
      *
@@ -33,7 +39,10 @@ impl Webhooks {
         &self,
     ) -> Result<crate::types::WebhooksEventWebhookResponse> {
         let url = "/user/webhooks/event/settings".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/user/webhooks/event/settings", &[]))
     }
 
     /**
@@ -59,8 +68,32 @@ impl Webhooks {
     ) -> Result<crate::types::WebhooksEventWebhookResponse> {
         let url = "/user/webhooks/event/settings".to_string();
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url("PATCH", "/user/webhooks/event/settings", &[])
+            })
+    }
+
+    /**
+     * Update Event Notification Settings.
+     *
+     * This function performs a `PATCH` to the `/user/webhooks/event/settings` endpoint.
+     *
+     * As opposed to `patch_user_event_settings`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_user_event_settings_if_changed<C: serde::Serialize>(
+        &self,
+        current: &C,
+        body: &crate::types::WebhooksEventWebhookUpdateWithOAuthRequest,
+    ) -> Result<Option<crate::types::WebhooksEventWebhookResponse>> {
+        let url = "/user/webhooks/event/settings".to_string();
+        self.client
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("PATCH", "/user/webhooks/event/settings", &[])
+            })
     }
 
     /**
@@ -78,7 +111,10 @@ impl Webhooks {
         &self,
     ) -> Result<crate::types::GetUserWebhooksParseSettingsResponse> {
         let url = "/user/webhooks/parse/settings".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/user/webhooks/parse/settings", &[]))
     }
 
     /**
@@ -109,26 +145,28 @@ impl Webhooks {
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::GetUserWebhooksParseStatsResponseData>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !offset.is_empty() {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/user/webhooks/parse/stats?{}", query_);
+        let url = crate::Client::url_with_query("/user/webhooks/parse/stats", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/user/webhooks/parse/stats", &[]))
     }
 
     /**
@@ -151,23 +189,25 @@ impl Webhooks {
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::GetUserWebhooksParseStatsResponseData>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !aggregated_by.to_string().is_empty() {
-            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+            query_args.push(("aggregated_by", aggregated_by.to_string()));
         }
         if !end_date.is_empty() {
-            query_args.push(("end_date".to_string(), end_date.to_string()));
+            query_args.push(("end_date", end_date.to_string()));
         }
         if !offset.is_empty() {
-            query_args.push(("offset".to_string(), offset.to_string()));
+            query_args.push(("offset", offset.to_string()));
         }
         if !start_date.is_empty() {
-            query_args.push(("start_date".to_string(), start_date.to_string()));
+            query_args.push(("start_date", start_date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/user/webhooks/parse/stats?{}", query_);
+        let url = crate::Client::url_with_query("/user/webhooks/parse/stats", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/user/webhooks/parse/stats", &[]))
     }
 
     /**
@@ -189,7 +229,9 @@ impl Webhooks {
         &self,
     ) -> Result<crate::types::GetUserWebhooksEventSettingsSignedResponse> {
         let url = "/user/webhooks/event/settings/signed".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/user/webhooks/event/settings/signed", &[])
+        })
     }
 
     /**
@@ -213,8 +255,32 @@ impl Webhooks {
     ) -> Result<crate::types::GetUserWebhooksEventSettingsSignedResponse> {
         let url = "/user/webhooks/event/settings/signed".to_string();
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url("PATCH", "/user/webhooks/event/settings/signed", &[])
+            })
+    }
+
+    /**
+     * Enable/Disable Signed Webhook.
+     *
+     * This function performs a `PATCH` to the `/user/webhooks/event/settings/signed` endpoint.
+     *
+     * As opposed to `patch_user_event_settings_signed`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn patch_user_event_settings_signed_if_changed<C: serde::Serialize>(
+        &self,
+        current: &C,
+        body: &crate::types::GetTrackingSettingsOpenResponse,
+    ) -> Result<Option<crate::types::GetUserWebhooksEventSettingsSignedResponse>> {
+        let url = "/user/webhooks/event/settings/signed".to_string();
+        self.client
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("PATCH", "/user/webhooks/event/settings/signed", &[])
+            })
     }
 
     /**
@@ -242,7 +308,106 @@ impl Webhooks {
     ) -> Result<()> {
         let url = "/user/webhooks/event/test".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/user/webhooks/event/test", &[]))
+    }
+}
+
+/// The functions of [`Webhooks`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn WebhooksApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait WebhooksApi: Send + Sync {
+    async fn get_user_event_settings(&self) -> Result<crate::types::WebhooksEventWebhookResponse>;
+    async fn patch_user_event_settings(
+        &self,
+        body: &crate::types::WebhooksEventWebhookUpdateWithOAuthRequest,
+    ) -> Result<crate::types::WebhooksEventWebhookResponse>;
+    async fn get_user_parse_settings(
+        &self,
+    ) -> Result<crate::types::GetUserWebhooksParseSettingsResponse>;
+    async fn get_user_parse_stats(
+        &self,
+        limit: &str,
+        offset: &str,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::GetUserWebhooksParseStatsResponseData>>;
+    async fn get_all_user_parse_stats(
+        &self,
+        offset: &str,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::GetUserWebhooksParseStatsResponseData>>;
+    async fn get_user_event_settings_signed(
+        &self,
+    ) -> Result<crate::types::GetUserWebhooksEventSettingsSignedResponse>;
+    async fn patch_user_event_settings_signed(
+        &self,
+        body: &crate::types::GetTrackingSettingsOpenResponse,
+    ) -> Result<crate::types::GetUserWebhooksEventSettingsSignedResponse>;
+    async fn post_user_event_test(
+        &self,
+        body: &crate::types::PostUserWebhooksEventTestRequest,
+    ) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl WebhooksApi for Webhooks {
+    async fn get_user_event_settings(&self) -> Result<crate::types::WebhooksEventWebhookResponse> {
+        Webhooks::get_user_event_settings(self).await
+    }
+    async fn patch_user_event_settings(
+        &self,
+        body: &crate::types::WebhooksEventWebhookUpdateWithOAuthRequest,
+    ) -> Result<crate::types::WebhooksEventWebhookResponse> {
+        Webhooks::patch_user_event_settings(self, body).await
+    }
+    async fn get_user_parse_settings(
+        &self,
+    ) -> Result<crate::types::GetUserWebhooksParseSettingsResponse> {
+        Webhooks::get_user_parse_settings(self).await
+    }
+    async fn get_user_parse_stats(
+        &self,
+        limit: &str,
+        offset: &str,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::GetUserWebhooksParseStatsResponseData>> {
+        Webhooks::get_user_parse_stats(self, limit, offset, aggregated_by, start_date, end_date)
             .await
     }
+    async fn get_all_user_parse_stats(
+        &self,
+        offset: &str,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::GetUserWebhooksParseStatsResponseData>> {
+        Webhooks::get_all_user_parse_stats(self, offset, aggregated_by, start_date, end_date).await
+    }
+    async fn get_user_event_settings_signed(
+        &self,
+    ) -> Result<crate::types::GetUserWebhooksEventSettingsSignedResponse> {
+        Webhooks::get_user_event_settings_signed(self).await
+    }
+    async fn patch_user_event_settings_signed(
+        &self,
+        body: &crate::types::GetTrackingSettingsOpenResponse,
+    ) -> Result<crate::types::GetUserWebhooksEventSettingsSignedResponse> {
+        Webhooks::patch_user_event_settings_signed(self, body).await
+    }
+    async fn post_user_event_test(
+        &self,
+        body: &crate::types::PostUserWebhooksEventTestRequest,
+    ) -> Result<()> {
+        Webhooks::post_user_event_test(self, body).await
+    }
 }
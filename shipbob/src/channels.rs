@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Channels {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Channels {
         Channels { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Channels {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get user-authorized channel info.
      *
@@ -19,7 +25,10 @@ impl Channels {
      */
     pub async fn get_page(&self) -> Result<Vec<crate::types::Channel>> {
         let url = "/channel".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/channel", &[]))
     }
 
     /**
@@ -31,6 +40,30 @@ impl Channels {
      */
     pub async fn get_all(&self) -> Result<Vec<crate::types::Channel>> {
         let url = "/channel".to_string();
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/channel", &[]))
+    }
+}
+
+/// The functions of [`Channels`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ChannelsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ChannelsApi: Send + Sync {
+    async fn get_page(&self) -> Result<Vec<crate::types::Channel>>;
+    async fn get_all(&self) -> Result<Vec<crate::types::Channel>>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ChannelsApi for Channels {
+    async fn get_page(&self) -> Result<Vec<crate::types::Channel>> {
+        Channels::get_page(self).await
+    }
+    async fn get_all(&self) -> Result<Vec<crate::types::Channel>> {
+        Channels::get_all(self).await
     }
 }
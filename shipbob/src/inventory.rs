@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Inventory {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Inventory {
         Inventory { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Inventory {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get an inventory item.
      *
@@ -24,10 +30,16 @@ impl Inventory {
     pub async fn get(&self, inventory_id: i64) -> Result<crate::types::Inventory> {
         let url = format!(
             "/inventory/{}",
-            crate::progenitor_support::encode_path(&inventory_id.to_string()),
+            self.client.encode_path(&inventory_id.to_string()),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/inventory/{inventoryId}",
+                &[("inventoryId", inventory_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -61,32 +73,34 @@ impl Inventory {
         sort: &str,
         search: &str,
     ) -> Result<Vec<crate::types::Inventory>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !ids.is_empty() {
-            query_args.push(("IDs".to_string(), ids.join(" ")));
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        for v in ids {
+            query_args.push(("IDs", v.to_string()));
         }
         if is_active {
-            query_args.push(("IsActive".to_string(), is_active.to_string()));
+            query_args.push(("IsActive", is_active.to_string()));
         }
         if is_digital {
-            query_args.push(("IsDigital".to_string(), is_digital.to_string()));
+            query_args.push(("IsDigital", is_digital.to_string()));
         }
         if limit > 0 {
-            query_args.push(("Limit".to_string(), limit.to_string()));
+            query_args.push(("Limit", limit.to_string()));
         }
         if page > 0 {
-            query_args.push(("Page".to_string(), page.to_string()));
+            query_args.push(("Page", page.to_string()));
         }
         if !search.is_empty() {
-            query_args.push(("Search".to_string(), search.to_string()));
+            query_args.push(("Search", search.to_string()));
         }
         if !sort.is_empty() {
-            query_args.push(("Sort".to_string(), sort.to_string()));
+            query_args.push(("Sort", sort.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/inventory?{}", query_);
+        let url = crate::Client::url_with_query("/inventory", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/inventory", &[]))
     }
 
     /**
@@ -104,26 +118,28 @@ impl Inventory {
         sort: &str,
         search: &str,
     ) -> Result<Vec<crate::types::Inventory>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !ids.is_empty() {
-            query_args.push(("IDs".to_string(), ids.join(" ")));
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        for v in ids {
+            query_args.push(("IDs", v.to_string()));
         }
         if is_active {
-            query_args.push(("IsActive".to_string(), is_active.to_string()));
+            query_args.push(("IsActive", is_active.to_string()));
         }
         if is_digital {
-            query_args.push(("IsDigital".to_string(), is_digital.to_string()));
+            query_args.push(("IsDigital", is_digital.to_string()));
         }
         if !search.is_empty() {
-            query_args.push(("Search".to_string(), search.to_string()));
+            query_args.push(("Search", search.to_string()));
         }
         if !sort.is_empty() {
-            query_args.push(("Sort".to_string(), sort.to_string()));
+            query_args.push(("Sort", sort.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/inventory?{}", query_);
+        let url = crate::Client::url_with_query("/inventory", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/inventory", &[]))
     }
 
     /**
@@ -139,10 +155,16 @@ impl Inventory {
     pub async fn get_product(&self, product_id: i64) -> Result<Vec<crate::types::Inventory>> {
         let url = format!(
             "/product/{}/inventory",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
+            self.client.encode_path(&product_id.to_string()),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/product/{productId}/inventory",
+                &[("productId", product_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -155,9 +177,83 @@ impl Inventory {
     pub async fn get_all_product(&self, product_id: i64) -> Result<Vec<crate::types::Inventory>> {
         let url = format!(
             "/product/{}/inventory",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
+            self.client.encode_path(&product_id.to_string()),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/product/{productId}/inventory",
+                    &[("productId", product_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`Inventory`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn InventoryApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait InventoryApi: Send + Sync {
+    async fn get(&self, inventory_id: i64) -> Result<crate::types::Inventory>;
+    async fn get_page(
+        &self,
+        page: i64,
+        limit: i64,
+        is_active: bool,
+        is_digital: bool,
+        ids: &[String],
+        sort: &str,
+        search: &str,
+    ) -> Result<Vec<crate::types::Inventory>>;
+    async fn get_all(
+        &self,
+        is_active: bool,
+        is_digital: bool,
+        ids: &[String],
+        sort: &str,
+        search: &str,
+    ) -> Result<Vec<crate::types::Inventory>>;
+    async fn get_product(&self, product_id: i64) -> Result<Vec<crate::types::Inventory>>;
+    async fn get_all_product(&self, product_id: i64) -> Result<Vec<crate::types::Inventory>>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl InventoryApi for Inventory {
+    async fn get(&self, inventory_id: i64) -> Result<crate::types::Inventory> {
+        Inventory::get(self, inventory_id).await
+    }
+    async fn get_page(
+        &self,
+        page: i64,
+        limit: i64,
+        is_active: bool,
+        is_digital: bool,
+        ids: &[String],
+        sort: &str,
+        search: &str,
+    ) -> Result<Vec<crate::types::Inventory>> {
+        Inventory::get_page(self, page, limit, is_active, is_digital, ids, sort, search).await
+    }
+    async fn get_all(
+        &self,
+        is_active: bool,
+        is_digital: bool,
+        ids: &[String],
+        sort: &str,
+        search: &str,
+    ) -> Result<Vec<crate::types::Inventory>> {
+        Inventory::get_all(self, is_active, is_digital, ids, sort, search).await
+    }
+    async fn get_product(&self, product_id: i64) -> Result<Vec<crate::types::Inventory>> {
+        Inventory::get_product(self, product_id).await
+    }
+    async fn get_all_product(&self, product_id: i64) -> Result<Vec<crate::types::Inventory>> {
+        Inventory::get_all_product(self, product_id).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Locations {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Locations {
         Locations { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Locations {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get locations.
      *
@@ -29,23 +35,22 @@ impl Locations {
         receiving_enabled: bool,
         access_granted: bool,
     ) -> Result<Vec<crate::types::IntegrationsLocationInternalAllOf>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if access_granted {
-            query_args.push(("AccessGranted".to_string(), access_granted.to_string()));
+            query_args.push(("AccessGranted", access_granted.to_string()));
         }
         if include_inactive {
-            query_args.push(("IncludeInactive".to_string(), include_inactive.to_string()));
+            query_args.push(("IncludeInactive", include_inactive.to_string()));
         }
         if receiving_enabled {
-            query_args.push((
-                "ReceivingEnabled".to_string(),
-                receiving_enabled.to_string(),
-            ));
+            query_args.push(("ReceivingEnabled", receiving_enabled.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/location?{}", query_);
+        let url = crate::Client::url_with_query("/location", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/location", &[]))
     }
 
     /**
@@ -61,22 +66,62 @@ impl Locations {
         receiving_enabled: bool,
         access_granted: bool,
     ) -> Result<Vec<crate::types::IntegrationsLocationInternalAllOf>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if access_granted {
-            query_args.push(("AccessGranted".to_string(), access_granted.to_string()));
+            query_args.push(("AccessGranted", access_granted.to_string()));
         }
         if include_inactive {
-            query_args.push(("IncludeInactive".to_string(), include_inactive.to_string()));
+            query_args.push(("IncludeInactive", include_inactive.to_string()));
         }
         if receiving_enabled {
-            query_args.push((
-                "ReceivingEnabled".to_string(),
-                receiving_enabled.to_string(),
-            ));
+            query_args.push(("ReceivingEnabled", receiving_enabled.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/location?{}", query_);
+        let url = crate::Client::url_with_query("/location", &query_args);
+
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/location", &[]))
+    }
+}
+
+/// The functions of [`Locations`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn LocationsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait LocationsApi: Send + Sync {
+    async fn get_page(
+        &self,
+        include_inactive: bool,
+        receiving_enabled: bool,
+        access_granted: bool,
+    ) -> Result<Vec<crate::types::IntegrationsLocationInternalAllOf>>;
+    async fn get_all(
+        &self,
+        include_inactive: bool,
+        receiving_enabled: bool,
+        access_granted: bool,
+    ) -> Result<Vec<crate::types::IntegrationsLocationInternalAllOf>>;
+}
 
-        self.client.get_all_pages(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl LocationsApi for Locations {
+    async fn get_page(
+        &self,
+        include_inactive: bool,
+        receiving_enabled: bool,
+        access_granted: bool,
+    ) -> Result<Vec<crate::types::IntegrationsLocationInternalAllOf>> {
+        Locations::get_page(self, include_inactive, receiving_enabled, access_granted).await
+    }
+    async fn get_all(
+        &self,
+        include_inactive: bool,
+        receiving_enabled: bool,
+        access_granted: bool,
+    ) -> Result<Vec<crate::types::IntegrationsLocationInternalAllOf>> {
+        Locations::get_all(self, include_inactive, receiving_enabled, access_granted).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Orders {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Orders {
         Orders { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Orders {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Estimate Fulfillment Cost For Order.
      *
@@ -32,8 +38,9 @@ impl Orders {
     ) -> Result<crate::types::OrdersEstimate> {
         let url = "/order/estimate".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/order/estimate", &[]))
     }
 
     /**
@@ -47,12 +54,15 @@ impl Orders {
      * * `channel_id: i64` -- Unique id of the channel.
      */
     pub async fn get(&self, order_id: i64) -> Result<crate::types::Order> {
-        let url = format!(
-            "/order/{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-        );
+        let url = format!("/order/{}", self.client.encode_path(&order_id.to_string()),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/order/{orderId}",
+                &[("orderId", order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -91,47 +101,111 @@ impl Orders {
         last_update_end_date: Option<chrono::DateTime<chrono::Utc>>,
         is_tracking_uploaded: bool,
     ) -> Result<Vec<crate::types::Order>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if let Some(date) = end_date {
+            query_args.push(("EndDate", date.to_rfc3339()));
+        }
+        if has_tracking {
+            query_args.push(("HasTracking", has_tracking.to_string()));
+        }
+        for v in ids {
+            query_args.push(("IDs", v.to_string()));
+        }
+        if is_tracking_uploaded {
+            query_args.push(("IsTrackingUploaded", is_tracking_uploaded.to_string()));
+        }
+        if let Some(date) = last_update_end_date {
+            query_args.push(("LastUpdateEndDate", date.to_rfc3339()));
+        }
+        if let Some(date) = last_update_start_date {
+            query_args.push(("LastUpdateStartDate", date.to_rfc3339()));
+        }
+        if limit > 0 {
+            query_args.push(("Limit", limit.to_string()));
+        }
+        if page > 0 {
+            query_args.push(("Page", page.to_string()));
+        }
+        for v in reference_ids {
+            query_args.push(("ReferenceIds", v.to_string()));
+        }
+        if !sort_order.to_string().is_empty() {
+            query_args.push(("SortOrder", sort_order.to_string()));
+        }
+        if let Some(date) = start_date {
+            query_args.push(("StartDate", date.to_rfc3339()));
+        }
+        let url = crate::Client::url_with_query("/order", &query_args);
+
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/order", &[]))
+    }
+
+    /**
+     * Get Orders.
+     *
+     * This function performs a `GET` to the `/order` endpoint.
+     *
+     * As opposed to `get_page`, this function streams the items as they are parsed from the response, rather than returning them all at once.
+     * This is useful for very large responses that would otherwise need to be held in memory.
+     * Only the one page the parameters ask for is streamed, the next pages are not fetched.
+     */
+    pub async fn get_page_stream_json(
+        &self,
+        page: i64,
+        limit: i64,
+        ids: &[String],
+        reference_ids: &[String],
+        start_date: Option<chrono::DateTime<chrono::Utc>>,
+        end_date: Option<chrono::DateTime<chrono::Utc>>,
+        sort_order: crate::types::SortOrder,
+        has_tracking: bool,
+        last_update_start_date: Option<chrono::DateTime<chrono::Utc>>,
+        last_update_end_date: Option<chrono::DateTime<chrono::Utc>>,
+        is_tracking_uploaded: bool,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::Order>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if let Some(date) = end_date {
-            query_args.push(("EndDate".to_string(), date.to_rfc3339()));
+            query_args.push(("EndDate", date.to_rfc3339()));
         }
         if has_tracking {
-            query_args.push(("HasTracking".to_string(), has_tracking.to_string()));
+            query_args.push(("HasTracking", has_tracking.to_string()));
         }
-        if !ids.is_empty() {
-            query_args.push(("IDs".to_string(), ids.join(" ")));
+        for v in ids {
+            query_args.push(("IDs", v.to_string()));
         }
         if is_tracking_uploaded {
-            query_args.push((
-                "IsTrackingUploaded".to_string(),
-                is_tracking_uploaded.to_string(),
-            ));
+            query_args.push(("IsTrackingUploaded", is_tracking_uploaded.to_string()));
         }
         if let Some(date) = last_update_end_date {
-            query_args.push(("LastUpdateEndDate".to_string(), date.to_rfc3339()));
+            query_args.push(("LastUpdateEndDate", date.to_rfc3339()));
         }
         if let Some(date) = last_update_start_date {
-            query_args.push(("LastUpdateStartDate".to_string(), date.to_rfc3339()));
+            query_args.push(("LastUpdateStartDate", date.to_rfc3339()));
         }
         if limit > 0 {
-            query_args.push(("Limit".to_string(), limit.to_string()));
+            query_args.push(("Limit", limit.to_string()));
         }
         if page > 0 {
-            query_args.push(("Page".to_string(), page.to_string()));
+            query_args.push(("Page", page.to_string()));
         }
-        if !reference_ids.is_empty() {
-            query_args.push(("ReferenceIds".to_string(), reference_ids.join(" ")));
+        for v in reference_ids {
+            query_args.push(("ReferenceIds", v.to_string()));
         }
         if !sort_order.to_string().is_empty() {
-            query_args.push(("SortOrder".to_string(), sort_order.to_string()));
+            query_args.push(("SortOrder", sort_order.to_string()));
         }
         if let Some(date) = start_date {
-            query_args.push(("StartDate".to_string(), date.to_rfc3339()));
+            query_args.push(("StartDate", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/order?{}", query_);
+        let url = crate::Client::url_with_query("/order", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get_stream_json(&url, "")
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/order", &[]))
     }
 
     /**
@@ -155,41 +229,40 @@ impl Orders {
         last_update_end_date: Option<chrono::DateTime<chrono::Utc>>,
         is_tracking_uploaded: bool,
     ) -> Result<Vec<crate::types::Order>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if let Some(date) = end_date {
-            query_args.push(("EndDate".to_string(), date.to_rfc3339()));
+            query_args.push(("EndDate", date.to_rfc3339()));
         }
         if has_tracking {
-            query_args.push(("HasTracking".to_string(), has_tracking.to_string()));
+            query_args.push(("HasTracking", has_tracking.to_string()));
         }
-        if !ids.is_empty() {
-            query_args.push(("IDs".to_string(), ids.join(" ")));
+        for v in ids {
+            query_args.push(("IDs", v.to_string()));
         }
         if is_tracking_uploaded {
-            query_args.push((
-                "IsTrackingUploaded".to_string(),
-                is_tracking_uploaded.to_string(),
-            ));
+            query_args.push(("IsTrackingUploaded", is_tracking_uploaded.to_string()));
         }
         if let Some(date) = last_update_end_date {
-            query_args.push(("LastUpdateEndDate".to_string(), date.to_rfc3339()));
+            query_args.push(("LastUpdateEndDate", date.to_rfc3339()));
         }
         if let Some(date) = last_update_start_date {
-            query_args.push(("LastUpdateStartDate".to_string(), date.to_rfc3339()));
+            query_args.push(("LastUpdateStartDate", date.to_rfc3339()));
         }
-        if !reference_ids.is_empty() {
-            query_args.push(("ReferenceIds".to_string(), reference_ids.join(" ")));
+        for v in reference_ids {
+            query_args.push(("ReferenceIds", v.to_string()));
         }
         if !sort_order.to_string().is_empty() {
-            query_args.push(("SortOrder".to_string(), sort_order.to_string()));
+            query_args.push(("SortOrder", sort_order.to_string()));
         }
         if let Some(date) = start_date {
-            query_args.push(("StartDate".to_string(), date.to_rfc3339()));
+            query_args.push(("StartDate", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/order?{}", query_);
+        let url = crate::Client::url_with_query("/order", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/order", &[]))
     }
 
     /**
@@ -207,8 +280,9 @@ impl Orders {
     ) -> Result<crate::types::Order> {
         let url = "/order".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/order", &[]))
     }
 
     /**
@@ -224,10 +298,16 @@ impl Orders {
     pub async fn post_cancel(&self, order_id: i64) -> Result<crate::types::OrdersCanceledOrder> {
         let url = format!(
             "/order/{}/cancel",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            self.client.encode_path(&order_id.to_string()),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/order/{orderId}/cancel",
+                &[("orderId", order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -242,10 +322,16 @@ impl Orders {
     pub async fn get_store_json(&self, order_id: i64) -> Result<String> {
         let url = format!(
             "/order/{}/storeOrderJson",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            self.client.encode_path(&order_id.to_string()),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/order/{orderId}/storeOrderJson",
+                &[("orderId", order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -264,12 +350,19 @@ impl Orders {
     ) -> Result<String> {
         let url = format!(
             "/order/{}/storeOrderJson",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            self.client.encode_path(&order_id.to_string()),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/order/{orderId}/storeOrderJson",
+                    &[("orderId", order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -290,11 +383,20 @@ impl Orders {
     ) -> Result<crate::types::OrdersShipment> {
         let url = format!(
             "/order/{}/shipment/{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&shipment_id.to_string()),
+            self.client.encode_path(&order_id.to_string()),
+            self.client.encode_path(&shipment_id.to_string()),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/order/{orderId}/shipment/{shipmentId}",
+                &[
+                    ("orderId", order_id.to_string()),
+                    ("shipmentId", shipment_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -315,11 +417,20 @@ impl Orders {
     ) -> Result<crate::types::OrdersShipment> {
         let url = format!(
             "/order/{}/shipment/{}/cancel",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&shipment_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(&shipment_id.to_string()),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/order/{orderId}/shipment/{shipmentId}/cancel",
+                &[
+                    ("orderId", order_id.to_string()),
+                    ("shipmentId", shipment_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -340,11 +451,20 @@ impl Orders {
     ) -> Result<Vec<crate::types::OrdersShipmentLog>> {
         let url = format!(
             "/order/{}/shipment/{}/timeline",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&shipment_id.to_string()),
+            self.client.encode_path(&order_id.to_string()),
+            self.client.encode_path(&shipment_id.to_string()),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/order/{orderId}/shipment/{shipmentId}/timeline",
+                &[
+                    ("orderId", order_id.to_string()),
+                    ("shipmentId", shipment_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -361,11 +481,23 @@ impl Orders {
     ) -> Result<Vec<crate::types::OrdersShipmentLog>> {
         let url = format!(
             "/order/{}/shipment/{}/timeline",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&shipment_id.to_string()),
+            self.client.encode_path(&order_id.to_string()),
+            self.client.encode_path(&shipment_id.to_string()),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/order/{orderId}/shipment/{shipmentId}/timeline",
+                    &[
+                        ("orderId", order_id.to_string()),
+                        ("shipmentId", shipment_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -381,10 +513,16 @@ impl Orders {
     pub async fn get_shipments(&self, order_id: i64) -> Result<Vec<crate::types::OrdersShipment>> {
         let url = format!(
             "/order/{}/shipment",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            self.client.encode_path(&order_id.to_string()),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/order/{orderId}/shipment",
+                &[("orderId", order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -400,10 +538,19 @@ impl Orders {
     ) -> Result<Vec<crate::types::OrdersShipment>> {
         let url = format!(
             "/order/{}/shipment",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            self.client.encode_path(&order_id.to_string()),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/order/{orderId}/shipment",
+                    &[("orderId", order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -424,11 +571,20 @@ impl Orders {
     ) -> Result<Vec<crate::types::OrdersShipmentLog>> {
         let url = format!(
             "/order/{}/shipment/{}/logs",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&shipment_id.to_string()),
+            self.client.encode_path(&order_id.to_string()),
+            self.client.encode_path(&shipment_id.to_string()),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/order/{orderId}/shipment/{shipmentId}/logs",
+                &[
+                    ("orderId", order_id.to_string()),
+                    ("shipmentId", shipment_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -445,11 +601,23 @@ impl Orders {
     ) -> Result<Vec<crate::types::OrdersShipmentLog>> {
         let url = format!(
             "/order/{}/shipment/{}/logs",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&shipment_id.to_string()),
+            self.client.encode_path(&order_id.to_string()),
+            self.client.encode_path(&shipment_id.to_string()),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/order/{orderId}/shipment/{shipmentId}/logs",
+                    &[
+                        ("orderId", order_id.to_string()),
+                        ("shipmentId", shipment_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -468,10 +636,16 @@ impl Orders {
     ) -> Result<crate::types::OrdersShipment> {
         let url = format!(
             "/shipment/{}",
-            crate::progenitor_support::encode_path(&shipment_id.to_string()),
+            self.client.encode_path(&shipment_id.to_string()),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/shipment/{shipmentId}",
+                &[("shipmentId", shipment_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -490,10 +664,16 @@ impl Orders {
     ) -> Result<crate::types::OrdersShipment> {
         let url = format!(
             "/shipment/{}/cancel",
-            crate::progenitor_support::encode_path(&shipment_id.to_string()),
+            self.client.encode_path(&shipment_id.to_string()),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/shipment/{shipmentId}/cancel",
+                &[("shipmentId", shipment_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -511,8 +691,9 @@ impl Orders {
     ) -> Result<crate::types::OrdersCanceledShipments> {
         let url = "/shipment/cancelbulk".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/shipment/cancelbulk", &[]))
     }
 
     /**
@@ -531,10 +712,16 @@ impl Orders {
     ) -> Result<Vec<crate::types::OrdersShipmentLog>> {
         let url = format!(
             "/shipment/{}/timeline",
-            crate::progenitor_support::encode_path(&shipment_id.to_string()),
+            self.client.encode_path(&shipment_id.to_string()),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/shipment/{shipmentId}/timeline",
+                &[("shipmentId", shipment_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -550,10 +737,19 @@ impl Orders {
     ) -> Result<Vec<crate::types::OrdersShipmentLog>> {
         let url = format!(
             "/shipment/{}/timeline",
-            crate::progenitor_support::encode_path(&shipment_id.to_string()),
+            self.client.encode_path(&shipment_id.to_string()),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/shipment/{shipmentId}/timeline",
+                    &[("shipmentId", shipment_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -572,10 +768,16 @@ impl Orders {
     ) -> Result<Vec<crate::types::OrdersShipmentLog>> {
         let url = format!(
             "/shipment/{}/logs",
-            crate::progenitor_support::encode_path(&shipment_id.to_string()),
+            self.client.encode_path(&shipment_id.to_string()),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/shipment/{shipmentId}/logs",
+                &[("shipmentId", shipment_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -591,10 +793,19 @@ impl Orders {
     ) -> Result<Vec<crate::types::OrdersShipmentLog>> {
         let url = format!(
             "/shipment/{}/logs",
-            crate::progenitor_support::encode_path(&shipment_id.to_string()),
+            self.client.encode_path(&shipment_id.to_string()),
         );
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/shipment/{shipmentId}/logs",
+                    &[("shipmentId", shipment_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -614,17 +825,19 @@ impl Orders {
         page: i64,
         limit: i64,
     ) -> Result<Vec<crate::types::OrdersShipMethodDetail>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if limit > 0 {
-            query_args.push(("Limit".to_string(), limit.to_string()));
+            query_args.push(("Limit", limit.to_string()));
         }
         if page > 0 {
-            query_args.push(("Page".to_string(), page.to_string()));
+            query_args.push(("Page", page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/shippingmethod?{}", query_);
+        let url = crate::Client::url_with_query("/shippingmethod", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/shippingmethod", &[]))
     }
 
     /**
@@ -640,6 +853,308 @@ impl Orders {
         &self,
     ) -> Result<Vec<crate::types::OrdersShipMethodDetail>> {
         let url = "/shippingmethod".to_string();
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/shippingmethod", &[]))
+    }
+}
+
+/// The functions of [`Orders`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn OrdersApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait OrdersApi: Send + Sync {
+    async fn post_estimate(
+        &self,
+        body: &crate::types::OrdersEstimateFulfillmentRequestModel,
+    ) -> Result<crate::types::OrdersEstimate>;
+    async fn get(&self, order_id: i64) -> Result<crate::types::Order>;
+    async fn get_page(
+        &self,
+        page: i64,
+        limit: i64,
+        ids: &[String],
+        reference_ids: &[String],
+        start_date: Option<chrono::DateTime<chrono::Utc>>,
+        end_date: Option<chrono::DateTime<chrono::Utc>>,
+        sort_order: crate::types::SortOrder,
+        has_tracking: bool,
+        last_update_start_date: Option<chrono::DateTime<chrono::Utc>>,
+        last_update_end_date: Option<chrono::DateTime<chrono::Utc>>,
+        is_tracking_uploaded: bool,
+    ) -> Result<Vec<crate::types::Order>>;
+    async fn get_all(
+        &self,
+        ids: &[String],
+        reference_ids: &[String],
+        start_date: Option<chrono::DateTime<chrono::Utc>>,
+        end_date: Option<chrono::DateTime<chrono::Utc>>,
+        sort_order: crate::types::SortOrder,
+        has_tracking: bool,
+        last_update_start_date: Option<chrono::DateTime<chrono::Utc>>,
+        last_update_end_date: Option<chrono::DateTime<chrono::Utc>>,
+        is_tracking_uploaded: bool,
+    ) -> Result<Vec<crate::types::Order>>;
+    async fn post(
+        &self,
+        body: &crate::types::OrdersCreateOrderModel,
+    ) -> Result<crate::types::Order>;
+    async fn post_cancel(&self, order_id: i64) -> Result<crate::types::OrdersCanceledOrder>;
+    async fn get_store_json(&self, order_id: i64) -> Result<String>;
+    async fn post_store_json(
+        &self,
+        order_id: i64,
+        body: &crate::types::OrdersAddStoreOrderJsonModel,
+    ) -> Result<String>;
+    async fn get_shipment(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+    ) -> Result<crate::types::OrdersShipment>;
+    async fn post_shipment_cancel(
+        &self,
+        shipment_id: i64,
+        order_id: &str,
+    ) -> Result<crate::types::OrdersShipment>;
+    async fn get_shipment_timeline(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+    ) -> Result<Vec<crate::types::OrdersShipmentLog>>;
+    async fn get_all_shipment_timeline(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+    ) -> Result<Vec<crate::types::OrdersShipmentLog>>;
+    async fn get_shipments(&self, order_id: i64) -> Result<Vec<crate::types::OrdersShipment>>;
+    async fn get_all_shipments(&self, order_id: i64) -> Result<Vec<crate::types::OrdersShipment>>;
+    async fn get_shipment_logs(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+    ) -> Result<Vec<crate::types::OrdersShipmentLog>>;
+    async fn get_all_shipment_logs(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+    ) -> Result<Vec<crate::types::OrdersShipmentLog>>;
+    async fn get_shipment_orders(&self, shipment_id: i64) -> Result<crate::types::OrdersShipment>;
+    async fn post_shipment_cancel_orders(
+        &self,
+        shipment_id: i64,
+    ) -> Result<crate::types::OrdersShipment>;
+    async fn post_shipment_cancel_bulk(
+        &self,
+        body: &crate::types::OrdersCancelShipmentsModel,
+    ) -> Result<crate::types::OrdersCanceledShipments>;
+    async fn get_shipment_timeline_orders(
+        &self,
+        shipment_id: i64,
+    ) -> Result<Vec<crate::types::OrdersShipmentLog>>;
+    async fn get_all_shipment_timeline_all(
+        &self,
+        shipment_id: i64,
+    ) -> Result<Vec<crate::types::OrdersShipmentLog>>;
+    async fn get_shipment_logs_orders(
+        &self,
+        shipment_id: i64,
+    ) -> Result<Vec<crate::types::OrdersShipmentLog>>;
+    async fn get_all_shipment_logs_all(
+        &self,
+        shipment_id: i64,
+    ) -> Result<Vec<crate::types::OrdersShipmentLog>>;
+    async fn get_shipping_method(
+        &self,
+        page: i64,
+        limit: i64,
+    ) -> Result<Vec<crate::types::OrdersShipMethodDetail>>;
+    async fn get_all_shipping_method(&self) -> Result<Vec<crate::types::OrdersShipMethodDetail>>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl OrdersApi for Orders {
+    async fn post_estimate(
+        &self,
+        body: &crate::types::OrdersEstimateFulfillmentRequestModel,
+    ) -> Result<crate::types::OrdersEstimate> {
+        Orders::post_estimate(self, body).await
+    }
+    async fn get(&self, order_id: i64) -> Result<crate::types::Order> {
+        Orders::get(self, order_id).await
+    }
+    async fn get_page(
+        &self,
+        page: i64,
+        limit: i64,
+        ids: &[String],
+        reference_ids: &[String],
+        start_date: Option<chrono::DateTime<chrono::Utc>>,
+        end_date: Option<chrono::DateTime<chrono::Utc>>,
+        sort_order: crate::types::SortOrder,
+        has_tracking: bool,
+        last_update_start_date: Option<chrono::DateTime<chrono::Utc>>,
+        last_update_end_date: Option<chrono::DateTime<chrono::Utc>>,
+        is_tracking_uploaded: bool,
+    ) -> Result<Vec<crate::types::Order>> {
+        Orders::get_page(
+            self,
+            page,
+            limit,
+            ids,
+            reference_ids,
+            start_date,
+            end_date,
+            sort_order,
+            has_tracking,
+            last_update_start_date,
+            last_update_end_date,
+            is_tracking_uploaded,
+        )
+        .await
+    }
+    async fn get_all(
+        &self,
+        ids: &[String],
+        reference_ids: &[String],
+        start_date: Option<chrono::DateTime<chrono::Utc>>,
+        end_date: Option<chrono::DateTime<chrono::Utc>>,
+        sort_order: crate::types::SortOrder,
+        has_tracking: bool,
+        last_update_start_date: Option<chrono::DateTime<chrono::Utc>>,
+        last_update_end_date: Option<chrono::DateTime<chrono::Utc>>,
+        is_tracking_uploaded: bool,
+    ) -> Result<Vec<crate::types::Order>> {
+        Orders::get_all(
+            self,
+            ids,
+            reference_ids,
+            start_date,
+            end_date,
+            sort_order,
+            has_tracking,
+            last_update_start_date,
+            last_update_end_date,
+            is_tracking_uploaded,
+        )
+        .await
+    }
+    async fn post(
+        &self,
+        body: &crate::types::OrdersCreateOrderModel,
+    ) -> Result<crate::types::Order> {
+        Orders::post(self, body).await
+    }
+    async fn post_cancel(&self, order_id: i64) -> Result<crate::types::OrdersCanceledOrder> {
+        Orders::post_cancel(self, order_id).await
+    }
+    async fn get_store_json(&self, order_id: i64) -> Result<String> {
+        Orders::get_store_json(self, order_id).await
+    }
+    async fn post_store_json(
+        &self,
+        order_id: i64,
+        body: &crate::types::OrdersAddStoreOrderJsonModel,
+    ) -> Result<String> {
+        Orders::post_store_json(self, order_id, body).await
+    }
+    async fn get_shipment(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+    ) -> Result<crate::types::OrdersShipment> {
+        Orders::get_shipment(self, order_id, shipment_id).await
+    }
+    async fn post_shipment_cancel(
+        &self,
+        shipment_id: i64,
+        order_id: &str,
+    ) -> Result<crate::types::OrdersShipment> {
+        Orders::post_shipment_cancel(self, shipment_id, order_id).await
+    }
+    async fn get_shipment_timeline(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+    ) -> Result<Vec<crate::types::OrdersShipmentLog>> {
+        Orders::get_shipment_timeline(self, order_id, shipment_id).await
+    }
+    async fn get_all_shipment_timeline(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+    ) -> Result<Vec<crate::types::OrdersShipmentLog>> {
+        Orders::get_all_shipment_timeline(self, order_id, shipment_id).await
+    }
+    async fn get_shipments(&self, order_id: i64) -> Result<Vec<crate::types::OrdersShipment>> {
+        Orders::get_shipments(self, order_id).await
+    }
+    async fn get_all_shipments(&self, order_id: i64) -> Result<Vec<crate::types::OrdersShipment>> {
+        Orders::get_all_shipments(self, order_id).await
+    }
+    async fn get_shipment_logs(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+    ) -> Result<Vec<crate::types::OrdersShipmentLog>> {
+        Orders::get_shipment_logs(self, order_id, shipment_id).await
+    }
+    async fn get_all_shipment_logs(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+    ) -> Result<Vec<crate::types::OrdersShipmentLog>> {
+        Orders::get_all_shipment_logs(self, order_id, shipment_id).await
+    }
+    async fn get_shipment_orders(&self, shipment_id: i64) -> Result<crate::types::OrdersShipment> {
+        Orders::get_shipment_orders(self, shipment_id).await
+    }
+    async fn post_shipment_cancel_orders(
+        &self,
+        shipment_id: i64,
+    ) -> Result<crate::types::OrdersShipment> {
+        Orders::post_shipment_cancel_orders(self, shipment_id).await
+    }
+    async fn post_shipment_cancel_bulk(
+        &self,
+        body: &crate::types::OrdersCancelShipmentsModel,
+    ) -> Result<crate::types::OrdersCanceledShipments> {
+        Orders::post_shipment_cancel_bulk(self, body).await
+    }
+    async fn get_shipment_timeline_orders(
+        &self,
+        shipment_id: i64,
+    ) -> Result<Vec<crate::types::OrdersShipmentLog>> {
+        Orders::get_shipment_timeline_orders(self, shipment_id).await
+    }
+    async fn get_all_shipment_timeline_all(
+        &self,
+        shipment_id: i64,
+    ) -> Result<Vec<crate::types::OrdersShipmentLog>> {
+        Orders::get_all_shipment_timeline_all(self, shipment_id).await
+    }
+    async fn get_shipment_logs_orders(
+        &self,
+        shipment_id: i64,
+    ) -> Result<Vec<crate::types::OrdersShipmentLog>> {
+        Orders::get_shipment_logs_orders(self, shipment_id).await
+    }
+    async fn get_all_shipment_logs_all(
+        &self,
+        shipment_id: i64,
+    ) -> Result<Vec<crate::types::OrdersShipmentLog>> {
+        Orders::get_all_shipment_logs_all(self, shipment_id).await
+    }
+    async fn get_shipping_method(
+        &self,
+        page: i64,
+        limit: i64,
+    ) -> Result<Vec<crate::types::OrdersShipMethodDetail>> {
+        Orders::get_shipping_method(self, page, limit).await
+    }
+    async fn get_all_shipping_method(&self) -> Result<Vec<crate::types::OrdersShipMethodDetail>> {
+        Orders::get_all_shipping_method(self).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Products {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Products {
         Products { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Products {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get multiple products.
      *
@@ -47,32 +53,34 @@ impl Products {
         active_status: crate::types::ProductActiveStatus,
         bundle_status: crate::types::ProductBundleStatus,
     ) -> Result<Vec<crate::types::Product>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !active_status.to_string().is_empty() {
-            query_args.push(("ActiveStatus".to_string(), active_status.to_string()));
+            query_args.push(("ActiveStatus", active_status.to_string()));
         }
         if !bundle_status.to_string().is_empty() {
-            query_args.push(("BundleStatus".to_string(), bundle_status.to_string()));
+            query_args.push(("BundleStatus", bundle_status.to_string()));
         }
-        if !ids.is_empty() {
-            query_args.push(("IDs".to_string(), ids.join(" ")));
+        for v in ids {
+            query_args.push(("IDs", v.to_string()));
         }
         if limit > 0 {
-            query_args.push(("Limit".to_string(), limit.to_string()));
+            query_args.push(("Limit", limit.to_string()));
         }
         if page > 0 {
-            query_args.push(("Page".to_string(), page.to_string()));
+            query_args.push(("Page", page.to_string()));
         }
-        if !reference_ids.is_empty() {
-            query_args.push(("ReferenceIds".to_string(), reference_ids.join(" ")));
+        for v in reference_ids {
+            query_args.push(("ReferenceIds", v.to_string()));
         }
         if !search.is_empty() {
-            query_args.push(("Search".to_string(), search.to_string()));
+            query_args.push(("Search", search.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/product?{}", query_);
+        let url = crate::Client::url_with_query("/product", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/product", &[]))
     }
 
     /**
@@ -90,26 +98,28 @@ impl Products {
         active_status: crate::types::ProductActiveStatus,
         bundle_status: crate::types::ProductBundleStatus,
     ) -> Result<Vec<crate::types::Product>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !active_status.to_string().is_empty() {
-            query_args.push(("ActiveStatus".to_string(), active_status.to_string()));
+            query_args.push(("ActiveStatus", active_status.to_string()));
         }
         if !bundle_status.to_string().is_empty() {
-            query_args.push(("BundleStatus".to_string(), bundle_status.to_string()));
+            query_args.push(("BundleStatus", bundle_status.to_string()));
         }
-        if !ids.is_empty() {
-            query_args.push(("IDs".to_string(), ids.join(" ")));
+        for v in ids {
+            query_args.push(("IDs", v.to_string()));
         }
-        if !reference_ids.is_empty() {
-            query_args.push(("ReferenceIds".to_string(), reference_ids.join(" ")));
+        for v in reference_ids {
+            query_args.push(("ReferenceIds", v.to_string()));
         }
         if !search.is_empty() {
-            query_args.push(("Search".to_string(), search.to_string()));
+            query_args.push(("Search", search.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/product?{}", query_);
+        let url = crate::Client::url_with_query("/product", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/product", &[]))
     }
 
     /**
@@ -127,8 +137,9 @@ impl Products {
     ) -> Result<Vec<crate::types::Product>> {
         let url = "/product".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/product", &[]))
     }
 
     /**
@@ -144,10 +155,16 @@ impl Products {
     pub async fn get(&self, product_id: i64) -> Result<crate::types::Product> {
         let url = format!(
             "/product/{}",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
+            self.client.encode_path(&product_id.to_string()),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/product/{productId}",
+                &[("productId", product_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -167,12 +184,19 @@ impl Products {
     ) -> Result<Vec<crate::types::Product>> {
         let url = format!(
             "/product/{}",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
+            self.client.encode_path(&product_id.to_string()),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/product/{productId}",
+                    &[("productId", product_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -190,7 +214,115 @@ impl Products {
     ) -> Result<Vec<crate::types::Product>> {
         let url = "/product/batch".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/product/batch", &[]))
+    }
+}
+
+/// The functions of [`Products`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ProductsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ProductsApi: Send + Sync {
+    async fn get_page(
+        &self,
+        page: i64,
+        limit: i64,
+        ids: &[String],
+        reference_ids: &[String],
+        search: &str,
+        active_status: crate::types::ProductActiveStatus,
+        bundle_status: crate::types::ProductBundleStatus,
+    ) -> Result<Vec<crate::types::Product>>;
+    async fn get_all(
+        &self,
+        ids: &[String],
+        reference_ids: &[String],
+        search: &str,
+        active_status: crate::types::ProductActiveStatus,
+        bundle_status: crate::types::ProductBundleStatus,
+    ) -> Result<Vec<crate::types::Product>>;
+    async fn post(
+        &self,
+        body: &crate::types::ProductsCreateProductModel,
+    ) -> Result<Vec<crate::types::Product>>;
+    async fn get(&self, product_id: i64) -> Result<crate::types::Product>;
+    async fn put(
+        &self,
+        product_id: i64,
+        body: &crate::types::ProductsUpdateProductModel,
+    ) -> Result<Vec<crate::types::Product>>;
+    async fn post_batch(
+        &self,
+        body: &[crate::types::ProductsCreateProductModel],
+    ) -> Result<Vec<crate::types::Product>>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ProductsApi for Products {
+    async fn get_page(
+        &self,
+        page: i64,
+        limit: i64,
+        ids: &[String],
+        reference_ids: &[String],
+        search: &str,
+        active_status: crate::types::ProductActiveStatus,
+        bundle_status: crate::types::ProductBundleStatus,
+    ) -> Result<Vec<crate::types::Product>> {
+        Products::get_page(
+            self,
+            page,
+            limit,
+            ids,
+            reference_ids,
+            search,
+            active_status,
+            bundle_status,
+        )
+        .await
+    }
+    async fn get_all(
+        &self,
+        ids: &[String],
+        reference_ids: &[String],
+        search: &str,
+        active_status: crate::types::ProductActiveStatus,
+        bundle_status: crate::types::ProductBundleStatus,
+    ) -> Result<Vec<crate::types::Product>> {
+        Products::get_all(
+            self,
+            ids,
+            reference_ids,
+            search,
+            active_status,
+            bundle_status,
+        )
+        .await
+    }
+    async fn post(
+        &self,
+        body: &crate::types::ProductsCreateProductModel,
+    ) -> Result<Vec<crate::types::Product>> {
+        Products::post(self, body).await
+    }
+    async fn get(&self, product_id: i64) -> Result<crate::types::Product> {
+        Products::get(self, product_id).await
+    }
+    async fn put(
+        &self,
+        product_id: i64,
+        body: &crate::types::ProductsUpdateProductModel,
+    ) -> Result<Vec<crate::types::Product>> {
+        Products::put(self, product_id, body).await
+    }
+    async fn post_batch(
+        &self,
+        body: &[crate::types::ProductsCreateProductModel],
+    ) -> Result<Vec<crate::types::Product>> {
+        Products::post_batch(self, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Receiving {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Receiving {
         Receiving { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Receiving {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get Fulfillment Centers.
      *
@@ -21,7 +27,10 @@ impl Receiving {
         &self,
     ) -> Result<Vec<crate::types::ReceivingFulfillmentCenter>> {
         let url = "/fulfillmentCenter".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/fulfillmentCenter", &[]))
     }
 
     /**
@@ -35,7 +44,10 @@ impl Receiving {
         &self,
     ) -> Result<Vec<crate::types::ReceivingFulfillmentCenter>> {
         let url = "/fulfillmentCenter".to_string();
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/fulfillmentCenter", &[]))
     }
 
     /**
@@ -48,12 +60,11 @@ impl Receiving {
      * * `id: i64` -- Unique id of the channel.
      */
     pub async fn get(&self, id: i64) -> Result<crate::types::ReceivingOrder> {
-        let url = format!(
-            "/receiving/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/receiving/{}", self.client.encode_path(&id.to_string()),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/receiving/{id}", &[("id", id.to_string())])
+        })
     }
 
     /**
@@ -68,10 +79,12 @@ impl Receiving {
     pub async fn get_label(&self, id: i64) -> Result<bytes::Bytes> {
         let url = format!(
             "/receiving/{}/labels",
-            crate::progenitor_support::encode_path(&id.to_string()),
+            self.client.encode_path(&id.to_string()),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/receiving/{id}/labels", &[("id", id.to_string())])
+        })
     }
 
     /**
@@ -85,8 +98,9 @@ impl Receiving {
     ) -> Result<crate::types::ReceivingOrder> {
         let url = "/receiving".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/receiving", &[]))
     }
 
     /**
@@ -101,9 +115,61 @@ impl Receiving {
     pub async fn post_cancel(&self, id: i64) -> Result<()> {
         let url = format!(
             "/receiving/{}/cancel",
-            crate::progenitor_support::encode_path(&id.to_string()),
+            self.client.encode_path(&id.to_string()),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("POST", "/receiving/{id}/cancel", &[("id", id.to_string())])
+        })
+    }
+}
+
+/// The functions of [`Receiving`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ReceivingApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ReceivingApi: Send + Sync {
+    async fn get_fulfillment_center(&self)
+        -> Result<Vec<crate::types::ReceivingFulfillmentCenter>>;
+    async fn get_all_fulfillment_center(
+        &self,
+    ) -> Result<Vec<crate::types::ReceivingFulfillmentCenter>>;
+    async fn get(&self, id: i64) -> Result<crate::types::ReceivingOrder>;
+    async fn get_label(&self, id: i64) -> Result<bytes::Bytes>;
+    async fn post(
+        &self,
+        body: &crate::types::ReceivingCreateOrderModel,
+    ) -> Result<crate::types::ReceivingOrder>;
+    async fn post_cancel(&self, id: i64) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ReceivingApi for Receiving {
+    async fn get_fulfillment_center(
+        &self,
+    ) -> Result<Vec<crate::types::ReceivingFulfillmentCenter>> {
+        Receiving::get_fulfillment_center(self).await
+    }
+    async fn get_all_fulfillment_center(
+        &self,
+    ) -> Result<Vec<crate::types::ReceivingFulfillmentCenter>> {
+        Receiving::get_all_fulfillment_center(self).await
+    }
+    async fn get(&self, id: i64) -> Result<crate::types::ReceivingOrder> {
+        Receiving::get(self, id).await
+    }
+    async fn get_label(&self, id: i64) -> Result<bytes::Bytes> {
+        Receiving::get_label(self, id).await
+    }
+    async fn post(
+        &self,
+        body: &crate::types::ReceivingCreateOrderModel,
+    ) -> Result<crate::types::ReceivingOrder> {
+        Receiving::post(self, body).await
+    }
+    async fn post_cancel(&self, id: i64) -> Result<()> {
+        Receiving::post_cancel(self, id).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Returns {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Returns {
         Returns { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Returns {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get Return Order.
      *
@@ -23,12 +29,11 @@ impl Returns {
      * * `channel_id: i64` -- Unique id of the channel.
      */
     pub async fn get(&self, id: i64) -> Result<crate::types::ReturnOrder> {
-        let url = format!(
-            "/return/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/return/{}", self.client.encode_path(&id.to_string()),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/return/{id}", &[("id", id.to_string())])
+        })
     }
 
     /**
@@ -46,14 +51,14 @@ impl Returns {
         id: i64,
         body: &crate::types::ReturnsCreateReturn,
     ) -> Result<crate::types::ReturnOrder> {
-        let url = format!(
-            "/return/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/return/{}", self.client.encode_path(&id.to_string()),);
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("PUT", "/return/{id}", &[("id", id.to_string())])
+            })
     }
 
     /**
@@ -92,53 +97,49 @@ impl Returns {
         original_shipment_ids: &[String],
         inventory_ids: &[String],
     ) -> Result<Vec<crate::types::ReturnOrder>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if let Some(date) = end_date {
-            query_args.push(("EndDate".to_string(), date.to_rfc3339()));
+            query_args.push(("EndDate", date.to_rfc3339()));
         }
-        if !fulfillment_center_ids.is_empty() {
-            query_args.push((
-                "FulfillmentCenterIds".to_string(),
-                fulfillment_center_ids.join(" "),
-            ));
+        for v in fulfillment_center_ids {
+            query_args.push(("FulfillmentCenterIds", v.to_string()));
         }
-        if !ids.is_empty() {
-            query_args.push(("IDs".to_string(), ids.join(" ")));
+        for v in ids {
+            query_args.push(("IDs", v.to_string()));
         }
-        if !inventory_ids.is_empty() {
-            query_args.push(("InventoryIds".to_string(), inventory_ids.join(" ")));
+        for v in inventory_ids {
+            query_args.push(("InventoryIds", v.to_string()));
         }
         if limit > 0 {
-            query_args.push(("Limit".to_string(), limit.to_string()));
+            query_args.push(("Limit", limit.to_string()));
         }
-        if !original_shipment_ids.is_empty() {
-            query_args.push((
-                "OriginalShipmentIds".to_string(),
-                original_shipment_ids.join(" "),
-            ));
+        for v in original_shipment_ids {
+            query_args.push(("OriginalShipmentIds", v.to_string()));
         }
         if page > 0 {
-            query_args.push(("Page".to_string(), page.to_string()));
+            query_args.push(("Page", page.to_string()));
         }
-        if !reference_ids.is_empty() {
-            query_args.push(("ReferenceIds".to_string(), reference_ids.join(" ")));
+        for v in reference_ids {
+            query_args.push(("ReferenceIds", v.to_string()));
         }
         if !sort_order.to_string().is_empty() {
-            query_args.push(("SortOrder".to_string(), sort_order.to_string()));
+            query_args.push(("SortOrder", sort_order.to_string()));
         }
         if let Some(date) = start_date {
-            query_args.push(("StartDate".to_string(), date.to_rfc3339()));
+            query_args.push(("StartDate", date.to_rfc3339()));
         }
-        if !status.is_empty() {
-            query_args.push(("Status".to_string(), status.join(" ")));
+        for v in status {
+            query_args.push(("Status", v.to_string()));
         }
-        if !tracking_numbers.is_empty() {
-            query_args.push(("TrackingNumbers".to_string(), tracking_numbers.join(" ")));
+        for v in tracking_numbers {
+            query_args.push(("TrackingNumbers", v.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/return?{}", query_);
+        let url = crate::Client::url_with_query("/return", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/return", &[]))
     }
 
     /**
@@ -161,47 +162,43 @@ impl Returns {
         original_shipment_ids: &[String],
         inventory_ids: &[String],
     ) -> Result<Vec<crate::types::ReturnOrder>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if let Some(date) = end_date {
-            query_args.push(("EndDate".to_string(), date.to_rfc3339()));
+            query_args.push(("EndDate", date.to_rfc3339()));
         }
-        if !fulfillment_center_ids.is_empty() {
-            query_args.push((
-                "FulfillmentCenterIds".to_string(),
-                fulfillment_center_ids.join(" "),
-            ));
+        for v in fulfillment_center_ids {
+            query_args.push(("FulfillmentCenterIds", v.to_string()));
         }
-        if !ids.is_empty() {
-            query_args.push(("IDs".to_string(), ids.join(" ")));
+        for v in ids {
+            query_args.push(("IDs", v.to_string()));
         }
-        if !inventory_ids.is_empty() {
-            query_args.push(("InventoryIds".to_string(), inventory_ids.join(" ")));
+        for v in inventory_ids {
+            query_args.push(("InventoryIds", v.to_string()));
         }
-        if !original_shipment_ids.is_empty() {
-            query_args.push((
-                "OriginalShipmentIds".to_string(),
-                original_shipment_ids.join(" "),
-            ));
+        for v in original_shipment_ids {
+            query_args.push(("OriginalShipmentIds", v.to_string()));
         }
-        if !reference_ids.is_empty() {
-            query_args.push(("ReferenceIds".to_string(), reference_ids.join(" ")));
+        for v in reference_ids {
+            query_args.push(("ReferenceIds", v.to_string()));
         }
         if !sort_order.to_string().is_empty() {
-            query_args.push(("SortOrder".to_string(), sort_order.to_string()));
+            query_args.push(("SortOrder", sort_order.to_string()));
         }
         if let Some(date) = start_date {
-            query_args.push(("StartDate".to_string(), date.to_rfc3339()));
+            query_args.push(("StartDate", date.to_rfc3339()));
         }
-        if !status.is_empty() {
-            query_args.push(("Status".to_string(), status.join(" ")));
+        for v in status {
+            query_args.push(("Status", v.to_string()));
         }
-        if !tracking_numbers.is_empty() {
-            query_args.push(("TrackingNumbers".to_string(), tracking_numbers.join(" ")));
+        for v in tracking_numbers {
+            query_args.push(("TrackingNumbers", v.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/return?{}", query_);
+        let url = crate::Client::url_with_query("/return", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/return", &[]))
     }
 
     /**
@@ -219,8 +216,9 @@ impl Returns {
     ) -> Result<crate::types::ReturnOrder> {
         let url = "/return".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/return", &[]))
     }
 
     /**
@@ -236,10 +234,12 @@ impl Returns {
     pub async fn post_cancel(&self, id: i64) -> Result<crate::types::ReturnOrder> {
         let url = format!(
             "/return/{}/cancel",
-            crate::progenitor_support::encode_path(&id.to_string()),
+            self.client.encode_path(&id.to_string()),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("POST", "/return/{id}/cancel", &[("id", id.to_string())])
+        })
     }
 
     /**
@@ -258,9 +258,150 @@ impl Returns {
     ) -> Result<crate::types::ReturnOrderStatusHistory> {
         let url = format!(
             "/return/{}/statushistory",
-            crate::progenitor_support::encode_path(&id.to_string()),
+            self.client.encode_path(&id.to_string()),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/return/{id}/statushistory",
+                &[("id", id.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`Returns`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ReturnsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ReturnsApi: Send + Sync {
+    async fn get(&self, id: i64) -> Result<crate::types::ReturnOrder>;
+    async fn put(
+        &self,
+        id: i64,
+        body: &crate::types::ReturnsCreateReturn,
+    ) -> Result<crate::types::ReturnOrder>;
+    async fn get_page(
+        &self,
+        page: i64,
+        limit: i64,
+        sort_order: crate::types::SortOrder,
+        start_date: Option<chrono::DateTime<chrono::Utc>>,
+        end_date: Option<chrono::DateTime<chrono::Utc>>,
+        ids: &[String],
+        reference_ids: &[String],
+        status: &[String],
+        fulfillment_center_ids: &[String],
+        tracking_numbers: &[String],
+        original_shipment_ids: &[String],
+        inventory_ids: &[String],
+    ) -> Result<Vec<crate::types::ReturnOrder>>;
+    async fn get_all(
+        &self,
+        sort_order: crate::types::SortOrder,
+        start_date: Option<chrono::DateTime<chrono::Utc>>,
+        end_date: Option<chrono::DateTime<chrono::Utc>>,
+        ids: &[String],
+        reference_ids: &[String],
+        status: &[String],
+        fulfillment_center_ids: &[String],
+        tracking_numbers: &[String],
+        original_shipment_ids: &[String],
+        inventory_ids: &[String],
+    ) -> Result<Vec<crate::types::ReturnOrder>>;
+    async fn post(
+        &self,
+        body: &crate::types::ReturnsCreateReturn,
+    ) -> Result<crate::types::ReturnOrder>;
+    async fn post_cancel(&self, id: i64) -> Result<crate::types::ReturnOrder>;
+    async fn get_status_history(&self, id: i64) -> Result<crate::types::ReturnOrderStatusHistory>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ReturnsApi for Returns {
+    async fn get(&self, id: i64) -> Result<crate::types::ReturnOrder> {
+        Returns::get(self, id).await
+    }
+    async fn put(
+        &self,
+        id: i64,
+        body: &crate::types::ReturnsCreateReturn,
+    ) -> Result<crate::types::ReturnOrder> {
+        Returns::put(self, id, body).await
+    }
+    async fn get_page(
+        &self,
+        page: i64,
+        limit: i64,
+        sort_order: crate::types::SortOrder,
+        start_date: Option<chrono::DateTime<chrono::Utc>>,
+        end_date: Option<chrono::DateTime<chrono::Utc>>,
+        ids: &[String],
+        reference_ids: &[String],
+        status: &[String],
+        fulfillment_center_ids: &[String],
+        tracking_numbers: &[String],
+        original_shipment_ids: &[String],
+        inventory_ids: &[String],
+    ) -> Result<Vec<crate::types::ReturnOrder>> {
+        Returns::get_page(
+            self,
+            page,
+            limit,
+            sort_order,
+            start_date,
+            end_date,
+            ids,
+            reference_ids,
+            status,
+            fulfillment_center_ids,
+            tracking_numbers,
+            original_shipment_ids,
+            inventory_ids,
+        )
+        .await
+    }
+    async fn get_all(
+        &self,
+        sort_order: crate::types::SortOrder,
+        start_date: Option<chrono::DateTime<chrono::Utc>>,
+        end_date: Option<chrono::DateTime<chrono::Utc>>,
+        ids: &[String],
+        reference_ids: &[String],
+        status: &[String],
+        fulfillment_center_ids: &[String],
+        tracking_numbers: &[String],
+        original_shipment_ids: &[String],
+        inventory_ids: &[String],
+    ) -> Result<Vec<crate::types::ReturnOrder>> {
+        Returns::get_all(
+            self,
+            sort_order,
+            start_date,
+            end_date,
+            ids,
+            reference_ids,
+            status,
+            fulfillment_center_ids,
+            tracking_numbers,
+            original_shipment_ids,
+            inventory_ids,
+        )
+        .await
+    }
+    async fn post(
+        &self,
+        body: &crate::types::ReturnsCreateReturn,
+    ) -> Result<crate::types::ReturnOrder> {
+        Returns::post(self, body).await
+    }
+    async fn post_cancel(&self, id: i64) -> Result<crate::types::ReturnOrder> {
+        Returns::post_cancel(self, id).await
+    }
+    async fn get_status_history(&self, id: i64) -> Result<crate::types::ReturnOrderStatusHistory> {
+        Returns::get_status_history(self, id).await
     }
 }
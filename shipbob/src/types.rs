@@ -2,6 +2,33 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+/// Either one of something, or many of them. Some endpoints send back an
+/// object when there is one and an array when there are more, so this takes both.
+// Many is tried first: serde reads a struct from an array of its fields too,
+// so an array would otherwise be taken for One.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum OneOrMany<T> {
+    Many(Vec<T>),
+    One(T),
+}
+
+impl<T> OneOrMany<T> {
+    /// All of them, as a vector.
+    pub fn into_vec(self) -> Vec<T> {
+        match self {
+            OneOrMany::One(t) => vec![t],
+            OneOrMany::Many(v) => v,
+        }
+    }
+}
+
+impl<T> Default for OneOrMany<T> {
+    fn default() -> Self {
+        OneOrMany::Many(Vec::new())
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Channel {
     /**
@@ -231,7 +258,7 @@ pub struct InventoryLotQuantity {
 /**
  * Attribute influencing the packaging requirements of this inventory item
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PackagingAttribute {
     #[serde(rename = "Book")]
     Book,
@@ -281,7 +308,40 @@ impl Default for PackagingAttribute {
         PackagingAttribute::Noop
     }
 }
+
 impl PackagingAttribute {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PackagingAttribute::Book => "Book",
+            PackagingAttribute::CustomDunnage => "CustomDunnage",
+            PackagingAttribute::CustomPackaging => "CustomPackaging",
+            PackagingAttribute::Foldable => "Foldable",
+            PackagingAttribute::Fragile => "Fragile",
+            PackagingAttribute::MarketingInsert => "MarketingInsert",
+            PackagingAttribute::None => "None",
+            PackagingAttribute::Poster => "Poster",
+            PackagingAttribute::Stackable => "Stackable",
+            PackagingAttribute::Noop => "",
+            PackagingAttribute::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PackagingAttribute] {
+        &[
+            PackagingAttribute::Book,
+            PackagingAttribute::CustomDunnage,
+            PackagingAttribute::CustomPackaging,
+            PackagingAttribute::Foldable,
+            PackagingAttribute::Fragile,
+            PackagingAttribute::MarketingInsert,
+            PackagingAttribute::None,
+            PackagingAttribute::Poster,
+            PackagingAttribute::Stackable,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PackagingAttribute::Noop)
     }
@@ -657,7 +717,7 @@ pub struct OrdersChannelInfo {
  *   ShipFrom
  *   MarkFor
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Type {
     #[serde(rename = "MarkFor")]
     MarkFor,
@@ -686,7 +746,23 @@ impl Default for Type {
         Type::Noop
     }
 }
+
 impl Type {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Type::MarkFor => "MarkFor",
+            Type::ShipFrom => "ShipFrom",
+            Type::Noop => "",
+            Type::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Type] {
+        &[Type::MarkFor, Type::ShipFrom]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Type::Noop)
     }
@@ -1165,7 +1241,7 @@ pub struct OrdersMeasurements {
 /**
  * The shipment status
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Status {
     #[serde(rename = "Cancelled")]
     Cancelled,
@@ -1218,7 +1294,42 @@ impl Default for Status {
         Status::Noop
     }
 }
+
 impl Status {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Status::Cancelled => "Cancelled",
+            Status::CleanSweeped => "CleanSweeped",
+            Status::Completed => "Completed",
+            Status::Exception => "Exception",
+            Status::ImportReview => "ImportReview",
+            Status::LabeledCreated => "LabeledCreated",
+            Status::None => "None",
+            Status::OnHold => "OnHold",
+            Status::Pending => "Pending",
+            Status::Processing => "Processing",
+            Status::Noop => "",
+            Status::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Status] {
+        &[
+            Status::Cancelled,
+            Status::CleanSweeped,
+            Status::Completed,
+            Status::Exception,
+            Status::ImportReview,
+            Status::LabeledCreated,
+            Status::None,
+            Status::OnHold,
+            Status::Pending,
+            Status::Processing,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Status::Noop)
     }
@@ -1227,7 +1338,7 @@ impl Status {
 /**
  * Container type for the shipment
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PackageMaterialType {
     #[serde(rename = "Bookfold")]
     Bookfold,
@@ -1280,7 +1391,42 @@ impl Default for PackageMaterialType {
         PackageMaterialType::Noop
     }
 }
+
 impl PackageMaterialType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PackageMaterialType::Bookfold => "Bookfold",
+            PackageMaterialType::Box => "Box",
+            PackageMaterialType::BubbleMailer => "BubbleMailer",
+            PackageMaterialType::Custom => "Custom",
+            PackageMaterialType::FragileBox => "FragileBox",
+            PackageMaterialType::OwnContainer => "OwnContainer",
+            PackageMaterialType::PolyMailer => "PolyMailer",
+            PackageMaterialType::PosterTube => "PosterTube",
+            PackageMaterialType::Undefined => "Undefined",
+            PackageMaterialType::Unknown => "Unknown",
+            PackageMaterialType::Noop => "",
+            PackageMaterialType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PackageMaterialType] {
+        &[
+            PackageMaterialType::Bookfold,
+            PackageMaterialType::Box,
+            PackageMaterialType::BubbleMailer,
+            PackageMaterialType::Custom,
+            PackageMaterialType::FragileBox,
+            PackageMaterialType::OwnContainer,
+            PackageMaterialType::PolyMailer,
+            PackageMaterialType::PosterTube,
+            PackageMaterialType::Undefined,
+            PackageMaterialType::Unknown,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PackageMaterialType::Noop)
     }
@@ -1289,7 +1435,7 @@ impl PackageMaterialType {
 /**
  * Status of ShipBob’s completion of the fulfillment operation.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum EstimatedFulfillmentDateStatus {
     #[serde(rename = "AwaitingInventoryAllocation")]
     AwaitingInventoryAllocation,
@@ -1335,7 +1481,38 @@ impl Default for EstimatedFulfillmentDateStatus {
         EstimatedFulfillmentDateStatus::Noop
     }
 }
+
 impl EstimatedFulfillmentDateStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EstimatedFulfillmentDateStatus::AwaitingInventoryAllocation => {
+                "AwaitingInventoryAllocation"
+            }
+            EstimatedFulfillmentDateStatus::AwaitingReset => "AwaitingReset",
+            EstimatedFulfillmentDateStatus::FulfilledLate => "FulfilledLate",
+            EstimatedFulfillmentDateStatus::FulfilledOnTime => "FulfilledOnTime",
+            EstimatedFulfillmentDateStatus::PendingLate => "PendingLate",
+            EstimatedFulfillmentDateStatus::PendingOnTime => "PendingOnTime",
+            EstimatedFulfillmentDateStatus::Unavailable => "Unavailable",
+            EstimatedFulfillmentDateStatus::Noop => "",
+            EstimatedFulfillmentDateStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [EstimatedFulfillmentDateStatus] {
+        &[
+            EstimatedFulfillmentDateStatus::AwaitingInventoryAllocation,
+            EstimatedFulfillmentDateStatus::AwaitingReset,
+            EstimatedFulfillmentDateStatus::FulfilledLate,
+            EstimatedFulfillmentDateStatus::FulfilledOnTime,
+            EstimatedFulfillmentDateStatus::PendingLate,
+            EstimatedFulfillmentDateStatus::PendingOnTime,
+            EstimatedFulfillmentDateStatus::Unavailable,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, EstimatedFulfillmentDateStatus::Noop)
     }
@@ -1521,7 +1698,7 @@ pub struct OrdersShipment {
  *   
  *   Freight: Larger boxes, usually transported by truckload.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum CarrierType {
     #[serde(rename = "Freight")]
     Freight,
@@ -1550,7 +1727,23 @@ impl Default for CarrierType {
         CarrierType::Noop
     }
 }
+
 impl CarrierType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CarrierType::Freight => "Freight",
+            CarrierType::Parcel => "Parcel",
+            CarrierType::Noop => "",
+            CarrierType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [CarrierType] {
+        &[CarrierType::Freight, CarrierType::Parcel]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, CarrierType::Noop)
     }
@@ -1565,7 +1758,7 @@ impl CarrierType {
  *   
  *   Prepaid: The shipper pays the shipping charges (Shipbob or merchant).
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PaymentTerm {
     #[serde(rename = "Collect")]
     Collect,
@@ -1597,7 +1790,28 @@ impl Default for PaymentTerm {
         PaymentTerm::Noop
     }
 }
+
 impl PaymentTerm {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PaymentTerm::Collect => "Collect",
+            PaymentTerm::Prepaid => "Prepaid",
+            PaymentTerm::ThirdParty => "ThirdParty",
+            PaymentTerm::Noop => "",
+            PaymentTerm::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PaymentTerm] {
+        &[
+            PaymentTerm::Collect,
+            PaymentTerm::Prepaid,
+            PaymentTerm::ThirdParty,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PaymentTerm::Noop)
     }
@@ -1681,7 +1895,7 @@ pub struct OrdersRetailerProgramData {
 /**
  * The order status
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum OrderStatus {
     #[serde(rename = "Cancelled")]
     Cancelled,
@@ -1722,7 +1936,34 @@ impl Default for OrderStatus {
         OrderStatus::Noop
     }
 }
+
 impl OrderStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrderStatus::Cancelled => "Cancelled",
+            OrderStatus::Exception => "Exception",
+            OrderStatus::Fulfilled => "Fulfilled",
+            OrderStatus::ImportReview => "ImportReview",
+            OrderStatus::PartiallyFulfilled => "PartiallyFulfilled",
+            OrderStatus::Processing => "Processing",
+            OrderStatus::Noop => "",
+            OrderStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [OrderStatus] {
+        &[
+            OrderStatus::Cancelled,
+            OrderStatus::Exception,
+            OrderStatus::Fulfilled,
+            OrderStatus::ImportReview,
+            OrderStatus::PartiallyFulfilled,
+            OrderStatus::Processing,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, OrderStatus::Noop)
     }
@@ -1731,7 +1972,7 @@ impl OrderStatus {
 /**
  * Shipment type of the order
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum OrderType {
     #[serde(rename = "B2B")]
     B2B,
@@ -1763,7 +2004,24 @@ impl Default for OrderType {
         OrderType::Noop
     }
 }
+
 impl OrderType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrderType::B2B => "B2B",
+            OrderType::Dtc => "DTC",
+            OrderType::DropShip => "DropShip",
+            OrderType::Noop => "",
+            OrderType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [OrderType] {
+        &[OrderType::B2B, OrderType::Dtc, OrderType::DropShip]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, OrderType::Noop)
     }
@@ -2073,7 +2331,7 @@ pub struct OrdersCreateOrderModel {
     pub type_: Option<OrderType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Action {
     #[serde(rename = "Cancel")]
     Cancel,
@@ -2099,7 +2357,22 @@ impl Default for Action {
         Action::Noop
     }
 }
+
 impl Action {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Action::Cancel => "Cancel",
+            Action::Noop => "",
+            Action::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Action] {
+        &[Action::Cancel]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Action::Noop)
     }
@@ -2144,7 +2417,7 @@ pub struct OrdersCanceledShipment {
 /**
  * The overall result of canceling the shipments associated with the order
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum OrdersCanceledOrderStatus {
     #[serde(rename = "Failure")]
     Failure,
@@ -2176,7 +2449,28 @@ impl Default for OrdersCanceledOrderStatus {
         OrdersCanceledOrderStatus::Noop
     }
 }
+
 impl OrdersCanceledOrderStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrdersCanceledOrderStatus::Failure => "Failure",
+            OrdersCanceledOrderStatus::PartialSuccess => "PartialSuccess",
+            OrdersCanceledOrderStatus::Success => "Success",
+            OrdersCanceledOrderStatus::Noop => "",
+            OrdersCanceledOrderStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [OrdersCanceledOrderStatus] {
+        &[
+            OrdersCanceledOrderStatus::Failure,
+            OrdersCanceledOrderStatus::PartialSuccess,
+            OrdersCanceledOrderStatus::Success,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, OrdersCanceledOrderStatus::Noop)
     }
@@ -2368,7 +2662,7 @@ pub struct OrdersShipMethodDetail {
     pub service_level: Option<OrdersServiceLevelDetail>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ProductActiveStatus {
     #[serde(rename = "Active")]
     Active,
@@ -2400,13 +2694,34 @@ impl Default for ProductActiveStatus {
         ProductActiveStatus::Noop
     }
 }
+
 impl ProductActiveStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProductActiveStatus::Active => "Active",
+            ProductActiveStatus::Any => "Any",
+            ProductActiveStatus::Inactive => "Inactive",
+            ProductActiveStatus::Noop => "",
+            ProductActiveStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ProductActiveStatus] {
+        &[
+            ProductActiveStatus::Active,
+            ProductActiveStatus::Any,
+            ProductActiveStatus::Inactive,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ProductActiveStatus::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ProductBundleStatus {
     #[serde(rename = "Any")]
     Any,
@@ -2438,7 +2753,28 @@ impl Default for ProductBundleStatus {
         ProductBundleStatus::Noop
     }
 }
+
 impl ProductBundleStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProductBundleStatus::Any => "Any",
+            ProductBundleStatus::Bundle => "Bundle",
+            ProductBundleStatus::NotBundle => "NotBundle",
+            ProductBundleStatus::Noop => "",
+            ProductBundleStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ProductBundleStatus] {
+        &[
+            ProductBundleStatus::Any,
+            ProductBundleStatus::Bundle,
+            ProductBundleStatus::NotBundle,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ProductBundleStatus::Noop)
     }
@@ -2919,7 +3255,7 @@ pub struct ReceivingFulfillmentCenter {
     pub zip_code: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ReceivingStatus {
     #[serde(rename = "Arrived")]
     Arrived,
@@ -2963,13 +3299,42 @@ impl Default for ReceivingStatus {
         ReceivingStatus::Noop
     }
 }
+
 impl ReceivingStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReceivingStatus::Arrived => "Arrived",
+            ReceivingStatus::Awaiting => "Awaiting",
+            ReceivingStatus::Cancelled => "Cancelled",
+            ReceivingStatus::Completed => "Completed",
+            ReceivingStatus::Incomplete => "Incomplete",
+            ReceivingStatus::PartiallyArrived => "PartiallyArrived",
+            ReceivingStatus::Processing => "Processing",
+            ReceivingStatus::Noop => "",
+            ReceivingStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ReceivingStatus] {
+        &[
+            ReceivingStatus::Arrived,
+            ReceivingStatus::Awaiting,
+            ReceivingStatus::Cancelled,
+            ReceivingStatus::Completed,
+            ReceivingStatus::Incomplete,
+            ReceivingStatus::PartiallyArrived,
+            ReceivingStatus::Processing,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ReceivingStatus::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ReceivingPackageType {
     #[serde(rename = "FloorLoadedContainer")]
     FloorLoadedContainer,
@@ -3001,13 +3366,34 @@ impl Default for ReceivingPackageType {
         ReceivingPackageType::Noop
     }
 }
+
 impl ReceivingPackageType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReceivingPackageType::FloorLoadedContainer => "FloorLoadedContainer",
+            ReceivingPackageType::Package => "Package",
+            ReceivingPackageType::Pallet => "Pallet",
+            ReceivingPackageType::Noop => "",
+            ReceivingPackageType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ReceivingPackageType] {
+        &[
+            ReceivingPackageType::FloorLoadedContainer,
+            ReceivingPackageType::Package,
+            ReceivingPackageType::Pallet,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ReceivingPackageType::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ReceivingPackingType {
     #[serde(rename = "EverythingInOneBox")]
     EverythingInOneBox,
@@ -3039,13 +3425,34 @@ impl Default for ReceivingPackingType {
         ReceivingPackingType::Noop
     }
 }
+
 impl ReceivingPackingType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReceivingPackingType::EverythingInOneBox => "EverythingInOneBox",
+            ReceivingPackingType::MultipleSkuPerBox => "MultipleSkuPerBox",
+            ReceivingPackingType::OneSkuPerBox => "OneSkuPerBox",
+            ReceivingPackingType::Noop => "",
+            ReceivingPackingType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ReceivingPackingType] {
+        &[
+            ReceivingPackingType::EverythingInOneBox,
+            ReceivingPackingType::MultipleSkuPerBox,
+            ReceivingPackingType::OneSkuPerBox,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ReceivingPackingType::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ReceivingBoxStatus {
     #[serde(rename = "Arrived")]
     Arrived,
@@ -3086,7 +3493,34 @@ impl Default for ReceivingBoxStatus {
         ReceivingBoxStatus::Noop
     }
 }
+
 impl ReceivingBoxStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReceivingBoxStatus::Arrived => "Arrived",
+            ReceivingBoxStatus::Awaiting => "Awaiting",
+            ReceivingBoxStatus::Cancelled => "Cancelled",
+            ReceivingBoxStatus::Completed => "Completed",
+            ReceivingBoxStatus::Counting => "Counting",
+            ReceivingBoxStatus::Stowing => "Stowing",
+            ReceivingBoxStatus::Noop => "",
+            ReceivingBoxStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ReceivingBoxStatus] {
+        &[
+            ReceivingBoxStatus::Arrived,
+            ReceivingBoxStatus::Awaiting,
+            ReceivingBoxStatus::Cancelled,
+            ReceivingBoxStatus::Completed,
+            ReceivingBoxStatus::Counting,
+            ReceivingBoxStatus::Stowing,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ReceivingBoxStatus::Noop)
     }
@@ -3456,7 +3890,7 @@ pub struct ReceivingCreateOrderModel {
     pub package_type: ReceivingPackageType,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ReturnStatus {
     #[serde(rename = "Arrived")]
     Arrived,
@@ -3494,13 +3928,38 @@ impl Default for ReturnStatus {
         ReturnStatus::Noop
     }
 }
+
 impl ReturnStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReturnStatus::Arrived => "Arrived",
+            ReturnStatus::AwaitingArrival => "AwaitingArrival",
+            ReturnStatus::Cancelled => "Cancelled",
+            ReturnStatus::Completed => "Completed",
+            ReturnStatus::Processing => "Processing",
+            ReturnStatus::Noop => "",
+            ReturnStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ReturnStatus] {
+        &[
+            ReturnStatus::Arrived,
+            ReturnStatus::AwaitingArrival,
+            ReturnStatus::Cancelled,
+            ReturnStatus::Completed,
+            ReturnStatus::Processing,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ReturnStatus::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ReturnsTransactionLogSource {
     #[serde(rename = "ReturnLabelInvoice")]
     ReturnLabelInvoice,
@@ -3532,7 +3991,28 @@ impl Default for ReturnsTransactionLogSource {
         ReturnsTransactionLogSource::Noop
     }
 }
+
 impl ReturnsTransactionLogSource {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReturnsTransactionLogSource::ReturnLabelInvoice => "ReturnLabelInvoice",
+            ReturnsTransactionLogSource::ReturnProcessingFee => "ReturnProcessingFee",
+            ReturnsTransactionLogSource::ReturnToSenderFee => "ReturnToSenderFee",
+            ReturnsTransactionLogSource::Noop => "",
+            ReturnsTransactionLogSource::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ReturnsTransactionLogSource] {
+        &[
+            ReturnsTransactionLogSource::ReturnLabelInvoice,
+            ReturnsTransactionLogSource::ReturnProcessingFee,
+            ReturnsTransactionLogSource::ReturnToSenderFee,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ReturnsTransactionLogSource::Noop)
     }
@@ -3576,7 +4056,7 @@ pub struct ReturnsFulfillmentCenter {
     pub name: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ReturnAction {
     #[serde(rename = "Default")]
     Default,
@@ -3611,13 +4091,36 @@ impl Default for ReturnAction {
         ReturnAction::Noop
     }
 }
+
 impl ReturnAction {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReturnAction::Default => "Default",
+            ReturnAction::Dispose => "Dispose",
+            ReturnAction::Quarantine => "Quarantine",
+            ReturnAction::Restock => "Restock",
+            ReturnAction::Noop => "",
+            ReturnAction::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ReturnAction] {
+        &[
+            ReturnAction::Default,
+            ReturnAction::Dispose,
+            ReturnAction::Quarantine,
+            ReturnAction::Restock,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ReturnAction::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ReturnActionSource {
     #[serde(rename = "InventoryDefault")]
     InventoryDefault,
@@ -3646,7 +4149,26 @@ impl Default for ReturnActionSource {
         ReturnActionSource::Noop
     }
 }
+
 impl ReturnActionSource {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReturnActionSource::InventoryDefault => "InventoryDefault",
+            ReturnActionSource::Override => "Override",
+            ReturnActionSource::Noop => "",
+            ReturnActionSource::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ReturnActionSource] {
+        &[
+            ReturnActionSource::InventoryDefault,
+            ReturnActionSource::Override,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ReturnActionSource::Noop)
     }
@@ -3735,7 +4257,7 @@ pub struct ReturnsInventoryItem {
     pub quantity: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ReturnType {
     #[serde(rename = "Regular")]
     Regular,
@@ -3764,7 +4286,23 @@ impl Default for ReturnType {
         ReturnType::Noop
     }
 }
+
 impl ReturnType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReturnType::Regular => "Regular",
+            ReturnType::ReturnToSender => "ReturnToSender",
+            ReturnType::Noop => "",
+            ReturnType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ReturnType] {
+        &[ReturnType::Regular, ReturnType::ReturnToSender]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ReturnType::Noop)
     }
@@ -3932,7 +4470,7 @@ pub struct ReturnsCreateReturn {
 /**
  * Order to sort results in
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SortOrder {
     #[serde(rename = "Newest")]
     Newest,
@@ -3961,7 +4499,23 @@ impl Default for SortOrder {
         SortOrder::Noop
     }
 }
+
 impl SortOrder {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SortOrder::Newest => "Newest",
+            SortOrder::Oldest => "Oldest",
+            SortOrder::Noop => "",
+            SortOrder::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SortOrder] {
+        &[SortOrder::Newest, SortOrder::Oldest]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SortOrder::Noop)
     }
@@ -3982,7 +4536,7 @@ pub struct ReturnOrderStatusHistory {
     pub time_stamp: Option<chrono::DateTime<chrono::Utc>>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum WebhooksTopics {
     #[serde(rename = "order_shipped")]
     OrderShipped,
@@ -4017,7 +4571,30 @@ impl Default for WebhooksTopics {
         WebhooksTopics::Noop
     }
 }
+
 impl WebhooksTopics {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhooksTopics::OrderShipped => "order_shipped",
+            WebhooksTopics::ShipmentDelivered => "shipment_delivered",
+            WebhooksTopics::ShipmentException => "shipment_exception",
+            WebhooksTopics::ShipmentOnhold => "shipment_onhold",
+            WebhooksTopics::Noop => "",
+            WebhooksTopics::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [WebhooksTopics] {
+        &[
+            WebhooksTopics::OrderShipped,
+            WebhooksTopics::ShipmentDelivered,
+            WebhooksTopics::ShipmentException,
+            WebhooksTopics::ShipmentOnhold,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, WebhooksTopics::Noop)
     }
@@ -4130,7 +4707,7 @@ pub struct MicrosoftAspNetCoreMvcProblemDetails {
     pub type_: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum IntegrationsLocationServiceTypeEnum {
     #[serde(rename = "Receiving")]
     Receiving,
@@ -4159,7 +4736,26 @@ impl Default for IntegrationsLocationServiceTypeEnum {
         IntegrationsLocationServiceTypeEnum::Noop
     }
 }
+
 impl IntegrationsLocationServiceTypeEnum {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IntegrationsLocationServiceTypeEnum::Receiving => "Receiving",
+            IntegrationsLocationServiceTypeEnum::Returns => "Returns",
+            IntegrationsLocationServiceTypeEnum::Noop => "",
+            IntegrationsLocationServiceTypeEnum::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [IntegrationsLocationServiceTypeEnum] {
+        &[
+            IntegrationsLocationServiceTypeEnum::Receiving,
+            IntegrationsLocationServiceTypeEnum::Returns,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, IntegrationsLocationServiceTypeEnum::Noop)
     }
@@ -4382,3 +4978,51 @@ pub struct IntegrationsLocationInternalAllOf {
     #[serde(flatten)]
     pub integrations_location_internal: IntegrationsLocationInternal,
 }
+
+/// The types taken and returned by the functions in [`crate::channels`].
+pub mod channels {
+    pub use super::Channel;
+}
+
+/// The types taken and returned by the functions in [`crate::inventory`].
+pub mod inventory {
+    pub use super::Inventory;
+}
+
+/// The types taken and returned by the functions in [`crate::locations`].
+pub mod locations {
+    pub use super::IntegrationsLocationInternalAllOf;
+}
+
+/// The types taken and returned by the functions in [`crate::orders`].
+pub mod orders {
+    pub use super::{
+        Order, OrdersAddStoreOrderJsonModel, OrdersCancelShipmentsModel, OrdersCanceledOrder,
+        OrdersCanceledShipments, OrdersCreateOrderModel, OrdersEstimate,
+        OrdersEstimateFulfillmentRequestModel, OrdersShipMethodDetail, OrdersShipment,
+        OrdersShipmentLog, SortOrder,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::products`].
+pub mod products {
+    pub use super::{
+        Product, ProductActiveStatus, ProductBundleStatus, ProductsCreateProductModel,
+        ProductsUpdateProductModel,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::receiving`].
+pub mod receiving {
+    pub use super::{ReceivingCreateOrderModel, ReceivingFulfillmentCenter, ReceivingOrder};
+}
+
+/// The types taken and returned by the functions in [`crate::returns`].
+pub mod returns {
+    pub use super::{ReturnOrder, ReturnOrderStatusHistory, ReturnsCreateReturn, SortOrder};
+}
+
+/// The types taken and returned by the functions in [`crate::webhooks`].
+pub mod webhooks {
+    pub use super::{Webhook, WebhooksCreateWebhookSubscriptionModel, WebhooksTopics};
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Webhooks {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Webhooks {
         Webhooks { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Webhooks {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Get Webhooks.
      *
@@ -31,20 +37,22 @@ impl Webhooks {
         page: u64,
         limit: i64,
     ) -> Result<Vec<crate::types::Webhook>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if limit > 0 {
-            query_args.push(("Limit".to_string(), limit.to_string()));
+            query_args.push(("Limit", limit.to_string()));
         }
         if !page.to_string().is_empty() {
-            query_args.push(("Page".to_string(), page.to_string()));
+            query_args.push(("Page", page.to_string()));
         }
         if !topic.to_string().is_empty() {
-            query_args.push(("Topic".to_string(), topic.to_string()));
+            query_args.push(("Topic", topic.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/webhook?{}", query_);
+        let url = crate::Client::url_with_query("/webhook", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/webhook", &[]))
     }
 
     /**
@@ -60,14 +68,16 @@ impl Webhooks {
         &self,
         topic: crate::types::WebhooksTopics,
     ) -> Result<Vec<crate::types::Webhook>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !topic.to_string().is_empty() {
-            query_args.push(("Topic".to_string(), topic.to_string()));
+            query_args.push(("Topic", topic.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/webhook?{}", query_);
+        let url = crate::Client::url_with_query("/webhook", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/webhook", &[]))
     }
 
     /**
@@ -85,8 +95,9 @@ impl Webhooks {
     ) -> Result<crate::types::Webhook> {
         let url = "/webhook".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/webhook", &[]))
     }
 
     /**
@@ -99,11 +110,83 @@ impl Webhooks {
      * * `id: i64` -- Unique id of the channel.
      */
     pub async fn delete(&self, id: i64) -> Result<()> {
-        let url = format!(
-            "/webhook/{}",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/webhook/{}", self.client.encode_path(&id.to_string()),);
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("DELETE", "/webhook/{id}", &[("id", id.to_string())])
+        })
+    }
+
+    /**
+     * Delete an existing webhook subscription.
+     *
+     * This function performs a `DELETE` to the `/webhook/{id}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, id: i64) -> Result<bool> {
+        let url = format!("/webhook/{}", self.client.encode_path(&id.to_string()),);
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url("DELETE", "/webhook/{id}", &[("id", id.to_string())])
+            })
+    }
+}
 
-        self.client.delete(&url, None).await
+/// The functions of [`Webhooks`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn WebhooksApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait WebhooksApi: Send + Sync {
+    async fn get_page(
+        &self,
+        topic: crate::types::WebhooksTopics,
+        page: u64,
+        limit: i64,
+    ) -> Result<Vec<crate::types::Webhook>>;
+    async fn get_all(
+        &self,
+        topic: crate::types::WebhooksTopics,
+    ) -> Result<Vec<crate::types::Webhook>>;
+    async fn post(
+        &self,
+        body: &crate::types::WebhooksCreateWebhookSubscriptionModel,
+    ) -> Result<crate::types::Webhook>;
+    async fn delete(&self, id: i64) -> Result<()>;
+    async fn delete_if_exists(&self, id: i64) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl WebhooksApi for Webhooks {
+    async fn get_page(
+        &self,
+        topic: crate::types::WebhooksTopics,
+        page: u64,
+        limit: i64,
+    ) -> Result<Vec<crate::types::Webhook>> {
+        Webhooks::get_page(self, topic, page, limit).await
+    }
+    async fn get_all(
+        &self,
+        topic: crate::types::WebhooksTopics,
+    ) -> Result<Vec<crate::types::Webhook>> {
+        Webhooks::get_all(self, topic).await
+    }
+    async fn post(
+        &self,
+        body: &crate::types::WebhooksCreateWebhookSubscriptionModel,
+    ) -> Result<crate::types::Webhook> {
+        Webhooks::post(self, body).await
+    }
+    async fn delete(&self, id: i64) -> Result<()> {
+        Webhooks::delete(self, id).await
+    }
+    async fn delete_if_exists(&self, id: i64) -> Result<bool> {
+        Webhooks::delete_if_exists(self, id).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Access {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Access {
         Access { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Access {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieves a list of access scopes associated to the access token.
      *
@@ -21,7 +27,9 @@ impl Access {
      */
     pub async fn get_admin_oauth_scope(&self) -> Result<()> {
         let url = "/admin/oauth/access_scopes.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/oauth/access_scopes.json", &[])
+        })
     }
 
     /**
@@ -33,7 +41,13 @@ impl Access {
      */
     pub async fn deprecated_202001_get_storefront_token(&self) -> Result<()> {
         let url = "/admin/api/2020-01/storefront_access_tokens.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/storefront_access_tokens.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -49,8 +63,15 @@ impl Access {
     ) -> Result<()> {
         let url = "/admin/api/2020-01/storefront_access_tokens.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-01/storefront_access_tokens.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -70,10 +91,50 @@ impl Access {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/storefront_access_tokens/{}/json",
-            crate::progenitor_support::encode_path(&storefront_access_token_id.to_string()),
+            self.client.encode_path(storefront_access_token_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-01/storefront_access_tokens/{storefront_access_token_id}.json",
+                &[(
+                    "storefront_access_token_id",
+                    storefront_access_token_id.to_string(),
+                )],
+            )
+        })
+    }
+
+    /**
+     * Deletes an existing storefront access token.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-01/storefront_access_tokens/{storefront_access_token_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_delete_storefront_tokens_param_token`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202001_delete_storefront_tokens_param_token_if_exists(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-01/storefront_access_tokens/{}/json",
+            self.client.encode_path(storefront_access_token_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-01/storefront_access_tokens/{storefront_access_token_id}.json",
+                    &[(
+                        "storefront_access_token_id",
+                        storefront_access_token_id.to_string(),
+                    )],
+                )
+            })
     }
 
     /**
@@ -85,7 +146,13 @@ impl Access {
      */
     pub async fn deprecated_202004_get_storefront_token(&self) -> Result<()> {
         let url = "/admin/api/2020-04/storefront_access_tokens.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/storefront_access_tokens.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -101,8 +168,15 @@ impl Access {
     ) -> Result<()> {
         let url = "/admin/api/2020-04/storefront_access_tokens.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-04/storefront_access_tokens.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -122,10 +196,50 @@ impl Access {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/storefront_access_tokens/{}/json",
-            crate::progenitor_support::encode_path(&storefront_access_token_id.to_string()),
+            self.client.encode_path(storefront_access_token_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-04/storefront_access_tokens/{storefront_access_token_id}.json",
+                &[(
+                    "storefront_access_token_id",
+                    storefront_access_token_id.to_string(),
+                )],
+            )
+        })
+    }
+
+    /**
+     * Deletes an existing storefront access token.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-04/storefront_access_tokens/{storefront_access_token_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_delete_storefront_tokens_param_token`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202004_delete_storefront_tokens_param_token_if_exists(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-04/storefront_access_tokens/{}/json",
+            self.client.encode_path(storefront_access_token_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-04/storefront_access_tokens/{storefront_access_token_id}.json",
+                    &[(
+                        "storefront_access_token_id",
+                        storefront_access_token_id.to_string(),
+                    )],
+                )
+            })
     }
 
     /**
@@ -137,7 +251,13 @@ impl Access {
      */
     pub async fn deprecated_202007_get_storefront_token(&self) -> Result<()> {
         let url = "/admin/api/2020-07/storefront_access_tokens.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/storefront_access_tokens.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -153,8 +273,15 @@ impl Access {
     ) -> Result<()> {
         let url = "/admin/api/2020-07/storefront_access_tokens.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-07/storefront_access_tokens.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -174,10 +301,50 @@ impl Access {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/storefront_access_tokens/{}/json",
-            crate::progenitor_support::encode_path(&storefront_access_token_id.to_string()),
+            self.client.encode_path(storefront_access_token_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-07/storefront_access_tokens/{storefront_access_token_id}.json",
+                &[(
+                    "storefront_access_token_id",
+                    storefront_access_token_id.to_string(),
+                )],
+            )
+        })
+    }
+
+    /**
+     * Deletes an existing storefront access token.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-07/storefront_access_tokens/{storefront_access_token_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_delete_storefront_tokens_param_token`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202007_delete_storefront_tokens_param_token_if_exists(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-07/storefront_access_tokens/{}/json",
+            self.client.encode_path(storefront_access_token_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-07/storefront_access_tokens/{storefront_access_token_id}.json",
+                    &[(
+                        "storefront_access_token_id",
+                        storefront_access_token_id.to_string(),
+                    )],
+                )
+            })
     }
 
     /**
@@ -189,7 +356,13 @@ impl Access {
      */
     pub async fn get_storefront_token(&self) -> Result<()> {
         let url = "/admin/api/2020-10/storefront_access_tokens.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/storefront_access_tokens.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -202,8 +375,15 @@ impl Access {
     pub async fn create_storefront_tokens(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-10/storefront_access_tokens.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-10/storefront_access_tokens.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -223,10 +403,50 @@ impl Access {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/storefront_access_tokens/{}/json",
-            crate::progenitor_support::encode_path(&storefront_access_token_id.to_string()),
+            self.client.encode_path(storefront_access_token_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-10/storefront_access_tokens/{storefront_access_token_id}.json",
+                &[(
+                    "storefront_access_token_id",
+                    storefront_access_token_id.to_string(),
+                )],
+            )
+        })
+    }
+
+    /**
+     * Deletes an existing storefront access token.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-10/storefront_access_tokens/{storefront_access_token_id}.json` endpoint.
+     *
+     * As opposed to `delete_storefront_tokens_param_token`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_storefront_tokens_param_token_if_exists(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-10/storefront_access_tokens/{}/json",
+            self.client.encode_path(storefront_access_token_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-10/storefront_access_tokens/{storefront_access_token_id}.json",
+                    &[(
+                        "storefront_access_token_id",
+                        storefront_access_token_id.to_string(),
+                    )],
+                )
+            })
     }
 
     /**
@@ -238,7 +458,13 @@ impl Access {
      */
     pub async fn deprecated_202101_get_storefront_token(&self) -> Result<()> {
         let url = "/admin/api/2021-01/storefront_access_tokens.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/storefront_access_tokens.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -254,8 +480,15 @@ impl Access {
     ) -> Result<()> {
         let url = "/admin/api/2021-01/storefront_access_tokens.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2021-01/storefront_access_tokens.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -275,10 +508,50 @@ impl Access {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/storefront_access_tokens/{}/json",
-            crate::progenitor_support::encode_path(&storefront_access_token_id.to_string()),
+            self.client.encode_path(storefront_access_token_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2021-01/storefront_access_tokens/{storefront_access_token_id}.json",
+                &[(
+                    "storefront_access_token_id",
+                    storefront_access_token_id.to_string(),
+                )],
+            )
+        })
+    }
+
+    /**
+     * Deletes an existing storefront access token.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2021-01/storefront_access_tokens/{storefront_access_token_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_delete_storefront_tokens_param_token`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202101_delete_storefront_tokens_param_token_if_exists(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2021-01/storefront_access_tokens/{}/json",
+            self.client.encode_path(storefront_access_token_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2021-01/storefront_access_tokens/{storefront_access_token_id}.json",
+                    &[(
+                        "storefront_access_token_id",
+                        storefront_access_token_id.to_string(),
+                    )],
+                )
+            })
     }
 
     /**
@@ -290,7 +563,13 @@ impl Access {
      */
     pub async fn deprecated_unstable_get_storefront_token(&self) -> Result<()> {
         let url = "/admin/api/unstable/storefront_access_tokens.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/storefront_access_tokens.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -306,8 +585,15 @@ impl Access {
     ) -> Result<()> {
         let url = "/admin/api/unstable/storefront_access_tokens.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/unstable/storefront_access_tokens.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -327,9 +613,293 @@ impl Access {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/storefront_access_tokens/{}/json",
-            crate::progenitor_support::encode_path(&storefront_access_token_id.to_string()),
+            self.client.encode_path(storefront_access_token_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/unstable/storefront_access_tokens/{storefront_access_token_id}.json",
+                &[(
+                    "storefront_access_token_id",
+                    storefront_access_token_id.to_string(),
+                )],
+            )
+        })
+    }
+
+    /**
+     * Deletes an existing storefront access token.
+     *
+     * This function performs a `DELETE` to the `/admin/api/unstable/storefront_access_tokens/{storefront_access_token_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_delete_storefront_tokens_param_token`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_unstable_delete_storefront_tokens_param_token_if_exists(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/unstable/storefront_access_tokens/{}/json",
+            self.client.encode_path(storefront_access_token_id),
+        );
+
+        self.client.delete_if_exists(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/admin/api/unstable/storefront_access_tokens/{storefront_access_token_id}.json", &[("storefront_access_token_id", storefront_access_token_id.to_string())]))
+    }
+}
+
+/// The functions of [`Access`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AccessApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AccessApi: Send + Sync {
+    async fn get_admin_oauth_scope(&self) -> Result<()>;
+    async fn deprecated_202001_get_storefront_token(&self) -> Result<()>;
+    async fn deprecated_202001_create_storefront_tokens(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_storefront_tokens_param_token(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_storefront_tokens_param_token_if_exists(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202004_get_storefront_token(&self) -> Result<()>;
+    async fn deprecated_202004_create_storefront_tokens(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_storefront_tokens_param_token(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_storefront_tokens_param_token_if_exists(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202007_get_storefront_token(&self) -> Result<()>;
+    async fn deprecated_202007_create_storefront_tokens(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_storefront_tokens_param_token(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_storefront_tokens_param_token_if_exists(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<bool>;
+    async fn get_storefront_token(&self) -> Result<()>;
+    async fn create_storefront_tokens(&self, body: &serde_json::Value) -> Result<()>;
+    async fn delete_storefront_tokens_param_token(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<()>;
+    async fn delete_storefront_tokens_param_token_if_exists(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202101_get_storefront_token(&self) -> Result<()>;
+    async fn deprecated_202101_create_storefront_tokens(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_storefront_tokens_param_token(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_storefront_tokens_param_token_if_exists(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_unstable_get_storefront_token(&self) -> Result<()>;
+    async fn deprecated_unstable_create_storefront_tokens(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_storefront_tokens_param_token(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_storefront_tokens_param_token_if_exists(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AccessApi for Access {
+    async fn get_admin_oauth_scope(&self) -> Result<()> {
+        Access::get_admin_oauth_scope(self).await
+    }
+    async fn deprecated_202001_get_storefront_token(&self) -> Result<()> {
+        Access::deprecated_202001_get_storefront_token(self).await
+    }
+    async fn deprecated_202001_create_storefront_tokens(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Access::deprecated_202001_create_storefront_tokens(self, body).await
+    }
+    async fn deprecated_202001_delete_storefront_tokens_param_token(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<()> {
+        Access::deprecated_202001_delete_storefront_tokens_param_token(
+            self,
+            storefront_access_token_id,
+        )
+        .await
+    }
+    async fn deprecated_202001_delete_storefront_tokens_param_token_if_exists(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<bool> {
+        Access::deprecated_202001_delete_storefront_tokens_param_token_if_exists(
+            self,
+            storefront_access_token_id,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_storefront_token(&self) -> Result<()> {
+        Access::deprecated_202004_get_storefront_token(self).await
+    }
+    async fn deprecated_202004_create_storefront_tokens(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Access::deprecated_202004_create_storefront_tokens(self, body).await
+    }
+    async fn deprecated_202004_delete_storefront_tokens_param_token(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<()> {
+        Access::deprecated_202004_delete_storefront_tokens_param_token(
+            self,
+            storefront_access_token_id,
+        )
+        .await
+    }
+    async fn deprecated_202004_delete_storefront_tokens_param_token_if_exists(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<bool> {
+        Access::deprecated_202004_delete_storefront_tokens_param_token_if_exists(
+            self,
+            storefront_access_token_id,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_storefront_token(&self) -> Result<()> {
+        Access::deprecated_202007_get_storefront_token(self).await
+    }
+    async fn deprecated_202007_create_storefront_tokens(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Access::deprecated_202007_create_storefront_tokens(self, body).await
+    }
+    async fn deprecated_202007_delete_storefront_tokens_param_token(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<()> {
+        Access::deprecated_202007_delete_storefront_tokens_param_token(
+            self,
+            storefront_access_token_id,
+        )
+        .await
+    }
+    async fn deprecated_202007_delete_storefront_tokens_param_token_if_exists(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<bool> {
+        Access::deprecated_202007_delete_storefront_tokens_param_token_if_exists(
+            self,
+            storefront_access_token_id,
+        )
+        .await
+    }
+    async fn get_storefront_token(&self) -> Result<()> {
+        Access::get_storefront_token(self).await
+    }
+    async fn create_storefront_tokens(&self, body: &serde_json::Value) -> Result<()> {
+        Access::create_storefront_tokens(self, body).await
+    }
+    async fn delete_storefront_tokens_param_token(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<()> {
+        Access::delete_storefront_tokens_param_token(self, storefront_access_token_id).await
+    }
+    async fn delete_storefront_tokens_param_token_if_exists(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<bool> {
+        Access::delete_storefront_tokens_param_token_if_exists(self, storefront_access_token_id)
+            .await
+    }
+    async fn deprecated_202101_get_storefront_token(&self) -> Result<()> {
+        Access::deprecated_202101_get_storefront_token(self).await
+    }
+    async fn deprecated_202101_create_storefront_tokens(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Access::deprecated_202101_create_storefront_tokens(self, body).await
+    }
+    async fn deprecated_202101_delete_storefront_tokens_param_token(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<()> {
+        Access::deprecated_202101_delete_storefront_tokens_param_token(
+            self,
+            storefront_access_token_id,
+        )
+        .await
+    }
+    async fn deprecated_202101_delete_storefront_tokens_param_token_if_exists(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<bool> {
+        Access::deprecated_202101_delete_storefront_tokens_param_token_if_exists(
+            self,
+            storefront_access_token_id,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_storefront_token(&self) -> Result<()> {
+        Access::deprecated_unstable_get_storefront_token(self).await
+    }
+    async fn deprecated_unstable_create_storefront_tokens(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Access::deprecated_unstable_create_storefront_tokens(self, body).await
+    }
+    async fn deprecated_unstable_delete_storefront_tokens_param_token(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<()> {
+        Access::deprecated_unstable_delete_storefront_tokens_param_token(
+            self,
+            storefront_access_token_id,
+        )
+        .await
+    }
+    async fn deprecated_unstable_delete_storefront_tokens_param_token_if_exists(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<bool> {
+        Access::deprecated_unstable_delete_storefront_tokens_param_token_if_exists(
+            self,
+            storefront_access_token_id,
+        )
+        .await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Analytics {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Analytics {
         Analytics { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Analytics {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieves a list of reports. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -38,29 +44,30 @@ impl Analytics {
         updated_at_max: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/reports.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-01/reports.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/reports.json", &[])
+        })
     }
 
     /**
@@ -81,19 +88,21 @@ impl Analytics {
         shopify_ql: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !name.is_empty() {
-            query_args.push(("name".to_string(), name.to_string()));
+            query_args.push(("name", name.to_string()));
         }
         if !shopify_ql.is_empty() {
-            query_args.push(("shopify_ql".to_string(), shopify_ql.to_string()));
+            query_args.push(("shopify_ql", shopify_ql.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/reports.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-01/reports.json", &query_args);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-01/reports.json", &[])
+            })
     }
 
     /**
@@ -113,18 +122,25 @@ impl Analytics {
         report_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/reports/{}/json?{}",
-            crate::progenitor_support::encode_path(&report_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/reports/{}/json",
+                self.client.encode_path(report_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/reports/{report_id}.json",
+                &[("report_id", report_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -145,12 +161,19 @@ impl Analytics {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/reports/{}/json",
-            crate::progenitor_support::encode_path(&report_id.to_string()),
+            self.client.encode_path(report_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-01/reports/{report_id}.json",
+                    &[("report_id", report_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -170,10 +193,44 @@ impl Analytics {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/reports/{}/json",
-            crate::progenitor_support::encode_path(&report_id.to_string()),
+            self.client.encode_path(report_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-01/reports/{report_id}.json",
+                &[("report_id", report_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a report.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-01/reports/{report_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_delete_reports_param_report`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202001_delete_reports_param_report_if_exists(
+        &self,
+        report_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-01/reports/{}/json",
+            self.client.encode_path(report_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-01/reports/{report_id}.json",
+                    &[("report_id", report_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -202,29 +259,30 @@ impl Analytics {
         updated_at_max: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/reports.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-04/reports.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/reports.json", &[])
+        })
     }
 
     /**
@@ -245,19 +303,21 @@ impl Analytics {
         shopify_ql: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !name.is_empty() {
-            query_args.push(("name".to_string(), name.to_string()));
+            query_args.push(("name", name.to_string()));
         }
         if !shopify_ql.is_empty() {
-            query_args.push(("shopify_ql".to_string(), shopify_ql.to_string()));
+            query_args.push(("shopify_ql", shopify_ql.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/reports.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-04/reports.json", &query_args);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-04/reports.json", &[])
+            })
     }
 
     /**
@@ -277,18 +337,25 @@ impl Analytics {
         report_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/reports/{}/json?{}",
-            crate::progenitor_support::encode_path(&report_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/reports/{}/json",
+                self.client.encode_path(report_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/reports/{report_id}.json",
+                &[("report_id", report_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -309,12 +376,19 @@ impl Analytics {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/reports/{}/json",
-            crate::progenitor_support::encode_path(&report_id.to_string()),
+            self.client.encode_path(report_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-04/reports/{report_id}.json",
+                    &[("report_id", report_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -334,10 +408,44 @@ impl Analytics {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/reports/{}/json",
-            crate::progenitor_support::encode_path(&report_id.to_string()),
+            self.client.encode_path(report_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-04/reports/{report_id}.json",
+                &[("report_id", report_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a report.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-04/reports/{report_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_delete_reports_param_report`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202004_delete_reports_param_report_if_exists(
+        &self,
+        report_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-04/reports/{}/json",
+            self.client.encode_path(report_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-04/reports/{report_id}.json",
+                    &[("report_id", report_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -366,29 +474,30 @@ impl Analytics {
         updated_at_max: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/reports.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-07/reports.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/reports.json", &[])
+        })
     }
 
     /**
@@ -409,19 +518,21 @@ impl Analytics {
         shopify_ql: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !name.is_empty() {
-            query_args.push(("name".to_string(), name.to_string()));
+            query_args.push(("name", name.to_string()));
         }
         if !shopify_ql.is_empty() {
-            query_args.push(("shopify_ql".to_string(), shopify_ql.to_string()));
+            query_args.push(("shopify_ql", shopify_ql.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/reports.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-07/reports.json", &query_args);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-07/reports.json", &[])
+            })
     }
 
     /**
@@ -441,18 +552,25 @@ impl Analytics {
         report_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/reports/{}/json?{}",
-            crate::progenitor_support::encode_path(&report_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/reports/{}/json",
+                self.client.encode_path(report_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/reports/{report_id}.json",
+                &[("report_id", report_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -473,12 +591,19 @@ impl Analytics {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/reports/{}/json",
-            crate::progenitor_support::encode_path(&report_id.to_string()),
+            self.client.encode_path(report_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-07/reports/{report_id}.json",
+                    &[("report_id", report_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -498,10 +623,44 @@ impl Analytics {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/reports/{}/json",
-            crate::progenitor_support::encode_path(&report_id.to_string()),
+            self.client.encode_path(report_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-07/reports/{report_id}.json",
+                &[("report_id", report_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a report.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-07/reports/{report_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_delete_reports_param_report`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202007_delete_reports_param_report_if_exists(
+        &self,
+        report_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-07/reports/{}/json",
+            self.client.encode_path(report_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-07/reports/{report_id}.json",
+                    &[("report_id", report_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -530,29 +689,30 @@ impl Analytics {
         updated_at_max: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/reports.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-10/reports.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/reports.json", &[])
+        })
     }
 
     /**
@@ -573,19 +733,21 @@ impl Analytics {
         shopify_ql: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !name.is_empty() {
-            query_args.push(("name".to_string(), name.to_string()));
+            query_args.push(("name", name.to_string()));
         }
         if !shopify_ql.is_empty() {
-            query_args.push(("shopify_ql".to_string(), shopify_ql.to_string()));
+            query_args.push(("shopify_ql", shopify_ql.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/reports.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-10/reports.json", &query_args);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-10/reports.json", &[])
+            })
     }
 
     /**
@@ -601,18 +763,25 @@ impl Analytics {
      * * `fields: &str` -- A comma-separated list of fields to include in the response.
      */
     pub async fn get_reports_param_report(&self, report_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/reports/{}/json?{}",
-            crate::progenitor_support::encode_path(&report_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/reports/{}/json",
+                self.client.encode_path(report_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/reports/{report_id}.json",
+                &[("report_id", report_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -633,12 +802,19 @@ impl Analytics {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/reports/{}/json",
-            crate::progenitor_support::encode_path(&report_id.to_string()),
+            self.client.encode_path(report_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-10/reports/{report_id}.json",
+                    &[("report_id", report_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -655,10 +831,41 @@ impl Analytics {
     pub async fn delete_reports_param_report(&self, report_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/reports/{}/json",
-            crate::progenitor_support::encode_path(&report_id.to_string()),
+            self.client.encode_path(report_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-10/reports/{report_id}.json",
+                &[("report_id", report_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a report.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-10/reports/{report_id}.json` endpoint.
+     *
+     * As opposed to `delete_reports_param_report`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_reports_param_report_if_exists(&self, report_id: &str) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-10/reports/{}/json",
+            self.client.encode_path(report_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-10/reports/{report_id}.json",
+                    &[("report_id", report_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -687,29 +894,30 @@ impl Analytics {
         updated_at_max: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/reports.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2021-01/reports.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/reports.json", &[])
+        })
     }
 
     /**
@@ -730,19 +938,21 @@ impl Analytics {
         shopify_ql: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !name.is_empty() {
-            query_args.push(("name".to_string(), name.to_string()));
+            query_args.push(("name", name.to_string()));
         }
         if !shopify_ql.is_empty() {
-            query_args.push(("shopify_ql".to_string(), shopify_ql.to_string()));
+            query_args.push(("shopify_ql", shopify_ql.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/reports.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2021-01/reports.json", &query_args);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2021-01/reports.json", &[])
+            })
     }
 
     /**
@@ -762,18 +972,25 @@ impl Analytics {
         report_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/reports/{}/json?{}",
-            crate::progenitor_support::encode_path(&report_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/reports/{}/json",
+                self.client.encode_path(report_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/reports/{report_id}.json",
+                &[("report_id", report_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -794,12 +1011,19 @@ impl Analytics {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/reports/{}/json",
-            crate::progenitor_support::encode_path(&report_id.to_string()),
+            self.client.encode_path(report_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2021-01/reports/{report_id}.json",
+                    &[("report_id", report_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -819,10 +1043,44 @@ impl Analytics {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/reports/{}/json",
-            crate::progenitor_support::encode_path(&report_id.to_string()),
+            self.client.encode_path(report_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2021-01/reports/{report_id}.json",
+                &[("report_id", report_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a report.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2021-01/reports/{report_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_delete_reports_param_report`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202101_delete_reports_param_report_if_exists(
+        &self,
+        report_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2021-01/reports/{}/json",
+            self.client.encode_path(report_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2021-01/reports/{report_id}.json",
+                    &[("report_id", report_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -851,29 +1109,30 @@ impl Analytics {
         updated_at_max: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/reports.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/unstable/reports.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/reports.json", &[])
+        })
     }
 
     /**
@@ -894,19 +1153,21 @@ impl Analytics {
         shopify_ql: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !name.is_empty() {
-            query_args.push(("name".to_string(), name.to_string()));
+            query_args.push(("name", name.to_string()));
         }
         if !shopify_ql.is_empty() {
-            query_args.push(("shopify_ql".to_string(), shopify_ql.to_string()));
+            query_args.push(("shopify_ql", shopify_ql.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/reports.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/unstable/reports.json", &query_args);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/unstable/reports.json", &[])
+            })
     }
 
     /**
@@ -926,18 +1187,25 @@ impl Analytics {
         report_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/reports/{}/json?{}",
-            crate::progenitor_support::encode_path(&report_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/reports/{}/json",
+                self.client.encode_path(report_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/reports/{report_id}.json",
+                &[("report_id", report_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -958,12 +1226,19 @@ impl Analytics {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/reports/{}/json",
-            crate::progenitor_support::encode_path(&report_id.to_string()),
+            self.client.encode_path(report_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/unstable/reports/{report_id}.json",
+                    &[("report_id", report_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -983,9 +1258,528 @@ impl Analytics {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/reports/{}/json",
-            crate::progenitor_support::encode_path(&report_id.to_string()),
+            self.client.encode_path(report_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/unstable/reports/{report_id}.json",
+                &[("report_id", report_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a report.
+     *
+     * This function performs a `DELETE` to the `/admin/api/unstable/reports/{report_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_delete_reports_param_report`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_unstable_delete_reports_param_report_if_exists(
+        &self,
+        report_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/unstable/reports/{}/json",
+            self.client.encode_path(report_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/unstable/reports/{report_id}.json",
+                    &[("report_id", report_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`Analytics`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AnalyticsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AnalyticsApi: Send + Sync {
+    async fn deprecated_202001_get_report(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_reports(
+        &self,
+        name: &str,
+        shopify_ql: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_reports_param_report(
+        &self,
+        report_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_update_reports_param_report(
+        &self,
+        report_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_reports_param_report(&self, report_id: &str) -> Result<()>;
+    async fn deprecated_202001_delete_reports_param_report_if_exists(
+        &self,
+        report_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202004_get_report(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_create_reports(
+        &self,
+        name: &str,
+        shopify_ql: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_reports_param_report(
+        &self,
+        report_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_update_reports_param_report(
+        &self,
+        report_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_reports_param_report(&self, report_id: &str) -> Result<()>;
+    async fn deprecated_202004_delete_reports_param_report_if_exists(
+        &self,
+        report_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202007_get_report(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_create_reports(
+        &self,
+        name: &str,
+        shopify_ql: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_reports_param_report(
+        &self,
+        report_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_update_reports_param_report(
+        &self,
+        report_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_reports_param_report(&self, report_id: &str) -> Result<()>;
+    async fn deprecated_202007_delete_reports_param_report_if_exists(
+        &self,
+        report_id: &str,
+    ) -> Result<bool>;
+    async fn get_report(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn create_reports(
+        &self,
+        name: &str,
+        shopify_ql: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn get_reports_param_report(&self, report_id: &str, fields: &str) -> Result<()>;
+    async fn update_reports_param_report(
+        &self,
+        report_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn delete_reports_param_report(&self, report_id: &str) -> Result<()>;
+    async fn delete_reports_param_report_if_exists(&self, report_id: &str) -> Result<bool>;
+    async fn deprecated_202101_get_report(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_create_reports(
+        &self,
+        name: &str,
+        shopify_ql: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_reports_param_report(
+        &self,
+        report_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_update_reports_param_report(
+        &self,
+        report_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_reports_param_report(&self, report_id: &str) -> Result<()>;
+    async fn deprecated_202101_delete_reports_param_report_if_exists(
+        &self,
+        report_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_unstable_get_report(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create_reports(
+        &self,
+        name: &str,
+        shopify_ql: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_reports_param_report(
+        &self,
+        report_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_update_reports_param_report(
+        &self,
+        report_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_reports_param_report(&self, report_id: &str) -> Result<()>;
+    async fn deprecated_unstable_delete_reports_param_report_if_exists(
+        &self,
+        report_id: &str,
+    ) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AnalyticsApi for Analytics {
+    async fn deprecated_202001_get_report(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Analytics::deprecated_202001_get_report(
+            self,
+            ids,
+            limit,
+            since_id,
+            updated_at_min,
+            updated_at_max,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202001_create_reports(
+        &self,
+        name: &str,
+        shopify_ql: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Analytics::deprecated_202001_create_reports(self, name, shopify_ql, body).await
+    }
+    async fn deprecated_202001_get_reports_param_report(
+        &self,
+        report_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Analytics::deprecated_202001_get_reports_param_report(self, report_id, fields).await
+    }
+    async fn deprecated_202001_update_reports_param_report(
+        &self,
+        report_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Analytics::deprecated_202001_update_reports_param_report(self, report_id, body).await
+    }
+    async fn deprecated_202001_delete_reports_param_report(&self, report_id: &str) -> Result<()> {
+        Analytics::deprecated_202001_delete_reports_param_report(self, report_id).await
+    }
+    async fn deprecated_202001_delete_reports_param_report_if_exists(
+        &self,
+        report_id: &str,
+    ) -> Result<bool> {
+        Analytics::deprecated_202001_delete_reports_param_report_if_exists(self, report_id).await
+    }
+    async fn deprecated_202004_get_report(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Analytics::deprecated_202004_get_report(
+            self,
+            ids,
+            limit,
+            since_id,
+            updated_at_min,
+            updated_at_max,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202004_create_reports(
+        &self,
+        name: &str,
+        shopify_ql: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Analytics::deprecated_202004_create_reports(self, name, shopify_ql, body).await
+    }
+    async fn deprecated_202004_get_reports_param_report(
+        &self,
+        report_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Analytics::deprecated_202004_get_reports_param_report(self, report_id, fields).await
+    }
+    async fn deprecated_202004_update_reports_param_report(
+        &self,
+        report_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Analytics::deprecated_202004_update_reports_param_report(self, report_id, body).await
+    }
+    async fn deprecated_202004_delete_reports_param_report(&self, report_id: &str) -> Result<()> {
+        Analytics::deprecated_202004_delete_reports_param_report(self, report_id).await
+    }
+    async fn deprecated_202004_delete_reports_param_report_if_exists(
+        &self,
+        report_id: &str,
+    ) -> Result<bool> {
+        Analytics::deprecated_202004_delete_reports_param_report_if_exists(self, report_id).await
+    }
+    async fn deprecated_202007_get_report(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Analytics::deprecated_202007_get_report(
+            self,
+            ids,
+            limit,
+            since_id,
+            updated_at_min,
+            updated_at_max,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202007_create_reports(
+        &self,
+        name: &str,
+        shopify_ql: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Analytics::deprecated_202007_create_reports(self, name, shopify_ql, body).await
+    }
+    async fn deprecated_202007_get_reports_param_report(
+        &self,
+        report_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Analytics::deprecated_202007_get_reports_param_report(self, report_id, fields).await
+    }
+    async fn deprecated_202007_update_reports_param_report(
+        &self,
+        report_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Analytics::deprecated_202007_update_reports_param_report(self, report_id, body).await
+    }
+    async fn deprecated_202007_delete_reports_param_report(&self, report_id: &str) -> Result<()> {
+        Analytics::deprecated_202007_delete_reports_param_report(self, report_id).await
+    }
+    async fn deprecated_202007_delete_reports_param_report_if_exists(
+        &self,
+        report_id: &str,
+    ) -> Result<bool> {
+        Analytics::deprecated_202007_delete_reports_param_report_if_exists(self, report_id).await
+    }
+    async fn get_report(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Analytics::get_report(
+            self,
+            ids,
+            limit,
+            since_id,
+            updated_at_min,
+            updated_at_max,
+            fields,
+        )
+        .await
+    }
+    async fn create_reports(
+        &self,
+        name: &str,
+        shopify_ql: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Analytics::create_reports(self, name, shopify_ql, body).await
+    }
+    async fn get_reports_param_report(&self, report_id: &str, fields: &str) -> Result<()> {
+        Analytics::get_reports_param_report(self, report_id, fields).await
+    }
+    async fn update_reports_param_report(
+        &self,
+        report_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Analytics::update_reports_param_report(self, report_id, body).await
+    }
+    async fn delete_reports_param_report(&self, report_id: &str) -> Result<()> {
+        Analytics::delete_reports_param_report(self, report_id).await
+    }
+    async fn delete_reports_param_report_if_exists(&self, report_id: &str) -> Result<bool> {
+        Analytics::delete_reports_param_report_if_exists(self, report_id).await
+    }
+    async fn deprecated_202101_get_report(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Analytics::deprecated_202101_get_report(
+            self,
+            ids,
+            limit,
+            since_id,
+            updated_at_min,
+            updated_at_max,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202101_create_reports(
+        &self,
+        name: &str,
+        shopify_ql: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Analytics::deprecated_202101_create_reports(self, name, shopify_ql, body).await
+    }
+    async fn deprecated_202101_get_reports_param_report(
+        &self,
+        report_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Analytics::deprecated_202101_get_reports_param_report(self, report_id, fields).await
+    }
+    async fn deprecated_202101_update_reports_param_report(
+        &self,
+        report_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Analytics::deprecated_202101_update_reports_param_report(self, report_id, body).await
+    }
+    async fn deprecated_202101_delete_reports_param_report(&self, report_id: &str) -> Result<()> {
+        Analytics::deprecated_202101_delete_reports_param_report(self, report_id).await
+    }
+    async fn deprecated_202101_delete_reports_param_report_if_exists(
+        &self,
+        report_id: &str,
+    ) -> Result<bool> {
+        Analytics::deprecated_202101_delete_reports_param_report_if_exists(self, report_id).await
+    }
+    async fn deprecated_unstable_get_report(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Analytics::deprecated_unstable_get_report(
+            self,
+            ids,
+            limit,
+            since_id,
+            updated_at_min,
+            updated_at_max,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_unstable_create_reports(
+        &self,
+        name: &str,
+        shopify_ql: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Analytics::deprecated_unstable_create_reports(self, name, shopify_ql, body).await
+    }
+    async fn deprecated_unstable_get_reports_param_report(
+        &self,
+        report_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Analytics::deprecated_unstable_get_reports_param_report(self, report_id, fields).await
+    }
+    async fn deprecated_unstable_update_reports_param_report(
+        &self,
+        report_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Analytics::deprecated_unstable_update_reports_param_report(self, report_id, body).await
+    }
+    async fn deprecated_unstable_delete_reports_param_report(&self, report_id: &str) -> Result<()> {
+        Analytics::deprecated_unstable_delete_reports_param_report(self, report_id).await
+    }
+    async fn deprecated_unstable_delete_reports_param_report_if_exists(
+        &self,
+        report_id: &str,
+    ) -> Result<bool> {
+        Analytics::deprecated_unstable_delete_reports_param_report_if_exists(self, report_id).await
     }
 }
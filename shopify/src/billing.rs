@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Billing {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Billing {
         Billing { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Billing {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieves a list of application charges.
      *
@@ -29,17 +35,21 @@ impl Billing {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/application_charges.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-01/application_charges.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/application_charges.json", &[])
+        })
     }
 
     /**
@@ -55,8 +65,11 @@ impl Billing {
     ) -> Result<()> {
         let url = "/admin/api/2020-01/application_charges.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-01/application_charges.json", &[])
+            })
     }
 
     /**
@@ -76,18 +89,25 @@ impl Billing {
         application_charge_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/application_charges/{}/json?{}",
-            crate::progenitor_support::encode_path(&application_charge_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/application_charges/{}/json",
+                self.client.encode_path(application_charge_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/application_charges/{application_charge_id}.json",
+                &[("application_charge_id", application_charge_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -113,12 +133,19 @@ impl Billing {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/application_charges/{}/activate.json",
-            crate::progenitor_support::encode_path(&application_charge_id.to_string()),
+            self.client.encode_path(application_charge_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-01/application_charges/{application_charge_id}/activate.json",
+                    &[("application_charge_id", application_charge_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -138,17 +165,21 @@ impl Billing {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/application_charges.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-04/application_charges.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/application_charges.json", &[])
+        })
     }
 
     /**
@@ -164,8 +195,11 @@ impl Billing {
     ) -> Result<()> {
         let url = "/admin/api/2020-04/application_charges.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-04/application_charges.json", &[])
+            })
     }
 
     /**
@@ -185,18 +219,25 @@ impl Billing {
         application_charge_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/application_charges/{}/json?{}",
-            crate::progenitor_support::encode_path(&application_charge_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/application_charges/{}/json",
+                self.client.encode_path(application_charge_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/application_charges/{application_charge_id}.json",
+                &[("application_charge_id", application_charge_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -222,12 +263,19 @@ impl Billing {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/application_charges/{}/activate.json",
-            crate::progenitor_support::encode_path(&application_charge_id.to_string()),
+            self.client.encode_path(application_charge_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-04/application_charges/{application_charge_id}/activate.json",
+                    &[("application_charge_id", application_charge_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -247,17 +295,21 @@ impl Billing {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/application_charges.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-07/application_charges.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/application_charges.json", &[])
+        })
     }
 
     /**
@@ -273,8 +325,11 @@ impl Billing {
     ) -> Result<()> {
         let url = "/admin/api/2020-07/application_charges.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-07/application_charges.json", &[])
+            })
     }
 
     /**
@@ -294,18 +349,25 @@ impl Billing {
         application_charge_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/application_charges/{}/json?{}",
-            crate::progenitor_support::encode_path(&application_charge_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/application_charges/{}/json",
+                self.client.encode_path(application_charge_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/application_charges/{application_charge_id}.json",
+                &[("application_charge_id", application_charge_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -331,12 +393,19 @@ impl Billing {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/application_charges/{}/activate.json",
-            crate::progenitor_support::encode_path(&application_charge_id.to_string()),
+            self.client.encode_path(application_charge_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-07/application_charges/{application_charge_id}/activate.json",
+                    &[("application_charge_id", application_charge_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -352,17 +421,21 @@ impl Billing {
      * * `fields: &str` -- A comma-separated list of fields to include in the response.
      */
     pub async fn get_application_charge(&self, since_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/application_charges.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-10/application_charges.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/application_charges.json", &[])
+        })
     }
 
     /**
@@ -375,8 +448,11 @@ impl Billing {
     pub async fn create_application_charges(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-10/application_charges.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-10/application_charges.json", &[])
+            })
     }
 
     /**
@@ -396,18 +472,25 @@ impl Billing {
         application_charge_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/application_charges/{}/json?{}",
-            crate::progenitor_support::encode_path(&application_charge_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/application_charges/{}/json",
+                self.client.encode_path(application_charge_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/application_charges/{application_charge_id}.json",
+                &[("application_charge_id", application_charge_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -433,12 +516,19 @@ impl Billing {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/application_charges/{}/activate.json",
-            crate::progenitor_support::encode_path(&application_charge_id.to_string()),
+            self.client.encode_path(application_charge_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-10/application_charges/{application_charge_id}/activate.json",
+                    &[("application_charge_id", application_charge_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -458,17 +548,21 @@ impl Billing {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/application_charges.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2021-01/application_charges.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/application_charges.json", &[])
+        })
     }
 
     /**
@@ -484,8 +578,11 @@ impl Billing {
     ) -> Result<()> {
         let url = "/admin/api/2021-01/application_charges.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2021-01/application_charges.json", &[])
+            })
     }
 
     /**
@@ -505,18 +602,25 @@ impl Billing {
         application_charge_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/application_charges/{}/json?{}",
-            crate::progenitor_support::encode_path(&application_charge_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/application_charges/{}/json",
+                self.client.encode_path(application_charge_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/application_charges/{application_charge_id}.json",
+                &[("application_charge_id", application_charge_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -536,17 +640,21 @@ impl Billing {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/application_charges.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/unstable/application_charges.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/application_charges.json", &[])
+        })
     }
 
     /**
@@ -562,8 +670,15 @@ impl Billing {
     ) -> Result<()> {
         let url = "/admin/api/unstable/application_charges.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/unstable/application_charges.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -583,18 +698,25 @@ impl Billing {
         application_charge_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/application_charges/{}/json?{}",
-            crate::progenitor_support::encode_path(&application_charge_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/application_charges/{}/json",
+                self.client.encode_path(application_charge_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/application_charges/{application_charge_id}.json",
+                &[("application_charge_id", application_charge_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -609,14 +731,18 @@ impl Billing {
      * * `fields: &str` -- A comma-separated list of fields to include in the response.
      */
     pub async fn deprecated_202001_get_application_credit(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/application_credits.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-01/application_credits.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/application_credits.json", &[])
+        })
     }
 
     /**
@@ -632,8 +758,11 @@ impl Billing {
     ) -> Result<()> {
         let url = "/admin/api/2020-01/application_credits.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-01/application_credits.json", &[])
+            })
     }
 
     /**
@@ -653,18 +782,25 @@ impl Billing {
         application_credit_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/application_credits/{}/json?{}",
-            crate::progenitor_support::encode_path(&application_credit_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/application_credits/{}/json",
+                self.client.encode_path(application_credit_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/application_credits/{application_credit_id}.json",
+                &[("application_credit_id", application_credit_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -679,14 +815,18 @@ impl Billing {
      * * `fields: &str` -- A comma-separated list of fields to include in the response.
      */
     pub async fn deprecated_202004_get_application_credit(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/application_credits.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-04/application_credits.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/application_credits.json", &[])
+        })
     }
 
     /**
@@ -702,8 +842,11 @@ impl Billing {
     ) -> Result<()> {
         let url = "/admin/api/2020-04/application_credits.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-04/application_credits.json", &[])
+            })
     }
 
     /**
@@ -723,18 +866,25 @@ impl Billing {
         application_credit_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/application_credits/{}/json?{}",
-            crate::progenitor_support::encode_path(&application_credit_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/application_credits/{}/json",
+                self.client.encode_path(application_credit_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/application_credits/{application_credit_id}.json",
+                &[("application_credit_id", application_credit_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -749,14 +899,18 @@ impl Billing {
      * * `fields: &str` -- A comma-separated list of fields to include in the response.
      */
     pub async fn deprecated_202007_get_application_credit(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/application_credits.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-07/application_credits.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/application_credits.json", &[])
+        })
     }
 
     /**
@@ -772,8 +926,11 @@ impl Billing {
     ) -> Result<()> {
         let url = "/admin/api/2020-07/application_credits.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-07/application_credits.json", &[])
+            })
     }
 
     /**
@@ -793,18 +950,25 @@ impl Billing {
         application_credit_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/application_credits/{}/json?{}",
-            crate::progenitor_support::encode_path(&application_credit_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/application_credits/{}/json",
+                self.client.encode_path(application_credit_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/application_credits/{application_credit_id}.json",
+                &[("application_credit_id", application_credit_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -819,14 +983,18 @@ impl Billing {
      * * `fields: &str` -- A comma-separated list of fields to include in the response.
      */
     pub async fn get_application_credit(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/application_credits.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-10/application_credits.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/application_credits.json", &[])
+        })
     }
 
     /**
@@ -839,8 +1007,11 @@ impl Billing {
     pub async fn create_application_credits(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-10/application_credits.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-10/application_credits.json", &[])
+            })
     }
 
     /**
@@ -860,18 +1031,25 @@ impl Billing {
         application_credit_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/application_credits/{}/json?{}",
-            crate::progenitor_support::encode_path(&application_credit_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/application_credits/{}/json",
+                self.client.encode_path(application_credit_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/application_credits/{application_credit_id}.json",
+                &[("application_credit_id", application_credit_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -886,14 +1064,18 @@ impl Billing {
      * * `fields: &str` -- A comma-separated list of fields to include in the response.
      */
     pub async fn deprecated_202101_get_application_credit(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/application_credits.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2021-01/application_credits.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/application_credits.json", &[])
+        })
     }
 
     /**
@@ -909,8 +1091,11 @@ impl Billing {
     ) -> Result<()> {
         let url = "/admin/api/2021-01/application_credits.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2021-01/application_credits.json", &[])
+            })
     }
 
     /**
@@ -930,18 +1115,25 @@ impl Billing {
         application_credit_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/application_credits/{}/json?{}",
-            crate::progenitor_support::encode_path(&application_credit_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/application_credits/{}/json",
+                self.client.encode_path(application_credit_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/application_credits/{application_credit_id}.json",
+                &[("application_credit_id", application_credit_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -956,14 +1148,18 @@ impl Billing {
      * * `fields: &str` -- A comma-separated list of fields to include in the response.
      */
     pub async fn deprecated_unstable_get_application_credit(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/application_credits.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/unstable/application_credits.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/application_credits.json", &[])
+        })
     }
 
     /**
@@ -979,8 +1175,15 @@ impl Billing {
     ) -> Result<()> {
         let url = "/admin/api/unstable/application_credits.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/unstable/application_credits.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -1000,18 +1203,25 @@ impl Billing {
         application_credit_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/application_credits/{}/json?{}",
-            crate::progenitor_support::encode_path(&application_credit_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/application_credits/{}/json",
+                self.client.encode_path(application_credit_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/application_credits/{application_credit_id}.json",
+                &[("application_credit_id", application_credit_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1031,20 +1241,25 @@ impl Billing {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/recurring_application_charges.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-01/recurring_application_charges.json",
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/recurring_application_charges.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -1060,8 +1275,15 @@ impl Billing {
     ) -> Result<()> {
         let url = "/admin/api/2020-01/recurring_application_charges.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-01/recurring_application_charges.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -1081,18 +1303,19 @@ impl Billing {
         recurring_application_charge_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/recurring_application_charges/{}/json?{}",
-            crate::progenitor_support::encode_path(&recurring_application_charge_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/recurring_application_charges/{}/json",
+                self.client.encode_path(recurring_application_charge_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-01/recurring_application_charges/{recurring_application_charge_id}.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string())]))
     }
 
     /**
@@ -1112,10 +1335,29 @@ impl Billing {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/recurring_application_charges/{}/json",
-            crate::progenitor_support::encode_path(&recurring_application_charge_id.to_string()),
+            self.client.encode_path(recurring_application_charge_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/admin/api/2020-01/recurring_application_charges/{recurring_application_charge_id}.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string())]))
+    }
+
+    /**
+     * Cancels a recurring application charge.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-01/recurring_application_charges/{recurring_application_charge_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_delete_recurring_application_charges_param_charge`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202001_delete_recurring_application_charges_param_charge_if_exists(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-01/recurring_application_charges/{}/json",
+            self.client.encode_path(recurring_application_charge_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete_if_exists(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/admin/api/2020-01/recurring_application_charges/{recurring_application_charge_id}.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string())]))
     }
 
     /**
@@ -1141,12 +1383,12 @@ impl Billing {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/recurring_application_charges/{}/activate.json",
-            crate::progenitor_support::encode_path(&recurring_application_charge_id.to_string()),
+            self.client.encode_path(recurring_application_charge_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-01/recurring_application_charges/{recurring_application_charge_id}/activate.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string())]))
     }
 
     /**
@@ -1166,21 +1408,22 @@ impl Billing {
         recurring_application_charge_id: &str,
         recurring_application_charge_capped_amount: i64,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if recurring_application_charge_capped_amount > 0 {
             query_args.push((
-                "recurring_application_charge[capped_amount]".to_string(),
+                "recurring_application_charge[capped_amount]",
                 recurring_application_charge_capped_amount.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/recurring_application_charges/{}/customize.json?{}",
-            crate::progenitor_support::encode_path(&recurring_application_charge_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/recurring_application_charges/{}/customize.json",
+                self.client.encode_path(recurring_application_charge_id)
+            ),
+            &query_args,
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| crate::utils::pretty_url("PUT", "/admin/api/2020-01/recurring_application_charges/{recurring_application_charge_id}/customize.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string())]))
     }
 
     /**
@@ -1200,20 +1443,25 @@ impl Billing {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/recurring_application_charges.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-04/recurring_application_charges.json",
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/recurring_application_charges.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -1229,8 +1477,15 @@ impl Billing {
     ) -> Result<()> {
         let url = "/admin/api/2020-04/recurring_application_charges.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-04/recurring_application_charges.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -1250,18 +1505,19 @@ impl Billing {
         recurring_application_charge_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/recurring_application_charges/{}/json?{}",
-            crate::progenitor_support::encode_path(&recurring_application_charge_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/recurring_application_charges/{}/json",
+                self.client.encode_path(recurring_application_charge_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-04/recurring_application_charges/{recurring_application_charge_id}.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string())]))
     }
 
     /**
@@ -1281,10 +1537,29 @@ impl Billing {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/recurring_application_charges/{}/json",
-            crate::progenitor_support::encode_path(&recurring_application_charge_id.to_string()),
+            self.client.encode_path(recurring_application_charge_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/admin/api/2020-04/recurring_application_charges/{recurring_application_charge_id}.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string())]))
+    }
+
+    /**
+     * Cancels a recurring application charge.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-04/recurring_application_charges/{recurring_application_charge_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_delete_recurring_application_charges_param_charge`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202004_delete_recurring_application_charges_param_charge_if_exists(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-04/recurring_application_charges/{}/json",
+            self.client.encode_path(recurring_application_charge_id),
+        );
+
+        self.client.delete_if_exists(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/admin/api/2020-04/recurring_application_charges/{recurring_application_charge_id}.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string())]))
     }
 
     /**
@@ -1310,12 +1585,12 @@ impl Billing {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/recurring_application_charges/{}/activate.json",
-            crate::progenitor_support::encode_path(&recurring_application_charge_id.to_string()),
+            self.client.encode_path(recurring_application_charge_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-04/recurring_application_charges/{recurring_application_charge_id}/activate.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string())]))
     }
 
     /**
@@ -1335,21 +1610,22 @@ impl Billing {
         recurring_application_charge_id: &str,
         recurring_application_charge_capped_amount: i64,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if recurring_application_charge_capped_amount > 0 {
             query_args.push((
-                "recurring_application_charge[capped_amount]".to_string(),
+                "recurring_application_charge[capped_amount]",
                 recurring_application_charge_capped_amount.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/recurring_application_charges/{}/customize.json?{}",
-            crate::progenitor_support::encode_path(&recurring_application_charge_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/recurring_application_charges/{}/customize.json",
+                self.client.encode_path(recurring_application_charge_id)
+            ),
+            &query_args,
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| crate::utils::pretty_url("PUT", "/admin/api/2020-04/recurring_application_charges/{recurring_application_charge_id}/customize.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string())]))
     }
 
     /**
@@ -1369,20 +1645,25 @@ impl Billing {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/recurring_application_charges.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-07/recurring_application_charges.json",
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/recurring_application_charges.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -1398,8 +1679,15 @@ impl Billing {
     ) -> Result<()> {
         let url = "/admin/api/2020-07/recurring_application_charges.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-07/recurring_application_charges.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -1419,18 +1707,19 @@ impl Billing {
         recurring_application_charge_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/recurring_application_charges/{}/json?{}",
-            crate::progenitor_support::encode_path(&recurring_application_charge_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/recurring_application_charges/{}/json",
+                self.client.encode_path(recurring_application_charge_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-07/recurring_application_charges/{recurring_application_charge_id}.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string())]))
     }
 
     /**
@@ -1450,10 +1739,29 @@ impl Billing {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/recurring_application_charges/{}/json",
-            crate::progenitor_support::encode_path(&recurring_application_charge_id.to_string()),
+            self.client.encode_path(recurring_application_charge_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/admin/api/2020-07/recurring_application_charges/{recurring_application_charge_id}.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string())]))
+    }
+
+    /**
+     * Cancels a recurring application charge.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-07/recurring_application_charges/{recurring_application_charge_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_delete_recurring_application_charges_param_charge`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202007_delete_recurring_application_charges_param_charge_if_exists(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-07/recurring_application_charges/{}/json",
+            self.client.encode_path(recurring_application_charge_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete_if_exists(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/admin/api/2020-07/recurring_application_charges/{recurring_application_charge_id}.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string())]))
     }
 
     /**
@@ -1479,12 +1787,12 @@ impl Billing {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/recurring_application_charges/{}/activate.json",
-            crate::progenitor_support::encode_path(&recurring_application_charge_id.to_string()),
+            self.client.encode_path(recurring_application_charge_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-07/recurring_application_charges/{recurring_application_charge_id}/activate.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string())]))
     }
 
     /**
@@ -1504,21 +1812,22 @@ impl Billing {
         recurring_application_charge_id: &str,
         recurring_application_charge_capped_amount: i64,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if recurring_application_charge_capped_amount > 0 {
             query_args.push((
-                "recurring_application_charge[capped_amount]".to_string(),
+                "recurring_application_charge[capped_amount]",
                 recurring_application_charge_capped_amount.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/recurring_application_charges/{}/customize.json?{}",
-            crate::progenitor_support::encode_path(&recurring_application_charge_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/recurring_application_charges/{}/customize.json",
+                self.client.encode_path(recurring_application_charge_id)
+            ),
+            &query_args,
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| crate::utils::pretty_url("PUT", "/admin/api/2020-07/recurring_application_charges/{recurring_application_charge_id}/customize.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string())]))
     }
 
     /**
@@ -1538,20 +1847,25 @@ impl Billing {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/recurring_application_charges.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-10/recurring_application_charges.json",
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/recurring_application_charges.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -1567,8 +1881,15 @@ impl Billing {
     ) -> Result<()> {
         let url = "/admin/api/2020-10/recurring_application_charges.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-10/recurring_application_charges.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -1588,18 +1909,19 @@ impl Billing {
         recurring_application_charge_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/recurring_application_charges/{}/json?{}",
-            crate::progenitor_support::encode_path(&recurring_application_charge_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/recurring_application_charges/{}/json",
+                self.client.encode_path(recurring_application_charge_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-10/recurring_application_charges/{recurring_application_charge_id}.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string())]))
     }
 
     /**
@@ -1619,10 +1941,29 @@ impl Billing {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/recurring_application_charges/{}/json",
-            crate::progenitor_support::encode_path(&recurring_application_charge_id.to_string()),
+            self.client.encode_path(recurring_application_charge_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/admin/api/2020-10/recurring_application_charges/{recurring_application_charge_id}.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string())]))
+    }
+
+    /**
+     * Cancels a recurring application charge.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-10/recurring_application_charges/{recurring_application_charge_id}.json` endpoint.
+     *
+     * As opposed to `delete_recurring_application_charges_param_charge`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_recurring_application_charges_param_charge_if_exists(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-10/recurring_application_charges/{}/json",
+            self.client.encode_path(recurring_application_charge_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete_if_exists(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/admin/api/2020-10/recurring_application_charges/{recurring_application_charge_id}.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string())]))
     }
 
     /**
@@ -1648,12 +1989,12 @@ impl Billing {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/recurring_application_charges/{}/activate.json",
-            crate::progenitor_support::encode_path(&recurring_application_charge_id.to_string()),
+            self.client.encode_path(recurring_application_charge_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-10/recurring_application_charges/{recurring_application_charge_id}/activate.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string())]))
     }
 
     /**
@@ -1673,21 +2014,22 @@ impl Billing {
         recurring_application_charge_id: &str,
         recurring_application_charge_capped_amount: i64,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if recurring_application_charge_capped_amount > 0 {
             query_args.push((
-                "recurring_application_charge[capped_amount]".to_string(),
+                "recurring_application_charge[capped_amount]",
                 recurring_application_charge_capped_amount.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/recurring_application_charges/{}/customize.json?{}",
-            crate::progenitor_support::encode_path(&recurring_application_charge_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/recurring_application_charges/{}/customize.json",
+                self.client.encode_path(recurring_application_charge_id)
+            ),
+            &query_args,
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| crate::utils::pretty_url("PUT", "/admin/api/2020-10/recurring_application_charges/{recurring_application_charge_id}/customize.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string())]))
     }
 
     /**
@@ -1707,20 +2049,25 @@ impl Billing {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/recurring_application_charges.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/2021-01/recurring_application_charges.json",
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/recurring_application_charges.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -1736,8 +2083,15 @@ impl Billing {
     ) -> Result<()> {
         let url = "/admin/api/2021-01/recurring_application_charges.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2021-01/recurring_application_charges.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -1757,18 +2111,19 @@ impl Billing {
         recurring_application_charge_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/recurring_application_charges/{}/json?{}",
-            crate::progenitor_support::encode_path(&recurring_application_charge_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/recurring_application_charges/{}/json",
+                self.client.encode_path(recurring_application_charge_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/2021-01/recurring_application_charges/{recurring_application_charge_id}.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string())]))
     }
 
     /**
@@ -1788,10 +2143,29 @@ impl Billing {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/recurring_application_charges/{}/json",
-            crate::progenitor_support::encode_path(&recurring_application_charge_id.to_string()),
+            self.client.encode_path(recurring_application_charge_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/admin/api/2021-01/recurring_application_charges/{recurring_application_charge_id}.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string())]))
+    }
+
+    /**
+     * Cancels a recurring application charge.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2021-01/recurring_application_charges/{recurring_application_charge_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_delete_recurring_application_charges_param_charge`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202101_delete_recurring_application_charges_param_charge_if_exists(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2021-01/recurring_application_charges/{}/json",
+            self.client.encode_path(recurring_application_charge_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete_if_exists(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/admin/api/2021-01/recurring_application_charges/{recurring_application_charge_id}.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string())]))
     }
 
     /**
@@ -1811,21 +2185,22 @@ impl Billing {
         recurring_application_charge_id: &str,
         recurring_application_charge_capped_amount: i64,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if recurring_application_charge_capped_amount > 0 {
             query_args.push((
-                "recurring_application_charge[capped_amount]".to_string(),
+                "recurring_application_charge[capped_amount]",
                 recurring_application_charge_capped_amount.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/recurring_application_charges/{}/customize.json?{}",
-            crate::progenitor_support::encode_path(&recurring_application_charge_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/recurring_application_charges/{}/customize.json",
+                self.client.encode_path(recurring_application_charge_id)
+            ),
+            &query_args,
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| crate::utils::pretty_url("PUT", "/admin/api/2021-01/recurring_application_charges/{recurring_application_charge_id}/customize.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string())]))
     }
 
     /**
@@ -1845,20 +2220,25 @@ impl Billing {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/recurring_application_charges.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/unstable/recurring_application_charges.json",
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/recurring_application_charges.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -1874,8 +2254,15 @@ impl Billing {
     ) -> Result<()> {
         let url = "/admin/api/unstable/recurring_application_charges.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/unstable/recurring_application_charges.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -1895,18 +2282,19 @@ impl Billing {
         recurring_application_charge_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/recurring_application_charges/{}/json?{}",
-            crate::progenitor_support::encode_path(&recurring_application_charge_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/recurring_application_charges/{}/json",
+                self.client.encode_path(recurring_application_charge_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/unstable/recurring_application_charges/{recurring_application_charge_id}.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string())]))
     }
 
     /**
@@ -1926,10 +2314,29 @@ impl Billing {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/recurring_application_charges/{}/json",
-            crate::progenitor_support::encode_path(&recurring_application_charge_id.to_string()),
+            self.client.encode_path(recurring_application_charge_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/admin/api/unstable/recurring_application_charges/{recurring_application_charge_id}.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string())]))
+    }
+
+    /**
+     * Cancels a recurring application charge.
+     *
+     * This function performs a `DELETE` to the `/admin/api/unstable/recurring_application_charges/{recurring_application_charge_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_delete_recurring_application_charges_param_charge`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_unstable_delete_recurring_application_charges_param_charge_if_exists(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/unstable/recurring_application_charges/{}/json",
+            self.client.encode_path(recurring_application_charge_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete_if_exists(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/admin/api/unstable/recurring_application_charges/{recurring_application_charge_id}.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string())]))
     }
 
     /**
@@ -1949,21 +2356,22 @@ impl Billing {
         recurring_application_charge_id: &str,
         recurring_application_charge_capped_amount: i64,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if recurring_application_charge_capped_amount > 0 {
             query_args.push((
-                "recurring_application_charge[capped_amount]".to_string(),
+                "recurring_application_charge[capped_amount]",
                 recurring_application_charge_capped_amount.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/recurring_application_charges/{}/customize.json?{}",
-            crate::progenitor_support::encode_path(&recurring_application_charge_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/recurring_application_charges/{}/customize.json",
+                self.client.encode_path(recurring_application_charge_id)
+            ),
+            &query_args,
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| crate::utils::pretty_url("PUT", "/admin/api/unstable/recurring_application_charges/{recurring_application_charge_id}/customize.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string())]))
     }
 
     /**
@@ -1983,18 +2391,19 @@ impl Billing {
         recurring_application_charge_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/recurring_application_charges/{}/usage_charges.json?{}",
-            crate::progenitor_support::encode_path(&recurring_application_charge_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/recurring_application_charges/{}/usage_charges.json",
+                self.client.encode_path(recurring_application_charge_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-01/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string())]))
     }
 
     /**
@@ -2015,12 +2424,12 @@ impl Billing {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/recurring_application_charges/{}/usage_charges.json",
-            crate::progenitor_support::encode_path(&recurring_application_charge_id.to_string()),
+            self.client.encode_path(recurring_application_charge_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-01/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string())]))
     }
 
     /**
@@ -2042,19 +2451,20 @@ impl Billing {
         usage_charge_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/recurring_application_charges/{}/usage_charges/{}/json?{}",
-            crate::progenitor_support::encode_path(&recurring_application_charge_id.to_string()),
-            crate::progenitor_support::encode_path(&usage_charge_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/recurring_application_charges/{}/usage_charges/{}/json",
+                self.client.encode_path(recurring_application_charge_id),
+                self.client.encode_path(usage_charge_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-01/recurring_application_charges/{recurring_application_charge_id}/usage_charges/{usage_charge_id}.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string()), ("usage_charge_id", usage_charge_id.to_string())]))
     }
 
     /**
@@ -2074,18 +2484,19 @@ impl Billing {
         recurring_application_charge_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/recurring_application_charges/{}/usage_charges.json?{}",
-            crate::progenitor_support::encode_path(&recurring_application_charge_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/recurring_application_charges/{}/usage_charges.json",
+                self.client.encode_path(recurring_application_charge_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-04/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string())]))
     }
 
     /**
@@ -2106,12 +2517,12 @@ impl Billing {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/recurring_application_charges/{}/usage_charges.json",
-            crate::progenitor_support::encode_path(&recurring_application_charge_id.to_string()),
+            self.client.encode_path(recurring_application_charge_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-04/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string())]))
     }
 
     /**
@@ -2133,19 +2544,20 @@ impl Billing {
         usage_charge_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/recurring_application_charges/{}/usage_charges/{}/json?{}",
-            crate::progenitor_support::encode_path(&recurring_application_charge_id.to_string()),
-            crate::progenitor_support::encode_path(&usage_charge_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/recurring_application_charges/{}/usage_charges/{}/json",
+                self.client.encode_path(recurring_application_charge_id),
+                self.client.encode_path(usage_charge_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-04/recurring_application_charges/{recurring_application_charge_id}/usage_charges/{usage_charge_id}.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string()), ("usage_charge_id", usage_charge_id.to_string())]))
     }
 
     /**
@@ -2165,18 +2577,19 @@ impl Billing {
         recurring_application_charge_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/recurring_application_charges/{}/usage_charges.json?{}",
-            crate::progenitor_support::encode_path(&recurring_application_charge_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/recurring_application_charges/{}/usage_charges.json",
+                self.client.encode_path(recurring_application_charge_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-07/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string())]))
     }
 
     /**
@@ -2197,12 +2610,12 @@ impl Billing {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/recurring_application_charges/{}/usage_charges.json",
-            crate::progenitor_support::encode_path(&recurring_application_charge_id.to_string()),
+            self.client.encode_path(recurring_application_charge_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-07/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string())]))
     }
 
     /**
@@ -2224,19 +2637,20 @@ impl Billing {
         usage_charge_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/recurring_application_charges/{}/usage_charges/{}/json?{}",
-            crate::progenitor_support::encode_path(&recurring_application_charge_id.to_string()),
-            crate::progenitor_support::encode_path(&usage_charge_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/recurring_application_charges/{}/usage_charges/{}/json",
+                self.client.encode_path(recurring_application_charge_id),
+                self.client.encode_path(usage_charge_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-07/recurring_application_charges/{recurring_application_charge_id}/usage_charges/{usage_charge_id}.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string()), ("usage_charge_id", usage_charge_id.to_string())]))
     }
 
     /**
@@ -2256,18 +2670,19 @@ impl Billing {
         recurring_application_charge_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/recurring_application_charges/{}/usage_charges.json?{}",
-            crate::progenitor_support::encode_path(&recurring_application_charge_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/recurring_application_charges/{}/usage_charges.json",
+                self.client.encode_path(recurring_application_charge_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-10/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string())]))
     }
 
     /**
@@ -2288,12 +2703,12 @@ impl Billing {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/recurring_application_charges/{}/usage_charges.json",
-            crate::progenitor_support::encode_path(&recurring_application_charge_id.to_string()),
+            self.client.encode_path(recurring_application_charge_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-10/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string())]))
     }
 
     /**
@@ -2315,19 +2730,20 @@ impl Billing {
         usage_charge_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/recurring_application_charges/{}/usage_charges/{}/json?{}",
-            crate::progenitor_support::encode_path(&recurring_application_charge_id.to_string()),
-            crate::progenitor_support::encode_path(&usage_charge_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/recurring_application_charges/{}/usage_charges/{}/json",
+                self.client.encode_path(recurring_application_charge_id),
+                self.client.encode_path(usage_charge_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-10/recurring_application_charges/{recurring_application_charge_id}/usage_charges/{usage_charge_id}.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string()), ("usage_charge_id", usage_charge_id.to_string())]))
     }
 
     /**
@@ -2347,18 +2763,19 @@ impl Billing {
         recurring_application_charge_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/recurring_application_charges/{}/usage_charges.json?{}",
-            crate::progenitor_support::encode_path(&recurring_application_charge_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/recurring_application_charges/{}/usage_charges.json",
+                self.client.encode_path(recurring_application_charge_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/2021-01/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string())]))
     }
 
     /**
@@ -2379,12 +2796,12 @@ impl Billing {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/recurring_application_charges/{}/usage_charges.json",
-            crate::progenitor_support::encode_path(&recurring_application_charge_id.to_string()),
+            self.client.encode_path(recurring_application_charge_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2021-01/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string())]))
     }
 
     /**
@@ -2406,19 +2823,20 @@ impl Billing {
         usage_charge_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/recurring_application_charges/{}/usage_charges/{}/json?{}",
-            crate::progenitor_support::encode_path(&recurring_application_charge_id.to_string()),
-            crate::progenitor_support::encode_path(&usage_charge_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/recurring_application_charges/{}/usage_charges/{}/json",
+                self.client.encode_path(recurring_application_charge_id),
+                self.client.encode_path(usage_charge_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/2021-01/recurring_application_charges/{recurring_application_charge_id}/usage_charges/{usage_charge_id}.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string()), ("usage_charge_id", usage_charge_id.to_string())]))
     }
 
     /**
@@ -2438,18 +2856,19 @@ impl Billing {
         recurring_application_charge_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/recurring_application_charges/{}/usage_charges.json?{}",
-            crate::progenitor_support::encode_path(&recurring_application_charge_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/recurring_application_charges/{}/usage_charges.json",
+                self.client.encode_path(recurring_application_charge_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/unstable/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string())]))
     }
 
     /**
@@ -2470,12 +2889,12 @@ impl Billing {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/recurring_application_charges/{}/usage_charges.json",
-            crate::progenitor_support::encode_path(&recurring_application_charge_id.to_string()),
+            self.client.encode_path(recurring_application_charge_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/unstable/recurring_application_charges/{recurring_application_charge_id}/usage_charges.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string())]))
     }
 
     /**
@@ -2497,18 +2916,1368 @@ impl Billing {
         usage_charge_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/recurring_application_charges/{}/usage_charges/{}/json?{}",
-            crate::progenitor_support::encode_path(&recurring_application_charge_id.to_string()),
-            crate::progenitor_support::encode_path(&usage_charge_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/recurring_application_charges/{}/usage_charges/{}/json",
+                self.client.encode_path(recurring_application_charge_id),
+                self.client.encode_path(usage_charge_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/unstable/recurring_application_charges/{recurring_application_charge_id}/usage_charges/{usage_charge_id}.json", &[("recurring_application_charge_id", recurring_application_charge_id.to_string()), ("usage_charge_id", usage_charge_id.to_string())]))
+    }
+}
+
+/// The functions of [`Billing`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn BillingApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait BillingApi: Send + Sync {
+    async fn deprecated_202001_get_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_application_charges_param_charge(
+        &self,
+        application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_application_charges_param_charge_activate(
+        &self,
+        application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_create_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_application_charges_param_charge(
+        &self,
+        application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_create_application_charges_param_charge_activate(
+        &self,
+        application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_create_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_application_charges_param_charge(
+        &self,
+        application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_create_application_charges_param_charge_activate(
+        &self,
+        application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn get_application_charge(&self, since_id: &str, fields: &str) -> Result<()>;
+    async fn create_application_charges(&self, body: &serde_json::Value) -> Result<()>;
+    async fn get_application_charges_param_charge(
+        &self,
+        application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn create_application_charges_param_charge_activate(
+        &self,
+        application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_create_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_application_charges_param_charge(
+        &self,
+        application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_application_charges_param_charge(
+        &self,
+        application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_application_credit(&self, fields: &str) -> Result<()>;
+    async fn deprecated_202001_create_application_credits(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_application_credits_param_credit(
+        &self,
+        application_credit_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_application_credit(&self, fields: &str) -> Result<()>;
+    async fn deprecated_202004_create_application_credits(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_application_credits_param_credit(
+        &self,
+        application_credit_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_application_credit(&self, fields: &str) -> Result<()>;
+    async fn deprecated_202007_create_application_credits(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_application_credits_param_credit(
+        &self,
+        application_credit_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn get_application_credit(&self, fields: &str) -> Result<()>;
+    async fn create_application_credits(&self, body: &serde_json::Value) -> Result<()>;
+    async fn get_application_credits_param_credit(
+        &self,
+        application_credit_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_application_credit(&self, fields: &str) -> Result<()>;
+    async fn deprecated_202101_create_application_credits(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_application_credits_param_credit(
+        &self,
+        application_credit_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_application_credit(&self, fields: &str) -> Result<()>;
+    async fn deprecated_unstable_create_application_credits(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_application_credits_param_credit(
+        &self,
+        application_credit_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_recurring_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_recurring_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_recurring_application_charges_param_charge_if_exists(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202001_create_recurring_application_charges_param_charge_activate(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_update_recurring_application_charges_param_charge_customize(
+        &self,
+        recurring_application_charge_id: &str,
+        recurring_application_charge_capped_amount: i64,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_recurring_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_create_recurring_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_recurring_application_charges_param_charge_if_exists(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202004_create_recurring_application_charges_param_charge_activate(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_update_recurring_application_charges_param_charge_customize(
+        &self,
+        recurring_application_charge_id: &str,
+        recurring_application_charge_capped_amount: i64,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_recurring_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_create_recurring_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_recurring_application_charges_param_charge_if_exists(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202007_create_recurring_application_charges_param_charge_activate(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_update_recurring_application_charges_param_charge_customize(
+        &self,
+        recurring_application_charge_id: &str,
+        recurring_application_charge_capped_amount: i64,
+    ) -> Result<()>;
+    async fn get_recurring_application_charge(&self, since_id: &str, fields: &str) -> Result<()>;
+    async fn create_recurring_application_charges(&self, body: &serde_json::Value) -> Result<()>;
+    async fn get_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn delete_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<()>;
+    async fn delete_recurring_application_charges_param_charge_if_exists(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<bool>;
+    async fn create_recurring_application_charges_param_charge_activate(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn update_recurring_application_charges_param_charge_customize(
+        &self,
+        recurring_application_charge_id: &str,
+        recurring_application_charge_capped_amount: i64,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_recurring_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_create_recurring_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_recurring_application_charges_param_charge_if_exists(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202101_update_recurring_application_charges_param_charge_customize(
+        &self,
+        recurring_application_charge_id: &str,
+        recurring_application_charge_capped_amount: i64,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_recurring_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create_recurring_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_recurring_application_charges_param_charge_if_exists(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_unstable_update_recurring_application_charges_param_charge_customize(
+        &self,
+        recurring_application_charge_id: &str,
+        recurring_application_charge_capped_amount: i64,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_recurring_application_charges_param_charge_usage_billing(
+        &self,
+        recurring_application_charge_id: &str,
+        usage_charge_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_create_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_recurring_application_charges_param_charge_usage_billing(
+        &self,
+        recurring_application_charge_id: &str,
+        usage_charge_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_create_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_recurring_application_charges_param_charge_usage_billing(
+        &self,
+        recurring_application_charge_id: &str,
+        usage_charge_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn get_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn create_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn get_recurring_application_charges_param_charge_usage_billing(
+        &self,
+        recurring_application_charge_id: &str,
+        usage_charge_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_create_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_recurring_application_charges_param_charge_usage_billing(
+        &self,
+        recurring_application_charge_id: &str,
+        usage_charge_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_recurring_application_charges_param_charge_usage_billing(
+        &self,
+        recurring_application_charge_id: &str,
+        usage_charge_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl BillingApi for Billing {
+    async fn deprecated_202001_get_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Billing::deprecated_202001_get_application_charge(self, since_id, fields).await
+    }
+    async fn deprecated_202001_create_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202001_create_application_charges(self, body).await
+    }
+    async fn deprecated_202001_get_application_charges_param_charge(
+        &self,
+        application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Billing::deprecated_202001_get_application_charges_param_charge(
+            self,
+            application_charge_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202001_create_application_charges_param_charge_activate(
+        &self,
+        application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202001_create_application_charges_param_charge_activate(
+            self,
+            application_charge_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Billing::deprecated_202004_get_application_charge(self, since_id, fields).await
+    }
+    async fn deprecated_202004_create_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202004_create_application_charges(self, body).await
+    }
+    async fn deprecated_202004_get_application_charges_param_charge(
+        &self,
+        application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Billing::deprecated_202004_get_application_charges_param_charge(
+            self,
+            application_charge_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202004_create_application_charges_param_charge_activate(
+        &self,
+        application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202004_create_application_charges_param_charge_activate(
+            self,
+            application_charge_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Billing::deprecated_202007_get_application_charge(self, since_id, fields).await
+    }
+    async fn deprecated_202007_create_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202007_create_application_charges(self, body).await
+    }
+    async fn deprecated_202007_get_application_charges_param_charge(
+        &self,
+        application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Billing::deprecated_202007_get_application_charges_param_charge(
+            self,
+            application_charge_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202007_create_application_charges_param_charge_activate(
+        &self,
+        application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202007_create_application_charges_param_charge_activate(
+            self,
+            application_charge_id,
+            body,
+        )
+        .await
+    }
+    async fn get_application_charge(&self, since_id: &str, fields: &str) -> Result<()> {
+        Billing::get_application_charge(self, since_id, fields).await
+    }
+    async fn create_application_charges(&self, body: &serde_json::Value) -> Result<()> {
+        Billing::create_application_charges(self, body).await
+    }
+    async fn get_application_charges_param_charge(
+        &self,
+        application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Billing::get_application_charges_param_charge(self, application_charge_id, fields).await
+    }
+    async fn create_application_charges_param_charge_activate(
+        &self,
+        application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::create_application_charges_param_charge_activate(self, application_charge_id, body)
+            .await
+    }
+    async fn deprecated_202101_get_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Billing::deprecated_202101_get_application_charge(self, since_id, fields).await
+    }
+    async fn deprecated_202101_create_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202101_create_application_charges(self, body).await
+    }
+    async fn deprecated_202101_get_application_charges_param_charge(
+        &self,
+        application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Billing::deprecated_202101_get_application_charges_param_charge(
+            self,
+            application_charge_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Billing::deprecated_unstable_get_application_charge(self, since_id, fields).await
+    }
+    async fn deprecated_unstable_create_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_unstable_create_application_charges(self, body).await
+    }
+    async fn deprecated_unstable_get_application_charges_param_charge(
+        &self,
+        application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Billing::deprecated_unstable_get_application_charges_param_charge(
+            self,
+            application_charge_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202001_get_application_credit(&self, fields: &str) -> Result<()> {
+        Billing::deprecated_202001_get_application_credit(self, fields).await
+    }
+    async fn deprecated_202001_create_application_credits(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202001_create_application_credits(self, body).await
+    }
+    async fn deprecated_202001_get_application_credits_param_credit(
+        &self,
+        application_credit_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Billing::deprecated_202001_get_application_credits_param_credit(
+            self,
+            application_credit_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_application_credit(&self, fields: &str) -> Result<()> {
+        Billing::deprecated_202004_get_application_credit(self, fields).await
+    }
+    async fn deprecated_202004_create_application_credits(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202004_create_application_credits(self, body).await
+    }
+    async fn deprecated_202004_get_application_credits_param_credit(
+        &self,
+        application_credit_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Billing::deprecated_202004_get_application_credits_param_credit(
+            self,
+            application_credit_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_application_credit(&self, fields: &str) -> Result<()> {
+        Billing::deprecated_202007_get_application_credit(self, fields).await
+    }
+    async fn deprecated_202007_create_application_credits(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202007_create_application_credits(self, body).await
+    }
+    async fn deprecated_202007_get_application_credits_param_credit(
+        &self,
+        application_credit_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Billing::deprecated_202007_get_application_credits_param_credit(
+            self,
+            application_credit_id,
+            fields,
+        )
+        .await
+    }
+    async fn get_application_credit(&self, fields: &str) -> Result<()> {
+        Billing::get_application_credit(self, fields).await
+    }
+    async fn create_application_credits(&self, body: &serde_json::Value) -> Result<()> {
+        Billing::create_application_credits(self, body).await
+    }
+    async fn get_application_credits_param_credit(
+        &self,
+        application_credit_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Billing::get_application_credits_param_credit(self, application_credit_id, fields).await
+    }
+    async fn deprecated_202101_get_application_credit(&self, fields: &str) -> Result<()> {
+        Billing::deprecated_202101_get_application_credit(self, fields).await
+    }
+    async fn deprecated_202101_create_application_credits(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202101_create_application_credits(self, body).await
+    }
+    async fn deprecated_202101_get_application_credits_param_credit(
+        &self,
+        application_credit_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Billing::deprecated_202101_get_application_credits_param_credit(
+            self,
+            application_credit_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_application_credit(&self, fields: &str) -> Result<()> {
+        Billing::deprecated_unstable_get_application_credit(self, fields).await
+    }
+    async fn deprecated_unstable_create_application_credits(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_unstable_create_application_credits(self, body).await
+    }
+    async fn deprecated_unstable_get_application_credits_param_credit(
+        &self,
+        application_credit_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Billing::deprecated_unstable_get_application_credits_param_credit(
+            self,
+            application_credit_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202001_get_recurring_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Billing::deprecated_202001_get_recurring_application_charge(self, since_id, fields).await
+    }
+    async fn deprecated_202001_create_recurring_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202001_create_recurring_application_charges(self, body).await
+    }
+    async fn deprecated_202001_get_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Billing::deprecated_202001_get_recurring_application_charges_param_charge(
+            self,
+            recurring_application_charge_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202001_delete_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<()> {
+        Billing::deprecated_202001_delete_recurring_application_charges_param_charge(
+            self,
+            recurring_application_charge_id,
+        )
+        .await
+    }
+    async fn deprecated_202001_delete_recurring_application_charges_param_charge_if_exists(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<bool> {
+        Billing::deprecated_202001_delete_recurring_application_charges_param_charge_if_exists(
+            self,
+            recurring_application_charge_id,
+        )
+        .await
+    }
+    async fn deprecated_202001_create_recurring_application_charges_param_charge_activate(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202001_create_recurring_application_charges_param_charge_activate(
+            self,
+            recurring_application_charge_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202001_update_recurring_application_charges_param_charge_customize(
+        &self,
+        recurring_application_charge_id: &str,
+        recurring_application_charge_capped_amount: i64,
+    ) -> Result<()> {
+        Billing::deprecated_202001_update_recurring_application_charges_param_charge_customize(
+            self,
+            recurring_application_charge_id,
+            recurring_application_charge_capped_amount,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_recurring_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Billing::deprecated_202004_get_recurring_application_charge(self, since_id, fields).await
+    }
+    async fn deprecated_202004_create_recurring_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202004_create_recurring_application_charges(self, body).await
+    }
+    async fn deprecated_202004_get_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Billing::deprecated_202004_get_recurring_application_charges_param_charge(
+            self,
+            recurring_application_charge_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202004_delete_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<()> {
+        Billing::deprecated_202004_delete_recurring_application_charges_param_charge(
+            self,
+            recurring_application_charge_id,
+        )
+        .await
+    }
+    async fn deprecated_202004_delete_recurring_application_charges_param_charge_if_exists(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<bool> {
+        Billing::deprecated_202004_delete_recurring_application_charges_param_charge_if_exists(
+            self,
+            recurring_application_charge_id,
+        )
+        .await
+    }
+    async fn deprecated_202004_create_recurring_application_charges_param_charge_activate(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202004_create_recurring_application_charges_param_charge_activate(
+            self,
+            recurring_application_charge_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202004_update_recurring_application_charges_param_charge_customize(
+        &self,
+        recurring_application_charge_id: &str,
+        recurring_application_charge_capped_amount: i64,
+    ) -> Result<()> {
+        Billing::deprecated_202004_update_recurring_application_charges_param_charge_customize(
+            self,
+            recurring_application_charge_id,
+            recurring_application_charge_capped_amount,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_recurring_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Billing::deprecated_202007_get_recurring_application_charge(self, since_id, fields).await
+    }
+    async fn deprecated_202007_create_recurring_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202007_create_recurring_application_charges(self, body).await
+    }
+    async fn deprecated_202007_get_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Billing::deprecated_202007_get_recurring_application_charges_param_charge(
+            self,
+            recurring_application_charge_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202007_delete_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<()> {
+        Billing::deprecated_202007_delete_recurring_application_charges_param_charge(
+            self,
+            recurring_application_charge_id,
+        )
+        .await
+    }
+    async fn deprecated_202007_delete_recurring_application_charges_param_charge_if_exists(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<bool> {
+        Billing::deprecated_202007_delete_recurring_application_charges_param_charge_if_exists(
+            self,
+            recurring_application_charge_id,
+        )
+        .await
+    }
+    async fn deprecated_202007_create_recurring_application_charges_param_charge_activate(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202007_create_recurring_application_charges_param_charge_activate(
+            self,
+            recurring_application_charge_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202007_update_recurring_application_charges_param_charge_customize(
+        &self,
+        recurring_application_charge_id: &str,
+        recurring_application_charge_capped_amount: i64,
+    ) -> Result<()> {
+        Billing::deprecated_202007_update_recurring_application_charges_param_charge_customize(
+            self,
+            recurring_application_charge_id,
+            recurring_application_charge_capped_amount,
+        )
+        .await
+    }
+    async fn get_recurring_application_charge(&self, since_id: &str, fields: &str) -> Result<()> {
+        Billing::get_recurring_application_charge(self, since_id, fields).await
+    }
+    async fn create_recurring_application_charges(&self, body: &serde_json::Value) -> Result<()> {
+        Billing::create_recurring_application_charges(self, body).await
+    }
+    async fn get_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Billing::get_recurring_application_charges_param_charge(
+            self,
+            recurring_application_charge_id,
+            fields,
+        )
+        .await
+    }
+    async fn delete_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<()> {
+        Billing::delete_recurring_application_charges_param_charge(
+            self,
+            recurring_application_charge_id,
+        )
+        .await
+    }
+    async fn delete_recurring_application_charges_param_charge_if_exists(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<bool> {
+        Billing::delete_recurring_application_charges_param_charge_if_exists(
+            self,
+            recurring_application_charge_id,
+        )
+        .await
+    }
+    async fn create_recurring_application_charges_param_charge_activate(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::create_recurring_application_charges_param_charge_activate(
+            self,
+            recurring_application_charge_id,
+            body,
+        )
+        .await
+    }
+    async fn update_recurring_application_charges_param_charge_customize(
+        &self,
+        recurring_application_charge_id: &str,
+        recurring_application_charge_capped_amount: i64,
+    ) -> Result<()> {
+        Billing::update_recurring_application_charges_param_charge_customize(
+            self,
+            recurring_application_charge_id,
+            recurring_application_charge_capped_amount,
+        )
+        .await
+    }
+    async fn deprecated_202101_get_recurring_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Billing::deprecated_202101_get_recurring_application_charge(self, since_id, fields).await
+    }
+    async fn deprecated_202101_create_recurring_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202101_create_recurring_application_charges(self, body).await
+    }
+    async fn deprecated_202101_get_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Billing::deprecated_202101_get_recurring_application_charges_param_charge(
+            self,
+            recurring_application_charge_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202101_delete_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<()> {
+        Billing::deprecated_202101_delete_recurring_application_charges_param_charge(
+            self,
+            recurring_application_charge_id,
+        )
+        .await
+    }
+    async fn deprecated_202101_delete_recurring_application_charges_param_charge_if_exists(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<bool> {
+        Billing::deprecated_202101_delete_recurring_application_charges_param_charge_if_exists(
+            self,
+            recurring_application_charge_id,
+        )
+        .await
+    }
+    async fn deprecated_202101_update_recurring_application_charges_param_charge_customize(
+        &self,
+        recurring_application_charge_id: &str,
+        recurring_application_charge_capped_amount: i64,
+    ) -> Result<()> {
+        Billing::deprecated_202101_update_recurring_application_charges_param_charge_customize(
+            self,
+            recurring_application_charge_id,
+            recurring_application_charge_capped_amount,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_recurring_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Billing::deprecated_unstable_get_recurring_application_charge(self, since_id, fields).await
+    }
+    async fn deprecated_unstable_create_recurring_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_unstable_create_recurring_application_charges(self, body).await
+    }
+    async fn deprecated_unstable_get_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Billing::deprecated_unstable_get_recurring_application_charges_param_charge(
+            self,
+            recurring_application_charge_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_unstable_delete_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<()> {
+        Billing::deprecated_unstable_delete_recurring_application_charges_param_charge(
+            self,
+            recurring_application_charge_id,
+        )
+        .await
+    }
+    async fn deprecated_unstable_delete_recurring_application_charges_param_charge_if_exists(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<bool> {
+        Billing::deprecated_unstable_delete_recurring_application_charges_param_charge_if_exists(
+            self,
+            recurring_application_charge_id,
+        )
+        .await
+    }
+    async fn deprecated_unstable_update_recurring_application_charges_param_charge_customize(
+        &self,
+        recurring_application_charge_id: &str,
+        recurring_application_charge_capped_amount: i64,
+    ) -> Result<()> {
+        Billing::deprecated_unstable_update_recurring_application_charges_param_charge_customize(
+            self,
+            recurring_application_charge_id,
+            recurring_application_charge_capped_amount,
+        )
+        .await
+    }
+    async fn deprecated_202001_get_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Billing::deprecated_202001_get_recurring_application_charges_param_charge_usage(
+            self,
+            recurring_application_charge_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202001_create_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202001_create_recurring_application_charges_param_charge_usage(
+            self,
+            recurring_application_charge_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202001_get_recurring_application_charges_param_charge_usage_billing(
+        &self,
+        recurring_application_charge_id: &str,
+        usage_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Billing::deprecated_202001_get_recurring_application_charges_param_charge_usage_billing(
+            self,
+            recurring_application_charge_id,
+            usage_charge_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Billing::deprecated_202004_get_recurring_application_charges_param_charge_usage(
+            self,
+            recurring_application_charge_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202004_create_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202004_create_recurring_application_charges_param_charge_usage(
+            self,
+            recurring_application_charge_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_recurring_application_charges_param_charge_usage_billing(
+        &self,
+        recurring_application_charge_id: &str,
+        usage_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Billing::deprecated_202004_get_recurring_application_charges_param_charge_usage_billing(
+            self,
+            recurring_application_charge_id,
+            usage_charge_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Billing::deprecated_202007_get_recurring_application_charges_param_charge_usage(
+            self,
+            recurring_application_charge_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202007_create_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202007_create_recurring_application_charges_param_charge_usage(
+            self,
+            recurring_application_charge_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_recurring_application_charges_param_charge_usage_billing(
+        &self,
+        recurring_application_charge_id: &str,
+        usage_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Billing::deprecated_202007_get_recurring_application_charges_param_charge_usage_billing(
+            self,
+            recurring_application_charge_id,
+            usage_charge_id,
+            fields,
+        )
+        .await
+    }
+    async fn get_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Billing::get_recurring_application_charges_param_charge_usage(
+            self,
+            recurring_application_charge_id,
+            fields,
+        )
+        .await
+    }
+    async fn create_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::create_recurring_application_charges_param_charge_usage(
+            self,
+            recurring_application_charge_id,
+            body,
+        )
+        .await
+    }
+    async fn get_recurring_application_charges_param_charge_usage_billing(
+        &self,
+        recurring_application_charge_id: &str,
+        usage_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Billing::get_recurring_application_charges_param_charge_usage_billing(
+            self,
+            recurring_application_charge_id,
+            usage_charge_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202101_get_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Billing::deprecated_202101_get_recurring_application_charges_param_charge_usage(
+            self,
+            recurring_application_charge_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202101_create_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202101_create_recurring_application_charges_param_charge_usage(
+            self,
+            recurring_application_charge_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202101_get_recurring_application_charges_param_charge_usage_billing(
+        &self,
+        recurring_application_charge_id: &str,
+        usage_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Billing::deprecated_202101_get_recurring_application_charges_param_charge_usage_billing(
+            self,
+            recurring_application_charge_id,
+            usage_charge_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Billing::deprecated_unstable_get_recurring_application_charges_param_charge_usage(
+            self,
+            recurring_application_charge_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_unstable_create_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_unstable_create_recurring_application_charges_param_charge_usage(
+            self,
+            recurring_application_charge_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_recurring_application_charges_param_charge_usage_billing(
+        &self,
+        recurring_application_charge_id: &str,
+        usage_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Billing::deprecated_unstable_get_recurring_application_charges_param_charge_usage_billing(
+            self,
+            recurring_application_charge_id,
+            usage_charge_id,
+            fields,
+        )
+        .await
     }
 }
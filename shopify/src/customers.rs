@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Customers {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Customers {
         Customers { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Customers {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieves a list of customers. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -42,35 +48,36 @@ impl Customers {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/customers.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-01/customers.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/customers.json", &[])
+        })
     }
 
     /**
@@ -83,8 +90,11 @@ impl Customers {
     pub async fn deprecated_202001_create(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-01/customers.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-01/customers.json", &[])
+            })
     }
 
     /**
@@ -110,23 +120,25 @@ impl Customers {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/customers/search.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-01/customers/search.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/customers/search.json", &[])
+        })
     }
 
     /**
@@ -142,18 +154,25 @@ impl Customers {
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
     pub async fn deprecated_202001_get_param(&self, customer_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/customers/{}/json?{}",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/customers/{}/json",
+                self.client.encode_path(customer_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/customers/{customer_id}.json",
+                &[("customer_id", customer_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -174,12 +193,19 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/customers/{}/json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-01/customers/{customer_id}.json",
+                    &[("customer_id", customer_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -196,10 +222,44 @@ impl Customers {
     pub async fn deprecated_202001_delete_param(&self, customer_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/customers/{}/json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-01/customers/{customer_id}.json",
+                &[("customer_id", customer_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a customer. A customer can't be deleted if they have existing orders.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-01/customers/{customer_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_delete_param`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202001_delete_param_if_exists(
+        &self,
+        customer_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-01/customers/{}/json",
+            self.client.encode_path(customer_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-01/customers/{customer_id}.json",
+                    &[("customer_id", customer_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -221,12 +281,19 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/customers/{}/account_activation_url.json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-01/customers/{customer_id}/account_activation_url.json",
+                    &[("customer_id", customer_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -247,12 +314,19 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/customers/{}/send_invite.json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-01/customers/{customer_id}/send_invite.json",
+                    &[("customer_id", customer_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -264,7 +338,9 @@ impl Customers {
      */
     pub async fn deprecated_202001_get_count(&self) -> Result<()> {
         let url = "/admin/api/2020-01/customers/count.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/customers/count.json", &[])
+        })
     }
 
     /**
@@ -281,10 +357,16 @@ impl Customers {
     pub async fn deprecated_202001_get_param_order(&self, customer_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/customers/{}/orders.json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/customers/{customer_id}/orders.json",
+                &[("customer_id", customer_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -317,35 +399,36 @@ impl Customers {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/customers.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-04/customers.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/customers.json", &[])
+        })
     }
 
     /**
@@ -358,8 +441,11 @@ impl Customers {
     pub async fn deprecated_202004_create(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-04/customers.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-04/customers.json", &[])
+            })
     }
 
     /**
@@ -385,23 +471,25 @@ impl Customers {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/customers/search.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-04/customers/search.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/customers/search.json", &[])
+        })
     }
 
     /**
@@ -417,18 +505,25 @@ impl Customers {
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
     pub async fn deprecated_202004_get_param(&self, customer_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/customers/{}/json?{}",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/customers/{}/json",
+                self.client.encode_path(customer_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/customers/{customer_id}.json",
+                &[("customer_id", customer_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -449,12 +544,19 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/customers/{}/json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-04/customers/{customer_id}.json",
+                    &[("customer_id", customer_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -471,10 +573,44 @@ impl Customers {
     pub async fn deprecated_202004_delete_param(&self, customer_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/customers/{}/json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-04/customers/{customer_id}.json",
+                &[("customer_id", customer_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a customer. A customer can't be deleted if they have existing orders.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-04/customers/{customer_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_delete_param`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202004_delete_param_if_exists(
+        &self,
+        customer_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-04/customers/{}/json",
+            self.client.encode_path(customer_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-04/customers/{customer_id}.json",
+                    &[("customer_id", customer_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -496,12 +632,19 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/customers/{}/account_activation_url.json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-04/customers/{customer_id}/account_activation_url.json",
+                    &[("customer_id", customer_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -522,12 +665,19 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/customers/{}/send_invite.json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-04/customers/{customer_id}/send_invite.json",
+                    &[("customer_id", customer_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -539,7 +689,9 @@ impl Customers {
      */
     pub async fn deprecated_202004_get_count(&self) -> Result<()> {
         let url = "/admin/api/2020-04/customers/count.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/customers/count.json", &[])
+        })
     }
 
     /**
@@ -556,10 +708,16 @@ impl Customers {
     pub async fn deprecated_202004_get_param_order(&self, customer_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/customers/{}/orders.json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/customers/{customer_id}/orders.json",
+                &[("customer_id", customer_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -592,35 +750,36 @@ impl Customers {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/customers.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-07/customers.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/customers.json", &[])
+        })
     }
 
     /**
@@ -633,8 +792,11 @@ impl Customers {
     pub async fn deprecated_202007_create(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-07/customers.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-07/customers.json", &[])
+            })
     }
 
     /**
@@ -660,23 +822,25 @@ impl Customers {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/customers/search.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-07/customers/search.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/customers/search.json", &[])
+        })
     }
 
     /**
@@ -692,18 +856,25 @@ impl Customers {
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
     pub async fn deprecated_202007_get_param(&self, customer_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/customers/{}/json?{}",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/customers/{}/json",
+                self.client.encode_path(customer_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/customers/{customer_id}.json",
+                &[("customer_id", customer_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -724,12 +895,19 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/customers/{}/json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-07/customers/{customer_id}.json",
+                    &[("customer_id", customer_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -746,10 +924,44 @@ impl Customers {
     pub async fn deprecated_202007_delete_param(&self, customer_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/customers/{}/json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-07/customers/{customer_id}.json",
+                &[("customer_id", customer_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a customer. A customer can't be deleted if they have existing orders.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-07/customers/{customer_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_delete_param`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202007_delete_param_if_exists(
+        &self,
+        customer_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-07/customers/{}/json",
+            self.client.encode_path(customer_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-07/customers/{customer_id}.json",
+                    &[("customer_id", customer_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -771,12 +983,19 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/customers/{}/account_activation_url.json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-07/customers/{customer_id}/account_activation_url.json",
+                    &[("customer_id", customer_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -797,12 +1016,19 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/customers/{}/send_invite.json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-07/customers/{customer_id}/send_invite.json",
+                    &[("customer_id", customer_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -814,7 +1040,9 @@ impl Customers {
      */
     pub async fn deprecated_202007_get_count(&self) -> Result<()> {
         let url = "/admin/api/2020-07/customers/count.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/customers/count.json", &[])
+        })
     }
 
     /**
@@ -831,10 +1059,16 @@ impl Customers {
     pub async fn deprecated_202007_get_param_order(&self, customer_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/customers/{}/orders.json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/customers/{customer_id}/orders.json",
+                &[("customer_id", customer_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -867,35 +1101,36 @@ impl Customers {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/customers.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-10/customers.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/customers.json", &[])
+        })
     }
 
     /**
@@ -908,8 +1143,11 @@ impl Customers {
     pub async fn create(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-10/customers.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-10/customers.json", &[])
+            })
     }
 
     /**
@@ -935,23 +1173,25 @@ impl Customers {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/customers/search.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-10/customers/search.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/customers/search.json", &[])
+        })
     }
 
     /**
@@ -967,18 +1207,25 @@ impl Customers {
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
     pub async fn get_param(&self, customer_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/customers/{}/json?{}",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/customers/{}/json",
+                self.client.encode_path(customer_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/customers/{customer_id}.json",
+                &[("customer_id", customer_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -995,12 +1242,19 @@ impl Customers {
     pub async fn update_param(&self, customer_id: &str, body: &serde_json::Value) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/customers/{}/json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-10/customers/{customer_id}.json",
+                    &[("customer_id", customer_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1017,10 +1271,41 @@ impl Customers {
     pub async fn delete_param(&self, customer_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/customers/{}/json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-10/customers/{customer_id}.json",
+                &[("customer_id", customer_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a customer. A customer can't be deleted if they have existing orders.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-10/customers/{customer_id}.json` endpoint.
+     *
+     * As opposed to `delete_param`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_param_if_exists(&self, customer_id: &str) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-10/customers/{}/json",
+            self.client.encode_path(customer_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-10/customers/{customer_id}.json",
+                    &[("customer_id", customer_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1042,12 +1327,19 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/customers/{}/account_activation_url.json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-10/customers/{customer_id}/account_activation_url.json",
+                    &[("customer_id", customer_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1068,12 +1360,19 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/customers/{}/send_invite.json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-10/customers/{customer_id}/send_invite.json",
+                    &[("customer_id", customer_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1085,7 +1384,9 @@ impl Customers {
      */
     pub async fn get_count(&self) -> Result<()> {
         let url = "/admin/api/2020-10/customers/count.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/customers/count.json", &[])
+        })
     }
 
     /**
@@ -1102,10 +1403,16 @@ impl Customers {
     pub async fn get_param_order(&self, customer_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/customers/{}/orders.json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/customers/{customer_id}/orders.json",
+                &[("customer_id", customer_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1138,35 +1445,36 @@ impl Customers {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/customers.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2021-01/customers.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/customers.json", &[])
+        })
     }
 
     /**
@@ -1179,8 +1487,11 @@ impl Customers {
     pub async fn deprecated_202101_create(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2021-01/customers.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2021-01/customers.json", &[])
+            })
     }
 
     /**
@@ -1206,23 +1517,25 @@ impl Customers {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/customers/search.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2021-01/customers/search.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/customers/search.json", &[])
+        })
     }
 
     /**
@@ -1238,18 +1551,25 @@ impl Customers {
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
     pub async fn deprecated_202101_get_param(&self, customer_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/customers/{}/json?{}",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/customers/{}/json",
+                self.client.encode_path(customer_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/customers/{customer_id}.json",
+                &[("customer_id", customer_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1270,12 +1590,19 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/customers/{}/json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2021-01/customers/{customer_id}.json",
+                    &[("customer_id", customer_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1292,10 +1619,44 @@ impl Customers {
     pub async fn deprecated_202101_delete_param(&self, customer_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/customers/{}/json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2021-01/customers/{customer_id}.json",
+                &[("customer_id", customer_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a customer. A customer can't be deleted if they have existing orders.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2021-01/customers/{customer_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_delete_param`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202101_delete_param_if_exists(
+        &self,
+        customer_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2021-01/customers/{}/json",
+            self.client.encode_path(customer_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2021-01/customers/{customer_id}.json",
+                    &[("customer_id", customer_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1317,12 +1678,19 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/customers/{}/account_activation_url.json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2021-01/customers/{customer_id}/account_activation_url.json",
+                    &[("customer_id", customer_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1343,12 +1711,19 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/customers/{}/send_invite.json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2021-01/customers/{customer_id}/send_invite.json",
+                    &[("customer_id", customer_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1360,7 +1735,9 @@ impl Customers {
      */
     pub async fn deprecated_202101_get_count(&self) -> Result<()> {
         let url = "/admin/api/2021-01/customers/count.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/customers/count.json", &[])
+        })
     }
 
     /**
@@ -1377,10 +1754,16 @@ impl Customers {
     pub async fn deprecated_202101_get_param_order(&self, customer_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/customers/{}/orders.json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/customers/{customer_id}/orders.json",
+                &[("customer_id", customer_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1413,35 +1796,36 @@ impl Customers {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/customers.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/unstable/customers.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/customers.json", &[])
+        })
     }
 
     /**
@@ -1454,8 +1838,11 @@ impl Customers {
     pub async fn deprecated_unstable_create(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/unstable/customers.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/unstable/customers.json", &[])
+            })
     }
 
     /**
@@ -1481,23 +1868,25 @@ impl Customers {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/customers/search.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/unstable/customers/search.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/customers/search.json", &[])
+        })
     }
 
     /**
@@ -1517,18 +1906,25 @@ impl Customers {
         customer_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/customers/{}/json?{}",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/customers/{}/json",
+                self.client.encode_path(customer_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/customers/{customer_id}.json",
+                &[("customer_id", customer_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1549,12 +1945,19 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/customers/{}/json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/unstable/customers/{customer_id}.json",
+                    &[("customer_id", customer_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1571,10 +1974,44 @@ impl Customers {
     pub async fn deprecated_unstable_delete_param(&self, customer_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/customers/{}/json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/unstable/customers/{customer_id}.json",
+                &[("customer_id", customer_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a customer. A customer can't be deleted if they have existing orders.
+     *
+     * This function performs a `DELETE` to the `/admin/api/unstable/customers/{customer_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_delete_param`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_unstable_delete_param_if_exists(
+        &self,
+        customer_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/unstable/customers/{}/json",
+            self.client.encode_path(customer_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/unstable/customers/{customer_id}.json",
+                    &[("customer_id", customer_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1596,12 +2033,19 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/customers/{}/account_activation_url.json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/unstable/customers/{customer_id}/account_activation_url.json",
+                    &[("customer_id", customer_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1622,12 +2066,19 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/customers/{}/send_invite.json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/unstable/customers/{customer_id}/send_invite.json",
+                    &[("customer_id", customer_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1639,7 +2090,9 @@ impl Customers {
      */
     pub async fn deprecated_unstable_get_count(&self) -> Result<()> {
         let url = "/admin/api/unstable/customers/count.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/customers/count.json", &[])
+        })
     }
 
     /**
@@ -1656,10 +2109,16 @@ impl Customers {
     pub async fn deprecated_unstable_get_param_order(&self, customer_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/customers/{}/orders.json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/customers/{customer_id}/orders.json",
+                &[("customer_id", customer_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1676,10 +2135,16 @@ impl Customers {
     pub async fn deprecated_202001_get_param_addresse(&self, customer_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/customers/{}/addresses.json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/customers/{customer_id}/addresses.json",
+                &[("customer_id", customer_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1700,12 +2165,19 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/customers/{}/addresses.json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-01/customers/{customer_id}/addresses.json",
+                    &[("customer_id", customer_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1727,11 +2199,20 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/customers/{}/addresses/{}/json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            crate::progenitor_support::encode_path(&address_id.to_string()),
+            self.client.encode_path(customer_id),
+            self.client.encode_path(address_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/customers/{customer_id}/addresses/{address_id}.json",
+                &[
+                    ("customer_id", customer_id.to_string()),
+                    ("address_id", address_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1754,13 +2235,23 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/customers/{}/addresses/{}/json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            crate::progenitor_support::encode_path(&address_id.to_string()),
+            self.client.encode_path(customer_id),
+            self.client.encode_path(address_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-01/customers/{customer_id}/addresses/{address_id}.json",
+                    &[
+                        ("customer_id", customer_id.to_string()),
+                        ("address_id", address_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1782,11 +2273,53 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/customers/{}/addresses/{}/json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            crate::progenitor_support::encode_path(&address_id.to_string()),
+            self.client.encode_path(customer_id),
+            self.client.encode_path(address_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-01/customers/{customer_id}/addresses/{address_id}.json",
+                &[
+                    ("customer_id", customer_id.to_string()),
+                    ("address_id", address_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Removes an address from a customer’s address list.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-01/customers/{customer_id}/addresses/{address_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_delete_param_addresses_address`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202001_delete_param_addresses_address_if_exists(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-01/customers/{}/addresses/{}/json",
+            self.client.encode_path(customer_id),
+            self.client.encode_path(address_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-01/customers/{customer_id}/addresses/{address_id}.json",
+                    &[
+                        ("customer_id", customer_id.to_string()),
+                        ("address_id", address_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1808,21 +2341,28 @@ impl Customers {
         address_ids: i64,
         operation: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if address_ids > 0 {
-            query_args.push(("address_ids[]".to_string(), address_ids.to_string()));
+            query_args.push(("address_ids[]", address_ids.to_string()));
         }
         if !operation.is_empty() {
-            query_args.push(("operation".to_string(), operation.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/customers/{}/addresses/set.json?{}",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            query_
+            query_args.push(("operation", operation.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/customers/{}/addresses/set.json",
+                self.client.encode_path(customer_id)
+            ),
+            &query_args,
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "PUT",
+                "/admin/api/2020-01/customers/{customer_id}/addresses/set.json",
+                &[("customer_id", customer_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1844,11 +2384,20 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/customers/{}/addresses/{}/default.json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            crate::progenitor_support::encode_path(&address_id.to_string()),
+            self.client.encode_path(customer_id),
+            self.client.encode_path(address_id),
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "PUT",
+                "/admin/api/2020-01/customers/{customer_id}/addresses/{address_id}/default.json",
+                &[
+                    ("customer_id", customer_id.to_string()),
+                    ("address_id", address_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1865,10 +2414,16 @@ impl Customers {
     pub async fn deprecated_202004_get_param_addresse(&self, customer_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/customers/{}/addresses.json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/customers/{customer_id}/addresses.json",
+                &[("customer_id", customer_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1889,12 +2444,19 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/customers/{}/addresses.json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-04/customers/{customer_id}/addresses.json",
+                    &[("customer_id", customer_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1916,11 +2478,20 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/customers/{}/addresses/{}/json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            crate::progenitor_support::encode_path(&address_id.to_string()),
+            self.client.encode_path(customer_id),
+            self.client.encode_path(address_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/customers/{customer_id}/addresses/{address_id}.json",
+                &[
+                    ("customer_id", customer_id.to_string()),
+                    ("address_id", address_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1943,13 +2514,23 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/customers/{}/addresses/{}/json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            crate::progenitor_support::encode_path(&address_id.to_string()),
+            self.client.encode_path(customer_id),
+            self.client.encode_path(address_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-04/customers/{customer_id}/addresses/{address_id}.json",
+                    &[
+                        ("customer_id", customer_id.to_string()),
+                        ("address_id", address_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1971,11 +2552,53 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/customers/{}/addresses/{}/json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            crate::progenitor_support::encode_path(&address_id.to_string()),
+            self.client.encode_path(customer_id),
+            self.client.encode_path(address_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-04/customers/{customer_id}/addresses/{address_id}.json",
+                &[
+                    ("customer_id", customer_id.to_string()),
+                    ("address_id", address_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Removes an address from a customer’s address list.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-04/customers/{customer_id}/addresses/{address_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_delete_param_addresses_address`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202004_delete_param_addresses_address_if_exists(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-04/customers/{}/addresses/{}/json",
+            self.client.encode_path(customer_id),
+            self.client.encode_path(address_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-04/customers/{customer_id}/addresses/{address_id}.json",
+                    &[
+                        ("customer_id", customer_id.to_string()),
+                        ("address_id", address_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1997,21 +2620,28 @@ impl Customers {
         address_ids: i64,
         operation: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if address_ids > 0 {
-            query_args.push(("address_ids[]".to_string(), address_ids.to_string()));
+            query_args.push(("address_ids[]", address_ids.to_string()));
         }
         if !operation.is_empty() {
-            query_args.push(("operation".to_string(), operation.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/customers/{}/addresses/set.json?{}",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            query_
+            query_args.push(("operation", operation.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/customers/{}/addresses/set.json",
+                self.client.encode_path(customer_id)
+            ),
+            &query_args,
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "PUT",
+                "/admin/api/2020-04/customers/{customer_id}/addresses/set.json",
+                &[("customer_id", customer_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2033,11 +2663,20 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/customers/{}/addresses/{}/default.json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            crate::progenitor_support::encode_path(&address_id.to_string()),
+            self.client.encode_path(customer_id),
+            self.client.encode_path(address_id),
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "PUT",
+                "/admin/api/2020-04/customers/{customer_id}/addresses/{address_id}/default.json",
+                &[
+                    ("customer_id", customer_id.to_string()),
+                    ("address_id", address_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -2054,10 +2693,16 @@ impl Customers {
     pub async fn deprecated_202007_get_param_addresse(&self, customer_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/customers/{}/addresses.json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/customers/{customer_id}/addresses.json",
+                &[("customer_id", customer_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2078,12 +2723,19 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/customers/{}/addresses.json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-07/customers/{customer_id}/addresses.json",
+                    &[("customer_id", customer_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2105,11 +2757,20 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/customers/{}/addresses/{}/json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            crate::progenitor_support::encode_path(&address_id.to_string()),
+            self.client.encode_path(customer_id),
+            self.client.encode_path(address_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/customers/{customer_id}/addresses/{address_id}.json",
+                &[
+                    ("customer_id", customer_id.to_string()),
+                    ("address_id", address_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -2132,13 +2793,23 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/customers/{}/addresses/{}/json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            crate::progenitor_support::encode_path(&address_id.to_string()),
+            self.client.encode_path(customer_id),
+            self.client.encode_path(address_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-07/customers/{customer_id}/addresses/{address_id}.json",
+                    &[
+                        ("customer_id", customer_id.to_string()),
+                        ("address_id", address_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -2160,11 +2831,53 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/customers/{}/addresses/{}/json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            crate::progenitor_support::encode_path(&address_id.to_string()),
+            self.client.encode_path(customer_id),
+            self.client.encode_path(address_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-07/customers/{customer_id}/addresses/{address_id}.json",
+                &[
+                    ("customer_id", customer_id.to_string()),
+                    ("address_id", address_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Removes an address from a customer’s address list.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-07/customers/{customer_id}/addresses/{address_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_delete_param_addresses_address`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202007_delete_param_addresses_address_if_exists(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-07/customers/{}/addresses/{}/json",
+            self.client.encode_path(customer_id),
+            self.client.encode_path(address_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-07/customers/{customer_id}/addresses/{address_id}.json",
+                    &[
+                        ("customer_id", customer_id.to_string()),
+                        ("address_id", address_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -2186,21 +2899,28 @@ impl Customers {
         address_ids: i64,
         operation: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if address_ids > 0 {
-            query_args.push(("address_ids[]".to_string(), address_ids.to_string()));
+            query_args.push(("address_ids[]", address_ids.to_string()));
         }
         if !operation.is_empty() {
-            query_args.push(("operation".to_string(), operation.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/customers/{}/addresses/set.json?{}",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            query_
+            query_args.push(("operation", operation.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/customers/{}/addresses/set.json",
+                self.client.encode_path(customer_id)
+            ),
+            &query_args,
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "PUT",
+                "/admin/api/2020-07/customers/{customer_id}/addresses/set.json",
+                &[("customer_id", customer_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2222,11 +2942,20 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/customers/{}/addresses/{}/default.json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            crate::progenitor_support::encode_path(&address_id.to_string()),
+            self.client.encode_path(customer_id),
+            self.client.encode_path(address_id),
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "PUT",
+                "/admin/api/2020-07/customers/{customer_id}/addresses/{address_id}/default.json",
+                &[
+                    ("customer_id", customer_id.to_string()),
+                    ("address_id", address_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -2243,10 +2972,16 @@ impl Customers {
     pub async fn get_param_addresse(&self, customer_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/customers/{}/addresses.json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/customers/{customer_id}/addresses.json",
+                &[("customer_id", customer_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2267,12 +3002,19 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/customers/{}/addresses.json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-10/customers/{customer_id}/addresses.json",
+                    &[("customer_id", customer_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2294,11 +3036,20 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/customers/{}/addresses/{}/json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            crate::progenitor_support::encode_path(&address_id.to_string()),
+            self.client.encode_path(customer_id),
+            self.client.encode_path(address_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/customers/{customer_id}/addresses/{address_id}.json",
+                &[
+                    ("customer_id", customer_id.to_string()),
+                    ("address_id", address_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -2321,13 +3072,23 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/customers/{}/addresses/{}/json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            crate::progenitor_support::encode_path(&address_id.to_string()),
+            self.client.encode_path(customer_id),
+            self.client.encode_path(address_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-10/customers/{customer_id}/addresses/{address_id}.json",
+                    &[
+                        ("customer_id", customer_id.to_string()),
+                        ("address_id", address_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -2349,11 +3110,53 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/customers/{}/addresses/{}/json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            crate::progenitor_support::encode_path(&address_id.to_string()),
+            self.client.encode_path(customer_id),
+            self.client.encode_path(address_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-10/customers/{customer_id}/addresses/{address_id}.json",
+                &[
+                    ("customer_id", customer_id.to_string()),
+                    ("address_id", address_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Removes an address from a customer’s address list.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-10/customers/{customer_id}/addresses/{address_id}.json` endpoint.
+     *
+     * As opposed to `delete_param_addresses_address`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_param_addresses_address_if_exists(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-10/customers/{}/addresses/{}/json",
+            self.client.encode_path(customer_id),
+            self.client.encode_path(address_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-10/customers/{customer_id}/addresses/{address_id}.json",
+                    &[
+                        ("customer_id", customer_id.to_string()),
+                        ("address_id", address_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -2375,21 +3178,28 @@ impl Customers {
         address_ids: i64,
         operation: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if address_ids > 0 {
-            query_args.push(("address_ids[]".to_string(), address_ids.to_string()));
+            query_args.push(("address_ids[]", address_ids.to_string()));
         }
         if !operation.is_empty() {
-            query_args.push(("operation".to_string(), operation.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/customers/{}/addresses/set.json?{}",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            query_
+            query_args.push(("operation", operation.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/customers/{}/addresses/set.json",
+                self.client.encode_path(customer_id)
+            ),
+            &query_args,
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "PUT",
+                "/admin/api/2020-10/customers/{customer_id}/addresses/set.json",
+                &[("customer_id", customer_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2411,11 +3221,20 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/customers/{}/addresses/{}/default.json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            crate::progenitor_support::encode_path(&address_id.to_string()),
+            self.client.encode_path(customer_id),
+            self.client.encode_path(address_id),
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "PUT",
+                "/admin/api/2020-10/customers/{customer_id}/addresses/{address_id}/default.json",
+                &[
+                    ("customer_id", customer_id.to_string()),
+                    ("address_id", address_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -2432,10 +3251,16 @@ impl Customers {
     pub async fn deprecated_202101_get_param_addresse(&self, customer_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/customers/{}/addresses.json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/customers/{customer_id}/addresses.json",
+                &[("customer_id", customer_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2456,12 +3281,19 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/customers/{}/addresses.json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2021-01/customers/{customer_id}/addresses.json",
+                    &[("customer_id", customer_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2483,11 +3315,20 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/customers/{}/addresses/{}/json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            crate::progenitor_support::encode_path(&address_id.to_string()),
+            self.client.encode_path(customer_id),
+            self.client.encode_path(address_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/customers/{customer_id}/addresses/{address_id}.json",
+                &[
+                    ("customer_id", customer_id.to_string()),
+                    ("address_id", address_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -2510,13 +3351,23 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/customers/{}/addresses/{}/json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            crate::progenitor_support::encode_path(&address_id.to_string()),
+            self.client.encode_path(customer_id),
+            self.client.encode_path(address_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2021-01/customers/{customer_id}/addresses/{address_id}.json",
+                    &[
+                        ("customer_id", customer_id.to_string()),
+                        ("address_id", address_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -2538,11 +3389,53 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/customers/{}/addresses/{}/json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            crate::progenitor_support::encode_path(&address_id.to_string()),
+            self.client.encode_path(customer_id),
+            self.client.encode_path(address_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2021-01/customers/{customer_id}/addresses/{address_id}.json",
+                &[
+                    ("customer_id", customer_id.to_string()),
+                    ("address_id", address_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Removes an address from a customer’s address list.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2021-01/customers/{customer_id}/addresses/{address_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_delete_param_addresses_address`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202101_delete_param_addresses_address_if_exists(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2021-01/customers/{}/addresses/{}/json",
+            self.client.encode_path(customer_id),
+            self.client.encode_path(address_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2021-01/customers/{customer_id}/addresses/{address_id}.json",
+                    &[
+                        ("customer_id", customer_id.to_string()),
+                        ("address_id", address_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -2564,21 +3457,28 @@ impl Customers {
         address_ids: i64,
         operation: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if address_ids > 0 {
-            query_args.push(("address_ids[]".to_string(), address_ids.to_string()));
+            query_args.push(("address_ids[]", address_ids.to_string()));
         }
         if !operation.is_empty() {
-            query_args.push(("operation".to_string(), operation.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/customers/{}/addresses/set.json?{}",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            query_
+            query_args.push(("operation", operation.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/customers/{}/addresses/set.json",
+                self.client.encode_path(customer_id)
+            ),
+            &query_args,
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "PUT",
+                "/admin/api/2021-01/customers/{customer_id}/addresses/set.json",
+                &[("customer_id", customer_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2600,11 +3500,20 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/customers/{}/addresses/{}/default.json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            crate::progenitor_support::encode_path(&address_id.to_string()),
+            self.client.encode_path(customer_id),
+            self.client.encode_path(address_id),
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "PUT",
+                "/admin/api/2021-01/customers/{customer_id}/addresses/{address_id}/default.json",
+                &[
+                    ("customer_id", customer_id.to_string()),
+                    ("address_id", address_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -2621,10 +3530,16 @@ impl Customers {
     pub async fn deprecated_unstable_get_param_addresse(&self, customer_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/customers/{}/addresses.json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/customers/{customer_id}/addresses.json",
+                &[("customer_id", customer_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2645,12 +3560,19 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/customers/{}/addresses.json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
+            self.client.encode_path(customer_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/unstable/customers/{customer_id}/addresses.json",
+                    &[("customer_id", customer_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2672,11 +3594,20 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/customers/{}/addresses/{}/json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            crate::progenitor_support::encode_path(&address_id.to_string()),
+            self.client.encode_path(customer_id),
+            self.client.encode_path(address_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/customers/{customer_id}/addresses/{address_id}.json",
+                &[
+                    ("customer_id", customer_id.to_string()),
+                    ("address_id", address_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -2699,13 +3630,23 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/customers/{}/addresses/{}/json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            crate::progenitor_support::encode_path(&address_id.to_string()),
+            self.client.encode_path(customer_id),
+            self.client.encode_path(address_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/unstable/customers/{customer_id}/addresses/{address_id}.json",
+                    &[
+                        ("customer_id", customer_id.to_string()),
+                        ("address_id", address_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -2727,11 +3668,53 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/customers/{}/addresses/{}/json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            crate::progenitor_support::encode_path(&address_id.to_string()),
+            self.client.encode_path(customer_id),
+            self.client.encode_path(address_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/unstable/customers/{customer_id}/addresses/{address_id}.json",
+                &[
+                    ("customer_id", customer_id.to_string()),
+                    ("address_id", address_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Removes an address from a customer’s address list.
+     *
+     * This function performs a `DELETE` to the `/admin/api/unstable/customers/{customer_id}/addresses/{address_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_delete_param_addresses_address`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_unstable_delete_param_addresses_address_if_exists(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/unstable/customers/{}/addresses/{}/json",
+            self.client.encode_path(customer_id),
+            self.client.encode_path(address_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/unstable/customers/{customer_id}/addresses/{address_id}.json",
+                    &[
+                        ("customer_id", customer_id.to_string()),
+                        ("address_id", address_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -2753,21 +3736,28 @@ impl Customers {
         address_ids: i64,
         operation: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if address_ids > 0 {
-            query_args.push(("address_ids[]".to_string(), address_ids.to_string()));
+            query_args.push(("address_ids[]", address_ids.to_string()));
         }
         if !operation.is_empty() {
-            query_args.push(("operation".to_string(), operation.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/customers/{}/addresses/set.json?{}",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            query_
+            query_args.push(("operation", operation.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/customers/{}/addresses/set.json",
+                self.client.encode_path(customer_id)
+            ),
+            &query_args,
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "PUT",
+                "/admin/api/unstable/customers/{customer_id}/addresses/set.json",
+                &[("customer_id", customer_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2789,11 +3779,20 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/customers/{}/addresses/{}/default.json",
-            crate::progenitor_support::encode_path(&customer_id.to_string()),
-            crate::progenitor_support::encode_path(&address_id.to_string()),
+            self.client.encode_path(customer_id),
+            self.client.encode_path(address_id),
         );
 
-        self.client.put(&url, None).await
+        self.client.put(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "PUT",
+                "/admin/api/unstable/customers/{customer_id}/addresses/{address_id}/default.json",
+                &[
+                    ("customer_id", customer_id.to_string()),
+                    ("address_id", address_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -2816,20 +3815,28 @@ impl Customers {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/customer_saved_searches.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-01/customer_saved_searches.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/customer_saved_searches.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -2845,8 +3852,15 @@ impl Customers {
     ) -> Result<()> {
         let url = "/admin/api/2020-01/customer_saved_searches.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-01/customer_saved_searches.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -2861,17 +3875,22 @@ impl Customers {
      * * `since_id: &str` -- Restrict results to after the specified ID.
      */
     pub async fn deprecated_202001_get_saved_searches_count(&self, since_id: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/customer_saved_searches/count.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-01/customer_saved_searches/count.json",
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/customer_saved_searches/count.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -2891,18 +3910,28 @@ impl Customers {
         customer_saved_search_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/customer_saved_searches/{}/json?{}",
-            crate::progenitor_support::encode_path(&customer_saved_search_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/customer_saved_searches/{}/json",
+                self.client.encode_path(customer_saved_search_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/customer_saved_searches/{customer_saved_search_id}.json",
+                &[(
+                    "customer_saved_search_id",
+                    customer_saved_search_id.to_string(),
+                )],
+            )
+        })
     }
 
     /**
@@ -2923,12 +3952,22 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/customer_saved_searches/{}/json",
-            crate::progenitor_support::encode_path(&customer_saved_search_id.to_string()),
+            self.client.encode_path(customer_saved_search_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-01/customer_saved_searches/{customer_saved_search_id}.json",
+                    &[(
+                        "customer_saved_search_id",
+                        customer_saved_search_id.to_string(),
+                    )],
+                )
+            })
     }
 
     /**
@@ -2948,10 +3987,50 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/customer_saved_searches/{}/json",
-            crate::progenitor_support::encode_path(&customer_saved_search_id.to_string()),
+            self.client.encode_path(customer_saved_search_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-01/customer_saved_searches/{customer_saved_search_id}.json",
+                &[(
+                    "customer_saved_search_id",
+                    customer_saved_search_id.to_string(),
+                )],
+            )
+        })
+    }
+
+    /**
+     * Deletes a customer saved search.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-01/customer_saved_searches/{customer_saved_search_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_delete_saved_searches_param_search`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202001_delete_saved_searches_param_search_if_exists(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-01/customer_saved_searches/{}/json",
+            self.client.encode_path(customer_saved_search_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-01/customer_saved_searches/{customer_saved_search_id}.json",
+                    &[(
+                        "customer_saved_search_id",
+                        customer_saved_search_id.to_string(),
+                    )],
+                )
+            })
     }
 
     /**
@@ -2977,24 +4056,25 @@ impl Customers {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/customer_saved_searches/{}/customers.json?{}",
-            crate::progenitor_support::encode_path(&customer_saved_search_id.to_string()),
-            query_
+            query_args.push(("order", order.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/customer_saved_searches/{}/customers.json",
+                self.client.encode_path(customer_saved_search_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-01/customer_saved_searches/{customer_saved_search_id}/customers.json", &[("customer_saved_search_id", customer_saved_search_id.to_string())]))
     }
 
     /**
@@ -3017,20 +4097,28 @@ impl Customers {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/customer_saved_searches.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-04/customer_saved_searches.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/customer_saved_searches.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -3046,8 +4134,15 @@ impl Customers {
     ) -> Result<()> {
         let url = "/admin/api/2020-04/customer_saved_searches.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-04/customer_saved_searches.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -3062,17 +4157,22 @@ impl Customers {
      * * `since_id: &str` -- Restrict results to after the specified ID.
      */
     pub async fn deprecated_202004_get_saved_searches_count(&self, since_id: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/customer_saved_searches/count.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-04/customer_saved_searches/count.json",
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/customer_saved_searches/count.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -3092,18 +4192,28 @@ impl Customers {
         customer_saved_search_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/customer_saved_searches/{}/json?{}",
-            crate::progenitor_support::encode_path(&customer_saved_search_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/customer_saved_searches/{}/json",
+                self.client.encode_path(customer_saved_search_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/customer_saved_searches/{customer_saved_search_id}.json",
+                &[(
+                    "customer_saved_search_id",
+                    customer_saved_search_id.to_string(),
+                )],
+            )
+        })
     }
 
     /**
@@ -3124,12 +4234,22 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/customer_saved_searches/{}/json",
-            crate::progenitor_support::encode_path(&customer_saved_search_id.to_string()),
+            self.client.encode_path(customer_saved_search_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-04/customer_saved_searches/{customer_saved_search_id}.json",
+                    &[(
+                        "customer_saved_search_id",
+                        customer_saved_search_id.to_string(),
+                    )],
+                )
+            })
     }
 
     /**
@@ -3149,18 +4269,58 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/customer_saved_searches/{}/json",
-            crate::progenitor_support::encode_path(&customer_saved_search_id.to_string()),
+            self.client.encode_path(customer_saved_search_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-04/customer_saved_searches/{customer_saved_search_id}.json",
+                &[(
+                    "customer_saved_search_id",
+                    customer_saved_search_id.to_string(),
+                )],
+            )
+        })
     }
 
     /**
-     * Retrieves all customers returned by a customer saved search.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-04/customer_saved_searches/{customer_saved_search_id}/customers.json` endpoint.
+     * Deletes a customer saved search.
      *
-     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#other-2020-04
+     * This function performs a `DELETE` to the `/admin/api/2020-04/customer_saved_searches/{customer_saved_search_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_delete_saved_searches_param_search`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202004_delete_saved_searches_param_search_if_exists(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-04/customer_saved_searches/{}/json",
+            self.client.encode_path(customer_saved_search_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-04/customer_saved_searches/{customer_saved_search_id}.json",
+                    &[(
+                        "customer_saved_search_id",
+                        customer_saved_search_id.to_string(),
+                    )],
+                )
+            })
+    }
+
+    /**
+     * Retrieves all customers returned by a customer saved search.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-04/customer_saved_searches/{customer_saved_search_id}/customers.json` endpoint.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/customers/customersavedsearch#other-2020-04
      *
      * **Parameters:**
      *
@@ -3178,24 +4338,25 @@ impl Customers {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/customer_saved_searches/{}/customers.json?{}",
-            crate::progenitor_support::encode_path(&customer_saved_search_id.to_string()),
-            query_
+            query_args.push(("order", order.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/customer_saved_searches/{}/customers.json",
+                self.client.encode_path(customer_saved_search_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-04/customer_saved_searches/{customer_saved_search_id}/customers.json", &[("customer_saved_search_id", customer_saved_search_id.to_string())]))
     }
 
     /**
@@ -3218,20 +4379,28 @@ impl Customers {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/customer_saved_searches.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-07/customer_saved_searches.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/customer_saved_searches.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -3247,8 +4416,15 @@ impl Customers {
     ) -> Result<()> {
         let url = "/admin/api/2020-07/customer_saved_searches.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-07/customer_saved_searches.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -3263,17 +4439,22 @@ impl Customers {
      * * `since_id: &str` -- Restrict results to after the specified ID.
      */
     pub async fn deprecated_202007_get_saved_searches_count(&self, since_id: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/customer_saved_searches/count.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-07/customer_saved_searches/count.json",
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/customer_saved_searches/count.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -3293,18 +4474,28 @@ impl Customers {
         customer_saved_search_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/customer_saved_searches/{}/json?{}",
-            crate::progenitor_support::encode_path(&customer_saved_search_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/customer_saved_searches/{}/json",
+                self.client.encode_path(customer_saved_search_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/customer_saved_searches/{customer_saved_search_id}.json",
+                &[(
+                    "customer_saved_search_id",
+                    customer_saved_search_id.to_string(),
+                )],
+            )
+        })
     }
 
     /**
@@ -3325,12 +4516,22 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/customer_saved_searches/{}/json",
-            crate::progenitor_support::encode_path(&customer_saved_search_id.to_string()),
+            self.client.encode_path(customer_saved_search_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-07/customer_saved_searches/{customer_saved_search_id}.json",
+                    &[(
+                        "customer_saved_search_id",
+                        customer_saved_search_id.to_string(),
+                    )],
+                )
+            })
     }
 
     /**
@@ -3350,10 +4551,50 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/customer_saved_searches/{}/json",
-            crate::progenitor_support::encode_path(&customer_saved_search_id.to_string()),
+            self.client.encode_path(customer_saved_search_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-07/customer_saved_searches/{customer_saved_search_id}.json",
+                &[(
+                    "customer_saved_search_id",
+                    customer_saved_search_id.to_string(),
+                )],
+            )
+        })
+    }
+
+    /**
+     * Deletes a customer saved search.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-07/customer_saved_searches/{customer_saved_search_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_delete_saved_searches_param_search`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202007_delete_saved_searches_param_search_if_exists(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-07/customer_saved_searches/{}/json",
+            self.client.encode_path(customer_saved_search_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-07/customer_saved_searches/{customer_saved_search_id}.json",
+                    &[(
+                        "customer_saved_search_id",
+                        customer_saved_search_id.to_string(),
+                    )],
+                )
+            })
     }
 
     /**
@@ -3379,24 +4620,25 @@ impl Customers {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/customer_saved_searches/{}/customers.json?{}",
-            crate::progenitor_support::encode_path(&customer_saved_search_id.to_string()),
-            query_
+            query_args.push(("order", order.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/customer_saved_searches/{}/customers.json",
+                self.client.encode_path(customer_saved_search_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-07/customer_saved_searches/{customer_saved_search_id}/customers.json", &[("customer_saved_search_id", customer_saved_search_id.to_string())]))
     }
 
     /**
@@ -3414,20 +4656,28 @@ impl Customers {
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
     pub async fn get_saved_searche(&self, limit: &str, since_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/customer_saved_searches.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-10/customer_saved_searches.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/customer_saved_searches.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -3440,8 +4690,15 @@ impl Customers {
     pub async fn create_saved_searches(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-10/customer_saved_searches.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-10/customer_saved_searches.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -3456,17 +4713,22 @@ impl Customers {
      * * `since_id: &str` -- Restrict results to after the specified ID.
      */
     pub async fn get_saved_searches_count(&self, since_id: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/customer_saved_searches/count.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-10/customer_saved_searches/count.json",
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/customer_saved_searches/count.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -3486,18 +4748,28 @@ impl Customers {
         customer_saved_search_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/customer_saved_searches/{}/json?{}",
-            crate::progenitor_support::encode_path(&customer_saved_search_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/customer_saved_searches/{}/json",
+                self.client.encode_path(customer_saved_search_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/customer_saved_searches/{customer_saved_search_id}.json",
+                &[(
+                    "customer_saved_search_id",
+                    customer_saved_search_id.to_string(),
+                )],
+            )
+        })
     }
 
     /**
@@ -3518,12 +4790,22 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/customer_saved_searches/{}/json",
-            crate::progenitor_support::encode_path(&customer_saved_search_id.to_string()),
+            self.client.encode_path(customer_saved_search_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-10/customer_saved_searches/{customer_saved_search_id}.json",
+                    &[(
+                        "customer_saved_search_id",
+                        customer_saved_search_id.to_string(),
+                    )],
+                )
+            })
     }
 
     /**
@@ -3543,10 +4825,50 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/customer_saved_searches/{}/json",
-            crate::progenitor_support::encode_path(&customer_saved_search_id.to_string()),
+            self.client.encode_path(customer_saved_search_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-10/customer_saved_searches/{customer_saved_search_id}.json",
+                &[(
+                    "customer_saved_search_id",
+                    customer_saved_search_id.to_string(),
+                )],
+            )
+        })
+    }
+
+    /**
+     * Deletes a customer saved search.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-10/customer_saved_searches/{customer_saved_search_id}.json` endpoint.
+     *
+     * As opposed to `delete_saved_searches_param_search`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_saved_searches_param_search_if_exists(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-10/customer_saved_searches/{}/json",
+            self.client.encode_path(customer_saved_search_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-10/customer_saved_searches/{customer_saved_search_id}.json",
+                    &[(
+                        "customer_saved_search_id",
+                        customer_saved_search_id.to_string(),
+                    )],
+                )
+            })
     }
 
     /**
@@ -3572,24 +4894,25 @@ impl Customers {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/customer_saved_searches/{}/customers.json?{}",
-            crate::progenitor_support::encode_path(&customer_saved_search_id.to_string()),
-            query_
+            query_args.push(("order", order.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/customer_saved_searches/{}/customers.json",
+                self.client.encode_path(customer_saved_search_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-10/customer_saved_searches/{customer_saved_search_id}/customers.json", &[("customer_saved_search_id", customer_saved_search_id.to_string())]))
     }
 
     /**
@@ -3612,20 +4935,28 @@ impl Customers {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/customer_saved_searches.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2021-01/customer_saved_searches.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/customer_saved_searches.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -3641,8 +4972,15 @@ impl Customers {
     ) -> Result<()> {
         let url = "/admin/api/2021-01/customer_saved_searches.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2021-01/customer_saved_searches.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -3657,17 +4995,22 @@ impl Customers {
      * * `since_id: &str` -- Restrict results to after the specified ID.
      */
     pub async fn deprecated_202101_get_saved_searches_count(&self, since_id: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/customer_saved_searches/count.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/2021-01/customer_saved_searches/count.json",
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/customer_saved_searches/count.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -3687,18 +5030,28 @@ impl Customers {
         customer_saved_search_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/customer_saved_searches/{}/json?{}",
-            crate::progenitor_support::encode_path(&customer_saved_search_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/customer_saved_searches/{}/json",
+                self.client.encode_path(customer_saved_search_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/customer_saved_searches/{customer_saved_search_id}.json",
+                &[(
+                    "customer_saved_search_id",
+                    customer_saved_search_id.to_string(),
+                )],
+            )
+        })
     }
 
     /**
@@ -3719,12 +5072,22 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/customer_saved_searches/{}/json",
-            crate::progenitor_support::encode_path(&customer_saved_search_id.to_string()),
+            self.client.encode_path(customer_saved_search_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2021-01/customer_saved_searches/{customer_saved_search_id}.json",
+                    &[(
+                        "customer_saved_search_id",
+                        customer_saved_search_id.to_string(),
+                    )],
+                )
+            })
     }
 
     /**
@@ -3744,10 +5107,50 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/customer_saved_searches/{}/json",
-            crate::progenitor_support::encode_path(&customer_saved_search_id.to_string()),
+            self.client.encode_path(customer_saved_search_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2021-01/customer_saved_searches/{customer_saved_search_id}.json",
+                &[(
+                    "customer_saved_search_id",
+                    customer_saved_search_id.to_string(),
+                )],
+            )
+        })
+    }
+
+    /**
+     * Deletes a customer saved search.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2021-01/customer_saved_searches/{customer_saved_search_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_delete_saved_searches_param_search`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202101_delete_saved_searches_param_search_if_exists(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2021-01/customer_saved_searches/{}/json",
+            self.client.encode_path(customer_saved_search_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2021-01/customer_saved_searches/{customer_saved_search_id}.json",
+                    &[(
+                        "customer_saved_search_id",
+                        customer_saved_search_id.to_string(),
+                    )],
+                )
+            })
     }
 
     /**
@@ -3773,24 +5176,25 @@ impl Customers {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/customer_saved_searches/{}/customers.json?{}",
-            crate::progenitor_support::encode_path(&customer_saved_search_id.to_string()),
-            query_
+            query_args.push(("order", order.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/customer_saved_searches/{}/customers.json",
+                self.client.encode_path(customer_saved_search_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/2021-01/customer_saved_searches/{customer_saved_search_id}/customers.json", &[("customer_saved_search_id", customer_saved_search_id.to_string())]))
     }
 
     /**
@@ -3813,23 +5217,28 @@ impl Customers {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/customer_saved_searches.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/unstable/customer_saved_searches.json",
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/customer_saved_searches.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -3845,8 +5254,15 @@ impl Customers {
     ) -> Result<()> {
         let url = "/admin/api/unstable/customer_saved_searches.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/unstable/customer_saved_searches.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -3861,17 +5277,22 @@ impl Customers {
      * * `since_id: &str` -- Restrict results to after the specified ID.
      */
     pub async fn deprecated_unstable_get_saved_searches_count(&self, since_id: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/customer_saved_searches/count.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/unstable/customer_saved_searches/count.json",
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/customer_saved_searches/count.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -3891,18 +5312,28 @@ impl Customers {
         customer_saved_search_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/customer_saved_searches/{}/json?{}",
-            crate::progenitor_support::encode_path(&customer_saved_search_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/customer_saved_searches/{}/json",
+                self.client.encode_path(customer_saved_search_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/customer_saved_searches/{customer_saved_search_id}.json",
+                &[(
+                    "customer_saved_search_id",
+                    customer_saved_search_id.to_string(),
+                )],
+            )
+        })
     }
 
     /**
@@ -3923,12 +5354,22 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/customer_saved_searches/{}/json",
-            crate::progenitor_support::encode_path(&customer_saved_search_id.to_string()),
+            self.client.encode_path(customer_saved_search_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/unstable/customer_saved_searches/{customer_saved_search_id}.json",
+                    &[(
+                        "customer_saved_search_id",
+                        customer_saved_search_id.to_string(),
+                    )],
+                )
+            })
     }
 
     /**
@@ -3948,10 +5389,50 @@ impl Customers {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/customer_saved_searches/{}/json",
-            crate::progenitor_support::encode_path(&customer_saved_search_id.to_string()),
+            self.client.encode_path(customer_saved_search_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/unstable/customer_saved_searches/{customer_saved_search_id}.json",
+                &[(
+                    "customer_saved_search_id",
+                    customer_saved_search_id.to_string(),
+                )],
+            )
+        })
+    }
+
+    /**
+     * Deletes a customer saved search.
+     *
+     * This function performs a `DELETE` to the `/admin/api/unstable/customer_saved_searches/{customer_saved_search_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_delete_saved_searches_param_search`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_unstable_delete_saved_searches_param_search_if_exists(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/unstable/customer_saved_searches/{}/json",
+            self.client.encode_path(customer_saved_search_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/unstable/customer_saved_searches/{customer_saved_search_id}.json",
+                    &[(
+                        "customer_saved_search_id",
+                        customer_saved_search_id.to_string(),
+                    )],
+                )
+            })
     }
 
     /**
@@ -3977,23 +5458,1989 @@ impl Customers {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/customer_saved_searches/{}/customers.json?{}",
-            crate::progenitor_support::encode_path(&customer_saved_search_id.to_string()),
-            query_
+            query_args.push(("order", order.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/customer_saved_searches/{}/customers.json",
+                self.client.encode_path(customer_saved_search_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/unstable/customer_saved_searches/{customer_saved_search_id}/customers.json", &[("customer_saved_search_id", customer_saved_search_id.to_string())]))
+    }
+}
+
+/// The functions of [`Customers`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn CustomersApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait CustomersApi: Send + Sync {
+    async fn deprecated_202001_get(
+        &self,
+        ids: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_create(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202001_get_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_param(&self, customer_id: &str, fields: &str) -> Result<()>;
+    async fn deprecated_202001_update_param(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_param(&self, customer_id: &str) -> Result<()>;
+    async fn deprecated_202001_delete_param_if_exists(&self, customer_id: &str) -> Result<bool>;
+    async fn deprecated_202001_create_param_account_activation_url(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_param_send_invite(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_count(&self) -> Result<()>;
+    async fn deprecated_202001_get_param_order(&self, customer_id: &str) -> Result<()>;
+    async fn deprecated_202004_get(
+        &self,
+        ids: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_create(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202004_get_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_param(&self, customer_id: &str, fields: &str) -> Result<()>;
+    async fn deprecated_202004_update_param(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_param(&self, customer_id: &str) -> Result<()>;
+    async fn deprecated_202004_delete_param_if_exists(&self, customer_id: &str) -> Result<bool>;
+    async fn deprecated_202004_create_param_account_activation_url(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_create_param_send_invite(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_count(&self) -> Result<()>;
+    async fn deprecated_202004_get_param_order(&self, customer_id: &str) -> Result<()>;
+    async fn deprecated_202007_get(
+        &self,
+        ids: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_create(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202007_get_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_param(&self, customer_id: &str, fields: &str) -> Result<()>;
+    async fn deprecated_202007_update_param(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_param(&self, customer_id: &str) -> Result<()>;
+    async fn deprecated_202007_delete_param_if_exists(&self, customer_id: &str) -> Result<bool>;
+    async fn deprecated_202007_create_param_account_activation_url(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_create_param_send_invite(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_count(&self) -> Result<()>;
+    async fn deprecated_202007_get_param_order(&self, customer_id: &str) -> Result<()>;
+    async fn get(
+        &self,
+        ids: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn create(&self, body: &serde_json::Value) -> Result<()>;
+    async fn get_search(&self, order: &str, query: &str, limit: &str, fields: &str) -> Result<()>;
+    async fn get_param(&self, customer_id: &str, fields: &str) -> Result<()>;
+    async fn update_param(&self, customer_id: &str, body: &serde_json::Value) -> Result<()>;
+    async fn delete_param(&self, customer_id: &str) -> Result<()>;
+    async fn delete_param_if_exists(&self, customer_id: &str) -> Result<bool>;
+    async fn create_param_account_activation_url(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn create_param_send_invite(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn get_count(&self) -> Result<()>;
+    async fn get_param_order(&self, customer_id: &str) -> Result<()>;
+    async fn deprecated_202101_get(
+        &self,
+        ids: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_create(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202101_get_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_param(&self, customer_id: &str, fields: &str) -> Result<()>;
+    async fn deprecated_202101_update_param(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_param(&self, customer_id: &str) -> Result<()>;
+    async fn deprecated_202101_delete_param_if_exists(&self, customer_id: &str) -> Result<bool>;
+    async fn deprecated_202101_create_param_account_activation_url(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_create_param_send_invite(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_count(&self) -> Result<()>;
+    async fn deprecated_202101_get_param_order(&self, customer_id: &str) -> Result<()>;
+    async fn deprecated_unstable_get(
+        &self,
+        ids: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_unstable_get_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_param(&self, customer_id: &str, fields: &str) -> Result<()>;
+    async fn deprecated_unstable_update_param(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_param(&self, customer_id: &str) -> Result<()>;
+    async fn deprecated_unstable_delete_param_if_exists(&self, customer_id: &str) -> Result<bool>;
+    async fn deprecated_unstable_create_param_account_activation_url(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create_param_send_invite(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_count(&self) -> Result<()>;
+    async fn deprecated_unstable_get_param_order(&self, customer_id: &str) -> Result<()>;
+    async fn deprecated_202001_get_param_addresse(&self, customer_id: &str) -> Result<()>;
+    async fn deprecated_202001_create_param_addresses(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_update_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_param_addresses_address_if_exists(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202001_update_param_addresses_set(
+        &self,
+        customer_id: &str,
+        address_ids: i64,
+        operation: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_update_param_addresses_address_default(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_param_addresse(&self, customer_id: &str) -> Result<()>;
+    async fn deprecated_202004_create_param_addresses(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_update_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_param_addresses_address_if_exists(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202004_update_param_addresses_set(
+        &self,
+        customer_id: &str,
+        address_ids: i64,
+        operation: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_update_param_addresses_address_default(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_param_addresse(&self, customer_id: &str) -> Result<()>;
+    async fn deprecated_202007_create_param_addresses(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_update_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_param_addresses_address_if_exists(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202007_update_param_addresses_set(
+        &self,
+        customer_id: &str,
+        address_ids: i64,
+        operation: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_update_param_addresses_address_default(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()>;
+    async fn get_param_addresse(&self, customer_id: &str) -> Result<()>;
+    async fn create_param_addresses(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn get_param_addresses_address(&self, customer_id: &str, address_id: &str) -> Result<()>;
+    async fn update_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn delete_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()>;
+    async fn delete_param_addresses_address_if_exists(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<bool>;
+    async fn update_param_addresses_set(
+        &self,
+        customer_id: &str,
+        address_ids: i64,
+        operation: &str,
+    ) -> Result<()>;
+    async fn update_param_addresses_address_default(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_param_addresse(&self, customer_id: &str) -> Result<()>;
+    async fn deprecated_202101_create_param_addresses(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_update_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_param_addresses_address_if_exists(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202101_update_param_addresses_set(
+        &self,
+        customer_id: &str,
+        address_ids: i64,
+        operation: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_update_param_addresses_address_default(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_param_addresse(&self, customer_id: &str) -> Result<()>;
+    async fn deprecated_unstable_create_param_addresses(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_update_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_param_addresses_address_if_exists(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_unstable_update_param_addresses_set(
+        &self,
+        customer_id: &str,
+        address_ids: i64,
+        operation: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_update_param_addresses_address_default(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_saved_searche(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_saved_searches(&self, body: &serde_json::Value)
+        -> Result<()>;
+    async fn deprecated_202001_get_saved_searches_count(&self, since_id: &str) -> Result<()>;
+    async fn deprecated_202001_get_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_update_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_saved_searches_param_search_if_exists(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202001_get_saved_searches_param_search_customers(
+        &self,
+        customer_saved_search_id: &str,
+        order: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_saved_searche(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_create_saved_searches(&self, body: &serde_json::Value)
+        -> Result<()>;
+    async fn deprecated_202004_get_saved_searches_count(&self, since_id: &str) -> Result<()>;
+    async fn deprecated_202004_get_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_update_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_saved_searches_param_search_if_exists(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202004_get_saved_searches_param_search_customers(
+        &self,
+        customer_saved_search_id: &str,
+        order: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_saved_searche(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_create_saved_searches(&self, body: &serde_json::Value)
+        -> Result<()>;
+    async fn deprecated_202007_get_saved_searches_count(&self, since_id: &str) -> Result<()>;
+    async fn deprecated_202007_get_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_update_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_saved_searches_param_search_if_exists(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202007_get_saved_searches_param_search_customers(
+        &self,
+        customer_saved_search_id: &str,
+        order: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn get_saved_searche(&self, limit: &str, since_id: &str, fields: &str) -> Result<()>;
+    async fn create_saved_searches(&self, body: &serde_json::Value) -> Result<()>;
+    async fn get_saved_searches_count(&self, since_id: &str) -> Result<()>;
+    async fn get_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn update_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn delete_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<()>;
+    async fn delete_saved_searches_param_search_if_exists(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<bool>;
+    async fn get_saved_searches_param_search_customers(
+        &self,
+        customer_saved_search_id: &str,
+        order: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_saved_searche(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_create_saved_searches(&self, body: &serde_json::Value)
+        -> Result<()>;
+    async fn deprecated_202101_get_saved_searches_count(&self, since_id: &str) -> Result<()>;
+    async fn deprecated_202101_get_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_update_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_saved_searches_param_search_if_exists(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202101_get_saved_searches_param_search_customers(
+        &self,
+        customer_saved_search_id: &str,
+        order: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_saved_searche(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create_saved_searches(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_saved_searches_count(&self, since_id: &str) -> Result<()>;
+    async fn deprecated_unstable_get_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_update_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_saved_searches_param_search_if_exists(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_unstable_get_saved_searches_param_search_customers(
+        &self,
+        customer_saved_search_id: &str,
+        order: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl CustomersApi for Customers {
+    async fn deprecated_202001_get(
+        &self,
+        ids: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202001_get(
+            self,
+            ids,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            limit,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202001_create(&self, body: &serde_json::Value) -> Result<()> {
+        Customers::deprecated_202001_create(self, body).await
+    }
+    async fn deprecated_202001_get_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202001_get_search(self, order, query, limit, fields).await
+    }
+    async fn deprecated_202001_get_param(&self, customer_id: &str, fields: &str) -> Result<()> {
+        Customers::deprecated_202001_get_param(self, customer_id, fields).await
+    }
+    async fn deprecated_202001_update_param(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202001_update_param(self, customer_id, body).await
+    }
+    async fn deprecated_202001_delete_param(&self, customer_id: &str) -> Result<()> {
+        Customers::deprecated_202001_delete_param(self, customer_id).await
+    }
+    async fn deprecated_202001_delete_param_if_exists(&self, customer_id: &str) -> Result<bool> {
+        Customers::deprecated_202001_delete_param_if_exists(self, customer_id).await
+    }
+    async fn deprecated_202001_create_param_account_activation_url(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202001_create_param_account_activation_url(self, customer_id, body)
+            .await
+    }
+    async fn deprecated_202001_create_param_send_invite(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202001_create_param_send_invite(self, customer_id, body).await
+    }
+    async fn deprecated_202001_get_count(&self) -> Result<()> {
+        Customers::deprecated_202001_get_count(self).await
+    }
+    async fn deprecated_202001_get_param_order(&self, customer_id: &str) -> Result<()> {
+        Customers::deprecated_202001_get_param_order(self, customer_id).await
+    }
+    async fn deprecated_202004_get(
+        &self,
+        ids: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202004_get(
+            self,
+            ids,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            limit,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202004_create(&self, body: &serde_json::Value) -> Result<()> {
+        Customers::deprecated_202004_create(self, body).await
+    }
+    async fn deprecated_202004_get_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202004_get_search(self, order, query, limit, fields).await
+    }
+    async fn deprecated_202004_get_param(&self, customer_id: &str, fields: &str) -> Result<()> {
+        Customers::deprecated_202004_get_param(self, customer_id, fields).await
+    }
+    async fn deprecated_202004_update_param(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202004_update_param(self, customer_id, body).await
+    }
+    async fn deprecated_202004_delete_param(&self, customer_id: &str) -> Result<()> {
+        Customers::deprecated_202004_delete_param(self, customer_id).await
+    }
+    async fn deprecated_202004_delete_param_if_exists(&self, customer_id: &str) -> Result<bool> {
+        Customers::deprecated_202004_delete_param_if_exists(self, customer_id).await
+    }
+    async fn deprecated_202004_create_param_account_activation_url(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202004_create_param_account_activation_url(self, customer_id, body)
+            .await
+    }
+    async fn deprecated_202004_create_param_send_invite(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202004_create_param_send_invite(self, customer_id, body).await
+    }
+    async fn deprecated_202004_get_count(&self) -> Result<()> {
+        Customers::deprecated_202004_get_count(self).await
+    }
+    async fn deprecated_202004_get_param_order(&self, customer_id: &str) -> Result<()> {
+        Customers::deprecated_202004_get_param_order(self, customer_id).await
+    }
+    async fn deprecated_202007_get(
+        &self,
+        ids: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202007_get(
+            self,
+            ids,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            limit,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202007_create(&self, body: &serde_json::Value) -> Result<()> {
+        Customers::deprecated_202007_create(self, body).await
+    }
+    async fn deprecated_202007_get_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202007_get_search(self, order, query, limit, fields).await
+    }
+    async fn deprecated_202007_get_param(&self, customer_id: &str, fields: &str) -> Result<()> {
+        Customers::deprecated_202007_get_param(self, customer_id, fields).await
+    }
+    async fn deprecated_202007_update_param(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202007_update_param(self, customer_id, body).await
+    }
+    async fn deprecated_202007_delete_param(&self, customer_id: &str) -> Result<()> {
+        Customers::deprecated_202007_delete_param(self, customer_id).await
+    }
+    async fn deprecated_202007_delete_param_if_exists(&self, customer_id: &str) -> Result<bool> {
+        Customers::deprecated_202007_delete_param_if_exists(self, customer_id).await
+    }
+    async fn deprecated_202007_create_param_account_activation_url(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202007_create_param_account_activation_url(self, customer_id, body)
+            .await
+    }
+    async fn deprecated_202007_create_param_send_invite(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202007_create_param_send_invite(self, customer_id, body).await
+    }
+    async fn deprecated_202007_get_count(&self) -> Result<()> {
+        Customers::deprecated_202007_get_count(self).await
+    }
+    async fn deprecated_202007_get_param_order(&self, customer_id: &str) -> Result<()> {
+        Customers::deprecated_202007_get_param_order(self, customer_id).await
+    }
+    async fn get(
+        &self,
+        ids: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Customers::get(
+            self,
+            ids,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            limit,
+            fields,
+        )
+        .await
+    }
+    async fn create(&self, body: &serde_json::Value) -> Result<()> {
+        Customers::create(self, body).await
+    }
+    async fn get_search(&self, order: &str, query: &str, limit: &str, fields: &str) -> Result<()> {
+        Customers::get_search(self, order, query, limit, fields).await
+    }
+    async fn get_param(&self, customer_id: &str, fields: &str) -> Result<()> {
+        Customers::get_param(self, customer_id, fields).await
+    }
+    async fn update_param(&self, customer_id: &str, body: &serde_json::Value) -> Result<()> {
+        Customers::update_param(self, customer_id, body).await
+    }
+    async fn delete_param(&self, customer_id: &str) -> Result<()> {
+        Customers::delete_param(self, customer_id).await
+    }
+    async fn delete_param_if_exists(&self, customer_id: &str) -> Result<bool> {
+        Customers::delete_param_if_exists(self, customer_id).await
+    }
+    async fn create_param_account_activation_url(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::create_param_account_activation_url(self, customer_id, body).await
+    }
+    async fn create_param_send_invite(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::create_param_send_invite(self, customer_id, body).await
+    }
+    async fn get_count(&self) -> Result<()> {
+        Customers::get_count(self).await
+    }
+    async fn get_param_order(&self, customer_id: &str) -> Result<()> {
+        Customers::get_param_order(self, customer_id).await
+    }
+    async fn deprecated_202101_get(
+        &self,
+        ids: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202101_get(
+            self,
+            ids,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            limit,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202101_create(&self, body: &serde_json::Value) -> Result<()> {
+        Customers::deprecated_202101_create(self, body).await
+    }
+    async fn deprecated_202101_get_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202101_get_search(self, order, query, limit, fields).await
+    }
+    async fn deprecated_202101_get_param(&self, customer_id: &str, fields: &str) -> Result<()> {
+        Customers::deprecated_202101_get_param(self, customer_id, fields).await
+    }
+    async fn deprecated_202101_update_param(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202101_update_param(self, customer_id, body).await
+    }
+    async fn deprecated_202101_delete_param(&self, customer_id: &str) -> Result<()> {
+        Customers::deprecated_202101_delete_param(self, customer_id).await
+    }
+    async fn deprecated_202101_delete_param_if_exists(&self, customer_id: &str) -> Result<bool> {
+        Customers::deprecated_202101_delete_param_if_exists(self, customer_id).await
+    }
+    async fn deprecated_202101_create_param_account_activation_url(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202101_create_param_account_activation_url(self, customer_id, body)
+            .await
+    }
+    async fn deprecated_202101_create_param_send_invite(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202101_create_param_send_invite(self, customer_id, body).await
+    }
+    async fn deprecated_202101_get_count(&self) -> Result<()> {
+        Customers::deprecated_202101_get_count(self).await
+    }
+    async fn deprecated_202101_get_param_order(&self, customer_id: &str) -> Result<()> {
+        Customers::deprecated_202101_get_param_order(self, customer_id).await
+    }
+    async fn deprecated_unstable_get(
+        &self,
+        ids: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Customers::deprecated_unstable_get(
+            self,
+            ids,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            limit,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_unstable_create(&self, body: &serde_json::Value) -> Result<()> {
+        Customers::deprecated_unstable_create(self, body).await
+    }
+    async fn deprecated_unstable_get_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Customers::deprecated_unstable_get_search(self, order, query, limit, fields).await
+    }
+    async fn deprecated_unstable_get_param(&self, customer_id: &str, fields: &str) -> Result<()> {
+        Customers::deprecated_unstable_get_param(self, customer_id, fields).await
+    }
+    async fn deprecated_unstable_update_param(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_unstable_update_param(self, customer_id, body).await
+    }
+    async fn deprecated_unstable_delete_param(&self, customer_id: &str) -> Result<()> {
+        Customers::deprecated_unstable_delete_param(self, customer_id).await
+    }
+    async fn deprecated_unstable_delete_param_if_exists(&self, customer_id: &str) -> Result<bool> {
+        Customers::deprecated_unstable_delete_param_if_exists(self, customer_id).await
+    }
+    async fn deprecated_unstable_create_param_account_activation_url(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_unstable_create_param_account_activation_url(self, customer_id, body)
+            .await
+    }
+    async fn deprecated_unstable_create_param_send_invite(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_unstable_create_param_send_invite(self, customer_id, body).await
+    }
+    async fn deprecated_unstable_get_count(&self) -> Result<()> {
+        Customers::deprecated_unstable_get_count(self).await
+    }
+    async fn deprecated_unstable_get_param_order(&self, customer_id: &str) -> Result<()> {
+        Customers::deprecated_unstable_get_param_order(self, customer_id).await
+    }
+    async fn deprecated_202001_get_param_addresse(&self, customer_id: &str) -> Result<()> {
+        Customers::deprecated_202001_get_param_addresse(self, customer_id).await
+    }
+    async fn deprecated_202001_create_param_addresses(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202001_create_param_addresses(self, customer_id, body).await
+    }
+    async fn deprecated_202001_get_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202001_get_param_addresses_address(self, customer_id, address_id)
+            .await
+    }
+    async fn deprecated_202001_update_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202001_update_param_addresses_address(
+            self,
+            customer_id,
+            address_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202001_delete_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202001_delete_param_addresses_address(self, customer_id, address_id)
+            .await
+    }
+    async fn deprecated_202001_delete_param_addresses_address_if_exists(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<bool> {
+        Customers::deprecated_202001_delete_param_addresses_address_if_exists(
+            self,
+            customer_id,
+            address_id,
+        )
+        .await
+    }
+    async fn deprecated_202001_update_param_addresses_set(
+        &self,
+        customer_id: &str,
+        address_ids: i64,
+        operation: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202001_update_param_addresses_set(
+            self,
+            customer_id,
+            address_ids,
+            operation,
+        )
+        .await
+    }
+    async fn deprecated_202001_update_param_addresses_address_default(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202001_update_param_addresses_address_default(
+            self,
+            customer_id,
+            address_id,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_param_addresse(&self, customer_id: &str) -> Result<()> {
+        Customers::deprecated_202004_get_param_addresse(self, customer_id).await
+    }
+    async fn deprecated_202004_create_param_addresses(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202004_create_param_addresses(self, customer_id, body).await
+    }
+    async fn deprecated_202004_get_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202004_get_param_addresses_address(self, customer_id, address_id)
+            .await
+    }
+    async fn deprecated_202004_update_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202004_update_param_addresses_address(
+            self,
+            customer_id,
+            address_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202004_delete_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202004_delete_param_addresses_address(self, customer_id, address_id)
+            .await
+    }
+    async fn deprecated_202004_delete_param_addresses_address_if_exists(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<bool> {
+        Customers::deprecated_202004_delete_param_addresses_address_if_exists(
+            self,
+            customer_id,
+            address_id,
+        )
+        .await
+    }
+    async fn deprecated_202004_update_param_addresses_set(
+        &self,
+        customer_id: &str,
+        address_ids: i64,
+        operation: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202004_update_param_addresses_set(
+            self,
+            customer_id,
+            address_ids,
+            operation,
+        )
+        .await
+    }
+    async fn deprecated_202004_update_param_addresses_address_default(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202004_update_param_addresses_address_default(
+            self,
+            customer_id,
+            address_id,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_param_addresse(&self, customer_id: &str) -> Result<()> {
+        Customers::deprecated_202007_get_param_addresse(self, customer_id).await
+    }
+    async fn deprecated_202007_create_param_addresses(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202007_create_param_addresses(self, customer_id, body).await
+    }
+    async fn deprecated_202007_get_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202007_get_param_addresses_address(self, customer_id, address_id)
+            .await
+    }
+    async fn deprecated_202007_update_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202007_update_param_addresses_address(
+            self,
+            customer_id,
+            address_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202007_delete_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202007_delete_param_addresses_address(self, customer_id, address_id)
+            .await
+    }
+    async fn deprecated_202007_delete_param_addresses_address_if_exists(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<bool> {
+        Customers::deprecated_202007_delete_param_addresses_address_if_exists(
+            self,
+            customer_id,
+            address_id,
+        )
+        .await
+    }
+    async fn deprecated_202007_update_param_addresses_set(
+        &self,
+        customer_id: &str,
+        address_ids: i64,
+        operation: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202007_update_param_addresses_set(
+            self,
+            customer_id,
+            address_ids,
+            operation,
+        )
+        .await
+    }
+    async fn deprecated_202007_update_param_addresses_address_default(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202007_update_param_addresses_address_default(
+            self,
+            customer_id,
+            address_id,
+        )
+        .await
+    }
+    async fn get_param_addresse(&self, customer_id: &str) -> Result<()> {
+        Customers::get_param_addresse(self, customer_id).await
+    }
+    async fn create_param_addresses(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::create_param_addresses(self, customer_id, body).await
+    }
+    async fn get_param_addresses_address(&self, customer_id: &str, address_id: &str) -> Result<()> {
+        Customers::get_param_addresses_address(self, customer_id, address_id).await
+    }
+    async fn update_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::update_param_addresses_address(self, customer_id, address_id, body).await
+    }
+    async fn delete_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()> {
+        Customers::delete_param_addresses_address(self, customer_id, address_id).await
+    }
+    async fn delete_param_addresses_address_if_exists(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<bool> {
+        Customers::delete_param_addresses_address_if_exists(self, customer_id, address_id).await
+    }
+    async fn update_param_addresses_set(
+        &self,
+        customer_id: &str,
+        address_ids: i64,
+        operation: &str,
+    ) -> Result<()> {
+        Customers::update_param_addresses_set(self, customer_id, address_ids, operation).await
+    }
+    async fn update_param_addresses_address_default(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()> {
+        Customers::update_param_addresses_address_default(self, customer_id, address_id).await
+    }
+    async fn deprecated_202101_get_param_addresse(&self, customer_id: &str) -> Result<()> {
+        Customers::deprecated_202101_get_param_addresse(self, customer_id).await
+    }
+    async fn deprecated_202101_create_param_addresses(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202101_create_param_addresses(self, customer_id, body).await
+    }
+    async fn deprecated_202101_get_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202101_get_param_addresses_address(self, customer_id, address_id)
+            .await
+    }
+    async fn deprecated_202101_update_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202101_update_param_addresses_address(
+            self,
+            customer_id,
+            address_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202101_delete_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202101_delete_param_addresses_address(self, customer_id, address_id)
+            .await
+    }
+    async fn deprecated_202101_delete_param_addresses_address_if_exists(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<bool> {
+        Customers::deprecated_202101_delete_param_addresses_address_if_exists(
+            self,
+            customer_id,
+            address_id,
+        )
+        .await
+    }
+    async fn deprecated_202101_update_param_addresses_set(
+        &self,
+        customer_id: &str,
+        address_ids: i64,
+        operation: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202101_update_param_addresses_set(
+            self,
+            customer_id,
+            address_ids,
+            operation,
+        )
+        .await
+    }
+    async fn deprecated_202101_update_param_addresses_address_default(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202101_update_param_addresses_address_default(
+            self,
+            customer_id,
+            address_id,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_param_addresse(&self, customer_id: &str) -> Result<()> {
+        Customers::deprecated_unstable_get_param_addresse(self, customer_id).await
+    }
+    async fn deprecated_unstable_create_param_addresses(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_unstable_create_param_addresses(self, customer_id, body).await
+    }
+    async fn deprecated_unstable_get_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()> {
+        Customers::deprecated_unstable_get_param_addresses_address(self, customer_id, address_id)
+            .await
+    }
+    async fn deprecated_unstable_update_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_unstable_update_param_addresses_address(
+            self,
+            customer_id,
+            address_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_unstable_delete_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()> {
+        Customers::deprecated_unstable_delete_param_addresses_address(self, customer_id, address_id)
+            .await
+    }
+    async fn deprecated_unstable_delete_param_addresses_address_if_exists(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<bool> {
+        Customers::deprecated_unstable_delete_param_addresses_address_if_exists(
+            self,
+            customer_id,
+            address_id,
+        )
+        .await
+    }
+    async fn deprecated_unstable_update_param_addresses_set(
+        &self,
+        customer_id: &str,
+        address_ids: i64,
+        operation: &str,
+    ) -> Result<()> {
+        Customers::deprecated_unstable_update_param_addresses_set(
+            self,
+            customer_id,
+            address_ids,
+            operation,
+        )
+        .await
+    }
+    async fn deprecated_unstable_update_param_addresses_address_default(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()> {
+        Customers::deprecated_unstable_update_param_addresses_address_default(
+            self,
+            customer_id,
+            address_id,
+        )
+        .await
+    }
+    async fn deprecated_202001_get_saved_searche(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202001_get_saved_searche(self, limit, since_id, fields).await
+    }
+    async fn deprecated_202001_create_saved_searches(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202001_create_saved_searches(self, body).await
+    }
+    async fn deprecated_202001_get_saved_searches_count(&self, since_id: &str) -> Result<()> {
+        Customers::deprecated_202001_get_saved_searches_count(self, since_id).await
+    }
+    async fn deprecated_202001_get_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202001_get_saved_searches_param_search(
+            self,
+            customer_saved_search_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202001_update_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202001_update_saved_searches_param_search(
+            self,
+            customer_saved_search_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202001_delete_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202001_delete_saved_searches_param_search(
+            self,
+            customer_saved_search_id,
+        )
+        .await
+    }
+    async fn deprecated_202001_delete_saved_searches_param_search_if_exists(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<bool> {
+        Customers::deprecated_202001_delete_saved_searches_param_search_if_exists(
+            self,
+            customer_saved_search_id,
+        )
+        .await
+    }
+    async fn deprecated_202001_get_saved_searches_param_search_customers(
+        &self,
+        customer_saved_search_id: &str,
+        order: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202001_get_saved_searches_param_search_customers(
+            self,
+            customer_saved_search_id,
+            order,
+            limit,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_saved_searche(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202004_get_saved_searche(self, limit, since_id, fields).await
+    }
+    async fn deprecated_202004_create_saved_searches(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202004_create_saved_searches(self, body).await
+    }
+    async fn deprecated_202004_get_saved_searches_count(&self, since_id: &str) -> Result<()> {
+        Customers::deprecated_202004_get_saved_searches_count(self, since_id).await
+    }
+    async fn deprecated_202004_get_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202004_get_saved_searches_param_search(
+            self,
+            customer_saved_search_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202004_update_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202004_update_saved_searches_param_search(
+            self,
+            customer_saved_search_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202004_delete_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202004_delete_saved_searches_param_search(
+            self,
+            customer_saved_search_id,
+        )
+        .await
+    }
+    async fn deprecated_202004_delete_saved_searches_param_search_if_exists(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<bool> {
+        Customers::deprecated_202004_delete_saved_searches_param_search_if_exists(
+            self,
+            customer_saved_search_id,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_saved_searches_param_search_customers(
+        &self,
+        customer_saved_search_id: &str,
+        order: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202004_get_saved_searches_param_search_customers(
+            self,
+            customer_saved_search_id,
+            order,
+            limit,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_saved_searche(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202007_get_saved_searche(self, limit, since_id, fields).await
+    }
+    async fn deprecated_202007_create_saved_searches(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202007_create_saved_searches(self, body).await
+    }
+    async fn deprecated_202007_get_saved_searches_count(&self, since_id: &str) -> Result<()> {
+        Customers::deprecated_202007_get_saved_searches_count(self, since_id).await
+    }
+    async fn deprecated_202007_get_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202007_get_saved_searches_param_search(
+            self,
+            customer_saved_search_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202007_update_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202007_update_saved_searches_param_search(
+            self,
+            customer_saved_search_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202007_delete_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202007_delete_saved_searches_param_search(
+            self,
+            customer_saved_search_id,
+        )
+        .await
+    }
+    async fn deprecated_202007_delete_saved_searches_param_search_if_exists(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<bool> {
+        Customers::deprecated_202007_delete_saved_searches_param_search_if_exists(
+            self,
+            customer_saved_search_id,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_saved_searches_param_search_customers(
+        &self,
+        customer_saved_search_id: &str,
+        order: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202007_get_saved_searches_param_search_customers(
+            self,
+            customer_saved_search_id,
+            order,
+            limit,
+            fields,
+        )
+        .await
+    }
+    async fn get_saved_searche(&self, limit: &str, since_id: &str, fields: &str) -> Result<()> {
+        Customers::get_saved_searche(self, limit, since_id, fields).await
+    }
+    async fn create_saved_searches(&self, body: &serde_json::Value) -> Result<()> {
+        Customers::create_saved_searches(self, body).await
+    }
+    async fn get_saved_searches_count(&self, since_id: &str) -> Result<()> {
+        Customers::get_saved_searches_count(self, since_id).await
+    }
+    async fn get_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Customers::get_saved_searches_param_search(self, customer_saved_search_id, fields).await
+    }
+    async fn update_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::update_saved_searches_param_search(self, customer_saved_search_id, body).await
+    }
+    async fn delete_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<()> {
+        Customers::delete_saved_searches_param_search(self, customer_saved_search_id).await
+    }
+    async fn delete_saved_searches_param_search_if_exists(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<bool> {
+        Customers::delete_saved_searches_param_search_if_exists(self, customer_saved_search_id)
+            .await
+    }
+    async fn get_saved_searches_param_search_customers(
+        &self,
+        customer_saved_search_id: &str,
+        order: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Customers::get_saved_searches_param_search_customers(
+            self,
+            customer_saved_search_id,
+            order,
+            limit,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202101_get_saved_searche(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202101_get_saved_searche(self, limit, since_id, fields).await
+    }
+    async fn deprecated_202101_create_saved_searches(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202101_create_saved_searches(self, body).await
+    }
+    async fn deprecated_202101_get_saved_searches_count(&self, since_id: &str) -> Result<()> {
+        Customers::deprecated_202101_get_saved_searches_count(self, since_id).await
+    }
+    async fn deprecated_202101_get_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202101_get_saved_searches_param_search(
+            self,
+            customer_saved_search_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202101_update_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202101_update_saved_searches_param_search(
+            self,
+            customer_saved_search_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202101_delete_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202101_delete_saved_searches_param_search(
+            self,
+            customer_saved_search_id,
+        )
+        .await
+    }
+    async fn deprecated_202101_delete_saved_searches_param_search_if_exists(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<bool> {
+        Customers::deprecated_202101_delete_saved_searches_param_search_if_exists(
+            self,
+            customer_saved_search_id,
+        )
+        .await
+    }
+    async fn deprecated_202101_get_saved_searches_param_search_customers(
+        &self,
+        customer_saved_search_id: &str,
+        order: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202101_get_saved_searches_param_search_customers(
+            self,
+            customer_saved_search_id,
+            order,
+            limit,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_saved_searche(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Customers::deprecated_unstable_get_saved_searche(self, limit, since_id, fields).await
+    }
+    async fn deprecated_unstable_create_saved_searches(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_unstable_create_saved_searches(self, body).await
+    }
+    async fn deprecated_unstable_get_saved_searches_count(&self, since_id: &str) -> Result<()> {
+        Customers::deprecated_unstable_get_saved_searches_count(self, since_id).await
+    }
+    async fn deprecated_unstable_get_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Customers::deprecated_unstable_get_saved_searches_param_search(
+            self,
+            customer_saved_search_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_unstable_update_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_unstable_update_saved_searches_param_search(
+            self,
+            customer_saved_search_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_unstable_delete_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<()> {
+        Customers::deprecated_unstable_delete_saved_searches_param_search(
+            self,
+            customer_saved_search_id,
+        )
+        .await
+    }
+    async fn deprecated_unstable_delete_saved_searches_param_search_if_exists(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<bool> {
+        Customers::deprecated_unstable_delete_saved_searches_param_search_if_exists(
+            self,
+            customer_saved_search_id,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_saved_searches_param_search_customers(
+        &self,
+        customer_saved_search_id: &str,
+        order: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Customers::deprecated_unstable_get_saved_searches_param_search_customers(
+            self,
+            customer_saved_search_id,
+            order,
+            limit,
+            fields,
+        )
+        .await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct DeprecatedApiCalls {
     pub client: Client,
@@ -12,6 +10,14 @@ impl DeprecatedApiCalls {
         DeprecatedApiCalls { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        DeprecatedApiCalls {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieves a list of deprecated API calls made by the authenticated private app in the past 30 days.
      *
@@ -21,7 +27,9 @@ impl DeprecatedApiCalls {
      */
     pub async fn deprecated_202101_get_call(&self) -> Result<()> {
         let url = "/admin/api/2021-01/deprecated_api_calls.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/deprecated_api_calls.json", &[])
+        })
     }
 
     /**
@@ -33,6 +41,29 @@ impl DeprecatedApiCalls {
      */
     pub async fn deprecated_unstable_get_call(&self) -> Result<()> {
         let url = "/admin/api/unstable/deprecated_api_calls.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/deprecated_api_calls.json", &[])
+        })
+    }
+}
+
+/// The functions of [`DeprecatedApiCalls`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn DeprecatedApiCallsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait DeprecatedApiCallsApi: Send + Sync {
+    async fn deprecated_202101_get_call(&self) -> Result<()>;
+    async fn deprecated_unstable_get_call(&self) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl DeprecatedApiCallsApi for DeprecatedApiCalls {
+    async fn deprecated_202101_get_call(&self) -> Result<()> {
+        DeprecatedApiCalls::deprecated_202101_get_call(self).await
+    }
+    async fn deprecated_unstable_get_call(&self) -> Result<()> {
+        DeprecatedApiCalls::deprecated_unstable_get_call(self).await
     }
 }
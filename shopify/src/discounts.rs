@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Discounts {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Discounts {
         Discounts { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Discounts {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieve a list of discount codes. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -29,10 +35,16 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/price_rules/{}/discount_codes.json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
+            self.client.encode_path(price_rule_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/price_rules/{price_rule_id}/discount_codes.json",
+                &[("price_rule_id", price_rule_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -53,12 +65,19 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/price_rules/{}/discount_codes.json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
+            self.client.encode_path(price_rule_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-01/price_rules/{price_rule_id}/discount_codes.json",
+                    &[("price_rule_id", price_rule_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -80,11 +99,11 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/price_rules/{}/discount_codes/{}/json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
-            crate::progenitor_support::encode_path(&discount_code_id.to_string()),
+            self.client.encode_path(price_rule_id),
+            self.client.encode_path(discount_code_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-01/price_rules/{price_rule_id}/discount_codes/{discount_code_id}.json", &[("price_rule_id", price_rule_id.to_string()), ("discount_code_id", discount_code_id.to_string())]))
     }
 
     /**
@@ -107,13 +126,13 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/price_rules/{}/discount_codes/{}/json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
-            crate::progenitor_support::encode_path(&discount_code_id.to_string()),
+            self.client.encode_path(price_rule_id),
+            self.client.encode_path(discount_code_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("PUT", "/admin/api/2020-01/price_rules/{price_rule_id}/discount_codes/{discount_code_id}.json", &[("price_rule_id", price_rule_id.to_string()), ("discount_code_id", discount_code_id.to_string())]))
     }
 
     /**
@@ -135,11 +154,32 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/price_rules/{}/discount_codes/{}/json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
-            crate::progenitor_support::encode_path(&discount_code_id.to_string()),
+            self.client.encode_path(price_rule_id),
+            self.client.encode_path(discount_code_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/admin/api/2020-01/price_rules/{price_rule_id}/discount_codes/{discount_code_id}.json", &[("price_rule_id", price_rule_id.to_string()), ("discount_code_id", discount_code_id.to_string())]))
+    }
+
+    /**
+     * Deletes a discount code.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-01/price_rules/{price_rule_id}/discount_codes/{discount_code_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_delete_price_rules_param_rule_codes_code`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202001_delete_price_rules_param_rule_codes_code_if_exists(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-01/price_rules/{}/discount_codes/{}/json",
+            self.client.encode_path(price_rule_id),
+            self.client.encode_path(discount_code_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete_if_exists(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/admin/api/2020-01/price_rules/{price_rule_id}/discount_codes/{discount_code_id}.json", &[("price_rule_id", price_rule_id.to_string()), ("discount_code_id", discount_code_id.to_string())]))
     }
 
     /**
@@ -156,14 +196,18 @@ impl Discounts {
     * * `code: i64` -- recurring_application_charge[capped_amount].
     */
     pub async fn deprecated_202001_get_codes_lookup(&self, code: i64) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if code > 0 {
-            query_args.push(("code".to_string(), code.to_string()));
+            query_args.push(("code", code.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/discount_codes/lookup.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-01/discount_codes/lookup.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/discount_codes/lookup.json", &[])
+        })
     }
 
     /**
@@ -201,12 +245,19 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/price_rules/{}/batch.json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
+            self.client.encode_path(price_rule_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-01/price_rules/{price_rule_id}/batch.json",
+                    &[("price_rule_id", price_rule_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -228,11 +279,20 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/price_rules/{}/batch/{}/json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
-            crate::progenitor_support::encode_path(&batch_id.to_string()),
+            self.client.encode_path(price_rule_id),
+            self.client.encode_path(batch_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/price_rules/{price_rule_id}/batch/{batch_id}.json",
+                &[
+                    ("price_rule_id", price_rule_id.to_string()),
+                    ("batch_id", batch_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -256,11 +316,11 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/price_rules/{}/batch/{}/discount_codes.json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
-            crate::progenitor_support::encode_path(&batch_id.to_string()),
+            self.client.encode_path(price_rule_id),
+            self.client.encode_path(batch_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-01/price_rules/{price_rule_id}/batch/{batch_id}/discount_codes.json", &[("price_rule_id", price_rule_id.to_string()), ("batch_id", batch_id.to_string())]))
     }
 
     /**
@@ -280,10 +340,16 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/price_rules/{}/discount_codes.json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
+            self.client.encode_path(price_rule_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/price_rules/{price_rule_id}/discount_codes.json",
+                &[("price_rule_id", price_rule_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -304,12 +370,19 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/price_rules/{}/discount_codes.json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
+            self.client.encode_path(price_rule_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-04/price_rules/{price_rule_id}/discount_codes.json",
+                    &[("price_rule_id", price_rule_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -331,11 +404,11 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/price_rules/{}/discount_codes/{}/json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
-            crate::progenitor_support::encode_path(&discount_code_id.to_string()),
+            self.client.encode_path(price_rule_id),
+            self.client.encode_path(discount_code_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-04/price_rules/{price_rule_id}/discount_codes/{discount_code_id}.json", &[("price_rule_id", price_rule_id.to_string()), ("discount_code_id", discount_code_id.to_string())]))
     }
 
     /**
@@ -358,13 +431,13 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/price_rules/{}/discount_codes/{}/json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
-            crate::progenitor_support::encode_path(&discount_code_id.to_string()),
+            self.client.encode_path(price_rule_id),
+            self.client.encode_path(discount_code_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("PUT", "/admin/api/2020-04/price_rules/{price_rule_id}/discount_codes/{discount_code_id}.json", &[("price_rule_id", price_rule_id.to_string()), ("discount_code_id", discount_code_id.to_string())]))
     }
 
     /**
@@ -386,11 +459,32 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/price_rules/{}/discount_codes/{}/json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
-            crate::progenitor_support::encode_path(&discount_code_id.to_string()),
+            self.client.encode_path(price_rule_id),
+            self.client.encode_path(discount_code_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/admin/api/2020-04/price_rules/{price_rule_id}/discount_codes/{discount_code_id}.json", &[("price_rule_id", price_rule_id.to_string()), ("discount_code_id", discount_code_id.to_string())]))
+    }
+
+    /**
+     * Deletes a discount code.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-04/price_rules/{price_rule_id}/discount_codes/{discount_code_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_delete_price_rules_param_rule_codes_code`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202004_delete_price_rules_param_rule_codes_code_if_exists(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-04/price_rules/{}/discount_codes/{}/json",
+            self.client.encode_path(price_rule_id),
+            self.client.encode_path(discount_code_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete_if_exists(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/admin/api/2020-04/price_rules/{price_rule_id}/discount_codes/{discount_code_id}.json", &[("price_rule_id", price_rule_id.to_string()), ("discount_code_id", discount_code_id.to_string())]))
     }
 
     /**
@@ -407,14 +501,18 @@ impl Discounts {
     * * `code: i64` -- recurring_application_charge[capped_amount].
     */
     pub async fn deprecated_202004_get_codes_lookup(&self, code: i64) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if code > 0 {
-            query_args.push(("code".to_string(), code.to_string()));
+            query_args.push(("code", code.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/discount_codes/lookup.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-04/discount_codes/lookup.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/discount_codes/lookup.json", &[])
+        })
     }
 
     /**
@@ -452,12 +550,19 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/price_rules/{}/batch.json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
+            self.client.encode_path(price_rule_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-04/price_rules/{price_rule_id}/batch.json",
+                    &[("price_rule_id", price_rule_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -479,11 +584,20 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/price_rules/{}/batch/{}/json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
-            crate::progenitor_support::encode_path(&batch_id.to_string()),
+            self.client.encode_path(price_rule_id),
+            self.client.encode_path(batch_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/price_rules/{price_rule_id}/batch/{batch_id}.json",
+                &[
+                    ("price_rule_id", price_rule_id.to_string()),
+                    ("batch_id", batch_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -507,11 +621,11 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/price_rules/{}/batch/{}/discount_codes.json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
-            crate::progenitor_support::encode_path(&batch_id.to_string()),
+            self.client.encode_path(price_rule_id),
+            self.client.encode_path(batch_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-04/price_rules/{price_rule_id}/batch/{batch_id}/discount_codes.json", &[("price_rule_id", price_rule_id.to_string()), ("batch_id", batch_id.to_string())]))
     }
 
     /**
@@ -531,10 +645,16 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/price_rules/{}/discount_codes.json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
+            self.client.encode_path(price_rule_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/price_rules/{price_rule_id}/discount_codes.json",
+                &[("price_rule_id", price_rule_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -555,12 +675,19 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/price_rules/{}/discount_codes.json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
+            self.client.encode_path(price_rule_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-07/price_rules/{price_rule_id}/discount_codes.json",
+                    &[("price_rule_id", price_rule_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -582,11 +709,11 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/price_rules/{}/discount_codes/{}/json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
-            crate::progenitor_support::encode_path(&discount_code_id.to_string()),
+            self.client.encode_path(price_rule_id),
+            self.client.encode_path(discount_code_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-07/price_rules/{price_rule_id}/discount_codes/{discount_code_id}.json", &[("price_rule_id", price_rule_id.to_string()), ("discount_code_id", discount_code_id.to_string())]))
     }
 
     /**
@@ -609,13 +736,13 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/price_rules/{}/discount_codes/{}/json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
-            crate::progenitor_support::encode_path(&discount_code_id.to_string()),
+            self.client.encode_path(price_rule_id),
+            self.client.encode_path(discount_code_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("PUT", "/admin/api/2020-07/price_rules/{price_rule_id}/discount_codes/{discount_code_id}.json", &[("price_rule_id", price_rule_id.to_string()), ("discount_code_id", discount_code_id.to_string())]))
     }
 
     /**
@@ -637,11 +764,32 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/price_rules/{}/discount_codes/{}/json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
-            crate::progenitor_support::encode_path(&discount_code_id.to_string()),
+            self.client.encode_path(price_rule_id),
+            self.client.encode_path(discount_code_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/admin/api/2020-07/price_rules/{price_rule_id}/discount_codes/{discount_code_id}.json", &[("price_rule_id", price_rule_id.to_string()), ("discount_code_id", discount_code_id.to_string())]))
+    }
+
+    /**
+     * Deletes a discount code.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-07/price_rules/{price_rule_id}/discount_codes/{discount_code_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_delete_price_rules_param_rule_codes_code`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202007_delete_price_rules_param_rule_codes_code_if_exists(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-07/price_rules/{}/discount_codes/{}/json",
+            self.client.encode_path(price_rule_id),
+            self.client.encode_path(discount_code_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete_if_exists(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/admin/api/2020-07/price_rules/{price_rule_id}/discount_codes/{discount_code_id}.json", &[("price_rule_id", price_rule_id.to_string()), ("discount_code_id", discount_code_id.to_string())]))
     }
 
     /**
@@ -658,14 +806,18 @@ impl Discounts {
     * * `code: i64` -- recurring_application_charge[capped_amount].
     */
     pub async fn deprecated_202007_get_codes_lookup(&self, code: i64) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if code > 0 {
-            query_args.push(("code".to_string(), code.to_string()));
+            query_args.push(("code", code.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/discount_codes/lookup.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-07/discount_codes/lookup.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/discount_codes/lookup.json", &[])
+        })
     }
 
     /**
@@ -703,12 +855,19 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/price_rules/{}/batch.json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
+            self.client.encode_path(price_rule_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-07/price_rules/{price_rule_id}/batch.json",
+                    &[("price_rule_id", price_rule_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -730,11 +889,20 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/price_rules/{}/batch/{}/json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
-            crate::progenitor_support::encode_path(&batch_id.to_string()),
+            self.client.encode_path(price_rule_id),
+            self.client.encode_path(batch_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/price_rules/{price_rule_id}/batch/{batch_id}.json",
+                &[
+                    ("price_rule_id", price_rule_id.to_string()),
+                    ("batch_id", batch_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -758,11 +926,11 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/price_rules/{}/batch/{}/discount_codes.json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
-            crate::progenitor_support::encode_path(&batch_id.to_string()),
+            self.client.encode_path(price_rule_id),
+            self.client.encode_path(batch_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-07/price_rules/{price_rule_id}/batch/{batch_id}/discount_codes.json", &[("price_rule_id", price_rule_id.to_string()), ("batch_id", batch_id.to_string())]))
     }
 
     /**
@@ -779,10 +947,16 @@ impl Discounts {
     pub async fn get_price_rules_param_rule_code(&self, price_rule_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/price_rules/{}/discount_codes.json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
+            self.client.encode_path(price_rule_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/price_rules/{price_rule_id}/discount_codes.json",
+                &[("price_rule_id", price_rule_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -803,12 +977,19 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/price_rules/{}/discount_codes.json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
+            self.client.encode_path(price_rule_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-10/price_rules/{price_rule_id}/discount_codes.json",
+                    &[("price_rule_id", price_rule_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -830,11 +1011,11 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/price_rules/{}/discount_codes/{}/json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
-            crate::progenitor_support::encode_path(&discount_code_id.to_string()),
+            self.client.encode_path(price_rule_id),
+            self.client.encode_path(discount_code_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-10/price_rules/{price_rule_id}/discount_codes/{discount_code_id}.json", &[("price_rule_id", price_rule_id.to_string()), ("discount_code_id", discount_code_id.to_string())]))
     }
 
     /**
@@ -857,13 +1038,13 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/price_rules/{}/discount_codes/{}/json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
-            crate::progenitor_support::encode_path(&discount_code_id.to_string()),
+            self.client.encode_path(price_rule_id),
+            self.client.encode_path(discount_code_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("PUT", "/admin/api/2020-10/price_rules/{price_rule_id}/discount_codes/{discount_code_id}.json", &[("price_rule_id", price_rule_id.to_string()), ("discount_code_id", discount_code_id.to_string())]))
     }
 
     /**
@@ -885,11 +1066,32 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/price_rules/{}/discount_codes/{}/json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
-            crate::progenitor_support::encode_path(&discount_code_id.to_string()),
+            self.client.encode_path(price_rule_id),
+            self.client.encode_path(discount_code_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/admin/api/2020-10/price_rules/{price_rule_id}/discount_codes/{discount_code_id}.json", &[("price_rule_id", price_rule_id.to_string()), ("discount_code_id", discount_code_id.to_string())]))
+    }
+
+    /**
+     * Deletes a discount code.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-10/price_rules/{price_rule_id}/discount_codes/{discount_code_id}.json` endpoint.
+     *
+     * As opposed to `delete_price_rules_param_rule_codes_code`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_price_rules_param_rule_codes_code_if_exists(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-10/price_rules/{}/discount_codes/{}/json",
+            self.client.encode_path(price_rule_id),
+            self.client.encode_path(discount_code_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete_if_exists(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/admin/api/2020-10/price_rules/{price_rule_id}/discount_codes/{discount_code_id}.json", &[("price_rule_id", price_rule_id.to_string()), ("discount_code_id", discount_code_id.to_string())]))
     }
 
     /**
@@ -906,14 +1108,18 @@ impl Discounts {
     * * `code: i64` -- recurring_application_charge[capped_amount].
     */
     pub async fn get_codes_lookup(&self, code: i64) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if code > 0 {
-            query_args.push(("code".to_string(), code.to_string()));
+            query_args.push(("code", code.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/discount_codes/lookup.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-10/discount_codes/lookup.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/discount_codes/lookup.json", &[])
+        })
     }
 
     /**
@@ -951,12 +1157,19 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/price_rules/{}/batch.json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
+            self.client.encode_path(price_rule_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-10/price_rules/{price_rule_id}/batch.json",
+                    &[("price_rule_id", price_rule_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -978,11 +1191,20 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/price_rules/{}/batch/{}/json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
-            crate::progenitor_support::encode_path(&batch_id.to_string()),
+            self.client.encode_path(price_rule_id),
+            self.client.encode_path(batch_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/price_rules/{price_rule_id}/batch/{batch_id}.json",
+                &[
+                    ("price_rule_id", price_rule_id.to_string()),
+                    ("batch_id", batch_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1006,11 +1228,11 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/price_rules/{}/batch/{}/discount_codes.json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
-            crate::progenitor_support::encode_path(&batch_id.to_string()),
+            self.client.encode_path(price_rule_id),
+            self.client.encode_path(batch_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-10/price_rules/{price_rule_id}/batch/{batch_id}/discount_codes.json", &[("price_rule_id", price_rule_id.to_string()), ("batch_id", batch_id.to_string())]))
     }
 
     /**
@@ -1030,10 +1252,16 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/price_rules/{}/discount_codes.json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
+            self.client.encode_path(price_rule_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/price_rules/{price_rule_id}/discount_codes.json",
+                &[("price_rule_id", price_rule_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1054,12 +1282,19 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/price_rules/{}/discount_codes.json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
+            self.client.encode_path(price_rule_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2021-01/price_rules/{price_rule_id}/discount_codes.json",
+                    &[("price_rule_id", price_rule_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1081,11 +1316,11 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/price_rules/{}/discount_codes/{}/json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
-            crate::progenitor_support::encode_path(&discount_code_id.to_string()),
+            self.client.encode_path(price_rule_id),
+            self.client.encode_path(discount_code_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/2021-01/price_rules/{price_rule_id}/discount_codes/{discount_code_id}.json", &[("price_rule_id", price_rule_id.to_string()), ("discount_code_id", discount_code_id.to_string())]))
     }
 
     /**
@@ -1108,13 +1343,13 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/price_rules/{}/discount_codes/{}/json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
-            crate::progenitor_support::encode_path(&discount_code_id.to_string()),
+            self.client.encode_path(price_rule_id),
+            self.client.encode_path(discount_code_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("PUT", "/admin/api/2021-01/price_rules/{price_rule_id}/discount_codes/{discount_code_id}.json", &[("price_rule_id", price_rule_id.to_string()), ("discount_code_id", discount_code_id.to_string())]))
     }
 
     /**
@@ -1136,11 +1371,32 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/price_rules/{}/discount_codes/{}/json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
-            crate::progenitor_support::encode_path(&discount_code_id.to_string()),
+            self.client.encode_path(price_rule_id),
+            self.client.encode_path(discount_code_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/admin/api/2021-01/price_rules/{price_rule_id}/discount_codes/{discount_code_id}.json", &[("price_rule_id", price_rule_id.to_string()), ("discount_code_id", discount_code_id.to_string())]))
+    }
+
+    /**
+     * Deletes a discount code.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2021-01/price_rules/{price_rule_id}/discount_codes/{discount_code_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_delete_price_rules_param_rule_codes_code`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202101_delete_price_rules_param_rule_codes_code_if_exists(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2021-01/price_rules/{}/discount_codes/{}/json",
+            self.client.encode_path(price_rule_id),
+            self.client.encode_path(discount_code_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete_if_exists(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/admin/api/2021-01/price_rules/{price_rule_id}/discount_codes/{discount_code_id}.json", &[("price_rule_id", price_rule_id.to_string()), ("discount_code_id", discount_code_id.to_string())]))
     }
 
     /**
@@ -1157,14 +1413,18 @@ impl Discounts {
     * * `code: i64` -- recurring_application_charge[capped_amount].
     */
     pub async fn deprecated_202101_get_codes_lookup(&self, code: i64) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if code > 0 {
-            query_args.push(("code".to_string(), code.to_string()));
+            query_args.push(("code", code.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/discount_codes/lookup.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2021-01/discount_codes/lookup.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/discount_codes/lookup.json", &[])
+        })
     }
 
     /**
@@ -1202,12 +1462,19 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/price_rules/{}/batch.json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
+            self.client.encode_path(price_rule_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2021-01/price_rules/{price_rule_id}/batch.json",
+                    &[("price_rule_id", price_rule_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1229,11 +1496,20 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/price_rules/{}/batch/{}/json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
-            crate::progenitor_support::encode_path(&batch_id.to_string()),
+            self.client.encode_path(price_rule_id),
+            self.client.encode_path(batch_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/price_rules/{price_rule_id}/batch/{batch_id}.json",
+                &[
+                    ("price_rule_id", price_rule_id.to_string()),
+                    ("batch_id", batch_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1257,11 +1533,11 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/price_rules/{}/batch/{}/discount_codes.json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
-            crate::progenitor_support::encode_path(&batch_id.to_string()),
+            self.client.encode_path(price_rule_id),
+            self.client.encode_path(batch_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/2021-01/price_rules/{price_rule_id}/batch/{batch_id}/discount_codes.json", &[("price_rule_id", price_rule_id.to_string()), ("batch_id", batch_id.to_string())]))
     }
 
     /**
@@ -1281,10 +1557,16 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/price_rules/{}/discount_codes.json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
+            self.client.encode_path(price_rule_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/price_rules/{price_rule_id}/discount_codes.json",
+                &[("price_rule_id", price_rule_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1305,12 +1587,19 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/price_rules/{}/discount_codes.json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
+            self.client.encode_path(price_rule_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/unstable/price_rules/{price_rule_id}/discount_codes.json",
+                    &[("price_rule_id", price_rule_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1332,11 +1621,11 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/price_rules/{}/discount_codes/{}/json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
-            crate::progenitor_support::encode_path(&discount_code_id.to_string()),
+            self.client.encode_path(price_rule_id),
+            self.client.encode_path(discount_code_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/unstable/price_rules/{price_rule_id}/discount_codes/{discount_code_id}.json", &[("price_rule_id", price_rule_id.to_string()), ("discount_code_id", discount_code_id.to_string())]))
     }
 
     /**
@@ -1359,13 +1648,13 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/price_rules/{}/discount_codes/{}/json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
-            crate::progenitor_support::encode_path(&discount_code_id.to_string()),
+            self.client.encode_path(price_rule_id),
+            self.client.encode_path(discount_code_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("PUT", "/admin/api/unstable/price_rules/{price_rule_id}/discount_codes/{discount_code_id}.json", &[("price_rule_id", price_rule_id.to_string()), ("discount_code_id", discount_code_id.to_string())]))
     }
 
     /**
@@ -1387,11 +1676,32 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/price_rules/{}/discount_codes/{}/json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
-            crate::progenitor_support::encode_path(&discount_code_id.to_string()),
+            self.client.encode_path(price_rule_id),
+            self.client.encode_path(discount_code_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/admin/api/unstable/price_rules/{price_rule_id}/discount_codes/{discount_code_id}.json", &[("price_rule_id", price_rule_id.to_string()), ("discount_code_id", discount_code_id.to_string())]))
+    }
+
+    /**
+     * Deletes a discount code.
+     *
+     * This function performs a `DELETE` to the `/admin/api/unstable/price_rules/{price_rule_id}/discount_codes/{discount_code_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_delete_price_rules_param_rule_codes_code`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_unstable_delete_price_rules_param_rule_codes_code_if_exists(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/unstable/price_rules/{}/discount_codes/{}/json",
+            self.client.encode_path(price_rule_id),
+            self.client.encode_path(discount_code_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete_if_exists(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/admin/api/unstable/price_rules/{price_rule_id}/discount_codes/{discount_code_id}.json", &[("price_rule_id", price_rule_id.to_string()), ("discount_code_id", discount_code_id.to_string())]))
     }
 
     /**
@@ -1408,14 +1718,18 @@ impl Discounts {
     * * `code: i64` -- recurring_application_charge[capped_amount].
     */
     pub async fn deprecated_unstable_get_codes_lookup(&self, code: i64) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if code > 0 {
-            query_args.push(("code".to_string(), code.to_string()));
+            query_args.push(("code", code.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/discount_codes/lookup.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/unstable/discount_codes/lookup.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/discount_codes/lookup.json", &[])
+        })
     }
 
     /**
@@ -1453,12 +1767,19 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/price_rules/{}/batch.json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
+            self.client.encode_path(price_rule_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/unstable/price_rules/{price_rule_id}/batch.json",
+                    &[("price_rule_id", price_rule_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1480,11 +1801,20 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/price_rules/{}/batch/{}/json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
-            crate::progenitor_support::encode_path(&batch_id.to_string()),
+            self.client.encode_path(price_rule_id),
+            self.client.encode_path(batch_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/price_rules/{price_rule_id}/batch/{batch_id}.json",
+                &[
+                    ("price_rule_id", price_rule_id.to_string()),
+                    ("batch_id", batch_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1508,10 +1838,859 @@ impl Discounts {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/price_rules/{}/batch/{}/discount_codes.json",
-            crate::progenitor_support::encode_path(&price_rule_id.to_string()),
-            crate::progenitor_support::encode_path(&batch_id.to_string()),
+            self.client.encode_path(price_rule_id),
+            self.client.encode_path(batch_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/unstable/price_rules/{price_rule_id}/batch/{batch_id}/discount_codes.json", &[("price_rule_id", price_rule_id.to_string()), ("batch_id", batch_id.to_string())]))
+    }
+}
+
+/// The functions of [`Discounts`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn DiscountsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait DiscountsApi: Send + Sync {
+    async fn deprecated_202001_get_price_rules_param_rule_code(
+        &self,
+        price_rule_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_price_rules_param_rule_codes(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_update_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_price_rules_param_rule_codes_code_if_exists(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202001_get_codes_lookup(&self, code: i64) -> Result<()>;
+    async fn deprecated_202001_create_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_price_rules_param_rule_batch_code(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_price_rules_param_rule_code(
+        &self,
+        price_rule_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_create_price_rules_param_rule_codes(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_update_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_price_rules_param_rule_codes_code_if_exists(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202004_get_codes_lookup(&self, code: i64) -> Result<()>;
+    async fn deprecated_202004_create_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_price_rules_param_rule_batch_code(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_price_rules_param_rule_code(
+        &self,
+        price_rule_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_create_price_rules_param_rule_codes(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_update_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_price_rules_param_rule_codes_code_if_exists(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202007_get_codes_lookup(&self, code: i64) -> Result<()>;
+    async fn deprecated_202007_create_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_price_rules_param_rule_batch_code(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()>;
+    async fn get_price_rules_param_rule_code(&self, price_rule_id: &str) -> Result<()>;
+    async fn create_price_rules_param_rule_codes(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn get_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()>;
+    async fn update_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn delete_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()>;
+    async fn delete_price_rules_param_rule_codes_code_if_exists(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<bool>;
+    async fn get_codes_lookup(&self, code: i64) -> Result<()>;
+    async fn create_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn get_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()>;
+    async fn get_price_rules_param_rule_batch_code(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_price_rules_param_rule_code(
+        &self,
+        price_rule_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_create_price_rules_param_rule_codes(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_update_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_price_rules_param_rule_codes_code_if_exists(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202101_get_codes_lookup(&self, code: i64) -> Result<()>;
+    async fn deprecated_202101_create_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_price_rules_param_rule_batch_code(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_price_rules_param_rule_code(
+        &self,
+        price_rule_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create_price_rules_param_rule_codes(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_update_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_price_rules_param_rule_codes_code_if_exists(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_unstable_get_codes_lookup(&self, code: i64) -> Result<()>;
+    async fn deprecated_unstable_create_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_price_rules_param_rule_batch_code(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl DiscountsApi for Discounts {
+    async fn deprecated_202001_get_price_rules_param_rule_code(
+        &self,
+        price_rule_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_202001_get_price_rules_param_rule_code(self, price_rule_id).await
+    }
+    async fn deprecated_202001_create_price_rules_param_rule_codes(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Discounts::deprecated_202001_create_price_rules_param_rule_codes(self, price_rule_id, body)
+            .await
+    }
+    async fn deprecated_202001_get_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_202001_get_price_rules_param_rule_codes_code(
+            self,
+            price_rule_id,
+            discount_code_id,
+        )
+        .await
+    }
+    async fn deprecated_202001_update_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Discounts::deprecated_202001_update_price_rules_param_rule_codes_code(
+            self,
+            price_rule_id,
+            discount_code_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202001_delete_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_202001_delete_price_rules_param_rule_codes_code(
+            self,
+            price_rule_id,
+            discount_code_id,
+        )
+        .await
+    }
+    async fn deprecated_202001_delete_price_rules_param_rule_codes_code_if_exists(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<bool> {
+        Discounts::deprecated_202001_delete_price_rules_param_rule_codes_code_if_exists(
+            self,
+            price_rule_id,
+            discount_code_id,
+        )
+        .await
+    }
+    async fn deprecated_202001_get_codes_lookup(&self, code: i64) -> Result<()> {
+        Discounts::deprecated_202001_get_codes_lookup(self, code).await
+    }
+    async fn deprecated_202001_create_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Discounts::deprecated_202001_create_price_rules_param_rule_batch(self, price_rule_id, body)
+            .await
+    }
+    async fn deprecated_202001_get_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_202001_get_price_rules_param_rule_batch(self, price_rule_id, batch_id)
+            .await
+    }
+    async fn deprecated_202001_get_price_rules_param_rule_batch_code(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_202001_get_price_rules_param_rule_batch_code(
+            self,
+            price_rule_id,
+            batch_id,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_price_rules_param_rule_code(
+        &self,
+        price_rule_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_202004_get_price_rules_param_rule_code(self, price_rule_id).await
+    }
+    async fn deprecated_202004_create_price_rules_param_rule_codes(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Discounts::deprecated_202004_create_price_rules_param_rule_codes(self, price_rule_id, body)
+            .await
+    }
+    async fn deprecated_202004_get_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_202004_get_price_rules_param_rule_codes_code(
+            self,
+            price_rule_id,
+            discount_code_id,
+        )
+        .await
+    }
+    async fn deprecated_202004_update_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Discounts::deprecated_202004_update_price_rules_param_rule_codes_code(
+            self,
+            price_rule_id,
+            discount_code_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202004_delete_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_202004_delete_price_rules_param_rule_codes_code(
+            self,
+            price_rule_id,
+            discount_code_id,
+        )
+        .await
+    }
+    async fn deprecated_202004_delete_price_rules_param_rule_codes_code_if_exists(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<bool> {
+        Discounts::deprecated_202004_delete_price_rules_param_rule_codes_code_if_exists(
+            self,
+            price_rule_id,
+            discount_code_id,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_codes_lookup(&self, code: i64) -> Result<()> {
+        Discounts::deprecated_202004_get_codes_lookup(self, code).await
+    }
+    async fn deprecated_202004_create_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Discounts::deprecated_202004_create_price_rules_param_rule_batch(self, price_rule_id, body)
+            .await
+    }
+    async fn deprecated_202004_get_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_202004_get_price_rules_param_rule_batch(self, price_rule_id, batch_id)
+            .await
+    }
+    async fn deprecated_202004_get_price_rules_param_rule_batch_code(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_202004_get_price_rules_param_rule_batch_code(
+            self,
+            price_rule_id,
+            batch_id,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_price_rules_param_rule_code(
+        &self,
+        price_rule_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_202007_get_price_rules_param_rule_code(self, price_rule_id).await
+    }
+    async fn deprecated_202007_create_price_rules_param_rule_codes(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Discounts::deprecated_202007_create_price_rules_param_rule_codes(self, price_rule_id, body)
+            .await
+    }
+    async fn deprecated_202007_get_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_202007_get_price_rules_param_rule_codes_code(
+            self,
+            price_rule_id,
+            discount_code_id,
+        )
+        .await
+    }
+    async fn deprecated_202007_update_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Discounts::deprecated_202007_update_price_rules_param_rule_codes_code(
+            self,
+            price_rule_id,
+            discount_code_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202007_delete_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_202007_delete_price_rules_param_rule_codes_code(
+            self,
+            price_rule_id,
+            discount_code_id,
+        )
+        .await
+    }
+    async fn deprecated_202007_delete_price_rules_param_rule_codes_code_if_exists(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<bool> {
+        Discounts::deprecated_202007_delete_price_rules_param_rule_codes_code_if_exists(
+            self,
+            price_rule_id,
+            discount_code_id,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_codes_lookup(&self, code: i64) -> Result<()> {
+        Discounts::deprecated_202007_get_codes_lookup(self, code).await
+    }
+    async fn deprecated_202007_create_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Discounts::deprecated_202007_create_price_rules_param_rule_batch(self, price_rule_id, body)
+            .await
+    }
+    async fn deprecated_202007_get_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_202007_get_price_rules_param_rule_batch(self, price_rule_id, batch_id)
+            .await
+    }
+    async fn deprecated_202007_get_price_rules_param_rule_batch_code(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_202007_get_price_rules_param_rule_batch_code(
+            self,
+            price_rule_id,
+            batch_id,
+        )
+        .await
+    }
+    async fn get_price_rules_param_rule_code(&self, price_rule_id: &str) -> Result<()> {
+        Discounts::get_price_rules_param_rule_code(self, price_rule_id).await
+    }
+    async fn create_price_rules_param_rule_codes(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Discounts::create_price_rules_param_rule_codes(self, price_rule_id, body).await
+    }
+    async fn get_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()> {
+        Discounts::get_price_rules_param_rule_codes_code(self, price_rule_id, discount_code_id)
+            .await
+    }
+    async fn update_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Discounts::update_price_rules_param_rule_codes_code(
+            self,
+            price_rule_id,
+            discount_code_id,
+            body,
+        )
+        .await
+    }
+    async fn delete_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()> {
+        Discounts::delete_price_rules_param_rule_codes_code(self, price_rule_id, discount_code_id)
+            .await
+    }
+    async fn delete_price_rules_param_rule_codes_code_if_exists(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<bool> {
+        Discounts::delete_price_rules_param_rule_codes_code_if_exists(
+            self,
+            price_rule_id,
+            discount_code_id,
+        )
+        .await
+    }
+    async fn get_codes_lookup(&self, code: i64) -> Result<()> {
+        Discounts::get_codes_lookup(self, code).await
+    }
+    async fn create_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Discounts::create_price_rules_param_rule_batch(self, price_rule_id, body).await
+    }
+    async fn get_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()> {
+        Discounts::get_price_rules_param_rule_batch(self, price_rule_id, batch_id).await
+    }
+    async fn get_price_rules_param_rule_batch_code(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()> {
+        Discounts::get_price_rules_param_rule_batch_code(self, price_rule_id, batch_id).await
+    }
+    async fn deprecated_202101_get_price_rules_param_rule_code(
+        &self,
+        price_rule_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_202101_get_price_rules_param_rule_code(self, price_rule_id).await
+    }
+    async fn deprecated_202101_create_price_rules_param_rule_codes(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Discounts::deprecated_202101_create_price_rules_param_rule_codes(self, price_rule_id, body)
+            .await
+    }
+    async fn deprecated_202101_get_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_202101_get_price_rules_param_rule_codes_code(
+            self,
+            price_rule_id,
+            discount_code_id,
+        )
+        .await
+    }
+    async fn deprecated_202101_update_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Discounts::deprecated_202101_update_price_rules_param_rule_codes_code(
+            self,
+            price_rule_id,
+            discount_code_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202101_delete_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_202101_delete_price_rules_param_rule_codes_code(
+            self,
+            price_rule_id,
+            discount_code_id,
+        )
+        .await
+    }
+    async fn deprecated_202101_delete_price_rules_param_rule_codes_code_if_exists(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<bool> {
+        Discounts::deprecated_202101_delete_price_rules_param_rule_codes_code_if_exists(
+            self,
+            price_rule_id,
+            discount_code_id,
+        )
+        .await
+    }
+    async fn deprecated_202101_get_codes_lookup(&self, code: i64) -> Result<()> {
+        Discounts::deprecated_202101_get_codes_lookup(self, code).await
+    }
+    async fn deprecated_202101_create_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Discounts::deprecated_202101_create_price_rules_param_rule_batch(self, price_rule_id, body)
+            .await
+    }
+    async fn deprecated_202101_get_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_202101_get_price_rules_param_rule_batch(self, price_rule_id, batch_id)
+            .await
+    }
+    async fn deprecated_202101_get_price_rules_param_rule_batch_code(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_202101_get_price_rules_param_rule_batch_code(
+            self,
+            price_rule_id,
+            batch_id,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_price_rules_param_rule_code(
+        &self,
+        price_rule_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_unstable_get_price_rules_param_rule_code(self, price_rule_id).await
+    }
+    async fn deprecated_unstable_create_price_rules_param_rule_codes(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Discounts::deprecated_unstable_create_price_rules_param_rule_codes(
+            self,
+            price_rule_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_unstable_get_price_rules_param_rule_codes_code(
+            self,
+            price_rule_id,
+            discount_code_id,
+        )
+        .await
+    }
+    async fn deprecated_unstable_update_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Discounts::deprecated_unstable_update_price_rules_param_rule_codes_code(
+            self,
+            price_rule_id,
+            discount_code_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_unstable_delete_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_unstable_delete_price_rules_param_rule_codes_code(
+            self,
+            price_rule_id,
+            discount_code_id,
+        )
+        .await
+    }
+    async fn deprecated_unstable_delete_price_rules_param_rule_codes_code_if_exists(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<bool> {
+        Discounts::deprecated_unstable_delete_price_rules_param_rule_codes_code_if_exists(
+            self,
+            price_rule_id,
+            discount_code_id,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_codes_lookup(&self, code: i64) -> Result<()> {
+        Discounts::deprecated_unstable_get_codes_lookup(self, code).await
+    }
+    async fn deprecated_unstable_create_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Discounts::deprecated_unstable_create_price_rules_param_rule_batch(
+            self,
+            price_rule_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_unstable_get_price_rules_param_rule_batch(
+            self,
+            price_rule_id,
+            batch_id,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_price_rules_param_rule_batch_code(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_unstable_get_price_rules_param_rule_batch_code(
+            self,
+            price_rule_id,
+            batch_id,
+        )
+        .await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Events {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Events {
         Events { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Events {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieves a list of events. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -40,32 +46,34 @@ impl Events {
         verb: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !verb.is_empty() {
-            query_args.push(("verb".to_string(), verb.to_string()));
+            query_args.push(("verb", verb.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/events.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-01/events.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-01/events.json", &[]))
     }
 
     /**
@@ -81,18 +89,25 @@ impl Events {
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
     pub async fn deprecated_202001_get_param(&self, event_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/events/{}/json?{}",
-            crate::progenitor_support::encode_path(&event_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/events/{}/json",
+                self.client.encode_path(event_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/events/{event_id}.json",
+                &[("event_id", event_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -112,17 +127,19 @@ impl Events {
         created_at_min: &str,
         created_at_max: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/events/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-01/events/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/events/count.json", &[])
+        })
     }
 
     /**
@@ -153,32 +170,34 @@ impl Events {
         verb: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !verb.is_empty() {
-            query_args.push(("verb".to_string(), verb.to_string()));
+            query_args.push(("verb", verb.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/events.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-04/events.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-04/events.json", &[]))
     }
 
     /**
@@ -194,18 +213,25 @@ impl Events {
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
     pub async fn deprecated_202004_get_param(&self, event_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/events/{}/json?{}",
-            crate::progenitor_support::encode_path(&event_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/events/{}/json",
+                self.client.encode_path(event_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/events/{event_id}.json",
+                &[("event_id", event_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -225,17 +251,19 @@ impl Events {
         created_at_min: &str,
         created_at_max: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/events/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-04/events/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/events/count.json", &[])
+        })
     }
 
     /**
@@ -266,32 +294,34 @@ impl Events {
         verb: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !verb.is_empty() {
-            query_args.push(("verb".to_string(), verb.to_string()));
+            query_args.push(("verb", verb.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/events.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-07/events.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-07/events.json", &[]))
     }
 
     /**
@@ -307,18 +337,25 @@ impl Events {
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
     pub async fn deprecated_202007_get_param(&self, event_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/events/{}/json?{}",
-            crate::progenitor_support::encode_path(&event_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/events/{}/json",
+                self.client.encode_path(event_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/events/{event_id}.json",
+                &[("event_id", event_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -338,17 +375,19 @@ impl Events {
         created_at_min: &str,
         created_at_max: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/events/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-07/events/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/events/count.json", &[])
+        })
     }
 
     /**
@@ -379,32 +418,34 @@ impl Events {
         verb: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !verb.is_empty() {
-            query_args.push(("verb".to_string(), verb.to_string()));
+            query_args.push(("verb", verb.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/events.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-10/events.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-10/events.json", &[]))
     }
 
     /**
@@ -420,18 +461,25 @@ impl Events {
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
     pub async fn get_param(&self, event_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/events/{}/json?{}",
-            crate::progenitor_support::encode_path(&event_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/events/{}/json",
+                self.client.encode_path(event_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/events/{event_id}.json",
+                &[("event_id", event_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -447,17 +495,19 @@ impl Events {
      * * `created_at_max: &str` -- Count only events created at or before this date and time. (format: 2014-04-25T16:15:47-04:00).
      */
     pub async fn get_count(&self, created_at_min: &str, created_at_max: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/events/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-10/events/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/events/count.json", &[])
+        })
     }
 
     /**
@@ -488,32 +538,34 @@ impl Events {
         verb: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !verb.is_empty() {
-            query_args.push(("verb".to_string(), verb.to_string()));
+            query_args.push(("verb", verb.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/events.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2021-01/events.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/admin/api/2021-01/events.json", &[]))
     }
 
     /**
@@ -529,18 +581,25 @@ impl Events {
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
     pub async fn deprecated_202101_get_param(&self, event_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/events/{}/json?{}",
-            crate::progenitor_support::encode_path(&event_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/events/{}/json",
+                self.client.encode_path(event_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/events/{event_id}.json",
+                &[("event_id", event_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -560,17 +619,19 @@ impl Events {
         created_at_min: &str,
         created_at_max: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/events/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2021-01/events/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/events/count.json", &[])
+        })
     }
 
     /**
@@ -601,32 +662,33 @@ impl Events {
         verb: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !filter.is_empty() {
-            query_args.push(("filter".to_string(), filter.to_string()));
+            query_args.push(("filter", filter.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !verb.is_empty() {
-            query_args.push(("verb".to_string(), verb.to_string()));
+            query_args.push(("verb", verb.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/events.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/unstable/events.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/events.json", &[])
+        })
     }
 
     /**
@@ -642,18 +704,25 @@ impl Events {
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
     pub async fn deprecated_unstable_get_param(&self, event_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/events/{}/json?{}",
-            crate::progenitor_support::encode_path(&event_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/events/{}/json",
+                self.client.encode_path(event_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/events/{event_id}.json",
+                &[("event_id", event_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -673,17 +742,19 @@ impl Events {
         created_at_min: &str,
         created_at_max: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/events/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/unstable/events/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/events/count.json", &[])
+        })
     }
 
     /**
@@ -719,38 +790,39 @@ impl Events {
         updated_at_min: &str,
         updated_at_max: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !address.is_empty() {
-            query_args.push(("address".to_string(), address.to_string()));
+            query_args.push(("address", address.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !topic.is_empty() {
-            query_args.push(("topic".to_string(), topic.to_string()));
+            query_args.push(("topic", topic.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/webhooks.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-01/webhooks.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/webhooks.json", &[])
+        })
     }
 
     /**
@@ -770,16 +842,18 @@ impl Events {
         format: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !format.is_empty() {
-            query_args.push(("format".to_string(), format.to_string()));
+            query_args.push(("format", format.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/webhooks.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-01/webhooks.json", &query_args);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-01/webhooks.json", &[])
+            })
     }
 
     /**
@@ -800,17 +874,19 @@ impl Events {
         address: &str,
         topic: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !address.is_empty() {
-            query_args.push(("address".to_string(), address.to_string()));
+            query_args.push(("address", address.to_string()));
         }
         if !topic.is_empty() {
-            query_args.push(("topic".to_string(), topic.to_string()));
+            query_args.push(("topic", topic.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/webhooks/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-01/webhooks/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/webhooks/count.json", &[])
+        })
     }
 
     /**
@@ -830,18 +906,25 @@ impl Events {
         webhook_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/webhooks/{}/json?{}",
-            crate::progenitor_support::encode_path(&webhook_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/webhooks/{}/json",
+                self.client.encode_path(webhook_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/webhooks/{webhook_id}.json",
+                &[("webhook_id", webhook_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -862,12 +945,19 @@ impl Events {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/webhooks/{}/json",
-            crate::progenitor_support::encode_path(&webhook_id.to_string()),
+            self.client.encode_path(webhook_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-01/webhooks/{webhook_id}.json",
+                    &[("webhook_id", webhook_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -887,10 +977,44 @@ impl Events {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/webhooks/{}/json",
-            crate::progenitor_support::encode_path(&webhook_id.to_string()),
+            self.client.encode_path(webhook_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-01/webhooks/{webhook_id}.json",
+                &[("webhook_id", webhook_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete a webhook subscription.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-01/webhooks/{webhook_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_delete_webhooks_param_webhook`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202001_delete_webhooks_param_webhook_if_exists(
+        &self,
+        webhook_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-01/webhooks/{}/json",
+            self.client.encode_path(webhook_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-01/webhooks/{webhook_id}.json",
+                    &[("webhook_id", webhook_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -926,38 +1050,39 @@ impl Events {
         updated_at_min: &str,
         updated_at_max: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !address.is_empty() {
-            query_args.push(("address".to_string(), address.to_string()));
+            query_args.push(("address", address.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !topic.is_empty() {
-            query_args.push(("topic".to_string(), topic.to_string()));
+            query_args.push(("topic", topic.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/webhooks.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-04/webhooks.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/webhooks.json", &[])
+        })
     }
 
     /**
@@ -977,16 +1102,18 @@ impl Events {
         format: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !format.is_empty() {
-            query_args.push(("format".to_string(), format.to_string()));
+            query_args.push(("format", format.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/webhooks.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-04/webhooks.json", &query_args);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-04/webhooks.json", &[])
+            })
     }
 
     /**
@@ -1007,17 +1134,19 @@ impl Events {
         address: &str,
         topic: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !address.is_empty() {
-            query_args.push(("address".to_string(), address.to_string()));
+            query_args.push(("address", address.to_string()));
         }
         if !topic.is_empty() {
-            query_args.push(("topic".to_string(), topic.to_string()));
+            query_args.push(("topic", topic.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/webhooks/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-04/webhooks/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/webhooks/count.json", &[])
+        })
     }
 
     /**
@@ -1037,18 +1166,25 @@ impl Events {
         webhook_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/webhooks/{}/json?{}",
-            crate::progenitor_support::encode_path(&webhook_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/webhooks/{}/json",
+                self.client.encode_path(webhook_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/webhooks/{webhook_id}.json",
+                &[("webhook_id", webhook_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1069,12 +1205,19 @@ impl Events {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/webhooks/{}/json",
-            crate::progenitor_support::encode_path(&webhook_id.to_string()),
+            self.client.encode_path(webhook_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-04/webhooks/{webhook_id}.json",
+                    &[("webhook_id", webhook_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1094,10 +1237,44 @@ impl Events {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/webhooks/{}/json",
-            crate::progenitor_support::encode_path(&webhook_id.to_string()),
+            self.client.encode_path(webhook_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-04/webhooks/{webhook_id}.json",
+                &[("webhook_id", webhook_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete a webhook subscription.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-04/webhooks/{webhook_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_delete_webhooks_param_webhook`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202004_delete_webhooks_param_webhook_if_exists(
+        &self,
+        webhook_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-04/webhooks/{}/json",
+            self.client.encode_path(webhook_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-04/webhooks/{webhook_id}.json",
+                    &[("webhook_id", webhook_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1133,38 +1310,39 @@ impl Events {
         updated_at_min: &str,
         updated_at_max: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !address.is_empty() {
-            query_args.push(("address".to_string(), address.to_string()));
+            query_args.push(("address", address.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !topic.is_empty() {
-            query_args.push(("topic".to_string(), topic.to_string()));
+            query_args.push(("topic", topic.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/webhooks.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-07/webhooks.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/webhooks.json", &[])
+        })
     }
 
     /**
@@ -1184,16 +1362,18 @@ impl Events {
         format: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !format.is_empty() {
-            query_args.push(("format".to_string(), format.to_string()));
+            query_args.push(("format", format.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/webhooks.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-07/webhooks.json", &query_args);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-07/webhooks.json", &[])
+            })
     }
 
     /**
@@ -1214,17 +1394,19 @@ impl Events {
         address: &str,
         topic: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !address.is_empty() {
-            query_args.push(("address".to_string(), address.to_string()));
+            query_args.push(("address", address.to_string()));
         }
         if !topic.is_empty() {
-            query_args.push(("topic".to_string(), topic.to_string()));
+            query_args.push(("topic", topic.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/webhooks/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-07/webhooks/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/webhooks/count.json", &[])
+        })
     }
 
     /**
@@ -1244,18 +1426,25 @@ impl Events {
         webhook_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/webhooks/{}/json?{}",
-            crate::progenitor_support::encode_path(&webhook_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/webhooks/{}/json",
+                self.client.encode_path(webhook_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/webhooks/{webhook_id}.json",
+                &[("webhook_id", webhook_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1276,12 +1465,19 @@ impl Events {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/webhooks/{}/json",
-            crate::progenitor_support::encode_path(&webhook_id.to_string()),
+            self.client.encode_path(webhook_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-07/webhooks/{webhook_id}.json",
+                    &[("webhook_id", webhook_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1301,10 +1497,44 @@ impl Events {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/webhooks/{}/json",
-            crate::progenitor_support::encode_path(&webhook_id.to_string()),
+            self.client.encode_path(webhook_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-07/webhooks/{webhook_id}.json",
+                &[("webhook_id", webhook_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete a webhook subscription.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-07/webhooks/{webhook_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_delete_webhooks_param_webhook`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202007_delete_webhooks_param_webhook_if_exists(
+        &self,
+        webhook_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-07/webhooks/{}/json",
+            self.client.encode_path(webhook_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-07/webhooks/{webhook_id}.json",
+                    &[("webhook_id", webhook_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1340,38 +1570,39 @@ impl Events {
         updated_at_min: &str,
         updated_at_max: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !address.is_empty() {
-            query_args.push(("address".to_string(), address.to_string()));
+            query_args.push(("address", address.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !topic.is_empty() {
-            query_args.push(("topic".to_string(), topic.to_string()));
+            query_args.push(("topic", topic.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/webhooks.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-10/webhooks.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/webhooks.json", &[])
+        })
     }
 
     /**
@@ -1387,16 +1618,18 @@ impl Events {
      *                     (default: json).
      */
     pub async fn create_webhooks(&self, format: &str, body: &serde_json::Value) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !format.is_empty() {
-            query_args.push(("format".to_string(), format.to_string()));
+            query_args.push(("format", format.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/webhooks.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-10/webhooks.json", &query_args);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-10/webhooks.json", &[])
+            })
     }
 
     /**
@@ -1413,17 +1646,19 @@ impl Events {
      *   For a list of valid values, refer to the topic property.>.
      */
     pub async fn get_webhooks_count(&self, address: &str, topic: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !address.is_empty() {
-            query_args.push(("address".to_string(), address.to_string()));
+            query_args.push(("address", address.to_string()));
         }
         if !topic.is_empty() {
-            query_args.push(("topic".to_string(), topic.to_string()));
+            query_args.push(("topic", topic.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/webhooks/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-10/webhooks/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/webhooks/count.json", &[])
+        })
     }
 
     /**
@@ -1439,18 +1674,25 @@ impl Events {
      * * `fields: &str` -- Comma-separated list of the properties you want returned for each item in the result list. Use this parameter to restrict the returned list of items to only those properties you specify.
      */
     pub async fn get_webhooks_param_webhook(&self, webhook_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/webhooks/{}/json?{}",
-            crate::progenitor_support::encode_path(&webhook_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/webhooks/{}/json",
+                self.client.encode_path(webhook_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/webhooks/{webhook_id}.json",
+                &[("webhook_id", webhook_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1471,12 +1713,19 @@ impl Events {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/webhooks/{}/json",
-            crate::progenitor_support::encode_path(&webhook_id.to_string()),
+            self.client.encode_path(webhook_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-10/webhooks/{webhook_id}.json",
+                    &[("webhook_id", webhook_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1493,10 +1742,41 @@ impl Events {
     pub async fn delete_webhooks_param_webhook(&self, webhook_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/webhooks/{}/json",
-            crate::progenitor_support::encode_path(&webhook_id.to_string()),
+            self.client.encode_path(webhook_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-10/webhooks/{webhook_id}.json",
+                &[("webhook_id", webhook_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete a webhook subscription.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-10/webhooks/{webhook_id}.json` endpoint.
+     *
+     * As opposed to `delete_webhooks_param_webhook`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_webhooks_param_webhook_if_exists(&self, webhook_id: &str) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-10/webhooks/{}/json",
+            self.client.encode_path(webhook_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-10/webhooks/{webhook_id}.json",
+                    &[("webhook_id", webhook_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1532,38 +1812,39 @@ impl Events {
         updated_at_min: &str,
         updated_at_max: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !address.is_empty() {
-            query_args.push(("address".to_string(), address.to_string()));
+            query_args.push(("address", address.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !topic.is_empty() {
-            query_args.push(("topic".to_string(), topic.to_string()));
+            query_args.push(("topic", topic.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/webhooks.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2021-01/webhooks.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/webhooks.json", &[])
+        })
     }
 
     /**
@@ -1583,16 +1864,18 @@ impl Events {
         format: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !format.is_empty() {
-            query_args.push(("format".to_string(), format.to_string()));
+            query_args.push(("format", format.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/webhooks.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2021-01/webhooks.json", &query_args);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2021-01/webhooks.json", &[])
+            })
     }
 
     /**
@@ -1613,17 +1896,19 @@ impl Events {
         address: &str,
         topic: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !address.is_empty() {
-            query_args.push(("address".to_string(), address.to_string()));
+            query_args.push(("address", address.to_string()));
         }
         if !topic.is_empty() {
-            query_args.push(("topic".to_string(), topic.to_string()));
+            query_args.push(("topic", topic.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/webhooks/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2021-01/webhooks/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/webhooks/count.json", &[])
+        })
     }
 
     /**
@@ -1643,18 +1928,25 @@ impl Events {
         webhook_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/webhooks/{}/json?{}",
-            crate::progenitor_support::encode_path(&webhook_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/webhooks/{}/json",
+                self.client.encode_path(webhook_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/webhooks/{webhook_id}.json",
+                &[("webhook_id", webhook_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1675,12 +1967,19 @@ impl Events {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/webhooks/{}/json",
-            crate::progenitor_support::encode_path(&webhook_id.to_string()),
+            self.client.encode_path(webhook_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2021-01/webhooks/{webhook_id}.json",
+                    &[("webhook_id", webhook_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1700,10 +1999,44 @@ impl Events {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/webhooks/{}/json",
-            crate::progenitor_support::encode_path(&webhook_id.to_string()),
+            self.client.encode_path(webhook_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2021-01/webhooks/{webhook_id}.json",
+                &[("webhook_id", webhook_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete a webhook subscription.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2021-01/webhooks/{webhook_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_delete_webhooks_param_webhook`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202101_delete_webhooks_param_webhook_if_exists(
+        &self,
+        webhook_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2021-01/webhooks/{}/json",
+            self.client.encode_path(webhook_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2021-01/webhooks/{webhook_id}.json",
+                    &[("webhook_id", webhook_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1739,38 +2072,39 @@ impl Events {
         updated_at_min: &str,
         updated_at_max: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !address.is_empty() {
-            query_args.push(("address".to_string(), address.to_string()));
+            query_args.push(("address", address.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !topic.is_empty() {
-            query_args.push(("topic".to_string(), topic.to_string()));
+            query_args.push(("topic", topic.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/webhooks.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/unstable/webhooks.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/webhooks.json", &[])
+        })
     }
 
     /**
@@ -1790,16 +2124,18 @@ impl Events {
         format: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !format.is_empty() {
-            query_args.push(("format".to_string(), format.to_string()));
+            query_args.push(("format", format.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/webhooks.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/unstable/webhooks.json", &query_args);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/unstable/webhooks.json", &[])
+            })
     }
 
     /**
@@ -1820,17 +2156,19 @@ impl Events {
         address: &str,
         topic: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !address.is_empty() {
-            query_args.push(("address".to_string(), address.to_string()));
+            query_args.push(("address", address.to_string()));
         }
         if !topic.is_empty() {
-            query_args.push(("topic".to_string(), topic.to_string()));
+            query_args.push(("topic", topic.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/webhooks/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/unstable/webhooks/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/webhooks/count.json", &[])
+        })
     }
 
     /**
@@ -1850,18 +2188,25 @@ impl Events {
         webhook_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/webhooks/{}/json?{}",
-            crate::progenitor_support::encode_path(&webhook_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/webhooks/{}/json",
+                self.client.encode_path(webhook_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/webhooks/{webhook_id}.json",
+                &[("webhook_id", webhook_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1882,12 +2227,19 @@ impl Events {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/webhooks/{}/json",
-            crate::progenitor_support::encode_path(&webhook_id.to_string()),
+            self.client.encode_path(webhook_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/unstable/webhooks/{webhook_id}.json",
+                    &[("webhook_id", webhook_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1907,9 +2259,896 @@ impl Events {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/webhooks/{}/json",
-            crate::progenitor_support::encode_path(&webhook_id.to_string()),
+            self.client.encode_path(webhook_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/unstable/webhooks/{webhook_id}.json",
+                &[("webhook_id", webhook_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete a webhook subscription.
+     *
+     * This function performs a `DELETE` to the `/admin/api/unstable/webhooks/{webhook_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_delete_webhooks_param_webhook`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_unstable_delete_webhooks_param_webhook_if_exists(
+        &self,
+        webhook_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/unstable/webhooks/{}/json",
+            self.client.encode_path(webhook_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/unstable/webhooks/{webhook_id}.json",
+                    &[("webhook_id", webhook_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`Events`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn EventsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait EventsApi: Send + Sync {
+    async fn deprecated_202001_get(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        filter: &str,
+        verb: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_param(&self, event_id: &str, fields: &str) -> Result<()>;
+    async fn deprecated_202001_get_count(
+        &self,
+        created_at_min: &str,
+        created_at_max: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_get(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        filter: &str,
+        verb: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_param(&self, event_id: &str, fields: &str) -> Result<()>;
+    async fn deprecated_202004_get_count(
+        &self,
+        created_at_min: &str,
+        created_at_max: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_get(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        filter: &str,
+        verb: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_param(&self, event_id: &str, fields: &str) -> Result<()>;
+    async fn deprecated_202007_get_count(
+        &self,
+        created_at_min: &str,
+        created_at_max: &str,
+    ) -> Result<()>;
+    async fn get(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        filter: &str,
+        verb: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn get_param(&self, event_id: &str, fields: &str) -> Result<()>;
+    async fn get_count(&self, created_at_min: &str, created_at_max: &str) -> Result<()>;
+    async fn deprecated_202101_get(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        filter: &str,
+        verb: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_param(&self, event_id: &str, fields: &str) -> Result<()>;
+    async fn deprecated_202101_get_count(
+        &self,
+        created_at_min: &str,
+        created_at_max: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        filter: &str,
+        verb: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_param(&self, event_id: &str, fields: &str) -> Result<()>;
+    async fn deprecated_unstable_get_count(
+        &self,
+        created_at_min: &str,
+        created_at_max: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_webhook(
+        &self,
+        address: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        topic: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_webhooks(
+        &self,
+        format: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_webhooks_count(&self, address: &str, topic: &str) -> Result<()>;
+    async fn deprecated_202001_get_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_update_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_webhooks_param_webhook(&self, webhook_id: &str)
+        -> Result<()>;
+    async fn deprecated_202001_delete_webhooks_param_webhook_if_exists(
+        &self,
+        webhook_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202004_get_webhook(
+        &self,
+        address: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        topic: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_create_webhooks(
+        &self,
+        format: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_webhooks_count(&self, address: &str, topic: &str) -> Result<()>;
+    async fn deprecated_202004_get_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_update_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_webhooks_param_webhook(&self, webhook_id: &str)
+        -> Result<()>;
+    async fn deprecated_202004_delete_webhooks_param_webhook_if_exists(
+        &self,
+        webhook_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202007_get_webhook(
+        &self,
+        address: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        topic: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_create_webhooks(
+        &self,
+        format: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_webhooks_count(&self, address: &str, topic: &str) -> Result<()>;
+    async fn deprecated_202007_get_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_update_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_webhooks_param_webhook(&self, webhook_id: &str)
+        -> Result<()>;
+    async fn deprecated_202007_delete_webhooks_param_webhook_if_exists(
+        &self,
+        webhook_id: &str,
+    ) -> Result<bool>;
+    async fn get_webhook(
+        &self,
+        address: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        topic: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> Result<()>;
+    async fn create_webhooks(&self, format: &str, body: &serde_json::Value) -> Result<()>;
+    async fn get_webhooks_count(&self, address: &str, topic: &str) -> Result<()>;
+    async fn get_webhooks_param_webhook(&self, webhook_id: &str, fields: &str) -> Result<()>;
+    async fn update_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn delete_webhooks_param_webhook(&self, webhook_id: &str) -> Result<()>;
+    async fn delete_webhooks_param_webhook_if_exists(&self, webhook_id: &str) -> Result<bool>;
+    async fn deprecated_202101_get_webhook(
+        &self,
+        address: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        topic: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_create_webhooks(
+        &self,
+        format: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_webhooks_count(&self, address: &str, topic: &str) -> Result<()>;
+    async fn deprecated_202101_get_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_update_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_webhooks_param_webhook(&self, webhook_id: &str)
+        -> Result<()>;
+    async fn deprecated_202101_delete_webhooks_param_webhook_if_exists(
+        &self,
+        webhook_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_unstable_get_webhook(
+        &self,
+        address: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        topic: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create_webhooks(
+        &self,
+        format: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_webhooks_count(
+        &self,
+        address: &str,
+        topic: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_update_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_webhooks_param_webhook_if_exists(
+        &self,
+        webhook_id: &str,
+    ) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl EventsApi for Events {
+    async fn deprecated_202001_get(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        filter: &str,
+        verb: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Events::deprecated_202001_get(
+            self,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            filter,
+            verb,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202001_get_param(&self, event_id: &str, fields: &str) -> Result<()> {
+        Events::deprecated_202001_get_param(self, event_id, fields).await
+    }
+    async fn deprecated_202001_get_count(
+        &self,
+        created_at_min: &str,
+        created_at_max: &str,
+    ) -> Result<()> {
+        Events::deprecated_202001_get_count(self, created_at_min, created_at_max).await
+    }
+    async fn deprecated_202004_get(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        filter: &str,
+        verb: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Events::deprecated_202004_get(
+            self,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            filter,
+            verb,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_param(&self, event_id: &str, fields: &str) -> Result<()> {
+        Events::deprecated_202004_get_param(self, event_id, fields).await
+    }
+    async fn deprecated_202004_get_count(
+        &self,
+        created_at_min: &str,
+        created_at_max: &str,
+    ) -> Result<()> {
+        Events::deprecated_202004_get_count(self, created_at_min, created_at_max).await
+    }
+    async fn deprecated_202007_get(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        filter: &str,
+        verb: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Events::deprecated_202007_get(
+            self,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            filter,
+            verb,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_param(&self, event_id: &str, fields: &str) -> Result<()> {
+        Events::deprecated_202007_get_param(self, event_id, fields).await
+    }
+    async fn deprecated_202007_get_count(
+        &self,
+        created_at_min: &str,
+        created_at_max: &str,
+    ) -> Result<()> {
+        Events::deprecated_202007_get_count(self, created_at_min, created_at_max).await
+    }
+    async fn get(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        filter: &str,
+        verb: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Events::get(
+            self,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            filter,
+            verb,
+            fields,
+        )
+        .await
+    }
+    async fn get_param(&self, event_id: &str, fields: &str) -> Result<()> {
+        Events::get_param(self, event_id, fields).await
+    }
+    async fn get_count(&self, created_at_min: &str, created_at_max: &str) -> Result<()> {
+        Events::get_count(self, created_at_min, created_at_max).await
+    }
+    async fn deprecated_202101_get(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        filter: &str,
+        verb: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Events::deprecated_202101_get(
+            self,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            filter,
+            verb,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202101_get_param(&self, event_id: &str, fields: &str) -> Result<()> {
+        Events::deprecated_202101_get_param(self, event_id, fields).await
+    }
+    async fn deprecated_202101_get_count(
+        &self,
+        created_at_min: &str,
+        created_at_max: &str,
+    ) -> Result<()> {
+        Events::deprecated_202101_get_count(self, created_at_min, created_at_max).await
+    }
+    async fn deprecated_unstable_get(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        filter: &str,
+        verb: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Events::deprecated_unstable_get(
+            self,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            filter,
+            verb,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_param(&self, event_id: &str, fields: &str) -> Result<()> {
+        Events::deprecated_unstable_get_param(self, event_id, fields).await
+    }
+    async fn deprecated_unstable_get_count(
+        &self,
+        created_at_min: &str,
+        created_at_max: &str,
+    ) -> Result<()> {
+        Events::deprecated_unstable_get_count(self, created_at_min, created_at_max).await
+    }
+    async fn deprecated_202001_get_webhook(
+        &self,
+        address: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        topic: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> Result<()> {
+        Events::deprecated_202001_get_webhook(
+            self,
+            address,
+            created_at_max,
+            created_at_min,
+            fields,
+            limit,
+            since_id,
+            topic,
+            updated_at_min,
+            updated_at_max,
+        )
+        .await
+    }
+    async fn deprecated_202001_create_webhooks(
+        &self,
+        format: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Events::deprecated_202001_create_webhooks(self, format, body).await
+    }
+    async fn deprecated_202001_get_webhooks_count(&self, address: &str, topic: &str) -> Result<()> {
+        Events::deprecated_202001_get_webhooks_count(self, address, topic).await
+    }
+    async fn deprecated_202001_get_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Events::deprecated_202001_get_webhooks_param_webhook(self, webhook_id, fields).await
+    }
+    async fn deprecated_202001_update_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Events::deprecated_202001_update_webhooks_param_webhook(self, webhook_id, body).await
+    }
+    async fn deprecated_202001_delete_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+    ) -> Result<()> {
+        Events::deprecated_202001_delete_webhooks_param_webhook(self, webhook_id).await
+    }
+    async fn deprecated_202001_delete_webhooks_param_webhook_if_exists(
+        &self,
+        webhook_id: &str,
+    ) -> Result<bool> {
+        Events::deprecated_202001_delete_webhooks_param_webhook_if_exists(self, webhook_id).await
+    }
+    async fn deprecated_202004_get_webhook(
+        &self,
+        address: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        topic: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> Result<()> {
+        Events::deprecated_202004_get_webhook(
+            self,
+            address,
+            created_at_max,
+            created_at_min,
+            fields,
+            limit,
+            since_id,
+            topic,
+            updated_at_min,
+            updated_at_max,
+        )
+        .await
+    }
+    async fn deprecated_202004_create_webhooks(
+        &self,
+        format: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Events::deprecated_202004_create_webhooks(self, format, body).await
+    }
+    async fn deprecated_202004_get_webhooks_count(&self, address: &str, topic: &str) -> Result<()> {
+        Events::deprecated_202004_get_webhooks_count(self, address, topic).await
+    }
+    async fn deprecated_202004_get_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Events::deprecated_202004_get_webhooks_param_webhook(self, webhook_id, fields).await
+    }
+    async fn deprecated_202004_update_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Events::deprecated_202004_update_webhooks_param_webhook(self, webhook_id, body).await
+    }
+    async fn deprecated_202004_delete_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+    ) -> Result<()> {
+        Events::deprecated_202004_delete_webhooks_param_webhook(self, webhook_id).await
+    }
+    async fn deprecated_202004_delete_webhooks_param_webhook_if_exists(
+        &self,
+        webhook_id: &str,
+    ) -> Result<bool> {
+        Events::deprecated_202004_delete_webhooks_param_webhook_if_exists(self, webhook_id).await
+    }
+    async fn deprecated_202007_get_webhook(
+        &self,
+        address: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        topic: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> Result<()> {
+        Events::deprecated_202007_get_webhook(
+            self,
+            address,
+            created_at_max,
+            created_at_min,
+            fields,
+            limit,
+            since_id,
+            topic,
+            updated_at_min,
+            updated_at_max,
+        )
+        .await
+    }
+    async fn deprecated_202007_create_webhooks(
+        &self,
+        format: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Events::deprecated_202007_create_webhooks(self, format, body).await
+    }
+    async fn deprecated_202007_get_webhooks_count(&self, address: &str, topic: &str) -> Result<()> {
+        Events::deprecated_202007_get_webhooks_count(self, address, topic).await
+    }
+    async fn deprecated_202007_get_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Events::deprecated_202007_get_webhooks_param_webhook(self, webhook_id, fields).await
+    }
+    async fn deprecated_202007_update_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Events::deprecated_202007_update_webhooks_param_webhook(self, webhook_id, body).await
+    }
+    async fn deprecated_202007_delete_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+    ) -> Result<()> {
+        Events::deprecated_202007_delete_webhooks_param_webhook(self, webhook_id).await
+    }
+    async fn deprecated_202007_delete_webhooks_param_webhook_if_exists(
+        &self,
+        webhook_id: &str,
+    ) -> Result<bool> {
+        Events::deprecated_202007_delete_webhooks_param_webhook_if_exists(self, webhook_id).await
+    }
+    async fn get_webhook(
+        &self,
+        address: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        topic: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> Result<()> {
+        Events::get_webhook(
+            self,
+            address,
+            created_at_max,
+            created_at_min,
+            fields,
+            limit,
+            since_id,
+            topic,
+            updated_at_min,
+            updated_at_max,
+        )
+        .await
+    }
+    async fn create_webhooks(&self, format: &str, body: &serde_json::Value) -> Result<()> {
+        Events::create_webhooks(self, format, body).await
+    }
+    async fn get_webhooks_count(&self, address: &str, topic: &str) -> Result<()> {
+        Events::get_webhooks_count(self, address, topic).await
+    }
+    async fn get_webhooks_param_webhook(&self, webhook_id: &str, fields: &str) -> Result<()> {
+        Events::get_webhooks_param_webhook(self, webhook_id, fields).await
+    }
+    async fn update_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Events::update_webhooks_param_webhook(self, webhook_id, body).await
+    }
+    async fn delete_webhooks_param_webhook(&self, webhook_id: &str) -> Result<()> {
+        Events::delete_webhooks_param_webhook(self, webhook_id).await
+    }
+    async fn delete_webhooks_param_webhook_if_exists(&self, webhook_id: &str) -> Result<bool> {
+        Events::delete_webhooks_param_webhook_if_exists(self, webhook_id).await
+    }
+    async fn deprecated_202101_get_webhook(
+        &self,
+        address: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        topic: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> Result<()> {
+        Events::deprecated_202101_get_webhook(
+            self,
+            address,
+            created_at_max,
+            created_at_min,
+            fields,
+            limit,
+            since_id,
+            topic,
+            updated_at_min,
+            updated_at_max,
+        )
+        .await
+    }
+    async fn deprecated_202101_create_webhooks(
+        &self,
+        format: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Events::deprecated_202101_create_webhooks(self, format, body).await
+    }
+    async fn deprecated_202101_get_webhooks_count(&self, address: &str, topic: &str) -> Result<()> {
+        Events::deprecated_202101_get_webhooks_count(self, address, topic).await
+    }
+    async fn deprecated_202101_get_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Events::deprecated_202101_get_webhooks_param_webhook(self, webhook_id, fields).await
+    }
+    async fn deprecated_202101_update_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Events::deprecated_202101_update_webhooks_param_webhook(self, webhook_id, body).await
+    }
+    async fn deprecated_202101_delete_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+    ) -> Result<()> {
+        Events::deprecated_202101_delete_webhooks_param_webhook(self, webhook_id).await
+    }
+    async fn deprecated_202101_delete_webhooks_param_webhook_if_exists(
+        &self,
+        webhook_id: &str,
+    ) -> Result<bool> {
+        Events::deprecated_202101_delete_webhooks_param_webhook_if_exists(self, webhook_id).await
+    }
+    async fn deprecated_unstable_get_webhook(
+        &self,
+        address: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        topic: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> Result<()> {
+        Events::deprecated_unstable_get_webhook(
+            self,
+            address,
+            created_at_max,
+            created_at_min,
+            fields,
+            limit,
+            since_id,
+            topic,
+            updated_at_min,
+            updated_at_max,
+        )
+        .await
+    }
+    async fn deprecated_unstable_create_webhooks(
+        &self,
+        format: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Events::deprecated_unstable_create_webhooks(self, format, body).await
+    }
+    async fn deprecated_unstable_get_webhooks_count(
+        &self,
+        address: &str,
+        topic: &str,
+    ) -> Result<()> {
+        Events::deprecated_unstable_get_webhooks_count(self, address, topic).await
+    }
+    async fn deprecated_unstable_get_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Events::deprecated_unstable_get_webhooks_param_webhook(self, webhook_id, fields).await
+    }
+    async fn deprecated_unstable_update_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Events::deprecated_unstable_update_webhooks_param_webhook(self, webhook_id, body).await
+    }
+    async fn deprecated_unstable_delete_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+    ) -> Result<()> {
+        Events::deprecated_unstable_delete_webhooks_param_webhook(self, webhook_id).await
+    }
+    async fn deprecated_unstable_delete_webhooks_param_webhook_if_exists(
+        &self,
+        webhook_id: &str,
+    ) -> Result<bool> {
+        Events::deprecated_unstable_delete_webhooks_param_webhook_if_exists(self, webhook_id).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Inventory {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Inventory {
         Inventory { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Inventory {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieves a list of inventory items. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -33,9 +39,9 @@ impl Inventory {
         limit: &str,
         ids: i64,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if ids > 0 {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !ids_required.is_empty() {
             query_args.push((
@@ -46,12 +52,14 @@ impl Inventory {
             ));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/inventory_items.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-01/inventory_items.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/inventory_items.json", &[])
+        })
     }
 
     /**
@@ -71,10 +79,16 @@ impl Inventory {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/inventory_items/{}/json",
-            crate::progenitor_support::encode_path(&inventory_item_id.to_string()),
+            self.client.encode_path(inventory_item_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/inventory_items/{inventory_item_id}.json",
+                &[("inventory_item_id", inventory_item_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -95,12 +109,19 @@ impl Inventory {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/inventory_items/{}/json",
-            crate::progenitor_support::encode_path(&inventory_item_id.to_string()),
+            self.client.encode_path(inventory_item_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-01/inventory_items/{inventory_item_id}.json",
+                    &[("inventory_item_id", inventory_item_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -124,9 +145,9 @@ impl Inventory {
         limit: &str,
         ids: i64,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if ids > 0 {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !ids_required.is_empty() {
             query_args.push((
@@ -137,12 +158,14 @@ impl Inventory {
             ));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/inventory_items.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-04/inventory_items.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/inventory_items.json", &[])
+        })
     }
 
     /**
@@ -162,10 +185,16 @@ impl Inventory {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/inventory_items/{}/json",
-            crate::progenitor_support::encode_path(&inventory_item_id.to_string()),
+            self.client.encode_path(inventory_item_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/inventory_items/{inventory_item_id}.json",
+                &[("inventory_item_id", inventory_item_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -186,12 +215,19 @@ impl Inventory {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/inventory_items/{}/json",
-            crate::progenitor_support::encode_path(&inventory_item_id.to_string()),
+            self.client.encode_path(inventory_item_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-04/inventory_items/{inventory_item_id}.json",
+                    &[("inventory_item_id", inventory_item_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -215,9 +251,9 @@ impl Inventory {
         limit: &str,
         ids: i64,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if ids > 0 {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !ids_required.is_empty() {
             query_args.push((
@@ -228,12 +264,14 @@ impl Inventory {
             ));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/inventory_items.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-07/inventory_items.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/inventory_items.json", &[])
+        })
     }
 
     /**
@@ -253,10 +291,16 @@ impl Inventory {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/inventory_items/{}/json",
-            crate::progenitor_support::encode_path(&inventory_item_id.to_string()),
+            self.client.encode_path(inventory_item_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/inventory_items/{inventory_item_id}.json",
+                &[("inventory_item_id", inventory_item_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -277,12 +321,19 @@ impl Inventory {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/inventory_items/{}/json",
-            crate::progenitor_support::encode_path(&inventory_item_id.to_string()),
+            self.client.encode_path(inventory_item_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-07/inventory_items/{inventory_item_id}.json",
+                    &[("inventory_item_id", inventory_item_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -301,9 +352,9 @@ impl Inventory {
      * * `ids: i64` -- recurring_application_charge[capped_amount].
      */
     pub async fn get_item(&self, ids_required: &str, limit: &str, ids: i64) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if ids > 0 {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !ids_required.is_empty() {
             query_args.push((
@@ -314,12 +365,14 @@ impl Inventory {
             ));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/inventory_items.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-10/inventory_items.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/inventory_items.json", &[])
+        })
     }
 
     /**
@@ -336,10 +389,16 @@ impl Inventory {
     pub async fn get_items_param_item(&self, inventory_item_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/inventory_items/{}/json",
-            crate::progenitor_support::encode_path(&inventory_item_id.to_string()),
+            self.client.encode_path(inventory_item_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/inventory_items/{inventory_item_id}.json",
+                &[("inventory_item_id", inventory_item_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -360,12 +419,19 @@ impl Inventory {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/inventory_items/{}/json",
-            crate::progenitor_support::encode_path(&inventory_item_id.to_string()),
+            self.client.encode_path(inventory_item_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-10/inventory_items/{inventory_item_id}.json",
+                    &[("inventory_item_id", inventory_item_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -389,9 +455,9 @@ impl Inventory {
         limit: &str,
         ids: i64,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if ids > 0 {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !ids_required.is_empty() {
             query_args.push((
@@ -402,12 +468,14 @@ impl Inventory {
             ));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/inventory_items.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2021-01/inventory_items.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/inventory_items.json", &[])
+        })
     }
 
     /**
@@ -427,10 +495,16 @@ impl Inventory {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/inventory_items/{}/json",
-            crate::progenitor_support::encode_path(&inventory_item_id.to_string()),
+            self.client.encode_path(inventory_item_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/inventory_items/{inventory_item_id}.json",
+                &[("inventory_item_id", inventory_item_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -451,12 +525,19 @@ impl Inventory {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/inventory_items/{}/json",
-            crate::progenitor_support::encode_path(&inventory_item_id.to_string()),
+            self.client.encode_path(inventory_item_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2021-01/inventory_items/{inventory_item_id}.json",
+                    &[("inventory_item_id", inventory_item_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -480,9 +561,9 @@ impl Inventory {
         limit: &str,
         ids: i64,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if ids > 0 {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !ids_required.is_empty() {
             query_args.push((
@@ -493,12 +574,14 @@ impl Inventory {
             ));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/inventory_items.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/unstable/inventory_items.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/inventory_items.json", &[])
+        })
     }
 
     /**
@@ -518,10 +601,16 @@ impl Inventory {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/inventory_items/{}/json",
-            crate::progenitor_support::encode_path(&inventory_item_id.to_string()),
+            self.client.encode_path(inventory_item_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/inventory_items/{inventory_item_id}.json",
+                &[("inventory_item_id", inventory_item_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -542,12 +631,19 @@ impl Inventory {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/inventory_items/{}/json",
-            crate::progenitor_support::encode_path(&inventory_item_id.to_string()),
+            self.client.encode_path(inventory_item_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/unstable/inventory_items/{inventory_item_id}.json",
+                    &[("inventory_item_id", inventory_item_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -576,26 +672,25 @@ impl Inventory {
         limit: &str,
         updated_at_min: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !inventory_item_ids.is_empty() {
-            query_args.push((
-                "inventory_item_ids".to_string(),
-                inventory_item_ids.to_string(),
-            ));
+            query_args.push(("inventory_item_ids", inventory_item_ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !location_ids.is_empty() {
-            query_args.push(("location_ids".to_string(), location_ids.to_string()));
+            query_args.push(("location_ids", location_ids.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/inventory_levels.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-01/inventory_levels.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/inventory_levels.json", &[])
+        })
     }
 
     /**
@@ -622,13 +717,58 @@ impl Inventory {
         inventory_item_id: i64,
         location_id: i64,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if inventory_item_id > 0 {
+            query_args.push(("inventory_item_id", inventory_item_id.to_string()));
+        }
+        if !inventory_item_id_required.is_empty() {
+            query_args.push((
+                "inventory_item_id
+                  required"
+                    .to_string(),
+                inventory_item_id_required.to_string(),
+            ));
+        }
+        if location_id > 0 {
+            query_args.push(("location_id", location_id.to_string()));
+        }
+        if !location_id_required.is_empty() {
             query_args.push((
-                "inventory_item_id".to_string(),
-                inventory_item_id.to_string(),
+                "location_id
+                  required"
+                    .to_string(),
+                location_id_required.to_string(),
             ));
         }
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-01/inventory_levels.json", &query_args);
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("DELETE", "/admin/api/2020-01/inventory_levels.json", &[])
+        })
+    }
+
+    /**
+    * Deletes an inventory level of an inventory item at a location.
+              Deleting an inventory level for an inventory item removes that item from the specified location.
+              Every inventory item must have at least one inventory level. To move inventory to another location,
+              first connect the inventory item to another location, and then delete the previous inventory level.
+    *
+    * This function performs a `DELETE` to the `/admin/api/2020-01/inventory_levels.json` endpoint.
+    *
+    * As opposed to `deprecated_202001_delete_levels`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+    */
+    pub async fn deprecated_202001_delete_levels_if_exists(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> Result<bool> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if inventory_item_id > 0 {
+            query_args.push(("inventory_item_id", inventory_item_id.to_string()));
+        }
         if !inventory_item_id_required.is_empty() {
             query_args.push((
                 "inventory_item_id
@@ -638,7 +778,7 @@ impl Inventory {
             ));
         }
         if location_id > 0 {
-            query_args.push(("location_id".to_string(), location_id.to_string()));
+            query_args.push(("location_id", location_id.to_string()));
         }
         if !location_id_required.is_empty() {
             query_args.push((
@@ -648,10 +788,15 @@ impl Inventory {
                 location_id_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/inventory_levels.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-01/inventory_levels.json", &query_args);
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url("DELETE", "/admin/api/2020-01/inventory_levels.json", &[])
+            })
     }
 
     /**
@@ -674,7 +819,7 @@ impl Inventory {
         available_adjustment_required: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !available_adjustment_required.is_empty() {
             query_args.push((
                 "available_adjustment
@@ -699,12 +844,21 @@ impl Inventory {
                 location_id_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/inventory_levels/adjust.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-01/inventory_levels/adjust.json",
+            &query_args,
+        );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-01/inventory_levels/adjust.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -730,7 +884,7 @@ impl Inventory {
         relocate_if_necessary: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !inventory_item_id_required.is_empty() {
             query_args.push((
                 "inventory_item_id
@@ -748,20 +902,23 @@ impl Inventory {
             ));
         }
         if !relocate_if_necessary.is_empty() {
-            query_args.push((
-                "relocate_if_necessary".to_string(),
-                relocate_if_necessary.to_string(),
-            ));
+            query_args.push(("relocate_if_necessary", relocate_if_necessary.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/inventory_levels/connect.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-01/inventory_levels/connect.json",
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-01/inventory_levels/connect.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -790,7 +947,7 @@ impl Inventory {
         disconnect_if_necessary: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !available_required.is_empty() {
             query_args.push((
                 "available
@@ -801,7 +958,7 @@ impl Inventory {
         }
         if !disconnect_if_necessary.is_empty() {
             query_args.push((
-                "disconnect_if_necessary".to_string(),
+                "disconnect_if_necessary",
                 disconnect_if_necessary.to_string(),
             ));
         }
@@ -821,12 +978,21 @@ impl Inventory {
                 location_id_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/inventory_levels/set.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-01/inventory_levels/set.json",
+            &query_args,
+        );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-01/inventory_levels/set.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -855,26 +1021,25 @@ impl Inventory {
         limit: &str,
         updated_at_min: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !inventory_item_ids.is_empty() {
-            query_args.push((
-                "inventory_item_ids".to_string(),
-                inventory_item_ids.to_string(),
-            ));
+            query_args.push(("inventory_item_ids", inventory_item_ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !location_ids.is_empty() {
-            query_args.push(("location_ids".to_string(), location_ids.to_string()));
+            query_args.push(("location_ids", location_ids.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/inventory_levels.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-04/inventory_levels.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/inventory_levels.json", &[])
+        })
     }
 
     /**
@@ -901,13 +1066,58 @@ impl Inventory {
         inventory_item_id: i64,
         location_id: i64,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if inventory_item_id > 0 {
+            query_args.push(("inventory_item_id", inventory_item_id.to_string()));
+        }
+        if !inventory_item_id_required.is_empty() {
+            query_args.push((
+                "inventory_item_id
+                  required"
+                    .to_string(),
+                inventory_item_id_required.to_string(),
+            ));
+        }
+        if location_id > 0 {
+            query_args.push(("location_id", location_id.to_string()));
+        }
+        if !location_id_required.is_empty() {
             query_args.push((
-                "inventory_item_id".to_string(),
-                inventory_item_id.to_string(),
+                "location_id
+                  required"
+                    .to_string(),
+                location_id_required.to_string(),
             ));
         }
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-04/inventory_levels.json", &query_args);
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("DELETE", "/admin/api/2020-04/inventory_levels.json", &[])
+        })
+    }
+
+    /**
+    * Deletes an inventory level of an inventory item at a location.
+              Deleting an inventory level for an inventory item removes that item from the specified location.
+              Every inventory item must have at least one inventory level. To move inventory to another location,
+              first connect the inventory item to another location, and then delete the previous inventory level.
+    *
+    * This function performs a `DELETE` to the `/admin/api/2020-04/inventory_levels.json` endpoint.
+    *
+    * As opposed to `deprecated_202004_delete_levels`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+    */
+    pub async fn deprecated_202004_delete_levels_if_exists(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> Result<bool> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if inventory_item_id > 0 {
+            query_args.push(("inventory_item_id", inventory_item_id.to_string()));
+        }
         if !inventory_item_id_required.is_empty() {
             query_args.push((
                 "inventory_item_id
@@ -917,7 +1127,7 @@ impl Inventory {
             ));
         }
         if location_id > 0 {
-            query_args.push(("location_id".to_string(), location_id.to_string()));
+            query_args.push(("location_id", location_id.to_string()));
         }
         if !location_id_required.is_empty() {
             query_args.push((
@@ -927,10 +1137,15 @@ impl Inventory {
                 location_id_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/inventory_levels.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-04/inventory_levels.json", &query_args);
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url("DELETE", "/admin/api/2020-04/inventory_levels.json", &[])
+            })
     }
 
     /**
@@ -953,7 +1168,7 @@ impl Inventory {
         available_adjustment_required: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !available_adjustment_required.is_empty() {
             query_args.push((
                 "available_adjustment
@@ -978,12 +1193,21 @@ impl Inventory {
                 location_id_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/inventory_levels/adjust.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-04/inventory_levels/adjust.json",
+            &query_args,
+        );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-04/inventory_levels/adjust.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -1009,7 +1233,7 @@ impl Inventory {
         relocate_if_necessary: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !inventory_item_id_required.is_empty() {
             query_args.push((
                 "inventory_item_id
@@ -1027,20 +1251,23 @@ impl Inventory {
             ));
         }
         if !relocate_if_necessary.is_empty() {
-            query_args.push((
-                "relocate_if_necessary".to_string(),
-                relocate_if_necessary.to_string(),
-            ));
+            query_args.push(("relocate_if_necessary", relocate_if_necessary.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/inventory_levels/connect.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-04/inventory_levels/connect.json",
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-04/inventory_levels/connect.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -1069,7 +1296,7 @@ impl Inventory {
         disconnect_if_necessary: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !available_required.is_empty() {
             query_args.push((
                 "available
@@ -1080,7 +1307,7 @@ impl Inventory {
         }
         if !disconnect_if_necessary.is_empty() {
             query_args.push((
-                "disconnect_if_necessary".to_string(),
+                "disconnect_if_necessary",
                 disconnect_if_necessary.to_string(),
             ));
         }
@@ -1100,12 +1327,21 @@ impl Inventory {
                 location_id_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/inventory_levels/set.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-04/inventory_levels/set.json",
+            &query_args,
+        );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-04/inventory_levels/set.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -1134,26 +1370,25 @@ impl Inventory {
         limit: &str,
         updated_at_min: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !inventory_item_ids.is_empty() {
-            query_args.push((
-                "inventory_item_ids".to_string(),
-                inventory_item_ids.to_string(),
-            ));
+            query_args.push(("inventory_item_ids", inventory_item_ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !location_ids.is_empty() {
-            query_args.push(("location_ids".to_string(), location_ids.to_string()));
+            query_args.push(("location_ids", location_ids.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/inventory_levels.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-07/inventory_levels.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/inventory_levels.json", &[])
+        })
     }
 
     /**
@@ -1180,13 +1415,58 @@ impl Inventory {
         inventory_item_id: i64,
         location_id: i64,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if inventory_item_id > 0 {
+            query_args.push(("inventory_item_id", inventory_item_id.to_string()));
+        }
+        if !inventory_item_id_required.is_empty() {
             query_args.push((
-                "inventory_item_id".to_string(),
-                inventory_item_id.to_string(),
+                "inventory_item_id
+                  required"
+                    .to_string(),
+                inventory_item_id_required.to_string(),
+            ));
+        }
+        if location_id > 0 {
+            query_args.push(("location_id", location_id.to_string()));
+        }
+        if !location_id_required.is_empty() {
+            query_args.push((
+                "location_id
+                  required"
+                    .to_string(),
+                location_id_required.to_string(),
             ));
         }
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-07/inventory_levels.json", &query_args);
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("DELETE", "/admin/api/2020-07/inventory_levels.json", &[])
+        })
+    }
+
+    /**
+    * Deletes an inventory level of an inventory item at a location.
+              Deleting an inventory level for an inventory item removes that item from the specified location.
+              Every inventory item must have at least one inventory level. To move inventory to another location,
+              first connect the inventory item to another location, and then delete the previous inventory level.
+    *
+    * This function performs a `DELETE` to the `/admin/api/2020-07/inventory_levels.json` endpoint.
+    *
+    * As opposed to `deprecated_202007_delete_levels`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+    */
+    pub async fn deprecated_202007_delete_levels_if_exists(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> Result<bool> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if inventory_item_id > 0 {
+            query_args.push(("inventory_item_id", inventory_item_id.to_string()));
+        }
         if !inventory_item_id_required.is_empty() {
             query_args.push((
                 "inventory_item_id
@@ -1196,7 +1476,7 @@ impl Inventory {
             ));
         }
         if location_id > 0 {
-            query_args.push(("location_id".to_string(), location_id.to_string()));
+            query_args.push(("location_id", location_id.to_string()));
         }
         if !location_id_required.is_empty() {
             query_args.push((
@@ -1206,10 +1486,15 @@ impl Inventory {
                 location_id_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/inventory_levels.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-07/inventory_levels.json", &query_args);
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url("DELETE", "/admin/api/2020-07/inventory_levels.json", &[])
+            })
     }
 
     /**
@@ -1232,7 +1517,7 @@ impl Inventory {
         available_adjustment_required: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !available_adjustment_required.is_empty() {
             query_args.push((
                 "available_adjustment
@@ -1257,12 +1542,21 @@ impl Inventory {
                 location_id_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/inventory_levels/adjust.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-07/inventory_levels/adjust.json",
+            &query_args,
+        );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-07/inventory_levels/adjust.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -1288,7 +1582,7 @@ impl Inventory {
         relocate_if_necessary: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !inventory_item_id_required.is_empty() {
             query_args.push((
                 "inventory_item_id
@@ -1306,20 +1600,23 @@ impl Inventory {
             ));
         }
         if !relocate_if_necessary.is_empty() {
-            query_args.push((
-                "relocate_if_necessary".to_string(),
-                relocate_if_necessary.to_string(),
-            ));
+            query_args.push(("relocate_if_necessary", relocate_if_necessary.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/inventory_levels/connect.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-07/inventory_levels/connect.json",
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-07/inventory_levels/connect.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -1348,7 +1645,7 @@ impl Inventory {
         disconnect_if_necessary: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !available_required.is_empty() {
             query_args.push((
                 "available
@@ -1359,7 +1656,7 @@ impl Inventory {
         }
         if !disconnect_if_necessary.is_empty() {
             query_args.push((
-                "disconnect_if_necessary".to_string(),
+                "disconnect_if_necessary",
                 disconnect_if_necessary.to_string(),
             ));
         }
@@ -1379,12 +1676,21 @@ impl Inventory {
                 location_id_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/inventory_levels/set.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-07/inventory_levels/set.json",
+            &query_args,
+        );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-07/inventory_levels/set.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -1413,26 +1719,25 @@ impl Inventory {
         limit: &str,
         updated_at_min: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !inventory_item_ids.is_empty() {
-            query_args.push((
-                "inventory_item_ids".to_string(),
-                inventory_item_ids.to_string(),
-            ));
+            query_args.push(("inventory_item_ids", inventory_item_ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !location_ids.is_empty() {
-            query_args.push(("location_ids".to_string(), location_ids.to_string()));
+            query_args.push(("location_ids", location_ids.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/inventory_levels.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-10/inventory_levels.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/inventory_levels.json", &[])
+        })
     }
 
     /**
@@ -1459,13 +1764,58 @@ impl Inventory {
         inventory_item_id: i64,
         location_id: i64,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if inventory_item_id > 0 {
+            query_args.push(("inventory_item_id", inventory_item_id.to_string()));
+        }
+        if !inventory_item_id_required.is_empty() {
+            query_args.push((
+                "inventory_item_id
+                  required"
+                    .to_string(),
+                inventory_item_id_required.to_string(),
+            ));
+        }
+        if location_id > 0 {
+            query_args.push(("location_id", location_id.to_string()));
+        }
+        if !location_id_required.is_empty() {
             query_args.push((
-                "inventory_item_id".to_string(),
-                inventory_item_id.to_string(),
+                "location_id
+                  required"
+                    .to_string(),
+                location_id_required.to_string(),
             ));
         }
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-10/inventory_levels.json", &query_args);
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("DELETE", "/admin/api/2020-10/inventory_levels.json", &[])
+        })
+    }
+
+    /**
+    * Deletes an inventory level of an inventory item at a location.
+              Deleting an inventory level for an inventory item removes that item from the specified location.
+              Every inventory item must have at least one inventory level. To move inventory to another location,
+              first connect the inventory item to another location, and then delete the previous inventory level.
+    *
+    * This function performs a `DELETE` to the `/admin/api/2020-10/inventory_levels.json` endpoint.
+    *
+    * As opposed to `delete_levels`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+    */
+    pub async fn delete_levels_if_exists(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> Result<bool> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if inventory_item_id > 0 {
+            query_args.push(("inventory_item_id", inventory_item_id.to_string()));
+        }
         if !inventory_item_id_required.is_empty() {
             query_args.push((
                 "inventory_item_id
@@ -1475,7 +1825,7 @@ impl Inventory {
             ));
         }
         if location_id > 0 {
-            query_args.push(("location_id".to_string(), location_id.to_string()));
+            query_args.push(("location_id", location_id.to_string()));
         }
         if !location_id_required.is_empty() {
             query_args.push((
@@ -1485,10 +1835,15 @@ impl Inventory {
                 location_id_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/inventory_levels.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-10/inventory_levels.json", &query_args);
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url("DELETE", "/admin/api/2020-10/inventory_levels.json", &[])
+            })
     }
 
     /**
@@ -1511,7 +1866,7 @@ impl Inventory {
         available_adjustment_required: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !available_adjustment_required.is_empty() {
             query_args.push((
                 "available_adjustment
@@ -1536,12 +1891,21 @@ impl Inventory {
                 location_id_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/inventory_levels/adjust.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-10/inventory_levels/adjust.json",
+            &query_args,
+        );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-10/inventory_levels/adjust.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -1567,7 +1931,7 @@ impl Inventory {
         relocate_if_necessary: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !inventory_item_id_required.is_empty() {
             query_args.push((
                 "inventory_item_id
@@ -1585,20 +1949,23 @@ impl Inventory {
             ));
         }
         if !relocate_if_necessary.is_empty() {
-            query_args.push((
-                "relocate_if_necessary".to_string(),
-                relocate_if_necessary.to_string(),
-            ));
+            query_args.push(("relocate_if_necessary", relocate_if_necessary.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/inventory_levels/connect.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-10/inventory_levels/connect.json",
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-10/inventory_levels/connect.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -1627,7 +1994,7 @@ impl Inventory {
         disconnect_if_necessary: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !available_required.is_empty() {
             query_args.push((
                 "available
@@ -1638,7 +2005,7 @@ impl Inventory {
         }
         if !disconnect_if_necessary.is_empty() {
             query_args.push((
-                "disconnect_if_necessary".to_string(),
+                "disconnect_if_necessary",
                 disconnect_if_necessary.to_string(),
             ));
         }
@@ -1658,12 +2025,21 @@ impl Inventory {
                 location_id_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/inventory_levels/set.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-10/inventory_levels/set.json",
+            &query_args,
+        );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-10/inventory_levels/set.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -1692,26 +2068,25 @@ impl Inventory {
         limit: &str,
         updated_at_min: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !inventory_item_ids.is_empty() {
-            query_args.push((
-                "inventory_item_ids".to_string(),
-                inventory_item_ids.to_string(),
-            ));
+            query_args.push(("inventory_item_ids", inventory_item_ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !location_ids.is_empty() {
-            query_args.push(("location_ids".to_string(), location_ids.to_string()));
+            query_args.push(("location_ids", location_ids.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/inventory_levels.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2021-01/inventory_levels.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/inventory_levels.json", &[])
+        })
     }
 
     /**
@@ -1738,13 +2113,58 @@ impl Inventory {
         inventory_item_id: i64,
         location_id: i64,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if inventory_item_id > 0 {
+            query_args.push(("inventory_item_id", inventory_item_id.to_string()));
+        }
+        if !inventory_item_id_required.is_empty() {
+            query_args.push((
+                "inventory_item_id
+                  required"
+                    .to_string(),
+                inventory_item_id_required.to_string(),
+            ));
+        }
+        if location_id > 0 {
+            query_args.push(("location_id", location_id.to_string()));
+        }
+        if !location_id_required.is_empty() {
             query_args.push((
-                "inventory_item_id".to_string(),
-                inventory_item_id.to_string(),
+                "location_id
+                  required"
+                    .to_string(),
+                location_id_required.to_string(),
             ));
         }
+        let url =
+            crate::Client::url_with_query("/admin/api/2021-01/inventory_levels.json", &query_args);
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("DELETE", "/admin/api/2021-01/inventory_levels.json", &[])
+        })
+    }
+
+    /**
+    * Deletes an inventory level of an inventory item at a location.
+              Deleting an inventory level for an inventory item removes that item from the specified location.
+              Every inventory item must have at least one inventory level. To move inventory to another location,
+              first connect the inventory item to another location, and then delete the previous inventory level.
+    *
+    * This function performs a `DELETE` to the `/admin/api/2021-01/inventory_levels.json` endpoint.
+    *
+    * As opposed to `deprecated_202101_delete_levels`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+    */
+    pub async fn deprecated_202101_delete_levels_if_exists(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> Result<bool> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if inventory_item_id > 0 {
+            query_args.push(("inventory_item_id", inventory_item_id.to_string()));
+        }
         if !inventory_item_id_required.is_empty() {
             query_args.push((
                 "inventory_item_id
@@ -1754,7 +2174,7 @@ impl Inventory {
             ));
         }
         if location_id > 0 {
-            query_args.push(("location_id".to_string(), location_id.to_string()));
+            query_args.push(("location_id", location_id.to_string()));
         }
         if !location_id_required.is_empty() {
             query_args.push((
@@ -1764,10 +2184,15 @@ impl Inventory {
                 location_id_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/inventory_levels.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2021-01/inventory_levels.json", &query_args);
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url("DELETE", "/admin/api/2021-01/inventory_levels.json", &[])
+            })
     }
 
     /**
@@ -1790,7 +2215,7 @@ impl Inventory {
         available_adjustment_required: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !available_adjustment_required.is_empty() {
             query_args.push((
                 "available_adjustment
@@ -1815,12 +2240,21 @@ impl Inventory {
                 location_id_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/inventory_levels/adjust.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2021-01/inventory_levels/adjust.json",
+            &query_args,
+        );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2021-01/inventory_levels/adjust.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -1846,7 +2280,7 @@ impl Inventory {
         relocate_if_necessary: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !inventory_item_id_required.is_empty() {
             query_args.push((
                 "inventory_item_id
@@ -1864,20 +2298,23 @@ impl Inventory {
             ));
         }
         if !relocate_if_necessary.is_empty() {
-            query_args.push((
-                "relocate_if_necessary".to_string(),
-                relocate_if_necessary.to_string(),
-            ));
+            query_args.push(("relocate_if_necessary", relocate_if_necessary.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/inventory_levels/connect.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/2021-01/inventory_levels/connect.json",
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2021-01/inventory_levels/connect.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -1906,7 +2343,7 @@ impl Inventory {
         disconnect_if_necessary: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !available_required.is_empty() {
             query_args.push((
                 "available
@@ -1917,7 +2354,7 @@ impl Inventory {
         }
         if !disconnect_if_necessary.is_empty() {
             query_args.push((
-                "disconnect_if_necessary".to_string(),
+                "disconnect_if_necessary",
                 disconnect_if_necessary.to_string(),
             ));
         }
@@ -1937,12 +2374,21 @@ impl Inventory {
                 location_id_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/inventory_levels/set.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2021-01/inventory_levels/set.json",
+            &query_args,
+        );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2021-01/inventory_levels/set.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -1971,26 +2417,25 @@ impl Inventory {
         limit: &str,
         updated_at_min: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !inventory_item_ids.is_empty() {
-            query_args.push((
-                "inventory_item_ids".to_string(),
-                inventory_item_ids.to_string(),
-            ));
+            query_args.push(("inventory_item_ids", inventory_item_ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !location_ids.is_empty() {
-            query_args.push(("location_ids".to_string(), location_ids.to_string()));
+            query_args.push(("location_ids", location_ids.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/inventory_levels.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/unstable/inventory_levels.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/inventory_levels.json", &[])
+        })
     }
 
     /**
@@ -2017,12 +2462,9 @@ impl Inventory {
         inventory_item_id: i64,
         location_id: i64,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if inventory_item_id > 0 {
-            query_args.push((
-                "inventory_item_id".to_string(),
-                inventory_item_id.to_string(),
-            ));
+            query_args.push(("inventory_item_id", inventory_item_id.to_string()));
         }
         if !inventory_item_id_required.is_empty() {
             query_args.push((
@@ -2033,7 +2475,7 @@ impl Inventory {
             ));
         }
         if location_id > 0 {
-            query_args.push(("location_id".to_string(), location_id.to_string()));
+            query_args.push(("location_id", location_id.to_string()));
         }
         if !location_id_required.is_empty() {
             query_args.push((
@@ -2043,37 +2485,90 @@ impl Inventory {
                 location_id_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/inventory_levels.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/unstable/inventory_levels.json", &query_args);
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("DELETE", "/admin/api/unstable/inventory_levels.json", &[])
+        })
     }
 
     /**
-     * Adjusts the inventory level of an inventory item at a single location.
-     *
-     * This function performs a `POST` to the `/admin/api/unstable/inventory_levels/adjust.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#adjust-unstable
-     *
-     * **Parameters:**
-     *
-     * * `inventory_item_required: &str` -- The ID of the inventory item.
-     * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-     * * `available_adjustment_required: &str` -- The amount to adjust the available inventory quantity. Send negative values to subtract from the current available quantity. For example, "available_adjustment": 2 increases the current available quantity by 2, and "available_adjustment": -3decreases the current available quantity by 3.
-     */
-    pub async fn deprecated_unstable_create_levels_adjust(
+    * Deletes an inventory level of an inventory item at a location.
+              Deleting an inventory level for an inventory item removes that item from the specified location.
+              Every inventory item must have at least one inventory level. To move inventory to another location,
+              first connect the inventory item to another location, and then delete the previous inventory level.
+    *
+    * This function performs a `DELETE` to the `/admin/api/unstable/inventory_levels.json` endpoint.
+    *
+    * As opposed to `deprecated_unstable_delete_levels`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+    */
+    pub async fn deprecated_unstable_delete_levels_if_exists(
         &self,
         inventory_item_id_required: &str,
         location_id_required: &str,
-        available_adjustment_required: &str,
-        body: &serde_json::Value,
-    ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !available_adjustment_required.is_empty() {
-            query_args.push((
-                "available_adjustment
-                  required"
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> Result<bool> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if inventory_item_id > 0 {
+            query_args.push(("inventory_item_id", inventory_item_id.to_string()));
+        }
+        if !inventory_item_id_required.is_empty() {
+            query_args.push((
+                "inventory_item_id
+                  required"
+                    .to_string(),
+                inventory_item_id_required.to_string(),
+            ));
+        }
+        if location_id > 0 {
+            query_args.push(("location_id", location_id.to_string()));
+        }
+        if !location_id_required.is_empty() {
+            query_args.push((
+                "location_id
+                  required"
+                    .to_string(),
+                location_id_required.to_string(),
+            ));
+        }
+        let url =
+            crate::Client::url_with_query("/admin/api/unstable/inventory_levels.json", &query_args);
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url("DELETE", "/admin/api/unstable/inventory_levels.json", &[])
+            })
+    }
+
+    /**
+     * Adjusts the inventory level of an inventory item at a single location.
+     *
+     * This function performs a `POST` to the `/admin/api/unstable/inventory_levels/adjust.json` endpoint.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#adjust-unstable
+     *
+     * **Parameters:**
+     *
+     * * `inventory_item_required: &str` -- The ID of the inventory item.
+     * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
+     * * `available_adjustment_required: &str` -- The amount to adjust the available inventory quantity. Send negative values to subtract from the current available quantity. For example, "available_adjustment": 2 increases the current available quantity by 2, and "available_adjustment": -3decreases the current available quantity by 3.
+     */
+    pub async fn deprecated_unstable_create_levels_adjust(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_adjustment_required: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !available_adjustment_required.is_empty() {
+            query_args.push((
+                "available_adjustment
+                  required"
                     .to_string(),
                 available_adjustment_required.to_string(),
             ));
@@ -2094,15 +2589,21 @@ impl Inventory {
                 location_id_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/inventory_levels/adjust.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/unstable/inventory_levels/adjust.json",
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/unstable/inventory_levels/adjust.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -2128,7 +2629,7 @@ impl Inventory {
         relocate_if_necessary: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !inventory_item_id_required.is_empty() {
             query_args.push((
                 "inventory_item_id
@@ -2146,20 +2647,23 @@ impl Inventory {
             ));
         }
         if !relocate_if_necessary.is_empty() {
-            query_args.push((
-                "relocate_if_necessary".to_string(),
-                relocate_if_necessary.to_string(),
-            ));
+            query_args.push(("relocate_if_necessary", relocate_if_necessary.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/inventory_levels/connect.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/unstable/inventory_levels/connect.json",
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/unstable/inventory_levels/connect.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -2188,7 +2692,7 @@ impl Inventory {
         disconnect_if_necessary: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !available_required.is_empty() {
             query_args.push((
                 "available
@@ -2199,7 +2703,7 @@ impl Inventory {
         }
         if !disconnect_if_necessary.is_empty() {
             query_args.push((
-                "disconnect_if_necessary".to_string(),
+                "disconnect_if_necessary",
                 disconnect_if_necessary.to_string(),
             ));
         }
@@ -2219,12 +2723,21 @@ impl Inventory {
                 location_id_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/inventory_levels/set.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/unstable/inventory_levels/set.json",
+            &query_args,
+        );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/unstable/inventory_levels/set.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -2236,7 +2749,9 @@ impl Inventory {
      */
     pub async fn deprecated_202001_get_location(&self) -> Result<()> {
         let url = "/admin/api/2020-01/locations.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/locations.json", &[])
+        })
     }
 
     /**
@@ -2256,10 +2771,16 @@ impl Inventory {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/locations/{}/json",
-            crate::progenitor_support::encode_path(&location_id.to_string()),
+            self.client.encode_path(location_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/locations/{location_id}.json",
+                &[("location_id", location_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2271,7 +2792,9 @@ impl Inventory {
      */
     pub async fn deprecated_202001_get_locations_count(&self) -> Result<()> {
         let url = "/admin/api/2020-01/locations/count.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/locations/count.json", &[])
+        })
     }
 
     /**
@@ -2291,10 +2814,16 @@ impl Inventory {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/locations/{}/inventory_levels.json",
-            crate::progenitor_support::encode_path(&location_id.to_string()),
+            self.client.encode_path(location_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/locations/{location_id}/inventory_levels.json",
+                &[("location_id", location_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2306,7 +2835,9 @@ impl Inventory {
      */
     pub async fn deprecated_202004_get_location(&self) -> Result<()> {
         let url = "/admin/api/2020-04/locations.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/locations.json", &[])
+        })
     }
 
     /**
@@ -2326,10 +2857,16 @@ impl Inventory {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/locations/{}/json",
-            crate::progenitor_support::encode_path(&location_id.to_string()),
+            self.client.encode_path(location_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/locations/{location_id}.json",
+                &[("location_id", location_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2341,7 +2878,9 @@ impl Inventory {
      */
     pub async fn deprecated_202004_get_locations_count(&self) -> Result<()> {
         let url = "/admin/api/2020-04/locations/count.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/locations/count.json", &[])
+        })
     }
 
     /**
@@ -2361,10 +2900,16 @@ impl Inventory {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/locations/{}/inventory_levels.json",
-            crate::progenitor_support::encode_path(&location_id.to_string()),
+            self.client.encode_path(location_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/locations/{location_id}/inventory_levels.json",
+                &[("location_id", location_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2376,7 +2921,9 @@ impl Inventory {
      */
     pub async fn deprecated_202007_get_location(&self) -> Result<()> {
         let url = "/admin/api/2020-07/locations.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/locations.json", &[])
+        })
     }
 
     /**
@@ -2396,10 +2943,16 @@ impl Inventory {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/locations/{}/json",
-            crate::progenitor_support::encode_path(&location_id.to_string()),
+            self.client.encode_path(location_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/locations/{location_id}.json",
+                &[("location_id", location_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2411,7 +2964,9 @@ impl Inventory {
      */
     pub async fn deprecated_202007_get_locations_count(&self) -> Result<()> {
         let url = "/admin/api/2020-07/locations/count.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/locations/count.json", &[])
+        })
     }
 
     /**
@@ -2431,10 +2986,16 @@ impl Inventory {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/locations/{}/inventory_levels.json",
-            crate::progenitor_support::encode_path(&location_id.to_string()),
+            self.client.encode_path(location_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/locations/{location_id}/inventory_levels.json",
+                &[("location_id", location_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2446,7 +3007,9 @@ impl Inventory {
      */
     pub async fn get_location(&self) -> Result<()> {
         let url = "/admin/api/2020-10/locations.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/locations.json", &[])
+        })
     }
 
     /**
@@ -2463,10 +3026,16 @@ impl Inventory {
     pub async fn get_locations_param_location(&self, location_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/locations/{}/json",
-            crate::progenitor_support::encode_path(&location_id.to_string()),
+            self.client.encode_path(location_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/locations/{location_id}.json",
+                &[("location_id", location_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2478,7 +3047,9 @@ impl Inventory {
      */
     pub async fn get_locations_count(&self) -> Result<()> {
         let url = "/admin/api/2020-10/locations/count.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/locations/count.json", &[])
+        })
     }
 
     /**
@@ -2495,10 +3066,16 @@ impl Inventory {
     pub async fn get_locations_param_location_level(&self, location_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/locations/{}/inventory_levels.json",
-            crate::progenitor_support::encode_path(&location_id.to_string()),
+            self.client.encode_path(location_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/locations/{location_id}/inventory_levels.json",
+                &[("location_id", location_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2510,7 +3087,9 @@ impl Inventory {
      */
     pub async fn deprecated_202101_get_location(&self) -> Result<()> {
         let url = "/admin/api/2021-01/locations.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/locations.json", &[])
+        })
     }
 
     /**
@@ -2530,10 +3109,16 @@ impl Inventory {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/locations/{}/json",
-            crate::progenitor_support::encode_path(&location_id.to_string()),
+            self.client.encode_path(location_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/locations/{location_id}.json",
+                &[("location_id", location_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2545,7 +3130,9 @@ impl Inventory {
      */
     pub async fn deprecated_202101_get_locations_count(&self) -> Result<()> {
         let url = "/admin/api/2021-01/locations/count.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/locations/count.json", &[])
+        })
     }
 
     /**
@@ -2565,10 +3152,16 @@ impl Inventory {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/locations/{}/inventory_levels.json",
-            crate::progenitor_support::encode_path(&location_id.to_string()),
+            self.client.encode_path(location_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/locations/{location_id}/inventory_levels.json",
+                &[("location_id", location_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2580,7 +3173,9 @@ impl Inventory {
      */
     pub async fn deprecated_unstable_get_location(&self) -> Result<()> {
         let url = "/admin/api/unstable/locations.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/locations.json", &[])
+        })
     }
 
     /**
@@ -2600,10 +3195,16 @@ impl Inventory {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/locations/{}/json",
-            crate::progenitor_support::encode_path(&location_id.to_string()),
+            self.client.encode_path(location_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/locations/{location_id}.json",
+                &[("location_id", location_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2615,7 +3216,9 @@ impl Inventory {
      */
     pub async fn deprecated_unstable_get_locations_count(&self) -> Result<()> {
         let url = "/admin/api/unstable/locations/count.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/locations/count.json", &[])
+        })
     }
 
     /**
@@ -2635,9 +3238,1196 @@ impl Inventory {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/locations/{}/inventory_levels.json",
-            crate::progenitor_support::encode_path(&location_id.to_string()),
+            self.client.encode_path(location_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/locations/{location_id}/inventory_levels.json",
+                &[("location_id", location_id.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`Inventory`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn InventoryApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait InventoryApi: Send + Sync {
+    async fn deprecated_202001_get_item(
+        &self,
+        ids_required: &str,
+        limit: &str,
+        ids: i64,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_items_param_item(&self, inventory_item_id: &str) -> Result<()>;
+    async fn deprecated_202001_update_items_param_item(
+        &self,
+        inventory_item_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_item(
+        &self,
+        ids_required: &str,
+        limit: &str,
+        ids: i64,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_items_param_item(&self, inventory_item_id: &str) -> Result<()>;
+    async fn deprecated_202004_update_items_param_item(
+        &self,
+        inventory_item_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_item(
+        &self,
+        ids_required: &str,
+        limit: &str,
+        ids: i64,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_items_param_item(&self, inventory_item_id: &str) -> Result<()>;
+    async fn deprecated_202007_update_items_param_item(
+        &self,
+        inventory_item_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn get_item(&self, ids_required: &str, limit: &str, ids: i64) -> Result<()>;
+    async fn get_items_param_item(&self, inventory_item_id: &str) -> Result<()>;
+    async fn update_items_param_item(
+        &self,
+        inventory_item_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_item(
+        &self,
+        ids_required: &str,
+        limit: &str,
+        ids: i64,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_items_param_item(&self, inventory_item_id: &str) -> Result<()>;
+    async fn deprecated_202101_update_items_param_item(
+        &self,
+        inventory_item_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_item(
+        &self,
+        ids_required: &str,
+        limit: &str,
+        ids: i64,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_items_param_item(&self, inventory_item_id: &str)
+        -> Result<()>;
+    async fn deprecated_unstable_update_items_param_item(
+        &self,
+        inventory_item_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_level(
+        &self,
+        inventory_item_ids: &str,
+        location_ids: &str,
+        limit: &str,
+        updated_at_min: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_levels(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_levels_if_exists(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> Result<bool>;
+    async fn deprecated_202001_create_levels_adjust(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_adjustment_required: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_levels_connect(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        relocate_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_levels_set(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_required: &str,
+        disconnect_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_level(
+        &self,
+        inventory_item_ids: &str,
+        location_ids: &str,
+        limit: &str,
+        updated_at_min: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_levels(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_levels_if_exists(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> Result<bool>;
+    async fn deprecated_202004_create_levels_adjust(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_adjustment_required: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_create_levels_connect(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        relocate_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_create_levels_set(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_required: &str,
+        disconnect_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_level(
+        &self,
+        inventory_item_ids: &str,
+        location_ids: &str,
+        limit: &str,
+        updated_at_min: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_levels(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_levels_if_exists(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> Result<bool>;
+    async fn deprecated_202007_create_levels_adjust(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_adjustment_required: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_create_levels_connect(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        relocate_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_create_levels_set(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_required: &str,
+        disconnect_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn get_level(
+        &self,
+        inventory_item_ids: &str,
+        location_ids: &str,
+        limit: &str,
+        updated_at_min: &str,
+    ) -> Result<()>;
+    async fn delete_levels(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> Result<()>;
+    async fn delete_levels_if_exists(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> Result<bool>;
+    async fn create_levels_adjust(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_adjustment_required: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn create_levels_connect(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        relocate_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn create_levels_set(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_required: &str,
+        disconnect_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_level(
+        &self,
+        inventory_item_ids: &str,
+        location_ids: &str,
+        limit: &str,
+        updated_at_min: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_levels(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_levels_if_exists(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> Result<bool>;
+    async fn deprecated_202101_create_levels_adjust(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_adjustment_required: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_create_levels_connect(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        relocate_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_create_levels_set(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_required: &str,
+        disconnect_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_level(
+        &self,
+        inventory_item_ids: &str,
+        location_ids: &str,
+        limit: &str,
+        updated_at_min: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_levels(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_levels_if_exists(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> Result<bool>;
+    async fn deprecated_unstable_create_levels_adjust(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_adjustment_required: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create_levels_connect(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        relocate_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create_levels_set(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_required: &str,
+        disconnect_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_location(&self) -> Result<()>;
+    async fn deprecated_202001_get_locations_param_location(&self, location_id: &str)
+        -> Result<()>;
+    async fn deprecated_202001_get_locations_count(&self) -> Result<()>;
+    async fn deprecated_202001_get_locations_param_location_level(
+        &self,
+        location_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_location(&self) -> Result<()>;
+    async fn deprecated_202004_get_locations_param_location(&self, location_id: &str)
+        -> Result<()>;
+    async fn deprecated_202004_get_locations_count(&self) -> Result<()>;
+    async fn deprecated_202004_get_locations_param_location_level(
+        &self,
+        location_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_location(&self) -> Result<()>;
+    async fn deprecated_202007_get_locations_param_location(&self, location_id: &str)
+        -> Result<()>;
+    async fn deprecated_202007_get_locations_count(&self) -> Result<()>;
+    async fn deprecated_202007_get_locations_param_location_level(
+        &self,
+        location_id: &str,
+    ) -> Result<()>;
+    async fn get_location(&self) -> Result<()>;
+    async fn get_locations_param_location(&self, location_id: &str) -> Result<()>;
+    async fn get_locations_count(&self) -> Result<()>;
+    async fn get_locations_param_location_level(&self, location_id: &str) -> Result<()>;
+    async fn deprecated_202101_get_location(&self) -> Result<()>;
+    async fn deprecated_202101_get_locations_param_location(&self, location_id: &str)
+        -> Result<()>;
+    async fn deprecated_202101_get_locations_count(&self) -> Result<()>;
+    async fn deprecated_202101_get_locations_param_location_level(
+        &self,
+        location_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_location(&self) -> Result<()>;
+    async fn deprecated_unstable_get_locations_param_location(
+        &self,
+        location_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_locations_count(&self) -> Result<()>;
+    async fn deprecated_unstable_get_locations_param_location_level(
+        &self,
+        location_id: &str,
+    ) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl InventoryApi for Inventory {
+    async fn deprecated_202001_get_item(
+        &self,
+        ids_required: &str,
+        limit: &str,
+        ids: i64,
+    ) -> Result<()> {
+        Inventory::deprecated_202001_get_item(self, ids_required, limit, ids).await
+    }
+    async fn deprecated_202001_get_items_param_item(&self, inventory_item_id: &str) -> Result<()> {
+        Inventory::deprecated_202001_get_items_param_item(self, inventory_item_id).await
+    }
+    async fn deprecated_202001_update_items_param_item(
+        &self,
+        inventory_item_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::deprecated_202001_update_items_param_item(self, inventory_item_id, body).await
+    }
+    async fn deprecated_202004_get_item(
+        &self,
+        ids_required: &str,
+        limit: &str,
+        ids: i64,
+    ) -> Result<()> {
+        Inventory::deprecated_202004_get_item(self, ids_required, limit, ids).await
+    }
+    async fn deprecated_202004_get_items_param_item(&self, inventory_item_id: &str) -> Result<()> {
+        Inventory::deprecated_202004_get_items_param_item(self, inventory_item_id).await
+    }
+    async fn deprecated_202004_update_items_param_item(
+        &self,
+        inventory_item_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::deprecated_202004_update_items_param_item(self, inventory_item_id, body).await
+    }
+    async fn deprecated_202007_get_item(
+        &self,
+        ids_required: &str,
+        limit: &str,
+        ids: i64,
+    ) -> Result<()> {
+        Inventory::deprecated_202007_get_item(self, ids_required, limit, ids).await
+    }
+    async fn deprecated_202007_get_items_param_item(&self, inventory_item_id: &str) -> Result<()> {
+        Inventory::deprecated_202007_get_items_param_item(self, inventory_item_id).await
+    }
+    async fn deprecated_202007_update_items_param_item(
+        &self,
+        inventory_item_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::deprecated_202007_update_items_param_item(self, inventory_item_id, body).await
+    }
+    async fn get_item(&self, ids_required: &str, limit: &str, ids: i64) -> Result<()> {
+        Inventory::get_item(self, ids_required, limit, ids).await
+    }
+    async fn get_items_param_item(&self, inventory_item_id: &str) -> Result<()> {
+        Inventory::get_items_param_item(self, inventory_item_id).await
+    }
+    async fn update_items_param_item(
+        &self,
+        inventory_item_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::update_items_param_item(self, inventory_item_id, body).await
+    }
+    async fn deprecated_202101_get_item(
+        &self,
+        ids_required: &str,
+        limit: &str,
+        ids: i64,
+    ) -> Result<()> {
+        Inventory::deprecated_202101_get_item(self, ids_required, limit, ids).await
+    }
+    async fn deprecated_202101_get_items_param_item(&self, inventory_item_id: &str) -> Result<()> {
+        Inventory::deprecated_202101_get_items_param_item(self, inventory_item_id).await
+    }
+    async fn deprecated_202101_update_items_param_item(
+        &self,
+        inventory_item_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::deprecated_202101_update_items_param_item(self, inventory_item_id, body).await
+    }
+    async fn deprecated_unstable_get_item(
+        &self,
+        ids_required: &str,
+        limit: &str,
+        ids: i64,
+    ) -> Result<()> {
+        Inventory::deprecated_unstable_get_item(self, ids_required, limit, ids).await
+    }
+    async fn deprecated_unstable_get_items_param_item(
+        &self,
+        inventory_item_id: &str,
+    ) -> Result<()> {
+        Inventory::deprecated_unstable_get_items_param_item(self, inventory_item_id).await
+    }
+    async fn deprecated_unstable_update_items_param_item(
+        &self,
+        inventory_item_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::deprecated_unstable_update_items_param_item(self, inventory_item_id, body).await
+    }
+    async fn deprecated_202001_get_level(
+        &self,
+        inventory_item_ids: &str,
+        location_ids: &str,
+        limit: &str,
+        updated_at_min: &str,
+    ) -> Result<()> {
+        Inventory::deprecated_202001_get_level(
+            self,
+            inventory_item_ids,
+            location_ids,
+            limit,
+            updated_at_min,
+        )
+        .await
+    }
+    async fn deprecated_202001_delete_levels(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> Result<()> {
+        Inventory::deprecated_202001_delete_levels(
+            self,
+            inventory_item_id_required,
+            location_id_required,
+            inventory_item_id,
+            location_id,
+        )
+        .await
+    }
+    async fn deprecated_202001_delete_levels_if_exists(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> Result<bool> {
+        Inventory::deprecated_202001_delete_levels_if_exists(
+            self,
+            inventory_item_id_required,
+            location_id_required,
+            inventory_item_id,
+            location_id,
+        )
+        .await
+    }
+    async fn deprecated_202001_create_levels_adjust(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_adjustment_required: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::deprecated_202001_create_levels_adjust(
+            self,
+            inventory_item_id_required,
+            location_id_required,
+            available_adjustment_required,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202001_create_levels_connect(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        relocate_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::deprecated_202001_create_levels_connect(
+            self,
+            inventory_item_id_required,
+            location_id_required,
+            relocate_if_necessary,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202001_create_levels_set(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_required: &str,
+        disconnect_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::deprecated_202001_create_levels_set(
+            self,
+            inventory_item_id_required,
+            location_id_required,
+            available_required,
+            disconnect_if_necessary,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_level(
+        &self,
+        inventory_item_ids: &str,
+        location_ids: &str,
+        limit: &str,
+        updated_at_min: &str,
+    ) -> Result<()> {
+        Inventory::deprecated_202004_get_level(
+            self,
+            inventory_item_ids,
+            location_ids,
+            limit,
+            updated_at_min,
+        )
+        .await
+    }
+    async fn deprecated_202004_delete_levels(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> Result<()> {
+        Inventory::deprecated_202004_delete_levels(
+            self,
+            inventory_item_id_required,
+            location_id_required,
+            inventory_item_id,
+            location_id,
+        )
+        .await
+    }
+    async fn deprecated_202004_delete_levels_if_exists(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> Result<bool> {
+        Inventory::deprecated_202004_delete_levels_if_exists(
+            self,
+            inventory_item_id_required,
+            location_id_required,
+            inventory_item_id,
+            location_id,
+        )
+        .await
+    }
+    async fn deprecated_202004_create_levels_adjust(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_adjustment_required: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::deprecated_202004_create_levels_adjust(
+            self,
+            inventory_item_id_required,
+            location_id_required,
+            available_adjustment_required,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202004_create_levels_connect(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        relocate_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::deprecated_202004_create_levels_connect(
+            self,
+            inventory_item_id_required,
+            location_id_required,
+            relocate_if_necessary,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202004_create_levels_set(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_required: &str,
+        disconnect_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::deprecated_202004_create_levels_set(
+            self,
+            inventory_item_id_required,
+            location_id_required,
+            available_required,
+            disconnect_if_necessary,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_level(
+        &self,
+        inventory_item_ids: &str,
+        location_ids: &str,
+        limit: &str,
+        updated_at_min: &str,
+    ) -> Result<()> {
+        Inventory::deprecated_202007_get_level(
+            self,
+            inventory_item_ids,
+            location_ids,
+            limit,
+            updated_at_min,
+        )
+        .await
+    }
+    async fn deprecated_202007_delete_levels(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> Result<()> {
+        Inventory::deprecated_202007_delete_levels(
+            self,
+            inventory_item_id_required,
+            location_id_required,
+            inventory_item_id,
+            location_id,
+        )
+        .await
+    }
+    async fn deprecated_202007_delete_levels_if_exists(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> Result<bool> {
+        Inventory::deprecated_202007_delete_levels_if_exists(
+            self,
+            inventory_item_id_required,
+            location_id_required,
+            inventory_item_id,
+            location_id,
+        )
+        .await
+    }
+    async fn deprecated_202007_create_levels_adjust(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_adjustment_required: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::deprecated_202007_create_levels_adjust(
+            self,
+            inventory_item_id_required,
+            location_id_required,
+            available_adjustment_required,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202007_create_levels_connect(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        relocate_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::deprecated_202007_create_levels_connect(
+            self,
+            inventory_item_id_required,
+            location_id_required,
+            relocate_if_necessary,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202007_create_levels_set(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_required: &str,
+        disconnect_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::deprecated_202007_create_levels_set(
+            self,
+            inventory_item_id_required,
+            location_id_required,
+            available_required,
+            disconnect_if_necessary,
+            body,
+        )
+        .await
+    }
+    async fn get_level(
+        &self,
+        inventory_item_ids: &str,
+        location_ids: &str,
+        limit: &str,
+        updated_at_min: &str,
+    ) -> Result<()> {
+        Inventory::get_level(
+            self,
+            inventory_item_ids,
+            location_ids,
+            limit,
+            updated_at_min,
+        )
+        .await
+    }
+    async fn delete_levels(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> Result<()> {
+        Inventory::delete_levels(
+            self,
+            inventory_item_id_required,
+            location_id_required,
+            inventory_item_id,
+            location_id,
+        )
+        .await
+    }
+    async fn delete_levels_if_exists(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> Result<bool> {
+        Inventory::delete_levels_if_exists(
+            self,
+            inventory_item_id_required,
+            location_id_required,
+            inventory_item_id,
+            location_id,
+        )
+        .await
+    }
+    async fn create_levels_adjust(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_adjustment_required: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::create_levels_adjust(
+            self,
+            inventory_item_id_required,
+            location_id_required,
+            available_adjustment_required,
+            body,
+        )
+        .await
+    }
+    async fn create_levels_connect(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        relocate_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::create_levels_connect(
+            self,
+            inventory_item_id_required,
+            location_id_required,
+            relocate_if_necessary,
+            body,
+        )
+        .await
+    }
+    async fn create_levels_set(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_required: &str,
+        disconnect_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::create_levels_set(
+            self,
+            inventory_item_id_required,
+            location_id_required,
+            available_required,
+            disconnect_if_necessary,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202101_get_level(
+        &self,
+        inventory_item_ids: &str,
+        location_ids: &str,
+        limit: &str,
+        updated_at_min: &str,
+    ) -> Result<()> {
+        Inventory::deprecated_202101_get_level(
+            self,
+            inventory_item_ids,
+            location_ids,
+            limit,
+            updated_at_min,
+        )
+        .await
+    }
+    async fn deprecated_202101_delete_levels(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> Result<()> {
+        Inventory::deprecated_202101_delete_levels(
+            self,
+            inventory_item_id_required,
+            location_id_required,
+            inventory_item_id,
+            location_id,
+        )
+        .await
+    }
+    async fn deprecated_202101_delete_levels_if_exists(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> Result<bool> {
+        Inventory::deprecated_202101_delete_levels_if_exists(
+            self,
+            inventory_item_id_required,
+            location_id_required,
+            inventory_item_id,
+            location_id,
+        )
+        .await
+    }
+    async fn deprecated_202101_create_levels_adjust(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_adjustment_required: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::deprecated_202101_create_levels_adjust(
+            self,
+            inventory_item_id_required,
+            location_id_required,
+            available_adjustment_required,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202101_create_levels_connect(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        relocate_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::deprecated_202101_create_levels_connect(
+            self,
+            inventory_item_id_required,
+            location_id_required,
+            relocate_if_necessary,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202101_create_levels_set(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_required: &str,
+        disconnect_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::deprecated_202101_create_levels_set(
+            self,
+            inventory_item_id_required,
+            location_id_required,
+            available_required,
+            disconnect_if_necessary,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_level(
+        &self,
+        inventory_item_ids: &str,
+        location_ids: &str,
+        limit: &str,
+        updated_at_min: &str,
+    ) -> Result<()> {
+        Inventory::deprecated_unstable_get_level(
+            self,
+            inventory_item_ids,
+            location_ids,
+            limit,
+            updated_at_min,
+        )
+        .await
+    }
+    async fn deprecated_unstable_delete_levels(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> Result<()> {
+        Inventory::deprecated_unstable_delete_levels(
+            self,
+            inventory_item_id_required,
+            location_id_required,
+            inventory_item_id,
+            location_id,
+        )
+        .await
+    }
+    async fn deprecated_unstable_delete_levels_if_exists(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> Result<bool> {
+        Inventory::deprecated_unstable_delete_levels_if_exists(
+            self,
+            inventory_item_id_required,
+            location_id_required,
+            inventory_item_id,
+            location_id,
+        )
+        .await
+    }
+    async fn deprecated_unstable_create_levels_adjust(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_adjustment_required: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::deprecated_unstable_create_levels_adjust(
+            self,
+            inventory_item_id_required,
+            location_id_required,
+            available_adjustment_required,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_unstable_create_levels_connect(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        relocate_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::deprecated_unstable_create_levels_connect(
+            self,
+            inventory_item_id_required,
+            location_id_required,
+            relocate_if_necessary,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_unstable_create_levels_set(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_required: &str,
+        disconnect_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::deprecated_unstable_create_levels_set(
+            self,
+            inventory_item_id_required,
+            location_id_required,
+            available_required,
+            disconnect_if_necessary,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202001_get_location(&self) -> Result<()> {
+        Inventory::deprecated_202001_get_location(self).await
+    }
+    async fn deprecated_202001_get_locations_param_location(
+        &self,
+        location_id: &str,
+    ) -> Result<()> {
+        Inventory::deprecated_202001_get_locations_param_location(self, location_id).await
+    }
+    async fn deprecated_202001_get_locations_count(&self) -> Result<()> {
+        Inventory::deprecated_202001_get_locations_count(self).await
+    }
+    async fn deprecated_202001_get_locations_param_location_level(
+        &self,
+        location_id: &str,
+    ) -> Result<()> {
+        Inventory::deprecated_202001_get_locations_param_location_level(self, location_id).await
+    }
+    async fn deprecated_202004_get_location(&self) -> Result<()> {
+        Inventory::deprecated_202004_get_location(self).await
+    }
+    async fn deprecated_202004_get_locations_param_location(
+        &self,
+        location_id: &str,
+    ) -> Result<()> {
+        Inventory::deprecated_202004_get_locations_param_location(self, location_id).await
+    }
+    async fn deprecated_202004_get_locations_count(&self) -> Result<()> {
+        Inventory::deprecated_202004_get_locations_count(self).await
+    }
+    async fn deprecated_202004_get_locations_param_location_level(
+        &self,
+        location_id: &str,
+    ) -> Result<()> {
+        Inventory::deprecated_202004_get_locations_param_location_level(self, location_id).await
+    }
+    async fn deprecated_202007_get_location(&self) -> Result<()> {
+        Inventory::deprecated_202007_get_location(self).await
+    }
+    async fn deprecated_202007_get_locations_param_location(
+        &self,
+        location_id: &str,
+    ) -> Result<()> {
+        Inventory::deprecated_202007_get_locations_param_location(self, location_id).await
+    }
+    async fn deprecated_202007_get_locations_count(&self) -> Result<()> {
+        Inventory::deprecated_202007_get_locations_count(self).await
+    }
+    async fn deprecated_202007_get_locations_param_location_level(
+        &self,
+        location_id: &str,
+    ) -> Result<()> {
+        Inventory::deprecated_202007_get_locations_param_location_level(self, location_id).await
+    }
+    async fn get_location(&self) -> Result<()> {
+        Inventory::get_location(self).await
+    }
+    async fn get_locations_param_location(&self, location_id: &str) -> Result<()> {
+        Inventory::get_locations_param_location(self, location_id).await
+    }
+    async fn get_locations_count(&self) -> Result<()> {
+        Inventory::get_locations_count(self).await
+    }
+    async fn get_locations_param_location_level(&self, location_id: &str) -> Result<()> {
+        Inventory::get_locations_param_location_level(self, location_id).await
+    }
+    async fn deprecated_202101_get_location(&self) -> Result<()> {
+        Inventory::deprecated_202101_get_location(self).await
+    }
+    async fn deprecated_202101_get_locations_param_location(
+        &self,
+        location_id: &str,
+    ) -> Result<()> {
+        Inventory::deprecated_202101_get_locations_param_location(self, location_id).await
+    }
+    async fn deprecated_202101_get_locations_count(&self) -> Result<()> {
+        Inventory::deprecated_202101_get_locations_count(self).await
+    }
+    async fn deprecated_202101_get_locations_param_location_level(
+        &self,
+        location_id: &str,
+    ) -> Result<()> {
+        Inventory::deprecated_202101_get_locations_param_location_level(self, location_id).await
+    }
+    async fn deprecated_unstable_get_location(&self) -> Result<()> {
+        Inventory::deprecated_unstable_get_location(self).await
+    }
+    async fn deprecated_unstable_get_locations_param_location(
+        &self,
+        location_id: &str,
+    ) -> Result<()> {
+        Inventory::deprecated_unstable_get_locations_param_location(self, location_id).await
+    }
+    async fn deprecated_unstable_get_locations_count(&self) -> Result<()> {
+        Inventory::deprecated_unstable_get_locations_count(self).await
+    }
+    async fn deprecated_unstable_get_locations_param_location_level(
+        &self,
+        location_id: &str,
+    ) -> Result<()> {
+        Inventory::deprecated_unstable_get_locations_param_location_level(self, location_id).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Metafield {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Metafield {
         Metafield { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Metafield {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieves a list of metafields that belong to a Product Image resource.
      *
@@ -29,23 +35,21 @@ impl Metafield {
         metafield_owner_id: i64,
         metafield_owner_resource: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if metafield_owner_id > 0 {
-            query_args.push((
-                "metafield[owner_id]".to_string(),
-                metafield_owner_id.to_string(),
-            ));
+            query_args.push(("metafield[owner_id]", metafield_owner_id.to_string()));
         }
         if !metafield_owner_resource.is_empty() {
             query_args.push((
-                "metafield[owner_resource]".to_string(),
+                "metafield[owner_resource]",
                 metafield_owner_resource.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/metafields.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-01/metafields.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/metafields.json", &[])
+        })
     }
 
     /**
@@ -58,8 +62,11 @@ impl Metafield {
     pub async fn deprecated_202001_create(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-01/metafields.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-01/metafields.json", &[])
+            })
     }
 
     /**
@@ -71,7 +78,9 @@ impl Metafield {
      */
     pub async fn deprecated_202001_get_count(&self) -> Result<()> {
         let url = "/admin/api/2020-01/metafields/count.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/metafields/count.json", &[])
+        })
     }
 
     /**
@@ -91,18 +100,25 @@ impl Metafield {
         metafield_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/metafields/{}/json?{}",
-            crate::progenitor_support::encode_path(&metafield_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/metafields/{}/json",
+                self.client.encode_path(metafield_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/metafields/{metafield_id}.json",
+                &[("metafield_id", metafield_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -123,12 +139,19 @@ impl Metafield {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/metafields/{}/json",
-            crate::progenitor_support::encode_path(&metafield_id.to_string()),
+            self.client.encode_path(metafield_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-01/metafields/{metafield_id}.json",
+                    &[("metafield_id", metafield_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -145,10 +168,44 @@ impl Metafield {
     pub async fn deprecated_202001_delete_param(&self, metafield_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/metafields/{}/json",
-            crate::progenitor_support::encode_path(&metafield_id.to_string()),
+            self.client.encode_path(metafield_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-01/metafields/{metafield_id}.json",
+                &[("metafield_id", metafield_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a metafield by its ID.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-01/metafields/{metafield_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_delete_param`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202001_delete_param_if_exists(
+        &self,
+        metafield_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-01/metafields/{}/json",
+            self.client.encode_path(metafield_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-01/metafields/{metafield_id}.json",
+                    &[("metafield_id", metafield_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -168,23 +225,21 @@ impl Metafield {
         metafield_owner_id: i64,
         metafield_owner_resource: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if metafield_owner_id > 0 {
-            query_args.push((
-                "metafield[owner_id]".to_string(),
-                metafield_owner_id.to_string(),
-            ));
+            query_args.push(("metafield[owner_id]", metafield_owner_id.to_string()));
         }
         if !metafield_owner_resource.is_empty() {
             query_args.push((
-                "metafield[owner_resource]".to_string(),
+                "metafield[owner_resource]",
                 metafield_owner_resource.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/metafields.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-04/metafields.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/metafields.json", &[])
+        })
     }
 
     /**
@@ -197,8 +252,11 @@ impl Metafield {
     pub async fn deprecated_202004_create(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-04/metafields.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-04/metafields.json", &[])
+            })
     }
 
     /**
@@ -210,7 +268,9 @@ impl Metafield {
      */
     pub async fn deprecated_202004_get_count(&self) -> Result<()> {
         let url = "/admin/api/2020-04/metafields/count.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/metafields/count.json", &[])
+        })
     }
 
     /**
@@ -230,18 +290,25 @@ impl Metafield {
         metafield_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/metafields/{}/json?{}",
-            crate::progenitor_support::encode_path(&metafield_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/metafields/{}/json",
+                self.client.encode_path(metafield_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/metafields/{metafield_id}.json",
+                &[("metafield_id", metafield_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -262,12 +329,19 @@ impl Metafield {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/metafields/{}/json",
-            crate::progenitor_support::encode_path(&metafield_id.to_string()),
+            self.client.encode_path(metafield_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-04/metafields/{metafield_id}.json",
+                    &[("metafield_id", metafield_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -284,10 +358,44 @@ impl Metafield {
     pub async fn deprecated_202004_delete_param(&self, metafield_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/metafields/{}/json",
-            crate::progenitor_support::encode_path(&metafield_id.to_string()),
+            self.client.encode_path(metafield_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-04/metafields/{metafield_id}.json",
+                &[("metafield_id", metafield_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a metafield by its ID.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-04/metafields/{metafield_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_delete_param`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202004_delete_param_if_exists(
+        &self,
+        metafield_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-04/metafields/{}/json",
+            self.client.encode_path(metafield_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-04/metafields/{metafield_id}.json",
+                    &[("metafield_id", metafield_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -307,23 +415,21 @@ impl Metafield {
         metafield_owner_id: i64,
         metafield_owner_resource: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if metafield_owner_id > 0 {
-            query_args.push((
-                "metafield[owner_id]".to_string(),
-                metafield_owner_id.to_string(),
-            ));
+            query_args.push(("metafield[owner_id]", metafield_owner_id.to_string()));
         }
         if !metafield_owner_resource.is_empty() {
             query_args.push((
-                "metafield[owner_resource]".to_string(),
+                "metafield[owner_resource]",
                 metafield_owner_resource.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/metafields.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-07/metafields.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/metafields.json", &[])
+        })
     }
 
     /**
@@ -336,8 +442,11 @@ impl Metafield {
     pub async fn deprecated_202007_create(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-07/metafields.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-07/metafields.json", &[])
+            })
     }
 
     /**
@@ -349,7 +458,9 @@ impl Metafield {
      */
     pub async fn deprecated_202007_get_count(&self) -> Result<()> {
         let url = "/admin/api/2020-07/metafields/count.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/metafields/count.json", &[])
+        })
     }
 
     /**
@@ -369,18 +480,25 @@ impl Metafield {
         metafield_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/metafields/{}/json?{}",
-            crate::progenitor_support::encode_path(&metafield_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/metafields/{}/json",
+                self.client.encode_path(metafield_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/metafields/{metafield_id}.json",
+                &[("metafield_id", metafield_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -401,12 +519,19 @@ impl Metafield {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/metafields/{}/json",
-            crate::progenitor_support::encode_path(&metafield_id.to_string()),
+            self.client.encode_path(metafield_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-07/metafields/{metafield_id}.json",
+                    &[("metafield_id", metafield_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -423,10 +548,44 @@ impl Metafield {
     pub async fn deprecated_202007_delete_param(&self, metafield_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/metafields/{}/json",
-            crate::progenitor_support::encode_path(&metafield_id.to_string()),
+            self.client.encode_path(metafield_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-07/metafields/{metafield_id}.json",
+                &[("metafield_id", metafield_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a metafield by its ID.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-07/metafields/{metafield_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_delete_param`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202007_delete_param_if_exists(
+        &self,
+        metafield_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-07/metafields/{}/json",
+            self.client.encode_path(metafield_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-07/metafields/{metafield_id}.json",
+                    &[("metafield_id", metafield_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -442,23 +601,21 @@ impl Metafield {
      * * `metafield_owner_resource: &str` -- storefront_access_token_id.
      */
     pub async fn get(&self, metafield_owner_id: i64, metafield_owner_resource: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if metafield_owner_id > 0 {
-            query_args.push((
-                "metafield[owner_id]".to_string(),
-                metafield_owner_id.to_string(),
-            ));
+            query_args.push(("metafield[owner_id]", metafield_owner_id.to_string()));
         }
         if !metafield_owner_resource.is_empty() {
             query_args.push((
-                "metafield[owner_resource]".to_string(),
+                "metafield[owner_resource]",
                 metafield_owner_resource.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/metafields.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-10/metafields.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/metafields.json", &[])
+        })
     }
 
     /**
@@ -471,8 +628,11 @@ impl Metafield {
     pub async fn create(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-10/metafields.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-10/metafields.json", &[])
+            })
     }
 
     /**
@@ -484,7 +644,9 @@ impl Metafield {
      */
     pub async fn get_count(&self) -> Result<()> {
         let url = "/admin/api/2020-10/metafields/count.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/metafields/count.json", &[])
+        })
     }
 
     /**
@@ -500,18 +662,25 @@ impl Metafield {
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
     pub async fn get_param(&self, metafield_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/metafields/{}/json?{}",
-            crate::progenitor_support::encode_path(&metafield_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/metafields/{}/json",
+                self.client.encode_path(metafield_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/metafields/{metafield_id}.json",
+                &[("metafield_id", metafield_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -528,12 +697,19 @@ impl Metafield {
     pub async fn update_param(&self, metafield_id: &str, body: &serde_json::Value) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/metafields/{}/json",
-            crate::progenitor_support::encode_path(&metafield_id.to_string()),
+            self.client.encode_path(metafield_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-10/metafields/{metafield_id}.json",
+                    &[("metafield_id", metafield_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -550,10 +726,41 @@ impl Metafield {
     pub async fn delete_param(&self, metafield_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/metafields/{}/json",
-            crate::progenitor_support::encode_path(&metafield_id.to_string()),
+            self.client.encode_path(metafield_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-10/metafields/{metafield_id}.json",
+                &[("metafield_id", metafield_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a metafield by its ID.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-10/metafields/{metafield_id}.json` endpoint.
+     *
+     * As opposed to `delete_param`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_param_if_exists(&self, metafield_id: &str) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-10/metafields/{}/json",
+            self.client.encode_path(metafield_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-10/metafields/{metafield_id}.json",
+                    &[("metafield_id", metafield_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -573,23 +780,21 @@ impl Metafield {
         metafield_owner_id: i64,
         metafield_owner_resource: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if metafield_owner_id > 0 {
-            query_args.push((
-                "metafield[owner_id]".to_string(),
-                metafield_owner_id.to_string(),
-            ));
+            query_args.push(("metafield[owner_id]", metafield_owner_id.to_string()));
         }
         if !metafield_owner_resource.is_empty() {
             query_args.push((
-                "metafield[owner_resource]".to_string(),
+                "metafield[owner_resource]",
                 metafield_owner_resource.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/metafields.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2021-01/metafields.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/metafields.json", &[])
+        })
     }
 
     /**
@@ -602,8 +807,11 @@ impl Metafield {
     pub async fn deprecated_202101_create(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2021-01/metafields.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2021-01/metafields.json", &[])
+            })
     }
 
     /**
@@ -615,7 +823,9 @@ impl Metafield {
      */
     pub async fn deprecated_202101_get_count(&self) -> Result<()> {
         let url = "/admin/api/2021-01/metafields/count.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/metafields/count.json", &[])
+        })
     }
 
     /**
@@ -635,18 +845,25 @@ impl Metafield {
         metafield_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/metafields/{}/json?{}",
-            crate::progenitor_support::encode_path(&metafield_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/metafields/{}/json",
+                self.client.encode_path(metafield_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/metafields/{metafield_id}.json",
+                &[("metafield_id", metafield_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -667,12 +884,19 @@ impl Metafield {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/metafields/{}/json",
-            crate::progenitor_support::encode_path(&metafield_id.to_string()),
+            self.client.encode_path(metafield_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2021-01/metafields/{metafield_id}.json",
+                    &[("metafield_id", metafield_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -689,10 +913,44 @@ impl Metafield {
     pub async fn deprecated_202101_delete_param(&self, metafield_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/metafields/{}/json",
-            crate::progenitor_support::encode_path(&metafield_id.to_string()),
+            self.client.encode_path(metafield_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2021-01/metafields/{metafield_id}.json",
+                &[("metafield_id", metafield_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a metafield by its ID.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2021-01/metafields/{metafield_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_delete_param`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202101_delete_param_if_exists(
+        &self,
+        metafield_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2021-01/metafields/{}/json",
+            self.client.encode_path(metafield_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2021-01/metafields/{metafield_id}.json",
+                    &[("metafield_id", metafield_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -712,23 +970,21 @@ impl Metafield {
         metafield_owner_id: i64,
         metafield_owner_resource: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if metafield_owner_id > 0 {
-            query_args.push((
-                "metafield[owner_id]".to_string(),
-                metafield_owner_id.to_string(),
-            ));
+            query_args.push(("metafield[owner_id]", metafield_owner_id.to_string()));
         }
         if !metafield_owner_resource.is_empty() {
             query_args.push((
-                "metafield[owner_resource]".to_string(),
+                "metafield[owner_resource]",
                 metafield_owner_resource.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/metafields.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/unstable/metafields.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/metafields.json", &[])
+        })
     }
 
     /**
@@ -741,8 +997,11 @@ impl Metafield {
     pub async fn deprecated_unstable_create(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/unstable/metafields.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/unstable/metafields.json", &[])
+            })
     }
 
     /**
@@ -754,7 +1013,9 @@ impl Metafield {
      */
     pub async fn deprecated_unstable_get_count(&self) -> Result<()> {
         let url = "/admin/api/unstable/metafields/count.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/metafields/count.json", &[])
+        })
     }
 
     /**
@@ -774,18 +1035,25 @@ impl Metafield {
         metafield_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/metafields/{}/json?{}",
-            crate::progenitor_support::encode_path(&metafield_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/metafields/{}/json",
+                self.client.encode_path(metafield_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/metafields/{metafield_id}.json",
+                &[("metafield_id", metafield_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -806,12 +1074,19 @@ impl Metafield {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/metafields/{}/json",
-            crate::progenitor_support::encode_path(&metafield_id.to_string()),
+            self.client.encode_path(metafield_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/unstable/metafields/{metafield_id}.json",
+                    &[("metafield_id", metafield_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -828,9 +1103,304 @@ impl Metafield {
     pub async fn deprecated_unstable_delete_param(&self, metafield_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/metafields/{}/json",
-            crate::progenitor_support::encode_path(&metafield_id.to_string()),
+            self.client.encode_path(metafield_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/unstable/metafields/{metafield_id}.json",
+                &[("metafield_id", metafield_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a metafield by its ID.
+     *
+     * This function performs a `DELETE` to the `/admin/api/unstable/metafields/{metafield_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_delete_param`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_unstable_delete_param_if_exists(
+        &self,
+        metafield_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/unstable/metafields/{}/json",
+            self.client.encode_path(metafield_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/unstable/metafields/{metafield_id}.json",
+                    &[("metafield_id", metafield_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`Metafield`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn MetafieldApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait MetafieldApi: Send + Sync {
+    async fn deprecated_202001_get(
+        &self,
+        metafield_owner_id: i64,
+        metafield_owner_resource: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_create(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202001_get_count(&self) -> Result<()>;
+    async fn deprecated_202001_get_param(&self, metafield_id: &str, fields: &str) -> Result<()>;
+    async fn deprecated_202001_update_param(
+        &self,
+        metafield_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_param(&self, metafield_id: &str) -> Result<()>;
+    async fn deprecated_202001_delete_param_if_exists(&self, metafield_id: &str) -> Result<bool>;
+    async fn deprecated_202004_get(
+        &self,
+        metafield_owner_id: i64,
+        metafield_owner_resource: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_create(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202004_get_count(&self) -> Result<()>;
+    async fn deprecated_202004_get_param(&self, metafield_id: &str, fields: &str) -> Result<()>;
+    async fn deprecated_202004_update_param(
+        &self,
+        metafield_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_param(&self, metafield_id: &str) -> Result<()>;
+    async fn deprecated_202004_delete_param_if_exists(&self, metafield_id: &str) -> Result<bool>;
+    async fn deprecated_202007_get(
+        &self,
+        metafield_owner_id: i64,
+        metafield_owner_resource: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_create(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202007_get_count(&self) -> Result<()>;
+    async fn deprecated_202007_get_param(&self, metafield_id: &str, fields: &str) -> Result<()>;
+    async fn deprecated_202007_update_param(
+        &self,
+        metafield_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_param(&self, metafield_id: &str) -> Result<()>;
+    async fn deprecated_202007_delete_param_if_exists(&self, metafield_id: &str) -> Result<bool>;
+    async fn get(&self, metafield_owner_id: i64, metafield_owner_resource: &str) -> Result<()>;
+    async fn create(&self, body: &serde_json::Value) -> Result<()>;
+    async fn get_count(&self) -> Result<()>;
+    async fn get_param(&self, metafield_id: &str, fields: &str) -> Result<()>;
+    async fn update_param(&self, metafield_id: &str, body: &serde_json::Value) -> Result<()>;
+    async fn delete_param(&self, metafield_id: &str) -> Result<()>;
+    async fn delete_param_if_exists(&self, metafield_id: &str) -> Result<bool>;
+    async fn deprecated_202101_get(
+        &self,
+        metafield_owner_id: i64,
+        metafield_owner_resource: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_create(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202101_get_count(&self) -> Result<()>;
+    async fn deprecated_202101_get_param(&self, metafield_id: &str, fields: &str) -> Result<()>;
+    async fn deprecated_202101_update_param(
+        &self,
+        metafield_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_param(&self, metafield_id: &str) -> Result<()>;
+    async fn deprecated_202101_delete_param_if_exists(&self, metafield_id: &str) -> Result<bool>;
+    async fn deprecated_unstable_get(
+        &self,
+        metafield_owner_id: i64,
+        metafield_owner_resource: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_unstable_get_count(&self) -> Result<()>;
+    async fn deprecated_unstable_get_param(&self, metafield_id: &str, fields: &str) -> Result<()>;
+    async fn deprecated_unstable_update_param(
+        &self,
+        metafield_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_param(&self, metafield_id: &str) -> Result<()>;
+    async fn deprecated_unstable_delete_param_if_exists(&self, metafield_id: &str) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl MetafieldApi for Metafield {
+    async fn deprecated_202001_get(
+        &self,
+        metafield_owner_id: i64,
+        metafield_owner_resource: &str,
+    ) -> Result<()> {
+        Metafield::deprecated_202001_get(self, metafield_owner_id, metafield_owner_resource).await
+    }
+    async fn deprecated_202001_create(&self, body: &serde_json::Value) -> Result<()> {
+        Metafield::deprecated_202001_create(self, body).await
+    }
+    async fn deprecated_202001_get_count(&self) -> Result<()> {
+        Metafield::deprecated_202001_get_count(self).await
+    }
+    async fn deprecated_202001_get_param(&self, metafield_id: &str, fields: &str) -> Result<()> {
+        Metafield::deprecated_202001_get_param(self, metafield_id, fields).await
+    }
+    async fn deprecated_202001_update_param(
+        &self,
+        metafield_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Metafield::deprecated_202001_update_param(self, metafield_id, body).await
+    }
+    async fn deprecated_202001_delete_param(&self, metafield_id: &str) -> Result<()> {
+        Metafield::deprecated_202001_delete_param(self, metafield_id).await
+    }
+    async fn deprecated_202001_delete_param_if_exists(&self, metafield_id: &str) -> Result<bool> {
+        Metafield::deprecated_202001_delete_param_if_exists(self, metafield_id).await
+    }
+    async fn deprecated_202004_get(
+        &self,
+        metafield_owner_id: i64,
+        metafield_owner_resource: &str,
+    ) -> Result<()> {
+        Metafield::deprecated_202004_get(self, metafield_owner_id, metafield_owner_resource).await
+    }
+    async fn deprecated_202004_create(&self, body: &serde_json::Value) -> Result<()> {
+        Metafield::deprecated_202004_create(self, body).await
+    }
+    async fn deprecated_202004_get_count(&self) -> Result<()> {
+        Metafield::deprecated_202004_get_count(self).await
+    }
+    async fn deprecated_202004_get_param(&self, metafield_id: &str, fields: &str) -> Result<()> {
+        Metafield::deprecated_202004_get_param(self, metafield_id, fields).await
+    }
+    async fn deprecated_202004_update_param(
+        &self,
+        metafield_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Metafield::deprecated_202004_update_param(self, metafield_id, body).await
+    }
+    async fn deprecated_202004_delete_param(&self, metafield_id: &str) -> Result<()> {
+        Metafield::deprecated_202004_delete_param(self, metafield_id).await
+    }
+    async fn deprecated_202004_delete_param_if_exists(&self, metafield_id: &str) -> Result<bool> {
+        Metafield::deprecated_202004_delete_param_if_exists(self, metafield_id).await
+    }
+    async fn deprecated_202007_get(
+        &self,
+        metafield_owner_id: i64,
+        metafield_owner_resource: &str,
+    ) -> Result<()> {
+        Metafield::deprecated_202007_get(self, metafield_owner_id, metafield_owner_resource).await
+    }
+    async fn deprecated_202007_create(&self, body: &serde_json::Value) -> Result<()> {
+        Metafield::deprecated_202007_create(self, body).await
+    }
+    async fn deprecated_202007_get_count(&self) -> Result<()> {
+        Metafield::deprecated_202007_get_count(self).await
+    }
+    async fn deprecated_202007_get_param(&self, metafield_id: &str, fields: &str) -> Result<()> {
+        Metafield::deprecated_202007_get_param(self, metafield_id, fields).await
+    }
+    async fn deprecated_202007_update_param(
+        &self,
+        metafield_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Metafield::deprecated_202007_update_param(self, metafield_id, body).await
+    }
+    async fn deprecated_202007_delete_param(&self, metafield_id: &str) -> Result<()> {
+        Metafield::deprecated_202007_delete_param(self, metafield_id).await
+    }
+    async fn deprecated_202007_delete_param_if_exists(&self, metafield_id: &str) -> Result<bool> {
+        Metafield::deprecated_202007_delete_param_if_exists(self, metafield_id).await
+    }
+    async fn get(&self, metafield_owner_id: i64, metafield_owner_resource: &str) -> Result<()> {
+        Metafield::get(self, metafield_owner_id, metafield_owner_resource).await
+    }
+    async fn create(&self, body: &serde_json::Value) -> Result<()> {
+        Metafield::create(self, body).await
+    }
+    async fn get_count(&self) -> Result<()> {
+        Metafield::get_count(self).await
+    }
+    async fn get_param(&self, metafield_id: &str, fields: &str) -> Result<()> {
+        Metafield::get_param(self, metafield_id, fields).await
+    }
+    async fn update_param(&self, metafield_id: &str, body: &serde_json::Value) -> Result<()> {
+        Metafield::update_param(self, metafield_id, body).await
+    }
+    async fn delete_param(&self, metafield_id: &str) -> Result<()> {
+        Metafield::delete_param(self, metafield_id).await
+    }
+    async fn delete_param_if_exists(&self, metafield_id: &str) -> Result<bool> {
+        Metafield::delete_param_if_exists(self, metafield_id).await
+    }
+    async fn deprecated_202101_get(
+        &self,
+        metafield_owner_id: i64,
+        metafield_owner_resource: &str,
+    ) -> Result<()> {
+        Metafield::deprecated_202101_get(self, metafield_owner_id, metafield_owner_resource).await
+    }
+    async fn deprecated_202101_create(&self, body: &serde_json::Value) -> Result<()> {
+        Metafield::deprecated_202101_create(self, body).await
+    }
+    async fn deprecated_202101_get_count(&self) -> Result<()> {
+        Metafield::deprecated_202101_get_count(self).await
+    }
+    async fn deprecated_202101_get_param(&self, metafield_id: &str, fields: &str) -> Result<()> {
+        Metafield::deprecated_202101_get_param(self, metafield_id, fields).await
+    }
+    async fn deprecated_202101_update_param(
+        &self,
+        metafield_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Metafield::deprecated_202101_update_param(self, metafield_id, body).await
+    }
+    async fn deprecated_202101_delete_param(&self, metafield_id: &str) -> Result<()> {
+        Metafield::deprecated_202101_delete_param(self, metafield_id).await
+    }
+    async fn deprecated_202101_delete_param_if_exists(&self, metafield_id: &str) -> Result<bool> {
+        Metafield::deprecated_202101_delete_param_if_exists(self, metafield_id).await
+    }
+    async fn deprecated_unstable_get(
+        &self,
+        metafield_owner_id: i64,
+        metafield_owner_resource: &str,
+    ) -> Result<()> {
+        Metafield::deprecated_unstable_get(self, metafield_owner_id, metafield_owner_resource).await
+    }
+    async fn deprecated_unstable_create(&self, body: &serde_json::Value) -> Result<()> {
+        Metafield::deprecated_unstable_create(self, body).await
+    }
+    async fn deprecated_unstable_get_count(&self) -> Result<()> {
+        Metafield::deprecated_unstable_get_count(self).await
+    }
+    async fn deprecated_unstable_get_param(&self, metafield_id: &str, fields: &str) -> Result<()> {
+        Metafield::deprecated_unstable_get_param(self, metafield_id, fields).await
+    }
+    async fn deprecated_unstable_update_param(
+        &self,
+        metafield_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Metafield::deprecated_unstable_update_param(self, metafield_id, body).await
+    }
+    async fn deprecated_unstable_delete_param(&self, metafield_id: &str) -> Result<()> {
+        Metafield::deprecated_unstable_delete_param(self, metafield_id).await
+    }
+    async fn deprecated_unstable_delete_param_if_exists(&self, metafield_id: &str) -> Result<bool> {
+        Metafield::deprecated_unstable_delete_param_if_exists(self, metafield_id).await
     }
 }
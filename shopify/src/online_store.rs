@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct OnlineStore {
     pub client: Client,
@@ -12,6 +10,14 @@ impl OnlineStore {
         OnlineStore { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        OnlineStore {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieves a list of all articles from a blog. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -59,54 +65,61 @@ impl OnlineStore {
         author: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !author.is_empty() {
-            query_args.push(("author".to_string(), author.to_string()));
+            query_args.push(("author", author.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !tag.is_empty() {
-            query_args.push(("tag".to_string(), tag.to_string()));
+            query_args.push(("tag", tag.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/blogs/{}/articles.json?{}",
-            crate::progenitor_support::encode_path(&blog_id.to_string()),
-            query_
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/blogs/{}/articles.json",
+                self.client.encode_path(blog_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/blogs/{blog_id}/articles.json",
+                &[("blog_id", blog_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -127,12 +140,19 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/blogs/{}/articles.json",
-            crate::progenitor_support::encode_path(&blog_id.to_string()),
+            self.client.encode_path(blog_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-01/blogs/{blog_id}/articles.json",
+                    &[("blog_id", blog_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -169,36 +189,43 @@ impl OnlineStore {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/blogs/{}/articles/count.json?{}",
-            crate::progenitor_support::encode_path(&blog_id.to_string()),
-            query_
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/blogs/{}/articles/count.json",
+                self.client.encode_path(blog_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/blogs/{blog_id}/articles/count.json",
+                &[("blog_id", blog_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -220,19 +247,29 @@ impl OnlineStore {
         article_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/blogs/{}/articles/{}/json?{}",
-            crate::progenitor_support::encode_path(&blog_id.to_string()),
-            crate::progenitor_support::encode_path(&article_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/blogs/{}/articles/{}/json",
+                self.client.encode_path(blog_id),
+                self.client.encode_path(article_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/blogs/{blog_id}/articles/{article_id}.json",
+                &[
+                    ("blog_id", blog_id.to_string()),
+                    ("article_id", article_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -255,13 +292,23 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/blogs/{}/articles/{}/json",
-            crate::progenitor_support::encode_path(&blog_id.to_string()),
-            crate::progenitor_support::encode_path(&article_id.to_string()),
+            self.client.encode_path(blog_id),
+            self.client.encode_path(article_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-01/blogs/{blog_id}/articles/{article_id}.json",
+                    &[
+                        ("blog_id", blog_id.to_string()),
+                        ("article_id", article_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -283,11 +330,53 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/blogs/{}/articles/{}/json",
-            crate::progenitor_support::encode_path(&blog_id.to_string()),
-            crate::progenitor_support::encode_path(&article_id.to_string()),
+            self.client.encode_path(blog_id),
+            self.client.encode_path(article_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-01/blogs/{blog_id}/articles/{article_id}.json",
+                &[
+                    ("blog_id", blog_id.to_string()),
+                    ("article_id", article_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Deletes an article.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-01/blogs/{blog_id}/articles/{article_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_delete_blogs_param_blog_articles_article`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202001_delete_blogs_param_blog_articles_article_if_exists(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-01/blogs/{}/articles/{}/json",
+            self.client.encode_path(blog_id),
+            self.client.encode_path(article_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-01/blogs/{blog_id}/articles/{article_id}.json",
+                    &[
+                        ("blog_id", blog_id.to_string()),
+                        ("article_id", article_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -299,7 +388,9 @@ impl OnlineStore {
      */
     pub async fn deprecated_202001_get_articles_author(&self) -> Result<()> {
         let url = "/admin/api/2020-01/articles/authors.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/articles/authors.json", &[])
+        })
     }
 
     /**
@@ -319,17 +410,19 @@ impl OnlineStore {
         limit: &str,
         popular: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !popular.is_empty() {
-            query_args.push(("popular".to_string(), popular.to_string()));
+            query_args.push(("popular", popular.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/articles/tags.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-01/articles/tags.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/articles/tags.json", &[])
+        })
     }
 
     /**
@@ -379,54 +472,61 @@ impl OnlineStore {
         author: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !author.is_empty() {
-            query_args.push(("author".to_string(), author.to_string()));
+            query_args.push(("author", author.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !tag.is_empty() {
-            query_args.push(("tag".to_string(), tag.to_string()));
+            query_args.push(("tag", tag.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/blogs/{}/articles.json?{}",
-            crate::progenitor_support::encode_path(&blog_id.to_string()),
-            query_
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/blogs/{}/articles.json",
+                self.client.encode_path(blog_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/blogs/{blog_id}/articles.json",
+                &[("blog_id", blog_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -447,12 +547,19 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/blogs/{}/articles.json",
-            crate::progenitor_support::encode_path(&blog_id.to_string()),
+            self.client.encode_path(blog_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-04/blogs/{blog_id}/articles.json",
+                    &[("blog_id", blog_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -489,36 +596,43 @@ impl OnlineStore {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/blogs/{}/articles/count.json?{}",
-            crate::progenitor_support::encode_path(&blog_id.to_string()),
-            query_
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/blogs/{}/articles/count.json",
+                self.client.encode_path(blog_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/blogs/{blog_id}/articles/count.json",
+                &[("blog_id", blog_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -540,19 +654,29 @@ impl OnlineStore {
         article_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/blogs/{}/articles/{}/json?{}",
-            crate::progenitor_support::encode_path(&blog_id.to_string()),
-            crate::progenitor_support::encode_path(&article_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/blogs/{}/articles/{}/json",
+                self.client.encode_path(blog_id),
+                self.client.encode_path(article_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/blogs/{blog_id}/articles/{article_id}.json",
+                &[
+                    ("blog_id", blog_id.to_string()),
+                    ("article_id", article_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -575,13 +699,23 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/blogs/{}/articles/{}/json",
-            crate::progenitor_support::encode_path(&blog_id.to_string()),
-            crate::progenitor_support::encode_path(&article_id.to_string()),
+            self.client.encode_path(blog_id),
+            self.client.encode_path(article_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-04/blogs/{blog_id}/articles/{article_id}.json",
+                    &[
+                        ("blog_id", blog_id.to_string()),
+                        ("article_id", article_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -603,11 +737,53 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/blogs/{}/articles/{}/json",
-            crate::progenitor_support::encode_path(&blog_id.to_string()),
-            crate::progenitor_support::encode_path(&article_id.to_string()),
+            self.client.encode_path(blog_id),
+            self.client.encode_path(article_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-04/blogs/{blog_id}/articles/{article_id}.json",
+                &[
+                    ("blog_id", blog_id.to_string()),
+                    ("article_id", article_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Deletes an article.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-04/blogs/{blog_id}/articles/{article_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_delete_blogs_param_blog_articles_article`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202004_delete_blogs_param_blog_articles_article_if_exists(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-04/blogs/{}/articles/{}/json",
+            self.client.encode_path(blog_id),
+            self.client.encode_path(article_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-04/blogs/{blog_id}/articles/{article_id}.json",
+                    &[
+                        ("blog_id", blog_id.to_string()),
+                        ("article_id", article_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -619,7 +795,9 @@ impl OnlineStore {
      */
     pub async fn deprecated_202004_get_articles_author(&self) -> Result<()> {
         let url = "/admin/api/2020-04/articles/authors.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/articles/authors.json", &[])
+        })
     }
 
     /**
@@ -639,17 +817,19 @@ impl OnlineStore {
         limit: &str,
         popular: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !popular.is_empty() {
-            query_args.push(("popular".to_string(), popular.to_string()));
+            query_args.push(("popular", popular.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/articles/tags.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-04/articles/tags.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/articles/tags.json", &[])
+        })
     }
 
     /**
@@ -699,54 +879,61 @@ impl OnlineStore {
         author: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !author.is_empty() {
-            query_args.push(("author".to_string(), author.to_string()));
+            query_args.push(("author", author.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !tag.is_empty() {
-            query_args.push(("tag".to_string(), tag.to_string()));
+            query_args.push(("tag", tag.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/blogs/{}/articles.json?{}",
-            crate::progenitor_support::encode_path(&blog_id.to_string()),
-            query_
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/blogs/{}/articles.json",
+                self.client.encode_path(blog_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/blogs/{blog_id}/articles.json",
+                &[("blog_id", blog_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -767,12 +954,19 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/blogs/{}/articles.json",
-            crate::progenitor_support::encode_path(&blog_id.to_string()),
+            self.client.encode_path(blog_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-07/blogs/{blog_id}/articles.json",
+                    &[("blog_id", blog_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -809,36 +1003,43 @@ impl OnlineStore {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/blogs/{}/articles/count.json?{}",
-            crate::progenitor_support::encode_path(&blog_id.to_string()),
-            query_
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/blogs/{}/articles/count.json",
+                self.client.encode_path(blog_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/blogs/{blog_id}/articles/count.json",
+                &[("blog_id", blog_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -860,19 +1061,29 @@ impl OnlineStore {
         article_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/blogs/{}/articles/{}/json?{}",
-            crate::progenitor_support::encode_path(&blog_id.to_string()),
-            crate::progenitor_support::encode_path(&article_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/blogs/{}/articles/{}/json",
+                self.client.encode_path(blog_id),
+                self.client.encode_path(article_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/blogs/{blog_id}/articles/{article_id}.json",
+                &[
+                    ("blog_id", blog_id.to_string()),
+                    ("article_id", article_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -895,13 +1106,23 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/blogs/{}/articles/{}/json",
-            crate::progenitor_support::encode_path(&blog_id.to_string()),
-            crate::progenitor_support::encode_path(&article_id.to_string()),
+            self.client.encode_path(blog_id),
+            self.client.encode_path(article_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-07/blogs/{blog_id}/articles/{article_id}.json",
+                    &[
+                        ("blog_id", blog_id.to_string()),
+                        ("article_id", article_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -923,11 +1144,53 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/blogs/{}/articles/{}/json",
-            crate::progenitor_support::encode_path(&blog_id.to_string()),
-            crate::progenitor_support::encode_path(&article_id.to_string()),
+            self.client.encode_path(blog_id),
+            self.client.encode_path(article_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-07/blogs/{blog_id}/articles/{article_id}.json",
+                &[
+                    ("blog_id", blog_id.to_string()),
+                    ("article_id", article_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Deletes an article.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-07/blogs/{blog_id}/articles/{article_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_delete_blogs_param_blog_articles_article`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202007_delete_blogs_param_blog_articles_article_if_exists(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-07/blogs/{}/articles/{}/json",
+            self.client.encode_path(blog_id),
+            self.client.encode_path(article_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-07/blogs/{blog_id}/articles/{article_id}.json",
+                    &[
+                        ("blog_id", blog_id.to_string()),
+                        ("article_id", article_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -939,7 +1202,9 @@ impl OnlineStore {
      */
     pub async fn deprecated_202007_get_articles_author(&self) -> Result<()> {
         let url = "/admin/api/2020-07/articles/authors.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/articles/authors.json", &[])
+        })
     }
 
     /**
@@ -959,17 +1224,19 @@ impl OnlineStore {
         limit: &str,
         popular: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !popular.is_empty() {
-            query_args.push(("popular".to_string(), popular.to_string()));
+            query_args.push(("popular", popular.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/articles/tags.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-07/articles/tags.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/articles/tags.json", &[])
+        })
     }
 
     /**
@@ -1019,54 +1286,61 @@ impl OnlineStore {
         author: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !author.is_empty() {
-            query_args.push(("author".to_string(), author.to_string()));
+            query_args.push(("author", author.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !tag.is_empty() {
-            query_args.push(("tag".to_string(), tag.to_string()));
+            query_args.push(("tag", tag.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/blogs/{}/articles.json?{}",
-            crate::progenitor_support::encode_path(&blog_id.to_string()),
-            query_
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/blogs/{}/articles.json",
+                self.client.encode_path(blog_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/blogs/{blog_id}/articles.json",
+                &[("blog_id", blog_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1087,12 +1361,19 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/blogs/{}/articles.json",
-            crate::progenitor_support::encode_path(&blog_id.to_string()),
+            self.client.encode_path(blog_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-10/blogs/{blog_id}/articles.json",
+                    &[("blog_id", blog_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1129,36 +1410,43 @@ impl OnlineStore {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/blogs/{}/articles/count.json?{}",
-            crate::progenitor_support::encode_path(&blog_id.to_string()),
-            query_
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/blogs/{}/articles/count.json",
+                self.client.encode_path(blog_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/blogs/{blog_id}/articles/count.json",
+                &[("blog_id", blog_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1180,19 +1468,29 @@ impl OnlineStore {
         article_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/blogs/{}/articles/{}/json?{}",
-            crate::progenitor_support::encode_path(&blog_id.to_string()),
-            crate::progenitor_support::encode_path(&article_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/blogs/{}/articles/{}/json",
+                self.client.encode_path(blog_id),
+                self.client.encode_path(article_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/blogs/{blog_id}/articles/{article_id}.json",
+                &[
+                    ("blog_id", blog_id.to_string()),
+                    ("article_id", article_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1215,13 +1513,23 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/blogs/{}/articles/{}/json",
-            crate::progenitor_support::encode_path(&blog_id.to_string()),
-            crate::progenitor_support::encode_path(&article_id.to_string()),
+            self.client.encode_path(blog_id),
+            self.client.encode_path(article_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-10/blogs/{blog_id}/articles/{article_id}.json",
+                    &[
+                        ("blog_id", blog_id.to_string()),
+                        ("article_id", article_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1243,11 +1551,53 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/blogs/{}/articles/{}/json",
-            crate::progenitor_support::encode_path(&blog_id.to_string()),
-            crate::progenitor_support::encode_path(&article_id.to_string()),
+            self.client.encode_path(blog_id),
+            self.client.encode_path(article_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-10/blogs/{blog_id}/articles/{article_id}.json",
+                &[
+                    ("blog_id", blog_id.to_string()),
+                    ("article_id", article_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Deletes an article.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-10/blogs/{blog_id}/articles/{article_id}.json` endpoint.
+     *
+     * As opposed to `delete_blogs_param_blog_articles_article`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_blogs_param_blog_articles_article_if_exists(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-10/blogs/{}/articles/{}/json",
+            self.client.encode_path(blog_id),
+            self.client.encode_path(article_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-10/blogs/{blog_id}/articles/{article_id}.json",
+                    &[
+                        ("blog_id", blog_id.to_string()),
+                        ("article_id", article_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1259,7 +1609,9 @@ impl OnlineStore {
      */
     pub async fn get_articles_author(&self) -> Result<()> {
         let url = "/admin/api/2020-10/articles/authors.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/articles/authors.json", &[])
+        })
     }
 
     /**
@@ -1275,17 +1627,19 @@ impl OnlineStore {
      * * `popular: &str` -- A flag for ordering retrieved tags. If present in the request, then the results will be ordered by popularity, starting with the most popular tag.
      */
     pub async fn get_articles_tag(&self, limit: &str, popular: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !popular.is_empty() {
-            query_args.push(("popular".to_string(), popular.to_string()));
+            query_args.push(("popular", popular.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/articles/tags.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-10/articles/tags.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/articles/tags.json", &[])
+        })
     }
 
     /**
@@ -1335,54 +1689,61 @@ impl OnlineStore {
         author: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !author.is_empty() {
-            query_args.push(("author".to_string(), author.to_string()));
+            query_args.push(("author", author.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !tag.is_empty() {
-            query_args.push(("tag".to_string(), tag.to_string()));
+            query_args.push(("tag", tag.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/blogs/{}/articles.json?{}",
-            crate::progenitor_support::encode_path(&blog_id.to_string()),
-            query_
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/blogs/{}/articles.json",
+                self.client.encode_path(blog_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/blogs/{blog_id}/articles.json",
+                &[("blog_id", blog_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1403,12 +1764,19 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/blogs/{}/articles.json",
-            crate::progenitor_support::encode_path(&blog_id.to_string()),
+            self.client.encode_path(blog_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2021-01/blogs/{blog_id}/articles.json",
+                    &[("blog_id", blog_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1445,36 +1813,43 @@ impl OnlineStore {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/blogs/{}/articles/count.json?{}",
-            crate::progenitor_support::encode_path(&blog_id.to_string()),
-            query_
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/blogs/{}/articles/count.json",
+                self.client.encode_path(blog_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/blogs/{blog_id}/articles/count.json",
+                &[("blog_id", blog_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1496,19 +1871,29 @@ impl OnlineStore {
         article_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/blogs/{}/articles/{}/json?{}",
-            crate::progenitor_support::encode_path(&blog_id.to_string()),
-            crate::progenitor_support::encode_path(&article_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/blogs/{}/articles/{}/json",
+                self.client.encode_path(blog_id),
+                self.client.encode_path(article_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/blogs/{blog_id}/articles/{article_id}.json",
+                &[
+                    ("blog_id", blog_id.to_string()),
+                    ("article_id", article_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1531,13 +1916,23 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/blogs/{}/articles/{}/json",
-            crate::progenitor_support::encode_path(&blog_id.to_string()),
-            crate::progenitor_support::encode_path(&article_id.to_string()),
+            self.client.encode_path(blog_id),
+            self.client.encode_path(article_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2021-01/blogs/{blog_id}/articles/{article_id}.json",
+                    &[
+                        ("blog_id", blog_id.to_string()),
+                        ("article_id", article_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1559,11 +1954,53 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/blogs/{}/articles/{}/json",
-            crate::progenitor_support::encode_path(&blog_id.to_string()),
-            crate::progenitor_support::encode_path(&article_id.to_string()),
+            self.client.encode_path(blog_id),
+            self.client.encode_path(article_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2021-01/blogs/{blog_id}/articles/{article_id}.json",
+                &[
+                    ("blog_id", blog_id.to_string()),
+                    ("article_id", article_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Deletes an article.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2021-01/blogs/{blog_id}/articles/{article_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_delete_blogs_param_blog_articles_article`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202101_delete_blogs_param_blog_articles_article_if_exists(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2021-01/blogs/{}/articles/{}/json",
+            self.client.encode_path(blog_id),
+            self.client.encode_path(article_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2021-01/blogs/{blog_id}/articles/{article_id}.json",
+                    &[
+                        ("blog_id", blog_id.to_string()),
+                        ("article_id", article_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1575,7 +2012,9 @@ impl OnlineStore {
      */
     pub async fn deprecated_202101_get_articles_author(&self) -> Result<()> {
         let url = "/admin/api/2021-01/articles/authors.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/articles/authors.json", &[])
+        })
     }
 
     /**
@@ -1595,17 +2034,19 @@ impl OnlineStore {
         limit: &str,
         popular: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !popular.is_empty() {
-            query_args.push(("popular".to_string(), popular.to_string()));
+            query_args.push(("popular", popular.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/articles/tags.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2021-01/articles/tags.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/articles/tags.json", &[])
+        })
     }
 
     /**
@@ -1655,54 +2096,61 @@ impl OnlineStore {
         author: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !author.is_empty() {
-            query_args.push(("author".to_string(), author.to_string()));
+            query_args.push(("author", author.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !tag.is_empty() {
-            query_args.push(("tag".to_string(), tag.to_string()));
+            query_args.push(("tag", tag.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/blogs/{}/articles.json?{}",
-            crate::progenitor_support::encode_path(&blog_id.to_string()),
-            query_
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/blogs/{}/articles.json",
+                self.client.encode_path(blog_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/blogs/{blog_id}/articles.json",
+                &[("blog_id", blog_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1723,12 +2171,19 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/blogs/{}/articles.json",
-            crate::progenitor_support::encode_path(&blog_id.to_string()),
+            self.client.encode_path(blog_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/unstable/blogs/{blog_id}/articles.json",
+                    &[("blog_id", blog_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1765,36 +2220,43 @@ impl OnlineStore {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/blogs/{}/articles/count.json?{}",
-            crate::progenitor_support::encode_path(&blog_id.to_string()),
-            query_
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/blogs/{}/articles/count.json",
+                self.client.encode_path(blog_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/blogs/{blog_id}/articles/count.json",
+                &[("blog_id", blog_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1816,19 +2278,29 @@ impl OnlineStore {
         article_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/blogs/{}/articles/{}/json?{}",
-            crate::progenitor_support::encode_path(&blog_id.to_string()),
-            crate::progenitor_support::encode_path(&article_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/blogs/{}/articles/{}/json",
+                self.client.encode_path(blog_id),
+                self.client.encode_path(article_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/blogs/{blog_id}/articles/{article_id}.json",
+                &[
+                    ("blog_id", blog_id.to_string()),
+                    ("article_id", article_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1851,13 +2323,23 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/blogs/{}/articles/{}/json",
-            crate::progenitor_support::encode_path(&blog_id.to_string()),
-            crate::progenitor_support::encode_path(&article_id.to_string()),
+            self.client.encode_path(blog_id),
+            self.client.encode_path(article_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/unstable/blogs/{blog_id}/articles/{article_id}.json",
+                    &[
+                        ("blog_id", blog_id.to_string()),
+                        ("article_id", article_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1879,11 +2361,53 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/blogs/{}/articles/{}/json",
-            crate::progenitor_support::encode_path(&blog_id.to_string()),
-            crate::progenitor_support::encode_path(&article_id.to_string()),
+            self.client.encode_path(blog_id),
+            self.client.encode_path(article_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/unstable/blogs/{blog_id}/articles/{article_id}.json",
+                &[
+                    ("blog_id", blog_id.to_string()),
+                    ("article_id", article_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Deletes an article.
+     *
+     * This function performs a `DELETE` to the `/admin/api/unstable/blogs/{blog_id}/articles/{article_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_delete_blogs_param_blog_articles_article`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_unstable_delete_blogs_param_blog_articles_article_if_exists(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/unstable/blogs/{}/articles/{}/json",
+            self.client.encode_path(blog_id),
+            self.client.encode_path(article_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/unstable/blogs/{blog_id}/articles/{article_id}.json",
+                    &[
+                        ("blog_id", blog_id.to_string()),
+                        ("article_id", article_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1895,7 +2419,9 @@ impl OnlineStore {
      */
     pub async fn deprecated_unstable_get_articles_author(&self) -> Result<()> {
         let url = "/admin/api/unstable/articles/authors.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/articles/authors.json", &[])
+        })
     }
 
     /**
@@ -1915,17 +2441,19 @@ impl OnlineStore {
         limit: &str,
         popular: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !popular.is_empty() {
-            query_args.push(("popular".to_string(), popular.to_string()));
+            query_args.push(("popular", popular.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/articles/tags.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/unstable/articles/tags.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/articles/tags.json", &[])
+        })
     }
 
     /**
@@ -1949,21 +2477,28 @@ impl OnlineStore {
         fields: &str,
         asset_key: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !asset_key.is_empty() {
-            query_args.push(("asset[key]".to_string(), asset_key.to_string()));
+            query_args.push(("asset[key]", asset_key.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/themes/{}/assets.json?{}",
-            crate::progenitor_support::encode_path(&theme_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/themes/{}/assets.json",
+                self.client.encode_path(theme_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/themes/{theme_id}/assets.json",
+                &[("theme_id", theme_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1987,23 +2522,31 @@ impl OnlineStore {
         source_key: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !source_key.is_empty() {
-            query_args.push(("source_key".to_string(), source_key.to_string()));
+            query_args.push(("source_key", source_key.to_string()));
         }
         if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/themes/{}/assets.json?{}",
-            crate::progenitor_support::encode_path(&theme_id.to_string()),
-            query_
+            query_args.push(("src", src.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/themes/{}/assets.json",
+                self.client.encode_path(theme_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-01/themes/{theme_id}/assets.json",
+                    &[("theme_id", theme_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2023,18 +2566,61 @@ impl OnlineStore {
         theme_id: &str,
         asset_key: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !asset_key.is_empty() {
-            query_args.push(("asset[key]".to_string(), asset_key.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/themes/{}/assets.json?{}",
-            crate::progenitor_support::encode_path(&theme_id.to_string()),
-            query_
+            query_args.push(("asset[key]", asset_key.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/themes/{}/assets.json",
+                self.client.encode_path(theme_id)
+            ),
+            &query_args,
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-01/themes/{theme_id}/assets.json",
+                &[("theme_id", theme_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes an asset from a theme.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-01/themes/{theme_id}/assets.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_delete_themes_param_theme_assets`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202001_delete_themes_param_theme_assets_if_exists(
+        &self,
+        theme_id: &str,
+        asset_key: &str,
+    ) -> Result<bool> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !asset_key.is_empty() {
+            query_args.push(("asset[key]", asset_key.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/themes/{}/assets.json",
+                self.client.encode_path(theme_id)
+            ),
+            &query_args,
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-01/themes/{theme_id}/assets.json",
+                    &[("theme_id", theme_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2058,21 +2644,28 @@ impl OnlineStore {
         fields: &str,
         asset_key: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !asset_key.is_empty() {
-            query_args.push(("asset[key]".to_string(), asset_key.to_string()));
+            query_args.push(("asset[key]", asset_key.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/themes/{}/assets.json?{}",
-            crate::progenitor_support::encode_path(&theme_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/themes/{}/assets.json",
+                self.client.encode_path(theme_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/themes/{theme_id}/assets.json",
+                &[("theme_id", theme_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2096,23 +2689,31 @@ impl OnlineStore {
         source_key: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !source_key.is_empty() {
-            query_args.push(("source_key".to_string(), source_key.to_string()));
+            query_args.push(("source_key", source_key.to_string()));
         }
         if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/themes/{}/assets.json?{}",
-            crate::progenitor_support::encode_path(&theme_id.to_string()),
-            query_
+            query_args.push(("src", src.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/themes/{}/assets.json",
+                self.client.encode_path(theme_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-04/themes/{theme_id}/assets.json",
+                    &[("theme_id", theme_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2132,18 +2733,61 @@ impl OnlineStore {
         theme_id: &str,
         asset_key: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !asset_key.is_empty() {
-            query_args.push(("asset[key]".to_string(), asset_key.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/themes/{}/assets.json?{}",
-            crate::progenitor_support::encode_path(&theme_id.to_string()),
-            query_
+            query_args.push(("asset[key]", asset_key.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/themes/{}/assets.json",
+                self.client.encode_path(theme_id)
+            ),
+            &query_args,
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-04/themes/{theme_id}/assets.json",
+                &[("theme_id", theme_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes an asset from a theme.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-04/themes/{theme_id}/assets.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_delete_themes_param_theme_assets`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202004_delete_themes_param_theme_assets_if_exists(
+        &self,
+        theme_id: &str,
+        asset_key: &str,
+    ) -> Result<bool> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !asset_key.is_empty() {
+            query_args.push(("asset[key]", asset_key.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/themes/{}/assets.json",
+                self.client.encode_path(theme_id)
+            ),
+            &query_args,
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-04/themes/{theme_id}/assets.json",
+                    &[("theme_id", theme_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2167,21 +2811,28 @@ impl OnlineStore {
         fields: &str,
         asset_key: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !asset_key.is_empty() {
-            query_args.push(("asset[key]".to_string(), asset_key.to_string()));
+            query_args.push(("asset[key]", asset_key.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/themes/{}/assets.json?{}",
-            crate::progenitor_support::encode_path(&theme_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/themes/{}/assets.json",
+                self.client.encode_path(theme_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/themes/{theme_id}/assets.json",
+                &[("theme_id", theme_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2205,23 +2856,31 @@ impl OnlineStore {
         source_key: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !source_key.is_empty() {
-            query_args.push(("source_key".to_string(), source_key.to_string()));
+            query_args.push(("source_key", source_key.to_string()));
         }
         if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/themes/{}/assets.json?{}",
-            crate::progenitor_support::encode_path(&theme_id.to_string()),
-            query_
+            query_args.push(("src", src.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/themes/{}/assets.json",
+                self.client.encode_path(theme_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-07/themes/{theme_id}/assets.json",
+                    &[("theme_id", theme_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2241,18 +2900,61 @@ impl OnlineStore {
         theme_id: &str,
         asset_key: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !asset_key.is_empty() {
-            query_args.push(("asset[key]".to_string(), asset_key.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/themes/{}/assets.json?{}",
-            crate::progenitor_support::encode_path(&theme_id.to_string()),
-            query_
+            query_args.push(("asset[key]", asset_key.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/themes/{}/assets.json",
+                self.client.encode_path(theme_id)
+            ),
+            &query_args,
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-07/themes/{theme_id}/assets.json",
+                &[("theme_id", theme_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes an asset from a theme.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-07/themes/{theme_id}/assets.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_delete_themes_param_theme_assets`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202007_delete_themes_param_theme_assets_if_exists(
+        &self,
+        theme_id: &str,
+        asset_key: &str,
+    ) -> Result<bool> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !asset_key.is_empty() {
+            query_args.push(("asset[key]", asset_key.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/themes/{}/assets.json",
+                self.client.encode_path(theme_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-07/themes/{theme_id}/assets.json",
+                    &[("theme_id", theme_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2276,21 +2978,28 @@ impl OnlineStore {
         fields: &str,
         asset_key: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !asset_key.is_empty() {
-            query_args.push(("asset[key]".to_string(), asset_key.to_string()));
+            query_args.push(("asset[key]", asset_key.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/themes/{}/assets.json?{}",
-            crate::progenitor_support::encode_path(&theme_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/themes/{}/assets.json",
+                self.client.encode_path(theme_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/themes/{theme_id}/assets.json",
+                &[("theme_id", theme_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2314,23 +3023,31 @@ impl OnlineStore {
         source_key: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !source_key.is_empty() {
-            query_args.push(("source_key".to_string(), source_key.to_string()));
+            query_args.push(("source_key", source_key.to_string()));
         }
         if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/themes/{}/assets.json?{}",
-            crate::progenitor_support::encode_path(&theme_id.to_string()),
-            query_
+            query_args.push(("src", src.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/themes/{}/assets.json",
+                self.client.encode_path(theme_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-10/themes/{theme_id}/assets.json",
+                    &[("theme_id", theme_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2350,18 +3067,61 @@ impl OnlineStore {
         theme_id: &str,
         asset_key: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !asset_key.is_empty() {
-            query_args.push(("asset[key]".to_string(), asset_key.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/themes/{}/assets.json?{}",
-            crate::progenitor_support::encode_path(&theme_id.to_string()),
-            query_
+            query_args.push(("asset[key]", asset_key.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/themes/{}/assets.json",
+                self.client.encode_path(theme_id)
+            ),
+            &query_args,
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-10/themes/{theme_id}/assets.json",
+                &[("theme_id", theme_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes an asset from a theme.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-10/themes/{theme_id}/assets.json` endpoint.
+     *
+     * As opposed to `delete_themes_param_theme_assets`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_themes_param_theme_assets_if_exists(
+        &self,
+        theme_id: &str,
+        asset_key: &str,
+    ) -> Result<bool> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !asset_key.is_empty() {
+            query_args.push(("asset[key]", asset_key.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/themes/{}/assets.json",
+                self.client.encode_path(theme_id)
+            ),
+            &query_args,
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-10/themes/{theme_id}/assets.json",
+                    &[("theme_id", theme_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2385,21 +3145,28 @@ impl OnlineStore {
         fields: &str,
         asset_key: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !asset_key.is_empty() {
-            query_args.push(("asset[key]".to_string(), asset_key.to_string()));
+            query_args.push(("asset[key]", asset_key.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/themes/{}/assets.json?{}",
-            crate::progenitor_support::encode_path(&theme_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/themes/{}/assets.json",
+                self.client.encode_path(theme_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/themes/{theme_id}/assets.json",
+                &[("theme_id", theme_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2423,23 +3190,31 @@ impl OnlineStore {
         source_key: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !source_key.is_empty() {
-            query_args.push(("source_key".to_string(), source_key.to_string()));
+            query_args.push(("source_key", source_key.to_string()));
         }
         if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/themes/{}/assets.json?{}",
-            crate::progenitor_support::encode_path(&theme_id.to_string()),
-            query_
+            query_args.push(("src", src.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/themes/{}/assets.json",
+                self.client.encode_path(theme_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2021-01/themes/{theme_id}/assets.json",
+                    &[("theme_id", theme_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2459,18 +3234,61 @@ impl OnlineStore {
         theme_id: &str,
         asset_key: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !asset_key.is_empty() {
-            query_args.push(("asset[key]".to_string(), asset_key.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/themes/{}/assets.json?{}",
-            crate::progenitor_support::encode_path(&theme_id.to_string()),
-            query_
+            query_args.push(("asset[key]", asset_key.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/themes/{}/assets.json",
+                self.client.encode_path(theme_id)
+            ),
+            &query_args,
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2021-01/themes/{theme_id}/assets.json",
+                &[("theme_id", theme_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes an asset from a theme.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2021-01/themes/{theme_id}/assets.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_delete_themes_param_theme_assets`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202101_delete_themes_param_theme_assets_if_exists(
+        &self,
+        theme_id: &str,
+        asset_key: &str,
+    ) -> Result<bool> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !asset_key.is_empty() {
+            query_args.push(("asset[key]", asset_key.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/themes/{}/assets.json",
+                self.client.encode_path(theme_id)
+            ),
+            &query_args,
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2021-01/themes/{theme_id}/assets.json",
+                    &[("theme_id", theme_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2494,21 +3312,28 @@ impl OnlineStore {
         fields: &str,
         asset_key: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !asset_key.is_empty() {
-            query_args.push(("asset[key]".to_string(), asset_key.to_string()));
+            query_args.push(("asset[key]", asset_key.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/themes/{}/assets.json?{}",
-            crate::progenitor_support::encode_path(&theme_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/themes/{}/assets.json",
+                self.client.encode_path(theme_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/themes/{theme_id}/assets.json",
+                &[("theme_id", theme_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2532,23 +3357,31 @@ impl OnlineStore {
         source_key: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !source_key.is_empty() {
-            query_args.push(("source_key".to_string(), source_key.to_string()));
+            query_args.push(("source_key", source_key.to_string()));
         }
         if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/themes/{}/assets.json?{}",
-            crate::progenitor_support::encode_path(&theme_id.to_string()),
-            query_
+            query_args.push(("src", src.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/themes/{}/assets.json",
+                self.client.encode_path(theme_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/unstable/themes/{theme_id}/assets.json",
+                    &[("theme_id", theme_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2568,18 +3401,61 @@ impl OnlineStore {
         theme_id: &str,
         asset_key: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !asset_key.is_empty() {
-            query_args.push(("asset[key]".to_string(), asset_key.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/themes/{}/assets.json?{}",
-            crate::progenitor_support::encode_path(&theme_id.to_string()),
-            query_
+            query_args.push(("asset[key]", asset_key.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/themes/{}/assets.json",
+                self.client.encode_path(theme_id)
+            ),
+            &query_args,
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/unstable/themes/{theme_id}/assets.json",
+                &[("theme_id", theme_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes an asset from a theme.
+     *
+     * This function performs a `DELETE` to the `/admin/api/unstable/themes/{theme_id}/assets.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_delete_themes_param_theme_assets`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_unstable_delete_themes_param_theme_assets_if_exists(
+        &self,
+        theme_id: &str,
+        asset_key: &str,
+    ) -> Result<bool> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !asset_key.is_empty() {
+            query_args.push(("asset[key]", asset_key.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/themes/{}/assets.json",
+                self.client.encode_path(theme_id)
+            ),
+            &query_args,
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/unstable/themes/{theme_id}/assets.json",
+                    &[("theme_id", theme_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2604,23 +3480,25 @@ impl OnlineStore {
         handle: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/blogs.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-01/blogs.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-01/blogs.json", &[]))
     }
 
     /**
@@ -2633,8 +3511,9 @@ impl OnlineStore {
     pub async fn deprecated_202001_create_blogs(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-01/blogs.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-01/blogs.json", &[]))
     }
 
     /**
@@ -2646,7 +3525,9 @@ impl OnlineStore {
      */
     pub async fn deprecated_202001_get_blogs_count(&self) -> Result<()> {
         let url = "/admin/api/2020-01/blogs/count.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/blogs/count.json", &[])
+        })
     }
 
     /**
@@ -2666,18 +3547,25 @@ impl OnlineStore {
         blog_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/blogs/{}/json?{}",
-            crate::progenitor_support::encode_path(&blog_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/blogs/{}/json",
+                self.client.encode_path(blog_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/blogs/{blog_id}.json",
+                &[("blog_id", blog_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2698,12 +3586,19 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/blogs/{}/json",
-            crate::progenitor_support::encode_path(&blog_id.to_string()),
+            self.client.encode_path(blog_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-01/blogs/{blog_id}.json",
+                    &[("blog_id", blog_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2720,10 +3615,44 @@ impl OnlineStore {
     pub async fn deprecated_202001_delete_blogs_param_blog(&self, blog_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/blogs/{}/json",
-            crate::progenitor_support::encode_path(&blog_id.to_string()),
+            self.client.encode_path(blog_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-01/blogs/{blog_id}.json",
+                &[("blog_id", blog_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete a blog.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-01/blogs/{blog_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_delete_blogs_param_blog`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202001_delete_blogs_param_blog_if_exists(
+        &self,
+        blog_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-01/blogs/{}/json",
+            self.client.encode_path(blog_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-01/blogs/{blog_id}.json",
+                    &[("blog_id", blog_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2775,50 +3704,51 @@ impl OnlineStore {
         article_id: i64,
         blog_id: i64,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if article_id > 0 {
-            query_args.push(("article_id".to_string(), article_id.to_string()));
+            query_args.push(("article_id", article_id.to_string()));
         }
         if blog_id > 0 {
-            query_args.push(("blog_id".to_string(), blog_id.to_string()));
+            query_args.push(("blog_id", blog_id.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/comments.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-01/comments.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/comments.json", &[])
+        })
     }
 
     /**
@@ -2831,8 +3761,11 @@ impl OnlineStore {
     pub async fn deprecated_202001_create_comments(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-01/comments.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-01/comments.json", &[])
+            })
     }
 
     /**
@@ -2877,41 +3810,43 @@ impl OnlineStore {
         article_id: i64,
         blog_id: i64,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if article_id > 0 {
-            query_args.push(("article_id".to_string(), article_id.to_string()));
+            query_args.push(("article_id", article_id.to_string()));
         }
         if blog_id > 0 {
-            query_args.push(("blog_id".to_string(), blog_id.to_string()));
+            query_args.push(("blog_id", blog_id.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/comments/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-01/comments/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/comments/count.json", &[])
+        })
     }
 
     /**
@@ -2931,18 +3866,25 @@ impl OnlineStore {
         comment_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/comments/{}/json?{}",
-            crate::progenitor_support::encode_path(&comment_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/comments/{}/json",
+                self.client.encode_path(comment_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/comments/{comment_id}.json",
+                &[("comment_id", comment_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2963,12 +3905,19 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/comments/{}/json",
-            crate::progenitor_support::encode_path(&comment_id.to_string()),
+            self.client.encode_path(comment_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-01/comments/{comment_id}.json",
+                    &[("comment_id", comment_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2989,12 +3938,19 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/comments/{}/spam.json",
-            crate::progenitor_support::encode_path(&comment_id.to_string()),
+            self.client.encode_path(comment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-01/comments/{comment_id}/spam.json",
+                    &[("comment_id", comment_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -3015,12 +3971,19 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/comments/{}/not_spam.json",
-            crate::progenitor_support::encode_path(&comment_id.to_string()),
+            self.client.encode_path(comment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-01/comments/{comment_id}/not_spam.json",
+                    &[("comment_id", comment_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -3041,12 +4004,19 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/comments/{}/approve.json",
-            crate::progenitor_support::encode_path(&comment_id.to_string()),
+            self.client.encode_path(comment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-01/comments/{comment_id}/approve.json",
+                    &[("comment_id", comment_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -3067,12 +4037,19 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/comments/{}/remove.json",
-            crate::progenitor_support::encode_path(&comment_id.to_string()),
+            self.client.encode_path(comment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-01/comments/{comment_id}/remove.json",
+                    &[("comment_id", comment_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -3093,12 +4070,19 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/comments/{}/restore.json",
-            crate::progenitor_support::encode_path(&comment_id.to_string()),
+            self.client.encode_path(comment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-01/comments/{comment_id}/restore.json",
+                    &[("comment_id", comment_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -3125,26 +4109,27 @@ impl OnlineStore {
         target: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
+            query_args.push(("path", path.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !target.is_empty() {
-            query_args.push(("target".to_string(), target.to_string()));
+            query_args.push(("target", target.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/redirects.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-01/redirects.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/redirects.json", &[])
+        })
     }
 
     /**
@@ -3158,8 +4143,11 @@ impl OnlineStore {
     pub async fn deprecated_202001_create_redirects(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-01/redirects.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-01/redirects.json", &[])
+            })
     }
 
     /**
@@ -3179,17 +4167,19 @@ impl OnlineStore {
         path: &str,
         target: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
+            query_args.push(("path", path.to_string()));
         }
         if !target.is_empty() {
-            query_args.push(("target".to_string(), target.to_string()));
+            query_args.push(("target", target.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/redirects/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-01/redirects/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/redirects/count.json", &[])
+        })
     }
 
     /**
@@ -3209,18 +4199,25 @@ impl OnlineStore {
         redirect_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/redirects/{}/json?{}",
-            crate::progenitor_support::encode_path(&redirect_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/redirects/{}/json",
+                self.client.encode_path(redirect_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/redirects/{redirect_id}.json",
+                &[("redirect_id", redirect_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -3241,12 +4238,19 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/redirects/{}/json",
-            crate::progenitor_support::encode_path(&redirect_id.to_string()),
+            self.client.encode_path(redirect_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-01/redirects/{redirect_id}.json",
+                    &[("redirect_id", redirect_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -3266,10 +4270,44 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/redirects/{}/json",
-            crate::progenitor_support::encode_path(&redirect_id.to_string()),
+            self.client.encode_path(redirect_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-01/redirects/{redirect_id}.json",
+                &[("redirect_id", redirect_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a redirect.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-01/redirects/{redirect_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_delete_redirects_param_redirect`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202001_delete_redirects_param_redirect_if_exists(
+        &self,
+        redirect_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-01/redirects/{}/json",
+            self.client.encode_path(redirect_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-01/redirects/{redirect_id}.json",
+                    &[("redirect_id", redirect_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -3296,26 +4334,27 @@ impl OnlineStore {
         target: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
+            query_args.push(("path", path.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !target.is_empty() {
-            query_args.push(("target".to_string(), target.to_string()));
+            query_args.push(("target", target.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/redirects.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-04/redirects.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/redirects.json", &[])
+        })
     }
 
     /**
@@ -3329,8 +4368,11 @@ impl OnlineStore {
     pub async fn deprecated_202004_create_redirects(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-04/redirects.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-04/redirects.json", &[])
+            })
     }
 
     /**
@@ -3350,17 +4392,19 @@ impl OnlineStore {
         path: &str,
         target: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
+            query_args.push(("path", path.to_string()));
         }
         if !target.is_empty() {
-            query_args.push(("target".to_string(), target.to_string()));
+            query_args.push(("target", target.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/redirects/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-04/redirects/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/redirects/count.json", &[])
+        })
     }
 
     /**
@@ -3380,18 +4424,25 @@ impl OnlineStore {
         redirect_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/redirects/{}/json?{}",
-            crate::progenitor_support::encode_path(&redirect_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/redirects/{}/json",
+                self.client.encode_path(redirect_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/redirects/{redirect_id}.json",
+                &[("redirect_id", redirect_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -3412,12 +4463,19 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/redirects/{}/json",
-            crate::progenitor_support::encode_path(&redirect_id.to_string()),
+            self.client.encode_path(redirect_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-04/redirects/{redirect_id}.json",
+                    &[("redirect_id", redirect_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -3437,10 +4495,44 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/redirects/{}/json",
-            crate::progenitor_support::encode_path(&redirect_id.to_string()),
+            self.client.encode_path(redirect_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-04/redirects/{redirect_id}.json",
+                &[("redirect_id", redirect_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a redirect.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-04/redirects/{redirect_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_delete_redirects_param_redirect`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202004_delete_redirects_param_redirect_if_exists(
+        &self,
+        redirect_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-04/redirects/{}/json",
+            self.client.encode_path(redirect_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-04/redirects/{redirect_id}.json",
+                    &[("redirect_id", redirect_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -3467,26 +4559,27 @@ impl OnlineStore {
         target: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
+            query_args.push(("path", path.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !target.is_empty() {
-            query_args.push(("target".to_string(), target.to_string()));
+            query_args.push(("target", target.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/redirects.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-07/redirects.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/redirects.json", &[])
+        })
     }
 
     /**
@@ -3500,8 +4593,11 @@ impl OnlineStore {
     pub async fn deprecated_202007_create_redirects(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-07/redirects.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-07/redirects.json", &[])
+            })
     }
 
     /**
@@ -3521,17 +4617,19 @@ impl OnlineStore {
         path: &str,
         target: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
+            query_args.push(("path", path.to_string()));
         }
         if !target.is_empty() {
-            query_args.push(("target".to_string(), target.to_string()));
+            query_args.push(("target", target.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/redirects/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-07/redirects/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/redirects/count.json", &[])
+        })
     }
 
     /**
@@ -3551,18 +4649,25 @@ impl OnlineStore {
         redirect_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/redirects/{}/json?{}",
-            crate::progenitor_support::encode_path(&redirect_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/redirects/{}/json",
+                self.client.encode_path(redirect_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/redirects/{redirect_id}.json",
+                &[("redirect_id", redirect_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -3583,12 +4688,19 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/redirects/{}/json",
-            crate::progenitor_support::encode_path(&redirect_id.to_string()),
+            self.client.encode_path(redirect_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-07/redirects/{redirect_id}.json",
+                    &[("redirect_id", redirect_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -3608,10 +4720,44 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/redirects/{}/json",
-            crate::progenitor_support::encode_path(&redirect_id.to_string()),
+            self.client.encode_path(redirect_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-07/redirects/{redirect_id}.json",
+                &[("redirect_id", redirect_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a redirect.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-07/redirects/{redirect_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_delete_redirects_param_redirect`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202007_delete_redirects_param_redirect_if_exists(
+        &self,
+        redirect_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-07/redirects/{}/json",
+            self.client.encode_path(redirect_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-07/redirects/{redirect_id}.json",
+                    &[("redirect_id", redirect_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -3638,26 +4784,27 @@ impl OnlineStore {
         target: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
+            query_args.push(("path", path.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !target.is_empty() {
-            query_args.push(("target".to_string(), target.to_string()));
+            query_args.push(("target", target.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/redirects.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-10/redirects.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/redirects.json", &[])
+        })
     }
 
     /**
@@ -3671,8 +4818,11 @@ impl OnlineStore {
     pub async fn create_redirects(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-10/redirects.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-10/redirects.json", &[])
+            })
     }
 
     /**
@@ -3688,17 +4838,19 @@ impl OnlineStore {
      * * `target: &str` -- Count redirects with given target.
      */
     pub async fn get_redirects_count(&self, path: &str, target: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
+            query_args.push(("path", path.to_string()));
         }
         if !target.is_empty() {
-            query_args.push(("target".to_string(), target.to_string()));
+            query_args.push(("target", target.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/redirects/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-10/redirects/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/redirects/count.json", &[])
+        })
     }
 
     /**
@@ -3718,18 +4870,25 @@ impl OnlineStore {
         redirect_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/redirects/{}/json?{}",
-            crate::progenitor_support::encode_path(&redirect_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/redirects/{}/json",
+                self.client.encode_path(redirect_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/redirects/{redirect_id}.json",
+                &[("redirect_id", redirect_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -3750,12 +4909,19 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/redirects/{}/json",
-            crate::progenitor_support::encode_path(&redirect_id.to_string()),
+            self.client.encode_path(redirect_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-10/redirects/{redirect_id}.json",
+                    &[("redirect_id", redirect_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -3772,10 +4938,44 @@ impl OnlineStore {
     pub async fn delete_redirects_param_redirect(&self, redirect_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/redirects/{}/json",
-            crate::progenitor_support::encode_path(&redirect_id.to_string()),
+            self.client.encode_path(redirect_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-10/redirects/{redirect_id}.json",
+                &[("redirect_id", redirect_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a redirect.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-10/redirects/{redirect_id}.json` endpoint.
+     *
+     * As opposed to `delete_redirects_param_redirect`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_redirects_param_redirect_if_exists(
+        &self,
+        redirect_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-10/redirects/{}/json",
+            self.client.encode_path(redirect_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-10/redirects/{redirect_id}.json",
+                    &[("redirect_id", redirect_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -3802,26 +5002,27 @@ impl OnlineStore {
         target: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
+            query_args.push(("path", path.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !target.is_empty() {
-            query_args.push(("target".to_string(), target.to_string()));
+            query_args.push(("target", target.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/redirects.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2021-01/redirects.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/redirects.json", &[])
+        })
     }
 
     /**
@@ -3835,8 +5036,11 @@ impl OnlineStore {
     pub async fn deprecated_202101_create_redirects(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2021-01/redirects.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2021-01/redirects.json", &[])
+            })
     }
 
     /**
@@ -3856,17 +5060,19 @@ impl OnlineStore {
         path: &str,
         target: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
+            query_args.push(("path", path.to_string()));
         }
         if !target.is_empty() {
-            query_args.push(("target".to_string(), target.to_string()));
+            query_args.push(("target", target.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/redirects/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2021-01/redirects/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/redirects/count.json", &[])
+        })
     }
 
     /**
@@ -3886,18 +5092,25 @@ impl OnlineStore {
         redirect_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/redirects/{}/json?{}",
-            crate::progenitor_support::encode_path(&redirect_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/redirects/{}/json",
+                self.client.encode_path(redirect_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/redirects/{redirect_id}.json",
+                &[("redirect_id", redirect_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -3918,12 +5131,19 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/redirects/{}/json",
-            crate::progenitor_support::encode_path(&redirect_id.to_string()),
+            self.client.encode_path(redirect_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2021-01/redirects/{redirect_id}.json",
+                    &[("redirect_id", redirect_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -3943,10 +5163,44 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/redirects/{}/json",
-            crate::progenitor_support::encode_path(&redirect_id.to_string()),
+            self.client.encode_path(redirect_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2021-01/redirects/{redirect_id}.json",
+                &[("redirect_id", redirect_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a redirect.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2021-01/redirects/{redirect_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_delete_redirects_param_redirect`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202101_delete_redirects_param_redirect_if_exists(
+        &self,
+        redirect_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2021-01/redirects/{}/json",
+            self.client.encode_path(redirect_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2021-01/redirects/{redirect_id}.json",
+                    &[("redirect_id", redirect_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -3973,26 +5227,27 @@ impl OnlineStore {
         target: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
+            query_args.push(("path", path.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !target.is_empty() {
-            query_args.push(("target".to_string(), target.to_string()));
+            query_args.push(("target", target.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/redirects.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/unstable/redirects.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/redirects.json", &[])
+        })
     }
 
     /**
@@ -4009,8 +5264,11 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = "/admin/api/unstable/redirects.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/unstable/redirects.json", &[])
+            })
     }
 
     /**
@@ -4030,17 +5288,19 @@ impl OnlineStore {
         path: &str,
         target: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
+            query_args.push(("path", path.to_string()));
         }
         if !target.is_empty() {
-            query_args.push(("target".to_string(), target.to_string()));
+            query_args.push(("target", target.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/redirects/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/unstable/redirects/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/redirects/count.json", &[])
+        })
     }
 
     /**
@@ -4060,18 +5320,25 @@ impl OnlineStore {
         redirect_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/redirects/{}/json?{}",
-            crate::progenitor_support::encode_path(&redirect_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/redirects/{}/json",
+                self.client.encode_path(redirect_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/redirects/{redirect_id}.json",
+                &[("redirect_id", redirect_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -4092,12 +5359,19 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/redirects/{}/json",
-            crate::progenitor_support::encode_path(&redirect_id.to_string()),
+            self.client.encode_path(redirect_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/unstable/redirects/{redirect_id}.json",
+                    &[("redirect_id", redirect_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -4117,10 +5391,44 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/redirects/{}/json",
-            crate::progenitor_support::encode_path(&redirect_id.to_string()),
+            self.client.encode_path(redirect_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/unstable/redirects/{redirect_id}.json",
+                &[("redirect_id", redirect_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a redirect.
+     *
+     * This function performs a `DELETE` to the `/admin/api/unstable/redirects/{redirect_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_delete_redirects_param_redirect`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_unstable_delete_redirects_param_redirect_if_exists(
+        &self,
+        redirect_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/unstable/redirects/{}/json",
+            self.client.encode_path(redirect_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/unstable/redirects/{redirect_id}.json",
+                    &[("redirect_id", redirect_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -4153,35 +5461,36 @@ impl OnlineStore {
         src: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
+            query_args.push(("src", src.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/script_tags.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-01/script_tags.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/script_tags.json", &[])
+        })
     }
 
     /**
@@ -4197,8 +5506,11 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = "/admin/api/2020-01/script_tags.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-01/script_tags.json", &[])
+            })
     }
 
     /**
@@ -4213,14 +5525,16 @@ impl OnlineStore {
      * * `src: &str` -- Count only script tags with a given URL.
      */
     pub async fn deprecated_202001_get_script_tags_count(&self, src: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
+            query_args.push(("src", src.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/script_tags/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-01/script_tags/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/script_tags/count.json", &[])
+        })
     }
 
     /**
@@ -4240,18 +5554,25 @@ impl OnlineStore {
         script_tag_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/script_tags/{}/json?{}",
-            crate::progenitor_support::encode_path(&script_tag_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/script_tags/{}/json",
+                self.client.encode_path(script_tag_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/script_tags/{script_tag_id}.json",
+                &[("script_tag_id", script_tag_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -4272,12 +5593,19 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/script_tags/{}/json",
-            crate::progenitor_support::encode_path(&script_tag_id.to_string()),
+            self.client.encode_path(script_tag_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-01/script_tags/{script_tag_id}.json",
+                    &[("script_tag_id", script_tag_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -4297,10 +5625,44 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/script_tags/{}/json",
-            crate::progenitor_support::encode_path(&script_tag_id.to_string()),
+            self.client.encode_path(script_tag_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-01/script_tags/{script_tag_id}.json",
+                &[("script_tag_id", script_tag_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a script tag.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-01/script_tags/{script_tag_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_delete_script_tags_param_tag`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202001_delete_script_tags_param_tag_if_exists(
+        &self,
+        script_tag_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-01/script_tags/{}/json",
+            self.client.encode_path(script_tag_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-01/script_tags/{script_tag_id}.json",
+                    &[("script_tag_id", script_tag_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -4333,35 +5695,36 @@ impl OnlineStore {
         src: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
+            query_args.push(("src", src.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/script_tags.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-04/script_tags.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/script_tags.json", &[])
+        })
     }
 
     /**
@@ -4377,8 +5740,11 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = "/admin/api/2020-04/script_tags.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-04/script_tags.json", &[])
+            })
     }
 
     /**
@@ -4393,14 +5759,16 @@ impl OnlineStore {
      * * `src: &str` -- Count only script tags with a given URL.
      */
     pub async fn deprecated_202004_get_script_tags_count(&self, src: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
+            query_args.push(("src", src.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/script_tags/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-04/script_tags/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/script_tags/count.json", &[])
+        })
     }
 
     /**
@@ -4420,18 +5788,25 @@ impl OnlineStore {
         script_tag_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/script_tags/{}/json?{}",
-            crate::progenitor_support::encode_path(&script_tag_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/script_tags/{}/json",
+                self.client.encode_path(script_tag_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/script_tags/{script_tag_id}.json",
+                &[("script_tag_id", script_tag_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -4452,12 +5827,19 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/script_tags/{}/json",
-            crate::progenitor_support::encode_path(&script_tag_id.to_string()),
+            self.client.encode_path(script_tag_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-04/script_tags/{script_tag_id}.json",
+                    &[("script_tag_id", script_tag_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -4477,10 +5859,44 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/script_tags/{}/json",
-            crate::progenitor_support::encode_path(&script_tag_id.to_string()),
+            self.client.encode_path(script_tag_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-04/script_tags/{script_tag_id}.json",
+                &[("script_tag_id", script_tag_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a script tag.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-04/script_tags/{script_tag_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_delete_script_tags_param_tag`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202004_delete_script_tags_param_tag_if_exists(
+        &self,
+        script_tag_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-04/script_tags/{}/json",
+            self.client.encode_path(script_tag_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-04/script_tags/{script_tag_id}.json",
+                    &[("script_tag_id", script_tag_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -4513,35 +5929,36 @@ impl OnlineStore {
         src: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
+            query_args.push(("src", src.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/script_tags.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-07/script_tags.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/script_tags.json", &[])
+        })
     }
 
     /**
@@ -4557,8 +5974,11 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = "/admin/api/2020-07/script_tags.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-07/script_tags.json", &[])
+            })
     }
 
     /**
@@ -4573,14 +5993,16 @@ impl OnlineStore {
      * * `src: &str` -- Count only script tags with a given URL.
      */
     pub async fn deprecated_202007_get_script_tags_count(&self, src: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
+            query_args.push(("src", src.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/script_tags/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-07/script_tags/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/script_tags/count.json", &[])
+        })
     }
 
     /**
@@ -4600,18 +6022,25 @@ impl OnlineStore {
         script_tag_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/script_tags/{}/json?{}",
-            crate::progenitor_support::encode_path(&script_tag_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/script_tags/{}/json",
+                self.client.encode_path(script_tag_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/script_tags/{script_tag_id}.json",
+                &[("script_tag_id", script_tag_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -4632,12 +6061,19 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/script_tags/{}/json",
-            crate::progenitor_support::encode_path(&script_tag_id.to_string()),
+            self.client.encode_path(script_tag_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-07/script_tags/{script_tag_id}.json",
+                    &[("script_tag_id", script_tag_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -4657,10 +6093,44 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/script_tags/{}/json",
-            crate::progenitor_support::encode_path(&script_tag_id.to_string()),
+            self.client.encode_path(script_tag_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-07/script_tags/{script_tag_id}.json",
+                &[("script_tag_id", script_tag_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a script tag.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-07/script_tags/{script_tag_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_delete_script_tags_param_tag`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202007_delete_script_tags_param_tag_if_exists(
+        &self,
+        script_tag_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-07/script_tags/{}/json",
+            self.client.encode_path(script_tag_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-07/script_tags/{script_tag_id}.json",
+                    &[("script_tag_id", script_tag_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -4693,35 +6163,36 @@ impl OnlineStore {
         src: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
+            query_args.push(("src", src.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/script_tags.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-10/script_tags.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/script_tags.json", &[])
+        })
     }
 
     /**
@@ -4734,8 +6205,11 @@ impl OnlineStore {
     pub async fn create_script_tags(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-10/script_tags.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-10/script_tags.json", &[])
+            })
     }
 
     /**
@@ -4750,14 +6224,16 @@ impl OnlineStore {
      * * `src: &str` -- Count only script tags with a given URL.
      */
     pub async fn get_script_tags_count(&self, src: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
+            query_args.push(("src", src.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/script_tags/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-10/script_tags/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/script_tags/count.json", &[])
+        })
     }
 
     /**
@@ -4773,18 +6249,25 @@ impl OnlineStore {
      * * `fields: &str` -- A comma-separated list of fields to include in the response.
      */
     pub async fn get_script_tags_param_tag(&self, script_tag_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/script_tags/{}/json?{}",
-            crate::progenitor_support::encode_path(&script_tag_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/script_tags/{}/json",
+                self.client.encode_path(script_tag_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/script_tags/{script_tag_id}.json",
+                &[("script_tag_id", script_tag_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -4805,12 +6288,19 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/script_tags/{}/json",
-            crate::progenitor_support::encode_path(&script_tag_id.to_string()),
+            self.client.encode_path(script_tag_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-10/script_tags/{script_tag_id}.json",
+                    &[("script_tag_id", script_tag_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -4827,10 +6317,44 @@ impl OnlineStore {
     pub async fn delete_script_tags_param_tag(&self, script_tag_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/script_tags/{}/json",
-            crate::progenitor_support::encode_path(&script_tag_id.to_string()),
+            self.client.encode_path(script_tag_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-10/script_tags/{script_tag_id}.json",
+                &[("script_tag_id", script_tag_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a script tag.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-10/script_tags/{script_tag_id}.json` endpoint.
+     *
+     * As opposed to `delete_script_tags_param_tag`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_script_tags_param_tag_if_exists(
+        &self,
+        script_tag_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-10/script_tags/{}/json",
+            self.client.encode_path(script_tag_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-10/script_tags/{script_tag_id}.json",
+                    &[("script_tag_id", script_tag_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -4863,35 +6387,36 @@ impl OnlineStore {
         src: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
+            query_args.push(("src", src.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/script_tags.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2021-01/script_tags.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/script_tags.json", &[])
+        })
     }
 
     /**
@@ -4907,8 +6432,11 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = "/admin/api/2021-01/script_tags.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2021-01/script_tags.json", &[])
+            })
     }
 
     /**
@@ -4923,14 +6451,16 @@ impl OnlineStore {
      * * `src: &str` -- Count only script tags with a given URL.
      */
     pub async fn deprecated_202101_get_script_tags_count(&self, src: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
+            query_args.push(("src", src.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/script_tags/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2021-01/script_tags/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/script_tags/count.json", &[])
+        })
     }
 
     /**
@@ -4950,18 +6480,25 @@ impl OnlineStore {
         script_tag_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/script_tags/{}/json?{}",
-            crate::progenitor_support::encode_path(&script_tag_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/script_tags/{}/json",
+                self.client.encode_path(script_tag_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/script_tags/{script_tag_id}.json",
+                &[("script_tag_id", script_tag_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -4982,12 +6519,19 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/script_tags/{}/json",
-            crate::progenitor_support::encode_path(&script_tag_id.to_string()),
+            self.client.encode_path(script_tag_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2021-01/script_tags/{script_tag_id}.json",
+                    &[("script_tag_id", script_tag_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -5007,10 +6551,44 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/script_tags/{}/json",
-            crate::progenitor_support::encode_path(&script_tag_id.to_string()),
+            self.client.encode_path(script_tag_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2021-01/script_tags/{script_tag_id}.json",
+                &[("script_tag_id", script_tag_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a script tag.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2021-01/script_tags/{script_tag_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_delete_script_tags_param_tag`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202101_delete_script_tags_param_tag_if_exists(
+        &self,
+        script_tag_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2021-01/script_tags/{}/json",
+            self.client.encode_path(script_tag_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2021-01/script_tags/{script_tag_id}.json",
+                    &[("script_tag_id", script_tag_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -5043,35 +6621,37 @@ impl OnlineStore {
         src: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
+            query_args.push(("src", src.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/script_tags.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/unstable/script_tags.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/script_tags.json", &[])
+        })
     }
 
     /**
@@ -5087,8 +6667,11 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = "/admin/api/unstable/script_tags.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/unstable/script_tags.json", &[])
+            })
     }
 
     /**
@@ -5103,14 +6686,18 @@ impl OnlineStore {
      * * `src: &str` -- Count only script tags with a given URL.
      */
     pub async fn deprecated_unstable_get_script_tags_count(&self, src: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !src.is_empty() {
-            query_args.push(("src".to_string(), src.to_string()));
+            query_args.push(("src", src.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/script_tags/count.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/unstable/script_tags/count.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/script_tags/count.json", &[])
+        })
     }
 
     /**
@@ -5130,18 +6717,25 @@ impl OnlineStore {
         script_tag_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/script_tags/{}/json?{}",
-            crate::progenitor_support::encode_path(&script_tag_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/script_tags/{}/json",
+                self.client.encode_path(script_tag_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/script_tags/{script_tag_id}.json",
+                &[("script_tag_id", script_tag_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -5162,12 +6756,19 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/script_tags/{}/json",
-            crate::progenitor_support::encode_path(&script_tag_id.to_string()),
+            self.client.encode_path(script_tag_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/unstable/script_tags/{script_tag_id}.json",
+                    &[("script_tag_id", script_tag_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -5187,34 +6788,70 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/script_tags/{}/json",
-            crate::progenitor_support::encode_path(&script_tag_id.to_string()),
+            self.client.encode_path(script_tag_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/unstable/script_tags/{script_tag_id}.json",
+                &[("script_tag_id", script_tag_id.to_string())],
+            )
+        })
     }
 
     /**
-     * Retrieves a list of themes.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/themes.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#index-2020-01
+     * Deletes a script tag.
      *
-     * **Parameters:**
+     * This function performs a `DELETE` to the `/admin/api/unstable/script_tags/{script_tag_id}.json` endpoint.
      *
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * As opposed to `deprecated_unstable_delete_script_tags_param_tag`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
      */
-    pub async fn deprecated_202001_get_theme(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/themes.json?{}", query_);
-
-        self.client.get(&url, None).await
-    }
-
+    pub async fn deprecated_unstable_delete_script_tags_param_tag_if_exists(
+        &self,
+        script_tag_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/unstable/script_tags/{}/json",
+            self.client.encode_path(script_tag_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/unstable/script_tags/{script_tag_id}.json",
+                    &[("script_tag_id", script_tag_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Retrieves a list of themes.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/themes.json` endpoint.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/online-store/theme#index-2020-01
+     *
+     * **Parameters:**
+     *
+     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     */
+    pub async fn deprecated_202001_get_theme(&self, fields: &str) -> Result<()> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !fields.is_empty() {
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query("/admin/api/2020-01/themes.json", &query_args);
+
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-01/themes.json", &[]))
+    }
+
     /**
     * Creates a theme by providing the public URL of a ZIP file that contains the theme.
               A new theme is always unpublished by default. To publish a theme when you create it, include
@@ -5228,8 +6865,11 @@ impl OnlineStore {
     pub async fn deprecated_202001_create_themes(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-01/themes.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-01/themes.json", &[])
+            })
     }
 
     /**
@@ -5249,18 +6889,25 @@ impl OnlineStore {
         theme_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/themes/{}/json?{}",
-            crate::progenitor_support::encode_path(&theme_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/themes/{}/json",
+                self.client.encode_path(theme_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/themes/{theme_id}.json",
+                &[("theme_id", theme_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -5281,12 +6928,19 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/themes/{}/json",
-            crate::progenitor_support::encode_path(&theme_id.to_string()),
+            self.client.encode_path(theme_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-01/themes/{theme_id}.json",
+                    &[("theme_id", theme_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -5303,10 +6957,44 @@ impl OnlineStore {
     pub async fn deprecated_202001_delete_themes_param_theme(&self, theme_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/themes/{}/json",
-            crate::progenitor_support::encode_path(&theme_id.to_string()),
+            self.client.encode_path(theme_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-01/themes/{theme_id}.json",
+                &[("theme_id", theme_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a theme.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-01/themes/{theme_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_delete_themes_param_theme`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202001_delete_themes_param_theme_if_exists(
+        &self,
+        theme_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-01/themes/{}/json",
+            self.client.encode_path(theme_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-01/themes/{theme_id}.json",
+                    &[("theme_id", theme_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -5321,14 +7009,16 @@ impl OnlineStore {
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
     pub async fn deprecated_202004_get_theme(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/themes.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-04/themes.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-04/themes.json", &[]))
     }
 
     /**
@@ -5344,8 +7034,11 @@ impl OnlineStore {
     pub async fn deprecated_202004_create_themes(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-04/themes.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-04/themes.json", &[])
+            })
     }
 
     /**
@@ -5365,18 +7058,25 @@ impl OnlineStore {
         theme_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/themes/{}/json?{}",
-            crate::progenitor_support::encode_path(&theme_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/themes/{}/json",
+                self.client.encode_path(theme_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/themes/{theme_id}.json",
+                &[("theme_id", theme_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -5397,12 +7097,19 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/themes/{}/json",
-            crate::progenitor_support::encode_path(&theme_id.to_string()),
+            self.client.encode_path(theme_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-04/themes/{theme_id}.json",
+                    &[("theme_id", theme_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -5419,10 +7126,44 @@ impl OnlineStore {
     pub async fn deprecated_202004_delete_themes_param_theme(&self, theme_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/themes/{}/json",
-            crate::progenitor_support::encode_path(&theme_id.to_string()),
+            self.client.encode_path(theme_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-04/themes/{theme_id}.json",
+                &[("theme_id", theme_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a theme.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-04/themes/{theme_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_delete_themes_param_theme`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202004_delete_themes_param_theme_if_exists(
+        &self,
+        theme_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-04/themes/{}/json",
+            self.client.encode_path(theme_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-04/themes/{theme_id}.json",
+                    &[("theme_id", theme_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -5437,14 +7178,16 @@ impl OnlineStore {
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
     pub async fn deprecated_202007_get_theme(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/themes.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-07/themes.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-07/themes.json", &[]))
     }
 
     /**
@@ -5460,8 +7203,11 @@ impl OnlineStore {
     pub async fn deprecated_202007_create_themes(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-07/themes.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-07/themes.json", &[])
+            })
     }
 
     /**
@@ -5481,18 +7227,25 @@ impl OnlineStore {
         theme_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/themes/{}/json?{}",
-            crate::progenitor_support::encode_path(&theme_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/themes/{}/json",
+                self.client.encode_path(theme_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/themes/{theme_id}.json",
+                &[("theme_id", theme_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -5513,12 +7266,19 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/themes/{}/json",
-            crate::progenitor_support::encode_path(&theme_id.to_string()),
+            self.client.encode_path(theme_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-07/themes/{theme_id}.json",
+                    &[("theme_id", theme_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -5535,10 +7295,44 @@ impl OnlineStore {
     pub async fn deprecated_202007_delete_themes_param_theme(&self, theme_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/themes/{}/json",
-            crate::progenitor_support::encode_path(&theme_id.to_string()),
+            self.client.encode_path(theme_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-07/themes/{theme_id}.json",
+                &[("theme_id", theme_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a theme.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-07/themes/{theme_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_delete_themes_param_theme`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202007_delete_themes_param_theme_if_exists(
+        &self,
+        theme_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-07/themes/{}/json",
+            self.client.encode_path(theme_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-07/themes/{theme_id}.json",
+                    &[("theme_id", theme_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -5553,14 +7347,16 @@ impl OnlineStore {
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
     pub async fn get_theme(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/themes.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-10/themes.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-10/themes.json", &[]))
     }
 
     /**
@@ -5576,8 +7372,11 @@ impl OnlineStore {
     pub async fn create_themes(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-10/themes.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-10/themes.json", &[])
+            })
     }
 
     /**
@@ -5593,18 +7392,25 @@ impl OnlineStore {
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
     pub async fn get_themes_param_theme(&self, theme_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/themes/{}/json?{}",
-            crate::progenitor_support::encode_path(&theme_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/themes/{}/json",
+                self.client.encode_path(theme_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/themes/{theme_id}.json",
+                &[("theme_id", theme_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -5625,12 +7431,19 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/themes/{}/json",
-            crate::progenitor_support::encode_path(&theme_id.to_string()),
+            self.client.encode_path(theme_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-10/themes/{theme_id}.json",
+                    &[("theme_id", theme_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -5647,10 +7460,41 @@ impl OnlineStore {
     pub async fn delete_themes_param_theme(&self, theme_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/themes/{}/json",
-            crate::progenitor_support::encode_path(&theme_id.to_string()),
+            self.client.encode_path(theme_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-10/themes/{theme_id}.json",
+                &[("theme_id", theme_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a theme.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-10/themes/{theme_id}.json` endpoint.
+     *
+     * As opposed to `delete_themes_param_theme`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_themes_param_theme_if_exists(&self, theme_id: &str) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-10/themes/{}/json",
+            self.client.encode_path(theme_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-10/themes/{theme_id}.json",
+                    &[("theme_id", theme_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -5665,14 +7509,16 @@ impl OnlineStore {
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
     pub async fn deprecated_202101_get_theme(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/themes.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2021-01/themes.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/admin/api/2021-01/themes.json", &[]))
     }
 
     /**
@@ -5688,8 +7534,11 @@ impl OnlineStore {
     pub async fn deprecated_202101_create_themes(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2021-01/themes.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2021-01/themes.json", &[])
+            })
     }
 
     /**
@@ -5709,18 +7558,25 @@ impl OnlineStore {
         theme_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/themes/{}/json?{}",
-            crate::progenitor_support::encode_path(&theme_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/themes/{}/json",
+                self.client.encode_path(theme_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/themes/{theme_id}.json",
+                &[("theme_id", theme_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -5741,12 +7597,19 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/themes/{}/json",
-            crate::progenitor_support::encode_path(&theme_id.to_string()),
+            self.client.encode_path(theme_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2021-01/themes/{theme_id}.json",
+                    &[("theme_id", theme_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -5763,10 +7626,44 @@ impl OnlineStore {
     pub async fn deprecated_202101_delete_themes_param_theme(&self, theme_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/themes/{}/json",
-            crate::progenitor_support::encode_path(&theme_id.to_string()),
+            self.client.encode_path(theme_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2021-01/themes/{theme_id}.json",
+                &[("theme_id", theme_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a theme.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2021-01/themes/{theme_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_delete_themes_param_theme`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202101_delete_themes_param_theme_if_exists(
+        &self,
+        theme_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2021-01/themes/{}/json",
+            self.client.encode_path(theme_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2021-01/themes/{theme_id}.json",
+                    &[("theme_id", theme_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -5781,14 +7678,15 @@ impl OnlineStore {
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
     pub async fn deprecated_unstable_get_theme(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/themes.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/unstable/themes.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/themes.json", &[])
+        })
     }
 
     /**
@@ -5804,8 +7702,11 @@ impl OnlineStore {
     pub async fn deprecated_unstable_create_themes(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/unstable/themes.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/unstable/themes.json", &[])
+            })
     }
 
     /**
@@ -5825,18 +7726,25 @@ impl OnlineStore {
         theme_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/themes/{}/json?{}",
-            crate::progenitor_support::encode_path(&theme_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/themes/{}/json",
+                self.client.encode_path(theme_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/themes/{theme_id}.json",
+                &[("theme_id", theme_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -5857,12 +7765,19 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/themes/{}/json",
-            crate::progenitor_support::encode_path(&theme_id.to_string()),
+            self.client.encode_path(theme_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/unstable/themes/{theme_id}.json",
+                    &[("theme_id", theme_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -5882,9 +7797,2891 @@ impl OnlineStore {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/themes/{}/json",
-            crate::progenitor_support::encode_path(&theme_id.to_string()),
+            self.client.encode_path(theme_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/unstable/themes/{theme_id}.json",
+                &[("theme_id", theme_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a theme.
+     *
+     * This function performs a `DELETE` to the `/admin/api/unstable/themes/{theme_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_delete_themes_param_theme`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_unstable_delete_themes_param_theme_if_exists(
+        &self,
+        theme_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/unstable/themes/{}/json",
+            self.client.encode_path(theme_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/unstable/themes/{theme_id}.json",
+                    &[("theme_id", theme_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`OnlineStore`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn OnlineStoreApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait OnlineStoreApi: Send + Sync {
+    async fn deprecated_202001_get_blogs_param_blog_article(
+        &self,
+        blog_id: &str,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        handle: &str,
+        tag: &str,
+        author: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_blogs_param_blog_articles(
+        &self,
+        blog_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_blogs_param_blog_articles_count(
+        &self,
+        blog_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_update_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_blogs_param_blog_articles_article_if_exists(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202001_get_articles_author(&self) -> Result<()>;
+    async fn deprecated_202001_get_articles_tag(&self, limit: &str, popular: &str) -> Result<()>;
+    async fn deprecated_202004_get_blogs_param_blog_article(
+        &self,
+        blog_id: &str,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        handle: &str,
+        tag: &str,
+        author: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_create_blogs_param_blog_articles(
+        &self,
+        blog_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_blogs_param_blog_articles_count(
+        &self,
+        blog_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_update_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_blogs_param_blog_articles_article_if_exists(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202004_get_articles_author(&self) -> Result<()>;
+    async fn deprecated_202004_get_articles_tag(&self, limit: &str, popular: &str) -> Result<()>;
+    async fn deprecated_202007_get_blogs_param_blog_article(
+        &self,
+        blog_id: &str,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        handle: &str,
+        tag: &str,
+        author: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_create_blogs_param_blog_articles(
+        &self,
+        blog_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_blogs_param_blog_articles_count(
+        &self,
+        blog_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_update_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_blogs_param_blog_articles_article_if_exists(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202007_get_articles_author(&self) -> Result<()>;
+    async fn deprecated_202007_get_articles_tag(&self, limit: &str, popular: &str) -> Result<()>;
+    async fn get_blogs_param_blog_article(
+        &self,
+        blog_id: &str,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        handle: &str,
+        tag: &str,
+        author: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn create_blogs_param_blog_articles(
+        &self,
+        blog_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn get_blogs_param_blog_articles_count(
+        &self,
+        blog_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()>;
+    async fn get_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn update_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn delete_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<()>;
+    async fn delete_blogs_param_blog_articles_article_if_exists(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<bool>;
+    async fn get_articles_author(&self) -> Result<()>;
+    async fn get_articles_tag(&self, limit: &str, popular: &str) -> Result<()>;
+    async fn deprecated_202101_get_blogs_param_blog_article(
+        &self,
+        blog_id: &str,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        handle: &str,
+        tag: &str,
+        author: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_create_blogs_param_blog_articles(
+        &self,
+        blog_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_blogs_param_blog_articles_count(
+        &self,
+        blog_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_update_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_blogs_param_blog_articles_article_if_exists(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202101_get_articles_author(&self) -> Result<()>;
+    async fn deprecated_202101_get_articles_tag(&self, limit: &str, popular: &str) -> Result<()>;
+    async fn deprecated_unstable_get_blogs_param_blog_article(
+        &self,
+        blog_id: &str,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        handle: &str,
+        tag: &str,
+        author: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create_blogs_param_blog_articles(
+        &self,
+        blog_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_blogs_param_blog_articles_count(
+        &self,
+        blog_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_update_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_blogs_param_blog_articles_article_if_exists(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_unstable_get_articles_author(&self) -> Result<()>;
+    async fn deprecated_unstable_get_articles_tag(&self, limit: &str, popular: &str) -> Result<()>;
+    async fn deprecated_202001_get_themes_param_theme_asset(
+        &self,
+        theme_id: &str,
+        fields: &str,
+        asset_key: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_update_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        src: &str,
+        source_key: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        asset_key: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_themes_param_theme_assets_if_exists(
+        &self,
+        theme_id: &str,
+        asset_key: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202004_get_themes_param_theme_asset(
+        &self,
+        theme_id: &str,
+        fields: &str,
+        asset_key: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_update_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        src: &str,
+        source_key: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        asset_key: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_themes_param_theme_assets_if_exists(
+        &self,
+        theme_id: &str,
+        asset_key: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202007_get_themes_param_theme_asset(
+        &self,
+        theme_id: &str,
+        fields: &str,
+        asset_key: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_update_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        src: &str,
+        source_key: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        asset_key: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_themes_param_theme_assets_if_exists(
+        &self,
+        theme_id: &str,
+        asset_key: &str,
+    ) -> Result<bool>;
+    async fn get_themes_param_theme_asset(
+        &self,
+        theme_id: &str,
+        fields: &str,
+        asset_key: &str,
+    ) -> Result<()>;
+    async fn update_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        src: &str,
+        source_key: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn delete_themes_param_theme_assets(&self, theme_id: &str, asset_key: &str)
+        -> Result<()>;
+    async fn delete_themes_param_theme_assets_if_exists(
+        &self,
+        theme_id: &str,
+        asset_key: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202101_get_themes_param_theme_asset(
+        &self,
+        theme_id: &str,
+        fields: &str,
+        asset_key: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_update_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        src: &str,
+        source_key: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        asset_key: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_themes_param_theme_assets_if_exists(
+        &self,
+        theme_id: &str,
+        asset_key: &str,
+    ) -> Result<bool>;
+    async fn deprecated_unstable_get_themes_param_theme_asset(
+        &self,
+        theme_id: &str,
+        fields: &str,
+        asset_key: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_update_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        src: &str,
+        source_key: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        asset_key: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_themes_param_theme_assets_if_exists(
+        &self,
+        theme_id: &str,
+        asset_key: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202001_get_blog(
+        &self,
+        limit: &str,
+        since_id: &str,
+        handle: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_blogs(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202001_get_blogs_count(&self) -> Result<()>;
+    async fn deprecated_202001_get_blogs_param_blog(
+        &self,
+        blog_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_update_blogs_param_blog(
+        &self,
+        blog_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_blogs_param_blog(&self, blog_id: &str) -> Result<()>;
+    async fn deprecated_202001_delete_blogs_param_blog_if_exists(
+        &self,
+        blog_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202001_get_comment(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        fields: &str,
+        published_status: &str,
+        status: &str,
+        article_id: i64,
+        blog_id: i64,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_comments(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202001_get_comments_count(
+        &self,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        status: &str,
+        article_id: i64,
+        blog_id: i64,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_comments_param_comment(
+        &self,
+        comment_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_update_comments_param_comment(
+        &self,
+        comment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_comments_param_comment_spam(
+        &self,
+        comment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_comments_param_comment_not_spam(
+        &self,
+        comment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_comments_param_comment_approve(
+        &self,
+        comment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_comments_param_comment_remove(
+        &self,
+        comment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_comments_param_comment_restore(
+        &self,
+        comment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_redirect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        path: &str,
+        target: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_redirects(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202001_get_redirects_count(&self, path: &str, target: &str) -> Result<()>;
+    async fn deprecated_202001_get_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_update_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_redirects_param_redirect_if_exists(
+        &self,
+        redirect_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202004_get_redirect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        path: &str,
+        target: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_create_redirects(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202004_get_redirects_count(&self, path: &str, target: &str) -> Result<()>;
+    async fn deprecated_202004_get_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_update_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_redirects_param_redirect_if_exists(
+        &self,
+        redirect_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202007_get_redirect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        path: &str,
+        target: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_create_redirects(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202007_get_redirects_count(&self, path: &str, target: &str) -> Result<()>;
+    async fn deprecated_202007_get_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_update_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_redirects_param_redirect_if_exists(
+        &self,
+        redirect_id: &str,
+    ) -> Result<bool>;
+    async fn get_redirect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        path: &str,
+        target: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn create_redirects(&self, body: &serde_json::Value) -> Result<()>;
+    async fn get_redirects_count(&self, path: &str, target: &str) -> Result<()>;
+    async fn get_redirects_param_redirect(&self, redirect_id: &str, fields: &str) -> Result<()>;
+    async fn update_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn delete_redirects_param_redirect(&self, redirect_id: &str) -> Result<()>;
+    async fn delete_redirects_param_redirect_if_exists(&self, redirect_id: &str) -> Result<bool>;
+    async fn deprecated_202101_get_redirect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        path: &str,
+        target: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_create_redirects(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202101_get_redirects_count(&self, path: &str, target: &str) -> Result<()>;
+    async fn deprecated_202101_get_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_update_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_redirects_param_redirect_if_exists(
+        &self,
+        redirect_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_unstable_get_redirect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        path: &str,
+        target: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create_redirects(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_unstable_get_redirects_count(&self, path: &str, target: &str)
+        -> Result<()>;
+    async fn deprecated_unstable_get_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_update_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_redirects_param_redirect_if_exists(
+        &self,
+        redirect_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202001_get_script_tag(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        src: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_script_tags(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202001_get_script_tags_count(&self, src: &str) -> Result<()>;
+    async fn deprecated_202001_get_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_update_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_script_tags_param_tag_if_exists(
+        &self,
+        script_tag_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202004_get_script_tag(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        src: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_create_script_tags(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202004_get_script_tags_count(&self, src: &str) -> Result<()>;
+    async fn deprecated_202004_get_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_update_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_script_tags_param_tag_if_exists(
+        &self,
+        script_tag_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202007_get_script_tag(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        src: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_create_script_tags(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202007_get_script_tags_count(&self, src: &str) -> Result<()>;
+    async fn deprecated_202007_get_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_update_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_script_tags_param_tag_if_exists(
+        &self,
+        script_tag_id: &str,
+    ) -> Result<bool>;
+    async fn get_script_tag(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        src: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn create_script_tags(&self, body: &serde_json::Value) -> Result<()>;
+    async fn get_script_tags_count(&self, src: &str) -> Result<()>;
+    async fn get_script_tags_param_tag(&self, script_tag_id: &str, fields: &str) -> Result<()>;
+    async fn update_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn delete_script_tags_param_tag(&self, script_tag_id: &str) -> Result<()>;
+    async fn delete_script_tags_param_tag_if_exists(&self, script_tag_id: &str) -> Result<bool>;
+    async fn deprecated_202101_get_script_tag(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        src: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_create_script_tags(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202101_get_script_tags_count(&self, src: &str) -> Result<()>;
+    async fn deprecated_202101_get_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_update_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_script_tags_param_tag_if_exists(
+        &self,
+        script_tag_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_unstable_get_script_tag(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        src: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create_script_tags(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_unstable_get_script_tags_count(&self, src: &str) -> Result<()>;
+    async fn deprecated_unstable_get_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_update_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_script_tags_param_tag_if_exists(
+        &self,
+        script_tag_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202001_get_theme(&self, fields: &str) -> Result<()>;
+    async fn deprecated_202001_create_themes(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202001_get_themes_param_theme(
+        &self,
+        theme_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_update_themes_param_theme(
+        &self,
+        theme_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_themes_param_theme(&self, theme_id: &str) -> Result<()>;
+    async fn deprecated_202001_delete_themes_param_theme_if_exists(
+        &self,
+        theme_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202004_get_theme(&self, fields: &str) -> Result<()>;
+    async fn deprecated_202004_create_themes(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202004_get_themes_param_theme(
+        &self,
+        theme_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_update_themes_param_theme(
+        &self,
+        theme_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_themes_param_theme(&self, theme_id: &str) -> Result<()>;
+    async fn deprecated_202004_delete_themes_param_theme_if_exists(
+        &self,
+        theme_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202007_get_theme(&self, fields: &str) -> Result<()>;
+    async fn deprecated_202007_create_themes(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202007_get_themes_param_theme(
+        &self,
+        theme_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_update_themes_param_theme(
+        &self,
+        theme_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_themes_param_theme(&self, theme_id: &str) -> Result<()>;
+    async fn deprecated_202007_delete_themes_param_theme_if_exists(
+        &self,
+        theme_id: &str,
+    ) -> Result<bool>;
+    async fn get_theme(&self, fields: &str) -> Result<()>;
+    async fn create_themes(&self, body: &serde_json::Value) -> Result<()>;
+    async fn get_themes_param_theme(&self, theme_id: &str, fields: &str) -> Result<()>;
+    async fn update_themes_param_theme(
+        &self,
+        theme_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn delete_themes_param_theme(&self, theme_id: &str) -> Result<()>;
+    async fn delete_themes_param_theme_if_exists(&self, theme_id: &str) -> Result<bool>;
+    async fn deprecated_202101_get_theme(&self, fields: &str) -> Result<()>;
+    async fn deprecated_202101_create_themes(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202101_get_themes_param_theme(
+        &self,
+        theme_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_update_themes_param_theme(
+        &self,
+        theme_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_themes_param_theme(&self, theme_id: &str) -> Result<()>;
+    async fn deprecated_202101_delete_themes_param_theme_if_exists(
+        &self,
+        theme_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_unstable_get_theme(&self, fields: &str) -> Result<()>;
+    async fn deprecated_unstable_create_themes(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_unstable_get_themes_param_theme(
+        &self,
+        theme_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_update_themes_param_theme(
+        &self,
+        theme_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_themes_param_theme(&self, theme_id: &str) -> Result<()>;
+    async fn deprecated_unstable_delete_themes_param_theme_if_exists(
+        &self,
+        theme_id: &str,
+    ) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl OnlineStoreApi for OnlineStore {
+    async fn deprecated_202001_get_blogs_param_blog_article(
+        &self,
+        blog_id: &str,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        handle: &str,
+        tag: &str,
+        author: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_get_blogs_param_blog_article(
+            self,
+            blog_id,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            handle,
+            tag,
+            author,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202001_create_blogs_param_blog_articles(
+        &self,
+        blog_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_create_blogs_param_blog_articles(self, blog_id, body).await
+    }
+    async fn deprecated_202001_get_blogs_param_blog_articles_count(
+        &self,
+        blog_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_get_blogs_param_blog_articles_count(
+            self,
+            blog_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        )
+        .await
+    }
+    async fn deprecated_202001_get_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_get_blogs_param_blog_articles_article(
+            self, blog_id, article_id, fields,
+        )
+        .await
+    }
+    async fn deprecated_202001_update_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_update_blogs_param_blog_articles_article(
+            self, blog_id, article_id, body,
+        )
+        .await
+    }
+    async fn deprecated_202001_delete_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_delete_blogs_param_blog_articles_article(
+            self, blog_id, article_id,
+        )
+        .await
+    }
+    async fn deprecated_202001_delete_blogs_param_blog_articles_article_if_exists(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<bool> {
+        OnlineStore::deprecated_202001_delete_blogs_param_blog_articles_article_if_exists(
+            self, blog_id, article_id,
+        )
+        .await
+    }
+    async fn deprecated_202001_get_articles_author(&self) -> Result<()> {
+        OnlineStore::deprecated_202001_get_articles_author(self).await
+    }
+    async fn deprecated_202001_get_articles_tag(&self, limit: &str, popular: &str) -> Result<()> {
+        OnlineStore::deprecated_202001_get_articles_tag(self, limit, popular).await
+    }
+    async fn deprecated_202004_get_blogs_param_blog_article(
+        &self,
+        blog_id: &str,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        handle: &str,
+        tag: &str,
+        author: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_get_blogs_param_blog_article(
+            self,
+            blog_id,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            handle,
+            tag,
+            author,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202004_create_blogs_param_blog_articles(
+        &self,
+        blog_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_create_blogs_param_blog_articles(self, blog_id, body).await
+    }
+    async fn deprecated_202004_get_blogs_param_blog_articles_count(
+        &self,
+        blog_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_get_blogs_param_blog_articles_count(
+            self,
+            blog_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_get_blogs_param_blog_articles_article(
+            self, blog_id, article_id, fields,
+        )
+        .await
+    }
+    async fn deprecated_202004_update_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_update_blogs_param_blog_articles_article(
+            self, blog_id, article_id, body,
+        )
+        .await
+    }
+    async fn deprecated_202004_delete_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_delete_blogs_param_blog_articles_article(
+            self, blog_id, article_id,
+        )
+        .await
+    }
+    async fn deprecated_202004_delete_blogs_param_blog_articles_article_if_exists(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<bool> {
+        OnlineStore::deprecated_202004_delete_blogs_param_blog_articles_article_if_exists(
+            self, blog_id, article_id,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_articles_author(&self) -> Result<()> {
+        OnlineStore::deprecated_202004_get_articles_author(self).await
+    }
+    async fn deprecated_202004_get_articles_tag(&self, limit: &str, popular: &str) -> Result<()> {
+        OnlineStore::deprecated_202004_get_articles_tag(self, limit, popular).await
+    }
+    async fn deprecated_202007_get_blogs_param_blog_article(
+        &self,
+        blog_id: &str,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        handle: &str,
+        tag: &str,
+        author: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_get_blogs_param_blog_article(
+            self,
+            blog_id,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            handle,
+            tag,
+            author,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202007_create_blogs_param_blog_articles(
+        &self,
+        blog_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_create_blogs_param_blog_articles(self, blog_id, body).await
+    }
+    async fn deprecated_202007_get_blogs_param_blog_articles_count(
+        &self,
+        blog_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_get_blogs_param_blog_articles_count(
+            self,
+            blog_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_get_blogs_param_blog_articles_article(
+            self, blog_id, article_id, fields,
+        )
+        .await
+    }
+    async fn deprecated_202007_update_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_update_blogs_param_blog_articles_article(
+            self, blog_id, article_id, body,
+        )
+        .await
+    }
+    async fn deprecated_202007_delete_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_delete_blogs_param_blog_articles_article(
+            self, blog_id, article_id,
+        )
+        .await
+    }
+    async fn deprecated_202007_delete_blogs_param_blog_articles_article_if_exists(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<bool> {
+        OnlineStore::deprecated_202007_delete_blogs_param_blog_articles_article_if_exists(
+            self, blog_id, article_id,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_articles_author(&self) -> Result<()> {
+        OnlineStore::deprecated_202007_get_articles_author(self).await
+    }
+    async fn deprecated_202007_get_articles_tag(&self, limit: &str, popular: &str) -> Result<()> {
+        OnlineStore::deprecated_202007_get_articles_tag(self, limit, popular).await
+    }
+    async fn get_blogs_param_blog_article(
+        &self,
+        blog_id: &str,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        handle: &str,
+        tag: &str,
+        author: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::get_blogs_param_blog_article(
+            self,
+            blog_id,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            handle,
+            tag,
+            author,
+            fields,
+        )
+        .await
+    }
+    async fn create_blogs_param_blog_articles(
+        &self,
+        blog_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::create_blogs_param_blog_articles(self, blog_id, body).await
+    }
+    async fn get_blogs_param_blog_articles_count(
+        &self,
+        blog_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        OnlineStore::get_blogs_param_blog_articles_count(
+            self,
+            blog_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        )
+        .await
+    }
+    async fn get_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::get_blogs_param_blog_articles_article(self, blog_id, article_id, fields).await
+    }
+    async fn update_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::update_blogs_param_blog_articles_article(self, blog_id, article_id, body).await
+    }
+    async fn delete_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<()> {
+        OnlineStore::delete_blogs_param_blog_articles_article(self, blog_id, article_id).await
+    }
+    async fn delete_blogs_param_blog_articles_article_if_exists(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<bool> {
+        OnlineStore::delete_blogs_param_blog_articles_article_if_exists(self, blog_id, article_id)
+            .await
+    }
+    async fn get_articles_author(&self) -> Result<()> {
+        OnlineStore::get_articles_author(self).await
+    }
+    async fn get_articles_tag(&self, limit: &str, popular: &str) -> Result<()> {
+        OnlineStore::get_articles_tag(self, limit, popular).await
+    }
+    async fn deprecated_202101_get_blogs_param_blog_article(
+        &self,
+        blog_id: &str,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        handle: &str,
+        tag: &str,
+        author: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_get_blogs_param_blog_article(
+            self,
+            blog_id,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            handle,
+            tag,
+            author,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202101_create_blogs_param_blog_articles(
+        &self,
+        blog_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_create_blogs_param_blog_articles(self, blog_id, body).await
+    }
+    async fn deprecated_202101_get_blogs_param_blog_articles_count(
+        &self,
+        blog_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_get_blogs_param_blog_articles_count(
+            self,
+            blog_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        )
+        .await
+    }
+    async fn deprecated_202101_get_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_get_blogs_param_blog_articles_article(
+            self, blog_id, article_id, fields,
+        )
+        .await
+    }
+    async fn deprecated_202101_update_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_update_blogs_param_blog_articles_article(
+            self, blog_id, article_id, body,
+        )
+        .await
+    }
+    async fn deprecated_202101_delete_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_delete_blogs_param_blog_articles_article(
+            self, blog_id, article_id,
+        )
+        .await
+    }
+    async fn deprecated_202101_delete_blogs_param_blog_articles_article_if_exists(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<bool> {
+        OnlineStore::deprecated_202101_delete_blogs_param_blog_articles_article_if_exists(
+            self, blog_id, article_id,
+        )
+        .await
+    }
+    async fn deprecated_202101_get_articles_author(&self) -> Result<()> {
+        OnlineStore::deprecated_202101_get_articles_author(self).await
+    }
+    async fn deprecated_202101_get_articles_tag(&self, limit: &str, popular: &str) -> Result<()> {
+        OnlineStore::deprecated_202101_get_articles_tag(self, limit, popular).await
+    }
+    async fn deprecated_unstable_get_blogs_param_blog_article(
+        &self,
+        blog_id: &str,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        handle: &str,
+        tag: &str,
+        author: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_get_blogs_param_blog_article(
+            self,
+            blog_id,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            handle,
+            tag,
+            author,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_unstable_create_blogs_param_blog_articles(
+        &self,
+        blog_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_create_blogs_param_blog_articles(self, blog_id, body).await
+    }
+    async fn deprecated_unstable_get_blogs_param_blog_articles_count(
+        &self,
+        blog_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_get_blogs_param_blog_articles_count(
+            self,
+            blog_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_get_blogs_param_blog_articles_article(
+            self, blog_id, article_id, fields,
+        )
+        .await
+    }
+    async fn deprecated_unstable_update_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_update_blogs_param_blog_articles_article(
+            self, blog_id, article_id, body,
+        )
+        .await
+    }
+    async fn deprecated_unstable_delete_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_delete_blogs_param_blog_articles_article(
+            self, blog_id, article_id,
+        )
+        .await
+    }
+    async fn deprecated_unstable_delete_blogs_param_blog_articles_article_if_exists(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<bool> {
+        OnlineStore::deprecated_unstable_delete_blogs_param_blog_articles_article_if_exists(
+            self, blog_id, article_id,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_articles_author(&self) -> Result<()> {
+        OnlineStore::deprecated_unstable_get_articles_author(self).await
+    }
+    async fn deprecated_unstable_get_articles_tag(&self, limit: &str, popular: &str) -> Result<()> {
+        OnlineStore::deprecated_unstable_get_articles_tag(self, limit, popular).await
+    }
+    async fn deprecated_202001_get_themes_param_theme_asset(
+        &self,
+        theme_id: &str,
+        fields: &str,
+        asset_key: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_get_themes_param_theme_asset(
+            self, theme_id, fields, asset_key,
+        )
+        .await
+    }
+    async fn deprecated_202001_update_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        src: &str,
+        source_key: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_update_themes_param_theme_assets(
+            self, theme_id, src, source_key, body,
+        )
+        .await
+    }
+    async fn deprecated_202001_delete_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        asset_key: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_delete_themes_param_theme_assets(self, theme_id, asset_key)
+            .await
+    }
+    async fn deprecated_202001_delete_themes_param_theme_assets_if_exists(
+        &self,
+        theme_id: &str,
+        asset_key: &str,
+    ) -> Result<bool> {
+        OnlineStore::deprecated_202001_delete_themes_param_theme_assets_if_exists(
+            self, theme_id, asset_key,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_themes_param_theme_asset(
+        &self,
+        theme_id: &str,
+        fields: &str,
+        asset_key: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_get_themes_param_theme_asset(
+            self, theme_id, fields, asset_key,
+        )
+        .await
+    }
+    async fn deprecated_202004_update_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        src: &str,
+        source_key: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_update_themes_param_theme_assets(
+            self, theme_id, src, source_key, body,
+        )
+        .await
+    }
+    async fn deprecated_202004_delete_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        asset_key: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_delete_themes_param_theme_assets(self, theme_id, asset_key)
+            .await
+    }
+    async fn deprecated_202004_delete_themes_param_theme_assets_if_exists(
+        &self,
+        theme_id: &str,
+        asset_key: &str,
+    ) -> Result<bool> {
+        OnlineStore::deprecated_202004_delete_themes_param_theme_assets_if_exists(
+            self, theme_id, asset_key,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_themes_param_theme_asset(
+        &self,
+        theme_id: &str,
+        fields: &str,
+        asset_key: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_get_themes_param_theme_asset(
+            self, theme_id, fields, asset_key,
+        )
+        .await
+    }
+    async fn deprecated_202007_update_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        src: &str,
+        source_key: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_update_themes_param_theme_assets(
+            self, theme_id, src, source_key, body,
+        )
+        .await
+    }
+    async fn deprecated_202007_delete_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        asset_key: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_delete_themes_param_theme_assets(self, theme_id, asset_key)
+            .await
+    }
+    async fn deprecated_202007_delete_themes_param_theme_assets_if_exists(
+        &self,
+        theme_id: &str,
+        asset_key: &str,
+    ) -> Result<bool> {
+        OnlineStore::deprecated_202007_delete_themes_param_theme_assets_if_exists(
+            self, theme_id, asset_key,
+        )
+        .await
+    }
+    async fn get_themes_param_theme_asset(
+        &self,
+        theme_id: &str,
+        fields: &str,
+        asset_key: &str,
+    ) -> Result<()> {
+        OnlineStore::get_themes_param_theme_asset(self, theme_id, fields, asset_key).await
+    }
+    async fn update_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        src: &str,
+        source_key: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::update_themes_param_theme_assets(self, theme_id, src, source_key, body).await
+    }
+    async fn delete_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        asset_key: &str,
+    ) -> Result<()> {
+        OnlineStore::delete_themes_param_theme_assets(self, theme_id, asset_key).await
+    }
+    async fn delete_themes_param_theme_assets_if_exists(
+        &self,
+        theme_id: &str,
+        asset_key: &str,
+    ) -> Result<bool> {
+        OnlineStore::delete_themes_param_theme_assets_if_exists(self, theme_id, asset_key).await
+    }
+    async fn deprecated_202101_get_themes_param_theme_asset(
+        &self,
+        theme_id: &str,
+        fields: &str,
+        asset_key: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_get_themes_param_theme_asset(
+            self, theme_id, fields, asset_key,
+        )
+        .await
+    }
+    async fn deprecated_202101_update_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        src: &str,
+        source_key: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_update_themes_param_theme_assets(
+            self, theme_id, src, source_key, body,
+        )
+        .await
+    }
+    async fn deprecated_202101_delete_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        asset_key: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_delete_themes_param_theme_assets(self, theme_id, asset_key)
+            .await
+    }
+    async fn deprecated_202101_delete_themes_param_theme_assets_if_exists(
+        &self,
+        theme_id: &str,
+        asset_key: &str,
+    ) -> Result<bool> {
+        OnlineStore::deprecated_202101_delete_themes_param_theme_assets_if_exists(
+            self, theme_id, asset_key,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_themes_param_theme_asset(
+        &self,
+        theme_id: &str,
+        fields: &str,
+        asset_key: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_get_themes_param_theme_asset(
+            self, theme_id, fields, asset_key,
+        )
+        .await
+    }
+    async fn deprecated_unstable_update_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        src: &str,
+        source_key: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_update_themes_param_theme_assets(
+            self, theme_id, src, source_key, body,
+        )
+        .await
+    }
+    async fn deprecated_unstable_delete_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        asset_key: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_delete_themes_param_theme_assets(self, theme_id, asset_key)
+            .await
+    }
+    async fn deprecated_unstable_delete_themes_param_theme_assets_if_exists(
+        &self,
+        theme_id: &str,
+        asset_key: &str,
+    ) -> Result<bool> {
+        OnlineStore::deprecated_unstable_delete_themes_param_theme_assets_if_exists(
+            self, theme_id, asset_key,
+        )
+        .await
+    }
+    async fn deprecated_202001_get_blog(
+        &self,
+        limit: &str,
+        since_id: &str,
+        handle: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_get_blog(self, limit, since_id, handle, fields).await
+    }
+    async fn deprecated_202001_create_blogs(&self, body: &serde_json::Value) -> Result<()> {
+        OnlineStore::deprecated_202001_create_blogs(self, body).await
+    }
+    async fn deprecated_202001_get_blogs_count(&self) -> Result<()> {
+        OnlineStore::deprecated_202001_get_blogs_count(self).await
+    }
+    async fn deprecated_202001_get_blogs_param_blog(
+        &self,
+        blog_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_get_blogs_param_blog(self, blog_id, fields).await
+    }
+    async fn deprecated_202001_update_blogs_param_blog(
+        &self,
+        blog_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_update_blogs_param_blog(self, blog_id, body).await
+    }
+    async fn deprecated_202001_delete_blogs_param_blog(&self, blog_id: &str) -> Result<()> {
+        OnlineStore::deprecated_202001_delete_blogs_param_blog(self, blog_id).await
+    }
+    async fn deprecated_202001_delete_blogs_param_blog_if_exists(
+        &self,
+        blog_id: &str,
+    ) -> Result<bool> {
+        OnlineStore::deprecated_202001_delete_blogs_param_blog_if_exists(self, blog_id).await
+    }
+    async fn deprecated_202001_get_comment(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        fields: &str,
+        published_status: &str,
+        status: &str,
+        article_id: i64,
+        blog_id: i64,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_get_comment(
+            self,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            fields,
+            published_status,
+            status,
+            article_id,
+            blog_id,
+        )
+        .await
+    }
+    async fn deprecated_202001_create_comments(&self, body: &serde_json::Value) -> Result<()> {
+        OnlineStore::deprecated_202001_create_comments(self, body).await
+    }
+    async fn deprecated_202001_get_comments_count(
+        &self,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        status: &str,
+        article_id: i64,
+        blog_id: i64,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_get_comments_count(
+            self,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            status,
+            article_id,
+            blog_id,
+        )
+        .await
+    }
+    async fn deprecated_202001_get_comments_param_comment(
+        &self,
+        comment_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_get_comments_param_comment(self, comment_id, fields).await
+    }
+    async fn deprecated_202001_update_comments_param_comment(
+        &self,
+        comment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_update_comments_param_comment(self, comment_id, body).await
+    }
+    async fn deprecated_202001_create_comments_param_comment_spam(
+        &self,
+        comment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_create_comments_param_comment_spam(self, comment_id, body)
+            .await
+    }
+    async fn deprecated_202001_create_comments_param_comment_not_spam(
+        &self,
+        comment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_create_comments_param_comment_not_spam(
+            self, comment_id, body,
+        )
+        .await
+    }
+    async fn deprecated_202001_create_comments_param_comment_approve(
+        &self,
+        comment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_create_comments_param_comment_approve(self, comment_id, body)
+            .await
+    }
+    async fn deprecated_202001_create_comments_param_comment_remove(
+        &self,
+        comment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_create_comments_param_comment_remove(self, comment_id, body)
+            .await
+    }
+    async fn deprecated_202001_create_comments_param_comment_restore(
+        &self,
+        comment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_create_comments_param_comment_restore(self, comment_id, body)
+            .await
+    }
+    async fn deprecated_202001_get_redirect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        path: &str,
+        target: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_get_redirect(self, limit, since_id, path, target, fields)
+            .await
+    }
+    async fn deprecated_202001_create_redirects(&self, body: &serde_json::Value) -> Result<()> {
+        OnlineStore::deprecated_202001_create_redirects(self, body).await
+    }
+    async fn deprecated_202001_get_redirects_count(&self, path: &str, target: &str) -> Result<()> {
+        OnlineStore::deprecated_202001_get_redirects_count(self, path, target).await
+    }
+    async fn deprecated_202001_get_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_get_redirects_param_redirect(self, redirect_id, fields).await
+    }
+    async fn deprecated_202001_update_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_update_redirects_param_redirect(self, redirect_id, body)
+            .await
+    }
+    async fn deprecated_202001_delete_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_delete_redirects_param_redirect(self, redirect_id).await
+    }
+    async fn deprecated_202001_delete_redirects_param_redirect_if_exists(
+        &self,
+        redirect_id: &str,
+    ) -> Result<bool> {
+        OnlineStore::deprecated_202001_delete_redirects_param_redirect_if_exists(self, redirect_id)
+            .await
+    }
+    async fn deprecated_202004_get_redirect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        path: &str,
+        target: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_get_redirect(self, limit, since_id, path, target, fields)
+            .await
+    }
+    async fn deprecated_202004_create_redirects(&self, body: &serde_json::Value) -> Result<()> {
+        OnlineStore::deprecated_202004_create_redirects(self, body).await
+    }
+    async fn deprecated_202004_get_redirects_count(&self, path: &str, target: &str) -> Result<()> {
+        OnlineStore::deprecated_202004_get_redirects_count(self, path, target).await
+    }
+    async fn deprecated_202004_get_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_get_redirects_param_redirect(self, redirect_id, fields).await
+    }
+    async fn deprecated_202004_update_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_update_redirects_param_redirect(self, redirect_id, body)
+            .await
+    }
+    async fn deprecated_202004_delete_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_delete_redirects_param_redirect(self, redirect_id).await
+    }
+    async fn deprecated_202004_delete_redirects_param_redirect_if_exists(
+        &self,
+        redirect_id: &str,
+    ) -> Result<bool> {
+        OnlineStore::deprecated_202004_delete_redirects_param_redirect_if_exists(self, redirect_id)
+            .await
+    }
+    async fn deprecated_202007_get_redirect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        path: &str,
+        target: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_get_redirect(self, limit, since_id, path, target, fields)
+            .await
+    }
+    async fn deprecated_202007_create_redirects(&self, body: &serde_json::Value) -> Result<()> {
+        OnlineStore::deprecated_202007_create_redirects(self, body).await
+    }
+    async fn deprecated_202007_get_redirects_count(&self, path: &str, target: &str) -> Result<()> {
+        OnlineStore::deprecated_202007_get_redirects_count(self, path, target).await
+    }
+    async fn deprecated_202007_get_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_get_redirects_param_redirect(self, redirect_id, fields).await
+    }
+    async fn deprecated_202007_update_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_update_redirects_param_redirect(self, redirect_id, body)
+            .await
+    }
+    async fn deprecated_202007_delete_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_delete_redirects_param_redirect(self, redirect_id).await
+    }
+    async fn deprecated_202007_delete_redirects_param_redirect_if_exists(
+        &self,
+        redirect_id: &str,
+    ) -> Result<bool> {
+        OnlineStore::deprecated_202007_delete_redirects_param_redirect_if_exists(self, redirect_id)
+            .await
+    }
+    async fn get_redirect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        path: &str,
+        target: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::get_redirect(self, limit, since_id, path, target, fields).await
+    }
+    async fn create_redirects(&self, body: &serde_json::Value) -> Result<()> {
+        OnlineStore::create_redirects(self, body).await
+    }
+    async fn get_redirects_count(&self, path: &str, target: &str) -> Result<()> {
+        OnlineStore::get_redirects_count(self, path, target).await
+    }
+    async fn get_redirects_param_redirect(&self, redirect_id: &str, fields: &str) -> Result<()> {
+        OnlineStore::get_redirects_param_redirect(self, redirect_id, fields).await
+    }
+    async fn update_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::update_redirects_param_redirect(self, redirect_id, body).await
+    }
+    async fn delete_redirects_param_redirect(&self, redirect_id: &str) -> Result<()> {
+        OnlineStore::delete_redirects_param_redirect(self, redirect_id).await
+    }
+    async fn delete_redirects_param_redirect_if_exists(&self, redirect_id: &str) -> Result<bool> {
+        OnlineStore::delete_redirects_param_redirect_if_exists(self, redirect_id).await
+    }
+    async fn deprecated_202101_get_redirect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        path: &str,
+        target: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_get_redirect(self, limit, since_id, path, target, fields)
+            .await
+    }
+    async fn deprecated_202101_create_redirects(&self, body: &serde_json::Value) -> Result<()> {
+        OnlineStore::deprecated_202101_create_redirects(self, body).await
+    }
+    async fn deprecated_202101_get_redirects_count(&self, path: &str, target: &str) -> Result<()> {
+        OnlineStore::deprecated_202101_get_redirects_count(self, path, target).await
+    }
+    async fn deprecated_202101_get_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_get_redirects_param_redirect(self, redirect_id, fields).await
+    }
+    async fn deprecated_202101_update_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_update_redirects_param_redirect(self, redirect_id, body)
+            .await
+    }
+    async fn deprecated_202101_delete_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_delete_redirects_param_redirect(self, redirect_id).await
+    }
+    async fn deprecated_202101_delete_redirects_param_redirect_if_exists(
+        &self,
+        redirect_id: &str,
+    ) -> Result<bool> {
+        OnlineStore::deprecated_202101_delete_redirects_param_redirect_if_exists(self, redirect_id)
+            .await
+    }
+    async fn deprecated_unstable_get_redirect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        path: &str,
+        target: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_get_redirect(self, limit, since_id, path, target, fields)
+            .await
+    }
+    async fn deprecated_unstable_create_redirects(&self, body: &serde_json::Value) -> Result<()> {
+        OnlineStore::deprecated_unstable_create_redirects(self, body).await
+    }
+    async fn deprecated_unstable_get_redirects_count(
+        &self,
+        path: &str,
+        target: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_get_redirects_count(self, path, target).await
+    }
+    async fn deprecated_unstable_get_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_get_redirects_param_redirect(self, redirect_id, fields)
+            .await
+    }
+    async fn deprecated_unstable_update_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_update_redirects_param_redirect(self, redirect_id, body)
+            .await
+    }
+    async fn deprecated_unstable_delete_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_delete_redirects_param_redirect(self, redirect_id).await
+    }
+    async fn deprecated_unstable_delete_redirects_param_redirect_if_exists(
+        &self,
+        redirect_id: &str,
+    ) -> Result<bool> {
+        OnlineStore::deprecated_unstable_delete_redirects_param_redirect_if_exists(
+            self,
+            redirect_id,
+        )
+        .await
+    }
+    async fn deprecated_202001_get_script_tag(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        src: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_get_script_tag(
+            self,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            src,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202001_create_script_tags(&self, body: &serde_json::Value) -> Result<()> {
+        OnlineStore::deprecated_202001_create_script_tags(self, body).await
+    }
+    async fn deprecated_202001_get_script_tags_count(&self, src: &str) -> Result<()> {
+        OnlineStore::deprecated_202001_get_script_tags_count(self, src).await
+    }
+    async fn deprecated_202001_get_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_get_script_tags_param_tag(self, script_tag_id, fields).await
+    }
+    async fn deprecated_202001_update_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_update_script_tags_param_tag(self, script_tag_id, body).await
+    }
+    async fn deprecated_202001_delete_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_delete_script_tags_param_tag(self, script_tag_id).await
+    }
+    async fn deprecated_202001_delete_script_tags_param_tag_if_exists(
+        &self,
+        script_tag_id: &str,
+    ) -> Result<bool> {
+        OnlineStore::deprecated_202001_delete_script_tags_param_tag_if_exists(self, script_tag_id)
+            .await
+    }
+    async fn deprecated_202004_get_script_tag(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        src: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_get_script_tag(
+            self,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            src,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202004_create_script_tags(&self, body: &serde_json::Value) -> Result<()> {
+        OnlineStore::deprecated_202004_create_script_tags(self, body).await
+    }
+    async fn deprecated_202004_get_script_tags_count(&self, src: &str) -> Result<()> {
+        OnlineStore::deprecated_202004_get_script_tags_count(self, src).await
+    }
+    async fn deprecated_202004_get_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_get_script_tags_param_tag(self, script_tag_id, fields).await
+    }
+    async fn deprecated_202004_update_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_update_script_tags_param_tag(self, script_tag_id, body).await
+    }
+    async fn deprecated_202004_delete_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_delete_script_tags_param_tag(self, script_tag_id).await
+    }
+    async fn deprecated_202004_delete_script_tags_param_tag_if_exists(
+        &self,
+        script_tag_id: &str,
+    ) -> Result<bool> {
+        OnlineStore::deprecated_202004_delete_script_tags_param_tag_if_exists(self, script_tag_id)
+            .await
+    }
+    async fn deprecated_202007_get_script_tag(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        src: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_get_script_tag(
+            self,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            src,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202007_create_script_tags(&self, body: &serde_json::Value) -> Result<()> {
+        OnlineStore::deprecated_202007_create_script_tags(self, body).await
+    }
+    async fn deprecated_202007_get_script_tags_count(&self, src: &str) -> Result<()> {
+        OnlineStore::deprecated_202007_get_script_tags_count(self, src).await
+    }
+    async fn deprecated_202007_get_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_get_script_tags_param_tag(self, script_tag_id, fields).await
+    }
+    async fn deprecated_202007_update_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_update_script_tags_param_tag(self, script_tag_id, body).await
+    }
+    async fn deprecated_202007_delete_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_delete_script_tags_param_tag(self, script_tag_id).await
+    }
+    async fn deprecated_202007_delete_script_tags_param_tag_if_exists(
+        &self,
+        script_tag_id: &str,
+    ) -> Result<bool> {
+        OnlineStore::deprecated_202007_delete_script_tags_param_tag_if_exists(self, script_tag_id)
+            .await
+    }
+    async fn get_script_tag(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        src: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::get_script_tag(
+            self,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            src,
+            fields,
+        )
+        .await
+    }
+    async fn create_script_tags(&self, body: &serde_json::Value) -> Result<()> {
+        OnlineStore::create_script_tags(self, body).await
+    }
+    async fn get_script_tags_count(&self, src: &str) -> Result<()> {
+        OnlineStore::get_script_tags_count(self, src).await
+    }
+    async fn get_script_tags_param_tag(&self, script_tag_id: &str, fields: &str) -> Result<()> {
+        OnlineStore::get_script_tags_param_tag(self, script_tag_id, fields).await
+    }
+    async fn update_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::update_script_tags_param_tag(self, script_tag_id, body).await
+    }
+    async fn delete_script_tags_param_tag(&self, script_tag_id: &str) -> Result<()> {
+        OnlineStore::delete_script_tags_param_tag(self, script_tag_id).await
+    }
+    async fn delete_script_tags_param_tag_if_exists(&self, script_tag_id: &str) -> Result<bool> {
+        OnlineStore::delete_script_tags_param_tag_if_exists(self, script_tag_id).await
+    }
+    async fn deprecated_202101_get_script_tag(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        src: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_get_script_tag(
+            self,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            src,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202101_create_script_tags(&self, body: &serde_json::Value) -> Result<()> {
+        OnlineStore::deprecated_202101_create_script_tags(self, body).await
+    }
+    async fn deprecated_202101_get_script_tags_count(&self, src: &str) -> Result<()> {
+        OnlineStore::deprecated_202101_get_script_tags_count(self, src).await
+    }
+    async fn deprecated_202101_get_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_get_script_tags_param_tag(self, script_tag_id, fields).await
+    }
+    async fn deprecated_202101_update_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_update_script_tags_param_tag(self, script_tag_id, body).await
+    }
+    async fn deprecated_202101_delete_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_delete_script_tags_param_tag(self, script_tag_id).await
+    }
+    async fn deprecated_202101_delete_script_tags_param_tag_if_exists(
+        &self,
+        script_tag_id: &str,
+    ) -> Result<bool> {
+        OnlineStore::deprecated_202101_delete_script_tags_param_tag_if_exists(self, script_tag_id)
+            .await
+    }
+    async fn deprecated_unstable_get_script_tag(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        src: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_get_script_tag(
+            self,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            src,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_unstable_create_script_tags(&self, body: &serde_json::Value) -> Result<()> {
+        OnlineStore::deprecated_unstable_create_script_tags(self, body).await
+    }
+    async fn deprecated_unstable_get_script_tags_count(&self, src: &str) -> Result<()> {
+        OnlineStore::deprecated_unstable_get_script_tags_count(self, src).await
+    }
+    async fn deprecated_unstable_get_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_get_script_tags_param_tag(self, script_tag_id, fields)
+            .await
+    }
+    async fn deprecated_unstable_update_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_update_script_tags_param_tag(self, script_tag_id, body)
+            .await
+    }
+    async fn deprecated_unstable_delete_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_delete_script_tags_param_tag(self, script_tag_id).await
+    }
+    async fn deprecated_unstable_delete_script_tags_param_tag_if_exists(
+        &self,
+        script_tag_id: &str,
+    ) -> Result<bool> {
+        OnlineStore::deprecated_unstable_delete_script_tags_param_tag_if_exists(self, script_tag_id)
+            .await
+    }
+    async fn deprecated_202001_get_theme(&self, fields: &str) -> Result<()> {
+        OnlineStore::deprecated_202001_get_theme(self, fields).await
+    }
+    async fn deprecated_202001_create_themes(&self, body: &serde_json::Value) -> Result<()> {
+        OnlineStore::deprecated_202001_create_themes(self, body).await
+    }
+    async fn deprecated_202001_get_themes_param_theme(
+        &self,
+        theme_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_get_themes_param_theme(self, theme_id, fields).await
+    }
+    async fn deprecated_202001_update_themes_param_theme(
+        &self,
+        theme_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_update_themes_param_theme(self, theme_id, body).await
+    }
+    async fn deprecated_202001_delete_themes_param_theme(&self, theme_id: &str) -> Result<()> {
+        OnlineStore::deprecated_202001_delete_themes_param_theme(self, theme_id).await
+    }
+    async fn deprecated_202001_delete_themes_param_theme_if_exists(
+        &self,
+        theme_id: &str,
+    ) -> Result<bool> {
+        OnlineStore::deprecated_202001_delete_themes_param_theme_if_exists(self, theme_id).await
+    }
+    async fn deprecated_202004_get_theme(&self, fields: &str) -> Result<()> {
+        OnlineStore::deprecated_202004_get_theme(self, fields).await
+    }
+    async fn deprecated_202004_create_themes(&self, body: &serde_json::Value) -> Result<()> {
+        OnlineStore::deprecated_202004_create_themes(self, body).await
+    }
+    async fn deprecated_202004_get_themes_param_theme(
+        &self,
+        theme_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_get_themes_param_theme(self, theme_id, fields).await
+    }
+    async fn deprecated_202004_update_themes_param_theme(
+        &self,
+        theme_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_update_themes_param_theme(self, theme_id, body).await
+    }
+    async fn deprecated_202004_delete_themes_param_theme(&self, theme_id: &str) -> Result<()> {
+        OnlineStore::deprecated_202004_delete_themes_param_theme(self, theme_id).await
+    }
+    async fn deprecated_202004_delete_themes_param_theme_if_exists(
+        &self,
+        theme_id: &str,
+    ) -> Result<bool> {
+        OnlineStore::deprecated_202004_delete_themes_param_theme_if_exists(self, theme_id).await
+    }
+    async fn deprecated_202007_get_theme(&self, fields: &str) -> Result<()> {
+        OnlineStore::deprecated_202007_get_theme(self, fields).await
+    }
+    async fn deprecated_202007_create_themes(&self, body: &serde_json::Value) -> Result<()> {
+        OnlineStore::deprecated_202007_create_themes(self, body).await
+    }
+    async fn deprecated_202007_get_themes_param_theme(
+        &self,
+        theme_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_get_themes_param_theme(self, theme_id, fields).await
+    }
+    async fn deprecated_202007_update_themes_param_theme(
+        &self,
+        theme_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_update_themes_param_theme(self, theme_id, body).await
+    }
+    async fn deprecated_202007_delete_themes_param_theme(&self, theme_id: &str) -> Result<()> {
+        OnlineStore::deprecated_202007_delete_themes_param_theme(self, theme_id).await
+    }
+    async fn deprecated_202007_delete_themes_param_theme_if_exists(
+        &self,
+        theme_id: &str,
+    ) -> Result<bool> {
+        OnlineStore::deprecated_202007_delete_themes_param_theme_if_exists(self, theme_id).await
+    }
+    async fn get_theme(&self, fields: &str) -> Result<()> {
+        OnlineStore::get_theme(self, fields).await
+    }
+    async fn create_themes(&self, body: &serde_json::Value) -> Result<()> {
+        OnlineStore::create_themes(self, body).await
+    }
+    async fn get_themes_param_theme(&self, theme_id: &str, fields: &str) -> Result<()> {
+        OnlineStore::get_themes_param_theme(self, theme_id, fields).await
+    }
+    async fn update_themes_param_theme(
+        &self,
+        theme_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::update_themes_param_theme(self, theme_id, body).await
+    }
+    async fn delete_themes_param_theme(&self, theme_id: &str) -> Result<()> {
+        OnlineStore::delete_themes_param_theme(self, theme_id).await
+    }
+    async fn delete_themes_param_theme_if_exists(&self, theme_id: &str) -> Result<bool> {
+        OnlineStore::delete_themes_param_theme_if_exists(self, theme_id).await
+    }
+    async fn deprecated_202101_get_theme(&self, fields: &str) -> Result<()> {
+        OnlineStore::deprecated_202101_get_theme(self, fields).await
+    }
+    async fn deprecated_202101_create_themes(&self, body: &serde_json::Value) -> Result<()> {
+        OnlineStore::deprecated_202101_create_themes(self, body).await
+    }
+    async fn deprecated_202101_get_themes_param_theme(
+        &self,
+        theme_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_get_themes_param_theme(self, theme_id, fields).await
+    }
+    async fn deprecated_202101_update_themes_param_theme(
+        &self,
+        theme_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_update_themes_param_theme(self, theme_id, body).await
+    }
+    async fn deprecated_202101_delete_themes_param_theme(&self, theme_id: &str) -> Result<()> {
+        OnlineStore::deprecated_202101_delete_themes_param_theme(self, theme_id).await
+    }
+    async fn deprecated_202101_delete_themes_param_theme_if_exists(
+        &self,
+        theme_id: &str,
+    ) -> Result<bool> {
+        OnlineStore::deprecated_202101_delete_themes_param_theme_if_exists(self, theme_id).await
+    }
+    async fn deprecated_unstable_get_theme(&self, fields: &str) -> Result<()> {
+        OnlineStore::deprecated_unstable_get_theme(self, fields).await
+    }
+    async fn deprecated_unstable_create_themes(&self, body: &serde_json::Value) -> Result<()> {
+        OnlineStore::deprecated_unstable_create_themes(self, body).await
+    }
+    async fn deprecated_unstable_get_themes_param_theme(
+        &self,
+        theme_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_get_themes_param_theme(self, theme_id, fields).await
+    }
+    async fn deprecated_unstable_update_themes_param_theme(
+        &self,
+        theme_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_update_themes_param_theme(self, theme_id, body).await
+    }
+    async fn deprecated_unstable_delete_themes_param_theme(&self, theme_id: &str) -> Result<()> {
+        OnlineStore::deprecated_unstable_delete_themes_param_theme(self, theme_id).await
+    }
+    async fn deprecated_unstable_delete_themes_param_theme_if_exists(
+        &self,
+        theme_id: &str,
+    ) -> Result<bool> {
+        OnlineStore::deprecated_unstable_delete_themes_param_theme_if_exists(self, theme_id).await
     }
 }
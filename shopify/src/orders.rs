@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Orders {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Orders {
         Orders { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Orders {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieves a count of checkouts from the past 90 days.
      *
@@ -41,29 +47,31 @@ impl Orders {
         updated_at_max: &str,
         status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/checkouts/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-01/checkouts/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/checkouts/count.json", &[])
+        })
     }
 
     /**
@@ -95,29 +103,31 @@ impl Orders {
         updated_at_max: &str,
         status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/checkouts/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-04/checkouts/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/checkouts/count.json", &[])
+        })
     }
 
     /**
@@ -149,29 +159,31 @@ impl Orders {
         updated_at_max: &str,
         status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/checkouts/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-07/checkouts/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/checkouts/count.json", &[])
+        })
     }
 
     /**
@@ -203,29 +215,31 @@ impl Orders {
         updated_at_max: &str,
         status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/checkouts/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-10/checkouts/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/checkouts/count.json", &[])
+        })
     }
 
     /**
@@ -257,29 +271,31 @@ impl Orders {
         updated_at_max: &str,
         status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/checkouts/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2021-01/checkouts/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/checkouts/count.json", &[])
+        })
     }
 
     /**
@@ -311,29 +327,31 @@ impl Orders {
         updated_at_max: &str,
         status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/checkouts/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/unstable/checkouts/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/checkouts/count.json", &[])
+        })
     }
 
     /**
@@ -402,59 +420,55 @@ impl Orders {
         fulfillment_status: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !attribution_app_id.is_empty() {
-            query_args.push((
-                "attribution_app_id".to_string(),
-                attribution_app_id.to_string(),
-            ));
+            query_args.push(("attribution_app_id", attribution_app_id.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !financial_status.is_empty() {
-            query_args.push(("financial_status".to_string(), financial_status.to_string()));
+            query_args.push(("financial_status", financial_status.to_string()));
         }
         if !fulfillment_status.is_empty() {
-            query_args.push((
-                "fulfillment_status".to_string(),
-                fulfillment_status.to_string(),
-            ));
+            query_args.push(("fulfillment_status", fulfillment_status.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !processed_at_max.is_empty() {
-            query_args.push(("processed_at_max".to_string(), processed_at_max.to_string()));
+            query_args.push(("processed_at_max", processed_at_max.to_string()));
         }
         if !processed_at_min.is_empty() {
-            query_args.push(("processed_at_min".to_string(), processed_at_min.to_string()));
+            query_args.push(("processed_at_min", processed_at_min.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/orders.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-01/orders.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-01/orders.json", &[]))
     }
 
     /**
@@ -490,8 +504,11 @@ impl Orders {
     pub async fn deprecated_202001_create(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-01/orders.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-01/orders.json", &[])
+            })
     }
 
     /**
@@ -507,18 +524,25 @@ impl Orders {
      * * `fields: &str` -- Retrieve only certain fields, specified by a comma-separated list of fields names.
      */
     pub async fn deprecated_202001_get_param(&self, order_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/orders/{}/json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/orders/{}/json",
+                self.client.encode_path(order_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/orders/{order_id}.json",
+                &[("order_id", order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -539,12 +563,19 @@ impl Orders {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/orders/{}/json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            self.client.encode_path(order_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-01/orders/{order_id}.json",
+                    &[("order_id", order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -561,10 +592,41 @@ impl Orders {
     pub async fn deprecated_202001_delete_param(&self, order_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/orders/{}/json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            self.client.encode_path(order_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-01/orders/{order_id}.json",
+                &[("order_id", order_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes an order. Orders that interact with an online gateway can't be deleted.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-01/orders/{order_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_delete_param`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202001_delete_param_if_exists(&self, order_id: &str) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-01/orders/{}/json",
+            self.client.encode_path(order_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-01/orders/{order_id}.json",
+                    &[("order_id", order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -614,35 +676,34 @@ impl Orders {
         financial_status: &str,
         fulfillment_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !financial_status.is_empty() {
-            query_args.push(("financial_status".to_string(), financial_status.to_string()));
+            query_args.push(("financial_status", financial_status.to_string()));
         }
         if !fulfillment_status.is_empty() {
-            query_args.push((
-                "fulfillment_status".to_string(),
-                fulfillment_status.to_string(),
-            ));
+            query_args.push(("fulfillment_status", fulfillment_status.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/orders/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-01/orders/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/orders/count.json", &[])
+        })
     }
 
     /**
@@ -663,12 +724,19 @@ impl Orders {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/orders/{}/close.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            self.client.encode_path(order_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-01/orders/{order_id}/close.json",
+                    &[("order_id", order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -689,12 +757,19 @@ impl Orders {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/orders/{}/open.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            self.client.encode_path(order_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-01/orders/{order_id}/open.json",
+                    &[("order_id", order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -731,21 +806,21 @@ impl Orders {
         refund: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !amount.is_empty() {
-            query_args.push(("amount".to_string(), amount.to_string()));
+            query_args.push(("amount", amount.to_string()));
         }
         if !currency.is_empty() {
-            query_args.push(("currency".to_string(), currency.to_string()));
+            query_args.push(("currency", currency.to_string()));
         }
         if !email.is_empty() {
-            query_args.push(("email".to_string(), email.to_string()));
+            query_args.push(("email", email.to_string()));
         }
         if !reason.is_empty() {
-            query_args.push(("reason".to_string(), reason.to_string()));
+            query_args.push(("reason", reason.to_string()));
         }
         if !refund.is_empty() {
-            query_args.push(("refund".to_string(), refund.to_string()));
+            query_args.push(("refund", refund.to_string()));
         }
         if !restock_deprecated.is_empty() {
             query_args.push((
@@ -755,16 +830,24 @@ impl Orders {
                 restock_deprecated.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/orders/{}/cancel.json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/orders/{}/cancel.json",
+                self.client.encode_path(order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-01/orders/{order_id}/cancel.json",
+                    &[("order_id", order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -833,59 +916,55 @@ impl Orders {
         fulfillment_status: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !attribution_app_id.is_empty() {
-            query_args.push((
-                "attribution_app_id".to_string(),
-                attribution_app_id.to_string(),
-            ));
+            query_args.push(("attribution_app_id", attribution_app_id.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !financial_status.is_empty() {
-            query_args.push(("financial_status".to_string(), financial_status.to_string()));
+            query_args.push(("financial_status", financial_status.to_string()));
         }
         if !fulfillment_status.is_empty() {
-            query_args.push((
-                "fulfillment_status".to_string(),
-                fulfillment_status.to_string(),
-            ));
+            query_args.push(("fulfillment_status", fulfillment_status.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !processed_at_max.is_empty() {
-            query_args.push(("processed_at_max".to_string(), processed_at_max.to_string()));
+            query_args.push(("processed_at_max", processed_at_max.to_string()));
         }
         if !processed_at_min.is_empty() {
-            query_args.push(("processed_at_min".to_string(), processed_at_min.to_string()));
+            query_args.push(("processed_at_min", processed_at_min.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/orders.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-04/orders.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-04/orders.json", &[]))
     }
 
     /**
@@ -921,8 +1000,11 @@ impl Orders {
     pub async fn deprecated_202004_create(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-04/orders.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-04/orders.json", &[])
+            })
     }
 
     /**
@@ -938,18 +1020,25 @@ impl Orders {
      * * `fields: &str` -- Retrieve only certain fields, specified by a comma-separated list of fields names.
      */
     pub async fn deprecated_202004_get_param(&self, order_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/orders/{}/json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/orders/{}/json",
+                self.client.encode_path(order_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/orders/{order_id}.json",
+                &[("order_id", order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -970,12 +1059,19 @@ impl Orders {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/orders/{}/json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            self.client.encode_path(order_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-04/orders/{order_id}.json",
+                    &[("order_id", order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -992,10 +1088,41 @@ impl Orders {
     pub async fn deprecated_202004_delete_param(&self, order_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/orders/{}/json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            self.client.encode_path(order_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-04/orders/{order_id}.json",
+                &[("order_id", order_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes an order. Orders that interact with an online gateway can't be deleted.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-04/orders/{order_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_delete_param`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202004_delete_param_if_exists(&self, order_id: &str) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-04/orders/{}/json",
+            self.client.encode_path(order_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-04/orders/{order_id}.json",
+                    &[("order_id", order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1045,35 +1172,34 @@ impl Orders {
         financial_status: &str,
         fulfillment_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !financial_status.is_empty() {
-            query_args.push(("financial_status".to_string(), financial_status.to_string()));
+            query_args.push(("financial_status", financial_status.to_string()));
         }
         if !fulfillment_status.is_empty() {
-            query_args.push((
-                "fulfillment_status".to_string(),
-                fulfillment_status.to_string(),
-            ));
+            query_args.push(("fulfillment_status", fulfillment_status.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/orders/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-04/orders/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/orders/count.json", &[])
+        })
     }
 
     /**
@@ -1094,12 +1220,19 @@ impl Orders {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/orders/{}/close.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            self.client.encode_path(order_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-04/orders/{order_id}/close.json",
+                    &[("order_id", order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1120,12 +1253,19 @@ impl Orders {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/orders/{}/open.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            self.client.encode_path(order_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-04/orders/{order_id}/open.json",
+                    &[("order_id", order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1162,21 +1302,21 @@ impl Orders {
         refund: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !amount.is_empty() {
-            query_args.push(("amount".to_string(), amount.to_string()));
+            query_args.push(("amount", amount.to_string()));
         }
         if !currency.is_empty() {
-            query_args.push(("currency".to_string(), currency.to_string()));
+            query_args.push(("currency", currency.to_string()));
         }
         if !email.is_empty() {
-            query_args.push(("email".to_string(), email.to_string()));
+            query_args.push(("email", email.to_string()));
         }
         if !reason.is_empty() {
-            query_args.push(("reason".to_string(), reason.to_string()));
+            query_args.push(("reason", reason.to_string()));
         }
         if !refund.is_empty() {
-            query_args.push(("refund".to_string(), refund.to_string()));
+            query_args.push(("refund", refund.to_string()));
         }
         if !restock_deprecated.is_empty() {
             query_args.push((
@@ -1186,16 +1326,24 @@ impl Orders {
                 restock_deprecated.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/orders/{}/cancel.json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/orders/{}/cancel.json",
+                self.client.encode_path(order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-04/orders/{order_id}/cancel.json",
+                    &[("order_id", order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1264,59 +1412,55 @@ impl Orders {
         fulfillment_status: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !attribution_app_id.is_empty() {
-            query_args.push((
-                "attribution_app_id".to_string(),
-                attribution_app_id.to_string(),
-            ));
+            query_args.push(("attribution_app_id", attribution_app_id.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !financial_status.is_empty() {
-            query_args.push(("financial_status".to_string(), financial_status.to_string()));
+            query_args.push(("financial_status", financial_status.to_string()));
         }
         if !fulfillment_status.is_empty() {
-            query_args.push((
-                "fulfillment_status".to_string(),
-                fulfillment_status.to_string(),
-            ));
+            query_args.push(("fulfillment_status", fulfillment_status.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !processed_at_max.is_empty() {
-            query_args.push(("processed_at_max".to_string(), processed_at_max.to_string()));
+            query_args.push(("processed_at_max", processed_at_max.to_string()));
         }
         if !processed_at_min.is_empty() {
-            query_args.push(("processed_at_min".to_string(), processed_at_min.to_string()));
+            query_args.push(("processed_at_min", processed_at_min.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/orders.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-07/orders.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-07/orders.json", &[]))
     }
 
     /**
@@ -1333,10 +1477,16 @@ impl Orders {
     pub async fn deprecated_202001_get_param_risk(&self, order_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/orders/{}/risks.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            self.client.encode_path(order_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/orders/{order_id}/risks.json",
+                &[("order_id", order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1357,12 +1507,19 @@ impl Orders {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/orders/{}/risks.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            self.client.encode_path(order_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-01/orders/{order_id}/risks.json",
+                    &[("order_id", order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1384,11 +1541,20 @@ impl Orders {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/orders/{}/risks/{}/json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&risk_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(risk_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/orders/{order_id}/risks/{risk_id}.json",
+                &[
+                    ("order_id", order_id.to_string()),
+                    ("risk_id", risk_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1415,13 +1581,23 @@ impl Orders {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/orders/{}/risks/{}/json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&risk_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(risk_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-01/orders/{order_id}/risks/{risk_id}.json",
+                    &[
+                        ("order_id", order_id.to_string()),
+                        ("risk_id", risk_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1447,11 +1623,57 @@ impl Orders {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/orders/{}/risks/{}/json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&risk_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(risk_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-01/orders/{order_id}/risks/{risk_id}.json",
+                &[
+                    ("order_id", order_id.to_string()),
+                    ("risk_id", risk_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+    * Deletes an order risk for an order
+
+
+                Note
+                You cannot delete an order risk that was created by another application.
+    *
+    * This function performs a `DELETE` to the `/admin/api/2020-01/orders/{order_id}/risks/{risk_id}.json` endpoint.
+    *
+    * As opposed to `deprecated_202001_delete_param_risks_risk`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+    */
+    pub async fn deprecated_202001_delete_param_risks_risk_if_exists(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-01/orders/{}/risks/{}/json",
+            self.client.encode_path(order_id),
+            self.client.encode_path(risk_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-01/orders/{order_id}/risks/{risk_id}.json",
+                    &[
+                        ("order_id", order_id.to_string()),
+                        ("risk_id", risk_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1468,10 +1690,16 @@ impl Orders {
     pub async fn deprecated_202004_get_param_risk(&self, order_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/orders/{}/risks.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            self.client.encode_path(order_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/orders/{order_id}/risks.json",
+                &[("order_id", order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1492,12 +1720,19 @@ impl Orders {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/orders/{}/risks.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            self.client.encode_path(order_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-04/orders/{order_id}/risks.json",
+                    &[("order_id", order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1519,11 +1754,20 @@ impl Orders {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/orders/{}/risks/{}/json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&risk_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(risk_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/orders/{order_id}/risks/{risk_id}.json",
+                &[
+                    ("order_id", order_id.to_string()),
+                    ("risk_id", risk_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1550,13 +1794,23 @@ impl Orders {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/orders/{}/risks/{}/json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&risk_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(risk_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-04/orders/{order_id}/risks/{risk_id}.json",
+                    &[
+                        ("order_id", order_id.to_string()),
+                        ("risk_id", risk_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1582,11 +1836,57 @@ impl Orders {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/orders/{}/risks/{}/json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&risk_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(risk_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-04/orders/{order_id}/risks/{risk_id}.json",
+                &[
+                    ("order_id", order_id.to_string()),
+                    ("risk_id", risk_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+    * Deletes an order risk for an order
+
+
+                Note
+                You cannot delete an order risk that was created by another application.
+    *
+    * This function performs a `DELETE` to the `/admin/api/2020-04/orders/{order_id}/risks/{risk_id}.json` endpoint.
+    *
+    * As opposed to `deprecated_202004_delete_param_risks_risk`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+    */
+    pub async fn deprecated_202004_delete_param_risks_risk_if_exists(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-04/orders/{}/risks/{}/json",
+            self.client.encode_path(order_id),
+            self.client.encode_path(risk_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-04/orders/{order_id}/risks/{risk_id}.json",
+                    &[
+                        ("order_id", order_id.to_string()),
+                        ("risk_id", risk_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1603,10 +1903,16 @@ impl Orders {
     pub async fn deprecated_202007_get_param_risk(&self, order_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/orders/{}/risks.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            self.client.encode_path(order_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/orders/{order_id}/risks.json",
+                &[("order_id", order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1627,12 +1933,19 @@ impl Orders {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/orders/{}/risks.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            self.client.encode_path(order_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-07/orders/{order_id}/risks.json",
+                    &[("order_id", order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1654,11 +1967,20 @@ impl Orders {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/orders/{}/risks/{}/json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&risk_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(risk_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/orders/{order_id}/risks/{risk_id}.json",
+                &[
+                    ("order_id", order_id.to_string()),
+                    ("risk_id", risk_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1685,13 +2007,23 @@ impl Orders {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/orders/{}/risks/{}/json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&risk_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(risk_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-07/orders/{order_id}/risks/{risk_id}.json",
+                    &[
+                        ("order_id", order_id.to_string()),
+                        ("risk_id", risk_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1717,11 +2049,57 @@ impl Orders {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/orders/{}/risks/{}/json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&risk_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(risk_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-07/orders/{order_id}/risks/{risk_id}.json",
+                &[
+                    ("order_id", order_id.to_string()),
+                    ("risk_id", risk_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+    * Deletes an order risk for an order
+
+
+                Note
+                You cannot delete an order risk that was created by another application.
+    *
+    * This function performs a `DELETE` to the `/admin/api/2020-07/orders/{order_id}/risks/{risk_id}.json` endpoint.
+    *
+    * As opposed to `deprecated_202007_delete_param_risks_risk`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+    */
+    pub async fn deprecated_202007_delete_param_risks_risk_if_exists(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-07/orders/{}/risks/{}/json",
+            self.client.encode_path(order_id),
+            self.client.encode_path(risk_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-07/orders/{order_id}/risks/{risk_id}.json",
+                    &[
+                        ("order_id", order_id.to_string()),
+                        ("risk_id", risk_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1738,10 +2116,16 @@ impl Orders {
     pub async fn get_param_risk(&self, order_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/orders/{}/risks.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            self.client.encode_path(order_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/orders/{order_id}/risks.json",
+                &[("order_id", order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1758,12 +2142,19 @@ impl Orders {
     pub async fn create_param_risks(&self, order_id: &str, body: &serde_json::Value) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/orders/{}/risks.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            self.client.encode_path(order_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-10/orders/{order_id}/risks.json",
+                    &[("order_id", order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1781,11 +2172,20 @@ impl Orders {
     pub async fn get_param_risks_risk(&self, order_id: &str, risk_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/orders/{}/risks/{}/json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&risk_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(risk_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/orders/{order_id}/risks/{risk_id}.json",
+                &[
+                    ("order_id", order_id.to_string()),
+                    ("risk_id", risk_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1812,13 +2212,23 @@ impl Orders {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/orders/{}/risks/{}/json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&risk_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(risk_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-10/orders/{order_id}/risks/{risk_id}.json",
+                    &[
+                        ("order_id", order_id.to_string()),
+                        ("risk_id", risk_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1840,11 +2250,57 @@ impl Orders {
     pub async fn delete_param_risks_risk(&self, order_id: &str, risk_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/orders/{}/risks/{}/json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&risk_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(risk_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-10/orders/{order_id}/risks/{risk_id}.json",
+                &[
+                    ("order_id", order_id.to_string()),
+                    ("risk_id", risk_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+    * Deletes an order risk for an order
+
+
+                Note
+                You cannot delete an order risk that was created by another application.
+    *
+    * This function performs a `DELETE` to the `/admin/api/2020-10/orders/{order_id}/risks/{risk_id}.json` endpoint.
+    *
+    * As opposed to `delete_param_risks_risk`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+    */
+    pub async fn delete_param_risks_risk_if_exists(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-10/orders/{}/risks/{}/json",
+            self.client.encode_path(order_id),
+            self.client.encode_path(risk_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-10/orders/{order_id}/risks/{risk_id}.json",
+                    &[
+                        ("order_id", order_id.to_string()),
+                        ("risk_id", risk_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1861,10 +2317,16 @@ impl Orders {
     pub async fn deprecated_202101_get_param_risk(&self, order_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/orders/{}/risks.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            self.client.encode_path(order_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/orders/{order_id}/risks.json",
+                &[("order_id", order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1885,12 +2347,19 @@ impl Orders {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/orders/{}/risks.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            self.client.encode_path(order_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2021-01/orders/{order_id}/risks.json",
+                    &[("order_id", order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1912,11 +2381,20 @@ impl Orders {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/orders/{}/risks/{}/json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&risk_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(risk_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/orders/{order_id}/risks/{risk_id}.json",
+                &[
+                    ("order_id", order_id.to_string()),
+                    ("risk_id", risk_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1943,13 +2421,23 @@ impl Orders {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/orders/{}/risks/{}/json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&risk_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(risk_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2021-01/orders/{order_id}/risks/{risk_id}.json",
+                    &[
+                        ("order_id", order_id.to_string()),
+                        ("risk_id", risk_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1975,11 +2463,57 @@ impl Orders {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/orders/{}/risks/{}/json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&risk_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(risk_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2021-01/orders/{order_id}/risks/{risk_id}.json",
+                &[
+                    ("order_id", order_id.to_string()),
+                    ("risk_id", risk_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+    * Deletes an order risk for an order
+
+
+                Note
+                You cannot delete an order risk that was created by another application.
+    *
+    * This function performs a `DELETE` to the `/admin/api/2021-01/orders/{order_id}/risks/{risk_id}.json` endpoint.
+    *
+    * As opposed to `deprecated_202101_delete_param_risks_risk`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+    */
+    pub async fn deprecated_202101_delete_param_risks_risk_if_exists(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2021-01/orders/{}/risks/{}/json",
+            self.client.encode_path(order_id),
+            self.client.encode_path(risk_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2021-01/orders/{order_id}/risks/{risk_id}.json",
+                    &[
+                        ("order_id", order_id.to_string()),
+                        ("risk_id", risk_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1996,10 +2530,16 @@ impl Orders {
     pub async fn deprecated_unstable_get_param_risk(&self, order_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/orders/{}/risks.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            self.client.encode_path(order_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/orders/{order_id}/risks.json",
+                &[("order_id", order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2020,12 +2560,19 @@ impl Orders {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/orders/{}/risks.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            self.client.encode_path(order_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/unstable/orders/{order_id}/risks.json",
+                    &[("order_id", order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2047,11 +2594,20 @@ impl Orders {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/orders/{}/risks/{}/json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&risk_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(risk_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/orders/{order_id}/risks/{risk_id}.json",
+                &[
+                    ("order_id", order_id.to_string()),
+                    ("risk_id", risk_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -2078,13 +2634,23 @@ impl Orders {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/orders/{}/risks/{}/json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&risk_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(risk_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/unstable/orders/{order_id}/risks/{risk_id}.json",
+                    &[
+                        ("order_id", order_id.to_string()),
+                        ("risk_id", risk_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -2110,11 +2676,57 @@ impl Orders {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/orders/{}/risks/{}/json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&risk_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(risk_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/unstable/orders/{order_id}/risks/{risk_id}.json",
+                &[
+                    ("order_id", order_id.to_string()),
+                    ("risk_id", risk_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+    * Deletes an order risk for an order
+
+
+                Note
+                You cannot delete an order risk that was created by another application.
+    *
+    * This function performs a `DELETE` to the `/admin/api/unstable/orders/{order_id}/risks/{risk_id}.json` endpoint.
+    *
+    * As opposed to `deprecated_unstable_delete_param_risks_risk`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+    */
+    pub async fn deprecated_unstable_delete_param_risks_risk_if_exists(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/unstable/orders/{}/risks/{}/json",
+            self.client.encode_path(order_id),
+            self.client.encode_path(risk_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/unstable/orders/{order_id}/risks/{risk_id}.json",
+                    &[
+                        ("order_id", order_id.to_string()),
+                        ("risk_id", risk_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -2140,24 +2752,31 @@ impl Orders {
         fields: &str,
         in_shop_currency: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !in_shop_currency.is_empty() {
-            query_args.push(("in_shop_currency".to_string(), in_shop_currency.to_string()));
+            query_args.push(("in_shop_currency", in_shop_currency.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/orders/{}/refunds.json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            query_
+            query_args.push(("limit", limit.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/orders/{}/refunds.json",
+                self.client.encode_path(order_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/orders/{order_id}/refunds.json",
+                &[("order_id", order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2223,27 +2842,21 @@ impl Orders {
         currency: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !currency.is_empty() {
-            query_args.push(("currency".to_string(), currency.to_string()));
+            query_args.push(("currency", currency.to_string()));
         }
         if !discrepancy_reason.is_empty() {
-            query_args.push((
-                "discrepancy_reason".to_string(),
-                discrepancy_reason.to_string(),
-            ));
+            query_args.push(("discrepancy_reason", discrepancy_reason.to_string()));
         }
         if !note.is_empty() {
-            query_args.push(("note".to_string(), note.to_string()));
+            query_args.push(("note", note.to_string()));
         }
         if !notify.is_empty() {
-            query_args.push(("notify".to_string(), notify.to_string()));
+            query_args.push(("notify", notify.to_string()));
         }
         if !refund_line_items.is_empty() {
-            query_args.push((
-                "refund_line_items".to_string(),
-                refund_line_items.to_string(),
-            ));
+            query_args.push(("refund_line_items", refund_line_items.to_string()));
         }
         if !restock_deprecated.is_empty() {
             query_args.push((
@@ -2254,21 +2867,29 @@ impl Orders {
             ));
         }
         if !shipping.is_empty() {
-            query_args.push(("shipping".to_string(), shipping.to_string()));
+            query_args.push(("shipping", shipping.to_string()));
         }
         if !transactions.is_empty() {
-            query_args.push(("transactions".to_string(), transactions.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/orders/{}/refunds.json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            query_
+            query_args.push(("transactions", transactions.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/orders/{}/refunds.json",
+                self.client.encode_path(order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-01/orders/{order_id}/refunds.json",
+                    &[("order_id", order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2293,22 +2914,32 @@ impl Orders {
         fields: &str,
         in_shop_currency: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !in_shop_currency.is_empty() {
-            query_args.push(("in_shop_currency".to_string(), in_shop_currency.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/orders/{}/refunds/{}/json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&refund_id.to_string()),
-            query_
+            query_args.push(("in_shop_currency", in_shop_currency.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/orders/{}/refunds/{}/json",
+                self.client.encode_path(order_id),
+                self.client.encode_path(refund_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/orders/{order_id}/refunds/{refund_id}.json",
+                &[
+                    ("order_id", order_id.to_string()),
+                    ("refund_id", refund_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -2367,29 +2998,34 @@ impl Orders {
         currency: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !currency.is_empty() {
-            query_args.push(("currency".to_string(), currency.to_string()));
+            query_args.push(("currency", currency.to_string()));
         }
         if !refund_line_items.is_empty() {
-            query_args.push((
-                "refund_line_items".to_string(),
-                refund_line_items.to_string(),
-            ));
+            query_args.push(("refund_line_items", refund_line_items.to_string()));
         }
         if !shipping.is_empty() {
-            query_args.push(("shipping".to_string(), shipping.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/orders/{}/refunds/calculate.json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            query_
+            query_args.push(("shipping", shipping.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/orders/{}/refunds/calculate.json",
+                self.client.encode_path(order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-01/orders/{order_id}/refunds/calculate.json",
+                    &[("order_id", order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2415,24 +3051,31 @@ impl Orders {
         fields: &str,
         in_shop_currency: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !in_shop_currency.is_empty() {
-            query_args.push(("in_shop_currency".to_string(), in_shop_currency.to_string()));
+            query_args.push(("in_shop_currency", in_shop_currency.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/orders/{}/refunds.json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            query_
+            query_args.push(("limit", limit.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/orders/{}/refunds.json",
+                self.client.encode_path(order_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/orders/{order_id}/refunds.json",
+                &[("order_id", order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2498,27 +3141,21 @@ impl Orders {
         currency: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !currency.is_empty() {
-            query_args.push(("currency".to_string(), currency.to_string()));
+            query_args.push(("currency", currency.to_string()));
         }
         if !discrepancy_reason.is_empty() {
-            query_args.push((
-                "discrepancy_reason".to_string(),
-                discrepancy_reason.to_string(),
-            ));
+            query_args.push(("discrepancy_reason", discrepancy_reason.to_string()));
         }
         if !note.is_empty() {
-            query_args.push(("note".to_string(), note.to_string()));
+            query_args.push(("note", note.to_string()));
         }
         if !notify.is_empty() {
-            query_args.push(("notify".to_string(), notify.to_string()));
+            query_args.push(("notify", notify.to_string()));
         }
         if !refund_line_items.is_empty() {
-            query_args.push((
-                "refund_line_items".to_string(),
-                refund_line_items.to_string(),
-            ));
+            query_args.push(("refund_line_items", refund_line_items.to_string()));
         }
         if !restock_deprecated.is_empty() {
             query_args.push((
@@ -2529,21 +3166,29 @@ impl Orders {
             ));
         }
         if !shipping.is_empty() {
-            query_args.push(("shipping".to_string(), shipping.to_string()));
+            query_args.push(("shipping", shipping.to_string()));
         }
         if !transactions.is_empty() {
-            query_args.push(("transactions".to_string(), transactions.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/orders/{}/refunds.json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            query_
+            query_args.push(("transactions", transactions.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/orders/{}/refunds.json",
+                self.client.encode_path(order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-04/orders/{order_id}/refunds.json",
+                    &[("order_id", order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2568,22 +3213,32 @@ impl Orders {
         fields: &str,
         in_shop_currency: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !in_shop_currency.is_empty() {
-            query_args.push(("in_shop_currency".to_string(), in_shop_currency.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/orders/{}/refunds/{}/json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&refund_id.to_string()),
-            query_
+            query_args.push(("in_shop_currency", in_shop_currency.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/orders/{}/refunds/{}/json",
+                self.client.encode_path(order_id),
+                self.client.encode_path(refund_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/orders/{order_id}/refunds/{refund_id}.json",
+                &[
+                    ("order_id", order_id.to_string()),
+                    ("refund_id", refund_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -2642,29 +3297,34 @@ impl Orders {
         currency: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !currency.is_empty() {
-            query_args.push(("currency".to_string(), currency.to_string()));
+            query_args.push(("currency", currency.to_string()));
         }
         if !refund_line_items.is_empty() {
-            query_args.push((
-                "refund_line_items".to_string(),
-                refund_line_items.to_string(),
-            ));
+            query_args.push(("refund_line_items", refund_line_items.to_string()));
         }
         if !shipping.is_empty() {
-            query_args.push(("shipping".to_string(), shipping.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/orders/{}/refunds/calculate.json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            query_
+            query_args.push(("shipping", shipping.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/orders/{}/refunds/calculate.json",
+                self.client.encode_path(order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-04/orders/{order_id}/refunds/calculate.json",
+                    &[("order_id", order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2690,24 +3350,31 @@ impl Orders {
         fields: &str,
         in_shop_currency: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !in_shop_currency.is_empty() {
-            query_args.push(("in_shop_currency".to_string(), in_shop_currency.to_string()));
+            query_args.push(("in_shop_currency", in_shop_currency.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/orders/{}/refunds.json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            query_
+            query_args.push(("limit", limit.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/orders/{}/refunds.json",
+                self.client.encode_path(order_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/orders/{order_id}/refunds.json",
+                &[("order_id", order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2773,27 +3440,21 @@ impl Orders {
         currency: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !currency.is_empty() {
-            query_args.push(("currency".to_string(), currency.to_string()));
+            query_args.push(("currency", currency.to_string()));
         }
         if !discrepancy_reason.is_empty() {
-            query_args.push((
-                "discrepancy_reason".to_string(),
-                discrepancy_reason.to_string(),
-            ));
+            query_args.push(("discrepancy_reason", discrepancy_reason.to_string()));
         }
         if !note.is_empty() {
-            query_args.push(("note".to_string(), note.to_string()));
+            query_args.push(("note", note.to_string()));
         }
         if !notify.is_empty() {
-            query_args.push(("notify".to_string(), notify.to_string()));
+            query_args.push(("notify", notify.to_string()));
         }
         if !refund_line_items.is_empty() {
-            query_args.push((
-                "refund_line_items".to_string(),
-                refund_line_items.to_string(),
-            ));
+            query_args.push(("refund_line_items", refund_line_items.to_string()));
         }
         if !restock_deprecated.is_empty() {
             query_args.push((
@@ -2804,21 +3465,29 @@ impl Orders {
             ));
         }
         if !shipping.is_empty() {
-            query_args.push(("shipping".to_string(), shipping.to_string()));
+            query_args.push(("shipping", shipping.to_string()));
         }
         if !transactions.is_empty() {
-            query_args.push(("transactions".to_string(), transactions.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/orders/{}/refunds.json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            query_
+            query_args.push(("transactions", transactions.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/orders/{}/refunds.json",
+                self.client.encode_path(order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-07/orders/{order_id}/refunds.json",
+                    &[("order_id", order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2843,22 +3512,32 @@ impl Orders {
         fields: &str,
         in_shop_currency: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !in_shop_currency.is_empty() {
-            query_args.push(("in_shop_currency".to_string(), in_shop_currency.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/orders/{}/refunds/{}/json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&refund_id.to_string()),
-            query_
+            query_args.push(("in_shop_currency", in_shop_currency.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/orders/{}/refunds/{}/json",
+                self.client.encode_path(order_id),
+                self.client.encode_path(refund_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/orders/{order_id}/refunds/{refund_id}.json",
+                &[
+                    ("order_id", order_id.to_string()),
+                    ("refund_id", refund_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -2917,29 +3596,34 @@ impl Orders {
         currency: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !currency.is_empty() {
-            query_args.push(("currency".to_string(), currency.to_string()));
+            query_args.push(("currency", currency.to_string()));
         }
         if !refund_line_items.is_empty() {
-            query_args.push((
-                "refund_line_items".to_string(),
-                refund_line_items.to_string(),
-            ));
+            query_args.push(("refund_line_items", refund_line_items.to_string()));
         }
         if !shipping.is_empty() {
-            query_args.push(("shipping".to_string(), shipping.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/orders/{}/refunds/calculate.json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            query_
+            query_args.push(("shipping", shipping.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/orders/{}/refunds/calculate.json",
+                self.client.encode_path(order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-07/orders/{order_id}/refunds/calculate.json",
+                    &[("order_id", order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2965,24 +3649,31 @@ impl Orders {
         fields: &str,
         in_shop_currency: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !in_shop_currency.is_empty() {
-            query_args.push(("in_shop_currency".to_string(), in_shop_currency.to_string()));
+            query_args.push(("in_shop_currency", in_shop_currency.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/orders/{}/refunds.json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            query_
+            query_args.push(("limit", limit.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/orders/{}/refunds.json",
+                self.client.encode_path(order_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/orders/{order_id}/refunds.json",
+                &[("order_id", order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -3048,27 +3739,21 @@ impl Orders {
         currency: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !currency.is_empty() {
-            query_args.push(("currency".to_string(), currency.to_string()));
+            query_args.push(("currency", currency.to_string()));
         }
         if !discrepancy_reason.is_empty() {
-            query_args.push((
-                "discrepancy_reason".to_string(),
-                discrepancy_reason.to_string(),
-            ));
+            query_args.push(("discrepancy_reason", discrepancy_reason.to_string()));
         }
         if !note.is_empty() {
-            query_args.push(("note".to_string(), note.to_string()));
+            query_args.push(("note", note.to_string()));
         }
         if !notify.is_empty() {
-            query_args.push(("notify".to_string(), notify.to_string()));
+            query_args.push(("notify", notify.to_string()));
         }
         if !refund_line_items.is_empty() {
-            query_args.push((
-                "refund_line_items".to_string(),
-                refund_line_items.to_string(),
-            ));
+            query_args.push(("refund_line_items", refund_line_items.to_string()));
         }
         if !restock_deprecated.is_empty() {
             query_args.push((
@@ -3079,21 +3764,29 @@ impl Orders {
             ));
         }
         if !shipping.is_empty() {
-            query_args.push(("shipping".to_string(), shipping.to_string()));
+            query_args.push(("shipping", shipping.to_string()));
         }
         if !transactions.is_empty() {
-            query_args.push(("transactions".to_string(), transactions.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/orders/{}/refunds.json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            query_
+            query_args.push(("transactions", transactions.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/orders/{}/refunds.json",
+                self.client.encode_path(order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-10/orders/{order_id}/refunds.json",
+                    &[("order_id", order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -3118,22 +3811,32 @@ impl Orders {
         fields: &str,
         in_shop_currency: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !in_shop_currency.is_empty() {
-            query_args.push(("in_shop_currency".to_string(), in_shop_currency.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/orders/{}/refunds/{}/json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&refund_id.to_string()),
-            query_
+            query_args.push(("in_shop_currency", in_shop_currency.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/orders/{}/refunds/{}/json",
+                self.client.encode_path(order_id),
+                self.client.encode_path(refund_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/orders/{order_id}/refunds/{refund_id}.json",
+                &[
+                    ("order_id", order_id.to_string()),
+                    ("refund_id", refund_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -3192,29 +3895,34 @@ impl Orders {
         currency: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !currency.is_empty() {
-            query_args.push(("currency".to_string(), currency.to_string()));
+            query_args.push(("currency", currency.to_string()));
         }
         if !refund_line_items.is_empty() {
-            query_args.push((
-                "refund_line_items".to_string(),
-                refund_line_items.to_string(),
-            ));
+            query_args.push(("refund_line_items", refund_line_items.to_string()));
         }
         if !shipping.is_empty() {
-            query_args.push(("shipping".to_string(), shipping.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/orders/{}/refunds/calculate.json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            query_
+            query_args.push(("shipping", shipping.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/orders/{}/refunds/calculate.json",
+                self.client.encode_path(order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-10/orders/{order_id}/refunds/calculate.json",
+                    &[("order_id", order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -3240,24 +3948,31 @@ impl Orders {
         fields: &str,
         in_shop_currency: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !in_shop_currency.is_empty() {
-            query_args.push(("in_shop_currency".to_string(), in_shop_currency.to_string()));
+            query_args.push(("in_shop_currency", in_shop_currency.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/orders/{}/refunds.json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            query_
+            query_args.push(("limit", limit.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/orders/{}/refunds.json",
+                self.client.encode_path(order_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/orders/{order_id}/refunds.json",
+                &[("order_id", order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -3323,27 +4038,21 @@ impl Orders {
         currency: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !currency.is_empty() {
-            query_args.push(("currency".to_string(), currency.to_string()));
+            query_args.push(("currency", currency.to_string()));
         }
         if !discrepancy_reason.is_empty() {
-            query_args.push((
-                "discrepancy_reason".to_string(),
-                discrepancy_reason.to_string(),
-            ));
+            query_args.push(("discrepancy_reason", discrepancy_reason.to_string()));
         }
         if !note.is_empty() {
-            query_args.push(("note".to_string(), note.to_string()));
+            query_args.push(("note", note.to_string()));
         }
         if !notify.is_empty() {
-            query_args.push(("notify".to_string(), notify.to_string()));
+            query_args.push(("notify", notify.to_string()));
         }
         if !refund_line_items.is_empty() {
-            query_args.push((
-                "refund_line_items".to_string(),
-                refund_line_items.to_string(),
-            ));
+            query_args.push(("refund_line_items", refund_line_items.to_string()));
         }
         if !restock_deprecated.is_empty() {
             query_args.push((
@@ -3354,21 +4063,29 @@ impl Orders {
             ));
         }
         if !shipping.is_empty() {
-            query_args.push(("shipping".to_string(), shipping.to_string()));
+            query_args.push(("shipping", shipping.to_string()));
         }
         if !transactions.is_empty() {
-            query_args.push(("transactions".to_string(), transactions.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/orders/{}/refunds.json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            query_
+            query_args.push(("transactions", transactions.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/orders/{}/refunds.json",
+                self.client.encode_path(order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2021-01/orders/{order_id}/refunds.json",
+                    &[("order_id", order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -3393,22 +4110,32 @@ impl Orders {
         fields: &str,
         in_shop_currency: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !in_shop_currency.is_empty() {
-            query_args.push(("in_shop_currency".to_string(), in_shop_currency.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/orders/{}/refunds/{}/json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&refund_id.to_string()),
-            query_
+            query_args.push(("in_shop_currency", in_shop_currency.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/orders/{}/refunds/{}/json",
+                self.client.encode_path(order_id),
+                self.client.encode_path(refund_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/orders/{order_id}/refunds/{refund_id}.json",
+                &[
+                    ("order_id", order_id.to_string()),
+                    ("refund_id", refund_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -3467,29 +4194,34 @@ impl Orders {
         currency: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !currency.is_empty() {
-            query_args.push(("currency".to_string(), currency.to_string()));
+            query_args.push(("currency", currency.to_string()));
         }
         if !refund_line_items.is_empty() {
-            query_args.push((
-                "refund_line_items".to_string(),
-                refund_line_items.to_string(),
-            ));
+            query_args.push(("refund_line_items", refund_line_items.to_string()));
         }
         if !shipping.is_empty() {
-            query_args.push(("shipping".to_string(), shipping.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/orders/{}/refunds/calculate.json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            query_
+            query_args.push(("shipping", shipping.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/orders/{}/refunds/calculate.json",
+                self.client.encode_path(order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2021-01/orders/{order_id}/refunds/calculate.json",
+                    &[("order_id", order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -3515,24 +4247,31 @@ impl Orders {
         fields: &str,
         in_shop_currency: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !in_shop_currency.is_empty() {
-            query_args.push(("in_shop_currency".to_string(), in_shop_currency.to_string()));
+            query_args.push(("in_shop_currency", in_shop_currency.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/orders/{}/refunds.json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            query_
+            query_args.push(("limit", limit.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/orders/{}/refunds.json",
+                self.client.encode_path(order_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/orders/{order_id}/refunds.json",
+                &[("order_id", order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -3598,27 +4337,21 @@ impl Orders {
         currency: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !currency.is_empty() {
-            query_args.push(("currency".to_string(), currency.to_string()));
+            query_args.push(("currency", currency.to_string()));
         }
         if !discrepancy_reason.is_empty() {
-            query_args.push((
-                "discrepancy_reason".to_string(),
-                discrepancy_reason.to_string(),
-            ));
+            query_args.push(("discrepancy_reason", discrepancy_reason.to_string()));
         }
         if !note.is_empty() {
-            query_args.push(("note".to_string(), note.to_string()));
+            query_args.push(("note", note.to_string()));
         }
         if !notify.is_empty() {
-            query_args.push(("notify".to_string(), notify.to_string()));
+            query_args.push(("notify", notify.to_string()));
         }
         if !refund_line_items.is_empty() {
-            query_args.push((
-                "refund_line_items".to_string(),
-                refund_line_items.to_string(),
-            ));
+            query_args.push(("refund_line_items", refund_line_items.to_string()));
         }
         if !restock_deprecated.is_empty() {
             query_args.push((
@@ -3629,21 +4362,29 @@ impl Orders {
             ));
         }
         if !shipping.is_empty() {
-            query_args.push(("shipping".to_string(), shipping.to_string()));
+            query_args.push(("shipping", shipping.to_string()));
         }
         if !transactions.is_empty() {
-            query_args.push(("transactions".to_string(), transactions.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/orders/{}/refunds.json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            query_
+            query_args.push(("transactions", transactions.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/orders/{}/refunds.json",
+                self.client.encode_path(order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/unstable/orders/{order_id}/refunds.json",
+                    &[("order_id", order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -3668,22 +4409,32 @@ impl Orders {
         fields: &str,
         in_shop_currency: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !in_shop_currency.is_empty() {
-            query_args.push(("in_shop_currency".to_string(), in_shop_currency.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/orders/{}/refunds/{}/json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&refund_id.to_string()),
-            query_
+            query_args.push(("in_shop_currency", in_shop_currency.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/orders/{}/refunds/{}/json",
+                self.client.encode_path(order_id),
+                self.client.encode_path(refund_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/orders/{order_id}/refunds/{refund_id}.json",
+                &[
+                    ("order_id", order_id.to_string()),
+                    ("refund_id", refund_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -3742,28 +4493,1629 @@ impl Orders {
         currency: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !currency.is_empty() {
-            query_args.push(("currency".to_string(), currency.to_string()));
+            query_args.push(("currency", currency.to_string()));
         }
         if !refund_line_items.is_empty() {
-            query_args.push((
-                "refund_line_items".to_string(),
-                refund_line_items.to_string(),
-            ));
+            query_args.push(("refund_line_items", refund_line_items.to_string()));
         }
         if !shipping.is_empty() {
-            query_args.push(("shipping".to_string(), shipping.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/orders/{}/refunds/calculate.json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            query_
+            query_args.push(("shipping", shipping.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/orders/{}/refunds/calculate.json",
+                self.client.encode_path(order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/unstable/orders/{order_id}/refunds/calculate.json",
+                    &[("order_id", order_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`Orders`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn OrdersApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait OrdersApi: Send + Sync {
+    async fn deprecated_202001_get_checkouts_count(
+        &self,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        status: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_checkouts_count(
+        &self,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        status: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_checkouts_count(
+        &self,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        status: &str,
+    ) -> Result<()>;
+    async fn get_checkouts_count(
+        &self,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        status: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_checkouts_count(
+        &self,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        status: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_checkouts_count(
+        &self,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        status: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_get(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        processed_at_min: &str,
+        processed_at_max: &str,
+        attribution_app_id: &str,
+        status: &str,
+        financial_status: &str,
+        fulfillment_status: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_create(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202001_get_param(&self, order_id: &str, fields: &str) -> Result<()>;
+    async fn deprecated_202001_update_param(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_param(&self, order_id: &str) -> Result<()>;
+    async fn deprecated_202001_delete_param_if_exists(&self, order_id: &str) -> Result<bool>;
+    async fn deprecated_202001_get_count(
+        &self,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        status: &str,
+        financial_status: &str,
+        fulfillment_status: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_param_close(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_param_open(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_param_cancel(
+        &self,
+        order_id: &str,
+        amount: &str,
+        currency: &str,
+        restock_deprecated: &str,
+        reason: &str,
+        email: &str,
+        refund: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_get(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        processed_at_min: &str,
+        processed_at_max: &str,
+        attribution_app_id: &str,
+        status: &str,
+        financial_status: &str,
+        fulfillment_status: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_create(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202004_get_param(&self, order_id: &str, fields: &str) -> Result<()>;
+    async fn deprecated_202004_update_param(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_param(&self, order_id: &str) -> Result<()>;
+    async fn deprecated_202004_delete_param_if_exists(&self, order_id: &str) -> Result<bool>;
+    async fn deprecated_202004_get_count(
+        &self,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        status: &str,
+        financial_status: &str,
+        fulfillment_status: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_create_param_close(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_create_param_open(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_create_param_cancel(
+        &self,
+        order_id: &str,
+        amount: &str,
+        currency: &str,
+        restock_deprecated: &str,
+        reason: &str,
+        email: &str,
+        refund: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_get(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        processed_at_min: &str,
+        processed_at_max: &str,
+        attribution_app_id: &str,
+        status: &str,
+        financial_status: &str,
+        fulfillment_status: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_param_risk(&self, order_id: &str) -> Result<()>;
+    async fn deprecated_202001_create_param_risks(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_update_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_param_risks_risk_if_exists(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202004_get_param_risk(&self, order_id: &str) -> Result<()>;
+    async fn deprecated_202004_create_param_risks(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_update_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_param_risks_risk_if_exists(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202007_get_param_risk(&self, order_id: &str) -> Result<()>;
+    async fn deprecated_202007_create_param_risks(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_update_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_param_risks_risk_if_exists(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<bool>;
+    async fn get_param_risk(&self, order_id: &str) -> Result<()>;
+    async fn create_param_risks(&self, order_id: &str, body: &serde_json::Value) -> Result<()>;
+    async fn get_param_risks_risk(&self, order_id: &str, risk_id: &str) -> Result<()>;
+    async fn update_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn delete_param_risks_risk(&self, order_id: &str, risk_id: &str) -> Result<()>;
+    async fn delete_param_risks_risk_if_exists(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202101_get_param_risk(&self, order_id: &str) -> Result<()>;
+    async fn deprecated_202101_create_param_risks(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_update_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_param_risks_risk_if_exists(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_unstable_get_param_risk(&self, order_id: &str) -> Result<()>;
+    async fn deprecated_unstable_create_param_risks(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_update_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_param_risks_risk_if_exists(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202001_get_param_refund(
+        &self,
+        order_id: &str,
+        limit: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_param_refunds(
+        &self,
+        order_id: &str,
+        restock_deprecated: &str,
+        notify: &str,
+        note: &str,
+        discrepancy_reason: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        transactions: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_param_refunds_refund(
+        &self,
+        order_id: &str,
+        refund_id: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_param_refunds_calculate(
+        &self,
+        order_id: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_param_refund(
+        &self,
+        order_id: &str,
+        limit: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_create_param_refunds(
+        &self,
+        order_id: &str,
+        restock_deprecated: &str,
+        notify: &str,
+        note: &str,
+        discrepancy_reason: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        transactions: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_param_refunds_refund(
+        &self,
+        order_id: &str,
+        refund_id: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_create_param_refunds_calculate(
+        &self,
+        order_id: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_param_refund(
+        &self,
+        order_id: &str,
+        limit: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_create_param_refunds(
+        &self,
+        order_id: &str,
+        restock_deprecated: &str,
+        notify: &str,
+        note: &str,
+        discrepancy_reason: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        transactions: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_param_refunds_refund(
+        &self,
+        order_id: &str,
+        refund_id: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_create_param_refunds_calculate(
+        &self,
+        order_id: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn get_param_refund(
+        &self,
+        order_id: &str,
+        limit: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> Result<()>;
+    async fn create_param_refunds(
+        &self,
+        order_id: &str,
+        restock_deprecated: &str,
+        notify: &str,
+        note: &str,
+        discrepancy_reason: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        transactions: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn get_param_refunds_refund(
+        &self,
+        order_id: &str,
+        refund_id: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> Result<()>;
+    async fn create_param_refunds_calculate(
+        &self,
+        order_id: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_param_refund(
+        &self,
+        order_id: &str,
+        limit: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_create_param_refunds(
+        &self,
+        order_id: &str,
+        restock_deprecated: &str,
+        notify: &str,
+        note: &str,
+        discrepancy_reason: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        transactions: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_param_refunds_refund(
+        &self,
+        order_id: &str,
+        refund_id: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_create_param_refunds_calculate(
+        &self,
+        order_id: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_param_refund(
+        &self,
+        order_id: &str,
+        limit: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create_param_refunds(
+        &self,
+        order_id: &str,
+        restock_deprecated: &str,
+        notify: &str,
+        note: &str,
+        discrepancy_reason: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        transactions: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_param_refunds_refund(
+        &self,
+        order_id: &str,
+        refund_id: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create_param_refunds_calculate(
+        &self,
+        order_id: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl OrdersApi for Orders {
+    async fn deprecated_202001_get_checkouts_count(
+        &self,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        status: &str,
+    ) -> Result<()> {
+        Orders::deprecated_202001_get_checkouts_count(
+            self,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            status,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_checkouts_count(
+        &self,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        status: &str,
+    ) -> Result<()> {
+        Orders::deprecated_202004_get_checkouts_count(
+            self,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            status,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_checkouts_count(
+        &self,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        status: &str,
+    ) -> Result<()> {
+        Orders::deprecated_202007_get_checkouts_count(
+            self,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            status,
+        )
+        .await
+    }
+    async fn get_checkouts_count(
+        &self,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        status: &str,
+    ) -> Result<()> {
+        Orders::get_checkouts_count(
+            self,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            status,
+        )
+        .await
+    }
+    async fn deprecated_202101_get_checkouts_count(
+        &self,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        status: &str,
+    ) -> Result<()> {
+        Orders::deprecated_202101_get_checkouts_count(
+            self,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            status,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_checkouts_count(
+        &self,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        status: &str,
+    ) -> Result<()> {
+        Orders::deprecated_unstable_get_checkouts_count(
+            self,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            status,
+        )
+        .await
+    }
+    async fn deprecated_202001_get(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        processed_at_min: &str,
+        processed_at_max: &str,
+        attribution_app_id: &str,
+        status: &str,
+        financial_status: &str,
+        fulfillment_status: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Orders::deprecated_202001_get(
+            self,
+            ids,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            processed_at_min,
+            processed_at_max,
+            attribution_app_id,
+            status,
+            financial_status,
+            fulfillment_status,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202001_create(&self, body: &serde_json::Value) -> Result<()> {
+        Orders::deprecated_202001_create(self, body).await
+    }
+    async fn deprecated_202001_get_param(&self, order_id: &str, fields: &str) -> Result<()> {
+        Orders::deprecated_202001_get_param(self, order_id, fields).await
+    }
+    async fn deprecated_202001_update_param(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202001_update_param(self, order_id, body).await
+    }
+    async fn deprecated_202001_delete_param(&self, order_id: &str) -> Result<()> {
+        Orders::deprecated_202001_delete_param(self, order_id).await
+    }
+    async fn deprecated_202001_delete_param_if_exists(&self, order_id: &str) -> Result<bool> {
+        Orders::deprecated_202001_delete_param_if_exists(self, order_id).await
+    }
+    async fn deprecated_202001_get_count(
+        &self,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        status: &str,
+        financial_status: &str,
+        fulfillment_status: &str,
+    ) -> Result<()> {
+        Orders::deprecated_202001_get_count(
+            self,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            status,
+            financial_status,
+            fulfillment_status,
+        )
+        .await
+    }
+    async fn deprecated_202001_create_param_close(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202001_create_param_close(self, order_id, body).await
+    }
+    async fn deprecated_202001_create_param_open(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202001_create_param_open(self, order_id, body).await
+    }
+    async fn deprecated_202001_create_param_cancel(
+        &self,
+        order_id: &str,
+        amount: &str,
+        currency: &str,
+        restock_deprecated: &str,
+        reason: &str,
+        email: &str,
+        refund: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202001_create_param_cancel(
+            self,
+            order_id,
+            amount,
+            currency,
+            restock_deprecated,
+            reason,
+            email,
+            refund,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202004_get(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        processed_at_min: &str,
+        processed_at_max: &str,
+        attribution_app_id: &str,
+        status: &str,
+        financial_status: &str,
+        fulfillment_status: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Orders::deprecated_202004_get(
+            self,
+            ids,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            processed_at_min,
+            processed_at_max,
+            attribution_app_id,
+            status,
+            financial_status,
+            fulfillment_status,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202004_create(&self, body: &serde_json::Value) -> Result<()> {
+        Orders::deprecated_202004_create(self, body).await
+    }
+    async fn deprecated_202004_get_param(&self, order_id: &str, fields: &str) -> Result<()> {
+        Orders::deprecated_202004_get_param(self, order_id, fields).await
+    }
+    async fn deprecated_202004_update_param(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202004_update_param(self, order_id, body).await
+    }
+    async fn deprecated_202004_delete_param(&self, order_id: &str) -> Result<()> {
+        Orders::deprecated_202004_delete_param(self, order_id).await
+    }
+    async fn deprecated_202004_delete_param_if_exists(&self, order_id: &str) -> Result<bool> {
+        Orders::deprecated_202004_delete_param_if_exists(self, order_id).await
+    }
+    async fn deprecated_202004_get_count(
+        &self,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        status: &str,
+        financial_status: &str,
+        fulfillment_status: &str,
+    ) -> Result<()> {
+        Orders::deprecated_202004_get_count(
+            self,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            status,
+            financial_status,
+            fulfillment_status,
+        )
+        .await
+    }
+    async fn deprecated_202004_create_param_close(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202004_create_param_close(self, order_id, body).await
+    }
+    async fn deprecated_202004_create_param_open(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202004_create_param_open(self, order_id, body).await
+    }
+    async fn deprecated_202004_create_param_cancel(
+        &self,
+        order_id: &str,
+        amount: &str,
+        currency: &str,
+        restock_deprecated: &str,
+        reason: &str,
+        email: &str,
+        refund: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202004_create_param_cancel(
+            self,
+            order_id,
+            amount,
+            currency,
+            restock_deprecated,
+            reason,
+            email,
+            refund,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202007_get(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        processed_at_min: &str,
+        processed_at_max: &str,
+        attribution_app_id: &str,
+        status: &str,
+        financial_status: &str,
+        fulfillment_status: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Orders::deprecated_202007_get(
+            self,
+            ids,
+            limit,
+            since_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            processed_at_min,
+            processed_at_max,
+            attribution_app_id,
+            status,
+            financial_status,
+            fulfillment_status,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202001_get_param_risk(&self, order_id: &str) -> Result<()> {
+        Orders::deprecated_202001_get_param_risk(self, order_id).await
+    }
+    async fn deprecated_202001_create_param_risks(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202001_create_param_risks(self, order_id, body).await
+    }
+    async fn deprecated_202001_get_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<()> {
+        Orders::deprecated_202001_get_param_risks_risk(self, order_id, risk_id).await
+    }
+    async fn deprecated_202001_update_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202001_update_param_risks_risk(self, order_id, risk_id, body).await
+    }
+    async fn deprecated_202001_delete_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<()> {
+        Orders::deprecated_202001_delete_param_risks_risk(self, order_id, risk_id).await
+    }
+    async fn deprecated_202001_delete_param_risks_risk_if_exists(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<bool> {
+        Orders::deprecated_202001_delete_param_risks_risk_if_exists(self, order_id, risk_id).await
+    }
+    async fn deprecated_202004_get_param_risk(&self, order_id: &str) -> Result<()> {
+        Orders::deprecated_202004_get_param_risk(self, order_id).await
+    }
+    async fn deprecated_202004_create_param_risks(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202004_create_param_risks(self, order_id, body).await
+    }
+    async fn deprecated_202004_get_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<()> {
+        Orders::deprecated_202004_get_param_risks_risk(self, order_id, risk_id).await
+    }
+    async fn deprecated_202004_update_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202004_update_param_risks_risk(self, order_id, risk_id, body).await
+    }
+    async fn deprecated_202004_delete_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<()> {
+        Orders::deprecated_202004_delete_param_risks_risk(self, order_id, risk_id).await
+    }
+    async fn deprecated_202004_delete_param_risks_risk_if_exists(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<bool> {
+        Orders::deprecated_202004_delete_param_risks_risk_if_exists(self, order_id, risk_id).await
+    }
+    async fn deprecated_202007_get_param_risk(&self, order_id: &str) -> Result<()> {
+        Orders::deprecated_202007_get_param_risk(self, order_id).await
+    }
+    async fn deprecated_202007_create_param_risks(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202007_create_param_risks(self, order_id, body).await
+    }
+    async fn deprecated_202007_get_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<()> {
+        Orders::deprecated_202007_get_param_risks_risk(self, order_id, risk_id).await
+    }
+    async fn deprecated_202007_update_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202007_update_param_risks_risk(self, order_id, risk_id, body).await
+    }
+    async fn deprecated_202007_delete_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<()> {
+        Orders::deprecated_202007_delete_param_risks_risk(self, order_id, risk_id).await
+    }
+    async fn deprecated_202007_delete_param_risks_risk_if_exists(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<bool> {
+        Orders::deprecated_202007_delete_param_risks_risk_if_exists(self, order_id, risk_id).await
+    }
+    async fn get_param_risk(&self, order_id: &str) -> Result<()> {
+        Orders::get_param_risk(self, order_id).await
+    }
+    async fn create_param_risks(&self, order_id: &str, body: &serde_json::Value) -> Result<()> {
+        Orders::create_param_risks(self, order_id, body).await
+    }
+    async fn get_param_risks_risk(&self, order_id: &str, risk_id: &str) -> Result<()> {
+        Orders::get_param_risks_risk(self, order_id, risk_id).await
+    }
+    async fn update_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::update_param_risks_risk(self, order_id, risk_id, body).await
+    }
+    async fn delete_param_risks_risk(&self, order_id: &str, risk_id: &str) -> Result<()> {
+        Orders::delete_param_risks_risk(self, order_id, risk_id).await
+    }
+    async fn delete_param_risks_risk_if_exists(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<bool> {
+        Orders::delete_param_risks_risk_if_exists(self, order_id, risk_id).await
+    }
+    async fn deprecated_202101_get_param_risk(&self, order_id: &str) -> Result<()> {
+        Orders::deprecated_202101_get_param_risk(self, order_id).await
+    }
+    async fn deprecated_202101_create_param_risks(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202101_create_param_risks(self, order_id, body).await
+    }
+    async fn deprecated_202101_get_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<()> {
+        Orders::deprecated_202101_get_param_risks_risk(self, order_id, risk_id).await
+    }
+    async fn deprecated_202101_update_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202101_update_param_risks_risk(self, order_id, risk_id, body).await
+    }
+    async fn deprecated_202101_delete_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<()> {
+        Orders::deprecated_202101_delete_param_risks_risk(self, order_id, risk_id).await
+    }
+    async fn deprecated_202101_delete_param_risks_risk_if_exists(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<bool> {
+        Orders::deprecated_202101_delete_param_risks_risk_if_exists(self, order_id, risk_id).await
+    }
+    async fn deprecated_unstable_get_param_risk(&self, order_id: &str) -> Result<()> {
+        Orders::deprecated_unstable_get_param_risk(self, order_id).await
+    }
+    async fn deprecated_unstable_create_param_risks(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_unstable_create_param_risks(self, order_id, body).await
+    }
+    async fn deprecated_unstable_get_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<()> {
+        Orders::deprecated_unstable_get_param_risks_risk(self, order_id, risk_id).await
+    }
+    async fn deprecated_unstable_update_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_unstable_update_param_risks_risk(self, order_id, risk_id, body).await
+    }
+    async fn deprecated_unstable_delete_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<()> {
+        Orders::deprecated_unstable_delete_param_risks_risk(self, order_id, risk_id).await
+    }
+    async fn deprecated_unstable_delete_param_risks_risk_if_exists(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<bool> {
+        Orders::deprecated_unstable_delete_param_risks_risk_if_exists(self, order_id, risk_id).await
+    }
+    async fn deprecated_202001_get_param_refund(
+        &self,
+        order_id: &str,
+        limit: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> Result<()> {
+        Orders::deprecated_202001_get_param_refund(self, order_id, limit, fields, in_shop_currency)
+            .await
+    }
+    async fn deprecated_202001_create_param_refunds(
+        &self,
+        order_id: &str,
+        restock_deprecated: &str,
+        notify: &str,
+        note: &str,
+        discrepancy_reason: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        transactions: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202001_create_param_refunds(
+            self,
+            order_id,
+            restock_deprecated,
+            notify,
+            note,
+            discrepancy_reason,
+            shipping,
+            refund_line_items,
+            transactions,
+            currency,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202001_get_param_refunds_refund(
+        &self,
+        order_id: &str,
+        refund_id: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> Result<()> {
+        Orders::deprecated_202001_get_param_refunds_refund(
+            self,
+            order_id,
+            refund_id,
+            fields,
+            in_shop_currency,
+        )
+        .await
+    }
+    async fn deprecated_202001_create_param_refunds_calculate(
+        &self,
+        order_id: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202001_create_param_refunds_calculate(
+            self,
+            order_id,
+            shipping,
+            refund_line_items,
+            currency,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_param_refund(
+        &self,
+        order_id: &str,
+        limit: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> Result<()> {
+        Orders::deprecated_202004_get_param_refund(self, order_id, limit, fields, in_shop_currency)
+            .await
+    }
+    async fn deprecated_202004_create_param_refunds(
+        &self,
+        order_id: &str,
+        restock_deprecated: &str,
+        notify: &str,
+        note: &str,
+        discrepancy_reason: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        transactions: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202004_create_param_refunds(
+            self,
+            order_id,
+            restock_deprecated,
+            notify,
+            note,
+            discrepancy_reason,
+            shipping,
+            refund_line_items,
+            transactions,
+            currency,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_param_refunds_refund(
+        &self,
+        order_id: &str,
+        refund_id: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> Result<()> {
+        Orders::deprecated_202004_get_param_refunds_refund(
+            self,
+            order_id,
+            refund_id,
+            fields,
+            in_shop_currency,
+        )
+        .await
+    }
+    async fn deprecated_202004_create_param_refunds_calculate(
+        &self,
+        order_id: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202004_create_param_refunds_calculate(
+            self,
+            order_id,
+            shipping,
+            refund_line_items,
+            currency,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_param_refund(
+        &self,
+        order_id: &str,
+        limit: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> Result<()> {
+        Orders::deprecated_202007_get_param_refund(self, order_id, limit, fields, in_shop_currency)
+            .await
+    }
+    async fn deprecated_202007_create_param_refunds(
+        &self,
+        order_id: &str,
+        restock_deprecated: &str,
+        notify: &str,
+        note: &str,
+        discrepancy_reason: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        transactions: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202007_create_param_refunds(
+            self,
+            order_id,
+            restock_deprecated,
+            notify,
+            note,
+            discrepancy_reason,
+            shipping,
+            refund_line_items,
+            transactions,
+            currency,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_param_refunds_refund(
+        &self,
+        order_id: &str,
+        refund_id: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> Result<()> {
+        Orders::deprecated_202007_get_param_refunds_refund(
+            self,
+            order_id,
+            refund_id,
+            fields,
+            in_shop_currency,
+        )
+        .await
+    }
+    async fn deprecated_202007_create_param_refunds_calculate(
+        &self,
+        order_id: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202007_create_param_refunds_calculate(
+            self,
+            order_id,
+            shipping,
+            refund_line_items,
+            currency,
+            body,
+        )
+        .await
+    }
+    async fn get_param_refund(
+        &self,
+        order_id: &str,
+        limit: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> Result<()> {
+        Orders::get_param_refund(self, order_id, limit, fields, in_shop_currency).await
+    }
+    async fn create_param_refunds(
+        &self,
+        order_id: &str,
+        restock_deprecated: &str,
+        notify: &str,
+        note: &str,
+        discrepancy_reason: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        transactions: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::create_param_refunds(
+            self,
+            order_id,
+            restock_deprecated,
+            notify,
+            note,
+            discrepancy_reason,
+            shipping,
+            refund_line_items,
+            transactions,
+            currency,
+            body,
+        )
+        .await
+    }
+    async fn get_param_refunds_refund(
+        &self,
+        order_id: &str,
+        refund_id: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> Result<()> {
+        Orders::get_param_refunds_refund(self, order_id, refund_id, fields, in_shop_currency).await
+    }
+    async fn create_param_refunds_calculate(
+        &self,
+        order_id: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::create_param_refunds_calculate(
+            self,
+            order_id,
+            shipping,
+            refund_line_items,
+            currency,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202101_get_param_refund(
+        &self,
+        order_id: &str,
+        limit: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> Result<()> {
+        Orders::deprecated_202101_get_param_refund(self, order_id, limit, fields, in_shop_currency)
+            .await
+    }
+    async fn deprecated_202101_create_param_refunds(
+        &self,
+        order_id: &str,
+        restock_deprecated: &str,
+        notify: &str,
+        note: &str,
+        discrepancy_reason: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        transactions: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202101_create_param_refunds(
+            self,
+            order_id,
+            restock_deprecated,
+            notify,
+            note,
+            discrepancy_reason,
+            shipping,
+            refund_line_items,
+            transactions,
+            currency,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202101_get_param_refunds_refund(
+        &self,
+        order_id: &str,
+        refund_id: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> Result<()> {
+        Orders::deprecated_202101_get_param_refunds_refund(
+            self,
+            order_id,
+            refund_id,
+            fields,
+            in_shop_currency,
+        )
+        .await
+    }
+    async fn deprecated_202101_create_param_refunds_calculate(
+        &self,
+        order_id: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202101_create_param_refunds_calculate(
+            self,
+            order_id,
+            shipping,
+            refund_line_items,
+            currency,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_param_refund(
+        &self,
+        order_id: &str,
+        limit: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> Result<()> {
+        Orders::deprecated_unstable_get_param_refund(
+            self,
+            order_id,
+            limit,
+            fields,
+            in_shop_currency,
+        )
+        .await
+    }
+    async fn deprecated_unstable_create_param_refunds(
+        &self,
+        order_id: &str,
+        restock_deprecated: &str,
+        notify: &str,
+        note: &str,
+        discrepancy_reason: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        transactions: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_unstable_create_param_refunds(
+            self,
+            order_id,
+            restock_deprecated,
+            notify,
+            note,
+            discrepancy_reason,
+            shipping,
+            refund_line_items,
+            transactions,
+            currency,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_param_refunds_refund(
+        &self,
+        order_id: &str,
+        refund_id: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> Result<()> {
+        Orders::deprecated_unstable_get_param_refunds_refund(
+            self,
+            order_id,
+            refund_id,
+            fields,
+            in_shop_currency,
+        )
+        .await
+    }
+    async fn deprecated_unstable_create_param_refunds_calculate(
+        &self,
+        order_id: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_unstable_create_param_refunds_calculate(
+            self,
+            order_id,
+            shipping,
+            refund_line_items,
+            currency,
+            body,
+        )
+        .await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Plus {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Plus {
         Plus { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Plus {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieves a list of gift cards. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -37,23 +43,24 @@ impl Plus {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/gift_cards.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-01/gift_cards.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/gift_cards.json", &[])
+        })
     }
 
     /**
@@ -69,8 +76,11 @@ impl Plus {
     ) -> Result<()> {
         let url = "/admin/api/2020-01/gift_cards.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-01/gift_cards.json", &[])
+            })
     }
 
     /**
@@ -90,10 +100,16 @@ impl Plus {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/gift_cards/{}/json",
-            crate::progenitor_support::encode_path(&gift_card_id.to_string()),
+            self.client.encode_path(gift_card_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/gift_cards/{gift_card_id}.json",
+                &[("gift_card_id", gift_card_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -115,12 +131,19 @@ impl Plus {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/gift_cards/{}/json",
-            crate::progenitor_support::encode_path(&gift_card_id.to_string()),
+            self.client.encode_path(gift_card_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-01/gift_cards/{gift_card_id}.json",
+                    &[("gift_card_id", gift_card_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -138,14 +161,16 @@ impl Plus {
      *                           disabled: Count only disabled gift cards.
      */
     pub async fn deprecated_202001_get_gift_cards_count(&self, status: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/gift_cards/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-01/gift_cards/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/gift_cards/count.json", &[])
+        })
     }
 
     /**
@@ -166,12 +191,19 @@ impl Plus {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/gift_cards/{}/disable.json",
-            crate::progenitor_support::encode_path(&gift_card_id.to_string()),
+            self.client.encode_path(gift_card_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-01/gift_cards/{gift_card_id}/disable.json",
+                    &[("gift_card_id", gift_card_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -208,23 +240,25 @@ impl Plus {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/gift_cards/search.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-01/gift_cards/search.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/gift_cards/search.json", &[])
+        })
     }
 
     /**
@@ -252,23 +286,24 @@ impl Plus {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/gift_cards.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-04/gift_cards.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/gift_cards.json", &[])
+        })
     }
 
     /**
@@ -284,8 +319,11 @@ impl Plus {
     ) -> Result<()> {
         let url = "/admin/api/2020-04/gift_cards.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-04/gift_cards.json", &[])
+            })
     }
 
     /**
@@ -305,10 +343,16 @@ impl Plus {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/gift_cards/{}/json",
-            crate::progenitor_support::encode_path(&gift_card_id.to_string()),
+            self.client.encode_path(gift_card_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/gift_cards/{gift_card_id}.json",
+                &[("gift_card_id", gift_card_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -330,12 +374,19 @@ impl Plus {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/gift_cards/{}/json",
-            crate::progenitor_support::encode_path(&gift_card_id.to_string()),
+            self.client.encode_path(gift_card_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-04/gift_cards/{gift_card_id}.json",
+                    &[("gift_card_id", gift_card_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -353,14 +404,16 @@ impl Plus {
      *                           disabled: Count only disabled gift cards.
      */
     pub async fn deprecated_202004_get_gift_cards_count(&self, status: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/gift_cards/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-04/gift_cards/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/gift_cards/count.json", &[])
+        })
     }
 
     /**
@@ -381,12 +434,19 @@ impl Plus {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/gift_cards/{}/disable.json",
-            crate::progenitor_support::encode_path(&gift_card_id.to_string()),
+            self.client.encode_path(gift_card_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-04/gift_cards/{gift_card_id}/disable.json",
+                    &[("gift_card_id", gift_card_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -423,23 +483,25 @@ impl Plus {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/gift_cards/search.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-04/gift_cards/search.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/gift_cards/search.json", &[])
+        })
     }
 
     /**
@@ -467,23 +529,24 @@ impl Plus {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/gift_cards.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-07/gift_cards.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/gift_cards.json", &[])
+        })
     }
 
     /**
@@ -499,8 +562,11 @@ impl Plus {
     ) -> Result<()> {
         let url = "/admin/api/2020-07/gift_cards.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-07/gift_cards.json", &[])
+            })
     }
 
     /**
@@ -520,10 +586,16 @@ impl Plus {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/gift_cards/{}/json",
-            crate::progenitor_support::encode_path(&gift_card_id.to_string()),
+            self.client.encode_path(gift_card_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/gift_cards/{gift_card_id}.json",
+                &[("gift_card_id", gift_card_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -545,12 +617,19 @@ impl Plus {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/gift_cards/{}/json",
-            crate::progenitor_support::encode_path(&gift_card_id.to_string()),
+            self.client.encode_path(gift_card_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-07/gift_cards/{gift_card_id}.json",
+                    &[("gift_card_id", gift_card_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -568,14 +647,16 @@ impl Plus {
      *                           disabled: Count only disabled gift cards.
      */
     pub async fn deprecated_202007_get_gift_cards_count(&self, status: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/gift_cards/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-07/gift_cards/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/gift_cards/count.json", &[])
+        })
     }
 
     /**
@@ -596,12 +677,19 @@ impl Plus {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/gift_cards/{}/disable.json",
-            crate::progenitor_support::encode_path(&gift_card_id.to_string()),
+            self.client.encode_path(gift_card_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-07/gift_cards/{gift_card_id}/disable.json",
+                    &[("gift_card_id", gift_card_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -638,23 +726,25 @@ impl Plus {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/gift_cards/search.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-07/gift_cards/search.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/gift_cards/search.json", &[])
+        })
     }
 
     /**
@@ -682,23 +772,24 @@ impl Plus {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/gift_cards.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-10/gift_cards.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/gift_cards.json", &[])
+        })
     }
 
     /**
@@ -711,8 +802,11 @@ impl Plus {
     pub async fn create_gift_cards(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-10/gift_cards.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-10/gift_cards.json", &[])
+            })
     }
 
     /**
@@ -729,10 +823,16 @@ impl Plus {
     pub async fn get_gift_cards_param_card(&self, gift_card_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/gift_cards/{}/json",
-            crate::progenitor_support::encode_path(&gift_card_id.to_string()),
+            self.client.encode_path(gift_card_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/gift_cards/{gift_card_id}.json",
+                &[("gift_card_id", gift_card_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -754,12 +854,19 @@ impl Plus {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/gift_cards/{}/json",
-            crate::progenitor_support::encode_path(&gift_card_id.to_string()),
+            self.client.encode_path(gift_card_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-10/gift_cards/{gift_card_id}.json",
+                    &[("gift_card_id", gift_card_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -777,14 +884,16 @@ impl Plus {
      *                           disabled: Count only disabled gift cards.
      */
     pub async fn get_gift_cards_count(&self, status: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/gift_cards/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-10/gift_cards/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/gift_cards/count.json", &[])
+        })
     }
 
     /**
@@ -805,12 +914,19 @@ impl Plus {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/gift_cards/{}/disable.json",
-            crate::progenitor_support::encode_path(&gift_card_id.to_string()),
+            self.client.encode_path(gift_card_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-10/gift_cards/{gift_card_id}/disable.json",
+                    &[("gift_card_id", gift_card_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -847,23 +963,25 @@ impl Plus {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/gift_cards/search.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-10/gift_cards/search.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/gift_cards/search.json", &[])
+        })
     }
 
     /**
@@ -891,23 +1009,24 @@ impl Plus {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/gift_cards.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2021-01/gift_cards.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/gift_cards.json", &[])
+        })
     }
 
     /**
@@ -923,8 +1042,11 @@ impl Plus {
     ) -> Result<()> {
         let url = "/admin/api/2021-01/gift_cards.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2021-01/gift_cards.json", &[])
+            })
     }
 
     /**
@@ -944,10 +1066,16 @@ impl Plus {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/gift_cards/{}/json",
-            crate::progenitor_support::encode_path(&gift_card_id.to_string()),
+            self.client.encode_path(gift_card_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/gift_cards/{gift_card_id}.json",
+                &[("gift_card_id", gift_card_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -969,12 +1097,19 @@ impl Plus {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/gift_cards/{}/json",
-            crate::progenitor_support::encode_path(&gift_card_id.to_string()),
+            self.client.encode_path(gift_card_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2021-01/gift_cards/{gift_card_id}.json",
+                    &[("gift_card_id", gift_card_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -992,14 +1127,16 @@ impl Plus {
      *                           disabled: Count only disabled gift cards.
      */
     pub async fn deprecated_202101_get_gift_cards_count(&self, status: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/gift_cards/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2021-01/gift_cards/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/gift_cards/count.json", &[])
+        })
     }
 
     /**
@@ -1020,12 +1157,19 @@ impl Plus {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/gift_cards/{}/disable.json",
-            crate::progenitor_support::encode_path(&gift_card_id.to_string()),
+            self.client.encode_path(gift_card_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2021-01/gift_cards/{gift_card_id}/disable.json",
+                    &[("gift_card_id", gift_card_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1062,23 +1206,25 @@ impl Plus {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/gift_cards/search.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2021-01/gift_cards/search.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/gift_cards/search.json", &[])
+        })
     }
 
     /**
@@ -1106,23 +1252,24 @@ impl Plus {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/gift_cards.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/unstable/gift_cards.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/gift_cards.json", &[])
+        })
     }
 
     /**
@@ -1138,8 +1285,11 @@ impl Plus {
     ) -> Result<()> {
         let url = "/admin/api/unstable/gift_cards.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/unstable/gift_cards.json", &[])
+            })
     }
 
     /**
@@ -1159,10 +1309,16 @@ impl Plus {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/gift_cards/{}/json",
-            crate::progenitor_support::encode_path(&gift_card_id.to_string()),
+            self.client.encode_path(gift_card_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/gift_cards/{gift_card_id}.json",
+                &[("gift_card_id", gift_card_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1184,12 +1340,19 @@ impl Plus {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/gift_cards/{}/json",
-            crate::progenitor_support::encode_path(&gift_card_id.to_string()),
+            self.client.encode_path(gift_card_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/unstable/gift_cards/{gift_card_id}.json",
+                    &[("gift_card_id", gift_card_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1207,14 +1370,16 @@ impl Plus {
      *                           disabled: Count only disabled gift cards.
      */
     pub async fn deprecated_unstable_get_gift_cards_count(&self, status: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/gift_cards/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/unstable/gift_cards/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/gift_cards/count.json", &[])
+        })
     }
 
     /**
@@ -1235,12 +1400,19 @@ impl Plus {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/gift_cards/{}/disable.json",
-            crate::progenitor_support::encode_path(&gift_card_id.to_string()),
+            self.client.encode_path(gift_card_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/unstable/gift_cards/{gift_card_id}/disable.json",
+                    &[("gift_card_id", gift_card_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1277,23 +1449,27 @@ impl Plus {
         limit: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/gift_cards/search.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/unstable/gift_cards/search.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/gift_cards/search.json", &[])
+        })
     }
 
     /**
@@ -1310,17 +1486,19 @@ impl Plus {
      * * `page_info: &str` -- A unique ID used to access a certain page of results.
      */
     pub async fn deprecated_202001_get_user(&self, limit: &str, page_info: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !page_info.is_empty() {
-            query_args.push(("page_info".to_string(), page_info.to_string()));
+            query_args.push(("page_info", page_info.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/users.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-01/users.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-01/users.json", &[]))
     }
 
     /**
@@ -1337,10 +1515,16 @@ impl Plus {
     pub async fn deprecated_202001_get_users_param_user(&self, user_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/users/{}/json",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(user_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/users/{user_id}.json",
+                &[("user_id", user_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1352,7 +1536,9 @@ impl Plus {
      */
     pub async fn deprecated_202001_get_users_current(&self) -> Result<()> {
         let url = "/admin/api/2020-01/users/current.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/users/current.json", &[])
+        })
     }
 
     /**
@@ -1369,17 +1555,19 @@ impl Plus {
      * * `page_info: &str` -- A unique ID used to access a certain page of results.
      */
     pub async fn deprecated_202004_get_user(&self, limit: &str, page_info: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !page_info.is_empty() {
-            query_args.push(("page_info".to_string(), page_info.to_string()));
+            query_args.push(("page_info", page_info.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/users.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-04/users.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-04/users.json", &[]))
     }
 
     /**
@@ -1396,10 +1584,16 @@ impl Plus {
     pub async fn deprecated_202004_get_users_param_user(&self, user_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/users/{}/json",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(user_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/users/{user_id}.json",
+                &[("user_id", user_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1411,7 +1605,9 @@ impl Plus {
      */
     pub async fn deprecated_202004_get_users_current(&self) -> Result<()> {
         let url = "/admin/api/2020-04/users/current.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/users/current.json", &[])
+        })
     }
 
     /**
@@ -1428,17 +1624,19 @@ impl Plus {
      * * `page_info: &str` -- A unique ID used to access a certain page of results.
      */
     pub async fn deprecated_202007_get_user(&self, limit: &str, page_info: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !page_info.is_empty() {
-            query_args.push(("page_info".to_string(), page_info.to_string()));
+            query_args.push(("page_info", page_info.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/users.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-07/users.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-07/users.json", &[]))
     }
 
     /**
@@ -1455,10 +1653,16 @@ impl Plus {
     pub async fn deprecated_202007_get_users_param_user(&self, user_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/users/{}/json",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(user_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/users/{user_id}.json",
+                &[("user_id", user_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1470,7 +1674,9 @@ impl Plus {
      */
     pub async fn deprecated_202007_get_users_current(&self) -> Result<()> {
         let url = "/admin/api/2020-07/users/current.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/users/current.json", &[])
+        })
     }
 
     /**
@@ -1487,17 +1693,19 @@ impl Plus {
      * * `page_info: &str` -- A unique ID used to access a certain page of results.
      */
     pub async fn get_user(&self, limit: &str, page_info: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !page_info.is_empty() {
-            query_args.push(("page_info".to_string(), page_info.to_string()));
+            query_args.push(("page_info", page_info.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/users.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-10/users.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-10/users.json", &[]))
     }
 
     /**
@@ -1514,10 +1722,16 @@ impl Plus {
     pub async fn get_users_param_user(&self, user_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/users/{}/json",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(user_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/users/{user_id}.json",
+                &[("user_id", user_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1529,7 +1743,9 @@ impl Plus {
      */
     pub async fn get_users_current(&self) -> Result<()> {
         let url = "/admin/api/2020-10/users/current.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/users/current.json", &[])
+        })
     }
 
     /**
@@ -1546,17 +1762,19 @@ impl Plus {
      * * `page_info: &str` -- A unique ID used to access a certain page of results.
      */
     pub async fn deprecated_202101_get_user(&self, limit: &str, page_info: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !page_info.is_empty() {
-            query_args.push(("page_info".to_string(), page_info.to_string()));
+            query_args.push(("page_info", page_info.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/users.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2021-01/users.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/admin/api/2021-01/users.json", &[]))
     }
 
     /**
@@ -1573,10 +1791,16 @@ impl Plus {
     pub async fn deprecated_202101_get_users_param_user(&self, user_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/users/{}/json",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(user_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/users/{user_id}.json",
+                &[("user_id", user_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1588,7 +1812,9 @@ impl Plus {
      */
     pub async fn deprecated_202101_get_users_current(&self) -> Result<()> {
         let url = "/admin/api/2021-01/users/current.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/users/current.json", &[])
+        })
     }
 
     /**
@@ -1605,17 +1831,19 @@ impl Plus {
      * * `page_info: &str` -- A unique ID used to access a certain page of results.
      */
     pub async fn deprecated_unstable_get_user(&self, limit: &str, page_info: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !page_info.is_empty() {
-            query_args.push(("page_info".to_string(), page_info.to_string()));
+            query_args.push(("page_info", page_info.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/users.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/unstable/users.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/admin/api/unstable/users.json", &[]))
     }
 
     /**
@@ -1632,10 +1860,16 @@ impl Plus {
     pub async fn deprecated_unstable_get_users_param_user(&self, user_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/users/{}/json",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(user_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/users/{user_id}.json",
+                &[("user_id", user_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1647,6 +1881,506 @@ impl Plus {
      */
     pub async fn deprecated_unstable_get_users_current(&self) -> Result<()> {
         let url = "/admin/api/unstable/users/current.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/users/current.json", &[])
+        })
+    }
+}
+
+/// The functions of [`Plus`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn PlusApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait PlusApi: Send + Sync {
+    async fn deprecated_202001_get_gift_card(
+        &self,
+        status: &str,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_gift_cards(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202001_get_gift_cards_param_card(&self, gift_card_id: &str) -> Result<()>;
+    async fn deprecated_202001_update_gift_cards_param_card(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_gift_cards_count(&self, status: &str) -> Result<()>;
+    async fn deprecated_202001_create_gift_cards_param_card_disable(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_gift_cards_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_gift_card(
+        &self,
+        status: &str,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_create_gift_cards(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202004_get_gift_cards_param_card(&self, gift_card_id: &str) -> Result<()>;
+    async fn deprecated_202004_update_gift_cards_param_card(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_gift_cards_count(&self, status: &str) -> Result<()>;
+    async fn deprecated_202004_create_gift_cards_param_card_disable(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_gift_cards_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_gift_card(
+        &self,
+        status: &str,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_create_gift_cards(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202007_get_gift_cards_param_card(&self, gift_card_id: &str) -> Result<()>;
+    async fn deprecated_202007_update_gift_cards_param_card(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_gift_cards_count(&self, status: &str) -> Result<()>;
+    async fn deprecated_202007_create_gift_cards_param_card_disable(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_gift_cards_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn get_gift_card(
+        &self,
+        status: &str,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn create_gift_cards(&self, body: &serde_json::Value) -> Result<()>;
+    async fn get_gift_cards_param_card(&self, gift_card_id: &str) -> Result<()>;
+    async fn update_gift_cards_param_card(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn get_gift_cards_count(&self, status: &str) -> Result<()>;
+    async fn create_gift_cards_param_card_disable(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn get_gift_cards_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_gift_card(
+        &self,
+        status: &str,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_create_gift_cards(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202101_get_gift_cards_param_card(&self, gift_card_id: &str) -> Result<()>;
+    async fn deprecated_202101_update_gift_cards_param_card(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_gift_cards_count(&self, status: &str) -> Result<()>;
+    async fn deprecated_202101_create_gift_cards_param_card_disable(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_gift_cards_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_gift_card(
+        &self,
+        status: &str,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create_gift_cards(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_unstable_get_gift_cards_param_card(&self, gift_card_id: &str)
+        -> Result<()>;
+    async fn deprecated_unstable_update_gift_cards_param_card(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_gift_cards_count(&self, status: &str) -> Result<()>;
+    async fn deprecated_unstable_create_gift_cards_param_card_disable(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_gift_cards_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_user(&self, limit: &str, page_info: &str) -> Result<()>;
+    async fn deprecated_202001_get_users_param_user(&self, user_id: &str) -> Result<()>;
+    async fn deprecated_202001_get_users_current(&self) -> Result<()>;
+    async fn deprecated_202004_get_user(&self, limit: &str, page_info: &str) -> Result<()>;
+    async fn deprecated_202004_get_users_param_user(&self, user_id: &str) -> Result<()>;
+    async fn deprecated_202004_get_users_current(&self) -> Result<()>;
+    async fn deprecated_202007_get_user(&self, limit: &str, page_info: &str) -> Result<()>;
+    async fn deprecated_202007_get_users_param_user(&self, user_id: &str) -> Result<()>;
+    async fn deprecated_202007_get_users_current(&self) -> Result<()>;
+    async fn get_user(&self, limit: &str, page_info: &str) -> Result<()>;
+    async fn get_users_param_user(&self, user_id: &str) -> Result<()>;
+    async fn get_users_current(&self) -> Result<()>;
+    async fn deprecated_202101_get_user(&self, limit: &str, page_info: &str) -> Result<()>;
+    async fn deprecated_202101_get_users_param_user(&self, user_id: &str) -> Result<()>;
+    async fn deprecated_202101_get_users_current(&self) -> Result<()>;
+    async fn deprecated_unstable_get_user(&self, limit: &str, page_info: &str) -> Result<()>;
+    async fn deprecated_unstable_get_users_param_user(&self, user_id: &str) -> Result<()>;
+    async fn deprecated_unstable_get_users_current(&self) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl PlusApi for Plus {
+    async fn deprecated_202001_get_gift_card(
+        &self,
+        status: &str,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Plus::deprecated_202001_get_gift_card(self, status, limit, since_id, fields).await
+    }
+    async fn deprecated_202001_create_gift_cards(&self, body: &serde_json::Value) -> Result<()> {
+        Plus::deprecated_202001_create_gift_cards(self, body).await
+    }
+    async fn deprecated_202001_get_gift_cards_param_card(&self, gift_card_id: &str) -> Result<()> {
+        Plus::deprecated_202001_get_gift_cards_param_card(self, gift_card_id).await
+    }
+    async fn deprecated_202001_update_gift_cards_param_card(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Plus::deprecated_202001_update_gift_cards_param_card(self, gift_card_id, body).await
+    }
+    async fn deprecated_202001_get_gift_cards_count(&self, status: &str) -> Result<()> {
+        Plus::deprecated_202001_get_gift_cards_count(self, status).await
+    }
+    async fn deprecated_202001_create_gift_cards_param_card_disable(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Plus::deprecated_202001_create_gift_cards_param_card_disable(self, gift_card_id, body).await
+    }
+    async fn deprecated_202001_get_gift_cards_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Plus::deprecated_202001_get_gift_cards_search(self, order, query, limit, fields).await
+    }
+    async fn deprecated_202004_get_gift_card(
+        &self,
+        status: &str,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Plus::deprecated_202004_get_gift_card(self, status, limit, since_id, fields).await
+    }
+    async fn deprecated_202004_create_gift_cards(&self, body: &serde_json::Value) -> Result<()> {
+        Plus::deprecated_202004_create_gift_cards(self, body).await
+    }
+    async fn deprecated_202004_get_gift_cards_param_card(&self, gift_card_id: &str) -> Result<()> {
+        Plus::deprecated_202004_get_gift_cards_param_card(self, gift_card_id).await
+    }
+    async fn deprecated_202004_update_gift_cards_param_card(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Plus::deprecated_202004_update_gift_cards_param_card(self, gift_card_id, body).await
+    }
+    async fn deprecated_202004_get_gift_cards_count(&self, status: &str) -> Result<()> {
+        Plus::deprecated_202004_get_gift_cards_count(self, status).await
+    }
+    async fn deprecated_202004_create_gift_cards_param_card_disable(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Plus::deprecated_202004_create_gift_cards_param_card_disable(self, gift_card_id, body).await
+    }
+    async fn deprecated_202004_get_gift_cards_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Plus::deprecated_202004_get_gift_cards_search(self, order, query, limit, fields).await
+    }
+    async fn deprecated_202007_get_gift_card(
+        &self,
+        status: &str,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Plus::deprecated_202007_get_gift_card(self, status, limit, since_id, fields).await
+    }
+    async fn deprecated_202007_create_gift_cards(&self, body: &serde_json::Value) -> Result<()> {
+        Plus::deprecated_202007_create_gift_cards(self, body).await
+    }
+    async fn deprecated_202007_get_gift_cards_param_card(&self, gift_card_id: &str) -> Result<()> {
+        Plus::deprecated_202007_get_gift_cards_param_card(self, gift_card_id).await
+    }
+    async fn deprecated_202007_update_gift_cards_param_card(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Plus::deprecated_202007_update_gift_cards_param_card(self, gift_card_id, body).await
+    }
+    async fn deprecated_202007_get_gift_cards_count(&self, status: &str) -> Result<()> {
+        Plus::deprecated_202007_get_gift_cards_count(self, status).await
+    }
+    async fn deprecated_202007_create_gift_cards_param_card_disable(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Plus::deprecated_202007_create_gift_cards_param_card_disable(self, gift_card_id, body).await
+    }
+    async fn deprecated_202007_get_gift_cards_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Plus::deprecated_202007_get_gift_cards_search(self, order, query, limit, fields).await
+    }
+    async fn get_gift_card(
+        &self,
+        status: &str,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Plus::get_gift_card(self, status, limit, since_id, fields).await
+    }
+    async fn create_gift_cards(&self, body: &serde_json::Value) -> Result<()> {
+        Plus::create_gift_cards(self, body).await
+    }
+    async fn get_gift_cards_param_card(&self, gift_card_id: &str) -> Result<()> {
+        Plus::get_gift_cards_param_card(self, gift_card_id).await
+    }
+    async fn update_gift_cards_param_card(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Plus::update_gift_cards_param_card(self, gift_card_id, body).await
+    }
+    async fn get_gift_cards_count(&self, status: &str) -> Result<()> {
+        Plus::get_gift_cards_count(self, status).await
+    }
+    async fn create_gift_cards_param_card_disable(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Plus::create_gift_cards_param_card_disable(self, gift_card_id, body).await
+    }
+    async fn get_gift_cards_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Plus::get_gift_cards_search(self, order, query, limit, fields).await
+    }
+    async fn deprecated_202101_get_gift_card(
+        &self,
+        status: &str,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Plus::deprecated_202101_get_gift_card(self, status, limit, since_id, fields).await
+    }
+    async fn deprecated_202101_create_gift_cards(&self, body: &serde_json::Value) -> Result<()> {
+        Plus::deprecated_202101_create_gift_cards(self, body).await
+    }
+    async fn deprecated_202101_get_gift_cards_param_card(&self, gift_card_id: &str) -> Result<()> {
+        Plus::deprecated_202101_get_gift_cards_param_card(self, gift_card_id).await
+    }
+    async fn deprecated_202101_update_gift_cards_param_card(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Plus::deprecated_202101_update_gift_cards_param_card(self, gift_card_id, body).await
+    }
+    async fn deprecated_202101_get_gift_cards_count(&self, status: &str) -> Result<()> {
+        Plus::deprecated_202101_get_gift_cards_count(self, status).await
+    }
+    async fn deprecated_202101_create_gift_cards_param_card_disable(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Plus::deprecated_202101_create_gift_cards_param_card_disable(self, gift_card_id, body).await
+    }
+    async fn deprecated_202101_get_gift_cards_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Plus::deprecated_202101_get_gift_cards_search(self, order, query, limit, fields).await
+    }
+    async fn deprecated_unstable_get_gift_card(
+        &self,
+        status: &str,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Plus::deprecated_unstable_get_gift_card(self, status, limit, since_id, fields).await
+    }
+    async fn deprecated_unstable_create_gift_cards(&self, body: &serde_json::Value) -> Result<()> {
+        Plus::deprecated_unstable_create_gift_cards(self, body).await
+    }
+    async fn deprecated_unstable_get_gift_cards_param_card(
+        &self,
+        gift_card_id: &str,
+    ) -> Result<()> {
+        Plus::deprecated_unstable_get_gift_cards_param_card(self, gift_card_id).await
+    }
+    async fn deprecated_unstable_update_gift_cards_param_card(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Plus::deprecated_unstable_update_gift_cards_param_card(self, gift_card_id, body).await
+    }
+    async fn deprecated_unstable_get_gift_cards_count(&self, status: &str) -> Result<()> {
+        Plus::deprecated_unstable_get_gift_cards_count(self, status).await
+    }
+    async fn deprecated_unstable_create_gift_cards_param_card_disable(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Plus::deprecated_unstable_create_gift_cards_param_card_disable(self, gift_card_id, body)
+            .await
+    }
+    async fn deprecated_unstable_get_gift_cards_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Plus::deprecated_unstable_get_gift_cards_search(self, order, query, limit, fields).await
+    }
+    async fn deprecated_202001_get_user(&self, limit: &str, page_info: &str) -> Result<()> {
+        Plus::deprecated_202001_get_user(self, limit, page_info).await
+    }
+    async fn deprecated_202001_get_users_param_user(&self, user_id: &str) -> Result<()> {
+        Plus::deprecated_202001_get_users_param_user(self, user_id).await
+    }
+    async fn deprecated_202001_get_users_current(&self) -> Result<()> {
+        Plus::deprecated_202001_get_users_current(self).await
+    }
+    async fn deprecated_202004_get_user(&self, limit: &str, page_info: &str) -> Result<()> {
+        Plus::deprecated_202004_get_user(self, limit, page_info).await
+    }
+    async fn deprecated_202004_get_users_param_user(&self, user_id: &str) -> Result<()> {
+        Plus::deprecated_202004_get_users_param_user(self, user_id).await
+    }
+    async fn deprecated_202004_get_users_current(&self) -> Result<()> {
+        Plus::deprecated_202004_get_users_current(self).await
+    }
+    async fn deprecated_202007_get_user(&self, limit: &str, page_info: &str) -> Result<()> {
+        Plus::deprecated_202007_get_user(self, limit, page_info).await
+    }
+    async fn deprecated_202007_get_users_param_user(&self, user_id: &str) -> Result<()> {
+        Plus::deprecated_202007_get_users_param_user(self, user_id).await
+    }
+    async fn deprecated_202007_get_users_current(&self) -> Result<()> {
+        Plus::deprecated_202007_get_users_current(self).await
+    }
+    async fn get_user(&self, limit: &str, page_info: &str) -> Result<()> {
+        Plus::get_user(self, limit, page_info).await
+    }
+    async fn get_users_param_user(&self, user_id: &str) -> Result<()> {
+        Plus::get_users_param_user(self, user_id).await
+    }
+    async fn get_users_current(&self) -> Result<()> {
+        Plus::get_users_current(self).await
+    }
+    async fn deprecated_202101_get_user(&self, limit: &str, page_info: &str) -> Result<()> {
+        Plus::deprecated_202101_get_user(self, limit, page_info).await
+    }
+    async fn deprecated_202101_get_users_param_user(&self, user_id: &str) -> Result<()> {
+        Plus::deprecated_202101_get_users_param_user(self, user_id).await
+    }
+    async fn deprecated_202101_get_users_current(&self) -> Result<()> {
+        Plus::deprecated_202101_get_users_current(self).await
+    }
+    async fn deprecated_unstable_get_user(&self, limit: &str, page_info: &str) -> Result<()> {
+        Plus::deprecated_unstable_get_user(self, limit, page_info).await
+    }
+    async fn deprecated_unstable_get_users_param_user(&self, user_id: &str) -> Result<()> {
+        Plus::deprecated_unstable_get_users_param_user(self, user_id).await
+    }
+    async fn deprecated_unstable_get_users_current(&self) -> Result<()> {
+        Plus::deprecated_unstable_get_users_current(self).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Products {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Products {
         Products { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Products {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieves a list of collects. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -32,20 +38,21 @@ impl Products {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/collects.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-01/collects.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/collects.json", &[])
+        })
     }
 
     /**
@@ -58,8 +65,11 @@ impl Products {
     pub async fn deprecated_202001_create_collects(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-01/collects.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-01/collects.json", &[])
+            })
     }
 
     /**
@@ -79,18 +89,25 @@ impl Products {
         collect_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/collects/{}/json?{}",
-            crate::progenitor_support::encode_path(&collect_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/collects/{}/json",
+                self.client.encode_path(collect_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/collects/{collect_id}.json",
+                &[("collect_id", collect_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -110,10 +127,44 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/collects/{}/json",
-            crate::progenitor_support::encode_path(&collect_id.to_string()),
+            self.client.encode_path(collect_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-01/collects/{collect_id}.json",
+                &[("collect_id", collect_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Removes a product from a collection.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-01/collects/{collect_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_delete_collects_param_collect`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202001_delete_collects_param_collect_if_exists(
+        &self,
+        collect_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-01/collects/{}/json",
+            self.client.encode_path(collect_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-01/collects/{collect_id}.json",
+                    &[("collect_id", collect_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -128,14 +179,16 @@ impl Products {
      * * `collection_id: i64` -- recurring_application_charge[capped_amount].
      */
     pub async fn deprecated_202001_get_collects_count(&self, collection_id: i64) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if collection_id > 0 {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/collects/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-01/collects/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/collects/count.json", &[])
+        })
     }
 
     /**
@@ -158,20 +211,21 @@ impl Products {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/collects.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-04/collects.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/collects.json", &[])
+        })
     }
 
     /**
@@ -184,8 +238,11 @@ impl Products {
     pub async fn deprecated_202004_create_collects(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-04/collects.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-04/collects.json", &[])
+            })
     }
 
     /**
@@ -205,18 +262,25 @@ impl Products {
         collect_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/collects/{}/json?{}",
-            crate::progenitor_support::encode_path(&collect_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/collects/{}/json",
+                self.client.encode_path(collect_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/collects/{collect_id}.json",
+                &[("collect_id", collect_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -236,10 +300,44 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/collects/{}/json",
-            crate::progenitor_support::encode_path(&collect_id.to_string()),
+            self.client.encode_path(collect_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-04/collects/{collect_id}.json",
+                &[("collect_id", collect_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Removes a product from a collection.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-04/collects/{collect_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_delete_collects_param_collect`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202004_delete_collects_param_collect_if_exists(
+        &self,
+        collect_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-04/collects/{}/json",
+            self.client.encode_path(collect_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-04/collects/{collect_id}.json",
+                    &[("collect_id", collect_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -254,14 +352,16 @@ impl Products {
      * * `collection_id: i64` -- recurring_application_charge[capped_amount].
      */
     pub async fn deprecated_202004_get_collects_count(&self, collection_id: i64) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if collection_id > 0 {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/collects/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-04/collects/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/collects/count.json", &[])
+        })
     }
 
     /**
@@ -284,20 +384,21 @@ impl Products {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/collects.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-07/collects.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/collects.json", &[])
+        })
     }
 
     /**
@@ -310,8 +411,11 @@ impl Products {
     pub async fn deprecated_202007_create_collects(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-07/collects.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-07/collects.json", &[])
+            })
     }
 
     /**
@@ -331,18 +435,25 @@ impl Products {
         collect_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/collects/{}/json?{}",
-            crate::progenitor_support::encode_path(&collect_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/collects/{}/json",
+                self.client.encode_path(collect_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/collects/{collect_id}.json",
+                &[("collect_id", collect_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -362,10 +473,44 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/collects/{}/json",
-            crate::progenitor_support::encode_path(&collect_id.to_string()),
+            self.client.encode_path(collect_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-07/collects/{collect_id}.json",
+                &[("collect_id", collect_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Removes a product from a collection.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-07/collects/{collect_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_delete_collects_param_collect`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202007_delete_collects_param_collect_if_exists(
+        &self,
+        collect_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-07/collects/{}/json",
+            self.client.encode_path(collect_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-07/collects/{collect_id}.json",
+                    &[("collect_id", collect_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -380,14 +525,16 @@ impl Products {
      * * `collection_id: i64` -- recurring_application_charge[capped_amount].
      */
     pub async fn deprecated_202007_get_collects_count(&self, collection_id: i64) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if collection_id > 0 {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/collects/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-07/collects/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/collects/count.json", &[])
+        })
     }
 
     /**
@@ -405,20 +552,21 @@ impl Products {
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
     pub async fn get_collect(&self, limit: &str, since_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/collects.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-10/collects.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/collects.json", &[])
+        })
     }
 
     /**
@@ -431,8 +579,11 @@ impl Products {
     pub async fn create_collects(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-10/collects.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-10/collects.json", &[])
+            })
     }
 
     /**
@@ -448,18 +599,25 @@ impl Products {
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
     pub async fn get_collects_param_collect(&self, collect_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/collects/{}/json?{}",
-            crate::progenitor_support::encode_path(&collect_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/collects/{}/json",
+                self.client.encode_path(collect_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/collects/{collect_id}.json",
+                &[("collect_id", collect_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -476,10 +634,41 @@ impl Products {
     pub async fn delete_collects_param_collect(&self, collect_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/collects/{}/json",
-            crate::progenitor_support::encode_path(&collect_id.to_string()),
+            self.client.encode_path(collect_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-10/collects/{collect_id}.json",
+                &[("collect_id", collect_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Removes a product from a collection.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-10/collects/{collect_id}.json` endpoint.
+     *
+     * As opposed to `delete_collects_param_collect`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_collects_param_collect_if_exists(&self, collect_id: &str) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-10/collects/{}/json",
+            self.client.encode_path(collect_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-10/collects/{collect_id}.json",
+                    &[("collect_id", collect_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -494,14 +683,16 @@ impl Products {
      * * `collection_id: i64` -- recurring_application_charge[capped_amount].
      */
     pub async fn get_collects_count(&self, collection_id: i64) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if collection_id > 0 {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/collects/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-10/collects/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/collects/count.json", &[])
+        })
     }
 
     /**
@@ -524,20 +715,21 @@ impl Products {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/collects.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2021-01/collects.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/collects.json", &[])
+        })
     }
 
     /**
@@ -550,8 +742,11 @@ impl Products {
     pub async fn deprecated_202101_create_collects(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2021-01/collects.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2021-01/collects.json", &[])
+            })
     }
 
     /**
@@ -571,18 +766,25 @@ impl Products {
         collect_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/collects/{}/json?{}",
-            crate::progenitor_support::encode_path(&collect_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/collects/{}/json",
+                self.client.encode_path(collect_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/collects/{collect_id}.json",
+                &[("collect_id", collect_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -602,10 +804,44 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/collects/{}/json",
-            crate::progenitor_support::encode_path(&collect_id.to_string()),
+            self.client.encode_path(collect_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2021-01/collects/{collect_id}.json",
+                &[("collect_id", collect_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Removes a product from a collection.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2021-01/collects/{collect_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_delete_collects_param_collect`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202101_delete_collects_param_collect_if_exists(
+        &self,
+        collect_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2021-01/collects/{}/json",
+            self.client.encode_path(collect_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2021-01/collects/{collect_id}.json",
+                    &[("collect_id", collect_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -620,14 +856,16 @@ impl Products {
      * * `collection_id: i64` -- recurring_application_charge[capped_amount].
      */
     pub async fn deprecated_202101_get_collects_count(&self, collection_id: i64) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if collection_id > 0 {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/collects/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2021-01/collects/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/collects/count.json", &[])
+        })
     }
 
     /**
@@ -650,20 +888,21 @@ impl Products {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/collects.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/unstable/collects.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/collects.json", &[])
+        })
     }
 
     /**
@@ -679,8 +918,11 @@ impl Products {
     ) -> Result<()> {
         let url = "/admin/api/unstable/collects.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/unstable/collects.json", &[])
+            })
     }
 
     /**
@@ -700,18 +942,25 @@ impl Products {
         collect_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/collects/{}/json?{}",
-            crate::progenitor_support::encode_path(&collect_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/collects/{}/json",
+                self.client.encode_path(collect_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/collects/{collect_id}.json",
+                &[("collect_id", collect_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -731,10 +980,44 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/collects/{}/json",
-            crate::progenitor_support::encode_path(&collect_id.to_string()),
+            self.client.encode_path(collect_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/unstable/collects/{collect_id}.json",
+                &[("collect_id", collect_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Removes a product from a collection.
+     *
+     * This function performs a `DELETE` to the `/admin/api/unstable/collects/{collect_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_delete_collects_param_collect`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_unstable_delete_collects_param_collect_if_exists(
+        &self,
+        collect_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/unstable/collects/{}/json",
+            self.client.encode_path(collect_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/unstable/collects/{collect_id}.json",
+                    &[("collect_id", collect_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -749,14 +1032,16 @@ impl Products {
      * * `collection_id: i64` -- recurring_application_charge[capped_amount].
      */
     pub async fn deprecated_unstable_get_collects_count(&self, collection_id: i64) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if collection_id > 0 {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/collects/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/unstable/collects/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/collects/count.json", &[])
+        })
     }
 
     /**
@@ -776,18 +1061,25 @@ impl Products {
         collection_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/collections/{}/json?{}",
-            crate::progenitor_support::encode_path(&collection_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/collections/{}/json",
+                self.client.encode_path(collection_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/collections/{collection_id}.json",
+                &[("collection_id", collection_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -808,18 +1100,25 @@ impl Products {
         collection_id: &str,
         limit: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/collections/{}/products.json?{}",
-            crate::progenitor_support::encode_path(&collection_id.to_string()),
-            query_
+            query_args.push(("limit", limit.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/collections/{}/products.json",
+                self.client.encode_path(collection_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/collections/{collection_id}/products.json",
+                &[("collection_id", collection_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -839,18 +1138,25 @@ impl Products {
         collection_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/collections/{}/json?{}",
-            crate::progenitor_support::encode_path(&collection_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/collections/{}/json",
+                self.client.encode_path(collection_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/collections/{collection_id}.json",
+                &[("collection_id", collection_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -871,18 +1177,25 @@ impl Products {
         collection_id: &str,
         limit: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/collections/{}/products.json?{}",
-            crate::progenitor_support::encode_path(&collection_id.to_string()),
-            query_
+            query_args.push(("limit", limit.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/collections/{}/products.json",
+                self.client.encode_path(collection_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/collections/{collection_id}/products.json",
+                &[("collection_id", collection_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -902,18 +1215,25 @@ impl Products {
         collection_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/collections/{}/json?{}",
-            crate::progenitor_support::encode_path(&collection_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/collections/{}/json",
+                self.client.encode_path(collection_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/collections/{collection_id}.json",
+                &[("collection_id", collection_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -934,18 +1254,25 @@ impl Products {
         collection_id: &str,
         limit: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/collections/{}/products.json?{}",
-            crate::progenitor_support::encode_path(&collection_id.to_string()),
-            query_
+            query_args.push(("limit", limit.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/collections/{}/products.json",
+                self.client.encode_path(collection_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/collections/{collection_id}/products.json",
+                &[("collection_id", collection_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -965,18 +1292,25 @@ impl Products {
         collection_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/collections/{}/json?{}",
-            crate::progenitor_support::encode_path(&collection_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/collections/{}/json",
+                self.client.encode_path(collection_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/collections/{collection_id}.json",
+                &[("collection_id", collection_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -997,18 +1331,25 @@ impl Products {
         collection_id: &str,
         limit: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/collections/{}/products.json?{}",
-            crate::progenitor_support::encode_path(&collection_id.to_string()),
-            query_
+            query_args.push(("limit", limit.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/collections/{}/products.json",
+                self.client.encode_path(collection_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/collections/{collection_id}/products.json",
+                &[("collection_id", collection_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1028,18 +1369,25 @@ impl Products {
         collection_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/collections/{}/json?{}",
-            crate::progenitor_support::encode_path(&collection_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/collections/{}/json",
+                self.client.encode_path(collection_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/collections/{collection_id}.json",
+                &[("collection_id", collection_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1060,18 +1408,25 @@ impl Products {
         collection_id: &str,
         limit: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/collections/{}/products.json?{}",
-            crate::progenitor_support::encode_path(&collection_id.to_string()),
-            query_
+            query_args.push(("limit", limit.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/collections/{}/products.json",
+                self.client.encode_path(collection_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/collections/{collection_id}/products.json",
+                &[("collection_id", collection_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1091,18 +1446,25 @@ impl Products {
         collection_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/collections/{}/json?{}",
-            crate::progenitor_support::encode_path(&collection_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/collections/{}/json",
+                self.client.encode_path(collection_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/collections/{collection_id}.json",
+                &[("collection_id", collection_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1123,18 +1485,25 @@ impl Products {
         collection_id: &str,
         limit: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/collections/{}/products.json?{}",
-            crate::progenitor_support::encode_path(&collection_id.to_string()),
-            query_
+            query_args.push(("limit", limit.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/collections/{}/products.json",
+                self.client.encode_path(collection_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/collections/{collection_id}/products.json",
+                &[("collection_id", collection_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1180,47 +1549,51 @@ impl Products {
         published_status: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/custom_collections.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-01/custom_collections.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/custom_collections.json", &[])
+        })
     }
 
     /**
@@ -1236,8 +1609,11 @@ impl Products {
     ) -> Result<()> {
         let url = "/admin/api/2020-01/custom_collections.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-01/custom_collections.json", &[])
+            })
     }
 
     /**
@@ -1272,35 +1648,40 @@ impl Products {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/custom_collections/count.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-01/custom_collections/count.json",
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/custom_collections/count.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -1320,18 +1701,25 @@ impl Products {
         custom_collection_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/custom_collections/{}/json?{}",
-            crate::progenitor_support::encode_path(&custom_collection_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/custom_collections/{}/json",
+                self.client.encode_path(custom_collection_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/custom_collections/{custom_collection_id}.json",
+                &[("custom_collection_id", custom_collection_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1352,12 +1740,19 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/custom_collections/{}/json",
-            crate::progenitor_support::encode_path(&custom_collection_id.to_string()),
+            self.client.encode_path(custom_collection_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-01/custom_collections/{custom_collection_id}.json",
+                    &[("custom_collection_id", custom_collection_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1377,10 +1772,44 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/custom_collections/{}/json",
-            crate::progenitor_support::encode_path(&custom_collection_id.to_string()),
+            self.client.encode_path(custom_collection_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-01/custom_collections/{custom_collection_id}.json",
+                &[("custom_collection_id", custom_collection_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a custom collection.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-01/custom_collections/{custom_collection_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_delete_custom_collections_param_collection`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202001_delete_custom_collections_param_collection_if_exists(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-01/custom_collections/{}/json",
+            self.client.encode_path(custom_collection_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-01/custom_collections/{custom_collection_id}.json",
+                    &[("custom_collection_id", custom_collection_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1426,47 +1855,51 @@ impl Products {
         published_status: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/custom_collections.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-04/custom_collections.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/custom_collections.json", &[])
+        })
     }
 
     /**
@@ -1482,8 +1915,11 @@ impl Products {
     ) -> Result<()> {
         let url = "/admin/api/2020-04/custom_collections.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-04/custom_collections.json", &[])
+            })
     }
 
     /**
@@ -1518,35 +1954,40 @@ impl Products {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/custom_collections/count.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-04/custom_collections/count.json",
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/custom_collections/count.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -1566,18 +2007,25 @@ impl Products {
         custom_collection_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/custom_collections/{}/json?{}",
-            crate::progenitor_support::encode_path(&custom_collection_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/custom_collections/{}/json",
+                self.client.encode_path(custom_collection_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/custom_collections/{custom_collection_id}.json",
+                &[("custom_collection_id", custom_collection_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1598,12 +2046,19 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/custom_collections/{}/json",
-            crate::progenitor_support::encode_path(&custom_collection_id.to_string()),
+            self.client.encode_path(custom_collection_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-04/custom_collections/{custom_collection_id}.json",
+                    &[("custom_collection_id", custom_collection_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1623,10 +2078,44 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/custom_collections/{}/json",
-            crate::progenitor_support::encode_path(&custom_collection_id.to_string()),
+            self.client.encode_path(custom_collection_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-04/custom_collections/{custom_collection_id}.json",
+                &[("custom_collection_id", custom_collection_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a custom collection.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-04/custom_collections/{custom_collection_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_delete_custom_collections_param_collection`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202004_delete_custom_collections_param_collection_if_exists(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-04/custom_collections/{}/json",
+            self.client.encode_path(custom_collection_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-04/custom_collections/{custom_collection_id}.json",
+                    &[("custom_collection_id", custom_collection_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1672,47 +2161,51 @@ impl Products {
         published_status: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/custom_collections.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-07/custom_collections.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/custom_collections.json", &[])
+        })
     }
 
     /**
@@ -1728,8 +2221,11 @@ impl Products {
     ) -> Result<()> {
         let url = "/admin/api/2020-07/custom_collections.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-07/custom_collections.json", &[])
+            })
     }
 
     /**
@@ -1764,35 +2260,40 @@ impl Products {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/custom_collections/count.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-07/custom_collections/count.json",
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/custom_collections/count.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -1812,18 +2313,25 @@ impl Products {
         custom_collection_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/custom_collections/{}/json?{}",
-            crate::progenitor_support::encode_path(&custom_collection_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/custom_collections/{}/json",
+                self.client.encode_path(custom_collection_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/custom_collections/{custom_collection_id}.json",
+                &[("custom_collection_id", custom_collection_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1844,12 +2352,19 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/custom_collections/{}/json",
-            crate::progenitor_support::encode_path(&custom_collection_id.to_string()),
+            self.client.encode_path(custom_collection_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-07/custom_collections/{custom_collection_id}.json",
+                    &[("custom_collection_id", custom_collection_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1869,10 +2384,44 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/custom_collections/{}/json",
-            crate::progenitor_support::encode_path(&custom_collection_id.to_string()),
+            self.client.encode_path(custom_collection_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-07/custom_collections/{custom_collection_id}.json",
+                &[("custom_collection_id", custom_collection_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a custom collection.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-07/custom_collections/{custom_collection_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_delete_custom_collections_param_collection`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202007_delete_custom_collections_param_collection_if_exists(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-07/custom_collections/{}/json",
+            self.client.encode_path(custom_collection_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-07/custom_collections/{custom_collection_id}.json",
+                    &[("custom_collection_id", custom_collection_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1918,47 +2467,51 @@ impl Products {
         published_status: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/custom_collections.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-10/custom_collections.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/custom_collections.json", &[])
+        })
     }
 
     /**
@@ -1971,8 +2524,11 @@ impl Products {
     pub async fn create_custom_collections(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-10/custom_collections.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-10/custom_collections.json", &[])
+            })
     }
 
     /**
@@ -2007,35 +2563,40 @@ impl Products {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/custom_collections/count.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-10/custom_collections/count.json",
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/custom_collections/count.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -2055,18 +2616,25 @@ impl Products {
         custom_collection_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/custom_collections/{}/json?{}",
-            crate::progenitor_support::encode_path(&custom_collection_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/custom_collections/{}/json",
+                self.client.encode_path(custom_collection_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/custom_collections/{custom_collection_id}.json",
+                &[("custom_collection_id", custom_collection_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2087,12 +2655,19 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/custom_collections/{}/json",
-            crate::progenitor_support::encode_path(&custom_collection_id.to_string()),
+            self.client.encode_path(custom_collection_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-10/custom_collections/{custom_collection_id}.json",
+                    &[("custom_collection_id", custom_collection_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2112,10 +2687,44 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/custom_collections/{}/json",
-            crate::progenitor_support::encode_path(&custom_collection_id.to_string()),
+            self.client.encode_path(custom_collection_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-10/custom_collections/{custom_collection_id}.json",
+                &[("custom_collection_id", custom_collection_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a custom collection.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-10/custom_collections/{custom_collection_id}.json` endpoint.
+     *
+     * As opposed to `delete_custom_collections_param_collection`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_custom_collections_param_collection_if_exists(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-10/custom_collections/{}/json",
+            self.client.encode_path(custom_collection_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-10/custom_collections/{custom_collection_id}.json",
+                    &[("custom_collection_id", custom_collection_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2161,47 +2770,51 @@ impl Products {
         published_status: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/custom_collections.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2021-01/custom_collections.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/custom_collections.json", &[])
+        })
     }
 
     /**
@@ -2217,8 +2830,11 @@ impl Products {
     ) -> Result<()> {
         let url = "/admin/api/2021-01/custom_collections.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2021-01/custom_collections.json", &[])
+            })
     }
 
     /**
@@ -2253,35 +2869,40 @@ impl Products {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/custom_collections/count.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/2021-01/custom_collections/count.json",
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/custom_collections/count.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -2301,18 +2922,25 @@ impl Products {
         custom_collection_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/custom_collections/{}/json?{}",
-            crate::progenitor_support::encode_path(&custom_collection_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/custom_collections/{}/json",
+                self.client.encode_path(custom_collection_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/custom_collections/{custom_collection_id}.json",
+                &[("custom_collection_id", custom_collection_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2333,12 +2961,19 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/custom_collections/{}/json",
-            crate::progenitor_support::encode_path(&custom_collection_id.to_string()),
+            self.client.encode_path(custom_collection_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2021-01/custom_collections/{custom_collection_id}.json",
+                    &[("custom_collection_id", custom_collection_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2358,10 +2993,44 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/custom_collections/{}/json",
-            crate::progenitor_support::encode_path(&custom_collection_id.to_string()),
+            self.client.encode_path(custom_collection_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2021-01/custom_collections/{custom_collection_id}.json",
+                &[("custom_collection_id", custom_collection_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a custom collection.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2021-01/custom_collections/{custom_collection_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_delete_custom_collections_param_collection`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202101_delete_custom_collections_param_collection_if_exists(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2021-01/custom_collections/{}/json",
+            self.client.encode_path(custom_collection_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2021-01/custom_collections/{custom_collection_id}.json",
+                    &[("custom_collection_id", custom_collection_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2407,47 +3076,51 @@ impl Products {
         published_status: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/custom_collections.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/unstable/custom_collections.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/custom_collections.json", &[])
+        })
     }
 
     /**
@@ -2463,8 +3136,11 @@ impl Products {
     ) -> Result<()> {
         let url = "/admin/api/unstable/custom_collections.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/unstable/custom_collections.json", &[])
+            })
     }
 
     /**
@@ -2499,35 +3175,40 @@ impl Products {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/custom_collections/count.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/unstable/custom_collections/count.json",
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/custom_collections/count.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -2547,18 +3228,25 @@ impl Products {
         custom_collection_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/custom_collections/{}/json?{}",
-            crate::progenitor_support::encode_path(&custom_collection_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/custom_collections/{}/json",
+                self.client.encode_path(custom_collection_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/custom_collections/{custom_collection_id}.json",
+                &[("custom_collection_id", custom_collection_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2579,12 +3267,19 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/custom_collections/{}/json",
-            crate::progenitor_support::encode_path(&custom_collection_id.to_string()),
+            self.client.encode_path(custom_collection_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/unstable/custom_collections/{custom_collection_id}.json",
+                    &[("custom_collection_id", custom_collection_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2604,10 +3299,44 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/custom_collections/{}/json",
-            crate::progenitor_support::encode_path(&custom_collection_id.to_string()),
+            self.client.encode_path(custom_collection_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/unstable/custom_collections/{custom_collection_id}.json",
+                &[("custom_collection_id", custom_collection_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a custom collection.
+     *
+     * This function performs a `DELETE` to the `/admin/api/unstable/custom_collections/{custom_collection_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_delete_custom_collections_param_collection`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_unstable_delete_custom_collections_param_collection_if_exists(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/unstable/custom_collections/{}/json",
+            self.client.encode_path(custom_collection_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/unstable/custom_collections/{custom_collection_id}.json",
+                    &[("custom_collection_id", custom_collection_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2670,68 +3399,66 @@ impl Products {
         fields: &str,
         presentment_currencies: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !presentment_currencies.is_empty() {
-            query_args.push((
-                "presentment_currencies".to_string(),
-                presentment_currencies.to_string(),
-            ));
+            query_args.push(("presentment_currencies", presentment_currencies.to_string()));
         }
         if !product_type.is_empty() {
-            query_args.push(("product_type".to_string(), product_type.to_string()));
+            query_args.push(("product_type", product_type.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
         if !vendor.is_empty() {
-            query_args.push(("vendor".to_string(), vendor.to_string()));
+            query_args.push(("vendor", vendor.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/products.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-01/products.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/products.json", &[])
+        })
     }
 
     /**
@@ -2748,8 +3475,11 @@ impl Products {
     pub async fn deprecated_202001_create(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-01/products.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-01/products.json", &[])
+            })
     }
 
     /**
@@ -2790,41 +3520,43 @@ impl Products {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !product_type.is_empty() {
-            query_args.push(("product_type".to_string(), product_type.to_string()));
+            query_args.push(("product_type", product_type.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
         if !vendor.is_empty() {
-            query_args.push(("vendor".to_string(), vendor.to_string()));
+            query_args.push(("vendor", vendor.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/products/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-01/products/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/products/count.json", &[])
+        })
     }
 
     /**
@@ -2840,18 +3572,25 @@ impl Products {
      * * `fields: &str` -- A comma-separated list of fields to include in the response.
      */
     pub async fn deprecated_202001_get_param(&self, product_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/products/{}/json?{}",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/products/{}/json",
+                self.client.encode_path(product_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/products/{product_id}.json",
+                &[("product_id", product_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2876,12 +3615,19 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/products/{}/json",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
+            self.client.encode_path(product_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-01/products/{product_id}.json",
+                    &[("product_id", product_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2898,10 +3644,41 @@ impl Products {
     pub async fn deprecated_202001_delete_param(&self, product_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/products/{}/json",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
+            self.client.encode_path(product_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-01/products/{product_id}.json",
+                &[("product_id", product_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a product.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-01/products/{product_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_delete_param`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202001_delete_param_if_exists(&self, product_id: &str) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-01/products/{}/json",
+            self.client.encode_path(product_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-01/products/{product_id}.json",
+                    &[("product_id", product_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2964,68 +3741,66 @@ impl Products {
         fields: &str,
         presentment_currencies: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !presentment_currencies.is_empty() {
-            query_args.push((
-                "presentment_currencies".to_string(),
-                presentment_currencies.to_string(),
-            ));
+            query_args.push(("presentment_currencies", presentment_currencies.to_string()));
         }
         if !product_type.is_empty() {
-            query_args.push(("product_type".to_string(), product_type.to_string()));
+            query_args.push(("product_type", product_type.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
         if !vendor.is_empty() {
-            query_args.push(("vendor".to_string(), vendor.to_string()));
+            query_args.push(("vendor", vendor.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/products.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-04/products.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/products.json", &[])
+        })
     }
 
     /**
@@ -3042,8 +3817,11 @@ impl Products {
     pub async fn deprecated_202004_create(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-04/products.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-04/products.json", &[])
+            })
     }
 
     /**
@@ -3084,41 +3862,43 @@ impl Products {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !product_type.is_empty() {
-            query_args.push(("product_type".to_string(), product_type.to_string()));
+            query_args.push(("product_type", product_type.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
         if !vendor.is_empty() {
-            query_args.push(("vendor".to_string(), vendor.to_string()));
+            query_args.push(("vendor", vendor.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/products/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-04/products/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/products/count.json", &[])
+        })
     }
 
     /**
@@ -3134,18 +3914,25 @@ impl Products {
      * * `fields: &str` -- A comma-separated list of fields to include in the response.
      */
     pub async fn deprecated_202004_get_param(&self, product_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/products/{}/json?{}",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/products/{}/json",
+                self.client.encode_path(product_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/products/{product_id}.json",
+                &[("product_id", product_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -3170,12 +3957,19 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/products/{}/json",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
+            self.client.encode_path(product_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-04/products/{product_id}.json",
+                    &[("product_id", product_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -3192,10 +3986,41 @@ impl Products {
     pub async fn deprecated_202004_delete_param(&self, product_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/products/{}/json",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
+            self.client.encode_path(product_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-04/products/{product_id}.json",
+                &[("product_id", product_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a product.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-04/products/{product_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_delete_param`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202004_delete_param_if_exists(&self, product_id: &str) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-04/products/{}/json",
+            self.client.encode_path(product_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-04/products/{product_id}.json",
+                    &[("product_id", product_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -3258,68 +4083,66 @@ impl Products {
         fields: &str,
         presentment_currencies: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !presentment_currencies.is_empty() {
-            query_args.push((
-                "presentment_currencies".to_string(),
-                presentment_currencies.to_string(),
-            ));
+            query_args.push(("presentment_currencies", presentment_currencies.to_string()));
         }
         if !product_type.is_empty() {
-            query_args.push(("product_type".to_string(), product_type.to_string()));
+            query_args.push(("product_type", product_type.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
         if !vendor.is_empty() {
-            query_args.push(("vendor".to_string(), vendor.to_string()));
+            query_args.push(("vendor", vendor.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/products.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-07/products.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/products.json", &[])
+        })
     }
 
     /**
@@ -3336,8 +4159,11 @@ impl Products {
     pub async fn deprecated_202007_create(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-07/products.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-07/products.json", &[])
+            })
     }
 
     /**
@@ -3378,41 +4204,43 @@ impl Products {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !product_type.is_empty() {
-            query_args.push(("product_type".to_string(), product_type.to_string()));
+            query_args.push(("product_type", product_type.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
         if !vendor.is_empty() {
-            query_args.push(("vendor".to_string(), vendor.to_string()));
+            query_args.push(("vendor", vendor.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/products/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-07/products/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/products/count.json", &[])
+        })
     }
 
     /**
@@ -3428,18 +4256,25 @@ impl Products {
      * * `fields: &str` -- A comma-separated list of fields to include in the response.
      */
     pub async fn deprecated_202007_get_param(&self, product_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/products/{}/json?{}",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/products/{}/json",
+                self.client.encode_path(product_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/products/{product_id}.json",
+                &[("product_id", product_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -3464,12 +4299,19 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/products/{}/json",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
+            self.client.encode_path(product_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-07/products/{product_id}.json",
+                    &[("product_id", product_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -3486,10 +4328,41 @@ impl Products {
     pub async fn deprecated_202007_delete_param(&self, product_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/products/{}/json",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
+            self.client.encode_path(product_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-07/products/{product_id}.json",
+                &[("product_id", product_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a product.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-07/products/{product_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_delete_param`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202007_delete_param_if_exists(&self, product_id: &str) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-07/products/{}/json",
+            self.client.encode_path(product_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-07/products/{product_id}.json",
+                    &[("product_id", product_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -3552,68 +4425,66 @@ impl Products {
         fields: &str,
         presentment_currencies: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !presentment_currencies.is_empty() {
-            query_args.push((
-                "presentment_currencies".to_string(),
-                presentment_currencies.to_string(),
-            ));
+            query_args.push(("presentment_currencies", presentment_currencies.to_string()));
         }
         if !product_type.is_empty() {
-            query_args.push(("product_type".to_string(), product_type.to_string()));
+            query_args.push(("product_type", product_type.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
         if !vendor.is_empty() {
-            query_args.push(("vendor".to_string(), vendor.to_string()));
+            query_args.push(("vendor", vendor.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/products.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-10/products.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/products.json", &[])
+        })
     }
 
     /**
@@ -3630,8 +4501,11 @@ impl Products {
     pub async fn create(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-10/products.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-10/products.json", &[])
+            })
     }
 
     /**
@@ -3672,41 +4546,43 @@ impl Products {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !product_type.is_empty() {
-            query_args.push(("product_type".to_string(), product_type.to_string()));
+            query_args.push(("product_type", product_type.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
         if !vendor.is_empty() {
-            query_args.push(("vendor".to_string(), vendor.to_string()));
+            query_args.push(("vendor", vendor.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/products/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-10/products/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/products/count.json", &[])
+        })
     }
 
     /**
@@ -3722,18 +4598,25 @@ impl Products {
      * * `fields: &str` -- A comma-separated list of fields to include in the response.
      */
     pub async fn get_param(&self, product_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/products/{}/json?{}",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/products/{}/json",
+                self.client.encode_path(product_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/products/{product_id}.json",
+                &[("product_id", product_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -3754,12 +4637,19 @@ impl Products {
     pub async fn update_param(&self, product_id: &str, body: &serde_json::Value) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/products/{}/json",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
+            self.client.encode_path(product_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-10/products/{product_id}.json",
+                    &[("product_id", product_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -3776,10 +4666,41 @@ impl Products {
     pub async fn delete_param(&self, product_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/products/{}/json",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
+            self.client.encode_path(product_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-10/products/{product_id}.json",
+                &[("product_id", product_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a product.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-10/products/{product_id}.json` endpoint.
+     *
+     * As opposed to `delete_param`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_param_if_exists(&self, product_id: &str) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-10/products/{}/json",
+            self.client.encode_path(product_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-10/products/{product_id}.json",
+                    &[("product_id", product_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -3842,68 +4763,66 @@ impl Products {
         fields: &str,
         presentment_currencies: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !presentment_currencies.is_empty() {
-            query_args.push((
-                "presentment_currencies".to_string(),
-                presentment_currencies.to_string(),
-            ));
+            query_args.push(("presentment_currencies", presentment_currencies.to_string()));
         }
         if !product_type.is_empty() {
-            query_args.push(("product_type".to_string(), product_type.to_string()));
+            query_args.push(("product_type", product_type.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
         if !vendor.is_empty() {
-            query_args.push(("vendor".to_string(), vendor.to_string()));
+            query_args.push(("vendor", vendor.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/products.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2021-01/products.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/products.json", &[])
+        })
     }
 
     /**
@@ -3920,8 +4839,11 @@ impl Products {
     pub async fn deprecated_202101_create(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2021-01/products.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2021-01/products.json", &[])
+            })
     }
 
     /**
@@ -3962,41 +4884,43 @@ impl Products {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !product_type.is_empty() {
-            query_args.push(("product_type".to_string(), product_type.to_string()));
+            query_args.push(("product_type", product_type.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
         if !vendor.is_empty() {
-            query_args.push(("vendor".to_string(), vendor.to_string()));
+            query_args.push(("vendor", vendor.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/products/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2021-01/products/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/products/count.json", &[])
+        })
     }
 
     /**
@@ -4012,18 +4936,25 @@ impl Products {
      * * `fields: &str` -- A comma-separated list of fields to include in the response.
      */
     pub async fn deprecated_202101_get_param(&self, product_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/products/{}/json?{}",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/products/{}/json",
+                self.client.encode_path(product_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/products/{product_id}.json",
+                &[("product_id", product_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -4048,12 +4979,19 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/products/{}/json",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
+            self.client.encode_path(product_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2021-01/products/{product_id}.json",
+                    &[("product_id", product_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -4070,10 +5008,41 @@ impl Products {
     pub async fn deprecated_202101_delete_param(&self, product_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/products/{}/json",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
+            self.client.encode_path(product_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2021-01/products/{product_id}.json",
+                &[("product_id", product_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a product.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2021-01/products/{product_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_delete_param`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202101_delete_param_if_exists(&self, product_id: &str) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2021-01/products/{}/json",
+            self.client.encode_path(product_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2021-01/products/{product_id}.json",
+                    &[("product_id", product_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -4136,68 +5105,66 @@ impl Products {
         fields: &str,
         presentment_currencies: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !presentment_currencies.is_empty() {
-            query_args.push((
-                "presentment_currencies".to_string(),
-                presentment_currencies.to_string(),
-            ));
+            query_args.push(("presentment_currencies", presentment_currencies.to_string()));
         }
         if !product_type.is_empty() {
-            query_args.push(("product_type".to_string(), product_type.to_string()));
+            query_args.push(("product_type", product_type.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
         if !vendor.is_empty() {
-            query_args.push(("vendor".to_string(), vendor.to_string()));
+            query_args.push(("vendor", vendor.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/products.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/unstable/products.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/products.json", &[])
+        })
     }
 
     /**
@@ -4214,8 +5181,11 @@ impl Products {
     pub async fn deprecated_unstable_create(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/unstable/products.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/unstable/products.json", &[])
+            })
     }
 
     /**
@@ -4256,41 +5226,43 @@ impl Products {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !product_type.is_empty() {
-            query_args.push(("product_type".to_string(), product_type.to_string()));
+            query_args.push(("product_type", product_type.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
         if !vendor.is_empty() {
-            query_args.push(("vendor".to_string(), vendor.to_string()));
+            query_args.push(("vendor", vendor.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/products/count.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/unstable/products/count.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/products/count.json", &[])
+        })
     }
 
     /**
@@ -4310,18 +5282,25 @@ impl Products {
         product_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/products/{}/json?{}",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/products/{}/json",
+                self.client.encode_path(product_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/products/{product_id}.json",
+                &[("product_id", product_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -4346,12 +5325,19 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/products/{}/json",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
+            self.client.encode_path(product_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/unstable/products/{product_id}.json",
+                    &[("product_id", product_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -4368,10 +5354,44 @@ impl Products {
     pub async fn deprecated_unstable_delete_param(&self, product_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/products/{}/json",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
+            self.client.encode_path(product_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/unstable/products/{product_id}.json",
+                &[("product_id", product_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a product.
+     *
+     * This function performs a `DELETE` to the `/admin/api/unstable/products/{product_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_delete_param`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_unstable_delete_param_if_exists(
+        &self,
+        product_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/unstable/products/{}/json",
+            self.client.encode_path(product_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/unstable/products/{product_id}.json",
+                    &[("product_id", product_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -4393,21 +5413,28 @@ impl Products {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/products/{}/images.json?{}",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            query_
+            query_args.push(("since_id", since_id.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/products/{}/images.json",
+                self.client.encode_path(product_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/products/{product_id}/images.json",
+                &[("product_id", product_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -4428,12 +5455,19 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/products/{}/images.json",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
+            self.client.encode_path(product_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-01/products/{product_id}/images.json",
+                    &[("product_id", product_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -4453,18 +5487,25 @@ impl Products {
         product_id: &str,
         since_id: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/products/{}/images/count.json?{}",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            query_
+            query_args.push(("since_id", since_id.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/products/{}/images/count.json",
+                self.client.encode_path(product_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/products/{product_id}/images/count.json",
+                &[("product_id", product_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -4486,19 +5527,29 @@ impl Products {
         image_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/products/{}/images/{}/json?{}",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            crate::progenitor_support::encode_path(&image_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/products/{}/images/{}/json",
+                self.client.encode_path(product_id),
+                self.client.encode_path(image_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/products/{product_id}/images/{image_id}.json",
+                &[
+                    ("product_id", product_id.to_string()),
+                    ("image_id", image_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -4521,13 +5572,23 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/products/{}/images/{}/json",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            crate::progenitor_support::encode_path(&image_id.to_string()),
+            self.client.encode_path(product_id),
+            self.client.encode_path(image_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-01/products/{product_id}/images/{image_id}.json",
+                    &[
+                        ("product_id", product_id.to_string()),
+                        ("image_id", image_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -4549,11 +5610,53 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/products/{}/images/{}/json",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            crate::progenitor_support::encode_path(&image_id.to_string()),
+            self.client.encode_path(product_id),
+            self.client.encode_path(image_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-01/products/{product_id}/images/{image_id}.json",
+                &[
+                    ("product_id", product_id.to_string()),
+                    ("image_id", image_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * .
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-01/products/{product_id}/images/{image_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_delete_param_images_image`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202001_delete_param_images_image_if_exists(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-01/products/{}/images/{}/json",
+            self.client.encode_path(product_id),
+            self.client.encode_path(image_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-01/products/{product_id}/images/{image_id}.json",
+                    &[
+                        ("product_id", product_id.to_string()),
+                        ("image_id", image_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -4575,21 +5678,28 @@ impl Products {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/products/{}/images.json?{}",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            query_
+            query_args.push(("since_id", since_id.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/products/{}/images.json",
+                self.client.encode_path(product_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/products/{product_id}/images.json",
+                &[("product_id", product_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -4610,12 +5720,19 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/products/{}/images.json",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
+            self.client.encode_path(product_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-04/products/{product_id}/images.json",
+                    &[("product_id", product_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -4635,18 +5752,25 @@ impl Products {
         product_id: &str,
         since_id: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/products/{}/images/count.json?{}",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            query_
+            query_args.push(("since_id", since_id.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/products/{}/images/count.json",
+                self.client.encode_path(product_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/products/{product_id}/images/count.json",
+                &[("product_id", product_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -4668,19 +5792,29 @@ impl Products {
         image_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/products/{}/images/{}/json?{}",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            crate::progenitor_support::encode_path(&image_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/products/{}/images/{}/json",
+                self.client.encode_path(product_id),
+                self.client.encode_path(image_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/products/{product_id}/images/{image_id}.json",
+                &[
+                    ("product_id", product_id.to_string()),
+                    ("image_id", image_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -4703,13 +5837,23 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/products/{}/images/{}/json",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            crate::progenitor_support::encode_path(&image_id.to_string()),
+            self.client.encode_path(product_id),
+            self.client.encode_path(image_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-04/products/{product_id}/images/{image_id}.json",
+                    &[
+                        ("product_id", product_id.to_string()),
+                        ("image_id", image_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -4731,11 +5875,53 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/products/{}/images/{}/json",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            crate::progenitor_support::encode_path(&image_id.to_string()),
+            self.client.encode_path(product_id),
+            self.client.encode_path(image_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-04/products/{product_id}/images/{image_id}.json",
+                &[
+                    ("product_id", product_id.to_string()),
+                    ("image_id", image_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * .
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-04/products/{product_id}/images/{image_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_delete_param_images_image`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202004_delete_param_images_image_if_exists(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-04/products/{}/images/{}/json",
+            self.client.encode_path(product_id),
+            self.client.encode_path(image_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-04/products/{product_id}/images/{image_id}.json",
+                    &[
+                        ("product_id", product_id.to_string()),
+                        ("image_id", image_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -4757,21 +5943,28 @@ impl Products {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/products/{}/images.json?{}",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            query_
+            query_args.push(("since_id", since_id.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/products/{}/images.json",
+                self.client.encode_path(product_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/products/{product_id}/images.json",
+                &[("product_id", product_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -4792,12 +5985,19 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/products/{}/images.json",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
+            self.client.encode_path(product_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-07/products/{product_id}/images.json",
+                    &[("product_id", product_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -4817,18 +6017,25 @@ impl Products {
         product_id: &str,
         since_id: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/products/{}/images/count.json?{}",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            query_
+            query_args.push(("since_id", since_id.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/products/{}/images/count.json",
+                self.client.encode_path(product_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/products/{product_id}/images/count.json",
+                &[("product_id", product_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -4850,19 +6057,29 @@ impl Products {
         image_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/products/{}/images/{}/json?{}",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            crate::progenitor_support::encode_path(&image_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/products/{}/images/{}/json",
+                self.client.encode_path(product_id),
+                self.client.encode_path(image_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/products/{product_id}/images/{image_id}.json",
+                &[
+                    ("product_id", product_id.to_string()),
+                    ("image_id", image_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -4885,13 +6102,23 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/products/{}/images/{}/json",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            crate::progenitor_support::encode_path(&image_id.to_string()),
+            self.client.encode_path(product_id),
+            self.client.encode_path(image_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-07/products/{product_id}/images/{image_id}.json",
+                    &[
+                        ("product_id", product_id.to_string()),
+                        ("image_id", image_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -4913,11 +6140,53 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/products/{}/images/{}/json",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            crate::progenitor_support::encode_path(&image_id.to_string()),
+            self.client.encode_path(product_id),
+            self.client.encode_path(image_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-07/products/{product_id}/images/{image_id}.json",
+                &[
+                    ("product_id", product_id.to_string()),
+                    ("image_id", image_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * .
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-07/products/{product_id}/images/{image_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_delete_param_images_image`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202007_delete_param_images_image_if_exists(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-07/products/{}/images/{}/json",
+            self.client.encode_path(product_id),
+            self.client.encode_path(image_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-07/products/{product_id}/images/{image_id}.json",
+                    &[
+                        ("product_id", product_id.to_string()),
+                        ("image_id", image_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -4939,21 +6208,28 @@ impl Products {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/products/{}/images.json?{}",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            query_
+            query_args.push(("since_id", since_id.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/products/{}/images.json",
+                self.client.encode_path(product_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/products/{product_id}/images.json",
+                &[("product_id", product_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -4974,12 +6250,19 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/products/{}/images.json",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
+            self.client.encode_path(product_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-10/products/{product_id}/images.json",
+                    &[("product_id", product_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -4995,18 +6278,25 @@ impl Products {
      * * `since_id: &str` -- Restrict results to after the specified ID.
      */
     pub async fn get_param_images_count(&self, product_id: &str, since_id: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/products/{}/images/count.json?{}",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            query_
+            query_args.push(("since_id", since_id.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/products/{}/images/count.json",
+                self.client.encode_path(product_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/products/{product_id}/images/count.json",
+                &[("product_id", product_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -5028,19 +6318,29 @@ impl Products {
         image_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/products/{}/images/{}/json?{}",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            crate::progenitor_support::encode_path(&image_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/products/{}/images/{}/json",
+                self.client.encode_path(product_id),
+                self.client.encode_path(image_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/products/{product_id}/images/{image_id}.json",
+                &[
+                    ("product_id", product_id.to_string()),
+                    ("image_id", image_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -5063,13 +6363,23 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/products/{}/images/{}/json",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            crate::progenitor_support::encode_path(&image_id.to_string()),
+            self.client.encode_path(product_id),
+            self.client.encode_path(image_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-10/products/{product_id}/images/{image_id}.json",
+                    &[
+                        ("product_id", product_id.to_string()),
+                        ("image_id", image_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -5087,11 +6397,53 @@ impl Products {
     pub async fn delete_param_images_image(&self, product_id: &str, image_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/products/{}/images/{}/json",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            crate::progenitor_support::encode_path(&image_id.to_string()),
+            self.client.encode_path(product_id),
+            self.client.encode_path(image_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-10/products/{product_id}/images/{image_id}.json",
+                &[
+                    ("product_id", product_id.to_string()),
+                    ("image_id", image_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * .
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-10/products/{product_id}/images/{image_id}.json` endpoint.
+     *
+     * As opposed to `delete_param_images_image`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_param_images_image_if_exists(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-10/products/{}/images/{}/json",
+            self.client.encode_path(product_id),
+            self.client.encode_path(image_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-10/products/{product_id}/images/{image_id}.json",
+                    &[
+                        ("product_id", product_id.to_string()),
+                        ("image_id", image_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -5113,21 +6465,28 @@ impl Products {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/products/{}/images.json?{}",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            query_
+            query_args.push(("since_id", since_id.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/products/{}/images.json",
+                self.client.encode_path(product_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/products/{product_id}/images.json",
+                &[("product_id", product_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -5148,12 +6507,19 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/products/{}/images.json",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
+            self.client.encode_path(product_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2021-01/products/{product_id}/images.json",
+                    &[("product_id", product_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -5173,18 +6539,25 @@ impl Products {
         product_id: &str,
         since_id: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/products/{}/images/count.json?{}",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            query_
+            query_args.push(("since_id", since_id.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/products/{}/images/count.json",
+                self.client.encode_path(product_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/products/{product_id}/images/count.json",
+                &[("product_id", product_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -5206,19 +6579,29 @@ impl Products {
         image_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/products/{}/images/{}/json?{}",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            crate::progenitor_support::encode_path(&image_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/products/{}/images/{}/json",
+                self.client.encode_path(product_id),
+                self.client.encode_path(image_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/products/{product_id}/images/{image_id}.json",
+                &[
+                    ("product_id", product_id.to_string()),
+                    ("image_id", image_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -5241,13 +6624,23 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/products/{}/images/{}/json",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            crate::progenitor_support::encode_path(&image_id.to_string()),
+            self.client.encode_path(product_id),
+            self.client.encode_path(image_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2021-01/products/{product_id}/images/{image_id}.json",
+                    &[
+                        ("product_id", product_id.to_string()),
+                        ("image_id", image_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -5269,11 +6662,53 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/products/{}/images/{}/json",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            crate::progenitor_support::encode_path(&image_id.to_string()),
+            self.client.encode_path(product_id),
+            self.client.encode_path(image_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2021-01/products/{product_id}/images/{image_id}.json",
+                &[
+                    ("product_id", product_id.to_string()),
+                    ("image_id", image_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * .
+     *
+     * This function performs a `DELETE` to the `/admin/api/2021-01/products/{product_id}/images/{image_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_delete_param_images_image`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202101_delete_param_images_image_if_exists(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2021-01/products/{}/images/{}/json",
+            self.client.encode_path(product_id),
+            self.client.encode_path(image_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2021-01/products/{product_id}/images/{image_id}.json",
+                    &[
+                        ("product_id", product_id.to_string()),
+                        ("image_id", image_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -5295,21 +6730,28 @@ impl Products {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/products/{}/images.json?{}",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            query_
+            query_args.push(("since_id", since_id.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/products/{}/images.json",
+                self.client.encode_path(product_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/products/{product_id}/images.json",
+                &[("product_id", product_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -5330,12 +6772,19 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/products/{}/images.json",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
+            self.client.encode_path(product_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/unstable/products/{product_id}/images.json",
+                    &[("product_id", product_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -5355,18 +6804,25 @@ impl Products {
         product_id: &str,
         since_id: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/products/{}/images/count.json?{}",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            query_
+            query_args.push(("since_id", since_id.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/products/{}/images/count.json",
+                self.client.encode_path(product_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/products/{product_id}/images/count.json",
+                &[("product_id", product_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -5388,19 +6844,29 @@ impl Products {
         image_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/products/{}/images/{}/json?{}",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            crate::progenitor_support::encode_path(&image_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/products/{}/images/{}/json",
+                self.client.encode_path(product_id),
+                self.client.encode_path(image_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/products/{product_id}/images/{image_id}.json",
+                &[
+                    ("product_id", product_id.to_string()),
+                    ("image_id", image_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -5423,13 +6889,23 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/products/{}/images/{}/json",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            crate::progenitor_support::encode_path(&image_id.to_string()),
+            self.client.encode_path(product_id),
+            self.client.encode_path(image_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/unstable/products/{product_id}/images/{image_id}.json",
+                    &[
+                        ("product_id", product_id.to_string()),
+                        ("image_id", image_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -5451,11 +6927,53 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/products/{}/images/{}/json",
-            crate::progenitor_support::encode_path(&product_id.to_string()),
-            crate::progenitor_support::encode_path(&image_id.to_string()),
+            self.client.encode_path(product_id),
+            self.client.encode_path(image_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/unstable/products/{product_id}/images/{image_id}.json",
+                &[
+                    ("product_id", product_id.to_string()),
+                    ("image_id", image_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * .
+     *
+     * This function performs a `DELETE` to the `/admin/api/unstable/products/{product_id}/images/{image_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_delete_param_images_image`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_unstable_delete_param_images_image_if_exists(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/unstable/products/{}/images/{}/json",
+            self.client.encode_path(product_id),
+            self.client.encode_path(image_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/unstable/products/{product_id}/images/{image_id}.json",
+                    &[
+                        ("product_id", product_id.to_string()),
+                        ("image_id", image_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -5501,47 +7019,49 @@ impl Products {
         published_status: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/smart_collections.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-01/smart_collections.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/smart_collections.json", &[])
+        })
     }
 
     /**
@@ -5557,8 +7077,11 @@ impl Products {
     ) -> Result<()> {
         let url = "/admin/api/2020-01/smart_collections.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-01/smart_collections.json", &[])
+            })
     }
 
     /**
@@ -5593,32 +7116,40 @@ impl Products {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/smart_collections/count.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-01/smart_collections/count.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/smart_collections/count.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -5638,18 +7169,25 @@ impl Products {
         smart_collection_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/smart_collections/{}/json?{}",
-            crate::progenitor_support::encode_path(&smart_collection_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/smart_collections/{}/json",
+                self.client.encode_path(smart_collection_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/smart_collections/{smart_collection_id}.json",
+                &[("smart_collection_id", smart_collection_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -5670,12 +7208,19 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/smart_collections/{}/json",
-            crate::progenitor_support::encode_path(&smart_collection_id.to_string()),
+            self.client.encode_path(smart_collection_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-01/smart_collections/{smart_collection_id}.json",
+                    &[("smart_collection_id", smart_collection_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -5695,10 +7240,44 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/smart_collections/{}/json",
-            crate::progenitor_support::encode_path(&smart_collection_id.to_string()),
+            self.client.encode_path(smart_collection_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-01/smart_collections/{smart_collection_id}.json",
+                &[("smart_collection_id", smart_collection_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Removes a smart collection.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-01/smart_collections/{smart_collection_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_delete_smart_collections_param_collection`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202001_delete_smart_collections_param_collection_if_exists(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-01/smart_collections/{}/json",
+            self.client.encode_path(smart_collection_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-01/smart_collections/{smart_collection_id}.json",
+                    &[("smart_collection_id", smart_collection_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -5723,23 +7302,31 @@ impl Products {
         sort_order: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !products.is_empty() {
-            query_args.push(("products".to_string(), products.to_string()));
+            query_args.push(("products", products.to_string()));
         }
         if !sort_order.is_empty() {
-            query_args.push(("sort_order".to_string(), sort_order.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/smart_collections/{}/order.json?{}",
-            crate::progenitor_support::encode_path(&smart_collection_id.to_string()),
-            query_
+            query_args.push(("sort_order", sort_order.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/smart_collections/{}/order.json",
+                self.client.encode_path(smart_collection_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-01/smart_collections/{smart_collection_id}/order.json",
+                    &[("smart_collection_id", smart_collection_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -5785,47 +7372,49 @@ impl Products {
         published_status: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/smart_collections.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-04/smart_collections.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/smart_collections.json", &[])
+        })
     }
 
     /**
@@ -5841,8 +7430,11 @@ impl Products {
     ) -> Result<()> {
         let url = "/admin/api/2020-04/smart_collections.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-04/smart_collections.json", &[])
+            })
     }
 
     /**
@@ -5877,32 +7469,40 @@ impl Products {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/smart_collections/count.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-04/smart_collections/count.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/smart_collections/count.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -5922,18 +7522,25 @@ impl Products {
         smart_collection_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/smart_collections/{}/json?{}",
-            crate::progenitor_support::encode_path(&smart_collection_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/smart_collections/{}/json",
+                self.client.encode_path(smart_collection_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/smart_collections/{smart_collection_id}.json",
+                &[("smart_collection_id", smart_collection_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -5954,12 +7561,19 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/smart_collections/{}/json",
-            crate::progenitor_support::encode_path(&smart_collection_id.to_string()),
+            self.client.encode_path(smart_collection_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-04/smart_collections/{smart_collection_id}.json",
+                    &[("smart_collection_id", smart_collection_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -5979,10 +7593,44 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/smart_collections/{}/json",
-            crate::progenitor_support::encode_path(&smart_collection_id.to_string()),
+            self.client.encode_path(smart_collection_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-04/smart_collections/{smart_collection_id}.json",
+                &[("smart_collection_id", smart_collection_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Removes a smart collection.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-04/smart_collections/{smart_collection_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_delete_smart_collections_param_collection`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202004_delete_smart_collections_param_collection_if_exists(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-04/smart_collections/{}/json",
+            self.client.encode_path(smart_collection_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-04/smart_collections/{smart_collection_id}.json",
+                    &[("smart_collection_id", smart_collection_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -6007,23 +7655,31 @@ impl Products {
         sort_order: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !products.is_empty() {
-            query_args.push(("products".to_string(), products.to_string()));
+            query_args.push(("products", products.to_string()));
         }
         if !sort_order.is_empty() {
-            query_args.push(("sort_order".to_string(), sort_order.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/smart_collections/{}/order.json?{}",
-            crate::progenitor_support::encode_path(&smart_collection_id.to_string()),
-            query_
+            query_args.push(("sort_order", sort_order.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/smart_collections/{}/order.json",
+                self.client.encode_path(smart_collection_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-04/smart_collections/{smart_collection_id}/order.json",
+                    &[("smart_collection_id", smart_collection_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -6069,47 +7725,49 @@ impl Products {
         published_status: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/smart_collections.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-07/smart_collections.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/smart_collections.json", &[])
+        })
     }
 
     /**
@@ -6125,8 +7783,11 @@ impl Products {
     ) -> Result<()> {
         let url = "/admin/api/2020-07/smart_collections.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-07/smart_collections.json", &[])
+            })
     }
 
     /**
@@ -6161,32 +7822,40 @@ impl Products {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/smart_collections/count.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-07/smart_collections/count.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/smart_collections/count.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -6206,18 +7875,25 @@ impl Products {
         smart_collection_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/smart_collections/{}/json?{}",
-            crate::progenitor_support::encode_path(&smart_collection_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/smart_collections/{}/json",
+                self.client.encode_path(smart_collection_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/smart_collections/{smart_collection_id}.json",
+                &[("smart_collection_id", smart_collection_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -6238,12 +7914,19 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/smart_collections/{}/json",
-            crate::progenitor_support::encode_path(&smart_collection_id.to_string()),
+            self.client.encode_path(smart_collection_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-07/smart_collections/{smart_collection_id}.json",
+                    &[("smart_collection_id", smart_collection_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -6263,10 +7946,44 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/smart_collections/{}/json",
-            crate::progenitor_support::encode_path(&smart_collection_id.to_string()),
+            self.client.encode_path(smart_collection_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-07/smart_collections/{smart_collection_id}.json",
+                &[("smart_collection_id", smart_collection_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Removes a smart collection.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-07/smart_collections/{smart_collection_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_delete_smart_collections_param_collection`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202007_delete_smart_collections_param_collection_if_exists(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-07/smart_collections/{}/json",
+            self.client.encode_path(smart_collection_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-07/smart_collections/{smart_collection_id}.json",
+                    &[("smart_collection_id", smart_collection_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -6291,23 +8008,31 @@ impl Products {
         sort_order: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !products.is_empty() {
-            query_args.push(("products".to_string(), products.to_string()));
+            query_args.push(("products", products.to_string()));
         }
         if !sort_order.is_empty() {
-            query_args.push(("sort_order".to_string(), sort_order.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/smart_collections/{}/order.json?{}",
-            crate::progenitor_support::encode_path(&smart_collection_id.to_string()),
-            query_
+            query_args.push(("sort_order", sort_order.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/smart_collections/{}/order.json",
+                self.client.encode_path(smart_collection_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-07/smart_collections/{smart_collection_id}/order.json",
+                    &[("smart_collection_id", smart_collection_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -6353,47 +8078,49 @@ impl Products {
         published_status: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/smart_collections.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-10/smart_collections.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/smart_collections.json", &[])
+        })
     }
 
     /**
@@ -6406,8 +8133,11 @@ impl Products {
     pub async fn create_smart_collections(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-10/smart_collections.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-10/smart_collections.json", &[])
+            })
     }
 
     /**
@@ -6442,32 +8172,40 @@ impl Products {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/smart_collections/count.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-10/smart_collections/count.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/smart_collections/count.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -6487,18 +8225,25 @@ impl Products {
         smart_collection_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/smart_collections/{}/json?{}",
-            crate::progenitor_support::encode_path(&smart_collection_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/smart_collections/{}/json",
+                self.client.encode_path(smart_collection_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/smart_collections/{smart_collection_id}.json",
+                &[("smart_collection_id", smart_collection_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -6519,12 +8264,19 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/smart_collections/{}/json",
-            crate::progenitor_support::encode_path(&smart_collection_id.to_string()),
+            self.client.encode_path(smart_collection_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-10/smart_collections/{smart_collection_id}.json",
+                    &[("smart_collection_id", smart_collection_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -6544,10 +8296,44 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/smart_collections/{}/json",
-            crate::progenitor_support::encode_path(&smart_collection_id.to_string()),
+            self.client.encode_path(smart_collection_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-10/smart_collections/{smart_collection_id}.json",
+                &[("smart_collection_id", smart_collection_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Removes a smart collection.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-10/smart_collections/{smart_collection_id}.json` endpoint.
+     *
+     * As opposed to `delete_smart_collections_param_collection`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_smart_collections_param_collection_if_exists(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-10/smart_collections/{}/json",
+            self.client.encode_path(smart_collection_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-10/smart_collections/{smart_collection_id}.json",
+                    &[("smart_collection_id", smart_collection_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -6572,23 +8358,31 @@ impl Products {
         sort_order: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !products.is_empty() {
-            query_args.push(("products".to_string(), products.to_string()));
+            query_args.push(("products", products.to_string()));
         }
         if !sort_order.is_empty() {
-            query_args.push(("sort_order".to_string(), sort_order.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/smart_collections/{}/order.json?{}",
-            crate::progenitor_support::encode_path(&smart_collection_id.to_string()),
-            query_
+            query_args.push(("sort_order", sort_order.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/smart_collections/{}/order.json",
+                self.client.encode_path(smart_collection_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-10/smart_collections/{smart_collection_id}/order.json",
+                    &[("smart_collection_id", smart_collection_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -6634,47 +8428,49 @@ impl Products {
         published_status: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/smart_collections.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2021-01/smart_collections.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/smart_collections.json", &[])
+        })
     }
 
     /**
@@ -6690,8 +8486,11 @@ impl Products {
     ) -> Result<()> {
         let url = "/admin/api/2021-01/smart_collections.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2021-01/smart_collections.json", &[])
+            })
     }
 
     /**
@@ -6726,32 +8525,40 @@ impl Products {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/smart_collections/count.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2021-01/smart_collections/count.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/smart_collections/count.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -6771,18 +8578,25 @@ impl Products {
         smart_collection_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/smart_collections/{}/json?{}",
-            crate::progenitor_support::encode_path(&smart_collection_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/smart_collections/{}/json",
+                self.client.encode_path(smart_collection_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/smart_collections/{smart_collection_id}.json",
+                &[("smart_collection_id", smart_collection_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -6803,12 +8617,19 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/smart_collections/{}/json",
-            crate::progenitor_support::encode_path(&smart_collection_id.to_string()),
+            self.client.encode_path(smart_collection_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2021-01/smart_collections/{smart_collection_id}.json",
+                    &[("smart_collection_id", smart_collection_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -6828,10 +8649,44 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/smart_collections/{}/json",
-            crate::progenitor_support::encode_path(&smart_collection_id.to_string()),
+            self.client.encode_path(smart_collection_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2021-01/smart_collections/{smart_collection_id}.json",
+                &[("smart_collection_id", smart_collection_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Removes a smart collection.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2021-01/smart_collections/{smart_collection_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_delete_smart_collections_param_collection`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202101_delete_smart_collections_param_collection_if_exists(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2021-01/smart_collections/{}/json",
+            self.client.encode_path(smart_collection_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2021-01/smart_collections/{smart_collection_id}.json",
+                    &[("smart_collection_id", smart_collection_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -6856,23 +8711,31 @@ impl Products {
         sort_order: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !products.is_empty() {
-            query_args.push(("products".to_string(), products.to_string()));
+            query_args.push(("products", products.to_string()));
         }
         if !sort_order.is_empty() {
-            query_args.push(("sort_order".to_string(), sort_order.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/smart_collections/{}/order.json?{}",
-            crate::progenitor_support::encode_path(&smart_collection_id.to_string()),
-            query_
+            query_args.push(("sort_order", sort_order.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/smart_collections/{}/order.json",
+                self.client.encode_path(smart_collection_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2021-01/smart_collections/{smart_collection_id}/order.json",
+                    &[("smart_collection_id", smart_collection_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -6918,47 +8781,51 @@ impl Products {
         published_status: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !ids.is_empty() {
-            query_args.push(("ids".to_string(), ids.to_string()));
+            query_args.push(("ids", ids.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/smart_collections.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/unstable/smart_collections.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/smart_collections.json", &[])
+        })
     }
 
     /**
@@ -6974,8 +8841,11 @@ impl Products {
     ) -> Result<()> {
         let url = "/admin/api/unstable/smart_collections.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/unstable/smart_collections.json", &[])
+            })
     }
 
     /**
@@ -7010,35 +8880,40 @@ impl Products {
         published_at_max: &str,
         published_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !product_id.is_empty() {
-            query_args.push(("product_id".to_string(), product_id.to_string()));
+            query_args.push(("product_id", product_id.to_string()));
         }
         if !published_at_max.is_empty() {
-            query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
+            query_args.push(("published_at_max", published_at_max.to_string()));
         }
         if !published_at_min.is_empty() {
-            query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
+            query_args.push(("published_at_min", published_at_min.to_string()));
         }
         if !published_status.is_empty() {
-            query_args.push(("published_status".to_string(), published_status.to_string()));
+            query_args.push(("published_status", published_status.to_string()));
         }
         if !title.is_empty() {
-            query_args.push(("title".to_string(), title.to_string()));
+            query_args.push(("title", title.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/smart_collections/count.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/unstable/smart_collections/count.json",
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/smart_collections/count.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -7058,18 +8933,25 @@ impl Products {
         smart_collection_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/smart_collections/{}/json?{}",
-            crate::progenitor_support::encode_path(&smart_collection_id.to_string()),
-            query_
+            query_args.push(("fields", fields.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/smart_collections/{}/json",
+                self.client.encode_path(smart_collection_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/smart_collections/{smart_collection_id}.json",
+                &[("smart_collection_id", smart_collection_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -7090,12 +8972,19 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/smart_collections/{}/json",
-            crate::progenitor_support::encode_path(&smart_collection_id.to_string()),
+            self.client.encode_path(smart_collection_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/unstable/smart_collections/{smart_collection_id}.json",
+                    &[("smart_collection_id", smart_collection_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -7115,10 +9004,44 @@ impl Products {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/smart_collections/{}/json",
-            crate::progenitor_support::encode_path(&smart_collection_id.to_string()),
+            self.client.encode_path(smart_collection_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/unstable/smart_collections/{smart_collection_id}.json",
+                &[("smart_collection_id", smart_collection_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Removes a smart collection.
+     *
+     * This function performs a `DELETE` to the `/admin/api/unstable/smart_collections/{smart_collection_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_delete_smart_collections_param_collection`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_unstable_delete_smart_collections_param_collection_if_exists(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/unstable/smart_collections/{}/json",
+            self.client.encode_path(smart_collection_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/unstable/smart_collections/{smart_collection_id}.json",
+                    &[("smart_collection_id", smart_collection_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -7143,22 +9066,3723 @@ impl Products {
         sort_order: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !products.is_empty() {
-            query_args.push(("products".to_string(), products.to_string()));
+            query_args.push(("products", products.to_string()));
         }
         if !sort_order.is_empty() {
-            query_args.push(("sort_order".to_string(), sort_order.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/smart_collections/{}/order.json?{}",
-            crate::progenitor_support::encode_path(&smart_collection_id.to_string()),
-            query_
+            query_args.push(("sort_order", sort_order.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/smart_collections/{}/order.json",
+                self.client.encode_path(smart_collection_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/unstable/smart_collections/{smart_collection_id}/order.json",
+                    &[("smart_collection_id", smart_collection_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`Products`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ProductsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ProductsApi: Send + Sync {
+    async fn deprecated_202001_get_collect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_collects(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202001_get_collects_param_collect(
+        &self,
+        collect_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_collects_param_collect(&self, collect_id: &str)
+        -> Result<()>;
+    async fn deprecated_202001_delete_collects_param_collect_if_exists(
+        &self,
+        collect_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202001_get_collects_count(&self, collection_id: i64) -> Result<()>;
+    async fn deprecated_202004_get_collect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_create_collects(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202004_get_collects_param_collect(
+        &self,
+        collect_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_collects_param_collect(&self, collect_id: &str)
+        -> Result<()>;
+    async fn deprecated_202004_delete_collects_param_collect_if_exists(
+        &self,
+        collect_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202004_get_collects_count(&self, collection_id: i64) -> Result<()>;
+    async fn deprecated_202007_get_collect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_create_collects(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202007_get_collects_param_collect(
+        &self,
+        collect_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_collects_param_collect(&self, collect_id: &str)
+        -> Result<()>;
+    async fn deprecated_202007_delete_collects_param_collect_if_exists(
+        &self,
+        collect_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202007_get_collects_count(&self, collection_id: i64) -> Result<()>;
+    async fn get_collect(&self, limit: &str, since_id: &str, fields: &str) -> Result<()>;
+    async fn create_collects(&self, body: &serde_json::Value) -> Result<()>;
+    async fn get_collects_param_collect(&self, collect_id: &str, fields: &str) -> Result<()>;
+    async fn delete_collects_param_collect(&self, collect_id: &str) -> Result<()>;
+    async fn delete_collects_param_collect_if_exists(&self, collect_id: &str) -> Result<bool>;
+    async fn get_collects_count(&self, collection_id: i64) -> Result<()>;
+    async fn deprecated_202101_get_collect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_create_collects(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202101_get_collects_param_collect(
+        &self,
+        collect_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_collects_param_collect(&self, collect_id: &str)
+        -> Result<()>;
+    async fn deprecated_202101_delete_collects_param_collect_if_exists(
+        &self,
+        collect_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202101_get_collects_count(&self, collection_id: i64) -> Result<()>;
+    async fn deprecated_unstable_get_collect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create_collects(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_unstable_get_collects_param_collect(
+        &self,
+        collect_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_collects_param_collect(
+        &self,
+        collect_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_collects_param_collect_if_exists(
+        &self,
+        collect_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_unstable_get_collects_count(&self, collection_id: i64) -> Result<()>;
+    async fn deprecated_202001_get_collections_param_collection(
+        &self,
+        collection_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_collections_param_collection_products(
+        &self,
+        collection_id: &str,
+        limit: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_collections_param_collection(
+        &self,
+        collection_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_collections_param_collection_products(
+        &self,
+        collection_id: &str,
+        limit: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_collections_param_collection(
+        &self,
+        collection_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_collections_param_collection_products(
+        &self,
+        collection_id: &str,
+        limit: &str,
+    ) -> Result<()>;
+    async fn get_collections_param_collection(
+        &self,
+        collection_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn get_collections_param_collection_products(
+        &self,
+        collection_id: &str,
+        limit: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_collections_param_collection(
+        &self,
+        collection_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_collections_param_collection_products(
+        &self,
+        collection_id: &str,
+        limit: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_collections_param_collection(
+        &self,
+        collection_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_collections_param_collection_products(
+        &self,
+        collection_id: &str,
+        limit: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_custom_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_custom_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_custom_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_update_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_custom_collections_param_collection_if_exists(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202004_get_custom_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_create_custom_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_custom_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_update_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_custom_collections_param_collection_if_exists(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202007_get_custom_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_create_custom_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_custom_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_update_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_custom_collections_param_collection_if_exists(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<bool>;
+    async fn get_custom_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn create_custom_collections(&self, body: &serde_json::Value) -> Result<()>;
+    async fn get_custom_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()>;
+    async fn get_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn update_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn delete_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<()>;
+    async fn delete_custom_collections_param_collection_if_exists(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202101_get_custom_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_create_custom_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_custom_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_update_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_custom_collections_param_collection_if_exists(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_unstable_get_custom_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create_custom_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_custom_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_update_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_custom_collections_param_collection_if_exists(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202001_get(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        title: &str,
+        vendor: &str,
+        handle: &str,
+        product_type: &str,
+        status: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+        presentment_currencies: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_create(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202001_get_count(
+        &self,
+        vendor: &str,
+        product_type: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_param(&self, product_id: &str, fields: &str) -> Result<()>;
+    async fn deprecated_202001_update_param(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_param(&self, product_id: &str) -> Result<()>;
+    async fn deprecated_202001_delete_param_if_exists(&self, product_id: &str) -> Result<bool>;
+    async fn deprecated_202004_get(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        title: &str,
+        vendor: &str,
+        handle: &str,
+        product_type: &str,
+        status: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+        presentment_currencies: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_create(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202004_get_count(
+        &self,
+        vendor: &str,
+        product_type: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_param(&self, product_id: &str, fields: &str) -> Result<()>;
+    async fn deprecated_202004_update_param(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_param(&self, product_id: &str) -> Result<()>;
+    async fn deprecated_202004_delete_param_if_exists(&self, product_id: &str) -> Result<bool>;
+    async fn deprecated_202007_get(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        title: &str,
+        vendor: &str,
+        handle: &str,
+        product_type: &str,
+        status: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+        presentment_currencies: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_create(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202007_get_count(
+        &self,
+        vendor: &str,
+        product_type: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_param(&self, product_id: &str, fields: &str) -> Result<()>;
+    async fn deprecated_202007_update_param(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_param(&self, product_id: &str) -> Result<()>;
+    async fn deprecated_202007_delete_param_if_exists(&self, product_id: &str) -> Result<bool>;
+    async fn get(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        title: &str,
+        vendor: &str,
+        handle: &str,
+        product_type: &str,
+        status: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+        presentment_currencies: &str,
+    ) -> Result<()>;
+    async fn create(&self, body: &serde_json::Value) -> Result<()>;
+    async fn get_count(
+        &self,
+        vendor: &str,
+        product_type: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()>;
+    async fn get_param(&self, product_id: &str, fields: &str) -> Result<()>;
+    async fn update_param(&self, product_id: &str, body: &serde_json::Value) -> Result<()>;
+    async fn delete_param(&self, product_id: &str) -> Result<()>;
+    async fn delete_param_if_exists(&self, product_id: &str) -> Result<bool>;
+    async fn deprecated_202101_get(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        title: &str,
+        vendor: &str,
+        handle: &str,
+        product_type: &str,
+        status: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+        presentment_currencies: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_create(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202101_get_count(
+        &self,
+        vendor: &str,
+        product_type: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_param(&self, product_id: &str, fields: &str) -> Result<()>;
+    async fn deprecated_202101_update_param(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_param(&self, product_id: &str) -> Result<()>;
+    async fn deprecated_202101_delete_param_if_exists(&self, product_id: &str) -> Result<bool>;
+    async fn deprecated_unstable_get(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        title: &str,
+        vendor: &str,
+        handle: &str,
+        product_type: &str,
+        status: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+        presentment_currencies: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_unstable_get_count(
+        &self,
+        vendor: &str,
+        product_type: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_param(&self, product_id: &str, fields: &str) -> Result<()>;
+    async fn deprecated_unstable_update_param(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_param(&self, product_id: &str) -> Result<()>;
+    async fn deprecated_unstable_delete_param_if_exists(&self, product_id: &str) -> Result<bool>;
+    async fn deprecated_202001_get_param_image(
+        &self,
+        product_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_param_images(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_param_images_count(
+        &self,
+        product_id: &str,
+        since_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_update_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_param_images_image_if_exists(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202004_get_param_image(
+        &self,
+        product_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_create_param_images(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_param_images_count(
+        &self,
+        product_id: &str,
+        since_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_update_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_param_images_image_if_exists(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202007_get_param_image(
+        &self,
+        product_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_create_param_images(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_param_images_count(
+        &self,
+        product_id: &str,
+        since_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_update_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_param_images_image_if_exists(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<bool>;
+    async fn get_param_image(&self, product_id: &str, since_id: &str, fields: &str) -> Result<()>;
+    async fn create_param_images(&self, product_id: &str, body: &serde_json::Value) -> Result<()>;
+    async fn get_param_images_count(&self, product_id: &str, since_id: &str) -> Result<()>;
+    async fn get_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn update_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn delete_param_images_image(&self, product_id: &str, image_id: &str) -> Result<()>;
+    async fn delete_param_images_image_if_exists(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202101_get_param_image(
+        &self,
+        product_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_create_param_images(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_param_images_count(
+        &self,
+        product_id: &str,
+        since_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_update_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_param_images_image_if_exists(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_unstable_get_param_image(
+        &self,
+        product_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create_param_images(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_param_images_count(
+        &self,
+        product_id: &str,
+        since_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_update_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_param_images_image_if_exists(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202001_get_smart_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_smart_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_smart_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_update_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_smart_collections_param_collection_if_exists(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202001_update_smart_collections_param_collection_order(
+        &self,
+        smart_collection_id: &str,
+        products: &str,
+        sort_order: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_smart_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_create_smart_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_smart_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_update_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_smart_collections_param_collection_if_exists(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202004_update_smart_collections_param_collection_order(
+        &self,
+        smart_collection_id: &str,
+        products: &str,
+        sort_order: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_smart_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_create_smart_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_smart_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_update_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_smart_collections_param_collection_if_exists(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202007_update_smart_collections_param_collection_order(
+        &self,
+        smart_collection_id: &str,
+        products: &str,
+        sort_order: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn get_smart_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn create_smart_collections(&self, body: &serde_json::Value) -> Result<()>;
+    async fn get_smart_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()>;
+    async fn get_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn update_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn delete_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<()>;
+    async fn delete_smart_collections_param_collection_if_exists(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<bool>;
+    async fn update_smart_collections_param_collection_order(
+        &self,
+        smart_collection_id: &str,
+        products: &str,
+        sort_order: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_smart_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_create_smart_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_smart_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_update_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_smart_collections_param_collection_if_exists(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202101_update_smart_collections_param_collection_order(
+        &self,
+        smart_collection_id: &str,
+        products: &str,
+        sort_order: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_smart_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create_smart_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_smart_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_update_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_smart_collections_param_collection_if_exists(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_unstable_update_smart_collections_param_collection_order(
+        &self,
+        smart_collection_id: &str,
+        products: &str,
+        sort_order: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ProductsApi for Products {
+    async fn deprecated_202001_get_collect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_202001_get_collect(self, limit, since_id, fields).await
+    }
+    async fn deprecated_202001_create_collects(&self, body: &serde_json::Value) -> Result<()> {
+        Products::deprecated_202001_create_collects(self, body).await
+    }
+    async fn deprecated_202001_get_collects_param_collect(
+        &self,
+        collect_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_202001_get_collects_param_collect(self, collect_id, fields).await
+    }
+    async fn deprecated_202001_delete_collects_param_collect(
+        &self,
+        collect_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_202001_delete_collects_param_collect(self, collect_id).await
+    }
+    async fn deprecated_202001_delete_collects_param_collect_if_exists(
+        &self,
+        collect_id: &str,
+    ) -> Result<bool> {
+        Products::deprecated_202001_delete_collects_param_collect_if_exists(self, collect_id).await
+    }
+    async fn deprecated_202001_get_collects_count(&self, collection_id: i64) -> Result<()> {
+        Products::deprecated_202001_get_collects_count(self, collection_id).await
+    }
+    async fn deprecated_202004_get_collect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_202004_get_collect(self, limit, since_id, fields).await
+    }
+    async fn deprecated_202004_create_collects(&self, body: &serde_json::Value) -> Result<()> {
+        Products::deprecated_202004_create_collects(self, body).await
+    }
+    async fn deprecated_202004_get_collects_param_collect(
+        &self,
+        collect_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_202004_get_collects_param_collect(self, collect_id, fields).await
+    }
+    async fn deprecated_202004_delete_collects_param_collect(
+        &self,
+        collect_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_202004_delete_collects_param_collect(self, collect_id).await
+    }
+    async fn deprecated_202004_delete_collects_param_collect_if_exists(
+        &self,
+        collect_id: &str,
+    ) -> Result<bool> {
+        Products::deprecated_202004_delete_collects_param_collect_if_exists(self, collect_id).await
+    }
+    async fn deprecated_202004_get_collects_count(&self, collection_id: i64) -> Result<()> {
+        Products::deprecated_202004_get_collects_count(self, collection_id).await
+    }
+    async fn deprecated_202007_get_collect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_202007_get_collect(self, limit, since_id, fields).await
+    }
+    async fn deprecated_202007_create_collects(&self, body: &serde_json::Value) -> Result<()> {
+        Products::deprecated_202007_create_collects(self, body).await
+    }
+    async fn deprecated_202007_get_collects_param_collect(
+        &self,
+        collect_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_202007_get_collects_param_collect(self, collect_id, fields).await
+    }
+    async fn deprecated_202007_delete_collects_param_collect(
+        &self,
+        collect_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_202007_delete_collects_param_collect(self, collect_id).await
+    }
+    async fn deprecated_202007_delete_collects_param_collect_if_exists(
+        &self,
+        collect_id: &str,
+    ) -> Result<bool> {
+        Products::deprecated_202007_delete_collects_param_collect_if_exists(self, collect_id).await
+    }
+    async fn deprecated_202007_get_collects_count(&self, collection_id: i64) -> Result<()> {
+        Products::deprecated_202007_get_collects_count(self, collection_id).await
+    }
+    async fn get_collect(&self, limit: &str, since_id: &str, fields: &str) -> Result<()> {
+        Products::get_collect(self, limit, since_id, fields).await
+    }
+    async fn create_collects(&self, body: &serde_json::Value) -> Result<()> {
+        Products::create_collects(self, body).await
+    }
+    async fn get_collects_param_collect(&self, collect_id: &str, fields: &str) -> Result<()> {
+        Products::get_collects_param_collect(self, collect_id, fields).await
+    }
+    async fn delete_collects_param_collect(&self, collect_id: &str) -> Result<()> {
+        Products::delete_collects_param_collect(self, collect_id).await
+    }
+    async fn delete_collects_param_collect_if_exists(&self, collect_id: &str) -> Result<bool> {
+        Products::delete_collects_param_collect_if_exists(self, collect_id).await
+    }
+    async fn get_collects_count(&self, collection_id: i64) -> Result<()> {
+        Products::get_collects_count(self, collection_id).await
+    }
+    async fn deprecated_202101_get_collect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_202101_get_collect(self, limit, since_id, fields).await
+    }
+    async fn deprecated_202101_create_collects(&self, body: &serde_json::Value) -> Result<()> {
+        Products::deprecated_202101_create_collects(self, body).await
+    }
+    async fn deprecated_202101_get_collects_param_collect(
+        &self,
+        collect_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_202101_get_collects_param_collect(self, collect_id, fields).await
+    }
+    async fn deprecated_202101_delete_collects_param_collect(
+        &self,
+        collect_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_202101_delete_collects_param_collect(self, collect_id).await
+    }
+    async fn deprecated_202101_delete_collects_param_collect_if_exists(
+        &self,
+        collect_id: &str,
+    ) -> Result<bool> {
+        Products::deprecated_202101_delete_collects_param_collect_if_exists(self, collect_id).await
+    }
+    async fn deprecated_202101_get_collects_count(&self, collection_id: i64) -> Result<()> {
+        Products::deprecated_202101_get_collects_count(self, collection_id).await
+    }
+    async fn deprecated_unstable_get_collect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_unstable_get_collect(self, limit, since_id, fields).await
+    }
+    async fn deprecated_unstable_create_collects(&self, body: &serde_json::Value) -> Result<()> {
+        Products::deprecated_unstable_create_collects(self, body).await
+    }
+    async fn deprecated_unstable_get_collects_param_collect(
+        &self,
+        collect_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_unstable_get_collects_param_collect(self, collect_id, fields).await
+    }
+    async fn deprecated_unstable_delete_collects_param_collect(
+        &self,
+        collect_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_unstable_delete_collects_param_collect(self, collect_id).await
+    }
+    async fn deprecated_unstable_delete_collects_param_collect_if_exists(
+        &self,
+        collect_id: &str,
+    ) -> Result<bool> {
+        Products::deprecated_unstable_delete_collects_param_collect_if_exists(self, collect_id)
+            .await
+    }
+    async fn deprecated_unstable_get_collects_count(&self, collection_id: i64) -> Result<()> {
+        Products::deprecated_unstable_get_collects_count(self, collection_id).await
+    }
+    async fn deprecated_202001_get_collections_param_collection(
+        &self,
+        collection_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_202001_get_collections_param_collection(self, collection_id, fields)
+            .await
+    }
+    async fn deprecated_202001_get_collections_param_collection_products(
+        &self,
+        collection_id: &str,
+        limit: &str,
+    ) -> Result<()> {
+        Products::deprecated_202001_get_collections_param_collection_products(
+            self,
+            collection_id,
+            limit,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_collections_param_collection(
+        &self,
+        collection_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_202004_get_collections_param_collection(self, collection_id, fields)
+            .await
+    }
+    async fn deprecated_202004_get_collections_param_collection_products(
+        &self,
+        collection_id: &str,
+        limit: &str,
+    ) -> Result<()> {
+        Products::deprecated_202004_get_collections_param_collection_products(
+            self,
+            collection_id,
+            limit,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_collections_param_collection(
+        &self,
+        collection_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_202007_get_collections_param_collection(self, collection_id, fields)
+            .await
+    }
+    async fn deprecated_202007_get_collections_param_collection_products(
+        &self,
+        collection_id: &str,
+        limit: &str,
+    ) -> Result<()> {
+        Products::deprecated_202007_get_collections_param_collection_products(
+            self,
+            collection_id,
+            limit,
+        )
+        .await
+    }
+    async fn get_collections_param_collection(
+        &self,
+        collection_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::get_collections_param_collection(self, collection_id, fields).await
+    }
+    async fn get_collections_param_collection_products(
+        &self,
+        collection_id: &str,
+        limit: &str,
+    ) -> Result<()> {
+        Products::get_collections_param_collection_products(self, collection_id, limit).await
+    }
+    async fn deprecated_202101_get_collections_param_collection(
+        &self,
+        collection_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_202101_get_collections_param_collection(self, collection_id, fields)
+            .await
+    }
+    async fn deprecated_202101_get_collections_param_collection_products(
+        &self,
+        collection_id: &str,
+        limit: &str,
+    ) -> Result<()> {
+        Products::deprecated_202101_get_collections_param_collection_products(
+            self,
+            collection_id,
+            limit,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_collections_param_collection(
+        &self,
+        collection_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_unstable_get_collections_param_collection(self, collection_id, fields)
+            .await
+    }
+    async fn deprecated_unstable_get_collections_param_collection_products(
+        &self,
+        collection_id: &str,
+        limit: &str,
+    ) -> Result<()> {
+        Products::deprecated_unstable_get_collections_param_collection_products(
+            self,
+            collection_id,
+            limit,
+        )
+        .await
+    }
+    async fn deprecated_202001_get_custom_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_202001_get_custom_collection(
+            self,
+            limit,
+            ids,
+            since_id,
+            title,
+            product_id,
+            handle,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202001_create_custom_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202001_create_custom_collections(self, body).await
+    }
+    async fn deprecated_202001_get_custom_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        Products::deprecated_202001_get_custom_collections_count(
+            self,
+            title,
+            product_id,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        )
+        .await
+    }
+    async fn deprecated_202001_get_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_202001_get_custom_collections_param_collection(
+            self,
+            custom_collection_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202001_update_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202001_update_custom_collections_param_collection(
+            self,
+            custom_collection_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202001_delete_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_202001_delete_custom_collections_param_collection(
+            self,
+            custom_collection_id,
+        )
+        .await
+    }
+    async fn deprecated_202001_delete_custom_collections_param_collection_if_exists(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<bool> {
+        Products::deprecated_202001_delete_custom_collections_param_collection_if_exists(
+            self,
+            custom_collection_id,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_custom_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_202004_get_custom_collection(
+            self,
+            limit,
+            ids,
+            since_id,
+            title,
+            product_id,
+            handle,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202004_create_custom_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202004_create_custom_collections(self, body).await
+    }
+    async fn deprecated_202004_get_custom_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        Products::deprecated_202004_get_custom_collections_count(
+            self,
+            title,
+            product_id,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_202004_get_custom_collections_param_collection(
+            self,
+            custom_collection_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202004_update_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202004_update_custom_collections_param_collection(
+            self,
+            custom_collection_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202004_delete_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_202004_delete_custom_collections_param_collection(
+            self,
+            custom_collection_id,
+        )
+        .await
+    }
+    async fn deprecated_202004_delete_custom_collections_param_collection_if_exists(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<bool> {
+        Products::deprecated_202004_delete_custom_collections_param_collection_if_exists(
+            self,
+            custom_collection_id,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_custom_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_202007_get_custom_collection(
+            self,
+            limit,
+            ids,
+            since_id,
+            title,
+            product_id,
+            handle,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202007_create_custom_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202007_create_custom_collections(self, body).await
+    }
+    async fn deprecated_202007_get_custom_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        Products::deprecated_202007_get_custom_collections_count(
+            self,
+            title,
+            product_id,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_202007_get_custom_collections_param_collection(
+            self,
+            custom_collection_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202007_update_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202007_update_custom_collections_param_collection(
+            self,
+            custom_collection_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202007_delete_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_202007_delete_custom_collections_param_collection(
+            self,
+            custom_collection_id,
+        )
+        .await
+    }
+    async fn deprecated_202007_delete_custom_collections_param_collection_if_exists(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<bool> {
+        Products::deprecated_202007_delete_custom_collections_param_collection_if_exists(
+            self,
+            custom_collection_id,
+        )
+        .await
+    }
+    async fn get_custom_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::get_custom_collection(
+            self,
+            limit,
+            ids,
+            since_id,
+            title,
+            product_id,
+            handle,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+        )
+        .await
+    }
+    async fn create_custom_collections(&self, body: &serde_json::Value) -> Result<()> {
+        Products::create_custom_collections(self, body).await
+    }
+    async fn get_custom_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        Products::get_custom_collections_count(
+            self,
+            title,
+            product_id,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        )
+        .await
+    }
+    async fn get_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::get_custom_collections_param_collection(self, custom_collection_id, fields).await
+    }
+    async fn update_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::update_custom_collections_param_collection(self, custom_collection_id, body).await
+    }
+    async fn delete_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<()> {
+        Products::delete_custom_collections_param_collection(self, custom_collection_id).await
+    }
+    async fn delete_custom_collections_param_collection_if_exists(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<bool> {
+        Products::delete_custom_collections_param_collection_if_exists(self, custom_collection_id)
+            .await
+    }
+    async fn deprecated_202101_get_custom_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_202101_get_custom_collection(
+            self,
+            limit,
+            ids,
+            since_id,
+            title,
+            product_id,
+            handle,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202101_create_custom_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202101_create_custom_collections(self, body).await
+    }
+    async fn deprecated_202101_get_custom_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        Products::deprecated_202101_get_custom_collections_count(
+            self,
+            title,
+            product_id,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        )
+        .await
+    }
+    async fn deprecated_202101_get_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_202101_get_custom_collections_param_collection(
+            self,
+            custom_collection_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202101_update_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202101_update_custom_collections_param_collection(
+            self,
+            custom_collection_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202101_delete_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_202101_delete_custom_collections_param_collection(
+            self,
+            custom_collection_id,
+        )
+        .await
+    }
+    async fn deprecated_202101_delete_custom_collections_param_collection_if_exists(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<bool> {
+        Products::deprecated_202101_delete_custom_collections_param_collection_if_exists(
+            self,
+            custom_collection_id,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_custom_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_unstable_get_custom_collection(
+            self,
+            limit,
+            ids,
+            since_id,
+            title,
+            product_id,
+            handle,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_unstable_create_custom_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_unstable_create_custom_collections(self, body).await
+    }
+    async fn deprecated_unstable_get_custom_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        Products::deprecated_unstable_get_custom_collections_count(
+            self,
+            title,
+            product_id,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_unstable_get_custom_collections_param_collection(
+            self,
+            custom_collection_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_unstable_update_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_unstable_update_custom_collections_param_collection(
+            self,
+            custom_collection_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_unstable_delete_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_unstable_delete_custom_collections_param_collection(
+            self,
+            custom_collection_id,
+        )
+        .await
+    }
+    async fn deprecated_unstable_delete_custom_collections_param_collection_if_exists(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<bool> {
+        Products::deprecated_unstable_delete_custom_collections_param_collection_if_exists(
+            self,
+            custom_collection_id,
+        )
+        .await
+    }
+    async fn deprecated_202001_get(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        title: &str,
+        vendor: &str,
+        handle: &str,
+        product_type: &str,
+        status: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+        presentment_currencies: &str,
+    ) -> Result<()> {
+        Products::deprecated_202001_get(
+            self,
+            ids,
+            limit,
+            since_id,
+            title,
+            vendor,
+            handle,
+            product_type,
+            status,
+            collection_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+            presentment_currencies,
+        )
+        .await
+    }
+    async fn deprecated_202001_create(&self, body: &serde_json::Value) -> Result<()> {
+        Products::deprecated_202001_create(self, body).await
+    }
+    async fn deprecated_202001_get_count(
+        &self,
+        vendor: &str,
+        product_type: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        Products::deprecated_202001_get_count(
+            self,
+            vendor,
+            product_type,
+            collection_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        )
+        .await
+    }
+    async fn deprecated_202001_get_param(&self, product_id: &str, fields: &str) -> Result<()> {
+        Products::deprecated_202001_get_param(self, product_id, fields).await
+    }
+    async fn deprecated_202001_update_param(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202001_update_param(self, product_id, body).await
+    }
+    async fn deprecated_202001_delete_param(&self, product_id: &str) -> Result<()> {
+        Products::deprecated_202001_delete_param(self, product_id).await
+    }
+    async fn deprecated_202001_delete_param_if_exists(&self, product_id: &str) -> Result<bool> {
+        Products::deprecated_202001_delete_param_if_exists(self, product_id).await
+    }
+    async fn deprecated_202004_get(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        title: &str,
+        vendor: &str,
+        handle: &str,
+        product_type: &str,
+        status: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+        presentment_currencies: &str,
+    ) -> Result<()> {
+        Products::deprecated_202004_get(
+            self,
+            ids,
+            limit,
+            since_id,
+            title,
+            vendor,
+            handle,
+            product_type,
+            status,
+            collection_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+            presentment_currencies,
+        )
+        .await
+    }
+    async fn deprecated_202004_create(&self, body: &serde_json::Value) -> Result<()> {
+        Products::deprecated_202004_create(self, body).await
+    }
+    async fn deprecated_202004_get_count(
+        &self,
+        vendor: &str,
+        product_type: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        Products::deprecated_202004_get_count(
+            self,
+            vendor,
+            product_type,
+            collection_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_param(&self, product_id: &str, fields: &str) -> Result<()> {
+        Products::deprecated_202004_get_param(self, product_id, fields).await
+    }
+    async fn deprecated_202004_update_param(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202004_update_param(self, product_id, body).await
+    }
+    async fn deprecated_202004_delete_param(&self, product_id: &str) -> Result<()> {
+        Products::deprecated_202004_delete_param(self, product_id).await
+    }
+    async fn deprecated_202004_delete_param_if_exists(&self, product_id: &str) -> Result<bool> {
+        Products::deprecated_202004_delete_param_if_exists(self, product_id).await
+    }
+    async fn deprecated_202007_get(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        title: &str,
+        vendor: &str,
+        handle: &str,
+        product_type: &str,
+        status: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+        presentment_currencies: &str,
+    ) -> Result<()> {
+        Products::deprecated_202007_get(
+            self,
+            ids,
+            limit,
+            since_id,
+            title,
+            vendor,
+            handle,
+            product_type,
+            status,
+            collection_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+            presentment_currencies,
+        )
+        .await
+    }
+    async fn deprecated_202007_create(&self, body: &serde_json::Value) -> Result<()> {
+        Products::deprecated_202007_create(self, body).await
+    }
+    async fn deprecated_202007_get_count(
+        &self,
+        vendor: &str,
+        product_type: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        Products::deprecated_202007_get_count(
+            self,
+            vendor,
+            product_type,
+            collection_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_param(&self, product_id: &str, fields: &str) -> Result<()> {
+        Products::deprecated_202007_get_param(self, product_id, fields).await
+    }
+    async fn deprecated_202007_update_param(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202007_update_param(self, product_id, body).await
+    }
+    async fn deprecated_202007_delete_param(&self, product_id: &str) -> Result<()> {
+        Products::deprecated_202007_delete_param(self, product_id).await
+    }
+    async fn deprecated_202007_delete_param_if_exists(&self, product_id: &str) -> Result<bool> {
+        Products::deprecated_202007_delete_param_if_exists(self, product_id).await
+    }
+    async fn get(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        title: &str,
+        vendor: &str,
+        handle: &str,
+        product_type: &str,
+        status: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+        presentment_currencies: &str,
+    ) -> Result<()> {
+        Products::get(
+            self,
+            ids,
+            limit,
+            since_id,
+            title,
+            vendor,
+            handle,
+            product_type,
+            status,
+            collection_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+            presentment_currencies,
+        )
+        .await
+    }
+    async fn create(&self, body: &serde_json::Value) -> Result<()> {
+        Products::create(self, body).await
+    }
+    async fn get_count(
+        &self,
+        vendor: &str,
+        product_type: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        Products::get_count(
+            self,
+            vendor,
+            product_type,
+            collection_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        )
+        .await
+    }
+    async fn get_param(&self, product_id: &str, fields: &str) -> Result<()> {
+        Products::get_param(self, product_id, fields).await
+    }
+    async fn update_param(&self, product_id: &str, body: &serde_json::Value) -> Result<()> {
+        Products::update_param(self, product_id, body).await
+    }
+    async fn delete_param(&self, product_id: &str) -> Result<()> {
+        Products::delete_param(self, product_id).await
+    }
+    async fn delete_param_if_exists(&self, product_id: &str) -> Result<bool> {
+        Products::delete_param_if_exists(self, product_id).await
+    }
+    async fn deprecated_202101_get(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        title: &str,
+        vendor: &str,
+        handle: &str,
+        product_type: &str,
+        status: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+        presentment_currencies: &str,
+    ) -> Result<()> {
+        Products::deprecated_202101_get(
+            self,
+            ids,
+            limit,
+            since_id,
+            title,
+            vendor,
+            handle,
+            product_type,
+            status,
+            collection_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+            presentment_currencies,
+        )
+        .await
+    }
+    async fn deprecated_202101_create(&self, body: &serde_json::Value) -> Result<()> {
+        Products::deprecated_202101_create(self, body).await
+    }
+    async fn deprecated_202101_get_count(
+        &self,
+        vendor: &str,
+        product_type: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        Products::deprecated_202101_get_count(
+            self,
+            vendor,
+            product_type,
+            collection_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        )
+        .await
+    }
+    async fn deprecated_202101_get_param(&self, product_id: &str, fields: &str) -> Result<()> {
+        Products::deprecated_202101_get_param(self, product_id, fields).await
+    }
+    async fn deprecated_202101_update_param(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202101_update_param(self, product_id, body).await
+    }
+    async fn deprecated_202101_delete_param(&self, product_id: &str) -> Result<()> {
+        Products::deprecated_202101_delete_param(self, product_id).await
+    }
+    async fn deprecated_202101_delete_param_if_exists(&self, product_id: &str) -> Result<bool> {
+        Products::deprecated_202101_delete_param_if_exists(self, product_id).await
+    }
+    async fn deprecated_unstable_get(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        title: &str,
+        vendor: &str,
+        handle: &str,
+        product_type: &str,
+        status: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+        presentment_currencies: &str,
+    ) -> Result<()> {
+        Products::deprecated_unstable_get(
+            self,
+            ids,
+            limit,
+            since_id,
+            title,
+            vendor,
+            handle,
+            product_type,
+            status,
+            collection_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+            presentment_currencies,
+        )
+        .await
+    }
+    async fn deprecated_unstable_create(&self, body: &serde_json::Value) -> Result<()> {
+        Products::deprecated_unstable_create(self, body).await
+    }
+    async fn deprecated_unstable_get_count(
+        &self,
+        vendor: &str,
+        product_type: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        Products::deprecated_unstable_get_count(
+            self,
+            vendor,
+            product_type,
+            collection_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_param(&self, product_id: &str, fields: &str) -> Result<()> {
+        Products::deprecated_unstable_get_param(self, product_id, fields).await
+    }
+    async fn deprecated_unstable_update_param(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_unstable_update_param(self, product_id, body).await
+    }
+    async fn deprecated_unstable_delete_param(&self, product_id: &str) -> Result<()> {
+        Products::deprecated_unstable_delete_param(self, product_id).await
+    }
+    async fn deprecated_unstable_delete_param_if_exists(&self, product_id: &str) -> Result<bool> {
+        Products::deprecated_unstable_delete_param_if_exists(self, product_id).await
+    }
+    async fn deprecated_202001_get_param_image(
+        &self,
+        product_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_202001_get_param_image(self, product_id, since_id, fields).await
+    }
+    async fn deprecated_202001_create_param_images(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202001_create_param_images(self, product_id, body).await
+    }
+    async fn deprecated_202001_get_param_images_count(
+        &self,
+        product_id: &str,
+        since_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_202001_get_param_images_count(self, product_id, since_id).await
+    }
+    async fn deprecated_202001_get_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_202001_get_param_images_image(self, product_id, image_id, fields).await
+    }
+    async fn deprecated_202001_update_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202001_update_param_images_image(self, product_id, image_id, body)
+            .await
+    }
+    async fn deprecated_202001_delete_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_202001_delete_param_images_image(self, product_id, image_id).await
+    }
+    async fn deprecated_202001_delete_param_images_image_if_exists(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<bool> {
+        Products::deprecated_202001_delete_param_images_image_if_exists(self, product_id, image_id)
+            .await
+    }
+    async fn deprecated_202004_get_param_image(
+        &self,
+        product_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_202004_get_param_image(self, product_id, since_id, fields).await
+    }
+    async fn deprecated_202004_create_param_images(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202004_create_param_images(self, product_id, body).await
+    }
+    async fn deprecated_202004_get_param_images_count(
+        &self,
+        product_id: &str,
+        since_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_202004_get_param_images_count(self, product_id, since_id).await
+    }
+    async fn deprecated_202004_get_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_202004_get_param_images_image(self, product_id, image_id, fields).await
+    }
+    async fn deprecated_202004_update_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202004_update_param_images_image(self, product_id, image_id, body)
+            .await
+    }
+    async fn deprecated_202004_delete_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_202004_delete_param_images_image(self, product_id, image_id).await
+    }
+    async fn deprecated_202004_delete_param_images_image_if_exists(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<bool> {
+        Products::deprecated_202004_delete_param_images_image_if_exists(self, product_id, image_id)
+            .await
+    }
+    async fn deprecated_202007_get_param_image(
+        &self,
+        product_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_202007_get_param_image(self, product_id, since_id, fields).await
+    }
+    async fn deprecated_202007_create_param_images(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202007_create_param_images(self, product_id, body).await
+    }
+    async fn deprecated_202007_get_param_images_count(
+        &self,
+        product_id: &str,
+        since_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_202007_get_param_images_count(self, product_id, since_id).await
+    }
+    async fn deprecated_202007_get_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_202007_get_param_images_image(self, product_id, image_id, fields).await
+    }
+    async fn deprecated_202007_update_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202007_update_param_images_image(self, product_id, image_id, body)
+            .await
+    }
+    async fn deprecated_202007_delete_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_202007_delete_param_images_image(self, product_id, image_id).await
+    }
+    async fn deprecated_202007_delete_param_images_image_if_exists(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<bool> {
+        Products::deprecated_202007_delete_param_images_image_if_exists(self, product_id, image_id)
+            .await
+    }
+    async fn get_param_image(&self, product_id: &str, since_id: &str, fields: &str) -> Result<()> {
+        Products::get_param_image(self, product_id, since_id, fields).await
+    }
+    async fn create_param_images(&self, product_id: &str, body: &serde_json::Value) -> Result<()> {
+        Products::create_param_images(self, product_id, body).await
+    }
+    async fn get_param_images_count(&self, product_id: &str, since_id: &str) -> Result<()> {
+        Products::get_param_images_count(self, product_id, since_id).await
+    }
+    async fn get_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::get_param_images_image(self, product_id, image_id, fields).await
+    }
+    async fn update_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::update_param_images_image(self, product_id, image_id, body).await
+    }
+    async fn delete_param_images_image(&self, product_id: &str, image_id: &str) -> Result<()> {
+        Products::delete_param_images_image(self, product_id, image_id).await
+    }
+    async fn delete_param_images_image_if_exists(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<bool> {
+        Products::delete_param_images_image_if_exists(self, product_id, image_id).await
+    }
+    async fn deprecated_202101_get_param_image(
+        &self,
+        product_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_202101_get_param_image(self, product_id, since_id, fields).await
+    }
+    async fn deprecated_202101_create_param_images(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202101_create_param_images(self, product_id, body).await
+    }
+    async fn deprecated_202101_get_param_images_count(
+        &self,
+        product_id: &str,
+        since_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_202101_get_param_images_count(self, product_id, since_id).await
+    }
+    async fn deprecated_202101_get_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_202101_get_param_images_image(self, product_id, image_id, fields).await
+    }
+    async fn deprecated_202101_update_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202101_update_param_images_image(self, product_id, image_id, body)
+            .await
+    }
+    async fn deprecated_202101_delete_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_202101_delete_param_images_image(self, product_id, image_id).await
+    }
+    async fn deprecated_202101_delete_param_images_image_if_exists(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<bool> {
+        Products::deprecated_202101_delete_param_images_image_if_exists(self, product_id, image_id)
+            .await
+    }
+    async fn deprecated_unstable_get_param_image(
+        &self,
+        product_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_unstable_get_param_image(self, product_id, since_id, fields).await
+    }
+    async fn deprecated_unstable_create_param_images(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_unstable_create_param_images(self, product_id, body).await
+    }
+    async fn deprecated_unstable_get_param_images_count(
+        &self,
+        product_id: &str,
+        since_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_unstable_get_param_images_count(self, product_id, since_id).await
+    }
+    async fn deprecated_unstable_get_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_unstable_get_param_images_image(self, product_id, image_id, fields)
+            .await
+    }
+    async fn deprecated_unstable_update_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_unstable_update_param_images_image(self, product_id, image_id, body)
+            .await
+    }
+    async fn deprecated_unstable_delete_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_unstable_delete_param_images_image(self, product_id, image_id).await
+    }
+    async fn deprecated_unstable_delete_param_images_image_if_exists(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<bool> {
+        Products::deprecated_unstable_delete_param_images_image_if_exists(
+            self, product_id, image_id,
+        )
+        .await
+    }
+    async fn deprecated_202001_get_smart_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_202001_get_smart_collection(
+            self,
+            limit,
+            ids,
+            since_id,
+            title,
+            product_id,
+            handle,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202001_create_smart_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202001_create_smart_collections(self, body).await
+    }
+    async fn deprecated_202001_get_smart_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        Products::deprecated_202001_get_smart_collections_count(
+            self,
+            title,
+            product_id,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        )
+        .await
+    }
+    async fn deprecated_202001_get_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_202001_get_smart_collections_param_collection(
+            self,
+            smart_collection_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202001_update_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202001_update_smart_collections_param_collection(
+            self,
+            smart_collection_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202001_delete_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_202001_delete_smart_collections_param_collection(
+            self,
+            smart_collection_id,
+        )
+        .await
+    }
+    async fn deprecated_202001_delete_smart_collections_param_collection_if_exists(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<bool> {
+        Products::deprecated_202001_delete_smart_collections_param_collection_if_exists(
+            self,
+            smart_collection_id,
+        )
+        .await
+    }
+    async fn deprecated_202001_update_smart_collections_param_collection_order(
+        &self,
+        smart_collection_id: &str,
+        products: &str,
+        sort_order: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202001_update_smart_collections_param_collection_order(
+            self,
+            smart_collection_id,
+            products,
+            sort_order,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_smart_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_202004_get_smart_collection(
+            self,
+            limit,
+            ids,
+            since_id,
+            title,
+            product_id,
+            handle,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202004_create_smart_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202004_create_smart_collections(self, body).await
+    }
+    async fn deprecated_202004_get_smart_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        Products::deprecated_202004_get_smart_collections_count(
+            self,
+            title,
+            product_id,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_202004_get_smart_collections_param_collection(
+            self,
+            smart_collection_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202004_update_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202004_update_smart_collections_param_collection(
+            self,
+            smart_collection_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202004_delete_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_202004_delete_smart_collections_param_collection(
+            self,
+            smart_collection_id,
+        )
+        .await
+    }
+    async fn deprecated_202004_delete_smart_collections_param_collection_if_exists(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<bool> {
+        Products::deprecated_202004_delete_smart_collections_param_collection_if_exists(
+            self,
+            smart_collection_id,
+        )
+        .await
+    }
+    async fn deprecated_202004_update_smart_collections_param_collection_order(
+        &self,
+        smart_collection_id: &str,
+        products: &str,
+        sort_order: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202004_update_smart_collections_param_collection_order(
+            self,
+            smart_collection_id,
+            products,
+            sort_order,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_smart_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_202007_get_smart_collection(
+            self,
+            limit,
+            ids,
+            since_id,
+            title,
+            product_id,
+            handle,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202007_create_smart_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202007_create_smart_collections(self, body).await
+    }
+    async fn deprecated_202007_get_smart_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        Products::deprecated_202007_get_smart_collections_count(
+            self,
+            title,
+            product_id,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_202007_get_smart_collections_param_collection(
+            self,
+            smart_collection_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202007_update_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202007_update_smart_collections_param_collection(
+            self,
+            smart_collection_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202007_delete_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_202007_delete_smart_collections_param_collection(
+            self,
+            smart_collection_id,
+        )
+        .await
+    }
+    async fn deprecated_202007_delete_smart_collections_param_collection_if_exists(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<bool> {
+        Products::deprecated_202007_delete_smart_collections_param_collection_if_exists(
+            self,
+            smart_collection_id,
+        )
+        .await
+    }
+    async fn deprecated_202007_update_smart_collections_param_collection_order(
+        &self,
+        smart_collection_id: &str,
+        products: &str,
+        sort_order: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202007_update_smart_collections_param_collection_order(
+            self,
+            smart_collection_id,
+            products,
+            sort_order,
+            body,
+        )
+        .await
+    }
+    async fn get_smart_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::get_smart_collection(
+            self,
+            limit,
+            ids,
+            since_id,
+            title,
+            product_id,
+            handle,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+        )
+        .await
+    }
+    async fn create_smart_collections(&self, body: &serde_json::Value) -> Result<()> {
+        Products::create_smart_collections(self, body).await
+    }
+    async fn get_smart_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        Products::get_smart_collections_count(
+            self,
+            title,
+            product_id,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        )
+        .await
+    }
+    async fn get_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::get_smart_collections_param_collection(self, smart_collection_id, fields).await
+    }
+    async fn update_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::update_smart_collections_param_collection(self, smart_collection_id, body).await
+    }
+    async fn delete_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<()> {
+        Products::delete_smart_collections_param_collection(self, smart_collection_id).await
+    }
+    async fn delete_smart_collections_param_collection_if_exists(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<bool> {
+        Products::delete_smart_collections_param_collection_if_exists(self, smart_collection_id)
+            .await
+    }
+    async fn update_smart_collections_param_collection_order(
+        &self,
+        smart_collection_id: &str,
+        products: &str,
+        sort_order: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::update_smart_collections_param_collection_order(
+            self,
+            smart_collection_id,
+            products,
+            sort_order,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202101_get_smart_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_202101_get_smart_collection(
+            self,
+            limit,
+            ids,
+            since_id,
+            title,
+            product_id,
+            handle,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202101_create_smart_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202101_create_smart_collections(self, body).await
+    }
+    async fn deprecated_202101_get_smart_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        Products::deprecated_202101_get_smart_collections_count(
+            self,
+            title,
+            product_id,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        )
+        .await
+    }
+    async fn deprecated_202101_get_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_202101_get_smart_collections_param_collection(
+            self,
+            smart_collection_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202101_update_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202101_update_smart_collections_param_collection(
+            self,
+            smart_collection_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202101_delete_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_202101_delete_smart_collections_param_collection(
+            self,
+            smart_collection_id,
+        )
+        .await
+    }
+    async fn deprecated_202101_delete_smart_collections_param_collection_if_exists(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<bool> {
+        Products::deprecated_202101_delete_smart_collections_param_collection_if_exists(
+            self,
+            smart_collection_id,
+        )
+        .await
+    }
+    async fn deprecated_202101_update_smart_collections_param_collection_order(
+        &self,
+        smart_collection_id: &str,
+        products: &str,
+        sort_order: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202101_update_smart_collections_param_collection_order(
+            self,
+            smart_collection_id,
+            products,
+            sort_order,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_smart_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_unstable_get_smart_collection(
+            self,
+            limit,
+            ids,
+            since_id,
+            title,
+            product_id,
+            handle,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_unstable_create_smart_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_unstable_create_smart_collections(self, body).await
+    }
+    async fn deprecated_unstable_get_smart_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        Products::deprecated_unstable_get_smart_collections_count(
+            self,
+            title,
+            product_id,
+            updated_at_min,
+            updated_at_max,
+            published_at_min,
+            published_at_max,
+            published_status,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        Products::deprecated_unstable_get_smart_collections_param_collection(
+            self,
+            smart_collection_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_unstable_update_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_unstable_update_smart_collections_param_collection(
+            self,
+            smart_collection_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_unstable_delete_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_unstable_delete_smart_collections_param_collection(
+            self,
+            smart_collection_id,
+        )
+        .await
+    }
+    async fn deprecated_unstable_delete_smart_collections_param_collection_if_exists(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<bool> {
+        Products::deprecated_unstable_delete_smart_collections_param_collection_if_exists(
+            self,
+            smart_collection_id,
+        )
+        .await
+    }
+    async fn deprecated_unstable_update_smart_collections_param_collection_order(
+        &self,
+        smart_collection_id: &str,
+        products: &str,
+        sort_order: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_unstable_update_smart_collections_param_collection_order(
+            self,
+            smart_collection_id,
+            products,
+            sort_order,
+            body,
+        )
+        .await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct SalesChannels {
     pub client: Client,
@@ -12,6 +10,14 @@ impl SalesChannels {
         SalesChannels { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        SalesChannels {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Creates a checkout.
      *
@@ -22,8 +28,11 @@ impl SalesChannels {
     pub async fn deprecated_202001_create_checkouts(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-01/checkouts.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-01/checkouts.json", &[])
+            })
     }
 
     /**
@@ -36,8 +45,11 @@ impl SalesChannels {
     pub async fn deprecated_202004_create_checkouts(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-04/checkouts.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-04/checkouts.json", &[])
+            })
     }
 
     /**
@@ -50,8 +62,11 @@ impl SalesChannels {
     pub async fn deprecated_202007_create_checkouts(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-07/checkouts.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-07/checkouts.json", &[])
+            })
     }
 
     /**
@@ -64,8 +79,11 @@ impl SalesChannels {
     pub async fn create_checkouts(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-10/checkouts.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-10/checkouts.json", &[])
+            })
     }
 
     /**
@@ -78,8 +96,11 @@ impl SalesChannels {
     pub async fn deprecated_202101_create_checkouts(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2021-01/checkouts.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2021-01/checkouts.json", &[])
+            })
     }
 
     /**
@@ -95,8 +116,11 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = "/admin/api/unstable/checkouts.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/unstable/checkouts.json", &[])
+            })
     }
 
     /**
@@ -117,12 +141,19 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/checkouts/{}/complete.json",
-            crate::progenitor_support::encode_path(&token.to_string()),
+            self.client.encode_path(token),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-01/checkouts/{token}/complete.json",
+                    &[("token", token.to_string())],
+                )
+            })
     }
 
     /**
@@ -139,10 +170,16 @@ impl SalesChannels {
     pub async fn deprecated_202001_get_checkouts_param_token(&self, token: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/checkouts/{}/json",
-            crate::progenitor_support::encode_path(&token.to_string()),
+            self.client.encode_path(token),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/checkouts/{token}.json",
+                &[("token", token.to_string())],
+            )
+        })
     }
 
     /**
@@ -163,12 +200,19 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/checkouts/{}/json",
-            crate::progenitor_support::encode_path(&token.to_string()),
+            self.client.encode_path(token),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-01/checkouts/{token}.json",
+                    &[("token", token.to_string())],
+                )
+            })
     }
 
     /**
@@ -190,10 +234,16 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/checkouts/{}/shipping_rates.json",
-            crate::progenitor_support::encode_path(&token.to_string()),
+            self.client.encode_path(token),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/checkouts/{token}/shipping_rates.json",
+                &[("token", token.to_string())],
+            )
+        })
     }
 
     /**
@@ -214,12 +264,19 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/checkouts/{}/complete.json",
-            crate::progenitor_support::encode_path(&token.to_string()),
+            self.client.encode_path(token),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-04/checkouts/{token}/complete.json",
+                    &[("token", token.to_string())],
+                )
+            })
     }
 
     /**
@@ -236,10 +293,16 @@ impl SalesChannels {
     pub async fn deprecated_202004_get_checkouts_param_token(&self, token: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/checkouts/{}/json",
-            crate::progenitor_support::encode_path(&token.to_string()),
+            self.client.encode_path(token),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/checkouts/{token}.json",
+                &[("token", token.to_string())],
+            )
+        })
     }
 
     /**
@@ -260,12 +323,19 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/checkouts/{}/json",
-            crate::progenitor_support::encode_path(&token.to_string()),
+            self.client.encode_path(token),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-04/checkouts/{token}.json",
+                    &[("token", token.to_string())],
+                )
+            })
     }
 
     /**
@@ -287,10 +357,16 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/checkouts/{}/shipping_rates.json",
-            crate::progenitor_support::encode_path(&token.to_string()),
+            self.client.encode_path(token),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/checkouts/{token}/shipping_rates.json",
+                &[("token", token.to_string())],
+            )
+        })
     }
 
     /**
@@ -311,12 +387,19 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/checkouts/{}/complete.json",
-            crate::progenitor_support::encode_path(&token.to_string()),
+            self.client.encode_path(token),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-07/checkouts/{token}/complete.json",
+                    &[("token", token.to_string())],
+                )
+            })
     }
 
     /**
@@ -333,10 +416,16 @@ impl SalesChannels {
     pub async fn deprecated_202007_get_checkouts_param_token(&self, token: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/checkouts/{}/json",
-            crate::progenitor_support::encode_path(&token.to_string()),
+            self.client.encode_path(token),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/checkouts/{token}.json",
+                &[("token", token.to_string())],
+            )
+        })
     }
 
     /**
@@ -357,12 +446,19 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/checkouts/{}/json",
-            crate::progenitor_support::encode_path(&token.to_string()),
+            self.client.encode_path(token),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-07/checkouts/{token}.json",
+                    &[("token", token.to_string())],
+                )
+            })
     }
 
     /**
@@ -384,10 +480,16 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/checkouts/{}/shipping_rates.json",
-            crate::progenitor_support::encode_path(&token.to_string()),
+            self.client.encode_path(token),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/checkouts/{token}/shipping_rates.json",
+                &[("token", token.to_string())],
+            )
+        })
     }
 
     /**
@@ -408,12 +510,19 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/checkouts/{}/complete.json",
-            crate::progenitor_support::encode_path(&token.to_string()),
+            self.client.encode_path(token),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-10/checkouts/{token}/complete.json",
+                    &[("token", token.to_string())],
+                )
+            })
     }
 
     /**
@@ -430,10 +539,16 @@ impl SalesChannels {
     pub async fn get_checkouts_param_token(&self, token: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/checkouts/{}/json",
-            crate::progenitor_support::encode_path(&token.to_string()),
+            self.client.encode_path(token),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/checkouts/{token}.json",
+                &[("token", token.to_string())],
+            )
+        })
     }
 
     /**
@@ -454,12 +569,19 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/checkouts/{}/json",
-            crate::progenitor_support::encode_path(&token.to_string()),
+            self.client.encode_path(token),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-10/checkouts/{token}.json",
+                    &[("token", token.to_string())],
+                )
+            })
     }
 
     /**
@@ -478,10 +600,16 @@ impl SalesChannels {
     pub async fn get_checkouts_param_token_shipping_rate(&self, token: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/checkouts/{}/shipping_rates.json",
-            crate::progenitor_support::encode_path(&token.to_string()),
+            self.client.encode_path(token),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/checkouts/{token}/shipping_rates.json",
+                &[("token", token.to_string())],
+            )
+        })
     }
 
     /**
@@ -502,12 +630,19 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/checkouts/{}/complete.json",
-            crate::progenitor_support::encode_path(&token.to_string()),
+            self.client.encode_path(token),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2021-01/checkouts/{token}/complete.json",
+                    &[("token", token.to_string())],
+                )
+            })
     }
 
     /**
@@ -524,10 +659,16 @@ impl SalesChannels {
     pub async fn deprecated_202101_get_checkouts_param_token(&self, token: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/checkouts/{}/json",
-            crate::progenitor_support::encode_path(&token.to_string()),
+            self.client.encode_path(token),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/checkouts/{token}.json",
+                &[("token", token.to_string())],
+            )
+        })
     }
 
     /**
@@ -548,12 +689,19 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/checkouts/{}/json",
-            crate::progenitor_support::encode_path(&token.to_string()),
+            self.client.encode_path(token),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2021-01/checkouts/{token}.json",
+                    &[("token", token.to_string())],
+                )
+            })
     }
 
     /**
@@ -575,10 +723,16 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/checkouts/{}/shipping_rates.json",
-            crate::progenitor_support::encode_path(&token.to_string()),
+            self.client.encode_path(token),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/checkouts/{token}/shipping_rates.json",
+                &[("token", token.to_string())],
+            )
+        })
     }
 
     /**
@@ -599,12 +753,19 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/checkouts/{}/complete.json",
-            crate::progenitor_support::encode_path(&token.to_string()),
+            self.client.encode_path(token),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/unstable/checkouts/{token}/complete.json",
+                    &[("token", token.to_string())],
+                )
+            })
     }
 
     /**
@@ -621,10 +782,16 @@ impl SalesChannels {
     pub async fn deprecated_unstable_get_checkouts_param_token(&self, token: &str) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/checkouts/{}/json",
-            crate::progenitor_support::encode_path(&token.to_string()),
+            self.client.encode_path(token),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/checkouts/{token}.json",
+                &[("token", token.to_string())],
+            )
+        })
     }
 
     /**
@@ -645,12 +812,19 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/checkouts/{}/json",
-            crate::progenitor_support::encode_path(&token.to_string()),
+            self.client.encode_path(token),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/unstable/checkouts/{token}.json",
+                    &[("token", token.to_string())],
+                )
+            })
     }
 
     /**
@@ -672,10 +846,16 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/checkouts/{}/shipping_rates.json",
-            crate::progenitor_support::encode_path(&token.to_string()),
+            self.client.encode_path(token),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/checkouts/{token}/shipping_rates.json",
+                &[("token", token.to_string())],
+            )
+        })
     }
 
     /**
@@ -691,14 +871,18 @@ impl SalesChannels {
      *                     (default: 50, maximum: 1000).
      */
     pub async fn deprecated_202001_get_collection_listing(&self, limit: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/collection_listings.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-01/collection_listings.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/collection_listings.json", &[])
+        })
     }
 
     /**
@@ -719,18 +903,25 @@ impl SalesChannels {
         collection_listing_id: &str,
         limit: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/collection_listings/{}/product_ids.json?{}",
-            crate::progenitor_support::encode_path(&collection_listing_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/collection_listings/{}/product_ids.json",
+                self.client.encode_path(collection_listing_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/collection_listings/{collection_listing_id}/product_ids.json",
+                &[("collection_listing_id", collection_listing_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -750,10 +941,16 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/collection_listings/{}/json",
-            crate::progenitor_support::encode_path(&collection_listing_id.to_string()),
+            self.client.encode_path(collection_listing_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/collection_listings/{collection_listing_id}.json",
+                &[("collection_listing_id", collection_listing_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -774,12 +971,19 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/collection_listings/{}/json",
-            crate::progenitor_support::encode_path(&collection_listing_id.to_string()),
+            self.client.encode_path(collection_listing_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-01/collection_listings/{collection_listing_id}.json",
+                    &[("collection_listing_id", collection_listing_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -799,10 +1003,44 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/collection_listings/{}/json",
-            crate::progenitor_support::encode_path(&collection_listing_id.to_string()),
+            self.client.encode_path(collection_listing_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-01/collection_listings/{collection_listing_id}.json",
+                &[("collection_listing_id", collection_listing_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete a collection listing to unpublish a collection from your app.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-01/collection_listings/{collection_listing_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_delete_collection_listings_param_listing`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202001_delete_collection_listings_param_listing_if_exists(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-01/collection_listings/{}/json",
+            self.client.encode_path(collection_listing_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-01/collection_listings/{collection_listing_id}.json",
+                    &[("collection_listing_id", collection_listing_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -818,14 +1056,18 @@ impl SalesChannels {
      *                     (default: 50, maximum: 1000).
      */
     pub async fn deprecated_202004_get_collection_listing(&self, limit: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/collection_listings.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-04/collection_listings.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/collection_listings.json", &[])
+        })
     }
 
     /**
@@ -846,18 +1088,25 @@ impl SalesChannels {
         collection_listing_id: &str,
         limit: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/collection_listings/{}/product_ids.json?{}",
-            crate::progenitor_support::encode_path(&collection_listing_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/collection_listings/{}/product_ids.json",
+                self.client.encode_path(collection_listing_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/collection_listings/{collection_listing_id}/product_ids.json",
+                &[("collection_listing_id", collection_listing_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -877,10 +1126,16 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/collection_listings/{}/json",
-            crate::progenitor_support::encode_path(&collection_listing_id.to_string()),
+            self.client.encode_path(collection_listing_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/collection_listings/{collection_listing_id}.json",
+                &[("collection_listing_id", collection_listing_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -901,12 +1156,19 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/collection_listings/{}/json",
-            crate::progenitor_support::encode_path(&collection_listing_id.to_string()),
+            self.client.encode_path(collection_listing_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-04/collection_listings/{collection_listing_id}.json",
+                    &[("collection_listing_id", collection_listing_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -926,10 +1188,44 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/collection_listings/{}/json",
-            crate::progenitor_support::encode_path(&collection_listing_id.to_string()),
+            self.client.encode_path(collection_listing_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-04/collection_listings/{collection_listing_id}.json",
+                &[("collection_listing_id", collection_listing_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete a collection listing to unpublish a collection from your app.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-04/collection_listings/{collection_listing_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_delete_collection_listings_param_listing`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202004_delete_collection_listings_param_listing_if_exists(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-04/collection_listings/{}/json",
+            self.client.encode_path(collection_listing_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-04/collection_listings/{collection_listing_id}.json",
+                    &[("collection_listing_id", collection_listing_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -945,14 +1241,18 @@ impl SalesChannels {
      *                     (default: 50, maximum: 1000).
      */
     pub async fn deprecated_202007_get_collection_listing(&self, limit: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/collection_listings.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-07/collection_listings.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/collection_listings.json", &[])
+        })
     }
 
     /**
@@ -973,18 +1273,25 @@ impl SalesChannels {
         collection_listing_id: &str,
         limit: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/collection_listings/{}/product_ids.json?{}",
-            crate::progenitor_support::encode_path(&collection_listing_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/collection_listings/{}/product_ids.json",
+                self.client.encode_path(collection_listing_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/collection_listings/{collection_listing_id}/product_ids.json",
+                &[("collection_listing_id", collection_listing_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1004,10 +1311,16 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/collection_listings/{}/json",
-            crate::progenitor_support::encode_path(&collection_listing_id.to_string()),
+            self.client.encode_path(collection_listing_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/collection_listings/{collection_listing_id}.json",
+                &[("collection_listing_id", collection_listing_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1028,12 +1341,19 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/collection_listings/{}/json",
-            crate::progenitor_support::encode_path(&collection_listing_id.to_string()),
+            self.client.encode_path(collection_listing_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-07/collection_listings/{collection_listing_id}.json",
+                    &[("collection_listing_id", collection_listing_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1053,10 +1373,44 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/collection_listings/{}/json",
-            crate::progenitor_support::encode_path(&collection_listing_id.to_string()),
+            self.client.encode_path(collection_listing_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-07/collection_listings/{collection_listing_id}.json",
+                &[("collection_listing_id", collection_listing_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete a collection listing to unpublish a collection from your app.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-07/collection_listings/{collection_listing_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_delete_collection_listings_param_listing`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202007_delete_collection_listings_param_listing_if_exists(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-07/collection_listings/{}/json",
+            self.client.encode_path(collection_listing_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-07/collection_listings/{collection_listing_id}.json",
+                    &[("collection_listing_id", collection_listing_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1072,14 +1426,18 @@ impl SalesChannels {
      *                     (default: 50, maximum: 1000).
      */
     pub async fn get_collection_listing(&self, limit: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/collection_listings.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-10/collection_listings.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/collection_listings.json", &[])
+        })
     }
 
     /**
@@ -1100,18 +1458,25 @@ impl SalesChannels {
         collection_listing_id: &str,
         limit: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/collection_listings/{}/product_ids.json?{}",
-            crate::progenitor_support::encode_path(&collection_listing_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/collection_listings/{}/product_ids.json",
+                self.client.encode_path(collection_listing_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/collection_listings/{collection_listing_id}/product_ids.json",
+                &[("collection_listing_id", collection_listing_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1131,10 +1496,16 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/collection_listings/{}/json",
-            crate::progenitor_support::encode_path(&collection_listing_id.to_string()),
+            self.client.encode_path(collection_listing_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/collection_listings/{collection_listing_id}.json",
+                &[("collection_listing_id", collection_listing_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1155,12 +1526,19 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/collection_listings/{}/json",
-            crate::progenitor_support::encode_path(&collection_listing_id.to_string()),
+            self.client.encode_path(collection_listing_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-10/collection_listings/{collection_listing_id}.json",
+                    &[("collection_listing_id", collection_listing_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1180,10 +1558,44 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/collection_listings/{}/json",
-            crate::progenitor_support::encode_path(&collection_listing_id.to_string()),
+            self.client.encode_path(collection_listing_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-10/collection_listings/{collection_listing_id}.json",
+                &[("collection_listing_id", collection_listing_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete a collection listing to unpublish a collection from your app.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-10/collection_listings/{collection_listing_id}.json` endpoint.
+     *
+     * As opposed to `delete_collection_listings_param_listing`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_collection_listings_param_listing_if_exists(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-10/collection_listings/{}/json",
+            self.client.encode_path(collection_listing_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-10/collection_listings/{collection_listing_id}.json",
+                    &[("collection_listing_id", collection_listing_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1199,14 +1611,18 @@ impl SalesChannels {
      *                     (default: 50, maximum: 1000).
      */
     pub async fn deprecated_202101_get_collection_listing(&self, limit: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/collection_listings.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2021-01/collection_listings.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/collection_listings.json", &[])
+        })
     }
 
     /**
@@ -1227,18 +1643,25 @@ impl SalesChannels {
         collection_listing_id: &str,
         limit: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/collection_listings/{}/product_ids.json?{}",
-            crate::progenitor_support::encode_path(&collection_listing_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/collection_listings/{}/product_ids.json",
+                self.client.encode_path(collection_listing_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/collection_listings/{collection_listing_id}/product_ids.json",
+                &[("collection_listing_id", collection_listing_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1258,10 +1681,16 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/collection_listings/{}/json",
-            crate::progenitor_support::encode_path(&collection_listing_id.to_string()),
+            self.client.encode_path(collection_listing_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/collection_listings/{collection_listing_id}.json",
+                &[("collection_listing_id", collection_listing_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1282,12 +1711,19 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/collection_listings/{}/json",
-            crate::progenitor_support::encode_path(&collection_listing_id.to_string()),
+            self.client.encode_path(collection_listing_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2021-01/collection_listings/{collection_listing_id}.json",
+                    &[("collection_listing_id", collection_listing_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1307,10 +1743,44 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/collection_listings/{}/json",
-            crate::progenitor_support::encode_path(&collection_listing_id.to_string()),
+            self.client.encode_path(collection_listing_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2021-01/collection_listings/{collection_listing_id}.json",
+                &[("collection_listing_id", collection_listing_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete a collection listing to unpublish a collection from your app.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2021-01/collection_listings/{collection_listing_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_delete_collection_listings_param_listing`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202101_delete_collection_listings_param_listing_if_exists(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2021-01/collection_listings/{}/json",
+            self.client.encode_path(collection_listing_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2021-01/collection_listings/{collection_listing_id}.json",
+                    &[("collection_listing_id", collection_listing_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1326,14 +1796,18 @@ impl SalesChannels {
      *                     (default: 50, maximum: 1000).
      */
     pub async fn deprecated_unstable_get_collection_listing(&self, limit: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/collection_listings.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/unstable/collection_listings.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/collection_listings.json", &[])
+        })
     }
 
     /**
@@ -1354,18 +1828,25 @@ impl SalesChannels {
         collection_listing_id: &str,
         limit: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/collection_listings/{}/product_ids.json?{}",
-            crate::progenitor_support::encode_path(&collection_listing_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/collection_listings/{}/product_ids.json",
+                self.client.encode_path(collection_listing_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/collection_listings/{collection_listing_id}/product_ids.json",
+                &[("collection_listing_id", collection_listing_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1385,10 +1866,16 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/collection_listings/{}/json",
-            crate::progenitor_support::encode_path(&collection_listing_id.to_string()),
+            self.client.encode_path(collection_listing_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/collection_listings/{collection_listing_id}.json",
+                &[("collection_listing_id", collection_listing_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1409,12 +1896,19 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/collection_listings/{}/json",
-            crate::progenitor_support::encode_path(&collection_listing_id.to_string()),
+            self.client.encode_path(collection_listing_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/unstable/collection_listings/{collection_listing_id}.json",
+                    &[("collection_listing_id", collection_listing_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1434,10 +1928,44 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/collection_listings/{}/json",
-            crate::progenitor_support::encode_path(&collection_listing_id.to_string()),
+            self.client.encode_path(collection_listing_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/unstable/collection_listings/{collection_listing_id}.json",
+                &[("collection_listing_id", collection_listing_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete a collection listing to unpublish a collection from your app.
+     *
+     * This function performs a `DELETE` to the `/admin/api/unstable/collection_listings/{collection_listing_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_delete_collection_listings_param_listing`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_unstable_delete_collection_listings_param_listing_if_exists(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/unstable/collection_listings/{}/json",
+            self.client.encode_path(collection_listing_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/unstable/collection_listings/{collection_listing_id}.json",
+                    &[("collection_listing_id", collection_listing_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1457,10 +1985,16 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/checkouts/{}/payments.json",
-            crate::progenitor_support::encode_path(&token.to_string()),
+            self.client.encode_path(token),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/checkouts/{token}/payments.json",
+                &[("token", token.to_string())],
+            )
+        })
     }
 
     /**
@@ -1492,7 +2026,7 @@ impl SalesChannels {
         unique_token_required: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !amount_required.is_empty() {
             query_args.push((
                 "amount
@@ -1525,16 +2059,24 @@ impl SalesChannels {
                 unique_token_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/checkouts/{}/payments.json?{}",
-            crate::progenitor_support::encode_path(&token.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/checkouts/{}/payments.json",
+                self.client.encode_path(token)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-01/checkouts/{token}/payments.json",
+                    &[("token", token.to_string())],
+                )
+            })
     }
 
     /**
@@ -1556,11 +2098,20 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/checkouts/{}/payments/{}/json",
-            crate::progenitor_support::encode_path(&token.to_string()),
-            crate::progenitor_support::encode_path(&payment_id.to_string()),
+            self.client.encode_path(token),
+            self.client.encode_path(payment_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/checkouts/{token}/payments/{payment_id}.json",
+                &[
+                    ("token", token.to_string()),
+                    ("payment_id", payment_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1580,10 +2131,16 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/checkouts/{}/payments/count.json",
-            crate::progenitor_support::encode_path(&token.to_string()),
+            self.client.encode_path(token),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/checkouts/{token}/payments/count.json",
+                &[("token", token.to_string())],
+            )
+        })
     }
 
     /**
@@ -1603,10 +2160,16 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/checkouts/{}/payments.json",
-            crate::progenitor_support::encode_path(&token.to_string()),
+            self.client.encode_path(token),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/checkouts/{token}/payments.json",
+                &[("token", token.to_string())],
+            )
+        })
     }
 
     /**
@@ -1638,7 +2201,7 @@ impl SalesChannels {
         unique_token_required: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !amount_required.is_empty() {
             query_args.push((
                 "amount
@@ -1671,16 +2234,24 @@ impl SalesChannels {
                 unique_token_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/checkouts/{}/payments.json?{}",
-            crate::progenitor_support::encode_path(&token.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/checkouts/{}/payments.json",
+                self.client.encode_path(token)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-04/checkouts/{token}/payments.json",
+                    &[("token", token.to_string())],
+                )
+            })
     }
 
     /**
@@ -1702,11 +2273,20 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/checkouts/{}/payments/{}/json",
-            crate::progenitor_support::encode_path(&token.to_string()),
-            crate::progenitor_support::encode_path(&payment_id.to_string()),
+            self.client.encode_path(token),
+            self.client.encode_path(payment_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/checkouts/{token}/payments/{payment_id}.json",
+                &[
+                    ("token", token.to_string()),
+                    ("payment_id", payment_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1726,10 +2306,16 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/checkouts/{}/payments/count.json",
-            crate::progenitor_support::encode_path(&token.to_string()),
+            self.client.encode_path(token),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/checkouts/{token}/payments/count.json",
+                &[("token", token.to_string())],
+            )
+        })
     }
 
     /**
@@ -1749,10 +2335,16 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/checkouts/{}/payments.json",
-            crate::progenitor_support::encode_path(&token.to_string()),
+            self.client.encode_path(token),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/checkouts/{token}/payments.json",
+                &[("token", token.to_string())],
+            )
+        })
     }
 
     /**
@@ -1784,7 +2376,7 @@ impl SalesChannels {
         unique_token_required: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !amount_required.is_empty() {
             query_args.push((
                 "amount
@@ -1817,16 +2409,24 @@ impl SalesChannels {
                 unique_token_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/checkouts/{}/payments.json?{}",
-            crate::progenitor_support::encode_path(&token.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/checkouts/{}/payments.json",
+                self.client.encode_path(token)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-07/checkouts/{token}/payments.json",
+                    &[("token", token.to_string())],
+                )
+            })
     }
 
     /**
@@ -1848,11 +2448,20 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/checkouts/{}/payments/{}/json",
-            crate::progenitor_support::encode_path(&token.to_string()),
-            crate::progenitor_support::encode_path(&payment_id.to_string()),
+            self.client.encode_path(token),
+            self.client.encode_path(payment_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/checkouts/{token}/payments/{payment_id}.json",
+                &[
+                    ("token", token.to_string()),
+                    ("payment_id", payment_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1872,10 +2481,16 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/checkouts/{}/payments/count.json",
-            crate::progenitor_support::encode_path(&token.to_string()),
+            self.client.encode_path(token),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/checkouts/{token}/payments/count.json",
+                &[("token", token.to_string())],
+            )
+        })
     }
 
     /**
@@ -1892,10 +2507,16 @@ impl SalesChannels {
     pub async fn get_checkouts_param_token_payment(&self, token: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/checkouts/{}/payments.json",
-            crate::progenitor_support::encode_path(&token.to_string()),
+            self.client.encode_path(token),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/checkouts/{token}/payments.json",
+                &[("token", token.to_string())],
+            )
+        })
     }
 
     /**
@@ -1927,7 +2548,7 @@ impl SalesChannels {
         unique_token_required: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !amount_required.is_empty() {
             query_args.push((
                 "amount
@@ -1960,16 +2581,24 @@ impl SalesChannels {
                 unique_token_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/checkouts/{}/payments.json?{}",
-            crate::progenitor_support::encode_path(&token.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/checkouts/{}/payments.json",
+                self.client.encode_path(token)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-10/checkouts/{token}/payments.json",
+                    &[("token", token.to_string())],
+                )
+            })
     }
 
     /**
@@ -1991,11 +2620,20 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/checkouts/{}/payments/{}/json",
-            crate::progenitor_support::encode_path(&token.to_string()),
-            crate::progenitor_support::encode_path(&payment_id.to_string()),
+            self.client.encode_path(token),
+            self.client.encode_path(payment_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/checkouts/{token}/payments/{payment_id}.json",
+                &[
+                    ("token", token.to_string()),
+                    ("payment_id", payment_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -2012,10 +2650,16 @@ impl SalesChannels {
     pub async fn get_checkouts_param_token_payments_count(&self, token: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/checkouts/{}/payments/count.json",
-            crate::progenitor_support::encode_path(&token.to_string()),
+            self.client.encode_path(token),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/checkouts/{token}/payments/count.json",
+                &[("token", token.to_string())],
+            )
+        })
     }
 
     /**
@@ -2035,10 +2679,16 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/checkouts/{}/payments.json",
-            crate::progenitor_support::encode_path(&token.to_string()),
+            self.client.encode_path(token),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/checkouts/{token}/payments.json",
+                &[("token", token.to_string())],
+            )
+        })
     }
 
     /**
@@ -2070,7 +2720,7 @@ impl SalesChannels {
         unique_token_required: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !amount_required.is_empty() {
             query_args.push((
                 "amount
@@ -2103,16 +2753,24 @@ impl SalesChannels {
                 unique_token_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/checkouts/{}/payments.json?{}",
-            crate::progenitor_support::encode_path(&token.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/checkouts/{}/payments.json",
+                self.client.encode_path(token)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2021-01/checkouts/{token}/payments.json",
+                    &[("token", token.to_string())],
+                )
+            })
     }
 
     /**
@@ -2134,11 +2792,20 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/checkouts/{}/payments/{}/json",
-            crate::progenitor_support::encode_path(&token.to_string()),
-            crate::progenitor_support::encode_path(&payment_id.to_string()),
+            self.client.encode_path(token),
+            self.client.encode_path(payment_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/checkouts/{token}/payments/{payment_id}.json",
+                &[
+                    ("token", token.to_string()),
+                    ("payment_id", payment_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -2158,10 +2825,16 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/checkouts/{}/payments/count.json",
-            crate::progenitor_support::encode_path(&token.to_string()),
+            self.client.encode_path(token),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/checkouts/{token}/payments/count.json",
+                &[("token", token.to_string())],
+            )
+        })
     }
 
     /**
@@ -2181,10 +2854,16 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/checkouts/{}/payments.json",
-            crate::progenitor_support::encode_path(&token.to_string()),
+            self.client.encode_path(token),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/checkouts/{token}/payments.json",
+                &[("token", token.to_string())],
+            )
+        })
     }
 
     /**
@@ -2216,7 +2895,7 @@ impl SalesChannels {
         unique_token_required: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !amount_required.is_empty() {
             query_args.push((
                 "amount
@@ -2249,16 +2928,24 @@ impl SalesChannels {
                 unique_token_required.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/checkouts/{}/payments.json?{}",
-            crate::progenitor_support::encode_path(&token.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/checkouts/{}/payments.json",
+                self.client.encode_path(token)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/unstable/checkouts/{token}/payments.json",
+                    &[("token", token.to_string())],
+                )
+            })
     }
 
     /**
@@ -2280,11 +2967,20 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/checkouts/{}/payments/{}/json",
-            crate::progenitor_support::encode_path(&token.to_string()),
-            crate::progenitor_support::encode_path(&payment_id.to_string()),
+            self.client.encode_path(token),
+            self.client.encode_path(payment_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/checkouts/{token}/payments/{payment_id}.json",
+                &[
+                    ("token", token.to_string()),
+                    ("payment_id", payment_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -2304,10 +3000,16 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/checkouts/{}/payments/count.json",
-            crate::progenitor_support::encode_path(&token.to_string()),
+            self.client.encode_path(token),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/checkouts/{token}/payments/count.json",
+                &[("token", token.to_string())],
+            )
+        })
     }
 
     /**
@@ -2334,26 +3036,28 @@ impl SalesChannels {
         updated_at_min: &str,
         handle: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !product_ids.is_empty() {
-            query_args.push(("product_ids".to_string(), product_ids.to_string()));
+            query_args.push(("product_ids", product_ids.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/product_listings.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-01/product_listings.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/product_listings.json", &[])
+        })
     }
 
     /**
@@ -2369,17 +3073,22 @@ impl SalesChannels {
      *                     (default: 50, maximum: 1000).
      */
     pub async fn deprecated_202001_get_product_listings_id(&self, limit: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/product_listings/product_ids.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-01/product_listings/product_ids.json",
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/product_listings/product_ids.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -2391,7 +3100,9 @@ impl SalesChannels {
      */
     pub async fn deprecated_202001_get_product_listings_count(&self) -> Result<()> {
         let url = "/admin/api/2020-01/product_listings/count.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/product_listings/count.json", &[])
+        })
     }
 
     /**
@@ -2411,10 +3122,16 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/product_listings/{}/json",
-            crate::progenitor_support::encode_path(&product_listing_id.to_string()),
+            self.client.encode_path(product_listing_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/product_listings/{product_listing_id}.json",
+                &[("product_listing_id", product_listing_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2435,12 +3152,19 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/product_listings/{}/json",
-            crate::progenitor_support::encode_path(&product_listing_id.to_string()),
+            self.client.encode_path(product_listing_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-01/product_listings/{product_listing_id}.json",
+                    &[("product_listing_id", product_listing_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2460,10 +3184,44 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/product_listings/{}/json",
-            crate::progenitor_support::encode_path(&product_listing_id.to_string()),
+            self.client.encode_path(product_listing_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-01/product_listings/{product_listing_id}.json",
+                &[("product_listing_id", product_listing_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete a product listing to unpublish a product from your app.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-01/product_listings/{product_listing_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_delete_product_listings_param_listing`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202001_delete_product_listings_param_listing_if_exists(
+        &self,
+        product_listing_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-01/product_listings/{}/json",
+            self.client.encode_path(product_listing_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-01/product_listings/{product_listing_id}.json",
+                    &[("product_listing_id", product_listing_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2490,26 +3248,28 @@ impl SalesChannels {
         updated_at_min: &str,
         handle: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !product_ids.is_empty() {
-            query_args.push(("product_ids".to_string(), product_ids.to_string()));
+            query_args.push(("product_ids", product_ids.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/product_listings.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-04/product_listings.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/product_listings.json", &[])
+        })
     }
 
     /**
@@ -2525,17 +3285,22 @@ impl SalesChannels {
      *                     (default: 50, maximum: 1000).
      */
     pub async fn deprecated_202004_get_product_listings_id(&self, limit: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/product_listings/product_ids.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-04/product_listings/product_ids.json",
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/product_listings/product_ids.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -2547,7 +3312,9 @@ impl SalesChannels {
      */
     pub async fn deprecated_202004_get_product_listings_count(&self) -> Result<()> {
         let url = "/admin/api/2020-04/product_listings/count.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/product_listings/count.json", &[])
+        })
     }
 
     /**
@@ -2567,10 +3334,16 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/product_listings/{}/json",
-            crate::progenitor_support::encode_path(&product_listing_id.to_string()),
+            self.client.encode_path(product_listing_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/product_listings/{product_listing_id}.json",
+                &[("product_listing_id", product_listing_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2591,12 +3364,19 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/product_listings/{}/json",
-            crate::progenitor_support::encode_path(&product_listing_id.to_string()),
+            self.client.encode_path(product_listing_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-04/product_listings/{product_listing_id}.json",
+                    &[("product_listing_id", product_listing_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2616,10 +3396,44 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/product_listings/{}/json",
-            crate::progenitor_support::encode_path(&product_listing_id.to_string()),
+            self.client.encode_path(product_listing_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-04/product_listings/{product_listing_id}.json",
+                &[("product_listing_id", product_listing_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete a product listing to unpublish a product from your app.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-04/product_listings/{product_listing_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_delete_product_listings_param_listing`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202004_delete_product_listings_param_listing_if_exists(
+        &self,
+        product_listing_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-04/product_listings/{}/json",
+            self.client.encode_path(product_listing_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-04/product_listings/{product_listing_id}.json",
+                    &[("product_listing_id", product_listing_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2646,26 +3460,28 @@ impl SalesChannels {
         updated_at_min: &str,
         handle: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !collection_id.is_empty() {
-            query_args.push(("collection_id".to_string(), collection_id.to_string()));
+            query_args.push(("collection_id", collection_id.to_string()));
         }
         if !handle.is_empty() {
-            query_args.push(("handle".to_string(), handle.to_string()));
+            query_args.push(("handle", handle.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !product_ids.is_empty() {
-            query_args.push(("product_ids".to_string(), product_ids.to_string()));
+            query_args.push(("product_ids", product_ids.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/product_listings.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-07/product_listings.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/product_listings.json", &[])
+        })
     }
 
     /**
@@ -2681,17 +3497,22 @@ impl SalesChannels {
      *                     (default: 50, maximum: 1000).
      */
     pub async fn deprecated_202007_get_product_listings_id(&self, limit: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/product_listings/product_ids.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-07/product_listings/product_ids.json",
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/product_listings/product_ids.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -2703,7 +3524,9 @@ impl SalesChannels {
      */
     pub async fn deprecated_202007_get_product_listings_count(&self) -> Result<()> {
         let url = "/admin/api/2020-07/product_listings/count.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/product_listings/count.json", &[])
+        })
     }
 
     /**
@@ -2723,10 +3546,16 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/product_listings/{}/json",
-            crate::progenitor_support::encode_path(&product_listing_id.to_string()),
+            self.client.encode_path(product_listing_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/product_listings/{product_listing_id}.json",
+                &[("product_listing_id", product_listing_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2747,12 +3576,19 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/product_listings/{}/json",
-            crate::progenitor_support::encode_path(&product_listing_id.to_string()),
+            self.client.encode_path(product_listing_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-07/product_listings/{product_listing_id}.json",
+                    &[("product_listing_id", product_listing_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2772,9 +3608,1401 @@ impl SalesChannels {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/product_listings/{}/json",
-            crate::progenitor_support::encode_path(&product_listing_id.to_string()),
+            self.client.encode_path(product_listing_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-07/product_listings/{product_listing_id}.json",
+                &[("product_listing_id", product_listing_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete a product listing to unpublish a product from your app.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-07/product_listings/{product_listing_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_delete_product_listings_param_listing`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202007_delete_product_listings_param_listing_if_exists(
+        &self,
+        product_listing_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-07/product_listings/{}/json",
+            self.client.encode_path(product_listing_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-07/product_listings/{product_listing_id}.json",
+                    &[("product_listing_id", product_listing_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`SalesChannels`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn SalesChannelsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait SalesChannelsApi: Send + Sync {
+    async fn deprecated_202001_create_checkouts(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202004_create_checkouts(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202007_create_checkouts(&self, body: &serde_json::Value) -> Result<()>;
+    async fn create_checkouts(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202101_create_checkouts(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_unstable_create_checkouts(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202001_create_checkouts_param_token_complete(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_checkouts_param_token(&self, token: &str) -> Result<()>;
+    async fn deprecated_202001_update_checkouts_param_token(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_checkouts_param_token_shipping_rate(
+        &self,
+        token: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_create_checkouts_param_token_complete(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_checkouts_param_token(&self, token: &str) -> Result<()>;
+    async fn deprecated_202004_update_checkouts_param_token(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_checkouts_param_token_shipping_rate(
+        &self,
+        token: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_create_checkouts_param_token_complete(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_checkouts_param_token(&self, token: &str) -> Result<()>;
+    async fn deprecated_202007_update_checkouts_param_token(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_checkouts_param_token_shipping_rate(
+        &self,
+        token: &str,
+    ) -> Result<()>;
+    async fn create_checkouts_param_token_complete(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn get_checkouts_param_token(&self, token: &str) -> Result<()>;
+    async fn update_checkouts_param_token(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn get_checkouts_param_token_shipping_rate(&self, token: &str) -> Result<()>;
+    async fn deprecated_202101_create_checkouts_param_token_complete(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_checkouts_param_token(&self, token: &str) -> Result<()>;
+    async fn deprecated_202101_update_checkouts_param_token(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_checkouts_param_token_shipping_rate(
+        &self,
+        token: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create_checkouts_param_token_complete(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_checkouts_param_token(&self, token: &str) -> Result<()>;
+    async fn deprecated_unstable_update_checkouts_param_token(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_checkouts_param_token_shipping_rate(
+        &self,
+        token: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_collection_listing(&self, limit: &str) -> Result<()>;
+    async fn deprecated_202001_get_collection_listings_param_listing_product_id(
+        &self,
+        collection_listing_id: &str,
+        limit: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_update_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_collection_listings_param_listing_if_exists(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202004_get_collection_listing(&self, limit: &str) -> Result<()>;
+    async fn deprecated_202004_get_collection_listings_param_listing_product_id(
+        &self,
+        collection_listing_id: &str,
+        limit: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_update_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_collection_listings_param_listing_if_exists(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202007_get_collection_listing(&self, limit: &str) -> Result<()>;
+    async fn deprecated_202007_get_collection_listings_param_listing_product_id(
+        &self,
+        collection_listing_id: &str,
+        limit: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_update_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_collection_listings_param_listing_if_exists(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<bool>;
+    async fn get_collection_listing(&self, limit: &str) -> Result<()>;
+    async fn get_collection_listings_param_listing_product_id(
+        &self,
+        collection_listing_id: &str,
+        limit: &str,
+    ) -> Result<()>;
+    async fn get_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()>;
+    async fn update_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn delete_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()>;
+    async fn delete_collection_listings_param_listing_if_exists(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202101_get_collection_listing(&self, limit: &str) -> Result<()>;
+    async fn deprecated_202101_get_collection_listings_param_listing_product_id(
+        &self,
+        collection_listing_id: &str,
+        limit: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_update_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_collection_listings_param_listing_if_exists(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_unstable_get_collection_listing(&self, limit: &str) -> Result<()>;
+    async fn deprecated_unstable_get_collection_listings_param_listing_product_id(
+        &self,
+        collection_listing_id: &str,
+        limit: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_update_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_collection_listings_param_listing_if_exists(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202001_get_checkouts_param_token_payment(&self, token: &str) -> Result<()>;
+    async fn deprecated_202001_create_checkouts_param_token_payments(
+        &self,
+        token: &str,
+        amount_required: &str,
+        request_details_required: &str,
+        session_id_required: &str,
+        unique_token_required: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_checkouts_param_token_payments_payment(
+        &self,
+        token: &str,
+        payment_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_checkouts_param_token_payments_count(
+        &self,
+        token: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_checkouts_param_token_payment(&self, token: &str) -> Result<()>;
+    async fn deprecated_202004_create_checkouts_param_token_payments(
+        &self,
+        token: &str,
+        amount_required: &str,
+        request_details_required: &str,
+        session_id_required: &str,
+        unique_token_required: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_checkouts_param_token_payments_payment(
+        &self,
+        token: &str,
+        payment_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_checkouts_param_token_payments_count(
+        &self,
+        token: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_checkouts_param_token_payment(&self, token: &str) -> Result<()>;
+    async fn deprecated_202007_create_checkouts_param_token_payments(
+        &self,
+        token: &str,
+        amount_required: &str,
+        request_details_required: &str,
+        session_id_required: &str,
+        unique_token_required: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_checkouts_param_token_payments_payment(
+        &self,
+        token: &str,
+        payment_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_checkouts_param_token_payments_count(
+        &self,
+        token: &str,
+    ) -> Result<()>;
+    async fn get_checkouts_param_token_payment(&self, token: &str) -> Result<()>;
+    async fn create_checkouts_param_token_payments(
+        &self,
+        token: &str,
+        amount_required: &str,
+        request_details_required: &str,
+        session_id_required: &str,
+        unique_token_required: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn get_checkouts_param_token_payments_payment(
+        &self,
+        token: &str,
+        payment_id: &str,
+    ) -> Result<()>;
+    async fn get_checkouts_param_token_payments_count(&self, token: &str) -> Result<()>;
+    async fn deprecated_202101_get_checkouts_param_token_payment(&self, token: &str) -> Result<()>;
+    async fn deprecated_202101_create_checkouts_param_token_payments(
+        &self,
+        token: &str,
+        amount_required: &str,
+        request_details_required: &str,
+        session_id_required: &str,
+        unique_token_required: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_checkouts_param_token_payments_payment(
+        &self,
+        token: &str,
+        payment_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_checkouts_param_token_payments_count(
+        &self,
+        token: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_checkouts_param_token_payment(
+        &self,
+        token: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create_checkouts_param_token_payments(
+        &self,
+        token: &str,
+        amount_required: &str,
+        request_details_required: &str,
+        session_id_required: &str,
+        unique_token_required: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_checkouts_param_token_payments_payment(
+        &self,
+        token: &str,
+        payment_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_checkouts_param_token_payments_count(
+        &self,
+        token: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_product_listing(
+        &self,
+        product_ids: &str,
+        limit: &str,
+        collection_id: &str,
+        updated_at_min: &str,
+        handle: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_product_listings_id(&self, limit: &str) -> Result<()>;
+    async fn deprecated_202001_get_product_listings_count(&self) -> Result<()>;
+    async fn deprecated_202001_get_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_update_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_product_listings_param_listing_if_exists(
+        &self,
+        product_listing_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202004_get_product_listing(
+        &self,
+        product_ids: &str,
+        limit: &str,
+        collection_id: &str,
+        updated_at_min: &str,
+        handle: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_product_listings_id(&self, limit: &str) -> Result<()>;
+    async fn deprecated_202004_get_product_listings_count(&self) -> Result<()>;
+    async fn deprecated_202004_get_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_update_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_product_listings_param_listing_if_exists(
+        &self,
+        product_listing_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202007_get_product_listing(
+        &self,
+        product_ids: &str,
+        limit: &str,
+        collection_id: &str,
+        updated_at_min: &str,
+        handle: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_product_listings_id(&self, limit: &str) -> Result<()>;
+    async fn deprecated_202007_get_product_listings_count(&self) -> Result<()>;
+    async fn deprecated_202007_get_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_update_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_product_listings_param_listing_if_exists(
+        &self,
+        product_listing_id: &str,
+    ) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl SalesChannelsApi for SalesChannels {
+    async fn deprecated_202001_create_checkouts(&self, body: &serde_json::Value) -> Result<()> {
+        SalesChannels::deprecated_202001_create_checkouts(self, body).await
+    }
+    async fn deprecated_202004_create_checkouts(&self, body: &serde_json::Value) -> Result<()> {
+        SalesChannels::deprecated_202004_create_checkouts(self, body).await
+    }
+    async fn deprecated_202007_create_checkouts(&self, body: &serde_json::Value) -> Result<()> {
+        SalesChannels::deprecated_202007_create_checkouts(self, body).await
+    }
+    async fn create_checkouts(&self, body: &serde_json::Value) -> Result<()> {
+        SalesChannels::create_checkouts(self, body).await
+    }
+    async fn deprecated_202101_create_checkouts(&self, body: &serde_json::Value) -> Result<()> {
+        SalesChannels::deprecated_202101_create_checkouts(self, body).await
+    }
+    async fn deprecated_unstable_create_checkouts(&self, body: &serde_json::Value) -> Result<()> {
+        SalesChannels::deprecated_unstable_create_checkouts(self, body).await
+    }
+    async fn deprecated_202001_create_checkouts_param_token_complete(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202001_create_checkouts_param_token_complete(self, token, body)
+            .await
+    }
+    async fn deprecated_202001_get_checkouts_param_token(&self, token: &str) -> Result<()> {
+        SalesChannels::deprecated_202001_get_checkouts_param_token(self, token).await
+    }
+    async fn deprecated_202001_update_checkouts_param_token(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202001_update_checkouts_param_token(self, token, body).await
+    }
+    async fn deprecated_202001_get_checkouts_param_token_shipping_rate(
+        &self,
+        token: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202001_get_checkouts_param_token_shipping_rate(self, token).await
+    }
+    async fn deprecated_202004_create_checkouts_param_token_complete(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202004_create_checkouts_param_token_complete(self, token, body)
+            .await
+    }
+    async fn deprecated_202004_get_checkouts_param_token(&self, token: &str) -> Result<()> {
+        SalesChannels::deprecated_202004_get_checkouts_param_token(self, token).await
+    }
+    async fn deprecated_202004_update_checkouts_param_token(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202004_update_checkouts_param_token(self, token, body).await
+    }
+    async fn deprecated_202004_get_checkouts_param_token_shipping_rate(
+        &self,
+        token: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202004_get_checkouts_param_token_shipping_rate(self, token).await
+    }
+    async fn deprecated_202007_create_checkouts_param_token_complete(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202007_create_checkouts_param_token_complete(self, token, body)
+            .await
+    }
+    async fn deprecated_202007_get_checkouts_param_token(&self, token: &str) -> Result<()> {
+        SalesChannels::deprecated_202007_get_checkouts_param_token(self, token).await
+    }
+    async fn deprecated_202007_update_checkouts_param_token(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202007_update_checkouts_param_token(self, token, body).await
+    }
+    async fn deprecated_202007_get_checkouts_param_token_shipping_rate(
+        &self,
+        token: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202007_get_checkouts_param_token_shipping_rate(self, token).await
+    }
+    async fn create_checkouts_param_token_complete(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::create_checkouts_param_token_complete(self, token, body).await
+    }
+    async fn get_checkouts_param_token(&self, token: &str) -> Result<()> {
+        SalesChannels::get_checkouts_param_token(self, token).await
+    }
+    async fn update_checkouts_param_token(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::update_checkouts_param_token(self, token, body).await
+    }
+    async fn get_checkouts_param_token_shipping_rate(&self, token: &str) -> Result<()> {
+        SalesChannels::get_checkouts_param_token_shipping_rate(self, token).await
+    }
+    async fn deprecated_202101_create_checkouts_param_token_complete(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202101_create_checkouts_param_token_complete(self, token, body)
+            .await
+    }
+    async fn deprecated_202101_get_checkouts_param_token(&self, token: &str) -> Result<()> {
+        SalesChannels::deprecated_202101_get_checkouts_param_token(self, token).await
+    }
+    async fn deprecated_202101_update_checkouts_param_token(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202101_update_checkouts_param_token(self, token, body).await
+    }
+    async fn deprecated_202101_get_checkouts_param_token_shipping_rate(
+        &self,
+        token: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202101_get_checkouts_param_token_shipping_rate(self, token).await
+    }
+    async fn deprecated_unstable_create_checkouts_param_token_complete(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_unstable_create_checkouts_param_token_complete(self, token, body)
+            .await
+    }
+    async fn deprecated_unstable_get_checkouts_param_token(&self, token: &str) -> Result<()> {
+        SalesChannels::deprecated_unstable_get_checkouts_param_token(self, token).await
+    }
+    async fn deprecated_unstable_update_checkouts_param_token(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_unstable_update_checkouts_param_token(self, token, body).await
+    }
+    async fn deprecated_unstable_get_checkouts_param_token_shipping_rate(
+        &self,
+        token: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_unstable_get_checkouts_param_token_shipping_rate(self, token)
+            .await
+    }
+    async fn deprecated_202001_get_collection_listing(&self, limit: &str) -> Result<()> {
+        SalesChannels::deprecated_202001_get_collection_listing(self, limit).await
+    }
+    async fn deprecated_202001_get_collection_listings_param_listing_product_id(
+        &self,
+        collection_listing_id: &str,
+        limit: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202001_get_collection_listings_param_listing_product_id(
+            self,
+            collection_listing_id,
+            limit,
+        )
+        .await
+    }
+    async fn deprecated_202001_get_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202001_get_collection_listings_param_listing(
+            self,
+            collection_listing_id,
+        )
+        .await
+    }
+    async fn deprecated_202001_update_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202001_update_collection_listings_param_listing(
+            self,
+            collection_listing_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202001_delete_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202001_delete_collection_listings_param_listing(
+            self,
+            collection_listing_id,
+        )
+        .await
+    }
+    async fn deprecated_202001_delete_collection_listings_param_listing_if_exists(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<bool> {
+        SalesChannels::deprecated_202001_delete_collection_listings_param_listing_if_exists(
+            self,
+            collection_listing_id,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_collection_listing(&self, limit: &str) -> Result<()> {
+        SalesChannels::deprecated_202004_get_collection_listing(self, limit).await
+    }
+    async fn deprecated_202004_get_collection_listings_param_listing_product_id(
+        &self,
+        collection_listing_id: &str,
+        limit: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202004_get_collection_listings_param_listing_product_id(
+            self,
+            collection_listing_id,
+            limit,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202004_get_collection_listings_param_listing(
+            self,
+            collection_listing_id,
+        )
+        .await
+    }
+    async fn deprecated_202004_update_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202004_update_collection_listings_param_listing(
+            self,
+            collection_listing_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202004_delete_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202004_delete_collection_listings_param_listing(
+            self,
+            collection_listing_id,
+        )
+        .await
+    }
+    async fn deprecated_202004_delete_collection_listings_param_listing_if_exists(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<bool> {
+        SalesChannels::deprecated_202004_delete_collection_listings_param_listing_if_exists(
+            self,
+            collection_listing_id,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_collection_listing(&self, limit: &str) -> Result<()> {
+        SalesChannels::deprecated_202007_get_collection_listing(self, limit).await
+    }
+    async fn deprecated_202007_get_collection_listings_param_listing_product_id(
+        &self,
+        collection_listing_id: &str,
+        limit: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202007_get_collection_listings_param_listing_product_id(
+            self,
+            collection_listing_id,
+            limit,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202007_get_collection_listings_param_listing(
+            self,
+            collection_listing_id,
+        )
+        .await
+    }
+    async fn deprecated_202007_update_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202007_update_collection_listings_param_listing(
+            self,
+            collection_listing_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202007_delete_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202007_delete_collection_listings_param_listing(
+            self,
+            collection_listing_id,
+        )
+        .await
+    }
+    async fn deprecated_202007_delete_collection_listings_param_listing_if_exists(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<bool> {
+        SalesChannels::deprecated_202007_delete_collection_listings_param_listing_if_exists(
+            self,
+            collection_listing_id,
+        )
+        .await
+    }
+    async fn get_collection_listing(&self, limit: &str) -> Result<()> {
+        SalesChannels::get_collection_listing(self, limit).await
+    }
+    async fn get_collection_listings_param_listing_product_id(
+        &self,
+        collection_listing_id: &str,
+        limit: &str,
+    ) -> Result<()> {
+        SalesChannels::get_collection_listings_param_listing_product_id(
+            self,
+            collection_listing_id,
+            limit,
+        )
+        .await
+    }
+    async fn get_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()> {
+        SalesChannels::get_collection_listings_param_listing(self, collection_listing_id).await
+    }
+    async fn update_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::update_collection_listings_param_listing(self, collection_listing_id, body)
+            .await
+    }
+    async fn delete_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()> {
+        SalesChannels::delete_collection_listings_param_listing(self, collection_listing_id).await
+    }
+    async fn delete_collection_listings_param_listing_if_exists(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<bool> {
+        SalesChannels::delete_collection_listings_param_listing_if_exists(
+            self,
+            collection_listing_id,
+        )
+        .await
+    }
+    async fn deprecated_202101_get_collection_listing(&self, limit: &str) -> Result<()> {
+        SalesChannels::deprecated_202101_get_collection_listing(self, limit).await
+    }
+    async fn deprecated_202101_get_collection_listings_param_listing_product_id(
+        &self,
+        collection_listing_id: &str,
+        limit: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202101_get_collection_listings_param_listing_product_id(
+            self,
+            collection_listing_id,
+            limit,
+        )
+        .await
+    }
+    async fn deprecated_202101_get_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202101_get_collection_listings_param_listing(
+            self,
+            collection_listing_id,
+        )
+        .await
+    }
+    async fn deprecated_202101_update_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202101_update_collection_listings_param_listing(
+            self,
+            collection_listing_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202101_delete_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202101_delete_collection_listings_param_listing(
+            self,
+            collection_listing_id,
+        )
+        .await
+    }
+    async fn deprecated_202101_delete_collection_listings_param_listing_if_exists(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<bool> {
+        SalesChannels::deprecated_202101_delete_collection_listings_param_listing_if_exists(
+            self,
+            collection_listing_id,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_collection_listing(&self, limit: &str) -> Result<()> {
+        SalesChannels::deprecated_unstable_get_collection_listing(self, limit).await
+    }
+    async fn deprecated_unstable_get_collection_listings_param_listing_product_id(
+        &self,
+        collection_listing_id: &str,
+        limit: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_unstable_get_collection_listings_param_listing_product_id(
+            self,
+            collection_listing_id,
+            limit,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_unstable_get_collection_listings_param_listing(
+            self,
+            collection_listing_id,
+        )
+        .await
+    }
+    async fn deprecated_unstable_update_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_unstable_update_collection_listings_param_listing(
+            self,
+            collection_listing_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_unstable_delete_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_unstable_delete_collection_listings_param_listing(
+            self,
+            collection_listing_id,
+        )
+        .await
+    }
+    async fn deprecated_unstable_delete_collection_listings_param_listing_if_exists(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<bool> {
+        SalesChannels::deprecated_unstable_delete_collection_listings_param_listing_if_exists(
+            self,
+            collection_listing_id,
+        )
+        .await
+    }
+    async fn deprecated_202001_get_checkouts_param_token_payment(&self, token: &str) -> Result<()> {
+        SalesChannels::deprecated_202001_get_checkouts_param_token_payment(self, token).await
+    }
+    async fn deprecated_202001_create_checkouts_param_token_payments(
+        &self,
+        token: &str,
+        amount_required: &str,
+        request_details_required: &str,
+        session_id_required: &str,
+        unique_token_required: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202001_create_checkouts_param_token_payments(
+            self,
+            token,
+            amount_required,
+            request_details_required,
+            session_id_required,
+            unique_token_required,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202001_get_checkouts_param_token_payments_payment(
+        &self,
+        token: &str,
+        payment_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202001_get_checkouts_param_token_payments_payment(
+            self, token, payment_id,
+        )
+        .await
+    }
+    async fn deprecated_202001_get_checkouts_param_token_payments_count(
+        &self,
+        token: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202001_get_checkouts_param_token_payments_count(self, token).await
+    }
+    async fn deprecated_202004_get_checkouts_param_token_payment(&self, token: &str) -> Result<()> {
+        SalesChannels::deprecated_202004_get_checkouts_param_token_payment(self, token).await
+    }
+    async fn deprecated_202004_create_checkouts_param_token_payments(
+        &self,
+        token: &str,
+        amount_required: &str,
+        request_details_required: &str,
+        session_id_required: &str,
+        unique_token_required: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202004_create_checkouts_param_token_payments(
+            self,
+            token,
+            amount_required,
+            request_details_required,
+            session_id_required,
+            unique_token_required,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_checkouts_param_token_payments_payment(
+        &self,
+        token: &str,
+        payment_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202004_get_checkouts_param_token_payments_payment(
+            self, token, payment_id,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_checkouts_param_token_payments_count(
+        &self,
+        token: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202004_get_checkouts_param_token_payments_count(self, token).await
+    }
+    async fn deprecated_202007_get_checkouts_param_token_payment(&self, token: &str) -> Result<()> {
+        SalesChannels::deprecated_202007_get_checkouts_param_token_payment(self, token).await
+    }
+    async fn deprecated_202007_create_checkouts_param_token_payments(
+        &self,
+        token: &str,
+        amount_required: &str,
+        request_details_required: &str,
+        session_id_required: &str,
+        unique_token_required: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202007_create_checkouts_param_token_payments(
+            self,
+            token,
+            amount_required,
+            request_details_required,
+            session_id_required,
+            unique_token_required,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_checkouts_param_token_payments_payment(
+        &self,
+        token: &str,
+        payment_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202007_get_checkouts_param_token_payments_payment(
+            self, token, payment_id,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_checkouts_param_token_payments_count(
+        &self,
+        token: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202007_get_checkouts_param_token_payments_count(self, token).await
+    }
+    async fn get_checkouts_param_token_payment(&self, token: &str) -> Result<()> {
+        SalesChannels::get_checkouts_param_token_payment(self, token).await
+    }
+    async fn create_checkouts_param_token_payments(
+        &self,
+        token: &str,
+        amount_required: &str,
+        request_details_required: &str,
+        session_id_required: &str,
+        unique_token_required: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::create_checkouts_param_token_payments(
+            self,
+            token,
+            amount_required,
+            request_details_required,
+            session_id_required,
+            unique_token_required,
+            body,
+        )
+        .await
+    }
+    async fn get_checkouts_param_token_payments_payment(
+        &self,
+        token: &str,
+        payment_id: &str,
+    ) -> Result<()> {
+        SalesChannels::get_checkouts_param_token_payments_payment(self, token, payment_id).await
+    }
+    async fn get_checkouts_param_token_payments_count(&self, token: &str) -> Result<()> {
+        SalesChannels::get_checkouts_param_token_payments_count(self, token).await
+    }
+    async fn deprecated_202101_get_checkouts_param_token_payment(&self, token: &str) -> Result<()> {
+        SalesChannels::deprecated_202101_get_checkouts_param_token_payment(self, token).await
+    }
+    async fn deprecated_202101_create_checkouts_param_token_payments(
+        &self,
+        token: &str,
+        amount_required: &str,
+        request_details_required: &str,
+        session_id_required: &str,
+        unique_token_required: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202101_create_checkouts_param_token_payments(
+            self,
+            token,
+            amount_required,
+            request_details_required,
+            session_id_required,
+            unique_token_required,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202101_get_checkouts_param_token_payments_payment(
+        &self,
+        token: &str,
+        payment_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202101_get_checkouts_param_token_payments_payment(
+            self, token, payment_id,
+        )
+        .await
+    }
+    async fn deprecated_202101_get_checkouts_param_token_payments_count(
+        &self,
+        token: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202101_get_checkouts_param_token_payments_count(self, token).await
+    }
+    async fn deprecated_unstable_get_checkouts_param_token_payment(
+        &self,
+        token: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_unstable_get_checkouts_param_token_payment(self, token).await
+    }
+    async fn deprecated_unstable_create_checkouts_param_token_payments(
+        &self,
+        token: &str,
+        amount_required: &str,
+        request_details_required: &str,
+        session_id_required: &str,
+        unique_token_required: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_unstable_create_checkouts_param_token_payments(
+            self,
+            token,
+            amount_required,
+            request_details_required,
+            session_id_required,
+            unique_token_required,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_checkouts_param_token_payments_payment(
+        &self,
+        token: &str,
+        payment_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_unstable_get_checkouts_param_token_payments_payment(
+            self, token, payment_id,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_checkouts_param_token_payments_count(
+        &self,
+        token: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_unstable_get_checkouts_param_token_payments_count(self, token)
+            .await
+    }
+    async fn deprecated_202001_get_product_listing(
+        &self,
+        product_ids: &str,
+        limit: &str,
+        collection_id: &str,
+        updated_at_min: &str,
+        handle: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202001_get_product_listing(
+            self,
+            product_ids,
+            limit,
+            collection_id,
+            updated_at_min,
+            handle,
+        )
+        .await
+    }
+    async fn deprecated_202001_get_product_listings_id(&self, limit: &str) -> Result<()> {
+        SalesChannels::deprecated_202001_get_product_listings_id(self, limit).await
+    }
+    async fn deprecated_202001_get_product_listings_count(&self) -> Result<()> {
+        SalesChannels::deprecated_202001_get_product_listings_count(self).await
+    }
+    async fn deprecated_202001_get_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202001_get_product_listings_param_listing(
+            self,
+            product_listing_id,
+        )
+        .await
+    }
+    async fn deprecated_202001_update_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202001_update_product_listings_param_listing(
+            self,
+            product_listing_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202001_delete_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202001_delete_product_listings_param_listing(
+            self,
+            product_listing_id,
+        )
+        .await
+    }
+    async fn deprecated_202001_delete_product_listings_param_listing_if_exists(
+        &self,
+        product_listing_id: &str,
+    ) -> Result<bool> {
+        SalesChannels::deprecated_202001_delete_product_listings_param_listing_if_exists(
+            self,
+            product_listing_id,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_product_listing(
+        &self,
+        product_ids: &str,
+        limit: &str,
+        collection_id: &str,
+        updated_at_min: &str,
+        handle: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202004_get_product_listing(
+            self,
+            product_ids,
+            limit,
+            collection_id,
+            updated_at_min,
+            handle,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_product_listings_id(&self, limit: &str) -> Result<()> {
+        SalesChannels::deprecated_202004_get_product_listings_id(self, limit).await
+    }
+    async fn deprecated_202004_get_product_listings_count(&self) -> Result<()> {
+        SalesChannels::deprecated_202004_get_product_listings_count(self).await
+    }
+    async fn deprecated_202004_get_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202004_get_product_listings_param_listing(
+            self,
+            product_listing_id,
+        )
+        .await
+    }
+    async fn deprecated_202004_update_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202004_update_product_listings_param_listing(
+            self,
+            product_listing_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202004_delete_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202004_delete_product_listings_param_listing(
+            self,
+            product_listing_id,
+        )
+        .await
+    }
+    async fn deprecated_202004_delete_product_listings_param_listing_if_exists(
+        &self,
+        product_listing_id: &str,
+    ) -> Result<bool> {
+        SalesChannels::deprecated_202004_delete_product_listings_param_listing_if_exists(
+            self,
+            product_listing_id,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_product_listing(
+        &self,
+        product_ids: &str,
+        limit: &str,
+        collection_id: &str,
+        updated_at_min: &str,
+        handle: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202007_get_product_listing(
+            self,
+            product_ids,
+            limit,
+            collection_id,
+            updated_at_min,
+            handle,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_product_listings_id(&self, limit: &str) -> Result<()> {
+        SalesChannels::deprecated_202007_get_product_listings_id(self, limit).await
+    }
+    async fn deprecated_202007_get_product_listings_count(&self) -> Result<()> {
+        SalesChannels::deprecated_202007_get_product_listings_count(self).await
+    }
+    async fn deprecated_202007_get_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202007_get_product_listings_param_listing(
+            self,
+            product_listing_id,
+        )
+        .await
+    }
+    async fn deprecated_202007_update_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202007_update_product_listings_param_listing(
+            self,
+            product_listing_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202007_delete_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202007_delete_product_listings_param_listing(
+            self,
+            product_listing_id,
+        )
+        .await
+    }
+    async fn deprecated_202007_delete_product_listings_param_listing_if_exists(
+        &self,
+        product_listing_id: &str,
+    ) -> Result<bool> {
+        SalesChannels::deprecated_202007_delete_product_listings_param_listing_if_exists(
+            self,
+            product_listing_id,
+        )
+        .await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct ShippingAndFulfillment {
     pub client: Client,
@@ -12,6 +10,14 @@ impl ShippingAndFulfillment {
         ShippingAndFulfillment { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        ShippingAndFulfillment {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieves a list of fulfillment orders on a shop for a specific app.
      *
@@ -34,23 +40,25 @@ impl ShippingAndFulfillment {
         assignment_status: &str,
         location_ids: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !assignment_status.is_empty() {
-            query_args.push((
-                "assignment_status".to_string(),
-                assignment_status.to_string(),
-            ));
+            query_args.push(("assignment_status", assignment_status.to_string()));
         }
         if !location_ids.is_empty() {
-            query_args.push(("location_ids".to_string(), location_ids.to_string()));
+            query_args.push(("location_ids", location_ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/assigned_fulfillment_orders.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-01/assigned_fulfillment_orders.json",
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/assigned_fulfillment_orders.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -75,23 +83,25 @@ impl ShippingAndFulfillment {
         assignment_status: &str,
         location_ids: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !assignment_status.is_empty() {
-            query_args.push((
-                "assignment_status".to_string(),
-                assignment_status.to_string(),
-            ));
+            query_args.push(("assignment_status", assignment_status.to_string()));
         }
         if !location_ids.is_empty() {
-            query_args.push(("location_ids".to_string(), location_ids.to_string()));
+            query_args.push(("location_ids", location_ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/assigned_fulfillment_orders.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-04/assigned_fulfillment_orders.json",
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/assigned_fulfillment_orders.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -116,23 +126,25 @@ impl ShippingAndFulfillment {
         assignment_status: &str,
         location_ids: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !assignment_status.is_empty() {
-            query_args.push((
-                "assignment_status".to_string(),
-                assignment_status.to_string(),
-            ));
+            query_args.push(("assignment_status", assignment_status.to_string()));
         }
         if !location_ids.is_empty() {
-            query_args.push(("location_ids".to_string(), location_ids.to_string()));
+            query_args.push(("location_ids", location_ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/assigned_fulfillment_orders.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-07/assigned_fulfillment_orders.json",
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/assigned_fulfillment_orders.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -157,23 +169,25 @@ impl ShippingAndFulfillment {
         assignment_status: &str,
         location_ids: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !assignment_status.is_empty() {
-            query_args.push((
-                "assignment_status".to_string(),
-                assignment_status.to_string(),
-            ));
+            query_args.push(("assignment_status", assignment_status.to_string()));
         }
         if !location_ids.is_empty() {
-            query_args.push(("location_ids".to_string(), location_ids.to_string()));
+            query_args.push(("location_ids", location_ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/assigned_fulfillment_orders.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-10/assigned_fulfillment_orders.json",
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/assigned_fulfillment_orders.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -198,23 +212,25 @@ impl ShippingAndFulfillment {
         assignment_status: &str,
         location_ids: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !assignment_status.is_empty() {
-            query_args.push((
-                "assignment_status".to_string(),
-                assignment_status.to_string(),
-            ));
+            query_args.push(("assignment_status", assignment_status.to_string()));
         }
         if !location_ids.is_empty() {
-            query_args.push(("location_ids".to_string(), location_ids.to_string()));
+            query_args.push(("location_ids", location_ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/assigned_fulfillment_orders.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/2021-01/assigned_fulfillment_orders.json",
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/assigned_fulfillment_orders.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -239,23 +255,25 @@ impl ShippingAndFulfillment {
         assignment_status: &str,
         location_ids: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !assignment_status.is_empty() {
-            query_args.push((
-                "assignment_status".to_string(),
-                assignment_status.to_string(),
-            ));
+            query_args.push(("assignment_status", assignment_status.to_string()));
         }
         if !location_ids.is_empty() {
-            query_args.push(("location_ids".to_string(), location_ids.to_string()));
+            query_args.push(("location_ids", location_ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/assigned_fulfillment_orders.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/unstable/assigned_fulfillment_orders.json",
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/assigned_fulfillment_orders.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -276,20 +294,21 @@ impl ShippingAndFulfillment {
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/fulfillment_orders/{}/cancellation_request.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/fulfillment_orders/{}/cancellation_request.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}/cancellation_request.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
     }
 
     /**
@@ -310,20 +329,21 @@ impl ShippingAndFulfillment {
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/fulfillment_orders/{}/cancellation_request/accept.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/fulfillment_orders/{}/cancellation_request/accept.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}/cancellation_request/accept.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
     }
 
     /**
@@ -344,20 +364,21 @@ impl ShippingAndFulfillment {
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/fulfillment_orders/{}/cancellation_request/reject.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/fulfillment_orders/{}/cancellation_request/reject.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}/cancellation_request/reject.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
     }
 
     /**
@@ -378,20 +399,21 @@ impl ShippingAndFulfillment {
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/fulfillment_orders/{}/cancellation_request.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/fulfillment_orders/{}/cancellation_request.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}/cancellation_request.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
     }
 
     /**
@@ -412,20 +434,21 @@ impl ShippingAndFulfillment {
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/fulfillment_orders/{}/cancellation_request/accept.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/fulfillment_orders/{}/cancellation_request/accept.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}/cancellation_request/accept.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
     }
 
     /**
@@ -446,20 +469,21 @@ impl ShippingAndFulfillment {
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/fulfillment_orders/{}/cancellation_request/reject.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/fulfillment_orders/{}/cancellation_request/reject.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}/cancellation_request/reject.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
     }
 
     /**
@@ -480,20 +504,21 @@ impl ShippingAndFulfillment {
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/fulfillment_orders/{}/cancellation_request.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/fulfillment_orders/{}/cancellation_request.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}/cancellation_request.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
     }
 
     /**
@@ -514,20 +539,21 @@ impl ShippingAndFulfillment {
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/fulfillment_orders/{}/cancellation_request/accept.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/fulfillment_orders/{}/cancellation_request/accept.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}/cancellation_request/accept.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
     }
 
     /**
@@ -548,20 +574,21 @@ impl ShippingAndFulfillment {
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/fulfillment_orders/{}/cancellation_request/reject.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/fulfillment_orders/{}/cancellation_request/reject.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}/cancellation_request/reject.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
     }
 
     /**
@@ -582,20 +609,21 @@ impl ShippingAndFulfillment {
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/fulfillment_orders/{}/cancellation_request.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/fulfillment_orders/{}/cancellation_request.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/cancellation_request.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
     }
 
     /**
@@ -616,20 +644,21 @@ impl ShippingAndFulfillment {
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/fulfillment_orders/{}/cancellation_request/accept.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/fulfillment_orders/{}/cancellation_request/accept.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/cancellation_request/accept.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
     }
 
     /**
@@ -650,20 +679,21 @@ impl ShippingAndFulfillment {
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/fulfillment_orders/{}/cancellation_request/reject.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/fulfillment_orders/{}/cancellation_request/reject.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/cancellation_request/reject.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
     }
 
     /**
@@ -684,20 +714,21 @@ impl ShippingAndFulfillment {
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/fulfillment_orders/{}/cancellation_request.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/fulfillment_orders/{}/cancellation_request.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/cancellation_request.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
     }
 
     /**
@@ -718,20 +749,21 @@ impl ShippingAndFulfillment {
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/fulfillment_orders/{}/cancellation_request/accept.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/fulfillment_orders/{}/cancellation_request/accept.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/cancellation_request/accept.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
     }
 
     /**
@@ -752,20 +784,21 @@ impl ShippingAndFulfillment {
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/fulfillment_orders/{}/cancellation_request/reject.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/fulfillment_orders/{}/cancellation_request/reject.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/cancellation_request/reject.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
     }
 
     /**
@@ -786,20 +819,21 @@ impl ShippingAndFulfillment {
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/fulfillment_orders/{}/cancellation_request.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/fulfillment_orders/{}/cancellation_request.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/cancellation_request.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
     }
 
     /**
@@ -820,20 +854,21 @@ impl ShippingAndFulfillment {
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/fulfillment_orders/{}/cancellation_request/accept.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/fulfillment_orders/{}/cancellation_request/accept.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/cancellation_request/accept.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
     }
 
     /**
@@ -854,20 +889,21 @@ impl ShippingAndFulfillment {
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/fulfillment_orders/{}/cancellation_request/reject.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/fulfillment_orders/{}/cancellation_request/reject.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/cancellation_request/reject.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
     }
 
     /**
@@ -879,7 +915,9 @@ impl ShippingAndFulfillment {
      */
     pub async fn deprecated_202001_get_carrier_service(&self) -> Result<()> {
         let url = "/admin/api/2020-01/carrier_services.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/carrier_services.json", &[])
+        })
     }
 
     /**
@@ -895,8 +933,11 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = "/admin/api/2020-01/carrier_services.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-01/carrier_services.json", &[])
+            })
     }
 
     /**
@@ -916,10 +957,16 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/carrier_services/{}/json",
-            crate::progenitor_support::encode_path(&carrier_service_id.to_string()),
+            self.client.encode_path(carrier_service_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/carrier_services/{carrier_service_id}.json",
+                &[("carrier_service_id", carrier_service_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -940,12 +987,19 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/carrier_services/{}/json",
-            crate::progenitor_support::encode_path(&carrier_service_id.to_string()),
+            self.client.encode_path(carrier_service_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-01/carrier_services/{carrier_service_id}.json",
+                    &[("carrier_service_id", carrier_service_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -965,10 +1019,44 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/carrier_services/{}/json",
-            crate::progenitor_support::encode_path(&carrier_service_id.to_string()),
+            self.client.encode_path(carrier_service_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-01/carrier_services/{carrier_service_id}.json",
+                &[("carrier_service_id", carrier_service_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a carrier service.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-01/carrier_services/{carrier_service_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_delete_carrier_services_param_service`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202001_delete_carrier_services_param_service_if_exists(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-01/carrier_services/{}/json",
+            self.client.encode_path(carrier_service_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-01/carrier_services/{carrier_service_id}.json",
+                    &[("carrier_service_id", carrier_service_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -980,7 +1068,9 @@ impl ShippingAndFulfillment {
      */
     pub async fn deprecated_202004_get_carrier_service(&self) -> Result<()> {
         let url = "/admin/api/2020-04/carrier_services.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/carrier_services.json", &[])
+        })
     }
 
     /**
@@ -996,8 +1086,11 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = "/admin/api/2020-04/carrier_services.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-04/carrier_services.json", &[])
+            })
     }
 
     /**
@@ -1017,10 +1110,16 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/carrier_services/{}/json",
-            crate::progenitor_support::encode_path(&carrier_service_id.to_string()),
+            self.client.encode_path(carrier_service_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/carrier_services/{carrier_service_id}.json",
+                &[("carrier_service_id", carrier_service_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1041,12 +1140,19 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/carrier_services/{}/json",
-            crate::progenitor_support::encode_path(&carrier_service_id.to_string()),
+            self.client.encode_path(carrier_service_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-04/carrier_services/{carrier_service_id}.json",
+                    &[("carrier_service_id", carrier_service_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1066,10 +1172,44 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/carrier_services/{}/json",
-            crate::progenitor_support::encode_path(&carrier_service_id.to_string()),
+            self.client.encode_path(carrier_service_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-04/carrier_services/{carrier_service_id}.json",
+                &[("carrier_service_id", carrier_service_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a carrier service.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-04/carrier_services/{carrier_service_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_delete_carrier_services_param_service`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202004_delete_carrier_services_param_service_if_exists(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-04/carrier_services/{}/json",
+            self.client.encode_path(carrier_service_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-04/carrier_services/{carrier_service_id}.json",
+                    &[("carrier_service_id", carrier_service_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1081,7 +1221,9 @@ impl ShippingAndFulfillment {
      */
     pub async fn deprecated_202007_get_carrier_service(&self) -> Result<()> {
         let url = "/admin/api/2020-07/carrier_services.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/carrier_services.json", &[])
+        })
     }
 
     /**
@@ -1097,8 +1239,11 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = "/admin/api/2020-07/carrier_services.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-07/carrier_services.json", &[])
+            })
     }
 
     /**
@@ -1118,10 +1263,16 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/carrier_services/{}/json",
-            crate::progenitor_support::encode_path(&carrier_service_id.to_string()),
+            self.client.encode_path(carrier_service_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/carrier_services/{carrier_service_id}.json",
+                &[("carrier_service_id", carrier_service_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1142,12 +1293,19 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/carrier_services/{}/json",
-            crate::progenitor_support::encode_path(&carrier_service_id.to_string()),
+            self.client.encode_path(carrier_service_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-07/carrier_services/{carrier_service_id}.json",
+                    &[("carrier_service_id", carrier_service_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1167,10 +1325,44 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/carrier_services/{}/json",
-            crate::progenitor_support::encode_path(&carrier_service_id.to_string()),
+            self.client.encode_path(carrier_service_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-07/carrier_services/{carrier_service_id}.json",
+                &[("carrier_service_id", carrier_service_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a carrier service.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-07/carrier_services/{carrier_service_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_delete_carrier_services_param_service`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202007_delete_carrier_services_param_service_if_exists(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-07/carrier_services/{}/json",
+            self.client.encode_path(carrier_service_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-07/carrier_services/{carrier_service_id}.json",
+                    &[("carrier_service_id", carrier_service_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1182,7 +1374,9 @@ impl ShippingAndFulfillment {
      */
     pub async fn get_carrier_service(&self) -> Result<()> {
         let url = "/admin/api/2020-10/carrier_services.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/carrier_services.json", &[])
+        })
     }
 
     /**
@@ -1195,8 +1389,11 @@ impl ShippingAndFulfillment {
     pub async fn create_carrier_services(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-10/carrier_services.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-10/carrier_services.json", &[])
+            })
     }
 
     /**
@@ -1213,10 +1410,16 @@ impl ShippingAndFulfillment {
     pub async fn get_carrier_services_param_service(&self, carrier_service_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/carrier_services/{}/json",
-            crate::progenitor_support::encode_path(&carrier_service_id.to_string()),
+            self.client.encode_path(carrier_service_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/carrier_services/{carrier_service_id}.json",
+                &[("carrier_service_id", carrier_service_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1237,12 +1440,19 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/carrier_services/{}/json",
-            crate::progenitor_support::encode_path(&carrier_service_id.to_string()),
+            self.client.encode_path(carrier_service_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-10/carrier_services/{carrier_service_id}.json",
+                    &[("carrier_service_id", carrier_service_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1262,10 +1472,44 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/carrier_services/{}/json",
-            crate::progenitor_support::encode_path(&carrier_service_id.to_string()),
+            self.client.encode_path(carrier_service_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-10/carrier_services/{carrier_service_id}.json",
+                &[("carrier_service_id", carrier_service_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a carrier service.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-10/carrier_services/{carrier_service_id}.json` endpoint.
+     *
+     * As opposed to `delete_carrier_services_param_service`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_carrier_services_param_service_if_exists(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-10/carrier_services/{}/json",
+            self.client.encode_path(carrier_service_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-10/carrier_services/{carrier_service_id}.json",
+                    &[("carrier_service_id", carrier_service_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1277,7 +1521,9 @@ impl ShippingAndFulfillment {
      */
     pub async fn deprecated_202101_get_carrier_service(&self) -> Result<()> {
         let url = "/admin/api/2021-01/carrier_services.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/carrier_services.json", &[])
+        })
     }
 
     /**
@@ -1293,8 +1539,11 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = "/admin/api/2021-01/carrier_services.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2021-01/carrier_services.json", &[])
+            })
     }
 
     /**
@@ -1314,10 +1563,16 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/carrier_services/{}/json",
-            crate::progenitor_support::encode_path(&carrier_service_id.to_string()),
+            self.client.encode_path(carrier_service_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/carrier_services/{carrier_service_id}.json",
+                &[("carrier_service_id", carrier_service_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1338,12 +1593,19 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/carrier_services/{}/json",
-            crate::progenitor_support::encode_path(&carrier_service_id.to_string()),
+            self.client.encode_path(carrier_service_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2021-01/carrier_services/{carrier_service_id}.json",
+                    &[("carrier_service_id", carrier_service_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1363,10 +1625,44 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/carrier_services/{}/json",
-            crate::progenitor_support::encode_path(&carrier_service_id.to_string()),
+            self.client.encode_path(carrier_service_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2021-01/carrier_services/{carrier_service_id}.json",
+                &[("carrier_service_id", carrier_service_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a carrier service.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2021-01/carrier_services/{carrier_service_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_delete_carrier_services_param_service`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202101_delete_carrier_services_param_service_if_exists(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2021-01/carrier_services/{}/json",
+            self.client.encode_path(carrier_service_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2021-01/carrier_services/{carrier_service_id}.json",
+                    &[("carrier_service_id", carrier_service_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1378,7 +1674,9 @@ impl ShippingAndFulfillment {
      */
     pub async fn deprecated_unstable_get_carrier_service(&self) -> Result<()> {
         let url = "/admin/api/unstable/carrier_services.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/carrier_services.json", &[])
+        })
     }
 
     /**
@@ -1394,8 +1692,11 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = "/admin/api/unstable/carrier_services.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/unstable/carrier_services.json", &[])
+            })
     }
 
     /**
@@ -1415,10 +1716,16 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/carrier_services/{}/json",
-            crate::progenitor_support::encode_path(&carrier_service_id.to_string()),
+            self.client.encode_path(carrier_service_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/carrier_services/{carrier_service_id}.json",
+                &[("carrier_service_id", carrier_service_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1439,12 +1746,19 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/carrier_services/{}/json",
-            crate::progenitor_support::encode_path(&carrier_service_id.to_string()),
+            self.client.encode_path(carrier_service_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/unstable/carrier_services/{carrier_service_id}.json",
+                    &[("carrier_service_id", carrier_service_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1464,10 +1778,44 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/carrier_services/{}/json",
-            crate::progenitor_support::encode_path(&carrier_service_id.to_string()),
+            self.client.encode_path(carrier_service_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/unstable/carrier_services/{carrier_service_id}.json",
+                &[("carrier_service_id", carrier_service_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a carrier service.
+     *
+     * This function performs a `DELETE` to the `/admin/api/unstable/carrier_services/{carrier_service_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_delete_carrier_services_param_service`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_unstable_delete_carrier_services_param_service_if_exists(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/unstable/carrier_services/{}/json",
+            self.client.encode_path(carrier_service_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/unstable/carrier_services/{carrier_service_id}.json",
+                    &[("carrier_service_id", carrier_service_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1500,36 +1848,43 @@ impl ShippingAndFulfillment {
         updated_at_max: &str,
         updated_at_min: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/orders/{}/fulfillments.json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/orders/{}/fulfillments.json",
+                self.client.encode_path(order_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/orders/{order_id}/fulfillments.json",
+                &[("order_id", order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1572,12 +1927,19 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/orders/{}/fulfillments.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            self.client.encode_path(order_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-01/orders/{order_id}/fulfillments.json",
+                    &[("order_id", order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1598,10 +1960,16 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/fulfillment_orders/{}/fulfillments.json",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
+            self.client.encode_path(fulfillment_order_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}/fulfillments.json",
+                &[("fulfillment_order_id", fulfillment_order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1627,27 +1995,34 @@ impl ShippingAndFulfillment {
         updated_at_min: &str,
         updated_at_max: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/orders/{}/fulfillments/count.json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/orders/{}/fulfillments/count.json",
+                self.client.encode_path(order_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/orders/{order_id}/fulfillments/count.json",
+                &[("order_id", order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1669,19 +2044,29 @@ impl ShippingAndFulfillment {
         fulfillment_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/orders/{}/fulfillments/{}/json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/orders/{}/fulfillments/{}/json",
+                self.client.encode_path(order_id),
+                self.client.encode_path(fulfillment_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/orders/{order_id}/fulfillments/{fulfillment_id}.json",
+                &[
+                    ("order_id", order_id.to_string()),
+                    ("fulfillment_id", fulfillment_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1704,13 +2089,23 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/orders/{}/fulfillments/{}/json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-01/orders/{order_id}/fulfillments/{fulfillment_id}.json",
+                    &[
+                        ("order_id", order_id.to_string()),
+                        ("fulfillment_id", fulfillment_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1726,8 +2121,11 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = "/admin/api/2020-01/fulfillments.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-01/fulfillments.json", &[])
+            })
     }
 
     /**
@@ -1748,12 +2146,19 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/fulfillments/{}/update_tracking.json",
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-01/fulfillments/{fulfillment_id}/update_tracking.json",
+                    &[("fulfillment_id", fulfillment_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1776,13 +2181,13 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/orders/{}/fulfillments/{}/complete.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-01/orders/{order_id}/fulfillments/{fulfillment_id}/complete.json", &[("order_id", order_id.to_string()), ("fulfillment_id", fulfillment_id.to_string())]))
     }
 
     /**
@@ -1805,13 +2210,23 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/orders/{}/fulfillments/{}/open.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-01/orders/{order_id}/fulfillments/{fulfillment_id}/open.json",
+                    &[
+                        ("order_id", order_id.to_string()),
+                        ("fulfillment_id", fulfillment_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1834,13 +2249,13 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/orders/{}/fulfillments/{}/cancel.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-01/orders/{order_id}/fulfillments/{fulfillment_id}/cancel.json", &[("order_id", order_id.to_string()), ("fulfillment_id", fulfillment_id.to_string())]))
     }
 
     /**
@@ -1861,12 +2276,19 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/fulfillments/{}/cancel.json",
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-01/fulfillments/{fulfillment_id}/cancel.json",
+                    &[("fulfillment_id", fulfillment_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1899,36 +2321,43 @@ impl ShippingAndFulfillment {
         updated_at_max: &str,
         updated_at_min: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/orders/{}/fulfillments.json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/orders/{}/fulfillments.json",
+                self.client.encode_path(order_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/orders/{order_id}/fulfillments.json",
+                &[("order_id", order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1971,12 +2400,19 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/orders/{}/fulfillments.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            self.client.encode_path(order_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-04/orders/{order_id}/fulfillments.json",
+                    &[("order_id", order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1997,10 +2433,16 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/fulfillment_orders/{}/fulfillments.json",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
+            self.client.encode_path(fulfillment_order_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}/fulfillments.json",
+                &[("fulfillment_order_id", fulfillment_order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2026,27 +2468,34 @@ impl ShippingAndFulfillment {
         updated_at_min: &str,
         updated_at_max: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/orders/{}/fulfillments/count.json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/orders/{}/fulfillments/count.json",
+                self.client.encode_path(order_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/orders/{order_id}/fulfillments/count.json",
+                &[("order_id", order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2068,19 +2517,29 @@ impl ShippingAndFulfillment {
         fulfillment_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/orders/{}/fulfillments/{}/json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/orders/{}/fulfillments/{}/json",
+                self.client.encode_path(order_id),
+                self.client.encode_path(fulfillment_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/orders/{order_id}/fulfillments/{fulfillment_id}.json",
+                &[
+                    ("order_id", order_id.to_string()),
+                    ("fulfillment_id", fulfillment_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -2103,13 +2562,23 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/orders/{}/fulfillments/{}/json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-04/orders/{order_id}/fulfillments/{fulfillment_id}.json",
+                    &[
+                        ("order_id", order_id.to_string()),
+                        ("fulfillment_id", fulfillment_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -2125,8 +2594,11 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = "/admin/api/2020-04/fulfillments.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-04/fulfillments.json", &[])
+            })
     }
 
     /**
@@ -2147,12 +2619,19 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/fulfillments/{}/update_tracking.json",
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-04/fulfillments/{fulfillment_id}/update_tracking.json",
+                    &[("fulfillment_id", fulfillment_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2175,13 +2654,13 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/orders/{}/fulfillments/{}/complete.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-04/orders/{order_id}/fulfillments/{fulfillment_id}/complete.json", &[("order_id", order_id.to_string()), ("fulfillment_id", fulfillment_id.to_string())]))
     }
 
     /**
@@ -2204,13 +2683,23 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/orders/{}/fulfillments/{}/open.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-04/orders/{order_id}/fulfillments/{fulfillment_id}/open.json",
+                    &[
+                        ("order_id", order_id.to_string()),
+                        ("fulfillment_id", fulfillment_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -2233,13 +2722,13 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/orders/{}/fulfillments/{}/cancel.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-04/orders/{order_id}/fulfillments/{fulfillment_id}/cancel.json", &[("order_id", order_id.to_string()), ("fulfillment_id", fulfillment_id.to_string())]))
     }
 
     /**
@@ -2260,12 +2749,19 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/fulfillments/{}/cancel.json",
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-04/fulfillments/{fulfillment_id}/cancel.json",
+                    &[("fulfillment_id", fulfillment_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2298,36 +2794,43 @@ impl ShippingAndFulfillment {
         updated_at_max: &str,
         updated_at_min: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/orders/{}/fulfillments.json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/orders/{}/fulfillments.json",
+                self.client.encode_path(order_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/orders/{order_id}/fulfillments.json",
+                &[("order_id", order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2370,12 +2873,19 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/orders/{}/fulfillments.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            self.client.encode_path(order_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-07/orders/{order_id}/fulfillments.json",
+                    &[("order_id", order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2396,10 +2906,16 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/fulfillment_orders/{}/fulfillments.json",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
+            self.client.encode_path(fulfillment_order_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}/fulfillments.json",
+                &[("fulfillment_order_id", fulfillment_order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2425,27 +2941,34 @@ impl ShippingAndFulfillment {
         updated_at_min: &str,
         updated_at_max: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/orders/{}/fulfillments/count.json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/orders/{}/fulfillments/count.json",
+                self.client.encode_path(order_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/orders/{order_id}/fulfillments/count.json",
+                &[("order_id", order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2467,19 +2990,29 @@ impl ShippingAndFulfillment {
         fulfillment_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/orders/{}/fulfillments/{}/json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/orders/{}/fulfillments/{}/json",
+                self.client.encode_path(order_id),
+                self.client.encode_path(fulfillment_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/orders/{order_id}/fulfillments/{fulfillment_id}.json",
+                &[
+                    ("order_id", order_id.to_string()),
+                    ("fulfillment_id", fulfillment_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -2502,13 +3035,23 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/orders/{}/fulfillments/{}/json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-07/orders/{order_id}/fulfillments/{fulfillment_id}.json",
+                    &[
+                        ("order_id", order_id.to_string()),
+                        ("fulfillment_id", fulfillment_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -2524,8 +3067,11 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = "/admin/api/2020-07/fulfillments.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-07/fulfillments.json", &[])
+            })
     }
 
     /**
@@ -2546,12 +3092,19 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/fulfillments/{}/update_tracking.json",
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-07/fulfillments/{fulfillment_id}/update_tracking.json",
+                    &[("fulfillment_id", fulfillment_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2574,13 +3127,13 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/orders/{}/fulfillments/{}/complete.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-07/orders/{order_id}/fulfillments/{fulfillment_id}/complete.json", &[("order_id", order_id.to_string()), ("fulfillment_id", fulfillment_id.to_string())]))
     }
 
     /**
@@ -2603,13 +3156,23 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/orders/{}/fulfillments/{}/open.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-07/orders/{order_id}/fulfillments/{fulfillment_id}/open.json",
+                    &[
+                        ("order_id", order_id.to_string()),
+                        ("fulfillment_id", fulfillment_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -2632,13 +3195,13 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/orders/{}/fulfillments/{}/cancel.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-07/orders/{order_id}/fulfillments/{fulfillment_id}/cancel.json", &[("order_id", order_id.to_string()), ("fulfillment_id", fulfillment_id.to_string())]))
     }
 
     /**
@@ -2659,12 +3222,19 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/fulfillments/{}/cancel.json",
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-07/fulfillments/{fulfillment_id}/cancel.json",
+                    &[("fulfillment_id", fulfillment_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2697,36 +3267,43 @@ impl ShippingAndFulfillment {
         updated_at_max: &str,
         updated_at_min: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/orders/{}/fulfillments.json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/orders/{}/fulfillments.json",
+                self.client.encode_path(order_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/orders/{order_id}/fulfillments.json",
+                &[("order_id", order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2769,12 +3346,19 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/orders/{}/fulfillments.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            self.client.encode_path(order_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-10/orders/{order_id}/fulfillments.json",
+                    &[("order_id", order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2795,10 +3379,16 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/fulfillment_orders/{}/fulfillments.json",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
+            self.client.encode_path(fulfillment_order_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/fulfillments.json",
+                &[("fulfillment_order_id", fulfillment_order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2824,27 +3414,34 @@ impl ShippingAndFulfillment {
         updated_at_min: &str,
         updated_at_max: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/orders/{}/fulfillments/count.json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/orders/{}/fulfillments/count.json",
+                self.client.encode_path(order_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/orders/{order_id}/fulfillments/count.json",
+                &[("order_id", order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -2866,19 +3463,29 @@ impl ShippingAndFulfillment {
         fulfillment_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/orders/{}/fulfillments/{}/json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/orders/{}/fulfillments/{}/json",
+                self.client.encode_path(order_id),
+                self.client.encode_path(fulfillment_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/orders/{order_id}/fulfillments/{fulfillment_id}.json",
+                &[
+                    ("order_id", order_id.to_string()),
+                    ("fulfillment_id", fulfillment_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -2901,13 +3508,23 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/orders/{}/fulfillments/{}/json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-10/orders/{order_id}/fulfillments/{fulfillment_id}.json",
+                    &[
+                        ("order_id", order_id.to_string()),
+                        ("fulfillment_id", fulfillment_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -2920,8 +3537,11 @@ impl ShippingAndFulfillment {
     pub async fn create_fulfillments(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-10/fulfillments.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-10/fulfillments.json", &[])
+            })
     }
 
     /**
@@ -2942,12 +3562,19 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/fulfillments/{}/update_tracking.json",
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-10/fulfillments/{fulfillment_id}/update_tracking.json",
+                    &[("fulfillment_id", fulfillment_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -2970,13 +3597,13 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/orders/{}/fulfillments/{}/complete.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-10/orders/{order_id}/fulfillments/{fulfillment_id}/complete.json", &[("order_id", order_id.to_string()), ("fulfillment_id", fulfillment_id.to_string())]))
     }
 
     /**
@@ -2999,13 +3626,23 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/orders/{}/fulfillments/{}/open.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-10/orders/{order_id}/fulfillments/{fulfillment_id}/open.json",
+                    &[
+                        ("order_id", order_id.to_string()),
+                        ("fulfillment_id", fulfillment_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -3028,13 +3665,13 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/orders/{}/fulfillments/{}/cancel.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-10/orders/{order_id}/fulfillments/{fulfillment_id}/cancel.json", &[("order_id", order_id.to_string()), ("fulfillment_id", fulfillment_id.to_string())]))
     }
 
     /**
@@ -3055,12 +3692,19 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/fulfillments/{}/cancel.json",
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-10/fulfillments/{fulfillment_id}/cancel.json",
+                    &[("fulfillment_id", fulfillment_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -3093,36 +3737,43 @@ impl ShippingAndFulfillment {
         updated_at_max: &str,
         updated_at_min: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/orders/{}/fulfillments.json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/orders/{}/fulfillments.json",
+                self.client.encode_path(order_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/orders/{order_id}/fulfillments.json",
+                &[("order_id", order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -3165,12 +3816,19 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/orders/{}/fulfillments.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            self.client.encode_path(order_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2021-01/orders/{order_id}/fulfillments.json",
+                    &[("order_id", order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -3191,10 +3849,16 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/fulfillment_orders/{}/fulfillments.json",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
+            self.client.encode_path(fulfillment_order_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/fulfillments.json",
+                &[("fulfillment_order_id", fulfillment_order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -3220,27 +3884,34 @@ impl ShippingAndFulfillment {
         updated_at_min: &str,
         updated_at_max: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/orders/{}/fulfillments/count.json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/orders/{}/fulfillments/count.json",
+                self.client.encode_path(order_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/orders/{order_id}/fulfillments/count.json",
+                &[("order_id", order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -3262,19 +3933,29 @@ impl ShippingAndFulfillment {
         fulfillment_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/orders/{}/fulfillments/{}/json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/orders/{}/fulfillments/{}/json",
+                self.client.encode_path(order_id),
+                self.client.encode_path(fulfillment_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/orders/{order_id}/fulfillments/{fulfillment_id}.json",
+                &[
+                    ("order_id", order_id.to_string()),
+                    ("fulfillment_id", fulfillment_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -3297,13 +3978,23 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/orders/{}/fulfillments/{}/json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2021-01/orders/{order_id}/fulfillments/{fulfillment_id}.json",
+                    &[
+                        ("order_id", order_id.to_string()),
+                        ("fulfillment_id", fulfillment_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -3319,8 +4010,11 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = "/admin/api/2021-01/fulfillments.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2021-01/fulfillments.json", &[])
+            })
     }
 
     /**
@@ -3341,12 +4035,19 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/fulfillments/{}/update_tracking.json",
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2021-01/fulfillments/{fulfillment_id}/update_tracking.json",
+                    &[("fulfillment_id", fulfillment_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -3369,13 +4070,13 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/orders/{}/fulfillments/{}/complete.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2021-01/orders/{order_id}/fulfillments/{fulfillment_id}/complete.json", &[("order_id", order_id.to_string()), ("fulfillment_id", fulfillment_id.to_string())]))
     }
 
     /**
@@ -3398,13 +4099,23 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/orders/{}/fulfillments/{}/open.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2021-01/orders/{order_id}/fulfillments/{fulfillment_id}/open.json",
+                    &[
+                        ("order_id", order_id.to_string()),
+                        ("fulfillment_id", fulfillment_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -3427,13 +4138,13 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/orders/{}/fulfillments/{}/cancel.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2021-01/orders/{order_id}/fulfillments/{fulfillment_id}/cancel.json", &[("order_id", order_id.to_string()), ("fulfillment_id", fulfillment_id.to_string())]))
     }
 
     /**
@@ -3454,12 +4165,19 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/fulfillments/{}/cancel.json",
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2021-01/fulfillments/{fulfillment_id}/cancel.json",
+                    &[("fulfillment_id", fulfillment_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -3492,36 +4210,43 @@ impl ShippingAndFulfillment {
         updated_at_max: &str,
         updated_at_min: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/orders/{}/fulfillments.json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/orders/{}/fulfillments.json",
+                self.client.encode_path(order_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/orders/{order_id}/fulfillments.json",
+                &[("order_id", order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -3564,12 +4289,19 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/orders/{}/fulfillments.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            self.client.encode_path(order_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/unstable/orders/{order_id}/fulfillments.json",
+                    &[("order_id", order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -3590,10 +4322,16 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/fulfillment_orders/{}/fulfillments.json",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
+            self.client.encode_path(fulfillment_order_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/fulfillments.json",
+                &[("fulfillment_order_id", fulfillment_order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -3619,27 +4357,34 @@ impl ShippingAndFulfillment {
         updated_at_min: &str,
         updated_at_max: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !created_at_max.is_empty() {
-            query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
+            query_args.push(("created_at_max", created_at_max.to_string()));
         }
         if !created_at_min.is_empty() {
-            query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
+            query_args.push(("created_at_min", created_at_min.to_string()));
         }
         if !updated_at_max.is_empty() {
-            query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
+            query_args.push(("updated_at_max", updated_at_max.to_string()));
         }
         if !updated_at_min.is_empty() {
-            query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
+            query_args.push(("updated_at_min", updated_at_min.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/orders/{}/fulfillments/count.json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/orders/{}/fulfillments/count.json",
+                self.client.encode_path(order_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/orders/{order_id}/fulfillments/count.json",
+                &[("order_id", order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -3661,19 +4406,29 @@ impl ShippingAndFulfillment {
         fulfillment_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/orders/{}/fulfillments/{}/json?{}",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/orders/{}/fulfillments/{}/json",
+                self.client.encode_path(order_id),
+                self.client.encode_path(fulfillment_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/orders/{order_id}/fulfillments/{fulfillment_id}.json",
+                &[
+                    ("order_id", order_id.to_string()),
+                    ("fulfillment_id", fulfillment_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -3696,13 +4451,23 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/orders/{}/fulfillments/{}/json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/unstable/orders/{order_id}/fulfillments/{fulfillment_id}.json",
+                    &[
+                        ("order_id", order_id.to_string()),
+                        ("fulfillment_id", fulfillment_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -3718,8 +4483,11 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = "/admin/api/unstable/fulfillments.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/unstable/fulfillments.json", &[])
+            })
     }
 
     /**
@@ -3740,12 +4508,19 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/fulfillments/{}/update_tracking.json",
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/unstable/fulfillments/{fulfillment_id}/update_tracking.json",
+                    &[("fulfillment_id", fulfillment_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -3768,13 +4543,13 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/orders/{}/fulfillments/{}/complete.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/unstable/orders/{order_id}/fulfillments/{fulfillment_id}/complete.json", &[("order_id", order_id.to_string()), ("fulfillment_id", fulfillment_id.to_string())]))
     }
 
     /**
@@ -3797,13 +4572,23 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/orders/{}/fulfillments/{}/open.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/unstable/orders/{order_id}/fulfillments/{fulfillment_id}/open.json",
+                    &[
+                        ("order_id", order_id.to_string()),
+                        ("fulfillment_id", fulfillment_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -3826,13 +4611,13 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/orders/{}/fulfillments/{}/cancel.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/unstable/orders/{order_id}/fulfillments/{fulfillment_id}/cancel.json", &[("order_id", order_id.to_string()), ("fulfillment_id", fulfillment_id.to_string())]))
     }
 
     /**
@@ -3853,12 +4638,19 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/fulfillments/{}/cancel.json",
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/unstable/fulfillments/{fulfillment_id}/cancel.json",
+                    &[("fulfillment_id", fulfillment_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -3882,11 +4674,20 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/orders/{}/fulfillments/{}/events.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/orders/{order_id}/fulfillments/{fulfillment_id}/events.json",
+                &[
+                    ("order_id", order_id.to_string()),
+                    ("fulfillment_id", fulfillment_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -3909,13 +4710,13 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/orders/{}/fulfillments/{}/events.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-01/orders/{order_id}/fulfillments/{fulfillment_id}/events.json", &[("order_id", order_id.to_string()), ("fulfillment_id", fulfillment_id.to_string())]))
     }
 
     /**
@@ -3940,12 +4741,12 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/orders/{}/fulfillments/{}/events/{}/json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
-            crate::progenitor_support::encode_path(&event_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
+            self.client.encode_path(event_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-01/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json", &[("order_id", order_id.to_string()), ("fulfillment_id", fulfillment_id.to_string()), ("event_id", event_id.to_string())]))
     }
 
     /**
@@ -3969,12 +4770,35 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/orders/{}/fulfillments/{}/events/{}/json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
-            crate::progenitor_support::encode_path(&event_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
+            self.client.encode_path(event_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/admin/api/2020-01/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json", &[("order_id", order_id.to_string()), ("fulfillment_id", fulfillment_id.to_string()), ("event_id", event_id.to_string())]))
+    }
+
+    /**
+     * Deletes a fulfillment event.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-01/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_delete_orders_param_order_fulfillments_fulfillment_events_event`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202001_delete_orders_param_order_fulfillments_fulfillment_events_event_if_exists(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-01/orders/{}/fulfillments/{}/events/{}/json",
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
+            self.client.encode_path(event_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete_if_exists(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/admin/api/2020-01/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json", &[("order_id", order_id.to_string()), ("fulfillment_id", fulfillment_id.to_string()), ("event_id", event_id.to_string())]))
     }
 
     /**
@@ -3998,11 +4822,20 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/orders/{}/fulfillments/{}/events.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/orders/{order_id}/fulfillments/{fulfillment_id}/events.json",
+                &[
+                    ("order_id", order_id.to_string()),
+                    ("fulfillment_id", fulfillment_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -4025,13 +4858,13 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/orders/{}/fulfillments/{}/events.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-04/orders/{order_id}/fulfillments/{fulfillment_id}/events.json", &[("order_id", order_id.to_string()), ("fulfillment_id", fulfillment_id.to_string())]))
     }
 
     /**
@@ -4056,12 +4889,12 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/orders/{}/fulfillments/{}/events/{}/json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
-            crate::progenitor_support::encode_path(&event_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
+            self.client.encode_path(event_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-04/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json", &[("order_id", order_id.to_string()), ("fulfillment_id", fulfillment_id.to_string()), ("event_id", event_id.to_string())]))
     }
 
     /**
@@ -4085,12 +4918,35 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/orders/{}/fulfillments/{}/events/{}/json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
-            crate::progenitor_support::encode_path(&event_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
+            self.client.encode_path(event_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/admin/api/2020-04/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json", &[("order_id", order_id.to_string()), ("fulfillment_id", fulfillment_id.to_string()), ("event_id", event_id.to_string())]))
+    }
+
+    /**
+     * Deletes a fulfillment event.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-04/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_delete_orders_param_order_fulfillments_fulfillment_events_event`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202004_delete_orders_param_order_fulfillments_fulfillment_events_event_if_exists(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-04/orders/{}/fulfillments/{}/events/{}/json",
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
+            self.client.encode_path(event_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete_if_exists(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/admin/api/2020-04/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json", &[("order_id", order_id.to_string()), ("fulfillment_id", fulfillment_id.to_string()), ("event_id", event_id.to_string())]))
     }
 
     /**
@@ -4114,11 +4970,20 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/orders/{}/fulfillments/{}/events.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/orders/{order_id}/fulfillments/{fulfillment_id}/events.json",
+                &[
+                    ("order_id", order_id.to_string()),
+                    ("fulfillment_id", fulfillment_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -4141,13 +5006,13 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/orders/{}/fulfillments/{}/events.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-07/orders/{order_id}/fulfillments/{fulfillment_id}/events.json", &[("order_id", order_id.to_string()), ("fulfillment_id", fulfillment_id.to_string())]))
     }
 
     /**
@@ -4172,12 +5037,12 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/orders/{}/fulfillments/{}/events/{}/json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
-            crate::progenitor_support::encode_path(&event_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
+            self.client.encode_path(event_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-07/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json", &[("order_id", order_id.to_string()), ("fulfillment_id", fulfillment_id.to_string()), ("event_id", event_id.to_string())]))
     }
 
     /**
@@ -4201,12 +5066,35 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/orders/{}/fulfillments/{}/events/{}/json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
-            crate::progenitor_support::encode_path(&event_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
+            self.client.encode_path(event_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/admin/api/2020-07/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json", &[("order_id", order_id.to_string()), ("fulfillment_id", fulfillment_id.to_string()), ("event_id", event_id.to_string())]))
+    }
+
+    /**
+     * Deletes a fulfillment event.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-07/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_delete_orders_param_order_fulfillments_fulfillment_events_event`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202007_delete_orders_param_order_fulfillments_fulfillment_events_event_if_exists(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-07/orders/{}/fulfillments/{}/events/{}/json",
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
+            self.client.encode_path(event_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete_if_exists(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/admin/api/2020-07/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json", &[("order_id", order_id.to_string()), ("fulfillment_id", fulfillment_id.to_string()), ("event_id", event_id.to_string())]))
     }
 
     /**
@@ -4230,11 +5118,20 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/orders/{}/fulfillments/{}/events.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/orders/{order_id}/fulfillments/{fulfillment_id}/events.json",
+                &[
+                    ("order_id", order_id.to_string()),
+                    ("fulfillment_id", fulfillment_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -4257,13 +5154,13 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/orders/{}/fulfillments/{}/events.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-10/orders/{order_id}/fulfillments/{fulfillment_id}/events.json", &[("order_id", order_id.to_string()), ("fulfillment_id", fulfillment_id.to_string())]))
     }
 
     /**
@@ -4288,12 +5185,12 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/orders/{}/fulfillments/{}/events/{}/json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
-            crate::progenitor_support::encode_path(&event_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
+            self.client.encode_path(event_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-10/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json", &[("order_id", order_id.to_string()), ("fulfillment_id", fulfillment_id.to_string()), ("event_id", event_id.to_string())]))
     }
 
     /**
@@ -4317,12 +5214,35 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/orders/{}/fulfillments/{}/events/{}/json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
-            crate::progenitor_support::encode_path(&event_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
+            self.client.encode_path(event_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/admin/api/2020-10/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json", &[("order_id", order_id.to_string()), ("fulfillment_id", fulfillment_id.to_string()), ("event_id", event_id.to_string())]))
+    }
+
+    /**
+     * Deletes a fulfillment event.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-10/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json` endpoint.
+     *
+     * As opposed to `delete_orders_param_order_fulfillments_fulfillment_events_event`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_orders_param_order_fulfillments_fulfillment_events_event_if_exists(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-10/orders/{}/fulfillments/{}/events/{}/json",
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
+            self.client.encode_path(event_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete_if_exists(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/admin/api/2020-10/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json", &[("order_id", order_id.to_string()), ("fulfillment_id", fulfillment_id.to_string()), ("event_id", event_id.to_string())]))
     }
 
     /**
@@ -4346,11 +5266,20 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/orders/{}/fulfillments/{}/events.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/orders/{order_id}/fulfillments/{fulfillment_id}/events.json",
+                &[
+                    ("order_id", order_id.to_string()),
+                    ("fulfillment_id", fulfillment_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -4373,13 +5302,13 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/orders/{}/fulfillments/{}/events.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2021-01/orders/{order_id}/fulfillments/{fulfillment_id}/events.json", &[("order_id", order_id.to_string()), ("fulfillment_id", fulfillment_id.to_string())]))
     }
 
     /**
@@ -4404,12 +5333,12 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/orders/{}/fulfillments/{}/events/{}/json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
-            crate::progenitor_support::encode_path(&event_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
+            self.client.encode_path(event_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/2021-01/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json", &[("order_id", order_id.to_string()), ("fulfillment_id", fulfillment_id.to_string()), ("event_id", event_id.to_string())]))
     }
 
     /**
@@ -4433,18 +5362,41 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/orders/{}/fulfillments/{}/events/{}/json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
-            crate::progenitor_support::encode_path(&event_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
+            self.client.encode_path(event_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/admin/api/2021-01/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json", &[("order_id", order_id.to_string()), ("fulfillment_id", fulfillment_id.to_string()), ("event_id", event_id.to_string())]))
     }
 
     /**
-     * Retrieves a list of fulfillment events for a specific fulfillment.
+     * Deletes a fulfillment event.
      *
-     * This function performs a `GET` to the `/admin/api/unstable/orders/{order_id}/fulfillments/{fulfillment_id}/events.json` endpoint.
+     * This function performs a `DELETE` to the `/admin/api/2021-01/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_delete_orders_param_order_fulfillments_fulfillment_events_event`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202101_delete_orders_param_order_fulfillments_fulfillment_events_event_if_exists(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2021-01/orders/{}/fulfillments/{}/events/{}/json",
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
+            self.client.encode_path(event_id),
+        );
+
+        self.client.delete_if_exists(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/admin/api/2021-01/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json", &[("order_id", order_id.to_string()), ("fulfillment_id", fulfillment_id.to_string()), ("event_id", event_id.to_string())]))
+    }
+
+    /**
+     * Retrieves a list of fulfillment events for a specific fulfillment.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/orders/{order_id}/fulfillments/{fulfillment_id}/events.json` endpoint.
      *
      * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentevent#index-unstable
      *
@@ -4462,11 +5414,20 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/orders/{}/fulfillments/{}/events.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/orders/{order_id}/fulfillments/{fulfillment_id}/events.json",
+                &[
+                    ("order_id", order_id.to_string()),
+                    ("fulfillment_id", fulfillment_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -4489,13 +5450,13 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/orders/{}/fulfillments/{}/events.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/unstable/orders/{order_id}/fulfillments/{fulfillment_id}/events.json", &[("order_id", order_id.to_string()), ("fulfillment_id", fulfillment_id.to_string())]))
     }
 
     /**
@@ -4520,12 +5481,12 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/orders/{}/fulfillments/{}/events/{}/json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
-            crate::progenitor_support::encode_path(&event_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
+            self.client.encode_path(event_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/unstable/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json", &[("order_id", order_id.to_string()), ("fulfillment_id", fulfillment_id.to_string()), ("event_id", event_id.to_string())]))
     }
 
     /**
@@ -4549,12 +5510,35 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/orders/{}/fulfillments/{}/events/{}/json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
-            crate::progenitor_support::encode_path(&fulfillment_id.to_string()),
-            crate::progenitor_support::encode_path(&event_id.to_string()),
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
+            self.client.encode_path(event_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/admin/api/unstable/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json", &[("order_id", order_id.to_string()), ("fulfillment_id", fulfillment_id.to_string()), ("event_id", event_id.to_string())]))
+    }
+
+    /**
+     * Deletes a fulfillment event.
+     *
+     * This function performs a `DELETE` to the `/admin/api/unstable/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_delete_orders_param_order_fulfillments_fulfillment_events_event`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_unstable_delete_orders_param_order_fulfillments_fulfillment_events_event_if_exists(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/unstable/orders/{}/fulfillments/{}/events/{}/json",
+            self.client.encode_path(order_id),
+            self.client.encode_path(fulfillment_id),
+            self.client.encode_path(event_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete_if_exists(&url, None).await.with_context(|| crate::utils::pretty_url("DELETE", "/admin/api/unstable/orders/{order_id}/fulfillments/{fulfillment_id}/events/{event_id}.json", &[("order_id", order_id.to_string()), ("fulfillment_id", fulfillment_id.to_string()), ("event_id", event_id.to_string())]))
     }
 
     /**
@@ -4575,10 +5559,16 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/orders/{}/fulfillment_orders.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            self.client.encode_path(order_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/orders/{order_id}/fulfillment_orders.json",
+                &[("order_id", order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -4598,10 +5588,16 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/fulfillment_orders/{}/json",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
+            self.client.encode_path(fulfillment_order_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}.json",
+                &[("fulfillment_order_id", fulfillment_order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -4622,12 +5618,19 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/fulfillment_orders/{}/cancel.json",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
+            self.client.encode_path(fulfillment_order_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}/cancel.json",
+                    &[("fulfillment_order_id", fulfillment_order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -4649,20 +5652,28 @@ impl ShippingAndFulfillment {
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/fulfillment_orders/{}/close.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/fulfillment_orders/{}/close.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}/close.json",
+                    &[("fulfillment_order_id", fulfillment_order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -4683,20 +5694,28 @@ impl ShippingAndFulfillment {
         new_location_id: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !new_location_id.is_empty() {
-            query_args.push(("new_location_id".to_string(), new_location_id.to_string()));
+            query_args.push(("new_location_id", new_location_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/fulfillment_orders/{}/move.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/fulfillment_orders/{}/move.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}/move.json",
+                    &[("fulfillment_order_id", fulfillment_order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -4717,10 +5736,16 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/orders/{}/fulfillment_orders.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            self.client.encode_path(order_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/orders/{order_id}/fulfillment_orders.json",
+                &[("order_id", order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -4740,10 +5765,16 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/fulfillment_orders/{}/json",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
+            self.client.encode_path(fulfillment_order_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}.json",
+                &[("fulfillment_order_id", fulfillment_order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -4764,12 +5795,19 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/fulfillment_orders/{}/cancel.json",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
+            self.client.encode_path(fulfillment_order_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}/cancel.json",
+                    &[("fulfillment_order_id", fulfillment_order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -4791,20 +5829,28 @@ impl ShippingAndFulfillment {
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/fulfillment_orders/{}/close.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/fulfillment_orders/{}/close.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}/close.json",
+                    &[("fulfillment_order_id", fulfillment_order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -4825,20 +5871,28 @@ impl ShippingAndFulfillment {
         new_location_id: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !new_location_id.is_empty() {
-            query_args.push(("new_location_id".to_string(), new_location_id.to_string()));
+            query_args.push(("new_location_id", new_location_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/fulfillment_orders/{}/move.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/fulfillment_orders/{}/move.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}/move.json",
+                    &[("fulfillment_order_id", fulfillment_order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -4859,10 +5913,16 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/orders/{}/fulfillment_orders.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            self.client.encode_path(order_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/orders/{order_id}/fulfillment_orders.json",
+                &[("order_id", order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -4882,10 +5942,16 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/fulfillment_orders/{}/json",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
+            self.client.encode_path(fulfillment_order_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}.json",
+                &[("fulfillment_order_id", fulfillment_order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -4906,12 +5972,19 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/fulfillment_orders/{}/cancel.json",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
+            self.client.encode_path(fulfillment_order_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}/cancel.json",
+                    &[("fulfillment_order_id", fulfillment_order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -4933,20 +6006,28 @@ impl ShippingAndFulfillment {
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/fulfillment_orders/{}/close.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/fulfillment_orders/{}/close.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}/close.json",
+                    &[("fulfillment_order_id", fulfillment_order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -4967,20 +6048,28 @@ impl ShippingAndFulfillment {
         new_location_id: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !new_location_id.is_empty() {
-            query_args.push(("new_location_id".to_string(), new_location_id.to_string()));
+            query_args.push(("new_location_id", new_location_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/fulfillment_orders/{}/move.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/fulfillment_orders/{}/move.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}/move.json",
+                    &[("fulfillment_order_id", fulfillment_order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -5001,10 +6090,16 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/orders/{}/fulfillment_orders.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            self.client.encode_path(order_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/orders/{order_id}/fulfillment_orders.json",
+                &[("order_id", order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -5024,10 +6119,16 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/fulfillment_orders/{}/json",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
+            self.client.encode_path(fulfillment_order_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}.json",
+                &[("fulfillment_order_id", fulfillment_order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -5048,12 +6149,19 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/fulfillment_orders/{}/cancel.json",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
+            self.client.encode_path(fulfillment_order_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/cancel.json",
+                    &[("fulfillment_order_id", fulfillment_order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -5075,20 +6183,28 @@ impl ShippingAndFulfillment {
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/fulfillment_orders/{}/close.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/fulfillment_orders/{}/close.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/close.json",
+                    &[("fulfillment_order_id", fulfillment_order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -5109,20 +6225,28 @@ impl ShippingAndFulfillment {
         new_location_id: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !new_location_id.is_empty() {
-            query_args.push(("new_location_id".to_string(), new_location_id.to_string()));
+            query_args.push(("new_location_id", new_location_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/fulfillment_orders/{}/move.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/fulfillment_orders/{}/move.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/move.json",
+                    &[("fulfillment_order_id", fulfillment_order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -5143,10 +6267,16 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/orders/{}/fulfillment_orders.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            self.client.encode_path(order_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/orders/{order_id}/fulfillment_orders.json",
+                &[("order_id", order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -5166,10 +6296,16 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/fulfillment_orders/{}/json",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
+            self.client.encode_path(fulfillment_order_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}.json",
+                &[("fulfillment_order_id", fulfillment_order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -5190,12 +6326,19 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/fulfillment_orders/{}/cancel.json",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
+            self.client.encode_path(fulfillment_order_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/cancel.json",
+                    &[("fulfillment_order_id", fulfillment_order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -5217,20 +6360,28 @@ impl ShippingAndFulfillment {
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/fulfillment_orders/{}/close.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/fulfillment_orders/{}/close.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/close.json",
+                    &[("fulfillment_order_id", fulfillment_order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -5251,20 +6402,28 @@ impl ShippingAndFulfillment {
         new_location_id: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !new_location_id.is_empty() {
-            query_args.push(("new_location_id".to_string(), new_location_id.to_string()));
+            query_args.push(("new_location_id", new_location_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/fulfillment_orders/{}/move.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/fulfillment_orders/{}/move.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/move.json",
+                    &[("fulfillment_order_id", fulfillment_order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -5286,12 +6445,19 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/fulfillment_orders/{}/open.json",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
+            self.client.encode_path(fulfillment_order_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/open.json",
+                    &[("fulfillment_order_id", fulfillment_order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -5313,12 +6479,19 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/fulfillment_orders/{}/reschedule.json",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
+            self.client.encode_path(fulfillment_order_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/reschedule.json",
+                    &[("fulfillment_order_id", fulfillment_order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -5339,10 +6512,16 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/orders/{}/fulfillment_orders.json",
-            crate::progenitor_support::encode_path(&order_id.to_string()),
+            self.client.encode_path(order_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/orders/{order_id}/fulfillment_orders.json",
+                &[("order_id", order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -5362,10 +6541,16 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/fulfillment_orders/{}/json",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
+            self.client.encode_path(fulfillment_order_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}.json",
+                &[("fulfillment_order_id", fulfillment_order_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -5386,12 +6571,19 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/fulfillment_orders/{}/cancel.json",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
+            self.client.encode_path(fulfillment_order_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/cancel.json",
+                    &[("fulfillment_order_id", fulfillment_order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -5411,19 +6603,25 @@ impl ShippingAndFulfillment {
         order_id: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !order_id.is_empty() {
-            query_args.push(("order_id".to_string(), order_id.to_string()));
+            query_args.push(("order_id", order_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/fulfillment_orders/release_hold.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/unstable/fulfillment_orders/release_hold.json",
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/unstable/fulfillment_orders/release_hold.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -5445,20 +6643,28 @@ impl ShippingAndFulfillment {
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/fulfillment_orders/{}/close.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/fulfillment_orders/{}/close.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/close.json",
+                    &[("fulfillment_order_id", fulfillment_order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -5479,20 +6685,28 @@ impl ShippingAndFulfillment {
         new_location_id: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !new_location_id.is_empty() {
-            query_args.push(("new_location_id".to_string(), new_location_id.to_string()));
+            query_args.push(("new_location_id", new_location_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/fulfillment_orders/{}/move.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/fulfillment_orders/{}/move.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/move.json",
+                    &[("fulfillment_order_id", fulfillment_order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -5514,12 +6728,19 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/fulfillment_orders/{}/open.json",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
+            self.client.encode_path(fulfillment_order_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/open.json",
+                    &[("fulfillment_order_id", fulfillment_order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -5541,12 +6762,19 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/fulfillment_orders/{}/reschedule.json",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
+            self.client.encode_path(fulfillment_order_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/reschedule.json",
+                    &[("fulfillment_order_id", fulfillment_order_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -5569,26 +6797,27 @@ impl ShippingAndFulfillment {
         fulfillment_order_line_items: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fulfillment_order_line_items.is_empty() {
             query_args.push((
-                "fulfillment_order_line_items".to_string(),
+                "fulfillment_order_line_items",
                 fulfillment_order_line_items.to_string(),
             ));
         }
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/fulfillment_orders/{}/fulfillment_request.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/fulfillment_orders/{}/fulfillment_request.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}/fulfillment_request.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
     }
 
     /**
@@ -5609,20 +6838,21 @@ impl ShippingAndFulfillment {
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/fulfillment_orders/{}/fulfillment_request/accept.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/fulfillment_orders/{}/fulfillment_request/accept.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/accept.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
     }
 
     /**
@@ -5643,20 +6873,21 @@ impl ShippingAndFulfillment {
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/fulfillment_orders/{}/fulfillment_request/reject.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/fulfillment_orders/{}/fulfillment_request/reject.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/reject.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
     }
 
     /**
@@ -5679,26 +6910,27 @@ impl ShippingAndFulfillment {
         fulfillment_order_line_items: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fulfillment_order_line_items.is_empty() {
             query_args.push((
-                "fulfillment_order_line_items".to_string(),
+                "fulfillment_order_line_items",
                 fulfillment_order_line_items.to_string(),
             ));
         }
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/fulfillment_orders/{}/fulfillment_request.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/fulfillment_orders/{}/fulfillment_request.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}/fulfillment_request.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
     }
 
     /**
@@ -5719,20 +6951,21 @@ impl ShippingAndFulfillment {
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/fulfillment_orders/{}/fulfillment_request/accept.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/fulfillment_orders/{}/fulfillment_request/accept.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/accept.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
     }
 
     /**
@@ -5753,20 +6986,21 @@ impl ShippingAndFulfillment {
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/fulfillment_orders/{}/fulfillment_request/reject.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/fulfillment_orders/{}/fulfillment_request/reject.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/reject.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
     }
 
     /**
@@ -5789,26 +7023,27 @@ impl ShippingAndFulfillment {
         fulfillment_order_line_items: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fulfillment_order_line_items.is_empty() {
             query_args.push((
-                "fulfillment_order_line_items".to_string(),
+                "fulfillment_order_line_items",
                 fulfillment_order_line_items.to_string(),
             ));
         }
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/fulfillment_orders/{}/fulfillment_request.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/fulfillment_orders/{}/fulfillment_request.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}/fulfillment_request.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
     }
 
     /**
@@ -5829,20 +7064,21 @@ impl ShippingAndFulfillment {
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/fulfillment_orders/{}/fulfillment_request/accept.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/fulfillment_orders/{}/fulfillment_request/accept.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/accept.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
     }
 
     /**
@@ -5863,20 +7099,21 @@ impl ShippingAndFulfillment {
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/fulfillment_orders/{}/fulfillment_request/reject.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/fulfillment_orders/{}/fulfillment_request/reject.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/reject.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
     }
 
     /**
@@ -5899,26 +7136,27 @@ impl ShippingAndFulfillment {
         fulfillment_order_line_items: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fulfillment_order_line_items.is_empty() {
             query_args.push((
-                "fulfillment_order_line_items".to_string(),
+                "fulfillment_order_line_items",
                 fulfillment_order_line_items.to_string(),
             ));
         }
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/fulfillment_orders/{}/fulfillment_request.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/fulfillment_orders/{}/fulfillment_request.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/fulfillment_request.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
     }
 
     /**
@@ -5939,20 +7177,21 @@ impl ShippingAndFulfillment {
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/fulfillment_orders/{}/fulfillment_request/accept.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/fulfillment_orders/{}/fulfillment_request/accept.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/accept.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
     }
 
     /**
@@ -5973,20 +7212,21 @@ impl ShippingAndFulfillment {
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/fulfillment_orders/{}/fulfillment_request/reject.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/fulfillment_orders/{}/fulfillment_request/reject.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/reject.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
     }
 
     /**
@@ -6009,26 +7249,27 @@ impl ShippingAndFulfillment {
         fulfillment_order_line_items: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fulfillment_order_line_items.is_empty() {
             query_args.push((
-                "fulfillment_order_line_items".to_string(),
+                "fulfillment_order_line_items",
                 fulfillment_order_line_items.to_string(),
             ));
         }
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/fulfillment_orders/{}/fulfillment_request.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/fulfillment_orders/{}/fulfillment_request.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/fulfillment_request.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
     }
 
     /**
@@ -6049,20 +7290,21 @@ impl ShippingAndFulfillment {
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/fulfillment_orders/{}/fulfillment_request/accept.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/fulfillment_orders/{}/fulfillment_request/accept.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/accept.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
     }
 
     /**
@@ -6083,20 +7325,21 @@ impl ShippingAndFulfillment {
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/fulfillment_orders/{}/fulfillment_request/reject.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/fulfillment_orders/{}/fulfillment_request/reject.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/reject.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
     }
 
     /**
@@ -6119,26 +7362,27 @@ impl ShippingAndFulfillment {
         fulfillment_order_line_items: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fulfillment_order_line_items.is_empty() {
             query_args.push((
-                "fulfillment_order_line_items".to_string(),
+                "fulfillment_order_line_items",
                 fulfillment_order_line_items.to_string(),
             ));
         }
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/fulfillment_orders/{}/fulfillment_request.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/fulfillment_orders/{}/fulfillment_request.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/fulfillment_request.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
     }
 
     /**
@@ -6159,20 +7403,21 @@ impl ShippingAndFulfillment {
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/fulfillment_orders/{}/fulfillment_request/accept.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/fulfillment_orders/{}/fulfillment_request/accept.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/accept.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
     }
 
     /**
@@ -6193,20 +7438,21 @@ impl ShippingAndFulfillment {
         message: &str,
         body: &serde_json::Value,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !message.is_empty() {
-            query_args.push(("message".to_string(), message.to_string()));
+            query_args.push(("message", message.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/fulfillment_orders/{}/fulfillment_request/reject.json?{}",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/fulfillment_orders/{}/fulfillment_request/reject.json",
+                self.client.encode_path(fulfillment_order_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await.with_context(|| crate::utils::pretty_url("POST", "/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/reject.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
     }
 
     /**
@@ -6226,17 +7472,19 @@ impl ShippingAndFulfillment {
         order_names: &str,
         shop: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !order_names.is_empty() {
-            query_args.push(("order_names".to_string(), order_names.to_string()));
+            query_args.push(("order_names", order_names.to_string()));
         }
         if !shop.is_empty() {
-            query_args.push(("shop".to_string(), shop.to_string()));
+            query_args.push(("shop", shop.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/fetch_tracking_numbers?{}", query_);
+        let url = crate::Client::url_with_query("/fetch_tracking_numbers", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/fetch_tracking_numbers", &[]))
     }
 
     /**
@@ -6260,23 +7508,25 @@ impl ShippingAndFulfillment {
         sku: &str,
         timestamp: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !max_retries.is_empty() {
-            query_args.push(("max_retries".to_string(), max_retries.to_string()));
+            query_args.push(("max_retries", max_retries.to_string()));
         }
         if !shop.is_empty() {
-            query_args.push(("shop".to_string(), shop.to_string()));
+            query_args.push(("shop", shop.to_string()));
         }
         if !sku.is_empty() {
-            query_args.push(("sku".to_string(), sku.to_string()));
+            query_args.push(("sku", sku.to_string()));
         }
         if !timestamp.is_empty() {
-            query_args.push(("timestamp".to_string(), timestamp.to_string()));
+            query_args.push(("timestamp", timestamp.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/fetch_stock?{}", query_);
+        let url = crate::Client::url_with_query("/fetch_stock", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/fetch_stock", &[]))
     }
 
     /**
@@ -6292,14 +7542,18 @@ impl ShippingAndFulfillment {
      *                           all: Returns all the fulfillment providers.
      */
     pub async fn deprecated_202001_get_fulfillment_service(&self, scope: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !scope.is_empty() {
-            query_args.push(("scope".to_string(), scope.to_string()));
+            query_args.push(("scope", scope.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/fulfillment_services.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-01/fulfillment_services.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/fulfillment_services.json", &[])
+        })
     }
 
     /**
@@ -6318,8 +7572,15 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = "/admin/api/2020-01/fulfillment_services.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-01/fulfillment_services.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -6339,10 +7600,16 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/fulfillment_services/{}/json",
-            crate::progenitor_support::encode_path(&fulfillment_service_id.to_string()),
+            self.client.encode_path(fulfillment_service_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/fulfillment_services/{fulfillment_service_id}.json",
+                &[("fulfillment_service_id", fulfillment_service_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -6363,12 +7630,19 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/fulfillment_services/{}/json",
-            crate::progenitor_support::encode_path(&fulfillment_service_id.to_string()),
+            self.client.encode_path(fulfillment_service_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-01/fulfillment_services/{fulfillment_service_id}.json",
+                    &[("fulfillment_service_id", fulfillment_service_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -6388,10 +7662,44 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/fulfillment_services/{}/json",
-            crate::progenitor_support::encode_path(&fulfillment_service_id.to_string()),
+            self.client.encode_path(fulfillment_service_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-01/fulfillment_services/{fulfillment_service_id}.json",
+                &[("fulfillment_service_id", fulfillment_service_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * .
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-01/fulfillment_services/{fulfillment_service_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_delete_fulfillment_services_param_service`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202001_delete_fulfillment_services_param_service_if_exists(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-01/fulfillment_services/{}/json",
+            self.client.encode_path(fulfillment_service_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-01/fulfillment_services/{fulfillment_service_id}.json",
+                    &[("fulfillment_service_id", fulfillment_service_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -6407,14 +7715,18 @@ impl ShippingAndFulfillment {
      *                           all: Returns all the fulfillment providers.
      */
     pub async fn deprecated_202004_get_fulfillment_service(&self, scope: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !scope.is_empty() {
-            query_args.push(("scope".to_string(), scope.to_string()));
+            query_args.push(("scope", scope.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/fulfillment_services.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-04/fulfillment_services.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/fulfillment_services.json", &[])
+        })
     }
 
     /**
@@ -6433,8 +7745,15 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = "/admin/api/2020-04/fulfillment_services.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-04/fulfillment_services.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -6454,10 +7773,16 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/fulfillment_services/{}/json",
-            crate::progenitor_support::encode_path(&fulfillment_service_id.to_string()),
+            self.client.encode_path(fulfillment_service_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/fulfillment_services/{fulfillment_service_id}.json",
+                &[("fulfillment_service_id", fulfillment_service_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -6478,12 +7803,19 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/fulfillment_services/{}/json",
-            crate::progenitor_support::encode_path(&fulfillment_service_id.to_string()),
+            self.client.encode_path(fulfillment_service_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-04/fulfillment_services/{fulfillment_service_id}.json",
+                    &[("fulfillment_service_id", fulfillment_service_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -6503,10 +7835,44 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/fulfillment_services/{}/json",
-            crate::progenitor_support::encode_path(&fulfillment_service_id.to_string()),
+            self.client.encode_path(fulfillment_service_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-04/fulfillment_services/{fulfillment_service_id}.json",
+                &[("fulfillment_service_id", fulfillment_service_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * .
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-04/fulfillment_services/{fulfillment_service_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_delete_fulfillment_services_param_service`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202004_delete_fulfillment_services_param_service_if_exists(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-04/fulfillment_services/{}/json",
+            self.client.encode_path(fulfillment_service_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-04/fulfillment_services/{fulfillment_service_id}.json",
+                    &[("fulfillment_service_id", fulfillment_service_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -6522,14 +7888,18 @@ impl ShippingAndFulfillment {
      *                           all: Returns all the fulfillment providers.
      */
     pub async fn deprecated_202007_get_fulfillment_service(&self, scope: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !scope.is_empty() {
-            query_args.push(("scope".to_string(), scope.to_string()));
+            query_args.push(("scope", scope.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/fulfillment_services.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-07/fulfillment_services.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/fulfillment_services.json", &[])
+        })
     }
 
     /**
@@ -6548,8 +7918,15 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = "/admin/api/2020-07/fulfillment_services.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-07/fulfillment_services.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -6569,10 +7946,16 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/fulfillment_services/{}/json",
-            crate::progenitor_support::encode_path(&fulfillment_service_id.to_string()),
+            self.client.encode_path(fulfillment_service_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/fulfillment_services/{fulfillment_service_id}.json",
+                &[("fulfillment_service_id", fulfillment_service_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -6593,12 +7976,19 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/fulfillment_services/{}/json",
-            crate::progenitor_support::encode_path(&fulfillment_service_id.to_string()),
+            self.client.encode_path(fulfillment_service_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-07/fulfillment_services/{fulfillment_service_id}.json",
+                    &[("fulfillment_service_id", fulfillment_service_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -6618,10 +8008,44 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/fulfillment_services/{}/json",
-            crate::progenitor_support::encode_path(&fulfillment_service_id.to_string()),
+            self.client.encode_path(fulfillment_service_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-07/fulfillment_services/{fulfillment_service_id}.json",
+                &[("fulfillment_service_id", fulfillment_service_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * .
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-07/fulfillment_services/{fulfillment_service_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_delete_fulfillment_services_param_service`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202007_delete_fulfillment_services_param_service_if_exists(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-07/fulfillment_services/{}/json",
+            self.client.encode_path(fulfillment_service_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-07/fulfillment_services/{fulfillment_service_id}.json",
+                    &[("fulfillment_service_id", fulfillment_service_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -6637,14 +8061,18 @@ impl ShippingAndFulfillment {
      *                           all: Returns all the fulfillment providers.
      */
     pub async fn get_fulfillment_service(&self, scope: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !scope.is_empty() {
-            query_args.push(("scope".to_string(), scope.to_string()));
+            query_args.push(("scope", scope.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/fulfillment_services.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-10/fulfillment_services.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/fulfillment_services.json", &[])
+        })
     }
 
     /**
@@ -6660,8 +8088,15 @@ impl ShippingAndFulfillment {
     pub async fn create_fulfillment_services(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-10/fulfillment_services.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2020-10/fulfillment_services.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -6681,10 +8116,16 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/fulfillment_services/{}/json",
-            crate::progenitor_support::encode_path(&fulfillment_service_id.to_string()),
+            self.client.encode_path(fulfillment_service_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/fulfillment_services/{fulfillment_service_id}.json",
+                &[("fulfillment_service_id", fulfillment_service_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -6705,12 +8146,19 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/fulfillment_services/{}/json",
-            crate::progenitor_support::encode_path(&fulfillment_service_id.to_string()),
+            self.client.encode_path(fulfillment_service_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-10/fulfillment_services/{fulfillment_service_id}.json",
+                    &[("fulfillment_service_id", fulfillment_service_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -6730,10 +8178,44 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/fulfillment_services/{}/json",
-            crate::progenitor_support::encode_path(&fulfillment_service_id.to_string()),
+            self.client.encode_path(fulfillment_service_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-10/fulfillment_services/{fulfillment_service_id}.json",
+                &[("fulfillment_service_id", fulfillment_service_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * .
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-10/fulfillment_services/{fulfillment_service_id}.json` endpoint.
+     *
+     * As opposed to `delete_fulfillment_services_param_service`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_fulfillment_services_param_service_if_exists(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-10/fulfillment_services/{}/json",
+            self.client.encode_path(fulfillment_service_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-10/fulfillment_services/{fulfillment_service_id}.json",
+                    &[("fulfillment_service_id", fulfillment_service_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -6749,14 +8231,18 @@ impl ShippingAndFulfillment {
      *                           all: Returns all the fulfillment providers.
      */
     pub async fn deprecated_202101_get_fulfillment_service(&self, scope: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !scope.is_empty() {
-            query_args.push(("scope".to_string(), scope.to_string()));
+            query_args.push(("scope", scope.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/fulfillment_services.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2021-01/fulfillment_services.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/fulfillment_services.json", &[])
+        })
     }
 
     /**
@@ -6775,8 +8261,15 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = "/admin/api/2021-01/fulfillment_services.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/2021-01/fulfillment_services.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -6796,10 +8289,16 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/fulfillment_services/{}/json",
-            crate::progenitor_support::encode_path(&fulfillment_service_id.to_string()),
+            self.client.encode_path(fulfillment_service_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/fulfillment_services/{fulfillment_service_id}.json",
+                &[("fulfillment_service_id", fulfillment_service_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -6820,12 +8319,19 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/fulfillment_services/{}/json",
-            crate::progenitor_support::encode_path(&fulfillment_service_id.to_string()),
+            self.client.encode_path(fulfillment_service_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2021-01/fulfillment_services/{fulfillment_service_id}.json",
+                    &[("fulfillment_service_id", fulfillment_service_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -6845,10 +8351,44 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/fulfillment_services/{}/json",
-            crate::progenitor_support::encode_path(&fulfillment_service_id.to_string()),
+            self.client.encode_path(fulfillment_service_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2021-01/fulfillment_services/{fulfillment_service_id}.json",
+                &[("fulfillment_service_id", fulfillment_service_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * .
+     *
+     * This function performs a `DELETE` to the `/admin/api/2021-01/fulfillment_services/{fulfillment_service_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_delete_fulfillment_services_param_service`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202101_delete_fulfillment_services_param_service_if_exists(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2021-01/fulfillment_services/{}/json",
+            self.client.encode_path(fulfillment_service_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2021-01/fulfillment_services/{fulfillment_service_id}.json",
+                    &[("fulfillment_service_id", fulfillment_service_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -6864,14 +8404,18 @@ impl ShippingAndFulfillment {
      *                           all: Returns all the fulfillment providers.
      */
     pub async fn deprecated_unstable_get_fulfillment_service(&self, scope: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !scope.is_empty() {
-            query_args.push(("scope".to_string(), scope.to_string()));
+            query_args.push(("scope", scope.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/fulfillment_services.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/unstable/fulfillment_services.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/fulfillment_services.json", &[])
+        })
     }
 
     /**
@@ -6890,8 +8434,15 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = "/admin/api/unstable/fulfillment_services.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/admin/api/unstable/fulfillment_services.json",
+                    &[],
+                )
+            })
     }
 
     /**
@@ -6911,10 +8462,16 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/fulfillment_services/{}/json",
-            crate::progenitor_support::encode_path(&fulfillment_service_id.to_string()),
+            self.client.encode_path(fulfillment_service_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/fulfillment_services/{fulfillment_service_id}.json",
+                &[("fulfillment_service_id", fulfillment_service_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -6935,12 +8492,19 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/fulfillment_services/{}/json",
-            crate::progenitor_support::encode_path(&fulfillment_service_id.to_string()),
+            self.client.encode_path(fulfillment_service_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/unstable/fulfillment_services/{fulfillment_service_id}.json",
+                    &[("fulfillment_service_id", fulfillment_service_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -6960,10 +8524,44 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/fulfillment_services/{}/json",
-            crate::progenitor_support::encode_path(&fulfillment_service_id.to_string()),
+            self.client.encode_path(fulfillment_service_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/unstable/fulfillment_services/{fulfillment_service_id}.json",
+                &[("fulfillment_service_id", fulfillment_service_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * .
+     *
+     * This function performs a `DELETE` to the `/admin/api/unstable/fulfillment_services/{fulfillment_service_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_delete_fulfillment_services_param_service`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_unstable_delete_fulfillment_services_param_service_if_exists(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/unstable/fulfillment_services/{}/json",
+            self.client.encode_path(fulfillment_service_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/unstable/fulfillment_services/{fulfillment_service_id}.json",
+                    &[("fulfillment_service_id", fulfillment_service_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -6985,10 +8583,10 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/fulfillment_orders/{}/locations_for_move.json",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
+            self.client.encode_path(fulfillment_order_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-01/fulfillment_orders/{fulfillment_order_id}/locations_for_move.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
     }
 
     /**
@@ -7010,10 +8608,10 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/fulfillment_orders/{}/locations_for_move.json",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
+            self.client.encode_path(fulfillment_order_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-04/fulfillment_orders/{fulfillment_order_id}/locations_for_move.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
     }
 
     /**
@@ -7035,10 +8633,10 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/fulfillment_orders/{}/locations_for_move.json",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
+            self.client.encode_path(fulfillment_order_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-07/fulfillment_orders/{fulfillment_order_id}/locations_for_move.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
     }
 
     /**
@@ -7060,10 +8658,10 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/fulfillment_orders/{}/locations_for_move.json",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
+            self.client.encode_path(fulfillment_order_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-10/fulfillment_orders/{fulfillment_order_id}/locations_for_move.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
     }
 
     /**
@@ -7085,10 +8683,10 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/fulfillment_orders/{}/locations_for_move.json",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
+            self.client.encode_path(fulfillment_order_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/locations_for_move.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
     }
 
     /**
@@ -7110,9 +8708,3850 @@ impl ShippingAndFulfillment {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/fulfillment_orders/{}/locations_for_move.json",
-            crate::progenitor_support::encode_path(&fulfillment_order_id.to_string()),
+            self.client.encode_path(fulfillment_order_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| crate::utils::pretty_url("GET", "/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/locations_for_move.json", &[("fulfillment_order_id", fulfillment_order_id.to_string())]))
+    }
+}
+
+/// The functions of [`ShippingAndFulfillment`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ShippingAndFulfillmentApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ShippingAndFulfillmentApi: Send + Sync {
+    async fn deprecated_202001_get_assigned_fulfillment_order(
+        &self,
+        assignment_status: &str,
+        location_ids: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_assigned_fulfillment_order(
+        &self,
+        assignment_status: &str,
+        location_ids: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_assigned_fulfillment_order(
+        &self,
+        assignment_status: &str,
+        location_ids: &str,
+    ) -> Result<()>;
+    async fn get_assigned_fulfillment_order(
+        &self,
+        assignment_status: &str,
+        location_ids: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_assigned_fulfillment_order(
+        &self,
+        assignment_status: &str,
+        location_ids: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_assigned_fulfillment_order(
+        &self,
+        assignment_status: &str,
+        location_ids: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_fulfillment_orders_param_order_cancellation_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_create_fulfillment_orders_param_order_cancellation_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_create_fulfillment_orders_param_order_cancellation_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn create_fulfillment_orders_param_order_cancellation_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn create_fulfillment_orders_param_order_cancellation_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn create_fulfillment_orders_param_order_cancellation_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_create_fulfillment_orders_param_order_cancellation_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_carrier_service(&self) -> Result<()>;
+    async fn deprecated_202001_create_carrier_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_update_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_carrier_services_param_service_if_exists(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202004_get_carrier_service(&self) -> Result<()>;
+    async fn deprecated_202004_create_carrier_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_update_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_carrier_services_param_service_if_exists(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202007_get_carrier_service(&self) -> Result<()>;
+    async fn deprecated_202007_create_carrier_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_update_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_carrier_services_param_service_if_exists(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<bool>;
+    async fn get_carrier_service(&self) -> Result<()>;
+    async fn create_carrier_services(&self, body: &serde_json::Value) -> Result<()>;
+    async fn get_carrier_services_param_service(&self, carrier_service_id: &str) -> Result<()>;
+    async fn update_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn delete_carrier_services_param_service(&self, carrier_service_id: &str) -> Result<()>;
+    async fn delete_carrier_services_param_service_if_exists(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202101_get_carrier_service(&self) -> Result<()>;
+    async fn deprecated_202101_create_carrier_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_update_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_carrier_services_param_service_if_exists(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_unstable_get_carrier_service(&self) -> Result<()>;
+    async fn deprecated_unstable_create_carrier_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_update_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_carrier_services_param_service_if_exists(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202001_get_orders_param_order_fulfillment(
+        &self,
+        order_id: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_max: &str,
+        updated_at_min: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_orders_param_order_fulfillments(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_fulfillment_orders_param_order_fulfillment(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_orders_param_order_fulfillments_count(
+        &self,
+        order_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_update_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_fulfillments(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202001_create_fulfillments_param_fulfillment_update_tracking(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_orders_param_order_fulfillments_fulfillment_complete(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_orders_param_order_fulfillments_fulfillment_open(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_orders_param_order_fulfillments_fulfillment_cancel(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_fulfillments_param_fulfillment_cancel(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_orders_param_order_fulfillment(
+        &self,
+        order_id: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_max: &str,
+        updated_at_min: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_create_orders_param_order_fulfillments(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_fulfillment_orders_param_order_fulfillment(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_orders_param_order_fulfillments_count(
+        &self,
+        order_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_update_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_create_fulfillments(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202004_create_fulfillments_param_fulfillment_update_tracking(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_create_orders_param_order_fulfillments_fulfillment_complete(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_create_orders_param_order_fulfillments_fulfillment_open(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_create_orders_param_order_fulfillments_fulfillment_cancel(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_create_fulfillments_param_fulfillment_cancel(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_orders_param_order_fulfillment(
+        &self,
+        order_id: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_max: &str,
+        updated_at_min: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_create_orders_param_order_fulfillments(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_fulfillment_orders_param_order_fulfillment(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_orders_param_order_fulfillments_count(
+        &self,
+        order_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_update_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_create_fulfillments(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202007_create_fulfillments_param_fulfillment_update_tracking(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_create_orders_param_order_fulfillments_fulfillment_complete(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_create_orders_param_order_fulfillments_fulfillment_open(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_create_orders_param_order_fulfillments_fulfillment_cancel(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_create_fulfillments_param_fulfillment_cancel(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn get_orders_param_order_fulfillment(
+        &self,
+        order_id: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_max: &str,
+        updated_at_min: &str,
+    ) -> Result<()>;
+    async fn create_orders_param_order_fulfillments(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn get_fulfillment_orders_param_order_fulfillment(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()>;
+    async fn get_orders_param_order_fulfillments_count(
+        &self,
+        order_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> Result<()>;
+    async fn get_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn update_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn create_fulfillments(&self, body: &serde_json::Value) -> Result<()>;
+    async fn create_fulfillments_param_fulfillment_update_tracking(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn create_orders_param_order_fulfillments_fulfillment_complete(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn create_orders_param_order_fulfillments_fulfillment_open(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn create_orders_param_order_fulfillments_fulfillment_cancel(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn create_fulfillments_param_fulfillment_cancel(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_orders_param_order_fulfillment(
+        &self,
+        order_id: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_max: &str,
+        updated_at_min: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_create_orders_param_order_fulfillments(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_fulfillment_orders_param_order_fulfillment(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_orders_param_order_fulfillments_count(
+        &self,
+        order_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_update_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_create_fulfillments(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202101_create_fulfillments_param_fulfillment_update_tracking(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_create_orders_param_order_fulfillments_fulfillment_complete(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_create_orders_param_order_fulfillments_fulfillment_open(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_create_orders_param_order_fulfillments_fulfillment_cancel(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_create_fulfillments_param_fulfillment_cancel(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_orders_param_order_fulfillment(
+        &self,
+        order_id: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_max: &str,
+        updated_at_min: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create_orders_param_order_fulfillments(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_fulfillment_orders_param_order_fulfillment(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_orders_param_order_fulfillments_count(
+        &self,
+        order_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_update_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create_fulfillments(&self, body: &serde_json::Value)
+        -> Result<()>;
+    async fn deprecated_unstable_create_fulfillments_param_fulfillment_update_tracking(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_complete(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_open(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_cancel(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create_fulfillments_param_fulfillment_cancel(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_orders_param_order_fulfillments_fulfillment_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_orders_param_order_fulfillments_fulfillment_events(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_orders_param_order_fulfillments_fulfillment_events_event_if_exists(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202004_get_orders_param_order_fulfillments_fulfillment_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_create_orders_param_order_fulfillments_fulfillment_events(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_orders_param_order_fulfillments_fulfillment_events_event_if_exists(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202007_get_orders_param_order_fulfillments_fulfillment_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_create_orders_param_order_fulfillments_fulfillment_events(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_orders_param_order_fulfillments_fulfillment_events_event_if_exists(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<bool>;
+    async fn get_orders_param_order_fulfillments_fulfillment_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+    ) -> Result<()>;
+    async fn create_orders_param_order_fulfillments_fulfillment_events(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn get_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()>;
+    async fn delete_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()>;
+    async fn delete_orders_param_order_fulfillments_fulfillment_events_event_if_exists(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202101_get_orders_param_order_fulfillments_fulfillment_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_create_orders_param_order_fulfillments_fulfillment_events(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_orders_param_order_fulfillments_fulfillment_events_event_if_exists(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_unstable_get_orders_param_order_fulfillments_fulfillment_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_events(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_orders_param_order_fulfillments_fulfillment_events_event_if_exists(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202001_get_orders_param_order_fulfillment_shipping_and_fulfillment(
+        &self,
+        order_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_fulfillment_orders_param_order(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_fulfillment_orders_param_order_cancel(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_fulfillment_orders_param_order_close(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_fulfillment_orders_param_order_move(
+        &self,
+        fulfillment_order_id: &str,
+        new_location_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_orders_param_order_fulfillment_shipping_and_fulfillment(
+        &self,
+        order_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_fulfillment_orders_param_order(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_create_fulfillment_orders_param_order_cancel(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_create_fulfillment_orders_param_order_close(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_create_fulfillment_orders_param_order_move(
+        &self,
+        fulfillment_order_id: &str,
+        new_location_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_orders_param_order_fulfillment_shipping_and_fulfillment(
+        &self,
+        order_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_fulfillment_orders_param_order(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_create_fulfillment_orders_param_order_cancel(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_create_fulfillment_orders_param_order_close(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_create_fulfillment_orders_param_order_move(
+        &self,
+        fulfillment_order_id: &str,
+        new_location_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn get_orders_param_order_fulfillment_shipping_and_fulfillment(
+        &self,
+        order_id: &str,
+    ) -> Result<()>;
+    async fn get_fulfillment_orders_param_order(&self, fulfillment_order_id: &str) -> Result<()>;
+    async fn create_fulfillment_orders_param_order_cancel(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn create_fulfillment_orders_param_order_close(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn create_fulfillment_orders_param_order_move(
+        &self,
+        fulfillment_order_id: &str,
+        new_location_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_orders_param_order_fulfillment_shipping_and_fulfillment(
+        &self,
+        order_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_fulfillment_orders_param_order(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_create_fulfillment_orders_param_order_cancel(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_create_fulfillment_orders_param_order_close(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_create_fulfillment_orders_param_order_move(
+        &self,
+        fulfillment_order_id: &str,
+        new_location_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_create_fulfillment_orders_param_order_open(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_create_fulfillment_orders_param_order_reschedule(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_orders_param_order_fulfillment_shipping_and_fulfillment(
+        &self,
+        order_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_fulfillment_orders_param_order(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_cancel(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create_fulfillment_orders_release_hold(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_close(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_move(
+        &self,
+        fulfillment_order_id: &str,
+        new_location_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_open(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_reschedule(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_fulfillment_orders_param_order_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        fulfillment_order_line_items: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_fulfillment_orders_param_order_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_create_fulfillment_orders_param_order_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_create_fulfillment_orders_param_order_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        fulfillment_order_line_items: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_create_fulfillment_orders_param_order_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_create_fulfillment_orders_param_order_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_create_fulfillment_orders_param_order_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        fulfillment_order_line_items: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_create_fulfillment_orders_param_order_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_create_fulfillment_orders_param_order_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn create_fulfillment_orders_param_order_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        fulfillment_order_line_items: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn create_fulfillment_orders_param_order_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn create_fulfillment_orders_param_order_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_create_fulfillment_orders_param_order_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        fulfillment_order_line_items: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_create_fulfillment_orders_param_order_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_create_fulfillment_orders_param_order_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        fulfillment_order_line_items: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unknown_version_get_fetch_tracking_number(
+        &self,
+        order_names: &str,
+        shop: &str,
+    ) -> Result<()>;
+    async fn deprecated_unknown_version_get_fetch_stock(
+        &self,
+        max_retries: &str,
+        shop: &str,
+        sku: &str,
+        timestamp: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_fulfillment_service(&self, scope: &str) -> Result<()>;
+    async fn deprecated_202001_create_fulfillment_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_update_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_fulfillment_services_param_service_if_exists(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202004_get_fulfillment_service(&self, scope: &str) -> Result<()>;
+    async fn deprecated_202004_create_fulfillment_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_update_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_fulfillment_services_param_service_if_exists(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202007_get_fulfillment_service(&self, scope: &str) -> Result<()>;
+    async fn deprecated_202007_create_fulfillment_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_update_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_fulfillment_services_param_service_if_exists(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<bool>;
+    async fn get_fulfillment_service(&self, scope: &str) -> Result<()>;
+    async fn create_fulfillment_services(&self, body: &serde_json::Value) -> Result<()>;
+    async fn get_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()>;
+    async fn update_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn delete_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()>;
+    async fn delete_fulfillment_services_param_service_if_exists(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202101_get_fulfillment_service(&self, scope: &str) -> Result<()>;
+    async fn deprecated_202101_create_fulfillment_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_update_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_fulfillment_services_param_service_if_exists(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_unstable_get_fulfillment_service(&self, scope: &str) -> Result<()>;
+    async fn deprecated_unstable_create_fulfillment_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_update_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_fulfillment_services_param_service_if_exists(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202001_get_fulfillment_orders_param_order_locations_for_move(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_fulfillment_orders_param_order_locations_for_move(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_fulfillment_orders_param_order_locations_for_move(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()>;
+    async fn get_fulfillment_orders_param_order_locations_for_move(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_fulfillment_orders_param_order_locations_for_move(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_fulfillment_orders_param_order_locations_for_move(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ShippingAndFulfillmentApi for ShippingAndFulfillment {
+    async fn deprecated_202001_get_assigned_fulfillment_order(
+        &self,
+        assignment_status: &str,
+        location_ids: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_get_assigned_fulfillment_order(
+            self,
+            assignment_status,
+            location_ids,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_assigned_fulfillment_order(
+        &self,
+        assignment_status: &str,
+        location_ids: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_get_assigned_fulfillment_order(
+            self,
+            assignment_status,
+            location_ids,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_assigned_fulfillment_order(
+        &self,
+        assignment_status: &str,
+        location_ids: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_get_assigned_fulfillment_order(
+            self,
+            assignment_status,
+            location_ids,
+        )
+        .await
+    }
+    async fn get_assigned_fulfillment_order(
+        &self,
+        assignment_status: &str,
+        location_ids: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::get_assigned_fulfillment_order(
+            self,
+            assignment_status,
+            location_ids,
+        )
+        .await
+    }
+    async fn deprecated_202101_get_assigned_fulfillment_order(
+        &self,
+        assignment_status: &str,
+        location_ids: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_get_assigned_fulfillment_order(
+            self,
+            assignment_status,
+            location_ids,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_assigned_fulfillment_order(
+        &self,
+        assignment_status: &str,
+        location_ids: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_get_assigned_fulfillment_order(
+            self,
+            assignment_status,
+            location_ids,
+        )
+        .await
+    }
+    async fn deprecated_202001_create_fulfillment_orders_param_order_cancellation_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_cancellation_request(self, fulfillment_order_id, message, body).await
+    }
+    async fn deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_accept(self, fulfillment_order_id, message, body).await
+    }
+    async fn deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_reject(self, fulfillment_order_id, message, body).await
+    }
+    async fn deprecated_202004_create_fulfillment_orders_param_order_cancellation_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_cancellation_request(self, fulfillment_order_id, message, body).await
+    }
+    async fn deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_accept(self, fulfillment_order_id, message, body).await
+    }
+    async fn deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_reject(self, fulfillment_order_id, message, body).await
+    }
+    async fn deprecated_202007_create_fulfillment_orders_param_order_cancellation_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_cancellation_request(self, fulfillment_order_id, message, body).await
+    }
+    async fn deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_accept(self, fulfillment_order_id, message, body).await
+    }
+    async fn deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_reject(self, fulfillment_order_id, message, body).await
+    }
+    async fn create_fulfillment_orders_param_order_cancellation_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::create_fulfillment_orders_param_order_cancellation_request(
+            self,
+            fulfillment_order_id,
+            message,
+            body,
+        )
+        .await
+    }
+    async fn create_fulfillment_orders_param_order_cancellation_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::create_fulfillment_orders_param_order_cancellation_request_accept(
+            self,
+            fulfillment_order_id,
+            message,
+            body,
+        )
+        .await
+    }
+    async fn create_fulfillment_orders_param_order_cancellation_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::create_fulfillment_orders_param_order_cancellation_request_reject(
+            self,
+            fulfillment_order_id,
+            message,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202101_create_fulfillment_orders_param_order_cancellation_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_cancellation_request(self, fulfillment_order_id, message, body).await
+    }
+    async fn deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_accept(self, fulfillment_order_id, message, body).await
+    }
+    async fn deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_reject(self, fulfillment_order_id, message, body).await
+    }
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request(self, fulfillment_order_id, message, body).await
+    }
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_accept(self, fulfillment_order_id, message, body).await
+    }
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_reject(self, fulfillment_order_id, message, body).await
+    }
+    async fn deprecated_202001_get_carrier_service(&self) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_get_carrier_service(self).await
+    }
+    async fn deprecated_202001_create_carrier_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_create_carrier_services(self, body).await
+    }
+    async fn deprecated_202001_get_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_get_carrier_services_param_service(
+            self,
+            carrier_service_id,
+        )
+        .await
+    }
+    async fn deprecated_202001_update_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_update_carrier_services_param_service(
+            self,
+            carrier_service_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202001_delete_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_delete_carrier_services_param_service(
+            self,
+            carrier_service_id,
+        )
+        .await
+    }
+    async fn deprecated_202001_delete_carrier_services_param_service_if_exists(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<bool> {
+        ShippingAndFulfillment::deprecated_202001_delete_carrier_services_param_service_if_exists(
+            self,
+            carrier_service_id,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_carrier_service(&self) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_get_carrier_service(self).await
+    }
+    async fn deprecated_202004_create_carrier_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_create_carrier_services(self, body).await
+    }
+    async fn deprecated_202004_get_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_get_carrier_services_param_service(
+            self,
+            carrier_service_id,
+        )
+        .await
+    }
+    async fn deprecated_202004_update_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_update_carrier_services_param_service(
+            self,
+            carrier_service_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202004_delete_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_delete_carrier_services_param_service(
+            self,
+            carrier_service_id,
+        )
+        .await
+    }
+    async fn deprecated_202004_delete_carrier_services_param_service_if_exists(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<bool> {
+        ShippingAndFulfillment::deprecated_202004_delete_carrier_services_param_service_if_exists(
+            self,
+            carrier_service_id,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_carrier_service(&self) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_get_carrier_service(self).await
+    }
+    async fn deprecated_202007_create_carrier_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_create_carrier_services(self, body).await
+    }
+    async fn deprecated_202007_get_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_get_carrier_services_param_service(
+            self,
+            carrier_service_id,
+        )
+        .await
+    }
+    async fn deprecated_202007_update_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_update_carrier_services_param_service(
+            self,
+            carrier_service_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202007_delete_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_delete_carrier_services_param_service(
+            self,
+            carrier_service_id,
+        )
+        .await
+    }
+    async fn deprecated_202007_delete_carrier_services_param_service_if_exists(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<bool> {
+        ShippingAndFulfillment::deprecated_202007_delete_carrier_services_param_service_if_exists(
+            self,
+            carrier_service_id,
+        )
+        .await
+    }
+    async fn get_carrier_service(&self) -> Result<()> {
+        ShippingAndFulfillment::get_carrier_service(self).await
+    }
+    async fn create_carrier_services(&self, body: &serde_json::Value) -> Result<()> {
+        ShippingAndFulfillment::create_carrier_services(self, body).await
+    }
+    async fn get_carrier_services_param_service(&self, carrier_service_id: &str) -> Result<()> {
+        ShippingAndFulfillment::get_carrier_services_param_service(self, carrier_service_id).await
+    }
+    async fn update_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::update_carrier_services_param_service(
+            self,
+            carrier_service_id,
+            body,
+        )
+        .await
+    }
+    async fn delete_carrier_services_param_service(&self, carrier_service_id: &str) -> Result<()> {
+        ShippingAndFulfillment::delete_carrier_services_param_service(self, carrier_service_id)
+            .await
+    }
+    async fn delete_carrier_services_param_service_if_exists(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<bool> {
+        ShippingAndFulfillment::delete_carrier_services_param_service_if_exists(
+            self,
+            carrier_service_id,
+        )
+        .await
+    }
+    async fn deprecated_202101_get_carrier_service(&self) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_get_carrier_service(self).await
+    }
+    async fn deprecated_202101_create_carrier_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_carrier_services(self, body).await
+    }
+    async fn deprecated_202101_get_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_get_carrier_services_param_service(
+            self,
+            carrier_service_id,
+        )
+        .await
+    }
+    async fn deprecated_202101_update_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_update_carrier_services_param_service(
+            self,
+            carrier_service_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202101_delete_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_delete_carrier_services_param_service(
+            self,
+            carrier_service_id,
+        )
+        .await
+    }
+    async fn deprecated_202101_delete_carrier_services_param_service_if_exists(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<bool> {
+        ShippingAndFulfillment::deprecated_202101_delete_carrier_services_param_service_if_exists(
+            self,
+            carrier_service_id,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_carrier_service(&self) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_get_carrier_service(self).await
+    }
+    async fn deprecated_unstable_create_carrier_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_carrier_services(self, body).await
+    }
+    async fn deprecated_unstable_get_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_get_carrier_services_param_service(
+            self,
+            carrier_service_id,
+        )
+        .await
+    }
+    async fn deprecated_unstable_update_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_update_carrier_services_param_service(
+            self,
+            carrier_service_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_unstable_delete_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_delete_carrier_services_param_service(
+            self,
+            carrier_service_id,
+        )
+        .await
+    }
+    async fn deprecated_unstable_delete_carrier_services_param_service_if_exists(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<bool> {
+        ShippingAndFulfillment::deprecated_unstable_delete_carrier_services_param_service_if_exists(
+            self,
+            carrier_service_id,
+        )
+        .await
+    }
+    async fn deprecated_202001_get_orders_param_order_fulfillment(
+        &self,
+        order_id: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_max: &str,
+        updated_at_min: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_get_orders_param_order_fulfillment(
+            self,
+            order_id,
+            created_at_max,
+            created_at_min,
+            fields,
+            limit,
+            since_id,
+            updated_at_max,
+            updated_at_min,
+        )
+        .await
+    }
+    async fn deprecated_202001_create_orders_param_order_fulfillments(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_create_orders_param_order_fulfillments(
+            self, order_id, body,
+        )
+        .await
+    }
+    async fn deprecated_202001_get_fulfillment_orders_param_order_fulfillment(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_get_fulfillment_orders_param_order_fulfillment(
+            self,
+            fulfillment_order_id,
+        )
+        .await
+    }
+    async fn deprecated_202001_get_orders_param_order_fulfillments_count(
+        &self,
+        order_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_get_orders_param_order_fulfillments_count(
+            self,
+            order_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+        )
+        .await
+    }
+    async fn deprecated_202001_get_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_get_orders_param_order_fulfillments_fulfillment(
+            self,
+            order_id,
+            fulfillment_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202001_update_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_update_orders_param_order_fulfillments_fulfillment(self, order_id, fulfillment_id, body).await
+    }
+    async fn deprecated_202001_create_fulfillments(&self, body: &serde_json::Value) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_create_fulfillments(self, body).await
+    }
+    async fn deprecated_202001_create_fulfillments_param_fulfillment_update_tracking(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_create_fulfillments_param_fulfillment_update_tracking(self, fulfillment_id, body).await
+    }
+    async fn deprecated_202001_create_orders_param_order_fulfillments_fulfillment_complete(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_create_orders_param_order_fulfillments_fulfillment_complete(self, order_id, fulfillment_id, body).await
+    }
+    async fn deprecated_202001_create_orders_param_order_fulfillments_fulfillment_open(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_create_orders_param_order_fulfillments_fulfillment_open(self, order_id, fulfillment_id, body).await
+    }
+    async fn deprecated_202001_create_orders_param_order_fulfillments_fulfillment_cancel(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_create_orders_param_order_fulfillments_fulfillment_cancel(self, order_id, fulfillment_id, body).await
+    }
+    async fn deprecated_202001_create_fulfillments_param_fulfillment_cancel(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_create_fulfillments_param_fulfillment_cancel(
+            self,
+            fulfillment_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_orders_param_order_fulfillment(
+        &self,
+        order_id: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_max: &str,
+        updated_at_min: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_get_orders_param_order_fulfillment(
+            self,
+            order_id,
+            created_at_max,
+            created_at_min,
+            fields,
+            limit,
+            since_id,
+            updated_at_max,
+            updated_at_min,
+        )
+        .await
+    }
+    async fn deprecated_202004_create_orders_param_order_fulfillments(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_create_orders_param_order_fulfillments(
+            self, order_id, body,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_fulfillment_orders_param_order_fulfillment(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_get_fulfillment_orders_param_order_fulfillment(
+            self,
+            fulfillment_order_id,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_orders_param_order_fulfillments_count(
+        &self,
+        order_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_get_orders_param_order_fulfillments_count(
+            self,
+            order_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_get_orders_param_order_fulfillments_fulfillment(
+            self,
+            order_id,
+            fulfillment_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202004_update_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_update_orders_param_order_fulfillments_fulfillment(self, order_id, fulfillment_id, body).await
+    }
+    async fn deprecated_202004_create_fulfillments(&self, body: &serde_json::Value) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_create_fulfillments(self, body).await
+    }
+    async fn deprecated_202004_create_fulfillments_param_fulfillment_update_tracking(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_create_fulfillments_param_fulfillment_update_tracking(self, fulfillment_id, body).await
+    }
+    async fn deprecated_202004_create_orders_param_order_fulfillments_fulfillment_complete(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_create_orders_param_order_fulfillments_fulfillment_complete(self, order_id, fulfillment_id, body).await
+    }
+    async fn deprecated_202004_create_orders_param_order_fulfillments_fulfillment_open(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_create_orders_param_order_fulfillments_fulfillment_open(self, order_id, fulfillment_id, body).await
+    }
+    async fn deprecated_202004_create_orders_param_order_fulfillments_fulfillment_cancel(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_create_orders_param_order_fulfillments_fulfillment_cancel(self, order_id, fulfillment_id, body).await
+    }
+    async fn deprecated_202004_create_fulfillments_param_fulfillment_cancel(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_create_fulfillments_param_fulfillment_cancel(
+            self,
+            fulfillment_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_orders_param_order_fulfillment(
+        &self,
+        order_id: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_max: &str,
+        updated_at_min: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_get_orders_param_order_fulfillment(
+            self,
+            order_id,
+            created_at_max,
+            created_at_min,
+            fields,
+            limit,
+            since_id,
+            updated_at_max,
+            updated_at_min,
+        )
+        .await
+    }
+    async fn deprecated_202007_create_orders_param_order_fulfillments(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_create_orders_param_order_fulfillments(
+            self, order_id, body,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_fulfillment_orders_param_order_fulfillment(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_get_fulfillment_orders_param_order_fulfillment(
+            self,
+            fulfillment_order_id,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_orders_param_order_fulfillments_count(
+        &self,
+        order_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_get_orders_param_order_fulfillments_count(
+            self,
+            order_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_get_orders_param_order_fulfillments_fulfillment(
+            self,
+            order_id,
+            fulfillment_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202007_update_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_update_orders_param_order_fulfillments_fulfillment(self, order_id, fulfillment_id, body).await
+    }
+    async fn deprecated_202007_create_fulfillments(&self, body: &serde_json::Value) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_create_fulfillments(self, body).await
+    }
+    async fn deprecated_202007_create_fulfillments_param_fulfillment_update_tracking(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_create_fulfillments_param_fulfillment_update_tracking(self, fulfillment_id, body).await
+    }
+    async fn deprecated_202007_create_orders_param_order_fulfillments_fulfillment_complete(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_create_orders_param_order_fulfillments_fulfillment_complete(self, order_id, fulfillment_id, body).await
+    }
+    async fn deprecated_202007_create_orders_param_order_fulfillments_fulfillment_open(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_create_orders_param_order_fulfillments_fulfillment_open(self, order_id, fulfillment_id, body).await
+    }
+    async fn deprecated_202007_create_orders_param_order_fulfillments_fulfillment_cancel(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_create_orders_param_order_fulfillments_fulfillment_cancel(self, order_id, fulfillment_id, body).await
+    }
+    async fn deprecated_202007_create_fulfillments_param_fulfillment_cancel(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_create_fulfillments_param_fulfillment_cancel(
+            self,
+            fulfillment_id,
+            body,
+        )
+        .await
+    }
+    async fn get_orders_param_order_fulfillment(
+        &self,
+        order_id: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_max: &str,
+        updated_at_min: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::get_orders_param_order_fulfillment(
+            self,
+            order_id,
+            created_at_max,
+            created_at_min,
+            fields,
+            limit,
+            since_id,
+            updated_at_max,
+            updated_at_min,
+        )
+        .await
+    }
+    async fn create_orders_param_order_fulfillments(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::create_orders_param_order_fulfillments(self, order_id, body).await
+    }
+    async fn get_fulfillment_orders_param_order_fulfillment(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::get_fulfillment_orders_param_order_fulfillment(
+            self,
+            fulfillment_order_id,
+        )
+        .await
+    }
+    async fn get_orders_param_order_fulfillments_count(
+        &self,
+        order_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::get_orders_param_order_fulfillments_count(
+            self,
+            order_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+        )
+        .await
+    }
+    async fn get_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::get_orders_param_order_fulfillments_fulfillment(
+            self,
+            order_id,
+            fulfillment_id,
+            fields,
+        )
+        .await
+    }
+    async fn update_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::update_orders_param_order_fulfillments_fulfillment(
+            self,
+            order_id,
+            fulfillment_id,
+            body,
+        )
+        .await
+    }
+    async fn create_fulfillments(&self, body: &serde_json::Value) -> Result<()> {
+        ShippingAndFulfillment::create_fulfillments(self, body).await
+    }
+    async fn create_fulfillments_param_fulfillment_update_tracking(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::create_fulfillments_param_fulfillment_update_tracking(
+            self,
+            fulfillment_id,
+            body,
+        )
+        .await
+    }
+    async fn create_orders_param_order_fulfillments_fulfillment_complete(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::create_orders_param_order_fulfillments_fulfillment_complete(
+            self,
+            order_id,
+            fulfillment_id,
+            body,
+        )
+        .await
+    }
+    async fn create_orders_param_order_fulfillments_fulfillment_open(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::create_orders_param_order_fulfillments_fulfillment_open(
+            self,
+            order_id,
+            fulfillment_id,
+            body,
+        )
+        .await
+    }
+    async fn create_orders_param_order_fulfillments_fulfillment_cancel(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::create_orders_param_order_fulfillments_fulfillment_cancel(
+            self,
+            order_id,
+            fulfillment_id,
+            body,
+        )
+        .await
+    }
+    async fn create_fulfillments_param_fulfillment_cancel(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::create_fulfillments_param_fulfillment_cancel(
+            self,
+            fulfillment_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202101_get_orders_param_order_fulfillment(
+        &self,
+        order_id: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_max: &str,
+        updated_at_min: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_get_orders_param_order_fulfillment(
+            self,
+            order_id,
+            created_at_max,
+            created_at_min,
+            fields,
+            limit,
+            since_id,
+            updated_at_max,
+            updated_at_min,
+        )
+        .await
+    }
+    async fn deprecated_202101_create_orders_param_order_fulfillments(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_orders_param_order_fulfillments(
+            self, order_id, body,
+        )
+        .await
+    }
+    async fn deprecated_202101_get_fulfillment_orders_param_order_fulfillment(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_get_fulfillment_orders_param_order_fulfillment(
+            self,
+            fulfillment_order_id,
+        )
+        .await
+    }
+    async fn deprecated_202101_get_orders_param_order_fulfillments_count(
+        &self,
+        order_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_get_orders_param_order_fulfillments_count(
+            self,
+            order_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+        )
+        .await
+    }
+    async fn deprecated_202101_get_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_get_orders_param_order_fulfillments_fulfillment(
+            self,
+            order_id,
+            fulfillment_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202101_update_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_update_orders_param_order_fulfillments_fulfillment(self, order_id, fulfillment_id, body).await
+    }
+    async fn deprecated_202101_create_fulfillments(&self, body: &serde_json::Value) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_fulfillments(self, body).await
+    }
+    async fn deprecated_202101_create_fulfillments_param_fulfillment_update_tracking(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_fulfillments_param_fulfillment_update_tracking(self, fulfillment_id, body).await
+    }
+    async fn deprecated_202101_create_orders_param_order_fulfillments_fulfillment_complete(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_orders_param_order_fulfillments_fulfillment_complete(self, order_id, fulfillment_id, body).await
+    }
+    async fn deprecated_202101_create_orders_param_order_fulfillments_fulfillment_open(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_orders_param_order_fulfillments_fulfillment_open(self, order_id, fulfillment_id, body).await
+    }
+    async fn deprecated_202101_create_orders_param_order_fulfillments_fulfillment_cancel(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_orders_param_order_fulfillments_fulfillment_cancel(self, order_id, fulfillment_id, body).await
+    }
+    async fn deprecated_202101_create_fulfillments_param_fulfillment_cancel(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_fulfillments_param_fulfillment_cancel(
+            self,
+            fulfillment_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_orders_param_order_fulfillment(
+        &self,
+        order_id: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_max: &str,
+        updated_at_min: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_get_orders_param_order_fulfillment(
+            self,
+            order_id,
+            created_at_max,
+            created_at_min,
+            fields,
+            limit,
+            since_id,
+            updated_at_max,
+            updated_at_min,
+        )
+        .await
+    }
+    async fn deprecated_unstable_create_orders_param_order_fulfillments(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_orders_param_order_fulfillments(
+            self, order_id, body,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_fulfillment_orders_param_order_fulfillment(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_get_fulfillment_orders_param_order_fulfillment(
+            self,
+            fulfillment_order_id,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_orders_param_order_fulfillments_count(
+        &self,
+        order_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_get_orders_param_order_fulfillments_count(
+            self,
+            order_id,
+            created_at_min,
+            created_at_max,
+            updated_at_min,
+            updated_at_max,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_get_orders_param_order_fulfillments_fulfillment(
+            self,
+            order_id,
+            fulfillment_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_unstable_update_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_update_orders_param_order_fulfillments_fulfillment(self, order_id, fulfillment_id, body).await
+    }
+    async fn deprecated_unstable_create_fulfillments(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_fulfillments(self, body).await
+    }
+    async fn deprecated_unstable_create_fulfillments_param_fulfillment_update_tracking(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_fulfillments_param_fulfillment_update_tracking(self, fulfillment_id, body).await
+    }
+    async fn deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_complete(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_complete(self, order_id, fulfillment_id, body).await
+    }
+    async fn deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_open(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_open(self, order_id, fulfillment_id, body).await
+    }
+    async fn deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_cancel(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_cancel(self, order_id, fulfillment_id, body).await
+    }
+    async fn deprecated_unstable_create_fulfillments_param_fulfillment_cancel(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_fulfillments_param_fulfillment_cancel(
+            self,
+            fulfillment_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202001_get_orders_param_order_fulfillments_fulfillment_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_get_orders_param_order_fulfillments_fulfillment_event(self, order_id, fulfillment_id).await
+    }
+    async fn deprecated_202001_create_orders_param_order_fulfillments_fulfillment_events(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_create_orders_param_order_fulfillments_fulfillment_events(self, order_id, fulfillment_id, body).await
+    }
+    async fn deprecated_202001_get_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_get_orders_param_order_fulfillments_fulfillment_events_event(self, order_id, fulfillment_id, event_id).await
+    }
+    async fn deprecated_202001_delete_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_delete_orders_param_order_fulfillments_fulfillment_events_event(self, order_id, fulfillment_id, event_id).await
+    }
+    async fn deprecated_202001_delete_orders_param_order_fulfillments_fulfillment_events_event_if_exists(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<bool> {
+        ShippingAndFulfillment::deprecated_202001_delete_orders_param_order_fulfillments_fulfillment_events_event_if_exists(self, order_id, fulfillment_id, event_id).await
+    }
+    async fn deprecated_202004_get_orders_param_order_fulfillments_fulfillment_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_get_orders_param_order_fulfillments_fulfillment_event(self, order_id, fulfillment_id).await
+    }
+    async fn deprecated_202004_create_orders_param_order_fulfillments_fulfillment_events(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_create_orders_param_order_fulfillments_fulfillment_events(self, order_id, fulfillment_id, body).await
+    }
+    async fn deprecated_202004_get_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_get_orders_param_order_fulfillments_fulfillment_events_event(self, order_id, fulfillment_id, event_id).await
+    }
+    async fn deprecated_202004_delete_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_delete_orders_param_order_fulfillments_fulfillment_events_event(self, order_id, fulfillment_id, event_id).await
+    }
+    async fn deprecated_202004_delete_orders_param_order_fulfillments_fulfillment_events_event_if_exists(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<bool> {
+        ShippingAndFulfillment::deprecated_202004_delete_orders_param_order_fulfillments_fulfillment_events_event_if_exists(self, order_id, fulfillment_id, event_id).await
+    }
+    async fn deprecated_202007_get_orders_param_order_fulfillments_fulfillment_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_get_orders_param_order_fulfillments_fulfillment_event(self, order_id, fulfillment_id).await
+    }
+    async fn deprecated_202007_create_orders_param_order_fulfillments_fulfillment_events(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_create_orders_param_order_fulfillments_fulfillment_events(self, order_id, fulfillment_id, body).await
+    }
+    async fn deprecated_202007_get_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_get_orders_param_order_fulfillments_fulfillment_events_event(self, order_id, fulfillment_id, event_id).await
+    }
+    async fn deprecated_202007_delete_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_delete_orders_param_order_fulfillments_fulfillment_events_event(self, order_id, fulfillment_id, event_id).await
+    }
+    async fn deprecated_202007_delete_orders_param_order_fulfillments_fulfillment_events_event_if_exists(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<bool> {
+        ShippingAndFulfillment::deprecated_202007_delete_orders_param_order_fulfillments_fulfillment_events_event_if_exists(self, order_id, fulfillment_id, event_id).await
+    }
+    async fn get_orders_param_order_fulfillments_fulfillment_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::get_orders_param_order_fulfillments_fulfillment_event(
+            self,
+            order_id,
+            fulfillment_id,
+        )
+        .await
+    }
+    async fn create_orders_param_order_fulfillments_fulfillment_events(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::create_orders_param_order_fulfillments_fulfillment_events(
+            self,
+            order_id,
+            fulfillment_id,
+            body,
+        )
+        .await
+    }
+    async fn get_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::get_orders_param_order_fulfillments_fulfillment_events_event(
+            self,
+            order_id,
+            fulfillment_id,
+            event_id,
+        )
+        .await
+    }
+    async fn delete_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::delete_orders_param_order_fulfillments_fulfillment_events_event(
+            self,
+            order_id,
+            fulfillment_id,
+            event_id,
+        )
+        .await
+    }
+    async fn delete_orders_param_order_fulfillments_fulfillment_events_event_if_exists(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<bool> {
+        ShippingAndFulfillment::delete_orders_param_order_fulfillments_fulfillment_events_event_if_exists(self, order_id, fulfillment_id, event_id).await
+    }
+    async fn deprecated_202101_get_orders_param_order_fulfillments_fulfillment_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_get_orders_param_order_fulfillments_fulfillment_event(self, order_id, fulfillment_id).await
+    }
+    async fn deprecated_202101_create_orders_param_order_fulfillments_fulfillment_events(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_orders_param_order_fulfillments_fulfillment_events(self, order_id, fulfillment_id, body).await
+    }
+    async fn deprecated_202101_get_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_get_orders_param_order_fulfillments_fulfillment_events_event(self, order_id, fulfillment_id, event_id).await
+    }
+    async fn deprecated_202101_delete_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_delete_orders_param_order_fulfillments_fulfillment_events_event(self, order_id, fulfillment_id, event_id).await
+    }
+    async fn deprecated_202101_delete_orders_param_order_fulfillments_fulfillment_events_event_if_exists(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<bool> {
+        ShippingAndFulfillment::deprecated_202101_delete_orders_param_order_fulfillments_fulfillment_events_event_if_exists(self, order_id, fulfillment_id, event_id).await
+    }
+    async fn deprecated_unstable_get_orders_param_order_fulfillments_fulfillment_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_get_orders_param_order_fulfillments_fulfillment_event(self, order_id, fulfillment_id).await
+    }
+    async fn deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_events(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_events(self, order_id, fulfillment_id, body).await
+    }
+    async fn deprecated_unstable_get_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_get_orders_param_order_fulfillments_fulfillment_events_event(self, order_id, fulfillment_id, event_id).await
+    }
+    async fn deprecated_unstable_delete_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_delete_orders_param_order_fulfillments_fulfillment_events_event(self, order_id, fulfillment_id, event_id).await
+    }
+    async fn deprecated_unstable_delete_orders_param_order_fulfillments_fulfillment_events_event_if_exists(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<bool> {
+        ShippingAndFulfillment::deprecated_unstable_delete_orders_param_order_fulfillments_fulfillment_events_event_if_exists(self, order_id, fulfillment_id, event_id).await
+    }
+    async fn deprecated_202001_get_orders_param_order_fulfillment_shipping_and_fulfillment(
+        &self,
+        order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_get_orders_param_order_fulfillment_shipping_and_fulfillment(self, order_id).await
+    }
+    async fn deprecated_202001_get_fulfillment_orders_param_order(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_get_fulfillment_orders_param_order(
+            self,
+            fulfillment_order_id,
+        )
+        .await
+    }
+    async fn deprecated_202001_create_fulfillment_orders_param_order_cancel(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_cancel(
+            self,
+            fulfillment_order_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202001_create_fulfillment_orders_param_order_close(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_close(
+            self,
+            fulfillment_order_id,
+            message,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202001_create_fulfillment_orders_param_order_move(
+        &self,
+        fulfillment_order_id: &str,
+        new_location_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_move(
+            self,
+            fulfillment_order_id,
+            new_location_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_orders_param_order_fulfillment_shipping_and_fulfillment(
+        &self,
+        order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_get_orders_param_order_fulfillment_shipping_and_fulfillment(self, order_id).await
+    }
+    async fn deprecated_202004_get_fulfillment_orders_param_order(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_get_fulfillment_orders_param_order(
+            self,
+            fulfillment_order_id,
+        )
+        .await
+    }
+    async fn deprecated_202004_create_fulfillment_orders_param_order_cancel(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_cancel(
+            self,
+            fulfillment_order_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202004_create_fulfillment_orders_param_order_close(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_close(
+            self,
+            fulfillment_order_id,
+            message,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202004_create_fulfillment_orders_param_order_move(
+        &self,
+        fulfillment_order_id: &str,
+        new_location_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_move(
+            self,
+            fulfillment_order_id,
+            new_location_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_orders_param_order_fulfillment_shipping_and_fulfillment(
+        &self,
+        order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_get_orders_param_order_fulfillment_shipping_and_fulfillment(self, order_id).await
+    }
+    async fn deprecated_202007_get_fulfillment_orders_param_order(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_get_fulfillment_orders_param_order(
+            self,
+            fulfillment_order_id,
+        )
+        .await
+    }
+    async fn deprecated_202007_create_fulfillment_orders_param_order_cancel(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_cancel(
+            self,
+            fulfillment_order_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202007_create_fulfillment_orders_param_order_close(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_close(
+            self,
+            fulfillment_order_id,
+            message,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202007_create_fulfillment_orders_param_order_move(
+        &self,
+        fulfillment_order_id: &str,
+        new_location_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_move(
+            self,
+            fulfillment_order_id,
+            new_location_id,
+            body,
+        )
+        .await
+    }
+    async fn get_orders_param_order_fulfillment_shipping_and_fulfillment(
+        &self,
+        order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::get_orders_param_order_fulfillment_shipping_and_fulfillment(
+            self, order_id,
+        )
+        .await
+    }
+    async fn get_fulfillment_orders_param_order(&self, fulfillment_order_id: &str) -> Result<()> {
+        ShippingAndFulfillment::get_fulfillment_orders_param_order(self, fulfillment_order_id).await
+    }
+    async fn create_fulfillment_orders_param_order_cancel(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::create_fulfillment_orders_param_order_cancel(
+            self,
+            fulfillment_order_id,
+            body,
+        )
+        .await
+    }
+    async fn create_fulfillment_orders_param_order_close(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::create_fulfillment_orders_param_order_close(
+            self,
+            fulfillment_order_id,
+            message,
+            body,
+        )
+        .await
+    }
+    async fn create_fulfillment_orders_param_order_move(
+        &self,
+        fulfillment_order_id: &str,
+        new_location_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::create_fulfillment_orders_param_order_move(
+            self,
+            fulfillment_order_id,
+            new_location_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202101_get_orders_param_order_fulfillment_shipping_and_fulfillment(
+        &self,
+        order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_get_orders_param_order_fulfillment_shipping_and_fulfillment(self, order_id).await
+    }
+    async fn deprecated_202101_get_fulfillment_orders_param_order(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_get_fulfillment_orders_param_order(
+            self,
+            fulfillment_order_id,
+        )
+        .await
+    }
+    async fn deprecated_202101_create_fulfillment_orders_param_order_cancel(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_cancel(
+            self,
+            fulfillment_order_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202101_create_fulfillment_orders_param_order_close(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_close(
+            self,
+            fulfillment_order_id,
+            message,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202101_create_fulfillment_orders_param_order_move(
+        &self,
+        fulfillment_order_id: &str,
+        new_location_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_move(
+            self,
+            fulfillment_order_id,
+            new_location_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202101_create_fulfillment_orders_param_order_open(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_open(
+            self,
+            fulfillment_order_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202101_create_fulfillment_orders_param_order_reschedule(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_reschedule(
+            self,
+            fulfillment_order_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_orders_param_order_fulfillment_shipping_and_fulfillment(
+        &self,
+        order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_get_orders_param_order_fulfillment_shipping_and_fulfillment(self, order_id).await
+    }
+    async fn deprecated_unstable_get_fulfillment_orders_param_order(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_get_fulfillment_orders_param_order(
+            self,
+            fulfillment_order_id,
+        )
+        .await
+    }
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_cancel(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_cancel(
+            self,
+            fulfillment_order_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_unstable_create_fulfillment_orders_release_hold(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_release_hold(
+            self, order_id, body,
+        )
+        .await
+    }
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_close(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_close(
+            self,
+            fulfillment_order_id,
+            message,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_move(
+        &self,
+        fulfillment_order_id: &str,
+        new_location_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_move(
+            self,
+            fulfillment_order_id,
+            new_location_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_open(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_open(
+            self,
+            fulfillment_order_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_reschedule(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_reschedule(self, fulfillment_order_id, body).await
+    }
+    async fn deprecated_202001_create_fulfillment_orders_param_order_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        fulfillment_order_line_items: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_request(
+            self,
+            fulfillment_order_id,
+            message,
+            fulfillment_order_line_items,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202001_create_fulfillment_orders_param_order_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_request_accept(self, fulfillment_order_id, message, body).await
+    }
+    async fn deprecated_202001_create_fulfillment_orders_param_order_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_request_reject(self, fulfillment_order_id, message, body).await
+    }
+    async fn deprecated_202004_create_fulfillment_orders_param_order_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        fulfillment_order_line_items: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_request(
+            self,
+            fulfillment_order_id,
+            message,
+            fulfillment_order_line_items,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202004_create_fulfillment_orders_param_order_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_request_accept(self, fulfillment_order_id, message, body).await
+    }
+    async fn deprecated_202004_create_fulfillment_orders_param_order_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_request_reject(self, fulfillment_order_id, message, body).await
+    }
+    async fn deprecated_202007_create_fulfillment_orders_param_order_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        fulfillment_order_line_items: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_request(
+            self,
+            fulfillment_order_id,
+            message,
+            fulfillment_order_line_items,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202007_create_fulfillment_orders_param_order_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_request_accept(self, fulfillment_order_id, message, body).await
+    }
+    async fn deprecated_202007_create_fulfillment_orders_param_order_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_request_reject(self, fulfillment_order_id, message, body).await
+    }
+    async fn create_fulfillment_orders_param_order_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        fulfillment_order_line_items: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::create_fulfillment_orders_param_order_request(
+            self,
+            fulfillment_order_id,
+            message,
+            fulfillment_order_line_items,
+            body,
+        )
+        .await
+    }
+    async fn create_fulfillment_orders_param_order_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::create_fulfillment_orders_param_order_request_accept(
+            self,
+            fulfillment_order_id,
+            message,
+            body,
+        )
+        .await
+    }
+    async fn create_fulfillment_orders_param_order_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::create_fulfillment_orders_param_order_request_reject(
+            self,
+            fulfillment_order_id,
+            message,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202101_create_fulfillment_orders_param_order_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        fulfillment_order_line_items: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_request(
+            self,
+            fulfillment_order_id,
+            message,
+            fulfillment_order_line_items,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202101_create_fulfillment_orders_param_order_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_request_accept(self, fulfillment_order_id, message, body).await
+    }
+    async fn deprecated_202101_create_fulfillment_orders_param_order_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_request_reject(self, fulfillment_order_id, message, body).await
+    }
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        fulfillment_order_line_items: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_request(
+            self,
+            fulfillment_order_id,
+            message,
+            fulfillment_order_line_items,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_request_accept(self, fulfillment_order_id, message, body).await
+    }
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_request_reject(self, fulfillment_order_id, message, body).await
+    }
+    async fn deprecated_unknown_version_get_fetch_tracking_number(
+        &self,
+        order_names: &str,
+        shop: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unknown_version_get_fetch_tracking_number(
+            self,
+            order_names,
+            shop,
+        )
+        .await
+    }
+    async fn deprecated_unknown_version_get_fetch_stock(
+        &self,
+        max_retries: &str,
+        shop: &str,
+        sku: &str,
+        timestamp: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unknown_version_get_fetch_stock(
+            self,
+            max_retries,
+            shop,
+            sku,
+            timestamp,
+        )
+        .await
+    }
+    async fn deprecated_202001_get_fulfillment_service(&self, scope: &str) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_get_fulfillment_service(self, scope).await
+    }
+    async fn deprecated_202001_create_fulfillment_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_create_fulfillment_services(self, body).await
+    }
+    async fn deprecated_202001_get_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_get_fulfillment_services_param_service(
+            self,
+            fulfillment_service_id,
+        )
+        .await
+    }
+    async fn deprecated_202001_update_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_update_fulfillment_services_param_service(
+            self,
+            fulfillment_service_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202001_delete_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_delete_fulfillment_services_param_service(
+            self,
+            fulfillment_service_id,
+        )
+        .await
+    }
+    async fn deprecated_202001_delete_fulfillment_services_param_service_if_exists(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<bool> {
+        ShippingAndFulfillment::deprecated_202001_delete_fulfillment_services_param_service_if_exists(self, fulfillment_service_id).await
+    }
+    async fn deprecated_202004_get_fulfillment_service(&self, scope: &str) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_get_fulfillment_service(self, scope).await
+    }
+    async fn deprecated_202004_create_fulfillment_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_create_fulfillment_services(self, body).await
+    }
+    async fn deprecated_202004_get_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_get_fulfillment_services_param_service(
+            self,
+            fulfillment_service_id,
+        )
+        .await
+    }
+    async fn deprecated_202004_update_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_update_fulfillment_services_param_service(
+            self,
+            fulfillment_service_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202004_delete_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_delete_fulfillment_services_param_service(
+            self,
+            fulfillment_service_id,
+        )
+        .await
+    }
+    async fn deprecated_202004_delete_fulfillment_services_param_service_if_exists(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<bool> {
+        ShippingAndFulfillment::deprecated_202004_delete_fulfillment_services_param_service_if_exists(self, fulfillment_service_id).await
+    }
+    async fn deprecated_202007_get_fulfillment_service(&self, scope: &str) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_get_fulfillment_service(self, scope).await
+    }
+    async fn deprecated_202007_create_fulfillment_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_create_fulfillment_services(self, body).await
+    }
+    async fn deprecated_202007_get_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_get_fulfillment_services_param_service(
+            self,
+            fulfillment_service_id,
+        )
+        .await
+    }
+    async fn deprecated_202007_update_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_update_fulfillment_services_param_service(
+            self,
+            fulfillment_service_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202007_delete_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_delete_fulfillment_services_param_service(
+            self,
+            fulfillment_service_id,
+        )
+        .await
+    }
+    async fn deprecated_202007_delete_fulfillment_services_param_service_if_exists(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<bool> {
+        ShippingAndFulfillment::deprecated_202007_delete_fulfillment_services_param_service_if_exists(self, fulfillment_service_id).await
+    }
+    async fn get_fulfillment_service(&self, scope: &str) -> Result<()> {
+        ShippingAndFulfillment::get_fulfillment_service(self, scope).await
+    }
+    async fn create_fulfillment_services(&self, body: &serde_json::Value) -> Result<()> {
+        ShippingAndFulfillment::create_fulfillment_services(self, body).await
+    }
+    async fn get_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::get_fulfillment_services_param_service(self, fulfillment_service_id)
+            .await
+    }
+    async fn update_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::update_fulfillment_services_param_service(
+            self,
+            fulfillment_service_id,
+            body,
+        )
+        .await
+    }
+    async fn delete_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::delete_fulfillment_services_param_service(
+            self,
+            fulfillment_service_id,
+        )
+        .await
+    }
+    async fn delete_fulfillment_services_param_service_if_exists(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<bool> {
+        ShippingAndFulfillment::delete_fulfillment_services_param_service_if_exists(
+            self,
+            fulfillment_service_id,
+        )
+        .await
+    }
+    async fn deprecated_202101_get_fulfillment_service(&self, scope: &str) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_get_fulfillment_service(self, scope).await
+    }
+    async fn deprecated_202101_create_fulfillment_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_fulfillment_services(self, body).await
+    }
+    async fn deprecated_202101_get_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_get_fulfillment_services_param_service(
+            self,
+            fulfillment_service_id,
+        )
+        .await
+    }
+    async fn deprecated_202101_update_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_update_fulfillment_services_param_service(
+            self,
+            fulfillment_service_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202101_delete_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_delete_fulfillment_services_param_service(
+            self,
+            fulfillment_service_id,
+        )
+        .await
+    }
+    async fn deprecated_202101_delete_fulfillment_services_param_service_if_exists(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<bool> {
+        ShippingAndFulfillment::deprecated_202101_delete_fulfillment_services_param_service_if_exists(self, fulfillment_service_id).await
+    }
+    async fn deprecated_unstable_get_fulfillment_service(&self, scope: &str) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_get_fulfillment_service(self, scope).await
+    }
+    async fn deprecated_unstable_create_fulfillment_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_fulfillment_services(self, body).await
+    }
+    async fn deprecated_unstable_get_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_get_fulfillment_services_param_service(
+            self,
+            fulfillment_service_id,
+        )
+        .await
+    }
+    async fn deprecated_unstable_update_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_update_fulfillment_services_param_service(
+            self,
+            fulfillment_service_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_unstable_delete_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_delete_fulfillment_services_param_service(
+            self,
+            fulfillment_service_id,
+        )
+        .await
+    }
+    async fn deprecated_unstable_delete_fulfillment_services_param_service_if_exists(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<bool> {
+        ShippingAndFulfillment::deprecated_unstable_delete_fulfillment_services_param_service_if_exists(self, fulfillment_service_id).await
+    }
+    async fn deprecated_202001_get_fulfillment_orders_param_order_locations_for_move(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_get_fulfillment_orders_param_order_locations_for_move(self, fulfillment_order_id).await
+    }
+    async fn deprecated_202004_get_fulfillment_orders_param_order_locations_for_move(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_get_fulfillment_orders_param_order_locations_for_move(self, fulfillment_order_id).await
+    }
+    async fn deprecated_202007_get_fulfillment_orders_param_order_locations_for_move(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_get_fulfillment_orders_param_order_locations_for_move(self, fulfillment_order_id).await
+    }
+    async fn get_fulfillment_orders_param_order_locations_for_move(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::get_fulfillment_orders_param_order_locations_for_move(
+            self,
+            fulfillment_order_id,
+        )
+        .await
+    }
+    async fn deprecated_202101_get_fulfillment_orders_param_order_locations_for_move(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_get_fulfillment_orders_param_order_locations_for_move(self, fulfillment_order_id).await
+    }
+    async fn deprecated_unstable_get_fulfillment_orders_param_order_locations_for_move(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_get_fulfillment_orders_param_order_locations_for_move(self, fulfillment_order_id).await
     }
 }
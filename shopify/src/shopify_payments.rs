@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct ShopifyPayments {
     pub client: Client,
@@ -12,6 +10,14 @@ impl ShopifyPayments {
         ShopifyPayments { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        ShopifyPayments {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieves the account's current balance.
      *
@@ -21,7 +27,13 @@ impl ShopifyPayments {
      */
     pub async fn deprecated_202001_get_balance(&self) -> Result<()> {
         let url = "/admin/api/2020-01/shopify_payments/balance.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/shopify_payments/balance.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -33,7 +45,13 @@ impl ShopifyPayments {
      */
     pub async fn deprecated_202004_get_balance(&self) -> Result<()> {
         let url = "/admin/api/2020-04/shopify_payments/balance.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/shopify_payments/balance.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -45,7 +63,13 @@ impl ShopifyPayments {
      */
     pub async fn deprecated_202007_get_balance(&self) -> Result<()> {
         let url = "/admin/api/2020-07/shopify_payments/balance.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/shopify_payments/balance.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -57,7 +81,13 @@ impl ShopifyPayments {
      */
     pub async fn get_balance(&self) -> Result<()> {
         let url = "/admin/api/2020-10/shopify_payments/balance.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/shopify_payments/balance.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -69,7 +99,13 @@ impl ShopifyPayments {
      */
     pub async fn deprecated_202101_get_balance(&self) -> Result<()> {
         let url = "/admin/api/2021-01/shopify_payments/balance.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/shopify_payments/balance.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -81,7 +117,13 @@ impl ShopifyPayments {
      */
     pub async fn deprecated_unstable_get_balance(&self) -> Result<()> {
         let url = "/admin/api/unstable/shopify_payments/balance.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/shopify_payments/balance.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -106,26 +148,31 @@ impl ShopifyPayments {
         status: &str,
         initiated_at: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !initiated_at.is_empty() {
-            query_args.push(("initiated_at".to_string(), initiated_at.to_string()));
+            query_args.push(("initiated_at", initiated_at.to_string()));
         }
         if !last_id.is_empty() {
-            query_args.push(("last_id".to_string(), last_id.to_string()));
+            query_args.push(("last_id", last_id.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/shopify_payments/disputes.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-01/shopify_payments/disputes.json",
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/shopify_payments/disputes.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -145,10 +192,16 @@ impl ShopifyPayments {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/shopify_payments/disputes/{}/json",
-            crate::progenitor_support::encode_path(&dispute_id.to_string()),
+            self.client.encode_path(dispute_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/shopify_payments/disputes/{dispute_id}.json",
+                &[("dispute_id", dispute_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -173,26 +226,31 @@ impl ShopifyPayments {
         status: &str,
         initiated_at: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !initiated_at.is_empty() {
-            query_args.push(("initiated_at".to_string(), initiated_at.to_string()));
+            query_args.push(("initiated_at", initiated_at.to_string()));
         }
         if !last_id.is_empty() {
-            query_args.push(("last_id".to_string(), last_id.to_string()));
+            query_args.push(("last_id", last_id.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/shopify_payments/disputes.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-04/shopify_payments/disputes.json",
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/shopify_payments/disputes.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -221,32 +279,37 @@ impl ShopifyPayments {
         date: &str,
         status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !date.is_empty() {
-            query_args.push(("date".to_string(), date.to_string()));
+            query_args.push(("date", date.to_string()));
         }
         if !date_max.is_empty() {
-            query_args.push(("date_max".to_string(), date_max.to_string()));
+            query_args.push(("date_max", date_max.to_string()));
         }
         if !date_min.is_empty() {
-            query_args.push(("date_min".to_string(), date_min.to_string()));
+            query_args.push(("date_min", date_min.to_string()));
         }
         if !last_id.is_empty() {
-            query_args.push(("last_id".to_string(), last_id.to_string()));
+            query_args.push(("last_id", last_id.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/shopify_payments/payouts.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-01/shopify_payments/payouts.json",
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/shopify_payments/payouts.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -263,10 +326,16 @@ impl ShopifyPayments {
     pub async fn deprecated_202001_get_payouts_param_payout(&self, payout_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/shopify_payments/payouts/{}/json",
-            crate::progenitor_support::encode_path(&payout_id.to_string()),
+            self.client.encode_path(payout_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/shopify_payments/payouts/{payout_id}.json",
+                &[("payout_id", payout_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -295,32 +364,37 @@ impl ShopifyPayments {
         date: &str,
         status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !date.is_empty() {
-            query_args.push(("date".to_string(), date.to_string()));
+            query_args.push(("date", date.to_string()));
         }
         if !date_max.is_empty() {
-            query_args.push(("date_max".to_string(), date_max.to_string()));
+            query_args.push(("date_max", date_max.to_string()));
         }
         if !date_min.is_empty() {
-            query_args.push(("date_min".to_string(), date_min.to_string()));
+            query_args.push(("date_min", date_min.to_string()));
         }
         if !last_id.is_empty() {
-            query_args.push(("last_id".to_string(), last_id.to_string()));
+            query_args.push(("last_id", last_id.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/shopify_payments/payouts.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-04/shopify_payments/payouts.json",
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/shopify_payments/payouts.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -337,10 +411,16 @@ impl ShopifyPayments {
     pub async fn deprecated_202004_get_payouts_param_payout(&self, payout_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/shopify_payments/payouts/{}/json",
-            crate::progenitor_support::encode_path(&payout_id.to_string()),
+            self.client.encode_path(payout_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/shopify_payments/payouts/{payout_id}.json",
+                &[("payout_id", payout_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -369,32 +449,37 @@ impl ShopifyPayments {
         date: &str,
         status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !date.is_empty() {
-            query_args.push(("date".to_string(), date.to_string()));
+            query_args.push(("date", date.to_string()));
         }
         if !date_max.is_empty() {
-            query_args.push(("date_max".to_string(), date_max.to_string()));
+            query_args.push(("date_max", date_max.to_string()));
         }
         if !date_min.is_empty() {
-            query_args.push(("date_min".to_string(), date_min.to_string()));
+            query_args.push(("date_min", date_min.to_string()));
         }
         if !last_id.is_empty() {
-            query_args.push(("last_id".to_string(), last_id.to_string()));
+            query_args.push(("last_id", last_id.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/shopify_payments/payouts.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-07/shopify_payments/payouts.json",
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/shopify_payments/payouts.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -411,10 +496,16 @@ impl ShopifyPayments {
     pub async fn deprecated_202007_get_payouts_param_payout(&self, payout_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/shopify_payments/payouts/{}/json",
-            crate::progenitor_support::encode_path(&payout_id.to_string()),
+            self.client.encode_path(payout_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/shopify_payments/payouts/{payout_id}.json",
+                &[("payout_id", payout_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -443,32 +534,37 @@ impl ShopifyPayments {
         date: &str,
         status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !date.is_empty() {
-            query_args.push(("date".to_string(), date.to_string()));
+            query_args.push(("date", date.to_string()));
         }
         if !date_max.is_empty() {
-            query_args.push(("date_max".to_string(), date_max.to_string()));
+            query_args.push(("date_max", date_max.to_string()));
         }
         if !date_min.is_empty() {
-            query_args.push(("date_min".to_string(), date_min.to_string()));
+            query_args.push(("date_min", date_min.to_string()));
         }
         if !last_id.is_empty() {
-            query_args.push(("last_id".to_string(), last_id.to_string()));
+            query_args.push(("last_id", last_id.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/shopify_payments/payouts.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-10/shopify_payments/payouts.json",
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/shopify_payments/payouts.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -485,10 +581,16 @@ impl ShopifyPayments {
     pub async fn get_payouts_param_payout(&self, payout_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/shopify_payments/payouts/{}/json",
-            crate::progenitor_support::encode_path(&payout_id.to_string()),
+            self.client.encode_path(payout_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/shopify_payments/payouts/{payout_id}.json",
+                &[("payout_id", payout_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -517,32 +619,37 @@ impl ShopifyPayments {
         date: &str,
         status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !date.is_empty() {
-            query_args.push(("date".to_string(), date.to_string()));
+            query_args.push(("date", date.to_string()));
         }
         if !date_max.is_empty() {
-            query_args.push(("date_max".to_string(), date_max.to_string()));
+            query_args.push(("date_max", date_max.to_string()));
         }
         if !date_min.is_empty() {
-            query_args.push(("date_min".to_string(), date_min.to_string()));
+            query_args.push(("date_min", date_min.to_string()));
         }
         if !last_id.is_empty() {
-            query_args.push(("last_id".to_string(), last_id.to_string()));
+            query_args.push(("last_id", last_id.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/shopify_payments/payouts.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/2021-01/shopify_payments/payouts.json",
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/shopify_payments/payouts.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -559,10 +666,16 @@ impl ShopifyPayments {
     pub async fn deprecated_202101_get_payouts_param_payout(&self, payout_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/shopify_payments/payouts/{}/json",
-            crate::progenitor_support::encode_path(&payout_id.to_string()),
+            self.client.encode_path(payout_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/shopify_payments/payouts/{payout_id}.json",
+                &[("payout_id", payout_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -591,32 +704,37 @@ impl ShopifyPayments {
         date: &str,
         status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !date.is_empty() {
-            query_args.push(("date".to_string(), date.to_string()));
+            query_args.push(("date", date.to_string()));
         }
         if !date_max.is_empty() {
-            query_args.push(("date_max".to_string(), date_max.to_string()));
+            query_args.push(("date_max", date_max.to_string()));
         }
         if !date_min.is_empty() {
-            query_args.push(("date_min".to_string(), date_min.to_string()));
+            query_args.push(("date_min", date_min.to_string()));
         }
         if !last_id.is_empty() {
-            query_args.push(("last_id".to_string(), last_id.to_string()));
+            query_args.push(("last_id", last_id.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/shopify_payments/payouts.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/unstable/shopify_payments/payouts.json",
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/shopify_payments/payouts.json",
+                &[],
+            )
+        })
     }
 
     /**
@@ -636,10 +754,16 @@ impl ShopifyPayments {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/shopify_payments/payouts/{}/json",
-            crate::progenitor_support::encode_path(&payout_id.to_string()),
+            self.client.encode_path(payout_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/shopify_payments/payouts/{payout_id}.json",
+                &[("payout_id", payout_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -667,28 +791,305 @@ impl ShopifyPayments {
         payout_id: &str,
         payout_status: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !last_id.is_empty() {
-            query_args.push(("last_id".to_string(), last_id.to_string()));
+            query_args.push(("last_id", last_id.to_string()));
         }
         if !payout_id.is_empty() {
-            query_args.push(("payout_id".to_string(), payout_id.to_string()));
+            query_args.push(("payout_id", payout_id.to_string()));
         }
         if !payout_status.is_empty() {
-            query_args.push(("payout_status".to_string(), payout_status.to_string()));
+            query_args.push(("payout_status", payout_status.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
         if !test.is_empty() {
-            query_args.push(("test".to_string(), test.to_string()));
+            query_args.push(("test", test.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/shopify_payments/balance/transactions.json?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-01/shopify_payments/balance/transactions.json",
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/shopify_payments/balance/transactions.json",
+                &[],
+            )
+        })
+    }
+}
+
+/// The functions of [`ShopifyPayments`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ShopifyPaymentsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ShopifyPaymentsApi: Send + Sync {
+    async fn deprecated_202001_get_balance(&self) -> Result<()>;
+    async fn deprecated_202004_get_balance(&self) -> Result<()>;
+    async fn deprecated_202007_get_balance(&self) -> Result<()>;
+    async fn get_balance(&self) -> Result<()>;
+    async fn deprecated_202101_get_balance(&self) -> Result<()>;
+    async fn deprecated_unstable_get_balance(&self) -> Result<()>;
+    async fn deprecated_202001_get_dispute(
+        &self,
+        since_id: &str,
+        last_id: &str,
+        status: &str,
+        initiated_at: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_disputes_param_dispute(&self, dispute_id: &str) -> Result<()>;
+    async fn deprecated_202004_get_dispute(
+        &self,
+        since_id: &str,
+        last_id: &str,
+        status: &str,
+        initiated_at: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_payout(
+        &self,
+        since_id: &str,
+        last_id: &str,
+        date_min: &str,
+        date_max: &str,
+        date: &str,
+        status: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_payouts_param_payout(&self, payout_id: &str) -> Result<()>;
+    async fn deprecated_202004_get_payout(
+        &self,
+        since_id: &str,
+        last_id: &str,
+        date_min: &str,
+        date_max: &str,
+        date: &str,
+        status: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_payouts_param_payout(&self, payout_id: &str) -> Result<()>;
+    async fn deprecated_202007_get_payout(
+        &self,
+        since_id: &str,
+        last_id: &str,
+        date_min: &str,
+        date_max: &str,
+        date: &str,
+        status: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_payouts_param_payout(&self, payout_id: &str) -> Result<()>;
+    async fn get_payout(
+        &self,
+        since_id: &str,
+        last_id: &str,
+        date_min: &str,
+        date_max: &str,
+        date: &str,
+        status: &str,
+    ) -> Result<()>;
+    async fn get_payouts_param_payout(&self, payout_id: &str) -> Result<()>;
+    async fn deprecated_202101_get_payout(
+        &self,
+        since_id: &str,
+        last_id: &str,
+        date_min: &str,
+        date_max: &str,
+        date: &str,
+        status: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_payouts_param_payout(&self, payout_id: &str) -> Result<()>;
+    async fn deprecated_unstable_get_payout(
+        &self,
+        since_id: &str,
+        last_id: &str,
+        date_min: &str,
+        date_max: &str,
+        date: &str,
+        status: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_payouts_param_payout(&self, payout_id: &str) -> Result<()>;
+    async fn deprecated_202001_get_balance_transaction(
+        &self,
+        since_id: &str,
+        last_id: &str,
+        test: &str,
+        payout_id: &str,
+        payout_status: &str,
+    ) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ShopifyPaymentsApi for ShopifyPayments {
+    async fn deprecated_202001_get_balance(&self) -> Result<()> {
+        ShopifyPayments::deprecated_202001_get_balance(self).await
+    }
+    async fn deprecated_202004_get_balance(&self) -> Result<()> {
+        ShopifyPayments::deprecated_202004_get_balance(self).await
+    }
+    async fn deprecated_202007_get_balance(&self) -> Result<()> {
+        ShopifyPayments::deprecated_202007_get_balance(self).await
+    }
+    async fn get_balance(&self) -> Result<()> {
+        ShopifyPayments::get_balance(self).await
+    }
+    async fn deprecated_202101_get_balance(&self) -> Result<()> {
+        ShopifyPayments::deprecated_202101_get_balance(self).await
+    }
+    async fn deprecated_unstable_get_balance(&self) -> Result<()> {
+        ShopifyPayments::deprecated_unstable_get_balance(self).await
+    }
+    async fn deprecated_202001_get_dispute(
+        &self,
+        since_id: &str,
+        last_id: &str,
+        status: &str,
+        initiated_at: &str,
+    ) -> Result<()> {
+        ShopifyPayments::deprecated_202001_get_dispute(
+            self,
+            since_id,
+            last_id,
+            status,
+            initiated_at,
+        )
+        .await
+    }
+    async fn deprecated_202001_get_disputes_param_dispute(&self, dispute_id: &str) -> Result<()> {
+        ShopifyPayments::deprecated_202001_get_disputes_param_dispute(self, dispute_id).await
+    }
+    async fn deprecated_202004_get_dispute(
+        &self,
+        since_id: &str,
+        last_id: &str,
+        status: &str,
+        initiated_at: &str,
+    ) -> Result<()> {
+        ShopifyPayments::deprecated_202004_get_dispute(
+            self,
+            since_id,
+            last_id,
+            status,
+            initiated_at,
+        )
+        .await
+    }
+    async fn deprecated_202001_get_payout(
+        &self,
+        since_id: &str,
+        last_id: &str,
+        date_min: &str,
+        date_max: &str,
+        date: &str,
+        status: &str,
+    ) -> Result<()> {
+        ShopifyPayments::deprecated_202001_get_payout(
+            self, since_id, last_id, date_min, date_max, date, status,
+        )
+        .await
+    }
+    async fn deprecated_202001_get_payouts_param_payout(&self, payout_id: &str) -> Result<()> {
+        ShopifyPayments::deprecated_202001_get_payouts_param_payout(self, payout_id).await
+    }
+    async fn deprecated_202004_get_payout(
+        &self,
+        since_id: &str,
+        last_id: &str,
+        date_min: &str,
+        date_max: &str,
+        date: &str,
+        status: &str,
+    ) -> Result<()> {
+        ShopifyPayments::deprecated_202004_get_payout(
+            self, since_id, last_id, date_min, date_max, date, status,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_payouts_param_payout(&self, payout_id: &str) -> Result<()> {
+        ShopifyPayments::deprecated_202004_get_payouts_param_payout(self, payout_id).await
+    }
+    async fn deprecated_202007_get_payout(
+        &self,
+        since_id: &str,
+        last_id: &str,
+        date_min: &str,
+        date_max: &str,
+        date: &str,
+        status: &str,
+    ) -> Result<()> {
+        ShopifyPayments::deprecated_202007_get_payout(
+            self, since_id, last_id, date_min, date_max, date, status,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_payouts_param_payout(&self, payout_id: &str) -> Result<()> {
+        ShopifyPayments::deprecated_202007_get_payouts_param_payout(self, payout_id).await
+    }
+    async fn get_payout(
+        &self,
+        since_id: &str,
+        last_id: &str,
+        date_min: &str,
+        date_max: &str,
+        date: &str,
+        status: &str,
+    ) -> Result<()> {
+        ShopifyPayments::get_payout(self, since_id, last_id, date_min, date_max, date, status).await
+    }
+    async fn get_payouts_param_payout(&self, payout_id: &str) -> Result<()> {
+        ShopifyPayments::get_payouts_param_payout(self, payout_id).await
+    }
+    async fn deprecated_202101_get_payout(
+        &self,
+        since_id: &str,
+        last_id: &str,
+        date_min: &str,
+        date_max: &str,
+        date: &str,
+        status: &str,
+    ) -> Result<()> {
+        ShopifyPayments::deprecated_202101_get_payout(
+            self, since_id, last_id, date_min, date_max, date, status,
+        )
+        .await
+    }
+    async fn deprecated_202101_get_payouts_param_payout(&self, payout_id: &str) -> Result<()> {
+        ShopifyPayments::deprecated_202101_get_payouts_param_payout(self, payout_id).await
+    }
+    async fn deprecated_unstable_get_payout(
+        &self,
+        since_id: &str,
+        last_id: &str,
+        date_min: &str,
+        date_max: &str,
+        date: &str,
+        status: &str,
+    ) -> Result<()> {
+        ShopifyPayments::deprecated_unstable_get_payout(
+            self, since_id, last_id, date_min, date_max, date, status,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_payouts_param_payout(&self, payout_id: &str) -> Result<()> {
+        ShopifyPayments::deprecated_unstable_get_payouts_param_payout(self, payout_id).await
+    }
+    async fn deprecated_202001_get_balance_transaction(
+        &self,
+        since_id: &str,
+        last_id: &str,
+        test: &str,
+        payout_id: &str,
+        payout_status: &str,
+    ) -> Result<()> {
+        ShopifyPayments::deprecated_202001_get_balance_transaction(
+            self,
+            since_id,
+            last_id,
+            test,
+            payout_id,
+            payout_status,
+        )
+        .await
     }
 }
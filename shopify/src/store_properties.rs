@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct StoreProperties {
     pub client: Client,
@@ -12,6 +10,14 @@ impl StoreProperties {
         StoreProperties { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        StoreProperties {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieves a list of countries.
      *
@@ -25,17 +31,18 @@ impl StoreProperties {
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
     pub async fn deprecated_202001_get_countrie(&self, since_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/countries.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-01/countries.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/countries.json", &[])
+        })
     }
 
     /**
@@ -51,8 +58,11 @@ impl StoreProperties {
     pub async fn deprecated_202001_create_countries(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-01/countries.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-01/countries.json", &[])
+            })
     }
 
     /**
@@ -64,7 +74,9 @@ impl StoreProperties {
      */
     pub async fn deprecated_202001_get_countries_count(&self) -> Result<()> {
         let url = "/admin/api/2020-01/countries/count.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/countries/count.json", &[])
+        })
     }
 
     /**
@@ -84,18 +96,25 @@ impl StoreProperties {
         country_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/countries/{}/json?{}",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/countries/{}/json",
+                self.client.encode_path(country_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/countries/{country_id}.json",
+                &[("country_id", country_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -119,12 +138,19 @@ impl StoreProperties {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/countries/{}/json",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
+            self.client.encode_path(country_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-01/countries/{country_id}.json",
+                    &[("country_id", country_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -144,10 +170,44 @@ impl StoreProperties {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/countries/{}/json",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
+            self.client.encode_path(country_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-01/countries/{country_id}.json",
+                &[("country_id", country_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a country.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-01/countries/{country_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202001_delete_countries_param_country`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202001_delete_countries_param_country_if_exists(
+        &self,
+        country_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-01/countries/{}/json",
+            self.client.encode_path(country_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-01/countries/{country_id}.json",
+                    &[("country_id", country_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -163,17 +223,18 @@ impl StoreProperties {
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
     pub async fn deprecated_202004_get_countrie(&self, since_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/countries.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-04/countries.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/countries.json", &[])
+        })
     }
 
     /**
@@ -189,8 +250,11 @@ impl StoreProperties {
     pub async fn deprecated_202004_create_countries(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-04/countries.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-04/countries.json", &[])
+            })
     }
 
     /**
@@ -202,7 +266,9 @@ impl StoreProperties {
      */
     pub async fn deprecated_202004_get_countries_count(&self) -> Result<()> {
         let url = "/admin/api/2020-04/countries/count.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/countries/count.json", &[])
+        })
     }
 
     /**
@@ -222,18 +288,25 @@ impl StoreProperties {
         country_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/countries/{}/json?{}",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/countries/{}/json",
+                self.client.encode_path(country_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/countries/{country_id}.json",
+                &[("country_id", country_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -257,12 +330,19 @@ impl StoreProperties {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/countries/{}/json",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
+            self.client.encode_path(country_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-04/countries/{country_id}.json",
+                    &[("country_id", country_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -282,10 +362,44 @@ impl StoreProperties {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/countries/{}/json",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
+            self.client.encode_path(country_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-04/countries/{country_id}.json",
+                &[("country_id", country_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a country.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-04/countries/{country_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202004_delete_countries_param_country`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202004_delete_countries_param_country_if_exists(
+        &self,
+        country_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-04/countries/{}/json",
+            self.client.encode_path(country_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-04/countries/{country_id}.json",
+                    &[("country_id", country_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -301,17 +415,18 @@ impl StoreProperties {
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
     pub async fn deprecated_202007_get_countrie(&self, since_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/countries.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-07/countries.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/countries.json", &[])
+        })
     }
 
     /**
@@ -327,8 +442,11 @@ impl StoreProperties {
     pub async fn deprecated_202007_create_countries(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-07/countries.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-07/countries.json", &[])
+            })
     }
 
     /**
@@ -340,7 +458,9 @@ impl StoreProperties {
      */
     pub async fn deprecated_202007_get_countries_count(&self) -> Result<()> {
         let url = "/admin/api/2020-07/countries/count.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/countries/count.json", &[])
+        })
     }
 
     /**
@@ -360,18 +480,25 @@ impl StoreProperties {
         country_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/countries/{}/json?{}",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/countries/{}/json",
+                self.client.encode_path(country_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/countries/{country_id}.json",
+                &[("country_id", country_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -395,12 +522,19 @@ impl StoreProperties {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/countries/{}/json",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
+            self.client.encode_path(country_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-07/countries/{country_id}.json",
+                    &[("country_id", country_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -420,10 +554,44 @@ impl StoreProperties {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/countries/{}/json",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
+            self.client.encode_path(country_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-07/countries/{country_id}.json",
+                &[("country_id", country_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a country.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-07/countries/{country_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202007_delete_countries_param_country`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202007_delete_countries_param_country_if_exists(
+        &self,
+        country_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-07/countries/{}/json",
+            self.client.encode_path(country_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-07/countries/{country_id}.json",
+                    &[("country_id", country_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -439,17 +607,18 @@ impl StoreProperties {
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
     pub async fn get_countrie(&self, since_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/countries.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-10/countries.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/countries.json", &[])
+        })
     }
 
     /**
@@ -465,8 +634,11 @@ impl StoreProperties {
     pub async fn create_countries(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2020-10/countries.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2020-10/countries.json", &[])
+            })
     }
 
     /**
@@ -478,7 +650,9 @@ impl StoreProperties {
      */
     pub async fn get_countries_count(&self) -> Result<()> {
         let url = "/admin/api/2020-10/countries/count.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/countries/count.json", &[])
+        })
     }
 
     /**
@@ -494,18 +668,25 @@ impl StoreProperties {
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
     pub async fn get_countries_param_country(&self, country_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/countries/{}/json?{}",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/countries/{}/json",
+                self.client.encode_path(country_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/countries/{country_id}.json",
+                &[("country_id", country_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -529,12 +710,19 @@ impl StoreProperties {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/countries/{}/json",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
+            self.client.encode_path(country_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-10/countries/{country_id}.json",
+                    &[("country_id", country_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -551,10 +739,41 @@ impl StoreProperties {
     pub async fn delete_countries_param_country(&self, country_id: &str) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/countries/{}/json",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
+            self.client.encode_path(country_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2020-10/countries/{country_id}.json",
+                &[("country_id", country_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a country.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2020-10/countries/{country_id}.json` endpoint.
+     *
+     * As opposed to `delete_countries_param_country`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_countries_param_country_if_exists(&self, country_id: &str) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2020-10/countries/{}/json",
+            self.client.encode_path(country_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2020-10/countries/{country_id}.json",
+                    &[("country_id", country_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -570,17 +789,18 @@ impl StoreProperties {
      * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
      */
     pub async fn deprecated_202101_get_countrie(&self, since_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/countries.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2021-01/countries.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/countries.json", &[])
+        })
     }
 
     /**
@@ -596,8 +816,11 @@ impl StoreProperties {
     pub async fn deprecated_202101_create_countries(&self, body: &serde_json::Value) -> Result<()> {
         let url = "/admin/api/2021-01/countries.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/2021-01/countries.json", &[])
+            })
     }
 
     /**
@@ -609,7 +832,9 @@ impl StoreProperties {
      */
     pub async fn deprecated_202101_get_countries_count(&self) -> Result<()> {
         let url = "/admin/api/2021-01/countries/count.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/countries/count.json", &[])
+        })
     }
 
     /**
@@ -629,18 +854,25 @@ impl StoreProperties {
         country_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/countries/{}/json?{}",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/countries/{}/json",
+                self.client.encode_path(country_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/countries/{country_id}.json",
+                &[("country_id", country_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -664,12 +896,19 @@ impl StoreProperties {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/countries/{}/json",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
+            self.client.encode_path(country_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2021-01/countries/{country_id}.json",
+                    &[("country_id", country_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -689,10 +928,44 @@ impl StoreProperties {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/countries/{}/json",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
+            self.client.encode_path(country_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/2021-01/countries/{country_id}.json",
+                &[("country_id", country_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a country.
+     *
+     * This function performs a `DELETE` to the `/admin/api/2021-01/countries/{country_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_202101_delete_countries_param_country`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_202101_delete_countries_param_country_if_exists(
+        &self,
+        country_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/2021-01/countries/{}/json",
+            self.client.encode_path(country_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/2021-01/countries/{country_id}.json",
+                    &[("country_id", country_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -712,17 +985,18 @@ impl StoreProperties {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/countries.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/unstable/countries.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/countries.json", &[])
+        })
     }
 
     /**
@@ -741,8 +1015,11 @@ impl StoreProperties {
     ) -> Result<()> {
         let url = "/admin/api/unstable/countries.json".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("POST", "/admin/api/unstable/countries.json", &[])
+            })
     }
 
     /**
@@ -754,7 +1031,9 @@ impl StoreProperties {
      */
     pub async fn deprecated_unstable_get_countries_count(&self) -> Result<()> {
         let url = "/admin/api/unstable/countries/count.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/countries/count.json", &[])
+        })
     }
 
     /**
@@ -774,18 +1053,25 @@ impl StoreProperties {
         country_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/countries/{}/json?{}",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/countries/{}/json",
+                self.client.encode_path(country_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/countries/{country_id}.json",
+                &[("country_id", country_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -809,12 +1095,19 @@ impl StoreProperties {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/countries/{}/json",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
+            self.client.encode_path(country_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/unstable/countries/{country_id}.json",
+                    &[("country_id", country_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -834,10 +1127,44 @@ impl StoreProperties {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/countries/{}/json",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
+            self.client.encode_path(country_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/admin/api/unstable/countries/{country_id}.json",
+                &[("country_id", country_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Deletes a country.
+     *
+     * This function performs a `DELETE` to the `/admin/api/unstable/countries/{country_id}.json` endpoint.
+     *
+     * As opposed to `deprecated_unstable_delete_countries_param_country`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn deprecated_unstable_delete_countries_param_country_if_exists(
+        &self,
+        country_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/admin/api/unstable/countries/{}/json",
+            self.client.encode_path(country_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/admin/api/unstable/countries/{country_id}.json",
+                    &[("country_id", country_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -849,7 +1176,9 @@ impl StoreProperties {
      */
     pub async fn deprecated_202001_get_currencie(&self) -> Result<()> {
         let url = "/admin/api/2020-01/currencies.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/currencies.json", &[])
+        })
     }
 
     /**
@@ -861,7 +1190,9 @@ impl StoreProperties {
      */
     pub async fn deprecated_202004_get_currencie(&self) -> Result<()> {
         let url = "/admin/api/2020-04/currencies.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/currencies.json", &[])
+        })
     }
 
     /**
@@ -873,7 +1204,9 @@ impl StoreProperties {
      */
     pub async fn deprecated_202007_get_currencie(&self) -> Result<()> {
         let url = "/admin/api/2020-07/currencies.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/currencies.json", &[])
+        })
     }
 
     /**
@@ -885,7 +1218,9 @@ impl StoreProperties {
      */
     pub async fn get_currencie(&self) -> Result<()> {
         let url = "/admin/api/2020-10/currencies.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/currencies.json", &[])
+        })
     }
 
     /**
@@ -897,7 +1232,9 @@ impl StoreProperties {
      */
     pub async fn deprecated_202101_get_currencie(&self) -> Result<()> {
         let url = "/admin/api/2021-01/currencies.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/currencies.json", &[])
+        })
     }
 
     /**
@@ -909,7 +1246,9 @@ impl StoreProperties {
      */
     pub async fn deprecated_unstable_get_currencie(&self) -> Result<()> {
         let url = "/admin/api/unstable/currencies.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/currencies.json", &[])
+        })
     }
 
     /**
@@ -921,7 +1260,9 @@ impl StoreProperties {
      */
     pub async fn deprecated_202001_get_policie(&self) -> Result<()> {
         let url = "/admin/api/2020-01/policies.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/policies.json", &[])
+        })
     }
 
     /**
@@ -933,7 +1274,9 @@ impl StoreProperties {
      */
     pub async fn deprecated_202004_get_policie(&self) -> Result<()> {
         let url = "/admin/api/2020-04/policies.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/policies.json", &[])
+        })
     }
 
     /**
@@ -945,7 +1288,9 @@ impl StoreProperties {
      */
     pub async fn deprecated_202007_get_policie(&self) -> Result<()> {
         let url = "/admin/api/2020-07/policies.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/policies.json", &[])
+        })
     }
 
     /**
@@ -957,7 +1302,9 @@ impl StoreProperties {
      */
     pub async fn get_policie(&self) -> Result<()> {
         let url = "/admin/api/2020-10/policies.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/policies.json", &[])
+        })
     }
 
     /**
@@ -969,7 +1316,9 @@ impl StoreProperties {
      */
     pub async fn deprecated_202101_get_policie(&self) -> Result<()> {
         let url = "/admin/api/2021-01/policies.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/policies.json", &[])
+        })
     }
 
     /**
@@ -981,7 +1330,9 @@ impl StoreProperties {
      */
     pub async fn deprecated_unstable_get_policie(&self) -> Result<()> {
         let url = "/admin/api/unstable/policies.json".to_string();
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/policies.json", &[])
+        })
     }
 
     /**
@@ -1003,21 +1354,28 @@ impl StoreProperties {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/countries/{}/provinces.json?{}",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/countries/{}/provinces.json",
+                self.client.encode_path(country_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/countries/{country_id}/provinces.json",
+                &[("country_id", country_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1037,10 +1395,16 @@ impl StoreProperties {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/countries/{}/provinces/count.json",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
+            self.client.encode_path(country_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/countries/{country_id}/provinces/count.json",
+                &[("country_id", country_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1062,19 +1426,29 @@ impl StoreProperties {
         province_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-01/countries/{}/provinces/{}/json?{}",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
-            crate::progenitor_support::encode_path(&province_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-01/countries/{}/provinces/{}/json",
+                self.client.encode_path(country_id),
+                self.client.encode_path(province_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-01/countries/{country_id}/provinces/{province_id}.json",
+                &[
+                    ("country_id", country_id.to_string()),
+                    ("province_id", province_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1100,13 +1474,23 @@ impl StoreProperties {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-01/countries/{}/provinces/{}/json",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
-            crate::progenitor_support::encode_path(&province_id.to_string()),
+            self.client.encode_path(country_id),
+            self.client.encode_path(province_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-01/countries/{country_id}/provinces/{province_id}.json",
+                    &[
+                        ("country_id", country_id.to_string()),
+                        ("province_id", province_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1128,21 +1512,28 @@ impl StoreProperties {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/countries/{}/provinces.json?{}",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/countries/{}/provinces.json",
+                self.client.encode_path(country_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/countries/{country_id}/provinces.json",
+                &[("country_id", country_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1162,10 +1553,16 @@ impl StoreProperties {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/countries/{}/provinces/count.json",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
+            self.client.encode_path(country_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/countries/{country_id}/provinces/count.json",
+                &[("country_id", country_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1187,19 +1584,29 @@ impl StoreProperties {
         province_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-04/countries/{}/provinces/{}/json?{}",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
-            crate::progenitor_support::encode_path(&province_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-04/countries/{}/provinces/{}/json",
+                self.client.encode_path(country_id),
+                self.client.encode_path(province_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-04/countries/{country_id}/provinces/{province_id}.json",
+                &[
+                    ("country_id", country_id.to_string()),
+                    ("province_id", province_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1225,13 +1632,23 @@ impl StoreProperties {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-04/countries/{}/provinces/{}/json",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
-            crate::progenitor_support::encode_path(&province_id.to_string()),
+            self.client.encode_path(country_id),
+            self.client.encode_path(province_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-04/countries/{country_id}/provinces/{province_id}.json",
+                    &[
+                        ("country_id", country_id.to_string()),
+                        ("province_id", province_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1253,21 +1670,28 @@ impl StoreProperties {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/countries/{}/provinces.json?{}",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/countries/{}/provinces.json",
+                self.client.encode_path(country_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/countries/{country_id}/provinces.json",
+                &[("country_id", country_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1287,10 +1711,16 @@ impl StoreProperties {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/countries/{}/provinces/count.json",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
+            self.client.encode_path(country_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/countries/{country_id}/provinces/count.json",
+                &[("country_id", country_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1312,19 +1742,29 @@ impl StoreProperties {
         province_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-07/countries/{}/provinces/{}/json?{}",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
-            crate::progenitor_support::encode_path(&province_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-07/countries/{}/provinces/{}/json",
+                self.client.encode_path(country_id),
+                self.client.encode_path(province_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-07/countries/{country_id}/provinces/{province_id}.json",
+                &[
+                    ("country_id", country_id.to_string()),
+                    ("province_id", province_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1350,13 +1790,23 @@ impl StoreProperties {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-07/countries/{}/provinces/{}/json",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
-            crate::progenitor_support::encode_path(&province_id.to_string()),
+            self.client.encode_path(country_id),
+            self.client.encode_path(province_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-07/countries/{country_id}/provinces/{province_id}.json",
+                    &[
+                        ("country_id", country_id.to_string()),
+                        ("province_id", province_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1378,21 +1828,28 @@ impl StoreProperties {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/countries/{}/provinces.json?{}",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/countries/{}/provinces.json",
+                self.client.encode_path(country_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/countries/{country_id}/provinces.json",
+                &[("country_id", country_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1412,10 +1869,16 @@ impl StoreProperties {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/countries/{}/provinces/count.json",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
+            self.client.encode_path(country_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/countries/{country_id}/provinces/count.json",
+                &[("country_id", country_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1437,19 +1900,29 @@ impl StoreProperties {
         province_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2020-10/countries/{}/provinces/{}/json?{}",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
-            crate::progenitor_support::encode_path(&province_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2020-10/countries/{}/provinces/{}/json",
+                self.client.encode_path(country_id),
+                self.client.encode_path(province_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2020-10/countries/{country_id}/provinces/{province_id}.json",
+                &[
+                    ("country_id", country_id.to_string()),
+                    ("province_id", province_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1475,13 +1948,23 @@ impl StoreProperties {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2020-10/countries/{}/provinces/{}/json",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
-            crate::progenitor_support::encode_path(&province_id.to_string()),
+            self.client.encode_path(country_id),
+            self.client.encode_path(province_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2020-10/countries/{country_id}/provinces/{province_id}.json",
+                    &[
+                        ("country_id", country_id.to_string()),
+                        ("province_id", province_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1503,21 +1986,28 @@ impl StoreProperties {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/countries/{}/provinces.json?{}",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/countries/{}/provinces.json",
+                self.client.encode_path(country_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/countries/{country_id}/provinces.json",
+                &[("country_id", country_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1537,10 +2027,16 @@ impl StoreProperties {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/countries/{}/provinces/count.json",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
+            self.client.encode_path(country_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/countries/{country_id}/provinces/count.json",
+                &[("country_id", country_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1562,19 +2058,29 @@ impl StoreProperties {
         province_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/2021-01/countries/{}/provinces/{}/json?{}",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
-            crate::progenitor_support::encode_path(&province_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/2021-01/countries/{}/provinces/{}/json",
+                self.client.encode_path(country_id),
+                self.client.encode_path(province_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/2021-01/countries/{country_id}/provinces/{province_id}.json",
+                &[
+                    ("country_id", country_id.to_string()),
+                    ("province_id", province_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1600,13 +2106,23 @@ impl StoreProperties {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/2021-01/countries/{}/provinces/{}/json",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
-            crate::progenitor_support::encode_path(&province_id.to_string()),
+            self.client.encode_path(country_id),
+            self.client.encode_path(province_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/2021-01/countries/{country_id}/provinces/{province_id}.json",
+                    &[
+                        ("country_id", country_id.to_string()),
+                        ("province_id", province_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1628,21 +2144,28 @@ impl StoreProperties {
         since_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/countries/{}/provinces.json?{}",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/countries/{}/provinces.json",
+                self.client.encode_path(country_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/countries/{country_id}/provinces.json",
+                &[("country_id", country_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1662,10 +2185,16 @@ impl StoreProperties {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/countries/{}/provinces/count.json",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
+            self.client.encode_path(country_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/countries/{country_id}/provinces/count.json",
+                &[("country_id", country_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1687,19 +2216,29 @@ impl StoreProperties {
         province_id: &str,
         fields: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin/api/unstable/countries/{}/provinces/{}/json?{}",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
-            crate::progenitor_support::encode_path(&province_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/admin/api/unstable/countries/{}/provinces/{}/json",
+                self.client.encode_path(country_id),
+                self.client.encode_path(province_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin/api/unstable/countries/{country_id}/provinces/{province_id}.json",
+                &[
+                    ("country_id", country_id.to_string()),
+                    ("province_id", province_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
@@ -1725,13 +2264,23 @@ impl StoreProperties {
     ) -> Result<()> {
         let url = format!(
             "/admin/api/unstable/countries/{}/provinces/{}/json",
-            crate::progenitor_support::encode_path(&country_id.to_string()),
-            crate::progenitor_support::encode_path(&province_id.to_string()),
+            self.client.encode_path(country_id),
+            self.client.encode_path(province_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/admin/api/unstable/countries/{country_id}/provinces/{province_id}.json",
+                    &[
+                        ("country_id", country_id.to_string()),
+                        ("province_id", province_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -1746,14 +2295,16 @@ impl StoreProperties {
      * * `fields: &str` -- comma-separated list of fields to include in the response.
      */
     pub async fn deprecated_202001_get_shipping_zone(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/shipping_zones.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-01/shipping_zones.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/shipping_zones.json", &[])
+        })
     }
 
     /**
@@ -1768,14 +2319,16 @@ impl StoreProperties {
      * * `fields: &str` -- comma-separated list of fields to include in the response.
      */
     pub async fn deprecated_202004_get_shipping_zone(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/shipping_zones.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-04/shipping_zones.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/shipping_zones.json", &[])
+        })
     }
 
     /**
@@ -1790,14 +2343,16 @@ impl StoreProperties {
      * * `fields: &str` -- comma-separated list of fields to include in the response.
      */
     pub async fn deprecated_202007_get_shipping_zone(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/shipping_zones.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-07/shipping_zones.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/shipping_zones.json", &[])
+        })
     }
 
     /**
@@ -1812,14 +2367,16 @@ impl StoreProperties {
      * * `fields: &str` -- comma-separated list of fields to include in the response.
      */
     pub async fn get_shipping_zone(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/shipping_zones.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2020-10/shipping_zones.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/shipping_zones.json", &[])
+        })
     }
 
     /**
@@ -1834,14 +2391,16 @@ impl StoreProperties {
      * * `fields: &str` -- comma-separated list of fields to include in the response.
      */
     pub async fn deprecated_202101_get_shipping_zone(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/shipping_zones.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/2021-01/shipping_zones.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/shipping_zones.json", &[])
+        })
     }
 
     /**
@@ -1856,14 +2415,16 @@ impl StoreProperties {
      * * `fields: &str` -- comma-separated list of fields to include in the response.
      */
     pub async fn deprecated_unstable_get_shipping_zone(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/shipping_zones.json?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin/api/unstable/shipping_zones.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/shipping_zones.json", &[])
+        })
     }
 
     /**
@@ -1878,14 +2439,16 @@ impl StoreProperties {
      * * `fields: &str` -- A comma-separated list of fields to include in the response.
      */
     pub async fn deprecated_202001_get_shop(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/shop.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-01/shop.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-01/shop.json", &[]))
     }
 
     /**
@@ -1900,14 +2463,16 @@ impl StoreProperties {
      * * `fields: &str` -- A comma-separated list of fields to include in the response.
      */
     pub async fn deprecated_202004_get_shop(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/shop.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-04/shop.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-04/shop.json", &[]))
     }
 
     /**
@@ -1922,14 +2487,16 @@ impl StoreProperties {
      * * `fields: &str` -- A comma-separated list of fields to include in the response.
      */
     pub async fn deprecated_202007_get_shop(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/shop.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-07/shop.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-07/shop.json", &[]))
     }
 
     /**
@@ -1944,14 +2511,16 @@ impl StoreProperties {
      * * `fields: &str` -- A comma-separated list of fields to include in the response.
      */
     pub async fn get_shop(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/shop.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2020-10/shop.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/admin/api/2020-10/shop.json", &[]))
     }
 
     /**
@@ -1966,14 +2535,16 @@ impl StoreProperties {
      * * `fields: &str` -- A comma-separated list of fields to include in the response.
      */
     pub async fn deprecated_202101_get_shop(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/shop.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/2021-01/shop.json", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/admin/api/2021-01/shop.json", &[]))
     }
 
     /**
@@ -1988,13 +2559,874 @@ impl StoreProperties {
      * * `fields: &str` -- A comma-separated list of fields to include in the response.
      */
     pub async fn deprecated_unstable_get_shop(&self, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+            query_args.push(("fields", fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/shop.json?{}", query_);
+        let url = crate::Client::url_with_query("/admin/api/unstable/shop.json", &query_args);
+
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/admin/api/unstable/shop.json", &[]))
+    }
+}
 
-        self.client.get(&url, None).await
+/// The functions of [`StoreProperties`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn StorePropertiesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait StorePropertiesApi: Send + Sync {
+    async fn deprecated_202001_get_countrie(&self, since_id: &str, fields: &str) -> Result<()>;
+    async fn deprecated_202001_create_countries(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202001_get_countries_count(&self) -> Result<()>;
+    async fn deprecated_202001_get_countries_param_country(
+        &self,
+        country_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_update_countries_param_country(
+        &self,
+        country_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_countries_param_country(
+        &self,
+        country_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_delete_countries_param_country_if_exists(
+        &self,
+        country_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202004_get_countrie(&self, since_id: &str, fields: &str) -> Result<()>;
+    async fn deprecated_202004_create_countries(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202004_get_countries_count(&self) -> Result<()>;
+    async fn deprecated_202004_get_countries_param_country(
+        &self,
+        country_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_update_countries_param_country(
+        &self,
+        country_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_countries_param_country(
+        &self,
+        country_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_delete_countries_param_country_if_exists(
+        &self,
+        country_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202007_get_countrie(&self, since_id: &str, fields: &str) -> Result<()>;
+    async fn deprecated_202007_create_countries(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202007_get_countries_count(&self) -> Result<()>;
+    async fn deprecated_202007_get_countries_param_country(
+        &self,
+        country_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_update_countries_param_country(
+        &self,
+        country_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_countries_param_country(
+        &self,
+        country_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_delete_countries_param_country_if_exists(
+        &self,
+        country_id: &str,
+    ) -> Result<bool>;
+    async fn get_countrie(&self, since_id: &str, fields: &str) -> Result<()>;
+    async fn create_countries(&self, body: &serde_json::Value) -> Result<()>;
+    async fn get_countries_count(&self) -> Result<()>;
+    async fn get_countries_param_country(&self, country_id: &str, fields: &str) -> Result<()>;
+    async fn update_countries_param_country(
+        &self,
+        country_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn delete_countries_param_country(&self, country_id: &str) -> Result<()>;
+    async fn delete_countries_param_country_if_exists(&self, country_id: &str) -> Result<bool>;
+    async fn deprecated_202101_get_countrie(&self, since_id: &str, fields: &str) -> Result<()>;
+    async fn deprecated_202101_create_countries(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_202101_get_countries_count(&self) -> Result<()>;
+    async fn deprecated_202101_get_countries_param_country(
+        &self,
+        country_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_update_countries_param_country(
+        &self,
+        country_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_countries_param_country(
+        &self,
+        country_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_delete_countries_param_country_if_exists(
+        &self,
+        country_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_unstable_get_countrie(&self, since_id: &str, fields: &str) -> Result<()>;
+    async fn deprecated_unstable_create_countries(&self, body: &serde_json::Value) -> Result<()>;
+    async fn deprecated_unstable_get_countries_count(&self) -> Result<()>;
+    async fn deprecated_unstable_get_countries_param_country(
+        &self,
+        country_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_update_countries_param_country(
+        &self,
+        country_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_countries_param_country(
+        &self,
+        country_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_delete_countries_param_country_if_exists(
+        &self,
+        country_id: &str,
+    ) -> Result<bool>;
+    async fn deprecated_202001_get_currencie(&self) -> Result<()>;
+    async fn deprecated_202004_get_currencie(&self) -> Result<()>;
+    async fn deprecated_202007_get_currencie(&self) -> Result<()>;
+    async fn get_currencie(&self) -> Result<()>;
+    async fn deprecated_202101_get_currencie(&self) -> Result<()>;
+    async fn deprecated_unstable_get_currencie(&self) -> Result<()>;
+    async fn deprecated_202001_get_policie(&self) -> Result<()>;
+    async fn deprecated_202004_get_policie(&self) -> Result<()>;
+    async fn deprecated_202007_get_policie(&self) -> Result<()>;
+    async fn get_policie(&self) -> Result<()>;
+    async fn deprecated_202101_get_policie(&self) -> Result<()>;
+    async fn deprecated_unstable_get_policie(&self) -> Result<()>;
+    async fn deprecated_202001_get_countries_param_country_province(
+        &self,
+        country_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_countries_param_country_provinces_count(
+        &self,
+        country_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202001_update_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_countries_param_country_province(
+        &self,
+        country_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_countries_param_country_provinces_count(
+        &self,
+        country_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_update_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_countries_param_country_province(
+        &self,
+        country_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_countries_param_country_provinces_count(
+        &self,
+        country_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_update_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn get_countries_param_country_province(
+        &self,
+        country_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn get_countries_param_country_provinces_count(&self, country_id: &str) -> Result<()>;
+    async fn get_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn update_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_countries_param_country_province(
+        &self,
+        country_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_countries_param_country_provinces_count(
+        &self,
+        country_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_update_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_countries_param_country_province(
+        &self,
+        country_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_countries_param_country_provinces_count(
+        &self,
+        country_id: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        fields: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_update_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+    async fn deprecated_202001_get_shipping_zone(&self, fields: &str) -> Result<()>;
+    async fn deprecated_202004_get_shipping_zone(&self, fields: &str) -> Result<()>;
+    async fn deprecated_202007_get_shipping_zone(&self, fields: &str) -> Result<()>;
+    async fn get_shipping_zone(&self, fields: &str) -> Result<()>;
+    async fn deprecated_202101_get_shipping_zone(&self, fields: &str) -> Result<()>;
+    async fn deprecated_unstable_get_shipping_zone(&self, fields: &str) -> Result<()>;
+    async fn deprecated_202001_get_shop(&self, fields: &str) -> Result<()>;
+    async fn deprecated_202004_get_shop(&self, fields: &str) -> Result<()>;
+    async fn deprecated_202007_get_shop(&self, fields: &str) -> Result<()>;
+    async fn get_shop(&self, fields: &str) -> Result<()>;
+    async fn deprecated_202101_get_shop(&self, fields: &str) -> Result<()>;
+    async fn deprecated_unstable_get_shop(&self, fields: &str) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl StorePropertiesApi for StoreProperties {
+    async fn deprecated_202001_get_countrie(&self, since_id: &str, fields: &str) -> Result<()> {
+        StoreProperties::deprecated_202001_get_countrie(self, since_id, fields).await
+    }
+    async fn deprecated_202001_create_countries(&self, body: &serde_json::Value) -> Result<()> {
+        StoreProperties::deprecated_202001_create_countries(self, body).await
+    }
+    async fn deprecated_202001_get_countries_count(&self) -> Result<()> {
+        StoreProperties::deprecated_202001_get_countries_count(self).await
+    }
+    async fn deprecated_202001_get_countries_param_country(
+        &self,
+        country_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202001_get_countries_param_country(self, country_id, fields)
+            .await
+    }
+    async fn deprecated_202001_update_countries_param_country(
+        &self,
+        country_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202001_update_countries_param_country(self, country_id, body)
+            .await
+    }
+    async fn deprecated_202001_delete_countries_param_country(
+        &self,
+        country_id: &str,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202001_delete_countries_param_country(self, country_id).await
+    }
+    async fn deprecated_202001_delete_countries_param_country_if_exists(
+        &self,
+        country_id: &str,
+    ) -> Result<bool> {
+        StoreProperties::deprecated_202001_delete_countries_param_country_if_exists(
+            self, country_id,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_countrie(&self, since_id: &str, fields: &str) -> Result<()> {
+        StoreProperties::deprecated_202004_get_countrie(self, since_id, fields).await
+    }
+    async fn deprecated_202004_create_countries(&self, body: &serde_json::Value) -> Result<()> {
+        StoreProperties::deprecated_202004_create_countries(self, body).await
+    }
+    async fn deprecated_202004_get_countries_count(&self) -> Result<()> {
+        StoreProperties::deprecated_202004_get_countries_count(self).await
+    }
+    async fn deprecated_202004_get_countries_param_country(
+        &self,
+        country_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202004_get_countries_param_country(self, country_id, fields)
+            .await
+    }
+    async fn deprecated_202004_update_countries_param_country(
+        &self,
+        country_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202004_update_countries_param_country(self, country_id, body)
+            .await
+    }
+    async fn deprecated_202004_delete_countries_param_country(
+        &self,
+        country_id: &str,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202004_delete_countries_param_country(self, country_id).await
+    }
+    async fn deprecated_202004_delete_countries_param_country_if_exists(
+        &self,
+        country_id: &str,
+    ) -> Result<bool> {
+        StoreProperties::deprecated_202004_delete_countries_param_country_if_exists(
+            self, country_id,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_countrie(&self, since_id: &str, fields: &str) -> Result<()> {
+        StoreProperties::deprecated_202007_get_countrie(self, since_id, fields).await
+    }
+    async fn deprecated_202007_create_countries(&self, body: &serde_json::Value) -> Result<()> {
+        StoreProperties::deprecated_202007_create_countries(self, body).await
+    }
+    async fn deprecated_202007_get_countries_count(&self) -> Result<()> {
+        StoreProperties::deprecated_202007_get_countries_count(self).await
+    }
+    async fn deprecated_202007_get_countries_param_country(
+        &self,
+        country_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202007_get_countries_param_country(self, country_id, fields)
+            .await
+    }
+    async fn deprecated_202007_update_countries_param_country(
+        &self,
+        country_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202007_update_countries_param_country(self, country_id, body)
+            .await
+    }
+    async fn deprecated_202007_delete_countries_param_country(
+        &self,
+        country_id: &str,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202007_delete_countries_param_country(self, country_id).await
+    }
+    async fn deprecated_202007_delete_countries_param_country_if_exists(
+        &self,
+        country_id: &str,
+    ) -> Result<bool> {
+        StoreProperties::deprecated_202007_delete_countries_param_country_if_exists(
+            self, country_id,
+        )
+        .await
+    }
+    async fn get_countrie(&self, since_id: &str, fields: &str) -> Result<()> {
+        StoreProperties::get_countrie(self, since_id, fields).await
+    }
+    async fn create_countries(&self, body: &serde_json::Value) -> Result<()> {
+        StoreProperties::create_countries(self, body).await
+    }
+    async fn get_countries_count(&self) -> Result<()> {
+        StoreProperties::get_countries_count(self).await
+    }
+    async fn get_countries_param_country(&self, country_id: &str, fields: &str) -> Result<()> {
+        StoreProperties::get_countries_param_country(self, country_id, fields).await
+    }
+    async fn update_countries_param_country(
+        &self,
+        country_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        StoreProperties::update_countries_param_country(self, country_id, body).await
+    }
+    async fn delete_countries_param_country(&self, country_id: &str) -> Result<()> {
+        StoreProperties::delete_countries_param_country(self, country_id).await
+    }
+    async fn delete_countries_param_country_if_exists(&self, country_id: &str) -> Result<bool> {
+        StoreProperties::delete_countries_param_country_if_exists(self, country_id).await
+    }
+    async fn deprecated_202101_get_countrie(&self, since_id: &str, fields: &str) -> Result<()> {
+        StoreProperties::deprecated_202101_get_countrie(self, since_id, fields).await
+    }
+    async fn deprecated_202101_create_countries(&self, body: &serde_json::Value) -> Result<()> {
+        StoreProperties::deprecated_202101_create_countries(self, body).await
+    }
+    async fn deprecated_202101_get_countries_count(&self) -> Result<()> {
+        StoreProperties::deprecated_202101_get_countries_count(self).await
+    }
+    async fn deprecated_202101_get_countries_param_country(
+        &self,
+        country_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202101_get_countries_param_country(self, country_id, fields)
+            .await
+    }
+    async fn deprecated_202101_update_countries_param_country(
+        &self,
+        country_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202101_update_countries_param_country(self, country_id, body)
+            .await
+    }
+    async fn deprecated_202101_delete_countries_param_country(
+        &self,
+        country_id: &str,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202101_delete_countries_param_country(self, country_id).await
+    }
+    async fn deprecated_202101_delete_countries_param_country_if_exists(
+        &self,
+        country_id: &str,
+    ) -> Result<bool> {
+        StoreProperties::deprecated_202101_delete_countries_param_country_if_exists(
+            self, country_id,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_countrie(&self, since_id: &str, fields: &str) -> Result<()> {
+        StoreProperties::deprecated_unstable_get_countrie(self, since_id, fields).await
+    }
+    async fn deprecated_unstable_create_countries(&self, body: &serde_json::Value) -> Result<()> {
+        StoreProperties::deprecated_unstable_create_countries(self, body).await
+    }
+    async fn deprecated_unstable_get_countries_count(&self) -> Result<()> {
+        StoreProperties::deprecated_unstable_get_countries_count(self).await
+    }
+    async fn deprecated_unstable_get_countries_param_country(
+        &self,
+        country_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        StoreProperties::deprecated_unstable_get_countries_param_country(self, country_id, fields)
+            .await
+    }
+    async fn deprecated_unstable_update_countries_param_country(
+        &self,
+        country_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        StoreProperties::deprecated_unstable_update_countries_param_country(self, country_id, body)
+            .await
+    }
+    async fn deprecated_unstable_delete_countries_param_country(
+        &self,
+        country_id: &str,
+    ) -> Result<()> {
+        StoreProperties::deprecated_unstable_delete_countries_param_country(self, country_id).await
+    }
+    async fn deprecated_unstable_delete_countries_param_country_if_exists(
+        &self,
+        country_id: &str,
+    ) -> Result<bool> {
+        StoreProperties::deprecated_unstable_delete_countries_param_country_if_exists(
+            self, country_id,
+        )
+        .await
+    }
+    async fn deprecated_202001_get_currencie(&self) -> Result<()> {
+        StoreProperties::deprecated_202001_get_currencie(self).await
+    }
+    async fn deprecated_202004_get_currencie(&self) -> Result<()> {
+        StoreProperties::deprecated_202004_get_currencie(self).await
+    }
+    async fn deprecated_202007_get_currencie(&self) -> Result<()> {
+        StoreProperties::deprecated_202007_get_currencie(self).await
+    }
+    async fn get_currencie(&self) -> Result<()> {
+        StoreProperties::get_currencie(self).await
+    }
+    async fn deprecated_202101_get_currencie(&self) -> Result<()> {
+        StoreProperties::deprecated_202101_get_currencie(self).await
+    }
+    async fn deprecated_unstable_get_currencie(&self) -> Result<()> {
+        StoreProperties::deprecated_unstable_get_currencie(self).await
+    }
+    async fn deprecated_202001_get_policie(&self) -> Result<()> {
+        StoreProperties::deprecated_202001_get_policie(self).await
+    }
+    async fn deprecated_202004_get_policie(&self) -> Result<()> {
+        StoreProperties::deprecated_202004_get_policie(self).await
+    }
+    async fn deprecated_202007_get_policie(&self) -> Result<()> {
+        StoreProperties::deprecated_202007_get_policie(self).await
+    }
+    async fn get_policie(&self) -> Result<()> {
+        StoreProperties::get_policie(self).await
+    }
+    async fn deprecated_202101_get_policie(&self) -> Result<()> {
+        StoreProperties::deprecated_202101_get_policie(self).await
+    }
+    async fn deprecated_unstable_get_policie(&self) -> Result<()> {
+        StoreProperties::deprecated_unstable_get_policie(self).await
+    }
+    async fn deprecated_202001_get_countries_param_country_province(
+        &self,
+        country_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202001_get_countries_param_country_province(
+            self, country_id, since_id, fields,
+        )
+        .await
+    }
+    async fn deprecated_202001_get_countries_param_country_provinces_count(
+        &self,
+        country_id: &str,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202001_get_countries_param_country_provinces_count(
+            self, country_id,
+        )
+        .await
+    }
+    async fn deprecated_202001_get_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202001_get_countries_param_country_provinces_province(
+            self,
+            country_id,
+            province_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202001_update_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202001_update_countries_param_country_provinces_province(
+            self,
+            country_id,
+            province_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_countries_param_country_province(
+        &self,
+        country_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202004_get_countries_param_country_province(
+            self, country_id, since_id, fields,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_countries_param_country_provinces_count(
+        &self,
+        country_id: &str,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202004_get_countries_param_country_provinces_count(
+            self, country_id,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202004_get_countries_param_country_provinces_province(
+            self,
+            country_id,
+            province_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202004_update_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202004_update_countries_param_country_provinces_province(
+            self,
+            country_id,
+            province_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_countries_param_country_province(
+        &self,
+        country_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202007_get_countries_param_country_province(
+            self, country_id, since_id, fields,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_countries_param_country_provinces_count(
+        &self,
+        country_id: &str,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202007_get_countries_param_country_provinces_count(
+            self, country_id,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202007_get_countries_param_country_provinces_province(
+            self,
+            country_id,
+            province_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202007_update_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202007_update_countries_param_country_provinces_province(
+            self,
+            country_id,
+            province_id,
+            body,
+        )
+        .await
+    }
+    async fn get_countries_param_country_province(
+        &self,
+        country_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        StoreProperties::get_countries_param_country_province(self, country_id, since_id, fields)
+            .await
+    }
+    async fn get_countries_param_country_provinces_count(&self, country_id: &str) -> Result<()> {
+        StoreProperties::get_countries_param_country_provinces_count(self, country_id).await
+    }
+    async fn get_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        StoreProperties::get_countries_param_country_provinces_province(
+            self,
+            country_id,
+            province_id,
+            fields,
+        )
+        .await
+    }
+    async fn update_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        StoreProperties::update_countries_param_country_provinces_province(
+            self,
+            country_id,
+            province_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202101_get_countries_param_country_province(
+        &self,
+        country_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202101_get_countries_param_country_province(
+            self, country_id, since_id, fields,
+        )
+        .await
+    }
+    async fn deprecated_202101_get_countries_param_country_provinces_count(
+        &self,
+        country_id: &str,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202101_get_countries_param_country_provinces_count(
+            self, country_id,
+        )
+        .await
+    }
+    async fn deprecated_202101_get_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202101_get_countries_param_country_provinces_province(
+            self,
+            country_id,
+            province_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_202101_update_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202101_update_countries_param_country_provinces_province(
+            self,
+            country_id,
+            province_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_countries_param_country_province(
+        &self,
+        country_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        StoreProperties::deprecated_unstable_get_countries_param_country_province(
+            self, country_id, since_id, fields,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_countries_param_country_provinces_count(
+        &self,
+        country_id: &str,
+    ) -> Result<()> {
+        StoreProperties::deprecated_unstable_get_countries_param_country_provinces_count(
+            self, country_id,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        StoreProperties::deprecated_unstable_get_countries_param_country_provinces_province(
+            self,
+            country_id,
+            province_id,
+            fields,
+        )
+        .await
+    }
+    async fn deprecated_unstable_update_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        StoreProperties::deprecated_unstable_update_countries_param_country_provinces_province(
+            self,
+            country_id,
+            province_id,
+            body,
+        )
+        .await
+    }
+    async fn deprecated_202001_get_shipping_zone(&self, fields: &str) -> Result<()> {
+        StoreProperties::deprecated_202001_get_shipping_zone(self, fields).await
+    }
+    async fn deprecated_202004_get_shipping_zone(&self, fields: &str) -> Result<()> {
+        StoreProperties::deprecated_202004_get_shipping_zone(self, fields).await
+    }
+    async fn deprecated_202007_get_shipping_zone(&self, fields: &str) -> Result<()> {
+        StoreProperties::deprecated_202007_get_shipping_zone(self, fields).await
+    }
+    async fn get_shipping_zone(&self, fields: &str) -> Result<()> {
+        StoreProperties::get_shipping_zone(self, fields).await
+    }
+    async fn deprecated_202101_get_shipping_zone(&self, fields: &str) -> Result<()> {
+        StoreProperties::deprecated_202101_get_shipping_zone(self, fields).await
+    }
+    async fn deprecated_unstable_get_shipping_zone(&self, fields: &str) -> Result<()> {
+        StoreProperties::deprecated_unstable_get_shipping_zone(self, fields).await
+    }
+    async fn deprecated_202001_get_shop(&self, fields: &str) -> Result<()> {
+        StoreProperties::deprecated_202001_get_shop(self, fields).await
+    }
+    async fn deprecated_202004_get_shop(&self, fields: &str) -> Result<()> {
+        StoreProperties::deprecated_202004_get_shop(self, fields).await
+    }
+    async fn deprecated_202007_get_shop(&self, fields: &str) -> Result<()> {
+        StoreProperties::deprecated_202007_get_shop(self, fields).await
+    }
+    async fn get_shop(&self, fields: &str) -> Result<()> {
+        StoreProperties::get_shop(self, fields).await
+    }
+    async fn deprecated_202101_get_shop(&self, fields: &str) -> Result<()> {
+        StoreProperties::deprecated_202101_get_shop(self, fields).await
+    }
+    async fn deprecated_unstable_get_shop(&self, fields: &str) -> Result<()> {
+        StoreProperties::deprecated_unstable_get_shop(self, fields).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Tendertransaction {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Tendertransaction {
         Tendertransaction { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Tendertransaction {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Retrieves a list of tender transactions. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -38,29 +44,33 @@ impl Tendertransaction {
         processed_at: &str,
         order: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !processed_at.is_empty() {
-            query_args.push(("processed_at".to_string(), processed_at.to_string()));
+            query_args.push(("processed_at", processed_at.to_string()));
         }
         if !processed_at_max.is_empty() {
-            query_args.push(("processed_at_max".to_string(), processed_at_max.to_string()));
+            query_args.push(("processed_at_max", processed_at_max.to_string()));
         }
         if !processed_at_min.is_empty() {
-            query_args.push(("processed_at_min".to_string(), processed_at_min.to_string()));
+            query_args.push(("processed_at_min", processed_at_min.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-01/tender_transactions.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-01/tender_transactions.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-01/tender_transactions.json", &[])
+        })
     }
 
     /**
@@ -89,29 +99,33 @@ impl Tendertransaction {
         processed_at: &str,
         order: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !processed_at.is_empty() {
-            query_args.push(("processed_at".to_string(), processed_at.to_string()));
+            query_args.push(("processed_at", processed_at.to_string()));
         }
         if !processed_at_max.is_empty() {
-            query_args.push(("processed_at_max".to_string(), processed_at_max.to_string()));
+            query_args.push(("processed_at_max", processed_at_max.to_string()));
         }
         if !processed_at_min.is_empty() {
-            query_args.push(("processed_at_min".to_string(), processed_at_min.to_string()));
+            query_args.push(("processed_at_min", processed_at_min.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-04/tender_transactions.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-04/tender_transactions.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-04/tender_transactions.json", &[])
+        })
     }
 
     /**
@@ -140,29 +154,33 @@ impl Tendertransaction {
         processed_at: &str,
         order: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !processed_at.is_empty() {
-            query_args.push(("processed_at".to_string(), processed_at.to_string()));
+            query_args.push(("processed_at", processed_at.to_string()));
         }
         if !processed_at_max.is_empty() {
-            query_args.push(("processed_at_max".to_string(), processed_at_max.to_string()));
+            query_args.push(("processed_at_max", processed_at_max.to_string()));
         }
         if !processed_at_min.is_empty() {
-            query_args.push(("processed_at_min".to_string(), processed_at_min.to_string()));
+            query_args.push(("processed_at_min", processed_at_min.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-07/tender_transactions.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-07/tender_transactions.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-07/tender_transactions.json", &[])
+        })
     }
 
     /**
@@ -191,29 +209,33 @@ impl Tendertransaction {
         processed_at: &str,
         order: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !processed_at.is_empty() {
-            query_args.push(("processed_at".to_string(), processed_at.to_string()));
+            query_args.push(("processed_at", processed_at.to_string()));
         }
         if !processed_at_max.is_empty() {
-            query_args.push(("processed_at_max".to_string(), processed_at_max.to_string()));
+            query_args.push(("processed_at_max", processed_at_max.to_string()));
         }
         if !processed_at_min.is_empty() {
-            query_args.push(("processed_at_min".to_string(), processed_at_min.to_string()));
+            query_args.push(("processed_at_min", processed_at_min.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2020-10/tender_transactions.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2020-10/tender_transactions.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2020-10/tender_transactions.json", &[])
+        })
     }
 
     /**
@@ -242,29 +264,33 @@ impl Tendertransaction {
         processed_at: &str,
         order: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !processed_at.is_empty() {
-            query_args.push(("processed_at".to_string(), processed_at.to_string()));
+            query_args.push(("processed_at", processed_at.to_string()));
         }
         if !processed_at_max.is_empty() {
-            query_args.push(("processed_at_max".to_string(), processed_at_max.to_string()));
+            query_args.push(("processed_at_max", processed_at_max.to_string()));
         }
         if !processed_at_min.is_empty() {
-            query_args.push(("processed_at_min".to_string(), processed_at_min.to_string()));
+            query_args.push(("processed_at_min", processed_at_min.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/2021-01/tender_transactions.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/2021-01/tender_transactions.json",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/2021-01/tender_transactions.json", &[])
+        })
     }
 
     /**
@@ -293,28 +319,219 @@ impl Tendertransaction {
         processed_at: &str,
         order: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !limit.is_empty() {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !order.is_empty() {
-            query_args.push(("order".to_string(), order.to_string()));
+            query_args.push(("order", order.to_string()));
         }
         if !processed_at.is_empty() {
-            query_args.push(("processed_at".to_string(), processed_at.to_string()));
+            query_args.push(("processed_at", processed_at.to_string()));
         }
         if !processed_at_max.is_empty() {
-            query_args.push(("processed_at_max".to_string(), processed_at_max.to_string()));
+            query_args.push(("processed_at_max", processed_at_max.to_string()));
         }
         if !processed_at_min.is_empty() {
-            query_args.push(("processed_at_min".to_string(), processed_at_min.to_string()));
+            query_args.push(("processed_at_min", processed_at_min.to_string()));
         }
         if !since_id.is_empty() {
-            query_args.push(("since_id".to_string(), since_id.to_string()));
+            query_args.push(("since_id", since_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin/api/unstable/tender_transactions.json?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin/api/unstable/tender_transactions.json",
+            &query_args,
+        );
+
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin/api/unstable/tender_transactions.json", &[])
+        })
+    }
+}
+
+/// The functions of [`Tendertransaction`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn TendertransactionApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait TendertransactionApi: Send + Sync {
+    async fn deprecated_202001_get_tender_transaction(
+        &self,
+        limit: &str,
+        since_id: &str,
+        processed_at_min: &str,
+        processed_at_max: &str,
+        processed_at: &str,
+        order: &str,
+    ) -> Result<()>;
+    async fn deprecated_202004_get_tender_transaction(
+        &self,
+        limit: &str,
+        since_id: &str,
+        processed_at_min: &str,
+        processed_at_max: &str,
+        processed_at: &str,
+        order: &str,
+    ) -> Result<()>;
+    async fn deprecated_202007_get_tender_transaction(
+        &self,
+        limit: &str,
+        since_id: &str,
+        processed_at_min: &str,
+        processed_at_max: &str,
+        processed_at: &str,
+        order: &str,
+    ) -> Result<()>;
+    async fn get_tender_transaction(
+        &self,
+        limit: &str,
+        since_id: &str,
+        processed_at_min: &str,
+        processed_at_max: &str,
+        processed_at: &str,
+        order: &str,
+    ) -> Result<()>;
+    async fn deprecated_202101_get_tender_transaction(
+        &self,
+        limit: &str,
+        since_id: &str,
+        processed_at_min: &str,
+        processed_at_max: &str,
+        processed_at: &str,
+        order: &str,
+    ) -> Result<()>;
+    async fn deprecated_unstable_get_tender_transaction(
+        &self,
+        limit: &str,
+        since_id: &str,
+        processed_at_min: &str,
+        processed_at_max: &str,
+        processed_at: &str,
+        order: &str,
+    ) -> Result<()>;
+}
 
-        self.client.get(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl TendertransactionApi for Tendertransaction {
+    async fn deprecated_202001_get_tender_transaction(
+        &self,
+        limit: &str,
+        since_id: &str,
+        processed_at_min: &str,
+        processed_at_max: &str,
+        processed_at: &str,
+        order: &str,
+    ) -> Result<()> {
+        Tendertransaction::deprecated_202001_get_tender_transaction(
+            self,
+            limit,
+            since_id,
+            processed_at_min,
+            processed_at_max,
+            processed_at,
+            order,
+        )
+        .await
+    }
+    async fn deprecated_202004_get_tender_transaction(
+        &self,
+        limit: &str,
+        since_id: &str,
+        processed_at_min: &str,
+        processed_at_max: &str,
+        processed_at: &str,
+        order: &str,
+    ) -> Result<()> {
+        Tendertransaction::deprecated_202004_get_tender_transaction(
+            self,
+            limit,
+            since_id,
+            processed_at_min,
+            processed_at_max,
+            processed_at,
+            order,
+        )
+        .await
+    }
+    async fn deprecated_202007_get_tender_transaction(
+        &self,
+        limit: &str,
+        since_id: &str,
+        processed_at_min: &str,
+        processed_at_max: &str,
+        processed_at: &str,
+        order: &str,
+    ) -> Result<()> {
+        Tendertransaction::deprecated_202007_get_tender_transaction(
+            self,
+            limit,
+            since_id,
+            processed_at_min,
+            processed_at_max,
+            processed_at,
+            order,
+        )
+        .await
+    }
+    async fn get_tender_transaction(
+        &self,
+        limit: &str,
+        since_id: &str,
+        processed_at_min: &str,
+        processed_at_max: &str,
+        processed_at: &str,
+        order: &str,
+    ) -> Result<()> {
+        Tendertransaction::get_tender_transaction(
+            self,
+            limit,
+            since_id,
+            processed_at_min,
+            processed_at_max,
+            processed_at,
+            order,
+        )
+        .await
+    }
+    async fn deprecated_202101_get_tender_transaction(
+        &self,
+        limit: &str,
+        since_id: &str,
+        processed_at_min: &str,
+        processed_at_max: &str,
+        processed_at: &str,
+        order: &str,
+    ) -> Result<()> {
+        Tendertransaction::deprecated_202101_get_tender_transaction(
+            self,
+            limit,
+            since_id,
+            processed_at_min,
+            processed_at_max,
+            processed_at,
+            order,
+        )
+        .await
+    }
+    async fn deprecated_unstable_get_tender_transaction(
+        &self,
+        limit: &str,
+        since_id: &str,
+        processed_at_min: &str,
+        processed_at_max: &str,
+        processed_at: &str,
+        order: &str,
+    ) -> Result<()> {
+        Tendertransaction::deprecated_unstable_get_tender_transaction(
+            self,
+            limit,
+            since_id,
+            processed_at_min,
+            processed_at_max,
+            processed_at,
+            order,
+        )
+        .await
     }
 }
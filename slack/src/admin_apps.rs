@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct AdminApps {
     pub client: Client,
@@ -12,6 +10,14 @@ impl AdminApps {
         AdminApps { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        AdminApps {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `POST` to the `/admin.apps.approve` endpoint.
      *
@@ -25,7 +31,10 @@ impl AdminApps {
      */
     pub async fn approve(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/admin.apps.approve".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/admin.apps.approve", &[]))
     }
 
     /**
@@ -41,6 +50,30 @@ impl AdminApps {
      */
     pub async fn restrict(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/admin.apps.restrict".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/admin.apps.restrict", &[]))
+    }
+}
+
+/// The functions of [`AdminApps`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AdminAppsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AdminAppsApi: Send + Sync {
+    async fn approve(&self) -> Result<crate::types::DndEndSchema>;
+    async fn restrict(&self) -> Result<crate::types::DndEndSchema>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AdminAppsApi for AdminApps {
+    async fn approve(&self) -> Result<crate::types::DndEndSchema> {
+        AdminApps::approve(self).await
+    }
+    async fn restrict(&self) -> Result<crate::types::DndEndSchema> {
+        AdminApps::restrict(self).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct AdminAppsApproved {
     pub client: Client,
@@ -12,6 +10,14 @@ impl AdminAppsApproved {
         AdminAppsApproved { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        AdminAppsApproved {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/admin.apps.approved.list` endpoint.
      *
@@ -34,22 +40,53 @@ impl AdminAppsApproved {
         team_id: &str,
         enterprise_id: &str,
     ) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if !enterprise_id.is_empty() {
-            query_args.push(("enterprise_id".to_string(), enterprise_id.to_string()));
+            query_args.push(("enterprise_id", enterprise_id.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !team_id.is_empty() {
-            query_args.push(("team_id".to_string(), team_id.to_string()));
+            query_args.push(("team_id", team_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin.apps.approved.list?{}", query_);
+        let url = crate::Client::url_with_query("/admin.apps.approved.list", &query_args);
+
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/admin.apps.approved.list", &[]))
+    }
+}
+
+/// The functions of [`AdminAppsApproved`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AdminAppsApprovedApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AdminAppsApprovedApi: Send + Sync {
+    async fn list(
+        &self,
+        limit: i64,
+        cursor: &str,
+        team_id: &str,
+        enterprise_id: &str,
+    ) -> Result<crate::types::DndEndSchema>;
+}
 
-        self.client.get(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AdminAppsApprovedApi for AdminAppsApproved {
+    async fn list(
+        &self,
+        limit: i64,
+        cursor: &str,
+        team_id: &str,
+        enterprise_id: &str,
+    ) -> Result<crate::types::DndEndSchema> {
+        AdminAppsApproved::list(self, limit, cursor, team_id, enterprise_id).await
     }
 }
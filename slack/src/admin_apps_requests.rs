@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct AdminAppsRequests {
     pub client: Client,
@@ -12,6 +10,14 @@ impl AdminAppsRequests {
         AdminAppsRequests { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        AdminAppsRequests {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/admin.apps.requests.list` endpoint.
      *
@@ -32,19 +38,48 @@ impl AdminAppsRequests {
         cursor: &str,
         team_id: &str,
     ) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !team_id.is_empty() {
-            query_args.push(("team_id".to_string(), team_id.to_string()));
+            query_args.push(("team_id", team_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin.apps.requests.list?{}", query_);
+        let url = crate::Client::url_with_query("/admin.apps.requests.list", &query_args);
+
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/admin.apps.requests.list", &[]))
+    }
+}
+
+/// The functions of [`AdminAppsRequests`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AdminAppsRequestsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AdminAppsRequestsApi: Send + Sync {
+    async fn list(
+        &self,
+        limit: i64,
+        cursor: &str,
+        team_id: &str,
+    ) -> Result<crate::types::DndEndSchema>;
+}
 
-        self.client.get(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AdminAppsRequestsApi for AdminAppsRequests {
+    async fn list(
+        &self,
+        limit: i64,
+        cursor: &str,
+        team_id: &str,
+    ) -> Result<crate::types::DndEndSchema> {
+        AdminAppsRequests::list(self, limit, cursor, team_id).await
     }
 }
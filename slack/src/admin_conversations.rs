@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct AdminConversations {
     pub client: Client,
@@ -12,6 +10,14 @@ impl AdminConversations {
         AdminConversations { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        AdminConversations {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `POST` to the `/admin.conversations.archive` endpoint.
      *
@@ -25,7 +31,10 @@ impl AdminConversations {
      */
     pub async fn archive(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/admin.conversations.archive".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/admin.conversations.archive", &[]))
     }
 
     /**
@@ -41,7 +50,9 @@ impl AdminConversations {
      */
     pub async fn convert_private(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/admin.conversations.convertToPrivate".to_string();
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("POST", "/admin.conversations.convertToPrivate", &[])
+        })
     }
 
     /**
@@ -57,7 +68,10 @@ impl AdminConversations {
      */
     pub async fn create(&self) -> Result<crate::types::AdminConversationsCreateSchema> {
         let url = "/admin.conversations.create".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/admin.conversations.create", &[]))
     }
 
     /**
@@ -73,7 +87,10 @@ impl AdminConversations {
      */
     pub async fn delete(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/admin.conversations.delete".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/admin.conversations.delete", &[]))
     }
 
     /**
@@ -89,7 +106,9 @@ impl AdminConversations {
      */
     pub async fn disconnect_shared(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/admin.conversations.disconnectShared".to_string();
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("POST", "/admin.conversations.disconnectShared", &[])
+        })
     }
 
     /**
@@ -108,14 +127,16 @@ impl AdminConversations {
         &self,
         channel_id: &str,
     ) -> Result<crate::types::AdminConversationsGetConversationPrefsSchemaData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !channel_id.is_empty() {
-            query_args.push(("channel_id".to_string(), channel_id.to_string()));
+            query_args.push(("channel_id", channel_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin.conversations.getConversationPrefs?{}", query_);
+        let url =
+            crate::Client::url_with_query("/admin.conversations.getConversationPrefs", &query_args);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin.conversations.getConversationPrefs", &[])
+        })
     }
 
     /**
@@ -138,20 +159,22 @@ impl AdminConversations {
         cursor: &str,
         limit: i64,
     ) -> Result<crate::types::AdminConversationsGetTeamsSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !channel_id.is_empty() {
-            query_args.push(("channel_id".to_string(), channel_id.to_string()));
+            query_args.push(("channel_id", channel_id.to_string()));
         }
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin.conversations.getTeams?{}", query_);
+        let url = crate::Client::url_with_query("/admin.conversations.getTeams", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/admin.conversations.getTeams", &[]))
     }
 
     /**
@@ -167,7 +190,10 @@ impl AdminConversations {
      */
     pub async fn invite(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/admin.conversations.invite".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/admin.conversations.invite", &[]))
     }
 
     /**
@@ -183,7 +209,10 @@ impl AdminConversations {
      */
     pub async fn rename(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/admin.conversations.rename".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/admin.conversations.rename", &[]))
     }
 
     /**
@@ -214,35 +243,34 @@ impl AdminConversations {
         sort: &str,
         sort_dir: &str,
     ) -> Result<crate::types::AdminConversationsSearchSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
         if !search_channel_types.is_empty() {
-            query_args.push((
-                "search_channel_types".to_string(),
-                search_channel_types.to_string(),
-            ));
+            query_args.push(("search_channel_types", search_channel_types.to_string()));
         }
         if !sort.is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
         if !sort_dir.is_empty() {
-            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+            query_args.push(("sort_dir", sort_dir.to_string()));
         }
         if !team_ids.is_empty() {
-            query_args.push(("team_ids".to_string(), team_ids.to_string()));
+            query_args.push(("team_ids", team_ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin.conversations.search?{}", query_);
+        let url = crate::Client::url_with_query("/admin.conversations.search", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/admin.conversations.search", &[]))
     }
 
     /**
@@ -258,7 +286,9 @@ impl AdminConversations {
      */
     pub async fn set_conversation_prefs(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/admin.conversations.setConversationPrefs".to_string();
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("POST", "/admin.conversations.setConversationPrefs", &[])
+        })
     }
 
     /**
@@ -274,7 +304,10 @@ impl AdminConversations {
      */
     pub async fn set_teams(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/admin.conversations.setTeams".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/admin.conversations.setTeams", &[]))
     }
 
     /**
@@ -290,6 +323,117 @@ impl AdminConversations {
      */
     pub async fn unarchive(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/admin.conversations.unarchive".to_string();
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("POST", "/admin.conversations.unarchive", &[])
+        })
+    }
+}
+
+/// The functions of [`AdminConversations`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AdminConversationsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AdminConversationsApi: Send + Sync {
+    async fn archive(&self) -> Result<crate::types::DndEndSchema>;
+    async fn convert_private(&self) -> Result<crate::types::DndEndSchema>;
+    async fn create(&self) -> Result<crate::types::AdminConversationsCreateSchema>;
+    async fn delete(&self) -> Result<crate::types::DndEndSchema>;
+    async fn disconnect_shared(&self) -> Result<crate::types::DndEndSchema>;
+    async fn get_conversation_pref(
+        &self,
+        channel_id: &str,
+    ) -> Result<crate::types::AdminConversationsGetConversationPrefsSchemaData>;
+    async fn get_team(
+        &self,
+        channel_id: &str,
+        cursor: &str,
+        limit: i64,
+    ) -> Result<crate::types::AdminConversationsGetTeamsSchema>;
+    async fn invite(&self) -> Result<crate::types::DndEndSchema>;
+    async fn rename(&self) -> Result<crate::types::DndEndSchema>;
+    async fn search(
+        &self,
+        team_ids: &str,
+        query: &str,
+        limit: i64,
+        cursor: &str,
+        search_channel_types: &str,
+        sort: &str,
+        sort_dir: &str,
+    ) -> Result<crate::types::AdminConversationsSearchSchema>;
+    async fn set_conversation_prefs(&self) -> Result<crate::types::DndEndSchema>;
+    async fn set_teams(&self) -> Result<crate::types::DndEndSchema>;
+    async fn unarchive(&self) -> Result<crate::types::DndEndSchema>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AdminConversationsApi for AdminConversations {
+    async fn archive(&self) -> Result<crate::types::DndEndSchema> {
+        AdminConversations::archive(self).await
+    }
+    async fn convert_private(&self) -> Result<crate::types::DndEndSchema> {
+        AdminConversations::convert_private(self).await
+    }
+    async fn create(&self) -> Result<crate::types::AdminConversationsCreateSchema> {
+        AdminConversations::create(self).await
+    }
+    async fn delete(&self) -> Result<crate::types::DndEndSchema> {
+        AdminConversations::delete(self).await
+    }
+    async fn disconnect_shared(&self) -> Result<crate::types::DndEndSchema> {
+        AdminConversations::disconnect_shared(self).await
+    }
+    async fn get_conversation_pref(
+        &self,
+        channel_id: &str,
+    ) -> Result<crate::types::AdminConversationsGetConversationPrefsSchemaData> {
+        AdminConversations::get_conversation_pref(self, channel_id).await
+    }
+    async fn get_team(
+        &self,
+        channel_id: &str,
+        cursor: &str,
+        limit: i64,
+    ) -> Result<crate::types::AdminConversationsGetTeamsSchema> {
+        AdminConversations::get_team(self, channel_id, cursor, limit).await
+    }
+    async fn invite(&self) -> Result<crate::types::DndEndSchema> {
+        AdminConversations::invite(self).await
+    }
+    async fn rename(&self) -> Result<crate::types::DndEndSchema> {
+        AdminConversations::rename(self).await
+    }
+    async fn search(
+        &self,
+        team_ids: &str,
+        query: &str,
+        limit: i64,
+        cursor: &str,
+        search_channel_types: &str,
+        sort: &str,
+        sort_dir: &str,
+    ) -> Result<crate::types::AdminConversationsSearchSchema> {
+        AdminConversations::search(
+            self,
+            team_ids,
+            query,
+            limit,
+            cursor,
+            search_channel_types,
+            sort,
+            sort_dir,
+        )
+        .await
+    }
+    async fn set_conversation_prefs(&self) -> Result<crate::types::DndEndSchema> {
+        AdminConversations::set_conversation_prefs(self).await
+    }
+    async fn set_teams(&self) -> Result<crate::types::DndEndSchema> {
+        AdminConversations::set_teams(self).await
+    }
+    async fn unarchive(&self) -> Result<crate::types::DndEndSchema> {
+        AdminConversations::unarchive(self).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct AdminConversationsEkm {
     pub client: Client,
@@ -12,6 +10,14 @@ impl AdminConversationsEkm {
         AdminConversationsEkm { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        AdminConversationsEkm {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/admin.conversations.ekm.listOriginalConnectedChannelInfo` endpoint.
      *
@@ -34,25 +40,66 @@ impl AdminConversationsEkm {
         limit: i64,
         cursor: &str,
     ) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !channel_ids.is_empty() {
-            query_args.push(("channel_ids".to_string(), channel_ids.to_string()));
+            query_args.push(("channel_ids", channel_ids.to_string()));
         }
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !team_ids.is_empty() {
-            query_args.push(("team_ids".to_string(), team_ids.to_string()));
+            query_args.push(("team_ids", team_ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/admin.conversations.ekm.listOriginalConnectedChannelInfo?{}",
-            query_
+        let url = crate::Client::url_with_query(
+            "/admin.conversations.ekm.listOriginalConnectedChannelInfo",
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/admin.conversations.ekm.listOriginalConnectedChannelInfo",
+                &[],
+            )
+        })
+    }
+}
+
+/// The functions of [`AdminConversationsEkm`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AdminConversationsEkmApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AdminConversationsEkmApi: Send + Sync {
+    async fn list_original_connected_channel_info(
+        &self,
+        channel_ids: &str,
+        team_ids: &str,
+        limit: i64,
+        cursor: &str,
+    ) -> Result<crate::types::DndEndSchema>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AdminConversationsEkmApi for AdminConversationsEkm {
+    async fn list_original_connected_channel_info(
+        &self,
+        channel_ids: &str,
+        team_ids: &str,
+        limit: i64,
+        cursor: &str,
+    ) -> Result<crate::types::DndEndSchema> {
+        AdminConversationsEkm::list_original_connected_channel_info(
+            self,
+            channel_ids,
+            team_ids,
+            limit,
+            cursor,
+        )
+        .await
     }
 }
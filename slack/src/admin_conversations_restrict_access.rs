@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct AdminConversationsRestrictAccess {
     pub client: Client,
@@ -12,6 +10,14 @@ impl AdminConversationsRestrictAccess {
         AdminConversationsRestrictAccess { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        AdminConversationsRestrictAccess {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `POST` to the `/admin.conversations.restrictAccess.addGroup` endpoint.
      *
@@ -21,7 +27,9 @@ impl AdminConversationsRestrictAccess {
      */
     pub async fn add_group(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/admin.conversations.restrictAccess.addGroup".to_string();
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("POST", "/admin.conversations.restrictAccess.addGroup", &[])
+        })
     }
 
     /**
@@ -42,17 +50,21 @@ impl AdminConversationsRestrictAccess {
         channel_id: &str,
         team_id: &str,
     ) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !channel_id.is_empty() {
-            query_args.push(("channel_id".to_string(), channel_id.to_string()));
+            query_args.push(("channel_id", channel_id.to_string()));
         }
         if !team_id.is_empty() {
-            query_args.push(("team_id".to_string(), team_id.to_string()));
+            query_args.push(("team_id", team_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin.conversations.restrictAccess.listGroups?{}", query_);
+        let url = crate::Client::url_with_query(
+            "/admin.conversations.restrictAccess.listGroups",
+            &query_args,
+        );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/admin.conversations.restrictAccess.listGroups", &[])
+        })
     }
 
     /**
@@ -64,6 +76,45 @@ impl AdminConversationsRestrictAccess {
      */
     pub async fn remove_group(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/admin.conversations.restrictAccess.removeGroup".to_string();
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/admin.conversations.restrictAccess.removeGroup",
+                &[],
+            )
+        })
+    }
+}
+
+/// The functions of [`AdminConversationsRestrictAccess`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AdminConversationsRestrictAccessApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AdminConversationsRestrictAccessApi: Send + Sync {
+    async fn add_group(&self) -> Result<crate::types::DndEndSchema>;
+    async fn list_group(
+        &self,
+        channel_id: &str,
+        team_id: &str,
+    ) -> Result<crate::types::DndEndSchema>;
+    async fn remove_group(&self) -> Result<crate::types::DndEndSchema>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AdminConversationsRestrictAccessApi for AdminConversationsRestrictAccess {
+    async fn add_group(&self) -> Result<crate::types::DndEndSchema> {
+        AdminConversationsRestrictAccess::add_group(self).await
+    }
+    async fn list_group(
+        &self,
+        channel_id: &str,
+        team_id: &str,
+    ) -> Result<crate::types::DndEndSchema> {
+        AdminConversationsRestrictAccess::list_group(self, channel_id, team_id).await
+    }
+    async fn remove_group(&self) -> Result<crate::types::DndEndSchema> {
+        AdminConversationsRestrictAccess::remove_group(self).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct AdminEmoji {
     pub client: Client,
@@ -12,6 +10,14 @@ impl AdminEmoji {
         AdminEmoji { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        AdminEmoji {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `POST` to the `/admin.emoji.add` endpoint.
      *
@@ -21,7 +27,10 @@ impl AdminEmoji {
      */
     pub async fn add(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/admin.emoji.add".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/admin.emoji.add", &[]))
     }
 
     /**
@@ -33,7 +42,10 @@ impl AdminEmoji {
      */
     pub async fn add_alias(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/admin.emoji.addAlias".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/admin.emoji.addAlias", &[]))
     }
 
     /**
@@ -50,17 +62,19 @@ impl AdminEmoji {
      * * `limit: i64` -- The maximum number of items to return. Must be between 1 - 1000 both inclusive.
      */
     pub async fn list(&self, cursor: &str, limit: i64) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin.emoji.list?{}", query_);
+        let url = crate::Client::url_with_query("/admin.emoji.list", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/admin.emoji.list", &[]))
     }
 
     /**
@@ -72,7 +86,10 @@ impl AdminEmoji {
      */
     pub async fn remove(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/admin.emoji.remove".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/admin.emoji.remove", &[]))
     }
 
     /**
@@ -84,6 +101,42 @@ impl AdminEmoji {
      */
     pub async fn rename(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/admin.emoji.rename".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/admin.emoji.rename", &[]))
+    }
+}
+
+/// The functions of [`AdminEmoji`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AdminEmojiApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AdminEmojiApi: Send + Sync {
+    async fn add(&self) -> Result<crate::types::DndEndSchema>;
+    async fn add_alias(&self) -> Result<crate::types::DndEndSchema>;
+    async fn list(&self, cursor: &str, limit: i64) -> Result<crate::types::DndEndSchema>;
+    async fn remove(&self) -> Result<crate::types::DndEndSchema>;
+    async fn rename(&self) -> Result<crate::types::DndEndSchema>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AdminEmojiApi for AdminEmoji {
+    async fn add(&self) -> Result<crate::types::DndEndSchema> {
+        AdminEmoji::add(self).await
+    }
+    async fn add_alias(&self) -> Result<crate::types::DndEndSchema> {
+        AdminEmoji::add_alias(self).await
+    }
+    async fn list(&self, cursor: &str, limit: i64) -> Result<crate::types::DndEndSchema> {
+        AdminEmoji::list(self, cursor, limit).await
+    }
+    async fn remove(&self) -> Result<crate::types::DndEndSchema> {
+        AdminEmoji::remove(self).await
+    }
+    async fn rename(&self) -> Result<crate::types::DndEndSchema> {
+        AdminEmoji::rename(self).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct AdminInviteRequests {
     pub client: Client,
@@ -12,6 +10,14 @@ impl AdminInviteRequests {
         AdminInviteRequests { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        AdminInviteRequests {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `POST` to the `/admin.inviteRequests.approve` endpoint.
      *
@@ -29,8 +35,9 @@ impl AdminInviteRequests {
     ) -> Result<crate::types::DndEndSchema> {
         let url = "/admin.inviteRequests.approve".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/admin.inviteRequests.approve", &[]))
     }
 
     /**
@@ -50,8 +57,9 @@ impl AdminInviteRequests {
     ) -> Result<crate::types::DndEndSchema> {
         let url = "/admin.inviteRequests.deny".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/admin.inviteRequests.deny", &[]))
     }
 
     /**
@@ -74,19 +82,68 @@ impl AdminInviteRequests {
         cursor: &str,
         limit: i64,
     ) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !team_id.is_empty() {
-            query_args.push(("team_id".to_string(), team_id.to_string()));
+            query_args.push(("team_id", team_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin.inviteRequests.list?{}", query_);
+        let url = crate::Client::url_with_query("/admin.inviteRequests.list", &query_args);
+
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/admin.inviteRequests.list", &[]))
+    }
+}
+
+/// The functions of [`AdminInviteRequests`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AdminInviteRequestsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AdminInviteRequestsApi: Send + Sync {
+    async fn approve(
+        &self,
+        body: &crate::types::AdminInviteRequestsApproveRequest,
+    ) -> Result<crate::types::DndEndSchema>;
+    async fn deny(
+        &self,
+        body: &crate::types::AdminInviteRequestsApproveRequest,
+    ) -> Result<crate::types::DndEndSchema>;
+    async fn list(
+        &self,
+        team_id: &str,
+        cursor: &str,
+        limit: i64,
+    ) -> Result<crate::types::DndEndSchema>;
+}
 
-        self.client.get(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AdminInviteRequestsApi for AdminInviteRequests {
+    async fn approve(
+        &self,
+        body: &crate::types::AdminInviteRequestsApproveRequest,
+    ) -> Result<crate::types::DndEndSchema> {
+        AdminInviteRequests::approve(self, body).await
+    }
+    async fn deny(
+        &self,
+        body: &crate::types::AdminInviteRequestsApproveRequest,
+    ) -> Result<crate::types::DndEndSchema> {
+        AdminInviteRequests::deny(self, body).await
+    }
+    async fn list(
+        &self,
+        team_id: &str,
+        cursor: &str,
+        limit: i64,
+    ) -> Result<crate::types::DndEndSchema> {
+        AdminInviteRequests::list(self, team_id, cursor, limit).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct AdminTeams {
     pub client: Client,
@@ -12,6 +10,14 @@ impl AdminTeams {
         AdminTeams { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        AdminTeams {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `POST` to the `/admin.teams.create` endpoint.
      *
@@ -25,7 +31,10 @@ impl AdminTeams {
      */
     pub async fn create(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/admin.teams.create".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/admin.teams.create", &[]))
     }
 
     /**
@@ -42,16 +51,39 @@ impl AdminTeams {
      * * `cursor: &str` -- Set `cursor` to `next_cursor` returned by the previous call to list items in the next page.
      */
     pub async fn list(&self, limit: i64, cursor: &str) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin.teams.list?{}", query_);
+        let url = crate::Client::url_with_query("/admin.teams.list", &query_args);
+
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/admin.teams.list", &[]))
+    }
+}
 
-        self.client.get(&url, None).await
+/// The functions of [`AdminTeams`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AdminTeamsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AdminTeamsApi: Send + Sync {
+    async fn create(&self) -> Result<crate::types::DndEndSchema>;
+    async fn list(&self, limit: i64, cursor: &str) -> Result<crate::types::DndEndSchema>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AdminTeamsApi for AdminTeams {
+    async fn create(&self) -> Result<crate::types::DndEndSchema> {
+        AdminTeams::create(self).await
+    }
+    async fn list(&self, limit: i64, cursor: &str) -> Result<crate::types::DndEndSchema> {
+        AdminTeams::list(self, limit, cursor).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct AdminTeamsSettings {
     pub client: Client,
@@ -12,6 +10,14 @@ impl AdminTeamsSettings {
         AdminTeamsSettings { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        AdminTeamsSettings {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/admin.teams.settings.info` endpoint.
      *
@@ -25,14 +31,16 @@ impl AdminTeamsSettings {
      * * `team_id: &str`
      */
     pub async fn info(&self, team_id: &str) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !team_id.is_empty() {
-            query_args.push(("team_id".to_string(), team_id.to_string()));
+            query_args.push(("team_id", team_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin.teams.settings.info?{}", query_);
+        let url = crate::Client::url_with_query("/admin.teams.settings.info", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/admin.teams.settings.info", &[]))
     }
 
     /**
@@ -44,7 +52,9 @@ impl AdminTeamsSettings {
      */
     pub async fn set_default_channels(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/admin.teams.settings.setDefaultChannels".to_string();
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("POST", "/admin.teams.settings.setDefaultChannels", &[])
+        })
     }
 
     /**
@@ -60,7 +70,9 @@ impl AdminTeamsSettings {
      */
     pub async fn set_description(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/admin.teams.settings.setDescription".to_string();
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("POST", "/admin.teams.settings.setDescription", &[])
+        })
     }
 
     /**
@@ -76,7 +88,9 @@ impl AdminTeamsSettings {
      */
     pub async fn set_discoverability(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/admin.teams.settings.setDiscoverability".to_string();
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("POST", "/admin.teams.settings.setDiscoverability", &[])
+        })
     }
 
     /**
@@ -88,7 +102,10 @@ impl AdminTeamsSettings {
      */
     pub async fn set_icon(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/admin.teams.settings.setIcon".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/admin.teams.settings.setIcon", &[]))
     }
 
     /**
@@ -104,6 +121,46 @@ impl AdminTeamsSettings {
      */
     pub async fn set_name(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/admin.teams.settings.setName".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/admin.teams.settings.setName", &[]))
+    }
+}
+
+/// The functions of [`AdminTeamsSettings`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AdminTeamsSettingsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AdminTeamsSettingsApi: Send + Sync {
+    async fn info(&self, team_id: &str) -> Result<crate::types::DndEndSchema>;
+    async fn set_default_channels(&self) -> Result<crate::types::DndEndSchema>;
+    async fn set_description(&self) -> Result<crate::types::DndEndSchema>;
+    async fn set_discoverability(&self) -> Result<crate::types::DndEndSchema>;
+    async fn set_icon(&self) -> Result<crate::types::DndEndSchema>;
+    async fn set_name(&self) -> Result<crate::types::DndEndSchema>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AdminTeamsSettingsApi for AdminTeamsSettings {
+    async fn info(&self, team_id: &str) -> Result<crate::types::DndEndSchema> {
+        AdminTeamsSettings::info(self, team_id).await
+    }
+    async fn set_default_channels(&self) -> Result<crate::types::DndEndSchema> {
+        AdminTeamsSettings::set_default_channels(self).await
+    }
+    async fn set_description(&self) -> Result<crate::types::DndEndSchema> {
+        AdminTeamsSettings::set_description(self).await
+    }
+    async fn set_discoverability(&self) -> Result<crate::types::DndEndSchema> {
+        AdminTeamsSettings::set_discoverability(self).await
+    }
+    async fn set_icon(&self) -> Result<crate::types::DndEndSchema> {
+        AdminTeamsSettings::set_icon(self).await
+    }
+    async fn set_name(&self) -> Result<crate::types::DndEndSchema> {
+        AdminTeamsSettings::set_name(self).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct AdminUsergroups {
     pub client: Client,
@@ -12,6 +10,14 @@ impl AdminUsergroups {
         AdminUsergroups { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        AdminUsergroups {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `POST` to the `/admin.usergroups.addChannels` endpoint.
      *
@@ -25,7 +31,10 @@ impl AdminUsergroups {
      */
     pub async fn add_channels(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/admin.usergroups.addChannels".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/admin.usergroups.addChannels", &[]))
     }
 
     /**
@@ -41,7 +50,10 @@ impl AdminUsergroups {
      */
     pub async fn add_teams(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/admin.usergroups.addTeams".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/admin.usergroups.addTeams", &[]))
     }
 
     /**
@@ -64,23 +76,22 @@ impl AdminUsergroups {
         team_id: &str,
         include_num_members: bool,
     ) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if include_num_members {
-            query_args.push((
-                "include_num_members".to_string(),
-                include_num_members.to_string(),
-            ));
+            query_args.push(("include_num_members", include_num_members.to_string()));
         }
         if !team_id.is_empty() {
-            query_args.push(("team_id".to_string(), team_id.to_string()));
+            query_args.push(("team_id", team_id.to_string()));
         }
         if !usergroup_id.is_empty() {
-            query_args.push(("usergroup_id".to_string(), usergroup_id.to_string()));
+            query_args.push(("usergroup_id", usergroup_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin.usergroups.listChannels?{}", query_);
+        let url = crate::Client::url_with_query("/admin.usergroups.listChannels", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/admin.usergroups.listChannels", &[]))
     }
 
     /**
@@ -96,6 +107,47 @@ impl AdminUsergroups {
      */
     pub async fn remove_channels(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/admin.usergroups.removeChannels".to_string();
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("POST", "/admin.usergroups.removeChannels", &[])
+        })
+    }
+}
+
+/// The functions of [`AdminUsergroups`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AdminUsergroupsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AdminUsergroupsApi: Send + Sync {
+    async fn add_channels(&self) -> Result<crate::types::DndEndSchema>;
+    async fn add_teams(&self) -> Result<crate::types::DndEndSchema>;
+    async fn list_channel(
+        &self,
+        usergroup_id: &str,
+        team_id: &str,
+        include_num_members: bool,
+    ) -> Result<crate::types::DndEndSchema>;
+    async fn remove_channels(&self) -> Result<crate::types::DndEndSchema>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AdminUsergroupsApi for AdminUsergroups {
+    async fn add_channels(&self) -> Result<crate::types::DndEndSchema> {
+        AdminUsergroups::add_channels(self).await
+    }
+    async fn add_teams(&self) -> Result<crate::types::DndEndSchema> {
+        AdminUsergroups::add_teams(self).await
+    }
+    async fn list_channel(
+        &self,
+        usergroup_id: &str,
+        team_id: &str,
+        include_num_members: bool,
+    ) -> Result<crate::types::DndEndSchema> {
+        AdminUsergroups::list_channel(self, usergroup_id, team_id, include_num_members).await
+    }
+    async fn remove_channels(&self) -> Result<crate::types::DndEndSchema> {
+        AdminUsergroups::remove_channels(self).await
     }
 }
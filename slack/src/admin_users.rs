@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct AdminUsers {
     pub client: Client,
@@ -12,6 +10,14 @@ impl AdminUsers {
         AdminUsers { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        AdminUsers {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `POST` to the `/admin.users.assign` endpoint.
      *
@@ -25,7 +31,10 @@ impl AdminUsers {
      */
     pub async fn assign(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/admin.users.assign".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/admin.users.assign", &[]))
     }
 
     /**
@@ -41,7 +50,10 @@ impl AdminUsers {
      */
     pub async fn invite(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/admin.users.invite".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/admin.users.invite", &[]))
     }
 
     /**
@@ -64,20 +76,22 @@ impl AdminUsers {
         cursor: &str,
         limit: i64,
     ) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !team_id.is_empty() {
-            query_args.push(("team_id".to_string(), team_id.to_string()));
+            query_args.push(("team_id", team_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/admin.users.list?{}", query_);
+        let url = crate::Client::url_with_query("/admin.users.list", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/admin.users.list", &[]))
     }
 
     /**
@@ -93,7 +107,10 @@ impl AdminUsers {
      */
     pub async fn remove(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/admin.users.remove".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/admin.users.remove", &[]))
     }
 
     /**
@@ -109,7 +126,10 @@ impl AdminUsers {
      */
     pub async fn set(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/admin.users.setAdmin".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/admin.users.setAdmin", &[]))
     }
 
     /**
@@ -125,7 +145,10 @@ impl AdminUsers {
      */
     pub async fn set_expiration(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/admin.users.setExpiration".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/admin.users.setExpiration", &[]))
     }
 
     /**
@@ -141,7 +164,10 @@ impl AdminUsers {
      */
     pub async fn set_owner(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/admin.users.setOwner".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/admin.users.setOwner", &[]))
     }
 
     /**
@@ -157,6 +183,64 @@ impl AdminUsers {
      */
     pub async fn set_regular(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/admin.users.setRegular".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/admin.users.setRegular", &[]))
+    }
+}
+
+/// The functions of [`AdminUsers`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AdminUsersApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AdminUsersApi: Send + Sync {
+    async fn assign(&self) -> Result<crate::types::DndEndSchema>;
+    async fn invite(&self) -> Result<crate::types::DndEndSchema>;
+    async fn list(
+        &self,
+        team_id: &str,
+        cursor: &str,
+        limit: i64,
+    ) -> Result<crate::types::DndEndSchema>;
+    async fn remove(&self) -> Result<crate::types::DndEndSchema>;
+    async fn set(&self) -> Result<crate::types::DndEndSchema>;
+    async fn set_expiration(&self) -> Result<crate::types::DndEndSchema>;
+    async fn set_owner(&self) -> Result<crate::types::DndEndSchema>;
+    async fn set_regular(&self) -> Result<crate::types::DndEndSchema>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AdminUsersApi for AdminUsers {
+    async fn assign(&self) -> Result<crate::types::DndEndSchema> {
+        AdminUsers::assign(self).await
+    }
+    async fn invite(&self) -> Result<crate::types::DndEndSchema> {
+        AdminUsers::invite(self).await
+    }
+    async fn list(
+        &self,
+        team_id: &str,
+        cursor: &str,
+        limit: i64,
+    ) -> Result<crate::types::DndEndSchema> {
+        AdminUsers::list(self, team_id, cursor, limit).await
+    }
+    async fn remove(&self) -> Result<crate::types::DndEndSchema> {
+        AdminUsers::remove(self).await
+    }
+    async fn set(&self) -> Result<crate::types::DndEndSchema> {
+        AdminUsers::set(self).await
+    }
+    async fn set_expiration(&self) -> Result<crate::types::DndEndSchema> {
+        AdminUsers::set_expiration(self).await
+    }
+    async fn set_owner(&self) -> Result<crate::types::DndEndSchema> {
+        AdminUsers::set_owner(self).await
+    }
+    async fn set_regular(&self) -> Result<crate::types::DndEndSchema> {
+        AdminUsers::set_regular(self).await
     }
 }
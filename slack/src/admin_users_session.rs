@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct AdminUsersSession {
     pub client: Client,
@@ -12,6 +10,14 @@ impl AdminUsersSession {
         AdminUsersSession { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        AdminUsersSession {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `POST` to the `/admin.users.session.invalidate` endpoint.
      *
@@ -25,7 +31,9 @@ impl AdminUsersSession {
      */
     pub async fn invalidate(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/admin.users.session.invalidate".to_string();
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("POST", "/admin.users.session.invalidate", &[])
+        })
     }
 
     /**
@@ -41,6 +49,30 @@ impl AdminUsersSession {
      */
     pub async fn reset(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/admin.users.session.reset".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/admin.users.session.reset", &[]))
+    }
+}
+
+/// The functions of [`AdminUsersSession`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AdminUsersSessionApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AdminUsersSessionApi: Send + Sync {
+    async fn invalidate(&self) -> Result<crate::types::DndEndSchema>;
+    async fn reset(&self) -> Result<crate::types::DndEndSchema>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AdminUsersSessionApi for AdminUsersSession {
+    async fn invalidate(&self) -> Result<crate::types::DndEndSchema> {
+        AdminUsersSession::invalidate(self).await
+    }
+    async fn reset(&self) -> Result<crate::types::DndEndSchema> {
+        AdminUsersSession::reset(self).await
     }
 }
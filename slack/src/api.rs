@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Api {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Api {
         Api { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Api {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/api.test` endpoint.
      *
@@ -25,16 +31,35 @@ impl Api {
      * * `foo_: &str` -- example property to return.
      */
     pub async fn test(&self, error: &str, foo_: &str) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !error.is_empty() {
-            query_args.push(("error".to_string(), error.to_string()));
+            query_args.push(("error", error.to_string()));
         }
         if !foo_.is_empty() {
-            query_args.push(("foo".to_string(), foo_.to_string()));
+            query_args.push(("foo", foo_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/api.test?{}", query_);
+        let url = crate::Client::url_with_query("/api.test", &query_args);
+
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/api.test", &[]))
+    }
+}
+
+/// The functions of [`Api`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ApiApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ApiApi: Send + Sync {
+    async fn test(&self, error: &str, foo_: &str) -> Result<crate::types::DndEndSchema>;
+}
 
-        self.client.get(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ApiApi for Api {
+    async fn test(&self, error: &str, foo_: &str) -> Result<crate::types::DndEndSchema> {
+        Api::test(self, error, foo_).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Apps {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Apps {
         Apps { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Apps {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/apps.uninstall` endpoint.
      *
@@ -30,16 +36,43 @@ impl Apps {
         client_id: &str,
         client_secret: &str,
     ) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !client_id.is_empty() {
-            query_args.push(("client_id".to_string(), client_id.to_string()));
+            query_args.push(("client_id", client_id.to_string()));
         }
         if !client_secret.is_empty() {
-            query_args.push(("client_secret".to_string(), client_secret.to_string()));
+            query_args.push(("client_secret", client_secret.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/apps.uninstall?{}", query_);
+        let url = crate::Client::url_with_query("/apps.uninstall", &query_args);
+
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/apps.uninstall", &[]))
+    }
+}
+
+/// The functions of [`Apps`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AppsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AppsApi: Send + Sync {
+    async fn uninstall(
+        &self,
+        client_id: &str,
+        client_secret: &str,
+    ) -> Result<crate::types::DndEndSchema>;
+}
 
-        self.client.get(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AppsApi for Apps {
+    async fn uninstall(
+        &self,
+        client_id: &str,
+        client_secret: &str,
+    ) -> Result<crate::types::DndEndSchema> {
+        Apps::uninstall(self, client_id, client_secret).await
     }
 }
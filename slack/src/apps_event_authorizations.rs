@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct AppsEventAuthorizations {
     pub client: Client,
@@ -12,6 +10,14 @@ impl AppsEventAuthorizations {
         AppsEventAuthorizations { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        AppsEventAuthorizations {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/apps.event.authorizations.list` endpoint.
      *
@@ -32,19 +38,47 @@ impl AppsEventAuthorizations {
         cursor: &str,
         limit: i64,
     ) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if !event_context.is_empty() {
-            query_args.push(("event_context".to_string(), event_context.to_string()));
+            query_args.push(("event_context", event_context.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/apps.event.authorizations.list?{}", query_);
+        let url = crate::Client::url_with_query("/apps.event.authorizations.list", &query_args);
+
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/apps.event.authorizations.list", &[])
+        })
+    }
+}
+
+/// The functions of [`AppsEventAuthorizations`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AppsEventAuthorizationsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AppsEventAuthorizationsApi: Send + Sync {
+    async fn list(
+        &self,
+        event_context: &str,
+        cursor: &str,
+        limit: i64,
+    ) -> Result<crate::types::DndEndSchema>;
+}
 
-        self.client.get(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AppsEventAuthorizationsApi for AppsEventAuthorizations {
+    async fn list(
+        &self,
+        event_context: &str,
+        cursor: &str,
+        limit: i64,
+    ) -> Result<crate::types::DndEndSchema> {
+        AppsEventAuthorizations::list(self, event_context, cursor, limit).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct AppsPermissions {
     pub client: Client,
@@ -12,6 +10,14 @@ impl AppsPermissions {
         AppsPermissions { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        AppsPermissions {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/apps.permissions.info` endpoint.
      *
@@ -25,7 +31,10 @@ impl AppsPermissions {
      */
     pub async fn info(&self) -> Result<crate::types::AppsPermissionsInfoSchema> {
         let url = "/apps.permissions.info".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/apps.permissions.info", &[]))
     }
 
     /**
@@ -46,16 +55,39 @@ impl AppsPermissions {
         scopes: &str,
         trigger_id: &str,
     ) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !scopes.is_empty() {
-            query_args.push(("scopes".to_string(), scopes.to_string()));
+            query_args.push(("scopes", scopes.to_string()));
         }
         if !trigger_id.is_empty() {
-            query_args.push(("trigger_id".to_string(), trigger_id.to_string()));
+            query_args.push(("trigger_id", trigger_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/apps.permissions.request?{}", query_);
+        let url = crate::Client::url_with_query("/apps.permissions.request", &query_args);
+
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/apps.permissions.request", &[]))
+    }
+}
 
-        self.client.get(&url, None).await
+/// The functions of [`AppsPermissions`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AppsPermissionsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AppsPermissionsApi: Send + Sync {
+    async fn info(&self) -> Result<crate::types::AppsPermissionsInfoSchema>;
+    async fn request(&self, scopes: &str, trigger_id: &str) -> Result<crate::types::DndEndSchema>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AppsPermissionsApi for AppsPermissions {
+    async fn info(&self) -> Result<crate::types::AppsPermissionsInfoSchema> {
+        AppsPermissions::info(self).await
+    }
+    async fn request(&self, scopes: &str, trigger_id: &str) -> Result<crate::types::DndEndSchema> {
+        AppsPermissions::request(self, scopes, trigger_id).await
     }
 }
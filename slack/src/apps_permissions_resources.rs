@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct AppsPermissionsResources {
     pub client: Client,
@@ -12,6 +10,14 @@ impl AppsPermissionsResources {
         AppsPermissionsResources { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        AppsPermissionsResources {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/apps.permissions.resources.list` endpoint.
      *
@@ -30,16 +36,42 @@ impl AppsPermissionsResources {
         cursor: &str,
         limit: i64,
     ) -> Result<crate::types::AppsPermissionsResourcesListSuccessSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/apps.permissions.resources.list?{}", query_);
+        let url = crate::Client::url_with_query("/apps.permissions.resources.list", &query_args);
+
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/apps.permissions.resources.list", &[])
+        })
+    }
+}
+
+/// The functions of [`AppsPermissionsResources`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AppsPermissionsResourcesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AppsPermissionsResourcesApi: Send + Sync {
+    async fn list(
+        &self,
+        cursor: &str,
+        limit: i64,
+    ) -> Result<crate::types::AppsPermissionsResourcesListSuccessSchema>;
+}
 
-        self.client.get(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AppsPermissionsResourcesApi for AppsPermissionsResources {
+    async fn list(
+        &self,
+        cursor: &str,
+        limit: i64,
+    ) -> Result<crate::types::AppsPermissionsResourcesListSuccessSchema> {
+        AppsPermissionsResources::list(self, cursor, limit).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct AppsPermissionsScopes {
     pub client: Client,
@@ -12,6 +10,14 @@ impl AppsPermissionsScopes {
         AppsPermissionsScopes { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        AppsPermissionsScopes {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/apps.permissions.scopes.list` endpoint.
      *
@@ -25,6 +31,26 @@ impl AppsPermissionsScopes {
      */
     pub async fn list(&self) -> Result<crate::types::ApiPermissionsScopesListSuccessSchema> {
         let url = "/apps.permissions.scopes.list".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/apps.permissions.scopes.list", &[]))
+    }
+}
+
+/// The functions of [`AppsPermissionsScopes`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AppsPermissionsScopesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AppsPermissionsScopesApi: Send + Sync {
+    async fn list(&self) -> Result<crate::types::ApiPermissionsScopesListSuccessSchema>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AppsPermissionsScopesApi for AppsPermissionsScopes {
+    async fn list(&self) -> Result<crate::types::ApiPermissionsScopesListSuccessSchema> {
+        AppsPermissionsScopes::list(self).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct AppsPermissionsUsers {
     pub client: Client,
@@ -12,6 +10,14 @@ impl AppsPermissionsUsers {
         AppsPermissionsUsers { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        AppsPermissionsUsers {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/apps.permissions.users.list` endpoint.
      *
@@ -26,17 +32,19 @@ impl AppsPermissionsUsers {
      * * `limit: i64` -- The maximum number of items to return.
      */
     pub async fn list(&self, cursor: &str, limit: i64) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/apps.permissions.users.list?{}", query_);
+        let url = crate::Client::url_with_query("/apps.permissions.users.list", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/apps.permissions.users.list", &[]))
     }
 
     /**
@@ -59,19 +67,51 @@ impl AppsPermissionsUsers {
         trigger_id: &str,
         user: &str,
     ) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !scopes.is_empty() {
-            query_args.push(("scopes".to_string(), scopes.to_string()));
+            query_args.push(("scopes", scopes.to_string()));
         }
         if !trigger_id.is_empty() {
-            query_args.push(("trigger_id".to_string(), trigger_id.to_string()));
+            query_args.push(("trigger_id", trigger_id.to_string()));
         }
         if !user.is_empty() {
-            query_args.push(("user".to_string(), user.to_string()));
+            query_args.push(("user", user.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/apps.permissions.users.request?{}", query_);
+        let url = crate::Client::url_with_query("/apps.permissions.users.request", &query_args);
+
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/apps.permissions.users.request", &[])
+        })
+    }
+}
 
-        self.client.get(&url, None).await
+/// The functions of [`AppsPermissionsUsers`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AppsPermissionsUsersApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AppsPermissionsUsersApi: Send + Sync {
+    async fn list(&self, cursor: &str, limit: i64) -> Result<crate::types::DndEndSchema>;
+    async fn request(
+        &self,
+        scopes: &str,
+        trigger_id: &str,
+        user: &str,
+    ) -> Result<crate::types::DndEndSchema>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AppsPermissionsUsersApi for AppsPermissionsUsers {
+    async fn list(&self, cursor: &str, limit: i64) -> Result<crate::types::DndEndSchema> {
+        AppsPermissionsUsers::list(self, cursor, limit).await
+    }
+    async fn request(
+        &self,
+        scopes: &str,
+        trigger_id: &str,
+        user: &str,
+    ) -> Result<crate::types::DndEndSchema> {
+        AppsPermissionsUsers::request(self, scopes, trigger_id, user).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Auth {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Auth {
         Auth { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Auth {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/auth.revoke` endpoint.
      *
@@ -25,14 +31,16 @@ impl Auth {
      * * `test: bool` -- Setting this parameter to `1` triggers a _testing mode_ where the specified token will not actually be revoked.
      */
     pub async fn revoke(&self, test: bool) -> Result<crate::types::AuthRevokeSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if test {
-            query_args.push(("test".to_string(), test.to_string()));
+            query_args.push(("test", test.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/auth.revoke?{}", query_);
+        let url = crate::Client::url_with_query("/auth.revoke", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/auth.revoke", &[]))
     }
 
     /**
@@ -48,6 +56,30 @@ impl Auth {
      */
     pub async fn test(&self) -> Result<crate::types::AuthTestSuccessSchema> {
         let url = "/auth.test".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/auth.test", &[]))
+    }
+}
+
+/// The functions of [`Auth`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AuthApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AuthApi: Send + Sync {
+    async fn revoke(&self, test: bool) -> Result<crate::types::AuthRevokeSchema>;
+    async fn test(&self) -> Result<crate::types::AuthTestSuccessSchema>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AuthApi for Auth {
+    async fn revoke(&self, test: bool) -> Result<crate::types::AuthRevokeSchema> {
+        Auth::revoke(self, test).await
+    }
+    async fn test(&self) -> Result<crate::types::AuthTestSuccessSchema> {
+        Auth::test(self).await
     }
 }
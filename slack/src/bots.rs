@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Bots {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Bots {
         Bots { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Bots {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/bots.info` endpoint.
      *
@@ -25,13 +31,32 @@ impl Bots {
      * * `bot: &str` -- Bot user to get info on.
      */
     pub async fn info(&self, bot: &str) -> Result<crate::types::BotsInfoSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !bot.is_empty() {
-            query_args.push(("bot".to_string(), bot.to_string()));
+            query_args.push(("bot", bot.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/bots.info?{}", query_);
+        let url = crate::Client::url_with_query("/bots.info", &query_args);
+
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/bots.info", &[]))
+    }
+}
+
+/// The functions of [`Bots`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn BotsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait BotsApi: Send + Sync {
+    async fn info(&self, bot: &str) -> Result<crate::types::BotsInfoSchema>;
+}
 
-        self.client.get(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl BotsApi for Bots {
+    async fn info(&self, bot: &str) -> Result<crate::types::BotsInfoSchema> {
+        Bots::info(self, bot).await
     }
 }
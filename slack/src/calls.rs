@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Calls {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Calls {
         Calls { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Calls {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `POST` to the `/calls.add` endpoint.
      *
@@ -25,7 +31,10 @@ impl Calls {
      */
     pub async fn add(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/calls.add".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/calls.add", &[]))
     }
 
     /**
@@ -41,7 +50,10 @@ impl Calls {
      */
     pub async fn end(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/calls.end".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/calls.end", &[]))
     }
 
     /**
@@ -57,14 +69,16 @@ impl Calls {
      * * `id: &str` -- `id` of the Call returned by the [`calls.add`](/methods/calls.add) method.
      */
     pub async fn info(&self, id: &str) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !id.is_empty() {
-            query_args.push(("id".to_string(), id.to_string()));
+            query_args.push(("id", id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/calls.info?{}", query_);
+        let url = crate::Client::url_with_query("/calls.info", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/calls.info", &[]))
     }
 
     /**
@@ -80,6 +94,38 @@ impl Calls {
      */
     pub async fn update(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/calls.update".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/calls.update", &[]))
+    }
+}
+
+/// The functions of [`Calls`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn CallsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait CallsApi: Send + Sync {
+    async fn add(&self) -> Result<crate::types::DndEndSchema>;
+    async fn end(&self) -> Result<crate::types::DndEndSchema>;
+    async fn info(&self, id: &str) -> Result<crate::types::DndEndSchema>;
+    async fn update(&self) -> Result<crate::types::DndEndSchema>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl CallsApi for Calls {
+    async fn add(&self) -> Result<crate::types::DndEndSchema> {
+        Calls::add(self).await
+    }
+    async fn end(&self) -> Result<crate::types::DndEndSchema> {
+        Calls::end(self).await
+    }
+    async fn info(&self, id: &str) -> Result<crate::types::DndEndSchema> {
+        Calls::info(self, id).await
+    }
+    async fn update(&self) -> Result<crate::types::DndEndSchema> {
+        Calls::update(self).await
     }
 }
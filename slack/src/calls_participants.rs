@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct CallsParticipants {
     pub client: Client,
@@ -12,6 +10,14 @@ impl CallsParticipants {
         CallsParticipants { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        CallsParticipants {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `POST` to the `/calls.participants.add` endpoint.
      *
@@ -25,7 +31,10 @@ impl CallsParticipants {
      */
     pub async fn add(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/calls.participants.add".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/calls.participants.add", &[]))
     }
 
     /**
@@ -41,6 +50,30 @@ impl CallsParticipants {
      */
     pub async fn remove(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/calls.participants.remove".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/calls.participants.remove", &[]))
+    }
+}
+
+/// The functions of [`CallsParticipants`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn CallsParticipantsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait CallsParticipantsApi: Send + Sync {
+    async fn add(&self) -> Result<crate::types::DndEndSchema>;
+    async fn remove(&self) -> Result<crate::types::DndEndSchema>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl CallsParticipantsApi for CallsParticipants {
+    async fn add(&self) -> Result<crate::types::DndEndSchema> {
+        CallsParticipants::add(self).await
+    }
+    async fn remove(&self) -> Result<crate::types::DndEndSchema> {
+        CallsParticipants::remove(self).await
     }
 }
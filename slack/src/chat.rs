@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Chat {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Chat {
         Chat { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Chat {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `POST` to the `/chat.delete` endpoint.
      *
@@ -25,7 +31,10 @@ impl Chat {
      */
     pub async fn delete(&self) -> Result<crate::types::ChatDeleteSuccessSchema> {
         let url = "/chat.delete".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/chat.delete", &[]))
     }
 
     /**
@@ -41,7 +50,10 @@ impl Chat {
      */
     pub async fn delete_scheduled_message(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/chat.deleteScheduledMessage".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/chat.deleteScheduledMessage", &[]))
     }
 
     /**
@@ -62,17 +74,19 @@ impl Chat {
         channel: &str,
         message_ts: &str,
     ) -> Result<crate::types::ChatGetPermalinkSuccessSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !channel.is_empty() {
-            query_args.push(("channel".to_string(), channel.to_string()));
+            query_args.push(("channel", channel.to_string()));
         }
         if !message_ts.is_empty() {
-            query_args.push(("message_ts".to_string(), message_ts.to_string()));
+            query_args.push(("message_ts", message_ts.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/chat.getPermalink?{}", query_);
+        let url = crate::Client::url_with_query("/chat.getPermalink", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/chat.getPermalink", &[]))
     }
 
     /**
@@ -88,7 +102,10 @@ impl Chat {
      */
     pub async fn me_message(&self) -> Result<crate::types::ChatMeMessageSchema> {
         let url = "/chat.meMessage".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/chat.meMessage", &[]))
     }
 
     /**
@@ -104,7 +121,10 @@ impl Chat {
      */
     pub async fn post_ephemeral(&self) -> Result<crate::types::ChatPostEphemeralSuccessSchema> {
         let url = "/chat.postEphemeral".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/chat.postEphemeral", &[]))
     }
 
     /**
@@ -120,7 +140,10 @@ impl Chat {
      */
     pub async fn post_message(&self) -> Result<crate::types::ChatPostMessageSuccessSchema> {
         let url = "/chat.postMessage".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/chat.postMessage", &[]))
     }
 
     /**
@@ -136,7 +159,10 @@ impl Chat {
      */
     pub async fn schedule_message(&self) -> Result<crate::types::ChatScheduleMessageSuccessSchema> {
         let url = "/chat.scheduleMessage".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/chat.scheduleMessage", &[]))
     }
 
     /**
@@ -152,7 +178,10 @@ impl Chat {
      */
     pub async fn unfurl(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/chat.unfurl".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/chat.unfurl", &[]))
     }
 
     /**
@@ -168,6 +197,66 @@ impl Chat {
      */
     pub async fn update(&self) -> Result<crate::types::ChatUpdateSuccessSchema> {
         let url = "/chat.update".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/chat.update", &[]))
+    }
+}
+
+/// The functions of [`Chat`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ChatApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ChatApi: Send + Sync {
+    async fn delete(&self) -> Result<crate::types::ChatDeleteSuccessSchema>;
+    async fn delete_scheduled_message(&self) -> Result<crate::types::DndEndSchema>;
+    async fn get_permalink(
+        &self,
+        channel: &str,
+        message_ts: &str,
+    ) -> Result<crate::types::ChatGetPermalinkSuccessSchema>;
+    async fn me_message(&self) -> Result<crate::types::ChatMeMessageSchema>;
+    async fn post_ephemeral(&self) -> Result<crate::types::ChatPostEphemeralSuccessSchema>;
+    async fn post_message(&self) -> Result<crate::types::ChatPostMessageSuccessSchema>;
+    async fn schedule_message(&self) -> Result<crate::types::ChatScheduleMessageSuccessSchema>;
+    async fn unfurl(&self) -> Result<crate::types::DndEndSchema>;
+    async fn update(&self) -> Result<crate::types::ChatUpdateSuccessSchema>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ChatApi for Chat {
+    async fn delete(&self) -> Result<crate::types::ChatDeleteSuccessSchema> {
+        Chat::delete(self).await
+    }
+    async fn delete_scheduled_message(&self) -> Result<crate::types::DndEndSchema> {
+        Chat::delete_scheduled_message(self).await
+    }
+    async fn get_permalink(
+        &self,
+        channel: &str,
+        message_ts: &str,
+    ) -> Result<crate::types::ChatGetPermalinkSuccessSchema> {
+        Chat::get_permalink(self, channel, message_ts).await
+    }
+    async fn me_message(&self) -> Result<crate::types::ChatMeMessageSchema> {
+        Chat::me_message(self).await
+    }
+    async fn post_ephemeral(&self) -> Result<crate::types::ChatPostEphemeralSuccessSchema> {
+        Chat::post_ephemeral(self).await
+    }
+    async fn post_message(&self) -> Result<crate::types::ChatPostMessageSuccessSchema> {
+        Chat::post_message(self).await
+    }
+    async fn schedule_message(&self) -> Result<crate::types::ChatScheduleMessageSuccessSchema> {
+        Chat::schedule_message(self).await
+    }
+    async fn unfurl(&self) -> Result<crate::types::DndEndSchema> {
+        Chat::unfurl(self).await
+    }
+    async fn update(&self) -> Result<crate::types::ChatUpdateSuccessSchema> {
+        Chat::update(self).await
     }
 }
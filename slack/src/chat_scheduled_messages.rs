@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct ChatScheduledMessages {
     pub client: Client,
@@ -12,6 +10,14 @@ impl ChatScheduledMessages {
         ChatScheduledMessages { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        ChatScheduledMessages {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/chat.scheduledMessages.list` endpoint.
      *
@@ -36,25 +42,58 @@ impl ChatScheduledMessages {
         limit: i64,
         cursor: &str,
     ) -> Result<crate::types::ChatScheduledMessagesListSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !channel.is_empty() {
-            query_args.push(("channel".to_string(), channel.to_string()));
+            query_args.push(("channel", channel.to_string()));
         }
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if !latest.to_string().is_empty() {
-            query_args.push(("latest".to_string(), latest.to_string()));
+            query_args.push(("latest", latest.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !oldest.to_string().is_empty() {
-            query_args.push(("oldest".to_string(), oldest.to_string()));
+            query_args.push(("oldest", oldest.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/chat.scheduledMessages.list?{}", query_);
+        let url = crate::Client::url_with_query("/chat.scheduledMessages.list", &query_args);
+
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/chat.scheduledMessages.list", &[]))
+    }
+}
+
+/// The functions of [`ChatScheduledMessages`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ChatScheduledMessagesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ChatScheduledMessagesApi: Send + Sync {
+    async fn list(
+        &self,
+        channel: &str,
+        latest: f64,
+        oldest: f64,
+        limit: i64,
+        cursor: &str,
+    ) -> Result<crate::types::ChatScheduledMessagesListSchema>;
+}
 
-        self.client.get(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ChatScheduledMessagesApi for ChatScheduledMessages {
+    async fn list(
+        &self,
+        channel: &str,
+        latest: f64,
+        oldest: f64,
+        limit: i64,
+        cursor: &str,
+    ) -> Result<crate::types::ChatScheduledMessagesListSchema> {
+        ChatScheduledMessages::list(self, channel, latest, oldest, limit, cursor).await
     }
 }
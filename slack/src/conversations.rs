@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Conversations {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Conversations {
         Conversations { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Conversations {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `POST` to the `/conversations.archive` endpoint.
      *
@@ -25,7 +31,10 @@ impl Conversations {
      */
     pub async fn archive(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/conversations.archive".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/conversations.archive", &[]))
     }
 
     /**
@@ -41,7 +50,10 @@ impl Conversations {
      */
     pub async fn close(&self) -> Result<crate::types::ConversationsCloseSuccessSchema> {
         let url = "/conversations.close".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/conversations.close", &[]))
     }
 
     /**
@@ -57,7 +69,10 @@ impl Conversations {
      */
     pub async fn create(&self) -> Result<crate::types::ConversationsInfoSuccessSchema> {
         let url = "/conversations.create".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/conversations.create", &[]))
     }
 
     /**
@@ -86,29 +101,31 @@ impl Conversations {
         limit: i64,
         cursor: &str,
     ) -> Result<crate::types::ConversationsHistorySuccessSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !channel.is_empty() {
-            query_args.push(("channel".to_string(), channel.to_string()));
+            query_args.push(("channel", channel.to_string()));
         }
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if inclusive {
-            query_args.push(("inclusive".to_string(), inclusive.to_string()));
+            query_args.push(("inclusive", inclusive.to_string()));
         }
         if !latest.to_string().is_empty() {
-            query_args.push(("latest".to_string(), latest.to_string()));
+            query_args.push(("latest", latest.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !oldest.to_string().is_empty() {
-            query_args.push(("oldest".to_string(), oldest.to_string()));
+            query_args.push(("oldest", oldest.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/conversations.history?{}", query_);
+        let url = crate::Client::url_with_query("/conversations.history", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/conversations.history", &[]))
     }
 
     /**
@@ -131,23 +148,22 @@ impl Conversations {
         include_locale: bool,
         include_num_members: bool,
     ) -> Result<crate::types::ConversationsInfoSuccessSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !channel.is_empty() {
-            query_args.push(("channel".to_string(), channel.to_string()));
+            query_args.push(("channel", channel.to_string()));
         }
         if include_locale {
-            query_args.push(("include_locale".to_string(), include_locale.to_string()));
+            query_args.push(("include_locale", include_locale.to_string()));
         }
         if include_num_members {
-            query_args.push((
-                "include_num_members".to_string(),
-                include_num_members.to_string(),
-            ));
+            query_args.push(("include_num_members", include_num_members.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/conversations.info?{}", query_);
+        let url = crate::Client::url_with_query("/conversations.info", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/conversations.info", &[]))
     }
 
     /**
@@ -163,7 +179,10 @@ impl Conversations {
      */
     pub async fn invite(&self) -> Result<crate::types::ConversationsInfoSuccessSchema> {
         let url = "/conversations.invite".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/conversations.invite", &[]))
     }
 
     /**
@@ -179,7 +198,10 @@ impl Conversations {
      */
     pub async fn join(&self) -> Result<crate::types::ConversationsJoinSuccessSchema> {
         let url = "/conversations.join".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/conversations.join", &[]))
     }
 
     /**
@@ -195,7 +217,10 @@ impl Conversations {
      */
     pub async fn kick(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/conversations.kick".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/conversations.kick", &[]))
     }
 
     /**
@@ -211,7 +236,10 @@ impl Conversations {
      */
     pub async fn leave(&self) -> Result<crate::types::ConversationsLeaveSuccessSchema> {
         let url = "/conversations.leave".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/conversations.leave", &[]))
     }
 
     /**
@@ -236,23 +264,25 @@ impl Conversations {
         limit: i64,
         cursor: &str,
     ) -> Result<crate::types::ConversationsListSuccessSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if exclude_archived {
-            query_args.push(("exclude_archived".to_string(), exclude_archived.to_string()));
+            query_args.push(("exclude_archived", exclude_archived.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !types.is_empty() {
-            query_args.push(("types".to_string(), types.to_string()));
+            query_args.push(("types", types.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/conversations.list?{}", query_);
+        let url = crate::Client::url_with_query("/conversations.list", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/conversations.list", &[]))
     }
 
     /**
@@ -268,7 +298,10 @@ impl Conversations {
      */
     pub async fn mark(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/conversations.mark".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/conversations.mark", &[]))
     }
 
     /**
@@ -291,20 +324,22 @@ impl Conversations {
         limit: i64,
         cursor: &str,
     ) -> Result<crate::types::ConversationsMembersSuccessSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !channel.is_empty() {
-            query_args.push(("channel".to_string(), channel.to_string()));
+            query_args.push(("channel", channel.to_string()));
         }
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/conversations.members?{}", query_);
+        let url = crate::Client::url_with_query("/conversations.members", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/conversations.members", &[]))
     }
 
     /**
@@ -320,7 +355,10 @@ impl Conversations {
      */
     pub async fn open(&self) -> Result<crate::types::ConversationsOpenSuccessSchema> {
         let url = "/conversations.open".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/conversations.open", &[]))
     }
 
     /**
@@ -336,7 +374,10 @@ impl Conversations {
      */
     pub async fn rename(&self) -> Result<crate::types::ConversationsInfoSuccessSchema> {
         let url = "/conversations.rename".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/conversations.rename", &[]))
     }
 
     /**
@@ -367,32 +408,34 @@ impl Conversations {
         limit: i64,
         cursor: &str,
     ) -> Result<crate::types::ConversationsRepliesSuccessSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !channel.is_empty() {
-            query_args.push(("channel".to_string(), channel.to_string()));
+            query_args.push(("channel", channel.to_string()));
         }
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if inclusive {
-            query_args.push(("inclusive".to_string(), inclusive.to_string()));
+            query_args.push(("inclusive", inclusive.to_string()));
         }
         if !latest.to_string().is_empty() {
-            query_args.push(("latest".to_string(), latest.to_string()));
+            query_args.push(("latest", latest.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !oldest.to_string().is_empty() {
-            query_args.push(("oldest".to_string(), oldest.to_string()));
+            query_args.push(("oldest", oldest.to_string()));
         }
         if !ts.to_string().is_empty() {
-            query_args.push(("ts".to_string(), ts.to_string()));
+            query_args.push(("ts", ts.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/conversations.replies?{}", query_);
+        let url = crate::Client::url_with_query("/conversations.replies", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/conversations.replies", &[]))
     }
 
     /**
@@ -408,7 +451,10 @@ impl Conversations {
      */
     pub async fn set_purpose(&self) -> Result<crate::types::ConversationsInfoSuccessSchema> {
         let url = "/conversations.setPurpose".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/conversations.setPurpose", &[]))
     }
 
     /**
@@ -424,7 +470,10 @@ impl Conversations {
      */
     pub async fn set_topic(&self) -> Result<crate::types::ConversationsInfoSuccessSchema> {
         let url = "/conversations.setTopic".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/conversations.setTopic", &[]))
     }
 
     /**
@@ -440,6 +489,160 @@ impl Conversations {
      */
     pub async fn unarchive(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/conversations.unarchive".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/conversations.unarchive", &[]))
+    }
+}
+
+/// The functions of [`Conversations`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ConversationsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ConversationsApi: Send + Sync {
+    async fn archive(&self) -> Result<crate::types::DndEndSchema>;
+    async fn close(&self) -> Result<crate::types::ConversationsCloseSuccessSchema>;
+    async fn create(&self) -> Result<crate::types::ConversationsInfoSuccessSchema>;
+    async fn history(
+        &self,
+        channel: &str,
+        latest: f64,
+        oldest: f64,
+        inclusive: bool,
+        limit: i64,
+        cursor: &str,
+    ) -> Result<crate::types::ConversationsHistorySuccessSchema>;
+    async fn info(
+        &self,
+        channel: &str,
+        include_locale: bool,
+        include_num_members: bool,
+    ) -> Result<crate::types::ConversationsInfoSuccessSchema>;
+    async fn invite(&self) -> Result<crate::types::ConversationsInfoSuccessSchema>;
+    async fn join(&self) -> Result<crate::types::ConversationsJoinSuccessSchema>;
+    async fn kick(&self) -> Result<crate::types::DndEndSchema>;
+    async fn leave(&self) -> Result<crate::types::ConversationsLeaveSuccessSchema>;
+    async fn list(
+        &self,
+        exclude_archived: bool,
+        types: &str,
+        limit: i64,
+        cursor: &str,
+    ) -> Result<crate::types::ConversationsListSuccessSchema>;
+    async fn mark(&self) -> Result<crate::types::DndEndSchema>;
+    async fn member(
+        &self,
+        channel: &str,
+        limit: i64,
+        cursor: &str,
+    ) -> Result<crate::types::ConversationsMembersSuccessSchema>;
+    async fn open(&self) -> Result<crate::types::ConversationsOpenSuccessSchema>;
+    async fn rename(&self) -> Result<crate::types::ConversationsInfoSuccessSchema>;
+    async fn replie(
+        &self,
+        channel: &str,
+        ts: f64,
+        latest: f64,
+        oldest: f64,
+        inclusive: bool,
+        limit: i64,
+        cursor: &str,
+    ) -> Result<crate::types::ConversationsRepliesSuccessSchema>;
+    async fn set_purpose(&self) -> Result<crate::types::ConversationsInfoSuccessSchema>;
+    async fn set_topic(&self) -> Result<crate::types::ConversationsInfoSuccessSchema>;
+    async fn unarchive(&self) -> Result<crate::types::DndEndSchema>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ConversationsApi for Conversations {
+    async fn archive(&self) -> Result<crate::types::DndEndSchema> {
+        Conversations::archive(self).await
+    }
+    async fn close(&self) -> Result<crate::types::ConversationsCloseSuccessSchema> {
+        Conversations::close(self).await
+    }
+    async fn create(&self) -> Result<crate::types::ConversationsInfoSuccessSchema> {
+        Conversations::create(self).await
+    }
+    async fn history(
+        &self,
+        channel: &str,
+        latest: f64,
+        oldest: f64,
+        inclusive: bool,
+        limit: i64,
+        cursor: &str,
+    ) -> Result<crate::types::ConversationsHistorySuccessSchema> {
+        Conversations::history(self, channel, latest, oldest, inclusive, limit, cursor).await
+    }
+    async fn info(
+        &self,
+        channel: &str,
+        include_locale: bool,
+        include_num_members: bool,
+    ) -> Result<crate::types::ConversationsInfoSuccessSchema> {
+        Conversations::info(self, channel, include_locale, include_num_members).await
+    }
+    async fn invite(&self) -> Result<crate::types::ConversationsInfoSuccessSchema> {
+        Conversations::invite(self).await
+    }
+    async fn join(&self) -> Result<crate::types::ConversationsJoinSuccessSchema> {
+        Conversations::join(self).await
+    }
+    async fn kick(&self) -> Result<crate::types::DndEndSchema> {
+        Conversations::kick(self).await
+    }
+    async fn leave(&self) -> Result<crate::types::ConversationsLeaveSuccessSchema> {
+        Conversations::leave(self).await
+    }
+    async fn list(
+        &self,
+        exclude_archived: bool,
+        types: &str,
+        limit: i64,
+        cursor: &str,
+    ) -> Result<crate::types::ConversationsListSuccessSchema> {
+        Conversations::list(self, exclude_archived, types, limit, cursor).await
+    }
+    async fn mark(&self) -> Result<crate::types::DndEndSchema> {
+        Conversations::mark(self).await
+    }
+    async fn member(
+        &self,
+        channel: &str,
+        limit: i64,
+        cursor: &str,
+    ) -> Result<crate::types::ConversationsMembersSuccessSchema> {
+        Conversations::member(self, channel, limit, cursor).await
+    }
+    async fn open(&self) -> Result<crate::types::ConversationsOpenSuccessSchema> {
+        Conversations::open(self).await
+    }
+    async fn rename(&self) -> Result<crate::types::ConversationsInfoSuccessSchema> {
+        Conversations::rename(self).await
+    }
+    async fn replie(
+        &self,
+        channel: &str,
+        ts: f64,
+        latest: f64,
+        oldest: f64,
+        inclusive: bool,
+        limit: i64,
+        cursor: &str,
+    ) -> Result<crate::types::ConversationsRepliesSuccessSchema> {
+        Conversations::replie(self, channel, ts, latest, oldest, inclusive, limit, cursor).await
+    }
+    async fn set_purpose(&self) -> Result<crate::types::ConversationsInfoSuccessSchema> {
+        Conversations::set_purpose(self).await
+    }
+    async fn set_topic(&self) -> Result<crate::types::ConversationsInfoSuccessSchema> {
+        Conversations::set_topic(self).await
+    }
+    async fn unarchive(&self) -> Result<crate::types::DndEndSchema> {
+        Conversations::unarchive(self).await
     }
 }
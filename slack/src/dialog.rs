@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Dialog {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Dialog {
         Dialog { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Dialog {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/dialog.open` endpoint.
      *
@@ -26,16 +32,35 @@ impl Dialog {
      * * `trigger_id: &str` -- Exchange a trigger to post to the user.
      */
     pub async fn open(&self, dialog: &str, trigger_id: &str) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !dialog.is_empty() {
-            query_args.push(("dialog".to_string(), dialog.to_string()));
+            query_args.push(("dialog", dialog.to_string()));
         }
         if !trigger_id.is_empty() {
-            query_args.push(("trigger_id".to_string(), trigger_id.to_string()));
+            query_args.push(("trigger_id", trigger_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/dialog.open?{}", query_);
+        let url = crate::Client::url_with_query("/dialog.open", &query_args);
+
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/dialog.open", &[]))
+    }
+}
+
+/// The functions of [`Dialog`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn DialogApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait DialogApi: Send + Sync {
+    async fn open(&self, dialog: &str, trigger_id: &str) -> Result<crate::types::DndEndSchema>;
+}
 
-        self.client.get(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl DialogApi for Dialog {
+    async fn open(&self, dialog: &str, trigger_id: &str) -> Result<crate::types::DndEndSchema> {
+        Dialog::open(self, dialog, trigger_id).await
     }
 }
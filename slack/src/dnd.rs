@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Dnd {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Dnd {
         Dnd { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Dnd {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `POST` to the `/dnd.endDnd` endpoint.
      *
@@ -25,7 +31,10 @@ impl Dnd {
      */
     pub async fn end(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/dnd.endDnd".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/dnd.endDnd", &[]))
     }
 
     /**
@@ -41,7 +50,10 @@ impl Dnd {
      */
     pub async fn end_snooze(&self) -> Result<crate::types::DndEndSnoozeSchema> {
         let url = "/dnd.endSnooze".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/dnd.endSnooze", &[]))
     }
 
     /**
@@ -57,14 +69,16 @@ impl Dnd {
      * * `user: &str` -- User to fetch status for (defaults to current user).
      */
     pub async fn info(&self, user: &str) -> Result<crate::types::DndInfoSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !user.is_empty() {
-            query_args.push(("user".to_string(), user.to_string()));
+            query_args.push(("user", user.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/dnd.info?{}", query_);
+        let url = crate::Client::url_with_query("/dnd.info", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/dnd.info", &[]))
     }
 
     /**
@@ -76,7 +90,10 @@ impl Dnd {
      */
     pub async fn set_snooze(&self) -> Result<crate::types::DndSetSnoozeSchema> {
         let url = "/dnd.setSnooze".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/dnd.setSnooze", &[]))
     }
 
     /**
@@ -92,13 +109,48 @@ impl Dnd {
      * * `users: &str` -- Comma-separated list of users to fetch Do Not Disturb status for.
      */
     pub async fn team_info(&self, users: &str) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !users.is_empty() {
-            query_args.push(("users".to_string(), users.to_string()));
+            query_args.push(("users", users.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/dnd.teamInfo?{}", query_);
+        let url = crate::Client::url_with_query("/dnd.teamInfo", &query_args);
+
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/dnd.teamInfo", &[]))
+    }
+}
 
-        self.client.get(&url, None).await
+/// The functions of [`Dnd`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn DndApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait DndApi: Send + Sync {
+    async fn end(&self) -> Result<crate::types::DndEndSchema>;
+    async fn end_snooze(&self) -> Result<crate::types::DndEndSnoozeSchema>;
+    async fn info(&self, user: &str) -> Result<crate::types::DndInfoSchema>;
+    async fn set_snooze(&self) -> Result<crate::types::DndSetSnoozeSchema>;
+    async fn team_info(&self, users: &str) -> Result<crate::types::DndEndSchema>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl DndApi for Dnd {
+    async fn end(&self) -> Result<crate::types::DndEndSchema> {
+        Dnd::end(self).await
+    }
+    async fn end_snooze(&self) -> Result<crate::types::DndEndSnoozeSchema> {
+        Dnd::end_snooze(self).await
+    }
+    async fn info(&self, user: &str) -> Result<crate::types::DndInfoSchema> {
+        Dnd::info(self, user).await
+    }
+    async fn set_snooze(&self) -> Result<crate::types::DndSetSnoozeSchema> {
+        Dnd::set_snooze(self).await
+    }
+    async fn team_info(&self, users: &str) -> Result<crate::types::DndEndSchema> {
+        Dnd::team_info(self, users).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Emoji {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Emoji {
         Emoji { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Emoji {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/emoji.list` endpoint.
      *
@@ -25,6 +31,26 @@ impl Emoji {
      */
     pub async fn list(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/emoji.list".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/emoji.list", &[]))
+    }
+}
+
+/// The functions of [`Emoji`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn EmojiApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait EmojiApi: Send + Sync {
+    async fn list(&self) -> Result<crate::types::DndEndSchema>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl EmojiApi for Emoji {
+    async fn list(&self) -> Result<crate::types::DndEndSchema> {
+        Emoji::list(self).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Files {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Files {
         Files { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Files {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `POST` to the `/files.delete` endpoint.
      *
@@ -25,7 +31,10 @@ impl Files {
      */
     pub async fn delete(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/files.delete".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/files.delete", &[]))
     }
 
     /**
@@ -52,26 +61,28 @@ impl Files {
         limit: i64,
         cursor: &str,
     ) -> Result<crate::types::FilesInfoSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if !file.is_empty() {
-            query_args.push(("file".to_string(), file.to_string()));
+            query_args.push(("file", file.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !page.is_empty() {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/files.info?{}", query_);
+        let url = crate::Client::url_with_query("/files.info", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/files.info", &[]))
     }
 
     /**
@@ -104,38 +115,40 @@ impl Files {
         page: &str,
         show_files_hidden_by_limit: bool,
     ) -> Result<crate::types::FilesListSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !channel.is_empty() {
-            query_args.push(("channel".to_string(), channel.to_string()));
+            query_args.push(("channel", channel.to_string()));
         }
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !page.is_empty() {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if show_files_hidden_by_limit {
             query_args.push((
-                "show_files_hidden_by_limit".to_string(),
+                "show_files_hidden_by_limit",
                 show_files_hidden_by_limit.to_string(),
             ));
         }
         if !ts_from.to_string().is_empty() {
-            query_args.push(("ts_from".to_string(), ts_from.to_string()));
+            query_args.push(("ts_from", ts_from.to_string()));
         }
         if !ts_to.to_string().is_empty() {
-            query_args.push(("ts_to".to_string(), ts_to.to_string()));
+            query_args.push(("ts_to", ts_to.to_string()));
         }
         if !types.is_empty() {
-            query_args.push(("types".to_string(), types.to_string()));
+            query_args.push(("types", types.to_string()));
         }
         if !user.is_empty() {
-            query_args.push(("user".to_string(), user.to_string()));
+            query_args.push(("user", user.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/files.list?{}", query_);
+        let url = crate::Client::url_with_query("/files.list", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/files.list", &[]))
     }
 
     /**
@@ -151,7 +164,10 @@ impl Files {
      */
     pub async fn revoke_public_url(&self) -> Result<crate::types::FilesUploadSchema> {
         let url = "/files.revokePublicURL".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/files.revokePublicURL", &[]))
     }
 
     /**
@@ -167,7 +183,10 @@ impl Files {
      */
     pub async fn shared_public_url(&self) -> Result<crate::types::FilesUploadSchema> {
         let url = "/files.sharedPublicURL".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/files.sharedPublicURL", &[]))
     }
 
     /**
@@ -179,6 +198,91 @@ impl Files {
      */
     pub async fn upload(&self) -> Result<crate::types::FilesUploadSchema> {
         let url = "/files.upload".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/files.upload", &[]))
+    }
+}
+
+/// The functions of [`Files`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn FilesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait FilesApi: Send + Sync {
+    async fn delete(&self) -> Result<crate::types::DndEndSchema>;
+    async fn info(
+        &self,
+        file: &str,
+        count: &str,
+        page: &str,
+        limit: i64,
+        cursor: &str,
+    ) -> Result<crate::types::FilesInfoSchema>;
+    async fn list(
+        &self,
+        user: &str,
+        channel: &str,
+        ts_from: f64,
+        ts_to: f64,
+        types: &str,
+        count: &str,
+        page: &str,
+        show_files_hidden_by_limit: bool,
+    ) -> Result<crate::types::FilesListSchema>;
+    async fn revoke_public_url(&self) -> Result<crate::types::FilesUploadSchema>;
+    async fn shared_public_url(&self) -> Result<crate::types::FilesUploadSchema>;
+    async fn upload(&self) -> Result<crate::types::FilesUploadSchema>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl FilesApi for Files {
+    async fn delete(&self) -> Result<crate::types::DndEndSchema> {
+        Files::delete(self).await
+    }
+    async fn info(
+        &self,
+        file: &str,
+        count: &str,
+        page: &str,
+        limit: i64,
+        cursor: &str,
+    ) -> Result<crate::types::FilesInfoSchema> {
+        Files::info(self, file, count, page, limit, cursor).await
+    }
+    async fn list(
+        &self,
+        user: &str,
+        channel: &str,
+        ts_from: f64,
+        ts_to: f64,
+        types: &str,
+        count: &str,
+        page: &str,
+        show_files_hidden_by_limit: bool,
+    ) -> Result<crate::types::FilesListSchema> {
+        Files::list(
+            self,
+            user,
+            channel,
+            ts_from,
+            ts_to,
+            types,
+            count,
+            page,
+            show_files_hidden_by_limit,
+        )
+        .await
+    }
+    async fn revoke_public_url(&self) -> Result<crate::types::FilesUploadSchema> {
+        Files::revoke_public_url(self).await
+    }
+    async fn shared_public_url(&self) -> Result<crate::types::FilesUploadSchema> {
+        Files::shared_public_url(self).await
+    }
+    async fn upload(&self) -> Result<crate::types::FilesUploadSchema> {
+        Files::upload(self).await
     }
 }
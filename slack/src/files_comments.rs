@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct FilesComments {
     pub client: Client,
@@ -12,6 +10,14 @@ impl FilesComments {
         FilesComments { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        FilesComments {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `POST` to the `/files.comments.delete` endpoint.
      *
@@ -25,6 +31,26 @@ impl FilesComments {
      */
     pub async fn delete(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/files.comments.delete".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/files.comments.delete", &[]))
+    }
+}
+
+/// The functions of [`FilesComments`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn FilesCommentsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait FilesCommentsApi: Send + Sync {
+    async fn delete(&self) -> Result<crate::types::DndEndSchema>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl FilesCommentsApi for FilesComments {
+    async fn delete(&self) -> Result<crate::types::DndEndSchema> {
+        FilesComments::delete(self).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct FilesRemote {
     pub client: Client,
@@ -12,6 +10,14 @@ impl FilesRemote {
         FilesRemote { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        FilesRemote {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `POST` to the `/files.remote.add` endpoint.
      *
@@ -21,7 +27,10 @@ impl FilesRemote {
      */
     pub async fn add(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/files.remote.add".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/files.remote.add", &[]))
     }
 
     /**
@@ -38,17 +47,19 @@ impl FilesRemote {
      * * `external_id: &str` -- Creator defined GUID for the file.
      */
     pub async fn info(&self, file: &str, external_id: &str) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !external_id.is_empty() {
-            query_args.push(("external_id".to_string(), external_id.to_string()));
+            query_args.push(("external_id", external_id.to_string()));
         }
         if !file.is_empty() {
-            query_args.push(("file".to_string(), file.to_string()));
+            query_args.push(("file", file.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/files.remote.info?{}", query_);
+        let url = crate::Client::url_with_query("/files.remote.info", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/files.remote.info", &[]))
     }
 
     /**
@@ -75,26 +86,28 @@ impl FilesRemote {
         limit: i64,
         cursor: &str,
     ) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !channel.is_empty() {
-            query_args.push(("channel".to_string(), channel.to_string()));
+            query_args.push(("channel", channel.to_string()));
         }
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !ts_from.to_string().is_empty() {
-            query_args.push(("ts_from".to_string(), ts_from.to_string()));
+            query_args.push(("ts_from", ts_from.to_string()));
         }
         if !ts_to.to_string().is_empty() {
-            query_args.push(("ts_to".to_string(), ts_to.to_string()));
+            query_args.push(("ts_to", ts_to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/files.remote.list?{}", query_);
+        let url = crate::Client::url_with_query("/files.remote.list", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/files.remote.list", &[]))
     }
 
     /**
@@ -106,7 +119,10 @@ impl FilesRemote {
      */
     pub async fn remove(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/files.remote.remove".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/files.remote.remove", &[]))
     }
 
     /**
@@ -129,20 +145,22 @@ impl FilesRemote {
         external_id: &str,
         channels: &str,
     ) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !channels.is_empty() {
-            query_args.push(("channels".to_string(), channels.to_string()));
+            query_args.push(("channels", channels.to_string()));
         }
         if !external_id.is_empty() {
-            query_args.push(("external_id".to_string(), external_id.to_string()));
+            query_args.push(("external_id", external_id.to_string()));
         }
         if !file.is_empty() {
-            query_args.push(("file".to_string(), file.to_string()));
+            query_args.push(("file", file.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/files.remote.share?{}", query_);
+        let url = crate::Client::url_with_query("/files.remote.share", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/files.remote.share", &[]))
     }
 
     /**
@@ -154,6 +172,70 @@ impl FilesRemote {
      */
     pub async fn update(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/files.remote.update".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/files.remote.update", &[]))
+    }
+}
+
+/// The functions of [`FilesRemote`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn FilesRemoteApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait FilesRemoteApi: Send + Sync {
+    async fn add(&self) -> Result<crate::types::DndEndSchema>;
+    async fn info(&self, file: &str, external_id: &str) -> Result<crate::types::DndEndSchema>;
+    async fn list(
+        &self,
+        channel: &str,
+        ts_from: f64,
+        ts_to: f64,
+        limit: i64,
+        cursor: &str,
+    ) -> Result<crate::types::DndEndSchema>;
+    async fn remove(&self) -> Result<crate::types::DndEndSchema>;
+    async fn share(
+        &self,
+        file: &str,
+        external_id: &str,
+        channels: &str,
+    ) -> Result<crate::types::DndEndSchema>;
+    async fn update(&self) -> Result<crate::types::DndEndSchema>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl FilesRemoteApi for FilesRemote {
+    async fn add(&self) -> Result<crate::types::DndEndSchema> {
+        FilesRemote::add(self).await
+    }
+    async fn info(&self, file: &str, external_id: &str) -> Result<crate::types::DndEndSchema> {
+        FilesRemote::info(self, file, external_id).await
+    }
+    async fn list(
+        &self,
+        channel: &str,
+        ts_from: f64,
+        ts_to: f64,
+        limit: i64,
+        cursor: &str,
+    ) -> Result<crate::types::DndEndSchema> {
+        FilesRemote::list(self, channel, ts_from, ts_to, limit, cursor).await
+    }
+    async fn remove(&self) -> Result<crate::types::DndEndSchema> {
+        FilesRemote::remove(self).await
+    }
+    async fn share(
+        &self,
+        file: &str,
+        external_id: &str,
+        channels: &str,
+    ) -> Result<crate::types::DndEndSchema> {
+        FilesRemote::share(self, file, external_id, channels).await
+    }
+    async fn update(&self) -> Result<crate::types::DndEndSchema> {
+        FilesRemote::update(self).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Migration {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Migration {
         Migration { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Migration {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/migration.exchange` endpoint.
      *
@@ -32,19 +38,48 @@ impl Migration {
         team_id: &str,
         to_old: bool,
     ) -> Result<crate::types::MigrationExchangeSuccessSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !team_id.is_empty() {
-            query_args.push(("team_id".to_string(), team_id.to_string()));
+            query_args.push(("team_id", team_id.to_string()));
         }
         if to_old {
-            query_args.push(("to_old".to_string(), to_old.to_string()));
+            query_args.push(("to_old", to_old.to_string()));
         }
         if !users.is_empty() {
-            query_args.push(("users".to_string(), users.to_string()));
+            query_args.push(("users", users.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/migration.exchange?{}", query_);
+        let url = crate::Client::url_with_query("/migration.exchange", &query_args);
+
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/migration.exchange", &[]))
+    }
+}
+
+/// The functions of [`Migration`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn MigrationApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait MigrationApi: Send + Sync {
+    async fn exchange(
+        &self,
+        users: &str,
+        team_id: &str,
+        to_old: bool,
+    ) -> Result<crate::types::MigrationExchangeSuccessSchema>;
+}
 
-        self.client.get(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl MigrationApi for Migration {
+    async fn exchange(
+        &self,
+        users: &str,
+        team_id: &str,
+        to_old: bool,
+    ) -> Result<crate::types::MigrationExchangeSuccessSchema> {
+        Migration::exchange(self, users, team_id, to_old).await
     }
 }
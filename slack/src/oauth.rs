@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Oauth {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Oauth {
         Oauth { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Oauth {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/oauth.access` endpoint.
      *
@@ -35,26 +41,28 @@ impl Oauth {
         redirect_uri: &str,
         single_channel: bool,
     ) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !client_id.is_empty() {
-            query_args.push(("client_id".to_string(), client_id.to_string()));
+            query_args.push(("client_id", client_id.to_string()));
         }
         if !client_secret.is_empty() {
-            query_args.push(("client_secret".to_string(), client_secret.to_string()));
+            query_args.push(("client_secret", client_secret.to_string()));
         }
         if !code.is_empty() {
-            query_args.push(("code".to_string(), code.to_string()));
+            query_args.push(("code", code.to_string()));
         }
         if !redirect_uri.is_empty() {
-            query_args.push(("redirect_uri".to_string(), redirect_uri.to_string()));
+            query_args.push(("redirect_uri", redirect_uri.to_string()));
         }
         if single_channel {
-            query_args.push(("single_channel".to_string(), single_channel.to_string()));
+            query_args.push(("single_channel", single_channel.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/oauth.access?{}", query_);
+        let url = crate::Client::url_with_query("/oauth.access", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/oauth.access", &[]))
     }
 
     /**
@@ -80,25 +88,92 @@ impl Oauth {
         redirect_uri: &str,
         single_channel: bool,
     ) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !client_id.is_empty() {
-            query_args.push(("client_id".to_string(), client_id.to_string()));
+            query_args.push(("client_id", client_id.to_string()));
         }
         if !client_secret.is_empty() {
-            query_args.push(("client_secret".to_string(), client_secret.to_string()));
+            query_args.push(("client_secret", client_secret.to_string()));
         }
         if !code.is_empty() {
-            query_args.push(("code".to_string(), code.to_string()));
+            query_args.push(("code", code.to_string()));
         }
         if !redirect_uri.is_empty() {
-            query_args.push(("redirect_uri".to_string(), redirect_uri.to_string()));
+            query_args.push(("redirect_uri", redirect_uri.to_string()));
         }
         if single_channel {
-            query_args.push(("single_channel".to_string(), single_channel.to_string()));
+            query_args.push(("single_channel", single_channel.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/oauth.token?{}", query_);
+        let url = crate::Client::url_with_query("/oauth.token", &query_args);
+
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/oauth.token", &[]))
+    }
+}
+
+/// The functions of [`Oauth`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn OauthApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait OauthApi: Send + Sync {
+    async fn access(
+        &self,
+        client_id: &str,
+        client_secret: &str,
+        code: &str,
+        redirect_uri: &str,
+        single_channel: bool,
+    ) -> Result<crate::types::DndEndSchema>;
+    async fn token(
+        &self,
+        client_id: &str,
+        client_secret: &str,
+        code: &str,
+        redirect_uri: &str,
+        single_channel: bool,
+    ) -> Result<crate::types::DndEndSchema>;
+}
 
-        self.client.get(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl OauthApi for Oauth {
+    async fn access(
+        &self,
+        client_id: &str,
+        client_secret: &str,
+        code: &str,
+        redirect_uri: &str,
+        single_channel: bool,
+    ) -> Result<crate::types::DndEndSchema> {
+        Oauth::access(
+            self,
+            client_id,
+            client_secret,
+            code,
+            redirect_uri,
+            single_channel,
+        )
+        .await
+    }
+    async fn token(
+        &self,
+        client_id: &str,
+        client_secret: &str,
+        code: &str,
+        redirect_uri: &str,
+        single_channel: bool,
+    ) -> Result<crate::types::DndEndSchema> {
+        Oauth::token(
+            self,
+            client_id,
+            client_secret,
+            code,
+            redirect_uri,
+            single_channel,
+        )
+        .await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct OauthV2 {
     pub client: Client,
@@ -12,6 +10,14 @@ impl OauthV2 {
         OauthV2 { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        OauthV2 {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/oauth.v2.access` endpoint.
      *
@@ -33,22 +39,53 @@ impl OauthV2 {
         code: &str,
         redirect_uri: &str,
     ) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !client_id.is_empty() {
-            query_args.push(("client_id".to_string(), client_id.to_string()));
+            query_args.push(("client_id", client_id.to_string()));
         }
         if !client_secret.is_empty() {
-            query_args.push(("client_secret".to_string(), client_secret.to_string()));
+            query_args.push(("client_secret", client_secret.to_string()));
         }
         if !code.is_empty() {
-            query_args.push(("code".to_string(), code.to_string()));
+            query_args.push(("code", code.to_string()));
         }
         if !redirect_uri.is_empty() {
-            query_args.push(("redirect_uri".to_string(), redirect_uri.to_string()));
+            query_args.push(("redirect_uri", redirect_uri.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/oauth.v2.access?{}", query_);
+        let url = crate::Client::url_with_query("/oauth.v2.access", &query_args);
+
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/oauth.v2.access", &[]))
+    }
+}
+
+/// The functions of [`OauthV2`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn OauthV2Api`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait OauthV2Api: Send + Sync {
+    async fn oauth_access(
+        &self,
+        client_id: &str,
+        client_secret: &str,
+        code: &str,
+        redirect_uri: &str,
+    ) -> Result<crate::types::DndEndSchema>;
+}
 
-        self.client.get(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl OauthV2Api for OauthV2 {
+    async fn oauth_access(
+        &self,
+        client_id: &str,
+        client_secret: &str,
+        code: &str,
+        redirect_uri: &str,
+    ) -> Result<crate::types::DndEndSchema> {
+        OauthV2::oauth_access(self, client_id, client_secret, code, redirect_uri).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Pins {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Pins {
         Pins { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Pins {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `POST` to the `/pins.add` endpoint.
      *
@@ -25,7 +31,10 @@ impl Pins {
      */
     pub async fn add(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/pins.add".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/pins.add", &[]))
     }
 
     /**
@@ -41,14 +50,16 @@ impl Pins {
      * * `channel: &str` -- Channel to get pinned items for.
      */
     pub async fn list(&self, channel: &str) -> Result<Vec<crate::types::PinsListResponseAnyOf>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !channel.is_empty() {
-            query_args.push(("channel".to_string(), channel.to_string()));
+            query_args.push(("channel", channel.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/pins.list?{}", query_);
+        let url = crate::Client::url_with_query("/pins.list", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/pins.list", &[]))
     }
 
     /**
@@ -64,14 +75,16 @@ impl Pins {
         &self,
         channel: &str,
     ) -> Result<Vec<crate::types::PinsListResponseAnyOf>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !channel.is_empty() {
-            query_args.push(("channel".to_string(), channel.to_string()));
+            query_args.push(("channel", channel.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/pins.list?{}", query_);
+        let url = crate::Client::url_with_query("/pins.list", &query_args);
 
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/pins.list", &[]))
     }
 
     /**
@@ -87,6 +100,38 @@ impl Pins {
      */
     pub async fn remove(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/pins.remove".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/pins.remove", &[]))
+    }
+}
+
+/// The functions of [`Pins`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn PinsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait PinsApi: Send + Sync {
+    async fn add(&self) -> Result<crate::types::DndEndSchema>;
+    async fn list(&self, channel: &str) -> Result<Vec<crate::types::PinsListResponseAnyOf>>;
+    async fn list_all(&self, channel: &str) -> Result<Vec<crate::types::PinsListResponseAnyOf>>;
+    async fn remove(&self) -> Result<crate::types::DndEndSchema>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl PinsApi for Pins {
+    async fn add(&self) -> Result<crate::types::DndEndSchema> {
+        Pins::add(self).await
+    }
+    async fn list(&self, channel: &str) -> Result<Vec<crate::types::PinsListResponseAnyOf>> {
+        Pins::list(self, channel).await
+    }
+    async fn list_all(&self, channel: &str) -> Result<Vec<crate::types::PinsListResponseAnyOf>> {
+        Pins::list_all(self, channel).await
+    }
+    async fn remove(&self) -> Result<crate::types::DndEndSchema> {
+        Pins::remove(self).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Reactions {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Reactions {
         Reactions { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Reactions {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `POST` to the `/reactions.add` endpoint.
      *
@@ -25,7 +31,10 @@ impl Reactions {
      */
     pub async fn add(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/reactions.add".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/reactions.add", &[]))
     }
 
     /**
@@ -52,26 +61,28 @@ impl Reactions {
         full: bool,
         timestamp: &str,
     ) -> Result<crate::types::Fields> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !channel.is_empty() {
-            query_args.push(("channel".to_string(), channel.to_string()));
+            query_args.push(("channel", channel.to_string()));
         }
         if !file.is_empty() {
-            query_args.push(("file".to_string(), file.to_string()));
+            query_args.push(("file", file.to_string()));
         }
         if !file_comment.is_empty() {
-            query_args.push(("file_comment".to_string(), file_comment.to_string()));
+            query_args.push(("file_comment", file_comment.to_string()));
         }
         if full {
-            query_args.push(("full".to_string(), full.to_string()));
+            query_args.push(("full", full.to_string()));
         }
         if !timestamp.is_empty() {
-            query_args.push(("timestamp".to_string(), timestamp.to_string()));
+            query_args.push(("timestamp", timestamp.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/reactions.get?{}", query_);
+        let url = crate::Client::url_with_query("/reactions.get", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/reactions.get", &[]))
     }
 
     /**
@@ -100,29 +111,31 @@ impl Reactions {
         cursor: &str,
         limit: i64,
     ) -> Result<crate::types::ReactionsListSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if full {
-            query_args.push(("full".to_string(), full.to_string()));
+            query_args.push(("full", full.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if !user.is_empty() {
-            query_args.push(("user".to_string(), user.to_string()));
+            query_args.push(("user", user.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/reactions.list?{}", query_);
+        let url = crate::Client::url_with_query("/reactions.list", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/reactions.list", &[]))
     }
 
     /**
@@ -138,6 +151,68 @@ impl Reactions {
      */
     pub async fn remove(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/reactions.remove".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/reactions.remove", &[]))
+    }
+}
+
+/// The functions of [`Reactions`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ReactionsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ReactionsApi: Send + Sync {
+    async fn add(&self) -> Result<crate::types::DndEndSchema>;
+    async fn get(
+        &self,
+        channel: &str,
+        file: &str,
+        file_comment: &str,
+        full: bool,
+        timestamp: &str,
+    ) -> Result<crate::types::Fields>;
+    async fn list(
+        &self,
+        user: &str,
+        full: bool,
+        count: i64,
+        page: i64,
+        cursor: &str,
+        limit: i64,
+    ) -> Result<crate::types::ReactionsListSchema>;
+    async fn remove(&self) -> Result<crate::types::DndEndSchema>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ReactionsApi for Reactions {
+    async fn add(&self) -> Result<crate::types::DndEndSchema> {
+        Reactions::add(self).await
+    }
+    async fn get(
+        &self,
+        channel: &str,
+        file: &str,
+        file_comment: &str,
+        full: bool,
+        timestamp: &str,
+    ) -> Result<crate::types::Fields> {
+        Reactions::get(self, channel, file, file_comment, full, timestamp).await
+    }
+    async fn list(
+        &self,
+        user: &str,
+        full: bool,
+        count: i64,
+        page: i64,
+        cursor: &str,
+        limit: i64,
+    ) -> Result<crate::types::ReactionsListSchema> {
+        Reactions::list(self, user, full, count, page, cursor, limit).await
+    }
+    async fn remove(&self) -> Result<crate::types::DndEndSchema> {
+        Reactions::remove(self).await
     }
 }
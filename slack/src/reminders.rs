@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Reminders {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Reminders {
         Reminders { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Reminders {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `POST` to the `/reminders.add` endpoint.
      *
@@ -25,7 +31,10 @@ impl Reminders {
      */
     pub async fn add(&self) -> Result<crate::types::RemindersAddSchema> {
         let url = "/reminders.add".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/reminders.add", &[]))
     }
 
     /**
@@ -41,7 +50,10 @@ impl Reminders {
      */
     pub async fn complete(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/reminders.complete".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/reminders.complete", &[]))
     }
 
     /**
@@ -57,7 +69,10 @@ impl Reminders {
      */
     pub async fn delete(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/reminders.delete".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/reminders.delete", &[]))
     }
 
     /**
@@ -73,14 +88,16 @@ impl Reminders {
      * * `reminder: &str` -- The ID of the reminder.
      */
     pub async fn info(&self, reminder: &str) -> Result<crate::types::RemindersAddSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !reminder.is_empty() {
-            query_args.push(("reminder".to_string(), reminder.to_string()));
+            query_args.push(("reminder", reminder.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/reminders.info?{}", query_);
+        let url = crate::Client::url_with_query("/reminders.info", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/reminders.info", &[]))
     }
 
     /**
@@ -96,6 +113,42 @@ impl Reminders {
      */
     pub async fn list(&self) -> Result<crate::types::RemindersListSchema> {
         let url = "/reminders.list".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/reminders.list", &[]))
+    }
+}
+
+/// The functions of [`Reminders`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn RemindersApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait RemindersApi: Send + Sync {
+    async fn add(&self) -> Result<crate::types::RemindersAddSchema>;
+    async fn complete(&self) -> Result<crate::types::DndEndSchema>;
+    async fn delete(&self) -> Result<crate::types::DndEndSchema>;
+    async fn info(&self, reminder: &str) -> Result<crate::types::RemindersAddSchema>;
+    async fn list(&self) -> Result<crate::types::RemindersListSchema>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl RemindersApi for Reminders {
+    async fn add(&self) -> Result<crate::types::RemindersAddSchema> {
+        Reminders::add(self).await
+    }
+    async fn complete(&self) -> Result<crate::types::DndEndSchema> {
+        Reminders::complete(self).await
+    }
+    async fn delete(&self) -> Result<crate::types::DndEndSchema> {
+        Reminders::delete(self).await
+    }
+    async fn info(&self, reminder: &str) -> Result<crate::types::RemindersAddSchema> {
+        Reminders::info(self, reminder).await
+    }
+    async fn list(&self) -> Result<crate::types::RemindersListSchema> {
+        Reminders::list(self).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Rtm {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Rtm {
         Rtm { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Rtm {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/rtm.connect` endpoint.
      *
@@ -30,19 +36,43 @@ impl Rtm {
         batch_presence_aware: bool,
         presence_sub: bool,
     ) -> Result<crate::types::RtmConnectSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if batch_presence_aware {
-            query_args.push((
-                "batch_presence_aware".to_string(),
-                batch_presence_aware.to_string(),
-            ));
+            query_args.push(("batch_presence_aware", batch_presence_aware.to_string()));
         }
         if presence_sub {
-            query_args.push(("presence_sub".to_string(), presence_sub.to_string()));
+            query_args.push(("presence_sub", presence_sub.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/rtm.connect?{}", query_);
+        let url = crate::Client::url_with_query("/rtm.connect", &query_args);
+
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/rtm.connect", &[]))
+    }
+}
+
+/// The functions of [`Rtm`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn RtmApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait RtmApi: Send + Sync {
+    async fn connect(
+        &self,
+        batch_presence_aware: bool,
+        presence_sub: bool,
+    ) -> Result<crate::types::RtmConnectSchema>;
+}
 
-        self.client.get(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl RtmApi for Rtm {
+    async fn connect(
+        &self,
+        batch_presence_aware: bool,
+        presence_sub: bool,
+    ) -> Result<crate::types::RtmConnectSchema> {
+        Rtm::connect(self, batch_presence_aware, presence_sub).await
     }
 }
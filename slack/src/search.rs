@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Search {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Search {
         Search { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Search {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/search.messages` endpoint.
      *
@@ -38,28 +44,63 @@ impl Search {
         sort: &str,
         sort_dir: &str,
     ) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if highlight {
-            query_args.push(("highlight".to_string(), highlight.to_string()));
+            query_args.push(("highlight", highlight.to_string()));
         }
         if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if !query.is_empty() {
-            query_args.push(("query".to_string(), query.to_string()));
+            query_args.push(("query", query.to_string()));
         }
         if !sort.is_empty() {
-            query_args.push(("sort".to_string(), sort.to_string()));
+            query_args.push(("sort", sort.to_string()));
         }
         if !sort_dir.is_empty() {
-            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+            query_args.push(("sort_dir", sort_dir.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/search.messages?{}", query_);
+        let url = crate::Client::url_with_query("/search.messages", &query_args);
+
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/search.messages", &[]))
+    }
+}
+
+/// The functions of [`Search`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn SearchApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait SearchApi: Send + Sync {
+    async fn message(
+        &self,
+        count: i64,
+        highlight: bool,
+        page: i64,
+        query: &str,
+        sort: &str,
+        sort_dir: &str,
+    ) -> Result<crate::types::DndEndSchema>;
+}
 
-        self.client.get(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl SearchApi for Search {
+    async fn message(
+        &self,
+        count: i64,
+        highlight: bool,
+        page: i64,
+        query: &str,
+        sort: &str,
+        sort_dir: &str,
+    ) -> Result<crate::types::DndEndSchema> {
+        Search::message(self, count, highlight, page, query, sort, sort_dir).await
     }
 }
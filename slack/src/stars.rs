@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Stars {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Stars {
         Stars { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Stars {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `POST` to the `/stars.add` endpoint.
      *
@@ -25,7 +31,10 @@ impl Stars {
      */
     pub async fn add(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/stars.add".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/stars.add", &[]))
     }
 
     /**
@@ -50,23 +59,25 @@ impl Stars {
         cursor: &str,
         limit: i64,
     ) -> Result<crate::types::StarsListSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !page.is_empty() {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/stars.list?{}", query_);
+        let url = crate::Client::url_with_query("/stars.list", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/stars.list", &[]))
     }
 
     /**
@@ -82,6 +93,46 @@ impl Stars {
      */
     pub async fn remove(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/stars.remove".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/stars.remove", &[]))
+    }
+}
+
+/// The functions of [`Stars`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn StarsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait StarsApi: Send + Sync {
+    async fn add(&self) -> Result<crate::types::DndEndSchema>;
+    async fn list(
+        &self,
+        count: &str,
+        page: &str,
+        cursor: &str,
+        limit: i64,
+    ) -> Result<crate::types::StarsListSchema>;
+    async fn remove(&self) -> Result<crate::types::DndEndSchema>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl StarsApi for Stars {
+    async fn add(&self) -> Result<crate::types::DndEndSchema> {
+        Stars::add(self).await
+    }
+    async fn list(
+        &self,
+        count: &str,
+        page: &str,
+        cursor: &str,
+        limit: i64,
+    ) -> Result<crate::types::StarsListSchema> {
+        Stars::list(self, count, page, cursor, limit).await
+    }
+    async fn remove(&self) -> Result<crate::types::DndEndSchema> {
+        Stars::remove(self).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Team {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Team {
         Team { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Team {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/team.accessLogs` endpoint.
      *
@@ -32,20 +38,22 @@ impl Team {
         count: &str,
         page: &str,
     ) -> Result<crate::types::TeamAccessLogsSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !before.is_empty() {
-            query_args.push(("before".to_string(), before.to_string()));
+            query_args.push(("before", before.to_string()));
         }
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !page.is_empty() {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/team.accessLogs?{}", query_);
+        let url = crate::Client::url_with_query("/team.accessLogs", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/team.accessLogs", &[]))
     }
 
     /**
@@ -61,14 +69,16 @@ impl Team {
      * * `user: &str` -- A user to retrieve the billable information for. Defaults to all users.
      */
     pub async fn billable_info(&self, user: &str) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !user.is_empty() {
-            query_args.push(("user".to_string(), user.to_string()));
+            query_args.push(("user", user.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/team.billableInfo?{}", query_);
+        let url = crate::Client::url_with_query("/team.billableInfo", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/team.billableInfo", &[]))
     }
 
     /**
@@ -84,14 +94,16 @@ impl Team {
      * * `team: &str` -- Team to get info on, if omitted, will return information about the current team. Will only return team that the authenticated token is allowed to see through external shared channels.
      */
     pub async fn info(&self, team: &str) -> Result<crate::types::TeamInfoSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !team.is_empty() {
-            query_args.push(("team".to_string(), team.to_string()));
+            query_args.push(("team", team.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/team.info?{}", query_);
+        let url = crate::Client::url_with_query("/team.info", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/team.info", &[]))
     }
 
     /**
@@ -120,28 +132,85 @@ impl Team {
         service_id: &str,
         user: &str,
     ) -> Result<crate::types::TeamIntegrationLogsSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !app_id.is_empty() {
-            query_args.push(("app_id".to_string(), app_id.to_string()));
+            query_args.push(("app_id", app_id.to_string()));
         }
         if !change_type.is_empty() {
-            query_args.push(("change_type".to_string(), change_type.to_string()));
+            query_args.push(("change_type", change_type.to_string()));
         }
         if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
+            query_args.push(("count", count.to_string()));
         }
         if !page.is_empty() {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if !service_id.is_empty() {
-            query_args.push(("service_id".to_string(), service_id.to_string()));
+            query_args.push(("service_id", service_id.to_string()));
         }
         if !user.is_empty() {
-            query_args.push(("user".to_string(), user.to_string()));
+            query_args.push(("user", user.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/team.integrationLogs?{}", query_);
+        let url = crate::Client::url_with_query("/team.integrationLogs", &query_args);
+
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/team.integrationLogs", &[]))
+    }
+}
+
+/// The functions of [`Team`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn TeamApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait TeamApi: Send + Sync {
+    async fn access_log(
+        &self,
+        before: &str,
+        count: &str,
+        page: &str,
+    ) -> Result<crate::types::TeamAccessLogsSchema>;
+    async fn billable_info(&self, user: &str) -> Result<crate::types::DndEndSchema>;
+    async fn info(&self, team: &str) -> Result<crate::types::TeamInfoSchema>;
+    async fn integration_log(
+        &self,
+        app_id: &str,
+        change_type: &str,
+        count: &str,
+        page: &str,
+        service_id: &str,
+        user: &str,
+    ) -> Result<crate::types::TeamIntegrationLogsSchema>;
+}
 
-        self.client.get(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl TeamApi for Team {
+    async fn access_log(
+        &self,
+        before: &str,
+        count: &str,
+        page: &str,
+    ) -> Result<crate::types::TeamAccessLogsSchema> {
+        Team::access_log(self, before, count, page).await
+    }
+    async fn billable_info(&self, user: &str) -> Result<crate::types::DndEndSchema> {
+        Team::billable_info(self, user).await
+    }
+    async fn info(&self, team: &str) -> Result<crate::types::TeamInfoSchema> {
+        Team::info(self, team).await
+    }
+    async fn integration_log(
+        &self,
+        app_id: &str,
+        change_type: &str,
+        count: &str,
+        page: &str,
+        service_id: &str,
+        user: &str,
+    ) -> Result<crate::types::TeamIntegrationLogsSchema> {
+        Team::integration_log(self, app_id, change_type, count, page, service_id, user).await
     }
 }
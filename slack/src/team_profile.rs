@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct TeamProfile {
     pub client: Client,
@@ -12,6 +10,14 @@ impl TeamProfile {
         TeamProfile { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        TeamProfile {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/team.profile.get` endpoint.
      *
@@ -25,13 +31,32 @@ impl TeamProfile {
      * * `visibility: &str` -- Filter by visibility.
      */
     pub async fn get(&self, visibility: &str) -> Result<crate::types::TeamProfileGetSuccessSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !visibility.is_empty() {
-            query_args.push(("visibility".to_string(), visibility.to_string()));
+            query_args.push(("visibility", visibility.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/team.profile.get?{}", query_);
+        let url = crate::Client::url_with_query("/team.profile.get", &query_args);
+
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/team.profile.get", &[]))
+    }
+}
+
+/// The functions of [`TeamProfile`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn TeamProfileApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait TeamProfileApi: Send + Sync {
+    async fn get(&self, visibility: &str) -> Result<crate::types::TeamProfileGetSuccessSchema>;
+}
 
-        self.client.get(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl TeamProfileApi for TeamProfile {
+    async fn get(&self, visibility: &str) -> Result<crate::types::TeamProfileGetSuccessSchema> {
+        TeamProfile::get(self, visibility).await
     }
 }
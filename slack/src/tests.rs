@@ -1 +1,37 @@
+#[test]
+fn test_deserialize_required_nullable_field() {
+    // `fields` is required, but it can be null.
+    let profile: crate::types::ObjsUserProfile = serde_json::from_str(
+        r#"{
+  "avatar_hash": "ge3b51ca72de",
+  "display_name": "spengler",
+  "display_name_normalized": "spengler",
+  "fields": null,
+  "phone": "",
+  "real_name": "Egon Spengler",
+  "real_name_normalized": "Egon Spengler",
+  "skype": "",
+  "status_emoji": ":books:",
+  "status_text": "Print is dead",
+  "title": "Ghostbuster"
+}"#,
+    )
+    .unwrap();
+    assert_eq!(profile.fields, None);
+    assert_eq!(profile.real_name, "Egon Spengler");
 
+    let profile: crate::types::ObjsUserProfile = serde_json::from_str(
+        r#"{
+  "display_name": "spengler",
+  "fields": ["a", "b"]
+}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        profile.fields,
+        Some(crate::types::FieldsOneOf::StringVector(vec![
+            "a".to_string(),
+            "b".to_string()
+        ]))
+    );
+}
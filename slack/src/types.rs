@@ -2,6 +2,33 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+/// Either one of something, or many of them. Some endpoints send back an
+/// object when there is one and an array when there are more, so this takes both.
+// Many is tried first: serde reads a struct from an array of its fields too,
+// so an array would otherwise be taken for One.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum OneOrMany<T> {
+    Many(Vec<T>),
+    One(T),
+}
+
+impl<T> OneOrMany<T> {
+    /// All of them, as a vector.
+    pub fn into_vec(self) -> Vec<T> {
+        match self {
+            OneOrMany::One(t) => vec![t],
+            OneOrMany::Many(v) => v,
+        }
+    }
+}
+
+impl<T> Default for OneOrMany<T> {
+    fn default() -> Self {
+        OneOrMany::Many(Vec::new())
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Blocks {
     #[serde(
@@ -79,16 +106,33 @@ pub struct ObjsBotProfile {
     pub updated: i64,
 }
 
-/// All of the following types are flattened into one object:
+/// All of the following types:
 ///
 /// - `ObjsMessage`
 /// - `serde_json::Value`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct LatestAnyOf {
-    #[serde(flatten)]
-    pub objs_message: ObjsMessage,
-    #[serde(flatten)]
-    pub value: serde_json::Value,
+#[serde(untagged)]
+pub enum LatestAnyOf {
+    ObjsMessage(ObjsMessage),
+    Value(serde_json::Value),
+}
+
+impl LatestAnyOf {
+    pub fn objs_message(&self) -> Option<&ObjsMessage> {
+        if let LatestAnyOf::ObjsMessage(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn serde_json_value(&self) -> Option<&serde_json::Value> {
+        if let LatestAnyOf::Value(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -368,16 +412,45 @@ pub struct DisplayCounts {
     pub guest_counts: i64,
 }
 
-/// All of the following types are flattened into one object:
+/// All of the following types:
 ///
 /// - `String`
 /// - `serde_json::Value`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ParentConversationAnyOf {
-    #[serde(flatten)]
-    pub string: String,
-    #[serde(flatten)]
-    pub value: serde_json::Value,
+#[serde(untagged)]
+pub enum ParentConversationAnyOf {
+    String(String),
+    Value(serde_json::Value),
+}
+
+impl ParentConversationAnyOf {
+    pub fn string(&self) -> Option<&String> {
+        if let ParentConversationAnyOf::String(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn serde_json_value(&self) -> Option<&serde_json::Value> {
+        if let ParentConversationAnyOf::Value(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+impl std::convert::From<String> for ParentConversationAnyOf {
+    fn from(f: String) -> Self {
+        ParentConversationAnyOf::String(f)
+    }
+}
+
+impl std::convert::From<ParentConversationAnyOf> for String {
+    fn from(f: ParentConversationAnyOf) -> Self {
+        f.string().unwrap().clone()
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1126,20 +1199,48 @@ pub struct ConversationImChannelObjectFromConversationsMethods {
     pub version: i64,
 }
 
-/// All of the following types are flattened into one object:
+/// All of the following types:
 ///
 /// - `ObjsConversation`
 /// - `ConversationMpimObject`
 /// - `ConversationImChannelObjectFromConversationsMethods`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ObjsConversationAnyOf {
-    #[serde(flatten)]
-    pub objs_conversation: ObjsConversation,
-    #[serde(flatten)]
-    pub conversation_mpim_object: ConversationMpimObject,
-    #[serde(flatten)]
-    pub conversation_im_channel_object_from_conversations_methods:
+#[serde(untagged)]
+pub enum ObjsConversationAnyOf {
+    ObjsConversation(ObjsConversation),
+    ConversationMpimObject(ConversationMpimObject),
+    ConversationImChannelObjectFromConversationsMethods(
         ConversationImChannelObjectFromConversationsMethods,
+    ),
+}
+
+impl ObjsConversationAnyOf {
+    pub fn conversation_im_channel_object_from_conversations_methods(
+        &self,
+    ) -> Option<&ConversationImChannelObjectFromConversationsMethods> {
+        if let ObjsConversationAnyOf::ConversationImChannelObjectFromConversationsMethods(ref_) =
+            self
+        {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn conversation_mpim_object(&self) -> Option<&ConversationMpimObject> {
+        if let ObjsConversationAnyOf::ConversationMpimObject(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn objs_conversation(&self) -> Option<&ObjsConversation> {
+        if let ObjsConversationAnyOf::ObjsConversation(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1718,16 +1819,45 @@ pub struct Attachments {
     pub image_width: i64,
 }
 
-/// All of the following types are flattened into one object:
+/// All of the following types:
 ///
 /// - `String`
 /// - `serde_json::Value`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct BotAnyOf {
-    #[serde(flatten)]
-    pub string: String,
-    #[serde(flatten)]
-    pub value: serde_json::Value,
+#[serde(untagged)]
+pub enum BotAnyOf {
+    String(String),
+    Value(serde_json::Value),
+}
+
+impl BotAnyOf {
+    pub fn string(&self) -> Option<&String> {
+        if let BotAnyOf::String(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn serde_json_value(&self) -> Option<&serde_json::Value> {
+        if let BotAnyOf::Value(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+impl std::convert::From<String> for BotAnyOf {
+    fn from(f: String) -> Self {
+        BotAnyOf::String(f)
+    }
+}
+
+impl std::convert::From<BotAnyOf> for String {
+    fn from(f: BotAnyOf) -> Self {
+        f.string().unwrap().clone()
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2125,7 +2255,7 @@ pub struct NewPagingStyle {
     pub next_cursor: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Warnings {
     #[serde(rename = "method_deprecated")]
     MethodDeprecated,
@@ -2151,7 +2281,22 @@ impl Default for Warnings {
         Warnings::Noop
     }
 }
+
 impl Warnings {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Warnings::MethodDeprecated => "method_deprecated",
+            Warnings::Noop => "",
+            Warnings::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Warnings] {
+        &[Warnings::MethodDeprecated]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Warnings::Noop)
     }
@@ -2195,22 +2340,45 @@ pub struct ObjsResponseMetadata {
     pub warnings: Vec<Warnings>,
 }
 
-/// All of the following types are flattened into one object:
+/// All of the following types:
 ///
 /// - `NewPagingStyle`
 /// - `DeprecationWarning`
 /// - `ObjsResponseMetadata`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ObjsResponseMetadataAnyOf {
-    #[serde(flatten)]
-    pub new_paging_style: NewPagingStyle,
-    #[serde(flatten)]
-    pub deprecation_warning: DeprecationWarning,
-    #[serde(flatten)]
-    pub objs_response_metadata: ObjsResponseMetadata,
+#[serde(untagged)]
+pub enum ObjsResponseMetadataAnyOf {
+    NewPagingStyle(NewPagingStyle),
+    DeprecationWarning(DeprecationWarning),
+    ObjsResponseMetadata(ObjsResponseMetadata),
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl ObjsResponseMetadataAnyOf {
+    pub fn deprecation_warning(&self) -> Option<&DeprecationWarning> {
+        if let ObjsResponseMetadataAnyOf::DeprecationWarning(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn new_paging_style(&self) -> Option<&NewPagingStyle> {
+        if let ObjsResponseMetadataAnyOf::NewPagingStyle(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn objs_response_metadata(&self) -> Option<&ObjsResponseMetadata> {
+        if let ObjsResponseMetadataAnyOf::ObjsResponseMetadata(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum AutoType {
     #[serde(rename = "admin")]
     Admin,
@@ -2239,34 +2407,96 @@ impl Default for AutoType {
         AutoType::Noop
     }
 }
+
 impl AutoType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AutoType::Admin => "admin",
+            AutoType::Owner => "owner",
+            AutoType::Noop => "",
+            AutoType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [AutoType] {
+        &[AutoType::Admin, AutoType::Owner]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, AutoType::Noop)
     }
 }
 
-/// All of the following types are flattened into one object:
+/// All of the following types:
 ///
 /// - `serde_json::Value`
 /// - `AutoType`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AutoTypeAnyOf {
-    #[serde(flatten)]
-    pub value: serde_json::Value,
-    #[serde(flatten)]
-    pub auto_type: AutoType,
+#[serde(untagged)]
+pub enum AutoTypeAnyOf {
+    Value(serde_json::Value),
+    AutoType(AutoType),
+}
+
+impl AutoTypeAnyOf {
+    pub fn auto_type(&self) -> Option<&AutoType> {
+        if let AutoTypeAnyOf::AutoType(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn serde_json_value(&self) -> Option<&serde_json::Value> {
+        if let AutoTypeAnyOf::Value(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
 }
 
-/// All of the following types are flattened into one object:
+/// All of the following types:
 ///
 /// - `String`
 /// - `serde_json::Value`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct DeletedByAnyOf {
-    #[serde(flatten)]
-    pub string: String,
-    #[serde(flatten)]
-    pub value: serde_json::Value,
+#[serde(untagged)]
+pub enum DeletedByAnyOf {
+    String(String),
+    Value(serde_json::Value),
+}
+
+impl DeletedByAnyOf {
+    pub fn string(&self) -> Option<&String> {
+        if let DeletedByAnyOf::String(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn serde_json_value(&self) -> Option<&serde_json::Value> {
+        if let DeletedByAnyOf::Value(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+impl std::convert::From<String> for DeletedByAnyOf {
+    fn from(f: String) -> Self {
+        DeletedByAnyOf::String(f)
+    }
+}
+
+impl std::convert::From<DeletedByAnyOf> for String {
+    fn from(f: DeletedByAnyOf) -> Self {
+        f.string().unwrap().clone()
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2407,19 +2637,48 @@ pub struct ObjsSubteam {
     pub users: Vec<String>,
 }
 
-/// All of the following types are flattened into one object:
+/// All of the following types:
 ///
 /// - `String`
 /// - `serde_json::Value`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct TzAnyOf {
-    #[serde(flatten)]
-    pub string: String,
-    #[serde(flatten)]
-    pub value: serde_json::Value,
+#[serde(untagged)]
+pub enum TzAnyOf {
+    String(String),
+    Value(serde_json::Value),
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl TzAnyOf {
+    pub fn string(&self) -> Option<&String> {
+        if let TzAnyOf::String(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn serde_json_value(&self) -> Option<&serde_json::Value> {
+        if let TzAnyOf::Value(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+impl std::convert::From<String> for TzAnyOf {
+    fn from(f: String) -> Self {
+        TzAnyOf::String(f)
+    }
+}
+
+impl std::convert::From<TzAnyOf> for String {
+    fn from(f: TzAnyOf) -> Self {
+        f.string().unwrap().clone()
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Plan {
     #[serde(rename = "compliance")]
     Compliance,
@@ -2454,7 +2713,25 @@ impl Default for Plan {
         Plan::Noop
     }
 }
+
 impl Plan {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Plan::Compliance => "compliance",
+            Plan::Enterprise => "enterprise",
+            Plan::Plus => "plus",
+            Plan::Std => "std",
+            Plan::Noop => "",
+            Plan::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Plan] {
+        &[Plan::Compliance, Plan::Enterprise, Plan::Plus, Plan::Std]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Plan::Noop)
     }
@@ -2634,19 +2911,36 @@ pub struct ObjsTeam {
     pub sso_provider: Option<SsoProvider>,
 }
 
-/// All of the following types are flattened into one object:
+/// All of the following types:
 ///
 /// - `serde_json::Value`
 /// - `ObjsTeamProfileFieldOption`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OptionsAnyOf {
-    #[serde(flatten)]
-    pub value: serde_json::Value,
-    #[serde(flatten)]
-    pub objs_team_profile_field_option: ObjsTeamProfileFieldOption,
+#[serde(untagged)]
+pub enum OptionsAnyOf {
+    Value(serde_json::Value),
+    ObjsTeamProfileFieldOption(ObjsTeamProfileFieldOption),
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl OptionsAnyOf {
+    pub fn objs_team_profile_field_option(&self) -> Option<&ObjsTeamProfileFieldOption> {
+        if let OptionsAnyOf::ObjsTeamProfileFieldOption(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn serde_json_value(&self) -> Option<&serde_json::Value> {
+        if let OptionsAnyOf::Value(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Type {
     #[serde(rename = "date")]
     Date,
@@ -2687,7 +2981,34 @@ impl Default for Type {
         Type::Noop
     }
 }
+
 impl Type {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Type::Date => "date",
+            Type::Link => "link",
+            Type::Mailto => "mailto",
+            Type::OptionsList => "options_list",
+            Type::Text => "text",
+            Type::User => "user",
+            Type::Noop => "",
+            Type::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Type] {
+        &[
+            Type::Date,
+            Type::Link,
+            Type::Mailto,
+            Type::OptionsList,
+            Type::Text,
+            Type::User,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Type::Noop)
     }
@@ -3218,22 +3539,39 @@ pub struct ObjsUserData {
     pub updated: f64,
 }
 
-/// All of the following types are flattened into one object:
+/// All of the following types:
 ///
 /// - `ObjsUser`
 /// - `ObjsUserData`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ObjsUserAnyOf {
+#[serde(untagged)]
+pub enum ObjsUserAnyOf {
     /**
      * user object for non enterprise type
      */
-    #[serde(flatten)]
-    pub objs_user: ObjsUser,
+    ObjsUser(ObjsUser),
     /**
      * enterprise user
      */
-    #[serde(flatten)]
-    pub objs_user_data: ObjsUserData,
+    ObjsUserData(ObjsUserData),
+}
+
+impl ObjsUserAnyOf {
+    pub fn objs_user(&self) -> Option<&ObjsUser> {
+        if let ObjsUserAnyOf::ObjsUser(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn objs_user_data(&self) -> Option<&ObjsUserData> {
+        if let ObjsUserAnyOf::ObjsUserData(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
 }
 
 /// All of the following types:
@@ -3320,8 +3658,8 @@ pub struct ObjsUserProfile {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email: String,
-    #[serde()]
-    pub fields: FieldsOneOf,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fields: Option<FieldsOneOf>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -4327,16 +4665,45 @@ pub struct ConversationsInfoSuccessSchema {
     pub ok: bool,
 }
 
-/// All of the following types are flattened into one object:
+/// All of the following types:
 ///
 /// - `i64`
 /// - `serde_json::Value`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ChannelActionsTsAnyOf {
-    #[serde(flatten)]
-    pub i64: i64,
-    #[serde(flatten)]
-    pub value: serde_json::Value,
+#[serde(untagged)]
+pub enum ChannelActionsTsAnyOf {
+    I64(i64),
+    Value(serde_json::Value),
+}
+
+impl ChannelActionsTsAnyOf {
+    pub fn i64(&self) -> Option<&i64> {
+        if let ChannelActionsTsAnyOf::I64(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn serde_json_value(&self) -> Option<&serde_json::Value> {
+        if let ChannelActionsTsAnyOf::Value(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+impl std::convert::From<i64> for ChannelActionsTsAnyOf {
+    fn from(f: i64) -> Self {
+        ChannelActionsTsAnyOf::I64(f)
+    }
+}
+
+impl std::convert::From<ChannelActionsTsAnyOf> for i64 {
+    fn from(f: ChannelActionsTsAnyOf) -> Self {
+        *f.i64().unwrap()
+    }
 }
 
 /// Schema for successful response from conversations.history method
@@ -4527,16 +4894,45 @@ pub struct ChannelData {
     pub user: String,
 }
 
-/// All of the following types are flattened into one object:
+/// All of the following types:
 ///
 /// - `Vec<ObjsConversationAnyOf>`
 /// - `ChannelData`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ChannelAnyOf {
-    #[serde(flatten)]
-    pub objs_conversation_any_of_vector: Vec<ObjsConversationAnyOf>,
-    #[serde(flatten)]
-    pub channel_data: ChannelData,
+#[serde(untagged)]
+pub enum ChannelAnyOf {
+    ObjsConversationAnyOfVector(Vec<ObjsConversationAnyOf>),
+    ChannelData(ChannelData),
+}
+
+impl ChannelAnyOf {
+    pub fn channel_data(&self) -> Option<&ChannelData> {
+        if let ChannelAnyOf::ChannelData(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn vec_objs_conversation_any_of(&self) -> Option<&Vec<ObjsConversationAnyOf>> {
+        if let ChannelAnyOf::ObjsConversationAnyOfVector(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+impl std::convert::From<Vec<ObjsConversationAnyOf>> for ChannelAnyOf {
+    fn from(f: Vec<ObjsConversationAnyOf>) -> Self {
+        ChannelAnyOf::ObjsConversationAnyOfVector(f)
+    }
+}
+
+impl std::convert::From<ChannelAnyOf> for Vec<ObjsConversationAnyOf> {
+    fn from(f: ChannelAnyOf) -> Self {
+        f.vec_objs_conversation_any_of().unwrap().clone()
+    }
 }
 
 /// Schema for successful response from conversations.open method when opening channels, ims, mpims
@@ -4816,7 +5212,7 @@ pub struct MigrationExchangeSuccessSchema {
     pub user_id_map: Option<Fields>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ItemsType {
     #[serde(rename = "file")]
     File,
@@ -4842,7 +5238,22 @@ impl Default for ItemsType {
         ItemsType::Noop
     }
 }
+
 impl ItemsType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ItemsType::File => "file",
+            ItemsType::Noop => "",
+            ItemsType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ItemsType] {
+        &[ItemsType::File]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ItemsType::Noop)
     }
@@ -4868,7 +5279,7 @@ pub struct Items {
     pub type_: Option<ItemsType>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum MessagePinType {
     #[serde(rename = "message")]
     Message,
@@ -4894,7 +5305,22 @@ impl Default for MessagePinType {
         MessagePinType::Noop
     }
 }
+
 impl MessagePinType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MessagePinType::Message => "message",
+            MessagePinType::Noop => "",
+            MessagePinType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [MessagePinType] {
+        &[MessagePinType::Message]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, MessagePinType::Noop)
     }
@@ -4926,16 +5352,33 @@ pub struct MessagePin {
     pub type_: Option<MessagePinType>,
 }
 
-/// All of the following types are flattened into one object:
+/// All of the following types:
 ///
 /// - `Items`
 /// - `MessagePin`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ItemsAnyOf {
-    #[serde(flatten)]
-    pub items: Items,
-    #[serde(flatten)]
-    pub message_pin: MessagePin,
+#[serde(untagged)]
+pub enum ItemsAnyOf {
+    Items(Items),
+    MessagePin(MessagePin),
+}
+
+impl ItemsAnyOf {
+    pub fn items(&self) -> Option<&Items> {
+        if let ItemsAnyOf::Items(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn message_pin(&self) -> Option<&MessagePin> {
+        if let ItemsAnyOf::MessagePin(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -4968,16 +5411,33 @@ pub struct PinsListResponseData {
     pub ok: bool,
 }
 
-/// All of the following types are flattened into one object:
+/// All of the following types:
 ///
 /// - `PinsListResponse`
 /// - `PinsListResponseData`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PinsListResponseAnyOf {
-    #[serde(flatten)]
-    pub pins_list_response: PinsListResponse,
-    #[serde(flatten)]
-    pub pins_list_response_data: PinsListResponseData,
+#[serde(untagged)]
+pub enum PinsListResponseAnyOf {
+    PinsListResponse(PinsListResponse),
+    PinsListResponseData(PinsListResponseData),
+}
+
+impl PinsListResponseAnyOf {
+    pub fn pins_list_response(&self) -> Option<&PinsListResponse> {
+        if let PinsListResponseAnyOf::PinsListResponse(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn pins_list_response_data(&self) -> Option<&PinsListResponseData> {
+        if let PinsListResponseAnyOf::PinsListResponseData(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
 }
 
 /// Schema for successful response from reactions.list method
@@ -5643,34 +6103,65 @@ pub struct UsersIdentityResponseDataTypeLinks {
     pub user: SelfData,
 }
 
-/// All of the following types are flattened into one object:
+/// All of the following types:
 ///
 /// - `UsersIdentityResponse`
 /// - `UsersIdentityResponseData`
 /// - `UsersIdentityResponseDataType`
 /// - `UsersIdentityResponseDataTypeLinks`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct UsersIdentityResponseAnyOf {
+#[serde(untagged)]
+pub enum UsersIdentityResponseAnyOf {
     /**
      * Schema for 'identity.basic' scope
      */
-    #[serde(flatten)]
-    pub users_identity_response: UsersIdentityResponse,
+    UsersIdentityResponse(UsersIdentityResponse),
     /**
      * Schema for 'identity.basic,identity.email' scopes
      */
-    #[serde(flatten)]
-    pub users_identity_response_data: UsersIdentityResponseData,
+    UsersIdentityResponseData(UsersIdentityResponseData),
     /**
      * Schema for 'identity.basic,identity.avatar' scopes
      */
-    #[serde(flatten)]
-    pub users_identity_response_data_type: UsersIdentityResponseDataType,
+    UsersIdentityResponseDataType(UsersIdentityResponseDataType),
     /**
      * Schema for 'identity.basic,identity.team' scopes
      */
-    #[serde(flatten)]
-    pub users_identity_response_data_type_links: UsersIdentityResponseDataTypeLinks,
+    UsersIdentityResponseDataTypeLinks(UsersIdentityResponseDataTypeLinks),
+}
+
+impl UsersIdentityResponseAnyOf {
+    pub fn users_identity_response(&self) -> Option<&UsersIdentityResponse> {
+        if let UsersIdentityResponseAnyOf::UsersIdentityResponse(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn users_identity_response_data(&self) -> Option<&UsersIdentityResponseData> {
+        if let UsersIdentityResponseAnyOf::UsersIdentityResponseData(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn users_identity_response_data_type(&self) -> Option<&UsersIdentityResponseDataType> {
+        if let UsersIdentityResponseAnyOf::UsersIdentityResponseDataType(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn users_identity_response_data_type_links(
+        &self,
+    ) -> Option<&UsersIdentityResponseDataTypeLinks> {
+        if let UsersIdentityResponseAnyOf::UsersIdentityResponseDataTypeLinks(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
 }
 
 /// Schema for successful response from users.info method
@@ -5828,3 +6319,296 @@ pub struct UsersSetPhotoSchema {
     #[serde()]
     pub profile: UsersSetPhotoSchemaProfile,
 }
+
+/// The types taken and returned by the functions in [`crate::admin_apps`].
+pub mod admin_apps {
+    pub use super::DndEndSchema;
+}
+
+/// The types taken and returned by the functions in [`crate::admin_apps_approved`].
+pub mod admin_apps_approved {
+    pub use super::DndEndSchema;
+}
+
+/// The types taken and returned by the functions in [`crate::admin_apps_requests`].
+pub mod admin_apps_requests {
+    pub use super::DndEndSchema;
+}
+
+/// The types taken and returned by the functions in [`crate::admin_apps_restricted`].
+pub mod admin_apps_restricted {
+    pub use super::DndEndSchema;
+}
+
+/// The types taken and returned by the functions in [`crate::admin_conversations`].
+pub mod admin_conversations {
+    pub use super::{
+        AdminConversationsCreateSchema, AdminConversationsGetConversationPrefsSchemaData,
+        AdminConversationsGetTeamsSchema, AdminConversationsSearchSchema, DndEndSchema,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::admin_conversations_ekm`].
+pub mod admin_conversations_ekm {
+    pub use super::DndEndSchema;
+}
+
+/// The types taken and returned by the functions in [`crate::admin_conversations_restrict_access`].
+pub mod admin_conversations_restrict_access {
+    pub use super::DndEndSchema;
+}
+
+/// The types taken and returned by the functions in [`crate::admin_emoji`].
+pub mod admin_emoji {
+    pub use super::DndEndSchema;
+}
+
+/// The types taken and returned by the functions in [`crate::admin_invite_requests`].
+pub mod admin_invite_requests {
+    pub use super::{AdminInviteRequestsApproveRequest, DndEndSchema};
+}
+
+/// The types taken and returned by the functions in [`crate::admin_invite_requests_approved`].
+pub mod admin_invite_requests_approved {
+    pub use super::DndEndSchema;
+}
+
+/// The types taken and returned by the functions in [`crate::admin_invite_requests_denied`].
+pub mod admin_invite_requests_denied {
+    pub use super::DndEndSchema;
+}
+
+/// The types taken and returned by the functions in [`crate::admin_teams`].
+pub mod admin_teams {
+    pub use super::DndEndSchema;
+}
+
+/// The types taken and returned by the functions in [`crate::admin_teams_admins`].
+pub mod admin_teams_admins {
+    pub use super::DndEndSchema;
+}
+
+/// The types taken and returned by the functions in [`crate::admin_teams_owners`].
+pub mod admin_teams_owners {
+    pub use super::DndEndSchema;
+}
+
+/// The types taken and returned by the functions in [`crate::admin_teams_settings`].
+pub mod admin_teams_settings {
+    pub use super::DndEndSchema;
+}
+
+/// The types taken and returned by the functions in [`crate::admin_usergroups`].
+pub mod admin_usergroups {
+    pub use super::DndEndSchema;
+}
+
+/// The types taken and returned by the functions in [`crate::admin_users`].
+pub mod admin_users {
+    pub use super::DndEndSchema;
+}
+
+/// The types taken and returned by the functions in [`crate::admin_users_session`].
+pub mod admin_users_session {
+    pub use super::DndEndSchema;
+}
+
+/// The types taken and returned by the functions in [`crate::api`].
+pub mod api {
+    pub use super::DndEndSchema;
+}
+
+/// The types taken and returned by the functions in [`crate::apps`].
+pub mod apps {
+    pub use super::DndEndSchema;
+}
+
+/// The types taken and returned by the functions in [`crate::apps_event_authorizations`].
+pub mod apps_event_authorizations {
+    pub use super::DndEndSchema;
+}
+
+/// The types taken and returned by the functions in [`crate::apps_permissions`].
+pub mod apps_permissions {
+    pub use super::{AppsPermissionsInfoSchema, DndEndSchema};
+}
+
+/// The types taken and returned by the functions in [`crate::apps_permissions_resources`].
+pub mod apps_permissions_resources {
+    pub use super::AppsPermissionsResourcesListSuccessSchema;
+}
+
+/// The types taken and returned by the functions in [`crate::apps_permissions_scopes`].
+pub mod apps_permissions_scopes {
+    pub use super::ApiPermissionsScopesListSuccessSchema;
+}
+
+/// The types taken and returned by the functions in [`crate::apps_permissions_users`].
+pub mod apps_permissions_users {
+    pub use super::DndEndSchema;
+}
+
+/// The types taken and returned by the functions in [`crate::auth`].
+pub mod auth {
+    pub use super::{AuthRevokeSchema, AuthTestSuccessSchema};
+}
+
+/// The types taken and returned by the functions in [`crate::bots`].
+pub mod bots {
+    pub use super::BotsInfoSchema;
+}
+
+/// The types taken and returned by the functions in [`crate::calls`].
+pub mod calls {
+    pub use super::DndEndSchema;
+}
+
+/// The types taken and returned by the functions in [`crate::calls_participants`].
+pub mod calls_participants {
+    pub use super::DndEndSchema;
+}
+
+/// The types taken and returned by the functions in [`crate::chat`].
+pub mod chat {
+    pub use super::{
+        ChatDeleteSuccessSchema, ChatGetPermalinkSuccessSchema, ChatMeMessageSchema,
+        ChatPostEphemeralSuccessSchema, ChatPostMessageSuccessSchema,
+        ChatScheduleMessageSuccessSchema, ChatUpdateSuccessSchema, DndEndSchema,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::chat_scheduled_messages`].
+pub mod chat_scheduled_messages {
+    pub use super::ChatScheduledMessagesListSchema;
+}
+
+/// The types taken and returned by the functions in [`crate::conversations`].
+pub mod conversations {
+    pub use super::{
+        ConversationsCloseSuccessSchema, ConversationsHistorySuccessSchema,
+        ConversationsInfoSuccessSchema, ConversationsJoinSuccessSchema,
+        ConversationsLeaveSuccessSchema, ConversationsListSuccessSchema,
+        ConversationsMembersSuccessSchema, ConversationsOpenSuccessSchema,
+        ConversationsRepliesSuccessSchema, DndEndSchema,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::dialog`].
+pub mod dialog {
+    pub use super::DndEndSchema;
+}
+
+/// The types taken and returned by the functions in [`crate::dnd`].
+pub mod dnd {
+    pub use super::{DndEndSchema, DndEndSnoozeSchema, DndInfoSchema, DndSetSnoozeSchema};
+}
+
+/// The types taken and returned by the functions in [`crate::emoji`].
+pub mod emoji {
+    pub use super::DndEndSchema;
+}
+
+/// The types taken and returned by the functions in [`crate::files`].
+pub mod files {
+    pub use super::{DndEndSchema, FilesInfoSchema, FilesListSchema, FilesUploadSchema};
+}
+
+/// The types taken and returned by the functions in [`crate::files_comments`].
+pub mod files_comments {
+    pub use super::DndEndSchema;
+}
+
+/// The types taken and returned by the functions in [`crate::files_remote`].
+pub mod files_remote {
+    pub use super::DndEndSchema;
+}
+
+/// The types taken and returned by the functions in [`crate::migration`].
+pub mod migration {
+    pub use super::MigrationExchangeSuccessSchema;
+}
+
+/// The types taken and returned by the functions in [`crate::oauth`].
+pub mod oauth {
+    pub use super::DndEndSchema;
+}
+
+/// The types taken and returned by the functions in [`crate::oauth_v_2`].
+pub mod oauth_v_2 {
+    pub use super::DndEndSchema;
+}
+
+/// The types taken and returned by the functions in [`crate::pins`].
+pub mod pins {
+    pub use super::{DndEndSchema, PinsListResponseAnyOf};
+}
+
+/// The types taken and returned by the functions in [`crate::reactions`].
+pub mod reactions {
+    pub use super::{DndEndSchema, Fields, ReactionsListSchema};
+}
+
+/// The types taken and returned by the functions in [`crate::reminders`].
+pub mod reminders {
+    pub use super::{DndEndSchema, RemindersAddSchema, RemindersListSchema};
+}
+
+/// The types taken and returned by the functions in [`crate::rtm`].
+pub mod rtm {
+    pub use super::RtmConnectSchema;
+}
+
+/// The types taken and returned by the functions in [`crate::search`].
+pub mod search {
+    pub use super::DndEndSchema;
+}
+
+/// The types taken and returned by the functions in [`crate::stars`].
+pub mod stars {
+    pub use super::{DndEndSchema, StarsListSchema};
+}
+
+/// The types taken and returned by the functions in [`crate::team`].
+pub mod team {
+    pub use super::{
+        DndEndSchema, TeamAccessLogsSchema, TeamInfoSchema, TeamIntegrationLogsSchema,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::team_profile`].
+pub mod team_profile {
+    pub use super::TeamProfileGetSuccessSchema;
+}
+
+/// The types taken and returned by the functions in [`crate::usergroups`].
+pub mod usergroups {
+    pub use super::{UsergroupsCreateSchema, UsergroupsListSchema};
+}
+
+/// The types taken and returned by the functions in [`crate::usergroups_users`].
+pub mod usergroups_users {
+    pub use super::{UsergroupsCreateSchema, UsergroupsUsersListSchema};
+}
+
+/// The types taken and returned by the functions in [`crate::users`].
+pub mod users {
+    pub use super::{
+        ApiMethodUsersGetPresence, DndEndSchema, UsersConversationsSuccessSchema,
+        UsersIdentityResponseAnyOf, UsersInfoSuccessSchema, UsersListSchema, UsersSetPhotoSchema,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::users_profile`].
+pub mod users_profile {
+    pub use super::{UsersProfileGetSchema, UsersProfileSetSchema};
+}
+
+/// The types taken and returned by the functions in [`crate::views`].
+pub mod views {
+    pub use super::DndEndSchema;
+}
+
+/// The types taken and returned by the functions in [`crate::workflows`].
+pub mod workflows {
+    pub use super::DndEndSchema;
+}
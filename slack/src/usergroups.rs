@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Usergroups {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Usergroups {
         Usergroups { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Usergroups {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `POST` to the `/usergroups.create` endpoint.
      *
@@ -25,7 +31,10 @@ impl Usergroups {
      */
     pub async fn create(&self) -> Result<crate::types::UsergroupsCreateSchema> {
         let url = "/usergroups.create".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/usergroups.create", &[]))
     }
 
     /**
@@ -41,7 +50,10 @@ impl Usergroups {
      */
     pub async fn disable(&self) -> Result<crate::types::UsergroupsCreateSchema> {
         let url = "/usergroups.disable".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/usergroups.disable", &[]))
     }
 
     /**
@@ -57,7 +69,10 @@ impl Usergroups {
      */
     pub async fn enable(&self) -> Result<crate::types::UsergroupsCreateSchema> {
         let url = "/usergroups.enable".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/usergroups.enable", &[]))
     }
 
     /**
@@ -80,20 +95,22 @@ impl Usergroups {
         include_count: bool,
         include_disabled: bool,
     ) -> Result<crate::types::UsergroupsListSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if include_count {
-            query_args.push(("include_count".to_string(), include_count.to_string()));
+            query_args.push(("include_count", include_count.to_string()));
         }
         if include_disabled {
-            query_args.push(("include_disabled".to_string(), include_disabled.to_string()));
+            query_args.push(("include_disabled", include_disabled.to_string()));
         }
         if include_users {
-            query_args.push(("include_users".to_string(), include_users.to_string()));
+            query_args.push(("include_users", include_users.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/usergroups.list?{}", query_);
+        let url = crate::Client::url_with_query("/usergroups.list", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/usergroups.list", &[]))
     }
 
     /**
@@ -109,6 +126,52 @@ impl Usergroups {
      */
     pub async fn update(&self) -> Result<crate::types::UsergroupsCreateSchema> {
         let url = "/usergroups.update".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/usergroups.update", &[]))
+    }
+}
+
+/// The functions of [`Usergroups`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn UsergroupsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait UsergroupsApi: Send + Sync {
+    async fn create(&self) -> Result<crate::types::UsergroupsCreateSchema>;
+    async fn disable(&self) -> Result<crate::types::UsergroupsCreateSchema>;
+    async fn enable(&self) -> Result<crate::types::UsergroupsCreateSchema>;
+    async fn list(
+        &self,
+        include_users: bool,
+        include_count: bool,
+        include_disabled: bool,
+    ) -> Result<crate::types::UsergroupsListSchema>;
+    async fn update(&self) -> Result<crate::types::UsergroupsCreateSchema>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl UsergroupsApi for Usergroups {
+    async fn create(&self) -> Result<crate::types::UsergroupsCreateSchema> {
+        Usergroups::create(self).await
+    }
+    async fn disable(&self) -> Result<crate::types::UsergroupsCreateSchema> {
+        Usergroups::disable(self).await
+    }
+    async fn enable(&self) -> Result<crate::types::UsergroupsCreateSchema> {
+        Usergroups::enable(self).await
+    }
+    async fn list(
+        &self,
+        include_users: bool,
+        include_count: bool,
+        include_disabled: bool,
+    ) -> Result<crate::types::UsergroupsListSchema> {
+        Usergroups::list(self, include_users, include_count, include_disabled).await
+    }
+    async fn update(&self) -> Result<crate::types::UsergroupsCreateSchema> {
+        Usergroups::update(self).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct UsergroupsUsers {
     pub client: Client,
@@ -12,6 +10,14 @@ impl UsergroupsUsers {
         UsergroupsUsers { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        UsergroupsUsers {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/usergroups.users.list` endpoint.
      *
@@ -30,17 +36,19 @@ impl UsergroupsUsers {
         include_disabled: bool,
         usergroup: &str,
     ) -> Result<crate::types::UsergroupsUsersListSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if include_disabled {
-            query_args.push(("include_disabled".to_string(), include_disabled.to_string()));
+            query_args.push(("include_disabled", include_disabled.to_string()));
         }
         if !usergroup.is_empty() {
-            query_args.push(("usergroup".to_string(), usergroup.to_string()));
+            query_args.push(("usergroup", usergroup.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/usergroups.users.list?{}", query_);
+        let url = crate::Client::url_with_query("/usergroups.users.list", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/usergroups.users.list", &[]))
     }
 
     /**
@@ -56,6 +64,38 @@ impl UsergroupsUsers {
      */
     pub async fn update(&self) -> Result<crate::types::UsergroupsCreateSchema> {
         let url = "/usergroups.users.update".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/usergroups.users.update", &[]))
+    }
+}
+
+/// The functions of [`UsergroupsUsers`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn UsergroupsUsersApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait UsergroupsUsersApi: Send + Sync {
+    async fn list(
+        &self,
+        include_disabled: bool,
+        usergroup: &str,
+    ) -> Result<crate::types::UsergroupsUsersListSchema>;
+    async fn update(&self) -> Result<crate::types::UsergroupsCreateSchema>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl UsergroupsUsersApi for UsergroupsUsers {
+    async fn list(
+        &self,
+        include_disabled: bool,
+        usergroup: &str,
+    ) -> Result<crate::types::UsergroupsUsersListSchema> {
+        UsergroupsUsers::list(self, include_disabled, usergroup).await
+    }
+    async fn update(&self) -> Result<crate::types::UsergroupsCreateSchema> {
+        UsergroupsUsers::update(self).await
     }
 }
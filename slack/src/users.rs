@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Users {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Users {
         Users { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Users {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/users.conversations` endpoint.
      *
@@ -36,26 +42,28 @@ impl Users {
         limit: i64,
         cursor: &str,
     ) -> Result<crate::types::UsersConversationsSuccessSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if exclude_archived {
-            query_args.push(("exclude_archived".to_string(), exclude_archived.to_string()));
+            query_args.push(("exclude_archived", exclude_archived.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
         if !types.is_empty() {
-            query_args.push(("types".to_string(), types.to_string()));
+            query_args.push(("types", types.to_string()));
         }
         if !user.is_empty() {
-            query_args.push(("user".to_string(), user.to_string()));
+            query_args.push(("user", user.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/users.conversations?{}", query_);
+        let url = crate::Client::url_with_query("/users.conversations", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/users.conversations", &[]))
     }
 
     /**
@@ -67,7 +75,10 @@ impl Users {
      */
     pub async fn delete_photo(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/users.deletePhoto".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/users.deletePhoto", &[]))
     }
 
     /**
@@ -86,14 +97,16 @@ impl Users {
         &self,
         user: &str,
     ) -> Result<crate::types::ApiMethodUsersGetPresence> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !user.is_empty() {
-            query_args.push(("user".to_string(), user.to_string()));
+            query_args.push(("user", user.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/users.getPresence?{}", query_);
+        let url = crate::Client::url_with_query("/users.getPresence", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/users.getPresence", &[]))
     }
 
     /**
@@ -109,7 +122,10 @@ impl Users {
      */
     pub async fn identity(&self) -> Result<Vec<crate::types::UsersIdentityResponseAnyOf>> {
         let url = "/users.identity".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/users.identity", &[]))
     }
 
     /**
@@ -123,7 +139,10 @@ impl Users {
      */
     pub async fn get_all_identity(&self) -> Result<Vec<crate::types::UsersIdentityResponseAnyOf>> {
         let url = "/users.identity".to_string();
-        self.client.get_all_pages(&url, None).await
+        self.client
+            .get_all_pages(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/users.identity", &[]))
     }
 
     /**
@@ -144,17 +163,19 @@ impl Users {
         include_locale: bool,
         user: &str,
     ) -> Result<crate::types::UsersInfoSuccessSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if include_locale {
-            query_args.push(("include_locale".to_string(), include_locale.to_string()));
+            query_args.push(("include_locale", include_locale.to_string()));
         }
         if !user.is_empty() {
-            query_args.push(("user".to_string(), user.to_string()));
+            query_args.push(("user", user.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/users.info?{}", query_);
+        let url = crate::Client::url_with_query("/users.info", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/users.info", &[]))
     }
 
     /**
@@ -177,20 +198,22 @@ impl Users {
         cursor: &str,
         include_locale: bool,
     ) -> Result<crate::types::UsersListSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cursor.is_empty() {
-            query_args.push(("cursor".to_string(), cursor.to_string()));
+            query_args.push(("cursor", cursor.to_string()));
         }
         if include_locale {
-            query_args.push(("include_locale".to_string(), include_locale.to_string()));
+            query_args.push(("include_locale", include_locale.to_string()));
         }
         if limit > 0 {
-            query_args.push(("limit".to_string(), limit.to_string()));
+            query_args.push(("limit", limit.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/users.list?{}", query_);
+        let url = crate::Client::url_with_query("/users.list", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/users.list", &[]))
     }
 
     /**
@@ -206,14 +229,16 @@ impl Users {
      * * `email: &str` -- An email address belonging to a user in the workspace.
      */
     pub async fn lookup_email(&self, email: &str) -> Result<crate::types::UsersInfoSuccessSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !email.is_empty() {
-            query_args.push(("email".to_string(), email.to_string()));
+            query_args.push(("email", email.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/users.lookupByEmail?{}", query_);
+        let url = crate::Client::url_with_query("/users.lookupByEmail", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/users.lookupByEmail", &[]))
     }
 
     /**
@@ -229,7 +254,10 @@ impl Users {
      */
     pub async fn set_active(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/users.setActive".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/users.setActive", &[]))
     }
 
     /**
@@ -241,7 +269,10 @@ impl Users {
      */
     pub async fn set_photo(&self) -> Result<crate::types::UsersSetPhotoSchema> {
         let url = "/users.setPhoto".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/users.setPhoto", &[]))
     }
 
     /**
@@ -257,6 +288,98 @@ impl Users {
      */
     pub async fn set_presence(&self) -> Result<crate::types::DndEndSchema> {
         let url = "/users.setPresence".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/users.setPresence", &[]))
+    }
+}
+
+/// The functions of [`Users`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn UsersApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait UsersApi: Send + Sync {
+    async fn conversation(
+        &self,
+        user: &str,
+        types: &str,
+        exclude_archived: bool,
+        limit: i64,
+        cursor: &str,
+    ) -> Result<crate::types::UsersConversationsSuccessSchema>;
+    async fn delete_photo(&self) -> Result<crate::types::DndEndSchema>;
+    async fn get_presence(&self, user: &str) -> Result<crate::types::ApiMethodUsersGetPresence>;
+    async fn identity(&self) -> Result<Vec<crate::types::UsersIdentityResponseAnyOf>>;
+    async fn get_all_identity(&self) -> Result<Vec<crate::types::UsersIdentityResponseAnyOf>>;
+    async fn info(
+        &self,
+        include_locale: bool,
+        user: &str,
+    ) -> Result<crate::types::UsersInfoSuccessSchema>;
+    async fn list(
+        &self,
+        limit: i64,
+        cursor: &str,
+        include_locale: bool,
+    ) -> Result<crate::types::UsersListSchema>;
+    async fn lookup_email(&self, email: &str) -> Result<crate::types::UsersInfoSuccessSchema>;
+    async fn set_active(&self) -> Result<crate::types::DndEndSchema>;
+    async fn set_photo(&self) -> Result<crate::types::UsersSetPhotoSchema>;
+    async fn set_presence(&self) -> Result<crate::types::DndEndSchema>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl UsersApi for Users {
+    async fn conversation(
+        &self,
+        user: &str,
+        types: &str,
+        exclude_archived: bool,
+        limit: i64,
+        cursor: &str,
+    ) -> Result<crate::types::UsersConversationsSuccessSchema> {
+        Users::conversation(self, user, types, exclude_archived, limit, cursor).await
+    }
+    async fn delete_photo(&self) -> Result<crate::types::DndEndSchema> {
+        Users::delete_photo(self).await
+    }
+    async fn get_presence(&self, user: &str) -> Result<crate::types::ApiMethodUsersGetPresence> {
+        Users::get_presence(self, user).await
+    }
+    async fn identity(&self) -> Result<Vec<crate::types::UsersIdentityResponseAnyOf>> {
+        Users::identity(self).await
+    }
+    async fn get_all_identity(&self) -> Result<Vec<crate::types::UsersIdentityResponseAnyOf>> {
+        Users::get_all_identity(self).await
+    }
+    async fn info(
+        &self,
+        include_locale: bool,
+        user: &str,
+    ) -> Result<crate::types::UsersInfoSuccessSchema> {
+        Users::info(self, include_locale, user).await
+    }
+    async fn list(
+        &self,
+        limit: i64,
+        cursor: &str,
+        include_locale: bool,
+    ) -> Result<crate::types::UsersListSchema> {
+        Users::list(self, limit, cursor, include_locale).await
+    }
+    async fn lookup_email(&self, email: &str) -> Result<crate::types::UsersInfoSuccessSchema> {
+        Users::lookup_email(self, email).await
+    }
+    async fn set_active(&self) -> Result<crate::types::DndEndSchema> {
+        Users::set_active(self).await
+    }
+    async fn set_photo(&self) -> Result<crate::types::UsersSetPhotoSchema> {
+        Users::set_photo(self).await
+    }
+    async fn set_presence(&self) -> Result<crate::types::DndEndSchema> {
+        Users::set_presence(self).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct UsersProfile {
     pub client: Client,
@@ -12,6 +10,14 @@ impl UsersProfile {
         UsersProfile { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        UsersProfile {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/users.profile.get` endpoint.
      *
@@ -30,17 +36,19 @@ impl UsersProfile {
         include_labels: bool,
         user: &str,
     ) -> Result<crate::types::UsersProfileGetSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if include_labels {
-            query_args.push(("include_labels".to_string(), include_labels.to_string()));
+            query_args.push(("include_labels", include_labels.to_string()));
         }
         if !user.is_empty() {
-            query_args.push(("user".to_string(), user.to_string()));
+            query_args.push(("user", user.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/users.profile.get?{}", query_);
+        let url = crate::Client::url_with_query("/users.profile.get", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/users.profile.get", &[]))
     }
 
     /**
@@ -56,6 +64,38 @@ impl UsersProfile {
      */
     pub async fn set(&self) -> Result<crate::types::UsersProfileSetSchema> {
         let url = "/users.profile.set".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .post(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/users.profile.set", &[]))
+    }
+}
+
+/// The functions of [`UsersProfile`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn UsersProfileApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait UsersProfileApi: Send + Sync {
+    async fn get(
+        &self,
+        include_labels: bool,
+        user: &str,
+    ) -> Result<crate::types::UsersProfileGetSchema>;
+    async fn set(&self) -> Result<crate::types::UsersProfileSetSchema>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl UsersProfileApi for UsersProfile {
+    async fn get(
+        &self,
+        include_labels: bool,
+        user: &str,
+    ) -> Result<crate::types::UsersProfileGetSchema> {
+        UsersProfile::get(self, include_labels, user).await
+    }
+    async fn set(&self) -> Result<crate::types::UsersProfileSetSchema> {
+        UsersProfile::set(self).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Views {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Views {
         Views { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Views {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/views.open` endpoint.
      *
@@ -26,17 +32,19 @@ impl Views {
      * * `view: &str` -- A [view payload](/reference/surfaces/views). This must be a JSON-encoded string.
      */
     pub async fn open(&self, trigger_id: &str, view: &str) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !trigger_id.is_empty() {
-            query_args.push(("trigger_id".to_string(), trigger_id.to_string()));
+            query_args.push(("trigger_id", trigger_id.to_string()));
         }
         if !view.is_empty() {
-            query_args.push(("view".to_string(), view.to_string()));
+            query_args.push(("view", view.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/views.open?{}", query_);
+        let url = crate::Client::url_with_query("/views.open", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/views.open", &[]))
     }
 
     /**
@@ -59,20 +67,22 @@ impl Views {
         view: &str,
         hash: &str,
     ) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !hash.is_empty() {
-            query_args.push(("hash".to_string(), hash.to_string()));
+            query_args.push(("hash", hash.to_string()));
         }
         if !user_id.is_empty() {
-            query_args.push(("user_id".to_string(), user_id.to_string()));
+            query_args.push(("user_id", user_id.to_string()));
         }
         if !view.is_empty() {
-            query_args.push(("view".to_string(), view.to_string()));
+            query_args.push(("view", view.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/views.publish?{}", query_);
+        let url = crate::Client::url_with_query("/views.publish", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/views.publish", &[]))
     }
 
     /**
@@ -89,17 +99,19 @@ impl Views {
      * * `view: &str` -- A [view payload](/reference/surfaces/views). This must be a JSON-encoded string.
      */
     pub async fn push(&self, trigger_id: &str, view: &str) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !trigger_id.is_empty() {
-            query_args.push(("trigger_id".to_string(), trigger_id.to_string()));
+            query_args.push(("trigger_id", trigger_id.to_string()));
         }
         if !view.is_empty() {
-            query_args.push(("view".to_string(), view.to_string()));
+            query_args.push(("view", view.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/views.push?{}", query_);
+        let url = crate::Client::url_with_query("/views.push", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/views.push", &[]))
     }
 
     /**
@@ -124,22 +136,75 @@ impl Views {
         view: &str,
         hash: &str,
     ) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !external_id.is_empty() {
-            query_args.push(("external_id".to_string(), external_id.to_string()));
+            query_args.push(("external_id", external_id.to_string()));
         }
         if !hash.is_empty() {
-            query_args.push(("hash".to_string(), hash.to_string()));
+            query_args.push(("hash", hash.to_string()));
         }
         if !view.is_empty() {
-            query_args.push(("view".to_string(), view.to_string()));
+            query_args.push(("view", view.to_string()));
         }
         if !view_id.is_empty() {
-            query_args.push(("view_id".to_string(), view_id.to_string()));
+            query_args.push(("view_id", view_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/views.update?{}", query_);
+        let url = crate::Client::url_with_query("/views.update", &query_args);
+
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/views.update", &[]))
+    }
+}
+
+/// The functions of [`Views`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ViewsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ViewsApi: Send + Sync {
+    async fn open(&self, trigger_id: &str, view: &str) -> Result<crate::types::DndEndSchema>;
+    async fn publish(
+        &self,
+        user_id: &str,
+        view: &str,
+        hash: &str,
+    ) -> Result<crate::types::DndEndSchema>;
+    async fn push(&self, trigger_id: &str, view: &str) -> Result<crate::types::DndEndSchema>;
+    async fn update(
+        &self,
+        view_id: &str,
+        external_id: &str,
+        view: &str,
+        hash: &str,
+    ) -> Result<crate::types::DndEndSchema>;
+}
 
-        self.client.get(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ViewsApi for Views {
+    async fn open(&self, trigger_id: &str, view: &str) -> Result<crate::types::DndEndSchema> {
+        Views::open(self, trigger_id, view).await
+    }
+    async fn publish(
+        &self,
+        user_id: &str,
+        view: &str,
+        hash: &str,
+    ) -> Result<crate::types::DndEndSchema> {
+        Views::publish(self, user_id, view, hash).await
+    }
+    async fn push(&self, trigger_id: &str, view: &str) -> Result<crate::types::DndEndSchema> {
+        Views::push(self, trigger_id, view).await
+    }
+    async fn update(
+        &self,
+        view_id: &str,
+        external_id: &str,
+        view: &str,
+        hash: &str,
+    ) -> Result<crate::types::DndEndSchema> {
+        Views::update(self, view_id, external_id, view, hash).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Workflows {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Workflows {
         Workflows { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Workflows {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * This function performs a `GET` to the `/workflows.stepCompleted` endpoint.
      *
@@ -30,20 +36,22 @@ impl Workflows {
         workflow_step_execute_id: &str,
         outputs: &str,
     ) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !outputs.is_empty() {
-            query_args.push(("outputs".to_string(), outputs.to_string()));
+            query_args.push(("outputs", outputs.to_string()));
         }
         if !workflow_step_execute_id.is_empty() {
             query_args.push((
-                "workflow_step_execute_id".to_string(),
+                "workflow_step_execute_id",
                 workflow_step_execute_id.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/workflows.stepCompleted?{}", query_);
+        let url = crate::Client::url_with_query("/workflows.stepCompleted", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/workflows.stepCompleted", &[]))
     }
 
     /**
@@ -64,20 +72,22 @@ impl Workflows {
         workflow_step_execute_id: &str,
         error: &str,
     ) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !error.is_empty() {
-            query_args.push(("error".to_string(), error.to_string()));
+            query_args.push(("error", error.to_string()));
         }
         if !workflow_step_execute_id.is_empty() {
             query_args.push((
-                "workflow_step_execute_id".to_string(),
+                "workflow_step_execute_id",
                 workflow_step_execute_id.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/workflows.stepFailed?{}", query_);
+        let url = crate::Client::url_with_query("/workflows.stepFailed", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/workflows.stepFailed", &[]))
     }
 
     /**
@@ -104,28 +114,90 @@ impl Workflows {
         step_name: &str,
         step_image_url: &str,
     ) -> Result<crate::types::DndEndSchema> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !inputs.is_empty() {
-            query_args.push(("inputs".to_string(), inputs.to_string()));
+            query_args.push(("inputs", inputs.to_string()));
         }
         if !outputs.is_empty() {
-            query_args.push(("outputs".to_string(), outputs.to_string()));
+            query_args.push(("outputs", outputs.to_string()));
         }
         if !step_image_url.is_empty() {
-            query_args.push(("step_image_url".to_string(), step_image_url.to_string()));
+            query_args.push(("step_image_url", step_image_url.to_string()));
         }
         if !step_name.is_empty() {
-            query_args.push(("step_name".to_string(), step_name.to_string()));
+            query_args.push(("step_name", step_name.to_string()));
         }
         if !workflow_step_edit_id.is_empty() {
-            query_args.push((
-                "workflow_step_edit_id".to_string(),
-                workflow_step_edit_id.to_string(),
-            ));
+            query_args.push(("workflow_step_edit_id", workflow_step_edit_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/workflows.updateStep?{}", query_);
+        let url = crate::Client::url_with_query("/workflows.updateStep", &query_args);
+
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/workflows.updateStep", &[]))
+    }
+}
+
+/// The functions of [`Workflows`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn WorkflowsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait WorkflowsApi: Send + Sync {
+    async fn step_completed(
+        &self,
+        workflow_step_execute_id: &str,
+        outputs: &str,
+    ) -> Result<crate::types::DndEndSchema>;
+    async fn step_failed(
+        &self,
+        workflow_step_execute_id: &str,
+        error: &str,
+    ) -> Result<crate::types::DndEndSchema>;
+    async fn update_step(
+        &self,
+        workflow_step_edit_id: &str,
+        inputs: &str,
+        outputs: &str,
+        step_name: &str,
+        step_image_url: &str,
+    ) -> Result<crate::types::DndEndSchema>;
+}
 
-        self.client.get(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl WorkflowsApi for Workflows {
+    async fn step_completed(
+        &self,
+        workflow_step_execute_id: &str,
+        outputs: &str,
+    ) -> Result<crate::types::DndEndSchema> {
+        Workflows::step_completed(self, workflow_step_execute_id, outputs).await
+    }
+    async fn step_failed(
+        &self,
+        workflow_step_execute_id: &str,
+        error: &str,
+    ) -> Result<crate::types::DndEndSchema> {
+        Workflows::step_failed(self, workflow_step_execute_id, error).await
+    }
+    async fn update_step(
+        &self,
+        workflow_step_edit_id: &str,
+        inputs: &str,
+        outputs: &str,
+        step_name: &str,
+        step_image_url: &str,
+    ) -> Result<crate::types::DndEndSchema> {
+        Workflows::update_step(
+            self,
+            workflow_step_edit_id,
+            inputs,
+            outputs,
+            step_name,
+            step_image_url,
+        )
+        .await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct BookingData {
     pub client: Client,
@@ -12,6 +10,14 @@ impl BookingData {
         BookingData { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        BookingData {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Your company's bookings.
      *
@@ -41,35 +47,38 @@ impl BookingData {
         size: i64,
         booking_type: crate::types::BookingType,
     ) -> Result<Vec<crate::types::BookingReport>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !booking_status.to_string().is_empty() {
-            query_args.push(("bookingStatus".to_string(), booking_status.to_string()));
+            query_args.push(("bookingStatus", booking_status.to_string()));
         }
         if !booking_type.to_string().is_empty() {
-            query_args.push(("bookingType".to_string(), booking_type.to_string()));
+            query_args.push(("bookingType", booking_type.to_string()));
         }
         if !created_from.is_empty() {
-            query_args.push(("createdFrom".to_string(), created_from.to_string()));
+            query_args.push(("createdFrom", created_from.to_string()));
         }
         if !created_to.is_empty() {
-            query_args.push(("createdTo".to_string(), created_to.to_string()));
+            query_args.push(("createdTo", created_to.to_string()));
         }
         if !page.to_string().is_empty() {
-            query_args.push(("page".to_string(), page.to_string()));
+            query_args.push(("page", page.to_string()));
         }
         if size > 0 {
-            query_args.push(("size".to_string(), size.to_string()));
+            query_args.push(("size", size.to_string()));
         }
         if !start_date_from.is_empty() {
-            query_args.push(("startDateFrom".to_string(), start_date_from.to_string()));
+            query_args.push(("startDateFrom", start_date_from.to_string()));
         }
         if !start_date_to.is_empty() {
-            query_args.push(("startDateTo".to_string(), start_date_to.to_string()));
+            query_args.push(("startDateTo", start_date_to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/bookings?{}", query_);
+        let url = crate::Client::url_with_query("/v1/bookings", &query_args);
 
-        let resp: crate::types::BookingReportResponse = self.client.get(&url, None).await?;
+        let resp: crate::types::BookingReportResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/v1/bookings", &[]))?;
 
         // Return our response data.
         Ok(resp.data)
@@ -93,27 +102,26 @@ impl BookingData {
         booking_status: crate::types::BookingStatus,
         booking_type: crate::types::BookingType,
     ) -> Result<Vec<crate::types::BookingReport>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !booking_status.to_string().is_empty() {
-            query_args.push(("bookingStatus".to_string(), booking_status.to_string()));
+            query_args.push(("bookingStatus", booking_status.to_string()));
         }
         if !booking_type.to_string().is_empty() {
-            query_args.push(("bookingType".to_string(), booking_type.to_string()));
+            query_args.push(("bookingType", booking_type.to_string()));
         }
         if !created_from.is_empty() {
-            query_args.push(("createdFrom".to_string(), created_from.to_string()));
+            query_args.push(("createdFrom", created_from.to_string()));
         }
         if !created_to.is_empty() {
-            query_args.push(("createdTo".to_string(), created_to.to_string()));
+            query_args.push(("createdTo", created_to.to_string()));
         }
         if !start_date_from.is_empty() {
-            query_args.push(("startDateFrom".to_string(), start_date_from.to_string()));
+            query_args.push(("startDateFrom", start_date_from.to_string()));
         }
         if !start_date_to.is_empty() {
-            query_args.push(("startDateTo".to_string(), start_date_to.to_string()));
+            query_args.push(("startDateTo", start_date_to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/v1/bookings?{}", query_);
+        let url = crate::Client::url_with_query("/v1/bookings", &query_args);
 
         let mut resp: crate::types::BookingReportResponse = if !url.contains('?') {
             self.client
@@ -134,7 +142,8 @@ impl BookingData {
                 resp = self
                     .client
                     .get(&format!("{}?page={}", url, page), None)
-                    .await?;
+                    .await
+                    .with_context(|| crate::utils::pretty_url("GET", "/v1/bookings", &[]))?;
             } else {
                 resp = self
                     .client
@@ -151,3 +160,80 @@ impl BookingData {
         Ok(data)
     }
 }
+
+/// The functions of [`BookingData`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn BookingDataApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait BookingDataApi: Send + Sync {
+    async fn get_booking_report(
+        &self,
+        created_from: &str,
+        created_to: &str,
+        start_date_from: &str,
+        start_date_to: &str,
+        booking_status: crate::types::BookingStatus,
+        page: u64,
+        size: i64,
+        booking_type: crate::types::BookingType,
+    ) -> Result<Vec<crate::types::BookingReport>>;
+    async fn get_all_booking_report(
+        &self,
+        created_from: &str,
+        created_to: &str,
+        start_date_from: &str,
+        start_date_to: &str,
+        booking_status: crate::types::BookingStatus,
+        booking_type: crate::types::BookingType,
+    ) -> Result<Vec<crate::types::BookingReport>>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl BookingDataApi for BookingData {
+    async fn get_booking_report(
+        &self,
+        created_from: &str,
+        created_to: &str,
+        start_date_from: &str,
+        start_date_to: &str,
+        booking_status: crate::types::BookingStatus,
+        page: u64,
+        size: i64,
+        booking_type: crate::types::BookingType,
+    ) -> Result<Vec<crate::types::BookingReport>> {
+        BookingData::get_booking_report(
+            self,
+            created_from,
+            created_to,
+            start_date_from,
+            start_date_to,
+            booking_status,
+            page,
+            size,
+            booking_type,
+        )
+        .await
+    }
+    async fn get_all_booking_report(
+        &self,
+        created_from: &str,
+        created_to: &str,
+        start_date_from: &str,
+        start_date_to: &str,
+        booking_status: crate::types::BookingStatus,
+        booking_type: crate::types::BookingType,
+    ) -> Result<Vec<crate::types::BookingReport>> {
+        BookingData::get_all_booking_report(
+            self,
+            created_from,
+            created_to,
+            start_date_from,
+            start_date_to,
+            booking_status,
+            booking_type,
+        )
+        .await
+    }
+}
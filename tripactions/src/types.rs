@@ -2,7 +2,34 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+/// Either one of something, or many of them. Some endpoints send back an
+/// object when there is one and an array when there are more, so this takes both.
+// Many is tried first: serde reads a struct from an array of its fields too,
+// so an array would otherwise be taken for One.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum OneOrMany<T> {
+    Many(Vec<T>),
+    One(T),
+}
+
+impl<T> OneOrMany<T> {
+    /// All of them, as a vector.
+    pub fn into_vec(self) -> Vec<T> {
+        match self {
+            OneOrMany::One(t) => vec![t],
+            OneOrMany::Many(v) => v,
+        }
+    }
+}
+
+impl<T> Default for OneOrMany<T> {
+    fn default() -> Self {
+        OneOrMany::Many(Vec::new())
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum TravelerType {
     #[serde(rename = "GUEST")]
     Guest,
@@ -31,13 +58,29 @@ impl Default for TravelerType {
         TravelerType::Noop
     }
 }
+
 impl TravelerType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TravelerType::Guest => "GUEST",
+            TravelerType::Passenger => "PASSENGER",
+            TravelerType::Noop => "",
+            TravelerType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [TravelerType] {
+        &[TravelerType::Guest, TravelerType::Passenger]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, TravelerType::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum BookingStatus {
     #[serde(rename = "ACCEPTED")]
     Accepted,
@@ -75,13 +118,38 @@ impl Default for BookingStatus {
         BookingStatus::Noop
     }
 }
+
 impl BookingStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BookingStatus::Accepted => "ACCEPTED",
+            BookingStatus::Canceled => "CANCELED",
+            BookingStatus::Confirmed => "CONFIRMED",
+            BookingStatus::Ticketed => "TICKETED",
+            BookingStatus::Voided => "VOIDED",
+            BookingStatus::Noop => "",
+            BookingStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [BookingStatus] {
+        &[
+            BookingStatus::Accepted,
+            BookingStatus::Canceled,
+            BookingStatus::Confirmed,
+            BookingStatus::Ticketed,
+            BookingStatus::Voided,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, BookingStatus::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum BookingType {
     #[serde(rename = "BLACK_CAR")]
     BlackCar,
@@ -122,13 +190,40 @@ impl Default for BookingType {
         BookingType::Noop
     }
 }
+
 impl BookingType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BookingType::BlackCar => "BLACK_CAR",
+            BookingType::Car => "CAR",
+            BookingType::Flight => "FLIGHT",
+            BookingType::Hotel => "HOTEL",
+            BookingType::Rail => "RAIL",
+            BookingType::Transportation => "TRANSPORTATION",
+            BookingType::Noop => "",
+            BookingType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [BookingType] {
+        &[
+            BookingType::BlackCar,
+            BookingType::Car,
+            BookingType::Flight,
+            BookingType::Hotel,
+            BookingType::Rail,
+            BookingType::Transportation,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, BookingType::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PaymentSchedule {
     #[serde(rename = "LATER")]
     Later,
@@ -163,13 +258,36 @@ impl Default for PaymentSchedule {
         PaymentSchedule::Noop
     }
 }
+
 impl PaymentSchedule {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PaymentSchedule::Later => "LATER",
+            PaymentSchedule::Now => "NOW",
+            PaymentSchedule::Partial => "PARTIAL",
+            PaymentSchedule::Unknown => "UNKNOWN",
+            PaymentSchedule::Noop => "",
+            PaymentSchedule::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PaymentSchedule] {
+        &[
+            PaymentSchedule::Later,
+            PaymentSchedule::Now,
+            PaymentSchedule::Partial,
+            PaymentSchedule::Unknown,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PaymentSchedule::Noop)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PassengerStatus {
     #[serde(rename = "DISABLED")]
     Disabled,
@@ -198,19 +316,35 @@ impl Default for PassengerStatus {
         PassengerStatus::Noop
     }
 }
+
 impl PassengerStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PassengerStatus::Disabled => "DISABLED",
+            PassengerStatus::Enabled => "ENABLED",
+            PassengerStatus::Noop => "",
+            PassengerStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PassengerStatus] {
+        &[PassengerStatus::Disabled, PassengerStatus::Enabled]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PassengerStatus::Noop)
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Location {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "airportCode"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub airport_code: String,
     #[serde(
@@ -234,74 +368,61 @@ pub struct Location {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Segment {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "airlineAlliance"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub airline_alliance: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub arrival: Option<Location>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub departure: Option<Location>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "endTimestamp"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub end_timestamp: Option<serde_json::Value>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "flightNumber"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub flight_number: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "hotelChain"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub hotel_chain: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "providerCode"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub provider_code: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "providerName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub provider_name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "startTimestamp"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub start_timestamp: Option<serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Person {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "billableEntity"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub billable_entity: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "costCenter"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub cost_center: String,
     #[serde(
@@ -319,29 +440,25 @@ pub struct Person {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "employeedId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub employeed_id: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "managerEmail"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub manager_email: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "managerName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub manager_name: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "managerUuid"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub manager_uuid: String,
     #[serde(
@@ -371,16 +488,13 @@ pub struct Person {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Passenger {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub person: Option<Person>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<PassengerStatus>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "travelerType"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub traveler_type: Option<TravelerType>,
 }
 
@@ -412,97 +526,110 @@ pub struct BookingReportResponse {
     pub page: Page,
 }
 
+impl IntoIterator for BookingReportResponse {
+    type Item = BookingReport;
+    type IntoIter = std::vec::IntoIter<BookingReport>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a BookingReportResponse {
+    type Item = &'a BookingReport;
+    type IntoIter = std::slice::Iter<'a, BookingReport>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
+impl BookingReportResponse {
+    /// Iterate over the `data`.
+    pub fn iter(&self) -> std::slice::Iter<'_, BookingReport> {
+        self.data.iter()
+    }
+}
+
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Page {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "currentPage"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub current_page: i64,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "pageSize"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub page_size: i64,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "totalElements"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_elements: i64,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "totalPages"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub total_pages: i64,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct Cnr {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "cnrCodes"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub cnr_codes: Vec<String>,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "publishedPrice"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub published_price: f64,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "camelCase")]
 pub struct BookingReport {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "airlineCreditCardSurcharge"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub airline_credit_card_surcharge: f64,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "approvalChangedAt"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub approval_changed_at: Option<serde_json::Value>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "approverEmail"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub approver_email: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "approverReason"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub approver_reason: String,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "basePrice"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub base_price: f64,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "billToClient"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub bill_to_client: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -510,43 +637,34 @@ pub struct BookingReport {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "bookingDuration"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub booking_duration: i64,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "bookingFee"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub booking_fee: f64,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "bookingId"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub booking_id: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "bookingMethod"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub booking_method: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "bookingStatus"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub booking_status: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "bookingType"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub booking_type: Option<BookingType>,
     #[serde(
         default,
@@ -557,8 +675,7 @@ pub struct BookingReport {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "cancellationReason"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub cancellation_reason: String,
     /**
@@ -567,49 +684,33 @@ pub struct BookingReport {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "cancelledAt"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub cancelled_at: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "carbonEmissions"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub carbon_emissions: Option<serde_json::Value>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "carbonOffsetCost"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub carbon_offset_cost: Option<serde_json::Value>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub cnr: Option<Cnr>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "companyOffice"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub company_office: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "companyPaymentMethod"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub company_payment_method: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "confirmationNumber"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub confirmation_number: Option<serde_json::Value>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "corporateDiscountUsed"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub corporate_discount_used: String,
     /**
@@ -630,15 +731,13 @@ pub struct BookingReport {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "currencyExhangeRateFromUsd"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub currency_exhange_rate_from_usd: f64,
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "customFields"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub custom_fields: Vec<Property>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -649,8 +748,7 @@ pub struct BookingReport {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize",
-        rename = "endDate"
+        deserialize_with = "crate::utils::date_format::deserialize"
     )]
     pub end_date: Option<chrono::NaiveDate>,
     #[serde(
@@ -662,15 +760,13 @@ pub struct BookingReport {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "exchangeAmount"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub exchange_amount: f64,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "exchangeFee"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub exchange_fee: f64,
     #[serde(
@@ -681,15 +777,13 @@ pub struct BookingReport {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "extrasFees"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub extras_fees: f64,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "fareClass"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub fare_class: String,
     #[serde(
@@ -698,17 +792,12 @@ pub struct BookingReport {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub flight: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "flightMiles"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub flight_miles: Option<serde_json::Value>,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "grandTotal"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub grand_total: f64,
     #[serde(
@@ -741,65 +830,56 @@ pub struct BookingReport {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastModified"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
     pub last_modified: Option<chrono::DateTime<chrono::Utc>>,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "leadTimeInDays"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub lead_time_in_days: i64,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nameOnCreditCard"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name_on_credit_card: String,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "netCharge"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub net_charge: f64,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "optimalPrice"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub optimal_price: f64,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub origin: Option<Location>,
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "outOfPolicy"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub out_of_policy: bool,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "outOfPolicyDescription"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub out_of_policy_description: String,
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "outOfPolicyViolationTypes"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub out_of_policy_violation_types: Vec<String>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "outOfPolicyViolations"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub out_of_policy_violations: String,
     #[serde(
@@ -811,29 +891,22 @@ pub struct BookingReport {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "paymentCreditCardTypeName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub payment_credit_card_type_name: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "paymentMethod"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub payment_method: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "paymentMethodUsed"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub payment_method_used: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "paymentSchedule"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub payment_schedule: Option<PaymentSchedule>,
     #[serde(
         default,
@@ -850,8 +923,7 @@ pub struct BookingReport {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "preferredVendor"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub preferred_vendor: String,
     #[serde(
@@ -881,8 +953,7 @@ pub struct BookingReport {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "resortFee"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub resort_fee: f64,
     #[serde(
@@ -894,8 +965,7 @@ pub struct BookingReport {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "savingMissed"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub saving_missed: f64,
     #[serde(
@@ -907,8 +977,7 @@ pub struct BookingReport {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "seatsFee"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub seats_fee: f64,
     #[serde(
@@ -923,15 +992,13 @@ pub struct BookingReport {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize",
-        rename = "startDate"
+        deserialize_with = "crate::utils::date_format::deserialize"
     )]
     pub start_date: Option<chrono::NaiveDate>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "statementDescription"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub statement_description: String,
     #[serde(
@@ -940,80 +1007,66 @@ pub struct BookingReport {
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub tax: f64,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "trainMiles"
-    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub train_miles: Option<serde_json::Value>,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "travelSpend"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub travel_spend: f64,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "tripBucksEarned"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub trip_bucks_earned: f64,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "tripBucksEarnedUsd"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub trip_bucks_earned_usd: f64,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "tripDescription"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub trip_description: String,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "tripFee"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub trip_fee: f64,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "tripLength"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub trip_length: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "tripName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub trip_name: String,
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "tripUuids"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub trip_uuids: Vec<String>,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "unitaryPrice"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub unitary_price: f64,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "usdGrandTotal"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub usd_grand_total: f64,
     #[serde(
@@ -1037,8 +1090,12 @@ pub struct BookingReport {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
-        rename = "vipFee"
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
     pub vip_fee: f64,
 }
+
+/// The types taken and returned by the functions in [`crate::booking_data`].
+pub mod booking_data {
+    pub use super::{BookingReport, BookingReportResponse, BookingStatus, BookingType};
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Accounts {
     pub client: Client,
@@ -12,6 +10,17 @@ impl Accounts {
         Accounts { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Accounts {
+            client: self.client.with_retry(policy),
+        }
+    }
+
+    /// The OAuth scopes for `get`, any one of them grants access.
+    pub const GET_SCOPES: &[&str] = &["account:read:admin"];
+
     /**
      * List sub accounts.
      *
@@ -42,22 +51,28 @@ impl Accounts {
         page_number: i64,
         next_page_token: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_number > 0 {
-            query_args.push(("page_number".to_string(), page_number.to_string()));
+            query_args.push(("page_number", page_number.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/accounts?{}", query_);
+        let url = crate::Client::url_with_query("/accounts", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/accounts", &[]))
     }
 
+    /// The OAuth scopes for `create`, any one of them grants access.
+    pub const CREATE_SCOPES: &[&str] = &["account:write:admin"];
+
     /**
      * Create a sub account.
      *
@@ -81,10 +96,14 @@ impl Accounts {
     ) -> Result<crate::types::AccountCreateResponse> {
         let url = "/accounts".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/accounts", &[]))
     }
 
+    /// The OAuth scopes for `account`, any one of them grants access.
+    pub const ACCOUNT_SCOPES: &[&str] = &["account:write:admin"];
+
     /**
      * Get sub account details.
      *
@@ -107,14 +126,20 @@ impl Accounts {
      * * `account_id: &str` -- User's first name.
      */
     pub async fn account(&self, account_id: &str) -> Result<crate::types::AccountResponse> {
-        let url = format!(
-            "/accounts/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-        );
+        let url = format!("/accounts/{}", self.client.encode_path(account_id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/accounts/{accountId}",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `disassociate`, any one of them grants access.
+    pub const DISASSOCIATE_SCOPES: &[&str] = &["account:write:admin"];
+
     /**
      * Disassociate a sub account.
      *
@@ -138,14 +163,42 @@ impl Accounts {
      * * `account_id: &str` -- User's first name.
      */
     pub async fn disassociate(&self, account_id: &str) -> Result<()> {
-        let url = format!(
-            "/accounts/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-        );
+        let url = format!("/accounts/{}", self.client.encode_path(account_id),);
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/accounts/{accountId}",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Disassociate a sub account.
+     *
+     * This function performs a `DELETE` to the `/accounts/{accountId}` endpoint.
+     *
+     * As opposed to `disassociate`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn disassociate_if_exists(&self, account_id: &str) -> Result<bool> {
+        let url = format!("/accounts/{}", self.client.encode_path(account_id),);
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/accounts/{accountId}",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `options_update`, any one of them grants access.
+    pub const OPTIONS_UPDATE_SCOPES: &[&str] = &["account:write:admin"];
+
     /**
      * Update options.
      *
@@ -172,14 +225,45 @@ impl Accounts {
         account_id: &str,
         body: &crate::types::Options,
     ) -> Result<()> {
-        let url = format!(
-            "/accounts/{}/options",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-        );
+        let url = format!("/accounts/{}/options", self.client.encode_path(account_id),);
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/accounts/{accountId}/options",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update options.
+     *
+     * This function performs a `PATCH` to the `/accounts/{accountId}/options` endpoint.
+     *
+     * As opposed to `options_update`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn options_update_if_changed<C: serde::Serialize>(
+        &self,
+        account_id: &str,
+        current: &C,
+        body: &crate::types::Options,
+    ) -> Result<Option<()>> {
+        let url = format!("/accounts/{}/options", self.client.encode_path(account_id),);
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/accounts/{accountId}/options",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -199,11 +283,11 @@ impl Accounts {
      * **Parameters:**
      *
      * * `account_id: &str` -- User's first name.
-     * * `option: crate::types::OptionData` -- Use the following options to filter the results of the account's information:
+     * * `option: Option<crate::types::OptionData>` -- Use the following options to filter the results of the account's information:
      *  \* `meeting_authentication` — View the account's [meeting authentication settings](https://support.zoom.us/hc/en-us/articles/360037117472-Authentication-Profiles-for-Meetings-and-Webinars).
      *  \* `recording_authentication` — View the account's [recording authentication settings](https://support.zoom.us/hc/en-us/articles/360037756671-Authentication-Profiles-for-Cloud-Recordings).
      *  \* `security` — View the account's security settings. For example, password requirements for user login or two-factor authentication.<br>
-     *  \* `meeting_security` — View the account's meeting security settings.
+     *  \* `meeting_security` — View the account's meeting security settings. `None` leaves it off the query, for the API's default.
      * * `custom_query_fields: &str` -- The name of the field by which to filter the response. For example, if you provide the `host_video` value for this field, you will get a response similar to the following:
      *   
      *   `{ "schedule_meeting": { "host_video": false    } }`
@@ -213,27 +297,28 @@ impl Accounts {
     pub async fn settings_domains(
         &self,
         account_id: &str,
-        option: crate::types::OptionData,
+        option: Option<crate::types::OptionData>,
         custom_query_fields: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_query_fields.is_empty() {
-            query_args.push((
-                "custom_query_fields".to_string(),
-                custom_query_fields.to_string(),
-            ));
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
         }
-        if !option.to_string().is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+        if let Some(v) = option {
+            query_args.push(("option", v.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/accounts/{}/settings?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/accounts/{}/settings", self.client.encode_path(account_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/accounts/{accountId}/settings",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -253,11 +338,11 @@ impl Accounts {
      * **Parameters:**
      *
      * * `account_id: &str` -- User's first name.
-     * * `option: crate::types::OptionData` -- Use the following options to filter the results of the account's information:
+     * * `option: Option<crate::types::OptionData>` -- Use the following options to filter the results of the account's information:
      *  \* `meeting_authentication` — View the account's [meeting authentication settings](https://support.zoom.us/hc/en-us/articles/360037117472-Authentication-Profiles-for-Meetings-and-Webinars).
      *  \* `recording_authentication` — View the account's [recording authentication settings](https://support.zoom.us/hc/en-us/articles/360037756671-Authentication-Profiles-for-Cloud-Recordings).
      *  \* `security` — View the account's security settings. For example, password requirements for user login or two-factor authentication.<br>
-     *  \* `meeting_security` — View the account's meeting security settings.
+     *  \* `meeting_security` — View the account's meeting security settings. `None` leaves it off the query, for the API's default.
      * * `custom_query_fields: &str` -- The name of the field by which to filter the response. For example, if you provide the `host_video` value for this field, you will get a response similar to the following:
      *   
      *   `{ "schedule_meeting": { "host_video": false    } }`
@@ -267,27 +352,28 @@ impl Accounts {
     pub async fn settings_security(
         &self,
         account_id: &str,
-        option: crate::types::OptionData,
+        option: Option<crate::types::OptionData>,
         custom_query_fields: &str,
     ) -> Result<crate::types::Security> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_query_fields.is_empty() {
-            query_args.push((
-                "custom_query_fields".to_string(),
-                custom_query_fields.to_string(),
-            ));
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
         }
-        if !option.to_string().is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+        if let Some(v) = option {
+            query_args.push(("option", v.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/accounts/{}/settings?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/accounts/{}/settings", self.client.encode_path(account_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/accounts/{accountId}/settings",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -307,11 +393,11 @@ impl Accounts {
      * **Parameters:**
      *
      * * `account_id: &str` -- User's first name.
-     * * `option: crate::types::OptionData` -- Use the following options to filter the results of the account's information:
+     * * `option: Option<crate::types::OptionData>` -- Use the following options to filter the results of the account's information:
      *  \* `meeting_authentication` — View the account's [meeting authentication settings](https://support.zoom.us/hc/en-us/articles/360037117472-Authentication-Profiles-for-Meetings-and-Webinars).
      *  \* `recording_authentication` — View the account's [recording authentication settings](https://support.zoom.us/hc/en-us/articles/360037756671-Authentication-Profiles-for-Cloud-Recordings).
      *  \* `security` — View the account's security settings. For example, password requirements for user login or two-factor authentication.<br>
-     *  \* `meeting_security` — View the account's meeting security settings.
+     *  \* `meeting_security` — View the account's meeting security settings. `None` leaves it off the query, for the API's default.
      * * `custom_query_fields: &str` -- The name of the field by which to filter the response. For example, if you provide the `host_video` value for this field, you will get a response similar to the following:
      *   
      *   `{ "schedule_meeting": { "host_video": false    } }`
@@ -321,27 +407,28 @@ impl Accounts {
     pub async fn settings_account(
         &self,
         account_id: &str,
-        option: crate::types::OptionData,
+        option: Option<crate::types::OptionData>,
         custom_query_fields: &str,
     ) -> Result<crate::types::AccountSettings> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_query_fields.is_empty() {
-            query_args.push((
-                "custom_query_fields".to_string(),
-                custom_query_fields.to_string(),
-            ));
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
         }
-        if !option.to_string().is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+        if let Some(v) = option {
+            query_args.push(("option", v.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/accounts/{}/settings?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/accounts/{}/settings", self.client.encode_path(account_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/accounts/{accountId}/settings",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -361,11 +448,11 @@ impl Accounts {
      * **Parameters:**
      *
      * * `account_id: &str` -- User's first name.
-     * * `option: crate::types::OptionData` -- Use the following options to filter the results of the account's information:
+     * * `option: Option<crate::types::OptionData>` -- Use the following options to filter the results of the account's information:
      *  \* `meeting_authentication` — View the account's [meeting authentication settings](https://support.zoom.us/hc/en-us/articles/360037117472-Authentication-Profiles-for-Meetings-and-Webinars).
      *  \* `recording_authentication` — View the account's [recording authentication settings](https://support.zoom.us/hc/en-us/articles/360037756671-Authentication-Profiles-for-Cloud-Recordings).
      *  \* `security` — View the account's security settings. For example, password requirements for user login or two-factor authentication.<br>
-     *  \* `meeting_security` — View the account's meeting security settings.
+     *  \* `meeting_security` — View the account's meeting security settings. `None` leaves it off the query, for the API's default.
      * * `custom_query_fields: &str` -- The name of the field by which to filter the response. For example, if you provide the `host_video` value for this field, you will get a response similar to the following:
      *   
      *   `{ "schedule_meeting": { "host_video": false    } }`
@@ -375,29 +462,33 @@ impl Accounts {
     pub async fn settings_meeting_security(
         &self,
         account_id: &str,
-        option: crate::types::OptionData,
+        option: Option<crate::types::OptionData>,
         custom_query_fields: &str,
     ) -> Result<crate::types::MeetingSecuritySettings> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_query_fields.is_empty() {
-            query_args.push((
-                "custom_query_fields".to_string(),
-                custom_query_fields.to_string(),
-            ));
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
         }
-        if !option.to_string().is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+        if let Some(v) = option {
+            query_args.push(("option", v.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/accounts/{}/settings?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/accounts/{}/settings", self.client.encode_path(account_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/accounts/{accountId}/settings",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `setting`, any one of them grants access.
+    pub const SETTING_SCOPES: &[&str] = &["account:read:admin"];
+
     /**
      * Get settings.
      *
@@ -415,11 +506,11 @@ impl Accounts {
      * **Parameters:**
      *
      * * `account_id: &str` -- User's first name.
-     * * `option: crate::types::OptionData` -- Use the following options to filter the results of the account's information:
+     * * `option: Option<crate::types::OptionData>` -- Use the following options to filter the results of the account's information:
      *  \* `meeting_authentication` — View the account's [meeting authentication settings](https://support.zoom.us/hc/en-us/articles/360037117472-Authentication-Profiles-for-Meetings-and-Webinars).
      *  \* `recording_authentication` — View the account's [recording authentication settings](https://support.zoom.us/hc/en-us/articles/360037756671-Authentication-Profiles-for-Cloud-Recordings).
      *  \* `security` — View the account's security settings. For example, password requirements for user login or two-factor authentication.<br>
-     *  \* `meeting_security` — View the account's meeting security settings.
+     *  \* `meeting_security` — View the account's meeting security settings. `None` leaves it off the query, for the API's default.
      * * `custom_query_fields: &str` -- The name of the field by which to filter the response. For example, if you provide the `host_video` value for this field, you will get a response similar to the following:
      *   
      *   `{ "schedule_meeting": { "host_video": false    } }`
@@ -429,29 +520,33 @@ impl Accounts {
     pub async fn setting(
         &self,
         account_id: &str,
-        option: crate::types::OptionData,
+        option: Option<crate::types::OptionData>,
         custom_query_fields: &str,
     ) -> Result<crate::types::AccountSettingsResponseOneOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_query_fields.is_empty() {
-            query_args.push((
-                "custom_query_fields".to_string(),
-                custom_query_fields.to_string(),
-            ));
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
         }
-        if !option.to_string().is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+        if let Some(v) = option {
+            query_args.push(("option", v.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/accounts/{}/settings?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/accounts/{}/settings", self.client.encode_path(account_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/accounts/{accountId}/settings",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `settings_update`, any one of them grants access.
+    pub const SETTINGS_UPDATE_SCOPES: &[&str] = &["account:write:admin"];
+
     /**
      * Update settings.
      *
@@ -467,30 +562,73 @@ impl Accounts {
      * **Parameters:**
      *
      * * `account_id: &str` -- User's first name.
-     * * `option: crate::types::AccountSettingsUpdateOption`
+     * * `option: Option<crate::types::AccountSettingsUpdateOption>` -- `None` leaves it off the query, for the API's default.
      */
     pub async fn settings_update(
         &self,
         account_id: &str,
-        option: crate::types::AccountSettingsUpdateOption,
+        option: Option<crate::types::AccountSettingsUpdateOption>,
         body: &crate::types::AccountSettingsUpdateRequestOneOf,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !option.to_string().is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if let Some(v) = option {
+            query_args.push(("option", v.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/accounts/{}/settings?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/accounts/{}/settings", self.client.encode_path(account_id)),
+            &query_args,
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/accounts/{accountId}/settings",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update settings.
+     *
+     * This function performs a `PATCH` to the `/accounts/{accountId}/settings` endpoint.
+     *
+     * As opposed to `settings_update`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn settings_update_if_changed<C: serde::Serialize>(
+        &self,
+        account_id: &str,
+        option: Option<crate::types::AccountSettingsUpdateOption>,
+        current: &C,
+        body: &crate::types::AccountSettingsUpdateRequestOneOf,
+    ) -> Result<Option<()>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if let Some(v) = option {
+            query_args.push(("option", v.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!("/accounts/{}/settings", self.client.encode_path(account_id)),
+            &query_args,
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/accounts/{accountId}/settings",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `managed_domain`, any one of them grants access.
+    pub const MANAGED_DOMAIN_SCOPES: &[&str] = &["account:read:admin"];
+
     /**
      * Get managed domains.
      *
@@ -517,12 +655,21 @@ impl Accounts {
     pub async fn managed_domain(&self, account_id: &str) -> Result<crate::types::DomainsList> {
         let url = format!(
             "/accounts/{}/managed_domains",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/accounts/{accountId}/managed_domains",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `trusted_domain`, any one of them grants access.
+    pub const TRUSTED_DOMAIN_SCOPES: &[&str] = &["account:read:admin"];
+
     /**
      * Get trusted domains.
      *
@@ -545,12 +692,21 @@ impl Accounts {
     ) -> Result<crate::types::AccountTrustedDomainResponse> {
         let url = format!(
             "/accounts/{}/trusted_domains",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/accounts/{accountId}/trusted_domains",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `get_lock_setting`, any one of them grants access.
+    pub const GET_LOCK_SETTING_SCOPES: &[&str] = &["account:read:admin"];
+
     /**
      * Get locked settings.
      *
@@ -590,26 +746,33 @@ impl Accounts {
         option: &str,
         custom_query_fields: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_query_fields.is_empty() {
-            query_args.push((
-                "custom_query_fields".to_string(),
-                custom_query_fields.to_string(),
-            ));
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
         }
         if !option.is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+            query_args.push(("option", option.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/accounts/{}/lock_settings?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/accounts/{}/lock_settings",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/accounts/{accountId}/lock_settings",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `update_lock_settings`, any one of them grants access.
+    pub const UPDATE_LOCK_SETTINGS_SCOPES: &[&str] = &["account:write:admin"];
+
     /**
      * Update locked settings.
      *
@@ -634,12 +797,21 @@ impl Accounts {
     pub async fn update_lock_settings(&self, account_id: &str) -> Result<()> {
         let url = format!(
             "/accounts/{}/lock_settings",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
-        self.client.patch(&url, None).await
+        self.client.patch(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "PATCH",
+                "/accounts/{accountId}/lock_settings",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `update_owner`, any one of them grants access.
+    pub const UPDATE_OWNER_SCOPES: &[&str] = &["account:write:admin", "account:master"];
+
     /**
      * Update the account owner.
      *
@@ -660,16 +832,23 @@ impl Accounts {
      * * `account_id: &str` -- The account's account ID.
      */
     pub async fn update_owner(&self, account_id: &str, body: &crate::types::Members) -> Result<()> {
-        let url = format!(
-            "/accounts/{}/owner",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-        );
+        let url = format!("/accounts/{}/owner", self.client.encode_path(account_id),);
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/accounts/{accountId}/owner",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `upload_vb`, any one of them grants access.
+    pub const UPLOAD_VB_SCOPES: &[&str] = &["account:write:admin"];
+
     /**
      * Upload virtual background files.
      *
@@ -696,14 +875,24 @@ impl Accounts {
     ) -> Result<crate::types::Files> {
         let url = format!(
             "/accounts/{}/settings/virtual_backgrounds",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/accounts/{accountId}/settings/virtual_backgrounds",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `del_vb`, any one of them grants access.
+    pub const DEL_VB_SCOPES: &[&str] = &["account:write:admin"];
+
     /**
      * Delete virtual background files.
      *
@@ -723,17 +912,249 @@ impl Accounts {
      * * `file_ids: &str` -- Provide the id of the file that is to be deleted. To delete multiple files, provide comma separated values for this field.
      */
     pub async fn del_vb(&self, account_id: &str, file_ids: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !file_ids.is_empty() {
-            query_args.push(("file_ids".to_string(), file_ids.to_string()));
+            query_args.push(("file_ids", file_ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/accounts/{}/settings/virtual_backgrounds?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/accounts/{}/settings/virtual_backgrounds",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/accounts/{accountId}/settings/virtual_backgrounds",
+                &[("accountId", account_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete virtual background files.
+     *
+     * This function performs a `DELETE` to the `/accounts/{accountId}/settings/virtual_backgrounds` endpoint.
+     *
+     * As opposed to `del_vb`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn del_vb_if_exists(&self, account_id: &str, file_ids: &str) -> Result<bool> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !file_ids.is_empty() {
+            query_args.push(("file_ids", file_ids.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/accounts/{}/settings/virtual_backgrounds",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/accounts/{accountId}/settings/virtual_backgrounds",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`Accounts`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn AccountsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait AccountsApi: Send + Sync {
+    async fn get(
+        &self,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::Domains>;
+    async fn create(
+        &self,
+        body: &crate::types::AccountCreateRequest,
+    ) -> Result<crate::types::AccountCreateResponse>;
+    async fn account(&self, account_id: &str) -> Result<crate::types::AccountResponse>;
+    async fn disassociate(&self, account_id: &str) -> Result<()>;
+    async fn disassociate_if_exists(&self, account_id: &str) -> Result<bool>;
+    async fn options_update(&self, account_id: &str, body: &crate::types::Options) -> Result<()>;
+    async fn settings_domains(
+        &self,
+        account_id: &str,
+        option: Option<crate::types::OptionData>,
+        custom_query_fields: &str,
+    ) -> Result<crate::types::Domains>;
+    async fn settings_security(
+        &self,
+        account_id: &str,
+        option: Option<crate::types::OptionData>,
+        custom_query_fields: &str,
+    ) -> Result<crate::types::Security>;
+    async fn settings_account(
+        &self,
+        account_id: &str,
+        option: Option<crate::types::OptionData>,
+        custom_query_fields: &str,
+    ) -> Result<crate::types::AccountSettings>;
+    async fn settings_meeting_security(
+        &self,
+        account_id: &str,
+        option: Option<crate::types::OptionData>,
+        custom_query_fields: &str,
+    ) -> Result<crate::types::MeetingSecuritySettings>;
+    async fn setting(
+        &self,
+        account_id: &str,
+        option: Option<crate::types::OptionData>,
+        custom_query_fields: &str,
+    ) -> Result<crate::types::AccountSettingsResponseOneOf>;
+    async fn settings_update(
+        &self,
+        account_id: &str,
+        option: Option<crate::types::AccountSettingsUpdateOption>,
+        body: &crate::types::AccountSettingsUpdateRequestOneOf,
+    ) -> Result<()>;
+    async fn managed_domain(&self, account_id: &str) -> Result<crate::types::DomainsList>;
+    async fn trusted_domain(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::AccountTrustedDomainResponse>;
+    async fn get_lock_setting(
+        &self,
+        account_id: &str,
+        option: &str,
+        custom_query_fields: &str,
+    ) -> Result<crate::types::Domains>;
+    async fn update_lock_settings(&self, account_id: &str) -> Result<()>;
+    async fn update_owner(&self, account_id: &str, body: &crate::types::Members) -> Result<()>;
+    async fn upload_vb(
+        &self,
+        account_id: &str,
+        body: &crate::types::UploadVbRequest,
+    ) -> Result<crate::types::Files>;
+    async fn del_vb(&self, account_id: &str, file_ids: &str) -> Result<()>;
+    async fn del_vb_if_exists(&self, account_id: &str, file_ids: &str) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl AccountsApi for Accounts {
+    async fn get(
+        &self,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::Domains> {
+        Accounts::get(self, page_size, page_number, next_page_token).await
+    }
+    async fn create(
+        &self,
+        body: &crate::types::AccountCreateRequest,
+    ) -> Result<crate::types::AccountCreateResponse> {
+        Accounts::create(self, body).await
+    }
+    async fn account(&self, account_id: &str) -> Result<crate::types::AccountResponse> {
+        Accounts::account(self, account_id).await
+    }
+    async fn disassociate(&self, account_id: &str) -> Result<()> {
+        Accounts::disassociate(self, account_id).await
+    }
+    async fn disassociate_if_exists(&self, account_id: &str) -> Result<bool> {
+        Accounts::disassociate_if_exists(self, account_id).await
+    }
+    async fn options_update(&self, account_id: &str, body: &crate::types::Options) -> Result<()> {
+        Accounts::options_update(self, account_id, body).await
+    }
+    async fn settings_domains(
+        &self,
+        account_id: &str,
+        option: Option<crate::types::OptionData>,
+        custom_query_fields: &str,
+    ) -> Result<crate::types::Domains> {
+        Accounts::settings_domains(self, account_id, option, custom_query_fields).await
+    }
+    async fn settings_security(
+        &self,
+        account_id: &str,
+        option: Option<crate::types::OptionData>,
+        custom_query_fields: &str,
+    ) -> Result<crate::types::Security> {
+        Accounts::settings_security(self, account_id, option, custom_query_fields).await
+    }
+    async fn settings_account(
+        &self,
+        account_id: &str,
+        option: Option<crate::types::OptionData>,
+        custom_query_fields: &str,
+    ) -> Result<crate::types::AccountSettings> {
+        Accounts::settings_account(self, account_id, option, custom_query_fields).await
+    }
+    async fn settings_meeting_security(
+        &self,
+        account_id: &str,
+        option: Option<crate::types::OptionData>,
+        custom_query_fields: &str,
+    ) -> Result<crate::types::MeetingSecuritySettings> {
+        Accounts::settings_meeting_security(self, account_id, option, custom_query_fields).await
+    }
+    async fn setting(
+        &self,
+        account_id: &str,
+        option: Option<crate::types::OptionData>,
+        custom_query_fields: &str,
+    ) -> Result<crate::types::AccountSettingsResponseOneOf> {
+        Accounts::setting(self, account_id, option, custom_query_fields).await
+    }
+    async fn settings_update(
+        &self,
+        account_id: &str,
+        option: Option<crate::types::AccountSettingsUpdateOption>,
+        body: &crate::types::AccountSettingsUpdateRequestOneOf,
+    ) -> Result<()> {
+        Accounts::settings_update(self, account_id, option, body).await
+    }
+    async fn managed_domain(&self, account_id: &str) -> Result<crate::types::DomainsList> {
+        Accounts::managed_domain(self, account_id).await
+    }
+    async fn trusted_domain(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::AccountTrustedDomainResponse> {
+        Accounts::trusted_domain(self, account_id).await
+    }
+    async fn get_lock_setting(
+        &self,
+        account_id: &str,
+        option: &str,
+        custom_query_fields: &str,
+    ) -> Result<crate::types::Domains> {
+        Accounts::get_lock_setting(self, account_id, option, custom_query_fields).await
+    }
+    async fn update_lock_settings(&self, account_id: &str) -> Result<()> {
+        Accounts::update_lock_settings(self, account_id).await
+    }
+    async fn update_owner(&self, account_id: &str, body: &crate::types::Members) -> Result<()> {
+        Accounts::update_owner(self, account_id, body).await
+    }
+    async fn upload_vb(
+        &self,
+        account_id: &str,
+        body: &crate::types::UploadVbRequest,
+    ) -> Result<crate::types::Files> {
+        Accounts::upload_vb(self, account_id, body).await
+    }
+    async fn del_vb(&self, account_id: &str, file_ids: &str) -> Result<()> {
+        Accounts::del_vb(self, account_id, file_ids).await
+    }
+    async fn del_vb_if_exists(&self, account_id: &str, file_ids: &str) -> Result<bool> {
+        Accounts::del_vb_if_exists(self, account_id, file_ids).await
     }
 }
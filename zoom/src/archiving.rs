@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Archiving {
     pub client: Client,
@@ -12,6 +10,17 @@ impl Archiving {
         Archiving { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Archiving {
+            client: self.client.with_retry(policy),
+        }
+    }
+
+    /// The OAuth scopes for `list_archived_files`, any one of them grants access.
+    pub const LIST_ARCHIVED_FILES_SCOPES: &[&str] = &["recording:read:admin"];
+
     /**
      * List archived files.
      *
@@ -42,31 +51,171 @@ impl Archiving {
         to: &str,
         query_date_type: crate::types::ListArchivedFilesQueryDateType,
     ) -> Result<Vec<crate::types::ListArchivedFilesResponseMeetings>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        if !query_date_type.to_string().is_empty() {
-            query_args.push(("query_date_type".to_string(), query_date_type.to_string()));
+        if query_date_type != crate::types::ListArchivedFilesQueryDateType::default() {
+            query_args.push(("query_date_type", query_date_type.to_string()));
         }
         if !to.is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/archive_files?{}", query_);
+        let url = crate::Client::url_with_query("/archive_files", &query_args);
 
-        let resp: crate::types::ListArchivedFilesResponse = self.client.get(&url, None).await?;
+        let resp: crate::types::ListArchivedFilesResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/archive_files", &[]))?;
 
         // Return our response data.
         Ok(resp.meetings)
     }
 
+    /**
+     * List archived files.
+     *
+     * This function performs a `GET` to the `/archive_files` endpoint.
+     *
+     * As opposed to `list_archived_files`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
+     */
+    pub async fn list_archived_files_page(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        from: &str,
+        to: &str,
+        query_date_type: crate::types::ListArchivedFilesQueryDateType,
+    ) -> Result<(Vec<crate::types::ListArchivedFilesResponseMeetings>, String)> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from.is_empty() {
+            query_args.push(("from", from.to_string()));
+        }
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        if query_date_type != crate::types::ListArchivedFilesQueryDateType::default() {
+            query_args.push(("query_date_type", query_date_type.to_string()));
+        }
+        if !to.is_empty() {
+            query_args.push(("to", to.to_string()));
+        }
+        let url = crate::Client::url_with_query("/archive_files", &query_args);
+
+        let resp: crate::types::ListArchivedFilesResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/archive_files", &[]))?;
+
+        // Return our response data, and where the next page starts.
+        Ok((resp.meetings, resp.next_page_token))
+    }
+
+    /**
+     * List archived files.
+     *
+     * This function performs a `GET` to the `/archive_files` endpoint.
+     *
+     * As opposed to `list_archived_files`, this function takes `from` and `to` as times, and sends them in UTC like `2021-08-15T01:52:41Z`.
+     */
+    pub async fn list_archived_files_between(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        from: chrono::DateTime<chrono::Utc>,
+        to: chrono::DateTime<chrono::Utc>,
+        query_date_type: crate::types::ListArchivedFilesQueryDateType,
+    ) -> Result<Vec<crate::types::ListArchivedFilesResponseMeetings>> {
+        let from = crate::utils::date_time_param(&from);
+        let to = crate::utils::date_time_param(&to);
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from.is_empty() {
+            query_args.push(("from", from.to_string()));
+        }
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        if query_date_type != crate::types::ListArchivedFilesQueryDateType::default() {
+            query_args.push(("query_date_type", query_date_type.to_string()));
+        }
+        if !to.is_empty() {
+            query_args.push(("to", to.to_string()));
+        }
+        let url = crate::Client::url_with_query("/archive_files", &query_args);
+
+        let resp: crate::types::ListArchivedFilesResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/archive_files", &[]))?;
+
+        // Return our response data.
+        Ok(resp.meetings)
+    }
+
+    /**
+     * List archived files.
+     *
+     * This function performs a `GET` to the `/archive_files` endpoint.
+     *
+     * As opposed to `list_archived_files_between`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
+     */
+    pub async fn list_archived_files_between_page(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        from: chrono::DateTime<chrono::Utc>,
+        to: chrono::DateTime<chrono::Utc>,
+        query_date_type: crate::types::ListArchivedFilesQueryDateType,
+    ) -> Result<(Vec<crate::types::ListArchivedFilesResponseMeetings>, String)> {
+        let from = crate::utils::date_time_param(&from);
+        let to = crate::utils::date_time_param(&to);
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from.is_empty() {
+            query_args.push(("from", from.to_string()));
+        }
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        if query_date_type != crate::types::ListArchivedFilesQueryDateType::default() {
+            query_args.push(("query_date_type", query_date_type.to_string()));
+        }
+        if !to.is_empty() {
+            query_args.push(("to", to.to_string()));
+        }
+        let url = crate::Client::url_with_query("/archive_files", &query_args);
+
+        let resp: crate::types::ListArchivedFilesResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/archive_files", &[]))?;
+
+        // Return our response data, and where the next page starts.
+        Ok((resp.meetings, resp.next_page_token))
+    }
+
     /**
      * List archived files.
      *
@@ -89,20 +238,23 @@ impl Archiving {
         to: &str,
         query_date_type: crate::types::ListArchivedFilesQueryDateType,
     ) -> Result<Vec<crate::types::ListArchivedFilesResponseMeetings>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
-        if !query_date_type.to_string().is_empty() {
-            query_args.push(("query_date_type".to_string(), query_date_type.to_string()));
+        if query_date_type != crate::types::ListArchivedFilesQueryDateType::default() {
+            query_args.push(("query_date_type", query_date_type.to_string()));
         }
         if !to.is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/archive_files?{}", query_);
+        let url = crate::Client::url_with_query("/archive_files", &query_args);
 
-        let mut resp: crate::types::ListArchivedFilesResponse = self.client.get(&url, None).await?;
+        let mut resp: crate::types::ListArchivedFilesResponse =
+            self.client
+                .get(&url, None)
+                .await
+                .with_context(|| crate::utils::pretty_url("GET", "/archive_files", &[]))?;
 
         let mut meetings = resp.meetings;
         let mut page = resp.next_page_token;
@@ -135,6 +287,92 @@ impl Archiving {
         Ok(meetings)
     }
 
+    /**
+     * List archived files.
+     *
+     * This function performs a `GET` to the `/archive_files` endpoint.
+     *
+     * As opposed to `list_archived_files`, this function streams the items of all the pages, getting the next page only once the items of the last one are used up. The stream ends after the last page.
+     */
+    pub async fn list_archived_files_stream(
+        &self,
+        from: &str,
+        to: &str,
+        query_date_type: crate::types::ListArchivedFilesQueryDateType,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::ListArchivedFilesResponseMeetings>>>
+    {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from.is_empty() {
+            query_args.push(("from", from.to_string()));
+        }
+        if query_date_type != crate::types::ListArchivedFilesQueryDateType::default() {
+            query_args.push(("query_date_type", query_date_type.to_string()));
+        }
+        if !to.is_empty() {
+            query_args.push(("to", to.to_string()));
+        }
+        let url = crate::Client::url_with_query("/archive_files", &query_args);
+
+        self.client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListArchivedFilesResponse| {
+                    (resp.meetings, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/archive_files", &[]))
+    }
+
+    /**
+     * List archived files.
+     *
+     * This function performs a `GET` to the `/archive_files` endpoint.
+     *
+     * As opposed to `list_archived_files_stream`, this function streams only the items `predicate` keeps. It too gets the next page only once the items of the last one are used up, so a stream that is cut short, for example with `take`, gets no more pages than it needs.
+     */
+    pub async fn list_archived_files_filter<
+        P: FnMut(&crate::types::ListArchivedFilesResponseMeetings) -> bool,
+    >(
+        &self,
+        from: &str,
+        to: &str,
+        query_date_type: crate::types::ListArchivedFilesQueryDateType,
+        mut predicate: P,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::ListArchivedFilesResponseMeetings>>>
+    {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from.is_empty() {
+            query_args.push(("from", from.to_string()));
+        }
+        if query_date_type != crate::types::ListArchivedFilesQueryDateType::default() {
+            query_args.push(("query_date_type", query_date_type.to_string()));
+        }
+        if !to.is_empty() {
+            query_args.push(("to", to.to_string()));
+        }
+        let url = crate::Client::url_with_query("/archive_files", &query_args);
+
+        let items = self
+            .client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListArchivedFilesResponse| {
+                    (resp.meetings, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/archive_files", &[]))?;
+        Ok(futures::TryStreamExt::try_filter(items, move |item| {
+            futures::future::ready(predicate(item))
+        }))
+    }
+
+    /// The OAuth scopes for `testget_record_archived_file`, any one of them grants access.
+    pub const TESTGET_RECORD_ARCHIVED_FILE_SCOPES: &[&str] = &["recording:read"];
+
     /**
      * Get meeting archived files.
      *
@@ -160,9 +398,149 @@ impl Archiving {
     ) -> Result<crate::types::CloudArchivedFiles> {
         let url = format!(
             "/past_meetings/{}/archive_files",
-            crate::progenitor_support::encode_path(&meeting_uuid.to_string()),
+            self.client.encode_path(meeting_uuid),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/past_meetings/{meetingUUID}/archive_files",
+                &[("meetingUUID", meeting_uuid.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`Archiving`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ArchivingApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ArchivingApi: Send + Sync {
+    async fn list_archived_files(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        from: &str,
+        to: &str,
+        query_date_type: crate::types::ListArchivedFilesQueryDateType,
+    ) -> Result<Vec<crate::types::ListArchivedFilesResponseMeetings>>;
+    async fn list_archived_files_page(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        from: &str,
+        to: &str,
+        query_date_type: crate::types::ListArchivedFilesQueryDateType,
+    ) -> Result<(Vec<crate::types::ListArchivedFilesResponseMeetings>, String)>;
+    async fn list_archived_files_between(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        from: chrono::DateTime<chrono::Utc>,
+        to: chrono::DateTime<chrono::Utc>,
+        query_date_type: crate::types::ListArchivedFilesQueryDateType,
+    ) -> Result<Vec<crate::types::ListArchivedFilesResponseMeetings>>;
+    async fn list_archived_files_between_page(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        from: chrono::DateTime<chrono::Utc>,
+        to: chrono::DateTime<chrono::Utc>,
+        query_date_type: crate::types::ListArchivedFilesQueryDateType,
+    ) -> Result<(Vec<crate::types::ListArchivedFilesResponseMeetings>, String)>;
+    async fn list_all_archived_files(
+        &self,
+        from: &str,
+        to: &str,
+        query_date_type: crate::types::ListArchivedFilesQueryDateType,
+    ) -> Result<Vec<crate::types::ListArchivedFilesResponseMeetings>>;
+    async fn testget_record_archived_file(
+        &self,
+        meeting_uuid: &str,
+    ) -> Result<crate::types::CloudArchivedFiles>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ArchivingApi for Archiving {
+    async fn list_archived_files(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        from: &str,
+        to: &str,
+        query_date_type: crate::types::ListArchivedFilesQueryDateType,
+    ) -> Result<Vec<crate::types::ListArchivedFilesResponseMeetings>> {
+        Archiving::list_archived_files(self, page_size, next_page_token, from, to, query_date_type)
+            .await
+    }
+    async fn list_archived_files_page(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        from: &str,
+        to: &str,
+        query_date_type: crate::types::ListArchivedFilesQueryDateType,
+    ) -> Result<(Vec<crate::types::ListArchivedFilesResponseMeetings>, String)> {
+        Archiving::list_archived_files_page(
+            self,
+            page_size,
+            next_page_token,
+            from,
+            to,
+            query_date_type,
+        )
+        .await
+    }
+    async fn list_archived_files_between(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        from: chrono::DateTime<chrono::Utc>,
+        to: chrono::DateTime<chrono::Utc>,
+        query_date_type: crate::types::ListArchivedFilesQueryDateType,
+    ) -> Result<Vec<crate::types::ListArchivedFilesResponseMeetings>> {
+        Archiving::list_archived_files_between(
+            self,
+            page_size,
+            next_page_token,
+            from,
+            to,
+            query_date_type,
+        )
+        .await
+    }
+    async fn list_archived_files_between_page(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        from: chrono::DateTime<chrono::Utc>,
+        to: chrono::DateTime<chrono::Utc>,
+        query_date_type: crate::types::ListArchivedFilesQueryDateType,
+    ) -> Result<(Vec<crate::types::ListArchivedFilesResponseMeetings>, String)> {
+        Archiving::list_archived_files_between_page(
+            self,
+            page_size,
+            next_page_token,
+            from,
+            to,
+            query_date_type,
+        )
+        .await
+    }
+    async fn list_all_archived_files(
+        &self,
+        from: &str,
+        to: &str,
+        query_date_type: crate::types::ListArchivedFilesQueryDateType,
+    ) -> Result<Vec<crate::types::ListArchivedFilesResponseMeetings>> {
+        Archiving::list_all_archived_files(self, from, to, query_date_type).await
+    }
+    async fn testget_record_archived_file(
+        &self,
+        meeting_uuid: &str,
+    ) -> Result<crate::types::CloudArchivedFiles> {
+        Archiving::testget_record_archived_file(self, meeting_uuid).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Billing {
     pub client: Client,
@@ -12,6 +10,17 @@ impl Billing {
         Billing { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Billing {
+            client: self.client.with_retry(policy),
+        }
+    }
+
+    /// The OAuth scopes for `account`, any one of them grants access.
+    pub const ACCOUNT_SCOPES: &[&str] = &["billing:master"];
+
     /**
      * Get billing information.
      *
@@ -32,14 +41,20 @@ impl Billing {
      * * `account_id: &str` -- User's first name.
      */
     pub async fn account(&self, account_id: &str) -> Result<crate::types::Contact> {
-        let url = format!(
-            "/accounts/{}/billing",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-        );
+        let url = format!("/accounts/{}/billing", self.client.encode_path(account_id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/accounts/{accountId}/billing",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `account_update`, any one of them grants access.
+    pub const ACCOUNT_UPDATE_SCOPES: &[&str] = &["billing:master"];
+
     /**
      * Update billing information.
      *
@@ -65,16 +80,50 @@ impl Billing {
         account_id: &str,
         body: &crate::types::BillingContact,
     ) -> Result<()> {
-        let url = format!(
-            "/accounts/{}/billing",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-        );
+        let url = format!("/accounts/{}/billing", self.client.encode_path(account_id),);
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/accounts/{accountId}/billing",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
+    /**
+     * Update billing information.
+     *
+     * This function performs a `PATCH` to the `/accounts/{accountId}/billing` endpoint.
+     *
+     * As opposed to `account_update`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn account_update_if_changed<C: serde::Serialize>(
+        &self,
+        account_id: &str,
+        current: &C,
+        body: &crate::types::BillingContact,
+    ) -> Result<Option<()>> {
+        let url = format!("/accounts/{}/billing", self.client.encode_path(account_id),);
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/accounts/{accountId}/billing",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The OAuth scopes for `account_plans`, any one of them grants access.
+    pub const ACCOUNT_PLANS_SCOPES: &[&str] = &["billing:master"];
+
     /**
      * Get plan Information.
      *
@@ -95,14 +144,20 @@ impl Billing {
         &self,
         account_id: &str,
     ) -> Result<crate::types::AccountPlansResponse> {
-        let url = format!(
-            "/accounts/{}/plans",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-        );
+        let url = format!("/accounts/{}/plans", self.client.encode_path(account_id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/accounts/{accountId}/plans",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `account_plan_create`, any one of them grants access.
+    pub const ACCOUNT_PLAN_CREATE_SCOPES: &[&str] = &["billing:master"];
+
     /**
      * Subscribe plans.
      *
@@ -121,16 +176,23 @@ impl Billing {
         account_id: &str,
         body: &crate::types::AccountPlanCreateRequestAllOf,
     ) -> Result<()> {
-        let url = format!(
-            "/accounts/{}/plans",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-        );
+        let url = format!("/accounts/{}/plans", self.client.encode_path(account_id),);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/accounts/{accountId}/plans",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `account_plan_base_update`, any one of them grants access.
+    pub const ACCOUNT_PLAN_BASE_UPDATE_SCOPES: &[&str] = &["billing:master"];
+
     /**
      * Update a base plan.
      *
@@ -156,14 +218,24 @@ impl Billing {
     ) -> Result<()> {
         let url = format!(
             "/accounts/{}/plans/base",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/accounts/{accountId}/plans/base",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `account_plan_addon_update`, any one of them grants access.
+    pub const ACCOUNT_PLAN_ADDON_UPDATE_SCOPES: &[&str] = &["billing:master"];
+
     /**
      * Update an additional plan.
      *
@@ -193,14 +265,24 @@ impl Billing {
     ) -> Result<()> {
         let url = format!(
             "/accounts/{}/plans/addons",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/accounts/{accountId}/plans/addons",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `account_plan_addon_create`, any one of them grants access.
+    pub const ACCOUNT_PLAN_ADDON_CREATE_SCOPES: &[&str] = &["billing:master"];
+
     /**
      * Subscribe additional plan.
      *
@@ -225,14 +307,24 @@ impl Billing {
     ) -> Result<crate::types::AccountPlans> {
         let url = format!(
             "/accounts/{}/plans/addons",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/accounts/{accountId}/plans/addons",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `account_plan_base_delete`, any one of them grants access.
+    pub const ACCOUNT_PLAN_BASE_DELETE_SCOPES: &[&str] = &["billing:master"];
+
     /**
      * Cancel a base plan.
      *
@@ -259,14 +351,54 @@ impl Billing {
     ) -> Result<()> {
         let url = format!(
             "/accounts/{}/plans/base/status",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/accounts/{accountId}/plans/base/status",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Cancel a base plan.
+     *
+     * This function performs a `PATCH` to the `/accounts/{accountId}/plans/base/status` endpoint.
+     *
+     * As opposed to `account_plan_base_delete`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn account_plan_base_delete_if_changed<C: serde::Serialize>(
+        &self,
+        account_id: &str,
+        current: &C,
+        body: &crate::types::AccountPlanBaseDeleteRequest,
+    ) -> Result<Option<()>> {
+        let url = format!(
+            "/accounts/{}/plans/base/status",
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/accounts/{accountId}/plans/base/status",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `account_plan_addon_cancel`, any one of them grants access.
+    pub const ACCOUNT_PLAN_ADDON_CANCEL_SCOPES: &[&str] = &["billing:master"];
+
     /**
      * Cancel additional plans.
      *
@@ -291,14 +423,54 @@ impl Billing {
     ) -> Result<()> {
         let url = format!(
             "/accounts/{}/plans/addons/status",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/accounts/{accountId}/plans/addons/status",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
+    /**
+     * Cancel additional plans.
+     *
+     * This function performs a `PATCH` to the `/accounts/{accountId}/plans/addons/status` endpoint.
+     *
+     * As opposed to `account_plan_addon_cancel`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn account_plan_addon_cancel_if_changed<C: serde::Serialize>(
+        &self,
+        account_id: &str,
+        current: &C,
+        body: &crate::types::AccountPlanAddonCancelRequest,
+    ) -> Result<Option<()>> {
+        let url = format!(
+            "/accounts/{}/plans/addons/status",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/accounts/{accountId}/plans/addons/status",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The OAuth scopes for `get_plan_usage`, any one of them grants access.
+    pub const GET_PLAN_USAGE_SCOPES: &[&str] = &["billing:master", "account:read:admin"];
+
     /**
      * Get plan usage.
      *
@@ -317,12 +489,21 @@ impl Billing {
     ) -> Result<crate::types::GetPlanUsageResponse> {
         let url = format!(
             "/accounts/{}/plans/usage",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/accounts/{accountId}/plans/usage",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `account_invoice`, any one of them grants access.
+    pub const ACCOUNT_INVOICE_SCOPES: &[&str] = &["billing:master"];
+
     /**
      * List billing invoices.
      *
@@ -350,23 +531,33 @@ impl Billing {
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> Result<crate::types::AccountBillingInvoicesResponseData> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/accounts/{}/billing/invoices?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/accounts/{}/billing/invoices",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/accounts/{accountId}/billing/invoices",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `get_account_invoice`, any one of them grants access.
+    pub const GET_ACCOUNT_INVOICE_SCOPES: &[&str] = &["billing:master"];
+
     /**
      * Get invoice details.
      *
@@ -388,13 +579,25 @@ impl Billing {
     ) -> Result<crate::types::GetAccountBillingInvoiceResponse> {
         let url = format!(
             "/accounts/{}/billing/invoices/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&invoice_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(invoice_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/accounts/{accountId}/billing/invoices/{invoiceId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("invoiceId", invoice_id.to_string()),
+                ],
+            )
+        })
     }
 
+    /// The OAuth scopes for `download_invoice_pdf`, any one of them grants access.
+    pub const DOWNLOAD_INVOICE_PDF_SCOPES: &[&str] = &["billing:master"];
+
     /**
      * Download an invoice file.
      *
@@ -409,9 +612,154 @@ impl Billing {
     pub async fn download_invoice_pdf(&self, invoice_id: &str) -> Result<()> {
         let url = format!(
             "/api/download/billing/invoices/{}",
-            crate::progenitor_support::encode_path(&invoice_id.to_string()),
+            self.client.encode_path(invoice_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/api/download/billing/invoices/{invoiceId}",
+                &[("invoiceId", invoice_id.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`Billing`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn BillingApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait BillingApi: Send + Sync {
+    async fn account(&self, account_id: &str) -> Result<crate::types::Contact>;
+    async fn account_update(
+        &self,
+        account_id: &str,
+        body: &crate::types::BillingContact,
+    ) -> Result<()>;
+    async fn account_plans(&self, account_id: &str) -> Result<crate::types::AccountPlansResponse>;
+    async fn account_plan_create(
+        &self,
+        account_id: &str,
+        body: &crate::types::AccountPlanCreateRequestAllOf,
+    ) -> Result<()>;
+    async fn account_plan_base_update(
+        &self,
+        account_id: &str,
+        body: &crate::types::PlanBase,
+    ) -> Result<()>;
+    async fn account_plan_addon_update(
+        &self,
+        account_id: &str,
+        body: &crate::types::PlanBase,
+    ) -> Result<()>;
+    async fn account_plan_addon_create(
+        &self,
+        account_id: &str,
+        body: &crate::types::AccountPlanAddonCreateRequestOneOf,
+    ) -> Result<crate::types::AccountPlans>;
+    async fn account_plan_base_delete(
+        &self,
+        account_id: &str,
+        body: &crate::types::AccountPlanBaseDeleteRequest,
+    ) -> Result<()>;
+    async fn account_plan_addon_cancel(
+        &self,
+        account_id: &str,
+        body: &crate::types::AccountPlanAddonCancelRequest,
+    ) -> Result<()>;
+    async fn get_plan_usage(&self, account_id: &str) -> Result<crate::types::GetPlanUsageResponse>;
+    async fn account_invoice(
+        &self,
+        account_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<crate::types::AccountBillingInvoicesResponseData>;
+    async fn get_account_invoice(
+        &self,
+        account_id: &str,
+        invoice_id: &str,
+    ) -> Result<crate::types::GetAccountBillingInvoiceResponse>;
+    async fn download_invoice_pdf(&self, invoice_id: &str) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl BillingApi for Billing {
+    async fn account(&self, account_id: &str) -> Result<crate::types::Contact> {
+        Billing::account(self, account_id).await
+    }
+    async fn account_update(
+        &self,
+        account_id: &str,
+        body: &crate::types::BillingContact,
+    ) -> Result<()> {
+        Billing::account_update(self, account_id, body).await
+    }
+    async fn account_plans(&self, account_id: &str) -> Result<crate::types::AccountPlansResponse> {
+        Billing::account_plans(self, account_id).await
+    }
+    async fn account_plan_create(
+        &self,
+        account_id: &str,
+        body: &crate::types::AccountPlanCreateRequestAllOf,
+    ) -> Result<()> {
+        Billing::account_plan_create(self, account_id, body).await
+    }
+    async fn account_plan_base_update(
+        &self,
+        account_id: &str,
+        body: &crate::types::PlanBase,
+    ) -> Result<()> {
+        Billing::account_plan_base_update(self, account_id, body).await
+    }
+    async fn account_plan_addon_update(
+        &self,
+        account_id: &str,
+        body: &crate::types::PlanBase,
+    ) -> Result<()> {
+        Billing::account_plan_addon_update(self, account_id, body).await
+    }
+    async fn account_plan_addon_create(
+        &self,
+        account_id: &str,
+        body: &crate::types::AccountPlanAddonCreateRequestOneOf,
+    ) -> Result<crate::types::AccountPlans> {
+        Billing::account_plan_addon_create(self, account_id, body).await
+    }
+    async fn account_plan_base_delete(
+        &self,
+        account_id: &str,
+        body: &crate::types::AccountPlanBaseDeleteRequest,
+    ) -> Result<()> {
+        Billing::account_plan_base_delete(self, account_id, body).await
+    }
+    async fn account_plan_addon_cancel(
+        &self,
+        account_id: &str,
+        body: &crate::types::AccountPlanAddonCancelRequest,
+    ) -> Result<()> {
+        Billing::account_plan_addon_cancel(self, account_id, body).await
+    }
+    async fn get_plan_usage(&self, account_id: &str) -> Result<crate::types::GetPlanUsageResponse> {
+        Billing::get_plan_usage(self, account_id).await
+    }
+    async fn account_invoice(
+        &self,
+        account_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<crate::types::AccountBillingInvoicesResponseData> {
+        Billing::account_invoice(self, account_id, from, to).await
+    }
+    async fn get_account_invoice(
+        &self,
+        account_id: &str,
+        invoice_id: &str,
+    ) -> Result<crate::types::GetAccountBillingInvoiceResponse> {
+        Billing::get_account_invoice(self, account_id, invoice_id).await
+    }
+    async fn download_invoice_pdf(&self, invoice_id: &str) -> Result<()> {
+        Billing::download_invoice_pdf(self, invoice_id).await
     }
 }
@@ -0,0 +1,80 @@
+use std::{fmt, str::FromStr};
+
+use anyhow::{bail, Result};
+
+/// A BYOC (Bring Your Own Carrier) phone number, checked to be in the E.164
+/// format the API takes: a `+`, the country code and the number, with no
+/// spaces or dashes, like `+14155550100`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ByocNumber(String);
+
+impl ByocNumber {
+    /// Check the number is in E.164 format.
+    pub fn new(number: &str) -> Result<Self> {
+        let digits = match number.strip_prefix('+') {
+            Some(digits) => digits,
+            None => bail!("BYOC number {:?} must start with a +", number),
+        };
+
+        // At most 15 digits, and country codes do not start with a 0.
+        if digits.is_empty()
+            || digits.len() > 15
+            || !digits.chars().all(|c| c.is_ascii_digit())
+            || digits.starts_with('0')
+        {
+            bail!("BYOC number {:?} is not in E.164 format", number);
+        }
+
+        Ok(ByocNumber(number.to_string()))
+    }
+
+    /// The number, as it is sent to the API.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for ByocNumber {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        ByocNumber::new(s)
+    }
+}
+
+impl fmt::Display for ByocNumber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl crate::types::AddByocNumberRequest {
+    /// A request to add the numbers from the carrier, to the account rather
+    /// than to a site or a SIP group.
+    pub fn new(carrier: &str, numbers: &[ByocNumber]) -> Result<Self> {
+        let request = crate::types::AddByocNumberRequest {
+            carrier: carrier.to_string(),
+            phone_numbers: numbers.iter().map(|n| n.to_string()).collect(),
+            sip_group_id: "".to_string(),
+            site_id: "".to_string(),
+        };
+        request.validate()?;
+        Ok(request)
+    }
+
+    /// Check the request names a carrier and at least one number, and that
+    /// each number is in E.164 format. `Phone::add_byoc_number` does this
+    /// before it sends the request.
+    pub fn validate(&self) -> Result<()> {
+        if self.carrier.trim().is_empty() {
+            bail!("BYOC numbers must name their carrier");
+        }
+        if self.phone_numbers.is_empty() {
+            bail!("no BYOC numbers to add");
+        }
+        for n in &self.phone_numbers {
+            ByocNumber::new(n)?;
+        }
+        Ok(())
+    }
+}
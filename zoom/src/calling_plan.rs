@@ -0,0 +1,99 @@
+use std::fmt;
+
+use anyhow::{bail, Result};
+
+/// The type of a Zoom Phone calling plan, as `Phone::unassign_calling_plan`
+/// takes it. Zoom has many more plans than are named here, any other type is
+/// kept as it was given in `Other`, and sent as it is.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CallingPlanType {
+    /// The type `100`.
+    OneHundred,
+    /// The type `200`, the Unlimited US/Canada calling plan.
+    TwoHundred,
+    /// Any other type.
+    Other(String),
+}
+
+impl CallingPlanType {
+    /// The type, as it is sent to the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            CallingPlanType::OneHundred => "100",
+            CallingPlanType::TwoHundred => "200",
+            CallingPlanType::Other(t) => t,
+        }
+    }
+}
+
+impl fmt::Display for CallingPlanType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for CallingPlanType {
+    fn from(t: &str) -> Self {
+        match t {
+            "100" => CallingPlanType::OneHundred,
+            "200" => CallingPlanType::TwoHundred,
+            t => CallingPlanType::Other(t.to_string()),
+        }
+    }
+}
+
+impl From<String> for CallingPlanType {
+    fn from(t: String) -> Self {
+        match t.as_str() {
+            "100" => CallingPlanType::OneHundred,
+            "200" => CallingPlanType::TwoHundred,
+            _ => CallingPlanType::Other(t),
+        }
+    }
+}
+
+impl std::str::FromStr for CallingPlanType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(t: &str) -> Result<Self, Self::Err> {
+        Ok(t.into())
+    }
+}
+
+impl crate::Client {
+    /// Have `Phone::unassign_calling_plan` fetch the user's calling plans
+    /// first, and refuse to unassign one they do not have, rather than send a
+    /// request Zoom fails with an error that does not say so. It is off by
+    /// default, since it is one more request for each plan unassigned.
+    pub fn with_calling_plan_check(&self, check: bool) -> Self {
+        let mut c = self.clone();
+        c.check_calling_plans = check;
+        c
+    }
+
+    /// Check the user has the calling plan of type `plan`, if the client is set
+    /// to with `with_calling_plan_check`.
+    pub(crate) async fn check_calling_plan(
+        &self,
+        user_id: &str,
+        plan: &CallingPlanType,
+    ) -> Result<()> {
+        if !self.check_calling_plans {
+            return Ok(());
+        }
+
+        let user = self.phone().user(user_id).await?;
+        if !user
+            .calling_plan
+            .iter()
+            .any(|p| p.type_.to_string() == plan.as_str())
+        {
+            bail!(
+                "user {} has no calling plan of type {} to unassign",
+                user_id,
+                plan
+            );
+        }
+        Ok(())
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct ChatChannels {
     pub client: Client,
@@ -12,6 +10,17 @@ impl ChatChannels {
         ChatChannels { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        ChatChannels {
+            client: self.client.with_retry(policy),
+        }
+    }
+
+    /// The OAuth scopes for `get_channels`, any one of them grants access.
+    pub const GET_CHANNELS_SCOPES: &[&str] = &["chat_channel:read", "chat_channel:read:admin"];
+
     /**
      * List user's channels.
      *
@@ -37,26 +46,71 @@ impl ChatChannels {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<Vec<crate::types::Channels>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/chat/users/{}/channels?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/chat/users/{}/channels", self.client.encode_path(user_id)),
+            &query_args,
         );
 
-        let resp: crate::types::GetChannelsResponse = self.client.get(&url, None).await?;
+        let resp: crate::types::GetChannelsResponse =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/chat/users/{userId}/channels",
+                    &[("userId", user_id.to_string())],
+                )
+            })?;
 
         // Return our response data.
         Ok(resp.channels)
     }
 
+    /**
+     * List user's channels.
+     *
+     * This function performs a `GET` to the `/chat/users/{userId}/channels` endpoint.
+     *
+     * As opposed to `get_channels`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
+     */
+    pub async fn get_channels_page(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::Channels>, String)> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!("/chat/users/{}/channels", self.client.encode_path(user_id)),
+            &query_args,
+        );
+
+        let resp: crate::types::GetChannelsResponse =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/chat/users/{userId}/channels",
+                    &[("userId", user_id.to_string())],
+                )
+            })?;
+
+        // Return our response data, and where the next page starts.
+        Ok((resp.channels, resp.next_page_token))
+    }
+
     /**
      * List user's channels.
      *
@@ -73,12 +127,16 @@ impl ChatChannels {
      * <p style="background-color:#e1f5fe; color:#01579b; padding:8px"> <b>Note:</b> This API supports both user-managed apps and account-level apps. However, in an <b>account-level</b> <a href="https://marketplace.zoom.us/docs/guides/getting-started/app-types/create-oauth-app">OAuth app</a>, to list channels of another user in the same Zoom account, the user calling this API must have a <a href="https://support.zoom.us/hc/en-us/articles/115001078646-Using-role-management#:~:text=Each%20user%20in%20a%20Zoom,owner%2C%20administrator%2C%20or%20member.&text=Role%2Dbased%20access%20control%20enables,needs%20to%20view%20or%20edit.">role</a> that has the <b>View</b> or <b>Edit</b> permission for the <b>Chat channels</b> feature.</p>
      */
     pub async fn get_all_channels(&self, user_id: &str) -> Result<Vec<crate::types::Channels>> {
-        let url = format!(
-            "/chat/users/{}/channels",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-        );
+        let url = format!("/chat/users/{}/channels", self.client.encode_path(user_id),);
 
-        let mut resp: crate::types::GetChannelsResponse = self.client.get(&url, None).await?;
+        let mut resp: crate::types::GetChannelsResponse =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/chat/users/{userId}/channels",
+                    &[("userId", user_id.to_string())],
+                )
+            })?;
 
         let mut channels = resp.channels;
         let mut page = resp.next_page_token;
@@ -111,6 +169,72 @@ impl ChatChannels {
         Ok(channels)
     }
 
+    /**
+     * List user's channels.
+     *
+     * This function performs a `GET` to the `/chat/users/{userId}/channels` endpoint.
+     *
+     * As opposed to `get_channels`, this function streams the items of all the pages, getting the next page only once the items of the last one are used up. The stream ends after the last page.
+     */
+    pub async fn get_channels_stream(
+        &self,
+        user_id: &str,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::Channels>>> {
+        let url = format!("/chat/users/{}/channels", self.client.encode_path(user_id),);
+
+        self.client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::GetChannelsResponse| (resp.channels, resp.next_page_token),
+            )
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/chat/users/{userId}/channels",
+                    &[("userId", user_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * List user's channels.
+     *
+     * This function performs a `GET` to the `/chat/users/{userId}/channels` endpoint.
+     *
+     * As opposed to `get_channels_stream`, this function streams only the items `predicate` keeps. It too gets the next page only once the items of the last one are used up, so a stream that is cut short, for example with `take`, gets no more pages than it needs.
+     */
+    pub async fn get_channels_filter<P: FnMut(&crate::types::Channels) -> bool>(
+        &self,
+        user_id: &str,
+        mut predicate: P,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::Channels>>> {
+        let url = format!("/chat/users/{}/channels", self.client.encode_path(user_id),);
+
+        let items = self
+            .client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::GetChannelsResponse| (resp.channels, resp.next_page_token),
+            )
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/chat/users/{userId}/channels",
+                    &[("userId", user_id.to_string())],
+                )
+            })?;
+        Ok(futures::TryStreamExt::try_filter(items, move |item| {
+            futures::future::ready(predicate(item))
+        }))
+    }
+
+    /// The OAuth scopes for `create_channel`, any one of them grants access.
+    pub const CREATE_CHANNEL_SCOPES: &[&str] = &["chat_channel:write", "chat_channel:write:admin"];
+
     /**
      * Create a channel.
      *
@@ -133,16 +257,23 @@ impl ChatChannels {
         user_id: &str,
         body: &crate::types::CreateChannelRequest,
     ) -> Result<crate::types::CreateChannelResponse> {
-        let url = format!(
-            "/chat/users/{}/channels",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-        );
+        let url = format!("/chat/users/{}/channels", self.client.encode_path(user_id),);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/chat/users/{userId}/channels",
+                    &[("userId", user_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `get_user_level_channel`, any one of them grants access.
+    pub const GET_USER_LEVEL_CHANNEL_SCOPES: &[&str] = &["chat_channel:read"];
+
     /**
      * Get a channel.
      *
@@ -163,14 +294,20 @@ impl ChatChannels {
      * * `channel_id: &str` -- Channel ID: Unique Identifier of a channel.
      */
     pub async fn get_user_level_channel(&self, channel_id: &str) -> Result<crate::types::Channel> {
-        let url = format!(
-            "/chat/channels/{}",
-            crate::progenitor_support::encode_path(&channel_id.to_string()),
-        );
+        let url = format!("/chat/channels/{}", self.client.encode_path(channel_id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/chat/channels/{channelId}",
+                &[("channelId", channel_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `delete_user_level_channel`, any one of them grants access.
+    pub const DELETE_USER_LEVEL_CHANNEL_SCOPES: &[&str] = &["chat_channel:write"];
+
     /**
      * Delete a channel.
      *
@@ -191,14 +328,42 @@ impl ChatChannels {
      * * `channel_id: &str` -- Channel ID: Unique Identifier of a channel.
      */
     pub async fn delete_user_level_channel(&self, channel_id: &str) -> Result<()> {
-        let url = format!(
-            "/chat/channels/{}",
-            crate::progenitor_support::encode_path(&channel_id.to_string()),
-        );
+        let url = format!("/chat/channels/{}", self.client.encode_path(channel_id),);
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/chat/channels/{channelId}",
+                &[("channelId", channel_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete a channel.
+     *
+     * This function performs a `DELETE` to the `/chat/channels/{channelId}` endpoint.
+     *
+     * As opposed to `delete_user_level_channel`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_user_level_channel_if_exists(&self, channel_id: &str) -> Result<bool> {
+        let url = format!("/chat/channels/{}", self.client.encode_path(channel_id),);
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/chat/channels/{channelId}",
+                    &[("channelId", channel_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `update_user_level_channel`, any one of them grants access.
+    pub const UPDATE_USER_LEVEL_CHANNEL_SCOPES: &[&str] = &["chat_channel:write"];
+
     /**
      * Update a channel.
      *
@@ -222,16 +387,50 @@ impl ChatChannels {
         channel_id: &str,
         body: &crate::types::Attendees,
     ) -> Result<()> {
-        let url = format!(
-            "/chat/channels/{}",
-            crate::progenitor_support::encode_path(&channel_id.to_string()),
-        );
+        let url = format!("/chat/channels/{}", self.client.encode_path(channel_id),);
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/chat/channels/{channelId}",
+                    &[("channelId", channel_id.to_string())],
+                )
+            })
     }
 
+    /**
+     * Update a channel.
+     *
+     * This function performs a `PATCH` to the `/chat/channels/{channelId}` endpoint.
+     *
+     * As opposed to `update_user_level_channel`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn update_user_level_channel_if_changed<C: serde::Serialize>(
+        &self,
+        channel_id: &str,
+        current: &C,
+        body: &crate::types::Attendees,
+    ) -> Result<Option<()>> {
+        let url = format!("/chat/channels/{}", self.client.encode_path(channel_id),);
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/chat/channels/{channelId}",
+                    &[("channelId", channel_id.to_string())],
+                )
+            })
+    }
+
+    /// The OAuth scopes for `remove_user_level_channel_member`, any one of them grants access.
+    pub const REMOVE_USER_LEVEL_CHANNEL_MEMBER_SCOPES: &[&str] = &["chat_channel:write"];
+
     /**
      * Remove a member.
      *
@@ -258,13 +457,58 @@ impl ChatChannels {
     ) -> Result<()> {
         let url = format!(
             "/chat/channels/{}/members/{}",
-            crate::progenitor_support::encode_path(&channel_id.to_string()),
-            crate::progenitor_support::encode_path(&member_id.to_string()),
+            self.client.encode_path(channel_id),
+            self.client.encode_path(member_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/chat/channels/{channelId}/members/{memberId}",
+                &[
+                    ("channelId", channel_id.to_string()),
+                    ("memberId", member_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Remove a member.
+     *
+     * This function performs a `DELETE` to the `/chat/channels/{channelId}/members/{memberId}` endpoint.
+     *
+     * As opposed to `remove_user_level_channel_member`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn remove_user_level_channel_member_if_exists(
+        &self,
+        channel_id: &str,
+        member_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/chat/channels/{}/members/{}",
+            self.client.encode_path(channel_id),
+            self.client.encode_path(member_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/chat/channels/{channelId}/members/{memberId}",
+                    &[
+                        ("channelId", channel_id.to_string()),
+                        ("memberId", member_id.to_string()),
+                    ],
+                )
+            })
     }
 
+    /// The OAuth scopes for `join_channel`, any one of them grants access.
+    pub const JOIN_CHANNEL_SCOPES: &[&str] = &["chat_channel:write"];
+
     /**
      * Join a channel.
      *
@@ -287,12 +531,21 @@ impl ChatChannels {
     ) -> Result<crate::types::JoinChannelResponse> {
         let url = format!(
             "/chat/channels/{}/members/me",
-            crate::progenitor_support::encode_path(&channel_id.to_string()),
+            self.client.encode_path(channel_id),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/chat/channels/{channelId}/members/me",
+                &[("channelId", channel_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `leave_channel`, any one of them grants access.
+    pub const LEAVE_CHANNEL_SCOPES: &[&str] = &["chat_channel:write"];
+
     /**
      * Leave a channel.
      *
@@ -312,9 +565,157 @@ impl ChatChannels {
     pub async fn leave_channel(&self, channel_id: &str) -> Result<()> {
         let url = format!(
             "/chat/channels/{}/members/me",
-            crate::progenitor_support::encode_path(&channel_id.to_string()),
+            self.client.encode_path(channel_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/chat/channels/{channelId}/members/me",
+                &[("channelId", channel_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Leave a channel.
+     *
+     * This function performs a `DELETE` to the `/chat/channels/{channelId}/members/me` endpoint.
+     *
+     * As opposed to `leave_channel`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn leave_channel_if_exists(&self, channel_id: &str) -> Result<bool> {
+        let url = format!(
+            "/chat/channels/{}/members/me",
+            self.client.encode_path(channel_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/chat/channels/{channelId}/members/me",
+                    &[("channelId", channel_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`ChatChannels`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ChatChannelsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ChatChannelsApi: Send + Sync {
+    async fn get_channels(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::Channels>>;
+    async fn get_channels_page(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::Channels>, String)>;
+    async fn get_all_channels(&self, user_id: &str) -> Result<Vec<crate::types::Channels>>;
+    async fn create_channel(
+        &self,
+        user_id: &str,
+        body: &crate::types::CreateChannelRequest,
+    ) -> Result<crate::types::CreateChannelResponse>;
+    async fn get_user_level_channel(&self, channel_id: &str) -> Result<crate::types::Channel>;
+    async fn delete_user_level_channel(&self, channel_id: &str) -> Result<()>;
+    async fn delete_user_level_channel_if_exists(&self, channel_id: &str) -> Result<bool>;
+    async fn update_user_level_channel(
+        &self,
+        channel_id: &str,
+        body: &crate::types::Attendees,
+    ) -> Result<()>;
+    async fn remove_user_level_channel_member(
+        &self,
+        channel_id: &str,
+        member_id: &str,
+    ) -> Result<()>;
+    async fn remove_user_level_channel_member_if_exists(
+        &self,
+        channel_id: &str,
+        member_id: &str,
+    ) -> Result<bool>;
+    async fn join_channel(&self, channel_id: &str) -> Result<crate::types::JoinChannelResponse>;
+    async fn leave_channel(&self, channel_id: &str) -> Result<()>;
+    async fn leave_channel_if_exists(&self, channel_id: &str) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ChatChannelsApi for ChatChannels {
+    async fn get_channels(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::Channels>> {
+        ChatChannels::get_channels(self, user_id, page_size, next_page_token).await
+    }
+    async fn get_channels_page(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::Channels>, String)> {
+        ChatChannels::get_channels_page(self, user_id, page_size, next_page_token).await
+    }
+    async fn get_all_channels(&self, user_id: &str) -> Result<Vec<crate::types::Channels>> {
+        ChatChannels::get_all_channels(self, user_id).await
+    }
+    async fn create_channel(
+        &self,
+        user_id: &str,
+        body: &crate::types::CreateChannelRequest,
+    ) -> Result<crate::types::CreateChannelResponse> {
+        ChatChannels::create_channel(self, user_id, body).await
+    }
+    async fn get_user_level_channel(&self, channel_id: &str) -> Result<crate::types::Channel> {
+        ChatChannels::get_user_level_channel(self, channel_id).await
+    }
+    async fn delete_user_level_channel(&self, channel_id: &str) -> Result<()> {
+        ChatChannels::delete_user_level_channel(self, channel_id).await
+    }
+    async fn delete_user_level_channel_if_exists(&self, channel_id: &str) -> Result<bool> {
+        ChatChannels::delete_user_level_channel_if_exists(self, channel_id).await
+    }
+    async fn update_user_level_channel(
+        &self,
+        channel_id: &str,
+        body: &crate::types::Attendees,
+    ) -> Result<()> {
+        ChatChannels::update_user_level_channel(self, channel_id, body).await
+    }
+    async fn remove_user_level_channel_member(
+        &self,
+        channel_id: &str,
+        member_id: &str,
+    ) -> Result<()> {
+        ChatChannels::remove_user_level_channel_member(self, channel_id, member_id).await
+    }
+    async fn remove_user_level_channel_member_if_exists(
+        &self,
+        channel_id: &str,
+        member_id: &str,
+    ) -> Result<bool> {
+        ChatChannels::remove_user_level_channel_member_if_exists(self, channel_id, member_id).await
+    }
+    async fn join_channel(&self, channel_id: &str) -> Result<crate::types::JoinChannelResponse> {
+        ChatChannels::join_channel(self, channel_id).await
+    }
+    async fn leave_channel(&self, channel_id: &str) -> Result<()> {
+        ChatChannels::leave_channel(self, channel_id).await
+    }
+    async fn leave_channel_if_exists(&self, channel_id: &str) -> Result<bool> {
+        ChatChannels::leave_channel_if_exists(self, channel_id).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct ChatChannelsAccountLevel {
     pub client: Client,
@@ -12,6 +10,17 @@ impl ChatChannelsAccountLevel {
         ChatChannelsAccountLevel { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        ChatChannelsAccountLevel {
+            client: self.client.with_retry(policy),
+        }
+    }
+
+    /// The OAuth scopes for `get_channel`, any one of them grants access.
+    pub const GET_CHANNEL_SCOPES: &[&str] = &["chat_channel:read:admin"];
+
     /**
      * Get a channel.
      *
@@ -37,13 +46,25 @@ impl ChatChannelsAccountLevel {
     ) -> Result<crate::types::Channel> {
         let url = format!(
             "/chat/users/{}/channels/{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&channel_id.to_string()),
+            self.client.encode_path(user_id),
+            self.client.encode_path(channel_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/chat/users/{userId}/channels/{channelId}",
+                &[
+                    ("userId", user_id.to_string()),
+                    ("channelId", channel_id.to_string()),
+                ],
+            )
+        })
     }
 
+    /// The OAuth scopes for `delete_channel`, any one of them grants access.
+    pub const DELETE_CHANNEL_SCOPES: &[&str] = &["chat_channel:write:admin"];
+
     /**
      * Delete a channel.
      *
@@ -64,13 +85,54 @@ impl ChatChannelsAccountLevel {
     pub async fn delete_channel(&self, user_id: &str, channel_id: &str) -> Result<()> {
         let url = format!(
             "/chat/users/{}/channels/{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&channel_id.to_string()),
+            self.client.encode_path(user_id),
+            self.client.encode_path(channel_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/chat/users/{userId}/channels/{channelId}",
+                &[
+                    ("userId", user_id.to_string()),
+                    ("channelId", channel_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Delete a channel.
+     *
+     * This function performs a `DELETE` to the `/chat/users/{userId}/channels/{channelId}` endpoint.
+     *
+     * As opposed to `delete_channel`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_channel_if_exists(&self, user_id: &str, channel_id: &str) -> Result<bool> {
+        let url = format!(
+            "/chat/users/{}/channels/{}",
+            self.client.encode_path(user_id),
+            self.client.encode_path(channel_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/chat/users/{userId}/channels/{channelId}",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("channelId", channel_id.to_string()),
+                    ],
+                )
+            })
     }
 
+    /// The OAuth scopes for `update_channel`, any one of them grants access.
+    pub const UPDATE_CHANNEL_SCOPES: &[&str] = &["chat_channel:write:admin"];
+
     /**
      * Update a channel.
      *
@@ -97,15 +159,63 @@ impl ChatChannelsAccountLevel {
     ) -> Result<()> {
         let url = format!(
             "/chat/users/{}/channels/{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&channel_id.to_string()),
+            self.client.encode_path(user_id),
+            self.client.encode_path(channel_id),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/chat/users/{userId}/channels/{channelId}",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("channelId", channel_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * Update a channel.
+     *
+     * This function performs a `PATCH` to the `/chat/users/{userId}/channels/{channelId}` endpoint.
+     *
+     * As opposed to `update_channel`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn update_channel_if_changed<C: serde::Serialize>(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+        current: &C,
+        body: &crate::types::Attendees,
+    ) -> Result<Option<()>> {
+        let url = format!(
+            "/chat/users/{}/channels/{}",
+            self.client.encode_path(user_id),
+            self.client.encode_path(channel_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/chat/users/{userId}/channels/{channelId}",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("channelId", channel_id.to_string()),
+                    ],
+                )
+            })
     }
 
+    /// The OAuth scopes for `list_channel_members`, any one of them grants access.
+    pub const LIST_CHANNEL_MEMBERS_SCOPES: &[&str] = &["chat_channel:read:admin"];
+
     /**
      * List channel members.
      *
@@ -131,28 +241,86 @@ impl ChatChannelsAccountLevel {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<Vec<crate::types::ListChannelMembersResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/chat/users/{}/channels/{}/members?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&channel_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/chat/users/{}/channels/{}/members",
+                self.client.encode_path(user_id),
+                self.client.encode_path(channel_id)
+            ),
+            &query_args,
         );
 
         let resp: crate::types::ListChannelMembersResponseData =
-            self.client.get(&url, None).await?;
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/chat/users/{userId}/channels/{channelId}/members",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("channelId", channel_id.to_string()),
+                    ],
+                )
+            })?;
 
         // Return our response data.
         Ok(resp.members)
     }
 
+    /**
+     * List channel members.
+     *
+     * This function performs a `GET` to the `/chat/users/{userId}/channels/{channelId}/members` endpoint.
+     *
+     * As opposed to `list_channel_members`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
+     */
+    pub async fn list_channel_members_page(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::ListChannelMembersResponse>, String)> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/chat/users/{}/channels/{}/members",
+                self.client.encode_path(user_id),
+                self.client.encode_path(channel_id)
+            ),
+            &query_args,
+        );
+
+        let resp: crate::types::ListChannelMembersResponseData =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/chat/users/{userId}/channels/{channelId}/members",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("channelId", channel_id.to_string()),
+                    ],
+                )
+            })?;
+
+        // Return our response data, and where the next page starts.
+        Ok((resp.members, resp.next_page_token))
+    }
+
     /**
      * List channel members.
      *
@@ -173,12 +341,21 @@ impl ChatChannelsAccountLevel {
     ) -> Result<Vec<crate::types::ListChannelMembersResponse>> {
         let url = format!(
             "/chat/users/{}/channels/{}/members",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&channel_id.to_string()),
+            self.client.encode_path(user_id),
+            self.client.encode_path(channel_id),
         );
 
         let mut resp: crate::types::ListChannelMembersResponseData =
-            self.client.get(&url, None).await?;
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/chat/users/{userId}/channels/{channelId}/members",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("channelId", channel_id.to_string()),
+                    ],
+                )
+            })?;
 
         let mut members = resp.members;
         let mut page = resp.next_page_token;
@@ -211,6 +388,94 @@ impl ChatChannelsAccountLevel {
         Ok(members)
     }
 
+    /**
+     * List channel members.
+     *
+     * This function performs a `GET` to the `/chat/users/{userId}/channels/{channelId}/members` endpoint.
+     *
+     * As opposed to `list_channel_members`, this function streams the items of all the pages, getting the next page only once the items of the last one are used up. The stream ends after the last page.
+     */
+    pub async fn list_channel_members_stream(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::ListChannelMembersResponse>>> {
+        let url = format!(
+            "/chat/users/{}/channels/{}/members",
+            self.client.encode_path(user_id),
+            self.client.encode_path(channel_id),
+        );
+
+        self.client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListChannelMembersResponseData| {
+                    (resp.members, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/chat/users/{userId}/channels/{channelId}/members",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("channelId", channel_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * List channel members.
+     *
+     * This function performs a `GET` to the `/chat/users/{userId}/channels/{channelId}/members` endpoint.
+     *
+     * As opposed to `list_channel_members_stream`, this function streams only the items `predicate` keeps. It too gets the next page only once the items of the last one are used up, so a stream that is cut short, for example with `take`, gets no more pages than it needs.
+     */
+    pub async fn list_channel_members_filter<
+        P: FnMut(&crate::types::ListChannelMembersResponse) -> bool,
+    >(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+        mut predicate: P,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::ListChannelMembersResponse>>> {
+        let url = format!(
+            "/chat/users/{}/channels/{}/members",
+            self.client.encode_path(user_id),
+            self.client.encode_path(channel_id),
+        );
+
+        let items = self
+            .client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListChannelMembersResponseData| {
+                    (resp.members, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/chat/users/{userId}/channels/{channelId}/members",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("channelId", channel_id.to_string()),
+                    ],
+                )
+            })?;
+        Ok(futures::TryStreamExt::try_filter(items, move |item| {
+            futures::future::ready(predicate(item))
+        }))
+    }
+
+    /// The OAuth scopes for `invite_channel_members`, any one of them grants access.
+    pub const INVITE_CHANNEL_MEMBERS_SCOPES: &[&str] = &["chat_channel:write:admin"];
+
     /**
      * Invite channel members.
      *
@@ -235,15 +500,28 @@ impl ChatChannelsAccountLevel {
     ) -> Result<crate::types::InviteChannelMembersResponse> {
         let url = format!(
             "/chat/users/{}/channels/{}/members",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&channel_id.to_string()),
+            self.client.encode_path(user_id),
+            self.client.encode_path(channel_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/chat/users/{userId}/channels/{channelId}/members",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("channelId", channel_id.to_string()),
+                    ],
+                )
+            })
     }
 
+    /// The OAuth scopes for `remove_channel_member`, any one of them grants access.
+    pub const REMOVE_CHANNEL_MEMBER_SCOPES: &[&str] = &["chat_channel:write:admin"];
+
     /**
      * Remove a member.
      *
@@ -271,11 +549,199 @@ impl ChatChannelsAccountLevel {
     ) -> Result<()> {
         let url = format!(
             "/chat/users/{}/channels/{}/members/{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&channel_id.to_string()),
-            crate::progenitor_support::encode_path(&member_id.to_string()),
+            self.client.encode_path(user_id),
+            self.client.encode_path(channel_id),
+            self.client.encode_path(member_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/chat/users/{userId}/channels/{channelId}/members/{memberId}",
+                &[
+                    ("userId", user_id.to_string()),
+                    ("channelId", channel_id.to_string()),
+                    ("memberId", member_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Remove a member.
+     *
+     * This function performs a `DELETE` to the `/chat/users/{userId}/channels/{channelId}/members/{memberId}` endpoint.
+     *
+     * As opposed to `remove_channel_member`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn remove_channel_member_if_exists(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+        member_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/chat/users/{}/channels/{}/members/{}",
+            self.client.encode_path(user_id),
+            self.client.encode_path(channel_id),
+            self.client.encode_path(member_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/chat/users/{userId}/channels/{channelId}/members/{memberId}",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("channelId", channel_id.to_string()),
+                        ("memberId", member_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`ChatChannelsAccountLevel`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ChatChannelsAccountLevelApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ChatChannelsAccountLevelApi: Send + Sync {
+    async fn get_channel(&self, user_id: &str, channel_id: &str) -> Result<crate::types::Channel>;
+    async fn delete_channel(&self, user_id: &str, channel_id: &str) -> Result<()>;
+    async fn delete_channel_if_exists(&self, user_id: &str, channel_id: &str) -> Result<bool>;
+    async fn update_channel(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+        body: &crate::types::Attendees,
+    ) -> Result<()>;
+    async fn list_channel_members(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::ListChannelMembersResponse>>;
+    async fn list_channel_members_page(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::ListChannelMembersResponse>, String)>;
+    async fn list_all_channel_members(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+    ) -> Result<Vec<crate::types::ListChannelMembersResponse>>;
+    async fn invite_channel_members(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+        body: &crate::types::InviteChannelMembersRequest,
+    ) -> Result<crate::types::InviteChannelMembersResponse>;
+    async fn remove_channel_member(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+        member_id: &str,
+    ) -> Result<()>;
+    async fn remove_channel_member_if_exists(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+        member_id: &str,
+    ) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ChatChannelsAccountLevelApi for ChatChannelsAccountLevel {
+    async fn get_channel(&self, user_id: &str, channel_id: &str) -> Result<crate::types::Channel> {
+        ChatChannelsAccountLevel::get_channel(self, user_id, channel_id).await
+    }
+    async fn delete_channel(&self, user_id: &str, channel_id: &str) -> Result<()> {
+        ChatChannelsAccountLevel::delete_channel(self, user_id, channel_id).await
+    }
+    async fn delete_channel_if_exists(&self, user_id: &str, channel_id: &str) -> Result<bool> {
+        ChatChannelsAccountLevel::delete_channel_if_exists(self, user_id, channel_id).await
+    }
+    async fn update_channel(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+        body: &crate::types::Attendees,
+    ) -> Result<()> {
+        ChatChannelsAccountLevel::update_channel(self, user_id, channel_id, body).await
+    }
+    async fn list_channel_members(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::ListChannelMembersResponse>> {
+        ChatChannelsAccountLevel::list_channel_members(
+            self,
+            user_id,
+            channel_id,
+            page_size,
+            next_page_token,
+        )
+        .await
+    }
+    async fn list_channel_members_page(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::ListChannelMembersResponse>, String)> {
+        ChatChannelsAccountLevel::list_channel_members_page(
+            self,
+            user_id,
+            channel_id,
+            page_size,
+            next_page_token,
+        )
+        .await
+    }
+    async fn list_all_channel_members(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+    ) -> Result<Vec<crate::types::ListChannelMembersResponse>> {
+        ChatChannelsAccountLevel::list_all_channel_members(self, user_id, channel_id).await
+    }
+    async fn invite_channel_members(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+        body: &crate::types::InviteChannelMembersRequest,
+    ) -> Result<crate::types::InviteChannelMembersResponse> {
+        ChatChannelsAccountLevel::invite_channel_members(self, user_id, channel_id, body).await
+    }
+    async fn remove_channel_member(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+        member_id: &str,
+    ) -> Result<()> {
+        ChatChannelsAccountLevel::remove_channel_member(self, user_id, channel_id, member_id).await
+    }
+    async fn remove_channel_member_if_exists(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+        member_id: &str,
+    ) -> Result<bool> {
+        ChatChannelsAccountLevel::remove_channel_member_if_exists(
+            self, user_id, channel_id, member_id,
+        )
+        .await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct ChatMessages {
     pub client: Client,
@@ -12,6 +10,17 @@ impl ChatMessages {
         ChatMessages { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        ChatMessages {
+            client: self.client.with_retry(policy),
+        }
+    }
+
+    /// The OAuth scopes for `get_page`, any one of them grants access.
+    pub const GET_PAGE_SCOPES: &[&str] = &["chat_message:read", "chat_message:read:admin"];
+
     /**
      * List user's chat messages.
      *
@@ -52,41 +61,115 @@ impl ChatMessages {
         next_page_token: &str,
         include_deleted_and_edited_message: &str,
     ) -> Result<Vec<crate::types::Messages>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        if !to_contact.is_empty() && !to_channel.is_empty() {
+            return Err(crate::ClientError::ConflictingParams {
+                params: vec!["to_contact".to_string(), "to_channel".to_string()],
+            });
+        }
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !date.to_string().is_empty() {
-            query_args.push(("date".to_string(), date.to_string()));
+            query_args.push(("date", date.to_string()));
         }
         if !include_deleted_and_edited_message.is_empty() {
             query_args.push((
-                "include_deleted_and_edited_message".to_string(),
+                "include_deleted_and_edited_message",
                 include_deleted_and_edited_message.to_string(),
             ));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !to_channel.is_empty() {
-            query_args.push(("to_channel".to_string(), to_channel.to_string()));
+            query_args.push(("to_channel", to_channel.to_string()));
         }
         if !to_contact.is_empty() {
-            query_args.push(("to_contact".to_string(), to_contact.to_string()));
+            query_args.push(("to_contact", to_contact.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/chat/users/{}/messages?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/chat/users/{}/messages", self.client.encode_path(user_id)),
+            &query_args,
         );
 
-        let resp: crate::types::GetChatMessagesResponse = self.client.get(&url, None).await?;
+        let resp: crate::types::GetChatMessagesResponse =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/chat/users/{userId}/messages",
+                    &[("userId", user_id.to_string())],
+                )
+            })?;
 
         // Return our response data.
         Ok(resp.messages)
     }
 
+    /**
+     * List user's chat messages.
+     *
+     * This function performs a `GET` to the `/chat/users/{userId}/messages` endpoint.
+     *
+     * As opposed to `get_page`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
+     */
+    pub async fn get_page_with_token(
+        &self,
+        user_id: &str,
+        to_contact: &str,
+        to_channel: &str,
+        date: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+        include_deleted_and_edited_message: &str,
+    ) -> Result<(Vec<crate::types::Messages>, String)> {
+        if !to_contact.is_empty() && !to_channel.is_empty() {
+            return Err(crate::ClientError::ConflictingParams {
+                params: vec!["to_contact".to_string(), "to_channel".to_string()],
+            });
+        }
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !date.to_string().is_empty() {
+            query_args.push(("date", date.to_string()));
+        }
+        if !include_deleted_and_edited_message.is_empty() {
+            query_args.push((
+                "include_deleted_and_edited_message",
+                include_deleted_and_edited_message.to_string(),
+            ));
+        }
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        if !to_channel.is_empty() {
+            query_args.push(("to_channel", to_channel.to_string()));
+        }
+        if !to_contact.is_empty() {
+            query_args.push(("to_contact", to_contact.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!("/chat/users/{}/messages", self.client.encode_path(user_id)),
+            &query_args,
+        );
+
+        let resp: crate::types::GetChatMessagesResponse =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/chat/users/{userId}/messages",
+                    &[("userId", user_id.to_string())],
+                )
+            })?;
+
+        // Return our response data, and where the next page starts.
+        Ok((resp.messages, resp.next_page_token))
+    }
+
     /**
      * List user's chat messages.
      *
@@ -113,30 +196,40 @@ impl ChatMessages {
         date: chrono::NaiveDate,
         include_deleted_and_edited_message: &str,
     ) -> Result<Vec<crate::types::Messages>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        if !to_contact.is_empty() && !to_channel.is_empty() {
+            return Err(crate::ClientError::ConflictingParams {
+                params: vec!["to_contact".to_string(), "to_channel".to_string()],
+            });
+        }
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !date.to_string().is_empty() {
-            query_args.push(("date".to_string(), date.to_string()));
+            query_args.push(("date", date.to_string()));
         }
         if !include_deleted_and_edited_message.is_empty() {
             query_args.push((
-                "include_deleted_and_edited_message".to_string(),
+                "include_deleted_and_edited_message",
                 include_deleted_and_edited_message.to_string(),
             ));
         }
         if !to_channel.is_empty() {
-            query_args.push(("to_channel".to_string(), to_channel.to_string()));
+            query_args.push(("to_channel", to_channel.to_string()));
         }
         if !to_contact.is_empty() {
-            query_args.push(("to_contact".to_string(), to_contact.to_string()));
+            query_args.push(("to_contact", to_contact.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/chat/users/{}/messages?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/chat/users/{}/messages", self.client.encode_path(user_id)),
+            &query_args,
         );
 
-        let mut resp: crate::types::GetChatMessagesResponse = self.client.get(&url, None).await?;
+        let mut resp: crate::types::GetChatMessagesResponse =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/chat/users/{userId}/messages",
+                    &[("userId", user_id.to_string())],
+                )
+            })?;
 
         let mut messages = resp.messages;
         let mut page = resp.next_page_token;
@@ -169,6 +262,145 @@ impl ChatMessages {
         Ok(messages)
     }
 
+    /**
+     * List user's chat messages.
+     *
+     * This function performs a `GET` to the `/chat/users/{userId}/messages` endpoint.
+     *
+     * As opposed to `get_page`, this function streams the items of all the pages, getting the next page only once the items of the last one are used up. The stream ends after the last page.
+     */
+    pub async fn get_page_stream(
+        &self,
+        user_id: &str,
+        to_contact: &str,
+        to_channel: &str,
+        date: chrono::NaiveDate,
+        include_deleted_and_edited_message: &str,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::Messages>>> {
+        if !to_contact.is_empty() && !to_channel.is_empty() {
+            return Err(crate::ClientError::ConflictingParams {
+                params: vec!["to_contact".to_string(), "to_channel".to_string()],
+            });
+        }
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !date.to_string().is_empty() {
+            query_args.push(("date", date.to_string()));
+        }
+        if !include_deleted_and_edited_message.is_empty() {
+            query_args.push((
+                "include_deleted_and_edited_message",
+                include_deleted_and_edited_message.to_string(),
+            ));
+        }
+        if !to_channel.is_empty() {
+            query_args.push(("to_channel", to_channel.to_string()));
+        }
+        if !to_contact.is_empty() {
+            query_args.push(("to_contact", to_contact.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!("/chat/users/{}/messages", self.client.encode_path(user_id)),
+            &query_args,
+        );
+
+        self.client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::GetChatMessagesResponse| (resp.messages, resp.next_page_token),
+            )
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/chat/users/{userId}/messages",
+                    &[("userId", user_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * List user's chat messages.
+     *
+     * This function performs a `GET` to the `/chat/users/{userId}/messages` endpoint.
+     *
+     * As opposed to `get_page_stream`, this function streams only the items `predicate` keeps. It too gets the next page only once the items of the last one are used up, so a stream that is cut short, for example with `take`, gets no more pages than it needs.
+     */
+    pub async fn get_page_filter<P: FnMut(&crate::types::Messages) -> bool>(
+        &self,
+        user_id: &str,
+        to_contact: &str,
+        to_channel: &str,
+        date: chrono::NaiveDate,
+        include_deleted_and_edited_message: &str,
+        mut predicate: P,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::Messages>>> {
+        if !to_contact.is_empty() && !to_channel.is_empty() {
+            return Err(crate::ClientError::ConflictingParams {
+                params: vec!["to_contact".to_string(), "to_channel".to_string()],
+            });
+        }
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !date.to_string().is_empty() {
+            query_args.push(("date", date.to_string()));
+        }
+        if !include_deleted_and_edited_message.is_empty() {
+            query_args.push((
+                "include_deleted_and_edited_message",
+                include_deleted_and_edited_message.to_string(),
+            ));
+        }
+        if !to_channel.is_empty() {
+            query_args.push(("to_channel", to_channel.to_string()));
+        }
+        if !to_contact.is_empty() {
+            query_args.push(("to_contact", to_contact.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!("/chat/users/{}/messages", self.client.encode_path(user_id)),
+            &query_args,
+        );
+
+        let items = self
+            .client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::GetChatMessagesResponse| (resp.messages, resp.next_page_token),
+            )
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/chat/users/{userId}/messages",
+                    &[("userId", user_id.to_string())],
+                )
+            })?;
+        Ok(futures::TryStreamExt::try_filter(items, move |item| {
+            futures::future::ready(predicate(item))
+        }))
+    }
+
+    /**
+     * A request to `get_page`, to set its query parameters by name rather than by position.
+     * The ones left unset are left off the query.
+     */
+    pub fn get_page_request(&self, user_id: &str, date: chrono::NaiveDate) -> GetPageRequest<'_> {
+        GetPageRequest {
+            api: self,
+            user_id: user_id.to_string(),
+            to_contact: None,
+            to_channel: None,
+            date,
+            page_size: None,
+            next_page_token: None,
+            include_deleted_and_edited_message: None,
+        }
+    }
+
+    /// The OAuth scopes for `senda`, any one of them grants access.
+    pub const SENDA_SCOPES: &[&str] = &["chat_message:write", "chat_message:write:admin"];
+
     /**
      * Send a chat message.
      *
@@ -187,14 +419,18 @@ impl ChatMessages {
         user_id: &str,
         body: &crate::types::SendaChatMessageRequest,
     ) -> Result<crate::types::Groups> {
-        let url = format!(
-            "/chat/users/{}/messages",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-        );
+        let url = format!("/chat/users/{}/messages", self.client.encode_path(user_id),);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/chat/users/{userId}/messages",
+                    &[("userId", user_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -221,15 +457,63 @@ impl ChatMessages {
     ) -> Result<()> {
         let url = format!(
             "/chat/users/{}/messages/{}/status",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&message_id.to_string()),
+            self.client.encode_path(user_id),
+            self.client.encode_path(message_id),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/chat/users/{userId}/messages/{messageId}/status",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("messageId", message_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * Mark message read or unread.
+     *
+     * This function performs a `PATCH` to the `/chat/users/{userId}/messages/{messageId}/status` endpoint.
+     *
+     * As opposed to `mark_message`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn mark_message_if_changed<C: serde::Serialize>(
+        &self,
+        user_id: &str,
+        message_id: &str,
+        current: &C,
+        body: &crate::types::MarkMessageRequest,
+    ) -> Result<Option<()>> {
+        let url = format!(
+            "/chat/users/{}/messages/{}/status",
+            self.client.encode_path(user_id),
+            self.client.encode_path(message_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/chat/users/{userId}/messages/{messageId}/status",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("messageId", message_id.to_string()),
+                    ],
+                )
+            })
     }
 
+    /// The OAuth scopes for `react_message`, any one of them grants access.
+    pub const REACT_MESSAGE_SCOPES: &[&str] = &["chat_message:write", "chat_message:write:admin"];
+
     /**
      * React to a chat message.
      *
@@ -254,15 +538,63 @@ impl ChatMessages {
     ) -> Result<()> {
         let url = format!(
             "/chat/users/{}/messages/{}/emoji_reactions",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&message_id.to_string()),
+            self.client.encode_path(user_id),
+            self.client.encode_path(message_id),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/chat/users/{userId}/messages/{messageId}/emoji_reactions",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("messageId", message_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * React to a chat message.
+     *
+     * This function performs a `PATCH` to the `/chat/users/{userId}/messages/{messageId}/emoji_reactions` endpoint.
+     *
+     * As opposed to `react_message`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn react_message_if_changed<C: serde::Serialize>(
+        &self,
+        user_id: &str,
+        message_id: &str,
+        current: &C,
+        body: &crate::types::ReactMessageRequest,
+    ) -> Result<Option<()>> {
+        let url = format!(
+            "/chat/users/{}/messages/{}/emoji_reactions",
+            self.client.encode_path(user_id),
+            self.client.encode_path(message_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/chat/users/{userId}/messages/{messageId}/emoji_reactions",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("messageId", message_id.to_string()),
+                    ],
+                )
+            })
     }
 
+    /// The OAuth scopes for `get`, any one of them grants access.
+    pub const GET_SCOPES: &[&str] = &["chat_message:read", "chat_message:read:admin"];
+
     /**
      * Get a message.
      *
@@ -291,24 +623,37 @@ impl ChatMessages {
         to_contact: &str,
         to_channel: &str,
     ) -> Result<crate::types::GetChatMessageResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !to_channel.is_empty() {
-            query_args.push(("to_channel".to_string(), to_channel.to_string()));
+            query_args.push(("to_channel", to_channel.to_string()));
         }
         if !to_contact.is_empty() {
-            query_args.push(("to_contact".to_string(), to_contact.to_string()));
+            query_args.push(("to_contact", to_contact.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/chat/users/{}/messages/{}?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&message_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/chat/users/{}/messages/{}",
+                self.client.encode_path(user_id),
+                self.client.encode_path(message_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/chat/users/{userId}/messages/{messageId}",
+                &[
+                    ("userId", user_id.to_string()),
+                    ("messageId", message_id.to_string()),
+                ],
+            )
+        })
     }
 
+    /// The OAuth scopes for `edit_message`, any one of them grants access.
+    pub const EDIT_MESSAGE_SCOPES: &[&str] = &["chat_message:write", "chat_message:write:admin"];
+
     /**
      * Update a message.
      *
@@ -335,15 +680,28 @@ impl ChatMessages {
     ) -> Result<()> {
         let url = format!(
             "/chat/users/{}/messages/{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&message_id.to_string()),
+            self.client.encode_path(user_id),
+            self.client.encode_path(message_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/chat/users/{userId}/messages/{messageId}",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("messageId", message_id.to_string()),
+                    ],
+                )
+            })
     }
 
+    /// The OAuth scopes for `delete`, any one of them grants access.
+    pub const DELETE_SCOPES: &[&str] = &["chat_message:write", "chat_message:write:admin"];
+
     /**
      * Delete a message.
      *
@@ -379,21 +737,356 @@ impl ChatMessages {
         to_contact: &str,
         to_channel: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        if !to_contact.is_empty() && !to_channel.is_empty() {
+            return Err(crate::ClientError::ConflictingParams {
+                params: vec!["to_contact".to_string(), "to_channel".to_string()],
+            });
+        }
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !to_channel.is_empty() {
-            query_args.push(("to_channel".to_string(), to_channel.to_string()));
+            query_args.push(("to_channel", to_channel.to_string()));
         }
         if !to_contact.is_empty() {
-            query_args.push(("to_contact".to_string(), to_contact.to_string()));
+            query_args.push(("to_contact", to_contact.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/chat/users/{}/messages/{}?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&message_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/chat/users/{}/messages/{}",
+                self.client.encode_path(user_id),
+                self.client.encode_path(message_id)
+            ),
+            &query_args,
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/chat/users/{userId}/messages/{messageId}",
+                &[
+                    ("userId", user_id.to_string()),
+                    ("messageId", message_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Delete a message.
+     *
+     * This function performs a `DELETE` to the `/chat/users/{userId}/messages/{messageId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(
+        &self,
+        user_id: &str,
+        message_id: &str,
+        to_contact: &str,
+        to_channel: &str,
+    ) -> Result<bool> {
+        if !to_contact.is_empty() && !to_channel.is_empty() {
+            return Err(crate::ClientError::ConflictingParams {
+                params: vec!["to_contact".to_string(), "to_channel".to_string()],
+            });
+        }
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !to_channel.is_empty() {
+            query_args.push(("to_channel", to_channel.to_string()));
+        }
+        if !to_contact.is_empty() {
+            query_args.push(("to_contact", to_contact.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/chat/users/{}/messages/{}",
+                self.client.encode_path(user_id),
+                self.client.encode_path(message_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/chat/users/{userId}/messages/{messageId}",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("messageId", message_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// A request to [`ChatMessages::get_page`], see [`ChatMessages::get_page_request`].
+pub struct GetPageRequest<'a> {
+    api: &'a ChatMessages,
+    user_id: String,
+    to_contact: Option<String>,
+    to_channel: Option<String>,
+    date: chrono::NaiveDate,
+    page_size: Option<i64>,
+    next_page_token: Option<String>,
+    include_deleted_and_edited_message: Option<String>,
+}
+
+impl<'a> GetPageRequest<'a> {
+    /// Set `to_contact`.
+    pub fn to_contact(mut self, to_contact: &str) -> Self {
+        self.to_contact = Some(to_contact.to_string());
+        self
+    }
+
+    /// Set `to_channel`.
+    pub fn to_channel(mut self, to_channel: &str) -> Self {
+        self.to_channel = Some(to_channel.to_string());
+        self
+    }
+
+    /// Set `page_size`.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Set `next_page_token`.
+    pub fn next_page_token(mut self, next_page_token: &str) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    /// Set `include_deleted_and_edited_message`.
+    pub fn include_deleted_and_edited_message(
+        mut self,
+        include_deleted_and_edited_message: &str,
+    ) -> Self {
+        self.include_deleted_and_edited_message =
+            Some(include_deleted_and_edited_message.to_string());
+        self
+    }
+
+    /// Send the request.
+    pub async fn send(self) -> Result<Vec<crate::types::Messages>> {
+        self.api
+            .get_page(
+                &self.user_id,
+                self.to_contact.as_deref().unwrap_or_default(),
+                self.to_channel.as_deref().unwrap_or_default(),
+                self.date,
+                self.page_size.unwrap_or_default(),
+                self.next_page_token.as_deref().unwrap_or_default(),
+                self.include_deleted_and_edited_message
+                    .as_deref()
+                    .unwrap_or_default(),
+            )
+            .await
+    }
+}
+
+/// The functions of [`ChatMessages`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ChatMessagesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ChatMessagesApi: Send + Sync {
+    async fn get_page(
+        &self,
+        user_id: &str,
+        to_contact: &str,
+        to_channel: &str,
+        date: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+        include_deleted_and_edited_message: &str,
+    ) -> Result<Vec<crate::types::Messages>>;
+    async fn get_page_with_token(
+        &self,
+        user_id: &str,
+        to_contact: &str,
+        to_channel: &str,
+        date: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+        include_deleted_and_edited_message: &str,
+    ) -> Result<(Vec<crate::types::Messages>, String)>;
+    async fn get_all(
+        &self,
+        user_id: &str,
+        to_contact: &str,
+        to_channel: &str,
+        date: chrono::NaiveDate,
+        include_deleted_and_edited_message: &str,
+    ) -> Result<Vec<crate::types::Messages>>;
+    async fn senda(
+        &self,
+        user_id: &str,
+        body: &crate::types::SendaChatMessageRequest,
+    ) -> Result<crate::types::Groups>;
+    async fn mark_message(
+        &self,
+        user_id: &str,
+        message_id: &str,
+        body: &crate::types::MarkMessageRequest,
+    ) -> Result<()>;
+    async fn react_message(
+        &self,
+        user_id: &str,
+        message_id: &str,
+        body: &crate::types::ReactMessageRequest,
+    ) -> Result<()>;
+    async fn get(
+        &self,
+        user_id: &str,
+        message_id: &str,
+        to_contact: &str,
+        to_channel: &str,
+    ) -> Result<crate::types::GetChatMessageResponse>;
+    async fn edit_message(
+        &self,
+        user_id: &str,
+        message_id: &str,
+        body: &crate::types::EditMessageRequest,
+    ) -> Result<()>;
+    async fn delete(
+        &self,
+        user_id: &str,
+        message_id: &str,
+        to_contact: &str,
+        to_channel: &str,
+    ) -> Result<()>;
+    async fn delete_if_exists(
+        &self,
+        user_id: &str,
+        message_id: &str,
+        to_contact: &str,
+        to_channel: &str,
+    ) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ChatMessagesApi for ChatMessages {
+    async fn get_page(
+        &self,
+        user_id: &str,
+        to_contact: &str,
+        to_channel: &str,
+        date: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+        include_deleted_and_edited_message: &str,
+    ) -> Result<Vec<crate::types::Messages>> {
+        ChatMessages::get_page(
+            self,
+            user_id,
+            to_contact,
+            to_channel,
+            date,
+            page_size,
+            next_page_token,
+            include_deleted_and_edited_message,
+        )
+        .await
+    }
+    async fn get_page_with_token(
+        &self,
+        user_id: &str,
+        to_contact: &str,
+        to_channel: &str,
+        date: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+        include_deleted_and_edited_message: &str,
+    ) -> Result<(Vec<crate::types::Messages>, String)> {
+        ChatMessages::get_page_with_token(
+            self,
+            user_id,
+            to_contact,
+            to_channel,
+            date,
+            page_size,
+            next_page_token,
+            include_deleted_and_edited_message,
+        )
+        .await
+    }
+    async fn get_all(
+        &self,
+        user_id: &str,
+        to_contact: &str,
+        to_channel: &str,
+        date: chrono::NaiveDate,
+        include_deleted_and_edited_message: &str,
+    ) -> Result<Vec<crate::types::Messages>> {
+        ChatMessages::get_all(
+            self,
+            user_id,
+            to_contact,
+            to_channel,
+            date,
+            include_deleted_and_edited_message,
+        )
+        .await
+    }
+    async fn senda(
+        &self,
+        user_id: &str,
+        body: &crate::types::SendaChatMessageRequest,
+    ) -> Result<crate::types::Groups> {
+        ChatMessages::senda(self, user_id, body).await
+    }
+    async fn mark_message(
+        &self,
+        user_id: &str,
+        message_id: &str,
+        body: &crate::types::MarkMessageRequest,
+    ) -> Result<()> {
+        ChatMessages::mark_message(self, user_id, message_id, body).await
+    }
+    async fn react_message(
+        &self,
+        user_id: &str,
+        message_id: &str,
+        body: &crate::types::ReactMessageRequest,
+    ) -> Result<()> {
+        ChatMessages::react_message(self, user_id, message_id, body).await
+    }
+    async fn get(
+        &self,
+        user_id: &str,
+        message_id: &str,
+        to_contact: &str,
+        to_channel: &str,
+    ) -> Result<crate::types::GetChatMessageResponse> {
+        ChatMessages::get(self, user_id, message_id, to_contact, to_channel).await
+    }
+    async fn edit_message(
+        &self,
+        user_id: &str,
+        message_id: &str,
+        body: &crate::types::EditMessageRequest,
+    ) -> Result<()> {
+        ChatMessages::edit_message(self, user_id, message_id, body).await
+    }
+    async fn delete(
+        &self,
+        user_id: &str,
+        message_id: &str,
+        to_contact: &str,
+        to_channel: &str,
+    ) -> Result<()> {
+        ChatMessages::delete(self, user_id, message_id, to_contact, to_channel).await
+    }
+    async fn delete_if_exists(
+        &self,
+        user_id: &str,
+        message_id: &str,
+        to_contact: &str,
+        to_channel: &str,
+    ) -> Result<bool> {
+        ChatMessages::delete_if_exists(self, user_id, message_id, to_contact, to_channel).await
     }
 }
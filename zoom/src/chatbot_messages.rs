@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct ChatbotMessages {
     pub client: Client,
@@ -12,6 +10,17 @@ impl ChatbotMessages {
         ChatbotMessages { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        ChatbotMessages {
+            client: self.client.with_retry(policy),
+        }
+    }
+
+    /// The OAuth scopes for `sendchatbot`, any one of them grants access.
+    pub const SENDCHATBOT_SCOPES: &[&str] = &["imchat:bot"];
+
     /**
      * Send chatbot messages.
      *
@@ -28,10 +37,14 @@ impl ChatbotMessages {
     pub async fn sendchatbot(&self, body: &crate::types::SendchatbotRequest) -> Result<()> {
         let url = "/im/chat/messages".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/im/chat/messages", &[]))
     }
 
+    /// The OAuth scopes for `edit`, any one of them grants access.
+    pub const EDIT_SCOPES: &[&str] = &["imchat:bot"];
+
     /**
      * Edit a chatbot message.
      *
@@ -55,16 +68,23 @@ impl ChatbotMessages {
         message_id: &str,
         body: &crate::types::EditChatbotMessageRequest,
     ) -> Result<crate::types::EditChatbotMessageResponse> {
-        let url = format!(
-            "/im/chat/messages/{}",
-            crate::progenitor_support::encode_path(&message_id.to_string()),
-        );
+        let url = format!("/im/chat/messages/{}", self.client.encode_path(message_id),);
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/im/chat/messages/{message_id}",
+                    &[("message_id", message_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `delete`, any one of them grants access.
+    pub const DELETE_SCOPES: &[&str] = &["imchat:bot"];
+
     /**
      * Delete a chatbot message.
      *
@@ -79,13 +99,58 @@ impl ChatbotMessages {
         message_id: &str,
         body: &crate::types::DeleteChatbotMessageRequest,
     ) -> Result<crate::types::DeleteChatbotMessageResponse> {
-        let url = format!(
-            "/im/chat/messages/{}",
-            crate::progenitor_support::encode_path(&message_id.to_string()),
-        );
+        let url = format!("/im/chat/messages/{}", self.client.encode_path(message_id),);
 
         self.client
-            .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .delete(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/im/chat/messages/{message_id}",
+                    &[("message_id", message_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`ChatbotMessages`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ChatbotMessagesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ChatbotMessagesApi: Send + Sync {
+    async fn sendchatbot(&self, body: &crate::types::SendchatbotRequest) -> Result<()>;
+    async fn edit(
+        &self,
+        message_id: &str,
+        body: &crate::types::EditChatbotMessageRequest,
+    ) -> Result<crate::types::EditChatbotMessageResponse>;
+    async fn delete(
+        &self,
+        message_id: &str,
+        body: &crate::types::DeleteChatbotMessageRequest,
+    ) -> Result<crate::types::DeleteChatbotMessageResponse>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ChatbotMessagesApi for ChatbotMessages {
+    async fn sendchatbot(&self, body: &crate::types::SendchatbotRequest) -> Result<()> {
+        ChatbotMessages::sendchatbot(self, body).await
+    }
+    async fn edit(
+        &self,
+        message_id: &str,
+        body: &crate::types::EditChatbotMessageRequest,
+    ) -> Result<crate::types::EditChatbotMessageResponse> {
+        ChatbotMessages::edit(self, message_id, body).await
+    }
+    async fn delete(
+        &self,
+        message_id: &str,
+        body: &crate::types::DeleteChatbotMessageRequest,
+    ) -> Result<crate::types::DeleteChatbotMessageResponse> {
+        ChatbotMessages::delete(self, message_id, body).await
     }
 }
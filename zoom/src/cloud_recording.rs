@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct CloudRecording {
     pub client: Client,
@@ -12,6 +10,17 @@ impl CloudRecording {
         CloudRecording { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        CloudRecording {
+            client: self.client.with_retry(policy),
+        }
+    }
+
+    /// The OAuth scopes for `recordings_list`, any one of them grants access.
+    pub const RECORDINGS_LIST_SCOPES: &[&str] = &["recording:read:admin", "recording:read"];
+
     /**
      * List all recordings.
      *
@@ -55,38 +64,69 @@ impl CloudRecording {
         to: chrono::NaiveDate,
         trash_type: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !mc.is_empty() {
-            query_args.push(("mc".to_string(), mc.to_string()));
+            query_args.push(("mc", mc.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
         if trash {
-            query_args.push(("trash".to_string(), trash.to_string()));
+            query_args.push(("trash", trash.to_string()));
         }
         if !trash_type.is_empty() {
-            query_args.push(("trash_type".to_string(), trash_type.to_string()));
+            query_args.push(("trash_type", trash_type.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/recordings?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/users/{}/recordings", self.client.encode_path(user_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/users/{userId}/recordings",
+                &[("userId", user_id.to_string())],
+            )
+        })
     }
 
+    /**
+     * A request to `recordings_list`, to set its query parameters by name rather than by position.
+     * The ones left unset are left off the query.
+     */
+    pub fn recordings_list_request(
+        &self,
+        user_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> RecordingsListRequest<'_> {
+        RecordingsListRequest {
+            api: self,
+            user_id: user_id.to_string(),
+            page_size: None,
+            next_page_token: None,
+            mc: None,
+            trash: None,
+            from,
+            to,
+            trash_type: None,
+        }
+    }
+
+    /// The OAuth scopes for `recording_get`, any one of them grants access.
+    pub const RECORDING_GET_SCOPES: &[&str] = &["recording:read:admin", "recording:read"];
+
     /**
      * Get meeting recordings.
      *
@@ -114,23 +154,33 @@ impl CloudRecording {
         include_fields: &str,
         ttl: u64,
     ) -> Result<crate::types::RecordingGetResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !include_fields.is_empty() {
-            query_args.push(("include_fields".to_string(), include_fields.to_string()));
+            query_args.push(("include_fields", include_fields.to_string()));
         }
         if !ttl.to_string().is_empty() {
-            query_args.push(("ttl".to_string(), ttl.to_string()));
+            query_args.push(("ttl", ttl.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/meetings/{}/recordings?{}",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/meetings/{}/recordings",
+                self.client.encode_path(meeting_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/meetings/{meetingId}/recordings",
+                &[("meetingId", meeting_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `recording_delete`, any one of them grants access.
+    pub const RECORDING_DELETE_SCOPES: &[&str] = &["recording:write:admin", "recording:write"];
+
     /**
      * Delete meeting recordings.
      *
@@ -158,20 +208,66 @@ impl CloudRecording {
         meeting_id: &str,
         action: crate::types::RecordingDeleteAction,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !action.to_string().is_empty() {
-            query_args.push(("action".to_string(), action.to_string()));
+            query_args.push(("action", action.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/meetings/{}/recordings?{}",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/meetings/{}/recordings",
+                self.client.encode_path(meeting_id)
+            ),
+            &query_args,
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/meetings/{meetingId}/recordings",
+                &[("meetingId", meeting_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete meeting recordings.
+     *
+     * This function performs a `DELETE` to the `/meetings/{meetingId}/recordings` endpoint.
+     *
+     * As opposed to `recording_delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn recording_delete_if_exists(
+        &self,
+        meeting_id: &str,
+        action: crate::types::RecordingDeleteAction,
+    ) -> Result<bool> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !action.to_string().is_empty() {
+            query_args.push(("action", action.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/meetings/{}/recordings",
+                self.client.encode_path(meeting_id)
+            ),
+            &query_args,
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/meetings/{meetingId}/recordings",
+                    &[("meetingId", meeting_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `recording_delete_one`, any one of them grants access.
+    pub const RECORDING_DELETE_ONE_SCOPES: &[&str] = &["recording:write:admin", "recording:write"];
+
     /**
      * Delete a meeting recording file.
      *
@@ -197,21 +293,76 @@ impl CloudRecording {
         recording_id: &str,
         action: crate::types::RecordingDeleteAction,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !action.to_string().is_empty() {
-            query_args.push(("action".to_string(), action.to_string()));
+            query_args.push(("action", action.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/meetings/{}/recordings/{}?{}",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
-            crate::progenitor_support::encode_path(&recording_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/meetings/{}/recordings/{}",
+                self.client.encode_path(meeting_id),
+                self.client.encode_path(recording_id)
+            ),
+            &query_args,
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/meetings/{meetingId}/recordings/{recordingId}",
+                &[
+                    ("meetingId", meeting_id.to_string()),
+                    ("recordingId", recording_id.to_string()),
+                ],
+            )
+        })
     }
 
+    /**
+     * Delete a meeting recording file.
+     *
+     * This function performs a `DELETE` to the `/meetings/{meetingId}/recordings/{recordingId}` endpoint.
+     *
+     * As opposed to `recording_delete_one`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn recording_delete_one_if_exists(
+        &self,
+        meeting_id: &str,
+        recording_id: &str,
+        action: crate::types::RecordingDeleteAction,
+    ) -> Result<bool> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !action.to_string().is_empty() {
+            query_args.push(("action", action.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/meetings/{}/recordings/{}",
+                self.client.encode_path(meeting_id),
+                self.client.encode_path(recording_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/meetings/{meetingId}/recordings/{recordingId}",
+                    &[
+                        ("meetingId", meeting_id.to_string()),
+                        ("recordingId", recording_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The OAuth scopes for `recording_status_update`, any one of them grants access.
+    pub const RECORDING_STATUS_UPDATE_SCOPES: &[&str] =
+        &["recording:write:admin", "recording:write"];
+
     /**
      * Recover meeting recordings.
      *
@@ -239,14 +390,25 @@ impl CloudRecording {
     ) -> Result<()> {
         let url = format!(
             "/meetings/{}/recordings/status",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
+            self.client.encode_path(meeting_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/meetings/{meetingId}/recordings/status",
+                    &[("meetingId", meeting_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `recording_status_update_one`, any one of them grants access.
+    pub const RECORDING_STATUS_UPDATE_ONE_SCOPES: &[&str] =
+        &["recording:write:admin", "recording:write"];
+
     /**
      * Recover a single recording.
      *
@@ -275,15 +437,29 @@ impl CloudRecording {
     ) -> Result<()> {
         let url = format!(
             "/meetings/{}/recordings/{}/status",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
-            crate::progenitor_support::encode_path(&recording_id.to_string()),
+            self.client.encode_path(meeting_id),
+            self.client.encode_path(recording_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/meetings/{meetingId}/recordings/{recordingId}/status",
+                    &[
+                        ("meetingId", meeting_id.to_string()),
+                        ("recordingId", recording_id.to_string()),
+                    ],
+                )
+            })
     }
 
+    /// The OAuth scopes for `recording_setting_update`, any one of them grants access.
+    pub const RECORDING_SETTING_UPDATE_SCOPES: &[&str] =
+        &["recording:read:admin", "recording:read"];
+
     /**
      * Get meeting recording settings.
      *
@@ -308,12 +484,22 @@ impl CloudRecording {
     ) -> Result<crate::types::RecordingSettings> {
         let url = format!(
             "/meetings/{}/recordings/settings",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
+            self.client.encode_path(meeting_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/meetings/{meetingId}/recordings/settings",
+                &[("meetingId", meeting_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `recording_settings_update`, any one of them grants access.
+    pub const RECORDING_SETTINGS_UPDATE_SCOPES: &[&str] =
+        &["recording:write:admin", "recording:write"];
+
     /**
      * Update meeting recording settings.
      *
@@ -339,14 +525,55 @@ impl CloudRecording {
     ) -> Result<()> {
         let url = format!(
             "/meetings/{}/recordings/settings",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
+            self.client.encode_path(meeting_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/meetings/{meetingId}/recordings/settings",
+                    &[("meetingId", meeting_id.to_string())],
+                )
+            })
     }
 
+    /**
+     * Update meeting recording settings.
+     *
+     * This function performs a `PATCH` to the `/meetings/{meetingId}/recordings/settings` endpoint.
+     *
+     * As opposed to `recording_settings_update`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn recording_settings_update_if_changed<C: serde::Serialize>(
+        &self,
+        meeting_id: &str,
+        current: &C,
+        body: &crate::types::RecordingSettings,
+    ) -> Result<Option<()>> {
+        let url = format!(
+            "/meetings/{}/recordings/settings",
+            self.client.encode_path(meeting_id),
+        );
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/meetings/{meetingId}/recordings/settings",
+                    &[("meetingId", meeting_id.to_string())],
+                )
+            })
+    }
+
+    /// The OAuth scopes for `meeting_recording_registrant`, any one of them grants access.
+    pub const MEETING_RECORDING_REGISTRANT_SCOPES: &[&str] =
+        &["recording:read:admin", "recording:read"];
+
     /**
      * List recording registrants.
      *
@@ -381,29 +608,41 @@ impl CloudRecording {
         page_number: i64,
         next_page_token: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_number > 0 {
-            query_args.push(("page_number".to_string(), page_number.to_string()));
+            query_args.push(("page_number", page_number.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/meetings/{}/recordings/registrants?{}",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/meetings/{}/recordings/registrants",
+                self.client.encode_path(&meeting_id.to_string())
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/meetings/{meetingId}/recordings/registrants",
+                &[("meetingId", meeting_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `meeting_recording_registrant_create`, any one of them grants access.
+    pub const MEETING_RECORDING_REGISTRANT_CREATE_SCOPES: &[&str] =
+        &["recording:write:admin", "recording:write"];
+
     /**
      * Create a recording registrant.
      *
@@ -430,12 +669,22 @@ impl CloudRecording {
     ) -> Result<crate::types::MeetingRecordingRegistrantCreateResponse> {
         let url = format!(
             "/meetings/{}/recordings/registrants",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
+            self.client.encode_path(&meeting_id.to_string()),
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/meetings/{meetingId}/recordings/registrants",
+                &[("meetingId", meeting_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `meeting_recording_registrant_status`, any one of them grants access.
+    pub const MEETING_RECORDING_REGISTRANT_STATUS_SCOPES: &[&str] =
+        &["recording:write:admin", "recording:write"];
+
     /**
      * Update registrant's status.
      *
@@ -462,14 +711,25 @@ impl CloudRecording {
     ) -> Result<()> {
         let url = format!(
             "/meetings/{}/recordings/registrants/status",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
+            self.client.encode_path(&meeting_id.to_string()),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/meetings/{meetingId}/recordings/registrants/status",
+                    &[("meetingId", meeting_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `recording_registrants_questions_get`, any one of them grants access.
+    pub const RECORDING_REGISTRANTS_QUESTIONS_GET_SCOPES: &[&str] =
+        &["recording:read:admin", "recording:read"];
+
     /**
      * Get registration questions.
      *
@@ -497,12 +757,22 @@ impl CloudRecording {
     ) -> Result<crate::types::RecordingRegistrantQuestionsData> {
         let url = format!(
             "/meetings/{}/recordings/registrants/questions",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
+            self.client.encode_path(meeting_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/meetings/{meetingId}/recordings/registrants/questions",
+                &[("meetingId", meeting_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `recording_registrant_question_update`, any one of them grants access.
+    pub const RECORDING_REGISTRANT_QUESTION_UPDATE_SCOPES: &[&str] =
+        &["recording:write:admin", "recording:write"];
+
     /**
      * Update registration questions.
      *
@@ -530,14 +800,54 @@ impl CloudRecording {
     ) -> Result<()> {
         let url = format!(
             "/meetings/{}/recordings/registrants/questions",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
+            self.client.encode_path(meeting_id),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/meetings/{meetingId}/recordings/registrants/questions",
+                    &[("meetingId", meeting_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update registration questions.
+     *
+     * This function performs a `PATCH` to the `/meetings/{meetingId}/recordings/registrants/questions` endpoint.
+     *
+     * As opposed to `recording_registrant_question_update`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn recording_registrant_question_update_if_changed<C: serde::Serialize>(
+        &self,
+        meeting_id: &str,
+        current: &C,
+        body: &crate::types::RecordingRegistrantQuestionsData,
+    ) -> Result<Option<()>> {
+        let url = format!(
+            "/meetings/{}/recordings/registrants/questions",
+            self.client.encode_path(meeting_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/meetings/{meetingId}/recordings/registrants/questions",
+                    &[("meetingId", meeting_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `get_account`, any one of them grants access.
+    pub const GET_ACCOUNT_SCOPES: &[&str] = &["recording:read:admin", "account:read:admin"];
+
     /**
      * List recordings of an account.
      *
@@ -572,33 +882,94 @@ impl CloudRecording {
         from: Option<chrono::DateTime<chrono::Utc>>,
         to: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<Vec<crate::types::GetAccountCloudRecordingResponseMeetings>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if let Some(date) = from {
-            query_args.push(("from".to_string(), date.to_rfc3339()));
+            query_args.push(("from", date.to_rfc3339()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if let Some(date) = to {
-            query_args.push(("to".to_string(), date.to_rfc3339()));
+            query_args.push(("to", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/accounts/{}/recordings?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/accounts/{}/recordings",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
         );
 
         let resp: crate::types::GetAccountCloudRecordingResponse =
-            self.client.get(&url, None).await?;
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/accounts/{accountId}/recordings",
+                    &[("accountId", account_id.to_string())],
+                )
+            })?;
 
         // Return our response data.
         Ok(resp.meetings)
     }
 
+    /**
+     * List recordings of an account.
+     *
+     * This function performs a `GET` to the `/accounts/{accountId}/recordings` endpoint.
+     *
+     * As opposed to `get_account`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
+     */
+    pub async fn get_account_page(
+        &self,
+        account_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<(
+        Vec<crate::types::GetAccountCloudRecordingResponseMeetings>,
+        String,
+    )> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if let Some(date) = from {
+            query_args.push(("from", date.to_rfc3339()));
+        }
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        if let Some(date) = to {
+            query_args.push(("to", date.to_rfc3339()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/accounts/{}/recordings",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
+        );
+
+        let resp: crate::types::GetAccountCloudRecordingResponse =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/accounts/{accountId}/recordings",
+                    &[("accountId", account_id.to_string())],
+                )
+            })?;
+
+        // Return our response data, and where the next page starts.
+        Ok((resp.meetings, resp.next_page_token))
+    }
+
     /**
      * List recordings of an account.
      *
@@ -625,22 +996,29 @@ impl CloudRecording {
         from: Option<chrono::DateTime<chrono::Utc>>,
         to: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<Vec<crate::types::GetAccountCloudRecordingResponseMeetings>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if let Some(date) = from {
-            query_args.push(("from".to_string(), date.to_rfc3339()));
+            query_args.push(("from", date.to_rfc3339()));
         }
         if let Some(date) = to {
-            query_args.push(("to".to_string(), date.to_rfc3339()));
+            query_args.push(("to", date.to_rfc3339()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/accounts/{}/recordings?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/accounts/{}/recordings",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
         );
 
         let mut resp: crate::types::GetAccountCloudRecordingResponse =
-            self.client.get(&url, None).await?;
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/accounts/{accountId}/recordings",
+                    &[("accountId", account_id.to_string())],
+                )
+            })?;
 
         let mut meetings = resp.meetings;
         let mut page = resp.next_page_token;
@@ -672,4 +1050,457 @@ impl CloudRecording {
         // Return our response data.
         Ok(meetings)
     }
+
+    /**
+     * List recordings of an account.
+     *
+     * This function performs a `GET` to the `/accounts/{accountId}/recordings` endpoint.
+     *
+     * As opposed to `get_account`, this function streams the items of all the pages, getting the next page only once the items of the last one are used up. The stream ends after the last page.
+     */
+    pub async fn get_account_stream(
+        &self,
+        account_id: &str,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<
+        impl futures::Stream<Item = Result<crate::types::GetAccountCloudRecordingResponseMeetings>>,
+    > {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if let Some(date) = from {
+            query_args.push(("from", date.to_rfc3339()));
+        }
+        if let Some(date) = to {
+            query_args.push(("to", date.to_rfc3339()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/accounts/{}/recordings",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::GetAccountCloudRecordingResponse| {
+                    (resp.meetings, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/accounts/{accountId}/recordings",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * List recordings of an account.
+     *
+     * This function performs a `GET` to the `/accounts/{accountId}/recordings` endpoint.
+     *
+     * As opposed to `get_account_stream`, this function streams only the items `predicate` keeps. It too gets the next page only once the items of the last one are used up, so a stream that is cut short, for example with `take`, gets no more pages than it needs.
+     */
+    pub async fn get_account_filter<
+        P: FnMut(&crate::types::GetAccountCloudRecordingResponseMeetings) -> bool,
+    >(
+        &self,
+        account_id: &str,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+        mut predicate: P,
+    ) -> Result<
+        impl futures::Stream<Item = Result<crate::types::GetAccountCloudRecordingResponseMeetings>>,
+    > {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if let Some(date) = from {
+            query_args.push(("from", date.to_rfc3339()));
+        }
+        if let Some(date) = to {
+            query_args.push(("to", date.to_rfc3339()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/accounts/{}/recordings",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
+        );
+
+        let items = self
+            .client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::GetAccountCloudRecordingResponse| {
+                    (resp.meetings, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/accounts/{accountId}/recordings",
+                    &[("accountId", account_id.to_string())],
+                )
+            })?;
+        Ok(futures::TryStreamExt::try_filter(items, move |item| {
+            futures::future::ready(predicate(item))
+        }))
+    }
+}
+
+/// A request to [`CloudRecording::recordings_list`], see [`CloudRecording::recordings_list_request`].
+pub struct RecordingsListRequest<'a> {
+    api: &'a CloudRecording,
+    user_id: String,
+    page_size: Option<i64>,
+    next_page_token: Option<String>,
+    mc: Option<String>,
+    trash: Option<bool>,
+    from: chrono::NaiveDate,
+    to: chrono::NaiveDate,
+    trash_type: Option<String>,
+}
+
+impl<'a> RecordingsListRequest<'a> {
+    /// Set `page_size`.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Set `next_page_token`.
+    pub fn next_page_token(mut self, next_page_token: &str) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    /// Set `mc`.
+    pub fn mc(mut self, mc: &str) -> Self {
+        self.mc = Some(mc.to_string());
+        self
+    }
+
+    /// Set `trash`.
+    pub fn trash(mut self, trash: bool) -> Self {
+        self.trash = Some(trash);
+        self
+    }
+
+    /// Set `trash_type`.
+    pub fn trash_type(mut self, trash_type: &str) -> Self {
+        self.trash_type = Some(trash_type.to_string());
+        self
+    }
+
+    /// Send the request.
+    pub async fn send(self) -> Result<crate::types::Domains> {
+        self.api
+            .recordings_list(
+                &self.user_id,
+                self.page_size.unwrap_or_default(),
+                self.next_page_token.as_deref().unwrap_or_default(),
+                self.mc.as_deref().unwrap_or_default(),
+                self.trash.unwrap_or_default(),
+                self.from,
+                self.to,
+                self.trash_type.as_deref().unwrap_or_default(),
+            )
+            .await
+    }
+}
+
+/// The functions of [`CloudRecording`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn CloudRecordingApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait CloudRecordingApi: Send + Sync {
+    async fn recordings_list(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        mc: &str,
+        trash: bool,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        trash_type: &str,
+    ) -> Result<crate::types::Domains>;
+    async fn recording_get(
+        &self,
+        meeting_id: &str,
+        include_fields: &str,
+        ttl: u64,
+    ) -> Result<crate::types::RecordingGetResponseAllOf>;
+    async fn recording_delete(
+        &self,
+        meeting_id: &str,
+        action: crate::types::RecordingDeleteAction,
+    ) -> Result<()>;
+    async fn recording_delete_if_exists(
+        &self,
+        meeting_id: &str,
+        action: crate::types::RecordingDeleteAction,
+    ) -> Result<bool>;
+    async fn recording_delete_one(
+        &self,
+        meeting_id: &str,
+        recording_id: &str,
+        action: crate::types::RecordingDeleteAction,
+    ) -> Result<()>;
+    async fn recording_delete_one_if_exists(
+        &self,
+        meeting_id: &str,
+        recording_id: &str,
+        action: crate::types::RecordingDeleteAction,
+    ) -> Result<bool>;
+    async fn recording_status_update(
+        &self,
+        meeting_id: &str,
+        body: &crate::types::RecordingStatusUpdateBodyRequest,
+    ) -> Result<()>;
+    async fn recording_status_update_one(
+        &self,
+        meeting_id: &str,
+        recording_id: &str,
+        body: &crate::types::RecordingStatusUpdateBodyRequest,
+    ) -> Result<()>;
+    async fn recording_setting_update(
+        &self,
+        meeting_id: &str,
+    ) -> Result<crate::types::RecordingSettings>;
+    async fn recording_settings_update(
+        &self,
+        meeting_id: &str,
+        body: &crate::types::RecordingSettings,
+    ) -> Result<()>;
+    async fn meeting_recording_registrant(
+        &self,
+        meeting_id: i64,
+        status: crate::types::MeetingRegistrantsStatus,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::Domains>;
+    async fn meeting_recording_registrant_create(
+        &self,
+        meeting_id: i64,
+    ) -> Result<crate::types::MeetingRecordingRegistrantCreateResponse>;
+    async fn meeting_recording_registrant_status(
+        &self,
+        meeting_id: i64,
+        body: &crate::types::RecordingRegistrantStatus,
+    ) -> Result<()>;
+    async fn recording_registrants_questions_get(
+        &self,
+        meeting_id: &str,
+    ) -> Result<crate::types::RecordingRegistrantQuestionsData>;
+    async fn recording_registrant_question_update(
+        &self,
+        meeting_id: &str,
+        body: &crate::types::RecordingRegistrantQuestionsData,
+    ) -> Result<()>;
+    async fn get_account(
+        &self,
+        account_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<crate::types::GetAccountCloudRecordingResponseMeetings>>;
+    async fn get_account_page(
+        &self,
+        account_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<(
+        Vec<crate::types::GetAccountCloudRecordingResponseMeetings>,
+        String,
+    )>;
+    async fn get_all_account(
+        &self,
+        account_id: &str,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<crate::types::GetAccountCloudRecordingResponseMeetings>>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl CloudRecordingApi for CloudRecording {
+    async fn recordings_list(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        mc: &str,
+        trash: bool,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        trash_type: &str,
+    ) -> Result<crate::types::Domains> {
+        CloudRecording::recordings_list(
+            self,
+            user_id,
+            page_size,
+            next_page_token,
+            mc,
+            trash,
+            from,
+            to,
+            trash_type,
+        )
+        .await
+    }
+    async fn recording_get(
+        &self,
+        meeting_id: &str,
+        include_fields: &str,
+        ttl: u64,
+    ) -> Result<crate::types::RecordingGetResponseAllOf> {
+        CloudRecording::recording_get(self, meeting_id, include_fields, ttl).await
+    }
+    async fn recording_delete(
+        &self,
+        meeting_id: &str,
+        action: crate::types::RecordingDeleteAction,
+    ) -> Result<()> {
+        CloudRecording::recording_delete(self, meeting_id, action).await
+    }
+    async fn recording_delete_if_exists(
+        &self,
+        meeting_id: &str,
+        action: crate::types::RecordingDeleteAction,
+    ) -> Result<bool> {
+        CloudRecording::recording_delete_if_exists(self, meeting_id, action).await
+    }
+    async fn recording_delete_one(
+        &self,
+        meeting_id: &str,
+        recording_id: &str,
+        action: crate::types::RecordingDeleteAction,
+    ) -> Result<()> {
+        CloudRecording::recording_delete_one(self, meeting_id, recording_id, action).await
+    }
+    async fn recording_delete_one_if_exists(
+        &self,
+        meeting_id: &str,
+        recording_id: &str,
+        action: crate::types::RecordingDeleteAction,
+    ) -> Result<bool> {
+        CloudRecording::recording_delete_one_if_exists(self, meeting_id, recording_id, action).await
+    }
+    async fn recording_status_update(
+        &self,
+        meeting_id: &str,
+        body: &crate::types::RecordingStatusUpdateBodyRequest,
+    ) -> Result<()> {
+        CloudRecording::recording_status_update(self, meeting_id, body).await
+    }
+    async fn recording_status_update_one(
+        &self,
+        meeting_id: &str,
+        recording_id: &str,
+        body: &crate::types::RecordingStatusUpdateBodyRequest,
+    ) -> Result<()> {
+        CloudRecording::recording_status_update_one(self, meeting_id, recording_id, body).await
+    }
+    async fn recording_setting_update(
+        &self,
+        meeting_id: &str,
+    ) -> Result<crate::types::RecordingSettings> {
+        CloudRecording::recording_setting_update(self, meeting_id).await
+    }
+    async fn recording_settings_update(
+        &self,
+        meeting_id: &str,
+        body: &crate::types::RecordingSettings,
+    ) -> Result<()> {
+        CloudRecording::recording_settings_update(self, meeting_id, body).await
+    }
+    async fn meeting_recording_registrant(
+        &self,
+        meeting_id: i64,
+        status: crate::types::MeetingRegistrantsStatus,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::Domains> {
+        CloudRecording::meeting_recording_registrant(
+            self,
+            meeting_id,
+            status,
+            page_size,
+            page_number,
+            next_page_token,
+        )
+        .await
+    }
+    async fn meeting_recording_registrant_create(
+        &self,
+        meeting_id: i64,
+    ) -> Result<crate::types::MeetingRecordingRegistrantCreateResponse> {
+        CloudRecording::meeting_recording_registrant_create(self, meeting_id).await
+    }
+    async fn meeting_recording_registrant_status(
+        &self,
+        meeting_id: i64,
+        body: &crate::types::RecordingRegistrantStatus,
+    ) -> Result<()> {
+        CloudRecording::meeting_recording_registrant_status(self, meeting_id, body).await
+    }
+    async fn recording_registrants_questions_get(
+        &self,
+        meeting_id: &str,
+    ) -> Result<crate::types::RecordingRegistrantQuestionsData> {
+        CloudRecording::recording_registrants_questions_get(self, meeting_id).await
+    }
+    async fn recording_registrant_question_update(
+        &self,
+        meeting_id: &str,
+        body: &crate::types::RecordingRegistrantQuestionsData,
+    ) -> Result<()> {
+        CloudRecording::recording_registrant_question_update(self, meeting_id, body).await
+    }
+    async fn get_account(
+        &self,
+        account_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<crate::types::GetAccountCloudRecordingResponseMeetings>> {
+        CloudRecording::get_account(self, account_id, page_size, next_page_token, from, to).await
+    }
+    async fn get_account_page(
+        &self,
+        account_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<(
+        Vec<crate::types::GetAccountCloudRecordingResponseMeetings>,
+        String,
+    )> {
+        CloudRecording::get_account_page(self, account_id, page_size, next_page_token, from, to)
+            .await
+    }
+    async fn get_all_account(
+        &self,
+        account_id: &str,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<crate::types::GetAccountCloudRecordingResponseMeetings>> {
+        CloudRecording::get_all_account(self, account_id, from, to).await
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct CommonAreaPhones {
     pub client: Client,
@@ -12,6 +10,17 @@ impl CommonAreaPhones {
         CommonAreaPhones { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        CommonAreaPhones {
+            client: self.client.with_retry(policy),
+        }
+    }
+
+    /// The OAuth scopes for `list`, any one of them grants access.
+    pub const LIST_SCOPES: &[&str] = &["phone:read:admin"];
+
     /**
      * List common area phones.
      *
@@ -39,22 +48,56 @@ impl CommonAreaPhones {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<Vec<crate::types::CommonAreaPhones>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/common_area_phones?{}", query_);
+        let url = crate::Client::url_with_query("/phone/common_area_phones", &query_args);
 
-        let resp: crate::types::ListCommonAreaPhonesResponse = self.client.get(&url, None).await?;
+        let resp: crate::types::ListCommonAreaPhonesResponse =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url("GET", "/phone/common_area_phones", &[])
+            })?;
 
         // Return our response data.
         Ok(resp.common_area_phones)
     }
 
+    /**
+     * List common area phones.
+     *
+     * This function performs a `GET` to the `/phone/common_area_phones` endpoint.
+     *
+     * As opposed to `list`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
+     */
+    pub async fn list_page(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::CommonAreaPhones>, String)> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/common_area_phones", &query_args);
+
+        let resp: crate::types::ListCommonAreaPhonesResponse =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url("GET", "/phone/common_area_phones", &[])
+            })?;
+
+        // Return our response data, and where the next page starts.
+        Ok((resp.common_area_phones, resp.next_page_token))
+    }
+
     /**
      * List common area phones.
      *
@@ -77,7 +120,9 @@ impl CommonAreaPhones {
     pub async fn list_all(&self) -> Result<Vec<crate::types::CommonAreaPhones>> {
         let url = "/phone/common_area_phones".to_string();
         let mut resp: crate::types::ListCommonAreaPhonesResponse =
-            self.client.get(&url, None).await?;
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url("GET", "/phone/common_area_phones", &[])
+            })?;
 
         let mut common_area_phones = resp.common_area_phones;
         let mut page = resp.next_page_token;
@@ -110,6 +155,60 @@ impl CommonAreaPhones {
         Ok(common_area_phones)
     }
 
+    /**
+     * List common area phones.
+     *
+     * This function performs a `GET` to the `/phone/common_area_phones` endpoint.
+     *
+     * As opposed to `list`, this function streams the items of all the pages, getting the next page only once the items of the last one are used up. The stream ends after the last page.
+     */
+    pub async fn list_stream(
+        &self,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::CommonAreaPhones>>> {
+        let url = "/phone/common_area_phones".to_string();
+        self.client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListCommonAreaPhonesResponse| {
+                    (resp.common_area_phones, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/common_area_phones", &[]))
+    }
+
+    /**
+     * List common area phones.
+     *
+     * This function performs a `GET` to the `/phone/common_area_phones` endpoint.
+     *
+     * As opposed to `list_stream`, this function streams only the items `predicate` keeps. It too gets the next page only once the items of the last one are used up, so a stream that is cut short, for example with `take`, gets no more pages than it needs.
+     */
+    pub async fn list_filter<P: FnMut(&crate::types::CommonAreaPhones) -> bool>(
+        &self,
+        mut predicate: P,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::CommonAreaPhones>>> {
+        let url = "/phone/common_area_phones".to_string();
+        let items = self
+            .client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListCommonAreaPhonesResponse| {
+                    (resp.common_area_phones, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/common_area_phones", &[]))?;
+        Ok(futures::TryStreamExt::try_filter(items, move |item| {
+            futures::future::ready(predicate(item))
+        }))
+    }
+
+    /// The OAuth scopes for `add`, any one of them grants access.
+    pub const ADD_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Add a common area phone.
      *
@@ -133,10 +232,14 @@ impl CommonAreaPhones {
     ) -> Result<crate::types::AddCommonAreaPhoneResponse> {
         let url = "/phone/common_area_phones".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/phone/common_area_phones", &[]))
     }
 
+    /// The OAuth scopes for `get`, any one of them grants access.
+    pub const GET_SCOPES: &[&str] = &["phone:read:admin"];
+
     /**
      * Get common area phone details.
      *
@@ -163,12 +266,21 @@ impl CommonAreaPhones {
     ) -> Result<crate::types::GetCommonAreaPhoneResponse> {
         let url = format!(
             "/phone/common_area_phones/{}",
-            crate::progenitor_support::encode_path(&common_area_phone_id.to_string()),
+            self.client.encode_path(common_area_phone_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/phone/common_area_phones/{commonAreaPhoneId}",
+                &[("commonAreaPhoneId", common_area_phone_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `delete`, any one of them grants access.
+    pub const DELETE_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Delete a common area phone.
      *
@@ -190,12 +302,46 @@ impl CommonAreaPhones {
     pub async fn delete(&self, common_area_phone_id: &str) -> Result<()> {
         let url = format!(
             "/phone/common_area_phones/{}",
-            crate::progenitor_support::encode_path(&common_area_phone_id.to_string()),
+            self.client.encode_path(common_area_phone_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/phone/common_area_phones/{commonAreaPhoneId}",
+                &[("commonAreaPhoneId", common_area_phone_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete a common area phone.
+     *
+     * This function performs a `DELETE` to the `/phone/common_area_phones/{commonAreaPhoneId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, common_area_phone_id: &str) -> Result<bool> {
+        let url = format!(
+            "/phone/common_area_phones/{}",
+            self.client.encode_path(common_area_phone_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/phone/common_area_phones/{commonAreaPhoneId}",
+                    &[("commonAreaPhoneId", common_area_phone_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `update`, any one of them grants access.
+    pub const UPDATE_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Update common area phone.
      *
@@ -220,14 +366,54 @@ impl CommonAreaPhones {
     ) -> Result<()> {
         let url = format!(
             "/phone/common_area_phones/{}",
-            crate::progenitor_support::encode_path(&common_area_phone_id.to_string()),
+            self.client.encode_path(common_area_phone_id),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/phone/common_area_phones/{commonAreaPhoneId}",
+                    &[("commonAreaPhoneId", common_area_phone_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update common area phone.
+     *
+     * This function performs a `PATCH` to the `/phone/common_area_phones/{commonAreaPhoneId}` endpoint.
+     *
+     * As opposed to `update`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn update_if_changed<C: serde::Serialize>(
+        &self,
+        common_area_phone_id: &str,
+        current: &C,
+        body: &crate::types::UpdateCommonAreaPhoneRequest,
+    ) -> Result<Option<()>> {
+        let url = format!(
+            "/phone/common_area_phones/{}",
+            self.client.encode_path(common_area_phone_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/phone/common_area_phones/{commonAreaPhoneId}",
+                    &[("commonAreaPhoneId", common_area_phone_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `assign_phone_numbers_common_area`, any one of them grants access.
+    pub const ASSIGN_PHONE_NUMBERS_COMMON_AREA_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Assign phone numbers to common area phone.
      *
@@ -249,14 +435,24 @@ impl CommonAreaPhones {
     ) -> Result<crate::types::AssignPhoneNumbersCommonAreaResponseData> {
         let url = format!(
             "/phone/common_area_phones/{}/phone_numbers",
-            crate::progenitor_support::encode_path(&common_area_phone_id.to_string()),
+            self.client.encode_path(common_area_phone_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/phone/common_area_phones/{commonAreaPhoneId}/phone_numbers",
+                    &[("commonAreaPhoneId", common_area_phone_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `unassign_phone_numbers_from_common_area`, any one of them grants access.
+    pub const UNASSIGN_PHONE_NUMBERS_FROM_COMMON_AREA_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Unassign phone numbers from a common area phone.
      *
@@ -282,13 +478,58 @@ impl CommonAreaPhones {
     ) -> Result<()> {
         let url = format!(
             "/phone/common_area_phones/{}/phone_numbers/{}",
-            crate::progenitor_support::encode_path(&common_area_phone_id.to_string()),
-            crate::progenitor_support::encode_path(&phone_number_id.to_string()),
+            self.client.encode_path(common_area_phone_id),
+            self.client.encode_path(phone_number_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/phone/common_area_phones/{commonAreaPhoneId}/phone_numbers/{phoneNumberId}",
+                &[
+                    ("commonAreaPhoneId", common_area_phone_id.to_string()),
+                    ("phoneNumberId", phone_number_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Unassign phone numbers from a common area phone.
+     *
+     * This function performs a `DELETE` to the `/phone/common_area_phones/{commonAreaPhoneId}/phone_numbers/{phoneNumberId}` endpoint.
+     *
+     * As opposed to `unassign_phone_numbers_from_common_area`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn unassign_phone_numbers_from_common_area_if_exists(
+        &self,
+        common_area_phone_id: &str,
+        phone_number_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/phone/common_area_phones/{}/phone_numbers/{}",
+            self.client.encode_path(common_area_phone_id),
+            self.client.encode_path(phone_number_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/phone/common_area_phones/{commonAreaPhoneId}/phone_numbers/{phoneNumberId}",
+                    &[
+                        ("commonAreaPhoneId", common_area_phone_id.to_string()),
+                        ("phoneNumberId", phone_number_id.to_string()),
+                    ],
+                )
+            })
     }
 
+    /// The OAuth scopes for `assign_calling_plans`, any one of them grants access.
+    pub const ASSIGN_CALLING_PLANS_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Assign calling plans to common area phone.
      *
@@ -310,14 +551,24 @@ impl CommonAreaPhones {
     ) -> Result<crate::types::AssignCallingPlansCommonAreaPhoneResponseData> {
         let url = format!(
             "/phone/common_area_phones/{}/calling_plans",
-            crate::progenitor_support::encode_path(&common_area_phone_id.to_string()),
+            self.client.encode_path(common_area_phone_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/phone/common_area_phones/{commonAreaPhoneId}/calling_plans",
+                    &[("commonAreaPhoneId", common_area_phone_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `unassign_calling_plans_from`, any one of them grants access.
+    pub const UNASSIGN_CALLING_PLANS_FROM_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Unassign calling plan from a common area phone.
      *
@@ -343,10 +594,216 @@ impl CommonAreaPhones {
     ) -> Result<()> {
         let url = format!(
             "/phone/common_area_phones/{}/calling_plans/{}",
-            crate::progenitor_support::encode_path(&common_area_phone_id.to_string()),
-            crate::progenitor_support::encode_path(&type_.to_string()),
+            self.client.encode_path(common_area_phone_id),
+            self.client.encode_path(type_),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/phone/common_area_phones/{commonAreaPhoneId}/calling_plans/{type}",
+                &[
+                    ("commonAreaPhoneId", common_area_phone_id.to_string()),
+                    ("type", type_.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Unassign calling plan from a common area phone.
+     *
+     * This function performs a `DELETE` to the `/phone/common_area_phones/{commonAreaPhoneId}/calling_plans/{type}` endpoint.
+     *
+     * As opposed to `unassign_calling_plans_from`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn unassign_calling_plans_from_if_exists(
+        &self,
+        common_area_phone_id: &str,
+        type_: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/phone/common_area_phones/{}/calling_plans/{}",
+            self.client.encode_path(common_area_phone_id),
+            self.client.encode_path(type_),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/phone/common_area_phones/{commonAreaPhoneId}/calling_plans/{type}",
+                    &[
+                        ("commonAreaPhoneId", common_area_phone_id.to_string()),
+                        ("type", type_.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`CommonAreaPhones`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn CommonAreaPhonesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait CommonAreaPhonesApi: Send + Sync {
+    async fn list(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::CommonAreaPhones>>;
+    async fn list_page(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::CommonAreaPhones>, String)>;
+    async fn list_all(&self) -> Result<Vec<crate::types::CommonAreaPhones>>;
+    async fn add(
+        &self,
+        body: &crate::types::AddCommonAreaPhoneRequest,
+    ) -> Result<crate::types::AddCommonAreaPhoneResponse>;
+    async fn get(
+        &self,
+        common_area_phone_id: &str,
+    ) -> Result<crate::types::GetCommonAreaPhoneResponse>;
+    async fn delete(&self, common_area_phone_id: &str) -> Result<()>;
+    async fn delete_if_exists(&self, common_area_phone_id: &str) -> Result<bool>;
+    async fn update(
+        &self,
+        common_area_phone_id: &str,
+        body: &crate::types::UpdateCommonAreaPhoneRequest,
+    ) -> Result<()>;
+    async fn assign_phone_numbers_common_area(
+        &self,
+        common_area_phone_id: &str,
+        body: &crate::types::AssignPhoneNumbersCommonAreaRequest,
+    ) -> Result<crate::types::AssignPhoneNumbersCommonAreaResponseData>;
+    async fn unassign_phone_numbers_from_common_area(
+        &self,
+        common_area_phone_id: &str,
+        phone_number_id: &str,
+    ) -> Result<()>;
+    async fn unassign_phone_numbers_from_common_area_if_exists(
+        &self,
+        common_area_phone_id: &str,
+        phone_number_id: &str,
+    ) -> Result<bool>;
+    async fn assign_calling_plans(
+        &self,
+        common_area_phone_id: &str,
+        body: &crate::types::AssignCallingPlansCommonAreaPhoneRequestData,
+    ) -> Result<crate::types::AssignCallingPlansCommonAreaPhoneResponseData>;
+    async fn unassign_calling_plans_from(
+        &self,
+        common_area_phone_id: &str,
+        type_: &str,
+    ) -> Result<()>;
+    async fn unassign_calling_plans_from_if_exists(
+        &self,
+        common_area_phone_id: &str,
+        type_: &str,
+    ) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl CommonAreaPhonesApi for CommonAreaPhones {
+    async fn list(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::CommonAreaPhones>> {
+        CommonAreaPhones::list(self, page_size, next_page_token).await
+    }
+    async fn list_page(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::CommonAreaPhones>, String)> {
+        CommonAreaPhones::list_page(self, page_size, next_page_token).await
+    }
+    async fn list_all(&self) -> Result<Vec<crate::types::CommonAreaPhones>> {
+        CommonAreaPhones::list_all(self).await
+    }
+    async fn add(
+        &self,
+        body: &crate::types::AddCommonAreaPhoneRequest,
+    ) -> Result<crate::types::AddCommonAreaPhoneResponse> {
+        CommonAreaPhones::add(self, body).await
+    }
+    async fn get(
+        &self,
+        common_area_phone_id: &str,
+    ) -> Result<crate::types::GetCommonAreaPhoneResponse> {
+        CommonAreaPhones::get(self, common_area_phone_id).await
+    }
+    async fn delete(&self, common_area_phone_id: &str) -> Result<()> {
+        CommonAreaPhones::delete(self, common_area_phone_id).await
+    }
+    async fn delete_if_exists(&self, common_area_phone_id: &str) -> Result<bool> {
+        CommonAreaPhones::delete_if_exists(self, common_area_phone_id).await
+    }
+    async fn update(
+        &self,
+        common_area_phone_id: &str,
+        body: &crate::types::UpdateCommonAreaPhoneRequest,
+    ) -> Result<()> {
+        CommonAreaPhones::update(self, common_area_phone_id, body).await
+    }
+    async fn assign_phone_numbers_common_area(
+        &self,
+        common_area_phone_id: &str,
+        body: &crate::types::AssignPhoneNumbersCommonAreaRequest,
+    ) -> Result<crate::types::AssignPhoneNumbersCommonAreaResponseData> {
+        CommonAreaPhones::assign_phone_numbers_common_area(self, common_area_phone_id, body).await
+    }
+    async fn unassign_phone_numbers_from_common_area(
+        &self,
+        common_area_phone_id: &str,
+        phone_number_id: &str,
+    ) -> Result<()> {
+        CommonAreaPhones::unassign_phone_numbers_from_common_area(
+            self,
+            common_area_phone_id,
+            phone_number_id,
+        )
+        .await
+    }
+    async fn unassign_phone_numbers_from_common_area_if_exists(
+        &self,
+        common_area_phone_id: &str,
+        phone_number_id: &str,
+    ) -> Result<bool> {
+        CommonAreaPhones::unassign_phone_numbers_from_common_area_if_exists(
+            self,
+            common_area_phone_id,
+            phone_number_id,
+        )
+        .await
+    }
+    async fn assign_calling_plans(
+        &self,
+        common_area_phone_id: &str,
+        body: &crate::types::AssignCallingPlansCommonAreaPhoneRequestData,
+    ) -> Result<crate::types::AssignCallingPlansCommonAreaPhoneResponseData> {
+        CommonAreaPhones::assign_calling_plans(self, common_area_phone_id, body).await
+    }
+    async fn unassign_calling_plans_from(
+        &self,
+        common_area_phone_id: &str,
+        type_: &str,
+    ) -> Result<()> {
+        CommonAreaPhones::unassign_calling_plans_from(self, common_area_phone_id, type_).await
+    }
+    async fn unassign_calling_plans_from_if_exists(
+        &self,
+        common_area_phone_id: &str,
+        type_: &str,
+    ) -> Result<bool> {
+        CommonAreaPhones::unassign_calling_plans_from_if_exists(self, common_area_phone_id, type_)
+            .await
     }
 }
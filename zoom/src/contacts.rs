@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Contacts {
     pub client: Client,
@@ -12,6 +10,17 @@ impl Contacts {
         Contacts { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Contacts {
+            client: self.client.with_retry(policy),
+        }
+    }
+
+    /// The OAuth scopes for `search_company`, any one of them grants access.
+    pub const SEARCH_COMPANY_SCOPES: &[&str] = &["contact:read:admin", "contact:read"];
+
     /**
      * Search company contacts.
      *
@@ -37,31 +46,72 @@ impl Contacts {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<Vec<crate::types::Contacts>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !query_presence_status.is_empty() {
-            query_args.push((
-                "query_presence_status".to_string(),
-                query_presence_status.to_string(),
-            ));
+            query_args.push(("query_presence_status", query_presence_status.to_string()));
         }
         if !search_key.is_empty() {
-            query_args.push(("search_key".to_string(), search_key.to_string()));
+            query_args.push(("search_key", search_key.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/contacts?{}", query_);
+        let url = crate::Client::url_with_query("/contacts", &query_args);
 
-        let resp: crate::types::SearchCompanyContactsResponse = self.client.get(&url, None).await?;
+        let resp: crate::types::SearchCompanyContactsResponse =
+            self.client
+                .get(&url, None)
+                .await
+                .with_context(|| crate::utils::pretty_url("GET", "/contacts", &[]))?;
 
         // Return our response data.
         Ok(resp.contacts)
     }
 
+    /**
+     * Search company contacts.
+     *
+     * This function performs a `GET` to the `/contacts` endpoint.
+     *
+     * As opposed to `search_company`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
+     */
+    pub async fn search_company_page(
+        &self,
+        search_key: &str,
+        query_presence_status: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::Contacts>, String)> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        if !query_presence_status.is_empty() {
+            query_args.push(("query_presence_status", query_presence_status.to_string()));
+        }
+        if !search_key.is_empty() {
+            query_args.push(("search_key", search_key.to_string()));
+        }
+        let url = crate::Client::url_with_query("/contacts", &query_args);
+
+        let resp: crate::types::SearchCompanyContactsResponse =
+            self.client
+                .get(&url, None)
+                .await
+                .with_context(|| crate::utils::pretty_url("GET", "/contacts", &[]))?;
+
+        // Return our response data, and where the next page starts.
+        Ok((resp.contacts, resp.next_page_token))
+    }
+
     /**
      * Search company contacts.
      *
@@ -80,21 +130,20 @@ impl Contacts {
         search_key: &str,
         query_presence_status: &str,
     ) -> Result<Vec<crate::types::Contacts>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !query_presence_status.is_empty() {
-            query_args.push((
-                "query_presence_status".to_string(),
-                query_presence_status.to_string(),
-            ));
+            query_args.push(("query_presence_status", query_presence_status.to_string()));
         }
         if !search_key.is_empty() {
-            query_args.push(("search_key".to_string(), search_key.to_string()));
+            query_args.push(("search_key", search_key.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/contacts?{}", query_);
+        let url = crate::Client::url_with_query("/contacts", &query_args);
 
-        let mut resp: crate::types::SearchCompanyContactsResponse =
-            self.client.get(&url, None).await?;
+        let mut resp: crate::types::SearchCompanyContactsResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/contacts", &[]))?;
 
         let mut contacts = resp.contacts;
         let mut page = resp.next_page_token;
@@ -127,6 +176,80 @@ impl Contacts {
         Ok(contacts)
     }
 
+    /**
+     * Search company contacts.
+     *
+     * This function performs a `GET` to the `/contacts` endpoint.
+     *
+     * As opposed to `search_company`, this function streams the items of all the pages, getting the next page only once the items of the last one are used up. The stream ends after the last page.
+     */
+    pub async fn search_company_stream(
+        &self,
+        search_key: &str,
+        query_presence_status: &str,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::Contacts>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !query_presence_status.is_empty() {
+            query_args.push(("query_presence_status", query_presence_status.to_string()));
+        }
+        if !search_key.is_empty() {
+            query_args.push(("search_key", search_key.to_string()));
+        }
+        let url = crate::Client::url_with_query("/contacts", &query_args);
+
+        self.client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::SearchCompanyContactsResponse| {
+                    (resp.contacts, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/contacts", &[]))
+    }
+
+    /**
+     * Search company contacts.
+     *
+     * This function performs a `GET` to the `/contacts` endpoint.
+     *
+     * As opposed to `search_company_stream`, this function streams only the items `predicate` keeps. It too gets the next page only once the items of the last one are used up, so a stream that is cut short, for example with `take`, gets no more pages than it needs.
+     */
+    pub async fn search_company_filter<P: FnMut(&crate::types::Contacts) -> bool>(
+        &self,
+        search_key: &str,
+        query_presence_status: &str,
+        mut predicate: P,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::Contacts>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !query_presence_status.is_empty() {
+            query_args.push(("query_presence_status", query_presence_status.to_string()));
+        }
+        if !search_key.is_empty() {
+            query_args.push(("search_key", search_key.to_string()));
+        }
+        let url = crate::Client::url_with_query("/contacts", &query_args);
+
+        let items = self
+            .client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::SearchCompanyContactsResponse| {
+                    (resp.contacts, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/contacts", &[]))?;
+        Ok(futures::TryStreamExt::try_filter(items, move |item| {
+            futures::future::ready(predicate(item))
+        }))
+    }
+
+    /// The OAuth scopes for `get_user`, any one of them grants access.
+    pub const GET_USER_SCOPES: &[&str] = &["chat_contact:read"];
+
     /**
      * List user's contacts.
      *
@@ -154,25 +277,65 @@ impl Contacts {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<Vec<crate::types::GetUserContactsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/chat/users/me/contacts?{}", query_);
+        let url = crate::Client::url_with_query("/chat/users/me/contacts", &query_args);
 
-        let resp: crate::types::GetUserContactsResponseData = self.client.get(&url, None).await?;
+        let resp: crate::types::GetUserContactsResponseData = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/chat/users/me/contacts", &[]))?;
 
         // Return our response data.
         Ok(resp.contacts)
     }
 
+    /**
+     * List user's contacts.
+     *
+     * This function performs a `GET` to the `/chat/users/me/contacts` endpoint.
+     *
+     * As opposed to `get_user`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
+     */
+    pub async fn get_user_page(
+        &self,
+        type_: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::GetUserContactsResponse>, String)> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::Client::url_with_query("/chat/users/me/contacts", &query_args);
+
+        let resp: crate::types::GetUserContactsResponseData = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/chat/users/me/contacts", &[]))?;
+
+        // Return our response data, and where the next page starts.
+        Ok((resp.contacts, resp.next_page_token))
+    }
+
     /**
      * List user's contacts.
      *
@@ -192,15 +355,17 @@ impl Contacts {
         &self,
         type_: &str,
     ) -> Result<Vec<crate::types::GetUserContactsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/chat/users/me/contacts?{}", query_);
+        let url = crate::Client::url_with_query("/chat/users/me/contacts", &query_args);
 
-        let mut resp: crate::types::GetUserContactsResponseData =
-            self.client.get(&url, None).await?;
+        let mut resp: crate::types::GetUserContactsResponseData = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/chat/users/me/contacts", &[]))?;
 
         let mut contacts = resp.contacts;
         let mut page = resp.next_page_token;
@@ -233,6 +398,72 @@ impl Contacts {
         Ok(contacts)
     }
 
+    /**
+     * List user's contacts.
+     *
+     * This function performs a `GET` to the `/chat/users/me/contacts` endpoint.
+     *
+     * As opposed to `get_user`, this function streams the items of all the pages, getting the next page only once the items of the last one are used up. The stream ends after the last page.
+     */
+    pub async fn get_user_stream(
+        &self,
+        type_: &str,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::GetUserContactsResponse>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !type_.is_empty() {
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::Client::url_with_query("/chat/users/me/contacts", &query_args);
+
+        self.client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::GetUserContactsResponseData| {
+                    (resp.contacts, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/chat/users/me/contacts", &[]))
+    }
+
+    /**
+     * List user's contacts.
+     *
+     * This function performs a `GET` to the `/chat/users/me/contacts` endpoint.
+     *
+     * As opposed to `get_user_stream`, this function streams only the items `predicate` keeps. It too gets the next page only once the items of the last one are used up, so a stream that is cut short, for example with `take`, gets no more pages than it needs.
+     */
+    pub async fn get_user_filter<P: FnMut(&crate::types::GetUserContactsResponse) -> bool>(
+        &self,
+        type_: &str,
+        mut predicate: P,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::GetUserContactsResponse>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !type_.is_empty() {
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::Client::url_with_query("/chat/users/me/contacts", &query_args);
+
+        let items = self
+            .client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::GetUserContactsResponseData| {
+                    (resp.contacts, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/chat/users/me/contacts", &[]))?;
+        Ok(futures::TryStreamExt::try_filter(items, move |item| {
+            futures::future::ready(predicate(item))
+        }))
+    }
+
+    /// The OAuth scopes for `get_user_contacts`, any one of them grants access.
+    pub const GET_USER_CONTACTS_SCOPES: &[&str] = &["chat_contact:read"];
+
     /**
      * Get user's contact details.
      *
@@ -256,20 +487,143 @@ impl Contacts {
         contact_id: &str,
         query_presence_status: bool,
     ) -> Result<crate::types::GetUserContactResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if query_presence_status {
-            query_args.push((
-                "query_presence_status".to_string(),
-                query_presence_status.to_string(),
-            ));
+            query_args.push(("query_presence_status", query_presence_status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/chat/users/me/contacts/{}?{}",
-            crate::progenitor_support::encode_path(&contact_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/chat/users/me/contacts/{}",
+                self.client.encode_path(contact_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/chat/users/me/contacts/{contactId}",
+                &[("contactId", contact_id.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`Contacts`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ContactsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ContactsApi: Send + Sync {
+    async fn search_company(
+        &self,
+        search_key: &str,
+        query_presence_status: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::Contacts>>;
+    async fn search_company_page(
+        &self,
+        search_key: &str,
+        query_presence_status: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::Contacts>, String)>;
+    async fn get_all_search_company(
+        &self,
+        search_key: &str,
+        query_presence_status: &str,
+    ) -> Result<Vec<crate::types::Contacts>>;
+    async fn get_user(
+        &self,
+        type_: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::GetUserContactsResponse>>;
+    async fn get_user_page(
+        &self,
+        type_: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::GetUserContactsResponse>, String)>;
+    async fn get_all_user(&self, type_: &str)
+        -> Result<Vec<crate::types::GetUserContactsResponse>>;
+    async fn get_user_contacts(
+        &self,
+        contact_id: &str,
+        query_presence_status: bool,
+    ) -> Result<crate::types::GetUserContactResponse>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ContactsApi for Contacts {
+    async fn search_company(
+        &self,
+        search_key: &str,
+        query_presence_status: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::Contacts>> {
+        Contacts::search_company(
+            self,
+            search_key,
+            query_presence_status,
+            page_size,
+            next_page_token,
+        )
+        .await
+    }
+    async fn search_company_page(
+        &self,
+        search_key: &str,
+        query_presence_status: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::Contacts>, String)> {
+        Contacts::search_company_page(
+            self,
+            search_key,
+            query_presence_status,
+            page_size,
+            next_page_token,
+        )
+        .await
+    }
+    async fn get_all_search_company(
+        &self,
+        search_key: &str,
+        query_presence_status: &str,
+    ) -> Result<Vec<crate::types::Contacts>> {
+        Contacts::get_all_search_company(self, search_key, query_presence_status).await
+    }
+    async fn get_user(
+        &self,
+        type_: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::GetUserContactsResponse>> {
+        Contacts::get_user(self, type_, page_size, next_page_token).await
+    }
+    async fn get_user_page(
+        &self,
+        type_: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::GetUserContactsResponse>, String)> {
+        Contacts::get_user_page(self, type_, page_size, next_page_token).await
+    }
+    async fn get_all_user(
+        &self,
+        type_: &str,
+    ) -> Result<Vec<crate::types::GetUserContactsResponse>> {
+        Contacts::get_all_user(self, type_).await
+    }
+    async fn get_user_contacts(
+        &self,
+        contact_id: &str,
+        query_presence_status: bool,
+    ) -> Result<crate::types::GetUserContactResponse> {
+        Contacts::get_user_contacts(self, contact_id, query_presence_status).await
     }
 }
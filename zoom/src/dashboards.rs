@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Dashboards {
     pub client: Client,
@@ -12,6 +10,17 @@ impl Dashboards {
         Dashboards { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Dashboards {
+            client: self.client.with_retry(policy),
+        }
+    }
+
+    /// The OAuth scopes for `meeting`, any one of them grants access.
+    pub const MEETING_SCOPES: &[&str] = &["dashboard_meetings:read:admin"];
+
     /**
      * List meetings.
      *
@@ -32,7 +41,7 @@ impl Dashboards {
      * * `to: chrono::NaiveDate` -- Start Date.
      * * `page_size: i64` -- The number of records returned within a single API call.
      * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `include_fields: crate::types::IncludeFields` -- Set the value of this field to "tracking_fields" if you would like to include tracking fields of each meeting in the response.
+     * * `include_fields: Option<crate::types::IncludeFields>` -- Set the value of this field to "tracking_fields" if you would like to include tracking fields of each meeting in the response. `None` leaves it off the query, for the API's default.
      */
     pub async fn meeting(
         &self,
@@ -41,33 +50,60 @@ impl Dashboards {
         to: chrono::NaiveDate,
         page_size: i64,
         next_page_token: &str,
-        include_fields: crate::types::IncludeFields,
+        include_fields: Option<crate::types::IncludeFields>,
     ) -> Result<crate::types::DashboardMeetingsResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
-        if !include_fields.to_string().is_empty() {
-            query_args.push(("include_fields".to_string(), include_fields.to_string()));
+        if let Some(v) = include_fields {
+            query_args.push(("include_fields", v.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/metrics/meetings?{}", query_);
+        let url = crate::Client::url_with_query("/metrics/meetings", &query_args);
+
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/metrics/meetings", &[]))
+    }
 
-        self.client.get(&url, None).await
+    /**
+     * A request to `meeting`, to set its query parameters by name rather than by position.
+     * The ones left unset are left off the query.
+     */
+    pub fn meeting_request(
+        &self,
+        type_: crate::types::DashboardMeetingsType,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> MeetingRequest<'_> {
+        MeetingRequest {
+            api: self,
+            type_,
+            from,
+            to,
+            page_size: None,
+            next_page_token: None,
+            include_fields: None,
+        }
     }
 
+    /// The OAuth scopes for `meeting_detail`, any one of them grants access.
+    pub const MEETING_DETAIL_SCOPES: &[&str] = &["dashboard_meetings:read:admin"];
+
     /**
      * Get meeting details.
      *
@@ -93,20 +129,27 @@ impl Dashboards {
         meeting_id: &str,
         type_: crate::types::DashboardMeetingsType,
     ) -> Result<crate::types::MeetingMetric> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/metrics/meetings/{}?{}",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/metrics/meetings/{}", self.client.encode_path(meeting_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/metrics/meetings/{meetingId}",
+                &[("meetingId", meeting_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `meeting_participant`, any one of them grants access.
+    pub const MEETING_PARTICIPANT_SCOPES: &[&str] = &["dashboard_meetings:read:admin"];
+
     /**
      * List meeting participants.
      *
@@ -129,7 +172,7 @@ impl Dashboards {
      *  If you do not provide this field, the default value will be `live` and thus, the API will only query responses for live meetings.
      * * `page_size: i64` -- The number of records returned within a single API call.
      * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `include_fields: crate::types::DashboardMeetingParticipantsIncludeFields` -- Provide `registrant_id` as the value for this field if you would like to see the registrant ID attribute in the response of this API call. A registrant ID is a unique identifier of a [meeting registrant](https://marketplace.zoom.us/docs/api-reference/zoom-api/meetings/meetingregistrants). This is not supported for `live` meeting types.
+     * * `include_fields: Option<crate::types::DashboardMeetingParticipantsIncludeFields>` -- Provide `registrant_id` as the value for this field if you would like to see the registrant ID attribute in the response of this API call. A registrant ID is a unique identifier of a [meeting registrant](https://marketplace.zoom.us/docs/api-reference/zoom-api/meetings/meetingregistrants). This is not supported for `live` meeting types. `None` leaves it off the query, for the API's default.
      */
     pub async fn meeting_participant(
         &self,
@@ -137,31 +180,42 @@ impl Dashboards {
         type_: crate::types::DashboardMeetingsType,
         page_size: i64,
         next_page_token: &str,
-        include_fields: crate::types::DashboardMeetingParticipantsIncludeFields,
+        include_fields: Option<crate::types::DashboardMeetingParticipantsIncludeFields>,
     ) -> Result<crate::types::DashboardMeetingParticipantsResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !include_fields.to_string().is_empty() {
-            query_args.push(("include_fields".to_string(), include_fields.to_string()));
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if let Some(v) = include_fields {
+            query_args.push(("include_fields", v.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/metrics/meetings/{}/participants?{}",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
-            query_
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/metrics/meetings/{}/participants",
+                self.client.encode_path(meeting_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/metrics/meetings/{meetingId}/participants",
+                &[("meetingId", meeting_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `meeting_participant_qo`, any one of them grants access.
+    pub const MEETING_PARTICIPANT_QO_SCOPES: &[&str] = &["dashboard_meetings:read:admin"];
+
     /**
      * Get meeting participant QoS.
      *
@@ -190,21 +244,34 @@ impl Dashboards {
         participant_id: &str,
         type_: crate::types::DashboardMeetingsType,
     ) -> Result<crate::types::ParticipantQos> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/metrics/meetings/{}/participants/{}/qos?{}",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
-            crate::progenitor_support::encode_path(&participant_id.to_string()),
-            query_
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/metrics/meetings/{}/participants/{}/qos",
+                self.client.encode_path(meeting_id),
+                self.client.encode_path(participant_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/metrics/meetings/{meetingId}/participants/{participantId}/qos",
+                &[
+                    ("meetingId", meeting_id.to_string()),
+                    ("participantId", participant_id.to_string()),
+                ],
+            )
+        })
     }
 
+    /// The OAuth scopes for `meeting_participants_qo`, any one of them grants access.
+    pub const MEETING_PARTICIPANTS_QO_SCOPES: &[&str] = &["dashboard_meetings:read:admin"];
+
     /**
      * List meeting participants QoS.
      *
@@ -234,26 +301,37 @@ impl Dashboards {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/metrics/meetings/{}/participants/qos?{}",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
-            query_
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/metrics/meetings/{}/participants/qos",
+                self.client.encode_path(meeting_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/metrics/meetings/{meetingId}/participants/qos",
+                &[("meetingId", meeting_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `meeting_participant_share`, any one of them grants access.
+    pub const MEETING_PARTICIPANT_SHARE_SCOPES: &[&str] = &["dashboard_meetings:read:admin"];
+
     /**
      * Get sharing/recording details.
      *
@@ -283,26 +361,37 @@ impl Dashboards {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<crate::types::DashboardMeetingParticipantShareResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/metrics/meetings/{}/participants/sharing?{}",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
-            query_
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/metrics/meetings/{}/participants/sharing",
+                self.client.encode_path(meeting_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/metrics/meetings/{meetingId}/participants/sharing",
+                &[("meetingId", meeting_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `webinar`, any one of them grants access.
+    pub const WEBINAR_SCOPES: &[&str] = &["dashboard_webinars:read:admin"];
+
     /**
      * List webinars.
      *
@@ -333,28 +422,34 @@ impl Dashboards {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<crate::types::DashboardWebinarsResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/metrics/webinars?{}", query_);
+        let url = crate::Client::url_with_query("/metrics/webinars", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/metrics/webinars", &[]))
     }
 
+    /// The OAuth scopes for `webinar_detail`, any one of them grants access.
+    pub const WEBINAR_DETAIL_SCOPES: &[&str] = &["dashboard_webinars:read:admin"];
+
     /**
      * Get webinar details.
      *
@@ -380,20 +475,27 @@ impl Dashboards {
         webinar_id: &str,
         type_: crate::types::DashboardWebinarsType,
     ) -> Result<crate::types::Webinars> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/metrics/webinars/{}?{}",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/metrics/webinars/{}", self.client.encode_path(webinar_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/metrics/webinars/{webinarId}",
+                &[("webinarId", webinar_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `webinar_participant`, any one of them grants access.
+    pub const WEBINAR_PARTICIPANT_SCOPES: &[&str] = &["dashboard_webinars:read:admin"];
+
     /**
      * Get webinar participants.
      *
@@ -415,7 +517,7 @@ impl Dashboards {
      * * `type_: crate::types::DashboardWebinarsType` -- The webinar type.
      * * `page_size: i64` -- The number of records returned within a single API call.
      * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `include_fields: crate::types::DashboardMeetingParticipantsIncludeFields` -- Provide `registrant_id` as the value for this field if you would like to see the registrant ID attribute in the response of this API call. A registrant ID is a unique identifier of a [meeting registrant](https://marketplace.zoom.us/docs/api-reference/zoom-api/meetings/meetingregistrants). This is not supported for `live` meeting types.
+     * * `include_fields: Option<crate::types::DashboardMeetingParticipantsIncludeFields>` -- Provide `registrant_id` as the value for this field if you would like to see the registrant ID attribute in the response of this API call. A registrant ID is a unique identifier of a [meeting registrant](https://marketplace.zoom.us/docs/api-reference/zoom-api/meetings/meetingregistrants). This is not supported for `live` meeting types. `None` leaves it off the query, for the API's default.
      */
     pub async fn webinar_participant(
         &self,
@@ -423,31 +525,42 @@ impl Dashboards {
         type_: crate::types::DashboardWebinarsType,
         page_size: i64,
         next_page_token: &str,
-        include_fields: crate::types::DashboardMeetingParticipantsIncludeFields,
+        include_fields: Option<crate::types::DashboardMeetingParticipantsIncludeFields>,
     ) -> Result<crate::types::DashboardWebinarParticipantsResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !include_fields.to_string().is_empty() {
-            query_args.push(("include_fields".to_string(), include_fields.to_string()));
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if let Some(v) = include_fields {
+            query_args.push(("include_fields", v.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/metrics/webinars/{}/participants?{}",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
-            query_
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/metrics/webinars/{}/participants",
+                self.client.encode_path(webinar_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/metrics/webinars/{webinarId}/participants",
+                &[("webinarId", webinar_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `webinar_participant_qo`, any one of them grants access.
+    pub const WEBINAR_PARTICIPANT_QO_SCOPES: &[&str] = &["dashboard_webinars:read:admin"];
+
     /**
      * Get webinar participant QoS.
      *
@@ -474,21 +587,34 @@ impl Dashboards {
         participant_id: &str,
         type_: crate::types::DashboardWebinarsType,
     ) -> Result<crate::types::ParticipantQos> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/metrics/webinars/{}/participants/{}/qos?{}",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
-            crate::progenitor_support::encode_path(&participant_id.to_string()),
-            query_
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/metrics/webinars/{}/participants/{}/qos",
+                self.client.encode_path(webinar_id),
+                self.client.encode_path(participant_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/metrics/webinars/{webinarId}/participants/{participantId}/qos",
+                &[
+                    ("webinarId", webinar_id.to_string()),
+                    ("participantId", participant_id.to_string()),
+                ],
+            )
+        })
     }
 
+    /// The OAuth scopes for `webinar_participants_qo`, any one of them grants access.
+    pub const WEBINAR_PARTICIPANTS_QO_SCOPES: &[&str] = &["dashboard_webinars:read:admin"];
+
     /**
      * List webinar participant QoS.
      *
@@ -518,26 +644,37 @@ impl Dashboards {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/metrics/webinars/{}/participants/qos?{}",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
-            query_
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/metrics/webinars/{}/participants/qos",
+                self.client.encode_path(webinar_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/metrics/webinars/{webinarId}/participants/qos",
+                &[("webinarId", webinar_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `webinar_participant_share`, any one of them grants access.
+    pub const WEBINAR_PARTICIPANT_SHARE_SCOPES: &[&str] = &["dashboard_webinars:read:admin"];
+
     /**
      * Get sharing/recording details.
      *
@@ -567,26 +704,37 @@ impl Dashboards {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<crate::types::DashboardMeetingParticipantShareResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/metrics/webinars/{}/participants/sharing?{}",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
-            query_
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/metrics/webinars/{}/participants/sharing",
+                self.client.encode_path(webinar_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/metrics/webinars/{webinarId}/participants/sharing",
+                &[("webinarId", webinar_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `zoom_room`, any one of them grants access.
+    pub const ZOOM_ROOM_SCOPES: &[&str] = &["dashboard_zr:read:admin"];
+
     /**
      * List Zoom Rooms.
      *
@@ -614,22 +762,28 @@ impl Dashboards {
         page_number: i64,
         next_page_token: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_number > 0 {
-            query_args.push(("page_number".to_string(), page_number.to_string()));
+            query_args.push(("page_number", page_number.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/metrics/zoomrooms?{}", query_);
+        let url = crate::Client::url_with_query("/metrics/zoomrooms", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/metrics/zoomrooms", &[]))
     }
 
+    /// The OAuth scopes for `zoom_room_dashboards`, any one of them grants access.
+    pub const ZOOM_ROOM_DASHBOARDS_SCOPES: &[&str] = &["dashboard_zr:read:admin"];
+
     /**
      * Get Zoom Rooms details.
      *
@@ -660,29 +814,40 @@ impl Dashboards {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/metrics/zoomrooms/{}?{}",
-            crate::progenitor_support::encode_path(&zoomroom_id.to_string()),
-            query_
+            query_args.push(("to", to.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/metrics/zoomrooms/{}",
+                self.client.encode_path(zoomroom_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/metrics/zoomrooms/{zoomroomId}",
+                &[("zoomroomId", zoomroom_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `crc`, any one of them grants access.
+    pub const CRC_SCOPES: &[&str] = &["dashboard_crc:read:admin"];
+
     /**
      * Get CRC port usage.
      *
@@ -707,19 +872,24 @@ impl Dashboards {
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/metrics/crc?{}", query_);
+        let url = crate::Client::url_with_query("/metrics/crc", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/metrics/crc", &[]))
     }
 
+    /// The OAuth scopes for `im`, any one of them grants access.
+    pub const IM_SCOPES: &[&str] = &["dashboard_im:read:admin"];
+
     /**
      * Get IM metrics.
      *
@@ -749,25 +919,31 @@ impl Dashboards {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<crate::types::DashboardImResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/metrics/im?{}", query_);
+        let url = crate::Client::url_with_query("/metrics/im", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/metrics/im", &[]))
     }
 
+    /// The OAuth scopes for `chat`, any one of them grants access.
+    pub const CHAT_SCOPES: &[&str] = &["dashboard_im:read:admin"];
+
     /**
      * Get chat metrics.
      *
@@ -799,25 +975,31 @@ impl Dashboards {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<crate::types::DashboardChatResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/metrics/chat?{}", query_);
+        let url = crate::Client::url_with_query("/metrics/chat", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/metrics/chat", &[]))
     }
 
+    /// The OAuth scopes for `client_feedback`, any one of them grants access.
+    pub const CLIENT_FEEDBACK_SCOPES: &[&str] = &["account:read:admin"];
+
     /**
      * List Zoom meetings client feedback.
      *
@@ -843,19 +1025,24 @@ impl Dashboards {
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> Result<crate::types::DashboardClientFeedbackResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/metrics/client/feedback?{}", query_);
+        let url = crate::Client::url_with_query("/metrics/client/feedback", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/metrics/client/feedback", &[]))
     }
 
+    /// The OAuth scopes for `zoom_room_issue`, any one of them grants access.
+    pub const ZOOM_ROOM_ISSUE_SCOPES: &[&str] = &["dashboard_zr:read:admin"];
+
     /**
      * Get top 25 issues of Zoom Rooms.
      *
@@ -880,19 +1067,24 @@ impl Dashboards {
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/metrics/zoomrooms/issues?{}", query_);
+        let url = crate::Client::url_with_query("/metrics/zoomrooms/issues", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/metrics/zoomrooms/issues", &[]))
     }
 
+    /// The OAuth scopes for `issue_zoom_room`, any one of them grants access.
+    pub const ISSUE_ZOOM_ROOM_SCOPES: &[&str] = &["dashboard_home:read:admin"];
+
     /**
      * Get top 25 Zoom Rooms with issues.
      *
@@ -915,19 +1107,24 @@ impl Dashboards {
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> Result<crate::types::DashboardIssueZoomRoomResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/metrics/issues/zoomrooms?{}", query_);
+        let url = crate::Client::url_with_query("/metrics/issues/zoomrooms", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/metrics/issues/zoomrooms", &[]))
     }
 
+    /// The OAuth scopes for `issue_detail_zoom_room`, any one of them grants access.
+    pub const ISSUE_DETAIL_ZOOM_ROOM_SCOPES: &[&str] = &["dashboard_home:read:admin"];
+
     /**
      * Get issues of Zoom Rooms.
      *
@@ -957,29 +1154,40 @@ impl Dashboards {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<crate::types::DashboardIssueDetailZoomRoomResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/metrics/issues/zoomrooms/{}?{}",
-            crate::progenitor_support::encode_path(&zoomroom_id.to_string()),
-            query_
+            query_args.push(("to", to.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/metrics/issues/zoomrooms/{}",
+                self.client.encode_path(zoomroom_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/metrics/issues/zoomrooms/{zoomroomId}",
+                &[("zoomroomId", zoomroom_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `client_feedback_detail`, any one of them grants access.
+    pub const CLIENT_FEEDBACK_DETAIL_SCOPES: &[&str] = &["dashboard_home:read:admin"];
+
     /**
      * Get zoom meetings client feedback.
      *
@@ -1012,29 +1220,40 @@ impl Dashboards {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<crate::types::DashboardClientFeedbackDetailResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/metrics/client/feedback/{}?{}",
-            crate::progenitor_support::encode_path(&feedback_id.to_string()),
-            query_
+            query_args.push(("to", to.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/metrics/client/feedback/{}",
+                self.client.encode_path(feedback_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/metrics/client/feedback/{feedbackId}",
+                &[("feedbackId", feedback_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `list_meeting_satisfaction`, any one of them grants access.
+    pub const LIST_MEETING_SATISFACTION_SCOPES: &[&str] = &["dashboard:read:admin"];
+
     /**
      * List client meeting satisfaction.
      *
@@ -1056,19 +1275,24 @@ impl Dashboards {
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> Result<crate::types::ListMeetingSatisfactionResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/metrics/client/satisfaction?{}", query_);
+        let url = crate::Client::url_with_query("/metrics/client/satisfaction", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/metrics/client/satisfaction", &[]))
     }
 
+    /// The OAuth scopes for `list_call_logs_metrics`, any one of them grants access.
+    pub const LIST_CALL_LOGS_METRICS_SCOPES: &[&str] = &["phone:read:admin", "phone:write:admin"];
+
     /**
      * List call logs.
      *
@@ -1110,35 +1334,234 @@ impl Dashboards {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<Vec<crate::types::ListCallLogsMetricsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from.is_empty() {
+            query_args.push(("from", from.to_string()));
+        }
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        if !quality_type.is_empty() {
+            query_args.push(("quality_type", quality_type.to_string()));
+        }
+        let site_id = self.client.site_id_or(site_id);
+        if !site_id.is_empty() {
+            query_args.push(("site_id", site_id.to_string()));
+        }
+        if !to.is_empty() {
+            query_args.push(("to", to.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/metrics/call_logs", &query_args);
+
+        let resp: crate::types::ListCallLogsMetricsResponseData =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url("GET", "/phone/metrics/call_logs", &[])
+            })?;
+
+        // Return our response data.
+        Ok(resp.call_logs)
+    }
+
+    /**
+     * List call logs.
+     *
+     * This function performs a `GET` to the `/phone/metrics/call_logs` endpoint.
+     *
+     * As opposed to `list_call_logs_metrics`, this function streams the items as they are parsed from the response, rather than returning them all at once.
+     * This is useful for very large responses that would otherwise need to be held in memory.
+     * Only the one page the parameters ask for is streamed, the next pages are not fetched.
+     */
+    pub async fn list_call_logs_metrics_stream_json(
+        &self,
+        from: &str,
+        to: &str,
+        site_id: &str,
+        quality_type: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::ListCallLogsMetricsResponse>>>
+    {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from.is_empty() {
+            query_args.push(("from", from.to_string()));
+        }
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        if !quality_type.is_empty() {
+            query_args.push(("quality_type", quality_type.to_string()));
+        }
+        let site_id = self.client.site_id_or(site_id);
+        if !site_id.is_empty() {
+            query_args.push(("site_id", site_id.to_string()));
+        }
+        if !to.is_empty() {
+            query_args.push(("to", to.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/metrics/call_logs", &query_args);
+
+        self.client
+            .get_stream_json(&url, "call_logs")
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/metrics/call_logs", &[]))
+    }
+
+    /**
+     * List call logs.
+     *
+     * This function performs a `GET` to the `/phone/metrics/call_logs` endpoint.
+     *
+     * As opposed to `list_call_logs_metrics`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
+     */
+    pub async fn list_call_logs_metrics_page(
+        &self,
+        from: &str,
+        to: &str,
+        site_id: &str,
+        quality_type: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::ListCallLogsMetricsResponse>, String)> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from.is_empty() {
+            query_args.push(("from", from.to_string()));
+        }
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        if !quality_type.is_empty() {
+            query_args.push(("quality_type", quality_type.to_string()));
+        }
+        let site_id = self.client.site_id_or(site_id);
+        if !site_id.is_empty() {
+            query_args.push(("site_id", site_id.to_string()));
+        }
+        if !to.is_empty() {
+            query_args.push(("to", to.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/metrics/call_logs", &query_args);
+
+        let resp: crate::types::ListCallLogsMetricsResponseData =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url("GET", "/phone/metrics/call_logs", &[])
+            })?;
+
+        // Return our response data, and where the next page starts.
+        Ok((resp.call_logs, resp.next_page_token))
+    }
+
+    /**
+     * List call logs.
+     *
+     * This function performs a `GET` to the `/phone/metrics/call_logs` endpoint.
+     *
+     * As opposed to `list_call_logs_metrics`, this function takes `from` and `to` as times, and sends them in UTC like `2021-08-15T01:52:41Z`.
+     */
+    pub async fn list_call_logs_metrics_between(
+        &self,
+        from: chrono::DateTime<chrono::Utc>,
+        to: chrono::DateTime<chrono::Utc>,
+        site_id: &str,
+        quality_type: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::ListCallLogsMetricsResponse>> {
+        let from = crate::utils::date_time_param(&from);
+        let to = crate::utils::date_time_param(&to);
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !quality_type.is_empty() {
-            query_args.push(("quality_type".to_string(), quality_type.to_string()));
+            query_args.push(("quality_type", quality_type.to_string()));
         }
+        let site_id = self.client.site_id_or(site_id);
         if !site_id.is_empty() {
-            query_args.push(("site_id".to_string(), site_id.to_string()));
+            query_args.push(("site_id", site_id.to_string()));
         }
         if !to.is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/metrics/call_logs?{}", query_);
+        let url = crate::Client::url_with_query("/phone/metrics/call_logs", &query_args);
 
         let resp: crate::types::ListCallLogsMetricsResponseData =
-            self.client.get(&url, None).await?;
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url("GET", "/phone/metrics/call_logs", &[])
+            })?;
 
         // Return our response data.
         Ok(resp.call_logs)
     }
 
+    /**
+     * List call logs.
+     *
+     * This function performs a `GET` to the `/phone/metrics/call_logs` endpoint.
+     *
+     * As opposed to `list_call_logs_metrics_between`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
+     */
+    pub async fn list_call_logs_metrics_between_page(
+        &self,
+        from: chrono::DateTime<chrono::Utc>,
+        to: chrono::DateTime<chrono::Utc>,
+        site_id: &str,
+        quality_type: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::ListCallLogsMetricsResponse>, String)> {
+        let from = crate::utils::date_time_param(&from);
+        let to = crate::utils::date_time_param(&to);
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from.is_empty() {
+            query_args.push(("from", from.to_string()));
+        }
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        if !quality_type.is_empty() {
+            query_args.push(("quality_type", quality_type.to_string()));
+        }
+        let site_id = self.client.site_id_or(site_id);
+        if !site_id.is_empty() {
+            query_args.push(("site_id", site_id.to_string()));
+        }
+        if !to.is_empty() {
+            query_args.push(("to", to.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/metrics/call_logs", &query_args);
+
+        let resp: crate::types::ListCallLogsMetricsResponseData =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url("GET", "/phone/metrics/call_logs", &[])
+            })?;
+
+        // Return our response data, and where the next page starts.
+        Ok((resp.call_logs, resp.next_page_token))
+    }
+
     /**
      * List call logs.
      *
@@ -1164,24 +1587,26 @@ impl Dashboards {
         site_id: &str,
         quality_type: &str,
     ) -> Result<Vec<crate::types::ListCallLogsMetricsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !quality_type.is_empty() {
-            query_args.push(("quality_type".to_string(), quality_type.to_string()));
+            query_args.push(("quality_type", quality_type.to_string()));
         }
+        let site_id = self.client.site_id_or(site_id);
         if !site_id.is_empty() {
-            query_args.push(("site_id".to_string(), site_id.to_string()));
+            query_args.push(("site_id", site_id.to_string()));
         }
         if !to.is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/metrics/call_logs?{}", query_);
+        let url = crate::Client::url_with_query("/phone/metrics/call_logs", &query_args);
 
         let mut resp: crate::types::ListCallLogsMetricsResponseData =
-            self.client.get(&url, None).await?;
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url("GET", "/phone/metrics/call_logs", &[])
+            })?;
 
         let mut call_logs = resp.call_logs;
         let mut page = resp.next_page_token;
@@ -1214,6 +1639,119 @@ impl Dashboards {
         Ok(call_logs)
     }
 
+    /**
+     * List call logs.
+     *
+     * This function performs a `GET` to the `/phone/metrics/call_logs` endpoint.
+     *
+     * As opposed to `list_call_logs_metrics`, this function streams the items of all the pages, getting the next page only once the items of the last one are used up. The stream ends after the last page.
+     */
+    pub async fn list_call_logs_metrics_stream(
+        &self,
+        from: &str,
+        to: &str,
+        site_id: &str,
+        quality_type: &str,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::ListCallLogsMetricsResponse>>>
+    {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from.is_empty() {
+            query_args.push(("from", from.to_string()));
+        }
+        if !quality_type.is_empty() {
+            query_args.push(("quality_type", quality_type.to_string()));
+        }
+        let site_id = self.client.site_id_or(site_id);
+        if !site_id.is_empty() {
+            query_args.push(("site_id", site_id.to_string()));
+        }
+        if !to.is_empty() {
+            query_args.push(("to", to.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/metrics/call_logs", &query_args);
+
+        self.client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListCallLogsMetricsResponseData| {
+                    (resp.call_logs, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/metrics/call_logs", &[]))
+    }
+
+    /**
+     * List call logs.
+     *
+     * This function performs a `GET` to the `/phone/metrics/call_logs` endpoint.
+     *
+     * As opposed to `list_call_logs_metrics_stream`, this function streams only the items `predicate` keeps. It too gets the next page only once the items of the last one are used up, so a stream that is cut short, for example with `take`, gets no more pages than it needs.
+     */
+    pub async fn list_call_logs_metrics_filter<
+        P: FnMut(&crate::types::ListCallLogsMetricsResponse) -> bool,
+    >(
+        &self,
+        from: &str,
+        to: &str,
+        site_id: &str,
+        quality_type: &str,
+        mut predicate: P,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::ListCallLogsMetricsResponse>>>
+    {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from.is_empty() {
+            query_args.push(("from", from.to_string()));
+        }
+        if !quality_type.is_empty() {
+            query_args.push(("quality_type", quality_type.to_string()));
+        }
+        let site_id = self.client.site_id_or(site_id);
+        if !site_id.is_empty() {
+            query_args.push(("site_id", site_id.to_string()));
+        }
+        if !to.is_empty() {
+            query_args.push(("to", to.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/metrics/call_logs", &query_args);
+
+        let items = self
+            .client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListCallLogsMetricsResponseData| {
+                    (resp.call_logs, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/metrics/call_logs", &[]))?;
+        Ok(futures::TryStreamExt::try_filter(items, move |item| {
+            futures::future::ready(predicate(item))
+        }))
+    }
+
+    /**
+     * A request to `list_call_logs_metrics`, to set its query parameters by name rather than by position.
+     * The ones left unset are left off the query.
+     */
+    pub fn list_call_logs_metrics_request(&self) -> ListCallLogsMetricsRequest<'_> {
+        ListCallLogsMetricsRequest {
+            api: self,
+            from: None,
+            to: None,
+            site_id: None,
+            quality_type: None,
+            page_size: None,
+            next_page_token: None,
+        }
+    }
+
+    /// The OAuth scopes for `get_call_log_metrics_details`, any one of them grants access.
+    pub const GET_CALL_LOG_METRICS_DETAILS_SCOPES: &[&str] =
+        &["phone:read:admin", "phone:write:admin"];
+
     /**
      * Get call details from call log.
      *
@@ -1241,12 +1779,21 @@ impl Dashboards {
     ) -> Result<crate::types::ListCallLogsMetricsResponse> {
         let url = format!(
             "/phone/metrics/call_logs/{}",
-            crate::progenitor_support::encode_path(&call_id.to_string()),
+            self.client.encode_path(call_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/phone/metrics/call_logs/{call_id}",
+                &[("call_id", call_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `get_call_qo`, any one of them grants access.
+    pub const GET_CALL_QO_SCOPES: &[&str] = &["phone:read:admin", "phone:write:admin"];
+
     /**
      * Get call QoS.
      *
@@ -1267,12 +1814,21 @@ impl Dashboards {
     pub async fn get_call_qo(&self, call_id: &str) -> Result<crate::types::GetCallQoSResponse> {
         let url = format!(
             "/phone/metrics/call_logs/{}/qos",
-            crate::progenitor_support::encode_path(&call_id.to_string()),
+            self.client.encode_path(call_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/phone/metrics/call_logs/{callId}/qos",
+                &[("callId", call_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `participant_feedback`, any one of them grants access.
+    pub const PARTICIPANT_FEEDBACK_SCOPES: &[&str] = &["dashboard_meetings:read:admiin"];
+
     /**
      * Get post meeting feedback.
      *
@@ -1306,29 +1862,87 @@ impl Dashboards {
         next_page_token: &str,
         page_size: i64,
     ) -> Result<Vec<crate::types::ParticipantFeedbackResponseParticipants>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/metrics/meetings/{}/participants/satisfaction?{}",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
-            query_
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/metrics/meetings/{}/participants/satisfaction",
+                self.client.encode_path(meeting_id)
+            ),
+            &query_args,
         );
 
-        let resp: crate::types::ParticipantFeedbackResponse = self.client.get(&url, None).await?;
+        let resp: crate::types::ParticipantFeedbackResponse =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/metrics/meetings/{meetingId}/participants/satisfaction",
+                    &[("meetingId", meeting_id.to_string())],
+                )
+            })?;
 
         // Return our response data.
         Ok(resp.participants)
     }
 
+    /**
+     * Get post meeting feedback.
+     *
+     * This function performs a `GET` to the `/metrics/meetings/{meetingId}/participants/satisfaction` endpoint.
+     *
+     * As opposed to `participant_feedback`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
+     */
+    pub async fn participant_feedback_page(
+        &self,
+        meeting_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<(
+        Vec<crate::types::ParticipantFeedbackResponseParticipants>,
+        String,
+    )> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        if !type_.to_string().is_empty() {
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/metrics/meetings/{}/participants/satisfaction",
+                self.client.encode_path(meeting_id)
+            ),
+            &query_args,
+        );
+
+        let resp: crate::types::ParticipantFeedbackResponse =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/metrics/meetings/{meetingId}/participants/satisfaction",
+                    &[("meetingId", meeting_id.to_string())],
+                )
+            })?;
+
+        // Return our response data, and where the next page starts.
+        Ok((resp.participants, resp.next_page_token))
+    }
+
     /**
      * Get post meeting feedback.
      *
@@ -1351,19 +1965,26 @@ impl Dashboards {
         meeting_id: &str,
         type_: crate::types::DashboardMeetingsType,
     ) -> Result<Vec<crate::types::ParticipantFeedbackResponseParticipants>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/metrics/meetings/{}/participants/satisfaction?{}",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
-            query_
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/metrics/meetings/{}/participants/satisfaction",
+                self.client.encode_path(meeting_id)
+            ),
+            &query_args,
         );
 
         let mut resp: crate::types::ParticipantFeedbackResponse =
-            self.client.get(&url, None).await?;
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/metrics/meetings/{meetingId}/participants/satisfaction",
+                    &[("meetingId", meeting_id.to_string())],
+                )
+            })?;
 
         let mut participants = resp.participants;
         let mut page = resp.next_page_token;
@@ -1397,11 +2018,109 @@ impl Dashboards {
     }
 
     /**
-     * Get post webinar feedback.
-     *
-     * This function performs a `GET` to the `/metrics/webinars/{webinarId}/participants/satisfaction` endpoint.
+     * Get post meeting feedback.
      *
-     * When a Webinar ends, each attendee will be prompted to share their Webinar experience by clicking either thumbs up or thumbs down. Use this API to retrieve the feedback submitted for a specific webinar. Note that this API only works for meetings scheduled after December 20, 2020.
+     * This function performs a `GET` to the `/metrics/meetings/{meetingId}/participants/satisfaction` endpoint.
+     *
+     * As opposed to `participant_feedback`, this function streams the items of all the pages, getting the next page only once the items of the last one are used up. The stream ends after the last page.
+     */
+    pub async fn participant_feedback_stream(
+        &self,
+        meeting_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+    ) -> Result<
+        impl futures::Stream<Item = Result<crate::types::ParticipantFeedbackResponseParticipants>>,
+    > {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !type_.to_string().is_empty() {
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/metrics/meetings/{}/participants/satisfaction",
+                self.client.encode_path(meeting_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ParticipantFeedbackResponse| {
+                    (resp.participants, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/metrics/meetings/{meetingId}/participants/satisfaction",
+                    &[("meetingId", meeting_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Get post meeting feedback.
+     *
+     * This function performs a `GET` to the `/metrics/meetings/{meetingId}/participants/satisfaction` endpoint.
+     *
+     * As opposed to `participant_feedback_stream`, this function streams only the items `predicate` keeps. It too gets the next page only once the items of the last one are used up, so a stream that is cut short, for example with `take`, gets no more pages than it needs.
+     */
+    pub async fn participant_feedback_filter<
+        P: FnMut(&crate::types::ParticipantFeedbackResponseParticipants) -> bool,
+    >(
+        &self,
+        meeting_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+        mut predicate: P,
+    ) -> Result<
+        impl futures::Stream<Item = Result<crate::types::ParticipantFeedbackResponseParticipants>>,
+    > {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !type_.to_string().is_empty() {
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/metrics/meetings/{}/participants/satisfaction",
+                self.client.encode_path(meeting_id)
+            ),
+            &query_args,
+        );
+
+        let items = self
+            .client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ParticipantFeedbackResponse| {
+                    (resp.participants, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/metrics/meetings/{meetingId}/participants/satisfaction",
+                    &[("meetingId", meeting_id.to_string())],
+                )
+            })?;
+        Ok(futures::TryStreamExt::try_filter(items, move |item| {
+            futures::future::ready(predicate(item))
+        }))
+    }
+
+    /// The OAuth scopes for `participant_webinar_feedback`, any one of them grants access.
+    pub const PARTICIPANT_WEBINAR_FEEDBACK_SCOPES: &[&str] = &["dashboard_webinars:read:admin"];
+
+    /**
+     * Get post webinar feedback.
+     *
+     * This function performs a `GET` to the `/metrics/webinars/{webinarId}/participants/satisfaction` endpoint.
+     *
+     * When a Webinar ends, each attendee will be prompted to share their Webinar experience by clicking either thumbs up or thumbs down. Use this API to retrieve the feedback submitted for a specific webinar. Note that this API only works for meetings scheduled after December 20, 2020.
      *
      * **Prerequisites:**
      * * [Feedback to Zoom](https://support.zoom.us/hc/en-us/articles/115005838023) setting must be enabled by the participant prior to the meeting.
@@ -1430,29 +2149,87 @@ impl Dashboards {
         next_page_token: &str,
         webinar_id: &str,
     ) -> Result<Vec<crate::types::ParticipantFeedbackResponseParticipants>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/metrics/webinars/{}/participants/satisfaction?{}",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
-            query_
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/metrics/webinars/{}/participants/satisfaction",
+                self.client.encode_path(webinar_id)
+            ),
+            &query_args,
         );
 
-        let resp: crate::types::ParticipantFeedbackResponse = self.client.get(&url, None).await?;
+        let resp: crate::types::ParticipantFeedbackResponse =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/metrics/webinars/{webinarId}/participants/satisfaction",
+                    &[("webinarId", webinar_id.to_string())],
+                )
+            })?;
 
         // Return our response data.
         Ok(resp.participants)
     }
 
+    /**
+     * Get post webinar feedback.
+     *
+     * This function performs a `GET` to the `/metrics/webinars/{webinarId}/participants/satisfaction` endpoint.
+     *
+     * As opposed to `participant_webinar_feedback`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
+     */
+    pub async fn participant_webinar_feedback_page(
+        &self,
+        type_: crate::types::DashboardMeetingsType,
+        page_size: i64,
+        next_page_token: &str,
+        webinar_id: &str,
+    ) -> Result<(
+        Vec<crate::types::ParticipantFeedbackResponseParticipants>,
+        String,
+    )> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        if !type_.to_string().is_empty() {
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/metrics/webinars/{}/participants/satisfaction",
+                self.client.encode_path(webinar_id)
+            ),
+            &query_args,
+        );
+
+        let resp: crate::types::ParticipantFeedbackResponse =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/metrics/webinars/{webinarId}/participants/satisfaction",
+                    &[("webinarId", webinar_id.to_string())],
+                )
+            })?;
+
+        // Return our response data, and where the next page starts.
+        Ok((resp.participants, resp.next_page_token))
+    }
+
     /**
      * Get post webinar feedback.
      *
@@ -1476,19 +2253,26 @@ impl Dashboards {
         type_: crate::types::DashboardMeetingsType,
         webinar_id: &str,
     ) -> Result<Vec<crate::types::ParticipantFeedbackResponseParticipants>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/metrics/webinars/{}/participants/satisfaction?{}",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
-            query_
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/metrics/webinars/{}/participants/satisfaction",
+                self.client.encode_path(webinar_id)
+            ),
+            &query_args,
         );
 
         let mut resp: crate::types::ParticipantFeedbackResponse =
-            self.client.get(&url, None).await?;
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/metrics/webinars/{webinarId}/participants/satisfaction",
+                    &[("webinarId", webinar_id.to_string())],
+                )
+            })?;
 
         let mut participants = resp.participants;
         let mut page = resp.next_page_token;
@@ -1520,4 +2304,863 @@ impl Dashboards {
         // Return our response data.
         Ok(participants)
     }
+
+    /**
+     * Get post webinar feedback.
+     *
+     * This function performs a `GET` to the `/metrics/webinars/{webinarId}/participants/satisfaction` endpoint.
+     *
+     * As opposed to `participant_webinar_feedback`, this function streams the items of all the pages, getting the next page only once the items of the last one are used up. The stream ends after the last page.
+     */
+    pub async fn participant_webinar_feedback_stream(
+        &self,
+        type_: crate::types::DashboardMeetingsType,
+        webinar_id: &str,
+    ) -> Result<
+        impl futures::Stream<Item = Result<crate::types::ParticipantFeedbackResponseParticipants>>,
+    > {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !type_.to_string().is_empty() {
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/metrics/webinars/{}/participants/satisfaction",
+                self.client.encode_path(webinar_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ParticipantFeedbackResponse| {
+                    (resp.participants, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/metrics/webinars/{webinarId}/participants/satisfaction",
+                    &[("webinarId", webinar_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Get post webinar feedback.
+     *
+     * This function performs a `GET` to the `/metrics/webinars/{webinarId}/participants/satisfaction` endpoint.
+     *
+     * As opposed to `participant_webinar_feedback_stream`, this function streams only the items `predicate` keeps. It too gets the next page only once the items of the last one are used up, so a stream that is cut short, for example with `take`, gets no more pages than it needs.
+     */
+    pub async fn participant_webinar_feedback_filter<
+        P: FnMut(&crate::types::ParticipantFeedbackResponseParticipants) -> bool,
+    >(
+        &self,
+        type_: crate::types::DashboardMeetingsType,
+        webinar_id: &str,
+        mut predicate: P,
+    ) -> Result<
+        impl futures::Stream<Item = Result<crate::types::ParticipantFeedbackResponseParticipants>>,
+    > {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !type_.to_string().is_empty() {
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/metrics/webinars/{}/participants/satisfaction",
+                self.client.encode_path(webinar_id)
+            ),
+            &query_args,
+        );
+
+        let items = self
+            .client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ParticipantFeedbackResponse| {
+                    (resp.participants, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/metrics/webinars/{webinarId}/participants/satisfaction",
+                    &[("webinarId", webinar_id.to_string())],
+                )
+            })?;
+        Ok(futures::TryStreamExt::try_filter(items, move |item| {
+            futures::future::ready(predicate(item))
+        }))
+    }
+}
+
+/// A request to [`Dashboards::meeting`], see [`Dashboards::meeting_request`].
+pub struct MeetingRequest<'a> {
+    api: &'a Dashboards,
+    type_: crate::types::DashboardMeetingsType,
+    from: chrono::NaiveDate,
+    to: chrono::NaiveDate,
+    page_size: Option<i64>,
+    next_page_token: Option<String>,
+    include_fields: Option<crate::types::IncludeFields>,
+}
+
+impl<'a> MeetingRequest<'a> {
+    /// Set `page_size`.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Set `next_page_token`.
+    pub fn next_page_token(mut self, next_page_token: &str) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    /// Set `include_fields`.
+    pub fn include_fields(mut self, include_fields: crate::types::IncludeFields) -> Self {
+        self.include_fields = Some(include_fields);
+        self
+    }
+
+    /// Send the request.
+    pub async fn send(self) -> Result<crate::types::DashboardMeetingsResponseAllOf> {
+        self.api
+            .meeting(
+                self.type_,
+                self.from,
+                self.to,
+                self.page_size.unwrap_or_default(),
+                self.next_page_token.as_deref().unwrap_or_default(),
+                self.include_fields,
+            )
+            .await
+    }
+}
+
+/// A request to [`Dashboards::list_call_logs_metrics`], see [`Dashboards::list_call_logs_metrics_request`].
+pub struct ListCallLogsMetricsRequest<'a> {
+    api: &'a Dashboards,
+    from: Option<String>,
+    to: Option<String>,
+    site_id: Option<String>,
+    quality_type: Option<String>,
+    page_size: Option<i64>,
+    next_page_token: Option<String>,
+}
+
+impl<'a> ListCallLogsMetricsRequest<'a> {
+    /// Set `from`.
+    pub fn from(mut self, from: &str) -> Self {
+        self.from = Some(from.to_string());
+        self
+    }
+
+    /// Set `to`.
+    pub fn to(mut self, to: &str) -> Self {
+        self.to = Some(to.to_string());
+        self
+    }
+
+    /// Set `site_id`.
+    pub fn site_id(mut self, site_id: &str) -> Self {
+        self.site_id = Some(site_id.to_string());
+        self
+    }
+
+    /// Set `quality_type`.
+    pub fn quality_type(mut self, quality_type: &str) -> Self {
+        self.quality_type = Some(quality_type.to_string());
+        self
+    }
+
+    /// Set `page_size`.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Set `next_page_token`.
+    pub fn next_page_token(mut self, next_page_token: &str) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    /// Send the request.
+    pub async fn send(self) -> Result<Vec<crate::types::ListCallLogsMetricsResponse>> {
+        self.api
+            .list_call_logs_metrics(
+                self.from.as_deref().unwrap_or_default(),
+                self.to.as_deref().unwrap_or_default(),
+                self.site_id.as_deref().unwrap_or_default(),
+                self.quality_type.as_deref().unwrap_or_default(),
+                self.page_size.unwrap_or_default(),
+                self.next_page_token.as_deref().unwrap_or_default(),
+            )
+            .await
+    }
+}
+
+/// The functions of [`Dashboards`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn DashboardsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait DashboardsApi: Send + Sync {
+    async fn meeting(
+        &self,
+        type_: crate::types::DashboardMeetingsType,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+        include_fields: Option<crate::types::IncludeFields>,
+    ) -> Result<crate::types::DashboardMeetingsResponseAllOf>;
+    async fn meeting_detail(
+        &self,
+        meeting_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+    ) -> Result<crate::types::MeetingMetric>;
+    async fn meeting_participant(
+        &self,
+        meeting_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+        page_size: i64,
+        next_page_token: &str,
+        include_fields: Option<crate::types::DashboardMeetingParticipantsIncludeFields>,
+    ) -> Result<crate::types::DashboardMeetingParticipantsResponseAllOf>;
+    async fn meeting_participant_qo(
+        &self,
+        meeting_id: &str,
+        participant_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+    ) -> Result<crate::types::ParticipantQos>;
+    async fn meeting_participants_qo(
+        &self,
+        meeting_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::Domains>;
+    async fn meeting_participant_share(
+        &self,
+        meeting_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::DashboardMeetingParticipantShareResponseAllOf>;
+    async fn webinar(
+        &self,
+        type_: crate::types::DashboardWebinarsType,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::DashboardWebinarsResponseAllOf>;
+    async fn webinar_detail(
+        &self,
+        webinar_id: &str,
+        type_: crate::types::DashboardWebinarsType,
+    ) -> Result<crate::types::Webinars>;
+    async fn webinar_participant(
+        &self,
+        webinar_id: &str,
+        type_: crate::types::DashboardWebinarsType,
+        page_size: i64,
+        next_page_token: &str,
+        include_fields: Option<crate::types::DashboardMeetingParticipantsIncludeFields>,
+    ) -> Result<crate::types::DashboardWebinarParticipantsResponseAllOf>;
+    async fn webinar_participant_qo(
+        &self,
+        webinar_id: &str,
+        participant_id: &str,
+        type_: crate::types::DashboardWebinarsType,
+    ) -> Result<crate::types::ParticipantQos>;
+    async fn webinar_participants_qo(
+        &self,
+        webinar_id: &str,
+        type_: crate::types::DashboardWebinarsType,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::Domains>;
+    async fn webinar_participant_share(
+        &self,
+        webinar_id: &str,
+        type_: crate::types::DashboardWebinarsType,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::DashboardMeetingParticipantShareResponseAllOf>;
+    async fn zoom_room(
+        &self,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::Domains>;
+    async fn zoom_room_dashboards(
+        &self,
+        zoomroom_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::Domains>;
+    async fn crc(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<crate::types::Domains>;
+    async fn im(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::DashboardImResponseAllOf>;
+    async fn chat(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::DashboardChatResponseAllOf>;
+    async fn client_feedback(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<crate::types::DashboardClientFeedbackResponse>;
+    async fn zoom_room_issue(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<crate::types::Domains>;
+    async fn issue_zoom_room(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<crate::types::DashboardIssueZoomRoomResponseAllOf>;
+    async fn issue_detail_zoom_room(
+        &self,
+        zoomroom_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::DashboardIssueDetailZoomRoomResponseAllOf>;
+    async fn client_feedback_detail(
+        &self,
+        feedback_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::DashboardClientFeedbackDetailResponseAllOf>;
+    async fn list_meeting_satisfaction(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<crate::types::ListMeetingSatisfactionResponse>;
+    async fn list_call_logs_metrics(
+        &self,
+        from: &str,
+        to: &str,
+        site_id: &str,
+        quality_type: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::ListCallLogsMetricsResponse>>;
+    async fn list_call_logs_metrics_page(
+        &self,
+        from: &str,
+        to: &str,
+        site_id: &str,
+        quality_type: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::ListCallLogsMetricsResponse>, String)>;
+    async fn list_call_logs_metrics_between(
+        &self,
+        from: chrono::DateTime<chrono::Utc>,
+        to: chrono::DateTime<chrono::Utc>,
+        site_id: &str,
+        quality_type: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::ListCallLogsMetricsResponse>>;
+    async fn list_call_logs_metrics_between_page(
+        &self,
+        from: chrono::DateTime<chrono::Utc>,
+        to: chrono::DateTime<chrono::Utc>,
+        site_id: &str,
+        quality_type: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::ListCallLogsMetricsResponse>, String)>;
+    async fn list_all_call_logs_metrics(
+        &self,
+        from: &str,
+        to: &str,
+        site_id: &str,
+        quality_type: &str,
+    ) -> Result<Vec<crate::types::ListCallLogsMetricsResponse>>;
+    async fn get_call_log_metrics_details(
+        &self,
+        call_id: &str,
+    ) -> Result<crate::types::ListCallLogsMetricsResponse>;
+    async fn get_call_qo(&self, call_id: &str) -> Result<crate::types::GetCallQoSResponse>;
+    async fn participant_feedback(
+        &self,
+        meeting_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<Vec<crate::types::ParticipantFeedbackResponseParticipants>>;
+    async fn participant_feedback_page(
+        &self,
+        meeting_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<(
+        Vec<crate::types::ParticipantFeedbackResponseParticipants>,
+        String,
+    )>;
+    async fn get_all_participant_feedback(
+        &self,
+        meeting_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+    ) -> Result<Vec<crate::types::ParticipantFeedbackResponseParticipants>>;
+    async fn participant_webinar_feedback(
+        &self,
+        type_: crate::types::DashboardMeetingsType,
+        page_size: i64,
+        next_page_token: &str,
+        webinar_id: &str,
+    ) -> Result<Vec<crate::types::ParticipantFeedbackResponseParticipants>>;
+    async fn participant_webinar_feedback_page(
+        &self,
+        type_: crate::types::DashboardMeetingsType,
+        page_size: i64,
+        next_page_token: &str,
+        webinar_id: &str,
+    ) -> Result<(
+        Vec<crate::types::ParticipantFeedbackResponseParticipants>,
+        String,
+    )>;
+    async fn get_all_participant_webinar_feedback(
+        &self,
+        type_: crate::types::DashboardMeetingsType,
+        webinar_id: &str,
+    ) -> Result<Vec<crate::types::ParticipantFeedbackResponseParticipants>>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl DashboardsApi for Dashboards {
+    async fn meeting(
+        &self,
+        type_: crate::types::DashboardMeetingsType,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+        include_fields: Option<crate::types::IncludeFields>,
+    ) -> Result<crate::types::DashboardMeetingsResponseAllOf> {
+        Dashboards::meeting(
+            self,
+            type_,
+            from,
+            to,
+            page_size,
+            next_page_token,
+            include_fields,
+        )
+        .await
+    }
+    async fn meeting_detail(
+        &self,
+        meeting_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+    ) -> Result<crate::types::MeetingMetric> {
+        Dashboards::meeting_detail(self, meeting_id, type_).await
+    }
+    async fn meeting_participant(
+        &self,
+        meeting_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+        page_size: i64,
+        next_page_token: &str,
+        include_fields: Option<crate::types::DashboardMeetingParticipantsIncludeFields>,
+    ) -> Result<crate::types::DashboardMeetingParticipantsResponseAllOf> {
+        Dashboards::meeting_participant(
+            self,
+            meeting_id,
+            type_,
+            page_size,
+            next_page_token,
+            include_fields,
+        )
+        .await
+    }
+    async fn meeting_participant_qo(
+        &self,
+        meeting_id: &str,
+        participant_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+    ) -> Result<crate::types::ParticipantQos> {
+        Dashboards::meeting_participant_qo(self, meeting_id, participant_id, type_).await
+    }
+    async fn meeting_participants_qo(
+        &self,
+        meeting_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::Domains> {
+        Dashboards::meeting_participants_qo(self, meeting_id, type_, page_size, next_page_token)
+            .await
+    }
+    async fn meeting_participant_share(
+        &self,
+        meeting_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::DashboardMeetingParticipantShareResponseAllOf> {
+        Dashboards::meeting_participant_share(self, meeting_id, type_, page_size, next_page_token)
+            .await
+    }
+    async fn webinar(
+        &self,
+        type_: crate::types::DashboardWebinarsType,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::DashboardWebinarsResponseAllOf> {
+        Dashboards::webinar(self, type_, from, to, page_size, next_page_token).await
+    }
+    async fn webinar_detail(
+        &self,
+        webinar_id: &str,
+        type_: crate::types::DashboardWebinarsType,
+    ) -> Result<crate::types::Webinars> {
+        Dashboards::webinar_detail(self, webinar_id, type_).await
+    }
+    async fn webinar_participant(
+        &self,
+        webinar_id: &str,
+        type_: crate::types::DashboardWebinarsType,
+        page_size: i64,
+        next_page_token: &str,
+        include_fields: Option<crate::types::DashboardMeetingParticipantsIncludeFields>,
+    ) -> Result<crate::types::DashboardWebinarParticipantsResponseAllOf> {
+        Dashboards::webinar_participant(
+            self,
+            webinar_id,
+            type_,
+            page_size,
+            next_page_token,
+            include_fields,
+        )
+        .await
+    }
+    async fn webinar_participant_qo(
+        &self,
+        webinar_id: &str,
+        participant_id: &str,
+        type_: crate::types::DashboardWebinarsType,
+    ) -> Result<crate::types::ParticipantQos> {
+        Dashboards::webinar_participant_qo(self, webinar_id, participant_id, type_).await
+    }
+    async fn webinar_participants_qo(
+        &self,
+        webinar_id: &str,
+        type_: crate::types::DashboardWebinarsType,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::Domains> {
+        Dashboards::webinar_participants_qo(self, webinar_id, type_, page_size, next_page_token)
+            .await
+    }
+    async fn webinar_participant_share(
+        &self,
+        webinar_id: &str,
+        type_: crate::types::DashboardWebinarsType,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::DashboardMeetingParticipantShareResponseAllOf> {
+        Dashboards::webinar_participant_share(self, webinar_id, type_, page_size, next_page_token)
+            .await
+    }
+    async fn zoom_room(
+        &self,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::Domains> {
+        Dashboards::zoom_room(self, page_size, page_number, next_page_token).await
+    }
+    async fn zoom_room_dashboards(
+        &self,
+        zoomroom_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::Domains> {
+        Dashboards::zoom_room_dashboards(self, zoomroom_id, from, to, page_size, next_page_token)
+            .await
+    }
+    async fn crc(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<crate::types::Domains> {
+        Dashboards::crc(self, from, to).await
+    }
+    async fn im(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::DashboardImResponseAllOf> {
+        Dashboards::im(self, from, to, page_size, next_page_token).await
+    }
+    async fn chat(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::DashboardChatResponseAllOf> {
+        Dashboards::chat(self, from, to, page_size, next_page_token).await
+    }
+    async fn client_feedback(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<crate::types::DashboardClientFeedbackResponse> {
+        Dashboards::client_feedback(self, from, to).await
+    }
+    async fn zoom_room_issue(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<crate::types::Domains> {
+        Dashboards::zoom_room_issue(self, from, to).await
+    }
+    async fn issue_zoom_room(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<crate::types::DashboardIssueZoomRoomResponseAllOf> {
+        Dashboards::issue_zoom_room(self, from, to).await
+    }
+    async fn issue_detail_zoom_room(
+        &self,
+        zoomroom_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::DashboardIssueDetailZoomRoomResponseAllOf> {
+        Dashboards::issue_detail_zoom_room(self, zoomroom_id, from, to, page_size, next_page_token)
+            .await
+    }
+    async fn client_feedback_detail(
+        &self,
+        feedback_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::DashboardClientFeedbackDetailResponseAllOf> {
+        Dashboards::client_feedback_detail(self, feedback_id, from, to, page_size, next_page_token)
+            .await
+    }
+    async fn list_meeting_satisfaction(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<crate::types::ListMeetingSatisfactionResponse> {
+        Dashboards::list_meeting_satisfaction(self, from, to).await
+    }
+    async fn list_call_logs_metrics(
+        &self,
+        from: &str,
+        to: &str,
+        site_id: &str,
+        quality_type: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::ListCallLogsMetricsResponse>> {
+        Dashboards::list_call_logs_metrics(
+            self,
+            from,
+            to,
+            site_id,
+            quality_type,
+            page_size,
+            next_page_token,
+        )
+        .await
+    }
+    async fn list_call_logs_metrics_page(
+        &self,
+        from: &str,
+        to: &str,
+        site_id: &str,
+        quality_type: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::ListCallLogsMetricsResponse>, String)> {
+        Dashboards::list_call_logs_metrics_page(
+            self,
+            from,
+            to,
+            site_id,
+            quality_type,
+            page_size,
+            next_page_token,
+        )
+        .await
+    }
+    async fn list_call_logs_metrics_between(
+        &self,
+        from: chrono::DateTime<chrono::Utc>,
+        to: chrono::DateTime<chrono::Utc>,
+        site_id: &str,
+        quality_type: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::ListCallLogsMetricsResponse>> {
+        Dashboards::list_call_logs_metrics_between(
+            self,
+            from,
+            to,
+            site_id,
+            quality_type,
+            page_size,
+            next_page_token,
+        )
+        .await
+    }
+    async fn list_call_logs_metrics_between_page(
+        &self,
+        from: chrono::DateTime<chrono::Utc>,
+        to: chrono::DateTime<chrono::Utc>,
+        site_id: &str,
+        quality_type: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::ListCallLogsMetricsResponse>, String)> {
+        Dashboards::list_call_logs_metrics_between_page(
+            self,
+            from,
+            to,
+            site_id,
+            quality_type,
+            page_size,
+            next_page_token,
+        )
+        .await
+    }
+    async fn list_all_call_logs_metrics(
+        &self,
+        from: &str,
+        to: &str,
+        site_id: &str,
+        quality_type: &str,
+    ) -> Result<Vec<crate::types::ListCallLogsMetricsResponse>> {
+        Dashboards::list_all_call_logs_metrics(self, from, to, site_id, quality_type).await
+    }
+    async fn get_call_log_metrics_details(
+        &self,
+        call_id: &str,
+    ) -> Result<crate::types::ListCallLogsMetricsResponse> {
+        Dashboards::get_call_log_metrics_details(self, call_id).await
+    }
+    async fn get_call_qo(&self, call_id: &str) -> Result<crate::types::GetCallQoSResponse> {
+        Dashboards::get_call_qo(self, call_id).await
+    }
+    async fn participant_feedback(
+        &self,
+        meeting_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<Vec<crate::types::ParticipantFeedbackResponseParticipants>> {
+        Dashboards::participant_feedback(self, meeting_id, type_, next_page_token, page_size).await
+    }
+    async fn participant_feedback_page(
+        &self,
+        meeting_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<(
+        Vec<crate::types::ParticipantFeedbackResponseParticipants>,
+        String,
+    )> {
+        Dashboards::participant_feedback_page(self, meeting_id, type_, next_page_token, page_size)
+            .await
+    }
+    async fn get_all_participant_feedback(
+        &self,
+        meeting_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+    ) -> Result<Vec<crate::types::ParticipantFeedbackResponseParticipants>> {
+        Dashboards::get_all_participant_feedback(self, meeting_id, type_).await
+    }
+    async fn participant_webinar_feedback(
+        &self,
+        type_: crate::types::DashboardMeetingsType,
+        page_size: i64,
+        next_page_token: &str,
+        webinar_id: &str,
+    ) -> Result<Vec<crate::types::ParticipantFeedbackResponseParticipants>> {
+        Dashboards::participant_webinar_feedback(
+            self,
+            type_,
+            page_size,
+            next_page_token,
+            webinar_id,
+        )
+        .await
+    }
+    async fn participant_webinar_feedback_page(
+        &self,
+        type_: crate::types::DashboardMeetingsType,
+        page_size: i64,
+        next_page_token: &str,
+        webinar_id: &str,
+    ) -> Result<(
+        Vec<crate::types::ParticipantFeedbackResponseParticipants>,
+        String,
+    )> {
+        Dashboards::participant_webinar_feedback_page(
+            self,
+            type_,
+            page_size,
+            next_page_token,
+            webinar_id,
+        )
+        .await
+    }
+    async fn get_all_participant_webinar_feedback(
+        &self,
+        type_: crate::types::DashboardMeetingsType,
+        webinar_id: &str,
+    ) -> Result<Vec<crate::types::ParticipantFeedbackResponseParticipants>> {
+        Dashboards::get_all_participant_webinar_feedback(self, type_, webinar_id).await
+    }
 }
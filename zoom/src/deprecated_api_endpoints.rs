@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct DeprecatedApiEndpoints {
     pub client: Client,
@@ -12,6 +10,17 @@ impl DeprecatedApiEndpoints {
         DeprecatedApiEndpoints { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        DeprecatedApiEndpoints {
+            client: self.client.with_retry(policy),
+        }
+    }
+
+    /// The OAuth scopes for `list_past_meeting_files`, any one of them grants access.
+    pub const LIST_PAST_MEETING_FILES_SCOPES: &[&str] = &["meeting:read", "meeting:read:admin"];
+
     /**
      * List past meeting's files.
      *
@@ -37,12 +46,21 @@ impl DeprecatedApiEndpoints {
     ) -> Result<crate::types::ListPastMeetingFilesResponse> {
         let url = format!(
             "/past_meetings/{}/files",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
+            self.client.encode_path(meeting_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/past_meetings/{meetingId}/files",
+                &[("meetingId", meeting_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `list_past_webinar_files`, any one of them grants access.
+    pub const LIST_PAST_WEBINAR_FILES_SCOPES: &[&str] = &["webinar:read", "webinar:read:admin"];
+
     /**
      * List past webinar files.
      *
@@ -68,9 +86,48 @@ impl DeprecatedApiEndpoints {
     ) -> Result<crate::types::ListPastMeetingFilesResponse> {
         let url = format!(
             "/past_webinars/{}/files",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
+            self.client.encode_path(webinar_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/past_webinars/{webinarId}/files",
+                &[("webinarId", webinar_id.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`DeprecatedApiEndpoints`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn DeprecatedApiEndpointsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait DeprecatedApiEndpointsApi: Send + Sync {
+    async fn list_past_meeting_files(
+        &self,
+        meeting_id: &str,
+    ) -> Result<crate::types::ListPastMeetingFilesResponse>;
+    async fn list_past_webinar_files(
+        &self,
+        webinar_id: &str,
+    ) -> Result<crate::types::ListPastMeetingFilesResponse>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl DeprecatedApiEndpointsApi for DeprecatedApiEndpoints {
+    async fn list_past_meeting_files(
+        &self,
+        meeting_id: &str,
+    ) -> Result<crate::types::ListPastMeetingFilesResponse> {
+        DeprecatedApiEndpoints::list_past_meeting_files(self, meeting_id).await
+    }
+    async fn list_past_webinar_files(
+        &self,
+        webinar_id: &str,
+    ) -> Result<crate::types::ListPastMeetingFilesResponse> {
+        DeprecatedApiEndpoints::list_past_webinar_files(self, webinar_id).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Devices {
     pub client: Client,
@@ -12,6 +10,14 @@ impl Devices {
         Devices { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Devices {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * List H.323/SIP devices.
      *
@@ -37,20 +43,23 @@ impl Devices {
         page_number: i64,
         next_page_token: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_number > 0 {
-            query_args.push(("page_number".to_string(), page_number.to_string()));
+            query_args.push(("page_number", page_number.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/h323/devices?{}", query_);
+        let url = crate::Client::url_with_query("/h323/devices", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/h323/devices", &[]))
     }
 
     /**
@@ -66,8 +75,9 @@ impl Devices {
     pub async fn create(&self, body: &crate::types::Device) -> Result<()> {
         let url = "/h323/devices".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/h323/devices", &[]))
     }
 
     /**
@@ -85,12 +95,37 @@ impl Devices {
      * * `device_id: &str` -- User's first name.
      */
     pub async fn delete(&self, device_id: &str) -> Result<()> {
-        let url = format!(
-            "/h323/devices/{}",
-            crate::progenitor_support::encode_path(&device_id.to_string()),
-        );
+        let url = format!("/h323/devices/{}", self.client.encode_path(device_id),);
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/h323/devices/{deviceId}",
+                &[("deviceId", device_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete a H.323/SIP device.
+     *
+     * This function performs a `DELETE` to the `/h323/devices/{deviceId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, device_id: &str) -> Result<bool> {
+        let url = format!("/h323/devices/{}", self.client.encode_path(device_id),);
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/h323/devices/{deviceId}",
+                    &[("deviceId", device_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -108,13 +143,87 @@ impl Devices {
      * * `device_id: &str` -- User's first name.
      */
     pub async fn update(&self, device_id: &str, body: &crate::types::Device) -> Result<()> {
-        let url = format!(
-            "/h323/devices/{}",
-            crate::progenitor_support::encode_path(&device_id.to_string()),
-        );
+        let url = format!("/h323/devices/{}", self.client.encode_path(device_id),);
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/h323/devices/{deviceId}",
+                    &[("deviceId", device_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update a H.323/SIP device.
+     *
+     * This function performs a `PATCH` to the `/h323/devices/{deviceId}` endpoint.
+     *
+     * As opposed to `update`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn update_if_changed<C: serde::Serialize>(
+        &self,
+        device_id: &str,
+        current: &C,
+        body: &crate::types::Device,
+    ) -> Result<Option<()>> {
+        let url = format!("/h323/devices/{}", self.client.encode_path(device_id),);
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/h323/devices/{deviceId}",
+                    &[("deviceId", device_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`Devices`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn DevicesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait DevicesApi: Send + Sync {
+    async fn list(
+        &self,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::Domains>;
+    async fn create(&self, body: &crate::types::Device) -> Result<()>;
+    async fn delete(&self, device_id: &str) -> Result<()>;
+    async fn delete_if_exists(&self, device_id: &str) -> Result<bool>;
+    async fn update(&self, device_id: &str, body: &crate::types::Device) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl DevicesApi for Devices {
+    async fn list(
+        &self,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::Domains> {
+        Devices::list(self, page_size, page_number, next_page_token).await
+    }
+    async fn create(&self, body: &crate::types::Device) -> Result<()> {
+        Devices::create(self, body).await
+    }
+    async fn delete(&self, device_id: &str) -> Result<()> {
+        Devices::delete(self, device_id).await
+    }
+    async fn delete_if_exists(&self, device_id: &str) -> Result<bool> {
+        Devices::delete_if_exists(self, device_id).await
+    }
+    async fn update(&self, device_id: &str, body: &crate::types::Device) -> Result<()> {
+        Devices::update(self, device_id, body).await
     }
 }
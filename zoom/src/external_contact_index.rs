@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+
+/// The external contacts of an account, by name, to look one up before
+/// updating or deleting it by its `external_contact_id`.
+///
+/// Names are not unique, so each name keeps all of the contacts that have it,
+/// in the order they were listed.
+#[derive(Clone, Debug, Default)]
+pub struct ExternalContactIndex {
+    by_name: HashMap<String, Vec<crate::types::ExternalContacts>>,
+}
+
+impl ExternalContactIndex {
+    /// Index `contacts`, say all of them as `Phone::list_all_external_contacts`
+    /// returns them.
+    pub fn new(contacts: Vec<crate::types::ExternalContacts>) -> Self {
+        let mut by_name: HashMap<String, Vec<crate::types::ExternalContacts>> = HashMap::new();
+        for contact in contacts {
+            by_name
+                .entry(contact.name.to_string())
+                .or_default()
+                .push(contact);
+        }
+        ExternalContactIndex { by_name }
+    }
+
+    /// The first contact listed with the name, if any.
+    pub fn get(&self, name: &str) -> Option<&crate::types::ExternalContacts> {
+        self.get_all(name).first()
+    }
+
+    /// All the contacts with the name, for names more than one contact has.
+    pub fn get_all(&self, name: &str) -> &[crate::types::ExternalContacts] {
+        self.by_name
+            .get(name)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Iterate over the names and the contacts that have them, in no order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &[crate::types::ExternalContacts])> {
+        self.by_name
+            .iter()
+            .map(|(name, contacts)| (name.as_str(), contacts.as_slice()))
+    }
+}
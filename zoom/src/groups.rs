@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Groups {
     pub client: Client,
@@ -12,6 +10,17 @@ impl Groups {
         Groups { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Groups {
+            client: self.client.with_retry(policy),
+        }
+    }
+
+    /// The OAuth scopes for `get`, any one of them grants access.
+    pub const GET_SCOPES: &[&str] = &["group:read:admin"];
+
     /**
      * List groups.
      *
@@ -26,9 +35,15 @@ impl Groups {
      */
     pub async fn get(&self) -> Result<crate::types::GroupList> {
         let url = "/groups".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/groups", &[]))
     }
 
+    /// The OAuth scopes for `create`, any one of them grants access.
+    pub const CREATE_SCOPES: &[&str] = &["group:write:admin"];
+
     /**
      * Create a group.
      *
@@ -46,10 +61,14 @@ impl Groups {
     pub async fn create(&self, body: &crate::types::GroupCreateRequest) -> Result<()> {
         let url = "/groups".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/groups", &[]))
     }
 
+    /// The OAuth scopes for `group`, any one of them grants access.
+    pub const GROUP_SCOPES: &[&str] = &["group:read:admin"];
+
     /**
      * Get a group.
      *
@@ -68,14 +87,20 @@ impl Groups {
      *   Can be retrieved by calling [GET /groups](https://marketplace.zoom.us/docs/api-reference/zoom-api/groups/groups).
      */
     pub async fn group(&self, group_id: &str) -> Result<crate::types::GroupResponse> {
-        let url = format!(
-            "/groups/{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-        );
+        let url = format!("/groups/{}", self.client.encode_path(group_id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/groups/{groupId}",
+                &[("groupId", group_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `delete`, any one of them grants access.
+    pub const DELETE_SCOPES: &[&str] = &["group:write:admin"];
+
     /**
      * Delete a group.
      *
@@ -94,14 +119,42 @@ impl Groups {
      *   Can be retrieved by calling [GET /groups](https://marketplace.zoom.us/docs/api-reference/zoom-api/groups/groups).
      */
     pub async fn delete(&self, group_id: &str) -> Result<()> {
-        let url = format!(
-            "/groups/{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-        );
+        let url = format!("/groups/{}", self.client.encode_path(group_id),);
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/groups/{groupId}",
+                &[("groupId", group_id.to_string())],
+            )
+        })
     }
 
+    /**
+     * Delete a group.
+     *
+     * This function performs a `DELETE` to the `/groups/{groupId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, group_id: &str) -> Result<bool> {
+        let url = format!("/groups/{}", self.client.encode_path(group_id),);
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/groups/{groupId}",
+                    &[("groupId", group_id.to_string())],
+                )
+            })
+    }
+
+    /// The OAuth scopes for `update`, any one of them grants access.
+    pub const UPDATE_SCOPES: &[&str] = &["group:write:admin"];
+
     /**
      * Update a group.
      *
@@ -124,16 +177,50 @@ impl Groups {
         group_id: &str,
         body: &crate::types::GroupCreateRequest,
     ) -> Result<()> {
-        let url = format!(
-            "/groups/{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-        );
+        let url = format!("/groups/{}", self.client.encode_path(group_id),);
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/groups/{groupId}",
+                    &[("groupId", group_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update a group.
+     *
+     * This function performs a `PATCH` to the `/groups/{groupId}` endpoint.
+     *
+     * As opposed to `update`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn update_if_changed<C: serde::Serialize>(
+        &self,
+        group_id: &str,
+        current: &C,
+        body: &crate::types::GroupCreateRequest,
+    ) -> Result<Option<()>> {
+        let url = format!("/groups/{}", self.client.encode_path(group_id),);
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/groups/{groupId}",
+                    &[("groupId", group_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `members`, any one of them grants access.
+    pub const MEMBERS_SCOPES: &[&str] = &["group:read:admin"];
+
     /**
      * List group members .
      *
@@ -164,29 +251,78 @@ impl Groups {
         page_number: i64,
         next_page_token: &str,
     ) -> Result<Vec<crate::types::UserCreateResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_number > 0 {
-            query_args.push(("page_number".to_string(), page_number.to_string()));
+            query_args.push(("page_number", page_number.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/groups/{}/members?{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/groups/{}/members", self.client.encode_path(group_id)),
+            &query_args,
         );
 
-        let resp: crate::types::GroupMembersResponseData = self.client.get(&url, None).await?;
+        let resp: crate::types::GroupMembersResponseData =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/groups/{groupId}/members",
+                    &[("groupId", group_id.to_string())],
+                )
+            })?;
 
         // Return our response data.
         Ok(resp.members)
     }
 
+    /**
+     * List group members .
+     *
+     * This function performs a `GET` to the `/groups/{groupId}/members` endpoint.
+     *
+     * As opposed to `members`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
+     */
+    pub async fn members_page(
+        &self,
+        group_id: &str,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::UserCreateResponse>, String)> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        if page_number > 0 {
+            query_args.push(("page_number", page_number.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!("/groups/{}/members", self.client.encode_path(group_id)),
+            &query_args,
+        );
+
+        let resp: crate::types::GroupMembersResponseData =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/groups/{groupId}/members",
+                    &[("groupId", group_id.to_string())],
+                )
+            })?;
+
+        // Return our response data, and where the next page starts.
+        Ok((resp.members, resp.next_page_token))
+    }
+
     /**
      * List group members .
      *
@@ -205,12 +341,16 @@ impl Groups {
         &self,
         group_id: &str,
     ) -> Result<Vec<crate::types::UserCreateResponse>> {
-        let url = format!(
-            "/groups/{}/members",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-        );
+        let url = format!("/groups/{}/members", self.client.encode_path(group_id),);
 
-        let mut resp: crate::types::GroupMembersResponseData = self.client.get(&url, None).await?;
+        let mut resp: crate::types::GroupMembersResponseData =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/groups/{groupId}/members",
+                    &[("groupId", group_id.to_string())],
+                )
+            })?;
 
         let mut members = resp.members;
         let mut page = resp.next_page_token;
@@ -243,6 +383,72 @@ impl Groups {
         Ok(members)
     }
 
+    /**
+     * List group members .
+     *
+     * This function performs a `GET` to the `/groups/{groupId}/members` endpoint.
+     *
+     * As opposed to `members`, this function streams the items of all the pages, getting the next page only once the items of the last one are used up. The stream ends after the last page.
+     */
+    pub async fn members_stream(
+        &self,
+        group_id: &str,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::UserCreateResponse>>> {
+        let url = format!("/groups/{}/members", self.client.encode_path(group_id),);
+
+        self.client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::GroupMembersResponseData| (resp.members, resp.next_page_token),
+            )
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/groups/{groupId}/members",
+                    &[("groupId", group_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * List group members .
+     *
+     * This function performs a `GET` to the `/groups/{groupId}/members` endpoint.
+     *
+     * As opposed to `members_stream`, this function streams only the items `predicate` keeps. It too gets the next page only once the items of the last one are used up, so a stream that is cut short, for example with `take`, gets no more pages than it needs.
+     */
+    pub async fn members_filter<P: FnMut(&crate::types::UserCreateResponse) -> bool>(
+        &self,
+        group_id: &str,
+        mut predicate: P,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::UserCreateResponse>>> {
+        let url = format!("/groups/{}/members", self.client.encode_path(group_id),);
+
+        let items = self
+            .client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::GroupMembersResponseData| (resp.members, resp.next_page_token),
+            )
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/groups/{groupId}/members",
+                    &[("groupId", group_id.to_string())],
+                )
+            })?;
+        Ok(futures::TryStreamExt::try_filter(items, move |item| {
+            futures::future::ready(predicate(item))
+        }))
+    }
+
+    /// The OAuth scopes for `members_create`, any one of them grants access.
+    pub const MEMBERS_CREATE_SCOPES: &[&str] = &["group:write:admin"];
+
     /**
      * Add group members.
      *
@@ -265,16 +471,23 @@ impl Groups {
         group_id: &str,
         body: &crate::types::AddRoleMembersRequest,
     ) -> Result<()> {
-        let url = format!(
-            "/groups/{}/members",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-        );
+        let url = format!("/groups/{}/members", self.client.encode_path(group_id),);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/groups/{groupId}/members",
+                    &[("groupId", group_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `members_delete`, any one of them grants access.
+    pub const MEMBERS_DELETE_SCOPES: &[&str] = &["group:write:admin"];
+
     /**
      * Delete a group member.
      *
@@ -296,13 +509,54 @@ impl Groups {
     pub async fn members_delete(&self, group_id: &str, member_id: &str) -> Result<()> {
         let url = format!(
             "/groups/{}/members/{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-            crate::progenitor_support::encode_path(&member_id.to_string()),
+            self.client.encode_path(group_id),
+            self.client.encode_path(member_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/groups/{groupId}/members/{memberId}",
+                &[
+                    ("groupId", group_id.to_string()),
+                    ("memberId", member_id.to_string()),
+                ],
+            )
+        })
     }
 
+    /**
+     * Delete a group member.
+     *
+     * This function performs a `DELETE` to the `/groups/{groupId}/members/{memberId}` endpoint.
+     *
+     * As opposed to `members_delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn members_delete_if_exists(&self, group_id: &str, member_id: &str) -> Result<bool> {
+        let url = format!(
+            "/groups/{}/members/{}",
+            self.client.encode_path(group_id),
+            self.client.encode_path(member_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/groups/{groupId}/members/{memberId}",
+                    &[
+                        ("groupId", group_id.to_string()),
+                        ("memberId", member_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The OAuth scopes for `update_member`, any one of them grants access.
+    pub const UPDATE_MEMBER_SCOPES: &[&str] = &["group:write:admin"];
+
     /**
      * Update a group member.
      *
@@ -334,13 +588,58 @@ impl Groups {
     ) -> Result<()> {
         let url = format!(
             "/groups/{}/members/{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-            crate::progenitor_support::encode_path(&member_id.to_string()),
+            self.client.encode_path(group_id),
+            self.client.encode_path(member_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/groups/{groupId}/members/{memberId}",
+                    &[
+                        ("groupId", group_id.to_string()),
+                        ("memberId", member_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * Update a group member.
+     *
+     * This function performs a `PATCH` to the `/groups/{groupId}/members/{memberId}` endpoint.
+     *
+     * As opposed to `update_member`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn update_member_if_changed<C: serde::Serialize>(
+        &self,
+        group_id: &str,
+        member_id: &str,
+        current: &C,
+        body: &crate::types::UpdateGroupMemberRequest,
+    ) -> Result<Option<()>> {
+        let url = format!(
+            "/groups/{}/members/{}",
+            self.client.encode_path(group_id),
+            self.client.encode_path(member_id),
+        );
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/groups/{groupId}/members/{memberId}",
+                    &[
+                        ("groupId", group_id.to_string()),
+                        ("memberId", member_id.to_string()),
+                    ],
+                )
+            })
     }
 
     /**
@@ -356,36 +655,37 @@ impl Groups {
      *
      * **Parameters:**
      *
-     * * `option: crate::types::OptionData` -- Use the following options to filter the results of the account's information:
+     * * `option: Option<crate::types::OptionData>` -- Use the following options to filter the results of the account's information:
      *  \* `meeting_authentication` — View the account's [meeting authentication settings](https://support.zoom.us/hc/en-us/articles/360037117472-Authentication-Profiles-for-Meetings-and-Webinars).
      *  \* `recording_authentication` — View the account's [recording authentication settings](https://support.zoom.us/hc/en-us/articles/360037756671-Authentication-Profiles-for-Cloud-Recordings).
      *  \* `security` — View the account's security settings. For example, password requirements for user login or two-factor authentication.<br>
-     *  \* `meeting_security` — View the account's meeting security settings.
+     *  \* `meeting_security` — View the account's meeting security settings. `None` leaves it off the query, for the API's default.
      */
     pub async fn get_settings_domains(
         &self,
         group_id: &str,
         custom_query_fields: &str,
-        option: crate::types::OptionData,
+        option: Option<crate::types::OptionData>,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_query_fields.is_empty() {
-            query_args.push((
-                "custom_query_fields".to_string(),
-                custom_query_fields.to_string(),
-            ));
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
         }
-        if !option.to_string().is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+        if let Some(v) = option {
+            query_args.push(("option", v.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/groups/{}/settings?{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/groups/{}/settings", self.client.encode_path(group_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/groups/{groupId}/settings",
+                &[("groupId", group_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -401,36 +701,37 @@ impl Groups {
      *
      * **Parameters:**
      *
-     * * `option: crate::types::OptionData` -- Use the following options to filter the results of the account's information:
+     * * `option: Option<crate::types::OptionData>` -- Use the following options to filter the results of the account's information:
      *  \* `meeting_authentication` — View the account's [meeting authentication settings](https://support.zoom.us/hc/en-us/articles/360037117472-Authentication-Profiles-for-Meetings-and-Webinars).
      *  \* `recording_authentication` — View the account's [recording authentication settings](https://support.zoom.us/hc/en-us/articles/360037756671-Authentication-Profiles-for-Cloud-Recordings).
      *  \* `security` — View the account's security settings. For example, password requirements for user login or two-factor authentication.<br>
-     *  \* `meeting_security` — View the account's meeting security settings.
+     *  \* `meeting_security` — View the account's meeting security settings. `None` leaves it off the query, for the API's default.
      */
     pub async fn get_settings_meeting_security(
         &self,
         group_id: &str,
         custom_query_fields: &str,
-        option: crate::types::OptionData,
+        option: Option<crate::types::OptionData>,
     ) -> Result<crate::types::MeetingSecuritySettings> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_query_fields.is_empty() {
-            query_args.push((
-                "custom_query_fields".to_string(),
-                custom_query_fields.to_string(),
-            ));
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
         }
-        if !option.to_string().is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+        if let Some(v) = option {
+            query_args.push(("option", v.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/groups/{}/settings?{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/groups/{}/settings", self.client.encode_path(group_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/groups/{groupId}/settings",
+                &[("groupId", group_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -446,38 +747,42 @@ impl Groups {
      *
      * **Parameters:**
      *
-     * * `option: crate::types::OptionData` -- Use the following options to filter the results of the account's information:
+     * * `option: Option<crate::types::OptionData>` -- Use the following options to filter the results of the account's information:
      *  \* `meeting_authentication` — View the account's [meeting authentication settings](https://support.zoom.us/hc/en-us/articles/360037117472-Authentication-Profiles-for-Meetings-and-Webinars).
      *  \* `recording_authentication` — View the account's [recording authentication settings](https://support.zoom.us/hc/en-us/articles/360037756671-Authentication-Profiles-for-Cloud-Recordings).
      *  \* `security` — View the account's security settings. For example, password requirements for user login or two-factor authentication.<br>
-     *  \* `meeting_security` — View the account's meeting security settings.
+     *  \* `meeting_security` — View the account's meeting security settings. `None` leaves it off the query, for the API's default.
      */
     pub async fn get_settings_group_response(
         &self,
         group_id: &str,
         custom_query_fields: &str,
-        option: crate::types::OptionData,
+        option: Option<crate::types::OptionData>,
     ) -> Result<crate::types::GetGroupSettingsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_query_fields.is_empty() {
-            query_args.push((
-                "custom_query_fields".to_string(),
-                custom_query_fields.to_string(),
-            ));
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
         }
-        if !option.to_string().is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+        if let Some(v) = option {
+            query_args.push(("option", v.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/groups/{}/settings?{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/groups/{}/settings", self.client.encode_path(group_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/groups/{groupId}/settings",
+                &[("groupId", group_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `get_setting`, any one of them grants access.
+    pub const GET_SETTING_SCOPES: &[&str] = &["group:read:admin"];
+
     /**
      * Get a group's settings.
      *
@@ -491,38 +796,42 @@ impl Groups {
      *
      * **Parameters:**
      *
-     * * `option: crate::types::OptionData` -- Use the following options to filter the results of the account's information:
+     * * `option: Option<crate::types::OptionData>` -- Use the following options to filter the results of the account's information:
      *  \* `meeting_authentication` — View the account's [meeting authentication settings](https://support.zoom.us/hc/en-us/articles/360037117472-Authentication-Profiles-for-Meetings-and-Webinars).
      *  \* `recording_authentication` — View the account's [recording authentication settings](https://support.zoom.us/hc/en-us/articles/360037756671-Authentication-Profiles-for-Cloud-Recordings).
      *  \* `security` — View the account's security settings. For example, password requirements for user login or two-factor authentication.<br>
-     *  \* `meeting_security` — View the account's meeting security settings.
+     *  \* `meeting_security` — View the account's meeting security settings. `None` leaves it off the query, for the API's default.
      */
     pub async fn get_setting(
         &self,
         group_id: &str,
         custom_query_fields: &str,
-        option: crate::types::OptionData,
+        option: Option<crate::types::OptionData>,
     ) -> Result<crate::types::GetGroupSettingsResponseOneOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_query_fields.is_empty() {
-            query_args.push((
-                "custom_query_fields".to_string(),
-                custom_query_fields.to_string(),
-            ));
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
         }
-        if !option.to_string().is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+        if let Some(v) = option {
+            query_args.push(("option", v.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/groups/{}/settings?{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/groups/{}/settings", self.client.encode_path(group_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/groups/{groupId}/settings",
+                &[("groupId", group_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `update_settings`, any one of them grants access.
+    pub const UPDATE_SETTINGS_SCOPES: &[&str] = &["group:write:admin"];
+
     /**
      * Update a group's settings.
      *
@@ -537,35 +846,76 @@ impl Groups {
      * **Parameters:**
      *
      * * `group_id: &str` -- User's first name.
-     * * `option: crate::types::UpdateGroupSettingsOption`
+     * * `option: Option<crate::types::UpdateGroupSettingsOption>` -- `None` leaves it off the query, for the API's default.
      */
     pub async fn update_settings(
         &self,
         group_id: &str,
         custom_query_fields: &str,
-        option: crate::types::UpdateGroupSettingsOption,
+        option: Option<crate::types::UpdateGroupSettingsOption>,
         body: &crate::types::UpdateGroupSettingsRequestOneOf,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_query_fields.is_empty() {
-            query_args.push((
-                "custom_query_fields".to_string(),
-                custom_query_fields.to_string(),
-            ));
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
         }
-        if !option.to_string().is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+        if let Some(v) = option {
+            query_args.push(("option", v.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/groups/{}/settings?{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/groups/{}/settings", self.client.encode_path(group_id)),
+            &query_args,
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/groups/{groupId}/settings",
+                    &[("groupId", group_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update a group's settings.
+     *
+     * This function performs a `PATCH` to the `/groups/{groupId}/settings` endpoint.
+     *
+     * As opposed to `update_settings`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn update_settings_if_changed<C: serde::Serialize>(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: Option<crate::types::UpdateGroupSettingsOption>,
+        current: &C,
+        body: &crate::types::UpdateGroupSettingsRequestOneOf,
+    ) -> Result<Option<()>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !custom_query_fields.is_empty() {
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
+        }
+        if let Some(v) = option {
+            query_args.push(("option", v.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!("/groups/{}/settings", self.client.encode_path(group_id)),
+            &query_args,
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/groups/{groupId}/settings",
+                    &[("groupId", group_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -591,24 +941,28 @@ impl Groups {
         custom_query_fields: &str,
         option: &str,
     ) -> Result<crate::types::MeetingSecuritySettings> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_query_fields.is_empty() {
-            query_args.push((
-                "custom_query_fields".to_string(),
-                custom_query_fields.to_string(),
-            ));
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
         }
         if !option.is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+            query_args.push(("option", option.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/groups/{}/lock_settings?{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/groups/{}/lock_settings",
+                self.client.encode_path(group_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/groups/{groupId}/lock_settings",
+                &[("groupId", group_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -634,26 +988,33 @@ impl Groups {
         custom_query_fields: &str,
         option: &str,
     ) -> Result<crate::types::GetGroupLockSettingsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_query_fields.is_empty() {
-            query_args.push((
-                "custom_query_fields".to_string(),
-                custom_query_fields.to_string(),
-            ));
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
         }
         if !option.is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+            query_args.push(("option", option.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/groups/{}/lock_settings?{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/groups/{}/lock_settings",
+                self.client.encode_path(group_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/groups/{groupId}/lock_settings",
+                &[("groupId", group_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `get_lock_setting`, any one of them grants access.
+    pub const GET_LOCK_SETTING_SCOPES: &[&str] = &["group:read:admin"];
+
     /**
      * Get locked settings.
      *
@@ -677,26 +1038,33 @@ impl Groups {
         custom_query_fields: &str,
         option: &str,
     ) -> Result<crate::types::GetGroupLockSettingsResponseOneOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_query_fields.is_empty() {
-            query_args.push((
-                "custom_query_fields".to_string(),
-                custom_query_fields.to_string(),
-            ));
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
         }
         if !option.is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+            query_args.push(("option", option.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/groups/{}/lock_settings?{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/groups/{}/lock_settings",
+                self.client.encode_path(group_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/groups/{groupId}/lock_settings",
+                &[("groupId", group_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `locked_settings`, any one of them grants access.
+    pub const LOCKED_SETTINGS_SCOPES: &[&str] = &["group:write:admin"];
+
     /**
      * Update locked settings.
      *
@@ -721,28 +1089,78 @@ impl Groups {
         option: &str,
         body: &crate::types::GroupLockedSettingsRequestOneOf,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_query_fields.is_empty() {
-            query_args.push((
-                "custom_query_fields".to_string(),
-                custom_query_fields.to_string(),
-            ));
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
         }
         if !option.is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+            query_args.push(("option", option.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/groups/{}/lock_settings?{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/groups/{}/lock_settings",
+                self.client.encode_path(group_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/groups/{groupId}/lock_settings",
+                    &[("groupId", group_id.to_string())],
+                )
+            })
     }
 
+    /**
+     * Update locked settings.
+     *
+     * This function performs a `PATCH` to the `/groups/{groupId}/lock_settings` endpoint.
+     *
+     * As opposed to `locked_settings`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn locked_settings_if_changed<C: serde::Serialize>(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: &str,
+        current: &C,
+        body: &crate::types::GroupLockedSettingsRequestOneOf,
+    ) -> Result<Option<()>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !custom_query_fields.is_empty() {
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
+        }
+        if !option.is_empty() {
+            query_args.push(("option", option.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/groups/{}/lock_settings",
+                self.client.encode_path(group_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/groups/{groupId}/lock_settings",
+                    &[("groupId", group_id.to_string())],
+                )
+            })
+    }
+
+    /// The OAuth scopes for `upload_vb`, any one of them grants access.
+    pub const UPLOAD_VB_SCOPES: &[&str] = &["group:write:admin"];
+
     /**
      * Upload virtual background files.
      *
@@ -769,22 +1187,33 @@ impl Groups {
         group_id: &str,
         body: &crate::types::UploadVbRequest,
     ) -> Result<crate::types::Files> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !file_ids.is_empty() {
-            query_args.push(("file_ids".to_string(), file_ids.to_string()));
+            query_args.push(("file_ids", file_ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/groups/{}/settings/virtual_backgrounds?{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/groups/{}/settings/virtual_backgrounds",
+                self.client.encode_path(group_id)
+            ),
+            &query_args,
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/groups/{groupId}/settings/virtual_backgrounds",
+                    &[("groupId", group_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `del_vb`, any one of them grants access.
+    pub const DEL_VB_SCOPES: &[&str] = &["group:write:admin"];
+
     /**
      * Delete virtual background files.
      *
@@ -805,17 +1234,322 @@ impl Groups {
      * * `file_ids: &str` -- Provide the id of the file that is to be deleted. To delete multiple files, provide comma separated values for this field.
      */
     pub async fn del_vb(&self, file_ids: &str, group_id: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !file_ids.is_empty() {
-            query_args.push(("file_ids".to_string(), file_ids.to_string()));
+            query_args.push(("file_ids", file_ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/groups/{}/settings/virtual_backgrounds?{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/groups/{}/settings/virtual_backgrounds",
+                self.client.encode_path(group_id)
+            ),
+            &query_args,
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/groups/{groupId}/settings/virtual_backgrounds",
+                &[("groupId", group_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete virtual background files.
+     *
+     * This function performs a `DELETE` to the `/groups/{groupId}/settings/virtual_backgrounds` endpoint.
+     *
+     * As opposed to `del_vb`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn del_vb_if_exists(&self, file_ids: &str, group_id: &str) -> Result<bool> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !file_ids.is_empty() {
+            query_args.push(("file_ids", file_ids.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/groups/{}/settings/virtual_backgrounds",
+                self.client.encode_path(group_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/groups/{groupId}/settings/virtual_backgrounds",
+                    &[("groupId", group_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`Groups`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn GroupsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait GroupsApi: Send + Sync {
+    async fn get(&self) -> Result<crate::types::GroupList>;
+    async fn create(&self, body: &crate::types::GroupCreateRequest) -> Result<()>;
+    async fn group(&self, group_id: &str) -> Result<crate::types::GroupResponse>;
+    async fn delete(&self, group_id: &str) -> Result<()>;
+    async fn delete_if_exists(&self, group_id: &str) -> Result<bool>;
+    async fn update(&self, group_id: &str, body: &crate::types::GroupCreateRequest) -> Result<()>;
+    async fn members(
+        &self,
+        group_id: &str,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::UserCreateResponse>>;
+    async fn members_page(
+        &self,
+        group_id: &str,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::UserCreateResponse>, String)>;
+    async fn get_all_members(
+        &self,
+        group_id: &str,
+    ) -> Result<Vec<crate::types::UserCreateResponse>>;
+    async fn members_create(
+        &self,
+        group_id: &str,
+        body: &crate::types::AddRoleMembersRequest,
+    ) -> Result<()>;
+    async fn members_delete(&self, group_id: &str, member_id: &str) -> Result<()>;
+    async fn members_delete_if_exists(&self, group_id: &str, member_id: &str) -> Result<bool>;
+    async fn update_member(
+        &self,
+        group_id: &str,
+        member_id: &str,
+        body: &crate::types::UpdateGroupMemberRequest,
+    ) -> Result<()>;
+    async fn get_settings_domains(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: Option<crate::types::OptionData>,
+    ) -> Result<crate::types::Domains>;
+    async fn get_settings_meeting_security(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: Option<crate::types::OptionData>,
+    ) -> Result<crate::types::MeetingSecuritySettings>;
+    async fn get_settings_group_response(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: Option<crate::types::OptionData>,
+    ) -> Result<crate::types::GetGroupSettingsResponse>;
+    async fn get_setting(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: Option<crate::types::OptionData>,
+    ) -> Result<crate::types::GetGroupSettingsResponseOneOf>;
+    async fn update_settings(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: Option<crate::types::UpdateGroupSettingsOption>,
+        body: &crate::types::UpdateGroupSettingsRequestOneOf,
+    ) -> Result<()>;
+    async fn get_lock_settings_meeting_security(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: &str,
+    ) -> Result<crate::types::MeetingSecuritySettings>;
+    async fn get_lock_settings_group_response(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: &str,
+    ) -> Result<crate::types::GetGroupLockSettingsResponse>;
+    async fn get_lock_setting(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: &str,
+    ) -> Result<crate::types::GetGroupLockSettingsResponseOneOf>;
+    async fn locked_settings(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: &str,
+        body: &crate::types::GroupLockedSettingsRequestOneOf,
+    ) -> Result<()>;
+    async fn upload_vb(
+        &self,
+        file_ids: &str,
+        group_id: &str,
+        body: &crate::types::UploadVbRequest,
+    ) -> Result<crate::types::Files>;
+    async fn del_vb(&self, file_ids: &str, group_id: &str) -> Result<()>;
+    async fn del_vb_if_exists(&self, file_ids: &str, group_id: &str) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl GroupsApi for Groups {
+    async fn get(&self) -> Result<crate::types::GroupList> {
+        Groups::get(self).await
+    }
+    async fn create(&self, body: &crate::types::GroupCreateRequest) -> Result<()> {
+        Groups::create(self, body).await
+    }
+    async fn group(&self, group_id: &str) -> Result<crate::types::GroupResponse> {
+        Groups::group(self, group_id).await
+    }
+    async fn delete(&self, group_id: &str) -> Result<()> {
+        Groups::delete(self, group_id).await
+    }
+    async fn delete_if_exists(&self, group_id: &str) -> Result<bool> {
+        Groups::delete_if_exists(self, group_id).await
+    }
+    async fn update(&self, group_id: &str, body: &crate::types::GroupCreateRequest) -> Result<()> {
+        Groups::update(self, group_id, body).await
+    }
+    async fn members(
+        &self,
+        group_id: &str,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::UserCreateResponse>> {
+        Groups::members(self, group_id, page_size, page_number, next_page_token).await
+    }
+    async fn members_page(
+        &self,
+        group_id: &str,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::UserCreateResponse>, String)> {
+        Groups::members_page(self, group_id, page_size, page_number, next_page_token).await
+    }
+    async fn get_all_members(
+        &self,
+        group_id: &str,
+    ) -> Result<Vec<crate::types::UserCreateResponse>> {
+        Groups::get_all_members(self, group_id).await
+    }
+    async fn members_create(
+        &self,
+        group_id: &str,
+        body: &crate::types::AddRoleMembersRequest,
+    ) -> Result<()> {
+        Groups::members_create(self, group_id, body).await
+    }
+    async fn members_delete(&self, group_id: &str, member_id: &str) -> Result<()> {
+        Groups::members_delete(self, group_id, member_id).await
+    }
+    async fn members_delete_if_exists(&self, group_id: &str, member_id: &str) -> Result<bool> {
+        Groups::members_delete_if_exists(self, group_id, member_id).await
+    }
+    async fn update_member(
+        &self,
+        group_id: &str,
+        member_id: &str,
+        body: &crate::types::UpdateGroupMemberRequest,
+    ) -> Result<()> {
+        Groups::update_member(self, group_id, member_id, body).await
+    }
+    async fn get_settings_domains(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: Option<crate::types::OptionData>,
+    ) -> Result<crate::types::Domains> {
+        Groups::get_settings_domains(self, group_id, custom_query_fields, option).await
+    }
+    async fn get_settings_meeting_security(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: Option<crate::types::OptionData>,
+    ) -> Result<crate::types::MeetingSecuritySettings> {
+        Groups::get_settings_meeting_security(self, group_id, custom_query_fields, option).await
+    }
+    async fn get_settings_group_response(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: Option<crate::types::OptionData>,
+    ) -> Result<crate::types::GetGroupSettingsResponse> {
+        Groups::get_settings_group_response(self, group_id, custom_query_fields, option).await
+    }
+    async fn get_setting(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: Option<crate::types::OptionData>,
+    ) -> Result<crate::types::GetGroupSettingsResponseOneOf> {
+        Groups::get_setting(self, group_id, custom_query_fields, option).await
+    }
+    async fn update_settings(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: Option<crate::types::UpdateGroupSettingsOption>,
+        body: &crate::types::UpdateGroupSettingsRequestOneOf,
+    ) -> Result<()> {
+        Groups::update_settings(self, group_id, custom_query_fields, option, body).await
+    }
+    async fn get_lock_settings_meeting_security(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: &str,
+    ) -> Result<crate::types::MeetingSecuritySettings> {
+        Groups::get_lock_settings_meeting_security(self, group_id, custom_query_fields, option)
+            .await
+    }
+    async fn get_lock_settings_group_response(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: &str,
+    ) -> Result<crate::types::GetGroupLockSettingsResponse> {
+        Groups::get_lock_settings_group_response(self, group_id, custom_query_fields, option).await
+    }
+    async fn get_lock_setting(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: &str,
+    ) -> Result<crate::types::GetGroupLockSettingsResponseOneOf> {
+        Groups::get_lock_setting(self, group_id, custom_query_fields, option).await
+    }
+    async fn locked_settings(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: &str,
+        body: &crate::types::GroupLockedSettingsRequestOneOf,
+    ) -> Result<()> {
+        Groups::locked_settings(self, group_id, custom_query_fields, option, body).await
+    }
+    async fn upload_vb(
+        &self,
+        file_ids: &str,
+        group_id: &str,
+        body: &crate::types::UploadVbRequest,
+    ) -> Result<crate::types::Files> {
+        Groups::upload_vb(self, file_ids, group_id, body).await
+    }
+    async fn del_vb(&self, file_ids: &str, group_id: &str) -> Result<()> {
+        Groups::del_vb(self, file_ids, group_id).await
+    }
+    async fn del_vb_if_exists(&self, file_ids: &str, group_id: &str) -> Result<bool> {
+        Groups::del_vb_if_exists(self, file_ids, group_id).await
     }
 }
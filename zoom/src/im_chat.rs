@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct ImChat {
     pub client: Client,
@@ -12,6 +10,17 @@ impl ImChat {
         ImChat { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        ImChat {
+            client: self.client.with_retry(policy),
+        }
+    }
+
+    /// The OAuth scopes for `session`, any one of them grants access.
+    pub const SESSION_SCOPES: &[&str] = &["imchat:read", "imchat:read:admin"];
+
     /**
      * Get IM chat sessions.
      *
@@ -40,25 +49,31 @@ impl ImChat {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<crate::types::ImChatSessionsResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/im/chat/sessions?{}", query_);
+        let url = crate::Client::url_with_query("/im/chat/sessions", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/im/chat/sessions", &[]))
     }
 
+    /// The OAuth scopes for `message`, any one of them grants access.
+    pub const MESSAGE_SCOPES: &[&str] = &["imchat:read"];
+
     /**
      * Get IM chat messages.
      *
@@ -93,29 +108,37 @@ impl ImChat {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<crate::types::ImChatMessagesResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/im/chat/sessions/{}?{}",
-            crate::progenitor_support::encode_path(&session_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/im/chat/sessions/{}", self.client.encode_path(session_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/im/chat/sessions/{sessionId}",
+                &[("sessionId", session_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `list_im_messages`, any one of them grants access.
+    pub const LIST_IM_MESSAGES_SCOPES: &[&str] = &["imchat:read"];
+
     /**
      * Get user’s IM messages.
      *
@@ -133,7 +156,7 @@ impl ImChat {
      * * `user_id: &str` -- The user ID or email address.
      * * `chat_user: &str` -- Chat user's ID or email address.
      * * `channel: &str` -- User's first name.
-     * * `date: &str` -- IM message's query date time, format as yyyy-MM-dd.
+     * * `date: Option<chrono::NaiveDate>` -- IM message's query date time, format as yyyy-MM-dd.
      * * `page_size: i64` -- The number of records returned within a single API call.
      * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
      */
@@ -142,39 +165,102 @@ impl ImChat {
         user_id: &str,
         chat_user: &str,
         channel: &str,
-        date: &str,
+        date: Option<chrono::NaiveDate>,
         page_size: i64,
         next_page_token: &str,
     ) -> Result<Vec<crate::types::ListimmessagesResponseMessages>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !channel.is_empty() {
-            query_args.push(("channel".to_string(), channel.to_string()));
+            query_args.push(("channel", channel.to_string()));
         }
         if !chat_user.is_empty() {
-            query_args.push(("chat_user".to_string(), chat_user.to_string()));
+            query_args.push(("chat_user", chat_user.to_string()));
         }
-        if !date.is_empty() {
-            query_args.push(("date".to_string(), date.to_string()));
+        if let Some(date) = date {
+            query_args.push(("date", date.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/im/users/{}/chat/messages?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/im/users/{}/chat/messages",
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
         );
 
-        let resp: crate::types::ListimmessagesResponse = self.client.get(&url, None).await?;
+        let resp: crate::types::ListimmessagesResponse =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/im/users/{userId}/chat/messages",
+                    &[("userId", user_id.to_string())],
+                )
+            })?;
 
         // Return our response data.
         Ok(resp.messages)
     }
 
+    /**
+     * Get user’s IM messages.
+     *
+     * This function performs a `GET` to the `/im/users/{userId}/chat/messages` endpoint.
+     *
+     * As opposed to `list_im_messages`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
+     */
+    pub async fn list_im_messages_page(
+        &self,
+        user_id: &str,
+        chat_user: &str,
+        channel: &str,
+        date: Option<chrono::NaiveDate>,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::ListimmessagesResponseMessages>, String)> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !channel.is_empty() {
+            query_args.push(("channel", channel.to_string()));
+        }
+        if !chat_user.is_empty() {
+            query_args.push(("chat_user", chat_user.to_string()));
+        }
+        if let Some(date) = date {
+            query_args.push(("date", date.to_string()));
+        }
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/im/users/{}/chat/messages",
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
+        );
+
+        let resp: crate::types::ListimmessagesResponse =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/im/users/{userId}/chat/messages",
+                    &[("userId", user_id.to_string())],
+                )
+            })?;
+
+        // Return our response data, and where the next page starts.
+        Ok((resp.messages, resp.next_page_token))
+    }
+
     /**
      * Get user’s IM messages.
      *
@@ -194,26 +280,34 @@ impl ImChat {
         user_id: &str,
         chat_user: &str,
         channel: &str,
-        date: &str,
+        date: Option<chrono::NaiveDate>,
     ) -> Result<Vec<crate::types::ListimmessagesResponseMessages>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !channel.is_empty() {
-            query_args.push(("channel".to_string(), channel.to_string()));
+            query_args.push(("channel", channel.to_string()));
         }
         if !chat_user.is_empty() {
-            query_args.push(("chat_user".to_string(), chat_user.to_string()));
+            query_args.push(("chat_user", chat_user.to_string()));
         }
-        if !date.is_empty() {
-            query_args.push(("date".to_string(), date.to_string()));
+        if let Some(date) = date {
+            query_args.push(("date", date.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/im/users/{}/chat/messages?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/im/users/{}/chat/messages",
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
         );
 
-        let mut resp: crate::types::ListimmessagesResponse = self.client.get(&url, None).await?;
+        let mut resp: crate::types::ListimmessagesResponse =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/im/users/{userId}/chat/messages",
+                    &[("userId", user_id.to_string())],
+                )
+            })?;
 
         let mut messages = resp.messages;
         let mut page = resp.next_page_token;
@@ -246,6 +340,114 @@ impl ImChat {
         Ok(messages)
     }
 
+    /**
+     * Get user’s IM messages.
+     *
+     * This function performs a `GET` to the `/im/users/{userId}/chat/messages` endpoint.
+     *
+     * As opposed to `list_im_messages`, this function streams the items of all the pages, getting the next page only once the items of the last one are used up. The stream ends after the last page.
+     */
+    pub async fn list_im_messages_stream(
+        &self,
+        user_id: &str,
+        chat_user: &str,
+        channel: &str,
+        date: Option<chrono::NaiveDate>,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::ListimmessagesResponseMessages>>>
+    {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !channel.is_empty() {
+            query_args.push(("channel", channel.to_string()));
+        }
+        if !chat_user.is_empty() {
+            query_args.push(("chat_user", chat_user.to_string()));
+        }
+        if let Some(date) = date {
+            query_args.push(("date", date.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/im/users/{}/chat/messages",
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListimmessagesResponse| (resp.messages, resp.next_page_token),
+            )
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/im/users/{userId}/chat/messages",
+                    &[("userId", user_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Get user’s IM messages.
+     *
+     * This function performs a `GET` to the `/im/users/{userId}/chat/messages` endpoint.
+     *
+     * As opposed to `list_im_messages_stream`, this function streams only the items `predicate` keeps. It too gets the next page only once the items of the last one are used up, so a stream that is cut short, for example with `take`, gets no more pages than it needs.
+     */
+    pub async fn list_im_messages_filter<
+        P: FnMut(&crate::types::ListimmessagesResponseMessages) -> bool,
+    >(
+        &self,
+        user_id: &str,
+        chat_user: &str,
+        channel: &str,
+        date: Option<chrono::NaiveDate>,
+        mut predicate: P,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::ListimmessagesResponseMessages>>>
+    {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !channel.is_empty() {
+            query_args.push(("channel", channel.to_string()));
+        }
+        if !chat_user.is_empty() {
+            query_args.push(("chat_user", chat_user.to_string()));
+        }
+        if let Some(date) = date {
+            query_args.push(("date", date.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/im/users/{}/chat/messages",
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
+        );
+
+        let items = self
+            .client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListimmessagesResponse| (resp.messages, resp.next_page_token),
+            )
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/im/users/{userId}/chat/messages",
+                    &[("userId", user_id.to_string())],
+                )
+            })?;
+        Ok(futures::TryStreamExt::try_filter(items, move |item| {
+            futures::future::ready(predicate(item))
+        }))
+    }
+
+    /// The OAuth scopes for `send_im_messages`, any one of them grants access.
+    pub const SEND_IM_MESSAGES_SCOPES: &[&str] = &["imchat:write"];
+
     /**
      * Send IM messages.
      *
@@ -264,15 +466,149 @@ impl ImChat {
         chat_user: &str,
         body: &crate::types::SendimmessagesRequest,
     ) -> Result<crate::types::Groups> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !chat_user.is_empty() {
-            query_args.push(("chat_user".to_string(), chat_user.to_string()));
+            query_args.push(("chat_user", chat_user.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/im/users/me/chat/messages?{}", query_);
+        let url = crate::Client::url_with_query("/im/users/me/chat/messages", &query_args);
+        body.validate()?;
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/im/users/me/chat/messages", &[]))
+    }
+}
+
+/// The functions of [`ImChat`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ImChatApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ImChatApi: Send + Sync {
+    async fn session(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::ImChatSessionsResponseAllOf>;
+    async fn message(
+        &self,
+        session_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::ImChatMessagesResponseAllOf>;
+    async fn list_im_messages(
+        &self,
+        user_id: &str,
+        chat_user: &str,
+        channel: &str,
+        date: Option<chrono::NaiveDate>,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::ListimmessagesResponseMessages>>;
+    async fn list_im_messages_page(
+        &self,
+        user_id: &str,
+        chat_user: &str,
+        channel: &str,
+        date: Option<chrono::NaiveDate>,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::ListimmessagesResponseMessages>, String)>;
+    async fn list_all_im_messages(
+        &self,
+        user_id: &str,
+        chat_user: &str,
+        channel: &str,
+        date: Option<chrono::NaiveDate>,
+    ) -> Result<Vec<crate::types::ListimmessagesResponseMessages>>;
+    async fn send_im_messages(
+        &self,
+        chat_user: &str,
+        body: &crate::types::SendimmessagesRequest,
+    ) -> Result<crate::types::Groups>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ImChatApi for ImChat {
+    async fn session(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::ImChatSessionsResponseAllOf> {
+        ImChat::session(self, from, to, page_size, next_page_token).await
+    }
+    async fn message(
+        &self,
+        session_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::ImChatMessagesResponseAllOf> {
+        ImChat::message(self, session_id, from, to, page_size, next_page_token).await
+    }
+    async fn list_im_messages(
+        &self,
+        user_id: &str,
+        chat_user: &str,
+        channel: &str,
+        date: Option<chrono::NaiveDate>,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::ListimmessagesResponseMessages>> {
+        ImChat::list_im_messages(
+            self,
+            user_id,
+            chat_user,
+            channel,
+            date,
+            page_size,
+            next_page_token,
+        )
+        .await
+    }
+    async fn list_im_messages_page(
+        &self,
+        user_id: &str,
+        chat_user: &str,
+        channel: &str,
+        date: Option<chrono::NaiveDate>,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::ListimmessagesResponseMessages>, String)> {
+        ImChat::list_im_messages_page(
+            self,
+            user_id,
+            chat_user,
+            channel,
+            date,
+            page_size,
+            next_page_token,
+        )
+        .await
+    }
+    async fn list_all_im_messages(
+        &self,
+        user_id: &str,
+        chat_user: &str,
+        channel: &str,
+        date: Option<chrono::NaiveDate>,
+    ) -> Result<Vec<crate::types::ListimmessagesResponseMessages>> {
+        ImChat::list_all_im_messages(self, user_id, chat_user, channel, date).await
+    }
+    async fn send_im_messages(
+        &self,
+        chat_user: &str,
+        body: &crate::types::SendimmessagesRequest,
+    ) -> Result<crate::types::Groups> {
+        ImChat::send_im_messages(self, chat_user, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct ImGroups {
     pub client: Client,
@@ -12,6 +10,17 @@ impl ImGroups {
         ImGroups { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        ImGroups {
+            client: self.client.with_retry(policy),
+        }
+    }
+
+    /// The OAuth scopes for `get`, any one of them grants access.
+    pub const GET_SCOPES: &[&str] = &["imgroup:read:admin"];
+
     /**
      * List IM directory groups.
      *
@@ -24,9 +33,15 @@ impl ImGroups {
      */
     pub async fn get(&self) -> Result<crate::types::Domains> {
         let url = "/im/groups".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/im/groups", &[]))
     }
 
+    /// The OAuth scopes for `create`, any one of them grants access.
+    pub const CREATE_SCOPES: &[&str] = &["imgroup:write:admin"];
+
     /**
      * Create an IM directory group.
      *
@@ -40,8 +55,9 @@ impl ImGroups {
     pub async fn create(&self, body: &crate::types::ImGroupCreateRequest) -> Result<()> {
         let url = "/im/groups".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/im/groups", &[]))
     }
 
     /**
@@ -60,12 +76,15 @@ impl ImGroups {
      *   Can be retrieved by calling [GET /groups](https://marketplace.zoom.us/docs/api-reference/zoom-api/groups/groups).
      */
     pub async fn im_group(&self, group_id: &str) -> Result<crate::types::ImGroupResponseAllOf> {
-        let url = format!(
-            "/im/groups/{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-        );
+        let url = format!("/im/groups/{}", self.client.encode_path(group_id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/im/groups/{groupId}",
+                &[("groupId", group_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -84,14 +103,42 @@ impl ImGroups {
      *   Can be retrieved by calling [GET /groups](https://marketplace.zoom.us/docs/api-reference/zoom-api/groups/groups).
      */
     pub async fn delete(&self, group_id: &str) -> Result<()> {
-        let url = format!(
-            "/im/groups/{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-        );
+        let url = format!("/im/groups/{}", self.client.encode_path(group_id),);
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/im/groups/{groupId}",
+                &[("groupId", group_id.to_string())],
+            )
+        })
     }
 
+    /**
+     * Delete an IM directory group.
+     *
+     * This function performs a `DELETE` to the `/im/groups/{groupId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, group_id: &str) -> Result<bool> {
+        let url = format!("/im/groups/{}", self.client.encode_path(group_id),);
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/im/groups/{groupId}",
+                    &[("groupId", group_id.to_string())],
+                )
+            })
+    }
+
+    /// The OAuth scopes for `update`, any one of them grants access.
+    pub const UPDATE_SCOPES: &[&str] = &["imgroup:write:admin"];
+
     /**
      * Update an IM directory group.
      *
@@ -112,16 +159,50 @@ impl ImGroups {
         group_id: &str,
         body: &crate::types::ImGroupCreateRequest,
     ) -> Result<()> {
-        let url = format!(
-            "/im/groups/{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-        );
+        let url = format!("/im/groups/{}", self.client.encode_path(group_id),);
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/im/groups/{groupId}",
+                    &[("groupId", group_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update an IM directory group.
+     *
+     * This function performs a `PATCH` to the `/im/groups/{groupId}` endpoint.
+     *
+     * As opposed to `update`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn update_if_changed<C: serde::Serialize>(
+        &self,
+        group_id: &str,
+        current: &C,
+        body: &crate::types::ImGroupCreateRequest,
+    ) -> Result<Option<()>> {
+        let url = format!("/im/groups/{}", self.client.encode_path(group_id),);
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/im/groups/{groupId}",
+                    &[("groupId", group_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `member`, any one of them grants access.
+    pub const MEMBER_SCOPES: &[&str] = &["imgroup:read:admin"];
+
     /**
      * List IM directory group members.
      *
@@ -150,26 +231,34 @@ impl ImGroups {
         page_number: i64,
         next_page_token: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_number > 0 {
-            query_args.push(("page_number".to_string(), page_number.to_string()));
+            query_args.push(("page_number", page_number.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/im/groups/{}/members?{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/im/groups/{}/members", self.client.encode_path(group_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/im/groups/{groupId}/members",
+                &[("groupId", group_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `members_create`, any one of them grants access.
+    pub const MEMBERS_CREATE_SCOPES: &[&str] = &["imgroup:write:admin"];
+
     /**
      * Add IM directory group members.
      *
@@ -190,14 +279,18 @@ impl ImGroups {
         group_id: &str,
         body: &crate::types::AddRoleMembersRequest,
     ) -> Result<()> {
-        let url = format!(
-            "/im/groups/{}/members",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-        );
+        let url = format!("/im/groups/{}/members", self.client.encode_path(group_id),);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/im/groups/{groupId}/members",
+                    &[("groupId", group_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -219,10 +312,126 @@ impl ImGroups {
     pub async fn members_delete(&self, group_id: &str, member_id: &str) -> Result<()> {
         let url = format!(
             "/im/groups/{}/members/{}",
-            crate::progenitor_support::encode_path(&group_id.to_string()),
-            crate::progenitor_support::encode_path(&member_id.to_string()),
+            self.client.encode_path(group_id),
+            self.client.encode_path(member_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/im/groups/{groupId}/members/{memberId}",
+                &[
+                    ("groupId", group_id.to_string()),
+                    ("memberId", member_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Delete IM directory group member.
+     *
+     * This function performs a `DELETE` to the `/im/groups/{groupId}/members/{memberId}` endpoint.
+     *
+     * As opposed to `members_delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn members_delete_if_exists(&self, group_id: &str, member_id: &str) -> Result<bool> {
+        let url = format!(
+            "/im/groups/{}/members/{}",
+            self.client.encode_path(group_id),
+            self.client.encode_path(member_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/im/groups/{groupId}/members/{memberId}",
+                    &[
+                        ("groupId", group_id.to_string()),
+                        ("memberId", member_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`ImGroups`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ImGroupsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ImGroupsApi: Send + Sync {
+    async fn get(&self) -> Result<crate::types::Domains>;
+    async fn create(&self, body: &crate::types::ImGroupCreateRequest) -> Result<()>;
+    async fn im_group(&self, group_id: &str) -> Result<crate::types::ImGroupResponseAllOf>;
+    async fn delete(&self, group_id: &str) -> Result<()>;
+    async fn delete_if_exists(&self, group_id: &str) -> Result<bool>;
+    async fn update(&self, group_id: &str, body: &crate::types::ImGroupCreateRequest)
+        -> Result<()>;
+    async fn member(
+        &self,
+        group_id: &str,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::Domains>;
+    async fn members_create(
+        &self,
+        group_id: &str,
+        body: &crate::types::AddRoleMembersRequest,
+    ) -> Result<()>;
+    async fn members_delete(&self, group_id: &str, member_id: &str) -> Result<()>;
+    async fn members_delete_if_exists(&self, group_id: &str, member_id: &str) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ImGroupsApi for ImGroups {
+    async fn get(&self) -> Result<crate::types::Domains> {
+        ImGroups::get(self).await
+    }
+    async fn create(&self, body: &crate::types::ImGroupCreateRequest) -> Result<()> {
+        ImGroups::create(self, body).await
+    }
+    async fn im_group(&self, group_id: &str) -> Result<crate::types::ImGroupResponseAllOf> {
+        ImGroups::im_group(self, group_id).await
+    }
+    async fn delete(&self, group_id: &str) -> Result<()> {
+        ImGroups::delete(self, group_id).await
+    }
+    async fn delete_if_exists(&self, group_id: &str) -> Result<bool> {
+        ImGroups::delete_if_exists(self, group_id).await
+    }
+    async fn update(
+        &self,
+        group_id: &str,
+        body: &crate::types::ImGroupCreateRequest,
+    ) -> Result<()> {
+        ImGroups::update(self, group_id, body).await
+    }
+    async fn member(
+        &self,
+        group_id: &str,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::Domains> {
+        ImGroups::member(self, group_id, page_size, page_number, next_page_token).await
+    }
+    async fn members_create(
+        &self,
+        group_id: &str,
+        body: &crate::types::AddRoleMembersRequest,
+    ) -> Result<()> {
+        ImGroups::members_create(self, group_id, body).await
+    }
+    async fn members_delete(&self, group_id: &str, member_id: &str) -> Result<()> {
+        ImGroups::members_delete(self, group_id, member_id).await
+    }
+    async fn members_delete_if_exists(&self, group_id: &str, member_id: &str) -> Result<bool> {
+        ImGroups::members_delete_if_exists(self, group_id, member_id).await
     }
 }
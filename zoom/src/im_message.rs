@@ -0,0 +1,64 @@
+use anyhow::{bail, Result};
+
+/// Builds the body of `ImChat::send_im_messages`, a chat message to a user.
+/// The message can not be empty, `build` checks it has some text.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ImMessageBuilder {
+    message: String,
+}
+
+impl ImMessageBuilder {
+    /// Set the text of the message, replacing any it had.
+    pub fn text(mut self, text: &str) -> Self {
+        self.message = text.to_string();
+        self
+    }
+
+    /// Add a line to the end of the message.
+    pub fn line(mut self, line: &str) -> Self {
+        if !self.message.is_empty() {
+            self.message.push('\n');
+        }
+        self.message.push_str(line);
+        self
+    }
+
+    /// The request, if the message has some text.
+    pub fn build(self) -> Result<crate::types::SendimmessagesRequest> {
+        let request = crate::types::SendimmessagesRequest {
+            message: self.message,
+        };
+        request.validate()?;
+        Ok(request)
+    }
+}
+
+impl crate::types::SendimmessagesRequest {
+    /// A builder for the message.
+    pub fn builder() -> ImMessageBuilder {
+        ImMessageBuilder::default()
+    }
+
+    /// A plain text message.
+    pub fn text(text: &str) -> Result<Self> {
+        Self::builder().text(text).build()
+    }
+
+    /// Check the message has some text. `ImChat::send_im_messages` does this
+    /// before it sends the request.
+    pub fn validate(&self) -> Result<()> {
+        if self.message.trim().is_empty() {
+            bail!("IM messages must have some text");
+        }
+        Ok(())
+    }
+}
+
+impl crate::im_chat::ImChat {
+    /// Send a plain text chat message to the user, by their email address or
+    /// user id.
+    pub async fn send_text(&self, chat_user: &str, text: &str) -> Result<crate::types::Groups> {
+        let body = crate::types::SendimmessagesRequest::text(text)?;
+        Ok(self.send_im_messages(chat_user, &body).await?)
+    }
+}
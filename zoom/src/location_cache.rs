@@ -0,0 +1,124 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use anyhow::Result;
+
+/// An in-memory cache of emergency service locations, keyed by id, for tools
+/// that look up the same locations over and over.
+///
+/// `get_location` looks in the cache before asking the API, and listing the
+/// locations through the cache fills it for `listed_location`. The list
+/// endpoint does not return a location's `emergency_address`, so the listed
+/// locations are kept apart from the ones we got, and never stand in for
+/// them. Updating or deleting a location through the cache drops it from the
+/// cache. Clones share the same cache.
+#[derive(Clone)]
+pub struct LocationCache {
+    client: crate::Client,
+    locations: Arc<Mutex<HashMap<String, crate::types::GetLocationResponse>>>,
+    listed: Arc<Mutex<HashMap<String, crate::types::ListLocationsResponse>>>,
+}
+
+impl LocationCache {
+    /// Create an empty cache that makes its calls with `client`.
+    pub fn new(client: crate::Client) -> Self {
+        LocationCache {
+            client,
+            locations: Default::default(),
+            listed: Default::default(),
+        }
+    }
+
+    /// List emergency service locations, as `Phone::list_locations` does, and
+    /// cache them.
+    pub async fn list_locations(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<Vec<crate::types::ListLocationsResponse>> {
+        let locations = self
+            .client
+            .phone()
+            .list_locations(next_page_token, page_size)
+            .await?;
+        self.insert_all(&locations);
+        Ok(locations)
+    }
+
+    /// List all the emergency service locations, as `Phone::list_all_locations`
+    /// does, and cache them.
+    pub async fn list_all_locations(&self) -> Result<Vec<crate::types::ListLocationsResponse>> {
+        let locations = self.client.phone().list_all_locations().await?;
+        self.insert_all(&locations);
+        Ok(locations)
+    }
+
+    /// A location as the last list through the cache returned it, without its
+    /// `emergency_address`, or `None` if no list returned it.
+    pub fn listed_location(
+        &self,
+        location_id: &str,
+    ) -> Option<crate::types::ListLocationsResponse> {
+        self.listed.lock().unwrap().get(location_id).cloned()
+    }
+
+    /// Get an emergency service location from the cache, or from the API if it
+    /// is not cached yet.
+    pub async fn get_location(
+        &self,
+        location_id: &str,
+    ) -> Result<crate::types::GetLocationResponse> {
+        if let Some(location) = self.locations.lock().unwrap().get(location_id) {
+            return Ok(location.clone());
+        }
+
+        let location = self.client.phone().get_location(location_id).await?;
+        self.locations
+            .lock()
+            .unwrap()
+            .insert(location_id.to_string(), location.clone());
+        Ok(location)
+    }
+
+    /// Update an emergency service location and drop it from the cache.
+    pub async fn update_location(
+        &self,
+        location_id: &str,
+        body: &crate::types::UpdateLocationRequest,
+    ) -> Result<()> {
+        // Drop it first, a failed update may still have changed the location.
+        self.invalidate(location_id);
+        Ok(self
+            .client
+            .phone()
+            .update_location(location_id, body)
+            .await?)
+    }
+
+    /// Delete an emergency service location and drop it from the cache.
+    pub async fn delete_location(&self, location_id: &str) -> Result<()> {
+        self.invalidate(location_id);
+        Ok(self.client.phone().delete_location(location_id).await?)
+    }
+
+    /// Drop a location from the cache, so the next `get_location` asks the API.
+    pub fn invalidate(&self, location_id: &str) {
+        self.locations.lock().unwrap().remove(location_id);
+        self.listed.lock().unwrap().remove(location_id);
+    }
+
+    /// Drop every location from the cache.
+    pub fn clear(&self) {
+        self.locations.lock().unwrap().clear();
+        self.listed.lock().unwrap().clear();
+    }
+
+    fn insert_all(&self, locations: &[crate::types::ListLocationsResponse]) {
+        let mut listed = self.listed.lock().unwrap();
+        for l in locations {
+            listed.insert(l.id.to_string(), l.clone());
+        }
+    }
+}
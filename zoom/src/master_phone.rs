@@ -0,0 +1,266 @@
+use crate::{CallContext, Client, ClientResult as Result};
+
+pub struct MasterPhone {
+    pub client: Client,
+}
+
+impl MasterPhone {
+    #[doc(hidden)]
+    pub fn new(client: Client) -> Self {
+        MasterPhone { client }
+    }
+
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        MasterPhone {
+            client: self.client.with_retry(policy),
+        }
+    }
+
+    /// The OAuth scopes for `update_settings`, any one of them grants access.
+    pub const UPDATE_SETTINGS_SCOPES: &[&str] = &["phone:master"];
+
+    /**
+     * Update BYOC settings.
+     *
+     * This function performs a `PATCH` to the `/accounts/{accountId}/phone/settings` endpoint.
+     *
+     * [Master account owners](https://marketplace.zoom.us/docs/api-reference/master-account-apis) can use this API to enable the BYOC (Bring Your Own Carrier) option for a subaccount.
+     *
+     * **Scopes:** `phone:master`
+     *
+     * **Prerequisites:**
+     * * A Business or Enterprise account
+     *
+     * **Parameters:**
+     *
+     * * `account_id: &str` -- Unique identifier of the sub account.
+     */
+    pub async fn update_settings(
+        &self,
+        account_id: &str,
+        body: &crate::types::UpdatePhoneSettingsRequest,
+    ) -> Result<()> {
+        let url = format!(
+            "/accounts/{}/phone/settings",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/accounts/{accountId}/phone/settings",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update BYOC settings.
+     *
+     * This function performs a `PATCH` to the `/accounts/{accountId}/phone/settings` endpoint.
+     *
+     * As opposed to `update_settings`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn update_settings_if_changed<C: serde::Serialize>(
+        &self,
+        account_id: &str,
+        current: &C,
+        body: &crate::types::UpdatePhoneSettingsRequest,
+    ) -> Result<Option<()>> {
+        let url = format!(
+            "/accounts/{}/phone/settings",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/accounts/{accountId}/phone/settings",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The OAuth scopes for `post_sip_trunk`, any one of them grants access.
+    pub const POST_SIP_TRUNK_SCOPES: &[&str] = &["phone:master"];
+
+    /**
+     * Assign SIP trunks.
+     *
+     * This function performs a `POST` to the `/accounts/{accountId}/phone/sip_trunk/trunks` endpoint.
+     *
+     * A [Master account](https://marketplace.zoom.us/docs/api-reference/master-account-apis) owner can use this API to assign SIP (Session Initiation Protocol) trunks to a subaccount.
+     *
+     * **Scopes:** `phone:master`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * A Business or Enterprise account
+     *
+     * **Parameters:**
+     *
+     * * `account_id: &str` -- Unique identifier of the account.
+     */
+    pub async fn post_sip_trunk(
+        &self,
+        account_id: &str,
+        body: &crate::types::PostPhoneSipTrunkRequest,
+    ) -> Result<crate::types::PostPhoneSipTrunkRequest> {
+        let url = format!(
+            "/accounts/{}/phone/sip_trunk/trunks",
+            self.client.encode_path(account_id),
+        );
+        crate::utils::check_items("sip_trunks", body.sip_trunks.len(), None, Some(3))?;
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/accounts/{accountId}/phone/sip_trunk/trunks",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The OAuth scopes for `update_sip_trunk`, any one of them grants access.
+    pub const UPDATE_SIP_TRUNK_SCOPES: &[&str] = &["phone:master"];
+
+    /**
+     * Update SIP trunk details.
+     *
+     * This function performs a `PATCH` to the `/accounts/{accountId}/phone/sip_trunk/trunks/{sipTrunkId}` endpoint.
+     *
+     * Use this API to update a subaccount's assigned SIP (Session Initiation Protocol) trunk information.
+     *
+     * **Scopes:** `phone:master` <br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * A Business or Enterprise account
+     *
+     * **Parameters:**
+     *
+     * * `sip_trunk_id: &str` -- Unique identifier of the SIP trunk.
+     * * `account_id: &str` -- Unique identifier of the sub account.
+     */
+    pub async fn update_sip_trunk(
+        &self,
+        sip_trunk_id: &str,
+        account_id: &str,
+        body: &crate::types::UpdatePhoneSipTrunkRequest,
+    ) -> Result<()> {
+        let url = format!(
+            "/accounts/{}/phone/sip_trunk/trunks/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(sip_trunk_id),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/accounts/{accountId}/phone/sip_trunk/trunks/{sipTrunkId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("sipTrunkId", sip_trunk_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * Update SIP trunk details.
+     *
+     * This function performs a `PATCH` to the `/accounts/{accountId}/phone/sip_trunk/trunks/{sipTrunkId}` endpoint.
+     *
+     * As opposed to `update_sip_trunk`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn update_sip_trunk_if_changed<C: serde::Serialize>(
+        &self,
+        sip_trunk_id: &str,
+        account_id: &str,
+        current: &C,
+        body: &crate::types::UpdatePhoneSipTrunkRequest,
+    ) -> Result<Option<()>> {
+        let url = format!(
+            "/accounts/{}/phone/sip_trunk/trunks/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(sip_trunk_id),
+        );
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/accounts/{accountId}/phone/sip_trunk/trunks/{sipTrunkId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("sipTrunkId", sip_trunk_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`MasterPhone`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn MasterPhoneApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait MasterPhoneApi: Send + Sync {
+    async fn update_settings(
+        &self,
+        account_id: &str,
+        body: &crate::types::UpdatePhoneSettingsRequest,
+    ) -> Result<()>;
+    async fn post_sip_trunk(
+        &self,
+        account_id: &str,
+        body: &crate::types::PostPhoneSipTrunkRequest,
+    ) -> Result<crate::types::PostPhoneSipTrunkRequest>;
+    async fn update_sip_trunk(
+        &self,
+        sip_trunk_id: &str,
+        account_id: &str,
+        body: &crate::types::UpdatePhoneSipTrunkRequest,
+    ) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl MasterPhoneApi for MasterPhone {
+    async fn update_settings(
+        &self,
+        account_id: &str,
+        body: &crate::types::UpdatePhoneSettingsRequest,
+    ) -> Result<()> {
+        MasterPhone::update_settings(self, account_id, body).await
+    }
+    async fn post_sip_trunk(
+        &self,
+        account_id: &str,
+        body: &crate::types::PostPhoneSipTrunkRequest,
+    ) -> Result<crate::types::PostPhoneSipTrunkRequest> {
+        MasterPhone::post_sip_trunk(self, account_id, body).await
+    }
+    async fn update_sip_trunk(
+        &self,
+        sip_trunk_id: &str,
+        account_id: &str,
+        body: &crate::types::UpdatePhoneSipTrunkRequest,
+    ) -> Result<()> {
+        MasterPhone::update_sip_trunk(self, sip_trunk_id, account_id, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Meetings {
     pub client: Client,
@@ -12,6 +10,17 @@ impl Meetings {
         Meetings { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Meetings {
+            client: self.client.with_retry(policy),
+        }
+    }
+
+    /// The OAuth scopes for `get`, any one of them grants access.
+    pub const GET_SCOPES: &[&str] = &["meeting:read:admin", "meeting:read"];
+
     /**
      * List meetings.
      *
@@ -39,27 +48,32 @@ impl Meetings {
         next_page_token: &str,
         page_number: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if !page_number.is_empty() {
-            query_args.push(("page_number".to_string(), page_number.to_string()));
+            query_args.push(("page_number", page_number.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/meetings?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/users/{}/meetings", self.client.encode_path(user_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/users/{userId}/meetings",
+                &[("userId", user_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -89,16 +103,23 @@ impl Meetings {
         user_id: &str,
         body: &crate::types::MeetingCreate,
     ) -> Result<crate::types::MeetingCreateResponseAllOf> {
-        let url = format!(
-            "/users/{}/meetings",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-        );
+        let url = format!("/users/{}/meetings", self.client.encode_path(user_id),);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/users/{userId}/meetings",
+                    &[("userId", user_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `meeting`, any one of them grants access.
+    pub const MEETING_SCOPES: &[&str] = &["meeting:read:admin", "meeting:read"];
+
     /**
      * Get a meeting.
      *
@@ -125,26 +146,36 @@ impl Meetings {
         occurrence_id: &str,
         show_previous_occurrences: bool,
     ) -> Result<crate::types::MeetingResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !occurrence_id.is_empty() {
-            query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
+            query_args.push(("occurrence_id", occurrence_id.to_string()));
         }
         if show_previous_occurrences {
             query_args.push((
-                "show_previous_occurrences".to_string(),
+                "show_previous_occurrences",
                 show_previous_occurrences.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/meetings/{}?{}",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/meetings/{}",
+                self.client.encode_path(&meeting_id.to_string())
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/meetings/{meetingId}",
+                &[("meetingId", meeting_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `delete`, any one of them grants access.
+    pub const DELETE_SCOPES: &[&str] = &["meeting:write:admin", "meeting:write"];
+
     /**
      * Delete a meeting.
      *
@@ -177,32 +208,86 @@ impl Meetings {
         schedule_for_reminder: bool,
         cancel_meeting_reminder: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cancel_meeting_reminder.is_empty() {
             query_args.push((
-                "cancel_meeting_reminder".to_string(),
+                "cancel_meeting_reminder",
                 cancel_meeting_reminder.to_string(),
             ));
         }
         if !occurrence_id.is_empty() {
-            query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
+            query_args.push(("occurrence_id", occurrence_id.to_string()));
         }
         if schedule_for_reminder {
+            query_args.push(("schedule_for_reminder", schedule_for_reminder.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/meetings/{}",
+                self.client.encode_path(&meeting_id.to_string())
+            ),
+            &query_args,
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/meetings/{meetingId}",
+                &[("meetingId", meeting_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete a meeting.
+     *
+     * This function performs a `DELETE` to the `/meetings/{meetingId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(
+        &self,
+        meeting_id: i64,
+        occurrence_id: &str,
+        schedule_for_reminder: bool,
+        cancel_meeting_reminder: &str,
+    ) -> Result<bool> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !cancel_meeting_reminder.is_empty() {
             query_args.push((
-                "schedule_for_reminder".to_string(),
-                schedule_for_reminder.to_string(),
+                "cancel_meeting_reminder",
+                cancel_meeting_reminder.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/meetings/{}?{}",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
-            query_
+        if !occurrence_id.is_empty() {
+            query_args.push(("occurrence_id", occurrence_id.to_string()));
+        }
+        if schedule_for_reminder {
+            query_args.push(("schedule_for_reminder", schedule_for_reminder.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/meetings/{}",
+                self.client.encode_path(&meeting_id.to_string())
+            ),
+            &query_args,
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/meetings/{meetingId}",
+                    &[("meetingId", meeting_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `update`, any one of them grants access.
+    pub const UPDATE_SCOPES: &[&str] = &["meeting:write:admin", "meeting:write"];
+
     /**
      * Update a meeting.
      *
@@ -225,22 +310,71 @@ impl Meetings {
         occurrence_id: &str,
         body: &crate::types::MeetingUpdateRequestAllOf,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !occurrence_id.is_empty() {
-            query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
+            query_args.push(("occurrence_id", occurrence_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/meetings/{}?{}",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/meetings/{}",
+                self.client.encode_path(&meeting_id.to_string())
+            ),
+            &query_args,
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/meetings/{meetingId}",
+                    &[("meetingId", meeting_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update a meeting.
+     *
+     * This function performs a `PATCH` to the `/meetings/{meetingId}` endpoint.
+     *
+     * As opposed to `update`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn update_if_changed<C: serde::Serialize>(
+        &self,
+        meeting_id: i64,
+        occurrence_id: &str,
+        current: &C,
+        body: &crate::types::MeetingUpdateRequestAllOf,
+    ) -> Result<Option<()>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !occurrence_id.is_empty() {
+            query_args.push(("occurrence_id", occurrence_id.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/meetings/{}",
+                self.client.encode_path(&meeting_id.to_string())
+            ),
+            &query_args,
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/meetings/{meetingId}",
+                    &[("meetingId", meeting_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `status`, any one of them grants access.
+    pub const STATUS_SCOPES: &[&str] = &["meeting:write:admin", "meeting:write"];
+
     /**
      * Update meeting status.
      *
@@ -263,14 +397,24 @@ impl Meetings {
     ) -> Result<()> {
         let url = format!(
             "/meetings/{}/status",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
+            self.client.encode_path(&meeting_id.to_string()),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/meetings/{meetingId}/status",
+                    &[("meetingId", meeting_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `registrant`, any one of them grants access.
+    pub const REGISTRANT_SCOPES: &[&str] = &["meeting:read:admin", "meeting:read"];
+
     /**
      * List meeting registrants.
      *
@@ -304,32 +448,43 @@ impl Meetings {
         page_number: i64,
         next_page_token: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if !occurrence_id.is_empty() {
-            query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
+            query_args.push(("occurrence_id", occurrence_id.to_string()));
         }
         if page_number > 0 {
-            query_args.push(("page_number".to_string(), page_number.to_string()));
+            query_args.push(("page_number", page_number.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/meetings/{}/registrants?{}",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/meetings/{}/registrants",
+                self.client.encode_path(&meeting_id.to_string())
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/meetings/{meetingId}/registrants",
+                &[("meetingId", meeting_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `registrant_create`, any one of them grants access.
+    pub const REGISTRANT_CREATE_SCOPES: &[&str] = &["meeting:write:admin", "meeting:write"];
+
     /**
      * Add meeting registrant.
      *
@@ -355,20 +510,30 @@ impl Meetings {
         meeting_id: i64,
         occurrence_ids: &str,
     ) -> Result<crate::types::MeetingRegistrantCreateResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !occurrence_ids.is_empty() {
-            query_args.push(("occurrence_ids".to_string(), occurrence_ids.to_string()));
+            query_args.push(("occurrence_ids", occurrence_ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/meetings/{}/registrants?{}",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/meetings/{}/registrants",
+                self.client.encode_path(&meeting_id.to_string())
+            ),
+            &query_args,
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/meetings/{meetingId}/registrants",
+                &[("meetingId", meeting_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `meetingregistrantdelete`, any one of them grants access.
+    pub const MEETINGREGISTRANTDELETE_SCOPES: &[&str] = &["meeting:write:admin", "meeting:write"];
+
     /**
      * Delete a meeting registrant.
      *
@@ -391,21 +556,75 @@ impl Meetings {
         meeting_id: i64,
         registrant_id: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !occurrence_id.is_empty() {
-            query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
+            query_args.push(("occurrence_id", occurrence_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/meetings/{}/registrants/{}?{}",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
-            crate::progenitor_support::encode_path(&registrant_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/meetings/{}/registrants/{}",
+                self.client.encode_path(&meeting_id.to_string()),
+                self.client.encode_path(registrant_id)
+            ),
+            &query_args,
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/meetings/{meetingId}/registrants/{registrantId}",
+                &[
+                    ("meetingId", meeting_id.to_string()),
+                    ("registrantId", registrant_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Delete a meeting registrant.
+     *
+     * This function performs a `DELETE` to the `/meetings/{meetingId}/registrants/{registrantId}` endpoint.
+     *
+     * As opposed to `meetingregistrantdelete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn meetingregistrantdelete_if_exists(
+        &self,
+        occurrence_id: &str,
+        meeting_id: i64,
+        registrant_id: &str,
+    ) -> Result<bool> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !occurrence_id.is_empty() {
+            query_args.push(("occurrence_id", occurrence_id.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/meetings/{}/registrants/{}",
+                self.client.encode_path(&meeting_id.to_string()),
+                self.client.encode_path(registrant_id)
+            ),
+            &query_args,
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/meetings/{meetingId}/registrants/{registrantId}",
+                    &[
+                        ("meetingId", meeting_id.to_string()),
+                        ("registrantId", registrant_id.to_string()),
+                    ],
+                )
+            })
     }
 
+    /// The OAuth scopes for `registrant_status`, any one of them grants access.
+    pub const REGISTRANT_STATUS_SCOPES: &[&str] = &["meeting:write:admin", "meeting:write"];
+
     /**
      * Update registrant's status.
      *
@@ -428,22 +647,33 @@ impl Meetings {
         occurrence_id: &str,
         body: &crate::types::RegistrantStatus,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !occurrence_id.is_empty() {
-            query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
+            query_args.push(("occurrence_id", occurrence_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/meetings/{}/registrants/status?{}",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/meetings/{}/registrants/status",
+                self.client.encode_path(&meeting_id.to_string())
+            ),
+            &query_args,
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/meetings/{meetingId}/registrants/status",
+                    &[("meetingId", meeting_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `past_details`, any one of them grants access.
+    pub const PAST_DETAILS_SCOPES: &[&str] = &["meeting:read:admin", "meeting:read"];
+
     /**
      * Get past meeting details.
      *
@@ -465,14 +695,20 @@ impl Meetings {
         &self,
         meeting_uuid: &str,
     ) -> Result<crate::types::PastMeetingDetailsResponse> {
-        let url = format!(
-            "/past_meetings/{}",
-            crate::progenitor_support::encode_path(&meeting_uuid.to_string()),
-        );
-
-        self.client.get(&url, None).await
+        let url = format!("/past_meetings/{}", self.client.encode_path(meeting_uuid),);
+
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/past_meetings/{meetingUUID}",
+                &[("meetingUUID", meeting_uuid.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `past_participant`, any one of them grants access.
+    pub const PAST_PARTICIPANT_SCOPES: &[&str] = &["meeting:read:admin", "meeting:read"];
+
     /**
      * Get past meeting participants.
      *
@@ -502,23 +738,34 @@ impl Meetings {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<crate::types::PastMeetingParticipantsResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/past_meetings/{}/participants?{}",
-            crate::progenitor_support::encode_path(&meeting_uuid.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/past_meetings/{}/participants",
+                self.client.encode_path(meeting_uuid)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/past_meetings/{meetingUUID}/participants",
+                &[("meetingUUID", meeting_uuid.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `past`, any one of them grants access.
+    pub const PAST_SCOPES: &[&str] = &["meeting:read:admin", "meeting:read"];
+
     /**
      * List ended meeting instances.
      *
@@ -538,12 +785,21 @@ impl Meetings {
     pub async fn past(&self, meeting_id: i64) -> Result<crate::types::Domains> {
         let url = format!(
             "/past_meetings/{}/instances",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
+            self.client.encode_path(&meeting_id.to_string()),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/past_meetings/{meetingId}/instances",
+                &[("meetingId", meeting_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `poll`, any one of them grants access.
+    pub const POLL_SCOPES: &[&str] = &["meeting:read:admin", "meeting:read"];
+
     /**
      * List meeting polls.
      *
@@ -566,12 +822,21 @@ impl Meetings {
     pub async fn poll(&self, meeting_id: i64) -> Result<crate::types::Domains> {
         let url = format!(
             "/meetings/{}/polls",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
+            self.client.encode_path(&meeting_id.to_string()),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/meetings/{meetingId}/polls",
+                &[("meetingId", meeting_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `poll_create`, any one of them grants access.
+    pub const POLL_CREATE_SCOPES: &[&str] = &["meeting:write:admin", "meeting:write"];
+
     /**
      * Create a meeting poll.
      *
@@ -599,14 +864,24 @@ impl Meetings {
     ) -> Result<crate::types::MeetingPollGetResponseAllOf> {
         let url = format!(
             "/meetings/{}/polls",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
+            self.client.encode_path(&meeting_id.to_string()),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/meetings/{meetingId}/polls",
+                    &[("meetingId", meeting_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `poll_get`, any one of them grants access.
+    pub const POLL_GET_SCOPES: &[&str] = &["meeting:read:admin", "meeting:read"];
+
     /**
      * Get a meeting poll.
      *
@@ -634,13 +909,25 @@ impl Meetings {
     ) -> Result<crate::types::MeetingPollGetResponseAllOf> {
         let url = format!(
             "/meetings/{}/polls/{}",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
-            crate::progenitor_support::encode_path(&poll_id.to_string()),
+            self.client.encode_path(&meeting_id.to_string()),
+            self.client.encode_path(poll_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/meetings/{meetingId}/polls/{pollId}",
+                &[
+                    ("meetingId", meeting_id.to_string()),
+                    ("pollId", poll_id.to_string()),
+                ],
+            )
+        })
     }
 
+    /// The OAuth scopes for `poll_update`, any one of them grants access.
+    pub const POLL_UPDATE_SCOPES: &[&str] = &["meeting:write:admin", "meeting:write"];
+
     /**
      * Update a meeting poll.
      *
@@ -668,15 +955,28 @@ impl Meetings {
     ) -> Result<()> {
         let url = format!(
             "/meetings/{}/polls/{}",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
-            crate::progenitor_support::encode_path(&poll_id.to_string()),
+            self.client.encode_path(&meeting_id.to_string()),
+            self.client.encode_path(poll_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/meetings/{meetingId}/polls/{pollId}",
+                    &[
+                        ("meetingId", meeting_id.to_string()),
+                        ("pollId", poll_id.to_string()),
+                    ],
+                )
+            })
     }
 
+    /// The OAuth scopes for `poll_delete`, any one of them grants access.
+    pub const POLL_DELETE_SCOPES: &[&str] = &["meeting:write:admin", "meeting:write"];
+
     /**
      * Delete a meeting poll.
      *
@@ -700,13 +1000,54 @@ impl Meetings {
     pub async fn poll_delete(&self, meeting_id: i64, poll_id: &str) -> Result<()> {
         let url = format!(
             "/meetings/{}/polls/{}",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
-            crate::progenitor_support::encode_path(&poll_id.to_string()),
+            self.client.encode_path(&meeting_id.to_string()),
+            self.client.encode_path(poll_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/meetings/{meetingId}/polls/{pollId}",
+                &[
+                    ("meetingId", meeting_id.to_string()),
+                    ("pollId", poll_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Delete a meeting poll.
+     *
+     * This function performs a `DELETE` to the `/meetings/{meetingId}/polls/{pollId}` endpoint.
+     *
+     * As opposed to `poll_delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn poll_delete_if_exists(&self, meeting_id: i64, poll_id: &str) -> Result<bool> {
+        let url = format!(
+            "/meetings/{}/polls/{}",
+            self.client.encode_path(&meeting_id.to_string()),
+            self.client.encode_path(poll_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/meetings/{meetingId}/polls/{pollId}",
+                    &[
+                        ("meetingId", meeting_id.to_string()),
+                        ("pollId", poll_id.to_string()),
+                    ],
+                )
+            })
     }
 
+    /// The OAuth scopes for `registrants_questions_get`, any one of them grants access.
+    pub const REGISTRANTS_QUESTIONS_GET_SCOPES: &[&str] = &["meeting:read", "meeting:read:admin"];
+
     /**
      * List registration questions .
      *
@@ -732,12 +1073,22 @@ impl Meetings {
     ) -> Result<crate::types::MeetingRegistrantQuestionsData> {
         let url = format!(
             "/meetings/{}/registrants/questions",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
+            self.client.encode_path(&meeting_id.to_string()),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/meetings/{meetingId}/registrants/questions",
+                &[("meetingId", meeting_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `registrant_question_update`, any one of them grants access.
+    pub const REGISTRANT_QUESTION_UPDATE_SCOPES: &[&str] =
+        &["meeting:write", "meeting:write:admin"];
+
     /**
      * Update registration questions.
      *
@@ -763,14 +1114,54 @@ impl Meetings {
     ) -> Result<()> {
         let url = format!(
             "/meetings/{}/registrants/questions",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
+            self.client.encode_path(&meeting_id.to_string()),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/meetings/{meetingId}/registrants/questions",
+                    &[("meetingId", meeting_id.to_string())],
+                )
+            })
     }
 
+    /**
+     * Update registration questions.
+     *
+     * This function performs a `PATCH` to the `/meetings/{meetingId}/registrants/questions` endpoint.
+     *
+     * As opposed to `registrant_question_update`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn registrant_question_update_if_changed<C: serde::Serialize>(
+        &self,
+        meeting_id: i64,
+        current: &C,
+        body: &crate::types::MeetingRegistrantQuestionsData,
+    ) -> Result<Option<()>> {
+        let url = format!(
+            "/meetings/{}/registrants/questions",
+            self.client.encode_path(&meeting_id.to_string()),
+        );
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/meetings/{meetingId}/registrants/questions",
+                    &[("meetingId", meeting_id.to_string())],
+                )
+            })
+    }
+
+    /// The OAuth scopes for `invitation`, any one of them grants access.
+    pub const INVITATION_SCOPES: &[&str] = &["meeting:read:admin", "meeting:read"];
+
     /**
      * Get meeting invitation.
      *
@@ -792,12 +1183,21 @@ impl Meetings {
     pub async fn invitation(&self, meeting_id: i64) -> Result<crate::types::MeetingInvitation> {
         let url = format!(
             "/meetings/{}/invitation",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
+            self.client.encode_path(&meeting_id.to_string()),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/meetings/{meetingId}/invitation",
+                &[("meetingId", meeting_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `get_live_stream_details`, any one of them grants access.
+    pub const GET_LIVE_STREAM_DETAILS_SCOPES: &[&str] = &["meeting:read:admin", "meeting:read"];
+
     /**
      * Get live stream details.
      *
@@ -824,12 +1224,21 @@ impl Meetings {
     ) -> Result<crate::types::GetLiveStreamDetailsResponse> {
         let url = format!(
             "/meetings/{}/livestream",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
+            self.client.encode_path(meeting_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/meetings/{meetingId}/livestream",
+                &[("meetingId", meeting_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `live_stream_update`, any one of them grants access.
+    pub const LIVE_STREAM_UPDATE_SCOPES: &[&str] = &["meeting:write:admin", "meeting:write"];
+
     /**
      * Update a live stream.
      *
@@ -855,14 +1264,54 @@ impl Meetings {
     ) -> Result<()> {
         let url = format!(
             "/meetings/{}/livestream",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
+            self.client.encode_path(meeting_id),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/meetings/{meetingId}/livestream",
+                    &[("meetingId", meeting_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update a live stream.
+     *
+     * This function performs a `PATCH` to the `/meetings/{meetingId}/livestream` endpoint.
+     *
+     * As opposed to `live_stream_update`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn live_stream_update_if_changed<C: serde::Serialize>(
+        &self,
+        meeting_id: &str,
+        current: &C,
+        body: &crate::types::MeetingLiveStream,
+    ) -> Result<Option<()>> {
+        let url = format!(
+            "/meetings/{}/livestream",
+            self.client.encode_path(meeting_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/meetings/{meetingId}/livestream",
+                    &[("meetingId", meeting_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `live_stream_status_update`, any one of them grants access.
+    pub const LIVE_STREAM_STATUS_UPDATE_SCOPES: &[&str] = &["meeting:write:admin", "meeting:write"];
+
     /**
      * Update Live Stream Status.
      *
@@ -889,14 +1338,54 @@ impl Meetings {
     ) -> Result<()> {
         let url = format!(
             "/meetings/{}/livestream/status",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
+            self.client.encode_path(&meeting_id.to_string()),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/meetings/{meetingId}/livestream/status",
+                    &[("meetingId", meeting_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update Live Stream Status.
+     *
+     * This function performs a `PATCH` to the `/meetings/{meetingId}/livestream/status` endpoint.
+     *
+     * As opposed to `live_stream_status_update`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn live_stream_status_update_if_changed<C: serde::Serialize>(
+        &self,
+        meeting_id: i64,
+        current: &C,
+        body: &crate::types::MeetingLiveStreamStatus,
+    ) -> Result<Option<()>> {
+        let url = format!(
+            "/meetings/{}/livestream/status",
+            self.client.encode_path(&meeting_id.to_string()),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/meetings/{meetingId}/livestream/status",
+                    &[("meetingId", meeting_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `list_past_polls`, any one of them grants access.
+    pub const LIST_PAST_POLLS_SCOPES: &[&str] = &["meeting:read:admin", "meeting:read"];
+
     /**
      * List past meeting's poll results.
      *
@@ -922,12 +1411,21 @@ impl Meetings {
     ) -> Result<crate::types::ReportMeetingPollsResponse> {
         let url = format!(
             "/past_meetings/{}/polls",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
+            self.client.encode_path(meeting_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/past_meetings/{meetingId}/polls",
+                &[("meetingId", meeting_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `add_batch_registrants`, any one of them grants access.
+    pub const ADD_BATCH_REGISTRANTS_SCOPES: &[&str] = &["meeting:write", "meeting:write:admin"];
+
     /**
      * Perform batch registration.
      *
@@ -960,14 +1458,25 @@ impl Meetings {
     ) -> Result<crate::types::AddBatchRegistrantsResponse> {
         let url = format!(
             "/meetings/{}/batch_registrants",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
+            self.client.encode_path(meeting_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/meetings/{meetingId}/batch_registrants",
+                    &[("meetingId", meeting_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `recording_control`, any one of them grants access.
+    pub const RECORDING_CONTROL_SCOPES: &[&str] =
+        &["meeting:write", "meeting:write:admin", "meeting:master"];
+
     /**
      * Use in-Meeting recording controls.
      *
@@ -994,12 +1503,49 @@ impl Meetings {
     ) -> Result<()> {
         let url = format!(
             "/live_meetings/{}/events",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
+            self.client.encode_path(meeting_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/live_meetings/{meetingId}/events",
+                    &[("meetingId", meeting_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Use in-Meeting recording controls.
+     *
+     * This function performs a `PATCH` to the `/live_meetings/{meetingId}/events` endpoint.
+     *
+     * As opposed to `recording_control`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn recording_control_if_changed<C: serde::Serialize>(
+        &self,
+        meeting_id: &str,
+        current: &C,
+        body: &crate::types::InMeetingRecordingControlRequest,
+    ) -> Result<Option<()>> {
+        let url = format!(
+            "/live_meetings/{}/events",
+            self.client.encode_path(meeting_id),
+        );
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/live_meetings/{meetingId}/events",
+                    &[("meetingId", meeting_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -1011,9 +1557,15 @@ impl Meetings {
      */
     pub async fn quality_score(&self) -> Result<crate::types::Domains> {
         let url = "/metrics/quality".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/metrics/quality", &[]))
     }
 
+    /// The OAuth scopes for `create_batch_polls`, any one of them grants access.
+    pub const CREATE_BATCH_POLLS_SCOPES: &[&str] = &["meeting:write:admin", "meeting:write"];
+
     /**
      * Perform batch poll creation.
      *
@@ -1039,14 +1591,24 @@ impl Meetings {
     ) -> Result<crate::types::CreateBatchPollsResponse> {
         let url = format!(
             "/meetings/{}/batch_polls",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
+            self.client.encode_path(meeting_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/meetings/{meetingId}/batch_polls",
+                    &[("meetingId", meeting_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `list_template`, any one of them grants access.
+    pub const LIST_TEMPLATE_SCOPES: &[&str] = &["meeting:read", "meeting:read:admin"];
+
     /**
      * List meeting templates.
      *
@@ -1067,12 +1629,21 @@ impl Meetings {
     ) -> Result<crate::types::ListMeetingTemplatesResponseData> {
         let url = format!(
             "/users/{}/meeting_templates",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(user_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/users/{userId}/meeting_templates",
+                &[("userId", user_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `invite_links_create`, any one of them grants access.
+    pub const INVITE_LINKS_CREATE_SCOPES: &[&str] = &["meeting:write:admin", "meeting:write"];
+
     /**
      * Create meeting's invite links.
      *
@@ -1095,11 +1666,449 @@ impl Meetings {
     ) -> Result<crate::types::InviteLinks> {
         let url = format!(
             "/meetings/{}/invite_links",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
+            self.client.encode_path(&meeting_id.to_string()),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/meetings/{meetingId}/invite_links",
+                    &[("meetingId", meeting_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`Meetings`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn MeetingsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait MeetingsApi: Send + Sync {
+    async fn get(
+        &self,
+        user_id: &str,
+        type_: crate::types::MeetingsType,
+        page_size: i64,
+        next_page_token: &str,
+        page_number: &str,
+    ) -> Result<crate::types::Domains>;
+    async fn create(
+        &self,
+        user_id: &str,
+        body: &crate::types::MeetingCreate,
+    ) -> Result<crate::types::MeetingCreateResponseAllOf>;
+    async fn meeting(
+        &self,
+        meeting_id: i64,
+        occurrence_id: &str,
+        show_previous_occurrences: bool,
+    ) -> Result<crate::types::MeetingResponseAllOf>;
+    async fn delete(
+        &self,
+        meeting_id: i64,
+        occurrence_id: &str,
+        schedule_for_reminder: bool,
+        cancel_meeting_reminder: &str,
+    ) -> Result<()>;
+    async fn delete_if_exists(
+        &self,
+        meeting_id: i64,
+        occurrence_id: &str,
+        schedule_for_reminder: bool,
+        cancel_meeting_reminder: &str,
+    ) -> Result<bool>;
+    async fn update(
+        &self,
+        meeting_id: i64,
+        occurrence_id: &str,
+        body: &crate::types::MeetingUpdateRequestAllOf,
+    ) -> Result<()>;
+    async fn status(
+        &self,
+        meeting_id: i64,
+        body: &crate::types::MeetingStatusRequest,
+    ) -> Result<()>;
+    async fn registrant(
+        &self,
+        meeting_id: i64,
+        occurrence_id: &str,
+        status: crate::types::MeetingRegistrantsStatus,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::Domains>;
+    async fn registrant_create(
+        &self,
+        meeting_id: i64,
+        occurrence_ids: &str,
+    ) -> Result<crate::types::MeetingRegistrantCreateResponse>;
+    async fn meetingregistrantdelete(
+        &self,
+        occurrence_id: &str,
+        meeting_id: i64,
+        registrant_id: &str,
+    ) -> Result<()>;
+    async fn meetingregistrantdelete_if_exists(
+        &self,
+        occurrence_id: &str,
+        meeting_id: i64,
+        registrant_id: &str,
+    ) -> Result<bool>;
+    async fn registrant_status(
+        &self,
+        meeting_id: i64,
+        occurrence_id: &str,
+        body: &crate::types::RegistrantStatus,
+    ) -> Result<()>;
+    async fn past_details(
+        &self,
+        meeting_uuid: &str,
+    ) -> Result<crate::types::PastMeetingDetailsResponse>;
+    async fn past_participant(
+        &self,
+        meeting_uuid: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::PastMeetingParticipantsResponseAllOf>;
+    async fn past(&self, meeting_id: i64) -> Result<crate::types::Domains>;
+    async fn poll(&self, meeting_id: i64) -> Result<crate::types::Domains>;
+    async fn poll_create(
+        &self,
+        meeting_id: i64,
+        body: &crate::types::Poll,
+    ) -> Result<crate::types::MeetingPollGetResponseAllOf>;
+    async fn poll_get(
+        &self,
+        meeting_id: i64,
+        poll_id: &str,
+    ) -> Result<crate::types::MeetingPollGetResponseAllOf>;
+    async fn poll_update(
+        &self,
+        meeting_id: i64,
+        poll_id: &str,
+        body: &crate::types::Poll,
+    ) -> Result<()>;
+    async fn poll_delete(&self, meeting_id: i64, poll_id: &str) -> Result<()>;
+    async fn poll_delete_if_exists(&self, meeting_id: i64, poll_id: &str) -> Result<bool>;
+    async fn registrants_questions_get(
+        &self,
+        meeting_id: i64,
+    ) -> Result<crate::types::MeetingRegistrantQuestionsData>;
+    async fn registrant_question_update(
+        &self,
+        meeting_id: i64,
+        body: &crate::types::MeetingRegistrantQuestionsData,
+    ) -> Result<()>;
+    async fn invitation(&self, meeting_id: i64) -> Result<crate::types::MeetingInvitation>;
+    async fn get_live_stream_details(
+        &self,
+        meeting_id: &str,
+    ) -> Result<crate::types::GetLiveStreamDetailsResponse>;
+    async fn live_stream_update(
+        &self,
+        meeting_id: &str,
+        body: &crate::types::MeetingLiveStream,
+    ) -> Result<()>;
+    async fn live_stream_status_update(
+        &self,
+        meeting_id: i64,
+        body: &crate::types::MeetingLiveStreamStatus,
+    ) -> Result<()>;
+    async fn list_past_polls(
+        &self,
+        meeting_id: &str,
+    ) -> Result<crate::types::ReportMeetingPollsResponse>;
+    async fn add_batch_registrants(
+        &self,
+        meeting_id: &str,
+        body: &crate::types::AddBatchRegistrantsRequest,
+    ) -> Result<crate::types::AddBatchRegistrantsResponse>;
+    async fn recording_control(
+        &self,
+        meeting_id: &str,
+        body: &crate::types::InMeetingRecordingControlRequest,
+    ) -> Result<()>;
+    async fn quality_score(&self) -> Result<crate::types::Domains>;
+    async fn create_batch_polls(
+        &self,
+        meeting_id: &str,
+        body: &crate::types::CreateBatchPollsRequest,
+    ) -> Result<crate::types::CreateBatchPollsResponse>;
+    async fn list_template(
+        &self,
+        user_id: &str,
+    ) -> Result<crate::types::ListMeetingTemplatesResponseData>;
+    async fn invite_links_create(
+        &self,
+        meeting_id: i64,
+        body: &crate::types::InviteLink,
+    ) -> Result<crate::types::InviteLinks>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl MeetingsApi for Meetings {
+    async fn get(
+        &self,
+        user_id: &str,
+        type_: crate::types::MeetingsType,
+        page_size: i64,
+        next_page_token: &str,
+        page_number: &str,
+    ) -> Result<crate::types::Domains> {
+        Meetings::get(
+            self,
+            user_id,
+            type_,
+            page_size,
+            next_page_token,
+            page_number,
+        )
+        .await
+    }
+    async fn create(
+        &self,
+        user_id: &str,
+        body: &crate::types::MeetingCreate,
+    ) -> Result<crate::types::MeetingCreateResponseAllOf> {
+        Meetings::create(self, user_id, body).await
+    }
+    async fn meeting(
+        &self,
+        meeting_id: i64,
+        occurrence_id: &str,
+        show_previous_occurrences: bool,
+    ) -> Result<crate::types::MeetingResponseAllOf> {
+        Meetings::meeting(self, meeting_id, occurrence_id, show_previous_occurrences).await
+    }
+    async fn delete(
+        &self,
+        meeting_id: i64,
+        occurrence_id: &str,
+        schedule_for_reminder: bool,
+        cancel_meeting_reminder: &str,
+    ) -> Result<()> {
+        Meetings::delete(
+            self,
+            meeting_id,
+            occurrence_id,
+            schedule_for_reminder,
+            cancel_meeting_reminder,
+        )
+        .await
+    }
+    async fn delete_if_exists(
+        &self,
+        meeting_id: i64,
+        occurrence_id: &str,
+        schedule_for_reminder: bool,
+        cancel_meeting_reminder: &str,
+    ) -> Result<bool> {
+        Meetings::delete_if_exists(
+            self,
+            meeting_id,
+            occurrence_id,
+            schedule_for_reminder,
+            cancel_meeting_reminder,
+        )
+        .await
+    }
+    async fn update(
+        &self,
+        meeting_id: i64,
+        occurrence_id: &str,
+        body: &crate::types::MeetingUpdateRequestAllOf,
+    ) -> Result<()> {
+        Meetings::update(self, meeting_id, occurrence_id, body).await
+    }
+    async fn status(
+        &self,
+        meeting_id: i64,
+        body: &crate::types::MeetingStatusRequest,
+    ) -> Result<()> {
+        Meetings::status(self, meeting_id, body).await
+    }
+    async fn registrant(
+        &self,
+        meeting_id: i64,
+        occurrence_id: &str,
+        status: crate::types::MeetingRegistrantsStatus,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::Domains> {
+        Meetings::registrant(
+            self,
+            meeting_id,
+            occurrence_id,
+            status,
+            page_size,
+            page_number,
+            next_page_token,
+        )
+        .await
+    }
+    async fn registrant_create(
+        &self,
+        meeting_id: i64,
+        occurrence_ids: &str,
+    ) -> Result<crate::types::MeetingRegistrantCreateResponse> {
+        Meetings::registrant_create(self, meeting_id, occurrence_ids).await
+    }
+    async fn meetingregistrantdelete(
+        &self,
+        occurrence_id: &str,
+        meeting_id: i64,
+        registrant_id: &str,
+    ) -> Result<()> {
+        Meetings::meetingregistrantdelete(self, occurrence_id, meeting_id, registrant_id).await
+    }
+    async fn meetingregistrantdelete_if_exists(
+        &self,
+        occurrence_id: &str,
+        meeting_id: i64,
+        registrant_id: &str,
+    ) -> Result<bool> {
+        Meetings::meetingregistrantdelete_if_exists(self, occurrence_id, meeting_id, registrant_id)
             .await
     }
+    async fn registrant_status(
+        &self,
+        meeting_id: i64,
+        occurrence_id: &str,
+        body: &crate::types::RegistrantStatus,
+    ) -> Result<()> {
+        Meetings::registrant_status(self, meeting_id, occurrence_id, body).await
+    }
+    async fn past_details(
+        &self,
+        meeting_uuid: &str,
+    ) -> Result<crate::types::PastMeetingDetailsResponse> {
+        Meetings::past_details(self, meeting_uuid).await
+    }
+    async fn past_participant(
+        &self,
+        meeting_uuid: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::PastMeetingParticipantsResponseAllOf> {
+        Meetings::past_participant(self, meeting_uuid, page_size, next_page_token).await
+    }
+    async fn past(&self, meeting_id: i64) -> Result<crate::types::Domains> {
+        Meetings::past(self, meeting_id).await
+    }
+    async fn poll(&self, meeting_id: i64) -> Result<crate::types::Domains> {
+        Meetings::poll(self, meeting_id).await
+    }
+    async fn poll_create(
+        &self,
+        meeting_id: i64,
+        body: &crate::types::Poll,
+    ) -> Result<crate::types::MeetingPollGetResponseAllOf> {
+        Meetings::poll_create(self, meeting_id, body).await
+    }
+    async fn poll_get(
+        &self,
+        meeting_id: i64,
+        poll_id: &str,
+    ) -> Result<crate::types::MeetingPollGetResponseAllOf> {
+        Meetings::poll_get(self, meeting_id, poll_id).await
+    }
+    async fn poll_update(
+        &self,
+        meeting_id: i64,
+        poll_id: &str,
+        body: &crate::types::Poll,
+    ) -> Result<()> {
+        Meetings::poll_update(self, meeting_id, poll_id, body).await
+    }
+    async fn poll_delete(&self, meeting_id: i64, poll_id: &str) -> Result<()> {
+        Meetings::poll_delete(self, meeting_id, poll_id).await
+    }
+    async fn poll_delete_if_exists(&self, meeting_id: i64, poll_id: &str) -> Result<bool> {
+        Meetings::poll_delete_if_exists(self, meeting_id, poll_id).await
+    }
+    async fn registrants_questions_get(
+        &self,
+        meeting_id: i64,
+    ) -> Result<crate::types::MeetingRegistrantQuestionsData> {
+        Meetings::registrants_questions_get(self, meeting_id).await
+    }
+    async fn registrant_question_update(
+        &self,
+        meeting_id: i64,
+        body: &crate::types::MeetingRegistrantQuestionsData,
+    ) -> Result<()> {
+        Meetings::registrant_question_update(self, meeting_id, body).await
+    }
+    async fn invitation(&self, meeting_id: i64) -> Result<crate::types::MeetingInvitation> {
+        Meetings::invitation(self, meeting_id).await
+    }
+    async fn get_live_stream_details(
+        &self,
+        meeting_id: &str,
+    ) -> Result<crate::types::GetLiveStreamDetailsResponse> {
+        Meetings::get_live_stream_details(self, meeting_id).await
+    }
+    async fn live_stream_update(
+        &self,
+        meeting_id: &str,
+        body: &crate::types::MeetingLiveStream,
+    ) -> Result<()> {
+        Meetings::live_stream_update(self, meeting_id, body).await
+    }
+    async fn live_stream_status_update(
+        &self,
+        meeting_id: i64,
+        body: &crate::types::MeetingLiveStreamStatus,
+    ) -> Result<()> {
+        Meetings::live_stream_status_update(self, meeting_id, body).await
+    }
+    async fn list_past_polls(
+        &self,
+        meeting_id: &str,
+    ) -> Result<crate::types::ReportMeetingPollsResponse> {
+        Meetings::list_past_polls(self, meeting_id).await
+    }
+    async fn add_batch_registrants(
+        &self,
+        meeting_id: &str,
+        body: &crate::types::AddBatchRegistrantsRequest,
+    ) -> Result<crate::types::AddBatchRegistrantsResponse> {
+        Meetings::add_batch_registrants(self, meeting_id, body).await
+    }
+    async fn recording_control(
+        &self,
+        meeting_id: &str,
+        body: &crate::types::InMeetingRecordingControlRequest,
+    ) -> Result<()> {
+        Meetings::recording_control(self, meeting_id, body).await
+    }
+    async fn quality_score(&self) -> Result<crate::types::Domains> {
+        Meetings::quality_score(self).await
+    }
+    async fn create_batch_polls(
+        &self,
+        meeting_id: &str,
+        body: &crate::types::CreateBatchPollsRequest,
+    ) -> Result<crate::types::CreateBatchPollsResponse> {
+        Meetings::create_batch_polls(self, meeting_id, body).await
+    }
+    async fn list_template(
+        &self,
+        user_id: &str,
+    ) -> Result<crate::types::ListMeetingTemplatesResponseData> {
+        Meetings::list_template(self, user_id).await
+    }
+    async fn invite_links_create(
+        &self,
+        meeting_id: i64,
+        body: &crate::types::InviteLink,
+    ) -> Result<crate::types::InviteLinks> {
+        Meetings::invite_links_create(self, meeting_id, body).await
+    }
 }
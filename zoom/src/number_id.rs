@@ -0,0 +1,56 @@
+use std::fmt;
+
+/// The id of a Zoom Phone number, as the `/phone/numbers/{numberId}` methods of
+/// `Phone` take it, so it cannot be passed where they take a site or user id.
+/// Get it from the numbers `list_account_numbers` returns, with `number_id`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NumberId(String);
+
+impl NumberId {
+    pub fn new<S: AsRef<str>>(id: S) -> Self {
+        NumberId(id.as_ref().to_string())
+    }
+
+    /// The id, as it is sent to the API.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for NumberId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for NumberId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&str> for NumberId {
+    fn from(id: &str) -> Self {
+        NumberId::new(id)
+    }
+}
+
+impl From<String> for NumberId {
+    fn from(id: String) -> Self {
+        NumberId(id)
+    }
+}
+
+impl crate::types::ListAccountPhoneNumbersResponse {
+    /// The id of the number, to get or update its details with.
+    pub fn number_id(&self) -> NumberId {
+        NumberId::new(&self.id)
+    }
+}
+
+impl crate::types::GetPhoneNumberDetailsResponse {
+    /// The id of the number, to update its details with.
+    pub fn number_id(&self) -> NumberId {
+        NumberId::new(&self.id)
+    }
+}
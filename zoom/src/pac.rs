@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Pac {
     pub client: Client,
@@ -12,6 +10,17 @@ impl Pac {
         Pac { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Pac {
+            client: self.client.with_retry(policy),
+        }
+    }
+
+    /// The OAuth scopes for `user_pa_cs`, any one of them grants access.
+    pub const USER_PA_CS_SCOPES: &[&str] = &["pac:read:admin", "pac:read"];
+
     /**
      * List a user's PAC accounts.
      *
@@ -32,11 +41,31 @@ impl Pac {
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
      */
     pub async fn user_pa_cs(&self, user_id: &str) -> Result<crate::types::UserPaCsResponse> {
-        let url = format!(
-            "/users/{}/pac",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-        );
+        let url = format!("/users/{}/pac", self.client.encode_path(user_id),);
+
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/users/{userId}/pac",
+                &[("userId", user_id.to_string())],
+            )
+        })
+    }
+}
+
+/// The functions of [`Pac`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn PacApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait PacApi: Send + Sync {
+    async fn user_pa_cs(&self, user_id: &str) -> Result<crate::types::UserPaCsResponse>;
+}
 
-        self.client.get(&url, None).await
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl PacApi for Pac {
+    async fn user_pa_cs(&self, user_id: &str) -> Result<crate::types::UserPaCsResponse> {
+        Pac::user_pa_cs(self, user_id).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Phone {
     pub client: Client,
@@ -12,6 +10,17 @@ impl Phone {
         Phone { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Phone {
+            client: self.client.with_retry(policy),
+        }
+    }
+
+    /// The OAuth scopes for `set_up_account`, any one of them grants access.
+    pub const SET_UP_ACCOUNT_SCOPES: &[&str] = &["phone:write:admin", "phone:write"];
+
     /**
      * Set up a Zoom Phone account.
      *
@@ -37,14 +46,24 @@ impl Phone {
     ) -> Result<()> {
         let url = format!(
             "/accounts/{}/phone/setup",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/accounts/{accountId}/phone/setup",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `list_account_numbers`, any one of them grants access.
+    pub const LIST_ACCOUNT_NUMBERS_SCOPES: &[&str] = &["phone:read:admin"];
+
     /**
      * List phone numbers.
      *
@@ -61,61 +80,118 @@ impl Phone {
      * **Parameters:**
      *
      * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `type_: crate::types::ListAccountPhoneNumbersType` -- Query response by number assignment. The value can be one of the following:
+     * * `type_: Option<crate::types::ListAccountPhoneNumbersType>` -- Query response by number assignment. The value can be one of the following:
      *  <br>
      *  `assigned`: The number has been assigned to either a user, a call queue, an auto-receptionist or a common area phone in an account. <br>`unassigned`: The number is not assigned to anyone.<br>
      *  `all`: Include both assigned and unassigned numbers in the response.<br>
-     *  `byoc`: Include Bring Your Own Carrier (BYOC) numbers only in the response.
-     * * `extension_type: crate::types::ExtensionType` -- The type of assignee to whom the number is assigned. The value can be one of the following:<br>
+     *  `byoc`: Include Bring Your Own Carrier (BYOC) numbers only in the response. `None` leaves it off the query, for the API's default.
+     * * `extension_type: Option<crate::types::ExtensionType>` -- The type of assignee to whom the number is assigned. The value can be one of the following:<br>
      *  `user`<br> `callQueue`<br> `autoReceptionist`<br>
-     *  `commonAreaPhone`.
+     *  `commonAreaPhone`. `None` leaves it off the query, for the API's default.
      * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `number_type: crate::types::Type` -- The type of phone number. The value can be either `toll` or `tollfree`.
+     * * `number_type: Option<crate::types::Type>` -- The type of phone number. The value can be either `toll` or `tollfree`. `None` leaves it off the query, for the API's default.
      * * `pending_numbers: bool` -- Enable/disable the option for a sub account to use shared [Virtual Room Connector(s)](https://support.zoom.us/hc/en-us/articles/202134758-Getting-Started-With-Virtual-Room-Connector) that are set up by the master account. Virtual Room Connectors can only be used by On-prem users.
      * * `site_id: &str` -- Unique identifier of the site. Use this query parameter if you have enabled multiple sites and would like to filter the response of this API call by a specific phone site. See [Managing multiple sites](https://support.zoom.us/hc/en-us/articles/360020809672-Managing-multiple-sites) or [Adding a site](https://support.zoom.us/hc/en-us/articles/360020809672-Managing-multiple-sites#h_05c88e35-1593-491f-b1a8-b7139a75dc15) for details.
      */
     pub async fn list_account_numbers(
         &self,
         next_page_token: &str,
-        type_: crate::types::ListAccountPhoneNumbersType,
-        extension_type: crate::types::ExtensionType,
+        type_: Option<crate::types::ListAccountPhoneNumbersType>,
+        extension_type: Option<crate::types::ExtensionType>,
         page_size: i64,
-        number_type: crate::types::Type,
+        number_type: Option<crate::types::Type>,
         pending_numbers: bool,
         site_id: &str,
     ) -> Result<Vec<crate::types::ListAccountPhoneNumbersResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !extension_type.to_string().is_empty() {
-            query_args.push(("extension_type".to_string(), extension_type.to_string()));
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if let Some(v) = extension_type {
+            query_args.push(("extension_type", v.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
-        if !number_type.to_string().is_empty() {
-            query_args.push(("number_type".to_string(), number_type.to_string()));
+        if let Some(v) = number_type {
+            query_args.push(("number_type", v.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if pending_numbers {
-            query_args.push(("pending_numbers".to_string(), pending_numbers.to_string()));
+            query_args.push(("pending_numbers", pending_numbers.to_string()));
         }
+        let site_id = self.client.site_id_or(site_id);
         if !site_id.is_empty() {
-            query_args.push(("site_id".to_string(), site_id.to_string()));
+            query_args.push(("site_id", site_id.to_string()));
         }
-        if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+        if let Some(v) = type_ {
+            query_args.push(("type", v.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/numbers?{}", query_);
+        let url = crate::Client::url_with_query("/phone/numbers", &query_args);
 
-        let resp: crate::types::ListAccountPhoneNumbersResponseData =
-            self.client.get(&url, None).await?;
+        let resp: crate::types::ListAccountPhoneNumbersResponseData = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/numbers", &[]))?;
 
         // Return our response data.
         Ok(resp.phone_numbers)
     }
 
+    /**
+     * List phone numbers.
+     *
+     * This function performs a `GET` to the `/phone/numbers` endpoint.
+     *
+     * As opposed to `list_account_numbers`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
+     */
+    pub async fn list_account_numbers_page(
+        &self,
+        next_page_token: &str,
+        type_: Option<crate::types::ListAccountPhoneNumbersType>,
+        extension_type: Option<crate::types::ExtensionType>,
+        page_size: i64,
+        number_type: Option<crate::types::Type>,
+        pending_numbers: bool,
+        site_id: &str,
+    ) -> Result<(Vec<crate::types::ListAccountPhoneNumbersResponse>, String)> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if let Some(v) = extension_type {
+            query_args.push(("extension_type", v.to_string()));
+        }
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        if let Some(v) = number_type {
+            query_args.push(("number_type", v.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        if pending_numbers {
+            query_args.push(("pending_numbers", pending_numbers.to_string()));
+        }
+        let site_id = self.client.site_id_or(site_id);
+        if !site_id.is_empty() {
+            query_args.push(("site_id", site_id.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type", v.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/numbers", &query_args);
+
+        let resp: crate::types::ListAccountPhoneNumbersResponseData = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/numbers", &[]))?;
+
+        // Return our response data, and where the next page starts.
+        Ok((resp.phone_numbers, resp.next_page_token))
+    }
+
     /**
      * List phone numbers.
      *
@@ -133,33 +209,36 @@ impl Phone {
      */
     pub async fn list_all_account_numbers(
         &self,
-        type_: crate::types::ListAccountPhoneNumbersType,
-        extension_type: crate::types::ExtensionType,
-        number_type: crate::types::Type,
+        type_: Option<crate::types::ListAccountPhoneNumbersType>,
+        extension_type: Option<crate::types::ExtensionType>,
+        number_type: Option<crate::types::Type>,
         pending_numbers: bool,
         site_id: &str,
     ) -> Result<Vec<crate::types::ListAccountPhoneNumbersResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !extension_type.to_string().is_empty() {
-            query_args.push(("extension_type".to_string(), extension_type.to_string()));
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if let Some(v) = extension_type {
+            query_args.push(("extension_type", v.to_string()));
         }
-        if !number_type.to_string().is_empty() {
-            query_args.push(("number_type".to_string(), number_type.to_string()));
+        if let Some(v) = number_type {
+            query_args.push(("number_type", v.to_string()));
         }
         if pending_numbers {
-            query_args.push(("pending_numbers".to_string(), pending_numbers.to_string()));
+            query_args.push(("pending_numbers", pending_numbers.to_string()));
         }
+        let site_id = self.client.site_id_or(site_id);
         if !site_id.is_empty() {
-            query_args.push(("site_id".to_string(), site_id.to_string()));
+            query_args.push(("site_id", site_id.to_string()));
         }
-        if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+        if let Some(v) = type_ {
+            query_args.push(("type", v.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/numbers?{}", query_);
+        let url = crate::Client::url_with_query("/phone/numbers", &query_args);
 
-        let mut resp: crate::types::ListAccountPhoneNumbersResponseData =
-            self.client.get(&url, None).await?;
+        let mut resp: crate::types::ListAccountPhoneNumbersResponseData = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/numbers", &[]))?;
 
         let mut phone_numbers = resp.phone_numbers;
         let mut page = resp.next_page_token;
@@ -192,6 +271,127 @@ impl Phone {
         Ok(phone_numbers)
     }
 
+    /**
+     * List phone numbers.
+     *
+     * This function performs a `GET` to the `/phone/numbers` endpoint.
+     *
+     * As opposed to `list_account_numbers`, this function streams the items of all the pages, getting the next page only once the items of the last one are used up. The stream ends after the last page.
+     */
+    pub async fn list_account_numbers_stream(
+        &self,
+        type_: Option<crate::types::ListAccountPhoneNumbersType>,
+        extension_type: Option<crate::types::ExtensionType>,
+        number_type: Option<crate::types::Type>,
+        pending_numbers: bool,
+        site_id: &str,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::ListAccountPhoneNumbersResponse>>>
+    {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if let Some(v) = extension_type {
+            query_args.push(("extension_type", v.to_string()));
+        }
+        if let Some(v) = number_type {
+            query_args.push(("number_type", v.to_string()));
+        }
+        if pending_numbers {
+            query_args.push(("pending_numbers", pending_numbers.to_string()));
+        }
+        let site_id = self.client.site_id_or(site_id);
+        if !site_id.is_empty() {
+            query_args.push(("site_id", site_id.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type", v.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/numbers", &query_args);
+
+        self.client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListAccountPhoneNumbersResponseData| {
+                    (resp.phone_numbers, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/numbers", &[]))
+    }
+
+    /**
+     * List phone numbers.
+     *
+     * This function performs a `GET` to the `/phone/numbers` endpoint.
+     *
+     * As opposed to `list_account_numbers_stream`, this function streams only the items `predicate` keeps. It too gets the next page only once the items of the last one are used up, so a stream that is cut short, for example with `take`, gets no more pages than it needs.
+     */
+    pub async fn list_account_numbers_filter<
+        P: FnMut(&crate::types::ListAccountPhoneNumbersResponse) -> bool,
+    >(
+        &self,
+        type_: Option<crate::types::ListAccountPhoneNumbersType>,
+        extension_type: Option<crate::types::ExtensionType>,
+        number_type: Option<crate::types::Type>,
+        pending_numbers: bool,
+        site_id: &str,
+        mut predicate: P,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::ListAccountPhoneNumbersResponse>>>
+    {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if let Some(v) = extension_type {
+            query_args.push(("extension_type", v.to_string()));
+        }
+        if let Some(v) = number_type {
+            query_args.push(("number_type", v.to_string()));
+        }
+        if pending_numbers {
+            query_args.push(("pending_numbers", pending_numbers.to_string()));
+        }
+        let site_id = self.client.site_id_or(site_id);
+        if !site_id.is_empty() {
+            query_args.push(("site_id", site_id.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type", v.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/numbers", &query_args);
+
+        let items = self
+            .client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListAccountPhoneNumbersResponseData| {
+                    (resp.phone_numbers, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/numbers", &[]))?;
+        Ok(futures::TryStreamExt::try_filter(items, move |item| {
+            futures::future::ready(predicate(item))
+        }))
+    }
+
+    /**
+     * A request to `list_account_numbers`, to set its query parameters by name rather than by position.
+     * The ones left unset are left off the query.
+     */
+    pub fn list_account_numbers_request(&self) -> ListAccountNumbersRequest<'_> {
+        ListAccountNumbersRequest {
+            api: self,
+            next_page_token: None,
+            type_: None,
+            extension_type: None,
+            page_size: None,
+            number_type: None,
+            pending_numbers: None,
+            site_id: None,
+        }
+    }
+
+    /// The OAuth scopes for `user`, any one of them grants access.
+    pub const USER_SCOPES: &[&str] = &["phone:read", "phone:read:admin"];
+
     /**
      * Get user's profile.
      *
@@ -210,14 +410,20 @@ impl Phone {
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
      */
     pub async fn user(&self, user_id: &str) -> Result<crate::types::PhoneUserResponse> {
-        let url = format!(
-            "/phone/users/{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-        );
-
-        self.client.get(&url, None).await
+        let url = format!("/phone/users/{}", self.client.encode_path(user_id),);
+
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/phone/users/{userId}",
+                &[("userId", user_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `update_user_profile`, any one of them grants access.
+    pub const UPDATE_USER_PROFILE_SCOPES: &[&str] = &["phone:write", "phone:write:admin"];
+
     /**
      * Update user's profile.
      *
@@ -236,61 +442,74 @@ impl Phone {
         user_id: &str,
         body: &crate::types::UpdateUserProfileRequest,
     ) -> Result<()> {
-        let url = format!(
-            "/phone/users/{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-        );
+        let url = format!("/phone/users/{}", self.client.encode_path(user_id),);
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/phone/users/{userId}",
+                    &[("userId", user_id.to_string())],
+                )
+            })
     }
 
     /**
-     * Get account's setting.
-     *
-     * This function performs a `GET` to the `/phone/settings` endpoint.
-     *
-     * Use this API to return an account's settings.
+     * Update user's profile.
      *
-     * **Scopes:** `phone:read`, `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     * This function performs a `PATCH` to the `/phone/users/{userId}` endpoint.
      *
-     * **Prerequisites:**
-     * * A Business or Enterprise account
-     * * A Zoom Phone license
+     * As opposed to `update_user_profile`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
      */
-    pub async fn setting(&self, account_id: &str) -> Result<crate::types::PhoneSettingResponse> {
-        let url = "/phone/settings".to_string();
-        self.client.get(&url, None).await
+    pub async fn update_user_profile_if_changed<C: serde::Serialize>(
+        &self,
+        user_id: &str,
+        current: &C,
+        body: &crate::types::UpdateUserProfileRequest,
+    ) -> Result<Option<()>> {
+        let url = format!("/phone/users/{}", self.client.encode_path(user_id),);
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/phone/users/{userId}",
+                    &[("userId", user_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `setting`, any one of them grants access.
+    pub const SETTING_SCOPES: &[&str] = &["phone:read", "phone:read:admin"];
+
     /**
-     * Update BYOC settings.
+     * Get account's setting.
      *
-     * This function performs a `PATCH` to the `/phone/settings` endpoint.
+     * This function performs a `GET` to the `/phone/settings` endpoint.
      *
-     * [Master account owners](https://marketplace.zoom.us/docs/api-reference/master-account-apis) can use this API to enable the BYOC (Bring Your Own Carrier) option for a subaccount.
+     * Use this API to return an account's settings.
      *
-     * **Scopes:** `phone:master`
+     * **Scopes:** `phone:read`, `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
      *
      * **Prerequisites:**
      * * A Business or Enterprise account
-     *
-     * **Parameters:**
-     *
-     * * `account_id: &str` -- Unique identifier of the sub account.
+     * * A Zoom Phone license
      */
-    pub async fn update_settings(
-        &self,
-        account_id: &str,
-        body: &crate::types::UpdatePhoneSettingsRequest,
-    ) -> Result<()> {
+    pub async fn setting(&self, account_id: &str) -> Result<crate::types::PhoneSettingResponse> {
         let url = "/phone/settings".to_string();
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .get(&url, None)
             .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/settings", &[]))
     }
 
+    /// The OAuth scopes for `user_settings`, any one of them grants access.
+    pub const USER_SETTINGS_SCOPES: &[&str] = &["phone:read", "phone:read:admin"];
+
     /**
      * Get user's settings.
      *
@@ -312,14 +531,20 @@ impl Phone {
         &self,
         user_id: &str,
     ) -> Result<crate::types::PhoneUserSettingsResponse> {
-        let url = format!(
-            "/phone/users/{}/settings",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-        );
-
-        self.client.get(&url, None).await
+        let url = format!("/phone/users/{}/settings", self.client.encode_path(user_id),);
+
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/phone/users/{userId}/settings",
+                &[("userId", user_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `list_setting_templates`, any one of them grants access.
+    pub const LIST_SETTING_TEMPLATES_SCOPES: &[&str] = &["phone:read:admin", "phone:read"];
+
     /**
      * List setting templates.
      *
@@ -345,25 +570,65 @@ impl Phone {
         next_page_token: &str,
         site_id: &str,
     ) -> Result<Vec<crate::types::Templates>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
+        let site_id = self.client.site_id_or(site_id);
         if !site_id.is_empty() {
-            query_args.push(("site_id".to_string(), site_id.to_string()));
+            query_args.push(("site_id", site_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/setting_templates?{}", query_);
+        let url = crate::Client::url_with_query("/phone/setting_templates", &query_args);
 
-        let resp: crate::types::ListSettingTemplatesResponse = self.client.get(&url, None).await?;
+        let resp: crate::types::ListSettingTemplatesResponse =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url("GET", "/phone/setting_templates", &[])
+            })?;
 
         // Return our response data.
         Ok(resp.templates)
     }
 
+    /**
+     * List setting templates.
+     *
+     * This function performs a `GET` to the `/phone/setting_templates` endpoint.
+     *
+     * As opposed to `list_setting_templates`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
+     */
+    pub async fn list_setting_templates_page(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        site_id: &str,
+    ) -> Result<(Vec<crate::types::Templates>, String)> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        let site_id = self.client.site_id_or(site_id);
+        if !site_id.is_empty() {
+            query_args.push(("site_id", site_id.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/setting_templates", &query_args);
+
+        let resp: crate::types::ListSettingTemplatesResponse =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url("GET", "/phone/setting_templates", &[])
+            })?;
+
+        // Return our response data, and where the next page starts.
+        Ok((resp.templates, resp.next_page_token))
+    }
+
     /**
      * List setting templates.
      *
@@ -383,15 +648,17 @@ impl Phone {
         &self,
         site_id: &str,
     ) -> Result<Vec<crate::types::Templates>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        let site_id = self.client.site_id_or(site_id);
         if !site_id.is_empty() {
-            query_args.push(("site_id".to_string(), site_id.to_string()));
+            query_args.push(("site_id", site_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/setting_templates?{}", query_);
+        let url = crate::Client::url_with_query("/phone/setting_templates", &query_args);
 
         let mut resp: crate::types::ListSettingTemplatesResponse =
-            self.client.get(&url, None).await?;
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url("GET", "/phone/setting_templates", &[])
+            })?;
 
         let mut templates = resp.templates;
         let mut page = resp.next_page_token;
@@ -424,6 +691,74 @@ impl Phone {
         Ok(templates)
     }
 
+    /**
+     * List setting templates.
+     *
+     * This function performs a `GET` to the `/phone/setting_templates` endpoint.
+     *
+     * As opposed to `list_setting_templates`, this function streams the items of all the pages, getting the next page only once the items of the last one are used up. The stream ends after the last page.
+     */
+    pub async fn list_setting_templates_stream(
+        &self,
+        site_id: &str,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::Templates>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        let site_id = self.client.site_id_or(site_id);
+        if !site_id.is_empty() {
+            query_args.push(("site_id", site_id.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/setting_templates", &query_args);
+
+        self.client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListSettingTemplatesResponse| {
+                    (resp.templates, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/setting_templates", &[]))
+    }
+
+    /**
+     * List setting templates.
+     *
+     * This function performs a `GET` to the `/phone/setting_templates` endpoint.
+     *
+     * As opposed to `list_setting_templates_stream`, this function streams only the items `predicate` keeps. It too gets the next page only once the items of the last one are used up, so a stream that is cut short, for example with `take`, gets no more pages than it needs.
+     */
+    pub async fn list_setting_templates_filter<P: FnMut(&crate::types::Templates) -> bool>(
+        &self,
+        site_id: &str,
+        mut predicate: P,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::Templates>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        let site_id = self.client.site_id_or(site_id);
+        if !site_id.is_empty() {
+            query_args.push(("site_id", site_id.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/setting_templates", &query_args);
+
+        let items = self
+            .client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListSettingTemplatesResponse| {
+                    (resp.templates, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/setting_templates", &[]))?;
+        Ok(futures::TryStreamExt::try_filter(items, move |item| {
+            futures::future::ready(predicate(item))
+        }))
+    }
+
+    /// The OAuth scopes for `add_setting_template`, any one of them grants access.
+    pub const ADD_SETTING_TEMPLATE_SCOPES: &[&str] = &["phone:write:admin", "phone:write"];
+
     /**
      * Add a setting template.
      *
@@ -443,8 +778,9 @@ impl Phone {
     ) -> Result<crate::types::AddSettingTemplateResponse> {
         let url = "/phone/setting_templates".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/phone/setting_templates", &[]))
     }
 
     /**
@@ -460,10 +796,14 @@ impl Phone {
     ) -> Result<Vec<crate::types::BatchAddLocationsResponse>> {
         let url = "/phone/batch_locations".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/phone/batch_locations", &[]))
     }
 
+    /// The OAuth scopes for `list_locations`, any one of them grants access.
+    pub const LIST_LOCATIONS_SCOPES: &[&str] = &["phone:read:admin"];
+
     /**
      * List emergency service locations.
      *
@@ -487,22 +827,58 @@ impl Phone {
         next_page_token: &str,
         page_size: i64,
     ) -> Result<Vec<crate::types::ListLocationsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/locations?{}", query_);
+        let url = crate::Client::url_with_query("/phone/locations", &query_args);
 
-        let resp: crate::types::ListLocationsResponseData = self.client.get(&url, None).await?;
+        let resp: crate::types::ListLocationsResponseData = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/locations", &[]))?;
 
         // Return our response data.
         Ok(resp.locations)
     }
 
+    /**
+     * List emergency service locations.
+     *
+     * This function performs a `GET` to the `/phone/locations` endpoint.
+     *
+     * As opposed to `list_locations`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
+     */
+    pub async fn list_locations_page(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<(Vec<crate::types::ListLocationsResponse>, String)> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/locations", &query_args);
+
+        let resp: crate::types::ListLocationsResponseData = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/locations", &[]))?;
+
+        // Return our response data, and where the next page starts.
+        Ok((resp.locations, resp.next_page_token))
+    }
+
     /**
      * List emergency service locations.
      *
@@ -520,7 +896,11 @@ impl Phone {
      */
     pub async fn list_all_locations(&self) -> Result<Vec<crate::types::ListLocationsResponse>> {
         let url = "/phone/locations".to_string();
-        let mut resp: crate::types::ListLocationsResponseData = self.client.get(&url, None).await?;
+        let mut resp: crate::types::ListLocationsResponseData =
+            self.client
+                .get(&url, None)
+                .await
+                .with_context(|| crate::utils::pretty_url("GET", "/phone/locations", &[]))?;
 
         let mut locations = resp.locations;
         let mut page = resp.next_page_token;
@@ -553,6 +933,57 @@ impl Phone {
         Ok(locations)
     }
 
+    /**
+     * List emergency service locations.
+     *
+     * This function performs a `GET` to the `/phone/locations` endpoint.
+     *
+     * As opposed to `list_locations`, this function streams the items of all the pages, getting the next page only once the items of the last one are used up. The stream ends after the last page.
+     */
+    pub async fn list_locations_stream(
+        &self,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::ListLocationsResponse>>> {
+        let url = "/phone/locations".to_string();
+        self.client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListLocationsResponseData| {
+                    (resp.locations, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/locations", &[]))
+    }
+
+    /**
+     * List emergency service locations.
+     *
+     * This function performs a `GET` to the `/phone/locations` endpoint.
+     *
+     * As opposed to `list_locations_stream`, this function streams only the items `predicate` keeps. It too gets the next page only once the items of the last one are used up, so a stream that is cut short, for example with `take`, gets no more pages than it needs.
+     */
+    pub async fn list_locations_filter<P: FnMut(&crate::types::ListLocationsResponse) -> bool>(
+        &self,
+        mut predicate: P,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::ListLocationsResponse>>> {
+        let url = "/phone/locations".to_string();
+        let items = self
+            .client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListLocationsResponseData| {
+                    (resp.locations, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/locations", &[]))?;
+        Ok(futures::TryStreamExt::try_filter(items, move |item| {
+            futures::future::ready(predicate(item))
+        }))
+    }
+
     /**
      * Add emergency service location.
      *
@@ -569,13 +1000,35 @@ impl Phone {
     pub async fn add_location(
         &self,
         body: &crate::types::AddLocationRequest,
-    ) -> Result<Vec<crate::types::Site>> {
+    ) -> Result<crate::types::AddLocationResponse> {
+        let url = "/phone/locations".to_string();
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/phone/locations", &[]))
+    }
+
+    /**
+     * Add emergency service location.
+     *
+     * This function performs a `POST` to the `/phone/locations` endpoint.
+     *
+     * As opposed to `add_location`, which only returns the id of what it creates, this function then does a `GET` to the `/phone/locations/{locationId}` endpoint and returns all of it.
+     */
+    pub async fn add_location_and_fetch(
+        &self,
+        body: &crate::types::AddLocationRequest,
+    ) -> Result<crate::types::GetLocationResponse> {
         let url = "/phone/locations".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post_and_get(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/phone/locations", &[]))
     }
 
+    /// The OAuth scopes for `get_location`, any one of them grants access.
+    pub const GET_LOCATION_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Get emergency service location details.
      *
@@ -597,14 +1050,20 @@ impl Phone {
         &self,
         location_id: &str,
     ) -> Result<crate::types::GetLocationResponse> {
-        let url = format!(
-            "/phone/locations/{}",
-            crate::progenitor_support::encode_path(&location_id.to_string()),
-        );
-
-        self.client.get(&url, None).await
+        let url = format!("/phone/locations/{}", self.client.encode_path(location_id),);
+
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/phone/locations/{locationId}",
+                &[("locationId", location_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `delete_location`, any one of them grants access.
+    pub const DELETE_LOCATION_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Delete an emergency location.
      *
@@ -623,14 +1082,42 @@ impl Phone {
      * * `location_id: &str` -- The emergency service location's ID.
      */
     pub async fn delete_location(&self, location_id: &str) -> Result<()> {
-        let url = format!(
-            "/phone/locations/{}",
-            crate::progenitor_support::encode_path(&location_id.to_string()),
-        );
+        let url = format!("/phone/locations/{}", self.client.encode_path(location_id),);
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/phone/locations/{locationId}",
+                &[("locationId", location_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete an emergency location.
+     *
+     * This function performs a `DELETE` to the `/phone/locations/{locationId}` endpoint.
+     *
+     * As opposed to `delete_location`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_location_if_exists(&self, location_id: &str) -> Result<bool> {
+        let url = format!("/phone/locations/{}", self.client.encode_path(location_id),);
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/phone/locations/{locationId}",
+                    &[("locationId", location_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `update_location`, any one of them grants access.
+    pub const UPDATE_LOCATION_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Update emergency service location.
      *
@@ -649,22 +1136,56 @@ impl Phone {
         location_id: &str,
         body: &crate::types::UpdateLocationRequest,
     ) -> Result<()> {
-        let url = format!(
-            "/phone/locations/{}",
-            crate::progenitor_support::encode_path(&location_id.to_string()),
-        );
+        let url = format!("/phone/locations/{}", self.client.encode_path(location_id),);
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/phone/locations/{locationId}",
+                    &[("locationId", location_id.to_string())],
+                )
+            })
     }
 
     /**
-     * List SIP groups.
-     *
-     * This function performs a `GET` to the `/phone/sip_groups` endpoint.
+     * Update emergency service location.
      *
-     * Use this API to list SIP (Session Initiation Protocol) groups.
+     * This function performs a `PATCH` to the `/phone/locations/{locationId}` endpoint.
+     *
+     * As opposed to `update_location`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn update_location_if_changed<C: serde::Serialize>(
+        &self,
+        location_id: &str,
+        current: &C,
+        body: &crate::types::UpdateLocationRequest,
+    ) -> Result<Option<()>> {
+        let url = format!("/phone/locations/{}", self.client.encode_path(location_id),);
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/phone/locations/{locationId}",
+                    &[("locationId", location_id.to_string())],
+                )
+            })
+    }
+
+    /// The OAuth scopes for `list_sip_groups`, any one of them grants access.
+    pub const LIST_SIP_GROUPS_SCOPES: &[&str] = &["phone:read:admin"];
+
+    /**
+     * List SIP groups.
+     *
+     * This function performs a `GET` to the `/phone/sip_groups` endpoint.
+     *
+     * Use this API to list SIP (Session Initiation Protocol) groups.
      *
      * **Scopes:** `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
      *
@@ -682,22 +1203,58 @@ impl Phone {
         next_page_token: &str,
         page_size: i64,
     ) -> Result<Vec<crate::types::SipGroups>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/sip_groups?{}", query_);
+        let url = crate::Client::url_with_query("/phone/sip_groups", &query_args);
 
-        let resp: crate::types::ListSipGroupsResponse = self.client.get(&url, None).await?;
+        let resp: crate::types::ListSipGroupsResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/sip_groups", &[]))?;
 
         // Return our response data.
         Ok(resp.sip_groups)
     }
 
+    /**
+     * List SIP groups.
+     *
+     * This function performs a `GET` to the `/phone/sip_groups` endpoint.
+     *
+     * As opposed to `list_sip_groups`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
+     */
+    pub async fn list_sip_groups_page(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<(Vec<crate::types::SipGroups>, String)> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/sip_groups", &query_args);
+
+        let resp: crate::types::ListSipGroupsResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/sip_groups", &[]))?;
+
+        // Return our response data, and where the next page starts.
+        Ok((resp.sip_groups, resp.next_page_token))
+    }
+
     /**
      * List SIP groups.
      *
@@ -715,7 +1272,11 @@ impl Phone {
      */
     pub async fn list_all_sip_groups(&self) -> Result<Vec<crate::types::SipGroups>> {
         let url = "/phone/sip_groups".to_string();
-        let mut resp: crate::types::ListSipGroupsResponse = self.client.get(&url, None).await?;
+        let mut resp: crate::types::ListSipGroupsResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/sip_groups", &[]))?;
 
         let mut sip_groups = resp.sip_groups;
         let mut page = resp.next_page_token;
@@ -748,6 +1309,56 @@ impl Phone {
         Ok(sip_groups)
     }
 
+    /**
+     * List SIP groups.
+     *
+     * This function performs a `GET` to the `/phone/sip_groups` endpoint.
+     *
+     * As opposed to `list_sip_groups`, this function streams the items of all the pages, getting the next page only once the items of the last one are used up. The stream ends after the last page.
+     */
+    pub async fn list_sip_groups_stream(
+        &self,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::SipGroups>>> {
+        let url = "/phone/sip_groups".to_string();
+        self.client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListSipGroupsResponse| (resp.sip_groups, resp.next_page_token),
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/sip_groups", &[]))
+    }
+
+    /**
+     * List SIP groups.
+     *
+     * This function performs a `GET` to the `/phone/sip_groups` endpoint.
+     *
+     * As opposed to `list_sip_groups_stream`, this function streams only the items `predicate` keeps. It too gets the next page only once the items of the last one are used up, so a stream that is cut short, for example with `take`, gets no more pages than it needs.
+     */
+    pub async fn list_sip_groups_filter<P: FnMut(&crate::types::SipGroups) -> bool>(
+        &self,
+        mut predicate: P,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::SipGroups>>> {
+        let url = "/phone/sip_groups".to_string();
+        let items = self
+            .client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListSipGroupsResponse| (resp.sip_groups, resp.next_page_token),
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/sip_groups", &[]))?;
+        Ok(futures::TryStreamExt::try_filter(items, move |item| {
+            futures::future::ready(predicate(item))
+        }))
+    }
+
+    /// The OAuth scopes for `get_setting_template`, any one of them grants access.
+    pub const GET_SETTING_TEMPLATE_SCOPES: &[&str] = &["phone:write:admin", "phone:write"];
+
     /**
      * Get setting template details.
      *
@@ -771,23 +1382,30 @@ impl Phone {
         template_id: &str,
         custom_query_fields: &str,
     ) -> Result<crate::types::GetSettingTemplateResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_query_fields.is_empty() {
-            query_args.push((
-                "custom_query_fields".to_string(),
-                custom_query_fields.to_string(),
-            ));
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/phone/setting_templates/{}?{}",
-            crate::progenitor_support::encode_path(&template_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/phone/setting_templates/{}",
+                self.client.encode_path(template_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/phone/setting_templates/{templateId}",
+                &[("templateId", template_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `update_setting_template`, any one of them grants access.
+    pub const UPDATE_SETTING_TEMPLATE_SCOPES: &[&str] = &["phone:write:admin", "phone:write"];
+
     /**
      * Update a setting template.
      *
@@ -812,14 +1430,59 @@ impl Phone {
     ) -> Result<()> {
         let url = format!(
             "/phone/setting_templates/{}",
-            crate::progenitor_support::encode_path(&template_id.to_string()),
+            self.client.encode_path(template_id),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/phone/setting_templates/{templateId}",
+                    &[("templateId", template_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update a setting template.
+     *
+     * This function performs a `PATCH` to the `/phone/setting_templates/{templateId}` endpoint.
+     *
+     * As opposed to `update_setting_template`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn update_setting_template_if_changed<C: serde::Serialize>(
+        &self,
+        template_id: &str,
+        current: &C,
+        body: &crate::types::UpdateSettingTemplateRequest,
+    ) -> Result<Option<()>> {
+        let url = format!(
+            "/phone/setting_templates/{}",
+            self.client.encode_path(template_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/phone/setting_templates/{templateId}",
+                    &[("templateId", template_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `user_call_logs`, any one of them grants access.
+    pub const USER_CALL_LOGS_SCOPES: &[&str] = &[
+        "phone:read",
+        "phone:read:admin",
+        "phone_call_log:read",
+        "phone_call_log:read:admin",
+    ];
+
     /**
      * Get user's call logs.
      *
@@ -839,7 +1502,7 @@ impl Phone {
      * * `page_size: i64` -- The number of records returned within a single API call.
      * * `from: chrono::NaiveDate` -- Start date in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the report includes only one month worth of data at once.
      * * `to: chrono::NaiveDate` -- Start Date.
-     * * `type_: crate::types::PhoneUserCallLogsType`
+     * * `type_: Option<crate::types::PhoneUserCallLogsType>` -- `None` leaves it off the query, for the API's default.
      * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
      * * `phone_number: &str` -- Filter API responses to include call logs of only the phone number defined in this field.
      * * `time_type: crate::types::TimeType` -- Enables you to sort call logs by start or end time. Choose the sort time value. Values include `startTime` or `endTime`.
@@ -850,46 +1513,180 @@ impl Phone {
         page_size: i64,
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
-        type_: crate::types::PhoneUserCallLogsType,
+        type_: Option<crate::types::PhoneUserCallLogsType>,
         next_page_token: &str,
         phone_number: &str,
         time_type: crate::types::TimeType,
     ) -> Result<Vec<crate::types::CallLogs>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !phone_number.is_empty() {
-            query_args.push(("phone_number".to_string(), phone_number.to_string()));
+            query_args.push(("phone_number", phone_number.to_string()));
         }
-        if !time_type.to_string().is_empty() {
-            query_args.push(("time_type".to_string(), time_type.to_string()));
+        if time_type != crate::types::TimeType::default() {
+            query_args.push(("time_type", time_type.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+        if let Some(v) = type_ {
+            query_args.push(("type", v.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/phone/users/{}/call_logs?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/phone/users/{}/call_logs",
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
         );
 
-        let resp: crate::types::PhoneUserCallLogsResponse = self.client.get(&url, None).await?;
+        let resp: crate::types::PhoneUserCallLogsResponse =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/phone/users/{userId}/call_logs",
+                    &[("userId", user_id.to_string())],
+                )
+            })?;
 
         // Return our response data.
         Ok(resp.call_logs)
     }
 
+    /**
+     * Get user's call logs.
+     *
+     * This function performs a `GET` to the `/phone/users/{userId}/call_logs` endpoint.
+     *
+     * As opposed to `user_call_logs`, this function streams the items as they are parsed from the response, rather than returning them all at once.
+     * This is useful for very large responses that would otherwise need to be held in memory.
+     * Only the one page the parameters ask for is streamed, the next pages are not fetched.
+     */
+    pub async fn user_call_logs_stream_json(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        type_: Option<crate::types::PhoneUserCallLogsType>,
+        next_page_token: &str,
+        phone_number: &str,
+        time_type: crate::types::TimeType,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::CallLogs>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from.to_string().is_empty() {
+            query_args.push(("from", from.to_string()));
+        }
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        if !phone_number.is_empty() {
+            query_args.push(("phone_number", phone_number.to_string()));
+        }
+        if time_type != crate::types::TimeType::default() {
+            query_args.push(("time_type", time_type.to_string()));
+        }
+        if !to.to_string().is_empty() {
+            query_args.push(("to", to.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type", v.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/phone/users/{}/call_logs",
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .get_stream_json(&url, "call_logs")
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/phone/users/{userId}/call_logs",
+                    &[("userId", user_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Get user's call logs.
+     *
+     * This function performs a `GET` to the `/phone/users/{userId}/call_logs` endpoint.
+     *
+     * As opposed to `user_call_logs`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
+     */
+    pub async fn user_call_logs_page(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        type_: Option<crate::types::PhoneUserCallLogsType>,
+        next_page_token: &str,
+        phone_number: &str,
+        time_type: crate::types::TimeType,
+    ) -> Result<(Vec<crate::types::CallLogs>, String)> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from.to_string().is_empty() {
+            query_args.push(("from", from.to_string()));
+        }
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        if !phone_number.is_empty() {
+            query_args.push(("phone_number", phone_number.to_string()));
+        }
+        if time_type != crate::types::TimeType::default() {
+            query_args.push(("time_type", time_type.to_string()));
+        }
+        if !to.to_string().is_empty() {
+            query_args.push(("to", to.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type", v.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/phone/users/{}/call_logs",
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
+        );
+
+        let resp: crate::types::PhoneUserCallLogsResponse =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/phone/users/{userId}/call_logs",
+                    &[("userId", user_id.to_string())],
+                )
+            })?;
+
+        // Return our response data, and where the next page starts.
+        Ok((resp.call_logs, resp.next_page_token))
+    }
+
     /**
      * Get user's call logs.
      *
@@ -910,34 +1707,42 @@ impl Phone {
         user_id: &str,
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
-        type_: crate::types::PhoneUserCallLogsType,
+        type_: Option<crate::types::PhoneUserCallLogsType>,
         phone_number: &str,
         time_type: crate::types::TimeType,
     ) -> Result<Vec<crate::types::CallLogs>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !phone_number.is_empty() {
-            query_args.push(("phone_number".to_string(), phone_number.to_string()));
+            query_args.push(("phone_number", phone_number.to_string()));
         }
-        if !time_type.to_string().is_empty() {
-            query_args.push(("time_type".to_string(), time_type.to_string()));
+        if time_type != crate::types::TimeType::default() {
+            query_args.push(("time_type", time_type.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+        if let Some(v) = type_ {
+            query_args.push(("type", v.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/phone/users/{}/call_logs?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/phone/users/{}/call_logs",
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
         );
 
-        let mut resp: crate::types::PhoneUserCallLogsResponse = self.client.get(&url, None).await?;
+        let mut resp: crate::types::PhoneUserCallLogsResponse =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/phone/users/{userId}/call_logs",
+                    &[("userId", user_id.to_string())],
+                )
+            })?;
 
         let mut call_logs = resp.call_logs;
         let mut page = resp.next_page_token;
@@ -970,6 +1775,159 @@ impl Phone {
         Ok(call_logs)
     }
 
+    /**
+     * Get user's call logs.
+     *
+     * This function performs a `GET` to the `/phone/users/{userId}/call_logs` endpoint.
+     *
+     * As opposed to `user_call_logs`, this function streams the items of all the pages, getting the next page only once the items of the last one are used up. The stream ends after the last page.
+     */
+    pub async fn user_call_logs_stream(
+        &self,
+        user_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        type_: Option<crate::types::PhoneUserCallLogsType>,
+        phone_number: &str,
+        time_type: crate::types::TimeType,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::CallLogs>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from.to_string().is_empty() {
+            query_args.push(("from", from.to_string()));
+        }
+        if !phone_number.is_empty() {
+            query_args.push(("phone_number", phone_number.to_string()));
+        }
+        if time_type != crate::types::TimeType::default() {
+            query_args.push(("time_type", time_type.to_string()));
+        }
+        if !to.to_string().is_empty() {
+            query_args.push(("to", to.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type", v.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/phone/users/{}/call_logs",
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::PhoneUserCallLogsResponse| {
+                    (resp.call_logs, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/phone/users/{userId}/call_logs",
+                    &[("userId", user_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Get user's call logs.
+     *
+     * This function performs a `GET` to the `/phone/users/{userId}/call_logs` endpoint.
+     *
+     * As opposed to `user_call_logs_stream`, this function streams only the items `predicate` keeps. It too gets the next page only once the items of the last one are used up, so a stream that is cut short, for example with `take`, gets no more pages than it needs.
+     */
+    pub async fn user_call_logs_filter<P: FnMut(&crate::types::CallLogs) -> bool>(
+        &self,
+        user_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        type_: Option<crate::types::PhoneUserCallLogsType>,
+        phone_number: &str,
+        time_type: crate::types::TimeType,
+        mut predicate: P,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::CallLogs>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from.to_string().is_empty() {
+            query_args.push(("from", from.to_string()));
+        }
+        if !phone_number.is_empty() {
+            query_args.push(("phone_number", phone_number.to_string()));
+        }
+        if time_type != crate::types::TimeType::default() {
+            query_args.push(("time_type", time_type.to_string()));
+        }
+        if !to.to_string().is_empty() {
+            query_args.push(("to", to.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type", v.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/phone/users/{}/call_logs",
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
+        );
+
+        let items = self
+            .client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::PhoneUserCallLogsResponse| {
+                    (resp.call_logs, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/phone/users/{userId}/call_logs",
+                    &[("userId", user_id.to_string())],
+                )
+            })?;
+        Ok(futures::TryStreamExt::try_filter(items, move |item| {
+            futures::future::ready(predicate(item))
+        }))
+    }
+
+    /**
+     * A request to `user_call_logs`, to set its query parameters by name rather than by position.
+     * The ones left unset are left off the query.
+     */
+    pub fn user_call_logs_request(
+        &self,
+        user_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        time_type: crate::types::TimeType,
+    ) -> UserCallLogsRequest<'_> {
+        UserCallLogsRequest {
+            api: self,
+            user_id: user_id.to_string(),
+            page_size: None,
+            from,
+            to,
+            type_: None,
+            next_page_token: None,
+            phone_number: None,
+            time_type,
+        }
+    }
+
+    /// The OAuth scopes for `user_recordings`, any one of them grants access.
+    pub const USER_RECORDINGS_SCOPES: &[&str] = &[
+        "phone:read",
+        "phone:read:admin",
+        "phone_recording:read",
+        "phone_recording:read:admin",
+    ];
+
     /**
      * Get user's recordings.
      *
@@ -999,32 +1957,91 @@ impl Phone {
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> Result<Vec<crate::types::Recordings>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/phone/users/{}/recordings?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/phone/users/{}/recordings",
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
         );
 
-        let resp: crate::types::PhoneUserRecordingsResponse = self.client.get(&url, None).await?;
+        let resp: crate::types::PhoneUserRecordingsResponse =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/phone/users/{userId}/recordings",
+                    &[("userId", user_id.to_string())],
+                )
+            })?;
 
         // Return our response data.
         Ok(resp.recordings)
     }
 
+    /**
+     * Get user's recordings.
+     *
+     * This function performs a `GET` to the `/phone/users/{userId}/recordings` endpoint.
+     *
+     * As opposed to `user_recordings`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
+     */
+    pub async fn user_recordings_page(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<(Vec<crate::types::Recordings>, String)> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from.to_string().is_empty() {
+            query_args.push(("from", from.to_string()));
+        }
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        if !to.to_string().is_empty() {
+            query_args.push(("to", to.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/phone/users/{}/recordings",
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
+        );
+
+        let resp: crate::types::PhoneUserRecordingsResponse =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/phone/users/{userId}/recordings",
+                    &[("userId", user_id.to_string())],
+                )
+            })?;
+
+        // Return our response data, and where the next page starts.
+        Ok((resp.recordings, resp.next_page_token))
+    }
+
     /**
      * Get user's recordings.
      *
@@ -1046,22 +2063,29 @@ impl Phone {
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> Result<Vec<crate::types::Recordings>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/phone/users/{}/recordings?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/phone/users/{}/recordings",
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
         );
 
         let mut resp: crate::types::PhoneUserRecordingsResponse =
-            self.client.get(&url, None).await?;
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/phone/users/{userId}/recordings",
+                    &[("userId", user_id.to_string())],
+                )
+            })?;
 
         let mut recordings = resp.recordings;
         let mut page = resp.next_page_token;
@@ -1095,65 +2119,233 @@ impl Phone {
     }
 
     /**
-     * Get user's voicemails.
-     *
-     * This function performs a `GET` to the `/phone/users/{userId}/voice_mails` endpoint.
-     *
-     * Use this API to get a user's Zoom Phone voicemails. For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
-     *
-     * **Scopes:** `phone:read`, `phone:read:admin`, `phone_voicemail:read`, `phone_voicemail:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
-     *
-     * **Prerequisites:**
-     * * A Business or Enterprise account
-     * * A Zoom Phone license
+     * Get user's recordings.
      *
-     * **Parameters:**
+     * This function performs a `GET` to the `/phone/users/{userId}/recordings` endpoint.
      *
-     * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `status: crate::types::PhoneUserVoiceMailsStatus` -- Status of the voice mail.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `from: chrono::NaiveDate` -- Start date for the query in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the response includes only one month worth of voicemail data. The month defined should fall within the last six months.
-     * * `to: chrono::NaiveDate` -- Start Date.
+     * As opposed to `user_recordings`, this function streams the items of all the pages, getting the next page only once the items of the last one are used up. The stream ends after the last page.
      */
-    pub async fn user_voice_mails(
+    pub async fn user_recordings_stream(
         &self,
         user_id: &str,
-        page_size: i64,
-        status: crate::types::PhoneUserVoiceMailsStatus,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::Recordings>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from.to_string().is_empty() {
+            query_args.push(("from", from.to_string()));
+        }
+        if !to.to_string().is_empty() {
+            query_args.push(("to", to.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/phone/users/{}/recordings",
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::PhoneUserRecordingsResponse| {
+                    (resp.recordings, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/phone/users/{userId}/recordings",
+                    &[("userId", user_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Get user's recordings.
+     *
+     * This function performs a `GET` to the `/phone/users/{userId}/recordings` endpoint.
+     *
+     * As opposed to `user_recordings_stream`, this function streams only the items `predicate` keeps. It too gets the next page only once the items of the last one are used up, so a stream that is cut short, for example with `take`, gets no more pages than it needs.
+     */
+    pub async fn user_recordings_filter<P: FnMut(&crate::types::Recordings) -> bool>(
+        &self,
+        user_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        mut predicate: P,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::Recordings>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from.to_string().is_empty() {
+            query_args.push(("from", from.to_string()));
+        }
+        if !to.to_string().is_empty() {
+            query_args.push(("to", to.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/phone/users/{}/recordings",
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
+        );
+
+        let items = self
+            .client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::PhoneUserRecordingsResponse| {
+                    (resp.recordings, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/phone/users/{userId}/recordings",
+                    &[("userId", user_id.to_string())],
+                )
+            })?;
+        Ok(futures::TryStreamExt::try_filter(items, move |item| {
+            futures::future::ready(predicate(item))
+        }))
+    }
+
+    /// The OAuth scopes for `user_voice_mails`, any one of them grants access.
+    pub const USER_VOICE_MAILS_SCOPES: &[&str] = &[
+        "phone:read",
+        "phone:read:admin",
+        "phone_voicemail:read",
+        "phone_voicemail:read:admin",
+    ];
+
+    /**
+     * Get user's voicemails.
+     *
+     * This function performs a `GET` to the `/phone/users/{userId}/voice_mails` endpoint.
+     *
+     * Use this API to get a user's Zoom Phone voicemails. For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     *
+     * **Scopes:** `phone:read`, `phone:read:admin`, `phone_voicemail:read`, `phone_voicemail:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     * **Prerequisites:**
+     * * A Business or Enterprise account
+     * * A Zoom Phone license
+     *
+     * **Parameters:**
+     *
+     * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
+     * * `page_size: i64` -- The number of records returned within a single API call.
+     * * `status: crate::types::PhoneUserVoiceMailsStatus` -- Status of the voice mail.
+     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+     * * `from: chrono::NaiveDate` -- Start date for the query in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the response includes only one month worth of voicemail data. The month defined should fall within the last six months.
+     * * `to: chrono::NaiveDate` -- Start Date.
+     */
+    pub async fn user_voice_mails(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        status: crate::types::PhoneUserVoiceMailsStatus,
         next_page_token: &str,
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> Result<Vec<crate::types::VoiceMails>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/phone/users/{}/voice_mails?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/phone/users/{}/voice_mails",
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
         );
 
-        let resp: crate::types::PhoneUserVoiceMailsResponse = self.client.get(&url, None).await?;
+        let resp: crate::types::PhoneUserVoiceMailsResponse =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/phone/users/{userId}/voice_mails",
+                    &[("userId", user_id.to_string())],
+                )
+            })?;
 
         // Return our response data.
         Ok(resp.voice_mails)
     }
 
+    /**
+     * Get user's voicemails.
+     *
+     * This function performs a `GET` to the `/phone/users/{userId}/voice_mails` endpoint.
+     *
+     * As opposed to `user_voice_mails`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
+     */
+    pub async fn user_voice_mails_page(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        status: crate::types::PhoneUserVoiceMailsStatus,
+        next_page_token: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<(Vec<crate::types::VoiceMails>, String)> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from.to_string().is_empty() {
+            query_args.push(("from", from.to_string()));
+        }
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        if !status.to_string().is_empty() {
+            query_args.push(("status", status.to_string()));
+        }
+        if !to.to_string().is_empty() {
+            query_args.push(("to", to.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/phone/users/{}/voice_mails",
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
+        );
+
+        let resp: crate::types::PhoneUserVoiceMailsResponse =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/phone/users/{userId}/voice_mails",
+                    &[("userId", user_id.to_string())],
+                )
+            })?;
+
+        // Return our response data, and where the next page starts.
+        Ok((resp.voice_mails, resp.next_page_token))
+    }
+
     /**
      * Get user's voicemails.
      *
@@ -1176,25 +2368,32 @@ impl Phone {
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> Result<Vec<crate::types::VoiceMails>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/phone/users/{}/voice_mails?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/phone/users/{}/voice_mails",
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
         );
 
         let mut resp: crate::types::PhoneUserVoiceMailsResponse =
-            self.client.get(&url, None).await?;
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/phone/users/{userId}/voice_mails",
+                    &[("userId", user_id.to_string())],
+                )
+            })?;
 
         let mut voice_mails = resp.voice_mails;
         let mut page = resp.next_page_token;
@@ -1227,6 +2426,114 @@ impl Phone {
         Ok(voice_mails)
     }
 
+    /**
+     * Get user's voicemails.
+     *
+     * This function performs a `GET` to the `/phone/users/{userId}/voice_mails` endpoint.
+     *
+     * As opposed to `user_voice_mails`, this function streams the items of all the pages, getting the next page only once the items of the last one are used up. The stream ends after the last page.
+     */
+    pub async fn user_voice_mails_stream(
+        &self,
+        user_id: &str,
+        status: crate::types::PhoneUserVoiceMailsStatus,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::VoiceMails>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from.to_string().is_empty() {
+            query_args.push(("from", from.to_string()));
+        }
+        if !status.to_string().is_empty() {
+            query_args.push(("status", status.to_string()));
+        }
+        if !to.to_string().is_empty() {
+            query_args.push(("to", to.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/phone/users/{}/voice_mails",
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::PhoneUserVoiceMailsResponse| {
+                    (resp.voice_mails, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/phone/users/{userId}/voice_mails",
+                    &[("userId", user_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Get user's voicemails.
+     *
+     * This function performs a `GET` to the `/phone/users/{userId}/voice_mails` endpoint.
+     *
+     * As opposed to `user_voice_mails_stream`, this function streams only the items `predicate` keeps. It too gets the next page only once the items of the last one are used up, so a stream that is cut short, for example with `take`, gets no more pages than it needs.
+     */
+    pub async fn user_voice_mails_filter<P: FnMut(&crate::types::VoiceMails) -> bool>(
+        &self,
+        user_id: &str,
+        status: crate::types::PhoneUserVoiceMailsStatus,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        mut predicate: P,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::VoiceMails>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from.to_string().is_empty() {
+            query_args.push(("from", from.to_string()));
+        }
+        if !status.to_string().is_empty() {
+            query_args.push(("status", status.to_string()));
+        }
+        if !to.to_string().is_empty() {
+            query_args.push(("to", to.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/phone/users/{}/voice_mails",
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
+        );
+
+        let items = self
+            .client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::PhoneUserVoiceMailsResponse| {
+                    (resp.voice_mails, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/phone/users/{userId}/voice_mails",
+                    &[("userId", user_id.to_string())],
+                )
+            })?;
+        Ok(futures::TryStreamExt::try_filter(items, move |item| {
+            futures::future::ready(predicate(item))
+        }))
+    }
+
+    /// The OAuth scopes for `add_user_setting`, any one of them grants access.
+    pub const ADD_USER_SETTING_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Set up shared access.
      *
@@ -1256,15 +2563,28 @@ impl Phone {
     ) -> Result<crate::types::AddUserSettingResponse> {
         let url = format!(
             "/phone/users/{}/settings/{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&setting_type.to_string()),
+            self.client.encode_path(user_id),
+            self.client.encode_path(setting_type),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/phone/users/{userId}/settings/{settingType}",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("settingType", setting_type.to_string()),
+                    ],
+                )
+            })
     }
 
+    /// The OAuth scopes for `delete_user_setting`, any one of them grants access.
+    pub const DELETE_USER_SETTING_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Remove shared access.
      *
@@ -1291,21 +2611,75 @@ impl Phone {
         setting_type: &str,
         shared_id: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !shared_id.is_empty() {
-            query_args.push(("shared_id".to_string(), shared_id.to_string()));
+            query_args.push(("shared_id", shared_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/phone/users/{}/settings/{}?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&setting_type.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/phone/users/{}/settings/{}",
+                self.client.encode_path(user_id),
+                self.client.encode_path(setting_type)
+            ),
+            &query_args,
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/phone/users/{userId}/settings/{settingType}",
+                &[
+                    ("userId", user_id.to_string()),
+                    ("settingType", setting_type.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Remove shared access.
+     *
+     * This function performs a `DELETE` to the `/phone/users/{userId}/settings/{settingType}` endpoint.
+     *
+     * As opposed to `delete_user_setting`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_user_setting_if_exists(
+        &self,
+        user_id: &str,
+        setting_type: &str,
+        shared_id: &str,
+    ) -> Result<bool> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !shared_id.is_empty() {
+            query_args.push(("shared_id", shared_id.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/phone/users/{}/settings/{}",
+                self.client.encode_path(user_id),
+                self.client.encode_path(setting_type)
+            ),
+            &query_args,
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/phone/users/{userId}/settings/{settingType}",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("settingType", setting_type.to_string()),
+                    ],
+                )
+            })
     }
 
+    /// The OAuth scopes for `update_user_setting`, any one of them grants access.
+    pub const UPDATE_USER_SETTING_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Update shared access.
      *
@@ -1335,15 +2709,64 @@ impl Phone {
     ) -> Result<()> {
         let url = format!(
             "/phone/users/{}/settings/{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&setting_type.to_string()),
+            self.client.encode_path(user_id),
+            self.client.encode_path(setting_type),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/phone/users/{userId}/settings/{settingType}",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("settingType", setting_type.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * Update shared access.
+     *
+     * This function performs a `PATCH` to the `/phone/users/{userId}/settings/{settingType}` endpoint.
+     *
+     * As opposed to `update_user_setting`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn update_user_setting_if_changed<C: serde::Serialize>(
+        &self,
+        setting_type: &str,
+        user_id: &str,
+        current: &C,
+        body: &crate::types::UpdateUserSettingRequest,
+    ) -> Result<Option<()>> {
+        let url = format!(
+            "/phone/users/{}/settings/{}",
+            self.client.encode_path(user_id),
+            self.client.encode_path(setting_type),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/phone/users/{userId}/settings/{settingType}",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("settingType", setting_type.to_string()),
+                    ],
+                )
+            })
     }
 
+    /// The OAuth scopes for `account_call_logs`, any one of them grants access.
+    pub const ACCOUNT_CALL_LOGS_SCOPES: &[&str] =
+        &["phone:read:admin", "phone_call_log:read:admin"];
+
     /**
      * Get account's call logs.
      *
@@ -1384,35 +2807,40 @@ impl Phone {
         time_type: crate::types::TimeType,
         site_id: &str,
     ) -> Result<Vec<crate::types::AccountCallLogsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
+            query_args.push(("path", path.to_string()));
         }
+        let site_id = self.client.site_id_or(site_id);
         if !site_id.is_empty() {
-            query_args.push(("site_id".to_string(), site_id.to_string()));
+            query_args.push(("site_id", site_id.to_string()));
         }
-        if !time_type.to_string().is_empty() {
-            query_args.push(("time_type".to_string(), time_type.to_string()));
+        if time_type != crate::types::TimeType::default() {
+            query_args.push(("time_type", time_type.to_string()));
         }
         if !to.is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/call_logs?{}", query_);
+        let url = crate::Client::url_with_query("/phone/call_logs", &query_args);
 
-        let resp: crate::types::AccountCallLogsResponseData = self.client.get(&url, None).await?;
+        let resp: crate::types::AccountCallLogsResponseData = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/call_logs", &[]))?;
 
         // Return our response data.
         Ok(resp.call_logs)
@@ -1423,324 +2851,285 @@ impl Phone {
      *
      * This function performs a `GET` to the `/phone/call_logs` endpoint.
      *
-     * As opposed to `account_call_logs`, this function returns all the pages of the request at once.
-     *
-     * Use this API to return an account's [call logs](https://support.zoom.us/hc/en-us/articles/360021114452-Viewing-Call-Logs).
-     *
-     * **Scopes:** `phone:read:admin`, `phone_call_log:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`
-     *
-     * **Prerequisites:**
-     * * A Business or Enterprise account
-     * * A Zoom Phone license
-     * * Account owner and a [role](https://support.zoom.us/hc/en-us/articles/115001078646-Role-Based-Access-Control) with Zoom Phone management
+     * As opposed to `account_call_logs`, this function streams the items as they are parsed from the response, rather than returning them all at once.
+     * This is useful for very large responses that would otherwise need to be held in memory.
+     * Only the one page the parameters ask for is streamed, the next pages are not fetched.
      */
-    pub async fn get_all_account_call_logs(
+    pub async fn account_call_logs_stream_json(
         &self,
+        page_size: i64,
         from: &str,
         to: &str,
         type_: &str,
+        next_page_token: &str,
         path: &str,
         time_type: crate::types::TimeType,
         site_id: &str,
-    ) -> Result<Vec<crate::types::AccountCallLogsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::AccountCallLogsResponse>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
+        }
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !path.is_empty() {
-            query_args.push(("path".to_string(), path.to_string()));
+            query_args.push(("path", path.to_string()));
         }
+        let site_id = self.client.site_id_or(site_id);
         if !site_id.is_empty() {
-            query_args.push(("site_id".to_string(), site_id.to_string()));
+            query_args.push(("site_id", site_id.to_string()));
         }
-        if !time_type.to_string().is_empty() {
-            query_args.push(("time_type".to_string(), time_type.to_string()));
+        if time_type != crate::types::TimeType::default() {
+            query_args.push(("time_type", time_type.to_string()));
         }
         if !to.is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/call_logs?{}", query_);
-
-        let mut resp: crate::types::AccountCallLogsResponseData =
-            self.client.get(&url, None).await?;
-
-        let mut call_logs = resp.call_logs;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
-
-            call_logs.append(&mut resp.call_logs);
-
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+            query_args.push(("type", type_.to_string()));
         }
-
-        // Return our response data.
-        Ok(call_logs)
-    }
-
-    /**
-     * Assign phone number to user.
-     *
-     * This function performs a `POST` to the `/phone/users/{userId}/phone_numbers` endpoint.
-     *
-     * Use this API to assign a [phone number](https://support.zoom.us/hc/en-us/articles/360020808292-Managing-Phone-Numbers) to a user who has already enabled Zoom Phone. For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
-     *
-     * **Scopes:** `phone:write`, `phone:write:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
-     *
-     * **Prerequisites:**
-     * * A Business or Enterprise account
-     * * A Zoom Phone license
-     */
-    pub async fn assign_number(
-        &self,
-        user_id: &str,
-        body: &crate::types::AddByocNumberResponse,
-    ) -> Result<crate::types::AddByocNumberResponse> {
-        let url = format!(
-            "/phone/users/{}/phone_numbers",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-        );
+        let url = crate::Client::url_with_query("/phone/call_logs", &query_args);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .get_stream_json(&url, "call_logs")
             .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/call_logs", &[]))
     }
 
     /**
-     * Unassign phone number.
-     *
-     * This function performs a `DELETE` to the `/phone/users/{userId}/phone_numbers/{phoneNumberId}` endpoint.
-     *
-     * Use this API to unassign Zoom Phone user's [phone number](https://support.zoom.us/hc/en-us/articles/360020808292-Managing-Phone-Numbers#h_38ba8b01-26e3-4b1b-a9b5-0717c00a7ca6). For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     * Get account's call logs.
      *
-     * After assigning a phone number, you can remove it if you do not want it to be assigned to anyone.
+     * This function performs a `GET` to the `/phone/call_logs` endpoint.
      *
-     * **Scopes:** `phone:write`, `phone:write:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
-     *
-     * **Prerequisites:**
-     * * A Business or Enterprise account
-     * * A Zoom Phone license
-     * * The user must have been previously assigned a Zoom Phone number
-     *
-     * **Parameters:**
-     *
-     * * `user_id: &str` -- Provide either userId or email address of the user.
-     * * `phone_number_id: &str` -- Provide either phone number or phoneNumberId of the user. .
+     * As opposed to `account_call_logs`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
      */
-    pub async fn unassign_number(&self, user_id: &str, phone_number_id: &str) -> Result<()> {
-        let url = format!(
-            "/phone/users/{}/phone_numbers/{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&phone_number_id.to_string()),
-        );
+    pub async fn account_call_logs_page(
+        &self,
+        page_size: i64,
+        from: &str,
+        to: &str,
+        type_: &str,
+        next_page_token: &str,
+        path: &str,
+        time_type: crate::types::TimeType,
+        site_id: &str,
+    ) -> Result<(Vec<crate::types::AccountCallLogsResponse>, String)> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from.is_empty() {
+            query_args.push(("from", from.to_string()));
+        }
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        if !path.is_empty() {
+            query_args.push(("path", path.to_string()));
+        }
+        let site_id = self.client.site_id_or(site_id);
+        if !site_id.is_empty() {
+            query_args.push(("site_id", site_id.to_string()));
+        }
+        if time_type != crate::types::TimeType::default() {
+            query_args.push(("time_type", time_type.to_string()));
+        }
+        if !to.is_empty() {
+            query_args.push(("to", to.to_string()));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/call_logs", &query_args);
+
+        let resp: crate::types::AccountCallLogsResponseData = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/call_logs", &[]))?;
 
-        self.client.delete(&url, None).await
+        // Return our response data, and where the next page starts.
+        Ok((resp.call_logs, resp.next_page_token))
     }
 
     /**
-     * Assign calling plan to a user.
-     *
-     * This function performs a `POST` to the `/phone/users/{userId}/calling_plans` endpoint.
-     *
-     * Use this API to assign a [calling plan](https://marketplace.zoom.us/docs/api-reference/other-references/plans#zoom-phone-calling-plans) to a [Zoom Phone](https://support.zoom.us/hc/en-us/categories/360001370051-Zoom-Phone) user. For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     * Get account's call logs.
      *
-     * **Scopes:** `phone:write`, `phone:write:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     * This function performs a `GET` to the `/phone/call_logs` endpoint.
      *
-     * **Prerequisites:**
-     * * A Business or Enterprise account
-     * * A Zoom Phone license
+     * As opposed to `account_call_logs`, this function takes `from` and `to` as times, and sends them in UTC like `2021-08-15T01:52:41Z`.
      */
-    pub async fn assign_calling_plan(
+    pub async fn account_call_logs_between(
         &self,
-        user_id: &str,
-        body: &crate::types::AssignCallingPlanRequest,
-    ) -> Result<()> {
-        let url = format!(
-            "/phone/users/{}/calling_plans",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-        );
+        page_size: i64,
+        from: chrono::DateTime<chrono::Utc>,
+        to: chrono::DateTime<chrono::Utc>,
+        type_: &str,
+        next_page_token: &str,
+        path: &str,
+        time_type: crate::types::TimeType,
+        site_id: &str,
+    ) -> Result<Vec<crate::types::AccountCallLogsResponse>> {
+        let from = crate::utils::date_time_param(&from);
+        let to = crate::utils::date_time_param(&to);
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from.is_empty() {
+            query_args.push(("from", from.to_string()));
+        }
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        if !path.is_empty() {
+            query_args.push(("path", path.to_string()));
+        }
+        let site_id = self.client.site_id_or(site_id);
+        if !site_id.is_empty() {
+            query_args.push(("site_id", site_id.to_string()));
+        }
+        if time_type != crate::types::TimeType::default() {
+            query_args.push(("time_type", time_type.to_string()));
+        }
+        if !to.is_empty() {
+            query_args.push(("to", to.to_string()));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/call_logs", &query_args);
 
-        self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+        let resp: crate::types::AccountCallLogsResponseData = self
+            .client
+            .get(&url, None)
             .await
-    }
-
-    /**
-     * Unassign user's calling plan.
-     *
-     * This function performs a `DELETE` to the `/phone/users/{userId}/calling_plans/{type}` endpoint.
-     *
-     * Use this API to unassign a a [Zoom Phone](https://support.zoom.us/hc/en-us/categories/360001370051) user's [calling plan](https://marketplace.zoom.us/docs/api-reference/other-references/plans#zoom-phone-calling-plans). For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
-     *
-     * **Scopes:** `phone:write`, `phone:write:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
-     *
-     * **Prerequisites:**
-     * * A Business or Enterprise account
-     * * A Zoom Phone license
-     *
-     * **Parameters:**
-     *
-     * * `type_: &str` -- The [type](https://marketplace.zoom.us/docs/api-reference/other-references/plans#zoom-phone-calling-plans) of the calling plan that was assigned to user. (e.g: The value of type would be "200" for Unlimited US/Canada calling plan.)
-     *   .
-     */
-    pub async fn unassign_calling_plan(&self, user_id: &str, type_: &str) -> Result<()> {
-        let url = format!(
-            "/phone/users/{}/calling_plans/{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&type_.to_string()),
-        );
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/call_logs", &[]))?;
 
-        self.client.delete(&url, None).await
+        // Return our response data.
+        Ok(resp.call_logs)
     }
 
     /**
-     * Get call recordings.
-     *
-     * This function performs a `GET` to the `/phone/recordings` endpoint.
-     *
-     * Use this API to list an account's [call recordings](https://support.zoom.us/hc/en-us/articles/360038521091-Accessing-and-sharing-call-recordings)
-     *
-     * **Scopes:** `phone:read:admin`, `phone:write:admin`,`phone_recording:read:admin`
-     *
-     * **Prerequisties:**
-     * * A Pro or higher account plan
-     * * A Zoom Phone license
-     * * Account owner or admin privileges
+     * Get account's call logs.
      *
-     * **Parameters:**
+     * This function performs a `GET` to the `/phone/call_logs` endpoint.
      *
-     * * `page_size: i64` -- The number of records returned within a single API call. The default is **30**, and the maximum is **100**.
-     * * `next_page_token: &str` -- The current page number of returned records.
-     * * `from: &str` -- Start date and time in **yyyy-mm-dd** format or **yyyy-MM-dd’T’HH:mm:ss’Z’** format. The date range defined by the from and to parameters should only be one month as the report includes only one month worth of data at once.
-     *   .
-     * * `to: &str` -- End date and time in **yyyy-mm-dd** format or **yyyy-MM-dd’T’HH:mm:ss’Z’** format, the same formats supported by the `from` parameter.
-     *   
-     *   .
-     * * `owner_type: &str` -- The owner type. The allowed values are null, `user`, or `callQueue`. The default is null. If null, returns all owner types.
-     *   .
-     * * `recording_type: &str` -- The recording type. The allowed values are null, `OnDemand`, or `Automatic`. The default is null. If null, returns all recording types.
-     *   .
-     * * `site_id: &str` -- The site ID. The default is `All sites`.
-     * * `query_date_type: crate::types::QueryDateType` -- Date types:<br>`start_time` - Query by call start time.<br>`end_time` - Query by call end time.
+     * As opposed to `account_call_logs_between`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
      */
-    pub async fn get_recordings(
+    pub async fn account_call_logs_between_page(
         &self,
         page_size: i64,
+        from: chrono::DateTime<chrono::Utc>,
+        to: chrono::DateTime<chrono::Utc>,
+        type_: &str,
         next_page_token: &str,
-        from: &str,
-        to: &str,
-        owner_type: &str,
-        recording_type: &str,
+        path: &str,
+        time_type: crate::types::TimeType,
         site_id: &str,
-        query_date_type: crate::types::QueryDateType,
-    ) -> Result<Vec<crate::types::GetPhoneRecordingsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    ) -> Result<(Vec<crate::types::AccountCallLogsResponse>, String)> {
+        let from = crate::utils::date_time_param(&from);
+        let to = crate::utils::date_time_param(&to);
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
-        }
-        if !owner_type.is_empty() {
-            query_args.push(("owner_type".to_string(), owner_type.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        if !query_date_type.to_string().is_empty() {
-            query_args.push(("query_date_type".to_string(), query_date_type.to_string()));
-        }
-        if !recording_type.is_empty() {
-            query_args.push(("recording_type".to_string(), recording_type.to_string()));
+        if !path.is_empty() {
+            query_args.push(("path", path.to_string()));
         }
+        let site_id = self.client.site_id_or(site_id);
         if !site_id.is_empty() {
-            query_args.push(("site_id".to_string(), site_id.to_string()));
+            query_args.push(("site_id", site_id.to_string()));
+        }
+        if time_type != crate::types::TimeType::default() {
+            query_args.push(("time_type", time_type.to_string()));
         }
         if !to.is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/recordings?{}", query_);
+        let url = crate::Client::url_with_query("/phone/call_logs", &query_args);
 
-        let resp: crate::types::GetPhoneRecordingsResponseData =
-            self.client.get(&url, None).await?;
+        let resp: crate::types::AccountCallLogsResponseData = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/call_logs", &[]))?;
 
-        // Return our response data.
-        Ok(resp.recordings)
+        // Return our response data, and where the next page starts.
+        Ok((resp.call_logs, resp.next_page_token))
     }
 
     /**
-     * Get call recordings.
+     * Get account's call logs.
      *
-     * This function performs a `GET` to the `/phone/recordings` endpoint.
+     * This function performs a `GET` to the `/phone/call_logs` endpoint.
      *
-     * As opposed to `get_recordings`, this function returns all the pages of the request at once.
+     * As opposed to `account_call_logs`, this function returns all the pages of the request at once.
      *
-     * Use this API to list an account's [call recordings](https://support.zoom.us/hc/en-us/articles/360038521091-Accessing-and-sharing-call-recordings)
+     * Use this API to return an account's [call logs](https://support.zoom.us/hc/en-us/articles/360021114452-Viewing-Call-Logs).
      *
-     * **Scopes:** `phone:read:admin`, `phone:write:admin`,`phone_recording:read:admin`
+     * **Scopes:** `phone:read:admin`, `phone_call_log:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`
      *
-     * **Prerequisties:**
-     * * A Pro or higher account plan
+     * **Prerequisites:**
+     * * A Business or Enterprise account
      * * A Zoom Phone license
-     * * Account owner or admin privileges
+     * * Account owner and a [role](https://support.zoom.us/hc/en-us/articles/115001078646-Role-Based-Access-Control) with Zoom Phone management
      */
-    pub async fn get_all_recordings(
+    pub async fn get_all_account_call_logs(
         &self,
         from: &str,
         to: &str,
-        owner_type: &str,
-        recording_type: &str,
+        type_: &str,
+        path: &str,
+        time_type: crate::types::TimeType,
         site_id: &str,
-        query_date_type: crate::types::QueryDateType,
-    ) -> Result<Vec<crate::types::GetPhoneRecordingsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+    ) -> Result<Vec<crate::types::AccountCallLogsResponse>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
-        if !owner_type.is_empty() {
-            query_args.push(("owner_type".to_string(), owner_type.to_string()));
-        }
-        if !query_date_type.to_string().is_empty() {
-            query_args.push(("query_date_type".to_string(), query_date_type.to_string()));
-        }
-        if !recording_type.is_empty() {
-            query_args.push(("recording_type".to_string(), recording_type.to_string()));
+        if !path.is_empty() {
+            query_args.push(("path", path.to_string()));
         }
+        let site_id = self.client.site_id_or(site_id);
         if !site_id.is_empty() {
-            query_args.push(("site_id".to_string(), site_id.to_string()));
+            query_args.push(("site_id", site_id.to_string()));
+        }
+        if time_type != crate::types::TimeType::default() {
+            query_args.push(("time_type", time_type.to_string()));
         }
         if !to.is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/recordings?{}", query_);
+        if !type_.is_empty() {
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/call_logs", &query_args);
 
-        let mut resp: crate::types::GetPhoneRecordingsResponseData =
-            self.client.get(&url, None).await?;
+        let mut resp: crate::types::AccountCallLogsResponseData = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/call_logs", &[]))?;
 
-        let mut recordings = resp.recordings;
+        let mut call_logs = resp.call_logs;
         let mut page = resp.next_page_token;
 
         // Paginate if we should.
@@ -1758,7 +3147,7 @@ impl Phone {
                     .await?;
             }
 
-            recordings.append(&mut resp.recordings);
+            call_logs.append(&mut resp.call_logs);
 
             if !resp.next_page_token.is_empty() && resp.next_page_token != page {
                 page = resp.next_page_token.to_string();
@@ -1768,538 +3157,678 @@ impl Phone {
         }
 
         // Return our response data.
-        Ok(recordings)
+        Ok(call_logs)
     }
 
     /**
-     * List BYOC SIP trunks.
-     *
-     * This function performs a `GET` to the `/phone/sip_trunk/trunks` endpoint.
-     *
-     * Use this API to return a list of an account's assigned [BYOC (Bring Your Own Carrier) SIP (Session Initiation Protocol) trunks](https://zoom.us/docs/doc/Zoom-Bring%20Your%20Own%20Carrier.pdf).
-     *
-     * **Scopes:** `phone:write:admin` or `phone:master`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
-     *
-     * **Prerequisites:**
-     * * A Business or Enterprise account
+     * Get account's call logs.
      *
-     * **Parameters:**
+     * This function performs a `GET` to the `/phone/call_logs` endpoint.
      *
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `page_size: i64` -- The number of records returned within a single API call.
+     * As opposed to `account_call_logs`, this function streams the items of all the pages, getting the next page only once the items of the last one are used up. The stream ends after the last page.
      */
-    pub async fn list_byocsip_trunk(
+    pub async fn account_call_logs_stream(
         &self,
-        next_page_token: &str,
-        page_size: i64,
-    ) -> Result<Vec<crate::types::ByocSipTrunk>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+        from: &str,
+        to: &str,
+        type_: &str,
+        path: &str,
+        time_type: crate::types::TimeType,
+        site_id: &str,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::AccountCallLogsResponse>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from.is_empty() {
+            query_args.push(("from", from.to_string()));
         }
-        if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+        if !path.is_empty() {
+            query_args.push(("path", path.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/sip_trunk/trunks?{}", query_);
-
-        let resp: crate::types::ListByocsipTrunkResponse = self.client.get(&url, None).await?;
+        let site_id = self.client.site_id_or(site_id);
+        if !site_id.is_empty() {
+            query_args.push(("site_id", site_id.to_string()));
+        }
+        if time_type != crate::types::TimeType::default() {
+            query_args.push(("time_type", time_type.to_string()));
+        }
+        if !to.is_empty() {
+            query_args.push(("to", to.to_string()));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/call_logs", &query_args);
 
-        // Return our response data.
-        Ok(resp.byoc_sip_trunk)
+        self.client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::AccountCallLogsResponseData| {
+                    (resp.call_logs, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/call_logs", &[]))
     }
 
     /**
-     * List BYOC SIP trunks.
-     *
-     * This function performs a `GET` to the `/phone/sip_trunk/trunks` endpoint.
-     *
-     * As opposed to `list_byocsip_trunk`, this function returns all the pages of the request at once.
-     *
-     * Use this API to return a list of an account's assigned [BYOC (Bring Your Own Carrier) SIP (Session Initiation Protocol) trunks](https://zoom.us/docs/doc/Zoom-Bring%20Your%20Own%20Carrier.pdf).
+     * Get account's call logs.
      *
-     * **Scopes:** `phone:write:admin` or `phone:master`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     * This function performs a `GET` to the `/phone/call_logs` endpoint.
      *
-     * **Prerequisites:**
-     * * A Business or Enterprise account
+     * As opposed to `account_call_logs_stream`, this function streams only the items `predicate` keeps. It too gets the next page only once the items of the last one are used up, so a stream that is cut short, for example with `take`, gets no more pages than it needs.
      */
-    pub async fn list_all_byocsip_trunk(&self) -> Result<Vec<crate::types::ByocSipTrunk>> {
-        let url = "/phone/sip_trunk/trunks".to_string();
-        let mut resp: crate::types::ListByocsipTrunkResponse = self.client.get(&url, None).await?;
-
-        let mut byoc_sip_trunk = resp.byoc_sip_trunk;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
-
-            byoc_sip_trunk.append(&mut resp.byoc_sip_trunk);
-
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
+    pub async fn account_call_logs_filter<
+        P: FnMut(&crate::types::AccountCallLogsResponse) -> bool,
+    >(
+        &self,
+        from: &str,
+        to: &str,
+        type_: &str,
+        path: &str,
+        time_type: crate::types::TimeType,
+        site_id: &str,
+        mut predicate: P,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::AccountCallLogsResponse>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from.is_empty() {
+            query_args.push(("from", from.to_string()));
+        }
+        if !path.is_empty() {
+            query_args.push(("path", path.to_string()));
+        }
+        let site_id = self.client.site_id_or(site_id);
+        if !site_id.is_empty() {
+            query_args.push(("site_id", site_id.to_string()));
+        }
+        if time_type != crate::types::TimeType::default() {
+            query_args.push(("time_type", time_type.to_string()));
+        }
+        if !to.is_empty() {
+            query_args.push(("to", to.to_string()));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type", type_.to_string()));
         }
+        let url = crate::Client::url_with_query("/phone/call_logs", &query_args);
+
+        let items = self
+            .client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::AccountCallLogsResponseData| {
+                    (resp.call_logs, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/call_logs", &[]))?;
+        Ok(futures::TryStreamExt::try_filter(items, move |item| {
+            futures::future::ready(predicate(item))
+        }))
+    }
 
-        // Return our response data.
-        Ok(byoc_sip_trunk)
+    /**
+     * A request to `account_call_logs`, to set its query parameters by name rather than by position.
+     * The ones left unset are left off the query.
+     */
+    pub fn account_call_logs_request(
+        &self,
+        time_type: crate::types::TimeType,
+    ) -> AccountCallLogsRequest<'_> {
+        AccountCallLogsRequest {
+            api: self,
+            page_size: None,
+            from: None,
+            to: None,
+            type_: None,
+            next_page_token: None,
+            path: None,
+            time_type,
+            site_id: None,
+        }
     }
 
+    /// The OAuth scopes for `assign_number`, any one of them grants access.
+    pub const ASSIGN_NUMBER_SCOPES: &[&str] = &["phone:write", "phone:write:admin"];
+
     /**
-     * Assign SIP trunks.
+     * Assign phone number to user.
      *
-     * This function performs a `POST` to the `/accounts/{accountId}/phone/sip_trunk/trunks` endpoint.
+     * This function performs a `POST` to the `/phone/users/{userId}/phone_numbers` endpoint.
      *
-     * A [Master account](https://marketplace.zoom.us/docs/api-reference/master-account-apis) owner can use this API to assign SIP (Session Initiation Protocol) trunks to a subaccount.
+     * Use this API to assign a [phone number](https://support.zoom.us/hc/en-us/articles/360020808292-Managing-Phone-Numbers) to a user who has already enabled Zoom Phone. For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
      *
-     * **Scopes:** `phone:master`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     * **Scopes:** `phone:write`, `phone:write:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
      *
      * **Prerequisites:**
      * * A Business or Enterprise account
-     *
-     * **Parameters:**
-     *
-     * * `account_id: &str` -- Unique identifier of the account.
+     * * A Zoom Phone license
      */
-    pub async fn post_sip_trunk(
+    pub async fn assign_number(
         &self,
-        account_id: &str,
-        body: &crate::types::PostPhoneSipTrunkRequest,
-    ) -> Result<crate::types::PostPhoneSipTrunkRequest> {
+        user_id: &str,
+        body: &crate::types::AddByocNumberResponse,
+    ) -> Result<crate::types::AddByocNumberResponse> {
         let url = format!(
-            "/accounts/{}/phone/sip_trunk/trunks",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            "/phone/users/{}/phone_numbers",
+            self.client.encode_path(user_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/phone/users/{userId}/phone_numbers",
+                    &[("userId", user_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `unassign_number`, any one of them grants access.
+    pub const UNASSIGN_NUMBER_SCOPES: &[&str] = &["phone:write", "phone:write:admin"];
+
     /**
-     * Update SIP trunk details.
+     * Unassign phone number.
+     *
+     * This function performs a `DELETE` to the `/phone/users/{userId}/phone_numbers/{phoneNumberId}` endpoint.
      *
-     * This function performs a `PATCH` to the `/accounts/{accountId}/phone/sip_trunk/trunks/{sipTrunkId}` endpoint.
+     * Use this API to unassign Zoom Phone user's [phone number](https://support.zoom.us/hc/en-us/articles/360020808292-Managing-Phone-Numbers#h_38ba8b01-26e3-4b1b-a9b5-0717c00a7ca6). For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
      *
-     * Use this API to update a subaccount's assigned SIP (Session Initiation Protocol) trunk information.
+     * After assigning a phone number, you can remove it if you do not want it to be assigned to anyone.
      *
-     * **Scopes:** `phone:master` <br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     * **Scopes:** `phone:write`, `phone:write:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
      *
      * **Prerequisites:**
      * * A Business or Enterprise account
+     * * A Zoom Phone license
+     * * The user must have been previously assigned a Zoom Phone number
      *
      * **Parameters:**
      *
-     * * `sip_trunk_id: &str` -- Unique identifier of the SIP trunk.
-     * * `account_id: &str` -- Unique identifier of the sub account.
+     * * `user_id: &str` -- Provide either userId or email address of the user.
+     * * `phone_number_id: &str` -- Provide either phone number or phoneNumberId of the user. .
      */
-    pub async fn update_sip_trunk(
-        &self,
-        sip_trunk_id: &str,
-        account_id: &str,
-        body: &crate::types::UpdatePhoneSipTrunkRequest,
-    ) -> Result<()> {
+    pub async fn unassign_number(&self, user_id: &str, phone_number_id: &str) -> Result<()> {
         let url = format!(
-            "/accounts/{}/phone/sip_trunk/trunks/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&sip_trunk_id.to_string()),
+            "/phone/users/{}/phone_numbers/{}",
+            self.client.encode_path(user_id),
+            self.client.encode_path(phone_number_id),
         );
 
-        self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/phone/users/{userId}/phone_numbers/{phoneNumberId}",
+                &[
+                    ("userId", user_id.to_string()),
+                    ("phoneNumberId", phone_number_id.to_string()),
+                ],
+            )
+        })
     }
 
     /**
-     * List external contacts.
-     *
-     * This function performs a `GET` to the `/phone/external_contacts` endpoint.
-     *
-     * Use this API to list external contacts.
-     *
-     * **Scopes:** `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
-     *
-     * **Prerequisites:**
-     * * Pro or a higher account with Zoom Phone license
-     * * Account owner or admin permissions
+     * Unassign phone number.
      *
-     * **Parameters:**
+     * This function performs a `DELETE` to the `/phone/users/{userId}/phone_numbers/{phoneNumberId}` endpoint.
      *
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `page_size: i64` -- The number of records returned within a single API call.
+     * As opposed to `unassign_number`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
      */
-    pub async fn list_external_contacts(
+    pub async fn unassign_number_if_exists(
         &self,
-        next_page_token: &str,
-        page_size: i64,
-    ) -> Result<Vec<crate::types::ExternalContacts>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
-        }
-        if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/external_contacts?{}", query_);
-
-        let resp: crate::types::ListExternalContactsResponse = self.client.get(&url, None).await?;
+        user_id: &str,
+        phone_number_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/phone/users/{}/phone_numbers/{}",
+            self.client.encode_path(user_id),
+            self.client.encode_path(phone_number_id),
+        );
 
-        // Return our response data.
-        Ok(resp.external_contacts)
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/phone/users/{userId}/phone_numbers/{phoneNumberId}",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("phoneNumberId", phone_number_id.to_string()),
+                    ],
+                )
+            })
     }
 
-    /**
-     * List external contacts.
-     *
-     * This function performs a `GET` to the `/phone/external_contacts` endpoint.
-     *
-     * As opposed to `list_external_contacts`, this function returns all the pages of the request at once.
-     *
-     * Use this API to list external contacts.
-     *
-     * **Scopes:** `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
-     *
-     * **Prerequisites:**
-     * * Pro or a higher account with Zoom Phone license
-     * * Account owner or admin permissions
-     */
-    pub async fn list_all_external_contacts(&self) -> Result<Vec<crate::types::ExternalContacts>> {
-        let url = "/phone/external_contacts".to_string();
-        let mut resp: crate::types::ListExternalContactsResponse =
-            self.client.get(&url, None).await?;
-
-        let mut external_contacts = resp.external_contacts;
-        let mut page = resp.next_page_token;
-
-        // Paginate if we should.
-        while !page.is_empty() {
-            // Check if we already have URL params and need to concat the token.
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?next_page_token={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&next_page_token={}", url, page), None)
-                    .await?;
-            }
-
-            external_contacts.append(&mut resp.external_contacts);
-
-            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
-                page = resp.next_page_token.to_string();
-            } else {
-                page = "".to_string();
-            }
-        }
-
-        // Return our response data.
-        Ok(external_contacts)
-    }
+    /// The OAuth scopes for `assign_calling_plan`, any one of them grants access.
+    pub const ASSIGN_CALLING_PLAN_SCOPES: &[&str] = &["phone:write", "phone:write:admin"];
 
     /**
-     * Add an external contact.
+     * Assign calling plan to a user.
      *
-     * This function performs a `POST` to the `/phone/external_contacts` endpoint.
+     * This function performs a `POST` to the `/phone/users/{userId}/calling_plans` endpoint.
      *
-     * Use this API to add an external contact.
+     * Use this API to assign a [calling plan](https://marketplace.zoom.us/docs/api-reference/other-references/plans#zoom-phone-calling-plans) to a [Zoom Phone](https://support.zoom.us/hc/en-us/categories/360001370051-Zoom-Phone) user. For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
      *
-     * **Scopes:** `phone:write:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     * **Scopes:** `phone:write`, `phone:write:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
      *
      * **Prerequisites:**
-     * * Pro or a higher account with Zoom Phone license
-     * * Account owner or admin permissions
+     * * A Business or Enterprise account
+     * * A Zoom Phone license
      */
-    pub async fn add_external_contact(
+    pub async fn assign_calling_plan(
         &self,
-        body: &crate::types::AddExternalContactRequest,
+        user_id: &str,
+        body: &crate::types::AssignCallingPlanRequest,
     ) -> Result<()> {
-        let url = "/phone/external_contacts".to_string();
+        let url = format!(
+            "/phone/users/{}/calling_plans",
+            self.client.encode_path(user_id),
+        );
+
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/phone/users/{userId}/calling_plans",
+                    &[("userId", user_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `unassign_calling_plan`, any one of them grants access.
+    pub const UNASSIGN_CALLING_PLAN_SCOPES: &[&str] = &["phone:write", "phone:write:admin"];
+
     /**
-     * Get external contact details.
+     * Unassign user's calling plan.
      *
-     * This function performs a `GET` to the `/phone/external_contacts/{externalContactId}` endpoint.
+     * This function performs a `DELETE` to the `/phone/users/{userId}/calling_plans/{type}` endpoint.
      *
-     * Use this API to get an external contact's information.
+     * Use this API to unassign a a [Zoom Phone](https://support.zoom.us/hc/en-us/categories/360001370051) user's [calling plan](https://marketplace.zoom.us/docs/api-reference/other-references/plans#zoom-phone-calling-plans). For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
      *
-     * **Scopes:** `phone:write:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     * **Scopes:** `phone:write`, `phone:write:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
      *
      * **Prerequisites:**
-     * * Pro or a higher account with Zoom Phone license
-     * * Account owner or admin permissions<br>
+     * * A Business or Enterprise account
+     * * A Zoom Phone license
      *
      * **Parameters:**
      *
-     * * `external_contact_id: &str` -- The external contact's ID.
+     * * `type_: &crate::calling_plan::CallingPlanType` -- The [type](https://marketplace.zoom.us/docs/api-reference/other-references/plans#zoom-phone-calling-plans) of the calling plan that was assigned to user. (e.g: The value of type would be "200" for Unlimited US/Canada calling plan.)
+     *   .
      */
-    pub async fn get_external_contact(
+    pub async fn unassign_calling_plan(
         &self,
-        external_contact_id: &str,
-    ) -> Result<crate::types::ExternalContacts> {
+        user_id: &str,
+        type_: &crate::calling_plan::CallingPlanType,
+    ) -> Result<()> {
         let url = format!(
-            "/phone/external_contacts/{}",
-            crate::progenitor_support::encode_path(&external_contact_id.to_string()),
+            "/phone/users/{}/calling_plans/{}",
+            self.client.encode_path(user_id),
+            self.client.encode_path(&type_.to_string()),
         );
-
-        self.client.get(&url, None).await
+        self.client.check_calling_plan(user_id, type_).await?;
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/phone/users/{userId}/calling_plans/{type}",
+                &[("userId", user_id.to_string()), ("type", type_.to_string())],
+            )
+        })
     }
 
     /**
-     * Delete an external contact.
-     *
-     * This function performs a `DELETE` to the `/phone/external_contacts/{externalContactId}` endpoint.
-     *
-     * Use this API to remove an external contact.
-     *
-     * **Scopes:** `phone:write:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
-     *
-     * **Prerequisites:**
-     * * Pro or a higher account with Zoom Phone license
-     * * Account owner or admin permissions
+     * Unassign user's calling plan.
      *
-     * **Parameters:**
+     * This function performs a `DELETE` to the `/phone/users/{userId}/calling_plans/{type}` endpoint.
      *
-     * * `external_contact_id: &str` -- The external contact's ID.
+     * As opposed to `unassign_calling_plan`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
      */
-    pub async fn delete_external_contact(&self, external_contact_id: &str) -> Result<()> {
+    pub async fn unassign_calling_plan_if_exists(
+        &self,
+        user_id: &str,
+        type_: &crate::calling_plan::CallingPlanType,
+    ) -> Result<bool> {
         let url = format!(
-            "/phone/external_contacts/{}",
-            crate::progenitor_support::encode_path(&external_contact_id.to_string()),
+            "/phone/users/{}/calling_plans/{}",
+            self.client.encode_path(user_id),
+            self.client.encode_path(&type_.to_string()),
         );
+        self.client.check_calling_plan(user_id, type_).await?;
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/phone/users/{userId}/calling_plans/{type}",
+                    &[("userId", user_id.to_string()), ("type", type_.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `get_recordings`, any one of them grants access.
+    pub const GET_RECORDINGS_SCOPES: &[&str] = &[
+        "phone:read:admin",
+        "phone:write:admin",
+        "phone_recording:read:admin",
+    ];
+
     /**
-     * Update external contact.
+     * Get call recordings.
      *
-     * This function performs a `PATCH` to the `/phone/external_contacts/{externalContactId}` endpoint.
+     * This function performs a `GET` to the `/phone/recordings` endpoint.
      *
-     * Use this API to update an external contact's information.
+     * Use this API to list an account's [call recordings](https://support.zoom.us/hc/en-us/articles/360038521091-Accessing-and-sharing-call-recordings)
      *
-     * **Scopes:** `phone:write:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     * **Scopes:** `phone:read:admin`, `phone:write:admin`,`phone_recording:read:admin`
      *
-     * **Prerequisites:**
-     * * Pro or a higher account with Zoom Phone license
-     * * Account owner or admin permissions
+     * **Prerequisties:**
+     * * A Pro or higher account plan
+     * * A Zoom Phone license
+     * * Account owner or admin privileges
      *
      * **Parameters:**
      *
-     * * `external_contact_id: &str` -- User's first name.
+     * * `page_size: i64` -- The number of records returned within a single API call. The default is **30**, and the maximum is **100**.
+     * * `next_page_token: &str` -- The current page number of returned records.
+     * * `from: &str` -- Start date and time in **yyyy-mm-dd** format or **yyyy-MM-dd’T’HH:mm:ss’Z’** format. The date range defined by the from and to parameters should only be one month as the report includes only one month worth of data at once.
+     *   .
+     * * `to: &str` -- End date and time in **yyyy-mm-dd** format or **yyyy-MM-dd’T’HH:mm:ss’Z’** format, the same formats supported by the `from` parameter.
+     *   
+     *   .
+     * * `owner_type: Option<crate::types::OwnerType>` -- The owner type. The allowed values are null, `user`, or `callQueue`. The default is null. If null, returns all owner types.
+     *.
+     * * `recording_type: Option<crate::types::GetPhoneRecordingsRecordingType>` -- The recording type. The allowed values are null, `OnDemand`, or `Automatic`. The default is null. If null, returns all recording types.
+     *.
+     * * `site_id: &str` -- The site ID. The default is `All sites`.
+     * * `query_date_type: crate::types::QueryDateType` -- Date types:<br>`start_time` - Query by call start time.<br>`end_time` - Query by call end time.
      */
-    pub async fn update_external_contact(
+    pub async fn get_recordings(
         &self,
-        external_contact_id: &str,
-        body: &crate::types::UpdateExternalContactRequest,
-    ) -> Result<()> {
-        let url = format!(
-            "/phone/external_contacts/{}",
-            crate::progenitor_support::encode_path(&external_contact_id.to_string()),
-        );
-
-        self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
-    }
+        page_size: i64,
+        next_page_token: &str,
+        from: &str,
+        to: &str,
+        owner_type: Option<crate::types::OwnerType>,
+        recording_type: Option<crate::types::GetPhoneRecordingsRecordingType>,
+        site_id: &str,
+        query_date_type: crate::types::QueryDateType,
+    ) -> Result<Vec<crate::types::GetPhoneRecordingsResponse>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from.is_empty() {
+            query_args.push(("from", from.to_string()));
+        }
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        if let Some(v) = owner_type {
+            query_args.push(("owner_type", v.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        if query_date_type != crate::types::QueryDateType::default() {
+            query_args.push(("query_date_type", query_date_type.to_string()));
+        }
+        if let Some(v) = recording_type {
+            query_args.push(("recording_type", v.to_string()));
+        }
+        let site_id = self.client.site_id_or(site_id);
+        if !site_id.is_empty() {
+            query_args.push(("site_id", site_id.to_string()));
+        }
+        if !to.is_empty() {
+            query_args.push(("to", to.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/recordings", &query_args);
 
-    /**
-     * Get phone number details.
-     *
-     * This function performs a `GET` to the `/phone/numbers/{numberId}` endpoint.
-     *
-     * Use this API to get information about an account's Zoom Phone number.
-     *
-     * **Scopes:** `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
-     *
-     * **Prerequisites:**
-     * * A Pro or higher account plan
-     * * A Zoom phone license
-     *
-     * **Parameters:**
-     *
-     * * `number_id: &str` -- Unique Identifier of the Phone Number. This can be retrieved from the List Phone Numbers API.
-     */
-    pub async fn get_number_details(
-        &self,
-        number_id: &str,
-    ) -> Result<crate::types::GetPhoneNumberDetailsResponse> {
-        let url = format!(
-            "/phone/numbers/{}",
-            crate::progenitor_support::encode_path(&number_id.to_string()),
-        );
+        let resp: crate::types::GetPhoneRecordingsResponseData = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/recordings", &[]))?;
 
-        self.client.get(&url, None).await
+        // Return our response data.
+        Ok(resp.recordings)
     }
 
     /**
-     * Update phone number details.
-     *
-     * This function performs a `PATCH` to the `/phone/numbers/{numberId}` endpoint.
-     *
-     * Use this API to update a Zoom Phone number's information.
-     *
-     * **Scopes:** `phone:write`, `phone:write:admin`, `phone:master`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
-     *
-     * **Prerequisites:**
-     * * A Paid account
+     * Get call recordings.
      *
-     * **Parameters:**
+     * This function performs a `GET` to the `/phone/recordings` endpoint.
      *
-     * * `number_id: &str` -- User's first name.
+     * As opposed to `get_recordings`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
      */
-    pub async fn update_number_details(
+    pub async fn get_recordings_page(
         &self,
-        number_id: &str,
-        body: &crate::types::UpdatePhoneNumberDetailsRequest,
-    ) -> Result<()> {
-        let url = format!(
-            "/phone/numbers/{}",
-            crate::progenitor_support::encode_path(&number_id.to_string()),
-        );
+        page_size: i64,
+        next_page_token: &str,
+        from: &str,
+        to: &str,
+        owner_type: Option<crate::types::OwnerType>,
+        recording_type: Option<crate::types::GetPhoneRecordingsRecordingType>,
+        site_id: &str,
+        query_date_type: crate::types::QueryDateType,
+    ) -> Result<(Vec<crate::types::GetPhoneRecordingsResponse>, String)> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from.is_empty() {
+            query_args.push(("from", from.to_string()));
+        }
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        if let Some(v) = owner_type {
+            query_args.push(("owner_type", v.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        if query_date_type != crate::types::QueryDateType::default() {
+            query_args.push(("query_date_type", query_date_type.to_string()));
+        }
+        if let Some(v) = recording_type {
+            query_args.push(("recording_type", v.to_string()));
+        }
+        let site_id = self.client.site_id_or(site_id);
+        if !site_id.is_empty() {
+            query_args.push(("site_id", site_id.to_string()));
+        }
+        if !to.is_empty() {
+            query_args.push(("to", to.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/recordings", &query_args);
 
-        self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+        let resp: crate::types::GetPhoneRecordingsResponseData = self
+            .client
+            .get(&url, None)
             .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/recordings", &[]))?;
+
+        // Return our response data, and where the next page starts.
+        Ok((resp.recordings, resp.next_page_token))
     }
 
     /**
-     * Change main company number.
-     *
-     * This function performs a `PUT` to the `/phone/company_number` endpoint.
-     *
-     * Use this API to [change an account's main company number](https://support.zoom.us/hc/en-us/articles/360028553691#h_82414c34-9df2-428a-85a4-efcf7f9e0d72).
-     *
-     * External users can use the [main company number](https://support.zoom.us/hc/en-us/articles/360028553691) to reach your Zoom Phone users by dialing the main company number and the user's extension. It can also be used by your account's Zoom Phone users as their caller ID when making calls.
+     * Get call recordings.
      *
-     * **Scopes:** `phone:write:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     * This function performs a `GET` to the `/phone/recordings` endpoint.
      *
-     * **Prerequisites:**
-     * * A Pro or higher account plan
-     * * Account owner or admin permissions
+     * As opposed to `get_recordings`, this function takes `from` and `to` as times, and sends them in UTC like `2021-08-15T01:52:41Z`.
      */
-    pub async fn change_main_company_number(
+    pub async fn get_recordings_between(
         &self,
-        body: &crate::types::ChangeMainCompanyNumberRequest,
-    ) -> Result<()> {
-        let url = "/phone/company_number".to_string();
-        self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+        page_size: i64,
+        next_page_token: &str,
+        from: chrono::DateTime<chrono::Utc>,
+        to: chrono::DateTime<chrono::Utc>,
+        owner_type: Option<crate::types::OwnerType>,
+        recording_type: Option<crate::types::GetPhoneRecordingsRecordingType>,
+        site_id: &str,
+        query_date_type: crate::types::QueryDateType,
+    ) -> Result<Vec<crate::types::GetPhoneRecordingsResponse>> {
+        let from = crate::utils::date_time_param(&from);
+        let to = crate::utils::date_time_param(&to);
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from.is_empty() {
+            query_args.push(("from", from.to_string()));
+        }
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        if let Some(v) = owner_type {
+            query_args.push(("owner_type", v.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        if query_date_type != crate::types::QueryDateType::default() {
+            query_args.push(("query_date_type", query_date_type.to_string()));
+        }
+        if let Some(v) = recording_type {
+            query_args.push(("recording_type", v.to_string()));
+        }
+        let site_id = self.client.site_id_or(site_id);
+        if !site_id.is_empty() {
+            query_args.push(("site_id", site_id.to_string()));
+        }
+        if !to.is_empty() {
+            query_args.push(("to", to.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/recordings", &query_args);
+
+        let resp: crate::types::GetPhoneRecordingsResponseData = self
+            .client
+            .get(&url, None)
             .await
-    }
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/recordings", &[]))?;
 
-    /**
-     * List calling plans.
-     *
-     * This function performs a `GET` to the `/phone/calling_plans` endpoint.
-     *
-     * Use this API to return all of an account's Zoom Phone [calling plans](https://marketplace.zoom.us/docs/api-reference/other-references/plans#zoom-phone-calling-plans).
-     *
-     * **Scopes:** `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
-     *
-     * **Prerequisites:**
-     * * A Pro or a higher account
-     * * A Zoom Phone license
-     */
-    pub async fn list_calling_plan(&self) -> Result<crate::types::ListCallingPlansResponseData> {
-        let url = "/phone/calling_plans".to_string();
-        self.client.get(&url, None).await
+        // Return our response data.
+        Ok(resp.recordings)
     }
 
     /**
-     * List phone users.
-     *
-     * This function performs a `GET` to the `/phone/users` endpoint.
-     *
-     * Use this API to return a list of all of an account's users who are assigned a Zoom Phone license.
-     *
-     * **Scopes:** `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
-     *
-     * **Prerequisites:**
-     * * A Pro or higher account plan
-     * * A Zoom Phone license
+     * Get call recordings.
      *
-     * **Parameters:**
+     * This function performs a `GET` to the `/phone/recordings` endpoint.
      *
-     * * `page_size: i64` -- The number of records returned from a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `site_id: &str` -- Unique Identifier of the site. This can be retrieved from the [List Phone Sites](https://marketplace.zoom.us/docs/api-reference/zoom-api/phone-site/listphonesites) API.
+     * As opposed to `get_recordings_between`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
      */
-    pub async fn list_users(
+    pub async fn get_recordings_between_page(
         &self,
         page_size: i64,
         next_page_token: &str,
+        from: chrono::DateTime<chrono::Utc>,
+        to: chrono::DateTime<chrono::Utc>,
+        owner_type: Option<crate::types::OwnerType>,
+        recording_type: Option<crate::types::GetPhoneRecordingsRecordingType>,
         site_id: &str,
-    ) -> Result<Vec<crate::types::ListPhoneUsersResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        query_date_type: crate::types::QueryDateType,
+    ) -> Result<(Vec<crate::types::GetPhoneRecordingsResponse>, String)> {
+        let from = crate::utils::date_time_param(&from);
+        let to = crate::utils::date_time_param(&to);
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from.is_empty() {
+            query_args.push(("from", from.to_string()));
+        }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        if let Some(v) = owner_type {
+            query_args.push(("owner_type", v.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
+        if query_date_type != crate::types::QueryDateType::default() {
+            query_args.push(("query_date_type", query_date_type.to_string()));
+        }
+        if let Some(v) = recording_type {
+            query_args.push(("recording_type", v.to_string()));
+        }
+        let site_id = self.client.site_id_or(site_id);
         if !site_id.is_empty() {
-            query_args.push(("site_id".to_string(), site_id.to_string()));
+            query_args.push(("site_id", site_id.to_string()));
+        }
+        if !to.is_empty() {
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/users?{}", query_);
+        let url = crate::Client::url_with_query("/phone/recordings", &query_args);
 
-        let resp: crate::types::ListPhoneUsersResponseData = self.client.get(&url, None).await?;
+        let resp: crate::types::GetPhoneRecordingsResponseData = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/recordings", &[]))?;
 
-        // Return our response data.
-        Ok(resp.users)
+        // Return our response data, and where the next page starts.
+        Ok((resp.recordings, resp.next_page_token))
     }
 
     /**
-     * List phone users.
+     * Get call recordings.
      *
-     * This function performs a `GET` to the `/phone/users` endpoint.
+     * This function performs a `GET` to the `/phone/recordings` endpoint.
      *
-     * As opposed to `list_users`, this function returns all the pages of the request at once.
+     * As opposed to `get_recordings`, this function returns all the pages of the request at once.
      *
-     * Use this API to return a list of all of an account's users who are assigned a Zoom Phone license.
+     * Use this API to list an account's [call recordings](https://support.zoom.us/hc/en-us/articles/360038521091-Accessing-and-sharing-call-recordings)
      *
-     * **Scopes:** `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     * **Scopes:** `phone:read:admin`, `phone:write:admin`,`phone_recording:read:admin`
      *
-     * **Prerequisites:**
+     * **Prerequisties:**
      * * A Pro or higher account plan
      * * A Zoom Phone license
+     * * Account owner or admin privileges
      */
-    pub async fn list_all_users(
+    pub async fn get_all_recordings(
         &self,
+        from: &str,
+        to: &str,
+        owner_type: Option<crate::types::OwnerType>,
+        recording_type: Option<crate::types::GetPhoneRecordingsRecordingType>,
         site_id: &str,
-    ) -> Result<Vec<crate::types::ListPhoneUsersResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        query_date_type: crate::types::QueryDateType,
+    ) -> Result<Vec<crate::types::GetPhoneRecordingsResponse>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from.is_empty() {
+            query_args.push(("from", from.to_string()));
+        }
+        if let Some(v) = owner_type {
+            query_args.push(("owner_type", v.to_string()));
+        }
+        if query_date_type != crate::types::QueryDateType::default() {
+            query_args.push(("query_date_type", query_date_type.to_string()));
+        }
+        if let Some(v) = recording_type {
+            query_args.push(("recording_type", v.to_string()));
+        }
+        let site_id = self.client.site_id_or(site_id);
         if !site_id.is_empty() {
-            query_args.push(("site_id".to_string(), site_id.to_string()));
+            query_args.push(("site_id", site_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/users?{}", query_);
+        if !to.is_empty() {
+            query_args.push(("to", to.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/recordings", &query_args);
 
-        let mut resp: crate::types::ListPhoneUsersResponseData =
-            self.client.get(&url, None).await?;
+        let mut resp: crate::types::GetPhoneRecordingsResponseData = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/recordings", &[]))?;
 
-        let mut users = resp.users;
+        let mut recordings = resp.recordings;
         let mut page = resp.next_page_token;
 
         // Paginate if we should.
@@ -2317,7 +3846,7 @@ impl Phone {
                     .await?;
             }
 
-            users.append(&mut resp.users);
+            recordings.append(&mut resp.recordings);
 
             if !resp.next_page_token.is_empty() && resp.next_page_token != page {
                 page = resp.next_page_token.to_string();
@@ -2327,111 +3856,2734 @@ impl Phone {
         }
 
         // Return our response data.
-        Ok(users)
+        Ok(recordings)
     }
 
     /**
-     * Get call log details.
-     *
-     * This function performs a `GET` to the `/phone/call_logs/{callLogId}` endpoint.
-     *
-     * Use this API to return information about a [call log](https://support.zoom.us/hc/en-us/articles/360021114452-Viewing-and-identifying-logs).
-     *
-     * **Scopes:** `phone:read`, `phone:read:admin`, `phone_call_log:read`, `phone_call_log:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`
-     *
-     * **Prerequisites:**
-     * * A Business or Enterprise account
-     * * A Zoom Phone license
+     * Get call recordings.
      *
-     * **Parameters:**
+     * This function performs a `GET` to the `/phone/recordings` endpoint.
      *
-     * * `call_log_id: &str` -- Unique identifier of the call log. Both `callLogId` and `callId` can be used as path parameters. The value for this field can be retrieved from [account's call logs](https://marketplace.zoom.us/docs/api-reference/zoom-api/phone/accountcalllogs) or the [user's call logs](https://marketplace.zoom.us/docs/api-reference/zoom-api/phone/phoneusercalllogs).
+     * As opposed to `get_recordings`, this function streams the items of all the pages, getting the next page only once the items of the last one are used up. The stream ends after the last page.
      */
-    pub async fn get_call_log_details(
+    pub async fn get_recordings_stream(
         &self,
-        call_log_id: &str,
-    ) -> Result<crate::types::GetCallLogDetailsResponse> {
-        let url = format!(
-            "/phone/call_logs/{}",
-            crate::progenitor_support::encode_path(&call_log_id.to_string()),
-        );
+        from: &str,
+        to: &str,
+        owner_type: Option<crate::types::OwnerType>,
+        recording_type: Option<crate::types::GetPhoneRecordingsRecordingType>,
+        site_id: &str,
+        query_date_type: crate::types::QueryDateType,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::GetPhoneRecordingsResponse>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from.is_empty() {
+            query_args.push(("from", from.to_string()));
+        }
+        if let Some(v) = owner_type {
+            query_args.push(("owner_type", v.to_string()));
+        }
+        if query_date_type != crate::types::QueryDateType::default() {
+            query_args.push(("query_date_type", query_date_type.to_string()));
+        }
+        if let Some(v) = recording_type {
+            query_args.push(("recording_type", v.to_string()));
+        }
+        let site_id = self.client.site_id_or(site_id);
+        if !site_id.is_empty() {
+            query_args.push(("site_id", site_id.to_string()));
+        }
+        if !to.is_empty() {
+            query_args.push(("to", to.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/recordings", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::GetPhoneRecordingsResponseData| {
+                    (resp.recordings, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/recordings", &[]))
     }
 
     /**
-     * Delete a user's call log.
-     *
-     * This function performs a `DELETE` to the `/phone/users/{userId}/call_logs/{callLogId}` endpoint.
-     *
-     * Use this API to delete a user's [call log](https://support.zoom.us/hc/en-us/articles/360021114452-Viewing-and-identifying-logs). For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
-     *
-     * **Scopes:** `phone:write`, `phone:write:admin`, `phone_call_log:write`, `phone_call_log:write:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
-     *
-     * **Prerequisites:**
-     * * User must belong to a Business or Enterprise account
-     * * User must have a Zoom Phone license
+     * Get call recordings.
      *
-     * **Parameters:**
+     * This function performs a `GET` to the `/phone/recordings` endpoint.
      *
-     * * `user_id: &str` -- The user ID or email address of the user.
-     * * `call_log_id: &str` -- Unique identifier of the call log. The value for this field can be retrieved from [account's call logs](https://marketplace.zoom.us/docs/api-reference/zoom-api/phone/accountcalllogs) or [user's call logs](https://marketplace.zoom.us/docs/api-reference/zoom-api/phone/phoneusercalllogs).
+     * As opposed to `get_recordings_stream`, this function streams only the items `predicate` keeps. It too gets the next page only once the items of the last one are used up, so a stream that is cut short, for example with `take`, gets no more pages than it needs.
      */
-    pub async fn delete_call_log(&self, user_id: &str, call_log_id: &str) -> Result<()> {
-        let url = format!(
-            "/phone/users/{}/call_logs/{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&call_log_id.to_string()),
-        );
+    pub async fn get_recordings_filter<
+        P: FnMut(&crate::types::GetPhoneRecordingsResponse) -> bool,
+    >(
+        &self,
+        from: &str,
+        to: &str,
+        owner_type: Option<crate::types::OwnerType>,
+        recording_type: Option<crate::types::GetPhoneRecordingsRecordingType>,
+        site_id: &str,
+        query_date_type: crate::types::QueryDateType,
+        mut predicate: P,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::GetPhoneRecordingsResponse>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from.is_empty() {
+            query_args.push(("from", from.to_string()));
+        }
+        if let Some(v) = owner_type {
+            query_args.push(("owner_type", v.to_string()));
+        }
+        if query_date_type != crate::types::QueryDateType::default() {
+            query_args.push(("query_date_type", query_date_type.to_string()));
+        }
+        if let Some(v) = recording_type {
+            query_args.push(("recording_type", v.to_string()));
+        }
+        let site_id = self.client.site_id_or(site_id);
+        if !site_id.is_empty() {
+            query_args.push(("site_id", site_id.to_string()));
+        }
+        if !to.is_empty() {
+            query_args.push(("to", to.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/recordings", &query_args);
+
+        let items = self
+            .client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::GetPhoneRecordingsResponseData| {
+                    (resp.recordings, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/recordings", &[]))?;
+        Ok(futures::TryStreamExt::try_filter(items, move |item| {
+            futures::future::ready(predicate(item))
+        }))
+    }
 
-        self.client.delete(&url, None).await
+    /**
+     * A request to `get_recordings`, to set its query parameters by name rather than by position.
+     * The ones left unset are left off the query.
+     */
+    pub fn get_recordings_request(
+        &self,
+        query_date_type: crate::types::QueryDateType,
+    ) -> GetRecordingsRequest<'_> {
+        GetRecordingsRequest {
+            api: self,
+            page_size: None,
+            next_page_token: None,
+            from: None,
+            to: None,
+            owner_type: None,
+            recording_type: None,
+            site_id: None,
+            query_date_type,
+        }
     }
 
+    /// The OAuth scopes for `list_byocsip_trunk`, any one of them grants access.
+    pub const LIST_BYOCSIP_TRUNK_SCOPES: &[&str] = &["phone:write:admin", "phone:master"];
+
     /**
-     * Add BYOC phone numbers.
+     * List BYOC SIP trunks.
      *
-     * This function performs a `POST` to the `/phone/byoc_numbers` endpoint.
+     * This function performs a `GET` to the `/phone/sip_trunk/trunks` endpoint.
      *
-     * Use this API to add BYOC (Bring Your Own Carrier) phone numbers to Zoom Phone.
+     * Use this API to return a list of an account's assigned [BYOC (Bring Your Own Carrier) SIP (Session Initiation Protocol) trunks](https://zoom.us/docs/doc/Zoom-Bring%20Your%20Own%20Carrier.pdf).
      *
-     * **Scopes:** `phone:write:admin`, `phone:write`, or `phone:master`</br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     * **Scopes:** `phone:write:admin` or `phone:master`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
      *
      * **Prerequisites:**
-     * * A Business or Enterprise plan
-     * * A Zoom Phone license
+     * * A Business or Enterprise account
+     *
+     * **Parameters:**
+     *
+     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+     * * `page_size: i64` -- The number of records returned within a single API call.
      */
-    pub async fn add_byoc_number(
+    pub async fn list_byocsip_trunk(
         &self,
-        body: &crate::types::AddByocNumberRequest,
-    ) -> Result<crate::types::AddByocNumberResponse> {
-        let url = "/phone/byoc_numbers".to_string();
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<Vec<crate::types::ByocSipTrunk>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/sip_trunk/trunks", &query_args);
+
+        let resp: crate::types::ListByocsipTrunkResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/sip_trunk/trunks", &[]))?;
+
+        // Return our response data.
+        Ok(resp.byoc_sip_trunk)
+    }
+
+    /**
+     * List BYOC SIP trunks.
+     *
+     * This function performs a `GET` to the `/phone/sip_trunk/trunks` endpoint.
+     *
+     * As opposed to `list_byocsip_trunk`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
+     */
+    pub async fn list_byocsip_trunk_page(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<(Vec<crate::types::ByocSipTrunk>, String)> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/sip_trunk/trunks", &query_args);
+
+        let resp: crate::types::ListByocsipTrunkResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/sip_trunk/trunks", &[]))?;
+
+        // Return our response data, and where the next page starts.
+        Ok((resp.byoc_sip_trunk, resp.next_page_token))
+    }
+
+    /**
+     * List BYOC SIP trunks.
+     *
+     * This function performs a `GET` to the `/phone/sip_trunk/trunks` endpoint.
+     *
+     * As opposed to `list_byocsip_trunk`, this function returns all the pages of the request at once.
+     *
+     * Use this API to return a list of an account's assigned [BYOC (Bring Your Own Carrier) SIP (Session Initiation Protocol) trunks](https://zoom.us/docs/doc/Zoom-Bring%20Your%20Own%20Carrier.pdf).
+     *
+     * **Scopes:** `phone:write:admin` or `phone:master`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * A Business or Enterprise account
+     */
+    pub async fn list_all_byocsip_trunk(&self) -> Result<Vec<crate::types::ByocSipTrunk>> {
+        let url = "/phone/sip_trunk/trunks".to_string();
+        let mut resp: crate::types::ListByocsipTrunkResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/sip_trunk/trunks", &[]))?;
+
+        let mut byoc_sip_trunk = resp.byoc_sip_trunk;
+        let mut page = resp.next_page_token;
+
+        // Paginate if we should.
+        while !page.is_empty() {
+            // Check if we already have URL params and need to concat the token.
+            if !url.contains('?') {
+                resp = self
+                    .client
+                    .get(&format!("{}?next_page_token={}", url, page), None)
+                    .await?;
+            } else {
+                resp = self
+                    .client
+                    .get(&format!("{}&next_page_token={}", url, page), None)
+                    .await?;
+            }
+
+            byoc_sip_trunk.append(&mut resp.byoc_sip_trunk);
+
+            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
+                page = resp.next_page_token.to_string();
+            } else {
+                page = "".to_string();
+            }
+        }
+
+        // Return our response data.
+        Ok(byoc_sip_trunk)
+    }
+
+    /**
+     * List BYOC SIP trunks.
+     *
+     * This function performs a `GET` to the `/phone/sip_trunk/trunks` endpoint.
+     *
+     * As opposed to `list_byocsip_trunk`, this function streams the items of all the pages, getting the next page only once the items of the last one are used up. The stream ends after the last page.
+     */
+    pub async fn list_byocsip_trunk_stream(
+        &self,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::ByocSipTrunk>>> {
+        let url = "/phone/sip_trunk/trunks".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListByocsipTrunkResponse| {
+                    (resp.byoc_sip_trunk, resp.next_page_token)
+                },
+            )
             .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/sip_trunk/trunks", &[]))
     }
 
     /**
-     * Delete a voicemail.
+     * List BYOC SIP trunks.
      *
-     * This function performs a `DELETE` to the `/phone/voice_mails/{voicemailId}` endpoint.
+     * This function performs a `GET` to the `/phone/sip_trunk/trunks` endpoint.
      *
-     * Use this API to delete an account's [voicemail message](https://support.zoom.us/hc/en-us/articles/360021400211-Managing-voicemail-messages).
+     * As opposed to `list_byocsip_trunk_stream`, this function streams only the items `predicate` keeps. It too gets the next page only once the items of the last one are used up, so a stream that is cut short, for example with `take`, gets no more pages than it needs.
+     */
+    pub async fn list_byocsip_trunk_filter<P: FnMut(&crate::types::ByocSipTrunk) -> bool>(
+        &self,
+        mut predicate: P,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::ByocSipTrunk>>> {
+        let url = "/phone/sip_trunk/trunks".to_string();
+        let items = self
+            .client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListByocsipTrunkResponse| {
+                    (resp.byoc_sip_trunk, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/sip_trunk/trunks", &[]))?;
+        Ok(futures::TryStreamExt::try_filter(items, move |item| {
+            futures::future::ready(predicate(item))
+        }))
+    }
+
+    /// The OAuth scopes for `list_external_contacts`, any one of them grants access.
+    pub const LIST_EXTERNAL_CONTACTS_SCOPES: &[&str] = &["phone:read:admin"];
+
+    /**
+     * List external contacts.
      *
-     * **Scopes:** `phone:write:admin`, `phone:write`, `phone_voicemail:write`, `phone_voicemail:write:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     * This function performs a `GET` to the `/phone/external_contacts` endpoint.
+     *
+     * Use this API to list external contacts.
+     *
+     * **Scopes:** `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
      *
      * **Prerequisites:**
-     * * A Zoom Phone license
+     * * Pro or a higher account with Zoom Phone license
+     * * Account owner or admin permissions
      *
      * **Parameters:**
      *
-     * * `voicemail_id: &str` -- Unique identifier of the voicemail. Retrieve the value for this field by calling the [Get voicemails](https://marketplace.zoom.us/docs/api-reference/zoom-api/phone/phoneuservoicemails) API.
+     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+     * * `page_size: i64` -- The number of records returned within a single API call.
      */
-    pub async fn delete_voicemail(&self, voicemail_id: &str) -> Result<()> {
-        let url = format!(
-            "/phone/voice_mails/{}",
-            crate::progenitor_support::encode_path(&voicemail_id.to_string()),
-        );
+    pub async fn list_external_contacts(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<Vec<crate::types::ExternalContacts>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/external_contacts", &query_args);
+
+        let resp: crate::types::ListExternalContactsResponse =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url("GET", "/phone/external_contacts", &[])
+            })?;
+
+        // Return our response data.
+        Ok(resp.external_contacts)
+    }
+
+    /**
+     * List external contacts.
+     *
+     * This function performs a `GET` to the `/phone/external_contacts` endpoint.
+     *
+     * As opposed to `list_external_contacts`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
+     */
+    pub async fn list_external_contacts_page(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<(Vec<crate::types::ExternalContacts>, String)> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/external_contacts", &query_args);
+
+        let resp: crate::types::ListExternalContactsResponse =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url("GET", "/phone/external_contacts", &[])
+            })?;
+
+        // Return our response data, and where the next page starts.
+        Ok((resp.external_contacts, resp.next_page_token))
+    }
+
+    /**
+     * List external contacts.
+     *
+     * This function performs a `GET` to the `/phone/external_contacts` endpoint.
+     *
+     * As opposed to `list_external_contacts`, this function returns all the pages of the request at once.
+     *
+     * Use this API to list external contacts.
+     *
+     * **Scopes:** `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * Pro or a higher account with Zoom Phone license
+     * * Account owner or admin permissions
+     */
+    pub async fn list_all_external_contacts(&self) -> Result<Vec<crate::types::ExternalContacts>> {
+        let url = "/phone/external_contacts".to_string();
+        let mut resp: crate::types::ListExternalContactsResponse =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url("GET", "/phone/external_contacts", &[])
+            })?;
+
+        let mut external_contacts = resp.external_contacts;
+        let mut page = resp.next_page_token;
+
+        // Paginate if we should.
+        while !page.is_empty() {
+            // Check if we already have URL params and need to concat the token.
+            if !url.contains('?') {
+                resp = self
+                    .client
+                    .get(&format!("{}?next_page_token={}", url, page), None)
+                    .await?;
+            } else {
+                resp = self
+                    .client
+                    .get(&format!("{}&next_page_token={}", url, page), None)
+                    .await?;
+            }
+
+            external_contacts.append(&mut resp.external_contacts);
+
+            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
+                page = resp.next_page_token.to_string();
+            } else {
+                page = "".to_string();
+            }
+        }
+
+        // Return our response data.
+        Ok(external_contacts)
+    }
 
-        self.client.delete(&url, None).await
+    /**
+     * List external contacts.
+     *
+     * This function performs a `GET` to the `/phone/external_contacts` endpoint.
+     *
+     * As opposed to `list_external_contacts`, this function streams the items of all the pages, getting the next page only once the items of the last one are used up. The stream ends after the last page.
+     */
+    pub async fn list_external_contacts_stream(
+        &self,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::ExternalContacts>>> {
+        let url = "/phone/external_contacts".to_string();
+        self.client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListExternalContactsResponse| {
+                    (resp.external_contacts, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/external_contacts", &[]))
+    }
+
+    /**
+     * List external contacts.
+     *
+     * This function performs a `GET` to the `/phone/external_contacts` endpoint.
+     *
+     * As opposed to `list_external_contacts_stream`, this function streams only the items `predicate` keeps. It too gets the next page only once the items of the last one are used up, so a stream that is cut short, for example with `take`, gets no more pages than it needs.
+     */
+    pub async fn list_external_contacts_filter<
+        P: FnMut(&crate::types::ExternalContacts) -> bool,
+    >(
+        &self,
+        mut predicate: P,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::ExternalContacts>>> {
+        let url = "/phone/external_contacts".to_string();
+        let items = self
+            .client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListExternalContactsResponse| {
+                    (resp.external_contacts, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/external_contacts", &[]))?;
+        Ok(futures::TryStreamExt::try_filter(items, move |item| {
+            futures::future::ready(predicate(item))
+        }))
+    }
+
+    /// The OAuth scopes for `add_external_contact`, any one of them grants access.
+    pub const ADD_EXTERNAL_CONTACT_SCOPES: &[&str] = &["phone:write:admin"];
+
+    /**
+     * Add an external contact.
+     *
+     * This function performs a `POST` to the `/phone/external_contacts` endpoint.
+     *
+     * Use this API to add an external contact.
+     *
+     * **Scopes:** `phone:write:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * Pro or a higher account with Zoom Phone license
+     * * Account owner or admin permissions
+     */
+    pub async fn add_external_contact(
+        &self,
+        body: &crate::types::AddExternalContactRequest,
+    ) -> Result<()> {
+        let url = "/phone/external_contacts".to_string();
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/phone/external_contacts", &[]))
+    }
+
+    /// The OAuth scopes for `get_external_contact`, any one of them grants access.
+    pub const GET_EXTERNAL_CONTACT_SCOPES: &[&str] = &["phone:write:admin"];
+
+    /**
+     * Get external contact details.
+     *
+     * This function performs a `GET` to the `/phone/external_contacts/{externalContactId}` endpoint.
+     *
+     * Use this API to get an external contact's information.
+     *
+     * **Scopes:** `phone:write:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * Pro or a higher account with Zoom Phone license
+     * * Account owner or admin permissions<br>
+     *
+     * **Parameters:**
+     *
+     * * `external_contact_id: &str` -- The external contact's ID.
+     */
+    pub async fn get_external_contact(
+        &self,
+        external_contact_id: &str,
+    ) -> Result<crate::types::ExternalContacts> {
+        let url = format!(
+            "/phone/external_contacts/{}",
+            self.client.encode_path(external_contact_id),
+        );
+
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/phone/external_contacts/{externalContactId}",
+                &[("externalContactId", external_contact_id.to_string())],
+            )
+        })
+    }
+
+    /// The OAuth scopes for `delete_external_contact`, any one of them grants access.
+    pub const DELETE_EXTERNAL_CONTACT_SCOPES: &[&str] = &["phone:write:admin"];
+
+    /**
+     * Delete an external contact.
+     *
+     * This function performs a `DELETE` to the `/phone/external_contacts/{externalContactId}` endpoint.
+     *
+     * Use this API to remove an external contact.
+     *
+     * **Scopes:** `phone:write:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * Pro or a higher account with Zoom Phone license
+     * * Account owner or admin permissions
+     *
+     * **Parameters:**
+     *
+     * * `external_contact_id: &str` -- The external contact's ID.
+     */
+    pub async fn delete_external_contact(&self, external_contact_id: &str) -> Result<()> {
+        let url = format!(
+            "/phone/external_contacts/{}",
+            self.client.encode_path(external_contact_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/phone/external_contacts/{externalContactId}",
+                &[("externalContactId", external_contact_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete an external contact.
+     *
+     * This function performs a `DELETE` to the `/phone/external_contacts/{externalContactId}` endpoint.
+     *
+     * As opposed to `delete_external_contact`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_external_contact_if_exists(
+        &self,
+        external_contact_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/phone/external_contacts/{}",
+            self.client.encode_path(external_contact_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/phone/external_contacts/{externalContactId}",
+                    &[("externalContactId", external_contact_id.to_string())],
+                )
+            })
+    }
+
+    /// The OAuth scopes for `update_external_contact`, any one of them grants access.
+    pub const UPDATE_EXTERNAL_CONTACT_SCOPES: &[&str] = &["phone:write:admin"];
+
+    /**
+     * Update external contact.
+     *
+     * This function performs a `PATCH` to the `/phone/external_contacts/{externalContactId}` endpoint.
+     *
+     * Use this API to update an external contact's information.
+     *
+     * **Scopes:** `phone:write:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * Pro or a higher account with Zoom Phone license
+     * * Account owner or admin permissions
+     *
+     * **Parameters:**
+     *
+     * * `external_contact_id: &str` -- User's first name.
+     */
+    pub async fn update_external_contact(
+        &self,
+        external_contact_id: &str,
+        body: &crate::types::UpdateExternalContactRequest,
+    ) -> Result<()> {
+        let url = format!(
+            "/phone/external_contacts/{}",
+            self.client.encode_path(external_contact_id),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/phone/external_contacts/{externalContactId}",
+                    &[("externalContactId", external_contact_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update external contact.
+     *
+     * This function performs a `PATCH` to the `/phone/external_contacts/{externalContactId}` endpoint.
+     *
+     * As opposed to `update_external_contact`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn update_external_contact_if_changed<C: serde::Serialize>(
+        &self,
+        external_contact_id: &str,
+        current: &C,
+        body: &crate::types::UpdateExternalContactRequest,
+    ) -> Result<Option<()>> {
+        let url = format!(
+            "/phone/external_contacts/{}",
+            self.client.encode_path(external_contact_id),
+        );
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/phone/external_contacts/{externalContactId}",
+                    &[("externalContactId", external_contact_id.to_string())],
+                )
+            })
+    }
+
+    /// The OAuth scopes for `get_number_details`, any one of them grants access.
+    pub const GET_NUMBER_DETAILS_SCOPES: &[&str] = &["phone:read:admin"];
+
+    /**
+     * Get phone number details.
+     *
+     * This function performs a `GET` to the `/phone/numbers/{numberId}` endpoint.
+     *
+     * Use this API to get information about an account's Zoom Phone number.
+     *
+     * **Scopes:** `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * A Pro or higher account plan
+     * * A Zoom phone license
+     *
+     * **Parameters:**
+     *
+     * * `number_id: &crate::number_id::NumberId` -- Unique Identifier of the Phone Number. This can be retrieved from the List Phone Numbers API.
+     */
+    pub async fn get_number_details(
+        &self,
+        number_id: &crate::number_id::NumberId,
+    ) -> Result<crate::types::GetPhoneNumberDetailsResponse> {
+        let url = format!(
+            "/phone/numbers/{}",
+            self.client.encode_path(&number_id.to_string()),
+        );
+
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/phone/numbers/{numberId}",
+                &[("numberId", number_id.to_string())],
+            )
+        })
+    }
+
+    /// The OAuth scopes for `update_number_details`, any one of them grants access.
+    pub const UPDATE_NUMBER_DETAILS_SCOPES: &[&str] =
+        &["phone:write", "phone:write:admin", "phone:master"];
+
+    /**
+     * Update phone number details.
+     *
+     * This function performs a `PATCH` to the `/phone/numbers/{numberId}` endpoint.
+     *
+     * Use this API to update a Zoom Phone number's information.
+     *
+     * **Scopes:** `phone:write`, `phone:write:admin`, `phone:master`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * A Paid account
+     *
+     * **Parameters:**
+     *
+     * * `number_id: &crate::number_id::NumberId` -- User's first name.
+     */
+    pub async fn update_number_details(
+        &self,
+        number_id: &crate::number_id::NumberId,
+        body: &crate::types::UpdatePhoneNumberDetailsRequest,
+    ) -> Result<()> {
+        let url = format!(
+            "/phone/numbers/{}",
+            self.client.encode_path(&number_id.to_string()),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/phone/numbers/{numberId}",
+                    &[("numberId", number_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update phone number details.
+     *
+     * This function performs a `PATCH` to the `/phone/numbers/{numberId}` endpoint.
+     *
+     * As opposed to `update_number_details`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn update_number_details_if_changed<C: serde::Serialize>(
+        &self,
+        number_id: &crate::number_id::NumberId,
+        current: &C,
+        body: &crate::types::UpdatePhoneNumberDetailsRequest,
+    ) -> Result<Option<()>> {
+        let url = format!(
+            "/phone/numbers/{}",
+            self.client.encode_path(&number_id.to_string()),
+        );
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/phone/numbers/{numberId}",
+                    &[("numberId", number_id.to_string())],
+                )
+            })
+    }
+
+    /// The OAuth scopes for `change_main_company_number`, any one of them grants access.
+    pub const CHANGE_MAIN_COMPANY_NUMBER_SCOPES: &[&str] = &["phone:write:admin"];
+
+    /**
+     * Change main company number.
+     *
+     * This function performs a `PUT` to the `/phone/company_number` endpoint.
+     *
+     * Use this API to [change an account's main company number](https://support.zoom.us/hc/en-us/articles/360028553691#h_82414c34-9df2-428a-85a4-efcf7f9e0d72).
+     *
+     * External users can use the [main company number](https://support.zoom.us/hc/en-us/articles/360028553691) to reach your Zoom Phone users by dialing the main company number and the user's extension. It can also be used by your account's Zoom Phone users as their caller ID when making calls.
+     *
+     * **Scopes:** `phone:write:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * A Pro or higher account plan
+     * * Account owner or admin permissions
+     */
+    pub async fn change_main_company_number(
+        &self,
+        body: &crate::types::ChangeMainCompanyNumberRequest,
+    ) -> Result<()> {
+        let url = "/phone/company_number".to_string();
+        self.client
+            .put(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| crate::utils::pretty_url("PUT", "/phone/company_number", &[]))
+    }
+
+    /// The OAuth scopes for `list_calling_plan`, any one of them grants access.
+    pub const LIST_CALLING_PLAN_SCOPES: &[&str] = &["phone:read:admin"];
+
+    /**
+     * List calling plans.
+     *
+     * This function performs a `GET` to the `/phone/calling_plans` endpoint.
+     *
+     * Use this API to return all of an account's Zoom Phone [calling plans](https://marketplace.zoom.us/docs/api-reference/other-references/plans#zoom-phone-calling-plans).
+     *
+     * **Scopes:** `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     * **Prerequisites:**
+     * * A Pro or a higher account
+     * * A Zoom Phone license
+     */
+    pub async fn list_calling_plan(&self) -> Result<crate::types::ListCallingPlansResponseData> {
+        let url = "/phone/calling_plans".to_string();
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/calling_plans", &[]))
+    }
+
+    /// The OAuth scopes for `list_users`, any one of them grants access.
+    pub const LIST_USERS_SCOPES: &[&str] = &["phone:read:admin"];
+
+    /**
+     * List phone users.
+     *
+     * This function performs a `GET` to the `/phone/users` endpoint.
+     *
+     * Use this API to return a list of all of an account's users who are assigned a Zoom Phone license.
+     *
+     * **Scopes:** `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * A Pro or higher account plan
+     * * A Zoom Phone license
+     *
+     * **Parameters:**
+     *
+     * * `page_size: i64` -- The number of records returned from a single API call.
+     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+     * * `site_id: &str` -- Unique Identifier of the site. This can be retrieved from the [List Phone Sites](https://marketplace.zoom.us/docs/api-reference/zoom-api/phone-site/listphonesites) API.
+     */
+    pub async fn list_users(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        site_id: &str,
+    ) -> Result<Vec<crate::types::ListPhoneUsersResponse>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        let site_id = self.client.site_id_or(site_id);
+        if !site_id.is_empty() {
+            query_args.push(("site_id", site_id.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/users", &query_args);
+
+        let resp: crate::types::ListPhoneUsersResponseData = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/users", &[]))?;
+
+        // Return our response data.
+        Ok(resp.users)
+    }
+
+    /**
+     * List phone users.
+     *
+     * This function performs a `GET` to the `/phone/users` endpoint.
+     *
+     * As opposed to `list_users`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
+     */
+    pub async fn list_users_page(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        site_id: &str,
+    ) -> Result<(Vec<crate::types::ListPhoneUsersResponse>, String)> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        let site_id = self.client.site_id_or(site_id);
+        if !site_id.is_empty() {
+            query_args.push(("site_id", site_id.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/users", &query_args);
+
+        let resp: crate::types::ListPhoneUsersResponseData = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/users", &[]))?;
+
+        // Return our response data, and where the next page starts.
+        Ok((resp.users, resp.next_page_token))
+    }
+
+    /**
+     * List phone users.
+     *
+     * This function performs a `GET` to the `/phone/users` endpoint.
+     *
+     * As opposed to `list_users`, this function returns all the pages of the request at once.
+     *
+     * Use this API to return a list of all of an account's users who are assigned a Zoom Phone license.
+     *
+     * **Scopes:** `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * A Pro or higher account plan
+     * * A Zoom Phone license
+     */
+    pub async fn list_all_users(
+        &self,
+        site_id: &str,
+    ) -> Result<Vec<crate::types::ListPhoneUsersResponse>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        let site_id = self.client.site_id_or(site_id);
+        if !site_id.is_empty() {
+            query_args.push(("site_id", site_id.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/users", &query_args);
+
+        let mut resp: crate::types::ListPhoneUsersResponseData = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/users", &[]))?;
+
+        let mut users = resp.users;
+        let mut page = resp.next_page_token;
+
+        // Paginate if we should.
+        while !page.is_empty() {
+            // Check if we already have URL params and need to concat the token.
+            if !url.contains('?') {
+                resp = self
+                    .client
+                    .get(&format!("{}?next_page_token={}", url, page), None)
+                    .await?;
+            } else {
+                resp = self
+                    .client
+                    .get(&format!("{}&next_page_token={}", url, page), None)
+                    .await?;
+            }
+
+            users.append(&mut resp.users);
+
+            if !resp.next_page_token.is_empty() && resp.next_page_token != page {
+                page = resp.next_page_token.to_string();
+            } else {
+                page = "".to_string();
+            }
+        }
+
+        // Return our response data.
+        Ok(users)
+    }
+
+    /**
+     * List phone users.
+     *
+     * This function performs a `GET` to the `/phone/users` endpoint.
+     *
+     * As opposed to `list_users`, this function streams the items of all the pages, getting the next page only once the items of the last one are used up. The stream ends after the last page.
+     */
+    pub async fn list_users_stream(
+        &self,
+        site_id: &str,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::ListPhoneUsersResponse>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        let site_id = self.client.site_id_or(site_id);
+        if !site_id.is_empty() {
+            query_args.push(("site_id", site_id.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/users", &query_args);
+
+        self.client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListPhoneUsersResponseData| (resp.users, resp.next_page_token),
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/users", &[]))
+    }
+
+    /**
+     * List phone users.
+     *
+     * This function performs a `GET` to the `/phone/users` endpoint.
+     *
+     * As opposed to `list_users_stream`, this function streams only the items `predicate` keeps. It too gets the next page only once the items of the last one are used up, so a stream that is cut short, for example with `take`, gets no more pages than it needs.
+     */
+    pub async fn list_users_filter<P: FnMut(&crate::types::ListPhoneUsersResponse) -> bool>(
+        &self,
+        site_id: &str,
+        mut predicate: P,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::ListPhoneUsersResponse>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        let site_id = self.client.site_id_or(site_id);
+        if !site_id.is_empty() {
+            query_args.push(("site_id", site_id.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/users", &query_args);
+
+        let items = self
+            .client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListPhoneUsersResponseData| (resp.users, resp.next_page_token),
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/users", &[]))?;
+        Ok(futures::TryStreamExt::try_filter(items, move |item| {
+            futures::future::ready(predicate(item))
+        }))
+    }
+
+    /// The OAuth scopes for `get_call_log_details`, any one of them grants access.
+    pub const GET_CALL_LOG_DETAILS_SCOPES: &[&str] = &[
+        "phone:read",
+        "phone:read:admin",
+        "phone_call_log:read",
+        "phone_call_log:read:admin",
+    ];
+
+    /**
+     * Get call log details.
+     *
+     * This function performs a `GET` to the `/phone/call_logs/{callLogId}` endpoint.
+     *
+     * Use this API to return information about a [call log](https://support.zoom.us/hc/en-us/articles/360021114452-Viewing-and-identifying-logs).
+     *
+     * **Scopes:** `phone:read`, `phone:read:admin`, `phone_call_log:read`, `phone_call_log:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`
+     *
+     * **Prerequisites:**
+     * * A Business or Enterprise account
+     * * A Zoom Phone license
+     *
+     * **Parameters:**
+     *
+     * * `call_log_id: &str` -- Unique identifier of the call log. Both `callLogId` and `callId` can be used as path parameters. The value for this field can be retrieved from [account's call logs](https://marketplace.zoom.us/docs/api-reference/zoom-api/phone/accountcalllogs) or the [user's call logs](https://marketplace.zoom.us/docs/api-reference/zoom-api/phone/phoneusercalllogs).
+     */
+    pub async fn get_call_log_details(
+        &self,
+        call_log_id: &str,
+    ) -> Result<crate::types::GetCallLogDetailsResponse> {
+        let url = format!("/phone/call_logs/{}", self.client.encode_path(call_log_id),);
+
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/phone/call_logs/{callLogId}",
+                &[("callLogId", call_log_id.to_string())],
+            )
+        })
+    }
+
+    /// The OAuth scopes for `delete_call_log`, any one of them grants access.
+    pub const DELETE_CALL_LOG_SCOPES: &[&str] = &[
+        "phone:write",
+        "phone:write:admin",
+        "phone_call_log:write",
+        "phone_call_log:write:admin",
+    ];
+
+    /**
+     * Delete a user's call log.
+     *
+     * This function performs a `DELETE` to the `/phone/users/{userId}/call_logs/{callLogId}` endpoint.
+     *
+     * Use this API to delete a user's [call log](https://support.zoom.us/hc/en-us/articles/360021114452-Viewing-and-identifying-logs). For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     *
+     * **Scopes:** `phone:write`, `phone:write:admin`, `phone_call_log:write`, `phone_call_log:write:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * User must belong to a Business or Enterprise account
+     * * User must have a Zoom Phone license
+     *
+     * **Parameters:**
+     *
+     * * `user_id: &str` -- The user ID or email address of the user.
+     * * `call_log_id: &str` -- Unique identifier of the call log. The value for this field can be retrieved from [account's call logs](https://marketplace.zoom.us/docs/api-reference/zoom-api/phone/accountcalllogs) or [user's call logs](https://marketplace.zoom.us/docs/api-reference/zoom-api/phone/phoneusercalllogs).
+     */
+    pub async fn delete_call_log(&self, user_id: &str, call_log_id: &str) -> Result<()> {
+        let url = format!(
+            "/phone/users/{}/call_logs/{}",
+            self.client.encode_path(user_id),
+            self.client.encode_path(call_log_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/phone/users/{userId}/call_logs/{callLogId}",
+                &[
+                    ("userId", user_id.to_string()),
+                    ("callLogId", call_log_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Delete a user's call log.
+     *
+     * This function performs a `DELETE` to the `/phone/users/{userId}/call_logs/{callLogId}` endpoint.
+     *
+     * As opposed to `delete_call_log`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_call_log_if_exists(
+        &self,
+        user_id: &str,
+        call_log_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/phone/users/{}/call_logs/{}",
+            self.client.encode_path(user_id),
+            self.client.encode_path(call_log_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/phone/users/{userId}/call_logs/{callLogId}",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("callLogId", call_log_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The OAuth scopes for `add_byoc_number`, any one of them grants access.
+    pub const ADD_BYOC_NUMBER_SCOPES: &[&str] =
+        &["phone:write:admin", "phone:write", "phone:master"];
+
+    /**
+     * Add BYOC phone numbers.
+     *
+     * This function performs a `POST` to the `/phone/byoc_numbers` endpoint.
+     *
+     * Use this API to add BYOC (Bring Your Own Carrier) phone numbers to Zoom Phone.
+     *
+     * **Scopes:** `phone:write:admin`, `phone:write`, or `phone:master`</br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * A Business or Enterprise plan
+     * * A Zoom Phone license
+     */
+    pub async fn add_byoc_number(
+        &self,
+        body: &crate::types::AddByocNumberRequest,
+    ) -> Result<crate::types::AddByocNumberResponse> {
+        let url = "/phone/byoc_numbers".to_string();
+        body.validate()?;
+
+        self.client
+            .post(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/phone/byoc_numbers", &[]))
+    }
+
+    /// The OAuth scopes for `delete_voicemail`, any one of them grants access.
+    pub const DELETE_VOICEMAIL_SCOPES: &[&str] = &[
+        "phone:write:admin",
+        "phone:write",
+        "phone_voicemail:write",
+        "phone_voicemail:write:admin",
+    ];
+
+    /**
+     * Delete a voicemail.
+     *
+     * This function performs a `DELETE` to the `/phone/voice_mails/{voicemailId}` endpoint.
+     *
+     * Use this API to delete an account's [voicemail message](https://support.zoom.us/hc/en-us/articles/360021400211-Managing-voicemail-messages).
+     *
+     * **Scopes:** `phone:write:admin`, `phone:write`, `phone_voicemail:write`, `phone_voicemail:write:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * A Zoom Phone license
+     *
+     * **Parameters:**
+     *
+     * * `voicemail_id: &str` -- Unique identifier of the voicemail. Retrieve the value for this field by calling the [Get voicemails](https://marketplace.zoom.us/docs/api-reference/zoom-api/phone/phoneuservoicemails) API.
+     */
+    pub async fn delete_voicemail(&self, voicemail_id: &str) -> Result<()> {
+        let url = format!(
+            "/phone/voice_mails/{}",
+            self.client.encode_path(voicemail_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/phone/voice_mails/{voicemailId}",
+                &[("voicemailId", voicemail_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete a voicemail.
+     *
+     * This function performs a `DELETE` to the `/phone/voice_mails/{voicemailId}` endpoint.
+     *
+     * As opposed to `delete_voicemail`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_voicemail_if_exists(&self, voicemail_id: &str) -> Result<bool> {
+        let url = format!(
+            "/phone/voice_mails/{}",
+            self.client.encode_path(voicemail_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/phone/voice_mails/{voicemailId}",
+                    &[("voicemailId", voicemail_id.to_string())],
+                )
+            })
+    }
+}
+
+/// A request to [`Phone::list_account_numbers`], see [`Phone::list_account_numbers_request`].
+pub struct ListAccountNumbersRequest<'a> {
+    api: &'a Phone,
+    next_page_token: Option<String>,
+    type_: Option<crate::types::ListAccountPhoneNumbersType>,
+    extension_type: Option<crate::types::ExtensionType>,
+    page_size: Option<i64>,
+    number_type: Option<crate::types::Type>,
+    pending_numbers: Option<bool>,
+    site_id: Option<String>,
+}
+
+impl<'a> ListAccountNumbersRequest<'a> {
+    /// Set `next_page_token`.
+    pub fn next_page_token(mut self, next_page_token: &str) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    /// Set `type_`.
+    pub fn type_(mut self, type_: crate::types::ListAccountPhoneNumbersType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Set `extension_type`.
+    pub fn extension_type(mut self, extension_type: crate::types::ExtensionType) -> Self {
+        self.extension_type = Some(extension_type);
+        self
+    }
+
+    /// Set `page_size`.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Set `number_type`.
+    pub fn number_type(mut self, number_type: crate::types::Type) -> Self {
+        self.number_type = Some(number_type);
+        self
+    }
+
+    /// Set `pending_numbers`.
+    pub fn pending_numbers(mut self, pending_numbers: bool) -> Self {
+        self.pending_numbers = Some(pending_numbers);
+        self
+    }
+
+    /// Set `site_id`.
+    pub fn site_id(mut self, site_id: &str) -> Self {
+        self.site_id = Some(site_id.to_string());
+        self
+    }
+
+    /// Send the request.
+    pub async fn send(self) -> Result<Vec<crate::types::ListAccountPhoneNumbersResponse>> {
+        self.api
+            .list_account_numbers(
+                self.next_page_token.as_deref().unwrap_or_default(),
+                self.type_,
+                self.extension_type,
+                self.page_size.unwrap_or_default(),
+                self.number_type,
+                self.pending_numbers.unwrap_or_default(),
+                self.site_id.as_deref().unwrap_or_default(),
+            )
+            .await
+    }
+}
+
+/// A request to [`Phone::user_call_logs`], see [`Phone::user_call_logs_request`].
+pub struct UserCallLogsRequest<'a> {
+    api: &'a Phone,
+    user_id: String,
+    page_size: Option<i64>,
+    from: chrono::NaiveDate,
+    to: chrono::NaiveDate,
+    type_: Option<crate::types::PhoneUserCallLogsType>,
+    next_page_token: Option<String>,
+    phone_number: Option<String>,
+    time_type: crate::types::TimeType,
+}
+
+impl<'a> UserCallLogsRequest<'a> {
+    /// Set `page_size`.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Set `type_`.
+    pub fn type_(mut self, type_: crate::types::PhoneUserCallLogsType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Set `next_page_token`.
+    pub fn next_page_token(mut self, next_page_token: &str) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    /// Set `phone_number`.
+    pub fn phone_number(mut self, phone_number: &str) -> Self {
+        self.phone_number = Some(phone_number.to_string());
+        self
+    }
+
+    /// Send the request.
+    pub async fn send(self) -> Result<Vec<crate::types::CallLogs>> {
+        self.api
+            .user_call_logs(
+                &self.user_id,
+                self.page_size.unwrap_or_default(),
+                self.from,
+                self.to,
+                self.type_,
+                self.next_page_token.as_deref().unwrap_or_default(),
+                self.phone_number.as_deref().unwrap_or_default(),
+                self.time_type,
+            )
+            .await
+    }
+}
+
+/// A request to [`Phone::account_call_logs`], see [`Phone::account_call_logs_request`].
+pub struct AccountCallLogsRequest<'a> {
+    api: &'a Phone,
+    page_size: Option<i64>,
+    from: Option<String>,
+    to: Option<String>,
+    type_: Option<String>,
+    next_page_token: Option<String>,
+    path: Option<String>,
+    time_type: crate::types::TimeType,
+    site_id: Option<String>,
+}
+
+impl<'a> AccountCallLogsRequest<'a> {
+    /// Set `page_size`.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Set `from`.
+    pub fn from(mut self, from: &str) -> Self {
+        self.from = Some(from.to_string());
+        self
+    }
+
+    /// Set `to`.
+    pub fn to(mut self, to: &str) -> Self {
+        self.to = Some(to.to_string());
+        self
+    }
+
+    /// Set `type_`.
+    pub fn type_(mut self, type_: &str) -> Self {
+        self.type_ = Some(type_.to_string());
+        self
+    }
+
+    /// Set `next_page_token`.
+    pub fn next_page_token(mut self, next_page_token: &str) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    /// Set `path`.
+    pub fn path(mut self, path: &str) -> Self {
+        self.path = Some(path.to_string());
+        self
+    }
+
+    /// Set `site_id`.
+    pub fn site_id(mut self, site_id: &str) -> Self {
+        self.site_id = Some(site_id.to_string());
+        self
+    }
+
+    /// Send the request.
+    pub async fn send(self) -> Result<Vec<crate::types::AccountCallLogsResponse>> {
+        self.api
+            .account_call_logs(
+                self.page_size.unwrap_or_default(),
+                self.from.as_deref().unwrap_or_default(),
+                self.to.as_deref().unwrap_or_default(),
+                self.type_.as_deref().unwrap_or_default(),
+                self.next_page_token.as_deref().unwrap_or_default(),
+                self.path.as_deref().unwrap_or_default(),
+                self.time_type,
+                self.site_id.as_deref().unwrap_or_default(),
+            )
+            .await
+    }
+}
+
+/// A request to [`Phone::get_recordings`], see [`Phone::get_recordings_request`].
+pub struct GetRecordingsRequest<'a> {
+    api: &'a Phone,
+    page_size: Option<i64>,
+    next_page_token: Option<String>,
+    from: Option<String>,
+    to: Option<String>,
+    owner_type: Option<crate::types::OwnerType>,
+    recording_type: Option<crate::types::GetPhoneRecordingsRecordingType>,
+    site_id: Option<String>,
+    query_date_type: crate::types::QueryDateType,
+}
+
+impl<'a> GetRecordingsRequest<'a> {
+    /// Set `page_size`.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Set `next_page_token`.
+    pub fn next_page_token(mut self, next_page_token: &str) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    /// Set `from`.
+    pub fn from(mut self, from: &str) -> Self {
+        self.from = Some(from.to_string());
+        self
+    }
+
+    /// Set `to`.
+    pub fn to(mut self, to: &str) -> Self {
+        self.to = Some(to.to_string());
+        self
+    }
+
+    /// Set `owner_type`.
+    pub fn owner_type(mut self, owner_type: crate::types::OwnerType) -> Self {
+        self.owner_type = Some(owner_type);
+        self
+    }
+
+    /// Set `recording_type`.
+    pub fn recording_type(
+        mut self,
+        recording_type: crate::types::GetPhoneRecordingsRecordingType,
+    ) -> Self {
+        self.recording_type = Some(recording_type);
+        self
+    }
+
+    /// Set `site_id`.
+    pub fn site_id(mut self, site_id: &str) -> Self {
+        self.site_id = Some(site_id.to_string());
+        self
+    }
+
+    /// Send the request.
+    pub async fn send(self) -> Result<Vec<crate::types::GetPhoneRecordingsResponse>> {
+        self.api
+            .get_recordings(
+                self.page_size.unwrap_or_default(),
+                self.next_page_token.as_deref().unwrap_or_default(),
+                self.from.as_deref().unwrap_or_default(),
+                self.to.as_deref().unwrap_or_default(),
+                self.owner_type,
+                self.recording_type,
+                self.site_id.as_deref().unwrap_or_default(),
+                self.query_date_type,
+            )
+            .await
+    }
+}
+
+/// The functions of [`Phone`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn PhoneApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait PhoneApi: Send + Sync {
+    async fn set_up_account(
+        &self,
+        account_id: &str,
+        body: &crate::types::SetUpAccountRequest,
+    ) -> Result<()>;
+    async fn list_account_numbers(
+        &self,
+        next_page_token: &str,
+        type_: Option<crate::types::ListAccountPhoneNumbersType>,
+        extension_type: Option<crate::types::ExtensionType>,
+        page_size: i64,
+        number_type: Option<crate::types::Type>,
+        pending_numbers: bool,
+        site_id: &str,
+    ) -> Result<Vec<crate::types::ListAccountPhoneNumbersResponse>>;
+    async fn list_account_numbers_page(
+        &self,
+        next_page_token: &str,
+        type_: Option<crate::types::ListAccountPhoneNumbersType>,
+        extension_type: Option<crate::types::ExtensionType>,
+        page_size: i64,
+        number_type: Option<crate::types::Type>,
+        pending_numbers: bool,
+        site_id: &str,
+    ) -> Result<(Vec<crate::types::ListAccountPhoneNumbersResponse>, String)>;
+    async fn list_all_account_numbers(
+        &self,
+        type_: Option<crate::types::ListAccountPhoneNumbersType>,
+        extension_type: Option<crate::types::ExtensionType>,
+        number_type: Option<crate::types::Type>,
+        pending_numbers: bool,
+        site_id: &str,
+    ) -> Result<Vec<crate::types::ListAccountPhoneNumbersResponse>>;
+    async fn user(&self, user_id: &str) -> Result<crate::types::PhoneUserResponse>;
+    async fn update_user_profile(
+        &self,
+        user_id: &str,
+        body: &crate::types::UpdateUserProfileRequest,
+    ) -> Result<()>;
+    async fn setting(&self, account_id: &str) -> Result<crate::types::PhoneSettingResponse>;
+    async fn user_settings(&self, user_id: &str)
+        -> Result<crate::types::PhoneUserSettingsResponse>;
+    async fn list_setting_templates(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        site_id: &str,
+    ) -> Result<Vec<crate::types::Templates>>;
+    async fn list_setting_templates_page(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        site_id: &str,
+    ) -> Result<(Vec<crate::types::Templates>, String)>;
+    async fn list_all_setting_templates(
+        &self,
+        site_id: &str,
+    ) -> Result<Vec<crate::types::Templates>>;
+    async fn add_setting_template(
+        &self,
+        body: &crate::types::AddSettingTemplateRequest,
+    ) -> Result<crate::types::AddSettingTemplateResponse>;
+    async fn batch_add_locations(
+        &self,
+        body: &crate::types::BatchAddLocationsRequest,
+    ) -> Result<Vec<crate::types::BatchAddLocationsResponse>>;
+    async fn list_locations(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<Vec<crate::types::ListLocationsResponse>>;
+    async fn list_locations_page(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<(Vec<crate::types::ListLocationsResponse>, String)>;
+    async fn list_all_locations(&self) -> Result<Vec<crate::types::ListLocationsResponse>>;
+    async fn add_location(
+        &self,
+        body: &crate::types::AddLocationRequest,
+    ) -> Result<crate::types::AddLocationResponse>;
+    async fn add_location_and_fetch(
+        &self,
+        body: &crate::types::AddLocationRequest,
+    ) -> Result<crate::types::GetLocationResponse>;
+    async fn get_location(&self, location_id: &str) -> Result<crate::types::GetLocationResponse>;
+    async fn delete_location(&self, location_id: &str) -> Result<()>;
+    async fn delete_location_if_exists(&self, location_id: &str) -> Result<bool>;
+    async fn update_location(
+        &self,
+        location_id: &str,
+        body: &crate::types::UpdateLocationRequest,
+    ) -> Result<()>;
+    async fn list_sip_groups(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<Vec<crate::types::SipGroups>>;
+    async fn list_sip_groups_page(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<(Vec<crate::types::SipGroups>, String)>;
+    async fn list_all_sip_groups(&self) -> Result<Vec<crate::types::SipGroups>>;
+    async fn get_setting_template(
+        &self,
+        template_id: &str,
+        custom_query_fields: &str,
+    ) -> Result<crate::types::GetSettingTemplateResponse>;
+    async fn update_setting_template(
+        &self,
+        template_id: &str,
+        body: &crate::types::UpdateSettingTemplateRequest,
+    ) -> Result<()>;
+    async fn user_call_logs(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        type_: Option<crate::types::PhoneUserCallLogsType>,
+        next_page_token: &str,
+        phone_number: &str,
+        time_type: crate::types::TimeType,
+    ) -> Result<Vec<crate::types::CallLogs>>;
+    async fn user_call_logs_page(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        type_: Option<crate::types::PhoneUserCallLogsType>,
+        next_page_token: &str,
+        phone_number: &str,
+        time_type: crate::types::TimeType,
+    ) -> Result<(Vec<crate::types::CallLogs>, String)>;
+    async fn get_all_user_call_logs(
+        &self,
+        user_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        type_: Option<crate::types::PhoneUserCallLogsType>,
+        phone_number: &str,
+        time_type: crate::types::TimeType,
+    ) -> Result<Vec<crate::types::CallLogs>>;
+    async fn user_recordings(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<Vec<crate::types::Recordings>>;
+    async fn user_recordings_page(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<(Vec<crate::types::Recordings>, String)>;
+    async fn get_all_user_recordings(
+        &self,
+        user_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<Vec<crate::types::Recordings>>;
+    async fn user_voice_mails(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        status: crate::types::PhoneUserVoiceMailsStatus,
+        next_page_token: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<Vec<crate::types::VoiceMails>>;
+    async fn user_voice_mails_page(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        status: crate::types::PhoneUserVoiceMailsStatus,
+        next_page_token: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<(Vec<crate::types::VoiceMails>, String)>;
+    async fn get_all_user_voice_mails(
+        &self,
+        user_id: &str,
+        status: crate::types::PhoneUserVoiceMailsStatus,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<Vec<crate::types::VoiceMails>>;
+    async fn add_user_setting(
+        &self,
+        user_id: &str,
+        setting_type: &str,
+        body: &crate::types::AddUserSettingRequest,
+    ) -> Result<crate::types::AddUserSettingResponse>;
+    async fn delete_user_setting(
+        &self,
+        user_id: &str,
+        setting_type: &str,
+        shared_id: &str,
+    ) -> Result<()>;
+    async fn delete_user_setting_if_exists(
+        &self,
+        user_id: &str,
+        setting_type: &str,
+        shared_id: &str,
+    ) -> Result<bool>;
+    async fn update_user_setting(
+        &self,
+        setting_type: &str,
+        user_id: &str,
+        body: &crate::types::UpdateUserSettingRequest,
+    ) -> Result<()>;
+    async fn account_call_logs(
+        &self,
+        page_size: i64,
+        from: &str,
+        to: &str,
+        type_: &str,
+        next_page_token: &str,
+        path: &str,
+        time_type: crate::types::TimeType,
+        site_id: &str,
+    ) -> Result<Vec<crate::types::AccountCallLogsResponse>>;
+    async fn account_call_logs_page(
+        &self,
+        page_size: i64,
+        from: &str,
+        to: &str,
+        type_: &str,
+        next_page_token: &str,
+        path: &str,
+        time_type: crate::types::TimeType,
+        site_id: &str,
+    ) -> Result<(Vec<crate::types::AccountCallLogsResponse>, String)>;
+    async fn account_call_logs_between(
+        &self,
+        page_size: i64,
+        from: chrono::DateTime<chrono::Utc>,
+        to: chrono::DateTime<chrono::Utc>,
+        type_: &str,
+        next_page_token: &str,
+        path: &str,
+        time_type: crate::types::TimeType,
+        site_id: &str,
+    ) -> Result<Vec<crate::types::AccountCallLogsResponse>>;
+    async fn account_call_logs_between_page(
+        &self,
+        page_size: i64,
+        from: chrono::DateTime<chrono::Utc>,
+        to: chrono::DateTime<chrono::Utc>,
+        type_: &str,
+        next_page_token: &str,
+        path: &str,
+        time_type: crate::types::TimeType,
+        site_id: &str,
+    ) -> Result<(Vec<crate::types::AccountCallLogsResponse>, String)>;
+    async fn get_all_account_call_logs(
+        &self,
+        from: &str,
+        to: &str,
+        type_: &str,
+        path: &str,
+        time_type: crate::types::TimeType,
+        site_id: &str,
+    ) -> Result<Vec<crate::types::AccountCallLogsResponse>>;
+    async fn assign_number(
+        &self,
+        user_id: &str,
+        body: &crate::types::AddByocNumberResponse,
+    ) -> Result<crate::types::AddByocNumberResponse>;
+    async fn unassign_number(&self, user_id: &str, phone_number_id: &str) -> Result<()>;
+    async fn unassign_number_if_exists(&self, user_id: &str, phone_number_id: &str)
+        -> Result<bool>;
+    async fn assign_calling_plan(
+        &self,
+        user_id: &str,
+        body: &crate::types::AssignCallingPlanRequest,
+    ) -> Result<()>;
+    async fn unassign_calling_plan(
+        &self,
+        user_id: &str,
+        type_: &crate::calling_plan::CallingPlanType,
+    ) -> Result<()>;
+    async fn unassign_calling_plan_if_exists(
+        &self,
+        user_id: &str,
+        type_: &crate::calling_plan::CallingPlanType,
+    ) -> Result<bool>;
+    async fn get_recordings(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        from: &str,
+        to: &str,
+        owner_type: Option<crate::types::OwnerType>,
+        recording_type: Option<crate::types::GetPhoneRecordingsRecordingType>,
+        site_id: &str,
+        query_date_type: crate::types::QueryDateType,
+    ) -> Result<Vec<crate::types::GetPhoneRecordingsResponse>>;
+    async fn get_recordings_page(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        from: &str,
+        to: &str,
+        owner_type: Option<crate::types::OwnerType>,
+        recording_type: Option<crate::types::GetPhoneRecordingsRecordingType>,
+        site_id: &str,
+        query_date_type: crate::types::QueryDateType,
+    ) -> Result<(Vec<crate::types::GetPhoneRecordingsResponse>, String)>;
+    async fn get_recordings_between(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        from: chrono::DateTime<chrono::Utc>,
+        to: chrono::DateTime<chrono::Utc>,
+        owner_type: Option<crate::types::OwnerType>,
+        recording_type: Option<crate::types::GetPhoneRecordingsRecordingType>,
+        site_id: &str,
+        query_date_type: crate::types::QueryDateType,
+    ) -> Result<Vec<crate::types::GetPhoneRecordingsResponse>>;
+    async fn get_recordings_between_page(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        from: chrono::DateTime<chrono::Utc>,
+        to: chrono::DateTime<chrono::Utc>,
+        owner_type: Option<crate::types::OwnerType>,
+        recording_type: Option<crate::types::GetPhoneRecordingsRecordingType>,
+        site_id: &str,
+        query_date_type: crate::types::QueryDateType,
+    ) -> Result<(Vec<crate::types::GetPhoneRecordingsResponse>, String)>;
+    async fn get_all_recordings(
+        &self,
+        from: &str,
+        to: &str,
+        owner_type: Option<crate::types::OwnerType>,
+        recording_type: Option<crate::types::GetPhoneRecordingsRecordingType>,
+        site_id: &str,
+        query_date_type: crate::types::QueryDateType,
+    ) -> Result<Vec<crate::types::GetPhoneRecordingsResponse>>;
+    async fn list_byocsip_trunk(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<Vec<crate::types::ByocSipTrunk>>;
+    async fn list_byocsip_trunk_page(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<(Vec<crate::types::ByocSipTrunk>, String)>;
+    async fn list_all_byocsip_trunk(&self) -> Result<Vec<crate::types::ByocSipTrunk>>;
+    async fn list_external_contacts(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<Vec<crate::types::ExternalContacts>>;
+    async fn list_external_contacts_page(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<(Vec<crate::types::ExternalContacts>, String)>;
+    async fn list_all_external_contacts(&self) -> Result<Vec<crate::types::ExternalContacts>>;
+    async fn add_external_contact(
+        &self,
+        body: &crate::types::AddExternalContactRequest,
+    ) -> Result<()>;
+    async fn get_external_contact(
+        &self,
+        external_contact_id: &str,
+    ) -> Result<crate::types::ExternalContacts>;
+    async fn delete_external_contact(&self, external_contact_id: &str) -> Result<()>;
+    async fn delete_external_contact_if_exists(&self, external_contact_id: &str) -> Result<bool>;
+    async fn update_external_contact(
+        &self,
+        external_contact_id: &str,
+        body: &crate::types::UpdateExternalContactRequest,
+    ) -> Result<()>;
+    async fn get_number_details(
+        &self,
+        number_id: &crate::number_id::NumberId,
+    ) -> Result<crate::types::GetPhoneNumberDetailsResponse>;
+    async fn update_number_details(
+        &self,
+        number_id: &crate::number_id::NumberId,
+        body: &crate::types::UpdatePhoneNumberDetailsRequest,
+    ) -> Result<()>;
+    async fn change_main_company_number(
+        &self,
+        body: &crate::types::ChangeMainCompanyNumberRequest,
+    ) -> Result<()>;
+    async fn list_calling_plan(&self) -> Result<crate::types::ListCallingPlansResponseData>;
+    async fn list_users(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        site_id: &str,
+    ) -> Result<Vec<crate::types::ListPhoneUsersResponse>>;
+    async fn list_users_page(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        site_id: &str,
+    ) -> Result<(Vec<crate::types::ListPhoneUsersResponse>, String)>;
+    async fn list_all_users(
+        &self,
+        site_id: &str,
+    ) -> Result<Vec<crate::types::ListPhoneUsersResponse>>;
+    async fn get_call_log_details(
+        &self,
+        call_log_id: &str,
+    ) -> Result<crate::types::GetCallLogDetailsResponse>;
+    async fn delete_call_log(&self, user_id: &str, call_log_id: &str) -> Result<()>;
+    async fn delete_call_log_if_exists(&self, user_id: &str, call_log_id: &str) -> Result<bool>;
+    async fn add_byoc_number(
+        &self,
+        body: &crate::types::AddByocNumberRequest,
+    ) -> Result<crate::types::AddByocNumberResponse>;
+    async fn delete_voicemail(&self, voicemail_id: &str) -> Result<()>;
+    async fn delete_voicemail_if_exists(&self, voicemail_id: &str) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl PhoneApi for Phone {
+    async fn set_up_account(
+        &self,
+        account_id: &str,
+        body: &crate::types::SetUpAccountRequest,
+    ) -> Result<()> {
+        Phone::set_up_account(self, account_id, body).await
+    }
+    async fn list_account_numbers(
+        &self,
+        next_page_token: &str,
+        type_: Option<crate::types::ListAccountPhoneNumbersType>,
+        extension_type: Option<crate::types::ExtensionType>,
+        page_size: i64,
+        number_type: Option<crate::types::Type>,
+        pending_numbers: bool,
+        site_id: &str,
+    ) -> Result<Vec<crate::types::ListAccountPhoneNumbersResponse>> {
+        Phone::list_account_numbers(
+            self,
+            next_page_token,
+            type_,
+            extension_type,
+            page_size,
+            number_type,
+            pending_numbers,
+            site_id,
+        )
+        .await
+    }
+    async fn list_account_numbers_page(
+        &self,
+        next_page_token: &str,
+        type_: Option<crate::types::ListAccountPhoneNumbersType>,
+        extension_type: Option<crate::types::ExtensionType>,
+        page_size: i64,
+        number_type: Option<crate::types::Type>,
+        pending_numbers: bool,
+        site_id: &str,
+    ) -> Result<(Vec<crate::types::ListAccountPhoneNumbersResponse>, String)> {
+        Phone::list_account_numbers_page(
+            self,
+            next_page_token,
+            type_,
+            extension_type,
+            page_size,
+            number_type,
+            pending_numbers,
+            site_id,
+        )
+        .await
+    }
+    async fn list_all_account_numbers(
+        &self,
+        type_: Option<crate::types::ListAccountPhoneNumbersType>,
+        extension_type: Option<crate::types::ExtensionType>,
+        number_type: Option<crate::types::Type>,
+        pending_numbers: bool,
+        site_id: &str,
+    ) -> Result<Vec<crate::types::ListAccountPhoneNumbersResponse>> {
+        Phone::list_all_account_numbers(
+            self,
+            type_,
+            extension_type,
+            number_type,
+            pending_numbers,
+            site_id,
+        )
+        .await
+    }
+    async fn user(&self, user_id: &str) -> Result<crate::types::PhoneUserResponse> {
+        Phone::user(self, user_id).await
+    }
+    async fn update_user_profile(
+        &self,
+        user_id: &str,
+        body: &crate::types::UpdateUserProfileRequest,
+    ) -> Result<()> {
+        Phone::update_user_profile(self, user_id, body).await
+    }
+    async fn setting(&self, account_id: &str) -> Result<crate::types::PhoneSettingResponse> {
+        Phone::setting(self, account_id).await
+    }
+    async fn user_settings(
+        &self,
+        user_id: &str,
+    ) -> Result<crate::types::PhoneUserSettingsResponse> {
+        Phone::user_settings(self, user_id).await
+    }
+    async fn list_setting_templates(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        site_id: &str,
+    ) -> Result<Vec<crate::types::Templates>> {
+        Phone::list_setting_templates(self, page_size, next_page_token, site_id).await
+    }
+    async fn list_setting_templates_page(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        site_id: &str,
+    ) -> Result<(Vec<crate::types::Templates>, String)> {
+        Phone::list_setting_templates_page(self, page_size, next_page_token, site_id).await
+    }
+    async fn list_all_setting_templates(
+        &self,
+        site_id: &str,
+    ) -> Result<Vec<crate::types::Templates>> {
+        Phone::list_all_setting_templates(self, site_id).await
+    }
+    async fn add_setting_template(
+        &self,
+        body: &crate::types::AddSettingTemplateRequest,
+    ) -> Result<crate::types::AddSettingTemplateResponse> {
+        Phone::add_setting_template(self, body).await
+    }
+    async fn batch_add_locations(
+        &self,
+        body: &crate::types::BatchAddLocationsRequest,
+    ) -> Result<Vec<crate::types::BatchAddLocationsResponse>> {
+        Phone::batch_add_locations(self, body).await
+    }
+    async fn list_locations(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<Vec<crate::types::ListLocationsResponse>> {
+        Phone::list_locations(self, next_page_token, page_size).await
+    }
+    async fn list_locations_page(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<(Vec<crate::types::ListLocationsResponse>, String)> {
+        Phone::list_locations_page(self, next_page_token, page_size).await
+    }
+    async fn list_all_locations(&self) -> Result<Vec<crate::types::ListLocationsResponse>> {
+        Phone::list_all_locations(self).await
+    }
+    async fn add_location(
+        &self,
+        body: &crate::types::AddLocationRequest,
+    ) -> Result<crate::types::AddLocationResponse> {
+        Phone::add_location(self, body).await
+    }
+    async fn add_location_and_fetch(
+        &self,
+        body: &crate::types::AddLocationRequest,
+    ) -> Result<crate::types::GetLocationResponse> {
+        Phone::add_location_and_fetch(self, body).await
+    }
+    async fn get_location(&self, location_id: &str) -> Result<crate::types::GetLocationResponse> {
+        Phone::get_location(self, location_id).await
+    }
+    async fn delete_location(&self, location_id: &str) -> Result<()> {
+        Phone::delete_location(self, location_id).await
+    }
+    async fn delete_location_if_exists(&self, location_id: &str) -> Result<bool> {
+        Phone::delete_location_if_exists(self, location_id).await
+    }
+    async fn update_location(
+        &self,
+        location_id: &str,
+        body: &crate::types::UpdateLocationRequest,
+    ) -> Result<()> {
+        Phone::update_location(self, location_id, body).await
+    }
+    async fn list_sip_groups(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<Vec<crate::types::SipGroups>> {
+        Phone::list_sip_groups(self, next_page_token, page_size).await
+    }
+    async fn list_sip_groups_page(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<(Vec<crate::types::SipGroups>, String)> {
+        Phone::list_sip_groups_page(self, next_page_token, page_size).await
+    }
+    async fn list_all_sip_groups(&self) -> Result<Vec<crate::types::SipGroups>> {
+        Phone::list_all_sip_groups(self).await
+    }
+    async fn get_setting_template(
+        &self,
+        template_id: &str,
+        custom_query_fields: &str,
+    ) -> Result<crate::types::GetSettingTemplateResponse> {
+        Phone::get_setting_template(self, template_id, custom_query_fields).await
+    }
+    async fn update_setting_template(
+        &self,
+        template_id: &str,
+        body: &crate::types::UpdateSettingTemplateRequest,
+    ) -> Result<()> {
+        Phone::update_setting_template(self, template_id, body).await
+    }
+    async fn user_call_logs(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        type_: Option<crate::types::PhoneUserCallLogsType>,
+        next_page_token: &str,
+        phone_number: &str,
+        time_type: crate::types::TimeType,
+    ) -> Result<Vec<crate::types::CallLogs>> {
+        Phone::user_call_logs(
+            self,
+            user_id,
+            page_size,
+            from,
+            to,
+            type_,
+            next_page_token,
+            phone_number,
+            time_type,
+        )
+        .await
+    }
+    async fn user_call_logs_page(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        type_: Option<crate::types::PhoneUserCallLogsType>,
+        next_page_token: &str,
+        phone_number: &str,
+        time_type: crate::types::TimeType,
+    ) -> Result<(Vec<crate::types::CallLogs>, String)> {
+        Phone::user_call_logs_page(
+            self,
+            user_id,
+            page_size,
+            from,
+            to,
+            type_,
+            next_page_token,
+            phone_number,
+            time_type,
+        )
+        .await
+    }
+    async fn get_all_user_call_logs(
+        &self,
+        user_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        type_: Option<crate::types::PhoneUserCallLogsType>,
+        phone_number: &str,
+        time_type: crate::types::TimeType,
+    ) -> Result<Vec<crate::types::CallLogs>> {
+        Phone::get_all_user_call_logs(self, user_id, from, to, type_, phone_number, time_type).await
+    }
+    async fn user_recordings(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<Vec<crate::types::Recordings>> {
+        Phone::user_recordings(self, user_id, page_size, next_page_token, from, to).await
+    }
+    async fn user_recordings_page(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<(Vec<crate::types::Recordings>, String)> {
+        Phone::user_recordings_page(self, user_id, page_size, next_page_token, from, to).await
+    }
+    async fn get_all_user_recordings(
+        &self,
+        user_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<Vec<crate::types::Recordings>> {
+        Phone::get_all_user_recordings(self, user_id, from, to).await
+    }
+    async fn user_voice_mails(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        status: crate::types::PhoneUserVoiceMailsStatus,
+        next_page_token: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<Vec<crate::types::VoiceMails>> {
+        Phone::user_voice_mails(self, user_id, page_size, status, next_page_token, from, to).await
+    }
+    async fn user_voice_mails_page(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        status: crate::types::PhoneUserVoiceMailsStatus,
+        next_page_token: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<(Vec<crate::types::VoiceMails>, String)> {
+        Phone::user_voice_mails_page(self, user_id, page_size, status, next_page_token, from, to)
+            .await
+    }
+    async fn get_all_user_voice_mails(
+        &self,
+        user_id: &str,
+        status: crate::types::PhoneUserVoiceMailsStatus,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<Vec<crate::types::VoiceMails>> {
+        Phone::get_all_user_voice_mails(self, user_id, status, from, to).await
+    }
+    async fn add_user_setting(
+        &self,
+        user_id: &str,
+        setting_type: &str,
+        body: &crate::types::AddUserSettingRequest,
+    ) -> Result<crate::types::AddUserSettingResponse> {
+        Phone::add_user_setting(self, user_id, setting_type, body).await
+    }
+    async fn delete_user_setting(
+        &self,
+        user_id: &str,
+        setting_type: &str,
+        shared_id: &str,
+    ) -> Result<()> {
+        Phone::delete_user_setting(self, user_id, setting_type, shared_id).await
+    }
+    async fn delete_user_setting_if_exists(
+        &self,
+        user_id: &str,
+        setting_type: &str,
+        shared_id: &str,
+    ) -> Result<bool> {
+        Phone::delete_user_setting_if_exists(self, user_id, setting_type, shared_id).await
+    }
+    async fn update_user_setting(
+        &self,
+        setting_type: &str,
+        user_id: &str,
+        body: &crate::types::UpdateUserSettingRequest,
+    ) -> Result<()> {
+        Phone::update_user_setting(self, setting_type, user_id, body).await
+    }
+    async fn account_call_logs(
+        &self,
+        page_size: i64,
+        from: &str,
+        to: &str,
+        type_: &str,
+        next_page_token: &str,
+        path: &str,
+        time_type: crate::types::TimeType,
+        site_id: &str,
+    ) -> Result<Vec<crate::types::AccountCallLogsResponse>> {
+        Phone::account_call_logs(
+            self,
+            page_size,
+            from,
+            to,
+            type_,
+            next_page_token,
+            path,
+            time_type,
+            site_id,
+        )
+        .await
+    }
+    async fn account_call_logs_page(
+        &self,
+        page_size: i64,
+        from: &str,
+        to: &str,
+        type_: &str,
+        next_page_token: &str,
+        path: &str,
+        time_type: crate::types::TimeType,
+        site_id: &str,
+    ) -> Result<(Vec<crate::types::AccountCallLogsResponse>, String)> {
+        Phone::account_call_logs_page(
+            self,
+            page_size,
+            from,
+            to,
+            type_,
+            next_page_token,
+            path,
+            time_type,
+            site_id,
+        )
+        .await
+    }
+    async fn account_call_logs_between(
+        &self,
+        page_size: i64,
+        from: chrono::DateTime<chrono::Utc>,
+        to: chrono::DateTime<chrono::Utc>,
+        type_: &str,
+        next_page_token: &str,
+        path: &str,
+        time_type: crate::types::TimeType,
+        site_id: &str,
+    ) -> Result<Vec<crate::types::AccountCallLogsResponse>> {
+        Phone::account_call_logs_between(
+            self,
+            page_size,
+            from,
+            to,
+            type_,
+            next_page_token,
+            path,
+            time_type,
+            site_id,
+        )
+        .await
+    }
+    async fn account_call_logs_between_page(
+        &self,
+        page_size: i64,
+        from: chrono::DateTime<chrono::Utc>,
+        to: chrono::DateTime<chrono::Utc>,
+        type_: &str,
+        next_page_token: &str,
+        path: &str,
+        time_type: crate::types::TimeType,
+        site_id: &str,
+    ) -> Result<(Vec<crate::types::AccountCallLogsResponse>, String)> {
+        Phone::account_call_logs_between_page(
+            self,
+            page_size,
+            from,
+            to,
+            type_,
+            next_page_token,
+            path,
+            time_type,
+            site_id,
+        )
+        .await
+    }
+    async fn get_all_account_call_logs(
+        &self,
+        from: &str,
+        to: &str,
+        type_: &str,
+        path: &str,
+        time_type: crate::types::TimeType,
+        site_id: &str,
+    ) -> Result<Vec<crate::types::AccountCallLogsResponse>> {
+        Phone::get_all_account_call_logs(self, from, to, type_, path, time_type, site_id).await
+    }
+    async fn assign_number(
+        &self,
+        user_id: &str,
+        body: &crate::types::AddByocNumberResponse,
+    ) -> Result<crate::types::AddByocNumberResponse> {
+        Phone::assign_number(self, user_id, body).await
+    }
+    async fn unassign_number(&self, user_id: &str, phone_number_id: &str) -> Result<()> {
+        Phone::unassign_number(self, user_id, phone_number_id).await
+    }
+    async fn unassign_number_if_exists(
+        &self,
+        user_id: &str,
+        phone_number_id: &str,
+    ) -> Result<bool> {
+        Phone::unassign_number_if_exists(self, user_id, phone_number_id).await
+    }
+    async fn assign_calling_plan(
+        &self,
+        user_id: &str,
+        body: &crate::types::AssignCallingPlanRequest,
+    ) -> Result<()> {
+        Phone::assign_calling_plan(self, user_id, body).await
+    }
+    async fn unassign_calling_plan(
+        &self,
+        user_id: &str,
+        type_: &crate::calling_plan::CallingPlanType,
+    ) -> Result<()> {
+        Phone::unassign_calling_plan(self, user_id, type_).await
+    }
+    async fn unassign_calling_plan_if_exists(
+        &self,
+        user_id: &str,
+        type_: &crate::calling_plan::CallingPlanType,
+    ) -> Result<bool> {
+        Phone::unassign_calling_plan_if_exists(self, user_id, type_).await
+    }
+    async fn get_recordings(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        from: &str,
+        to: &str,
+        owner_type: Option<crate::types::OwnerType>,
+        recording_type: Option<crate::types::GetPhoneRecordingsRecordingType>,
+        site_id: &str,
+        query_date_type: crate::types::QueryDateType,
+    ) -> Result<Vec<crate::types::GetPhoneRecordingsResponse>> {
+        Phone::get_recordings(
+            self,
+            page_size,
+            next_page_token,
+            from,
+            to,
+            owner_type,
+            recording_type,
+            site_id,
+            query_date_type,
+        )
+        .await
+    }
+    async fn get_recordings_page(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        from: &str,
+        to: &str,
+        owner_type: Option<crate::types::OwnerType>,
+        recording_type: Option<crate::types::GetPhoneRecordingsRecordingType>,
+        site_id: &str,
+        query_date_type: crate::types::QueryDateType,
+    ) -> Result<(Vec<crate::types::GetPhoneRecordingsResponse>, String)> {
+        Phone::get_recordings_page(
+            self,
+            page_size,
+            next_page_token,
+            from,
+            to,
+            owner_type,
+            recording_type,
+            site_id,
+            query_date_type,
+        )
+        .await
+    }
+    async fn get_recordings_between(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        from: chrono::DateTime<chrono::Utc>,
+        to: chrono::DateTime<chrono::Utc>,
+        owner_type: Option<crate::types::OwnerType>,
+        recording_type: Option<crate::types::GetPhoneRecordingsRecordingType>,
+        site_id: &str,
+        query_date_type: crate::types::QueryDateType,
+    ) -> Result<Vec<crate::types::GetPhoneRecordingsResponse>> {
+        Phone::get_recordings_between(
+            self,
+            page_size,
+            next_page_token,
+            from,
+            to,
+            owner_type,
+            recording_type,
+            site_id,
+            query_date_type,
+        )
+        .await
+    }
+    async fn get_recordings_between_page(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        from: chrono::DateTime<chrono::Utc>,
+        to: chrono::DateTime<chrono::Utc>,
+        owner_type: Option<crate::types::OwnerType>,
+        recording_type: Option<crate::types::GetPhoneRecordingsRecordingType>,
+        site_id: &str,
+        query_date_type: crate::types::QueryDateType,
+    ) -> Result<(Vec<crate::types::GetPhoneRecordingsResponse>, String)> {
+        Phone::get_recordings_between_page(
+            self,
+            page_size,
+            next_page_token,
+            from,
+            to,
+            owner_type,
+            recording_type,
+            site_id,
+            query_date_type,
+        )
+        .await
+    }
+    async fn get_all_recordings(
+        &self,
+        from: &str,
+        to: &str,
+        owner_type: Option<crate::types::OwnerType>,
+        recording_type: Option<crate::types::GetPhoneRecordingsRecordingType>,
+        site_id: &str,
+        query_date_type: crate::types::QueryDateType,
+    ) -> Result<Vec<crate::types::GetPhoneRecordingsResponse>> {
+        Phone::get_all_recordings(
+            self,
+            from,
+            to,
+            owner_type,
+            recording_type,
+            site_id,
+            query_date_type,
+        )
+        .await
+    }
+    async fn list_byocsip_trunk(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<Vec<crate::types::ByocSipTrunk>> {
+        Phone::list_byocsip_trunk(self, next_page_token, page_size).await
+    }
+    async fn list_byocsip_trunk_page(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<(Vec<crate::types::ByocSipTrunk>, String)> {
+        Phone::list_byocsip_trunk_page(self, next_page_token, page_size).await
+    }
+    async fn list_all_byocsip_trunk(&self) -> Result<Vec<crate::types::ByocSipTrunk>> {
+        Phone::list_all_byocsip_trunk(self).await
+    }
+    async fn list_external_contacts(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<Vec<crate::types::ExternalContacts>> {
+        Phone::list_external_contacts(self, next_page_token, page_size).await
+    }
+    async fn list_external_contacts_page(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<(Vec<crate::types::ExternalContacts>, String)> {
+        Phone::list_external_contacts_page(self, next_page_token, page_size).await
+    }
+    async fn list_all_external_contacts(&self) -> Result<Vec<crate::types::ExternalContacts>> {
+        Phone::list_all_external_contacts(self).await
+    }
+    async fn add_external_contact(
+        &self,
+        body: &crate::types::AddExternalContactRequest,
+    ) -> Result<()> {
+        Phone::add_external_contact(self, body).await
+    }
+    async fn get_external_contact(
+        &self,
+        external_contact_id: &str,
+    ) -> Result<crate::types::ExternalContacts> {
+        Phone::get_external_contact(self, external_contact_id).await
+    }
+    async fn delete_external_contact(&self, external_contact_id: &str) -> Result<()> {
+        Phone::delete_external_contact(self, external_contact_id).await
+    }
+    async fn delete_external_contact_if_exists(&self, external_contact_id: &str) -> Result<bool> {
+        Phone::delete_external_contact_if_exists(self, external_contact_id).await
+    }
+    async fn update_external_contact(
+        &self,
+        external_contact_id: &str,
+        body: &crate::types::UpdateExternalContactRequest,
+    ) -> Result<()> {
+        Phone::update_external_contact(self, external_contact_id, body).await
+    }
+    async fn get_number_details(
+        &self,
+        number_id: &crate::number_id::NumberId,
+    ) -> Result<crate::types::GetPhoneNumberDetailsResponse> {
+        Phone::get_number_details(self, number_id).await
+    }
+    async fn update_number_details(
+        &self,
+        number_id: &crate::number_id::NumberId,
+        body: &crate::types::UpdatePhoneNumberDetailsRequest,
+    ) -> Result<()> {
+        Phone::update_number_details(self, number_id, body).await
+    }
+    async fn change_main_company_number(
+        &self,
+        body: &crate::types::ChangeMainCompanyNumberRequest,
+    ) -> Result<()> {
+        Phone::change_main_company_number(self, body).await
+    }
+    async fn list_calling_plan(&self) -> Result<crate::types::ListCallingPlansResponseData> {
+        Phone::list_calling_plan(self).await
+    }
+    async fn list_users(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        site_id: &str,
+    ) -> Result<Vec<crate::types::ListPhoneUsersResponse>> {
+        Phone::list_users(self, page_size, next_page_token, site_id).await
+    }
+    async fn list_users_page(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        site_id: &str,
+    ) -> Result<(Vec<crate::types::ListPhoneUsersResponse>, String)> {
+        Phone::list_users_page(self, page_size, next_page_token, site_id).await
+    }
+    async fn list_all_users(
+        &self,
+        site_id: &str,
+    ) -> Result<Vec<crate::types::ListPhoneUsersResponse>> {
+        Phone::list_all_users(self, site_id).await
+    }
+    async fn get_call_log_details(
+        &self,
+        call_log_id: &str,
+    ) -> Result<crate::types::GetCallLogDetailsResponse> {
+        Phone::get_call_log_details(self, call_log_id).await
+    }
+    async fn delete_call_log(&self, user_id: &str, call_log_id: &str) -> Result<()> {
+        Phone::delete_call_log(self, user_id, call_log_id).await
+    }
+    async fn delete_call_log_if_exists(&self, user_id: &str, call_log_id: &str) -> Result<bool> {
+        Phone::delete_call_log_if_exists(self, user_id, call_log_id).await
+    }
+    async fn add_byoc_number(
+        &self,
+        body: &crate::types::AddByocNumberRequest,
+    ) -> Result<crate::types::AddByocNumberResponse> {
+        Phone::add_byoc_number(self, body).await
+    }
+    async fn delete_voicemail(&self, voicemail_id: &str) -> Result<()> {
+        Phone::delete_voicemail(self, voicemail_id).await
+    }
+    async fn delete_voicemail_if_exists(&self, voicemail_id: &str) -> Result<bool> {
+        Phone::delete_voicemail_if_exists(self, voicemail_id).await
     }
 }
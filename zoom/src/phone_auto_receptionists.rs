@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct PhoneAutoReceptionists {
     pub client: Client,
@@ -12,6 +10,17 @@ impl PhoneAutoReceptionists {
         PhoneAutoReceptionists { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        PhoneAutoReceptionists {
+            client: self.client.with_retry(policy),
+        }
+    }
+
+    /// The OAuth scopes for `update_auto_receptionist`, any one of them grants access.
+    pub const UPDATE_AUTO_RECEPTIONIST_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Update auto receptionist details.
      *
@@ -37,14 +46,54 @@ impl PhoneAutoReceptionists {
     ) -> Result<()> {
         let url = format!(
             "/phone/auto_receptionists/{}",
-            crate::progenitor_support::encode_path(&auto_receptionist_id.to_string()),
+            self.client.encode_path(auto_receptionist_id),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/phone/auto_receptionists/{autoReceptionistId}",
+                    &[("autoReceptionistId", auto_receptionist_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update auto receptionist details.
+     *
+     * This function performs a `PATCH` to the `/phone/auto_receptionists/{autoReceptionistId}` endpoint.
+     *
+     * As opposed to `update_auto_receptionist`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn update_auto_receptionist_if_changed<C: serde::Serialize>(
+        &self,
+        auto_receptionist_id: &str,
+        current: &C,
+        body: &crate::types::UpdateAutoReceptionistRequest,
+    ) -> Result<Option<()>> {
+        let url = format!(
+            "/phone/auto_receptionists/{}",
+            self.client.encode_path(auto_receptionist_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/phone/auto_receptionists/{autoReceptionistId}",
+                    &[("autoReceptionistId", auto_receptionist_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `assign_phone_numbers_auto_receptionist`, any one of them grants access.
+    pub const ASSIGN_PHONE_NUMBERS_AUTO_RECEPTIONIST_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Assign phone numbers.
      *
@@ -71,14 +120,24 @@ impl PhoneAutoReceptionists {
     ) -> Result<()> {
         let url = format!(
             "/phone/auto_receptionists/{}/phone_numbers",
-            crate::progenitor_support::encode_path(&auto_receptionist_id.to_string()),
+            self.client.encode_path(auto_receptionist_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/phone/auto_receptionists/{autoReceptionistId}/phone_numbers",
+                    &[("autoReceptionistId", auto_receptionist_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `unassign_all_phone_nums_auto_receptionist`, any one of them grants access.
+    pub const UNASSIGN_ALL_PHONE_NUMS_AUTO_RECEPTIONIST_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Unassign all phone numbers.
      *
@@ -100,12 +159,49 @@ impl PhoneAutoReceptionists {
     ) -> Result<()> {
         let url = format!(
             "/phone/auto_receptionists/{}/phone_numbers",
-            crate::progenitor_support::encode_path(&auto_receptionist_id.to_string()),
+            self.client.encode_path(auto_receptionist_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/phone/auto_receptionists/{autoReceptionistId}/phone_numbers",
+                &[("autoReceptionistId", auto_receptionist_id.to_string())],
+            )
+        })
     }
 
+    /**
+     * Unassign all phone numbers.
+     *
+     * This function performs a `DELETE` to the `/phone/auto_receptionists/{autoReceptionistId}/phone_numbers` endpoint.
+     *
+     * As opposed to `unassign_all_phone_nums_auto_receptionist`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn unassign_all_phone_nums_auto_receptionist_if_exists(
+        &self,
+        auto_receptionist_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/phone/auto_receptionists/{}/phone_numbers",
+            self.client.encode_path(auto_receptionist_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/phone/auto_receptionists/{autoReceptionistId}/phone_numbers",
+                    &[("autoReceptionistId", auto_receptionist_id.to_string())],
+                )
+            })
+    }
+
+    /// The OAuth scopes for `unassign_phone_num_auto_receptionist`, any one of them grants access.
+    pub const UNASSIGN_PHONE_NUM_AUTO_RECEPTIONIST_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Unassign a phone number.
      *
@@ -133,13 +229,58 @@ impl PhoneAutoReceptionists {
     ) -> Result<()> {
         let url = format!(
             "/phone/auto_receptionists/{}/phone_numbers/{}",
-            crate::progenitor_support::encode_path(&auto_receptionist_id.to_string()),
-            crate::progenitor_support::encode_path(&phone_number_id.to_string()),
+            self.client.encode_path(auto_receptionist_id),
+            self.client.encode_path(phone_number_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/phone/auto_receptionists/{autoReceptionistId}/phone_numbers/{phoneNumberId}",
+                &[
+                    ("autoReceptionistId", auto_receptionist_id.to_string()),
+                    ("phoneNumberId", phone_number_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Unassign a phone number.
+     *
+     * This function performs a `DELETE` to the `/phone/auto_receptionists/{autoReceptionistId}/phone_numbers/{phoneNumberId}` endpoint.
+     *
+     * As opposed to `unassign_phone_num_auto_receptionist`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn unassign_phone_num_auto_receptionist_if_exists(
+        &self,
+        auto_receptionist_id: &str,
+        phone_number_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/phone/auto_receptionists/{}/phone_numbers/{}",
+            self.client.encode_path(auto_receptionist_id),
+            self.client.encode_path(phone_number_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/phone/auto_receptionists/{autoReceptionistId}/phone_numbers/{phoneNumberId}",
+                    &[
+                        ("autoReceptionistId", auto_receptionist_id.to_string()),
+                        ("phoneNumberId", phone_number_id.to_string()),
+                    ],
+                )
+            })
     }
 
+    /// The OAuth scopes for `add_auto_receptionist`, any one of them grants access.
+    pub const ADD_AUTO_RECEPTIONIST_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Add an auto receptionist.
      *
@@ -160,7 +301,122 @@ impl PhoneAutoReceptionists {
     ) -> Result<crate::types::AddAutoReceptionistResponse> {
         let url = "/phone/auto_receptionists".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/phone/auto_receptionists", &[]))
+    }
+}
+
+/// The functions of [`PhoneAutoReceptionists`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn PhoneAutoReceptionistsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait PhoneAutoReceptionistsApi: Send + Sync {
+    async fn update_auto_receptionist(
+        &self,
+        auto_receptionist_id: &str,
+        body: &crate::types::UpdateAutoReceptionistRequest,
+    ) -> Result<()>;
+    async fn assign_phone_numbers_auto_receptionist(
+        &self,
+        auto_receptionist_id: &str,
+        body: &crate::types::AddByocNumberResponse,
+    ) -> Result<()>;
+    async fn unassign_all_phone_nums_auto_receptionist(
+        &self,
+        auto_receptionist_id: &str,
+    ) -> Result<()>;
+    async fn unassign_all_phone_nums_auto_receptionist_if_exists(
+        &self,
+        auto_receptionist_id: &str,
+    ) -> Result<bool>;
+    async fn unassign_phone_num_auto_receptionist(
+        &self,
+        auto_receptionist_id: &str,
+        phone_number_id: &str,
+    ) -> Result<()>;
+    async fn unassign_phone_num_auto_receptionist_if_exists(
+        &self,
+        auto_receptionist_id: &str,
+        phone_number_id: &str,
+    ) -> Result<bool>;
+    async fn add_auto_receptionist(
+        &self,
+        body: &crate::types::AddAutoReceptionistRequest,
+    ) -> Result<crate::types::AddAutoReceptionistResponse>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl PhoneAutoReceptionistsApi for PhoneAutoReceptionists {
+    async fn update_auto_receptionist(
+        &self,
+        auto_receptionist_id: &str,
+        body: &crate::types::UpdateAutoReceptionistRequest,
+    ) -> Result<()> {
+        PhoneAutoReceptionists::update_auto_receptionist(self, auto_receptionist_id, body).await
+    }
+    async fn assign_phone_numbers_auto_receptionist(
+        &self,
+        auto_receptionist_id: &str,
+        body: &crate::types::AddByocNumberResponse,
+    ) -> Result<()> {
+        PhoneAutoReceptionists::assign_phone_numbers_auto_receptionist(
+            self,
+            auto_receptionist_id,
+            body,
+        )
+        .await
+    }
+    async fn unassign_all_phone_nums_auto_receptionist(
+        &self,
+        auto_receptionist_id: &str,
+    ) -> Result<()> {
+        PhoneAutoReceptionists::unassign_all_phone_nums_auto_receptionist(
+            self,
+            auto_receptionist_id,
+        )
+        .await
+    }
+    async fn unassign_all_phone_nums_auto_receptionist_if_exists(
+        &self,
+        auto_receptionist_id: &str,
+    ) -> Result<bool> {
+        PhoneAutoReceptionists::unassign_all_phone_nums_auto_receptionist_if_exists(
+            self,
+            auto_receptionist_id,
+        )
+        .await
+    }
+    async fn unassign_phone_num_auto_receptionist(
+        &self,
+        auto_receptionist_id: &str,
+        phone_number_id: &str,
+    ) -> Result<()> {
+        PhoneAutoReceptionists::unassign_phone_num_auto_receptionist(
+            self,
+            auto_receptionist_id,
+            phone_number_id,
+        )
+        .await
+    }
+    async fn unassign_phone_num_auto_receptionist_if_exists(
+        &self,
+        auto_receptionist_id: &str,
+        phone_number_id: &str,
+    ) -> Result<bool> {
+        PhoneAutoReceptionists::unassign_phone_num_auto_receptionist_if_exists(
+            self,
+            auto_receptionist_id,
+            phone_number_id,
+        )
+        .await
+    }
+    async fn add_auto_receptionist(
+        &self,
+        body: &crate::types::AddAutoReceptionistRequest,
+    ) -> Result<crate::types::AddAutoReceptionistResponse> {
+        PhoneAutoReceptionists::add_auto_receptionist(self, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct PhoneBlockedList {
     pub client: Client,
@@ -12,6 +10,17 @@ impl PhoneBlockedList {
         PhoneBlockedList { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        PhoneBlockedList {
+            client: self.client.with_retry(policy),
+        }
+    }
+
+    /// The OAuth scopes for `list_blocked`, any one of them grants access.
+    pub const LIST_BLOCKED_SCOPES: &[&str] = &["phone:read:admin"];
+
     /**
      * List blocked lists.
      *
@@ -35,22 +44,58 @@ impl PhoneBlockedList {
         next_page_token: &str,
         page_size: i64,
     ) -> Result<Vec<crate::types::BlockedList>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/blocked_list?{}", query_);
+        let url = crate::Client::url_with_query("/phone/blocked_list", &query_args);
 
-        let resp: crate::types::ListBlockedResponse = self.client.get(&url, None).await?;
+        let resp: crate::types::ListBlockedResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/blocked_list", &[]))?;
 
         // Return our response data.
         Ok(resp.blocked_list)
     }
 
+    /**
+     * List blocked lists.
+     *
+     * This function performs a `GET` to the `/phone/blocked_list` endpoint.
+     *
+     * As opposed to `list_blocked`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
+     */
+    pub async fn list_blocked_page(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<(Vec<crate::types::BlockedList>, String)> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/blocked_list", &query_args);
+
+        let resp: crate::types::ListBlockedResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/blocked_list", &[]))?;
+
+        // Return our response data, and where the next page starts.
+        Ok((resp.blocked_list, resp.next_page_token))
+    }
+
     /**
      * List blocked lists.
      *
@@ -68,7 +113,11 @@ impl PhoneBlockedList {
      */
     pub async fn list_all_blocked(&self) -> Result<Vec<crate::types::BlockedList>> {
         let url = "/phone/blocked_list".to_string();
-        let mut resp: crate::types::ListBlockedResponse = self.client.get(&url, None).await?;
+        let mut resp: crate::types::ListBlockedResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/blocked_list", &[]))?;
 
         let mut blocked_list = resp.blocked_list;
         let mut page = resp.next_page_token;
@@ -101,6 +150,56 @@ impl PhoneBlockedList {
         Ok(blocked_list)
     }
 
+    /**
+     * List blocked lists.
+     *
+     * This function performs a `GET` to the `/phone/blocked_list` endpoint.
+     *
+     * As opposed to `list_blocked`, this function streams the items of all the pages, getting the next page only once the items of the last one are used up. The stream ends after the last page.
+     */
+    pub async fn list_blocked_stream(
+        &self,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::BlockedList>>> {
+        let url = "/phone/blocked_list".to_string();
+        self.client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListBlockedResponse| (resp.blocked_list, resp.next_page_token),
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/blocked_list", &[]))
+    }
+
+    /**
+     * List blocked lists.
+     *
+     * This function performs a `GET` to the `/phone/blocked_list` endpoint.
+     *
+     * As opposed to `list_blocked_stream`, this function streams only the items `predicate` keeps. It too gets the next page only once the items of the last one are used up, so a stream that is cut short, for example with `take`, gets no more pages than it needs.
+     */
+    pub async fn list_blocked_filter<P: FnMut(&crate::types::BlockedList) -> bool>(
+        &self,
+        mut predicate: P,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::BlockedList>>> {
+        let url = "/phone/blocked_list".to_string();
+        let items = self
+            .client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListBlockedResponse| (resp.blocked_list, resp.next_page_token),
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/blocked_list", &[]))?;
+        Ok(futures::TryStreamExt::try_filter(items, move |item| {
+            futures::future::ready(predicate(item))
+        }))
+    }
+
+    /// The OAuth scopes for `add_anumber_blocked_list`, any one of them grants access.
+    pub const ADD_ANUMBER_BLOCKED_LIST_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Create a blocked list.
      *
@@ -120,10 +219,32 @@ impl PhoneBlockedList {
     ) -> Result<crate::types::Groups> {
         let url = "/phone/blocked_list".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/phone/blocked_list", &[]))
     }
 
+    /**
+     * Create a blocked list.
+     *
+     * This function performs a `POST` to the `/phone/blocked_list` endpoint.
+     *
+     * As opposed to `add_anumber_blocked_list`, which only returns the id of what it creates, this function then does a `GET` to the `/phone/blocked_list/{blockedListId}` endpoint and returns all of it.
+     */
+    pub async fn add_anumber_blocked_list_and_fetch(
+        &self,
+        body: &crate::types::UpdateBlockedListRequest,
+    ) -> Result<crate::types::BlockedList> {
+        let url = "/phone/blocked_list".to_string();
+        self.client
+            .post_and_get(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/phone/blocked_list", &[]))
+    }
+
+    /// The OAuth scopes for `get_blocked_list`, any one of them grants access.
+    pub const GET_BLOCKED_LIST_SCOPES: &[&str] = &["phone:read:admin"];
+
     /**
      * Get blocked list details.
      *
@@ -146,12 +267,21 @@ impl PhoneBlockedList {
     ) -> Result<crate::types::BlockedList> {
         let url = format!(
             "/phone/blocked_list/{}",
-            crate::progenitor_support::encode_path(&blocked_list_id.to_string()),
+            self.client.encode_path(blocked_list_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/phone/blocked_list/{blockedListId}",
+                &[("blockedListId", blocked_list_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `delete_blocked_list`, any one of them grants access.
+    pub const DELETE_BLOCKED_LIST_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Delete a blocked list.
      *
@@ -173,12 +303,46 @@ impl PhoneBlockedList {
     pub async fn delete_blocked_list(&self, blocked_list_id: &str) -> Result<()> {
         let url = format!(
             "/phone/blocked_list/{}",
-            crate::progenitor_support::encode_path(&blocked_list_id.to_string()),
+            self.client.encode_path(blocked_list_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/phone/blocked_list/{blockedListId}",
+                &[("blockedListId", blocked_list_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete a blocked list.
+     *
+     * This function performs a `DELETE` to the `/phone/blocked_list/{blockedListId}` endpoint.
+     *
+     * As opposed to `delete_blocked_list`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_blocked_list_if_exists(&self, blocked_list_id: &str) -> Result<bool> {
+        let url = format!(
+            "/phone/blocked_list/{}",
+            self.client.encode_path(blocked_list_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/phone/blocked_list/{blockedListId}",
+                    &[("blockedListId", blocked_list_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `update_blocked_list`, any one of them grants access.
+    pub const UPDATE_BLOCKED_LIST_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Update a blocked list.
      *
@@ -203,11 +367,133 @@ impl PhoneBlockedList {
     ) -> Result<()> {
         let url = format!(
             "/phone/blocked_list/{}",
-            crate::progenitor_support::encode_path(&blocked_list_id.to_string()),
+            self.client.encode_path(blocked_list_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/phone/blocked_list/{blockedListId}",
+                    &[("blockedListId", blocked_list_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update a blocked list.
+     *
+     * This function performs a `PATCH` to the `/phone/blocked_list/{blockedListId}` endpoint.
+     *
+     * As opposed to `update_blocked_list`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn update_blocked_list_if_changed<C: serde::Serialize>(
+        &self,
+        blocked_list_id: &str,
+        current: &C,
+        body: &crate::types::UpdateBlockedListRequest,
+    ) -> Result<Option<()>> {
+        let url = format!(
+            "/phone/blocked_list/{}",
+            self.client.encode_path(blocked_list_id),
+        );
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/phone/blocked_list/{blockedListId}",
+                    &[("blockedListId", blocked_list_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`PhoneBlockedList`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn PhoneBlockedListApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait PhoneBlockedListApi: Send + Sync {
+    async fn list_blocked(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<Vec<crate::types::BlockedList>>;
+    async fn list_blocked_page(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<(Vec<crate::types::BlockedList>, String)>;
+    async fn list_all_blocked(&self) -> Result<Vec<crate::types::BlockedList>>;
+    async fn add_anumber_blocked_list(
+        &self,
+        body: &crate::types::UpdateBlockedListRequest,
+    ) -> Result<crate::types::Groups>;
+    async fn add_anumber_blocked_list_and_fetch(
+        &self,
+        body: &crate::types::UpdateBlockedListRequest,
+    ) -> Result<crate::types::BlockedList>;
+    async fn get_blocked_list(&self, blocked_list_id: &str) -> Result<crate::types::BlockedList>;
+    async fn delete_blocked_list(&self, blocked_list_id: &str) -> Result<()>;
+    async fn delete_blocked_list_if_exists(&self, blocked_list_id: &str) -> Result<bool>;
+    async fn update_blocked_list(
+        &self,
+        blocked_list_id: &str,
+        body: &crate::types::UpdateBlockedListRequest,
+    ) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl PhoneBlockedListApi for PhoneBlockedList {
+    async fn list_blocked(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<Vec<crate::types::BlockedList>> {
+        PhoneBlockedList::list_blocked(self, next_page_token, page_size).await
+    }
+    async fn list_blocked_page(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<(Vec<crate::types::BlockedList>, String)> {
+        PhoneBlockedList::list_blocked_page(self, next_page_token, page_size).await
+    }
+    async fn list_all_blocked(&self) -> Result<Vec<crate::types::BlockedList>> {
+        PhoneBlockedList::list_all_blocked(self).await
+    }
+    async fn add_anumber_blocked_list(
+        &self,
+        body: &crate::types::UpdateBlockedListRequest,
+    ) -> Result<crate::types::Groups> {
+        PhoneBlockedList::add_anumber_blocked_list(self, body).await
+    }
+    async fn add_anumber_blocked_list_and_fetch(
+        &self,
+        body: &crate::types::UpdateBlockedListRequest,
+    ) -> Result<crate::types::BlockedList> {
+        PhoneBlockedList::add_anumber_blocked_list_and_fetch(self, body).await
+    }
+    async fn get_blocked_list(&self, blocked_list_id: &str) -> Result<crate::types::BlockedList> {
+        PhoneBlockedList::get_blocked_list(self, blocked_list_id).await
+    }
+    async fn delete_blocked_list(&self, blocked_list_id: &str) -> Result<()> {
+        PhoneBlockedList::delete_blocked_list(self, blocked_list_id).await
+    }
+    async fn delete_blocked_list_if_exists(&self, blocked_list_id: &str) -> Result<bool> {
+        PhoneBlockedList::delete_blocked_list_if_exists(self, blocked_list_id).await
+    }
+    async fn update_blocked_list(
+        &self,
+        blocked_list_id: &str,
+        body: &crate::types::UpdateBlockedListRequest,
+    ) -> Result<()> {
+        PhoneBlockedList::update_blocked_list(self, blocked_list_id, body).await
     }
 }
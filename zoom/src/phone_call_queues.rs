@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct PhoneCallQueues {
     pub client: Client,
@@ -12,6 +10,17 @@ impl PhoneCallQueues {
         PhoneCallQueues { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        PhoneCallQueues {
+            client: self.client.with_retry(policy),
+        }
+    }
+
+    /// The OAuth scopes for `list_call_queues`, any one of them grants access.
+    pub const LIST_CALL_QUEUES_SCOPES: &[&str] = &["phone:read:admin"];
+
     /**
      * List call queues.
      *
@@ -38,22 +47,58 @@ impl PhoneCallQueues {
         next_page_token: &str,
         page_size: i64,
     ) -> Result<Vec<crate::types::CallQueues>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/call_queues?{}", query_);
+        let url = crate::Client::url_with_query("/phone/call_queues", &query_args);
 
-        let resp: crate::types::ListCallQueuesResponse = self.client.get(&url, None).await?;
+        let resp: crate::types::ListCallQueuesResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/call_queues", &[]))?;
 
         // Return our response data.
         Ok(resp.call_queues)
     }
 
+    /**
+     * List call queues.
+     *
+     * This function performs a `GET` to the `/phone/call_queues` endpoint.
+     *
+     * As opposed to `list_call_queues`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
+     */
+    pub async fn list_call_queues_page(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<(Vec<crate::types::CallQueues>, String)> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/call_queues", &query_args);
+
+        let resp: crate::types::ListCallQueuesResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/call_queues", &[]))?;
+
+        // Return our response data, and where the next page starts.
+        Ok((resp.call_queues, resp.next_page_token))
+    }
+
     /**
      * List call queues.
      *
@@ -74,7 +119,11 @@ impl PhoneCallQueues {
      */
     pub async fn list_all_call_queues(&self) -> Result<Vec<crate::types::CallQueues>> {
         let url = "/phone/call_queues".to_string();
-        let mut resp: crate::types::ListCallQueuesResponse = self.client.get(&url, None).await?;
+        let mut resp: crate::types::ListCallQueuesResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/call_queues", &[]))?;
 
         let mut call_queues = resp.call_queues;
         let mut page = resp.next_page_token;
@@ -107,6 +156,60 @@ impl PhoneCallQueues {
         Ok(call_queues)
     }
 
+    /**
+     * List call queues.
+     *
+     * This function performs a `GET` to the `/phone/call_queues` endpoint.
+     *
+     * As opposed to `list_call_queues`, this function streams the items of all the pages, getting the next page only once the items of the last one are used up. The stream ends after the last page.
+     */
+    pub async fn list_call_queues_stream(
+        &self,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::CallQueues>>> {
+        let url = "/phone/call_queues".to_string();
+        self.client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListCallQueuesResponse| {
+                    (resp.call_queues, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/call_queues", &[]))
+    }
+
+    /**
+     * List call queues.
+     *
+     * This function performs a `GET` to the `/phone/call_queues` endpoint.
+     *
+     * As opposed to `list_call_queues_stream`, this function streams only the items `predicate` keeps. It too gets the next page only once the items of the last one are used up, so a stream that is cut short, for example with `take`, gets no more pages than it needs.
+     */
+    pub async fn list_call_queues_filter<P: FnMut(&crate::types::CallQueues) -> bool>(
+        &self,
+        mut predicate: P,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::CallQueues>>> {
+        let url = "/phone/call_queues".to_string();
+        let items = self
+            .client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListCallQueuesResponse| {
+                    (resp.call_queues, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/call_queues", &[]))?;
+        Ok(futures::TryStreamExt::try_filter(items, move |item| {
+            futures::future::ready(predicate(item))
+        }))
+    }
+
+    /// The OAuth scopes for `create_call_queue`, any one of them grants access.
+    pub const CREATE_CALL_QUEUE_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Create a call queue.
      *
@@ -129,10 +232,14 @@ impl PhoneCallQueues {
     ) -> Result<crate::types::CreateCallQueueResponse> {
         let url = "/phone/call_queues".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/phone/call_queues", &[]))
     }
 
+    /// The OAuth scopes for `get_call_queue`, any one of them grants access.
+    pub const GET_CALL_QUEUE_SCOPES: &[&str] = &["phone:read:admin"];
+
     /**
      * Get call queue details.
      *
@@ -158,12 +265,21 @@ impl PhoneCallQueues {
     ) -> Result<crate::types::GetCallQueueResponse> {
         let url = format!(
             "/phone/call_queues/{}",
-            crate::progenitor_support::encode_path(&call_queue_id.to_string()),
+            self.client.encode_path(call_queue_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/phone/call_queues/{callQueueId}",
+                &[("callQueueId", call_queue_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `delete_call_queue`, any one of them grants access.
+    pub const DELETE_CALL_QUEUE_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Delete a call queue.
      *
@@ -188,12 +304,46 @@ impl PhoneCallQueues {
     pub async fn delete_call_queue(&self, call_queue_id: &str) -> Result<()> {
         let url = format!(
             "/phone/call_queues/{}",
-            crate::progenitor_support::encode_path(&call_queue_id.to_string()),
+            self.client.encode_path(call_queue_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/phone/call_queues/{callQueueId}",
+                &[("callQueueId", call_queue_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete a call queue.
+     *
+     * This function performs a `DELETE` to the `/phone/call_queues/{callQueueId}` endpoint.
+     *
+     * As opposed to `delete_call_queue`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_call_queue_if_exists(&self, call_queue_id: &str) -> Result<bool> {
+        let url = format!(
+            "/phone/call_queues/{}",
+            self.client.encode_path(call_queue_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/phone/call_queues/{callQueueId}",
+                    &[("callQueueId", call_queue_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `update_call_queue`, any one of them grants access.
+    pub const UPDATE_CALL_QUEUE_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Update call queue details.
      *
@@ -220,14 +370,54 @@ impl PhoneCallQueues {
     ) -> Result<()> {
         let url = format!(
             "/phone/call_queues/{}",
-            crate::progenitor_support::encode_path(&call_queue_id.to_string()),
+            self.client.encode_path(call_queue_id),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/phone/call_queues/{callQueueId}",
+                    &[("callQueueId", call_queue_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update call queue details.
+     *
+     * This function performs a `PATCH` to the `/phone/call_queues/{callQueueId}` endpoint.
+     *
+     * As opposed to `update_call_queue`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn update_call_queue_if_changed<C: serde::Serialize>(
+        &self,
+        call_queue_id: &str,
+        current: &C,
+        body: &crate::types::UpdateCallQueueRequest,
+    ) -> Result<Option<()>> {
+        let url = format!(
+            "/phone/call_queues/{}",
+            self.client.encode_path(call_queue_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/phone/call_queues/{callQueueId}",
+                    &[("callQueueId", call_queue_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `assign`, any one of them grants access.
+    pub const ASSIGN_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Assign numbers to a call queue.
      *
@@ -254,14 +444,24 @@ impl PhoneCallQueues {
     ) -> Result<crate::types::Domains> {
         let url = format!(
             "/phone/call_queues/{}/phone_numbers",
-            crate::progenitor_support::encode_path(&call_queue_id.to_string()),
+            self.client.encode_path(call_queue_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/phone/call_queues/{callQueueId}/phone_numbers",
+                    &[("callQueueId", call_queue_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `unassign_phone_num_call_queue`, any one of them grants access.
+    pub const UNASSIGN_PHONE_NUM_CALL_QUEUE_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Unassign all phone numbers.
      *
@@ -286,12 +486,21 @@ impl PhoneCallQueues {
     ) -> Result<crate::types::Domains> {
         let url = format!(
             "/phone/call_queues/{}/phone_numbers",
-            crate::progenitor_support::encode_path(&call_queue_id.to_string()),
+            self.client.encode_path(call_queue_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/phone/call_queues/{callQueueId}/phone_numbers",
+                &[("callQueueId", call_queue_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `un_assign_phone_num_call_queue`, any one of them grants access.
+    pub const UN_ASSIGN_PHONE_NUM_CALL_QUEUE_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Unassign a phone number.
      *
@@ -318,13 +527,25 @@ impl PhoneCallQueues {
     ) -> Result<crate::types::Domains> {
         let url = format!(
             "/phone/call_queues/{}/phone_numbers/{}",
-            crate::progenitor_support::encode_path(&call_queue_id.to_string()),
-            crate::progenitor_support::encode_path(&phone_number_id.to_string()),
+            self.client.encode_path(call_queue_id),
+            self.client.encode_path(phone_number_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/phone/call_queues/{callQueueId}/phone_numbers/{phoneNumberId}",
+                &[
+                    ("callQueueId", call_queue_id.to_string()),
+                    ("phoneNumberId", phone_number_id.to_string()),
+                ],
+            )
+        })
     }
 
+    /// The OAuth scopes for `add_members_call_queue`, any one of them grants access.
+    pub const ADD_MEMBERS_CALL_QUEUE_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Add members to a call queue.
      *
@@ -350,14 +571,24 @@ impl PhoneCallQueues {
     ) -> Result<crate::types::Domains> {
         let url = format!(
             "/phone/call_queues/{}/members",
-            crate::progenitor_support::encode_path(&call_queue_id.to_string()),
+            self.client.encode_path(call_queue_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/phone/call_queues/{callQueueId}/members",
+                    &[("callQueueId", call_queue_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `unassign_all_members`, any one of them grants access.
+    pub const UNASSIGN_ALL_MEMBERS_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Unassign all members.
      *
@@ -379,12 +610,46 @@ impl PhoneCallQueues {
     pub async fn unassign_all_members(&self, call_queue_id: &str) -> Result<()> {
         let url = format!(
             "/phone/call_queues/{}/members",
-            crate::progenitor_support::encode_path(&call_queue_id.to_string()),
+            self.client.encode_path(call_queue_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/phone/call_queues/{callQueueId}/members",
+                &[("callQueueId", call_queue_id.to_string())],
+            )
+        })
     }
 
+    /**
+     * Unassign all members.
+     *
+     * This function performs a `DELETE` to the `/phone/call_queues/{callQueueId}/members` endpoint.
+     *
+     * As opposed to `unassign_all_members`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn unassign_all_members_if_exists(&self, call_queue_id: &str) -> Result<bool> {
+        let url = format!(
+            "/phone/call_queues/{}/members",
+            self.client.encode_path(call_queue_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/phone/call_queues/{callQueueId}/members",
+                    &[("callQueueId", call_queue_id.to_string())],
+                )
+            })
+    }
+
+    /// The OAuth scopes for `unassign_member_from_call_queue`, any one of them grants access.
+    pub const UNASSIGN_MEMBER_FROM_CALL_QUEUE_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Unassign a member.
      *
@@ -411,13 +676,58 @@ impl PhoneCallQueues {
     ) -> Result<()> {
         let url = format!(
             "/phone/call_queues/{}/members/{}",
-            crate::progenitor_support::encode_path(&call_queue_id.to_string()),
-            crate::progenitor_support::encode_path(&member_id.to_string()),
+            self.client.encode_path(call_queue_id),
+            self.client.encode_path(member_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/phone/call_queues/{callQueueId}/members/{memberId}",
+                &[
+                    ("callQueueId", call_queue_id.to_string()),
+                    ("memberId", member_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Unassign a member.
+     *
+     * This function performs a `DELETE` to the `/phone/call_queues/{callQueueId}/members/{memberId}` endpoint.
+     *
+     * As opposed to `unassign_member_from_call_queue`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn unassign_member_from_call_queue_if_exists(
+        &self,
+        call_queue_id: &str,
+        member_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/phone/call_queues/{}/members/{}",
+            self.client.encode_path(call_queue_id),
+            self.client.encode_path(member_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/phone/call_queues/{callQueueId}/members/{memberId}",
+                    &[
+                        ("callQueueId", call_queue_id.to_string()),
+                        ("memberId", member_id.to_string()),
+                    ],
+                )
+            })
     }
 
+    /// The OAuth scopes for `change_call_queue_manager`, any one of them grants access.
+    pub const CHANGE_CALL_QUEUE_MANAGER_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Change call queue manager.
      *
@@ -445,14 +755,24 @@ impl PhoneCallQueues {
     ) -> Result<()> {
         let url = format!(
             "/phone/call_queues/{}/manager",
-            crate::progenitor_support::encode_path(&call_queue_id.to_string()),
+            self.client.encode_path(call_queue_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/phone/call_queues/{callQueueId}/manager",
+                    &[("callQueueId", call_queue_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `get_call_queue_recordings`, any one of them grants access.
+    pub const GET_CALL_QUEUE_RECORDINGS_SCOPES: &[&str] = &["phone:read:admin"];
+
     /**
      * Get call queue recordings.
      *
@@ -483,33 +803,91 @@ impl PhoneCallQueues {
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> Result<Vec<crate::types::GetCallQueueRecordingsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/phone/call_queues/{}/recordings?{}",
-            crate::progenitor_support::encode_path(&call_queue_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/phone/call_queues/{}/recordings",
+                self.client.encode_path(call_queue_id)
+            ),
+            &query_args,
         );
 
         let resp: crate::types::GetCallQueueRecordingsResponseData =
-            self.client.get(&url, None).await?;
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/phone/call_queues/{callQueueId}/recordings",
+                    &[("callQueueId", call_queue_id.to_string())],
+                )
+            })?;
 
         // Return our response data.
         Ok(resp.recordings)
     }
 
+    /**
+     * Get call queue recordings.
+     *
+     * This function performs a `GET` to the `/phone/call_queues/{callQueueId}/recordings` endpoint.
+     *
+     * As opposed to `get_call_queue_recordings`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
+     */
+    pub async fn get_call_queue_recordings_page(
+        &self,
+        call_queue_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<(Vec<crate::types::GetCallQueueRecordingsResponse>, String)> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from.to_string().is_empty() {
+            query_args.push(("from", from.to_string()));
+        }
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        if !to.to_string().is_empty() {
+            query_args.push(("to", to.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/phone/call_queues/{}/recordings",
+                self.client.encode_path(call_queue_id)
+            ),
+            &query_args,
+        );
+
+        let resp: crate::types::GetCallQueueRecordingsResponseData =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/phone/call_queues/{callQueueId}/recordings",
+                    &[("callQueueId", call_queue_id.to_string())],
+                )
+            })?;
+
+        // Return our response data, and where the next page starts.
+        Ok((resp.recordings, resp.next_page_token))
+    }
+
     /**
      * Get call queue recordings.
      *
@@ -532,22 +910,29 @@ impl PhoneCallQueues {
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> Result<Vec<crate::types::GetCallQueueRecordingsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/phone/call_queues/{}/recordings?{}",
-            crate::progenitor_support::encode_path(&call_queue_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/phone/call_queues/{}/recordings",
+                self.client.encode_path(call_queue_id)
+            ),
+            &query_args,
         );
 
         let mut resp: crate::types::GetCallQueueRecordingsResponseData =
-            self.client.get(&url, None).await?;
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/phone/call_queues/{callQueueId}/recordings",
+                    &[("callQueueId", call_queue_id.to_string())],
+                )
+            })?;
 
         let mut recordings = resp.recordings;
         let mut page = resp.next_page_token;
@@ -579,4 +964,343 @@ impl PhoneCallQueues {
         // Return our response data.
         Ok(recordings)
     }
+
+    /**
+     * Get call queue recordings.
+     *
+     * This function performs a `GET` to the `/phone/call_queues/{callQueueId}/recordings` endpoint.
+     *
+     * As opposed to `get_call_queue_recordings`, this function streams the items of all the pages, getting the next page only once the items of the last one are used up. The stream ends after the last page.
+     */
+    pub async fn get_call_queue_recordings_stream(
+        &self,
+        call_queue_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::GetCallQueueRecordingsResponse>>>
+    {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from.to_string().is_empty() {
+            query_args.push(("from", from.to_string()));
+        }
+        if !to.to_string().is_empty() {
+            query_args.push(("to", to.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/phone/call_queues/{}/recordings",
+                self.client.encode_path(call_queue_id)
+            ),
+            &query_args,
+        );
+
+        self.client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::GetCallQueueRecordingsResponseData| {
+                    (resp.recordings, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/phone/call_queues/{callQueueId}/recordings",
+                    &[("callQueueId", call_queue_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Get call queue recordings.
+     *
+     * This function performs a `GET` to the `/phone/call_queues/{callQueueId}/recordings` endpoint.
+     *
+     * As opposed to `get_call_queue_recordings_stream`, this function streams only the items `predicate` keeps. It too gets the next page only once the items of the last one are used up, so a stream that is cut short, for example with `take`, gets no more pages than it needs.
+     */
+    pub async fn get_call_queue_recordings_filter<
+        P: FnMut(&crate::types::GetCallQueueRecordingsResponse) -> bool,
+    >(
+        &self,
+        call_queue_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        mut predicate: P,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::GetCallQueueRecordingsResponse>>>
+    {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from.to_string().is_empty() {
+            query_args.push(("from", from.to_string()));
+        }
+        if !to.to_string().is_empty() {
+            query_args.push(("to", to.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/phone/call_queues/{}/recordings",
+                self.client.encode_path(call_queue_id)
+            ),
+            &query_args,
+        );
+
+        let items = self
+            .client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::GetCallQueueRecordingsResponseData| {
+                    (resp.recordings, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/phone/call_queues/{callQueueId}/recordings",
+                    &[("callQueueId", call_queue_id.to_string())],
+                )
+            })?;
+        Ok(futures::TryStreamExt::try_filter(items, move |item| {
+            futures::future::ready(predicate(item))
+        }))
+    }
+}
+
+/// The functions of [`PhoneCallQueues`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn PhoneCallQueuesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait PhoneCallQueuesApi: Send + Sync {
+    async fn list_call_queues(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<Vec<crate::types::CallQueues>>;
+    async fn list_call_queues_page(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<(Vec<crate::types::CallQueues>, String)>;
+    async fn list_all_call_queues(&self) -> Result<Vec<crate::types::CallQueues>>;
+    async fn create_call_queue(
+        &self,
+        body: &crate::types::CreateCallQueueRequest,
+    ) -> Result<crate::types::CreateCallQueueResponse>;
+    async fn get_call_queue(
+        &self,
+        call_queue_id: &str,
+    ) -> Result<crate::types::GetCallQueueResponse>;
+    async fn delete_call_queue(&self, call_queue_id: &str) -> Result<()>;
+    async fn delete_call_queue_if_exists(&self, call_queue_id: &str) -> Result<bool>;
+    async fn update_call_queue(
+        &self,
+        call_queue_id: &str,
+        body: &crate::types::UpdateCallQueueRequest,
+    ) -> Result<()>;
+    async fn assign(
+        &self,
+        call_queue_id: &str,
+        body: &crate::types::AddByocNumberResponse,
+    ) -> Result<crate::types::Domains>;
+    async fn unassign_phone_num_call_queue(
+        &self,
+        call_queue_id: &str,
+    ) -> Result<crate::types::Domains>;
+    async fn un_assign_phone_num_call_queue(
+        &self,
+        call_queue_id: &str,
+        phone_number_id: &str,
+    ) -> Result<crate::types::Domains>;
+    async fn add_members_call_queue(
+        &self,
+        call_queue_id: &str,
+        body: &crate::types::AddMembersCallQueueRequestData,
+    ) -> Result<crate::types::Domains>;
+    async fn unassign_all_members(&self, call_queue_id: &str) -> Result<()>;
+    async fn unassign_all_members_if_exists(&self, call_queue_id: &str) -> Result<bool>;
+    async fn unassign_member_from_call_queue(
+        &self,
+        call_queue_id: &str,
+        member_id: &str,
+    ) -> Result<()>;
+    async fn unassign_member_from_call_queue_if_exists(
+        &self,
+        call_queue_id: &str,
+        member_id: &str,
+    ) -> Result<bool>;
+    async fn change_call_queue_manager(
+        &self,
+        call_queue_id: &str,
+        body: &crate::types::ChangeCallQueueManagerRequest,
+    ) -> Result<()>;
+    async fn get_call_queue_recordings(
+        &self,
+        call_queue_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<Vec<crate::types::GetCallQueueRecordingsResponse>>;
+    async fn get_call_queue_recordings_page(
+        &self,
+        call_queue_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<(Vec<crate::types::GetCallQueueRecordingsResponse>, String)>;
+    async fn get_all_call_queue_recordings(
+        &self,
+        call_queue_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<Vec<crate::types::GetCallQueueRecordingsResponse>>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl PhoneCallQueuesApi for PhoneCallQueues {
+    async fn list_call_queues(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<Vec<crate::types::CallQueues>> {
+        PhoneCallQueues::list_call_queues(self, next_page_token, page_size).await
+    }
+    async fn list_call_queues_page(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<(Vec<crate::types::CallQueues>, String)> {
+        PhoneCallQueues::list_call_queues_page(self, next_page_token, page_size).await
+    }
+    async fn list_all_call_queues(&self) -> Result<Vec<crate::types::CallQueues>> {
+        PhoneCallQueues::list_all_call_queues(self).await
+    }
+    async fn create_call_queue(
+        &self,
+        body: &crate::types::CreateCallQueueRequest,
+    ) -> Result<crate::types::CreateCallQueueResponse> {
+        PhoneCallQueues::create_call_queue(self, body).await
+    }
+    async fn get_call_queue(
+        &self,
+        call_queue_id: &str,
+    ) -> Result<crate::types::GetCallQueueResponse> {
+        PhoneCallQueues::get_call_queue(self, call_queue_id).await
+    }
+    async fn delete_call_queue(&self, call_queue_id: &str) -> Result<()> {
+        PhoneCallQueues::delete_call_queue(self, call_queue_id).await
+    }
+    async fn delete_call_queue_if_exists(&self, call_queue_id: &str) -> Result<bool> {
+        PhoneCallQueues::delete_call_queue_if_exists(self, call_queue_id).await
+    }
+    async fn update_call_queue(
+        &self,
+        call_queue_id: &str,
+        body: &crate::types::UpdateCallQueueRequest,
+    ) -> Result<()> {
+        PhoneCallQueues::update_call_queue(self, call_queue_id, body).await
+    }
+    async fn assign(
+        &self,
+        call_queue_id: &str,
+        body: &crate::types::AddByocNumberResponse,
+    ) -> Result<crate::types::Domains> {
+        PhoneCallQueues::assign(self, call_queue_id, body).await
+    }
+    async fn unassign_phone_num_call_queue(
+        &self,
+        call_queue_id: &str,
+    ) -> Result<crate::types::Domains> {
+        PhoneCallQueues::unassign_phone_num_call_queue(self, call_queue_id).await
+    }
+    async fn un_assign_phone_num_call_queue(
+        &self,
+        call_queue_id: &str,
+        phone_number_id: &str,
+    ) -> Result<crate::types::Domains> {
+        PhoneCallQueues::un_assign_phone_num_call_queue(self, call_queue_id, phone_number_id).await
+    }
+    async fn add_members_call_queue(
+        &self,
+        call_queue_id: &str,
+        body: &crate::types::AddMembersCallQueueRequestData,
+    ) -> Result<crate::types::Domains> {
+        PhoneCallQueues::add_members_call_queue(self, call_queue_id, body).await
+    }
+    async fn unassign_all_members(&self, call_queue_id: &str) -> Result<()> {
+        PhoneCallQueues::unassign_all_members(self, call_queue_id).await
+    }
+    async fn unassign_all_members_if_exists(&self, call_queue_id: &str) -> Result<bool> {
+        PhoneCallQueues::unassign_all_members_if_exists(self, call_queue_id).await
+    }
+    async fn unassign_member_from_call_queue(
+        &self,
+        call_queue_id: &str,
+        member_id: &str,
+    ) -> Result<()> {
+        PhoneCallQueues::unassign_member_from_call_queue(self, call_queue_id, member_id).await
+    }
+    async fn unassign_member_from_call_queue_if_exists(
+        &self,
+        call_queue_id: &str,
+        member_id: &str,
+    ) -> Result<bool> {
+        PhoneCallQueues::unassign_member_from_call_queue_if_exists(self, call_queue_id, member_id)
+            .await
+    }
+    async fn change_call_queue_manager(
+        &self,
+        call_queue_id: &str,
+        body: &crate::types::ChangeCallQueueManagerRequest,
+    ) -> Result<()> {
+        PhoneCallQueues::change_call_queue_manager(self, call_queue_id, body).await
+    }
+    async fn get_call_queue_recordings(
+        &self,
+        call_queue_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<Vec<crate::types::GetCallQueueRecordingsResponse>> {
+        PhoneCallQueues::get_call_queue_recordings(
+            self,
+            call_queue_id,
+            page_size,
+            next_page_token,
+            from,
+            to,
+        )
+        .await
+    }
+    async fn get_call_queue_recordings_page(
+        &self,
+        call_queue_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<(Vec<crate::types::GetCallQueueRecordingsResponse>, String)> {
+        PhoneCallQueues::get_call_queue_recordings_page(
+            self,
+            call_queue_id,
+            page_size,
+            next_page_token,
+            from,
+            to,
+        )
+        .await
+    }
+    async fn get_all_call_queue_recordings(
+        &self,
+        call_queue_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<Vec<crate::types::GetCallQueueRecordingsResponse>> {
+        PhoneCallQueues::get_all_call_queue_recordings(self, call_queue_id, from, to).await
+    }
 }
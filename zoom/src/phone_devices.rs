@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct PhoneDevices {
     pub client: Client,
@@ -12,6 +10,17 @@ impl PhoneDevices {
         PhoneDevices { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        PhoneDevices {
+            client: self.client.with_retry(policy),
+        }
+    }
+
+    /// The OAuth scopes for `list`, any one of them grants access.
+    pub const LIST_SCOPES: &[&str] = &["phone:read:admin"];
+
     /**
      * List devices.
      *
@@ -37,25 +46,65 @@ impl PhoneDevices {
         next_page_token: &str,
         page_size: i64,
     ) -> Result<Vec<crate::types::ListPhoneDevicesResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/devices?{}", query_);
+        let url = crate::Client::url_with_query("/phone/devices", &query_args);
 
-        let resp: crate::types::ListPhoneDevicesResponseData = self.client.get(&url, None).await?;
+        let resp: crate::types::ListPhoneDevicesResponseData = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/devices", &[]))?;
 
         // Return our response data.
         Ok(resp.devices)
     }
 
+    /**
+     * List devices.
+     *
+     * This function performs a `GET` to the `/phone/devices` endpoint.
+     *
+     * As opposed to `list`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
+     */
+    pub async fn list_page(
+        &self,
+        type_: crate::types::ListPhoneDevicesType,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<(Vec<crate::types::ListPhoneDevicesResponse>, String)> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        if !type_.to_string().is_empty() {
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/devices", &query_args);
+
+        let resp: crate::types::ListPhoneDevicesResponseData = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/devices", &[]))?;
+
+        // Return our response data, and where the next page starts.
+        Ok((resp.devices, resp.next_page_token))
+    }
+
     /**
      * List devices.
      *
@@ -75,15 +124,17 @@ impl PhoneDevices {
         &self,
         type_: crate::types::ListPhoneDevicesType,
     ) -> Result<Vec<crate::types::ListPhoneDevicesResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/devices?{}", query_);
+        let url = crate::Client::url_with_query("/phone/devices", &query_args);
 
-        let mut resp: crate::types::ListPhoneDevicesResponseData =
-            self.client.get(&url, None).await?;
+        let mut resp: crate::types::ListPhoneDevicesResponseData = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/devices", &[]))?;
 
         let mut devices = resp.devices;
         let mut page = resp.next_page_token;
@@ -116,6 +167,72 @@ impl PhoneDevices {
         Ok(devices)
     }
 
+    /**
+     * List devices.
+     *
+     * This function performs a `GET` to the `/phone/devices` endpoint.
+     *
+     * As opposed to `list`, this function streams the items of all the pages, getting the next page only once the items of the last one are used up. The stream ends after the last page.
+     */
+    pub async fn list_stream(
+        &self,
+        type_: crate::types::ListPhoneDevicesType,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::ListPhoneDevicesResponse>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !type_.to_string().is_empty() {
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/devices", &query_args);
+
+        self.client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListPhoneDevicesResponseData| {
+                    (resp.devices, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/devices", &[]))
+    }
+
+    /**
+     * List devices.
+     *
+     * This function performs a `GET` to the `/phone/devices` endpoint.
+     *
+     * As opposed to `list_stream`, this function streams only the items `predicate` keeps. It too gets the next page only once the items of the last one are used up, so a stream that is cut short, for example with `take`, gets no more pages than it needs.
+     */
+    pub async fn list_filter<P: FnMut(&crate::types::ListPhoneDevicesResponse) -> bool>(
+        &self,
+        type_: crate::types::ListPhoneDevicesType,
+        mut predicate: P,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::ListPhoneDevicesResponse>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !type_.to_string().is_empty() {
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/devices", &query_args);
+
+        let items = self
+            .client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListPhoneDevicesResponseData| {
+                    (resp.devices, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/devices", &[]))?;
+        Ok(futures::TryStreamExt::try_filter(items, move |item| {
+            futures::future::ready(predicate(item))
+        }))
+    }
+
+    /// The OAuth scopes for `add`, any one of them grants access.
+    pub const ADD_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Add a device.
      *
@@ -135,10 +252,14 @@ impl PhoneDevices {
     pub async fn add(&self, body: &crate::types::AddPhoneDeviceRequest) -> Result<()> {
         let url = "/phone/devices".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/phone/devices", &[]))
     }
 
+    /// The OAuth scopes for `get_device`, any one of them grants access.
+    pub const GET_DEVICE_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Get device details.
      *
@@ -157,14 +278,20 @@ impl PhoneDevices {
      * * `device_id: &str` -- Unique Identifier of the device.
      */
     pub async fn get_device(&self, device_id: &str) -> Result<crate::types::GetDeviceResponse> {
-        let url = format!(
-            "/phone/devices/{}",
-            crate::progenitor_support::encode_path(&device_id.to_string()),
-        );
+        let url = format!("/phone/devices/{}", self.client.encode_path(device_id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/phone/devices/{deviceId}",
+                &[("deviceId", device_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `delete_device`, any one of them grants access.
+    pub const DELETE_DEVICE_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Delete a device.
      *
@@ -185,14 +312,42 @@ impl PhoneDevices {
      * * `device_id: &str` -- Unique Identifier of the device.
      */
     pub async fn delete_device(&self, device_id: &str) -> Result<()> {
-        let url = format!(
-            "/phone/devices/{}",
-            crate::progenitor_support::encode_path(&device_id.to_string()),
-        );
+        let url = format!("/phone/devices/{}", self.client.encode_path(device_id),);
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/phone/devices/{deviceId}",
+                &[("deviceId", device_id.to_string())],
+            )
+        })
     }
 
+    /**
+     * Delete a device.
+     *
+     * This function performs a `DELETE` to the `/phone/devices/{deviceId}` endpoint.
+     *
+     * As opposed to `delete_device`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_device_if_exists(&self, device_id: &str) -> Result<bool> {
+        let url = format!("/phone/devices/{}", self.client.encode_path(device_id),);
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/phone/devices/{deviceId}",
+                    &[("deviceId", device_id.to_string())],
+                )
+            })
+    }
+
+    /// The OAuth scopes for `update_device`, any one of them grants access.
+    pub const UPDATE_DEVICE_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Update a device.
      *
@@ -216,13 +371,123 @@ impl PhoneDevices {
         device_id: &str,
         body: &crate::types::UpdateDeviceRequest,
     ) -> Result<()> {
-        let url = format!(
-            "/phone/devices/{}",
-            crate::progenitor_support::encode_path(&device_id.to_string()),
-        );
+        let url = format!("/phone/devices/{}", self.client.encode_path(device_id),);
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/phone/devices/{deviceId}",
+                    &[("deviceId", device_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update a device.
+     *
+     * This function performs a `PATCH` to the `/phone/devices/{deviceId}` endpoint.
+     *
+     * As opposed to `update_device`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn update_device_if_changed<C: serde::Serialize>(
+        &self,
+        device_id: &str,
+        current: &C,
+        body: &crate::types::UpdateDeviceRequest,
+    ) -> Result<Option<()>> {
+        let url = format!("/phone/devices/{}", self.client.encode_path(device_id),);
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/phone/devices/{deviceId}",
+                    &[("deviceId", device_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`PhoneDevices`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn PhoneDevicesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait PhoneDevicesApi: Send + Sync {
+    async fn list(
+        &self,
+        type_: crate::types::ListPhoneDevicesType,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<Vec<crate::types::ListPhoneDevicesResponse>>;
+    async fn list_page(
+        &self,
+        type_: crate::types::ListPhoneDevicesType,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<(Vec<crate::types::ListPhoneDevicesResponse>, String)>;
+    async fn list_all(
+        &self,
+        type_: crate::types::ListPhoneDevicesType,
+    ) -> Result<Vec<crate::types::ListPhoneDevicesResponse>>;
+    async fn add(&self, body: &crate::types::AddPhoneDeviceRequest) -> Result<()>;
+    async fn get_device(&self, device_id: &str) -> Result<crate::types::GetDeviceResponse>;
+    async fn delete_device(&self, device_id: &str) -> Result<()>;
+    async fn delete_device_if_exists(&self, device_id: &str) -> Result<bool>;
+    async fn update_device(
+        &self,
+        device_id: &str,
+        body: &crate::types::UpdateDeviceRequest,
+    ) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl PhoneDevicesApi for PhoneDevices {
+    async fn list(
+        &self,
+        type_: crate::types::ListPhoneDevicesType,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<Vec<crate::types::ListPhoneDevicesResponse>> {
+        PhoneDevices::list(self, type_, next_page_token, page_size).await
+    }
+    async fn list_page(
+        &self,
+        type_: crate::types::ListPhoneDevicesType,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<(Vec<crate::types::ListPhoneDevicesResponse>, String)> {
+        PhoneDevices::list_page(self, type_, next_page_token, page_size).await
+    }
+    async fn list_all(
+        &self,
+        type_: crate::types::ListPhoneDevicesType,
+    ) -> Result<Vec<crate::types::ListPhoneDevicesResponse>> {
+        PhoneDevices::list_all(self, type_).await
+    }
+    async fn add(&self, body: &crate::types::AddPhoneDeviceRequest) -> Result<()> {
+        PhoneDevices::add(self, body).await
+    }
+    async fn get_device(&self, device_id: &str) -> Result<crate::types::GetDeviceResponse> {
+        PhoneDevices::get_device(self, device_id).await
+    }
+    async fn delete_device(&self, device_id: &str) -> Result<()> {
+        PhoneDevices::delete_device(self, device_id).await
+    }
+    async fn delete_device_if_exists(&self, device_id: &str) -> Result<bool> {
+        PhoneDevices::delete_device_if_exists(self, device_id).await
+    }
+    async fn update_device(
+        &self,
+        device_id: &str,
+        body: &crate::types::UpdateDeviceRequest,
+    ) -> Result<()> {
+        PhoneDevices::update_device(self, device_id, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct PhoneReports {
     pub client: Client,
@@ -12,6 +10,17 @@ impl PhoneReports {
         PhoneReports { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        PhoneReports {
+            client: self.client.with_retry(policy),
+        }
+    }
+
+    /// The OAuth scopes for `get_ps_operation_log`, any one of them grants access.
+    pub const GET_PS_OPERATION_LOG_SCOPES: &[&str] = &["phone:read:admin", "phone:write:admin"];
+
     /**
      * Get operation logs report.
      *
@@ -42,25 +51,135 @@ impl PhoneReports {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<crate::types::ReportOperationLogsResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !category_type.is_empty() {
+            query_args.push(("category_type", category_type.to_string()));
+        }
+        if !from.is_empty() {
+            query_args.push(("from", from.to_string()));
+        }
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        if !to.is_empty() {
+            query_args.push(("to", to.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/reports/operationlogs", &query_args);
+
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/reports/operationlogs", &[]))
+    }
+
+    /**
+     * Get operation logs report.
+     *
+     * This function performs a `GET` to the `/phone/reports/operationlogs` endpoint.
+     *
+     * As opposed to `get_ps_operation_log`, this function takes `from` and `to` as times, and sends them in UTC like `2021-08-15T01:52:41Z`.
+     */
+    pub async fn get_ps_operation_log_between(
+        &self,
+        from: chrono::DateTime<chrono::Utc>,
+        to: chrono::DateTime<chrono::Utc>,
+        category_type: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::ReportOperationLogsResponseAllOf> {
+        let from = crate::utils::date_time_param(&from);
+        let to = crate::utils::date_time_param(&to);
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !category_type.is_empty() {
-            query_args.push(("category_type".to_string(), category_type.to_string()));
+            query_args.push(("category_type", category_type.to_string()));
         }
         if !from.is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !to.is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/reports/operationlogs?{}", query_);
+        let url = crate::Client::url_with_query("/phone/reports/operationlogs", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/reports/operationlogs", &[]))
+    }
+}
+
+/// The functions of [`PhoneReports`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn PhoneReportsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait PhoneReportsApi: Send + Sync {
+    async fn get_ps_operation_log(
+        &self,
+        from: &str,
+        to: &str,
+        category_type: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::ReportOperationLogsResponseAllOf>;
+    async fn get_ps_operation_log_between(
+        &self,
+        from: chrono::DateTime<chrono::Utc>,
+        to: chrono::DateTime<chrono::Utc>,
+        category_type: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::ReportOperationLogsResponseAllOf>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl PhoneReportsApi for PhoneReports {
+    async fn get_ps_operation_log(
+        &self,
+        from: &str,
+        to: &str,
+        category_type: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::ReportOperationLogsResponseAllOf> {
+        PhoneReports::get_ps_operation_log(
+            self,
+            from,
+            to,
+            category_type,
+            page_size,
+            next_page_token,
+        )
+        .await
+    }
+    async fn get_ps_operation_log_between(
+        &self,
+        from: chrono::DateTime<chrono::Utc>,
+        to: chrono::DateTime<chrono::Utc>,
+        category_type: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::ReportOperationLogsResponseAllOf> {
+        PhoneReports::get_ps_operation_log_between(
+            self,
+            from,
+            to,
+            category_type,
+            page_size,
+            next_page_token,
+        )
+        .await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct PhoneSharedLineGroups {
     pub client: Client,
@@ -12,6 +10,17 @@ impl PhoneSharedLineGroups {
         PhoneSharedLineGroups { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        PhoneSharedLineGroups {
+            client: self.client.with_retry(policy),
+        }
+    }
+
+    /// The OAuth scopes for `list_shared_line_groups`, any one of them grants access.
+    pub const LIST_SHARED_LINE_GROUPS_SCOPES: &[&str] = &["phone:read:admin", "phone:write:admin"];
+
     /**
      * List shared line groups.
      *
@@ -38,22 +47,56 @@ impl PhoneSharedLineGroups {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<Vec<crate::types::SharedLineGroups>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/shared_line_groups?{}", query_);
+        let url = crate::Client::url_with_query("/phone/shared_line_groups", &query_args);
 
-        let resp: crate::types::ListSharedLineGroupsResponse = self.client.get(&url, None).await?;
+        let resp: crate::types::ListSharedLineGroupsResponse =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url("GET", "/phone/shared_line_groups", &[])
+            })?;
 
         // Return our response data.
         Ok(resp.shared_line_groups)
     }
 
+    /**
+     * List shared line groups.
+     *
+     * This function performs a `GET` to the `/phone/shared_line_groups` endpoint.
+     *
+     * As opposed to `list_shared_line_groups`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
+     */
+    pub async fn list_shared_line_groups_page(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::SharedLineGroups>, String)> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/shared_line_groups", &query_args);
+
+        let resp: crate::types::ListSharedLineGroupsResponse =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url("GET", "/phone/shared_line_groups", &[])
+            })?;
+
+        // Return our response data, and where the next page starts.
+        Ok((resp.shared_line_groups, resp.next_page_token))
+    }
+
     /**
      * List shared line groups.
      *
@@ -75,7 +118,9 @@ impl PhoneSharedLineGroups {
     pub async fn list_all_shared_line_groups(&self) -> Result<Vec<crate::types::SharedLineGroups>> {
         let url = "/phone/shared_line_groups".to_string();
         let mut resp: crate::types::ListSharedLineGroupsResponse =
-            self.client.get(&url, None).await?;
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url("GET", "/phone/shared_line_groups", &[])
+            })?;
 
         let mut shared_line_groups = resp.shared_line_groups;
         let mut page = resp.next_page_token;
@@ -108,6 +153,62 @@ impl PhoneSharedLineGroups {
         Ok(shared_line_groups)
     }
 
+    /**
+     * List shared line groups.
+     *
+     * This function performs a `GET` to the `/phone/shared_line_groups` endpoint.
+     *
+     * As opposed to `list_shared_line_groups`, this function streams the items of all the pages, getting the next page only once the items of the last one are used up. The stream ends after the last page.
+     */
+    pub async fn list_shared_line_groups_stream(
+        &self,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::SharedLineGroups>>> {
+        let url = "/phone/shared_line_groups".to_string();
+        self.client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListSharedLineGroupsResponse| {
+                    (resp.shared_line_groups, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/shared_line_groups", &[]))
+    }
+
+    /**
+     * List shared line groups.
+     *
+     * This function performs a `GET` to the `/phone/shared_line_groups` endpoint.
+     *
+     * As opposed to `list_shared_line_groups_stream`, this function streams only the items `predicate` keeps. It too gets the next page only once the items of the last one are used up, so a stream that is cut short, for example with `take`, gets no more pages than it needs.
+     */
+    pub async fn list_shared_line_groups_filter<
+        P: FnMut(&crate::types::SharedLineGroups) -> bool,
+    >(
+        &self,
+        mut predicate: P,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::SharedLineGroups>>> {
+        let url = "/phone/shared_line_groups".to_string();
+        let items = self
+            .client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListSharedLineGroupsResponse| {
+                    (resp.shared_line_groups, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/shared_line_groups", &[]))?;
+        Ok(futures::TryStreamExt::try_filter(items, move |item| {
+            futures::future::ready(predicate(item))
+        }))
+    }
+
+    /// The OAuth scopes for `create_shared_line_group`, any one of them grants access.
+    pub const CREATE_SHARED_LINE_GROUP_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Create a shared line group.
      *
@@ -129,10 +230,14 @@ impl PhoneSharedLineGroups {
     ) -> Result<()> {
         let url = "/phone/shared_line_groups".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/phone/shared_line_groups", &[]))
     }
 
+    /// The OAuth scopes for `get_shared_line_group`, any one of them grants access.
+    pub const GET_SHARED_LINE_GROUP_SCOPES: &[&str] = &["phone:read:admin", "phone:write:admin"];
+
     /**
      * Get a shared line group.
      *
@@ -159,12 +264,21 @@ impl PhoneSharedLineGroups {
     ) -> Result<crate::types::GetSharedLineGroupResponse> {
         let url = format!(
             "/phone/shared_line_groups/{}",
-            crate::progenitor_support::encode_path(&shared_line_group_id.to_string()),
+            self.client.encode_path(shared_line_group_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/phone/shared_line_groups/{sharedLineGroupId}",
+                &[("sharedLineGroupId", shared_line_group_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `delete_shared_line_group`, any one of them grants access.
+    pub const DELETE_SHARED_LINE_GROUP_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Delete a shared line group.
      *
@@ -187,12 +301,49 @@ impl PhoneSharedLineGroups {
     pub async fn delete_shared_line_group(&self, shared_line_group_id: &str) -> Result<()> {
         let url = format!(
             "/phone/shared_line_groups/{}",
-            crate::progenitor_support::encode_path(&shared_line_group_id.to_string()),
+            self.client.encode_path(shared_line_group_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/phone/shared_line_groups/{sharedLineGroupId}",
+                &[("sharedLineGroupId", shared_line_group_id.to_string())],
+            )
+        })
     }
 
+    /**
+     * Delete a shared line group.
+     *
+     * This function performs a `DELETE` to the `/phone/shared_line_groups/{sharedLineGroupId}` endpoint.
+     *
+     * As opposed to `delete_shared_line_group`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_shared_line_group_if_exists(
+        &self,
+        shared_line_group_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/phone/shared_line_groups/{}",
+            self.client.encode_path(shared_line_group_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/phone/shared_line_groups/{sharedLineGroupId}",
+                    &[("sharedLineGroupId", shared_line_group_id.to_string())],
+                )
+            })
+    }
+
+    /// The OAuth scopes for `update_shared_line_group`, any one of them grants access.
+    pub const UPDATE_SHARED_LINE_GROUP_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Update a shared line group.
      *
@@ -218,14 +369,54 @@ impl PhoneSharedLineGroups {
     ) -> Result<()> {
         let url = format!(
             "/phone/shared_line_groups/{}",
-            crate::progenitor_support::encode_path(&shared_line_group_id.to_string()),
+            self.client.encode_path(shared_line_group_id),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/phone/shared_line_groups/{sharedLineGroupId}",
+                    &[("sharedLineGroupId", shared_line_group_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update a shared line group.
+     *
+     * This function performs a `PATCH` to the `/phone/shared_line_groups/{sharedLineGroupId}` endpoint.
+     *
+     * As opposed to `update_shared_line_group`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn update_shared_line_group_if_changed<C: serde::Serialize>(
+        &self,
+        shared_line_group_id: &str,
+        current: &C,
+        body: &crate::types::UpdateSharedLineGroupRequest,
+    ) -> Result<Option<()>> {
+        let url = format!(
+            "/phone/shared_line_groups/{}",
+            self.client.encode_path(shared_line_group_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/phone/shared_line_groups/{sharedLineGroupId}",
+                    &[("sharedLineGroupId", shared_line_group_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `add_members_shared_line_group`, any one of them grants access.
+    pub const ADD_MEMBERS_SHARED_LINE_GROUP_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Add members to a shared line group.
      *
@@ -254,14 +445,24 @@ impl PhoneSharedLineGroups {
     ) -> Result<()> {
         let url = format!(
             "/phone/shared_line_groups/{}/members",
-            crate::progenitor_support::encode_path(&shared_line_group_id.to_string()),
+            self.client.encode_path(shared_line_group_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/phone/shared_line_groups/{sharedLineGroupId}/members",
+                    &[("sharedLineGroupId", shared_line_group_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `delete_members_of_slg`, any one of them grants access.
+    pub const DELETE_MEMBERS_OF_SLG_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Unassign members of a shared line group.
      *
@@ -285,12 +486,49 @@ impl PhoneSharedLineGroups {
     pub async fn delete_members_of_slg(&self, shared_line_group_id: &str) -> Result<()> {
         let url = format!(
             "/phone/shared_line_groups/{}/members",
-            crate::progenitor_support::encode_path(&shared_line_group_id.to_string()),
+            self.client.encode_path(shared_line_group_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/phone/shared_line_groups/{sharedLineGroupId}/members",
+                &[("sharedLineGroupId", shared_line_group_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Unassign members of a shared line group.
+     *
+     * This function performs a `DELETE` to the `/phone/shared_line_groups/{sharedLineGroupId}/members` endpoint.
+     *
+     * As opposed to `delete_members_of_slg`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_members_of_slg_if_exists(
+        &self,
+        shared_line_group_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/phone/shared_line_groups/{}/members",
+            self.client.encode_path(shared_line_group_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/phone/shared_line_groups/{sharedLineGroupId}/members",
+                    &[("sharedLineGroupId", shared_line_group_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `delete_member_slg`, any one of them grants access.
+    pub const DELETE_MEMBER_SLG_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Unassign a member from a shared line group.
      *
@@ -319,13 +557,58 @@ impl PhoneSharedLineGroups {
     ) -> Result<()> {
         let url = format!(
             "/phone/shared_line_groups/{}/members/{}",
-            crate::progenitor_support::encode_path(&shared_line_group_id.to_string()),
-            crate::progenitor_support::encode_path(&member_id.to_string()),
+            self.client.encode_path(shared_line_group_id),
+            self.client.encode_path(member_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/phone/shared_line_groups/{sharedLineGroupId}/members/{memberId}",
+                &[
+                    ("sharedLineGroupId", shared_line_group_id.to_string()),
+                    ("memberId", member_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Unassign a member from a shared line group.
+     *
+     * This function performs a `DELETE` to the `/phone/shared_line_groups/{sharedLineGroupId}/members/{memberId}` endpoint.
+     *
+     * As opposed to `delete_member_slg`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_member_slg_if_exists(
+        &self,
+        shared_line_group_id: &str,
+        member_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/phone/shared_line_groups/{}/members/{}",
+            self.client.encode_path(shared_line_group_id),
+            self.client.encode_path(member_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/phone/shared_line_groups/{sharedLineGroupId}/members/{memberId}",
+                    &[
+                        ("sharedLineGroupId", shared_line_group_id.to_string()),
+                        ("memberId", member_id.to_string()),
+                    ],
+                )
+            })
     }
 
+    /// The OAuth scopes for `assign_phone_numbers_slg`, any one of them grants access.
+    pub const ASSIGN_PHONE_NUMBERS_SLG_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Assign phone numbers.
      *
@@ -351,14 +634,24 @@ impl PhoneSharedLineGroups {
     ) -> Result<()> {
         let url = format!(
             "/phone/shared_line_groups/{}/phone_numbers",
-            crate::progenitor_support::encode_path(&shared_line_group_id.to_string()),
+            self.client.encode_path(shared_line_group_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/phone/shared_line_groups/{sharedLineGroupId}/phone_numbers",
+                    &[("sharedLineGroupId", shared_line_group_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `delete_phone_numbers_slg`, any one of them grants access.
+    pub const DELETE_PHONE_NUMBERS_SLG_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Unassign all phone numbers.
      *
@@ -380,12 +673,49 @@ impl PhoneSharedLineGroups {
     pub async fn delete_phone_numbers_slg(&self, shared_line_group_id: &str) -> Result<()> {
         let url = format!(
             "/phone/shared_line_groups/{}/phone_numbers",
-            crate::progenitor_support::encode_path(&shared_line_group_id.to_string()),
+            self.client.encode_path(shared_line_group_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/phone/shared_line_groups/{sharedLineGroupId}/phone_numbers",
+                &[("sharedLineGroupId", shared_line_group_id.to_string())],
+            )
+        })
     }
 
+    /**
+     * Unassign all phone numbers.
+     *
+     * This function performs a `DELETE` to the `/phone/shared_line_groups/{sharedLineGroupId}/phone_numbers` endpoint.
+     *
+     * As opposed to `delete_phone_numbers_slg`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_phone_numbers_slg_if_exists(
+        &self,
+        shared_line_group_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/phone/shared_line_groups/{}/phone_numbers",
+            self.client.encode_path(shared_line_group_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/phone/shared_line_groups/{sharedLineGroupId}/phone_numbers",
+                    &[("sharedLineGroupId", shared_line_group_id.to_string())],
+                )
+            })
+    }
+
+    /// The OAuth scopes for `delete_phone_number_slg`, any one of them grants access.
+    pub const DELETE_PHONE_NUMBER_SLG_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Unassign a phone number.
      *
@@ -412,10 +742,220 @@ impl PhoneSharedLineGroups {
     ) -> Result<()> {
         let url = format!(
             "/phone/shared_line_groups/{}/phone_numbers/{}",
-            crate::progenitor_support::encode_path(&shared_line_group_id.to_string()),
-            crate::progenitor_support::encode_path(&phone_number_id.to_string()),
+            self.client.encode_path(shared_line_group_id),
+            self.client.encode_path(phone_number_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/phone/shared_line_groups/{sharedLineGroupId}/phone_numbers/{phoneNumberId}",
+                &[
+                    ("sharedLineGroupId", shared_line_group_id.to_string()),
+                    ("phoneNumberId", phone_number_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Unassign a phone number.
+     *
+     * This function performs a `DELETE` to the `/phone/shared_line_groups/{sharedLineGroupId}/phone_numbers/{phoneNumberId}` endpoint.
+     *
+     * As opposed to `delete_phone_number_slg`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_phone_number_slg_if_exists(
+        &self,
+        shared_line_group_id: &str,
+        phone_number_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/phone/shared_line_groups/{}/phone_numbers/{}",
+            self.client.encode_path(shared_line_group_id),
+            self.client.encode_path(phone_number_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/phone/shared_line_groups/{sharedLineGroupId}/phone_numbers/{phoneNumberId}",
+                    &[
+                        ("sharedLineGroupId", shared_line_group_id.to_string()),
+                        ("phoneNumberId", phone_number_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`PhoneSharedLineGroups`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn PhoneSharedLineGroupsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait PhoneSharedLineGroupsApi: Send + Sync {
+    async fn list_shared_line_groups(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::SharedLineGroups>>;
+    async fn list_shared_line_groups_page(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::SharedLineGroups>, String)>;
+    async fn list_all_shared_line_groups(&self) -> Result<Vec<crate::types::SharedLineGroups>>;
+    async fn create_shared_line_group(
+        &self,
+        body: &crate::types::CreateSharedLineGroupRequest,
+    ) -> Result<()>;
+    async fn get_shared_line_group(
+        &self,
+        shared_line_group_id: &str,
+    ) -> Result<crate::types::GetSharedLineGroupResponse>;
+    async fn delete_shared_line_group(&self, shared_line_group_id: &str) -> Result<()>;
+    async fn delete_shared_line_group_if_exists(&self, shared_line_group_id: &str) -> Result<bool>;
+    async fn update_shared_line_group(
+        &self,
+        shared_line_group_id: &str,
+        body: &crate::types::UpdateSharedLineGroupRequest,
+    ) -> Result<()>;
+    async fn add_members_shared_line_group(
+        &self,
+        shared_line_group_id: &str,
+        body: &crate::types::AddMembersSharedLineGroupRequestData,
+    ) -> Result<()>;
+    async fn delete_members_of_slg(&self, shared_line_group_id: &str) -> Result<()>;
+    async fn delete_members_of_slg_if_exists(&self, shared_line_group_id: &str) -> Result<bool>;
+    async fn delete_member_slg(&self, shared_line_group_id: &str, member_id: &str) -> Result<()>;
+    async fn delete_member_slg_if_exists(
+        &self,
+        shared_line_group_id: &str,
+        member_id: &str,
+    ) -> Result<bool>;
+    async fn assign_phone_numbers_slg(
+        &self,
+        shared_line_group_id: &str,
+        body: &crate::types::AddByocNumberResponse,
+    ) -> Result<()>;
+    async fn delete_phone_numbers_slg(&self, shared_line_group_id: &str) -> Result<()>;
+    async fn delete_phone_numbers_slg_if_exists(&self, shared_line_group_id: &str) -> Result<bool>;
+    async fn delete_phone_number_slg(
+        &self,
+        shared_line_group_id: &str,
+        phone_number_id: &str,
+    ) -> Result<()>;
+    async fn delete_phone_number_slg_if_exists(
+        &self,
+        shared_line_group_id: &str,
+        phone_number_id: &str,
+    ) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl PhoneSharedLineGroupsApi for PhoneSharedLineGroups {
+    async fn list_shared_line_groups(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::SharedLineGroups>> {
+        PhoneSharedLineGroups::list_shared_line_groups(self, page_size, next_page_token).await
+    }
+    async fn list_shared_line_groups_page(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::SharedLineGroups>, String)> {
+        PhoneSharedLineGroups::list_shared_line_groups_page(self, page_size, next_page_token).await
+    }
+    async fn list_all_shared_line_groups(&self) -> Result<Vec<crate::types::SharedLineGroups>> {
+        PhoneSharedLineGroups::list_all_shared_line_groups(self).await
+    }
+    async fn create_shared_line_group(
+        &self,
+        body: &crate::types::CreateSharedLineGroupRequest,
+    ) -> Result<()> {
+        PhoneSharedLineGroups::create_shared_line_group(self, body).await
+    }
+    async fn get_shared_line_group(
+        &self,
+        shared_line_group_id: &str,
+    ) -> Result<crate::types::GetSharedLineGroupResponse> {
+        PhoneSharedLineGroups::get_shared_line_group(self, shared_line_group_id).await
+    }
+    async fn delete_shared_line_group(&self, shared_line_group_id: &str) -> Result<()> {
+        PhoneSharedLineGroups::delete_shared_line_group(self, shared_line_group_id).await
+    }
+    async fn delete_shared_line_group_if_exists(&self, shared_line_group_id: &str) -> Result<bool> {
+        PhoneSharedLineGroups::delete_shared_line_group_if_exists(self, shared_line_group_id).await
+    }
+    async fn update_shared_line_group(
+        &self,
+        shared_line_group_id: &str,
+        body: &crate::types::UpdateSharedLineGroupRequest,
+    ) -> Result<()> {
+        PhoneSharedLineGroups::update_shared_line_group(self, shared_line_group_id, body).await
+    }
+    async fn add_members_shared_line_group(
+        &self,
+        shared_line_group_id: &str,
+        body: &crate::types::AddMembersSharedLineGroupRequestData,
+    ) -> Result<()> {
+        PhoneSharedLineGroups::add_members_shared_line_group(self, shared_line_group_id, body).await
+    }
+    async fn delete_members_of_slg(&self, shared_line_group_id: &str) -> Result<()> {
+        PhoneSharedLineGroups::delete_members_of_slg(self, shared_line_group_id).await
+    }
+    async fn delete_members_of_slg_if_exists(&self, shared_line_group_id: &str) -> Result<bool> {
+        PhoneSharedLineGroups::delete_members_of_slg_if_exists(self, shared_line_group_id).await
+    }
+    async fn delete_member_slg(&self, shared_line_group_id: &str, member_id: &str) -> Result<()> {
+        PhoneSharedLineGroups::delete_member_slg(self, shared_line_group_id, member_id).await
+    }
+    async fn delete_member_slg_if_exists(
+        &self,
+        shared_line_group_id: &str,
+        member_id: &str,
+    ) -> Result<bool> {
+        PhoneSharedLineGroups::delete_member_slg_if_exists(self, shared_line_group_id, member_id)
+            .await
+    }
+    async fn assign_phone_numbers_slg(
+        &self,
+        shared_line_group_id: &str,
+        body: &crate::types::AddByocNumberResponse,
+    ) -> Result<()> {
+        PhoneSharedLineGroups::assign_phone_numbers_slg(self, shared_line_group_id, body).await
+    }
+    async fn delete_phone_numbers_slg(&self, shared_line_group_id: &str) -> Result<()> {
+        PhoneSharedLineGroups::delete_phone_numbers_slg(self, shared_line_group_id).await
+    }
+    async fn delete_phone_numbers_slg_if_exists(&self, shared_line_group_id: &str) -> Result<bool> {
+        PhoneSharedLineGroups::delete_phone_numbers_slg_if_exists(self, shared_line_group_id).await
+    }
+    async fn delete_phone_number_slg(
+        &self,
+        shared_line_group_id: &str,
+        phone_number_id: &str,
+    ) -> Result<()> {
+        PhoneSharedLineGroups::delete_phone_number_slg(self, shared_line_group_id, phone_number_id)
+            .await
+    }
+    async fn delete_phone_number_slg_if_exists(
+        &self,
+        shared_line_group_id: &str,
+        phone_number_id: &str,
+    ) -> Result<bool> {
+        PhoneSharedLineGroups::delete_phone_number_slg_if_exists(
+            self,
+            shared_line_group_id,
+            phone_number_id,
+        )
+        .await
     }
 }
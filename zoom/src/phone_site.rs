@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct PhoneSite {
     pub client: Client,
@@ -12,6 +10,17 @@ impl PhoneSite {
         PhoneSite { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        PhoneSite {
+            client: self.client.with_retry(policy),
+        }
+    }
+
+    /// The OAuth scopes for `list`, any one of them grants access.
+    pub const LIST_SCOPES: &[&str] = &["phone:read:admin"];
+
     /**
      * List phone sites.
      *
@@ -35,22 +44,58 @@ impl PhoneSite {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<Vec<crate::types::Sites>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/phone/sites?{}", query_);
+        let url = crate::Client::url_with_query("/phone/sites", &query_args);
 
-        let resp: crate::types::ListPhoneSitesResponse = self.client.get(&url, None).await?;
+        let resp: crate::types::ListPhoneSitesResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/sites", &[]))?;
 
         // Return our response data.
         Ok(resp.sites)
     }
 
+    /**
+     * List phone sites.
+     *
+     * This function performs a `GET` to the `/phone/sites` endpoint.
+     *
+     * As opposed to `list`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
+     */
+    pub async fn list_page(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::Sites>, String)> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        let url = crate::Client::url_with_query("/phone/sites", &query_args);
+
+        let resp: crate::types::ListPhoneSitesResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/sites", &[]))?;
+
+        // Return our response data, and where the next page starts.
+        Ok((resp.sites, resp.next_page_token))
+    }
+
     /**
      * List phone sites.
      *
@@ -68,7 +113,11 @@ impl PhoneSite {
      */
     pub async fn list_all(&self) -> Result<Vec<crate::types::Sites>> {
         let url = "/phone/sites".to_string();
-        let mut resp: crate::types::ListPhoneSitesResponse = self.client.get(&url, None).await?;
+        let mut resp: crate::types::ListPhoneSitesResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/sites", &[]))?;
 
         let mut sites = resp.sites;
         let mut page = resp.next_page_token;
@@ -101,6 +150,56 @@ impl PhoneSite {
         Ok(sites)
     }
 
+    /**
+     * List phone sites.
+     *
+     * This function performs a `GET` to the `/phone/sites` endpoint.
+     *
+     * As opposed to `list`, this function streams the items of all the pages, getting the next page only once the items of the last one are used up. The stream ends after the last page.
+     */
+    pub async fn list_stream(
+        &self,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::Sites>>> {
+        let url = "/phone/sites".to_string();
+        self.client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListPhoneSitesResponse| (resp.sites, resp.next_page_token),
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/sites", &[]))
+    }
+
+    /**
+     * List phone sites.
+     *
+     * This function performs a `GET` to the `/phone/sites` endpoint.
+     *
+     * As opposed to `list_stream`, this function streams only the items `predicate` keeps. It too gets the next page only once the items of the last one are used up, so a stream that is cut short, for example with `take`, gets no more pages than it needs.
+     */
+    pub async fn list_filter<P: FnMut(&crate::types::Sites) -> bool>(
+        &self,
+        mut predicate: P,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::Sites>>> {
+        let url = "/phone/sites".to_string();
+        let items = self
+            .client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListPhoneSitesResponse| (resp.sites, resp.next_page_token),
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/phone/sites", &[]))?;
+        Ok(futures::TryStreamExt::try_filter(items, move |item| {
+            futures::future::ready(predicate(item))
+        }))
+    }
+
+    /// The OAuth scopes for `create`, any one of them grants access.
+    pub const CREATE_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Create a phone site.
      *
@@ -122,10 +221,32 @@ impl PhoneSite {
     ) -> Result<crate::types::Site> {
         let url = "/phone/sites".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/phone/sites", &[]))
     }
 
+    /**
+     * Create a phone site.
+     *
+     * This function performs a `POST` to the `/phone/sites` endpoint.
+     *
+     * As opposed to `create`, which only returns the id of what it creates, this function then does a `GET` to the `/phone/sites/{siteId}` endpoint and returns all of it.
+     */
+    pub async fn create_and_fetch(
+        &self,
+        body: &crate::types::CreatePhoneSiteRequest,
+    ) -> Result<crate::types::GetSiteResponse> {
+        let url = "/phone/sites".to_string();
+        self.client
+            .post_and_get(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| crate::utils::pretty_url("POST", "/phone/sites", &[]))
+    }
+
+    /// The OAuth scopes for `get_site`, any one of them grants access.
+    pub const GET_SITE_SCOPES: &[&str] = &["phone:read:admin"];
+
     /**
      * Get phone site details.
      *
@@ -147,14 +268,20 @@ impl PhoneSite {
      * * `site_id: &str` -- Unique Identifier of the Site.
      */
     pub async fn get_site(&self, site_id: &str) -> Result<crate::types::GetSiteResponse> {
-        let url = format!(
-            "/phone/sites/{}",
-            crate::progenitor_support::encode_path(&site_id.to_string()),
-        );
+        let url = format!("/phone/sites/{}", self.client.encode_path(site_id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/phone/sites/{siteId}",
+                &[("siteId", site_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `delete`, any one of them grants access.
+    pub const DELETE_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Delete a phone site.
      *
@@ -177,20 +304,56 @@ impl PhoneSite {
      * * `transfer_site_id: &str` -- The Site ID of another site where the assets of the current site (users, numbers and phones) can be transferred to.
      */
     pub async fn delete(&self, site_id: &str, transfer_site_id: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !transfer_site_id.is_empty() {
+            query_args.push(("transfer_site_id", transfer_site_id.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!("/phone/sites/{}", self.client.encode_path(site_id)),
+            &query_args,
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/phone/sites/{siteId}",
+                &[("siteId", site_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete a phone site.
+     *
+     * This function performs a `DELETE` to the `/phone/sites/{siteId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, site_id: &str, transfer_site_id: &str) -> Result<bool> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !transfer_site_id.is_empty() {
-            query_args.push(("transfer_site_id".to_string(), transfer_site_id.to_string()));
+            query_args.push(("transfer_site_id", transfer_site_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/phone/sites/{}?{}",
-            crate::progenitor_support::encode_path(&site_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/phone/sites/{}", self.client.encode_path(site_id)),
+            &query_args,
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/phone/sites/{siteId}",
+                    &[("siteId", site_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `update_site_details`, any one of them grants access.
+    pub const UPDATE_SITE_DETAILS_SCOPES: &[&str] = &["phone:write:admin"];
+
     /**
      * Update phone site details.
      *
@@ -215,13 +378,126 @@ impl PhoneSite {
         site_id: &str,
         body: &crate::types::UpdateSiteDetailsRequest,
     ) -> Result<()> {
-        let url = format!(
-            "/phone/sites/{}",
-            crate::progenitor_support::encode_path(&site_id.to_string()),
-        );
+        let url = format!("/phone/sites/{}", self.client.encode_path(site_id),);
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/phone/sites/{siteId}",
+                    &[("siteId", site_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update phone site details.
+     *
+     * This function performs a `PATCH` to the `/phone/sites/{siteId}` endpoint.
+     *
+     * As opposed to `update_site_details`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn update_site_details_if_changed<C: serde::Serialize>(
+        &self,
+        site_id: &str,
+        current: &C,
+        body: &crate::types::UpdateSiteDetailsRequest,
+    ) -> Result<Option<()>> {
+        let url = format!("/phone/sites/{}", self.client.encode_path(site_id),);
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/phone/sites/{siteId}",
+                    &[("siteId", site_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`PhoneSite`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn PhoneSiteApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait PhoneSiteApi: Send + Sync {
+    async fn list(&self, page_size: i64, next_page_token: &str)
+        -> Result<Vec<crate::types::Sites>>;
+    async fn list_page(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::Sites>, String)>;
+    async fn list_all(&self) -> Result<Vec<crate::types::Sites>>;
+    async fn create(
+        &self,
+        body: &crate::types::CreatePhoneSiteRequest,
+    ) -> Result<crate::types::Site>;
+    async fn create_and_fetch(
+        &self,
+        body: &crate::types::CreatePhoneSiteRequest,
+    ) -> Result<crate::types::GetSiteResponse>;
+    async fn get_site(&self, site_id: &str) -> Result<crate::types::GetSiteResponse>;
+    async fn delete(&self, site_id: &str, transfer_site_id: &str) -> Result<()>;
+    async fn delete_if_exists(&self, site_id: &str, transfer_site_id: &str) -> Result<bool>;
+    async fn update_site_details(
+        &self,
+        site_id: &str,
+        body: &crate::types::UpdateSiteDetailsRequest,
+    ) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl PhoneSiteApi for PhoneSite {
+    async fn list(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::Sites>> {
+        PhoneSite::list(self, page_size, next_page_token).await
+    }
+    async fn list_page(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::Sites>, String)> {
+        PhoneSite::list_page(self, page_size, next_page_token).await
+    }
+    async fn list_all(&self) -> Result<Vec<crate::types::Sites>> {
+        PhoneSite::list_all(self).await
+    }
+    async fn create(
+        &self,
+        body: &crate::types::CreatePhoneSiteRequest,
+    ) -> Result<crate::types::Site> {
+        PhoneSite::create(self, body).await
+    }
+    async fn create_and_fetch(
+        &self,
+        body: &crate::types::CreatePhoneSiteRequest,
+    ) -> Result<crate::types::GetSiteResponse> {
+        PhoneSite::create_and_fetch(self, body).await
+    }
+    async fn get_site(&self, site_id: &str) -> Result<crate::types::GetSiteResponse> {
+        PhoneSite::get_site(self, site_id).await
+    }
+    async fn delete(&self, site_id: &str, transfer_site_id: &str) -> Result<()> {
+        PhoneSite::delete(self, site_id, transfer_site_id).await
+    }
+    async fn delete_if_exists(&self, site_id: &str, transfer_site_id: &str) -> Result<bool> {
+        PhoneSite::delete_if_exists(self, site_id, transfer_site_id).await
+    }
+    async fn update_site_details(
+        &self,
+        site_id: &str,
+        body: &crate::types::UpdateSiteDetailsRequest,
+    ) -> Result<()> {
+        PhoneSite::update_site_details(self, site_id, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Reports {
     pub client: Client,
@@ -12,6 +10,17 @@ impl Reports {
         Reports { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Reports {
+            client: self.client.with_retry(policy),
+        }
+    }
+
+    /// The OAuth scopes for `daily`, any one of them grants access.
+    pub const DAILY_SCOPES: &[&str] = &["report:read:admin"];
+
     /**
      * Get daily usage report.
      *
@@ -30,19 +39,24 @@ impl Reports {
      * * `month: i64` -- Month for this report.
      */
     pub async fn daily(&self, year: i64, month: i64) -> Result<crate::types::ReportDailyResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if month > 0 {
-            query_args.push(("month".to_string(), month.to_string()));
+            query_args.push(("month", month.to_string()));
         }
         if year > 0 {
-            query_args.push(("year".to_string(), year.to_string()));
+            query_args.push(("year", year.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/report/daily?{}", query_);
+        let url = crate::Client::url_with_query("/report/daily", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/report/daily", &[]))
     }
 
+    /// The OAuth scopes for `user`, any one of them grants access.
+    pub const USER_SCOPES: &[&str] = &["report:read:admin"];
+
     /**
      * Get active/inactive host reports.
      *
@@ -58,7 +72,7 @@ impl Reports {
      *
      * **Parameters:**
      *
-     * * `type_: crate::types::ReportUsersType` -- Active or inactive hosts.<br>`active` - Active hosts. <br>`inactive` - Inactive hosts.
+     * * `type_: Option<crate::types::ReportUsersType>` -- Active or inactive hosts.<br>`active` - Active hosts. <br>`inactive` - Inactive hosts. `None` leaves it off the query, for the API's default.
      * * `from: chrono::NaiveDate` -- Start date in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the report includes only one month worth of data at once.
      * * `to: chrono::NaiveDate` -- Start Date.
      * * `page_size: i64` -- The number of records returned within a single API call.
@@ -67,38 +81,60 @@ impl Reports {
      */
     pub async fn user(
         &self,
-        type_: crate::types::ReportUsersType,
+        type_: Option<crate::types::ReportUsersType>,
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
         page_size: i64,
         page_number: i64,
         next_page_token: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_number > 0 {
-            query_args.push(("page_number".to_string(), page_number.to_string()));
+            query_args.push(("page_number", page_number.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+        if let Some(v) = type_ {
+            query_args.push(("type", v.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/report/users?{}", query_);
+        let url = crate::Client::url_with_query("/report/users", &query_args);
+
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/report/users", &[]))
+    }
 
-        self.client.get(&url, None).await
+    /**
+     * A request to `user`, to set its query parameters by name rather than by position.
+     * The ones left unset are left off the query.
+     */
+    pub fn user_request(&self, from: chrono::NaiveDate, to: chrono::NaiveDate) -> UserRequest<'_> {
+        UserRequest {
+            api: self,
+            type_: None,
+            from,
+            to,
+            page_size: None,
+            page_number: None,
+            next_page_token: None,
+        }
     }
 
+    /// The OAuth scopes for `meeting`, any one of them grants access.
+    pub const MEETING_SCOPES: &[&str] = &["report:read:admin"];
+
     /**
      * Get meeting reports.
      *
@@ -131,32 +167,43 @@ impl Reports {
         next_page_token: &str,
         type_: crate::types::ReportMeetingsType,
     ) -> Result<crate::types::ReportMeetingsResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
         if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/report/users/{}/meetings?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/report/users/{}/meetings",
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/report/users/{userId}/meetings",
+                &[("userId", user_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `meeting_details`, any one of them grants access.
+    pub const MEETING_DETAILS_SCOPES: &[&str] = &["report:read:admin"];
+
     /**
      * Get meeting detail reports.
      *
@@ -180,14 +227,20 @@ impl Reports {
         &self,
         meeting_id: &str,
     ) -> Result<crate::types::ReportMeetingDetailsResponse> {
-        let url = format!(
-            "/report/meetings/{}",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
-        );
+        let url = format!("/report/meetings/{}", self.client.encode_path(meeting_id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/report/meetings/{meetingId}",
+                &[("meetingId", meeting_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `meeting_participant`, any one of them grants access.
+    pub const MEETING_PARTICIPANT_SCOPES: &[&str] = &["report:read:admin"];
+
     /**
      * Get meeting participant reports.
      *
@@ -209,35 +262,46 @@ impl Reports {
      *   If a UUID starts with "/" or contains "//" (example: "/ajXp112QmuoKj4854875==\"), you must **double encode** the UUID before making an API request.
      * * `page_size: i64` -- The number of records returned within a single API call.
      * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `include_fields: crate::types::DashboardMeetingParticipantsIncludeFields` -- Provide `registrant_id` as the value for this field if you would like to see the registrant ID attribute in the response of this API call. A registrant ID is a unique identifier of a [meeting registrant](https://marketplace.zoom.us/docs/api-reference/zoom-api/meetings/meetingregistrants). This is not supported for `live` meeting types.
+     * * `include_fields: Option<crate::types::DashboardMeetingParticipantsIncludeFields>` -- Provide `registrant_id` as the value for this field if you would like to see the registrant ID attribute in the response of this API call. A registrant ID is a unique identifier of a [meeting registrant](https://marketplace.zoom.us/docs/api-reference/zoom-api/meetings/meetingregistrants). This is not supported for `live` meeting types. `None` leaves it off the query, for the API's default.
      */
     pub async fn meeting_participant(
         &self,
         meeting_id: &str,
         page_size: i64,
         next_page_token: &str,
-        include_fields: crate::types::DashboardMeetingParticipantsIncludeFields,
+        include_fields: Option<crate::types::DashboardMeetingParticipantsIncludeFields>,
     ) -> Result<crate::types::ReportMeetingParticipantsResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !include_fields.to_string().is_empty() {
-            query_args.push(("include_fields".to_string(), include_fields.to_string()));
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if let Some(v) = include_fields {
+            query_args.push(("include_fields", v.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/report/meetings/{}/participants?{}",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/report/meetings/{}/participants",
+                self.client.encode_path(meeting_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/report/meetings/{meetingId}/participants",
+                &[("meetingId", meeting_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `meeting_polls`, any one of them grants access.
+    pub const MEETING_POLLS_SCOPES: &[&str] = &["report:read:admin"];
+
     /**
      * Get meeting poll reports.
      *
@@ -263,12 +327,21 @@ impl Reports {
     ) -> Result<crate::types::ReportMeetingPollsResponse> {
         let url = format!(
             "/report/meetings/{}/polls",
-            crate::progenitor_support::encode_path(&meeting_id.to_string()),
+            self.client.encode_path(meeting_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/report/meetings/{meetingId}/polls",
+                &[("meetingId", meeting_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `webinar_details`, any one of them grants access.
+    pub const WEBINAR_DETAILS_SCOPES: &[&str] = &["report:read:admin"];
+
     /**
      * Get webinar detail reports.
      *
@@ -291,14 +364,20 @@ impl Reports {
         &self,
         webinar_id: &str,
     ) -> Result<crate::types::ReportMeetingDetailsResponse> {
-        let url = format!(
-            "/report/webinars/{}",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
-        );
+        let url = format!("/report/webinars/{}", self.client.encode_path(webinar_id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/report/webinars/{webinarId}",
+                &[("webinarId", webinar_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `webinar_participant`, any one of them grants access.
+    pub const WEBINAR_PARTICIPANT_SCOPES: &[&str] = &["report:read:admin"];
+
     /**
      * Get webinar participant reports.
      *
@@ -318,35 +397,46 @@ impl Reports {
      *   If a UUID starts with "/" or contains "//" (example: "/ajXp112QmuoKj4854875==\"), you must **double encode** the UUID before making an API request.
      * * `page_size: i64` -- The number of records returned within a single API call.
      * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `include_fields: crate::types::DashboardMeetingParticipantsIncludeFields` -- Provide `registrant_id` as the value for this field if you would like to see the registrant ID attribute in the response of this API call. A registrant ID is a unique identifier of a [meeting registrant](https://marketplace.zoom.us/docs/api-reference/zoom-api/meetings/meetingregistrants). This is not supported for `live` meeting types.
+     * * `include_fields: Option<crate::types::DashboardMeetingParticipantsIncludeFields>` -- Provide `registrant_id` as the value for this field if you would like to see the registrant ID attribute in the response of this API call. A registrant ID is a unique identifier of a [meeting registrant](https://marketplace.zoom.us/docs/api-reference/zoom-api/meetings/meetingregistrants). This is not supported for `live` meeting types. `None` leaves it off the query, for the API's default.
      */
     pub async fn webinar_participant(
         &self,
         webinar_id: &str,
         page_size: i64,
         next_page_token: &str,
-        include_fields: crate::types::DashboardMeetingParticipantsIncludeFields,
+        include_fields: Option<crate::types::DashboardMeetingParticipantsIncludeFields>,
     ) -> Result<crate::types::ReportWebinarParticipantsResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !include_fields.to_string().is_empty() {
-            query_args.push(("include_fields".to_string(), include_fields.to_string()));
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if let Some(v) = include_fields {
+            query_args.push(("include_fields", v.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/report/webinars/{}/participants?{}",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/report/webinars/{}/participants",
+                self.client.encode_path(webinar_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/report/webinars/{webinarId}/participants",
+                &[("webinarId", webinar_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `webinar_polls`, any one of them grants access.
+    pub const WEBINAR_POLLS_SCOPES: &[&str] = &["report:read:admin"];
+
     /**
      * Get webinar poll reports.
      *
@@ -371,12 +461,21 @@ impl Reports {
     ) -> Result<crate::types::ReportMeetingPollsResponse> {
         let url = format!(
             "/report/webinars/{}/polls",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
+            self.client.encode_path(webinar_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/report/webinars/{webinarId}/polls",
+                &[("webinarId", webinar_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `webinar_qa`, any one of them grants access.
+    pub const WEBINAR_QA_SCOPES: &[&str] = &["report:read:admin"];
+
     /**
      * Get webinar Q&A report.
      *
@@ -403,12 +502,21 @@ impl Reports {
     ) -> Result<crate::types::ReportWebinarQaResponse> {
         let url = format!(
             "/report/webinars/{}/qa",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
+            self.client.encode_path(webinar_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/report/webinars/{webinarId}/qa",
+                &[("webinarId", webinar_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `telephone`, any one of them grants access.
+    pub const TELEPHONE_SCOPES: &[&str] = &["report:read:admin"];
+
     /**
      * Get telephone reports.
      *
@@ -445,34 +553,62 @@ impl Reports {
         page_number: i64,
         next_page_token: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_number > 0 {
-            query_args.push(("page_number".to_string(), page_number.to_string()));
+            query_args.push(("page_number", page_number.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        if !query_date_type.to_string().is_empty() {
-            query_args.push(("query_date_type".to_string(), query_date_type.to_string()));
+        if query_date_type != crate::types::QueryDateType::default() {
+            query_args.push(("query_date_type", query_date_type.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/report/telephone?{}", query_);
+        let url = crate::Client::url_with_query("/report/telephone", &query_args);
+
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/report/telephone", &[]))
+    }
 
-        self.client.get(&url, None).await
+    /**
+     * A request to `telephone`, to set its query parameters by name rather than by position.
+     * The ones left unset are left off the query.
+     */
+    pub fn telephone_request(
+        &self,
+        query_date_type: crate::types::QueryDateType,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> TelephoneRequest<'_> {
+        TelephoneRequest {
+            api: self,
+            type_: None,
+            query_date_type,
+            from,
+            to,
+            page_size: None,
+            page_number: None,
+            next_page_token: None,
+        }
     }
 
+    /// The OAuth scopes for `cloud_recording`, any one of them grants access.
+    pub const CLOUD_RECORDING_SCOPES: &[&str] = &["report:read:admin"];
+
     /**
      * Get cloud recording usage report.
      *
@@ -495,19 +631,24 @@ impl Reports {
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> Result<crate::types::ReportCloudRecordingResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/report/cloud_recording?{}", query_);
+        let url = crate::Client::url_with_query("/report/cloud_recording", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/report/cloud_recording", &[]))
     }
 
+    /// The OAuth scopes for `operation_log`, any one of them grants access.
+    pub const OPERATION_LOG_SCOPES: &[&str] = &["report:read:admin"];
+
     /**
      * Get operation logs report.
      *
@@ -527,9 +668,9 @@ impl Reports {
      * * `to: chrono::NaiveDate` -- Start Date.
      * * `page_size: i64` -- The number of records returned within a single API call.
      * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `category_type: crate::types::CategoryType` -- \*\*Optional\*\*<br>
+     * * `category_type: Option<crate::types::CategoryType>` -- \*\*Optional\*\*<br>
      *  Filter your response by a category type to see reports for a specific category.
-     *  The value for this field can be one of the following:<br> `all`<br>`user`<br>`user_settings`<br>`account`<br>`billing`<br>`im`<br>`recording`<br>`phone_contacts`<br>`webinar`<br>`sub_account`<br>`role`<br>`zoom_rooms`.
+     *  The value for this field can be one of the following:<br> `all`<br>`user`<br>`user_settings`<br>`account`<br>`billing`<br>`im`<br>`recording`<br>`phone_contacts`<br>`webinar`<br>`sub_account`<br>`role`<br>`zoom_rooms`. `None` leaves it off the query, for the API's default.
      */
     pub async fn operation_log(
         &self,
@@ -537,30 +678,36 @@ impl Reports {
         to: chrono::NaiveDate,
         page_size: i64,
         next_page_token: &str,
-        category_type: crate::types::CategoryType,
+        category_type: Option<crate::types::CategoryType>,
     ) -> Result<crate::types::ReportOperationLogsResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !category_type.to_string().is_empty() {
-            query_args.push(("category_type".to_string(), category_type.to_string()));
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if let Some(v) = category_type {
+            query_args.push(("category_type", v.to_string()));
         }
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/report/operationlogs?{}", query_);
+        let url = crate::Client::url_with_query("/report/operationlogs", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/report/operationlogs", &[]))
     }
 
+    /// The OAuth scopes for `sign_out_activities`, any one of them grants access.
+    pub const SIGN_OUT_ACTIVITIES_SCOPES: &[&str] = &["report:read:admin"];
+
     /**
      * Get sign In / sign out activity report.
      *
@@ -587,29 +734,72 @@ impl Reports {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<Vec<crate::types::ActivityLogs>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/report/activities?{}", query_);
+        let url = crate::Client::url_with_query("/report/activities", &query_args);
 
-        let resp: crate::types::ReportSignInOutActivitiesResponse =
-            self.client.get(&url, None).await?;
+        let resp: crate::types::ReportSignInOutActivitiesResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/report/activities", &[]))?;
 
         // Return our response data.
         Ok(resp.activity_logs)
     }
 
+    /**
+     * Get sign In / sign out activity report.
+     *
+     * This function performs a `GET` to the `/report/activities` endpoint.
+     *
+     * As opposed to `sign_out_activities`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
+     */
+    pub async fn sign_out_activities_page(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::ActivityLogs>, String)> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from.to_string().is_empty() {
+            query_args.push(("from", from.to_string()));
+        }
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        if !to.to_string().is_empty() {
+            query_args.push(("to", to.to_string()));
+        }
+        let url = crate::Client::url_with_query("/report/activities", &query_args);
+
+        let resp: crate::types::ReportSignInOutActivitiesResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/report/activities", &[]))?;
+
+        // Return our response data, and where the next page starts.
+        Ok((resp.activity_logs, resp.next_page_token))
+    }
+
     /**
      * Get sign In / sign out activity report.
      *
@@ -629,18 +819,20 @@ impl Reports {
         from: chrono::NaiveDate,
         to: chrono::NaiveDate,
     ) -> Result<Vec<crate::types::ActivityLogs>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !from.to_string().is_empty() {
-            query_args.push(("from".to_string(), from.to_string()));
+            query_args.push(("from", from.to_string()));
         }
         if !to.to_string().is_empty() {
-            query_args.push(("to".to_string(), to.to_string()));
+            query_args.push(("to", to.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/report/activities?{}", query_);
+        let url = crate::Client::url_with_query("/report/activities", &query_args);
 
-        let mut resp: crate::types::ReportSignInOutActivitiesResponse =
-            self.client.get(&url, None).await?;
+        let mut resp: crate::types::ReportSignInOutActivitiesResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/report/activities", &[]))?;
 
         let mut activity_logs = resp.activity_logs;
         let mut page = resp.next_page_token;
@@ -673,6 +865,80 @@ impl Reports {
         Ok(activity_logs)
     }
 
+    /**
+     * Get sign In / sign out activity report.
+     *
+     * This function performs a `GET` to the `/report/activities` endpoint.
+     *
+     * As opposed to `sign_out_activities`, this function streams the items of all the pages, getting the next page only once the items of the last one are used up. The stream ends after the last page.
+     */
+    pub async fn sign_out_activities_stream(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::ActivityLogs>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from.to_string().is_empty() {
+            query_args.push(("from", from.to_string()));
+        }
+        if !to.to_string().is_empty() {
+            query_args.push(("to", to.to_string()));
+        }
+        let url = crate::Client::url_with_query("/report/activities", &query_args);
+
+        self.client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ReportSignInOutActivitiesResponse| {
+                    (resp.activity_logs, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/report/activities", &[]))
+    }
+
+    /**
+     * Get sign In / sign out activity report.
+     *
+     * This function performs a `GET` to the `/report/activities` endpoint.
+     *
+     * As opposed to `sign_out_activities_stream`, this function streams only the items `predicate` keeps. It too gets the next page only once the items of the last one are used up, so a stream that is cut short, for example with `take`, gets no more pages than it needs.
+     */
+    pub async fn sign_out_activities_filter<P: FnMut(&crate::types::ActivityLogs) -> bool>(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        mut predicate: P,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::ActivityLogs>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !from.to_string().is_empty() {
+            query_args.push(("from", from.to_string()));
+        }
+        if !to.to_string().is_empty() {
+            query_args.push(("to", to.to_string()));
+        }
+        let url = crate::Client::url_with_query("/report/activities", &query_args);
+
+        let items = self
+            .client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ReportSignInOutActivitiesResponse| {
+                    (resp.activity_logs, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/report/activities", &[]))?;
+        Ok(futures::TryStreamExt::try_filter(items, move |item| {
+            futures::future::ready(predicate(item))
+        }))
+    }
+
+    /// The OAuth scopes for `get_billing`, any one of them grants access.
+    pub const GET_BILLING_SCOPES: &[&str] = &["report:read:admin", "report:master"];
+
     /**
      * Get billing reports.
      *
@@ -689,9 +955,15 @@ impl Reports {
      */
     pub async fn get_billing(&self) -> Result<crate::types::GetBillingReportResponse> {
         let url = "/report/billing".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/report/billing", &[]))
     }
 
+    /// The OAuth scopes for `get_billing_invoices`, any one of them grants access.
+    pub const GET_BILLING_INVOICES_SCOPES: &[&str] = &["report:read:admin", "report:master"];
+
     /**
      * Get billing invoice reports.
      *
@@ -717,13 +989,383 @@ impl Reports {
         &self,
         billing_id: &str,
     ) -> Result<crate::types::GetBillingInvoicesReportsResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !billing_id.is_empty() {
-            query_args.push(("billing_id".to_string(), billing_id.to_string()));
+            query_args.push(("billing_id", billing_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/report/billing/invoices?{}", query_);
+        let url = crate::Client::url_with_query("/report/billing/invoices", &query_args);
+
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/report/billing/invoices", &[]))
+    }
+}
+
+/// A request to [`Reports::user`], see [`Reports::user_request`].
+pub struct UserRequest<'a> {
+    api: &'a Reports,
+    type_: Option<crate::types::ReportUsersType>,
+    from: chrono::NaiveDate,
+    to: chrono::NaiveDate,
+    page_size: Option<i64>,
+    page_number: Option<i64>,
+    next_page_token: Option<String>,
+}
+
+impl<'a> UserRequest<'a> {
+    /// Set `type_`.
+    pub fn type_(mut self, type_: crate::types::ReportUsersType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Set `page_size`.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Set `page_number`.
+    pub fn page_number(mut self, page_number: i64) -> Self {
+        self.page_number = Some(page_number);
+        self
+    }
+
+    /// Set `next_page_token`.
+    pub fn next_page_token(mut self, next_page_token: &str) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    /// Send the request.
+    pub async fn send(self) -> Result<crate::types::Domains> {
+        self.api
+            .user(
+                self.type_,
+                self.from,
+                self.to,
+                self.page_size.unwrap_or_default(),
+                self.page_number.unwrap_or_default(),
+                self.next_page_token.as_deref().unwrap_or_default(),
+            )
+            .await
+    }
+}
+
+/// A request to [`Reports::telephone`], see [`Reports::telephone_request`].
+pub struct TelephoneRequest<'a> {
+    api: &'a Reports,
+    type_: Option<String>,
+    query_date_type: crate::types::QueryDateType,
+    from: chrono::NaiveDate,
+    to: chrono::NaiveDate,
+    page_size: Option<i64>,
+    page_number: Option<i64>,
+    next_page_token: Option<String>,
+}
+
+impl<'a> TelephoneRequest<'a> {
+    /// Set `type_`.
+    pub fn type_(mut self, type_: &str) -> Self {
+        self.type_ = Some(type_.to_string());
+        self
+    }
+
+    /// Set `page_size`.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Set `page_number`.
+    pub fn page_number(mut self, page_number: i64) -> Self {
+        self.page_number = Some(page_number);
+        self
+    }
+
+    /// Set `next_page_token`.
+    pub fn next_page_token(mut self, next_page_token: &str) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
 
-        self.client.get(&url, None).await
+    /// Send the request.
+    pub async fn send(self) -> Result<crate::types::Domains> {
+        self.api
+            .telephone(
+                self.type_.as_deref().unwrap_or_default(),
+                self.query_date_type,
+                self.from,
+                self.to,
+                self.page_size.unwrap_or_default(),
+                self.page_number.unwrap_or_default(),
+                self.next_page_token.as_deref().unwrap_or_default(),
+            )
+            .await
+    }
+}
+
+/// The functions of [`Reports`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn ReportsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait ReportsApi: Send + Sync {
+    async fn daily(&self, year: i64, month: i64) -> Result<crate::types::ReportDailyResponse>;
+    async fn user(
+        &self,
+        type_: Option<crate::types::ReportUsersType>,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::Domains>;
+    async fn meeting(
+        &self,
+        user_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+        type_: crate::types::ReportMeetingsType,
+    ) -> Result<crate::types::ReportMeetingsResponseAllOf>;
+    async fn meeting_details(
+        &self,
+        meeting_id: &str,
+    ) -> Result<crate::types::ReportMeetingDetailsResponse>;
+    async fn meeting_participant(
+        &self,
+        meeting_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        include_fields: Option<crate::types::DashboardMeetingParticipantsIncludeFields>,
+    ) -> Result<crate::types::ReportMeetingParticipantsResponseAllOf>;
+    async fn meeting_polls(
+        &self,
+        meeting_id: &str,
+    ) -> Result<crate::types::ReportMeetingPollsResponse>;
+    async fn webinar_details(
+        &self,
+        webinar_id: &str,
+    ) -> Result<crate::types::ReportMeetingDetailsResponse>;
+    async fn webinar_participant(
+        &self,
+        webinar_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        include_fields: Option<crate::types::DashboardMeetingParticipantsIncludeFields>,
+    ) -> Result<crate::types::ReportWebinarParticipantsResponseAllOf>;
+    async fn webinar_polls(
+        &self,
+        webinar_id: &str,
+    ) -> Result<crate::types::ReportMeetingPollsResponse>;
+    async fn webinar_qa(&self, webinar_id: &str) -> Result<crate::types::ReportWebinarQaResponse>;
+    async fn telephone(
+        &self,
+        type_: &str,
+        query_date_type: crate::types::QueryDateType,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::Domains>;
+    async fn cloud_recording(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<crate::types::ReportCloudRecordingResponseAllOf>;
+    async fn operation_log(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+        category_type: Option<crate::types::CategoryType>,
+    ) -> Result<crate::types::ReportOperationLogsResponseAllOf>;
+    async fn sign_out_activities(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::ActivityLogs>>;
+    async fn sign_out_activities_page(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::ActivityLogs>, String)>;
+    async fn get_all_sign_out_activities(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<Vec<crate::types::ActivityLogs>>;
+    async fn get_billing(&self) -> Result<crate::types::GetBillingReportResponse>;
+    async fn get_billing_invoices(
+        &self,
+        billing_id: &str,
+    ) -> Result<crate::types::GetBillingInvoicesReportsResponse>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl ReportsApi for Reports {
+    async fn daily(&self, year: i64, month: i64) -> Result<crate::types::ReportDailyResponse> {
+        Reports::daily(self, year, month).await
+    }
+    async fn user(
+        &self,
+        type_: Option<crate::types::ReportUsersType>,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::Domains> {
+        Reports::user(
+            self,
+            type_,
+            from,
+            to,
+            page_size,
+            page_number,
+            next_page_token,
+        )
+        .await
+    }
+    async fn meeting(
+        &self,
+        user_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+        type_: crate::types::ReportMeetingsType,
+    ) -> Result<crate::types::ReportMeetingsResponseAllOf> {
+        Reports::meeting(self, user_id, from, to, page_size, next_page_token, type_).await
+    }
+    async fn meeting_details(
+        &self,
+        meeting_id: &str,
+    ) -> Result<crate::types::ReportMeetingDetailsResponse> {
+        Reports::meeting_details(self, meeting_id).await
+    }
+    async fn meeting_participant(
+        &self,
+        meeting_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        include_fields: Option<crate::types::DashboardMeetingParticipantsIncludeFields>,
+    ) -> Result<crate::types::ReportMeetingParticipantsResponseAllOf> {
+        Reports::meeting_participant(self, meeting_id, page_size, next_page_token, include_fields)
+            .await
+    }
+    async fn meeting_polls(
+        &self,
+        meeting_id: &str,
+    ) -> Result<crate::types::ReportMeetingPollsResponse> {
+        Reports::meeting_polls(self, meeting_id).await
+    }
+    async fn webinar_details(
+        &self,
+        webinar_id: &str,
+    ) -> Result<crate::types::ReportMeetingDetailsResponse> {
+        Reports::webinar_details(self, webinar_id).await
+    }
+    async fn webinar_participant(
+        &self,
+        webinar_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        include_fields: Option<crate::types::DashboardMeetingParticipantsIncludeFields>,
+    ) -> Result<crate::types::ReportWebinarParticipantsResponseAllOf> {
+        Reports::webinar_participant(self, webinar_id, page_size, next_page_token, include_fields)
+            .await
+    }
+    async fn webinar_polls(
+        &self,
+        webinar_id: &str,
+    ) -> Result<crate::types::ReportMeetingPollsResponse> {
+        Reports::webinar_polls(self, webinar_id).await
+    }
+    async fn webinar_qa(&self, webinar_id: &str) -> Result<crate::types::ReportWebinarQaResponse> {
+        Reports::webinar_qa(self, webinar_id).await
+    }
+    async fn telephone(
+        &self,
+        type_: &str,
+        query_date_type: crate::types::QueryDateType,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::Domains> {
+        Reports::telephone(
+            self,
+            type_,
+            query_date_type,
+            from,
+            to,
+            page_size,
+            page_number,
+            next_page_token,
+        )
+        .await
+    }
+    async fn cloud_recording(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<crate::types::ReportCloudRecordingResponseAllOf> {
+        Reports::cloud_recording(self, from, to).await
+    }
+    async fn operation_log(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+        category_type: Option<crate::types::CategoryType>,
+    ) -> Result<crate::types::ReportOperationLogsResponseAllOf> {
+        Reports::operation_log(self, from, to, page_size, next_page_token, category_type).await
+    }
+    async fn sign_out_activities(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::ActivityLogs>> {
+        Reports::sign_out_activities(self, from, to, page_size, next_page_token).await
+    }
+    async fn sign_out_activities_page(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::ActivityLogs>, String)> {
+        Reports::sign_out_activities_page(self, from, to, page_size, next_page_token).await
+    }
+    async fn get_all_sign_out_activities(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<Vec<crate::types::ActivityLogs>> {
+        Reports::get_all_sign_out_activities(self, from, to).await
+    }
+    async fn get_billing(&self) -> Result<crate::types::GetBillingReportResponse> {
+        Reports::get_billing(self).await
+    }
+    async fn get_billing_invoices(
+        &self,
+        billing_id: &str,
+    ) -> Result<crate::types::GetBillingInvoicesReportsResponse> {
+        Reports::get_billing_invoices(self, billing_id).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Roles {
     pub client: Client,
@@ -12,6 +10,17 @@ impl Roles {
         Roles { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Roles {
+            client: self.client.with_retry(policy),
+        }
+    }
+
+    /// The OAuth scopes for `get`, any one of them grants access.
+    pub const GET_SCOPES: &[&str] = &["role:read:admin"];
+
     /**
      * List roles.
      *
@@ -29,9 +38,15 @@ impl Roles {
      */
     pub async fn get(&self) -> Result<crate::types::Domains> {
         let url = "/roles".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/roles", &[]))
     }
 
+    /// The OAuth scopes for `create`, any one of them grants access.
+    pub const CREATE_SCOPES: &[&str] = &["role:write:admin"];
+
     /**
      * Create a role.
      *
@@ -50,10 +65,14 @@ impl Roles {
     pub async fn create(&self, body: &crate::types::CreateRoleRequest) -> Result<()> {
         let url = "/roles".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/roles", &[]))
     }
 
+    /// The OAuth scopes for `members`, any one of them grants access.
+    pub const MEMBERS_SCOPES: &[&str] = &["role:read:admin"];
+
     /**
      * List members in a role.
      *
@@ -85,32 +104,85 @@ impl Roles {
         next_page_token: &str,
         page_size: i64,
     ) -> Result<Vec<crate::types::Domains>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if !page_count.is_empty() {
-            query_args.push(("page_count".to_string(), page_count.to_string()));
+            query_args.push(("page_count", page_count.to_string()));
         }
         if page_number > 0 {
-            query_args.push(("page_number".to_string(), page_number.to_string()));
+            query_args.push(("page_number", page_number.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/roles/{}/members?{}",
-            crate::progenitor_support::encode_path(&role_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/roles/{}/members", self.client.encode_path(role_id)),
+            &query_args,
         );
 
-        let resp: crate::types::RoleMembersList = self.client.get(&url, None).await?;
+        let resp: crate::types::RoleMembersList =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/roles/{roleId}/members",
+                    &[("roleId", role_id.to_string())],
+                )
+            })?;
 
         // Return our response data.
         Ok(resp.members)
     }
 
+    /**
+     * List members in a role.
+     *
+     * This function performs a `GET` to the `/roles/{roleId}/members` endpoint.
+     *
+     * As opposed to `members`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
+     */
+    pub async fn members_page(
+        &self,
+        role_id: &str,
+        page_count: &str,
+        page_number: i64,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<(Vec<crate::types::Domains>, String)> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        if !page_count.is_empty() {
+            query_args.push(("page_count", page_count.to_string()));
+        }
+        if page_number > 0 {
+            query_args.push(("page_number", page_number.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!("/roles/{}/members", self.client.encode_path(role_id)),
+            &query_args,
+        );
+
+        let resp: crate::types::RoleMembersList =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/roles/{roleId}/members",
+                    &[("roleId", role_id.to_string())],
+                )
+            })?;
+
+        // Return our response data, and where the next page starts.
+        Ok((resp.members, resp.next_page_token))
+    }
+
     /**
      * List members in a role.
      *
@@ -130,18 +202,23 @@ impl Roles {
         role_id: &str,
         page_count: &str,
     ) -> Result<Vec<crate::types::Domains>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !page_count.is_empty() {
-            query_args.push(("page_count".to_string(), page_count.to_string()));
+            query_args.push(("page_count", page_count.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/roles/{}/members?{}",
-            crate::progenitor_support::encode_path(&role_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/roles/{}/members", self.client.encode_path(role_id)),
+            &query_args,
         );
 
-        let mut resp: crate::types::RoleMembersList = self.client.get(&url, None).await?;
+        let mut resp: crate::types::RoleMembersList =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/roles/{roleId}/members",
+                    &[("roleId", role_id.to_string())],
+                )
+            })?;
 
         let mut members = resp.members;
         let mut page = resp.next_page_token;
@@ -174,6 +251,88 @@ impl Roles {
         Ok(members)
     }
 
+    /**
+     * List members in a role.
+     *
+     * This function performs a `GET` to the `/roles/{roleId}/members` endpoint.
+     *
+     * As opposed to `members`, this function streams the items of all the pages, getting the next page only once the items of the last one are used up. The stream ends after the last page.
+     */
+    pub async fn members_stream(
+        &self,
+        role_id: &str,
+        page_count: &str,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::Domains>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !page_count.is_empty() {
+            query_args.push(("page_count", page_count.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!("/roles/{}/members", self.client.encode_path(role_id)),
+            &query_args,
+        );
+
+        self.client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::RoleMembersList| (resp.members, resp.next_page_token),
+            )
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/roles/{roleId}/members",
+                    &[("roleId", role_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * List members in a role.
+     *
+     * This function performs a `GET` to the `/roles/{roleId}/members` endpoint.
+     *
+     * As opposed to `members_stream`, this function streams only the items `predicate` keeps. It too gets the next page only once the items of the last one are used up, so a stream that is cut short, for example with `take`, gets no more pages than it needs.
+     */
+    pub async fn members_filter<P: FnMut(&crate::types::Domains) -> bool>(
+        &self,
+        role_id: &str,
+        page_count: &str,
+        mut predicate: P,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::Domains>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !page_count.is_empty() {
+            query_args.push(("page_count", page_count.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!("/roles/{}/members", self.client.encode_path(role_id)),
+            &query_args,
+        );
+
+        let items = self
+            .client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::RoleMembersList| (resp.members, resp.next_page_token),
+            )
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/roles/{roleId}/members",
+                    &[("roleId", role_id.to_string())],
+                )
+            })?;
+        Ok(futures::TryStreamExt::try_filter(items, move |item| {
+            futures::future::ready(predicate(item))
+        }))
+    }
+
+    /// The OAuth scopes for `add_members`, any one of them grants access.
+    pub const ADD_MEMBERS_SCOPES: &[&str] = &["role:write:admin"];
+
     /**
      * Assign a role.
      *
@@ -196,16 +355,24 @@ impl Roles {
         role_id: &str,
         body: &crate::types::AddRoleMembersRequest,
     ) -> Result<crate::types::AddRoleMembersResponse> {
-        let url = format!(
-            "/roles/{}/members",
-            crate::progenitor_support::encode_path(&role_id.to_string()),
-        );
+        let url = format!("/roles/{}/members", self.client.encode_path(role_id),);
+        crate::utils::check_items("members", body.members.len(), None, Some(30))?;
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/roles/{roleId}/members",
+                    &[("roleId", role_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `member_delete`, any one of them grants access.
+    pub const MEMBER_DELETE_SCOPES: &[&str] = &["role:write:admin"];
+
     /**
      * Unassign a role.
      *
@@ -227,13 +394,54 @@ impl Roles {
     pub async fn member_delete(&self, role_id: &str, member_id: &str) -> Result<()> {
         let url = format!(
             "/roles/{}/members/{}",
-            crate::progenitor_support::encode_path(&role_id.to_string()),
-            crate::progenitor_support::encode_path(&member_id.to_string()),
+            self.client.encode_path(role_id),
+            self.client.encode_path(member_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/roles/{roleId}/members/{memberId}",
+                &[
+                    ("roleId", role_id.to_string()),
+                    ("memberId", member_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Unassign a role.
+     *
+     * This function performs a `DELETE` to the `/roles/{roleId}/members/{memberId}` endpoint.
+     *
+     * As opposed to `member_delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn member_delete_if_exists(&self, role_id: &str, member_id: &str) -> Result<bool> {
+        let url = format!(
+            "/roles/{}/members/{}",
+            self.client.encode_path(role_id),
+            self.client.encode_path(member_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/roles/{roleId}/members/{memberId}",
+                    &[
+                        ("roleId", role_id.to_string()),
+                        ("memberId", member_id.to_string()),
+                    ],
+                )
+            })
     }
 
+    /// The OAuth scopes for `get_information`, any one of them grants access.
+    pub const GET_INFORMATION_SCOPES: &[&str] = &["role:read:admin"];
+
     /**
      * Get role information.
      *
@@ -258,14 +466,16 @@ impl Roles {
         &self,
         role_id: &str,
     ) -> Result<crate::types::GetRoleInformationResponse> {
-        let url = format!(
-            "/roles/{}",
-            crate::progenitor_support::encode_path(&role_id.to_string()),
-        );
+        let url = format!("/roles/{}", self.client.encode_path(role_id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/roles/{roleId}", &[("roleId", role_id.to_string())])
+        })
     }
 
+    /// The OAuth scopes for `delete`, any one of them grants access.
+    pub const DELETE_SCOPES: &[&str] = &["role:write:admin"];
+
     /**
      * Delete a role.
      *
@@ -287,14 +497,42 @@ impl Roles {
      * * `role_id: &str` -- User's first name.
      */
     pub async fn delete(&self, role_id: &str) -> Result<()> {
-        let url = format!(
-            "/roles/{}",
-            crate::progenitor_support::encode_path(&role_id.to_string()),
-        );
+        let url = format!("/roles/{}", self.client.encode_path(role_id),);
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/roles/{roleId}",
+                &[("roleId", role_id.to_string())],
+            )
+        })
     }
 
+    /**
+     * Delete a role.
+     *
+     * This function performs a `DELETE` to the `/roles/{roleId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, role_id: &str) -> Result<bool> {
+        let url = format!("/roles/{}", self.client.encode_path(role_id),);
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/roles/{roleId}",
+                    &[("roleId", role_id.to_string())],
+                )
+            })
+    }
+
+    /// The OAuth scopes for `update`, any one of them grants access.
+    pub const UPDATE_SCOPES: &[&str] = &["role:write:admin"];
+
     /**
      * Update role information.
      *
@@ -318,13 +556,171 @@ impl Roles {
         role_id: &str,
         body: &crate::types::UpdateRoleRequest,
     ) -> Result<()> {
-        let url = format!(
-            "/roles/{}",
-            crate::progenitor_support::encode_path(&role_id.to_string()),
-        );
+        let url = format!("/roles/{}", self.client.encode_path(role_id),);
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/roles/{roleId}",
+                    &[("roleId", role_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update role information.
+     *
+     * This function performs a `PATCH` to the `/roles/{roleId}` endpoint.
+     *
+     * As opposed to `update`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn update_if_changed<C: serde::Serialize>(
+        &self,
+        role_id: &str,
+        current: &C,
+        body: &crate::types::UpdateRoleRequest,
+    ) -> Result<Option<()>> {
+        let url = format!("/roles/{}", self.client.encode_path(role_id),);
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/roles/{roleId}",
+                    &[("roleId", role_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`Roles`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn RolesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait RolesApi: Send + Sync {
+    async fn get(&self) -> Result<crate::types::Domains>;
+    async fn create(&self, body: &crate::types::CreateRoleRequest) -> Result<()>;
+    async fn members(
+        &self,
+        role_id: &str,
+        page_count: &str,
+        page_number: i64,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<Vec<crate::types::Domains>>;
+    async fn members_page(
+        &self,
+        role_id: &str,
+        page_count: &str,
+        page_number: i64,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<(Vec<crate::types::Domains>, String)>;
+    async fn get_all_members(
+        &self,
+        role_id: &str,
+        page_count: &str,
+    ) -> Result<Vec<crate::types::Domains>>;
+    async fn add_members(
+        &self,
+        role_id: &str,
+        body: &crate::types::AddRoleMembersRequest,
+    ) -> Result<crate::types::AddRoleMembersResponse>;
+    async fn member_delete(&self, role_id: &str, member_id: &str) -> Result<()>;
+    async fn member_delete_if_exists(&self, role_id: &str, member_id: &str) -> Result<bool>;
+    async fn get_information(
+        &self,
+        role_id: &str,
+    ) -> Result<crate::types::GetRoleInformationResponse>;
+    async fn delete(&self, role_id: &str) -> Result<()>;
+    async fn delete_if_exists(&self, role_id: &str) -> Result<bool>;
+    async fn update(&self, role_id: &str, body: &crate::types::UpdateRoleRequest) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl RolesApi for Roles {
+    async fn get(&self) -> Result<crate::types::Domains> {
+        Roles::get(self).await
+    }
+    async fn create(&self, body: &crate::types::CreateRoleRequest) -> Result<()> {
+        Roles::create(self, body).await
+    }
+    async fn members(
+        &self,
+        role_id: &str,
+        page_count: &str,
+        page_number: i64,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<Vec<crate::types::Domains>> {
+        Roles::members(
+            self,
+            role_id,
+            page_count,
+            page_number,
+            next_page_token,
+            page_size,
+        )
+        .await
+    }
+    async fn members_page(
+        &self,
+        role_id: &str,
+        page_count: &str,
+        page_number: i64,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<(Vec<crate::types::Domains>, String)> {
+        Roles::members_page(
+            self,
+            role_id,
+            page_count,
+            page_number,
+            next_page_token,
+            page_size,
+        )
+        .await
+    }
+    async fn get_all_members(
+        &self,
+        role_id: &str,
+        page_count: &str,
+    ) -> Result<Vec<crate::types::Domains>> {
+        Roles::get_all_members(self, role_id, page_count).await
+    }
+    async fn add_members(
+        &self,
+        role_id: &str,
+        body: &crate::types::AddRoleMembersRequest,
+    ) -> Result<crate::types::AddRoleMembersResponse> {
+        Roles::add_members(self, role_id, body).await
+    }
+    async fn member_delete(&self, role_id: &str, member_id: &str) -> Result<()> {
+        Roles::member_delete(self, role_id, member_id).await
+    }
+    async fn member_delete_if_exists(&self, role_id: &str, member_id: &str) -> Result<bool> {
+        Roles::member_delete_if_exists(self, role_id, member_id).await
+    }
+    async fn get_information(
+        &self,
+        role_id: &str,
+    ) -> Result<crate::types::GetRoleInformationResponse> {
+        Roles::get_information(self, role_id).await
+    }
+    async fn delete(&self, role_id: &str) -> Result<()> {
+        Roles::delete(self, role_id).await
+    }
+    async fn delete_if_exists(&self, role_id: &str) -> Result<bool> {
+        Roles::delete_if_exists(self, role_id).await
+    }
+    async fn update(&self, role_id: &str, body: &crate::types::UpdateRoleRequest) -> Result<()> {
+        Roles::update(self, role_id, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Rooms {
     pub client: Client,
@@ -12,6 +10,17 @@ impl Rooms {
         Rooms { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Rooms {
+            client: self.client.with_retry(policy),
+        }
+    }
+
+    /// The OAuth scopes for `list_zoom`, any one of them grants access.
+    pub const LIST_ZOOM_SCOPES: &[&str] = &["room:read:admin"];
+
     /**
      * List Zoom Rooms.
      *
@@ -25,8 +34,8 @@ impl Rooms {
      *
      * **Parameters:**
      *
-     * * `status: crate::types::ListZoomRoomsStatus` -- The status of the Zoom Room.
-     * * `type_: crate::types::ListZoomRoomsType` -- Type of the Zoom Rooms.
+     * * `status: Option<crate::types::ListZoomRoomsStatus>` -- The status of the Zoom Room. `None` leaves it off the query, for the API's default.
+     * * `type_: Option<crate::types::ListZoomRoomsType>` -- Type of the Zoom Rooms. `None` leaves it off the query, for the API's default.
      * * `unassigned_rooms: bool` -- Enable/disable the option for a sub account to use shared [Virtual Room Connector(s)](https://support.zoom.us/hc/en-us/articles/202134758-Getting-Started-With-Virtual-Room-Connector) that are set up by the master account. Virtual Room Connectors can only be used by On-prem users.
      * * `page_size: i64` -- The number of records returned within a single API call.
      * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
@@ -34,41 +43,93 @@ impl Rooms {
      */
     pub async fn list_zoom(
         &self,
-        status: crate::types::ListZoomRoomsStatus,
-        type_: crate::types::ListZoomRoomsType,
+        status: Option<crate::types::ListZoomRoomsStatus>,
+        type_: Option<crate::types::ListZoomRoomsType>,
         unassigned_rooms: bool,
         page_size: i64,
         next_page_token: &str,
         location_id: &str,
     ) -> Result<Vec<crate::types::ListZoomRoomsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !location_id.is_empty() {
-            query_args.push(("location_id".to_string(), location_id.to_string()));
+            query_args.push(("location_id", location_id.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+        if let Some(v) = status {
+            query_args.push(("status", v.to_string()));
         }
-        if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+        if let Some(v) = type_ {
+            query_args.push(("type", v.to_string()));
         }
         if unassigned_rooms {
-            query_args.push(("unassigned_rooms".to_string(), unassigned_rooms.to_string()));
+            query_args.push(("unassigned_rooms", unassigned_rooms.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/rooms?{}", query_);
+        let url = crate::Client::url_with_query("/rooms", &query_args);
 
-        let resp: crate::types::ListZoomRoomsResponseData = self.client.get(&url, None).await?;
+        let resp: crate::types::ListZoomRoomsResponseData = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/rooms", &[]))?;
 
         // Return our response data.
         Ok(resp.rooms)
     }
 
+    /**
+     * List Zoom Rooms.
+     *
+     * This function performs a `GET` to the `/rooms` endpoint.
+     *
+     * As opposed to `list_zoom`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
+     */
+    pub async fn list_zoom_page(
+        &self,
+        status: Option<crate::types::ListZoomRoomsStatus>,
+        type_: Option<crate::types::ListZoomRoomsType>,
+        unassigned_rooms: bool,
+        page_size: i64,
+        next_page_token: &str,
+        location_id: &str,
+    ) -> Result<(Vec<crate::types::ListZoomRoomsResponse>, String)> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !location_id.is_empty() {
+            query_args.push(("location_id", location_id.to_string()));
+        }
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status", v.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type", v.to_string()));
+        }
+        if unassigned_rooms {
+            query_args.push(("unassigned_rooms", unassigned_rooms.to_string()));
+        }
+        let url = crate::Client::url_with_query("/rooms", &query_args);
+
+        let resp: crate::types::ListZoomRoomsResponseData = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/rooms", &[]))?;
+
+        // Return our response data, and where the next page starts.
+        Ok((resp.rooms, resp.next_page_token))
+    }
+
     /**
      * List Zoom Rooms.
      *
@@ -84,28 +145,31 @@ impl Rooms {
      */
     pub async fn list_all_zoom(
         &self,
-        status: crate::types::ListZoomRoomsStatus,
-        type_: crate::types::ListZoomRoomsType,
+        status: Option<crate::types::ListZoomRoomsStatus>,
+        type_: Option<crate::types::ListZoomRoomsType>,
         unassigned_rooms: bool,
         location_id: &str,
     ) -> Result<Vec<crate::types::ListZoomRoomsResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !location_id.is_empty() {
-            query_args.push(("location_id".to_string(), location_id.to_string()));
+            query_args.push(("location_id", location_id.to_string()));
         }
-        if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+        if let Some(v) = status {
+            query_args.push(("status", v.to_string()));
         }
-        if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+        if let Some(v) = type_ {
+            query_args.push(("type", v.to_string()));
         }
         if unassigned_rooms {
-            query_args.push(("unassigned_rooms".to_string(), unassigned_rooms.to_string()));
+            query_args.push(("unassigned_rooms", unassigned_rooms.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/rooms?{}", query_);
+        let url = crate::Client::url_with_query("/rooms", &query_args);
 
-        let mut resp: crate::types::ListZoomRoomsResponseData = self.client.get(&url, None).await?;
+        let mut resp: crate::types::ListZoomRoomsResponseData =
+            self.client
+                .get(&url, None)
+                .await
+                .with_context(|| crate::utils::pretty_url("GET", "/rooms", &[]))?;
 
         let mut rooms = resp.rooms;
         let mut page = resp.next_page_token;
@@ -138,6 +202,108 @@ impl Rooms {
         Ok(rooms)
     }
 
+    /**
+     * List Zoom Rooms.
+     *
+     * This function performs a `GET` to the `/rooms` endpoint.
+     *
+     * As opposed to `list_zoom`, this function streams the items of all the pages, getting the next page only once the items of the last one are used up. The stream ends after the last page.
+     */
+    pub async fn list_zoom_stream(
+        &self,
+        status: Option<crate::types::ListZoomRoomsStatus>,
+        type_: Option<crate::types::ListZoomRoomsType>,
+        unassigned_rooms: bool,
+        location_id: &str,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::ListZoomRoomsResponse>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !location_id.is_empty() {
+            query_args.push(("location_id", location_id.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status", v.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type", v.to_string()));
+        }
+        if unassigned_rooms {
+            query_args.push(("unassigned_rooms", unassigned_rooms.to_string()));
+        }
+        let url = crate::Client::url_with_query("/rooms", &query_args);
+
+        self.client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListZoomRoomsResponseData| (resp.rooms, resp.next_page_token),
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/rooms", &[]))
+    }
+
+    /**
+     * List Zoom Rooms.
+     *
+     * This function performs a `GET` to the `/rooms` endpoint.
+     *
+     * As opposed to `list_zoom_stream`, this function streams only the items `predicate` keeps. It too gets the next page only once the items of the last one are used up, so a stream that is cut short, for example with `take`, gets no more pages than it needs.
+     */
+    pub async fn list_zoom_filter<P: FnMut(&crate::types::ListZoomRoomsResponse) -> bool>(
+        &self,
+        status: Option<crate::types::ListZoomRoomsStatus>,
+        type_: Option<crate::types::ListZoomRoomsType>,
+        unassigned_rooms: bool,
+        location_id: &str,
+        mut predicate: P,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::ListZoomRoomsResponse>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !location_id.is_empty() {
+            query_args.push(("location_id", location_id.to_string()));
+        }
+        if let Some(v) = status {
+            query_args.push(("status", v.to_string()));
+        }
+        if let Some(v) = type_ {
+            query_args.push(("type", v.to_string()));
+        }
+        if unassigned_rooms {
+            query_args.push(("unassigned_rooms", unassigned_rooms.to_string()));
+        }
+        let url = crate::Client::url_with_query("/rooms", &query_args);
+
+        let items = self
+            .client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListZoomRoomsResponseData| (resp.rooms, resp.next_page_token),
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/rooms", &[]))?;
+        Ok(futures::TryStreamExt::try_filter(items, move |item| {
+            futures::future::ready(predicate(item))
+        }))
+    }
+
+    /**
+     * A request to `list_zoom`, to set its query parameters by name rather than by position.
+     * The ones left unset are left off the query.
+     */
+    pub fn list_zoom_request(&self) -> ListZoomRequest<'_> {
+        ListZoomRequest {
+            api: self,
+            status: None,
+            type_: None,
+            unassigned_rooms: None,
+            page_size: None,
+            next_page_token: None,
+            location_id: None,
+        }
+    }
+
+    /// The OAuth scopes for `add`, any one of them grants access.
+    pub const ADD_SCOPES: &[&str] = &["room:write:admin"];
+
     /**
      * Add a Zoom Room.
      *
@@ -155,10 +321,14 @@ impl Rooms {
     ) -> Result<crate::types::AddRoomResponse> {
         let url = "/rooms".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/rooms", &[]))
     }
 
+    /// The OAuth scopes for `get_zr_profile`, any one of them grants access.
+    pub const GET_ZR_PROFILE_SCOPES: &[&str] = &["room:read:admin"];
+
     /**
      * Get Zoom Room profile.
      *
@@ -180,14 +350,16 @@ impl Rooms {
         &self,
         room_id: &str,
     ) -> Result<crate::types::GetZrProfileResponse> {
-        let url = format!(
-            "/rooms/{}",
-            crate::progenitor_support::encode_path(&room_id.to_string()),
-        );
+        let url = format!("/rooms/{}", self.client.encode_path(room_id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/rooms/{roomId}", &[("roomId", room_id.to_string())])
+        })
     }
 
+    /// The OAuth scopes for `delete_zoom`, any one of them grants access.
+    pub const DELETE_ZOOM_SCOPES: &[&str] = &["room:write:admin"];
+
     /**
      * Delete a Zoom Room.
      *
@@ -204,14 +376,20 @@ impl Rooms {
      * * `room_id: &str` -- Unique Identifier of a Zoom Room.
      */
     pub async fn delete_zoom(&self, room_id: &str) -> Result<crate::types::Domains> {
-        let url = format!(
-            "/rooms/{}",
-            crate::progenitor_support::encode_path(&room_id.to_string()),
-        );
+        let url = format!("/rooms/{}", self.client.encode_path(room_id),);
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/rooms/{roomId}",
+                &[("roomId", room_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `update_profile`, any one of them grants access.
+    pub const UPDATE_PROFILE_SCOPES: &[&str] = &["room:write:admin"];
+
     /**
      * Update a Zoom Room profile.
      *
@@ -233,16 +411,50 @@ impl Rooms {
         room_id: &str,
         body: &crate::types::UpdateRoomProfileRequest,
     ) -> Result<crate::types::Domains> {
-        let url = format!(
-            "/rooms/{}",
-            crate::progenitor_support::encode_path(&room_id.to_string()),
-        );
+        let url = format!("/rooms/{}", self.client.encode_path(room_id),);
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/rooms/{roomId}",
+                    &[("roomId", room_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update a Zoom Room profile.
+     *
+     * This function performs a `PATCH` to the `/rooms/{roomId}` endpoint.
+     *
+     * As opposed to `update_profile`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn update_profile_if_changed<C: serde::Serialize>(
+        &self,
+        room_id: &str,
+        current: &C,
+        body: &crate::types::UpdateRoomProfileRequest,
+    ) -> Result<Option<crate::types::Domains>> {
+        let url = format!("/rooms/{}", self.client.encode_path(room_id),);
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/rooms/{roomId}",
+                    &[("roomId", room_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `get_zr_setting`, any one of them grants access.
+    pub const GET_ZR_SETTING_SCOPES: &[&str] = &["room:read:admin"];
+
     /**
      * Get Zoom Room settings.
      *
@@ -267,20 +479,27 @@ impl Rooms {
         room_id: &str,
         setting_type: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !setting_type.is_empty() {
-            query_args.push(("setting_type".to_string(), setting_type.to_string()));
+            query_args.push(("setting_type", setting_type.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/rooms/{}/settings?{}",
-            crate::progenitor_support::encode_path(&room_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/rooms/{}/settings", self.client.encode_path(room_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/rooms/{roomId}/settings",
+                &[("roomId", room_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `update_zr_settings`, any one of them grants access.
+    pub const UPDATE_ZR_SETTINGS_SCOPES: &[&str] = &["room:write:admin"];
+
     /**
      * Update Zoom Room settings.
      *
@@ -301,20 +520,27 @@ impl Rooms {
      *   `signage`: Digital signage settings applied on the Zoom Room.
      */
     pub async fn update_zr_settings(&self, room_id: &str, setting_type: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !setting_type.is_empty() {
-            query_args.push(("setting_type".to_string(), setting_type.to_string()));
+            query_args.push(("setting_type", setting_type.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/rooms/{}/settings?{}",
-            crate::progenitor_support::encode_path(&room_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/rooms/{}/settings", self.client.encode_path(room_id)),
+            &query_args,
         );
 
-        self.client.patch(&url, None).await
+        self.client.patch(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "PATCH",
+                "/rooms/{roomId}/settings",
+                &[("roomId", room_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `list_zr_devices`, any one of them grants access.
+    pub const LIST_ZR_DEVICES_SCOPES: &[&str] = &["room:read:admin"];
+
     /**
      * List Zoom Room devices.
      *
@@ -336,14 +562,20 @@ impl Rooms {
         &self,
         room_id: &str,
     ) -> Result<crate::types::ListZrDevicesResponse> {
-        let url = format!(
-            "/rooms/{}/devices",
-            crate::progenitor_support::encode_path(&room_id.to_string()),
-        );
+        let url = format!("/rooms/{}/devices", self.client.encode_path(room_id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/rooms/{roomId}/devices",
+                &[("roomId", room_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `change_zr_location`, any one of them grants access.
+    pub const CHANGE_ZR_LOCATION_SCOPES: &[&str] = &["room:write:admin"];
+
     /**
      * Change a Zoom Room's location.
      *
@@ -365,16 +597,23 @@ impl Rooms {
         room_id: &str,
         body: &crate::types::ChangeZrLocationRequest,
     ) -> Result<()> {
-        let url = format!(
-            "/rooms/{}/location",
-            crate::progenitor_support::encode_path(&room_id.to_string()),
-        );
+        let url = format!("/rooms/{}/location", self.client.encode_path(room_id),);
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/rooms/{roomId}/location",
+                    &[("roomId", room_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `check`, any one of them grants access.
+    pub const CHECK_SCOPES: &[&str] = &["room:write:admin"];
+
     /**
      * Check-in or check-out of a Zoom Room.
      *
@@ -393,14 +632,37 @@ impl Rooms {
      * * `id: &str` -- User's first name.
      */
     pub async fn check(&self, id: &str, body: &crate::types::CheckInRoomsRequest) -> Result<()> {
-        let url = format!(
-            "/rooms/{}/events",
-            crate::progenitor_support::encode_path(&id.to_string()),
-        );
+        let url = format!("/rooms/{}/events", self.client.encode_path(id),);
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url("PATCH", "/rooms/{id}/events", &[("id", id.to_string())])
+            })
+    }
+
+    /**
+     * Check-in or check-out of a Zoom Room.
+     *
+     * This function performs a `PATCH` to the `/rooms/{id}/events` endpoint.
+     *
+     * As opposed to `check`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn check_if_changed<C: serde::Serialize>(
+        &self,
+        id: &str,
+        current: &C,
+        body: &crate::types::CheckInRoomsRequest,
+    ) -> Result<Option<()>> {
+        let url = format!("/rooms/{}/events", self.client.encode_path(id),);
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url("PATCH", "/rooms/{id}/events", &[("id", id.to_string())])
+            })
     }
 
     /**
@@ -433,29 +695,72 @@ impl Rooms {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<Vec<crate::types::Site>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !folder_id.is_empty() {
-            query_args.push(("folder_id".to_string(), folder_id.to_string()));
+            query_args.push(("folder_id", folder_id.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/rooms/digital_signage?{}", query_);
+        let url = crate::Client::url_with_query("/rooms/digital_signage", &query_args);
 
-        let resp: crate::types::ListDigitalSignageContentResponse =
-            self.client.get(&url, None).await?;
+        let resp: crate::types::ListDigitalSignageContentResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/rooms/digital_signage", &[]))?;
 
         // Return our response data.
         Ok(resp.contents)
     }
 
+    /**
+     * List digital signage contents.
+     *
+     * This function performs a `GET` to the `/rooms/digital_signage` endpoint.
+     *
+     * As opposed to `list_digital_signage_content`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
+     */
+    pub async fn list_digital_signage_content_page(
+        &self,
+        type_: &str,
+        folder_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::Site>, String)> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !folder_id.is_empty() {
+            query_args.push(("folder_id", folder_id.to_string()));
+        }
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::Client::url_with_query("/rooms/digital_signage", &query_args);
+
+        let resp: crate::types::ListDigitalSignageContentResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/rooms/digital_signage", &[]))?;
+
+        // Return our response data, and where the next page starts.
+        Ok((resp.contents, resp.next_page_token))
+    }
+
     /**
      * List digital signage contents.
      *
@@ -477,18 +782,20 @@ impl Rooms {
         type_: &str,
         folder_id: &str,
     ) -> Result<Vec<crate::types::Site>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !folder_id.is_empty() {
-            query_args.push(("folder_id".to_string(), folder_id.to_string()));
+            query_args.push(("folder_id", folder_id.to_string()));
         }
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/rooms/digital_signage?{}", query_);
+        let url = crate::Client::url_with_query("/rooms/digital_signage", &query_args);
 
-        let mut resp: crate::types::ListDigitalSignageContentResponse =
-            self.client.get(&url, None).await?;
+        let mut resp: crate::types::ListDigitalSignageContentResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/rooms/digital_signage", &[]))?;
 
         let mut contents = resp.contents;
         let mut page = resp.next_page_token;
@@ -521,6 +828,80 @@ impl Rooms {
         Ok(contents)
     }
 
+    /**
+     * List digital signage contents.
+     *
+     * This function performs a `GET` to the `/rooms/digital_signage` endpoint.
+     *
+     * As opposed to `list_digital_signage_content`, this function streams the items of all the pages, getting the next page only once the items of the last one are used up. The stream ends after the last page.
+     */
+    pub async fn list_digital_signage_content_stream(
+        &self,
+        type_: &str,
+        folder_id: &str,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::Site>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !folder_id.is_empty() {
+            query_args.push(("folder_id", folder_id.to_string()));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::Client::url_with_query("/rooms/digital_signage", &query_args);
+
+        self.client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListDigitalSignageContentResponse| {
+                    (resp.contents, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/rooms/digital_signage", &[]))
+    }
+
+    /**
+     * List digital signage contents.
+     *
+     * This function performs a `GET` to the `/rooms/digital_signage` endpoint.
+     *
+     * As opposed to `list_digital_signage_content_stream`, this function streams only the items `predicate` keeps. It too gets the next page only once the items of the last one are used up, so a stream that is cut short, for example with `take`, gets no more pages than it needs.
+     */
+    pub async fn list_digital_signage_content_filter<P: FnMut(&crate::types::Site) -> bool>(
+        &self,
+        type_: &str,
+        folder_id: &str,
+        mut predicate: P,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::Site>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !folder_id.is_empty() {
+            query_args.push(("folder_id", folder_id.to_string()));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::Client::url_with_query("/rooms/digital_signage", &query_args);
+
+        let items = self
+            .client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListDigitalSignageContentResponse| {
+                    (resp.contents, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/rooms/digital_signage", &[]))?;
+        Ok(futures::TryStreamExt::try_filter(items, move |item| {
+            futures::future::ready(predicate(item))
+        }))
+    }
+
+    /// The OAuth scopes for `manage_e_91_1signage`, any one of them grants access.
+    pub const MANAGE_E_91_1SIGNAGE_SCOPES: &[&str] = &["room:write:admin"];
+
     /**
      * Update E911 digital signage.
      *
@@ -537,6 +918,275 @@ impl Rooms {
      */
     pub async fn manage_e_91_1signage(&self) -> Result<crate::types::ManageE911SignageResponse> {
         let url = "/rooms/events".to_string();
-        self.client.patch(&url, None).await
+        self.client
+            .patch(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("PATCH", "/rooms/events", &[]))
+    }
+}
+
+/// A request to [`Rooms::list_zoom`], see [`Rooms::list_zoom_request`].
+pub struct ListZoomRequest<'a> {
+    api: &'a Rooms,
+    status: Option<crate::types::ListZoomRoomsStatus>,
+    type_: Option<crate::types::ListZoomRoomsType>,
+    unassigned_rooms: Option<bool>,
+    page_size: Option<i64>,
+    next_page_token: Option<String>,
+    location_id: Option<String>,
+}
+
+impl<'a> ListZoomRequest<'a> {
+    /// Set `status`.
+    pub fn status(mut self, status: crate::types::ListZoomRoomsStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Set `type_`.
+    pub fn type_(mut self, type_: crate::types::ListZoomRoomsType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    /// Set `unassigned_rooms`.
+    pub fn unassigned_rooms(mut self, unassigned_rooms: bool) -> Self {
+        self.unassigned_rooms = Some(unassigned_rooms);
+        self
+    }
+
+    /// Set `page_size`.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Set `next_page_token`.
+    pub fn next_page_token(mut self, next_page_token: &str) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    /// Set `location_id`.
+    pub fn location_id(mut self, location_id: &str) -> Self {
+        self.location_id = Some(location_id.to_string());
+        self
+    }
+
+    /// Send the request.
+    pub async fn send(self) -> Result<Vec<crate::types::ListZoomRoomsResponse>> {
+        self.api
+            .list_zoom(
+                self.status,
+                self.type_,
+                self.unassigned_rooms.unwrap_or_default(),
+                self.page_size.unwrap_or_default(),
+                self.next_page_token.as_deref().unwrap_or_default(),
+                self.location_id.as_deref().unwrap_or_default(),
+            )
+            .await
+    }
+}
+
+/// The functions of [`Rooms`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn RoomsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait RoomsApi: Send + Sync {
+    async fn list_zoom(
+        &self,
+        status: Option<crate::types::ListZoomRoomsStatus>,
+        type_: Option<crate::types::ListZoomRoomsType>,
+        unassigned_rooms: bool,
+        page_size: i64,
+        next_page_token: &str,
+        location_id: &str,
+    ) -> Result<Vec<crate::types::ListZoomRoomsResponse>>;
+    async fn list_zoom_page(
+        &self,
+        status: Option<crate::types::ListZoomRoomsStatus>,
+        type_: Option<crate::types::ListZoomRoomsType>,
+        unassigned_rooms: bool,
+        page_size: i64,
+        next_page_token: &str,
+        location_id: &str,
+    ) -> Result<(Vec<crate::types::ListZoomRoomsResponse>, String)>;
+    async fn list_all_zoom(
+        &self,
+        status: Option<crate::types::ListZoomRoomsStatus>,
+        type_: Option<crate::types::ListZoomRoomsType>,
+        unassigned_rooms: bool,
+        location_id: &str,
+    ) -> Result<Vec<crate::types::ListZoomRoomsResponse>>;
+    async fn add(
+        &self,
+        body: &crate::types::AddRoomRequest,
+    ) -> Result<crate::types::AddRoomResponse>;
+    async fn get_zr_profile(&self, room_id: &str) -> Result<crate::types::GetZrProfileResponse>;
+    async fn delete_zoom(&self, room_id: &str) -> Result<crate::types::Domains>;
+    async fn update_profile(
+        &self,
+        room_id: &str,
+        body: &crate::types::UpdateRoomProfileRequest,
+    ) -> Result<crate::types::Domains>;
+    async fn get_zr_setting(
+        &self,
+        room_id: &str,
+        setting_type: &str,
+    ) -> Result<crate::types::Domains>;
+    async fn update_zr_settings(&self, room_id: &str, setting_type: &str) -> Result<()>;
+    async fn list_zr_devices(&self, room_id: &str) -> Result<crate::types::ListZrDevicesResponse>;
+    async fn change_zr_location(
+        &self,
+        room_id: &str,
+        body: &crate::types::ChangeZrLocationRequest,
+    ) -> Result<()>;
+    async fn check(&self, id: &str, body: &crate::types::CheckInRoomsRequest) -> Result<()>;
+    async fn list_digital_signage_content(
+        &self,
+        type_: &str,
+        folder_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::Site>>;
+    async fn list_digital_signage_content_page(
+        &self,
+        type_: &str,
+        folder_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::Site>, String)>;
+    async fn list_all_digital_signage_content(
+        &self,
+        type_: &str,
+        folder_id: &str,
+    ) -> Result<Vec<crate::types::Site>>;
+    async fn manage_e_91_1signage(&self) -> Result<crate::types::ManageE911SignageResponse>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl RoomsApi for Rooms {
+    async fn list_zoom(
+        &self,
+        status: Option<crate::types::ListZoomRoomsStatus>,
+        type_: Option<crate::types::ListZoomRoomsType>,
+        unassigned_rooms: bool,
+        page_size: i64,
+        next_page_token: &str,
+        location_id: &str,
+    ) -> Result<Vec<crate::types::ListZoomRoomsResponse>> {
+        Rooms::list_zoom(
+            self,
+            status,
+            type_,
+            unassigned_rooms,
+            page_size,
+            next_page_token,
+            location_id,
+        )
+        .await
+    }
+    async fn list_zoom_page(
+        &self,
+        status: Option<crate::types::ListZoomRoomsStatus>,
+        type_: Option<crate::types::ListZoomRoomsType>,
+        unassigned_rooms: bool,
+        page_size: i64,
+        next_page_token: &str,
+        location_id: &str,
+    ) -> Result<(Vec<crate::types::ListZoomRoomsResponse>, String)> {
+        Rooms::list_zoom_page(
+            self,
+            status,
+            type_,
+            unassigned_rooms,
+            page_size,
+            next_page_token,
+            location_id,
+        )
+        .await
+    }
+    async fn list_all_zoom(
+        &self,
+        status: Option<crate::types::ListZoomRoomsStatus>,
+        type_: Option<crate::types::ListZoomRoomsType>,
+        unassigned_rooms: bool,
+        location_id: &str,
+    ) -> Result<Vec<crate::types::ListZoomRoomsResponse>> {
+        Rooms::list_all_zoom(self, status, type_, unassigned_rooms, location_id).await
+    }
+    async fn add(
+        &self,
+        body: &crate::types::AddRoomRequest,
+    ) -> Result<crate::types::AddRoomResponse> {
+        Rooms::add(self, body).await
+    }
+    async fn get_zr_profile(&self, room_id: &str) -> Result<crate::types::GetZrProfileResponse> {
+        Rooms::get_zr_profile(self, room_id).await
+    }
+    async fn delete_zoom(&self, room_id: &str) -> Result<crate::types::Domains> {
+        Rooms::delete_zoom(self, room_id).await
+    }
+    async fn update_profile(
+        &self,
+        room_id: &str,
+        body: &crate::types::UpdateRoomProfileRequest,
+    ) -> Result<crate::types::Domains> {
+        Rooms::update_profile(self, room_id, body).await
+    }
+    async fn get_zr_setting(
+        &self,
+        room_id: &str,
+        setting_type: &str,
+    ) -> Result<crate::types::Domains> {
+        Rooms::get_zr_setting(self, room_id, setting_type).await
+    }
+    async fn update_zr_settings(&self, room_id: &str, setting_type: &str) -> Result<()> {
+        Rooms::update_zr_settings(self, room_id, setting_type).await
+    }
+    async fn list_zr_devices(&self, room_id: &str) -> Result<crate::types::ListZrDevicesResponse> {
+        Rooms::list_zr_devices(self, room_id).await
+    }
+    async fn change_zr_location(
+        &self,
+        room_id: &str,
+        body: &crate::types::ChangeZrLocationRequest,
+    ) -> Result<()> {
+        Rooms::change_zr_location(self, room_id, body).await
+    }
+    async fn check(&self, id: &str, body: &crate::types::CheckInRoomsRequest) -> Result<()> {
+        Rooms::check(self, id, body).await
+    }
+    async fn list_digital_signage_content(
+        &self,
+        type_: &str,
+        folder_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::Site>> {
+        Rooms::list_digital_signage_content(self, type_, folder_id, page_size, next_page_token)
+            .await
+    }
+    async fn list_digital_signage_content_page(
+        &self,
+        type_: &str,
+        folder_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::Site>, String)> {
+        Rooms::list_digital_signage_content_page(self, type_, folder_id, page_size, next_page_token)
+            .await
+    }
+    async fn list_all_digital_signage_content(
+        &self,
+        type_: &str,
+        folder_id: &str,
+    ) -> Result<Vec<crate::types::Site>> {
+        Rooms::list_all_digital_signage_content(self, type_, folder_id).await
+    }
+    async fn manage_e_91_1signage(&self) -> Result<crate::types::ManageE911SignageResponse> {
+        Rooms::manage_e_91_1signage(self).await
     }
 }
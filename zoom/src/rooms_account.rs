@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct RoomsAccount {
     pub client: Client,
@@ -12,6 +10,17 @@ impl RoomsAccount {
         RoomsAccount { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        RoomsAccount {
+            client: self.client.with_retry(policy),
+        }
+    }
+
+    /// The OAuth scopes for `get_zr_account_profile`, any one of them grants access.
+    pub const GET_ZR_ACCOUNT_PROFILE_SCOPES: &[&str] = &["room:read:admin"];
+
     /**
      * Get Zoom Room account profile.
      *
@@ -27,9 +36,15 @@ impl RoomsAccount {
      */
     pub async fn get_zr_account_profile(&self) -> Result<crate::types::UpdateZrAccProfileRequest> {
         let url = "/rooms/account_profile".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/rooms/account_profile", &[]))
     }
 
+    /// The OAuth scopes for `update_zr_acc_profile`, any one of them grants access.
+    pub const UPDATE_ZR_ACC_PROFILE_SCOPES: &[&str] = &["room:write:admin"];
+
     /**
      * Update Zoom Room account profile.
      *
@@ -49,10 +64,33 @@ impl RoomsAccount {
     ) -> Result<crate::types::Domains> {
         let url = "/rooms/account_profile".to_string();
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("PATCH", "/rooms/account_profile", &[]))
     }
 
+    /**
+     * Update Zoom Room account profile.
+     *
+     * This function performs a `PATCH` to the `/rooms/account_profile` endpoint.
+     *
+     * As opposed to `update_zr_acc_profile`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn update_zr_acc_profile_if_changed<C: serde::Serialize>(
+        &self,
+        current: &C,
+        body: &crate::types::UpdateZrAccProfileRequest,
+    ) -> Result<Option<crate::types::Domains>> {
+        let url = "/rooms/account_profile".to_string();
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| crate::utils::pretty_url("PATCH", "/rooms/account_profile", &[]))
+    }
+
+    /// The OAuth scopes for `get_zr_account_setting`, any one of them grants access.
+    pub const GET_ZR_ACCOUNT_SETTING_SCOPES: &[&str] = &["room:read:admin"];
+
     /**
      * Get Zoom Room account settings.
      *
@@ -76,16 +114,21 @@ impl RoomsAccount {
         &self,
         setting_type: crate::types::SettingType,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !setting_type.to_string().is_empty() {
-            query_args.push(("setting_type".to_string(), setting_type.to_string()));
+            query_args.push(("setting_type", setting_type.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/rooms/account_settings?{}", query_);
+        let url = crate::Client::url_with_query("/rooms/account_settings", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/rooms/account_settings", &[]))
     }
 
+    /// The OAuth scopes for `update_zoom_room_acc_settings`, any one of them grants access.
+    pub const UPDATE_ZOOM_ROOM_ACC_SETTINGS_SCOPES: &[&str] = &["room:write:admin"];
+
     /**
      * Update Zoom Room account settings.
      *
@@ -109,13 +152,62 @@ impl RoomsAccount {
         &self,
         setting_type: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !setting_type.is_empty() {
-            query_args.push(("setting_type".to_string(), setting_type.to_string()));
+            query_args.push(("setting_type", setting_type.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/rooms/account_settings?{}", query_);
+        let url = crate::Client::url_with_query("/rooms/account_settings", &query_args);
 
-        self.client.patch(&url, None).await
+        self.client
+            .patch(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("PATCH", "/rooms/account_settings", &[]))
+    }
+}
+
+/// The functions of [`RoomsAccount`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn RoomsAccountApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait RoomsAccountApi: Send + Sync {
+    async fn get_zr_account_profile(&self) -> Result<crate::types::UpdateZrAccProfileRequest>;
+    async fn update_zr_acc_profile(
+        &self,
+        body: &crate::types::UpdateZrAccProfileRequest,
+    ) -> Result<crate::types::Domains>;
+    async fn get_zr_account_setting(
+        &self,
+        setting_type: crate::types::SettingType,
+    ) -> Result<crate::types::Domains>;
+    async fn update_zoom_room_acc_settings(
+        &self,
+        setting_type: &str,
+    ) -> Result<crate::types::Domains>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl RoomsAccountApi for RoomsAccount {
+    async fn get_zr_account_profile(&self) -> Result<crate::types::UpdateZrAccProfileRequest> {
+        RoomsAccount::get_zr_account_profile(self).await
+    }
+    async fn update_zr_acc_profile(
+        &self,
+        body: &crate::types::UpdateZrAccProfileRequest,
+    ) -> Result<crate::types::Domains> {
+        RoomsAccount::update_zr_acc_profile(self, body).await
+    }
+    async fn get_zr_account_setting(
+        &self,
+        setting_type: crate::types::SettingType,
+    ) -> Result<crate::types::Domains> {
+        RoomsAccount::get_zr_account_setting(self, setting_type).await
+    }
+    async fn update_zoom_room_acc_settings(
+        &self,
+        setting_type: &str,
+    ) -> Result<crate::types::Domains> {
+        RoomsAccount::update_zoom_room_acc_settings(self, setting_type).await
     }
 }
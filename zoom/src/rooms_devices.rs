@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct RoomsDevices {
     pub client: Client,
@@ -12,6 +10,14 @@ impl RoomsDevices {
         RoomsDevices { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        RoomsDevices {
+            client: self.client.with_retry(policy),
+        }
+    }
+
     /**
      * Change Zoom Rooms' app version.
      *
@@ -36,12 +42,49 @@ impl RoomsDevices {
     ) -> Result<()> {
         let url = format!(
             "/rooms/{}/devices/{}/app_version",
-            crate::progenitor_support::encode_path(&room_id.to_string()),
-            crate::progenitor_support::encode_path(&device_id.to_string()),
+            self.client.encode_path(room_id),
+            self.client.encode_path(device_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/rooms/{roomId}/devices/{deviceId}/app_version",
+                    &[
+                        ("roomId", room_id.to_string()),
+                        ("deviceId", device_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`RoomsDevices`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn RoomsDevicesApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait RoomsDevicesApi: Send + Sync {
+    async fn change_zoom_rooms_app_version(
+        &self,
+        room_id: &str,
+        device_id: &str,
+        body: &crate::types::ChangeZoomRoomsAppVersionRequest,
+    ) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl RoomsDevicesApi for RoomsDevices {
+    async fn change_zoom_rooms_app_version(
+        &self,
+        room_id: &str,
+        device_id: &str,
+        body: &crate::types::ChangeZoomRoomsAppVersionRequest,
+    ) -> Result<()> {
+        RoomsDevices::change_zoom_rooms_app_version(self, room_id, device_id, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct RoomsLocation {
     pub client: Client,
@@ -12,6 +10,17 @@ impl RoomsLocation {
         RoomsLocation { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        RoomsLocation {
+            client: self.client.with_retry(policy),
+        }
+    }
+
+    /// The OAuth scopes for `list_zr_locations`, any one of them grants access.
+    pub const LIST_ZR_LOCATIONS_SCOPES: &[&str] = &["room:read:admin"];
+
     /**
      * List Zoom Room locations.
      *
@@ -40,31 +49,72 @@ impl RoomsLocation {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<Vec<crate::types::AddAzrLocationResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !parent_location_id.is_empty() {
-            query_args.push((
-                "parent_location_id".to_string(),
-                parent_location_id.to_string(),
-            ));
+            query_args.push(("parent_location_id", parent_location_id.to_string()));
         }
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/rooms/locations?{}", query_);
+        let url = crate::Client::url_with_query("/rooms/locations", &query_args);
 
-        let resp: crate::types::ListZrLocationsResponseData = self.client.get(&url, None).await?;
+        let resp: crate::types::ListZrLocationsResponseData = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/rooms/locations", &[]))?;
 
         // Return our response data.
         Ok(resp.locations)
     }
 
+    /**
+     * List Zoom Room locations.
+     *
+     * This function performs a `GET` to the `/rooms/locations` endpoint.
+     *
+     * As opposed to `list_zr_locations`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
+     */
+    pub async fn list_zr_locations_page(
+        &self,
+        parent_location_id: &str,
+        type_: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::AddAzrLocationResponse>, String)> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        if !parent_location_id.is_empty() {
+            query_args.push(("parent_location_id", parent_location_id.to_string()));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::Client::url_with_query("/rooms/locations", &query_args);
+
+        let resp: crate::types::ListZrLocationsResponseData = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/rooms/locations", &[]))?;
+
+        // Return our response data, and where the next page starts.
+        Ok((resp.locations, resp.next_page_token))
+    }
+
     /**
      * List Zoom Room locations.
      *
@@ -85,21 +135,20 @@ impl RoomsLocation {
         parent_location_id: &str,
         type_: &str,
     ) -> Result<Vec<crate::types::AddAzrLocationResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !parent_location_id.is_empty() {
-            query_args.push((
-                "parent_location_id".to_string(),
-                parent_location_id.to_string(),
-            ));
+            query_args.push(("parent_location_id", parent_location_id.to_string()));
         }
         if !type_.is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+            query_args.push(("type", type_.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/rooms/locations?{}", query_);
+        let url = crate::Client::url_with_query("/rooms/locations", &query_args);
 
-        let mut resp: crate::types::ListZrLocationsResponseData =
-            self.client.get(&url, None).await?;
+        let mut resp: crate::types::ListZrLocationsResponseData = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/rooms/locations", &[]))?;
 
         let mut locations = resp.locations;
         let mut page = resp.next_page_token;
@@ -132,6 +181,82 @@ impl RoomsLocation {
         Ok(locations)
     }
 
+    /**
+     * List Zoom Room locations.
+     *
+     * This function performs a `GET` to the `/rooms/locations` endpoint.
+     *
+     * As opposed to `list_zr_locations`, this function streams the items of all the pages, getting the next page only once the items of the last one are used up. The stream ends after the last page.
+     */
+    pub async fn list_zr_locations_stream(
+        &self,
+        parent_location_id: &str,
+        type_: &str,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::AddAzrLocationResponse>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !parent_location_id.is_empty() {
+            query_args.push(("parent_location_id", parent_location_id.to_string()));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::Client::url_with_query("/rooms/locations", &query_args);
+
+        self.client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListZrLocationsResponseData| {
+                    (resp.locations, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/rooms/locations", &[]))
+    }
+
+    /**
+     * List Zoom Room locations.
+     *
+     * This function performs a `GET` to the `/rooms/locations` endpoint.
+     *
+     * As opposed to `list_zr_locations_stream`, this function streams only the items `predicate` keeps. It too gets the next page only once the items of the last one are used up, so a stream that is cut short, for example with `take`, gets no more pages than it needs.
+     */
+    pub async fn list_zr_locations_filter<
+        P: FnMut(&crate::types::AddAzrLocationResponse) -> bool,
+    >(
+        &self,
+        parent_location_id: &str,
+        type_: &str,
+        mut predicate: P,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::AddAzrLocationResponse>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !parent_location_id.is_empty() {
+            query_args.push(("parent_location_id", parent_location_id.to_string()));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type", type_.to_string()));
+        }
+        let url = crate::Client::url_with_query("/rooms/locations", &query_args);
+
+        let items = self
+            .client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListZrLocationsResponseData| {
+                    (resp.locations, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/rooms/locations", &[]))?;
+        Ok(futures::TryStreamExt::try_filter(items, move |item| {
+            futures::future::ready(predicate(item))
+        }))
+    }
+
+    /// The OAuth scopes for `add_azr_location`, any one of them grants access.
+    pub const ADD_AZR_LOCATION_SCOPES: &[&str] = &["room:write:admin"];
+
     /**
      * Add a location.
      *
@@ -153,10 +278,14 @@ impl RoomsLocation {
     ) -> Result<crate::types::AddAzrLocationResponse> {
         let url = "/rooms/locations".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/rooms/locations", &[]))
     }
 
+    /// The OAuth scopes for `get_zr_location_profile`, any one of them grants access.
+    pub const GET_ZR_LOCATION_PROFILE_SCOPES: &[&str] = &["room:read:admin"];
+
     /**
      * Get Zoom Room location profile.
      *
@@ -179,14 +308,20 @@ impl RoomsLocation {
         &self,
         location_id: &str,
     ) -> Result<crate::types::GetZrLocationProfileResponse> {
-        let url = format!(
-            "/rooms/locations/{}",
-            crate::progenitor_support::encode_path(&location_id.to_string()),
-        );
+        let url = format!("/rooms/locations/{}", self.client.encode_path(location_id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/rooms/locations/{locationId}",
+                &[("locationId", location_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `update_zr_location_profile`, any one of them grants access.
+    pub const UPDATE_ZR_LOCATION_PROFILE_SCOPES: &[&str] = &["room:write:admin"];
+
     /**
      * Update Zoom Room location profile.
      *
@@ -211,16 +346,50 @@ impl RoomsLocation {
         location_id: &str,
         body: &crate::types::GetZrLocationProfileResponse,
     ) -> Result<()> {
-        let url = format!(
-            "/rooms/locations/{}",
-            crate::progenitor_support::encode_path(&location_id.to_string()),
-        );
+        let url = format!("/rooms/locations/{}", self.client.encode_path(location_id),);
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/rooms/locations/{locationId}",
+                    &[("locationId", location_id.to_string())],
+                )
+            })
     }
 
+    /**
+     * Update Zoom Room location profile.
+     *
+     * This function performs a `PATCH` to the `/rooms/locations/{locationId}` endpoint.
+     *
+     * As opposed to `update_zr_location_profile`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn update_zr_location_profile_if_changed<C: serde::Serialize>(
+        &self,
+        location_id: &str,
+        current: &C,
+        body: &crate::types::GetZrLocationProfileResponse,
+    ) -> Result<Option<()>> {
+        let url = format!("/rooms/locations/{}", self.client.encode_path(location_id),);
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/rooms/locations/{locationId}",
+                    &[("locationId", location_id.to_string())],
+                )
+            })
+    }
+
+    /// The OAuth scopes for `get_zr_location_setting`, any one of them grants access.
+    pub const GET_ZR_LOCATION_SETTING_SCOPES: &[&str] = &["room:read:admin"];
+
     /**
      * Get location settings.
      *
@@ -245,20 +414,30 @@ impl RoomsLocation {
         location_id: &str,
         setting_type: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !setting_type.is_empty() {
-            query_args.push(("setting_type".to_string(), setting_type.to_string()));
+            query_args.push(("setting_type", setting_type.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/rooms/locations/{}/settings?{}",
-            crate::progenitor_support::encode_path(&location_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/rooms/locations/{}/settings",
+                self.client.encode_path(location_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/rooms/locations/{locationId}/settings",
+                &[("locationId", location_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `update_zr_location_settings`, any one of them grants access.
+    pub const UPDATE_ZR_LOCATION_SETTINGS_SCOPES: &[&str] = &["room:write:admin"];
+
     /**
      * Update location settings.
      *
@@ -283,20 +462,30 @@ impl RoomsLocation {
         location_id: &str,
         setting_type: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !setting_type.is_empty() {
-            query_args.push(("setting_type".to_string(), setting_type.to_string()));
+            query_args.push(("setting_type", setting_type.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/rooms/locations/{}/settings?{}",
-            crate::progenitor_support::encode_path(&location_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/rooms/locations/{}/settings",
+                self.client.encode_path(location_id)
+            ),
+            &query_args,
         );
 
-        self.client.patch(&url, None).await
+        self.client.patch(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "PATCH",
+                "/rooms/locations/{locationId}/settings",
+                &[("locationId", location_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `get_zr_location_structure`, any one of them grants access.
+    pub const GET_ZR_LOCATION_STRUCTURE_SCOPES: &[&str] = &["room:read:admin"];
+
     /**
      * Get Zoom Room location structure.
      *
@@ -313,9 +502,15 @@ impl RoomsLocation {
         &self,
     ) -> Result<crate::types::GetZrLocationStructureResponse> {
         let url = "/rooms/locations/structure".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/rooms/locations/structure", &[]))
     }
 
+    /// The OAuth scopes for `update_zoom_structure`, any one of them grants access.
+    pub const UPDATE_ZOOM_STRUCTURE_SCOPES: &[&str] = &["room:write:admin"];
+
     /**
      * Update Zoom Rooms location structure.
      *
@@ -334,10 +529,33 @@ impl RoomsLocation {
     ) -> Result<()> {
         let url = "/rooms/locations/structure".to_string();
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| crate::utils::pretty_url("PATCH", "/rooms/locations/structure", &[]))
+    }
+
+    /**
+     * Update Zoom Rooms location structure.
+     *
+     * This function performs a `PATCH` to the `/rooms/locations/structure` endpoint.
+     *
+     * As opposed to `update_zoom_structure`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn update_zoom_structure_if_changed<C: serde::Serialize>(
+        &self,
+        current: &C,
+        body: &crate::types::GetZrLocationStructureResponse,
+    ) -> Result<Option<()>> {
+        let url = "/rooms/locations/structure".to_string();
+        self.client
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| crate::utils::pretty_url("PATCH", "/rooms/locations/structure", &[]))
     }
 
+    /// The OAuth scopes for `change_parent_location`, any one of them grants access.
+    pub const CHANGE_PARENT_LOCATION_SCOPES: &[&str] = &["room:write:admin"];
+
     /**
      * Change the assigned parent location.
      *
@@ -361,11 +579,175 @@ impl RoomsLocation {
     ) -> Result<()> {
         let url = format!(
             "/rooms/locations/{}/location",
-            crate::progenitor_support::encode_path(&location_id.to_string()),
+            self.client.encode_path(location_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/rooms/locations/{locationId}/location",
+                    &[("locationId", location_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`RoomsLocation`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn RoomsLocationApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait RoomsLocationApi: Send + Sync {
+    async fn list_zr_locations(
+        &self,
+        parent_location_id: &str,
+        type_: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::AddAzrLocationResponse>>;
+    async fn list_zr_locations_page(
+        &self,
+        parent_location_id: &str,
+        type_: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::AddAzrLocationResponse>, String)>;
+    async fn list_all_zr_locations(
+        &self,
+        parent_location_id: &str,
+        type_: &str,
+    ) -> Result<Vec<crate::types::AddAzrLocationResponse>>;
+    async fn add_azr_location(
+        &self,
+        body: &crate::types::AddAzrLocationRequest,
+    ) -> Result<crate::types::AddAzrLocationResponse>;
+    async fn get_zr_location_profile(
+        &self,
+        location_id: &str,
+    ) -> Result<crate::types::GetZrLocationProfileResponse>;
+    async fn update_zr_location_profile(
+        &self,
+        location_id: &str,
+        body: &crate::types::GetZrLocationProfileResponse,
+    ) -> Result<()>;
+    async fn get_zr_location_setting(
+        &self,
+        location_id: &str,
+        setting_type: &str,
+    ) -> Result<crate::types::Domains>;
+    async fn update_zr_location_settings(
+        &self,
+        location_id: &str,
+        setting_type: &str,
+    ) -> Result<()>;
+    async fn get_zr_location_structure(
+        &self,
+    ) -> Result<crate::types::GetZrLocationStructureResponse>;
+    async fn update_zoom_structure(
+        &self,
+        body: &crate::types::GetZrLocationStructureResponse,
+    ) -> Result<()>;
+    async fn change_parent_location(
+        &self,
+        location_id: &str,
+        body: &crate::types::ChangeParentLocationRequest,
+    ) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl RoomsLocationApi for RoomsLocation {
+    async fn list_zr_locations(
+        &self,
+        parent_location_id: &str,
+        type_: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::AddAzrLocationResponse>> {
+        RoomsLocation::list_zr_locations(
+            self,
+            parent_location_id,
+            type_,
+            page_size,
+            next_page_token,
+        )
+        .await
+    }
+    async fn list_zr_locations_page(
+        &self,
+        parent_location_id: &str,
+        type_: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::AddAzrLocationResponse>, String)> {
+        RoomsLocation::list_zr_locations_page(
+            self,
+            parent_location_id,
+            type_,
+            page_size,
+            next_page_token,
+        )
+        .await
+    }
+    async fn list_all_zr_locations(
+        &self,
+        parent_location_id: &str,
+        type_: &str,
+    ) -> Result<Vec<crate::types::AddAzrLocationResponse>> {
+        RoomsLocation::list_all_zr_locations(self, parent_location_id, type_).await
+    }
+    async fn add_azr_location(
+        &self,
+        body: &crate::types::AddAzrLocationRequest,
+    ) -> Result<crate::types::AddAzrLocationResponse> {
+        RoomsLocation::add_azr_location(self, body).await
+    }
+    async fn get_zr_location_profile(
+        &self,
+        location_id: &str,
+    ) -> Result<crate::types::GetZrLocationProfileResponse> {
+        RoomsLocation::get_zr_location_profile(self, location_id).await
+    }
+    async fn update_zr_location_profile(
+        &self,
+        location_id: &str,
+        body: &crate::types::GetZrLocationProfileResponse,
+    ) -> Result<()> {
+        RoomsLocation::update_zr_location_profile(self, location_id, body).await
+    }
+    async fn get_zr_location_setting(
+        &self,
+        location_id: &str,
+        setting_type: &str,
+    ) -> Result<crate::types::Domains> {
+        RoomsLocation::get_zr_location_setting(self, location_id, setting_type).await
+    }
+    async fn update_zr_location_settings(
+        &self,
+        location_id: &str,
+        setting_type: &str,
+    ) -> Result<()> {
+        RoomsLocation::update_zr_location_settings(self, location_id, setting_type).await
+    }
+    async fn get_zr_location_structure(
+        &self,
+    ) -> Result<crate::types::GetZrLocationStructureResponse> {
+        RoomsLocation::get_zr_location_structure(self).await
+    }
+    async fn update_zoom_structure(
+        &self,
+        body: &crate::types::GetZrLocationStructureResponse,
+    ) -> Result<()> {
+        RoomsLocation::update_zoom_structure(self, body).await
+    }
+    async fn change_parent_location(
+        &self,
+        location_id: &str,
+        body: &crate::types::ChangeParentLocationRequest,
+    ) -> Result<()> {
+        RoomsLocation::change_parent_location(self, location_id, body).await
     }
 }
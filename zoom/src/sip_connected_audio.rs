@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct SipConnectedAudio {
     pub client: Client,
@@ -12,6 +10,17 @@ impl SipConnectedAudio {
         SipConnectedAudio { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        SipConnectedAudio {
+            client: self.client.with_retry(policy),
+        }
+    }
+
+    /// The OAuth scopes for `list_sip_trunk_numbers`, any one of them grants access.
+    pub const LIST_SIP_TRUNK_NUMBERS_SCOPES: &[&str] = &["sip_trunk:master"];
+
     /**
      * List SIP trunk numbers.
      *
@@ -30,9 +39,15 @@ impl SipConnectedAudio {
         &self,
     ) -> Result<crate::types::ListSipTrunkNumbersResponse> {
         let url = "/sip_trunk/numbers".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/sip_trunk/numbers", &[]))
     }
 
+    /// The OAuth scopes for `assign_sip_config`, any one of them grants access.
+    pub const ASSIGN_SIP_CONFIG_SCOPES: &[&str] = &["sip_trunk:master"];
+
     /**
      * Assign SIP trunk configuration.
      *
@@ -55,14 +70,54 @@ impl SipConnectedAudio {
     ) -> Result<()> {
         let url = format!(
             "/accounts/{}/sip_trunk/settings",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/accounts/{accountId}/sip_trunk/settings",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Assign SIP trunk configuration.
+     *
+     * This function performs a `PATCH` to the `/accounts/{accountId}/sip_trunk/settings` endpoint.
+     *
+     * As opposed to `assign_sip_config`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn assign_sip_config_if_changed<C: serde::Serialize>(
+        &self,
+        account_id: &str,
+        current: &C,
+        body: &crate::types::AssignSipConfigRequest,
+    ) -> Result<Option<()>> {
+        let url = format!(
+            "/accounts/{}/sip_trunk/settings",
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/accounts/{accountId}/sip_trunk/settings",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `assign_sip_trunk_numbers`, any one of them grants access.
+    pub const ASSIGN_SIP_TRUNK_NUMBERS_SCOPES: &[&str] = &["sip_trunk:master"];
+
     /**
      * Assign numbers.
      *
@@ -88,14 +143,24 @@ impl SipConnectedAudio {
     ) -> Result<()> {
         let url = format!(
             "/accounts/{}/sip_trunk/numbers",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/accounts/{accountId}/sip_trunk/numbers",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `delete_all_sip_numbers`, any one of them grants access.
+    pub const DELETE_ALL_SIP_NUMBERS_SCOPES: &[&str] = &["sip_trunk:master"];
+
     /**
      * Delete all numbers.
      *
@@ -116,12 +181,46 @@ impl SipConnectedAudio {
     pub async fn delete_all_sip_numbers(&self, account_id: &str) -> Result<()> {
         let url = format!(
             "/accounts/{}/sip_trunk/numbers",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/accounts/{accountId}/sip_trunk/numbers",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
+    /**
+     * Delete all numbers.
+     *
+     * This function performs a `DELETE` to the `/accounts/{accountId}/sip_trunk/numbers` endpoint.
+     *
+     * As opposed to `delete_all_sip_numbers`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_all_sip_numbers_if_exists(&self, account_id: &str) -> Result<bool> {
+        let url = format!(
+            "/accounts/{}/sip_trunk/numbers",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/accounts/{accountId}/sip_trunk/numbers",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /// The OAuth scopes for `list_sip_trunk`, any one of them grants access.
+    pub const LIST_SIP_TRUNK_SCOPES: &[&str] = &["sip_trunk:read:admin"];
+
     /**
      * List SIP trunks.
      *
@@ -142,12 +241,21 @@ impl SipConnectedAudio {
     ) -> Result<crate::types::ListSipTrunksResponseData> {
         let url = format!(
             "/accounts/{}/sip_trunk/trunks",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/accounts/{accountId}/sip_trunk/trunks",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `assign_sip_trunks`, any one of them grants access.
+    pub const ASSIGN_SIP_TRUNKS_SCOPES: &[&str] = &["sip_trunk:master"];
+
     /**
      * Assign SIP trunks.
      *
@@ -168,14 +276,25 @@ impl SipConnectedAudio {
     ) -> Result<crate::types::AssignSipTrunksResponseData> {
         let url = format!(
             "/accounts/{}/sip_trunk/trunks",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
+        crate::utils::check_items("sip_trunks", body.sip_trunks.len(), Some(1), Some(30))?;
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/accounts/{accountId}/sip_trunk/trunks",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `delete_sip_trunk`, any one of them grants access.
+    pub const DELETE_SIP_TRUNK_SCOPES: &[&str] = &["sip_trunk:master"];
+
     /**
      * Delete a SIP trunk.
      *
@@ -198,13 +317,25 @@ impl SipConnectedAudio {
     ) -> Result<crate::types::Domains> {
         let url = format!(
             "/accounts/{}/sip_trunk/trunks/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&trunk_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(trunk_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/accounts/{accountId}/sip_trunk/trunks/{trunkId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("trunkId", trunk_id.to_string()),
+                ],
+            )
+        })
     }
 
+    /// The OAuth scopes for `list_internal_callout_countries`, any one of them grants access.
+    pub const LIST_INTERNAL_CALLOUT_COUNTRIES_SCOPES: &[&str] = &["sip_trunk:master"];
+
     /**
      * List internal call-out countries.
      *
@@ -225,12 +356,21 @@ impl SipConnectedAudio {
     ) -> Result<crate::types::ListInternalCalloutCountriesResponse> {
         let url = format!(
             "/accounts/{}/sip_trunk/callout_countries",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/accounts/{accountId}/sip_trunk/callout_countries",
+                &[("accountId", account_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `add_callout_countries`, any one of them grants access.
+    pub const ADD_CALLOUT_COUNTRIES_SCOPES: &[&str] = &["sip_trunk:master"];
+
     /**
      * Add internal call-out countries.
      *
@@ -252,14 +392,30 @@ impl SipConnectedAudio {
     ) -> Result<crate::types::AddCalloutCountriesResponse> {
         let url = format!(
             "/accounts/{}/sip_trunk/callout_countries",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
+        crate::utils::check_items(
+            "callout_countries",
+            body.callout_countries.len(),
+            Some(1),
+            Some(30),
+        )?;
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/accounts/{accountId}/sip_trunk/callout_countries",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `delete_internal_call_out_country`, any one of them grants access.
+    pub const DELETE_INTERNAL_CALL_OUT_COUNTRY_SCOPES: &[&str] = &["sip_trunk:master"];
+
     /**
      * Delete internal call-out country.
      *
@@ -283,13 +439,58 @@ impl SipConnectedAudio {
     ) -> Result<()> {
         let url = format!(
             "/accounts/{}/sip_trunk/callout_countries/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&country_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(country_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/accounts/{accountId}/sip_trunk/callout_countries/{countryId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("countryId", country_id.to_string()),
+                ],
+            )
+        })
     }
 
+    /**
+     * Delete internal call-out country.
+     *
+     * This function performs a `DELETE` to the `/accounts/{accountId}/sip_trunk/callout_countries/{countryId}` endpoint.
+     *
+     * As opposed to `delete_internal_call_out_country`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_internal_call_out_country_if_exists(
+        &self,
+        account_id: &str,
+        country_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/accounts/{}/sip_trunk/callout_countries/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(country_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/accounts/{accountId}/sip_trunk/callout_countries/{countryId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("countryId", country_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The OAuth scopes for `list_internal_numbers`, any one of them grants access.
+    pub const LIST_INTERNAL_NUMBERS_SCOPES: &[&str] = &["sip_trunk:master"];
+
     /**
      * List internal numbers.
      *
@@ -315,26 +516,77 @@ impl SipConnectedAudio {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<Vec<crate::types::InternalNumbers>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/accounts/{}/sip_trunk/internal_numbers?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/accounts/{}/sip_trunk/internal_numbers",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
         );
 
-        let resp: crate::types::ListInternalNumbersResponse = self.client.get(&url, None).await?;
+        let resp: crate::types::ListInternalNumbersResponse =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/accounts/{accountId}/sip_trunk/internal_numbers",
+                    &[("accountId", account_id.to_string())],
+                )
+            })?;
 
         // Return our response data.
         Ok(resp.internal_numbers)
     }
 
+    /**
+     * List internal numbers.
+     *
+     * This function performs a `GET` to the `/accounts/{accountId}/sip_trunk/internal_numbers` endpoint.
+     *
+     * As opposed to `list_internal_numbers`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
+     */
+    pub async fn list_internal_numbers_page(
+        &self,
+        account_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::InternalNumbers>, String)> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/accounts/{}/sip_trunk/internal_numbers",
+                self.client.encode_path(account_id)
+            ),
+            &query_args,
+        );
+
+        let resp: crate::types::ListInternalNumbersResponse =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/accounts/{accountId}/sip_trunk/internal_numbers",
+                    &[("accountId", account_id.to_string())],
+                )
+            })?;
+
+        // Return our response data, and where the next page starts.
+        Ok((resp.internal_numbers, resp.next_page_token))
+    }
+
     /**
      * List internal numbers.
      *
@@ -356,11 +608,17 @@ impl SipConnectedAudio {
     ) -> Result<Vec<crate::types::InternalNumbers>> {
         let url = format!(
             "/accounts/{}/sip_trunk/internal_numbers",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
 
         let mut resp: crate::types::ListInternalNumbersResponse =
-            self.client.get(&url, None).await?;
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/accounts/{accountId}/sip_trunk/internal_numbers",
+                    &[("accountId", account_id.to_string())],
+                )
+            })?;
 
         let mut internal_numbers = resp.internal_numbers;
         let mut page = resp.next_page_token;
@@ -393,6 +651,82 @@ impl SipConnectedAudio {
         Ok(internal_numbers)
     }
 
+    /**
+     * List internal numbers.
+     *
+     * This function performs a `GET` to the `/accounts/{accountId}/sip_trunk/internal_numbers` endpoint.
+     *
+     * As opposed to `list_internal_numbers`, this function streams the items of all the pages, getting the next page only once the items of the last one are used up. The stream ends after the last page.
+     */
+    pub async fn list_internal_numbers_stream(
+        &self,
+        account_id: &str,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::InternalNumbers>>> {
+        let url = format!(
+            "/accounts/{}/sip_trunk/internal_numbers",
+            self.client.encode_path(account_id),
+        );
+
+        self.client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListInternalNumbersResponse| {
+                    (resp.internal_numbers, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/accounts/{accountId}/sip_trunk/internal_numbers",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * List internal numbers.
+     *
+     * This function performs a `GET` to the `/accounts/{accountId}/sip_trunk/internal_numbers` endpoint.
+     *
+     * As opposed to `list_internal_numbers_stream`, this function streams only the items `predicate` keeps. It too gets the next page only once the items of the last one are used up, so a stream that is cut short, for example with `take`, gets no more pages than it needs.
+     */
+    pub async fn list_internal_numbers_filter<P: FnMut(&crate::types::InternalNumbers) -> bool>(
+        &self,
+        account_id: &str,
+        mut predicate: P,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::InternalNumbers>>> {
+        let url = format!(
+            "/accounts/{}/sip_trunk/internal_numbers",
+            self.client.encode_path(account_id),
+        );
+
+        let items = self
+            .client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListInternalNumbersResponse| {
+                    (resp.internal_numbers, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/accounts/{accountId}/sip_trunk/internal_numbers",
+                    &[("accountId", account_id.to_string())],
+                )
+            })?;
+        Ok(futures::TryStreamExt::try_filter(items, move |item| {
+            futures::future::ready(predicate(item))
+        }))
+    }
+
+    /// The OAuth scopes for `add_internal_numbers`, any one of them grants access.
+    pub const ADD_INTERNAL_NUMBERS_SCOPES: &[&str] = &["sip_trunk:master"];
+
     /**
      * Add internal numbers.
      *
@@ -416,14 +750,30 @@ impl SipConnectedAudio {
     ) -> Result<crate::types::AddInternalNumbersResponseData> {
         let url = format!(
             "/accounts/{}/sip_trunk/internal_numbers",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
+            self.client.encode_path(account_id),
         );
+        crate::utils::check_items(
+            "internal_numbers",
+            body.internal_numbers.len(),
+            Some(1),
+            Some(30),
+        )?;
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/accounts/{accountId}/sip_trunk/internal_numbers",
+                    &[("accountId", account_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `delete_internal_number`, any one of them grants access.
+    pub const DELETE_INTERNAL_NUMBER_SCOPES: &[&str] = &["sip_trunk:master"];
+
     /**
      * Delete an internal number.
      *
@@ -445,10 +795,249 @@ impl SipConnectedAudio {
     pub async fn delete_internal_number(&self, account_id: &str, number_id: &str) -> Result<()> {
         let url = format!(
             "/accounts/{}/sip_trunk/internal_numbers/{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&number_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(number_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/accounts/{accountId}/sip_trunk/internal_numbers/{numberId}",
+                &[
+                    ("accountId", account_id.to_string()),
+                    ("numberId", number_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Delete an internal number.
+     *
+     * This function performs a `DELETE` to the `/accounts/{accountId}/sip_trunk/internal_numbers/{numberId}` endpoint.
+     *
+     * As opposed to `delete_internal_number`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_internal_number_if_exists(
+        &self,
+        account_id: &str,
+        number_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/accounts/{}/sip_trunk/internal_numbers/{}",
+            self.client.encode_path(account_id),
+            self.client.encode_path(number_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/accounts/{accountId}/sip_trunk/internal_numbers/{numberId}",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("numberId", number_id.to_string()),
+                    ],
+                )
+            })
+    }
+}
+
+/// The functions of [`SipConnectedAudio`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn SipConnectedAudioApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait SipConnectedAudioApi: Send + Sync {
+    async fn list_sip_trunk_numbers(&self) -> Result<crate::types::ListSipTrunkNumbersResponse>;
+    async fn assign_sip_config(
+        &self,
+        account_id: &str,
+        body: &crate::types::AssignSipConfigRequest,
+    ) -> Result<()>;
+    async fn assign_sip_trunk_numbers(
+        &self,
+        account_id: &str,
+        body: &crate::types::AssignSipTrunkNumbersRequest,
+    ) -> Result<()>;
+    async fn delete_all_sip_numbers(&self, account_id: &str) -> Result<()>;
+    async fn delete_all_sip_numbers_if_exists(&self, account_id: &str) -> Result<bool>;
+    async fn list_sip_trunk(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::ListSipTrunksResponseData>;
+    async fn assign_sip_trunks(
+        &self,
+        account_id: &str,
+        body: &crate::types::AssignSipTrunksRequestData,
+    ) -> Result<crate::types::AssignSipTrunksResponseData>;
+    async fn delete_sip_trunk(
+        &self,
+        account_id: &str,
+        trunk_id: &str,
+    ) -> Result<crate::types::Domains>;
+    async fn list_internal_callout_countries(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::ListInternalCalloutCountriesResponse>;
+    async fn add_callout_countries(
+        &self,
+        account_id: &str,
+        body: &crate::types::AddCalloutCountriesRequestData,
+    ) -> Result<crate::types::AddCalloutCountriesResponse>;
+    async fn delete_internal_call_out_country(
+        &self,
+        account_id: &str,
+        country_id: &str,
+    ) -> Result<()>;
+    async fn delete_internal_call_out_country_if_exists(
+        &self,
+        account_id: &str,
+        country_id: &str,
+    ) -> Result<bool>;
+    async fn list_internal_numbers(
+        &self,
+        account_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::InternalNumbers>>;
+    async fn list_internal_numbers_page(
+        &self,
+        account_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::InternalNumbers>, String)>;
+    async fn list_all_internal_numbers(
+        &self,
+        account_id: &str,
+    ) -> Result<Vec<crate::types::InternalNumbers>>;
+    async fn add_internal_numbers(
+        &self,
+        account_id: &str,
+        body: &crate::types::AddInternalNumbersRequest,
+    ) -> Result<crate::types::AddInternalNumbersResponseData>;
+    async fn delete_internal_number(&self, account_id: &str, number_id: &str) -> Result<()>;
+    async fn delete_internal_number_if_exists(
+        &self,
+        account_id: &str,
+        number_id: &str,
+    ) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl SipConnectedAudioApi for SipConnectedAudio {
+    async fn list_sip_trunk_numbers(&self) -> Result<crate::types::ListSipTrunkNumbersResponse> {
+        SipConnectedAudio::list_sip_trunk_numbers(self).await
+    }
+    async fn assign_sip_config(
+        &self,
+        account_id: &str,
+        body: &crate::types::AssignSipConfigRequest,
+    ) -> Result<()> {
+        SipConnectedAudio::assign_sip_config(self, account_id, body).await
+    }
+    async fn assign_sip_trunk_numbers(
+        &self,
+        account_id: &str,
+        body: &crate::types::AssignSipTrunkNumbersRequest,
+    ) -> Result<()> {
+        SipConnectedAudio::assign_sip_trunk_numbers(self, account_id, body).await
+    }
+    async fn delete_all_sip_numbers(&self, account_id: &str) -> Result<()> {
+        SipConnectedAudio::delete_all_sip_numbers(self, account_id).await
+    }
+    async fn delete_all_sip_numbers_if_exists(&self, account_id: &str) -> Result<bool> {
+        SipConnectedAudio::delete_all_sip_numbers_if_exists(self, account_id).await
+    }
+    async fn list_sip_trunk(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::ListSipTrunksResponseData> {
+        SipConnectedAudio::list_sip_trunk(self, account_id).await
+    }
+    async fn assign_sip_trunks(
+        &self,
+        account_id: &str,
+        body: &crate::types::AssignSipTrunksRequestData,
+    ) -> Result<crate::types::AssignSipTrunksResponseData> {
+        SipConnectedAudio::assign_sip_trunks(self, account_id, body).await
+    }
+    async fn delete_sip_trunk(
+        &self,
+        account_id: &str,
+        trunk_id: &str,
+    ) -> Result<crate::types::Domains> {
+        SipConnectedAudio::delete_sip_trunk(self, account_id, trunk_id).await
+    }
+    async fn list_internal_callout_countries(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::ListInternalCalloutCountriesResponse> {
+        SipConnectedAudio::list_internal_callout_countries(self, account_id).await
+    }
+    async fn add_callout_countries(
+        &self,
+        account_id: &str,
+        body: &crate::types::AddCalloutCountriesRequestData,
+    ) -> Result<crate::types::AddCalloutCountriesResponse> {
+        SipConnectedAudio::add_callout_countries(self, account_id, body).await
+    }
+    async fn delete_internal_call_out_country(
+        &self,
+        account_id: &str,
+        country_id: &str,
+    ) -> Result<()> {
+        SipConnectedAudio::delete_internal_call_out_country(self, account_id, country_id).await
+    }
+    async fn delete_internal_call_out_country_if_exists(
+        &self,
+        account_id: &str,
+        country_id: &str,
+    ) -> Result<bool> {
+        SipConnectedAudio::delete_internal_call_out_country_if_exists(self, account_id, country_id)
+            .await
+    }
+    async fn list_internal_numbers(
+        &self,
+        account_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::InternalNumbers>> {
+        SipConnectedAudio::list_internal_numbers(self, account_id, page_size, next_page_token).await
+    }
+    async fn list_internal_numbers_page(
+        &self,
+        account_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::InternalNumbers>, String)> {
+        SipConnectedAudio::list_internal_numbers_page(self, account_id, page_size, next_page_token)
+            .await
+    }
+    async fn list_all_internal_numbers(
+        &self,
+        account_id: &str,
+    ) -> Result<Vec<crate::types::InternalNumbers>> {
+        SipConnectedAudio::list_all_internal_numbers(self, account_id).await
+    }
+    async fn add_internal_numbers(
+        &self,
+        account_id: &str,
+        body: &crate::types::AddInternalNumbersRequest,
+    ) -> Result<crate::types::AddInternalNumbersResponseData> {
+        SipConnectedAudio::add_internal_numbers(self, account_id, body).await
+    }
+    async fn delete_internal_number(&self, account_id: &str, number_id: &str) -> Result<()> {
+        SipConnectedAudio::delete_internal_number(self, account_id, number_id).await
+    }
+    async fn delete_internal_number_if_exists(
+        &self,
+        account_id: &str,
+        number_id: &str,
+    ) -> Result<bool> {
+        SipConnectedAudio::delete_internal_number_if_exists(self, account_id, number_id).await
     }
 }
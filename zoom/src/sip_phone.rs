@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct SipPhone {
     pub client: Client,
@@ -12,6 +10,17 @@ impl SipPhone {
         SipPhone { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        SipPhone {
+            client: self.client.with_retry(policy),
+        }
+    }
+
+    /// The OAuth scopes for `list`, any one of them grants access.
+    pub const LIST_SCOPES: &[&str] = &["sip_phone:read:admin"];
+
     /**
      * List SIP phones.
      *
@@ -41,28 +50,72 @@ impl SipPhone {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<Vec<crate::types::Phones>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if page_number > 0 {
-            query_args.push(("page_number".to_string(), page_number.to_string()));
+            query_args.push(("page_number", page_number.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !search_key.is_empty() {
-            query_args.push(("search_key".to_string(), search_key.to_string()));
+            query_args.push(("search_key", search_key.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/sip_phones?{}", query_);
+        let url = crate::Client::url_with_query("/sip_phones", &query_args);
 
-        let resp: crate::types::ListSipPhonesResponse = self.client.get(&url, None).await?;
+        let resp: crate::types::ListSipPhonesResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/sip_phones", &[]))?;
 
         // Return our response data.
         Ok(resp.phones)
     }
 
+    /**
+     * List SIP phones.
+     *
+     * This function performs a `GET` to the `/sip_phones` endpoint.
+     *
+     * As opposed to `list`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
+     */
+    pub async fn list_page(
+        &self,
+        page_number: i64,
+        search_key: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::Phones>, String)> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        if page_number > 0 {
+            query_args.push(("page_number", page_number.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        if !search_key.is_empty() {
+            query_args.push(("search_key", search_key.to_string()));
+        }
+        let url = crate::Client::url_with_query("/sip_phones", &query_args);
+
+        let resp: crate::types::ListSipPhonesResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/sip_phones", &[]))?;
+
+        // Return our response data, and where the next page starts.
+        Ok((resp.phones, resp.next_page_token))
+    }
+
     /**
      * List SIP phones.
      *
@@ -78,14 +131,17 @@ impl SipPhone {
      *
      */
     pub async fn list_all(&self, search_key: &str) -> Result<Vec<crate::types::Phones>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !search_key.is_empty() {
-            query_args.push(("search_key".to_string(), search_key.to_string()));
+            query_args.push(("search_key", search_key.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/sip_phones?{}", query_);
+        let url = crate::Client::url_with_query("/sip_phones", &query_args);
 
-        let mut resp: crate::types::ListSipPhonesResponse = self.client.get(&url, None).await?;
+        let mut resp: crate::types::ListSipPhonesResponse = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/sip_phones", &[]))?;
 
         let mut phones = resp.phones;
         let mut page = resp.next_page_token;
@@ -118,6 +174,68 @@ impl SipPhone {
         Ok(phones)
     }
 
+    /**
+     * List SIP phones.
+     *
+     * This function performs a `GET` to the `/sip_phones` endpoint.
+     *
+     * As opposed to `list`, this function streams the items of all the pages, getting the next page only once the items of the last one are used up. The stream ends after the last page.
+     */
+    pub async fn list_stream(
+        &self,
+        search_key: &str,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::Phones>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !search_key.is_empty() {
+            query_args.push(("search_key", search_key.to_string()));
+        }
+        let url = crate::Client::url_with_query("/sip_phones", &query_args);
+
+        self.client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListSipPhonesResponse| (resp.phones, resp.next_page_token),
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/sip_phones", &[]))
+    }
+
+    /**
+     * List SIP phones.
+     *
+     * This function performs a `GET` to the `/sip_phones` endpoint.
+     *
+     * As opposed to `list_stream`, this function streams only the items `predicate` keeps. It too gets the next page only once the items of the last one are used up, so a stream that is cut short, for example with `take`, gets no more pages than it needs.
+     */
+    pub async fn list_filter<P: FnMut(&crate::types::Phones) -> bool>(
+        &self,
+        search_key: &str,
+        mut predicate: P,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::Phones>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !search_key.is_empty() {
+            query_args.push(("search_key", search_key.to_string()));
+        }
+        let url = crate::Client::url_with_query("/sip_phones", &query_args);
+
+        let items = self
+            .client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListSipPhonesResponse| (resp.phones, resp.next_page_token),
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/sip_phones", &[]))?;
+        Ok(futures::TryStreamExt::try_filter(items, move |item| {
+            futures::future::ready(predicate(item))
+        }))
+    }
+
+    /// The OAuth scopes for `create`, any one of them grants access.
+    pub const CREATE_SCOPES: &[&str] = &["sip_phone:write:admin"];
+
     /**
      * Enable SIP phone.
      *
@@ -135,10 +253,14 @@ impl SipPhone {
     pub async fn create(&self, body: &crate::types::CreateSipPhoneRequest) -> Result<()> {
         let url = "/sip_phones".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/sip_phones", &[]))
     }
 
+    /// The OAuth scopes for `delete`, any one of them grants access.
+    pub const DELETE_SCOPES: &[&str] = &["sip_phone:read:admin"];
+
     /**
      * Delete SIP phone.
      *
@@ -156,14 +278,42 @@ impl SipPhone {
      * * `phone_id: &str` -- Unique Identifier of the SIP Phone. It can be retrieved from the List SIP Phones API.
      */
     pub async fn delete(&self, phone_id: &str) -> Result<()> {
-        let url = format!(
-            "/sip_phones/{}",
-            crate::progenitor_support::encode_path(&phone_id.to_string()),
-        );
+        let url = format!("/sip_phones/{}", self.client.encode_path(phone_id),);
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/sip_phones/{phoneId}",
+                &[("phoneId", phone_id.to_string())],
+            )
+        })
     }
 
+    /**
+     * Delete SIP phone.
+     *
+     * This function performs a `DELETE` to the `/sip_phones/{phoneId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(&self, phone_id: &str) -> Result<bool> {
+        let url = format!("/sip_phones/{}", self.client.encode_path(phone_id),);
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/sip_phones/{phoneId}",
+                    &[("phoneId", phone_id.to_string())],
+                )
+            })
+    }
+
+    /// The OAuth scopes for `update`, any one of them grants access.
+    pub const UPDATE_SCOPES: &[&str] = &["sip_phone:write:admin"];
+
     /**
      * Update SIP phone.
      *
@@ -185,13 +335,117 @@ impl SipPhone {
         phone_id: &str,
         body: &crate::types::UpdateSipPhoneRequest,
     ) -> Result<()> {
-        let url = format!(
-            "/sip_phones/{}",
-            crate::progenitor_support::encode_path(&phone_id.to_string()),
-        );
+        let url = format!("/sip_phones/{}", self.client.encode_path(phone_id),);
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/sip_phones/{phoneId}",
+                    &[("phoneId", phone_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update SIP phone.
+     *
+     * This function performs a `PATCH` to the `/sip_phones/{phoneId}` endpoint.
+     *
+     * As opposed to `update`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn update_if_changed<C: serde::Serialize>(
+        &self,
+        phone_id: &str,
+        current: &C,
+        body: &crate::types::UpdateSipPhoneRequest,
+    ) -> Result<Option<()>> {
+        let url = format!("/sip_phones/{}", self.client.encode_path(phone_id),);
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/sip_phones/{phoneId}",
+                    &[("phoneId", phone_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`SipPhone`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn SipPhoneApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait SipPhoneApi: Send + Sync {
+    async fn list(
+        &self,
+        page_number: i64,
+        search_key: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::Phones>>;
+    async fn list_page(
+        &self,
+        page_number: i64,
+        search_key: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::Phones>, String)>;
+    async fn list_all(&self, search_key: &str) -> Result<Vec<crate::types::Phones>>;
+    async fn create(&self, body: &crate::types::CreateSipPhoneRequest) -> Result<()>;
+    async fn delete(&self, phone_id: &str) -> Result<()>;
+    async fn delete_if_exists(&self, phone_id: &str) -> Result<bool>;
+    async fn update(
+        &self,
+        phone_id: &str,
+        body: &crate::types::UpdateSipPhoneRequest,
+    ) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl SipPhoneApi for SipPhone {
+    async fn list(
+        &self,
+        page_number: i64,
+        search_key: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::Phones>> {
+        SipPhone::list(self, page_number, search_key, page_size, next_page_token).await
+    }
+    async fn list_page(
+        &self,
+        page_number: i64,
+        search_key: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::Phones>, String)> {
+        SipPhone::list_page(self, page_number, search_key, page_size, next_page_token).await
+    }
+    async fn list_all(&self, search_key: &str) -> Result<Vec<crate::types::Phones>> {
+        SipPhone::list_all(self, search_key).await
+    }
+    async fn create(&self, body: &crate::types::CreateSipPhoneRequest) -> Result<()> {
+        SipPhone::create(self, body).await
+    }
+    async fn delete(&self, phone_id: &str) -> Result<()> {
+        SipPhone::delete(self, phone_id).await
+    }
+    async fn delete_if_exists(&self, phone_id: &str) -> Result<bool> {
+        SipPhone::delete_if_exists(self, phone_id).await
+    }
+    async fn update(
+        &self,
+        phone_id: &str,
+        body: &crate::types::UpdateSipPhoneRequest,
+    ) -> Result<()> {
+        SipPhone::update(self, phone_id, body).await
     }
 }
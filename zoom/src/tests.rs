@@ -83,3 +83,3095 @@ fn test_deserialize_recorded_meetings() {
         deserialized.from.unwrap()
     );
 }
+
+#[tokio::test]
+async fn test_stream_json_call_logs() {
+    use futures::StreamExt;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    // Far more than the socket buffers and the client's channels hold, so the
+    // server can only write all of it if the client reads ahead of the caller.
+    const CALL_LOGS: usize = 16 * 1024;
+    const PADDING: usize = 1024;
+    const BODY: u64 = (CALL_LOGS * PADDING) as u64;
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let host = format!("http://{}", listener.local_addr().unwrap());
+    let (first_item_tx, first_item_rx) = tokio::sync::oneshot::channel::<()>();
+    let written = std::sync::Arc::new(std::sync::atomic::AtomicU64::new(0));
+
+    let w = written.clone();
+    tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+
+        // Read the request headers, we do not care what they say.
+        let mut request = Vec::new();
+        let mut buf = [0; 1024];
+        while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+            let n = socket.read(&mut buf).await.unwrap();
+            request.extend_from_slice(&buf[..n]);
+        }
+
+        let chunk = |s: String| format!("{:x}\r\n{}\r\n", s.len(), s).into_bytes();
+        let padding = "x".repeat(PADDING);
+
+        socket
+            .write_all(
+                b"HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ntransfer-encoding: chunked\r\n\r\n",
+            )
+            .await
+            .unwrap();
+        socket
+            .write_all(&chunk(
+                r#"{"next_page_token":"","call_logs":[{"call_id":"0"}"#.to_string(),
+            ))
+            .await
+            .unwrap();
+
+        // Hold the rest of the body back until the first item has been seen, so
+        // we know the items are parsed as they arrive.
+        first_item_rx.await.unwrap();
+
+        for i in 1..CALL_LOGS {
+            let call_log = chunk(format!(r#",{{"call_id":"{}","padding":"{}"}}"#, i, padding));
+            socket.write_all(&call_log).await.unwrap();
+            w.fetch_add(call_log.len() as u64, std::sync::atomic::Ordering::SeqCst);
+        }
+        socket.write_all(&chunk("]}".to_string())).await.unwrap();
+        socket.write_all(b"0\r\n\r\n").await.unwrap();
+    });
+
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+
+    let result = tokio::time::timeout(std::time::Duration::from_secs(60), async move {
+        let mut call_logs = Box::pin(
+            zoom.phone()
+                .account_call_logs_stream_json(
+                    0,
+                    "",
+                    "",
+                    "",
+                    "",
+                    "",
+                    crate::types::TimeType::StartTime,
+                    "",
+                )
+                .await
+                .unwrap(),
+        );
+
+        let first = call_logs.next().await.unwrap().unwrap();
+        assert_eq!(first.call_id, "0");
+        first_item_tx.send(()).unwrap();
+
+        // While the caller takes no more items, the client stops reading the
+        // body once its channels are full, and the server can not write more
+        // than the socket buffers hold: the body is not read into memory.
+        let mut before = 0;
+        loop {
+            tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+            let now = written.load(std::sync::atomic::Ordering::SeqCst);
+            if now == before {
+                break;
+            }
+            before = now;
+        }
+        assert!(before < BODY / 2, "{} of {} bytes written", before, BODY);
+
+        let mut count = 1;
+        while let Some(call_log) = call_logs.next().await {
+            assert_eq!(call_log.unwrap().call_id, count.to_string());
+            count += 1;
+        }
+        count
+    })
+    .await
+    .expect("streaming the call logs timed out");
+
+    assert_eq!(result, CALL_LOGS);
+}
+
+/// Serve each of the given raw HTTP responses on its own connection, and send
+/// back the raw requests as they come in.
+async fn mock_server(
+    responses: Vec<String>,
+) -> (String, tokio::sync::mpsc::UnboundedReceiver<String>) {
+    use tokio::io::AsyncWriteExt;
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let host = format!("http://{}", listener.local_addr().unwrap());
+    let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+
+    tokio::spawn(async move {
+        for response in responses {
+            let (mut socket, _) = listener.accept().await.unwrap();
+
+            let request = read_request(&mut socket).await;
+            tx.send(request).unwrap();
+            socket.write_all(response.as_bytes()).await.unwrap();
+        }
+    });
+
+    (host, rx)
+}
+
+/// Read one raw HTTP request, including its body, from the socket.
+async fn read_request(socket: &mut tokio::net::TcpStream) -> String {
+    use tokio::io::AsyncReadExt;
+
+    let mut request = Vec::new();
+    let mut buf = [0; 1024];
+    loop {
+        let n = socket.read(&mut buf).await.unwrap();
+        request.extend_from_slice(&buf[..n]);
+
+        let request = String::from_utf8_lossy(&request);
+        if let Some(i) = request.find("\r\n\r\n") {
+            let content_length = request[..i]
+                .lines()
+                .find_map(|l| {
+                    let (k, v) = l.split_once(':')?;
+                    if k.eq_ignore_ascii_case("content-length") {
+                        v.trim().parse::<usize>().ok()
+                    } else {
+                        None
+                    }
+                })
+                .unwrap_or(0);
+            // A streamed body is chunked, and ends with an empty chunk.
+            let chunked = request[..i]
+                .to_lowercase()
+                .contains("transfer-encoding: chunked");
+            if chunked && request.ends_with("0\r\n\r\n") {
+                break;
+            }
+            if !chunked && request.len() >= i + 4 + content_length {
+                break;
+            }
+        }
+        if n == 0 {
+            break;
+        }
+    }
+
+    String::from_utf8_lossy(&request).to_string()
+}
+
+/// Build a raw HTTP response with the given extra headers and JSON body.
+fn mock_response(headers: &str, body: &str) -> String {
+    format!(
+        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: \
+         close\r\n{}\r\n{}",
+        body.len(),
+        headers,
+        body
+    )
+}
+
+#[tokio::test]
+async fn test_cookie_store() {
+    let (host, mut requests) = mock_server(vec![
+        mock_response("set-cookie: session=abc123; Path=/\r\n", "{}"),
+        mock_response("", "{}"),
+    ])
+    .await;
+
+    let zoom = crate::Client::new("", "", "", "token", "")
+        .with_host(host)
+        .with_cookie_store();
+
+    zoom.phone().setting("").await.unwrap();
+    let first = requests.recv().await.unwrap();
+    assert!(!first.to_lowercase().contains("cookie:"));
+
+    zoom.phone().setting("").await.unwrap();
+    let second = requests.recv().await.unwrap();
+    assert!(second.to_lowercase().contains("cookie: session=abc123"));
+}
+
+#[test]
+fn test_list_account_numbers_scopes() {
+    assert_eq!(
+        crate::phone::Phone::LIST_ACCOUNT_NUMBERS_SCOPES,
+        &["phone:read:admin"]
+    );
+
+    assert!(crate::utils::has_scope(
+        "meeting:read phone:read:admin",
+        crate::phone::Phone::LIST_ACCOUNT_NUMBERS_SCOPES
+    ));
+    assert!(!crate::utils::has_scope(
+        "phone:read",
+        crate::phone::Phone::LIST_ACCOUNT_NUMBERS_SCOPES
+    ));
+}
+
+#[tokio::test]
+async fn test_token_invalid_grant() {
+    let resp = http::Response::builder()
+        .status(400)
+        .body(r#"{"error":"invalid_grant","error_description":"Invalid Token!"}"#)
+        .unwrap();
+
+    let err = crate::Client::parse_access_token(resp.into())
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast_ref::<crate::ClientError>(),
+        Some(&crate::ClientError::AuthExpired {
+            error: "invalid_grant".to_string(),
+            error_description: "Invalid Token!".to_string(),
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_max_concurrent_requests() {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let host = format!("http://{}", listener.local_addr().unwrap());
+
+    let in_flight = Arc::new(AtomicUsize::new(0));
+    let max_in_flight = Arc::new(AtomicUsize::new(0));
+
+    let (i, m) = (in_flight.clone(), max_in_flight.clone());
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let (in_flight, max_in_flight) = (i.clone(), m.clone());
+            tokio::spawn(async move {
+                let mut buf = [0; 4096];
+                let _ = socket.read(&mut buf).await.unwrap();
+
+                let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                max_in_flight.fetch_max(now, Ordering::SeqCst);
+
+                // Be slow, so the requests would overlap if they could.
+                tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                socket
+                    .write_all(mock_response("", "{}").as_bytes())
+                    .await
+                    .unwrap();
+            });
+        }
+    });
+
+    let zoom = crate::Client::new("", "", "", "token", "")
+        .with_host(host)
+        .with_max_concurrent_requests(2);
+
+    let mut handles = Vec::new();
+    for _ in 0..8 {
+        let zoom = zoom.clone();
+        handles.push(tokio::spawn(async move {
+            zoom.phone().setting("").await.unwrap()
+        }));
+    }
+    for h in handles {
+        h.await.unwrap();
+    }
+
+    assert_eq!(max_in_flight.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_max_concurrent_requests_zero() {
+    let (host, mut requests) = mock_server(vec![mock_response("", "{}")]).await;
+
+    // A limit of 0 is no limit, rather than a client that waits forever.
+    let zoom = crate::Client::new("", "", "", "token", "")
+        .with_host(host)
+        .with_max_concurrent_requests(0);
+    tokio::time::timeout(std::time::Duration::from_secs(5), zoom.phone().setting(""))
+        .await
+        .unwrap()
+        .unwrap();
+    requests.recv().await.unwrap();
+}
+
+/// A clock whose sleeps last until the test lets them end.
+#[derive(Clone, Default)]
+struct GatedClock {
+    clock: crate::MockClock,
+    sleeping: std::sync::Arc<tokio::sync::Notify>,
+    wake: std::sync::Arc<tokio::sync::Notify>,
+}
+
+#[async_trait::async_trait]
+impl crate::Clock for GatedClock {
+    fn now(&self) -> std::time::Instant {
+        self.clock.now()
+    }
+
+    fn now_utc(&self) -> chrono::DateTime<chrono::Utc> {
+        self.clock.now_utc()
+    }
+
+    async fn sleep(&self, duration: std::time::Duration) {
+        self.sleeping.notify_one();
+        self.wake.notified().await;
+        self.clock.advance(duration);
+    }
+}
+
+#[tokio::test]
+async fn test_max_concurrent_requests_retry_wait() {
+    let (host, mut requests) = mock_server(vec![
+        mock_response("retry-after: 120\r\n", "{}").replacen("200 OK", "429 Too Many Requests", 1),
+        mock_response("", "{}"),
+        mock_response("", "{}"),
+    ])
+    .await;
+    let clock = GatedClock::default();
+    let zoom = crate::Client::new("", "", "", "token", "")
+        .with_host(host)
+        .with_clock(clock.clone())
+        .with_retry(crate::RetryPolicy::new(
+            1,
+            std::time::Duration::from_secs(1),
+        ))
+        .with_max_concurrent_requests(1);
+
+    let first = tokio::spawn({
+        let zoom = zoom.clone();
+        async move { zoom.phone().setting("").await.unwrap() }
+    });
+    clock.sleeping.notified().await;
+
+    // The request waiting out its Retry-After does not hold up another one.
+    tokio::time::timeout(std::time::Duration::from_secs(5), zoom.phone().setting(""))
+        .await
+        .unwrap()
+        .unwrap();
+
+    clock.wake.notify_one();
+    first.await.unwrap();
+    for _ in 0..3 {
+        requests.recv().await.unwrap();
+    }
+}
+
+#[tokio::test]
+async fn test_max_concurrent_requests_rate_limit_wait() {
+    let (host, mut requests) = mock_server(vec![
+        mock_response("", "{}"),
+        mock_response("", "{}"),
+        mock_response("", "{}"),
+    ])
+    .await;
+    let clock = GatedClock::default();
+    let zoom = crate::Client::new("", "", "", "token", "")
+        .with_host(host)
+        .with_clock(clock.clone())
+        .with_max_concurrent_requests(1);
+    let limited =
+        zoom.with_rate_limit(crate::RateLimit::new(1, std::time::Duration::from_secs(60)));
+
+    limited.phone().setting("").await.unwrap();
+    let second = tokio::spawn({
+        let limited = limited.clone();
+        async move { limited.phone().setting("").await.unwrap() }
+    });
+    clock.sleeping.notified().await;
+
+    // The request waiting for its turn under the rate limit does not hold up
+    // another one.
+    tokio::time::timeout(std::time::Duration::from_secs(5), zoom.phone().setting(""))
+        .await
+        .unwrap()
+        .unwrap();
+
+    clock.wake.notify_one();
+    second.await.unwrap();
+    for _ in 0..3 {
+        requests.recv().await.unwrap();
+    }
+}
+
+#[test]
+fn test_enum_variants() {
+    use crate::types::AutoRecording;
+
+    assert_eq!(
+        AutoRecording::all_variants(),
+        &[
+            AutoRecording::Cloud,
+            AutoRecording::Local,
+            AutoRecording::None
+        ]
+    );
+
+    // Each variant is what it serializes as, and back.
+    for v in AutoRecording::all_variants() {
+        let s = serde_json::to_string(v).unwrap();
+        assert_eq!(s, format!(r#""{}""#, v.as_str()));
+        assert_eq!(serde_json::from_str::<AutoRecording>(&s).unwrap(), *v);
+    }
+    assert_eq!(AutoRecording::Noop.as_str(), "");
+}
+
+#[tokio::test]
+async fn test_get_recordings_optional_enums() {
+    let (host, mut requests) = mock_server(vec![
+        mock_response("", r#"{"recordings":[]}"#),
+        mock_response("", r#"{"recordings":[]}"#),
+    ])
+    .await;
+
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+
+    zoom.phone()
+        .get_recordings(
+            0,
+            "",
+            "",
+            "",
+            None,
+            None,
+            "",
+            crate::types::QueryDateType::StartTime,
+        )
+        .await
+        .unwrap();
+    let request = requests.recv().await.unwrap();
+    let request_line = request.lines().next().unwrap();
+    assert!(!request_line.contains("owner_type"));
+    assert!(!request_line.contains("recording_type"));
+
+    zoom.phone()
+        .get_recordings(
+            0,
+            "",
+            "",
+            "",
+            Some(crate::types::OwnerType::CallQueue),
+            Some(crate::types::GetPhoneRecordingsRecordingType::OnDemand),
+            "",
+            crate::types::QueryDateType::StartTime,
+        )
+        .await
+        .unwrap();
+    let request = requests.recv().await.unwrap();
+    let request_line = request.lines().next().unwrap();
+    assert!(request_line.contains("owner_type=callQueue"));
+    assert!(request_line.contains("recording_type=OnDemand"));
+}
+
+#[tokio::test]
+async fn test_redirect_policy_none() {
+    let (host, mut requests) = mock_server(vec!["HTTP/1.1 302 Found\r\nlocation: \
+                                                 /somewhere/else\r\ncontent-length: \
+                                                 0\r\nconnection: close\r\n\r\n"
+        .to_string()])
+    .await;
+
+    let zoom = crate::Client::new("", "", "", "token", "")
+        .with_host(host)
+        .with_redirect_policy(crate::RedirectPolicy::None);
+
+    let resp = zoom
+        .request_raw(reqwest::Method::GET, "/phone/settings", None)
+        .await
+        .unwrap();
+    requests.recv().await.unwrap();
+
+    assert_eq!(resp.status(), reqwest::StatusCode::FOUND);
+    assert_eq!(
+        resp.headers().get(reqwest::header::LOCATION).unwrap(),
+        "/somewhere/else"
+    );
+}
+
+#[test]
+fn test_types_grouped_by_tag() {
+    // The types a tag uses are the same types, just found in a module of their own.
+    let resp: crate::types::phone::ListAccountPhoneNumbersResponseData =
+        serde_json::from_str(r#"{"phone_numbers":[]}"#).unwrap();
+    let _: &crate::types::ListAccountPhoneNumbersResponseData = &resp;
+
+    let _: Option<crate::types::users::UsersResponseData> = None;
+}
+
+#[tokio::test]
+async fn test_clone_with_token() {
+    use tokio::io::AsyncWriteExt;
+
+    // Keep every connection open and note which connection each request came
+    // in on, so we can tell whether the clients share a connection pool.
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let host = format!("http://{}", listener.local_addr().unwrap());
+    let (tx, mut requests) = tokio::sync::mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        let mut connection = 0;
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let tx = tx.clone();
+            tokio::spawn(async move {
+                loop {
+                    let request = read_request(&mut socket).await;
+                    if request.is_empty() {
+                        break;
+                    }
+                    tx.send((connection, request)).unwrap();
+                    socket
+                        .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\n\r\n")
+                        .await
+                        .unwrap();
+                }
+            });
+            connection += 1;
+        }
+    });
+
+    let zoom = crate::Client::new("", "", "", "", "").with_host(host);
+    let alice = zoom.clone_with_token("alice-token", "alice-refresh");
+    let bob = zoom.clone_with_token("bob-token", "bob-refresh");
+
+    for client in [&alice, &bob] {
+        client
+            .request_raw(reqwest::Method::GET, "/users/me", None)
+            .await
+            .unwrap()
+            .text()
+            .await
+            .unwrap();
+    }
+
+    let (alice_connection, alice_request) = requests.recv().await.unwrap();
+    let (bob_connection, bob_request) = requests.recv().await.unwrap();
+
+    assert!(alice_request
+        .to_lowercase()
+        .contains("authorization: bearer alice-token"));
+    assert!(bob_request
+        .to_lowercase()
+        .contains("authorization: bearer bob-token"));
+    assert_eq!(alice_connection, bob_connection);
+}
+
+#[test]
+fn test_iterate_list_account_numbers_response() {
+    let numbers: crate::types::ListAccountPhoneNumbersResponseData = serde_json::from_str(
+        r#"{"next_page_token":"","phone_numbers":[{"id":"1","number":"+15550100"},{"id":"2","number":"+15550101"}]}"#,
+    )
+    .unwrap();
+
+    let ids: Vec<&str> = numbers.iter().map(|n| n.id.as_str()).collect();
+    assert_eq!(ids, vec!["1", "2"]);
+
+    let mut count = 0;
+    for number in &numbers {
+        assert!(number.number.starts_with("+1555"));
+        count += 1;
+    }
+    assert_eq!(count, 2);
+
+    let numbers: Vec<String> = numbers.into_iter().map(|n| n.number).collect();
+    assert_eq!(numbers, vec!["+15550100", "+15550101"]);
+}
+
+#[tokio::test]
+async fn test_default_query() {
+    let (host, mut requests) = mock_server(vec![
+        mock_response("", r#"{"phone_numbers":[]}"#),
+        mock_response("", r#"{"phone_numbers":[]}"#),
+    ])
+    .await;
+
+    let zoom = crate::Client::new("", "", "", "token", "")
+        .with_host(host)
+        .with_default_query(&[("account_id", "acme"), ("page_size", "5")]);
+
+    zoom.phone()
+        .list_account_numbers("", None, None, 0, None, false, "")
+        .await
+        .unwrap();
+    let request = requests.recv().await.unwrap();
+    let request_line = request.lines().next().unwrap();
+    assert!(request_line.contains("account_id=acme"));
+    assert!(request_line.contains("page_size=5"));
+
+    zoom.phone()
+        .list_account_numbers("", None, None, 30, None, false, "")
+        .await
+        .unwrap();
+    let request = requests.recv().await.unwrap();
+    let request_line = request.lines().next().unwrap();
+    assert!(request_line.contains("account_id=acme"));
+    assert!(request_line.contains("page_size=30"));
+    assert!(!request_line.contains("page_size=5"));
+}
+
+#[tokio::test]
+async fn test_delete_call_log_if_exists() {
+    let (host, mut requests) = mock_server(vec![
+        mock_response("", r#"{"code":404,"message":"Call log does not exist."}"#).replacen(
+            "200 OK",
+            "404 Not Found",
+            1,
+        ),
+        "HTTP/1.1 204 No Content\r\nconnection: close\r\n\r\n".to_string(),
+    ])
+    .await;
+
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+
+    let deleted = zoom
+        .phone()
+        .delete_call_log_if_exists("me", "missing")
+        .await
+        .unwrap();
+    assert!(!deleted);
+    let request = requests.recv().await.unwrap();
+    assert!(request.starts_with("DELETE /phone/users/me/call_logs/missing "));
+
+    let deleted = zoom
+        .phone()
+        .delete_call_log_if_exists("me", "existing")
+        .await
+        .unwrap();
+    assert!(deleted);
+}
+
+#[tokio::test]
+async fn test_update_answering_no_content() {
+    let (host, _requests) = mock_server(vec![
+        "HTTP/1.1 204 No Content\r\nconnection: close\r\n\r\n".to_string(),
+        mock_response("", r#"{"id":"loc1","name":"HQ"}"#),
+    ])
+    .await;
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+
+    // An update documented to answer 204 or 200 with what it updated returns
+    // an `Option`, as a 204 has no body to parse.
+    let updated: Option<crate::types::GetLocationResponse> =
+        zoom.patch("/phone/locations/loc1", None).await.unwrap();
+    assert_eq!(updated, None);
+
+    let updated: Option<crate::types::GetLocationResponse> =
+        zoom.patch("/phone/locations/loc1", None).await.unwrap();
+    assert_eq!(updated.unwrap().name, "HQ");
+}
+
+#[cfg(feature = "axum")]
+#[tokio::test]
+async fn test_client_error_into_axum_response() {
+    use axum_core::response::IntoResponse;
+
+    let (host, _requests) = mock_server(vec![mock_response(
+        "",
+        r#"{"code":404,"message":"Call log does not exist."}"#,
+    )
+    .replacen("200 OK", "404 Not Found", 1)])
+    .await;
+
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+
+    let err = zoom
+        .phone()
+        .delete_call_log("me", "missing")
+        .await
+        .unwrap_err();
+    assert_eq!(err.status(), reqwest::StatusCode::NOT_FOUND);
+
+    let resp = err.into_response();
+    assert_eq!(resp.status(), http::StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_health_with_scopes() {
+    let (host, mut requests) = mock_server(vec![mock_response("", "{}")]).await;
+
+    let mut zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+    // As if the token endpoint had granted these.
+    zoom.scope = "phone:read:admin user:read:admin".to_string();
+
+    let missing = zoom
+        .health_with_scopes(&["phone:read:admin", "phone:write:admin"])
+        .await
+        .unwrap();
+    // Any user may get their own profile, whatever the token's scopes.
+    let request = requests.recv().await.unwrap();
+    assert!(request.starts_with("GET /users/me "));
+
+    assert_eq!(missing, vec!["phone:write:admin".to_string()]);
+}
+
+#[tokio::test]
+async fn test_health_with_scopes_unknown() {
+    let (host, mut requests) = mock_server(vec![mock_response("", "{}")]).await;
+
+    // The client has not gotten or refreshed its token, so it does not know
+    // what scopes it was granted.
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+
+    let err = zoom
+        .health_with_scopes(&["phone:read:admin"])
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<crate::ClientError>().unwrap().status(),
+        reqwest::StatusCode::INTERNAL_SERVER_ERROR
+    );
+    assert!(err.to_string().contains("scopes are not known"));
+
+    // Nothing was sent.
+    assert!(requests.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn test_retry_policy_per_call_override() {
+    let responses = || {
+        vec![
+            mock_response("", r#"{"code":500,"message":"Internal error."}"#).replacen(
+                "200 OK",
+                "500 Internal Server Error",
+                1,
+            ),
+            mock_response("", "{}"),
+        ]
+    };
+
+    // With the client's policy the failed request is sent again.
+    let (host, mut requests) = mock_server(responses()).await;
+    let zoom = crate::Client::new("", "", "", "token", "")
+        .with_host(host)
+        .with_retry(crate::RetryPolicy::new(
+            2,
+            std::time::Duration::from_millis(1),
+        ));
+    zoom.phone().setting("").await.unwrap();
+    requests.recv().await.unwrap();
+    requests.recv().await.unwrap();
+
+    // A call made with its own policy does not retry.
+    let (host, mut requests) = mock_server(responses()).await;
+    let zoom = zoom.with_host(host);
+    let err = zoom
+        .phone()
+        .retry(crate::RetryPolicy::none())
+        .setting("")
+        .await
+        .unwrap_err();
+    assert_eq!(err.status(), reqwest::StatusCode::INTERNAL_SERVER_ERROR);
+    requests.recv().await.unwrap();
+    assert!(requests.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn test_location_cache() {
+    let (host, mut requests) = mock_server(vec![
+        mock_response(
+            "",
+            r#"{"next_page_token":"","page_size":30,"locations":[{"id":"loc1","name":"HQ","elin":{"phone_number":"+15550100"}}]}"#,
+        ),
+        mock_response(
+            "",
+            r#"{"id":"loc1","name":"HQ","emergency_address":{"city":"San Jose"}}"#,
+        ),
+        mock_response("", "{}"),
+        mock_response("", r#"{"id":"loc1","name":"HQ West"}"#),
+    ])
+    .await;
+
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+    let cache = crate::location_cache::LocationCache::new(zoom);
+
+    cache.list_locations("", 30).await.unwrap();
+    assert!(requests
+        .recv()
+        .await
+        .unwrap()
+        .starts_with("GET /phone/locations?"));
+
+    // The listed location is served from the cache as it was listed.
+    let listed = cache.listed_location("loc1").unwrap();
+    assert_eq!(listed.name, "HQ");
+    assert_eq!(listed.elin.unwrap().phone_number, "+15550100");
+    assert!(requests.try_recv().is_err());
+
+    // But it does not stand in for the full location, which has an address.
+    let location = cache.get_location("loc1").await.unwrap();
+    assert_eq!(location.emergency_address.unwrap().city, "San Jose");
+    assert!(requests
+        .recv()
+        .await
+        .unwrap()
+        .starts_with("GET /phone/locations/loc1 "));
+
+    // Which is then served from the cache.
+    let location = cache.get_location("loc1").await.unwrap();
+    assert_eq!(location.name, "HQ");
+    assert!(requests.try_recv().is_err());
+
+    // Updating it drops it from the cache, so the next get asks the API.
+    let update = crate::types::UpdateLocationRequest {
+        bssid: String::new(),
+        elin_phone_number_id: String::new(),
+        emergency_address_id: String::new(),
+        name: "HQ West".to_string(),
+        network_switches: Vec::new(),
+        private_ip: String::new(),
+        public_ip: String::new(),
+        sip_group_id: String::new(),
+    };
+    cache.update_location("loc1", &update).await.unwrap();
+    assert!(requests
+        .recv()
+        .await
+        .unwrap()
+        .starts_with("PATCH /phone/locations/loc1 "));
+    assert!(cache.listed_location("loc1").is_none());
+
+    let location = cache.get_location("loc1").await.unwrap();
+    assert_eq!(location.name, "HQ West");
+    assert!(requests
+        .recv()
+        .await
+        .unwrap()
+        .starts_with("GET /phone/locations/loc1 "));
+}
+
+#[tokio::test]
+async fn test_get_with_deadline() {
+    let (host, mut requests) = mock_server(vec![mock_response("", "{}")]).await;
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+
+    // A deadline that already passed fails the call before it is sent.
+    let err = zoom
+        .get_with_deadline::<serde_json::Value>("/phone/settings", std::time::Instant::now())
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<crate::ClientError>(),
+        Some(&crate::ClientError::DeadlineExceeded)
+    );
+    assert!(requests.try_recv().is_err());
+
+    // One that has not is passed on in a header, if asked to.
+    zoom.with_deadline_header()
+        .get_with_deadline::<serde_json::Value>(
+            "/phone/settings",
+            std::time::Instant::now() + std::time::Duration::from_secs(30),
+        )
+        .await
+        .unwrap();
+    let request = requests.recv().await.unwrap();
+    assert!(request.to_lowercase().contains("x-request-deadline: "));
+}
+
+#[tokio::test]
+async fn test_account_call_logs_time_type() {
+    let (host, mut requests) = mock_server(vec![mock_response("", r#"{"call_logs":[]}"#)]).await;
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+
+    zoom.phone()
+        .account_call_logs(
+            30,
+            "2021-07-01",
+            "2021-07-31",
+            "all",
+            "",
+            "",
+            crate::types::TimeType::EndTime,
+            "",
+        )
+        .await
+        .unwrap();
+
+    let request = requests.recv().await.unwrap();
+    let request_line = request.lines().next().unwrap();
+    assert!(request_line.starts_with("GET /phone/call_logs?"));
+    assert!(request_line.contains("time_type=endTime"));
+}
+
+#[tokio::test]
+async fn test_request_id_in_error() {
+    let error = || {
+        mock_response("", r#"{"code":500,"message":"Internal error."}"#).replacen(
+            "200 OK",
+            "500 Internal Server Error",
+            1,
+        )
+    };
+    let (host, mut requests) = mock_server(vec![error(), error()]).await;
+    let zoom = crate::Client::new("", "", "", "token", "")
+        .with_host(host)
+        .with_request_id("x-request-id")
+        .unwrap();
+
+    let mut ids = Vec::new();
+    for _ in 0..2 {
+        let err = zoom.phone().setting("").await.unwrap_err();
+        let request_id = match &err {
+            crate::ClientError::HttpError { request_id, .. } => request_id.clone().unwrap(),
+            e => panic!("unexpected error: {:?}", e),
+        };
+        assert!(format!("{:#}", err).contains(&request_id));
+
+        // It is the id the request was sent with.
+        let request = requests.recv().await.unwrap();
+        assert!(request
+            .to_lowercase()
+            .contains(&format!("x-request-id: {}", request_id)));
+        ids.push(request_id);
+    }
+    assert_ne!(ids[0], ids[1]);
+}
+
+#[test]
+fn test_request_id_invalid_header() {
+    let err = crate::Client::new("", "", "", "token", "")
+        .with_request_id("x request id")
+        .err()
+        .unwrap();
+    assert!(err.to_string().contains("invalid request id header"));
+}
+
+#[tokio::test]
+async fn test_patch_if_changed() {
+    let (host, mut requests) = mock_server(vec![mock_response("", "{}")]).await;
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+
+    let current = crate::types::UpdatePhoneNumberDetailsRequest {
+        capability: vec!["incoming".to_string()],
+        display_name: "Front desk".to_string(),
+        sip_group_id: "".to_string(),
+    };
+
+    // Nothing changed, so nothing is sent.
+    let updated = zoom
+        .phone()
+        .update_number_details_if_changed(&"num1".into(), &current, &current)
+        .await
+        .unwrap();
+    assert_eq!(updated, None);
+    assert!(requests.try_recv().is_err());
+
+    // Only the field that changed is sent.
+    let mut desired = current.clone();
+    desired.display_name = "Reception".to_string();
+    let updated = zoom
+        .phone()
+        .update_number_details_if_changed(&"num1".into(), &current, &desired)
+        .await
+        .unwrap();
+    assert_eq!(updated, Some(()));
+
+    let request = requests.recv().await.unwrap();
+    assert!(request.starts_with("PATCH /phone/numbers/num1 "));
+    assert!(request.ends_with(r#"{"display_name":"Reception"}"#));
+}
+
+#[tokio::test]
+async fn test_retry_policy_max_total_delay() {
+    let (host, mut requests) = mock_server(vec![mock_response(
+        "retry-after: 120\r\n",
+        r#"{"code":429,"message":"Too many requests."}"#,
+    )
+    .replacen("200 OK", "429 Too Many Requests", 1)])
+    .await;
+    let zoom = crate::Client::new("", "", "", "token", "")
+        .with_host(host)
+        .with_retry(
+            crate::RetryPolicy::new(5, std::time::Duration::from_millis(1))
+                .with_max_total_delay(std::time::Duration::from_secs(10)),
+        );
+
+    // The server asks for a wait over the budget, so we get its error back
+    // straight away instead of waiting.
+    let started = std::time::Instant::now();
+    let err = zoom.phone().setting("").await.unwrap_err();
+    assert!(started.elapsed() < std::time::Duration::from_secs(10));
+    assert_eq!(err.status(), reqwest::StatusCode::TOO_MANY_REQUESTS);
+    requests.recv().await.unwrap();
+    assert!(requests.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn test_proxy() {
+    // Requests go to the proxy, with the URL they are for.
+    let (proxy, mut requests) = mock_server(vec![mock_response("", "{}")]).await;
+    let zoom = crate::Client::new("", "", "", "token", "")
+        .with_host("http://zoom.test")
+        .with_proxy(&proxy)
+        .unwrap()
+        .with_no_proxy("");
+    zoom.phone().setting("").await.unwrap();
+    let request = requests.recv().await.unwrap();
+    assert!(request.starts_with("GET http://zoom.test/phone/settings "));
+
+    // A host in the no proxy list goes straight to the host. Nothing listens
+    // on the proxy, so this fails if the proxy is used.
+    let (host, mut requests) = mock_server(vec![mock_response("", "{}")]).await;
+    let zoom = crate::Client::new("", "", "", "token", "")
+        .with_host(host)
+        .with_proxy("http://127.0.0.1:9")
+        .unwrap()
+        .with_no_proxy("example.com, 127.0.0.1");
+    zoom.phone().setting("").await.unwrap();
+    let request = requests.recv().await.unwrap();
+    assert!(request.starts_with("GET /phone/settings "));
+
+    // A url that does not parse is an error, not a panic.
+    let err = zoom.with_proxy("http://[::1").err().unwrap();
+    assert!(err.to_string().contains("invalid proxy url"));
+}
+
+#[tokio::test]
+async fn test_get_json() {
+    let (host, mut requests) = mock_server(vec![mock_response(
+        "",
+        r#"{"country":{"code":"US"},"beta_field":{"enabled":true}}"#,
+    )])
+    .await;
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+
+    // A field the types do not have is still there.
+    let settings = zoom.get_json("/phone/settings").await.unwrap();
+    assert_eq!(settings["beta_field"]["enabled"], serde_json::json!(true));
+    assert_eq!(settings["country"]["code"], "US");
+
+    let request = requests.recv().await.unwrap();
+    assert!(request.starts_with("GET /phone/settings "));
+    assert!(request
+        .to_lowercase()
+        .contains("authorization: bearer token"));
+}
+
+#[tokio::test]
+async fn test_execute_all() {
+    use tokio::io::AsyncWriteExt;
+
+    // Answer by path, the requests may arrive in either order.
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let host = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        loop {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            tokio::spawn(async move {
+                let request = read_request(&mut socket).await;
+                let response = if request.starts_with("GET /users?") {
+                    mock_response("", r#"{"users":[{"id":"u1","email":"a@example.com"}]}"#)
+                } else if request.starts_with("GET /phone/numbers/n1 ") {
+                    mock_response("", r#"{"id":"n1","number":"+14155550100"}"#)
+                } else {
+                    mock_response("", "{}").replacen("200 OK", "404 Not Found", 1)
+                };
+                socket.write_all(response.as_bytes()).await.unwrap();
+            });
+        }
+    });
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+
+    enum Response {
+        Users(Vec<crate::types::UsersResponse>),
+        Number(crate::types::GetPhoneNumberDetailsResponse),
+    }
+    let requests: Vec<crate::BoxedRequest<Response>> = vec![
+        Box::new(|c| {
+            Box::pin(async move {
+                let users = c
+                    .users()
+                    .get_page(crate::types::UsersStatus::Active, 30, "", "", None, "")
+                    .await?;
+                Ok(Response::Users(users))
+            })
+        }),
+        Box::new(|c| {
+            Box::pin(async move {
+                let number = c.phone().get_number_details(&"n1".into()).await?;
+                Ok(Response::Number(number))
+            })
+        }),
+        Box::new(|c| {
+            Box::pin(async move {
+                let number = c.phone().get_number_details(&"missing".into()).await?;
+                Ok(Response::Number(number))
+            })
+        }),
+    ];
+
+    let mut results = zoom.execute_all(requests, 2).await.into_iter();
+    match results.next().unwrap().unwrap() {
+        Response::Users(users) => assert_eq!(users[0].email, "a@example.com"),
+        Response::Number(_) => panic!("expected the users first"),
+    }
+    match results.next().unwrap().unwrap() {
+        Response::Number(number) => assert_eq!(number.number, "+14155550100"),
+        Response::Users(_) => panic!("expected the number second"),
+    }
+    assert!(results.next().unwrap().is_err());
+    assert!(results.next().is_none());
+}
+
+#[cfg(feature = "rustls-tls")]
+#[test]
+fn test_tls_backend_rustls() {
+    let zoom =
+        crate::Client::new("", "", "", "token", "").with_tls_backend(crate::TlsBackend::Rustls);
+    assert_eq!(zoom.http_config.tls_backend, crate::TlsBackend::Rustls);
+}
+
+#[cfg(feature = "native-tls")]
+#[test]
+fn test_tls_backend_native_tls() {
+    let zoom =
+        crate::Client::new("", "", "", "token", "").with_tls_backend(crate::TlsBackend::NativeTls);
+    assert_eq!(zoom.http_config.tls_backend, crate::TlsBackend::NativeTls);
+}
+
+#[test]
+fn test_pool_settings() {
+    let zoom = crate::Client::new("", "", "", "token", "")
+        .with_max_idle_per_host(8)
+        .with_pool_idle_timeout(std::time::Duration::from_secs(30));
+    assert_eq!(zoom.http_config.pool_max_idle_per_host, Some(8));
+    assert_eq!(
+        zoom.http_config.pool_idle_timeout,
+        Some(std::time::Duration::from_secs(30))
+    );
+
+    // Other settings rebuild the inner client with the pool settings kept.
+    let zoom = zoom.with_cookie_store();
+    assert_eq!(zoom.http_config.pool_max_idle_per_host, Some(8));
+}
+
+#[test]
+fn test_byoc_number() {
+    for n in &["+14155550100", "+442071838750", "+861012345678901"] {
+        assert_eq!(crate::byoc::ByocNumber::new(n).unwrap().as_str(), *n);
+    }
+
+    for n in &[
+        "",
+        "+",
+        "14155550100",
+        "+1 415 555 0100",
+        "+1-415-555-0100",
+        "+04155550100",
+        "+1415555010012345",
+    ] {
+        assert!(crate::byoc::ByocNumber::new(n).is_err(), "{:?} is valid", n);
+    }
+}
+
+#[tokio::test]
+async fn test_add_byoc_number_validates() {
+    let (host, mut requests) =
+        mock_server(vec![mock_response("", r#"{"phone_numbers":[]}"#)]).await;
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+
+    let number = "+14155550100".parse().unwrap();
+    assert!(crate::types::AddByocNumberRequest::new("", &[number]).is_err());
+    assert!(crate::types::AddByocNumberRequest::new("Carrier", &[]).is_err());
+
+    // A bad number is not sent.
+    let mut body =
+        crate::types::AddByocNumberRequest::new("Carrier", &["+14155550100".parse().unwrap()])
+            .unwrap();
+    body.phone_numbers.push("415-555-0101".to_string());
+    assert!(zoom.phone().add_byoc_number(&body).await.is_err());
+    assert!(requests.try_recv().is_err());
+
+    // A good one is.
+    body.phone_numbers.pop();
+    zoom.phone().add_byoc_number(&body).await.unwrap();
+    let request = requests.recv().await.unwrap();
+    assert!(request.starts_with("POST /phone/byoc_numbers "));
+    assert!(request.ends_with(r#"{"carrier":"Carrier","phone_numbers":["+14155550100"]}"#));
+}
+
+#[tokio::test]
+async fn test_im_message_builder() {
+    let body = crate::types::SendimmessagesRequest::builder()
+        .line("Hello")
+        .line("The build is green.")
+        .build()
+        .unwrap();
+    assert_eq!(
+        serde_json::to_string(&body).unwrap(),
+        r#"{"message":"Hello\nThe build is green."}"#
+    );
+
+    assert!(crate::types::SendimmessagesRequest::builder()
+        .build()
+        .is_err());
+    assert!(crate::types::SendimmessagesRequest::text("  ").is_err());
+
+    let (host, mut requests) = mock_server(vec![mock_response("", "{}")]).await;
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+
+    // An empty message is not sent.
+    assert!(zoom
+        .im_chat()
+        .send_text("jchill@example.com", "")
+        .await
+        .is_err());
+    assert!(requests.try_recv().is_err());
+
+    zoom.im_chat()
+        .send_text("jchill@example.com", "Hello")
+        .await
+        .unwrap();
+    let request = requests.recv().await.unwrap();
+    assert!(request.starts_with("POST /im/users/me/chat/messages?chat_user=jchill%40example.com "));
+    assert!(request.ends_with(r#"{"message":"Hello"}"#));
+}
+
+#[tokio::test]
+async fn test_list_im_messages_date() {
+    let (host, mut requests) = mock_server(vec![
+        mock_response("", r#"{"messages":[]}"#),
+        mock_response("", r#"{"messages":[]}"#),
+    ])
+    .await;
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+
+    let date = chrono::NaiveDate::from_ymd(2021, 3, 4);
+    zoom.im_chat()
+        .list_im_messages("me", "", "", Some(date), 0, "")
+        .await
+        .unwrap();
+    let request = requests.recv().await.unwrap();
+    assert!(request.starts_with("GET /im/users/me/chat/messages?date=2021-03-04 "));
+
+    // Without a date, the messages of any are listed.
+    zoom.im_chat()
+        .list_im_messages("me", "", "", None, 0, "")
+        .await
+        .unwrap();
+    let request = requests.recv().await.unwrap();
+    assert!(
+        request.starts_with("GET /im/users/me/chat/messages "),
+        "{}",
+        request
+    );
+}
+
+#[tokio::test]
+async fn test_default_page_size() {
+    let (host, mut requests) = mock_server(vec![
+        mock_response("", r#"{"messages":[]}"#),
+        mock_response("", r#"{"messages":[]}"#),
+    ])
+    .await;
+    let zoom = crate::Client::new("", "", "", "token", "")
+        .with_host(host)
+        .with_default_page_size(300);
+    let date = chrono::NaiveDate::from_ymd(2021, 3, 4);
+
+    // Passing `0` gets the default.
+    zoom.im_chat()
+        .list_im_messages("me", "", "", Some(date), 0, "")
+        .await
+        .unwrap();
+    let request = requests.recv().await.unwrap();
+    assert!(
+        request.starts_with("GET /im/users/me/chat/messages?date=2021-03-04&page_size=300 "),
+        "{}",
+        request
+    );
+
+    // Anything else is sent as it is.
+    zoom.im_chat()
+        .list_im_messages("me", "", "", Some(date), 10, "")
+        .await
+        .unwrap();
+    let request = requests.recv().await.unwrap();
+    assert!(
+        request.starts_with("GET /im/users/me/chat/messages?date=2021-03-04&page_size=10 "),
+        "{}",
+        request
+    );
+}
+
+#[tokio::test]
+async fn test_user_voice_mails_status() {
+    let (host, mut requests) = mock_server(vec![
+        mock_response("", r#"{"voice_mails":[]}"#),
+        mock_response("", r#"{"voice_mails":[]}"#),
+    ])
+    .await;
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+    let day = chrono::NaiveDate::from_ymd(2021, 3, 4);
+
+    // All voice mails is the default, and is left off the query.
+    assert_eq!(
+        crate::types::PhoneUserVoiceMailsStatus::default(),
+        crate::types::PhoneUserVoiceMailsStatus::All
+    );
+    zoom.phone()
+        .user_voice_mails("me", 0, Default::default(), "", day, day)
+        .await
+        .unwrap();
+    let request = requests.recv().await.unwrap();
+    assert!(request.starts_with("GET /phone/users/me/voice_mails?from=2021-03-04&to=2021-03-04 "));
+
+    zoom.phone()
+        .user_voice_mails(
+            "me",
+            0,
+            crate::types::PhoneUserVoiceMailsStatus::Unread,
+            "",
+            day,
+            day,
+        )
+        .await
+        .unwrap();
+    let request = requests.recv().await.unwrap();
+    assert!(request.starts_with(
+        "GET /phone/users/me/voice_mails?from=2021-03-04&status=unread&to=2021-03-04 "
+    ));
+}
+
+#[tokio::test]
+async fn test_clock_retries() {
+    let (host, mut requests) = mock_server(vec![
+        mock_response("", "{}").replacen("200 OK", "500 Internal Server Error", 1),
+        mock_response("retry-after: 120\r\n", "{}").replacen(
+            "200 OK",
+            "503 Service Unavailable",
+            1,
+        ),
+        mock_response("", "{}"),
+    ])
+    .await;
+    let clock = crate::MockClock::new();
+    let zoom = crate::Client::new("", "", "", "token", "")
+        .with_host(host)
+        .with_clock(clock.clone())
+        .with_retry(crate::RetryPolicy::new(
+            2,
+            std::time::Duration::from_secs(30),
+        ));
+
+    // The waits before the retries move the clock rather than take the time.
+    let started = std::time::Instant::now();
+    let start = crate::Clock::now(&clock);
+    zoom.phone().setting("").await.unwrap();
+    assert!(started.elapsed() < std::time::Duration::from_secs(30));
+    assert_eq!(
+        crate::Clock::now(&clock) - start,
+        std::time::Duration::from_secs(30 + 120)
+    );
+    for _ in 0..3 {
+        requests.recv().await.unwrap();
+    }
+}
+
+#[tokio::test]
+async fn test_clock_token_expiry() {
+    let clock = crate::MockClock::new();
+    let mut zoom =
+        crate::Client::new("id", "secret", "", "token", "refresh").with_clock(clock.clone());
+
+    // The client does not know when a token it was given expires.
+    assert!(!zoom.token_expired());
+
+    zoom.set_token_expiry(3600);
+    clock.advance(std::time::Duration::from_secs(3599));
+    assert!(!zoom.token_expired());
+    assert!(zoom
+        .refresh_access_token_if_expired()
+        .await
+        .unwrap()
+        .is_none());
+
+    // Once it expires the client asks the token endpoint for a new one, which
+    // will not take these credentials.
+    clock.advance(std::time::Duration::from_secs(1));
+    assert!(zoom.token_expired());
+    assert!(zoom.refresh_access_token_if_expired().await.is_err());
+    assert_eq!(zoom.token, "token");
+
+    // A new token is not known to expire.
+    let zoom = zoom.clone_with_token("other", "");
+    assert!(!zoom.token_expired());
+}
+
+#[tokio::test]
+async fn test_create_and_fetch() {
+    let (host, mut requests) = mock_server(vec![
+        mock_response("", r#"{"id":"site1","name":"HQ"}"#).replacen("200 OK", "201 Created", 1),
+        mock_response("", r#"{"id":"site1","name":"HQ","site_code":10}"#),
+    ])
+    .await;
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+
+    let body: crate::types::CreatePhoneSiteRequest = serde_json::from_value(serde_json::json!({
+        "name": "HQ",
+        "auto_receptionist_name": "HQ AR",
+    }))
+    .unwrap();
+    let site = zoom.phone_site().create_and_fetch(&body).await.unwrap();
+    assert_eq!(site.id, "site1");
+    assert_eq!(site.site_code, 10);
+
+    let request = requests.recv().await.unwrap();
+    assert!(request.starts_with("POST /phone/sites "));
+    assert!(request.ends_with(r#""name":"HQ"}"#));
+    let request = requests.recv().await.unwrap();
+    assert!(request.starts_with("GET /phone/sites/site1 "));
+}
+
+#[tokio::test]
+async fn test_add_location_and_fetch() {
+    let (host, mut requests) = mock_server(vec![
+        mock_response("", r#"{"id":"loc1","name":"HQ"}"#).replacen("200 OK", "201 Created", 1),
+        mock_response(
+            "",
+            r#"{"id":"loc1","name":"HQ","public_ip":"203.0.113.1","site_id":"site1"}"#,
+        ),
+    ])
+    .await;
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+
+    let body: crate::types::AddLocationRequest = serde_json::from_value(serde_json::json!({
+        "name": "HQ",
+        "public_ip": "203.0.113.1",
+        "site_id": "site1",
+    }))
+    .unwrap();
+    let location = zoom.phone().add_location_and_fetch(&body).await.unwrap();
+    assert_eq!(location.id, "loc1");
+    assert_eq!(location.public_ip, "203.0.113.1");
+
+    let request = requests.recv().await.unwrap();
+    assert!(request.starts_with("POST /phone/locations "));
+    assert!(request.ends_with(r#""site_id":"site1"}"#));
+    let request = requests.recv().await.unwrap();
+    assert!(request.starts_with("GET /phone/locations/loc1 "));
+}
+
+#[tokio::test]
+async fn test_encode_path() {
+    let (host, mut requests) = mock_server(vec![mock_response("", r#"{"id":"a b/c"}"#)]).await;
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+
+    // The id stays in its own path segment.
+    zoom.phone().get_location("a b/c%").await.unwrap();
+    let request = requests.recv().await.unwrap();
+    assert!(request.starts_with("GET /phone/locations/a%20b%2Fc%25 "));
+}
+
+#[test]
+fn test_list_users_total() {
+    let users: crate::types::UsersResponseData = serde_json::from_str(
+        r#"{"next_page_token":"abc","page_size":2,"total_records":5,"users":[{"id":"1"},{"id":"2"}]}"#,
+    )
+    .unwrap();
+
+    assert_eq!(users.total(), 5);
+    assert_eq!(users.remaining(users.users.len()), 3);
+    assert_eq!(users.remaining(7), 0);
+}
+
+#[test]
+fn test_canonical_json() {
+    let body = serde_json::json!({
+        "b": 1,
+        "a": {"d": [{"f": "x y", "e": 2}], "c": null},
+    });
+    let out = crate::BodySerializer::serialize(&crate::CanonicalJson, &body).unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        r#"{"a":{"c":null,"d":[{"e":2,"f":"x y"}]},"b":1}"#
+    );
+}
+
+#[tokio::test]
+async fn test_body_serializer() {
+    // Sign the body the way a signed-request scheme might.
+    struct Signed;
+
+    impl crate::BodySerializer for Signed {
+        fn serialize(&self, body: &serde_json::Value) -> anyhow::Result<Vec<u8>> {
+            let canonical = crate::CanonicalJson.serialize(body)?;
+            let signed = serde_json::json!({
+                "body": String::from_utf8(canonical)?,
+                "signature": "sig",
+            });
+            Ok(serde_json::to_vec(&signed)?)
+        }
+    }
+
+    let (host, mut requests) = mock_server(vec![mock_response("", r#"{"id":"loc1"}"#)]).await;
+    let zoom = crate::Client::new("", "", "", "token", "")
+        .with_host(host)
+        .with_body_serializer(Signed);
+
+    let body: crate::types::AddLocationRequest = serde_json::from_value(serde_json::json!({
+        "site_id": "site1",
+        "name": "HQ",
+    }))
+    .unwrap();
+    zoom.phone().add_location(&body).await.unwrap();
+    let request = requests.recv().await.unwrap();
+    assert!(request
+        .ends_with(r#"{"body":"{\"name\":\"HQ\",\"site_id\":\"site1\"}","signature":"sig"}"#));
+}
+
+#[tokio::test]
+async fn test_error_code() {
+    assert_eq!(
+        crate::error_code::ZoomErrorCode::from_code(1001),
+        crate::error_code::ZoomErrorCode::UserNotFound
+    );
+    assert_eq!(crate::error_code::ZoomErrorCode::UserNotFound.code(), 1001);
+    assert_eq!(
+        crate::error_code::ZoomErrorCode::from_code(99999),
+        crate::error_code::ZoomErrorCode::Other(99999)
+    );
+
+    let (host, _requests) = mock_server(vec![mock_response(
+        "",
+        r#"{"code":1001,"message":"User does not exist: me."}"#,
+    )
+    .replacen("200 OK", "404 Not Found", 1)])
+    .await;
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+
+    let err = zoom.phone().setting("").await.unwrap_err();
+    assert_eq!(
+        err.error_code(),
+        Some(crate::error_code::ZoomErrorCode::UserNotFound)
+    );
+    assert_eq!(crate::ClientError::DeadlineExceeded.error_code(), None);
+}
+
+#[cfg(feature = "mock")]
+#[tokio::test]
+async fn test_phone_api() {
+    let (host, mut requests) = mock_server(vec![mock_response("", "{}")]).await;
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+
+    // The trait can be made into an object, and `Phone` implements it.
+    let phone: Box<dyn crate::phone::PhoneApi> = Box::new(zoom.phone());
+    phone.setting("").await.unwrap();
+
+    let request = requests.recv().await.unwrap();
+    assert!(request.starts_with("GET /phone/settings "), "{}", request);
+}
+
+#[cfg(feature = "mock")]
+mockall::mock! {
+    ChatbotMessages {}
+
+    #[async_trait::async_trait]
+    impl crate::chatbot_messages::ChatbotMessagesApi for ChatbotMessages {
+        async fn sendchatbot(
+            &self,
+            body: &crate::types::SendchatbotRequest,
+        ) -> crate::ClientResult<()>;
+        async fn edit(
+            &self,
+            message_id: &str,
+            body: &crate::types::EditChatbotMessageRequest,
+        ) -> crate::ClientResult<crate::types::EditChatbotMessageResponse>;
+        async fn delete(
+            &self,
+            message_id: &str,
+            body: &crate::types::DeleteChatbotMessageRequest,
+        ) -> crate::ClientResult<crate::types::DeleteChatbotMessageResponse>;
+    }
+}
+
+/// Edit a chatbot message through the trait, as code that takes whichever
+/// implementation of it it is given would.
+#[cfg(feature = "mock")]
+async fn edit_chatbot_message(
+    messages: &dyn crate::chatbot_messages::ChatbotMessagesApi,
+    message_id: &str,
+) -> crate::ClientResult<String> {
+    let body = crate::types::EditChatbotMessageRequest {
+        account_id: String::new(),
+        content: crate::types::Domains {},
+        is_markdown_support: None,
+        robot_jid: "bot1".to_string(),
+        user_jid: String::new(),
+    };
+    Ok(messages.edit(message_id, &body).await?.message_id)
+}
+
+#[cfg(feature = "mock")]
+#[tokio::test]
+async fn test_mock_tag_api() {
+    // The mock answers in place of the API, with no server to send to.
+    let mut mock = MockChatbotMessages::new();
+    mock.expect_edit()
+        .withf(|message_id, body| *message_id == "m1" && body.robot_jid == "bot1")
+        .times(1)
+        .returning(|message_id, body| {
+            Ok(crate::types::EditChatbotMessageResponse {
+                message_id: message_id.to_string(),
+                robot_jid: body.robot_jid.to_string(),
+                sent_time: None,
+                to_jid: String::new(),
+                user_jid: String::new(),
+            })
+        });
+    mock.expect_delete().never();
+
+    assert_eq!(edit_chatbot_message(&mock, "m1").await.unwrap(), "m1");
+}
+
+#[tokio::test]
+async fn test_single_flight() {
+    let (host, mut requests) = mock_server(vec![mock_response("", "{}"); 10]).await;
+    let zoom = crate::Client::new("", "", "", "token", "")
+        .with_host(host)
+        .with_single_flight(true);
+
+    // All ten ask for the same settings at once, so only one of them is sent.
+    let phone = zoom.phone();
+    let results = futures::future::join_all((0..10).map(|_| phone.setting(""))).await;
+    for result in results {
+        result.unwrap();
+    }
+
+    let request = requests.recv().await.unwrap();
+    assert!(request.starts_with("GET /phone/settings "), "{}", request);
+    assert!(requests.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn test_single_flight_scoped_clients() {
+    let (host, mut requests) = mock_server(vec![mock_response("", "{}"); 2]).await;
+    let zoom = crate::Client::new("", "", "", "token", "")
+        .with_host(host)
+        .with_single_flight(true);
+
+    // The clones share the flights, but scoped to other accounts their
+    // requests are not the same, so each of them is sent.
+    let a = zoom.with_default_query(&[("account_id", "a")]).phone();
+    let b = zoom.with_default_query(&[("account_id", "b")]).phone();
+    let (ra, rb) = futures::join!(a.setting(""), b.setting(""));
+    ra.unwrap();
+    rb.unwrap();
+
+    let mut sent = Vec::new();
+    for _ in 0..2 {
+        let request = requests.recv().await.unwrap();
+        sent.push(request.lines().next().unwrap().to_string());
+    }
+    sent.sort();
+    assert_eq!(
+        sent,
+        vec![
+            "GET /phone/settings?account_id=a HTTP/1.1",
+            "GET /phone/settings?account_id=b HTTP/1.1",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_insufficient_scope() {
+    assert_eq!(
+        crate::utils::insufficient_scope(
+            r#"Bearer realm="zoom", error="insufficient_scope", scope="phone:read:admin phone:write:admin""#
+        ),
+        Some(vec![
+            "phone:read:admin".to_string(),
+            "phone:write:admin".to_string()
+        ])
+    );
+    assert_eq!(
+        crate::utils::insufficient_scope(r#"Bearer error="invalid_token""#),
+        None
+    );
+
+    let (host, _requests) = mock_server(vec![mock_response(
+        "www-authenticate: Bearer error=\"insufficient_scope\", scope=\"phone:read:admin\"\r\n",
+        "",
+    )
+    .replacen("200 OK", "403 Forbidden", 1)])
+    .await;
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+
+    let err = zoom.phone().setting("").await.unwrap_err();
+    assert_eq!(
+        err,
+        crate::ClientError::InsufficientScope {
+            required: vec!["phone:read:admin".to_string()]
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_enum_is_copy() {
+    let (host, mut requests) = mock_server(vec![
+        mock_response("", r#"{"call_logs":[]}"#),
+        mock_response("", r#"{"call_logs":[]}"#),
+    ])
+    .await;
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+
+    // Unit-only enums are `Copy`, so the same value can be passed to both calls.
+    let time_type = crate::types::TimeType::EndTime;
+    for to in ["2021-07-15", "2021-07-31"] {
+        zoom.phone()
+            .account_call_logs(30, "2021-07-01", to, "all", "", "", time_type, "")
+            .await
+            .unwrap();
+
+        let request = requests.recv().await.unwrap();
+        assert!(request
+            .lines()
+            .next()
+            .unwrap()
+            .contains("time_type=endTime"));
+    }
+    assert_eq!(time_type, crate::types::TimeType::EndTime);
+}
+
+#[tokio::test]
+async fn test_rate_limited() {
+    let (host, mut requests) = mock_server(vec![mock_response("", "{}")]).await;
+    let clock = crate::MockClock::new();
+    let zoom = crate::Client::new("", "", "", "token", "")
+        .with_host(host)
+        .with_clock(clock.clone())
+        .with_rate_limit(
+            crate::RateLimit::new(1, std::time::Duration::from_secs(60))
+                .with_max_wait(std::time::Duration::from_secs(1)),
+        );
+
+    zoom.phone().setting("").await.unwrap();
+    requests.recv().await.unwrap();
+
+    // The bucket is empty, so the next call fails rather than wait for it.
+    let err = zoom.phone().setting("").await.unwrap_err();
+    assert_eq!(
+        err,
+        crate::ClientError::RateLimited {
+            retry_after: std::time::Duration::from_secs(60)
+        }
+    );
+    assert!(requests.try_recv().is_err());
+
+    // Once it refilled, the call goes through again.
+    clock.advance(std::time::Duration::from_secs(60));
+    let (host, _requests) = mock_server(vec![mock_response("", "{}")]).await;
+    zoom.with_host(host).phone().setting("").await.unwrap();
+}
+
+#[tokio::test]
+async fn test_find_template_by_name() {
+    use crate::traits::SettingTemplateOps;
+
+    let (host, mut requests) = mock_server(vec![
+        mock_response(
+            "",
+            r#"{"next_page_token":"page2","templates":[{"id":"t1","name":"Sales"}]}"#,
+        ),
+        mock_response(
+            "",
+            r#"{"next_page_token":"","templates":[{"id":"t2","name":"Support"}]}"#,
+        ),
+    ])
+    .await;
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+
+    let template = zoom
+        .phone()
+        .find_template_by_name("site1", "Support")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(template.id, "t2");
+
+    let first = requests.recv().await.unwrap();
+    assert!(
+        first.starts_with("GET /phone/setting_templates?site_id=site1 "),
+        "{}",
+        first
+    );
+    let second = requests.recv().await.unwrap();
+    assert!(
+        second.starts_with("GET /phone/setting_templates?site_id=site1&next_page_token=page2 "),
+        "{}",
+        second
+    );
+}
+
+#[tokio::test]
+async fn test_circuit_breaker() {
+    let error = || mock_response("", "{}").replacen("200 OK", "500 Internal Server Error", 1);
+    let (host, mut requests) =
+        mock_server(vec![error(), error(), error(), mock_response("", "{}")]).await;
+    let clock = crate::MockClock::new();
+    let zoom = crate::Client::new("", "", "", "token", "")
+        .with_host(host)
+        .with_clock(clock.clone())
+        .with_circuit_breaker(crate::CircuitConfig::new(
+            2,
+            std::time::Duration::from_secs(30),
+        ));
+
+    for _ in 0..2 {
+        let err = zoom.phone().setting("").await.unwrap_err();
+        assert_eq!(err.status(), reqwest::StatusCode::INTERNAL_SERVER_ERROR);
+        requests.recv().await.unwrap();
+    }
+
+    // Two failures in a row open the circuit, so the next call fails fast.
+    let err = zoom.phone().setting("").await.unwrap_err();
+    assert_eq!(
+        err,
+        crate::ClientError::CircuitOpen {
+            retry_after: std::time::Duration::from_secs(30)
+        }
+    );
+    assert!(requests.try_recv().is_err());
+
+    // The circuit is for the host the request goes to, so one the interceptor
+    // sends to another host is still sent.
+    let (other, mut other_requests) = mock_server(vec![mock_response("", "{}")]).await;
+    let other: reqwest::Url = other.parse().unwrap();
+    zoom.with_interceptor(move |url| {
+        url.set_host(Some("localhost")).unwrap();
+        url.set_port(other.port()).unwrap();
+    })
+    .phone()
+    .setting("")
+    .await
+    .unwrap();
+    other_requests.recv().await.unwrap();
+
+    // After the cooldown, one call is let through, and as it fails too the
+    // circuit opens again.
+    clock.advance(std::time::Duration::from_secs(30));
+    let err = zoom.phone().setting("").await.unwrap_err();
+    assert_eq!(err.status(), reqwest::StatusCode::INTERNAL_SERVER_ERROR);
+    requests.recv().await.unwrap();
+    let err = zoom.phone().setting("").await.unwrap_err();
+    assert_eq!(
+        err,
+        crate::ClientError::CircuitOpen {
+            retry_after: std::time::Duration::from_secs(30)
+        }
+    );
+
+    // After another cooldown, the call succeeds and closes the circuit.
+    clock.advance(std::time::Duration::from_secs(30));
+    zoom.phone().setting("").await.unwrap();
+    requests.recv().await.unwrap();
+}
+
+#[test]
+fn test_circuit_breaker_probe() {
+    let breaker = crate::CircuitBreaker {
+        config: crate::CircuitConfig::new(1, std::time::Duration::from_secs(30)),
+        circuits: Default::default(),
+    };
+    let now = std::time::Instant::now();
+    breaker.record("zoom.us", now, true);
+    assert_eq!(
+        breaker.admit("zoom.us", now),
+        Err(std::time::Duration::from_secs(30))
+    );
+    assert_eq!(breaker.admit("other.zoom.us", now), Ok(()));
+
+    // After the cooldown only one probe is let through until it is recorded.
+    let now = now + std::time::Duration::from_secs(30);
+    assert_eq!(breaker.admit("zoom.us", now), Ok(()));
+    assert_eq!(
+        breaker.admit("zoom.us", now),
+        Err(std::time::Duration::from_secs(30))
+    );
+    breaker.record("zoom.us", now, false);
+    assert_eq!(breaker.admit("zoom.us", now), Ok(()));
+    assert_eq!(breaker.admit("zoom.us", now), Ok(()));
+
+    // A probe that is never recorded is given up on after another cooldown.
+    breaker.record("zoom.us", now, true);
+    let now = now + std::time::Duration::from_secs(30);
+    assert_eq!(breaker.admit("zoom.us", now), Ok(()));
+    let now = now + std::time::Duration::from_secs(30);
+    assert_eq!(breaker.admit("zoom.us", now), Ok(()));
+}
+
+#[tokio::test]
+async fn test_provision_user_rolls_back() {
+    use crate::traits::ProvisionOps;
+
+    let no_content = || "HTTP/1.1 204 No Content\r\nconnection: close\r\n\r\n".to_string();
+    let (host, mut requests) = mock_server(vec![
+        no_content(),
+        mock_response("", r#"{"code":300,"message":"Invalid phone number."}"#).replacen(
+            "200 OK",
+            "400 Bad Request",
+            1,
+        ),
+        no_content(),
+    ])
+    .await;
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+
+    let number = crate::types::PhoneUserResponseNumbers {
+        id: String::new(),
+        number: "+15550100".to_string(),
+    };
+    let err = zoom
+        .phone()
+        .provision_user(
+            "u1",
+            &crate::calling_plan::CallingPlanType::TwoHundred,
+            &number,
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<crate::ClientError>().unwrap().status(),
+        reqwest::StatusCode::BAD_REQUEST
+    );
+
+    // The plan was assigned, the number was not, so the plan was unassigned.
+    for expected in [
+        "POST /phone/users/u1/calling_plans ",
+        "POST /phone/users/u1/phone_numbers ",
+        "DELETE /phone/users/u1/calling_plans/200 ",
+    ] {
+        let request = requests.recv().await.unwrap();
+        assert!(request.starts_with(expected), "{}", request);
+    }
+}
+
+#[tokio::test]
+async fn test_unassign_calling_plan_not_assigned() {
+    let (host, mut requests) = mock_server(vec![mock_response(
+        "",
+        r#"{"id":"u1","calling_plan":[{"type":200}]}"#,
+    )])
+    .await;
+    let zoom = crate::Client::new("", "", "", "token", "")
+        .with_host(host)
+        .with_calling_plan_check(true);
+
+    let err = zoom
+        .phone()
+        .unassign_calling_plan("u1", &crate::calling_plan::CallingPlanType::OneHundred)
+        .await
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("user u1 has no calling plan of type 100 to unassign"),
+        "{}",
+        err
+    );
+
+    // Only the user was fetched, nothing was unassigned.
+    let request = requests.recv().await.unwrap();
+    assert!(request.starts_with("GET /phone/users/u1 "), "{}", request);
+    assert!(requests.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn test_unassign_calling_plan_any_type() {
+    use crate::calling_plan::CallingPlanType;
+
+    // Plan types are open ended, the ones without a name round trip as they are.
+    let plan: CallingPlanType = "3098".parse().unwrap();
+    assert_eq!(plan, CallingPlanType::Other("3098".to_string()));
+    assert_eq!(plan.to_string(), "3098");
+    assert_eq!(CallingPlanType::from("200"), CallingPlanType::TwoHundred);
+    assert_eq!(CallingPlanType::TwoHundred.to_string(), "200");
+
+    let (host, mut requests) = mock_server(vec![
+        mock_response("", r#"{"id":"u1","calling_plan":[{"type":3098}]}"#),
+        "HTTP/1.1 204 No Content\r\nconnection: close\r\n\r\n".to_string(),
+    ])
+    .await;
+    let zoom = crate::Client::new("", "", "", "token", "")
+        .with_host(host)
+        .with_calling_plan_check(true);
+
+    // So any of them is checked and sent as is.
+    zoom.phone()
+        .unassign_calling_plan("u1", &plan)
+        .await
+        .unwrap();
+    requests.recv().await.unwrap();
+    let request = requests.recv().await.unwrap();
+    assert!(
+        request.starts_with("DELETE /phone/users/u1/calling_plans/3098 "),
+        "{}",
+        request
+    );
+}
+
+#[tokio::test]
+async fn test_add_members_max_items() {
+    let (host, mut requests) = mock_server(vec![mock_response("", "{}")]).await;
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+
+    // The spec allows at most 30 members at once.
+    let members: Vec<serde_json::Value> = (0..31)
+        .map(|i| serde_json::json!({ "id": format!("u{}", i) }))
+        .collect();
+    let body: crate::types::AddRoleMembersRequest =
+        serde_json::from_value(serde_json::json!({ "members": members })).unwrap();
+
+    let err = zoom.roles().add_members("r1", &body).await.unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "members must have at most 30 items, it has 31"
+    );
+    assert!(requests.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn test_scoped_for_site() {
+    let (host, mut requests) = mock_server(vec![
+        mock_response("", r#"{"users":[]}"#),
+        mock_response("", r#"{"users":[]}"#),
+        mock_response("", "{}"),
+    ])
+    .await;
+    let zoom = crate::Client::new("", "", "", "token", "")
+        .with_host(host)
+        .scoped_for_site("site1");
+
+    zoom.phone().list_users(0, "", "").await.unwrap();
+    let request = requests.recv().await.unwrap();
+    assert!(
+        request.starts_with("GET /phone/users?site_id=site1 "),
+        "{}",
+        request
+    );
+
+    // A site given to the call wins over the scope.
+    zoom.phone().list_users(0, "", "site2").await.unwrap();
+    let request = requests.recv().await.unwrap();
+    assert!(
+        request.starts_with("GET /phone/users?site_id=site2 "),
+        "{}",
+        request
+    );
+
+    // Calls that do not take a site are sent as they are.
+    zoom.phone().setting("").await.unwrap();
+    let request = requests.recv().await.unwrap();
+    assert!(request.starts_with("GET /phone/settings "), "{}", request);
+}
+
+#[tokio::test]
+async fn test_error_names_call() {
+    let (host, _requests) = mock_server(vec![mock_response(
+        "",
+        r#"{"code":1001,"message":"User does not exist."}"#,
+    )
+    .replacen("200 OK", "404 Not Found", 1)])
+    .await;
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+
+    let err = zoom.phone().user("a b").await.unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("GET /phone/users/{userId} [userId=a b]: code: 404 Not Found"));
+
+    // The response of the API is still there.
+    assert!(err.to_string().contains("User does not exist."));
+    match err {
+        crate::ClientError::HttpError { status, call, .. } => {
+            assert_eq!(status, reqwest::StatusCode::NOT_FOUND);
+            assert_eq!(call.unwrap(), "GET /phone/users/{userId} [userId=a b]");
+        }
+        e => panic!("unexpected error: {:?}", e),
+    }
+}
+
+#[test]
+fn test_one_or_many() {
+    let one: crate::types::OneOrMany<crate::types::Site> =
+        serde_json::from_str(r#"{"id": "a", "name": "Main"}"#).unwrap();
+    let many: crate::types::OneOrMany<crate::types::Site> =
+        serde_json::from_str(r#"[{"id": "a", "name": "Main"}, {"id": "b", "name": "Branch"}]"#)
+            .unwrap();
+
+    let one = one.into_vec();
+    assert_eq!(one.len(), 1);
+    assert_eq!(one[0].name, "Main");
+
+    let many = many.into_vec();
+    assert_eq!(many.len(), 2);
+    assert_eq!(many[0], one[0]);
+    assert_eq!(many[1].id, "b");
+}
+
+#[tokio::test]
+async fn test_replay_list_users() {
+    // Record the fixture again from the API with a real token, with
+    // `RECORD_FIXTURES=1 ZOOM_TOKEN=... cargo test --features record`.
+    let zoom = if std::env::var_os("RECORD_FIXTURES").is_some() {
+        crate::Client::new_from_env(std::env::var("ZOOM_TOKEN").unwrap(), "")
+    } else {
+        crate::Client::new("", "", "", "", "")
+    }
+    .with_fixtures(concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures"));
+
+    let users = zoom
+        .users()
+        .get_page(crate::types::UsersStatus::Active, 30, "", "", None, "")
+        .await
+        .unwrap();
+    assert_eq!(users.len(), 2);
+    assert_eq!(users[0].email, "ada@example.com");
+    assert_eq!(users[1].dept, "Engineering");
+    assert_eq!(users[1].status, Some(crate::types::UsersStatus::Active));
+}
+
+#[tokio::test]
+async fn test_between_sends_utc_times() {
+    let (host, mut requests) = mock_server(vec![mock_response("", r#"{"call_logs":[]}"#)]).await;
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+
+    let from = DateTime::<Utc>::from_utc(
+        NaiveDate::from_ymd(2021, 8, 15).and_hms_milli(1, 52, 41, 500),
+        Utc,
+    );
+    let to = DateTime::<Utc>::from_utc(NaiveDate::from_ymd(2021, 8, 16).and_hms(0, 0, 0), Utc);
+    assert_eq!(crate::utils::date_time_param(&from), "2021-08-15T01:52:41Z");
+
+    zoom.phone()
+        .account_call_logs_between(
+            0,
+            from,
+            to,
+            "",
+            "",
+            "",
+            crate::types::TimeType::StartTime,
+            "",
+        )
+        .await
+        .unwrap();
+    let request = requests.recv().await.unwrap();
+    assert!(
+        request.starts_with(
+            "GET /phone/call_logs?from=2021-08-15T01%3A52%3A41Z&to=2021-08-16T00%3A00%3A00Z "
+        ),
+        "{}",
+        request
+    );
+}
+
+#[tokio::test]
+async fn test_observers_see_each_attempt() {
+    let (host, mut requests) = mock_server(vec![
+        mock_response("", r#"{"code":500,"message":"Internal error."}"#).replacen(
+            "200 OK",
+            "500 Internal Server Error",
+            1,
+        ),
+        mock_response("", "{}"),
+    ])
+    .await;
+
+    let attempts = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let statuses = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let (a, s) = (attempts.clone(), statuses.clone());
+    let zoom = crate::Client::new("", "", "", "token", "")
+        .with_host(host)
+        .with_retry(crate::RetryPolicy::new(
+            2,
+            std::time::Duration::from_millis(1),
+        ))
+        .with_on_request(move |r| {
+            a.lock()
+                .unwrap()
+                .push((r.attempt, r.url.path().to_string()))
+        })
+        .with_on_response(move |r| s.lock().unwrap().push(r.status));
+
+    zoom.phone().setting("").await.unwrap();
+    requests.recv().await.unwrap();
+    requests.recv().await.unwrap();
+
+    assert_eq!(
+        *attempts.lock().unwrap(),
+        vec![
+            (1, "/phone/settings".to_string()),
+            (2, "/phone/settings".to_string())
+        ]
+    );
+    assert_eq!(
+        *statuses.lock().unwrap(),
+        vec![
+            Some(reqwest::StatusCode::INTERNAL_SERVER_ERROR),
+            Some(reqwest::StatusCode::OK)
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_retry_budget_reports_retries() {
+    let error = mock_response("", r#"{"code":500,"message":"Internal error."}"#).replacen(
+        "200 OK",
+        "500 Internal Server Error",
+        1,
+    );
+    let (host, mut requests) =
+        mock_server(vec![error.clone(), error, mock_response("", "{}")]).await;
+
+    let budgets = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let b = budgets.clone();
+    let zoom = crate::Client::new("", "", "", "token", "")
+        .with_host(host)
+        .with_retry(crate::RetryPolicy::new(
+            3,
+            std::time::Duration::from_millis(1),
+        ))
+        .with_on_retry_budget(move |r| {
+            b.lock()
+                .unwrap()
+                .push((r.url.path().to_string(), r.retries, r.exhausted))
+        });
+
+    zoom.phone().setting("").await.unwrap();
+    for _ in 0..3 {
+        requests.recv().await.unwrap();
+    }
+
+    assert_eq!(
+        *budgets.lock().unwrap(),
+        vec![("/phone/settings".to_string(), 2, false)]
+    );
+}
+
+#[tokio::test]
+async fn test_page_returns_next_page_token() {
+    let (host, mut requests) = mock_server(vec![mock_response(
+        "",
+        r#"{"next_page_token":"tok2","page_size":1,"phone_numbers":[{"id":"n1","number":"+14155550100"}],"total_records":2}"#,
+    )])
+    .await;
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+
+    let (numbers, next_page_token) = zoom
+        .phone()
+        .list_account_numbers_page("tok1", None, None, 1, None, false, "")
+        .await
+        .unwrap();
+    let request = requests.recv().await.unwrap();
+    assert!(
+        request.starts_with("GET /phone/numbers?next_page_token=tok1&page_size=1 "),
+        "{}",
+        request
+    );
+
+    assert_eq!(numbers.len(), 1);
+    assert_eq!(numbers[0].id, "n1");
+    assert_eq!(next_page_token, "tok2");
+}
+
+#[tokio::test]
+async fn test_number_id_from_list() {
+    let (host, mut requests) = mock_server(vec![
+        mock_response(
+            "",
+            r#"{"next_page_token":"","page_size":1,"phone_numbers":[{"id":"n 1","number":"+14155550100"}],"total_records":1}"#,
+        ),
+        mock_response("", r#"{"id":"n 1","number":"+14155550100"}"#),
+    ])
+    .await;
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+
+    let numbers = zoom
+        .phone()
+        .list_account_numbers("", None, None, 1, None, false, "")
+        .await
+        .unwrap();
+    requests.recv().await.unwrap();
+
+    // The id goes in its own segment, escaped.
+    let number_id = numbers[0].number_id();
+    assert_eq!(number_id.as_str(), "n 1");
+    let number = zoom.phone().get_number_details(&number_id).await.unwrap();
+    let request = requests.recv().await.unwrap();
+    assert!(
+        request.starts_with("GET /phone/numbers/n%201 "),
+        "{}",
+        request
+    );
+    assert_eq!(number.number_id(), number_id);
+}
+
+#[cfg(feature = "validate-responses")]
+#[tokio::test]
+async fn test_validate_responses() {
+    // The spec has the number as a string, which serde would quietly make empty.
+    let (host, _requests) = mock_server(vec![mock_response(
+        "",
+        r#"{"next_page_token":"","page_size":1,"phone_numbers":[{"id":"n1","number":14155550100}],"total_records":1}"#,
+    )])
+    .await;
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+
+    let err = zoom
+        .phone()
+        .list_account_numbers_page("", None, None, 1, None, false, "")
+        .await
+        .unwrap_err();
+    match &err {
+        crate::ClientError::SchemaMismatch { errors } => {
+            assert!(
+                errors
+                    .iter()
+                    .any(|e| e.starts_with("/phone_numbers/0/number: ")),
+                "{:?}",
+                errors
+            );
+        }
+        _ => panic!("expected a schema mismatch, got {:?}", err),
+    }
+}
+
+#[tokio::test]
+async fn test_conflicting_params() {
+    let (host, mut requests) = mock_server(vec![mock_response("", "")]).await;
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+
+    // The docs say to pass a contact or a channel, not both.
+    let err = zoom
+        .chat_messages()
+        .delete("me", "m1", "jchill@example.com", "c1")
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        crate::ClientError::ConflictingParams {
+            params: vec!["to_contact".to_string(), "to_channel".to_string()],
+        }
+    );
+    assert!(requests.try_recv().is_err());
+
+    zoom.chat_messages()
+        .delete("me", "m1", "", "c1")
+        .await
+        .unwrap();
+    let request = requests.recv().await.unwrap();
+    assert!(request.starts_with("DELETE /chat/users/me/messages/m1?to_channel=c1 "));
+}
+
+#[tokio::test]
+async fn test_download_to_file() {
+    let body = "not really an mp4, but it will do";
+    let (host, mut requests) = mock_server(vec![mock_response("", body)]).await;
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(&host);
+
+    // Download links are full urls, as a recording's `download_url` is.
+    let path = std::env::temp_dir().join(format!("zoom-download-{}.mp4", std::process::id()));
+    let written = zoom
+        .download_to_file(&format!("{}/rec/download/exImJm", host), &path)
+        .await
+        .unwrap();
+    let request = requests.recv().await.unwrap();
+    assert!(request.starts_with("GET /rec/download/exImJm "));
+    assert!(request
+        .to_lowercase()
+        .contains("authorization: bearer token"));
+
+    assert_eq!(written, body.len() as u64);
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), body);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+async fn test_download_to_file_short_body() {
+    let body = "not really an mp4";
+    let response = mock_response("", body).replacen(
+        &format!("content-length: {}", body.len()),
+        "content-length: 100",
+        1,
+    );
+    let (host, _requests) = mock_server(vec![response]).await;
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(&host);
+
+    // The connection closes before the body the response said it had is in.
+    let path = std::env::temp_dir().join(format!("zoom-short-{}.mp4", std::process::id()));
+    zoom.download_to_file(&format!("{}/rec/download/exImJm", host), &path)
+        .await
+        .unwrap_err();
+
+    // Neither the file nor the part of it that was written is left behind.
+    assert!(!path.exists());
+    let mut part = path.into_os_string();
+    part.push(".part");
+    assert!(!std::path::Path::new(&part).exists());
+}
+
+#[tokio::test]
+async fn test_default_date_types_left_out() {
+    let (host, mut requests) = mock_server(vec![
+        mock_response("", r#"{"call_logs":[]}"#),
+        mock_response("", r#"{"recordings":[]}"#),
+    ])
+    .await;
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+
+    // Zoom takes `startTime` and `start_time` when they are left out.
+    assert_eq!(
+        crate::types::TimeType::default(),
+        crate::types::TimeType::StartTime
+    );
+    assert_eq!(
+        crate::types::QueryDateType::default(),
+        crate::types::QueryDateType::StartTime
+    );
+
+    zoom.phone()
+        .account_call_logs(
+            30,
+            "2021-07-01",
+            "2021-07-31",
+            "all",
+            "",
+            "",
+            Default::default(),
+            "",
+        )
+        .await
+        .unwrap();
+    let request = requests.recv().await.unwrap();
+    assert!(!request.lines().next().unwrap().contains("time_type"));
+
+    zoom.phone()
+        .get_recordings(0, "", "", "", None, None, "", Default::default())
+        .await
+        .unwrap();
+    let request = requests.recv().await.unwrap();
+    assert!(!request.lines().next().unwrap().contains("query_date_type"));
+}
+
+#[tokio::test]
+async fn test_stream_pages() {
+    use futures::StreamExt;
+
+    let (host, mut requests) = mock_server(vec![
+        mock_response(
+            "",
+            r#"{"call_logs":[{"id":"c1"},{"id":"c2"}],"next_page_token":"tok2+/="}"#,
+        ),
+        mock_response("", r#"{"call_logs":[{"id":"c3"}],"next_page_token":""}"#),
+    ])
+    .await;
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+
+    let mut call_logs = Box::pin(
+        zoom.phone()
+            .account_call_logs_stream("", "", "", "", Default::default(), "")
+            .await
+            .unwrap(),
+    );
+
+    // The second page is only asked for once the first is used up.
+    assert_eq!(call_logs.next().await.unwrap().unwrap().id, "c1");
+    assert_eq!(call_logs.next().await.unwrap().unwrap().id, "c2");
+    let request = requests.recv().await.unwrap();
+    assert!(!request.lines().next().unwrap().contains("next_page_token"));
+    assert!(requests.try_recv().is_err());
+
+    // The token is encoded, as it can have characters that mean something in a query.
+    assert_eq!(call_logs.next().await.unwrap().unwrap().id, "c3");
+    let request = requests.recv().await.unwrap();
+    assert!(request
+        .lines()
+        .next()
+        .unwrap()
+        .contains("next_page_token=tok2%2B%2F%3D"));
+
+    // The last page has no token, so the stream ends there.
+    assert!(call_logs.next().await.is_none());
+    assert!(requests.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn test_index_external_contacts() {
+    use crate::traits::ExternalContactOps;
+
+    let (host, mut requests) = mock_server(vec![
+        mock_response(
+            "",
+            r#"{"external_contacts":[{"external_contact_id":"e1","name":"Jill Chill"}],"next_page_token":"tok2"}"#,
+        ),
+        mock_response(
+            "",
+            r#"{"external_contacts":[{"external_contact_id":"e2","name":"Front Desk"},{"external_contact_id":"e3","name":"Front Desk"}],"next_page_token":""}"#,
+        ),
+    ])
+    .await;
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+
+    let contacts = zoom.phone().index_external_contacts().await.unwrap();
+    requests.recv().await.unwrap();
+    let request = requests.recv().await.unwrap();
+    assert!(request.starts_with("GET /phone/external_contacts?next_page_token=tok2 "));
+
+    assert_eq!(
+        contacts.get("Jill Chill").unwrap().external_contact_id,
+        "e1"
+    );
+    assert!(contacts.get("Jack Chill").is_none());
+
+    // Both contacts named the same are kept, in the order they were listed.
+    let front_desk: Vec<&str> = contacts
+        .get_all("Front Desk")
+        .iter()
+        .map(|c| c.external_contact_id.as_str())
+        .collect();
+    assert_eq!(front_desk, vec!["e2", "e3"]);
+}
+
+#[test]
+fn test_serialize_body_error() {
+    let zoom = crate::Client::new("", "", "", "token", "");
+
+    // JSON object keys must be strings, so a map keyed by pairs cannot be sent.
+    let mut body = std::collections::BTreeMap::new();
+    body.insert((1, 2), "a");
+    let err = zoom.serialize_body(&body).unwrap_err();
+    assert!(err.to_string().contains("key must be a string"));
+}
+
+#[tokio::test]
+async fn test_interceptor_routes_by_path() {
+    let (us_host, mut us_requests) = mock_server(vec![mock_response("", "{}")]).await;
+    let (eu_host, mut eu_requests) = mock_server(vec![mock_response("", "{}")]).await;
+    let eu_port = eu_host.parse::<reqwest::Url>().unwrap().port();
+
+    // Users with an id from the EU region are served by the EU host.
+    let zoom = crate::Client::new("", "", "", "token", "")
+        .with_host(us_host)
+        .with_interceptor(move |url| {
+            let eu = url
+                .path_segments()
+                .and_then(|mut s| s.nth(2))
+                .map_or(false, |id| id.starts_with("eu-"));
+            if eu {
+                url.set_port(eu_port).unwrap();
+            }
+        });
+
+    zoom.phone().user("eu-jchill").await.unwrap();
+    let request = eu_requests.recv().await.unwrap();
+    assert!(request.starts_with("GET /phone/users/eu-jchill "));
+
+    zoom.phone().user("us-jchill").await.unwrap();
+    let request = us_requests.recv().await.unwrap();
+    assert!(request.starts_with("GET /phone/users/us-jchill "));
+    assert!(eu_requests.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn test_master_phone() {
+    let (host, mut requests) = mock_server(vec![mock_response("", "{}")]).await;
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+
+    // The master scope calls are on their own accessor, not on `phone`.
+    let body = crate::types::UpdatePhoneSettingsRequest {
+        byoc: Some(crate::types::UpdatePhoneSettingsRequestByoc { enable: true }),
+        show_device_ip_for_call_log: None,
+    };
+    zoom.master_phone()
+        .update_settings("sub1", &body)
+        .await
+        .unwrap();
+    let request = requests.recv().await.unwrap();
+    assert!(request.starts_with("PATCH /accounts/sub1/phone/settings "));
+    assert!(request.contains(r#""byoc":{"enable":true}"#));
+    assert_eq!(
+        crate::master_phone::MasterPhone::UPDATE_SETTINGS_SCOPES,
+        &["phone:master"]
+    );
+}
+
+#[tokio::test]
+async fn test_body_sizes() {
+    // Serve the users page of the recorded fixture, to count what we know.
+    let fixture: serde_json::Value = serde_json::from_str(include_str!(
+        "../fixtures/GET_v2_users_page_size_30_status_active.json"
+    ))
+    .unwrap();
+    let users = fixture["body"].as_str().unwrap();
+    let (host, mut requests) =
+        mock_server(vec![mock_response("", users), mock_response("", "{}")]).await;
+
+    let sizes = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let s = sizes.clone();
+    let zoom = crate::Client::new("", "", "", "token", "")
+        .with_host(host)
+        .with_on_body_size(move |b| s.lock().unwrap().push((b.sent, b.received)));
+
+    // The response is still there to parse after it was counted.
+    let page = zoom
+        .users()
+        .get_page(crate::types::UsersStatus::Active, 30, "", "", None, "")
+        .await
+        .unwrap();
+    assert_eq!(page.len(), 2);
+    requests.recv().await.unwrap();
+
+    let update = crate::types::UpdateLocationRequest {
+        bssid: String::new(),
+        elin_phone_number_id: String::new(),
+        emergency_address_id: String::new(),
+        name: "HQ West".to_string(),
+        network_switches: Vec::new(),
+        private_ip: String::new(),
+        public_ip: String::new(),
+        sip_group_id: String::new(),
+    };
+    zoom.phone().update_location("loc1", &update).await.unwrap();
+    let request = requests.recv().await.unwrap();
+    let body = request.split("\r\n\r\n").nth(1).unwrap();
+
+    assert!(!body.is_empty());
+    assert_eq!(
+        *sizes.lock().unwrap(),
+        vec![(0, users.len() as u64), (body.len() as u64, 2)]
+    );
+}
+
+#[tokio::test]
+async fn test_body_sizes_streamed() {
+    let download = "not really an mp4, but it will do";
+    let (host, mut requests) =
+        mock_server(vec![mock_response("", download), mock_response("", "{}")]).await;
+
+    let sizes = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let s = sizes.clone();
+    let zoom = crate::Client::new("", "", "", "token", "")
+        .with_host(&host)
+        .with_request_body_limit(4)
+        .with_on_body_size(move |b| s.lock().unwrap().push((b.sent, b.received)));
+
+    // A download is counted as it is streamed to the file, which still gets
+    // all of it.
+    let path = std::env::temp_dir().join(format!("zoom-counted-{}.mp4", std::process::id()));
+    zoom.download_to_file(&format!("{}/rec/download/exImJm", host), &path)
+        .await
+        .unwrap();
+    requests.recv().await.unwrap();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), download);
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(*sizes.lock().unwrap(), vec![(0, download.len() as u64)]);
+
+    // So is an upload over the request body limit, as it is sent.
+    let upload: &'static [u8] = b"0123456789";
+    let (client, body) = zoom.reader_body(upload).await.unwrap();
+    assert!(body.as_bytes().is_none());
+    client
+        .request_raw(reqwest::Method::POST, "/upload", Some(body))
+        .await
+        .unwrap()
+        .bytes()
+        .await
+        .unwrap();
+    let request = requests.recv().await.unwrap();
+    assert!(request
+        .to_lowercase()
+        .contains("transfer-encoding: chunked"));
+    assert_eq!(
+        *sizes.lock().unwrap(),
+        vec![(0, download.len() as u64), (upload.len() as u64, 2)]
+    );
+}
+
+#[tokio::test]
+async fn test_typed_errors() {
+    let (host, _requests) = mock_server(vec![
+        mock_response("", r#"{"code":429,"message":"Too many requests."}"#).replacen(
+            "200 OK",
+            "429 Too Many Requests",
+            1,
+        ),
+        mock_response("", "not json"),
+    ])
+    .await;
+    let zoom = crate::Client::new("", "", "", "token", "")
+        .with_host(host)
+        .with_retry(crate::RetryPolicy::none());
+
+    // The status the API responded with can be matched on as it is.
+    match zoom.phone().setting("").await.unwrap_err() {
+        crate::ClientError::HttpError { status, call, .. } => {
+            assert_eq!(status, reqwest::StatusCode::TOO_MANY_REQUESTS);
+            assert_eq!(call.unwrap(), "GET /phone/settings");
+        }
+        e => panic!("unexpected error: {:?}", e),
+    }
+
+    // So can a body that is not the JSON of its type.
+    match zoom.phone().setting("").await.unwrap_err() {
+        crate::ClientError::Decode { error } => {
+            assert!(error.starts_with("GET /phone/settings: "), "{}", error)
+        }
+        e => panic!("unexpected error: {:?}", e),
+    }
+
+    // And a request that could not be sent at all.
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let closed = format!("http://{}", listener.local_addr().unwrap());
+    drop(listener);
+    let err = zoom
+        .with_host(closed)
+        .phone()
+        .setting("")
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, crate::ClientError::Transport { .. }),
+        "{:?}",
+        err
+    );
+    assert_eq!(err.status(), reqwest::StatusCode::BAD_GATEWAY);
+
+    // Each of them still converts to an `anyhow::Error` with `?`.
+    let err: anyhow::Error = err.into();
+    assert!(err.downcast_ref::<crate::ClientError>().is_some());
+}
+
+#[tokio::test]
+async fn test_omitted_enum_params() {
+    let (host, mut requests) = mock_server(vec![
+        mock_response("", r#"{"phone_numbers":[]}"#),
+        mock_response("", r#"{"phone_numbers":[]}"#),
+    ])
+    .await;
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+
+    // `None` sends no `type`, so the numbers are not filtered by it.
+    zoom.phone()
+        .list_account_numbers("", None, None, 0, None, false, "")
+        .await
+        .unwrap();
+    let request = requests.recv().await.unwrap();
+    let request_line = request.lines().next().unwrap();
+    assert!(!request_line.contains("type="), "{}", request_line);
+
+    zoom.phone()
+        .list_account_numbers(
+            "",
+            Some(crate::types::ListAccountPhoneNumbersType::Unassigned),
+            None,
+            0,
+            Some(crate::types::Type::Tollfree),
+            false,
+            "",
+        )
+        .await
+        .unwrap();
+    let request = requests.recv().await.unwrap();
+    let request_line = request.lines().next().unwrap();
+    assert!(request_line.contains("type=unassigned"), "{}", request_line);
+    assert!(
+        request_line.contains("number_type=tollfree"),
+        "{}",
+        request_line
+    );
+    assert!(
+        !request_line.contains("extension_type="),
+        "{}",
+        request_line
+    );
+}
+
+#[tokio::test]
+async fn test_multipart_form() {
+    let (host, mut requests) = mock_server(vec![mock_response("", "")]).await;
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+
+    zoom.users().picture("me", b"picture").await.unwrap();
+
+    // The file is a part of the form, named as the API wants it.
+    let request = requests.recv().await.unwrap();
+    assert!(
+        request.starts_with("POST /users/me/picture "),
+        "{}",
+        request
+    );
+    assert!(
+        request.contains("content-type: multipart/form-data; boundary="),
+        "{}",
+        request
+    );
+    let head = &request[..request.find("\r\n\r\n").unwrap()];
+    assert_eq!(head.matches("content-type:").count(), 1, "{}", head);
+    assert!(
+        request.contains(r#"Content-Disposition: form-data; name="pic_file"; filename="pic_file""#),
+        "{}",
+        request
+    );
+    assert!(request.contains("\r\n\r\npicture\r\n"), "{}", request);
+}
+
+#[test]
+fn test_build_info() {
+    let info = crate::Client::build_info();
+    assert_eq!(info.version, env!("CARGO_PKG_VERSION"));
+    assert_eq!(info.spec_version, "2.0.0");
+
+    // The revision is the FNV-1a hash of the spec, so it changes with it.
+    assert_eq!(info.spec_revision, "49737fee6d025c4a");
+
+    // The spec is not packaged with the crate, so only check the hash against
+    // it when building from the repo.
+    if let Ok(spec) = std::fs::read(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../specs/zoom/zoom.json"
+    )) {
+        let hash = spec.iter().fold(0xcbf2_9ce4_8422_2325_u64, |hash, b| {
+            (hash ^ u64::from(*b)).wrapping_mul(0x0000_0100_0000_01b3)
+        });
+        assert_eq!(info.spec_revision, format!("{:016x}", hash));
+    }
+}
+
+#[tokio::test]
+async fn test_query_builder() {
+    let (host, mut requests) =
+        mock_server(vec![mock_response("", r#"{"phone_numbers":[]}"#)]).await;
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+
+    // Only the parameters set on the request are sent.
+    zoom.phone()
+        .list_account_numbers_request()
+        .site_id("abc")
+        .send()
+        .await
+        .unwrap();
+    let request = requests.recv().await.unwrap();
+    let request_line = request.lines().next().unwrap();
+    assert!(request_line.contains("site_id=abc"), "{}", request_line);
+    for name in &[
+        "next_page_token=",
+        "type=",
+        "page_size=",
+        "pending_numbers=",
+    ] {
+        assert!(!request_line.contains(name), "{}", request_line);
+    }
+}
+
+#[test]
+fn test_date_in_timezone() {
+    use chrono::TimeZone;
+
+    // 05:30 UTC on the 1st of March is still the 28th of February in Los
+    // Angeles, so that is the day Zoom reads for the account.
+    let time = chrono::Utc.ymd(2021, 3, 1).and_hms(5, 30, 0);
+    let zoom = crate::Client::new("", "", "", "token", "");
+    assert_eq!(
+        zoom.date_in_timezone(&time),
+        chrono::NaiveDate::from_ymd(2021, 3, 1)
+    );
+
+    let zoom = zoom.with_timezone(chrono_tz::America::Los_Angeles);
+    assert_eq!(
+        zoom.date_in_timezone(&time),
+        chrono::NaiveDate::from_ymd(2021, 2, 28)
+    );
+
+    // A time given in another timezone is converted too.
+    let time = chrono::FixedOffset::east(9 * 3600)
+        .ymd(2021, 3, 1)
+        .and_hms(23, 0, 0);
+    assert_eq!(
+        zoom.date_in_timezone(&time),
+        chrono::NaiveDate::from_ymd(2021, 3, 1)
+    );
+}
+
+#[tokio::test]
+async fn test_with_user_timezone() {
+    let (host, mut requests) = mock_server(vec![mock_response(
+        "",
+        r#"{"id":"u1","email":"jill@example.com","timezone":"Asia/Tokyo"}"#,
+    )])
+    .await;
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+
+    let zoom = zoom.with_user_timezone().await.unwrap();
+    assert_eq!(zoom.timezone(), chrono_tz::Asia::Tokyo);
+    let request = requests.recv().await.unwrap();
+    assert!(request.starts_with("GET /users/me"), "{}", request);
+}
+
+#[tokio::test]
+async fn test_failpoints() {
+    let (host, mut requests) = mock_server(vec![mock_response("", "{}")]).await;
+    let statuses = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let s = statuses.clone();
+    let zoom = crate::Client::new("", "", "", "token", "")
+        .with_host(host)
+        .with_retry(crate::RetryPolicy::new(
+            2,
+            std::time::Duration::from_millis(1),
+        ))
+        .with_on_response(move |r| s.lock().unwrap().push(r.status))
+        .with_failpoints(crate::Failpoints::new().fail(
+            reqwest::Method::GET,
+            "/phone/settings",
+            1,
+            crate::Failpoint::Status(reqwest::StatusCode::TOO_MANY_REQUESTS),
+        ));
+
+    // The first attempt gets the injected 429 without reaching the server, and
+    // the retry gets the server's response.
+    zoom.phone().setting("").await.unwrap();
+    requests.recv().await.unwrap();
+    assert!(requests.try_recv().is_err());
+    assert_eq!(
+        *statuses.lock().unwrap(),
+        vec![
+            Some(reqwest::StatusCode::TOO_MANY_REQUESTS),
+            Some(reqwest::StatusCode::OK)
+        ]
+    );
+
+    // A malformed body fails the call, it is not retried.
+    let zoom = zoom.with_failpoints(crate::Failpoints::new().fail(
+        reqwest::Method::GET,
+        "/phone/settings",
+        1,
+        crate::Failpoint::Body("{".to_string()),
+    ));
+    assert!(zoom.phone().setting("").await.is_err());
+}
+
+#[test]
+fn test_url_with_query() {
+    assert_eq!(crate::Client::url_with_query("/users", &[]), "/users");
+    assert_eq!(
+        crate::Client::url_with_query("/users", &[("status", "".to_string())]),
+        "/users"
+    );
+    assert_eq!(
+        crate::Client::url_with_query(
+            "/users",
+            &[
+                ("status", "active".to_string()),
+                ("role_id", "".to_string()),
+                ("page_size", "30".to_string()),
+            ]
+        ),
+        "/users?status=active&page_size=30"
+    );
+    assert_eq!(
+        crate::Client::url_with_query("/phone/users", &[("keyword", "a&b c".to_string())]),
+        "/phone/users?keyword=a%26b+c"
+    );
+}
+
+#[tokio::test]
+async fn test_retry_policy_jitter() {
+    let (host, mut requests) = mock_server(vec![
+        mock_response("", "{}").replacen("200 OK", "429 Too Many Requests", 1),
+        mock_response("", "{}"),
+    ])
+    .await;
+    let clock = crate::MockClock::new();
+    let zoom = crate::Client::new("", "", "", "token", "")
+        .with_host(host)
+        .with_clock(clock.clone())
+        .with_retry(crate::RetryPolicy::new(2, std::time::Duration::from_secs(30)).with_jitter());
+
+    // The 429 is retried after somewhere between half the backoff and all of it.
+    let start = crate::Clock::now(&clock);
+    zoom.phone().setting("").await.unwrap();
+    let waited = crate::Clock::now(&clock) - start;
+    assert!(waited >= std::time::Duration::from_secs(15));
+    assert!(waited <= std::time::Duration::from_secs(30));
+    for _ in 0..2 {
+        requests.recv().await.unwrap();
+    }
+}
+
+#[tokio::test]
+async fn test_filter_pages() {
+    use futures::{StreamExt, TryStreamExt};
+
+    let (host, mut requests) = mock_server(vec![
+        mock_response(
+            "",
+            r#"{"call_logs":[{"id":"c1"},{"id":"c2"}],"next_page_token":"tok2"}"#,
+        ),
+        mock_response("", r#"{"call_logs":[{"id":"c3"}],"next_page_token":""}"#),
+    ])
+    .await;
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+
+    let call_logs: Vec<crate::types::AccountCallLogsResponse> = zoom
+        .phone()
+        .account_call_logs_filter("", "", "", "", Default::default(), "", |c| c.id == "c2")
+        .await
+        .unwrap()
+        .take(1)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(call_logs.len(), 1);
+    assert_eq!(call_logs[0].id, "c2");
+
+    // The first page had the item, so the second is never asked for.
+    requests.recv().await.unwrap();
+    assert!(requests.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn test_last_rate_limit() {
+    let (host, _requests) = mock_server(vec![
+        mock_response("", "{}"),
+        mock_response(
+            "x-ratelimit-limit: 30\r\nx-ratelimit-remaining: 29\r\n",
+            "{}",
+        ),
+        mock_response(
+            "x-ratelimit-limit: 30\r\nx-ratelimit-remaining: soon\r\nretry-after: 5\r\n",
+            "{}",
+        ),
+        mock_response("", "{}"),
+    ])
+    .await;
+    let zoom = crate::Client::new("", "", "", "token", "").with_host(host);
+
+    // Nothing until a response says.
+    zoom.phone().setting("").await.unwrap();
+    assert_eq!(zoom.last_rate_limit(), None);
+
+    zoom.phone().setting("").await.unwrap();
+    assert_eq!(
+        zoom.last_rate_limit(),
+        Some(crate::RateLimitHeaders {
+            limit: Some(30),
+            remaining: Some(29),
+            retry_after: None,
+        })
+    );
+
+    // A header that is not a number is left out, rather than failing the call.
+    zoom.phone().setting("").await.unwrap();
+    assert_eq!(
+        zoom.last_rate_limit(),
+        Some(crate::RateLimitHeaders {
+            limit: Some(30),
+            remaining: None,
+            retry_after: Some(std::time::Duration::from_secs(5)),
+        })
+    );
+
+    // A response without the headers keeps what the last one said.
+    zoom.phone().setting("").await.unwrap();
+    assert_eq!(zoom.last_rate_limit().unwrap().limit, Some(30));
+}
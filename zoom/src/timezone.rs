@@ -0,0 +1,45 @@
+use anyhow::{anyhow, Result};
+use chrono::TimeZone;
+
+impl crate::Client {
+    /// Read the dates of queries in `tz`, the timezone of the account, as Zoom
+    /// does, so `date_in_timezone` gives the day Zoom means by a time.
+    pub fn with_timezone(&self, tz: chrono_tz::Tz) -> Self {
+        let mut c = self.clone();
+        c.timezone = Some(tz);
+        c
+    }
+
+    /// Fetch the timezone of the user the token is for, from their profile, and
+    /// use it as `with_timezone` does.
+    ///
+    /// This is the user's timezone, not the account's: the account settings
+    /// have no timezone to fetch. Users set their own, so only use this when
+    /// the user is known to have the account's timezone, and otherwise pass the
+    /// account's to `with_timezone`.
+    pub async fn with_user_timezone(&self) -> Result<Self> {
+        let me = self.users().user("me", None, false).await?;
+        if me.user.timezone.is_empty() {
+            return Err(anyhow!("the user has no timezone set"));
+        }
+        let tz = me
+            .user
+            .timezone
+            .parse::<chrono_tz::Tz>()
+            .map_err(|e| anyhow!("unknown timezone `{}`: {}", me.user.timezone, e))?;
+        Ok(self.with_timezone(tz))
+    }
+
+    /// The timezone dates are read in, UTC unless one is set with
+    /// `with_timezone` or `with_user_timezone`.
+    pub fn timezone(&self) -> chrono_tz::Tz {
+        self.timezone.unwrap_or(chrono_tz::UTC)
+    }
+
+    /// The date of `time` in the timezone of the account, to pass it as one of
+    /// the `chrono::NaiveDate` parameters, such as `from` and `to`. Taking the
+    /// date of a UTC time instead would be a day off near midnight.
+    pub fn date_in_timezone<T: TimeZone>(&self, time: &chrono::DateTime<T>) -> chrono::NaiveDate {
+        time.with_timezone(&self.timezone()).naive_local().date()
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct TrackingField {
     pub client: Client,
@@ -12,6 +10,17 @@ impl TrackingField {
         TrackingField { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        TrackingField {
+            client: self.client.with_retry(policy),
+        }
+    }
+
+    /// The OAuth scopes for `trackingfield_list`, any one of them grants access.
+    pub const TRACKINGFIELD_LIST_SCOPES: &[&str] = &["trackingfield:read:admin"];
+
     /**
      * List tracking fields.
      *
@@ -26,9 +35,15 @@ impl TrackingField {
      */
     pub async fn trackingfield_list(&self) -> Result<crate::types::Domains> {
         let url = "/tracking_fields".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/tracking_fields", &[]))
     }
 
+    /// The OAuth scopes for `trackingfield_create`, any one of them grants access.
+    pub const TRACKINGFIELD_CREATE_SCOPES: &[&str] = &["trackingfield:write:admin"];
+
     /**
      * Create a tracking field.
      *
@@ -47,10 +62,14 @@ impl TrackingField {
     ) -> Result<crate::types::TrackingfieldGetResponseAllOf> {
         let url = "/tracking_fields".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/tracking_fields", &[]))
     }
 
+    /// The OAuth scopes for `trackingfield_get`, any one of them grants access.
+    pub const TRACKINGFIELD_GET_SCOPES: &[&str] = &["trackingfield:read:admin"];
+
     /**
      * Get a tracking field.
      *
@@ -72,14 +91,20 @@ impl TrackingField {
         &self,
         field_id: &str,
     ) -> Result<crate::types::TrackingfieldGetResponseAllOf> {
-        let url = format!(
-            "/tracking_fields/{}",
-            crate::progenitor_support::encode_path(&field_id.to_string()),
-        );
+        let url = format!("/tracking_fields/{}", self.client.encode_path(field_id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/tracking_fields/{fieldId}",
+                &[("fieldId", field_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `trackingfield_delete`, any one of them grants access.
+    pub const TRACKINGFIELD_DELETE_SCOPES: &[&str] = &["trackingfield:write:admin"];
+
     /**
      * Delete a tracking field.
      *
@@ -97,14 +122,42 @@ impl TrackingField {
      * * `field_id: &str` -- The Tracking Field ID.
      */
     pub async fn trackingfield_delete(&self, field_id: &str) -> Result<()> {
-        let url = format!(
-            "/tracking_fields/{}",
-            crate::progenitor_support::encode_path(&field_id.to_string()),
-        );
+        let url = format!("/tracking_fields/{}", self.client.encode_path(field_id),);
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/tracking_fields/{fieldId}",
+                &[("fieldId", field_id.to_string())],
+            )
+        })
     }
 
+    /**
+     * Delete a tracking field.
+     *
+     * This function performs a `DELETE` to the `/tracking_fields/{fieldId}` endpoint.
+     *
+     * As opposed to `trackingfield_delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn trackingfield_delete_if_exists(&self, field_id: &str) -> Result<bool> {
+        let url = format!("/tracking_fields/{}", self.client.encode_path(field_id),);
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/tracking_fields/{fieldId}",
+                    &[("fieldId", field_id.to_string())],
+                )
+            })
+    }
+
+    /// The OAuth scopes for `trackingfield_update`, any one of them grants access.
+    pub const TRACKINGFIELD_UPDATE_SCOPES: &[&str] = &["trackingfield:write:admin"];
+
     /**
      * Update a tracking field.
      *
@@ -126,13 +179,101 @@ impl TrackingField {
         field_id: &str,
         body: &crate::types::TrackingField,
     ) -> Result<()> {
-        let url = format!(
-            "/tracking_fields/{}",
-            crate::progenitor_support::encode_path(&field_id.to_string()),
-        );
+        let url = format!("/tracking_fields/{}", self.client.encode_path(field_id),);
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/tracking_fields/{fieldId}",
+                    &[("fieldId", field_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update a tracking field.
+     *
+     * This function performs a `PATCH` to the `/tracking_fields/{fieldId}` endpoint.
+     *
+     * As opposed to `trackingfield_update`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn trackingfield_update_if_changed<C: serde::Serialize>(
+        &self,
+        field_id: &str,
+        current: &C,
+        body: &crate::types::TrackingField,
+    ) -> Result<Option<()>> {
+        let url = format!("/tracking_fields/{}", self.client.encode_path(field_id),);
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/tracking_fields/{fieldId}",
+                    &[("fieldId", field_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`TrackingField`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn TrackingFieldApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait TrackingFieldApi: Send + Sync {
+    async fn trackingfield_list(&self) -> Result<crate::types::Domains>;
+    async fn trackingfield_create(
+        &self,
+        body: &crate::types::TrackingField,
+    ) -> Result<crate::types::TrackingfieldGetResponseAllOf>;
+    async fn trackingfield_get(
+        &self,
+        field_id: &str,
+    ) -> Result<crate::types::TrackingfieldGetResponseAllOf>;
+    async fn trackingfield_delete(&self, field_id: &str) -> Result<()>;
+    async fn trackingfield_delete_if_exists(&self, field_id: &str) -> Result<bool>;
+    async fn trackingfield_update(
+        &self,
+        field_id: &str,
+        body: &crate::types::TrackingField,
+    ) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl TrackingFieldApi for TrackingField {
+    async fn trackingfield_list(&self) -> Result<crate::types::Domains> {
+        TrackingField::trackingfield_list(self).await
+    }
+    async fn trackingfield_create(
+        &self,
+        body: &crate::types::TrackingField,
+    ) -> Result<crate::types::TrackingfieldGetResponseAllOf> {
+        TrackingField::trackingfield_create(self, body).await
+    }
+    async fn trackingfield_get(
+        &self,
+        field_id: &str,
+    ) -> Result<crate::types::TrackingfieldGetResponseAllOf> {
+        TrackingField::trackingfield_get(self, field_id).await
+    }
+    async fn trackingfield_delete(&self, field_id: &str) -> Result<()> {
+        TrackingField::trackingfield_delete(self, field_id).await
+    }
+    async fn trackingfield_delete_if_exists(&self, field_id: &str) -> Result<bool> {
+        TrackingField::trackingfield_delete_if_exists(self, field_id).await
+    }
+    async fn trackingfield_update(
+        &self,
+        field_id: &str,
+        body: &crate::types::TrackingField,
+    ) -> Result<()> {
+        TrackingField::trackingfield_update(self, field_id, body).await
     }
 }
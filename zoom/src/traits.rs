@@ -0,0 +1,100 @@
+use anyhow::Result;
+
+#[async_trait::async_trait]
+pub trait SettingTemplateOps {
+    /// Find a phone setting template by its name, since `get_setting_template`
+    /// needs its id. This lists all the templates of the site, or of the account
+    /// if `site_id` is empty, and returns the first one named `name`.
+    async fn find_template_by_name(
+        &self,
+        site_id: &str,
+        name: &str,
+    ) -> Result<Option<crate::types::Templates>>;
+}
+
+#[async_trait::async_trait]
+impl SettingTemplateOps for crate::phone::Phone {
+    async fn find_template_by_name(
+        &self,
+        site_id: &str,
+        name: &str,
+    ) -> Result<Option<crate::types::Templates>> {
+        let templates = self.list_all_setting_templates(site_id).await?;
+        Ok(templates.into_iter().find(|t| t.name == name))
+    }
+}
+
+#[async_trait::async_trait]
+pub trait ProvisionOps {
+    /// Provision a phone user: assign them the calling plan `plan`, then
+    /// `number`, by its id or the number itself. If the number can not be
+    /// assigned, the calling plan is unassigned again before returning the error,
+    /// so the user is left as they were.
+    async fn provision_user(
+        &self,
+        user_id: &str,
+        plan: &crate::calling_plan::CallingPlanType,
+        number: &crate::types::PhoneUserResponseNumbers,
+    ) -> Result<crate::types::AddByocNumberResponse>;
+}
+
+#[async_trait::async_trait]
+impl ProvisionOps for crate::phone::Phone {
+    async fn provision_user(
+        &self,
+        user_id: &str,
+        plan: &crate::calling_plan::CallingPlanType,
+        number: &crate::types::PhoneUserResponseNumbers,
+    ) -> Result<crate::types::AddByocNumberResponse> {
+        self.assign_calling_plan(
+            user_id,
+            &crate::types::AssignCallingPlanRequest {
+                calling_plans: vec![crate::types::CallingPlans {
+                    type_: plan.to_string(),
+                }],
+            },
+        )
+        .await?;
+
+        let assigned = self
+            .assign_number(
+                user_id,
+                &crate::types::AddByocNumberResponse {
+                    phone_numbers: vec![number.clone()],
+                },
+            )
+            .await;
+        match assigned {
+            Ok(numbers) => Ok(numbers),
+            Err(e) => {
+                if let Err(rollback) = self.unassign_calling_plan(user_id, plan).await {
+                    return Err(anyhow::Error::from(e).context(format!(
+                        "unassigning calling plan {} of user {} also failed: {}",
+                        plan, user_id, rollback
+                    )));
+                }
+                Err(e.into())
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+pub trait ExternalContactOps {
+    /// List all the external contacts, with every page, and index them by name.
+    async fn index_external_contacts(
+        &self,
+    ) -> Result<crate::external_contact_index::ExternalContactIndex>;
+}
+
+#[async_trait::async_trait]
+impl ExternalContactOps for crate::phone::Phone {
+    async fn index_external_contacts(
+        &self,
+    ) -> Result<crate::external_contact_index::ExternalContactIndex> {
+        let contacts = self.list_all_external_contacts().await?;
+        Ok(crate::external_contact_index::ExternalContactIndex::new(
+            contacts,
+        ))
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Tsp {
     pub client: Client,
@@ -12,6 +10,17 @@ impl Tsp {
         Tsp { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Tsp {
+            client: self.client.with_retry(policy),
+        }
+    }
+
+    /// The OAuth scopes for `get`, any one of them grants access.
+    pub const GET_SCOPES: &[&str] = &["tsp:read:admin"];
+
     /**
      * Get account's TSP information.
      *
@@ -27,9 +36,15 @@ impl Tsp {
      */
     pub async fn get(&self) -> Result<crate::types::TspResponse> {
         let url = "/tsp".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/tsp", &[]))
     }
 
+    /// The OAuth scopes for `update`, any one of them grants access.
+    pub const UPDATE_SCOPES: &[&str] = &["tsp:write:admin"];
+
     /**
      * Update account's TSP information.
      *
@@ -46,10 +61,33 @@ impl Tsp {
     pub async fn update(&self, body: &crate::types::TspUpdateRequest) -> Result<()> {
         let url = "/tsp".to_string();
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("PATCH", "/tsp", &[]))
     }
 
+    /**
+     * Update account's TSP information.
+     *
+     * This function performs a `PATCH` to the `/tsp` endpoint.
+     *
+     * As opposed to `update`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn update_if_changed<C: serde::Serialize>(
+        &self,
+        current: &C,
+        body: &crate::types::TspUpdateRequest,
+    ) -> Result<Option<()>> {
+        let url = "/tsp".to_string();
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| crate::utils::pretty_url("PATCH", "/tsp", &[]))
+    }
+
+    /// The OAuth scopes for `user_ts_ps`, any one of them grants access.
+    pub const USER_TS_PS_SCOPES: &[&str] = &["tsp:read:admin", "tsp:read"];
+
     /**
      * List user's TSP accounts.
      *
@@ -65,14 +103,20 @@ impl Tsp {
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
      */
     pub async fn user_ts_ps(&self, user_id: &str) -> Result<crate::types::UserTsPsResponse> {
-        let url = format!(
-            "/users/{}/tsp",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-        );
+        let url = format!("/users/{}/tsp", self.client.encode_path(user_id),);
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/users/{userId}/tsp",
+                &[("userId", user_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `user_create`, any one of them grants access.
+    pub const USER_CREATE_SCOPES: &[&str] = &["tsp:write:admin", "tsp:write"];
+
     /**
      * Add a user's TSP account.
      *
@@ -93,16 +137,23 @@ impl Tsp {
         user_id: &str,
         body: &crate::types::TspAccountsList,
     ) -> Result<crate::types::TspAccountsList> {
-        let url = format!(
-            "/users/{}/tsp",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-        );
+        let url = format!("/users/{}/tsp", self.client.encode_path(user_id),);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/users/{userId}/tsp",
+                    &[("userId", user_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `user`, any one of them grants access.
+    pub const USER_SCOPES: &[&str] = &["tsp:read:admin", "tsp:read"];
+
     /**
      * Get a user's TSP account.
      *
@@ -123,13 +174,25 @@ impl Tsp {
     pub async fn user(&self, user_id: &str, tsp_id: &str) -> Result<crate::types::TspAccount> {
         let url = format!(
             "/users/{}/tsp/{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&tsp_id.to_string()),
+            self.client.encode_path(user_id),
+            self.client.encode_path(tsp_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/users/{userId}/tsp/{tspId}",
+                &[
+                    ("userId", user_id.to_string()),
+                    ("tspId", tsp_id.to_string()),
+                ],
+            )
+        })
     }
 
+    /// The OAuth scopes for `user_delete`, any one of them grants access.
+    pub const USER_DELETE_SCOPES: &[&str] = &["tsp:write:admin", "tsp:write"];
+
     /**
      * Delete a user's TSP account.
      *
@@ -150,13 +213,54 @@ impl Tsp {
     pub async fn user_delete(&self, user_id: &str, tsp_id: &str) -> Result<()> {
         let url = format!(
             "/users/{}/tsp/{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&tsp_id.to_string()),
+            self.client.encode_path(user_id),
+            self.client.encode_path(tsp_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/users/{userId}/tsp/{tspId}",
+                &[
+                    ("userId", user_id.to_string()),
+                    ("tspId", tsp_id.to_string()),
+                ],
+            )
+        })
     }
 
+    /**
+     * Delete a user's TSP account.
+     *
+     * This function performs a `DELETE` to the `/users/{userId}/tsp/{tspId}` endpoint.
+     *
+     * As opposed to `user_delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn user_delete_if_exists(&self, user_id: &str, tsp_id: &str) -> Result<bool> {
+        let url = format!(
+            "/users/{}/tsp/{}",
+            self.client.encode_path(user_id),
+            self.client.encode_path(tsp_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/users/{userId}/tsp/{tspId}",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("tspId", tsp_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The OAuth scopes for `user_update`, any one of them grants access.
+    pub const USER_UPDATE_SCOPES: &[&str] = &["tsp:write:admin", "tsp:write"];
+
     /**
      * Update a TSP account.
      *
@@ -182,15 +286,63 @@ impl Tsp {
     ) -> Result<()> {
         let url = format!(
             "/users/{}/tsp/{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&tsp_id.to_string()),
+            self.client.encode_path(user_id),
+            self.client.encode_path(tsp_id),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/users/{userId}/tsp/{tspId}",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("tspId", tsp_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /**
+     * Update a TSP account.
+     *
+     * This function performs a `PATCH` to the `/users/{userId}/tsp/{tspId}` endpoint.
+     *
+     * As opposed to `user_update`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn user_update_if_changed<C: serde::Serialize>(
+        &self,
+        user_id: &str,
+        tsp_id: &str,
+        current: &C,
+        body: &crate::types::TspAccountData,
+    ) -> Result<Option<()>> {
+        let url = format!(
+            "/users/{}/tsp/{}",
+            self.client.encode_path(user_id),
+            self.client.encode_path(tsp_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/users/{userId}/tsp/{tspId}",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("tspId", tsp_id.to_string()),
+                    ],
+                )
+            })
     }
 
+    /// The OAuth scopes for `url_update`, any one of them grants access.
+    pub const URL_UPDATE_SCOPES: &[&str] = &["tsp:write:admin", "tsp:write"];
+
     /**
      * Set global dial-in URL for a TSP user.
      *
@@ -211,13 +363,111 @@ impl Tsp {
         user_id: &str,
         body: &crate::types::TspGlobalDialIn,
     ) -> Result<()> {
-        let url = format!(
-            "/users/{}/tsp/settings",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-        );
+        let url = format!("/users/{}/tsp/settings", self.client.encode_path(user_id),);
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/users/{userId}/tsp/settings",
+                    &[("userId", user_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Set global dial-in URL for a TSP user.
+     *
+     * This function performs a `PATCH` to the `/users/{userId}/tsp/settings` endpoint.
+     *
+     * As opposed to `url_update`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn url_update_if_changed<C: serde::Serialize>(
+        &self,
+        user_id: &str,
+        current: &C,
+        body: &crate::types::TspGlobalDialIn,
+    ) -> Result<Option<()>> {
+        let url = format!("/users/{}/tsp/settings", self.client.encode_path(user_id),);
+
+        self.client
+            .patch_if_changed(&url, current, body)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/users/{userId}/tsp/settings",
+                    &[("userId", user_id.to_string())],
+                )
+            })
+    }
+}
+
+/// The functions of [`Tsp`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn TspApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait TspApi: Send + Sync {
+    async fn get(&self) -> Result<crate::types::TspResponse>;
+    async fn update(&self, body: &crate::types::TspUpdateRequest) -> Result<()>;
+    async fn user_ts_ps(&self, user_id: &str) -> Result<crate::types::UserTsPsResponse>;
+    async fn user_create(
+        &self,
+        user_id: &str,
+        body: &crate::types::TspAccountsList,
+    ) -> Result<crate::types::TspAccountsList>;
+    async fn user(&self, user_id: &str, tsp_id: &str) -> Result<crate::types::TspAccount>;
+    async fn user_delete(&self, user_id: &str, tsp_id: &str) -> Result<()>;
+    async fn user_delete_if_exists(&self, user_id: &str, tsp_id: &str) -> Result<bool>;
+    async fn user_update(
+        &self,
+        user_id: &str,
+        tsp_id: &str,
+        body: &crate::types::TspAccountData,
+    ) -> Result<()>;
+    async fn url_update(&self, user_id: &str, body: &crate::types::TspGlobalDialIn) -> Result<()>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl TspApi for Tsp {
+    async fn get(&self) -> Result<crate::types::TspResponse> {
+        Tsp::get(self).await
+    }
+    async fn update(&self, body: &crate::types::TspUpdateRequest) -> Result<()> {
+        Tsp::update(self, body).await
+    }
+    async fn user_ts_ps(&self, user_id: &str) -> Result<crate::types::UserTsPsResponse> {
+        Tsp::user_ts_ps(self, user_id).await
+    }
+    async fn user_create(
+        &self,
+        user_id: &str,
+        body: &crate::types::TspAccountsList,
+    ) -> Result<crate::types::TspAccountsList> {
+        Tsp::user_create(self, user_id, body).await
+    }
+    async fn user(&self, user_id: &str, tsp_id: &str) -> Result<crate::types::TspAccount> {
+        Tsp::user(self, user_id, tsp_id).await
+    }
+    async fn user_delete(&self, user_id: &str, tsp_id: &str) -> Result<()> {
+        Tsp::user_delete(self, user_id, tsp_id).await
+    }
+    async fn user_delete_if_exists(&self, user_id: &str, tsp_id: &str) -> Result<bool> {
+        Tsp::user_delete_if_exists(self, user_id, tsp_id).await
+    }
+    async fn user_update(
+        &self,
+        user_id: &str,
+        tsp_id: &str,
+        body: &crate::types::TspAccountData,
+    ) -> Result<()> {
+        Tsp::user_update(self, user_id, tsp_id, body).await
+    }
+    async fn url_update(&self, user_id: &str, body: &crate::types::TspGlobalDialIn) -> Result<()> {
+        Tsp::url_update(self, user_id, body).await
     }
 }
@@ -2,10 +2,37 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+/// Either one of something, or many of them. Some endpoints send back an
+/// object when there is one and an array when there are more, so this takes both.
+// Many is tried first: serde reads a struct from an array of its fields too,
+// so an array would otherwise be taken for One.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum OneOrMany<T> {
+    Many(Vec<T>),
+    One(T),
+}
+
+impl<T> OneOrMany<T> {
+    /// All of them, as a vector.
+    pub fn into_vec(self) -> Vec<T> {
+        match self {
+            OneOrMany::One(t) => vec![t],
+            OneOrMany::Many(v) => v,
+        }
+    }
+}
+
+impl<T> Default for OneOrMany<T> {
+    fn default() -> Self {
+        OneOrMany::Many(Vec::new())
+    }
+}
+
 /**
  * Payee:<br>`master` - master account holder pays.<br>`sub` - Sub account holder pays.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PayMode {
     #[serde(rename = "master")]
     Master,
@@ -32,6 +59,22 @@ impl Default for PayMode {
     }
 }
 
+impl PayMode {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PayMode::Master => "master",
+            PayMode::Sub => "sub",
+            PayMode::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PayMode] {
+        &[PayMode::Master, PayMode::Sub]
+    }
+}
+
 /// Account options object.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Options {
@@ -475,7 +518,7 @@ pub struct AccountPlans {
 /**
  * Determine how participants can join the audio portion of the meeting.<br>`both` - Telephony and VoIP.<br>`telephony` - Audio PSTN telephony only.<br>`voip` - VoIP only.<br>`thirdParty` - 3rd party audio conference.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum AudioType {
     #[serde(rename = "both")]
     Both,
@@ -508,11 +551,34 @@ impl Default for AudioType {
     }
 }
 
+impl AudioType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AudioType::Both => "both",
+            AudioType::Telephony => "telephony",
+            AudioType::ThirdParty => "thirdParty",
+            AudioType::Voip => "voip",
+            AudioType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [AudioType] {
+        &[
+            AudioType::Both,
+            AudioType::Telephony,
+            AudioType::ThirdParty,
+            AudioType::Voip,
+        ]
+    }
+}
+
 /**
  * Require a password for a meeting held using Personal Meeting ID (PMI) This setting is always enabled for free accounts and Pro accounts with a single host and cannot be modified for these accounts.
  *   
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum RequirePasswordPmiMeetings {
     #[serde(rename = "all")]
     All,
@@ -544,7 +610,28 @@ impl Default for RequirePasswordPmiMeetings {
         RequirePasswordPmiMeetings::Noop
     }
 }
+
 impl RequirePasswordPmiMeetings {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RequirePasswordPmiMeetings::All => "all",
+            RequirePasswordPmiMeetings::JbhOnly => "jbh_only",
+            RequirePasswordPmiMeetings::None => "none",
+            RequirePasswordPmiMeetings::Noop => "",
+            RequirePasswordPmiMeetings::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [RequirePasswordPmiMeetings] {
+        &[
+            RequirePasswordPmiMeetings::All,
+            RequirePasswordPmiMeetings::JbhOnly,
+            RequirePasswordPmiMeetings::None,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, RequirePasswordPmiMeetings::Noop)
     }
@@ -766,7 +853,7 @@ pub struct ScheduleMeeting {
 /**
  * Play sound when participants join or leave.<br>`host` - Heard by host only.<br>`all` - Heard by host and all attendees.<br>`none` - Disable.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum EntryExitChime {
     #[serde(rename = "all")]
     All,
@@ -798,7 +885,28 @@ impl Default for EntryExitChime {
         EntryExitChime::Noop
     }
 }
+
 impl EntryExitChime {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EntryExitChime::All => "all",
+            EntryExitChime::Host => "host",
+            EntryExitChime::None => "none",
+            EntryExitChime::Noop => "",
+            EntryExitChime::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [EntryExitChime] {
+        &[
+            EntryExitChime::All,
+            EntryExitChime::Host,
+            EntryExitChime::None,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, EntryExitChime::Noop)
     }
@@ -897,7 +1005,7 @@ pub struct VirtualBackgroundSettings {
  *   `all`: Both hosts and attendees can share their screen during meetings. For Webinar, the hosts and panelists can start screen sharing, but not the attendees.
  *   
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum WhoCanShareScreen {
     #[serde(rename = "all")]
     All,
@@ -926,7 +1034,23 @@ impl Default for WhoCanShareScreen {
         WhoCanShareScreen::Noop
     }
 }
+
 impl WhoCanShareScreen {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WhoCanShareScreen::All => "all",
+            WhoCanShareScreen::Host => "host",
+            WhoCanShareScreen::Noop => "",
+            WhoCanShareScreen::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [WhoCanShareScreen] {
+        &[WhoCanShareScreen::All, WhoCanShareScreen::Host]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, WhoCanShareScreen::Noop)
     }
@@ -1543,7 +1667,7 @@ pub struct PasswordRequirement {
  *   `sign_in_with_two_factor_auth_roles` field.
  *   
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SignInWithTwoFactorAuth {
     #[serde(rename = "all")]
     All,
@@ -1578,7 +1702,30 @@ impl Default for SignInWithTwoFactorAuth {
         SignInWithTwoFactorAuth::Noop
     }
 }
+
 impl SignInWithTwoFactorAuth {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SignInWithTwoFactorAuth::All => "all",
+            SignInWithTwoFactorAuth::Group => "group",
+            SignInWithTwoFactorAuth::None => "none",
+            SignInWithTwoFactorAuth::Role => "role",
+            SignInWithTwoFactorAuth::Noop => "",
+            SignInWithTwoFactorAuth::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SignInWithTwoFactorAuth] {
+        &[
+            SignInWithTwoFactorAuth::All,
+            SignInWithTwoFactorAuth::Group,
+            SignInWithTwoFactorAuth::None,
+            SignInWithTwoFactorAuth::Role,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, SignInWithTwoFactorAuth::Noop)
     }
@@ -1662,7 +1809,7 @@ pub struct Security {
 /**
  * Automatic recording:<br>`local` - Record on local.<br>`cloud` -  Record on cloud.<br>`none` - Disabled.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum AutoRecording {
     #[serde(rename = "cloud")]
     Cloud,
@@ -1694,7 +1841,28 @@ impl Default for AutoRecording {
         AutoRecording::Noop
     }
 }
+
 impl AutoRecording {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AutoRecording::Cloud => "cloud",
+            AutoRecording::Local => "local",
+            AutoRecording::None => "none",
+            AutoRecording::Noop => "",
+            AutoRecording::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [AutoRecording] {
+        &[
+            AutoRecording::Cloud,
+            AutoRecording::Local,
+            AutoRecording::None,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, AutoRecording::Noop)
     }
@@ -2562,7 +2730,7 @@ pub struct DateTime {
 /**
  * Device protocol:<br>`H.323` - H.323.<br>`SIP` - SIP.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Protocol {
     #[serde(rename = "H.323")]
     H323,
@@ -2591,7 +2759,23 @@ impl Default for Protocol {
         Protocol::Noop
     }
 }
+
 impl Protocol {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Protocol::H323 => "H.323",
+            Protocol::Sip => "SIP",
+            Protocol::Noop => "",
+            Protocol::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Protocol] {
+        &[Protocol::H323, Protocol::Sip]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Protocol::Noop)
     }
@@ -2600,7 +2784,7 @@ impl Protocol {
 /**
  * Device encryption:<br>`auto` - auto.<br>`yes` - yes.<br>`no` - no.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Encryption {
     #[serde(rename = "auto")]
     Auto,
@@ -2632,7 +2816,24 @@ impl Default for Encryption {
         Encryption::Noop
     }
 }
+
 impl Encryption {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Encryption::Auto => "auto",
+            Encryption::No => "no",
+            Encryption::Yes => "yes",
+            Encryption::Noop => "",
+            Encryption::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Encryption] {
+        &[Encryption::Auto, Encryption::No, Encryption::Yes]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Encryption::Noop)
     }
@@ -2870,7 +3071,7 @@ pub struct Occurrence {
 /**
  * Determine how participants can join the audio portion of the meeting.<br>`both` - Both Telephony and VoIP.<br>`telephony` - Telephony only.<br>`voip` - VoIP only.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Audio {
     #[serde(rename = "both")]
     Both,
@@ -2900,10 +3101,27 @@ impl Default for Audio {
     }
 }
 
+impl Audio {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Audio::Both => "both",
+            Audio::Telephony => "telephony",
+            Audio::Voip => "voip",
+            Audio::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Audio] {
+        &[Audio::Both, Audio::Telephony, Audio::Voip]
+    }
+}
+
 /**
  * Type of number.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Type {
     #[serde(rename = "toll")]
     Toll,
@@ -2932,7 +3150,23 @@ impl Default for Type {
         Type::Noop
     }
 }
+
 impl Type {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Type::Toll => "toll",
+            Type::Tollfree => "tollfree",
+            Type::Noop => "",
+            Type::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Type] {
+        &[Type::Toll, Type::Tollfree]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Type::Noop)
     }
@@ -2989,7 +3223,7 @@ pub struct GlobalDialInNumbers {
  *   
  *   `e2ee`: [End-to-end encryption](https://support.zoom.us/hc/en-us/articles/360048660871). The encryption key is stored in your local device and can not be obtained by anyone else. Enabling this setting also **disables** the following features: join before host, cloud recording, streaming, live transcription, breakout rooms, polling, 1:1 private chat, and meeting reactions.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum EncryptionType {
     #[serde(rename = "e2ee")]
     E2Ee,
@@ -3018,7 +3252,23 @@ impl Default for EncryptionType {
         EncryptionType::Noop
     }
 }
+
 impl EncryptionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EncryptionType::E2Ee => "e2ee",
+            EncryptionType::EnhancedEncryption => "enhanced_encryption",
+            EncryptionType::Noop => "",
+            EncryptionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [EncryptionType] {
+        &[EncryptionType::E2Ee, EncryptionType::EnhancedEncryption]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, EncryptionType::Noop)
     }
@@ -3029,7 +3279,7 @@ impl EncryptionType {
  *   `approve`: Allow users from specific regions/countries to join this meeting. If this setting is selected, the approved regions/countries must be included in the `approved_list`.<br><br>
  *   `deny`: Block users from specific regions/countries from joining this meeting. If this setting is selected, the approved regions/countries must be included in the `denied_list`
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Method {
     #[serde(rename = "approve")]
     Approve,
@@ -3058,7 +3308,23 @@ impl Default for Method {
         Method::Noop
     }
 }
+
 impl Method {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Method::Approve => "approve",
+            Method::Deny => "deny",
+            Method::Noop => "",
+            Method::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Method] {
+        &[Method::Approve, Method::Deny]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Method::Noop)
     }
@@ -3540,7 +3806,7 @@ pub struct MeetingSettings {
  *   
  *    <br>`1`  - Sunday. <br>`2` - Monday.<br>`3` - Tuesday.<br>`4` -  Wednesday.<br>`5` -  Thursday.<br>`6` - Friday.<br>`7` - Saturday.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum WeeklyDays {
     #[serde(rename = "1")]
     One,
@@ -3582,6 +3848,35 @@ impl Default for WeeklyDays {
     }
 }
 
+impl WeeklyDays {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WeeklyDays::One => "1",
+            WeeklyDays::Two => "2",
+            WeeklyDays::Three => "3",
+            WeeklyDays::Four => "4",
+            WeeklyDays::Five => "5",
+            WeeklyDays::Six => "6",
+            WeeklyDays::Seven => "7",
+            WeeklyDays::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [WeeklyDays] {
+        &[
+            WeeklyDays::One,
+            WeeklyDays::Two,
+            WeeklyDays::Three,
+            WeeklyDays::Four,
+            WeeklyDays::Five,
+            WeeklyDays::Six,
+            WeeklyDays::Seven,
+        ]
+    }
+}
+
 /// Recurrence object. Use this object only for a meeting with type `8` i.e., a recurring meeting with fixed time.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Recurrence {
@@ -3810,7 +4105,7 @@ pub struct MeetingInfo {
 /**
  * Meeting status
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Status {
     #[serde(rename = "started")]
     Started,
@@ -3839,7 +4134,23 @@ impl Default for Status {
         Status::Noop
     }
 }
+
 impl Status {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Status::Started => "started",
+            Status::Waiting => "waiting",
+            Status::Noop => "",
+            Status::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Status] {
+        &[Status::Started, Status::Waiting]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Status::Noop)
     }
@@ -4241,7 +4552,7 @@ pub struct MeetingLiveStream {
  *   `start`: Start a live stream. <br>
  *   `stop`: Stop an ongoing live stream.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Action {
     #[serde(rename = "start")]
     Start,
@@ -4270,7 +4581,23 @@ impl Default for Action {
         Action::Noop
     }
 }
+
 impl Action {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Action::Start => "start",
+            Action::Stop => "stop",
+            Action::Noop => "",
+            Action::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Action] {
+        &[Action::Start, Action::Stop]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Action::Noop)
     }
@@ -4594,7 +4921,7 @@ pub struct QosAudio {
 /**
  * The recording status.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum RecordingStatus {
     #[serde(rename = "completed")]
     Completed,
@@ -4620,7 +4947,22 @@ impl Default for RecordingStatus {
         RecordingStatus::Noop
     }
 }
+
 impl RecordingStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RecordingStatus::Completed => "completed",
+            RecordingStatus::Noop => "",
+            RecordingStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [RecordingStatus] {
+        &[RecordingStatus::Completed]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, RecordingStatus::Noop)
     }
@@ -4811,7 +5153,7 @@ pub struct RecordingSeparateAudio {
 /**
  * Determine how the meeting recording is shared.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ShareRecording {
     #[serde(rename = "internally")]
     Internally,
@@ -4843,7 +5185,28 @@ impl Default for ShareRecording {
         ShareRecording::Noop
     }
 }
+
 impl ShareRecording {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ShareRecording::Internally => "internally",
+            ShareRecording::None => "none",
+            ShareRecording::Publicly => "publicly",
+            ShareRecording::Noop => "",
+            ShareRecording::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ShareRecording] {
+        &[
+            ShareRecording::Internally,
+            ShareRecording::None,
+            ShareRecording::Publicly,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ShareRecording::Noop)
     }
@@ -5092,7 +5455,7 @@ pub struct Registrant {
 /**
  * Registrant Status:<br>`approve` - Approve registrant.<br>`cancel` - Cancel previously approved registrant's registration.<br>`deny` - Deny registrant.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum RegistrantStatusAction {
     #[serde(rename = "approve")]
     Approve,
@@ -5124,7 +5487,28 @@ impl Default for RegistrantStatusAction {
         RegistrantStatusAction::Noop
     }
 }
+
 impl RegistrantStatusAction {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RegistrantStatusAction::Approve => "approve",
+            RegistrantStatusAction::Cancel => "cancel",
+            RegistrantStatusAction::Deny => "deny",
+            RegistrantStatusAction::Noop => "",
+            RegistrantStatusAction::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [RegistrantStatusAction] {
+        &[
+            RegistrantStatusAction::Approve,
+            RegistrantStatusAction::Cancel,
+            RegistrantStatusAction::Deny,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, RegistrantStatusAction::Noop)
     }
@@ -5229,6 +5613,42 @@ pub struct RoleMembersList {
     pub total_records: i64,
 }
 
+impl IntoIterator for RoleMembersList {
+    type Item = Domains;
+    type IntoIter = std::vec::IntoIter<Domains>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.members.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a RoleMembersList {
+    type Item = &'a Domains;
+    type IntoIter = std::slice::Iter<'a, Domains>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.members.iter()
+    }
+}
+
+impl RoleMembersList {
+    /// Iterate over the `members`.
+    pub fn iter(&self) -> std::slice::Iter<'_, Domains> {
+        self.members.iter()
+    }
+
+    /// The number of `members` in the whole list, across all of its pages.
+    pub fn total(&self) -> i64 {
+        self.total_records
+    }
+
+    /// The number of `members` left in the list once `seen` of them have been
+    /// read, say from a page or a stream, to report progress.
+    pub fn remaining(&self, seen: usize) -> i64 {
+        (self.total_records - seen as i64).max(0)
+    }
+}
+
 /// Add Role Members
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddRoleMembers {
@@ -5570,7 +5990,7 @@ pub struct RecurrenceWebinar {
  *   
  *   *  `all`: Attendees are able to view all questions submitted in the Q&A.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum AnswerQuestions {
     #[serde(rename = "all")]
     All,
@@ -5599,7 +6019,23 @@ impl Default for AnswerQuestions {
         AnswerQuestions::Noop
     }
 }
+
 impl AnswerQuestions {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AnswerQuestions::All => "all",
+            AnswerQuestions::Only => "only",
+            AnswerQuestions::Noop => "",
+            AnswerQuestions::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [AnswerQuestions] {
+        &[AnswerQuestions::All, AnswerQuestions::Only]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, AnswerQuestions::Noop)
     }
@@ -6184,7 +6620,7 @@ pub struct SessionWebinarUpdate {
  * Dial-in number types:<br>`toll` - Toll number.<br>`tollfree` -Toll free number.<br>
  *   `media_link` - Media link.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum TspAccountsListDialInNumbersType {
     #[serde(rename = "media_link")]
     MediaLink,
@@ -6216,7 +6652,28 @@ impl Default for TspAccountsListDialInNumbersType {
         TspAccountsListDialInNumbersType::Noop
     }
 }
+
 impl TspAccountsListDialInNumbersType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TspAccountsListDialInNumbersType::MediaLink => "media_link",
+            TspAccountsListDialInNumbersType::Toll => "toll",
+            TspAccountsListDialInNumbersType::Tollfree => "tollfree",
+            TspAccountsListDialInNumbersType::Noop => "",
+            TspAccountsListDialInNumbersType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [TspAccountsListDialInNumbersType] {
+        &[
+            TspAccountsListDialInNumbersType::MediaLink,
+            TspAccountsListDialInNumbersType::Toll,
+            TspAccountsListDialInNumbersType::Tollfree,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, TspAccountsListDialInNumbersType::Noop)
     }
@@ -6262,7 +6719,7 @@ pub struct DialInNumbers {
 /**
  * Telephony bridge
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum TspBridge {
     #[serde(rename = "EU_TSP_TB")]
     EuTspTb,
@@ -6291,7 +6748,23 @@ impl Default for TspBridge {
         TspBridge::Noop
     }
 }
+
 impl TspBridge {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TspBridge::EuTspTb => "EU_TSP_TB",
+            TspBridge::UsTspTb => "US_TSP_TB",
+            TspBridge::Noop => "",
+            TspBridge::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [TspBridge] {
+        &[TspBridge::EuTspTb, TspBridge::UsTspTb]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, TspBridge::Noop)
     }
@@ -6559,7 +7032,7 @@ pub struct CustomAttributes {
  *   * `Home`
  *   * `Fax`
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Label {
     #[serde(rename = "Fax")]
     Fax,
@@ -6594,7 +7067,25 @@ impl Default for Label {
         Label::Noop
     }
 }
+
 impl Label {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Label::Fax => "Fax",
+            Label::Home => "Home",
+            Label::Mobile => "Mobile",
+            Label::Office => "Office",
+            Label::Noop => "",
+            Label::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Label] {
+        &[Label::Fax, Label::Home, Label::Mobile, Label::Office]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Label::Noop)
     }
@@ -8329,7 +8820,7 @@ pub struct ZoomRoom {
 /**
  * Poll Question & Answer type:<br>`single` - Single choice<br>`mutliple` - Multiple choice
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PollQuestionsType {
     #[serde(rename = "multiple")]
     Multiple,
@@ -8358,7 +8849,23 @@ impl Default for PollQuestionsType {
         PollQuestionsType::Noop
     }
 }
+
 impl PollQuestionsType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PollQuestionsType::Multiple => "multiple",
+            PollQuestionsType::Single => "single",
+            PollQuestionsType::Noop => "",
+            PollQuestionsType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PollQuestionsType] {
+        &[PollQuestionsType::Multiple, PollQuestionsType::Single]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PollQuestionsType::Noop)
     }
@@ -8420,7 +8927,7 @@ pub struct Poll {
 /**
  * Field name of the question.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum FieldName {
     #[serde(rename = "address")]
     Address,
@@ -8482,7 +8989,48 @@ impl Default for FieldName {
         FieldName::Noop
     }
 }
+
 impl FieldName {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FieldName::Address => "address",
+            FieldName::City => "city",
+            FieldName::Comments => "comments",
+            FieldName::Country => "country",
+            FieldName::Industry => "industry",
+            FieldName::JobTitle => "job_title",
+            FieldName::NoOfEmployees => "no_of_employees",
+            FieldName::Org => "org",
+            FieldName::Phone => "phone",
+            FieldName::PurchasingTimeFrame => "purchasing_time_frame",
+            FieldName::RoleInPurchaseProcess => "role_in_purchase_process",
+            FieldName::State => "state",
+            FieldName::Zip => "zip",
+            FieldName::Noop => "",
+            FieldName::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [FieldName] {
+        &[
+            FieldName::Address,
+            FieldName::City,
+            FieldName::Comments,
+            FieldName::Country,
+            FieldName::Industry,
+            FieldName::JobTitle,
+            FieldName::NoOfEmployees,
+            FieldName::Org,
+            FieldName::Phone,
+            FieldName::PurchasingTimeFrame,
+            FieldName::RoleInPurchaseProcess,
+            FieldName::State,
+            FieldName::Zip,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, FieldName::Noop)
     }
@@ -8508,7 +9056,7 @@ pub struct MeetingRegistrantQuestions {
 /**
  * Type of the question being asked.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum MeetingRegistrantQuestionsCustomType {
     #[serde(rename = "short")]
     Short,
@@ -8537,7 +9085,26 @@ impl Default for MeetingRegistrantQuestionsCustomType {
         MeetingRegistrantQuestionsCustomType::Noop
     }
 }
+
 impl MeetingRegistrantQuestionsCustomType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MeetingRegistrantQuestionsCustomType::Short => "short",
+            MeetingRegistrantQuestionsCustomType::Single => "single",
+            MeetingRegistrantQuestionsCustomType::Noop => "",
+            MeetingRegistrantQuestionsCustomType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [MeetingRegistrantQuestionsCustomType] {
+        &[
+            MeetingRegistrantQuestionsCustomType::Short,
+            MeetingRegistrantQuestionsCustomType::Single,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, MeetingRegistrantQuestionsCustomType::Noop)
     }
@@ -8630,7 +9197,7 @@ pub struct WebinarRegistrantQuestions {
 /**
  * Field name.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum RecordingRegistrantQuestionsFieldName {
     #[serde(rename = "address")]
     Address,
@@ -8697,7 +9264,52 @@ impl Default for RecordingRegistrantQuestionsFieldName {
         RecordingRegistrantQuestionsFieldName::Noop
     }
 }
+
 impl RecordingRegistrantQuestionsFieldName {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RecordingRegistrantQuestionsFieldName::Address => "address",
+            RecordingRegistrantQuestionsFieldName::City => "city",
+            RecordingRegistrantQuestionsFieldName::Comments => "comments",
+            RecordingRegistrantQuestionsFieldName::Country => "country",
+            RecordingRegistrantQuestionsFieldName::Industry => "industry",
+            RecordingRegistrantQuestionsFieldName::JobTitle => "job_title",
+            RecordingRegistrantQuestionsFieldName::LastName => "last_name",
+            RecordingRegistrantQuestionsFieldName::NoOfEmployees => "no_of_employees",
+            RecordingRegistrantQuestionsFieldName::Org => "org",
+            RecordingRegistrantQuestionsFieldName::Phone => "phone",
+            RecordingRegistrantQuestionsFieldName::PurchasingTimeFrame => "purchasing_time_frame",
+            RecordingRegistrantQuestionsFieldName::RoleInPurchaseProcess => {
+                "role_in_purchase_process"
+            }
+            RecordingRegistrantQuestionsFieldName::State => "state",
+            RecordingRegistrantQuestionsFieldName::Zip => "zip",
+            RecordingRegistrantQuestionsFieldName::Noop => "",
+            RecordingRegistrantQuestionsFieldName::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [RecordingRegistrantQuestionsFieldName] {
+        &[
+            RecordingRegistrantQuestionsFieldName::Address,
+            RecordingRegistrantQuestionsFieldName::City,
+            RecordingRegistrantQuestionsFieldName::Comments,
+            RecordingRegistrantQuestionsFieldName::Country,
+            RecordingRegistrantQuestionsFieldName::Industry,
+            RecordingRegistrantQuestionsFieldName::JobTitle,
+            RecordingRegistrantQuestionsFieldName::LastName,
+            RecordingRegistrantQuestionsFieldName::NoOfEmployees,
+            RecordingRegistrantQuestionsFieldName::Org,
+            RecordingRegistrantQuestionsFieldName::Phone,
+            RecordingRegistrantQuestionsFieldName::PurchasingTimeFrame,
+            RecordingRegistrantQuestionsFieldName::RoleInPurchaseProcess,
+            RecordingRegistrantQuestionsFieldName::State,
+            RecordingRegistrantQuestionsFieldName::Zip,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, RecordingRegistrantQuestionsFieldName::Noop)
     }
@@ -8723,7 +9335,7 @@ pub struct RecordingRegistrantQuestions {
 /**
  * The type of registration question and answers.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum RecordingRegistrantQuestionsCustomType {
     #[serde(rename = "multiple")]
     Multiple,
@@ -8755,7 +9367,28 @@ impl Default for RecordingRegistrantQuestionsCustomType {
         RecordingRegistrantQuestionsCustomType::Noop
     }
 }
+
 impl RecordingRegistrantQuestionsCustomType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RecordingRegistrantQuestionsCustomType::Multiple => "multiple",
+            RecordingRegistrantQuestionsCustomType::Short => "short",
+            RecordingRegistrantQuestionsCustomType::Single => "single",
+            RecordingRegistrantQuestionsCustomType::Noop => "",
+            RecordingRegistrantQuestionsCustomType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [RecordingRegistrantQuestionsCustomType] {
+        &[
+            RecordingRegistrantQuestionsCustomType::Multiple,
+            RecordingRegistrantQuestionsCustomType::Short,
+            RecordingRegistrantQuestionsCustomType::Single,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, RecordingRegistrantQuestionsCustomType::Noop)
     }
@@ -10241,7 +10874,7 @@ pub struct ParticipantQos {
     pub version: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum AccountSettingsRecordingAuthenticationUpdateOptionType {
     #[serde(rename = "enforce_login")]
     EnforceLogin,
@@ -10275,7 +10908,30 @@ impl Default for AccountSettingsRecordingAuthenticationUpdateOptionType {
         AccountSettingsRecordingAuthenticationUpdateOptionType::Noop
     }
 }
+
 impl AccountSettingsRecordingAuthenticationUpdateOptionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AccountSettingsRecordingAuthenticationUpdateOptionType::EnforceLogin => "enforce_login",
+            AccountSettingsRecordingAuthenticationUpdateOptionType::EnforceLoginWithDomains => {
+                "enforce_login_with_domains"
+            }
+            AccountSettingsRecordingAuthenticationUpdateOptionType::Internally => "internally",
+            AccountSettingsRecordingAuthenticationUpdateOptionType::Noop => "",
+            AccountSettingsRecordingAuthenticationUpdateOptionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [AccountSettingsRecordingAuthenticationUpdateOptionType] {
+        &[
+            AccountSettingsRecordingAuthenticationUpdateOptionType::EnforceLogin,
+            AccountSettingsRecordingAuthenticationUpdateOptionType::EnforceLoginWithDomains,
+            AccountSettingsRecordingAuthenticationUpdateOptionType::Internally,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(
             self,
@@ -10284,7 +10940,7 @@ impl AccountSettingsRecordingAuthenticationUpdateOptionType {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum AccountSettingsRecordingAuthenticationUpdateOptionAction {
     #[serde(rename = "add")]
     Add,
@@ -10316,7 +10972,28 @@ impl Default for AccountSettingsRecordingAuthenticationUpdateOptionAction {
         AccountSettingsRecordingAuthenticationUpdateOptionAction::Noop
     }
 }
+
 impl AccountSettingsRecordingAuthenticationUpdateOptionAction {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AccountSettingsRecordingAuthenticationUpdateOptionAction::Add => "add",
+            AccountSettingsRecordingAuthenticationUpdateOptionAction::Delete => "delete",
+            AccountSettingsRecordingAuthenticationUpdateOptionAction::Update => "update",
+            AccountSettingsRecordingAuthenticationUpdateOptionAction::Noop => "",
+            AccountSettingsRecordingAuthenticationUpdateOptionAction::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [AccountSettingsRecordingAuthenticationUpdateOptionAction] {
+        &[
+            AccountSettingsRecordingAuthenticationUpdateOptionAction::Add,
+            AccountSettingsRecordingAuthenticationUpdateOptionAction::Delete,
+            AccountSettingsRecordingAuthenticationUpdateOptionAction::Update,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(
             self,
@@ -10877,7 +11554,7 @@ pub struct Metrics {
  *   
  *   For more information, read our [Managing and sharing cloud recordings](https://support.zoom.us/hc/en-us/articles/205347605-Managing-and-sharing-cloud-recordings#h_9898497b-e736-4980-a749-d55608f10773) documentation.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum FileType {
     #[serde(rename = "CC")]
     Cc,
@@ -10918,7 +11595,34 @@ impl Default for FileType {
         FileType::Noop
     }
 }
+
 impl FileType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FileType::Cc => "CC",
+            FileType::Chat => "CHAT",
+            FileType::Csv => "CSV",
+            FileType::M4A => "M4A",
+            FileType::Mp4 => "MP4",
+            FileType::Transcript => "TRANSCRIPT",
+            FileType::Noop => "",
+            FileType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [FileType] {
+        &[
+            FileType::Cc,
+            FileType::Chat,
+            FileType::Csv,
+            FileType::M4A,
+            FileType::Mp4,
+            FileType::Transcript,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, FileType::Noop)
     }
@@ -10940,7 +11644,7 @@ impl FileType {
  *   
  *   For more information, read our [Managing and sharing cloud recordings](https://support.zoom.us/hc/en-us/articles/205347605-Managing-and-sharing-cloud-recordings#h_9898497b-e736-4980-a749-d55608f10773) documentation.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum RecordingType {
     #[serde(rename = "active_speaker")]
     ActiveSpeaker,
@@ -10996,7 +11700,44 @@ impl Default for RecordingType {
         RecordingType::Noop
     }
 }
+
 impl RecordingType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RecordingType::ActiveSpeaker => "active_speaker",
+            RecordingType::AudioOnly => "audio_only",
+            RecordingType::AudioTranscript => "audio_transcript",
+            RecordingType::ChatFile => "chat_file",
+            RecordingType::GalleryView => "gallery_view",
+            RecordingType::HostVideo => "host_video",
+            RecordingType::SharedScreen => "shared_screen",
+            RecordingType::SharedScreenWithGalleryView => "shared_screen_with_gallery_view",
+            RecordingType::SharedScreenWithSpeakerView => "shared_screen_with_speaker_view",
+            RecordingType::SharedScreenWithSpeakerViewCc => "shared_screen_with_speaker_view(CC)",
+            RecordingType::SpeakerView => "speaker_view",
+            RecordingType::Noop => "",
+            RecordingType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [RecordingType] {
+        &[
+            RecordingType::ActiveSpeaker,
+            RecordingType::AudioOnly,
+            RecordingType::AudioTranscript,
+            RecordingType::ChatFile,
+            RecordingType::GalleryView,
+            RecordingType::HostVideo,
+            RecordingType::SharedScreen,
+            RecordingType::SharedScreenWithGalleryView,
+            RecordingType::SharedScreenWithSpeakerView,
+            RecordingType::SharedScreenWithSpeakerViewCc,
+            RecordingType::SpeakerView,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, RecordingType::Noop)
     }
@@ -11258,7 +11999,7 @@ pub struct InviteLinks {
     pub attendees: Vec<InviteLinksAttendees>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum RecordingStatusUpdateBodyRequestAction {
     #[serde(rename = "recover")]
     Recover,
@@ -11284,7 +12025,22 @@ impl Default for RecordingStatusUpdateBodyRequestAction {
         RecordingStatusUpdateBodyRequestAction::Noop
     }
 }
+
 impl RecordingStatusUpdateBodyRequestAction {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RecordingStatusUpdateBodyRequestAction::Recover => "recover",
+            RecordingStatusUpdateBodyRequestAction::Noop => "",
+            RecordingStatusUpdateBodyRequestAction::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [RecordingStatusUpdateBodyRequestAction] {
+        &[RecordingStatusUpdateBodyRequestAction::Recover]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, RecordingStatusUpdateBodyRequestAction::Noop)
     }
@@ -11395,7 +12151,7 @@ pub struct SetUpAccountRequest {
  *   `all`: Include both assigned and unassigned numbers in the response.<br>
  *   `byoc`: Include Bring Your Own Carrier (BYOC) numbers only in the response.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ListAccountPhoneNumbersType {
     #[serde(rename = "all")]
     All,
@@ -11427,7 +12183,28 @@ impl Default for ListAccountPhoneNumbersType {
         ListAccountPhoneNumbersType::Noop
     }
 }
+
 impl ListAccountPhoneNumbersType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ListAccountPhoneNumbersType::All => "all",
+            ListAccountPhoneNumbersType::Assigned => "assigned",
+            ListAccountPhoneNumbersType::Unassigned => "unassigned",
+            ListAccountPhoneNumbersType::Noop => "",
+            ListAccountPhoneNumbersType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ListAccountPhoneNumbersType] {
+        &[
+            ListAccountPhoneNumbersType::All,
+            ListAccountPhoneNumbersType::Assigned,
+            ListAccountPhoneNumbersType::Unassigned,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ListAccountPhoneNumbersType::Noop)
     }
@@ -11438,7 +12215,7 @@ impl ListAccountPhoneNumbersType {
  *   `user`<br> `callQueue`<br> `autoReceptionist`<br>
  *   `commonAreaPhone`
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ExtensionType {
     #[serde(rename = "autoReceptionist")]
     AutoReceptionist,
@@ -11473,7 +12250,30 @@ impl Default for ExtensionType {
         ExtensionType::Noop
     }
 }
+
 impl ExtensionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExtensionType::AutoReceptionist => "autoReceptionist",
+            ExtensionType::CallQueue => "callQueue",
+            ExtensionType::CommonAreaPhone => "commonAreaPhone",
+            ExtensionType::User => "user",
+            ExtensionType::Noop => "",
+            ExtensionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ExtensionType] {
+        &[
+            ExtensionType::AutoReceptionist,
+            ExtensionType::CallQueue,
+            ExtensionType::CommonAreaPhone,
+            ExtensionType::User,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ExtensionType::Noop)
     }
@@ -11482,7 +12282,7 @@ impl ExtensionType {
 /**
  * Source of phone number.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Source {
     #[serde(rename = "external")]
     External,
@@ -11511,7 +12311,23 @@ impl Default for Source {
         Source::Noop
     }
 }
+
 impl Source {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Source::External => "external",
+            Source::Internal => "internal",
+            Source::Noop => "",
+            Source::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Source] {
+        &[Source::External, Source::Internal]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Source::Noop)
     }
@@ -11520,7 +12336,7 @@ impl Source {
 /**
  * Status of the number.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ListAccountPhoneNumbersResponseStatus {
     #[serde(rename = "available")]
     Available,
@@ -11549,7 +12365,26 @@ impl Default for ListAccountPhoneNumbersResponseStatus {
         ListAccountPhoneNumbersResponseStatus::Noop
     }
 }
+
 impl ListAccountPhoneNumbersResponseStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ListAccountPhoneNumbersResponseStatus::Available => "available",
+            ListAccountPhoneNumbersResponseStatus::Pending => "pending",
+            ListAccountPhoneNumbersResponseStatus::Noop => "",
+            ListAccountPhoneNumbersResponseStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ListAccountPhoneNumbersResponseStatus] {
+        &[
+            ListAccountPhoneNumbersResponseStatus::Available,
+            ListAccountPhoneNumbersResponseStatus::Pending,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ListAccountPhoneNumbersResponseStatus::Noop)
     }
@@ -11779,10 +12614,46 @@ pub struct ListAccountPhoneNumbersResponseData {
     pub total_records: i64,
 }
 
+impl IntoIterator for ListAccountPhoneNumbersResponseData {
+    type Item = ListAccountPhoneNumbersResponse;
+    type IntoIter = std::vec::IntoIter<ListAccountPhoneNumbersResponse>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.phone_numbers.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ListAccountPhoneNumbersResponseData {
+    type Item = &'a ListAccountPhoneNumbersResponse;
+    type IntoIter = std::slice::Iter<'a, ListAccountPhoneNumbersResponse>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.phone_numbers.iter()
+    }
+}
+
+impl ListAccountPhoneNumbersResponseData {
+    /// Iterate over the `phone_numbers`.
+    pub fn iter(&self) -> std::slice::Iter<'_, ListAccountPhoneNumbersResponse> {
+        self.phone_numbers.iter()
+    }
+
+    /// The number of `phone_numbers` in the whole list, across all of its pages.
+    pub fn total(&self) -> i64 {
+        self.total_records
+    }
+
+    /// The number of `phone_numbers` left in the list once `seen` of them have been
+    /// read, say from a page or a stream, to report progress.
+    pub fn remaining(&self, seen: usize) -> i64 {
+        (self.total_records - seen as i64).max(0)
+    }
+}
+
 /**
  * Protocols supported by the SIP provider.<br> The value must be either `UDP`, `TCP`, `TLS`, `AUTO`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum TransportProtocol {
     #[serde(rename = "AUTO")]
     Auto,
@@ -11817,7 +12688,30 @@ impl Default for TransportProtocol {
         TransportProtocol::Noop
     }
 }
+
 impl TransportProtocol {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TransportProtocol::Auto => "AUTO",
+            TransportProtocol::Tcp => "TCP",
+            TransportProtocol::Tls => "TLS",
+            TransportProtocol::Udp => "UDP",
+            TransportProtocol::Noop => "",
+            TransportProtocol::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [TransportProtocol] {
+        &[
+            TransportProtocol::Auto,
+            TransportProtocol::Tcp,
+            TransportProtocol::Tls,
+            TransportProtocol::Udp,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, TransportProtocol::Noop)
     }
@@ -12038,6 +12932,42 @@ pub struct ListSipPhonesResponse {
     pub total_records: i64,
 }
 
+impl IntoIterator for ListSipPhonesResponse {
+    type Item = Phones;
+    type IntoIter = std::vec::IntoIter<Phones>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.phones.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ListSipPhonesResponse {
+    type Item = &'a Phones;
+    type IntoIter = std::slice::Iter<'a, Phones>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.phones.iter()
+    }
+}
+
+impl ListSipPhonesResponse {
+    /// Iterate over the `phones`.
+    pub fn iter(&self) -> std::slice::Iter<'_, Phones> {
+        self.phones.iter()
+    }
+
+    /// The number of `phones` in the whole list, across all of its pages.
+    pub fn total(&self) -> i64 {
+        self.total_records
+    }
+
+    /// The number of `phones` left in the list once `seen` of them have been
+    /// read, say from a page or a stream, to report progress.
+    pub fn remaining(&self, seen: usize) -> i64 {
+        (self.total_records - seen as i64).max(0)
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CreateSipPhoneRequest {
     /**
@@ -12328,7 +13258,7 @@ pub struct UpdateSipPhoneRequest {
 /**
  * The status of the Zoom Room.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ListZoomRoomsStatus {
     #[serde(rename = "Available")]
     Available,
@@ -12363,7 +13293,30 @@ impl Default for ListZoomRoomsStatus {
         ListZoomRoomsStatus::Noop
     }
 }
+
 impl ListZoomRoomsStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ListZoomRoomsStatus::Available => "Available",
+            ListZoomRoomsStatus::InMeeting => "InMeeting",
+            ListZoomRoomsStatus::Offline => "Offline",
+            ListZoomRoomsStatus::UnderConstruction => "UnderConstruction",
+            ListZoomRoomsStatus::Noop => "",
+            ListZoomRoomsStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ListZoomRoomsStatus] {
+        &[
+            ListZoomRoomsStatus::Available,
+            ListZoomRoomsStatus::InMeeting,
+            ListZoomRoomsStatus::Offline,
+            ListZoomRoomsStatus::UnderConstruction,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ListZoomRoomsStatus::Noop)
     }
@@ -12372,7 +13325,7 @@ impl ListZoomRoomsStatus {
 /**
  * Type of the Zoom Rooms.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ListZoomRoomsType {
     #[serde(rename = "DigitalSignageOnly")]
     DigitalSignageOnly,
@@ -12404,7 +13357,28 @@ impl Default for ListZoomRoomsType {
         ListZoomRoomsType::Noop
     }
 }
+
 impl ListZoomRoomsType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ListZoomRoomsType::DigitalSignageOnly => "DigitalSignageOnly",
+            ListZoomRoomsType::SchedulingDisplayOnly => "SchedulingDisplayOnly",
+            ListZoomRoomsType::ZoomRoom => "ZoomRoom",
+            ListZoomRoomsType::Noop => "",
+            ListZoomRoomsType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ListZoomRoomsType] {
+        &[
+            ListZoomRoomsType::DigitalSignageOnly,
+            ListZoomRoomsType::SchedulingDisplayOnly,
+            ListZoomRoomsType::ZoomRoom,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ListZoomRoomsType::Noop)
     }
@@ -12495,6 +13469,31 @@ pub struct ListZoomRoomsResponseData {
     pub rooms: Vec<ListZoomRoomsResponse>,
 }
 
+impl IntoIterator for ListZoomRoomsResponseData {
+    type Item = ListZoomRoomsResponse;
+    type IntoIter = std::vec::IntoIter<ListZoomRoomsResponse>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.rooms.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ListZoomRoomsResponseData {
+    type Item = &'a ListZoomRoomsResponse;
+    type IntoIter = std::slice::Iter<'a, ListZoomRoomsResponse>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.rooms.iter()
+    }
+}
+
+impl ListZoomRoomsResponseData {
+    /// Iterate over the `rooms`.
+    pub fn iter(&self) -> std::slice::Iter<'_, ListZoomRoomsResponse> {
+        self.rooms.iter()
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddRoomRequest {
     /**
@@ -12716,6 +13715,31 @@ pub struct ListPhoneSitesResponse {
     pub total_records: String,
 }
 
+impl IntoIterator for ListPhoneSitesResponse {
+    type Item = Sites;
+    type IntoIter = std::vec::IntoIter<Sites>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.sites.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ListPhoneSitesResponse {
+    type Item = &'a Sites;
+    type IntoIter = std::slice::Iter<'a, Sites>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.sites.iter()
+    }
+}
+
+impl ListPhoneSitesResponse {
+    /// Iterate over the `sites`.
+    pub fn iter(&self) -> std::slice::Iter<'_, Sites> {
+        self.sites.iter()
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DefaultEmergencyAddress {
     /**
@@ -12941,7 +13965,7 @@ pub struct UpdateSiteDetailsRequest {
  * Presence status of the contact in Zoom Client. The value of this field can be one of the following:
  *   `Do_Not_Disturb`<br> `away`<br> `Available`<br> `Offline`
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PresenceStatus {
     #[serde(rename = "Available")]
     Available,
@@ -12976,7 +14000,30 @@ impl Default for PresenceStatus {
         PresenceStatus::Noop
     }
 }
+
 impl PresenceStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PresenceStatus::Available => "Available",
+            PresenceStatus::Away => "Away",
+            PresenceStatus::DoNotDisturb => "Do_Not_Disturb",
+            PresenceStatus::Offline => "Offline",
+            PresenceStatus::Noop => "",
+            PresenceStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PresenceStatus] {
+        &[
+            PresenceStatus::Available,
+            PresenceStatus::Away,
+            PresenceStatus::DoNotDisturb,
+            PresenceStatus::Offline,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PresenceStatus::Noop)
     }
@@ -13140,12 +14187,37 @@ pub struct SearchCompanyContactsResponse {
     pub page_size: i64,
 }
 
+impl IntoIterator for SearchCompanyContactsResponse {
+    type Item = Contacts;
+    type IntoIter = std::vec::IntoIter<Contacts>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.contacts.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a SearchCompanyContactsResponse {
+    type Item = &'a Contacts;
+    type IntoIter = std::slice::Iter<'a, Contacts>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.contacts.iter()
+    }
+}
+
+impl SearchCompanyContactsResponse {
+    /// Iterate over the `contacts`.
+    pub fn iter(&self) -> std::slice::Iter<'_, Contacts> {
+        self.contacts.iter()
+    }
+}
+
 /**
  * This field indicates whether a message is an original message(unedited), an edited message or a deleted message.
  *   
  *   This field is only returned if you set the value of `for include_deleted_and_edited_message` query parameter to `true`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GetChatMessagesResponseStatus {
     #[serde(rename = "Deleted")]
     Deleted,
@@ -13177,7 +14249,28 @@ impl Default for GetChatMessagesResponseStatus {
         GetChatMessagesResponseStatus::Noop
     }
 }
+
 impl GetChatMessagesResponseStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GetChatMessagesResponseStatus::Deleted => "Deleted",
+            GetChatMessagesResponseStatus::Edited => "Edited",
+            GetChatMessagesResponseStatus::Normal => "Normal",
+            GetChatMessagesResponseStatus::Noop => "",
+            GetChatMessagesResponseStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GetChatMessagesResponseStatus] {
+        &[
+            GetChatMessagesResponseStatus::Deleted,
+            GetChatMessagesResponseStatus::Edited,
+            GetChatMessagesResponseStatus::Normal,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, GetChatMessagesResponseStatus::Noop)
     }
@@ -13336,6 +14429,31 @@ pub struct GetChatMessagesResponse {
     pub page_size: i64,
 }
 
+impl IntoIterator for GetChatMessagesResponse {
+    type Item = Messages;
+    type IntoIter = std::vec::IntoIter<Messages>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.messages.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a GetChatMessagesResponse {
+    type Item = &'a Messages;
+    type IntoIter = std::slice::Iter<'a, Messages>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.messages.iter()
+    }
+}
+
+impl GetChatMessagesResponse {
+    /// Iterate over the `messages`.
+    pub fn iter(&self) -> std::slice::Iter<'_, Messages> {
+        self.messages.iter()
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AtItems {
     /**
@@ -13461,7 +14579,7 @@ pub struct MarkMessageRequest {
  *   * `add` — Add an emoji reaction.
  *   * `remove` — Remove an emoji reaction.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ReactMessageRequestAction {
     #[serde(rename = "add")]
     Add,
@@ -13490,7 +14608,26 @@ impl Default for ReactMessageRequestAction {
         ReactMessageRequestAction::Noop
     }
 }
+
 impl ReactMessageRequestAction {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReactMessageRequestAction::Add => "add",
+            ReactMessageRequestAction::Remove => "remove",
+            ReactMessageRequestAction::Noop => "",
+            ReactMessageRequestAction::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ReactMessageRequestAction] {
+        &[
+            ReactMessageRequestAction::Add,
+            ReactMessageRequestAction::Remove,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ReactMessageRequestAction::Noop)
     }
@@ -13779,6 +14916,42 @@ pub struct GetChannelsResponse {
     pub total_records: i64,
 }
 
+impl IntoIterator for GetChannelsResponse {
+    type Item = Channels;
+    type IntoIter = std::vec::IntoIter<Channels>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.channels.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a GetChannelsResponse {
+    type Item = &'a Channels;
+    type IntoIter = std::slice::Iter<'a, Channels>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.channels.iter()
+    }
+}
+
+impl GetChannelsResponse {
+    /// Iterate over the `channels`.
+    pub fn iter(&self) -> std::slice::Iter<'_, Channels> {
+        self.channels.iter()
+    }
+
+    /// The number of `channels` in the whole list, across all of its pages.
+    pub fn total(&self) -> i64 {
+        self.total_records
+    }
+
+    /// The number of `channels` left in the list once `seen` of them have been
+    /// read, say from a page or a stream, to report progress.
+    pub fn remaining(&self, seen: usize) -> i64 {
+        (self.total_records - seen as i64).max(0)
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Members {
     /**
@@ -13936,6 +15109,31 @@ pub struct GetUserContactsResponseData {
     pub page_size: i64,
 }
 
+impl IntoIterator for GetUserContactsResponseData {
+    type Item = GetUserContactsResponse;
+    type IntoIter = std::vec::IntoIter<GetUserContactsResponse>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.contacts.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a GetUserContactsResponseData {
+    type Item = &'a GetUserContactsResponse;
+    type IntoIter = std::slice::Iter<'a, GetUserContactsResponse>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.contacts.iter()
+    }
+}
+
+impl GetUserContactsResponseData {
+    /// Iterate over the `contacts`.
+    pub fn iter(&self) -> std::slice::Iter<'_, GetUserContactsResponse> {
+        self.contacts.iter()
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetUserContactResponse {
     /**
@@ -14015,7 +15213,7 @@ pub struct GetUserContactResponse {
 /**
  * The role of the member. The value can be one of these: `owner`, `admin`, `member`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Role {
     #[serde(rename = "admin")]
     Admin,
@@ -14047,7 +15245,24 @@ impl Default for Role {
         Role::Noop
     }
 }
+
 impl Role {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Admin => "admin",
+            Role::Member => "member",
+            Role::Owner => "owner",
+            Role::Noop => "",
+            Role::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Role] {
+        &[Role::Admin, Role::Member, Role::Owner]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Role::Noop)
     }
@@ -14147,6 +15362,42 @@ pub struct ListChannelMembersResponseData {
     pub total_records: i64,
 }
 
+impl IntoIterator for ListChannelMembersResponseData {
+    type Item = ListChannelMembersResponse;
+    type IntoIter = std::vec::IntoIter<ListChannelMembersResponse>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.members.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ListChannelMembersResponseData {
+    type Item = &'a ListChannelMembersResponse;
+    type IntoIter = std::slice::Iter<'a, ListChannelMembersResponse>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.members.iter()
+    }
+}
+
+impl ListChannelMembersResponseData {
+    /// Iterate over the `members`.
+    pub fn iter(&self) -> std::slice::Iter<'_, ListChannelMembersResponse> {
+        self.members.iter()
+    }
+
+    /// The number of `members` in the whole list, across all of its pages.
+    pub fn total(&self) -> i64 {
+        self.total_records
+    }
+
+    /// The number of `members` left in the list once `seen` of them have been
+    /// read, say from a page or a stream, to report progress.
+    pub fn remaining(&self, seen: usize) -> i64 {
+        (self.total_records - seen as i64).max(0)
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct InviteChannelMembersRequest {
     /**
@@ -14363,7 +15614,7 @@ pub struct AccountResponse {
  *   * `security` — View the account's security settings. For example, password requirements for user login or two-factor authentication.<br>
  *   * `meeting_security` — View the account's meeting security settings.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum OptionData {
     #[serde(rename = "meeting_authentication")]
     MeetingAuthentication,
@@ -14392,7 +15643,26 @@ impl Default for OptionData {
         OptionData::Noop
     }
 }
+
 impl OptionData {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OptionData::MeetingAuthentication => "meeting_authentication",
+            OptionData::RecordingAuthentication => "recording_authentication",
+            OptionData::Noop => "",
+            OptionData::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [OptionData] {
+        &[
+            OptionData::MeetingAuthentication,
+            OptionData::RecordingAuthentication,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, OptionData::Noop)
     }
@@ -14486,7 +15756,7 @@ impl AccountSettingsUpdateRequestOneOf {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum AccountSettingsUpdateOption {
     #[serde(rename = "meeting_authentication")]
     MeetingAuthentication,
@@ -14521,7 +15791,30 @@ impl Default for AccountSettingsUpdateOption {
         AccountSettingsUpdateOption::Noop
     }
 }
+
 impl AccountSettingsUpdateOption {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AccountSettingsUpdateOption::MeetingAuthentication => "meeting_authentication",
+            AccountSettingsUpdateOption::MeetingSecurity => "meeting_security",
+            AccountSettingsUpdateOption::RecordingAuthentication => "recording_authentication",
+            AccountSettingsUpdateOption::Security => "security",
+            AccountSettingsUpdateOption::Noop => "",
+            AccountSettingsUpdateOption::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [AccountSettingsUpdateOption] {
+        &[
+            AccountSettingsUpdateOption::MeetingAuthentication,
+            AccountSettingsUpdateOption::MeetingSecurity,
+            AccountSettingsUpdateOption::RecordingAuthentication,
+            AccountSettingsUpdateOption::Security,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, AccountSettingsUpdateOption::Noop)
     }
@@ -14546,7 +15839,7 @@ pub struct AccountTrustedDomainResponse {
 /**
  * Status of the plan.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum AccountPlansResponsePlanBaseStatus {
     #[serde(rename = "active")]
     Active,
@@ -14578,7 +15871,28 @@ impl Default for AccountPlansResponsePlanBaseStatus {
         AccountPlansResponsePlanBaseStatus::Noop
     }
 }
+
 impl AccountPlansResponsePlanBaseStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AccountPlansResponsePlanBaseStatus::Active => "active",
+            AccountPlansResponsePlanBaseStatus::Cancel => "cancel",
+            AccountPlansResponsePlanBaseStatus::Expired => "expired",
+            AccountPlansResponsePlanBaseStatus::Noop => "",
+            AccountPlansResponsePlanBaseStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [AccountPlansResponsePlanBaseStatus] {
+        &[
+            AccountPlansResponsePlanBaseStatus::Active,
+            AccountPlansResponsePlanBaseStatus::Cancel,
+            AccountPlansResponsePlanBaseStatus::Expired,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, AccountPlansResponsePlanBaseStatus::Noop)
     }
@@ -14680,7 +15994,7 @@ pub struct PlanWebinar {
 /**
  * Status of additional Cloud Recording plan.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PlanRecordingStatus {
     #[serde(rename = "Active")]
     Active,
@@ -14709,7 +16023,23 @@ impl Default for PlanRecordingStatus {
         PlanRecordingStatus::Noop
     }
 }
+
 impl PlanRecordingStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PlanRecordingStatus::Active => "Active",
+            PlanRecordingStatus::Cancelled => "Cancelled",
+            PlanRecordingStatus::Noop => "",
+            PlanRecordingStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PlanRecordingStatus] {
+        &[PlanRecordingStatus::Active, PlanRecordingStatus::Cancelled]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PlanRecordingStatus::Noop)
     }
@@ -15156,6 +16486,42 @@ pub struct GroupMembersResponseData {
     pub total_records: i64,
 }
 
+impl IntoIterator for GroupMembersResponseData {
+    type Item = UserCreateResponse;
+    type IntoIter = std::vec::IntoIter<UserCreateResponse>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.members.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a GroupMembersResponseData {
+    type Item = &'a UserCreateResponse;
+    type IntoIter = std::slice::Iter<'a, UserCreateResponse>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.members.iter()
+    }
+}
+
+impl GroupMembersResponseData {
+    /// Iterate over the `members`.
+    pub fn iter(&self) -> std::slice::Iter<'_, UserCreateResponse> {
+        self.members.iter()
+    }
+
+    /// The number of `members` in the whole list, across all of its pages.
+    pub fn total(&self) -> i64 {
+        self.total_records
+    }
+
+    /// The number of `members` left in the list once `seen` of them have been
+    /// read, say from a page or a stream, to report progress.
+    pub fn remaining(&self, seen: usize) -> i64 {
+        (self.total_records - seen as i64).max(0)
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddRoleMembersRequest {
     /**
@@ -15196,7 +16562,7 @@ pub struct GroupMembersCreateResponse {
  *   * `move` — Remove the group member from one group and move them to a different group.
  *   * `set_primary` — Set the user's primary group.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum UpdateGroupMemberRequestAction {
     #[serde(rename = "move")]
     Move,
@@ -15225,7 +16591,26 @@ impl Default for UpdateGroupMemberRequestAction {
         UpdateGroupMemberRequestAction::Noop
     }
 }
+
 impl UpdateGroupMemberRequestAction {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UpdateGroupMemberRequestAction::Move => "move",
+            UpdateGroupMemberRequestAction::SetPrimary => "set_primary",
+            UpdateGroupMemberRequestAction::Noop => "",
+            UpdateGroupMemberRequestAction::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [UpdateGroupMemberRequestAction] {
+        &[
+            UpdateGroupMemberRequestAction::Move,
+            UpdateGroupMemberRequestAction::SetPrimary,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, UpdateGroupMemberRequestAction::Noop)
     }
@@ -15287,7 +16672,7 @@ pub struct TrackingfieldGetResponseAllOf {
 /**
  * IM Group types:<br>`normal` - Only members can see automatically see the other members of this group. Other people can search for members within this group. <br>`shared` - Everyone under an account can see the group members automatically.<br>`restricted` - Nobody can see the group or search for members except the members in the group.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ImGroupCreateRequestType {
     #[serde(rename = "normal")]
     Normal,
@@ -15317,6 +16702,27 @@ impl Default for ImGroupCreateRequestType {
     }
 }
 
+impl ImGroupCreateRequestType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImGroupCreateRequestType::Normal => "normal",
+            ImGroupCreateRequestType::Restricted => "restricted",
+            ImGroupCreateRequestType::Shared => "shared",
+            ImGroupCreateRequestType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ImGroupCreateRequestType] {
+        &[
+            ImGroupCreateRequestType::Normal,
+            ImGroupCreateRequestType::Restricted,
+            ImGroupCreateRequestType::Shared,
+        ]
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ImGroupCreateRequest {
     /**
@@ -15442,7 +16848,7 @@ pub struct DashboardImResponse {
 /**
  * IM chat session type.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ImChatSessionsResponseType {
     #[serde(rename = "1:1")]
     One,
@@ -15471,7 +16877,26 @@ impl Default for ImChatSessionsResponseType {
         ImChatSessionsResponseType::Noop
     }
 }
+
 impl ImChatSessionsResponseType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImChatSessionsResponseType::One => "1:1",
+            ImChatSessionsResponseType::Group => "Group",
+            ImChatSessionsResponseType::Noop => "",
+            ImChatSessionsResponseType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ImChatSessionsResponseType] {
+        &[
+            ImChatSessionsResponseType::One,
+            ImChatSessionsResponseType::Group,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ImChatSessionsResponseType::Noop)
     }
@@ -15658,7 +17083,7 @@ pub struct ImChatMessagesResponseAllOf {
 /**
  * The meeting types: <br>`scheduled` - This includes all valid past meetings (unexpired), live meetings and upcoming scheduled meetings. It is equivalent to the combined list of "Previous Meetings" and "Upcoming Meetings" displayed in the user's [Meetings page](https://zoom.us/meeting) on the Zoom Web Portal.<br>`live` - All the ongoing meetings.<br>`upcoming` - All upcoming meetings including live meetings.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum MeetingsType {
     #[serde(rename = "live")]
     Live,
@@ -15688,6 +17113,27 @@ impl Default for MeetingsType {
     }
 }
 
+impl MeetingsType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MeetingsType::Live => "live",
+            MeetingsType::Scheduled => "scheduled",
+            MeetingsType::Upcoming => "upcoming",
+            MeetingsType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [MeetingsType] {
+        &[
+            MeetingsType::Live,
+            MeetingsType::Scheduled,
+            MeetingsType::Upcoming,
+        ]
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MeetingCreateResponse {
     /**
@@ -15837,7 +17283,7 @@ pub struct MeetingUpdateRequestAllOf {
  *   `recover` - [Recover](https://support.zoom.us/hc/en-us/articles/360038297111-Recover-a-deleted-meeting) a deleted meeting.
  *   
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum MeetingStatusRequestAction {
     #[serde(rename = "end")]
     End,
@@ -15866,7 +17312,26 @@ impl Default for MeetingStatusRequestAction {
         MeetingStatusRequestAction::Noop
     }
 }
+
 impl MeetingStatusRequestAction {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MeetingStatusRequestAction::End => "end",
+            MeetingStatusRequestAction::Recover => "recover",
+            MeetingStatusRequestAction::Noop => "",
+            MeetingStatusRequestAction::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [MeetingStatusRequestAction] {
+        &[
+            MeetingStatusRequestAction::End,
+            MeetingStatusRequestAction::Recover,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, MeetingStatusRequestAction::Noop)
     }
@@ -15886,7 +17351,7 @@ pub struct MeetingStatusRequest {
 /**
  * The registrant status:<br>`pending` - Registrant's status is pending.<br>`approved` - Registrant's status is approved.<br>`denied` - Registrant's status is denied.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum MeetingRegistrantsStatus {
     #[serde(rename = "approved")]
     Approved,
@@ -15916,6 +17381,27 @@ impl Default for MeetingRegistrantsStatus {
     }
 }
 
+impl MeetingRegistrantsStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MeetingRegistrantsStatus::Approved => "approved",
+            MeetingRegistrantsStatus::Denied => "denied",
+            MeetingRegistrantsStatus::Pending => "pending",
+            MeetingRegistrantsStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [MeetingRegistrantsStatus] {
+        &[
+            MeetingRegistrantsStatus::Approved,
+            MeetingRegistrantsStatus::Denied,
+            MeetingRegistrantsStatus::Pending,
+        ]
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MeetingRegistrantCreateResponse {
     /**
@@ -16140,7 +17626,7 @@ pub struct PastMeetingParticipantsResponseAllOf {
 /**
  * Status of the Meeting Poll:<br>`notstart` - Poll not started<br>`started` - Poll started<br>`ended` - Poll ended<br>`sharing` - Sharing poll results
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum MeetingPollCreateResponseStatus {
     #[serde(rename = "ended")]
     Ended,
@@ -16175,7 +17661,30 @@ impl Default for MeetingPollCreateResponseStatus {
         MeetingPollCreateResponseStatus::Noop
     }
 }
+
 impl MeetingPollCreateResponseStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MeetingPollCreateResponseStatus::Ended => "ended",
+            MeetingPollCreateResponseStatus::Notstart => "notstart",
+            MeetingPollCreateResponseStatus::Sharing => "sharing",
+            MeetingPollCreateResponseStatus::Started => "started",
+            MeetingPollCreateResponseStatus::Noop => "",
+            MeetingPollCreateResponseStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [MeetingPollCreateResponseStatus] {
+        &[
+            MeetingPollCreateResponseStatus::Ended,
+            MeetingPollCreateResponseStatus::Notstart,
+            MeetingPollCreateResponseStatus::Sharing,
+            MeetingPollCreateResponseStatus::Started,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, MeetingPollCreateResponseStatus::Noop)
     }
@@ -16251,7 +17760,7 @@ pub struct RecordingGetResponseAllOf {
 /**
  * The recording delete actions:<br>`trash` - Move recording to trash.<br>`delete` - Delete recording permanently.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum RecordingDeleteAction {
     #[serde(rename = "delete")]
     Delete,
@@ -16278,6 +17787,22 @@ impl Default for RecordingDeleteAction {
     }
 }
 
+impl RecordingDeleteAction {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RecordingDeleteAction::Delete => "delete",
+            RecordingDeleteAction::Trash => "trash",
+            RecordingDeleteAction::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [RecordingDeleteAction] {
+        &[RecordingDeleteAction::Delete, RecordingDeleteAction::Trash]
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MeetingRecordingRegistrantCreateResponse {
     /**
@@ -16323,7 +17848,7 @@ pub struct MeetingRecordingRegistrantCreateResponse {
  *   
  *   If you do not provide this field, the default value will be `live` and thus, the API will only query responses for live meetings.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum DashboardMeetingsType {
     #[serde(rename = "live")]
     Live,
@@ -16353,10 +17878,31 @@ impl Default for DashboardMeetingsType {
     }
 }
 
+impl DashboardMeetingsType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DashboardMeetingsType::Live => "live",
+            DashboardMeetingsType::Past => "past",
+            DashboardMeetingsType::PastOne => "pastOne",
+            DashboardMeetingsType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [DashboardMeetingsType] {
+        &[
+            DashboardMeetingsType::Live,
+            DashboardMeetingsType::Past,
+            DashboardMeetingsType::PastOne,
+        ]
+    }
+}
+
 /**
  * Set the value of this field to "tracking_fields" if you would like to include tracking fields of each meeting in the response.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum IncludeFields {
     #[serde(rename = "tracking_fields")]
     TrackingFields,
@@ -16382,7 +17928,22 @@ impl Default for IncludeFields {
         IncludeFields::Noop
     }
 }
+
 impl IncludeFields {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IncludeFields::TrackingFields => "tracking_fields",
+            IncludeFields::Noop => "",
+            IncludeFields::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [IncludeFields] {
+        &[IncludeFields::TrackingFields]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, IncludeFields::Noop)
     }
@@ -16422,7 +17983,7 @@ pub struct DashboardMeetingsResponseAllOf {
 /**
  * Provide `registrant_id` as the value for this field if you would like to see the registrant ID attribute in the response of this API call. A registrant ID is a unique identifier of a [meeting registrant](https://marketplace.zoom.us/docs/api-reference/zoom-api/meetings/meetingregistrants). This is not supported for `live` meeting types.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum DashboardMeetingParticipantsIncludeFields {
     #[serde(rename = "registrant_id")]
     RegistrantId,
@@ -16448,7 +18009,22 @@ impl Default for DashboardMeetingParticipantsIncludeFields {
         DashboardMeetingParticipantsIncludeFields::Noop
     }
 }
+
 impl DashboardMeetingParticipantsIncludeFields {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DashboardMeetingParticipantsIncludeFields::RegistrantId => "registrant_id",
+            DashboardMeetingParticipantsIncludeFields::Noop => "",
+            DashboardMeetingParticipantsIncludeFields::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [DashboardMeetingParticipantsIncludeFields] {
+        &[DashboardMeetingParticipantsIncludeFields::RegistrantId]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, DashboardMeetingParticipantsIncludeFields::Noop)
     }
@@ -16465,7 +18041,7 @@ impl DashboardMeetingParticipantsIncludeFields {
  *   
  *   **Note:** This response returns an empty string (`““`) value for any users who are **not** a part of the host’s account (external users).
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum DashboardMeetingParticipantsResponseDevice {
     #[serde(rename = "Android")]
     Android,
@@ -16506,7 +18082,34 @@ impl Default for DashboardMeetingParticipantsResponseDevice {
         DashboardMeetingParticipantsResponseDevice::Noop
     }
 }
+
 impl DashboardMeetingParticipantsResponseDevice {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DashboardMeetingParticipantsResponseDevice::Android => "Android",
+            DashboardMeetingParticipantsResponseDevice::H323Sip => "H.323/SIP",
+            DashboardMeetingParticipantsResponseDevice::Mac => "Mac",
+            DashboardMeetingParticipantsResponseDevice::Phone => "Phone",
+            DashboardMeetingParticipantsResponseDevice::Windows => "Windows",
+            DashboardMeetingParticipantsResponseDevice::IOs => "iOS",
+            DashboardMeetingParticipantsResponseDevice::Noop => "",
+            DashboardMeetingParticipantsResponseDevice::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [DashboardMeetingParticipantsResponseDevice] {
+        &[
+            DashboardMeetingParticipantsResponseDevice::Android,
+            DashboardMeetingParticipantsResponseDevice::H323Sip,
+            DashboardMeetingParticipantsResponseDevice::Mac,
+            DashboardMeetingParticipantsResponseDevice::Phone,
+            DashboardMeetingParticipantsResponseDevice::Windows,
+            DashboardMeetingParticipantsResponseDevice::IOs,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, DashboardMeetingParticipantsResponseDevice::Noop)
     }
@@ -16521,7 +18124,7 @@ impl DashboardMeetingParticipantsResponseDevice {
  *   * `Cellular` — 3G, 4G, and 5G cellular.
  *   * `Others` — An unknown device.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum NetworkType {
     #[serde(rename = "Cellular")]
     Cellular,
@@ -16559,7 +18162,32 @@ impl Default for NetworkType {
         NetworkType::Noop
     }
 }
+
 impl NetworkType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NetworkType::Cellular => "Cellular",
+            NetworkType::Others => "Others",
+            NetworkType::Ppp => "PPP",
+            NetworkType::Wifi => "Wifi",
+            NetworkType::Wired => "Wired",
+            NetworkType::Noop => "",
+            NetworkType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [NetworkType] {
+        &[
+            NetworkType::Cellular,
+            NetworkType::Others,
+            NetworkType::Ppp,
+            NetworkType::Wifi,
+            NetworkType::Wired,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, NetworkType::Noop)
     }
@@ -16568,7 +18196,7 @@ impl NetworkType {
 /**
  * Audio quality of the participant.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum AudioQuality {
     #[serde(rename = "bad")]
     Bad,
@@ -16603,7 +18231,30 @@ impl Default for AudioQuality {
         AudioQuality::Noop
     }
 }
+
 impl AudioQuality {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AudioQuality::Bad => "bad",
+            AudioQuality::Fair => "fair",
+            AudioQuality::Good => "good",
+            AudioQuality::Poor => "poor",
+            AudioQuality::Noop => "",
+            AudioQuality::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [AudioQuality] {
+        &[
+            AudioQuality::Bad,
+            AudioQuality::Fair,
+            AudioQuality::Good,
+            AudioQuality::Poor,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, AudioQuality::Noop)
     }
@@ -16614,7 +18265,7 @@ impl AudioQuality {
  *   
  *   The value of this field can be `in_meeting` or `in_waiting_room`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum DashboardMeetingParticipantsResponseStatus {
     #[serde(rename = "in_meeting")]
     InMeeting,
@@ -16643,7 +18294,26 @@ impl Default for DashboardMeetingParticipantsResponseStatus {
         DashboardMeetingParticipantsResponseStatus::Noop
     }
 }
+
 impl DashboardMeetingParticipantsResponseStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DashboardMeetingParticipantsResponseStatus::InMeeting => "in_meeting",
+            DashboardMeetingParticipantsResponseStatus::InWaitingRoom => "in_waiting_room",
+            DashboardMeetingParticipantsResponseStatus::Noop => "",
+            DashboardMeetingParticipantsResponseStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [DashboardMeetingParticipantsResponseStatus] {
+        &[
+            DashboardMeetingParticipantsResponseStatus::InMeeting,
+            DashboardMeetingParticipantsResponseStatus::InWaitingRoom,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, DashboardMeetingParticipantsResponseStatus::Noop)
     }
@@ -17061,7 +18731,7 @@ pub struct DashboardMeetingParticipantShareResponseAllOf {
 /**
  * The webinar type.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum DashboardWebinarsType {
     #[serde(rename = "live")]
     Live,
@@ -17088,6 +18758,22 @@ impl Default for DashboardWebinarsType {
     }
 }
 
+impl DashboardWebinarsType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DashboardWebinarsType::Live => "live",
+            DashboardWebinarsType::Past => "past",
+            DashboardWebinarsType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [DashboardWebinarsType] {
+        &[DashboardWebinarsType::Live, DashboardWebinarsType::Past]
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DashboardWebinarsResponse {
     /**
@@ -18049,7 +19735,7 @@ pub struct ReportDailyResponse {
 /**
  * Active or inactive hosts.<br>`active` - Active hosts. <br>`inactive` - Inactive hosts.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ReportUsersType {
     #[serde(rename = "active")]
     Active,
@@ -18078,7 +19764,23 @@ impl Default for ReportUsersType {
         ReportUsersType::Noop
     }
 }
+
 impl ReportUsersType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReportUsersType::Active => "active",
+            ReportUsersType::Inactive => "inactive",
+            ReportUsersType::Noop => "",
+            ReportUsersType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ReportUsersType] {
+        &[ReportUsersType::Active, ReportUsersType::Inactive]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ReportUsersType::Noop)
     }
@@ -18087,7 +19789,7 @@ impl ReportUsersType {
 /**
  * The meeting types: <br>`past` - Past meetings.<br>`pastOne` - Past one user meetings.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ReportMeetingsType {
     #[serde(rename = "past")]
     Past,
@@ -18114,6 +19816,22 @@ impl Default for ReportMeetingsType {
     }
 }
 
+impl ReportMeetingsType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReportMeetingsType::Past => "past",
+            ReportMeetingsType::PastOne => "pastOne",
+            ReportMeetingsType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ReportMeetingsType] {
+        &[ReportMeetingsType::Past, ReportMeetingsType::PastOne]
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Meetings {
     /**
@@ -18276,6 +19994,31 @@ pub struct ReportMeetingsResponse {
     pub to: Option<chrono::NaiveDate>,
 }
 
+impl IntoIterator for ReportMeetingsResponse {
+    type Item = Meetings;
+    type IntoIter = std::vec::IntoIter<Meetings>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.meetings.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ReportMeetingsResponse {
+    type Item = &'a Meetings;
+    type IntoIter = std::slice::Iter<'a, Meetings>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.meetings.iter()
+    }
+}
+
+impl ReportMeetingsResponse {
+    /// Iterate over the `meetings`.
+    pub fn iter(&self) -> std::slice::Iter<'_, Meetings> {
+        self.meetings.iter()
+    }
+}
+
 /// All of the following types are flattened into one object:
 ///
 /// - `Pagination`
@@ -18866,7 +20609,7 @@ pub struct ReportWebinarQaResponse {
 /**
  * Date types:<br>`start_time` - Query by call start time.<br>`end_time` - Query by call end time.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum QueryDateType {
     #[serde(rename = "end_time")]
     EndTime,
@@ -18893,6 +20636,22 @@ impl Default for QueryDateType {
     }
 }
 
+impl QueryDateType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            QueryDateType::EndTime => "end_time",
+            QueryDateType::StartTime => "start_time",
+            QueryDateType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [QueryDateType] {
+        &[QueryDateType::EndTime, QueryDateType::StartTime]
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ReportCloudRecordingResponse {
     /**
@@ -18926,7 +20685,7 @@ pub struct ReportCloudRecordingResponseAllOf {
  *   Filter your response by a category type to see reports for a specific category.
  *   The value for this field can be one of the following:<br> `all`<br>`user`<br>`user_settings`<br>`account`<br>`billing`<br>`im`<br>`recording`<br>`phone_contacts`<br>`webinar`<br>`sub_account`<br>`role`<br>`zoom_rooms`
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum CategoryType {
     #[serde(rename = "account")]
     Account,
@@ -18985,7 +20744,46 @@ impl Default for CategoryType {
         CategoryType::Noop
     }
 }
+
 impl CategoryType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CategoryType::Account => "account",
+            CategoryType::All => "all",
+            CategoryType::Billing => "billing",
+            CategoryType::Im => "im",
+            CategoryType::PhoneContacts => "phone_contacts",
+            CategoryType::Recording => "recording",
+            CategoryType::Role => "role",
+            CategoryType::SubAccount => "sub_account",
+            CategoryType::User => "user",
+            CategoryType::UserSettings => "user_settings",
+            CategoryType::Webinar => "webinar",
+            CategoryType::ZoomRooms => "zoom_rooms",
+            CategoryType::Noop => "",
+            CategoryType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [CategoryType] {
+        &[
+            CategoryType::Account,
+            CategoryType::All,
+            CategoryType::Billing,
+            CategoryType::Im,
+            CategoryType::PhoneContacts,
+            CategoryType::Recording,
+            CategoryType::Role,
+            CategoryType::SubAccount,
+            CategoryType::User,
+            CategoryType::UserSettings,
+            CategoryType::Webinar,
+            CategoryType::ZoomRooms,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, CategoryType::Noop)
     }
@@ -19323,7 +21121,7 @@ pub struct TspUpdateRequest {
  *   
  *   This value defaults to `active`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum UsersStatus {
     #[serde(rename = "active")]
     Active,
@@ -19353,12 +21151,33 @@ impl Default for UsersStatus {
     }
 }
 
+impl UsersStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UsersStatus::Active => "active",
+            UsersStatus::Inactive => "inactive",
+            UsersStatus::Pending => "pending",
+            UsersStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [UsersStatus] {
+        &[
+            UsersStatus::Active,
+            UsersStatus::Inactive,
+            UsersStatus::Pending,
+        ]
+    }
+}
+
 /**
  * Use this parameter to display one of the following attributes in the API call's response:
  *   * `custom_attributes` — Return the user's custom attributes.
  *   * `host_key` — Return the user's [host key](https://support.zoom.us/hc/en-us/articles/205172555-Using-your-host-key).
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum UsersIncludeFields {
     #[serde(rename = "custom_attributes")]
     CustomAttributes,
@@ -19387,7 +21206,26 @@ impl Default for UsersIncludeFields {
         UsersIncludeFields::Noop
     }
 }
+
 impl UsersIncludeFields {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UsersIncludeFields::CustomAttributes => "custom_attributes",
+            UsersIncludeFields::HostKey => "host_key",
+            UsersIncludeFields::Noop => "",
+            UsersIncludeFields::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [UsersIncludeFields] {
+        &[
+            UsersIncludeFields::CustomAttributes,
+            UsersIncludeFields::HostKey,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, UsersIncludeFields::Noop)
     }
@@ -19667,10 +21505,46 @@ pub struct UsersResponseData {
     pub users: Vec<UsersResponse>,
 }
 
+impl IntoIterator for UsersResponseData {
+    type Item = UsersResponse;
+    type IntoIter = std::vec::IntoIter<UsersResponse>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.users.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a UsersResponseData {
+    type Item = &'a UsersResponse;
+    type IntoIter = std::slice::Iter<'a, UsersResponse>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.users.iter()
+    }
+}
+
+impl UsersResponseData {
+    /// Iterate over the `users`.
+    pub fn iter(&self) -> std::slice::Iter<'_, UsersResponse> {
+        self.users.iter()
+    }
+
+    /// The number of `users` in the whole list, across all of its pages.
+    pub fn total(&self) -> i64 {
+        self.total_records
+    }
+
+    /// The number of `users` left in the list once `seen` of them have been
+    /// read, say from a page or a stream, to report progress.
+    pub fn remaining(&self, seen: usize) -> i64 {
+        (self.total_records - seen as i64).max(0)
+    }
+}
+
 /**
  * Specify how to create the new user: <br>`create` - User will get an email sent from Zoom. There is a confirmation link in this email. The user will then need to use the link to activate their Zoom account. The user can then set or change their password.<br>`autoCreate` - This action is provided for the enterprise customer who has a managed domain. This feature is disabled by default because of the security risk involved in creating a user who does not belong to your domain.<br>`custCreate` - Users created via this option do not have passwords and will not have the ability to log into the Zoom Web Portal or the Zoom Client. These users can still host and join meetings using the `start_url` and `join_url` respectively. To use this option, you must contact the ISV Platform Sales team at isv@zoom.us.<br>`ssoCreate` - This action is provided for the enabled “Pre-provisioning SSO User” option. A user created in this way has no password. If not a basic user, a personal vanity URL using the user name (no domain) of the provisioning email will be generated. If the user name or PMI is invalid or occupied, it will use a random number or random personal vanity URL.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum UserCreateRequestAction {
     #[serde(rename = "autoCreate")]
     AutoCreate,
@@ -19705,7 +21579,30 @@ impl Default for UserCreateRequestAction {
         UserCreateRequestAction::Noop
     }
 }
+
 impl UserCreateRequestAction {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UserCreateRequestAction::AutoCreate => "autoCreate",
+            UserCreateRequestAction::Create => "create",
+            UserCreateRequestAction::CustCreate => "custCreate",
+            UserCreateRequestAction::SsoCreate => "ssoCreate",
+            UserCreateRequestAction::Noop => "",
+            UserCreateRequestAction::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [UserCreateRequestAction] {
+        &[
+            UserCreateRequestAction::AutoCreate,
+            UserCreateRequestAction::Create,
+            UserCreateRequestAction::CustCreate,
+            UserCreateRequestAction::SsoCreate,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, UserCreateRequestAction::Noop)
     }
@@ -19781,7 +21678,7 @@ pub struct UserCreateRequest {
  *   
  *   `11` — Phone number</br>`21` — WeChat</br>`23` — Alipay
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum LoginType {
     #[serde(rename = "0")]
     Zero,
@@ -19840,7 +21737,46 @@ impl Default for LoginType {
         LoginType::Noop
     }
 }
+
 impl LoginType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LoginType::Zero => "0",
+            LoginType::One => "1",
+            LoginType::OneHundred => "100",
+            LoginType::OneHundredAndOne => "101",
+            LoginType::Eleven => "11",
+            LoginType::TwentyOne => "21",
+            LoginType::TwentyThree => "23",
+            LoginType::TwentyFour => "24",
+            LoginType::TwentySeven => "27",
+            LoginType::NinetySeven => "97",
+            LoginType::NinetyEight => "98",
+            LoginType::NinetyNine => "99",
+            LoginType::Noop => "",
+            LoginType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [LoginType] {
+        &[
+            LoginType::Zero,
+            LoginType::One,
+            LoginType::OneHundred,
+            LoginType::OneHundredAndOne,
+            LoginType::Eleven,
+            LoginType::TwentyOne,
+            LoginType::TwentyThree,
+            LoginType::TwentyFour,
+            LoginType::TwentySeven,
+            LoginType::NinetySeven,
+            LoginType::NinetyEight,
+            LoginType::NinetyNine,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, LoginType::Noop)
     }
@@ -20172,7 +22108,7 @@ pub struct UserResponseAllOf {
 /**
  * Delete action options:<br>`disassociate` - Disassociate a user.<br>`delete`-  Permanently delete a user.<br>Note: To delete pending user in the account, use `disassociate`
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum UserDeleteAction {
     #[serde(rename = "delete")]
     Delete,
@@ -20199,6 +22135,22 @@ impl Default for UserDeleteAction {
     }
 }
 
+impl UserDeleteAction {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UserDeleteAction::Delete => "delete",
+            UserDeleteAction::Disassociate => "disassociate",
+            UserDeleteAction::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [UserDeleteAction] {
+        &[UserDeleteAction::Delete, UserDeleteAction::Disassociate]
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UserZakResponse {
     /**
@@ -20288,7 +22240,7 @@ impl UserSettingsUpdateRequestOneOf {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum UserSettingsUpdateOption {
     #[serde(rename = "meeting_authentication")]
     MeetingAuthentication,
@@ -20320,7 +22272,28 @@ impl Default for UserSettingsUpdateOption {
         UserSettingsUpdateOption::Noop
     }
 }
+
 impl UserSettingsUpdateOption {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UserSettingsUpdateOption::MeetingAuthentication => "meeting_authentication",
+            UserSettingsUpdateOption::MeetingSecuirty => "meeting_secuirty",
+            UserSettingsUpdateOption::RecordingAuthentication => "recording_authentication",
+            UserSettingsUpdateOption::Noop => "",
+            UserSettingsUpdateOption::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [UserSettingsUpdateOption] {
+        &[
+            UserSettingsUpdateOption::MeetingAuthentication,
+            UserSettingsUpdateOption::MeetingSecuirty,
+            UserSettingsUpdateOption::RecordingAuthentication,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, UserSettingsUpdateOption::Noop)
     }
@@ -20329,7 +22302,7 @@ impl UserSettingsUpdateOption {
 /**
  * The action types:<br>`activate` - Activate a deactivated user.<br>`deactivate` - Deactivate a user.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum UserStatusRequestAction {
     #[serde(rename = "activate")]
     Activate,
@@ -20358,7 +22331,26 @@ impl Default for UserStatusRequestAction {
         UserStatusRequestAction::Noop
     }
 }
+
 impl UserStatusRequestAction {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UserStatusRequestAction::Activate => "activate",
+            UserStatusRequestAction::Deactivate => "deactivate",
+            UserStatusRequestAction::Noop => "",
+            UserStatusRequestAction::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [UserStatusRequestAction] {
+        &[
+            UserStatusRequestAction::Activate,
+            UserStatusRequestAction::Deactivate,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, UserStatusRequestAction::Noop)
     }
@@ -20588,7 +22580,7 @@ pub struct TspAccountData {
 /**
  * User token types:<br>`token` - Used for starting meetings with the client SDK. This token expires in 14 days and a new token will be returned after the expiry.<br>`zak` - Used for generating the start meeting URL. The token expiration time is two hours. For API users, the expiration time is 90 days.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum UserTokenType {
     #[serde(rename = "token")]
     Token,
@@ -20617,7 +22609,23 @@ impl Default for UserTokenType {
         UserTokenType::Noop
     }
 }
+
 impl UserTokenType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UserTokenType::Token => "token",
+            UserTokenType::Zak => "zak",
+            UserTokenType::Noop => "",
+            UserTokenType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [UserTokenType] {
+        &[UserTokenType::Token, UserTokenType::Zak]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, UserTokenType::Noop)
     }
@@ -20823,7 +22831,43 @@ pub struct ListWebinarParticipantsResponse {
     pub total_records: i64,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+impl IntoIterator for ListWebinarParticipantsResponse {
+    type Item = Participants;
+    type IntoIter = std::vec::IntoIter<Participants>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.participants.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ListWebinarParticipantsResponse {
+    type Item = &'a Participants;
+    type IntoIter = std::slice::Iter<'a, Participants>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.participants.iter()
+    }
+}
+
+impl ListWebinarParticipantsResponse {
+    /// Iterate over the `participants`.
+    pub fn iter(&self) -> std::slice::Iter<'_, Participants> {
+        self.participants.iter()
+    }
+
+    /// The number of `participants` in the whole list, across all of its pages.
+    pub fn total(&self) -> i64 {
+        self.total_records
+    }
+
+    /// The number of `participants` left in the list once `seen` of them have been
+    /// read, say from a page or a stream, to report progress.
+    pub fn remaining(&self, seen: usize) -> i64 {
+        (self.total_records - seen as i64).max(0)
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum WebinarStatusRequestAction {
     #[serde(rename = "end")]
     End,
@@ -20849,7 +22893,22 @@ impl Default for WebinarStatusRequestAction {
         WebinarStatusRequestAction::Noop
     }
 }
+
 impl WebinarStatusRequestAction {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebinarStatusRequestAction::End => "end",
+            WebinarStatusRequestAction::Noop => "",
+            WebinarStatusRequestAction::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [WebinarStatusRequestAction] {
+        &[WebinarStatusRequestAction::End]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, WebinarStatusRequestAction::Noop)
     }
@@ -21430,6 +23489,31 @@ pub struct ListimmessagesResponse {
     pub page_size: i64,
 }
 
+impl IntoIterator for ListimmessagesResponse {
+    type Item = ListimmessagesResponseMessages;
+    type IntoIter = std::vec::IntoIter<ListimmessagesResponseMessages>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.messages.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ListimmessagesResponse {
+    type Item = &'a ListimmessagesResponseMessages;
+    type IntoIter = std::slice::Iter<'a, ListimmessagesResponseMessages>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.messages.iter()
+    }
+}
+
+impl ListimmessagesResponse {
+    /// Iterate over the `messages`.
+    pub fn iter(&self) -> std::slice::Iter<'_, ListimmessagesResponseMessages> {
+        self.messages.iter()
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SendimmessagesRequest {
     /**
@@ -21960,7 +24044,7 @@ pub struct PhoneUserSettingsResponse {
  *   * `zr`<br>
  *   * `interop`<br>
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ListSettingTemplatesResponseType {
     #[serde(rename = "autReceptionist")]
     AutReceptionist,
@@ -22001,7 +24085,34 @@ impl Default for ListSettingTemplatesResponseType {
         ListSettingTemplatesResponseType::Noop
     }
 }
+
 impl ListSettingTemplatesResponseType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ListSettingTemplatesResponseType::AutReceptionist => "autReceptionist",
+            ListSettingTemplatesResponseType::CommonArea => "commonArea",
+            ListSettingTemplatesResponseType::Group => "group",
+            ListSettingTemplatesResponseType::Interop => "interop",
+            ListSettingTemplatesResponseType::User => "user",
+            ListSettingTemplatesResponseType::Zr => "zr",
+            ListSettingTemplatesResponseType::Noop => "",
+            ListSettingTemplatesResponseType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ListSettingTemplatesResponseType] {
+        &[
+            ListSettingTemplatesResponseType::AutReceptionist,
+            ListSettingTemplatesResponseType::CommonArea,
+            ListSettingTemplatesResponseType::Group,
+            ListSettingTemplatesResponseType::Interop,
+            ListSettingTemplatesResponseType::User,
+            ListSettingTemplatesResponseType::Zr,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ListSettingTemplatesResponseType::Noop)
     }
@@ -22087,10 +24198,46 @@ pub struct ListSettingTemplatesResponse {
     pub total_records: i64,
 }
 
+impl IntoIterator for ListSettingTemplatesResponse {
+    type Item = Templates;
+    type IntoIter = std::vec::IntoIter<Templates>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.templates.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ListSettingTemplatesResponse {
+    type Item = &'a Templates;
+    type IntoIter = std::slice::Iter<'a, Templates>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.templates.iter()
+    }
+}
+
+impl ListSettingTemplatesResponse {
+    /// Iterate over the `templates`.
+    pub fn iter(&self) -> std::slice::Iter<'_, Templates> {
+        self.templates.iter()
+    }
+
+    /// The number of `templates` in the whole list, across all of its pages.
+    pub fn total(&self) -> i64 {
+        self.total_records
+    }
+
+    /// The number of `templates` left in the list once `seen` of them have been
+    /// read, say from a page or a stream, to report progress.
+    pub fn remaining(&self, seen: usize) -> i64 {
+        (self.total_records - seen as i64).max(0)
+    }
+}
+
 /**
  * The type of template. Values include `user`, `group`, `autoReceptionist`, `commonarea`, or `interop`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum AddSettingTemplateRequestType {
     #[serde(rename = "autoReceptionist")]
     AutoReceptionist,
@@ -22128,7 +24275,32 @@ impl Default for AddSettingTemplateRequestType {
         AddSettingTemplateRequestType::Noop
     }
 }
+
 impl AddSettingTemplateRequestType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AddSettingTemplateRequestType::AutoReceptionist => "autoReceptionist",
+            AddSettingTemplateRequestType::Commonarea => "commonarea",
+            AddSettingTemplateRequestType::Group => "group",
+            AddSettingTemplateRequestType::Interop => "interop",
+            AddSettingTemplateRequestType::User => "user",
+            AddSettingTemplateRequestType::Noop => "",
+            AddSettingTemplateRequestType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [AddSettingTemplateRequestType] {
+        &[
+            AddSettingTemplateRequestType::AutoReceptionist,
+            AddSettingTemplateRequestType::Commonarea,
+            AddSettingTemplateRequestType::Group,
+            AddSettingTemplateRequestType::Interop,
+            AddSettingTemplateRequestType::User,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, AddSettingTemplateRequestType::Noop)
     }
@@ -22646,6 +24818,31 @@ pub struct ListLocationsResponseData {
     pub page_size: i64,
 }
 
+impl IntoIterator for ListLocationsResponseData {
+    type Item = ListLocationsResponse;
+    type IntoIter = std::vec::IntoIter<ListLocationsResponse>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.locations.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ListLocationsResponseData {
+    type Item = &'a ListLocationsResponse;
+    type IntoIter = std::slice::Iter<'a, ListLocationsResponse>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.locations.iter()
+    }
+}
+
+impl ListLocationsResponseData {
+    /// Iterate over the `locations`.
+    pub fn iter(&self) -> std::slice::Iter<'_, ListLocationsResponse> {
+        self.locations.iter()
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddLocationRequest {
     /**
@@ -22731,6 +24928,28 @@ pub struct AddLocationRequest {
     pub site_id: String,
 }
 
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AddLocationResponse {
+    /**
+     * The phone's location ID.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    /**
+     * The phone's location name.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+}
+
 /// The ELIN (Emergency Location Identification Number).
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetLocationResponseElin {
@@ -23125,10 +25344,35 @@ pub struct ListSipGroupsResponse {
     pub sip_groups: Vec<SipGroups>,
 }
 
+impl IntoIterator for ListSipGroupsResponse {
+    type Item = SipGroups;
+    type IntoIter = std::vec::IntoIter<SipGroups>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.sip_groups.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ListSipGroupsResponse {
+    type Item = &'a SipGroups;
+    type IntoIter = std::slice::Iter<'a, SipGroups>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.sip_groups.iter()
+    }
+}
+
+impl ListSipGroupsResponse {
+    /// Iterate over the `sip_groups`.
+    pub fn iter(&self) -> std::slice::Iter<'_, SipGroups> {
+        self.sip_groups.iter()
+    }
+}
+
 /**
  * The type of template being queried. Values: `user`, `group`, `auto receptionist` `common area`,`zr`, `interop`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GetSettingTemplateResponseType {
     #[serde(rename = "autoReceptionist")]
     AutoReceptionist,
@@ -23169,7 +25413,34 @@ impl Default for GetSettingTemplateResponseType {
         GetSettingTemplateResponseType::Noop
     }
 }
+
 impl GetSettingTemplateResponseType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GetSettingTemplateResponseType::AutoReceptionist => "autoReceptionist",
+            GetSettingTemplateResponseType::CommonArea => "commonArea",
+            GetSettingTemplateResponseType::Group => "group",
+            GetSettingTemplateResponseType::Interop => "interop",
+            GetSettingTemplateResponseType::User => "user",
+            GetSettingTemplateResponseType::Zr => "zr",
+            GetSettingTemplateResponseType::Noop => "",
+            GetSettingTemplateResponseType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GetSettingTemplateResponseType] {
+        &[
+            GetSettingTemplateResponseType::AutoReceptionist,
+            GetSettingTemplateResponseType::CommonArea,
+            GetSettingTemplateResponseType::Group,
+            GetSettingTemplateResponseType::Interop,
+            GetSettingTemplateResponseType::User,
+            GetSettingTemplateResponseType::Zr,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, GetSettingTemplateResponseType::Noop)
     }
@@ -23358,7 +25629,7 @@ pub struct CustomHours {
  *   5-zoomRoom, <br>
  *   7-sharedLineGroup
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType {
     #[serde(rename = "autoReceptionist")]
     AutoReceptionist,
@@ -23404,7 +25675,34 @@ impl Default
         GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::Noop
     }
 }
+
 impl GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::AutoReceptionist => "autoReceptionist",
+            GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::CallQueue => "callQueue",
+            GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::CommonAreaPhone => "commonAreaPhone",
+            GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::SharedLineGroup => "sharedLineGroup",
+            GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::User => "user",
+            GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::ZoomRoom => "zoomRoom",
+            GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::Noop => "",
+            GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType]{
+        &[
+            GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::AutoReceptionist,
+            GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::CallQueue,
+            GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::CommonAreaPhone,
+            GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::SharedLineGroup,
+            GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::User,
+            GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::ZoomRoom,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::Noop)
     }
@@ -23536,7 +25834,7 @@ pub struct GetSettingTemplateResponseUserSettingsDeskPhone {
  *   
  *   * `disable`: This means that the hold music is disabled.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum HoldMusic {
     #[serde(rename = "default")]
     Default,
@@ -23565,7 +25863,23 @@ impl Default for HoldMusic {
         HoldMusic::Noop
     }
 }
+
 impl HoldMusic {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HoldMusic::Default => "default",
+            HoldMusic::Disable => "disable",
+            HoldMusic::Noop => "",
+            HoldMusic::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [HoldMusic] {
+        &[HoldMusic::Default, HoldMusic::Disable]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, HoldMusic::Noop)
     }
@@ -23650,7 +25964,7 @@ pub struct GetSettingTemplateResponse {
 /**
  * Values: inbound, outbound, both.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum RecordingCalls {
     #[serde(rename = "both")]
     Both,
@@ -23682,7 +25996,28 @@ impl Default for RecordingCalls {
         RecordingCalls::Noop
     }
 }
+
 impl RecordingCalls {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RecordingCalls::Both => "both",
+            RecordingCalls::Inbound => "inbound",
+            RecordingCalls::Outbound => "outbound",
+            RecordingCalls::Noop => "",
+            RecordingCalls::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [RecordingCalls] {
+        &[
+            RecordingCalls::Both,
+            RecordingCalls::Inbound,
+            RecordingCalls::Outbound,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, RecordingCalls::Noop)
     }
@@ -23761,7 +26096,7 @@ pub struct UpdateSettingTemplateRequest {
     pub user_settings: Option<GetSettingTemplateResponseUserSettings>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PhoneUserCallLogsType {
     #[serde(rename = "all")]
     All,
@@ -23790,7 +26125,23 @@ impl Default for PhoneUserCallLogsType {
         PhoneUserCallLogsType::Noop
     }
 }
+
 impl PhoneUserCallLogsType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PhoneUserCallLogsType::All => "all",
+            PhoneUserCallLogsType::Missed => "missed",
+            PhoneUserCallLogsType::Noop => "",
+            PhoneUserCallLogsType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PhoneUserCallLogsType] {
+        &[PhoneUserCallLogsType::All, PhoneUserCallLogsType::Missed]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PhoneUserCallLogsType::Noop)
     }
@@ -23799,7 +26150,7 @@ impl PhoneUserCallLogsType {
 /**
  * Enables you to sort call logs by start or end time. Choose the sort time value. Values include `startTime` or `endTime`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum TimeType {
     #[serde(rename = "endTime")]
     EndTime,
@@ -23826,6 +26177,22 @@ impl Default for TimeType {
     }
 }
 
+impl TimeType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TimeType::EndTime => "endTime",
+            TimeType::StartTime => "startTime",
+            TimeType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [TimeType] {
+        &[TimeType::EndTime, TimeType::StartTime]
+    }
+}
+
 /// Indicates who the call was forwarded to.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ForwardedTo {
@@ -23876,7 +26243,7 @@ pub struct ForwardedTo {
     pub phone_number: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PhoneUserCallLogsResponseForwardedByExtensionType {
     #[serde(rename = "autoReceptionist")]
     AutoReceptionist,
@@ -23916,7 +26283,34 @@ impl Default for PhoneUserCallLogsResponseForwardedByExtensionType {
         PhoneUserCallLogsResponseForwardedByExtensionType::Noop
     }
 }
+
 impl PhoneUserCallLogsResponseForwardedByExtensionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PhoneUserCallLogsResponseForwardedByExtensionType::AutoReceptionist => {
+                "autoReceptionist"
+            }
+            PhoneUserCallLogsResponseForwardedByExtensionType::CallQueue => "callQueue",
+            PhoneUserCallLogsResponseForwardedByExtensionType::CommonAreaPhone => "commonAreaPhone",
+            PhoneUserCallLogsResponseForwardedByExtensionType::SharedLineGroup => "sharedLineGroup",
+            PhoneUserCallLogsResponseForwardedByExtensionType::User => "user",
+            PhoneUserCallLogsResponseForwardedByExtensionType::Noop => "",
+            PhoneUserCallLogsResponseForwardedByExtensionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PhoneUserCallLogsResponseForwardedByExtensionType] {
+        &[
+            PhoneUserCallLogsResponseForwardedByExtensionType::AutoReceptionist,
+            PhoneUserCallLogsResponseForwardedByExtensionType::CallQueue,
+            PhoneUserCallLogsResponseForwardedByExtensionType::CommonAreaPhone,
+            PhoneUserCallLogsResponseForwardedByExtensionType::SharedLineGroup,
+            PhoneUserCallLogsResponseForwardedByExtensionType::User,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(
             self,
@@ -24372,6 +26766,42 @@ pub struct PhoneUserCallLogsResponse {
     pub total_records: i64,
 }
 
+impl IntoIterator for PhoneUserCallLogsResponse {
+    type Item = CallLogs;
+    type IntoIter = std::vec::IntoIter<CallLogs>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.call_logs.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a PhoneUserCallLogsResponse {
+    type Item = &'a CallLogs;
+    type IntoIter = std::slice::Iter<'a, CallLogs>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.call_logs.iter()
+    }
+}
+
+impl PhoneUserCallLogsResponse {
+    /// Iterate over the `call_logs`.
+    pub fn iter(&self) -> std::slice::Iter<'_, CallLogs> {
+        self.call_logs.iter()
+    }
+
+    /// The number of `call_logs` in the whole list, across all of its pages.
+    pub fn total(&self) -> i64 {
+        self.total_records
+    }
+
+    /// The number of `call_logs` left in the list once `seen` of them have been
+    /// read, say from a page or a stream, to report progress.
+    pub fn remaining(&self, seen: usize) -> i64 {
+        (self.total_records - seen as i64).max(0)
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Recordings {
     /**
@@ -24561,10 +26991,46 @@ pub struct PhoneUserRecordingsResponse {
     pub total_records: i64,
 }
 
+impl IntoIterator for PhoneUserRecordingsResponse {
+    type Item = Recordings;
+    type IntoIter = std::vec::IntoIter<Recordings>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.recordings.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a PhoneUserRecordingsResponse {
+    type Item = &'a Recordings;
+    type IntoIter = std::slice::Iter<'a, Recordings>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.recordings.iter()
+    }
+}
+
+impl PhoneUserRecordingsResponse {
+    /// Iterate over the `recordings`.
+    pub fn iter(&self) -> std::slice::Iter<'_, Recordings> {
+        self.recordings.iter()
+    }
+
+    /// The number of `recordings` in the whole list, across all of its pages.
+    pub fn total(&self) -> i64 {
+        self.total_records
+    }
+
+    /// The number of `recordings` left in the list once `seen` of them have been
+    /// read, say from a page or a stream, to report progress.
+    pub fn remaining(&self, seen: usize) -> i64 {
+        (self.total_records - seen as i64).max(0)
+    }
+}
+
 /**
  * Status of the voice mail
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PhoneUserVoiceMailsStatus {
     #[serde(rename = "all")]
     All,
@@ -24579,7 +27045,7 @@ pub enum PhoneUserVoiceMailsStatus {
 impl std::fmt::Display for PhoneUserVoiceMailsStatus {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match &*self {
-            PhoneUserVoiceMailsStatus::All => "all",
+            PhoneUserVoiceMailsStatus::All => "",
             PhoneUserVoiceMailsStatus::Read => "read",
             PhoneUserVoiceMailsStatus::Unread => "unread",
             PhoneUserVoiceMailsStatus::FallthroughString => "*",
@@ -24594,10 +27060,31 @@ impl Default for PhoneUserVoiceMailsStatus {
     }
 }
 
+impl PhoneUserVoiceMailsStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PhoneUserVoiceMailsStatus::All => "all",
+            PhoneUserVoiceMailsStatus::Read => "read",
+            PhoneUserVoiceMailsStatus::Unread => "unread",
+            PhoneUserVoiceMailsStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PhoneUserVoiceMailsStatus] {
+        &[
+            PhoneUserVoiceMailsStatus::All,
+            PhoneUserVoiceMailsStatus::Read,
+            PhoneUserVoiceMailsStatus::Unread,
+        ]
+    }
+}
+
 /**
  * Status of the voice mail. Can be either 'read' or 'unread'
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum PhoneUserVoiceMailsResponseStatus {
     #[serde(rename = "read")]
     Read,
@@ -24626,7 +27113,26 @@ impl Default for PhoneUserVoiceMailsResponseStatus {
         PhoneUserVoiceMailsResponseStatus::Noop
     }
 }
+
 impl PhoneUserVoiceMailsResponseStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PhoneUserVoiceMailsResponseStatus::Read => "read",
+            PhoneUserVoiceMailsResponseStatus::Unread => "unread",
+            PhoneUserVoiceMailsResponseStatus::Noop => "",
+            PhoneUserVoiceMailsResponseStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [PhoneUserVoiceMailsResponseStatus] {
+        &[
+            PhoneUserVoiceMailsResponseStatus::Read,
+            PhoneUserVoiceMailsResponseStatus::Unread,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, PhoneUserVoiceMailsResponseStatus::Noop)
     }
@@ -24799,6 +27305,42 @@ pub struct PhoneUserVoiceMailsResponse {
     pub voice_mails: Vec<VoiceMails>,
 }
 
+impl IntoIterator for PhoneUserVoiceMailsResponse {
+    type Item = VoiceMails;
+    type IntoIter = std::vec::IntoIter<VoiceMails>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.voice_mails.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a PhoneUserVoiceMailsResponse {
+    type Item = &'a VoiceMails;
+    type IntoIter = std::slice::Iter<'a, VoiceMails>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.voice_mails.iter()
+    }
+}
+
+impl PhoneUserVoiceMailsResponse {
+    /// Iterate over the `voice_mails`.
+    pub fn iter(&self) -> std::slice::Iter<'_, VoiceMails> {
+        self.voice_mails.iter()
+    }
+
+    /// The number of `voice_mails` in the whole list, across all of its pages.
+    pub fn total(&self) -> i64 {
+        self.total_records
+    }
+
+    /// The number of `voice_mails` left in the list once `seen` of them have been
+    /// read, say from a page or a stream, to report progress.
+    pub fn remaining(&self, seen: usize) -> i64 {
+        (self.total_records - seen as i64).max(0)
+    }
+}
+
 /// Update the voicemail setting.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddUserSettingRequestVoiceMail {
@@ -24938,7 +27480,7 @@ pub struct UpdateUserSettingRequest {
  *   *`international`
  *   *`contactCenter`
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum CallType {
     #[serde(rename = "contactCenter")]
     ContactCenter,
@@ -24976,7 +27518,32 @@ impl Default for CallType {
         CallType::Noop
     }
 }
+
 impl CallType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CallType::ContactCenter => "contactCenter",
+            CallType::International => "international",
+            CallType::Pstn => "pstn",
+            CallType::Tollfree => "tollfree",
+            CallType::Voip => "voip",
+            CallType::Noop => "",
+            CallType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [CallType] {
+        &[
+            CallType::ContactCenter,
+            CallType::International,
+            CallType::Pstn,
+            CallType::Tollfree,
+            CallType::Voip,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, CallType::Noop)
     }
@@ -25302,6 +27869,42 @@ pub struct AccountCallLogsResponseData {
     pub total_records: i64,
 }
 
+impl IntoIterator for AccountCallLogsResponseData {
+    type Item = AccountCallLogsResponse;
+    type IntoIter = std::vec::IntoIter<AccountCallLogsResponse>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.call_logs.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a AccountCallLogsResponseData {
+    type Item = &'a AccountCallLogsResponse;
+    type IntoIter = std::slice::Iter<'a, AccountCallLogsResponse>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.call_logs.iter()
+    }
+}
+
+impl AccountCallLogsResponseData {
+    /// Iterate over the `call_logs`.
+    pub fn iter(&self) -> std::slice::Iter<'_, AccountCallLogsResponse> {
+        self.call_logs.iter()
+    }
+
+    /// The number of `call_logs` in the whole list, across all of its pages.
+    pub fn total(&self) -> i64 {
+        self.total_records
+    }
+
+    /// The number of `call_logs` left in the list once `seen` of them have been
+    /// read, say from a page or a stream, to report progress.
+    pub fn remaining(&self, seen: usize) -> i64 {
+        (self.total_records - seen as i64).max(0)
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddByocNumberResponse {
     #[serde(
@@ -25336,10 +27939,123 @@ pub struct AssignCallingPlanRequest {
     pub calling_plans: Vec<CallingPlans>,
 }
 
+/**
+ * The owner type. The allowed values are null, `user`, or `callQueue`. The default is null. If null, returns all owner types.
+ *   
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
+pub enum OwnerType {
+    #[serde(rename = "callQueue")]
+    CallQueue,
+    #[serde(rename = "user")]
+    User,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(other)]
+    FallthroughString,
+}
+
+impl std::fmt::Display for OwnerType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            OwnerType::CallQueue => "callQueue",
+            OwnerType::User => "user",
+            OwnerType::Noop => "",
+            OwnerType::FallthroughString => "*",
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for OwnerType {
+    fn default() -> OwnerType {
+        OwnerType::Noop
+    }
+}
+
+impl OwnerType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OwnerType::CallQueue => "callQueue",
+            OwnerType::User => "user",
+            OwnerType::Noop => "",
+            OwnerType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [OwnerType] {
+        &[OwnerType::CallQueue, OwnerType::User]
+    }
+
+    pub fn is_noop(&self) -> bool {
+        matches!(self, OwnerType::Noop)
+    }
+}
+
+/**
+ * The recording type. The allowed values are null, `OnDemand`, or `Automatic`. The default is null. If null, returns all recording types.
+ *   
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
+pub enum GetPhoneRecordingsRecordingType {
+    #[serde(rename = "Automatic")]
+    Automatic,
+    #[serde(rename = "OnDemand")]
+    OnDemand,
+    #[serde(rename = "")]
+    Noop,
+    #[serde(other)]
+    FallthroughString,
+}
+
+impl std::fmt::Display for GetPhoneRecordingsRecordingType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &*self {
+            GetPhoneRecordingsRecordingType::Automatic => "Automatic",
+            GetPhoneRecordingsRecordingType::OnDemand => "OnDemand",
+            GetPhoneRecordingsRecordingType::Noop => "",
+            GetPhoneRecordingsRecordingType::FallthroughString => "*",
+        }
+        .fmt(f)
+    }
+}
+
+impl Default for GetPhoneRecordingsRecordingType {
+    fn default() -> GetPhoneRecordingsRecordingType {
+        GetPhoneRecordingsRecordingType::Noop
+    }
+}
+
+impl GetPhoneRecordingsRecordingType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GetPhoneRecordingsRecordingType::Automatic => "Automatic",
+            GetPhoneRecordingsRecordingType::OnDemand => "OnDemand",
+            GetPhoneRecordingsRecordingType::Noop => "",
+            GetPhoneRecordingsRecordingType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GetPhoneRecordingsRecordingType] {
+        &[
+            GetPhoneRecordingsRecordingType::Automatic,
+            GetPhoneRecordingsRecordingType::OnDemand,
+        ]
+    }
+
+    pub fn is_noop(&self) -> bool {
+        matches!(self, GetPhoneRecordingsRecordingType::Noop)
+    }
+}
+
 /**
  * The owner type: `user` or `call queue`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GetPhoneRecordingsResponseOwnerType {
     #[serde(rename = "call queue")]
     CallQueue,
@@ -25368,7 +28084,26 @@ impl Default for GetPhoneRecordingsResponseOwnerType {
         GetPhoneRecordingsResponseOwnerType::Noop
     }
 }
+
 impl GetPhoneRecordingsResponseOwnerType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GetPhoneRecordingsResponseOwnerType::CallQueue => "call queue",
+            GetPhoneRecordingsResponseOwnerType::User => "user",
+            GetPhoneRecordingsResponseOwnerType::Noop => "",
+            GetPhoneRecordingsResponseOwnerType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GetPhoneRecordingsResponseOwnerType] {
+        &[
+            GetPhoneRecordingsResponseOwnerType::CallQueue,
+            GetPhoneRecordingsResponseOwnerType::User,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, GetPhoneRecordingsResponseOwnerType::Noop)
     }
@@ -25414,7 +28149,7 @@ pub struct GetPhoneRecordingsResponseOwner {
 /**
  * The direction of the call. Values: `inbound` or `outbound`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Direction {
     #[serde(rename = "inbound")]
     Inbound,
@@ -25443,7 +28178,23 @@ impl Default for Direction {
         Direction::Noop
     }
 }
+
 impl Direction {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Direction::Inbound => "inbound",
+            Direction::Outbound => "outbound",
+            Direction::Noop => "",
+            Direction::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Direction] {
+        &[Direction::Inbound, Direction::Outbound]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Direction::Noop)
     }
@@ -25622,6 +28373,42 @@ pub struct GetPhoneRecordingsResponseData {
     pub total_records: i64,
 }
 
+impl IntoIterator for GetPhoneRecordingsResponseData {
+    type Item = GetPhoneRecordingsResponse;
+    type IntoIter = std::vec::IntoIter<GetPhoneRecordingsResponse>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.recordings.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a GetPhoneRecordingsResponseData {
+    type Item = &'a GetPhoneRecordingsResponse;
+    type IntoIter = std::slice::Iter<'a, GetPhoneRecordingsResponse>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.recordings.iter()
+    }
+}
+
+impl GetPhoneRecordingsResponseData {
+    /// Iterate over the `recordings`.
+    pub fn iter(&self) -> std::slice::Iter<'_, GetPhoneRecordingsResponse> {
+        self.recordings.iter()
+    }
+
+    /// The number of `recordings` in the whole list, across all of its pages.
+    pub fn total(&self) -> i64 {
+        self.total_records
+    }
+
+    /// The number of `recordings` left in the list once `seen` of them have been
+    /// read, say from a page or a stream, to report progress.
+    pub fn remaining(&self, seen: usize) -> i64 {
+        (self.total_records - seen as i64).max(0)
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ByocSipTrunk {
     /**
@@ -25708,6 +28495,31 @@ pub struct ListByocsipTrunkResponse {
     pub page_size: i64,
 }
 
+impl IntoIterator for ListByocsipTrunkResponse {
+    type Item = ByocSipTrunk;
+    type IntoIter = std::vec::IntoIter<ByocSipTrunk>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.byoc_sip_trunk.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ListByocsipTrunkResponse {
+    type Item = &'a ByocSipTrunk;
+    type IntoIter = std::slice::Iter<'a, ByocSipTrunk>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.byoc_sip_trunk.iter()
+    }
+}
+
+impl ListByocsipTrunkResponse {
+    /// Iterate over the `byoc_sip_trunk`.
+    pub fn iter(&self) -> std::slice::Iter<'_, ByocSipTrunk> {
+        self.byoc_sip_trunk.iter()
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SipTrunks {
     /**
@@ -25808,7 +28620,7 @@ pub struct AccountPlanBaseDeleteRequest {
 /**
  * The action that needs to be taken for this sub account. Value must be set to "cancel".
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum AccountPlanAddonCancelRequestAction {
     #[serde(rename = "cancel")]
     Cancel,
@@ -25834,7 +28646,22 @@ impl Default for AccountPlanAddonCancelRequestAction {
         AccountPlanAddonCancelRequestAction::Noop
     }
 }
+
 impl AccountPlanAddonCancelRequestAction {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AccountPlanAddonCancelRequestAction::Cancel => "cancel",
+            AccountPlanAddonCancelRequestAction::Noop => "",
+            AccountPlanAddonCancelRequestAction::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [AccountPlanAddonCancelRequestAction] {
+        &[AccountPlanAddonCancelRequestAction::Cancel]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, AccountPlanAddonCancelRequestAction::Noop)
     }
@@ -25987,6 +28814,31 @@ pub struct ReportSignInOutActivitiesResponse {
     pub to: String,
 }
 
+impl IntoIterator for ReportSignInOutActivitiesResponse {
+    type Item = ActivityLogs;
+    type IntoIter = std::vec::IntoIter<ActivityLogs>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.activity_logs.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ReportSignInOutActivitiesResponse {
+    type Item = &'a ActivityLogs;
+    type IntoIter = std::slice::Iter<'a, ActivityLogs>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.activity_logs.iter()
+    }
+}
+
+impl ReportSignInOutActivitiesResponse {
+    /// Iterate over the `activity_logs`.
+    pub fn iter(&self) -> std::slice::Iter<'_, ActivityLogs> {
+        self.activity_logs.iter()
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GetGroupSettingsResponseScheduleMeeting {
     /**
@@ -27309,7 +30161,7 @@ impl UpdateGroupSettingsRequestOneOf {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum UpdateGroupSettingsOption {
     #[serde(rename = "meeting_authentication")]
     MeetingAuthentication,
@@ -27341,7 +30193,28 @@ impl Default for UpdateGroupSettingsOption {
         UpdateGroupSettingsOption::Noop
     }
 }
+
 impl UpdateGroupSettingsOption {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UpdateGroupSettingsOption::MeetingAuthentication => "meeting_authentication",
+            UpdateGroupSettingsOption::MeetingSecurity => "meeting_security",
+            UpdateGroupSettingsOption::RecordingAuthentication => "recording_authentication",
+            UpdateGroupSettingsOption::Noop => "",
+            UpdateGroupSettingsOption::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [UpdateGroupSettingsOption] {
+        &[
+            UpdateGroupSettingsOption::MeetingAuthentication,
+            UpdateGroupSettingsOption::MeetingSecurity,
+            UpdateGroupSettingsOption::RecordingAuthentication,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, UpdateGroupSettingsOption::Noop)
     }
@@ -28474,7 +31347,7 @@ pub struct SwitchUserAccountRequest {
  *   A recording file object with file type of either `CC` or `TB` **does not have** the following properties:<br>
  *   	`id`, `status`, `file_size`, `recording_type`, and `play_url`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GetAccountCloudRecordingResponseMeetingsFilesFileType {
     #[serde(rename = "CC")]
     Cc,
@@ -28518,7 +31391,36 @@ impl Default for GetAccountCloudRecordingResponseMeetingsFilesFileType {
         GetAccountCloudRecordingResponseMeetingsFilesFileType::Noop
     }
 }
+
 impl GetAccountCloudRecordingResponseMeetingsFilesFileType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GetAccountCloudRecordingResponseMeetingsFilesFileType::Cc => "CC",
+            GetAccountCloudRecordingResponseMeetingsFilesFileType::Chat => "CHAT",
+            GetAccountCloudRecordingResponseMeetingsFilesFileType::Csv => "CSV",
+            GetAccountCloudRecordingResponseMeetingsFilesFileType::M4A => "M4A",
+            GetAccountCloudRecordingResponseMeetingsFilesFileType::Mp4 => "MP4",
+            GetAccountCloudRecordingResponseMeetingsFilesFileType::Tb => "TB",
+            GetAccountCloudRecordingResponseMeetingsFilesFileType::Transcript => "TRANSCRIPT",
+            GetAccountCloudRecordingResponseMeetingsFilesFileType::Noop => "",
+            GetAccountCloudRecordingResponseMeetingsFilesFileType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GetAccountCloudRecordingResponseMeetingsFilesFileType] {
+        &[
+            GetAccountCloudRecordingResponseMeetingsFilesFileType::Cc,
+            GetAccountCloudRecordingResponseMeetingsFilesFileType::Chat,
+            GetAccountCloudRecordingResponseMeetingsFilesFileType::Csv,
+            GetAccountCloudRecordingResponseMeetingsFilesFileType::M4A,
+            GetAccountCloudRecordingResponseMeetingsFilesFileType::Mp4,
+            GetAccountCloudRecordingResponseMeetingsFilesFileType::Tb,
+            GetAccountCloudRecordingResponseMeetingsFilesFileType::Transcript,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(
             self,
@@ -28530,7 +31432,7 @@ impl GetAccountCloudRecordingResponseMeetingsFilesFileType {
 /**
  * The status of the recording, either `completed` or `processing`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GetAccountCloudRecordingResponseMeetingsFilesStatus {
     #[serde(rename = "completed")]
     Completed,
@@ -28559,7 +31461,26 @@ impl Default for GetAccountCloudRecordingResponseMeetingsFilesStatus {
         GetAccountCloudRecordingResponseMeetingsFilesStatus::Noop
     }
 }
+
 impl GetAccountCloudRecordingResponseMeetingsFilesStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GetAccountCloudRecordingResponseMeetingsFilesStatus::Completed => "completed",
+            GetAccountCloudRecordingResponseMeetingsFilesStatus::Processing => "processing",
+            GetAccountCloudRecordingResponseMeetingsFilesStatus::Noop => "",
+            GetAccountCloudRecordingResponseMeetingsFilesStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GetAccountCloudRecordingResponseMeetingsFilesStatus] {
+        &[
+            GetAccountCloudRecordingResponseMeetingsFilesStatus::Completed,
+            GetAccountCloudRecordingResponseMeetingsFilesStatus::Processing,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(
             self,
@@ -28586,7 +31507,7 @@ impl GetAccountCloudRecordingResponseMeetingsFilesStatus {
  *   * `thumbnail`
  *   
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GetAccountCloudRecordingResponseMeetingsFilesType {
     #[serde(rename = "active_speaker")]
     ActiveSpeaker,
@@ -28659,7 +31580,58 @@ impl Default for GetAccountCloudRecordingResponseMeetingsFilesType {
         GetAccountCloudRecordingResponseMeetingsFilesType::Noop
     }
 }
+
 impl GetAccountCloudRecordingResponseMeetingsFilesType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GetAccountCloudRecordingResponseMeetingsFilesType::ActiveSpeaker => "active_speaker",
+            GetAccountCloudRecordingResponseMeetingsFilesType::AudioOnly => "audio_only",
+            GetAccountCloudRecordingResponseMeetingsFilesType::AudioTranscript => {
+                "audio_transcript"
+            }
+            GetAccountCloudRecordingResponseMeetingsFilesType::ChatFile => "chat_file",
+            GetAccountCloudRecordingResponseMeetingsFilesType::ClosedCaption => "closed_caption",
+            GetAccountCloudRecordingResponseMeetingsFilesType::GalleryView => "gallery_view",
+            GetAccountCloudRecordingResponseMeetingsFilesType::HostVideo => "host_video",
+            GetAccountCloudRecordingResponseMeetingsFilesType::Poll => "poll",
+            GetAccountCloudRecordingResponseMeetingsFilesType::SharedScreen => "shared_screen",
+            GetAccountCloudRecordingResponseMeetingsFilesType::SharedScreenWithGalleryView => {
+                "shared_screen_with_gallery_view"
+            }
+            GetAccountCloudRecordingResponseMeetingsFilesType::SharedScreenWithSpeakerView => {
+                "shared_screen_with_speaker_view"
+            }
+            GetAccountCloudRecordingResponseMeetingsFilesType::SharedScreenWithSpeakerViewCc => {
+                "shared_screen_with_speaker_view(CC)"
+            }
+            GetAccountCloudRecordingResponseMeetingsFilesType::Thumbnail => "thumbnail",
+            GetAccountCloudRecordingResponseMeetingsFilesType::Timeline => "timeline",
+            GetAccountCloudRecordingResponseMeetingsFilesType::Noop => "",
+            GetAccountCloudRecordingResponseMeetingsFilesType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GetAccountCloudRecordingResponseMeetingsFilesType] {
+        &[
+            GetAccountCloudRecordingResponseMeetingsFilesType::ActiveSpeaker,
+            GetAccountCloudRecordingResponseMeetingsFilesType::AudioOnly,
+            GetAccountCloudRecordingResponseMeetingsFilesType::AudioTranscript,
+            GetAccountCloudRecordingResponseMeetingsFilesType::ChatFile,
+            GetAccountCloudRecordingResponseMeetingsFilesType::ClosedCaption,
+            GetAccountCloudRecordingResponseMeetingsFilesType::GalleryView,
+            GetAccountCloudRecordingResponseMeetingsFilesType::HostVideo,
+            GetAccountCloudRecordingResponseMeetingsFilesType::Poll,
+            GetAccountCloudRecordingResponseMeetingsFilesType::SharedScreen,
+            GetAccountCloudRecordingResponseMeetingsFilesType::SharedScreenWithGalleryView,
+            GetAccountCloudRecordingResponseMeetingsFilesType::SharedScreenWithSpeakerView,
+            GetAccountCloudRecordingResponseMeetingsFilesType::SharedScreenWithSpeakerViewCc,
+            GetAccountCloudRecordingResponseMeetingsFilesType::Thumbnail,
+            GetAccountCloudRecordingResponseMeetingsFilesType::Timeline,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(
             self,
@@ -28918,6 +31890,42 @@ pub struct GetAccountCloudRecordingResponse {
     pub total_records: i64,
 }
 
+impl IntoIterator for GetAccountCloudRecordingResponse {
+    type Item = GetAccountCloudRecordingResponseMeetings;
+    type IntoIter = std::vec::IntoIter<GetAccountCloudRecordingResponseMeetings>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.meetings.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a GetAccountCloudRecordingResponse {
+    type Item = &'a GetAccountCloudRecordingResponseMeetings;
+    type IntoIter = std::slice::Iter<'a, GetAccountCloudRecordingResponseMeetings>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.meetings.iter()
+    }
+}
+
+impl GetAccountCloudRecordingResponse {
+    /// Iterate over the `meetings`.
+    pub fn iter(&self) -> std::slice::Iter<'_, GetAccountCloudRecordingResponseMeetings> {
+        self.meetings.iter()
+    }
+
+    /// The number of `meetings` in the whole list, across all of its pages.
+    pub fn total(&self) -> i64 {
+        self.total_records
+    }
+
+    /// The number of `meetings` left in the list once `seen` of them have been
+    /// read, say from a page or a stream, to report progress.
+    pub fn remaining(&self, seen: usize) -> i64 {
+        (self.total_records - seen as i64).max(0)
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TrackingSources {
     /**
@@ -29039,7 +32047,7 @@ pub struct UpdateZrAccProfileRequest {
  *   `meeting`: Meeting settings of the Zoom Rooms Account. <br>
  *   `signage`: View digital signage settings of the Zoom Rooms Account.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum SettingType {
     #[serde(rename = "alert")]
     Alert,
@@ -29066,11 +32074,27 @@ impl Default for SettingType {
     }
 }
 
+impl SettingType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SettingType::Alert => "alert",
+            SettingType::Meeting => "meeting",
+            SettingType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [SettingType] {
+        &[SettingType::Alert, SettingType::Meeting]
+    }
+}
+
 /**
  * The type of location. The value can be one of the following:
  *   `country`, `states`, `city`, `campus`, `building`, `floor`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ListZrLocationsResponseType {
     #[serde(rename = "building")]
     Building,
@@ -29111,7 +32135,34 @@ impl Default for ListZrLocationsResponseType {
         ListZrLocationsResponseType::Noop
     }
 }
+
 impl ListZrLocationsResponseType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ListZrLocationsResponseType::Building => "building",
+            ListZrLocationsResponseType::Campus => "campus",
+            ListZrLocationsResponseType::City => "city",
+            ListZrLocationsResponseType::Country => "country",
+            ListZrLocationsResponseType::Floor => "floor",
+            ListZrLocationsResponseType::States => "states",
+            ListZrLocationsResponseType::Noop => "",
+            ListZrLocationsResponseType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ListZrLocationsResponseType] {
+        &[
+            ListZrLocationsResponseType::Building,
+            ListZrLocationsResponseType::Campus,
+            ListZrLocationsResponseType::City,
+            ListZrLocationsResponseType::Country,
+            ListZrLocationsResponseType::Floor,
+            ListZrLocationsResponseType::States,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ListZrLocationsResponseType::Noop)
     }
@@ -29182,6 +32233,31 @@ pub struct ListZrLocationsResponseData {
     pub page_size: i64,
 }
 
+impl IntoIterator for ListZrLocationsResponseData {
+    type Item = AddAzrLocationResponse;
+    type IntoIter = std::vec::IntoIter<AddAzrLocationResponse>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.locations.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ListZrLocationsResponseData {
+    type Item = &'a AddAzrLocationResponse;
+    type IntoIter = std::slice::Iter<'a, AddAzrLocationResponse>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.locations.iter()
+    }
+}
+
+impl ListZrLocationsResponseData {
+    /// Iterate over the `locations`.
+    pub fn iter(&self) -> std::slice::Iter<'_, AddAzrLocationResponse> {
+        self.locations.iter()
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddAzrLocationRequest {
     /**
@@ -29438,7 +32514,7 @@ pub struct UpdateRoomProfileRequest {
 /**
  * Type of the device. The value of this field can be one of the following:<br>`Zoom Rooms Computer`,<br> `Controller`, `Scheduling Display`, `Zoom Rooms Control System`, `Companion Whiteboard`
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum DeviceType {
     #[serde(rename = "Companion Whiteboard")]
     CompanionWhiteboard,
@@ -29476,7 +32552,32 @@ impl Default for DeviceType {
         DeviceType::Noop
     }
 }
+
 impl DeviceType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DeviceType::CompanionWhiteboard => "Companion Whiteboard",
+            DeviceType::Controller => "Controller",
+            DeviceType::SchedulingDisplay => "Scheduling Display",
+            DeviceType::ZoomRoomsComputer => "Zoom Rooms Computer",
+            DeviceType::ZoomRoomsControlSystem => "Zoom Rooms Control System",
+            DeviceType::Noop => "",
+            DeviceType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [DeviceType] {
+        &[
+            DeviceType::CompanionWhiteboard,
+            DeviceType::Controller,
+            DeviceType::SchedulingDisplay,
+            DeviceType::ZoomRoomsComputer,
+            DeviceType::ZoomRoomsControlSystem,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, DeviceType::Noop)
     }
@@ -29485,7 +32586,7 @@ impl DeviceType {
 /**
  * Status of the device. The value can be either `Online` or `Offline`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ListZrDevicesResponseStatus {
     #[serde(rename = "Offline")]
     Offline,
@@ -29514,7 +32615,26 @@ impl Default for ListZrDevicesResponseStatus {
         ListZrDevicesResponseStatus::Noop
     }
 }
+
 impl ListZrDevicesResponseStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ListZrDevicesResponseStatus::Offline => "Offline",
+            ListZrDevicesResponseStatus::Online => "Online",
+            ListZrDevicesResponseStatus::Noop => "",
+            ListZrDevicesResponseStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ListZrDevicesResponseStatus] {
+        &[
+            ListZrDevicesResponseStatus::Offline,
+            ListZrDevicesResponseStatus::Online,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ListZrDevicesResponseStatus::Noop)
     }
@@ -29789,7 +32909,7 @@ pub struct ListMeetingSatisfactionResponse {
 /**
  * State of the device. The value should be either `assigned` to list devices that have been assigned to user(s) or `unassigned` to list devices that have not yet been assigned to any user in the Zoom account.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ListPhoneDevicesType {
     #[serde(rename = "assigned")]
     Assigned,
@@ -29818,7 +32938,26 @@ impl Default for ListPhoneDevicesType {
         ListPhoneDevicesType::Noop
     }
 }
+
 impl ListPhoneDevicesType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ListPhoneDevicesType::Assigned => "assigned",
+            ListPhoneDevicesType::Unassigned => "unassigned",
+            ListPhoneDevicesType::Noop => "",
+            ListPhoneDevicesType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ListPhoneDevicesType] {
+        &[
+            ListPhoneDevicesType::Assigned,
+            ListPhoneDevicesType::Unassigned,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ListPhoneDevicesType::Noop)
     }
@@ -29858,7 +32997,7 @@ pub struct AddAutoReceptionistResponse {
 /**
  * Status of the device. The value is either `online` or `offline`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ListPhoneDevicesResponseStatus {
     #[serde(rename = "offline")]
     Offline,
@@ -29887,7 +33026,26 @@ impl Default for ListPhoneDevicesResponseStatus {
         ListPhoneDevicesResponseStatus::Noop
     }
 }
+
 impl ListPhoneDevicesResponseStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ListPhoneDevicesResponseStatus::Offline => "offline",
+            ListPhoneDevicesResponseStatus::Online => "online",
+            ListPhoneDevicesResponseStatus::Noop => "",
+            ListPhoneDevicesResponseStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ListPhoneDevicesResponseStatus] {
+        &[
+            ListPhoneDevicesResponseStatus::Offline,
+            ListPhoneDevicesResponseStatus::Online,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ListPhoneDevicesResponseStatus::Noop)
     }
@@ -29979,6 +33137,31 @@ pub struct ListPhoneDevicesResponseData {
     pub total_records: String,
 }
 
+impl IntoIterator for ListPhoneDevicesResponseData {
+    type Item = ListPhoneDevicesResponse;
+    type IntoIter = std::vec::IntoIter<ListPhoneDevicesResponse>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.devices.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ListPhoneDevicesResponseData {
+    type Item = &'a ListPhoneDevicesResponse;
+    type IntoIter = std::slice::Iter<'a, ListPhoneDevicesResponse>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.devices.iter()
+    }
+}
+
+impl ListPhoneDevicesResponseData {
+    /// Iterate over the `devices`.
+    pub fn iter(&self) -> std::slice::Iter<'_, ListPhoneDevicesResponse> {
+        self.devices.iter()
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddPhoneDeviceRequest {
     /**
@@ -30070,7 +33253,7 @@ pub struct GetDeviceResponseAssignee {
  *   
  *   
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GetDeviceResponseProvisionType {
     #[serde(rename = "assisted")]
     Assisted,
@@ -30102,7 +33285,28 @@ impl Default for GetDeviceResponseProvisionType {
         GetDeviceResponseProvisionType::Noop
     }
 }
+
 impl GetDeviceResponseProvisionType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GetDeviceResponseProvisionType::Assisted => "assisted",
+            GetDeviceResponseProvisionType::Manual => "manual",
+            GetDeviceResponseProvisionType::Ztp => "ztp",
+            GetDeviceResponseProvisionType::Noop => "",
+            GetDeviceResponseProvisionType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GetDeviceResponseProvisionType] {
+        &[
+            GetDeviceResponseProvisionType::Assisted,
+            GetDeviceResponseProvisionType::Manual,
+            GetDeviceResponseProvisionType::Ztp,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, GetDeviceResponseProvisionType::Noop)
     }
@@ -30448,6 +33652,31 @@ pub struct ListExternalContactsResponse {
     pub page_size: i64,
 }
 
+impl IntoIterator for ListExternalContactsResponse {
+    type Item = ExternalContacts;
+    type IntoIter = std::vec::IntoIter<ExternalContacts>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.external_contacts.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ListExternalContactsResponse {
+    type Item = &'a ExternalContacts;
+    type IntoIter = std::slice::Iter<'a, ExternalContacts>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.external_contacts.iter()
+    }
+}
+
+impl ListExternalContactsResponse {
+    /// Iterate over the `external_contacts`.
+    pub fn iter(&self) -> std::slice::Iter<'_, ExternalContacts> {
+        self.external_contacts.iter()
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddExternalContactRequest {
     /**
@@ -30725,6 +33954,42 @@ pub struct ListCallQueuesResponse {
     pub total_records: i64,
 }
 
+impl IntoIterator for ListCallQueuesResponse {
+    type Item = CallQueues;
+    type IntoIter = std::vec::IntoIter<CallQueues>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.call_queues.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ListCallQueuesResponse {
+    type Item = &'a CallQueues;
+    type IntoIter = std::slice::Iter<'a, CallQueues>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.call_queues.iter()
+    }
+}
+
+impl ListCallQueuesResponse {
+    /// Iterate over the `call_queues`.
+    pub fn iter(&self) -> std::slice::Iter<'_, CallQueues> {
+        self.call_queues.iter()
+    }
+
+    /// The number of `call_queues` in the whole list, across all of its pages.
+    pub fn total(&self) -> i64 {
+        self.total_records
+    }
+
+    /// The number of `call_queues` left in the list once `seen` of them have been
+    /// read, say from a page or a stream, to report progress.
+    pub fn remaining(&self, seen: usize) -> i64 {
+        (self.total_records - seen as i64).max(0)
+    }
+}
+
 /// A list of one or more phone users to be included in the call queue. Provide either users or common area phone(s). Provide at least one user in the users object.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CreateCallQueueRequestMembers {
@@ -30838,7 +34103,7 @@ pub struct CreateCallQueueResponse {
  *   `manager`: A call queue manager has the privilege to change call queue settings, policy settings and manage recordings and voicemail inbox. There can only be one manager for each call queue.<br><br>
  *   `user`: Regular user without the privileges of a manager.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Level {
     #[serde(rename = "manager")]
     Manager,
@@ -30867,7 +34132,23 @@ impl Default for Level {
         Level::Noop
     }
 }
+
 impl Level {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Level::Manager => "manager",
+            Level::User => "user",
+            Level::Noop => "",
+            Level::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Level] {
+        &[Level::Manager, Level::User]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Level::Noop)
     }
@@ -31426,6 +34707,42 @@ pub struct ListPhoneUsersResponseData {
     pub users: Vec<ListPhoneUsersResponse>,
 }
 
+impl IntoIterator for ListPhoneUsersResponseData {
+    type Item = ListPhoneUsersResponse;
+    type IntoIter = std::vec::IntoIter<ListPhoneUsersResponse>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.users.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ListPhoneUsersResponseData {
+    type Item = &'a ListPhoneUsersResponse;
+    type IntoIter = std::slice::Iter<'a, ListPhoneUsersResponse>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.users.iter()
+    }
+}
+
+impl ListPhoneUsersResponseData {
+    /// Iterate over the `users`.
+    pub fn iter(&self) -> std::slice::Iter<'_, ListPhoneUsersResponse> {
+        self.users.iter()
+    }
+
+    /// The number of `users` in the whole list, across all of its pages.
+    pub fn total(&self) -> i64 {
+        self.total_records
+    }
+
+    /// The number of `users` left in the list once `seen` of them have been
+    /// read, say from a page or a stream, to report progress.
+    pub fn remaining(&self, seen: usize) -> i64 {
+        (self.total_records - seen as i64).max(0)
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateAutoReceptionistRequest {
     /**
@@ -31551,6 +34868,42 @@ pub struct ListCommonAreaPhonesResponse {
     pub total_records: i64,
 }
 
+impl IntoIterator for ListCommonAreaPhonesResponse {
+    type Item = CommonAreaPhones;
+    type IntoIter = std::vec::IntoIter<CommonAreaPhones>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.common_area_phones.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ListCommonAreaPhonesResponse {
+    type Item = &'a CommonAreaPhones;
+    type IntoIter = std::slice::Iter<'a, CommonAreaPhones>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.common_area_phones.iter()
+    }
+}
+
+impl ListCommonAreaPhonesResponse {
+    /// Iterate over the `common_area_phones`.
+    pub fn iter(&self) -> std::slice::Iter<'_, CommonAreaPhones> {
+        self.common_area_phones.iter()
+    }
+
+    /// The number of `common_area_phones` in the whole list, across all of its pages.
+    pub fn total(&self) -> i64 {
+        self.total_records
+    }
+
+    /// The number of `common_area_phones` left in the list once `seen` of them have been
+    /// read, say from a page or a stream, to report progress.
+    pub fn remaining(&self, seen: usize) -> i64 {
+        (self.total_records - seen as i64).max(0)
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddCommonAreaPhoneRequest {
     /**
@@ -31805,7 +35158,7 @@ pub struct InternationalCall {
 /**
  * Allow Hot Desking feature to current device.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
     #[serde(rename = "off")]
     Off,
@@ -31837,7 +35190,28 @@ impl Default for GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
         GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Noop
     }
 }
+
 impl GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Off => "off",
+            GetCommonAreaPhoneResponsePolicyHotDeskingStatus::On => "on",
+            GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Unsupported => "unsupported",
+            GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Noop => "",
+            GetCommonAreaPhoneResponsePolicyHotDeskingStatus::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [GetCommonAreaPhoneResponsePolicyHotDeskingStatus] {
+        &[
+            GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Off,
+            GetCommonAreaPhoneResponsePolicyHotDeskingStatus::On,
+            GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Unsupported,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Noop)
     }
@@ -32112,7 +35486,7 @@ pub struct AssignCallingPlansCommonAreaPhoneResponseData {
  *   `phoneNumber`: Indicates that only a specific phone number that is shown in the `phone_number` field is blocked.<br><br>
  *   `prefix`: Indicates that all numbers starting with prefix that is shown in the `phone_number` field are blocked.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum MatchType {
     #[serde(rename = "phoneNumber")]
     PhoneNumber,
@@ -32141,7 +35515,23 @@ impl Default for MatchType {
         MatchType::Noop
     }
 }
+
 impl MatchType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MatchType::PhoneNumber => "phoneNumber",
+            MatchType::Prefix => "prefix",
+            MatchType::Noop => "",
+            MatchType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [MatchType] {
+        &[MatchType::PhoneNumber, MatchType::Prefix]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, MatchType::Noop)
     }
@@ -32232,6 +35622,42 @@ pub struct ListBlockedResponse {
     pub total_records: i64,
 }
 
+impl IntoIterator for ListBlockedResponse {
+    type Item = BlockedList;
+    type IntoIter = std::vec::IntoIter<BlockedList>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.blocked_list.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ListBlockedResponse {
+    type Item = &'a BlockedList;
+    type IntoIter = std::slice::Iter<'a, BlockedList>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.blocked_list.iter()
+    }
+}
+
+impl ListBlockedResponse {
+    /// Iterate over the `blocked_list`.
+    pub fn iter(&self) -> std::slice::Iter<'_, BlockedList> {
+        self.blocked_list.iter()
+    }
+
+    /// The number of `blocked_list` in the whole list, across all of its pages.
+    pub fn total(&self) -> i64 {
+        self.total_records
+    }
+
+    /// The number of `blocked_list` left in the list once `seen` of them have been
+    /// read, say from a page or a stream, to report progress.
+    pub fn remaining(&self, seen: usize) -> i64 {
+        (self.total_records - seen as i64).max(0)
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UpdateBlockedListRequest {
     /**
@@ -32546,6 +35972,42 @@ pub struct ListSharedLineGroupsResponse {
     pub total_records: i64,
 }
 
+impl IntoIterator for ListSharedLineGroupsResponse {
+    type Item = SharedLineGroups;
+    type IntoIter = std::vec::IntoIter<SharedLineGroups>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.shared_line_groups.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ListSharedLineGroupsResponse {
+    type Item = &'a SharedLineGroups;
+    type IntoIter = std::slice::Iter<'a, SharedLineGroups>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.shared_line_groups.iter()
+    }
+}
+
+impl ListSharedLineGroupsResponse {
+    /// Iterate over the `shared_line_groups`.
+    pub fn iter(&self) -> std::slice::Iter<'_, SharedLineGroups> {
+        self.shared_line_groups.iter()
+    }
+
+    /// The number of `shared_line_groups` in the whole list, across all of its pages.
+    pub fn total(&self) -> i64 {
+        self.total_records
+    }
+
+    /// The number of `shared_line_groups` left in the list once `seen` of them have been
+    /// read, say from a page or a stream, to report progress.
+    pub fn remaining(&self, seen: usize) -> i64 {
+        (self.total_records - seen as i64).max(0)
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CreateSharedLineGroupRequest {
     /**
@@ -32978,6 +36440,31 @@ pub struct GetCallQueueRecordingsResponseData {
     pub total_records: String,
 }
 
+impl IntoIterator for GetCallQueueRecordingsResponseData {
+    type Item = GetCallQueueRecordingsResponse;
+    type IntoIter = std::vec::IntoIter<GetCallQueueRecordingsResponse>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.recordings.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a GetCallQueueRecordingsResponseData {
+    type Item = &'a GetCallQueueRecordingsResponse;
+    type IntoIter = std::slice::Iter<'a, GetCallQueueRecordingsResponse>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.recordings.iter()
+    }
+}
+
+impl GetCallQueueRecordingsResponseData {
+    /// Iterate over the `recordings`.
+    pub fn iter(&self) -> std::slice::Iter<'_, GetCallQueueRecordingsResponse> {
+        self.recordings.iter()
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Params {
     /**
@@ -33315,7 +36802,7 @@ pub struct AddCalloutCountriesResponse {
 /**
  * Specify the language in which this phone number should be displayed. Currently, the only supported languages are `en-GB`, `en-US`, `de-DE`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Languages {
     #[serde(rename = "de-DE")]
     De,
@@ -33347,7 +36834,24 @@ impl Default for Languages {
         Languages::Noop
     }
 }
+
 impl Languages {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Languages::De => "de-DE",
+            Languages::EnGb => "en-GB",
+            Languages::EnUs => "en-US",
+            Languages::Noop => "",
+            Languages::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Languages] {
+        &[Languages::De, Languages::EnGb, Languages::EnUs]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Languages::Noop)
     }
@@ -33469,6 +36973,42 @@ pub struct ListInternalNumbersResponse {
     pub total_records: i64,
 }
 
+impl IntoIterator for ListInternalNumbersResponse {
+    type Item = InternalNumbers;
+    type IntoIter = std::vec::IntoIter<InternalNumbers>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.internal_numbers.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ListInternalNumbersResponse {
+    type Item = &'a InternalNumbers;
+    type IntoIter = std::slice::Iter<'a, InternalNumbers>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.internal_numbers.iter()
+    }
+}
+
+impl ListInternalNumbersResponse {
+    /// Iterate over the `internal_numbers`.
+    pub fn iter(&self) -> std::slice::Iter<'_, InternalNumbers> {
+        self.internal_numbers.iter()
+    }
+
+    /// The number of `internal_numbers` in the whole list, across all of its pages.
+    pub fn total(&self) -> i64 {
+        self.total_records
+    }
+
+    /// The number of `internal_numbers` left in the list once `seen` of them have been
+    /// read, say from a page or a stream, to report progress.
+    pub fn remaining(&self, seen: usize) -> i64 {
+        (self.total_records - seen as i64).max(0)
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddInternalNumbersRequest {
     #[serde(
@@ -33584,7 +37124,7 @@ pub struct AddInternalNumbersResponseData {
  *   `downgrade`: Downgrade the Zoom Rooms App Version.<br>
  *   `cancel`: Cancel an ongoing upgrade or downgrade process.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ChangeZoomRoomsAppVersionRequestAction {
     #[serde(rename = "cancel")]
     Cancel,
@@ -33616,7 +37156,28 @@ impl Default for ChangeZoomRoomsAppVersionRequestAction {
         ChangeZoomRoomsAppVersionRequestAction::Noop
     }
 }
+
 impl ChangeZoomRoomsAppVersionRequestAction {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChangeZoomRoomsAppVersionRequestAction::Cancel => "cancel",
+            ChangeZoomRoomsAppVersionRequestAction::Downgrade => "downgrade",
+            ChangeZoomRoomsAppVersionRequestAction::Upgrade => "upgrade",
+            ChangeZoomRoomsAppVersionRequestAction::Noop => "",
+            ChangeZoomRoomsAppVersionRequestAction::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ChangeZoomRoomsAppVersionRequestAction] {
+        &[
+            ChangeZoomRoomsAppVersionRequestAction::Cancel,
+            ChangeZoomRoomsAppVersionRequestAction::Downgrade,
+            ChangeZoomRoomsAppVersionRequestAction::Upgrade,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, ChangeZoomRoomsAppVersionRequestAction::Noop)
     }
@@ -33663,6 +37224,31 @@ pub struct ListDigitalSignageContentResponse {
     pub page_size: i64,
 }
 
+impl IntoIterator for ListDigitalSignageContentResponse {
+    type Item = Site;
+    type IntoIter = std::vec::IntoIter<Site>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.contents.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ListDigitalSignageContentResponse {
+    type Item = &'a Site;
+    type IntoIter = std::slice::Iter<'a, Site>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.contents.iter()
+    }
+}
+
+impl ListDigitalSignageContentResponse {
+    /// Iterate over the `contents`.
+    pub fn iter(&self) -> std::slice::Iter<'_, Site> {
+        self.contents.iter()
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ListWebinarTemplatesResponse {
     #[serde(
@@ -33702,7 +37288,7 @@ pub struct ListWebinarTemplatesResponse {
  *   * `Available` to `Away`
  *   * `Away` to `Available`
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum UpdatePresenceStatusRequest {
     #[serde(rename = "Available")]
     Available,
@@ -33746,7 +37332,36 @@ impl Default for UpdatePresenceStatusRequest {
         UpdatePresenceStatusRequest::Noop
     }
 }
+
 impl UpdatePresenceStatusRequest {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UpdatePresenceStatusRequest::Available => "Available",
+            UpdatePresenceStatusRequest::Away => "Away",
+            UpdatePresenceStatusRequest::DoNoDisturb => "Do_No_Disturb",
+            UpdatePresenceStatusRequest::InAZoomMeeting => "In_A_Zoom_Meeting",
+            UpdatePresenceStatusRequest::InCalendarEvent => "In_Calendar_Event",
+            UpdatePresenceStatusRequest::OnACall => "On_A_Call",
+            UpdatePresenceStatusRequest::Presenting => "Presenting",
+            UpdatePresenceStatusRequest::Noop => "",
+            UpdatePresenceStatusRequest::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [UpdatePresenceStatusRequest] {
+        &[
+            UpdatePresenceStatusRequest::Available,
+            UpdatePresenceStatusRequest::Away,
+            UpdatePresenceStatusRequest::DoNoDisturb,
+            UpdatePresenceStatusRequest::InAZoomMeeting,
+            UpdatePresenceStatusRequest::InCalendarEvent,
+            UpdatePresenceStatusRequest::OnACall,
+            UpdatePresenceStatusRequest::Presenting,
+        ]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, UpdatePresenceStatusRequest::Noop)
     }
@@ -34094,6 +37709,42 @@ pub struct ListCallLogsMetricsResponseData {
     pub total_records: i64,
 }
 
+impl IntoIterator for ListCallLogsMetricsResponseData {
+    type Item = ListCallLogsMetricsResponse;
+    type IntoIter = std::vec::IntoIter<ListCallLogsMetricsResponse>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.call_logs.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ListCallLogsMetricsResponseData {
+    type Item = &'a ListCallLogsMetricsResponse;
+    type IntoIter = std::slice::Iter<'a, ListCallLogsMetricsResponse>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.call_logs.iter()
+    }
+}
+
+impl ListCallLogsMetricsResponseData {
+    /// Iterate over the `call_logs`.
+    pub fn iter(&self) -> std::slice::Iter<'_, ListCallLogsMetricsResponse> {
+        self.call_logs.iter()
+    }
+
+    /// The number of `call_logs` in the whole list, across all of its pages.
+    pub fn total(&self) -> i64 {
+        self.total_records
+    }
+
+    /// The number of `call_logs` left in the list once `seen` of them have been
+    /// read, say from a page or a stream, to report progress.
+    pub fn remaining(&self, seen: usize) -> i64 {
+        (self.total_records - seen as i64).max(0)
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Qos {
     /**
@@ -34635,7 +38286,7 @@ pub struct GetAccountBillingInvoiceResponse {
  *   * `GOOD`: Thumbs up.
  *   * `NOT GOOD`: Thumbs down.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Quality {
     #[serde(rename = "GOOD")]
     Good,
@@ -34664,7 +38315,23 @@ impl Default for Quality {
         Quality::Noop
     }
 }
+
 impl Quality {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Quality::Good => "GOOD",
+            Quality::NotGood => "NOT GOOD",
+            Quality::Noop => "",
+            Quality::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Quality] {
+        &[Quality::Good, Quality::NotGood]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Quality::Noop)
     }
@@ -34737,10 +38404,35 @@ pub struct ParticipantFeedbackResponse {
     pub participants: Vec<ParticipantFeedbackResponseParticipants>,
 }
 
+impl IntoIterator for ParticipantFeedbackResponse {
+    type Item = ParticipantFeedbackResponseParticipants;
+    type IntoIter = std::vec::IntoIter<ParticipantFeedbackResponseParticipants>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.participants.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ParticipantFeedbackResponse {
+    type Item = &'a ParticipantFeedbackResponseParticipants;
+    type IntoIter = std::slice::Iter<'a, ParticipantFeedbackResponseParticipants>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.participants.iter()
+    }
+}
+
+impl ParticipantFeedbackResponse {
+    /// Iterate over the `participants`.
+    pub fn iter(&self) -> std::slice::Iter<'_, ParticipantFeedbackResponseParticipants> {
+        self.participants.iter()
+    }
+}
+
 /**
  * Result of the call: `call_connected` | `recorded` | `no_answer`.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum Result {
     #[serde(rename = "call_connected")]
     CallConnected,
@@ -34772,7 +38464,24 @@ impl Default for Result {
         Result::Noop
     }
 }
+
 impl Result {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Result::CallConnected => "call_connected",
+            Result::NoAnswer => "no_answer",
+            Result::Recorded => "recorded",
+            Result::Noop => "",
+            Result::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [Result] {
+        &[Result::CallConnected, Result::NoAnswer, Result::Recorded]
+    }
+
     pub fn is_noop(&self) -> bool {
         matches!(self, Result::Noop)
     }
@@ -35146,7 +38855,7 @@ pub struct ListMeetingTemplatesResponseData {
 /**
  * The query date type for the `from` and `to` parameters.
  */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy, JsonSchema)]
 pub enum ListArchivedFilesQueryDateType {
     #[serde(rename = "archive_complete_time")]
     ArchiveCompleteTime,
@@ -35173,6 +38882,25 @@ impl Default for ListArchivedFilesQueryDateType {
     }
 }
 
+impl ListArchivedFilesQueryDateType {
+    /// The name of the variant in the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ListArchivedFilesQueryDateType::ArchiveCompleteTime => "archive_complete_time",
+            ListArchivedFilesQueryDateType::MeetingStartTime => "meeting_start_time",
+            ListArchivedFilesQueryDateType::FallthroughString => "*",
+        }
+    }
+
+    /// All the variants the API lists, in order, without `Noop` or `FallthroughString`.
+    pub fn all_variants() -> &'static [ListArchivedFilesQueryDateType] {
+        &[
+            ListArchivedFilesQueryDateType::ArchiveCompleteTime,
+            ListArchivedFilesQueryDateType::MeetingStartTime,
+        ]
+    }
+}
+
 /// Information about the archived files.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ListArchivedFilesResponseMeetingsArchive {
@@ -35379,3 +39107,379 @@ pub struct ListArchivedFilesResponse {
     )]
     pub to: String,
 }
+
+impl IntoIterator for ListArchivedFilesResponse {
+    type Item = ListArchivedFilesResponseMeetings;
+    type IntoIter = std::vec::IntoIter<ListArchivedFilesResponseMeetings>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.meetings.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ListArchivedFilesResponse {
+    type Item = &'a ListArchivedFilesResponseMeetings;
+    type IntoIter = std::slice::Iter<'a, ListArchivedFilesResponseMeetings>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.meetings.iter()
+    }
+}
+
+impl ListArchivedFilesResponse {
+    /// Iterate over the `meetings`.
+    pub fn iter(&self) -> std::slice::Iter<'_, ListArchivedFilesResponseMeetings> {
+        self.meetings.iter()
+    }
+}
+
+/// The types taken and returned by the functions in [`crate::accounts`].
+pub mod accounts {
+    pub use super::{
+        AccountCreateRequest, AccountCreateResponse, AccountResponse, AccountSettings,
+        AccountSettingsResponseOneOf, AccountSettingsUpdateOption,
+        AccountSettingsUpdateRequestOneOf, AccountTrustedDomainResponse, Domains, DomainsList,
+        Files, MeetingSecuritySettings, Members, OptionData, Options, Security, UploadVbRequest,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::archiving`].
+pub mod archiving {
+    pub use super::{
+        CloudArchivedFiles, ListArchivedFilesQueryDateType, ListArchivedFilesResponse,
+        ListArchivedFilesResponseMeetings,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::billing`].
+pub mod billing {
+    pub use super::{
+        AccountBillingInvoicesResponseData, AccountPlanAddonCancelRequest,
+        AccountPlanAddonCreateRequestOneOf, AccountPlanBaseDeleteRequest,
+        AccountPlanCreateRequestAllOf, AccountPlans, AccountPlansResponse, BillingContact, Contact,
+        GetAccountBillingInvoiceResponse, GetPlanUsageResponse, PlanBase,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::chat_channels`].
+pub mod chat_channels {
+    pub use super::{
+        Attendees, Channel, Channels, CreateChannelRequest, CreateChannelResponse,
+        GetChannelsResponse, JoinChannelResponse,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::chat_channels_account_level`].
+pub mod chat_channels_account_level {
+    pub use super::{
+        Attendees, Channel, InviteChannelMembersRequest, InviteChannelMembersResponse,
+        ListChannelMembersResponse, ListChannelMembersResponseData,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::chat_messages`].
+pub mod chat_messages {
+    pub use super::{
+        EditMessageRequest, GetChatMessageResponse, GetChatMessagesResponse, Groups,
+        MarkMessageRequest, Messages, ReactMessageRequest, SendaChatMessageRequest,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::chatbot_messages`].
+pub mod chatbot_messages {
+    pub use super::{
+        DeleteChatbotMessageRequest, DeleteChatbotMessageResponse, EditChatbotMessageRequest,
+        EditChatbotMessageResponse, SendchatbotRequest,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::cloud_recording`].
+pub mod cloud_recording {
+    pub use super::{
+        Domains, GetAccountCloudRecordingResponse, GetAccountCloudRecordingResponseMeetings,
+        MeetingRecordingRegistrantCreateResponse, MeetingRegistrantsStatus, RecordingDeleteAction,
+        RecordingGetResponseAllOf, RecordingRegistrantQuestionsData, RecordingRegistrantStatus,
+        RecordingSettings, RecordingStatusUpdateBodyRequest,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::common_area_phones`].
+pub mod common_area_phones {
+    pub use super::{
+        AddCommonAreaPhoneRequest, AddCommonAreaPhoneResponse,
+        AssignCallingPlansCommonAreaPhoneRequestData,
+        AssignCallingPlansCommonAreaPhoneResponseData, AssignPhoneNumbersCommonAreaRequest,
+        AssignPhoneNumbersCommonAreaResponseData, CommonAreaPhones, GetCommonAreaPhoneResponse,
+        ListCommonAreaPhonesResponse, UpdateCommonAreaPhoneRequest,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::contacts`].
+pub mod contacts {
+    pub use super::{
+        Contacts, GetUserContactResponse, GetUserContactsResponse, GetUserContactsResponseData,
+        SearchCompanyContactsResponse,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::dashboards`].
+pub mod dashboards {
+    pub use super::{
+        DashboardChatResponseAllOf, DashboardClientFeedbackDetailResponseAllOf,
+        DashboardClientFeedbackResponse, DashboardImResponseAllOf,
+        DashboardIssueDetailZoomRoomResponseAllOf, DashboardIssueZoomRoomResponseAllOf,
+        DashboardMeetingParticipantShareResponseAllOf, DashboardMeetingParticipantsIncludeFields,
+        DashboardMeetingParticipantsResponseAllOf, DashboardMeetingsResponseAllOf,
+        DashboardMeetingsType, DashboardWebinarParticipantsResponseAllOf,
+        DashboardWebinarsResponseAllOf, DashboardWebinarsType, Domains, GetCallQoSResponse,
+        IncludeFields, ListCallLogsMetricsResponse, ListCallLogsMetricsResponseData,
+        ListMeetingSatisfactionResponse, MeetingMetric, ParticipantFeedbackResponse,
+        ParticipantFeedbackResponseParticipants, ParticipantQos, Webinars,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::deprecated_api_endpoints`].
+pub mod deprecated_api_endpoints {
+    pub use super::ListPastMeetingFilesResponse;
+}
+
+/// The types taken and returned by the functions in [`crate::devices`].
+pub mod devices {
+    pub use super::{Device, Domains};
+}
+
+/// The types taken and returned by the functions in [`crate::groups`].
+pub mod groups {
+    pub use super::{
+        AddRoleMembersRequest, Domains, Files, GetGroupLockSettingsResponse,
+        GetGroupLockSettingsResponseOneOf, GetGroupSettingsResponse, GetGroupSettingsResponseOneOf,
+        GroupCreateRequest, GroupList, GroupLockedSettingsRequestOneOf, GroupMembersResponseData,
+        GroupResponse, MeetingSecuritySettings, OptionData, UpdateGroupMemberRequest,
+        UpdateGroupSettingsOption, UpdateGroupSettingsRequestOneOf, UploadVbRequest,
+        UserCreateResponse,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::im_chat`].
+pub mod im_chat {
+    pub use super::{
+        Groups, ImChatMessagesResponseAllOf, ImChatSessionsResponseAllOf, ListimmessagesResponse,
+        ListimmessagesResponseMessages, SendimmessagesRequest,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::im_groups`].
+pub mod im_groups {
+    pub use super::{AddRoleMembersRequest, Domains, ImGroupCreateRequest, ImGroupResponseAllOf};
+}
+
+/// The types taken and returned by the functions in [`crate::master_phone`].
+pub mod master_phone {
+    pub use super::{
+        PostPhoneSipTrunkRequest, UpdatePhoneSettingsRequest, UpdatePhoneSipTrunkRequest,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::meetings`].
+pub mod meetings {
+    pub use super::{
+        AddBatchRegistrantsRequest, AddBatchRegistrantsResponse, CreateBatchPollsRequest,
+        CreateBatchPollsResponse, Domains, GetLiveStreamDetailsResponse,
+        InMeetingRecordingControlRequest, InviteLink, InviteLinks,
+        ListMeetingTemplatesResponseData, MeetingCreate, MeetingCreateResponseAllOf,
+        MeetingInvitation, MeetingLiveStream, MeetingLiveStreamStatus, MeetingPollGetResponseAllOf,
+        MeetingRegistrantCreateResponse, MeetingRegistrantQuestionsData, MeetingRegistrantsStatus,
+        MeetingResponseAllOf, MeetingStatusRequest, MeetingUpdateRequestAllOf, MeetingsType,
+        PastMeetingDetailsResponse, PastMeetingParticipantsResponseAllOf, Poll, RegistrantStatus,
+        ReportMeetingPollsResponse,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::pac`].
+pub mod pac {
+    pub use super::UserPaCsResponse;
+}
+
+/// The types taken and returned by the functions in [`crate::phone`].
+pub mod phone {
+    pub use super::{
+        AccountCallLogsResponse, AccountCallLogsResponseData, AddByocNumberRequest,
+        AddByocNumberResponse, AddExternalContactRequest, AddLocationRequest,
+        AddSettingTemplateRequest, AddSettingTemplateResponse, AddUserSettingRequest,
+        AddUserSettingResponse, AssignCallingPlanRequest, BatchAddLocationsRequest,
+        BatchAddLocationsResponse, ByocSipTrunk, CallLogs, ChangeMainCompanyNumberRequest,
+        ExtensionType, ExternalContacts, GetCallLogDetailsResponse, GetLocationResponse,
+        GetPhoneNumberDetailsResponse, GetPhoneRecordingsRecordingType, GetPhoneRecordingsResponse,
+        GetPhoneRecordingsResponseData, GetSettingTemplateResponse,
+        ListAccountPhoneNumbersResponse, ListAccountPhoneNumbersResponseData,
+        ListAccountPhoneNumbersType, ListByocsipTrunkResponse, ListCallingPlansResponseData,
+        ListExternalContactsResponse, ListLocationsResponse, ListLocationsResponseData,
+        ListPhoneUsersResponse, ListPhoneUsersResponseData, ListSettingTemplatesResponse,
+        ListSipGroupsResponse, OwnerType, PhoneSettingResponse, PhoneUserCallLogsResponse,
+        PhoneUserCallLogsType, PhoneUserRecordingsResponse, PhoneUserResponse,
+        PhoneUserSettingsResponse, PhoneUserVoiceMailsResponse, PhoneUserVoiceMailsStatus,
+        QueryDateType, Recordings, SetUpAccountRequest, SipGroups, Site, Templates, TimeType, Type,
+        UpdateExternalContactRequest, UpdateLocationRequest, UpdatePhoneNumberDetailsRequest,
+        UpdateSettingTemplateRequest, UpdateUserProfileRequest, UpdateUserSettingRequest,
+        VoiceMails,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::phone_auto_receptionists`].
+pub mod phone_auto_receptionists {
+    pub use super::{
+        AddAutoReceptionistRequest, AddAutoReceptionistResponse, AddByocNumberResponse,
+        UpdateAutoReceptionistRequest,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::phone_blocked_list`].
+pub mod phone_blocked_list {
+    pub use super::{BlockedList, Groups, ListBlockedResponse, UpdateBlockedListRequest};
+}
+
+/// The types taken and returned by the functions in [`crate::phone_call_queues`].
+pub mod phone_call_queues {
+    pub use super::{
+        AddByocNumberResponse, AddMembersCallQueueRequestData, CallQueues,
+        ChangeCallQueueManagerRequest, CreateCallQueueRequest, CreateCallQueueResponse, Domains,
+        GetCallQueueRecordingsResponse, GetCallQueueRecordingsResponseData, GetCallQueueResponse,
+        ListCallQueuesResponse, UpdateCallQueueRequest,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::phone_devices`].
+pub mod phone_devices {
+    pub use super::{
+        AddPhoneDeviceRequest, GetDeviceResponse, ListPhoneDevicesResponse,
+        ListPhoneDevicesResponseData, ListPhoneDevicesType, UpdateDeviceRequest,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::phone_reports`].
+pub mod phone_reports {
+    pub use super::ReportOperationLogsResponseAllOf;
+}
+
+/// The types taken and returned by the functions in [`crate::phone_shared_line_groups`].
+pub mod phone_shared_line_groups {
+    pub use super::{
+        AddByocNumberResponse, AddMembersSharedLineGroupRequestData, CreateSharedLineGroupRequest,
+        GetSharedLineGroupResponse, ListSharedLineGroupsResponse, SharedLineGroups,
+        UpdateSharedLineGroupRequest,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::phone_site`].
+pub mod phone_site {
+    pub use super::{
+        CreatePhoneSiteRequest, GetSiteResponse, ListPhoneSitesResponse, Site, Sites,
+        UpdateSiteDetailsRequest,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::reports`].
+pub mod reports {
+    pub use super::{
+        ActivityLogs, CategoryType, DashboardMeetingParticipantsIncludeFields, Domains,
+        GetBillingInvoicesReportsResponse, GetBillingReportResponse, QueryDateType,
+        ReportCloudRecordingResponseAllOf, ReportDailyResponse, ReportMeetingDetailsResponse,
+        ReportMeetingParticipantsResponseAllOf, ReportMeetingPollsResponse,
+        ReportMeetingsResponseAllOf, ReportMeetingsType, ReportOperationLogsResponseAllOf,
+        ReportSignInOutActivitiesResponse, ReportUsersType, ReportWebinarParticipantsResponseAllOf,
+        ReportWebinarQaResponse,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::roles`].
+pub mod roles {
+    pub use super::{
+        AddRoleMembersRequest, AddRoleMembersResponse, CreateRoleRequest, Domains,
+        GetRoleInformationResponse, RoleMembersList, UpdateRoleRequest,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::rooms`].
+pub mod rooms {
+    pub use super::{
+        AddRoomRequest, AddRoomResponse, ChangeZrLocationRequest, CheckInRoomsRequest, Domains,
+        GetZrProfileResponse, ListDigitalSignageContentResponse, ListZoomRoomsResponse,
+        ListZoomRoomsResponseData, ListZoomRoomsStatus, ListZoomRoomsType, ListZrDevicesResponse,
+        ManageE911SignageResponse, Site, UpdateRoomProfileRequest,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::rooms_account`].
+pub mod rooms_account {
+    pub use super::{Domains, SettingType, UpdateZrAccProfileRequest};
+}
+
+/// The types taken and returned by the functions in [`crate::rooms_devices`].
+pub mod rooms_devices {
+    pub use super::ChangeZoomRoomsAppVersionRequest;
+}
+
+/// The types taken and returned by the functions in [`crate::rooms_location`].
+pub mod rooms_location {
+    pub use super::{
+        AddAzrLocationRequest, AddAzrLocationResponse, ChangeParentLocationRequest, Domains,
+        GetZrLocationProfileResponse, GetZrLocationStructureResponse, ListZrLocationsResponseData,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::sip_connected_audio`].
+pub mod sip_connected_audio {
+    pub use super::{
+        AddCalloutCountriesRequestData, AddCalloutCountriesResponse, AddInternalNumbersRequest,
+        AddInternalNumbersResponseData, AssignSipConfigRequest, AssignSipTrunkNumbersRequest,
+        AssignSipTrunksRequestData, AssignSipTrunksResponseData, Domains, InternalNumbers,
+        ListInternalCalloutCountriesResponse, ListInternalNumbersResponse,
+        ListSipTrunkNumbersResponse, ListSipTrunksResponseData,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::sip_phone`].
+pub mod sip_phone {
+    pub use super::{CreateSipPhoneRequest, ListSipPhonesResponse, Phones, UpdateSipPhoneRequest};
+}
+
+/// The types taken and returned by the functions in [`crate::tracking_field`].
+pub mod tracking_field {
+    pub use super::{Domains, TrackingField, TrackingfieldGetResponseAllOf};
+}
+
+/// The types taken and returned by the functions in [`crate::tsp`].
+pub mod tsp {
+    pub use super::{
+        TspAccount, TspAccountData, TspAccountsList, TspGlobalDialIn, TspResponse,
+        TspUpdateRequest, UserTsPsResponse,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::users`].
+pub mod users {
+    pub use super::{
+        AddRoleMembersResponse, Domains, Files, LoginType, MeetingSecuritySettings, Members,
+        OptionData, SwitchUserAccountRequest, UpdatePresenceStatusRequestData, UploadVbRequest,
+        UserAssistantsList, UserCreateRequest, UserCreateResponse, UserDeleteAction,
+        UserEmailResponse, UserPasswordRequest, UserPermissions, UserResponseAllOf,
+        UserSchedulersList, UserSettings, UserSettingsResponseOneOf, UserSettingsUpdateOption,
+        UserSettingsUpdateRequestOneOf, UserStatusRequest, UserTokenType, UserUpdate,
+        UserVanityNameResponse, UserZakResponse, UsersIncludeFields, UsersResponse,
+        UsersResponseData, UsersStatus,
+    };
+}
+
+/// The types taken and returned by the functions in [`crate::webinars`].
+pub mod webinars {
+    pub use super::{
+        AddBatchRegistrantsRequest, AddBatchRegistrantsResponse, Domains,
+        GetLiveStreamDetailsResponse, GetTrackingSourcesResponse, InviteLink, InviteLinks,
+        ListWebinarParticipantsResponse, ListWebinarTemplatesResponse, MeetingLiveStream,
+        MeetingPollGetResponseAllOf, MeetingRegistrantsStatus, Participants, Poll,
+        RegistrantStatus, ReportMeetingPollsResponse, ReportWebinarQaResponse,
+        WebinarCreateResponseAllOf, WebinarLiveStreamStatus, WebinarPanelist,
+        WebinarRegistrantCreateResponse, WebinarRegistrantQuestions, WebinarResponseAllOf,
+        WebinarStatusRequest,
+    };
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Users {
     pub client: Client,
@@ -12,6 +10,17 @@ impl Users {
         Users { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Users {
+            client: self.client.with_retry(policy),
+        }
+    }
+
+    /// The OAuth scopes for `get_page`, any one of them grants access.
+    pub const GET_PAGE_SCOPES: &[&str] = &["user:read:admin"];
+
     /**
      * List users.
      *
@@ -32,9 +41,9 @@ impl Users {
      * * `page_size: i64` -- The number of records returned within a single API call.
      * * `role_id: &str` -- The role's unique ID. Use this parameter to filter the response by a specific role. You can use the [List Roles](https://marketplace.zoom.us/docs/api-reference/zoom-api/roles/roles) API to get a role's unique ID value.
      * * `page_number: &str` -- The page number of the current page in the returned records.
-     * * `include_fields: crate::types::UsersIncludeFields` -- Use this parameter to display one of the following attributes in the API call's response:
+     * * `include_fields: Option<crate::types::UsersIncludeFields>` -- Use this parameter to display one of the following attributes in the API call's response:
      *  \* `custom_attributes` — Return the user's custom attributes.
-     *  \* `host_key` — Return the user's [host key](https://support.zoom.us/hc/en-us/articles/205172555-Using-your-host-key).
+     *  \* `host_key` — Return the user's [host key](https://support.zoom.us/hc/en-us/articles/205172555-Using-your-host-key). `None` leaves it off the query, for the API's default.
      * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
      */
     pub async fn get_page(
@@ -43,37 +52,89 @@ impl Users {
         page_size: i64,
         role_id: &str,
         page_number: &str,
-        include_fields: crate::types::UsersIncludeFields,
+        include_fields: Option<crate::types::UsersIncludeFields>,
         next_page_token: &str,
     ) -> Result<Vec<crate::types::UsersResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !include_fields.to_string().is_empty() {
-            query_args.push(("include_fields".to_string(), include_fields.to_string()));
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if let Some(v) = include_fields {
+            query_args.push(("include_fields", v.to_string()));
         }
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if !page_number.is_empty() {
-            query_args.push(("page_number".to_string(), page_number.to_string()));
+            query_args.push(("page_number", page_number.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !role_id.is_empty() {
-            query_args.push(("role_id".to_string(), role_id.to_string()));
+            query_args.push(("role_id", role_id.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/users?{}", query_);
+        let url = crate::Client::url_with_query("/users", &query_args);
 
-        let resp: crate::types::UsersResponseData = self.client.get(&url, None).await?;
+        let resp: crate::types::UsersResponseData = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/users", &[]))?;
 
         // Return our response data.
         Ok(resp.users)
     }
 
+    /**
+     * List users.
+     *
+     * This function performs a `GET` to the `/users` endpoint.
+     *
+     * As opposed to `get_page`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
+     */
+    pub async fn get_page_with_token(
+        &self,
+        status: crate::types::UsersStatus,
+        page_size: i64,
+        role_id: &str,
+        page_number: &str,
+        include_fields: Option<crate::types::UsersIncludeFields>,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::UsersResponse>, String)> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if let Some(v) = include_fields {
+            query_args.push(("include_fields", v.to_string()));
+        }
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        if !page_number.is_empty() {
+            query_args.push(("page_number", page_number.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        if !role_id.is_empty() {
+            query_args.push(("role_id", role_id.to_string()));
+        }
+        if !status.to_string().is_empty() {
+            query_args.push(("status", status.to_string()));
+        }
+        let url = crate::Client::url_with_query("/users", &query_args);
+
+        let resp: crate::types::UsersResponseData = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/users", &[]))?;
+
+        // Return our response data, and where the next page starts.
+        Ok((resp.users, resp.next_page_token))
+    }
+
     /**
      * List users.
      *
@@ -89,22 +150,25 @@ impl Users {
         &self,
         status: crate::types::UsersStatus,
         role_id: &str,
-        include_fields: crate::types::UsersIncludeFields,
+        include_fields: Option<crate::types::UsersIncludeFields>,
     ) -> Result<Vec<crate::types::UsersResponse>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !include_fields.to_string().is_empty() {
-            query_args.push(("include_fields".to_string(), include_fields.to_string()));
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if let Some(v) = include_fields {
+            query_args.push(("include_fields", v.to_string()));
         }
         if !role_id.is_empty() {
-            query_args.push(("role_id".to_string(), role_id.to_string()));
+            query_args.push(("role_id", role_id.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/users?{}", query_);
+        let url = crate::Client::url_with_query("/users", &query_args);
 
-        let mut resp: crate::types::UsersResponseData = self.client.get(&url, None).await?;
+        let mut resp: crate::types::UsersResponseData = self
+            .client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/users", &[]))?;
 
         let mut users = resp.users;
         let mut page = resp.next_page_token;
@@ -137,6 +201,100 @@ impl Users {
         Ok(users)
     }
 
+    /**
+     * List users.
+     *
+     * This function performs a `GET` to the `/users` endpoint.
+     *
+     * As opposed to `get_page`, this function streams the items of all the pages, getting the next page only once the items of the last one are used up. The stream ends after the last page.
+     */
+    pub async fn get_page_stream(
+        &self,
+        status: crate::types::UsersStatus,
+        role_id: &str,
+        include_fields: Option<crate::types::UsersIncludeFields>,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::UsersResponse>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if let Some(v) = include_fields {
+            query_args.push(("include_fields", v.to_string()));
+        }
+        if !role_id.is_empty() {
+            query_args.push(("role_id", role_id.to_string()));
+        }
+        if !status.to_string().is_empty() {
+            query_args.push(("status", status.to_string()));
+        }
+        let url = crate::Client::url_with_query("/users", &query_args);
+
+        self.client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::UsersResponseData| (resp.users, resp.next_page_token),
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/users", &[]))
+    }
+
+    /**
+     * List users.
+     *
+     * This function performs a `GET` to the `/users` endpoint.
+     *
+     * As opposed to `get_page_stream`, this function streams only the items `predicate` keeps. It too gets the next page only once the items of the last one are used up, so a stream that is cut short, for example with `take`, gets no more pages than it needs.
+     */
+    pub async fn get_page_filter<P: FnMut(&crate::types::UsersResponse) -> bool>(
+        &self,
+        status: crate::types::UsersStatus,
+        role_id: &str,
+        include_fields: Option<crate::types::UsersIncludeFields>,
+        mut predicate: P,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::UsersResponse>>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if let Some(v) = include_fields {
+            query_args.push(("include_fields", v.to_string()));
+        }
+        if !role_id.is_empty() {
+            query_args.push(("role_id", role_id.to_string()));
+        }
+        if !status.to_string().is_empty() {
+            query_args.push(("status", status.to_string()));
+        }
+        let url = crate::Client::url_with_query("/users", &query_args);
+
+        let items = self
+            .client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::UsersResponseData| (resp.users, resp.next_page_token),
+            )
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/users", &[]))?;
+        Ok(futures::TryStreamExt::try_filter(items, move |item| {
+            futures::future::ready(predicate(item))
+        }))
+    }
+
+    /**
+     * A request to `get_page`, to set its query parameters by name rather than by position.
+     * The ones left unset are left off the query.
+     */
+    pub fn get_page_request(&self, status: crate::types::UsersStatus) -> GetPageRequest<'_> {
+        GetPageRequest {
+            api: self,
+            status,
+            page_size: None,
+            role_id: None,
+            page_number: None,
+            include_fields: None,
+            next_page_token: None,
+        }
+    }
+
+    /// The OAuth scopes for `create`, any one of them grants access.
+    pub const CREATE_SCOPES: &[&str] = &["user:write:admin", "user:write"];
+
     /**
      * Create users.
      *
@@ -155,10 +313,14 @@ impl Users {
     ) -> Result<crate::types::UserCreateResponse> {
         let url = "/users".to_string();
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| crate::utils::pretty_url("POST", "/users", &[]))
     }
 
+    /// The OAuth scopes for `user`, any one of them grants access.
+    pub const USER_SCOPES: &[&str] = &["user:read:admin", "user:read", "user_info:read"];
+
     /**
      * Get a user.
      *
@@ -173,38 +335,41 @@ impl Users {
      * **Parameters:**
      *
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
-     * * `login_type: crate::types::LoginType` -- The user's login method:
+     * * `login_type: Option<crate::types::LoginType>` -- The user's login method:
      *  
      *  `0` — Facebook OAuth</br>`1` — Google OAuth</br>`24` — Apple OAuth</br>`27` — Microsoft OAuth</br>`97` — Mobile device</br>`98` — RingCentral OAuth</br>`99` — API user</br>`100` — Zoom Work email</br>`101` — Single Sign-On (SSO)
      *  
      *  The following login methods are only available in China:
      *  
-     *  `11` — Phone number</br>`21` — WeChat</br>`23` — Alipay.
+     *  `11` — Phone number</br>`21` — WeChat</br>`23` — Alipay. `None` leaves it off the query, for the API's default.
      * * `encrypted_email: bool` -- Enable/disable the option for a sub account to use shared [Virtual Room Connector(s)](https://support.zoom.us/hc/en-us/articles/202134758-Getting-Started-With-Virtual-Room-Connector) that are set up by the master account. Virtual Room Connectors can only be used by On-prem users.
      */
     pub async fn user(
         &self,
         user_id: &str,
-        login_type: crate::types::LoginType,
+        login_type: Option<crate::types::LoginType>,
         encrypted_email: bool,
     ) -> Result<crate::types::UserResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if encrypted_email {
-            query_args.push(("encrypted_email".to_string(), encrypted_email.to_string()));
+            query_args.push(("encrypted_email", encrypted_email.to_string()));
         }
-        if !login_type.to_string().is_empty() {
-            query_args.push(("login_type".to_string(), login_type.to_string()));
+        if let Some(v) = login_type {
+            query_args.push(("login_type", v.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/users/{}", self.client.encode_path(user_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url("GET", "/users/{userId}", &[("userId", user_id.to_string())])
+        })
     }
 
+    /// The OAuth scopes for `delete`, any one of them grants access.
+    pub const DELETE_SCOPES: &[&str] = &["user:write:admin", "user:write"];
+
     /**
      * Delete a user.
      *
@@ -236,35 +401,88 @@ impl Users {
         transfer_webinar: bool,
         transfer_recording: bool,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !action.to_string().is_empty() {
-            query_args.push(("action".to_string(), action.to_string()));
+            query_args.push(("action", action.to_string()));
         }
         if !transfer_email.is_empty() {
-            query_args.push(("transfer_email".to_string(), transfer_email.to_string()));
+            query_args.push(("transfer_email", transfer_email.to_string()));
         }
         if transfer_meeting {
-            query_args.push(("transfer_meeting".to_string(), transfer_meeting.to_string()));
+            query_args.push(("transfer_meeting", transfer_meeting.to_string()));
         }
         if transfer_recording {
-            query_args.push((
-                "transfer_recording".to_string(),
-                transfer_recording.to_string(),
-            ));
+            query_args.push(("transfer_recording", transfer_recording.to_string()));
         }
         if transfer_webinar {
-            query_args.push(("transfer_webinar".to_string(), transfer_webinar.to_string()));
+            query_args.push(("transfer_webinar", transfer_webinar.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/users/{}", self.client.encode_path(user_id)),
+            &query_args,
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/users/{userId}",
+                &[("userId", user_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete a user.
+     *
+     * This function performs a `DELETE` to the `/users/{userId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(
+        &self,
+        user_id: &str,
+        action: crate::types::UserDeleteAction,
+        transfer_email: &str,
+        transfer_meeting: bool,
+        transfer_webinar: bool,
+        transfer_recording: bool,
+    ) -> Result<bool> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !action.to_string().is_empty() {
+            query_args.push(("action", action.to_string()));
+        }
+        if !transfer_email.is_empty() {
+            query_args.push(("transfer_email", transfer_email.to_string()));
+        }
+        if transfer_meeting {
+            query_args.push(("transfer_meeting", transfer_meeting.to_string()));
+        }
+        if transfer_recording {
+            query_args.push(("transfer_recording", transfer_recording.to_string()));
+        }
+        if transfer_webinar {
+            query_args.push(("transfer_webinar", transfer_webinar.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!("/users/{}", self.client.encode_path(user_id)),
+            &query_args,
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/users/{userId}",
+                    &[("userId", user_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `update`, any one of them grants access.
+    pub const UPDATE_SCOPES: &[&str] = &["user:write:admin", "user:write"];
+
     /**
      * Update a user.
      *
@@ -277,36 +495,79 @@ impl Users {
      * **Parameters:**
      *
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
-     * * `login_type: crate::types::LoginType` -- The user's login method:
+     * * `login_type: Option<crate::types::LoginType>` -- The user's login method:
      *  
      *  `0` — Facebook OAuth</br>`1` — Google OAuth</br>`24` — Apple OAuth</br>`27` — Microsoft OAuth</br>`97` — Mobile device</br>`98` — RingCentral OAuth</br>`99` — API user</br>`100` — Zoom Work email</br>`101` — Single Sign-On (SSO)
      *  
      *  The following login methods are only available in China:
      *  
-     *  `11` — Phone number</br>`21` — WeChat</br>`23` — Alipay.
+     *  `11` — Phone number</br>`21` — WeChat</br>`23` — Alipay. `None` leaves it off the query, for the API's default.
      */
     pub async fn update(
         &self,
         user_id: &str,
-        login_type: crate::types::LoginType,
+        login_type: Option<crate::types::LoginType>,
         body: &crate::types::UserUpdate,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !login_type.to_string().is_empty() {
-            query_args.push(("login_type".to_string(), login_type.to_string()));
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if let Some(v) = login_type {
+            query_args.push(("login_type", v.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/users/{}", self.client.encode_path(user_id)),
+            &query_args,
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/users/{userId}",
+                    &[("userId", user_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update a user.
+     *
+     * This function performs a `PATCH` to the `/users/{userId}` endpoint.
+     *
+     * As opposed to `update`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn update_if_changed<C: serde::Serialize>(
+        &self,
+        user_id: &str,
+        login_type: Option<crate::types::LoginType>,
+        current: &C,
+        body: &crate::types::UserUpdate,
+    ) -> Result<Option<()>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if let Some(v) = login_type {
+            query_args.push(("login_type", v.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!("/users/{}", self.client.encode_path(user_id)),
+            &query_args,
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/users/{userId}",
+                    &[("userId", user_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `zak`, any one of them grants access.
+    pub const ZAK_SCOPES: &[&str] = &["user_zak:read"];
+
     /**
      * Get user's ZAK.
      *
@@ -321,9 +582,15 @@ impl Users {
      */
     pub async fn zak(&self) -> Result<crate::types::UserZakResponse> {
         let url = "/users/me/zak".to_string();
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/users/me/zak", &[]))
     }
 
+    /// The OAuth scopes for `assistant`, any one of them grants access.
+    pub const ASSISTANT_SCOPES: &[&str] = &["user:read:admin", "user:read"];
+
     /**
      * List user assistants.
      *
@@ -344,14 +611,20 @@ impl Users {
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
      */
     pub async fn assistant(&self, user_id: &str) -> Result<crate::types::UserAssistantsList> {
-        let url = format!(
-            "/users/{}/assistants",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-        );
-
-        self.client.get(&url, None).await
+        let url = format!("/users/{}/assistants", self.client.encode_path(user_id),);
+
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/users/{userId}/assistants",
+                &[("userId", user_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `assistant_create`, any one of them grants access.
+    pub const ASSISTANT_CREATE_SCOPES: &[&str] = &["user:write:admin", "user:write"];
+
     /**
      * Add assistants.
      *
@@ -376,16 +649,23 @@ impl Users {
         user_id: &str,
         body: &crate::types::UserAssistantsList,
     ) -> Result<crate::types::AddRoleMembersResponse> {
-        let url = format!(
-            "/users/{}/assistants",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-        );
+        let url = format!("/users/{}/assistants", self.client.encode_path(user_id),);
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/users/{userId}/assistants",
+                    &[("userId", user_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `assistants_delete`, any one of them grants access.
+    pub const ASSISTANTS_DELETE_SCOPES: &[&str] = &["user:write:admin", "user:write"];
+
     /**
      * Delete user assistants.
      *
@@ -406,14 +686,42 @@ impl Users {
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
      */
     pub async fn assistants_delete(&self, user_id: &str) -> Result<()> {
-        let url = format!(
-            "/users/{}/assistants",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-        );
+        let url = format!("/users/{}/assistants", self.client.encode_path(user_id),);
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/users/{userId}/assistants",
+                &[("userId", user_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete user assistants.
+     *
+     * This function performs a `DELETE` to the `/users/{userId}/assistants` endpoint.
+     *
+     * As opposed to `assistants_delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn assistants_delete_if_exists(&self, user_id: &str) -> Result<bool> {
+        let url = format!("/users/{}/assistants", self.client.encode_path(user_id),);
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/users/{userId}/assistants",
+                    &[("userId", user_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `assistant_delete`, any one of them grants access.
+    pub const ASSISTANT_DELETE_SCOPES: &[&str] = &["user:write:admin", "user:write"];
+
     /**
      * Delete a user assistant.
      *
@@ -437,13 +745,58 @@ impl Users {
     pub async fn assistant_delete(&self, user_id: &str, assistant_id: &str) -> Result<()> {
         let url = format!(
             "/users/{}/assistants/{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&assistant_id.to_string()),
+            self.client.encode_path(user_id),
+            self.client.encode_path(assistant_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/users/{userId}/assistants/{assistantId}",
+                &[
+                    ("userId", user_id.to_string()),
+                    ("assistantId", assistant_id.to_string()),
+                ],
+            )
+        })
     }
 
+    /**
+     * Delete a user assistant.
+     *
+     * This function performs a `DELETE` to the `/users/{userId}/assistants/{assistantId}` endpoint.
+     *
+     * As opposed to `assistant_delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn assistant_delete_if_exists(
+        &self,
+        user_id: &str,
+        assistant_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/users/{}/assistants/{}",
+            self.client.encode_path(user_id),
+            self.client.encode_path(assistant_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/users/{userId}/assistants/{assistantId}",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("assistantId", assistant_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The OAuth scopes for `scheduler`, any one of them grants access.
+    pub const SCHEDULER_SCOPES: &[&str] = &["user:read:admin", "user:read"];
+
     /**
      * List user schedulers.
      *
@@ -463,14 +816,20 @@ impl Users {
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
      */
     pub async fn scheduler(&self, user_id: &str) -> Result<crate::types::UserSchedulersList> {
-        let url = format!(
-            "/users/{}/schedulers",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-        );
-
-        self.client.get(&url, None).await
+        let url = format!("/users/{}/schedulers", self.client.encode_path(user_id),);
+
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/users/{userId}/schedulers",
+                &[("userId", user_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `schedulers_delete`, any one of them grants access.
+    pub const SCHEDULERS_DELETE_SCOPES: &[&str] = &["user:write:admin", "user:write"];
+
     /**
      * Delete user schedulers.
      *
@@ -490,14 +849,42 @@ impl Users {
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
      */
     pub async fn schedulers_delete(&self, user_id: &str) -> Result<()> {
-        let url = format!(
-            "/users/{}/schedulers",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-        );
+        let url = format!("/users/{}/schedulers", self.client.encode_path(user_id),);
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/users/{userId}/schedulers",
+                &[("userId", user_id.to_string())],
+            )
+        })
+    }
 
-        self.client.delete(&url, None).await
+    /**
+     * Delete user schedulers.
+     *
+     * This function performs a `DELETE` to the `/users/{userId}/schedulers` endpoint.
+     *
+     * As opposed to `schedulers_delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn schedulers_delete_if_exists(&self, user_id: &str) -> Result<bool> {
+        let url = format!("/users/{}/schedulers", self.client.encode_path(user_id),);
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/users/{userId}/schedulers",
+                    &[("userId", user_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `scheduler_delete`, any one of them grants access.
+    pub const SCHEDULER_DELETE_SCOPES: &[&str] = &["user:write:admin", "user:write"];
+
     /**
      * Delete a scheduler.
      *
@@ -520,13 +907,58 @@ impl Users {
     pub async fn scheduler_delete(&self, user_id: &str, scheduler_id: &str) -> Result<()> {
         let url = format!(
             "/users/{}/schedulers/{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            crate::progenitor_support::encode_path(&scheduler_id.to_string()),
+            self.client.encode_path(user_id),
+            self.client.encode_path(scheduler_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/users/{userId}/schedulers/{schedulerId}",
+                &[
+                    ("userId", user_id.to_string()),
+                    ("schedulerId", scheduler_id.to_string()),
+                ],
+            )
+        })
     }
 
+    /**
+     * Delete a scheduler.
+     *
+     * This function performs a `DELETE` to the `/users/{userId}/schedulers/{schedulerId}` endpoint.
+     *
+     * As opposed to `scheduler_delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn scheduler_delete_if_exists(
+        &self,
+        user_id: &str,
+        scheduler_id: &str,
+    ) -> Result<bool> {
+        let url = format!(
+            "/users/{}/schedulers/{}",
+            self.client.encode_path(user_id),
+            self.client.encode_path(scheduler_id),
+        );
+
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/users/{userId}/schedulers/{schedulerId}",
+                    &[
+                        ("userId", user_id.to_string()),
+                        ("schedulerId", scheduler_id.to_string()),
+                    ],
+                )
+            })
+    }
+
+    /// The OAuth scopes for `picture`, any one of them grants access.
+    pub const PICTURE_SCOPES: &[&str] = &["user:write:admin", "user:write"];
+
     /**
      * Upload a user's profile picture.
      *
@@ -541,14 +973,26 @@ impl Users {
      * **Parameters:**
      *
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
+     * * `pic_file: &[u8]` -- The file's path.
      */
-    pub async fn picture(&self, user_id: &str) -> Result<()> {
-        let url = format!(
-            "/users/{}/picture",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+    pub async fn picture(&self, user_id: &str, pic_file: &[u8]) -> Result<()> {
+        let url = format!("/users/{}/picture", self.client.encode_path(user_id),);
+
+        let form = reqwest::multipart::Form::new().part(
+            "pic_file",
+            reqwest::multipart::Part::bytes(pic_file.to_vec()).file_name("pic_file"),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(reqwest::Method::POST, &url, form)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/users/{userId}/picture",
+                    &[("userId", user_id.to_string())],
+                )
+            })
     }
 
     /**
@@ -563,18 +1007,18 @@ impl Users {
      * **Parameters:**
      *
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
-     * * `login_type: crate::types::LoginType` -- The user's login method:
+     * * `login_type: Option<crate::types::LoginType>` -- The user's login method:
      *  
      *  `0` — Facebook OAuth</br>`1` — Google OAuth</br>`24` — Apple OAuth</br>`27` — Microsoft OAuth</br>`97` — Mobile device</br>`98` — RingCentral OAuth</br>`99` — API user</br>`100` — Zoom Work email</br>`101` — Single Sign-On (SSO)
      *  
      *  The following login methods are only available in China:
      *  
-     *  `11` — Phone number</br>`21` — WeChat</br>`23` — Alipay.
-     * * `option: crate::types::OptionData` -- Use the following options to filter the results of the account's information:
+     *  `11` — Phone number</br>`21` — WeChat</br>`23` — Alipay. `None` leaves it off the query, for the API's default.
+     * * `option: Option<crate::types::OptionData>` -- Use the following options to filter the results of the account's information:
      *  \* `meeting_authentication` — View the account's [meeting authentication settings](https://support.zoom.us/hc/en-us/articles/360037117472-Authentication-Profiles-for-Meetings-and-Webinars).
      *  \* `recording_authentication` — View the account's [recording authentication settings](https://support.zoom.us/hc/en-us/articles/360037756671-Authentication-Profiles-for-Cloud-Recordings).
      *  \* `security` — View the account's security settings. For example, password requirements for user login or two-factor authentication.<br>
-     *  \* `meeting_security` — View the account's meeting security settings.
+     *  \* `meeting_security` — View the account's meeting security settings. `None` leaves it off the query, for the API's default.
      * * `custom_query_fields: &str` -- Provide the name of the field by which you would like to filter the response. For example, if you provide "host_video" as the value of this field, you will get a response similar to the following:<br>
      *   {
      *       "schedule_meeting": {
@@ -586,31 +1030,32 @@ impl Users {
     pub async fn settings_domains(
         &self,
         user_id: &str,
-        login_type: crate::types::LoginType,
-        option: crate::types::OptionData,
+        login_type: Option<crate::types::LoginType>,
+        option: Option<crate::types::OptionData>,
         custom_query_fields: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_query_fields.is_empty() {
-            query_args.push((
-                "custom_query_fields".to_string(),
-                custom_query_fields.to_string(),
-            ));
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
         }
-        if !login_type.to_string().is_empty() {
-            query_args.push(("login_type".to_string(), login_type.to_string()));
+        if let Some(v) = login_type {
+            query_args.push(("login_type", v.to_string()));
         }
-        if !option.to_string().is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+        if let Some(v) = option {
+            query_args.push(("option", v.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/settings?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/users/{}/settings", self.client.encode_path(user_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/users/{userId}/settings",
+                &[("userId", user_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -625,18 +1070,18 @@ impl Users {
      * **Parameters:**
      *
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
-     * * `login_type: crate::types::LoginType` -- The user's login method:
+     * * `login_type: Option<crate::types::LoginType>` -- The user's login method:
      *  
      *  `0` — Facebook OAuth</br>`1` — Google OAuth</br>`24` — Apple OAuth</br>`27` — Microsoft OAuth</br>`97` — Mobile device</br>`98` — RingCentral OAuth</br>`99` — API user</br>`100` — Zoom Work email</br>`101` — Single Sign-On (SSO)
      *  
      *  The following login methods are only available in China:
      *  
-     *  `11` — Phone number</br>`21` — WeChat</br>`23` — Alipay.
-     * * `option: crate::types::OptionData` -- Use the following options to filter the results of the account's information:
+     *  `11` — Phone number</br>`21` — WeChat</br>`23` — Alipay. `None` leaves it off the query, for the API's default.
+     * * `option: Option<crate::types::OptionData>` -- Use the following options to filter the results of the account's information:
      *  \* `meeting_authentication` — View the account's [meeting authentication settings](https://support.zoom.us/hc/en-us/articles/360037117472-Authentication-Profiles-for-Meetings-and-Webinars).
      *  \* `recording_authentication` — View the account's [recording authentication settings](https://support.zoom.us/hc/en-us/articles/360037756671-Authentication-Profiles-for-Cloud-Recordings).
      *  \* `security` — View the account's security settings. For example, password requirements for user login or two-factor authentication.<br>
-     *  \* `meeting_security` — View the account's meeting security settings.
+     *  \* `meeting_security` — View the account's meeting security settings. `None` leaves it off the query, for the API's default.
      * * `custom_query_fields: &str` -- Provide the name of the field by which you would like to filter the response. For example, if you provide "host_video" as the value of this field, you will get a response similar to the following:<br>
      *   {
      *       "schedule_meeting": {
@@ -648,31 +1093,32 @@ impl Users {
     pub async fn settings_user(
         &self,
         user_id: &str,
-        login_type: crate::types::LoginType,
-        option: crate::types::OptionData,
+        login_type: Option<crate::types::LoginType>,
+        option: Option<crate::types::OptionData>,
         custom_query_fields: &str,
     ) -> Result<crate::types::UserSettings> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_query_fields.is_empty() {
-            query_args.push((
-                "custom_query_fields".to_string(),
-                custom_query_fields.to_string(),
-            ));
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
         }
-        if !login_type.to_string().is_empty() {
-            query_args.push(("login_type".to_string(), login_type.to_string()));
+        if let Some(v) = login_type {
+            query_args.push(("login_type", v.to_string()));
         }
-        if !option.to_string().is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+        if let Some(v) = option {
+            query_args.push(("option", v.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/settings?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/users/{}/settings", self.client.encode_path(user_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/users/{userId}/settings",
+                &[("userId", user_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -687,18 +1133,18 @@ impl Users {
      * **Parameters:**
      *
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
-     * * `login_type: crate::types::LoginType` -- The user's login method:
+     * * `login_type: Option<crate::types::LoginType>` -- The user's login method:
      *  
      *  `0` — Facebook OAuth</br>`1` — Google OAuth</br>`24` — Apple OAuth</br>`27` — Microsoft OAuth</br>`97` — Mobile device</br>`98` — RingCentral OAuth</br>`99` — API user</br>`100` — Zoom Work email</br>`101` — Single Sign-On (SSO)
      *  
      *  The following login methods are only available in China:
      *  
-     *  `11` — Phone number</br>`21` — WeChat</br>`23` — Alipay.
-     * * `option: crate::types::OptionData` -- Use the following options to filter the results of the account's information:
+     *  `11` — Phone number</br>`21` — WeChat</br>`23` — Alipay. `None` leaves it off the query, for the API's default.
+     * * `option: Option<crate::types::OptionData>` -- Use the following options to filter the results of the account's information:
      *  \* `meeting_authentication` — View the account's [meeting authentication settings](https://support.zoom.us/hc/en-us/articles/360037117472-Authentication-Profiles-for-Meetings-and-Webinars).
      *  \* `recording_authentication` — View the account's [recording authentication settings](https://support.zoom.us/hc/en-us/articles/360037756671-Authentication-Profiles-for-Cloud-Recordings).
      *  \* `security` — View the account's security settings. For example, password requirements for user login or two-factor authentication.<br>
-     *  \* `meeting_security` — View the account's meeting security settings.
+     *  \* `meeting_security` — View the account's meeting security settings. `None` leaves it off the query, for the API's default.
      * * `custom_query_fields: &str` -- Provide the name of the field by which you would like to filter the response. For example, if you provide "host_video" as the value of this field, you will get a response similar to the following:<br>
      *   {
      *       "schedule_meeting": {
@@ -710,33 +1156,37 @@ impl Users {
     pub async fn settings_meeting_security(
         &self,
         user_id: &str,
-        login_type: crate::types::LoginType,
-        option: crate::types::OptionData,
+        login_type: Option<crate::types::LoginType>,
+        option: Option<crate::types::OptionData>,
         custom_query_fields: &str,
     ) -> Result<crate::types::MeetingSecuritySettings> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_query_fields.is_empty() {
-            query_args.push((
-                "custom_query_fields".to_string(),
-                custom_query_fields.to_string(),
-            ));
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
         }
-        if !login_type.to_string().is_empty() {
-            query_args.push(("login_type".to_string(), login_type.to_string()));
+        if let Some(v) = login_type {
+            query_args.push(("login_type", v.to_string()));
         }
-        if !option.to_string().is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+        if let Some(v) = option {
+            query_args.push(("option", v.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/settings?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/users/{}/settings", self.client.encode_path(user_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/users/{userId}/settings",
+                &[("userId", user_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `setting`, any one of them grants access.
+    pub const SETTING_SCOPES: &[&str] = &["user:read:admin", "user:read"];
+
     /**
      * Get user settings.
      *
@@ -749,18 +1199,18 @@ impl Users {
      * **Parameters:**
      *
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
-     * * `login_type: crate::types::LoginType` -- The user's login method:
+     * * `login_type: Option<crate::types::LoginType>` -- The user's login method:
      *  
      *  `0` — Facebook OAuth</br>`1` — Google OAuth</br>`24` — Apple OAuth</br>`27` — Microsoft OAuth</br>`97` — Mobile device</br>`98` — RingCentral OAuth</br>`99` — API user</br>`100` — Zoom Work email</br>`101` — Single Sign-On (SSO)
      *  
      *  The following login methods are only available in China:
      *  
-     *  `11` — Phone number</br>`21` — WeChat</br>`23` — Alipay.
-     * * `option: crate::types::OptionData` -- Use the following options to filter the results of the account's information:
+     *  `11` — Phone number</br>`21` — WeChat</br>`23` — Alipay. `None` leaves it off the query, for the API's default.
+     * * `option: Option<crate::types::OptionData>` -- Use the following options to filter the results of the account's information:
      *  \* `meeting_authentication` — View the account's [meeting authentication settings](https://support.zoom.us/hc/en-us/articles/360037117472-Authentication-Profiles-for-Meetings-and-Webinars).
      *  \* `recording_authentication` — View the account's [recording authentication settings](https://support.zoom.us/hc/en-us/articles/360037756671-Authentication-Profiles-for-Cloud-Recordings).
      *  \* `security` — View the account's security settings. For example, password requirements for user login or two-factor authentication.<br>
-     *  \* `meeting_security` — View the account's meeting security settings.
+     *  \* `meeting_security` — View the account's meeting security settings. `None` leaves it off the query, for the API's default.
      * * `custom_query_fields: &str` -- Provide the name of the field by which you would like to filter the response. For example, if you provide "host_video" as the value of this field, you will get a response similar to the following:<br>
      *   {
      *       "schedule_meeting": {
@@ -772,33 +1222,37 @@ impl Users {
     pub async fn setting(
         &self,
         user_id: &str,
-        login_type: crate::types::LoginType,
-        option: crate::types::OptionData,
+        login_type: Option<crate::types::LoginType>,
+        option: Option<crate::types::OptionData>,
         custom_query_fields: &str,
     ) -> Result<crate::types::UserSettingsResponseOneOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !custom_query_fields.is_empty() {
-            query_args.push((
-                "custom_query_fields".to_string(),
-                custom_query_fields.to_string(),
-            ));
+            query_args.push(("custom_query_fields", custom_query_fields.to_string()));
         }
-        if !login_type.to_string().is_empty() {
-            query_args.push(("login_type".to_string(), login_type.to_string()));
+        if let Some(v) = login_type {
+            query_args.push(("login_type", v.to_string()));
         }
-        if !option.to_string().is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+        if let Some(v) = option {
+            query_args.push(("option", v.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/settings?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/users/{}/settings", self.client.encode_path(user_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/users/{userId}/settings",
+                &[("userId", user_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `settings_update`, any one of them grants access.
+    pub const SETTINGS_UPDATE_SCOPES: &[&str] = &["user:write:admin", "user:write"];
+
     /**
      * Update user settings.
      *
@@ -810,31 +1264,74 @@ impl Users {
      *
      * **Parameters:**
      *
-     * * `option: crate::types::UserSettingsUpdateOption`
+     * * `option: Option<crate::types::UserSettingsUpdateOption>` -- `None` leaves it off the query, for the API's default.
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
      */
     pub async fn settings_update(
         &self,
-        option: crate::types::UserSettingsUpdateOption,
+        option: Option<crate::types::UserSettingsUpdateOption>,
         user_id: &str,
         body: &crate::types::UserSettingsUpdateRequestOneOf,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !option.to_string().is_empty() {
-            query_args.push(("option".to_string(), option.to_string()));
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if let Some(v) = option {
+            query_args.push(("option", v.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/settings?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/users/{}/settings", self.client.encode_path(user_id)),
+            &query_args,
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/users/{userId}/settings",
+                    &[("userId", user_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update user settings.
+     *
+     * This function performs a `PATCH` to the `/users/{userId}/settings` endpoint.
+     *
+     * As opposed to `settings_update`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn settings_update_if_changed<C: serde::Serialize>(
+        &self,
+        option: Option<crate::types::UserSettingsUpdateOption>,
+        user_id: &str,
+        current: &C,
+        body: &crate::types::UserSettingsUpdateRequestOneOf,
+    ) -> Result<Option<()>> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if let Some(v) = option {
+            query_args.push(("option", v.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!("/users/{}/settings", self.client.encode_path(user_id)),
+            &query_args,
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/users/{userId}/settings",
+                    &[("userId", user_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `status`, any one of them grants access.
+    pub const STATUS_SCOPES: &[&str] = &["user:write:admin", "user:write"];
+
     /**
      * Update user status.
      *
@@ -855,16 +1352,23 @@ impl Users {
         user_id: &str,
         body: &crate::types::UserStatusRequest,
     ) -> Result<()> {
-        let url = format!(
-            "/users/{}/status",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-        );
+        let url = format!("/users/{}/status", self.client.encode_path(user_id),);
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/users/{userId}/status",
+                    &[("userId", user_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `password`, any one of them grants access.
+    pub const PASSWORD_SCOPES: &[&str] = &["user:write:admin", "user:write"];
+
     /**
      * Update a user's password.
      *
@@ -888,16 +1392,23 @@ impl Users {
         user_id: &str,
         body: &crate::types::UserPasswordRequest,
     ) -> Result<()> {
-        let url = format!(
-            "/users/{}/password",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-        );
+        let url = format!("/users/{}/password", self.client.encode_path(user_id),);
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/users/{userId}/password",
+                    &[("userId", user_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `permission`, any one of them grants access.
+    pub const PERMISSION_SCOPES: &[&str] = &["user:read:admin", "user:read"];
+
     /**
      * Get user permissions.
      *
@@ -914,14 +1425,20 @@ impl Users {
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
      */
     pub async fn permission(&self, user_id: &str) -> Result<crate::types::UserPermissions> {
-        let url = format!(
-            "/users/{}/permissions",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-        );
-
-        self.client.get(&url, None).await
+        let url = format!("/users/{}/permissions", self.client.encode_path(user_id),);
+
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/users/{userId}/permissions",
+                &[("userId", user_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `token`, any one of them grants access.
+    pub const TOKEN_SCOPES: &[&str] = &["user:read:admin", "user:read"];
+
     /**
      * Get a user token.
      *
@@ -936,32 +1453,39 @@ impl Users {
      * **Parameters:**
      *
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
-     * * `type_: crate::types::UserTokenType` -- User token types:<br>`token` - Used for starting meetings with the client SDK. This token expires in 14 days and a new token will be returned after the expiry.<br>`zak` - Used for generating the start meeting URL. The token expiration time is two hours. For API users, the expiration time is 90 days.
+     * * `type_: Option<crate::types::UserTokenType>` -- User token types:<br>`token` - Used for starting meetings with the client SDK. This token expires in 14 days and a new token will be returned after the expiry.<br>`zak` - Used for generating the start meeting URL. The token expiration time is two hours. For API users, the expiration time is 90 days. `None` leaves it off the query, for the API's default.
      * * `ttl: i64` -- Use this field in conjunction with the `type` field where the value of `type` field is `zak`. The value of this field denotes the expiry time of the `zak` token in seconds. For example, if you would like the zak token to be expired after one hour of the token generation, the value of this field should be `3600`.
      */
     pub async fn token(
         &self,
         user_id: &str,
-        type_: crate::types::UserTokenType,
+        type_: Option<crate::types::UserTokenType>,
         ttl: i64,
     ) -> Result<crate::types::UserZakResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if ttl > 0 {
-            query_args.push(("ttl".to_string(), ttl.to_string()));
+            query_args.push(("ttl", ttl.to_string()));
         }
-        if !type_.to_string().is_empty() {
-            query_args.push(("type".to_string(), type_.to_string()));
+        if let Some(v) = type_ {
+            query_args.push(("type", v.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/token?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/users/{}/token", self.client.encode_path(user_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/users/{userId}/token",
+                &[("userId", user_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `sso_token_delete`, any one of them grants access.
+    pub const SSO_TOKEN_DELETE_SCOPES: &[&str] = &["user:write:admin", "user:write"];
+
     /**
      * Revoke a user's SSO token.
      *
@@ -978,14 +1502,42 @@ impl Users {
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
      */
     pub async fn sso_token_delete(&self, user_id: &str) -> Result<()> {
-        let url = format!(
-            "/users/{}/token",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-        );
+        let url = format!("/users/{}/token", self.client.encode_path(user_id),);
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/users/{userId}/token",
+                &[("userId", user_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Revoke a user's SSO token.
+     *
+     * This function performs a `DELETE` to the `/users/{userId}/token` endpoint.
+     *
+     * As opposed to `sso_token_delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn sso_token_delete_if_exists(&self, user_id: &str) -> Result<bool> {
+        let url = format!("/users/{}/token", self.client.encode_path(user_id),);
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/users/{userId}/token",
+                    &[("userId", user_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `email`, any one of them grants access.
+    pub const EMAIL_SCOPES: &[&str] = &["user:read:admin", "user:read"];
+
     /**
      * Check a user email.
      *
@@ -1005,16 +1557,21 @@ impl Users {
      * * `email: &str` -- The email address to be verified.
      */
     pub async fn email(&self, email: &str) -> Result<crate::types::UserEmailResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !email.is_empty() {
-            query_args.push(("email".to_string(), email.to_string()));
+            query_args.push(("email", email.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/users/email?{}", query_);
+        let url = crate::Client::url_with_query("/users/email", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/users/email", &[]))
     }
 
+    /// The OAuth scopes for `email_update`, any one of them grants access.
+    pub const EMAIL_UPDATE_SCOPES: &[&str] = &["user:write:admin", "user:write"];
+
     /**
      * Update a user's email.
      *
@@ -1036,16 +1593,23 @@ impl Users {
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
      */
     pub async fn email_update(&self, user_id: &str, body: &crate::types::Members) -> Result<()> {
-        let url = format!(
-            "/users/{}/email",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-        );
+        let url = format!("/users/{}/email", self.client.encode_path(user_id),);
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/users/{userId}/email",
+                    &[("userId", user_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `vanity_name`, any one of them grants access.
+    pub const VANITY_NAME_SCOPES: &[&str] = &["user:read:admin", "user:read"];
+
     /**
      * Check a user's PM room.
      *
@@ -1065,16 +1629,21 @@ impl Users {
         &self,
         vanity_name: &str,
     ) -> Result<crate::types::UserVanityNameResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !vanity_name.is_empty() {
-            query_args.push(("vanity_name".to_string(), vanity_name.to_string()));
+            query_args.push(("vanity_name", vanity_name.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!("/users/vanity_name?{}", query_);
+        let url = crate::Client::url_with_query("/users/vanity_name", &query_args);
 
-        self.client.get(&url, None).await
+        self.client
+            .get(&url, None)
+            .await
+            .with_context(|| crate::utils::pretty_url("GET", "/users/vanity_name", &[]))
     }
 
+    /// The OAuth scopes for `switch_account`, any one of them grants access.
+    pub const SWITCH_ACCOUNT_SCOPES: &[&str] = &["user:master"];
+
     /**
      * Switch a user's account.
      *
@@ -1107,15 +1676,28 @@ impl Users {
     ) -> Result<()> {
         let url = format!(
             "/accounts/{}/users/{}/account",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(account_id),
+            self.client.encode_path(user_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/accounts/{accountId}/users/{userId}/account",
+                    &[
+                        ("accountId", account_id.to_string()),
+                        ("userId", user_id.to_string()),
+                    ],
+                )
+            })
     }
 
+    /// The OAuth scopes for `update_presence_status`, any one of them grants access.
+    pub const UPDATE_PRESENCE_STATUS_SCOPES: &[&str] = &["user:write", "user:write:admin"];
+
     /**
      * Update a user's presence status.
      *
@@ -1145,14 +1727,24 @@ impl Users {
     ) -> Result<()> {
         let url = format!(
             "/users/{}/presence_status",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(user_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/users/{userId}/presence_status",
+                    &[("userId", user_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `upload_v_buser`, any one of them grants access.
+    pub const UPLOAD_V_BUSER_SCOPES: &[&str] = &["user:write:admin"];
+
     /**
      * Upload virtual background files.
      *
@@ -1179,14 +1771,24 @@ impl Users {
     ) -> Result<crate::types::Files> {
         let url = format!(
             "/users/{}/settings/virtual_backgrounds",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(user_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/users/{userId}/settings/virtual_backgrounds",
+                    &[("userId", user_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `del_vb`, any one of them grants access.
+    pub const DEL_VB_SCOPES: &[&str] = &["user:write:admin"];
+
     /**
      * Delete virtual background files.
      *
@@ -1205,17 +1807,530 @@ impl Users {
      * * `user_id: &str` -- Unique identifier of the user. Retrieve the value of this field by calling the [List users](https://marketplace.zoom.us/docs/api-reference/zoom-api/users/users) API. .
      */
     pub async fn del_vb(&self, user_id: &str, file_ids: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !file_ids.is_empty() {
-            query_args.push(("file_ids".to_string(), file_ids.to_string()));
+            query_args.push(("file_ids", file_ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/settings/virtual_backgrounds?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/users/{}/settings/virtual_backgrounds",
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/users/{userId}/settings/virtual_backgrounds",
+                &[("userId", user_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete virtual background files.
+     *
+     * This function performs a `DELETE` to the `/users/{userId}/settings/virtual_backgrounds` endpoint.
+     *
+     * As opposed to `del_vb`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn del_vb_if_exists(&self, user_id: &str, file_ids: &str) -> Result<bool> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !file_ids.is_empty() {
+            query_args.push(("file_ids", file_ids.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/users/{}/settings/virtual_backgrounds",
+                self.client.encode_path(user_id)
+            ),
+            &query_args,
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/users/{userId}/settings/virtual_backgrounds",
+                    &[("userId", user_id.to_string())],
+                )
+            })
+    }
+}
+
+/// A request to [`Users::get_page`], see [`Users::get_page_request`].
+pub struct GetPageRequest<'a> {
+    api: &'a Users,
+    status: crate::types::UsersStatus,
+    page_size: Option<i64>,
+    role_id: Option<String>,
+    page_number: Option<String>,
+    include_fields: Option<crate::types::UsersIncludeFields>,
+    next_page_token: Option<String>,
+}
+
+impl<'a> GetPageRequest<'a> {
+    /// Set `page_size`.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Set `role_id`.
+    pub fn role_id(mut self, role_id: &str) -> Self {
+        self.role_id = Some(role_id.to_string());
+        self
+    }
+
+    /// Set `page_number`.
+    pub fn page_number(mut self, page_number: &str) -> Self {
+        self.page_number = Some(page_number.to_string());
+        self
+    }
+
+    /// Set `include_fields`.
+    pub fn include_fields(mut self, include_fields: crate::types::UsersIncludeFields) -> Self {
+        self.include_fields = Some(include_fields);
+        self
+    }
+
+    /// Set `next_page_token`.
+    pub fn next_page_token(mut self, next_page_token: &str) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    /// Send the request.
+    pub async fn send(self) -> Result<Vec<crate::types::UsersResponse>> {
+        self.api
+            .get_page(
+                self.status,
+                self.page_size.unwrap_or_default(),
+                self.role_id.as_deref().unwrap_or_default(),
+                self.page_number.as_deref().unwrap_or_default(),
+                self.include_fields,
+                self.next_page_token.as_deref().unwrap_or_default(),
+            )
+            .await
+    }
+}
+
+/// The functions of [`Users`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn UsersApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait UsersApi: Send + Sync {
+    async fn get_page(
+        &self,
+        status: crate::types::UsersStatus,
+        page_size: i64,
+        role_id: &str,
+        page_number: &str,
+        include_fields: Option<crate::types::UsersIncludeFields>,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::UsersResponse>>;
+    async fn get_page_with_token(
+        &self,
+        status: crate::types::UsersStatus,
+        page_size: i64,
+        role_id: &str,
+        page_number: &str,
+        include_fields: Option<crate::types::UsersIncludeFields>,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::UsersResponse>, String)>;
+    async fn get_all(
+        &self,
+        status: crate::types::UsersStatus,
+        role_id: &str,
+        include_fields: Option<crate::types::UsersIncludeFields>,
+    ) -> Result<Vec<crate::types::UsersResponse>>;
+    async fn create(
+        &self,
+        body: &crate::types::UserCreateRequest,
+    ) -> Result<crate::types::UserCreateResponse>;
+    async fn user(
+        &self,
+        user_id: &str,
+        login_type: Option<crate::types::LoginType>,
+        encrypted_email: bool,
+    ) -> Result<crate::types::UserResponseAllOf>;
+    async fn delete(
+        &self,
+        user_id: &str,
+        action: crate::types::UserDeleteAction,
+        transfer_email: &str,
+        transfer_meeting: bool,
+        transfer_webinar: bool,
+        transfer_recording: bool,
+    ) -> Result<()>;
+    async fn delete_if_exists(
+        &self,
+        user_id: &str,
+        action: crate::types::UserDeleteAction,
+        transfer_email: &str,
+        transfer_meeting: bool,
+        transfer_webinar: bool,
+        transfer_recording: bool,
+    ) -> Result<bool>;
+    async fn update(
+        &self,
+        user_id: &str,
+        login_type: Option<crate::types::LoginType>,
+        body: &crate::types::UserUpdate,
+    ) -> Result<()>;
+    async fn zak(&self) -> Result<crate::types::UserZakResponse>;
+    async fn assistant(&self, user_id: &str) -> Result<crate::types::UserAssistantsList>;
+    async fn assistant_create(
+        &self,
+        user_id: &str,
+        body: &crate::types::UserAssistantsList,
+    ) -> Result<crate::types::AddRoleMembersResponse>;
+    async fn assistants_delete(&self, user_id: &str) -> Result<()>;
+    async fn assistants_delete_if_exists(&self, user_id: &str) -> Result<bool>;
+    async fn assistant_delete(&self, user_id: &str, assistant_id: &str) -> Result<()>;
+    async fn assistant_delete_if_exists(&self, user_id: &str, assistant_id: &str) -> Result<bool>;
+    async fn scheduler(&self, user_id: &str) -> Result<crate::types::UserSchedulersList>;
+    async fn schedulers_delete(&self, user_id: &str) -> Result<()>;
+    async fn schedulers_delete_if_exists(&self, user_id: &str) -> Result<bool>;
+    async fn scheduler_delete(&self, user_id: &str, scheduler_id: &str) -> Result<()>;
+    async fn scheduler_delete_if_exists(&self, user_id: &str, scheduler_id: &str) -> Result<bool>;
+    async fn picture(&self, user_id: &str, pic_file: &[u8]) -> Result<()>;
+    async fn settings_domains(
+        &self,
+        user_id: &str,
+        login_type: Option<crate::types::LoginType>,
+        option: Option<crate::types::OptionData>,
+        custom_query_fields: &str,
+    ) -> Result<crate::types::Domains>;
+    async fn settings_user(
+        &self,
+        user_id: &str,
+        login_type: Option<crate::types::LoginType>,
+        option: Option<crate::types::OptionData>,
+        custom_query_fields: &str,
+    ) -> Result<crate::types::UserSettings>;
+    async fn settings_meeting_security(
+        &self,
+        user_id: &str,
+        login_type: Option<crate::types::LoginType>,
+        option: Option<crate::types::OptionData>,
+        custom_query_fields: &str,
+    ) -> Result<crate::types::MeetingSecuritySettings>;
+    async fn setting(
+        &self,
+        user_id: &str,
+        login_type: Option<crate::types::LoginType>,
+        option: Option<crate::types::OptionData>,
+        custom_query_fields: &str,
+    ) -> Result<crate::types::UserSettingsResponseOneOf>;
+    async fn settings_update(
+        &self,
+        option: Option<crate::types::UserSettingsUpdateOption>,
+        user_id: &str,
+        body: &crate::types::UserSettingsUpdateRequestOneOf,
+    ) -> Result<()>;
+    async fn status(&self, user_id: &str, body: &crate::types::UserStatusRequest) -> Result<()>;
+    async fn password(&self, user_id: &str, body: &crate::types::UserPasswordRequest)
+        -> Result<()>;
+    async fn permission(&self, user_id: &str) -> Result<crate::types::UserPermissions>;
+    async fn token(
+        &self,
+        user_id: &str,
+        type_: Option<crate::types::UserTokenType>,
+        ttl: i64,
+    ) -> Result<crate::types::UserZakResponse>;
+    async fn sso_token_delete(&self, user_id: &str) -> Result<()>;
+    async fn sso_token_delete_if_exists(&self, user_id: &str) -> Result<bool>;
+    async fn email(&self, email: &str) -> Result<crate::types::UserEmailResponse>;
+    async fn email_update(&self, user_id: &str, body: &crate::types::Members) -> Result<()>;
+    async fn vanity_name(&self, vanity_name: &str) -> Result<crate::types::UserVanityNameResponse>;
+    async fn switch_account(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::SwitchUserAccountRequest,
+    ) -> Result<()>;
+    async fn update_presence_status(
+        &self,
+        user_id: &str,
+        body: &crate::types::UpdatePresenceStatusRequestData,
+    ) -> Result<()>;
+    async fn upload_v_buser(
+        &self,
+        user_id: &str,
+        body: &crate::types::UploadVbRequest,
+    ) -> Result<crate::types::Files>;
+    async fn del_vb(&self, user_id: &str, file_ids: &str) -> Result<()>;
+    async fn del_vb_if_exists(&self, user_id: &str, file_ids: &str) -> Result<bool>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl UsersApi for Users {
+    async fn get_page(
+        &self,
+        status: crate::types::UsersStatus,
+        page_size: i64,
+        role_id: &str,
+        page_number: &str,
+        include_fields: Option<crate::types::UsersIncludeFields>,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::UsersResponse>> {
+        Users::get_page(
+            self,
+            status,
+            page_size,
+            role_id,
+            page_number,
+            include_fields,
+            next_page_token,
+        )
+        .await
+    }
+    async fn get_page_with_token(
+        &self,
+        status: crate::types::UsersStatus,
+        page_size: i64,
+        role_id: &str,
+        page_number: &str,
+        include_fields: Option<crate::types::UsersIncludeFields>,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::UsersResponse>, String)> {
+        Users::get_page_with_token(
+            self,
+            status,
+            page_size,
+            role_id,
+            page_number,
+            include_fields,
+            next_page_token,
+        )
+        .await
+    }
+    async fn get_all(
+        &self,
+        status: crate::types::UsersStatus,
+        role_id: &str,
+        include_fields: Option<crate::types::UsersIncludeFields>,
+    ) -> Result<Vec<crate::types::UsersResponse>> {
+        Users::get_all(self, status, role_id, include_fields).await
+    }
+    async fn create(
+        &self,
+        body: &crate::types::UserCreateRequest,
+    ) -> Result<crate::types::UserCreateResponse> {
+        Users::create(self, body).await
+    }
+    async fn user(
+        &self,
+        user_id: &str,
+        login_type: Option<crate::types::LoginType>,
+        encrypted_email: bool,
+    ) -> Result<crate::types::UserResponseAllOf> {
+        Users::user(self, user_id, login_type, encrypted_email).await
+    }
+    async fn delete(
+        &self,
+        user_id: &str,
+        action: crate::types::UserDeleteAction,
+        transfer_email: &str,
+        transfer_meeting: bool,
+        transfer_webinar: bool,
+        transfer_recording: bool,
+    ) -> Result<()> {
+        Users::delete(
+            self,
+            user_id,
+            action,
+            transfer_email,
+            transfer_meeting,
+            transfer_webinar,
+            transfer_recording,
+        )
+        .await
+    }
+    async fn delete_if_exists(
+        &self,
+        user_id: &str,
+        action: crate::types::UserDeleteAction,
+        transfer_email: &str,
+        transfer_meeting: bool,
+        transfer_webinar: bool,
+        transfer_recording: bool,
+    ) -> Result<bool> {
+        Users::delete_if_exists(
+            self,
+            user_id,
+            action,
+            transfer_email,
+            transfer_meeting,
+            transfer_webinar,
+            transfer_recording,
+        )
+        .await
+    }
+    async fn update(
+        &self,
+        user_id: &str,
+        login_type: Option<crate::types::LoginType>,
+        body: &crate::types::UserUpdate,
+    ) -> Result<()> {
+        Users::update(self, user_id, login_type, body).await
+    }
+    async fn zak(&self) -> Result<crate::types::UserZakResponse> {
+        Users::zak(self).await
+    }
+    async fn assistant(&self, user_id: &str) -> Result<crate::types::UserAssistantsList> {
+        Users::assistant(self, user_id).await
+    }
+    async fn assistant_create(
+        &self,
+        user_id: &str,
+        body: &crate::types::UserAssistantsList,
+    ) -> Result<crate::types::AddRoleMembersResponse> {
+        Users::assistant_create(self, user_id, body).await
+    }
+    async fn assistants_delete(&self, user_id: &str) -> Result<()> {
+        Users::assistants_delete(self, user_id).await
+    }
+    async fn assistants_delete_if_exists(&self, user_id: &str) -> Result<bool> {
+        Users::assistants_delete_if_exists(self, user_id).await
+    }
+    async fn assistant_delete(&self, user_id: &str, assistant_id: &str) -> Result<()> {
+        Users::assistant_delete(self, user_id, assistant_id).await
+    }
+    async fn assistant_delete_if_exists(&self, user_id: &str, assistant_id: &str) -> Result<bool> {
+        Users::assistant_delete_if_exists(self, user_id, assistant_id).await
+    }
+    async fn scheduler(&self, user_id: &str) -> Result<crate::types::UserSchedulersList> {
+        Users::scheduler(self, user_id).await
+    }
+    async fn schedulers_delete(&self, user_id: &str) -> Result<()> {
+        Users::schedulers_delete(self, user_id).await
+    }
+    async fn schedulers_delete_if_exists(&self, user_id: &str) -> Result<bool> {
+        Users::schedulers_delete_if_exists(self, user_id).await
+    }
+    async fn scheduler_delete(&self, user_id: &str, scheduler_id: &str) -> Result<()> {
+        Users::scheduler_delete(self, user_id, scheduler_id).await
+    }
+    async fn scheduler_delete_if_exists(&self, user_id: &str, scheduler_id: &str) -> Result<bool> {
+        Users::scheduler_delete_if_exists(self, user_id, scheduler_id).await
+    }
+    async fn picture(&self, user_id: &str, pic_file: &[u8]) -> Result<()> {
+        Users::picture(self, user_id, pic_file).await
+    }
+    async fn settings_domains(
+        &self,
+        user_id: &str,
+        login_type: Option<crate::types::LoginType>,
+        option: Option<crate::types::OptionData>,
+        custom_query_fields: &str,
+    ) -> Result<crate::types::Domains> {
+        Users::settings_domains(self, user_id, login_type, option, custom_query_fields).await
+    }
+    async fn settings_user(
+        &self,
+        user_id: &str,
+        login_type: Option<crate::types::LoginType>,
+        option: Option<crate::types::OptionData>,
+        custom_query_fields: &str,
+    ) -> Result<crate::types::UserSettings> {
+        Users::settings_user(self, user_id, login_type, option, custom_query_fields).await
+    }
+    async fn settings_meeting_security(
+        &self,
+        user_id: &str,
+        login_type: Option<crate::types::LoginType>,
+        option: Option<crate::types::OptionData>,
+        custom_query_fields: &str,
+    ) -> Result<crate::types::MeetingSecuritySettings> {
+        Users::settings_meeting_security(self, user_id, login_type, option, custom_query_fields)
+            .await
+    }
+    async fn setting(
+        &self,
+        user_id: &str,
+        login_type: Option<crate::types::LoginType>,
+        option: Option<crate::types::OptionData>,
+        custom_query_fields: &str,
+    ) -> Result<crate::types::UserSettingsResponseOneOf> {
+        Users::setting(self, user_id, login_type, option, custom_query_fields).await
+    }
+    async fn settings_update(
+        &self,
+        option: Option<crate::types::UserSettingsUpdateOption>,
+        user_id: &str,
+        body: &crate::types::UserSettingsUpdateRequestOneOf,
+    ) -> Result<()> {
+        Users::settings_update(self, option, user_id, body).await
+    }
+    async fn status(&self, user_id: &str, body: &crate::types::UserStatusRequest) -> Result<()> {
+        Users::status(self, user_id, body).await
+    }
+    async fn password(
+        &self,
+        user_id: &str,
+        body: &crate::types::UserPasswordRequest,
+    ) -> Result<()> {
+        Users::password(self, user_id, body).await
+    }
+    async fn permission(&self, user_id: &str) -> Result<crate::types::UserPermissions> {
+        Users::permission(self, user_id).await
+    }
+    async fn token(
+        &self,
+        user_id: &str,
+        type_: Option<crate::types::UserTokenType>,
+        ttl: i64,
+    ) -> Result<crate::types::UserZakResponse> {
+        Users::token(self, user_id, type_, ttl).await
+    }
+    async fn sso_token_delete(&self, user_id: &str) -> Result<()> {
+        Users::sso_token_delete(self, user_id).await
+    }
+    async fn sso_token_delete_if_exists(&self, user_id: &str) -> Result<bool> {
+        Users::sso_token_delete_if_exists(self, user_id).await
+    }
+    async fn email(&self, email: &str) -> Result<crate::types::UserEmailResponse> {
+        Users::email(self, email).await
+    }
+    async fn email_update(&self, user_id: &str, body: &crate::types::Members) -> Result<()> {
+        Users::email_update(self, user_id, body).await
+    }
+    async fn vanity_name(&self, vanity_name: &str) -> Result<crate::types::UserVanityNameResponse> {
+        Users::vanity_name(self, vanity_name).await
+    }
+    async fn switch_account(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::SwitchUserAccountRequest,
+    ) -> Result<()> {
+        Users::switch_account(self, account_id, user_id, body).await
+    }
+    async fn update_presence_status(
+        &self,
+        user_id: &str,
+        body: &crate::types::UpdatePresenceStatusRequestData,
+    ) -> Result<()> {
+        Users::update_presence_status(self, user_id, body).await
+    }
+    async fn upload_v_buser(
+        &self,
+        user_id: &str,
+        body: &crate::types::UploadVbRequest,
+    ) -> Result<crate::types::Files> {
+        Users::upload_v_buser(self, user_id, body).await
+    }
+    async fn del_vb(&self, user_id: &str, file_ids: &str) -> Result<()> {
+        Users::del_vb(self, user_id, file_ids).await
+    }
+    async fn del_vb_if_exists(&self, user_id: &str, file_ids: &str) -> Result<bool> {
+        Users::del_vb_if_exists(self, user_id, file_ids).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{CallContext, Client, ClientResult as Result};
 
 pub struct Webinars {
     pub client: Client,
@@ -12,6 +10,17 @@ impl Webinars {
         Webinars { client }
     }
 
+    /// Make the calls through this with the given retry policy, rather
+    /// than with the client's.
+    pub fn retry(&self, policy: crate::RetryPolicy) -> Self {
+        Webinars {
+            client: self.client.with_retry(policy),
+        }
+    }
+
+    /// The OAuth scopes for `get`, any one of them grants access.
+    pub const GET_SCOPES: &[&str] = &["webinar:read:admin", "webinar:read"];
+
     /**
      * List webinars.
      *
@@ -41,23 +50,31 @@ impl Webinars {
         page_size: i64,
         page_number: i64,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if page_number > 0 {
-            query_args.push(("page_number".to_string(), page_number.to_string()));
+            query_args.push(("page_number", page_number.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/users/{}/webinars?{}",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!("/users/{}/webinars", self.client.encode_path(user_id)),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/users/{userId}/webinars",
+                &[("userId", user_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `create`, any one of them grants access.
+    pub const CREATE_SCOPES: &[&str] = &["webinar:write:admin", "webinar:write"];
+
     /**
      * Create a webinar.
      *
@@ -77,14 +94,20 @@ impl Webinars {
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
      */
     pub async fn create(&self, user_id: &str) -> Result<crate::types::WebinarCreateResponseAllOf> {
-        let url = format!(
-            "/users/{}/webinars",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
-        );
-
-        self.client.post(&url, None).await
+        let url = format!("/users/{}/webinars", self.client.encode_path(user_id),);
+
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/users/{userId}/webinars",
+                &[("userId", user_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `webinar`, any one of them grants access.
+    pub const WEBINAR_SCOPES: &[&str] = &["webinar:read:admin", "webinar:read"];
+
     /**
      * Get a webinar.
      *
@@ -107,26 +130,36 @@ impl Webinars {
         occurrence_id: &str,
         show_previous_occurrences: bool,
     ) -> Result<crate::types::WebinarResponseAllOf> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !occurrence_id.is_empty() {
-            query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
+            query_args.push(("occurrence_id", occurrence_id.to_string()));
         }
         if show_previous_occurrences {
             query_args.push((
-                "show_previous_occurrences".to_string(),
+                "show_previous_occurrences",
                 show_previous_occurrences.to_string(),
             ));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/webinars/{}?{}",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/webinars/{}",
+                self.client.encode_path(&webinar_id.to_string())
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/webinars/{webinarId}",
+                &[("webinarId", webinar_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `delete`, any one of them grants access.
+    pub const DELETE_SCOPES: &[&str] = &["webinar:write:admin", "webinar:write"];
+
     /**
      * Delete a webinar.
      *
@@ -155,26 +188,79 @@ impl Webinars {
         occurrence_id: &str,
         cancel_webinar_reminder: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !cancel_webinar_reminder.is_empty() {
             query_args.push((
-                "cancel_webinar_reminder".to_string(),
+                "cancel_webinar_reminder",
                 cancel_webinar_reminder.to_string(),
             ));
         }
         if !occurrence_id.is_empty() {
-            query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
+            query_args.push(("occurrence_id", occurrence_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/webinars/{}?{}",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/webinars/{}",
+                self.client.encode_path(&webinar_id.to_string())
+            ),
+            &query_args,
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/webinars/{webinarId}",
+                &[("webinarId", webinar_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Delete a webinar.
+     *
+     * This function performs a `DELETE` to the `/webinars/{webinarId}` endpoint.
+     *
+     * As opposed to `delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_if_exists(
+        &self,
+        webinar_id: i64,
+        occurrence_id: &str,
+        cancel_webinar_reminder: &str,
+    ) -> Result<bool> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !cancel_webinar_reminder.is_empty() {
+            query_args.push((
+                "cancel_webinar_reminder",
+                cancel_webinar_reminder.to_string(),
+            ));
+        }
+        if !occurrence_id.is_empty() {
+            query_args.push(("occurrence_id", occurrence_id.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/webinars/{}",
+                self.client.encode_path(&webinar_id.to_string())
+            ),
+            &query_args,
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/webinars/{webinarId}",
+                    &[("webinarId", webinar_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `update`, any one of them grants access.
+    pub const UPDATE_SCOPES: &[&str] = &["webinar:write:admin", "webinar:write"];
+
     /**
      * Update a webinar.
      *
@@ -194,20 +280,30 @@ impl Webinars {
      * * `occurrence_id: &str` -- Webinar occurrence id. Support change of agenda, start_time, duration, settings: {host_video, panelist_video, hd_video, watermark, auto_recording}.
      */
     pub async fn update(&self, webinar_id: i64, occurrence_id: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !occurrence_id.is_empty() {
-            query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
+            query_args.push(("occurrence_id", occurrence_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/webinars/{}?{}",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/webinars/{}",
+                self.client.encode_path(&webinar_id.to_string())
+            ),
+            &query_args,
         );
 
-        self.client.patch(&url, None).await
+        self.client.patch(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "PATCH",
+                "/webinars/{webinarId}",
+                &[("webinarId", webinar_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `list_participants`, any one of them grants access.
+    pub const LIST_PARTICIPANTS_SCOPES: &[&str] = &["webinar:read:admin", "webinar:read"];
+
     /**
      * List webinar participants.
      *
@@ -237,27 +333,77 @@ impl Webinars {
         page_size: i64,
         next_page_token: &str,
     ) -> Result<Vec<crate::types::Participants>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/past_webinars/{}/participants?{}",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/past_webinars/{}/participants",
+                self.client.encode_path(webinar_id)
+            ),
+            &query_args,
         );
 
         let resp: crate::types::ListWebinarParticipantsResponse =
-            self.client.get(&url, None).await?;
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/past_webinars/{webinarId}/participants",
+                    &[("webinarId", webinar_id.to_string())],
+                )
+            })?;
 
         // Return our response data.
         Ok(resp.participants)
     }
 
+    /**
+     * List webinar participants.
+     *
+     * This function performs a `GET` to the `/past_webinars/{webinarId}/participants` endpoint.
+     *
+     * As opposed to `list_participants`, this function also returns the token of the next page, to pass as `next_page_token` to get it. The token is empty on the last page.
+     */
+    pub async fn list_participants_page(
+        &self,
+        webinar_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::Participants>, String)> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !next_page_token.is_empty() {
+            query_args.push(("next_page_token", next_page_token.to_string()));
+        }
+        let page_size = self.client.page_size_or(page_size);
+        if page_size > 0 {
+            query_args.push(("page_size", page_size.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/past_webinars/{}/participants",
+                self.client.encode_path(webinar_id)
+            ),
+            &query_args,
+        );
+
+        let resp: crate::types::ListWebinarParticipantsResponse =
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/past_webinars/{webinarId}/participants",
+                    &[("webinarId", webinar_id.to_string())],
+                )
+            })?;
+
+        // Return our response data, and where the next page starts.
+        Ok((resp.participants, resp.next_page_token))
+    }
+
     /**
      * List webinar participants.
      *
@@ -283,11 +429,17 @@ impl Webinars {
     ) -> Result<Vec<crate::types::Participants>> {
         let url = format!(
             "/past_webinars/{}/participants",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
+            self.client.encode_path(webinar_id),
         );
 
         let mut resp: crate::types::ListWebinarParticipantsResponse =
-            self.client.get(&url, None).await?;
+            self.client.get(&url, None).await.with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/past_webinars/{webinarId}/participants",
+                    &[("webinarId", webinar_id.to_string())],
+                )
+            })?;
 
         let mut participants = resp.participants;
         let mut page = resp.next_page_token;
@@ -320,6 +472,82 @@ impl Webinars {
         Ok(participants)
     }
 
+    /**
+     * List webinar participants.
+     *
+     * This function performs a `GET` to the `/past_webinars/{webinarId}/participants` endpoint.
+     *
+     * As opposed to `list_participants`, this function streams the items of all the pages, getting the next page only once the items of the last one are used up. The stream ends after the last page.
+     */
+    pub async fn list_participants_stream(
+        &self,
+        webinar_id: &str,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::Participants>>> {
+        let url = format!(
+            "/past_webinars/{}/participants",
+            self.client.encode_path(webinar_id),
+        );
+
+        self.client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListWebinarParticipantsResponse| {
+                    (resp.participants, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/past_webinars/{webinarId}/participants",
+                    &[("webinarId", webinar_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * List webinar participants.
+     *
+     * This function performs a `GET` to the `/past_webinars/{webinarId}/participants` endpoint.
+     *
+     * As opposed to `list_participants_stream`, this function streams only the items `predicate` keeps. It too gets the next page only once the items of the last one are used up, so a stream that is cut short, for example with `take`, gets no more pages than it needs.
+     */
+    pub async fn list_participants_filter<P: FnMut(&crate::types::Participants) -> bool>(
+        &self,
+        webinar_id: &str,
+        mut predicate: P,
+    ) -> Result<impl futures::Stream<Item = Result<crate::types::Participants>>> {
+        let url = format!(
+            "/past_webinars/{}/participants",
+            self.client.encode_path(webinar_id),
+        );
+
+        let items = self
+            .client
+            .get_stream_pages(
+                &url,
+                "next_page_token",
+                |resp: crate::types::ListWebinarParticipantsResponse| {
+                    (resp.participants, resp.next_page_token)
+                },
+            )
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "GET",
+                    "/past_webinars/{webinarId}/participants",
+                    &[("webinarId", webinar_id.to_string())],
+                )
+            })?;
+        Ok(futures::TryStreamExt::try_filter(items, move |item| {
+            futures::future::ready(predicate(item))
+        }))
+    }
+
+    /// The OAuth scopes for `status`, any one of them grants access.
+    pub const STATUS_SCOPES: &[&str] = &["webinar:write:admin", "webinar:write"];
+
     /**
      * Update webinar status.
      *
@@ -343,14 +571,24 @@ impl Webinars {
     ) -> Result<()> {
         let url = format!(
             "/webinars/{}/status",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
+            self.client.encode_path(&webinar_id.to_string()),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/webinars/{webinarId}/status",
+                    &[("webinarId", webinar_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `panelist`, any one of them grants access.
+    pub const PANELIST_SCOPES: &[&str] = &["webinar:read:admin", "webinar:read"];
+
     /**
      * List panelists.
      *
@@ -372,12 +610,21 @@ impl Webinars {
     pub async fn panelist(&self, webinar_id: i64) -> Result<crate::types::Domains> {
         let url = format!(
             "/webinars/{}/panelists",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
+            self.client.encode_path(&webinar_id.to_string()),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/webinars/{webinarId}/panelists",
+                &[("webinarId", webinar_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `panelist_create`, any one of them grants access.
+    pub const PANELIST_CREATE_SCOPES: &[&str] = &["webinar:write:admin", "webinar:write"];
+
     /**
      * Add panelists.
      *
@@ -402,14 +649,24 @@ impl Webinars {
     ) -> Result<()> {
         let url = format!(
             "/webinars/{}/panelists",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
+            self.client.encode_path(&webinar_id.to_string()),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/webinars/{webinarId}/panelists",
+                    &[("webinarId", webinar_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `panelists_delete`, any one of them grants access.
+    pub const PANELISTS_DELETE_SCOPES: &[&str] = &["webinar:write:admin", "webinar:write"];
+
     /**
      * Remove panelists.
      *
@@ -429,12 +686,46 @@ impl Webinars {
     pub async fn panelists_delete(&self, webinar_id: i64) -> Result<()> {
         let url = format!(
             "/webinars/{}/panelists",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
+            self.client.encode_path(&webinar_id.to_string()),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/webinars/{webinarId}/panelists",
+                &[("webinarId", webinar_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * Remove panelists.
+     *
+     * This function performs a `DELETE` to the `/webinars/{webinarId}/panelists` endpoint.
+     *
+     * As opposed to `panelists_delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn panelists_delete_if_exists(&self, webinar_id: i64) -> Result<bool> {
+        let url = format!(
+            "/webinars/{}/panelists",
+            self.client.encode_path(&webinar_id.to_string()),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/webinars/{webinarId}/panelists",
+                    &[("webinarId", webinar_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `panelist_delete`, any one of them grants access.
+    pub const PANELIST_DELETE_SCOPES: &[&str] = &["webinar:write:admin", "webinar:write"];
+
     /**
      * Remove a panelist.
      *
@@ -457,13 +748,58 @@ impl Webinars {
     pub async fn panelist_delete(&self, webinar_id: i64, panelist_id: i64) -> Result<()> {
         let url = format!(
             "/webinars/{}/panelists/{}",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
-            crate::progenitor_support::encode_path(&panelist_id.to_string()),
+            self.client.encode_path(&webinar_id.to_string()),
+            self.client.encode_path(&panelist_id.to_string()),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/webinars/{webinarId}/panelists/{panelistId}",
+                &[
+                    ("webinarId", webinar_id.to_string()),
+                    ("panelistId", panelist_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Remove a panelist.
+     *
+     * This function performs a `DELETE` to the `/webinars/{webinarId}/panelists/{panelistId}` endpoint.
+     *
+     * As opposed to `panelist_delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn panelist_delete_if_exists(
+        &self,
+        webinar_id: i64,
+        panelist_id: i64,
+    ) -> Result<bool> {
+        let url = format!(
+            "/webinars/{}/panelists/{}",
+            self.client.encode_path(&webinar_id.to_string()),
+            self.client.encode_path(&panelist_id.to_string()),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/webinars/{webinarId}/panelists/{panelistId}",
+                    &[
+                        ("webinarId", webinar_id.to_string()),
+                        ("panelistId", panelist_id.to_string()),
+                    ],
+                )
+            })
     }
 
+    /// The OAuth scopes for `registrant`, any one of them grants access.
+    pub const REGISTRANT_SCOPES: &[&str] = &["webinar:read:admin", "webinar:read"];
+
     /**
      * List webinar registrants.
      *
@@ -501,38 +837,67 @@ impl Webinars {
         page_number: i64,
         next_page_token: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if !occurrence_id.is_empty() {
-            query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
+            query_args.push(("occurrence_id", occurrence_id.to_string()));
         }
         if page_number > 0 {
-            query_args.push(("page_number".to_string(), page_number.to_string()));
+            query_args.push(("page_number", page_number.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
         if !status.to_string().is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
+            query_args.push(("status", status.to_string()));
         }
         if !tracking_source_id.is_empty() {
-            query_args.push((
-                "tracking_source_id".to_string(),
-                tracking_source_id.to_string(),
-            ));
+            query_args.push(("tracking_source_id", tracking_source_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/webinars/{}/registrants?{}",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/webinars/{}/registrants",
+                self.client.encode_path(webinar_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/webinars/{webinarId}/registrants",
+                &[("webinarId", webinar_id.to_string())],
+            )
+        })
+    }
+
+    /**
+     * A request to `registrant`, to set its query parameters by name rather than by position.
+     * The ones left unset are left off the query.
+     */
+    pub fn registrant_request(
+        &self,
+        webinar_id: &str,
+        status: crate::types::MeetingRegistrantsStatus,
+    ) -> RegistrantRequest<'_> {
+        RegistrantRequest {
+            api: self,
+            webinar_id: webinar_id.to_string(),
+            occurrence_id: None,
+            status,
+            tracking_source_id: None,
+            page_size: None,
+            page_number: None,
+            next_page_token: None,
+        }
     }
 
+    /// The OAuth scopes for `registrant_create`, any one of them grants access.
+    pub const REGISTRANT_CREATE_SCOPES: &[&str] = &["webinar:write:admin", "webinar:write"];
+
     /**
      * Add a webinar registrant.
      *
@@ -555,20 +920,30 @@ impl Webinars {
         webinar_id: &str,
         occurrence_ids: &str,
     ) -> Result<crate::types::WebinarRegistrantCreateResponse> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !occurrence_ids.is_empty() {
-            query_args.push(("occurrence_ids".to_string(), occurrence_ids.to_string()));
+            query_args.push(("occurrence_ids", occurrence_ids.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/webinars/{}/registrants?{}",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/webinars/{}/registrants",
+                self.client.encode_path(webinar_id)
+            ),
+            &query_args,
         );
 
-        self.client.post(&url, None).await
+        self.client.post(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "POST",
+                "/webinars/{webinarId}/registrants",
+                &[("webinarId", webinar_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `add_batch_registrants`, any one of them grants access.
+    pub const ADD_BATCH_REGISTRANTS_SCOPES: &[&str] = &["webinar:write", "webinar:write:admin"];
+
     /**
      * Perform batch registration.
      *
@@ -601,14 +976,24 @@ impl Webinars {
     ) -> Result<crate::types::AddBatchRegistrantsResponse> {
         let url = format!(
             "/webinars/{}/batch_registrants",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
+            self.client.encode_path(webinar_id),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/webinars/{webinarId}/batch_registrants",
+                    &[("webinarId", webinar_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `registrant_status`, any one of them grants access.
+    pub const REGISTRANT_STATUS_SCOPES: &[&str] = &["webinar:write:admin", "webinar:write"];
+
     /**
      * Update registrant's status.
      *
@@ -632,22 +1017,33 @@ impl Webinars {
         occurrence_id: &str,
         body: &crate::types::RegistrantStatus,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !occurrence_id.is_empty() {
-            query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
+            query_args.push(("occurrence_id", occurrence_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/webinars/{}/registrants/status?{}",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/webinars/{}/registrants/status",
+                self.client.encode_path(&webinar_id.to_string())
+            ),
+            &query_args,
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/webinars/{webinarId}/registrants/status",
+                    &[("webinarId", webinar_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `past`, any one of them grants access.
+    pub const PAST_SCOPES: &[&str] = &["webinar:read:admin", "webinar:read"];
+
     /**
      * List past webinar instances.
      *
@@ -667,12 +1063,21 @@ impl Webinars {
     pub async fn past(&self, webinar_id: i64) -> Result<crate::types::Domains> {
         let url = format!(
             "/past_webinars/{}/instances",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
+            self.client.encode_path(&webinar_id.to_string()),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/past_webinars/{webinarId}/instances",
+                &[("webinarId", webinar_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `poll`, any one of them grants access.
+    pub const POLL_SCOPES: &[&str] = &["webinar:read:admin", "webinar:read"];
+
     /**
      * List a webinar's polls .
      *
@@ -692,12 +1097,21 @@ impl Webinars {
     pub async fn poll(&self, webinar_id: i64) -> Result<crate::types::Domains> {
         let url = format!(
             "/webinars/{}/polls",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
+            self.client.encode_path(&webinar_id.to_string()),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/webinars/{webinarId}/polls",
+                &[("webinarId", webinar_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `poll_create`, any one of them grants access.
+    pub const POLL_CREATE_SCOPES: &[&str] = &["webinar:write:admin", "webinar:write"];
+
     /**
      * Create a webinar's poll.
      *
@@ -721,14 +1135,24 @@ impl Webinars {
     ) -> Result<crate::types::MeetingPollGetResponseAllOf> {
         let url = format!(
             "/webinars/{}/polls",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
+            self.client.encode_path(&webinar_id.to_string()),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/webinars/{webinarId}/polls",
+                    &[("webinarId", webinar_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `poll_get`, any one of them grants access.
+    pub const POLL_GET_SCOPES: &[&str] = &["webinar:read:admin", "webinar:read"];
+
     /**
      * Get a webinar poll.
      *
@@ -753,13 +1177,25 @@ impl Webinars {
     ) -> Result<crate::types::MeetingPollGetResponseAllOf> {
         let url = format!(
             "/webinars/{}/polls/{}",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
-            crate::progenitor_support::encode_path(&poll_id.to_string()),
+            self.client.encode_path(&webinar_id.to_string()),
+            self.client.encode_path(poll_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/webinars/{webinarId}/polls/{pollId}",
+                &[
+                    ("webinarId", webinar_id.to_string()),
+                    ("pollId", poll_id.to_string()),
+                ],
+            )
+        })
     }
 
+    /// The OAuth scopes for `poll_update`, any one of them grants access.
+    pub const POLL_UPDATE_SCOPES: &[&str] = &["webinar:write:admin", "webinar:write"];
+
     /**
      * Update a webinar poll.
      *
@@ -785,15 +1221,28 @@ impl Webinars {
     ) -> Result<()> {
         let url = format!(
             "/webinars/{}/polls/{}",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
-            crate::progenitor_support::encode_path(&poll_id.to_string()),
+            self.client.encode_path(&webinar_id.to_string()),
+            self.client.encode_path(poll_id),
         );
 
         self.client
-            .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .put(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PUT",
+                    "/webinars/{webinarId}/polls/{pollId}",
+                    &[
+                        ("webinarId", webinar_id.to_string()),
+                        ("pollId", poll_id.to_string()),
+                    ],
+                )
+            })
     }
 
+    /// The OAuth scopes for `poll_delete`, any one of them grants access.
+    pub const POLL_DELETE_SCOPES: &[&str] = &["webinar:write:admin", "webinar:write"];
+
     /**
      * Delete a webinar poll.
      *
@@ -814,13 +1263,54 @@ impl Webinars {
     pub async fn poll_delete(&self, webinar_id: i64, poll_id: &str) -> Result<()> {
         let url = format!(
             "/webinars/{}/polls/{}",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
-            crate::progenitor_support::encode_path(&poll_id.to_string()),
+            self.client.encode_path(&webinar_id.to_string()),
+            self.client.encode_path(poll_id),
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/webinars/{webinarId}/polls/{pollId}",
+                &[
+                    ("webinarId", webinar_id.to_string()),
+                    ("pollId", poll_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Delete a webinar poll.
+     *
+     * This function performs a `DELETE` to the `/webinars/{webinarId}/polls/{pollId}` endpoint.
+     *
+     * As opposed to `poll_delete`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn poll_delete_if_exists(&self, webinar_id: i64, poll_id: &str) -> Result<bool> {
+        let url = format!(
+            "/webinars/{}/polls/{}",
+            self.client.encode_path(&webinar_id.to_string()),
+            self.client.encode_path(poll_id),
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/webinars/{webinarId}/polls/{pollId}",
+                    &[
+                        ("webinarId", webinar_id.to_string()),
+                        ("pollId", poll_id.to_string()),
+                    ],
+                )
+            })
     }
 
+    /// The OAuth scopes for `registrants_questions_get`, any one of them grants access.
+    pub const REGISTRANTS_QUESTIONS_GET_SCOPES: &[&str] = &["webinar:read:admin", "webinar:read"];
+
     /**
      * List registration questions.
      *
@@ -845,12 +1335,22 @@ impl Webinars {
     ) -> Result<crate::types::WebinarRegistrantQuestions> {
         let url = format!(
             "/webinars/{}/registrants/questions",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
+            self.client.encode_path(&webinar_id.to_string()),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/webinars/{webinarId}/registrants/questions",
+                &[("webinarId", webinar_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `registrant_question_update`, any one of them grants access.
+    pub const REGISTRANT_QUESTION_UPDATE_SCOPES: &[&str] =
+        &["webinar:write:admin", "webinar:write"];
+
     /**
      * Update registration questions.
      *
@@ -877,14 +1377,54 @@ impl Webinars {
     ) -> Result<()> {
         let url = format!(
             "/webinars/{}/registrants/questions",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
+            self.client.encode_path(&webinar_id.to_string()),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/webinars/{webinarId}/registrants/questions",
+                    &[("webinarId", webinar_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update registration questions.
+     *
+     * This function performs a `PATCH` to the `/webinars/{webinarId}/registrants/questions` endpoint.
+     *
+     * As opposed to `registrant_question_update`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn registrant_question_update_if_changed<C: serde::Serialize>(
+        &self,
+        webinar_id: i64,
+        current: &C,
+        body: &crate::types::WebinarRegistrantQuestions,
+    ) -> Result<Option<()>> {
+        let url = format!(
+            "/webinars/{}/registrants/questions",
+            self.client.encode_path(&webinar_id.to_string()),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/webinars/{webinarId}/registrants/questions",
+                    &[("webinarId", webinar_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `registrant_get`, any one of them grants access.
+    pub const REGISTRANT_GET_SCOPES: &[&str] = &["webinar:read:admin", "webinar:read"];
+
     /**
      * Get a webinar registrant.
      *
@@ -909,21 +1449,34 @@ impl Webinars {
         registrant_id: &str,
         occurrence_id: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !occurrence_id.is_empty() {
-            query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
+            query_args.push(("occurrence_id", occurrence_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/webinars/{}/registrants/{}?{}",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
-            crate::progenitor_support::encode_path(&registrant_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/webinars/{}/registrants/{}",
+                self.client.encode_path(webinar_id),
+                self.client.encode_path(registrant_id)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/webinars/{webinarId}/registrants/{registrantId}",
+                &[
+                    ("webinarId", webinar_id.to_string()),
+                    ("registrantId", registrant_id.to_string()),
+                ],
+            )
+        })
     }
 
+    /// The OAuth scopes for `delete_registrant`, any one of them grants access.
+    pub const DELETE_REGISTRANT_SCOPES: &[&str] = &["webinar:write:admin", "webinar:write"];
+
     /**
      * Delete a webinar registrant.
      *
@@ -946,21 +1499,75 @@ impl Webinars {
         registrant_id: &str,
         occurrence_id: &str,
     ) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !occurrence_id.is_empty() {
-            query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
+            query_args.push(("occurrence_id", occurrence_id.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/webinars/{}/registrants/{}?{}",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
-            crate::progenitor_support::encode_path(&registrant_id.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/webinars/{}/registrants/{}",
+                self.client.encode_path(webinar_id),
+                self.client.encode_path(registrant_id)
+            ),
+            &query_args,
+        );
+
+        self.client.delete(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "DELETE",
+                "/webinars/{webinarId}/registrants/{registrantId}",
+                &[
+                    ("webinarId", webinar_id.to_string()),
+                    ("registrantId", registrant_id.to_string()),
+                ],
+            )
+        })
+    }
+
+    /**
+     * Delete a webinar registrant.
+     *
+     * This function performs a `DELETE` to the `/webinars/{webinarId}/registrants/{registrantId}` endpoint.
+     *
+     * As opposed to `delete_registrant`, this function returns `false` rather than an error if there was nothing to delete, and `true` if there was.
+     */
+    pub async fn delete_registrant_if_exists(
+        &self,
+        webinar_id: &str,
+        registrant_id: &str,
+        occurrence_id: &str,
+    ) -> Result<bool> {
+        let mut query_args: Vec<(&str, String)> = Default::default();
+        if !occurrence_id.is_empty() {
+            query_args.push(("occurrence_id", occurrence_id.to_string()));
+        }
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/webinars/{}/registrants/{}",
+                self.client.encode_path(webinar_id),
+                self.client.encode_path(registrant_id)
+            ),
+            &query_args,
         );
 
-        self.client.delete(&url, None).await
+        self.client
+            .delete_if_exists(&url, None)
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "DELETE",
+                    "/webinars/{webinarId}/registrants/{registrantId}",
+                    &[
+                        ("webinarId", webinar_id.to_string()),
+                        ("registrantId", registrant_id.to_string()),
+                    ],
+                )
+            })
     }
 
+    /// The OAuth scopes for `absentee`, any one of them grants access.
+    pub const ABSENTEE_SCOPES: &[&str] = &["webinar:read:admin", "webinar:read"];
+
     /**
      * Get webinar absentees.
      *
@@ -984,26 +1591,37 @@ impl Webinars {
         next_page_token: &str,
         webinar_uuid: &str,
     ) -> Result<crate::types::Domains> {
-        let mut query_args: Vec<(String, String)> = Default::default();
+        let mut query_args: Vec<(&str, String)> = Default::default();
         if !next_page_token.is_empty() {
-            query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
+            query_args.push(("next_page_token", next_page_token.to_string()));
         }
         if !occurrence_id.is_empty() {
-            query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
+            query_args.push(("occurrence_id", occurrence_id.to_string()));
         }
+        let page_size = self.client.page_size_or(page_size);
         if page_size > 0 {
-            query_args.push(("page_size".to_string(), page_size.to_string()));
+            query_args.push(("page_size", page_size.to_string()));
         }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
-        let url = format!(
-            "/past_webinars/{}/absentees?{}",
-            crate::progenitor_support::encode_path(&webinar_uuid.to_string()),
-            query_
+        let url = crate::Client::url_with_query(
+            &format!(
+                "/past_webinars/{}/absentees",
+                self.client.encode_path(webinar_uuid)
+            ),
+            &query_args,
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/past_webinars/{WebinarUUID}/absentees",
+                &[("WebinarUUID", webinar_uuid.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `get_tracking_sources`, any one of them grants access.
+    pub const GET_TRACKING_SOURCES_SCOPES: &[&str] = &["webinar:read:admin", "webinar:read"];
+
     /**
      * Get webinar tracking sources.
      *
@@ -1028,12 +1646,21 @@ impl Webinars {
     ) -> Result<crate::types::GetTrackingSourcesResponse> {
         let url = format!(
             "/webinars/{}/tracking_sources",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
+            self.client.encode_path(webinar_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/webinars/{webinarId}/tracking_sources",
+                &[("webinarId", webinar_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `list_past_poll_results`, any one of them grants access.
+    pub const LIST_PAST_POLL_RESULTS_SCOPES: &[&str] = &["webinar:read:admin", "webinar:read"];
+
     /**
      * List past webinar poll results.
      *
@@ -1060,12 +1687,21 @@ impl Webinars {
     ) -> Result<crate::types::ReportMeetingPollsResponse> {
         let url = format!(
             "/past_webinars/{}/polls",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
+            self.client.encode_path(webinar_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/past_webinars/{webinarId}/polls",
+                &[("webinarId", webinar_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `list_past_qa`, any one of them grants access.
+    pub const LIST_PAST_QA_SCOPES: &[&str] = &["webinar:read:admin", "webinar:read"];
+
     /**
      * List Q&A of past webinar.
      *
@@ -1092,12 +1728,15 @@ impl Webinars {
         &self,
         webinar_id: &str,
     ) -> Result<crate::types::ReportWebinarQaResponse> {
-        let url = format!(
-            "/past_webinars/{}/qa",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
-        );
-
-        self.client.get(&url, None).await
+        let url = format!("/past_webinars/{}/qa", self.client.encode_path(webinar_id),);
+
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/past_webinars/{webinarId}/qa",
+                &[("webinarId", webinar_id.to_string())],
+            )
+        })
     }
 
     /**
@@ -1118,12 +1757,21 @@ impl Webinars {
     ) -> Result<crate::types::ListWebinarTemplatesResponse> {
         let url = format!(
             "/users/{}/webinar_templates",
-            crate::progenitor_support::encode_path(&user_id.to_string()),
+            self.client.encode_path(user_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/users/{userId}/webinar_templates",
+                &[("userId", user_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `get_live_stream_details`, any one of them grants access.
+    pub const GET_LIVE_STREAM_DETAILS_SCOPES: &[&str] = &["webinar:read:admin", "webinar:read"];
+
     /**
      * Get live stream details.
      *
@@ -1150,12 +1798,21 @@ impl Webinars {
     ) -> Result<crate::types::GetLiveStreamDetailsResponse> {
         let url = format!(
             "/webinars/{}/livestream",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
+            self.client.encode_path(webinar_id),
         );
 
-        self.client.get(&url, None).await
+        self.client.get(&url, None).await.with_context(|| {
+            crate::utils::pretty_url(
+                "GET",
+                "/webinars/{webinarId}/livestream",
+                &[("webinarId", webinar_id.to_string())],
+            )
+        })
     }
 
+    /// The OAuth scopes for `live_stream_update`, any one of them grants access.
+    pub const LIVE_STREAM_UPDATE_SCOPES: &[&str] = &["webinar:write:admin", "webinar:write"];
+
     /**
      * Update a live stream.
      *
@@ -1182,14 +1839,54 @@ impl Webinars {
     ) -> Result<()> {
         let url = format!(
             "/webinars/{}/livestream",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
+            self.client.encode_path(webinar_id),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/webinars/{webinarId}/livestream",
+                    &[("webinarId", webinar_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update a live stream.
+     *
+     * This function performs a `PATCH` to the `/webinars/{webinarId}/livestream` endpoint.
+     *
+     * As opposed to `live_stream_update`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn live_stream_update_if_changed<C: serde::Serialize>(
+        &self,
+        webinar_id: &str,
+        current: &C,
+        body: &crate::types::MeetingLiveStream,
+    ) -> Result<Option<()>> {
+        let url = format!(
+            "/webinars/{}/livestream",
+            self.client.encode_path(webinar_id),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/webinars/{webinarId}/livestream",
+                    &[("webinarId", webinar_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `live_stream_status_update`, any one of them grants access.
+    pub const LIVE_STREAM_STATUS_UPDATE_SCOPES: &[&str] = &["webinar:write:admin", "webinar:write"];
+
     /**
      * Update Live Stream Status.
      *
@@ -1215,14 +1912,54 @@ impl Webinars {
     ) -> Result<()> {
         let url = format!(
             "/webinars/{}/livestream/status",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
+            self.client.encode_path(&webinar_id.to_string()),
+        );
+
+        self.client
+            .patch(&url, Some(self.client.serialize_body(body)?))
+            .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/webinars/{webinarId}/livestream/status",
+                    &[("webinarId", webinar_id.to_string())],
+                )
+            })
+    }
+
+    /**
+     * Update Live Stream Status.
+     *
+     * This function performs a `PATCH` to the `/webinars/{webinarId}/livestream/status` endpoint.
+     *
+     * As opposed to `live_stream_status_update`, this function takes the `current` state of what it updates and only sends the fields of `body` that differ from it. It returns `None` without sending anything if none do.
+     */
+    pub async fn live_stream_status_update_if_changed<C: serde::Serialize>(
+        &self,
+        webinar_id: i64,
+        current: &C,
+        body: &crate::types::WebinarLiveStreamStatus,
+    ) -> Result<Option<()>> {
+        let url = format!(
+            "/webinars/{}/livestream/status",
+            self.client.encode_path(&webinar_id.to_string()),
         );
 
         self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .patch_if_changed(&url, current, body)
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "PATCH",
+                    "/webinars/{webinarId}/livestream/status",
+                    &[("webinarId", webinar_id.to_string())],
+                )
+            })
     }
 
+    /// The OAuth scopes for `invite_links_create`, any one of them grants access.
+    pub const INVITE_LINKS_CREATE_SCOPES: &[&str] = &["webinar:write:admin", "webinar:write"];
+
     /**
      * Create webinar's invite links.
      *
@@ -1247,11 +1984,531 @@ impl Webinars {
     ) -> Result<crate::types::InviteLinks> {
         let url = format!(
             "/webinars/{}/invite_links",
-            crate::progenitor_support::encode_path(&webinar_id.to_string()),
+            self.client.encode_path(&webinar_id.to_string()),
         );
 
         self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .post(&url, Some(self.client.serialize_body(body)?))
             .await
+            .with_context(|| {
+                crate::utils::pretty_url(
+                    "POST",
+                    "/webinars/{webinarId}/invite_links",
+                    &[("webinarId", webinar_id.to_string())],
+                )
+            })
+    }
+}
+
+/// A request to [`Webinars::registrant`], see [`Webinars::registrant_request`].
+pub struct RegistrantRequest<'a> {
+    api: &'a Webinars,
+    webinar_id: String,
+    occurrence_id: Option<String>,
+    status: crate::types::MeetingRegistrantsStatus,
+    tracking_source_id: Option<String>,
+    page_size: Option<i64>,
+    page_number: Option<i64>,
+    next_page_token: Option<String>,
+}
+
+impl<'a> RegistrantRequest<'a> {
+    /// Set `occurrence_id`.
+    pub fn occurrence_id(mut self, occurrence_id: &str) -> Self {
+        self.occurrence_id = Some(occurrence_id.to_string());
+        self
+    }
+
+    /// Set `tracking_source_id`.
+    pub fn tracking_source_id(mut self, tracking_source_id: &str) -> Self {
+        self.tracking_source_id = Some(tracking_source_id.to_string());
+        self
+    }
+
+    /// Set `page_size`.
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Set `page_number`.
+    pub fn page_number(mut self, page_number: i64) -> Self {
+        self.page_number = Some(page_number);
+        self
+    }
+
+    /// Set `next_page_token`.
+    pub fn next_page_token(mut self, next_page_token: &str) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    /// Send the request.
+    pub async fn send(self) -> Result<crate::types::Domains> {
+        self.api
+            .registrant(
+                &self.webinar_id,
+                self.occurrence_id.as_deref().unwrap_or_default(),
+                self.status,
+                self.tracking_source_id.as_deref().unwrap_or_default(),
+                self.page_size.unwrap_or_default(),
+                self.page_number.unwrap_or_default(),
+                self.next_page_token.as_deref().unwrap_or_default(),
+            )
+            .await
+    }
+}
+
+/// The functions of [`Webinars`] as a trait, to mock them in tests, say with
+/// `mockall::mock!`. Functions generic over their body or returning an
+/// `impl Stream` are left out, so the trait can be used as a `dyn WebinarsApi`.
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+pub trait WebinarsApi: Send + Sync {
+    async fn get(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        page_number: i64,
+    ) -> Result<crate::types::Domains>;
+    async fn create(&self, user_id: &str) -> Result<crate::types::WebinarCreateResponseAllOf>;
+    async fn webinar(
+        &self,
+        webinar_id: i64,
+        occurrence_id: &str,
+        show_previous_occurrences: bool,
+    ) -> Result<crate::types::WebinarResponseAllOf>;
+    async fn delete(
+        &self,
+        webinar_id: i64,
+        occurrence_id: &str,
+        cancel_webinar_reminder: &str,
+    ) -> Result<()>;
+    async fn delete_if_exists(
+        &self,
+        webinar_id: i64,
+        occurrence_id: &str,
+        cancel_webinar_reminder: &str,
+    ) -> Result<bool>;
+    async fn update(&self, webinar_id: i64, occurrence_id: &str) -> Result<()>;
+    async fn list_participants(
+        &self,
+        webinar_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::Participants>>;
+    async fn list_participants_page(
+        &self,
+        webinar_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::Participants>, String)>;
+    async fn list_all_participants(
+        &self,
+        webinar_id: &str,
+    ) -> Result<Vec<crate::types::Participants>>;
+    async fn status(
+        &self,
+        webinar_id: i64,
+        body: &crate::types::WebinarStatusRequest,
+    ) -> Result<()>;
+    async fn panelist(&self, webinar_id: i64) -> Result<crate::types::Domains>;
+    async fn panelist_create(
+        &self,
+        webinar_id: i64,
+        body: &crate::types::WebinarPanelist,
+    ) -> Result<()>;
+    async fn panelists_delete(&self, webinar_id: i64) -> Result<()>;
+    async fn panelists_delete_if_exists(&self, webinar_id: i64) -> Result<bool>;
+    async fn panelist_delete(&self, webinar_id: i64, panelist_id: i64) -> Result<()>;
+    async fn panelist_delete_if_exists(&self, webinar_id: i64, panelist_id: i64) -> Result<bool>;
+    async fn registrant(
+        &self,
+        webinar_id: &str,
+        occurrence_id: &str,
+        status: crate::types::MeetingRegistrantsStatus,
+        tracking_source_id: &str,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::Domains>;
+    async fn registrant_create(
+        &self,
+        webinar_id: &str,
+        occurrence_ids: &str,
+    ) -> Result<crate::types::WebinarRegistrantCreateResponse>;
+    async fn add_batch_registrants(
+        &self,
+        webinar_id: &str,
+        body: &crate::types::AddBatchRegistrantsRequest,
+    ) -> Result<crate::types::AddBatchRegistrantsResponse>;
+    async fn registrant_status(
+        &self,
+        webinar_id: i64,
+        occurrence_id: &str,
+        body: &crate::types::RegistrantStatus,
+    ) -> Result<()>;
+    async fn past(&self, webinar_id: i64) -> Result<crate::types::Domains>;
+    async fn poll(&self, webinar_id: i64) -> Result<crate::types::Domains>;
+    async fn poll_create(
+        &self,
+        webinar_id: i64,
+        body: &crate::types::Poll,
+    ) -> Result<crate::types::MeetingPollGetResponseAllOf>;
+    async fn poll_get(
+        &self,
+        webinar_id: i64,
+        poll_id: &str,
+    ) -> Result<crate::types::MeetingPollGetResponseAllOf>;
+    async fn poll_update(
+        &self,
+        webinar_id: i64,
+        poll_id: &str,
+        body: &crate::types::Poll,
+    ) -> Result<()>;
+    async fn poll_delete(&self, webinar_id: i64, poll_id: &str) -> Result<()>;
+    async fn poll_delete_if_exists(&self, webinar_id: i64, poll_id: &str) -> Result<bool>;
+    async fn registrants_questions_get(
+        &self,
+        webinar_id: i64,
+    ) -> Result<crate::types::WebinarRegistrantQuestions>;
+    async fn registrant_question_update(
+        &self,
+        webinar_id: i64,
+        body: &crate::types::WebinarRegistrantQuestions,
+    ) -> Result<()>;
+    async fn registrant_get(
+        &self,
+        webinar_id: &str,
+        registrant_id: &str,
+        occurrence_id: &str,
+    ) -> Result<crate::types::Domains>;
+    async fn delete_registrant(
+        &self,
+        webinar_id: &str,
+        registrant_id: &str,
+        occurrence_id: &str,
+    ) -> Result<()>;
+    async fn delete_registrant_if_exists(
+        &self,
+        webinar_id: &str,
+        registrant_id: &str,
+        occurrence_id: &str,
+    ) -> Result<bool>;
+    async fn absentee(
+        &self,
+        occurrence_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        webinar_uuid: &str,
+    ) -> Result<crate::types::Domains>;
+    async fn get_tracking_sources(
+        &self,
+        webinar_id: &str,
+    ) -> Result<crate::types::GetTrackingSourcesResponse>;
+    async fn list_past_poll_results(
+        &self,
+        webinar_id: &str,
+    ) -> Result<crate::types::ReportMeetingPollsResponse>;
+    async fn list_past_qa(&self, webinar_id: &str)
+        -> Result<crate::types::ReportWebinarQaResponse>;
+    async fn list_templates(
+        &self,
+        user_id: &str,
+    ) -> Result<crate::types::ListWebinarTemplatesResponse>;
+    async fn get_live_stream_details(
+        &self,
+        webinar_id: &str,
+    ) -> Result<crate::types::GetLiveStreamDetailsResponse>;
+    async fn live_stream_update(
+        &self,
+        webinar_id: &str,
+        body: &crate::types::MeetingLiveStream,
+    ) -> Result<()>;
+    async fn live_stream_status_update(
+        &self,
+        webinar_id: i64,
+        body: &crate::types::WebinarLiveStreamStatus,
+    ) -> Result<()>;
+    async fn invite_links_create(
+        &self,
+        webinar_id: i64,
+        body: &crate::types::InviteLink,
+    ) -> Result<crate::types::InviteLinks>;
+}
+
+#[cfg(feature = "mock")]
+#[async_trait::async_trait]
+impl WebinarsApi for Webinars {
+    async fn get(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        page_number: i64,
+    ) -> Result<crate::types::Domains> {
+        Webinars::get(self, user_id, page_size, page_number).await
+    }
+    async fn create(&self, user_id: &str) -> Result<crate::types::WebinarCreateResponseAllOf> {
+        Webinars::create(self, user_id).await
+    }
+    async fn webinar(
+        &self,
+        webinar_id: i64,
+        occurrence_id: &str,
+        show_previous_occurrences: bool,
+    ) -> Result<crate::types::WebinarResponseAllOf> {
+        Webinars::webinar(self, webinar_id, occurrence_id, show_previous_occurrences).await
+    }
+    async fn delete(
+        &self,
+        webinar_id: i64,
+        occurrence_id: &str,
+        cancel_webinar_reminder: &str,
+    ) -> Result<()> {
+        Webinars::delete(self, webinar_id, occurrence_id, cancel_webinar_reminder).await
+    }
+    async fn delete_if_exists(
+        &self,
+        webinar_id: i64,
+        occurrence_id: &str,
+        cancel_webinar_reminder: &str,
+    ) -> Result<bool> {
+        Webinars::delete_if_exists(self, webinar_id, occurrence_id, cancel_webinar_reminder).await
+    }
+    async fn update(&self, webinar_id: i64, occurrence_id: &str) -> Result<()> {
+        Webinars::update(self, webinar_id, occurrence_id).await
+    }
+    async fn list_participants(
+        &self,
+        webinar_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::Participants>> {
+        Webinars::list_participants(self, webinar_id, page_size, next_page_token).await
+    }
+    async fn list_participants_page(
+        &self,
+        webinar_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<(Vec<crate::types::Participants>, String)> {
+        Webinars::list_participants_page(self, webinar_id, page_size, next_page_token).await
+    }
+    async fn list_all_participants(
+        &self,
+        webinar_id: &str,
+    ) -> Result<Vec<crate::types::Participants>> {
+        Webinars::list_all_participants(self, webinar_id).await
+    }
+    async fn status(
+        &self,
+        webinar_id: i64,
+        body: &crate::types::WebinarStatusRequest,
+    ) -> Result<()> {
+        Webinars::status(self, webinar_id, body).await
+    }
+    async fn panelist(&self, webinar_id: i64) -> Result<crate::types::Domains> {
+        Webinars::panelist(self, webinar_id).await
+    }
+    async fn panelist_create(
+        &self,
+        webinar_id: i64,
+        body: &crate::types::WebinarPanelist,
+    ) -> Result<()> {
+        Webinars::panelist_create(self, webinar_id, body).await
+    }
+    async fn panelists_delete(&self, webinar_id: i64) -> Result<()> {
+        Webinars::panelists_delete(self, webinar_id).await
+    }
+    async fn panelists_delete_if_exists(&self, webinar_id: i64) -> Result<bool> {
+        Webinars::panelists_delete_if_exists(self, webinar_id).await
+    }
+    async fn panelist_delete(&self, webinar_id: i64, panelist_id: i64) -> Result<()> {
+        Webinars::panelist_delete(self, webinar_id, panelist_id).await
+    }
+    async fn panelist_delete_if_exists(&self, webinar_id: i64, panelist_id: i64) -> Result<bool> {
+        Webinars::panelist_delete_if_exists(self, webinar_id, panelist_id).await
+    }
+    async fn registrant(
+        &self,
+        webinar_id: &str,
+        occurrence_id: &str,
+        status: crate::types::MeetingRegistrantsStatus,
+        tracking_source_id: &str,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::Domains> {
+        Webinars::registrant(
+            self,
+            webinar_id,
+            occurrence_id,
+            status,
+            tracking_source_id,
+            page_size,
+            page_number,
+            next_page_token,
+        )
+        .await
+    }
+    async fn registrant_create(
+        &self,
+        webinar_id: &str,
+        occurrence_ids: &str,
+    ) -> Result<crate::types::WebinarRegistrantCreateResponse> {
+        Webinars::registrant_create(self, webinar_id, occurrence_ids).await
+    }
+    async fn add_batch_registrants(
+        &self,
+        webinar_id: &str,
+        body: &crate::types::AddBatchRegistrantsRequest,
+    ) -> Result<crate::types::AddBatchRegistrantsResponse> {
+        Webinars::add_batch_registrants(self, webinar_id, body).await
+    }
+    async fn registrant_status(
+        &self,
+        webinar_id: i64,
+        occurrence_id: &str,
+        body: &crate::types::RegistrantStatus,
+    ) -> Result<()> {
+        Webinars::registrant_status(self, webinar_id, occurrence_id, body).await
+    }
+    async fn past(&self, webinar_id: i64) -> Result<crate::types::Domains> {
+        Webinars::past(self, webinar_id).await
+    }
+    async fn poll(&self, webinar_id: i64) -> Result<crate::types::Domains> {
+        Webinars::poll(self, webinar_id).await
+    }
+    async fn poll_create(
+        &self,
+        webinar_id: i64,
+        body: &crate::types::Poll,
+    ) -> Result<crate::types::MeetingPollGetResponseAllOf> {
+        Webinars::poll_create(self, webinar_id, body).await
+    }
+    async fn poll_get(
+        &self,
+        webinar_id: i64,
+        poll_id: &str,
+    ) -> Result<crate::types::MeetingPollGetResponseAllOf> {
+        Webinars::poll_get(self, webinar_id, poll_id).await
+    }
+    async fn poll_update(
+        &self,
+        webinar_id: i64,
+        poll_id: &str,
+        body: &crate::types::Poll,
+    ) -> Result<()> {
+        Webinars::poll_update(self, webinar_id, poll_id, body).await
+    }
+    async fn poll_delete(&self, webinar_id: i64, poll_id: &str) -> Result<()> {
+        Webinars::poll_delete(self, webinar_id, poll_id).await
+    }
+    async fn poll_delete_if_exists(&self, webinar_id: i64, poll_id: &str) -> Result<bool> {
+        Webinars::poll_delete_if_exists(self, webinar_id, poll_id).await
+    }
+    async fn registrants_questions_get(
+        &self,
+        webinar_id: i64,
+    ) -> Result<crate::types::WebinarRegistrantQuestions> {
+        Webinars::registrants_questions_get(self, webinar_id).await
+    }
+    async fn registrant_question_update(
+        &self,
+        webinar_id: i64,
+        body: &crate::types::WebinarRegistrantQuestions,
+    ) -> Result<()> {
+        Webinars::registrant_question_update(self, webinar_id, body).await
+    }
+    async fn registrant_get(
+        &self,
+        webinar_id: &str,
+        registrant_id: &str,
+        occurrence_id: &str,
+    ) -> Result<crate::types::Domains> {
+        Webinars::registrant_get(self, webinar_id, registrant_id, occurrence_id).await
+    }
+    async fn delete_registrant(
+        &self,
+        webinar_id: &str,
+        registrant_id: &str,
+        occurrence_id: &str,
+    ) -> Result<()> {
+        Webinars::delete_registrant(self, webinar_id, registrant_id, occurrence_id).await
+    }
+    async fn delete_registrant_if_exists(
+        &self,
+        webinar_id: &str,
+        registrant_id: &str,
+        occurrence_id: &str,
+    ) -> Result<bool> {
+        Webinars::delete_registrant_if_exists(self, webinar_id, registrant_id, occurrence_id).await
+    }
+    async fn absentee(
+        &self,
+        occurrence_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        webinar_uuid: &str,
+    ) -> Result<crate::types::Domains> {
+        Webinars::absentee(
+            self,
+            occurrence_id,
+            page_size,
+            next_page_token,
+            webinar_uuid,
+        )
+        .await
+    }
+    async fn get_tracking_sources(
+        &self,
+        webinar_id: &str,
+    ) -> Result<crate::types::GetTrackingSourcesResponse> {
+        Webinars::get_tracking_sources(self, webinar_id).await
+    }
+    async fn list_past_poll_results(
+        &self,
+        webinar_id: &str,
+    ) -> Result<crate::types::ReportMeetingPollsResponse> {
+        Webinars::list_past_poll_results(self, webinar_id).await
+    }
+    async fn list_past_qa(
+        &self,
+        webinar_id: &str,
+    ) -> Result<crate::types::ReportWebinarQaResponse> {
+        Webinars::list_past_qa(self, webinar_id).await
+    }
+    async fn list_templates(
+        &self,
+        user_id: &str,
+    ) -> Result<crate::types::ListWebinarTemplatesResponse> {
+        Webinars::list_templates(self, user_id).await
+    }
+    async fn get_live_stream_details(
+        &self,
+        webinar_id: &str,
+    ) -> Result<crate::types::GetLiveStreamDetailsResponse> {
+        Webinars::get_live_stream_details(self, webinar_id).await
+    }
+    async fn live_stream_update(
+        &self,
+        webinar_id: &str,
+        body: &crate::types::MeetingLiveStream,
+    ) -> Result<()> {
+        Webinars::live_stream_update(self, webinar_id, body).await
+    }
+    async fn live_stream_status_update(
+        &self,
+        webinar_id: i64,
+        body: &crate::types::WebinarLiveStreamStatus,
+    ) -> Result<()> {
+        Webinars::live_stream_status_update(self, webinar_id, body).await
+    }
+    async fn invite_links_create(
+        &self,
+        webinar_id: i64,
+        body: &crate::types::InviteLink,
+    ) -> Result<crate::types::InviteLinks> {
+        Webinars::invite_links_create(self, webinar_id, body).await
     }
 }